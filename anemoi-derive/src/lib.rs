@@ -0,0 +1,129 @@
+//! This crate provides a derive macro for the `AnemoiAbsorb` trait of the
+//! `anemoi` crate, and is re-exported by the latter under its `derive` feature.
+
+#![deny(missing_docs)]
+#![deny(unsafe_code)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Index};
+
+/// Derives `AnemoiAbsorb<F>` for every field `F` over which all the fields of the
+/// type can be absorbed.
+///
+/// The fields of a struct are encoded one after the other in declaration order.
+/// A variant of an enum is encoded as its index in declaration order, followed by
+/// the encoding of its fields. Unions are not supported.
+#[proc_macro_derive(AnemoiAbsorb)]
+pub fn derive_anemoi_absorb(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let mut field_types = Vec::new();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            field_types.extend(data.fields.iter().map(|field| field.ty.clone()));
+            let members: Vec<TokenStream2> = match &data.fields {
+                Fields::Named(fields) => fields
+                    .named
+                    .iter()
+                    .map(|field| {
+                        let ident = &field.ident;
+                        quote!(self.#ident)
+                    })
+                    .collect(),
+                Fields::Unnamed(fields) => (0..fields.unnamed.len())
+                    .map(|i| {
+                        let index = Index::from(i);
+                        quote!(self.#index)
+                    })
+                    .collect(),
+                Fields::Unit => Vec::new(),
+            };
+
+            quote! {
+                #(::anemoi::AnemoiAbsorb::<__F>::absorb_into(&#members, elements);)*
+            }
+        }
+        Data::Enum(data) => {
+            let arms: Vec<TokenStream2> = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    field_types.extend(variant.fields.iter().map(|field| field.ty.clone()));
+                    let ident = &variant.ident;
+                    let index = i as u64;
+                    let bindings: Vec<_> = (0..variant.fields.len())
+                        .map(|j| format_ident!("__field{}", j))
+                        .collect();
+                    let pattern = match &variant.fields {
+                        Fields::Named(fields) => {
+                            let names = fields.named.iter().map(|field| &field.ident);
+                            quote!(Self::#ident { #(#names: #bindings),* })
+                        }
+                        Fields::Unnamed(_) => quote!(Self::#ident(#(#bindings),*)),
+                        Fields::Unit => quote!(Self::#ident),
+                    };
+
+                    quote! {
+                        #pattern => {
+                            elements.push(<__F as ::core::convert::From<u64>>::from(#index));
+                            #(::anemoi::AnemoiAbsorb::<__F>::absorb_into(#bindings, elements);)*
+                        }
+                    }
+                })
+                .collect();
+
+            if data.variants.is_empty() {
+                quote!(match *self {})
+            } else {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "AnemoiAbsorb cannot be derived for unions",
+            ));
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    generics
+        .params
+        .push(parse_quote!(__F: ::anemoi::__private::PrimeField));
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let mut where_clause = input
+        .generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| parse_quote!(where));
+    for ty in field_types {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::anemoi::AnemoiAbsorb<__F>));
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::anemoi::AnemoiAbsorb<__F> for #name #ty_generics #where_clause {
+            fn absorb_into(&self, elements: &mut ::anemoi::__private::Vec<__F>) {
+                #body
+            }
+        }
+    })
+}
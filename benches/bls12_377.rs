@@ -1,4 +1,4 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 
 extern crate anemoi;
 use anemoi::bls12_377::*;
@@ -173,6 +173,411 @@ fn criterion_benchmark(c: &mut Criterion) {
             bench.iter(|| anemoi_12_11::AnemoiHash::hash(black_box(&data)))
         },
     );
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bls12_377/2-1 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_2_1::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_2_1::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_2_1::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bls12_377/2-1 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_2_1::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_2_1::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bls12_377/4-3 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_4_3::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_4_3::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_4_3::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bls12_377/4-3 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_4_3::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_4_3::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bls12_377/6-5 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_6_5::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_6_5::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_6_5::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bls12_377/6-5 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_6_5::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_6_5::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bls12_377/8-7 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_8_7::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_8_7::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_8_7::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bls12_377/8-7 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_8_7::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_8_7::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bls12_377/10-9 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_10_9::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_10_9::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_10_9::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bls12_377/10-9 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_10_9::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_10_9::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bls12_377/12-11 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_12_11::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_12_11::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_12_11::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bls12_377/12-11 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_12_11::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_12_11::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    #[cfg(feature = "anemoi_256")]
+    anemoi_256_benchmark(c);
+}
+
+#[cfg(feature = "anemoi_256")]
+fn anemoi_256_benchmark(c: &mut Criterion) {
+    use anemoi::bls12_377::anemoi_256::*;
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/4-2 (256 bits security) - 2-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_4_2::STATE_WIDTH];
+
+            bench.iter(|| anemoi_4_2::AnemoiHash::compress(black_box(&v)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/4-2 (256 bits security) - 4-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_4_2::STATE_WIDTH];
+
+            bench.iter(|| anemoi_4_2::AnemoiHash::compress_k(black_box(&v), 4))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/6-4 (256 bits security) - 2-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_6_4::STATE_WIDTH];
+
+            bench.iter(|| anemoi_6_4::AnemoiHash::compress(black_box(&v)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/6-4 (256 bits security) - 6-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_6_4::STATE_WIDTH];
+
+            bench.iter(|| anemoi_6_4::AnemoiHash::compress_k(black_box(&v), 6))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/8-6 (256 bits security) - 2-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_8_6::STATE_WIDTH];
+
+            bench.iter(|| anemoi_8_6::AnemoiHash::compress(black_box(&v)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/8-6 (256 bits security) - 8-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_8_6::STATE_WIDTH];
+
+            bench.iter(|| anemoi_8_6::AnemoiHash::compress_k(black_box(&v), 8))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/10-8 (256 bits security) - 2-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_10_8::STATE_WIDTH];
+
+            bench.iter(|| anemoi_10_8::AnemoiHash::compress(black_box(&v)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/10-8 (256 bits security) - 10-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_10_8::STATE_WIDTH];
+
+            bench.iter(|| anemoi_10_8::AnemoiHash::compress_k(black_box(&v), 10))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/12-10 (256 bits security) - 2-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_12_10::STATE_WIDTH];
+
+            bench.iter(|| anemoi_12_10::AnemoiHash::compress(black_box(&v)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-jive/bls12_377/12-10 (256 bits security) - 12-to-1 compression",
+        |bench| {
+            let v = [Felt::one(); anemoi_12_10::STATE_WIDTH];
+
+            bench.iter(|| anemoi_12_10::AnemoiHash::compress_k(black_box(&v), 12))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-sponge/bls12_377/4-2 (256 bits security) - hash 10KB",
+        |bench| {
+            let mut data = vec![0u8; 10 * 1024];
+            let mut rng = OsRng;
+            rng.fill_bytes(&mut data);
+
+            bench.iter(|| anemoi_4_2::AnemoiHash::hash(black_box(&data)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-sponge/bls12_377/6-4 (256 bits security) - hash 10KB",
+        |bench| {
+            let mut data = vec![0u8; 10 * 1024];
+            let mut rng = OsRng;
+            rng.fill_bytes(&mut data);
+
+            bench.iter(|| anemoi_6_4::AnemoiHash::hash(black_box(&data)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-sponge/bls12_377/8-6 (256 bits security) - hash 10KB",
+        |bench| {
+            let mut data = vec![0u8; 10 * 1024];
+            let mut rng = OsRng;
+            rng.fill_bytes(&mut data);
+
+            bench.iter(|| anemoi_8_6::AnemoiHash::hash(black_box(&data)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-sponge/bls12_377/10-8 (256 bits security) - hash 10KB",
+        |bench| {
+            let mut data = vec![0u8; 10 * 1024];
+            let mut rng = OsRng;
+            rng.fill_bytes(&mut data);
+
+            bench.iter(|| anemoi_10_8::AnemoiHash::hash(black_box(&data)))
+        },
+    );
+
+    c.bench_function(
+        "anemoi-sponge/bls12_377/12-10 (256 bits security) - hash 10KB",
+        |bench| {
+            let mut data = vec![0u8; 10 * 1024];
+            let mut rng = OsRng;
+            rng.fill_bytes(&mut data);
+
+            bench.iter(|| anemoi_12_10::AnemoiHash::hash(black_box(&data)))
+        },
+    );
 }
 
 criterion_group!(
@@ -1,4 +1,4 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 
 extern crate anemoi;
 use anemoi::bn_254::*;
@@ -173,6 +173,258 @@ fn criterion_benchmark(c: &mut Criterion) {
             bench.iter(|| anemoi_12_11::AnemoiHash::hash(black_box(&data)))
         },
     );
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bn_254/2-1 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_2_1::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_2_1::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_2_1::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bn_254/2-1 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_2_1::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_2_1::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bn_254/4-3 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_4_3::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_4_3::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_4_3::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bn_254/4-3 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_4_3::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_4_3::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bn_254/6-5 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_6_5::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_6_5::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_6_5::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bn_254/6-5 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_6_5::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_6_5::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bn_254/8-7 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_8_7::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_8_7::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_8_7::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bn_254/8-7 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_8_7::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_8_7::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bn_254/10-9 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_10_9::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_10_9::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_10_9::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bn_254/10-9 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_10_9::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_10_9::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-jive/bn_254/12-11 (128 bits security) - 2-to-1 compression of 64 states",
+        );
+        group.throughput(Throughput::Elements(64));
+        let states = vec![[Felt::one(); anemoi_12_11::STATE_WIDTH]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&states)
+                    .iter()
+                    .map(|state| anemoi_12_11::AnemoiHash::compress(state))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_12_11::AnemoiHash::compress_batch(black_box(&states)))
+        });
+        group.finish();
+    }
+
+    {
+        let mut group = c.benchmark_group(
+            "anemoi-sponge/bn_254/12-11 (128 bits security) - hash 64 sequences of 10 elements",
+        );
+        group.throughput(Throughput::Elements(64));
+        let inputs = vec![vec![Felt::one(); 10]; 64];
+
+        group.bench_function("scalar", |bench| {
+            bench.iter(|| {
+                black_box(&inputs)
+                    .iter()
+                    .map(|input| anemoi_12_11::AnemoiHash::hash_field(input))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function("batch", |bench| {
+            bench.iter(|| anemoi_12_11::AnemoiHash::hash_field_batch(black_box(&inputs)))
+        });
+        group.finish();
+    }
 }
 
 criterion_group!(
@@ -0,0 +1,290 @@
+#!/usr/bin/env python3
+"""Computes Anemoi hash and Jive compression outputs, as used in the test vectors
+of the `hasher.rs` files of the instantiations.
+
+This is a port to plain Python of the Sage reference implementation of Anemoi
+(https://github.com/anemoi-hash/anemoi-hash/, `anemoi.py`), written independently
+from the Rust implementation:
+
+- the number of rounds of the 128-bit instantiations follows the rule of the reference
+  implementation, i.e. the Gröbner basis bound with `kappa = (alpha - 1) / 2`, plus a
+  security margin of `min(5, l + 1)` rounds, with a floor of 10 rounds, and that of the
+  256-bit instantiations follows the rule of the Anemoi paper, like the `params` module;
+- the constants are derived from a generator `g` of the multiplicative group and
+  from the digits of pi;
+- the sponge pads its input with a single 1 followed by zeros, unless its length is
+  a multiple of the rate, in which case 1 is added to the last capacity register.
+
+The byte hashing of the Rust implementation, which splits bytes into chunks of as
+many whole bytes as fit below the modulus and pads the last one with a byte set to 1, is provided
+on top of it. It outputs the same vectors as the reference implementation for the
+BLS12-377, BLS12-381, BN-254, Ed-on-BLS12-377, Jubjub, Pallas and Vesta instantiations
+of the `test_anemoi_hash` and `test_anemoi_jive` tests, some of which `check()` verifies.
+
+Usage: python3 scripts/anemoi.py <field> <num columns> <security level> hash|jive [k] <x>...
+where the inputs `x` are integers, e.g.
+    python3 scripts/anemoi.py stark252 2 128 jive 2 0 0 1 1
+It only requires Python 3.8 or later, and prints the output elements in Montgomery
+form, as the limbs given to `Felt::new`. `python3 scripts/anemoi.py check` runs
+the self-checks.
+"""
+
+import sys
+from math import comb, gcd
+
+PI_0 = 1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679
+PI_1 = 8214808651328230664709384460955058223172535940812848111745028410270193852110555964462294895493038196
+
+# Modulus and generator of the multiplicative group of each field.
+FIELDS = {
+    "bls12_377": (
+        0x01AE3A4617C510EAC63B05C06CA1493B1A22D9F300F5138F1EF3622FBA094800170B5D44300000008508C00000000001,
+        15,
+    ),
+    "bls12_381": (
+        0x1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAB,
+        2,
+    ),
+    "bn_254": (
+        21888242871839275222246405745257275088696311157297823662689037894645226208583,
+        3,
+    ),
+    "bw6_761": (
+        0x122E824FB83CE0AD187C94004FAFF3EB926186A81D14688528275EF8087BE41707BA638E584E91903CEBAFF25B423048689C8ED12F9FD9071DCD3DC73EBFF2E98A116C25667A8F8160CF8AEEAF0A437E6913E6870000082F49D00000000008B,
+        2,
+    ),
+    "ed_on_bls12_377": (
+        0x12AB655E9A2CA55660B44D1E5C37B00159AA76FED00000010A11800000000001,
+        22,
+    ),
+    "jubjub": (
+        0x73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFF00000001,
+        7,
+    ),
+    "pallas": (
+        0x40000000000000000000000000000000224698FC094CF91B992D30ED00000001,
+        5,
+    ),
+    "secp256k1": (2**256 - 2**32 - 977, 3),
+    "secq256k1": (
+        0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141,
+        7,
+    ),
+    "stark252": (2**251 + 17 * 2**192 + 1, 3),
+    "vesta": (
+        0x40000000000000000000000000000000224698FC0994A8DD8C46EB2100000001,
+        5,
+    ),
+}
+
+
+def num_rounds(alpha, num_columns, security_level):
+    """Number of rounds, following the rule of the reference implementation."""
+    kappa = (alpha - 1) // 2
+    r = 0
+    while True:
+        r += 1
+        if comb(4 * num_columns * r + kappa, 2 * num_columns * r) ** 2 >= 2**security_level:
+            break
+    return max(10, r + min(5, num_columns + 1))
+
+
+def paper_num_rounds(p, alpha, num_columns, security_level):
+    """Number of rounds, following the rule of the Anemoi paper."""
+
+    def smallest_rounds(cost):
+        r = 1
+        while cost(r) ** 2 < 2**security_level:
+            r += 1
+        return r
+
+    def degree_rounds(num_variables):
+        degree_bits = p.bit_length() - 1 - (alpha - 1).bit_length()
+        monomial_bits = num_variables * (degree_bits - (num_variables - 1).bit_length())
+        assert monomial_bits >= security_level, "the field is too small"
+        return 1
+
+    kappa = {3: 1, 5: 2, 7: 4, 9: 7, 11: 9}[alpha]
+    n = lambda r: 2 * num_columns * r
+    algebraic = max(
+        smallest_rounds(lambda r: comb(2 * n(r) + kappa, n(r))),
+        smallest_rounds(lambda r: comb(n(r) + 1 + n(r) * (alpha - 1), n(r))),
+        degree_rounds(num_columns),
+        degree_rounds(2 * num_columns),
+    )
+    return max(8, min(5, num_columns + 1) + 2 + algebraic)
+
+
+def mds_matrix(g, num_columns):
+    if num_columns in (1, 2):
+        return [[1, g], [g, g * g + 1]]
+    if num_columns == 3:
+        return [[g + 1, 1, g + 1], [1, 1, g], [g, 1, 1]]
+    if num_columns == 4:
+        return [
+            [1, g + 1, g, g],
+            [g * g, g * g + g, g + 1, 2 * g + 1],
+            [g * g, g * g, 1, g + 1],
+            [g + 1, 2 * g + 1, g, g + 1],
+        ]
+    row = [1, 1, 3, 4, 5, 6]
+    return [[row[(j - i) % num_columns] for j in range(num_columns)] for i in range(num_columns)]
+
+
+class Anemoi:
+    def __init__(self, field, num_columns, security_level):
+        self.p, self.g = FIELDS[field]
+        p, g = self.p, self.g
+        # As required by arkworks, the most significant bit of the last limb is always free.
+        self.num_limbs = p.bit_length() // 64 + 1
+        # Bytes are hashed by chunks of as many whole bytes as fit below the modulus.
+        self.chunk_size = (p.bit_length() - 1) // 8
+        self.l = num_columns
+        self.alpha = next(a for a in range(3, 100, 2) if gcd(a, p - 1) == 1)
+        self.inv_alpha = pow(self.alpha, -1, p - 1)
+        self.delta = pow(g, -1, p)
+        if security_level == 128:
+            self.n_rounds = num_rounds(self.alpha, num_columns, security_level)
+        else:
+            self.n_rounds = paper_num_rounds(p, self.alpha, num_columns, security_level)
+        self.mds = mds_matrix(g, num_columns)
+
+        # The 128-bit instantiations have a capacity of one element, and output a
+        # single element. The 256-bit ones have a capacity of two elements.
+        self.capacity = 1 if security_level == 128 else 2
+        self.rate = 2 * num_columns - self.capacity
+        self.digest_size = self.capacity
+
+        self.c, self.d = [], []
+        for r in range(self.n_rounds):
+            pi_0_r = pow(PI_0, r, p)
+            c_r, d_r = [], []
+            for i in range(num_columns):
+                pi_1_i = pow(PI_1, i, p)
+                pow_alpha = pow(pi_0_r + pi_1_i, self.alpha, p)
+                c_r.append((g * pi_0_r * pi_0_r + pow_alpha) % p)
+                d_r.append((g * pi_1_i * pi_1_i + pow_alpha + self.delta) % p)
+            self.c.append(c_r)
+            self.d.append(d_r)
+
+    def mul(self, m, v):
+        return [sum(a * b for a, b in zip(row, v)) % self.p for row in m]
+
+    def linear_layer(self, x, y):
+        if self.l == 1:
+            x, y = self.mul(self.mds, [x[0], y[0]])
+            return [x], [y]
+        return self.mul(self.mds, x), self.mul(self.mds, y[1:] + y[:1])
+
+    def sbox(self, x, y):
+        p = self.p
+        x = (x - self.g * y * y) % p
+        y = (y - pow(x, self.inv_alpha, p)) % p
+        x = (x + self.g * y * y + self.delta) % p
+        return x, y
+
+    def permutation(self, state):
+        x, y = state[: self.l], state[self.l :]
+        for r in range(self.n_rounds):
+            x = [(a + b) % self.p for a, b in zip(x, self.c[r])]
+            y = [(a + b) % self.p for a, b in zip(y, self.d[r])]
+            x, y = self.linear_layer(x, y)
+            x, y = map(list, zip(*(self.sbox(a, b) for a, b in zip(x, y))))
+        x, y = self.linear_layer(x, y)
+        return x + y
+
+    def hash(self, elements):
+        x = list(elements)
+        sigma = 0
+        if len(x) % self.rate == 0:
+            sigma = 1
+        else:
+            x.append(1)
+            x += [0] * (-len(x) % self.rate)
+
+        state = [0] * (2 * self.l)
+        for i in range(0, len(x), self.rate):
+            for j in range(self.rate):
+                state[j] = (state[j] + x[i + j]) % self.p
+            state = self.permutation(state)
+        state[-1] = (state[-1] + sigma) % self.p
+
+        return state[: self.digest_size]
+
+    def hash_bytes(self, data):
+        """Hashes bytes as the Rust implementation does, splitting them into chunks of
+        `chunk_size` bytes, and padding the last one with a byte set to 1."""
+        size = self.chunk_size
+        elements = []
+        for i in range(0, len(data), size):
+            chunk = data[i : i + size]
+            if len(chunk) < size:
+                chunk = chunk + b"\x01"
+            elements.append(int.from_bytes(chunk, "little"))
+        return self.hash(elements)
+
+    def jive(self, state, k):
+        """Jive compression by a factor `k` of a full state."""
+        output = self.permutation(state)
+        n = len(state) // k
+        return [sum(state[i + n * j] + output[i + n * j] for j in range(k)) % self.p for i in range(n)]
+
+    def to_montgomery(self, x):
+        x = x * 2 ** (64 * self.num_limbs) % self.p
+        return [(x >> (64 * i)) & (2**64 - 1) for i in range(self.num_limbs)]
+
+    def from_montgomery(self, limbs):
+        x = sum(limb << (64 * i) for i, limb in enumerate(limbs))
+        return x * pow(2, -64 * self.num_limbs, self.p) % self.p
+
+
+def check():
+    """Checks the round counts against the table of the `params` module, and a few
+    outputs against vectors of the reference implementation."""
+    for alpha, expected in [
+        (3, [19, 12, 10, 10, 10, 10]),
+        (5, [19, 12, 10, 10, 10, 10]),
+        (11, [18, 11, 10, 10, 10, 10]),
+    ]:
+        assert [num_rounds(alpha, l, 128) for l in range(1, 7)] == expected
+    p = FIELDS["bls12_381"][0]
+    assert [paper_num_rounds(p, 5, l, 128) for l in range(1, 7)] == [21, 14, 12, 12, 11, 10]
+    assert [paper_num_rounds(p, 5, l, 256) for l in range(2, 7)] == [22, 17, 16, 14, 13]
+
+    # Vectors of the reference implementation, from the `test_anemoi_hash` tests of
+    # `src/bn_254/anemoi_2_1/hasher.rs` and `src/vesta/anemoi_8_7/hasher.rs`, and from
+    # the `test_anemoi_jive` test of `src/bls12_381/anemoi_4_3/hasher.rs`.
+    assert Anemoi("bn_254", 1, 128).hash([1, 1]) == [
+        3228662258691880240805467319431014260355065472214663737477157286830651118527
+    ]
+    assert Anemoi("vesta", 4, 128).hash([1] * 8) == [
+        96534867538429216277513804685616562042640890598239301834270307101784781431
+    ]
+    assert Anemoi("bls12_381", 2, 128).jive([0, 0, 1, 1], 2) == [
+        2310833481002213911592975420286002170947154661634300549627938042266689439921445904129240133991903797962867915751718,
+        1184800901629606915895803289763661787133858702110015977054885857798806779579688146255577140291903468624137014165437,
+    ]
+
+
+def main():
+    if sys.argv[1:] == ["check"]:
+        check()
+        print("ok")
+        return
+
+    field, num_columns, security_level, mode = sys.argv[1:5]
+    anemoi = Anemoi(field, int(num_columns), int(security_level))
+    args = [int(x, 0) for x in sys.argv[5:]]
+    if mode == "hash":
+        output = anemoi.hash(args)
+    elif mode == "jive":
+        output = anemoi.jive(args[1:], args[0])
+    else:
+        raise SystemExit("unknown mode: " + mode)
+
+    for x in output:
+        print("[" + ", ".join("0x%016x" % limb for limb in anemoi.to_montgomery(x)) + "]")
+
+
+if __name__ == "__main__":
+    main()
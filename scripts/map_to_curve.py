@@ -0,0 +1,369 @@
+#!/usr/bin/env python3
+"""Generates the `test_map_to_curve` vectors of `src/bn_254/hash_to_g1.rs`,
+`src/bls12_377/hash_to_g1.rs`, `src/jubjub/curves/jubjub.rs`,
+`src/jubjub/curves/bandersnatch/mod.rs` and `src/ed_on_bls12_377/curve.rs`.
+
+This is a straightforward implementation of the Shallue-van de Woestijne, simplified
+SWU and Elligator 2 maps of RFC 9380 (Appendices F.1 and F.2, and Section 6.7.1), with
+their parameters chosen as in Appendices H.1, H.2 and H.3, written independently from the
+Rust implementation. The 2-isogeny of BLS12-377 is the one of ark-bls12-377 0.4.0
+(`src/curves/g1_swu_iso.rs`). Elligator 2 maps to the Montgomery form of the twisted
+Edwards curves, which is then sent to the curves with the rational map of Appendix D.1.
+
+Usage: python3 scripts/map_to_curve.py
+It only requires Python 3.8 or later, and prints the vectors as Rust tuples `(u, x, y)`.
+"""
+
+
+class Field:
+    def __init__(self, p):
+        self.p = p
+
+    def inv0(self, x):
+        return pow(x, self.p - 2, self.p)
+
+    def is_square(self, x):
+        return pow(x, (self.p - 1) // 2, self.p) in (0, 1)
+
+    def sqrt(self, x):
+        """Tonelli-Shanks square root of a square `x`."""
+        p = self.p
+        assert self.is_square(x)
+        if x % p == 0:
+            return 0
+        q, s = p - 1, 0
+        while q % 2 == 0:
+            q, s = q // 2, s + 1
+        z = 2
+        while self.is_square(z):
+            z += 1
+        m, c, t, r = s, pow(z, q, p), pow(x, q, p), pow(x, (q + 1) // 2, p)
+        while t != 1:
+            i, t2 = 0, t
+            while t2 != 1:
+                t2, i = t2 * t2 % p, i + 1
+            b = pow(c, 1 << (m - i - 1), p)
+            m, c, t, r = i, b * b % p, t * b * b % p, r * b % p
+        assert r * r % p == x % p
+        return r
+
+    @staticmethod
+    def sgn0(x):
+        return x % 2
+
+
+class Curve:
+    """The curve y^2 = x^3 + A * x + B over `field`."""
+
+    def __init__(self, field, a, b):
+        self.field, self.a, self.b = field, a % field.p, b % field.p
+
+    def g(self, x):
+        return (x * x * x + self.a * x + self.b) % self.field.p
+
+    def is_on_curve(self, point):
+        x, y = point
+        return (y * y - self.g(x)) % self.field.p == 0
+
+
+class EdwardsCurve:
+    """The twisted Edwards curve a * v^2 + w^2 = 1 + d * v^2 * w^2 over `field`."""
+
+    def __init__(self, field, a, d):
+        self.field, self.a, self.d = field, a % field.p, d % field.p
+
+    def is_on_curve(self, point):
+        v, w = point
+        v2, w2 = v * v, w * w
+        return (self.a * v2 + w2 - 1 - self.d * v2 * w2) % self.field.p == 0
+
+
+def find_z_svdw(curve):
+    """Appendix H.1 of RFC 9380."""
+    f, p = curve.field, curve.field.p
+
+    def h(z):
+        return -(3 * z * z + 4 * curve.a) * f.inv0(4 * curve.g(z)) % p
+
+    ctr = 1
+    while True:
+        for z in (ctr % p, -ctr % p):
+            if curve.g(z) == 0 or h(z) == 0 or not f.is_square(h(z)):
+                continue
+            if f.is_square(curve.g(z)) or f.is_square(curve.g(-z * f.inv0(2) % p)):
+                return z
+        ctr += 1
+
+
+def map_to_curve_svdw(curve, z, u):
+    """Appendix F.1 of RFC 9380."""
+    f, p = curve.field, curve.field.p
+    c1 = curve.g(z)
+    c2 = -z * f.inv0(2) % p
+    c3 = f.sqrt(-c1 * (3 * z * z + 4 * curve.a) % p)
+    if f.sgn0(c3) == 1:
+        c3 = p - c3
+    c4 = -4 * c1 * f.inv0(3 * z * z + 4 * curve.a) % p
+
+    tv1 = u * u * c1 % p
+    tv2 = (1 + tv1) % p
+    tv1 = (1 - tv1) % p
+    tv3 = f.inv0(tv1 * tv2 % p)
+    tv4 = u * tv1 * tv3 * c3 % p
+    x1 = (c2 - tv4) % p
+    e1 = f.is_square(curve.g(x1))
+    x2 = (c2 + tv4) % p
+    e2 = f.is_square(curve.g(x2)) and not e1
+    x3 = pow(tv2 * tv2 * tv3, 2, p) * c4 % p
+    x3 = (x3 + z) % p
+    x = x1 if e1 else (x2 if e2 else x3)
+    y = f.sqrt(curve.g(x))
+    if f.sgn0(u) != f.sgn0(y):
+        y = (p - y) % p
+
+    return x, y
+
+
+def is_irreducible_cubic(field, a, b, c):
+    """Returns whether x^3 + a * x + (b - c) has no root, by computing gcd(x^p - x, g)
+    through x^p mod g."""
+    p = field.p
+
+    def mulmod(u, v):
+        # Polynomials of degree at most 2, reduced modulo x^3 = -a * x - (b - c).
+        w = [0] * 5
+        for i, ui in enumerate(u):
+            for j, vj in enumerate(v):
+                w[i + j] = (w[i + j] + ui * vj) % p
+        for k in (4, 3):
+            w[k - 2] = (w[k - 2] - a * w[k]) % p
+            w[k - 3] = (w[k - 3] - (b - c) * w[k]) % p
+            w[k] = 0
+        return w[:3]
+
+    result, base, e = [1, 0, 0], [0, 1, 0], p
+    while e:
+        if e & 1:
+            result = mulmod(result, base)
+        base = mulmod(base, base)
+        e >>= 1
+    # A cubic has no root if and only if x^p = x has no solution modulo it,
+    # i.e. if x^p - x is coprime with it, which we check by a gcd.
+    xp_minus_x = [result[0], (result[1] - 1) % p, result[2]]
+    return polynomial_gcd_degree(field, [(b - c) % p, a % p, 0, 1], xp_minus_x) == 0
+
+
+def polynomial_gcd_degree(field, u, v):
+    p = field.p
+
+    def trim(w):
+        while w and w[-1] == 0:
+            w = w[:-1]
+        return w
+
+    u, v = trim(u), trim(v)
+    while v:
+        inv = field.inv0(v[-1])
+        while len(u) >= len(v):
+            factor = u[-1] * inv % p
+            shift = len(u) - len(v)
+            for i, vi in enumerate(v):
+                u[i + shift] = (u[i + shift] - factor * vi) % p
+            u = trim(u)
+        u, v = v, u
+    return len(u) - 1
+
+
+def find_z_sswu(curve):
+    """Appendix H.2 of RFC 9380."""
+    f, p = curve.field, curve.field.p
+
+    def h(z):
+        return curve.b * f.inv0(z * curve.a) % p
+
+    ctr = 1
+    while True:
+        for z in (ctr % p, -ctr % p):
+            if f.is_square(z) or z == p - 1:
+                continue
+            if not is_irreducible_cubic(f, curve.a, curve.b, z):
+                continue
+            if f.is_square(curve.g(h(z))):
+                return z
+        ctr += 1
+
+
+def map_to_curve_sswu(curve, z, u):
+    """Appendix F.2 of RFC 9380, without the optimizations of the straight-line version."""
+    f, p = curve.field, curve.field.p
+    tv1 = f.inv0((z * z * pow(u, 4, p) + z * u * u) % p)
+    if tv1 == 0:
+        x1 = curve.b * f.inv0(z * curve.a) % p
+    else:
+        x1 = -curve.b * f.inv0(curve.a) * (1 + tv1) % p
+    gx1 = curve.g(x1)
+    x2 = z * u * u * x1 % p
+    if f.is_square(gx1):
+        x, y = x1, f.sqrt(gx1)
+    else:
+        x, y = x2, f.sqrt(curve.g(x2))
+    if f.sgn0(u) != f.sgn0(y):
+        y = (p - y) % p
+
+    return x, y
+
+
+def find_z_elligator2(field):
+    """Appendix H.3 of RFC 9380."""
+    ctr = 1
+    while True:
+        for z in (ctr % field.p, -ctr % field.p):
+            if not field.is_square(z):
+                return z
+        ctr += 1
+
+
+def map_to_curve_elligator2(curve, z, u):
+    """Section 6.7.1 of RFC 9380, to the Montgomery form K * t^2 = s^3 + J * s^2 + s of
+    `curve`, with J = 2 * (a + d) / (a - d) and K = 4 / (a - d), followed by the rational
+    map of Appendix D.1 of RFC 9380, sending its exceptional cases to the identity."""
+    f, p = curve.field, curve.field.p
+    j = 2 * (curve.a + curve.d) * f.inv0(curve.a - curve.d) % p
+    k = 4 * f.inv0(curve.a - curve.d) % p
+    j_over_k = j * f.inv0(k) % p
+
+    def g(x):
+        return (x * x * x + j_over_k * x * x + x * f.inv0(k * k)) % p
+
+    x1 = -j_over_k * f.inv0((1 + z * u * u) % p) % p
+    if x1 == 0:
+        x1 = -j_over_k % p
+    x2 = (-x1 - j_over_k) % p
+    if f.is_square(g(x1)):
+        x, y, sign = x1, f.sqrt(g(x1)), 1
+    else:
+        x, y, sign = x2, f.sqrt(g(x2)), 0
+    if f.sgn0(y) != sign:
+        y = (p - y) % p
+    s, t = x * k % p, y * k % p
+
+    if t == 0 or (s + 1) % p == 0:
+        return 0, 1
+    return s * f.inv0(t) % p, (s - 1) * f.inv0(s + 1) % p
+
+
+def evaluate(coefficients, x, p):
+    return sum(c * pow(x, i, p) for i, c in enumerate(coefficients)) % p
+
+
+def iso_map(field, isogeny, point):
+    x_num, x_den, y_num, y_den = isogeny
+    p = field.p
+    x, y = point
+    return (
+        evaluate(x_num, x, p) * field.inv0(evaluate(x_den, x, p)) % p,
+        y * evaluate(y_num, x, p) * field.inv0(evaluate(y_den, x, p)) % p,
+    )
+
+
+BLS12_381_R = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+
+BLS12_377_R = 8444461749428370424248824938781546531375899335154063827935233455917409239041
+
+BN_254_P = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+
+BLS12_377_P = 258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177
+
+# The 2-isogeny from y^2 = x^3 + A' * x + 22 to y^2 = x^3 + 1 of ark-bls12-377 0.4.0,
+# coefficients in increasing degree order.
+BLS12_377_ISO_A = 258664426012969092796408009721202742408018065645352501567204841856062976176281513834280849065051431927238430294002
+BLS12_377_ISO_B = 22
+BLS12_377_ISOGENY = (
+    [
+        193998319509726820447277314072485610595876362210707887456279225959507476652652651634192264150953923683470146535424,
+        40474824132456359704279181570318738632422647360355249739068643631356267969150730939906729705473,
+        193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093633,
+    ],
+    [
+        161899296529825438817116726281274954529690589441420998956274574525425071876602923759626918821892,
+        1,
+    ],
+    [
+        193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093631,
+        32333053251621136903112182208573040583096119983059602439070460434672245065050016464457115901761911040205276577794,
+        129332213006484547066038603046131306324615528732935438218576102373893108782773376834518846023512776472080255287298,
+        226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905,
+    ],
+    [
+        258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458169,
+        971395779178952632902700357687649727178143536648525993737647447152550431259617542557761512931340,
+        485697889589476316451350178843824863589071768324262996868823723576275215629808771278880756465676,
+        1,
+    ],
+)
+
+
+def print_vectors(name, inputs, map_to_curve, curve):
+    print(f"// {name}")
+    for u in inputs:
+        point = map_to_curve(u)
+        assert curve.is_on_curve(point)
+        print(f'("{u}", "{point[0]}", "{point[1]}"),')
+
+
+def main():
+    arbitrary = 0x0123456789ABCDEF0123456789ABCDEF
+
+    field = Field(BN_254_P)
+    curve = Curve(field, 0, 3)
+    z = find_z_svdw(curve)
+    assert z == 1
+    print_vectors(
+        "BN-254, Shallue-van de Woestijne",
+        [1, 2, field.p - 1, arbitrary, field.p // 3],
+        lambda u: map_to_curve_svdw(curve, z, u),
+        curve,
+    )
+
+    field = Field(BLS12_377_P)
+    iso_curve = Curve(field, BLS12_377_ISO_A, BLS12_377_ISO_B)
+    curve = Curve(field, 0, 1)
+    z = find_z_sswu(iso_curve)
+    assert z == field.p - 11
+    print_vectors(
+        "BLS12-377, simplified SWU and 2-isogeny",
+        [1, 2, field.p - 1, arbitrary, field.p // 3],
+        lambda u: iso_map(field, BLS12_377_ISOGENY, map_to_curve_sswu(iso_curve, z, u)),
+        curve,
+    )
+
+    for name, field, a, d, expected_z in [
+        (
+            "Jubjub, Elligator 2",
+            Field(BLS12_381_R),
+            -1,
+            -10240 * Field(BLS12_381_R).inv0(10241),
+            5,
+        ),
+        (
+            "Bandersnatch, Elligator 2",
+            Field(BLS12_381_R),
+            -5,
+            45022363124591815672509500913686876175488063829319466900776701791074614335719,
+            5,
+        ),
+        ("Ed-on-BLS12-377, Elligator 2", Field(BLS12_377_R), -1, 3021, 11),
+    ]:
+        curve = EdwardsCurve(field, a, d)
+        z = find_z_elligator2(field)
+        assert z == expected_z
+        print_vectors(
+            name,
+            [0, 1, 2, field.p - 1, arbitrary, field.p // 3],
+            lambda u: map_to_curve_elligator2(curve, z, u),
+            curve,
+        )
+
+
+if __name__ == "__main__":
+    main()
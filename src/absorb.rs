@@ -0,0 +1,467 @@
+//! Injective encoding of structured values as sequences of field elements.
+//!
+//! A value of a type implementing [`AnemoiAbsorb<F>`] is encoded as a sequence of elements of
+//! the prime field `F`, which can be absorbed by an Anemoi hasher over `F` with `absorb`. The
+//! encodings of the values of a given type are *prefix-free*: none of them is a prefix of
+//! another. Hence, concatenating the encodings of the components of a composite value yields
+//! an injective encoding of the composite type, from which the encodings below are built:
+//!
+//! * an element of `F` is encoded as itself;
+//! * a `bool` is encoded as `0` or `1`;
+//! * an unsigned integer is encoded as a single element holding its value, and a signed integer
+//!   as the unsigned integer of the same width sharing its two's complement representation. This
+//!   is only injective if the modulus of `F` exceeds 2^128, as for all fields of this crate, and
+//!   encoding an integer, including the length of a string or slice, panics otherwise;
+//! * a byte array `[u8; N]` is split into chunks of `F::Params::CAPACITY / 8` bytes, the last one
+//!   possibly shorter, each encoded as the element whose little-endian representation it is;
+//! * a `str` or `String` is encoded as its length in bytes, followed by its bytes encoded as
+//!   for a byte array;
+//! * a slice `[T]` or `Vec<T>` is encoded as its length, followed by the encodings of its items;
+//! * an `Option<T>` is encoded as `0` if it is `None`, and as `1` followed by the encoding of
+//!   the wrapped value otherwise;
+//! * a tuple is encoded as the concatenation of the encodings of its components, and `()` as an
+//!   empty sequence;
+//! * a reference `&T` is encoded as the value it refers to;
+//! * an `AnemoiDigest` is encoded as its elements.
+//!
+//! With the `derive` feature, `#[derive(AnemoiAbsorb)]` implements the trait for structs and
+//! enums. A struct is encoded as the concatenation of the encodings of its fields, in declaration
+//! order, and a variant of an enum as its index in declaration order followed by the encodings of
+//! its fields. Reordering the fields or variants of a type hence changes its encoding.
+//!
+//! The encoding is injective for values of the same type only: values of different types, e.g.
+//! `0u8` and `false`, may share the same encoding.
+//!
+//! Field elements are only encoded for the arkworks field types of 256 bits or more, so that
+//! elements of 64-bit fields, whose moduli are below 2^64, cannot be absorbed.
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+use ark_ff::{Fp256, Fp320, Fp384, Fp448, Fp768, Fp832, FpParameters, PrimeField};
+use ark_ff::{
+    Fp256Parameters, Fp320Parameters, Fp384Parameters, Fp448Parameters, Fp768Parameters,
+    Fp832Parameters,
+};
+
+/// Trait for values which can be absorbed by an Anemoi hasher over the field `F`,
+/// through an injective encoding as a sequence of elements of `F`.
+///
+/// Implementations must be prefix-free, as described in the [module](crate::absorb)
+/// documentation, which is preserved by encoding composite values as the concatenation
+/// of the encodings of their components.
+pub trait AnemoiAbsorb<F: PrimeField> {
+    /// Appends the encoding of this value to `elements`.
+    fn absorb_into(&self, elements: &mut Vec<F>);
+
+    /// Returns the encoding of this value.
+    fn encode(&self) -> Vec<F> {
+        let mut elements = Vec::new();
+        self.absorb_into(&mut elements);
+
+        elements
+    }
+}
+
+macro_rules! impl_field {
+    ($($field:ident: $params:ident),*) => {
+        $(
+            impl<P: $params> AnemoiAbsorb<$field<P>> for $field<P> {
+                fn absorb_into(&self, elements: &mut Vec<$field<P>>) {
+                    elements.push(*self);
+                }
+            }
+        )*
+    };
+}
+
+impl_field!(
+    Fp256: Fp256Parameters,
+    Fp320: Fp320Parameters,
+    Fp384: Fp384Parameters,
+    Fp448: Fp448Parameters,
+    Fp768: Fp768Parameters,
+    Fp832: Fp832Parameters
+);
+
+impl<F: PrimeField> AnemoiAbsorb<F> for bool {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        elements.push(F::from(*self));
+    }
+}
+
+macro_rules! impl_integer {
+    ($($unsigned:ty, $signed:ty);*) => {
+        $(
+            impl<F: PrimeField> AnemoiAbsorb<F> for $unsigned {
+                fn absorb_into(&self, elements: &mut Vec<F>) {
+                    absorb_integer(*self as u128, elements);
+                }
+            }
+
+            impl<F: PrimeField> AnemoiAbsorb<F> for $signed {
+                fn absorb_into(&self, elements: &mut Vec<F>) {
+                    absorb_integer(*self as $unsigned as u128, elements);
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, i8; u16, i16; u32, i32; u64, i64; u128, i128);
+
+impl<F: PrimeField> AnemoiAbsorb<F> for usize {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        absorb_integer(*self as u128, elements);
+    }
+}
+
+impl<F: PrimeField> AnemoiAbsorb<F> for isize {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        absorb_integer(*self as usize as u128, elements);
+    }
+}
+
+/// Appends the element holding `value`.
+///
+/// # Panics
+///
+/// Panics if the modulus of `F` is less than 2^128, as distinct integers could then
+/// be encoded as the same element.
+fn absorb_integer<F: PrimeField>(value: u128, elements: &mut Vec<F>) {
+    assert!(
+        F::Params::MODULUS_BITS > 128,
+        "integers are only absorbed over fields whose modulus exceeds 2^128"
+    );
+    elements.push(F::from(value));
+}
+
+/// Appends the elements whose little-endian representations are the successive
+/// chunks of `bytes`, each of `F::Params::CAPACITY / 8` bytes but the last one.
+fn absorb_bytes<F: PrimeField>(bytes: &[u8], elements: &mut Vec<F>) {
+    let chunk_size = F::Params::CAPACITY as usize / 8;
+
+    // Chunks are less than 2^CAPACITY, and hence canonical
+    elements.extend(bytes.chunks(chunk_size).map(F::from_le_bytes_mod_order));
+}
+
+impl<F: PrimeField, const N: usize> AnemoiAbsorb<F> for [u8; N] {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        absorb_bytes(self, elements);
+    }
+}
+
+impl<F: PrimeField> AnemoiAbsorb<F> for str {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        self.len().absorb_into(elements);
+        absorb_bytes(self.as_bytes(), elements);
+    }
+}
+
+impl<F: PrimeField> AnemoiAbsorb<F> for String {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        self.as_str().absorb_into(elements);
+    }
+}
+
+impl<F: PrimeField, T: AnemoiAbsorb<F>> AnemoiAbsorb<F> for [T] {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        self.len().absorb_into(elements);
+        for item in self {
+            item.absorb_into(elements);
+        }
+    }
+}
+
+impl<F: PrimeField, T: AnemoiAbsorb<F>> AnemoiAbsorb<F> for Vec<T> {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        self.as_slice().absorb_into(elements);
+    }
+}
+
+impl<F: PrimeField, T: AnemoiAbsorb<F>> AnemoiAbsorb<F> for Option<T> {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        match self {
+            None => elements.push(F::zero()),
+            Some(value) => {
+                elements.push(F::one());
+                value.absorb_into(elements);
+            }
+        }
+    }
+}
+
+impl<F: PrimeField, T: AnemoiAbsorb<F> + ?Sized> AnemoiAbsorb<F> for &T {
+    fn absorb_into(&self, elements: &mut Vec<F>) {
+        (**self).absorb_into(elements);
+    }
+}
+
+impl<F: PrimeField> AnemoiAbsorb<F> for () {
+    fn absorb_into(&self, _elements: &mut Vec<F>) {}
+}
+
+macro_rules! impl_tuple {
+    ($(($($name:ident),+)),*) => {
+        $(
+            impl<F: PrimeField, $($name: AnemoiAbsorb<F>),+> AnemoiAbsorb<F> for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn absorb_into(&self, elements: &mut Vec<F>) {
+                    let ($($name,)+) = self;
+                    $($name.absorb_into(elements);)+
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, G),
+    (A, B, C, D, E, G, H),
+    (A, B, C, D, E, G, H, I)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{One, Zero};
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    fn test_encoding() {
+        use crate::bls12_381::anemoi_4_3::{AnemoiDigest, AnemoiHash};
+        use crate::bls12_381::Felt;
+        use crate::Sponge;
+
+        let felt = |x: u64| Felt::from(x);
+
+        assert_eq!(Felt::from(7u64).encode(), vec![felt(7)]);
+        assert_eq!(AnemoiAbsorb::<Felt>::encode(&true), vec![felt(1)]);
+        assert_eq!(AnemoiAbsorb::<Felt>::encode(&false), vec![felt(0)]);
+        assert_eq!(AnemoiAbsorb::<Felt>::encode(&42u8), vec![felt(42)]);
+        assert_eq!(AnemoiAbsorb::<Felt>::encode(&-1i8), vec![felt(255)]);
+        assert_eq!(AnemoiAbsorb::<Felt>::encode(&-1i64), vec![felt(u64::MAX)]);
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&u128::MAX),
+            vec![Felt::from(u128::MAX)]
+        );
+        assert_eq!(AnemoiAbsorb::<Felt>::encode(&-1isize), vec![felt(u64::MAX)]);
+
+        // BLS12-381 base field elements hold chunks of 47 bytes
+        let bytes = [0xffu8; 50];
+        let mut chunk = [0u8; 47];
+        chunk.copy_from_slice(&bytes[..47]);
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&bytes),
+            vec![
+                Felt::from_le_bytes_mod_order(&chunk),
+                Felt::from(0xffffffu64)
+            ]
+        );
+        assert!(AnemoiAbsorb::<Felt>::encode(&[0u8; 0]).is_empty());
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode("ab"),
+            vec![felt(2), felt(0x6261)]
+        );
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&String::from("ab")),
+            AnemoiAbsorb::<Felt>::encode("ab")
+        );
+
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&vec![3u8, 4]),
+            vec![felt(2), felt(3), felt(4)]
+        );
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&Some(5u8)),
+            vec![felt(1), felt(5)]
+        );
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&None::<u8>),
+            vec![Felt::zero()]
+        );
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&(1u8, true, [2u8; 1])),
+            vec![felt(1), felt(1), felt(2)]
+        );
+        assert!(AnemoiAbsorb::<Felt>::encode(&()).is_empty());
+
+        let digest = AnemoiHash::hash_field(&[Felt::one()]);
+        assert_eq!(digest.encode(), digest.to_elements().to_vec());
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&vec![digest; 2]),
+            [
+                vec![felt(2)],
+                AnemoiDigest::digests_to_elements(&[digest; 2])
+            ]
+            .concat()
+        );
+
+        let value = (vec![Some(1u32), None], "anemoi", Felt::one());
+        let mut hasher = AnemoiHash::new();
+        hasher.absorb(&value);
+        assert_eq!(hasher.finalize(), AnemoiHash::hash_field(&value.encode()));
+    }
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    fn test_injectivity() {
+        use crate::bls12_381::Felt;
+
+        // Nested sequences with the same flattening
+        let a: Vec<Vec<u8>> = vec![vec![1, 2], vec![]];
+        let b: Vec<Vec<u8>> = vec![vec![1], vec![2]];
+        assert_ne!(
+            AnemoiAbsorb::<Felt>::encode(&a),
+            AnemoiAbsorb::<Felt>::encode(&b)
+        );
+
+        // Options with the same payload encoding
+        let a: Vec<Option<u8>> = vec![None, Some(0)];
+        let b: Vec<Option<u8>> = vec![Some(0), None];
+        assert_ne!(
+            AnemoiAbsorb::<Felt>::encode(&a),
+            AnemoiAbsorb::<Felt>::encode(&b)
+        );
+
+        // Strings split differently
+        assert_ne!(
+            AnemoiAbsorb::<Felt>::encode(&("ab", "c")),
+            AnemoiAbsorb::<Felt>::encode(&("a", "bc"))
+        );
+    }
+
+    // The Goldilocks field, of modulus 2^64 - 2^32 + 1, over which integers cannot be
+    // encoded injectively
+    mod goldilocks {
+        use ark_ff::{biginteger::BigInteger64, FftParameters, Fp64, Fp64Parameters, FpParameters};
+
+        pub(super) struct FrParameters;
+
+        pub(super) type Fr = Fp64<FrParameters>;
+
+        impl Fp64Parameters for FrParameters {}
+
+        impl FftParameters for FrParameters {
+            type BigInt = BigInteger64;
+
+            const TWO_ADICITY: u32 = 32;
+            const TWO_ADIC_ROOT_OF_UNITY: BigInteger64 = BigInteger64([15733474329512464024]);
+        }
+
+        impl FpParameters for FrParameters {
+            const MODULUS: BigInteger64 = BigInteger64([0xffffffff00000001]);
+            const MODULUS_BITS: u32 = 64;
+            const CAPACITY: u32 = 63;
+            const REPR_SHAVE_BITS: u32 = 0;
+            const R: BigInteger64 = BigInteger64([0xffffffff]);
+            const R2: BigInteger64 = BigInteger64([0xfffffffe00000001]);
+            const INV: u64 = 0xfffffffeffffffff;
+            const GENERATOR: BigInteger64 = BigInteger64([0x6fffffff9]);
+            const MODULUS_MINUS_ONE_DIV_TWO: BigInteger64 = BigInteger64([0x7fffffff80000000]);
+            const T: BigInteger64 = BigInteger64([0xffffffff]);
+            const T_MINUS_ONE_DIV_TWO: BigInteger64 = BigInteger64([0x7fffffff]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "integers are only absorbed over fields whose modulus exceeds 2^128")]
+    fn test_small_field_integer() {
+        // 2^64 - 2^32 + 1 and 0 would otherwise share the same encoding
+        AnemoiAbsorb::<goldilocks::Fr>::encode(&0xffffffff00000001u64);
+    }
+
+    #[test]
+    #[should_panic(expected = "integers are only absorbed over fields whose modulus exceeds 2^128")]
+    fn test_small_field_length() {
+        AnemoiAbsorb::<goldilocks::Fr>::encode("anemoi");
+    }
+
+    #[test]
+    fn test_small_field_bytes() {
+        // Byte arrays and booleans do not involve integers
+        assert_eq!(
+            AnemoiAbsorb::<goldilocks::Fr>::encode(&[1u8, 2]),
+            vec![goldilocks::Fr::from(0x0201u64)]
+        );
+        assert_eq!(
+            AnemoiAbsorb::<goldilocks::Fr>::encode(&true),
+            vec![goldilocks::Fr::from(1u64)]
+        );
+    }
+
+    #[cfg(all(feature = "derive", feature = "bls12_381"))]
+    #[test]
+    fn test_derive() {
+        use crate::bls12_381::anemoi_2_1::AnemoiDigest;
+        use crate::bls12_381::Felt;
+        use crate::AnemoiAbsorb;
+
+        #[derive(AnemoiAbsorb)]
+        struct Transfer {
+            from: [u8; 20],
+            to: [u8; 20],
+            amount: u128,
+            memo: Option<String>,
+        }
+
+        #[derive(AnemoiAbsorb)]
+        struct Pair<T>(T, Felt);
+
+        #[derive(AnemoiAbsorb)]
+        struct Unit;
+
+        #[derive(AnemoiAbsorb)]
+        enum Message {
+            Ping,
+            Transfer(Transfer),
+            Commit { root: AnemoiDigest, height: u64 },
+        }
+
+        #[derive(AnemoiAbsorb)]
+        enum Never {}
+
+        let felt = |x: u64| Felt::from(x);
+
+        let transfer = Transfer {
+            from: [1; 20],
+            to: [2; 20],
+            amount: 1000,
+            memo: None,
+        };
+        let expected = [
+            AnemoiAbsorb::<Felt>::encode(&[1u8; 20]),
+            AnemoiAbsorb::<Felt>::encode(&[2u8; 20]),
+            vec![felt(1000), Felt::zero()],
+        ]
+        .concat();
+        assert_eq!(AnemoiAbsorb::<Felt>::encode(&transfer), expected);
+
+        assert_eq!(Pair(true, felt(3)).encode(), vec![Felt::one(), felt(3)]);
+        assert!(AnemoiAbsorb::<Felt>::encode(&Unit).is_empty());
+
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&Message::Ping),
+            vec![Felt::zero()]
+        );
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&Message::Transfer(transfer)),
+            [vec![felt(1)], expected].concat()
+        );
+        let root = AnemoiDigest::new([felt(9)]);
+        assert_eq!(
+            AnemoiAbsorb::<Felt>::encode(&Message::Commit { root, height: 4 }),
+            vec![felt(2), felt(9), felt(4)]
+        );
+
+        fn absorb_never(value: &Never) -> Vec<Felt> {
+            value.encode()
+        }
+        let _ = absorb_never;
+    }
+}
@@ -0,0 +1,137 @@
+//! Canonical encoding of elliptic curve points as field elements.
+//!
+//! An affine point is encoded over the prime field its curve is defined over, or over which the
+//! base field of its curve is an extension. Each coordinate is decomposed into its components
+//! over that field by increasing degree, e.g. `c0` then `c1` for an element `c0 + c1 * u` of a
+//! quadratic extension, as for G2 points of pairing-friendly curves. Then:
+//!
+//! * a point `(x, y)` of a short Weierstrass curve is encoded as `x || y || 0`, and the point at
+//!   infinity, which has no affine coordinates, as `0 || 0 || 1`;
+//! * a point `(x, y)` of a twisted Edwards curve is encoded as `x || y`, as its identity
+//!   `(0, 1)` is an affine point of the curve.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use ark_ec::models::{short_weierstrass_jacobian, twisted_edwards_extended};
+use ark_ec::models::{SWModelParameters, TEModelParameters};
+use ark_ff::{PrimeField, ToConstraintField};
+
+/// Trait for elliptic curve points in affine coordinates which can be absorbed
+/// by an Anemoi hasher over the field `F`.
+pub trait AffineEncoding<F: PrimeField> {
+    /// Returns the canonical encoding of this point as a sequence of elements of `F`.
+    fn to_elements(&self) -> Vec<F>;
+}
+
+impl<F, P> AffineEncoding<F> for short_weierstrass_jacobian::GroupAffine<P>
+where
+    F: PrimeField,
+    P: SWModelParameters,
+    P::BaseField: ToConstraintField<F>,
+{
+    fn to_elements(&self) -> Vec<F> {
+        let (x, y, flag) = if self.infinity {
+            (P::BaseField::default(), P::BaseField::default(), F::one())
+        } else {
+            (self.x, self.y, F::zero())
+        };
+
+        let mut elements = x.to_field_elements().unwrap();
+        elements.extend(y.to_field_elements().unwrap());
+        elements.push(flag);
+
+        elements
+    }
+}
+
+impl<F, P> AffineEncoding<F> for twisted_edwards_extended::GroupAffine<P>
+where
+    F: PrimeField,
+    P: TEModelParameters,
+    P::BaseField: ToConstraintField<F>,
+{
+    fn to_elements(&self) -> Vec<F> {
+        let mut elements = self.x.to_field_elements().unwrap();
+        elements.extend(self.y.to_field_elements().unwrap());
+
+        elements
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sponge;
+    use ark_ec::AffineCurve;
+    use ark_ff::{One, Zero};
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    fn test_short_weierstrass_encoding() {
+        use crate::bls12_381::anemoi_4_3::AnemoiHash;
+        use crate::bls12_381::Felt;
+        use ark_bls12_381::{G1Affine, G2Affine};
+
+        let g1 = G1Affine::prime_subgroup_generator();
+        assert_eq!(g1.to_elements(), vec![g1.x, g1.y, Felt::zero()]);
+        assert_eq!(
+            AffineEncoding::<Felt>::to_elements(&G1Affine::zero()),
+            vec![Felt::zero(), Felt::zero(), Felt::one()]
+        );
+
+        let g2 = G2Affine::prime_subgroup_generator();
+        assert_eq!(
+            g2.to_elements(),
+            vec![g2.x.c0, g2.x.c1, g2.y.c0, g2.y.c1, Felt::zero()]
+        );
+        assert_eq!(
+            AffineEncoding::<Felt>::to_elements(&G2Affine::zero()),
+            vec![Felt::zero(); 4]
+                .into_iter()
+                .chain([Felt::one()])
+                .collect::<Vec<_>>()
+        );
+
+        let mut hasher = AnemoiHash::new();
+        hasher.absorb_affine(&g1);
+        hasher.absorb_affine(&G1Affine::zero());
+        hasher.absorb_affine(&g2);
+        let elements = [
+            g1.to_elements(),
+            G1Affine::zero().to_elements(),
+            g2.to_elements(),
+        ]
+        .concat();
+        assert_eq!(hasher.finalize(), AnemoiHash::hash_field(&elements));
+
+        // The point at infinity is distinguished from points with null coordinates
+        let mut hasher = AnemoiHash::new();
+        hasher.absorb_affine(&G1Affine::zero());
+        let mut other = AnemoiHash::new();
+        other.absorb_field(&[Felt::zero(), Felt::zero(), Felt::zero()]);
+        assert_ne!(hasher.finalize(), other.finalize());
+    }
+
+    #[cfg(feature = "jubjub")]
+    #[test]
+    fn test_twisted_edwards_encoding() {
+        use crate::jubjub::anemoi_2_1::AnemoiHash;
+        use crate::jubjub::curves::jubjub::EdwardsAffine;
+        use crate::jubjub::Felt;
+
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        assert_eq!(generator.to_elements(), vec![generator.x, generator.y]);
+        assert_eq!(
+            AffineEncoding::<Felt>::to_elements(&EdwardsAffine::zero()),
+            vec![Felt::zero(), Felt::one()]
+        );
+
+        let mut hasher = AnemoiHash::new();
+        hasher.absorb_affine(&generator);
+        assert_eq!(
+            hasher.finalize(),
+            AnemoiHash::hash_field(&[generator.x, generator.y])
+        );
+    }
+}
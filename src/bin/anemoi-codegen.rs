@@ -0,0 +1,66 @@
+//! Generates the constants files of the Anemoi instantiations over a given field.
+//!
+//! Usage: `anemoi-codegen [--new-chain] <field> [<field module directory>]`
+//!
+//! The field module directory defaults to `src/<field>`. The existing implementation
+//! of `exp_inv_alpha` is kept, unless `--new-chain` is set or there is none, in which
+//! case a new addition chain is searched for.
+
+use std::path::PathBuf;
+use std::process;
+
+use anemoi::codegen::write_field_files;
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let new_chain = args.iter().any(|arg| arg == "--new-chain");
+    args.retain(|arg| arg != "--new-chain");
+    let mut args = args.into_iter();
+    let field = match args.next() {
+        Some(field) => field,
+        None => {
+            eprintln!("Usage: anemoi-codegen [--new-chain] <field> [<field module directory>]");
+            process::exit(1);
+        }
+    };
+    let dir = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("src").join(&field));
+
+    let result = match field.as_str() {
+        #[cfg(feature = "bls12_377")]
+        "bls12_377" => write_field_files::<anemoi::bls12_377::Felt>(15, &dir, new_chain),
+        #[cfg(feature = "bls12_381")]
+        "bls12_381" => write_field_files::<anemoi::bls12_381::Felt>(2, &dir, new_chain),
+        #[cfg(feature = "bn_254")]
+        "bn_254" => write_field_files::<anemoi::bn_254::Felt>(3, &dir, new_chain),
+        #[cfg(feature = "bw6_761")]
+        "bw6_761" => write_field_files::<anemoi::bw6_761::Felt>(2, &dir, new_chain),
+        #[cfg(feature = "ed_on_bls12_377")]
+        "ed_on_bls12_377" => {
+            write_field_files::<anemoi::ed_on_bls12_377::Felt>(22, &dir, new_chain)
+        }
+        #[cfg(feature = "jubjub")]
+        "jubjub" => write_field_files::<anemoi::jubjub::Felt>(7, &dir, new_chain),
+        #[cfg(feature = "pallas")]
+        "pallas" => write_field_files::<anemoi::pallas::Felt>(5, &dir, new_chain),
+        #[cfg(feature = "secp256k1")]
+        "secp256k1" => write_field_files::<anemoi::secp256k1::Felt>(3, &dir, new_chain),
+        #[cfg(feature = "secq256k1")]
+        "secq256k1" => write_field_files::<anemoi::secq256k1::Felt>(7, &dir, new_chain),
+        #[cfg(feature = "stark252")]
+        "stark252" => write_field_files::<anemoi::stark252::Felt>(3, &dir, new_chain),
+        #[cfg(feature = "vesta")]
+        "vesta" => write_field_files::<anemoi::vesta::Felt>(5, &dir, new_chain),
+        _ => {
+            eprintln!("Unknown or disabled field: {}", field);
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("Could not write the constants files: {}", e);
+        process::exit(1);
+    }
+}
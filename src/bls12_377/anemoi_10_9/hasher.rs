@@ -4,19 +4,255 @@
 use alloc::vec::Vec;
 
 use super::digest::AnemoiDigest;
-use super::{apply_permutation, DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+use super::{apply_permutation, apply_permutation_batch};
 use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
 
 use super::Felt;
 use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
 
-use ark_ff::FromBytes;
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An Anemoi hash instantiation
 pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
     state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
     idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
 }
 
 impl Sponge<Felt> for AnemoiHash {
@@ -25,7 +261,7 @@ impl Sponge<Felt> for AnemoiHash {
     fn hash(bytes: &[u8]) -> Self::Digest {
         // Compute the number of field elements required to represent this
         // sequence of bytes.
-        let num_elements = if bytes.len() % 47 == 0 {
+        let num_elements = if bytes.len().is_multiple_of(47) {
             bytes.len() / 47
         } else {
             bytes.len() / 47 + 1
@@ -101,7 +337,7 @@ impl Sponge<Felt> for AnemoiHash {
         // initialize state to all zeros
         let mut state = [Felt::zero(); STATE_WIDTH];
 
-        let sigma = if elems.len() % RATE_WIDTH == 0 {
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
             Felt::one()
         } else {
             Felt::zero()
@@ -168,8 +404,8 @@ impl Jive<Felt> for AnemoiHash {
 
     fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
         assert!(elems.len() == STATE_WIDTH);
-        assert!(STATE_WIDTH % k == 0);
-        assert!(k % 2 == 0);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
 
         let mut state = elems.try_into().unwrap();
         apply_permutation(&mut state);
@@ -186,9 +422,259 @@ impl Jive<Felt> for AnemoiHash {
     }
 }
 
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U48;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U9>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
     use super::*;
     use ark_ff::to_bytes;
 
@@ -2139,4 +2625,257 @@ mod tests {
             assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
         }
     }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 423, 424, 1274] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 423];
+        let mut opad = vec![0x5cu8; 423];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
 }
@@ -0,0 +1,3026 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U8>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger384([
+                0x097bc4270c605c38,
+                0x1d26be163df00b5f,
+                0x46e369ee7acd5a17,
+                0x62250fe260e81c32,
+                0x4f8fa79a57b3959f,
+                0x009eafa48b93794e,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0xd5a9f163c68bd0fc,
+                    0x1d57324b477b5e92,
+                    0xa17ca9d6293f1b4f,
+                    0x742a5b02064bdf97,
+                    0x968fc8ae40d2ca1c,
+                    0x015bd9a4d450ef5a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb8578b7080e8f655,
+                    0x5f829122a4ecc71c,
+                    0x07d83f4eb7adaeda,
+                    0x5c391d44fde09d2d,
+                    0x252421de7bee868e,
+                    0x0009ae58f5c142e1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xc3ed9faa9c17162a,
+                    0x58664202fcae17a8,
+                    0xa0595a95a9963721,
+                    0xbd33d7915d32370e,
+                    0xc03a58f39d148859,
+                    0x006162659c2483c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x872ca9bab57fc472,
+                    0x5ea40d6b82f5c022,
+                    0x5307d77e5157f153,
+                    0x635306243a63c09a,
+                    0xb5a34227d1ebb4ca,
+                    0x008e3a0eabae52ac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40dffd903a493c3a,
+                    0xbfb97538fa461ae5,
+                    0x517da78898466d8b,
+                    0x07ba243e9120cf18,
+                    0x32df31507684dff7,
+                    0x019837763b02bc74,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x9fde84337ba6c105,
+                    0xfb45830d908f444c,
+                    0x85477e446af7f0c2,
+                    0xb3803d10da98734e,
+                    0xaf19479e6c10b30a,
+                    0x0095ceae07b3a511,
+                ])),
+                Felt::new(BigInteger384([
+                    0x403b9456c0e77aba,
+                    0x51525e5b77772430,
+                    0x3538fef899367127,
+                    0x9183f28f7a504774,
+                    0x5e653e6dbb869654,
+                    0x00b85fa55d318422,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf68eeea23a0e5002,
+                    0xd9d7226bff163757,
+                    0xf170d8b6731ccd35,
+                    0x480888ad705afcb4,
+                    0xa8f13a59aba24f09,
+                    0x01095791c08f7390,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a18fd0fe1c53213,
+                    0xfbaf13c6b2483fcf,
+                    0xffb5836f56654643,
+                    0x9558ee8952808973,
+                    0x0562ca4bdb2015bd,
+                    0x00ed0081bc7050b0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x2e48496bb3316a53,
+                    0x2a7f490369f79a68,
+                    0x1e5e17c744f7f846,
+                    0xe6f4677d949f0265,
+                    0xe365f88b9fa98d61,
+                    0x0064d70890fec3ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8d1537b7ac2daf87,
+                    0xe61d375909551402,
+                    0x071363a94d18c061,
+                    0x782169fe3dad5f77,
+                    0x2c111792c43d12c6,
+                    0x01ad7ccedcdf6151,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40b70531caf6399d,
+                    0x5bb524c151bbc7e1,
+                    0xf3e7f393d3421879,
+                    0xd9fcd2b9b42a679f,
+                    0x7ceffffc11dd685c,
+                    0x00262948bbc456fb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x176990112d5245d8,
+                    0xb51d1484b3286c62,
+                    0x98bde56c5aa8c3d7,
+                    0x87672d7ee07d87f6,
+                    0xdffacc7be0d3c802,
+                    0x0116c449193a9fa4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x292ced639aabb6e8,
+                    0x7bd22449138bc07b,
+                    0xd2a0192c48bcbc8d,
+                    0x1a81d7678493991a,
+                    0xc22c91c6f3203cbc,
+                    0x013b842ffa70cf03,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x15d5e13ea1abdf3e,
+                    0x892ed4b368f68da4,
+                    0xf858940dd76cf78b,
+                    0x0e6e64875bda519c,
+                    0x2932264df8dbad80,
+                    0x00eb1a312dddd5bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x20ddfddf179c1064,
+                    0xb83351b3147309e8,
+                    0xc2361f5dc012393a,
+                    0x7bef1178ceb991c7,
+                    0x8762fb7788e53f19,
+                    0x01834b2e6b62e870,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3bad06dbc8fe93f7,
+                    0x7010c419cbb6d9cb,
+                    0x31f3803c9e4fcfb2,
+                    0xdfc80a49a301660c,
+                    0xc02294a3f18e41c1,
+                    0x010f27e6c452af9e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xafc71598fbcd997e,
+                    0xe2d670fd5affac52,
+                    0x8c5beb86446df931,
+                    0x5f1c5b486aae3b41,
+                    0xc628b7476183805d,
+                    0x017cce6a0d623a11,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0c2cafc92b0d5757,
+                    0x245eb3174fc5bea7,
+                    0x3adb2fe80b3ba445,
+                    0x704154260a1b80be,
+                    0xcfcc650f892b9ca9,
+                    0x001797ec42686f32,
+                ])),
+                Felt::new(BigInteger384([
+                    0x270f31ed80f4b19a,
+                    0xfc6dbbb3ae344a7c,
+                    0xb4b48af38413076a,
+                    0x9f002eb02204237b,
+                    0x6a9b4bd66c94f36c,
+                    0x0102294d7d90d314,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x359c0e157704bd4a,
+                    0xb284303c2b5f1dca,
+                    0x118e44b9ecab920b,
+                    0x8ef8a3e10f726295,
+                    0x8cc3c73063752bc8,
+                    0x016ad2154bcefed9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ae6f4f134ccccd5,
+                    0x989af5b9b726ca0e,
+                    0x41db6325540c7174,
+                    0x65a4fb6e3212a887,
+                    0x1ff7e3202eff6ae1,
+                    0x012cabaee720c0bc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x51c3e7b08b61ebb4,
+                    0xf432d260c926fb3e,
+                    0x50e2d2156dff8ecd,
+                    0xe63a9dd9015cd61b,
+                    0xa84f774722accb16,
+                    0x00cdb259daf41ce8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x602b617b0d6295da,
+                    0xe97b04d3125cd976,
+                    0xfa269ee80a454bf1,
+                    0xb6859ad09a0ad32b,
+                    0x5304972c44df4b4b,
+                    0x01512ffcf9780045,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x324c6c75f8d45849,
+                    0x7cfd6e3ae522c7ee,
+                    0xc123c618a225a745,
+                    0x6635db00f2b07098,
+                    0xa22d56bcf06c5f81,
+                    0x006bebd4e0ac96bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc90831ace7dc037b,
+                    0x7a73d22fcca28c9c,
+                    0x1db33378f1068334,
+                    0x2d84740786bddb36,
+                    0x9515e92791944eb7,
+                    0x009b3fc4e8622622,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1a44c9b52922fc88,
+                    0x068cfebdbe88f22d,
+                    0x3f19e8c3b33f0986,
+                    0x245aa585ae78228f,
+                    0x81f3c0baacb502a0,
+                    0x0139abafdcac1d02,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb8848803133234c7,
+                    0xef568716d1caf2b6,
+                    0x524361d6ca31cf0c,
+                    0xf222abc4d5140f0f,
+                    0x4b5a53c8dc939a98,
+                    0x00b8af02daa6b018,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x69410cf3f95f6a42,
+                    0xefaae218d28f8344,
+                    0x7fadc854840b5656,
+                    0xa66a175bd8a6af9a,
+                    0x70a726ee3997d61a,
+                    0x00a68f876faf7010,
+                ])),
+                Felt::new(BigInteger384([
+                    0x47383cf797493960,
+                    0x56bd0defa70fc7fd,
+                    0xc34c62e24e4cbe58,
+                    0x1e263bb00410c177,
+                    0x8880fe57838cce63,
+                    0x0079c91a23d3ef8b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x90c8f604b1543a41,
+                    0xf48ceeaca4b7e79f,
+                    0x2e1f913b45e76c81,
+                    0xa365ec5d3d7b0957,
+                    0xbd2e03806ed77ed4,
+                    0x00da5b458f071739,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf63f96bd2b162b2b,
+                    0xb9e950cb8ed479fe,
+                    0xa892a3ebdeda1152,
+                    0xee1729553885158f,
+                    0xd19b9773bf9c97b1,
+                    0x003c86af0a2a1c91,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd117cbfc4c438ee1,
+                    0x01fc572bf41a054d,
+                    0x701605920dd38b9b,
+                    0x5dc300bd91801d78,
+                    0x9de9792769d66fd5,
+                    0x00d2f9b31a515780,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbaf9be76b22a1b76,
+                    0x2983486efe19db07,
+                    0xb485c090cb23e542,
+                    0x0a15269ca7411e3e,
+                    0xf9dcd3a730987045,
+                    0x0051ad8e843810c6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x52cc5ebcd37452ab,
+                    0x1ed579434ff5c8e1,
+                    0x90cbdf6e3ce0a662,
+                    0xf557561e53482e1a,
+                    0x66763778c32225d0,
+                    0x017681b8cc3fd257,
+                ])),
+                Felt::new(BigInteger384([
+                    0x172ebd6b87f1befc,
+                    0x2a0ad8cb7959271a,
+                    0xaa6fda554484fbb9,
+                    0x937cd3a6964b772d,
+                    0xf2b4e173da72c47a,
+                    0x002390db4f411122,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xdb598ac79399b2b4,
+                    0xf939bd4bcae56557,
+                    0x00e7fa1a9fe42800,
+                    0xab6ec78fa27938a8,
+                    0x6d20735fe947e03b,
+                    0x01164ac82319a243,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2c0767d9f9235cf8,
+                    0xc1b35642ee8de726,
+                    0xb62b384c29684ecf,
+                    0x92d6dd1e44a864db,
+                    0xe897abef8d2009a4,
+                    0x010f10b51b184f3b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc5fccfeaf968c162,
+                    0x51ec99059b31baa7,
+                    0x9d3cd2badb96f6c8,
+                    0x188b20fdb012700b,
+                    0xd0fabafe9c74927d,
+                    0x00754233de696187,
+                ])),
+                Felt::new(BigInteger384([
+                    0x394f6fa682b63195,
+                    0xe4d74d07b1cba9b3,
+                    0xa3f3cacdb3df1ff1,
+                    0x1134fc8afc253dba,
+                    0x9f2c192527bfbddc,
+                    0x015d9d9459e41c50,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x359c0e157704bd4a,
+                    0xb284303c2b5f1dca,
+                    0x118e44b9ecab920b,
+                    0x8ef8a3e10f726295,
+                    0x8cc3c73063752bc8,
+                    0x016ad2154bcefed9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ae6f4f134ccccd5,
+                    0x989af5b9b726ca0e,
+                    0x41db6325540c7174,
+                    0x65a4fb6e3212a887,
+                    0x1ff7e3202eff6ae1,
+                    0x012cabaee720c0bc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x51c3e7b08b61ebb4,
+                    0xf432d260c926fb3e,
+                    0x50e2d2156dff8ecd,
+                    0xe63a9dd9015cd61b,
+                    0xa84f774722accb16,
+                    0x00cdb259daf41ce8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x602b617b0d6295da,
+                    0xe97b04d3125cd976,
+                    0xfa269ee80a454bf1,
+                    0xb6859ad09a0ad32b,
+                    0x5304972c44df4b4b,
+                    0x01512ffcf9780045,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x324c6c75f8d45849,
+                    0x7cfd6e3ae522c7ee,
+                    0xc123c618a225a745,
+                    0x6635db00f2b07098,
+                    0xa22d56bcf06c5f81,
+                    0x006bebd4e0ac96bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc90831ace7dc037b,
+                    0x7a73d22fcca28c9c,
+                    0x1db33378f1068334,
+                    0x2d84740786bddb36,
+                    0x9515e92791944eb7,
+                    0x009b3fc4e8622622,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1a44c9b52922fc88,
+                    0x068cfebdbe88f22d,
+                    0x3f19e8c3b33f0986,
+                    0x245aa585ae78228f,
+                    0x81f3c0baacb502a0,
+                    0x0139abafdcac1d02,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb8848803133234c7,
+                    0xef568716d1caf2b6,
+                    0x524361d6ca31cf0c,
+                    0xf222abc4d5140f0f,
+                    0x4b5a53c8dc939a98,
+                    0x00b8af02daa6b018,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 470];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+            bytes[188..235].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..47]);
+            bytes[235..282].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..47]);
+            bytes[282..329].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..47]);
+            bytes[329..376].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..47]);
+            bytes[376..423].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..47]);
+            bytes[423..470].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x6818b2b5a2b967ea,
+                    0x02ac954fd6690086,
+                    0xf296c3589f8c327b,
+                    0x579bf09890e6ae16,
+                    0x5191e47c7d2e523d,
+                    0x019d1f5ae949095f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6560b1653cfab443,
+                    0x9639c930c6179477,
+                    0xe0b88d4a6e401392,
+                    0x3f57e4ed59086340,
+                    0xbf744ed11baf561f,
+                    0x01abb526b57eddef,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee6a9242a954652a,
+                    0x79a2481859d5949c,
+                    0x76183626e510212e,
+                    0x2e1167eff7b7df42,
+                    0x9b66216c8d87b1c7,
+                    0x002b7c39f3f7c630,
+                ])),
+                Felt::new(BigInteger384([
+                    0x884039bf99f17e17,
+                    0x27d52c2849621d57,
+                    0xc2a025fc6094f580,
+                    0xfac4ad0d52599b2b,
+                    0xb790df519f8b5b1d,
+                    0x0115ee8a38719ac3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfa17c330c7d19d6c,
+                    0x2e8b73fe913d8e8b,
+                    0x218cf5079d1f7093,
+                    0x4f2c1229f1700a2c,
+                    0x365d2e9351b36484,
+                    0x0129e7b9a0e76c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf0c86db369c0e34d,
+                    0xae7bf8192a92c08e,
+                    0xb38d1a4f9e468e1b,
+                    0x7cf97c7d5147f5f1,
+                    0xd3eb0b3b7fd34b54,
+                    0x005d5cbfedef6caa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd75d80b331bb13f5,
+                    0xbfd6a5d5c2a58948,
+                    0x6b25043a41b90944,
+                    0x8250806d08f64672,
+                    0x2b21eec733a41852,
+                    0x008b406ad1ee7d21,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d56b8a5c5727ed4,
+                    0xb58452c3f0c9ecc9,
+                    0xa28cc98a2b7efee4,
+                    0xcae7d8f1c9a7782e,
+                    0x9e9f2e5922f5a191,
+                    0x00952955b5809106,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3932d8f651af3ebf,
+                    0x8ec3f5aa43fb1311,
+                    0x79823ea36349d73f,
+                    0xc4db7a2c0140ff0e,
+                    0x0231343f2e1dfd5f,
+                    0x010a56e9a245f069,
+                ])),
+                Felt::new(BigInteger384([
+                    0x426438d7a69fce4a,
+                    0x6a2988c4afceb1c6,
+                    0x969fb97b330c0c9e,
+                    0xb6ecc5e6e2f5b0d3,
+                    0xc598b504f895b19a,
+                    0x012ded1aadc0af42,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x1c26c3539b8b30b0,
+                    0xb566993f1490bc52,
+                    0x481caf6aa2a39f11,
+                    0xa1c4afecf0760048,
+                    0xfcab49ffb49c26c9,
+                    0x00876700b49d6849,
+                ])),
+                Felt::new(BigInteger384([
+                    0x10edcaa1728546eb,
+                    0x51225f34d0fc4034,
+                    0x23d5d2f2bfd061fd,
+                    0xea678f86fd47ff52,
+                    0xdda44ce9c6e73c38,
+                    0x0048856039d51717,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6004230dd22445f6,
+                    0x2b89b87192bbb8c1,
+                    0x4ada6c66562d42ab,
+                    0xe32cfc863bbc3f54,
+                    0xf6c42d17ebed4b9d,
+                    0x0036f17e5f5a091e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x29e62be7bcd1826a,
+                    0xa7dd5f18c342857c,
+                    0x90693c4d6795509a,
+                    0xb4dd3af6e8ed580c,
+                    0x6121edb2f970dd03,
+                    0x017baeee20effcb1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdac237ad66119ea9,
+                    0x8702be2154d8efe3,
+                    0x5cb739795c2c03ff,
+                    0x3287a9b5a02dd615,
+                    0x078f2efe8445749c,
+                    0x008c720b133a349e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e98b09b67031cce,
+                    0x4c959734749a55cd,
+                    0x54dcd2b8c5390846,
+                    0x903949025a9528b4,
+                    0xd0413edd50b2f7b6,
+                    0x0072d3b22de5fed5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40eb081ac1bd9515,
+                    0x3d6fb160265d61dd,
+                    0x4739f35a015fc307,
+                    0x8d59077d5a6eb778,
+                    0x4d53111f89737da6,
+                    0x00e87c72eaad4533,
+                ])),
+                Felt::new(BigInteger384([
+                    0x683cbeff790ba768,
+                    0x71f8c26996c8424a,
+                    0x40ab86710b0a5644,
+                    0x96dbcf15b992cc63,
+                    0xe3f3adf9c715abb8,
+                    0x0149ad060e75d1fb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaeeb50083ae83c3b,
+                    0x6136f6a1a6d9626b,
+                    0x70758f7aab64645b,
+                    0x717332eeb1632386,
+                    0x4a972e62e16406a1,
+                    0x002e94f9d4b291d3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9aa43727ea4f60c0,
+                    0xb4d68716396c9a4a,
+                    0x5d62a247fb3f4157,
+                    0x3e171975b4097e11,
+                    0x72340dafa00cdb5d,
+                    0x0148d628ee800446,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xb92dab80c956460f,
+                    0x81c18b9a94267ffe,
+                    0x425abf41e1a4ae7a,
+                    0x207f14a6403412ac,
+                    0x762e70e351a83e7e,
+                    0x018f10ca60d07439,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ee33f611cd32da2,
+                    0x4ddd802a40fcd52a,
+                    0x469c1b935a34f82b,
+                    0x9d273fb574ab014a,
+                    0x107d8ac157802273,
+                    0x018dfc06927f061f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xce84dc0ef4514322,
+                    0xc276e72c523de514,
+                    0xe8576e0d6847abce,
+                    0x5e1f5b996263954f,
+                    0xa497c5b094ab3f28,
+                    0x00f854d141dc9486,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93a8bfca06251cd6,
+                    0x787df985694df30e,
+                    0x56b40e4cbe21c0a1,
+                    0xe20875eaeed58e52,
+                    0xd3312f1f3991f1f8,
+                    0x016893aedbec043a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x99691698c84d23cc,
+                    0x8d37cc4401681a2c,
+                    0x51a4f5a42d6fbc53,
+                    0x71f920ae123b946a,
+                    0x83d7d5098daa3185,
+                    0x00312d25d0195840,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa12653671187adfe,
+                    0x137eeabd859d9e61,
+                    0x08f7507f711c0e2c,
+                    0xcc9b80ae567c5c88,
+                    0x843277dfe62f0fe1,
+                    0x004d679f69fb6056,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c3b6924274f7dc9,
+                    0x5df0f6b649b58fd4,
+                    0x3145e2567197029d,
+                    0xfddd250a8fe180a3,
+                    0xade7edbe1452750d,
+                    0x0129cb2b0416d06d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeb67705a7cafd14e,
+                    0x606fa31df50c25ee,
+                    0xc4b426e51bfba013,
+                    0x4f5de927225d660a,
+                    0x11758e6cf3bbfd00,
+                    0x000148043eb03109,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98fcb9b089eb680c,
+                    0x5001c9858f3186e9,
+                    0x2602ea1df33af5d4,
+                    0xea7d05d2a2433917,
+                    0xeef9719941d8f02b,
+                    0x00bf75f3c99b0f1c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9aebe7868337af97,
+                    0x201794021bba09c8,
+                    0x8e7bb5e3b0847da6,
+                    0xf1402ee5fea9c34a,
+                    0xdf8d9fe676a77a8d,
+                    0x015447fe0b4af6f9,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x5328667862d3f0f6,
+                    0x6bca57f9a3791a3a,
+                    0x76187722b5a52136,
+                    0x1d729bb300a8df3b,
+                    0x7c80a7d8612f8b2d,
+                    0x006463842d1d555d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7b0e86d93b52a0d7,
+                    0x82ae87a066cacdec,
+                    0x9a4cd440a592ff6b,
+                    0x16375204b4042856,
+                    0xee994d362ff5bb50,
+                    0x015d04cb55fc373b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf051cf9b0ece8507,
+                    0x5496dda7e2e26746,
+                    0x56324848bcdff1a7,
+                    0x1a5c9127253bc8a2,
+                    0xfccb81d2026202bb,
+                    0x00814b64e811e036,
+                ])),
+                Felt::new(BigInteger384([
+                    0x973e0b1aa970fcbd,
+                    0xbfcb3644f601875b,
+                    0x492ba824a7dc7f4a,
+                    0x77bbc33a09a48b47,
+                    0x3d26de9ad41d2671,
+                    0x00b0c1e6f2cd896e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c037d878f48b0fc,
+                    0x8ea8877e8ff22110,
+                    0x28bcc153a6608ed1,
+                    0x5f3e827a38326722,
+                    0x869bd97d19a63c76,
+                    0x0089328df3aef5e9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd444e7f61d02e1f2,
+                    0x93e5c02a7c35943d,
+                    0x9e8bcffb7fca37e2,
+                    0x2ace3400d18dc850,
+                    0x482ac3589e169516,
+                    0x019bb6e8cb4c0da9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd0508f9c6255f84,
+                    0x2ccb1222d85641c2,
+                    0xba8ed51e180cfcf7,
+                    0x68532227f3dd16e1,
+                    0xd1ab8d77a2ba8490,
+                    0x014cd6abdfca45d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe6b662b73efbe566,
+                    0xbba8670ab43cb5c1,
+                    0x5bd8fdd47352a21d,
+                    0x49c2df41c4984f69,
+                    0x51508775339e37ca,
+                    0x00179883ad16b45b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdf3aaa78010af759,
+                    0xc4179c5448050926,
+                    0x3d7373a95a989f03,
+                    0xf45a7393d5e51846,
+                    0x4d527b667f9bc9e8,
+                    0x00f701533f8f38ca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x24d98ff60fe181fd,
+                    0x51c3e45df161a05b,
+                    0x29cc246169ae0e60,
+                    0x85579e9e1d53cce6,
+                    0xabc2a588922ae75a,
+                    0x0124b1998ddfc2e6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x32ecd790daa7529b,
+                    0x01df3211d3b62a85,
+                    0xadc62a61a224411b,
+                    0xb0a149fac0174d6e,
+                    0x80cc1e9342d6d881,
+                    0x0004a04d235af474,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbbc54ffc78e954e3,
+                    0x733c6f87cd8defd1,
+                    0x0c0f91a6fb5367e6,
+                    0x8aaa0804a6dee007,
+                    0x099450fedeb9a666,
+                    0x01091ddb99e9900d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6cb35179d581a243,
+                    0x41e8db0f1118049a,
+                    0x81de2b58f7459b42,
+                    0x51897a4996e36855,
+                    0x566c3d53bd651331,
+                    0x013f43708b4eb568,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4281dab46733c37d,
+                    0x3676ebdcb7a2d1db,
+                    0x9a7d5d060d9d0192,
+                    0xf8927c493b26106d,
+                    0xdc630087cdd43ae6,
+                    0x00c1bc5a3584bb88,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9293e41a26c09c08,
+                    0x5aabe50f9058bd5a,
+                    0xa443ba4080da881d,
+                    0xfaa054d9da7f6d7e,
+                    0xe1f3d13642f4dac4,
+                    0x015ad50197a7a69f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1bd26c67acfcea6f,
+                    0x6325094efdfd4355,
+                    0x6295724ec8db144b,
+                    0x7cf25e7e3fcf506a,
+                    0x3dc282df3a461f78,
+                    0x017a9282dc23f142,
+                ])),
+                Felt::new(BigInteger384([
+                    0x00e4fc7ae81c53d5,
+                    0x9543541adcbc685c,
+                    0x956880c76f8bb094,
+                    0xadabfd975112f545,
+                    0x3f3a874f0f78763f,
+                    0x013c850b34865dad,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb4f1be96f92cb93a,
+                    0x10d2c7cd8b4d2d90,
+                    0x9c417199f9005eca,
+                    0x4960e799e4389d8b,
+                    0xe2e8baf1d08c7cbf,
+                    0x00b4b4cf196993ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe4a6b93f48356f51,
+                    0xb91e6a2ed019f151,
+                    0xf58bce3a7801cf8e,
+                    0x55c4bab4c868e7e4,
+                    0x26a2fa9893d8a980,
+                    0x0170c6ae4784d587,
+                ])),
+                Felt::new(BigInteger384([
+                    0xda65b691269d2310,
+                    0xfdd99f28bdf1b03e,
+                    0x0428d34100e4b68c,
+                    0xd1fd47a6d05d033a,
+                    0x10026bf3db4c37f5,
+                    0x016e092d501a6668,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x0c78ccde86ef94cc,
+                    0xc6fc74bf3c691350,
+                    0x8e410aac3352695b,
+                    0xff732f9d038e5699,
+                    0xb3674c233f2c47fe,
+                    0x018ae807ade35e9e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x082518590a0ed21a,
+                    0x54264f6c0811f61d,
+                    0x59117f4eaaff01f5,
+                    0xc8e276f36ac6dfc2,
+                    0x656ab4f124eceb30,
+                    0x01749c3d85a99695,
+                ])),
+                Felt::new(BigInteger384([
+                    0xec4591cc98e12b75,
+                    0x9955fd691b91cfd6,
+                    0xdcc55c9290b3ecef,
+                    0x94fb748914b034d3,
+                    0x85e8df1073836133,
+                    0x01ac700126bba6f8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8dc0f3e7a1fa12b2,
+                    0xc3a7f270303676a0,
+                    0x84459668b2fc2851,
+                    0x079a67b6d148d69d,
+                    0xdec34de2d569af73,
+                    0x005dbbdad5226915,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2ae130e42a25c03,
+                    0x05d6de68bb3adb53,
+                    0x9df07e925353e5ef,
+                    0x93938ac55b69a7b2,
+                    0x130404c16ff109da,
+                    0x0084d987ad9626f2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c55460022105213,
+                    0x04f330b50c333a32,
+                    0xde31469b670d560d,
+                    0x490edc5234fddc43,
+                    0x58b1b7fbe709cc7a,
+                    0x014164df41c7d032,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ab1aaa045b5ab19,
+                    0xb817b755bb65d1ef,
+                    0xdffe79a4af31f86e,
+                    0xd6a4a162025c6112,
+                    0x1f1e22398113a229,
+                    0x00a23bc68df68ebb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe2204ffed6da251a,
+                    0xc53fc23284a4a959,
+                    0x701011e831a9e535,
+                    0x73b4b06f9a7d4114,
+                    0xf0b052b52dd31ebe,
+                    0x01331a45db0ab2cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e49e03b00859126,
+                    0xc0acfc975dd98d83,
+                    0x6c79e175f8944819,
+                    0x96dbb55af083b8d8,
+                    0xd35f4a59bea4706a,
+                    0x009792d13eb38a79,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0400ab8fdae58bda,
+                    0x37bfd8d4fb08eaea,
+                    0x967263d18871f522,
+                    0x1150fe177ef4e9cf,
+                    0x85ee27389ee837d0,
+                    0x0125e4e8dccbaa25,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x22990d1142f49dab,
+                    0xa1c9fdb4e16b045a,
+                    0x00c64a6ec565d36e,
+                    0x8bfb3d322f239530,
+                    0x4e6e6646177b787e,
+                    0x01a30ff54164b56c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f8ec4a4f3f98eae,
+                    0xb4098d629348ecd3,
+                    0x950f4e38b772cc12,
+                    0x239b97283f27974c,
+                    0x8973fea045f7c4e8,
+                    0x007c240900b83212,
+                ])),
+                Felt::new(BigInteger384([
+                    0x454071c03be50fba,
+                    0xd53c731f39f42775,
+                    0x8a856198e4faff11,
+                    0xf3f900aa8b5e7e28,
+                    0xd384494c8d070f4e,
+                    0x005fcf6af1055426,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd73764b31e835df,
+                    0xfab8f5fe7412cfc0,
+                    0xa78c6d9ebea9534c,
+                    0x25139c1084cc928a,
+                    0x9a4fe0b8a7e7c2c3,
+                    0x00a58349613577e9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc20d3509450a4daf,
+                    0x9ac8db856554d62c,
+                    0xda0868d19a47de45,
+                    0xd495f7525d0d17e7,
+                    0x106abaa161619f6a,
+                    0x00042caf6a5fa77d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x999d19176884e554,
+                    0x4dd8815274b000d2,
+                    0x6eb36c60c077fbeb,
+                    0x23e3fb1ced6bb025,
+                    0x49f56011f0c91464,
+                    0x011d66bb0dff5cdf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2376c0db8967b834,
+                    0xe6384bbcf21766b3,
+                    0x999a67c528c96a58,
+                    0xbbb29b948f4eff3f,
+                    0xd32e13c14a5aa3db,
+                    0x005f5f3a93198f04,
+                ])),
+                Felt::new(BigInteger384([
+                    0x794d0ae0bbe228d0,
+                    0x0d2d0cc4e4db39b2,
+                    0xe29d17c3765099cf,
+                    0xf66f73eef31d522d,
+                    0x9156dad6b4d9c870,
+                    0x000477f408fd2294,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2d563ae02d3cc31,
+                    0xdbbb22b043cc86ad,
+                    0xe8a07e36810d087d,
+                    0xbfd267fc7ec2d2d4,
+                    0xe5e6fb65b4c6dde1,
+                    0x0106591d7383cab6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x121d6ab319a38fd1,
+                    0x6ed9e794fb6e5987,
+                    0x9e53cfbd617c76e9,
+                    0xdb369dfa516e56c8,
+                    0x9fe55119f669e496,
+                    0x00d292954c3a0a87,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5c6c776e8773e2cc,
+                    0xd33a4b5d748d3d1c,
+                    0xb91a1c88a8762d1a,
+                    0x40f356037e2f3953,
+                    0x491443561bd662eb,
+                    0x015a15c0efa10b3f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc2eff4637572db4a,
+                    0xe15501aac3759d3a,
+                    0x3735e81e3e5b675a,
+                    0xf89278c456c2206b,
+                    0x5328c7ad93c50d43,
+                    0x00d51d0fd0b863d3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdff52bdb313315e7,
+                    0xb477a441b482d0f7,
+                    0x0bd595461a03ba8c,
+                    0x850c7088e9cb43d6,
+                    0xa149b8306996d96d,
+                    0x0088b3559d63065c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa5e88b125ac7063c,
+                    0xc228a3a2bd48975b,
+                    0x0b6bcd3e13499fba,
+                    0x30fe75b264619145,
+                    0x2c3198190d1691b3,
+                    0x00336f0aaf42e082,
+                ])),
+                Felt::new(BigInteger384([
+                    0x26a83ce07015f9da,
+                    0x4ef1fda589bbc201,
+                    0xee586d94a4b7d4ad,
+                    0xf95afdb3ee908fde,
+                    0xb1ffe0b2057e605f,
+                    0x0101d7a49c38f17d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x10c09c1a28d91c70,
+                    0xa5ee9017deee395e,
+                    0x08cb092d584610e9,
+                    0x10b585cf6e467da4,
+                    0x7ca6dda6f3b4460b,
+                    0x019128530504c4c9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2e7abf36b97a0506,
+                    0xe08ca2b4ac7e55ea,
+                    0x1ef77bd38ea60356,
+                    0x8de048e00c54b782,
+                    0x3bc48570a4c2e14d,
+                    0x00a47c516df73aee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3e3557d46b3714bc,
+                    0x3cd2412dc60b5bae,
+                    0x446cb463c7d4b8dc,
+                    0x78b0f05a7e8f074d,
+                    0xf8164b1008c7616c,
+                    0x013f497a0d9ba1b3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c594f3c17706af1,
+                    0x2e048c7c6b7901f4,
+                    0xa4e5503422ab0dda,
+                    0x91035d95208340ea,
+                    0xba4557ae49ea8f56,
+                    0x019e2d32f745b880,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5892b1342d7308f0,
+                    0x4f9cbafdc8a287a4,
+                    0x27e706b27490b925,
+                    0x4cbb4ccb1c279704,
+                    0xf6d2442c0d828f0d,
+                    0x00cf791487b82c20,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfbe779419446bfa1,
+                    0x16ceea2dfeab42e1,
+                    0x2e31a29832958611,
+                    0x54101d9d046d25a0,
+                    0x2393294dd988ce45,
+                    0x01a9fa7d530fecc3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd81e764e74f5fc91,
+                    0x61d41fe98f958842,
+                    0x098eae237ce4cab7,
+                    0x3f563fa3855cea36,
+                    0x74589d88a964925d,
+                    0x01384f8a36930357,
+                ])),
+                Felt::new(BigInteger384([
+                    0x37c08249dcb462ba,
+                    0xad0b5c4314ecc9cf,
+                    0xcf73990f26008754,
+                    0x288fd2f03efb44a2,
+                    0x1126e05ec8f5885a,
+                    0x00f0256cafe2fc21,
+                ])),
+                Felt::new(BigInteger384([
+                    0x632f8a1bb0c513da,
+                    0x8a817888fddfc0da,
+                    0x356043d6563024e2,
+                    0xa01585bb351aecf8,
+                    0x03222e58becf4771,
+                    0x01ad7a29e3719d17,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c1459065970817a,
+                    0x2555857e1efa4922,
+                    0x109ed2413c77b243,
+                    0x9f4c3a657c501435,
+                    0xd68ed45c7e6b97f9,
+                    0x0074332f8792605e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x955c5685b958a0ce,
+                    0x9b9af09ecc8948c4,
+                    0x52325cddf8141c6d,
+                    0xa125322bdf2cd9c2,
+                    0x6700435f377b908a,
+                    0x00b3b9f6bee77b94,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ed4a925d723f890,
+                    0x3c80086f19b8c888,
+                    0xe80394bb700f760d,
+                    0xafb177d837f9e3f5,
+                    0x7bd205960513881e,
+                    0x00f97f60499dc297,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa399186507a260d3,
+                    0x17704c7eed2f9258,
+                    0xf8fe8a3fc62b0a62,
+                    0x3011db33c94473ef,
+                    0x8fb5d495473cafdf,
+                    0x0049d35ef9cdfb4b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a74033debfd1ba9,
+                    0xc982a46d00aad18c,
+                    0x33580d3e9a6b7bec,
+                    0x68ca7347bc5e5128,
+                    0xb90a26a1ec0a1499,
+                    0x00fea0ba024becde,
+                ])),
+                Felt::new(BigInteger384([
+                    0x86bb36e76b844843,
+                    0x5b55d0b34126a893,
+                    0xa9f06bdfbf55f7f5,
+                    0xa1c8b946fb6b401f,
+                    0x36386c5844c22054,
+                    0x00bfe2522b4d6ce8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xbe8217c8da4191a8,
+                    0x20e4f56c574ce381,
+                    0x010794242e001315,
+                    0x792a7d4782d1cdb6,
+                    0x2767d1ac580042e2,
+                    0x0015fe2010bd0adc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd24c8ccb3be4585d,
+                    0x7874cfbce2a2aafa,
+                    0x993fec959b328919,
+                    0xbe7ab8dfa0757511,
+                    0x45b66b323a542ee2,
+                    0x003b46e8ad5025f8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2507b27b00b37541,
+                    0x6b6e30be9b3437b5,
+                    0x2c495c0c0fbb7896,
+                    0xd557c0e19da45185,
+                    0x4d15bb0450dcc5c2,
+                    0x006758b1a7dbecd6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x259ce7fc7fd4afb6,
+                    0xe2de5ffffb363e72,
+                    0x1f66292adf94b3a4,
+                    0x2e9a0e8f5fe74f65,
+                    0x1905399fe722a8e8,
+                    0x008bd6ecc2ea98b9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x435e34179c6e85df,
+                    0xc11ab7caceb14cf6,
+                    0x728abd7cfc794800,
+                    0xd6584ba2807e18f6,
+                    0x085c55b8088e146c,
+                    0x0109bc8f73c81a19,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2e096ab2a774995c,
+                    0x401a88ec020e46ce,
+                    0x68739d91e1252e01,
+                    0x986cc8c3a833e160,
+                    0x1316a99b4d7872a0,
+                    0x00cdaee8196ffb67,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a7a2a6c25959f10,
+                    0x7b40ad04350b927d,
+                    0x6ec3288c0dfebbeb,
+                    0x82d3c4c2007f8b12,
+                    0xb4b0d30678e94608,
+                    0x002abd41027ca336,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf3b8e215f89fed36,
+                    0x9f80563670c55f24,
+                    0xdc3dfce048d3bc65,
+                    0x5bff436876c17994,
+                    0x305f8ab0ebc67831,
+                    0x0079d418b83547c9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf5aa275759b37b94,
+                    0x8e9dcd94e27775f2,
+                    0xe983d85d9774c536,
+                    0x43f6a3c7abf3b6b4,
+                    0x2206e7039e9eb869,
+                    0x0040769644562ee6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe6acc462f1e24de6,
+                    0x2381b71c2b11d7a1,
+                    0x1a4d8afd9880992c,
+                    0xdf7b7e24eec59bba,
+                    0x4b6525a3b54d5c42,
+                    0x010b9ebea514bd2d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x83494d0193978bb0,
+                    0x7adc31d89468d6eb,
+                    0xdec056f94edbd8e1,
+                    0x75bda5a0066db70c,
+                    0x70885288614090cc,
+                    0x007f8b954e6321b8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc4e641ca2f94c6fb,
+                    0x94ab1266805124dc,
+                    0x6063ca82e590a0f6,
+                    0x14a3c19d94d483e9,
+                    0x1d1fb15291443ced,
+                    0x00950f0da2615c8a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x11403a18f0429b97,
+                    0xf74017048b76bb11,
+                    0x7e5c480c5694432b,
+                    0xc73b05509629f5ce,
+                    0x03cd3610f9073b31,
+                    0x006f00fc8a75d1ca,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfeec7868dfd22d15,
+                    0xd408494d0ebfa413,
+                    0x9e3b06fde332d4ed,
+                    0x2db8aed1ac9e5417,
+                    0xd300d347fd6379f8,
+                    0x005c6ea3f77830bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc860a89d2699632d,
+                    0x09ec999d6a056465,
+                    0xfd14dee98931a0b0,
+                    0x2518c396a5a4354e,
+                    0x6cc6bea70bebdea3,
+                    0x0054956480827fe2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9a489cc0e153075a,
+                    0x69155da59b72d508,
+                    0x5e8fbed7e0e6998e,
+                    0x6e5de2343f70ab51,
+                    0x1bd7699fe4d561d6,
+                    0x00d537a6cfd96d15,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe7d81dea1c68db20,
+                    0xeea45f24cf0491f7,
+                    0x313c3442cd7778ed,
+                    0x5edd7318c0696e6f,
+                    0x8bebd9fdf1eb1769,
+                    0x00a0f1c4c8b84e7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x298a12112d5e8ea8,
+                    0x8e9b20b6c4aa620f,
+                    0x20a44bde3cfba72d,
+                    0xd2aa3e99975ef47c,
+                    0x8fedf58eba923c3a,
+                    0x01445767eaa7f74e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc7c6259b02b0408,
+                    0xa4e82b16fac1d8fd,
+                    0x1efb4ca14839aa11,
+                    0xda37478576f040bd,
+                    0x0692780bf0ba69d5,
+                    0x0062087ca93b942a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x056682076b706ee0,
+                    0x1dfa92989f713bd3,
+                    0xa6e004679753b0ed,
+                    0x6ea77ea7179b5372,
+                    0xdb0e243cbd26ced8,
+                    0x00dbf3de195250e6,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xa24f348c455ca2a4,
+                    0x43e29b146805aee7,
+                    0xe3cb33da7dd8ca69,
+                    0xed316d7aa1221a96,
+                    0xb6f234edbffb847b,
+                    0x00ce94ca721b876e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b9bd2da3d51d779,
+                    0xdf1fa97f87a5da88,
+                    0x1476e3b7709a6ff1,
+                    0x0ebecc1d98be50fc,
+                    0xa9a6ae97f003827d,
+                    0x0084ece506c6a925,
+                ])),
+                Felt::new(BigInteger384([
+                    0x15d4949d1f1fc4f3,
+                    0xe916425eb7c367bb,
+                    0xc07c807ab3bf4278,
+                    0xe555524772656613,
+                    0x0ce5d6e6853293ce,
+                    0x011e8fea4f788142,
+                ])),
+                Felt::new(BigInteger384([
+                    0x48da8930a21c83a3,
+                    0x8c7784d4b38c68cb,
+                    0xe272df5539258253,
+                    0x6e662215321c1866,
+                    0x69aa8edcce843a2d,
+                    0x009fe963a7faf373,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe114bb3b81bb838f,
+                    0x79c30d214d522d78,
+                    0xbdfb5f649fbabb4c,
+                    0xbbc394f6bf9bfff0,
+                    0xea8736f09d36e6a9,
+                    0x00e1670bfb850b64,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb273a4b5105ce8a0,
+                    0xef403d7f139e424b,
+                    0x4c9ba58ac1d4e304,
+                    0xabe3e288e730d916,
+                    0x85d0d12ec04af77a,
+                    0x00589383233f8685,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf1f8edf20e673410,
+                    0xac07077a0dfc7801,
+                    0xa8b3de83ed8bf639,
+                    0xf131e900f21088c1,
+                    0xb2cd909b0d8abd1b,
+                    0x0064cf7eab0fc350,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8de9e7dc09f57541,
+                    0x7169d6e17f3dd13e,
+                    0x084338affd56b6d5,
+                    0xf48ba1ae0101c40a,
+                    0x3e7dd6b9c36168f3,
+                    0x00ce1f6a888499b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x18b25eded7031874,
+                    0x3c04d55b75a3cd85,
+                    0x4781c21c910a15dd,
+                    0x9b86c19995dde9a0,
+                    0x9d4a977e8e003b60,
+                    0x0080f4915b9beeff,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae697b6681c997c4,
+                    0x779d68c9d71341d4,
+                    0x94b0f65da6c62e04,
+                    0x6e295c2527d47ee4,
+                    0x2f8d1169da015344,
+                    0x00a9414178a2fc3d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x2fcb0d4f77206555,
+                    0x358d3938e21ff5c0,
+                    0x7e18516eff466789,
+                    0x6a7c1f96c6424d5a,
+                    0x707b96046293458d,
+                    0x0100762f41b650bc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a61174724359147,
+                    0x4b8acc51b89cdd62,
+                    0x91a6835b7c702816,
+                    0xfc043d1bdd93bc19,
+                    0x4463c1272a427079,
+                    0x007d67dd65a31345,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8a9a977c383384ad,
+                    0x13fe1f9993b481ee,
+                    0x92639e60847a4354,
+                    0xae4d77e286a6c1d2,
+                    0x852c8af43dc31571,
+                    0x011ccaad9663dff5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x548327ecece07767,
+                    0x727e0043312e4286,
+                    0x3e2fddbc87c838c8,
+                    0x0c9605d8c42d8dda,
+                    0xc0fd6219917aa6f8,
+                    0x00ecbb9f1d24d8d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x91e445f700103c98,
+                    0xc383e10560dc32c2,
+                    0x3c417926c1a705a6,
+                    0x14391f721eec67fc,
+                    0xc2990e686028da2c,
+                    0x008e4f08a022e8bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c7fd4beb3eeadf4,
+                    0xb871c979fa242279,
+                    0x85b9471df04d7587,
+                    0x2f32ed8b3c1ff843,
+                    0xcab5264784fb08bf,
+                    0x01812779a36cf9da,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c3eefc40c21215a,
+                    0x2d7838b07ff184f0,
+                    0xe32b1aab65227300,
+                    0x747c2117706150d6,
+                    0x48523d7774a762e5,
+                    0x0012905881ce1f05,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0800c7e64f0bea26,
+                    0x62bbb944074e2365,
+                    0xf0b96826c8eedb2a,
+                    0x1b6454397abc6738,
+                    0x4aec2892ad2a8360,
+                    0x002451b8eaa3cdd5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13d44a5960114205,
+                    0x164c2187077bea74,
+                    0x032ebfcc83450243,
+                    0xbeeacbe43d4a0453,
+                    0xdbc5c4a20b28cc63,
+                    0x018ab711b0e3993a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae4aa03fe36484cc,
+                    0x718ca65e678b7a95,
+                    0xb087d5860582a421,
+                    0x7712b67f21218348,
+                    0xa6ac32bc9b47a87e,
+                    0x00b04f95176f517a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xfe0bb7401d990f2c,
+                    0x8a6aaa1cf89fb990,
+                    0x4f4fdb95a1c3ee32,
+                    0x09f87e00e08641f7,
+                    0xd23e1949c76e6713,
+                    0x0175a37a55d7e07d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2755f95d1260e9d9,
+                    0xf1af9c442baa95c4,
+                    0xbcdc5295843708a8,
+                    0x3c2815971fd43b78,
+                    0x5d21dffbe980fd9b,
+                    0x008e31b44c65206f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcaca272f3f43e9af,
+                    0x0e38560c76aef547,
+                    0xe3a4409692fb0a91,
+                    0x61681636afedf41f,
+                    0x824f8d2801edb243,
+                    0x003d48dbbd4ffb2d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x506b4c2f99c5d58b,
+                    0xd79d6a5baa4994fc,
+                    0x246b28f6940822af,
+                    0x24ead8e50d3f861f,
+                    0xd535329bb3d8b0f6,
+                    0x00cb39d3c0e19eb9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x850ea356b0bda988,
+                    0x591363c1c4d1d999,
+                    0x60184f463f6f5c26,
+                    0x636b5b079518209e,
+                    0x3dbd7b37bbae03a0,
+                    0x00b5b2360e3b29bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb9117c4be115138,
+                    0x3fae845927acff0b,
+                    0x3ef6d444b1a2a933,
+                    0x877eeac8cceecf45,
+                    0xe79e7fadbed7eadc,
+                    0x01639394fb7562f7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf23d9402af61ac9d,
+                    0xf048b7945dd8fe5e,
+                    0x007460922be40af1,
+                    0x6594619527f61a9a,
+                    0xda9fe43ad4bc2d2f,
+                    0x0168b7b28389e2ea,
+                ])),
+                Felt::new(BigInteger384([
+                    0x34fba062fef79da5,
+                    0x5e00a981ed0857a6,
+                    0xde32e0a113895361,
+                    0x11b5856e376321b1,
+                    0x572a4fdc224c0fc3,
+                    0x0130f1f3f69ebcd8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9daf39f1ee63f452,
+                    0x3cae7c88b94fbc86,
+                    0x1dc2172daee23457,
+                    0xc4dcc453d9c9b2c4,
+                    0x6dd0314f0a86d86d,
+                    0x004af7d483531206,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd04b1a9938a21f87,
+                    0x4c8ab30fe38b1488,
+                    0x72db97734d68786f,
+                    0x5018cc3c36c5a064,
+                    0x686dca880c8fec55,
+                    0x0125f9020b5e1804,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xba40e5e58c4ebc60,
+                    0x956817ec68216758,
+                    0x86f97bbe44cb29b9,
+                    0x3541bd6de08fc3cf,
+                    0xfeeaa28df445c44f,
+                    0x001031e01489fe9c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xda2b7524fcb299dd,
+                    0x895662632f754d7a,
+                    0xdf574eb2bb6ce7b4,
+                    0x74dcf2abc0ddfd13,
+                    0x6b505a0cf23b7a13,
+                    0x012f44fe6c31d93b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27f6f041080cf82a,
+                    0xe7900444a7afca75,
+                    0xa2226b53d1223c0c,
+                    0xb27d1d7e9d35eb6f,
+                    0x89a911849a1d1cba,
+                    0x00573c37af3a0e84,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5cb76dbd770354f8,
+                    0xb74ee886f97ed4b9,
+                    0xffefcd2938daf633,
+                    0xd3436f00023c7ed8,
+                    0x9f9bc6a00ac228ff,
+                    0x01a756cdfff90951,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf7a140919285e1db,
+                    0x948ec5a01c7e8537,
+                    0xe03dc899b8fef571,
+                    0x35fbb8511742f90e,
+                    0xe8e4b840f18f2d48,
+                    0x009624a263ca7dc3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc6e8c6256ce79d8,
+                    0xf4d4a502b86f9af0,
+                    0x8409d2e81d6411c2,
+                    0x1c99d0b983ae452a,
+                    0xaa1ca3a55b91d42f,
+                    0x016f5e8ed047d389,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa1fc2efc180e0c85,
+                    0xe7b5e2dba12e622d,
+                    0x787204b357341038,
+                    0x5ae4d59b7f72f391,
+                    0x798afbe591ec9d8e,
+                    0x0097c593339308df,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbbcd8357c002a75b,
+                    0x148c4d33f3c672c0,
+                    0xd6d3642a773d5741,
+                    0x538f73106e3272aa,
+                    0xa98a334ff5e9aa9e,
+                    0x019ccde311e68a5f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x18fcd79e4604adf9,
+                    0x4e4a2c79e059082f,
+                    0x86aa799b118d3c9b,
+                    0xb02a204abe557b52,
+                    0xfd03b271661e3505,
+                    0x0016a61e8049bd0e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d1e1323e4b065c1,
+                    0x3bd138cdcaef3e77,
+                    0xae64ceb24fd0cd72,
+                    0x2d4f22cc09ee0762,
+                    0x1edf3ba6f4d220d5,
+                    0x002238b5e05f7880,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xdf85a31088033dc7,
+                    0x9404869740deda8f,
+                    0x0633607c5f27bf4c,
+                    0x01ced9c6ffc3f088,
+                    0xbcd5dfbd29a0d95b,
+                    0x0012f2b8c725ea7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0147b5442f6781fc,
+                    0x57011695f465d558,
+                    0x6522b2c782d9938a,
+                    0x2ed47e42fa538344,
+                    0x62fdd6c982d43307,
+                    0x013e80f1440c8c5d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x32d8576b2458f6c5,
+                    0xa0ad91b483b19ebb,
+                    0xcdfcf843eda22439,
+                    0x8116acede802c81e,
+                    0xb84b272c92fd6d72,
+                    0x01aa6408dfb1e318,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2fd016121a9e354d,
+                    0xfa9dde561886d4ce,
+                    0xa04402bc486d4f81,
+                    0xc0d19a78a9cf7cae,
+                    0x83a5280cc07d4e3e,
+                    0x016cc506ad439768,
+                ])),
+                Felt::new(BigInteger384([
+                    0x94c663250b8bfc04,
+                    0x97228121713ed589,
+                    0x0d341227e8eca8ce,
+                    0x9ae9cf910e92e534,
+                    0x22b30861e3a114dc,
+                    0x0028b9360f3a625a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa96851ffa6348dd8,
+                    0xa791a32b7d57ae81,
+                    0x4d471e1ec731f992,
+                    0xfca8373c140e988b,
+                    0x11e38b4b99cfe6d6,
+                    0x00b3a7ca4965add1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaca944355b72f670,
+                    0x0336a82fb9112b57,
+                    0x9ab1a48499e8beb0,
+                    0xd677c5b549ca1a37,
+                    0x961a5515357ddd9a,
+                    0x00dbccd328527467,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4bc1c2ee4c619805,
+                    0x96c74b6feb08dbdd,
+                    0x5f493f8466f9eb41,
+                    0x5c57905900b4e000,
+                    0x678858ff23583863,
+                    0x0164d11df86dacbf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9bcff40c5c0e8e8e,
+                    0xe85d5081d2242299,
+                    0x386cfcf51e03b095,
+                    0xe6ea85f261b041c6,
+                    0x1992e07b76146eba,
+                    0x008c469f389fe77c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8b388015c79eb6d3,
+                    0xaf2cb7a6c1eff4d2,
+                    0xca5f7aafd4ff1aeb,
+                    0xdbdd42f67b5dc3df,
+                    0x9f8f7de0f89224c7,
+                    0x006d747ccbfa07e2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xd3c59e1e7ae89de3,
+                    0x7f596323cbb405c8,
+                    0xcbfad88e5ffd6b3c,
+                    0x48670a90e59bdaf1,
+                    0x70d6a35e43f62988,
+                    0x014664b980fce94a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf98746a4ea598e1c,
+                    0xa3876304086548d8,
+                    0x3fd650e5dc367e53,
+                    0x459189593355a0b3,
+                    0x74f8de77c7f08a39,
+                    0x010b339b2425b2a2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfe1dc7a1d16cef59,
+                    0xd9bad7818622882d,
+                    0x3b06d2b85a4481fb,
+                    0x0cd4e5fabc531a9a,
+                    0xba8d1ed1cb9355b8,
+                    0x0165ccf41991a317,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe4db1a8124db9538,
+                    0x93b9ff09c24e1a2c,
+                    0x95f1a5389431c4b9,
+                    0x563486024f467251,
+                    0xf315ceff815a192e,
+                    0x0022b1f9b9a9b0e3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f092a7f11a3ba3a,
+                    0x8394e5c20a382597,
+                    0x3675d8f14515a4a5,
+                    0xfb1b581ab8530972,
+                    0x030c79e5e206fa8c,
+                    0x0160d159ffc59caf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdd26bb61e77ee35d,
+                    0x850fab488435b58f,
+                    0x2add510498fd7d7a,
+                    0x62a85d3d2c3f8d58,
+                    0x3e78469594d68ac3,
+                    0x0046fe2ca821c407,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfd882a84a7cb64ad,
+                    0xbaab9b7194c7cdb9,
+                    0xb1019d5b332cc1fd,
+                    0x36f4b82b3e1a3812,
+                    0x24e5be15ecb5b27e,
+                    0x00d954a1a1d3d461,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd4b7bc86ee52cbb2,
+                    0x1eaa6e0cf898d278,
+                    0x812bae3327db4a12,
+                    0x737c70142b6f82db,
+                    0xf0e97d4f1a14f1fa,
+                    0x01807cdc4bbbc0d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc5cded2738eaaaa3,
+                    0x55df557dbedaeaf3,
+                    0x31e66a9c06d5745b,
+                    0x737cae89e979171a,
+                    0x992a1c9ea4d39162,
+                    0x001e1f8c511d6547,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66e65d55fba9fef8,
+                    0x70649de30ae951dd,
+                    0xb10bee332675d8a5,
+                    0xbcd15243f5dbb12c,
+                    0xb37081885af86150,
+                    0x014665793fbae61b,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger384([
+                0xa1e02ecae9c5bfa0,
+                0xa9867276580fbe8f,
+                0x82fc6e8100bb8825,
+                0x83168e74da8e4188,
+                0x8fe643cc872265a8,
+                0x017a791be6f24a21,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x664af334ca462259,
+                0x74c786d55add816c,
+                0x52ebd7ad8812377a,
+                0x56ec36a43f1417a1,
+                0x6e0b95692e8cf9ee,
+                0x01abef56520ed2cc,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xc1d0df9ff8f6d411,
+                0x4c0ad809d38a04aa,
+                0xb803106044c4336a,
+                0xb4a5fed10fc4979a,
+                0x8f42307e5284a939,
+                0x0090b84979ae2599,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x63427395926daa10,
+                0xfbcca3a7f593748d,
+                0xdc2169bc17e0bc1b,
+                0xa69cdb9132f5d9b4,
+                0x0ee838c0b2c7cb77,
+                0x00d7e593b846534d,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xcebc97fff6d668e5,
+                0x91ad29e4cee20327,
+                0xabe8c4394e52b174,
+                0x77b435786a803ece,
+                0x39a2a89232be349b,
+                0x01987681c9a885c1,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x91371616508b1a9d,
+                0xcf56cbac72857728,
+                0xcef51d7cbd7f8e60,
+                0x4987e47d2938d4cc,
+                0xb80eb8d584f2ccf3,
+                0x01112c064556708b,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x0897f77b0a0f7935,
+                0x0a8524ec48c5afcf,
+                0xf65bdc090e6a5a43,
+                0xf297085cfa4a55db,
+                0x6704af95a37050d4,
+                0x017ed59030102bde,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x202ae626249b2f5f,
+                0x54c968cb47dd293b,
+                0xfce6ae435a30de3d,
+                0x60848a075d22f88f,
+                0xab1bc97b0fdf3fd4,
+                0x00ea078609867471,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x377525a8638d9a83,
+                0x8285147e153a0732,
+                0x2797ce31587049c4,
+                0x25b1c8e05931fd71,
+                0xb4f9c1f663a62fe1,
+                0x016082964fb909de,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xb6ff01dce71941bd,
+                0xdc25c4189ebd1960,
+                0xbc02c20c3e0d4a89,
+                0xf0b30ce766f04dcb,
+                0xd3d253453c352be8,
+                0x01106ebcb3f3ba8a,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 376, 377, 1133] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 376];
+        let mut opad = vec![0x5cu8; 376];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
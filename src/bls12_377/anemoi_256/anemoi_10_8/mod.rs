@@ -0,0 +1,2775 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 480 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 8 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 8;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 14 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 14;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6681f9149e676629,
+                    0xff36896069640b6d,
+                    0x0923f46b0437fdb9,
+                    0xfbdd1fa1b48f59f1,
+                    0xb4f877e43cc9572c,
+                    0x00b5f8cd33f55fff,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdfbbcc462d5740b2,
+                    0x3bf5787a822376b0,
+                    0xdf425a09c561d8be,
+                    0x2fce538140b9f926,
+                    0xd1be6107134e4758,
+                    0x015d8dcadf93cf55,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5370cf8433ebd3d5,
+                    0xf1599950b82148dc,
+                    0x791799bc4eb2b3cc,
+                    0xb854f8c5dff102a0,
+                    0x64eae40a0e823a63,
+                    0x019a71f747082986,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43bec427fb1212ff,
+                    0xcab118bab2afdb46,
+                    0xb093deb0a53a6b71,
+                    0x4931eb4a69034f4b,
+                    0xdbc12ded713c26ff,
+                    0x00daf1c493e36778,
+                ])),
+                Felt::new(BigInteger384([
+                    0x83e07e3adb8d9ff2,
+                    0x7f97d085281340cf,
+                    0x7f6603d6a98e5b35,
+                    0x4dbd944dd1c4f699,
+                    0x43be2ad05bacabcc,
+                    0x0107341b2f4c77cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x11978ac5ee71ea30,
+                    0x3fd3bc160842a3d4,
+                    0xcc4f0d0037daaadc,
+                    0x54470f380465f8fd,
+                    0xde3c077c348f9bee,
+                    0x00cfbb698d1a85df,
+                ])),
+                Felt::new(BigInteger384([
+                    0x900ad7d51d9c70e7,
+                    0x12a5e9dbf81be3bf,
+                    0x389c527a221bdc80,
+                    0x60b9caf046da330f,
+                    0xdc0e37359e8e10a1,
+                    0x00e1c7bacfb88851,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2c4cdd3cc1c4a5bc,
+                    0x11350d8e9610f79a,
+                    0xf9b2585bbfbe6df8,
+                    0x42b9771c3b6d6822,
+                    0x1f7fd5e998798275,
+                    0x0082b7cf0a54649c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfe2360f4aba6daef,
+                    0xe7fffd1c32b819c1,
+                    0xbc9c964bb15d04c8,
+                    0x8a92293b339f0e86,
+                    0x3c20f61bdc763361,
+                    0x0172b845a8c83d0b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe15642f6bfcfa680,
+                    0x120e0e8f6d2fe62e,
+                    0xc0ad7719e508e150,
+                    0x796843d4932d2fba,
+                    0x734e600aacae1983,
+                    0x014bc076be3a7c72,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9c0afdadd661d330,
+                    0xc959bc43eb11396a,
+                    0x0300e3549782d765,
+                    0x9133d48750db8590,
+                    0x0f5aff856ca7b8fa,
+                    0x00dc9f028286c3cb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa9b28ee13624194e,
+                    0x726011ffd9c5ed02,
+                    0xbc053391927a8140,
+                    0x303db64186402f4a,
+                    0x12516193b71d6a24,
+                    0x018ce8c607d024a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x23aaf372dae4f5ed,
+                    0xd173126a64039c30,
+                    0x54850cd029bccf57,
+                    0x03028253ff90f149,
+                    0xf5e0a2d06b40eb0c,
+                    0x002733c854129c4a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8473d1eccae48fba,
+                    0x0bbb06a687573878,
+                    0x859825f9f469d708,
+                    0x8b4d565d7becb6ed,
+                    0x2e2163e736c6d3b1,
+                    0x011f1a4fb5250cf3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea874a1031e97f32,
+                    0xc7cf70627997993d,
+                    0x319115acf1e0ff69,
+                    0x5c7f0fb40015cf70,
+                    0x5fe117902db05c4c,
+                    0x01548ad318cc1467,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc6a871800c2d121e,
+                    0x3eb35b6ff21538da,
+                    0xb025bb5bdc36fbe2,
+                    0x668e0ea4098e4e3d,
+                    0x149bb437a41fcbe1,
+                    0x018455062f243e75,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe2fbe678b3fbe7a9,
+                    0xfb922426b079e847,
+                    0x6ae1d5bafe675c05,
+                    0xd46ae866c650d5ff,
+                    0x418805b698fee6fc,
+                    0x00b313901d7a0600,
+                ])),
+                Felt::new(BigInteger384([
+                    0x040f3a72fc01c943,
+                    0xe4083c5f262a5de3,
+                    0x7f98880a93a6599b,
+                    0xa99a4dd64781bbfc,
+                    0x866def2c9d73b9ec,
+                    0x015be228516f373e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x502e8e110f967337,
+                    0xa49c06157044a089,
+                    0x45784ad7cd5c2fc4,
+                    0x70f97e753fa06c28,
+                    0xcc09c41cf16a29dd,
+                    0x012214aec555c298,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d7933cd13b9ed42,
+                    0xd28d0bc921a9b626,
+                    0xc36aad26e35a0120,
+                    0x90d2acfed7b8e8f2,
+                    0x84b021fbe2a08300,
+                    0x0199625beb394ddb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x12190df3b24d3213,
+                    0x7c077b20473b0bef,
+                    0x6f55e23ca296e1da,
+                    0xe158748104173384,
+                    0xfb8030d5f67551e6,
+                    0x01368ae026f165d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x70e89aca1cedf0d8,
+                    0x620899be7ba0a53f,
+                    0xbb7d1af828dae8c5,
+                    0xefd8f5779cf6b498,
+                    0x8cc2bf665e4237fa,
+                    0x006853ca03594bdf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7cbb12b0895026eb,
+                    0x33315e2d4dc9c8ac,
+                    0x081752aa8eb449fc,
+                    0x50ad9ad9527369c5,
+                    0x527f8f0f09a02731,
+                    0x00e8166218cd7c32,
+                ])),
+                Felt::new(BigInteger384([
+                    0xede92cf418bad246,
+                    0xa7b469cbf95f2e11,
+                    0xa0b35fc0f5714094,
+                    0x1fd24a79507ff2d6,
+                    0x336ac18f186d4391,
+                    0x0076962305c35bc8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeed8418c81562a31,
+                    0xf7cec3178c95f1a4,
+                    0x159b57ee5a11809d,
+                    0x8da91f3c54be0885,
+                    0x64c58cef1d3f721b,
+                    0x018acf0cb39630d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5aab3062c8006119,
+                    0xf5723b13fe21e58a,
+                    0x21c9ee445a575f6e,
+                    0x78fc357de0d51a06,
+                    0x64bbb6ba1849dc7f,
+                    0x00acad32a2ff5c54,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f91c61fe5cf5b2c,
+                    0x12a3743d79cf736f,
+                    0xf255f0485050420a,
+                    0x0cb505e4c2c4d1ea,
+                    0xe7ea614a2f092a0b,
+                    0x01502653c63b4d93,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb3eb191cd17baf27,
+                    0xbe18046eba315f91,
+                    0xc37f27fbd020980b,
+                    0xd8d97eb222b6a1af,
+                    0x10744a4a4b19f558,
+                    0x0171bf8e322bb0b7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5b0b33dd95054716,
+                    0xb883a3d4709a4364,
+                    0xbd6222ccf142842c,
+                    0x0ab37dea3f7a0072,
+                    0xf9b942e3dc1d4ac9,
+                    0x019a09dd2c598dc6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a4b74a06c8918cf,
+                    0xdb1b2a128586e7bd,
+                    0xe102c29f43306f41,
+                    0xd0a03368736a05ab,
+                    0x972ed65991b3a8e3,
+                    0x018fb581f5bfeb01,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe2a17135c806777f,
+                    0x2429af78a575ae05,
+                    0x944a34a956c6b787,
+                    0xce14109b46dd44b6,
+                    0x257a9cf1a8e91f7e,
+                    0x00eaca7cca86113c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf369426458bd146f,
+                    0x3e08c3a900ecc86f,
+                    0xcb73f49ce1153c19,
+                    0x7a0c6a7bf983d647,
+                    0x418b7fa6f428fb58,
+                    0x001b6de1b87f49d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbddc2c104d53c858,
+                    0x13239f9739b3f9ff,
+                    0x80f4cadf9db46474,
+                    0x202e95fdf18195a3,
+                    0x69063e6c40ba1105,
+                    0x005b2d36b858aad3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf609e64ba6a83225,
+                    0x53ce994ba82674bc,
+                    0xf3108236a56020ce,
+                    0xb6e5f953a08346d7,
+                    0xa39833c242cb629d,
+                    0x00ad3b59adfec3bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd7217f211a0e8304,
+                    0xae8a1516896b78f5,
+                    0x4fcf4c607cd68bd3,
+                    0xab16c81bb69db541,
+                    0xb392e5a5159d0929,
+                    0x0164dca8de90baa9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2035b2c81db18695,
+                    0xd49aaa6e4bfb3936,
+                    0xc1e0c672c11a7f03,
+                    0x9a8a911490333f4b,
+                    0xd8cb8cb4e05c5792,
+                    0x00c4072f1a704b48,
+                ])),
+                Felt::new(BigInteger384([
+                    0x929dad35a8c91aef,
+                    0x93d74ea77445f622,
+                    0xa859163a410046b9,
+                    0x5260c6d5758b47e3,
+                    0xdd37f98e7928ceae,
+                    0x0182b44546591171,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5592022e7d3d66db,
+                    0x8c17ae15deade940,
+                    0x49562a1a1f72dead,
+                    0xfbf2d93c890c04c9,
+                    0x5399c98818750579,
+                    0x0128ccad00bc8adb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9950ef3419d1e2fd,
+                    0x6cee29fc58e0ebfd,
+                    0x6fcbfd1cb7e34f8b,
+                    0x7a399a860b98c4d0,
+                    0x08288ca15195f2a6,
+                    0x014cf161febc1797,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b226626e6ceb65e,
+                    0xaf34583a163db1c9,
+                    0xbbea26beeee63cc3,
+                    0x911b35fab422ed2d,
+                    0x6a8d7c6b33286db6,
+                    0x01027179e0cbd5cd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1d783194a13baa8f,
+                    0x640c1d1a26f0eca6,
+                    0xe5f11384a81bbd6c,
+                    0x12def044db6024d2,
+                    0x533d8cdaa2153a63,
+                    0x002fe5c9765cb3a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c4ed5a6d060fc46,
+                    0xb83287652f5864fd,
+                    0x8743d5ef23ddd26f,
+                    0x1585c12b9276ac84,
+                    0x84a13c5291da743d,
+                    0x00da31f6070d4077,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe3203f160e980ca6,
+                    0x16fae358da82c755,
+                    0xa82c0f7ae0564315,
+                    0xa0f72578fa2372e3,
+                    0x6b7afd36e5a1350e,
+                    0x01177fcc67b9615b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96d746f04ad224a0,
+                    0xc277653c3a824519,
+                    0x2c2fb0553c41664f,
+                    0x4d8105b290576975,
+                    0xdb93033a346c6093,
+                    0x01006e69a4f285b4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x75b3f06afcf657b8,
+                    0x37b03f31815f3c99,
+                    0x846d30e5bc78757e,
+                    0xa70e3da510c90486,
+                    0x04213244abc5ee81,
+                    0x017900f151b4ad80,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf202194808ec9a85,
+                    0x8e804f3be091df11,
+                    0xe2a8b9e72b15f682,
+                    0x4658a55d47ddff3d,
+                    0x40b8a7b389eb42e4,
+                    0x0160f4af52ea5c2f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea49bb4c39b36059,
+                    0xeed8b3ca33ff82a4,
+                    0xd3e9940b3e67332a,
+                    0x8fbfc04fb0d6c925,
+                    0x4e54255df5639dc2,
+                    0x00f88c819d43e73c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x10e417fba35614a9,
+                    0x8e4bc58985657cc6,
+                    0xb5679d182f5f6a8c,
+                    0xefc389e35a0bae49,
+                    0xfa10a5ace03e63de,
+                    0x01406c7edae32a97,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c59b82c8e1b7fac,
+                    0x9b39c7b6ef783da0,
+                    0x4ea34fc90340e8e2,
+                    0xc56f6bce65c5bc23,
+                    0x106aa49551e9fc91,
+                    0x003a953a03268dac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x01b3b06b175a210a,
+                    0x75613f3d02598a9c,
+                    0x2bb37d13734aa9c9,
+                    0x347645a3ac221805,
+                    0x8c3f21007febbb83,
+                    0x00720a421d32f77e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4da6223c27ca44b5,
+                    0x177bcd01232ed86d,
+                    0x038838d99960a582,
+                    0x4c68bc1a7a189551,
+                    0xd520e48dc9aa029c,
+                    0x016d5aa409e8f008,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb20d7d25c247e267,
+                    0x571a253d720533da,
+                    0xc299db0f17156636,
+                    0xda203766adb3d3a6,
+                    0x90d7c9b09193c88c,
+                    0x0157a0c8c0fd3b02,
+                ])),
+                Felt::new(BigInteger384([
+                    0x00aaa8c85950a03d,
+                    0xe58ee6730db33397,
+                    0x202dab517fa63bcd,
+                    0xc18f39f8dd2d9668,
+                    0xfc0c761f058559a3,
+                    0x01a94eea7e0c85c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x452310c7f2aee29d,
+                    0x32203050f68135ab,
+                    0xec1010d0197318a4,
+                    0xd57af0674467b30b,
+                    0xdf46b88495b79d64,
+                    0x0166b32d7137b017,
+                ])),
+                Felt::new(BigInteger384([
+                    0x38bae2dff658fb79,
+                    0xc9ed2a948cfadeec,
+                    0xb86bde2e042fb28e,
+                    0x85229cb56e82941e,
+                    0x2ed77a92caf31b10,
+                    0x009f36bb29596012,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf9506cf63622db21,
+                    0xb41dd9fa86c56c7f,
+                    0x44bf2a61a970ece1,
+                    0x46f4fc9772a79644,
+                    0x0b1e26d83b044c9d,
+                    0x00978a9c4741c099,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc48b2158bc765cc0,
+                    0x63450b13405fd4bc,
+                    0xe4cec4aebe447aec,
+                    0x32e35001aef51707,
+                    0x828d0bbc3027f3b8,
+                    0x0071b782c7a34ebf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe07fab345151db90,
+                    0x5ff147c528f67b88,
+                    0xb1a17ed2cf22adfa,
+                    0xd25da72c23129536,
+                    0x5956f3d04512c9a9,
+                    0x01314045bc43daf3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a42ca75a931d0a3,
+                    0x1d15641de051f2b3,
+                    0x922112479bc2dfca,
+                    0x6106de0f490050f3,
+                    0x13baf61d338aef2e,
+                    0x00642bf3981b5ed4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6e78a1b943e98b54,
+                    0xdbb89b237e055173,
+                    0x2504cbe6316d4a2e,
+                    0x9a935c8719e24a68,
+                    0x046ea98d2949d616,
+                    0x01718dae47ba39fc,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe98a3e911822efb1,
+                    0xadef343a1200e30e,
+                    0xe4c65c75fe4f9710,
+                    0x6f9c6a74db3a104b,
+                    0x440387a59b6b5763,
+                    0x01488d2ed09bc27d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x94852d94b1455c0e,
+                    0xb0fb2f5e1bd57be7,
+                    0xafa269968451f39b,
+                    0x52184a5e5fb96b08,
+                    0xbf24ad2319508536,
+                    0x004ce49786f784a4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x563b4798f2d9ac8c,
+                    0xf077feabbd1560f9,
+                    0xf2973cdfc03c1db5,
+                    0xb88f97bdebc6ccf5,
+                    0x6e1c50f65483dc0d,
+                    0x0188c06efa83a048,
+                ])),
+                Felt::new(BigInteger384([
+                    0x78a808db4cc85a7a,
+                    0x3e2e16a83cf391b8,
+                    0x715d25c82f9d7ceb,
+                    0x9191f33a7d973263,
+                    0x657e26e6af9522b5,
+                    0x010031433a4e6220,
+                ])),
+                Felt::new(BigInteger384([
+                    0x33641e6df4e071b0,
+                    0x26eba90172f3b260,
+                    0x3be428fd803138b9,
+                    0xa1745feda0c329a4,
+                    0x0afd215037b7d4b9,
+                    0x016015ebf6a22e37,
+                ])),
+                Felt::new(BigInteger384([
+                    0x50588bad0d04587e,
+                    0x87be37e8acfd5398,
+                    0xe33b9cec25fab1d7,
+                    0x344b0901be7fae8c,
+                    0x148f8bcba03abbcd,
+                    0x00384f5504a684f1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d7e620f44c0b487,
+                    0x94a6abf3765acf75,
+                    0x34379aa8831fcf90,
+                    0x3b1c9de35ab3f724,
+                    0x9a4b38e2d5a0e338,
+                    0x00df10b773bb071c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x50704585d198846c,
+                    0xc4ff21e57bc9cbe0,
+                    0x59fdbf037199c251,
+                    0x0e0080432e2211c4,
+                    0xa560d1038a6f5269,
+                    0x0158111d0bee6431,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1cbac007293381de,
+                    0x1dbd973c220c86be,
+                    0x8e240babbf51cd5e,
+                    0xecdadabca64b95f3,
+                    0xed1fecd62f67fdf8,
+                    0x012c8700503db81a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb0d4b17daab60df5,
+                    0x1423fb7672855ebf,
+                    0xb39aca9aa1c334f6,
+                    0xfcf391d79e5e4ffe,
+                    0x08d8a0ea5dbb8513,
+                    0x008b7266f8858280,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x93eea5cc61d1ad2c,
+                    0xbef22a98df8fecf3,
+                    0x33464e5aaee52f5d,
+                    0xb8abb263220ae292,
+                    0xe45198255c45f766,
+                    0x015a186a517055ae,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ef3e1d767b1e6fc,
+                    0x1178173a84ef4a06,
+                    0x0f4c733293a0a709,
+                    0x35936120b402fc4f,
+                    0x14428cc4e5ae56f6,
+                    0x014d53e15ee84790,
+                ])),
+                Felt::new(BigInteger384([
+                    0x826605df88dc5c33,
+                    0xbea51b0b83cb8bff,
+                    0x343b145b0fc1971f,
+                    0xe609cf5787ff029e,
+                    0x3f2348b592bbdbe8,
+                    0x010babf8309d051b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc6bf0ac4589662d1,
+                    0xe4a09d2a6d0d8a69,
+                    0xa9d5ca0235d37e8f,
+                    0x86a21a0f628d76a5,
+                    0xd1d2b95fc2be0cc9,
+                    0x00915ef15ee8b731,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e8dbb63f693bdd4,
+                    0x722be75f1203862c,
+                    0x9c1f8adab6cf7554,
+                    0xbbe1b3b3bb325b3b,
+                    0xd71e464f14a2e104,
+                    0x00c88272a066958c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9702d02f75c9b901,
+                    0xa8f3b1167b56c3ff,
+                    0x7e0fcfa88c6ebb21,
+                    0x9228abe8dfe08630,
+                    0x3deab42c778ae133,
+                    0x01543ee6456a4157,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4335c228bb6fa9e4,
+                    0x748e6a30c054b8bc,
+                    0xde793027f284e4c3,
+                    0x0b360c0f9499e448,
+                    0x7973c71fdaf52276,
+                    0x00fe84c5b1d8907d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2c55550778c62339,
+                    0x73f59145820abdbc,
+                    0x96a466bb8126f7d0,
+                    0xe97f741c59400735,
+                    0x274449e61053ab93,
+                    0x018a4a034e50219b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x597df22d9ea45a7f,
+                    0x83b78ec401086e0b,
+                    0x068fbc956cdb9155,
+                    0x09a168402e144c28,
+                    0xb9daaa27e0ba06a0,
+                    0x010a6c6f05e7ba43,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b72cbe550e7aaa3,
+                    0x42b749fc6335354b,
+                    0x4a231eba7975b5fb,
+                    0xb845843584616ca7,
+                    0xada8aa1f8b85daa0,
+                    0x017971fcf4f4fe14,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6ca66d025a639e9c,
+                    0xd193a3828cf093a8,
+                    0x6c612ad62e82fbe1,
+                    0x2d25a36531b3295e,
+                    0x018833452ae53c2d,
+                    0x0134f78bd2bd868a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b03d66423fab5c1,
+                    0x2c76b39193eb9632,
+                    0x5bbedf9435941df5,
+                    0x41634ddd2e2a597b,
+                    0x333e3b52fcbedaeb,
+                    0x014f10c2f061aff8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb640fe76a9f94cd,
+                    0xacfa02d187cfb0ba,
+                    0x5ca7c856f24318b3,
+                    0x7e04a7f6315aa8e8,
+                    0xb960e4bde66d1ad8,
+                    0x00dabe0b50905e5c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe0a176fe02ed070f,
+                    0x4d61c667aec39ff3,
+                    0x1d748eea9efc010f,
+                    0x3441fa54d4afee89,
+                    0x5bec03c58764b7aa,
+                    0x00e28fd950e42efb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfe41ad1b6d374e38,
+                    0x5b6a775e9f1e6c6f,
+                    0xa11523b92ca02191,
+                    0xb3b9528c47a850eb,
+                    0x5dcdc8f7983f8508,
+                    0x018d7df56520cefa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8a0ac81aac8acf9b,
+                    0x62cae4ffdcad1503,
+                    0xfa694d8cdaa463a1,
+                    0x1af660fe5f53918e,
+                    0x4b7b00a8ebcd935e,
+                    0x011ad68dced83bd5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd89179a45574f68a,
+                    0x6f2a88a86c2c6e3a,
+                    0x2a134b417d122af2,
+                    0x887b112da7287bec,
+                    0x71483cc90e1c228a,
+                    0x00524e369d05a9ed,
+                ])),
+                Felt::new(BigInteger384([
+                    0x03de8d30f0304c9b,
+                    0xcdb6205fc9585c3f,
+                    0x1b182afbd868c4c6,
+                    0xc00c328ca3aaed4c,
+                    0x6b486d7acb1e1e08,
+                    0x00ecd1a2226d753f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1ed5fa3941d70e49,
+                    0x43bfbfd50331f87a,
+                    0xe5008a1e84daff91,
+                    0x1267a83004ed6622,
+                    0x5554a9efb23e5027,
+                    0x01344291c9cd8356,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbb6b21356619b894,
+                    0x2219c94c6fa7fdaa,
+                    0x21bdd7ff11c41488,
+                    0xbe0ea5f9acca3b98,
+                    0xfbc88554bde962f0,
+                    0x00396b49e9284739,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0b61a48387de7929,
+                    0x7e2828e8ec8c9ef4,
+                    0xb60d9e78771098da,
+                    0x13dccdc2b63cdedc,
+                    0xfcfb50d4852e2915,
+                    0x000dc4fdb8442483,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc0b0466bede3a08a,
+                    0x316bbafba336a54c,
+                    0x51979b4e779e2d9c,
+                    0xf00a89b7bbe11220,
+                    0x19c8285ab6875db8,
+                    0x00865d49d464dfd7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x20b0116755ff71e2,
+                    0x633919bb66bb4550,
+                    0x8f0930267985b5a1,
+                    0x96556accb8655736,
+                    0x471acde578695f0f,
+                    0x0178e9bbba28c256,
+                ])),
+                Felt::new(BigInteger384([
+                    0x238ac697a63d6dfc,
+                    0x6fbd7330925770cc,
+                    0x5bc8a9a1dacbbb40,
+                    0x6d61c2cf46fadfc8,
+                    0x43bfc0549f36de98,
+                    0x009902f0dc24c5ea,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b56f267b0777d1a,
+                    0x4ea7f3ab215f206e,
+                    0x6c82f4f376259c31,
+                    0xda80022a853264e0,
+                    0x95305c9628769b2b,
+                    0x016b8d0323326f73,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd5e52932d5f8e30f,
+                    0xdb4b033a094250ba,
+                    0x94913030850d1dad,
+                    0xf5aa294e35eda3d2,
+                    0x0a8a8b3caa8d0e92,
+                    0x01721267ad048c6d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d390431c6137976,
+                    0x4b4f738f2876d080,
+                    0xb583010d51c07460,
+                    0x81990815a29000f0,
+                    0xde6f1a9b593dc0f3,
+                    0x01853241a7d392f2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd7326d104b199d4f,
+                    0xd224951d3c41695a,
+                    0xb98120bf05b0bb18,
+                    0xda9e1fb5c286ea32,
+                    0xde4587f572c8869d,
+                    0x015d8842024b6e1a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf68f21c71d5eb8ce,
+                    0xf0d003f719a9fafb,
+                    0x72a06f31b20cb032,
+                    0xe98f72ab3089d3ae,
+                    0x0450c0c9bc0d3272,
+                    0x002b62fcd6b314af,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b10c00936af9833,
+                    0xfeb1680a5ca68600,
+                    0x51642e599d359237,
+                    0x4a5ce63500c93ada,
+                    0xcfa75e585982dd8b,
+                    0x00d3559974228e36,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9e2ab3339ab3ad06,
+                    0x820833791b097a55,
+                    0x910058e442172a30,
+                    0x8000c0ab8f870924,
+                    0xa0886b11d2f23652,
+                    0x007986314587d793,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9f0c63edab0d27b5,
+                    0x03e2590f634632e4,
+                    0xd4c0475b0c0208ce,
+                    0x30f394e9a5862986,
+                    0x36ed71affe3d1063,
+                    0x00a02c541ce156e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45b746dfa875ec2c,
+                    0x1257654494fe7401,
+                    0xdc02714ba22b12d2,
+                    0x784604db94efbc8d,
+                    0x0ea2f764a93fe772,
+                    0x0030b0ec749e9105,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe1c49e568329dab6,
+                    0xa63c8e502d17636b,
+                    0xc57ee4dd84e5cc22,
+                    0xfa7267442962ff18,
+                    0xdac00348e0b1d452,
+                    0x0132cf6a19c73e79,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc734865f9d4e877c,
+                    0xad65093f8e9e3543,
+                    0x96e2b6863c1313bf,
+                    0x6d0cfe078fa90412,
+                    0xdd4b9649fca2b279,
+                    0x016fa289a934c101,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd4488487cbad4edc,
+                    0x2e0a80d9e4ad83e2,
+                    0x280b6254c95d77b4,
+                    0xb3f6d2be0303bfcf,
+                    0xba2dee03110f7a79,
+                    0x018895148a4175f5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xadf027f53627168e,
+                    0x0ffd03bf9b61636e,
+                    0x8f425e078d81005e,
+                    0x87a595932bb84092,
+                    0xbb6cb59b70b87dc8,
+                    0x0003212017ba939e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x70b0275baed4abeb,
+                    0x35ed2a6cdc8bf712,
+                    0x29a56979e1ec85d1,
+                    0xde8f5603056aabeb,
+                    0x4c1a6a8658daf6ff,
+                    0x01a2d520de574e04,
+                ])),
+                Felt::new(BigInteger384([
+                    0x72e593c2625f35c8,
+                    0xdeb67ccdfb40753c,
+                    0x4d7922f64489e924,
+                    0x7219fa67aad6bfcb,
+                    0xe9be3cf01472547a,
+                    0x01150d28dde2b90b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21ec242c58d206e6,
+                    0x8db78f9d3045a64c,
+                    0xf8f2aa8428470c73,
+                    0x1a8c5bb9fa91d269,
+                    0x4134b9f0d931742e,
+                    0x01935a6cbfa0aabd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfdf073111ab13977,
+                    0x65f09f1c3bf8356f,
+                    0xdae48a160977bea1,
+                    0x097eb22b0e0ff37b,
+                    0x468bb892379736dd,
+                    0x000bc673cd68c840,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc3facf482eeeb6f5,
+                    0xaa35f1be16bc8515,
+                    0x09ca223cee8149ad,
+                    0xbf491a9c8e4ecff4,
+                    0x88f838d9e916b5af,
+                    0x009db1fdc02733e6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3d8635ac5b2c19ec,
+                    0xbced582ca9457d3e,
+                    0x35ef4e6904ee6e41,
+                    0x0dee4f651da8bdcd,
+                    0xc348d1ccaebad7a8,
+                    0x0116f70243d265f9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9f27a56318733a4d,
+                    0xb8f3dc6942efc257,
+                    0xec9df74fd4476f27,
+                    0x8d53c2602fff0a72,
+                    0xb7747875ae17ab29,
+                    0x0134d995ce5907c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf9d94cc925444b85,
+                    0x921bae39e726f474,
+                    0x7d48e75586128d85,
+                    0xd9b58802747b796c,
+                    0x96c415261f41b393,
+                    0x0101cf229ccc6640,
+                ])),
+                Felt::new(BigInteger384([
+                    0x57ab2b9ff9417eed,
+                    0xc0a8fe376aaeb37a,
+                    0xcda071e62b4a0c7b,
+                    0x263ef8ab0b11097e,
+                    0x906c5c65d81ef2ea,
+                    0x0012465f70ad56bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x065fa1d5c24e8bab,
+                    0xfe791ad785387fe2,
+                    0xb7f6a5b2a6344bdb,
+                    0xc509f7e5c97619bc,
+                    0x3b0890cc1ce29b62,
+                    0x00ddf77cecc4bd85,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf7a7408abafab6d1,
+                    0xe1128b1b81b97465,
+                    0x71103060eb51de4f,
+                    0x7f029d3da7da31d5,
+                    0x8b11b1e046081b3c,
+                    0x00b8942f32cdaa44,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7b7ea078b2f53555,
+                    0x4eb1c0761fe09fd9,
+                    0x068b26a76d3decde,
+                    0x1d177e0317648441,
+                    0x9e430b2eef05d8c1,
+                    0x0076337f47aab3ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc85cc2dfcea48d92,
+                    0x635ce10c8d6aae6d,
+                    0x1b53b4fee5092591,
+                    0x5ad0464cc1431aae,
+                    0xf4491c2267d075f3,
+                    0x015ac051b4b939e5,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6054fc9bb6caa1ca,
+                    0xdef4123ec1c5672f,
+                    0x9057248499b3e39b,
+                    0xaa4d5f1673e85e72,
+                    0xa7a007fe71f17661,
+                    0x000806cf7cb9162c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x418afb328b7b0b03,
+                    0x2f508805621be0e3,
+                    0xed1292bd9533ad5c,
+                    0x83eda99f5e3eb3a3,
+                    0x4bf5a8d063235eaa,
+                    0x01692092f758f95c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d3d6acbe3b3119e,
+                    0x22d6a92b3cc38949,
+                    0x44a4466174186e00,
+                    0x5f7e451f1aad0fc9,
+                    0xfdaac5c09b91c483,
+                    0x00005ebf7e1897da,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6604636301c31f76,
+                    0x952ee7daff3c0b57,
+                    0x949ecc12b1f95d1a,
+                    0x7c69bfac330a0211,
+                    0xc6975c9f76c75787,
+                    0x0193001ac6189765,
+                ])),
+                Felt::new(BigInteger384([
+                    0x80e7e1f484caa37d,
+                    0xab2e0090c6874764,
+                    0xd4034eb8704f31dd,
+                    0x9e2ae1b9765494cc,
+                    0x9015d99efb769ea5,
+                    0x01ab4f15ef68f11a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0fef9d3734fa022,
+                    0x1774d801d209f3d4,
+                    0x825e4fc5642defaf,
+                    0x5319c2b0bb026afd,
+                    0x9ebaa8522d0f27ec,
+                    0x00ac57dbd025b6fa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd3edd1e4fbbbb9c,
+                    0x794117b259bc0063,
+                    0x2cb044fa1178401a,
+                    0x9e5285a1de3af891,
+                    0xfa987a755ca79c9c,
+                    0x01032a968d87b1f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d1dcd6d1079459c,
+                    0x51c5325279d1c098,
+                    0x1f826202b2f96ddf,
+                    0xbac0fd28e1d173ef,
+                    0x32aa6b491a99f09b,
+                    0x016a01f73ebf7337,
+                ])),
+                Felt::new(BigInteger384([
+                    0x194e5c3441769379,
+                    0x17f406d17d5ee79e,
+                    0x3d56d347928a50ba,
+                    0x6876b20909f044e3,
+                    0xa5d53d316bcaefba,
+                    0x0010eb24272b085c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3446c54e4f5ed46c,
+                    0xd75bf1426a9a5f22,
+                    0x294f9d6742876a2e,
+                    0x81cdba88a8692151,
+                    0xffa8f9dcd7abe387,
+                    0x007c0e2a92888626,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x45fce48ea3a8ec66,
+                    0x6f449485dc6d1c0a,
+                    0x30ed7e2c1ef732d3,
+                    0xb9f8aaf1fcd12e9b,
+                    0x907ebe4deda51400,
+                    0x01abef82d2c2fc77,
+                ])),
+                Felt::new(BigInteger384([
+                    0x82b70923a863ce6d,
+                    0xef4a27b2ef6aa743,
+                    0xe38a65fc0f57dfa5,
+                    0x81af83148fcda870,
+                    0x5cd1c41e05cfdb5c,
+                    0x0128c80b0ae2befe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x99cc6a2dbb5a7f38,
+                    0x0f89e732bc66de19,
+                    0x15cf200b063bdc84,
+                    0x9ecbfa862fa61756,
+                    0xa305deb35d0a9682,
+                    0x0149f83a9f5a3dd7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd1cc15eb6fc49af,
+                    0xc7ce75a38c8dba09,
+                    0xe9529396da9af322,
+                    0xf6c215e1de7e7c9b,
+                    0x7ce4b84417d84757,
+                    0x003be43386ec9bc9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x14778906ec5a89b4,
+                    0x23c242d8e4e20a14,
+                    0x3be03433f657fd88,
+                    0x88cfb142e390a318,
+                    0x1aa989b375854895,
+                    0x003f49455e711968,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdae8e24462bb1fef,
+                    0x726de5347303cb2a,
+                    0x9ecb9b12916fc924,
+                    0x840422d655f360eb,
+                    0x7dc36d9ceb6f400f,
+                    0x009a39581e6845b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0c8b9050458d5254,
+                    0xecd983c98916b55d,
+                    0xa33a104ce66359f5,
+                    0x5157bd7643015cc5,
+                    0x95f9f9da81f0fd22,
+                    0x019dbb588b0ecb31,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d670f5729acc84c,
+                    0xbb02a213c5948ca5,
+                    0xae0b432115ce307b,
+                    0xaa0ecc8ee4cb0f83,
+                    0x9ac6883d161e9fc3,
+                    0x011d152af15f3dd4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2019710efb502133,
+                    0x8e2cfab3d78bd11d,
+                    0xebe357816a13a42e,
+                    0x19c854c49446369a,
+                    0x9b3467cb76cc549a,
+                    0x00d0dc04bb6ef0ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13294ec12108eb12,
+                    0xc7896ffa55eef716,
+                    0x90badf0ed00d2817,
+                    0xdcbf03d9c25d157f,
+                    0x6b5954443b048257,
+                    0x00864dbf9f6684a0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x75d59de8d181e8c8,
+                    0x9c95adc4628a2057,
+                    0xd4eeb210d89b501e,
+                    0x85cd8fe89f2b00c9,
+                    0x9642fb31e74f6bdb,
+                    0x0097aed5db1091a6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd8a7bd87f3b5cd24,
+                    0x67586feebc6e4def,
+                    0x77fe01c6170a6241,
+                    0xe177a07841c4b0d1,
+                    0x0996cce384607474,
+                    0x00606a7fb164a7de,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe38cea70d6943b0f,
+                    0xb5699d5bf7039867,
+                    0xa454a749a646e230,
+                    0xe368c8eb615aed27,
+                    0x8fb0a8ae732ae90d,
+                    0x0147e43193cb7163,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ee1cae4a000873a,
+                    0x42a738785ef4b931,
+                    0xb52cd2362409df93,
+                    0x8d5ebece4fcd14f2,
+                    0x28a5b9a296a055b5,
+                    0x008a3b091248df89,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4bac11b613c0531f,
+                    0xc45c954b7e0d526e,
+                    0xe4b25e5e342411e2,
+                    0xac3486558191eb57,
+                    0xa30ee355f3d094e8,
+                    0x00d7fb2a776c1166,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5762c92b73b65bb8,
+                    0x5529e906d81d4238,
+                    0x58da92353777c22e,
+                    0x08288bc583b9d899,
+                    0x1d7d7e663f9cd3b5,
+                    0x010bc154a9357c6e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x39986c1aabb122dc,
+                    0xbcf52d6a07bb6844,
+                    0xf334c0873a16658e,
+                    0x2a436591ec32e747,
+                    0x844362fe247eb88e,
+                    0x0100e98d8d51fb4e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb76a64ae5d5475b3,
+                    0x16e6efbc8684d2e5,
+                    0xd6b3371cf7d1edd9,
+                    0xced5b4928190473b,
+                    0xe4a4233409fc74f7,
+                    0x0126e1c4a1ceede4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x83f8e591d9ec5744,
+                    0x3cb85e70135b54e1,
+                    0x0b9f7e8d5cb30820,
+                    0xd9ddd83bdfda9264,
+                    0xf8d7aa11dac69f28,
+                    0x00497791b6dbbda7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xafc47d070bdf697d,
+                    0x594a674663903e2d,
+                    0x5b6a13ae7b12016b,
+                    0xfc0bd2191bffbfb6,
+                    0x43898a540d7d7321,
+                    0x0020f137dca71473,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfa5151bf4a6e0c9f,
+                    0x0e28954156cfbdd1,
+                    0x4bace68b7dc0942b,
+                    0x1875e77cde573c71,
+                    0x3ab4078d637cbd65,
+                    0x002ebb03170f11eb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbed43c777db51b77,
+                    0xb63eed9132be6927,
+                    0xe44f723733c58ee6,
+                    0x95173565726033e3,
+                    0xfc336c45bc0cee94,
+                    0x00bdbc84e19344d6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x37c0e18e0dc23edc,
+                    0xc927a08c716016ff,
+                    0xcd14b467ee4681c3,
+                    0xceb7d7932a663f04,
+                    0x5d45dd901f7d1da4,
+                    0x0046fb2a50e1c863,
+                ])),
+                Felt::new(BigInteger384([
+                    0x431e8bd073972a9f,
+                    0xef94d904c1163ecd,
+                    0x49f0f9fe903c5f1e,
+                    0x012b8a0cdf4d657f,
+                    0x659a246a08916d17,
+                    0x014e7546288a784d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe77b5f342a91c701,
+                    0xe594bfa6cfb9c302,
+                    0xd58db34a2e573d0d,
+                    0x8f5e48b2ee8b01dd,
+                    0xc0792693c12444e0,
+                    0x012b05487439ac65,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdcb20aecc2d620c5,
+                    0xdb27f2bc2bb39431,
+                    0xbb63591f55d2b579,
+                    0x9a5aaa4025282c1b,
+                    0x81128f79d2eff3b4,
+                    0x0117dc6fa00306e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x58db4a86758d583d,
+                    0xc79e887b0279a72c,
+                    0x76d264e178f1d179,
+                    0x4beac7193cf079c5,
+                    0x4a1e9c913c5d1e8a,
+                    0x00d3aeccdd0c31aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x37a5da0c90c35a3d,
+                    0x1e130c4c551ae2b6,
+                    0xbe10f378bc8cf35d,
+                    0x84b963bf30afafde,
+                    0x593d9a2e14de3440,
+                    0x0145359aecf37033,
+                ])),
+                Felt::new(BigInteger384([
+                    0x23b9dd35b0ed0770,
+                    0xea7f319c4affd4b9,
+                    0x576a7eff413f28f4,
+                    0x4224f6a8eade020c,
+                    0x5690c7d1ed5624b5,
+                    0x014a528dd99c12b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3303a3776b62ec6b,
+                    0xa136411abbc6d994,
+                    0xb24e905fbb87410b,
+                    0x9d74647a29fc6cfb,
+                    0xcdf635ace714b01f,
+                    0x01939d119aeab094,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x071b85644f8b6d6e,
+                    0xbb5275f3786fa691,
+                    0xbd875e79183d98c5,
+                    0xc86ec51529e9b715,
+                    0xd65e276e50e64965,
+                    0x0005472dc0884ff5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2e2cd41e1202282a,
+                    0xba6e80cee0f803cc,
+                    0xcd49eeb56c5eef20,
+                    0x6cf14ba94230be54,
+                    0xf4b1ba1a27357305,
+                    0x00fdf49643e0c3dc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8e5cba1367de40cf,
+                    0x1093cbfb8f10b683,
+                    0x606607b34f36eeee,
+                    0xead6adf966797361,
+                    0x8b096ec72484ef86,
+                    0x007a28648489f5be,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3ca80def77d7f1cb,
+                    0x7d80c9dedf25f823,
+                    0xdcdbeb32f303ed8e,
+                    0x8950dd213f24d397,
+                    0x2d2ccdf835d9bcf8,
+                    0x00dc34d33f9dbc01,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe97ef1e577e7047a,
+                    0xfa7302c8d27bbc36,
+                    0xf95069f95676585f,
+                    0xd443170de6e90714,
+                    0x7f3109b392d62ca7,
+                    0x00fe52a87d9c84e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a0d26fc79bb5569,
+                    0xbed7a761f40793cc,
+                    0x8b9a78e4568b6655,
+                    0x58d0be747284617f,
+                    0xad6aef76a6e7770c,
+                    0x005d0b60d04d9e57,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7327206c25289fd6,
+                    0xbd11c2db72b1b73e,
+                    0xdc8c88cfeb7dce67,
+                    0x1474c98d78ee9344,
+                    0xfd5c40afb1724b35,
+                    0x00dd3d446c1db661,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0ed45506422e024b,
+                    0x79a4f79b16ac5a2b,
+                    0x3f21a7773484ff24,
+                    0x0d3686517f71e58d,
+                    0xb7ef5328e85c045d,
+                    0x00d09f7cddd831bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3fd7a510d5579e0d,
+                    0x7c3776d6f9ef7cb0,
+                    0x447489532fb890cb,
+                    0x0881d8bd8aaed7a9,
+                    0x3d8f2e5b15d5d52c,
+                    0x00ccbf7fda964cd6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe48078d5444ee39c,
+                    0x95c224b43e84ce50,
+                    0x3287f7939e85a1c5,
+                    0xc1b707d6b9994456,
+                    0xe2e92fe48dcea58a,
+                    0x00bffc01e3d26937,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x48b7b7388ba0b750,
+                    0x3985688bbf5d490a,
+                    0xc6a1c9cc26607a30,
+                    0xdb308b6b5f047f06,
+                    0xc51270cbea3bbc08,
+                    0x00043e4e5ea44336,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe50741ebb48081da,
+                    0xedeab8adfc23a553,
+                    0x13c1ca1296c42f79,
+                    0x18c11b3c5bb412f1,
+                    0x108b08713cfbfee9,
+                    0x003180aebb91b233,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc142143526de22f2,
+                    0x636a297892e5d40b,
+                    0xf1accf8229187f8c,
+                    0x1630975a5fa10600,
+                    0xbb34740cab8b2850,
+                    0x015f31e68c5634b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53f16b46aa59108c,
+                    0x15f4d319fe868002,
+                    0xee495b6b5ac5e783,
+                    0x32e4bb2934c6b32e,
+                    0x8eb14bb486096df6,
+                    0x0044ff99190fb2f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x219fecefabc2b326,
+                    0xc97344a2b267ce33,
+                    0xfb47098ef66c70c6,
+                    0x065398fe42f58a3f,
+                    0xcb61e8a4ae0fa058,
+                    0x008d02f63c76c574,
+                ])),
+                Felt::new(BigInteger384([
+                    0x613e99bba52e8443,
+                    0x3917f384b654ed93,
+                    0xfe47da5bbf7f3579,
+                    0xb094225492612a42,
+                    0xef7fb32670fbd513,
+                    0x0048a10270995af5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55aad7fa4aaf6221,
+                    0x457b33cee72d6bfd,
+                    0xd7d0a39b606afdd5,
+                    0x812dd8244c3f81ef,
+                    0xc2720c12dccaadf9,
+                    0x003f00cf46fdd826,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d75efdd8764c15f,
+                    0x306ca44cd394631c,
+                    0x9e27ae5950e14f91,
+                    0x4c666f28d60f3fc6,
+                    0xc18a3cbf4da7a67b,
+                    0x014ace96195c9d12,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4febcb5a3cd4d1c9,
+                    0x3b9bb85627e36b69,
+                    0x430842893c9a4538,
+                    0xa8eba7eb54b44568,
+                    0xb613ee35c617a2d2,
+                    0x00ca10952a684f68,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee931113b52d1f78,
+                    0x3a9308db44dde929,
+                    0xec5691247063a731,
+                    0x5e88964087d9b0e5,
+                    0xe23bae186d984d9d,
+                    0x015ebfeb02f68df9,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1320fffffffff7ac,
+                    0x155b44203ffffbac,
+                    0x3d124a86aab45f40,
+                    0x55c0e43c06f91c12,
+                    0x1c74197750ca1fca,
+                    0x0102b0420acf1cc5,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x58e26f848c6090e7,
+                    0x5403f0a007c0763e,
+                    0xab53d09dfa4ec0b6,
+                    0x69f995d8355f81b0,
+                    0xdf4d80e59a8d06dc,
+                    0x00f6c302dddd51f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x22e0c704199731e2,
+                    0x77e0b9612f8badae,
+                    0x38ac7f81cab7742d,
+                    0x6de39a7428326c28,
+                    0xb840eaa09233f3a5,
+                    0x0134461d4e991446,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf6be4e780867d9ba,
+                    0x699799ad01366bf7,
+                    0x406b5ce5f78711ef,
+                    0x222ba6d64fe72fc6,
+                    0x5cf7ed45572960fe,
+                    0x005138081be4b2ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0x971e7df04b592c6e,
+                    0xd573bac0d354ba43,
+                    0x8a664847e67ead3c,
+                    0x83fc7d02feaa6de8,
+                    0x9292b17c3f523c65,
+                    0x00959c4a08b25328,
+                ])),
+                Felt::new(BigInteger384([
+                    0x30a36c4675a24a0e,
+                    0xd4fdecb21ddaaf14,
+                    0x2da22fdb43946712,
+                    0xef3440ef6736bb2f,
+                    0xc15e942af1f3aee0,
+                    0x005d60f16ae1c06e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x083ab582a2a22df9,
+                    0x896ce67b5045b785,
+                    0xb97f9c8c92eed74e,
+                    0xd0a3e8b022b98925,
+                    0x1e1fb75f48188d36,
+                    0x0134f4d73d92a618,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbb4232719bf580e7,
+                    0x21a1b3d51fd396df,
+                    0x07fe585998f34c66,
+                    0x6fd36a06226f2e37,
+                    0x91fd79220523824a,
+                    0x009aee569322fe3b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6edc86977c1192bf,
+                    0xfb31f05da61fe6c2,
+                    0x46734c76f6116fbb,
+                    0x3189ed5fbdbb30a4,
+                    0x378c04445d3a26ab,
+                    0x003ee2dfdbd83405,
+                ])),
+                Felt::new(BigInteger384([
+                    0x51ccd9363b179f29,
+                    0x8f28cc2383f9dc96,
+                    0x28c6176d277fb54c,
+                    0x0863613db15528d4,
+                    0xf09ece2814489afa,
+                    0x001927b73b7be42b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe706ca91872ba08d,
+                    0xa76885e8b3bab021,
+                    0x36be2ffb83326739,
+                    0xc9e06addabb717b3,
+                    0xe7cd1df69fe558c3,
+                    0x0036585f1c692e34,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x97b61ed8f7d20fb6,
+                    0xebf8c446bbad7823,
+                    0x8bcfacb9d8f80038,
+                    0x1c74a9b00294dd2b,
+                    0x701d93559669cd09,
+                    0x00777c0838c30e95,
+                ])),
+                Felt::new(BigInteger384([
+                    0x906b12526c69ef79,
+                    0x704fd4f3e32467fa,
+                    0xc0cdd92a035791d3,
+                    0x12e7e560d2942219,
+                    0x480881ae2c48cda3,
+                    0x00bba7993032adbd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2190868e0ffd1fb4,
+                    0xf3c953d596646ad6,
+                    0xf091355cbc28b1c9,
+                    0xcf368fc77376e562,
+                    0x5022d3dc440c0693,
+                    0x014d8c5004eb4b59,
+                ])),
+                Felt::new(BigInteger384([
+                    0x12166f84d8a54e58,
+                    0x74d25171c2640bc6,
+                    0x9ca206c9000fce50,
+                    0xe2fc0f60dd5a11ef,
+                    0xf35bb3166d1596da,
+                    0x0173e8dbd0074386,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d26789109864ea6,
+                    0xfbd107db1fef367d,
+                    0x4391f9544515510a,
+                    0x78002142a11d220f,
+                    0x18ca85fad435f60b,
+                    0x01a38930e345efa8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f3c192ed2f5ca26,
+                    0x226a204f4c1d8985,
+                    0xb820906bf5985512,
+                    0xb2ff2a379615d557,
+                    0x7f320c18f5eb2cfe,
+                    0x00345ca435bfe549,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0913c74c6ec6c60d,
+                    0x51682a56986dfa7d,
+                    0xffd4e41be56ef350,
+                    0x2fc49bf15f543dae,
+                    0x2b1e568a10602376,
+                    0x008c9658fdc4440e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2be034922b1f9ed5,
+                    0x380d0c6ed6c7be5d,
+                    0x359324f36ab0f9f5,
+                    0x2f030c39d3dc7f7e,
+                    0xe58d61be18d01768,
+                    0x000e208ec3381050,
+                ])),
+                Felt::new(BigInteger384([
+                    0x418a549de3afc924,
+                    0xfb14643c30442a7a,
+                    0x75375b95adf7b5f4,
+                    0xacd8698d52373f58,
+                    0xec52a95af1b49606,
+                    0x011087b40f7a5082,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31a96e9bb7bbe747,
+                    0x246e3a5338b23e57,
+                    0x15a6e0f415965dbe,
+                    0xed93909a5e81bccb,
+                    0xc43f222f263c4339,
+                    0x015ccb5df9b2bfb5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x90d31ee42bb82396,
+                    0x7a529288d618b869,
+                    0xcc657d451b1e80ac,
+                    0x07300a55c64ff039,
+                    0x7b6af6d656fd2379,
+                    0x011b3c3e5778fd54,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b8aa50b10d67640,
+                    0x91dd9e62c537fd58,
+                    0x5c8b289092d9534f,
+                    0x38244cf0afdc2c56,
+                    0xaf9bfb08f0155f68,
+                    0x013633452a348122,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcee455c2ba6a6075,
+                    0x48feaa1d18704fee,
+                    0x18887acd9a2fbb2d,
+                    0x71433af6f697d995,
+                    0x4b59dcc9f936850c,
+                    0x0033bd3178b8be7b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf239c0a92802f086,
+                    0x7af279fea96b9e69,
+                    0x74964531215425ca,
+                    0x251547a596faf259,
+                    0xdde9eb0205efd5e7,
+                    0x00fd040734d416cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x85e108713ab733ab,
+                    0x2fa1f30cacb85c29,
+                    0x831980d842f3bf68,
+                    0x8a14b9a9f4b9a697,
+                    0xbab365969a46f783,
+                    0x004e43ce82a65862,
+                ])),
+                Felt::new(BigInteger384([
+                    0x57c1a37408d80ee2,
+                    0x25c7905d9f257d9c,
+                    0x7bf2b221a771d45d,
+                    0x1cfe64590b29bdbc,
+                    0x15e108f513ae8bea,
+                    0x005b9a2f9d828d89,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa018288842e2694,
+                    0xdaabdb942baef483,
+                    0x5e4eaf1069d24ebd,
+                    0xf3f912575b918a35,
+                    0x0dfd324f36609fbe,
+                    0x00f1646fc7715da7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x121e25edc259abd1,
+                    0x5a8858446ec91942,
+                    0x9744af3b275e938e,
+                    0xeaa97524b8f15447,
+                    0xb17ec352729ab7db,
+                    0x019023ea471263ac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x074219153998b8e3,
+                    0xe6454014adbbad2e,
+                    0x6918ef91b35c244a,
+                    0x62d97e57c419d1b0,
+                    0xb735ee5b9ca1a8ca,
+                    0x0024ee7bb8ba8927,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21d16abbda734c2a,
+                    0xabfbeeb5f2a5ad96,
+                    0x4bebcbf24b04f8e1,
+                    0x8a1422affc324f78,
+                    0x6bcf8fea7e19828b,
+                    0x0092fb75e7925aa0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4f0dfe27949f7224,
+                    0xb8c9f9efa0a83637,
+                    0x121b2f198ebe5e1d,
+                    0x344641a8ef2343cc,
+                    0x3be236cb6d931109,
+                    0x010ec09f2c2e78b3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2340570404aa3549,
+                    0xa0fed274b856fdb1,
+                    0xa61a155ea4a95715,
+                    0x824737a946d063b9,
+                    0x0c4e54a35f776297,
+                    0x0070760e61a81bd1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b90404d9f507a48,
+                    0xbe1edf0c0a9aa3e1,
+                    0xa12eb29861384b39,
+                    0x469ba2e885c93d8a,
+                    0xfcf59485868889b1,
+                    0x011ef790235c912b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x178039e6b912bfa1,
+                    0x6a37bc61d71952df,
+                    0x195d2710c626fd1b,
+                    0xcf542ce00289d91c,
+                    0x806e4f8bcf4c13c4,
+                    0x00a69c669d931416,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2eb127a4671bf68a,
+                    0x5ebcad4c29b45248,
+                    0xba7c56f57578b096,
+                    0x0b01c53b24605c2f,
+                    0xbb7f7cc275cbaea7,
+                    0x0121cf3daf9fa265,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe4e000b194d21e45,
+                    0x8b5e470a22315352,
+                    0x77cbfe965019ff44,
+                    0x0b4d707e8661fcab,
+                    0xa5f5f8c595f367a1,
+                    0x00ef57c8f4db158f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x762c81fbdaf4632a,
+                    0x6f872f2ade9dd8cc,
+                    0x55820cce5a1244bc,
+                    0x99026aca56385a20,
+                    0x98811c005f583dc6,
+                    0x00ac2ea592ab1040,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb92f4dcc0ed87a46,
+                    0x02a062ef8d4e751e,
+                    0xb6f2fbf106730926,
+                    0x8ab9bf7962e599c2,
+                    0x9659ed53d55a9c4a,
+                    0x00ae3fb193943758,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfe318836468fa402,
+                    0x109baf210a5215bc,
+                    0x68f2ed552a4b7d2f,
+                    0x8df41ac4566d2018,
+                    0x4b1bb6e89397f4eb,
+                    0x018eeca1effed291,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb31849c4c7504385,
+                    0xcf8eaa24f45f7644,
+                    0xf1262e39b4a63217,
+                    0x70cc102c67060772,
+                    0xb272e6f8dbb98ce9,
+                    0x004a0699ab778cd0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4444f8f5d00b358d,
+                    0xf77a60837f9c5b84,
+                    0x4450d5d4926914c9,
+                    0xa3a5e6c318e8cbfc,
+                    0xf77273be0f78a6c6,
+                    0x00c4c7e475ee17f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27e41a8b4ebd7364,
+                    0x79bf8e3af197d468,
+                    0xe15caf857f89e416,
+                    0xc6655edbcf790e01,
+                    0x1af067be1a9a9625,
+                    0x016780e2e14b4d43,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf1a417dadfa3be8d,
+                    0x9ba727b83c409089,
+                    0x8701695322408c56,
+                    0xb0b41d28482a8e6b,
+                    0x77937d53fb5f2901,
+                    0x00f515791ba8fef3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa7d982db2dc1233e,
+                    0x8a5ab896aa2447cd,
+                    0xaa1ac92ee79c5768,
+                    0x984b7026a918ebeb,
+                    0xa08249415c4c1a9d,
+                    0x018c103c45f3c944,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc5a7a19ef429a693,
+                    0x109fecd865d420e7,
+                    0x36e4fe4053886e08,
+                    0xa0a3acf8a744734b,
+                    0x9c47fde36ce986b1,
+                    0x001dc3a6cb653dec,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc9a4ae85941b5c0,
+                    0x496324614565a3b8,
+                    0xd26f1c1637cd9eb1,
+                    0x7b726ff9b69e96d8,
+                    0x7d8d6f0b35e94a57,
+                    0x007e20ce03ad36a4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0af9e2a5852abff9,
+                    0xf0a1760ae9c12535,
+                    0xfd95bc379142ae9d,
+                    0xa1ba1e6958dd8ec3,
+                    0x678bf5b89b50b03c,
+                    0x013c8f7d4ad76ef0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b0d3dab66610cad,
+                    0x18bc438d30afa634,
+                    0x0df207092a38eae7,
+                    0xcbbdc60cf70a93f9,
+                    0x1074acac5c286b1e,
+                    0x00169281a662fddb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf4560e8360f237ee,
+                    0x553415aea7005ff2,
+                    0xece10a461c0193dd,
+                    0x1a785c3b445cb6cc,
+                    0xf68ff352cbe28224,
+                    0x00e19cfe262b0c62,
+                ])),
+                Felt::new(BigInteger384([
+                    0x515db6e91056e65a,
+                    0x3c3cb456ab1453de,
+                    0x7a51a62a5adeee34,
+                    0xe2b2896c2ec3e465,
+                    0x91fdb001a206a85b,
+                    0x0089b38024cac5e8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5547838cb8ed63ea,
+                    0x3c94cc282a537188,
+                    0xd525171e8b7eda3d,
+                    0xb92cee86f7add5b9,
+                    0x21fd786af5c7e886,
+                    0x016f78cbf2b4f6fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8a225fce43983796,
+                    0xaa84be31b10e9f65,
+                    0x60a8feaff5affb9b,
+                    0xf49b4d3e3eabaf82,
+                    0xf2412aa8ecca0c31,
+                    0x014c80352e9d43d6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0c04ecc78984b370,
+                    0x334d4d8732cb1d73,
+                    0x11349cc87e67ff86,
+                    0x10521f73a1f48ba2,
+                    0x86965b943c5bcf89,
+                    0x00f742794fa99e0e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x10f42a988d569fbf,
+                    0xc17d311dce11e2dc,
+                    0xaa1c0d454ab09a7f,
+                    0x1a13165fdcf5c280,
+                    0xb455cfff214d9976,
+                    0x00199657a68fbdd5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae09d321441ace84,
+                    0x6bfc278bea67d771,
+                    0xef82aff56333dd25,
+                    0x1a76138a37a78bcb,
+                    0x5f71a67be915fb3f,
+                    0x01429fd01d632d3b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe534eedf972fac04,
+                    0xa63ab689b58c59da,
+                    0x2e1e54ce89ee1531,
+                    0x188d280615f9b37b,
+                    0x37432912dc3de562,
+                    0x00b8c8388fcdfca8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd13fcd44ede7ed3c,
+                    0xe85075fd37485c40,
+                    0x3ba554d91c8bed6b,
+                    0xd6735a1e844968d0,
+                    0xc963217451467e2c,
+                    0x002783a3fb29e66c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2dff38ddb142cd63,
+                    0x9f3e70e22e5bbccc,
+                    0xe68b0bcea55661ff,
+                    0xff1715d4ed3d465c,
+                    0x92e7f346f24cb096,
+                    0x005f620e8b52fabc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x38106af78824e881,
+                    0xa5959315a67dc031,
+                    0x80240f476774be87,
+                    0x7409298bf8fd45c1,
+                    0x083bfcfefe413d45,
+                    0x016c92f8f3670d3a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcf93c41a03410ef5,
+                    0x6acc37b99022be57,
+                    0x62c65760669f3ad8,
+                    0xc238074b5337592c,
+                    0x083266c50e0b3c03,
+                    0x00f83d4efdf68683,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
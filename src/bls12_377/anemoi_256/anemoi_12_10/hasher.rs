@@ -0,0 +1,3416 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U10>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger384([
+                0xa2b466203803ce1d,
+                0x47ec34ad68de5caa,
+                0x40af7484b4bbb4a1,
+                0xee7f06234aa78c2a,
+                0xb4e83d1bfa1383a0,
+                0x00b3830f015f4b83,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe9814b7b5c5b2202,
+                    0xf4c1459f8247e9ed,
+                    0x231e36af65c22cf4,
+                    0xa083b8a1ed55ff8b,
+                    0xe96e59470c959d39,
+                    0x008db365f5f16a6c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xef4b4b4eecf11afa,
+                    0x078d1277c5385319,
+                    0x70bd5c37c1c1aee4,
+                    0x7d07fcdb62fc9b58,
+                    0xbd49ef082f9ae099,
+                    0x01761f60c6c61d2d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x4ce49e9f226bcdf1,
+                    0x625d9feeaa23a598,
+                    0x87d010d536e443ab,
+                    0xd84ae2630e598263,
+                    0x152d9238ceb1342d,
+                    0x0111a88032d09c78,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9a01a0bd36bc5ada,
+                    0xaa53b556239964dd,
+                    0xe4eaf60ed3c739b3,
+                    0x3dec93c4f5384628,
+                    0xdd903a0879c4995b,
+                    0x00cb998abc84c30f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e6208b9336c89d7,
+                    0x846973928fcb6a91,
+                    0xbc65a3554df4400a,
+                    0x790cc57794288430,
+                    0xaa373d805f320ec0,
+                    0x009bfc9a54a52b70,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x629014293a395d15,
+                    0x263f7244e373165f,
+                    0x2238ee96ea1d6dfb,
+                    0x0add9ac2f16bae9a,
+                    0x4a7b39aa9f175dae,
+                    0x006c539ffc90e0c7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xab179d2285153c9c,
+                    0xbc340649db18645e,
+                    0x4d646b994c4a2cb7,
+                    0xac2616fc7d8e5036,
+                    0xbabd756e01cd3f3d,
+                    0x009502d6cbd4cb13,
+                ])),
+                Felt::new(BigInteger384([
+                    0x628f5752c5ef029b,
+                    0x37b2b3d8d92e9e83,
+                    0x5cee20737266fe28,
+                    0xb387d55244264c37,
+                    0x386e6173be05aafa,
+                    0x000e51a6a1a5f142,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7ad6f3e7499f6e96,
+                    0x8e91a5eda4bada49,
+                    0xda886bf08fc4dec9,
+                    0x6ed06dea8cab765d,
+                    0x89c2af0d164d6cf2,
+                    0x003967645c9504d6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x6345efda23aa8495,
+                    0xb5371ae0448872b7,
+                    0xa1c093b2ea605ae2,
+                    0xeb5291e4d86e4fa6,
+                    0xc5b6d940663688e4,
+                    0x01952b0efa811b17,
+                ])),
+                Felt::new(BigInteger384([
+                    0xadf1253b81a42b4d,
+                    0x3634a0a0d6f30aff,
+                    0x471930fbbe11474a,
+                    0xe17562a798e47243,
+                    0x04d892ca4563df2a,
+                    0x00769bc971d0beb9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb75fb599e0552cf9,
+                    0xc9039f4cea4187c6,
+                    0x260ff9b00ff6b51f,
+                    0xfa0bcca7c6e28bcc,
+                    0x17862d9e916007fc,
+                    0x01012290fee52ddd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x90b1868c5eb6ea9a,
+                    0x6e842daec0ddc4fe,
+                    0x8ea24d8ae86820e7,
+                    0x775b3678734b3c4a,
+                    0xcea8f6ab87ba0fca,
+                    0x00d17ae96e06ca39,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa13c222a89d719d3,
+                    0x87ba4bfb1ffdc38b,
+                    0x2d9c79e15f0c24d0,
+                    0x8ec9d19da9aa4c2e,
+                    0xfb807d05e7b5aed0,
+                    0x011b22d8957f50aa,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xdc19f189802cfa74,
+                    0xab3108759b1b685b,
+                    0x3fe94bcf9ecad2d7,
+                    0x9382e60987bcde0e,
+                    0xa4a388a2d552522e,
+                    0x013a819725004614,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40a9750bcce88f15,
+                    0xbc54ec009fcb3f0a,
+                    0x4f2313babb2c73a5,
+                    0x555bf8660acd768d,
+                    0x9a132c5dc50292e1,
+                    0x0080541867a680fe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x478398a6a447a772,
+                    0xc1553d57482900ae,
+                    0x564e48035579e3df,
+                    0x790a83e1f2e2a3f9,
+                    0x14104a845f947802,
+                    0x0170703b74d35872,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd151afda8bbb2207,
+                    0xba869fd8ec5d636f,
+                    0x8b1c5e39d73b8cd6,
+                    0xdc8b87b8e21ca4c1,
+                    0x0e0fac5fb1c95aff,
+                    0x0023f44d3627a187,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb461c358bd9a93d,
+                    0x2e8f5d91d19dbda6,
+                    0xbacb16d0f35021e8,
+                    0x388e3d6e3ae61afd,
+                    0xeafe9bd28e64567c,
+                    0x012a43d03a92f16a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3cce64c49b430da6,
+                    0x0786512f7e5bafaf,
+                    0x9d58cdce15ad6c7f,
+                    0x04a6b1f6a0597cf4,
+                    0x0e235b98f3f81a85,
+                    0x0113e320bb8396b1,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xb945d092e1d1ea27,
+                    0x38571b3ce7d1b78a,
+                    0xc3cad6c4c53ba6e9,
+                    0x2f4819ae44bd10c0,
+                    0x2dfb4ea74dd9b038,
+                    0x017322c862137a9b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe6c2cf9450c0e84f,
+                    0x6445095d47e16fb1,
+                    0x0cd21d2ada57ce4a,
+                    0xdb4549cb82ddc8fa,
+                    0xa6cb62c09d3033a0,
+                    0x00d8af9f684246dc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xdc04e7f9877b3e36,
+                    0xc883283e1ca75b52,
+                    0x4420e987af52ffaf,
+                    0x605a0203da9b6a42,
+                    0x59ae617423bedc6c,
+                    0x01a8ad171f480924,
+                ])),
+                Felt::new(BigInteger384([
+                    0x99dcb9c0d38a9471,
+                    0xfcf0e0a8c3073b7a,
+                    0xfc35de40f852839a,
+                    0x64d699c94d4b231b,
+                    0xcf1bfcc48e08ad86,
+                    0x007311edf00368ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5cc4b21d8ea2e63f,
+                    0xeca9f871d6d19111,
+                    0x47abfc3abb3582cf,
+                    0xd568ca12fdbe861c,
+                    0xadad939761241739,
+                    0x00d6bef4a78702bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x017ef6c9df9593d6,
+                    0x27568fa91bb91608,
+                    0xee1639e6a9bc4107,
+                    0xa36ac18cb6c5a997,
+                    0xa78aa179d1e2f72e,
+                    0x010624712ceffc2d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8467cffa50e8161d,
+                    0x6a90914a508fb065,
+                    0x8f411645ae2f0b9a,
+                    0x44404a11f52549d9,
+                    0x49ebdcbe58b06355,
+                    0x000d4660d65a7229,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb2de32bd8133b70,
+                    0x98f06cb4f7f51b5d,
+                    0x4c92599c49911070,
+                    0xe86f289d384ee8ac,
+                    0x6985c976913c46ae,
+                    0x005f96bd9b0edae4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x715cf91d8668afb2,
+                    0x86440cdf68525706,
+                    0xc8f94c4d2f72c867,
+                    0xdb157d7df4f66c2b,
+                    0xed8e1bb473b91bc5,
+                    0x00aebf452f0fb084,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe17aae8472dc8247,
+                    0x7918b8d7d3c734b8,
+                    0x255706bcd3d55f93,
+                    0x4db56565490a7da1,
+                    0x2870eca19effeb98,
+                    0x002917f4492ee4db,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x77490970ad72d065,
+                    0x3b16c34e3193e0cc,
+                    0xb2b6ad7cad8054b6,
+                    0x6f2df21c88e8faa2,
+                    0xb790301547a2dfac,
+                    0x0130337bdcbe35f6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf708152b23c01a01,
+                    0x6dea643c09f5600a,
+                    0x529f6b21886bf63f,
+                    0x92b5f059e0d9ec3f,
+                    0x309edeb5a0a29454,
+                    0x0123022ccb2a3890,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x18dfc4de44203d94,
+                    0xeb14c0767833bf19,
+                    0x1fe83ff743c43476,
+                    0x51080b0d227735a5,
+                    0x7ad8336a1c9df0d2,
+                    0x01a3597b88a60767,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1a4eed27bab3f31f,
+                    0x8aacbe6b2b49b3ae,
+                    0x429d574ba829f48a,
+                    0x116dbba28f2bfb3c,
+                    0x4261de02eb2c8a6a,
+                    0x00fd085a7d96a922,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x17a707a86de4c193,
+                    0x6e9251964118b02e,
+                    0x2bca080ddd671c0d,
+                    0xee9ad190797960dc,
+                    0x4e6b9c459d194326,
+                    0x0106c67085afa167,
+                ])),
+                Felt::new(BigInteger384([
+                    0x419a9bea478aa671,
+                    0xc3e76012f0b64eeb,
+                    0x6afc521161491aa2,
+                    0x2c516962e7c60eb7,
+                    0x53da1d256764f7aa,
+                    0x013192a903cef2ad,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3764de84f26f0f49,
+                    0x5e4d1b86016efa7b,
+                    0x3565c51e1942b4f7,
+                    0xc1af08f94ab0da73,
+                    0xf19c3310bd936681,
+                    0x00fada8ce86e0beb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa9b81d62b0a32b09,
+                    0x684bc5351cf2cc1f,
+                    0x6289298f5e0c4e3f,
+                    0x1198117c5812c764,
+                    0x3b7a010e61993959,
+                    0x01a1382ade10bf30,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6450f97c3d81ec38,
+                    0x35be3f1350aa688f,
+                    0x2d9807dd6759abba,
+                    0x96a92782fba95e73,
+                    0x17be45e9867ad646,
+                    0x0102f67b076702aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0745498370c54fbf,
+                    0x63797130d6ea2344,
+                    0x1a122b30806f5f8c,
+                    0xda40731f3b05dd22,
+                    0x8e82fd60f4376ce2,
+                    0x016a08432bc3ad48,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xb945d092e1d1ea27,
+                    0x38571b3ce7d1b78a,
+                    0xc3cad6c4c53ba6e9,
+                    0x2f4819ae44bd10c0,
+                    0x2dfb4ea74dd9b038,
+                    0x017322c862137a9b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe6c2cf9450c0e84f,
+                    0x6445095d47e16fb1,
+                    0x0cd21d2ada57ce4a,
+                    0xdb4549cb82ddc8fa,
+                    0xa6cb62c09d3033a0,
+                    0x00d8af9f684246dc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xdc04e7f9877b3e36,
+                    0xc883283e1ca75b52,
+                    0x4420e987af52ffaf,
+                    0x605a0203da9b6a42,
+                    0x59ae617423bedc6c,
+                    0x01a8ad171f480924,
+                ])),
+                Felt::new(BigInteger384([
+                    0x99dcb9c0d38a9471,
+                    0xfcf0e0a8c3073b7a,
+                    0xfc35de40f852839a,
+                    0x64d699c94d4b231b,
+                    0xcf1bfcc48e08ad86,
+                    0x007311edf00368ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5cc4b21d8ea2e63f,
+                    0xeca9f871d6d19111,
+                    0x47abfc3abb3582cf,
+                    0xd568ca12fdbe861c,
+                    0xadad939761241739,
+                    0x00d6bef4a78702bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x017ef6c9df9593d6,
+                    0x27568fa91bb91608,
+                    0xee1639e6a9bc4107,
+                    0xa36ac18cb6c5a997,
+                    0xa78aa179d1e2f72e,
+                    0x010624712ceffc2d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8467cffa50e8161d,
+                    0x6a90914a508fb065,
+                    0x8f411645ae2f0b9a,
+                    0x44404a11f52549d9,
+                    0x49ebdcbe58b06355,
+                    0x000d4660d65a7229,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb2de32bd8133b70,
+                    0x98f06cb4f7f51b5d,
+                    0x4c92599c49911070,
+                    0xe86f289d384ee8ac,
+                    0x6985c976913c46ae,
+                    0x005f96bd9b0edae4,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 564];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+            bytes[188..235].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..47]);
+            bytes[235..282].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..47]);
+            bytes[282..329].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..47]);
+            bytes[329..376].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..47]);
+            bytes[376..423].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..47]);
+            bytes[423..470].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..47]);
+            bytes[470..517].copy_from_slice(&to_bytes!(input[10]).unwrap()[0..47]);
+            bytes[517..564].copy_from_slice(&to_bytes!(input[11]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xbe2128ec03b9e09e,
+                    0x78c790397098998c,
+                    0x4326263d67968873,
+                    0x8909f72d1d412ec1,
+                    0x2fe22dd2275635ed,
+                    0x0005486c61d64c8d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf2976970e81f17a,
+                    0xab365444653ce438,
+                    0xe86a3ecb06e0a56d,
+                    0x6b05dfab94d580a7,
+                    0x91b8ed4f8484938c,
+                    0x01ad63c51cfe939f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x355eb7dff10958dd,
+                    0xb4c7c8a066694b2a,
+                    0xa1ddcb7862365110,
+                    0xfb08539a99e23075,
+                    0x2021039a69ded9e1,
+                    0x0022bc4cdb4ab598,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdbded934ea75de48,
+                    0x8d459d905858a156,
+                    0xac98688f1f090126,
+                    0xc97bc983ecf9e905,
+                    0x83d78d57962d7a59,
+                    0x014d6c851d261048,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f115f2d31715bb0,
+                    0x445a1c745be49abf,
+                    0xe85eef5b9194c7f3,
+                    0xac42262b9c7828bc,
+                    0xe509dca4f6bd6af5,
+                    0x003edc4fa13e3fc8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x410c5adf19ac0bb4,
+                    0x2057dd0aaab2e4fc,
+                    0x1cc4ecf586569665,
+                    0xda2245a00aa8089a,
+                    0x068336e4dca8bfdc,
+                    0x0042c9e1110aafc9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c92f249739d41c9,
+                    0x80687586781cb049,
+                    0x5e3d8ab987ace3c8,
+                    0x23af56ca79aa38ab,
+                    0xded68cf9dff7e94d,
+                    0x01a5d27f271b339a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x33d7b62e55988fea,
+                    0xec890b65458ac399,
+                    0x49766029182de169,
+                    0xeb8d7190ba25fd99,
+                    0xe4095bd48bf84b0d,
+                    0x011400311e964b30,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f254362132749df,
+                    0x8349301c35441ec6,
+                    0x90b5835266d72939,
+                    0x1716f7609c224abc,
+                    0x156bbd03e8c50d69,
+                    0x00ffc3b9de152aa8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a7ef263bdf0a2ee,
+                    0xa2b1be96c15071a9,
+                    0x80ea19e179a3bce5,
+                    0xcb3dfba031b6bf7f,
+                    0x0488e13ddc715485,
+                    0x0197f66443b8abe6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb44a4cd6c56630b,
+                    0x3361690f64d9d5c4,
+                    0x37b5bc0a7b7146e7,
+                    0xff11ec291baafa65,
+                    0x1d9483996edba647,
+                    0x00a6fd6b4c08aa40,
+                ])),
+                Felt::new(BigInteger384([
+                    0x91710fbf0fb26bbe,
+                    0x96d5da0dabccaa41,
+                    0x68e4b46524ed9974,
+                    0x4d56f8506dfd1ad7,
+                    0xa507f50e4c425cbc,
+                    0x019e3c18613932ea,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x6c000752d77e6e49,
+                    0xd1d58ffd73eea485,
+                    0xe77cb214cc79df0f,
+                    0x8fbdf1f42ee147d7,
+                    0x1d5b3acc4794665c,
+                    0x0098d0c54817fa72,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe642c199e984674b,
+                    0x616a57776e46c64d,
+                    0x47f296f4ec197ca5,
+                    0x42227045d8690fae,
+                    0x956d6ba3fead4174,
+                    0x015dea969f2afc0d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x603c65ba2e96a72b,
+                    0xad13676132762333,
+                    0x66f0980040fe2b8a,
+                    0x96c3a238ad2f662c,
+                    0x1da52f0ef1b5334b,
+                    0x00e6f5707f97dd9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6333961da4fddc10,
+                    0xff34c1cb6765db3c,
+                    0xdb4e916be4ffb207,
+                    0x842ab18e97b0f99f,
+                    0x8bea9f63b31c228f,
+                    0x009ecb5b8667b2f8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x292fbe379c12b6be,
+                    0x836e11a47f3763b8,
+                    0xb6951ab960fad47b,
+                    0x013ea7b19c4878b8,
+                    0xe7a565fd10504659,
+                    0x01a0a608abc239ea,
+                ])),
+                Felt::new(BigInteger384([
+                    0xff967b859e052569,
+                    0x0ab8a0fe70d1ca50,
+                    0x359daa7ec62c5317,
+                    0x944a821b5af8ec04,
+                    0x7d6303b5a75ee607,
+                    0x0164dad24899ffed,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93adf763e735cfcc,
+                    0x873debfd7e356acf,
+                    0xe4eed7eab3b7ed59,
+                    0x849fda222edd5aa6,
+                    0x0c9aa2f453c6f8fe,
+                    0x01148c55abf24b10,
+                ])),
+                Felt::new(BigInteger384([
+                    0x703fc25fcfeefe23,
+                    0x23c20582a12770a2,
+                    0x8b1428add1180233,
+                    0xafe79ec5bc511fea,
+                    0x8dd162bbf609ce59,
+                    0x00764625fcca781e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x20ce7c3813c64959,
+                    0x4748b12789019ea8,
+                    0xaf3eb71026d2d04b,
+                    0x8d290e60ddc3e4a6,
+                    0xda5adea9c33d2de8,
+                    0x0053e6277f6907d0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfeee1b2010cd0915,
+                    0xa71cc59b66340342,
+                    0x173cca6863a72e02,
+                    0xe9492faa2ae9ef29,
+                    0xeeacc073a72d3d8c,
+                    0x005a553728fe77f7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdbfe12d2efb9ae78,
+                    0x92bd773d4d8ed8c1,
+                    0x22f68e0d35ab7932,
+                    0xbccd827fb1e1b332,
+                    0xd6b965d71e79f681,
+                    0x00935b8a0db180e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x547ba5e5968acfcb,
+                    0x54a865dddc455e79,
+                    0x30857088a9d24817,
+                    0x21ba6e8264a0d489,
+                    0x5e9171b38da8bd56,
+                    0x0056293eb3b3478d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xd12be3a6af0fc5d0,
+                    0x2efc05d4abf153a3,
+                    0x39e95151a35a0f68,
+                    0xe1e71c28c3a4e48c,
+                    0x7c7e6e881b86f5fe,
+                    0x01631fec96f4b35d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e41d783bb1bf1de,
+                    0x08d763bd54982cbe,
+                    0xa1a3d5f4b31cf5d4,
+                    0xdb8b547ea798bdc6,
+                    0x49798b7792767275,
+                    0x00a274c63881482a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9801f98af486aff4,
+                    0xb3d0bc8aef94b576,
+                    0xd7bad013f4d77564,
+                    0x9300f9a5142966fc,
+                    0x7ccf9bcb5514993e,
+                    0x004a3742ea2fbf26,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ceb8cefb4f97eb3,
+                    0xf82084dae8459d46,
+                    0xbcce5426975700a0,
+                    0x432130590f5f06e9,
+                    0xdbb923a8ee302841,
+                    0x01a3141dac2fcd7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x608ea5e044be659e,
+                    0xcb2a5ef0cb8f153b,
+                    0x47e84b3c0044b483,
+                    0x83049e3a32383a7a,
+                    0x784ba9785d083e2d,
+                    0x014246a8b1d8275f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf6d2ae02d1a6f62d,
+                    0x3723a8a7f5ccb84c,
+                    0x9c64062997acf732,
+                    0x9bc7ec7165849871,
+                    0x780dee6bd80949cd,
+                    0x00a3aa9121426b33,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfcb4cab3fe86edda,
+                    0xebd0ca24d68c93e3,
+                    0x666f060fb257e524,
+                    0x3e8d3acaccde826c,
+                    0xf65a33904ac0d62b,
+                    0x00b2f60502bf202a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x778ccaa029ed6482,
+                    0x369f55f06815ab95,
+                    0x960d76d88c5f5375,
+                    0xd33e5085f41edbf9,
+                    0x894763b866c90835,
+                    0x007c311f1bcbba1f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf1c93b5e8996cc8a,
+                    0x01843d6035a04019,
+                    0xe30a29cf9703ee9a,
+                    0xe2aba361d8183b5d,
+                    0xaf8fe99ebb6da86d,
+                    0x00838ec8cf06c900,
+                ])),
+                Felt::new(BigInteger384([
+                    0x73e85c1dee8128fd,
+                    0x22994a2c8611c5b3,
+                    0xd410cf5219dda87f,
+                    0x202909ba03481a71,
+                    0x61750e1f80311b37,
+                    0x0036d38fd4d7b702,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf25d22fb55f8930a,
+                    0x07ddbd20a70d418c,
+                    0x537d1de82e02087d,
+                    0x0e5857e9ccdf9eab,
+                    0xb1a412ec4f43842a,
+                    0x0144bb6c8cf9e490,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4ca824b683aa008e,
+                    0x132cf9a5913c9652,
+                    0xd71bff7a23e32578,
+                    0x193c224210bc03a9,
+                    0xae2b5877c478244f,
+                    0x011667bb09d75c01,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x136287b4d9fa853b,
+                    0xf0200446f97a89df,
+                    0xe42fc7c5880d1a35,
+                    0xc7cc1ebf6c498fa4,
+                    0x53cbb7126e278c8b,
+                    0x0173db2a4fef6da8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8517e77c91f6a047,
+                    0x71a6f149ee7f27c2,
+                    0x2fbd3a06149ba75b,
+                    0x11ed042205e0c496,
+                    0xbe145fcb82c20bff,
+                    0x00ecfc830e7687c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8228dd5676163925,
+                    0xa644cb5b4c0a413d,
+                    0x1ec3307c480eec0c,
+                    0x5f265f43b10bab7f,
+                    0xe7f4a9ee38a41ed4,
+                    0x012a23025324fe5f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0ce1ef819c6e8565,
+                    0x0598d47145dec2bf,
+                    0xb3d31cbd46971e44,
+                    0xf61239abd8d08221,
+                    0x850372aff2e784a0,
+                    0x00adfa89c17e6578,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1a32473d5fe1456e,
+                    0xc500f54161d14f28,
+                    0xea0fb46ebfce2485,
+                    0xb6b9b17f46b3af1f,
+                    0xef6bf54fd210bf91,
+                    0x005a158571732bc0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x452ca9ec12f4c5ea,
+                    0xb052e8366aa1f4d2,
+                    0xfdc3f875fdf0704e,
+                    0xdbc4cfe2812ee80a,
+                    0x9b6dd353a39ddbbf,
+                    0x000dbb8ffaa069ef,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc12a728f9d10076a,
+                    0x297b98aefe8e1f33,
+                    0xb7a23540b6ddfc57,
+                    0x14eb8199babf0c10,
+                    0xb8eefca6118d9faa,
+                    0x013f744e47fdd6b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8e43263ff9263e27,
+                    0x74066bf136b315f1,
+                    0xdaf36e9449e3d1dd,
+                    0xdd158e22d7ffef61,
+                    0x97f50da93f7dbc70,
+                    0x00a9892f100f800b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x400925d4526c24e8,
+                    0x5256d5b698507f4c,
+                    0x70e4a8bd5175a944,
+                    0x32cfeee998f45399,
+                    0xe752922ce327018f,
+                    0x006d189d1d1e9656,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e6b5fbe9e8e48b9,
+                    0x809632f354a4ca75,
+                    0x94a01f8b0196dc39,
+                    0x8de755d172df460a,
+                    0xb13df5b1634f72a6,
+                    0x0049128a2fe8a5fb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc1efa6c2ee0d6385,
+                    0x2243f8115d968cf3,
+                    0xb10e6b204791153c,
+                    0xb2496a96788ba6a7,
+                    0x7802ff36cc45f4b7,
+                    0x017b78034329bbea,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a5a75c131a9fcb3,
+                    0x450c8f431839b2af,
+                    0x4c792aefb1fe828e,
+                    0x6ec3ea8a0f092a55,
+                    0xf7a47dc2d7ab4319,
+                    0x019584d1c72a4ac3,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x6f32d84eb540bc73,
+                    0xc6f5ab7915a6a10c,
+                    0xbc7bfab2822e3f3d,
+                    0x782ce0fd4a9bf0eb,
+                    0x18c7f270609a88c4,
+                    0x0033e9d40f78b472,
+                ])),
+                Felt::new(BigInteger384([
+                    0x04f700eaa5d14562,
+                    0x177c03800a9693ba,
+                    0x66d18712bcf44d8a,
+                    0x1b60ed3e385bd8b8,
+                    0xf5d1fc70a32b6226,
+                    0x0003e54f9786a8e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x751534248d8e61b8,
+                    0xd2ff347c01bf23e0,
+                    0xc56990eb00720df6,
+                    0xf27cbff9d81a6cf5,
+                    0x1d4eb4fc6a3e4296,
+                    0x00e10e5048443467,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8fd856c32177ada1,
+                    0xada07e23a6980cfa,
+                    0x4738483c577205f2,
+                    0x23e8d867f2c347cc,
+                    0xb68ba1da60ee8111,
+                    0x0151e0b1aea0a0fa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4fae08b152fb3604,
+                    0x88fc7c86c654ecc5,
+                    0x2427cae941edc5c6,
+                    0xf675df0e57e86f48,
+                    0x27898ad8e651f4c3,
+                    0x00bb6c52ee8ed13e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0099e53bc07b869a,
+                    0x5ac98d1bf36d3244,
+                    0x7281f88c65ccb674,
+                    0x179d0d6ec1218e00,
+                    0xa281f04c88d74d98,
+                    0x00414ba059678d58,
+                ])),
+                Felt::new(BigInteger384([
+                    0x753788e69c020241,
+                    0xb94f82c25202fe62,
+                    0xee037fe32726f706,
+                    0x747f6b760f5303b0,
+                    0x626b66595baefcf7,
+                    0x007ad22027b2c6fb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x769645b6f3e87d93,
+                    0xd9d0c84506b96e11,
+                    0x7559be5e969dc8cc,
+                    0x718ddc0d984e5c6b,
+                    0x617202fa15e5219a,
+                    0x009e672a626cecc4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x03c57d4c60196642,
+                    0x525c14578d471361,
+                    0xfc83fc78f8d84bc7,
+                    0xba0d2f1db3442b65,
+                    0x5e694524cfbabb03,
+                    0x013f5a3ce76c5937,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf144adc75ce62ad7,
+                    0x0216394ef56c005b,
+                    0x85c90a5e9755ca43,
+                    0x716286556e3f7adf,
+                    0x3407b71259bcacfa,
+                    0x014c9f9b47619559,
+                ])),
+                Felt::new(BigInteger384([
+                    0x63c1b8890e1c02c0,
+                    0x14a7c78f23f7c72b,
+                    0x476aa9ca606cd182,
+                    0xe5f0a19659171877,
+                    0x8b81fa39db8daf9a,
+                    0x01628b37aad68e9b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfa9d1abd866411da,
+                    0xa5fa57e20b1357d4,
+                    0xaefed9cb8a365892,
+                    0x33ae07e86458e520,
+                    0xc2aa2867a3b1ecb0,
+                    0x0140982eae4cb9d9,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x666710b55ef413d8,
+                    0xbdf9e1cae7719946,
+                    0xbbdb5ba4b0ee45b3,
+                    0x739f5725f240a5a3,
+                    0x140f2d753caf5993,
+                    0x009dc2ae24649ff8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66a3b1799f83652f,
+                    0xb0e155b4f1eee1dd,
+                    0x078f588f9d2d8aa5,
+                    0x9c0eeae07308bd61,
+                    0xc06752b3b57e293d,
+                    0x00ac4b4127a0b691,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa50b2c2a540b6a7a,
+                    0xdb920b0cd64128f8,
+                    0xef25669efac48e32,
+                    0xf9c3a8c0d2f045ca,
+                    0x04eec288019a9260,
+                    0x00a6d4791c069379,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27d034056490736f,
+                    0x4e0810cd34c32dc4,
+                    0xa175fe5c3dbbd4c9,
+                    0xf25362a8456bb563,
+                    0xca510931afbaa732,
+                    0x0165350473bc2625,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa11340a6c685f6e6,
+                    0xa45716ead6e7be0a,
+                    0x6e2bb9b1125b2a8d,
+                    0x05856ff0024f5bb6,
+                    0x1f8ee11057bf097b,
+                    0x009c0ae78eb6b102,
+                ])),
+                Felt::new(BigInteger384([
+                    0x22c0682d69fbc43b,
+                    0x83ee97a5797460f9,
+                    0x62e87581ec25f9ee,
+                    0xc82c35a3732f0278,
+                    0x2f779658445a99c4,
+                    0x001b7195d92f7ae7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcb907c54120ecf45,
+                    0xefdee2ca394c1665,
+                    0xca57985771b1b980,
+                    0x1e1c4a6f890db893,
+                    0x20e1e7602e47924c,
+                    0x003c38bc9adab840,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9c58ee8d8ee9a4e5,
+                    0xb361b9ac5947a89a,
+                    0xcbf441422c1d16e7,
+                    0x5503c4c4081c4360,
+                    0x6068bd0a15ab1b45,
+                    0x00a2d9054bfcb076,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc87e1e538ca0c502,
+                    0x29bc03dca8350e03,
+                    0xd95d96062ba3ea84,
+                    0x6ca62b11d52cfd56,
+                    0xcc26a9a20977ac9a,
+                    0x00f8ff053dd2a613,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4ea5d727e2b15345,
+                    0x21cdb04a9f6d4d93,
+                    0xb8672cb27bdac216,
+                    0x37507034fba59f25,
+                    0x84a1aa5766898590,
+                    0x01a06381abacbc35,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcac0661b0fcf6fba,
+                    0xb658cad266bf6a9a,
+                    0xa1eaa0647866a634,
+                    0xf6110f6bb77b9751,
+                    0xdeeb2d4dd9ac5d4e,
+                    0x003cb60104d63fdf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x000c9a49741aca28,
+                    0xe3ccfd2c2e5ed89d,
+                    0x4d1eb28576d75230,
+                    0xec50ffff9db451b5,
+                    0xb29aa7775f901714,
+                    0x00086a7a67adfa07,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x935ab6440783a725,
+                    0x6d244718638174f3,
+                    0x1ebd670d57c4d7f3,
+                    0x50c598c77a948641,
+                    0x06add9fc2e974bcc,
+                    0x0177f6ef5741b900,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf91ca95b474ca644,
+                    0x618b7ea6a5f61047,
+                    0x3f1806ecef2592cc,
+                    0x5f13f33d0fbd5417,
+                    0xd156a31dd33bc200,
+                    0x0054fefeb420016a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x577dc2ea4927891e,
+                    0x8eeeae83a3fd1f06,
+                    0x2ac0a624e230cc70,
+                    0x6f12f4fc9d4c6d7c,
+                    0x5db3c5aa36d39839,
+                    0x01626686ed038635,
+                ])),
+                Felt::new(BigInteger384([
+                    0x65c86a3749044a8c,
+                    0x26e724d5ad4b82fc,
+                    0x67b170cb7f6f3c7e,
+                    0x0f62144efb894311,
+                    0xe3198b812707dc62,
+                    0x0058e04172d802e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b6f857ed9ecdb6b,
+                    0xef59fcc0fa240b2d,
+                    0xccb6b85cc5bffa6c,
+                    0x896a630c330253ba,
+                    0xb59b5feca471e19e,
+                    0x00da6f3a2d457ad6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb9fe6c11679a7d69,
+                    0xab6981f1bd5b8087,
+                    0xc9e7763b0614e0d9,
+                    0x29f1d0f3ed06fd05,
+                    0x737073f7d49cd3ae,
+                    0x00956fee005a9e5e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1ac684ab8e8cbdef,
+                    0x53db23a3e3fe2174,
+                    0x3b1f637bccd8c840,
+                    0x1929f1712ed1d690,
+                    0x98f9675b02a357e7,
+                    0x01a7f78cb63ffffa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x529586866cadfb83,
+                    0xf876f9772a2bf6c9,
+                    0x77d7f053a7f7621a,
+                    0x062bc37d2a571d2b,
+                    0x5fc917dcea8dd2a8,
+                    0x004ed4c5afd6883b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92071a451b8e99fa,
+                    0x5b39a4ba8f578bd3,
+                    0x8afac8620f26110f,
+                    0x3be3e0e0a8796337,
+                    0xec8180a595ac0ddb,
+                    0x015b6a7c3a54c3b3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcf3bf3f88fb6eb33,
+                    0x216c19ea08d6d12f,
+                    0xb5125b86f4bfd112,
+                    0x5ab9ea121f5bb373,
+                    0xed5411b5c09b08f4,
+                    0x0145e7ccd06491a8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb9e0d1fbae1e9843,
+                    0x7e3294547fb909d5,
+                    0x50d7eacb48b041e2,
+                    0xa3fd1a30050601ae,
+                    0x7d364738b2d04a5d,
+                    0x0073c145fcd99b03,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9a4f803c71d6c872,
+                    0xbd5cd5594fe90bdb,
+                    0x3ecf0075a0afa092,
+                    0x3277fb6f07ce2dec,
+                    0xc0b492d8533ce4a1,
+                    0x009173c6f9fcc1b2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x75495887f592e4c9,
+                    0x31399f632aa3ae78,
+                    0x4ec93c8be8eeb944,
+                    0x3db5a42c72233233,
+                    0x8efd0a9ac47ceba0,
+                    0x00cb96332ae951a5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x04d255e11381c8e1,
+                    0x370b166e1e0a68b7,
+                    0x1291818779a1aacd,
+                    0xb957844784e7c06c,
+                    0x1616718679dbca46,
+                    0x0001baa0590084ca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44c35006b139597f,
+                    0xc10fcdfce68014e9,
+                    0x3fe638f69564b1ea,
+                    0xf784c8144ad3650c,
+                    0x863ebbe81a29235f,
+                    0x010b34c86f24f8d1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbe95e1fb88f7db5b,
+                    0x2bab32395451e5e1,
+                    0x394704ed97a2514d,
+                    0x11566f5b65c8684b,
+                    0x8824e6a083f183a5,
+                    0x0104038560d76f85,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1b923dcb306f7ede,
+                    0x5db4dee345837ec2,
+                    0x8d657ccd1d279ce5,
+                    0x6ba151d51ca3ee75,
+                    0x05ec495c6533bedf,
+                    0x00b73d6a65f1aad7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x12c6d2ff7767ecb9,
+                    0xc0107b52e2024821,
+                    0x05675a1fa221bfb3,
+                    0xa83e918c07176f22,
+                    0x921e4a241b437409,
+                    0x01a9d8d26e4e9808,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x798eacb873edc726,
+                    0xe3d28fae0447b4d2,
+                    0x1e1333657e738bba,
+                    0x4d42be231f2abaff,
+                    0xb99e31ba827b5434,
+                    0x00a541b30e03273e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x254ba95f35605032,
+                    0x4d44761189d0bd78,
+                    0x54afeb2f1493e849,
+                    0x654f023d3521e549,
+                    0xe6d0e153b18b4cf8,
+                    0x00e840b660af1202,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ddf217d3e73a7be,
+                    0xfe17b157f82fc910,
+                    0xce47f25e36238d81,
+                    0x2e8bae73f0e9ae4b,
+                    0x9138c427b6e07110,
+                    0x0108fbaa6b0ce20b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6bd7b00a8052127e,
+                    0xe5d716512fbd0802,
+                    0x37449cfc5329c3ef,
+                    0x69a4e7ab6b78a6f0,
+                    0xa3b133011b82ddb7,
+                    0x00a69b0a82f01a44,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb23f09e949db84f,
+                    0x017b9ce82b7dcb9d,
+                    0x25eba7378a2d3431,
+                    0xbebf744e8bc79396,
+                    0xee7c4455366ed9d3,
+                    0x018704c12de6d8c2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0271d50c8ea1e055,
+                    0xbafd4f13562f3d24,
+                    0x99d1bb1908346027,
+                    0x952b0533f06dfbf6,
+                    0xb2654ec421341ff5,
+                    0x0077930b83bbe2b8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x85c917206138acfb,
+                    0x04ff2f5e48db1e34,
+                    0x234ce94607f98c96,
+                    0x0dbd8fb1397a6c5f,
+                    0x66fa2a622a1f0d78,
+                    0x0093214c641b1090,
+                ])),
+                Felt::new(BigInteger384([
+                    0x185d953456e56242,
+                    0x8d8957a5629f91f2,
+                    0xf9db035cb5e8b96d,
+                    0x06d09c134c4df636,
+                    0xc52bccef7535ea4a,
+                    0x009b02141a38fe23,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd50965c7f61fe133,
+                    0x4be216f5ab605786,
+                    0x74177ffd67640fbf,
+                    0x0a9047e2afde4e57,
+                    0x8557b7c71bd49f0b,
+                    0x014548a211c13c14,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd06224d5ccad348e,
+                    0x7a01e5e764deb4db,
+                    0x0ce48a2222ad2662,
+                    0x4aa6d860a3703bfd,
+                    0x6ba71c466f4f8c02,
+                    0x014634028e497095,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf68b8c61b135a2ab,
+                    0x4ae54ccbf489329f,
+                    0xe379bf4eb3f1538d,
+                    0x857915dbfe04c6ff,
+                    0x00c8fcf13eba5f04,
+                    0x00bc08e59dd6bb89,
+                ])),
+                Felt::new(BigInteger384([
+                    0x34aa57df0a6f2d6c,
+                    0xdbb86115176fe93d,
+                    0xa5bb2fbb25a380d1,
+                    0x0cc7fe6851e629bf,
+                    0xdb354b9b25072eb6,
+                    0x0081b53fcc1faa5b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1c08930c148d82a8,
+                    0x7bbefae86ef7d2f1,
+                    0x385f3f15afe60963,
+                    0xfb24d1eeb2eb3629,
+                    0xe28d2c887867486e,
+                    0x0089b9c0eeb01376,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf38b83db940853a2,
+                    0xaf7427b477eae9e9,
+                    0x6023ec00dddfea25,
+                    0x9815f9db17e665bc,
+                    0x8634bd9d596e9871,
+                    0x0040740ed911d4b8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2c8a7248414f74b6,
+                    0x29e7099f4b98f492,
+                    0xfc3f3da7e810f9dd,
+                    0xf8b8c949a6820c8c,
+                    0x42fc8e010ee7d671,
+                    0x0072f7454df9657a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e2f746b77a13642,
+                    0xcd55c62f371aa538,
+                    0xea7f04e2e05d38e0,
+                    0x252b6a2b68c9ba4a,
+                    0xd0c6c20ce898f8de,
+                    0x0075932c9a347339,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3d377bd90cd28844,
+                    0x9f232dec67dd06bd,
+                    0xff5770c68ab621c9,
+                    0xc64bd09267a911b0,
+                    0x9a57abeb114b765f,
+                    0x00813a9c2a5afb4e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf45a569aca33dde5,
+                    0x6b51f911df1f5b5f,
+                    0xd21453fb0efcffba,
+                    0x2b4e3e83c06fc94a,
+                    0xddc0aa0d349f8738,
+                    0x01ac50a3134a1c8c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf860baa1358167bb,
+                    0xbcd183964f4a0cc5,
+                    0x149ac6e00d68f054,
+                    0xfbd651f95af36e85,
+                    0xe169283b3d6f8deb,
+                    0x005a82d55c768775,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfee18efa9e5ea5cf,
+                    0xc8bfa0fba9a797e5,
+                    0x1fc9999396d77830,
+                    0x4909e78c18ae4805,
+                    0x3cee22c23ad974ed,
+                    0x00224dcd8234292e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x63797cbdbb5bac26,
+                    0x6ca627cea86a2f63,
+                    0x29994cb6d9e96a6c,
+                    0x3f28e45d1de3eaf7,
+                    0xef2c15aae04eb35d,
+                    0x00cde6fb238a72e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x994414101e9e4fef,
+                    0xdf9e1dbca4339afd,
+                    0x870e1f6d3fe32cf3,
+                    0x7f634f7d43c2aaf2,
+                    0xf3e751558890a4b1,
+                    0x001908775654db5e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfd1201f944cb8123,
+                    0xd6a49e919964569a,
+                    0x9eb25cd76ff6820c,
+                    0x4750b2d4239186b0,
+                    0x06586e95b37df6b0,
+                    0x0010b55fab4062e3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8a2a288e6c3de099,
+                    0x24b0517f86880695,
+                    0x7cafc3c7f220d7ba,
+                    0x0f07c12d92e838b0,
+                    0x62366ad76f9971f0,
+                    0x007516af2229a465,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe71a421376d5bf9e,
+                    0xe8cb19dbcd4073a2,
+                    0x1ab9d29eaa137b26,
+                    0x934e348c9d9bb166,
+                    0xbaf4bf690dfa7934,
+                    0x007b434108e60c56,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc61b0478bd58cd66,
+                    0x1b3f11abd4badb27,
+                    0xd70046e809a75d5a,
+                    0xacd2a7e7dcf25219,
+                    0x74100afb6fc5f224,
+                    0x01485f8c310c979b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc438c550f2aeab2d,
+                    0xfbadbf157761b23f,
+                    0xeffa059297f10b85,
+                    0xf126b2e0d2a13735,
+                    0x8538731626379135,
+                    0x00e6f5039ee2f5d6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x23e9964d224eade5,
+                    0x1885f8360bf68bdc,
+                    0x844d2f320f7e5aa5,
+                    0x35a07761e9a364da,
+                    0x4e2f2eb9b52d1b04,
+                    0x002e713c741569f0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b84e684102dafc0,
+                    0x4a6c4c78bf6fdc0d,
+                    0xf2db9ed508379401,
+                    0xda2bd1cac0a30d0c,
+                    0xac7c7d513e26c886,
+                    0x004f380da0b14f61,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdfaf9366b8b158f4,
+                    0xd1f3d2a25231dfaf,
+                    0xf9c190d038a0608d,
+                    0x7b321c6b125c9dc5,
+                    0x755c982a09cab048,
+                    0x01ab55fc610d4b1e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xbad805d3277767ce,
+                    0x931d133dc7c10909,
+                    0x2989edf22e8de00d,
+                    0x8a5243cfef4c23b5,
+                    0xd0af76783902e883,
+                    0x006db1ebfca4adb9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x49a360d0ab4b20ed,
+                    0x218f39037deaada0,
+                    0x88a71746261c46fc,
+                    0x8b0cac07c9f11a45,
+                    0x136e73eac8bd9916,
+                    0x0027345e226f954e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xffdbaba004b3675d,
+                    0x1e0d4a894e037c20,
+                    0x5c4240d484110f61,
+                    0xe48fea109fabeee9,
+                    0xe83ebe5d9937f210,
+                    0x0076776e1ee6a049,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc326806afc5d5fe6,
+                    0xa2725caf7306c2b1,
+                    0x67a6f1506bf3257d,
+                    0xefdcf9bb330d9326,
+                    0x3ef05e5879892376,
+                    0x008a2c47a8c5eed3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7ff9878decc95b1f,
+                    0x93f89b8c6890497b,
+                    0x8bb80a53ddd91799,
+                    0xe67bae92cae75f65,
+                    0x1586d91ed06ea3f0,
+                    0x0091d6207aa67271,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf266f3d5dc146ea,
+                    0x5c253ca412ff8cfc,
+                    0xfb48fc1b202d250b,
+                    0x112704f21797933a,
+                    0x3b220ff2a107119c,
+                    0x012b7e55f0726c28,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd6bdae1dc3f7bf28,
+                    0xb654a30a50c4d518,
+                    0xab665f88354e22fa,
+                    0x5ddd0f2d75ef6052,
+                    0x9307d177d619be17,
+                    0x00549bccd1545dda,
+                ])),
+                Felt::new(BigInteger384([
+                    0x468d0685425162f8,
+                    0x42bc9c4290f59c31,
+                    0xe1c47cb96f3a1d5d,
+                    0x2c66d743f1a374c4,
+                    0x18fe568a150026bb,
+                    0x006c9950091e4a3f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x01f85801db742f46,
+                    0xa562f6338385d160,
+                    0x24aa4f88a5258788,
+                    0xb8619023e7cc3516,
+                    0x09453f6418b7936f,
+                    0x00051668987a0bda,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcee8614d8a200b01,
+                    0x595f645b852d6fae,
+                    0xaf468d32328e14fc,
+                    0x14c8ee3da5ece245,
+                    0x46ee18878dbea35b,
+                    0x0124721268ef29c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdfe05441815e0a83,
+                    0x39df2c7df40fb315,
+                    0x21710fb9254a9ad5,
+                    0x39f8f22fe200842c,
+                    0x9fd14d71cc6559c7,
+                    0x009b65fa34d53ce9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x841ef729b2e6a5d1,
+                    0x6b4ad6e4da3a7c59,
+                    0x42dff3ae57c68495,
+                    0xeb8ce90c5c8ca83e,
+                    0x2b2a4eb13b107a4b,
+                    0x00dffe065bc59a47,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x38840215ac7d8960,
+                    0xa0ad3056da6d2a78,
+                    0xe17d953692efe5ba,
+                    0xbff2180ef1ee8d4f,
+                    0x2192827d3796c296,
+                    0x008f0e1b33f3d58d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e87713f4d94fbe9,
+                    0x1e31a9691c1563c8,
+                    0x10beb547de3e37a8,
+                    0x32d3f5dda1cc667c,
+                    0xed757a8372a19c34,
+                    0x017f2ac1ce91387f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3337d3d90e1bbf01,
+                    0x171d126f535d03b5,
+                    0xd8eee5b278f8a5a8,
+                    0xc4c4184f3ddafdce,
+                    0x7532bc9d7af2ba96,
+                    0x01aa25e93c30eba4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xefd4500c7c2216c1,
+                    0xf21d1e6c7d859a61,
+                    0x3a9f43890bebaf4c,
+                    0x7da5f22690b18f48,
+                    0x5756e61890c0c5c1,
+                    0x00dde1e91869da4d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc4396dde3e6407cd,
+                    0x50adb2a6e25ae270,
+                    0xaa962b898d0b5a22,
+                    0x1269fc5358f0b7b5,
+                    0xf73dfbbf118f470a,
+                    0x0065fc11ec7cc6f9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ee8a8ce45aa5bae,
+                    0x64712f579a60f703,
+                    0x1f75680afdfa4382,
+                    0xf648f01eb0b51115,
+                    0xe3df0471cb1607eb,
+                    0x0080abd597275f3e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeda0b0da37af4cec,
+                    0xf4a61c1d4c714c95,
+                    0x9dcb3f728cd266fc,
+                    0xa8d3485e7703766f,
+                    0xb036a153298abc72,
+                    0x016dbcff96bfc1f7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc1dde2427702eee7,
+                    0x50c7834a5198f35e,
+                    0x7fb2b309e33917ac,
+                    0xb252a4c7884bbb7f,
+                    0x901ca99e4e8b99f0,
+                    0x00192939f6911558,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7ad77e87c2326dc1,
+                    0x94d1596965c2bd27,
+                    0xbfaf4dc7065d81e6,
+                    0xe1879973167b7774,
+                    0x210c541505d86c35,
+                    0x018d53546399c9cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d314f1a581ee1fb,
+                    0x491c4852619a79ae,
+                    0x3fd0160e7d7e08a7,
+                    0x2fa58291d30e19bb,
+                    0x9bc169e32222ea11,
+                    0x007311940dd1299d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d273238cda62d0c,
+                    0xee5682bc71d3f290,
+                    0xd9422a859066b474,
+                    0x14fca77e688888c4,
+                    0xe62e2c6b4f334a30,
+                    0x0097dce548dfa710,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0e888cd68da28dd,
+                    0x3b145d72b09f7852,
+                    0x536256f384fc7419,
+                    0xc936f0605cbdf90a,
+                    0x09ab12fa8a0de09a,
+                    0x000890a409359e4e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x4944e802d799f677,
+                    0x4e4b4a699900fcfd,
+                    0x0557af3cf0cfa19b,
+                    0xa00c53e1ce7698a3,
+                    0xed47c81c0dad5a86,
+                    0x002a8eb7a14401dc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x605108d3f78b4fca,
+                    0x42330890e64fd0d6,
+                    0xc9a12cae009b6d98,
+                    0x142021fd1db49ccf,
+                    0xf377f5590a2a92e1,
+                    0x00a3ec2d92716303,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b391796fd6f4919,
+                    0x883b153eedf1e34d,
+                    0x3eb6c3b188940b1d,
+                    0x014d309f7361f4d7,
+                    0x15a4c7e5e8e1f308,
+                    0x00e1e70e5cb25d12,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf125e28e7573b60d,
+                    0xe8b3d45b26087c58,
+                    0xd8532aef801a4a0c,
+                    0x13d18edf4c724686,
+                    0x222a7164b2224fb4,
+                    0x01511a0728247bda,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb5db25db5ba35b96,
+                    0xd54bbdb87af6556d,
+                    0xac06423e27798832,
+                    0xbfd91833f40e4eb5,
+                    0xd288d7f22a0a9bca,
+                    0x01a0b67c2af8dbbe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x991e53dea9aa9ca7,
+                    0x640a8da2161a6be1,
+                    0xcf46aed87996c2a2,
+                    0x1d7622fb45724bcb,
+                    0x921d1a42789559f4,
+                    0x00b7bf9d3f525b6d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ed174f80a2e52be,
+                    0x5aab3f95c0b929d1,
+                    0xcf0ee5eb52dcd888,
+                    0xc9a246b4cdef548c,
+                    0x77c93d966e152efa,
+                    0x01a60d7593118d9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x03cfdacd985bcc9d,
+                    0x1b27acbd759198e9,
+                    0x7dce08030bc20452,
+                    0xbdf44c2f0ec24969,
+                    0xed7c94d3a0e026a9,
+                    0x0099aa58857b8251,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3d7f52892dbe884e,
+                    0x1de7d2a3f56eb542,
+                    0xa129f6ac519a6487,
+                    0xdd66b441f23dea4a,
+                    0xed414c4142f0830c,
+                    0x00484fd176accf1c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca9dca0a7d316fcf,
+                    0x1ded87bcac50344c,
+                    0x2c2a4de4163bc2c8,
+                    0x392cb0e6e1a6bb95,
+                    0x8aa8d4a127fa0f0e,
+                    0x0007122611f14fa9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa445fb0efa6afd83,
+                    0xe86480bab091fb45,
+                    0x80bd9cb8f8d1ddc6,
+                    0x813cad0b9adcfd51,
+                    0xf13bd8bdb5124d37,
+                    0x0039ea10aae55c85,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6bafa8b25976ec82,
+                    0x045ecf706c858fca,
+                    0xb08823353e4a0366,
+                    0x711efdfc0f6064ed,
+                    0xf73edef02558902b,
+                    0x008eed7373560bf1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x2f4c6a6d87f5cc22,
+                    0x961ed2790e6f2c3c,
+                    0xbdb6e1a8b24a9964,
+                    0x749dfbc9ab0a2634,
+                    0x83b745ff36fb3824,
+                    0x00d4f1b50cc91e83,
+                ])),
+                Felt::new(BigInteger384([
+                    0x754b03b426a4cdb3,
+                    0x764496f7e13cf31c,
+                    0x487490c80e50fb97,
+                    0x38d2e4a7dc1016fa,
+                    0xdf2c912dabef5be0,
+                    0x01188227830f4a4b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6b40c11073d55e9c,
+                    0xf8a640a76d011a22,
+                    0x3fde1c1ce888e839,
+                    0x09a9b0559bd72242,
+                    0x21aa86d3abe294fc,
+                    0x015cbb10fd6de762,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9dedb4d9fd2a9798,
+                    0x13915b4529bd81bb,
+                    0x2496f0222886b92b,
+                    0xb86e2be35cd7bd6e,
+                    0xd2433d542a01e486,
+                    0x0025e1d0e400cc78,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2c2736378bf632f8,
+                    0x353e070d03c3f860,
+                    0x748845e3cde6f25a,
+                    0x5573bdb93276167c,
+                    0x57acaf41d9f67f81,
+                    0x0019e2c4a2ee4df2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb1ec90088a0ac11,
+                    0xb0ce55279b4f758e,
+                    0x13c4be5eae524ee5,
+                    0xff5f5d5b73857244,
+                    0x489291006be15dff,
+                    0x01726bcd8e76cfce,
+                ])),
+                Felt::new(BigInteger384([
+                    0xde45485b5e0bc301,
+                    0x053a3a4ffdac0179,
+                    0x1c40abf12dd96703,
+                    0x41c3a1cc58bedcfd,
+                    0x70b69f0d248bcf3a,
+                    0x017c2458fa2ccd5f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb961eca7578d4485,
+                    0xe0a198505b811ec8,
+                    0x2ab0b55acb85af49,
+                    0xc32f50593b5ec062,
+                    0x490f9a5cf839e439,
+                    0x00671dec92ff1538,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e2cc22bd812188c,
+                    0x546e25493e141f11,
+                    0x9a789aa891931ea4,
+                    0xafe12b5681fc97d8,
+                    0x31caa8f138a21043,
+                    0x0073f080a542dccb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x986f939c2877caeb,
+                    0x99ea2d688067b2dc,
+                    0x7f3e0d3217d67f5b,
+                    0x6674832a5320073c,
+                    0xab2308ccce47a82d,
+                    0x00520b438ba35a9e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77a8b587d3874e77,
+                    0x41f1685c90306898,
+                    0x2511934adf38da10,
+                    0xff50cdd3b363a5fb,
+                    0x4e17864fc421a2f5,
+                    0x008d3540e444f812,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2c86d4b44321a36a,
+                    0xcaa331dfe3fc5cd0,
+                    0x1cbc485fc0577d84,
+                    0xb0e008ac2d1ee1ec,
+                    0xc847a38277397ddd,
+                    0x01344ed4d7c5b3a6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x630a97b3142ebc7d,
+                    0x2ef5f6d5e0d2ff31,
+                    0x166c0d9abe8f326f,
+                    0x7ca00012dcc89066,
+                    0xe27706441f57df6a,
+                    0x00f935908b82e664,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f064dad150be384,
+                    0xe1a8711dea8781c5,
+                    0xd13a6baffec03216,
+                    0x8d2758db1ef21ba8,
+                    0x85e6c38edd515fb0,
+                    0x000b260604f7f49a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x576d38d58d787ac8,
+                    0xcfaeccf474b7a05e,
+                    0x2537966112bcc66b,
+                    0x8515166ae571a51c,
+                    0x93bca1c536d48281,
+                    0x012f093c45fcaaeb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x87b82fc10611ad76,
+                    0xd2217c10ab6688a1,
+                    0x70a5a1d2b66ae1a9,
+                    0x527f4833957e10d2,
+                    0x36bdcfd4b7850c8d,
+                    0x017e44d3a8343bd6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19973e9e9d3726b7,
+                    0x7772eead16ae7bca,
+                    0x6415c9cda2dbcd0c,
+                    0xa9da0b87bad6e5cc,
+                    0xde967b5dc9212268,
+                    0x00a91771940c3288,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a65fc741bcc30ad,
+                    0xb4ec44763d0fd4a5,
+                    0x92369696f2909cdb,
+                    0xad5683cc4bf25127,
+                    0xc20fd0889bd6369a,
+                    0x012c0eb0a48c9a6c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a310db87bbb6f64,
+                    0xaecf0e8646d5d6e9,
+                    0x0b63055ae1a2e83f,
+                    0x23dd5db999443e12,
+                    0xf4512aa62c189401,
+                    0x019e8b790bd3d3ff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e124152d2ebef17,
+                    0x897fe3756a58c195,
+                    0x4e0ccb06ba56b18b,
+                    0x9d10be698bea3c71,
+                    0x89edd3b55e35dc1d,
+                    0x0092a888d0c65d60,
+                ])),
+                Felt::new(BigInteger384([
+                    0x26a027fb699f0b84,
+                    0x513f28363f3694fc,
+                    0xff823a31736b4432,
+                    0xbece838e03afa4a5,
+                    0x5b78704d5866b4df,
+                    0x00cd34ec17c726ee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x20654ff57d014305,
+                    0xc95daf3faaa791d5,
+                    0x8c21afbba2771503,
+                    0xdadd0e325fcc6ebf,
+                    0x3c266016f4f772d1,
+                    0x00a108663283be2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x07b69188f1ab5e51,
+                    0xf8471fc1312f2c18,
+                    0x03fc53f148dbab8f,
+                    0x9a69972b25b386d4,
+                    0xb0675bbc5d728813,
+                    0x005c0b919516668f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x62e3d493c627d0cc,
+                    0x596f192561ab78ad,
+                    0xaf3168e5243ab043,
+                    0x88a25c526d8ccc09,
+                    0x1766b0cfd3eb333c,
+                    0x013734b03a3cd3c1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x3a055b38e1dffc38,
+                    0x4e7e26796cb0d5b9,
+                    0xefdcf3d0a2f395aa,
+                    0x2df94d7a0a2ad949,
+                    0x88306f1fa661ba57,
+                    0x00997045b341e6b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4f4c7613509be4de,
+                    0x60c03a07f2e36bd0,
+                    0xd26d53329c9b5d03,
+                    0x2e6c0f0ec6a7f701,
+                    0xa15e8b7799c59ad4,
+                    0x00c6e06cccbf8b78,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a2d8dce27d242eb,
+                    0xc4ec6f2c3613fb35,
+                    0x05e3cb58e2f2d0c7,
+                    0x4f5c67f8b54bee49,
+                    0x971f409ded7cb9e5,
+                    0x017ea6ad6a648367,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa0acddc569227bed,
+                    0x4f15773353d3571e,
+                    0x00d41b26a464b8c4,
+                    0x9506d052aa2fbe08,
+                    0x06568f636a41aced,
+                    0x0185198c18c447f1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4db084ba0aa2316a,
+                    0x10382f4174e55066,
+                    0xb836ad35b324c521,
+                    0x15395770e9cc1fb5,
+                    0x430bcecf062e892a,
+                    0x00465248ed3cbcf6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6b5108fcbe558342,
+                    0x86bef76a4ae4f439,
+                    0xfc8ad63cbf39b975,
+                    0xae033521bf651a28,
+                    0x732c962e4cffdbb3,
+                    0x007aab728d0f4ec8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd4b37f13e3666b6,
+                    0x373e718843efba5e,
+                    0xf363f33a7e1e1d6e,
+                    0xb965050da005abd8,
+                    0xb7e6a4aa13b7d281,
+                    0x004e974e422ba3a5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0759b39bd6c00c3e,
+                    0x97db46cf6cfca49e,
+                    0xb3d0e6f47728b674,
+                    0x0fb96207760b8023,
+                    0xa47e33d179cfde9e,
+                    0x00461b83094e2f62,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe0883ed42c96d538,
+                    0x9eed5a72680c76b7,
+                    0xb4e23c4cde2d821d,
+                    0x86bcf30ae5b3f393,
+                    0x6a2277f5fa8ef51a,
+                    0x00f8c34d272fcc2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52db3b35af5ec03f,
+                    0xf7f744b5202bc081,
+                    0x68120e06dc0ce966,
+                    0x324e4945b6b74ef3,
+                    0x3bcff97bef6cfe3a,
+                    0x0169108341046e3e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27285d4517a6887f,
+                    0xca928c06f6196e34,
+                    0x1f212f75959eb35d,
+                    0xba69c555e138e1cf,
+                    0x10ba34346089697f,
+                    0x013628db66e612f0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x73408e183bc65be0,
+                    0x4063252b45ccc964,
+                    0x3417fb46abf6fba5,
+                    0xc37c7a911ce42b9e,
+                    0x9e58f95588fdff94,
+                    0x00fa4bf744df4101,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe6e5ac622c2980d9,
+                    0x38bcb6ab8decd580,
+                    0xef8214ebd6d801c4,
+                    0xd05502d9b3cceba3,
+                    0xb9cd28252427aec8,
+                    0x00a817cfa368d1ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f77e1a78be0b3ce,
+                    0x1af16c355680c2b0,
+                    0xc21ca726828de89e,
+                    0xb1b1fc5ac351c02b,
+                    0xf6445318cb02743d,
+                    0x00be1f5e5143b229,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb2f3cf3f9943f3e0,
+                    0x4da03492cec77960,
+                    0x4a1aecf56731a759,
+                    0x675da7cf4820ed94,
+                    0x10421e0591eab48a,
+                    0x01ad99e9c6a0cbc7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb1b7f067f036445,
+                    0xb09ab6930985ea43,
+                    0xeb823d9b65fc16db,
+                    0x6cada027852d5a41,
+                    0x589cf6038357149b,
+                    0x011dd76fe2d0ed3e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x24f1f66919e3dbee,
+                    0x7c5ae2de781a3a55,
+                    0x669293a1962f9170,
+                    0x57fafbbfccf15b59,
+                    0x52897031c8e0a128,
+                    0x00a93842ace99731,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0cc99ff5039638be,
+                    0x890550c8afe00698,
+                    0xaaaa74938be87eed,
+                    0x535405e6acae6d9c,
+                    0x2c9e93820f85823a,
+                    0x00737c6e991301e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x919a69230cec826c,
+                    0x816c729b6bcf5dd9,
+                    0xadbc5da51e0c2192,
+                    0xea33d693e3516bec,
+                    0xb0c9b6304a7aab37,
+                    0x00379849870a04a5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f4329d8f4d2e25d,
+                    0x362251457b32c37e,
+                    0x09df4e03aed0614f,
+                    0x6fff1906b018cb12,
+                    0x94a069db1ca90ce1,
+                    0x00a3ea97f0f29ccc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8736f7702d7c234,
+                    0x0f6669989bcf47b9,
+                    0x6a8134b271dbe7f5,
+                    0x1d1e13a77bed4bf2,
+                    0x69adae5b31158c0b,
+                    0x00e7d0b30b224f11,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ce80b3a563e8a47,
+                    0x21bef77849e20465,
+                    0x784cffd20f60b421,
+                    0xd72b89403468a435,
+                    0x740b2011b6a31157,
+                    0x012e87ed582dd171,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9e8dbd8ac4fd6474,
+                    0xcf1e72a14e2358a7,
+                    0x2f0b493913943e83,
+                    0x0b8e6f52833ef87f,
+                    0x4614a83ed9a0a7fd,
+                    0x0086d47a40639d37,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7663a8d77a18dda5,
+                    0x83e205ebd74a3d54,
+                    0x73760c558a414bd9,
+                    0x859ad81bd8bc379d,
+                    0xb42b408b3bcc6ff0,
+                    0x00a4f60ab6099cd7,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x1c367ead2a980bac,
+                    0xbd5637d4a1a29f26,
+                    0xd84e012f8ba30ed0,
+                    0x14fd3cea48f92059,
+                    0x8d86f412309a332e,
+                    0x0058582442009836,
+                ])),
+                Felt::new(BigInteger384([
+                    0x18e37fa3f7eb6e39,
+                    0x33dc256e109d13cc,
+                    0x70b0edf374a9b024,
+                    0x9867d87ff84d942e,
+                    0x27e0a296cee07210,
+                    0x01434f2e2752a2ac,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5c9cf0ec5839f02a,
+                    0xff30a22a930eb71c,
+                    0xd90b5249b09c8b31,
+                    0xc4c5389bda671457,
+                    0x763b23b871dd1da9,
+                    0x001aaec2bde43cdc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x37183abb6e3baf27,
+                    0xc0348b7652ebd3d4,
+                    0x4cc5ea20835d8bbf,
+                    0x793acf0b508beafc,
+                    0x4796b6aa91c47702,
+                    0x0077f6136272caac,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x50962659d73bbd25,
+                    0x38f2eeff26a74223,
+                    0xfd21901fe171c014,
+                    0x86b8e422d8a57225,
+                    0x54ed0a1ed73884a4,
+                    0x00dfce1fe83ae463,
+                ])),
+                Felt::new(BigInteger384([
+                    0x51264adc13e190f4,
+                    0x0bbb66b6245e96b9,
+                    0x324c7e64f95c73ce,
+                    0x58c9ab3bf0d2844a,
+                    0x6a1e9c8aac6f78ba,
+                    0x01015cb210617b6d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5d88fed446ff2732,
+                    0xcc5a80a9f7f54980,
+                    0xf3536acb84bb056d,
+                    0x206b06f29ae6e951,
+                    0x73183477032955dd,
+                    0x018707d88f31d122,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e8c6e7644544304,
+                    0xd70d7e31dfbd029e,
+                    0x06d2e114b5e8c460,
+                    0x49fc1529901374a1,
+                    0x76ac5d5881a1016a,
+                    0x005834864f95fe15,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8e0ea0a801372ade,
+                    0x7204ce0eeb1aa7eb,
+                    0xf56fe7063fce51da,
+                    0x39186b4fa4d7f327,
+                    0x3a74a48fd325d05e,
+                    0x00560266f6fa6919,
+                ])),
+                Felt::new(BigInteger384([
+                    0xacdc3ff6a3b3b810,
+                    0x024ec6e3ed56f966,
+                    0x45b9e84456b6711f,
+                    0x9a610298152f4bc8,
+                    0x2938f97dd469e468,
+                    0x01626d3a017185b6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0e59015a237694c3,
+                    0xc7996e1cffefd920,
+                    0x5e89a6700d37a752,
+                    0x2057d7346d4eaa10,
+                    0x1045610338e50817,
+                    0x00ee69b95190bf4a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f2c9e5c3ea2209a,
+                    0x4aff5b64e4a1de63,
+                    0x0dab1a97daa3f4ce,
+                    0xa6467f6f21c68280,
+                    0x1f85e2c917e7a85c,
+                    0x014e45ffd407d980,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5f41376ca6934853,
+                    0x9668a3797b8bcc98,
+                    0xa404efdd74f42cad,
+                    0x610fedabeb513acc,
+                    0x0d325df983efc800,
+                    0x0046b0cd4b835f95,
+                ])),
+                Felt::new(BigInteger384([
+                    0x265518c3af0bdb65,
+                    0x4bc04d1860c2eb06,
+                    0x1603ec8496256e08,
+                    0x370db049eaf77577,
+                    0x1fdff5dee500c12a,
+                    0x0162f16b9c6156f3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0cfece3c262d1351,
+                    0xdef1f5a621a30547,
+                    0x46df12c1126a8636,
+                    0x300e1b2bf27197b9,
+                    0xca706406aa558e7f,
+                    0x00ddf96192696e70,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcde1ef8a186f8226,
+                    0x8db3d38bafa2f8d1,
+                    0x51abb71eccc43bf0,
+                    0xa6c93b7e40938751,
+                    0xd69e5b5aca29191d,
+                    0x0095b0b1c3361efb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3f9e545ab2f829c5,
+                    0xd9a844b513c00366,
+                    0x2686d40d498eb152,
+                    0xb408f8c6d7a71f98,
+                    0x6fc27d0e363b2aee,
+                    0x00800cff88ad34ac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x10a2835f50a26187,
+                    0x16a0e44200b4b687,
+                    0x04756c3154226568,
+                    0x55298eac57cbb885,
+                    0xab368abbbb89a41c,
+                    0x00ca13bd89bcde2f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb51b7e6495d480ed,
+                    0x57ab96ab1b1ec25b,
+                    0x88755fdde4c5d464,
+                    0x266fb0e25f2e0bd5,
+                    0x3b7d269c96a4eedf,
+                    0x00a60036d2ce264d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9822478d636d3646,
+                    0xfaf3f21029aaa22e,
+                    0xe684f7efd31a1bb8,
+                    0x7315a1d797267b86,
+                    0x04b5585a94d6d0c6,
+                    0x000dc45fcf508e7b,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 470, 471, 1415] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 470];
+        let mut opad = vec![0x5cu8; 470];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,3260 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 576 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 10 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 10;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 13 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 13;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0f90ff98075c7595,
+                    0x1f88cdb6a0b071c4,
+                    0xc2544cd6583d8112,
+                    0x79b66c733b7322f0,
+                    0x668e2b4da09ab931,
+                    0x00f70823d400bd0c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee1ecf1b0fccae18,
+                    0xaba621be57675df5,
+                    0x3f1d031a14d76bab,
+                    0x5613bb85a3e0a70a,
+                    0xb352433b412375ee,
+                    0x012cfc782550cea4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92849b2c8474d9d4,
+                    0xfa89bf174b2a7fbb,
+                    0x33b9b4cda878d2fa,
+                    0x387952788ef6c758,
+                    0xee6387c5aa223337,
+                    0x00c68f42d2d9ce68,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9a7b92e203eb810b,
+                    0x386f3091131b670f,
+                    0x0f27b0ab09a63886,
+                    0x2abc7fe6651c02bc,
+                    0x59c1237320eef8d3,
+                    0x01a9304fa2bfd979,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71add74e59606e2a,
+                    0x89f58477581f8492,
+                    0xbda196b24711a308,
+                    0x622b4a5419bb39be,
+                    0xccb37607ed72d099,
+                    0x009efd0236878e6c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd709b07b3f17b3cc,
+                    0x9a63b643325703b0,
+                    0x6d59e8ebe406530e,
+                    0x38c7e80129c4e3b9,
+                    0x6345656ed3dceab4,
+                    0x007fc64f205b6770,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfaca19a69909bd95,
+                    0x00572adb0e72b46e,
+                    0x3ab668b632eac402,
+                    0x4d85af8585f0a243,
+                    0xf8eb9b94d5e8df17,
+                    0x00457709a4f6cb57,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53d1e495d99d79d3,
+                    0x747a086043546ba5,
+                    0xa22542f4cd1fde67,
+                    0x43098680d8df11ca,
+                    0x74c406208dac495f,
+                    0x01a768b6b06a35ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7f044b480b183bec,
+                    0x84cb2a6fa159ad14,
+                    0xe7f073624ab0f5c2,
+                    0x694658322c4dc068,
+                    0xca02cd235574132f,
+                    0x00a4a9b2a1903a76,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf548f86368408630,
+                    0x47e29a44c28ed9d8,
+                    0x1a7e55f988f00135,
+                    0x7b1d988f82476130,
+                    0xc5b5fb4577cf06d0,
+                    0x0031c4ed399781d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbe747aba55df1e91,
+                    0x0fa1f05e2c271e25,
+                    0x8359e130264b78ab,
+                    0xba88952aa7ce0e71,
+                    0x2ad68aef0d58dba6,
+                    0x0069ea7f5b86cd7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x35ebc45ed7d42e25,
+                    0xadad0597bd1f8d20,
+                    0x7c66692cbb8a2ce3,
+                    0xb15402ce2136a7de,
+                    0x0fad035cdf9612e6,
+                    0x0168b8699e91f470,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x293594a5d20cf00e,
+                    0x660e1a56be89ae3f,
+                    0x0250ef65154c5a00,
+                    0x42916d2b63b54e1b,
+                    0x84b2b70ffbf3bf59,
+                    0x009d76c723599b63,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbc2b47606231ba94,
+                    0x29370ca586112522,
+                    0xdd7182e2236287b3,
+                    0xf0c8894d494c511e,
+                    0x75cef4650d162415,
+                    0x0130854144221e41,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5886036901a80058,
+                    0x6659db642fe00b42,
+                    0x4def95e41e12e858,
+                    0x95d3358f9ac82249,
+                    0x660cce0cf6c591bb,
+                    0x0035b998cfe24b31,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb0595f83956b58de,
+                    0xc90c3ed5b8a72a36,
+                    0x510d311b533debb0,
+                    0xa4c900e3598722ac,
+                    0x822a639f29b60a98,
+                    0x01356221514a396f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe1610fa8cd41143d,
+                    0xc69cf535ced55c4c,
+                    0x69f2d265bba10e07,
+                    0x649b51684b6c9949,
+                    0x30969a291a4d8c75,
+                    0x003e2361c6258df9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaea799047bb2ed66,
+                    0x888aa171f441c470,
+                    0x245ea5172cf6c072,
+                    0x2d208abc49499892,
+                    0x6312831790fe2d6a,
+                    0x002737cd8edf6322,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0025b156b2782fd4,
+                    0x2a9b75cb62ddf71d,
+                    0x66153f4b863046b8,
+                    0x155bb10348afafcc,
+                    0x2055fec31d49ea0d,
+                    0x00ff8e67ac2d47e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd88ad65a216b4cdf,
+                    0x80cbef7d50790883,
+                    0xd7806b79fe9890d0,
+                    0x084bccb1ce4402a8,
+                    0x83e2eb672e90b8e2,
+                    0x01930272a959006f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x08162b96b26a4757,
+                    0x6d198fc82fdd9540,
+                    0x8a38d4a6776d6bcf,
+                    0x873155d025004007,
+                    0x9598bb9096f0101b,
+                    0x00d6d58ad4ba6cb1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa76d2268d6bd2465,
+                    0x77daf30c21faf931,
+                    0x98744698b3438f07,
+                    0x2f115dca4fa686bd,
+                    0xee6d134618e325be,
+                    0x00ac57befe51c678,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3fea7acc03b99da2,
+                    0x86528e4d4a1d465f,
+                    0xec16fad894ada50d,
+                    0xf5c9a50dfbc84ca7,
+                    0x3274a5a8aec6fd50,
+                    0x01a5ad477610646d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb758d372dfae19a5,
+                    0x9fb48f32fc177d07,
+                    0x2b4d94def3a64099,
+                    0x1f58971afee8b3fc,
+                    0x5c9d2565b5939808,
+                    0x0051881cc1ea5a25,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe8ebf8f23bb73475,
+                    0xdf336d1d272a1a10,
+                    0x6ccd0f444735626c,
+                    0x592f277f2b409add,
+                    0x99913c124702a4c9,
+                    0x00b27cb3432a29ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0d49c611507a9616,
+                    0xc4b88ba3ecb2cab1,
+                    0x5555b177ae8390b1,
+                    0x97456c8148e884f8,
+                    0x64b9dfb6470bc895,
+                    0x00ecce710bd2ce82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x84a48ad853cad7c0,
+                    0xb4d3dc9c74560982,
+                    0xbd0c0d4065969793,
+                    0x5babcd888325508e,
+                    0xbe210649684f77f0,
+                    0x0167fb2e6120d29b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x858f14f6f6e97028,
+                    0x04fe8800077622fd,
+                    0xe1d99425ed064840,
+                    0x56321923e17c2898,
+                    0x3afa2c52de7cd273,
+                    0x00c843f794cc5e83,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb1b42c41807dbee,
+                    0xe441adef712a118e,
+                    0xe5bcf7a844affe7a,
+                    0xe1b2fa58859574a5,
+                    0xeef39d532596f1b8,
+                    0x01a59af3a321bdd8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x25fdc2295baa2073,
+                    0x550ff85d398f8548,
+                    0x5d292e3eb78bbcf2,
+                    0x2a8c452f279af42e,
+                    0xdf2e878a501c0f0a,
+                    0x01a7cb292b481f90,
+                ])),
+                Felt::new(BigInteger384([
+                    0x81c5cb134a33707f,
+                    0x2699722490252a81,
+                    0xcba005a564869a2e,
+                    0xe9b251acefe4238b,
+                    0x77a7a367d1e6c481,
+                    0x01673ccf2562c7ac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f329eb56caff241,
+                    0xa31f4b3f67fc0399,
+                    0xb471a6dc81bb517f,
+                    0xd4b7a715797a85f9,
+                    0x7041343566993a1f,
+                    0x000038810ccac1f2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77fb6f91bf990d99,
+                    0x580f385a4f856ef6,
+                    0x667bcbffafc689d5,
+                    0xced583a60a6ca458,
+                    0x57989e77cc309287,
+                    0x0061c1af0482eeb4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x090d25dc91eab927,
+                    0x07620aa1d96d2d4c,
+                    0xa2e8c83f8ff7742d,
+                    0x392b7c4184094331,
+                    0xfeadf69586aaee80,
+                    0x0160269842804a77,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52fce27333ec9af6,
+                    0x55d96ee9d3a477f2,
+                    0x9280881e59bfac6d,
+                    0xc3f77a6f9f0937ba,
+                    0xd405f7876652501c,
+                    0x001b6a79abc806be,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77536ccc52eb135c,
+                    0xf6a7568ff1690f8d,
+                    0x4e361c962db8b036,
+                    0xadc3181bb7f409ca,
+                    0x3bb2b6e52948e15a,
+                    0x012ae58d6863701e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x79f7fc82349424ef,
+                    0xd2d00da1e8e91558,
+                    0x868a823d3b4de2e1,
+                    0x172bf5b4bfcc26b3,
+                    0xa8299b06ae1019f6,
+                    0x013239ca83c974cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfecde9315124b24d,
+                    0xf63f627101b5a740,
+                    0x6f63b3dde626dfff,
+                    0x619e14e15cfdd6a6,
+                    0x58517f8f35a448ab,
+                    0x012741efb17d4fd2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x35f7d3761042c439,
+                    0xaf6e252ef0bbd7e5,
+                    0xbbd9be7910889c3e,
+                    0x05038f9fa6c5b44d,
+                    0x2d2793979f0455b3,
+                    0x0155e02d1d07dfc4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ab2e20845f2561f,
+                    0xd1ec8ee969fb077f,
+                    0xf335e80957d8fd27,
+                    0xcbac5210be8472b3,
+                    0x5970ce1ca729ae68,
+                    0x014f7a033353b7ee,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb000dc7ff62c3e5,
+                    0x1ad231788abe0ae9,
+                    0x57208640f6a97ad4,
+                    0x36aeac363afe384a,
+                    0x229e17be4146b444,
+                    0x01524d09f6a63088,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a1f1d6121fd5c2b,
+                    0xafd25a8d912a1a45,
+                    0x4ec494a44409b5f5,
+                    0xd4e638a7117f0f56,
+                    0x0f9ef323b36eba24,
+                    0x00595f120b919656,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43a31af7db6bedb6,
+                    0x60f915946f8dca53,
+                    0x5688660ba40e63df,
+                    0x5d819242b3e3e99d,
+                    0x37485f672bece244,
+                    0x01a30fb6a2569754,
+                ])),
+                Felt::new(BigInteger384([
+                    0x355b0a099ec7eaf7,
+                    0xbf786ee133da66f8,
+                    0x183556aaca52665e,
+                    0xa5f67cdc132630b3,
+                    0x2cde409d0012e10f,
+                    0x0085bb1846490b9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8720cc81b8e0714b,
+                    0xb4f42a40b07bec92,
+                    0xcd46156549032e12,
+                    0x5e101b167d52e4af,
+                    0xfbef129c835bd0c5,
+                    0x0140c80d85832933,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa6273922c1b3c3c7,
+                    0x43a24dbd41044e38,
+                    0xdfde9726f424a092,
+                    0xde414566e2d1606b,
+                    0x3cef9e8742a6a0ce,
+                    0x012eb555917ddcab,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2f246fae203d192,
+                    0x85d960ce263acbcb,
+                    0xcc11e72a96bd36c8,
+                    0xdef9b1443b9e1588,
+                    0x10d28b328a5fc23e,
+                    0x015560087d1445f5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf55b8c78e404abf1,
+                    0xf5e7d41abc2b4bff,
+                    0xac56567b4632b80d,
+                    0xb0a683102a239a06,
+                    0x090765f77c602052,
+                    0x007ddf32eaf8e8f7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xec1bf7060ac2ea8b,
+                    0xf758d86624b27b5a,
+                    0xaa072f838ea38b80,
+                    0xfe64e7c3803a528b,
+                    0x8e1ba0e6a6fdf9ab,
+                    0x0101e4e739811a8e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x88016e78bfc3ff57,
+                    0x3139875a589dc6be,
+                    0x79c48b74a5c796f7,
+                    0x06f35929f248d279,
+                    0x0cab741af9662dd6,
+                    0x00aa38b6190d2305,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f4ab1a7614f5ceb,
+                    0xfac34e85c916c2f9,
+                    0x2228424aa4fdd682,
+                    0xe021cf3786212324,
+                    0x1e92fdb4e5dfa4a8,
+                    0x0138241a6ce0ac81,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53a9a346926270fe,
+                    0x5639327933056782,
+                    0x3151ab8ec7da839d,
+                    0xfa876995419e1f84,
+                    0xafe64da8d52d7c05,
+                    0x0050fc50bb200011,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa77c12418132f6c5,
+                    0x1225bcaa0d64157a,
+                    0x5352ee42c20366f3,
+                    0x759a2846feaa897a,
+                    0x8a73ad929f1e39a9,
+                    0x01a6b97d8ff74106,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd6b4cec02ca7afc4,
+                    0xacd2ef91b813d4d8,
+                    0x9b8216a1fdfe4682,
+                    0x60504306664ca1d8,
+                    0x420c1a39ec266d68,
+                    0x012695246bedac20,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16a56513220efb06,
+                    0xb3a718204c5a798d,
+                    0x6e8d46ddd4b14f6f,
+                    0xbdad04f7ddc432e2,
+                    0x909860f4238ee618,
+                    0x0199165894781004,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3cb9932099f14905,
+                    0x5fec72fc387f0a18,
+                    0x537b7b29d9ec22ae,
+                    0x325e24c36049d310,
+                    0x748481dad80a3659,
+                    0x014bfb743bfb8285,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17e5a5020adbf129,
+                    0x2b571aba5c5ac0f2,
+                    0x0c5bd18988bdd206,
+                    0xd803f92777eeffe0,
+                    0xa7159ead4361f177,
+                    0x017e39239e8de95e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7dfe594ea7083bb5,
+                    0xf4ea686d12735c94,
+                    0x2b69303cd789e373,
+                    0x92a825ad47986cb9,
+                    0x2b7ffe2ef8ee32e7,
+                    0x01a32dc704bef595,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb1c3881bd27bd7ae,
+                    0x68f58921d14bf741,
+                    0x1f3b850f735454e9,
+                    0x23ef4bf4d06c9125,
+                    0x1edc5b41ad0f49cb,
+                    0x0167f8379b9fa02d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcef2522a693f6b5d,
+                    0x45c6802f7d54bb8c,
+                    0x77968e706679c8f0,
+                    0x65ceb1e2fc1dac7f,
+                    0xbad61459e9922893,
+                    0x0084228adc9233c9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8d05d1aa399a0239,
+                    0xbdf9a582b2fbb539,
+                    0xeef89f33e5e20d39,
+                    0x78a946881c38e435,
+                    0x24b6eb679c66ade1,
+                    0x00dd3fa54435943e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e34d4d35de41027,
+                    0x803a115f0960b0ea,
+                    0xb79b5f6f485766c2,
+                    0xa1c6d5d95c88fb49,
+                    0x257d0b97bb44a25d,
+                    0x0155036d1814d459,
+                ])),
+                Felt::new(BigInteger384([
+                    0x84537f2b629fa578,
+                    0x1d128e85c696589f,
+                    0x7416028e69fcf5f5,
+                    0x5c3103d33da68183,
+                    0xfe5c128f41acc600,
+                    0x01161a55048e4937,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8744ab9c9a6f03f,
+                    0xcaef066de94ba1e4,
+                    0xb21535b3dc893a7b,
+                    0x2a91fd87d1bb4a4c,
+                    0x983c2ff970cacef6,
+                    0x019ac5634023523a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ab0637dcd4d3a1c,
+                    0x42d6507579d56d51,
+                    0x3da4269895caa46f,
+                    0xd56c957d491606d9,
+                    0xa1b32eaa3ee9a815,
+                    0x00338569bd5294ee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b5fbf3ed0047b95,
+                    0xa549898653a00488,
+                    0xd3d3879616e81168,
+                    0x0b2ecba494df79ac,
+                    0x9661c70c1538f4cc,
+                    0x01a38e78fbeec479,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1938282374da603f,
+                    0x2050be19eb0f59dd,
+                    0x0531bdeaaa7247a7,
+                    0xd598cbcb65d308d0,
+                    0xf914ca906eab7928,
+                    0x015797049a83fed4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x39f1f62656e9f7b3,
+                    0x8e0872207da1898d,
+                    0xf2583f926f0986c3,
+                    0x3e09d8e99e561cc9,
+                    0xc1e3b14299373e77,
+                    0x0155f96cd945374f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d7b44588bb08d0b,
+                    0x21f81a574ec08848,
+                    0x9968629919499d8e,
+                    0xf3d6ca9993bad875,
+                    0x9bce800e19000907,
+                    0x00aa3537dc42252c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x237fd2052d1ea449,
+                    0xeacb5f10d1b7402b,
+                    0x6b45498a5ab3bf9f,
+                    0x12f4324f1d79e00c,
+                    0xe9fabbc7020f6704,
+                    0x012e7162da78f2f3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x349a4de4cdee32df,
+                    0x69b0aa603d7bdb1c,
+                    0x0a4bdbf2609a48f6,
+                    0x06278db741360ef2,
+                    0xfd7f2d45c7ce1307,
+                    0x00d78b0238e300fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8aa547f26f90a05,
+                    0xded1e8521ba43f86,
+                    0xaa67acf2330857a2,
+                    0xb3d0a67f5438b6bd,
+                    0x16fff4ed0b189ea0,
+                    0x00f45cde6afc5c02,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf0e971bc5a2eb834,
+                    0x2f47562c5d449de6,
+                    0x8daf9c2dfd24e61a,
+                    0x3a83a753dba758ca,
+                    0xaff6bc03671591c5,
+                    0x00da234eded6d391,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe4d120beb77962fd,
+                    0xae9e97fe66759594,
+                    0x5509f46c70f54824,
+                    0xae3d51436f6467ae,
+                    0x1b807a3b79092ec2,
+                    0x015b0ef06d03d62d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb025c0e068a44d7c,
+                    0xcd10dcb1b1fa1359,
+                    0xb4dacc19e7d2ff51,
+                    0x1dc7f057abbe1ced,
+                    0x19d82deeab5d6c02,
+                    0x002e1442e0eac0d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b375e1dbd8167b5,
+                    0xfa9862eb0a56391f,
+                    0xdb076c365f07459d,
+                    0x8e69e381ef7b35e7,
+                    0x90d8adeb1d23b5f5,
+                    0x0096b6d53a24909b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc643fdf8386e2de0,
+                    0xc7953bb71b5a4d04,
+                    0x73a17e1723e67fcc,
+                    0x7b8bde5fd44c6847,
+                    0xa58bf604dd0818cc,
+                    0x004c3ec332f7112f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc46dd8ea0cffc394,
+                    0xfa08d2b70673fa4c,
+                    0xd12572a0bdec4869,
+                    0xc91c7e97c6919283,
+                    0xaaad01435086aa96,
+                    0x008f2c6a9d91a9d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5edb11cc7dfb4394,
+                    0x1a2c3501014ffdb9,
+                    0x51881bf17ef4da60,
+                    0xad514725d9c78d12,
+                    0x15ec1208b204a0bd,
+                    0x018a94f4e41e5968,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf8412278cdbf7e15,
+                    0xb0b677114099f43b,
+                    0xebf565eaa17dd2f8,
+                    0xfd91ad20eba7f8ba,
+                    0x57a333c2006624d7,
+                    0x00aa81d40682b7b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea331713be8d0c1c,
+                    0x9d3c84a79defe174,
+                    0x16a18f62ac4fc6c0,
+                    0xe19c523bb73037bb,
+                    0x977b559a77f79715,
+                    0x0162ee22d7bd1a60,
+                ])),
+                Felt::new(BigInteger384([
+                    0x18df2e7b01ca651d,
+                    0xf237a22187a729a9,
+                    0xe4f4cfbaa32f1728,
+                    0x0a6b2e049dbb6476,
+                    0x0eadcc476af9e114,
+                    0x0105263b146cc25c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x938f37d49aa5c75b,
+                    0x91ccbe8ab8853b40,
+                    0x14896bb6a55c193b,
+                    0xb9348387d62d5f19,
+                    0x2fe19887ef547338,
+                    0x003c6727c09ced81,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1eefc9c6731c4489,
+                    0xcfe0c7994bdad882,
+                    0x6532ac9cf0ff9190,
+                    0x80306da19a8ab019,
+                    0x809693cc529e2575,
+                    0x012aeb412a96b524,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfc188bca8ba87f40,
+                    0x2a647ac655b3b285,
+                    0xbc6829d44791a219,
+                    0xd0037d7a3842e9e8,
+                    0x69ae2cb66eade43b,
+                    0x00f060a839aa7733,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae2290556e2298f0,
+                    0x4508ec8b864f14d2,
+                    0x5050f2384c9acc39,
+                    0xc3b0be19571da031,
+                    0x9a41dcda461e9752,
+                    0x001744f252825671,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6fd0d9cd5a766b2d,
+                    0x66646d71b426e55b,
+                    0xc9fa31013e2a191b,
+                    0xecf76cf7bb559a53,
+                    0x3e7ce536e097513b,
+                    0x00d1e3bc03ce0224,
+                ])),
+                Felt::new(BigInteger384([
+                    0x86d6ebeb184e4633,
+                    0xb238473773eeddc0,
+                    0xd4cc593ce60e41c4,
+                    0x731de79330e7bbf4,
+                    0xc689cdef61625091,
+                    0x006ee33d6e79ab32,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4893739c4e6d1895,
+                    0x476ae77be2a13abe,
+                    0x2405d17583f2c6aa,
+                    0xf023a64d6bcf724d,
+                    0x4db24a7699d9fa7a,
+                    0x002a74621f582130,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56317ecfd8e3bf45,
+                    0x632567d1ce9fb06f,
+                    0x3c2aa85e2ddef1b5,
+                    0xcbcc42a751791d54,
+                    0x851af4ccc94d2884,
+                    0x00571b29db49e37c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5d256a983e015fed,
+                    0xd805d69b88a8adf3,
+                    0xaadf9617841660ac,
+                    0x4629f12ffeb9d96c,
+                    0xcf9fdd62a8838aee,
+                    0x00fe619e7159f12a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x670c0988c1a550f8,
+                    0x851ca334327223b3,
+                    0x62b6a6404ea68e2c,
+                    0x73cca7114700c8f9,
+                    0x04261876db6789de,
+                    0x00b97a0e9189ada0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ac248fc336fe9e6,
+                    0x32843b3bf1e624a0,
+                    0x952d84994004e3b9,
+                    0x9ba3af278d1fe75e,
+                    0xaff1f43e6bd4b088,
+                    0x018b860eb9ceed4c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31213660e6ff0261,
+                    0x842069d37ed8b48b,
+                    0xa149006e9cad8ee7,
+                    0x8666a4eb160e9158,
+                    0x42f52e0d2775e48e,
+                    0x005555aca6d41aa6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2dcba814560eac46,
+                    0xa7c5078af72cd353,
+                    0xc3440a85c28e41e2,
+                    0x5a077ef44590077f,
+                    0x325eb7fddd8ae77f,
+                    0x01455066f5b25d6d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7662b04f90879460,
+                    0x02fa9e442852ee07,
+                    0xa7fb12af2f8e1b80,
+                    0xfb70b6138e94b81c,
+                    0xf4a09462148dc12e,
+                    0x0079fbfd8475937a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa5a39071cc1c3b92,
+                    0x52b07b8a082cfe67,
+                    0xf3e32a24fe34b269,
+                    0x832adeeea987251d,
+                    0xc7a90a5324900467,
+                    0x006b2bc18ad810ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdae8c98c184c7c60,
+                    0x228b4e82bd6387f9,
+                    0xa56e3227141ec91d,
+                    0x145ea5c777474f2a,
+                    0xcc6cb6a322939d7a,
+                    0x0108f6b06fb442fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd7d49ad6db865c4f,
+                    0x70111c86492d6c38,
+                    0xec327748005e6dd5,
+                    0x44de66dd72dc451a,
+                    0xdb2f686db86be3b7,
+                    0x011ca697af6ff21e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdcf2acfd8e833b4e,
+                    0xb25bb304d102582f,
+                    0xd1d1b78f7f66fe50,
+                    0x45fbe5d9f2fa92f5,
+                    0xcfd65632552f7f48,
+                    0x00128bef7fee461c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e30889fa44be44b,
+                    0x6c6daa025188faaa,
+                    0x6152992d867d31c8,
+                    0x024ac5379552d3bc,
+                    0x6c363a31fcc607cc,
+                    0x0025dc85fcc83831,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3736bc8ed4131cad,
+                    0xbd82264ac1cd2fd1,
+                    0x71ed99c15b2a07ba,
+                    0xef7366f32e64da13,
+                    0xe340957d75683a5c,
+                    0x000981b15b2cf8f9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3ab4f6518b93d513,
+                    0xd3f329f568a7c8e7,
+                    0xafb24cbae8d747d9,
+                    0xdb0d1e9d93796ef5,
+                    0x84fd9b7983d2e59e,
+                    0x004c0c36bf421426,
+                ])),
+                Felt::new(BigInteger384([
+                    0x02741d9b5bb8795d,
+                    0x84e2bb221073925f,
+                    0x8b92e232ecdb09a6,
+                    0xc8ce244d0fe341d5,
+                    0x57cc18bc7ebd1838,
+                    0x0089a73afdf20e82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x842c808827b0ebbb,
+                    0x87e23bd991bcf1b5,
+                    0x2bb0bdeda2178c1a,
+                    0xb16ca32b4f947ed9,
+                    0x909a574d86ea7e6a,
+                    0x0020e6fb99cf4ce7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76e7b7b3bf7608d9,
+                    0xbebb2004d1b94a0b,
+                    0xa39e2709e4bc190a,
+                    0xec4085be948c714f,
+                    0xe671f4b1a8ce6253,
+                    0x00cfbf6273ee83af,
+                ])),
+                Felt::new(BigInteger384([
+                    0x09ac5711b1d40f34,
+                    0xa3e4ef925c2b3c98,
+                    0x3aff9e2d877767ea,
+                    0xb7bc1bf3a1eb5d25,
+                    0xd82751982130ae8d,
+                    0x00c82045779b1cfe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x38ab195fe1274b6b,
+                    0x21d7ddcf719996c5,
+                    0x33decfb6c5fc8c96,
+                    0x04fe87932be88d2b,
+                    0xed66595e5d7ed8de,
+                    0x00a702100ab1a1c7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb7aae3576ac785dc,
+                    0x3443df54fc7fc5e1,
+                    0x385efbf8b2cf8d2e,
+                    0x3f9e9ea2ceafcd40,
+                    0x5f6af448aef65db4,
+                    0x0176eecae790a6fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x14122782c826dc44,
+                    0xafc2470fa3243582,
+                    0x602c111da619dbab,
+                    0x37f703bc833ea7c4,
+                    0x1796d63c5329e36d,
+                    0x015d81354b98034c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x396bcbdabb0e31ef,
+                    0x1021789f63ee9a11,
+                    0x11e536bc69898f9a,
+                    0xb026fb38a10881ae,
+                    0x89489c00bf09c060,
+                    0x003f4b12d2d3111d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x94bfcccaae18b477,
+                    0xd3f15f50e9414ce3,
+                    0x708a8c2a4441d534,
+                    0x54b3504d4ac076fe,
+                    0x507712ed003670c4,
+                    0x00b1ffe283986bce,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a594b6cdd906cc1,
+                    0x554898462037cd12,
+                    0x7c6ff93536728be4,
+                    0x6d9fef212c3da5aa,
+                    0x7b0e095e93982a3a,
+                    0x00c1983b0b95e3df,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd21768cb16cff364,
+                    0x06e25400a58679f6,
+                    0xebb2667ff78f4425,
+                    0x9c333da91b3f8a40,
+                    0x1b270eb8ee091b67,
+                    0x018e5649e16e16ca,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb99e2aeb09c1253f,
+                    0x226c9cf0ac48f4ba,
+                    0xb167786f87a73523,
+                    0x1742390b9d4bf353,
+                    0xd88068bf9bdb2b82,
+                    0x008f92308628ad8f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0d2633db485270b9,
+                    0x154d9ba2a3d62ed2,
+                    0x7351dd915451bb40,
+                    0x0757409bb4d92cc2,
+                    0x43e6677617380a22,
+                    0x015f1a1320e0af1b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x252e46db016bb268,
+                    0xe12313bb393fbb1d,
+                    0xad5eb027ea98f9d4,
+                    0x45b11e964d6d80c9,
+                    0xc76d667f7a62de7b,
+                    0x01604ded2b0a201c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x379a8c4d9389616d,
+                    0xbd67a642187144ab,
+                    0x620880405cb911fc,
+                    0xa6e9a82d431ef2e0,
+                    0x5aed783da9d52355,
+                    0x0061cdd76f86bab3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c9d6da387545fa0,
+                    0xe2e0ecacfed0d3c1,
+                    0xfb1f6a6f62cf28ff,
+                    0xbb013885015d9ac4,
+                    0x4842be987d7e1d93,
+                    0x002e9a02d90629b7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c4829d8721604b5,
+                    0xd97e7efddd5edaaa,
+                    0xf50e518d982f54ef,
+                    0xce7afe0c8cf390eb,
+                    0xb5f7ac89e82afd69,
+                    0x0102fc390abb883a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0e5e04e3567e42bf,
+                    0xaa191ef2668f2cfd,
+                    0x220ba932296cf83f,
+                    0x7b9b44979d7f302a,
+                    0xab655fb67071ec22,
+                    0x00d4a262cee4a9ee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x72dee678e027a615,
+                    0x27c35eaed0eec496,
+                    0xd7d5f2c762b0f75f,
+                    0xffad84bc1f193364,
+                    0x09787faf943976f5,
+                    0x013b11a4d2a7578d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee055770eaf141fa,
+                    0x37729a76418bcb3e,
+                    0xafe8bea0cd1883e8,
+                    0x06fbfbdc2ce108cb,
+                    0x3cb8478342f27cc6,
+                    0x006e92a6e89193ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6a5f1bdd602dba17,
+                    0x3d0676c294299b93,
+                    0x52319bb6fa49f821,
+                    0x89f5cc1c1dd95203,
+                    0x73b625f2fa48acad,
+                    0x0127f1643fc369c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe68547d439d048e3,
+                    0x5dd94ff6f793b4c6,
+                    0x17fcd2062606a4e2,
+                    0x74a86dcc517ee0b6,
+                    0xb017664b307c84c1,
+                    0x001bb8be981bb812,
+                ])),
+                Felt::new(BigInteger384([
+                    0x29965e6c1ff02dc5,
+                    0x50a9874b6e3577b1,
+                    0x4c8e23e38c390b61,
+                    0xd4e374a2be7a9390,
+                    0x29c5e4691abac4eb,
+                    0x00a851f5b6c2f923,
+                ])),
+                Felt::new(BigInteger384([
+                    0x268a4d40f27233c7,
+                    0x3be812f87b492e6b,
+                    0xbcd9c45f21741672,
+                    0x575a68ab8edb5776,
+                    0x10472029d28ddf15,
+                    0x00f521b8fbd7f5ce,
+                ])),
+                Felt::new(BigInteger384([
+                    0x031c7bfafcf421f9,
+                    0xb3fa80d7d3aa9a44,
+                    0x94b5136beaa3c891,
+                    0x49d1e09029a07845,
+                    0x776e7a162fee13fc,
+                    0x001d42045cddec68,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44d1b2d29b9524bf,
+                    0xaf52afe6dc0bea02,
+                    0x574e255e516e6a3c,
+                    0xed59b696fae27b90,
+                    0x23e45225445dd721,
+                    0x00aa0286495bf812,
+                ])),
+                Felt::new(BigInteger384([
+                    0xead70bfcdc8c2bc4,
+                    0x3ca36c6d5723834f,
+                    0x619cc8b3df8493a4,
+                    0x58d2db627501b435,
+                    0xfe418c4c89569cf3,
+                    0x0190c07aca137806,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf0a555f2bca05b6b,
+                    0x661637785835427c,
+                    0x2853aafce91c4732,
+                    0x509beade215f57a7,
+                    0x458db05b43ee3c23,
+                    0x002ab610541a68ae,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdd0d49c942a0698c,
+                    0x494f2dc024588205,
+                    0x27e65a209dca3683,
+                    0x0172e5cdb364ae92,
+                    0x6a578bcefbc1ad9a,
+                    0x00ff35685952c5f0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8bfd78fe1deee860,
+                    0xf4ccece8700ecd7d,
+                    0x481dabbef32c0fbc,
+                    0x7a6073696e867c77,
+                    0x3575b27a0bce4519,
+                    0x0112d5747f01f85d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc3dc2a5576059ceb,
+                    0x50ed9f0611dda103,
+                    0xc524120d477ee171,
+                    0x84f7e2337cb5ecef,
+                    0x8269c877b14749c7,
+                    0x00d2652228998c48,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d1f19c470abd5e4,
+                    0x7029a1fcfd9d454a,
+                    0xc4267af82597471c,
+                    0x6a98cf21b5351341,
+                    0x0836a3f61247e389,
+                    0x0134beb077dba01f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc87d94c4714fbfdb,
+                    0xe6ff333a39039d5f,
+                    0x7858d3aebb54cf9c,
+                    0x3efcc1e18ef9f6c1,
+                    0x61856ca2f15e75b8,
+                    0x00506d303ede2724,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1159fe635f1d87e2,
+                    0x14757672e6a7289f,
+                    0xda4125375990d6ad,
+                    0xc4964304d86a0349,
+                    0xf82ae19372dfbad6,
+                    0x0016126d5145a06c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x38b9e1bed69d8133,
+                    0xa3fe6857cc53a083,
+                    0xf0073fed46d08fda,
+                    0x3d5a47b0ad5b89bc,
+                    0xefaf95430627730a,
+                    0x0151b1646a4a89ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x64724944cb5b45ed,
+                    0x5b29dfc0f0891cdb,
+                    0xc10c705ecb1a5086,
+                    0x0cc0a8b763bd79ca,
+                    0x2e7c6c15b28d3ec9,
+                    0x00ec12755cd22d14,
+                ])),
+                Felt::new(BigInteger384([
+                    0x315b583272dc9794,
+                    0xfb5a48150fa420f9,
+                    0x6bc865b06b970769,
+                    0x5c1c4648127545d8,
+                    0xdaf6694b0a3639b4,
+                    0x0151ffbc49810179,
+                ])),
+                Felt::new(BigInteger384([
+                    0x282b0df1ba80afd4,
+                    0x8b5f7cf2da7fc156,
+                    0xf41aef668faf94d8,
+                    0xad6ab57fe96536d3,
+                    0x3501f47221cda2ed,
+                    0x011639e1571689cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x80dfedda78c39332,
+                    0x55cfa3977a859efe,
+                    0x008e48b24d933517,
+                    0xb92e2ed5e2f94714,
+                    0x571daaa6bfaf028e,
+                    0x0144d08e5e5789cd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5d0d8cfc98674b0d,
+                    0x09a6a60f67f3b6fa,
+                    0x49a848af217ffc41,
+                    0x51f9771a2aefc0ac,
+                    0x53e56aa04e5f5ed1,
+                    0x00ca2266ef8746e6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x08e81ba877106d32,
+                    0xc95409210e75ee2e,
+                    0x96651017694bbf3d,
+                    0x0915285e0b2c78cc,
+                    0xecb330b5bb16104f,
+                    0x0086ae6971ef6d6f,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8d9050412c302b4d,
+                    0x0935dfc14bcffc46,
+                    0x7207ad6f0f77752d,
+                    0x18b6b32598ca81b3,
+                    0x085a9abdbe63d298,
+                    0x00be9683fb690cb2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ade78fda1bce066,
+                    0x2c2e14eb5f2b0d35,
+                    0x0505a3eb7f20638d,
+                    0xba649e10328d114d,
+                    0x06094e05302beef9,
+                    0x004fd282b97e2646,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb42348f6984f55d3,
+                    0x882346163383ae99,
+                    0x90400f4bccf4c7ae,
+                    0x3284b6886b771a11,
+                    0x0815206c08ba2b92,
+                    0x00cd05f967a0ef4f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf1d6225fa5fcb473,
+                    0xa5ac64166276c54e,
+                    0xc691f1d58092aa8e,
+                    0x3b7e1d8a6b5b75b5,
+                    0x0982d5f5709c450f,
+                    0x004aa72247ad61a2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31a899442d49095a,
+                    0x9291f4dbfe28b922,
+                    0x34c61db8b9fb67eb,
+                    0xe7721288e5e9ce4e,
+                    0x97e4a3278f925c64,
+                    0x001e3d6cbde7d4fa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbe0c0533b118c8f5,
+                    0x232b489b39e7fdaa,
+                    0x6ae20e4506965277,
+                    0x13ec0aec59d298ec,
+                    0x55dac44994aaa4c4,
+                    0x0160d4a52d386f1d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x710e567d7ada1935,
+                    0x25d1632de6b4a6ce,
+                    0x194ceaf3f40b62c6,
+                    0xe0ed3b0ca068b85a,
+                    0x7dd50dab365d1e0a,
+                    0x002bb99bbdd6e2e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x469125f057d49ff3,
+                    0x6513afa884dd9159,
+                    0xe7223384c3b4e863,
+                    0x987151056172b1d3,
+                    0x5c17a930bebeda32,
+                    0x00bf84348fe9c650,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8b4cd62df5a12997,
+                    0xbc2b44a9646d7cce,
+                    0x3a0065583370d7e8,
+                    0x3c88147aba17ce43,
+                    0xc6705f8b3eafe74a,
+                    0x006ec2466d1a5d87,
+                ])),
+                Felt::new(BigInteger384([
+                    0x49ec6438d35888fb,
+                    0x7f065214066c8ddd,
+                    0x87532b567e851066,
+                    0x3c5eda53309155a9,
+                    0xe61c690f4ffc35ce,
+                    0x0013cebd93a18f65,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96bca804e5dd1a96,
+                    0xf3546da0636c4ec1,
+                    0x3ccfdcbfce9b7db4,
+                    0x044da00662579a62,
+                    0x9dc6a2fed5103fd8,
+                    0x00691f239c6bb8f0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96b59b024fc44520,
+                    0xb9d8404054f70ccf,
+                    0xdc434f1e76b6814f,
+                    0x6a77ffa41902c6c8,
+                    0x086f58784133fa38,
+                    0x002fd124e5bb4f94,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2cbab0ecf9907400,
+                    0x2c9b2952dae2e7d3,
+                    0x1416ba45997efa84,
+                    0xb767c16a875270a3,
+                    0x854279f245939cef,
+                    0x019835d42796c32a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb58db5fb8650c99d,
+                    0xe73425d07a858e92,
+                    0x7a7f4b12f0e6eb18,
+                    0x2c414e3b374f026d,
+                    0x431d132f7a0535e4,
+                    0x018f201bd57ca423,
+                ])),
+                Felt::new(BigInteger384([
+                    0x681c4e9e4f0230e5,
+                    0x45e6b1f2d7c0dfdd,
+                    0x1057acb27fe1fed7,
+                    0x79b085477e4d37ae,
+                    0x647b89f933d5161e,
+                    0x017ff1b83fcc28ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ca146cc36229e6a,
+                    0xe61c7e888fbc20dc,
+                    0x47ec8b948993f43e,
+                    0x18af074eb7cc4813,
+                    0x9d4427809bb9f9fb,
+                    0x00a91f62f2cbdcad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x05f4fda039eb80dd,
+                    0x4a04d91558ac33f8,
+                    0x4e8d56bfebf33b86,
+                    0x75ba61f1785c6f7d,
+                    0x047aec38d1561ec0,
+                    0x01a3e763fda21c9a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x779f2bb1048a2903,
+                    0xabd8fb655c8ce92e,
+                    0x83c7373fc0aaac9d,
+                    0xaa77aa5cc854dc12,
+                    0x7b596fc628bd03bf,
+                    0x00089f3921fc0839,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5840f646746f12b2,
+                    0xf418f695d6c7c4d7,
+                    0xfbb84a78485cea33,
+                    0xace2c6777cccdffa,
+                    0x307fe34cf274f39f,
+                    0x00bdd22469d2e2e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1e2697ed3b8b7939,
+                    0x48b7826453979a1d,
+                    0x3438a6c5da49bd8f,
+                    0x3c472939a5cb3a0c,
+                    0x83de2797233b71db,
+                    0x01054081fc03348a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x39fbb187a8b733cb,
+                    0xbd6b5e469a6394ff,
+                    0x51223989c23ad85e,
+                    0x7dfee3ac7687b0f3,
+                    0x8eac46ef6aba4276,
+                    0x0186c5b72c008f60,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd9afbde6ebe85411,
+                    0x8a030cbaa840094d,
+                    0xb0167ec9f41d3986,
+                    0x78d82cf1c0e53230,
+                    0x1e17d3b3acb4cf07,
+                    0x0004327b8bea57ff,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee661c127b26ccbd,
+                    0x0b062f1b40501418,
+                    0x01b296491269710a,
+                    0xd86a7fd3f0079267,
+                    0xaedd99506086dcb6,
+                    0x0171760212d61c03,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9e390049ff218f58,
+                    0xe6599d4d2565004b,
+                    0x64921912ee0613cb,
+                    0xce4f50359c64eeb6,
+                    0xad79c0b7b3b1bf00,
+                    0x01913bb9d3c6120f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x65ee2edaaec4a31d,
+                    0x6b9d69a71aaafb5d,
+                    0x425a6085ec1b4a95,
+                    0x376fe9ddb351e16c,
+                    0xd640e5903fef4feb,
+                    0x0167da8b333c1aac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2071e73ce57b6a7c,
+                    0xa4d28e9a7b2d4f6d,
+                    0x3b1b41722d7d116a,
+                    0xd9289cdadb631685,
+                    0x833607e3c18295ed,
+                    0x0034e4347107cc5a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7a0b6e05193a43d5,
+                    0x69e2fbaeef360379,
+                    0xfbc4b55a39c26869,
+                    0xf55897694619b492,
+                    0xd66b6e1a10a3e8c1,
+                    0x00b8e38a9a520e8d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6681c83d91f2bb0f,
+                    0x3895ebdae5efeedf,
+                    0xfe1c47d4291800c6,
+                    0x1e969c549d2787df,
+                    0xe2aee57d98c9c78b,
+                    0x00ab29f5ed66c272,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c395db7f0a1edb5,
+                    0x06140e7e8b999769,
+                    0x15176e90683e5476,
+                    0x0d59b5c3320992e2,
+                    0x8ab755fbe1e74f06,
+                    0x0181278caf716228,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43562f4753048d8f,
+                    0xebca1818b811de98,
+                    0x6aafe3138b726dcf,
+                    0xfa01f2a101785e4d,
+                    0x0bc14ca7b965008c,
+                    0x015ef03d7abff756,
+                ])),
+                Felt::new(BigInteger384([
+                    0x234f2dc3bdf34e3f,
+                    0x9983356bcb40b82e,
+                    0x68cc429f75182efd,
+                    0x14b33714d045e300,
+                    0xca6886104dae589a,
+                    0x00c81c2cb2c547a8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x24898ed13b978644,
+                    0x8bc86ac17563d030,
+                    0xb563ebbc39a3fe48,
+                    0x44649f3e007b28bd,
+                    0x9476c557b69dec6c,
+                    0x00fc91f0c22cf229,
+                ])),
+                Felt::new(BigInteger384([
+                    0x58bd53a61a615d97,
+                    0x9ffffc579cd34828,
+                    0xe61b4490d41ec273,
+                    0xae309a19319468fe,
+                    0xdd1d0f9df59e6358,
+                    0x007cb3936063b89a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55dfde40e3785061,
+                    0x4f168a4672e9669c,
+                    0xded3c593a115ff10,
+                    0x377fa9c48c193bde,
+                    0xabc336762032de05,
+                    0x004f1bae3ecd12a8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x763995ba81edfbdf,
+                    0x285ec23a347700eb,
+                    0x833a4c1d140dbaa7,
+                    0x4d451ffebabbba38,
+                    0xdb74b269950a198e,
+                    0x00cae53d11a64687,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfbd27d448f2cb6b2,
+                    0xd56e22111c0d7562,
+                    0xf2e708ce4f88de55,
+                    0xbe0ebdb6f20b688e,
+                    0xb8146544bf88dd60,
+                    0x004e6ecd8d54f30b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf3de49acc01b165b,
+                    0x8942879ff679f2e6,
+                    0x76cb4c6bcf34abfb,
+                    0x4c7615389cbf02f9,
+                    0x0661867418d57935,
+                    0x00f2868cb6616b8a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf7132d6b6cdb1a48,
+                    0x5f47357cb6049334,
+                    0x13fa2aa9cc89f363,
+                    0xb6579f36f5e67ca3,
+                    0xcf2415d92cc18152,
+                    0x002f8c6a90c4c15d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf532267b54bb5bed,
+                    0xfd8b5f2ba685f77d,
+                    0x78c2ae0fc7e0a584,
+                    0x6075c4f6d9067d41,
+                    0x711980f30f5fc9c7,
+                    0x017a8cf42f167c4e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27d28d3f6d8e5ff6,
+                    0xc9f2a43abb317174,
+                    0x737945dcc04d3226,
+                    0x360d517839113f8d,
+                    0xf08bd03a9802dc31,
+                    0x01a88c1be4ef3f8a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9fde9295002558d7,
+                    0x74e2f40efe846f50,
+                    0xa0121972c543a9aa,
+                    0xeab7de5a2ad8946d,
+                    0x77d2097ab65e088c,
+                    0x0169978e6e08430a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd3480ecb94511328,
+                    0xcb75f9aea5af9a6b,
+                    0x921f64bce6b1e816,
+                    0x763b95294a39178a,
+                    0x62fa94952ffee4c6,
+                    0x0059a7edfae068aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe091b2583387aa25,
+                    0xc378b29838ccde4d,
+                    0xe147414659d1c7ef,
+                    0xefa522afa080fc52,
+                    0xd768efa517a50b62,
+                    0x0158bbd02672efa0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8fadc521c6ffc59,
+                    0x2e71a4af4de5641b,
+                    0x4982cdd7bd6db124,
+                    0xa3da883fa629f13f,
+                    0xc3522c35673fe8e8,
+                    0x010844baa5755bcd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc6ea86bacad22688,
+                    0x4f827698f53c4d4c,
+                    0xc45616ba971fcfb9,
+                    0xec69a27fd2882d96,
+                    0x3c7d86d1e518b225,
+                    0x017f56ce230e302f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ff5e215b2d4c448,
+                    0x4f1dcc847af822ad,
+                    0xc8597f53ab24cf70,
+                    0xb08d11dfd0673c01,
+                    0xfa4ee22b81bc3953,
+                    0x0176c944c8ed5f59,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd3903b1b5c5d6ae,
+                    0xdbaee8fd62bef803,
+                    0xd0174fba6cc9ba62,
+                    0xf3517d19f86ef31e,
+                    0x2d7543398bfe72a2,
+                    0x0058ce2a8d5ea622,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0dfdc674145eee00,
+                    0x77714d0fa69e7d4c,
+                    0x73df36e90aa56ed8,
+                    0xa7d151021c5c140f,
+                    0xf0df5e42a0327f77,
+                    0x01162b10eb4427b4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe7e19eff7169ce9f,
+                    0x1cae1dd931b04aef,
+                    0xa1583d13726585a2,
+                    0xcea117959f1d5575,
+                    0x33ed8244ec64b095,
+                    0x004d7066690b4761,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc6f67f1f4f8c92a0,
+                    0x71d2c41eade30c60,
+                    0xdbaf9adfa3f25bed,
+                    0x76f0ed0502144605,
+                    0x2e69cc113e135f36,
+                    0x00262fe0770113c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4eaf75913a9fb83a,
+                    0x0f4aeeffbe17f517,
+                    0xbff9e9e6c42d8051,
+                    0x9fdc073ae70ab04f,
+                    0x42088aad5ecdf97b,
+                    0x010770af0bd174be,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa32f997bbb6c5e6,
+                    0x6deb286522c90427,
+                    0xd0cea0cb9c66f1e8,
+                    0xd973c5db78dfe41d,
+                    0x44d55ab9651b0289,
+                    0x018004018a54c432,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8fd80deaed892b1c,
+                    0x684ee23887a99f8b,
+                    0x96507bee8160301b,
+                    0xd72e6d78149bf5b9,
+                    0xc23bec8ce7a5b768,
+                    0x018b6d9ed0692153,
+                ])),
+                Felt::new(BigInteger384([
+                    0x74d990c54cbbbf7b,
+                    0x3624c88492ff01d1,
+                    0xa31eda286f4f3ee0,
+                    0x4d1ddc89f700f490,
+                    0xf09244d8d34af6b1,
+                    0x019769629e083740,
+                ])),
+                Felt::new(BigInteger384([
+                    0x36e884decc4c8525,
+                    0xc0a9899cda7ec0d8,
+                    0x160f503f8a51d852,
+                    0x36a6a12806e1f657,
+                    0x8271d0406eea781d,
+                    0x0121d088668200db,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf5c8e977fde25f59,
+                    0xf1b615a62863fb20,
+                    0xd9d3e4bad884a7be,
+                    0xb7e7b14e6ce0e861,
+                    0xd22f46f5216ec549,
+                    0x006a57618ebff374,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd5262b79995ec95,
+                    0xe181079fc48f44bc,
+                    0x976fa280848af6be,
+                    0x4fa10339e07bf03a,
+                    0x1f8ba9d380e4b5fa,
+                    0x019314f34516647d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c3d7d8f88f26853,
+                    0x14b497660313fd38,
+                    0x12e4d7447dc7bda6,
+                    0x6a155233e3c25786,
+                    0x97c9e816a8d0ad5b,
+                    0x00b6040d388b4832,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44bff6f6ac151134,
+                    0x2f1f2d9336fdc845,
+                    0xc5e99d20afa01fd9,
+                    0x74678c9a57444d96,
+                    0x3bcd32f9351cc607,
+                    0x00179b40ab3496c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb4b49ef4282aabaf,
+                    0x44c0d540b078572c,
+                    0x4d0dfe954e150996,
+                    0x9fbf309a338e3d24,
+                    0x78367abfb1a19a2e,
+                    0x0080912fb093621e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5019a3e987794446,
+                    0x875acc14de5e52d1,
+                    0x893a4fdef929d826,
+                    0xc012ae90c8316a37,
+                    0x9cb86e5c61b47399,
+                    0x01978a234ec349d4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x164823b3a023f353,
+                    0x8daea858b0623b92,
+                    0x9f54cbc25997eadb,
+                    0x64c81d959a2b186a,
+                    0xe25bec636fc303b1,
+                    0x0169ccda7b15d74d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcb2c3721b2952027,
+                    0x52842e3066464537,
+                    0x53c89475a619b7e9,
+                    0x3f74ec9de2836bca,
+                    0x38734d0e5c8412ac,
+                    0x009a1754c5370bef,
+                ])),
+                Felt::new(BigInteger384([
+                    0x540d0b1101e10d95,
+                    0x179f9443a0e59aab,
+                    0x494ced044803fb00,
+                    0x4eee39fc176ba558,
+                    0x0d43573d8c9b0f35,
+                    0x0137ff5928a8c2b9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b03edd9c914afe1,
+                    0xf62cc7a226dfb7d1,
+                    0x197380135e2bbb11,
+                    0x452fa3c47dd5e90a,
+                    0x52e80f8a872f2530,
+                    0x013226fc0b510505,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5afba483a34bf463,
+                    0x44ff519b7619e33d,
+                    0xb933d1a8af7bac06,
+                    0xfdada06d7aa8c2d6,
+                    0x203b00ccadc631ce,
+                    0x0027b0391c8ac617,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ec5b7cbaa105825,
+                    0x77f9cfa0181bf63c,
+                    0x6172af3745851f41,
+                    0x5b9c663e5e445e5e,
+                    0x1123245aea460a2e,
+                    0x01a26d9fcfd2ba3d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf81a340fb29e23df,
+                    0x096c6c9a2bcce4bb,
+                    0xed8e006ba8ac3755,
+                    0x20b62ca518e2a463,
+                    0x1dbe94abcbfac87c,
+                    0x001a55c9024611d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x898261412c87e862,
+                    0x7c344ca3c66632eb,
+                    0xf544ed82b692cd78,
+                    0x5ad4671cbec33c79,
+                    0x4b6c25fefc6f0947,
+                    0x00d06c5540950b3f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x95a4137576c9fff3,
+                    0x309c44edc6846b5b,
+                    0x5995439d20d128d3,
+                    0xf7c2d5defd2c2976,
+                    0x389fe291415154b2,
+                    0x0053b4fc9c5e660c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x41336622484c7825,
+                    0xcf4196d64c8d6685,
+                    0x544b677d7b1fbf1e,
+                    0x0b8da1f400a7a428,
+                    0x1b4aaeefbcd0f388,
+                    0x015842a479f22ace,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6aa1bc46b75b3421,
+                    0x572bbef764d6b918,
+                    0xf00903d1a1fd631b,
+                    0xf94e0c615cba441a,
+                    0xdaed48b279b8474e,
+                    0x01279f0ef7bf7e3b,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19e37ffffffff41a,
+                    0xcec846acdffff9d1,
+                    0xbc1dbc2075914eed,
+                    0x0552bea29bf94715,
+                    0x5db9907378f2aac7,
+                    0x00f6a2012d38b68d,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe07e4b2c37cfc370,
+                    0x5c01e916a19cba2b,
+                    0xd3a51f4ff0e59064,
+                    0x844db9974c02b8a4,
+                    0x156e3d58b000b93d,
+                    0x000a744a1130c3ac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a7e89ad5fc36989,
+                    0x1e5f1a231b1dce3d,
+                    0x87114610632ce5f7,
+                    0x63f99142c5f13012,
+                    0x37c6c4cec1a638d9,
+                    0x005f9fb6f82e7ece,
+                ])),
+                Felt::new(BigInteger384([
+                    0x54ee732931e86506,
+                    0x0804fa9ade26d296,
+                    0x204d1ec1c7a99b0c,
+                    0x970275d71469c5c9,
+                    0x1310a28f5b49cf15,
+                    0x00c9ffb039863b09,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7aca1eff51dd2ac8,
+                    0x1bf6d4c5ce84c22b,
+                    0x871e6dfc05433e6c,
+                    0xc38bab5a3bca6c08,
+                    0xe1374b8f77ec4095,
+                    0x00712ac3c9f68a9e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55602e40c75ebd99,
+                    0x828db7c4c887e94c,
+                    0xc8cd6e569379ddde,
+                    0xf3cfd55d6c0a00c8,
+                    0xdb0cf319639cf5ab,
+                    0x00a5cab0e8ea941c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe6bb67bf7162ab16,
+                    0x0a095eba628e0d4c,
+                    0xafb8653703d56d54,
+                    0x3958338eaf9b7763,
+                    0x7068aebd77d00814,
+                    0x00068b7056983d51,
+                ])),
+                Felt::new(BigInteger384([
+                    0x986656d8cee5bfaf,
+                    0xc1ae7adc3b511ba9,
+                    0x5192d6d042e52f86,
+                    0xc0ee212d7434f787,
+                    0xdcacd0c0f494672c,
+                    0x01a72a14e9bf7335,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbcd132942ee0548b,
+                    0xbbef7967c7b3d44c,
+                    0x5650a6896af3a6df,
+                    0xbe6e7304dca284b4,
+                    0x2c6937f2e87253fb,
+                    0x011c0330228526bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf982fa000cd8f10a,
+                    0x3d61a25f367561da,
+                    0x22007a2ba07a1688,
+                    0x00a50da79f8c2649,
+                    0xeeb1c05941716649,
+                    0x01588153d7b58fb9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfac9667bc5bb30f2,
+                    0xfdc064c2dca65897,
+                    0xd3aa30ce407542d6,
+                    0xa3a8b172a46a0c95,
+                    0xbc04064e1b2a9f57,
+                    0x0001e8a7338bb6d2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee91a1422c8a30f1,
+                    0xd27e7c2310a2f8bd,
+                    0x28a9e671ebbd2120,
+                    0xe5fa1ba60332ca0c,
+                    0xcc96a8268a56ab2e,
+                    0x0075fbe93552da7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6c0e3a015cdf7d84,
+                    0x9215b6fa1174bcac,
+                    0xc885ffc1ef5bb8e4,
+                    0x665dc2ee714f9d90,
+                    0xba7baa455bc1c62e,
+                    0x00a893570cc546ce,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8146283b8248c44c,
+                    0xf9a67efaf2461459,
+                    0x2a47fef351b5d06e,
+                    0xdf69472a19fdb923,
+                    0xe0d8c58c2a2e87d1,
+                    0x003667ec42425b0d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0174cf824f7e5e37,
+                    0x59db396c143d9dbb,
+                    0x34d5cd26613192f2,
+                    0x2e0b161fc88cd5c4,
+                    0xceda8a151bf8068d,
+                    0x012bb15a5e6cc69d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbf9675954ac6f392,
+                    0xc117411769451fe4,
+                    0xe52720b7485ccf48,
+                    0xce140d0ba3916e6f,
+                    0x5256b98f2db4ce25,
+                    0x0103e2222d85381f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdd49b0d2dd8f7fb0,
+                    0xb3db60de0cbbb7b2,
+                    0x687233ebeac67835,
+                    0x9ace4f98a26f4329,
+                    0x35d69053393adf6e,
+                    0x005d9c843a694b17,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe7d5c5e71444d73b,
+                    0x980d3eec25253361,
+                    0x48882f77c91647c0,
+                    0x7e13646e6cc95c35,
+                    0xead864ef6b961a1f,
+                    0x00a0ed9e8f520ab8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7cd54cc83747da9f,
+                    0x86ac762118b9d846,
+                    0xb76687227d725628,
+                    0x8324168c499512a3,
+                    0xeba243bd40e7eac9,
+                    0x003d9b6d147558bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8b03ba8c4ad91903,
+                    0xf4123e1a6ba339e9,
+                    0x062c57c5625e86db,
+                    0x61b393800e69c9ea,
+                    0x63e421422fd69640,
+                    0x007cd1e697ae1c8b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x047c63bdae0a6036,
+                    0x4f1d9058509c50a3,
+                    0x2a2a400ababf1091,
+                    0x2fa79748bf69131e,
+                    0x67f8fcb9134758e7,
+                    0x001ff15303b55162,
+                ])),
+                Felt::new(BigInteger384([
+                    0x34fa14c15434ae70,
+                    0xc8fff33b8429b22d,
+                    0x27ed28d85065acc7,
+                    0x3d53ca1871035a53,
+                    0x71c361299e7ac74e,
+                    0x018a9382806fbcc2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17adc9f498c97462,
+                    0xf94238d24ddcec29,
+                    0xab006c6b8225fcae,
+                    0xe42dc4e16672e78c,
+                    0xbcedc1aa5c188831,
+                    0x003deeed49efdc92,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd346e26847824d98,
+                    0x89f28f40eba28e8b,
+                    0x48b4a36f48c6fe04,
+                    0x5d18c9583ddcbf4b,
+                    0x0929abe745e3dab5,
+                    0x000ceb6df1006523,
+                ])),
+                Felt::new(BigInteger384([
+                    0x26fc687ebc4cd344,
+                    0x0ec3a5994c78a965,
+                    0xb53f2275b7871af2,
+                    0x02b29d8bd7d56ba8,
+                    0x42f1669b9d4e7a93,
+                    0x012dec903952420e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2e3bb160ccfebb2a,
+                    0xa9fdb75095a53d4e,
+                    0xc06d603246ad2981,
+                    0x3603e985cc304b20,
+                    0x5291fcccd43694b7,
+                    0x005a82d84189f75f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xacb1aa5f2ec82cbd,
+                    0xaecb607fe2f4ba4c,
+                    0x77d0dc7398dec28d,
+                    0x206d2d5beef7bc79,
+                    0x959d0e8851e3f272,
+                    0x00b68474ddb532c7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0b84223a93e0acad,
+                    0x5d62d95cb0a0f852,
+                    0x3dd9086b786fe33b,
+                    0x30a8009019a38565,
+                    0x4644f40d52858d34,
+                    0x012b620c21f06a3b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x709d0785497b8945,
+                    0x7d7af3b42791b17c,
+                    0x354f9331ddbdc28e,
+                    0xf037eab184d8a3d0,
+                    0xc1f23a3c93b4ddc6,
+                    0x00780ff0bf69213c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f16ce0dcfb5c405,
+                    0x5d8cc81ce00f5065,
+                    0xfc363f0cfaff6e64,
+                    0xa956cee7bfd3f59f,
+                    0xae32c69377673d04,
+                    0x006a993f461a1a24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6548a77094adb8f2,
+                    0x2f540c9ab4660cf2,
+                    0x6e13a3d3279674ed,
+                    0x5bda037d92d1971b,
+                    0xee7f42621cda4f9b,
+                    0x002b43a7248e1064,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7387191147ad2b67,
+                    0xdd716cd43c96ebdb,
+                    0x9f88bdf277382227,
+                    0x49b1c00a5673021e,
+                    0xcdec3874f856b5f0,
+                    0x000768a87275f3a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x641325e2dba42928,
+                    0x4bd2f71355f20116,
+                    0x6ef3d3bdc2880b77,
+                    0xf67f99f9c2a63cbe,
+                    0x388b02c03a23ff3e,
+                    0x00c860825636f6f9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x74b022d2d2f77a29,
+                    0x3766fb5ad0726a48,
+                    0xf4e3bd5d7aca9ecf,
+                    0xc5e4a263833d7489,
+                    0x827a411684f3e3b1,
+                    0x013d35442a5ae8fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0edd2f53120b6c91,
+                    0x69bb2b58296eb00a,
+                    0x83e076e6385535bb,
+                    0x721fe1535a446d98,
+                    0xf760319619971fe6,
+                    0x0198baa58c217ead,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbf2f3fb7e6598731,
+                    0x6ea43cffe41fc551,
+                    0xa925162849877537,
+                    0x86db5614a0456987,
+                    0xc84490592e5c7774,
+                    0x0088610505b381ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc16a8ccabb42624d,
+                    0x81d0263e7167c030,
+                    0xd3aa76a594b29c28,
+                    0x9b894241e213d74f,
+                    0x99a30d444092dfce,
+                    0x002eeceeaaf05bb2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x407255365c02f359,
+                    0x01a0aabb2789fd7a,
+                    0xdca0da267ea3b965,
+                    0x1dbee88b8a127e44,
+                    0x4936c5f06c01e145,
+                    0x0133145652559830,
+                ])),
+                Felt::new(BigInteger384([
+                    0x037509ff710edf52,
+                    0xc1965dcf7b7a51d1,
+                    0xb65c9a4548b04e5c,
+                    0x5e43ddb03a92baaa,
+                    0x81cdfbc4b7af2b77,
+                    0x013d09db4dfcdef0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf45278c61a82077,
+                    0x859bc3388880524f,
+                    0xe72a3fd079b94543,
+                    0x22de5bef86ff367a,
+                    0x1dedb54aae226edb,
+                    0x012e98817ffb4231,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7137bce747bef69d,
+                    0xad7845a119e7289b,
+                    0xa807d307e0121611,
+                    0x826c7a8e8fa5c1a1,
+                    0x9ed2eab13f6cdce1,
+                    0x00e7fab81dca723c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x228683f9c1332388,
+                    0xdd125354937aad6d,
+                    0x64c51b29427523c1,
+                    0xf28be055a9fe98c4,
+                    0xf248a42a3ca2c049,
+                    0x00d818a9e32a02a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc1022c631292dcd9,
+                    0xd52b72385760f80a,
+                    0x785f80c96f9d090f,
+                    0x88397e0e3dc82bf4,
+                    0x459b59e05a088819,
+                    0x01022197e000eca6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd1a1266a71e06d43,
+                    0xaf8b767894eba1c9,
+                    0xbee1a16da0d95291,
+                    0x1a84828b10f902df,
+                    0x1a9f969698d26478,
+                    0x01a7b2edba8fdbe0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x22d3335b9cba639d,
+                    0xe2ef2c36e24bfd5b,
+                    0x787ee0132c73383a,
+                    0x61e7173df9b03d97,
+                    0x00df1e2955c380de,
+                    0x011bc2fdc4ea2ab9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc959f87fc2f2e269,
+                    0x21ab4c5959aad01c,
+                    0xd16ea7dcb0875a27,
+                    0xa9bd99b1c08a128b,
+                    0xbc57b431bee8101c,
+                    0x0113388772bd3a67,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf7ac009efd930eca,
+                    0x65e585abc757f331,
+                    0xc55c2ea48a8bf8da,
+                    0x32d029aa7fade7bd,
+                    0x20afba1936914023,
+                    0x00aa3f1e821c3b37,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb7e4a944894a04c0,
+                    0xa16edd6e155f3a94,
+                    0x99e9fd652542949b,
+                    0x21b4a706d29c8e87,
+                    0xadbaa6fd3b0e6ddb,
+                    0x00c11857adede209,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77ca646282d7f090,
+                    0x1f72e942e7b97cdb,
+                    0xed798e7a30940301,
+                    0x90cdfa1878b6b39e,
+                    0x773179a89ab7bb43,
+                    0x00d077f981fdbb4b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0480cf67cfc4f5e4,
+                    0x68caf7c2ed496fd0,
+                    0xf238e0a510ad5bed,
+                    0x0585bb105c869f82,
+                    0x0cea3ed2f7d91924,
+                    0x019a34a88e86be94,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcebfa6fa9b9ade11,
+                    0x125ef3abbc1956cd,
+                    0xeb9a6de565536e92,
+                    0x1f9ba08228665674,
+                    0xe78a39f2fe0cc427,
+                    0x01765919d2a54ff6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xffb131b8063d7632,
+                    0xf9a6c6b11f9da016,
+                    0x167dc6112b4839c6,
+                    0x1ae3e2259930e744,
+                    0xb1e5de6d08c61825,
+                    0x00cfa7e380d3bd45,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45bcb2d442979862,
+                    0xb0da02c2542fd152,
+                    0x8997ad9a77c43ab5,
+                    0xf9234a375afbdd53,
+                    0x24af278715bd823f,
+                    0x00d2f6679d042feb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa76f5d90581bdd6f,
+                    0x9e45b032de73960c,
+                    0xdc7a970a5890849e,
+                    0x8476aeb13f32f5dd,
+                    0x6adf7ef0062605d0,
+                    0x004cb227f933a4a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdcf9f6dfd31306fb,
+                    0x5154498f329f1845,
+                    0xdefd9e9a820d0bbc,
+                    0xf69634dd041b1600,
+                    0x8c725c2dd85a4bfe,
+                    0x0160dc57fc794df9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9387d8b6754442f8,
+                    0xf1341393e3789188,
+                    0xcf9f4a7588f158b3,
+                    0xacf3c7e8791f70d0,
+                    0xde308e2f1dd52533,
+                    0x0059cc6f460ef401,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfca51225b83f0d08,
+                    0x62db21da154f9e72,
+                    0xe0e589ccf9fb6a60,
+                    0x3fa5159f413fde2a,
+                    0x594ea8928a5615d5,
+                    0x00f5b689f4333c11,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0540c4836da8f959,
+                    0x2fdf60823ac55271,
+                    0xb07521ef526a9ac0,
+                    0x0844fba24af29d97,
+                    0x5fe5f08b1cb56463,
+                    0x018e80dd0a157bcb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f07295a664ada87,
+                    0x7a04041c21c6957b,
+                    0xed7e81a043c5c501,
+                    0x9d01dfc1026309f9,
+                    0xf23dc273f4479f6d,
+                    0x00fc35e2894ec7bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc8b768af09b07203,
+                    0x418337e31fb6c010,
+                    0x77a1bad3f0872f76,
+                    0x5284e3917ab4abc8,
+                    0xe18859331ac2406a,
+                    0x0185db854f3f4a77,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf65cd9460823a7a0,
+                    0x90866a4142a77373,
+                    0xd501d36313f43ba9,
+                    0xd35391ef87377762,
+                    0xa3176c23f13a8f62,
+                    0x00d34e6f7bca0ab2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x03c5599d080ff810,
+                    0x0889d8771c23b33a,
+                    0x090683bace8bfe3d,
+                    0x7fb8c5ccb419c995,
+                    0xc552bd9dfe553ddc,
+                    0x0178722cca097538,
+                ])),
+                Felt::new(BigInteger384([
+                    0x74c6417ada025b44,
+                    0x7059b330d98f9125,
+                    0x96ca84dadc628d12,
+                    0xe0f8495dc540792f,
+                    0x90eed279265f189e,
+                    0x009d70d331980deb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc0f2e794031a182e,
+                    0x2bf191ce5e5797c8,
+                    0xa603a1d34b77a61d,
+                    0x1df19c9bd79b7eec,
+                    0x0c1d3d7b38acf8d7,
+                    0x009ed6c6b94f9723,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6b7c336822f12d35,
+                    0xb03addfb6b1d109d,
+                    0x722b5f0e3f75e87f,
+                    0xe2983b7a6d65a971,
+                    0x6db44fa252e7f52f,
+                    0x01706acbc5e70360,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1e26a2f70b8c3096,
+                    0x06b5c2f04e5e7bdf,
+                    0xb405e85bdba46c88,
+                    0xd94eafe1a66448f2,
+                    0x2b914eedaf24c12e,
+                    0x004124389982f5e8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x448e5a7e9566f661,
+                    0xe6190368cc7251de,
+                    0x035bddf2327f0dcf,
+                    0xda0f1b59f809ebb8,
+                    0x2ed134faa366b794,
+                    0x017f299be360e64e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53afa461f5e00224,
+                    0xac9dbf7f90cf56ae,
+                    0xa74188b306ca7ed2,
+                    0x41752f34ca947a5a,
+                    0x1cab18f5d9ae879d,
+                    0x0102a320806af321,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc0612790dbaab130,
+                    0x0a32e9c17546e1c9,
+                    0x2ffcad6c21f5905a,
+                    0xc94c020292ed3dde,
+                    0xc53f2ab20b651614,
+                    0x00190b4d41123eca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x710d597015af9aac,
+                    0x172e9d5a436b7f48,
+                    0x31a830b9e66ea3c5,
+                    0x1cd8fc587881f0a1,
+                    0xc4328ed4bc968cab,
+                    0x00447b7cde03e805,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71ad75116fb4cdc5,
+                    0x8c60a9bab00517fe,
+                    0x0b21a26215d8159f,
+                    0x5e29cad67cea1f9d,
+                    0x3b9f96c1364f5c10,
+                    0x00670b1c1939344d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xddd99b5f2f2269ff,
+                    0xdb6ca13a15d8a91b,
+                    0x9259b5abb8531d15,
+                    0x5344e489ede079c6,
+                    0x39c915bd9b7b99aa,
+                    0x00eecbb1e45001cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x986285c8730fc66a,
+                    0xd2459fea7cf87850,
+                    0x865e6aefca575889,
+                    0x5df43650cd3d544e,
+                    0x192194e1904b8944,
+                    0x00a762e83011975a,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2111 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // We use internally the Jive compression method, as both digests do not fit
+        // into the rate registers at once.
+        let result = Self::compress(&Self::Digest::digests_to_elements(digests));
+        Self::Digest::new(result.try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U2>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger384([
+                0x1f2dba17684c8445,
+                0x14c98e4058d31b62,
+                0xd4b3bbc39239147f,
+                0x4cba90da98b091b9,
+                0x6a42ab2141edb681,
+                0x014a69481343e26e,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0x92984212fd736ee3,
+                    0x73d65de41d88d81d,
+                    0x051bd9e0de6593c5,
+                    0xf48c3aa47d4bae2a,
+                    0xe774821566bbbc20,
+                    0x0006793456d9a8a4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98612f037cdb1c3d,
+                    0x64057dc568523e55,
+                    0x211669d69d7d06a2,
+                    0xcf1c63471f83642f,
+                    0xdba55b9c6f49cfda,
+                    0x0060a1eccd8c3de2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x0cb78ba1acbcdfad,
+                    0xa08df6b84ac1ce2b,
+                    0x6f177125ff41286f,
+                    0xde434fd1a3ee7535,
+                    0xe69d8047dea372f1,
+                    0x008067c5d9187939,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbeee75db7f541564,
+                    0xa5e3ffc431761073,
+                    0xbca38519add1a8ab,
+                    0xb9a2a3be35d36e20,
+                    0x8f4d6705fbfaaba1,
+                    0x01150abf75222d38,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7a8047795cfffb25,
+                    0x4b93767c6d389508,
+                    0x7664f5a38d34471c,
+                    0xb173b9f92408f710,
+                    0x1130e8020d501b10,
+                    0x0186f224f5a4ff52,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xd5dcfb907afbb6df,
+                    0xd58f736195aadba2,
+                    0x3d02310e58eb0e66,
+                    0xdec9e1686e0248e5,
+                    0x990e05607aa6289c,
+                    0x006d565c4f608068,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27e6efd2ea6d1fd0,
+                    0x6cbde7c41baff0d0,
+                    0x036d4ff710922c95,
+                    0xe62fbd7b02957cd9,
+                    0x3417ae3de7a522df,
+                    0x003a690d19d54786,
+                ])),
+                Felt::new(BigInteger384([
+                    0x172be2ad8e60dd4c,
+                    0xc276bf50497e9052,
+                    0xbc60ef261767ac18,
+                    0xa9daabe0c4447d15,
+                    0x4807e943233347b9,
+                    0x0106103cb18eec02,
+                ])),
+                Felt::new(BigInteger384([
+                    0x217b97bc597d2a80,
+                    0x82fb1acedab83ada,
+                    0xa49fe70a52d81b1e,
+                    0x6924608cf8267eec,
+                    0x4c5c033d3f393faa,
+                    0x01809e0ac4a9c908,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x8bf60715d587083d,
+                    0x77d54ff298422081,
+                    0x6f25b1c2a428f230,
+                    0xf25d9193d1edaa43,
+                    0xc8d777699ed62cdd,
+                    0x01442c8da124f6bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdc1bf62b773b8c4e,
+                    0xcdb5528bcda58efc,
+                    0x3974b770cbf90cd4,
+                    0x9148c236380ba0f8,
+                    0x2a3c0f373a3d4422,
+                    0x00bbb58e6f075efa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa86f0eea7217f347,
+                    0x6d182d2bbfd6219d,
+                    0xc5c21c31ce9ebd52,
+                    0xa7417de37743f1f6,
+                    0xa690b4e7a8bbb6d5,
+                    0x008b869aa6db7a43,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe5b5e7d67636884d,
+                    0x9c689ce98bf9cba2,
+                    0x6a7a2324f58dc643,
+                    0xf0b3e890ecdfb08f,
+                    0xa3b91e49698ff0fe,
+                    0x0045d1234c27aba9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x03ec3ed75be4bc3c,
+                    0xa49f2c8345fe048b,
+                    0x88bcea5cb8b7d7e8,
+                    0xb718b315961f0da4,
+                    0xe7d46e87d6928fbf,
+                    0x00379f3c5fb313b3,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x5d14b7856ef40a2d,
+                    0xf6e250a321660cd2,
+                    0x583c72b01d2b5c8a,
+                    0xade9894119554e30,
+                    0xc878a70fc39ad676,
+                    0x0135dce9b62ceb62,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4ed1221ba822c531,
+                    0x17dd21e118a03da6,
+                    0x984d123fac2e8ea5,
+                    0x4fc52ca52039fea2,
+                    0x89727ea8dee26fc4,
+                    0x010a4bac9e417841,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e3b6e07c127d77e,
+                    0xb5f9b1d3c79fe898,
+                    0xb65191e395ccc3ae,
+                    0xc37911a399a18704,
+                    0x0087b8a849de363c,
+                    0x002d8280473e2f5f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x679b11a14567fcb2,
+                    0xc8403081b5ee8059,
+                    0x29917078d4a5edb2,
+                    0xec5272a6edc85ae5,
+                    0x06b7f0ee63b09ca0,
+                    0x00bf732a96647fab,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdcb308b8d5c9c496,
+                    0xa7fe218497771015,
+                    0x79ab68e39bef7a0c,
+                    0xd6a996db228259a1,
+                    0xa335c5b1d2879a17,
+                    0x00613894a4b75840,
+                ])),
+                Felt::new(BigInteger384([
+                    0xebb4d89529f705c3,
+                    0x7e682e0042bdf7c0,
+                    0xa404c1ccf2ffdbb0,
+                    0x9035adbfb65c5b03,
+                    0x669999927914d79f,
+                    0x014113debb4a34b3,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xf19a7970bbb74ccf,
+                    0x376fff2acd783251,
+                    0x8179bb28b566373d,
+                    0x82c85c1373571987,
+                    0x2a0710011ef638ce,
+                    0x0002f0f95f953c03,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59a6a165c583603d,
+                    0x9106dba62a6de7f9,
+                    0x5a509d4101dd34c2,
+                    0x49402ef5f8a7c1cb,
+                    0x8f6f9820ce31b523,
+                    0x00c39cd439fa3cd0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x398490559fc2be27,
+                    0x4ebdb044fe7f4318,
+                    0x45879f66f02dcbef,
+                    0xe59924ca6eabbbc9,
+                    0x4a2aac0624105231,
+                    0x0051e2fbc63953e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9dcd1bb58d40e5c9,
+                    0xfea460bc81378d3d,
+                    0x4274a4de5d269785,
+                    0x402209ba891d874e,
+                    0x965b34faab26472c,
+                    0x0100b338add5a01f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xbbf3756984a2ca23,
+                    0x6339598ff8d74e5c,
+                    0x67a5fba42310c480,
+                    0x9c330c14572558d8,
+                    0xc4f59622ca133d59,
+                    0x00cf3cb72e05393a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb22fb95d3c652e96,
+                    0x6f16c47680c1bb76,
+                    0xec3e39f75041f4db,
+                    0x9d0f75f85d5f4af6,
+                    0x45e052c79a0d3d82,
+                    0x009778aaeb2b7664,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x58d080db04ce0307,
+                    0xb81709e443d72c0b,
+                    0x237833f44a397ca3,
+                    0xcc2f43688aa3bc75,
+                    0x7c3be072bc7b6b9b,
+                    0x00740298b6dda632,
+                ])),
+                Felt::new(BigInteger384([
+                    0x85c4f0e48f6aebc7,
+                    0x461c818f64d02f9f,
+                    0x1849ed54914666a8,
+                    0xbb645b7da087189d,
+                    0x6c5acdc23c0b225d,
+                    0x009949a405f1f67f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc655067020711414,
+                    0xdace1e1dfbd16075,
+                    0x17eb8ab73375a4ad,
+                    0xba5bcc706f285182,
+                    0x472a6ee220c4fad4,
+                    0x012ad97bb3505072,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2be6ef27d159c6d3,
+                    0xcd4ea8013e77f5a6,
+                    0xf25b187fa54eccac,
+                    0xc308a3616c71691a,
+                    0xd79e455a3337a9df,
+                    0x00d58ccd85c55e83,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x908c63f6f2019547,
+                    0x97c7869eeb9f88a2,
+                    0xb8fc076d566095e4,
+                    0xed5d9a3303bbecda,
+                    0x765b4eb8135054d4,
+                    0x00ab8cf16dfc7699,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7ca8975db6d26b8e,
+                    0x3bcc1eb725f257d8,
+                    0xd934f196003a2c4b,
+                    0x470340525dd3f9f4,
+                    0x0ce2c7aa885bfadf,
+                    0x0084f87f2ebfe1eb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd7139d0a9d4a0d8d,
+                    0x01156089c517870d,
+                    0xd7b46b47d6923117,
+                    0xa26ee6f40b7b647d,
+                    0x48410b6a8f60d704,
+                    0x00b53c55253e09e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe86f607293c66ed9,
+                    0xbb86d9cec92e492e,
+                    0xe19275159fa5b618,
+                    0x579c4968411d5766,
+                    0x9e6ce48c38eccb8c,
+                    0x006b2513f3a08cd0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x94bdd9c1537a18d0,
+                    0x715a205284302261,
+                    0xfa179368d2eee4c7,
+                    0x5126c3255eeb4386,
+                    0x69af02661cc18a67,
+                    0x014e401ebca4b11a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x102f2f494f3d1d3d,
+                    0x6abc6b70712a3502,
+                    0xb27c719f748d1a70,
+                    0xdd017ad38e41aeb7,
+                    0x043c40287d063025,
+                    0x0035d6ffb3f09a71,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x24549d90aac6c7e9,
+                    0x943fd3af382d3b98,
+                    0xfb7e133bd2d85e55,
+                    0xefd026397b310280,
+                    0xf59e7635d33e1f3a,
+                    0x01666442e97c3153,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5da7331d8db528ef,
+                    0x208219b0df9fd94a,
+                    0xc5937b1a27058584,
+                    0xb76f2ffcbc875f41,
+                    0xf6827363876c6f29,
+                    0x00a415053c3891b3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf2eb5ee2990d25a1,
+                    0xbda5231a6836e334,
+                    0xd94bd51354589e76,
+                    0x6c365791b7d7e609,
+                    0xb742ea27bb0af2f1,
+                    0x018c38f5d24ca084,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40d0e836997b445d,
+                    0x04c740031b055267,
+                    0x73b781bd1531018a,
+                    0xdf10f0267a149784,
+                    0x4eff24fd5c13039a,
+                    0x00f3240f8f70581b,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xf19a7970bbb74ccf,
+                    0x376fff2acd783251,
+                    0x8179bb28b566373d,
+                    0x82c85c1373571987,
+                    0x2a0710011ef638ce,
+                    0x0002f0f95f953c03,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59a6a165c583603d,
+                    0x9106dba62a6de7f9,
+                    0x5a509d4101dd34c2,
+                    0x49402ef5f8a7c1cb,
+                    0x8f6f9820ce31b523,
+                    0x00c39cd439fa3cd0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x398490559fc2be27,
+                    0x4ebdb044fe7f4318,
+                    0x45879f66f02dcbef,
+                    0xe59924ca6eabbbc9,
+                    0x4a2aac0624105231,
+                    0x0051e2fbc63953e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9dcd1bb58d40e5c9,
+                    0xfea460bc81378d3d,
+                    0x4274a4de5d269785,
+                    0x402209ba891d874e,
+                    0x965b34faab26472c,
+                    0x0100b338add5a01f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xbbf3756984a2ca23,
+                    0x6339598ff8d74e5c,
+                    0x67a5fba42310c480,
+                    0x9c330c14572558d8,
+                    0xc4f59622ca133d59,
+                    0x00cf3cb72e05393a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb22fb95d3c652e96,
+                    0x6f16c47680c1bb76,
+                    0xec3e39f75041f4db,
+                    0x9d0f75f85d5f4af6,
+                    0x45e052c79a0d3d82,
+                    0x009778aaeb2b7664,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x58d080db04ce0307,
+                    0xb81709e443d72c0b,
+                    0x237833f44a397ca3,
+                    0xcc2f43688aa3bc75,
+                    0x7c3be072bc7b6b9b,
+                    0x00740298b6dda632,
+                ])),
+                Felt::new(BigInteger384([
+                    0x85c4f0e48f6aebc7,
+                    0x461c818f64d02f9f,
+                    0x1849ed54914666a8,
+                    0xbb645b7da087189d,
+                    0x6c5acdc23c0b225d,
+                    0x009949a405f1f67f,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 188];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger384([
+                    0x96aaa16432df1c88,
+                    0x929ee60ba4f73046,
+                    0x19c42631d121816b,
+                    0x0bcf021115aa6069,
+                    0x218d1ac3b4654287,
+                    0x00e9ee73276c0f10,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6cc19e33c89e0eb7,
+                    0xcc7685eb9d926aa2,
+                    0x7d6d8d1a9fc1f16b,
+                    0xe0660b4401f77fcd,
+                    0x9ceb490675c82b5c,
+                    0x0090e2a58998fa49,
+                ])),
+                Felt::new(BigInteger384([
+                    0x97ddccc4a6ab9a84,
+                    0x0445cde9c8baf407,
+                    0xbd71c84e34f0aed2,
+                    0x208c2eb4e046a5b9,
+                    0x3b64bf34f5079db8,
+                    0x005067b3f8866c2f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x41ddae0659755ba5,
+                    0x0027b288b82a04b5,
+                    0x1022038c18a9920e,
+                    0x3b63a5dedeb051e8,
+                    0xeb21099362415d3f,
+                    0x011005c02a2517b9,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xcd8ee635198d8d4f,
+                    0x43f327d42122ab5d,
+                    0x9677aa596fc13eba,
+                    0x7b71542567cd3dba,
+                    0x6554bea3a9f21b48,
+                    0x003b8575f3f740b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1a7442a23d87ee1c,
+                    0xdc23a8bc1eb8e431,
+                    0x385e2d8fae6ee112,
+                    0x8a2ad233d215902e,
+                    0xfe15493771e6120e,
+                    0x005a7fd5488c1b52,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbeabc2e8f03bc502,
+                    0x3e8d6d33957ff8f3,
+                    0x25c8910c4d5d9114,
+                    0xe46bafa88189e02f,
+                    0xa7a4142cfb4f3b45,
+                    0x003c7094a78b47c3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x449760ea6e4bef39,
+                    0xe919d92c00e09bf9,
+                    0x0c62c8e01852eba4,
+                    0x1ec4ff1dcaa6720c,
+                    0x2f6b0939c3f6130a,
+                    0x0095a884cbd51603,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x5246c021023958e6,
+                    0x4f5cf9e7bca0cc3a,
+                    0xd3a087aad43f5a8e,
+                    0x4921cc6fa0dee5c1,
+                    0x5015f4c4b94a0d60,
+                    0x008a70c0f38e2caf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb2b57514f6b6fd76,
+                    0xac7a24938c9c63a1,
+                    0x99469c00d0aa6b86,
+                    0xd1930d7210e45189,
+                    0x0757d63e96dbffb2,
+                    0x00bfe0171aebca17,
+                ])),
+                Felt::new(BigInteger384([
+                    0x48bdab58c9d4afe9,
+                    0x96a382fcea603bbd,
+                    0x4a95b62123750a42,
+                    0xdddb1b43a6c1db74,
+                    0x3eb5ead5b15ea7b4,
+                    0x00dc4ec8ddce247a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcc398af0410009e5,
+                    0x58457bb802c01853,
+                    0x88b43777a76f4849,
+                    0xd0bd913c1ab3449f,
+                    0x3f0754c729b6a6cc,
+                    0x00add449c3c9fac2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xb2332d0e1afb52a0,
+                    0x79badce543d2124d,
+                    0xdb10628b29703486,
+                    0xe4bb15d389e255b0,
+                    0xe86a65100b6ea2d7,
+                    0x000bfed82772b5f3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1fbf2a47cff53a4c,
+                    0x1582b6a988f37e9e,
+                    0xc9c4270a7d5c26d8,
+                    0x2b1acdbe3f7c9f91,
+                    0x7e117db101c85560,
+                    0x002cb40aa30a6e75,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2e8a8d18d80c5bac,
+                    0x92cfe772beffc8b0,
+                    0x9258fc145fcab1e6,
+                    0x9e8590b7bb0a379f,
+                    0xfe0543d0d6cbc77d,
+                    0x0174746f4e1b4210,
+                ])),
+                Felt::new(BigInteger384([
+                    0x80fcd2ad4bb4ac7a,
+                    0xc9b26546e254eae6,
+                    0x41a9d9ef224a5a8c,
+                    0x911929a44e15fc82,
+                    0xe200fc02e7a20315,
+                    0x007e025257e5724c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xcd405d60d22e11b9,
+                    0x67363ee4ba029497,
+                    0x055ab5832d6673a7,
+                    0xfa5d83b131fdb896,
+                    0xb1f7e12b740b623b,
+                    0x002292e8ed99ab8f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdafaa8baaea30caa,
+                    0x6eaf1afbd7c773af,
+                    0xd944e4e8a1aedafa,
+                    0xd2898a7391725a2b,
+                    0xefc76cda5851153d,
+                    0x016841c40b44df23,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb4ad73681b181c42,
+                    0x36f87c38a8f63a2b,
+                    0xd223c49d05058bb4,
+                    0xd4274117be7669d1,
+                    0x59c6d9049222f54d,
+                    0x013523864d7971d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1ff039e5d97dc190,
+                    0x493f067523f7f852,
+                    0x082bc5deadd0a583,
+                    0x5fe7e6ff3e100a42,
+                    0x3e42c1e1254c7a00,
+                    0x0151e0707ed08887,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x53159ae5bc8edcf3,
+                    0xcfc0c5e74768bb5c,
+                    0xd8a5cb6e816b0bf2,
+                    0x727bbfb44a525156,
+                    0xa598647f86fdf410,
+                    0x01480f2f62f8e1bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9cec8c72e21b687f,
+                    0x528ebc40450a9686,
+                    0xaf72a1de95f67884,
+                    0x4818af7207728cc5,
+                    0xe562fb40c1f1c29a,
+                    0x013bdcabc3b2569c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x327c4362cf69468b,
+                    0x1dafd20cf0cfcda8,
+                    0x00215c17be5c5d6e,
+                    0xb7ec91d32a550e05,
+                    0xd472fdcf1d7453b8,
+                    0x0018151740477eae,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44fc4e37537f77cb,
+                    0x439ad388a7b584dd,
+                    0xe4d1730dce5c0a48,
+                    0x2e5570cf78170e28,
+                    0xfa82615271f94d8a,
+                    0x013aa7e7d97366c2,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x1681e41927a28a65,
+                    0x23518a9d6b2a7b6e,
+                    0x73a17b74bc02a315,
+                    0xbf73bb7735c44d71,
+                    0x604f5abf66cdb040,
+                    0x007e3da8ed70620c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1a615f01351b5c4a,
+                    0x16052753742cdb9f,
+                    0x748e74f0c6439772,
+                    0x86b74347124947a1,
+                    0x66ee21ce023b67fd,
+                    0x016fd58a7b3bc15d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xea76e85883a7a115,
+                    0xbba0ac367467c719,
+                    0x54f4187ad3ab43a7,
+                    0x308145cd1e4ed3df,
+                    0x2ecbb15cb9de3b3e,
+                    0x013ebcb08e457849,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3cb19a6875030ebb,
+                    0x336841e8b9299f5f,
+                    0x6f6df525a14b4c92,
+                    0x90a9abc618929d39,
+                    0x1ac7542b9250ac26,
+                    0x003a261a3a044060,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x15fd36d234fc95b5,
+                    0xc26eb02028fd07ac,
+                    0x6dd12986c4b246d4,
+                    0x945c5c73d1e14727,
+                    0xfd234b8717e55077,
+                    0x002a625e5f2835d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f47a790e86ef5ab,
+                    0x06c6a6238a3fe737,
+                    0xabbf1d9daf3737a8,
+                    0x3858c6a5322b5d25,
+                    0xeec1f7c08789c92e,
+                    0x000b44528ea38e3b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x22fece483379c72d,
+                    0x7ff0f41087f877c1,
+                    0x43b18508beb679f2,
+                    0x47964905d4638f0a,
+                    0xa14f6cfc80e8d3af,
+                    0x010360a67e5d884f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x924382d3095107eb,
+                    0x609ddece3773e784,
+                    0xae49f67756cf1dd1,
+                    0x0d6248e9a71210b6,
+                    0x2ed0f579f29d4098,
+                    0x008566346247011a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x304a37442e461634,
+                    0xd4979d039314e126,
+                    0x9bb279d0771c161c,
+                    0x6b947073e6d1568e,
+                    0x59219ebb84ff5919,
+                    0x00a2f983674ed4a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c9e1b28caca603e,
+                    0x293f74310ba2fd89,
+                    0x590210040073625d,
+                    0x2a251c48223ad40e,
+                    0xc7232718bcb4cbfa,
+                    0x007d8d3b5bf63353,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7145dbe4fba26f58,
+                    0x5710fd3375316b61,
+                    0x5860067657115865,
+                    0x75eb4863c1b56ba9,
+                    0x2435abebecea732d,
+                    0x00fb474968c17a9c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf0083ab672c47b6e,
+                    0x98cae8518472bcff,
+                    0x196c51b67df837de,
+                    0x7fda9a594871a262,
+                    0xbeb2c0f2215dc86f,
+                    0x0082899cd591d196,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb7701b8d84465a79,
+                    0x845bca8998bc1f3e,
+                    0x73a057fd7579ff17,
+                    0x974f51117ad85a41,
+                    0x10f005bd73c078c4,
+                    0x000a778f0863e5be,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59f18923777315db,
+                    0xeed784e939680d18,
+                    0xcfb63665fb308d06,
+                    0x189b9e2a6ec268bb,
+                    0xd36176762e1a73ce,
+                    0x0026aeed5138ddb5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0c72a97401738e70,
+                    0x436cbd66986f1d6c,
+                    0x5e60924cac067e81,
+                    0xa128a45a4df93c17,
+                    0x7b0d3d53df135265,
+                    0x00211ac7abaeaa96,
+                ])),
+                Felt::new(BigInteger384([
+                    0x72d87ee53b558c66,
+                    0x9583d332efbd24b6,
+                    0xccc79e8578e5517f,
+                    0x3f30de37c7e7a965,
+                    0xd6c850f614c21c1d,
+                    0x00fc5dbe1512cd2b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x779d910303167054,
+                    0x4b58b47f9e83740f,
+                    0xf4baae17c1f4ba52,
+                    0x5933fdfdc20dd73d,
+                    0xe9b9fea26290b5c5,
+                    0x012bd84606d232bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f9c628d7207b07a,
+                    0x0c2e89a69bf2463f,
+                    0x42bee0bf0b9b6a25,
+                    0xb437382709b8d9a2,
+                    0xd0b47c06da674770,
+                    0x01754b6c1fbfd243,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe2578d6859259dc7,
+                    0xd0ce9faafc01d226,
+                    0x4adca254f2d57dd5,
+                    0x7e329f975e250974,
+                    0x61dd4f3cfa57275d,
+                    0x0144c23515ccf198,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcc86b1ba9b87103f,
+                    0x81ade3956be4f2f6,
+                    0x59a41dc8051b0d3f,
+                    0x05079c210e4529d3,
+                    0x03f60dc1f8f5a971,
+                    0x00271f85b961fa7f,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger384([
+                    0xb42718b0d9fe607d,
+                    0x7bb2badbb2dcd92b,
+                    0x8c84054f06b106dd,
+                    0x8dda30cbcc54eebc,
+                    0x70196972fa3ddd3d,
+                    0x0034b705f37b7b41,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1cef0d2f49b20172,
+                    0xe6462bd34c4fee0c,
+                    0x6820da5dfb6ba1be,
+                    0x8b21b5d58b34bfcc,
+                    0x2c9a49ed6ff883f9,
+                    0x01a271b7fe10743d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ebf83b9c9ef1c63,
+                    0xa000428355c211a6,
+                    0x6d50023beb281b54,
+                    0x7abfc4e36b197473,
+                    0x718ecfa1009d7465,
+                    0x0007533c40ab10df,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfbcf2f2c2a8c1f7d,
+                    0x00dc13888eb046d2,
+                    0xeb6aeaf8ae1bcd11,
+                    0x28d5d2fcdc550164,
+                    0x61a7032a8354f17f,
+                    0x00326426cdd1b69a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x47a6246e48bd33d1,
+                    0x6650f9d8f84b3d5c,
+                    0xf37cb713be92cbbd,
+                    0xcfc1855ccac571cf,
+                    0xe0aa9e73feab54b0,
+                    0x0174a0ec8f1252a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa824241bbd9b5678,
+                    0xa27394585fa75b24,
+                    0x5874a78fbcf67c42,
+                    0xdab19d884731dd0f,
+                    0x9b39f31d88d84456,
+                    0x016398d2182c51f1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d3ef297ae11dedc,
+                    0x68d81505da5960e8,
+                    0xb6458148ef9c1426,
+                    0xe7e4123f45921ba5,
+                    0x1c0dac64079ae8e5,
+                    0x005fd9ec0a25ff7b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x807d87462e72a483,
+                    0x154795e4a8b6bfb9,
+                    0xff25d2507a73e864,
+                    0x499a6021ccc4cdb4,
+                    0x38eaeeb5e30be93d,
+                    0x01264ed6020c314d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xc8ae7a8726e53fa0,
+                    0x617377873777c0c2,
+                    0x2cbef368d9eb5598,
+                    0x47624f9a0e39c5ef,
+                    0x279282f2129c371d,
+                    0x0155991801831500,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf12d56b076a36e80,
+                    0x9a6b685525237733,
+                    0x0820511b4ee6f7a3,
+                    0x5b0b646f32b64794,
+                    0xe2460a42b1ded918,
+                    0x00d695e764f0a116,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdfe607ee2a81b90d,
+                    0xe77fdaee844bd67e,
+                    0x668d8a99a3f85318,
+                    0xc2b5363d99b9c980,
+                    0x8cb83c8fa43d8158,
+                    0x0198721bc931518d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ea8c0930a558f9c,
+                    0xa7f0d2d4b0b6a47c,
+                    0x9c95a8d48517ce28,
+                    0x355dfbbfe3942b4c,
+                    0x9d1847a41cabc8e0,
+                    0x0017a09d453a24a4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x01809622b57417f7,
+                    0x7329f5141c140993,
+                    0x9183e12b0fd14d34,
+                    0x5bc453accf213cd7,
+                    0x2b5c65c3a10d989f,
+                    0x010f5434d87811f8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x907573f46474f951,
+                    0x8ac5a0b7610360a5,
+                    0xa4ce0516710d63b0,
+                    0xfc460ad623d2f192,
+                    0x6180ca9f3c483ade,
+                    0x0117c0ce1e0cb6ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0x11f420313275431d,
+                    0x742d70f2bbd1b262,
+                    0x6438597092a3f7a9,
+                    0x35f2f1c0ca72b9ba,
+                    0x654155dc369f1a6b,
+                    0x004fdfa07145f8fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe6c9264dbc204c52,
+                    0x5ba27ebd1f7114fc,
+                    0x1b374439c4cda8c2,
+                    0x47f29a70ded240ba,
+                    0x702c27239d0bd20b,
+                    0x0118c60e972f0351,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xa4b34776d43837e8,
+                    0x1953ef538cc495b0,
+                    0x856b20d3211ae156,
+                    0x4752d03648438f64,
+                    0xd29ff474191eaba1,
+                    0x01309703eafc1b72,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaeed92665610db33,
+                    0x07c731e9f033a53c,
+                    0xcc28bfa5d93da5e6,
+                    0x73571ffe0bcaddf6,
+                    0x264ad087a78ef51d,
+                    0x002aebb848fc0479,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8f03e81ed84fd8d4,
+                    0xc6e8a7f12cc53b50,
+                    0x2c717affff848acb,
+                    0xccdad61cdcc1e0dc,
+                    0xb3c04a21c3661e6b,
+                    0x015c810f9c66acad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x910fa9f4c638bca8,
+                    0x5c55fe8b4fde2605,
+                    0xcf14b2133ddad51d,
+                    0x58379bd0472ba0e1,
+                    0xced9f4210bc7f10d,
+                    0x014006c4925a2dab,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x39d15ffaba7c2cbf,
+                    0x47a5f5eee9f20f27,
+                    0x0bed7ac99a7fd57b,
+                    0xc39d7f8bb398ace1,
+                    0x98832ef5d331d21a,
+                    0x0172b251e311d751,
+                ])),
+                Felt::new(BigInteger384([
+                    0x41d8156dd4d0b74c,
+                    0x8585c50fc8d6c104,
+                    0x245da111b2392979,
+                    0x0aaf6e30b71afce9,
+                    0xc706172e56380dcd,
+                    0x005b9bf46f6cd0b4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5acbedb2e20b72f0,
+                    0xecaf524123815bfb,
+                    0x96f3c7e15cc96a49,
+                    0x74c422b4062c15f3,
+                    0x4e9e3dbddc319c04,
+                    0x011995276dbd7513,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc50d8a4e90cdef4d,
+                    0x913d1854e9af7cbd,
+                    0xbb308c525b6d77b1,
+                    0x7949ae882497c36d,
+                    0xb2d06f520ea79920,
+                    0x0118eacd4c8d54e0,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger384([
+                0xabda831a5cbde6ae,
+                0x224b54acaf57570c,
+                0xc93c8e35c83cf287,
+                0x2c0824cb47188183,
+                0x010276ccfc67cf03,
+                0x003fd8ed50e7127f,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x272882c0f8aaafd0,
+                0xef08ee1f2d916679,
+                0xc4620da074f69039,
+                0xc12af19336e17118,
+                0x499305884c2ee764,
+                0x0178e2cac849b8a9,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x3544de631d6b8b60,
+                0xc9355643b33ceee3,
+                0x1990472473e97e7c,
+                0xccb52319040ca44d,
+                0xebe543479f6f19a5,
+                0x0035a6b0edcbc415,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xb542511b3ccacf18,
+                0xe08ed2debf6c5f45,
+                0xf1fb7b80158597c3,
+                0x54f891ef7b759fc0,
+                0xd020627673861447,
+                0x0188c6dae0a48969,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xa45347381a3f99b9,
+                0xd6ba10dad2ccaf9d,
+                0xaa15fe0646ea9ed7,
+                0xacc64b94e0745d9c,
+                0xf2ece5badaf2f83f,
+                0x010f9684006d10ef,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x7a167085a55e51ff,
+                0x633afedd3201a847,
+                0xfe3a7323b681f853,
+                0x9d5545b44f9d28b8,
+                0x610e0e74d936f42d,
+                0x005ff550843d43f7,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x8802ad6feb8e6a9b,
+                0xb2640f9d6b3cafcb,
+                0x1cdd9dd96f045b94,
+                0xa16fe00adae67b68,
+                0xfbdfa907319aa0c1,
+                0x004fa3d278bb6a0c,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xaf79564d52bb7681,
+                0x160da11d744cb47f,
+                0xdae9a83c2ac5bafe,
+                0x12e8cdd0d34100dc,
+                0xe6be65d0ee9eae0a,
+                0x0040ab923196bc40,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xd59b9dde09f7d253,
+                0xf2db7b69279576fe,
+                0x345fcc6fda62319d,
+                0xce0c1d6a5907a65c,
+                0xa7ed0281edf0d8f3,
+                0x00bf5e651ab22c7f,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xb3e9f529ae18fb30,
+                0x801914db99a265e2,
+                0xeb8b71cad169ecba,
+                0x9d0c75e1d2c54645,
+                0x0934dc8d1262dca6,
+                0x0168f513168a5672,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 94, 95, 287] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 94];
+        let mut opad = vec![0x5cu8; 94];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,1296 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 192 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 2 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 2;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 22 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 22;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger384([
+                    0xd783ff2e36c1fe33,
+                    0xa413c843d49c94d6,
+                    0x69bec0185c740b78,
+                    0xceec4f08af4d9b07,
+                    0x49366778ee0875bc,
+                    0x0093ea96609df97b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3025b2e53e16773b,
+                    0xfa33d1f4e95f36ac,
+                    0x53d626d93a83f470,
+                    0x5c79e78f4fa02366,
+                    0x8cb211ffe7d3712e,
+                    0x014b38f6048bb32f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xffa13f54b425ec86,
+                    0x676924344fe378a7,
+                    0x2cf52ddbc85566de,
+                    0x8764814e5460b3e8,
+                    0xcdc18092db640e5b,
+                    0x00943743d94a433e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x707bf693d5f84caf,
+                    0x824717557b5070b5,
+                    0x5168ab55d6b39545,
+                    0x673cf1139329934a,
+                    0x2285fc3e5ce15b3c,
+                    0x008c7b911c945da9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7fa72f246f6e59b6,
+                    0x464197d93fc64823,
+                    0x8cb8ae1237e7a63c,
+                    0x3efcf68f6fe11ac0,
+                    0x1e66375ed10648b2,
+                    0x014736b9f53cae7d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ebb0b8cd69d7205,
+                    0x8cabd4405292d138,
+                    0x260ae45e2c2fd72f,
+                    0x3239a8b234be3160,
+                    0x8d478c3443e71b68,
+                    0x00e59223703cb800,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc88342f2724445d3,
+                    0x59793ec3e786aaf5,
+                    0xe4f420071f69fc39,
+                    0x916d4e245eb8fffd,
+                    0x33ce28ead7cf2caf,
+                    0x0043f0cc7e2b022c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb4cbadcddfbc393c,
+                    0x56970c08eed62e38,
+                    0x34fd47b683778a02,
+                    0x01f8b5725814ab60,
+                    0xe83fcf0c6a70ee7d,
+                    0x00523512a640ccae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xdf0aa24cb3c96497,
+                    0xebb2164fd19ec3e9,
+                    0x5278225d6d337371,
+                    0x1b9e46c3a47d72ee,
+                    0x7915837659afc0ca,
+                    0x00b5d95f03121745,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa3cbe3255f2ba122,
+                    0xb6abdc4692664a4c,
+                    0x84c116311e6b2059,
+                    0xbf00d2a946a96def,
+                    0xcbd04f1b166860f6,
+                    0x00f68b2fe93a5d0a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52acc12053e019ca,
+                    0x34d503bd4380968d,
+                    0x2489637de3b45310,
+                    0xf42fb451a0e6aac6,
+                    0x11b27ea2acd8c4a1,
+                    0x00212c37395e9ce0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x47028612f2661412,
+                    0x9ad63f96ff197b94,
+                    0x3bac607cec148695,
+                    0xdc9db69b6663a20e,
+                    0x23e946abbf3b296a,
+                    0x00bed60c7b378f55,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa22d10c95c0c55de,
+                    0xfaa134fc0a46132d,
+                    0xc6be16f3fc318674,
+                    0x310dc62ce959a3a3,
+                    0x7d05bd717416860d,
+                    0x0002c4f22edfdc93,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d8bbd76b018b8c2,
+                    0x6d4613e948110228,
+                    0x74c41e8589f34e75,
+                    0xbfadfa897a95b5eb,
+                    0xaa5b867a5b7b4a74,
+                    0x01652e354413d8b4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xceb68e1a8ed545cc,
+                    0x459d35d8c3606b6a,
+                    0x7a15f14f3c12f884,
+                    0xc8ec30642e4efb4a,
+                    0x03f29a407d27f317,
+                    0x00ca75c993832149,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6e2cf74adfeac082,
+                    0x3d1558afc1b21112,
+                    0x232a74e5893d4667,
+                    0x05785e535b3595d1,
+                    0x6d007a57b95cc72c,
+                    0x00055f59ebe0369a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8eb0b6ea83b409af,
+                    0xbd07843f6330b210,
+                    0x5ff28636850d2efa,
+                    0xd568b6c1faec4010,
+                    0x110cb7d345605484,
+                    0x0027604782ea9af0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe917f52c9dabdbb1,
+                    0x0d76f0178a079b1b,
+                    0x2c409d7275006d92,
+                    0xbf1f365ff92f1e6a,
+                    0xe8555dd265cb34f1,
+                    0x017daa03b40744b9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4ffbc8f9ba0c1617,
+                    0x370921e52ec0674b,
+                    0xeb5f68a7c91743aa,
+                    0x0cf3f5b398e39121,
+                    0xac4b8264ce2f8b17,
+                    0x00606584a9fda5fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf564e6d316386c7c,
+                    0x86a3e528d0ea8ccd,
+                    0xa3a43209d335e87d,
+                    0x8999a3278bfe27d0,
+                    0x46ba4e82655db968,
+                    0x00e319afcfd6e1cc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x27530a9536b327c1,
+                    0xcb61a9a01bb6b28d,
+                    0x38a2de62cf08f5e4,
+                    0x43e2eefa5d10dd16,
+                    0xcaf54a1d6b21f29a,
+                    0x010647f73556ba98,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4ff99c6321b09aef,
+                    0x9253b5880778685c,
+                    0x5cb7911a4d993d93,
+                    0x38a45c3017d63f76,
+                    0x443f0b22dc7371b2,
+                    0x006201879105f6ca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56eb37c083e867a4,
+                    0x8d0144580be36b0e,
+                    0xac1f3be9ebff76a7,
+                    0xce565671e4156717,
+                    0xdb004b48f7ba7cb4,
+                    0x0074fcb8c15a8c5a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52041a526c8cce9b,
+                    0x3e4cddae7fae0655,
+                    0x1f88d0fd4ac94285,
+                    0x4bc9ce2b326ca397,
+                    0xe3d11b9eed977108,
+                    0x0160ee5243bef783,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x077ba65e51f6e10f,
+                    0x47b9dbebe8a50307,
+                    0x060b2f27021882f6,
+                    0xfc0451bf7d6d7889,
+                    0x1956667545ade7bb,
+                    0x00d447548e527628,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f796f259e9dac56,
+                    0xbb5a5952242c961e,
+                    0x3013d242216f9cb5,
+                    0x590033da996b6bc5,
+                    0x1f34167a45847934,
+                    0x00fe0140a1f58dff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8e5a785e89f01c44,
+                    0xa87da4c0b39e1183,
+                    0x21af7ef53308116e,
+                    0x0349c63a64a39a9a,
+                    0xda24ab9ac5f15772,
+                    0x0115b7a8219987c6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6080fd2f742fc0a0,
+                    0xca2e8ff6c69f2e26,
+                    0xaec00d901b93ae73,
+                    0xfe49dce20a15d226,
+                    0x059c258f551412d3,
+                    0x00586341a09bbefc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6ea0237c443f9b7a,
+                    0xe2c8f2b95ea2504b,
+                    0xdc6b513c31116ef4,
+                    0x45aa23ba6b0d9e9d,
+                    0xb6b0a283139e5eab,
+                    0x01a05ca320c17195,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd249460169032bb,
+                    0xdec76eba38dfe5ff,
+                    0xcf61caeb9b9d989d,
+                    0xceda50fea2a03b03,
+                    0xa5378f32c5768bf9,
+                    0x00a319f49c0b6ad8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x34e00deabf85ddf3,
+                    0x55644047032afc1f,
+                    0xd2b78306d66a047f,
+                    0x8a4198f930ddb9bb,
+                    0x5ccc6ca6ce41d757,
+                    0x0136fd7493444584,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd4e6ca312724dc00,
+                    0x44052814601fd150,
+                    0xbb6584003492c8fb,
+                    0x3509364d84b4d263,
+                    0xe90d0ead83872eb7,
+                    0x00e611d89c8a145a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6d17728622ecac43,
+                    0x083918ab0cdeb705,
+                    0xa11f0c8ad72f698a,
+                    0xe9e4899ee6ebc78e,
+                    0xe1cf9e50eda104bb,
+                    0x00f6e038cbc57ae7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x61cd7f9b8df10878,
+                    0xeff578c7ed74a0c5,
+                    0xc3407b76f108ca0f,
+                    0xa52262fe13ac277a,
+                    0xf90c877b4be406aa,
+                    0x017ed44a724dcdac,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc0f10999e78c180,
+                    0x462d891e5fba0c22,
+                    0xc9a55d1c8671eb9e,
+                    0x4e90a9ee5ba190e8,
+                    0xd917d40b85d9baca,
+                    0x00862468fae90a01,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe9175a4f8ca49221,
+                    0x37f7134808c4b6e2,
+                    0x00e32d30e8170167,
+                    0xc993d592f8677a03,
+                    0xf0fc7638784a459b,
+                    0x0000a71276e081f9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7b73afffb3a104c0,
+                    0xe353a958d674679a,
+                    0xd8172adc843af6c9,
+                    0xf511e57b21f1bd93,
+                    0xb7fe52847d7d417c,
+                    0x0071e3f4101ddfec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x351f286dfb9935fb,
+                    0xd7ea4d1c2c2a8034,
+                    0x19a3d97b6e3aed4e,
+                    0x920213492f35b814,
+                    0xaa26ea874d03158b,
+                    0x00913ceef4ae3dc1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x22ca99ff997d6a2d,
+                    0xe5d66acc939b928f,
+                    0x7d20b90174e58479,
+                    0x7806e2faed116f46,
+                    0xf6fd3f2c1f921f14,
+                    0x0099890a3677f7dc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b4c896843610b0f,
+                    0xfc2f68009a0479e7,
+                    0xb232cc8afd649b09,
+                    0x14386d82eaf91177,
+                    0xb0aa34c37b53bffd,
+                    0x0127c8402ce72aa9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb92a758117bc1c4e,
+                    0xf779f0fccba9e38f,
+                    0x39359067acf4d2e1,
+                    0x3a8ce5af8ca29a7b,
+                    0x6a9ffec2197561d5,
+                    0x01248c1254e0617b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa903a05687308df6,
+                    0x2d341f4e2ebed694,
+                    0xcb98bf6a3d66c3f3,
+                    0x2969125d12e2d8b2,
+                    0x67adf6e3379860d9,
+                    0x00775d0e362ca8e9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x910550485580f753,
+                    0x5c92723d493c6ba7,
+                    0xd939835aa826f9a9,
+                    0x251664e2b5a6bd9a,
+                    0x29b3871f64ec8ec6,
+                    0x0005161cc1d073d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40d28fbb82648b3c,
+                    0xa1bdad9a650a2a97,
+                    0x07d9ed95d2e4073b,
+                    0xcdff14626aac6727,
+                    0x025c5eb8f696401c,
+                    0x00151f4d91d1e1d7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb1e449815d3b64a7,
+                    0x7eb1d87a7f99912a,
+                    0xaffd0e97697b7cba,
+                    0xc90f8b78364c9380,
+                    0x46c08d22aca5ee0c,
+                    0x0025a2c9733430bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbed5d64fe78c0f84,
+                    0x9eb0263f7ff06106,
+                    0xb1b6d4f137bc8fdd,
+                    0xc5f51b021c0699f8,
+                    0x76fdf8c8c791a93b,
+                    0x004207a4060e7baf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf614aa46885f1b54,
+                    0x17c845e54fa19502,
+                    0x63be25a900458f7d,
+                    0x2695c2d5ad8dddba,
+                    0xa86ffbb91c479f70,
+                    0x00340a737ad7d72b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x991ae7fa9166bef9,
+                    0xf1dd11b63add5508,
+                    0xc79e25cda1fc8f4a,
+                    0xc2db2a8d04e4e25b,
+                    0xdc9f2e98b5f12034,
+                    0x00eafa37e48811e4,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger384([
+                    0x9a5c7521c3c36532,
+                    0xbc4281d29fe8599b,
+                    0x86d858e9dadbb76e,
+                    0x4b93c80d9a1d4abf,
+                    0x87e7dfabb0f32c5b,
+                    0x00f00a14bd2e5350,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdc9c4de11b2784a9,
+                    0x14066905af8c41fe,
+                    0x01fc790ba52f748e,
+                    0x3068dfa66e95f63a,
+                    0x4e1090f4ebdac84e,
+                    0x00b6b42d6dff1f69,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf8d4efba9ea6c43c,
+                    0xcff3604a7e5806ec,
+                    0xe7681184c3230e3f,
+                    0xa99b3b7c017f57a1,
+                    0x570ca29dc56fd5a6,
+                    0x0032e26c5dfa03bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca9eb485887d3330,
+                    0x05ee0cd066aa2719,
+                    0xc5beae6fcc816ec7,
+                    0x5b91ee52308a08f3,
+                    0xad529586702d2b2c,
+                    0x001d54df9d472a86,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xaba16b65752a8cc4,
+                    0x8e9f2ce232b4bf6d,
+                    0x6cfb528e920dc6a9,
+                    0x198c8b07952efc02,
+                    0x9969dae9129adb5c,
+                    0x01a78f493dc2044e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf85127e77ffdbbbc,
+                    0x021e3c97a1983db7,
+                    0x846650c01ec10480,
+                    0x8fd9838f53a5b0b3,
+                    0x3b9c07aa80fee98a,
+                    0x0063997e2d333f40,
+                ])),
+                Felt::new(BigInteger384([
+                    0xacc301405d50c99e,
+                    0xe3176facc7c887e1,
+                    0xa5e289417d3ca1a6,
+                    0x738412e7be62db2c,
+                    0x4d9376a45cf09ef8,
+                    0x00c179b92c1f86e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x294aa8b6e29d2828,
+                    0xcd39d7e8c3492187,
+                    0x3882538cd1910b66,
+                    0xab31ad9392d11e52,
+                    0x8c3cbaae93e493c3,
+                    0x00e0a380c77971f8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9cb5ee655ec8a199,
+                    0x286ab87ca1005819,
+                    0xdca368a186278be0,
+                    0xd230c4f41fab8f21,
+                    0xbd6745c21758118c,
+                    0x017cb17d9fef0778,
+                ])),
+                Felt::new(BigInteger384([
+                    0x28aaa2e0c2211de7,
+                    0xcd920d1dd4676bd1,
+                    0xa49ab9b9947aa399,
+                    0x0a578362e84660a7,
+                    0xcaca8720d259e4c1,
+                    0x0000f47c80a71af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb5491ed73a1ac808,
+                    0x128f08ec7dfacdb3,
+                    0x9c093c4d4221a142,
+                    0x44b24eb750278e10,
+                    0xf75bbf6d4d8b78be,
+                    0x0174c5f56d8bb043,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc99dfe395eb0eff0,
+                    0x21818ace410020f3,
+                    0x11f95a9ea21c42a8,
+                    0x714a9c97009c9040,
+                    0xe7ee3b359335cdf1,
+                    0x00a336c871ed6d4d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5feee5a82e2ea629,
+                    0x5d5daade40cb2b63,
+                    0x995b3169ee638f9f,
+                    0x9a5907703da11bed,
+                    0xe0873bbf80d005c2,
+                    0x011925d11abbe755,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8cdc816e93734260,
+                    0xce37ea40eb908d0e,
+                    0x232c302fabeaca91,
+                    0xc054c38fcc88e848,
+                    0x196148b35170ca13,
+                    0x011d83b365631e6d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc16e068de7c07112,
+                    0x4bc51ca294a74605,
+                    0xc32f932a62764521,
+                    0xcf8bda6cacc78b52,
+                    0xe29c6f9703ee0882,
+                    0x0173cb186daa63b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x401bae6c9e127a5d,
+                    0x6838ce7d9ace86d7,
+                    0x98d4a3000f776af5,
+                    0x3e696501eb53079d,
+                    0xed7f07279554655a,
+                    0x004aac57d48ec4d5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4d48f45fb8aa2ddb,
+                    0x8e9cba06eac31bdb,
+                    0xe66783b4793d3081,
+                    0x1958b074ef4ede65,
+                    0x304d69efef7b59d3,
+                    0x01104eea50513124,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf22d8dd305c6d8f3,
+                    0xc3fdb18cad7d35db,
+                    0x1ffd04021cdf231e,
+                    0xfefb662c293aa15e,
+                    0x4d30c784d531ef82,
+                    0x00fa33ff8c25b875,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9cb97fdbeab4e74a,
+                    0x4f69c564d0980259,
+                    0x3c71a0a9edef2777,
+                    0x14e430421854650d,
+                    0xd03794647786930c,
+                    0x00e9c4b092444fe1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x97119e19b66c7d7e,
+                    0x6a0c47204f64a62b,
+                    0x1840b055e4388793,
+                    0x452e8a4bf863d12b,
+                    0xf3939c4b5d978140,
+                    0x00cd71209c271104,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa4adaf94fc5572d7,
+                    0x8fe9c4915b968f72,
+                    0x5eaa8e6ce962ea56,
+                    0x9f466400118c8625,
+                    0x762465ab4a2cdddf,
+                    0x00926d626c69a169,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4072fbde5abaa840,
+                    0xdfdfe2af67cf8ae2,
+                    0x8d93490b3cf2befb,
+                    0x4a71ce950fc35e6f,
+                    0x7c69bcb0686714ad,
+                    0x008ab4bc81f89467,
+                ])),
+                Felt::new(BigInteger384([
+                    0x575374ed8063d0f5,
+                    0xd5def156ba8fbffd,
+                    0x03464b6462ef667d,
+                    0x05f5f678293441a6,
+                    0x3af3071489a914a5,
+                    0x00e8d772a6c2ce17,
+                ])),
+                Felt::new(BigInteger384([
+                    0x75d54491b4c9aaa9,
+                    0xc8404a1c27eda842,
+                    0xa822c55028230a9b,
+                    0x3adba1f36c904c3d,
+                    0xdd21754ae9b5ed4c,
+                    0x005c23243c205006,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger384([
+                    0x93b43ffffffff67b,
+                    0xa0d125e30ffffb0d,
+                    0x5d1a4faa05a59724,
+                    0x323b39b7e2fcce8e,
+                    0xf0223f35e4a1e060,
+                    0x006f42bfb905f50e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x963abfffffff7099,
+                    0x615462c0afffb57a,
+                    0x983ad5e0f70bfb17,
+                    0x043b91b1b7782f20,
+                    0x460a32e63333859f,
+                    0x005966855b430ccf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93b43ffffffff67b,
+                    0xa0d125e30ffffb0d,
+                    0x5d1a4faa05a59724,
+                    0x323b39b7e2fcce8e,
+                    0xf0223f35e4a1e060,
+                    0x006f42bfb905f50e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x963abfffffff7099,
+                    0x615462c0afffb57a,
+                    0x983ad5e0f70bfb17,
+                    0x043b91b1b7782f20,
+                    0x460a32e63333859f,
+                    0x005966855b430ccf,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0x93b43ffffffff67b,
+                    0xa0d125e30ffffb0d,
+                    0x5d1a4faa05a59724,
+                    0x323b39b7e2fcce8e,
+                    0xf0223f35e4a1e060,
+                    0x006f42bfb905f50e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x963abfffffff7099,
+                    0x615462c0afffb57a,
+                    0x983ad5e0f70bfb17,
+                    0x043b91b1b7782f20,
+                    0x460a32e63333859f,
+                    0x005966855b430ccf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x93b43ffffffff67b,
+                    0xa0d125e30ffffb0d,
+                    0x5d1a4faa05a59724,
+                    0x323b39b7e2fcce8e,
+                    0xf0223f35e4a1e060,
+                    0x006f42bfb905f50e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x963abfffffff7099,
+                    0x615462c0afffb57a,
+                    0x983ad5e0f70bfb17,
+                    0x043b91b1b7782f20,
+                    0x460a32e63333859f,
+                    0x005966855b430ccf,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x695085525b142b13,
+                    0x5e5e798ec9203787,
+                    0xeaef237a2c6bdbc1,
+                    0x84e7c71c0f2842ca,
+                    0x757e3b82f6fd318d,
+                    0x018f3b19a082c4fb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc1d99db471560ab8,
+                    0x58f06ea8d86f82e6,
+                    0x14b02e9812ff64e1,
+                    0x8c159f01448ace4a,
+                    0x593bbd1b73deae5d,
+                    0x0093fad888e1bd4e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xda100074d442b2b3,
+                    0x1e2553e99dd28efb,
+                    0x35e453078185fc84,
+                    0x318790a3460b17e1,
+                    0x00d51704951966b5,
+                    0x016a5ef3072851d2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x835bf6930e8f3cad,
+                    0x7f9aebc87dae67a8,
+                    0x9c6054b8a18d79fc,
+                    0x56ea7da910a8d31e,
+                    0x18c4b6dd6a5d6b7f,
+                    0x0143b760ac1a040c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x243fc1f5f5088cc4,
+                    0x523744b3eaa05d33,
+                    0xb32c85117737ea29,
+                    0x1ebfd8a17810084a,
+                    0xfea236e4ef05658e,
+                    0x00c4a49584ae7566,
+                ])),
+                Felt::new(BigInteger384([
+                    0x74d04550db7dfb31,
+                    0x320bb64510fdb3b3,
+                    0x2b5a6d7804c6c4eb,
+                    0xa6233f6055e2a42a,
+                    0xbb8016d189e6dc40,
+                    0x0025a6564d08a9dd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa57a7b7c5a58f863,
+                    0x7a49d62b280917bc,
+                    0x1820af1511dd8c2d,
+                    0xb8fad382b3e86d06,
+                    0xac43860d0d94e3b4,
+                    0x00732d6fb6eee2b1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4acd3d89a8875767,
+                    0xb13d87236050ebed,
+                    0x93ff42bea112b850,
+                    0xe1ab0fc4452cf04b,
+                    0x4c9c3a667624d1a2,
+                    0x00c83a2c850a8b9e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfeb57990beb96222,
+                    0x33f97d3c130fa95a,
+                    0x81b44a81395721e3,
+                    0x6d5177bfbbcb38f4,
+                    0x9f4530ae6a9c78dc,
+                    0x018b04c929b99ba3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53db025beefdddd8,
+                    0xad9ca72c8252581f,
+                    0xabcf1adf7e1cf7e8,
+                    0x1e587647dcbcb8b6,
+                    0x0ed916938d5441ab,
+                    0x014d46b6bd855d9c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa7750cd5c642a85b,
+                    0x0c4f543332b22e74,
+                    0xa427e6b90f9c0d86,
+                    0x23f827fda67be4f4,
+                    0x554f27d79a312917,
+                    0x00a1da9aa81ae55d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66f05f5dd802a557,
+                    0x40f0c853566b8686,
+                    0x80ab7406d00ebc1c,
+                    0xc36b8ae20bab830b,
+                    0x519cf28ccaa529b6,
+                    0x00db3760b880bb3b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6e82fb22d1ef89bf,
+                    0x8c32c0022e436f38,
+                    0x73702e57bcc89e29,
+                    0xd9f3f95f3013f490,
+                    0x9eec44bd081f009b,
+                    0x010596971dd8068e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x593a7878e07c5488,
+                    0x35cae2fbf1841158,
+                    0xd03073a631588700,
+                    0x9a66b69895168cb6,
+                    0x71261c02f9964021,
+                    0x01514a174f1de887,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd41c50bd32591a5e,
+                    0xad31bf8de09ba126,
+                    0x7642460f61eccfe7,
+                    0x13d62008fe923332,
+                    0x23aa463b4c162d04,
+                    0x003a9d370c88c092,
+                ])),
+                Felt::new(BigInteger384([
+                    0x250541a3daf8fc92,
+                    0x43989b6a5dc5b74b,
+                    0x732ae9b1ab43e3b4,
+                    0xc4d2070d956e6429,
+                    0x6d20818f9ff81949,
+                    0x01868cc5fa238a70,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd0a583bd0f50e40f,
+                    0x3b13d9df651943b9,
+                    0xafac4c269ffcb74e,
+                    0x1eda03805120a2e1,
+                    0xbe15e6f49bbccf6c,
+                    0x00994e6cb09967c2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92b485e6eb8435cf,
+                    0xc6eea34ea9f82dbc,
+                    0xd0548f56da7f78b3,
+                    0x4b1358f1e55acacb,
+                    0x91523317d61ba8b1,
+                    0x018ea8ff6e497848,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9daa1bfc77060acc,
+                    0x58e3ede7084cc967,
+                    0xab4e08cd04f19790,
+                    0xad768e935dad2078,
+                    0xf4fd202af96fd51c,
+                    0x010e25486fff37e9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2c6263a6e40f8935,
+                    0xb55e6e889d17cf67,
+                    0x2f75b0ffadc380eb,
+                    0x539ce1598cdb9c17,
+                    0x32f9432543687ca6,
+                    0x019de7784c4afe56,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd23eb19c4d454e92,
+                    0x3acf3a8380bfb2b1,
+                    0x0f8aeb26d96db318,
+                    0x79433cfcf935ace3,
+                    0xe12f573f4910a5df,
+                    0x004be10f9320fee3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x880de406e1ca42cc,
+                    0x23ed95db930b034c,
+                    0x38d04bf2864dad67,
+                    0x311cad81a7fe589f,
+                    0x21b9cde4d71e3a4f,
+                    0x01a07019f15d61ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6b731e7c3aa2e8fc,
+                    0x97f5840f9659e816,
+                    0xe1a61eb423dfcbfa,
+                    0xc32e4365cef7887e,
+                    0x1f86b17b9593d91e,
+                    0x008ef0ab4361dc0d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x09e67e34efef77b4,
+                    0x4a08dbeb99d4594b,
+                    0xa1412d03daddf22c,
+                    0xf2fda7b044ebdf49,
+                    0x34b050902e2c5f4a,
+                    0x00e1ce1d22a55e46,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,805 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger384;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0xb84ebfffffffe409,
+            0x9132d225affff177,
+            0x77d13b548673857b,
+            0x1b6315703a7a08a5,
+            0x837227e22da91c38,
+            0x00c061dd4813ea92,
+        ])),
+        Felt::new(BigInteger384([
+            0x62a7dbeae2048989,
+            0x42c66ca79f589974,
+            0x58c464bc7d6cd826,
+            0xa011460aa1069de0,
+            0xf0ec0bf46bd02ec6,
+            0x008e6fe6537e4eb9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x74034dac2931d81f,
+            0x4aea7b7eb441de41,
+            0xc97fc4e0ae77e68f,
+            0x46681d97065a56e1,
+            0xf428ac080bc359d6,
+            0x00c826345c784e0c,
+        ])),
+        Felt::new(BigInteger384([
+            0xbc46d198e31720c4,
+            0x5d65d1fd527ab126,
+            0x5263a97425b196e9,
+            0x3005423f8fad72ed,
+            0xf34bd0b23a47a6b6,
+            0x01578b9112bd25fa,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x2ddac3088f724417,
+            0xf226a1094d8acd73,
+            0x4ad0b2730feb469e,
+            0x19a247b2b8d51bf1,
+            0x301c54a3233336d8,
+            0x00d737ed857c66a8,
+        ])),
+        Felt::new(BigInteger384([
+            0x75e5bc392886bd1b,
+            0xbee5efc4015c81b1,
+            0x31f3ba709f34c2db,
+            0x445a44639814eace,
+            0x2dffce8351837c63,
+            0x001e61573416d6c7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x8945f5ba9fbec70f,
+            0x52db7d35101dfdd6,
+            0xe731a332b2f14ace,
+            0x84db593a6f33450b,
+            0xd52b89331990e227,
+            0x012de4b2bbe0a4c8,
+        ])),
+        Felt::new(BigInteger384([
+            0x54fec443e11b4430,
+            0x410bb1e31b433883,
+            0x3093db33dd6e5a3c,
+            0xc095390a9ff8a063,
+            0x1acd00e455511067,
+            0x01039ddcb3c99415,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xe7a341ea0ec835f2,
+            0x2d8932e901571700,
+            0xffeaede791e5906d,
+            0x717acae64da66bbe,
+            0x249598981f376f6b,
+            0x01197fa7f1e24729,
+        ])),
+        Felt::new(BigInteger384([
+            0x50cb1a8519b3d398,
+            0x59d4e3e1808b5607,
+            0xf9bf3bc4fed90b4b,
+            0x676141569b7052de,
+            0xfb4325c6bd3a4a15,
+            0x01850ff50498d04c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x6d12470ea50e10cb,
+            0xc0fa39947a07445f,
+            0xdd8c20fe8f3a9466,
+            0x72ff7297dc37f1f6,
+            0xcb755de2ec4dc814,
+            0x004a97aceb124e10,
+        ])),
+        Felt::new(BigInteger384([
+            0x837007a966e9736f,
+            0x702f9ae58da529d0,
+            0x882f22215186cd47,
+            0x17d41e161b112edf,
+            0xa0ada3814d8fb67b,
+            0x003f1d37d1c0a085,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xb7c98917bb436a23,
+            0x99da2691bff3fbdf,
+            0x5f3fa334e1e41bd8,
+            0x11db6b8b6a9fe44d,
+            0x486490b928f5fcc4,
+            0x018fe3625b0c1e62,
+        ])),
+        Felt::new(BigInteger384([
+            0x63dfa984cf4910ba,
+            0x8134dbff7f865250,
+            0x2a73ae24925c8f61,
+            0x9ab6025de9e9f509,
+            0xc398ad1a3668478b,
+            0x0078a9aee77070fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x7ad28a3e5b2eb157,
+            0x7a7f1ace81dfb343,
+            0x9789ee32bed7853a,
+            0x5d23f0c42eeaa1dc,
+            0xa23283072c36215e,
+            0x00dcd7a958b08881,
+        ])),
+        Felt::new(BigInteger384([
+            0x06389e16214ef8fb,
+            0x68f7cd36a2791818,
+            0xdd97cce290886028,
+            0x96dce3e6df1f11be,
+            0x8c5afae58a2d1f49,
+            0x010bb308e97fff66,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9265f3ce4d1828fd,
+            0xa3304701fa785273,
+            0x7891f98014d7c696,
+            0x778ac2b26ef114a2,
+            0xcb23f2ba9a2b387c,
+            0x010de63c1ea5d8cb,
+        ])),
+        Felt::new(BigInteger384([
+            0xfc8080b5469449fd,
+            0x7c8738308b780f87,
+            0x7e87dd9ce0292876,
+            0x8d2d1f63405565e7,
+            0x426ce04b8a2c22d2,
+            0x0189786c02997aa6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x80290e04b1e39d4c,
+            0x09a6c9235fa69a1e,
+            0x54604b7492a31071,
+            0x80610c382ce4b57a,
+            0x04889e6b59a208c6,
+            0x01420455a4b8acbf,
+        ])),
+        Felt::new(BigInteger384([
+            0x194aecbc78ebdbc8,
+            0x67158f577e635adb,
+            0xd6ce1b951543bc80,
+            0x5fd70c913fc87b0e,
+            0x20d58e467c85420a,
+            0x017532ba31894464,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xbeb8672966bad2e4,
+            0xa6ee5492a0da65b5,
+            0x1aab81dc18b38295,
+            0xaa370e59e7e5e551,
+            0x36bfe5665e391091,
+            0x001d23093b6a3233,
+        ])),
+        Felt::new(BigInteger384([
+            0xed4a3d40db0133b6,
+            0xed8f913a5a8b2124,
+            0x01f307b95cf4ec10,
+            0xb21bd231bbf56a32,
+            0x91138da4844da01b,
+            0x007e3377f2276759,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x60f929dff39dec93,
+            0xa3e3b509ddf4e2cb,
+            0x230997f3b5dd8ed2,
+            0x51e0b0baecf91f22,
+            0xe5b0a61f86d9c849,
+            0x0034a826c2c5d385,
+        ])),
+        Felt::new(BigInteger384([
+            0x839bb1e94a852a38,
+            0x5b3e80058bb44699,
+            0x359800a14f6da20a,
+            0x2ea1b66c7367ec55,
+            0x16bf1643265a9e8b,
+            0x00b9c73d42463963,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xb237b6241e98fd7a,
+            0xe06c6ba5c27594c7,
+            0x225af546badeece5,
+            0x176c5348e4c5a69d,
+            0x523f96fe40dcd3f8,
+            0x00d7b20d91b0c999,
+        ])),
+        Felt::new(BigInteger384([
+            0x12a4e071d7320bed,
+            0x78416b8d8f00759e,
+            0xda786fbe42337a8c,
+            0xf2bfbc5d8c3635c6,
+            0xfd8eea844c309228,
+            0x00fc392436ceb239,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xa5e84b6c8c6b0b72,
+            0xdc7d6f187bce5473,
+            0x4b3a5736c6c85a26,
+            0x7e17e458305be6fc,
+            0x9feb006f36ca65a3,
+            0x00cc8361a6a44396,
+        ])),
+        Felt::new(BigInteger384([
+            0xe4ca2c87e0c653cb,
+            0x8c8905097d5c18a1,
+            0x0e1759691d5baccb,
+            0x2a68a7bba070c9e3,
+            0x5975986ce4ada483,
+            0x008bcea25d147988,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xb9c8edae279ea878,
+            0x04b8013813c1f826,
+            0x52ed835aaa898d25,
+            0x51be98d728217933,
+            0x209ad37a86937ec0,
+            0x012d17f9a950879e,
+        ])),
+        Felt::new(BigInteger384([
+            0x493d1dd2486f7733,
+            0x65d1a1b52589859d,
+            0x8e81d6b874309b30,
+            0x698021983a819e5e,
+            0x09651ae179d5c85d,
+            0x00cdcc6c7b90d2f6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x3651944079574608,
+            0xcc4a2c08cca0f28e,
+            0x06b838ce79561aee,
+            0x1b3b94be65f3c719,
+            0x0912c9b364539d5e,
+            0x00d570865118f8c7,
+        ])),
+        Felt::new(BigInteger384([
+            0x588703cb4ffe1a45,
+            0x753b8560d1a28bd6,
+            0x1a40f07b9edbbf3f,
+            0xe95ca62d81f44ab1,
+            0x2f22776629e33927,
+            0x0035e0f4c41c812c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xb71d0a5818cbeeac,
+            0x4e64fcec0267d360,
+            0xd1f993aa9e08311b,
+            0x3c50e5401339cf4d,
+            0x0b1ade26c8002259,
+            0x0056b3ea48f91edf,
+        ])),
+        Felt::new(BigInteger384([
+            0xa344ce5e710442e8,
+            0x26ca27809e153e1f,
+            0x5b2547e28e1c2e06,
+            0xecdd7b3d1dd6feb8,
+            0xaecfe6a59c46ecda,
+            0x003c362511b4a4d1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x819755be4b8f8bcd,
+            0x7f9ad569fb7bff26,
+            0xfb45a2b81f4b34fe,
+            0xbd63b4b9d4203761,
+            0x69476bf324a1764e,
+            0x004b5cf4255a276a,
+        ])),
+        Felt::new(BigInteger384([
+            0x6c4566d481327909,
+            0xac3088997a5c673a,
+            0x10c9b6a951c91a22,
+            0x30bea494ea80ea73,
+            0x21812ee7ee921bbf,
+            0x002c87ca02dcf5df,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x4674425703128357,
+            0xd0efb95928d1cea4,
+            0x75a7956bcdbc88aa,
+            0x324cea780e996ee2,
+            0x9a98341abb80596c,
+            0x002e666fb128e8d2,
+        ])),
+        Felt::new(BigInteger384([
+            0x4be7525d5b40e66a,
+            0x13828ead9d6ff096,
+            0x7f8c9f3690904b74,
+            0x980232b39269d487,
+            0x5104174421e0680a,
+            0x0013cd562125d065,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xf4dd574c64848d3e,
+            0x0f94bd76616e6e0b,
+            0x2261a5bcb2a6c648,
+            0xe89184962ed0ec82,
+            0x5700d015e7d48b2a,
+            0x0067ce8cbdccf9ed,
+        ])),
+        Felt::new(BigInteger384([
+            0xc8aee2a38ea825d3,
+            0xe84da3813ec434ac,
+            0x533071fd0f04ca8d,
+            0x08665cf1e194566f,
+            0x5844e1cf02d73b99,
+            0x00b1df54b0cde980,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9beb3f06081b93f8,
+            0x14561a649b775c22,
+            0xc3dc2343660aec7f,
+            0x9ade7df787cd3e13,
+            0xa4942482a8ee0c70,
+            0x0159e70e89e742c1,
+        ])),
+        Felt::new(BigInteger384([
+            0xb8a355f8e1e7ca14,
+            0xb440c70c12116715,
+            0xaf126d9db4162d3c,
+            0xc440eb42d6cb2ca0,
+            0xaf8bacfd264865ae,
+            0x010683cacb2153e6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x46db5528bf24546c,
+            0xdfb32748c61facee,
+            0xde3962b42d0bf313,
+            0x6d2dd065ca0c5657,
+            0xdc2be24f1e80d443,
+            0x006efd8eaf667d6b,
+        ])),
+        Felt::new(BigInteger384([
+            0x056c86f18d5023c2,
+            0x63a9a4646fb42862,
+            0x639e776c95afc8bb,
+            0x046cc7511265e604,
+            0xc81f8ad2a053b1a4,
+            0x00189dad271c8ce5,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0x0f2b9dddddddc1dd,
+            0xbee4d384e77768ea,
+            0x022b94b0d25c368b,
+            0x3ba4d123a87f69cc,
+            0x01b45588a882d235,
+            0x013c894bd5041075,
+        ])),
+        Felt::new(BigInteger384([
+            0xc127fdd80251fe53,
+            0x5d74ae3de14a3902,
+            0xd2a54ef75ccc8895,
+            0x201a93c949443fd8,
+            0x87b0ceec0cfa8488,
+            0x0046ad8fa9d396a3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x657b5e0b56e4d2b7,
+            0x933663e5e80640f4,
+            0x86c2454201fd11ed,
+            0xcf83ff8bc23367ca,
+            0x9a8a7677376b0d32,
+            0x0040371c5113783f,
+        ])),
+        Felt::new(BigInteger384([
+            0xb56226075339b252,
+            0x92adfa9b90b93bf5,
+            0xff2cbab40cadc1a6,
+            0x18e8b63f85bec4a7,
+            0xb23030728c3ff9d7,
+            0x000bb2b3d0bd7234,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xd324015401688047,
+            0xfb9be3cde7a0ef39,
+            0x4fb39a9a5b7c56a1,
+            0x1c3e01dc0c9f57b3,
+            0xdf6560638adc3d36,
+            0x01290b3a4c5237af,
+        ])),
+        Felt::new(BigInteger384([
+            0xa7dafe93dcec9042,
+            0xcc62d003d5eccb94,
+            0x455095a638461a3d,
+            0xc0e06a8b270c7af1,
+            0xbc0675554c1e6bc0,
+            0x015a8524dc16dac0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x60b0e3af020e9c96,
+            0x416a5b22dfa65319,
+            0x630bfe8ce801f965,
+            0x9deb2a4a31ecee30,
+            0x5f0972a6468933bb,
+            0x00b72055d7a051b9,
+        ])),
+        Felt::new(BigInteger384([
+            0xb915b64785dab0ae,
+            0x33a22d4c2545b5e2,
+            0xbae8299c5fff5032,
+            0x538f76189ddf9de8,
+            0x83688569153b4afb,
+            0x01772a00b0b373f7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xca0db9d8c5bb8e8a,
+            0x89ba58de009b21e4,
+            0x76b3390706f68af1,
+            0x94a05f70acd10464,
+            0x8c707d625b7e47d7,
+            0x00134adace20802d,
+        ])),
+        Felt::new(BigInteger384([
+            0xbfe196831316c327,
+            0xba0da751ba498907,
+            0x7f0179f2c16a4d2e,
+            0x047141df35c83fe5,
+            0x41dba5a28c730b81,
+            0x01692ba8c2013c43,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xa849acfcabce741c,
+            0x7a896a113fb51232,
+            0x37a73d1ddfbabc9c,
+            0x37f5cc90da6a5ee9,
+            0xf7eeebcf560417d7,
+            0x007a7516abf0be83,
+        ])),
+        Felt::new(BigInteger384([
+            0x4b5371a6b0196db7,
+            0x2dc668dd8dcd1fc0,
+            0xf0c4314eef873cdc,
+            0x56b4e40d5470f032,
+            0xabe4cc7f4a37ef3d,
+            0x01594b2273c943ea,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0968c0f83b99d274,
+            0xf4e19a4a70777337,
+            0x9e9a00528b97de76,
+            0x1080aec869a53647,
+            0x67b02dea6c872871,
+            0x00af1aa68349a6c1,
+        ])),
+        Felt::new(BigInteger384([
+            0x422ae574920f1002,
+            0xe043ed336a83f1c4,
+            0x7847fe508990995e,
+            0x1345b199241c9b64,
+            0xc1a1e55d0ceb5c38,
+            0x00823173f0d82c4c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x8da057eab75619e6,
+            0x3db569dc13d3f23f,
+            0x6528030b37ada2fc,
+            0xe5b854fca6068f63,
+            0x809575400013a9f9,
+            0x017db231029e1f9c,
+        ])),
+        Felt::new(BigInteger384([
+            0x20a9afd1bfe5f880,
+            0x192a5c7b3ee77f30,
+            0x9abc72999cd57d49,
+            0x7f38da2a90734017,
+            0x8340826f845b47a9,
+            0x00e8a3cb5cd2b888,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xf1174c8186008859,
+            0x4155cc8eea9fc380,
+            0x77e6c82a1d0f8cf7,
+            0xb5119d96ed8f8a7c,
+            0x07ff78ff185fa7e3,
+            0x002f74028c46bb3c,
+        ])),
+        Felt::new(BigInteger384([
+            0xe7dddd77c1ec4050,
+            0x1eb45b38b619a8b1,
+            0x8c569f5535e13636,
+            0x449e6645fa213022,
+            0x5e0601a19b527b39,
+            0x019556b351649008,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x6e9da662fd560b87,
+            0xba8311b6267c6301,
+            0x6151af9f6b285f0b,
+            0x617868fc51456f65,
+            0x4def95f0b53088a5,
+            0x0121d8cf25e2d67c,
+        ])),
+        Felt::new(BigInteger384([
+            0x0f62c92a06cde0f9,
+            0x04ee18214fb34bda,
+            0xd346109e81400a7a,
+            0xa0b5fb609e6175cb,
+            0x82bf1b1cfe6461ad,
+            0x00911d6e7c189028,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x92d9d0422f5f1e77,
+            0xfd521bfb4e3b1158,
+            0xf6cc488b1d116e43,
+            0x847add22113785df,
+            0xb42470d4b1ed987c,
+            0x008e4b42880b103c,
+        ])),
+        Felt::new(BigInteger384([
+            0xc90eea68e615163f,
+            0x30ef98da1265f4e3,
+            0xcd9a5f46f4c9d71e,
+            0xec2733051f7f4b92,
+            0x26faae63fe52c7ca,
+            0x002b71ec082d676a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x87cd6ab9a6472438,
+            0xd96e0d3cb753a679,
+            0x90fe75bbf68d82e8,
+            0xdc5673f1ef499c8f,
+            0x5e8c1b8829695570,
+            0x0093593f39511671,
+        ])),
+        Felt::new(BigInteger384([
+            0xb21336d23f9df8d3,
+            0x7dc5186f6f8d3263,
+            0x93136f482394957f,
+            0x18df0baeaff0aa94,
+            0xa81d20fcef3acb77,
+            0x00548e9082369e55,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xa5caf7f4c5ed171a,
+            0xb485b6c50b1663fd,
+            0xda5b5979ee5f85fb,
+            0x39bfb9b1c48e0298,
+            0x2460de8347217842,
+            0x017443c9722c9b91,
+        ])),
+        Felt::new(BigInteger384([
+            0x0ddb6651c0f5bc83,
+            0x3956f6e3e21b6cf0,
+            0x81ff64d0092b1301,
+            0x74dab4d1a636d294,
+            0xe832c75a78c5d637,
+            0x00d4e11ae0afa638,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xd8aac18abddae532,
+            0x41e6d5042103257c,
+            0x0dcc358bf0e36844,
+            0x12a156ce806948e7,
+            0xfdd849142d54a719,
+            0x00ca4060350fa7b2,
+        ])),
+        Felt::new(BigInteger384([
+            0xa438a6b554a5c482,
+            0xf5fa08705d0b11c7,
+            0xdf232acc94f70247,
+            0x38dc86302bab802e,
+            0x96207c236e29cef8,
+            0x0173dc21ccaa1096,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x725debd4349f35fc,
+            0x76cb2304e0929a8f,
+            0x21cfd3fc37bfa922,
+            0xc2ac999496c50367,
+            0x26d51dcf16f536ba,
+            0x0046b8415396b3ba,
+        ])),
+        Felt::new(BigInteger384([
+            0x8e7e200797df9bae,
+            0xdbec60fd2cd45022,
+            0x6bde1a684ee6fe8c,
+            0x54588e53e4527cf3,
+            0xee5d00479d296957,
+            0x00d1bd3507013203,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xba181245b3241c8d,
+            0x31029cb9cacd9637,
+            0x52e5285ee0434a80,
+            0xb36ad061c6ac8b50,
+            0xbffd71de8109a952,
+            0x0185e721dd430fa7,
+        ])),
+        Felt::new(BigInteger384([
+            0xe3f0c5dfcc3a87c0,
+            0xc6f03648da49579b,
+            0x55f470ea993fee2f,
+            0xe15373dc1ce54fba,
+            0xfe8fb4e26ce9e4e0,
+            0x00226dcb19abba13,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x1fc229500b938f86,
+            0x94a10236c4323fb5,
+            0x1997e736521d0abf,
+            0x23a5c9ff725bded0,
+            0x34f34f29491d86d2,
+            0x01162e216bfc8782,
+        ])),
+        Felt::new(BigInteger384([
+            0x138d3165a63b7ab8,
+            0x5a026d026a59d290,
+            0x924a2c4cd5a80709,
+            0x33f9f207b7314f0c,
+            0xf12aecf943b4f118,
+            0x0037c696fe1d2f7b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9dfb82d73f4aae49,
+            0x04637acb7fc7fa60,
+            0xc0f5afc39f361ff6,
+            0xa3ae518c55393cfa,
+            0xac3ea1393be51a48,
+            0x0082cfbedaebc502,
+        ])),
+        Felt::new(BigInteger384([
+            0x155597fcb75d327c,
+            0x3500cb7639228a91,
+            0xe4f3b6c31f344c79,
+            0x90f3ad65a6c7446c,
+            0x4335ff3f98c7a8b8,
+            0x014e4b159998c669,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x16b3f45ee642d04e,
+            0x3b46a1b75b1dcb03,
+            0x4fae17560b81c5b5,
+            0x9d426c53a1ff8808,
+            0xd8b46e7ce006ac71,
+            0x00cd07aca4b89be3,
+        ])),
+        Felt::new(BigInteger384([
+            0xa8d3087480e0ca58,
+            0x81e114870a361511,
+            0x680d142f1bd5cfdd,
+            0x7ce2208d60fd420e,
+            0x6dddecb7d958a40f,
+            0x019cbf13f5dfb668,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x3b4bdedfa66afab6,
+            0x55d4f53619867387,
+            0x243e326cc5fc9109,
+            0x96231a249f290c5c,
+            0xe08b2c9300f640f5,
+            0x0002fe169ccf7b09,
+        ])),
+        Felt::new(BigInteger384([
+            0x9bc96e4612fe2a42,
+            0x329578bc31566244,
+            0x6386f1bb6fdadcae,
+            0x2fe25e7e8d19caaa,
+            0xc08cd95daeeada63,
+            0x01375f5f70fa9d8e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x4c2725a4f8ec3639,
+            0x71c107c53676ad5e,
+            0x3388cd6ed10285ba,
+            0x4dad718b52e4018a,
+            0xdef86813326f999e,
+            0x00d08ed3e1823fec,
+        ])),
+        Felt::new(BigInteger384([
+            0xf58b40a71528034c,
+            0x15b351e7e78ae06d,
+            0x2d390ad76c8e0dd7,
+            0xf0fa4ad4dd0f4478,
+            0xc8ad8b9f42bbdbdb,
+            0x01677c1103e68403,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0d203ed6d22eb227,
+            0xaa691266073a69ee,
+            0xffc831af9c17e862,
+            0x58be0e7153318b60,
+            0x63834082b05686f0,
+            0x0140883b526c9c8d,
+        ])),
+        Felt::new(BigInteger384([
+            0xd354b4aee2ca1873,
+            0x1b5bcfb8bb490d7d,
+            0x74b3d7469832bd69,
+            0x4fc49767d5c35bdf,
+            0x67f97e57587a0415,
+            0x00263e949387ce0e,
+        ])),
+    ],
+];
@@ -0,0 +1,2449 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U4>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger384([
+                0x97fa8de2abbf84df,
+                0xcf1ca396107fd0ca,
+                0xf3498990ff6e4cdb,
+                0xbf95413b4e965f59,
+                0x3be7af68c4515106,
+                0x00b426fc139cd204,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0x163d7ac4b72face1,
+                    0x41fdb76ad72d7ef9,
+                    0xf3e227c328f9da77,
+                    0xb170503d118ac90e,
+                    0x0f096639d5218943,
+                    0x00b9c7be5bdaa16e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe43d5677558d6749,
+                    0x9da772f6fdf5ad97,
+                    0x872f1d4d42591c11,
+                    0x2528d37bed306b44,
+                    0x9bfcb31d75669fef,
+                    0x014a206837814bb4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x46807883ffc560df,
+                    0x35f1789c2c0210b3,
+                    0x7bd364b5be1d5fad,
+                    0x1386de04762e8d21,
+                    0x7cd0880f459f568b,
+                    0x01abfa78e3e0d243,
+                ])),
+                Felt::new(BigInteger384([
+                    0x64e6c43650d4542a,
+                    0xc6df188b0ce1c66d,
+                    0xe04099ee59426d1c,
+                    0x737f8c547f8f7bf7,
+                    0xb0ae412f8addac9a,
+                    0x0021d006d67c4d37,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31c32763c77bb485,
+                    0x1c29c29e7d4f091f,
+                    0x4dbc5a55f95ac51d,
+                    0xd5e36f48022e4fc5,
+                    0xdbeedc306d3516ba,
+                    0x009ba4ba0ef4bb48,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x5ec5eb16d1de215d,
+                    0x40dfc234109ca6be,
+                    0xfc5063839392be2f,
+                    0xe1554cb63f1722df,
+                    0xccfb757af844d476,
+                    0x019233e478487114,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27640d2230b077bf,
+                    0x45beffcd74bdbf76,
+                    0x22e40573dfbd9e05,
+                    0xa56cd9c2292ba547,
+                    0xd4d5a9665b9fd79e,
+                    0x0142dfead8bc695a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77aae46715259ded,
+                    0x82d7f75fc43b5eac,
+                    0xf8f6b626d03de8ca,
+                    0xe36bb4040ed9da12,
+                    0xcd71526c7770490b,
+                    0x0006eb773d5fe99b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba90b0ae59571765,
+                    0xd0cfb3f483d08c04,
+                    0x365d20bbf4760ecb,
+                    0x2681a549210afb9e,
+                    0x944968ac66aed2c4,
+                    0x0123b0cdcc88f1cd,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xdbba2bc2e2bf58be,
+                    0xdb9033b1d9545d08,
+                    0x429fb44bfeacc5f4,
+                    0x82b557f5f56a31b1,
+                    0x21304b5508914f13,
+                    0x0009c9a13ee3886a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xece6633dd25a52e5,
+                    0xbe242ad12e61a6eb,
+                    0x6c5bab238494a645,
+                    0xdd4fe560cf3e6894,
+                    0x7a0346ec08913bf5,
+                    0x018d6461c9b073ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe500a46e88a87baa,
+                    0x324adcfc229471c3,
+                    0x1f88c0b923f9e315,
+                    0x6e4c3436f5395e49,
+                    0xa0e06810e0b4890d,
+                    0x00c3875310b8275c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x50a22c63f3767452,
+                    0x78871ba197ded16e,
+                    0x1c935fc15b2b1fbf,
+                    0x26dc135d94705383,
+                    0xa93e1e9b35c7034c,
+                    0x0093e26263980843,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd9bb5f6086073a76,
+                    0xa021a8522291eef9,
+                    0x76d8cc07fbb95011,
+                    0x44f0ac7ae84cec0f,
+                    0x64d759e607728946,
+                    0x011b120772accc0d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xed719729e428f2f0,
+                    0x16fc9adf2118d1c8,
+                    0x5fd6efccd2d1420d,
+                    0x67e4d4cccf4bf5aa,
+                    0x190e6710cdaf0921,
+                    0x01188c02fdc52caf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf3bde57574e0ef12,
+                    0x1e24e27e21fd6d67,
+                    0xdfbd8fee38a21d69,
+                    0xc3260be6897f0e1b,
+                    0xfa3d55266253af1c,
+                    0x01ad58c29b2e4903,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f62eb93cfc088f8,
+                    0x460731328064a733,
+                    0x562a7468bcd34ef6,
+                    0xc6aee48c8d1bd186,
+                    0x130d34405da6cc8f,
+                    0x00fe9a805d0f071d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6b5a234cabd5eaf4,
+                    0xf4b0f1f6f029173b,
+                    0x9c433bc3dc13dd1c,
+                    0xca57d57e3884cdc0,
+                    0x9b7f077d4476f469,
+                    0x00525f554eabe5da,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4f1e09b670f924d8,
+                    0x1d5f001c2c7078b8,
+                    0xeb3e37d132b44517,
+                    0x623a77b47a2ce3c7,
+                    0x56056e01bab82c2f,
+                    0x007adb5328b84024,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbb1f088ba42edb17,
+                    0xdace32544d2a0efc,
+                    0x10ee87f5093a0174,
+                    0x63232499dddae8bb,
+                    0x342a11bb8a57728a,
+                    0x00dbcf5181fceaea,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x59693bb24019671e,
+                    0x168eaad07d9c6acc,
+                    0x1514ae72d421cbde,
+                    0x0518bf5fe317884a,
+                    0x30fa83d17abd22bf,
+                    0x001f71e0facd229d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeab00bafd2d763d2,
+                    0xc8ba0ddedfbf718c,
+                    0x2632ce1f3507a803,
+                    0x5edde0a9cf127312,
+                    0x2b8283ea96ab41a9,
+                    0x007b6c701880a8ed,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x67b68410f69a998a,
+                    0x81afeb3f03b66f26,
+                    0xf0158ca0f9a1ac61,
+                    0xb73a743591a122f1,
+                    0x15aad84fa8e97eb5,
+                    0x0163e9fe7d0be943,
+                ])),
+                Felt::new(BigInteger384([
+                    0x082df3fef207df06,
+                    0xbc7df4eb307e6484,
+                    0xd205c477b675fe93,
+                    0xef20e7a3295eceae,
+                    0x57ed8e51dda3c80e,
+                    0x0005f3a1c82548a0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x807b1666ef7ff717,
+                    0xf303f5c2d76abfc6,
+                    0xd69672e79f6badf2,
+                    0xa698413038b730aa,
+                    0xb806d8a6008bb132,
+                    0x0167864fdbdfc699,
+                ])),
+                Felt::new(BigInteger384([
+                    0x830aafdd038988e4,
+                    0x8e81337fd553497f,
+                    0xed3742ed2751213a,
+                    0xe447127f7c080577,
+                    0x081faa8b28eb72f5,
+                    0x002c5020f88b25c5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x15bfd4d77370124e,
+                    0xf504d18983e27a8a,
+                    0x6d66525e464eb51b,
+                    0x9a2f5fcb82547008,
+                    0xfc182fbb880134c8,
+                    0x000da362a695408d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x87d753636a91d2b2,
+                    0x6303031f685b3f59,
+                    0xa6bc2f0d9ac198a2,
+                    0x775ccc74628e79aa,
+                    0x5c8afd54c81c786c,
+                    0x00e01a3633c74ec9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1986751851b08781,
+                    0xe90149d82d1070bc,
+                    0x9f997eab7065aafd,
+                    0xeb4b88b70c897b1b,
+                    0x0cd15d066b5ade04,
+                    0x00ff7fc434ad525e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4afd2cda7829771e,
+                    0x20504572e094bb9d,
+                    0x0970c7e1b0633689,
+                    0xdb03574b042e9e0f,
+                    0xdcda650df6d3c39e,
+                    0x00c56afcf6933302,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7b527fa6741a4c9e,
+                    0x2486a20422023ced,
+                    0x471ac4138ea6a89b,
+                    0x29557c34bdd025f6,
+                    0x18de5e8c89d42ea4,
+                    0x009322a8054fef76,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7d02624b958705b9,
+                    0x54b7291dc4005539,
+                    0x77867a952459271f,
+                    0x5e74c82e594cb88d,
+                    0xf76496443e0be3da,
+                    0x009a82a2262905af,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4c8092a4db35a9ce,
+                    0x49012ceefa0fb1a5,
+                    0xc2df11da23d8cf35,
+                    0x98d15bfb88b3b14f,
+                    0x00a1946e1d14dd59,
+                    0x01604a7747c79a25,
+                ])),
+                Felt::new(BigInteger384([
+                    0x46be4ad6b43bd5ad,
+                    0x86be84f017ffef0d,
+                    0x6f60d10058e1f4d0,
+                    0xeed06403102d0f61,
+                    0x8fcb56f89e4e315e,
+                    0x014df96a2fe4f4e2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1878819832035d05,
+                    0x5865f428dc640f78,
+                    0x2f72a4397615f756,
+                    0x9d1e94caac48d297,
+                    0x5cc11e03cb71aa32,
+                    0x004e0ad4b9e742e8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56be9211f5512f51,
+                    0xeb70f402101ddca0,
+                    0xe518066fce8cb3e5,
+                    0x716686dc487b86c1,
+                    0xa267d96fef46f73f,
+                    0x016ea06969822edb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x45c7c5a1095f78c0,
+                    0x584dbd1afaaae672,
+                    0xf1f8d64ff2be16ae,
+                    0xdf808c2cc49de87a,
+                    0x765d1973f24240be,
+                    0x002e3baa2e64844b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e62f13d88e1e452,
+                    0x3383bde94fd43f45,
+                    0x031f100a31e25d4c,
+                    0x45ddee9b571ebb2d,
+                    0x4f93fec31a9726c5,
+                    0x01134b918c60aaf6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9b207b80831c3ba5,
+                    0xab2c7fb2aff390eb,
+                    0x44b45fb730a9ba9a,
+                    0x0e2c5864b4dc343b,
+                    0x14f03a903f634507,
+                    0x01a88d7e1990383c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x366e174da9883c0b,
+                    0x7c093af6a7992ae1,
+                    0x1753682440f40754,
+                    0xa71127d88572f790,
+                    0xca3a19e231baecb7,
+                    0x00e1ce037d03dc94,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x59693bb24019671e,
+                    0x168eaad07d9c6acc,
+                    0x1514ae72d421cbde,
+                    0x0518bf5fe317884a,
+                    0x30fa83d17abd22bf,
+                    0x001f71e0facd229d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeab00bafd2d763d2,
+                    0xc8ba0ddedfbf718c,
+                    0x2632ce1f3507a803,
+                    0x5edde0a9cf127312,
+                    0x2b8283ea96ab41a9,
+                    0x007b6c701880a8ed,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x67b68410f69a998a,
+                    0x81afeb3f03b66f26,
+                    0xf0158ca0f9a1ac61,
+                    0xb73a743591a122f1,
+                    0x15aad84fa8e97eb5,
+                    0x0163e9fe7d0be943,
+                ])),
+                Felt::new(BigInteger384([
+                    0x082df3fef207df06,
+                    0xbc7df4eb307e6484,
+                    0xd205c477b675fe93,
+                    0xef20e7a3295eceae,
+                    0x57ed8e51dda3c80e,
+                    0x0005f3a1c82548a0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x807b1666ef7ff717,
+                    0xf303f5c2d76abfc6,
+                    0xd69672e79f6badf2,
+                    0xa698413038b730aa,
+                    0xb806d8a6008bb132,
+                    0x0167864fdbdfc699,
+                ])),
+                Felt::new(BigInteger384([
+                    0x830aafdd038988e4,
+                    0x8e81337fd553497f,
+                    0xed3742ed2751213a,
+                    0xe447127f7c080577,
+                    0x081faa8b28eb72f5,
+                    0x002c5020f88b25c5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x15bfd4d77370124e,
+                    0xf504d18983e27a8a,
+                    0x6d66525e464eb51b,
+                    0x9a2f5fcb82547008,
+                    0xfc182fbb880134c8,
+                    0x000da362a695408d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x87d753636a91d2b2,
+                    0x6303031f685b3f59,
+                    0xa6bc2f0d9ac198a2,
+                    0x775ccc74628e79aa,
+                    0x5c8afd54c81c786c,
+                    0x00e01a3633c74ec9,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 282];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+            bytes[188..235].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..47]);
+            bytes[235..282].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x6c50dfe7bee62c77,
+                    0x3f5f19250e9267fb,
+                    0xc8a05a0f34e6a926,
+                    0xe647c956db46f8a1,
+                    0xf227ae22ddda541e,
+                    0x0109fb73aea526a6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x07e8224f569200dc,
+                    0x512bf11d8e0141c7,
+                    0xa0ef98d0b8d3742f,
+                    0x79f6fa35b85a1a73,
+                    0x5a6902f6ef24745b,
+                    0x0148ea286ec52f0f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44908c8bc6b819ef,
+                    0x81b9b28c28de548b,
+                    0x9fa4743524ece4bc,
+                    0x3b70fc506f2724c6,
+                    0x54c876d17f8fcce7,
+                    0x00d3d112495d1692,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb4f1f8e2520d6af8,
+                    0x25ae634f8bb2c4f9,
+                    0x3c9ba39d72815c7b,
+                    0xaa378667ba7a3569,
+                    0x29fdb0cd21c8f05e,
+                    0x01aad232d45ac023,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2a0d6082c8e9874,
+                    0xe9f683eeb6d09945,
+                    0x77c18a2de074d0c4,
+                    0xf581b2a5f1b65826,
+                    0xbcc198ac64924fa9,
+                    0x00c1403ad2a21f0d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd93de8e1052f8bbe,
+                    0xa3d62122d82c3ddf,
+                    0x1a162166612c663e,
+                    0x23288eb1b8a20e1b,
+                    0x3716582592dba1bc,
+                    0x0171180a821faf3a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xbfaab23b524a8ee4,
+                    0x7cf003da08954017,
+                    0x2938fbdd344742e9,
+                    0xcca1fb74eac22799,
+                    0xc06c39a147c76ecd,
+                    0x00875fa3dd4b4c7c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x86963febfb8ec985,
+                    0x2517ee8b60a911ff,
+                    0xfcd212b8bc9b72b2,
+                    0x37d234154a4365d2,
+                    0xaa8eae9458d3ad37,
+                    0x00a21da7d6f21993,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9efddb48dd19dc57,
+                    0xfdfc2c74c6e89b26,
+                    0x7ab53199ef880c1a,
+                    0xb3673f8b6c8bf60c,
+                    0x93c148b0407bd92e,
+                    0x00e47b7f8bd42adc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xad5da3206b85148c,
+                    0x673f47b123f83369,
+                    0x89afd5f4d4a9a3e0,
+                    0xd3f1247afdba0f96,
+                    0xa18157b89baeb80b,
+                    0x0097b14c29adde91,
+                ])),
+                Felt::new(BigInteger384([
+                    0x645b0d81d2a5f61e,
+                    0xb154867d0d7de460,
+                    0x3dd635bcecbee04e,
+                    0xd9a7b5c39e47263d,
+                    0x89dec3f19858a8c6,
+                    0x01886a8358a48b69,
+                ])),
+                Felt::new(BigInteger384([
+                    0x87fc8e3be276aa08,
+                    0xaf8b803203d21889,
+                    0x8c047dbd61398285,
+                    0x278dfe325215c8c0,
+                    0xa3d0c769213da6c4,
+                    0x00636210cb9cc666,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x94aae3d01fa070b2,
+                    0xf3f3176485335cc9,
+                    0xa5e79fec430f20ab,
+                    0x7520d7e480800c2a,
+                    0x8995a40481669cfe,
+                    0x012af835ad15bcdd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc5ece4434d0a4e9f,
+                    0x3fde3642edab72c6,
+                    0x01839453a789d595,
+                    0x5aba8caef9733697,
+                    0xa1d0b812a0ad7704,
+                    0x014f417a6b0f22b1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a1720ac7831a2de,
+                    0x2b008d85bf2c1976,
+                    0x333941bace645533,
+                    0x345cc5c88451a661,
+                    0x6ef30915f130fa1a,
+                    0x015f96112b9a8a10,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5924a86bfa2f8f20,
+                    0x77663a4085675bfa,
+                    0xac9fce1b63cf2e32,
+                    0x635a8e0fd34e2b61,
+                    0x995750c7346fa6e5,
+                    0x014e6104be5a2e32,
+                ])),
+                Felt::new(BigInteger384([
+                    0x29015cc804849920,
+                    0x073b63e758170a07,
+                    0x2db8b8e20e225323,
+                    0xbc3cb9fe5353c465,
+                    0x2b0b9cf3c84b3725,
+                    0x0100588d3208958a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf319f8c68c491e4d,
+                    0x76818bbc64c3f7c5,
+                    0x86d51b37eebd8b65,
+                    0xb58e6828e633928e,
+                    0x2eb5a17017ed40ba,
+                    0x017e5ee5df408d95,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x6dc7f7c1535b9e05,
+                    0x93c59655ebe9af4a,
+                    0x5970f9fb4d24351a,
+                    0xc63bce7f3d8c9e4f,
+                    0x5da0d004b785c9e8,
+                    0x0037356c6832b647,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7a4521e8ef43ff70,
+                    0xb4a0998107af6e93,
+                    0x1c4ce03603c697fd,
+                    0x6f12aaa3160318a2,
+                    0x23b7404f47598786,
+                    0x01525a8a3e8ead91,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56d307cc1eb51c7b,
+                    0xd1acd47d80cbdff2,
+                    0x23be7b6959aaf7bd,
+                    0x4542ce193c3d81df,
+                    0xdbe4624bc16b8757,
+                    0x011b3f014f55d8e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd66a21a1996a68d3,
+                    0xab476a52995bdf23,
+                    0xce5d96d7803f3e18,
+                    0x921fcf348b58187d,
+                    0x24318b55783c64c0,
+                    0x01a2fc58b9a386b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d99779db2d94ad2,
+                    0x979950ad5114ef8d,
+                    0x2bc0eec7e6dd60fe,
+                    0x02640e45a9d08e2d,
+                    0x0da095f184d6b95b,
+                    0x007d4135248d572d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0b3478b072aaa81c,
+                    0x0053973fe88f9afc,
+                    0xe2b9b1b98c347ae2,
+                    0xc46cfbdc8db9a5fc,
+                    0xad117ab532f33d2d,
+                    0x007e0b95c15cec01,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x9d7c49e5d98d3eef,
+                    0x62a7dabee3725bad,
+                    0xe56c06be81d99456,
+                    0x96ed112156bb577e,
+                    0x7e0bab4123a1eca6,
+                    0x00829af03c1e419c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x15bf1feb3d32a4a9,
+                    0x6e438434f4f22fb2,
+                    0xe54168f94c9acfab,
+                    0xa5cb689963afdce5,
+                    0x1b419284a250081b,
+                    0x017416c93757a19a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0bad8216a338730,
+                    0x484c5109f87d5fbe,
+                    0xfd3785ae0d42dc1c,
+                    0xdda773902ccff106,
+                    0x764eede42099b826,
+                    0x00bae39dd2599b2f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe191482a48151808,
+                    0xfb96e15ca5da3a39,
+                    0xee69a00de8b95965,
+                    0x18fcf90f7a3ad243,
+                    0x4cbb087fff6490a9,
+                    0x00b788bf30488fe9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee1ad0c5b2eabf80,
+                    0x643cde6f9afc1b8b,
+                    0x75c874ea63940193,
+                    0xba996fef552e66af,
+                    0x4a45dec29a9a689c,
+                    0x01560ab6d9c12c88,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe11bdd11831fca60,
+                    0xae333863e62e36c9,
+                    0x26b1157763fcd40e,
+                    0x5c5febd61dba5a63,
+                    0xf3e32ac4b8163ebe,
+                    0x00db5ee92675b099,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x5ca5c3cbeb5ac80c,
+                    0x16ccfaf9901bd6f4,
+                    0x97c21b7ed70875b6,
+                    0xf5c87fe9b48f5ec8,
+                    0x665b7735bd2cb3b1,
+                    0x00b54a202b3f469d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf921c6e35e5e8928,
+                    0x9a2442f855fc5740,
+                    0xb4f79a9df7ac8479,
+                    0x5bdd8828775d57dc,
+                    0x7a5718484a80814e,
+                    0x0112cbfe999bd929,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8fb9319be5c6bd5b,
+                    0xfe5790a2d70ec745,
+                    0x90a7da9010523572,
+                    0x5b5bf24eb94c5485,
+                    0xc4069d08a01fc418,
+                    0x00aa7d5f02cf9ab0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa59bb7011f5da02d,
+                    0x99fb5c4ec8993982,
+                    0x3ba91fc21e5db373,
+                    0xc1addaa12685d355,
+                    0x00ff8eb4f9d38db7,
+                    0x0010277ceef5bb7d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb2ff02c7a81728b0,
+                    0x94ed79091c6f26ac,
+                    0x1c310e8112d11051,
+                    0x9c2baee267cef861,
+                    0xac73a7b7b1f774c0,
+                    0x00d202ca5d51e044,
+                ])),
+                Felt::new(BigInteger384([
+                    0x246ef97c23e89e08,
+                    0x471b69c5a258a9ea,
+                    0xb2274d01a6441c38,
+                    0x750e90867dc1939a,
+                    0xca6b9b1d6febadf1,
+                    0x001acd20aee60e0c,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xa5eb12529dfa7c8d,
+                    0x863de08114ed1ae6,
+                    0x0590d9952760f9cd,
+                    0x6b15c6d336ad4e73,
+                    0xeea585c31e1e9a71,
+                    0x005ba6fb3f16c821,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a31c0f5e2376efd,
+                    0x44009a0481b7cb11,
+                    0x75e329479f2613ea,
+                    0xb06b5949a345d73c,
+                    0x316e5b9781b04764,
+                    0x0153db1f42d88a0a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd31ea55914ed9024,
+                    0x69718dcab135ebf6,
+                    0x7186f17bf621276f,
+                    0xa9737d0e4b271d4d,
+                    0x29d4c039fc8c0788,
+                    0x01532d6ada2c1ae1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd6ce07c470d5b9e4,
+                    0xffc5a5f4cef48625,
+                    0xa66e7e6797656966,
+                    0x027b2152228972d0,
+                    0x7a1ef32d0e90b372,
+                    0x00d2c8267031a465,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbe16680d0a79b386,
+                    0xfc8b59458ed88dfa,
+                    0x80ba0cbf32044ccb,
+                    0x071c5483b570b033,
+                    0xee964da0e6382b5b,
+                    0x0064f3e2f5b7ff65,
+                ])),
+                Felt::new(BigInteger384([
+                    0x91f532e0127dbead,
+                    0xa1f9e13df58cf067,
+                    0x6a3d9d61613d30e4,
+                    0xd6fdf4f96f6f3022,
+                    0x2152f3f8fb10356f,
+                    0x004ec785bd8217b5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6e75927ade670729,
+                    0xbd6995854d6fc47a,
+                    0xe0ab1634fc507094,
+                    0x4e800754a97b6868,
+                    0x77c784d2f7248e87,
+                    0x0193279809a085b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe5872294aaeede86,
+                    0xd1e0021b36ef587b,
+                    0x9ae024d2f7d459a9,
+                    0x21fc2c64f78c11fb,
+                    0xee975de56f2085dc,
+                    0x00e9b7a21611059a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x11fe7f39cbb1c472,
+                    0xc2b83553a23e6982,
+                    0x63dc3b8ff69c5af6,
+                    0xaa8a3bc01a663793,
+                    0xc91b6aa7785068d2,
+                    0x01965709aeea0ab2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd6b73b77c9d2d0c0,
+                    0xa6ff0f470727ca2d,
+                    0x24d297a72dca5a0b,
+                    0x0b0e7d9598135500,
+                    0xfe5dff68cc19c9b8,
+                    0x0041a3bb8368540e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf8e680b1bb5978f6,
+                    0x4c6d372c6a355a68,
+                    0xacf11ce0be6467de,
+                    0x6a28858ac0c91cbb,
+                    0x1c4d258cab81a5cd,
+                    0x00e5f5ffe5ebc161,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf8493d40297f58e1,
+                    0xb67ec0f9a5ab59f0,
+                    0xb042d7974f51d90b,
+                    0x63b19e544bc9f97e,
+                    0xd6c0a4bc85ac1fa7,
+                    0x01216de2442ea94d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x32e92889da41ff0f,
+                    0xda91d40e5197e358,
+                    0x69de6cc8bc7d71fd,
+                    0xc05e1508c457d158,
+                    0x617265d3e9f8b493,
+                    0x01054ef62ffc6553,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7d2f5d47353bb667,
+                    0x04a7a289857718bb,
+                    0xd1f38a7c560e75d1,
+                    0x2547618553df0c07,
+                    0xd0decb1fcfe8677e,
+                    0x0097d1f9e8044877,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59ba9cf5d1d724b5,
+                    0x436c8b1a4f740099,
+                    0xff2369ebdb94c7bc,
+                    0x4317e626c7b99109,
+                    0xb7820c6d3855f2ac,
+                    0x00868cd2701774b2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe599c3a75a72eb75,
+                    0x149ef991b3caaab6,
+                    0x1eb239936477c35f,
+                    0x68a92cb001f6c8a1,
+                    0xc00368cae2d11bcd,
+                    0x0144592f148fade0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x982d399a7c8ef2a9,
+                    0x19372c8ec851a080,
+                    0x660491f981f50ae3,
+                    0x4db11579c0f382e1,
+                    0x66441b7cdc2666c4,
+                    0x01877e88f8100feb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f31c567b7d915c7,
+                    0x946e486ca605a149,
+                    0x9d60fde17b3ef4f2,
+                    0xecfa079845ce2baa,
+                    0x21e2abc0551d6162,
+                    0x0043c92c6fd978f4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2195605821030fe0,
+                    0xd4d300f0d386fe93,
+                    0x6bbcfb3da69113e4,
+                    0x5e5d7b58ea79be59,
+                    0x39295787cfa61217,
+                    0x014019750de3e8dd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd94ee7e150d914ae,
+                    0x02d8b727c7396a4b,
+                    0x5a7c6012e01f94c6,
+                    0xdc9e5af8b564fcd3,
+                    0xbb7df21e2339f19a,
+                    0x00e84cfb438da592,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe6dd5117e8fc721d,
+                    0xcb1721c930f9f57c,
+                    0x2c86cdf8469a015b,
+                    0xb1fcf6f737d29a08,
+                    0x1141476d1ab85cef,
+                    0x002563daf709df45,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x24d6a85438cd5f5d,
+                    0xc4fb166937775763,
+                    0x00e7d830b48ce07e,
+                    0xd0727b3e218e64fe,
+                    0xcfb0ff382310d4b1,
+                    0x00bc6b8b580e771b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ac90d1f6951a410,
+                    0xd791f3418b699612,
+                    0x7b370aca05e19fe9,
+                    0x70d1a2de9cee3d6e,
+                    0x79d7e0b5290c1dc5,
+                    0x0156e827babe5b06,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbc933e1b5ec92f60,
+                    0xb2472b9431074329,
+                    0x320bba6efcfba06a,
+                    0xc25eca347cf905c9,
+                    0xaa1f20cefb2a654d,
+                    0x00efb430ce66f822,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xdd2d70527b807d87,
+                    0x60a0787591547ce6,
+                    0xdb00da16ed6c6f32,
+                    0xf13ee6fec0f6dbd0,
+                    0x9bce342d3982197a,
+                    0x019aa1257307c7d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ebabee88fd79e8d,
+                    0x447e76686ddac9b5,
+                    0x43afc108257bdb92,
+                    0x4a2d16e4784400d3,
+                    0x8463cb9a128c4379,
+                    0x016e9e36af3fc00a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x136500c00b51e6ab,
+                    0x16ab8074f774db31,
+                    0xa02217aaca36b2b0,
+                    0xbc78cc95f78e569e,
+                    0xae7ee6b60ca5b8ce,
+                    0x0131f6b643c54c94,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb8d9df7f55087030,
+                    0xf95455c8195d6894,
+                    0xbc7644b204382f9c,
+                    0x9b5d79f7f7cc3d2e,
+                    0x31b84a0713e0cc90,
+                    0x00a657725926ec7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2e898caad3225025,
+                    0x5b8c44e6d931d2e1,
+                    0xef7d69ddc77de75e,
+                    0x6d7a6bf2bd05d50a,
+                    0xae746876a5363831,
+                    0x005e4435d72b0a5a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c2336d064323a41,
+                    0x80767aa5ad7ab35c,
+                    0x1e53f6fd38a471c5,
+                    0x100914ba0f4643eb,
+                    0x5c9aed17084c9386,
+                    0x0010c3efdb8ed2e6,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xdda8039392f24abc,
+                    0x029e68d60b286cab,
+                    0x496e1df6795b0fba,
+                    0xa3038617a24a194f,
+                    0xab2fa98683a3bccf,
+                    0x01617d32f11433d2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52b7a10c2878d533,
+                    0x41f88db4f50b971a,
+                    0xd5d723faffcde323,
+                    0x1106ac828c99618a,
+                    0x7b8ce640a35cddf3,
+                    0x010d809baf06d4c2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea10ec5a49f93e73,
+                    0x86bb71f522553a0e,
+                    0x9f8058830515fcfa,
+                    0xa00a7e4e25c56d61,
+                    0x0d217af28bee83a3,
+                    0x00aba2509afdfd54,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5baecfb3a7d014a4,
+                    0x900136bc0c012e7c,
+                    0x958098493bf33e40,
+                    0x450f94bd003b2c90,
+                    0x8ff7109aa11e7065,
+                    0x012b5b552c808edb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x656c39f35136eda3,
+                    0x99f97ff43134c584,
+                    0x9d50b2ba993fce81,
+                    0xf28c117126f0a013,
+                    0x4cbcd214e5fafe88,
+                    0x003f3ef660b06452,
+                ])),
+                Felt::new(BigInteger384([
+                    0x82bf938604b0f2e4,
+                    0xb524473926a5abf7,
+                    0x3a40632a8c188e7a,
+                    0x4a7945dca241832b,
+                    0x9d86dd8a68bc8799,
+                    0x001dbe50fbd58354,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xc190fc5b9861773a,
+                    0xfd3e4562a73f505c,
+                    0x608d09114e697d05,
+                    0x080d07986a0ac85a,
+                    0xafff0ff4e795c4ac,
+                    0x00337884d98a90da,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e27d077cad88029,
+                    0x818ce627ed8d9610,
+                    0xb17490553ca19810,
+                    0x2b65e30122649d4f,
+                    0x2d254308588211fc,
+                    0x00dcfa0f25b83ca7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x09c6fcfa22841c25,
+                    0xd073386d06dd9eef,
+                    0x3ba6160647fedeb4,
+                    0xd54fb2a6c1672c57,
+                    0x4c573b9394b4b004,
+                    0x0033ad72bbb29fea,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc040544cdffa91da,
+                    0x21d938efbdaf774a,
+                    0x4d87e23a891211d4,
+                    0x3406fde3e95d658c,
+                    0x923eaba0f9e36f9a,
+                    0x00a165bdfd0823bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f2eb0f2b9462edf,
+                    0x86ac0dc8ab09e93e,
+                    0xc6f84a77d60a24f4,
+                    0xa77803582b2eacf1,
+                    0xa1fc359ae9e144ee,
+                    0x007c4b61c80edcf3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a84aa5c03c59a37,
+                    0x2c04c6c501255a65,
+                    0xfb712fb2d584910c,
+                    0x251af82fe7466c88,
+                    0x345fb402654f1d1d,
+                    0x00282a1678b0e27e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x39c8ea6482c2f376,
+                    0x5efb962684f224f5,
+                    0x1647882eab82fd8e,
+                    0x1cfc61f5ddcb8401,
+                    0xe3c70dfd9fae7fb5,
+                    0x015051c37b5d742e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17402d8a1dffdb67,
+                    0xd9021d3aa202c740,
+                    0x34904b4bcb503acd,
+                    0xdaa1a0127f1294b8,
+                    0x6123eb136090fc45,
+                    0x00f360c98129ee42,
+                ])),
+                Felt::new(BigInteger384([
+                    0x101615ecdb1d6e50,
+                    0x173503dfa8cadd5e,
+                    0x2a8c8ffee0dca82f,
+                    0x8cdd6095207cff10,
+                    0x53e501079ddff92a,
+                    0x011dff879b17c1ce,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa943e89b7e41cf1b,
+                    0xeabac399f686fdf0,
+                    0x7953938db97b11d1,
+                    0xb7b3763857239c66,
+                    0xa7aec53a58dda6a6,
+                    0x0068cef09d523963,
+                ])),
+                Felt::new(BigInteger384([
+                    0x587deeb53cd0c172,
+                    0x50eac92dab9ce293,
+                    0x00f3581a235a44f2,
+                    0x0e70f738a5fb2d67,
+                    0x04919d60a7f9e067,
+                    0x00e733c8639f5aa1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd835aee450a2ecf5,
+                    0xd6e60bf1b38cf4c5,
+                    0x6df6ff0909b2fd4e,
+                    0xa6225dee8d3658a2,
+                    0xcf587cd9e183689d,
+                    0x019cd7ed7ffb77b2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x33cbc0a2d2f742c7,
+                    0x34fae48324744511,
+                    0x5201cb6e77ea6cb1,
+                    0xface9c4354907aac,
+                    0x09e6c2fdedf35685,
+                    0x004f9e76dc0cc22a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb0b8b134c453ed38,
+                    0x6308eeaa0871134b,
+                    0x366e5f6a93277d1a,
+                    0xe5af660bab597ad4,
+                    0x44c1f39d1dbffd7e,
+                    0x008123da365208e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x63eb650b02ca0fe2,
+                    0x509aab14de78c8d7,
+                    0x51691612be248262,
+                    0x2f9316abdb068ca0,
+                    0x5d3101673609d671,
+                    0x017422219c2e5be2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc692906d56876254,
+                    0x44d2527f96720832,
+                    0x0cf377c57034b6a6,
+                    0x189b31963424b170,
+                    0x6bbdf40b6fb03e98,
+                    0x00dcfdd76747c78d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x832688879e14c9ae,
+                    0xd4b22cc97460542b,
+                    0x06d6b0055a5eddac,
+                    0x1bb92ab32bb5ce93,
+                    0xf442460e0d6d5227,
+                    0x009ca60b02dc55e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xede1f7a603c2b5e2,
+                    0xe00b4638470a20cd,
+                    0x1beeb5e08d886abe,
+                    0x0744f6d8c832b3d4,
+                    0x7b3aa314dce917f9,
+                    0x003a90ec94baa2cd,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x7da6167cb9f9e10a,
+                    0xccc1a3e80691d77d,
+                    0xe8e857b4894e2e53,
+                    0x8ac8c99ac596620b,
+                    0x9b888729725a2ed7,
+                    0x0128f6080fca975c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x07138795c44b7938,
+                    0x71c8ec46499d8282,
+                    0x05a9f262abb689cc,
+                    0x0ffe88b89d0321db,
+                    0x6bc4f1b439466a3b,
+                    0x01803cbc9dd1cba6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcfad8d52d8ec054a,
+                    0x3dd4f59ebcbc44a3,
+                    0x25db1fab1ea191bc,
+                    0x3f8bf9cea4aa28a3,
+                    0x0238bd1114273c65,
+                    0x006874c118213f8d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1fef41c4c401681f,
+                    0x92c0ff0b1860be00,
+                    0xd74307a7e678619d,
+                    0x0ba95a0f5b1e7b1c,
+                    0xa48ec21253f9888d,
+                    0x00c05d4bf2d89a6b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc6965a3bc687d4f5,
+                    0x534c9fbe1a481e51,
+                    0xabd1d074d5c2de53,
+                    0xb57614f7fcc69b61,
+                    0xd298b2ab44663b4b,
+                    0x0050adbd4de568f7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6c782205428e5204,
+                    0x2d7813b3eaf4bc2d,
+                    0xeb8400a601431562,
+                    0x5bd0ca33574c59d8,
+                    0x8c209e8fce996fd9,
+                    0x0184395fae7c5b86,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x5b962c575b93f8a2,
+                    0x2abb32dc26ec647b,
+                    0x2124102214ef76cc,
+                    0x5099d60c38a3c25c,
+                    0xc4fb1c6471387067,
+                    0x00f435ad6bd91f17,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb3661287b2176ed4,
+                    0x70a31d3c7c493fa6,
+                    0x42c7314238f54513,
+                    0xde8d8605f2a8ad16,
+                    0x04286fd176f0a6c4,
+                    0x01505481488fa73b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe608df5dcbb8f9ed,
+                    0x61dfc6e2a4d276bb,
+                    0xb14fa972eba48ef6,
+                    0xb0fbd0250881067f,
+                    0x2733b88ea942cd0b,
+                    0x0048c72520ff0f80,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b0d37813e0ccbd8,
+                    0x176c62a5ffedd3e0,
+                    0xb5635cdf65033f19,
+                    0x9e45511e2042fce8,
+                    0x3c7ce356b1f56628,
+                    0x0068d54e5b473b41,
+                ])),
+                Felt::new(BigInteger384([
+                    0x83ee65cfdc188867,
+                    0x0aad86347767f544,
+                    0x0cb144ce844f2680,
+                    0x505b409618ab62d7,
+                    0xa481c2b46b8817b3,
+                    0x00ce26275fc7f4e7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45a1e204833a2466,
+                    0x6b6404c103f531c8,
+                    0x70c3569dc21582e0,
+                    0xc6c73d67cc8b20ae,
+                    0xb212beb2825641d8,
+                    0x0089c5fa1f8f3e3a,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger384([
+                0x4e32b8a1951f7bad,
+                0x1ca4ab0c17dad1ee,
+                0xce079229029eed27,
+                0xaad1c33824252f6d,
+                0x83ad9bd42fb9a023,
+                0x0154753f44565c22,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x26d9a2b18dcd2c17,
+                0x9e4ae078535a0488,
+                0x916628882aa6e717,
+                0xe0956acf47695326,
+                0x8a0834c6efd9143c,
+                0x0186838f236bbb80,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x5be9b4495507aa1f,
+                0x23eb126bc69d8677,
+                0xa180b23876ae9535,
+                0xe6c0bb93b9838ad9,
+                0xa30441df0552eabf,
+                0x00b6c1b79f11742e,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x42de3969aeaba296,
+                0x92dfaa28e7087e87,
+                0x631329ef817752f5,
+                0xbec5c781a3b157ab,
+                0x2b30c3f190a645f1,
+                0x009acd5795bdadd3,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x659e2f4805c884f6,
+                0x1d3d64b5b634c59e,
+                0xe873b4f46268c196,
+                0x68a34e025b407cc5,
+                0x1a2a982550e4cfa4,
+                0x016d8ca056245644,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x3e0b0fd94cceee59,
+                0xf3113fa88e6cb050,
+                0x16ac36be55c6b136,
+                0x5ab355303eb2e866,
+                0x613cd1617dab91cf,
+                0x005f2652d8608e59,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xf307bf67f3e5447c,
+                0x0adde9643eb11e1b,
+                0xc18fa2b1065dde20,
+                0x32801a7140cac185,
+                0x44d5c7bbc3eaaaa5,
+                0x01a1f377f645edb0,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xab75a910da35e254,
+                0x27e0ae0b1a73b0f4,
+                0x54e7ef894b5ac5c7,
+                0xc336a844923c9c8e,
+                0x6aea53af2e30a48a,
+                0x00ee1babb4c9fdbc,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xf1cf2e5e50e40b06,
+                0x027acaaa10dbe1ec,
+                0xf9ebbd1edd904846,
+                0xe07ba5da6cb8ab05,
+                0x8baec9414709259e,
+                0x004f955f1bce5588,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xf67e6ceb00e4d9f4,
+                0x881b9333edc61064,
+                0x9d6c78b1fce8d145,
+                0xa9d7da604127ab87,
+                0xcdafebdf755633a4,
+                0x0049ffbb334e0f2a,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 188, 189, 569] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 188];
+        let mut opad = vec![0x5cu8; 188];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,1806 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 288 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 4 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 4;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 17 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 17;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0ea7da2336d14392,
+                    0x7d4ec7f3fb47aa46,
+                    0xe98e6f0aab99bfec,
+                    0xa7e244a0b7d2cc43,
+                    0xebfcac69f9dc54e9,
+                    0x016ac14d712bccae,
+                ])),
+                Felt::new(BigInteger384([
+                    0x78c4b3edafcc386a,
+                    0x318a74365c182a50,
+                    0xa1376f9367d87d66,
+                    0xe9ec35372dce6f82,
+                    0xdf5fa3c8451b3369,
+                    0x0042b8be48e2df95,
+                ])),
+                Felt::new(BigInteger384([
+                    0x772628176b21bb81,
+                    0x32faf0f901da2358,
+                    0x85e38a1417ad22c4,
+                    0x18ac70e1678e854a,
+                    0xb2ff278807a233cd,
+                    0x00a6cd3130572faa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd64e7eee8318d30b,
+                    0x92ea8aca34238bb2,
+                    0x68cde93098113ac4,
+                    0x205b8fc3456727f3,
+                    0x2efff92ee255ff3d,
+                    0x00e6ef690fb84dc8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd4ca182de76d8812,
+                    0x118ff670008c74fd,
+                    0xdd70ed12a826208f,
+                    0xa8908cdaaf811138,
+                    0xd284148aed171026,
+                    0x0030430f9121bb08,
+                ])),
+                Felt::new(BigInteger384([
+                    0x837763f765d7d01d,
+                    0x81e2e5aa3d13d215,
+                    0x03992e85566336c4,
+                    0xd6b6771f8605cb09,
+                    0x44bc952732133dfe,
+                    0x00c43790f8909db2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8a4bd9dda8714619,
+                    0xd58877c9e11cd78d,
+                    0x9aba858534ce51df,
+                    0xed3fc6cde125237c,
+                    0xe7d8db3c7a31b475,
+                    0x00da59f82f999b98,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4070e8989232e150,
+                    0xa7cea909bd0c2432,
+                    0x52fbc76d61fc3ac1,
+                    0x6303de2b52b7743b,
+                    0x8078da985ef6bc82,
+                    0x00bdd6055b96a709,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53d74172fa26527b,
+                    0x8387c8e46b155a30,
+                    0x95cb945eb7a8c4c1,
+                    0x6eec0d7583513921,
+                    0x35ff0afddc9ce79c,
+                    0x0150b3fa715a6353,
+                ])),
+                Felt::new(BigInteger384([
+                    0x02e091a7748dc01c,
+                    0xd8a1cb3ebeac5515,
+                    0x91c3073b24dca477,
+                    0xf5105aaa5000072c,
+                    0x7d291e6a32f3ad6a,
+                    0x01060646fcb083f6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5e4f84a039b58eef,
+                    0xc74fe2dcf675cc9d,
+                    0xc1a86397b87b6f4f,
+                    0x2fd310874deea789,
+                    0x7d82f99eb38f7eac,
+                    0x001c198f6f489fbe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5373cdc7775974f4,
+                    0x25a706d37b0819ee,
+                    0x66b8b387e48efddf,
+                    0xcc7662f11607b1bb,
+                    0xa41bc628c14ae653,
+                    0x012938d5509309ba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x77d411dd6e63e36c,
+                    0xc3c8bafec6b3bcb8,
+                    0x8e6d3b07727a2c2a,
+                    0x63e4011e034125c2,
+                    0x8b514358d70261ed,
+                    0x014f0430b2309fcf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc19cb401e0a29038,
+                    0x38438e4df00af9f2,
+                    0x90fc0bb639ab7414,
+                    0x4c99dd32dab5f934,
+                    0xea7cb20c9c05ec0c,
+                    0x00a140b515852263,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf770be3e467e20c8,
+                    0xefd8c9599f00c016,
+                    0x6bc7c5ff9f5e6765,
+                    0x7b99dd2f712d87b6,
+                    0xf4b3e2004eacad7e,
+                    0x00eadcbb478d747e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93b87c532e68a080,
+                    0x666e11accb61e4a2,
+                    0x3cd198b6af1fdfe8,
+                    0xade390a6aa59be3b,
+                    0x89193f426db5733e,
+                    0x005ee0e144030618,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc6851fe96efa2d5f,
+                    0xa22ce7dfdf6f2c98,
+                    0x0b1f41ba0756cc02,
+                    0x9459bb9bd5bc82c0,
+                    0xdccc1c3279deeb74,
+                    0x01348fae323299cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16173f0573953380,
+                    0x2303ab0dedadc1b4,
+                    0xdfdc985e7ed4e5f4,
+                    0x9f43cda1c570d748,
+                    0x464ec6753eeff277,
+                    0x00c8e3cec9bf5b5c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf0776301b95b150c,
+                    0xbe89da13e65a0351,
+                    0x4b194dbf84370740,
+                    0xc9c24ed605fe1868,
+                    0x445878f6a514eb49,
+                    0x00b86747e52fee22,
+                ])),
+                Felt::new(BigInteger384([
+                    0x89c6735db3d2d2f1,
+                    0x402324d3e68ddfbd,
+                    0x993b29d09a311894,
+                    0x3d2e8bab6f6e70bd,
+                    0xec1099671b3d81b0,
+                    0x005886a6d8f16ab3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x445ffc28a49c891a,
+                    0x6a5a51be9e0a4f34,
+                    0x85489df683a975f4,
+                    0xaf04417a4f79230d,
+                    0xcfad789dabb4e45e,
+                    0x001d97b40a1c132b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbeb1862bad2d493a,
+                    0xe6aff70b9ea1df94,
+                    0x8d74d6215f2bae6b,
+                    0xd05d70ada8270e31,
+                    0x19fd8f6b5fe92fe9,
+                    0x0085009593fcdfe6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2843289cb9232cc5,
+                    0xf59ca9d95809c7d9,
+                    0xa6a12c331fb0f415,
+                    0xb821f1b5718bbd85,
+                    0x1bbff88c9f3fee80,
+                    0x0086e40f4cc66b67,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe7acf8fdb24bdd8b,
+                    0x8653485383f0710e,
+                    0xcb59cd65da8712bd,
+                    0xf47e3038051dce65,
+                    0xb318a41e785057c4,
+                    0x014cdfb12d1a637a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x509072844547dbd0,
+                    0x9dca3d7e468c9951,
+                    0xfae0a0c171db5973,
+                    0x95c24b62affd8e96,
+                    0xdaa117cab0240221,
+                    0x0166bfad38ad02a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xac920fdedc516e47,
+                    0xf3149d92e8b424ea,
+                    0xa698da7e631fe7cb,
+                    0x4b42ee187ac8903c,
+                    0x6df791f2d6f5599b,
+                    0x00b62f7cd3fdec03,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa5291a55e5a32b3a,
+                    0xa3fdeff7948e7990,
+                    0x30c6c8a26910a44c,
+                    0xa6c02ff5064521a1,
+                    0x284121d9aac3da9a,
+                    0x009ae59528d76980,
+                ])),
+                Felt::new(BigInteger384([
+                    0x14d8fce34bb51ea1,
+                    0x76422096e4d188fd,
+                    0x8fd1b3204d4eb197,
+                    0x560ba04bf180be8d,
+                    0xb781556b915d7fc3,
+                    0x00900a6f74cb42a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45b68ffb54032540,
+                    0x6261193b533b5b2e,
+                    0x03ed169d798470e1,
+                    0x764afbebabe5c34b,
+                    0xa284f11a62577888,
+                    0x015442a6d9d3b1da,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf80fe4f403e678f7,
+                    0x9c526936cedf5de5,
+                    0x4e2ae9d14c3d3b65,
+                    0x58bd880b3592f8ba,
+                    0xf070d6e8995eb9d4,
+                    0x0044dd5b1d561304,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4a4f7b6409ec7716,
+                    0xc959bb5e7a5c5293,
+                    0x9d6e8465e447c65a,
+                    0x96f29245a6ed9fdb,
+                    0x4e2c6c07d27f0887,
+                    0x016bb47e9b069d7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb607f869095fb43b,
+                    0x1b16c7ab9b9f7393,
+                    0x1e2eb6fb26bc36fc,
+                    0xac32d2bd49ac2279,
+                    0xf609ff0e240b5589,
+                    0x00303cb47c3ff30c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52024d8acefcd8cb,
+                    0x959168aab4d7a1d1,
+                    0x86e858d3feaa3f8d,
+                    0xa7d64fbf4f401b69,
+                    0x1804ccfec9b7cd5e,
+                    0x014e627b4fb2de36,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaef9baf9bcfdef6f,
+                    0x91e3c70904cdcc51,
+                    0x4082d21072f0b234,
+                    0xc8f7b31ae4703107,
+                    0x9bdc09e3a3bd4db4,
+                    0x0092f409b66ee68a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd110cb3d2fa05e12,
+                    0x195b15714b71c2ae,
+                    0x480d52f0f615c874,
+                    0xc189a06770a133b1,
+                    0xbdd6089d9026e183,
+                    0x002b768837c78907,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21a0b44cdf9f591f,
+                    0x62f502e78757d602,
+                    0xf1359a7dcf7c31e0,
+                    0x8c6354db3f670c46,
+                    0xea8fb72c7e3bef88,
+                    0x01362752a6ac5e5e,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x32e7932ee9d48c49,
+                    0x15d9ed6a59e478c1,
+                    0xa3a18d731e8ac7ec,
+                    0x9f7c79e5ebf8666b,
+                    0xc9cab26cc0eef2b1,
+                    0x019277f685537959,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21be86c37def7cf6,
+                    0xd726fd266b5fb50f,
+                    0xbe4b8aeb768836c1,
+                    0xec297a451d89a480,
+                    0xfb003e76d3b9efdc,
+                    0x01793acc6ec54abf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x90276132319c3b1a,
+                    0x05d7fe6f7a931882,
+                    0xa2d4fff22ab81e50,
+                    0x88651888d2bbb17c,
+                    0x4a71185737f43569,
+                    0x00d10000e6b98577,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcfcf8c06837d7f74,
+                    0xaceb27664af50890,
+                    0x7d7209fef3106338,
+                    0x2f434d1a3242ba7a,
+                    0x2050355019d4062d,
+                    0x01588ef64f9f5229,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4bf5518de9ea3a24,
+                    0xf2a990ff56161fd2,
+                    0xf09a77911e7c51a5,
+                    0xbb75e32327144a51,
+                    0x8d15f50b4d021b98,
+                    0x012c3693591c4719,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd9b8b112d75ccdd2,
+                    0x85a19fcfa47e0a36,
+                    0x636b9f50e9b996ca,
+                    0xee9ebfb00ce99e10,
+                    0x6750b17c983c48d9,
+                    0x00f251d13b6a5c3b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe03805a25e5644e2,
+                    0xcd9f2bee8867ac2a,
+                    0x15f1dc3f0ee33e8b,
+                    0x73d9cf0b956cc9f8,
+                    0xa2ef2a0f1088876a,
+                    0x01285d87cd3ba604,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe97ab7a636df928d,
+                    0x151d00dea397b9af,
+                    0x795c521a68861a17,
+                    0xe6f89cbac1894b1b,
+                    0xae810ed51c9f6d67,
+                    0x012cfb2d6f3c0d83,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52d6c8157ae083ab,
+                    0xe4410e74baab2ba4,
+                    0x7fd6e0754ea351cd,
+                    0x0b1474dd8bcaf7f8,
+                    0x44223e61552fa5a4,
+                    0x018d2828f86f97e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2c56d3388710d1a,
+                    0xb4f5be8833e5ddd5,
+                    0x349dcfecf94749c4,
+                    0x9065f168ce50a50d,
+                    0x63a29efd1a2ffb6a,
+                    0x00d6e421f6864955,
+                ])),
+                Felt::new(BigInteger384([
+                    0xecb45db39cf28fe6,
+                    0x60353688756f4fa5,
+                    0x229e2de39d3e7da0,
+                    0xa2e8ccc35460ba37,
+                    0x3668a1783f6403cb,
+                    0x00316c5a1941efc1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe52a2b4ef22670ea,
+                    0x79b11c6afe8127c2,
+                    0x9f663286bde43349,
+                    0xed37396d14ecbaa3,
+                    0x69f56a79d0d8b691,
+                    0x019aa47b824440ab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x521ebf9b60819634,
+                    0xac82ee2d058e1a4c,
+                    0xb770342116c95ec5,
+                    0x4c8d57d2c9de7aee,
+                    0xc300770271499385,
+                    0x014c2de4f4e6dad2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0ba22e96a9313f68,
+                    0xc9fec9f0013abc9f,
+                    0xe33ba330468dd591,
+                    0x6a5fe789907121a5,
+                    0x08db066e7fa82c1a,
+                    0x0148f80c07cc7726,
+                ])),
+                Felt::new(BigInteger384([
+                    0x11a3941106fd25df,
+                    0xa081529ec7d903c7,
+                    0x7f59d54c4e39c91c,
+                    0xd61c3b2e8d440f53,
+                    0x3de56b2128ffc07a,
+                    0x00d0540f0332754c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d6826bd6456f962,
+                    0x632b7b6d63bf7737,
+                    0x28971f9303736c26,
+                    0xe420da0333e0cd0f,
+                    0x660e7cf49f1002d3,
+                    0x0009d86850fe1e7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa27cfb41858241ff,
+                    0x9f88220367b3d312,
+                    0xaad07bb876d8c9e3,
+                    0xb9d8ca02c76276c0,
+                    0xc53a020b1c46a220,
+                    0x018e6d3300e0aced,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4ae65c11d13d178c,
+                    0x33417026c8b456ca,
+                    0xe4d1ce0ac8ad3e51,
+                    0x4a164cf5d7f69ed2,
+                    0x0835f0a4be6c1348,
+                    0x00bebb9033e34c6f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6e8244408556bbad,
+                    0xd0223e7fc78fb12a,
+                    0xe57ca1e29eda33d8,
+                    0x0bddb8442d39a8ca,
+                    0xe9a6e53ae317097f,
+                    0x0162069044ee1ba9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf4a4b6cdc3df9259,
+                    0x196ba8e93fd4ad35,
+                    0x8e55916dda7b1909,
+                    0x0a11199798ef197d,
+                    0x96001debf72973c0,
+                    0x002e4cce67163be5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x86d0e1ce7655207e,
+                    0xedcb957737640ae8,
+                    0xb02c11228ed43c34,
+                    0xd480a7a29a4f6191,
+                    0x6d3529b77b85c845,
+                    0x000e7c773d7a3ebd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa0aa58f153b0af16,
+                    0x97d68c79d268f96e,
+                    0xa445362de1ec259a,
+                    0x99e7b8cdbf603e86,
+                    0x2b03da9cf962ea2a,
+                    0x00d31ccacd08f2d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x01e49f50744eac38,
+                    0x208dafccdba3fed3,
+                    0x6fff0f848a55773d,
+                    0x70d0a19b686dc5a8,
+                    0x6630ce0b6230ae2c,
+                    0x0072c35af09ac492,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ff0b4466e4a4d63,
+                    0x6101da4bb6b3059a,
+                    0xf8e5b94da4409cbd,
+                    0x9240ab4c0dba5e0d,
+                    0x0617e1a2a0044d32,
+                    0x0127dbf7296f143f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xefe1437f194c576b,
+                    0x304612614219f93f,
+                    0x79920f11d712386b,
+                    0x0c21378aef160278,
+                    0x164ab37bd617365e,
+                    0x0030709f209ab543,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6b5b82118ec54541,
+                    0x0d7b905cdd8c5a1e,
+                    0x75f1c54daf304eb1,
+                    0x63066eb8b0b8454d,
+                    0x1268a61c7576e10a,
+                    0x0077f5bfa68493d5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x14d016d51ab2f264,
+                    0xe1a3f5e933ca39aa,
+                    0x4d4ed027a4ba6f96,
+                    0xb4849b5dbd25b1fa,
+                    0x7d245203c43d4951,
+                    0x013d5474c3a02adc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x074a836c9b54cd38,
+                    0x512dbd9959b8bd86,
+                    0x712ac8cd10981aa1,
+                    0x30e84e20ceeef641,
+                    0xc0f253993aae1bda,
+                    0x0116cfa70bc95398,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8f237c6bb91b4070,
+                    0xb6eb0a0b7b718625,
+                    0x1100d1a9695208a7,
+                    0x7de75d98f85d2b4d,
+                    0x399ffa8e9ad542f1,
+                    0x01907b55258b7615,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd640e7430dc30da2,
+                    0x2e625b57d023c031,
+                    0x74aafbf4694b6db9,
+                    0xfa9986ac26c898ef,
+                    0x5727a5942c298f68,
+                    0x00ac9b0fcbde9d5d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x734f1201d193bca9,
+                    0x99ced4387a953131,
+                    0x1bf47aa6e06a92ab,
+                    0xb94cdc48c64aab73,
+                    0xc8ece7e8e1752be8,
+                    0x001e64d4de056573,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9a910f2c6e70a9f4,
+                    0x14db4f280c2d1bd8,
+                    0x028c1395bd2255b8,
+                    0x2142f87ccc343003,
+                    0x6820ee907b2be9a5,
+                    0x00c7034d83e65e44,
+                ])),
+                Felt::new(BigInteger384([
+                    0x755c2be6d777b644,
+                    0xd699ab1c188ab452,
+                    0xcba4ed239d81b62c,
+                    0x16bac0a37c8c45a0,
+                    0x2a436f9009bfd236,
+                    0x00ef263f8c190f49,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98f942289e99b4fd,
+                    0x6355310fda5f0045,
+                    0xdcc0c6f75a723de4,
+                    0xf6dd0afa22cd909f,
+                    0x7154cacf5d018e7c,
+                    0x006b4b8d1e031ec7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b6cdafdf2887a27,
+                    0xaa2a794b258a455a,
+                    0xe01307a2368a6bca,
+                    0x24db5f36c16d7f48,
+                    0x2f0befedf109950b,
+                    0x0134f3575f66535d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x021891731a572bfe,
+                    0x11b2db2e09975c3c,
+                    0x9a2cd15d06231433,
+                    0x5d8d7dd34729ba09,
+                    0x328fc3bd86ec7ef4,
+                    0x019af38b2a7b2302,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf1d0648ed7c971e7,
+                    0x1810d10b2af65dfb,
+                    0x709ecf880c438b28,
+                    0x84557d3e9c5ba1b0,
+                    0x5242a396994673af,
+                    0x005346f20c90ee8f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd633be14b07eb446,
+                    0x1b2c9b70be756006,
+                    0x7d2d26a2f554e95a,
+                    0x42a99c44503c426c,
+                    0x11c3a088f0eaff6c,
+                    0x016eac15386189f8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0bce9beecc0a3f3e,
+                    0x4f24eb0f4d5d6e7b,
+                    0x8ad5fbae77884709,
+                    0xf9d84f2e72cfa27a,
+                    0x7cb1a4d11278d6a0,
+                    0x005eee239608f9e6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa5201d8109e9ad22,
+                    0xfa23d575d3decee1,
+                    0xb590d8c8fb54dbb9,
+                    0x9f7d902b524cffe5,
+                    0x81663c0b75bf25f8,
+                    0x002bf6a470434fad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x697f7d64ae5cb71d,
+                    0xd7751c3759445de5,
+                    0x27e8ab22504e9a37,
+                    0x42c707e11bd71c58,
+                    0x68508ce98283bab8,
+                    0x00c0b96b4fe6e05f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeaea191fc48cdef1,
+                    0xb0ee3cdfc21d1116,
+                    0x32e726e2aa20f3c4,
+                    0x6ada05cf71bea870,
+                    0xa0f5d2da96275944,
+                    0x00bfaedf1578137e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf1d34590b6d7ee51,
+                    0x9154330415e72333,
+                    0x14e5c6dba0eadccc,
+                    0x9682d88b4bad2edc,
+                    0xb24cee44aa59154a,
+                    0x015d2764006d6fa1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45d44b6e05c36906,
+                    0x17c7f2dd4b31107b,
+                    0xe6a9aebc8735733d,
+                    0x6a87a38886f33420,
+                    0x950f73df905865f9,
+                    0x000675ec18b5ade4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x024922724da937d0,
+                    0xbca087cdf60d0837,
+                    0x8bba99468ac26877,
+                    0x456352de3794c107,
+                    0x6f45f998bb3e983a,
+                    0x0053a3cd5c5eb98f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x380c6bf0bf9e9f02,
+                    0xa861bd65d57bd5d8,
+                    0x85b962cf992d9b28,
+                    0x34ce78f122ff6a9c,
+                    0xa77cb1879a985776,
+                    0x0183cb8b25703d45,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa7f45970c51dcc2b,
+                    0xaf1946d6cb77741b,
+                    0xd9be4e9833e328be,
+                    0x1ce6ab54e27d0257,
+                    0x404744bacd52ae01,
+                    0x015500b6b57bfaf3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd2c7bc9c5f078c7b,
+                    0x60afc1b7b70edad6,
+                    0x57d68c4aff3d0189,
+                    0x29e622938512a22c,
+                    0x2d40a85341d6692e,
+                    0x00d015c33f145bbc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8a81b1a6418aee87,
+                    0xf49bb36ad9b4f996,
+                    0x152073b3cb7ff5c6,
+                    0x9facd5c82cf8c9c9,
+                    0xa3782e9547dc42a1,
+                    0x005d0619ed03e738,
+                ])),
+                Felt::new(BigInteger384([
+                    0xef8d5f3b8aa176da,
+                    0x2af1fc859a086343,
+                    0x2d3057a8b0b2863b,
+                    0xb6fc2964b0c59174,
+                    0x51f60e0c7a35d535,
+                    0x006f4863f6258f85,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7f18b3578e57083f,
+                    0x79c96872a8cdeb09,
+                    0xf7113aef2c5e4bc3,
+                    0x936545c6fda78201,
+                    0x084a4eadf4eaa7c7,
+                    0x010db14dc62f0860,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd65806358e6aaee6,
+                    0x880406e2be2035a1,
+                    0xabf0bed78e2569e7,
+                    0xa753610d182ba79a,
+                    0xb6861672bc896efc,
+                    0x0088a56be735dc71,
+                ])),
+                Felt::new(BigInteger384([
+                    0x716083d5ede96330,
+                    0x1e70a8fae793c2c9,
+                    0x04c5591c99692df6,
+                    0xb4af3e01b5fa75dd,
+                    0xf7ea76d0603de47d,
+                    0x003837f828f848bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xef0caaed549e0f71,
+                    0x5a6334bfb2c75f71,
+                    0x37842f4f339789c5,
+                    0xf61cdff481909b10,
+                    0xe64250b51f641808,
+                    0x00435b8c364f8fe4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x14128fef97f69d03,
+                    0xb764317e340e71de,
+                    0x531a4c227ff44e2e,
+                    0xce747961bf589e19,
+                    0xc3761bae70fdfac3,
+                    0x015958ec481d8213,
+                ])),
+                Felt::new(BigInteger384([
+                    0x79bbb833961a1a25,
+                    0x4f6430f5d1a3dd39,
+                    0x6de2bf0cce15fa4e,
+                    0xd26b3a5f27f8a512,
+                    0x8d1380439be8452b,
+                    0x018cbc136a2af7d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55a66d42fb597471,
+                    0x98e3dc7306220c27,
+                    0x84c43325e3699052,
+                    0x1172756842595569,
+                    0x603dcf7792d62726,
+                    0x009ca687150638d0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5d2e19fd8c7f942f,
+                    0xb31b16981f094580,
+                    0x65c51656e777bede,
+                    0x3d9f7bf33295f0ce,
+                    0xcff5c4f636c2b492,
+                    0x00f3b28c0a74568b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x95321dc0ea493dc5,
+                    0x8afeae04819f994d,
+                    0xef4b8ea5fb93934e,
+                    0xa7c56e83ac144f18,
+                    0x65501cb89c17d182,
+                    0x00cff23f764b21ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9d7a4b4c3cb59c41,
+                    0xb3e307f2274f63e8,
+                    0x3708da55b9d146de,
+                    0x4df85ce7accea9c6,
+                    0x21cfc2e624dae70f,
+                    0x01174f00d1b84282,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd6d7966ad770464c,
+                    0x50aacb74384ba665,
+                    0x92fe7b641fc66204,
+                    0xbdd2ecd2cd44527e,
+                    0x5201f6242d72d7c6,
+                    0x0027f59890c45f01,
+                ])),
+                Felt::new(BigInteger384([
+                    0x681e5cba71e78eba,
+                    0x83954b41c026fe86,
+                    0x32fc91ca88061613,
+                    0x1dec82008b76d7d8,
+                    0xae61382c847c80f9,
+                    0x007579f11a6f6144,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb1541c4dcbd61482,
+                    0x9d5f16c76573ab50,
+                    0xed1d296cfa57485f,
+                    0x610f8dc086af59d1,
+                    0x570b300352745888,
+                    0x0036dc19779f316f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2be9d5e02e738f7a,
+                    0x2e808c617c770e4d,
+                    0x2c0b42dd71ca200e,
+                    0x8d9389b81a38a1ec,
+                    0x8298d825db630cec,
+                    0x00cca8ef57363845,
+                ])),
+                Felt::new(BigInteger384([
+                    0x78f7ce0bd206f9ef,
+                    0xacd4d8a1b7356ef3,
+                    0x6063d7f004e6c2f8,
+                    0xc70f4f1b5a8ab839,
+                    0x76dcdf5a882fe7e8,
+                    0x004c67ba4e37eaba,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a8f4d31911861a3,
+                    0x9801ab7845e7da62,
+                    0x21eb1da9038429b9,
+                    0x76ed8c90d6cf323c,
+                    0xa997b91f1d576a86,
+                    0x011b11aae379d639,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8cbcef1201bc49eb,
+                    0x6cffe4754032a180,
+                    0xf6dd30982a51e5b4,
+                    0x76185edd5d54786c,
+                    0xc79db1ad39276dec,
+                    0x00a43b6295a6d614,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbf9b6623082514b4,
+                    0x366dcaf7bb58c05a,
+                    0x40811942832106bf,
+                    0x7bb5debf181fecb6,
+                    0xdc72e01c0fef3166,
+                    0x00a482aa794316dc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f2a588902ecf569,
+                    0xa881df65057d2be1,
+                    0x3831f0f6db632683,
+                    0x8ace74fc159f3f7a,
+                    0xd27f3a36d8e99fb4,
+                    0x01344d6a96dd12c2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe847c5742292f70b,
+                    0xf4eb9d899afdba2e,
+                    0xcaba76cf6c67443c,
+                    0x55c6cae1e6973289,
+                    0x43b7dcc733742640,
+                    0x00f30865f7beeca8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5e0f21cb5593d67e,
+                    0x426d59ad88cd21df,
+                    0x76272bfb89d302d5,
+                    0x6daad81c4e41d8d4,
+                    0xf85e3ee272b2cc19,
+                    0x00c1c650d638f3fe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x01c39278ab153b9f,
+                    0x38dc14f6a2cc2313,
+                    0x228effa42b67d01d,
+                    0x3b66db374811f946,
+                    0xd0caee10c786219c,
+                    0x007ce2fb823fb276,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger384([
+                    0x2a367fffffffec5e,
+                    0x92e2eb499ffff5cc,
+                    0x59b252fd95c86e3b,
+                    0xdfc50b2734760022,
+                    0x2d39142b498045a8,
+                    0x016bebe15509deb8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96823ffffffff5e3,
+                    0xf211c5668ffffabe,
+                    0xfc9803539022d716,
+                    0xad89d16f51793193,
+                    0x3d16d4f564de6548,
+                    0x00fca9219c03e9a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96823ffffffff5e3,
+                    0xf211c5668ffffabe,
+                    0xfc9803539022d716,
+                    0xad89d16f51793193,
+                    0x3d16d4f564de6548,
+                    0x00fca9219c03e9a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a367fffffffec5e,
+                    0x92e2eb499ffff5cc,
+                    0x59b252fd95c86e3b,
+                    0xdfc50b2734760022,
+                    0x2d39142b498045a8,
+                    0x016bebe15509deb8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96823ffffffff5e3,
+                    0xf211c5668ffffabe,
+                    0xfc9803539022d716,
+                    0xad89d16f51793193,
+                    0x3d16d4f564de6548,
+                    0x00fca9219c03e9a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96823ffffffff5e3,
+                    0xf211c5668ffffabe,
+                    0xfc9803539022d716,
+                    0xad89d16f51793193,
+                    0x3d16d4f564de6548,
+                    0x00fca9219c03e9a9,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0x2a367fffffffec5e,
+                    0x92e2eb499ffff5cc,
+                    0x59b252fd95c86e3b,
+                    0xdfc50b2734760022,
+                    0x2d39142b498045a8,
+                    0x016bebe15509deb8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96823ffffffff5e3,
+                    0xf211c5668ffffabe,
+                    0xfc9803539022d716,
+                    0xad89d16f51793193,
+                    0x3d16d4f564de6548,
+                    0x00fca9219c03e9a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96823ffffffff5e3,
+                    0xf211c5668ffffabe,
+                    0xfc9803539022d716,
+                    0xad89d16f51793193,
+                    0x3d16d4f564de6548,
+                    0x00fca9219c03e9a9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2a367fffffffec5e,
+                    0x92e2eb499ffff5cc,
+                    0x59b252fd95c86e3b,
+                    0xdfc50b2734760022,
+                    0x2d39142b498045a8,
+                    0x016bebe15509deb8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96823ffffffff5e3,
+                    0xf211c5668ffffabe,
+                    0xfc9803539022d716,
+                    0xad89d16f51793193,
+                    0x3d16d4f564de6548,
+                    0x00fca9219c03e9a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96823ffffffff5e3,
+                    0xf211c5668ffffabe,
+                    0xfc9803539022d716,
+                    0xad89d16f51793193,
+                    0x3d16d4f564de6548,
+                    0x00fca9219c03e9a9,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0ce685eeedb9c68f,
+                    0xed38d03af3b22773,
+                    0x5bd22abd1bd1146f,
+                    0x6e926d703c3cfc32,
+                    0x956afec0b476a3d6,
+                    0x00ce6584bb9c9ae9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x64fc45a17ce1dacb,
+                    0x7a39bd50b1e53736,
+                    0x94882ca51a6f7e0d,
+                    0x0220556fa2ee1b0e,
+                    0x9982d55decc0e19e,
+                    0x0098fe04b064d95d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e1dfe622156a00a,
+                    0x8e2bae5b00425043,
+                    0x447724baf6b67fbc,
+                    0xaf1bb444e98b2390,
+                    0x0dabc9ebb8a0c1a4,
+                    0x01a4139543994b84,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2795077b48f322d8,
+                    0xfb17104ce44fdd83,
+                    0xf3f0f1e9da04cae0,
+                    0xa3e9dd0b4b62bc45,
+                    0x444f2e6648a9d13e,
+                    0x006aa364422d7c0c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf739d114079abb0a,
+                    0x02662076256f8e33,
+                    0xc065c16c4055dde2,
+                    0xd1b6cc545e2e9c1f,
+                    0x11cdd8ef259bbb17,
+                    0x00b76760c9c67d33,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1b454f8705e546bf,
+                    0xa99f2cb680fd6066,
+                    0x6672576043cfe0c3,
+                    0x3d0d16865ef2c896,
+                    0xd3772851b9356f6b,
+                    0x0072eae28ddf1257,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x370ecb9e4dd5cb06,
+                    0x865dcbbb0a73c722,
+                    0x01798de3a1754780,
+                    0x86bcbaf0abc0f969,
+                    0x4a8d95af1fbeaa95,
+                    0x002f84a06927324a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcad655b149859c84,
+                    0x716203263bdbaee6,
+                    0xcea44a2b1b68970d,
+                    0xc373c940117877c0,
+                    0x79c4f0d5ed788afc,
+                    0x013f87863b60ca2d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3715815b0a139789,
+                    0x43cf18b649c928b7,
+                    0x387254a4c74b6bb0,
+                    0x47f36f2c2230c958,
+                    0x2c131e792f3fcecd,
+                    0x00a4ad4c5e4126ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcae7d2b4aace3f30,
+                    0x6a2d2f50b6437a06,
+                    0x0ba647bf314ea7fc,
+                    0x838b6a52c300615a,
+                    0xc146712e4b840767,
+                    0x00fcb47c6c0f1687,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7cfbe9285e70a9b6,
+                    0xaae407cb64c7d696,
+                    0x56224671004a51a8,
+                    0xf0fdf8bf671fd1dc,
+                    0x669c037a2d4435e0,
+                    0x0156d3cbd95d00ed,
+                ])),
+                Felt::new(BigInteger384([
+                    0x20b3a628ab6521f5,
+                    0x1ff8e93d088a7e47,
+                    0x0d5a8d99304157dd,
+                    0xbc739426e0f331aa,
+                    0x87eb160760163ab4,
+                    0x0075f673d1c67156,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc7c1ef1888c0e32d,
+                    0xe5affde75236ad3d,
+                    0x5def456bbc1df6e0,
+                    0x9458d1004e584fe0,
+                    0xfd1e977ac273c3c8,
+                    0x0141c94ff452ad23,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f32d3032345e109,
+                    0x72f48742a7ce2264,
+                    0xa5c4cbb28b656475,
+                    0x0e5e6c4ab1f14952,
+                    0x79b5a50f4a265975,
+                    0x0179a595a9fd5c1d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x781c7b50f01c78fe,
+                    0x9dad732a4470ee1d,
+                    0xe55ad161e16b18a6,
+                    0x3cf68e1a4d2c9801,
+                    0xd55f0077f2833f89,
+                    0x00376c1e407ae08b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc7580ba519df30ca,
+                    0x205ea5bcbe06e622,
+                    0x6a3dff4396400f9f,
+                    0xff52db3c5d3ee9a1,
+                    0x61adfca3f3704f10,
+                    0x008d99e292ce6c74,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5669cbe69ac7b216,
+                    0xfdd772aece3e46b4,
+                    0xb4a3cc1e7f03844b,
+                    0xf6008bfb9d508131,
+                    0xb0e0fb7c6c890f2c,
+                    0x0016217874966eb6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ffaeaabd3b58e25,
+                    0x7cea67cda28ffee0,
+                    0xed1e62744ad41518,
+                    0xff6f2f35417f037f,
+                    0x970f51dca64b57ec,
+                    0x00bad3f654878da2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x35a54b5ccb1b3152,
+                    0x9e0d23f9e4abbd86,
+                    0x9fba6a0835438a61,
+                    0xe043f9f630e769a0,
+                    0x11f0c55bc931f466,
+                    0x00fe601578c664ae,
+                ])),
+                Felt::new(BigInteger384([
+                    0xef516f0fe84e89c0,
+                    0x5ad2c05411b10561,
+                    0xafd45b198af8c553,
+                    0xefa09f37c3d9bb70,
+                    0x888b9bb0af098a8c,
+                    0x00503eff474257b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b06d48078e6c1b6,
+                    0x7b933757749e955d,
+                    0x3ed56bcbbe57775c,
+                    0xa8ebbb2a94113fb3,
+                    0x503da42e496f65ca,
+                    0x008ca2e383e9f3e5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xede90733a942bb70,
+                    0x0dbe203501dd50be,
+                    0x79e9a4f88fe8a1b1,
+                    0xe7f68b32872c5bba,
+                    0x4c427c0c1bcd6b70,
+                    0x00bfbd114999a65e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb112ee8627888be,
+                    0xee11c17aca7a0fb6,
+                    0x06524465bb158138,
+                    0xa6dc6520470e64ef,
+                    0xbe73364824afba0f,
+                    0x010eaafe0ee6de1d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x90522397837fcef3,
+                    0xd38f66ac5eb2a4f0,
+                    0xaaa03ae88ea46756,
+                    0x8f1282f9ececa091,
+                    0xaf9f08aa1bf89870,
+                    0x00c861140c6b0ac2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5bbf4844a64d3b97,
+                    0x1f11572f0c1c19ed,
+                    0xd7dcb84cfbc4e452,
+                    0xbdab9d5cc7d26232,
+                    0xec8289633d0baa39,
+                    0x0157e63f5959358e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98d21bb43ae308a2,
+                    0xdebbb1dc8a39aea5,
+                    0x6cc9b631ded725b1,
+                    0x576309353b174685,
+                    0x8d94f301ad7df647,
+                    0x0077e07b7dfd83f6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b0b894add51c1af,
+                    0xc3eaf094420969ce,
+                    0x9e0f93e5a4f3d4b3,
+                    0x843516281831f385,
+                    0x0d4ece8e140a34eb,
+                    0x01557fb4f5cb12dd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf1494bf198c0f828,
+                    0x4fdaf9bd70afae66,
+                    0x94679273702c4226,
+                    0xa19bbc57187cfd6e,
+                    0xbc69f1e0ab989251,
+                    0x002f9b4234e44c22,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc2fd63601be4c3b1,
+                    0x5d0f30f2f8171fd7,
+                    0x3e5031449d01d586,
+                    0xd2adf7d0b3f7de62,
+                    0x5bcc1a6e87ec13ad,
+                    0x01a1c780a77b969b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0de3f5c30df4961b,
+                    0xa1d8d186ee8068f2,
+                    0x96f5e10790b7de59,
+                    0x5ffe2b0a3c4964d7,
+                    0xd0709651ada53265,
+                    0x01571fb288a79cab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa87b1fd352b909e3,
+                    0x06c8d714e6559670,
+                    0x2349ad0783d4023f,
+                    0xcdd2a33337151ea8,
+                    0x54563c95fa4a2e89,
+                    0x019b30d5ad6b84bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2873453c35c3bfbb,
+                    0x858cc46a3de0f40a,
+                    0x2dd6304489dc1629,
+                    0xf467baf0af414626,
+                    0x74fbbbbb53d8d55c,
+                    0x00dcc648e0664c45,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3fa340ba251a5edc,
+                    0xb7a9dda263cd62c1,
+                    0x35f496057d18f2d4,
+                    0x5520c7019ff3c538,
+                    0xbbcd60f6b6608a93,
+                    0x0162ed3746484f56,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16a606702d82141f,
+                    0x3db5b425df79e3ef,
+                    0x41730957ee77c13f,
+                    0xd0536bea86517638,
+                    0x2e7c4ca45fe0b386,
+                    0x00e62a06e56cae3e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4eb8861207458bb9,
+                    0x05b1624e90772dad,
+                    0x6314ae1984f44a84,
+                    0xc47ded0510921825,
+                    0xc8dbe8db6c5b9866,
+                    0x005c1acf06baec10,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc9b112d6d151c405,
+                    0x70e066cdf1ced954,
+                    0x00ed5ae294eb46d8,
+                    0x473c5a1cbdd15759,
+                    0x366b51d9bb0b3cbc,
+                    0x0106f23360f174a4,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
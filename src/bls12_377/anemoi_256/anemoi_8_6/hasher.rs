@@ -0,0 +1,2736 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U6>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger384([
+                0x418d890a32dfd393,
+                0xf1b9710590a105b1,
+                0x977fd15bdd13ff20,
+                0x59f00cc7691bedeb,
+                0xb1de4759b878cdbd,
+                0x01937fb83044bd3b,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0xdfcc0d2e934dcf50,
+                    0xd1bb4a64dd8947a0,
+                    0x4fb4a22d3c3024b3,
+                    0xa8430959d529c6d9,
+                    0x82d627c82d1f89b7,
+                    0x01396b2229d9ef53,
+                ])),
+                Felt::new(BigInteger384([
+                    0x54f1e16c874a1bf4,
+                    0xd2cf2fc2b2e06a9b,
+                    0x94705e4581d69e3b,
+                    0x18652181e01c8c9e,
+                    0x2ed21d6caf8d75c0,
+                    0x006bcd92872f529c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x0b203a72eea9f8da,
+                    0xc88f6b1721bb583e,
+                    0x19cdffd6207f7b01,
+                    0x91db969b8b2b79e1,
+                    0xf0fa42312eb58f63,
+                    0x013c600082671c49,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7efbc5fc27a394fb,
+                    0x06144fc6f55b95ba,
+                    0x080c7a7dc059e046,
+                    0x31dc73469af9df58,
+                    0x104c8285b1766f22,
+                    0x01647bf5e49c8db8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3cff409cf29b7a2c,
+                    0xb6dc005af6085a88,
+                    0x1d78bcf1494da8f1,
+                    0x78c55c398ee338fe,
+                    0x02bd8f7168797b7a,
+                    0x018e989db12dbebf,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x4a0fcf0876dd26fd,
+                    0x1c0629445cde8736,
+                    0xb16b97c5f0c76dcc,
+                    0x36ab365fa80ca290,
+                    0xf9d0ee8970a5cd06,
+                    0x0167babc1830eaee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x42d596bfe2671a72,
+                    0x5ae1290863c9f6c0,
+                    0xf0ccb572a12f9f9e,
+                    0xcbd01f57528d5e8d,
+                    0xc67be11a285ae229,
+                    0x001b0fa9f7f1ea9c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0d5317a5cf454cc4,
+                    0x3deb7b120e9fd40e,
+                    0x436497dbbe2001ac,
+                    0x0bc3f6630845c583,
+                    0xff152e80fad6c8ff,
+                    0x0039f91bce0a374f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3c6572046855c3f5,
+                    0x226bbb19a33b5420,
+                    0xfddb9d67ef83776c,
+                    0x85d4f19fc6fb1e16,
+                    0x6687f3fd7b4d48ab,
+                    0x01214f761f8c6588,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x1e19f1d34fade4c2,
+                    0x7609359fb865cddd,
+                    0x14b1106580a1adfc,
+                    0x233918495cd6e5e6,
+                    0x21ae86001b8f0c93,
+                    0x00efa233309aa1f8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa0ffdecc6e151735,
+                    0xcfd3d809ac2419fb,
+                    0x20556e04cfb32d1d,
+                    0xc2c1c2450407a7ad,
+                    0x327c3943105d8b1f,
+                    0x009cd2b1ec5b1ff5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x86a0d37022c320f3,
+                    0x5ac8999a3ca4a425,
+                    0xb8f8121794713d78,
+                    0x4395dd23f4fa60f4,
+                    0xa5180f635d667b7f,
+                    0x01975f1da556270c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7750ebef000776a6,
+                    0x7b0ae2ea59a8f019,
+                    0x26ca4d6cb97c8a6c,
+                    0x8e8f9bff52105269,
+                    0x786f7e7a1b569812,
+                    0x0083808b4f69a062,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9fc1d132b0148fa7,
+                    0xd4c8e6b2f5068117,
+                    0x4359e7c5c0d3fe13,
+                    0x725d3ac5fcb105d0,
+                    0xdf5f30a6c1f51846,
+                    0x017aa64d8b71de7e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xca143899f070f047,
+                    0x8f121e64188857df,
+                    0x186688b78190cc34,
+                    0xb089508d51a9f163,
+                    0x8fcaca128843143f,
+                    0x003184aa16bab612,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f7647b548a3fbd7,
+                    0xc2a235596f52e3c9,
+                    0x5123dd00be785bf9,
+                    0xac0a38c5a56acfe9,
+                    0xa42ad07068ded872,
+                    0x00e476aa310c095c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x420f9ce643963ff1,
+                    0x656ef624978795e7,
+                    0xd1e2cfab7ac12059,
+                    0x93527bf9934a84c5,
+                    0xf7e247a712c9ce6a,
+                    0x0061034d9e072f3e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1282b911ca4b654e,
+                    0x00759523d48158ce,
+                    0x63c14293f986cdfd,
+                    0x16ac36bdc4aa1366,
+                    0xf38f3c52fdab31dd,
+                    0x018c093bed5ecc76,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f34dd9f44edb4bd,
+                    0x32458ff14121bc7a,
+                    0x14da22826e868dde,
+                    0x77950132af29bec7,
+                    0xc1c9be00e0a5c00a,
+                    0x0159cfdbab45197f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1aa81607327af53d,
+                    0xa43643e387ef18fb,
+                    0x6e00cb9e68004b38,
+                    0xf7d475e4ac8090c7,
+                    0x28d898b5849c48c4,
+                    0x00025a911241fcb6,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x043970882e220fcc,
+                    0xc1f3717124dc76dd,
+                    0x1cdb40fd27ccea13,
+                    0x98123d30f5f86491,
+                    0xd3e50314677c2b67,
+                    0x00781517c3874815,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53bfc8b729565948,
+                    0x230b9d7d1cf9a58e,
+                    0xf741ed4c818a4812,
+                    0xda05fbe676f04f7d,
+                    0x8ccfd55c1cd065c1,
+                    0x00c02f111ffb731c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1ed1e1e99113a9a8,
+                    0x3a3bd72a089ac2ac,
+                    0xec92d1581bce6c90,
+                    0x93fa9f506672a364,
+                    0x33c8a65c6b22a64c,
+                    0x0026b79ff47e534d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd72cabda9d69ddec,
+                    0xa09faeffaf945790,
+                    0x99e4648666a1c578,
+                    0xba396e72a9889ee0,
+                    0x2cf2212cf1e6d842,
+                    0x01338c4fffda78ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd8cddc4aadf25d5f,
+                    0xc3daab71d2b071b3,
+                    0xee74fcda9530c995,
+                    0xb7c4b90389e56395,
+                    0x1e062398a639aa46,
+                    0x0109a892943fac9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa78ac6ad4939eed,
+                    0xb5cafbc04db2cda0,
+                    0x0fa29d16f7412b14,
+                    0xaf5143ee8d10496f,
+                    0x663a3312ac93453b,
+                    0x00d01ed8dcebf095,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe0de82f9638617a2,
+                    0xd22958219668da63,
+                    0x8eca114eb0340e8f,
+                    0x6dc8ed12ef42ca6f,
+                    0xce6eb0bb85d3f492,
+                    0x00ce4b8f773f175c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9be188e767ae8fc5,
+                    0x3f23b8a1dcc33729,
+                    0xfe3a9781da577a13,
+                    0x0944a372c70fabee,
+                    0xb37b9b4900e5b82d,
+                    0x00fd9d91f124f535,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xba77c9f3c46c98e5,
+                    0xf83625559cb1e5c1,
+                    0xfb8fa0103f8f7690,
+                    0x8ac907418d206834,
+                    0x072e00123963d48b,
+                    0x00fde1563748667e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x300fece3112f2fc3,
+                    0x0bdcba2f79af3d1a,
+                    0xec5f597034424e68,
+                    0x2c37173b896da35f,
+                    0xba1dda5c0457a8da,
+                    0x012ac7ee2d0368b9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xab3037df8e5ef250,
+                    0x069e218e05410803,
+                    0xeef6969aa5b89386,
+                    0x7419e1629bcfe462,
+                    0x0facc604b0fe7d4e,
+                    0x0162600e6626cd53,
+                ])),
+                Felt::new(BigInteger384([
+                    0x86cd4976e53af837,
+                    0xc4d5189f81a4a21c,
+                    0x7ad29b6c51cf4576,
+                    0x07ae9d973a941c84,
+                    0xab69ca9d375b4094,
+                    0x0171b070fe9f8fe1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xabd860b8359d419c,
+                    0x73e968c0867e266e,
+                    0xa382c4f009741353,
+                    0x97e589bccfd6c0d4,
+                    0xb0c4f9f7244be6f2,
+                    0x00764f69c24f3f1c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa631607b2c744a00,
+                    0xf3476210563fa839,
+                    0x31b8b318a97a234e,
+                    0x8513f91c375a92b7,
+                    0x384a277efe0a22c4,
+                    0x013da57316008ab6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xdc6f4458f0f26935,
+                    0xf418ca8f08f5e2d0,
+                    0xf0b16fed34749b2c,
+                    0xa0bb6082a4fb4c26,
+                    0x06a93e933d5266e8,
+                    0x00641729733f435e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcb0267eb4103eac2,
+                    0xd56865d45deb4f0a,
+                    0xe3e9fb90a1baa133,
+                    0x14876b23056ef7f8,
+                    0x401367858df3a828,
+                    0x0147c427449dffde,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc2b7f3caeb47cb29,
+                    0xeb90bc8043c53c7d,
+                    0x15b30ca029abb3ad,
+                    0x9aa29a516929c8a7,
+                    0x35e75080514ad050,
+                    0x0197609187ba9878,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfae5819a93a46c87,
+                    0x6bb15c386a7f2338,
+                    0xd47c092ceb88c8fe,
+                    0x64641b64e9c08a3f,
+                    0xa3d6313f1300dd26,
+                    0x015091a27955db22,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf87b209b946485df,
+                    0xc2b276e0718d2a67,
+                    0x7882445a203270d8,
+                    0x38e890cce135e341,
+                    0xe596c30ce92d7dcc,
+                    0x002ac5fcce0b9b78,
+                ])),
+                Felt::new(BigInteger384([
+                    0x709d7b2060b14a54,
+                    0x46ed16fc995e208e,
+                    0x53bf01df7ba95d52,
+                    0x579047bd0354fe1a,
+                    0xc7c8398883e4227b,
+                    0x016ddb009c981710,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x043970882e220fcc,
+                    0xc1f3717124dc76dd,
+                    0x1cdb40fd27ccea13,
+                    0x98123d30f5f86491,
+                    0xd3e50314677c2b67,
+                    0x00781517c3874815,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53bfc8b729565948,
+                    0x230b9d7d1cf9a58e,
+                    0xf741ed4c818a4812,
+                    0xda05fbe676f04f7d,
+                    0x8ccfd55c1cd065c1,
+                    0x00c02f111ffb731c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1ed1e1e99113a9a8,
+                    0x3a3bd72a089ac2ac,
+                    0xec92d1581bce6c90,
+                    0x93fa9f506672a364,
+                    0x33c8a65c6b22a64c,
+                    0x0026b79ff47e534d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd72cabda9d69ddec,
+                    0xa09faeffaf945790,
+                    0x99e4648666a1c578,
+                    0xba396e72a9889ee0,
+                    0x2cf2212cf1e6d842,
+                    0x01338c4fffda78ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd8cddc4aadf25d5f,
+                    0xc3daab71d2b071b3,
+                    0xee74fcda9530c995,
+                    0xb7c4b90389e56395,
+                    0x1e062398a639aa46,
+                    0x0109a892943fac9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa78ac6ad4939eed,
+                    0xb5cafbc04db2cda0,
+                    0x0fa29d16f7412b14,
+                    0xaf5143ee8d10496f,
+                    0x663a3312ac93453b,
+                    0x00d01ed8dcebf095,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe0de82f9638617a2,
+                    0xd22958219668da63,
+                    0x8eca114eb0340e8f,
+                    0x6dc8ed12ef42ca6f,
+                    0xce6eb0bb85d3f492,
+                    0x00ce4b8f773f175c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9be188e767ae8fc5,
+                    0x3f23b8a1dcc33729,
+                    0xfe3a9781da577a13,
+                    0x0944a372c70fabee,
+                    0xb37b9b4900e5b82d,
+                    0x00fd9d91f124f535,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 376];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+            bytes[188..235].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..47]);
+            bytes[235..282].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..47]);
+            bytes[282..329].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..47]);
+            bytes[329..376].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x3558180c807ee9d5,
+                    0x785ea35802e85c00,
+                    0x0cc5a35ccb116e30,
+                    0x6f0a63d7cc046cc1,
+                    0x219cf91ea02961d3,
+                    0x009edbfa68494c03,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdc71cf4408adf569,
+                    0x26b82d312731e83e,
+                    0x5ce0029fbecb5c7d,
+                    0xa4338e8deeb4a7b4,
+                    0x59fcf3655dc972cd,
+                    0x01aaf8c9dd4d756d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a4d081da4758163,
+                    0x76b4391c74e20fed,
+                    0x9cdd6ababb498f6b,
+                    0x7072b398e26386ff,
+                    0x7043227e29d98a4f,
+                    0x0187c1b4dc9abdf0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x42e9113ce125c885,
+                    0x061a5be14d0dac7b,
+                    0x2057e0a199f65cb1,
+                    0xf9496f5c46e3c16b,
+                    0xd25ff2ccd134c4b7,
+                    0x002ef7d0c887176f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x007a0a0d69b5d920,
+                    0x5340b4261a5d5ffd,
+                    0xf444b40e2742a202,
+                    0xa13676209fbc49e1,
+                    0x4d989948606f30c8,
+                    0x00c040e1186f2950,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf1bb1596487e0b1f,
+                    0x6253be2ff57969ab,
+                    0x68887059b0b329e0,
+                    0xf4f088a7dfc2a5c1,
+                    0xd7f7979efddf74e3,
+                    0x01077615db2ff115,
+                ])),
+                Felt::new(BigInteger384([
+                    0xab596249fd47c79f,
+                    0xf35af9059aaf8753,
+                    0x24d25a3dbff2542f,
+                    0x56e66e3fb807f27a,
+                    0x0da8c252767ff21d,
+                    0x0097d61b705b2c53,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45f2f7824fb71760,
+                    0x82272d02b5783381,
+                    0x3c2cf715836665d6,
+                    0xeaffdb62da2bf0f9,
+                    0x58d9d64a2d647a97,
+                    0x0023471ad8b000ef,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x1db9cb6d9a408ad3,
+                    0x777cbf673032bc46,
+                    0x2b01d00e64ac7a97,
+                    0x0170ce7f75c526ae,
+                    0x03c939fed91dc208,
+                    0x001817166f216ba0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2dbd228f10cef237,
+                    0xf8dd837a866066c9,
+                    0xa1d90ecceac904a4,
+                    0x2816f8e0a8d77726,
+                    0x8dd29fc79c4d1f87,
+                    0x005873bc4bd6e6c9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d76953140671c9c,
+                    0x8725857f40006b07,
+                    0x387febab7af7d5d0,
+                    0xe7cea02ba4d053b6,
+                    0x45d376029e37e2a5,
+                    0x0170236955a6461a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x110d70dfa7cb0644,
+                    0x7b02a3a53792afab,
+                    0x81e585d83969bf9a,
+                    0x1e1b85484da559ac,
+                    0x3eef60c195507c96,
+                    0x012ce8e902d3dea9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x26a3f604107b7eb1,
+                    0xa8ded1d2470a1b67,
+                    0x69173d4bcde1975f,
+                    0x76274288407ae7f4,
+                    0x2049de9d83875f05,
+                    0x00ca6a5f1087db8b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0321bb10735f753e,
+                    0x43aa3520a4af9d43,
+                    0xeae08d0019829f5f,
+                    0x1ebf45127c409125,
+                    0x3060d154fc27a6ff,
+                    0x0009aaa89fe63c4b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcccaf4abc6cface1,
+                    0x9074704cf29cd91d,
+                    0xe39c2add4083ba27,
+                    0x42511fc4b4d4f42e,
+                    0x1094e880e71e0e1b,
+                    0x00c850959c5acd52,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7992181850360e9a,
+                    0x2819cd71c7876b77,
+                    0x95d335ad36ea8155,
+                    0x0ff4a0b68bc80922,
+                    0x3b5d2968aa5d7e22,
+                    0x00eeaca348697b7a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xb29cae0718ed95e0,
+                    0x1c5ca8551233cef1,
+                    0x139dabdbd19e09e5,
+                    0xd13c5d7ce085dadd,
+                    0x033663148eaab228,
+                    0x01860ac4a9977ec9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96d49288ade1650f,
+                    0xef679244f8cd5aff,
+                    0xb5dd16bdd2bc4a35,
+                    0x19b5d342e6f1bcc2,
+                    0xa95f993663e8299a,
+                    0x001f793e3d3014e6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0aa3625884acdd1e,
+                    0x92825391cff9144a,
+                    0x7d988410b235a5b4,
+                    0x99dd935b3ed97518,
+                    0x0247580132d292e4,
+                    0x009d8db5bf34cd6b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd6d09e16182e6e2f,
+                    0x817d478b79fc716d,
+                    0x5b9decc4e54f0e11,
+                    0x73af3f930d1d89c6,
+                    0x865ea058073d9e42,
+                    0x01adaf9e7777f131,
+                ])),
+                Felt::new(BigInteger384([
+                    0x61face4df0df0aac,
+                    0x21a052556b20ee16,
+                    0x4e67319fa51e6c4b,
+                    0xe40a073bb9da8cce,
+                    0xf2ab5c1c563b2ea5,
+                    0x00be831bd2e3a3a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf6ccebcef507b791,
+                    0xd48c9de5cd07c00d,
+                    0x77999c5a5e4e1475,
+                    0x96fb2731340b2635,
+                    0x8324d79a1927e692,
+                    0x0040ee66cc2245aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7cc36024f761f7cb,
+                    0xee0f7b51b41b5482,
+                    0x7cc20bc18898c252,
+                    0xa49ef310387e7c47,
+                    0x7acdde1347ecd304,
+                    0x0124ce28db157b94,
+                ])),
+                Felt::new(BigInteger384([
+                    0x39d85a3c63c72579,
+                    0xc61a93e5b66e2d49,
+                    0xf03daedf8878d0ca,
+                    0x87da82e08f2205d8,
+                    0xc8e593c803fe164c,
+                    0x01a82d7d1e3aea7d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x03fff4c9549aecfc,
+                    0x5aa88d4cf457a653,
+                    0x939483ff6bdd3b9e,
+                    0xdcb485293256e9d3,
+                    0xfde207470d7c372b,
+                    0x00a925fd36b398cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x217fb375532b3520,
+                    0xc883d6409ce16098,
+                    0x5b5a574f4c3ac203,
+                    0xa260ed3099aa7154,
+                    0x3af1cb7ebc872f39,
+                    0x01828876eefffe85,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98a1fd74618d991c,
+                    0xe193cb628d6057f7,
+                    0x54a2c7e9b7bd287b,
+                    0x2b2ce22f5f9071f8,
+                    0x9863cec8fc20d2b8,
+                    0x016c04873db8ec33,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d75e21545831a52,
+                    0x09304823a032b808,
+                    0x340503768526734b,
+                    0xebb20f0c387f5c55,
+                    0xcd0e594618a65135,
+                    0x01783d5dfed4193b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5002fcc2430f483e,
+                    0x1a805a397ffa1362,
+                    0x592465c41c059541,
+                    0x93a8bda20197f0c4,
+                    0x4178ef004783092f,
+                    0x0119005f79f4e1f3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe32ccc73bff50c56,
+                    0x713eafaf1626d80c,
+                    0x93de8084c6a72dba,
+                    0xb32ac94baac02395,
+                    0x98950311566496d1,
+                    0x0115da7e66b3ff08,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe68065c5369b9f6f,
+                    0x794caeab24c09dab,
+                    0x29f8e9639b7de3b6,
+                    0xb23fe2628a711eea,
+                    0xe0b2865d84ead663,
+                    0x00092f8274ea7f05,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53acc47a1f15085d,
+                    0x9239945924a2c4b9,
+                    0xef1af1ac854306cd,
+                    0x58cf6a8078b6619c,
+                    0x371655999a81b715,
+                    0x014851985be9e9f0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x2932ecd19017d796,
+                    0x3430105410f1fa91,
+                    0xbb6161287910e373,
+                    0xdc90f1a81f0be0d0,
+                    0x31a5fc878ed11f3e,
+                    0x011750ab6b2031ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0x25441f250758eb72,
+                    0xfc92fc2236d3846c,
+                    0x8e89bf0fd4f25a67,
+                    0xf39342e7831cb289,
+                    0x65e2a8473a480122,
+                    0x014ee6b3bab34f59,
+                ])),
+                Felt::new(BigInteger384([
+                    0xac75b94b65f1ab30,
+                    0x0319418429349490,
+                    0x94a0065a60f33a07,
+                    0xfea203e708d5a494,
+                    0xa350b212eb9ab697,
+                    0x0194313a5fb5e225,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb9efbee8963faf4,
+                    0x5ee4864f32ac83ec,
+                    0xd2c8618825868d3f,
+                    0x4f568ad1ce221260,
+                    0x917b94a14145cc31,
+                    0x0000277a85d61900,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf647a030d2c4affa,
+                    0xcc0acd90aca1ff7b,
+                    0xbffdaccd0287615b,
+                    0x2335d7c92d882703,
+                    0x11e9b44f061f52b0,
+                    0x00c43855e5255fb7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1669cffac5746cd0,
+                    0x44feb2a2303dfd48,
+                    0xbd90d8d8a00dec50,
+                    0x5f88cddfc4dde205,
+                    0xbf61b48a8188b882,
+                    0x01034c20455c79a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x01f7137b987a214d,
+                    0x3f79e5a1cc5f310f,
+                    0x2d854dec43cf4ae1,
+                    0xd22aef36f5dc3f03,
+                    0xc7dd14b9b9b95c68,
+                    0x001202c3538169a4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e02559b7d5bbab4,
+                    0x6672266c21c6629f,
+                    0xb890b4c144386676,
+                    0x6f51b54dccb35a49,
+                    0x4a9ed2cea16abd21,
+                    0x01a0e2cd6aa65c51,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x819770466bd12fb7,
+                    0x9a5be327522511c5,
+                    0x93c9df21cbf3c4d5,
+                    0xd142c86167362743,
+                    0x887a52c953a4ab2a,
+                    0x009da2cf9feacb43,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1fb33df1c2d71e4f,
+                    0x9c0df3888e7131a2,
+                    0x4a5ec1fe5e96f1da,
+                    0x2d57c0bbf31cf114,
+                    0x051565292372d555,
+                    0x00b485526c7c201f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x63e60702b50d971e,
+                    0x12ceb2b34dee25db,
+                    0xe0a41c6f601dfce3,
+                    0x7e3f0fbd37a1b4f8,
+                    0x722c69515f2e4042,
+                    0x014f08f219cdd8da,
+                ])),
+                Felt::new(BigInteger384([
+                    0x361bb67239f676a8,
+                    0x0f4174c99b4ba3bf,
+                    0x5ad5ef0364728068,
+                    0x3252b1e4b8385d7b,
+                    0x3962c73b8e091366,
+                    0x00d7a1cad5aff8a6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfade632ecc50247a,
+                    0x8c0e5f6357b3695b,
+                    0x51b24741b09ab8ef,
+                    0xd97a16ca154a955b,
+                    0x57dd083d3414c230,
+                    0x00b4cb933a694039,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc5c00cd55b85e278,
+                    0xcc8b17e74269d9c4,
+                    0x7419c0898179e110,
+                    0xdfcf1fcd7e1fe735,
+                    0x67ee1238e91552d7,
+                    0x015a4021058608df,
+                ])),
+                Felt::new(BigInteger384([
+                    0x798222d8ac7f5496,
+                    0x5ab6e842c4135610,
+                    0xe40e034c99f7a65c,
+                    0x163a9ac6029f6072,
+                    0x7e53b4ca56b7d98e,
+                    0x00bbe928aefa77a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x99799f183bb81772,
+                    0xe396b730984b5850,
+                    0x378a57ff51e36a95,
+                    0x0de5c4dbf1279c5e,
+                    0xfbe3f272a5a3fa02,
+                    0x007efede7cf47349,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x2fbb0976adee2dbd,
+                    0x6e7f64f147bbd129,
+                    0x03544decc5344fa1,
+                    0x24c5b6a1663da78d,
+                    0x9474bab0651d28dc,
+                    0x0107da932509bd75,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd1a20fd98bf5414,
+                    0x0ee37879ee207aa8,
+                    0x6d1cc6e0214d0953,
+                    0x5e1d751545f76b85,
+                    0xddf641b8239d264f,
+                    0x017da046d12fd155,
+                ])),
+                Felt::new(BigInteger384([
+                    0x64ea1bce673a9e8e,
+                    0x655b6c24f21fc0e5,
+                    0xa95a3ad08fc410ec,
+                    0x9568cd3c3a11877e,
+                    0xb038060fb0f5ff9e,
+                    0x015c76619239317e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf2b7d12859f89376,
+                    0xf7310418de0598a0,
+                    0xa3f77a3fe43989eb,
+                    0x1a78c1db195cf850,
+                    0xd735ff9a5790cd8e,
+                    0x010eeb90e7a57290,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf6cb9bd92dc981be,
+                    0x9d4de1e982ee11df,
+                    0xad5a4cfaea1299c0,
+                    0xfd63d953c733605a,
+                    0xca177f7980b86285,
+                    0x01ac1bf981d003d1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6312c890bb7cc626,
+                    0x4a7c0d2ccd22cb0c,
+                    0x18ca8c6ffe33b65e,
+                    0xc0939e926fd85348,
+                    0x83aca345944fb0d7,
+                    0x01358cead73da37b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb84702b68b0d6a2,
+                    0x7d137100331a72ae,
+                    0xe040d9935a95f7e6,
+                    0x3fa4bf086f6f7fb3,
+                    0x8cbc14d2892f14df,
+                    0x00b135e70fb38055,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9fcfa75490b1908d,
+                    0x79d91277a2a71d1f,
+                    0xd58c7f1a4c8a705e,
+                    0x12018c1d5aaffab5,
+                    0xc9b8595d7807ed77,
+                    0x00d63a551c2e6834,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf6cbffca39976bd5,
+                    0x1a608933f0c0e294,
+                    0xaef14e335a13778d,
+                    0xb058900990376bcf,
+                    0xdd08dd052a6557a8,
+                    0x00356e48cdec1e59,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d0913995866f5c9,
+                    0x2e093ee0a7d28cc0,
+                    0x98d36ea89aa384f6,
+                    0xaa0f5b0bf62a726d,
+                    0x67daac06dda60252,
+                    0x006ba8f64ee665b3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9dae678825c23044,
+                    0x5ebf6cbd49c4cb30,
+                    0x9b7146c828fbef48,
+                    0x71dd51621166838a,
+                    0xe4c27731e5d6d423,
+                    0x005c5840bbc46667,
+                ])),
+                Felt::new(BigInteger384([
+                    0xde90184d57e8ae34,
+                    0xe6687c5389e21e53,
+                    0x8e1d2af72f63094b,
+                    0xa28314e9357588ef,
+                    0x6a16a462985586bf,
+                    0x013ad24141e5cf4c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc80d0e4ae533b4f1,
+                    0xa474660dfb29107d,
+                    0xbe0a6c269ef8af75,
+                    0x70a049196f718e61,
+                    0x51d6f3af580c6be9,
+                    0x013ea881b90f7b1a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc4936e1348fea032,
+                    0x3b2655789cdc092a,
+                    0xe19a2aba7c6e0bd5,
+                    0x12e75ae7fe0082f6,
+                    0xadd2ecc5b279ca7f,
+                    0x002055bd2f94bb95,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaeb02fe096e3a1cd,
+                    0x6db2c5d8a94307b2,
+                    0x0e93bed080f09059,
+                    0xbfe6e42b9b0b625d,
+                    0x0466de221dc28d86,
+                    0x00ba34c09d0b6ff9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0352294a09303717,
+                    0xa8271bf93985a5b8,
+                    0x9135c36e707c4a17,
+                    0x1cb3c8c51ced8a16,
+                    0xe8ba98d92abbe003,
+                    0x00cc9ed8128e2cbb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb5a2100393c158fc,
+                    0xf601ced41c750718,
+                    0x5b31acc6bf7fb20d,
+                    0x4ca0cdf2ed61b320,
+                    0xbc50da353d22150d,
+                    0x007d69abf3dc6cf3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc9dc136b7033fbc4,
+                    0x5cdf31214ba836c6,
+                    0xaa823b54c1ba2ae2,
+                    0x69d1b8be35619ae9,
+                    0x4cc537affb7827e4,
+                    0x014de9cc2e7798a2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x12256cdefe69738e,
+                    0xf51c6bbb42fea433,
+                    0x6f6e3393b521ac1a,
+                    0x6c2b7bf887b188ad,
+                    0xeac8a3fd3f643c52,
+                    0x0165ee2a9b48e744,
+                ])),
+                Felt::new(BigInteger384([
+                    0xddb0061e644be815,
+                    0x7f3e85eb230f792d,
+                    0xeeba1d9f6a376bd8,
+                    0x271bc9a017090a43,
+                    0x8cbcc9406fd8462e,
+                    0x003946c43982e461,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8bdeb41c3d671b7b,
+                    0x1cc30ee3aab0ea34,
+                    0xa2bb2c6c37b04407,
+                    0x55516a64b797625f,
+                    0x32d22324fb1b67fb,
+                    0x01a93dce687ed8de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x73fd04395e15ed1c,
+                    0x3d25971e2e6e88ba,
+                    0xa48c17b3a52fd7a4,
+                    0x71c7fd61cea30806,
+                    0xa213efb05e992a87,
+                    0x009c7bec6df24030,
+                ])),
+                Felt::new(BigInteger384([
+                    0x693b708f906a203d,
+                    0x5f8429b11d498150,
+                    0xd28338834860dda2,
+                    0xa55935eff73dc3eb,
+                    0xb03a9c45dc274e93,
+                    0x000a23a7594d593d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52cad084572cefd2,
+                    0x7f8b0f6e50ef6a4a,
+                    0x6ee6ba2117c74613,
+                    0x8072cea0e7086159,
+                    0x960fc50fa7584da4,
+                    0x013e305bbf6d1b8f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xff6038958f54772d,
+                    0xea184a57b46bb8d9,
+                    0xb8ca34dcda83c64f,
+                    0x789576eacb87310c,
+                    0xe38e97d804df289b,
+                    0x00683e736460906a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd8d469297ed48af,
+                    0x796bd5632f6627cd,
+                    0x9a6ca5366a7468f4,
+                    0xe915fe401e65bbc8,
+                    0x1954e5714d1de381,
+                    0x005483f903140273,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc2ab1654138d10a7,
+                    0x8113c018ca8b92b7,
+                    0x080c62a7c7e002bb,
+                    0x517e352e2c245c5e,
+                    0x22798655240128ca,
+                    0x0007bd1b318dca22,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf558f6d316a56794,
+                    0x749242e439155e25,
+                    0x852120a12601cf50,
+                    0xf7821c5b9400e585,
+                    0xda28f8d9bf673bec,
+                    0x01959b9c5083e0d3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7bf3bde25e4f569c,
+                    0x871fc4bd2559b885,
+                    0xf1bd52b11b643c70,
+                    0xc5fdc0fda72d59f3,
+                    0x1035990f8c3be110,
+                    0x00113b62de983a2b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x20778d2002851820,
+                    0x23c539eaafafbd19,
+                    0x85dcfaf036a742b0,
+                    0xee018a88984a0986,
+                    0x584f55ac84c8acbd,
+                    0x00f647abe577bd9d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d809e0d10263099,
+                    0xe71fa46cd1301665,
+                    0xb17c84cdf2db7d84,
+                    0x00ae6d678d83a9b8,
+                    0x424cdd1b68bfeac9,
+                    0x0102f35765237224,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9627b7cc12cc33de,
+                    0xd008b92c50bd2a80,
+                    0x1320d503f05b963d,
+                    0xef441cf4b57b584f,
+                    0x00a5701fdd5b3670,
+                    0x0027fe0c8bbb7496,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9ea3920561389d77,
+                    0x618bc9284359ff01,
+                    0xc05c09ed22087dc9,
+                    0xd63be7790a472858,
+                    0x0dc96ca48d69a853,
+                    0x0073185df063aef9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x15ea34772fa380aa,
+                    0xae8479d473139041,
+                    0xa5905da9947779ac,
+                    0x06c7cae35315c7f1,
+                    0x61e9f1a74f671339,
+                    0x014c618da67385f0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x69a25267c57c6c63,
+                    0x837bcfc74058b357,
+                    0xd703298e533a7c23,
+                    0xff924d98fb43a1ad,
+                    0x5c2186f088ff89a3,
+                    0x008de78344eb19b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe4aa7cbf253d4a53,
+                    0x7de922237e682975,
+                    0x87f73d7ff8dafa87,
+                    0x22050da997edfd0e,
+                    0x7ab1b664850cf202,
+                    0x00fdb6767ae67c86,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x28cf012899e54b2a,
+                    0x5dd57dcc1f315c60,
+                    0x720c388c9e789df4,
+                    0x521e20a370f80a37,
+                    0x2eca3662e55ef2c7,
+                    0x010cc5c10cb3177d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2287bc9386cf19fc,
+                    0xbb0ffa04fd590528,
+                    0xdbf5945ccad0d8e5,
+                    0x99c83ad17f6676fc,
+                    0x404dbec1285751b4,
+                    0x01716e674da7e38e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7054492a934661fb,
+                    0x5967f760d75a5996,
+                    0xee5e228f30ee4f1f,
+                    0x99c85b0375e46b98,
+                    0xfd105756dc9ebb2f,
+                    0x003bfc3c3966b0b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x24f74202910f890c,
+                    0x2cde367130ec5b2a,
+                    0x1deaea95840a4248,
+                    0xb883c53a81d9b06e,
+                    0x96e19d029a4239fc,
+                    0x002b043b3cd20143,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xf8ff2d971d21024c,
+                    0x98546c257e0a5871,
+                    0x7841a67a399d6324,
+                    0xc8697e439f001193,
+                    0x87ae078322adbf7f,
+                    0x0152adef4555df3c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x049294e6d656358e,
+                    0xe8333c6249cd3106,
+                    0x9242f6cada101a92,
+                    0x176de49e1e9736de,
+                    0x5987ed3bdac63d18,
+                    0x0065f4cd07413cdd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd5f96e5ed63810e5,
+                    0xec7b2ceb57b70eaf,
+                    0xb425df35508e76bf,
+                    0xa6c21e020551f29d,
+                    0xb567082522f368b2,
+                    0x01424035b43c82ac,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe1baa39e412b685a,
+                    0x2d0cb6f5a6be9a7a,
+                    0xd4fe22031a921e5f,
+                    0x8c5632f5d7d091f0,
+                    0xf46db1133d049119,
+                    0x0165540352f7d1ce,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0b2d9702f5df545,
+                    0x856f4984d1edd23e,
+                    0x4fdd8b4188d22e76,
+                    0xe983627fbd59d30a,
+                    0xdddbfe002e7daf17,
+                    0x0120e617761084d1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1e17c1b48f501c05,
+                    0x90a93bce85d6a6cf,
+                    0xef287e69d389ee6b,
+                    0xec96f73043c54e0b,
+                    0x5b761c778a4369fa,
+                    0x007308a42eb0eeee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4162eb0cf7eb7d94,
+                    0x01b96272cc7edfcc,
+                    0x32f05e1bb8c4d425,
+                    0x34e6e12895d88b56,
+                    0xd45917b81763340d,
+                    0x01083aaa57d2ec2b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd99901bf86679b8d,
+                    0xf5703901b5ed2e71,
+                    0x6821ac3daa847fcb,
+                    0x3a6d8c1864d0ec06,
+                    0x90ad414ff418baaf,
+                    0x001a2fb061d7eea4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x04bc0137c3085f4b,
+                    0x05da0b23800ff671,
+                    0xf948a9c79fb90a2e,
+                    0xd7797e64aed6be16,
+                    0xf8ee26102366a5ed,
+                    0x00b29904eaea68b3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x854566883eadb367,
+                    0xa5c049708c9bb456,
+                    0xd68ee95cb794774d,
+                    0x63d78f4c71ac6b66,
+                    0xc486f7fab53b0394,
+                    0x01147ebafa3a846c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92d1da8590253f6a,
+                    0x95ec145f2269e864,
+                    0x8bc4ab95d2c29c8c,
+                    0x8214fbf7c752d0ff,
+                    0xb47643dcc0deb908,
+                    0x00489285c3f98f67,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd547f43f2b5d47c2,
+                    0x7546da7b51540fdd,
+                    0x8e2e30d2e8dc521a,
+                    0x9666178852cd8ab4,
+                    0xbac048570d46c1ac,
+                    0x017136e4aba12374,
+                ])),
+                Felt::new(BigInteger384([
+                    0x742aadac23ed489d,
+                    0x708717d4c732c596,
+                    0x694f987412842334,
+                    0xc0f4d1ddc9387a11,
+                    0xc2aa0d01ca145853,
+                    0x00b75602d5687d8c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8decda1688efce2e,
+                    0x2fafa7275a545ff3,
+                    0x6c901b5b4946ab0a,
+                    0xc21eada3bfc49439,
+                    0xcf056b59eb1fee4a,
+                    0x00cd0428c251a624,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8ef15e8cb26ca84,
+                    0x3f646502f5388a73,
+                    0xd80fe35d9b3fbe97,
+                    0x979bc75a17d0a9df,
+                    0xdc4fc84f20d32cfe,
+                    0x00de2e77c6a9b55b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd5d3f4124e673f7d,
+                    0x1bd4ff6af028e3d8,
+                    0x767c0296debdcd4f,
+                    0x21dc7126b40f52ac,
+                    0xda85c2e5a507c5d9,
+                    0x005f10a38768cba8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x90e283d87a81b212,
+                    0x1e097c40bd39d720,
+                    0x77983250ff9d6a67,
+                    0xe00b33092dd9f2a6,
+                    0x38c0755d18a6ab99,
+                    0x006225f98dd9871f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd3014108792bb200,
+                    0xbfefd03140b7a522,
+                    0x0083ed0b09b54baf,
+                    0x4953f2cc40fd1a1c,
+                    0x307ffb11316bfc91,
+                    0x00c51b40fb5ccfa5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaacc44ca5abe1c45,
+                    0x7eef48d329fd8bb6,
+                    0x31b18c98b8b4cd56,
+                    0x7ea8742ffb778651,
+                    0x2cc586fbb60ae4a1,
+                    0x0180b862676dfc99,
+                ])),
+                Felt::new(BigInteger384([
+                    0x39c7c5da4a605432,
+                    0xfd0012babfb6225d,
+                    0xe194f7f3c2bb8da8,
+                    0x96daeacf0012e9f5,
+                    0x606a32da045d3a14,
+                    0x007da2fccba18fc4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x543bb552f7c33a16,
+                    0xa9ce63c011932b9d,
+                    0x6c25bdb4316c3ab2,
+                    0xf99c19efcdc275c2,
+                    0x8a7e2764793555f4,
+                    0x00d4778feefbec52,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9940625286dfbedf,
+                    0xd8b2a3cc24fc54c8,
+                    0x93e0672538043c91,
+                    0x7aade45f70ce1960,
+                    0x7bd7ceb8d2dcbe1e,
+                    0x01871a3144180feb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7239636e79ebb42e,
+                    0x1c155913bed2043a,
+                    0xb9fc10726eb58b37,
+                    0xad7b1fcbaa4dc31b,
+                    0xcf4f5caf249f42e1,
+                    0x008ae2a5e7691a97,
+                ])),
+                Felt::new(BigInteger384([
+                    0x46ed07739d4aeccd,
+                    0x3e703b9794fd1f4f,
+                    0xfe6851adc1ec1734,
+                    0x057b175b518396a1,
+                    0xb859500fa29bc489,
+                    0x00734d47f0bcd325,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x9d111d56721e7526,
+                    0x9d20af81a796e058,
+                    0xcecff93c70baa6dd,
+                    0x33ed0bb90979df28,
+                    0x12fd2d723bd112a7,
+                    0x018c1431c6aa4052,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc8e24f824472466b,
+                    0x44da35217b2caf7c,
+                    0xfbd142aa48956afc,
+                    0xf0e8c76a4ad66b30,
+                    0xab7f9fac97e4d2e6,
+                    0x013350060e7c907f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9e5e6d19438bd493,
+                    0x5022150af875bb3e,
+                    0x18f8ce96e4d5898a,
+                    0x43d471e31a467fda,
+                    0x37799c8aab4cda67,
+                    0x001a57fa9267c46f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c4edd09b6665599,
+                    0x59aea7de3810a8e9,
+                    0x00edca64f76be66f,
+                    0xc873ab491f0aaba9,
+                    0xed36d923c3666765,
+                    0x0122dbad8ecf6c3e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x48206bd93550c609,
+                    0x46f1b5896567e7d3,
+                    0xcd5c37f8511290dc,
+                    0xe4a4e386a0ecd0f3,
+                    0x22454c6a4cab41d2,
+                    0x01772c7a8f087d4a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9475beea6f7022fd,
+                    0x7927e9f1416c0a67,
+                    0xd5bf85e9c70e7057,
+                    0x60acd243122ab881,
+                    0x971f59b7d5f1afe9,
+                    0x0034230e0343ddd6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x86f81a80d7aa05c6,
+                    0x2bcab2ae04280580,
+                    0x80d3647a302b5e84,
+                    0x4b4e32f57c4c59f1,
+                    0x52e41d536cf24460,
+                    0x003ce7500bf2bce2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdfe8bdc599354f2d,
+                    0x48f9437cb38ffab5,
+                    0xd4e4d34ce39f9955,
+                    0xaf25e010d58abdff,
+                    0x2ed93f37b3ae10f5,
+                    0x000bf0a0b6a5298a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x3034ea1fc13d7d7e,
+                    0xb5def2de199b1f57,
+                    0x93facfe94cbf6397,
+                    0xfa32a0429849e78c,
+                    0xf0e3d1c336355861,
+                    0x014eb9e728aac2bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c19f60d56d20579,
+                    0xb83ff9f0646820e3,
+                    0x4724016ded4ac099,
+                    0xa6e73cf266c17047,
+                    0xc61526ab7692e126,
+                    0x00f263ed0df2b2ea,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe97f6872d581b334,
+                    0x1b17f4c4be165924,
+                    0xacb5519c8774286a,
+                    0x43284d1b32e42118,
+                    0xe051ad12ef938808,
+                    0x004118f3126aa027,
+                ])),
+                Felt::new(BigInteger384([
+                    0x15c0730b19a087ed,
+                    0x9dc9bfca7b10cf6c,
+                    0xa2cf4a803b3883f1,
+                    0xb61e2814a6f55abb,
+                    0x7557dfc8176b5329,
+                    0x00f6af3ddcb69fa6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x04dba48fda72bc53,
+                    0x00e43dc9617e219a,
+                    0xcd2dcaa5adf186d1,
+                    0x1497661396c36259,
+                    0x8d507e7a36ea0056,
+                    0x000733037899541a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc0794c80190d0648,
+                    0x76e4741efe5d94f8,
+                    0x702aa19c6393deca,
+                    0xfae7085608459351,
+                    0xf526e5db42df22c0,
+                    0x00936c8e9863c1f9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x08ea315d11c264a2,
+                    0x415d4b4d852451b7,
+                    0x05f45d3836bc0a19,
+                    0x6fa61f30c48d0bb1,
+                    0xc1fcf5ecc7afdc30,
+                    0x011010d29caa6939,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6420c11284fe0796,
+                    0x6e2cf46d664b1c1d,
+                    0x27b33135fa97623b,
+                    0x25ffa3c78e579e11,
+                    0x5f737a59d06543e9,
+                    0x006cada76f49beb7,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x9d53de84ed0f2a3d,
+                    0x504b128e00bd3f5b,
+                    0xe818ccdec6ac5b3b,
+                    0x7b89b81dcd815043,
+                    0x84d0b1882ab08035,
+                    0x0171d5087b3c9d42,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7d115f773a6e1644,
+                    0x47133065c2261448,
+                    0x5d76de1369438611,
+                    0x3eae7e6f9c43879e,
+                    0xc49111da20b6a07b,
+                    0x00818c0d7dc2fe17,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3747aa68cea0fe35,
+                    0x19316a01cf35199d,
+                    0x73d8fa9422c42625,
+                    0xb0b4743628805950,
+                    0x62a74f0758741636,
+                    0x00ab67701452a30d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2561f4b4e9e5589c,
+                    0xe8f00965fc91f2b9,
+                    0x745b12c0f616cd3f,
+                    0xd7e1fe24f0289a06,
+                    0xf626602826731b48,
+                    0x019cb9211fe577ff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16ff67b188ff75bc,
+                    0x5f0ae0f359417187,
+                    0xf6fe9836a1cd4d56,
+                    0x971a85f2a7ad2c44,
+                    0x4038f8d56d892a4b,
+                    0x0163fb9f1c3ef0d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd27d52c570fbc263,
+                    0x45eba8aca4be5773,
+                    0xb186bf914fde79f6,
+                    0x1101252cf70b289c,
+                    0xc58f73e2140e92ed,
+                    0x00bf705a3c397e3a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5b1c4c2e290ec0da,
+                    0x87333b033b9e05ec,
+                    0x1f2033771197fed3,
+                    0xbbad1e90f102d6bc,
+                    0x006b635447b5af5f,
+                    0x0025aaf6790949af,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf3d0af43624b553,
+                    0xc812d0f96518e7bf,
+                    0xe515ea9471314001,
+                    0xb8df6859d73f28e1,
+                    0xe99b0cd4d1221f63,
+                    0x00ebaf41cc7a7151,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger384([
+                0x4a65976b07e0b3d3,
+                0xabd89320a601a557,
+                0x7fdc057de66c63cc,
+                0xfe7f06e7fdb96bc3,
+                0x6d62f691b7fe89e1,
+                0x01946840408e1105,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xcb20fbe9e2a8af11,
+                0xb09fb805c5d26cb9,
+                0x3e0b6db91b542863,
+                0xdb580f25ff4106ee,
+                0x17c2856e3cfc833d,
+                0x010ca49455656e02,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x1b0ad3390fa94015,
+                0x768653e13c3a58d9,
+                0x525fcc6b930cad17,
+                0x54a5776dcc48d728,
+                0xcd819ee019966ba3,
+                0x008a077b02b7a8d6,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xb99a1588ce462e06,
+                0xde6940144acdc712,
+                0x207ab6f052ca4dbb,
+                0x45ff76ff2475ea3d,
+                0x269051afe6635ab7,
+                0x013797918078c27a,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xdd14ccb4c46b697c,
+                0xda28157ddb1029da,
+                0x51e912622fd06c50,
+                0x45af00b9b3857eee,
+                0xf721087d1749197d,
+                0x018bdd2525fa867a,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xe5f97f2fc9a80b5d,
+                0xcd14d82733f374eb,
+                0xb8849946bf989e74,
+                0x9c57ad448ae739ae,
+                0x70b53f5a2c9edf39,
+                0x003078913cbd989a,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xe9194e795d7660fb,
+                0x406f5800d479e778,
+                0x2eb665719d52451d,
+                0x86ae8a1c420cb4e1,
+                0x6bbf875b37b29d8e,
+                0x00716585482c396a,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xecb7edc705cea4ad,
+                0x6cfc7521dc34795e,
+                0x9e0f8ebca8e65692,
+                0x5a404d5601ff1574,
+                0x5ea34dea7d194bb1,
+                0x0187a855be7b2296,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x45ca73cee80c1df9,
+                0x6ffd9985165c5a3b,
+                0x07a69bea1cb7c527,
+                0xf74d2daf531af628,
+                0x96fa71cb71caf2bf,
+                0x01294619d17ae8a9,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xf716f761f0010f9d,
+                0xe4de26e5f180445d,
+                0xcd94de2ff4172d66,
+                0x4f116871709087b5,
+                0x4ee3b6d2aa10a108,
+                0x00a3dd57024b701c,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 282, 283, 851] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 282];
+        let mut opad = vec![0x5cu8; 282];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
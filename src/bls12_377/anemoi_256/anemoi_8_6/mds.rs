@@ -0,0 +1,142 @@
+//! MDS matrix implementation for Anemoi
+
+use super::BigInteger384;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [  1  16  15  15]
+/// [225 240  16  31]
+/// [225 225   1  16]
+/// [ 16  31  15  16]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x93b43ffffffff67b,
+        0xa0d125e30ffffb0d,
+        0x5d1a4faa05a59724,
+        0x323b39b7e2fcce8e,
+        0xf0223f35e4a1e060,
+        0x006f42bfb905f50e,
+    ])),
+    Felt::new(BigInteger384([
+        0x15eefffffffff714,
+        0x669be3a3bffffb5d,
+        0xdc8ffe3035319f32,
+        0xd10f7bf375757f17,
+        0x6968af36d106a4b2,
+        0x019016a3edcd115f,
+    ])),
+    Felt::new(BigInteger384([
+        0x15eefffffffff714,
+        0x669be3a3bffffb5d,
+        0xdc8ffe3035319f32,
+        0xd10f7bf375757f17,
+        0x6968af36d106a4b2,
+        0x019016a3edcd115f,
+    ])),
+    Felt::new(BigInteger384([
+        0x878f3fffffff7a1f,
+        0xd78e9a21cfffba6d,
+        0x5a13e866ab6fabf2,
+        0xec2331ecd5707421,
+        0x1c22f970bb32ee79,
+        0x01985e0bba0228ab,
+    ])),
+    Felt::new(BigInteger384([
+        0x18757fffffff7132,
+        0x271f20815fffb5ca,
+        0x17b0846726980325,
+        0xa30fd3ed49f0dfaa,
+        0xbf50a2e71f9849f1,
+        0x017a3a69900a291f,
+    ])),
+    Felt::new(BigInteger384([
+        0x93b43ffffffff67b,
+        0xa0d125e30ffffb0d,
+        0x5d1a4faa05a59724,
+        0x323b39b7e2fcce8e,
+        0xf0223f35e4a1e060,
+        0x006f42bfb905f50e,
+    ])),
+    Felt::new(BigInteger384([
+        0x249a7fffffffed8e,
+        0xf061ac429ffff66a,
+        0x1ab6ebaa80cdee56,
+        0xe927dbb8577d3a17,
+        0x934fe8ac49073bd7,
+        0x00511f1d8f0df583,
+    ])),
+    Felt::new(BigInteger384([
+        0x878f3fffffff7a1f,
+        0xd78e9a21cfffba6d,
+        0x5a13e866ab6fabf2,
+        0xec2331ecd5707421,
+        0x1c22f970bb32ee79,
+        0x01985e0bba0228ab,
+    ])),
+    Felt::new(BigInteger384([
+        0x878f3fffffff7a1f,
+        0xd78e9a21cfffba6d,
+        0x5a13e866ab6fabf2,
+        0xec2331ecd5707421,
+        0x1c22f970bb32ee79,
+        0x01985e0bba0228ab,
+    ])),
+    Felt::new(BigInteger384([
+        0x02cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x008d6661e2fdf49a,
+    ])),
+    Felt::new(BigInteger384([
+        0x93b43ffffffff67b,
+        0xa0d125e30ffffb0d,
+        0x5d1a4faa05a59724,
+        0x323b39b7e2fcce8e,
+        0xf0223f35e4a1e060,
+        0x006f42bfb905f50e,
+    ])),
+    Felt::new(BigInteger384([
+        0x93b43ffffffff67b,
+        0xa0d125e30ffffb0d,
+        0x5d1a4faa05a59724,
+        0x323b39b7e2fcce8e,
+        0xf0223f35e4a1e060,
+        0x006f42bfb905f50e,
+    ])),
+    Felt::new(BigInteger384([
+        0x249a7fffffffed8e,
+        0xf061ac429ffff66a,
+        0x1ab6ebaa80cdee56,
+        0xe927dbb8577d3a17,
+        0x934fe8ac49073bd7,
+        0x00511f1d8f0df583,
+    ])),
+    Felt::new(BigInteger384([
+        0x15eefffffffff714,
+        0x669be3a3bffffb5d,
+        0xdc8ffe3035319f32,
+        0xd10f7bf375757f17,
+        0x6968af36d106a4b2,
+        0x019016a3edcd115f,
+    ])),
+    Felt::new(BigInteger384([
+        0x93b43ffffffff67b,
+        0xa0d125e30ffffb0d,
+        0x5d1a4faa05a59724,
+        0x323b39b7e2fcce8e,
+        0xf0223f35e4a1e060,
+        0x006f42bfb905f50e,
+    ])),
+];
@@ -0,0 +1,2291 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 384 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 6 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 6;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 16 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 16;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x139314962fa78077,
+                    0x3e2e6fc030cf5bc3,
+                    0xb8aeb2dd2a8a86cb,
+                    0xb74c52235a0feaf3,
+                    0xc10a5bac67331e83,
+                    0x00a0f6f730178ac1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x67a9f17ae5330a4e,
+                    0xa18c2a43ece79a43,
+                    0x7bb7c1311a92534b,
+                    0x1204edb30b3df051,
+                    0xe79611ee6ad6bd21,
+                    0x00da3432b35d7979,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa21ad24f93ff5e61,
+                    0xfb5ed23fa62fd270,
+                    0xa70447ddb05ef37d,
+                    0x13d908224be7b6fa,
+                    0x5a2d0ceebc022381,
+                    0x00e88f7b4069b556,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdbded89f3ab54819,
+                    0xc3815cd4644aff53,
+                    0x9ec21e778409c3f1,
+                    0xb875894ed11590c1,
+                    0x87b45d5238d93b41,
+                    0x00dbbbc76807f94f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ac0366e6878df52,
+                    0xc9bd45fcde6656fb,
+                    0x43da04ef2d99796a,
+                    0xad452486789ddf60,
+                    0x3a34f73df6d01f11,
+                    0x00361fb7f7a5146c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b8f203cea00873a,
+                    0xb2e884a4feb7dce6,
+                    0x43fac410a3ce7c17,
+                    0x4a6e6a35f59f0fa8,
+                    0x2f76313b207d30c4,
+                    0x00e1c09c137dbde4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f316c85b54cd630,
+                    0xf9f6706f7d052fd2,
+                    0x82ab283a36a7f413,
+                    0xdd77006d7bee80b9,
+                    0x01a3992a1070b56f,
+                    0x00aad8da3b13ffb0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbcb0e4ad00ceefb9,
+                    0xcde48ebb5588d679,
+                    0x51cc0385a2b685ec,
+                    0xa3fd7dbe196d0c28,
+                    0x837ac6029d314414,
+                    0x01704b136f35bb6c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x81b19822e3ba1665,
+                    0x85bac49a27474b1a,
+                    0x91f08212bac01b6f,
+                    0xe9f5aa2c176c25f0,
+                    0x99822e210d31e80e,
+                    0x00d8cbc8b69965a6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13ea0bfa65db4c97,
+                    0xed389aa88c8b84bb,
+                    0x76231e6b1c92db29,
+                    0xbec04bf9eb5a434b,
+                    0xe70779ee843f619c,
+                    0x00507735798c9e70,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40a45cb077364d91,
+                    0xee539c1086664d95,
+                    0xd6c60555ad7aeb2d,
+                    0xa2e3bcf333f320fb,
+                    0x1f803d8e85958dd1,
+                    0x00d7c88857d82b42,
+                ])),
+                Felt::new(BigInteger384([
+                    0x91f56d834a122936,
+                    0x0d55bb92cb2f3883,
+                    0x901e194cbb92d9c2,
+                    0xb291a0e689013f9c,
+                    0xdb333644f0198ad7,
+                    0x009a62079a897fbf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc55fc6268bf93ff8,
+                    0x7a2b43e61998a55f,
+                    0x5deb4901d54ffb20,
+                    0x46986c0c9c85080f,
+                    0xda3eb31cbd5999b9,
+                    0x00ee04235bc3081c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea52e608b660caea,
+                    0x587b6abd1921bc94,
+                    0xeb02ba6919351a49,
+                    0x73208d24c841e12f,
+                    0x88a462da3ea4314e,
+                    0x00f0415afe8739dc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x89dcb35d31d2baa7,
+                    0x8b35fdc69a5c32bf,
+                    0xe85715bbcebd312f,
+                    0x75e076dac528d1d1,
+                    0x19ecdeb1dafea299,
+                    0x0075d37fb2f6539c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc73a9dd7b278ebc4,
+                    0x8ec71f13b350d982,
+                    0x639d919df8a2a70d,
+                    0x9ce97e749910f7fc,
+                    0x13b50902b076e06a,
+                    0x00b3ed6cbd09c224,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6a177a5ad509ca48,
+                    0xb804a32d699659cb,
+                    0xe9cf7a012e3cebc8,
+                    0x38918da6b1aed92f,
+                    0xb2b1fd59b83aa8cd,
+                    0x01aa6b5929bab68b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x233c64598f4b6fd5,
+                    0x8db864b5b2f0ca93,
+                    0xd9c3cff4ebc31a16,
+                    0xa1eb434da20985b5,
+                    0x5943663fc66f7ff8,
+                    0x01225357b3526c96,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcf7d4404de2e4653,
+                    0x2133f4ded3f6e752,
+                    0xb62d7e408d4be374,
+                    0x096471f2145cf34a,
+                    0x6419043b0e2ef3c5,
+                    0x0128eb1ca72d8381,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ac79ed6f218c2f1,
+                    0x23aab2f11b053f62,
+                    0x80a1c3e59b95b8c4,
+                    0x5b22fde458b0b690,
+                    0xe29a7d87178b043d,
+                    0x00102fb88c5c1291,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd69cf742d2183441,
+                    0xe7486ecf635b33a3,
+                    0x1857757ad4b3dc2f,
+                    0xe0062c16ff9ba95d,
+                    0xc518d985bf1bb826,
+                    0x0091042c621102a6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x06033df16700fc50,
+                    0xb83fcfb4250ebaff,
+                    0xa5a1738d546e837b,
+                    0x807ae512c213098f,
+                    0x4b9642b7cffcffaf,
+                    0x0146e5c90bbfee9a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9c852ab820633b2e,
+                    0x676257a544978cb1,
+                    0xdcf428ffc4dfabe9,
+                    0x60e1ab98bd6dd3ae,
+                    0x182921ca4f36bd0e,
+                    0x00324f46e30c8cc4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0856c9a5f0b688f8,
+                    0x3d1a416f1bc20223,
+                    0x839c3eda160d5bb2,
+                    0x8d1896b06040c299,
+                    0x270c943a3c02573f,
+                    0x0125929f1d17269a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfa8bbbd4d7e0cfa8,
+                    0x8da48b1fbcbcd9cb,
+                    0x7466a0523980ff97,
+                    0x7bc6ed09ceb24b9e,
+                    0x54268bb339e5278f,
+                    0x015954018e409888,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc2c8adecc97476d,
+                    0x1a35f5dc58ad7c9f,
+                    0x7215c499f4f8f9d8,
+                    0xbc323ff430ed3a83,
+                    0xd02aa064cee65c4f,
+                    0x0036370bbf72c257,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa17fc0aa3a79ed90,
+                    0x45cc2efe69108dfb,
+                    0xb0b734fb8448a636,
+                    0x158ce4c39a88f11d,
+                    0xd2cba9afff8ab521,
+                    0x0077e62b66eef2c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe397d8792c22e13c,
+                    0x8d7e31916f76cab8,
+                    0x0470970d6df5a339,
+                    0xdd326f31821aa1e2,
+                    0xe4f355dc8f8fca2c,
+                    0x006e0ba64c644774,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c3bf6864d68ffaa,
+                    0xd472060b2e003603,
+                    0x37fa9ce8773a7eb7,
+                    0x8ece015918bd3373,
+                    0x862e0ec54824b72b,
+                    0x012df428e1740111,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe8b232addbcb3ca3,
+                    0x05a19d4e8166269b,
+                    0x7743ea8d109d15eb,
+                    0x4cbd7e4d7a3d57ea,
+                    0x2cc7b7b7b94c3e4e,
+                    0x0049ac43b78d50d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5189a8b584a4aa74,
+                    0xf6b92fdad36b4d12,
+                    0x09f583dcdd0719fe,
+                    0x35fc5457d5e8d616,
+                    0xb0f34527da124977,
+                    0x005ca9fcccd08ddb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb98b3f2d7cf97a1f,
+                    0x7167aff29fb67b1d,
+                    0x6cf2dbccf154cfbd,
+                    0x6c8a3d26df8c3bcf,
+                    0xab376bc50ba079a0,
+                    0x000a21ada1923475,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc4e853a651e408b3,
+                    0x1a12b3b906b6f376,
+                    0x384a2c812d0bcc9e,
+                    0x67ac79d17e86659c,
+                    0xfdb7eb8be122f9a1,
+                    0x014a3ebcc7dc6276,
+                ])),
+                Felt::new(BigInteger384([
+                    0x500a216c1cb37299,
+                    0xbc300906ea592f3f,
+                    0xbe40d17d037c1815,
+                    0x0259814176be3479,
+                    0x590681d1fa5055c0,
+                    0x01755e118e3883c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeb8ef2c4e9322c1a,
+                    0x128f98a8424fad3c,
+                    0xb8c24be22431ecd4,
+                    0x171ca5ee0660a285,
+                    0x9c5bac1565a445ca,
+                    0x00ea02113635c904,
+                ])),
+                Felt::new(BigInteger384([
+                    0xede532a10a4f8c38,
+                    0x8f28906e361d3738,
+                    0x6e1105c8221e2350,
+                    0x214506fbc3d75867,
+                    0x561e18d85d050772,
+                    0x000c37c4ec68032e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71c151db09fedf61,
+                    0x076791354cc6c4ab,
+                    0xe1da5640a6f35de8,
+                    0x4a05d43e0d7e8798,
+                    0xcc9ee046f8fc06e6,
+                    0x0069bb5421b427fa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa3233826b44adf57,
+                    0x1e50a48e8346533a,
+                    0x9032e858c897a834,
+                    0x36ae729556f959fd,
+                    0x06d37feabc7a0d5b,
+                    0x01804d17c2f3c58d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xce889638063901f2,
+                    0xdacb691fe698f62a,
+                    0x0db735f021ff6b4e,
+                    0x936cf22ecf14c51a,
+                    0x8ca033970bbbecc4,
+                    0x01a6da1bf9968d0f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc61276cc169e4f34,
+                    0xfb00b3d60ec390ea,
+                    0x6438d39c8a1da0e9,
+                    0xf396b386236702d7,
+                    0x661850e158d37a9c,
+                    0x00de67caa71c3225,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa7c2424d7eddd57e,
+                    0x26c9178e72744e51,
+                    0xea4c5a83c9b56963,
+                    0x0b31856cc1b0c588,
+                    0x61b490ba39a55675,
+                    0x0198d8b7e7234a28,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca1e7f2002222015,
+                    0x9f30021ca0faf6d9,
+                    0xf77760122bacc3e0,
+                    0xc50e6e97c488ed32,
+                    0xed9fdc2e8f5744f8,
+                    0x00fd9de2618acdee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d6c1b17f7c7a124,
+                    0xfb635ea823e68d21,
+                    0x01826afe30a28080,
+                    0xc52d4261ea2ccff9,
+                    0x7ce4c5ac95b9c2ce,
+                    0x00454657a4c8e813,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcdb3867e15617b13,
+                    0xcfe18d5118f785a2,
+                    0x0d5b4bf22ef796da,
+                    0xc2b3616e695467ed,
+                    0x55e8f97b4770e7f8,
+                    0x00df0cf83da9ce9e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcbe0aad903ad9379,
+                    0x65369d0630af70a1,
+                    0x5051de7505a94ee2,
+                    0x7f38652f021d72a9,
+                    0x96c3fe21e868133a,
+                    0x0089578f06d16a2d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe748c40ba6f98269,
+                    0x650b8186ea6f384a,
+                    0x2c386456bd6efd8f,
+                    0x533db7de066c99ed,
+                    0x9ca05d8879b925bf,
+                    0x00a03edb4d3d884b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd87fd248acf4782b,
+                    0x47453d44214c8386,
+                    0x71d9dc57f38b563a,
+                    0xafac195358b06268,
+                    0xc355be08ef738f6f,
+                    0x01149005927cf8db,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf45629f176060894,
+                    0xebbb501c8f5ccca0,
+                    0x20ac707f94b1923b,
+                    0x1e6cceaac1ea9adc,
+                    0x113aa5f2ce69e2ad,
+                    0x01584a8680d203d6,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56dcddddddddddd4,
+                    0x2db2015f37777772,
+                    0x8a5a595c4be8b110,
+                    0x2041bbb36e056126,
+                    0x7e422da67ad9b5fd,
+                    0x007c276e8cf025e2,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59b3fa50b6287770,
+                    0x8d8cc2314dc632d6,
+                    0x29b1a14daf4e27f8,
+                    0xedb8547b775eb818,
+                    0xd8e661a68b195234,
+                    0x0105b860faad5b24,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa578190a443f6866,
+                    0xba8dc4fbbf9b29b6,
+                    0x7eb0b8ad3adf4bee,
+                    0x878939a768b75022,
+                    0x5a0c806ddc201082,
+                    0x00b44682633de315,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f83be968f6712f4,
+                    0x7a14ed2c157a3e9f,
+                    0x13379f76f22125ab,
+                    0xd2b0fd505a8223b8,
+                    0x90580d58fcb34c3f,
+                    0x0073a0b34fa4fca9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af47e2abade4fc8,
+                    0x1a826133f52d710b,
+                    0x6c3926ec49ea6ba8,
+                    0xbfd567223db99dfb,
+                    0xca056d88e9e47a82,
+                    0x0036eb7a315a0db0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea911dddddddd44f,
+                    0xce8327424777727f,
+                    0xe774a906518e4834,
+                    0x527cf56b51022fb4,
+                    0x6e646cdc5f7b965d,
+                    0x00eb6a2e45f61af1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+                Felt::new(BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x0120d3e434c71c50,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8abdae1c49698ff2,
+                    0x9f5e105f86ee3740,
+                    0x802b92982c745cdc,
+                    0xee3619eaa1490112,
+                    0xdce99b9b57f96994,
+                    0x01acc24d05afa09a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6e2299a06da7ae68,
+                    0x7390ecaedb5e6904,
+                    0xbe51b36d316ceae5,
+                    0x26321f7bf314eea3,
+                    0x73214567e6d32e80,
+                    0x002f2421191e983e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d91281c60cac45f,
+                    0x36f656ca840e85fe,
+                    0x9e96ee39ca23aa8f,
+                    0x663c265b381e4460,
+                    0x3575f6fdb31ab71a,
+                    0x00e106272410cab4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa006f86134727585,
+                    0x3c42f6b7ada8a2ad,
+                    0xffb78e512fc2561f,
+                    0xe2308f3e82fe4a0d,
+                    0x4d090fd6db910992,
+                    0x0088c9e07feb5c78,
+                ])),
+                Felt::new(BigInteger384([
+                    0x67a58d168f09784d,
+                    0xcd47fe0f8a6d6a3d,
+                    0x2c64d2a9bd492288,
+                    0xe8139919aa0370a4,
+                    0x2053d7ae564bdfbb,
+                    0x00a1454ffc271c80,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeeeaf021344b8b96,
+                    0xb8ec1adae2394eae,
+                    0x5e87f4ae2461a864,
+                    0x454605eb8d762a70,
+                    0xf6b6f19d131f20cc,
+                    0x0119b065d4eda3d5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa72eee06d5985128,
+                    0x7dceb8645cd41560,
+                    0xf4da5785811b8e8d,
+                    0x56f781b30e19e932,
+                    0x961cef3afd9fc9cb,
+                    0x00cc3685c130536e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdcbf4f7c90dd7a1f,
+                    0xd972fcdbd9fbb97c,
+                    0x03e861516463cf51,
+                    0xe849651210604724,
+                    0x194902f2e3cec1d5,
+                    0x01a280d6fb8b1ffa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4ea0a636b617e1c5,
+                    0xca5e910af4d7e1b9,
+                    0x44413760730c1edf,
+                    0x093cb0952a5dede7,
+                    0xac1edfa1cce2925a,
+                    0x009fc32096f9b622,
+                ])),
+                Felt::new(BigInteger384([
+                    0x672ca5c4a1bfd68b,
+                    0xaabf944ded22c4ed,
+                    0xe7a60b61ab66e21a,
+                    0x53198ff23ed6c4f3,
+                    0x20204bd5027af790,
+                    0x006edc6bbfb1d9ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c7aaa829f7e93f5,
+                    0x67075a3d46dd706c,
+                    0x16d2269da784277b,
+                    0xb6ba273d4f9c6dfd,
+                    0x79c697da11289e09,
+                    0x0044d663d356a467,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf3d8372d97254299,
+                    0xeff056ad2f75808d,
+                    0x3e94f8e6b3785fe6,
+                    0x6eb1050f8e95a480,
+                    0x7e1cf39cc55a1592,
+                    0x006cd925de39d443,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfcab6533e316a594,
+                    0x154b932f2ad81890,
+                    0x15cf8ee331ff4ff6,
+                    0x6e38ad85e262b150,
+                    0xad7e13cfd824ed12,
+                    0x002318d457a8aaf3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c7dd64d12ef9bef,
+                    0xa0a881371d2e881b,
+                    0xb16d1523a2556d55,
+                    0x20f6e11191c0a3c2,
+                    0x4ee523830873b058,
+                    0x00fd076935694827,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5b0a2afbd74e4382,
+                    0xeebd26e0f71f6bd6,
+                    0x4b943490084e9812,
+                    0x86435223f690cbc0,
+                    0xa11cb810f7f8986a,
+                    0x0105bf7d380d2c5e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2054f6fb385c0a44,
+                    0x34ac2ba14192b705,
+                    0x74535dd02af37db8,
+                    0xad92d46771a2351b,
+                    0x49a5182b11835175,
+                    0x00d329a501a32486,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x57d4d1755df2c1eb,
+                    0x2b8b480a56a8111f,
+                    0x068e0981b3e2ed5b,
+                    0xa572e2ce67c33a02,
+                    0xa5f83c29a6451365,
+                    0x0136a604bf1a0d67,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa90c0f7dfdcb8828,
+                    0x0f936d1c8c6284eb,
+                    0x97f31cec38ce87fa,
+                    0xbd7b1034e6fa9be3,
+                    0x6580e05fb408dbcc,
+                    0x004ba35eb038ec7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x864d58915e4eab72,
+                    0xe45e5a40907f9eb9,
+                    0x3474125160280a19,
+                    0x30afb711d2cabf7c,
+                    0x76fdff68436b9719,
+                    0x001d482e369dd038,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5021163b22361dc2,
+                    0x52b941de058c583e,
+                    0xf7ef7933bca70c6e,
+                    0xacc20f3b00057456,
+                    0x43c455a0b08646fb,
+                    0x015e71caa4ec09bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xde08368d0923d8b1,
+                    0x824b6863601e783e,
+                    0x37f721e4043ea4c4,
+                    0x042a3d16be38691a,
+                    0xb0d56c62023415bb,
+                    0x007775e28b95f979,
+                ])),
+                Felt::new(BigInteger384([
+                    0xef5d3024af922458,
+                    0x634be472b1542ddf,
+                    0xb751b05176c7ffd8,
+                    0x86cd85080358f54b,
+                    0xd3682181a178c4c3,
+                    0x01210aa713bff7f1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27d8a7de430c2a49,
+                    0xef0ffb3f1f108644,
+                    0x56ef3180f0527adb,
+                    0xdcddba13aa6cf9d1,
+                    0xf0ae74e7813d4d33,
+                    0x00b2892a93d56a82,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbb903b4935835117,
+                    0x532a19d2295a2a7b,
+                    0x0670e2584430c493,
+                    0xd746ee750f01b24a,
+                    0x7f93e97c015fbacc,
+                    0x0127100d615cbdf5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2503191987ce8024,
+                    0x37bd5ccf1fe620fa,
+                    0xb7a23bd1e9d6e0b4,
+                    0xf22f10c043955fbf,
+                    0x0553cc15ffdd90ef,
+                    0x0095f5577819ccf1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f62dc44dc385174,
+                    0xc654c4e9d8b2e005,
+                    0xffb4c53a701a16b1,
+                    0xcd8a67842c9d6546,
+                    0xe06dbe407e6d2770,
+                    0x00326c3e61229432,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb1701bae1c43772,
+                    0xfe59305cf41f0229,
+                    0xf7935b41a6d1b6e2,
+                    0xfc90e0af4e0c8707,
+                    0xb08cf7339163a138,
+                    0x014dce753fe75e64,
+                ])),
+                Felt::new(BigInteger384([
+                    0x419a1d193bfcc24c,
+                    0x946944147f6fec05,
+                    0xbd5aa4d4b85244d4,
+                    0x8b90c9ed51e79fcb,
+                    0xadcae6f4764ed442,
+                    0x01a89c002f3066c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea94e0b8448ca74b,
+                    0x711f8379a9859521,
+                    0xdce87919f54cd644,
+                    0x470ed00c7d409257,
+                    0x7e40c21abbe44b80,
+                    0x00f3bdc22c899bdb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1fe8864da0dbdf45,
+                    0x1a91cfcc21a23cf3,
+                    0x06788cf79fb9129f,
+                    0xfd11b8c299186686,
+                    0x708a6e56ae343e80,
+                    0x0006e13f2610288c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0043b7bf44858a0f,
+                    0x991277cf96f647e9,
+                    0xd3fbfdfab56c81d9,
+                    0xca8c851f885abfad,
+                    0x191588325453bd41,
+                    0x006daa1b68cf9a0d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8d33cb9bc20a9f57,
+                    0xc929fa4682f08663,
+                    0x4f3a79d7f6916edb,
+                    0x584daf3b9e27a8d9,
+                    0x378d613fb05d9376,
+                    0x0093ed2b22dd79cf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x52417318c000eeaf,
+                    0x4074b248a3e6616f,
+                    0x2a925c56c938da15,
+                    0x23cb4bf8a807b810,
+                    0x9b99e399521f65d7,
+                    0x0116f2db48061127,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ca90957c8990e5e,
+                    0xe630eb82c356a877,
+                    0xf929d48b641a0f02,
+                    0xc482da0baa605f4b,
+                    0x59bbcd1a67ac8529,
+                    0x00482d83e53ee986,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17619fc10cf2f78d,
+                    0xc9752fa8e7cd9713,
+                    0xbbbc6cb69911af96,
+                    0x266469c073beec29,
+                    0x5bbaaf0315eb702c,
+                    0x00eb3d18fa807378,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9f3bc1d14d017838,
+                    0x2ed7e8c2bf2f097c,
+                    0xdbfb81da71ba87be,
+                    0x231212d6812ecbbf,
+                    0x4da7347b94b7a065,
+                    0x0092d82996aa26ef,
+                ])),
+                Felt::new(BigInteger384([
+                    0x185fc8568111323d,
+                    0xbecbc3e9cd8b5206,
+                    0x291e22220b8d7f93,
+                    0x194f5c69c036f06e,
+                    0xbba31e77cc241b06,
+                    0x016a67aacf46c678,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa4fc2baef91f97bb,
+                    0x16b2161bce29dddf,
+                    0xca108fb560e77941,
+                    0x22f8c3555eb9981f,
+                    0x8ada21f8ba64b597,
+                    0x01635ecebddf7903,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4419a36923506da7,
+                    0x98ab6698b6276c99,
+                    0x3efa1201cef74d1a,
+                    0x0f30f51cf6c3e208,
+                    0xb10005dfbc9ee1c3,
+                    0x00c6398f9219e340,
+                ])),
+                Felt::new(BigInteger384([
+                    0x590a9cb5940a4f07,
+                    0x63de8e0f2fa29b96,
+                    0xf41f2358c403d96a,
+                    0xd630fdbd4d20163d,
+                    0x9a83c718b4bf8aee,
+                    0x015a9a3c60de5f6d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6e6c540bb7c4c36d,
+                    0xd37861ead1d5ccfd,
+                    0x33e07a8506ffb293,
+                    0x5209975821062e5b,
+                    0xa835e913ce8f8839,
+                    0x00cff7571dc66a59,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb88f96b955ed91e,
+                    0x4316d6927876a16c,
+                    0x4423824b494158ba,
+                    0x6dcb1269fe399ad2,
+                    0x75249d521c3170bc,
+                    0x0074aef566c333fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3acb954a6426644c,
+                    0xf238476c649d95c5,
+                    0x2b66a1b7cb22e898,
+                    0x63463a54b43b3df6,
+                    0x44efbee036ed55a4,
+                    0x015e371278b26bff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x03b092ef2251b4e9,
+                    0xa359af6ac6be24f1,
+                    0x4ff219642431865a,
+                    0x22916b6a45e42e24,
+                    0xdd5c73331c508335,
+                    0x012de96775e492b9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x37463cd952f24169,
+                    0x14b4def5bf8968eb,
+                    0x12bcd911808e665f,
+                    0xc4f4c6df0c068646,
+                    0x8562735ded6e50f1,
+                    0x0090fc4a2a3f97b0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6e1452926ee0d3c5,
+                    0x94ceb0a89a2cd950,
+                    0x6b9b23fbc3a4b8e8,
+                    0x961e1eeb4283f691,
+                    0x2689d093bab5337c,
+                    0x012efd189bdad767,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca4d11f56a22c76f,
+                    0x4907d34a0247f3f5,
+                    0xd6a187260ecfe759,
+                    0x43e0838d0e858157,
+                    0x01a68e7ccfbaaeb2,
+                    0x0126072e23281826,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2fcbec30504961dc,
+                    0xc04b78473fc90a18,
+                    0xc9f4f778e72d14d2,
+                    0xdb49153dba441d51,
+                    0x6c72ffa5f88f2130,
+                    0x003a547c24d78ee2,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfc592ac0894a955e,
+                    0xcd1a9fc82db31738,
+                    0x8cdab71442ef0f20,
+                    0x7764e24fd35fc27b,
+                    0x5b5b7f7b037fae9d,
+                    0x011676972168f58f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba4a5ed321f71909,
+                    0xaac622c167776513,
+                    0xf19ab36a665a8dd9,
+                    0xc357c941eb4a68d0,
+                    0x678b6f0d5d6720a3,
+                    0x019c743e3eb2c45c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd9449e37709cea34,
+                    0x95d105ef7013e17e,
+                    0xbf677ec7c86fc496,
+                    0x8d6a01c65088a39e,
+                    0x2cbab93f4b69fb73,
+                    0x002314929506281f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcaa5b1f39d13c431,
+                    0xf29c2ff86246a9a6,
+                    0xba55c00a0071cd79,
+                    0xc78592dc2e2aa46d,
+                    0x56d218fe33938cb7,
+                    0x0148a2961e6aac64,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd493f4cc91dde03,
+                    0x39f4c491b26bec24,
+                    0x5d35dcbec0a2b455,
+                    0x784ed5714d1faa76,
+                    0x1e1f3918d02b8baf,
+                    0x0125507290f0a8c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c852ea5100255c5,
+                    0x80b945d21ad75fc6,
+                    0x30f525ba134c1a3a,
+                    0xd3f62bf55c2ed719,
+                    0xc9e771a2a913b8c2,
+                    0x0144ff4e76368b39,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8aa7376b68eed24,
+                    0x2d5f4ea1b2914b14,
+                    0xc23debb0f0fd3c31,
+                    0x99ffd1d0eabf55c7,
+                    0x04b697b4aa587f9d,
+                    0x0184b0ecedbd1684,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3e4258c2cb858eb0,
+                    0xf37cb0b69df6eaef,
+                    0x692acf1e25647f4d,
+                    0x578331418bf5e8c1,
+                    0xa70b14fcf81a7734,
+                    0x016378f5684993a4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x55d9e6c99f542551,
+                    0xa0998e628b9ec039,
+                    0xb94ebe6c7599c46e,
+                    0x9193fddab4217b2b,
+                    0xb9b53c7ad200e460,
+                    0x00c32c0318acfc82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5e6b2fccb58331a9,
+                    0xe773415994aad741,
+                    0x8d48c93c79ef2ba6,
+                    0xfdccb53db1e01d68,
+                    0xd92fd5295a23c19c,
+                    0x00f7f1f6f71a59a2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7dddda8a88992dc2,
+                    0xb385b2e0bb44760f,
+                    0x5cb90ac54a423255,
+                    0x7cc915e4bd589fed,
+                    0x8736d12753ca7ac1,
+                    0x0195273a26c896c1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc8670ae9e07deef,
+                    0xf0ef24cb04ab7681,
+                    0xd23d8482674f2e98,
+                    0x8a95a17428a79c5e,
+                    0xeb79746b0d06cd0b,
+                    0x0145528d9acd6d06,
+                ])),
+                Felt::new(BigInteger384([
+                    0x48fbd33938a2043d,
+                    0x58e4c61625127914,
+                    0x9300caeb0e2302f5,
+                    0xa9e9f0d0208e210c,
+                    0x51b01a5dc7b9ceb2,
+                    0x004652d20120d8b3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x054ce3e8087de887,
+                    0x89dd283cabb94c63,
+                    0x48323edcf2015b8f,
+                    0xeb6784c2386600be,
+                    0xab6e507c2111ebd2,
+                    0x015030a31af9a385,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8b87fe53c2a13d6b,
+                    0xe6a2c607fe583921,
+                    0xf6a767fb8192ca65,
+                    0x91b628a4840d053d,
+                    0xc51738fa5f4a98db,
+                    0x000f6af7efe745d5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x460b7da6676c11e3,
+                    0x2207b71980899417,
+                    0x67dc25e2148ed89d,
+                    0xe283c71e36bf8c25,
+                    0x633fd918a2a71b8e,
+                    0x00203c1f7c13a17b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x661c4c7023066210,
+                    0xf7703fa2d94123c4,
+                    0x53082211eb3a52b0,
+                    0x6ab3b10814ff217a,
+                    0xe262a73f70a1e737,
+                    0x00f8417afaa9b2a8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x485ea278a8ff978e,
+                    0x57e300bd6e13a89e,
+                    0xb63289a0a9cdbe6b,
+                    0x87fae94ccd28b1a8,
+                    0x2fbd18e09da03198,
+                    0x0096c6b5b06655e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb7bc62076528572a,
+                    0x82639ad2d91729d3,
+                    0x77e6d797faffcca5,
+                    0xdac41066926cead2,
+                    0x8ee5a4f3c50bcb1c,
+                    0x00c7d7708b948e88,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa3b4898280607331,
+                    0x71c79836e9a38a8f,
+                    0x8789a007036aba86,
+                    0xbe4c9c17b6e06863,
+                    0xfa01e7841f58663e,
+                    0x0049f9857b3b22b4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7b7115f29f709880,
+                    0xadd80cacec47edab,
+                    0x0f3a334aa39f9540,
+                    0x1eb014f57685042e,
+                    0x9d2e70378912960a,
+                    0x018de9fd40ec9a8e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4f2842eebd213670,
+                    0x3f62ae43d845bcdc,
+                    0x328e59262c8740fb,
+                    0x30a96dd4cd7e7fdf,
+                    0x8c58d59fdd61d734,
+                    0x01310fab57011ccc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfbae4cf7c286d0bf,
+                    0x0da851cf52588b0e,
+                    0x762cebf08bb3d571,
+                    0xc3c632cc28022e4e,
+                    0x4695bcd8beb3b56a,
+                    0x000e8976744f3b3c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7376a0218b8e206d,
+                    0x5b71c6ab5857242a,
+                    0x2441fde58f7c8f5d,
+                    0xf31c49002d0e5c0f,
+                    0x15eadb0b4a8574b3,
+                    0x00b127ea49f41c82,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1eb2102aaa9ea471,
+                    0x904f60f13b948d95,
+                    0xf6b574cb0f8357d6,
+                    0xfb22c95d759789a4,
+                    0x06eb893655e7f25b,
+                    0x010a5d1501c8d918,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd5ad85bb5c186d76,
+                    0x80871c214391418a,
+                    0x411a39ed3810fe9b,
+                    0x5ae5b4e2a6bf89f8,
+                    0xe35f49bea4220672,
+                    0x010717a87519ae47,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb6903f904b4756d,
+                    0xe7fbbd344d59a4ad,
+                    0x7c86d4df0b5199c1,
+                    0x1d15c9add1d2ff1b,
+                    0x41eccc523dbf5532,
+                    0x0068c53f9f7664e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f5c48f8de810523,
+                    0xb423775e44a4b97d,
+                    0xdcd71579d1c42e46,
+                    0xce2bcbfcccac2476,
+                    0x7fa4b1f22f6dfc50,
+                    0x00f7e9ac94cb2b5d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfa9f538749c81675,
+                    0xd1ad24d37ecb76c8,
+                    0x9cde04c914961c70,
+                    0x1bbebc76f049dbf0,
+                    0x15d80d8e5d2881c9,
+                    0x00bb5ea7f13008c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeca75849c9f56126,
+                    0x57156fa9cbe26d53,
+                    0xdb775f3f285f17e7,
+                    0x0a5dae3656c6b6d5,
+                    0x9f943c923666bedf,
+                    0x00675aeddf4c0772,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21c8e7bd9f440249,
+                    0xf078a8ec88bd5e46,
+                    0xc8aab2883f02886b,
+                    0xfb2e3283fdb2a822,
+                    0x1655acfa67e4705a,
+                    0x0071c2c507b97557,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba10e7981e451628,
+                    0x5fc9fb71f8f3c40a,
+                    0x48d6b856b2c3eb63,
+                    0x6f8924651ee4a551,
+                    0xf0e78c8036e3945c,
+                    0x00e42c78b69a9aad,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xbbb0d70a386364b9,
+                    0x4996c703b06bac99,
+                    0x5a3365c9c91fb0a9,
+                    0x6f755f2c98820042,
+                    0xac86761d00984e67,
+                    0x00a52c07df1fdc10,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55e961c5e1c284fc,
+                    0xe1e1d72149d6bee2,
+                    0x2092c61279013321,
+                    0xc5b9ff96d0fa8cb5,
+                    0x1c2fbd0fb5f6a71a,
+                    0x00eebf128b7abae2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa532731f0004aae3,
+                    0xf1fd35c8a077c128,
+                    0xd2912ba4ebf91f90,
+                    0xa3ea1057412705d3,
+                    0x36f39b72f2ddaf42,
+                    0x003848c1c0c2f1ca,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe0327edd7ac8973d,
+                    0xaf0ea1a992a4a62d,
+                    0x4cc0a309da1a6171,
+                    0xb604ca1f658585ce,
+                    0xebf7f10fd4accc36,
+                    0x01ac3db6c7c7f9b9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x476fd339cd3c2e83,
+                    0x23d018f56e7e5b3b,
+                    0x910b5de79fbd52c1,
+                    0xb836c4c30fbed575,
+                    0xe3faeee98aa86155,
+                    0x01953608ad4cac03,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6398e1736ad7d11b,
+                    0x11a5b60a63490fb1,
+                    0x9b9c7275919a5ed9,
+                    0x139086f754972781,
+                    0xa8e8e2680535401d,
+                    0x00b2ca097be431c2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf8cb8b06c06cca4,
+                    0x136a276b95d9bf40,
+                    0x42900e60e369d9db,
+                    0xbc4ee7243627b01e,
+                    0xbeb68fb1dc209a0b,
+                    0x0117269801914cf7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae55cba6300a1de2,
+                    0xf1c694b5877996d8,
+                    0x626390b2c9dd30b2,
+                    0x6147db06915ffc0f,
+                    0xe5e036aa68735f04,
+                    0x00fe3b3a68fdd097,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1f070b51fe930aea,
+                    0x59010a4f4a87db92,
+                    0x47d5900ea6b667fb,
+                    0x0d405dcac1499307,
+                    0x6df2f60b5a5610ae,
+                    0x019b560d09414bbd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee052aaa4dc35fdf,
+                    0xefe1aa955efba59f,
+                    0x8927d994128ee77e,
+                    0x2daa4e3bbb7b4680,
+                    0x878b9a3945a0d123,
+                    0x013e74bddd00085e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3c90d1cfe5b07e66,
+                    0x6928ea8bf65dbb28,
+                    0x82297dffa0dad3f3,
+                    0x20ab9d22626a7af1,
+                    0x14aff5ddc87a9872,
+                    0x014d618aef738ad4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3ce90c88a81f71bb,
+                    0xa50f72cfa7b21b43,
+                    0xd8d2e84c55885632,
+                    0x30af5df45288bf90,
+                    0xb96d8c51df777929,
+                    0x007f3b3b882f6db2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5df1748322d4da7d,
+                    0x933d0cb0bcb3cf4d,
+                    0x335ad7478a71ec91,
+                    0xdf6eb4b00e4f9bae,
+                    0x2e75bc0257e9bb19,
+                    0x01983c3cc12785a4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x159a1ed2e46c836a,
+                    0xd84ee8d083531cc2,
+                    0xa2f382b37f6e7076,
+                    0x8dd2c6b8703cf9d1,
+                    0x7cf2a20a28b72c89,
+                    0x017d21fd86d3ae43,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc0f193d80f170bf,
+                    0xa0014aac97bba81b,
+                    0x750743c85048f5ae,
+                    0x6a17efc73dc4b90a,
+                    0xdc5f8b97012fe635,
+                    0x01804efc32f5d698,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf625a56adcdce354,
+                    0x04f6a4801ef22998,
+                    0x81c7ea71b6e4a593,
+                    0x5034905e70b1c3b0,
+                    0x5d5e87b6a006685d,
+                    0x0071dd3b6b328b3b,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger384([
+                    0xb84ebfffffffe409,
+                    0x9132d225affff177,
+                    0x77d13b548673857b,
+                    0x1b6315703a7a08a5,
+                    0x837227e22da91c38,
+                    0x00c061dd4813ea92,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e41fffffffecf58,
+                    0x61c9d2407fff61ae,
+                    0xabaee3c2e468a493,
+                    0x7650676457f13552,
+                    0xd26fb8b92f31c22d,
+                    0x007685c662961a87,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9b8f3ffffffeea1f,
+                    0x73183f21cfff6f98,
+                    0x72d90fc172ef9efc,
+                    0x518a8162fa6ff2b8,
+                    0xe8e6bc560201afc6,
+                    0x00d0f0ace07e1929,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdce93fffffffd197,
+                    0x81947e684fffe7e1,
+                    0x928826ff074173d2,
+                    0x048af12891f742bc,
+                    0x16c2108e76b05810,
+                    0x011180fad721e016,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb84ebfffffffe409,
+                    0x9132d225affff177,
+                    0x77d13b548673857b,
+                    0x1b6315703a7a08a5,
+                    0x837227e22da91c38,
+                    0x00c061dd4813ea92,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e41fffffffecf58,
+                    0x61c9d2407fff61ae,
+                    0xabaee3c2e468a493,
+                    0x7650676457f13552,
+                    0xd26fb8b92f31c22d,
+                    0x007685c662961a87,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9b8f3ffffffeea1f,
+                    0x73183f21cfff6f98,
+                    0x72d90fc172ef9efc,
+                    0x518a8162fa6ff2b8,
+                    0xe8e6bc560201afc6,
+                    0x00d0f0ace07e1929,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdce93fffffffd197,
+                    0x81947e684fffe7e1,
+                    0x928826ff074173d2,
+                    0x048af12891f742bc,
+                    0x16c2108e76b05810,
+                    0x011180fad721e016,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0xb84ebfffffffe409,
+                    0x9132d225affff177,
+                    0x77d13b548673857b,
+                    0x1b6315703a7a08a5,
+                    0x837227e22da91c38,
+                    0x00c061dd4813ea92,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e41fffffffecf58,
+                    0x61c9d2407fff61ae,
+                    0xabaee3c2e468a493,
+                    0x7650676457f13552,
+                    0xd26fb8b92f31c22d,
+                    0x007685c662961a87,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9b8f3ffffffeea1f,
+                    0x73183f21cfff6f98,
+                    0x72d90fc172ef9efc,
+                    0x518a8162fa6ff2b8,
+                    0xe8e6bc560201afc6,
+                    0x00d0f0ace07e1929,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdce93fffffffd197,
+                    0x81947e684fffe7e1,
+                    0x928826ff074173d2,
+                    0x048af12891f742bc,
+                    0x16c2108e76b05810,
+                    0x011180fad721e016,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb84ebfffffffe409,
+                    0x9132d225affff177,
+                    0x77d13b548673857b,
+                    0x1b6315703a7a08a5,
+                    0x837227e22da91c38,
+                    0x00c061dd4813ea92,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e41fffffffecf58,
+                    0x61c9d2407fff61ae,
+                    0xabaee3c2e468a493,
+                    0x7650676457f13552,
+                    0xd26fb8b92f31c22d,
+                    0x007685c662961a87,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9b8f3ffffffeea1f,
+                    0x73183f21cfff6f98,
+                    0x72d90fc172ef9efc,
+                    0x518a8162fa6ff2b8,
+                    0xe8e6bc560201afc6,
+                    0x00d0f0ace07e1929,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdce93fffffffd197,
+                    0x81947e684fffe7e1,
+                    0x928826ff074173d2,
+                    0x048af12891f742bc,
+                    0x16c2108e76b05810,
+                    0x011180fad721e016,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xaec4ca7876165dbd,
+                    0xf2a4c0feb8778f9a,
+                    0x65fddecd14cb9bb0,
+                    0xcb1b555fd9b9649e,
+                    0xdade20df6928b0ec,
+                    0x011f1632f6a3d55c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb0c5e91f916823ad,
+                    0x1a5f77c11222cde4,
+                    0x77d3dfaeaf44f2ee,
+                    0x3b4fef527db1c60e,
+                    0xf106c5b8dd62670e,
+                    0x012d2101003adce3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x339be83ac6937456,
+                    0x88901c07bed5ba96,
+                    0x7158093089b9ac37,
+                    0x90ce6b9161ad792d,
+                    0x12d48da1687f3c27,
+                    0x0112bb5d51edb088,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f24cc131c0359de,
+                    0xab503b5e283b81b3,
+                    0x83704599d5a593d5,
+                    0x7647ad0b1beda98d,
+                    0x4174a410ae823bb1,
+                    0x00dfca71646fbfa4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a168efa2e84865d,
+                    0xabaf944f09b6ab2a,
+                    0x7b50c1d4ba347bd9,
+                    0xda382d5f9cfc002a,
+                    0xfc43ab645766486a,
+                    0x01656f137cf60bea,
+                ])),
+                Felt::new(BigInteger384([
+                    0x697ab4d74b027f9d,
+                    0x02ee8d2c8a01d743,
+                    0x177552eb9c03de91,
+                    0xb99fd6b29bb67e78,
+                    0xf701ddb45efcf64f,
+                    0x0040ba05c1de51fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1493c7f8e30f3c29,
+                    0x05578d514db3d6f4,
+                    0x8f57a281e618b923,
+                    0x4d5da71945a8ab2e,
+                    0xc95c3ba75b02e645,
+                    0x01a4fb2da8dbe7cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf246cde79a254fed,
+                    0xc1ed92bce5449a16,
+                    0xf3ccaaffd631f07b,
+                    0x1c66bec0f92c4f45,
+                    0x13a6db100287b1c0,
+                    0x00aa27e27f2976fa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x383b0bee3af6fe1d,
+                    0x941886b9855b10c3,
+                    0x13074ae10ccc54d5,
+                    0x4a2843b22aa52d9a,
+                    0xeaf4d9554673301f,
+                    0x003d7691a22b7f55,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf3a574ece07a349c,
+                    0x81fcc32d3bbb79d1,
+                    0xf7291e50cd042b7b,
+                    0x15d34488cb3b8457,
+                    0x882beb2ceda3365d,
+                    0x0180b0eab427388c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeaa6bf94fa5a8d78,
+                    0x5ee5aeeba6aa0088,
+                    0x0bc5f8e8f5b77ebb,
+                    0x40e8d9fc05a2dbc3,
+                    0x69e11dbb66b36300,
+                    0x01501e0d09fdfe71,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7441cf6bd19cf5a2,
+                    0x0d1203476e56676d,
+                    0x18ef9f4ddc8d14b0,
+                    0x993ac4643e1381b7,
+                    0x0e29fa581f8b2354,
+                    0x0095e664ad55e9e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0b17863e93650b13,
+                    0xcbb769768561a407,
+                    0xe9cd51db2b74bf82,
+                    0xd8ac6e3592ef2c4e,
+                    0x7e050d109ee40e76,
+                    0x01905730106ce5f3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf2caabca403293cc,
+                    0x2dba3a7c46679d00,
+                    0x0352cd261916ae27,
+                    0xd674a1ac0525cff8,
+                    0x6a3ca5e720ecf8ec,
+                    0x0127912bde2bf812,
+                ])),
+                Felt::new(BigInteger384([
+                    0x788807c777ecaeab,
+                    0xd282bf4a6b177e7e,
+                    0x585f222361361499,
+                    0x7ae5e0dd2ea9a9a5,
+                    0x5cbd224d02656f24,
+                    0x00f6d596d89ffb7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f1adaf8b2da4871,
+                    0x9087691c317aefd9,
+                    0x9932e7088ccd53d8,
+                    0x838e76b3b1c8a8de,
+                    0x5688ea9a67d8eb62,
+                    0x0097d2887fb98f16,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7c21010f2603b636,
+                    0x6a7dd20d536c3f73,
+                    0xdd84fda189c8ecf8,
+                    0x5a519bfe24b5f6cc,
+                    0x46501a094f109aa7,
+                    0x013a812502ae75c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7480c6f1166da9dd,
+                    0x73d56559a0eee82c,
+                    0x5ec22495ccdd02b7,
+                    0xe7ecd4dc3e030789,
+                    0xee7695b1fa8923ed,
+                    0x012f13bd588fb98b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa6dab4cabc6fed45,
+                    0x58aad3ac94d7751b,
+                    0x8a77d2a6d81c26db,
+                    0x8049d332fb74e3df,
+                    0xba463bc8b9baa216,
+                    0x01839ac900f14c53,
+                ])),
+                Felt::new(BigInteger384([
+                    0x128f0a359abdc99b,
+                    0x3f87162dcb07c3c4,
+                    0x3e2f4e831c2ab826,
+                    0xe2f157c50e84ac6a,
+                    0x78f3f2e8537673d9,
+                    0x015ec5af37132be7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1fe8fd996a7b182e,
+                    0xc14c86cd1320794a,
+                    0x0facec17a3a7d54c,
+                    0x532a3b0ccf296d9f,
+                    0x3158934d454acd2a,
+                    0x0080ebe3cbf52749,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb6ff2694a0190906,
+                    0x18d637d68a7565af,
+                    0xe95a26ca18416f68,
+                    0x9850476b87d9a051,
+                    0x832f8a4ce5cbe6cc,
+                    0x01877da53caafe6f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ce3f8e1b545f806,
+                    0x8d8953d871f33450,
+                    0x63751d9972cb6887,
+                    0x1f72d30cad3bcd51,
+                    0x5e8a83b7cff55d06,
+                    0x0097f09d24411e44,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc183820d84c41c63,
+                    0xde4135673eae9db8,
+                    0x8e77917a5a4e5ae4,
+                    0x8ac2820c9cbbbbc4,
+                    0xdfbd5390dc1131bf,
+                    0x009e1392d95a1f5a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7c596e0cbc47aa2b,
+                    0xc39b33bfe2902abf,
+                    0x9bef5eb9880dca0d,
+                    0x335721a515f3906f,
+                    0x8b540c99a7d3d519,
+                    0x012d156d584bbd25,
+                ])),
+                Felt::new(BigInteger384([
+                    0x266c6ad9893c1acb,
+                    0x01d5021e4b4aa2a3,
+                    0x46ac1d523518c3f0,
+                    0x61cb7fb06de33a34,
+                    0x194f2730b59e1d17,
+                    0x0163bb35d447766e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1969d2b2d3ab8286,
+                    0x3804ee2cb7e04703,
+                    0xc3990b213a960854,
+                    0x6a5a02587351a9d2,
+                    0xb20ae7cb9b32f791,
+                    0x0099e03fdb192fbd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2da93f7fff82bbc4,
+                    0xbe7813240a6c0712,
+                    0x71e16774bcd2b101,
+                    0x1b6c265a7989677d,
+                    0x0ef9ad9e6ddef61e,
+                    0x013c830020bc95b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44ebcafad6fb22d7,
+                    0xa5ce53b8fbecc414,
+                    0xae9cb544b2270413,
+                    0x1003b045046f6bce,
+                    0x7a02ab8bbe0bea9a,
+                    0x019c953a83e7c010,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6a073183a3f8a0f6,
+                    0xd8610ab23d6a1b3c,
+                    0xc219e0183555f757,
+                    0xbb643b479e4eab13,
+                    0x4331b408f105515d,
+                    0x00d71d9b362b1887,
+                ])),
+                Felt::new(BigInteger384([
+                    0x338ba6903636e18e,
+                    0x7a20cf8f961d22c2,
+                    0xb79f3c9aea909397,
+                    0xb0ec6bbcee589e3d,
+                    0x7f18f209d14495fd,
+                    0x0013a6139948d540,
+                ])),
+                Felt::new(BigInteger384([
+                    0xefd8def14b200cc5,
+                    0xf073ff39f01528df,
+                    0xf1deb33f052b4564,
+                    0xadddc80ee02d3bd7,
+                    0x06b73a53fc91e6f0,
+                    0x019edf2d40419150,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x684da1338692943f,
+                    0x803e5fdc6c81a8c3,
+                    0x1472ea560f6621ec,
+                    0x688a02df5362fd8b,
+                    0x5950ed3708be8c2f,
+                    0x004313222ceb9f18,
+                ])),
+                Felt::new(BigInteger384([
+                    0x05e03841144d3070,
+                    0x14a7ba66d161d8e4,
+                    0xcd97f9303d9b1a4a,
+                    0xe36f37a261827475,
+                    0xef154ce71d1d2a9e,
+                    0x00dd7c8cb57951ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a240fdda7e085e5,
+                    0xa8d69b6b2f229b9c,
+                    0x14f7d886b64c9428,
+                    0x95f1b99376a6f053,
+                    0x983f8d1c5e4c4ab6,
+                    0x00801a3edb63389d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x850634428993dd08,
+                    0x61b3ceb1d90a9b2e,
+                    0xc28d5e7be2e2a444,
+                    0xb44a9534d8d8a076,
+                    0x6a80879f32587090,
+                    0x0064ad49cf36734a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9cd7fb9a02631525,
+                    0x93a553fdfa6c32d6,
+                    0x0abdab68fd136854,
+                    0xf86c3db80848eabd,
+                    0xda8d53630514b581,
+                    0x0153536ed2ef201b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x84635a3908fa2159,
+                    0x5308b59cc84090b0,
+                    0x2f4c6ae19141f2a3,
+                    0xd20c7dee09698207,
+                    0xfd109a5f7b2664f3,
+                    0x0179aaf641ffcb1b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x75a838c2dd75a9f2,
+                    0xcd67e1d096f72ccb,
+                    0xe3c7de1f4f297b02,
+                    0x8f5cd45e8aea5b5a,
+                    0xc3e7b355eac6405f,
+                    0x00420de2d4c118cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2de716ed66aa81c8,
+                    0x5ba23555d1f4ae38,
+                    0x923b10b61d7245a2,
+                    0x2269fcf528f0160f,
+                    0xa6f490098d0f024f,
+                    0x012bc44646081974,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9088bd2729f81aad,
+                    0xa72af88e3d2fc5ce,
+                    0xcc7e6a8ae6707822,
+                    0xd06a2146f870450b,
+                    0x4bf2995ea9ee2883,
+                    0x017171e1424087de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f2814445d3d2321,
+                    0x6886b758a1c0402d,
+                    0x0e98cfbb7a77aa1d,
+                    0x20d582f43e1d9215,
+                    0xe13ae44296b57f7f,
+                    0x00901afe56b10455,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66a2cd197f9b733c,
+                    0xf333166ade13fb90,
+                    0xf4246ca393433974,
+                    0x713333c0c17d1301,
+                    0xc48bd568201cef91,
+                    0x004a39a1e2ecbfa8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e4573784b27ce15,
+                    0x3a5b8bd6f39672f3,
+                    0xbf728a873217267d,
+                    0xcd4f70ee819dc708,
+                    0x45cc042ae07b6cee,
+                    0x0101a3e1de402b5c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9dc5779ceeedae78,
+                    0xcaf3efacadc733ef,
+                    0x931170ec5cde1382,
+                    0x9b64b6b29e059b92,
+                    0xe6bf9582c1fd333d,
+                    0x00a40cc23f10e6a6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc2ecf392ab4ddbde,
+                    0x0587986aa4e293d6,
+                    0x385bb2fda05872d3,
+                    0x8b87cacea8d7f947,
+                    0xbfee7d33ddfb46bd,
+                    0x009a4af1dc5eb146,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb1c7f40621be218e,
+                    0x9bd87ef6e22a24c4,
+                    0x9e51a65d59287d75,
+                    0xcdeaf0a8b7defd01,
+                    0x6c460f91d943dd03,
+                    0x014574e33f523e91,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77ac35160243d740,
+                    0xe5a5cd3cc0588c31,
+                    0xcb7f2c3cba0b1c43,
+                    0x25c44649c3a08e7e,
+                    0xa2915ceaaccd0511,
+                    0x000787f649795b86,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
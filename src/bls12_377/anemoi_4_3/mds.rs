@@ -6,7 +6,7 @@ use super::NUM_COLUMNS;
 
 /// Maximum Diffusion Layer matrix for Anemoi
 /// [  1  15]
-/// [ 15 256]
+/// [ 15 226]
 #[allow(unused)]
 pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
     Felt::new(BigInteger384([
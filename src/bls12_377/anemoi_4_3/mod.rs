@@ -1,8 +1,11 @@
 //! Implementation of the Anemoi permutation
 
 use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
 use crate::{Jive, Sponge};
 use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use unroll::unroll_for_loops;
 
 /// Digest for Anemoi
@@ -15,7 +18,7 @@ mod mds;
 mod round_constants;
 
 pub use digest::AnemoiDigest;
-pub use hasher::AnemoiHash;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
 
 // ANEMOI CONSTANTS
 // ================================================================================================
@@ -35,11 +38,17 @@ pub const DIGEST_SIZE: usize = 1;
 /// The number of rounds is set to 12 to provide 128-bit security level.
 pub const NUM_HASH_ROUNDS: usize = 12;
 
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
 /// Applies the Anemoi S-Box on the current
 /// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
 #[inline(always)]
 pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
     let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
@@ -68,6 +77,30 @@ pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
     state[NUM_COLUMNS..].copy_from_slice(&y);
 }
 
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
 /// Applies matrix-vector multiplication of the current
 /// hash state with the Anemoi MDS matrix.
 #[inline(always)]
@@ -84,6 +117,9 @@ pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
 // ================================================================================================
 
 /// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
 #[inline(always)]
 #[unroll_for_loops]
 pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
@@ -98,6 +134,15 @@ pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
 #[inline(always)]
 #[unroll_for_loops]
 pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
     // determine which round constants to use
     let c = &round_constants::C[step % NUM_HASH_ROUNDS];
     let d = &round_constants::D[step % NUM_HASH_ROUNDS];
@@ -106,9 +151,46 @@ pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
         state[i] += c[i];
         state[NUM_COLUMNS + i] += d[i];
     }
+}
 
-    apply_mds(state);
-    apply_sbox(state);
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
 }
 
 #[cfg(test)]
@@ -1198,4 +1280,17 @@ mod tests {
             assert_eq!(output[index], i_2);
         }
     }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
 }
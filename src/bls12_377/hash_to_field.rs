@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 64,
+    expanded: "ab51ee075fd08718e321addd781233428224fb2614f8e3d20c600916c128e237",
+    element: "49065141520636870327228128059862824701247964455708529479573495137429115768864137430904522267179906843190364328017",
+}
@@ -0,0 +1,298 @@
+//! Hashing to the G1 group of BLS12-377 with Anemoi, following RFC 9380.
+
+use super::{hash_to_field, BigInteger384, Felt};
+use crate::sswu::{self, SswuParameters};
+use ark_bls12_377::g1::Parameters;
+use ark_bls12_377::G1Affine;
+
+impl SswuParameters for Parameters {
+    /// ISO_COEFF_A = 258664426012969092796408009721202742408018065645352501567204841856062976176281513834280849065051431927238430294002
+    const ISO_COEFF_A: Felt = Felt::new(BigInteger384([
+        0xef6dc9934d3ce250,
+        0x74af9b7f7e982df3,
+        0xff914ed397c8e910,
+        0x95d6f551d83676ca,
+        0x83527885cb405a6f,
+        0x00c361fbac151eaf,
+    ]));
+
+    /// ISO_COEFF_B = 22
+    const ISO_COEFF_B: Felt = Felt::new(BigInteger384([
+        0x9a76bffffffff2e9,
+        0x5a3e286faffff932,
+        0xdc25c143d08286d2,
+        0xe1cd141e77fcf991,
+        0x3167b6320cca6b5c,
+        0x0063347edb6f8ed7,
+    ]));
+
+    /// Z = -11
+    const Z: Felt = Felt::new(BigInteger384([
+        0xf54900000000068c,
+        0xde669a6a40000366,
+        0xa166d075f4c36096,
+        0x1c2ae2ea447c0cfe,
+        0xca69a7c72feb6eef,
+        0x00a582e39e2ac109,
+    ]));
+
+    const X_MAP_NUMERATOR: &'static [Felt] = &[
+        Felt::new(BigInteger384([
+            0x823123adc3dca4eb,
+            0x7a0f9955afee024c,
+            0xd28c7eda6a6936da,
+            0xa3f9423c4b6291ac,
+            0xd71abcad5823fd42,
+            0x010be7dd0a10e548,
+        ])),
+        Felt::new(BigInteger384([
+            0xac492836d2c23c6d,
+            0x8999b34a056153a5,
+            0x4db250a866fb9367,
+            0x2e621304854e2c3f,
+            0x2e7c2456fee6b206,
+            0x0023815f9d6011c0,
+        ])),
+        Felt::new(BigInteger384([
+            0x40b37fffffffffda,
+            0x945027e0dfffffec,
+            0x67df6cea629f4ffc,
+            0x1ed3a5eddb9f18c1,
+            0x933d256fe00f213a,
+            0x0023599878bf7d26,
+        ])),
+    ];
+
+    const X_MAP_DENOMINATOR: &'static [Felt] = &[
+        Felt::new(BigInteger384([
+            0xb124a0db4b08f1b4,
+            0x2666cd2815854e96,
+            0x36c942a19bee4d9e,
+            0xb9884c121538b0fd,
+            0xb9f0915bfb9ac818,
+            0x008e057e75804700,
+        ])),
+        Felt::new(BigInteger384([
+            0x02cdffffffffff68,
+            0x51409f837fffffb1,
+            0x9f7db3a98a7d3ff2,
+            0x7b4e97b76e7c6305,
+            0x4cf495bf803c84e8,
+            0x008d6661e2fdf49a,
+        ])),
+    ];
+
+    const Y_MAP_NUMERATOR: &'static [Felt] = &[
+        Felt::new(BigInteger384([
+            0xc02040000000010b,
+            0x08da461e1000008a,
+            0x47d767c707ae1818,
+            0x42595071ff9b6645,
+            0xbf8effb14c3760a4,
+            0x00b6c71aca88a4dc,
+        ])),
+        Felt::new(BigInteger384([
+            0x071b06cd965863b7,
+            0x087569d4402cfa42,
+            0xbf01385547102ade,
+            0x2767fb48c5ee44b5,
+            0xd5d0b6afb3393ded,
+            0x0195ce06a2426d14,
+        ])),
+        Felt::new(BigInteger384([
+            0xc4f21c523c235aa4,
+            0x59ec3b912011fd78,
+            0x84052a14777e011b,
+            0xd2a48980486fcc26,
+            0xa8d7b962b4aaafa6,
+            0x010c5f3277f2a315,
+        ])),
+        Felt::new(BigInteger384([
+            0x2059bfffffffffed,
+            0x4a2813f06ffffff6,
+            0xb3efb675314fa7fe,
+            0x0f69d2f6edcf8c60,
+            0x499e92b7f007909d,
+            0x0011accc3c5fbe93,
+        ])),
+    ];
+
+    const Y_MAP_DENOMINATOR: &'static [Felt] = &[
+        Felt::new(BigInteger384([
+            0x78aa4000000004c3,
+            0xbb1d1bb090000279,
+            0x60ec8942da31d86d,
+            0x73f3d01d8efc2280,
+            0xeb0c634543ffb46d,
+            0x009f7bc32f5f8ded,
+        ])),
+        Felt::new(BigInteger384([
+            0x0f454523c235b15a,
+            0x45780f4ee11fdb3d,
+            0x0cb9e8369dc96259,
+            0xc5c85fb95369a8ca,
+            0x4ca26eaebc0d0824,
+            0x000fc8ec4ca4549e,
+        ])),
+        Felt::new(BigInteger384([
+            0x136de291e11ad51c,
+            0x73346778408febc4,
+            0xa45bc7e4d3cae8da,
+            0x2c98e4363faa12f7,
+            0x2dd1b413f2d0584a,
+            0x01aa107b6080d502,
+        ])),
+        Felt::new(BigInteger384([
+            0x02cdffffffffff68,
+            0x51409f837fffffb1,
+            0x9f7db3a98a7d3ff2,
+            0x7b4e97b76e7c6305,
+            0x4cf495bf803c84e8,
+            0x008d6661e2fdf49a,
+        ])),
+    ];
+
+    /// H_EFF = x - 1 = 0x8508c00000000000
+    const H_EFF: &'static [u64] = &[0x8508c00000000000];
+}
+
+/// Hashes `msg` to a point of the G1 group of BLS12-377, under the domain separation tag `dst`.
+///
+/// This follows the `hash_to_curve` construction of RFC 9380: two field elements are obtained
+/// with [`hash_to_field`], i.e. with the extendable output of the [`anemoi_4_3`](super::anemoi_4_3)
+/// instantiation, mapped with the simplified SWU map to a 2-isogenous curve followed by the
+/// isogeny, as the curve itself has `A = 0`, and added, before clearing the cofactor with the
+/// effective cofactor `x - 1`.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    sswu::hash_to_curve(&hash_to_field(msg, dst, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+    use core::str::FromStr;
+
+    const DST: &[u8] = b"ANEMOI-HASH-TO-G1-TEST";
+
+    fn point(x: &str, y: &str) -> G1Affine {
+        G1Affine::new(
+            Felt::from_str(x).unwrap(),
+            Felt::from_str(y).unwrap(),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        // Generated with scripts/map_to_curve.py.
+        let vectors = [
+            ("1", "135546951294498553135052181699214439595425643290184489158020204978090192164521135749992076592348385020106569340513", "248147570073866406917373859971518251652346058276844344660150440270648084470955849149623680188515216543635615952678"),
+            ("2", "49976846116682679841065251402635521456895552346156017137970211572145578748937037112875342514657682561405672633635", "256884588876895257638605092863953051293021108866667968834551559430270335093410976328883661455831003085604176850344"),
+            ("258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458176", "135546951294498553135052181699214439595425643290184489158020204978090192164521135749992076592348385020106569340513", "10516855939102687093278873723375281884047454478070315879733822396072383877384973625345207951058143580804705505499"),
+            ("1512366075204170929049582354406559215", "248280171048791451949810674747005721676718646656295714410658593714689025465217219427237357835869023358954841632109", "114437116505070348860768418207992269168139262665220510424226519063876188009177251572200423093827881687161211757438"),
+            ("86221475337656364670217577898297844512131170918304886846628087555573489449446940924989629379857786708146773819392", "147167066124273740118841830968632884461305752614320648325294608560941723446505419253246089790429590941703644946407", "220368272418089271149957015482151402414313975434673578820770842290437297999786677320328275484152858382570048917785"),
+        ];
+
+        for (u, x, y) in vectors {
+            let mapped = sswu::map_to_curve::<Parameters>(Felt::from_str(u).unwrap());
+            assert!(mapped.is_on_curve());
+            assert_eq!(mapped, point(x, y));
+        }
+    }
+
+    #[test]
+    fn test_isogeny() {
+        // The constants of `ISOGENY_MAP_TO_G1` and `SwuIsoConfig` in ark-bls12-377 0.4.0,
+        // src/curves/g1_swu_iso.rs, in increasing degree order.
+        let maps: [(&[Felt], &[&str]); 4] = [
+            (
+                Parameters::X_MAP_NUMERATOR,
+                &[
+                "193998319509726820447277314072485610595876362210707887456279225959507476652652651634192264150953923683470146535424",
+                "40474824132456359704279181570318738632422647360355249739068643631356267969150730939906729705473",
+                "193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093633",
+                ][..],
+            ),
+            (
+                Parameters::X_MAP_DENOMINATOR,
+                &[
+                "161899296529825438817116726281274954529690589441420998956274574525425071876602923759626918821892",
+                "1",
+                ][..],
+            ),
+            (
+                Parameters::Y_MAP_NUMERATOR,
+                &[
+                "193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093631",
+                "32333053251621136903112182208573040583096119983059602439070460434672245065050016464457115901761911040205276577794",
+                "129332213006484547066038603046131306324615528732935438218576102373893108782773376834518846023512776472080255287298",
+                "226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905",
+                ][..],
+            ),
+            (
+                Parameters::Y_MAP_DENOMINATOR,
+                &[
+                "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458169",
+                "971395779178952632902700357687649727178143536648525993737647447152550431259617542557761512931340",
+                "485697889589476316451350178843824863589071768324262996868823723576275215629808771278880756465676",
+                "1",
+                ][..],
+            ),
+        ];
+
+        for (map, expected) in maps {
+            assert_eq!(map.len(), expected.len());
+            for (coefficient, expected) in map.iter().zip(expected) {
+                assert_eq!(*coefficient, Felt::from_str(expected).unwrap());
+            }
+        }
+        assert_eq!(Parameters::ISO_COEFF_A, Felt::from_str("258664426012969092796408009721202742408018065645352501567204841856062976176281513834280849065051431927238430294002").unwrap());
+        assert_eq!(Parameters::ISO_COEFF_B, Felt::from(22u64));
+        assert_eq!(Parameters::Z, -Felt::from(11u64));
+    }
+
+    #[test]
+    fn test_map_to_curve_arkworks() {
+        // The `u` inputs and `Q0`, `Q1` outputs of ark-bls12-377 0.4.0,
+        // src/curves/tests/BLS12377G1_XMD-SHA-256_SSWU_RO_.json.
+        let vectors = [
+            ("140152979952660692870238147763629459820988234162216512643357324514138354974971241448799334247388771650119168229075", "135299817289358564097340279536416363637714996656177159012200012196007593939641033747732386441257628882410616390295", "184408556201958819760237318413311313364610448974812266871533846975743399676095529307147046638968111353985293888919"),
+            ("194873135454571165703510804106049151156244936304492661707892579887589189846330569049166424421461539064214987125366", "51876672382562844037152133389010522305197624392758715228200089271227346811409970672166093006689009335621144946309", "244516159499789998027854905279617827840585718205123046980369811220144962190079295178148213844125895260797272235932"),
+            ("124605680106303989795115663752582509576719682855229271793581367806502053433144692181222204118323249537959316724827", "155492052698585598562566301491750237234125349997921791077301648109158049382988736556301084427652464808610681868288", "10759394064919072802593144152051831127116351453431952205194309110647365736610364642476597838700505808027241336463"),
+            ("113514309580363039197798005738626840071779818940146582704221895784540697864201114682605260032519217010308397600921", "95248219018620367272759085012152198778017754472899990120494038010213485924807925937304616397982740274901153124154", "87043985830937002232097812517421153791855363716706216653370437548241737198543510160477443579488045063066746036635"),
+            ("240720353500778326460670128304863779666477759286672971697957693677003494944148211110945862220705480423735163578296", "223411622073319227232931922059541320402837506437111960745728155548728452639093618077680690712635208578698348796001", "215265094046868315477398905024586298083200967959660868332754031295232508345052066695268341783273433857978149719896"),
+            ("36917320043061166883678420460867814141518886380758582033363445959102971924029640934076886760889254962862437636681", "124934084727744598079755825939788333574750308058828453506415108439001353269239806873428354542106795060168414798061", "232219595378795258647013834521618424262947992640036838998459335913955746430011313699940328251669957057911079212153"),
+            ("8869911330692985194827084695410214744635971481344873728957116528482060832435446679729391730957343276901117278463", "98869218131440058687565881536034711308370223780817632657211086401762843031926468252097834383980075476367332773675", "19924826697372295695579238818046944609261203889716996752049022846685144536650610741722921855614389308650658731580"),
+            ("8457248106809566821468653029261401334118314229034529495259953123306378529201712035915935676200276819552477697088", "80575126217360030669090112880671286675296095841180821298760973164876085813506172779917340206026564930394931202579", "23811478964731330790661176656849169705814833490316489310258188833284126129664790786854714985196550078829722177241"),
+            ("128536513208327880957147460115107319337092292156711159426752397954490270435174013620189947605348653814210134005992", "36466989042471222586834008120532720804649214734544689090420114132292794864848648044654946148406481197249580033564", "46424662052669924677130108973330913905608613087030140917625476349356447299677650531001154086608126439985982355328"),
+            ("111290413472991356929961456945905733590543220198716341463541565692294965523575288332702317549867352458337266445857", "172670398802404069223794985171713492828526008571944448642870600603666635798924873177532901650416269833187883498392", "31519072929168759159960617811221177614598534217874784967892793068963907289448792104898461475858745921929608534592"),
+        ];
+
+        for (u, x, y) in vectors {
+            let mapped = sswu::map_to_curve::<Parameters>(Felt::from_str(u).unwrap());
+            assert_eq!(mapped, point(x, y));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_g1() {
+        // Self-generated with this implementation, as there are no reference vectors for
+        // hashing to curves with Anemoi.
+        let vectors = [
+            (&b""[..], "107682007487487669587874846830084334904726670617943733864744019426785358972664237989055100939130321231017913134222", "252453970760115971012281478419820897019043497891678720491546758901066958547693835597676254169771769942637217778835"),
+            (&b"abc"[..], "192925152121245358988565435040038255869649993806242795767356241446691104371757972169540335729629030393132335978812", "171063623180582559454553530104026611466208577370769471950608153891290187817552632295757709850028386226019161201511"),
+            (&[0u8; 200][..], "89348580203191349803132686518346472050184978267403307026633863333178907629208152322716777097064585399920385069624", "200859344008914504781471970418309560283408735043998737918538041786188714218390834762774052855334971314325941101111"),
+        ];
+
+        for (msg, x, y) in vectors {
+            let hashed = hash_to_g1(msg, DST);
+            assert!(hashed.is_on_curve());
+            assert!(hashed.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!hashed.is_zero());
+            assert_eq!(hashed, point(x, y));
+
+            assert_ne!(hashed, hash_to_g1(msg, b"ANEMOI-OTHER-DST"));
+        }
+    }
+}
@@ -0,0 +1,3026 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U8>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger384([
+                0x6379373f3f40958d,
+                0x652e954ed13e1e6e,
+                0x73d81990d67dbb84,
+                0xb7acb995996a43d7,
+                0xf1eb221b35761189,
+                0x0c3364fe4e147cc8,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0x49c1b36e0e393c16,
+                    0x37e2066ec2efbf43,
+                    0x2442ea051859fe9d,
+                    0x4a330ab731a8041c,
+                    0x0ca307fc61f180cf,
+                    0x0d1f8dc7b6ebe500,
+                ])),
+                Felt::new(BigInteger384([
+                    0x814b70b2bcf73cfa,
+                    0x8b6c1dcb0e42547d,
+                    0xe5419e6d0c118d7e,
+                    0x32d36a25999db614,
+                    0xc308afe46f29a03c,
+                    0x05f9227e21ed0613,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x57b69b485cb452c9,
+                    0x6b6af6377306ab1c,
+                    0x520ad2a6367e0773,
+                    0x1453c2f0939a49a9,
+                    0x2001cafcfd3039c5,
+                    0x099b28deb486aed8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb37a36c7b5514dcd,
+                    0x39d0762802cef722,
+                    0xb0adc2ddde41265e,
+                    0xf628375124c4e568,
+                    0x248940aebf2b6d09,
+                    0x15f231eea7e19706,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5709674cd68b746c,
+                    0x5cde0da125fcecd0,
+                    0xde14f7e05f4632df,
+                    0x8004550728609d71,
+                    0xe384eb6a8ad27329,
+                    0x058827bd857aedc1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xfc3aaeb5b42aca94,
+                    0x74e6a90a2c3ad7e8,
+                    0x61ee492a64d46e28,
+                    0x3f9ba0f0779505e7,
+                    0x63815af1a84db444,
+                    0x089c2e7878efac3a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0bc2fba046fb316b,
+                    0x26f29afa21e697fd,
+                    0xdf6eba49cd992804,
+                    0x06b732475d847396,
+                    0xde72069b69266708,
+                    0x04d75540fc8ddc1e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4507a2192063a182,
+                    0xdc07aa6e617b5fc3,
+                    0xcc0892fb111d30e2,
+                    0x04cfb19f8158931e,
+                    0x52e656a5c606279d,
+                    0x0b43edc5ff8d5ef0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x333f64068961827c,
+                    0xc2d6dbf7e7e4394a,
+                    0x502bbfd81f10894d,
+                    0x4b3e6ee837be8014,
+                    0x7798768a0a81a105,
+                    0x050b353000fb0274,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x29e7a3a6444c4941,
+                    0xb708a8aaa4428e1c,
+                    0x7d590191ac086a7b,
+                    0x817123c4cba3ecd1,
+                    0x384a5109150303aa,
+                    0x14a331b4b9078f34,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5fe45777b8411460,
+                    0xe5cce99b504979fe,
+                    0x89e04db319a7be12,
+                    0xbd5e98730dceb6db,
+                    0xaaae63ab03d178dd,
+                    0x19cc8fdee9e80f88,
+                ])),
+                Felt::new(BigInteger384([
+                    0xced88833c1bc08bd,
+                    0xf8ab90b902c2b4b5,
+                    0xe48bd5b0dc53a43c,
+                    0x2b58ad865a11c3f5,
+                    0x98559e2af6a6c5c9,
+                    0x0a1aa2950aed6762,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43ced0510cdfd14c,
+                    0x03edb26b3d7ac5d5,
+                    0xa17e47b31c6f4d28,
+                    0x9a3b4c6a371205da,
+                    0xbbfa50fc1b30671f,
+                    0x067beec49345c422,
+                ])),
+                Felt::new(BigInteger384([
+                    0x013e864127ea460d,
+                    0xc900048c79be11e5,
+                    0xda26b83a3d665320,
+                    0x2eb99af8808fed6e,
+                    0xf096259c05610a30,
+                    0x03ddad6f413d00a8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe21e0ca5a9352b26,
+                    0xeabdba7e0f5a2dbb,
+                    0x7fddbe53246f4cf6,
+                    0x2489327f83c41ec0,
+                    0xa5b1ab73d053cb75,
+                    0x0a53544968fdfeb2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d557732e9d726c4,
+                    0x40a0398e98588f2f,
+                    0x6f16f53a118ee666,
+                    0x21dcfb9c3bb838ec,
+                    0xc86a780fee43ec71,
+                    0x04b06ef55a285559,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbb63d0be611cdcc3,
+                    0xb611ed0455280959,
+                    0x612e083e4ab43915,
+                    0xf802af133dd59e98,
+                    0xc90e868c8134f638,
+                    0x101607065800b349,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6465529895364231,
+                    0x36a3519786d8b9f5,
+                    0x1f525ca30654084c,
+                    0x9878a656c171f212,
+                    0xb8990b1dd2398a34,
+                    0x010b368bd38b532c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xefd057713e44162d,
+                    0x1723d239e6464d2e,
+                    0xa04e1a9d43d9c91f,
+                    0x1ac1d01ccf9a5f7f,
+                    0x4ee7a272285db648,
+                    0x09af6667b3e929c1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x397f75db22c7050a,
+                    0x2b2fff23762ffd33,
+                    0x689db0e1222b75aa,
+                    0xbcd5ef00b3611e02,
+                    0x7b82a12de1336fd0,
+                    0x142a357945ed15d0,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x10b8aaf847be3893,
+                    0x80fa9f1e20704601,
+                    0xc5c8b84aeb74a2e9,
+                    0x9c5f8467037876a7,
+                    0xc36a5a75654f2e0b,
+                    0x10409f810fdea7a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8728701432937afe,
+                    0xb7f213ba609b78ba,
+                    0xddb5e91bdd603c78,
+                    0x9fe73b6b16b288fc,
+                    0x213cab24a25425ee,
+                    0x13e6165c1731c573,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1dc1d6e2801a1b5c,
+                    0xcd23397cbd01063f,
+                    0x8f20566c275dc968,
+                    0xed67600860c9fc28,
+                    0x806800a39a3eb917,
+                    0x06989d8faca56d76,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2f9eb72aa47471c,
+                    0x04a03412b6d5bbec,
+                    0xcdfc09e90bc95d1c,
+                    0x174f3fae2dc65f79,
+                    0x59419d4e7dae415e,
+                    0x0c9a652fea21df83,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf2be04c87eb4461c,
+                    0xc8ddb20ea0c6be88,
+                    0x2cd6f7e4676718f1,
+                    0xc0c966220cf8dabb,
+                    0xc40ba41d8f9919a7,
+                    0x0204f5fcc336ebfb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x405f48cc52a6841a,
+                    0xb4aa19ec61a2e656,
+                    0xc759e88f39552beb,
+                    0x67338692f69b26a1,
+                    0x258fc8195819b4f2,
+                    0x07d9b66edbd503be,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x93f58b970b2a031e,
+                    0x70a8eba08a4e0379,
+                    0x697fb4d20ddee1fa,
+                    0x007ef4722a938055,
+                    0xecf3f46035a322ad,
+                    0x17cc306401212b31,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71be9e83fb9070d3,
+                    0xa8b2f74f54a97b5b,
+                    0x3339c26a10737251,
+                    0xeae0d4b7a1fe5c7a,
+                    0x00f3792d168effe6,
+                    0x149ca85fe125a2fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xee87c8e912856ed0,
+                    0xbc6463fdbc57ebcc,
+                    0xe7dc36224bbaa991,
+                    0x7a0e725bdf82f7de,
+                    0xcb1a88eb699d4333,
+                    0x0970207d1b51adc2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x998e4f0739d35edc,
+                    0x1b22153d3c065c9d,
+                    0xacf516a11aab9084,
+                    0x1de91bdf00f40a1f,
+                    0x9d8548dc27dbe3fc,
+                    0x12abd6e7fc6c5a65,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x74997efe777a41cd,
+                    0xf26e7f0d94cf49fa,
+                    0xa190c8950372d92e,
+                    0xea8654d604bf8234,
+                    0xea0b8310a1261364,
+                    0x0de53d362872c48e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a68ff588c49e7d9,
+                    0xbd3420373f69c560,
+                    0x8d1c2534f2e90dea,
+                    0xcfb176f4262c5a12,
+                    0x8c1fe1dd0a6e4219,
+                    0x0ec60cfa46aea3d5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4145ba3598c213b9,
+                    0x756b4f5149e57d4c,
+                    0x5f7600de9e431d41,
+                    0x1d32d90cf415c42c,
+                    0x345f37970cdf8da7,
+                    0x1876816c347b3332,
+                ])),
+                Felt::new(BigInteger384([
+                    0x84f298250ef7833c,
+                    0x783a82162c2a012b,
+                    0xe262d194b22e02e5,
+                    0xcd71d74f59271abb,
+                    0xbf3af21f9519ed76,
+                    0x156c7b5616ab7d7a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x52160d58cb485af1,
+                    0xbcbca42574669901,
+                    0x2e09029fb345c853,
+                    0x39624737db992034,
+                    0x763a2a81467d21b6,
+                    0x05559ddcb302f26f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92249fdd3bb1fae8,
+                    0xb104dbbc7fa11f41,
+                    0x208251602c984f81,
+                    0x994a97c5d99cb5d0,
+                    0xd8c50144fde73c75,
+                    0x0252bb478b274051,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb0a82f5c1f284481,
+                    0x75afeb31a329a249,
+                    0x3d6185992087cedc,
+                    0xd32d596fce2bd6dd,
+                    0x570bc319aa8e0389,
+                    0x142102fcf9685e71,
+                ])),
+                Felt::new(BigInteger384([
+                    0x59ab2abeca89f9cf,
+                    0x6d0820c6fdb2d60a,
+                    0x9913789799157657,
+                    0x32c98c6055f46eff,
+                    0x7bba1adcbef04e42,
+                    0x0bb9f723825b6c89,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8bdff32bc9f9a3d2,
+                    0xb5163a52a8cded22,
+                    0xb0450d223410ed3a,
+                    0xf16b8e863b0f0b74,
+                    0x4be7bcaea7d699f3,
+                    0x10053133661ea031,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2649df78f333ad2c,
+                    0xc130f8c6dce93c60,
+                    0x435306e3b9706796,
+                    0x690724a41727d4a2,
+                    0xcafbc83514b78cf2,
+                    0x01120d84a3fd5ec0,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x10b8aaf847be3893,
+                    0x80fa9f1e20704601,
+                    0xc5c8b84aeb74a2e9,
+                    0x9c5f8467037876a7,
+                    0xc36a5a75654f2e0b,
+                    0x10409f810fdea7a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8728701432937afe,
+                    0xb7f213ba609b78ba,
+                    0xddb5e91bdd603c78,
+                    0x9fe73b6b16b288fc,
+                    0x213cab24a25425ee,
+                    0x13e6165c1731c573,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1dc1d6e2801a1b5c,
+                    0xcd23397cbd01063f,
+                    0x8f20566c275dc968,
+                    0xed67600860c9fc28,
+                    0x806800a39a3eb917,
+                    0x06989d8faca56d76,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2f9eb72aa47471c,
+                    0x04a03412b6d5bbec,
+                    0xcdfc09e90bc95d1c,
+                    0x174f3fae2dc65f79,
+                    0x59419d4e7dae415e,
+                    0x0c9a652fea21df83,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf2be04c87eb4461c,
+                    0xc8ddb20ea0c6be88,
+                    0x2cd6f7e4676718f1,
+                    0xc0c966220cf8dabb,
+                    0xc40ba41d8f9919a7,
+                    0x0204f5fcc336ebfb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x405f48cc52a6841a,
+                    0xb4aa19ec61a2e656,
+                    0xc759e88f39552beb,
+                    0x67338692f69b26a1,
+                    0x258fc8195819b4f2,
+                    0x07d9b66edbd503be,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x93f58b970b2a031e,
+                    0x70a8eba08a4e0379,
+                    0x697fb4d20ddee1fa,
+                    0x007ef4722a938055,
+                    0xecf3f46035a322ad,
+                    0x17cc306401212b31,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71be9e83fb9070d3,
+                    0xa8b2f74f54a97b5b,
+                    0x3339c26a10737251,
+                    0xeae0d4b7a1fe5c7a,
+                    0x00f3792d168effe6,
+                    0x149ca85fe125a2fc,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 470];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+            bytes[188..235].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..47]);
+            bytes[235..282].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..47]);
+            bytes[282..329].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..47]);
+            bytes[329..376].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..47]);
+            bytes[376..423].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..47]);
+            bytes[423..470].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x7fb69ee74674940f,
+                    0x23b96880cd80c020,
+                    0xbffaf5aa5ad23209,
+                    0x5e5f2775bcc54dd2,
+                    0x4d82ac55c8c37fc4,
+                    0x13591e8cfa20d3b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4986bfbc903a19f9,
+                    0x1e6997c3c633c46a,
+                    0x99d00d584cacf72d,
+                    0xea259222e3ef52dc,
+                    0xc6a9f9205ad18752,
+                    0x18010502494403f3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe396ba88ea95ebc7,
+                    0xea2d11d4ab5671f5,
+                    0x2b68675bc94de7dc,
+                    0x588698aa5f75a69b,
+                    0x8451e29d805d5c29,
+                    0x01fdfddffc2f2b60,
+                ])),
+                Felt::new(BigInteger384([
+                    0x269e4bad6fbe8d5f,
+                    0x78c6f224283cd3f8,
+                    0xc307100e02246608,
+                    0xc364a754ba0f4fb8,
+                    0x5b6abd05301b90e3,
+                    0x16a3a8bb6a562f63,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb0b0c029e99627e7,
+                    0x82a578aa28fec71e,
+                    0xf684cf4b3e787fff,
+                    0x89046af8b7e61f45,
+                    0xa39760e870f94308,
+                    0x128e9872d01e612f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc27f2c1c7cd8f652,
+                    0x59b7778dfd547ffc,
+                    0x0690c70f2d17a082,
+                    0x7d94a62f2bbdea93,
+                    0x097e87f85e053f14,
+                    0x1710380a1d76cb03,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa6b92c942c6f1b9c,
+                    0x45234b11877fd538,
+                    0xc41ed779818de547,
+                    0x805a8c739652e49a,
+                    0x1f176f63c0d67ad2,
+                    0x01202f36d92cadd3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xce6422a0bea1f9c0,
+                    0xd809776d0230be4c,
+                    0x9bb30e03bd09e50e,
+                    0x53503611b5751fd7,
+                    0xdb21e2b203397d2a,
+                    0x07086adaa9f3b006,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7cb7bc9a32a9b9b4,
+                    0x56969b4dc021dbe7,
+                    0x660aa2e2e37dbdb9,
+                    0x90e80c9dd8c32ab9,
+                    0xf18f1c4bba9e5a3f,
+                    0x026cdad018bbcdae,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb40608187d98b132,
+                    0x2675a1e95f9b83c8,
+                    0x67ffc6ee04b3367a,
+                    0x6ec15d867a46b433,
+                    0x0fe1243f274cf991,
+                    0x0d167b4493cce550,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xf0fd882a9bfea9b4,
+                    0xba6ec22836e5d81e,
+                    0xff5da3c3edefcf5b,
+                    0xdec1b8fe38820dd9,
+                    0x97390297844ebede,
+                    0x01b9a32d04e61cf7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f8a45a2dd9bb3fb,
+                    0xbfc00995c5e8033a,
+                    0x5b983a8a43954688,
+                    0xaa4259afa2e71542,
+                    0x35300d66aa144f8d,
+                    0x01c20d199cb716c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6691d7e2c85e4772,
+                    0x4fb5aebe4205a1f5,
+                    0xfce69d0a872f727e,
+                    0xb7b8ab39dae7f2c4,
+                    0x61270e5e698444ed,
+                    0x14476f990b69893c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x74fc89af2bd77ef9,
+                    0xe3236e1947921547,
+                    0x6b3b503de56646a0,
+                    0x569d0b23cad2fbaa,
+                    0xb992f6a07b5cac4d,
+                    0x109db01b6aa20a5f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7cf95c1bb653ff26,
+                    0xc59040141f521db9,
+                    0xe9a8c20dd7f376e2,
+                    0xbce9031eaf6bc313,
+                    0x422d82a8ed33f246,
+                    0x0f1a4abf978d8d25,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca54178eb70a39f5,
+                    0xa1d43db84ba789db,
+                    0x85d09673f09e1a73,
+                    0x12dc5bf9a95f6992,
+                    0xb9a82d81a8d7f083,
+                    0x073107af2910668a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee2f8a96c2deda05,
+                    0xbe69ae6b9b69de97,
+                    0x0a6c62db1d2b9153,
+                    0xd66c32908540c267,
+                    0xd276526333ed24f8,
+                    0x12464fe2207c65d5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f6b38bfaf30f4ac,
+                    0x60dd67a3cab2b7e4,
+                    0x174168639c2b045c,
+                    0x2f4a026d82b8b8b3,
+                    0x8cae4a1db63d0dfe,
+                    0x0ab34fb823a19c0b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb38d308a009a232e,
+                    0xc23f505ef4ac533b,
+                    0x7d36723041a9c7ac,
+                    0x565bbae8657b5701,
+                    0x439b34a8fe34bffe,
+                    0x0a01af834a4fbb89,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb7fb63ba3c8310e2,
+                    0x296616d533f3d9b9,
+                    0x9780ffe8828c7c29,
+                    0x7f50adf08a5d06be,
+                    0x3cd0ed1d27143ab3,
+                    0x0bf325ffb71761c8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x02c78319ad3bba44,
+                    0x236dffae71996138,
+                    0x423ef9a3eb552e83,
+                    0xcda88fc2d8e51083,
+                    0xfe745284ebc1521a,
+                    0x00a8b37df268bf74,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe27c8f09ea1f5405,
+                    0xca658438e986db2b,
+                    0xefc50447103e08c9,
+                    0x8b9ba4499657bf1f,
+                    0xa3454a19d2011c15,
+                    0x1054d31552a0768f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee71dbe28f029c90,
+                    0x61c40a13ecbcde92,
+                    0x739a8031514a49bb,
+                    0x5babfdb734c26c2d,
+                    0xb47636bce21e565c,
+                    0x16029c192a6e4a2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1a0f68ad8b72e556,
+                    0x40b8b76c8764442d,
+                    0x026156de28d2e0b4,
+                    0xf48ba9f0047d95de,
+                    0xdbf65eb3bb4768d8,
+                    0x0d1b78fa00aa6c33,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb0ff54b7fb9bb8ad,
+                    0xa5b43cba0df80391,
+                    0x0d41e8f810398092,
+                    0x0fd2d820f07c6592,
+                    0x97596848cbcd6cc2,
+                    0x03b319b15d680e3a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x977531d23402f6f8,
+                    0xc93626a9cd6e3b3f,
+                    0x2573074536aaf2c3,
+                    0x0b13a8a33f93c6dc,
+                    0x03e6bdc66f05c087,
+                    0x16c39b4166312b6c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfd109bcc4bf5f8a9,
+                    0x3f3364c5c209b660,
+                    0xd8938cea4a974c73,
+                    0xe2bc5e7ab74a809f,
+                    0x7c43875decca5e5b,
+                    0x0706688b007e4a7d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b213bc053744e6e,
+                    0x2d7534df18e54fd0,
+                    0x0fb5dc497724464a,
+                    0x86c6528a031df42c,
+                    0x797262c1d9411691,
+                    0x146c7234dfbd687c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd3c50d5b35cd20a3,
+                    0x40e9d860a0ad64ae,
+                    0x8b8876dcb3357f77,
+                    0xcd6c8ff40b148be9,
+                    0x9facb8a57c014aab,
+                    0x00cf78ae72d6bc45,
+                ])),
+                Felt::new(BigInteger384([
+                    0x115b8be6245752b0,
+                    0x9b57f961767b3cd1,
+                    0xf8318b33dda45575,
+                    0x54233fcfc4a4e938,
+                    0x2ef58971447e828a,
+                    0x18e4a0eb35481d80,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x1b3d51745a167180,
+                    0x360f3fec91558d75,
+                    0x1343c1de74e6b789,
+                    0xa9e002f11fcbba13,
+                    0x9b70ca5738510b01,
+                    0x0eebcb5ffd50180e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1887dfa8bc10d2e4,
+                    0x6f242d890ae64c06,
+                    0x3af20240f79ced93,
+                    0xc39832881dd2d715,
+                    0xdf7ca76ea691b546,
+                    0x0762de0b3044c943,
+                ])),
+                Felt::new(BigInteger384([
+                    0x57fac0c358af6a15,
+                    0x2a20eba68b71fb30,
+                    0x916da80065ee6f18,
+                    0x911ef0ff1f4b254d,
+                    0x90c554ccb872c69f,
+                    0x028c7f1bd8538567,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfa4dfa94902280a8,
+                    0x601f844cf5b66c91,
+                    0xafc4d8e7c7a29454,
+                    0xa839920dd8efcc11,
+                    0x65e8e291d05a84e3,
+                    0x0c99796e54eb5798,
+                ])),
+                Felt::new(BigInteger384([
+                    0xafbcf50bc7a42bd6,
+                    0xa59060cf604cd18c,
+                    0xfe62c22428a43f69,
+                    0xe97da8a1cb51c2d7,
+                    0x52b4023210457a47,
+                    0x146098f1c81a25cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4dd35f33d587c537,
+                    0x124a48d4d8efdfab,
+                    0x708a5ec104052452,
+                    0x79e92d713cd0d51b,
+                    0x2286268fb9de8d96,
+                    0x037bc6ea7277b3d3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4f02c0af7ab3e50e,
+                    0x20190fbcce6aa064,
+                    0x9a05973e580b5c53,
+                    0xd890a8621a231f23,
+                    0x7ae52b3b07a05711,
+                    0x0d83f1ea33178bf4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d2b89300fa74d67,
+                    0x79c3b685dabcc512,
+                    0x74989e475da4c63b,
+                    0x430b1162d56a9396,
+                    0x02902ecf4cb5a7d2,
+                    0x185c6ce4b119f8f8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f1cfd7af56447d2,
+                    0x9d0265f90823c932,
+                    0xdf73449035482b40,
+                    0xccb2ad24b6beb385,
+                    0x551acf0ba2148e18,
+                    0x0b0132d73eced687,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc38e25c1afea58d4,
+                    0x700418b8e3d52542,
+                    0x64f6a6bbc297b7c1,
+                    0xb0ae2be75c7a7599,
+                    0xd61b04525ded1777,
+                    0x018610a17d05899f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xdbe6da2007340de2,
+                    0x8b9bb8c11007f41c,
+                    0x15892363a8add918,
+                    0xcb7b975487393144,
+                    0xa6d4e3821efbcaf7,
+                    0x04f58f66da3d730c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ab32917cd735531,
+                    0x535a78870715ca7b,
+                    0x1a6ae66cbd98df05,
+                    0x9a64f7e1e67c48d4,
+                    0xcba2ac2d14610dba,
+                    0x05e81258e0cec65f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0859c009386b79f3,
+                    0x9fd5dc6a846ad724,
+                    0x6640832c39e8ce1d,
+                    0x6fad3c7118a07f08,
+                    0x2ae8acf2dc7930c9,
+                    0x0c102396d439d0ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4bc5fefd176cf858,
+                    0x247bb41096a1e98f,
+                    0x94a189ac4e9a8bdf,
+                    0xbb48148fb452cd0e,
+                    0x5ddb3be60ce7bb44,
+                    0x0836fa17003ae084,
+                ])),
+                Felt::new(BigInteger384([
+                    0x54ba8e16408f36d6,
+                    0x54a532a57c6648fa,
+                    0xa7fb7d151d2bb272,
+                    0x46f7f5c51b0a3945,
+                    0x6b029b2c5f9cf7ba,
+                    0x0c3a5623d58d78b4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf748d4614c42b677,
+                    0xd6d586a9f8cd2ced,
+                    0xff00e1cf9c19450c,
+                    0x8a2f25b8eb13b301,
+                    0x75b592d0e816f8a7,
+                    0x158ffa2a2522a868,
+                ])),
+                Felt::new(BigInteger384([
+                    0x00e5734719341506,
+                    0x24e5b6c8bfcf91a3,
+                    0x71f6f622233eb506,
+                    0xb9627cc50d811038,
+                    0xd62b7ee9acd853d6,
+                    0x04a61cd4be55d851,
+                ])),
+                Felt::new(BigInteger384([
+                    0xacc68ebe3a2dfdaf,
+                    0xeb9c7c1b3326abd7,
+                    0xd16c2286a0862fb6,
+                    0x0da36e34d41ac827,
+                    0x503d7c32b0d3a796,
+                    0x066594c4cf278af8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbfe0b3649d5d9df4,
+                    0x8cd7ed1016db1c5e,
+                    0x26fb26624262a1ef,
+                    0x76847636feb3dd56,
+                    0xec04f458d539024a,
+                    0x10c5ae9a0a020a2d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x393e71300dea8ea1,
+                    0x9e97948fa1c79510,
+                    0xd7fe4c95fc2e565c,
+                    0x660266c11d0f89db,
+                    0x2828cc7628156f87,
+                    0x09516efa64d0f182,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x77d7389b096738a1,
+                    0x09aada69b994ba85,
+                    0xefa43c8ea6d086a6,
+                    0xdce85e39315fd1ba,
+                    0xf65394009aa86994,
+                    0x05f58f90e51f6cc2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c819712bc02e11f,
+                    0xf9eff4811791f876,
+                    0x3485b9a3036eb530,
+                    0xfbc72a5bcaeff6db,
+                    0x45469c5055d7df41,
+                    0x065f621156dbc5b4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8d14e9ef62b65286,
+                    0xfacbe809b273cbb7,
+                    0x82682077bc942990,
+                    0x960d364a72c20ff8,
+                    0x4ec059f9e5640922,
+                    0x141c0332fc1520a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfbdd5350adedef98,
+                    0x3cc52d534bc2bef5,
+                    0xe2aeaacfbf650229,
+                    0xd7e9c4a5879cbe69,
+                    0x3fd9aa0b4ce0fc0f,
+                    0x186eafaf0651c5ff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6242e4958370779a,
+                    0xdbb73826fbfa94d6,
+                    0x074d9827de822eae,
+                    0x7cd9f0f13dcedd52,
+                    0x68d1de9b5f8c34fd,
+                    0x0cae264d5326c73f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf272aface2b4c541,
+                    0xbb3e664617cac3d5,
+                    0x9b7b91b50860967d,
+                    0xf595410d32e26f7e,
+                    0xa0e8355743116f6b,
+                    0x079ae83d11da5894,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1cfb7f2ab66f3dd5,
+                    0x33a15d5d3ec0a152,
+                    0xf65a1623eaac9b70,
+                    0x24217e2d83d71532,
+                    0x92d78f3516d6751d,
+                    0x09227f9192434eae,
+                ])),
+                Felt::new(BigInteger384([
+                    0x09850e0821e5e827,
+                    0xcaeafcd2cb434ceb,
+                    0x0074633f4529096b,
+                    0xab45bb306d679f0e,
+                    0x1b164cf446463e06,
+                    0x123594a127a4a139,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4bb4164970b37b76,
+                    0xa47ea1abbbf22af4,
+                    0x42b3ea6004c3f412,
+                    0x8d3858996a9dc5ff,
+                    0xe04e2a267a288a5e,
+                    0x046ceeaf4d73b326,
+                ])),
+                Felt::new(BigInteger384([
+                    0x025c3078b1cc50c4,
+                    0x10eaf053238c8dc6,
+                    0xea455b04c2c69b8c,
+                    0xb1bf965a1b71324e,
+                    0x042e182e40f4b26d,
+                    0x02a9bcdc07b2c3e1,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x08bd7121c34360b8,
+                    0x826fca43aa61dcaf,
+                    0xd5466c680fa7a845,
+                    0x25970aa8506cdfc0,
+                    0xb2142c09bb5bebd8,
+                    0x1787c664ea550056,
+                ])),
+                Felt::new(BigInteger384([
+                    0x10cc22e6837fdde1,
+                    0xb41fab501e05ce73,
+                    0xc445e39749fcf459,
+                    0x2a23c26a77cc0cd9,
+                    0x10f87800aa86bfa0,
+                    0x1005f6876f623a49,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16b497725446d400,
+                    0xebe17343190c9248,
+                    0x2578e4c2874bfee9,
+                    0x401962ca549b52c1,
+                    0x9294131b1d47f49f,
+                    0x0c962f11ec614328,
+                ])),
+                Felt::new(BigInteger384([
+                    0x230f6289ffcb4121,
+                    0x9ac9c75a9be651ee,
+                    0xe8cb4c5fe82b984c,
+                    0x767f2f41ae6d0673,
+                    0x900f85155d8d2d00,
+                    0x16c0b384d9baed4f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x276b2eb5e0aa8888,
+                    0x1b5ed4fb410b613e,
+                    0x80313599346b922c,
+                    0x1b518291b6c8dd46,
+                    0x3ed27e7e862976bf,
+                    0x00fec6ceb4504299,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x02ad5c9d745bccde,
+                    0x98f4223b53e95eda,
+                    0xb90f5aefdadfd3be,
+                    0x5d3c307d860ce79e,
+                    0x05cbee5b84795afd,
+                    0x02f3541fbe808472,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0d3aa1e0e87751bc,
+                    0x3be4e40fb60ddff9,
+                    0x1a838fa3641d9c78,
+                    0xa31bc231c8b5a803,
+                    0x7782fad0b6101146,
+                    0x16e442c97f15ff82,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe288af8b05a3921e,
+                    0xeb8e67eeaa719a3c,
+                    0x6723a5abf7d40315,
+                    0xf2d558439874774b,
+                    0x861945544ecf425d,
+                    0x16457d4b0b698e88,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe5e37a9a97865b40,
+                    0x98fdbc7288941b04,
+                    0xa36d892dfc4ff75f,
+                    0xa3744fb38ba0c506,
+                    0xa6162a65bca65ea1,
+                    0x0de308bcb05d60c6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfd2770246543938f,
+                    0x26a60dd1cf4598d3,
+                    0x71d7b9b500ffc950,
+                    0xfdeb0e9eb867ebd1,
+                    0xc360803992d95ae0,
+                    0x05ea33e4a2298a1f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8042d6d3a079017e,
+                    0x945c015fd0e84957,
+                    0x3ccd81ca946998b9,
+                    0x9ce3bdc3a24ec468,
+                    0x29c8e5492c166845,
+                    0x18055f69076e8a4c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcebd18c9256c4862,
+                    0xff4cdddc4754491d,
+                    0xea40e615ac062999,
+                    0x215798829993ca8b,
+                    0x2befffdc581ebd06,
+                    0x19051980d531d250,
+                ])),
+                Felt::new(BigInteger384([
+                    0x541f02ab326bc484,
+                    0x11d3d219a27ecd2f,
+                    0x43151364a09c92f1,
+                    0xff5743fa02116d44,
+                    0x2104dac48ea25527,
+                    0x0edd04105ef560bc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf26cdcd80d24264f,
+                    0x1407343b41ce7d63,
+                    0xf1c990d1c4f991b3,
+                    0x29b91048e18087b8,
+                    0xb374497f5281c929,
+                    0x00480acd7fac63b0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x99dce4589aa6e6b7,
+                    0xf066b4cb1fe26ce1,
+                    0x30bed85b6726d01f,
+                    0x60ea4d745b9ed5fe,
+                    0x91f2011da1abd502,
+                    0x14f788586105cc5f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x50e90d44041eb5c4,
+                    0xe779a0636f43ef51,
+                    0x643b2f88ea20877c,
+                    0x45da1da898a4ed79,
+                    0x064ed76448991550,
+                    0x0e7c373ba3fa45a5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcf6ef763b6a69989,
+                    0xea6547b02c99212e,
+                    0xf88abe5f57884cdf,
+                    0xbacf2b06433ef29a,
+                    0x72d4c1678b04d9b3,
+                    0x0f0a9ac9e43e5bba,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7b062d3427c1e831,
+                    0xbe9c68673f06cf68,
+                    0x63e2c362ea06328a,
+                    0x86dc579008fbe967,
+                    0xfd58547865aade00,
+                    0x0c6f144897c21c4e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0aa7da0adc1a6bac,
+                    0x7fbccf75fd5cb10e,
+                    0x609bb35c80482516,
+                    0x62c8df1068632113,
+                    0x60c709bd59a8666d,
+                    0x0f744e98aa0d35c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb99e73aff75d46e4,
+                    0xb8af44f03af0dfab,
+                    0x479f65d1c27a273b,
+                    0xe7b82361b635d823,
+                    0x4e3501639a95a9da,
+                    0x0cb041b6b9ce4395,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe7b6ebfe41deb253,
+                    0x5ae36b08a9083ad2,
+                    0x294297ae1889e777,
+                    0x867d811bfe489cd4,
+                    0x984e02d10af07dcf,
+                    0x099452067bf87574,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3be504d0276fb28e,
+                    0xfef85c50f2f3062f,
+                    0xe5dce5fd5976a737,
+                    0x68e9e2167fdf889b,
+                    0xdf387de7b2c4d09e,
+                    0x0587fb28a68a7b2d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x32622da1fcd350a8,
+                    0x188f7cc4a471c0f8,
+                    0x6fb76f0c81743cc5,
+                    0xb0c8024a7013ed3f,
+                    0x0722d98f3d20ebe7,
+                    0x167f02b75f96e86a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x62f41b3c56f96725,
+                    0xb790f526466756bf,
+                    0xd862143925a90c8f,
+                    0x160d61b77f59e566,
+                    0x85121be8cc69dac3,
+                    0x154fba15a1b285f2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa13e320051f5c7c9,
+                    0xdfa4326806d99353,
+                    0xca7f9874ca88a60a,
+                    0x551bd16df7753514,
+                    0xd39fd04c91c9a987,
+                    0x1447d647f099276a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0af480d1b1810d0e,
+                    0x94ef554b94d97720,
+                    0x024144deedb2572b,
+                    0x50db270bdf052504,
+                    0x8f8b3aa687edd562,
+                    0x18a06ac3ce83b3e6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x06ba6cd509d64d1a,
+                    0x6bda7d06f5994ad9,
+                    0xb6378884ab611cdf,
+                    0x9f1fa8f30b7b4e46,
+                    0x0ff1d8d3f148b503,
+                    0x12292f613464de6f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3872154187a2b695,
+                    0x2c9dea615523ded0,
+                    0xb00ca4a09fe557b4,
+                    0x488b90b67abd51b8,
+                    0x3616da834cd3f341,
+                    0x13974edee245a8f0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8af0849d1c71590e,
+                    0xd7dc353c26884763,
+                    0x26d5a024ba034043,
+                    0x1937ec81b9289d05,
+                    0x81cd4ce49c87ff41,
+                    0x11f698b4f39c6983,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd438bd13c50fa665,
+                    0xb67711dcf65ae0d1,
+                    0xc428fe943e09fffe,
+                    0x10c8304c8368ed63,
+                    0xd0dea4b6e6bf1a26,
+                    0x0a5eb484f992d032,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x47c2351c56a86e10,
+                    0xb197c8bbe8583f5b,
+                    0xde8605b58dcfffaf,
+                    0xe288cce0a4cdbed9,
+                    0x201c67f91337bf7f,
+                    0x09840a8f0214b619,
+                ])),
+                Felt::new(BigInteger384([
+                    0x296ea5b0ea5d836a,
+                    0x032f0b85cc797412,
+                    0xf21c8a49145ae6e6,
+                    0xbd9f66a518bbf628,
+                    0x01cd70e5a82e2475,
+                    0x19bbffee1327e2ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbadf8a49f69d3093,
+                    0x13775defef7eb91d,
+                    0x293a3967537c84bf,
+                    0xf04d612680ec19ba,
+                    0xc5a4b240c18e6f0e,
+                    0x16693c85afea9d02,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdc745b0c58348698,
+                    0x0122b0dbe7be0783,
+                    0x886dba03eb078696,
+                    0x3600f2fb5ee068ab,
+                    0xf00e0068c2dc6bb0,
+                    0x0b00d5fbc46b20db,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc3e8a0c616d57450,
+                    0x4b60568c848b50d3,
+                    0x246356a3a3ee2075,
+                    0x00c8d4571bda5b26,
+                    0x571debe72ef55a9c,
+                    0x15d56fb3253869bf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x327363b66b79801a,
+                    0x9d250a4ed413a477,
+                    0x35203f8b03d97802,
+                    0x09efdbda515e1f4c,
+                    0x0af44162792eaaa4,
+                    0x00297175b57daa1a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9582f154095d53cf,
+                    0x8a61d5e554fe3f11,
+                    0xbecf526ee2451690,
+                    0x2d96ba29c5f4b9d5,
+                    0xaea0e9c8224180ca,
+                    0x0debeecb539eec27,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0d9e35078e880202,
+                    0x648d53784374e65c,
+                    0x82c807f0f9d3ea4f,
+                    0xb59cf1829a29c7b1,
+                    0x6c829504383fc6d9,
+                    0x0d7dcd3fb47b6039,
+                ])),
+                Felt::new(BigInteger384([
+                    0x955993f72921cd52,
+                    0xfc47b93f7b684117,
+                    0xa5701bc082828793,
+                    0xdb6c70a67f4f9a6c,
+                    0x01293758fc76f2f8,
+                    0x172b98a36d9adda0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2e785b22c11f231b,
+                    0xb3106ade44529359,
+                    0xb69635e430f0f63a,
+                    0xc359864122711071,
+                    0x457aa4e22555eb90,
+                    0x10223f773aa01afe,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7aee62a77ecc5e3e,
+                    0xbeb721b36d547917,
+                    0xf1e116b5fd4d357a,
+                    0x6d31bb8a05fc0e40,
+                    0xb1365a7d1c5fc6e1,
+                    0x125b7c0b212fe9e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19fd3165381c6d47,
+                    0x7001e4ebf26d45cd,
+                    0x2fa263b61b5df5a0,
+                    0xa67a6a410377116c,
+                    0xb97b8554b73d07cd,
+                    0x0377fbc2941ced82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e0d51ac76455ba4,
+                    0x69acf6cbfca74d0c,
+                    0xce3a1949a9502d29,
+                    0x78be5800e24790dc,
+                    0x8805a9ff82ca20fa,
+                    0x158dbf52962656d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeab06d616f04d2ce,
+                    0x961307d1943a2c12,
+                    0x6b9089aee1d35fa8,
+                    0xfa4050d7f52d4507,
+                    0xb6903244c7ea67a9,
+                    0x04dc63449afd03f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc429ae7157c38306,
+                    0xbefc9acd9f0e5182,
+                    0xe8212ea2c6c0bdd5,
+                    0xbc384e41163f4e9f,
+                    0x30adde2762e6b985,
+                    0x169b21211a6c26d7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x06787db0b4609369,
+                    0xf23e656fd14f3b48,
+                    0xa737b35f9abefe10,
+                    0xe66c48cb65c3d2eb,
+                    0x5b05ba8ecd4cdd7d,
+                    0x0b95ddb199cdf8ee,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd90117dd93dfdc0a,
+                    0xedef08c13fd5b845,
+                    0x80894468a7393d40,
+                    0x8446b2a7f487c896,
+                    0xc85f4141f3504d2e,
+                    0x0e4e5b657b3bea9d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x67ae44002fc6264e,
+                    0x7050112e5494f605,
+                    0x7e1402fc4ccf0da7,
+                    0x0bcd4813fe0767aa,
+                    0xd5064b2217275201,
+                    0x116356040bb1373e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x819f853a09eb765c,
+                    0x67e93a592365f4b5,
+                    0xb7e21c5af3c1fdda,
+                    0x71dd5b62853ea4fd,
+                    0xb525aa6e48a3a862,
+                    0x0f9857206a341611,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdbbdd4c72957c6af,
+                    0x3168907adc132596,
+                    0x98e26b79382c0156,
+                    0x1c6fb7f56bde5200,
+                    0xbde1acf3e70da70b,
+                    0x15bfbda05e1c89c4,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x0fa53e3edaf59a5f,
+                    0x1874d82de86e8352,
+                    0x9af4b4edde7698c0,
+                    0x0e693bce4fe5d97b,
+                    0xeac08f1ca3939135,
+                    0x1133c46785fee71a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeb4f9366e77756a2,
+                    0x4a8c08db67df0496,
+                    0xf86b8ade06415f00,
+                    0xb51a3574dd44880c,
+                    0xfca102f2cfbdc5b8,
+                    0x0437ea58af50f4fe,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc636c5f6aa2bfab4,
+                    0x0b8249ee6252b7f8,
+                    0x38f4de47c447cab3,
+                    0x96f8cc4f2659af9d,
+                    0x94e359a5ecbe268f,
+                    0x049b09e26cba754f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe2f6a0734b3bada5,
+                    0x3e00e47cb5b95b6a,
+                    0xa92256bece32d65b,
+                    0x4375175192ce2f50,
+                    0xf081346dee0facdd,
+                    0x09803969f721de6c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x739492d44b509917,
+                    0x925a51f6df852d26,
+                    0xf380daaead6b4a20,
+                    0xb136e0868aa2d113,
+                    0xb1387799ffc4ad70,
+                    0x13d88a22fd2b8e7b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc0083f4b7cb1e245,
+                    0xec7abd8bd42ab9da,
+                    0x567922a99e244669,
+                    0x51bda8c7ae9db28b,
+                    0xdc03a9fb296b2351,
+                    0x191c29f24a5a55fe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3475356901f0bb2e,
+                    0xb21d08f47cd1d75a,
+                    0x04aa7b4af1f321c7,
+                    0x01ce715508ec8b7d,
+                    0x0d840c38db3352f9,
+                    0x10f2907a4ecd0e17,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf130ab4cc3ed2de1,
+                    0xf4f65b5a609efe4b,
+                    0xbe213161a6e0db15,
+                    0x17fd56b762d3c1a6,
+                    0x4d75005abafb2278,
+                    0x0ca86c6d18c9bd46,
+                ])),
+                Felt::new(BigInteger384([
+                    0x23ecad576cd904c0,
+                    0xb4cacf4f1448b375,
+                    0x3a42085963620670,
+                    0x3ca61a9dc236742b,
+                    0x2f79bd1495061a9a,
+                    0x1918501d93315d99,
+                ])),
+                Felt::new(BigInteger384([
+                    0x897f23b064277e6a,
+                    0x4d921a87a486a5f0,
+                    0x87b869905cd20586,
+                    0x9f4a20adbb509b70,
+                    0x944fd76ad6ebf947,
+                    0x1023d1b1b21a7026,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x9d99338d52e13b87,
+                    0x61c01209d3439a98,
+                    0x3748cb7e261778b1,
+                    0xe52918fdd7095bfb,
+                    0xc3a1cc1ae891aeb4,
+                    0x1094c00a718c42df,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb1f6fa5213eba36e,
+                    0x729d4c32912958d8,
+                    0x6864597a7e9f78bd,
+                    0xb41d8e22bd16d07f,
+                    0x3e1dd65655f23356,
+                    0x0bc88a8acf133cca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d3fad449142e681,
+                    0x9603cbf6d28b1693,
+                    0x539c3dcfaa205a47,
+                    0x595f42d94859ea82,
+                    0xcec7f1f70cbf6993,
+                    0x1543ee91a167d86a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x29609f582d6aed37,
+                    0x310e435aa379aa45,
+                    0x6bc48a1a54e82777,
+                    0xeb63aff206eb317a,
+                    0x31cfa25278819d8c,
+                    0x14d7e73077d07db3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6af08fa22fd19698,
+                    0x559e49912becc3db,
+                    0xd8c674b4b9da4d24,
+                    0x2acb0eb73a5d37a4,
+                    0x66cf4d94cb4aa1f7,
+                    0x11e9846e19e9869d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe1aa81afe795ecb8,
+                    0x312546ba197f4a3d,
+                    0x2f94eef08ddc8fbf,
+                    0x9c8a6b257fbd07c6,
+                    0xba542079fd9ae493,
+                    0x124f9051c29c5614,
+                ])),
+                Felt::new(BigInteger384([
+                    0x743bcbe57d9d1d90,
+                    0x8eee052f8dbfc6f1,
+                    0xc42e567511b8ceac,
+                    0x090258c218194c96,
+                    0xb7e7ee6d01d3cf5b,
+                    0x0ee90b44815a5cc0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x64b5f8d913c6fa82,
+                    0x914ce92aeabc1b19,
+                    0x255dfd8c16c2d2dd,
+                    0x988f3e19124c08fd,
+                    0xebe8f819efd79e70,
+                    0x0aa74094ae7092e7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45d911a71d7fac65,
+                    0x6ecf7f5c11756228,
+                    0x21c6056d468bc683,
+                    0xbb16510e319b7863,
+                    0x8f9cea648bdb72af,
+                    0x0f75a2612583232a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xefb94d0df64f672a,
+                    0x20b6776b11f4a841,
+                    0xff4786b3b00bd46d,
+                    0x431d8fed905de78f,
+                    0x6381424757828c6c,
+                    0x02ce4ac3fd7edf1b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xdb97d22f0bc31c63,
+                    0x6b21da022f27a78a,
+                    0x8c1d52b69fee58c3,
+                    0x78f76f215bef3a89,
+                    0x5065e729896fc395,
+                    0x0591d619241fa721,
+                ])),
+                Felt::new(BigInteger384([
+                    0xed1b7b33717f910b,
+                    0xb582c07b04d9140f,
+                    0xd8a38c2deae7e98c,
+                    0x77bf90ff88e8a7f2,
+                    0x646ebfa33e32e8ac,
+                    0x194876cf35fa1015,
+                ])),
+                Felt::new(BigInteger384([
+                    0x03af77f757e904d4,
+                    0x29ea24ca84646e18,
+                    0x616a63747ade1585,
+                    0x377e1578abaa558d,
+                    0xd66ce3549a1071da,
+                    0x16887ec6624d13a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5632ccbd687ac716,
+                    0xf5fb61fd42e6cb6e,
+                    0x4c2518fbcffa0aba,
+                    0xd0b0dca2848ea1cf,
+                    0xf76288a7ad3db46b,
+                    0x19d763163af40574,
+                ])),
+                Felt::new(BigInteger384([
+                    0x35cbe6b41186fb24,
+                    0x2a175ce58ed837e5,
+                    0x7143eb31eb6c1787,
+                    0x62c7302e7ee85673,
+                    0x499a554ae4584205,
+                    0x0513f810e3030f35,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc9ce3ebff89c03b,
+                    0x1195e716d6c1176b,
+                    0xe9a87dfaef5179f4,
+                    0x84f4445b1c1a4ed2,
+                    0x78e5e4db1c2b7674,
+                    0x0b22e687264b6867,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfebcae9ebf6e8685,
+                    0xe25462e99b4d5817,
+                    0xffb1f0f927e54b3c,
+                    0x6db63afdbe6dec66,
+                    0x6703e7482ee6fa24,
+                    0x0a9f3f0c6fc05701,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44df4532592398d6,
+                    0x773219b4b2ecd3d0,
+                    0xdc3b58d1263dba49,
+                    0xe6125dfc7483d38f,
+                    0x3f55b5f92dc2f2d1,
+                    0x0272fd0ee3e092be,
+                ])),
+                Felt::new(BigInteger384([
+                    0x70d5dfc620b94a5d,
+                    0x97f2c3e531b9e8d1,
+                    0xd2d689a8d8cc6960,
+                    0xfe083cfdb3e9c774,
+                    0x71df0a412e24a0f8,
+                    0x0b65b06f4aa70ab3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa71c93b1d09176fd,
+                    0x7143e326ce3ffe76,
+                    0x822858a5de83dea6,
+                    0xceb858018a5b2652,
+                    0x2eeb871b5012d0c5,
+                    0x0e63c4700d192716,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe96da845468761a1,
+                    0x3937cde7f26c96f7,
+                    0x5d4599da83cd5f04,
+                    0x56380bb656e951a2,
+                    0xd5c7514dec2085c1,
+                    0x13c1e873639904b8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5769689444e2e1f8,
+                    0x4c4c0461825cb56a,
+                    0xd025b1e67814f829,
+                    0x6d7c4e4be1622732,
+                    0xe852ce4c324d936a,
+                    0x0f5d32286882d437,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdaecdc1b0998caca,
+                    0x63ee963e796bff78,
+                    0xef3ff80792be44ae,
+                    0x8d2a923e9b9f26c0,
+                    0xb0acee152837bc4e,
+                    0x043897ef58066a22,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0ab65c6eb2337906,
+                    0xb865b8ff45927bcb,
+                    0x9ecb7b441ebc8cb4,
+                    0x24b498a1c5ea600b,
+                    0xda0fdba5e0d6d860,
+                    0x0eeaa7a4b1cdd003,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f161af6398cebd7,
+                    0xcea2d1e14ed5dc31,
+                    0x5426b2dae23210a2,
+                    0xb0735e6c7bd58cba,
+                    0x790af68650899a69,
+                    0x00504c74fdb37825,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb8cc834639c00980,
+                    0x06eda46f825408e1,
+                    0xe7e86aa94316c151,
+                    0x6e846a7223c1f304,
+                    0x594b52f5295f2547,
+                    0x09ee16fdf7fbdee7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1ccc70db119daf9e,
+                    0xc3bd37ad740f3e55,
+                    0xee51ebee871bf905,
+                    0x476df278e2426cb6,
+                    0x04acd9f6a0998246,
+                    0x0dc505fda2e80b05,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc1d1cdb0f46ba9c8,
+                    0xd09376345796114e,
+                    0x2142470e42455e76,
+                    0xb596080300f9570e,
+                    0xfa5b9e6058b82bfb,
+                    0x0abe6895a6166d80,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdc50af805c30eaf6,
+                    0x22ba3778d4b3505d,
+                    0xf7d219752e2f33e2,
+                    0x667c5f139f4b5eb5,
+                    0xefbfeab52e17349b,
+                    0x01ddb91223763939,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2e31e8d2acf18279,
+                    0xc2cce62673cc198e,
+                    0xe06d754d51c52a2a,
+                    0xf79ca88db70b4ef4,
+                    0x1c20aca461da45e8,
+                    0x1676f0eec4ee44a8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x97273323eba300e7,
+                    0x8700dc6b967529a0,
+                    0x3cce89fecca9e2fc,
+                    0xb7a4fd97f64f1151,
+                    0x640f72bc7ac4b7d4,
+                    0x11de4fd65e9c78ed,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4424345aab6b4eca,
+                    0xb456ea8413d214a8,
+                    0xbf95b7958d05bde8,
+                    0x68ecb3acd627a69d,
+                    0x9d15a7cb269f9e54,
+                    0x0856ab54e77b4b74,
+                ])),
+                Felt::new(BigInteger384([
+                    0x79d556395670b366,
+                    0x13e160a0de5912e4,
+                    0x4e2cd325a00c2f19,
+                    0x8014031e7129c265,
+                    0xbf21f19e75e3cbe6,
+                    0x1350e34caa3b3ba8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x922ba8ccfce87c88,
+                    0x56bb1e571b9d99ea,
+                    0xa23f21291d942745,
+                    0x231eea6e649bb5eb,
+                    0xc6ecdcd52140094f,
+                    0x0ac96e013cc2f315,
+                ])),
+                Felt::new(BigInteger384([
+                    0x271d0515d80fc814,
+                    0x3eecd4a99a8efc18,
+                    0x1fc40e59881eb84e,
+                    0xe6e8aea8c2997184,
+                    0x41456668252c8f7e,
+                    0x117630e6e3420492,
+                ])),
+                Felt::new(BigInteger384([
+                    0x11879d081c852325,
+                    0x858dc1a5494093be,
+                    0x8f55fe490e52900f,
+                    0x45e17ab1a3df8534,
+                    0x090d6ad88ebfd6fe,
+                    0x00ec0ce69775a11b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x03955a0c048e219c,
+                    0xb45a30c741abac87,
+                    0xa35f432d4ffed4f6,
+                    0xbdadc33151d59732,
+                    0x2cde2dba319d3573,
+                    0x1921d21e60117f69,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa829e10e42c3bdec,
+                    0x3e7af801973e2a23,
+                    0xebe999d38bbf9ddb,
+                    0x21452227e8f79077,
+                    0x46768fa2cfd704b1,
+                    0x0f27a33f1cd77a80,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7d773f3fdfe23eb5,
+                    0x2f956f68d1aa6163,
+                    0x7593585093bb168c,
+                    0x4a79f01e76f7e0c5,
+                    0x62d209ca602bc496,
+                    0x17a5fd13863563c7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd6f380eecd4557c7,
+                    0xd545a9c99e7be7ad,
+                    0xf9da927ba9b3d979,
+                    0xf564ae1691287747,
+                    0xbb2ff68ee0998253,
+                    0x0c508f8b763e0355,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x91e95559c7293d63,
+                    0x1e3f4d37e2cb3f08,
+                    0x2db8ff9517c4a46f,
+                    0xbba8513313f62ef2,
+                    0x7712e49662045021,
+                    0x12d0bbc222ee749b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ba18bf2cdc01d73,
+                    0x6288e76e820db2c0,
+                    0x5645137ddac256be,
+                    0x8c13dbdadd2f6838,
+                    0x6aaa67279ede72b9,
+                    0x13e324015354b4c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x29de240d26d07b5d,
+                    0xf631080df3afe4ed,
+                    0x976b6815b433432b,
+                    0x4b55cf8cb0e6dbe7,
+                    0xe9d117c60bc74a23,
+                    0x123e750ed6bd6cf3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a128e65379013fc,
+                    0x0a885f6db8460e09,
+                    0xbe688b86662867a6,
+                    0xb4c84b1ad7f5c5b7,
+                    0x4fb035dd8ab157bb,
+                    0x18a84b67d984df71,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9b9291359092e39e,
+                    0xdb80a3b45fc5b284,
+                    0x1f4f1ea3ea0fca30,
+                    0x40e347a16ee4039d,
+                    0x65dd56b421840906,
+                    0x0298e9e457b505ed,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66349a904d6b8cec,
+                    0xfa9b016c18ce9b21,
+                    0x62984096b605bb2e,
+                    0x888ad2c83ac557f7,
+                    0x0b5388581dc55b3b,
+                    0x16dac52bcbf96489,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6e24eea60898b959,
+                    0x29b695a0474aad2a,
+                    0x147a180de6525d6a,
+                    0x928eacf36d77eebc,
+                    0xe8bfc6fa14c98d84,
+                    0x16acbf0adcf7d4d2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb34b7be648fec32d,
+                    0xcbef511c2b78057f,
+                    0xd42fa476b9b11d5e,
+                    0x5df3973c8a504379,
+                    0x35c48a5c6f89e6ed,
+                    0x0821f9ca9739b205,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd6b7eca286900e2,
+                    0x90445aaba455454f,
+                    0x043ae0edc59c4554,
+                    0xbad6bf697584a6b2,
+                    0xecb09be0aaf6d443,
+                    0x08bb08656ad9adc2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7708d649d5218421,
+                    0x07ec1a7a7b0e666d,
+                    0xec30044f2c814fb2,
+                    0xbcf4a8329353da66,
+                    0x71273dab8960ace8,
+                    0x01f42d1ae88a99a7,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger384([
+                0x06babcba7b8086ec,
+                0x9b41852f5bbdf097,
+                0x59a011791025d9b9,
+                0x58b64aa69afffd97,
+                0x8e4b6b4ce049ea28,
+                0x17e1427d6123e07c,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x617d98c85f414a31,
+                0x42b33880a99a8ce9,
+                0x819a2de046bf47b4,
+                0xcb9e123b44359246,
+                0xd6a789b352410e75,
+                0x0fe82d012887302e,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x016bb378a01d1b69,
+                0x4de69a60087049eb,
+                0x57196c8f2919d4ab,
+                0x1ad0156ea08421b1,
+                0xdad113643d221219,
+                0x0723da616fc83999,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xeba67f96b5ff94b8,
+                0x8b8f64e3b08970a2,
+                0x9a822537810f66f1,
+                0x09180ba71c6e9d33,
+                0x8f40a8f8a6ef839e,
+                0x121852c910d669cf,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x19e34b7a538d2a37,
+                0x0f7fa3e941ebed50,
+                0xd2bcd09023525394,
+                0xabb2b27689f1fc3e,
+                0xec705ea073242d82,
+                0x0da3e92eb5c40cdc,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xcbf19fdd910169d0,
+                0x2b59dcbdcff823d3,
+                0xb04fb1032c724117,
+                0x9cdbd4a340ea2175,
+                0x20c4faa595a0e862,
+                0x0fba55b9bf473ff2,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x7c11014bd69b41ea,
+                0xf98278a72b65e875,
+                0x8cbcf8202bc91115,
+                0xc079d17bcf44a9c4,
+                0x70794348bf419613,
+                0x11afafac959cc3a7,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x5a70abc29eaeeeb5,
+                0xe7c2f427be17cf9d,
+                0xea99c8f7993753f3,
+                0x51ecd720699c3ee5,
+                0xe13fabc8775434d2,
+                0x0b67d905a95ae32e,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x34a336870a38cd3d,
+                0x02dc4f0f780a4f8f,
+                0x772bd48b5f521285,
+                0xc5dadf80c5904ce4,
+                0x58fbc8a3665f9bad,
+                0x09c92052a2ba569e,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x64fdcb75dd9260d3,
+                0x9ea3bc3c30e8a01a,
+                0x648d0f7a2e1c8524,
+                0x44bc1b905b5185c6,
+                0xf5a8137a3e12cb25,
+                0x057d982245cd6c00,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 376, 377, 1133] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 376];
+        let mut opad = vec![0x5cu8; 376];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
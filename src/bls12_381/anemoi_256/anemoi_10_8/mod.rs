@@ -0,0 +1,2775 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 480 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 8 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 8;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 14 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 14;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x93de64ab25f99d73,
+                    0xc374f419ccb65c50,
+                    0x934d1f7cf34700c7,
+                    0x61448d8e23d73fb9,
+                    0xa604ca00853946d0,
+                    0x0e8b397253a7a351,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0333b00c968de837,
+                    0x075013a72901dbdc,
+                    0x2dededafd941b20e,
+                    0x21a17d130fd5a39a,
+                    0xa336b9ed789a25b7,
+                    0x0a80a445936d3aa7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf7ce382cb52a6872,
+                    0x4cd84b0055544ee9,
+                    0x1f9d68d4b4a84d90,
+                    0xd36a5b13c8504429,
+                    0xeccd19789586648e,
+                    0x00fe90c3ed915162,
+                ])),
+                Felt::new(BigInteger384([
+                    0x712f3ea8605870be,
+                    0x71c6f7b8aead2c07,
+                    0x3393cdf0c954a6ac,
+                    0x3aa5672ff71ea6cb,
+                    0xa13d2158f0ef221a,
+                    0x16efcdcee430c374,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd4b1dc896a2a7b51,
+                    0x7e8425aaf4714dbf,
+                    0x64a3bda1a12c5792,
+                    0xa98f21581ed7f8ad,
+                    0x886b29ba942482c4,
+                    0x098e2d3743cce318,
+                ])),
+                Felt::new(BigInteger384([
+                    0x61b280844827497b,
+                    0xf5bda930ffa8cbf1,
+                    0x2d8ef4b41d7f3a42,
+                    0x1ca20ea324e5e441,
+                    0xb1cb1004365e529e,
+                    0x123954d1f9cfd934,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7a856c1a12eb253b,
+                    0x37918a28f782c1f3,
+                    0xf0a3e2d7c6f192e7,
+                    0x2bcd9d48a67d641f,
+                    0xf461dcfcb0297edb,
+                    0x152225b731b61ca1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7eff8f2ef922bdcf,
+                    0xcee1631e38ac6c7e,
+                    0x70a31f225eed5dec,
+                    0xeb377cc2ab554bc2,
+                    0xe42acfdcde6dab64,
+                    0x0e51b438ca8bf814,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb309fab7d149d395,
+                    0xd74b67cf0f2eafdc,
+                    0xcef6c6696fa66c90,
+                    0x1f7a4aa08ea0fa5b,
+                    0x91e637dfc04beab7,
+                    0x0ee0b7f621c9dd54,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf871535f8b5bad47,
+                    0x3246b26d8f435357,
+                    0x73e3c6b8e8e52bdc,
+                    0xde17db4ceffd6213,
+                    0x7fabf8e2be28ea17,
+                    0x03859a4bb04e4717,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6de4c0c53fea72ec,
+                    0x44c486590bc612c7,
+                    0xd1eaeb9f000c6c95,
+                    0xee345e2e05ee1512,
+                    0xa3f79193067665f0,
+                    0x1230f74717788b2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d7163d09c705485,
+                    0xf4144c09ec5cf857,
+                    0x94a826b29c3e8082,
+                    0x9549704c2bdd8bea,
+                    0x8df2077f4ffadcba,
+                    0x17b608593c7b4d03,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21d76bbf4bada6aa,
+                    0x0789512a55c5b971,
+                    0x6df6c5a8555fee11,
+                    0x6ac6d7dcf0bd1c3b,
+                    0xc20dac966db9929a,
+                    0x0eb0b8a3bddf9b95,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9e424d44b26d4ad6,
+                    0xf23c2a366166b8a9,
+                    0xfcc42e7eb4dc1f26,
+                    0xdab60e8f130f3dba,
+                    0x136c99c8a5978641,
+                    0x0ec2be7237ea2b2b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xade6f1b7eae71098,
+                    0x5bf2a1ee58a12d6d,
+                    0xca450ff4126533fe,
+                    0xd0cc55ac8359f247,
+                    0xfc18af7cc2e1dc32,
+                    0x0f27382e6ffac3a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xde3fa16efbdd0e18,
+                    0x2daa467926b28eb1,
+                    0x7e61db1fdd084d7e,
+                    0xa03069320133ba66,
+                    0xedf845b10845e83c,
+                    0x168a5769a71c903d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd7309227dc99f2c9,
+                    0x0bd3fdcee591b02d,
+                    0xcec738dac750d55e,
+                    0xcefe7c2c98146e80,
+                    0x73e2f30769bdf6e9,
+                    0x0c76f6051f4bc5ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb8be951bcb1cf4d,
+                    0x67f19b072565098b,
+                    0x6ea8c954738bc10d,
+                    0x9517db6a939a257f,
+                    0xa877184d24982d94,
+                    0x0b378502e3d28283,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221fdda397457eaf,
+                    0xfc9f7c3a692f835c,
+                    0xd0e0eb47a0729fbf,
+                    0x7650cc7975b86edb,
+                    0x47ff8f16f3956036,
+                    0x1541681b32042c6e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdeb54dd028f7bd5a,
+                    0x731dc0a56bf9cfa9,
+                    0x98ddf404b35581c9,
+                    0x248c4d0c2d13bcf8,
+                    0xb034b8a38c027862,
+                    0x1001cc7f8f56d583,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf43ff233dcf172e7,
+                    0x3e3a4c5335065ade,
+                    0x71873f2f6108c87e,
+                    0xefdd6f74a2c1e3d1,
+                    0x1f8900228001932c,
+                    0x172e9cb49329edf7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xec83a1cdf33307a6,
+                    0x62b2992016621197,
+                    0xe5f8de639bbc5f02,
+                    0xd002855060dd5417,
+                    0x4948067c42323a48,
+                    0x05c8f8ff03ef1480,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8f860889fef92fc3,
+                    0x96d2d6cbc6b36c1b,
+                    0xc2ec465e2a41db2b,
+                    0x7640a9278fb3eadd,
+                    0xadb9a706f34fb06b,
+                    0x17c37b4ba7bce571,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a034cad62c2158c,
+                    0x7407313440af9cd0,
+                    0x941439442eb1f679,
+                    0x0864167db399dc5f,
+                    0x939c9a6f64b1a285,
+                    0x01e8017060893e44,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9d4476898c3ea443,
+                    0xed6bb9352d877b31,
+                    0x041c1bc62b208e6c,
+                    0xbd0e9dd76f3c899f,
+                    0x4e581c0ba60f1fa3,
+                    0x0f56bcd33507587e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d621589ce38e302,
+                    0x757689e94f219d4e,
+                    0x3b9c2808cea0b64c,
+                    0xb1fdce5063289930,
+                    0x0e4e1289e1e986c2,
+                    0x09a5cda6e7f8cda8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5eda79f33a007809,
+                    0xcdec8898eea13f9d,
+                    0xcf08f8ef3571a381,
+                    0xe649aa7221d6c943,
+                    0xf6b9c4be2596c480,
+                    0x0bbbcd298e412fcd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3e79f2187814f207,
+                    0x425911e599b899e9,
+                    0xf4133731be6412f6,
+                    0xf20f2cb6dcdd5ba3,
+                    0x4191b60de6989ee5,
+                    0x180877f6a1c67eb2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd00c210d7f1f8a98,
+                    0x76916a1595dd6ba0,
+                    0x8037bed7ac19444b,
+                    0x1f6d1539ed010151,
+                    0x2931248a9e405c72,
+                    0x0d6e3c9197a1ff11,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b57d4035b0aebd6,
+                    0xf5e999a09a1d5f64,
+                    0x8037081785e8ceb4,
+                    0xdc622b8a00eaa10a,
+                    0xa5fd8d59633c181b,
+                    0x0cf3a98d857d21d9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xddee0fb61850bc30,
+                    0xdfd027e74270465d,
+                    0xfcab70c15b31a8ba,
+                    0x95f9bc8690b02c16,
+                    0x36f07c6c3546ea5d,
+                    0x166f631293e6ae9a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x653c98263d00bdf6,
+                    0xde0cfe37ac2febfd,
+                    0x397eba8268a5e3b7,
+                    0x002a82239ca94760,
+                    0x85d8606512ad9f50,
+                    0x0e68b611e007447c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x833b67ff83d9904d,
+                    0xecb1c940889c534f,
+                    0x937233b485c25625,
+                    0x9a39a2628c51549a,
+                    0xca24df0bc89b6964,
+                    0x13c37e55c0b0d561,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56d8696a315cf0f4,
+                    0xedfc0143bbb9f67f,
+                    0x9341c70d4046302e,
+                    0x6e5aadb11b6c6edf,
+                    0xfdd582feb890cf9f,
+                    0x07e73e0f0683e644,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb58638a9ee1d40c6,
+                    0x8c75c22be224b7dd,
+                    0xa55eb1929c4af684,
+                    0x2867661b11e12a2a,
+                    0x0da581e2d8f0ed4a,
+                    0x0742418c60acc52a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9a9e2b2b44b0847f,
+                    0xcb6647648a40c444,
+                    0x114b619f11ab8a2c,
+                    0xecc85aa408ae771d,
+                    0xe30a785615773c50,
+                    0x1181685d8d81d47f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x986a6e7d018895bd,
+                    0x7efdeb27c87198e9,
+                    0x62ca78946d009f94,
+                    0x81e63ef387377abf,
+                    0x90377828e539b9a2,
+                    0x1635f11a98ca0ef1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfbe5a632a8b86dbe,
+                    0x2ced9293f87dd139,
+                    0x4b42bb32f1947555,
+                    0x16f3759b80de0510,
+                    0xcbcde91111c982f5,
+                    0x046b2b2041d8a40e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71bd19e0ec2d4835,
+                    0xad67325f0fe4a8ac,
+                    0x3e6bad33dd2fafef,
+                    0x5c450aa7270cc9b3,
+                    0x810233270bb43d89,
+                    0x027fa86a850e77d1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53870d1621bf9d7e,
+                    0xfce9ea7f1bb222b0,
+                    0xcf725d7cc2995afe,
+                    0xf9f97088f4cfc43c,
+                    0x8b10e575ebe5a06a,
+                    0x191223152d8d8d6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1c9baeec82d8e8b7,
+                    0x353090558d63bcca,
+                    0x6f99b5dc0faa8c03,
+                    0xd67a5d4a1646cb7a,
+                    0x68280839bbc29340,
+                    0x0bc81013c6f455a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc120fa2bcdde997b,
+                    0xe3fdeca253ce41a6,
+                    0xa2afe4938cf95a9b,
+                    0xcf9f0090fb9fdbad,
+                    0x7f115cb23b9c24d5,
+                    0x171ab93d5331632a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2893c68ff085a890,
+                    0xa06ee1f463e9e60d,
+                    0xbd299e4fb783672c,
+                    0x8bca6f8f1c22fe9d,
+                    0x846f5c88a0a30968,
+                    0x08755dad0c0c8398,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba53871ca0fdd239,
+                    0x93f6394073d7e696,
+                    0x996c360cc0203290,
+                    0x2722befd2051053c,
+                    0x1c4497c4bfd62333,
+                    0x05808cba418cc779,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9d8e463249d9db2f,
+                    0xfd7bb7a65787a257,
+                    0x438b3ab05283375b,
+                    0x5e3ac79d0fd685f1,
+                    0x8adc848ade58b56d,
+                    0x0f7fe4ab4aa2de92,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a90123c69f5c9c1,
+                    0xae4df6e0a6fe068a,
+                    0x0b65f08e9113a9b8,
+                    0x4b09b9def881b3a9,
+                    0xddf53b4801dda337,
+                    0x05326bccbd1b1c7d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xab8f029fc644662d,
+                    0x21c81eda9fdf5c1d,
+                    0xac5dca3fe83e7a8f,
+                    0xa050a82347393087,
+                    0x27f6dbce3b7bf694,
+                    0x110d331dbe3c1957,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6709cf8507b3f015,
+                    0xd0381e6c4a299796,
+                    0x5f9436ade1321c98,
+                    0x1f9fe47cad2fefa0,
+                    0x140592d6bc25b27d,
+                    0x192c450fab51532b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x85d7f0dab12b8935,
+                    0x8d176181a0cc9c7e,
+                    0x2236f364263c80eb,
+                    0x06bcd8245b30c9dc,
+                    0x84b2818b69950d14,
+                    0x0dcef40b37d7559e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5fdfcc49ecc1d992,
+                    0x81fe8b6b8dbcc02b,
+                    0x8e70285aab6bdc8e,
+                    0x1116bc1add032439,
+                    0x2171d112541160fc,
+                    0x065499aa9e02a0ab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb485c20a2fcff5b2,
+                    0x2e73506022776b5b,
+                    0xc0bca98e16c3c32a,
+                    0x05ec23746786dc44,
+                    0x424b4db6bed4b54e,
+                    0x00dbd7ca73853c87,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13630297fdecdf10,
+                    0xdd018676fc1e48ca,
+                    0x76e47401510f11a2,
+                    0x362d0902c6916d19,
+                    0xa6f1a032549b8a20,
+                    0x06c548b76a1289d1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x58fe5ec5cd5e56d3,
+                    0xd1b946b3d9be9b5b,
+                    0x4fbec3aa2f7eda76,
+                    0xca332dc4e5114ee3,
+                    0x28fdb81e956d226d,
+                    0x0942f8c38a575692,
+                ])),
+                Felt::new(BigInteger384([
+                    0x24c91299bea02640,
+                    0x56590ec99d0e7a47,
+                    0xde3cefe702cbbaa0,
+                    0x5bf4726dc45757e9,
+                    0xe0ae8439f91199f7,
+                    0x0bdf5618171bfaf0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43d2fbeb555dc20a,
+                    0xe882d0615a25a063,
+                    0x9e563a98333d0d04,
+                    0xa534d4db0c9ce295,
+                    0x5c621de592da384b,
+                    0x168c71be319a6e09,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7a9ee9d960d94e00,
+                    0x794a566b96a3bedc,
+                    0x926d464eec73377f,
+                    0xf24eb9b022b44254,
+                    0x586677724d2417ff,
+                    0x12c1a37028df9b64,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0587f3dc77b7382,
+                    0xd1533b0938f44288,
+                    0x86e09197a70b174b,
+                    0x88f0ba55302c4f49,
+                    0x94f592eababbf559,
+                    0x054c666cde1ed34c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xda2abb358e122026,
+                    0x9dfc669e918223d9,
+                    0x4edcf0fd6d1d1bdc,
+                    0x794d05c187d29bd7,
+                    0x63978f49ba1691e4,
+                    0x15da46fd6afa98a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa7fe475c3b252e98,
+                    0xc7699b562de1578e,
+                    0x593544b25f8eec5c,
+                    0xdda2bfe91b58d991,
+                    0xae6e6b89ebbbeb9f,
+                    0x085a0f92d67384fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc93a20212a9af6df,
+                    0xef3065f3cfa55799,
+                    0xf2d1269b44845d82,
+                    0xe534744653526d1d,
+                    0xc621ff19d4b405a3,
+                    0x14044284d2cfe3c8,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd0dd030cc53c27fe,
+                    0x20425648408fd9d2,
+                    0x6297bbf55e90d3df,
+                    0x42c10ee149dedd90,
+                    0xf74e1f716364d9b0,
+                    0x0cc65db674d8c42a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9d2fb3344c2244aa,
+                    0x5d728e0c3a6bc09b,
+                    0x6f70a399c520f982,
+                    0xcf2793f2757a10c8,
+                    0x5581c88e6a4e9b67,
+                    0x17f1b51de4d6f7a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf8273bde6d7b3337,
+                    0xf764958eb464595d,
+                    0x41b22ab071922506,
+                    0x1ffb7cc0ae483942,
+                    0xb0865fd9093898e3,
+                    0x19a1fb5d845147f9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa08c5190976b2bc5,
+                    0x0e3d13a3fac4a175,
+                    0x0069d81f9449fa80,
+                    0xe0312bc4424d1304,
+                    0x61987c39cd817b6b,
+                    0x1671eb38a8bdd7f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbe27b9c690f28c87,
+                    0xaa9b47fae5692683,
+                    0x50acd6ce713cbd3d,
+                    0xeab2e4ca336dcd35,
+                    0x6a314ade35a4717f,
+                    0x0adb72329fd87e50,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa95814036679fc1c,
+                    0x186ccca907b510d7,
+                    0x02d85c9a30d36ccf,
+                    0x0ba8f8020165952a,
+                    0xbd446136bb97b073,
+                    0x16b2e900a3285983,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9bd794ba8956a311,
+                    0xd6176e6540f9b8a4,
+                    0x9f0b41a84ea7e95f,
+                    0x6d362aeadcba399b,
+                    0xbe75d0f68c0b84dc,
+                    0x123948934cacc271,
+                ])),
+                Felt::new(BigInteger384([
+                    0xafd389a3a17c5cab,
+                    0x830731fc93615a68,
+                    0xf807d4bbede41252,
+                    0x45b517b8b0a22259,
+                    0x6b072e72346f8cc0,
+                    0x16268e591ed61232,
+                ])),
+                Felt::new(BigInteger384([
+                    0x858522669f20e87c,
+                    0x79c6ec79211e2b4e,
+                    0x444607f80ea6de14,
+                    0x7a41f7ea579e2640,
+                    0x9a9835cff8c08603,
+                    0x1935b877d6945c99,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1708ceb8ad7388a2,
+                    0x637b508125f7c6a6,
+                    0x3cdf48ade3b308a1,
+                    0x441511ad9cfc4418,
+                    0xb6ea0d14a9bc11dc,
+                    0x0404a9c37d287193,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf928353898f9c246,
+                    0x174995400860667b,
+                    0xd246befc6f5fb251,
+                    0x00c3c5293a02ae03,
+                    0xfac731771e74c81c,
+                    0x055fdf98315cb17c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd6fd15195c0d130e,
+                    0xbfb9f93b382a3fea,
+                    0x6d6d2e39ddc33587,
+                    0x2593cefe01a5214e,
+                    0x573c5407a138ee1f,
+                    0x157c2a6cfadb6690,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5154c405578abe44,
+                    0x0188ac0f06a04ba2,
+                    0x0abe55568c5c65fa,
+                    0x6462c93c91510f71,
+                    0x62d8cdd9fa81ddfd,
+                    0x0697b5248196eeee,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe8da05b9ac4e3de3,
+                    0x573ad52b712c86e8,
+                    0xa3fa1ca3db2461c0,
+                    0xdb594e4fa47fddc2,
+                    0x4cd8845f9400d355,
+                    0x18d8781a5318aed5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaea333eac6a949ce,
+                    0xd1cdb55de8d0cd42,
+                    0x79d7a08c367e7268,
+                    0x01a8e308247954d1,
+                    0x8c0ab807f063d1c1,
+                    0x0742d3c16a74420c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5b2ff8d430aefb40,
+                    0x212e41079032f908,
+                    0x8ae9ae49d54b83db,
+                    0xadcf2e06011006ca,
+                    0x63120fff6e5414cc,
+                    0x102f25484fca0583,
+                ])),
+                Felt::new(BigInteger384([
+                    0x75f4b142d4af22ec,
+                    0x2368ceba940ea4eb,
+                    0x01a883850dc04e51,
+                    0xbc8e617122e07b59,
+                    0x963599513fe8759e,
+                    0x171ebc31b0992d0a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdbf47801d645b935,
+                    0xd54134e3ecb3ee3e,
+                    0x9b91e82a9f862386,
+                    0x24a22f4f181662a5,
+                    0xbfd7c07a803e8412,
+                    0x0e850675d7874997,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d064b2c7ab23549,
+                    0x1c444834fc691c69,
+                    0x1e094197bf457957,
+                    0x6d8078f18b0d7597,
+                    0x642a2861d0dc15e6,
+                    0x0ec7427ae8f9d8f3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x24c4d01eb6b2dba4,
+                    0x0b7d0d7add6d96b7,
+                    0xc84ee94017f8a5c7,
+                    0xe236ebf6c661db7b,
+                    0xaf61a24914adf1d4,
+                    0x155f0657c33d1a35,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x436a7f7605eb0bcb,
+                    0xdd07ce6f96237893,
+                    0xa5e837c043093e5b,
+                    0x0345ac0951c18203,
+                    0xbd9909a89ec40a6d,
+                    0x08773dfecf5862f1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf17431d76f3043bc,
+                    0x3150f964bb2f8483,
+                    0x85568d42183a3eb0,
+                    0x78e3a255e6edf5fd,
+                    0x2d6bf6fbaa26cf8f,
+                    0x08fa14012c1b59e8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x95c953e49258c4f3,
+                    0x81e41e7f0d22ee22,
+                    0x494b71a7ff058e01,
+                    0x80be05f504aaa0f8,
+                    0x006fa1c0b54513ff,
+                    0x19d8a08dea506660,
+                ])),
+                Felt::new(BigInteger384([
+                    0xab040983c5e39ef3,
+                    0xa26ae6ce4522805e,
+                    0xf2516cace02e3fb2,
+                    0xc2194175b6b3eaeb,
+                    0x141bd5f24cb06575,
+                    0x138c61455778c66b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9aaa34d736215047,
+                    0x754ebbcecf18b525,
+                    0x4011e6f2247e4295,
+                    0xd996eb72ca263e2b,
+                    0xd57247c179cd95ca,
+                    0x02b31d2a05c9a5e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a3d41b63acadd81,
+                    0x4ccef5f940328681,
+                    0xb5894ac8ffdcb332,
+                    0x448ffc57204a82d3,
+                    0x9337ba72a3dc94d5,
+                    0x0dd8bd481d1f52dd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xec61ad5d54dec3e2,
+                    0xf3e3f0a20343e3b0,
+                    0xcb56f1e9e31b3822,
+                    0x22e746dc48691c94,
+                    0xf166f358b6abb0b7,
+                    0x12eba15644551d22,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b6c5ce058eb3047,
+                    0x51138cd2126fe9ec,
+                    0x958bbfd2fddc3a66,
+                    0xb200b1b79c72d1eb,
+                    0x449d573c4eb486ca,
+                    0x05a1b34a52d7650a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x05f3912ff5da02c2,
+                    0x0cdff19a98c9c9d7,
+                    0x428e3057a2d30f03,
+                    0x0ce0920f1e9e01ac,
+                    0xfb0f9ae53cf40637,
+                    0x0fe06c9143c42ab9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0d77d65a0820739,
+                    0x72e8fdf0920b16de,
+                    0x6cd845b2140fb8b2,
+                    0xa8de080e784c3216,
+                    0x1032818f21d5f4b0,
+                    0x0a7d8aba2bdbc2b5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xafd5c0a5db68586d,
+                    0x385f2d17684765f0,
+                    0x2fdc1559775e4785,
+                    0x7de7667e0b5f47fd,
+                    0x0f592cf7205aa777,
+                    0x152c815eea1b0575,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8d1ca5b72b1203a9,
+                    0xc71edefb6944ffed,
+                    0x6baf84825742f846,
+                    0x786509b6a2f2ea09,
+                    0x8a09b73d4ce68797,
+                    0x13a92316af58449f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae2e54c6cfa8d8e1,
+                    0x7f3e0a78eb99d3c0,
+                    0x528576d74aa01d41,
+                    0x09dd134f3891c81b,
+                    0xee84f4bb7c565387,
+                    0x1698494405086919,
+                ])),
+                Felt::new(BigInteger384([
+                    0x686f3b002171575a,
+                    0x596fb44a96389ecd,
+                    0x9fc6e2bf2a3c6487,
+                    0xf81e0e2ede4ce849,
+                    0xbbaedbb9598608dd,
+                    0x19c5429257ceecf9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x544590a949866ef0,
+                    0x71fb44b6908da532,
+                    0x581a95263fcfa8f9,
+                    0xb3bd026e4075c16b,
+                    0x9ffb48be1dc19da1,
+                    0x181fd939e3c89d60,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e10ffac26afb4d1,
+                    0x68516413713d159c,
+                    0xe18eb22cd810a6b4,
+                    0x3fb75e9a74ec982a,
+                    0x7b977fa99ab3dce3,
+                    0x0b6829af5750abda,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbb5fc9f7ae65aac3,
+                    0x0dcc245129a72c9c,
+                    0x9a79b9ccdbbe433b,
+                    0x2c9a12d4863a611d,
+                    0xd8ac76f5638c46b2,
+                    0x1837f2e25e2d6b14,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e920f3b6bb295c1,
+                    0x4fbf6539fbb64f20,
+                    0x46ca130cd72c067f,
+                    0x2fd06b838109ffe6,
+                    0x0af4eb2f45f1cd60,
+                    0x13ca983dd8076c5b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f40727facc35dba,
+                    0x1b82c9e98c5fc9d8,
+                    0xf9609f5c0f59c35e,
+                    0x5f0c520496c0faf0,
+                    0x1a796415154ab6be,
+                    0x0a869c14cc72a53e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ff4b7a8d1e8d132,
+                    0xd5a7db66555442c0,
+                    0xa37abd169f5de555,
+                    0x101945d35b3ec6ed,
+                    0xaf19a097fcf1eb59,
+                    0x00c721c67d78add8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x88a067f13e99b3c5,
+                    0x5655a505effb3bad,
+                    0xd780e7d557ff7a86,
+                    0xd51e5f149512de89,
+                    0xbbfb7b2f78da27d1,
+                    0x07b6fac59bab0dca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d7f6fe921428595,
+                    0x9792ede04033da84,
+                    0xbf79abfba92440f5,
+                    0xce04d02387ef9718,
+                    0x5ed3f497f1af161d,
+                    0x05a2c7da7cddb0d2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x666ecffac2db79d0,
+                    0x242aa98921cbc74d,
+                    0xd43b7eca45a4c393,
+                    0x253f72af8eb87b48,
+                    0xfdde63658dae5522,
+                    0x098299995d1a5b26,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf04c04540e11fd28,
+                    0xe6374d47c4c076af,
+                    0xf1b975ee0e3287e6,
+                    0xfd7957e8756e9711,
+                    0x86c36a595f411488,
+                    0x01673848357f4440,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19b0f44fcf50b0be,
+                    0x984c2123962a9893,
+                    0x8824cc0b0218739c,
+                    0x24bb17e20ba4eb0c,
+                    0x10763130c2c03edc,
+                    0x113b81b5504d0231,
+                ])),
+                Felt::new(BigInteger384([
+                    0xff6ef47b1e5e9294,
+                    0x1e862681bc55ef20,
+                    0xd4c76b853c675848,
+                    0x6ba361eb76398108,
+                    0x49b0d4fd4bcd7bb4,
+                    0x0c74e12d078bfdbd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x215aa26da56d671a,
+                    0xbf0b742db05168f9,
+                    0x70c68b05c95832de,
+                    0x97be3864593698c2,
+                    0x731eca1a522f0267,
+                    0x0a9abab2c77f7a52,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae80b2665c4ea665,
+                    0xa6f7b70ae0d5d0eb,
+                    0x67461c5715731ba8,
+                    0x2034a3650343bddb,
+                    0xde57bd2406e1779a,
+                    0x0d18937455d1d605,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd0e67ccfb4649c5,
+                    0xf056ba2134a7010b,
+                    0x87d22267024776b8,
+                    0x2ccdbcab0226f59b,
+                    0x5a96765fa4cf7041,
+                    0x1322d2d3aa003e4a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x393941d0ff362701,
+                    0x60234bcd6ae41ff8,
+                    0xc5fbf3ed7aee0322,
+                    0x0babeb1b671c5533,
+                    0xd7cb7fa9eda37e3c,
+                    0x11dfb8dd19f79b33,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8f839e487646c1dd,
+                    0x30fd753affc8ceac,
+                    0xc95a165edcc4fff0,
+                    0xe070ecaa368a834b,
+                    0xb23672b82e23ff50,
+                    0x0678be0c7a317d69,
+                ])),
+                Felt::new(BigInteger384([
+                    0x148bd6145cd066a2,
+                    0x6939640ee2d421b5,
+                    0xa512acf81ad132c0,
+                    0x56759c7e8cd02311,
+                    0x1eb251b5d1b6505c,
+                    0x0720b1819a21fab0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9892d7c70917fdd3,
+                    0x05e202ec58235f4e,
+                    0x25738fcb97c7015d,
+                    0x88b01b550bd4bc19,
+                    0x623681f458de8236,
+                    0x0319199f5416b0c1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2eee31e1d644c668,
+                    0xdcdb2ce812466b10,
+                    0x0eb4f6d4f9c24bf9,
+                    0x8572efb07aad2482,
+                    0x19aaae0d47fbb5c8,
+                    0x128245652ebd7f7f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe17b32b5bbec2ab3,
+                    0x198b8973180023f6,
+                    0x4cb5c2435a9d673e,
+                    0x5d07fccff696a5e7,
+                    0x47f6f87d774fd456,
+                    0x0c8959df661b2ae2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3429c985efd37860,
+                    0x5c1683174aaa198d,
+                    0xa1b8cd19f3028b90,
+                    0xb5fc6c543baae0be,
+                    0x60a2bf63974c0f0b,
+                    0x03c7b850e21bfa78,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa505b5211f564430,
+                    0xbfa4f3e665566104,
+                    0xfa29e10a6793416c,
+                    0xcdfce8079121ee63,
+                    0x854ab25074938a84,
+                    0x103a6bc1cc8e7abd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x493c28d9a5d893e2,
+                    0x0ae7b51746c53aee,
+                    0x4b2f2d718826c99e,
+                    0x6dccfbd864c954c5,
+                    0x02a6f0b6b8b07270,
+                    0x192ba84de245b751,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f32e432ff2ef867,
+                    0x95dec9f8fcc56fb3,
+                    0xf4b2a18cf79189fd,
+                    0x3023459042d9c0e0,
+                    0xb2f879b05a0284ce,
+                    0x0bfa8bd996932406,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa0f17f68fdf005e6,
+                    0x802a0a5d83754de2,
+                    0x77f5e17065b15fb9,
+                    0x4b5d7f84feb99286,
+                    0xcb31ef474c80b7aa,
+                    0x09d2791e0792c2f6,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x16752a89dbd95ffb,
+                    0xbb5e508a2c87f9bb,
+                    0x99e113d976ab6b75,
+                    0xe86424d830d76786,
+                    0x7f2522197df6cf0f,
+                    0x068069522e004faa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa4e9bbd34e0a841f,
+                    0xf83223baa1923b61,
+                    0x48ab9ee0f1a50562,
+                    0x652c4047f02eccc2,
+                    0xdc104c0e50dc5fd1,
+                    0x1407817992d173d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d5bf87d53b7a2c0,
+                    0xc611c4d000429df5,
+                    0x4f96ed412d777c99,
+                    0xcfb37d1307eaf45c,
+                    0xe1bf405a1e0fbbbf,
+                    0x173e0d642453525f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xad06f813071fd418,
+                    0xa657685e3c012ffc,
+                    0xd40f8f63c6433705,
+                    0xf81861347c848431,
+                    0x669f737adcb208a0,
+                    0x10f3ce2a9ae1d6d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x970ba47a13707113,
+                    0x8feb5cbb6225d943,
+                    0x15d304742dadf062,
+                    0x475ad85950eb277f,
+                    0xa89baa10f2a19488,
+                    0x02e0932b1ff9c989,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7fab97e12d836ce4,
+                    0x76c6f80a810b0053,
+                    0xc960022a4149a5c6,
+                    0x8d6b30721096cdde,
+                    0x6d8e126aa0ebebe3,
+                    0x1687426e571f236c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x07944814e7bf4e41,
+                    0x3e3eeed17ae1d8cf,
+                    0xa03cf7e3b18e2125,
+                    0x04868b2ea5b012d2,
+                    0x77350c74b37d0774,
+                    0x04ba44345c7b9965,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0cb38bc66d7e3821,
+                    0xe5bbeef17cdad9df,
+                    0x26e525d0fb9e64fc,
+                    0x1562b5e2eb3ba552,
+                    0x39e4c2061a20a863,
+                    0x0c0d7b8271cfd676,
+                ])),
+                Felt::new(BigInteger384([
+                    0x092ba146f5800d5d,
+                    0xc0ad839196f5be90,
+                    0x011902f9f33e0091,
+                    0xabf3c37b2af2ad52,
+                    0x0cb52e58a8587a0c,
+                    0x0f93d53cd268803f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x788b13dfee314ece,
+                    0xf2b368a50ea3e3e7,
+                    0x5d65ad45e12b5c76,
+                    0x4fbaccb7e1722a8b,
+                    0x4c1aafa432a8be98,
+                    0x0d0421ae47689f96,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0d004baab038472a,
+                    0xa17431f8d2c08714,
+                    0x440e64e5cbc0b658,
+                    0xa0a336d37db464c4,
+                    0x7963182b41b252ac,
+                    0x13dcaaf1d3722b9c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xddf46fd981d000e9,
+                    0x8b2ad9b5e97d6706,
+                    0xeb47448f66166e10,
+                    0xf2b29ebe1f3764de,
+                    0x02fa5cd7a40a9417,
+                    0x069f2bb05c4aaf20,
+                ])),
+                Felt::new(BigInteger384([
+                    0x00e394847ffa8f05,
+                    0xef7d58e2a451cf64,
+                    0x586ebe7053305958,
+                    0x3ebf3cc101bfbd6d,
+                    0x6666769fff8c1f9e,
+                    0x11dac1895152ca62,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ff064914bd826cb,
+                    0x91d38cb255279653,
+                    0x59567bc5bb4812a1,
+                    0x27241d3196124cce,
+                    0xaa6341da56aba58a,
+                    0x0b6d6465e9737377,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba04b3ab6bb261de,
+                    0x5f893f2959662588,
+                    0xe05ee82a61069e6b,
+                    0xe2eda14af8434e41,
+                    0xaf2b74e7550d8953,
+                    0x0766a6ff66cf771e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c30f5392e4f182a,
+                    0xa79e8599b8754f1b,
+                    0xfe3774412bb328fa,
+                    0xd45e91700bc8f961,
+                    0x8fd25d1b36be7170,
+                    0x16ecc9ecf78f07ae,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1cd792e4c10bf463,
+                    0x412b47addfe845ed,
+                    0x528eba1e2f78a612,
+                    0xdc1d7692bf2bc328,
+                    0x36e0517270b1ab4d,
+                    0x0ac84b0ce95788e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa6b1846a3febd77c,
+                    0xb592b170063ef127,
+                    0x8df5135169e4a476,
+                    0x9acc912767935ff5,
+                    0x7ee0f165889e6f83,
+                    0x07a7fdbdd624887c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x194476a00bb58f25,
+                    0xd97da6d9956c385e,
+                    0x4972de71a5ff7cc9,
+                    0x3ba256cff2df7c48,
+                    0x582d1e49a9ec8aea,
+                    0x18c67b9ea37a019a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd36f7140b96aece7,
+                    0x253f58a4c52d27e7,
+                    0xebdfda5ce627a892,
+                    0x976c9a0ac09d1daa,
+                    0xc815839d1cd157c9,
+                    0x14b5e7a3684a74ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf6cf31ed666292eb,
+                    0xd5076b527bf99397,
+                    0xbb238a03de9d40a8,
+                    0x454af923ddb7f553,
+                    0x58a8e695fc98c9c2,
+                    0x152f8f77b0f7b4b1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf45d24ea28101cd8,
+                    0xa8cc1b0146116471,
+                    0xebaf8e140f0be172,
+                    0x64140a24f7e51f63,
+                    0xdc1abe3e1e3e9f94,
+                    0x1147e608744fecac,
+                ])),
+                Felt::new(BigInteger384([
+                    0xffad0d0a8a3e7bf6,
+                    0xc180b3694d57a776,
+                    0x5d2a78d62ff7e5a7,
+                    0x1ea7d66418a64a6c,
+                    0x7a4004fcb8d9ad66,
+                    0x169562f006e1dfc7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x97e4abdf36ac61b1,
+                    0x069a6bfdd899473f,
+                    0x79cf63f6affd86c7,
+                    0xd0a9c0ecbf11547b,
+                    0x44f2d85bd0b9059e,
+                    0x0808e27c0b8e58fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d77cc2fc2f89e3b,
+                    0x6f06c319455f5ace,
+                    0x57d5e04e6e2c0735,
+                    0xf8413ee40d09521a,
+                    0x629f79d01f5236a0,
+                    0x00aaa93759ab508a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc3d1ffd43b2ee6c5,
+                    0x41f73e7a80eabf6c,
+                    0x6f83867837cdb9d9,
+                    0x2fe81d720c37b28d,
+                    0xbc6e99bc12049ae1,
+                    0x18df4ed81d1371d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x00e9d6b70e3ddee6,
+                    0x14cd7bfb2ca2dbf4,
+                    0x26e3bf6fb063ab86,
+                    0x07525db6fb92b621,
+                    0x3929e45a7f04cd3c,
+                    0x00b3876ba6e88bb5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x936d5d162dbc0e91,
+                    0x2c99e11354783b36,
+                    0x062ff7c0fec930af,
+                    0xbd846cc7afe21b47,
+                    0x41d080f917dc1f22,
+                    0x07fcedf3b60787a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc4d9310ddb733525,
+                    0x3c049f2aff9c35f6,
+                    0x2b6656454e26f461,
+                    0xbb0b9d14b23f5453,
+                    0x119ba28c9be3e27b,
+                    0x08f879ee963ab543,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6cc53fd838f38f95,
+                    0x9ad342b01f70f40f,
+                    0x9069c8abea2bd1b4,
+                    0xa1bd6e195705951f,
+                    0x3178adb3d5ba26eb,
+                    0x1375b877e8d6ccf8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf644e21e82aa9c4a,
+                    0xb65a9d64a2b79eae,
+                    0xa8853fe7eb1f984e,
+                    0x921ed9b82d741bf8,
+                    0x4968023023c088f4,
+                    0x05f928578d0b9804,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8fb319490511b0bd,
+                    0x446816dfd0ade279,
+                    0x7522cd7810f85aa0,
+                    0x441c830ff4a4f214,
+                    0xb254681d6499b66d,
+                    0x0c3cb42038e97632,
+                ])),
+                Felt::new(BigInteger384([
+                    0x104d6c8a7cead039,
+                    0xf021841db3baf344,
+                    0x3329a5f4783f1629,
+                    0x013489225d6003a9,
+                    0xd138de77e8e7612a,
+                    0x0ff4d891213e611b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2eac11f67da73c31,
+                    0x20e3671be6182579,
+                    0xdc6b91c29b502025,
+                    0x691b9125136b1b05,
+                    0x0095fa2bec8404b7,
+                    0x07a7fb84c29a72cd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xda001715f55955bb,
+                    0x0ffeb21e46290314,
+                    0xd496514f11ee12f1,
+                    0x2912c719969e112a,
+                    0x2c7b97e4bf889285,
+                    0x164e97db9505cfd0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f1480073370da59,
+                    0x3f7585f80041dd0f,
+                    0xd0c71b93651d5f7d,
+                    0x6c570c96e077424d,
+                    0x656e154fa6757e24,
+                    0x1035a8b31b3d97f0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71d08ded5edd43f6,
+                    0xabc9229a11bd6540,
+                    0x848f886d962f5c98,
+                    0xdd58f617d5f11f60,
+                    0x77dcfdd2cbd4d9f4,
+                    0x062c3fd741d1c22c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x42563ee50c934d55,
+                    0x3e1c627a92f8210b,
+                    0xfda3e0125d8ebab6,
+                    0xe9ef8f7a9f4bf036,
+                    0xdc5c47d2f0e1c990,
+                    0x033a169e5ab54fb1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd9bc18ec3eb14c01,
+                    0xbd74dbc67d4f5977,
+                    0xd895988ff66804a2,
+                    0x186f231ec6958a74,
+                    0x9c2812459c50fbbf,
+                    0x1063cdabdbc064f5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56941b1fc352fdb5,
+                    0x58a79eab654f367b,
+                    0x8fa96f4164c03fd1,
+                    0xbab8b4111f4faded,
+                    0x6e0ab76510c6bd19,
+                    0x0a0a3c34047c1827,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x01e440775b7f5df1,
+                    0x6c7bff260c568a0b,
+                    0x8b5a62f48719b893,
+                    0x8046c0cb3cc31a41,
+                    0x5f9dccbb415af765,
+                    0x0b769db66265c564,
+                ])),
+                Felt::new(BigInteger384([
+                    0x179d46c85e0a458f,
+                    0x4290a87eeecf0226,
+                    0x15a14e81684a82fd,
+                    0xe6c5a8a7118583bc,
+                    0xec0b5e5ded42f346,
+                    0x00cf91237f7c2990,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa231c3a21b1a9fa9,
+                    0x4284dbe549abecf3,
+                    0x8c3d806933045c84,
+                    0x669d9031afa8043f,
+                    0xb3472244b563bb4c,
+                    0x0d7282d5c81ad553,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1bda7241052cafd6,
+                    0x63b4adf9bc878a2e,
+                    0x446f0a9d0fe7fc19,
+                    0xf3b98cfd0647c8ce,
+                    0xe3d36b8121edb05e,
+                    0x17fd7d74fc1973c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8bdc3f7aac835881,
+                    0xbbd21b3c8f0195e0,
+                    0x8b282111fb3ac416,
+                    0xfcf4fff931241e75,
+                    0x8eb4c29713c24871,
+                    0x105f14b736e796e7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3e78f507ac8f88c2,
+                    0xd6ccdcecc6925704,
+                    0x6df7fa3dc5ae748b,
+                    0xdb99f767f386dc9d,
+                    0x661ee85037d28637,
+                    0x04f912292f1640fe,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc9146e7ce360f3c8,
+                    0xd7c0d2986c942d86,
+                    0xcf9c896a4a9704d3,
+                    0xcb47ee4262d7cc6d,
+                    0x606a002c4279c43d,
+                    0x02f7417ad5f0309d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5d03d3285db3fe52,
+                    0x21e70b4b6c850569,
+                    0x446e22b194fa49ec,
+                    0xa09faef94f5f80ee,
+                    0xf1ccad2549b2ccb7,
+                    0x0267ba216aa27de2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d68c5dc958665ec,
+                    0xbde14ee3c792fd1d,
+                    0xa2bebe8236684398,
+                    0x856a7b3ce5bcb935,
+                    0x3c31aee24fee6075,
+                    0x0582a0157ec186be,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc71f626d096febd6,
+                    0x2f3270b6628f088e,
+                    0x97cf9b85dec4a5ad,
+                    0xfddb5e6f789b3014,
+                    0x2e493085b68eab0d,
+                    0x134cb67291b79004,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa92238acbb25585f,
+                    0x0f9378ae7dab7986,
+                    0x35eee6b4a8bd586c,
+                    0x3c8e27e9c88ca356,
+                    0x7dfe11fc1fcf8eda,
+                    0x15529faba1c1a1c9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x549805ffa41fefe3,
+                    0xccd83af311f42860,
+                    0x5154f8324327dd61,
+                    0x33f23787308e1e7f,
+                    0xcf029273eb49aa4b,
+                    0x0f4153bfcebf4584,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfdfe0d151d5e82dc,
+                    0xe04d632dc4563ec9,
+                    0x882fe9dc235828c5,
+                    0xccd175f6da8de8a3,
+                    0xec5b78fd106501aa,
+                    0x19770993ba7ca385,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3af5187115fef5e5,
+                    0x0224fb5f475afba9,
+                    0x874ec6d5b851a92e,
+                    0xd3ac81b94399ddd7,
+                    0x3078619d2b8a2b88,
+                    0x1847394a68f40a5f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd51ac6441cf8d67e,
+                    0x9a6414d57ba2d86b,
+                    0x2aa30d9ceb9bf62b,
+                    0x554068736e3080b4,
+                    0x4871e9df22eef9e9,
+                    0x011562588e26ccdf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e68170924f69596,
+                    0x7814fc423c750a07,
+                    0x1abf7e28df7d48ce,
+                    0xfed876d87fafe268,
+                    0x2508ff07ac1c7e31,
+                    0x16f4b83a3f0a4021,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31a3e62d10c7372c,
+                    0xd5947e957b505e84,
+                    0x5f6f8f922e66cbe0,
+                    0x77b0b76a6a2aa86d,
+                    0x12ea2e7a0bce5ff8,
+                    0x14ee1ad98b6a0e6f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x36ab8a2b057a3f1c,
+                    0x1c3216409cc85806,
+                    0xc418593ccd7aa202,
+                    0x016844f79f402339,
+                    0x61efab111ed15b36,
+                    0x0cd1175aa10abd69,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3e9ef620f9556e3b,
+                    0x3b722a0359183f30,
+                    0xcc780570bf8e3bd8,
+                    0x1caadc1f712fa75e,
+                    0x12fe81e477987739,
+                    0x19db577ca3ba3b80,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd16a90c53b82a83,
+                    0x312ae2ca416ed255,
+                    0x32d415109d8704ab,
+                    0x4740a25c4e03d18d,
+                    0x8319fa6531727de0,
+                    0x0d86ded962a06814,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76890000002daa7d,
+                    0x95f400b31a0c0025,
+                    0xc6df47dbfb4c46ab,
+                    0x3c2895d9adc8f791,
+                    0xce333f75fc8080bb,
+                    0x156d69a73a8d976d,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xeecee683a2be5217,
+                    0xb6b433de81ac0d42,
+                    0x2389de8c2603afd0,
+                    0xa706d5cc0a527724,
+                    0x23556130c52c5e41,
+                    0x166dbffce0349e12,
+                ])),
+                Felt::new(BigInteger384([
+                    0x23d803235023f203,
+                    0x0073274c4673af04,
+                    0x692e9238a8823330,
+                    0xce3480fde3ef9394,
+                    0x984bf30cf4e032af,
+                    0x081ffb11ce337e35,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf5f133468ac4de96,
+                    0x2a97383614b86992,
+                    0xccd90406478588b4,
+                    0xde7e76fe365a1c74,
+                    0xc871c86fcb099043,
+                    0x12f4563b5c3ba017,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13ede5ea88dfa355,
+                    0x13731dbe6f60f9c5,
+                    0xc76f858555a44711,
+                    0x13a072e8b89a3260,
+                    0xc1af106bc54f20cb,
+                    0x01a044eb4043df32,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8d9bcd24bea0ce0,
+                    0x77d035986480ecea,
+                    0x4ba90f3f623dca3f,
+                    0xee59f0af50e3b2fc,
+                    0xa0130dbbcd3d899d,
+                    0x0d291c1398a5d503,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc058fe95d216b4cd,
+                    0x8d53e34af1c47f9f,
+                    0xf55d51859c7c8572,
+                    0xfc0d54754e55d9a6,
+                    0xd28d36789b884d14,
+                    0x142f82fc4ac3eb3d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x481a309a6a5e6cbb,
+                    0xd8d236bb82598180,
+                    0x1bbb20f24725f2ee,
+                    0xa20894198431f762,
+                    0x728598f7cbce25fe,
+                    0x1867a90bec595a87,
+                ])),
+                Felt::new(BigInteger384([
+                    0x029257fe2eb1f47e,
+                    0xd5553a5b5c90480b,
+                    0x92e7639c80710708,
+                    0xc62c1694b7f556e2,
+                    0xdd2022e87a965702,
+                    0x1353cd16712cb861,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13a1d97c546dc9c8,
+                    0xc6827ac9be948ef6,
+                    0x3d694d7b1da937a0,
+                    0x413b828bb1a6b848,
+                    0x83bee8fb0c82b797,
+                    0x13ccdd763e4502a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16c76bc4dab67855,
+                    0x4fbccd393e3bd487,
+                    0xe357175f59ad0b7f,
+                    0x514bda2ae25bd76c,
+                    0x3409f68d6a66d3c9,
+                    0x08c6791d253810e8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf37e8daffbd679a2,
+                    0x680b84f694d87140,
+                    0x94b16ceb3583ce3a,
+                    0xfffc61f41ea64bf1,
+                    0x02513a8b548d7d5e,
+                    0x06bc84557290f3be,
+                ])),
+                Felt::new(BigInteger384([
+                    0x81c47b15053844ec,
+                    0xfed8cf3a375532ed,
+                    0x53274d10ab264db9,
+                    0x9681b9be901db658,
+                    0x76664f806d39ad1f,
+                    0x05a48fba93f13c3a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8caf720258dcfc9d,
+                    0x258bab89b828f5c5,
+                    0x905aed5396d98219,
+                    0xf3ea6dba5e640ff0,
+                    0x501659cdfc988341,
+                    0x080435a9317355aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7740a1394f5a8237,
+                    0xd92385142911fa29,
+                    0x9ad19aa9f9059018,
+                    0x313efd11e46b8cbf,
+                    0xc3e02dc627a55b77,
+                    0x0c2505433fe2cf9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf82897cb1c7fa9cc,
+                    0x818d2151fbb40ee2,
+                    0x9b898f968c0e9ca8,
+                    0xbf8e743713d9e6e3,
+                    0x5c61d9179ea44567,
+                    0x13a865a7caaeb35d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c140a4ff1535063,
+                    0x0ce8ec4cb6cf0b5b,
+                    0xf4580a9364df9c87,
+                    0x5c3c0ccec6071f52,
+                    0x738a48dc97d2e4a9,
+                    0x042f86c4a86026b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71470228f65d53ea,
+                    0xcf14df0ff09d3b4f,
+                    0x8ff78cccb24a55f7,
+                    0xb290a508f79b41d5,
+                    0xdd00c0c739c80c80,
+                    0x06232dff19f097e5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3023a93293db20d5,
+                    0xaf6f263c49891b15,
+                    0x6da79c3b1fc3b3e9,
+                    0x075c449830c3ef4a,
+                    0x17de200201c657ec,
+                    0x0da4648a34070d1c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xff4b81f1651bb68b,
+                    0xb0bebd5296d42741,
+                    0x4f4f8d6ed04d146e,
+                    0x71c729b8ca6bb7ca,
+                    0x5810f463ff5fd90a,
+                    0x16736475d9d50c2d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x996526207d0e7015,
+                    0x15a938419eb91181,
+                    0x1b18be420365532e,
+                    0xdc5871420090b928,
+                    0xe87ca88e1b6b3a4f,
+                    0x194ae1a2f5315c6e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2b222a62d6bc6c39,
+                    0x6e85200bf0afd176,
+                    0x58c956d892ee4385,
+                    0x2aef4b039b6a82b1,
+                    0x2ce22fbad46938f4,
+                    0x0bab1cc208ffb65a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x67a95ff462251562,
+                    0xab54376d769a2eda,
+                    0x522c76559898f6b8,
+                    0xd5693775215e09d2,
+                    0xa857c63721c21c19,
+                    0x1089f7232061396e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0b0e9dc16bb1e98f,
+                    0xde959d0c6b0043bd,
+                    0x9321a9c5315330f2,
+                    0x22625aa2a489843d,
+                    0x8879065b9a13e1f3,
+                    0x15bc7fb396614e51,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0fa8e2b4804897d8,
+                    0x4b5f5e2df0ed33f3,
+                    0xe2eb0f2739cfaac3,
+                    0x3a319c183068cf83,
+                    0xe13f326d9d676744,
+                    0x14431fb0664abdc7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0190fc61dbe5b54e,
+                    0x8ec569132a9e1dd8,
+                    0xd9692951eae969bb,
+                    0x3875640447bcf278,
+                    0x839552e29698f6ef,
+                    0x162c658d2a62b91a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdf1ac57cda0f4d10,
+                    0x968a7dc04655466e,
+                    0x7dc81560ad1cfa24,
+                    0xe44645afcbb1f6e8,
+                    0x7fe01f0cbde32253,
+                    0x03c3ae074edd84d6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56757e90e7fce805,
+                    0xf0f0ae286d4896d4,
+                    0xf82f8f5394771658,
+                    0xe738d965ccd01757,
+                    0x34bc1b106452d705,
+                    0x164f0647649fbe22,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbfe39cade398e81f,
+                    0x3598f29e840e002e,
+                    0xc0e569b8c9c3c671,
+                    0xc70c80adca080445,
+                    0x2e8fed2cca914a73,
+                    0x0fc588cce0942bca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x714c2d6f9f0dac5d,
+                    0x7f019545b0e24205,
+                    0xc994d14dda483e32,
+                    0xf30b3b22c2a631ce,
+                    0xcc428649c2825f18,
+                    0x13396f61dbe7f064,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9c49f13e5d31e92e,
+                    0xfc1127d56d4fe92e,
+                    0x564baf34f12d267b,
+                    0xc5664baeeae67232,
+                    0xeb1259bb9dbe582a,
+                    0x00b0a4c938b01c25,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe398fc4ebfdb05c5,
+                    0xa94e57aefb240043,
+                    0x163c47cb76346395,
+                    0x27eac41bdcd344fb,
+                    0xf74ba82a986deb9c,
+                    0x0034bc365137d6fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x076382a75dae99e5,
+                    0xaeebabc954f36be9,
+                    0x6f5dbdb2725851ae,
+                    0xa4bd578314e4a6d9,
+                    0xe39fb157302c0f8c,
+                    0x0e3b3ce65e48ff6d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1892caa9fea2f272,
+                    0xb86be98de29a0919,
+                    0x0df63ed6828bd0a5,
+                    0x27c6a0a6fc900bfc,
+                    0x35e1c3600641c459,
+                    0x1396562a9aa88aca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1fd053407fdf3ad9,
+                    0x5e31e183b1ce4ed7,
+                    0x13c826aa4394107c,
+                    0x0437da3522baea3e,
+                    0x2611a328a273e495,
+                    0x1496ac402fa16162,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4df65109ef2a7213,
+                    0x4080a82c8056d1a0,
+                    0x8c1f7a78515defa9,
+                    0x3f175f26c37a28a6,
+                    0x120c40e2bf81cdf2,
+                    0x0b12bcf2c5cc253f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb18043a3606afdd,
+                    0x0a2630f58b31bded,
+                    0xf35889509b643d16,
+                    0xbe603907f37241b4,
+                    0x195cd6538149f4d3,
+                    0x17c0904b70ed3171,
+                ])),
+                Felt::new(BigInteger384([
+                    0x33386ef03d54fa50,
+                    0xc4efdf2c234b8c75,
+                    0x5b2bc1b33424fd08,
+                    0x404d5606b1c9ccd1,
+                    0x38238415040ad6c2,
+                    0x0f6b2153da79a6ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb883265056adc096,
+                    0x3f7f832c43e2615b,
+                    0x44429219dc43a24d,
+                    0x8d6174a52d76e2e3,
+                    0x3b4fc92a7705d790,
+                    0x0bde2cc518c741b0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7324bd207f20fff2,
+                    0xadd66142e29286d1,
+                    0x1ba682120139e86a,
+                    0xeab016db1e1c65c4,
+                    0x41befa459b5c79e6,
+                    0x099aa9ae7b46ad2d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcdc1b5f3836dd584,
+                    0xbd51587b440d1bba,
+                    0x7af3b59a13e56f25,
+                    0x188248568997640d,
+                    0x91ab821bb99af514,
+                    0x11cd3685d31dd4ca,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5ad5d88f7e1fa700,
+                    0xbb287b750aaf6929,
+                    0xd5b26d77faaf64d3,
+                    0x36d9f1b1cfebd045,
+                    0x6576cf2d1eede18c,
+                    0x16668571711efbbb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x764ca16d0437d71b,
+                    0xc887f70c5d28975f,
+                    0x1b09ccc8a5eacde8,
+                    0x7a0b47852c3c4989,
+                    0x0ad78f7883e24fb8,
+                    0x00ff6473377f5bf8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3d49581a6a03d73a,
+                    0x3f1722ae17f96baf,
+                    0x07c0e5708903476d,
+                    0xddd8f1f1d26ac664,
+                    0xe65e99be69d882c4,
+                    0x06703168506f7232,
+                ])),
+                Felt::new(BigInteger384([
+                    0x94d6606dc3de015c,
+                    0x2279b4252580678b,
+                    0x816af37b6f3af5c0,
+                    0x1758c953e079fc79,
+                    0x4a687289e944f466,
+                    0x0f35e4772129aabd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd2aa9e18a86dca44,
+                    0x15e6e95e63ce8d14,
+                    0x0c31e3da17f6a935,
+                    0xcf5fabced7db3db5,
+                    0xe4f0b708c507bed4,
+                    0x15fa6710922d22f2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb30e6158636d454,
+                    0xa765de0dd599ff99,
+                    0xe499ee6aff811e69,
+                    0xabb92fa439683d64,
+                    0x921c66c760204a47,
+                    0x13f2c8cc09252d5a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd917bcdb32ae0daf,
+                    0xa3533d0380f75be6,
+                    0x3ef7d3ed0f4a8672,
+                    0x5905e7e180b102be,
+                    0x521498941f1515e5,
+                    0x1685d7dadc14f7eb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7159e11e06ad5d6c,
+                    0x3ca8d93dec1adbe3,
+                    0x4aad76a08c15df8d,
+                    0x9d634cc26a1f1002,
+                    0x4147ab57a1c25509,
+                    0x0b9c11c197a9446d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x83f2ccdbc292f17e,
+                    0x8cec7d0fa428ef60,
+                    0xe5add011fac8b618,
+                    0x8ff1adaa67ccee41,
+                    0x1399e8eee5aa397a,
+                    0x12557228f3f02ee9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x15dde0912e575507,
+                    0x5e68408d0d28b408,
+                    0x88f3d23e3137d334,
+                    0x9d5f3e1e74dea356,
+                    0xfc995782d76d2983,
+                    0x03ac3907f90a554e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3d16a704a03b8388,
+                    0x947c32ddd99c8707,
+                    0xd8b766db1bb87290,
+                    0xac2e765421f5e0a7,
+                    0xe550e9857ebe671c,
+                    0x077851b93e89c6e6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd59390d81f1c12e9,
+                    0x75a63f0784a01b78,
+                    0xee215982d40fe35b,
+                    0xdbd73fe0dd423e2d,
+                    0x8ba7f3ff8eb979bd,
+                    0x1078219d597c2fc2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x33cc7903ab7fb7ce,
+                    0xfac5a691446c73b5,
+                    0xc445b84edbb2b7ee,
+                    0x72788a2de189d871,
+                    0xa0517eb9a20d4315,
+                    0x0686fc0392a8c26e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0fd6e22387c378aa,
+                    0x2d6f55fe6c381c1f,
+                    0x3a19c05cb8944c99,
+                    0xc1ce9aa78f3b9243,
+                    0xdda39b130c12c319,
+                    0x11a246617ccd0244,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9cd1bf79a7f68216,
+                    0x4b0ab3ff06aeb08c,
+                    0xaac1599aeb2f442d,
+                    0x322958b60de8a912,
+                    0x9d0f4a683ba1fabb,
+                    0x05607cf61a5e1889,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c996a1a09f6ac45,
+                    0x8e798b616a1def9a,
+                    0xd21589e66b3102b7,
+                    0xf9ad793a9045b886,
+                    0x8455b6d512ccceea,
+                    0x14299a0c9e595d49,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a6c5676e1b9ec66,
+                    0xc4a752a4274b0eed,
+                    0x69502a1428ab6d10,
+                    0x5901857088dd5789,
+                    0x01de738775466448,
+                    0x0fafbf65e2e277d0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc4e02fd41a7471b9,
+                    0xa41c6b39111d61ce,
+                    0x010b29cc0c54cd37,
+                    0xbe0f71d87380c940,
+                    0x9691a5361907a40c,
+                    0x16049df410d83556,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa03d69ed9f9bde86,
+                    0x914885ed332793f6,
+                    0x72b387cd5900c6f4,
+                    0x5579acbe3befc384,
+                    0xd8842d7731451c87,
+                    0x13c84753bbb7f0a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7604c178ce1e178e,
+                    0x71d2d3a5fdb5890e,
+                    0x55c530dedbf75a29,
+                    0xc97ef1c882bf0105,
+                    0x954534d88c542e7f,
+                    0x135c3019242061c6,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,1189 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger384;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0x554e0000006f54e6,
+            0xfb9801b4a468005b,
+            0x5e4d31fe251edcdf,
+            0xec5b788a48618a47,
+            0x01eb303433447efa,
+            0x129aa068fb2122b2,
+        ])),
+        Felt::new(BigInteger384([
+            0x36bcf2f85421d531,
+            0xcdb0b77548eef61d,
+            0xb9b1aa5f9670c1ed,
+            0x53e8474b8ecb1eb2,
+            0x4f7c9d6872597084,
+            0x151cd29979b576c3,
+        ])),
+        Felt::new(BigInteger384([
+            0x8a6fbae6e771c3b9,
+            0x729d0347d47efcf5,
+            0xb34f74a6a561c933,
+            0x0ab8a3ce15e6f905,
+            0xef078ec90304f282,
+            0x19bb04b5763b793f,
+        ])),
+        Felt::new(BigInteger384([
+            0x6b38f1283efb0e2a,
+            0x1ba09099cc5ce953,
+            0x9f9570eb5f55f072,
+            0x099a610bdadeff65,
+            0x18fb0f7a492e2c60,
+            0x07f4f8c4ce48be3b,
+        ])),
+        Felt::new(BigInteger384([
+            0xcda47206bbf6bd25,
+            0x30f960237103d8f7,
+            0xf4a37ab9d7a55b8f,
+            0xaa00a696488aa383,
+            0xd0d1424c1a2c8062,
+            0x173dcafbea2ffce5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xaf8842374610fd4f,
+            0x44977c689d0bfbff,
+            0x3219561f9f572518,
+            0x219ad8d001cefc41,
+            0xa788eb5936eb3069,
+            0x114906e42d3525b2,
+        ])),
+        Felt::new(BigInteger384([
+            0xc0a18965503674fe,
+            0xf6ba280d987ad5cd,
+            0x182bfd3839f74553,
+            0xba847ba0dda11ee1,
+            0x745076b098c11027,
+            0x15c43e198cffdb41,
+        ])),
+        Felt::new(BigInteger384([
+            0xa7da1a71446ddeb5,
+            0xcb35d0f311ff63f6,
+            0x87398bab2b56b4d0,
+            0x51fe3c194bf878d7,
+            0x4970a34b1716c197,
+            0x0ee61101114891be,
+        ])),
+        Felt::new(BigInteger384([
+            0x844bdc95b3daa726,
+            0xf61fd9894c5375c4,
+            0x404b739a89b67189,
+            0x75f7b9da74a570a7,
+            0xfe745bdd9352227c,
+            0x03da97042db94e32,
+        ])),
+        Felt::new(BigInteger384([
+            0xc0245a67838006f4,
+            0xb3cf3b4ba6d74626,
+            0xb23e411787d38413,
+            0xd994bdc3101d9422,
+            0xce02f458d57c70e2,
+            0x194ac9be6d964581,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xe3b9df48c8a2dce4,
+            0xbb2c1b9b44487dd4,
+            0x3f7c3a95f53d835f,
+            0xf7f5035d40a06106,
+            0x092ea343ea1e465c,
+            0x0ce327ca3934c193,
+        ])),
+        Felt::new(BigInteger384([
+            0xcd50471d39f8c23e,
+            0xc0a0bcbea3e84339,
+            0x08b6545993fa0e05,
+            0x07fae58eb8c367a1,
+            0xa422b22628d77692,
+            0x13a9da8dffccd33c,
+        ])),
+        Felt::new(BigInteger384([
+            0xe12faaad3c0356b4,
+            0x99d324098586e4b3,
+            0x05813be57b17d0ae,
+            0xfee6af2366eb6ace,
+            0x2de1585c01795504,
+            0x03a54209d80cd454,
+        ])),
+        Felt::new(BigInteger384([
+            0xab08bf72ee677200,
+            0x716a6839875a2679,
+            0x1ca2b17e7d93fcb0,
+            0x90b325b29773cb33,
+            0xf48cc1c71d957e60,
+            0x05875cc4643df876,
+        ])),
+        Felt::new(BigInteger384([
+            0x63dfa44e10ca00df,
+            0x3128e7d018185b95,
+            0x0f16e34a979c00fa,
+            0x19e0fb806645ed94,
+            0x23b27ba6b06a510d,
+            0x12c502a9b75eb19f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x3016ef9979d96cf6,
+            0x5856c522bae21569,
+            0x80391bdcca543195,
+            0x0b8877e14e2ab858,
+            0xe493ffb341473727,
+            0x11182fcaaa29983d,
+        ])),
+        Felt::new(BigInteger384([
+            0x53cb4d96bd4c75a3,
+            0xbda7fefa1e79bda3,
+            0xc9392cbfb1aac667,
+            0x4ac2f81ab7fd904b,
+            0x5e00bb13061ffcaf,
+            0x0d88edccad8d8f98,
+        ])),
+        Felt::new(BigInteger384([
+            0x89b6cd6e943d5552,
+            0x99d0c30240adf82c,
+            0x46eda3649c6c96b5,
+            0x07c3785863e0890e,
+            0xdb2a97f7119eb52c,
+            0x0c392a075af8a5a3,
+        ])),
+        Felt::new(BigInteger384([
+            0xfb951ff097d6cbab,
+            0x144e7bbab3a98ac2,
+            0xf515a052f13ff5a9,
+            0x0810d5dcaa6ec1d8,
+            0x0d5c7b172b6d1b6d,
+            0x0ef4fc309e44fb51,
+        ])),
+        Felt::new(BigInteger384([
+            0x4a102abc91c114a2,
+            0xf118056e0f25f682,
+            0xc4f08377f5db8905,
+            0x7067917bba2efcb9,
+            0x8d89fe10f2add013,
+            0x15126748dd11123c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xab21fd59b9c21f9a,
+            0xcbdc24518e56b16d,
+            0x1802e4c76b194656,
+            0x91ee92ec97003e5b,
+            0x1617a16309943af8,
+            0x06a590e9f424abbf,
+        ])),
+        Felt::new(BigInteger384([
+            0x13eb741fc42a81b3,
+            0x08c118a1b0353a6a,
+            0xb457e25d6a327d2a,
+            0xeedbd59b86fcd9ed,
+            0x2c5235d5ab85dfb3,
+            0x051b72d12b934685,
+        ])),
+        Felt::new(BigInteger384([
+            0x212eadb1245ddf20,
+            0x15b0086c20c2704f,
+            0x8a828fe1ddbfe2dc,
+            0x9d4259210b28d7b1,
+            0xd3b75e3b57eca6ff,
+            0x05f5e9d2b4890128,
+        ])),
+        Felt::new(BigInteger384([
+            0x317539ab0dd5bedf,
+            0xd57fa750723c89f5,
+            0xe58e1d5060112df8,
+            0xf351bb129969cc1e,
+            0x6419e74327f082a6,
+            0x1049a61c57be7308,
+        ])),
+        Felt::new(BigInteger384([
+            0xdc0f16a49a186cd4,
+            0x01748ecfe48222b3,
+            0x66dcd9fa53631d56,
+            0x764276f0a7b11edc,
+            0x593043a58783593b,
+            0x15cf8401170d47c3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xa56afd4698b3e027,
+            0x82e066b3a4a90aad,
+            0x98a848c960ea604f,
+            0xdebc932ee883dd9b,
+            0xaf162edfb610ad02,
+            0x122690f274fb48f1,
+        ])),
+        Felt::new(BigInteger384([
+            0x1d4150d3b5707004,
+            0x458d72b599d11b23,
+            0x7fbfaa7ed51d15e9,
+            0x1841cf7b1f4336f3,
+            0xfa56bc8cd10b0fb9,
+            0x16c2008dabe1c052,
+        ])),
+        Felt::new(BigInteger384([
+            0x589ce841b909139e,
+            0x7f31d69a94351938,
+            0x00e392d16f552cad,
+            0x932b631e35bc9c04,
+            0x8c368f68e2b7cb47,
+            0x0ac19810dc94b271,
+        ])),
+        Felt::new(BigInteger384([
+            0xe1e2eb5cd9b69951,
+            0x8c75aca5c376bf0e,
+            0x8c8890e221a39bfa,
+            0xc92c4330471ea511,
+            0x47a83e8ed0f9bb96,
+            0x0b708945ecd0d3c9,
+        ])),
+        Felt::new(BigInteger384([
+            0x612899a2c9b05cff,
+            0x924b3261f019a750,
+            0x85f907b64c95f1c1,
+            0xb468f55075eb13d1,
+            0xa623b2c768089390,
+            0x1492117d334f4d71,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0ac633ec0f9ed5c6,
+            0x2d4975e1432bc04e,
+            0x886a94c421734588,
+            0x9628d9f0fe1e020c,
+            0xe540a1f286234168,
+            0x1872e7c1a537f17b,
+        ])),
+        Felt::new(BigInteger384([
+            0xa34913ab8eca284c,
+            0x77d02eb60c93fc92,
+            0x6921cb8fc167dbb7,
+            0x62976f72cb98fcb9,
+            0x7ac798b130a2d325,
+            0x0dc08d9c33429d1b,
+        ])),
+        Felt::new(BigInteger384([
+            0xea69e572fe69f9ff,
+            0x2ec259fa4a468535,
+            0xfae4d22d6df118dc,
+            0x598c2cedf50e925e,
+            0xfb747e30afd94e28,
+            0x1456c4c8b7803d33,
+        ])),
+        Felt::new(BigInteger384([
+            0xaf0e3c0d9dafc058,
+            0x566056426675e07c,
+            0x38a7563a42c9e08b,
+            0x1feb52599502da2b,
+            0xcc4821d0701c6b6c,
+            0x03463eb3fe2a33bf,
+        ])),
+        Felt::new(BigInteger384([
+            0xf0adb9909b0e6660,
+            0x5346711184af0bb2,
+            0x7b484077b6707a78,
+            0x08e3ad415364e249,
+            0xfb210a36b3d15069,
+            0x01687a62271c98fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x1266bf394049156f,
+            0x23d6e9c0313b88a5,
+            0x996e80b65d93210a,
+            0x739ce296e4c945de,
+            0xdab1df119a7c3760,
+            0x05feecdf2b61e634,
+        ])),
+        Felt::new(BigInteger384([
+            0x06925d47888290ca,
+            0x8d0fd081430ba3a0,
+            0x28dca818963b549e,
+            0x71efac4fff570af1,
+            0x2ccf8ecb28664c70,
+            0x1726cb40a95c5c6b,
+        ])),
+        Felt::new(BigInteger384([
+            0xa9589fd3f5c10ca9,
+            0x926d547862a64996,
+            0xb4d264cb45ea6cde,
+            0xe7edcd174d15a158,
+            0x1d0bb1ec87a1e763,
+            0x01e41523b5f309de,
+        ])),
+        Felt::new(BigInteger384([
+            0x8e3bfdf00b1bee62,
+            0xfbe4581bed287381,
+            0x7f1c6ba5134932fd,
+            0x88cd1cde0521da21,
+            0x0aeffbed37fc1d59,
+            0x15e1dc47c784ed04,
+        ])),
+        Felt::new(BigInteger384([
+            0x2b8d4d201dfe582d,
+            0x592c61eef30dddba,
+            0x0448982508abd931,
+            0x8995e8f68cf0fa1d,
+            0x238752525e90f531,
+            0x09fa9eaa0e12dc21,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xc0d83808eb7f604f,
+            0x6d93e2aee6304bfc,
+            0x6596c5b8c5da2f8b,
+            0xf5ea136838bdf4df,
+            0xf9ed67ab58387808,
+            0x0a1a018aca7dc1db,
+        ])),
+        Felt::new(BigInteger384([
+            0x1d81e6c71e045f30,
+            0xc48345aad756021f,
+            0x5f1b821e399a0329,
+            0x5ee47b0cf90d5af3,
+            0x7b8717482d4a03af,
+            0x125e6dc5b55f169e,
+        ])),
+        Felt::new(BigInteger384([
+            0x0b0741b0f12bead9,
+            0x941450e185ad58ed,
+            0xa9784b10938a9853,
+            0x24316da51be17b36,
+            0xba60c2bf6002d6bb,
+            0x10eb0c3d8c4bbb28,
+        ])),
+        Felt::new(BigInteger384([
+            0x978b75a4eb757306,
+            0xb58a05f112fcd680,
+            0x480392550ea09931,
+            0xd011ac9380633ffe,
+            0x84923aea2ba26624,
+            0x02cbed707988b9ad,
+        ])),
+        Felt::new(BigInteger384([
+            0xb1711f325ad25dca,
+            0xb8ffdc28c5dba4e5,
+            0x5d6401fbd0f31a12,
+            0x932e7253cf77d9f9,
+            0x14cea02576bdcda8,
+            0x177a5639017cb1c8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9becb1b618c73764,
+            0x256667fd6b696a00,
+            0x7f1157ab9fdd2607,
+            0x67817025c977bcca,
+            0x26dc483c1d3480cc,
+            0x0a8b3a522660ef70,
+        ])),
+        Felt::new(BigInteger384([
+            0xaa16221373bb9885,
+            0xe67930db105366ab,
+            0x9d80e4b6f7bd8156,
+            0x4e1caed917481f87,
+            0x839c09665b9be458,
+            0x17cf243d49039e50,
+        ])),
+        Felt::new(BigInteger384([
+            0x3e1aafdbb109638c,
+            0x3ce9d2a936e83eae,
+            0x1b05fa0a18068b73,
+            0x5aeb0b2a92c92517,
+            0x944c20da054ba2fb,
+            0x19ea48e206c7be83,
+        ])),
+        Felt::new(BigInteger384([
+            0x973e8e65c5f88279,
+            0xee076e8780a2ed88,
+            0x4d5845cb32378010,
+            0xaa1e227c44c7a204,
+            0xa31ae30db473588a,
+            0x1007524ac747c5e1,
+        ])),
+        Felt::new(BigInteger384([
+            0x2564ec669ba80e32,
+            0xa108025159c3b319,
+            0x338660fe0b25672c,
+            0xd4dcba30157bb675,
+            0x41a2b2048990f2a6,
+            0x114ffee20f980f1a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x89422710ae50060c,
+            0x0ec87f403bafe63a,
+            0x59b0f6af25e5ec0b,
+            0x51dadef6ecdbfdc2,
+            0xba19f0a40779927d,
+            0x18a860ddbb27de9d,
+        ])),
+        Felt::new(BigInteger384([
+            0x3206047cd513625d,
+            0x76224320afb308df,
+            0xf6fe09d24c392388,
+            0x7d8817abd23a5930,
+            0xbd4324a3a97272f3,
+            0x0c4d748950dfc563,
+        ])),
+        Felt::new(BigInteger384([
+            0x526117937daf4296,
+            0x4bb63823b5576b9d,
+            0xe632b73273eb2f9c,
+            0x363518360dbfbb5e,
+            0x87ccb6dfb02984a4,
+            0x0005ff3765f7fea0,
+        ])),
+        Felt::new(BigInteger384([
+            0xa5dae0e72eae5d98,
+            0x6f04d98739b522fa,
+            0xa118a6b2fc961683,
+            0xb5fb30afd7d13df4,
+            0x74c43b7895d685b4,
+            0x0ca18698f47e48a6,
+        ])),
+        Felt::new(BigInteger384([
+            0x4aee28b3d44998b7,
+            0xa475ad76067ce034,
+            0x09ecd5b71120ab28,
+            0xb0ce7e1d3a6fd5e9,
+            0xc99de445a35c0070,
+            0x02e0d895d360d49c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xc528ae62d491768d,
+            0xf820b0da1c6d3d7e,
+            0x27c0edb2252e9c9c,
+            0x62c17bd6c5a72c9e,
+            0xb35ad2b9c4d8178e,
+            0x0507bf0ef358f4a2,
+        ])),
+        Felt::new(BigInteger384([
+            0xf8e5d7eb15d50ef6,
+            0x4ed0470ae5b58700,
+            0x3b3564213d9a82c8,
+            0x64b081202c920aad,
+            0xd3ab70f9e2fd07d1,
+            0x06b8d3e901a1bef2,
+        ])),
+        Felt::new(BigInteger384([
+            0x9f8ac2bc5d2dcaf7,
+            0x3f6785094c0c6287,
+            0x645ad2fd11973641,
+            0x0201a40c7bfd6c98,
+            0xe03fb4afffa51f07,
+            0x0f2f525460da692b,
+        ])),
+        Felt::new(BigInteger384([
+            0xd224d741c6f7e4f5,
+            0x1c62b8eb7dc71ac6,
+            0xa4f0063bc83b3613,
+            0x473d93592cd6b63b,
+            0xabe993abcbc412da,
+            0x02b42209220122ad,
+        ])),
+        Felt::new(BigInteger384([
+            0x334c72833723a373,
+            0xdd158afe64c7007e,
+            0xc8e9e04d95504e8c,
+            0x4f7b3bfa3ba4a65f,
+            0x4f58c07d82187d00,
+            0x1801ee3e79ba0b44,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xe32ee000f77d2c46,
+            0xed46afaf8b2fdb60,
+            0x16a888f52804ef4c,
+            0x98638bb83592844a,
+            0xdd2c3cb0e15663aa,
+            0x0ef4b01c44becd36,
+        ])),
+        Felt::new(BigInteger384([
+            0x8ad96d71d509a6e2,
+            0xaaafa105e6789f55,
+            0x484f5ca2d4a5b113,
+            0x34d4f4c5e23e6460,
+            0xa2b2bcfe5b80ac94,
+            0x056ad1bbf6a3bd01,
+        ])),
+        Felt::new(BigInteger384([
+            0x8110fe4789791ad8,
+            0xa07930d72be778cf,
+            0x4ebfb1297100e473,
+            0x4b4d3f99dfc5be84,
+            0x7d67de819be8601e,
+            0x01e1c0de60a3f954,
+        ])),
+        Felt::new(BigInteger384([
+            0xcd590eac32d1b1b0,
+            0x76846eeb6a67915c,
+            0x7a6534fc2dfd17f7,
+            0x2dc1563bd2ebd921,
+            0x7cfdd8bc4d66fe23,
+            0x11b895857f4d9612,
+        ])),
+        Felt::new(BigInteger384([
+            0xec5394008b52bc48,
+            0x4443239f5f9ba251,
+            0xb1d68f6bbf0cd54f,
+            0x545c586637bac356,
+            0x96d18ec27d51f955,
+            0x10a664abbd094580,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x08db0fcf6c4b8dde,
+            0xc2a195af50242d1a,
+            0xa85e363998db58f0,
+            0xbe468471b6ed37db,
+            0xc88d958f2f5dc602,
+            0x1208ca208a34bf63,
+        ])),
+        Felt::new(BigInteger384([
+            0xad9b2d7e40bd5a96,
+            0x0a10e81b4ec85afa,
+            0x7c5ab4b0da0ebb83,
+            0x2714606fcdda8386,
+            0xb2e933c366b446f9,
+            0x019e37d1f2246a56,
+        ])),
+        Felt::new(BigInteger384([
+            0xec8e170133db1e51,
+            0x32167ee2ce000c71,
+            0x81d81db6a77bf953,
+            0x2d483b21608a17d1,
+            0xddd38ba5611cc4db,
+            0x08fd925d0c3a81f8,
+        ])),
+        Felt::new(BigInteger384([
+            0x52e9e34bdb5f867a,
+            0x14257ad3c49f61b2,
+            0x428895d827be1049,
+            0xe3920669dac69613,
+            0x9c622b43bf7b0de0,
+            0x19ff9efdd03f0aed,
+        ])),
+        Felt::new(BigInteger384([
+            0x6070620ef45b58bc,
+            0xaff33041e47e2429,
+            0xc3e6142a699e7d30,
+            0x31be42798428352e,
+            0xacee31f85f20a259,
+            0x173d7c84fe2c31e0,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0xb35300000070ff8f,
+            0x623c01bb2dc4005c,
+            0x5a5914d953aa0e2b,
+            0xf606fef186c82c8a,
+            0x8a60e9a4e2ca1ec5,
+            0x109546d5dba1a1ae,
+        ])),
+        Felt::new(BigInteger384([
+            0xb7c6a2a2c919a7e8,
+            0xaedf622c4a682a7c,
+            0x2a4c8ab1ffef7f2f,
+            0x2403f35be651c6ae,
+            0x7b376e61b129a4bb,
+            0x0b5193fe9d38ca88,
+        ])),
+        Felt::new(BigInteger384([
+            0xd91a2d4d4d043125,
+            0x077e8f62bc1c54fe,
+            0xbca357b486c00565,
+            0xbc94b02d2d2b39a2,
+            0xcb0ce50e149c6aef,
+            0x0682d86d5e645200,
+        ])),
+        Felt::new(BigInteger384([
+            0x33dfcaea34fc2b34,
+            0x350a31dc83163bf9,
+            0x01f3b0b504fe34df,
+            0x365602d8dfa90ff5,
+            0x3f6584966589b635,
+            0x0a059afa4d4cebb8,
+        ])),
+        Felt::new(BigInteger384([
+            0x47067fc29b298363,
+            0xa829792535977638,
+            0x7fd70d1bf364a9cf,
+            0x9eaa0245224e6437,
+            0x57812f8d5521cd98,
+            0x07c4eadffe2eeb01,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0edfd8aed14d0eee,
+            0x4c7b3e49117fb384,
+            0x9190d375aab1c894,
+            0x5435c20ee7d640b1,
+            0xd761c6e55eaf83ed,
+            0x12b482e32d994227,
+        ])),
+        Felt::new(BigInteger384([
+            0x42fdcf875068aeab,
+            0x7928949e850bc1b0,
+            0xec327805804574c6,
+            0xb38f8a88dcc86909,
+            0x476e69c54fcff817,
+            0x0f69d510d066cc7f,
+        ])),
+        Felt::new(BigInteger384([
+            0xb1d6234f353a5dc2,
+            0x20031ee696087382,
+            0x5b29dbd4e0355957,
+            0x9140f6d4fe626e61,
+            0x17f4c361e4389a95,
+            0x191fcc3552d4ee92,
+        ])),
+        Felt::new(BigInteger384([
+            0x4e454ccf35162b26,
+            0xb0c93ca5ee247fed,
+            0x06154ddf0c2e2827,
+            0xcba2be7f21102364,
+            0xcc41f31527ec600a,
+            0x095c0ecbcca11928,
+        ])),
+        Felt::new(BigInteger384([
+            0x3ad8fe9aeded3428,
+            0xcc3f162756829aea,
+            0xa0dd6df480624484,
+            0xf72d7c499181f703,
+            0xfc1603b588b071d1,
+            0x0d42bf34a178d115,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xc3b6cc6ac460a022,
+            0x33b6d050390ca37d,
+            0xc7aa0ed3955e9331,
+            0xd7baa33253367979,
+            0x72c71fdee90300e5,
+            0x06e787b65f0cc438,
+        ])),
+        Felt::new(BigInteger384([
+            0xd051e3e9aaacad8a,
+            0xb3b61c2410c99d40,
+            0x0573260e6f0ea9cd,
+            0xae30ab0ce47985cd,
+            0xb1004649b706c586,
+            0x05e8557268a7aaaa,
+        ])),
+        Felt::new(BigInteger384([
+            0x6bd10a359d518760,
+            0x5f4764d189e06264,
+            0x0227e2f6c4bce18a,
+            0xeb54207545e4345b,
+            0x36251981a5bb9507,
+            0x0677e12b3f0d1758,
+        ])),
+        Felt::new(BigInteger384([
+            0xf5a78656e024a79f,
+            0x9cbabe2aa97b9ec6,
+            0x0b22e2aa94d21fa3,
+            0x9388e0ed706d51f3,
+            0xfc19fa0d895022f3,
+            0x03a1b8792899a99c,
+        ])),
+        Felt::new(BigInteger384([
+            0x19389f2bebb88a5d,
+            0xd8ebb57ef9681e7d,
+            0x8d9d39b01ba223e4,
+            0x491bbc2207be3737,
+            0xd6a0d3c87e0a65d8,
+            0x1956edf74a3509fd,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xdf0be418b6896b42,
+            0x198729d410874d6d,
+            0xdcb6cbaa24bfa62f,
+            0xb956d82a9c0f6c14,
+            0x0f74e838700ce8d9,
+            0x15ce449721dac59b,
+        ])),
+        Felt::new(BigInteger384([
+            0x25c4f1c06ef29bfd,
+            0xf9630e5bec3c2a06,
+            0x9a45da044709c6f7,
+            0xbf017e0d1f0249c0,
+            0x2c26bb20c43042cd,
+            0x0a791d91684191be,
+        ])),
+        Felt::new(BigInteger384([
+            0xe3503454367dc10c,
+            0xa7eab3c6a5e88838,
+            0x17e42605a05c0c59,
+            0xc239aa1e7e27ede4,
+            0xa4b6c506e5c1ec58,
+            0x19bd7e0913d2135f,
+        ])),
+        Felt::new(BigInteger384([
+            0x152bee31ca863c58,
+            0x884481a836ac156c,
+            0xb7e5ad0ec2c87d64,
+            0xd8ef518bbeb6e3e1,
+            0xd6321f47c708b729,
+            0x17c10cc5b479d72e,
+        ])),
+        Felt::new(BigInteger384([
+            0x14622cf7ada22e83,
+            0xc2d4831aa002cbc6,
+            0xb095e2cc3d7b1a94,
+            0x0933c70ca370cee6,
+            0xb6a51a66ace32f31,
+            0x0c54f58c8840aeb8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x13c51b58762fd55d,
+            0xc3f606e37698802e,
+            0xe9b046d1d108470c,
+            0xc8392d18cc8c585f,
+            0xb7950454f1315b70,
+            0x0d9c127033530639,
+        ])),
+        Felt::new(BigInteger384([
+            0x9f9341c8f58e5f84,
+            0x7b65a5e410943d88,
+            0xfa9441df0b1509d6,
+            0xeb969570d5a8f9aa,
+            0x7114b050226d9497,
+            0x044c0f4fadc475c8,
+        ])),
+        Felt::new(BigInteger384([
+            0x34763e16465c0251,
+            0x5ab3771118999717,
+            0xd0a8c4bfed32e49c,
+            0xe034c4ca0d17a2cf,
+            0x13e005b7e4e74cf1,
+            0x15baaa8e34df9c02,
+        ])),
+        Felt::new(BigInteger384([
+            0x4abb316bc0433c58,
+            0x61b32b1fd687ab5a,
+            0xb65d09a8466c4bac,
+            0xe835251fa1d441b0,
+            0x58705e2a3917e051,
+            0x01551180fbf09569,
+        ])),
+        Felt::new(BigInteger384([
+            0x600f425f35b73e2c,
+            0x0a1a8a5d07fb8eb3,
+            0xc7b1eb8ba6863b01,
+            0x978ae664789a5751,
+            0xf8e7da67fa90271e,
+            0x0f527efe89ba115c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x97e30dd5766cd52b,
+            0x688796158c8e7c75,
+            0x949dbff26481cfa7,
+            0xe03ce17569f74fee,
+            0x6271027b9f20ca9d,
+            0x17ace94ee4fbe578,
+        ])),
+        Felt::new(BigInteger384([
+            0x32be110d081f8d16,
+            0xa5bf4cc7f9d3c149,
+            0xf0441f1f13a81137,
+            0xe032436ab9d6aefe,
+            0x50f6a7b14965c1bf,
+            0x148273e25ee531a2,
+        ])),
+        Felt::new(BigInteger384([
+            0xf5b96b36fc527610,
+            0xb1c2920f8bafe307,
+            0x7151dcce1c709d0f,
+            0xa15382e18392bf70,
+            0xde3ca78f71256e5c,
+            0x19162fa28dbd8f56,
+        ])),
+        Felt::new(BigInteger384([
+            0x3efcd5adad6f00b6,
+            0x24e27d43d8b9837b,
+            0xeed064f99c581e74,
+            0xedbcacdc8ef585b0,
+            0x98f7cdd626dfc33b,
+            0x150cdd6afb551ed0,
+        ])),
+        Felt::new(BigInteger384([
+            0x6efdb7ed869a6d98,
+            0x887e7abf1336b657,
+            0x11162e663d617e0e,
+            0xa0e718de92bba495,
+            0x57b8ba33dc885e96,
+            0x0ca4e350d6ce5917,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x4525df0da55703d6,
+            0x85b8dce787244883,
+            0x9502c02e3d3f66de,
+            0x8b203eb5fd5fa145,
+            0x4f06439f378c9cfb,
+            0x0d8976760fd1ca51,
+        ])),
+        Felt::new(BigInteger384([
+            0xbaac6e7799782915,
+            0x6976406b79fdb925,
+            0x5179c7120ed87f28,
+            0x82764565d77fb46a,
+            0xd2edf99cb4a26ffb,
+            0x151249331a5f3153,
+        ])),
+        Felt::new(BigInteger384([
+            0xcf6e02faf9b2957d,
+            0xd41b4d139dd46572,
+            0x7bf5d06b33413b3c,
+            0x5b2b632fc0b2e2b1,
+            0x03e5646806a02f35,
+            0x123b92b263425668,
+        ])),
+        Felt::new(BigInteger384([
+            0x0e0ec0f129670b74,
+            0x80415e83891fbb56,
+            0x12c2b133cc640b27,
+            0x9c6a1e094e2cfa70,
+            0x1f1e26ded1a75de0,
+            0x1673db1b40c7a1b0,
+        ])),
+        Felt::new(BigInteger384([
+            0x0069726e0ff75ab0,
+            0xdaedf111b5333127,
+            0x7e38ee09b621aee7,
+            0x4d5032d2e188b2b2,
+            0xae3c876a33f6063e,
+            0x030c9477feb4c78a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x2e8317697e8b6706,
+            0x39ab42ba083f5c4a,
+            0x609389e3b7c9a834,
+            0x254adc127a0aedb8,
+            0x3c19a386dcd0505f,
+            0x0f4a62f3881118a5,
+        ])),
+        Felt::new(BigInteger384([
+            0x45b365223bbb0a6f,
+            0x1d6ed42b922caba3,
+            0x6490aebd2b6567bf,
+            0xea0dcb74adb8b883,
+            0x317c6ac8fa04f9da,
+            0x18ac5c4d490e63a4,
+        ])),
+        Felt::new(BigInteger384([
+            0x70196a6a9993cbae,
+            0xf52b3985493f7543,
+            0xf07040cc49a4f512,
+            0xa643980faeb9fa4b,
+            0x1d1ebaec6f5385dc,
+            0x13fdca6d53ca7cad,
+        ])),
+        Felt::new(BigInteger384([
+            0x5afb2fe23f5e07af,
+            0xa5d25253403599cb,
+            0x4562ff1fedebd725,
+            0xf913e63a6d41dd88,
+            0xbf30d457a3da738a,
+            0x09423c3a8937e75a,
+        ])),
+        Felt::new(BigInteger384([
+            0x6306b30c3b71c559,
+            0x7f8cd3e405494e9e,
+            0x5a9550d950167d50,
+            0x2641b7b9bd8fc067,
+            0x83294a982c54bb9b,
+            0x05d28e359e407db0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9a098a2d602289d6,
+            0x7ba26524bbe1e5e8,
+            0xb261465b415562dc,
+            0xfab789eac1fe80de,
+            0x64811fa26204834c,
+            0x06a949c0aa9bb083,
+        ])),
+        Felt::new(BigInteger384([
+            0x19b7e896079db0c5,
+            0x4d1c72d12524d069,
+            0x20750037f008c271,
+            0x2a2217389b6decab,
+            0x895fe6c7c660a35f,
+            0x0727d0f3d87fda0e,
+        ])),
+        Felt::new(BigInteger384([
+            0x8edd063bcb5f81ce,
+            0xef0c5f6a6af44ae0,
+            0x6abb9e86b889ccae,
+            0x35a6b5a47184b84f,
+            0xc39fbf410f2c6779,
+            0x164893a8ad91ea2e,
+        ])),
+        Felt::new(BigInteger384([
+            0xdb5ea18b56180eee,
+            0x765e29a3160bc310,
+            0xfb206fe60138dfa4,
+            0xf7ef3e7bd0073a49,
+            0x8d1aae8ca2445b72,
+            0x0371316ef82a56d5,
+        ])),
+        Felt::new(BigInteger384([
+            0xa5ff7f12aea6a2e6,
+            0xd79a7799d6c4dc10,
+            0x3956322539a26a58,
+            0x82f9be1df4158469,
+            0x7d9c8bed0bf98657,
+            0x069617e615190f8e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x43b3ff031221a4f3,
+            0xb9cdc9b4d7d468b5,
+            0xa0b3b117183bb241,
+            0x4e6c9a6a99587f8f,
+            0x02dac9201be99ea1,
+            0x02462176aff78b2a,
+        ])),
+        Felt::new(BigInteger384([
+            0x74e21f0ae20c2e22,
+            0xf56b3d42f4db99be,
+            0x33b23b99ab0f9987,
+            0xfb77fec70048e805,
+            0x02dfa1d2e99b9698,
+            0x07c4265a159d0ed3,
+        ])),
+        Felt::new(BigInteger384([
+            0xd6876f8f0ff493de,
+            0xff8ec0750194956a,
+            0x49f053a843382293,
+            0xea06bb673b878636,
+            0xc3da3e9266129972,
+            0x00725d519806b401,
+        ])),
+        Felt::new(BigInteger384([
+            0xa9a7b574b5526269,
+            0x3534717b1a6b3ee1,
+            0xd54cfc2521b31f6d,
+            0xb4196826db0bd315,
+            0x1d0e1f9d1ffe6069,
+            0x0bd83537ef62101c,
+        ])),
+        Felt::new(BigInteger384([
+            0xa288476f74334201,
+            0x64a77d02b2ba4f0c,
+            0x4b813c9167690680,
+            0x0b3d0541743eaa6a,
+            0x66f70e6f57016abe,
+            0x15987168062d008d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xbaf7d8bc5ba7297a,
+            0xb57d81f53aeec342,
+            0x1a8037194545e1f9,
+            0xe8d9ccfced7cb40c,
+            0xba40ab707f8d639e,
+            0x028de20f08b4f146,
+        ])),
+        Felt::new(BigInteger384([
+            0x40bf65d2f7605884,
+            0xb60df084d8631a45,
+            0x938d1a549d3d9b91,
+            0x3f6e76dfdf8027f2,
+            0xabca9eaef41c8558,
+            0x086e229d1aef936f,
+        ])),
+        Felt::new(BigInteger384([
+            0xe8ba3ba590967e1d,
+            0x5e00c6ea757fa0ac,
+            0x82ab9d1133801cb9,
+            0x3e53233dce483581,
+            0x71ba5ded10e68817,
+            0x0cbad1882e2d3842,
+        ])),
+        Felt::new(BigInteger384([
+            0xfc316c54d2049e12,
+            0xe72b7d7717a552a6,
+            0x2f6b16ac89c415b7,
+            0xd48185a0925a754a,
+            0x5dfb59a6be0bedb7,
+            0x149e157d1a0ef06a,
+        ])),
+        Felt::new(BigInteger384([
+            0x0bfee81b60d12d10,
+            0x990ec923a39b5a7b,
+            0x28456aea0b16aa54,
+            0xfbb9d874bd77d022,
+            0x5e36224f2d76d8ab,
+            0x1354f712c76c2399,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x260a92001e59e9b9,
+            0xdc2ff74a6f0ce95a,
+            0x26fcbd7a4015358c,
+            0xcdc7700834b14e1d,
+            0xa3e86b47f253c68f,
+            0x06ccb1287c06f3d2,
+        ])),
+        Felt::new(BigInteger384([
+            0x7ccc6b32d493aa30,
+            0xad6a382bb072673a,
+            0xaf0031609374a7ae,
+            0x96269afab4bc31e4,
+            0x677e21109fc34b3e,
+            0x00b7e0facd5292eb,
+        ])),
+        Felt::new(BigInteger384([
+            0xab1118c00c86ab91,
+            0x7060578cae41666a,
+            0xd80f7598d601d03b,
+            0x79af69bab76a3f30,
+            0x6f7891c8d27e5382,
+            0x19c283a32ab0a8ba,
+        ])),
+        Felt::new(BigInteger384([
+            0x9da894a106bfca82,
+            0xb337a097fdc41946,
+            0x0a7e32f25a3ee224,
+            0xd553a2f062444607,
+            0x3a6be7e56615abe5,
+            0x088f0feb4932d05e,
+        ])),
+        Felt::new(BigInteger384([
+            0xaf8b63dc601d3234,
+            0xd1b0ea69f29e4998,
+            0x574d5f9c9d6b0471,
+            0xa57f0573460be64f,
+            0x3e208cdc3b03d96c,
+            0x0c5359cf35e67993,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xece5834812aadf17,
+            0x51fe2f0d8ead9756,
+            0xca72ffc9909d44f6,
+            0xf2681e718ccbfbb7,
+            0x78e8b18b481b4c3b,
+            0x027d887050a0dbe2,
+        ])),
+        Felt::new(BigInteger384([
+            0x7193c063652d2c6c,
+            0xa89dcb1313678fa9,
+            0xd7d9a38f6ee288d7,
+            0xb9c0f8ad461cf445,
+            0x2ccff11794dbd668,
+            0x0b2ed6f27f088710,
+        ])),
+        Felt::new(BigInteger384([
+            0xef6b13f50a36e5c2,
+            0x70c63c46f04e8ccc,
+            0xde33cd7279ce314b,
+            0x1670ef54f6e6f9ca,
+            0x02eb3f9ceb567b01,
+            0x1839ea51e72e2ef9,
+        ])),
+        Felt::new(BigInteger384([
+            0x41b18bb543fed6e2,
+            0x8e018f859b429ff7,
+            0x948208bf0db280c2,
+            0xaad6045af088bedb,
+            0xb6af09422101d0be,
+            0x095769a229b3533e,
+        ])),
+        Felt::new(BigInteger384([
+            0xcb66450385b13559,
+            0xd832bbf74fa4fb86,
+            0x5bf988680b8a3e11,
+            0xfdd60bec1dd66bf4,
+            0x7be3df23b2d23c28,
+            0x10bcc86135e9a9e5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x4ceca524fa99fe91,
+            0xae9ac237d73b90d3,
+            0x54cc06f376136fec,
+            0x038fb4403aa690f5,
+            0x1a6170dfce6c9a62,
+            0x013bcd7163aa64eb,
+        ])),
+        Felt::new(BigInteger384([
+            0xceb0727e44019e02,
+            0x8f40bf52ff50f311,
+            0x04885582e8eb5499,
+            0x9745016c5e38f518,
+            0xf31dce52d8595c9b,
+            0x030c6805481ccb40,
+        ])),
+        Felt::new(BigInteger384([
+            0xdb441ebd27b9fc72,
+            0x6af9377e64acc832,
+            0xa2bec1442e381159,
+            0x7f393c6cb0a62204,
+            0xce52ab80a5891eb4,
+            0x00fed4e326d867de,
+        ])),
+        Felt::new(BigInteger384([
+            0x019d52635fad698e,
+            0xb2e4489879141810,
+            0x5548c3807c133a66,
+            0x4beb519e24e35d79,
+            0x8c2ac23fcb5fcc4a,
+            0x0d489e1748385ef5,
+        ])),
+        Felt::new(BigInteger384([
+            0x79de052061da8faf,
+            0xcb2475c458212521,
+            0x66ac670c2abba745,
+            0xc67c931496c3bf78,
+            0x4817e8cfccead0fa,
+            0x12fe0b3744a02d21,
+        ])),
+    ],
+];
@@ -0,0 +1,3416 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U10>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger384([
+                0x7bff09503ae549cb,
+                0xdaf2ea847b469a66,
+                0x8707e45f5fe3b492,
+                0x9193335c3df38b7f,
+                0x10091787cd1c820a,
+                0x039b93af3814be1a,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0x58ec79d826bbec40,
+                    0x0f6060204d58da52,
+                    0xcd4982af1e41dfad,
+                    0x194df7dd78ed1dfe,
+                    0x623d054173df2a47,
+                    0x16f2aba68cfde618,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb5908636d73285bd,
+                    0x130787909433773d,
+                    0x9960e2aa59471465,
+                    0x4b7c95513affa5ac,
+                    0x4532d8db8b4f1259,
+                    0x14aef31904941b20,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe1e4403b03cd56a9,
+                    0x2c276f2f07425103,
+                    0xef1d1b38cd527e3a,
+                    0x6926e68498d7a9f9,
+                    0x51ee4b3daff31551,
+                    0x01a5ac769a2f8080,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321deb3d4950b97e,
+                    0x183d82609ff4caf1,
+                    0xd4bd04809ded832c,
+                    0x7fa0e6ad59e8f3b8,
+                    0x78c339dfbe70c890,
+                    0x12615092cd70439c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xefd8b8df73b7c939,
+                    0x5724dd63fed57b8d,
+                    0xcd43f2b69623033d,
+                    0x5d8e9f8b2876409d,
+                    0x2fde91a4fd1865bc,
+                    0x03b3475429fd8e23,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xf0347e6fa257a3bd,
+                    0x6d87103eda4899c7,
+                    0x296cd114d2528170,
+                    0xb842024c21d5ce1e,
+                    0xd412dae1d413bff0,
+                    0x0d53173b9a19fdd2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9c20bf48c86f2b1b,
+                    0x8769d600c43cc9d1,
+                    0xffe5eebc80ff8acc,
+                    0xa08e843e65ce41d9,
+                    0x6057ce396d092543,
+                    0x0adb41e28aaa3895,
+                ])),
+                Felt::new(BigInteger384([
+                    0x73c9d0db8993c307,
+                    0xb9bc15785ef60b41,
+                    0x893794c04d9a2031,
+                    0xb3e46c6b7ddf1a17,
+                    0x7ac57ae00e35511d,
+                    0x171f7a89ef649ffc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x152dbe3e7b7be20e,
+                    0xcb9193e3e20c8a60,
+                    0x8eccd97c20fbeb89,
+                    0xe569712ee249a7e7,
+                    0xdab60e11808ecdc2,
+                    0x0e34bee65b17e2bf,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x5e547221d4a79252,
+                    0x30e68f8cc7ec8130,
+                    0xd59e1678776964b9,
+                    0xc2ecb0701c91d8f5,
+                    0x62d0d109a175984e,
+                    0x172a51319083f3ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc2b106a49d7d355,
+                    0xeb9f1dbf2c0e3a94,
+                    0x07f3478f995205bb,
+                    0xe00e974b8cdadc4c,
+                    0x5ad41913bae42a9e,
+                    0x166d7b08bd4aac40,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b92bc23659653a8,
+                    0x82e3510b7819cc4f,
+                    0x59f6a68693f312d0,
+                    0x70a95395d9b37113,
+                    0x9c4c4d2eafa0daae,
+                    0x0a0ac01e8c5c50d2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfadd9401d4c47f71,
+                    0x4e2aa46416dbfdab,
+                    0x4c150cd15420cc37,
+                    0x84a7eb2f341a3347,
+                    0x0737ea2cc78c485b,
+                    0x041bad8fadea2b5d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x026c53ce2862c461,
+                    0x32b3f0e8b8e3f9f3,
+                    0xd06fe8d432d1e56b,
+                    0xdcd47cc37a674a75,
+                    0x46bf8d055f9a3dfc,
+                    0x1792ad881a5b5a2c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x757cabe750d0ffc0,
+                    0x7f3c3673510c1636,
+                    0x2f299ab4ae1afb3d,
+                    0x9f282f6cd93a9921,
+                    0x27c15b991da16d62,
+                    0x134497012f614505,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe712729a02fd1027,
+                    0x48858f4d0386f5d4,
+                    0x4c891c764d67adf8,
+                    0x33c15e1f54d2c196,
+                    0xab210757490f910e,
+                    0x0607e87a66f84b08,
+                ])),
+                Felt::new(BigInteger384([
+                    0xde7cf666d7e1dd5c,
+                    0x9a1578038b4b687e,
+                    0x2338629732e5cedd,
+                    0x60b74d7e285d660d,
+                    0x7d65a527e93bcfe9,
+                    0x16ce252af9662984,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c69a1940fb586cd,
+                    0xc2c932596e61d4fc,
+                    0x74ab5dfeb8958585,
+                    0xf0cbabbb0ce7e890,
+                    0xbd2b4cf90c03fd82,
+                    0x1133b305a82c12ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x539d2fda05f53fcf,
+                    0xed99e052d503bbd3,
+                    0xb3a10f194c634bfe,
+                    0x9b670688228efc65,
+                    0x8ce691afffa67d1e,
+                    0x132e2feb8e755a2e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x62c42d4d13a4f5e4,
+                    0x1584a490988454cf,
+                    0xd858fe978ddf42c5,
+                    0x238f1f8770207f85,
+                    0x837aa9f994f1cdbc,
+                    0x0e151e3d1bc43119,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xf53fc31acfd3cd23,
+                    0x09523076b7569d2a,
+                    0x4afe3b0ebeeb50bd,
+                    0x20ee19ffb714bc3e,
+                    0xfc4e51fcda95e3e1,
+                    0x11f403e2c97d7bfe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x94cf6b443e54b5a4,
+                    0x62aa32caaeb748f6,
+                    0xfab53940b3c30fb6,
+                    0x644aa77a0695b86f,
+                    0xead2e79d1f098046,
+                    0x09afda648fae14fd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1f0d427f983bd320,
+                    0xc7d917b032473b3a,
+                    0x2750daefdf13fada,
+                    0xb7783ae0219cad13,
+                    0xdc687bc475333f7a,
+                    0x1974f7a4c5d2dbe9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8290009b0f077816,
+                    0x40ccfa668433f2a1,
+                    0xc36fdaab067674cb,
+                    0x3387ca22645a6ffc,
+                    0x0b3252485deef616,
+                    0x0511270e9dd942b6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6dca78a4c4801da8,
+                    0x702f731ba79a15c9,
+                    0xcf71d53477b9e447,
+                    0x6c9d7e8654577c19,
+                    0xd5c448c9005ca5e7,
+                    0x056aea436d21f8bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3fe35628cefa6967,
+                    0xe264e0a5925ac5c9,
+                    0xa09b99972be89478,
+                    0x749ead440f3b7949,
+                    0x06f707b5d5eb007f,
+                    0x0f07c114ab2fb300,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2584849c6aba1176,
+                    0x801065d45f09b45e,
+                    0x7ff9a6f153cef097,
+                    0x2f99f24637ed1ad2,
+                    0xae03c290f68f6e05,
+                    0x08e79012fa0feff0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x85e908dfa9dc35f3,
+                    0x852307184f693b41,
+                    0xd8ef414e88f1db21,
+                    0x40d34a832995a501,
+                    0x8ed5ae9988d125f6,
+                    0x0424854d994d2cf3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x61a51691ed0a1d5d,
+                    0xa9a5bf06230098ee,
+                    0xfa63d6f0ad622407,
+                    0xa758f17fec771ed5,
+                    0x038ca80c2a2159cd,
+                    0x14f313bc458c5b0c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd96e7c50260cb05a,
+                    0xf865a5581d0f19d4,
+                    0xc7109e7e5282dafa,
+                    0x3a17de3da4ea8a2d,
+                    0x43cc6a491c846fb9,
+                    0x075c7cc3c13ad75f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x220d6bb16b831a70,
+                    0x84a46880f7428fc4,
+                    0xacac9ffb6dfdf704,
+                    0x088245cc538735ab,
+                    0x745d919d6a071593,
+                    0x175e3743ff11008f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x41f4b99b8d4cac76,
+                    0xfd04954447693a2a,
+                    0x612c22f7e7b22202,
+                    0x7642fe8ba9b8e178,
+                    0x1a8ee54aca21b1e9,
+                    0x190503087a7123f0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x12b4e4573e0322e0,
+                    0x6daeb17868ca4fc1,
+                    0x972a499377f3b084,
+                    0xda7a6bdd5424c57d,
+                    0x95f6d789305987f0,
+                    0x0c3b17b823ae37dd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5efaf1ec1ca5bd09,
+                    0x0433fbadba06364c,
+                    0xff347f32fe816766,
+                    0x84d7d8974b0af1f7,
+                    0xc25d1b697f07a4ca,
+                    0x136c85931141753e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x50743e722470036a,
+                    0xf8e2d596bc7ace22,
+                    0xd5c9eaab20b6b349,
+                    0x8b4432cf1016e507,
+                    0x4fb55396967e8e9c,
+                    0x16af06c74b5cbe9e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf14ba7f2898ddb45,
+                    0x614b5b3cc8ff4313,
+                    0x29d55eaaa1a2d3e1,
+                    0x2bb63afb73598c53,
+                    0x789780ddd7caca47,
+                    0x137cad6566b096e2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1c8eb8981e6f6779,
+                    0x9f2818df2e6ad391,
+                    0xdd4a6d27371d01c8,
+                    0x35038f3f584e511c,
+                    0x013da70f6981e81f,
+                    0x06b017f3248799e6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0d19f3ffa0250f70,
+                    0x77de09381338bffe,
+                    0x6499cb7b7d3b8feb,
+                    0xcc8b09c29cfe55f3,
+                    0xf08886c0a7076ff4,
+                    0x0f9fd8b5de7aaa89,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x398a116e91cb4c4e,
+                    0x4a48924955d365f6,
+                    0xbb3b718701420409,
+                    0x9b892f0107a24849,
+                    0xcafb5f5bea615b37,
+                    0x0565f6373c53f1a8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xefa7f2a1b6061f79,
+                    0xc681eca8c2ed9755,
+                    0xb1cde71a703033a7,
+                    0xa5db11c5e1a0f20d,
+                    0x097bf2f56d1830ac,
+                    0x12d589d7f1eecc2f,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xf53fc31acfd3cd23,
+                    0x09523076b7569d2a,
+                    0x4afe3b0ebeeb50bd,
+                    0x20ee19ffb714bc3e,
+                    0xfc4e51fcda95e3e1,
+                    0x11f403e2c97d7bfe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x94cf6b443e54b5a4,
+                    0x62aa32caaeb748f6,
+                    0xfab53940b3c30fb6,
+                    0x644aa77a0695b86f,
+                    0xead2e79d1f098046,
+                    0x09afda648fae14fd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1f0d427f983bd320,
+                    0xc7d917b032473b3a,
+                    0x2750daefdf13fada,
+                    0xb7783ae0219cad13,
+                    0xdc687bc475333f7a,
+                    0x1974f7a4c5d2dbe9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8290009b0f077816,
+                    0x40ccfa668433f2a1,
+                    0xc36fdaab067674cb,
+                    0x3387ca22645a6ffc,
+                    0x0b3252485deef616,
+                    0x0511270e9dd942b6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6dca78a4c4801da8,
+                    0x702f731ba79a15c9,
+                    0xcf71d53477b9e447,
+                    0x6c9d7e8654577c19,
+                    0xd5c448c9005ca5e7,
+                    0x056aea436d21f8bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3fe35628cefa6967,
+                    0xe264e0a5925ac5c9,
+                    0xa09b99972be89478,
+                    0x749ead440f3b7949,
+                    0x06f707b5d5eb007f,
+                    0x0f07c114ab2fb300,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2584849c6aba1176,
+                    0x801065d45f09b45e,
+                    0x7ff9a6f153cef097,
+                    0x2f99f24637ed1ad2,
+                    0xae03c290f68f6e05,
+                    0x08e79012fa0feff0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x85e908dfa9dc35f3,
+                    0x852307184f693b41,
+                    0xd8ef414e88f1db21,
+                    0x40d34a832995a501,
+                    0x8ed5ae9988d125f6,
+                    0x0424854d994d2cf3,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 564];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+            bytes[188..235].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..47]);
+            bytes[235..282].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..47]);
+            bytes[282..329].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..47]);
+            bytes[329..376].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..47]);
+            bytes[376..423].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..47]);
+            bytes[423..470].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..47]);
+            bytes[470..517].copy_from_slice(&to_bytes!(input[10]).unwrap()[0..47]);
+            bytes[517..564].copy_from_slice(&to_bytes!(input[11]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x7feff1aeb578e5b3,
+                    0xa0a91b2e416ffc46,
+                    0x9f077fb2c0bf2e81,
+                    0x0c195eb57f96affa,
+                    0xb9ef839ec1476f90,
+                    0x1764772cc1085d7f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ee321e687c99764,
+                    0xbf526b100a5ffe5a,
+                    0x77e908f68aff0261,
+                    0x0f4a4e537dab68c5,
+                    0xa6a1299a4ff3fe2a,
+                    0x03471af6ca7d3c30,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd275765546a7824,
+                    0xc5c81ce86022fc72,
+                    0xb729176d28f6c656,
+                    0xe9c8da58fc949398,
+                    0x40b6cb42ea56f65e,
+                    0x01a6dd402e261a37,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3c009e7a3fec3e17,
+                    0x23825c3cb60f8c17,
+                    0xe3b4a6ffea293139,
+                    0x32f12b9087b16110,
+                    0x6c864327b3014543,
+                    0x19125d2269704958,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdc99996c4c503b81,
+                    0x7c289a1c1f93aebb,
+                    0x212dba4faf861489,
+                    0xcf38ec582c3a27dc,
+                    0x282a33b97fe57da7,
+                    0x1904fd4e8bd4c83d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbf2aef5b4ff5f548,
+                    0x0dd5ccf99f15e9a7,
+                    0xe977c222dadf107d,
+                    0xf52b2def10df4f5b,
+                    0xb61a30261ec4a721,
+                    0x1657fbd75aa50bd7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2bf8d10e37b2467f,
+                    0xac4eb8c76fb97c90,
+                    0x9ffea0ab39aebbf7,
+                    0x9ab57e7f42e721f9,
+                    0xb50ef03c7b7f2aa0,
+                    0x053251b52a734e4a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8126bc8a43754c9d,
+                    0xe2a9ee0d089a6157,
+                    0x8ef82efc06f1662d,
+                    0x56b84dc08146c95c,
+                    0xbcd959d421d79a7b,
+                    0x16dc9511199b38e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x898ad3851bc7acdb,
+                    0x4824cb8fb3d422b5,
+                    0x04ee37c7264f808c,
+                    0xc6deb832b98b9628,
+                    0xa697a6b60cdd22eb,
+                    0x0189ad970305ea2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x519e881712a986c0,
+                    0xffcbb626323aff0f,
+                    0x5d7da6b68935ad61,
+                    0xf427505eadb39919,
+                    0x689562ff4f5e3d49,
+                    0x0e43edcd16ae8830,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c318b7327bd9ea3,
+                    0x8811144eb6f802b9,
+                    0x645d15eee5364880,
+                    0x403e60548a466097,
+                    0x12f400a857ec856d,
+                    0x03b6c03f24a2a66b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x847262bae9af2a5f,
+                    0x7d9da1dad339e840,
+                    0x51752a577aef30cd,
+                    0x9609f51849af372a,
+                    0xbc682e8efa1064b7,
+                    0x11f21d4063e21c48,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x4443a0a8c5a954e9,
+                    0x64702134ce0756ab,
+                    0x94ab4c1ebe3d91a6,
+                    0x62bc3a67576058a9,
+                    0x6a176d8f9acc5f5b,
+                    0x02752f9484c4571f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfad0a75898b88027,
+                    0xe2f1055ef17937f4,
+                    0x2a4c7a59cef40da8,
+                    0x6c6d721dd8138929,
+                    0x52565f1ae41ef100,
+                    0x0c43a624bd31db27,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe3c8b345cd8782fb,
+                    0xec17f0c69c4b5f50,
+                    0x4e72c5618cba7f9d,
+                    0x2233d685aa263cf7,
+                    0x9ffb610909c06152,
+                    0x111753c7f7ed9281,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d70f9bb8d851bad,
+                    0x08832b9d0d44edc6,
+                    0x64f4a90e015706d3,
+                    0xf420d8705b053edf,
+                    0x9b3ec00f35ba0d9e,
+                    0x1685f22e56dd10cb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaadbd93381941847,
+                    0x6b492fc9017b1a65,
+                    0xe91a579a093ba651,
+                    0xa2a232178a94ad26,
+                    0xf7167046bc8e0da5,
+                    0x146de0fa9dfc26c1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x026f10adbc22af2f,
+                    0xd11d26cf39900557,
+                    0x41da6706ebea92a1,
+                    0xb41ae852f6bbf94d,
+                    0x27554fdfee10175c,
+                    0x0d15fa647872ee52,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf2eda3ed5e8526c8,
+                    0x2ace652b7987baf3,
+                    0x5613273d872a3b71,
+                    0xf1bc0f1f5f92504d,
+                    0x610b3ad7906afe28,
+                    0x16c6e78302d842b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0c2f55e1aedaacbf,
+                    0x0da9b9376830be62,
+                    0x7c38c39eede55b3d,
+                    0x6d746423429681cd,
+                    0x11507e0373fee9b3,
+                    0x167b0e864646c56a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf3738333f209a905,
+                    0xb663c1b392d15763,
+                    0xd477b687858758d1,
+                    0x17d724f4bb5ba887,
+                    0x9f92b6217ff774b5,
+                    0x16a56205afa0d8f6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xab86504e5270f82d,
+                    0x5c00b607c8740f2b,
+                    0x89b52968b7c18c00,
+                    0xd1aa50f5ca08d6d8,
+                    0x11aececba6682750,
+                    0x0e17848708afb082,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb12e1c953497af1c,
+                    0x11dfe841c5ca3b0c,
+                    0x14053d6f4e979b45,
+                    0x40bf523c19dc9cc6,
+                    0x60ce69be0ced3f89,
+                    0x180158fd824aba3a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3376e426401f78ea,
+                    0x4329e4628c05c707,
+                    0xc01225db465eec73,
+                    0x2564622f5c124d8b,
+                    0x28850855fc9f00aa,
+                    0x135e5330d1d72974,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xb24897da900fe52e,
+                    0xcb9f8c3169477ae5,
+                    0xb3ef9ba8ce6b8b8b,
+                    0xc47c7bdba56ee78d,
+                    0xfb7bf909f6fabec4,
+                    0x0117077dbbfc92a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e93d78ef5a2dde4,
+                    0x92e8c5159ec3eb72,
+                    0xedb9d58c9d840b0d,
+                    0xd3b924e7d2741bc6,
+                    0xcf79a9ce6daf2c38,
+                    0x033713573044ca57,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2ce604ebdc1c756,
+                    0xc664591e4075a3a1,
+                    0x65ac3644db969f1a,
+                    0x1dd826db9ad74529,
+                    0xb0cd1a1a040d4ba9,
+                    0x08b6a1b206908c35,
+                ])),
+                Felt::new(BigInteger384([
+                    0x68779dab1dfdb8a7,
+                    0x095a1cb6cccae896,
+                    0x03625a2f4a7a94d5,
+                    0x501f11df38820e7d,
+                    0x5a2cc465e01e67fa,
+                    0x06ef861d480909e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3083ca820a59e1c0,
+                    0x4f46815238c7d875,
+                    0x7de29e8cb420cfe1,
+                    0x08b5d648db89fe07,
+                    0x741c4a5c613c1c50,
+                    0x078400c1acc7885b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0190664a22500c02,
+                    0x3352673cdf688d49,
+                    0x625ede3119b773e9,
+                    0x8b03d417da7b8bb5,
+                    0x9bf91718ea9c1415,
+                    0x066c7c1e7a67ed53,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf5e794eed41c304,
+                    0xf5401c613cb46bd8,
+                    0x3e022051ffbe475b,
+                    0x90d25fd5daee4057,
+                    0x189504ce6148de50,
+                    0x03ce36608341c6cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xce3418611f38e50d,
+                    0x30fd6a8dfc019521,
+                    0x4eeeb816584cac2e,
+                    0x0a2ce5e54ac7e5c3,
+                    0x8082e6d01fcfa4fa,
+                    0x09cab16980f7387c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeb122315b1b896c6,
+                    0x2e712f860a18539d,
+                    0xd20faf99a5c154f6,
+                    0x3695aa35d3a2d720,
+                    0x20e03b95ed620752,
+                    0x12f0b655a916fc36,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfeb4d39f34f094c6,
+                    0x1be52127c5991ae1,
+                    0x4575170ee3ba5d30,
+                    0x898a99dfdf72ba81,
+                    0xa6e7a69e90005f09,
+                    0x0330f4a7496e80d0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x01e216b77dcdcf6e,
+                    0xd161f6914a737fac,
+                    0x9bc1ab4d2165da25,
+                    0x99ec5bdc78201c98,
+                    0xdf3067e1a21cfb4d,
+                    0x0a7234516ff23e37,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ac10887576d4fbd,
+                    0x333eb62b5efb9112,
+                    0xc50557fe0bba3c72,
+                    0x4441d8a27ad67685,
+                    0x790069cf8da9a823,
+                    0x18703ba0acacc45d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xad3adc5d5e6606c2,
+                    0x269eb9a9d4872977,
+                    0x1effb681b06bee2d,
+                    0x62f9b73c3370c93f,
+                    0x85446fb292c5183c,
+                    0x169dd6a79ca0d6f5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2fb892db4cffba99,
+                    0xc42f4dca4af9298e,
+                    0x482c7870eec58b73,
+                    0xc93586c3433b2515,
+                    0xf95872fb982bb352,
+                    0x04761e66f1b6be8d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x426e9b207dee2fa5,
+                    0x990b8b3f9faaa57e,
+                    0x13ca5d334968b32d,
+                    0x253ef167777b964f,
+                    0xd7a0c1ddc334d44f,
+                    0x196e58d346791f33,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc5825f8c064da4c3,
+                    0xd0462fbe6ec13f9c,
+                    0x106fcaffc9be6e6f,
+                    0xac3e672e110ae2fa,
+                    0x0719784a14b01ce7,
+                    0x01c690cf2199cbbd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x606c0e9d7d93f1dc,
+                    0x87a1b31d04dc00c2,
+                    0x7e36f77b6b5ba61b,
+                    0x9d0d7924d82fa27a,
+                    0x39ff6e790177f961,
+                    0x14b6f8872a02ac00,
+                ])),
+                Felt::new(BigInteger384([
+                    0x838e8f139967f950,
+                    0x1fddda0a7e92f1a2,
+                    0xc0208c03f0435155,
+                    0xd9fb3b1c375daf92,
+                    0x571c8d0bc8729c1c,
+                    0x058195706b88b723,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa3947c626d8bac50,
+                    0x4904a9d65cbc6fb0,
+                    0xf75a8359537e838b,
+                    0x9a98f8e4df93365f,
+                    0x7e348e821c758206,
+                    0x0ce24ad55cd4c7df,
+                ])),
+                Felt::new(BigInteger384([
+                    0xec95fc3ec7a829e5,
+                    0xea0825fca7194786,
+                    0xb59bff3aa23bf9d6,
+                    0x6f1a9df92210ccad,
+                    0xcda4c16ba3af1c75,
+                    0x0e4bf89715ef131d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x46d538fb0bee00e9,
+                    0x89cadc326cef861c,
+                    0xb886d79834850648,
+                    0x61634dc148e5e5a1,
+                    0x8948dfbcd600d469,
+                    0x17cd97b8e5fa867b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2552cec0f0dc405f,
+                    0x67c52b16920e2421,
+                    0xace01c2c03cd4611,
+                    0x5897fa4521d42a34,
+                    0x5f9503f5750f7d1c,
+                    0x0a557fad4cf996ac,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbabc06d1605086ac,
+                    0x4a9b2474f0c59adf,
+                    0xf16def15f03f249f,
+                    0x355a0521d8cc9897,
+                    0x6960a1ed75d43c9f,
+                    0x09e9b230f5789bd2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x364a18e12ff4fbc7,
+                    0x1cab0201e0c66ea3,
+                    0x9580f0598cf6e98f,
+                    0x2f6fd2c9bbaafa6e,
+                    0xaeedefcead6be4db,
+                    0x08f2fb7b7097222d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x1276a4fd5037ba2a,
+                    0xa6f158153e499b0c,
+                    0x8a8c9070c0e9371f,
+                    0x506270d3edbec569,
+                    0x4854732c7862a8d7,
+                    0x09b4fcbac89b999b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x36b1ec2ef389951f,
+                    0x4c377b5b92c29622,
+                    0x794d72af31cce1c7,
+                    0x00447e9b7923abec,
+                    0x5f20f9931f9d3d70,
+                    0x0aa26b2615838dd1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2fb8444ce2ed5bdc,
+                    0x8f482ad16fff8bf7,
+                    0x73616166c8275ece,
+                    0xe6dbaa32a872fa45,
+                    0xbbce5606e7a16806,
+                    0x16e1f554f45d74fb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6399f42918e2174d,
+                    0x9b961b0d85bfdacc,
+                    0x16dfe6257d48d78d,
+                    0x9d30d60a7d2c5f29,
+                    0x330cb550dcbcc106,
+                    0x150d7b3e099499a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x893a009e95ec503e,
+                    0xd7be8d86e6b97749,
+                    0x2cf15e97c80e2ea1,
+                    0x8aa1bca09ccbdaf7,
+                    0x972dd057cea03b47,
+                    0x0996594d387fc75b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3127351d7e4ae194,
+                    0x55b5b9e9de3ba3d3,
+                    0x7f83b78009262c16,
+                    0x1c26bc8a6764b75c,
+                    0xcd69a7bff0e4f383,
+                    0x0fe7978408e8690f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d5989724d003bf1,
+                    0xcf65b0821b78b217,
+                    0x13f84d016ae242d1,
+                    0x869f0db28a9183b2,
+                    0xf4f2adbd468117b5,
+                    0x0ac9301b5930040a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x026f857cfeb57a62,
+                    0xd758e19cb9cf683a,
+                    0x881effa336caa516,
+                    0xddf0291d3bed37a7,
+                    0x8d8d90fe0282ce31,
+                    0x17c402f91854b2de,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcfe468a3340ac3b4,
+                    0x084f51a7aa2d58f7,
+                    0xd1adec8f3fa80606,
+                    0xd3fb31d1bb05cc19,
+                    0x0c0515fe372188de,
+                    0x10171564095867ff,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb37d30bb9732e8a7,
+                    0xe017bde6bd7e8c6d,
+                    0xfe35aef594d57025,
+                    0x45c7aee1ccbd77ba,
+                    0x7e36e1fd9a94fafb,
+                    0x0bcd41ebac6b98da,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8177da520e6d773d,
+                    0xe73ee105b55fd429,
+                    0xfd41cbd5db2ff928,
+                    0x04a9891600daa11a,
+                    0x8762723296ec05e9,
+                    0x134283cb550766c3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1ff84883d094ec67,
+                    0xa1c2fcdf83c89bd0,
+                    0x46f35bf30ab55c47,
+                    0x44be1585cc3d639f,
+                    0xae8b699ac5c30d5b,
+                    0x04258a6c5b888047,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xd9b9d6fcc0a2c59f,
+                    0x715b9f07a22d93d6,
+                    0xc4aeff992cc6f981,
+                    0x5945d250ef73c6ba,
+                    0xe14f2f0ff4fe3087,
+                    0x18f533a7cc24faf4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x307dbbb08c205274,
+                    0x9c69d1fc2c2ad20a,
+                    0xb707230b8a9daf6f,
+                    0xa37ac1bd5586f032,
+                    0x90b36f5a59d30a56,
+                    0x042b1eb44bfa9585,
+                ])),
+                Felt::new(BigInteger384([
+                    0x25e4025e4dc52669,
+                    0x36ee36ba731fe737,
+                    0x57eacc448748b277,
+                    0xdf3674318f45fe96,
+                    0xd82f49dc17479759,
+                    0x10cff0e21f57919b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53bcc348b9e923b3,
+                    0x8ee0d07a60c15a54,
+                    0x96c33498b7ca61c5,
+                    0x64c72f0a25fb8601,
+                    0x6d631e29608ba937,
+                    0x0b2d2c7abd9a534a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7902008744071c08,
+                    0xbbacd37ad133e35d,
+                    0x27a13947c991f83d,
+                    0x0b7db83b3fd87c4e,
+                    0x7d5519705e9f1d6f,
+                    0x1182b38df8b09c26,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c9b5c7c5d4a271c,
+                    0x1c84c18b26e3bbd5,
+                    0xfa17a7c033504732,
+                    0x9295b0217ac4a4fc,
+                    0xd5a102683d075031,
+                    0x0a056da3705b5c62,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe788b2f879358bc0,
+                    0x78632aa46f1bab02,
+                    0xcf853197bc7cb91f,
+                    0x94afc72e498ebf27,
+                    0xb761394f22e53175,
+                    0x1889b68a6d8482f6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x68d9181e3e5be6a1,
+                    0x907c73641dd1d24c,
+                    0xa880dcf2aecc80dc,
+                    0x78b11b140e2595ce,
+                    0x4b5e994fab1d51b5,
+                    0x065228a9e87188de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56ebed5c28c28e6c,
+                    0xbbdf711bf13bbb43,
+                    0x9ec792f231953b8b,
+                    0xaf65839125883fa9,
+                    0x8bd033dd5f3a2e66,
+                    0x1117a00502ccc185,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f73e014035d5a7f,
+                    0xe38bb885dfdb1569,
+                    0x8646f6a0ef076f0c,
+                    0x8b209234d0ac8650,
+                    0xa00a03247bb5611d,
+                    0x0809a293b2c6eea0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x116ca7f4757e4d32,
+                    0x22e3a580131d7c44,
+                    0x2919282b316b5523,
+                    0x7fe59ac11e5a4088,
+                    0xaf6cc153013173b8,
+                    0x137a6234f4ba7ab1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9fdc6f13fe030be5,
+                    0xe0d987bd6027bfef,
+                    0x9ee482689c31d017,
+                    0x9ff3cca8f03b62bc,
+                    0xd20b8f7372cfdee7,
+                    0x08df47827cc0e2bb,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x11431fe7dc3f3233,
+                    0x208f40f875f85804,
+                    0x45c5109f196eb576,
+                    0x689211cc6f8333fa,
+                    0x90853cfadde405cb,
+                    0x0e7a76927bb9eed8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb775499bec6ffa03,
+                    0x63e294f207569269,
+                    0x95d586a957f71451,
+                    0xcdb2e29147fc63e8,
+                    0x000a2048f91667b5,
+                    0x0b6702b98ee56b0b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbb424fd69e31ab8e,
+                    0x6be6d59bd510e3bc,
+                    0xb7e63707d7e6abad,
+                    0x7fd6a57945f8fa1d,
+                    0xe3889fe685f8d87c,
+                    0x06e654317903d900,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31051c4dcf57700e,
+                    0x6e8db3a2316d0126,
+                    0x00db826f8d0fd4b2,
+                    0x4aaf6f15428092a8,
+                    0x6dd3e9bd69a86bab,
+                    0x073d7e17c4f39475,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5cf6dfaedc62cf27,
+                    0xd7b0399f6319fb89,
+                    0xc59d5bccc40e1b36,
+                    0x914faf041db6e7d2,
+                    0x467aed473f9bc047,
+                    0x13e15002c5d6c90c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9bc5bd2e2353eee9,
+                    0x4b0bca046657283a,
+                    0x036243d3ed7d7c4a,
+                    0xeac5fe021ffd2f0e,
+                    0xcc9ff19fc1e90f29,
+                    0x04e808b57b33155c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5e0a965a1b0576bf,
+                    0xc509ffa89d887e6b,
+                    0x8432b30ba97356c6,
+                    0x56495e9a37cdbe48,
+                    0xbc55c545ef34bb7e,
+                    0x15cbb7d8de374d3e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x516fe0539819fd98,
+                    0xdfd749e98c4c256e,
+                    0x9ed0082e19b56a4b,
+                    0x2677d4ab3a2ed461,
+                    0x4556705e6da66bad,
+                    0x0f1ef9deab1b4990,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ee2a5f26dc52a76,
+                    0x8c7d410669980686,
+                    0x343b6785006d6dca,
+                    0x405dded2c7b513b7,
+                    0xbc10d15fa9476b83,
+                    0x11ada5e1996294eb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x34929e46301e16d1,
+                    0x98cebd7cc913736f,
+                    0x576b323341d748c4,
+                    0x7692313c14f944dd,
+                    0x7818753f69c03e99,
+                    0x0ac6c77a437bf8ce,
+                ])),
+                Felt::new(BigInteger384([
+                    0x28485cc20d51957c,
+                    0xa3079df3d994b6b3,
+                    0xff7baf061edf9fab,
+                    0xe802341a9656583a,
+                    0x90b3c84efc1b57fb,
+                    0x1065c5076cf48a33,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f424672210f2ec4,
+                    0xb8266bcdbebdae90,
+                    0x0ec74d563aaa8506,
+                    0xdef2204b0729937a,
+                    0x6307145d6cde12f3,
+                    0x154708e2220b3cde,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2ebd0c2875909f84,
+                    0xb788bfedfe60a4b7,
+                    0x5b86784dbee7b25e,
+                    0x0460b582587bf2b4,
+                    0x081faddfc252df09,
+                    0x11356bcfacf8bf32,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6038383bdd6847e9,
+                    0x95f1eede6ecee32d,
+                    0x7721b60ecfdf4515,
+                    0x4d77ec4745deb056,
+                    0xf7540d4675c31fd4,
+                    0x18b4ec99ef3677a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc2cd98e04a618ba5,
+                    0xc7ae3848a3e76d51,
+                    0xcc4cfb605fc93e82,
+                    0x18ae357a2da5bcfd,
+                    0xd52a9c75691d66b4,
+                    0x0b99ec1822f256cd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a2c7a7bcd9df797,
+                    0x3943b17f3187701e,
+                    0x874474a1f61667c6,
+                    0x577d1d8afe939c23,
+                    0x499e01e8b84c0b69,
+                    0x0ebf35ca66d38186,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7a8c4ade9f8c722d,
+                    0x3532dd3f3d9d6a31,
+                    0xa409649a3383a636,
+                    0x5a4ca84830523bab,
+                    0x7bfa45198f0a878c,
+                    0x092b2180c69ea79c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf335dd4d284da0bd,
+                    0x2888c11f88b85a26,
+                    0x1af2ca4e3d57b113,
+                    0xcb2bd0faa37ca1f1,
+                    0xcbc80fb9b95f2d10,
+                    0x006718c06d8b9dab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfb6f4e1c885b9908,
+                    0x8d440b9896cad4c3,
+                    0x565fc8eefc2fbdb7,
+                    0x60ccb26548ca3def,
+                    0x163c63a12a165c5a,
+                    0x04b36cb9bd541ede,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21a53769ae2387e3,
+                    0x3a357e114d19538b,
+                    0x31930eb280373a9c,
+                    0x2acf4b7d753742ba,
+                    0x79d4f2b15934e8f6,
+                    0x0d6a2e220107f1a5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6aa478563399b789,
+                    0xae14107ae8fdbf71,
+                    0x2928dc4e97a6dec1,
+                    0x4dcd414c4aadc5b6,
+                    0x085688ee7da2c15e,
+                    0x1919af0a9b8a1f44,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6043d981abadc5dc,
+                    0x216ad3c6cb57bf93,
+                    0x8b5665712381d6e8,
+                    0x11b3f25cc117c7c4,
+                    0x82bd5da7f798673e,
+                    0x08e1a6d2446f2025,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb144ac6b61d5e6af,
+                    0x4b4744319c8eff3f,
+                    0x89ce29a96b68b5ae,
+                    0x73dc2de6a94e2d79,
+                    0x074801667f422322,
+                    0x0aa9df823bb5c880,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8070df25afceee27,
+                    0xab296b4d1d90229b,
+                    0x122428fe19fdbc5c,
+                    0xc578a1b8ca4ca5e2,
+                    0xec930379903bdaa1,
+                    0x0ce13dcec5aa24a2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1f9d18239c25bcd9,
+                    0xa3c65a1b69c8e2a4,
+                    0x128ee020aa0ebe11,
+                    0x805b64e7430a0de7,
+                    0x2e854d1b001413d0,
+                    0x13b5f13ea5e6f51f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x996fc47663f860bd,
+                    0x5d1806884d020fd6,
+                    0x602f6c4a881a1355,
+                    0xfb9c6234e38a5c1b,
+                    0x113a37eb0e098511,
+                    0x0b80c0e63315c44b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6176886b5c3ed0ad,
+                    0x289cf9d1f6da19bd,
+                    0x8e3c2f79c13e089d,
+                    0xcd9d606bb705f084,
+                    0x7518b8776237814a,
+                    0x1073baf9620f60ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4fe8d0032062c59a,
+                    0x30a59596b320aaa0,
+                    0x9ccdc0f64acf922f,
+                    0x7a6c0f684e53ed1d,
+                    0x2187755eabbe83a8,
+                    0x0fb03b3d6b30259e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb72165db4c9d91cb,
+                    0x0b436a4974086ecf,
+                    0x53f794b402eecae9,
+                    0x2bcc9c3e394501ba,
+                    0x6b69087fdae2618f,
+                    0x0bdc481ddeaca82c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8b8b7344a788e29e,
+                    0x4220593cc5c630c0,
+                    0x38d703aa51229c80,
+                    0xfe56f78388ab8607,
+                    0xedacefe66f4aecde,
+                    0x0d1a235de22c3593,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf6703864dac0568b,
+                    0x928513a6feb0348b,
+                    0x33c588f1bdf4a15c,
+                    0x82b0630bb55060d7,
+                    0x70f0361815c2ddd4,
+                    0x018251a90f563d64,
+                ])),
+                Felt::new(BigInteger384([
+                    0x862234183d9def6b,
+                    0x00f8be676851e478,
+                    0x90cadc2770a1c4b3,
+                    0x02319e8fdde23120,
+                    0x2ce659f0be048ce0,
+                    0x17a1f5ca52f4b6f5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55a9ba0d44afdf67,
+                    0xcc6049fe0c9788d0,
+                    0xad8e1a8179313145,
+                    0x1c9e786ff4d91678,
+                    0xa4cb0f3095e9b98c,
+                    0x0c96e810864011f5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e22c7ba59463499,
+                    0xe485a7cb0f8bce13,
+                    0x289d0fc6756905db,
+                    0xb2bbd6cd27d52b0d,
+                    0xe6a00f5d125b59b7,
+                    0x17ccb6c48252d6ce,
+                ])),
+                Felt::new(BigInteger384([
+                    0x26e416f02c948467,
+                    0x18c32c4a6ba136d7,
+                    0xa95d4084ae548529,
+                    0x39492f6d97ca8be6,
+                    0xc1aea61a4964fcc9,
+                    0x17ea44a35957cf3f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe1f987f9e104ea98,
+                    0xcc3b6ec3a71c76ca,
+                    0x5bb962d747006825,
+                    0x39f70b8802f0554e,
+                    0xb32edb9d62ba24a0,
+                    0x09407b7b22a70f86,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf05bf2bf4636da38,
+                    0x1958b0e150f4defb,
+                    0xc7c13d87293d2a37,
+                    0x2dae5652d281baa6,
+                    0x4f0459185c6af87d,
+                    0x11651b12ce0deee9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19ecc911140a44f8,
+                    0x84da4887a5e21d55,
+                    0x9c7944fdba518101,
+                    0xbb95b24b7a66f34c,
+                    0xa7e6bbd35eb808c4,
+                    0x14870b76469e8cca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45497f1613508fe6,
+                    0xa1a451ec91ab2f54,
+                    0x182bc5b269de255f,
+                    0x69a33d9f094512fc,
+                    0x0f82af86d3c59fcd,
+                    0x11fd3a4a23ad2fa4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d97738107690b37,
+                    0xc25b34bb85282286,
+                    0x2dc1b05a80ac43a8,
+                    0x6dcbd6f126b3ff91,
+                    0xcd11941bea9c9ec3,
+                    0x13287584b4b51cb6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbb3ee26fef35e1e8,
+                    0x321be28fe22be30a,
+                    0x24844660bec0ab63,
+                    0x2cfa35ad183c630e,
+                    0xe7d3f9d23e36d101,
+                    0x0c15e74627643433,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2907665739a02880,
+                    0x21a045aa5f7eb462,
+                    0x93c6c2609e509d01,
+                    0x83c985af61ba902c,
+                    0x7eb8bd4d06ed0875,
+                    0x02cf7894118f6ad7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2a16223a7a0ebaa3,
+                    0xd56201f1ffa81237,
+                    0x065b1f482690a5db,
+                    0xc77920f07f867c17,
+                    0xab339eafa2675778,
+                    0x0727c5a5c52f5a39,
+                ])),
+                Felt::new(BigInteger384([
+                    0x191dbc28234d0b0d,
+                    0x8ea6eda43c06e634,
+                    0x557c6729c01d2fbe,
+                    0x5382ac08b7e0ce8d,
+                    0xe553f40b0ab70e16,
+                    0x0b3f65f9849bc201,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c03ba59b1c26b28,
+                    0xeabe67aad7091124,
+                    0x92011ff411c13435,
+                    0x7f0ddc99776573f7,
+                    0x4dba779b424314c5,
+                    0x073348556712a418,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe388024cc4f1c489,
+                    0xda11e06cb99bd2dc,
+                    0x55e339d609b90e57,
+                    0xf2e69eb4e73af00d,
+                    0xedc542356d243549,
+                    0x0c26c59bfca3c9b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x808160ef251a90e0,
+                    0x8d782ed71ff729ff,
+                    0x69863241be9ea69f,
+                    0x71d198179b2d4774,
+                    0x71ba04aeb6f51d27,
+                    0x02650485575ffd0a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d156380e4c8f0cb,
+                    0x4cba5ea1a02e32f1,
+                    0x21ef505a96603bff,
+                    0xd26bc4c24b38db85,
+                    0x505ff70236416931,
+                    0x02120238e6a56156,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3bfe97a2b0b3fea0,
+                    0x40e9b736fe97befd,
+                    0x6c63f60860ec1c16,
+                    0x252b5eee87e9458e,
+                    0x58280a6c8dadaf2e,
+                    0x018dc4da75262af6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6dca03bee3113ea6,
+                    0x639cd52e2101feae,
+                    0x0299c0d9854449ce,
+                    0xa923a30bed627a54,
+                    0xa65207c07975359d,
+                    0x166d058062882371,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21f5196673f4172b,
+                    0xc0c10925c4b5e75e,
+                    0x0571c01dc4a22f97,
+                    0xc272a0c1b6924335,
+                    0x15b82a08e5c40212,
+                    0x0ddedd391cf80c79,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0f538987ded39a1f,
+                    0x2e20029e9c408a50,
+                    0xdf86ac6f78219862,
+                    0x12ae1e395ccdb158,
+                    0x0855daa0304ab33f,
+                    0x0a0660c4c80d01fa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf3a156cd6b8adc8e,
+                    0x7dd84d3c7baa852b,
+                    0xa8e0909b0c9c7b77,
+                    0xf666a5c9300faa96,
+                    0x55ebf4ce5c2facf9,
+                    0x130d1aec78d90e0f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x966b4f2c6716b338,
+                    0x602245914b776c0e,
+                    0x34c6d1c438fdf9ae,
+                    0xb1d3c3279179cd51,
+                    0x89ed2261ff5c89db,
+                    0x0c2172ed834f2aa6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x88b3e49ad5e8ad0d,
+                    0xdafa7d9b3e7a99c7,
+                    0xa6f1715a25b59346,
+                    0x1c6d115a73fed04b,
+                    0x0cc3848d8030ecbb,
+                    0x0515959203fd1498,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92386a21444c2e81,
+                    0x75e1177fe55a73c3,
+                    0xc45fc7eb6d6bf746,
+                    0x5f37231a6f382850,
+                    0xe6ffee15899bcffc,
+                    0x13b12322705be943,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf64453524815679,
+                    0x68a7ac2b8b577f4c,
+                    0x4dbb4dba06a23ed6,
+                    0xa0c3290c1e9e8161,
+                    0xc08a19e6037f152c,
+                    0x12ebba8068e1fa45,
+                ])),
+                Felt::new(BigInteger384([
+                    0x20b3b2f94817dc43,
+                    0xeb383ddc21f84ce6,
+                    0x762e6c995fcea780,
+                    0xf0fdebd98b5a4fa8,
+                    0x45fafe616c30256a,
+                    0x148c0fc7cf4b7d40,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8b5a768fff362dbe,
+                    0x074df9dcb8eb95a7,
+                    0xbf2ecf6f06e09f81,
+                    0x19ec2f3b77504d50,
+                    0xc1869a835e835bab,
+                    0x13bb9117bcda9b1b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6cf443236f8c9395,
+                    0x6b7f3c1894b1a489,
+                    0xeffd3bbd8ca6c68d,
+                    0xa69f29ce400c2372,
+                    0xa2d15174143b3cb5,
+                    0x13c59566a263c364,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x3d590610a449b095,
+                    0x7a9155130011acaf,
+                    0x15562f222f1b76d5,
+                    0x93f5ebaaf02bdbe0,
+                    0x34fb4131db1d2725,
+                    0x0f7b9a118bad8ad6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf77edc575fef3caa,
+                    0x8595ba3fcb7d0c72,
+                    0x59e6d15bfa1a62cb,
+                    0x51d9dd8dc29639fd,
+                    0x68c81da58c890116,
+                    0x0df4a2269416b79f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x75a0673a46bdf27b,
+                    0x66fa085d97d37500,
+                    0xcd1de3f820f4fb46,
+                    0x5ed767c2fb14b0cb,
+                    0x34b4ec3b5049628c,
+                    0x16da118262a91299,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5bb0647829fa1565,
+                    0xb96e98d4759bc7c8,
+                    0x5976f7506617eab2,
+                    0x89c0e041d6702630,
+                    0x1994e0361597befa,
+                    0x08d3d575717d64ff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9f4cff2873606459,
+                    0x2b86c90114aa966c,
+                    0x5ac8165d9d8b5648,
+                    0xa695dfd7bd26e474,
+                    0x4d742c9e6340ab36,
+                    0x07e7ef243c5ca2b3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c20899261cb6787,
+                    0x7dec2ea99227bfa5,
+                    0x2223eff8c6666f83,
+                    0x8e4fe0b4c5c35985,
+                    0x0470703c17524af2,
+                    0x1249c5df29596b55,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a04599d544091c6,
+                    0xd26ac4d02d73ce43,
+                    0xe93bed9725ce2034,
+                    0x56bb990d785fe75b,
+                    0x24398d731b1185ff,
+                    0x05ff3fd6a938ddce,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ad90562c2840b8b,
+                    0xed4e8602309f7ec9,
+                    0x2d9093dce09539a8,
+                    0xeed1e6e9cf25d148,
+                    0x4b5482fc42204281,
+                    0x134ed820f4113215,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe4fe57dcd62b1425,
+                    0x2ca7a9ef475c4e50,
+                    0x5b4f093c91d2dd1b,
+                    0x43920b36bc7311ed,
+                    0xcaf909e12f162098,
+                    0x1549fadcd1e24add,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe93c5262e06eb21f,
+                    0x9940361594e5094f,
+                    0x300bf4c144430f35,
+                    0x2e4fa9d2570877ba,
+                    0xe0cd4614cb8a6393,
+                    0x0d45229368fae30b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2b685bdaf03a4c0,
+                    0x37f258aef5be006c,
+                    0xac60f88cdefdd2ef,
+                    0x44c1dba66ac81e8d,
+                    0x70d6bc49c30c4b84,
+                    0x164e4b686b7c9bfd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e982303cc22c7eb,
+                    0x943fd7068028b9b3,
+                    0xbcbffd627c051125,
+                    0x4c8ba20259d9c2d6,
+                    0x2a3c286c22258cbb,
+                    0x18c0851ea08f796d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x1fad048bfd49cfed,
+                    0x77c93e5249193c7b,
+                    0xdc5eb295f81cbb4e,
+                    0x36021663e402c5ba,
+                    0x9b25dc501d59fa9a,
+                    0x0c6cdbc35d1170da,
+                ])),
+                Felt::new(BigInteger384([
+                    0x812c992acfd730ab,
+                    0x87740cb388bd04b1,
+                    0xe8db87d0165ec3d1,
+                    0x2463a45f49916550,
+                    0x4579c5d43f78e3df,
+                    0x1580183b13d1ac21,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3508713b14973b87,
+                    0x73d28371c7ce7916,
+                    0x69cf54a321f3b60c,
+                    0xe78f57c49c3e539e,
+                    0x0f794074d72e304a,
+                    0x100e5ee22d28ce60,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7024125c8e645260,
+                    0x9960738b641f979a,
+                    0x41ac9e7ec82bcd5a,
+                    0xbdc08a1ab19db765,
+                    0x6f7fb6a40b3a2f92,
+                    0x0453e2a1d47ca2ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x716bce0df8f66da7,
+                    0xb5e5c7e756bef82f,
+                    0x9ab014d67e771ea3,
+                    0x9a3bcef66a8f179b,
+                    0x8cd33f9c3391ebf0,
+                    0x08bb0d4e82a6a9c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xff210862f416e17f,
+                    0x5f08d9614b3bd748,
+                    0xef9b49a795e91fbf,
+                    0xe31ed48c05798db4,
+                    0x96324295e1f0357d,
+                    0x024407ef345dfdda,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb581b30f5efbff80,
+                    0xd4d26a7ef18a903b,
+                    0x0daa1c24f06b040e,
+                    0x461650c1e44f54e3,
+                    0xc49c79ff5fa7ae3e,
+                    0x0a3e3df008f0facf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x604b2b027a78357f,
+                    0x542edb6fc939d0df,
+                    0xba5b80f1d9f50ada,
+                    0x9ea4ee7474a19ab8,
+                    0x8c7a78867bb92c74,
+                    0x0a6d16586841f208,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe073f3697026a86a,
+                    0x0bf37e8c8a593385,
+                    0xb908e5bb6813d7e2,
+                    0x093d61e030e50ab1,
+                    0xec7fab3524bb4e2e,
+                    0x032f6bb9866bcbac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x396c9f1a3d844326,
+                    0xdb71db9d596c93c7,
+                    0x1d3e6e83c47d7544,
+                    0x6743450410f1e09c,
+                    0x73a530fc9afe7c69,
+                    0x0399cfea29182526,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1289bd4994a71349,
+                    0x64f53a179d6b4002,
+                    0xe26c14b2433b1863,
+                    0xf209a2bfbaed411b,
+                    0xcba61c74fea56fcb,
+                    0x03635a2c1ad22721,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c19efa2fe160964,
+                    0x33f9f4bf5bf2b4e7,
+                    0x3d612325a21996bb,
+                    0xa0d14f531fc1fe55,
+                    0x7bc45fa8660a9447,
+                    0x11657adebca04983,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x0e48808d38a54e79,
+                    0xc04174ca1a12c622,
+                    0x593fa76ca9a99dfb,
+                    0x4267aea3e8597204,
+                    0x239432b97e124803,
+                    0x13d71251ae59cf23,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd40d782a898d9b5b,
+                    0x0f69714189977373,
+                    0x361a3f2d20f32180,
+                    0x8ecb1ddb05f20360,
+                    0x06e08a69d886b97b,
+                    0x19abe9d1cdade0bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4fe6266fa58e3364,
+                    0xb5f6178937c5ae14,
+                    0x264ffd10585a6b5c,
+                    0x36f3930526d05b57,
+                    0x3d85d365f99b7222,
+                    0x087eaa7877e1ac21,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16bef60406e14bb0,
+                    0xabe89a8c7a395180,
+                    0xdfb32611d162cbbc,
+                    0x9ecabb19f02b09a8,
+                    0xbdc9811d2e76a1e8,
+                    0x13b0ee4b7cda2608,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc04828578daa57b1,
+                    0x4f7b2f9a1b6a829c,
+                    0x234c6b472de7754c,
+                    0xcf1d040646d71d69,
+                    0xf11595e66035eb3f,
+                    0x060c1a8e8550b509,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4af77fda6e6ddff1,
+                    0x32b632ebd79f7aab,
+                    0xe3d4d61c42f4f8df,
+                    0xad37ed206e35aca0,
+                    0x5e20d4a65c156267,
+                    0x0540217da1f5c0fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x184ea5ddcb490312,
+                    0x186378cba74392cd,
+                    0xdc432a581a3cdb35,
+                    0xcb400085a43977e1,
+                    0x92c265238445f03e,
+                    0x0b4fc10ac3effb52,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16793e63b9722af6,
+                    0x246c6fd2d71c89fb,
+                    0x121f8057aaa46353,
+                    0x2cd264624f8b009b,
+                    0x9b46793c7a0c5f50,
+                    0x13cf7394c04f454e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb08f8b15e1e9f030,
+                    0x5fc985dae187d930,
+                    0xb366ec50683c079e,
+                    0x77affcb540e6e84d,
+                    0xfea74e17b7eca87d,
+                    0x04a4ad88a12a89ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0c90e7a831f79530,
+                    0x4a7879d902e25cf7,
+                    0x4328696e9b632f33,
+                    0x5e7797d70e895ea0,
+                    0xa5da867a575b4a93,
+                    0x0bd2ddee85b44d35,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf39109672bc2e08c,
+                    0x1e6e2672f1fbd073,
+                    0x9666933b74fed09d,
+                    0x28fa8aca8e79d6f5,
+                    0x19d043cf2917b693,
+                    0x090ae974f2213420,
+                ])),
+                Felt::new(BigInteger384([
+                    0xec59cd4ca5fef136,
+                    0xb4feee199d05a20c,
+                    0x8d7736374a9859ce,
+                    0x5137248763a069fb,
+                    0xe0d7330a12b60af9,
+                    0x02aabc4a0caab990,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x695110b524c541d3,
+                    0xfd7d7eebb7f6c9bd,
+                    0x224acfe3217e1c83,
+                    0xb57d0bbee0fc0e33,
+                    0xb0f585d47c8d71ac,
+                    0x0905df6ef13208a4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa13af6afe9940d3,
+                    0x6bbc570fab8ebd30,
+                    0x65448e41eb8f1d2e,
+                    0xf8ee03fea9880693,
+                    0x0d2602a1c6cedc9b,
+                    0x0da79cea995e9e84,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ec376fdb0c954aa,
+                    0xe038a8fb5c9ff242,
+                    0xf8d216a53acfe5b5,
+                    0x1950c6e982541f1b,
+                    0xed584f4b00744973,
+                    0x08f23867e95d995d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb546143ac97d2ebe,
+                    0xb53ad869b0bd30e2,
+                    0xb7adf55a87b44e4d,
+                    0x5d04c456ae81518b,
+                    0x592033d30c1c2af6,
+                    0x0b8e926bb5573193,
+                ])),
+                Felt::new(BigInteger384([
+                    0x166c9f8ef692d61a,
+                    0x699085ac80489e24,
+                    0x6a07a673d5a4fb00,
+                    0x112ad05836e6d9e7,
+                    0xcc5e0118f17e0f5e,
+                    0x0e7a2e11b2ecf9f2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xce7985c1e97f9c3b,
+                    0xc6e047045ab33394,
+                    0x782cce1c8b1a34b5,
+                    0x841fd88c0ba72532,
+                    0x43d1231315f64c9a,
+                    0x020a1cd0afd99859,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3c44687a1c1fc619,
+                    0x6deb826585859c35,
+                    0xe6ee565262e8c2f4,
+                    0xc95250901ef19eb1,
+                    0x34cc3e9acc34c51e,
+                    0x157eb2a90c6ff573,
+                ])),
+                Felt::new(BigInteger384([
+                    0x62f31c2f476b7833,
+                    0x761e3034b9dbef09,
+                    0xda5c3716ad2591ad,
+                    0xa324324cf12083f9,
+                    0x08dda6782fd6215a,
+                    0x0794907305266fc1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27237b1617e8a850,
+                    0xce41b0f45f67563c,
+                    0xb965e607d598a45b,
+                    0xf9227ebb8f021513,
+                    0x1e9fc4af2794ec87,
+                    0x05a9f252f7a69b03,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf04702861a846543,
+                    0x65494bcceae098ec,
+                    0x12144c55206a11d8,
+                    0x20ac3eea287e6a69,
+                    0x21e3f659f95153aa,
+                    0x10db8dbf4b188597,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbc20a3c3bb6d465f,
+                    0xf815366ef9922eea,
+                    0x250e50e69f064380,
+                    0x160081fd99c309a3,
+                    0xbef41d73ab3ac590,
+                    0x061995a0d83420eb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98e7b6cb9ca806e8,
+                    0xed08377449b77a3b,
+                    0x627fcecd4f14861e,
+                    0x6426ffdedb7c05f5,
+                    0x3c084aa1c6bbc216,
+                    0x1686d80701beaced,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x8340a99438f4879e,
+                    0xb3d5fc9702f09e87,
+                    0x1793c58fa00477d4,
+                    0xb1fcd7220e8f4e79,
+                    0x044484bdc51a8dd9,
+                    0x04e7635207900254,
+                ])),
+                Felt::new(BigInteger384([
+                    0x65f93f01531d501b,
+                    0x49b17da099044d08,
+                    0x0ff0369c3e139336,
+                    0x629e2552464250c2,
+                    0x89ce1747b9cf23e0,
+                    0x00cf043fbe94e385,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93e6eb7cdc952f5d,
+                    0xaa694112249222e0,
+                    0x3d984822683a2b84,
+                    0x34e6651bf244426e,
+                    0x0d801f862ac6c76f,
+                    0x08c8971d23636593,
+                ])),
+                Felt::new(BigInteger384([
+                    0x23704ae313e0e0e1,
+                    0x6d4480d3dc7c50c4,
+                    0xa06f2bd888187028,
+                    0x6a59ea762e9d3709,
+                    0x5fe65bbff6c59fe8,
+                    0x091d4d184867e1c3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xce5182f281da65b7,
+                    0x14e0cdc1e0a131de,
+                    0x1444b89de8648e21,
+                    0x6713e13d2264ebe5,
+                    0x98ba0a97ecc9f470,
+                    0x16c9a31f43710a31,
+                ])),
+                Felt::new(BigInteger384([
+                    0x18d505cb39908cfd,
+                    0x9c93a7922d4810e8,
+                    0x52311c8ac750525f,
+                    0xbf13455a1905290e,
+                    0x01c3152185640bff,
+                    0x192d2f912f0c8277,
+                ])),
+                Felt::new(BigInteger384([
+                    0x19580acee9552bad,
+                    0xb86bda4c4b53d057,
+                    0xdbaf7199c0a37da4,
+                    0x87bb204425ec7a9e,
+                    0x180c6271b0b03345,
+                    0x113d0537cf78f0af,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0eb12056e8124fa4,
+                    0xafd722680f9e112c,
+                    0x621c6396153d138b,
+                    0x9abc2cb7acec08a5,
+                    0x2835b460cead9db5,
+                    0x19bf03bb555e517d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8b372adcaf51e8dc,
+                    0x6e495a6682a81624,
+                    0x990cdedb7e88a322,
+                    0x9cc0e748211ec7ac,
+                    0x25c525ea654ef79b,
+                    0x0d80ab81ba88ef30,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa4b25e6d0bf38537,
+                    0x472dbd094fc9ca69,
+                    0xd0154573100f2604,
+                    0x28f9c25dd3a3ac02,
+                    0xd3d17d32da20bcea,
+                    0x14b0868fb0366c09,
+                ])),
+                Felt::new(BigInteger384([
+                    0x30f1cc469ef873ed,
+                    0x489b85d32bfa599e,
+                    0xd020f97face67830,
+                    0xefe2faf14fd50dff,
+                    0xdd93752e31f7c2aa,
+                    0x0bb9b23d711863e7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55b5ce5ca96c92d2,
+                    0xd598567e6e004fc8,
+                    0x71927105196c66e0,
+                    0xab51ed2035198a47,
+                    0xcf8b4e48ebe7dbac,
+                    0x0aa0149015e0b99d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xf7fa434939fefb4a,
+                    0x32cfdd6783d2fab5,
+                    0xec03e28208b6f87a,
+                    0x88fc0373838a18cf,
+                    0x8f49766383753e20,
+                    0x19570388ded8c07b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xacfa319d198de087,
+                    0x1a39e3a1d7f39a5b,
+                    0xe8cb03240bc9995a,
+                    0x1cc639720d293f49,
+                    0xe94d0b7cb62fc6a0,
+                    0x0c25d14936df38a6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4965e6a59b6e4bbe,
+                    0xb9f2a9902f1f7f51,
+                    0x99c10291db24836c,
+                    0x9e03d60a5e14bd0c,
+                    0xb659981d8f4663ba,
+                    0x0200f771bbd94b1d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9829f33b9db2b967,
+                    0x976f40ac3b180f2c,
+                    0xc9398b764fe2567d,
+                    0x7fd7f1b79b434adb,
+                    0xdb4c89ee6de6fc61,
+                    0x0a0197c5b4051f42,
+                ])),
+                Felt::new(BigInteger384([
+                    0x50a5cb85035cd101,
+                    0x56dd9c44099f1e9c,
+                    0x1e8490c740604598,
+                    0x3c2f8f8cc531f9d8,
+                    0x20ae06049212200e,
+                    0x04fc30827436d4ee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x25b819174ff6a337,
+                    0xb3cf02834751e3ce,
+                    0x3fd6c9c65c1e3d00,
+                    0xb0a5734376148b2b,
+                    0x3b2f99c7e0abeefb,
+                    0x01eb76a40d1fb84c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x995642f2d1994a3e,
+                    0xa41358d44eeec30d,
+                    0x69a8e0bb994574cd,
+                    0x7bb43ca53d9d2146,
+                    0xd3b66d3daa0994e2,
+                    0x12600fd46be2b41d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x551db5e6aef32553,
+                    0x24d39a291276d834,
+                    0x1d97814c0a4eb9c4,
+                    0x9af0cafbcda4f24a,
+                    0x9c7d435b82bdcfe8,
+                    0x191974b79d283d01,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca42c9e40ea599d0,
+                    0x14cd966cc9f8fcd4,
+                    0x245c4bdb6a869dd1,
+                    0x88173330b80bd537,
+                    0x18fa44d2cd87f148,
+                    0x157d7aee8a5c327c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f2d41f02443fac8,
+                    0x57d5309cb6c073c1,
+                    0x9c2f788dad6aa894,
+                    0x7bf7e4ad0ec23b71,
+                    0x08433fc97ac1f874,
+                    0x034fd550cd9b756b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0da90cb2a4f05fca,
+                    0x1eeac513c1df1fa5,
+                    0x704025e66eb4512d,
+                    0x78cfc2d103639e77,
+                    0xdc91f5346ff598af,
+                    0x01afaf3490709d97,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdde354c487f360ff,
+                    0xba0c4dd946ffd70e,
+                    0xd036a5cd735c0580,
+                    0xeea0bb4de8dff983,
+                    0x54637f9e6280acac,
+                    0x0d8916f545d71255,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x6f7d4f6d56d4023d,
+                    0x457a5034fccf374a,
+                    0x5c17d0d2beb28636,
+                    0x15411ac4dfae032b,
+                    0x6f6d2272602cf1b8,
+                    0x0f4108dc8114aa4b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x84402317df1b58fa,
+                    0x1d7c12989f1abbca,
+                    0x9a134cecd284654e,
+                    0x03284fa8aa7a259e,
+                    0x3a7dfba624a7e28b,
+                    0x178c8986cf0c14dd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7137990e961ce15b,
+                    0xb736dea57e0d3ba5,
+                    0xe9882454db5e77f4,
+                    0xb5bada7daecf04bb,
+                    0x72e30f880e00254e,
+                    0x03dcfeed718e9f29,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0b45c50be9479882,
+                    0x1220733562c9476e,
+                    0x9dd1b5169f8641f3,
+                    0x1784daad62cc99f9,
+                    0xd55a524500f91063,
+                    0x152bb850d72298a2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb217efe75f7ef2ab,
+                    0x95bdd5772e917c3a,
+                    0x64ac05a75b83a0f3,
+                    0x12e447bfc2eed89e,
+                    0x0e28e7b8772f2072,
+                    0x0bf9677e5d09d702,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf39b9004d3543592,
+                    0xd912617e77baad72,
+                    0xb228225e0c9c67ca,
+                    0x0ba98f47f469dbab,
+                    0xc19e7732a422ab77,
+                    0x0dda293a8a15b039,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5d5972de1dcb8c95,
+                    0x67f360466b039375,
+                    0xa225fc46088e5c03,
+                    0xbdfed61349411e5f,
+                    0xdabf463fe3af9403,
+                    0x0e75e95c5b142007,
+                ])),
+                Felt::new(BigInteger384([
+                    0x485af01109a0913b,
+                    0xe81dbd2684ad35ba,
+                    0x67dcca80c705d7bc,
+                    0x9d84940e0d169da1,
+                    0x9e09ac1c9dbd7dfe,
+                    0x092c00d8d1a14fd3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7789ec8736d4d018,
+                    0xf0326f3faf01802b,
+                    0xa9e3f818768d30f3,
+                    0x2c2b7761ed1ccb59,
+                    0xd707146ac883891a,
+                    0x0ca78e2583127696,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5fdf14d2f62f3886,
+                    0x2f38ebf3e5feb36e,
+                    0x913cef07f675166b,
+                    0xf907de307e37b1f1,
+                    0xdeb3fcd368f1b5ce,
+                    0x12b10be7e792b370,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc8135960a3bc96a8,
+                    0x2e22a7431440a682,
+                    0xa44de2299de26597,
+                    0x4e9f3fcd780cf002,
+                    0x93507978a7b148f4,
+                    0x18eb79a825a7605c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdbfd37a3c1da24b4,
+                    0x71eee3070fd91317,
+                    0xad0b185b82464c6f,
+                    0x4ae30e5f2ac9b545,
+                    0xcca5c1e60a5cf403,
+                    0x01d0fa1634f9f183,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfeafde65537ecf85,
+                    0x315ee1e1077381b2,
+                    0x59b6a1434644bc9f,
+                    0x876cfcfc7a88a698,
+                    0x100f7f9d91a3359b,
+                    0x197118f581b5f03e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfa2efbee46ff5bc3,
+                    0x77e0cafe97cc44de,
+                    0x98e8dd8a0c8edddf,
+                    0x4caf9cd98901ef66,
+                    0xb0d646ee59ead243,
+                    0x07382606dc2a1b16,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7af898f29f2dce97,
+                    0x88019fbb9c85c8f5,
+                    0x217842beecc34059,
+                    0x6587475353712bcc,
+                    0x8e5b0819aa47fb72,
+                    0x0e0f588797716496,
+                ])),
+                Felt::new(BigInteger384([
+                    0x10b4ba071cc6dc30,
+                    0xd6566917f8f402b0,
+                    0xd4669af997e7750f,
+                    0x3a72b060759985ee,
+                    0x20b9ef6702621015,
+                    0x09962109df2f0282,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf895aef2df741779,
+                    0x02ff3c5945939bca,
+                    0xa53698af2acab4dd,
+                    0x41f5972bf122305c,
+                    0x23fed3fa537ea063,
+                    0x154c7f79e3ada9c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5fe6f769311e2160,
+                    0xcb7cecacc5fad16b,
+                    0x8ee437c91cf26bd7,
+                    0xa03cfc3e9ac45c41,
+                    0x1dfb1c8a9c8cc7b8,
+                    0x01ad956324c1411b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd79474ad8c271800,
+                    0x6d287f30643cfcc6,
+                    0xf31846ab94eac994,
+                    0xd6fa9d4dc47532d9,
+                    0x98c8b5581f908d88,
+                    0x05e70bac00364983,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa3ea276a3e10b6e,
+                    0x57cebb8ef0b58eae,
+                    0x2c21877f8cddbe6a,
+                    0xbc673971dd1bdbc5,
+                    0x81cb3ed7cf37c6bf,
+                    0x03b913400b626562,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 470, 471, 1415] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 470];
+        let mut opad = vec![0x5cu8; 470];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,3260 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 576 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 10 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 10;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 13 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 13;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xcea7c0b7544915d8,
+                    0x967ca92e315f06e6,
+                    0x55af3fc02d1e0af6,
+                    0x807bbc4ba346232e,
+                    0xa63ece12e0ea589c,
+                    0x05e5bae64729b2b1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x51c1a58e3b0865a3,
+                    0x9461e3b4ca95d167,
+                    0x27a2d41d30692f48,
+                    0xd1fe67c0a3edcab9,
+                    0xe6059e2bbf830964,
+                    0x10fa027365e3df2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfa3036558f935c4b,
+                    0x2c9369b322c37118,
+                    0x364288337023a851,
+                    0x39162d5284649b19,
+                    0xd076e0ca969bd86a,
+                    0x039ae2cebc4ce159,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7ecd62f3d322800d,
+                    0xaa97a46589d40fa1,
+                    0xcf04876ca66f3044,
+                    0xd24073b32a0e00bd,
+                    0xef037ec56edfc14a,
+                    0x168ce0fb607b5a51,
+                ])),
+                Felt::new(BigInteger384([
+                    0xec230a98a593677f,
+                    0xf0e8997a85623e08,
+                    0xd3360fe12913e434,
+                    0x2586370af4363d58,
+                    0x81c29c222133d5af,
+                    0x0bc3060563d2f542,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa3ecc8d0975efe10,
+                    0xb348a4d91c2d9cea,
+                    0x83580abde92c6f60,
+                    0x49154c4c1117fe57,
+                    0x0a708447c7f92f53,
+                    0x17f62d0ee7a2fdcc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb7cf88098697c893,
+                    0x0d075d4adc099762,
+                    0xc6a7500034a0cc85,
+                    0x3c2ac2c2c34617ff,
+                    0x196cbabe37aaf2d8,
+                    0x07ff4c98d771178f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x945f9ec10c76af9f,
+                    0x23e91e0de1595cb6,
+                    0x0e154ebb28b6b20d,
+                    0x95f2813a033e7b8a,
+                    0x052df406c2ecac1f,
+                    0x13b8254528643570,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe292286efd9db396,
+                    0xa5fec25c3881cb93,
+                    0x9ac1b5a397e5b9ad,
+                    0x6e7e02bd82b38253,
+                    0x8fb5b3d26c517b46,
+                    0x08107e73e2920223,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44a447177ea490cc,
+                    0xfac286d88850a0a6,
+                    0x85a6dbd01d287b4b,
+                    0x03f6270a9e7c7db4,
+                    0xf96db5d78d30c090,
+                    0x190a19cf83ddb8a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x200bc12a3761b839,
+                    0xd70a0749876abf2d,
+                    0x1822ff13121a354b,
+                    0xe8d6308c888a317a,
+                    0x054ec9d5c565b94b,
+                    0x0df058f6c796eb1a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x65f62ec0c11373f2,
+                    0xb74012c452dbece4,
+                    0x0900468f9d1af726,
+                    0xe2ab2fba742439dc,
+                    0xcffb8c913bf0a65b,
+                    0x14b722a963a7e17e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x533495c8b9cee5bb,
+                    0x3cb37b703c6580df,
+                    0xc7a5a360aedbc751,
+                    0x6dadad9f2b621430,
+                    0xc714835323d91dab,
+                    0x0ef7cd8174ea596b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c1ccf6f3978fb3c,
+                    0x63999dcc3177026e,
+                    0x7af5618b39e67e76,
+                    0xd81306f26a08d8da,
+                    0x289871477e0b7f8c,
+                    0x099267e445550d93,
+                ])),
+                Felt::new(BigInteger384([
+                    0x723f435ce709e3ac,
+                    0x2d2cdbc2b8794f0e,
+                    0x082e284ddec9cfaa,
+                    0x9d6a8ee46f686b13,
+                    0x85ad6869f521e18b,
+                    0x17103868ca9ff2ea,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf56f1149351685c8,
+                    0xdf0adfb01c995c54,
+                    0x85a495208d2abb28,
+                    0xbff5e70b691b6f84,
+                    0xb2223aeb86805ff8,
+                    0x0ec630007c917a14,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd2384df274430584,
+                    0xd9b06d3e90632e56,
+                    0x5ee37ae96cc46590,
+                    0x10e1746ce67c97b7,
+                    0xc22b1d0463951e0d,
+                    0x0dd764639c936be8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8e35c24f8c244bd4,
+                    0xb0518c52ec90bdf0,
+                    0x92e9696d6caaaf7d,
+                    0x30e44b4e9ed967d2,
+                    0x31cc03e6f4228a02,
+                    0x0d07cd8525d348d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93659be88cb3a904,
+                    0xb9ff5c15090e1874,
+                    0x04eede2d76f08835,
+                    0x194fceb2e45965a7,
+                    0x149bae8d65b84f25,
+                    0x1725f65a34cd063d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1a48d02f0fa33bea,
+                    0xda48726dad1b496d,
+                    0xb2fb857b7b6ed143,
+                    0x30a11bebc421c1f0,
+                    0x9d912b7786763e48,
+                    0x0a47192fc1f12262,
+                ])),
+                Felt::new(BigInteger384([
+                    0x51ca792e52530238,
+                    0xbd52a3d432d64ae0,
+                    0xf003b17b1c864f92,
+                    0x1609fec72305137f,
+                    0x914096eddbe92983,
+                    0x0d98eb1e870ca034,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdc9bab34de978405,
+                    0xda9fe41c3fb91324,
+                    0xecd6019ba0619bfa,
+                    0x6f38259252ae5be0,
+                    0x1255d47833e8a3c3,
+                    0x117b946c41093ec9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4e73f8340b0aa9cb,
+                    0x8ffd0655ce5f5901,
+                    0xf5a682e43f5bb661,
+                    0xf36030cdf43c2d58,
+                    0xe0c71c4fc1dd52a5,
+                    0x13b28d5eb7dfd675,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2e07dd9f25bb4d38,
+                    0x95a9cdcd94035795,
+                    0x2da0fcc60dfa2cb0,
+                    0xdf86c0d96f9cef80,
+                    0x44abfcec992eb972,
+                    0x0d036bd293bf50d6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x79b2fc3e4ee8cab8,
+                    0x696a221528f725e4,
+                    0xc50c768102e415d5,
+                    0xef0c2b4421d6050b,
+                    0x179314f339f6df2d,
+                    0x0a005bc4fd8c5b33,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ecca4aee6bb2f77,
+                    0x418b669022a8c633,
+                    0xbb243908a949bf79,
+                    0x91cf0f92e4333ba5,
+                    0x8d15aff820458bb5,
+                    0x08c7ec8ddee65175,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1ef799fdd86c0ffa,
+                    0xbd275590f1fe1814,
+                    0xf3c81538c0122c1d,
+                    0xcb995030111f4de9,
+                    0x6d96d9eb8ba73a46,
+                    0x0169338826aed92f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x024daa9a917de4f4,
+                    0xd8dcd1fed6d3ddcb,
+                    0x279298731cb433fe,
+                    0xa868913ceac88885,
+                    0x5a4d02821bb8ba21,
+                    0x152f5a70d12eff8f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae5f0565a4da756b,
+                    0x7045bf94cd443a8a,
+                    0xe1a0f33981d4ed90,
+                    0xcb99e4225a36dc39,
+                    0xec2abc82b538bb3f,
+                    0x1966c28482e3b3ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x00a004483d8ba3eb,
+                    0x4fcbfd028c4d1845,
+                    0x6c5d29122c72f0ea,
+                    0xe7fda7ec01b82a9d,
+                    0x31ce71abe4d1b74e,
+                    0x18916255c51d09ed,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9048f90f3b844677,
+                    0xd02981fd6878faa3,
+                    0xc19636ca622e96ec,
+                    0x3dbfd8a4c295db4e,
+                    0x02a9a7f64aa9dfd8,
+                    0x184a2b870e195b1a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x177f6b81367042ea,
+                    0x5faa996f9a96af96,
+                    0xb5af47f4e1ee27c6,
+                    0x2e3b18af27068d87,
+                    0x94fcceab0da30a7b,
+                    0x13dff3b682d9e9cb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7ebc217ed009704a,
+                    0x849238ee3872a7a2,
+                    0xd1b51875b9f804e5,
+                    0xf3378c7a576490e2,
+                    0x3c4a325523430b3d,
+                    0x16c3fdf4240c92b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d82912d6d658107,
+                    0x5f95576f4b60f03b,
+                    0xf5300c895807b5ef,
+                    0x8096b541ecdaecbe,
+                    0x2c2bb8891b1ed4c4,
+                    0x039750da275b004b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc837cbdc364b1a0c,
+                    0xf72b216e66403403,
+                    0x837952cc622540fd,
+                    0x6eb89c47edf20b8b,
+                    0x4e1d998a3adeb1fd,
+                    0x09329548a6ed1ea4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe55cbd7278db0093,
+                    0xc7236a105c542578,
+                    0x4465b45ffd1979f4,
+                    0x1461de1b1baf79a8,
+                    0x8aeefd9860e94589,
+                    0x1115d094ec4bc239,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x26c71d59d0317bfd,
+                    0x131d03a2e418da90,
+                    0x0d8307cb89c1731a,
+                    0xa6f43a8fc3e61762,
+                    0xa04c121d39ad6d48,
+                    0x09c6d204adc153e7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x60102dd7be5ac5ce,
+                    0x5db35cbb75c09351,
+                    0x536d8a90559c76b5,
+                    0x847be8c76276f1c6,
+                    0xf5c16fc251ee54e6,
+                    0x08d80f1529db2b83,
+                ])),
+                Felt::new(BigInteger384([
+                    0x844e7e902de8936e,
+                    0xbb1b847c47202ab4,
+                    0x5373880a9c27f792,
+                    0xe8ee5d63d633c94d,
+                    0xd90cc68a2db351c8,
+                    0x001d2c0186e8901c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb0a7dacd7a9d62f,
+                    0x5e762943de61ed92,
+                    0x49f8babdabefa8bc,
+                    0x5b151ae03662dc8e,
+                    0xffdea404a0e74298,
+                    0x1553f7cad9d3dbbe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8fed8517dcbfc4e9,
+                    0x89e257aa83fbb007,
+                    0xab038acedb938a1a,
+                    0x618db7592561ff92,
+                    0x1c8bff0e756110e0,
+                    0x0441aa29497013a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x87796945455eeb26,
+                    0x58db8ba415d71c80,
+                    0x2c44bc7382406a76,
+                    0xe49ee03e553489fa,
+                    0x94309b4847c90f92,
+                    0x13f6acaaeb97ff65,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe3cce5272a3b09f7,
+                    0x3999c20a836314a0,
+                    0x3254676008dc2be9,
+                    0x985ea9ec57f92f33,
+                    0x3e0731b6219db9d0,
+                    0x05a4715bd86feee6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe9be9a1cac2d9a60,
+                    0x29452ba218d77321,
+                    0x6d8cde1b73700dc5,
+                    0x94b9cfdaa603ecda,
+                    0x096baf0d8efad9a5,
+                    0x11a4c3ce01c8c5ea,
+                ])),
+                Felt::new(BigInteger384([
+                    0x65e48e2cd6e1e567,
+                    0x0e23fcfabbadffa4,
+                    0xccc997e73626d1a3,
+                    0x4da746241252aab8,
+                    0xc8d103d80c9e20d0,
+                    0x0382cb1e72cd2376,
+                ])),
+                Felt::new(BigInteger384([
+                    0xac31e714f9775eb7,
+                    0x19babdeeec8eaaab,
+                    0xa8e93d7287c23a88,
+                    0x6abc8b4151d6a7c3,
+                    0x4014ee6cc718b93c,
+                    0x087eaecd4e661f5c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe5ad9d9658465856,
+                    0x61e3b6c2806ec20a,
+                    0x71697104571f15bf,
+                    0x900d41bb54c0ac59,
+                    0x4e9d83da0eb11767,
+                    0x0e945d399f9363cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ac4e3a215ff25f1,
+                    0x1c849e146c791299,
+                    0xe226fa866c068c94,
+                    0x6bf5459a12d2148a,
+                    0xfb8e96ba826185d0,
+                    0x0bcf2fdd92f38713,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x24aa3beee4e00fbb,
+                    0x24fd53b81c9033e3,
+                    0x88a976ee754cc096,
+                    0xfb9437f5f5b88c01,
+                    0xb3b8f84a7702fae8,
+                    0x117945662dbde9cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9e461d72f7f34792,
+                    0x550ccd26cf071abc,
+                    0xe3ca0d8fe9049dc1,
+                    0x4048c16353479b2c,
+                    0xe0c39b1b583a8de6,
+                    0x117b857fa1d8a422,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb9b75cdd631d0f5,
+                    0x68c643aba82f274e,
+                    0x2583ea198cdfae68,
+                    0x0544821ff6be4bbd,
+                    0xcac0dd386751ef39,
+                    0x11478172b56785a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf79588d1770248f1,
+                    0xe3909c56f09f665d,
+                    0xdd0f6b3486989b9c,
+                    0x7ba3d92d9d41c9be,
+                    0x38e3bdded370203e,
+                    0x012dce3305748b54,
+                ])),
+                Felt::new(BigInteger384([
+                    0x089df693c70ff7e1,
+                    0xbafe3b9dc400273c,
+                    0xdf61a92e89870208,
+                    0x83ca12d4721a98b3,
+                    0xe65c2a062cd23a47,
+                    0x0680d48493d72f48,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3745803e4971b9ec,
+                    0x2e1a80dd822433bf,
+                    0x7e5d32b08f07f366,
+                    0xe9950eebaae3e8d5,
+                    0x14fc24e6f7a588d2,
+                    0x13712134a31de436,
+                ])),
+                Felt::new(BigInteger384([
+                    0x78699bac7faf5a5b,
+                    0xd19dd1a90fcad3f6,
+                    0x9a453607dbb0c38d,
+                    0x3e12afa9a4ae0d38,
+                    0xd66d6f853b47fdf3,
+                    0x17a94ddffe5ffdf5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x62ac61c35fd833ea,
+                    0x8673f1f1ea06a5f0,
+                    0x91b00130c2d9fd5c,
+                    0x052c509f40b4b5c0,
+                    0xb42cfecf431f2c87,
+                    0x0be0be1f6aaf8a58,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c15cdab723084cc,
+                    0xfc2ec719f2603d70,
+                    0x7866104560acbac0,
+                    0x03c1085f3fb9ff35,
+                    0xab61ce9b5b4a617a,
+                    0x143d28114acb2e06,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa26b69e30a01864d,
+                    0x521188e8f1fc5094,
+                    0x9697764bc8cc02d6,
+                    0xdbe853fb3d23d22e,
+                    0x1a1be4cc323c1397,
+                    0x0dd23584dfa29246,
+                ])),
+                Felt::new(BigInteger384([
+                    0x15f0b410ab2558c4,
+                    0xd7e0bc745aa52633,
+                    0xf823bb52b5dc72ea,
+                    0x273919c22b037ec9,
+                    0x8e37d41b7f293259,
+                    0x0c5aef982f0b4cda,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9736550ae10f94df,
+                    0x6cbd1fb5321049b4,
+                    0xf2a510a1c6490597,
+                    0x924356ec0059a9d1,
+                    0xc22ea9e16d20176c,
+                    0x0b370061b238fb40,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf8c4e2cfa4af6403,
+                    0xddc473ae7c1c9c30,
+                    0xcd252bda5fd2a811,
+                    0x735d9c9c8f7e56eb,
+                    0xf3c9b6f36021e3ef,
+                    0x1859739ced35dd55,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5085e51fef46605b,
+                    0x91baa9d3aef191cc,
+                    0x55d5939a42c83dc7,
+                    0x8bd859b9e6c614ad,
+                    0xab07b2b2b57c04bc,
+                    0x0c8112900521e63d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe29ccd7bdd9fbb7c,
+                    0x1e6de39d4e39176c,
+                    0xf1996b5d334bee18,
+                    0xf79d5666d87c7f8c,
+                    0x12ef8293be3819a1,
+                    0x122b4a5f9bdd90b1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe654639bb7b6ca77,
+                    0x0d229a00c40be80c,
+                    0xb334546716047016,
+                    0xc8aa84c02135d2d3,
+                    0x263c0003676a0e03,
+                    0x13f3998c29da1e91,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc6a4ee4969f22b5b,
+                    0xc1635593f8b46ecf,
+                    0x961998e134501f34,
+                    0x8835812c589da5c0,
+                    0xb4e714f78c280e96,
+                    0x06df2a0f90368906,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4250f60a794e97ee,
+                    0x30481e1152a644fd,
+                    0x616f874e344534a6,
+                    0x9c597a1afeafb00a,
+                    0xba21de3139927c55,
+                    0x03ca421fa52ff005,
+                ])),
+                Felt::new(BigInteger384([
+                    0x51e38b1e5e3a61c3,
+                    0xa53de2513fbbae4a,
+                    0x4a5a106183800fb4,
+                    0x06e880ad557f88a9,
+                    0x0f2fc14194b63217,
+                    0x0cd58bb6a5b1891e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77dca7d929af836a,
+                    0x6c9a30d138cb03ca,
+                    0x8c88e060fd459edf,
+                    0x53d7e65d13f1b4db,
+                    0xf7205d73e13e4301,
+                    0x051c8832d54efd63,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6488f91eba6c933f,
+                    0x11483bb5bb2befad,
+                    0x54624afee806c173,
+                    0xaeda15a7515d3c54,
+                    0x88f1c55f35878a9d,
+                    0x0471f0bce267e54c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x619ccc5c92103cda,
+                    0xe116731bf03d2b30,
+                    0x7b33fc49061d5311,
+                    0xa705c36727c460c4,
+                    0xd8eb304208af07b6,
+                    0x0039e95a56c46569,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca586fe52977d500,
+                    0x9e7c224aeb48cb4d,
+                    0x8db2a81e857f8e54,
+                    0xfbe1aa0e4ecc2a5c,
+                    0x321be1b5af578b60,
+                    0x091faeaeb08db65b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf991615aa0f25a08,
+                    0xb3851fbeffd7c626,
+                    0x41b5edb7ad12887b,
+                    0x363d18d9e73f2eac,
+                    0xf92db85f280417b4,
+                    0x16dbb5d7e3490e47,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xcf9014ac181c1850,
+                    0x1dee255e6bb5ac2f,
+                    0x9836a3c90ea720f1,
+                    0x04dc5880fe38e374,
+                    0xd087257fe4c102b2,
+                    0x00f96a03ddd02645,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9a54e9baf1f382d7,
+                    0xd2dd67ece498a13c,
+                    0xf52e7e9118749d53,
+                    0x7872ed77eafcb57b,
+                    0x72a198a636fc511e,
+                    0x060f9fa1efc97024,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0a91fd0526de93a0,
+                    0xc1c3193cb16f1577,
+                    0xd2300ad41f0d484b,
+                    0x5de1363592d98614,
+                    0x08723078f5f44a64,
+                    0x0c0c819fbbfc5294,
+                ])),
+                Felt::new(BigInteger384([
+                    0x878c1957aecc3770,
+                    0xa7cb6b587c65fcde,
+                    0x4c771b042c18e148,
+                    0xb4718ecdc77ca286,
+                    0x75a88bfcff010f4f,
+                    0x14f7840fe0a4028e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x05c2654f0c62e423,
+                    0x1deb2b6f31121713,
+                    0xf79001805cba0d0a,
+                    0x91298a8c6115d0b4,
+                    0x5dd36e8a02d932ee,
+                    0x10602fae8669c1b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c65a39ce4e40548,
+                    0x7ca8f85560542714,
+                    0x4f98ee917eea8960,
+                    0xdd602c6c78870ebb,
+                    0x846fe066b1b2c952,
+                    0x16e6e2038d05c525,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2daab543c5da310f,
+                    0x67720417494f07d2,
+                    0xb901b38d31032de9,
+                    0x57d25aa5e1fb119e,
+                    0x2066989f4897412d,
+                    0x0ce1d9a31f760344,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeb8f45fe69f047fe,
+                    0xc3ee0d9913369a6e,
+                    0xbed92b16127af7e7,
+                    0x907f83113c024f87,
+                    0x4d2cf14fec5a7b93,
+                    0x1756c9c12db217f0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf8acc7cae9cbb57,
+                    0xc6d6ad9ecdcfdcef,
+                    0x90cdd27a62ec9de7,
+                    0xe1bb2ff96bcdc3c8,
+                    0x7cf217cad9466b20,
+                    0x12a56db255b5cab9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f2b75d7297b8186,
+                    0x429d5455209c9d83,
+                    0x5fb7c710643eb2db,
+                    0xdf946137f41c913a,
+                    0x8d81cdcd9e354c09,
+                    0x01a0fd2f9e8d7bb6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5905f04672a3e705,
+                    0x8ade6718bd5dab29,
+                    0x5044c39a9cdeb300,
+                    0xe2266a13484a6b65,
+                    0x8effe9fbe87855e9,
+                    0x01791130f22ed861,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb87ad0a58800b4e1,
+                    0x3b1f72f55938be94,
+                    0x048c17704ec10905,
+                    0x25f7a8f9bac54fc9,
+                    0x662b48200d1ae282,
+                    0x051c065f78a09d4c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc7477817aa395198,
+                    0x0666b5eaf0c6f0d3,
+                    0x14c5b11c72f10f55,
+                    0x3b51c180e35b8a06,
+                    0xd6b6bebb297bcb9f,
+                    0x06d5c3a6e152e5cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb17124a013c7cc3a,
+                    0xae092059a1e4c589,
+                    0x0ceaf8a4cafab5db,
+                    0x419e460e6a7df3e6,
+                    0x4ce8e11463efd0b1,
+                    0x0ca2468595bcffad,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc3f802dcb745ac94,
+                    0x933366c5427738e4,
+                    0x2b5ee5106d2d6830,
+                    0x12c630e2d431ad73,
+                    0xa618d7fccf6a7d73,
+                    0x0c5cde86fdd8b073,
+                ])),
+                Felt::new(BigInteger384([
+                    0x038c553c761bd966,
+                    0x9b237df82a04acde,
+                    0x4022d8b6e877d599,
+                    0xbc6df05d03a0af34,
+                    0x812725c502623285,
+                    0x14c1514e1b7da13e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4f1732abe5771a51,
+                    0xf035adff7d9bdcc1,
+                    0x1f47c9d7928cb27c,
+                    0x01e7b4b164b4ed7f,
+                    0x0d7c9c4d5d1c5ec9,
+                    0x0efbcfb29c85f711,
+                ])),
+                Felt::new(BigInteger384([
+                    0x181e977011f89365,
+                    0x7ae16e26272faa27,
+                    0x1a4894a53a9985e7,
+                    0xfc9e9f6e1ab70953,
+                    0xd6b43b18ce100388,
+                    0x05369658935dd5f7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x771fe5ed35725882,
+                    0x3e0edf243fa179a8,
+                    0xf0824952d3b3d46a,
+                    0x748ed1e869868768,
+                    0x1dd1dfe1cd21eeb0,
+                    0x151ecd1cba156485,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb63f74e6351b9751,
+                    0x500e564b30ff31e5,
+                    0x0b54786d188ad0c0,
+                    0x72764530c6f866a6,
+                    0x459445a457e416ae,
+                    0x1929dd270bc0b926,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7ffcc454f5c5d934,
+                    0xb1d2e7c6a2becd1d,
+                    0x756dcfd20388e9cc,
+                    0x67af7bae276eac63,
+                    0x8015419b2534a0d5,
+                    0x135b99b90eb91389,
+                ])),
+                Felt::new(BigInteger384([
+                    0x00486be17715523a,
+                    0x0d3dd47e660c7449,
+                    0xf4ac77d5ba4a9447,
+                    0x1c889231b335f866,
+                    0x57d43da53be3375f,
+                    0x1963675c69329bd2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaed499ed3a07b0b6,
+                    0x506b7c8144674e82,
+                    0x57e43754f95a28a5,
+                    0x9ad5948cc46fa735,
+                    0x56c34bd1a0530adc,
+                    0x0fc8e58ce87358a2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x532550cc69ac9dae,
+                    0xd0ae2f261ce60335,
+                    0xf99c576d461ae99d,
+                    0x09f9a5adf7aa460a,
+                    0xab96df47738bde04,
+                    0x14695e68b2ba91b5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfd8eda9ffc30e878,
+                    0x801d271f3de1f12a,
+                    0x123d91c236fbac96,
+                    0x18a580b9980b5b5c,
+                    0xff12101427080dc2,
+                    0x03e0419902b968af,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee2a6bf0c1c84695,
+                    0xa9bb6abd4598890d,
+                    0xcf8a58c37c7881d0,
+                    0xf60fbc38775e0f22,
+                    0x5b63c9de921fffbc,
+                    0x09017afc35be6654,
+                ])),
+                Felt::new(BigInteger384([
+                    0x939d75b48f73952e,
+                    0x3a074b86465f52da,
+                    0xebfae537d967ca04,
+                    0x60d4bfc6732e7360,
+                    0xabae6081a66c2294,
+                    0x0d9e0eec4a7e96c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdca97f43160f9024,
+                    0x9d2d7bd00595baf4,
+                    0xf0382ab19a57c176,
+                    0xbf46546221e89e3d,
+                    0x48c253505d0635e7,
+                    0x1907fe69a94024d4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x641fb54ea6fda2eb,
+                    0xca381fa7e819bc8e,
+                    0xfc62f2496d4328a0,
+                    0xd211324249733c31,
+                    0xb918cc5297dfcad4,
+                    0x0217f3d76399bc51,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c416e912be34959,
+                    0x7934cc8cb34d088c,
+                    0xf64f8e0e11dc841d,
+                    0xc2f786d5f48510f0,
+                    0x44dbb93a4d87582a,
+                    0x08cbe66ca438b353,
+                ])),
+                Felt::new(BigInteger384([
+                    0x69f1cda79226b483,
+                    0x9fb1e3f2c3a56683,
+                    0x4b36c47a8cb4d250,
+                    0x007e655bf0cd356e,
+                    0xd299bc08b9c67f05,
+                    0x1996c2822f20549a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0a7595634c5b3cd,
+                    0xabf13d7e6a33fe01,
+                    0x373905dee0ea9d93,
+                    0x77fb94982e2b2d03,
+                    0x3a345d6933dcd3c3,
+                    0x104237989ab97b2a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xedd8c6ba699d8287,
+                    0x7191aff271174481,
+                    0x116e7ea0fe5604f1,
+                    0x5a4ab1f85041aec0,
+                    0x01ba79ffcd96f3a8,
+                    0x0de2095a6e56f440,
+                ])),
+                Felt::new(BigInteger384([
+                    0x157a1ef02773fdc2,
+                    0xb15ae9c282cad578,
+                    0xf1dffa3010f11526,
+                    0x725e17804bc8ccfe,
+                    0xc59750b328db1332,
+                    0x0c02933ce879acae,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdd9f9d5fe943558e,
+                    0x301bfdd9564f01fd,
+                    0xfcc265bbe6b4d8d1,
+                    0xa17bdc31d8fda739,
+                    0xb3a6a5dbccf2d4b4,
+                    0x0386732ee9684e19,
+                ])),
+                Felt::new(BigInteger384([
+                    0x26c236fff11da804,
+                    0xb4a83d105ed52a64,
+                    0xf13a23da4e00c0c4,
+                    0xb18e22aba3dadd2d,
+                    0x3ba87e3723f9b1be,
+                    0x090905f2ec415fc2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd6c3f6cc1d5484f3,
+                    0x1c4f51f431a896ab,
+                    0x79080c4094910090,
+                    0x99ae6c994f3cc4f9,
+                    0x7393d6d513e46564,
+                    0x171956cc00beb7c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x644c246281e74e2e,
+                    0x75d34022bfdfccc1,
+                    0x6bb8e47ebe38de7b,
+                    0xb6d52bb824348475,
+                    0x263619d22971d35b,
+                    0x18fbbf292675a204,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc3bade1ae98c5495,
+                    0x9a7d8546c7017abd,
+                    0xbad6dcc24074b191,
+                    0x692a1e069f62916b,
+                    0x11e1d59db3f9a9ae,
+                    0x0a1f5f92d3d666cb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x582b0ce083ca26c4,
+                    0x1af982e154f08657,
+                    0x6b45f08cf0b18d64,
+                    0xd528bacf21f8181c,
+                    0x49254a592e96b2e7,
+                    0x0ffbf68be74c7f04,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd93d322081cfea2e,
+                    0xce20335b05cd8f20,
+                    0xd00a5754f125c7e6,
+                    0x6ffc8cea3d79db7d,
+                    0xf0e5079b379d2f07,
+                    0x022e7f08e8dd9722,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf5062ada0a221a34,
+                    0x6c81f91d62d37367,
+                    0x90b5c51aef97648b,
+                    0xd62bf89161e3202e,
+                    0xff6525098d8b9e6a,
+                    0x0580fa9989439fe7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8a323e5238f30f9e,
+                    0xd5a8e587a8a5d2e3,
+                    0xd530b9447d322439,
+                    0x1503455b5d4a04fc,
+                    0xec11722dfc83bdf5,
+                    0x0d23fb5f19a8bbe6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c76a8df2ef6ffd0,
+                    0x03fe46c2197cdc9d,
+                    0x65b4b6cc837094aa,
+                    0xfeca6a110de64cab,
+                    0xfa9c0012c7586add,
+                    0x01b3fd7d1a408b7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x007d96b511f22c89,
+                    0x5d6989ef476998e3,
+                    0x176a56f1fa1081a8,
+                    0x8d33fc45186026da,
+                    0x62d21cdf4300b10b,
+                    0x0234a470c59092c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7b791b7d7a403157,
+                    0x870b3faac8f9ce83,
+                    0x1d8ade977734eacb,
+                    0xbc3c3c9d4dcab872,
+                    0x74d231edc6421660,
+                    0x02db07d164a2497e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa599ad9c05a6cafb,
+                    0xa5e930a836022c98,
+                    0xd36af6faf7258f75,
+                    0x76ff4732939cc8c0,
+                    0x9a02cd2fb88c41e4,
+                    0x03f8bef33eae6a84,
+                ])),
+                Felt::new(BigInteger384([
+                    0x403b06a5d23fef6f,
+                    0x6c86ca65c55d6983,
+                    0x99dee7f81a51ee2f,
+                    0xfbfafc5273decc8c,
+                    0x09ef1cb07ea2a69b,
+                    0x03bd0d9a9181dc6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6cf3bd9a970a6db0,
+                    0x5ca2b2604e62d640,
+                    0x7e9586f9a75adc5c,
+                    0x596f1d619ed30430,
+                    0xeeee7d98cee27b2d,
+                    0x0dec7acede0262dd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0fc242b52f634c46,
+                    0x5ce319090f17fd74,
+                    0x980bab19f6a6f874,
+                    0xca59befe0da10f11,
+                    0x77d8fdf21b464f7a,
+                    0x184c0d70a3489fb2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x75546f030725e1f5,
+                    0xf65ab2f868c9270a,
+                    0x30b402c643120330,
+                    0x10ee67b6b0c1851d,
+                    0x1e042c98880a0cb3,
+                    0x0bd0ca74d3f60adc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6222b5f48d9fcc28,
+                    0xd42b603b381c9dd8,
+                    0x9f37f5c894268a6c,
+                    0xccf1ef1957ffdffc,
+                    0x89d754914d6bf809,
+                    0x06ff49f0d5aa7b0d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c7271b79a104752,
+                    0x70442158d5722484,
+                    0x020f59f3ba7c3367,
+                    0x568ef84362fd24a6,
+                    0xa0045f03eb682a28,
+                    0x0d1540ba2bf8057e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x18ee16be94fe9f7f,
+                    0x22a7dca5cc6c73e6,
+                    0xd16cd49b4a024437,
+                    0xf151e061e2aed233,
+                    0x5ff32839c382a9eb,
+                    0x0b84a86e5982f948,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6100607076e98bb3,
+                    0x26e36605468be314,
+                    0x7856882b033b9d33,
+                    0x3b3ced126372a4f8,
+                    0xdb89deb164261d39,
+                    0x195b09814586ef6d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbf91a2e4e944e91f,
+                    0xfc7ebc35063a581b,
+                    0x4b0792973fc6b0a3,
+                    0xe7003dd347dfe413,
+                    0xd312f6baba6da29d,
+                    0x0ba60d733481199a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ee4e9b98ff26bc6,
+                    0x23bd519e109ae770,
+                    0x4b332f825c5e1777,
+                    0xbed9284dbdfc9db5,
+                    0x72f3910369a0e8ab,
+                    0x08d790831c68a231,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13fa5c4104e3d448,
+                    0x06e296944953e4b3,
+                    0x6fce0d513e09c41d,
+                    0xb8fddc5a07b30862,
+                    0x7c995af1152064af,
+                    0x0b0772864c38571f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf5bc978cc889e58b,
+                    0xf84941bf14c3ed16,
+                    0x23096a175a6ca39f,
+                    0xcc2ec958f7ea8435,
+                    0x3fd1b5c2d0289f2d,
+                    0x14a3f59d00161605,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe3fa62fb48e98b86,
+                    0xdac66a8f4469ce46,
+                    0x9532fb840e65694e,
+                    0x108bc9510d5748f5,
+                    0x6949c81bdcf8d2aa,
+                    0x16824f54d3f2953b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xac82cffc5747b767,
+                    0x115a185681843b9b,
+                    0xb655aed76513e77a,
+                    0x719499916e963cc6,
+                    0x2eff436b6df07bea,
+                    0x00cbfd83e413cbf7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4f541f7cf2554271,
+                    0xf4fdb766b28b32ad,
+                    0xe70618dbcaaafba4,
+                    0x129f711169882563,
+                    0x2732427d32e47994,
+                    0x0b8e9a032812ad11,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8be127156c0b367e,
+                    0xbf7ecb44753a579b,
+                    0x13b493f4995cc4d0,
+                    0x064f0afd1e61e653,
+                    0x11edf79bcdc0e20a,
+                    0x1731b436759e5c0f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa337a48804eaa6ba,
+                    0x5489e79cc6770df7,
+                    0x7efe6775efea28c3,
+                    0x9667d549cb9aecb4,
+                    0x0607b176a95f5999,
+                    0x021d121cefbe692a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc4c9bf51209295e0,
+                    0xe13a7e19c8be0b2a,
+                    0x2abff9341754e28f,
+                    0x8493871e712f61df,
+                    0x8ed1b384591e8ed6,
+                    0x01eff1308a24e2b4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa7d9aed07b64873d,
+                    0x6a0795d29d6281d2,
+                    0x6adee81479c7f157,
+                    0x596600b73659278f,
+                    0x5a63be646b4fa3f1,
+                    0x0044bc624101c301,
+                ])),
+                Felt::new(BigInteger384([
+                    0x04cdda8f6114bc6c,
+                    0x4f868e582b8a5d59,
+                    0xfd744b34ede9367d,
+                    0x29b0d392e96d899b,
+                    0xc56f8b45ac775808,
+                    0x0041900cadda3069,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbeb0f12528466da9,
+                    0x1f51ab644f4f71ea,
+                    0x76410cfb62491f22,
+                    0x86233308849f4b0e,
+                    0x870c727f9091cf31,
+                    0x184038c57b45e0db,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6666e6f63aa762ba,
+                    0x2cf57591dd0f8ab0,
+                    0x6146fb36465e3804,
+                    0xa0467a3f9955831e,
+                    0x387b9b9466bc4a5f,
+                    0x0c0e33a6d2ec039a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x348a582c4a66dcac,
+                    0x0116dcf55c5645b9,
+                    0xcce198fc714e0ba9,
+                    0xcd362deccd17a0e1,
+                    0xc31fcfa05157fc34,
+                    0x1139340580f57ec7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf67e971bd4a43c74,
+                    0xeba705cbb88c2f76,
+                    0x6d1282cff3a3a17f,
+                    0x3a25c205a00769d9,
+                    0x1104f3b3bb5c7630,
+                    0x1892a8f8876069a8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x88e6fb6eca8d73af,
+                    0x744e0f5c9ea138e4,
+                    0x0dbc5a47ca9d0144,
+                    0x882b69b8812c11d9,
+                    0x7f64b51a1d09de65,
+                    0x12939fa592f10346,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7e598cbea22f1d10,
+                    0xa357c5a429ebf61c,
+                    0xa440204c79b6ae82,
+                    0x299738a0d1fdb8ac,
+                    0x4c7f8b5533288a46,
+                    0x0e6ea4e67e348990,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52ed071cf608f063,
+                    0x489222a5415066fe,
+                    0xe4680e4d7e791a13,
+                    0x79f9d2b343d885e8,
+                    0x6719e30cadf41752,
+                    0x017d5f5d0fd93c77,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf82e43f7d4f2dc3e,
+                    0xb6b4488b692e0e14,
+                    0x621a6a3307eeb12d,
+                    0x5475172221ddc38b,
+                    0x95549fdfc7800865,
+                    0x0a24f02cdbc5fca2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd4ffa6f78cc22161,
+                    0x9eefc92a98d5e2bf,
+                    0x5996463453f67f34,
+                    0xa8cd9c52ed741952,
+                    0xec9edd7b1557af8d,
+                    0x008428ebbfb2adae,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe8f4c1bb30c15ce0,
+                    0xe9a22fd8faefa7cb,
+                    0x91d010685ef40f85,
+                    0x912d6ebf1d6931e3,
+                    0x7028b0d16c158aef,
+                    0x0d39bc6751187790,
+                ])),
+                Felt::new(BigInteger384([
+                    0xac3c1c42a22471da,
+                    0xacffd571435bc9ab,
+                    0xd3c91cc89cfc3f3a,
+                    0xc1ef9e75f4738be6,
+                    0x2b8c32108a936197,
+                    0x1975bb75efc7e7d5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x929df6c5b942612b,
+                    0x5eb3874df7264508,
+                    0x9e4ece820dd25e8c,
+                    0x8e6d2da44d35705a,
+                    0xf3e737b2ebd17638,
+                    0x067bf8cdbc60f1d1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x46e919987b0a36b7,
+                    0xd14f225360950e19,
+                    0x1ed24132c69af701,
+                    0x25c93e3ea273b735,
+                    0x7f97458e370974df,
+                    0x0dec00377eaa4019,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27e7ae0bdd238cfb,
+                    0xf0a917179b476ec7,
+                    0x676ebeaaf28333d4,
+                    0x925625e701454d47,
+                    0x8d40316974742a89,
+                    0x039d9bc22e1e0773,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6330c7f66ecd2125,
+                    0x44f0adaad59cc7d1,
+                    0xd93beff9f59174c0,
+                    0x784467091f519b07,
+                    0x3b5e62f723842e4a,
+                    0x0872eab077f76397,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf7317e4d85e5352b,
+                    0x1060904542622986,
+                    0xce50d2d34c931cec,
+                    0x14a21356da9b95d9,
+                    0x3d13c9c441f869f2,
+                    0x139d570ade470230,
+                ])),
+                Felt::new(BigInteger384([
+                    0x387eee29c6b2acd4,
+                    0x858fdfe4cddfcd96,
+                    0x28fe631f601c8e0d,
+                    0x7d8aacaa6cf212aa,
+                    0x2b5d6a1026d24fec,
+                    0x020e0b70a20f6c70,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8d6abda9c248be37,
+                    0x68159997c2755c75,
+                    0x8648980641915d27,
+                    0xf167c1c9bd09130f,
+                    0xd1c26de8e7e1a4cf,
+                    0x184457baf9f4c332,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3bb94e01e9e96620,
+                    0x39d17c7d694ce008,
+                    0xd81fcc3543e13b80,
+                    0xe5b9288828cd9c9a,
+                    0x2d52405826ff53d2,
+                    0x015f4bed005e3f18,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0bb9081da9d58929,
+                    0x29c6f99d1e43c325,
+                    0xbe8f84657e1d0e46,
+                    0x43342f01ec93f47b,
+                    0x5c0995fb862d5159,
+                    0x179fcdde09033e5d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x551b9bd728747e20,
+                    0xc29fbc88d0ab24f5,
+                    0xd829e27f507b17a9,
+                    0xa0593419d9fa2a19,
+                    0x5fe1846516a28bc0,
+                    0x14d0a75c7474e734,
+                ])),
+                Felt::new(BigInteger384([
+                    0x259e9ed569cb57f2,
+                    0xe30cc300719e8c6e,
+                    0x1eb2437d54b03777,
+                    0xf07084f67ea24ee4,
+                    0x44dc1e113dd50225,
+                    0x06d42a19228b7302,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1bbc7214647a5143,
+                    0x6619b3e0e0c3a715,
+                    0xc91b53ebda21d49c,
+                    0xc2b43579ce5d4ab6,
+                    0x7d0b8ac445451441,
+                    0x0d3a4f63e7ace1d6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc95d79572b6ce704,
+                    0x0d670ceb21668483,
+                    0x0f9e48246ee9baa9,
+                    0x87b4c5cb6a2d2816,
+                    0xe4fe568e83d916b8,
+                    0x0f24c6d25d75ebe5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa4ccf97047db0186,
+                    0x5259bebd8d043b28,
+                    0x273504c52545f440,
+                    0x7bf92440bbbadd6a,
+                    0x7ce64948416ed211,
+                    0x113fd7ed50a25be5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbbab840cac4f0cfb,
+                    0x068ba2fcf8e4ef79,
+                    0x1eb07ba65ff538dc,
+                    0x7a9a09ec08df0867,
+                    0xc5dff3a069372af5,
+                    0x0465ca55161ce41c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe8c647bbff6b3e5f,
+                    0xf112209530bf2946,
+                    0x3ffc36fc11142f2c,
+                    0x22f70f5c28b48be2,
+                    0x34bce13eb968188e,
+                    0x04494fadb387d5c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbe8001fa4b594524,
+                    0xe370cb7ab6ff53ba,
+                    0x56d3296838e81a84,
+                    0xae8467c95c6fb0af,
+                    0xe6a7b02e10113d31,
+                    0x0668334d6ca52609,
+                ])),
+                Felt::new(BigInteger384([
+                    0x57d212aff69c3767,
+                    0x995ece6f14487783,
+                    0x99262dedaf4401bf,
+                    0x499f832668260d9b,
+                    0xa107f6315750c9fe,
+                    0x0872e8410244518e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x53bf94c7b47fe640,
+                    0x1d0cf7c48247d0d8,
+                    0x1145a50f180d9f84,
+                    0x2ac63a318d92507e,
+                    0xeb55bdb5905ff395,
+                    0x0e609d7d5387e409,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa81d098c4a0026a2,
+                    0x0697ad19e0293c78,
+                    0xd3bc98cc578ddb8a,
+                    0x4df81b5e01d7f4ee,
+                    0x0f0d1e794bace7d1,
+                    0x102c360997ad335e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x27a85c805cb8507b,
+                    0xd2459f934bfd603a,
+                    0x906e8f28b3fdaad5,
+                    0xe5b8439652abeb1d,
+                    0xbc55fdcff6474a18,
+                    0x080e33fdb371227a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3c53fed15e8223ac,
+                    0xc264b099817bed68,
+                    0xec132b92259ccd0b,
+                    0x748a0371192a6504,
+                    0x2359ffe1c464e12f,
+                    0x0864fa70b5bd2afc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd015d6268c2c5b1c,
+                    0x2042e157d0d00842,
+                    0x6acb903b11365551,
+                    0xb4ac23096e863837,
+                    0xc3edac67fe03bc79,
+                    0x03de5f23ade42adb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8152e630f4db703d,
+                    0x62c9d672d4d86935,
+                    0x24cf8e15ea8390b0,
+                    0x69258e7be2b07be8,
+                    0x76b2d25e04d035a2,
+                    0x05fd265747675842,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0c229760b297c32d,
+                    0xbe7b75f4edcf9743,
+                    0xc710c2232c0faf87,
+                    0x68f6887a9d97c532,
+                    0x65f522afdbe6b773,
+                    0x0ded5d113aaa4ec9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5bd3a4cec9b78298,
+                    0x2951148ceb2e0ef8,
+                    0x096ba940df3d76a0,
+                    0x773f3255cfefc65a,
+                    0x8f37a8307026d173,
+                    0x180e2f99dc6cdfb4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x489ce67eaba37b7f,
+                    0xc1f47bf0464e7789,
+                    0x886ecaedd2875e85,
+                    0xd4fd2bd0fd7bbd6b,
+                    0xfe327d4805479dd5,
+                    0x0f92283b4ef92055,
+                ])),
+                Felt::new(BigInteger384([
+                    0x516c693a51c08a66,
+                    0xff166d267949ced5,
+                    0xff4a9efbc7f6aad8,
+                    0x23ecb89d175c489c,
+                    0x94c8c5b74263d881,
+                    0x01fbbb6eb98ff1af,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd143f10dae0b8537,
+                    0x90c6231b31e005b1,
+                    0x087131cb1c3ed30d,
+                    0xa1096fbdd5cd31ff,
+                    0xb05609cf4d41c07f,
+                    0x1181db0c189f62d3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xadc71c8ddea65c0e,
+                    0xf6112a48b6b6687a,
+                    0x0173646c5c7a55ae,
+                    0x7ab5c48b020507bd,
+                    0x5f9fc8461466c775,
+                    0x1006020ff62c715a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0f866a516d04ae1a,
+                    0xdf04fe5e9742e279,
+                    0xda7834c35b3510f7,
+                    0x4202e3f472e8cc70,
+                    0x9dffd777a109e883,
+                    0x0110386c5450779b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc7ce917b2654e5da,
+                    0xcf9b9557d0bd6add,
+                    0x209ddef6927fb384,
+                    0x4a884467c9588b9f,
+                    0x739c14ce3cf45da1,
+                    0x0e7499bfacd917e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa1c86dcf8834b94a,
+                    0x15a22ffb5164d159,
+                    0xcd4d164e3e3f2b93,
+                    0x9e2d24fd9ebd4940,
+                    0x985ef6a8c9e2df89,
+                    0x0bd9e424bd223646,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6a9f875eb1f9bc3b,
+                    0x5797e730a0cca905,
+                    0x3eb2c3ccedf68b46,
+                    0x8354a175cacbe3d6,
+                    0x2022e4a90dc41650,
+                    0x09dedf6b8db0673f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x999454a3e9acbef6,
+                    0xf91fe05f7197d806,
+                    0x7355d2b4a796f34b,
+                    0xfd4a91dc110c8c0e,
+                    0xa1b8d990faffe5c6,
+                    0x1101c09bbfd3e9e9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb23338405a4b5477,
+                    0xbf91f01b3dbc5737,
+                    0xe38010576b7b5ff5,
+                    0x127566fb264bc723,
+                    0x94c48b092855882b,
+                    0x187b62852b51c6c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8b26b4bca8498fb0,
+                    0x372e5ab898bd6851,
+                    0xecbab9fdc4c26bf4,
+                    0x40b9bc5c37a1b2cd,
+                    0x19360815cb429d93,
+                    0x008f849c90ed3a08,
+                ])),
+                Felt::new(BigInteger384([
+                    0x62d593bf3b03fb61,
+                    0x9ff9a8363748cdac,
+                    0xa438b612de7b8382,
+                    0xd37a12a052b39072,
+                    0x7162ecf913464485,
+                    0x055177ee314199f7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x018fd06458187a3c,
+                    0x76f7f5a2eba5d163,
+                    0x81774623c04a093c,
+                    0x385cf13b9dd7d74d,
+                    0x06d69c430a3eb7e6,
+                    0x17edc7d9652eecc3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf8d872663a7071df,
+                    0x03c330493a7b65ca,
+                    0x4507afeecf7d392a,
+                    0x56ff7e5bbca96c90,
+                    0x9978704e12bb0cc5,
+                    0x09a6246892219284,
+                ])),
+                Felt::new(BigInteger384([
+                    0x75db84b2dd3b2886,
+                    0xd23465a3d621e318,
+                    0x7664a7e9ab7a6ea9,
+                    0x70dffc294cb9ad5f,
+                    0x0d7d1b1ca89fdc5a,
+                    0x18f53597d825dfcb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8bb48ad5963ab7f2,
+                    0xa7b21abe83612033,
+                    0x6571d61684d2a2b1,
+                    0x63fba42392c3c98c,
+                    0x31ddd7cb96241ed7,
+                    0x10d1fa83b244f17d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xaab8e33f41553b98,
+                    0x069d1d6bc74ae1ce,
+                    0x4bc1c96a682ff51e,
+                    0x98ba370faf349192,
+                    0x0a3de68ce6ec3390,
+                    0x17a9b3880b7c3f8e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe25b827ef83e41a6,
+                    0x0c20b7ea78a0d45c,
+                    0xc166952a4b85586a,
+                    0x2d02e654f136c8f2,
+                    0x9d17748ea1930244,
+                    0x0ae0924f4a85e0c6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc3e9c77c65cd2860,
+                    0x4d5db756df41f4e0,
+                    0xec3f255658094b4b,
+                    0x43792fbc19c5733f,
+                    0x1520b2fbf1fab2ab,
+                    0x06f6e971c85acafd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa3dd319cb7235529,
+                    0x11e70be08813449d,
+                    0x043d7f5b8461e416,
+                    0xc86f1076125912d7,
+                    0xcf4e5c7ffb85af0c,
+                    0x118e05cb4d0892a6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5d6158ce357b81d7,
+                    0x5786baa25b0d200c,
+                    0xd4f8e021494c223f,
+                    0x6cdbc94a82c97ea0,
+                    0xd5e2e1d733fd1256,
+                    0x12c557d6494cebec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x07dbfbb384d2d27a,
+                    0xe96397c37a27d173,
+                    0x20c5786ae112b951,
+                    0x9b46ae1d9d5ef58b,
+                    0x1e8720e43722f20f,
+                    0x14f837b4edfab588,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d406cdbdc1ba09f,
+                    0x0d4d0a7dd9066032,
+                    0xa69e1ebf3928abe7,
+                    0x49e07bafa02cf1b1,
+                    0xfec0692443b626c2,
+                    0x0dee186aa22515a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa23dd4f8d1ad14ad,
+                    0x3ae40a55b6cc3b49,
+                    0xe89eb2886d93b56d,
+                    0xef90ed18c73f521e,
+                    0x063815667c18caf6,
+                    0x018f6be0f4d2a380,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52d54acbeaf0b903,
+                    0xfba85d8eb2ca6be5,
+                    0x0a2b6bbaf8745d9f,
+                    0x091159b6c0e6d8e3,
+                    0x19d17b1e6f476902,
+                    0x17fa9a596380eeae,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7fd086ce1a893c06,
+                    0xc2bd85bbc2c18da0,
+                    0xb6bbf8e4714d723b,
+                    0x92caf299951446af,
+                    0xaa7d22527a452f63,
+                    0x0f9c4a3ae980d1ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f18c076fcc2b4a2,
+                    0x30a7e9406fb66e55,
+                    0xe1738a81b35f1cd3,
+                    0x319688517169119c,
+                    0x0191ac41518c63df,
+                    0x05596c2b771b7d4f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x89981220548103de,
+                    0xa868298cd43510c3,
+                    0x363a14eee7cc7c05,
+                    0x78a4df234c406654,
+                    0x29aa47a1f9615475,
+                    0x0b98aec46c16a1b1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd4a2f213d199804c,
+                    0xf749ec24cf525e0b,
+                    0xc45e779e6fbfc431,
+                    0x1e8f45fe931bc110,
+                    0xdc6db82027d3a7b2,
+                    0x08ac734b36049bd6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0018f1123df57c0,
+                    0x8576f15258bffb3d,
+                    0xbf1e7159033fae4f,
+                    0xfa383683ce0b69b2,
+                    0x25f5b1d29e044414,
+                    0x05dcf926852f3b90,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba154f82fa258aa9,
+                    0xf792e96aeb9fa4ff,
+                    0xa1995ad7af5faf7d,
+                    0x7b5b59a849fb3b70,
+                    0xe0eb0e3f1a3b758d,
+                    0x05a57a92b17521cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8f0ebf733b3b1622,
+                    0x98d0c36894c8ce0f,
+                    0x723dd077082c5b15,
+                    0xd994998883e5a5af,
+                    0x49f5f89c1ceaa6e0,
+                    0x0e642123d4319a61,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdea78784c89f2060,
+                    0xfce2abc0d531285c,
+                    0x3d7eb8d60e80c197,
+                    0x3707b9325e98dd37,
+                    0x378b1c9cd245c907,
+                    0x07fa4274ed95eded,
+                ])),
+                Felt::new(BigInteger384([
+                    0x726d29a1fb2d3ace,
+                    0xf5c83f2f03faaa5e,
+                    0xa7ad3983ba9a8f99,
+                    0x0cb89d5454419168,
+                    0xd83e918b8ea808c1,
+                    0x13d0e778e82d2afd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8bdb417b70c0f89b,
+                    0xbd9febd6d8dd8591,
+                    0xadb793b2ec20df81,
+                    0x85bb1827db193a60,
+                    0x132d2d31222f9940,
+                    0x165595f7411162ce,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7d6c07fac0cb45de,
+                    0x9775ac817d6a3133,
+                    0x2ac486d2d4abdfcd,
+                    0x41f51b8db077a8ab,
+                    0xc4fdaf4e6dad954b,
+                    0x060e08e2e7a9a947,
+                ])),
+                Felt::new(BigInteger384([
+                    0x329713ac2e95ea35,
+                    0xeb4509659b35f46a,
+                    0x47ce497b90149629,
+                    0x0edf01d2e5efd421,
+                    0xc0374863a118d521,
+                    0x18add7851831ce7d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb04b62dfdc17f87c,
+                    0x3ff3049d9d8b5db6,
+                    0x6d5f7e3920f9eea5,
+                    0xc4247820bd252089,
+                    0xa0b4e7f0f4b0af35,
+                    0x02946975e5e68e15,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8871d2953775939e,
+                    0xf684fcc3c1483c0a,
+                    0x93d6e2b718e28edc,
+                    0x9e198144c68f93f5,
+                    0xbe1413f6e94684a8,
+                    0x128f38cf1bfd6162,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5b8aee8ce00beb82,
+                    0x8a200200d086d76b,
+                    0x25421f91b1f9dcc4,
+                    0x41d5cb237537d109,
+                    0x275df559da965af6,
+                    0x19bddd7f1b9e4f57,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98c4000000415514,
+                    0x845001003bb00035,
+                    0xfe9ebcc320838c58,
+                    0x14aa2e358e1da574,
+                    0x7ed398747a0fab17,
+                    0x172e48abfa1371de,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x600a6dd90abdf87f,
+                    0xf737d68797ab135d,
+                    0xdd2f4a78459a0485,
+                    0xb8feaf53fbd073e1,
+                    0x6d12d74947e6b8d1,
+                    0x0743d81b15553575,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7d3d20ad2c1be138,
+                    0x84f45330bfc9718a,
+                    0x8f0ba42b46673cc2,
+                    0xe41df2abd2b8b10d,
+                    0x38938fd6acb0bf50,
+                    0x13ef8f78d43cdeed,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd2528b926baeb9f1,
+                    0xb54da324cf20476d,
+                    0x166fb70a8e92afbe,
+                    0x409e28ba4e3acafd,
+                    0x10da2555448bb7b3,
+                    0x193e218358009623,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5bcdabd4f88dce1a,
+                    0xdaa4f071388f1778,
+                    0x80e25dd66327fec6,
+                    0x5f0c151cb2736e72,
+                    0x3c0a429ee630c89e,
+                    0x082199db82d7e852,
+                ])),
+                Felt::new(BigInteger384([
+                    0x90dbde5206e3a79b,
+                    0x54ba028aed9686af,
+                    0xe40f6260b0f46501,
+                    0x5c90a5e80fac0b5d,
+                    0x1c711ac65d06cfbb,
+                    0x0bbd30d8a4716e37,
+                ])),
+                Felt::new(BigInteger384([
+                    0x46d69b705e8ea52e,
+                    0xe15332258bc4899f,
+                    0x6d55e27bc73c14ff,
+                    0x03918cb4b80f1df0,
+                    0x801470154509f5c0,
+                    0x0ad90ab058b0e385,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa35d8830f537a2f1,
+                    0xee490ed42e881c94,
+                    0x62e3f1fc3776357c,
+                    0xe2ed92aca6f8bbf1,
+                    0x80da392ad32a19f5,
+                    0x0e7f5abec95ddc92,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc33c150178f68465,
+                    0x67a367a8ad7b974a,
+                    0x4db880f07c271f5b,
+                    0x2a41c2a90c4fee4d,
+                    0xe09dbbc6076928ce,
+                    0x0d0d193fdc8b0ed6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfe578019c3ddeb96,
+                    0x81f5310b6e294d67,
+                    0x9e82c70e5868f158,
+                    0x73d9d7fae7e23043,
+                    0x2012876806a74844,
+                    0x157295539c785e91,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc1dfcd2c4f0a681f,
+                    0x903114e05a22d769,
+                    0xfef10fb0a6659d77,
+                    0x2716a484c6228a7f,
+                    0xa6554bba8ffccd67,
+                    0x098e6d330d8557ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a9bc789b3057573,
+                    0xe06bf08b839fd84a,
+                    0x9c08fbb36d8ee51b,
+                    0x781447b5c6acd208,
+                    0x117ca3ceac9acb4e,
+                    0x10aa844f546706a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8f846d9cc97da34,
+                    0xd0fab65277365fe6,
+                    0xeac28cb479a1e422,
+                    0x49e9214f8e576846,
+                    0x7ba61c4aad4a0817,
+                    0x136d036a70030497,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa54c5a06b75f0245,
+                    0xac30fb65a74cc26a,
+                    0xdbc4b7786c579f95,
+                    0x136de2b3ec1eb208,
+                    0x9f1923e2ec7a32b8,
+                    0x073c94788977fba5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x007a515b3865d506,
+                    0x369cd4b962aeb1f4,
+                    0xd6e85a4341f63aec,
+                    0x8c171391a9a551ea,
+                    0x92ed48878a7f7915,
+                    0x0e727c9fa10c2c41,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe018d122e87c6ffb,
+                    0x7d3cb99dcefd3838,
+                    0x26afdac26815ae4a,
+                    0x50dbdc5b923b9851,
+                    0x036e61da7f1e5001,
+                    0x0d5e0e0e624c3515,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbc9b3e4c5565f849,
+                    0x240149bc62910b83,
+                    0x4502b9b8f19f3709,
+                    0xf8c4bb1da5178e54,
+                    0xe872e379844dcf08,
+                    0x00f39667bbc5eef2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9fd0518e00b07438,
+                    0x3a9280f5b9077681,
+                    0x6774c31bd582670f,
+                    0x164defd284501482,
+                    0x278977f792966a75,
+                    0x034db8fdf5de8809,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd282fe6ed0e3421c,
+                    0x0f72ea2127f5b399,
+                    0xbb18294acb6a4314,
+                    0x49d05bfa6e9cf5e5,
+                    0xd466cee5fe10012c,
+                    0x151d509cdd578fd3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6e1d9214f56db29c,
+                    0x326f471c48a9660b,
+                    0xea459f18e30fd26e,
+                    0xf8a11d488d9ffd87,
+                    0xf0f7e1af0bbbfbde,
+                    0x0b36134cdd852a76,
+                ])),
+                Felt::new(BigInteger384([
+                    0x80e453ccf07c8497,
+                    0x247d18d358e9eb87,
+                    0x605d043e36cd7620,
+                    0xd5c96c0ed1102cf6,
+                    0xf51841406e436d82,
+                    0x06e0a55eca4219ae,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2405ea04d70c5f0,
+                    0xa3e216b5b604ba42,
+                    0xe36073775804a2dd,
+                    0xf4ecac6aaa02f53e,
+                    0x4d57b4b482e7c3f9,
+                    0x0136bd56e8ab8f6b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc8a3a9183db8eece,
+                    0xfd8aae193c87ea77,
+                    0x69df073a481e8fc1,
+                    0x5b2795fc5d80ad0a,
+                    0x816aea80cb66f0b3,
+                    0x14f735f91494ba83,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f28fb146c07d845,
+                    0x28a50b578763302c,
+                    0xde116133e73538e3,
+                    0xff411f505d8b7156,
+                    0x0c4d37d8f3488c7c,
+                    0x16c8c8d1981b71b9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x69b797e0b64b59be,
+                    0xa7bd06faf221a2f0,
+                    0xd7e661435f0bd692,
+                    0x2b56e57d60d0b593,
+                    0x5663ef31f24e599c,
+                    0x0f7054ebcbe271c8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xda89430108b5a0a6,
+                    0xfcb5b36ce6d78430,
+                    0x357da22425b7efc1,
+                    0x03f8ba94a3403da2,
+                    0xe117dad292efce69,
+                    0x0d8cc16dcede919e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe63ab6bdf63ae540,
+                    0x854de2ba01cb7915,
+                    0x881da09a1df90e79,
+                    0x179455e463ddcdf9,
+                    0x58026d606dbc7bfe,
+                    0x1911db0f251b2c26,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9cfd97b975b05823,
+                    0x4409337bd5098a50,
+                    0x6363dcd1875b4c46,
+                    0xa2d2551774eda27d,
+                    0xa0627a1345880155,
+                    0x0ce6ad90f42fa2a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45ec35693995b733,
+                    0x9871135b7812e467,
+                    0x5623bc358ec3ec12,
+                    0xd42f7ce8c322084d,
+                    0x551f67c63807e9d5,
+                    0x08e6f95fe70089ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d656849cd312bb8,
+                    0xa8ea9503a75f477a,
+                    0x3373ace05233a608,
+                    0xea1fd800997ed90b,
+                    0x61c2e874320bbefe,
+                    0x004a8960463d7630,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1e38089d1bd3e151,
+                    0xb2350ee86be544fc,
+                    0xa50585a43461aa72,
+                    0x3ca4477120553191,
+                    0x0635fe52879cccca,
+                    0x0a43e97476c6d0c7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4782a63cab9c66cb,
+                    0x3e23fb939bff9fc3,
+                    0x5b3c60f5ae7c4e17,
+                    0x2ad35d5aa32a3000,
+                    0x5fe5ca3a5e166c61,
+                    0x133e7be2aa706ddd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb6a166d5b9683a84,
+                    0x6d35c4b256f04783,
+                    0x8539a104f1e68ed5,
+                    0x851a487b3427475b,
+                    0xab2c9bd6029e8cb1,
+                    0x0a15831a8f7b123e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x236bac765c8ca269,
+                    0x563e356174588318,
+                    0xb3c236e464f73ba4,
+                    0x7585103dfff015ec,
+                    0x37cc0f2f65587d5a,
+                    0x18ec195a15563507,
+                ])),
+                Felt::new(BigInteger384([
+                    0x22665ed0100f325d,
+                    0xf922fc1ba8b89e88,
+                    0x22c6f7e6a3614e1d,
+                    0x4774d2a1ecba4e0a,
+                    0x0350627bbdc05be7,
+                    0x013f2d51d5bc8921,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a97f0cb49fdbd99,
+                    0x65aec2161bb33724,
+                    0x2db6596d5ec08e53,
+                    0x2a8dd99df9c4f13d,
+                    0x86e162f8d06967b4,
+                    0x1351a727f68ee7f6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6c86f52c645ef993,
+                    0x8f916035e56ed788,
+                    0x91f56154b7ec7111,
+                    0x07a8f77d79595332,
+                    0x033faf27c1307342,
+                    0x1043938deb6aea78,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xdc515b6ba2066652,
+                    0x38e1c2fb39d2a8eb,
+                    0x1d2c05e09ad7eadc,
+                    0x2002d62014714952,
+                    0x7b300865242bd1d0,
+                    0x0a6e377780613a46,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5df67f20bcc18a99,
+                    0xb470a32e4f329a51,
+                    0x66f4b21ec96cec71,
+                    0xc808482cededb63a,
+                    0xeb23fe494e611f90,
+                    0x12b2bc7ddf58bfa3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a735095677a2333,
+                    0x5ce7f0ac29614ce4,
+                    0xf4259ae56505fd1f,
+                    0x7fce32f7a94a7646,
+                    0xcd51b9365bf3c3c9,
+                    0x02ad90edab3f59b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x80ab984e4f8305f9,
+                    0x874c9c02e8c10f08,
+                    0x98961392ea94c218,
+                    0x6191846131dbbff9,
+                    0x287607c7945a3473,
+                    0x0dec61a6ea472599,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7244630af93e679f,
+                    0x18897c0c89368f03,
+                    0xa9e16e7d842bc0a1,
+                    0x070cdbe2f3c6164f,
+                    0x40bc2dca23ee1ad9,
+                    0x0cf2554fd349bd1d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0ae8aef24f121584,
+                    0xbdf134ab6d111cf6,
+                    0x3a9ec42a679d0886,
+                    0x40834b6149475b81,
+                    0x6b037f232da7198b,
+                    0x07ab265d7f20dcd7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x99d4c0b9983c6329,
+                    0xe8916b5c144a6fbf,
+                    0xb53bce64439a00d0,
+                    0xc46866c2f55ddac5,
+                    0x6737c8ad4cd0cc3b,
+                    0x0b73e26288d5b5aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc397fa642eafa2d3,
+                    0x6e8278bc72d33ce9,
+                    0xfee79787feaa98de,
+                    0x86adb608f5f2bce7,
+                    0x5dc035a6c0ff1d60,
+                    0x02694e6a79026dc0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xac002fa2b32bc745,
+                    0x7f6b5bf75c113776,
+                    0x3bdc8d9f4a66616c,
+                    0xe217b53b61187720,
+                    0x5d64982beb004e62,
+                    0x176fc18114ada1e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x81453d166f828b6f,
+                    0xf4b71f56be0beca5,
+                    0xb7b49ae7eaab4127,
+                    0x808d2486c2b2e84a,
+                    0x679388c93397b68f,
+                    0x083bbe20636ddabc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d069491a38b4f30,
+                    0x2aa797fa49d486a3,
+                    0x8dec4a746834ea3b,
+                    0xa33d33d64b4bd8fd,
+                    0x85940f7fee0eb975,
+                    0x0efe26efa53ef895,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf79f5bc1ace530dc,
+                    0xc59341b7be917205,
+                    0x9c9f9c2211686979,
+                    0x8b7f8c01e8f7a3d9,
+                    0xa33b9d23ad517cc1,
+                    0x03eae7e1b51e366b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf86224e26fe71862,
+                    0xe1ae5eaea0eb3037,
+                    0x92de4d00fb494314,
+                    0x5bc91bd71787464f,
+                    0x3822905b30714b27,
+                    0x074535e1fa16f2e2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x491280077985f2ca,
+                    0xd7db24d806ed7963,
+                    0x6ccb743eb803ca24,
+                    0x36f9b0c536778b29,
+                    0xe726fc7be1dbda82,
+                    0x025a2b7bab7e995d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd1297187e8d25623,
+                    0xe68134d7181342a9,
+                    0xb832e2a683add7a3,
+                    0xaf3bf813df8471f9,
+                    0xf18ef069473aa975,
+                    0x0a3bdc32b646c422,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc90543e7bc7621f3,
+                    0xfb7ee72866d74355,
+                    0x4bf0ab5601ddf10b,
+                    0x8ecd1fb3040c8a66,
+                    0x7fc77f13601b33eb,
+                    0x040362073c75da92,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ba11755a720812e,
+                    0x221f6656fbba4c77,
+                    0x2631b35ec9e35a03,
+                    0x3934c8a56adb2fbe,
+                    0x92cafdb360f7d28c,
+                    0x0857e04cc34e6172,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcef391461ab0d402,
+                    0x5f21558954701b3c,
+                    0x05a74754400f9338,
+                    0xef2022737d0c36b4,
+                    0x2a3715c307fd631e,
+                    0x1379aedfbaed3f8c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa66b9dd3ce722b57,
+                    0xc72facd86ff09e68,
+                    0xf2ebec74b926a94e,
+                    0x05a6da03bba94698,
+                    0x4aa4a01cb7e3cce7,
+                    0x014af0b2d5148b72,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa0b79a08460c18e,
+                    0xddcfb7be7374cc4b,
+                    0x87d431090a2ceda5,
+                    0xc72f95b6f276226f,
+                    0xcc1960936b148c61,
+                    0x19027a05f15898bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x08f21ab91f469183,
+                    0xfb807aabe8a45edd,
+                    0x958c05f21467d234,
+                    0x4d65cfa652654682,
+                    0xeefc0aa32e01d821,
+                    0x031a2ab50b985243,
+                ])),
+                Felt::new(BigInteger384([
+                    0x12810bde62394796,
+                    0x348a51dacb64fa17,
+                    0x215b7b87dc215660,
+                    0xf7900be07af6be95,
+                    0xd14f2d526f82c16c,
+                    0x127e21adc8767f63,
+                ])),
+                Felt::new(BigInteger384([
+                    0x58974de6c2a82e77,
+                    0x3299dd7fcce42c8d,
+                    0xf90bae0b0a06ff8d,
+                    0x34c9d03447f4d44f,
+                    0x5c3bad2f5d7ab0bf,
+                    0x023bcf26cf61495b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92556c85c3966ec8,
+                    0x17cccc099f12a1f0,
+                    0x9495c28398bd1420,
+                    0xe36d7d116f53fe87,
+                    0x5a3e9eda0916c4d7,
+                    0x0c28107b3e0fed28,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8cfd0cde9f03d340,
+                    0x65cf7d4443f64890,
+                    0xb211c8f69dba6ba0,
+                    0xc87495e6918785a0,
+                    0x50328a0d9dd8f60a,
+                    0x0dc9789b2fd7327b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5fddb7a1c3b0ce1a,
+                    0xbf9f98ce0b5e3401,
+                    0xed4da68e9c723556,
+                    0x4d35507fa65bbfc3,
+                    0x82c15f6bb2abd8c1,
+                    0x03ad78dc310f46c7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa00e60d66b30d272,
+                    0x7cb3f18157b64f4f,
+                    0xe94177a750debd9d,
+                    0xe3c4288645dd7ab0,
+                    0x56a1d0748cc1bf9c,
+                    0x13c09af089d05448,
+                ])),
+                Felt::new(BigInteger384([
+                    0x28173494cc07ded0,
+                    0x04f00c6eccb933ec,
+                    0xd2497c5769d29bec,
+                    0x1d74a81938b04008,
+                    0xbc9c8aae5a2b31a1,
+                    0x17ae38e3bc0d05a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf5cdcde90e36ca7e,
+                    0x8273983ae7a9e733,
+                    0x31dd0533046b2c00,
+                    0x4b504b6b8501e0b1,
+                    0x4f8322bbaa115457,
+                    0x09d116b66a81d3c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ca7f151fcdf733b,
+                    0x6e727c8f99d2869f,
+                    0x09a4157cb2a3b2b1,
+                    0xd23033b59bda65d1,
+                    0x5b8888dda09c9193,
+                    0x0674e78b979049f6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc794c04066457fd1,
+                    0x939f47ee6b9e8571,
+                    0x31f96512b0319442,
+                    0x02b0f231037c9b39,
+                    0x7c05ad50f5dc753b,
+                    0x0b5ddc7e0d05e8a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xde401fc8e9cac9ca,
+                    0x0818b29c9b9626fd,
+                    0x94a21a04cbfdb721,
+                    0x2775be2dcbb22b9d,
+                    0xb12862e1d169e217,
+                    0x15daad8fc1dbd491,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf17fa4f85235b646,
+                    0x96da4dba979ce6cb,
+                    0x07dbe4a70a76ffdc,
+                    0x63292edfd46f4ec5,
+                    0x605483a0334e1f3a,
+                    0x01fdd15d4b80f19f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5800591b7e723bac,
+                    0xfcaebf09de1a6156,
+                    0x5cae0323d54772a4,
+                    0x6f81e9c7f84ce332,
+                    0xc92d9a9721cd850b,
+                    0x0e5af6f636d7d932,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd0357487e6c35952,
+                    0x70ae1cab8b48ff13,
+                    0x9d4e2828fd072331,
+                    0x011b5ec9dbd6a227,
+                    0xe8321cae250c235c,
+                    0x032a8b4b21660e0e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbfc1c43183459b7b,
+                    0x954dc2e84665439a,
+                    0xeaa968ae163db378,
+                    0x8c528d1d744fb7df,
+                    0x6dd4f51a88f95ce6,
+                    0x15f02f624f14bd08,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
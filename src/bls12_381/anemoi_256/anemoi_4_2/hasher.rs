@@ -0,0 +1,2111 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // We use internally the Jive compression method, as both digests do not fit
+        // into the rate registers at once.
+        let result = Self::compress(&Self::Digest::digests_to_elements(digests));
+        Self::Digest::new(result.try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U2>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger384([
+                0xe2de3ea84ac16e92,
+                0xb574851af2ad7ac1,
+                0x819e28e18ced7222,
+                0x67e5b59bf6126d2a,
+                0x960e366f58347bf6,
+                0x0181ce379c3ae1b1,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0x67394de0fdd0f591,
+                    0xea68de5654c8de82,
+                    0x54021a380bf14bb9,
+                    0x88efaec2df0e60fd,
+                    0xed851ad3aa5e52df,
+                    0x0082420416db59c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x889548c0c334a299,
+                    0xac11e45d98e055f4,
+                    0x8a007c19c966c32b,
+                    0xcb0e2d8ca7d37cd5,
+                    0x4f2c851ecbd2a90d,
+                    0x0b85e74c9a2314b4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x27642d7d7b16fae7,
+                    0x23cb747f839cbe5f,
+                    0xda452f079279e07a,
+                    0x235dea51f9f04a78,
+                    0x77c3b08826b45685,
+                    0x02f617e771e601ed,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf75c085c4e2640c3,
+                    0x73c2208967850903,
+                    0x3c41d27a73c9bae4,
+                    0x09efc1e2e11f2d53,
+                    0x5c0a962d73bd8704,
+                    0x17be1921782cdce5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xffba4a08a37b5908,
+                    0xd327612b7ef0aba8,
+                    0x4d6d050610afe0af,
+                    0x8cdbeb78dbdc5422,
+                    0xe48c254d5be16a4b,
+                    0x07d018d1d7e414a5,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe8c7e7f58208591d,
+                    0xc1cbcc320eccd6d2,
+                    0x08aa39928bf6e63d,
+                    0x0a57bc2d67d99d5f,
+                    0x4d8c6afef99703d6,
+                    0x0f827b25bea58777,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe611ade8f77a11e8,
+                    0x2d4e27beb74a718f,
+                    0x2682ad1c0cd93280,
+                    0xab5abf943f5f801a,
+                    0x71e2110ed1d80108,
+                    0x13b335643c98f511,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb7cba740d92e85d8,
+                    0xf3e84b6fd3e2f848,
+                    0x8507ee95149e230c,
+                    0xee07e60d125500c5,
+                    0x1172634ed97ede1c,
+                    0x0002e475888356eb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x58b690269d1a3f4d,
+                    0x7200e7e48ca8a4ec,
+                    0x73d6f3458223798a,
+                    0x934539461e469216,
+                    0x247a6161eedc85c5,
+                    0x14629adb1161bcf0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x3d9fdb75a1e7ac14,
+                    0x6c83cac1dcc7ac1f,
+                    0x08dd2ee1f1f7410e,
+                    0x1c7ba0ccc51e923d,
+                    0xeb9673fee4c1f0d3,
+                    0x11dfcf9308543535,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2132910bb2f32c46,
+                    0x23a839a0c71a41dc,
+                    0x852c320b3853e8dd,
+                    0xe6450a610c3164a7,
+                    0xdf72893a6ac71bce,
+                    0x1325eb8d5cda4321,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98574c504257e715,
+                    0x53b0162900cb58f0,
+                    0x65da76f4177aeef7,
+                    0xb25836a484ccefc5,
+                    0x65990b82c0675f66,
+                    0x0ffc1e3d856ae562,
+                ])),
+                Felt::new(BigInteger384([
+                    0x03acc856556fff76,
+                    0x28460f7ed453918c,
+                    0xce599b25b3cd13e7,
+                    0x32fc290f5360dab7,
+                    0x931b9f99a35572f7,
+                    0x03eb1328a6604fd6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x28729c270de10197,
+                    0xdb87adc22baa91aa,
+                    0x84767d5a585391e3,
+                    0x63ed2a51e7f52f2d,
+                    0x38a65012dd0aa82c,
+                    0x05d680253f3d00ba,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe16dad12529dc009,
+                    0xc563a7e9402559b2,
+                    0x150fdce5ef4b345f,
+                    0xbadd29db96286ee8,
+                    0x33984c063f27c20f,
+                    0x18901df865600af5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3c06db6fb0101650,
+                    0x5ed1319f8a2947df,
+                    0x444a9d35f7c6e6bf,
+                    0x2d89a479ceaea117,
+                    0x2b7800a474e41252,
+                    0x0f8ac90753ae5961,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ab61b39a4322f06,
+                    0x0f654b46dc6a6e7a,
+                    0x6c12ce590194cef6,
+                    0x9e4c2a6f7de58569,
+                    0x12ec154572718acd,
+                    0x07a34f3ef48947f9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9049e14253314ff8,
+                    0x036f679d2148b681,
+                    0x5767d155f8fbfbcb,
+                    0x8107b1908c0444a2,
+                    0x5ec0164b7cd96af1,
+                    0x14441146755602f3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x954d6b215ed05e3c,
+                    0x1ba7a0b37c3489be,
+                    0x25141175babb0a89,
+                    0xb0c091e92b9516a1,
+                    0x99030fde4874fdc1,
+                    0x12eaaeb8698b14b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd079bdce3f2ce640,
+                    0xe9208019efd977d6,
+                    0x64e07dd76bd08ea2,
+                    0xa0da0d16dca818ef,
+                    0xfb906502f9750788,
+                    0x03a01f51c1eb8eb6,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xf72d727cba3b0adc,
+                    0x1962fd16e440ce73,
+                    0x5adf39233c3f74e6,
+                    0xbd82f21fbd91ffb7,
+                    0x50e693aacefba55e,
+                    0x0bbc2f186b148fa5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x553658458c60fd62,
+                    0xdeae8ab6054b832c,
+                    0xabb66171442ed553,
+                    0xb79ed89f2e890a7a,
+                    0x47189b245bd5e96d,
+                    0x19c14914d8cb2fd8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x43ccf4fdb76184bd,
+                    0x67899c18fa4ebf0c,
+                    0xc3291fc803e229af,
+                    0x35d1fc18b69926d6,
+                    0xbe6b7775005d0070,
+                    0x096f8b3ae5d21174,
+                ])),
+                Felt::new(BigInteger384([
+                    0xed856962ea78c08d,
+                    0xa3f4de34a399d607,
+                    0x36f75ccbb9579375,
+                    0x09e4f3198e444eaf,
+                    0x4433d176af082961,
+                    0x003523661d0376b5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x26a157ffc00d461a,
+                    0x861a7b7c86e18d88,
+                    0xd4228d2b03b067fe,
+                    0x930b0f907dba21b3,
+                    0xbb366530dacd87db,
+                    0x14e1723fa73e8a8f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb359af60eca4d75,
+                    0x7972950a4aa9d3d7,
+                    0x01018aa4428c73da,
+                    0x204a9560d2f04046,
+                    0xb6f1629ee73e9456,
+                    0x1053f0808926ade8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x39aefd9fbaafd12a,
+                    0x6ba30eea9ad35768,
+                    0x5996187eed3d4986,
+                    0x2da66351409d3453,
+                    0x1278a02908f14d63,
+                    0x141c48e4a1dae974,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb0f35ba6abd4c7a,
+                    0xc3cbe1753d39ccce,
+                    0xb936b9f791070ebe,
+                    0xf3e5b41592b71545,
+                    0xaf03064240f9472f,
+                    0x0ae151948ca20ddb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2010fc96129c7448,
+                    0x47a2fb08adaefae3,
+                    0x15daf1f78f27bd5c,
+                    0xa70f873a67630d4c,
+                    0x329d8036d8e59d43,
+                    0x03c98404123267cd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe9223157b3bdaa7e,
+                    0x31b841c36d08e226,
+                    0x9f0c04d1c4acf8be,
+                    0xd59ee77dfa2fbbdc,
+                    0x2ddc498b115f4eff,
+                    0x125404d82b72e5a9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x273b83ab2e7e2d71,
+                    0x8a0609db8c9c31b4,
+                    0x5a26c09f505c3d83,
+                    0xe779fab30f47fc21,
+                    0xa897f24776e5a325,
+                    0x095541ba9ab9203f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x952837099d188a04,
+                    0x28a4042c5da32e91,
+                    0xb9fad578c0c8d90a,
+                    0xe66a3c5e67679d12,
+                    0x1ecb4c92c1c95c4b,
+                    0x020a33f6e2d9744e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7daf69554eb9b2f3,
+                    0x67900787a060a9ca,
+                    0xb5dac1a5fdb3def3,
+                    0xc75969ad6ce221bf,
+                    0x9072d591448156c5,
+                    0x18aca0e7bd560cd0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0681d272ae84ac09,
+                    0xc1108d6828d87ad7,
+                    0xf9b29f8307b49bed,
+                    0x03ba0dbb6aa6c4c8,
+                    0xf24ce4ae87ff4c82,
+                    0x06d41182ce20dbe8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7d77f9d5d5920675,
+                    0xd24ba01c29deeb5d,
+                    0x944645a14d8def4f,
+                    0x0ff542d96bb1d781,
+                    0xa503048abf3e03a4,
+                    0x18541b56fcaa91ed,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe66a3c7379dd9d7d,
+                    0xe4d5f203f1912336,
+                    0xaa01256d602c22f0,
+                    0x6b56d6a22fd41f82,
+                    0xfcea3c8e0b838e5a,
+                    0x0f80c9ac9f4c9b52,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8fe2a1554d911038,
+                    0xd165d60c987cffa3,
+                    0xe1938d83a17f7053,
+                    0xa17a9ae94191b991,
+                    0x1b51b9b1c757e80d,
+                    0x110ac823b810bc04,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa68f8bc1c57b20bd,
+                    0xf87065ff00f81aa4,
+                    0xa440470242b0533c,
+                    0xe8aa11229a67eef7,
+                    0x53e271b79a9632f9,
+                    0x04c16aed044c29ed,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x80bba4f001bd4886,
+                    0x66d38e2efe524eaf,
+                    0xd035f587d6b1d762,
+                    0x08b273eb9cd4d3f8,
+                    0x38ccf1c077166a01,
+                    0x0ba0e86d89257fb8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf1954bd8e736e933,
+                    0x8cf3cb7ef4e6fb28,
+                    0x9ca584b7cd71c53f,
+                    0xa38db321307e0a24,
+                    0xe02358b2bf66d002,
+                    0x05136e205137aa2b,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0xf72d727cba3b0adc,
+                    0x1962fd16e440ce73,
+                    0x5adf39233c3f74e6,
+                    0xbd82f21fbd91ffb7,
+                    0x50e693aacefba55e,
+                    0x0bbc2f186b148fa5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x553658458c60fd62,
+                    0xdeae8ab6054b832c,
+                    0xabb66171442ed553,
+                    0xb79ed89f2e890a7a,
+                    0x47189b245bd5e96d,
+                    0x19c14914d8cb2fd8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x43ccf4fdb76184bd,
+                    0x67899c18fa4ebf0c,
+                    0xc3291fc803e229af,
+                    0x35d1fc18b69926d6,
+                    0xbe6b7775005d0070,
+                    0x096f8b3ae5d21174,
+                ])),
+                Felt::new(BigInteger384([
+                    0xed856962ea78c08d,
+                    0xa3f4de34a399d607,
+                    0x36f75ccbb9579375,
+                    0x09e4f3198e444eaf,
+                    0x4433d176af082961,
+                    0x003523661d0376b5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x26a157ffc00d461a,
+                    0x861a7b7c86e18d88,
+                    0xd4228d2b03b067fe,
+                    0x930b0f907dba21b3,
+                    0xbb366530dacd87db,
+                    0x14e1723fa73e8a8f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb359af60eca4d75,
+                    0x7972950a4aa9d3d7,
+                    0x01018aa4428c73da,
+                    0x204a9560d2f04046,
+                    0xb6f1629ee73e9456,
+                    0x1053f0808926ade8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x39aefd9fbaafd12a,
+                    0x6ba30eea9ad35768,
+                    0x5996187eed3d4986,
+                    0x2da66351409d3453,
+                    0x1278a02908f14d63,
+                    0x141c48e4a1dae974,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb0f35ba6abd4c7a,
+                    0xc3cbe1753d39ccce,
+                    0xb936b9f791070ebe,
+                    0xf3e5b41592b71545,
+                    0xaf03064240f9472f,
+                    0x0ae151948ca20ddb,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 188];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger384([
+                    0x8020b23d0666707b,
+                    0x5fba3c7cd4ca1249,
+                    0x0b98516e6b6e7d19,
+                    0x2e77da34df5d9991,
+                    0xf704e77e9a446c67,
+                    0x0673287faff9f4a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x25b7c8d6119bbbcd,
+                    0x0352de072b5f972b,
+                    0x2939c9e0386e4151,
+                    0x7f16e1ee8f60626b,
+                    0x1eafa282018c7932,
+                    0x12543bc0cca50bb6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc2738a1c6de73584,
+                    0x1c46a5d91a7b34de,
+                    0xea0d10ec7dbe2194,
+                    0x109640f3111be47d,
+                    0xc70e15699748a972,
+                    0x14c29fcaf0000fcb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbeb1664bce13d1ba,
+                    0xea816fbd785d2517,
+                    0x233a1895115eb688,
+                    0x4048910b6e3fc630,
+                    0xc02a3e5b0ba0a98a,
+                    0x008f3ff9137b0ae7,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x73679125b90040db,
+                    0x9272385657697e1a,
+                    0x815def91c223fd02,
+                    0xdf8a770e6af33336,
+                    0x86936c4691aacba7,
+                    0x00334851100037c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7df0cdd888cf7d71,
+                    0x2cab53f6d6abbdd1,
+                    0x259da1537f18a995,
+                    0xb27545a27d43199b,
+                    0x3906cffdc54bcadf,
+                    0x1516e635ae4fee66,
+                ])),
+                Felt::new(BigInteger384([
+                    0x72a591d20306cada,
+                    0xd61aec7c1390991c,
+                    0x595018c34bd5edb1,
+                    0xf43af6e212c1bdb7,
+                    0xc862599483e1e804,
+                    0x1845c041dc1c29b7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x63432613e370b1ff,
+                    0xe43b87c2f0660980,
+                    0x883f5a4dd270cb97,
+                    0x956ef678f4464082,
+                    0x8fa249bed61c8cc4,
+                    0x179ff0f7a671f137,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x0690a547fc092b7c,
+                    0x44bf768e535cfde3,
+                    0xd68681b1863869dc,
+                    0xa4b63254b4a18816,
+                    0xbbaa26d14b6bb445,
+                    0x017d4a2138afe76a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x46671c64dd650f4b,
+                    0xfe5d61b670cabfe8,
+                    0x85403ccf6f7c11e2,
+                    0xb9a9ed7f38259e28,
+                    0x873ccc17c31c6ff3,
+                    0x0290cbd79b8931f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe8b7afc008cdd0d5,
+                    0xffc19d045ea314e7,
+                    0x1390e45cbe245f50,
+                    0x0f712c64d42ee729,
+                    0x2b3fdf8931acbaa7,
+                    0x14981b1e556763bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf21ff5632f09cf8,
+                    0x5faab06caf6626fc,
+                    0xfaf92167827086f7,
+                    0xa7d8c1c2c812892d,
+                    0x11822e1f52bf804f,
+                    0x10b8bd29bf1f5fd6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x2c0615311567c847,
+                    0xf3cd902cdc0641d6,
+                    0x1f188a0d693221d7,
+                    0xcf44d776c7bfe985,
+                    0x6df6cfa7e7e18bc2,
+                    0x01f1ac689c47b67b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x50387f9e2d956f12,
+                    0x3bc71588c9a285e0,
+                    0x27d1088c18039a58,
+                    0x6ebdcebc38eff54a,
+                    0x734811cd65967850,
+                    0x0815c5afab4b0808,
+                ])),
+                Felt::new(BigInteger384([
+                    0x841a014b25796c10,
+                    0x0d850e859efa7279,
+                    0xa82ca6b0ff0fa228,
+                    0x416d0be9d205dcd4,
+                    0x5f7f79517c81f007,
+                    0x0b3f1e1959e2bddd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x627df311751d2505,
+                    0xadfec08fad255dd0,
+                    0x3c07a51f8d6f0a39,
+                    0xa4c586b93280fb41,
+                    0x2926c9f600fdd9f2,
+                    0x02e7c9d056f3ff65,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x0c9000d315c6c593,
+                    0x988f0768bbdd539d,
+                    0x54f1874c59970d55,
+                    0x525162240a5e96bb,
+                    0xf83e4fbe6fb9f6de,
+                    0x04c1ecc79bb646e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52e5e194418e52f9,
+                    0x9c1af4f7d7dd1378,
+                    0x2a6be115f2d753da,
+                    0x7b67ad8fab78588a,
+                    0x59c7d7be025a3111,
+                    0x0d7b995a543ca43a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ea2286b78f2608e,
+                    0xac5882905fea9d2f,
+                    0x0755d9724f1ecd87,
+                    0xc51b53732615fd75,
+                    0xed11a5b9451ddc3c,
+                    0x198df749e2797fa4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcea76abbbfe1b98f,
+                    0x8bbb9434fad809ad,
+                    0xa2701632d618b2e1,
+                    0xf1d5edef0649cfaa,
+                    0x7b052da48ba1155f,
+                    0x10dafbafe0618266,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x600adf094ae3f9b2,
+                    0x6fb03c4259afee15,
+                    0x72d0422cbb5f8cbf,
+                    0x45f91fb7b04d3524,
+                    0x7dc2dec60cf4e2d7,
+                    0x04bdcd9eaf3241ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa0500ef9f570eba2,
+                    0x95fe1f41e8b57eec,
+                    0xb62ab36d41d31f9c,
+                    0xe986da054c1c4f74,
+                    0x049658c4079c5bf2,
+                    0x010d209d3df5b574,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0c6e498e2f4dddf8,
+                    0x1a6854094628cff7,
+                    0x902319460af15749,
+                    0xf196def41cb1e738,
+                    0x89a181e1e2bc66d3,
+                    0x199a641999b05c71,
+                ])),
+                Felt::new(BigInteger384([
+                    0x12592887e43cabb7,
+                    0x59babb30ae93e72c,
+                    0x2a1cafbab49c6cf8,
+                    0xab55cb10468273e6,
+                    0x922dbe93bdf098b5,
+                    0x0ef0461ef778ffe8,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x706c77bfe4005170,
+                    0xa74edade15843a99,
+                    0xfee0557827a81044,
+                    0xeeea8fab2777ee97,
+                    0x819a221de4e50c66,
+                    0x0476ec15d5f1ce31,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7f3b6f24091663d8,
+                    0x19ddba6a03a13594,
+                    0xcb10aad29d36a152,
+                    0x8d2e929ef7c43538,
+                    0x1a444cf9835fe522,
+                    0x188f734e800dbd56,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x67f3ee9a925e733e,
+                    0xaf42493596d123a8,
+                    0xf17fd37ce348f634,
+                    0x50909d0fffa40b93,
+                    0xace2d3bdc8649765,
+                    0x1386aee48a63d664,
+                ])),
+                Felt::new(BigInteger384([
+                    0x94cbd09448af28fc,
+                    0x4eea59af733fca76,
+                    0xd41e7e950e68dc56,
+                    0xa02b032e757960a9,
+                    0x690a0f90c5fff031,
+                    0x0fdc53ec92b654c5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe6675ef7dff07cf6,
+                    0xe7885dd7521e8a69,
+                    0x2c43e03fbbdb5c87,
+                    0x56a18ca357ec87c1,
+                    0xf7eb23aff6ea7afa,
+                    0x0b698d88a1916fed,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c58d0a8a74a03fc,
+                    0xa45befde0a6dbdc4,
+                    0x0717ae56d6eac814,
+                    0x7708bf733d8816c0,
+                    0x47346a5ac836ba90,
+                    0x168198176e37f60a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1f4cab2dbc1f1876,
+                    0xb9b484896f659a59,
+                    0x9cbd3eae9697059e,
+                    0x931f84b800a9097c,
+                    0xa48fd72489e5acc8,
+                    0x0d523b08b23f73a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ee129f7e10aa62d,
+                    0x9f4eff8f4bc28227,
+                    0x7a1cc9416cbaf716,
+                    0xf8cad7a519457041,
+                    0x11a1d870de2e64b8,
+                    0x00b1126b69852041,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x25f8d88661279a18,
+                    0x74ba0454b0560462,
+                    0x5b87f0fd780b9e02,
+                    0xf808f5d38a5f4a00,
+                    0x386fd7ce91c923a1,
+                    0x08f3427f3b348f2e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0b9edf15a35c029c,
+                    0x523e2f0fefd6a711,
+                    0xe1d3353e24abac5f,
+                    0x4fb54c875d2b9d0c,
+                    0xae42f39096ca0ce6,
+                    0x119533ec1868532d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb1f5c83e495c0520,
+                    0x3409dc52ad5f0ef3,
+                    0xa922d6403af019d0,
+                    0xf2a3e682bae77de5,
+                    0x8bdcf29db590aa45,
+                    0x140a0a02e7d28d9e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd803d599c888d2a9,
+                    0x12388ba03feebbdb,
+                    0x2170c2c278f7f5d6,
+                    0x28e4848215f4dd62,
+                    0x7d635a393c7603f6,
+                    0x0cba6210ee868451,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd596771a6515701d,
+                    0x9208be6927d74384,
+                    0xd54ae0f1165a5161,
+                    0x98980dc07d16771c,
+                    0x6e3a29a4b6ee0926,
+                    0x09c0da8a85c2af2b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f894f8a8fb79bff,
+                    0xf18ed0cc928a2c67,
+                    0x436f7d12f3446df7,
+                    0xeb5045cd2b2f186d,
+                    0x0c2c455f4a9282c9,
+                    0x17284ac5b95ceea2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xee5fe2cf1d868892,
+                    0x553761b062b55406,
+                    0xf743362a38efb0d9,
+                    0x9796e4406ff8d6cb,
+                    0x5fd0d595444fb3de,
+                    0x0024d755881101d9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7eb2c99cba3eb982,
+                    0x76c6114f679e761b,
+                    0x83508c89e429d745,
+                    0x28e713cc77751efe,
+                    0x79343f79e097e5d7,
+                    0x0f9e4ea21d42696e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x101821d891df0ba9,
+                    0xe4a02fc926011d1d,
+                    0x99a44c368f7bfc64,
+                    0x4b1e9cdbfc20e7e4,
+                    0xd5a4c11c4f99380b,
+                    0x1669ed8e1b63b6af,
+                ])),
+                Felt::new(BigInteger384([
+                    0x81ea072abb91847a,
+                    0x077255fb61238738,
+                    0x0eab1aebb1a263c6,
+                    0x9f9c3086f21df35c,
+                    0xc7202a3c75366609,
+                    0x0797777175a2604a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x53277cdb0f1f16cc,
+                    0xca90d5412f29c14d,
+                    0x6ace5fc4b9c10da5,
+                    0x1a118d84c1b2019e,
+                    0xb7a8e306aba5d154,
+                    0x18f08b3c9563ec52,
+                ])),
+                Felt::new(BigInteger384([
+                    0x97144b15e1f0d60f,
+                    0x8baf423d91783530,
+                    0x1b826fcfbc51556f,
+                    0x7812f63649747410,
+                    0x6310f85a122dc2bd,
+                    0x08b90db3c43c207d,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger384([
+                    0x7d55bb2e0590a2b1,
+                    0x692b39542e114101,
+                    0xab14f0c22e621519,
+                    0x057c03631d21472e,
+                    0x0d07a69b7a8b56c3,
+                    0x11be006cfd3a73b1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x419b95d4a6d059e7,
+                    0x0eb9cd231797e2c2,
+                    0xde061338819b4910,
+                    0xd646702a7ae8b85b,
+                    0x8cf9b2a1d1f73447,
+                    0x06a418779e84645c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c6586bb2e8b8922,
+                    0x3b5bb8117749a38b,
+                    0x3f511e98fd99b50b,
+                    0xbe6342f4a173f6e7,
+                    0x4e11ccccc8a7d539,
+                    0x0de24a3f947261d6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa79baf9767bcdb8b,
+                    0xce1b93c4e88b5211,
+                    0xdda247c9875bcf62,
+                    0xf1240d578c8fdfbb,
+                    0x70a3be47f71b6a2b,
+                    0x10cc7f0fcab8ae1a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x2b395f2d0f120e26,
+                    0xdddf9d4967909b2d,
+                    0x82f7410eb3317276,
+                    0x9532aa8695559d51,
+                    0xd7090446c7c7e6fd,
+                    0x1444731fa316dbfb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1b4683b06babd23a,
+                    0x8f33a38082a39430,
+                    0x2ece70face861111,
+                    0xeacdb199d05d6c5a,
+                    0x7b77f5ac447cf842,
+                    0x0e8ab7e8311e26d5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf8ee97e8388f9146,
+                    0xe6a82a59f3462ff9,
+                    0x409a064bf53bebba,
+                    0xa3dbe9f46e087470,
+                    0xc34ca964c1860795,
+                    0x10a6d74ba140cfa8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5d3d5a8b50cd4c62,
+                    0x0ff3a628bb87d346,
+                    0xd16ee41e3caaa1a6,
+                    0xbba8a25f65e54561,
+                    0xbab6519a027e9b12,
+                    0x02444485b8fd5ce5,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x9b6a435c96b6db43,
+                    0x82779f4215fa3731,
+                    0x4fda2e77b946b51e,
+                    0xebfc608487de9fd3,
+                    0x0f4270cc1c0387b5,
+                    0x01279fef8fa02983,
+                ])),
+                Felt::new(BigInteger384([
+                    0xed0e812c0f921b88,
+                    0xbe82be803ecebcce,
+                    0x4b04a8dcbe36ccb9,
+                    0x57e05f1dd2ad4caf,
+                    0x024bbdead497341f,
+                    0x172815c5f4ae367b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x57cfaf1e7fcad275,
+                    0xdb0521b4a01d946b,
+                    0x9c6360b2214d1b0b,
+                    0xbcd6aece85935c13,
+                    0x3d757d318fc23cb3,
+                    0x1161a100cb9f9880,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbfa475833c7beb70,
+                    0x17549fe79a192427,
+                    0x6c4f34d3f9043dc4,
+                    0x00ef18bcc62b0fda,
+                    0xfed31543aabcd441,
+                    0x0efe8538482873ae,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xdf0264ee39368fe7,
+                    0xb7169109bee23b3c,
+                    0xc47a8da62108e7c2,
+                    0xdfaee38018b560ff,
+                    0x597e9729801790bb,
+                    0x12605497ea85289c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x53c869b0f8aea633,
+                    0xca6a73df90db575b,
+                    0x5acf1171672a3016,
+                    0x52dfcae6b6c8e99b,
+                    0xe435b88b4cc0ab3c,
+                    0x0860d8fedd09a967,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfd251d5d881abbfd,
+                    0x7a4170ecf13f0222,
+                    0x753ff786e70e8afb,
+                    0xeaaabaa47aa2cdb3,
+                    0x424ecc09d81d66b9,
+                    0x0aeea77f0f34c287,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfaa1f6168800eb87,
+                    0x09d636faf7b9ced3,
+                    0x900dc1f7751c62fb,
+                    0x7f78cf65d5c3c79f,
+                    0xbafa302301807366,
+                    0x080a2109420d6c52,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xaff279d33ca8cb7f,
+                    0xb819f9decd739d40,
+                    0xfed5b9497c6c7b3a,
+                    0x7ea951044278f6b8,
+                    0xb5c22db7d6df4c46,
+                    0x09c4252401264371,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc80eb4e917c3e05a,
+                    0x714ddf4564d42dec,
+                    0x18463462306306fd,
+                    0x54430b430a7aaee6,
+                    0x187d12888dcca494,
+                    0x159c45513c1ade7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5923bd3bd1250c62,
+                    0xa19c5892f2f31225,
+                    0x215724d7fa3d300e,
+                    0x921cd4e3b1da9e32,
+                    0x2e36418e5806b426,
+                    0x0d9dc2934083da99,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1da1099d499ddad0,
+                    0x68e0644b1933d036,
+                    0xc53f8fa9fe2104b1,
+                    0x4db21ca3ce35a148,
+                    0x60b47cb1f2404425,
+                    0x0db4b9dc5eff9ce2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xa623f1e2badf65ba,
+                    0xf973a05cb753b636,
+                    0x400e6f2d7073be15,
+                    0xb9080cd763868f55,
+                    0x18af40818cf18e6f,
+                    0x0c45c95cc72e99fa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb356a51debe522bf,
+                    0xf700de72b92b9db5,
+                    0xb94037927db514c3,
+                    0xb8167ed4351aeed3,
+                    0x647f5ff694360196,
+                    0x0123ed0480d2e36f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x87787914c539b477,
+                    0x37179e65118ff2a3,
+                    0x4510e273b710b347,
+                    0x8b78bab67ac72e20,
+                    0x58d75e782452fa43,
+                    0x176d68993ce0df1b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2140428f58f42a0c,
+                    0x8064aa90a994ec47,
+                    0x7b7b6bfb0f3a3176,
+                    0xc107015010782f81,
+                    0x080893937b0686b1,
+                    0x00d9c4bfda728e8f,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger384([
+                0x35a8e6e3ed170a9d,
+                0xa280954967d1702e,
+                0x62c02da9ce2dbb72,
+                0x17a1d6c52bb71111,
+                0x50c2c76124f944b2,
+                0x03054d7a1c7fa4ed,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x42c0bf2edb0df18f,
+                0xdf80a2e658bcee1f,
+                0x5e6d7f70fb00dc66,
+                0x8c4454b98198597e,
+                0xcad13b984b18dabf,
+                0x0961f0e6e39a448f,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x88c12fa0873ad647,
+                0x6d384db6ab38482e,
+                0xcc2abbf59c152e78,
+                0x69330091a1ef8bc1,
+                0xf403e6547bd588b3,
+                0x07ea13b5d6497f5d,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x8e2dd5259d29bea3,
+                0x59038418bb281c80,
+                0x16da07f00351fcb5,
+                0x8bea5c5d19ee79be,
+                0xb631af9568141181,
+                0x0e034d741bc493e1,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xce55c38dfbd7a3a9,
+                0x3b39997f4dae9ec5,
+                0xf02c297d0f8b41e6,
+                0xf5d846961192be3e,
+                0x570fe932ff000e2d,
+                0x19054b3a8a7c9eae,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x3a38fdc5904e41ed,
+                0x120e6df1d94ef0bb,
+                0x975d81cb351c0edb,
+                0xf63e98c25c9eb12c,
+                0xa87cb80b3dca4c95,
+                0x0f690f9ac4605cb3,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x0cb075e98dd7576a,
+                0x2ef0fddeb5eaa853,
+                0xb360c9ca5d7e291f,
+                0x0df6c20a043f7a74,
+                0x54b93a0a5c32c111,
+                0x057142cd8f0c1dba,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x83145b0d11975185,
+                0x53a9a6c5774f56b0,
+                0x1d15bae1a186869a,
+                0x04306b1bf599279e,
+                0xac6e1257031abb90,
+                0x18ab3195d6c7f0f7,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x1d82e8fcdb108511,
+                0x92ab401f3f749c93,
+                0x114ef863a807e1ac,
+                0xa899bc9fdce9cd00,
+                0xc302db9560654302,
+                0x0ead5b23d46b6239,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xe31ee95c7dabdc3a,
+                0xf40b74cb7b3721ad,
+                0x7bfe51cd296ef5c9,
+                0x25df02169eb31639,
+                0xf0a575a1805cc18f,
+                0x15abd75bb792bb59,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 94, 95, 287] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 94];
+        let mut opad = vec![0x5cu8; 94];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,1296 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 192 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 2 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 2;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 22 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 22;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger384([
+                    0xd4f7eab0a304f836,
+                    0x9095c432e853a3c7,
+                    0x322b31e5343ed14a,
+                    0xc9787d9b051db8fb,
+                    0x0f68ea21f0586620,
+                    0x11717ab1e7074a7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ac278b666f6dd73,
+                    0x155afc060ca54ac8,
+                    0x2f548e20a43c01bf,
+                    0x131d4ee53c5ddf68,
+                    0x8cfae26b60399c42,
+                    0x0e7e72d32bc31f33,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4934f0b959483ff3,
+                    0xd5b8bf1e4260042d,
+                    0x447acc3de75b5478,
+                    0x1b0def90c22c7b02,
+                    0x6ce8430f0f12dcaf,
+                    0x1607aefebef0aa97,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf94a84d88e465134,
+                    0x900f9e8dee7c932c,
+                    0x310bc1a2f71b9799,
+                    0x8b175c5349b5e82f,
+                    0x674023417d3229fe,
+                    0x12cd4c4c61fcfaef,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x90b3469e6f06d186,
+                    0x84f918745951378c,
+                    0xa57ae41489d57aec,
+                    0xfb0b95e6efb8a225,
+                    0x997650b1d99aa1fb,
+                    0x025d943598fbbf3c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x112f9486b428c599,
+                    0x474d1227040c2fc9,
+                    0xa0472f20b49265d4,
+                    0x5cc057f7274df034,
+                    0x6d4d236c05d87185,
+                    0x182da3dcdb7dd2a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x50a6d8e8b43c20d5,
+                    0x117ea3391c41c015,
+                    0x40d398108c9f083b,
+                    0xdb3f063bf56f8f6f,
+                    0xb8b792eb416afd5c,
+                    0x05ef971b0119650a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb22a6233fd24d9f3,
+                    0x06856b0bf91e9613,
+                    0xfb5660e842428a8e,
+                    0xfdd8ec0f01619e9a,
+                    0x2d14817368e24acf,
+                    0x0ff2ec9d8fb48345,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe77f51204c88c599,
+                    0xecd11fb14d1a5da0,
+                    0x51e34246dc902bdb,
+                    0x4d9444c06c74f271,
+                    0xb451c47700fe87f0,
+                    0x0bd6e24065bddc08,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17e8fb9faa13f7a3,
+                    0x056b227a302eb927,
+                    0x3cc494dd69994582,
+                    0x0a22ff4531bf1a14,
+                    0x1f2f1d6a99f23ee3,
+                    0x12348a5ada9271b0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9d6f857536940a3e,
+                    0x144c0143cafa8a19,
+                    0x0e68765f79117522,
+                    0x6baf29dcc2d59f0c,
+                    0xa49f6cf11dc83698,
+                    0x09609d1668d3f13d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbde46246fe3529ea,
+                    0x078c74e3d19b7f7a,
+                    0xfcc17814c3fcbb34,
+                    0xfddf7d70d8aa067d,
+                    0x0f2688b7094833a1,
+                    0x171f2833889a5b8e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x31aadae9754ce685,
+                    0x8a5476b81167c850,
+                    0x958ed66f842331f3,
+                    0x57e422214909e87d,
+                    0xeef9c5647765520d,
+                    0x019c861dc4f98e34,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31dd4bcaa04a873d,
+                    0xb07363de7fe67806,
+                    0x654b9fdef20de96a,
+                    0xb720b94154980482,
+                    0xcebf310c4d3a7ca5,
+                    0x10dbd06743920e8e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93e9d14ad9019eb0,
+                    0x2249e9757784bdfd,
+                    0x494b75c1ae67d4e4,
+                    0xd42b06a2084ecbee,
+                    0x5e0ed7035412667c,
+                    0x1818f846e58e5f08,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfda8f281d7308970,
+                    0xdd99292be72b04f3,
+                    0x166986cb11c0f6c4,
+                    0xc1243b74657e0cee,
+                    0xec5132154a83f8a2,
+                    0x1862bc2a00ab4d36,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3f79e2fe71546bd4,
+                    0xf778a5101cb45348,
+                    0x58ab4bd0f2c4eb6d,
+                    0xb5822b51fe7fbed9,
+                    0x8c68f295244a2d3f,
+                    0x09b8325224c6eadd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77428217a5bde00f,
+                    0x0467906800ea75a7,
+                    0x86c8377750fd38c6,
+                    0x680060bf01adfe78,
+                    0x7cb02eaf21cb391c,
+                    0x0a28076f7cb8f6e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf061c47550aa5abb,
+                    0x139a3d15d91c38d6,
+                    0xd5189821b79485ae,
+                    0xc3f29226646435a4,
+                    0xdd3238c27a8d5bc9,
+                    0x0881d046414af27e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa25a06b1dd01126f,
+                    0x1de2e9f9697de9e6,
+                    0x1888f2d91f39e830,
+                    0xc52cac415fb77b61,
+                    0x1452d67dccb66e6e,
+                    0x0e9bd70347082e3f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xcc0f1a028daf6255,
+                    0x77eb1a9036f7f24b,
+                    0x8362af32154f5c38,
+                    0x22ffb2a3b98ba66e,
+                    0x318ead6e1c8f37d5,
+                    0x0f31cb4a647593f2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17f3b35fcfda145c,
+                    0x8c57808a449bee98,
+                    0x3e41f18ed136f56c,
+                    0x60b4c5c5a7724c0f,
+                    0x7d23039e38aee927,
+                    0x166d9a810847b7e3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa8404c5164056aeb,
+                    0x0e3239ea055fabda,
+                    0x296c57fc6f2a7e6b,
+                    0xd51f074af92343c6,
+                    0x3b543dc02e96f64f,
+                    0x0628202302e008fe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55d072a999aec7cb,
+                    0x35271313d9af6f9b,
+                    0x063ce4bb331c1ac4,
+                    0x76b783f6ed970990,
+                    0x587e0e5f03149f34,
+                    0x07b8b0446e715200,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf99e1965c6415219,
+                    0x2c3b16f708f4d288,
+                    0x9a6716d4377b4a5a,
+                    0x8f6b01c543d06bd3,
+                    0xf66c989da5512894,
+                    0x06ef9162fcd4c850,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76737d8b422bdb19,
+                    0x7cdcfba48c3fd410,
+                    0xfd74716cf2fd564b,
+                    0xf4c6328936d83bc8,
+                    0xa857a7627eda4c5b,
+                    0x01543bea74b12bf7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1385b77242ef7233,
+                    0x6a91c140ee5cf20e,
+                    0xfa3f58e43b261816,
+                    0x3fc1bc24a6e3379a,
+                    0x7d2c5bbd9f0b0157,
+                    0x0252b88a086fcf79,
+                ])),
+                Felt::new(BigInteger384([
+                    0x182706ad4ac413de,
+                    0x6e8355a734a89826,
+                    0xa4e82d93a606f08e,
+                    0x8afdc15a04f5657f,
+                    0xbdc1b1434955ac9f,
+                    0x1556e8dd2f54dfc2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc05deb28b9aa40e4,
+                    0xdb60ef9120c69b02,
+                    0x547cb26e93563e11,
+                    0xccbd887fea0fe3c4,
+                    0x841ae0513db490c5,
+                    0x1317bd3fdb81b8b8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x95d48865a8c29159,
+                    0x89898e472f30589d,
+                    0xc438cff9fafe65fa,
+                    0x20775b5c805d3a95,
+                    0xb0553415268e55e9,
+                    0x12eab5d59925e36a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x74b7c9c16f96cb23,
+                    0x31ae8e734408380b,
+                    0x5390b99c8f153809,
+                    0x2a5eac842bdb7342,
+                    0xb425032409fd9b1a,
+                    0x1576f629a5000906,
+                ])),
+                Felt::new(BigInteger384([
+                    0x64a29bfdb5891f2a,
+                    0x065e890de53f38a3,
+                    0x2f0f69d5e558c225,
+                    0xc4dc256ea030327d,
+                    0xba08fbb55b8b5e68,
+                    0x0409b822863b0621,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x17b8730de144b90a,
+                    0x26c80e996859dd02,
+                    0xd8afbe307750ce0e,
+                    0xa23cb495a67892ca,
+                    0xb69d7243e3cd0c74,
+                    0x05ef30f936571f16,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe22c0dfc0f1747ff,
+                    0x36b5e515748a8fa3,
+                    0x543138c358d751f6,
+                    0xbe184384fab51cf4,
+                    0x9109f6ab8b5c2613,
+                    0x08bfe08e11f57abd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2af5d093f930fe7c,
+                    0x414e086e0a6e77a1,
+                    0x9f03ac71f3106c85,
+                    0x8f2ccd525205f494,
+                    0x4a3749eb2dfa72e1,
+                    0x0927401d64eb6f68,
+                ])),
+                Felt::new(BigInteger384([
+                    0x129b2e5bb0a2e05d,
+                    0x4cb38d56825f100a,
+                    0x40bff1ba098d1512,
+                    0x3b1a7a61475bf9e9,
+                    0xd68fd7465523d52e,
+                    0x07de3080379efc95,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb0c4104ade0e37cc,
+                    0x6468f8806aa3d4ee,
+                    0xc27cad9cd7a15bee,
+                    0xbb35d05d73737419,
+                    0x17f73618530509b1,
+                    0x0d79ce1770489fd1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x77e70f9369af90c2,
+                    0x885d85b633cb3b94,
+                    0x7307ea5f22351d4c,
+                    0xf3d708caac253af5,
+                    0x37b53583cd0cbdef,
+                    0x0019f11163ea1465,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9a9e217070595cc9,
+                    0xcb5b10b12434429e,
+                    0xc57fad8361583a2d,
+                    0x581883e1183c4763,
+                    0xea56bd92dea6ccc1,
+                    0x1372f3f0332848e8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x84d0e593e1ac7029,
+                    0x97d138f592a6a416,
+                    0x69e03d4dd2111828,
+                    0x4a0b4548a88b305b,
+                    0x7b0bd55cc6a64fb0,
+                    0x18d0904e3c9d1d98,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb6f5d759fcb77368,
+                    0x9308ebacb5f84156,
+                    0x1f9f08023d01341d,
+                    0xfefe5c7144c23e7d,
+                    0x9032a0936c65bdb5,
+                    0x05106429fddb95d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa09339a105ab0d9,
+                    0xdf1fc1d593fb57d0,
+                    0x372933424e1fe978,
+                    0xaa75bae63da26044,
+                    0xe4a2833027abbddf,
+                    0x0fb4912de15c034e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd2e7a171d1f5263d,
+                    0x5a6a96171d3f9f1b,
+                    0x57ab946aa64c9c6c,
+                    0x933393e69678bf69,
+                    0xd2966e1bbf5680f6,
+                    0x0658b414359a0e23,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8e689ed4e4e2a903,
+                    0x525c67666ea6200c,
+                    0xb13e90765fe40128,
+                    0xa8599eec9f67aaa2,
+                    0xd35db6bf9ab6c9e3,
+                    0x0fc8848239d0c1a2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xcea33d672e4821d8,
+                    0x4c204eab9059c2b0,
+                    0xa76ffa84ccb95452,
+                    0xc627c1d8aa4bdcb9,
+                    0x8ad045cac43f5051,
+                    0x0f40483ee2dbffa2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe568b9e781f6e313,
+                    0x11951ab044de94c5,
+                    0x32e17ab44d354d47,
+                    0xa1234e2ed0622e14,
+                    0x87f79b3d6a344e5f,
+                    0x0ebe7cc59bd6d05e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d32f12cd8e24c3c,
+                    0xdf833955c53b3d6b,
+                    0x05f48f7d31e7845f,
+                    0xd622bc18be16c069,
+                    0x0ed047f8c6ee53e9,
+                    0x18323b04e00d4a68,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0cfd9ab0f6027549,
+                    0x3cfa9f6ce9b7d86d,
+                    0x83e7350e98b30d94,
+                    0x996f143d89fcffb1,
+                    0xaed5d66d3684b18c,
+                    0x0e1007a6d53b8a46,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger384([
+                    0x0f13dc7342d9c766,
+                    0xd8d9682e7fddc6fa,
+                    0x0204f6b0351374cd,
+                    0x4a04089047eb4a2b,
+                    0xba425b4974fa21ad,
+                    0x18688765189d2321,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd4f6938c6acdd327,
+                    0x0e2bffeb29abbb5c,
+                    0x7f4ca5a0e444547b,
+                    0x7ac62ececa6ae126,
+                    0xf05556ce4e3985e2,
+                    0x1257bb76b3104e51,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf9a2f21259ba53ec,
+                    0xe61d7a209274d0b1,
+                    0x45f632f43d6d36ad,
+                    0xed0f55b882f9ad52,
+                    0x5947288a9303bbae,
+                    0x185fe652f53775f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13998e388844cab8,
+                    0x4b3a7dccb00ad3b4,
+                    0x8eebdea768368874,
+                    0x2f4a245ecfaf1678,
+                    0x478b777dffc5acf8,
+                    0x13af38e25dae8eba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x682a8d4eb1eebf8d,
+                    0x31eb1891b3169d47,
+                    0x0b8143b2406b2a95,
+                    0x8aec327621e16054,
+                    0x3bd317072963f0d7,
+                    0x01c0dc77bc2bb9d1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2749cb969d738eaa,
+                    0xe81d0aa203589a35,
+                    0x942c9a1426f8fb59,
+                    0x5220ab44ab0fce25,
+                    0x4678e120986c0f47,
+                    0x0ff8c0c9c441202d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x00cbd75bc7957342,
+                    0xd320e9c7aa6aa649,
+                    0x3bb20aedcbca9fb0,
+                    0x319920e5de7e9371,
+                    0xe9a20a218722f4c6,
+                    0x0a26dc0cd07baa1e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6a9c625a9df1597d,
+                    0xfadd4c4ed30e5e4a,
+                    0x355a7d426a2d0a5d,
+                    0xb4d794e0df1af8de,
+                    0x8cc6e8ea0b53dcc1,
+                    0x07838662ff220d43,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2e78bdcb34bdd4d4,
+                    0x84e5708801262f72,
+                    0x33bf1c70c7e9852f,
+                    0x741e29e5369484de,
+                    0x8e51237b9d1d01a5,
+                    0x01a457c291235a74,
+                ])),
+                Felt::new(BigInteger384([
+                    0xff718b69d0e06404,
+                    0x7da1f439c17c202f,
+                    0x2d47779587ff6a7d,
+                    0xb5a4ad0093fb2106,
+                    0xb4e9a500254342b4,
+                    0x0b55da0d8802750f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf3468312b80f442,
+                    0xfc633601e0038ffb,
+                    0x755a18f2aaec305c,
+                    0x87ff15aa4bb29815,
+                    0xc8af992f3f80d61c,
+                    0x0122b7cb045b84fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x821f10d5af4f14b8,
+                    0x0094c449303000a4,
+                    0x9d4a8edc99e26db6,
+                    0xec9f70da70be0a3f,
+                    0x8c497038af1cb505,
+                    0x053749e3365710eb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd04ecd0c4aedc5a0,
+                    0x4f54e6a44e6ac530,
+                    0x677901a46a4027a0,
+                    0x10ec69edd13ad9c2,
+                    0xdd64436a45e64f39,
+                    0x17d36ead6099542d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4f34943628f10c35,
+                    0xd838ce183203fead,
+                    0x26cfbc727429f8ce,
+                    0xa71a8b823d18cf36,
+                    0x4aceec5d125de6c2,
+                    0x0be88a9cbdf0f9e5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x569e5f4dbd627149,
+                    0xbafe1214fe6dc513,
+                    0x2a4cf3e05c0afae0,
+                    0x0015175655deaab4,
+                    0xcfa87223cfe9d0bf,
+                    0x0f8e6c6710707499,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfdb4a4d7097760fa,
+                    0xa1f9a70941580504,
+                    0x88a39b6a1a463e54,
+                    0x7233923bca11d325,
+                    0x122ad922211169af,
+                    0x05e4583790bc8fab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1367fbbb0771279a,
+                    0xce63d4cf831d25b3,
+                    0x55a7588573906bcd,
+                    0x98b8997f4bbfe95c,
+                    0x75e5aa387b1fe60c,
+                    0x0bfca557970f53cc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xffdc304c7a26c66c,
+                    0x83a2ae5d6bc9a21d,
+                    0xad4cf2d55c9eb622,
+                    0x62689e3feb517ec3,
+                    0x5e6f79b9fa9e870b,
+                    0x124fbf54f4119b8f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb90965df71125620,
+                    0x924667166d74f091,
+                    0xca9d362b5c0cd581,
+                    0x1352d816d964153f,
+                    0x475a03a7e3665477,
+                    0x0a664c678f9aae3c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5ab573808a4f664e,
+                    0x4533b81e22ee89f1,
+                    0xd4e314c929600465,
+                    0x1f9da19a238d0cd2,
+                    0x355cc38915fff8e4,
+                    0x0f9ddea6e1233306,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0db5fd55f4c5eb63,
+                    0x2f8b4e54fda7a3d8,
+                    0x5ac05b0260f3131a,
+                    0x459f9540fb1a1e9a,
+                    0x83c1295346326c8a,
+                    0x179751c148bf86e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfdd6bcddfb0fc793,
+                    0xb824b2aae94b73e7,
+                    0x8abaccbf68ac4e2f,
+                    0x18b6e757487b226c,
+                    0xf6afa54cd1a0de4c,
+                    0x175b992d15d6edb3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x08993778a08be5e8,
+                    0x74a155ac2ab62475,
+                    0x838eb2ba0653eb24,
+                    0xfedcdffca0d70a0b,
+                    0x551cacdb45106756,
+                    0x05dc12df65148bfe,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc916252abca9ac86,
+                    0xb8e9caa6308f7dd0,
+                    0x554ae398d3d9d3f9,
+                    0x554e05180e607362,
+                    0x7fe45bdb7620519c,
+                    0x0a2d8ad32a8d9c3c,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger384([
+                    0xee1d00000009aaa1,
+                    0x86840025e97c0007,
+                    0x4f7823c40df41de8,
+                    0x9e7c71f069ece051,
+                    0x7dde005a606d6b99,
+                    0x0de0f8777c82e085,
+                ])),
+                Felt::new(BigInteger384([
+                    0x984400000016aa94,
+                    0xda500058e5b00012,
+                    0x97080d3e78fe9e67,
+                    0x504ff0af50a70528,
+                    0x0ca773961fe616c9,
+                    0x17b73dc8ba06bf04,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee1d00000009aaa1,
+                    0x86840025e97c0007,
+                    0x4f7823c40df41de8,
+                    0x9e7c71f069ece051,
+                    0x7dde005a606d6b99,
+                    0x0de0f8777c82e085,
+                ])),
+                Felt::new(BigInteger384([
+                    0x984400000016aa94,
+                    0xda500058e5b00012,
+                    0x97080d3e78fe9e67,
+                    0x504ff0af50a70528,
+                    0x0ca773961fe616c9,
+                    0x17b73dc8ba06bf04,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0xee1d00000009aaa1,
+                    0x86840025e97c0007,
+                    0x4f7823c40df41de8,
+                    0x9e7c71f069ece051,
+                    0x7dde005a606d6b99,
+                    0x0de0f8777c82e085,
+                ])),
+                Felt::new(BigInteger384([
+                    0x984400000016aa94,
+                    0xda500058e5b00012,
+                    0x97080d3e78fe9e67,
+                    0x504ff0af50a70528,
+                    0x0ca773961fe616c9,
+                    0x17b73dc8ba06bf04,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xee1d00000009aaa1,
+                    0x86840025e97c0007,
+                    0x4f7823c40df41de8,
+                    0x9e7c71f069ece051,
+                    0x7dde005a606d6b99,
+                    0x0de0f8777c82e085,
+                ])),
+                Felt::new(BigInteger384([
+                    0x984400000016aa94,
+                    0xda500058e5b00012,
+                    0x97080d3e78fe9e67,
+                    0x504ff0af50a70528,
+                    0x0ca773961fe616c9,
+                    0x17b73dc8ba06bf04,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4503038c1876185e,
+                    0xb7d96807708d3db4,
+                    0x323c9cb0103a317b,
+                    0x76a1cf23f5b6e6f9,
+                    0x04b5b9798ad5d3c3,
+                    0x0915da7e0bbdf291,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa4fd9aa49bba5938,
+                    0x5f32cffb597236c5,
+                    0x7c950c600e07c14e,
+                    0x03928191c2539c59,
+                    0xaea5220b20998092,
+                    0x0a825e88910c4cd9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92e1725d3bba1d3a,
+                    0xda1d7210724c7518,
+                    0x4c769f4df5af0987,
+                    0x407a38c5ee984b9e,
+                    0x63e279269f35caa7,
+                    0x106ce1b3d51dad6e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xab67d6ccd12f390a,
+                    0x5d005e441465bae3,
+                    0x1081cc4e3b695d75,
+                    0xa515303a79201f10,
+                    0x8ad4cb6b4ad7f74e,
+                    0x053785e62c73039c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfcbf247becd63236,
+                    0xe3792dd70873d1b1,
+                    0xcca9a53997ac2b24,
+                    0xcab63d7a847be9df,
+                    0x7da9319216f0628e,
+                    0x07b14c210b2e1391,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66c9148e7720486b,
+                    0x9063665162ec3d99,
+                    0xc64f11e65fa05b7f,
+                    0x8315dab4c0828f25,
+                    0xf6af9c8e8301278d,
+                    0x055a4721a11d60b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb23511122d1c9556,
+                    0x82731fdf768faadc,
+                    0x458dc07d0b11539b,
+                    0xb3928b27a8930d01,
+                    0x14ef5576d64e1976,
+                    0x01d02c9266997ae7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6535f98021ce9dee,
+                    0xd80729869789fc02,
+                    0xc6cd8be7e1ed46e7,
+                    0x98be37352fa4ad73,
+                    0x1380b50f33bf27b3,
+                    0x0dc735319dae9fed,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2d5bd49ed67e9cdc,
+                    0x802958fb841e6fd2,
+                    0x8e4e0b9bd7e85a2a,
+                    0xdf6783e65e8ac6ea,
+                    0xf8246d7be7a3870e,
+                    0x18500bdda1284493,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe62b34a77dde4866,
+                    0x409ca6336710ffd4,
+                    0x7b81e98b4a6e328a,
+                    0xab851dc36a06895c,
+                    0x0efb308b6df2f723,
+                    0x07f3cdf457533103,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe087e1380650fd3c,
+                    0xf95b304cf037209b,
+                    0x87fec0c1efbace6f,
+                    0xfc9d9c2f08233a6a,
+                    0x1da8a2972e1e613e,
+                    0x077cb9793f0e1ae5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x70442aa13822eeba,
+                    0xef19969bc071d133,
+                    0x85579a768a61cd3c,
+                    0x813a4e085bf90cea,
+                    0x0400de5d9bbd989a,
+                    0x101c2abd8277bac7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb4b8f5789cd0335f,
+                    0xe11a82d6011ec28b,
+                    0x4de7a7e85be32319,
+                    0xfaaa356d57e7656f,
+                    0x27e6746e27566fe6,
+                    0x15a371fca2fb615e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44a87f2762921d9d,
+                    0x5d15d3c6d19983c5,
+                    0xf43d67013e8e52ba,
+                    0xd3805f5305dd7495,
+                    0x046485ccda736ce1,
+                    0x032d4ac190e7ef6d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf0f26372843c98e1,
+                    0xf949cb348cdf8f2b,
+                    0x760cb089dbab3df1,
+                    0x0de67563824a15ce,
+                    0x666015b37d995e56,
+                    0x0b001f1b781d9244,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7e842632c5dbf860,
+                    0x8ee5a87f66d8e36b,
+                    0xaf3582531cb080a0,
+                    0xb76ab69866edc391,
+                    0x514cf5d487d0e093,
+                    0x0b8d98b3c72bb288,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x59215c53fbbf09c7,
+                    0xb6fd318ba95c69ef,
+                    0x49106b8f361ce1ee,
+                    0xf9128a7a2eddd424,
+                    0xe7a8f5f62d11474b,
+                    0x169b121745b2a450,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3e20e8f471a584a4,
+                    0xb44511775bda75fd,
+                    0x710c24b1db768db7,
+                    0x8b9f1c2a6203018d,
+                    0x978a1639ce29bbf4,
+                    0x0b83bfaf0c7716fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x12c93f3f6c7467e3,
+                    0x4b14864c4c846b15,
+                    0x02ecae7eeac8b944,
+                    0xe1cc0642e2d02493,
+                    0x78f523229980f4fa,
+                    0x0a69658bc6d8a8e4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x249ce45e49fb7b3b,
+                    0x09c373b05529c6bc,
+                    0x6945c0883aed51e6,
+                    0x72739917ab7f4ba6,
+                    0xee28a236d31c9195,
+                    0x05380594e3cc196a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x95657711eae62533,
+                    0x627cb3ad6d968ba8,
+                    0xa1d44f3f44e9c331,
+                    0xae1ecce5a5063df4,
+                    0xdae9248062dccf73,
+                    0x124c6047016d9513,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb4a3ab01d0dcbca3,
+                    0x3fc61a0861d08b39,
+                    0x0001c5fc051de84a,
+                    0xac05ea18ab7d78d7,
+                    0x164a9ee110c32384,
+                    0x07f235e6a5b24aa7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xda48941bfdc17856,
+                    0xa22c75fe85fbc6ba,
+                    0x5c68490ce081aa42,
+                    0x5307c511500e8779,
+                    0x2a1db5920041204a,
+                    0x15e5b091f4b6b439,
+                ])),
+                Felt::new(BigInteger384([
+                    0x032b5fb09c0f2be9,
+                    0x9a4e41aa8559b1eb,
+                    0xd52e7232d0a64985,
+                    0x40751e9a4d6f063e,
+                    0x5e3c70490246fb14,
+                    0x17a6621915020dd6,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
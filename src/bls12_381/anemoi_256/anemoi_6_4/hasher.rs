@@ -0,0 +1,2449 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U4>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger384([
+                0xa0bc24783d2bd970,
+                0x70e09de60e838fda,
+                0x9f0c6f16a4482fbd,
+                0x7c041b2e37ed4984,
+                0x04e4a3a77b8f5952,
+                0x103e6866b4a70870,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0xc95d1b62b269323c,
+                    0xce29565371588bb6,
+                    0xccf5c85718a19544,
+                    0x6365985870efefed,
+                    0xe77b94c83ec32008,
+                    0x0c50218c42577e7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x76f487717eb7f8b7,
+                    0x2375ff42e97bf21d,
+                    0x7f8c1f19c987be86,
+                    0x2e45cff22dd30f2a,
+                    0x17ca2fe7694d885e,
+                    0x1516c1ba17491de4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xbad39d4ec20f7fc6,
+                    0x40b761d58e343c25,
+                    0xb9aa49c6a24d28ec,
+                    0x7413dcef57bac558,
+                    0x2c5f34d472c110de,
+                    0x0b7324a432f08419,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf3f512f35a4982a7,
+                    0x4618068a488e81d5,
+                    0x47fb6adbae9d1d92,
+                    0x0932842da41fec2d,
+                    0xae09a63dc6bdf4db,
+                    0x103751f776d6692a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd65a6e21533e7252,
+                    0xfa1e353edc1014fa,
+                    0xe049ad63a6e4ff62,
+                    0xee66660d10bbc64f,
+                    0x2302344f27607703,
+                    0x05cf374de6ca0118,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x66bf09487543b716,
+                    0xb295b397160c6a19,
+                    0x3d9692de515d9efe,
+                    0x5c9b0e07c0753837,
+                    0x78b79ed558a43467,
+                    0x0e8b19e62cef21e5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaed61de3ec9c6ef6,
+                    0xb1a67656b84d8097,
+                    0x5bc545966d2b970b,
+                    0x4defd30c6845a697,
+                    0x7fcd8276029147c0,
+                    0x15a08364c0c919c2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x96da9732e0b2050f,
+                    0xf985bf0550b371dd,
+                    0x196da4e8a435c99b,
+                    0xe52b388d9d305e5b,
+                    0x518854d6fb0650de,
+                    0x017e8b70392b5bf7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40fbdf741485fe3c,
+                    0x37cd71742759cf89,
+                    0x5d48eb18de133040,
+                    0x642107f43870f4b7,
+                    0xbbda68718cd492e4,
+                    0x1543362b9228212c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x0a22c5d21060418b,
+                    0xf5d69ce60d0b7527,
+                    0x0cef825a5f219ea1,
+                    0xfbfbc84bb99ba748,
+                    0xfc8af10d342c3dca,
+                    0x052da3ef992e1bda,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb34fc11465532911,
+                    0xc5d02a6e3f3362ed,
+                    0x49fc41382061389e,
+                    0xa7ae1f89b6ee8dbb,
+                    0x457f94d2b3041c75,
+                    0x078edff23f50da1a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xebdf6a558c82cdc1,
+                    0x5373b75b1f8df9fc,
+                    0xff588b3604cef271,
+                    0x70aacb565ba5d2f5,
+                    0xf193c26bdac47219,
+                    0x06f91bc7a0ac95ff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0bf121373e6bb2a9,
+                    0xd003f0626ff408cd,
+                    0x7199851daa64190a,
+                    0x33536e78bb630726,
+                    0xbe7c2474467eaaef,
+                    0x0c1bbd85133efd68,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c03a535f8c6997c,
+                    0x36ed27c12855f600,
+                    0xb691621bff1c6dcc,
+                    0xa46541ed932c581b,
+                    0x3b4daf3ef1d17555,
+                    0x01d7033457fb22a9,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x23c06c2fbb49b81f,
+                    0xc33cafd7c2ff1797,
+                    0x596d9eaf439acfd4,
+                    0x887b79167508a785,
+                    0x7241ca3fb37eba41,
+                    0x0dd16428fb4ee784,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa9d92bd6ba5e0ac1,
+                    0x4e3e8876ed2385aa,
+                    0x79a541a3f46f6b08,
+                    0xd676bc6d5181f08f,
+                    0x881f8563e970ca3c,
+                    0x11c8c8a7ab1a1509,
+                ])),
+                Felt::new(BigInteger384([
+                    0x971ceef6da849a45,
+                    0x0e17df8d9ba5b1a6,
+                    0xa0ed3776a0818d88,
+                    0x170156f0a6b2aa2a,
+                    0x17fe6ae2397eb94e,
+                    0x01dd8f635b0f039d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4461647b141b652a,
+                    0x2e2cd98d2d1213aa,
+                    0x9d8c87293550c688,
+                    0xb451d5fcb0ac5811,
+                    0xd51035168e5b68fc,
+                    0x1933c7d38af3e6ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbe5ea2c585f2c8fe,
+                    0x31b2902a797352a8,
+                    0xe8a298d0575d21fa,
+                    0x267ceae23326771a,
+                    0x2e2708c457cecc3d,
+                    0x0407c2445f61a2ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9973cbb8329c012d,
+                    0x943d855d1c27d547,
+                    0x4090da59de754b1c,
+                    0xd77447a1eea0e2bc,
+                    0x4ebd43982df52808,
+                    0x0af3bea2d63c078f,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x2086ca97055f333a,
+                    0xaa486f8457502c25,
+                    0xd20cb573e544506f,
+                    0x9aee379d79f0e102,
+                    0xf66e15fef549b6f4,
+                    0x08e37b3c94be98cb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1fed15692f771c45,
+                    0x945bfc8a3d06766d,
+                    0x93cf7b3ab4fb06a2,
+                    0xd018854e19301378,
+                    0x9234a3790d65ef0f,
+                    0x1923c79e1a056e6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7eba14719a56237e,
+                    0x71a1805350187371,
+                    0xd29d6f32452bb861,
+                    0x5fc2d3ee5bc698a8,
+                    0x8c25b4836f00032a,
+                    0x048f15dfa6e61acb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x295603973852da66,
+                    0xffd6ba231a1266b2,
+                    0x0c20be6e94b5c2b0,
+                    0xe0e5e111da44e069,
+                    0x1b7aebc2f56eefd9,
+                    0x07a31daabe783f81,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6d719366b78a33e5,
+                    0xd388a4210ef45936,
+                    0x0f5dc58bcf628115,
+                    0x3d82e61010a5c608,
+                    0xcbf75738117b30d3,
+                    0x0f3ac8060a420c53,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ad7d43a92304303,
+                    0x46ce8593b28b430c,
+                    0xaf30c8420ccba4cf,
+                    0xe1714e580b3ca98a,
+                    0xe60ed14ff3b1b527,
+                    0x026111f529f48b9c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfcc84ec38ba837c2,
+                    0x0d99deaf8ab47909,
+                    0x2a22b5ef9dff874a,
+                    0x1faed27265d818c8,
+                    0x9a24c0018cb00c29,
+                    0x110264e5f2b3859b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x503d39e44fd05862,
+                    0x7990844fdb9d6024,
+                    0x4524cbc81214c2a2,
+                    0x63178f16659dbc0f,
+                    0xd9881b3e61546023,
+                    0x0bf2fc1245c42133,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0f335f8ffc42a51d,
+                    0xca8f82f1748628d6,
+                    0xc659b9ad2d596af9,
+                    0x78c2ee315664c489,
+                    0xd808f95c1976ea32,
+                    0x134a7278644e0a9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b0cf933d416182f,
+                    0x97a0b2b83f267263,
+                    0x0097c3a0c66191f1,
+                    0x00a59bcbf888bc67,
+                    0x1765484cd34fd433,
+                    0x17560a02fb5614d1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x181fd3fa13a235dc,
+                    0x80bfcc1d6a377a82,
+                    0x5b89409ae9870bdc,
+                    0x5d88183061f221db,
+                    0x46822fcb5cceaacf,
+                    0x10a22d05e6f30f42,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe3cc04428f78eb18,
+                    0x367ad6bd42e59894,
+                    0x0d964c92064905a0,
+                    0x70f65b5ef90ebf2b,
+                    0xea6419cf481d66fb,
+                    0x1943ed1430f6954f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x571683e5e6e5b3ee,
+                    0x77d17a1c71aadb32,
+                    0x7180757580ac54b0,
+                    0xa96333de924f790d,
+                    0x47d1f354b951f558,
+                    0x05aa24f1e6a9f8fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x662e02d95357e6d1,
+                    0xfa6c9df681aed324,
+                    0xa6b04ff2d64dcaae,
+                    0xf8b2ab03a409a4c7,
+                    0x3722aecfcd7cbbb7,
+                    0x00f0ae5e504adad6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb696691334400672,
+                    0x9a112ad1e4c121e0,
+                    0x610a33de427a81bc,
+                    0xa2b4551c428fdbf1,
+                    0x36fe7eddb9fc9674,
+                    0x0fc4372cf28f9314,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd18c590f4e253180,
+                    0x8ff7f663efd2ffb6,
+                    0xc67f7f33013c5f2e,
+                    0x0867f2294b45fa97,
+                    0x64dd024924beefb7,
+                    0x0a70f68c39deaff7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4365ab14673a5cd7,
+                    0x695d5bb6033b2dee,
+                    0xfc999a30c119b214,
+                    0x8142cb3090f92d91,
+                    0x5c0b38797ce7b126,
+                    0x156a0024965b428a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7f05cd04bb79165d,
+                    0x83ec2c1cccc3a085,
+                    0x8f15501ebb9d4657,
+                    0x5f0e8d6665275ddf,
+                    0xe6b1a9ce6f149eb5,
+                    0x0ebc691acc48bd15,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfed146a44234aa33,
+                    0xe57d253804970c6a,
+                    0xb306379017fcd9a8,
+                    0xef88b98bf92a40cc,
+                    0x14b5757b93e25987,
+                    0x058445224a699bb0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x49225bea509313e4,
+                    0x193f42db681d34a9,
+                    0xa7e30f5b08e7a506,
+                    0x1001a43ba81e9eae,
+                    0xff8bac1b1a3f4446,
+                    0x144ad81292e97ac1,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x2086ca97055f333a,
+                    0xaa486f8457502c25,
+                    0xd20cb573e544506f,
+                    0x9aee379d79f0e102,
+                    0xf66e15fef549b6f4,
+                    0x08e37b3c94be98cb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1fed15692f771c45,
+                    0x945bfc8a3d06766d,
+                    0x93cf7b3ab4fb06a2,
+                    0xd018854e19301378,
+                    0x9234a3790d65ef0f,
+                    0x1923c79e1a056e6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7eba14719a56237e,
+                    0x71a1805350187371,
+                    0xd29d6f32452bb861,
+                    0x5fc2d3ee5bc698a8,
+                    0x8c25b4836f00032a,
+                    0x048f15dfa6e61acb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x295603973852da66,
+                    0xffd6ba231a1266b2,
+                    0x0c20be6e94b5c2b0,
+                    0xe0e5e111da44e069,
+                    0x1b7aebc2f56eefd9,
+                    0x07a31daabe783f81,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6d719366b78a33e5,
+                    0xd388a4210ef45936,
+                    0x0f5dc58bcf628115,
+                    0x3d82e61010a5c608,
+                    0xcbf75738117b30d3,
+                    0x0f3ac8060a420c53,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ad7d43a92304303,
+                    0x46ce8593b28b430c,
+                    0xaf30c8420ccba4cf,
+                    0xe1714e580b3ca98a,
+                    0xe60ed14ff3b1b527,
+                    0x026111f529f48b9c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfcc84ec38ba837c2,
+                    0x0d99deaf8ab47909,
+                    0x2a22b5ef9dff874a,
+                    0x1faed27265d818c8,
+                    0x9a24c0018cb00c29,
+                    0x110264e5f2b3859b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x503d39e44fd05862,
+                    0x7990844fdb9d6024,
+                    0x4524cbc81214c2a2,
+                    0x63178f16659dbc0f,
+                    0xd9881b3e61546023,
+                    0x0bf2fc1245c42133,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 282];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+            bytes[188..235].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..47]);
+            bytes[235..282].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x61b2f978f78a1ea8,
+                    0x22bb71be8879f283,
+                    0xa93bcf0d814c0606,
+                    0xa567947247716888,
+                    0x7d1e45b60a4a092d,
+                    0x15df5e090bacec9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x792dd0243e1a18e8,
+                    0xd1c74666899a3b79,
+                    0x687832f8704bd01e,
+                    0xa02e2f79b1d3ee64,
+                    0xd9ad9429049f75cc,
+                    0x00afebb1db1d624c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x49b600ec5621663f,
+                    0xc33b3d0916bda591,
+                    0x8e9d63aab5e5d412,
+                    0xf655233a8c6dc0cd,
+                    0x000fa7360c69b91f,
+                    0x17099ce8b3ff009b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe896cdcf8c085e0d,
+                    0x7597ae50b4099e15,
+                    0x5920283256aa2924,
+                    0xe3cdc625d48d889e,
+                    0xe00af2db567ef109,
+                    0x0620fa9fe1ca8b4c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3a27d9b7349b8b08,
+                    0x09d1cef48c44143e,
+                    0x5a12af1e2b24722d,
+                    0x011a9bb77714170a,
+                    0x7c5ac763e74a3d13,
+                    0x0fb537854c3f4607,
+                ])),
+                Felt::new(BigInteger384([
+                    0x85e5a9a3cd714612,
+                    0x248a2d4fe423558c,
+                    0x286d6a278ce8e8e4,
+                    0x0f000b2be4dd0f77,
+                    0xc53ef883e4a1b72c,
+                    0x0cedcb466346f593,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x8def2a779bbef89e,
+                    0x9f973276f9a9af40,
+                    0x951c6f2b2e1515fa,
+                    0x3fcec8ec14ec7370,
+                    0xbddaf1b5eb13f918,
+                    0x17d870259d1cff86,
+                ])),
+                Felt::new(BigInteger384([
+                    0xddea5b8e565d3dbd,
+                    0xfd0ad5487504145b,
+                    0xa49925142740d539,
+                    0x7a93f74f581f68fc,
+                    0x3eb2a65368562147,
+                    0x0bc915452c486b42,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8d2d17e80d083cc7,
+                    0xcf5e2b57df53bbbf,
+                    0xc35dd09f583f75c3,
+                    0x12fcedf9cb08fdf0,
+                    0x4a82dc1d0d5afd62,
+                    0x01c8bdbd2ab13682,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c6d7e3e24b20060,
+                    0x54fb706554be3c96,
+                    0x66bacc35414af982,
+                    0x43a1f06ef6378c88,
+                    0x4e3db63b0f894c09,
+                    0x02b0d439070c1ac0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x25a7a944902a1dd2,
+                    0xd2b3bd39937ffe62,
+                    0x41da980e9292ce26,
+                    0x37d7f9d853e74f3a,
+                    0x3ac734a3c956a208,
+                    0x07197138f1ac3221,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe8fbdb69944f2518,
+                    0xe3767bf903d05a41,
+                    0x79fffbd09a219e54,
+                    0x102194fa382f8bbb,
+                    0xf1916e51231011aa,
+                    0x028dd0278f75a7a6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x20b99cf313dd4ee5,
+                    0x0d7303ca876f9509,
+                    0x1de392be99c80a07,
+                    0xf61b38c8b3e055c7,
+                    0xc9a2b0890c9f721e,
+                    0x1788e0e4087fdfa5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb7ddeb05f2d8dee3,
+                    0x487173e84af0c49b,
+                    0x6ae537927d2ee061,
+                    0x7a0fcc6ab7ea3b32,
+                    0xc668aa90ac37443e,
+                    0x0ff16d92e146c4c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x449bd91e063adf40,
+                    0x5069ebebddb6bc4d,
+                    0x10643258986ebaf7,
+                    0xaa9f594d7ae1368f,
+                    0xf6671915cf49bd53,
+                    0x145dea4d4ee63431,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb5ca79a223b60795,
+                    0x1642f3895c0c5978,
+                    0x57806711028dd4cb,
+                    0x08aa1467b31e251b,
+                    0x91eaf8d49e17af85,
+                    0x11c5e928aaee498f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x135d743459698db0,
+                    0xd825208bf14cffe9,
+                    0xb7b8b5535f9412fe,
+                    0x448e81a14fe0b935,
+                    0xe89b4b438fbd0c82,
+                    0x0c0b7f3b5f14a4a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x70cc4e07150852d2,
+                    0x624ac8a22eb76630,
+                    0x9c427c34ea872011,
+                    0x60b83893abf5028c,
+                    0x54658eef8ef4275c,
+                    0x0ca99a523f0a2cb4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xf38b0adb30f1d05f,
+                    0x0c31343ef07542c9,
+                    0x11213b59720fa04e,
+                    0xe2418aed3d2c3698,
+                    0x7063fd6740c5e613,
+                    0x09eb0077f7803290,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7bfcfbc4185577aa,
+                    0xc6b289d15d17a62f,
+                    0x07a13e669460eec6,
+                    0x08a8a1293fe57873,
+                    0xf4e73b43d4ed6f3f,
+                    0x03b6b96957fd809c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4478815cc16236cc,
+                    0x7c6bdae16193f5ce,
+                    0x9297f00788ca4cfb,
+                    0x07bae60755e4d9af,
+                    0xf82a94008a41b4ae,
+                    0x0074815e5903ad86,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbfd982b082691835,
+                    0x129eddea4abe3090,
+                    0xfda6c7c6db88a0d7,
+                    0xa143d9ffe5f9e16d,
+                    0xd3cc630062732e3e,
+                    0x12d2569b584a6606,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa2d576bb3fcb87c9,
+                    0xce87db2a71b9e795,
+                    0x54475966d99c9309,
+                    0x3798c4c90a029d3f,
+                    0x85bc60a01798051b,
+                    0x14ff66b020c18c8d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x792efc26577450a2,
+                    0x747fe060646f2a5e,
+                    0x1ef5b92fb3cc4bf7,
+                    0x11e03271aeabeb9f,
+                    0x88b5e63b9cdce208,
+                    0x04a507009581922c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x76ac1591845aaac3,
+                    0x7121493ec5b8f466,
+                    0xb8cf8b6e7ebab12e,
+                    0x900337c056b75b08,
+                    0xdd48ca3599a02231,
+                    0x081a111cc596f7dd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbf2ab748dd24c488,
+                    0x1fabb6c1d9099086,
+                    0xbdc60d753aafdf02,
+                    0x23516e3c9d3a5d3c,
+                    0xb57914a4dce9eea9,
+                    0x19f95bfa8d3610c7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4267263e4cebe967,
+                    0x7104a5177d8be6ea,
+                    0xf91b6170626b5b21,
+                    0x13d50bbba5263d8b,
+                    0x3f2de43d2137daed,
+                    0x07d4cc467f55ebf9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c8f5560261ef0e1,
+                    0x47c507e28ad94766,
+                    0x605bdae750943fad,
+                    0xb7adb53355155b84,
+                    0x11445c5b2b7e1ce7,
+                    0x135e433ebcf1c6cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x045400a776f5bb56,
+                    0x9c8661a274569e74,
+                    0xe0ebf0d5b0a9de52,
+                    0xdfbf528106665e76,
+                    0xbe456d281cb12aa3,
+                    0x17b8c013251f0d9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8dc29ec479864ee9,
+                    0x6e5d43d6a26645b0,
+                    0xf2be38e5eb6dcb06,
+                    0xb3b6f2117315d877,
+                    0x68059813d6d316b3,
+                    0x173d3a8bca9094bc,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x13decbe2be9f9e23,
+                    0x5d7bee7e5542320b,
+                    0x9b370aa03016c2f0,
+                    0x621f16300f900453,
+                    0xe54ffe9cb3398ede,
+                    0x048898c4df58088c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc474f20e56dfadf4,
+                    0x7e6ec5b33f055ae1,
+                    0x7b7cc22db33344ba,
+                    0xd998a123d867da81,
+                    0xbb8a0dee2baaa299,
+                    0x0891a439c5585c86,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1c2dd129ea764acb,
+                    0x54997eb2905cf20d,
+                    0xcbcde6ff55f2c898,
+                    0x6301246c08b89832,
+                    0xedeeff2c440f86e8,
+                    0x0cafc409accf8818,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee279aa52f156202,
+                    0x8bba80298f666290,
+                    0x8bd4abde26c3f97c,
+                    0x7742167ecaa420b9,
+                    0xa324d6ceae62fc3d,
+                    0x04a5c4d4fda7b889,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb2f6abda28bc6ade,
+                    0x4c3b59558267f442,
+                    0x8c6a6bcae2a326cf,
+                    0x428a7bca4915307b,
+                    0xe2fa72d8b25b9afe,
+                    0x02a926e9d22cbf88,
+                ])),
+                Felt::new(BigInteger384([
+                    0x48d57580715c9e2f,
+                    0x6a988774f50289e2,
+                    0xdbf5fe859c9370bd,
+                    0xbc3eaea536af2140,
+                    0xba641061bf12e1de,
+                    0x073590a128684135,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x19ef194803911b4d,
+                    0xe4858efe92d34df3,
+                    0x63005ef1cb39884e,
+                    0xa6e92f8b63095b0c,
+                    0x69849169a7686332,
+                    0x0071892c1c83852f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfbe15161a9c01641,
+                    0xb75fc66201f03bcf,
+                    0x4224f996db7048fb,
+                    0xdf749162db5fe38a,
+                    0x51a7fb8d96ca6057,
+                    0x190456a822d5d3cb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x52f526085d9ebb1d,
+                    0xcfa542cc6ae2f7e0,
+                    0x3fdcced11fc1e7c4,
+                    0x3d65ab5c99905e0c,
+                    0xf22cdae7f78703ea,
+                    0x09bc11bc7471feaa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x641406411b0493ff,
+                    0x3f2af5e40a6dfff7,
+                    0xd8fb386329927480,
+                    0xbb556723fe8d4758,
+                    0x7506909b9ded4b65,
+                    0x06b4941917b4e04f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x845a8cbe8483ec1b,
+                    0x8e08c5bcce9727af,
+                    0xc0727e23008c7ac6,
+                    0x2e6141b0130d1774,
+                    0x9b3f7ad47bd71e67,
+                    0x1627eb4b161bd353,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf44f6426adc410b7,
+                    0xa9b58ab2bfa4b276,
+                    0x23b5c4d6c6140a14,
+                    0x95bf2b10879db907,
+                    0x01bb482524b7548a,
+                    0x070e75b7a73988d2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7c8bc59b800296dc,
+                    0x185dad56e9ffa3e7,
+                    0xbcd927533dace16f,
+                    0xdfbfcefb86b3babd,
+                    0x6e4aefa174a20537,
+                    0x146baf9f880a0a01,
+                ])),
+                Felt::new(BigInteger384([
+                    0x58c38d9fe20fea7c,
+                    0x06a3fef2c47fff8c,
+                    0x93302c0aeb1191b3,
+                    0x020063a2a4a5f127,
+                    0x559d43d2867f4c5f,
+                    0x022c17d065857800,
+                ])),
+                Felt::new(BigInteger384([
+                    0x351e463d556f015b,
+                    0xf083d9ab882b20f5,
+                    0xd4f814f3280aa825,
+                    0xddffb16095e33cb8,
+                    0x4d07323f581a565a,
+                    0x02fe7d24324e8acd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6fe1da8e332bd680,
+                    0x97be1aa53c0d17fb,
+                    0x730db923f7c76edb,
+                    0x19a156c55578aea4,
+                    0x21f7a4782b7ce544,
+                    0x0c154a804391dfaf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x73553cc062d2fa84,
+                    0x0ffd71330d58f5d1,
+                    0x49ca9b400fb4b00a,
+                    0x1a3ecc867934c1d1,
+                    0xdd70ab904758641e,
+                    0x08e40886b4cc949d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x15719c063cbe0f3f,
+                    0xe655ae6ff81ebe87,
+                    0x6609543b43f2a016,
+                    0x2c54dd584ac2bb74,
+                    0x584fe4d7ea2f2d2e,
+                    0x0a6140acb01dc3ef,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x695596cef1e8b60e,
+                    0x9e200895832d742d,
+                    0xcc1ae9e8729e0376,
+                    0x0b3c616728e7a477,
+                    0xfe934062b1c91cb4,
+                    0x17901032fb43ae64,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee905b8dc80da932,
+                    0x85a7cb16f0e57d1e,
+                    0x31e087ceb017c2f9,
+                    0xae0ed9723f3978c4,
+                    0x4d3e330122c8e17d,
+                    0x0f6b3841074618d0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa6d4af123f789346,
+                    0xe955ce3ae666f25b,
+                    0xa7ac70ea036ece76,
+                    0xa3a5c466c0183f1a,
+                    0xbf700b5ef7d22075,
+                    0x19d052345a21f9d8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x7491405e2dcac6e8,
+                    0x9dfe2da2ddf88987,
+                    0xeec97214869fa788,
+                    0x1abc7296c1d2a09b,
+                    0x41f7c16c9996c7e6,
+                    0x05ab884c14f5471a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c1dbc0daa1e480a,
+                    0xa77f97f784fdbfcc,
+                    0x8dd6e71c80e7e508,
+                    0x2bb268fd068e8c10,
+                    0x43f4acdcdddcce9a,
+                    0x13da135008f86d16,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2d97ae855e2b96ec,
+                    0x34d42674699591f0,
+                    0x230862d5d96c4d81,
+                    0x7f20257c60e049d1,
+                    0xed315e0605195c0f,
+                    0x112111b353d4061e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x88ee7638f821546d,
+                    0x3f5d4a73f1754c04,
+                    0xd359918ab3899cef,
+                    0x147ac5495c83cd78,
+                    0x73cd2116d617ea78,
+                    0x068810d664991f75,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc0723ca7299d5933,
+                    0x620620ebe49e11b2,
+                    0x85b731adfd6c88f3,
+                    0x441d262d50d64dce,
+                    0xd54b727145ea403f,
+                    0x0c9debd4cb814fc0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1e62116a8e03815c,
+                    0x628bbab993c9436e,
+                    0xb1cf0589ec4efd98,
+                    0x6d5e411fba0c37f8,
+                    0xd523582d8d589e0c,
+                    0x04ba46f8128be5c7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xbd97dbc7d1b623c8,
+                    0x0383d4097c792091,
+                    0x0cc645bd76b11e64,
+                    0x3c46fc253d444f1d,
+                    0xf0c914ec120ab5fe,
+                    0x04bb3ce62b752c98,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66d774d4db049b77,
+                    0x80f61bb8e3501aac,
+                    0xd6627da77be7d2c2,
+                    0x5be33f5c80995565,
+                    0xae5f240bf0f415ab,
+                    0x0bee590e5fc1fbff,
+                ])),
+                Felt::new(BigInteger384([
+                    0xeba64992d66f8961,
+                    0xabd475534fce134e,
+                    0x4d8836e881da718a,
+                    0xfb765f1ae1b06090,
+                    0x738b1f4e72c6ee7d,
+                    0x00e162335e21e31d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0b3def1817eba45f,
+                    0xea21fd700aab1255,
+                    0xa388ff9d24880d7f,
+                    0x60d18800701f2c00,
+                    0x0cebb1fb42366042,
+                    0x11e722bff49cff31,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45dfd73f679c5946,
+                    0x87f3d68a650fa195,
+                    0x9cc18ea359aaaf13,
+                    0xb51c6a98e18faafb,
+                    0x49d696930b61a574,
+                    0x03e8abd8f15cce65,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9d4f8f35c05cd1bb,
+                    0x4770177aa6d74218,
+                    0x5582b551c8e714ad,
+                    0x4eb6562b7b8478d9,
+                    0x05e7037693363e15,
+                    0x0226a8274398d30d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x53c70d09bc23b62d,
+                    0xfdab863a9d0e57d3,
+                    0x454c8b63427b549a,
+                    0xce517eccbe5165d2,
+                    0xf9158a14805daaf6,
+                    0x090948febfa01905,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f9343064e13b0fe,
+                    0x8230f1553b2771c3,
+                    0xad0d7f5e9669f79d,
+                    0x67847f8d88c885c8,
+                    0x0e9ba2236dd1cefd,
+                    0x0a1d2da564cfc2ca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x543ab33e9c7ad74e,
+                    0xf0b04902619f6985,
+                    0x7a542f4450f9bdf8,
+                    0x6ff0a1afcbb03f4c,
+                    0x00cc24de1261a8e0,
+                    0x009b6df850c84d6a,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x078f9298957b4a85,
+                    0x8cc7adbd54ba0cc6,
+                    0x31d53d23ac267ffa,
+                    0x794da6de46abd0d5,
+                    0xafe1e7ef2c806719,
+                    0x19451757c2a85a71,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c03fc5ff3c9b0c7,
+                    0x006fb7891b2bd8a1,
+                    0x3eabbfc1f75e79f0,
+                    0xf7512994c231c551,
+                    0x2a8e9b20d079988f,
+                    0x0ec6047c9b186f18,
+                ])),
+                Felt::new(BigInteger384([
+                    0x38b89834ebba3d2f,
+                    0xb303432a8609c0ca,
+                    0x3e29b7d1c03ef8c9,
+                    0x775a4e78685d61c3,
+                    0x383f1adde821d3e9,
+                    0x02e404ec6ccf8b78,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe260907cfde7ad3a,
+                    0xac59bdf8383125f4,
+                    0x16c7390c184e266e,
+                    0x7e81923b2510554d,
+                    0xb7fad5c298e90239,
+                    0x1064633f84156157,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa65532a18e39952b,
+                    0x7384cba287f35fa7,
+                    0x9b6e7c924a1b5642,
+                    0x5f8c8f48e22ba9ad,
+                    0x0a3f4fe7e3951886,
+                    0x04acf1af7a4b2088,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0b57c297050ac216,
+                    0xddd6fb66b7dde98c,
+                    0x02a71dc1dca0354e,
+                    0xaa7e4eb7c9e94d4f,
+                    0x06ccddde90282cc0,
+                    0x0e856c7dc3690da4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xd43f4d731efc05ed,
+                    0x171eef9cf07a7c80,
+                    0x6fe5eecc0d6d8ff4,
+                    0xb2475f9751998f6a,
+                    0x2b666999be576c07,
+                    0x03514a3cc7bf10fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbdc52274a8c1a39e,
+                    0x381692bdce2c7b22,
+                    0xd2d8d4bd79dd5f93,
+                    0x87c5ec1f7a3bd8f3,
+                    0xd37724a9e1026ce7,
+                    0x0c507bd5b7de75dc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe77bafc6c7a772c4,
+                    0x51b25700b1676e2b,
+                    0x406777607afb5c12,
+                    0x29ec78017d6d24a2,
+                    0xb6f8fef9dcc806e5,
+                    0x0ec91bb3d8333248,
+                ])),
+                Felt::new(BigInteger384([
+                    0x38ff2d606694ea4e,
+                    0xa772c83822c28db7,
+                    0xb94253adac44c3f2,
+                    0xec07b0ca765f0e57,
+                    0xb34ff85a278da5f4,
+                    0x037ec08b12d22fff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x36974ca3ea6fbba8,
+                    0xc9d1c42eab8bafb3,
+                    0x91767ab8275d60f7,
+                    0x828ee7b7af8ca838,
+                    0x989c1b285d88c5c1,
+                    0x1127ac5845f8b8fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xffa1b5e13e1f6414,
+                    0xf8a2a0ff55849ddc,
+                    0x0d3744ef35a8e92b,
+                    0x91311d18ef40fa09,
+                    0x2698d8bb2eee875b,
+                    0x03ad3cf777818c18,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x9849be03ace928e1,
+                    0x16cdb5494a2cf724,
+                    0xd44cf6e75932fe4f,
+                    0xc0933041db302e6c,
+                    0xc93d7fa60b64f909,
+                    0x013e237ce19a599f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1487b81632337869,
+                    0xa984d3a8007acd95,
+                    0x09bf39e66bbecaca,
+                    0xb84165716e545f57,
+                    0x4a5b73fedbdc616c,
+                    0x09b28f92926dfca8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x585e1cda3225b86c,
+                    0xcae43538f4c4919b,
+                    0xf99e37313a4f8347,
+                    0x0a0f27a031ee2ab1,
+                    0x2d9660d5739d1058,
+                    0x09904f1b754a4abe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x90df8fc69e5a8b2a,
+                    0xc359a9ec6ad04093,
+                    0xf6125db4fedcf5d7,
+                    0xfd6cbb5f47b29866,
+                    0xdd7b72f49571b5e8,
+                    0x05b3c21c2fe5af85,
+                ])),
+                Felt::new(BigInteger384([
+                    0x291132ed287c47e5,
+                    0x41eae4618146fd1e,
+                    0x3932eb9a5615fd74,
+                    0xfe78e549ce74a29a,
+                    0x4a12ca5aabb9f4f1,
+                    0x0d97f505ab1edab8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17e09b5845b8d884,
+                    0xcbcb0c0cede5be22,
+                    0xe4fffe95f7ddf6fb,
+                    0x10b35b414135fed1,
+                    0x6038bba262e0a4dd,
+                    0x10c7eaee26cadcf0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xa93d9da8b2712623,
+                    0xa544e297e57bedd9,
+                    0xbbdf7dacfdd19283,
+                    0xc5a27e66d381d350,
+                    0x6dba6a809afec7ff,
+                    0x04d1fcf624fb7086,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd560697ace680f7,
+                    0x218618b9cad61b89,
+                    0xa318eb5edc9f985b,
+                    0x3e8b3dd8fb33fe9c,
+                    0x5e09d8e4d8ed2a97,
+                    0x0960bf40d42c7bee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8832222c7ab4a4e3,
+                    0x8665401bab062320,
+                    0x4895a054c0ebd786,
+                    0x2ac2790112b42e56,
+                    0x914173037ea6b8f9,
+                    0x0d3e244295b3f07e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb6d80c2a2f1249dd,
+                    0x07ab4f694e600514,
+                    0x0044d3c92ec4782a,
+                    0x46c8b1bdb9847e35,
+                    0x54ec0bc908f6243d,
+                    0x0938e4034ddf0ed7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9288f760d70eb02e,
+                    0x8e1390d40ce60b64,
+                    0xdc59fa34be7b172e,
+                    0x0d3553b90091e670,
+                    0x5fe42d41e7df4f00,
+                    0x072628b106898344,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3f486c74d54df3e8,
+                    0xb9a8f993d45f4f16,
+                    0xdbc6f4c4cec48db1,
+                    0x6c2e8ee8e443755c,
+                    0x967b3b9c85e807a0,
+                    0x158abef5dec9e8b5,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x7e86f7e6ab19b503,
+                    0xf9fbd85e6b2e85e0,
+                    0xe5303215efc514ed,
+                    0x7660ac8bba05f295,
+                    0x47668223a7551191,
+                    0x181d3ccfc29e8e9f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba0e7a165b3e74e1,
+                    0x642a4b7923d2aabd,
+                    0x9c96859a304fb61e,
+                    0x8a0fa159dbc01c30,
+                    0x078f60bd0668e2ef,
+                    0x06a7917e92144e05,
+                ])),
+                Felt::new(BigInteger384([
+                    0x09263993fa2b2e29,
+                    0x394c6fc29cb62f62,
+                    0x80f1be74b8b7170e,
+                    0xbad5a0f4e57ef407,
+                    0xe395d0eba0004e90,
+                    0x0ae951898c5b8612,
+                ])),
+                Felt::new(BigInteger384([
+                    0x30dbae3a52e0744e,
+                    0x19c7c197d22f18a7,
+                    0x89c80c6aee843347,
+                    0x415135753bddc8a5,
+                    0x54157345a7546549,
+                    0x0c5805286db9d052,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1e7be50ab06fb942,
+                    0xb1b7b1093f186f6d,
+                    0x83d030495e73fbd3,
+                    0x9abea4a30b00b173,
+                    0x22d6105613c68a43,
+                    0x0f0cc71b717368c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45e31812b88121cc,
+                    0x99df13f06a7feb11,
+                    0xdec7c20054e92726,
+                    0x8639ecdb3fd63791,
+                    0x133a7ec411f6809b,
+                    0x143ac5bdd7e0302a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x81416155a5b2ec02,
+                    0x416957133599e35b,
+                    0x587fd9884adced9b,
+                    0xa3b8a52d1318061d,
+                    0x36a75826f3f58e50,
+                    0x105c5d06548af51b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9505b26a2778a410,
+                    0x1cb07fce292c6d62,
+                    0x07beeb3c56023b17,
+                    0x306a0f7001abbbae,
+                    0x244cd26e5a05a5d5,
+                    0x185ba33106160289,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdf534d18723d9c32,
+                    0xa5520330c95621a7,
+                    0xa75fed8c9724ad5c,
+                    0x7fdd6ef9b074f809,
+                    0x1fb7cae95bfc1ddd,
+                    0x14fc5e04a33088d0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x801d3df0ce7e288b,
+                    0x9ec66d25dae793dd,
+                    0x6b40f9fce918260f,
+                    0x04c4932672010503,
+                    0xf5238e2e094515f8,
+                    0x18fc64ef188ee749,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd29d6debe5f7564,
+                    0xc1cc6187b55406d4,
+                    0xf4f0047ca552b726,
+                    0x099eaea8a16f7a5c,
+                    0xb690cb76b4e927f3,
+                    0x0517aa67a111b4d1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1f00eade94d85924,
+                    0x91fa7f5dc734c530,
+                    0x022a62e1a5f2035d,
+                    0x7b60960ab95cdb62,
+                    0xbc2825c19f74231b,
+                    0x0e2d7a3648f8c63c,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger384([
+                0xaec690b20af04200,
+                0x4cde982e4e5281a3,
+                0x7dd154b8cfbac2eb,
+                0x5f4c20c5e47489e3,
+                0x623dc028f26e1a9d,
+                0x0930dfa67a4b710b,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x22bef7264d4ce626,
+                0x583d4654e755da1e,
+                0x55f2a8bbf9820337,
+                0x1afe885fa5b30515,
+                0xc6e5abdefb301180,
+                0x09e9e3319b8a55da,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x0a6d9978b78182b3,
+                0x0f8585f536aac469,
+                0x2501685150c91b48,
+                0xbfbfe3fec13ce89e,
+                0x10ef65b3533ba7f1,
+                0x199644941fde0ccf,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x3ea9b354d2bd3598,
+                0x6f653a499030cc54,
+                0xbbb0d5fe54bdc8d8,
+                0xfbbdb51f25eb192a,
+                0x0c9c8d2a19b8c9b8,
+                0x055981c96efc51a2,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xe3abb34a5772d47d,
+                0x99c82403626908f2,
+                0xa489b6726891ff49,
+                0xbf53b477ff566f31,
+                0x5c98f480f36acdd1,
+                0x1533861970685727,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x29cb1e8728579735,
+                0xf426a4c8e77186c4,
+                0x855796c66ad69920,
+                0x09dddc0718db8fd6,
+                0x5fb21c685ee8bc15,
+                0x049748568ab69b37,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x8882d0ab9e72594f,
+                0xd2b4c3c24cf9b169,
+                0x2218276519dbc513,
+                0xe8bb0c6589b6b7be,
+                0x4fa38d5f04f045a8,
+                0x111dd532a15e8ac3,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x5728d7d0da97f59a,
+                0xa82465ca9eeeac94,
+                0x08cfadfad52716e0,
+                0x6654e8008b262847,
+                0xc8d7d063023334b6,
+                0x11d880e3010b026f,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xa720a098209faa32,
+                0x114c5d3c94305b95,
+                0x5974f9be6425998d,
+                0xc94b3886c7f57770,
+                0x2263f4a43a833d57,
+                0x0deff9c97b89b52d,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x85e9e3307d959aaa,
+                0xea2c944db0b65346,
+                0x826ac78f71eac4e9,
+                0xf2d9d39f5e525ebe,
+                0xa4ccdbd4449f57d2,
+                0x039d825e4ecde444,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 188, 189, 569] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 188];
+        let mut opad = vec![0x5cu8; 188];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,1806 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 288 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 4 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 4;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 17 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 17;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2ec5623bc8693a1d,
+                    0x788f469ed7d23362,
+                    0xff057dbf997dbcc4,
+                    0xedf788de422ee79a,
+                    0xe750e284104eae0b,
+                    0x084644b598bb0877,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1728d3dcc18f0ada,
+                    0x3f43ff399782631b,
+                    0xe82c960da9dc5ebb,
+                    0x245309bfcd7412f3,
+                    0xdb30c499a27e9331,
+                    0x11693a7f24c78c06,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf2e1ce5a97816279,
+                    0xd69392c6b10333c9,
+                    0x61d7e7030b5629a5,
+                    0x5239ecd74c2f5e33,
+                    0xb1133a697cf76263,
+                    0x14a82d27d0ab2011,
+                ])),
+                Felt::new(BigInteger384([
+                    0x83b5d9b2508be579,
+                    0x6758071cc33f8a49,
+                    0x744c6133fdf26e6c,
+                    0xc110afdccb22019d,
+                    0x0623bf20d9f0f408,
+                    0x1282c3c2151f5307,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4cad38f528b547c9,
+                    0xd0d43587fefc1f83,
+                    0x3d276da1a6222ab0,
+                    0x8deb8edf767f2384,
+                    0x066cc67603acba7e,
+                    0x00f76e05fe6e04de,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ab7059bfa65e071,
+                    0xa4b9a3e21fbd0c09,
+                    0x1d91c24f2805d382,
+                    0x9a47be947f6f04ed,
+                    0x1ebe9af378b68769,
+                    0x0230e7b5822a7964,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x59366891dea9f892,
+                    0x85a6cb54afa8d9d9,
+                    0xe5460a1954661763,
+                    0xdbb08b77aa3c4e5f,
+                    0x31421491eea50ee4,
+                    0x0aa71ff4fcaf9b7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdd5758894b5871b2,
+                    0x0c22f717a45213c9,
+                    0x8d0ba9eb34ffe3ec,
+                    0x1ca3c9835d836223,
+                    0x1f9d9cbcd1c53e59,
+                    0x01065e005f84f45e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0714807f8edbca9c,
+                    0x2eb08873b6012e2b,
+                    0x17eb64e5e3f2f19e,
+                    0x1270897e078a87d0,
+                    0xd6597fc9735a6a3f,
+                    0x01aa4906e857c333,
+                ])),
+                Felt::new(BigInteger384([
+                    0x58b16468c81c6bc0,
+                    0x4bf6566bf9bb6f27,
+                    0xb8cfcbb9f01a8d06,
+                    0xc94b07a5db02dea2,
+                    0x1a0fc5487ca7a20f,
+                    0x17a264908bc17cff,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8133fb7565c709ca,
+                    0x87e0e8d6a5a20c1d,
+                    0xdb99319a7d4a880c,
+                    0xc3516a3b24589f90,
+                    0xbd056ba772397568,
+                    0x09aa52c2460e6dbf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x49d93787e23211f0,
+                    0x7a15fb0d40dd0e67,
+                    0xf615159ba677cfd2,
+                    0x9df9b48d172e8d2a,
+                    0xa27773f1e338728c,
+                    0x14311208e99d261f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x78b6461c8343ade6,
+                    0x5dbe1ed4d51835dd,
+                    0xe4585e209f157e84,
+                    0x19eb9395b88fc593,
+                    0x559964ff5fc9de5e,
+                    0x19d9402269bf5b09,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa157e43313d52de7,
+                    0x2ce09f1e2147c02a,
+                    0xf1cc0dd70082c906,
+                    0xeb9ec3213f156073,
+                    0x9c9c01b1a8d9a3b4,
+                    0x11caf0fd5f70b747,
+                ])),
+                Felt::new(BigInteger384([
+                    0xadcd0c6fe838c012,
+                    0xdc165fca6aa277f5,
+                    0x6a44946208c7647d,
+                    0xdfb7ae42a1a192f1,
+                    0x0d957438d5b55e2e,
+                    0x102b3360631382ea,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf05b4ea63023e2ec,
+                    0x70db44c26336776c,
+                    0x9c8b3e7192f00d86,
+                    0x90e6c7ac5856edb6,
+                    0xb3716dea66562c43,
+                    0x1000acfc6b6ccd69,
+                ])),
+                Felt::new(BigInteger384([
+                    0x67e9de22f357876f,
+                    0xb435d4f10e1b31a8,
+                    0xf4888861793a3439,
+                    0x2e1f1dc9865f34c7,
+                    0xe1851bd354a9d03d,
+                    0x0f00b938da3306eb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x68019a3230182ff1,
+                    0x3a894ff817af9477,
+                    0xdf90bfa0fe5b0eaf,
+                    0xfa1b9edbb736084e,
+                    0x43ae589d57f7a29b,
+                    0x0dc8025e0dcd8278,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf2fb4244c1334775,
+                    0x892b300b4d28f452,
+                    0x50a4ea73fbef027b,
+                    0xea2a7d6bcc5acea3,
+                    0xa0fcc9562d8ac991,
+                    0x0ebca034dda2d36a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x36cd0ca753abe62f,
+                    0x20eb0ad0c4b1b414,
+                    0xeabde77b910c2c4a,
+                    0x0adb42be6b2b838d,
+                    0x47a706286c479cda,
+                    0x184e5cf6f9adb85b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e7d27d8fdc66e20,
+                    0x74b2a203f941fb7a,
+                    0x9b908c9b8e5e83ca,
+                    0xf104c1fa177e1b1d,
+                    0x2c251b893901e56a,
+                    0x1961dd4207021e78,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdcab5610d3c6859f,
+                    0xb945895c1d21ff79,
+                    0x16f2966ebaba91f7,
+                    0xf052ba4971f87421,
+                    0x3b38f8de9bda7604,
+                    0x15e3f3dc9d8d39b5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2bea5cb250f38a26,
+                    0x75d7455e03106dae,
+                    0x56bf0833282effeb,
+                    0x70020cfcf53315a4,
+                    0x7c8d967797c49df4,
+                    0x0a8ccf9649eddb71,
+                ])),
+                Felt::new(BigInteger384([
+                    0x39d8d6eb140465d8,
+                    0x4967595342b6863d,
+                    0x5cba995b01dd9cc7,
+                    0x1da676f85169d807,
+                    0xdc46dc49b29c8bc6,
+                    0x14c112d79de2652c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xab6c15a03cac5d35,
+                    0xa3be941f8c3c87ca,
+                    0x999d06aa85a1690e,
+                    0x85910f4786d10588,
+                    0x519d0a50fa507172,
+                    0x0ab7f73f69017b00,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43b90d77a0c7ec7d,
+                    0x74b8b1c3c59ad45f,
+                    0x027b8460eabf11de,
+                    0x90b370bf4effaaf1,
+                    0x2c7184c12feb085d,
+                    0x0463c818fb3d24d8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x45038e7851bbd1ee,
+                    0x1cf767173c932a95,
+                    0x3e220fa982f6e9f3,
+                    0xafe120c1f555ac7d,
+                    0xc6f6601f7ab9ea04,
+                    0x01f134fe6f9da419,
+                ])),
+                Felt::new(BigInteger384([
+                    0x871acf01bf283c1d,
+                    0x71f7290bac4869fa,
+                    0x50872e6320a1ba4d,
+                    0x35827bbfaf1a75e0,
+                    0x527ab6890e328bb0,
+                    0x0a04fd025f3ccd46,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe60a1e24002bfbf8,
+                    0x6f98a15ae24017d8,
+                    0x5f5f2d98891396b4,
+                    0x6c1a22e2f9007a19,
+                    0x4c0a9936f5a60cab,
+                    0x1742432f3666838f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x13c3b8569e43ee44,
+                    0x113c4461cab030d3,
+                    0xfa37c448de7787a6,
+                    0x4a7625449687fbc3,
+                    0x85dfbcd2bb0d5435,
+                    0x0abfebb20253ebaa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xadce8e9983ed430e,
+                    0x5ceb3847e18435d0,
+                    0x09000eb238e28a49,
+                    0xc66272a207b10268,
+                    0xdf9d204fa9d47227,
+                    0x109e4093f8755c37,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17ee8df742d86558,
+                    0xc4aa75220a9d3749,
+                    0x355af66bf1613f86,
+                    0x6149764fde4e01d5,
+                    0xc786d67d36075c0e,
+                    0x03678bec048ebc69,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf847ea79dfc4ea70,
+                    0xf96b3726b3b5d269,
+                    0xcbc4a16633865ff9,
+                    0xcd7ea0d6364b2312,
+                    0x1a3fa32f5bd78164,
+                    0x1353f70824389fe1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa1ce8b444d7935d6,
+                    0x22ec8899308e30ab,
+                    0x6b683aa223b481e6,
+                    0x18df054de4e86b23,
+                    0x349b7ef3d1d3b1da,
+                    0x0772b704ca1c6b5a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfc6301d3e1e28a20,
+                    0x02960bcbffdde25b,
+                    0x6eee9e2535216e7e,
+                    0x9c2f65f4a74534bc,
+                    0xbccb8010915cc9f6,
+                    0x16dccb5601af02b6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd733bb0b8549e8c4,
+                    0xc734deaee9912a94,
+                    0x644c511506bc7d37,
+                    0x010ed3602f53c072,
+                    0xde27fcb0c393a16e,
+                    0x0704cd281cbb4373,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x61b8edfa5206e297,
+                    0x94ce4b280b716767,
+                    0xfe56774392682515,
+                    0x6ba7969fd83c36a9,
+                    0x74b524782ae2e3b5,
+                    0x0f246bf0e3955215,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3b0d40a2f16c1ebe,
+                    0x0fca7127fc979610,
+                    0xd9f2609623331ba8,
+                    0x52a552f08fb234d6,
+                    0xb1a00d406bf1fe2c,
+                    0x018d0f9cc996e2c7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x67afde9d5f673feb,
+                    0x5943589704e20783,
+                    0xfa980667a60285cd,
+                    0xc5ef2a188b7995f8,
+                    0xb2f8e60cddd7bd61,
+                    0x11841b4c45afc9e0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbf4994db0196bf9d,
+                    0xb1c9cf74ad135f80,
+                    0x35435e83acd7d44e,
+                    0xba70ed819d0ca758,
+                    0x8e24952382d6c3b0,
+                    0x0359263747bed98b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4cf0f9221db1cfab,
+                    0xc4e3407a95b72fb4,
+                    0x7890149560dc28bc,
+                    0xe3c68df1c339af86,
+                    0xd7dfb73aa46831e6,
+                    0x023976fe74eedef0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4215f3c057063c8e,
+                    0x613c1b280b51376d,
+                    0xd85205da98430b5c,
+                    0x557ef1e06d6cf148,
+                    0x549187b93b9ecbd6,
+                    0x16aafc6ab1a4168c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6c600fbd9640d811,
+                    0xc4e08259eabd4b52,
+                    0xfe0090d95f6e1c05,
+                    0x7366ed4f3b5c53f9,
+                    0x7d31ffdb229e45ba,
+                    0x037b9fc73fa88e9d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6b9dc6ea300f577e,
+                    0x6cf966cba71e7d29,
+                    0x3161eef82422ce6b,
+                    0x8ce825da0ba2604b,
+                    0x7aca2bd274fd50a4,
+                    0x0b42dcba73b0dbc3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e71ee1766f01ca4,
+                    0x25989af6d33ae5ec,
+                    0x933bd8fe7f841c22,
+                    0x6e8e65c385895c44,
+                    0x135115359a0e579b,
+                    0x183f2504ddebf959,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe1b54387a06e0456,
+                    0xe5565061be2d6f70,
+                    0x11d5a4ca7c945ff8,
+                    0x603c7e115a63acdb,
+                    0xd512e4ec40ab5f2f,
+                    0x09290fb6584e730b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xec4788492691c753,
+                    0xf244767e0d0fe47f,
+                    0x6791e7d064e567e9,
+                    0x0e35373626557de4,
+                    0xcc42490f5d41e6f7,
+                    0x172238a1481bed72,
+                ])),
+                Felt::new(BigInteger384([
+                    0xef86108ef27cb2a9,
+                    0xff3c33148a3bc346,
+                    0x3e145253495e0bb6,
+                    0x99e2cc3d1099ec30,
+                    0x7d7e9f10863b425b,
+                    0x19f7ba74d9b1f908,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x18e9f53c7f551e9d,
+                    0x53c66ad6aa01725b,
+                    0x3bcb552950bd21f1,
+                    0x20876f1b0ec21ff7,
+                    0xa788caddb142d3aa,
+                    0x10e4d4e2a5292249,
+                ])),
+                Felt::new(BigInteger384([
+                    0x48ddd644fd644cf5,
+                    0x323154cfbe2f0a92,
+                    0xee8afd977f83ff24,
+                    0x178bfb7cd3c5fd03,
+                    0x03c1e027b24c45d3,
+                    0x0a1bd6987547dba6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x582df3e17b444e53,
+                    0x5d10f81d0220066b,
+                    0x82ca45129a1716ec,
+                    0x177f0231846dcec0,
+                    0x0f64a04a5b3f6837,
+                    0x0df3215f07e88abb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdcf82c2a7530923f,
+                    0x76d91239c893f985,
+                    0xa187f2d787f65b73,
+                    0x69bca7b4b4d0075c,
+                    0x38acee876a216710,
+                    0x0b3322fdbedd7794,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6941b0d5d76ce0ac,
+                    0x24296064ee0e0834,
+                    0x91ffc211b3f9a838,
+                    0x3494d122473a1664,
+                    0x5c3a39851a2c5392,
+                    0x03f88c993a067680,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa5c884c711d1c6f7,
+                    0x3596c9a880080526,
+                    0x9fc14546d3d2b60a,
+                    0xd9da61f2165a62cf,
+                    0x1ae9bd8063487a92,
+                    0x0f3c67efe9cf6213,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5d45e504129ec571,
+                    0x4b9e1497a4bb7407,
+                    0xaf6af49c51f41b8e,
+                    0x804c4465b50bb05d,
+                    0x956a9a100d0ce35c,
+                    0x01017b769b772a44,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfd9d1a4cc1225846,
+                    0xda0a288b6a997634,
+                    0xb663713ece370cbe,
+                    0xcea2d0637084f187,
+                    0x902ea421ca8442a2,
+                    0x0184f2da50708319,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd575467d1f8ab883,
+                    0xcb618166c97d198a,
+                    0x5a03d59969a703cb,
+                    0xa1246e46deeb35cb,
+                    0x0d7a6d711ccb0cd9,
+                    0x0e348117bc6e0c15,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdba043a6896d2774,
+                    0xcaee7888eca800ad,
+                    0x7791fdaf8e535b81,
+                    0x1daa989e734f14af,
+                    0xd690d6d3b02ae76a,
+                    0x15fedf069334a18b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9d5af3f52612d61d,
+                    0x792559902b0b1f6e,
+                    0x8b3a94d5ccb94036,
+                    0x0e62dc5b2038ea9c,
+                    0xdbebf756312be029,
+                    0x01c959fe2a930b80,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdcf901965d72e86a,
+                    0x073217a604454547,
+                    0xbc3fec390f34b2dd,
+                    0x30a2d65d9fbf68be,
+                    0x0ffb9fd7b4be6cc5,
+                    0x0430664b30c79aab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9f5f229d01a96b74,
+                    0xc2dd8a4215a6b587,
+                    0xb978c8d41e899905,
+                    0x93a0672fb3b06483,
+                    0xbc0306a8f44b1d0b,
+                    0x069aaa1e37da0c58,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1de3bcb568e9d74c,
+                    0xbb93210979be4202,
+                    0x7607272ff24ae34a,
+                    0xbc3cf654000fc7db,
+                    0x51335c49a1d7b451,
+                    0x171734e028298513,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f7875fdb63ef77d,
+                    0xcb397e127cef2daf,
+                    0xbfaf0ba4af593187,
+                    0x2a3557b2b9120b16,
+                    0xa75aba9ec6a85536,
+                    0x05d65ca2a82acf09,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7475d4559e39568a,
+                    0x070a7da65f11691f,
+                    0xbaafe46148630375,
+                    0xe1c2ed380443345c,
+                    0x89a152fe153ec797,
+                    0x0dfe44dec15ec3e6,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb74badc83bf10015,
+                    0xe6b0386ab54460fb,
+                    0x09fb44930936c7c5,
+                    0x92d401d3a8742256,
+                    0x08d9cfa8255ca199,
+                    0x14c999085fb8914f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x95ae80c96ec52abf,
+                    0x12a5a0184060dc2c,
+                    0xdfb35b68f1363727,
+                    0xeeb34aa43e5f5612,
+                    0x1ca95a9d3469faaf,
+                    0x02b293a3b12286b8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb687808a02271fc5,
+                    0x8cc4ddb29d697198,
+                    0x73271d85f5bbd0ec,
+                    0xf51bf95830a9b089,
+                    0x50db1c4b25e72c87,
+                    0x0554e6d71ab28c42,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7bdb3c63aae92f11,
+                    0xdd8e8b74a248ee85,
+                    0xb1f262a89053d869,
+                    0x2df7bffe45d385a9,
+                    0x26d66652e8705bd6,
+                    0x10e36b27a2833466,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd916a30ab4d5dd26,
+                    0x56217108eeab22ac,
+                    0xe1a64b623c8857b2,
+                    0xd70943245dc91964,
+                    0x7a8b1a3d4755b15c,
+                    0x1674a965eac6abd7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93ec42faf35e5d81,
+                    0xfa26e43e44d3771e,
+                    0x73b5860e4d47873e,
+                    0x01e92c61c7d2805d,
+                    0xb5f1d119f6af9444,
+                    0x1083c2fc73e0f8b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6b536a80e44ea660,
+                    0x671a8b3131135c04,
+                    0x87c828c03b50076d,
+                    0xf6b7565d1da1d426,
+                    0x0e20da591df144d3,
+                    0x0c66117e4ca8d423,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc570718af83b6555,
+                    0xb82507bbe3f33678,
+                    0xc03798aa21b687c0,
+                    0x8005ef37c65c3ff0,
+                    0x3cf77861199c7b6e,
+                    0x03279f137176ee29,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xdad3e24ee4c1b5f3,
+                    0x39c2b541a9c1daaa,
+                    0x4e0ab57e4a9b04fd,
+                    0x1d01d22d02ec802c,
+                    0xbbf22a1b0ca831c9,
+                    0x070404562e064d66,
+                ])),
+                Felt::new(BigInteger384([
+                    0xecadeda0a9ebd09c,
+                    0x73c7961889017262,
+                    0x6147aec088f9ed34,
+                    0x28f78857b1acdedc,
+                    0x73996bf167f7b0e4,
+                    0x1048dbf69419afca,
+                ])),
+                Felt::new(BigInteger384([
+                    0x85060d12cc023a6f,
+                    0x74341055fcdd3958,
+                    0xf5d51b335fa3bcd3,
+                    0xe46fe609110798e9,
+                    0x0476057ab8bc2fe1,
+                    0x180d1df86b49af2e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf0b360bdccd61ba2,
+                    0x5c086041c5390b76,
+                    0xfc86c8c8ce2d7fa1,
+                    0xbf92c59a830c3f78,
+                    0xf54657328ca6fac8,
+                    0x02e38d1b119098b4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3d4b355e6496c57d,
+                    0x93e4af22e2ef4298,
+                    0x47a91775b217bb82,
+                    0x01a8696168e87318,
+                    0x3fc59e249455bc88,
+                    0x122e60de8ba6b386,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd904dc0125a1b17d,
+                    0x2bc39c026c07b8c2,
+                    0xfedc36975714f5fa,
+                    0x5e03c4de7f427cb8,
+                    0x8808d04555fb07d7,
+                    0x10c33aa4fb03ea72,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa217c435736629d8,
+                    0x6f8b11f4e3d40d10,
+                    0x0d61141aa5f0302e,
+                    0x564ca7874d4b4342,
+                    0x1f6998d684f4590a,
+                    0x144882ca4971d29a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x111a681a4bc7c87c,
+                    0x111f19fde99cbd7a,
+                    0x6f21b67a1fe48c7c,
+                    0xe9eb8070928830b0,
+                    0x05c6db8c6654d339,
+                    0x070731031e0da275,
+                ])),
+                Felt::new(BigInteger384([
+                    0xffad1762b447feb1,
+                    0xc85558feaa06da2f,
+                    0xfd7ca5cce4422090,
+                    0xe9b42eb05c895984,
+                    0x606cf53a017253ff,
+                    0x16d2f26ace472821,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfa27486133b2a60a,
+                    0x714ca9d60441b1a9,
+                    0x17e821757c698524,
+                    0xb56202013b7f47cb,
+                    0x2f04c46182cd1231,
+                    0x193425c5a91216aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x98b92e3f002daffb,
+                    0x3da94fc10d66df03,
+                    0x001f7484831d6c13,
+                    0xecda486808ab33c5,
+                    0x13e7305df2c51aed,
+                    0x0427e3203a6e76a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd5848aa9362139f9,
+                    0x78a518eeb1fed70b,
+                    0x494a13cf996c2ef8,
+                    0x5cf10c0c4750db0d,
+                    0x5db4c2aef16f3c6c,
+                    0x14138b9f5b4d2514,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf28ad3297cb2ba29,
+                    0x4dbe3923f7c4cdf9,
+                    0xbc1a5053298e0d84,
+                    0xe7f9f9b07b794ff4,
+                    0x9d64e1646c45f2a9,
+                    0x0d72dbc0dc736929,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2986489044841332,
+                    0x982b45ed13e742c0,
+                    0x8fbeea98841d8374,
+                    0x6e11af7346bab7fc,
+                    0x8a61ebd5e95cbe73,
+                    0x1363c0f885086dc7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe9cb0cc0a81e4a02,
+                    0x7bb55727959eb7ce,
+                    0x8248496545687a00,
+                    0x1504645455d1f905,
+                    0xdd5c6fc400db9e54,
+                    0x180d8461c7f19b94,
+                ])),
+                Felt::new(BigInteger384([
+                    0x167dbed779afa906,
+                    0x873ce6e3dd176034,
+                    0xf1c1de80d6b1c550,
+                    0xa377a817feb4a900,
+                    0x77fabb53a8339422,
+                    0x191d49398889a35d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6a59469eaa21035e,
+                    0xf40a9995dc591f3e,
+                    0xd97bfa05189af66f,
+                    0x44408eeda013e748,
+                    0x5c1a9aebcf3e52d6,
+                    0x0cae6a9fefa377d5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa0ac52a45fd280d7,
+                    0x9c40f5da6bc781e2,
+                    0x9f8e1ec163710011,
+                    0xc5330e674cc4a144,
+                    0xe141afd9e4a93456,
+                    0x148df3ea1d009ac3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x80f17575e54792e6,
+                    0xdc05a8ca2bdbf237,
+                    0x385eddc269a041dc,
+                    0x2e3b5ac3eea8612c,
+                    0x527117e998899280,
+                    0x19074a719ebfaba3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66effa69f2b33931,
+                    0x48d25425b07569a1,
+                    0xeb287ecf68c990c3,
+                    0x41a880b780467f65,
+                    0x9439ea4e2826b286,
+                    0x0216c2501a53b7bf,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb9ea3eb45cf01740,
+                    0xba9a250a96e15329,
+                    0xb5c047a150824c28,
+                    0xb11a7626f9d2b15e,
+                    0x1d43a8c43ff9c02a,
+                    0x0d3c0f2482723ebe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2a989b547d6b3b0a,
+                    0xa7309397e7603887,
+                    0x820b5995a70abeb4,
+                    0x76b93bdfd834d93e,
+                    0xbb5f997d9683eabf,
+                    0x08f40ad10bf4546c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe713a955500c51be,
+                    0x53eabf0a37b58317,
+                    0x81c99affd6354f23,
+                    0xe489c771f3032265,
+                    0x1009d5a7922ca508,
+                    0x0a30f88828a67383,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6a88f63565144741,
+                    0x1b30043faf7fe40d,
+                    0x21e903479a0628ad,
+                    0x03ab390b32f19a77,
+                    0x74b82ed3de084495,
+                    0x15ffdd0cf5257ea1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x557c9c05a503fa5b,
+                    0x6c5ee6ec0a1b95cf,
+                    0xf21e1b8633a0eb42,
+                    0x30e1f427bcda7928,
+                    0xa148ab651da00f18,
+                    0x0c85768744bcf90d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa33dc475229733bd,
+                    0x485f67a2f5994891,
+                    0x0af41aa68592f9e7,
+                    0x4bf59b19b8c15b24,
+                    0x72318bcd8a64d7f9,
+                    0x19caeb2df8e935c1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc84b94344ab5e356,
+                    0x56240dfa1a6ca0e8,
+                    0x557f673578c5b048,
+                    0xc33ef0a456f31195,
+                    0xfa90f76a4c77f17e,
+                    0x0f64a4a3f106d9c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x887f25831f75be76,
+                    0x170fa60eb87f618f,
+                    0x480780d1c9c8a063,
+                    0xf93cce931e3adf44,
+                    0xbb7a604edc79fa6b,
+                    0x037477e780e9a9fc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbcd3bb323281226a,
+                    0xe506e3e7498172a7,
+                    0xb90f4cf8cc51821b,
+                    0xe3041f925f8575f6,
+                    0x88f418a203938fe2,
+                    0x16c5faffd4566599,
+                ])),
+                Felt::new(BigInteger384([
+                    0x74f425974cbfec8f,
+                    0x52e7adb49b8249b9,
+                    0xa1351eb06ba3c8c7,
+                    0xb88883f52f301d52,
+                    0x483d3e8e29d41299,
+                    0x02af5943fde3a483,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0aca35a168cc0a21,
+                    0x30bc34f1f6bd3b1d,
+                    0xbdb2d12452164936,
+                    0x6839d63470aaf93e,
+                    0xa8fbe3b01df24702,
+                    0x00d19e73667f6502,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b4e870607b8b2f2,
+                    0x0ad9b00877552002,
+                    0x1b1e389494069071,
+                    0xd26a9ea9414bf5e2,
+                    0x7dbde418f56b0442,
+                    0x0e3e7db8f1a5823b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe28a3e67f5a841c2,
+                    0x1afdc9262728bed8,
+                    0xed6481190008ed26,
+                    0x8e30bfda4d5b63f4,
+                    0xf0b476434f3621fe,
+                    0x13be5884fb0d8f48,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7c71ab073584d81d,
+                    0xb0318f48c557d460,
+                    0x7f4d62765f7b5f8a,
+                    0x59d5385b07db3298,
+                    0x4b97d6621f4efa1b,
+                    0x1161adefbd8699bc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc89776065699fd81,
+                    0x48c56fea6274459d,
+                    0x7d4c3ae0f83955e5,
+                    0x05db9eb3ab01ab95,
+                    0x34c1fc98c4b660fc,
+                    0x18f55c97ba1df96c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x71848bcdcb33ec29,
+                    0xa4e9be1ee5e97108,
+                    0xbfe5054220404d69,
+                    0xdbf89deebff30970,
+                    0xf9aac9b99a0eaa0a,
+                    0x124bca0eac4ceb8d,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger384([
+                    0x984400000016aa94,
+                    0xda500058e5b00012,
+                    0x97080d3e78fe9e67,
+                    0x504ff0af50a70528,
+                    0x0ca773961fe616c9,
+                    0x17b73dc8ba06bf04,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa270000000cfff3,
+                    0x53cc0032fc34000a,
+                    0x478fe97a6b0a807f,
+                    0xb1d37ebee6ba24d7,
+                    0x8ec9733bbf78ab2f,
+                    0x09d645513d83de7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa270000000cfff3,
+                    0x53cc0032fc34000a,
+                    0x478fe97a6b0a807f,
+                    0xb1d37ebee6ba24d7,
+                    0x8ec9733bbf78ab2f,
+                    0x09d645513d83de7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x984400000016aa94,
+                    0xda500058e5b00012,
+                    0x97080d3e78fe9e67,
+                    0x504ff0af50a70528,
+                    0x0ca773961fe616c9,
+                    0x17b73dc8ba06bf04,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa270000000cfff3,
+                    0x53cc0032fc34000a,
+                    0x478fe97a6b0a807f,
+                    0xb1d37ebee6ba24d7,
+                    0x8ec9733bbf78ab2f,
+                    0x09d645513d83de7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa270000000cfff3,
+                    0x53cc0032fc34000a,
+                    0x478fe97a6b0a807f,
+                    0xb1d37ebee6ba24d7,
+                    0x8ec9733bbf78ab2f,
+                    0x09d645513d83de7e,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0x984400000016aa94,
+                    0xda500058e5b00012,
+                    0x97080d3e78fe9e67,
+                    0x504ff0af50a70528,
+                    0x0ca773961fe616c9,
+                    0x17b73dc8ba06bf04,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa270000000cfff3,
+                    0x53cc0032fc34000a,
+                    0x478fe97a6b0a807f,
+                    0xb1d37ebee6ba24d7,
+                    0x8ec9733bbf78ab2f,
+                    0x09d645513d83de7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa270000000cfff3,
+                    0x53cc0032fc34000a,
+                    0x478fe97a6b0a807f,
+                    0xb1d37ebee6ba24d7,
+                    0x8ec9733bbf78ab2f,
+                    0x09d645513d83de7e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x984400000016aa94,
+                    0xda500058e5b00012,
+                    0x97080d3e78fe9e67,
+                    0x504ff0af50a70528,
+                    0x0ca773961fe616c9,
+                    0x17b73dc8ba06bf04,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa270000000cfff3,
+                    0x53cc0032fc34000a,
+                    0x478fe97a6b0a807f,
+                    0xb1d37ebee6ba24d7,
+                    0x8ec9733bbf78ab2f,
+                    0x09d645513d83de7e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa270000000cfff3,
+                    0x53cc0032fc34000a,
+                    0x478fe97a6b0a807f,
+                    0xb1d37ebee6ba24d7,
+                    0x8ec9733bbf78ab2f,
+                    0x09d645513d83de7e,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x243fbbc5bc38f716,
+                    0x02fbe6e4b78eae6e,
+                    0x9023d651acf25a16,
+                    0x9b6f82e61f74df21,
+                    0x88635bd9aaf62287,
+                    0x0577fb397a0a0b1f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5d8fea1526b2a617,
+                    0x589a6c08c9d5bfbf,
+                    0xcc9af563a57a7f90,
+                    0x45ea8f8cef9e6b5d,
+                    0xa24051955f80e8c2,
+                    0x1364f86925b38e58,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb35dbf513f72219b,
+                    0x1e2910f476ba6111,
+                    0x24d08fae9071c7ba,
+                    0x7e7c7bb0e18352a0,
+                    0x59bc7635b36ceaa9,
+                    0x025bdec6e8702c91,
+                ])),
+                Felt::new(BigInteger384([
+                    0x35039e55b9e954d9,
+                    0x9f86ca345084a2f1,
+                    0x95d95f6ff3d1c4f9,
+                    0x744f6f4368915c2e,
+                    0x45d9b927ef0e2743,
+                    0x01f5ced3262a1b55,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3db7d2db23e50393,
+                    0x590d0baa28151249,
+                    0xd8620cfdaed6ba9b,
+                    0x7a5a6deffabe5c03,
+                    0x673f7518c0530d19,
+                    0x0eb7a3cf704be8c8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd050a77bbba602c3,
+                    0x723d5a8c9477496a,
+                    0xbc5389099c100058,
+                    0x57f8c631ed157ce3,
+                    0x66a3145484b62201,
+                    0x040165a8b0e21cff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x546dfae2c2d3ecc0,
+                    0x1f645adf1c89733d,
+                    0x8bc6c70bed06b005,
+                    0xb399897ece6ca948,
+                    0x0dc03f2ea90e7a23,
+                    0x0654370f45b911a4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3e8e5b1527be9a60,
+                    0xd3fcddf2bed67eeb,
+                    0xa91a70eca0f71183,
+                    0x4ab1ee4eb1dc017d,
+                    0x4fd30f6a67967a95,
+                    0x14f374ce910df81d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe0f907e7e6dcc587,
+                    0x7b3296e8f8a3b1cb,
+                    0xb8fedf3a62a52121,
+                    0xb74a6725a29deb3a,
+                    0x0ecfb306eb187f9f,
+                    0x1269052e0c38a296,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa629ee89d1c3915c,
+                    0x0ad705b921a88915,
+                    0xf49d8b39bd3d2a0f,
+                    0xb1c8bd3445bc02bf,
+                    0xfa0a021444f7106d,
+                    0x04235ea81fcf329e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee8e49aa9db4e0b2,
+                    0x5b8fbc5e65411963,
+                    0xaad825fd27fab90c,
+                    0xeba0c16cdff078e9,
+                    0x396e2c636337220e,
+                    0x16a1967674dffbdb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8d202f886a2feaa3,
+                    0x27ec62496e6646bd,
+                    0x930f790c2eae9ffb,
+                    0x231907d3ad1c64e3,
+                    0x1e50985fd32f2acb,
+                    0x019553d1063c5bd8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1c8ce84eb2f8ca5c,
+                    0x5b29f6d6456dd41c,
+                    0x46f29a9cff8c4b4e,
+                    0x6eb84fe8f903352e,
+                    0x831b98bfe0471138,
+                    0x01df87cd52b7faff,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc1aa353b1174615e,
+                    0x81502d6422ed8058,
+                    0x1ad755d35469a28d,
+                    0x52ae8f3d9348c1be,
+                    0x012cb59f8d76e73f,
+                    0x02ee6fbe44df5a4c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8468fba3e6087c30,
+                    0x72050f5f36679683,
+                    0xbbda2f622f402c35,
+                    0x8a1b701eac752b6c,
+                    0x0c50cef63c2ce86c,
+                    0x1254d90792e10e7b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x81366306cb4630ac,
+                    0x74bb774e2175003b,
+                    0xfd538b2e5fe2649e,
+                    0x860739df6784f464,
+                    0xe5f76c08fa84890c,
+                    0x03ebb5e366086b58,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0a0416f1fd53d640,
+                    0x42c15d3bee53618a,
+                    0x26fb5de5455c9eb6,
+                    0x22fd0af60fb2a251,
+                    0x4bfeca4a3a6ba8ec,
+                    0x0573bb3e7137a585,
+                ])),
+                Felt::new(BigInteger384([
+                    0x17de9eb92dc4db43,
+                    0xce3b0fec9ee92094,
+                    0x75e64c0a7df6c5f9,
+                    0x283d3d50a496f358,
+                    0x7b3a5198a4c21477,
+                    0x1305ee8f11d16097,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2f8716e8b95b8cf7,
+                    0x9201bda9335d39c6,
+                    0x18c2a476bc6d6243,
+                    0x4dccc5646ba36c09,
+                    0xb6e98d7ea481f729,
+                    0x0cdc876997e9dc7a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe7b7ed4891dba541,
+                    0x5cb44707a76c022c,
+                    0xc0a64692860c7ea9,
+                    0x092a30bf7b8a1dd0,
+                    0x8afb0453ba0c6bad,
+                    0x019407364af813aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaebf240a1a3320e7,
+                    0x7e1fcac73c66a13a,
+                    0x4344dcb39f2a745d,
+                    0x864b155c705fcd9e,
+                    0xc0287379129c3e02,
+                    0x0d5f42836745808f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7190c432cd7bed98,
+                    0xb07dfc29f8c516eb,
+                    0xf7d569252db36fc8,
+                    0xe80e6071ba0a5524,
+                    0xf5a185208e36ed67,
+                    0x016bb159e67622a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xecced633aff76468,
+                    0x9ecfea7b04a1d833,
+                    0x40987ed1c79fff15,
+                    0x71302cb7e2d95c9a,
+                    0xb0658fc059f11245,
+                    0x1817d94cfc34b463,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa949e43482987b1c,
+                    0x4b8a15ed54f722c4,
+                    0x4056c03bf6ca8f84,
+                    0xdf00b849fda7a5c1,
+                    0x050fcbea5599cc8e,
+                    0x1954c70418e6d37a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x149a5522f1c62224,
+                    0x1538465a9de1ecbb,
+                    0x45095855b002f3f7,
+                    0x967b1b6a2615b061,
+                    0x194fd562bb7e2660,
+                    0x0584f506b43513d3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x155388e35d079f6e,
+                    0x23ae6a85d1e62033,
+                    0xd9af5f55bd5d5972,
+                    0x3a66d9803c77d1f8,
+                    0x7264d69aba5d7060,
+                    0x11178728acb41526,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa28490b4b755b673,
+                    0x39e94377c1951519,
+                    0x764e13a67838946c,
+                    0xa809dd03a25f398c,
+                    0x191c8a958b858df9,
+                    0x0e38590c006a346e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16efc7b742a58f2e,
+                    0xeb274b9a8d88c660,
+                    0x6ee7102d49df4dd7,
+                    0x1fe56bd6cde1e4c5,
+                    0x3435b23e0019ab00,
+                    0x035b7c3b51241f77,
+                ])),
+                Felt::new(BigInteger384([
+                    0x88c72bcfbe2ce13a,
+                    0x4761ddbaa4ae7f80,
+                    0x83229aabd4d59585,
+                    0x298ef528d7a63f12,
+                    0xfd0a7017a30fea7d,
+                    0x065d32289a8d777b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbd1bc17ed138452e,
+                    0x15591b9335b09098,
+                    0xf42a66d2d75e773e,
+                    0x1356462818f0d5c4,
+                    0xca84286aca297ff4,
+                    0x19aeb540edfa3318,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9f4de32223164145,
+                    0xb0afaa536e5f0de4,
+                    0x4e028a0a9d02473d,
+                    0xecbbc9cb9454e7fe,
+                    0xb497a286b64ce596,
+                    0x17ec3ecda34c91e9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa72f39775bd5eb41,
+                    0x3439774959bbd8d1,
+                    0xe53866a8f8ccc7ab,
+                    0x8e175d8865a3918b,
+                    0x71eb64e32b323593,
+                    0x028aa961db40389b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x896e30b0cef95e4b,
+                    0x68a3e313daa45515,
+                    0x1cb78955418b19df,
+                    0xcc97bf677c783995,
+                    0x754e7a063d3a076e,
+                    0x099f013a8031f4ef,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ea4eef66f9217ac,
+                    0xf672bbbee6a9bf04,
+                    0xb1bdc02355e99f04,
+                    0x04b6a98216f0463d,
+                    0x032dfc1af5a45b1c,
+                    0x0f4b9011f3bb2404,
+                ])),
+                Felt::new(BigInteger384([
+                    0x050257e28cd899e3,
+                    0xf20e4c9ebf115f68,
+                    0x06398d2cf35d7ff7,
+                    0x6818cac9a01be1f9,
+                    0xe4497bf3d37ff8b7,
+                    0x00014cc734f864a3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x512822e1adedbf47,
+                    0x8aa22d405c2dd0a5,
+                    0x043865978c1b7652,
+                    0x141f312243425af6,
+                    0xcd73a22a78e75f98,
+                    0x0794fb6f318fc453,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,897 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger384;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0x554e0000006f54e6,
+            0xfb9801b4a468005b,
+            0x5e4d31fe251edcdf,
+            0xec5b788a48618a47,
+            0x01eb303433447efa,
+            0x129aa068fb2122b2,
+        ])),
+        Felt::new(BigInteger384([
+            0x36bcf2f85421d531,
+            0xcdb0b77548eef61d,
+            0xb9b1aa5f9670c1ed,
+            0x53e8474b8ecb1eb2,
+            0x4f7c9d6872597084,
+            0x151cd29979b576c3,
+        ])),
+        Felt::new(BigInteger384([
+            0x8a6fbae6e771c3b9,
+            0x729d0347d47efcf5,
+            0xb34f74a6a561c933,
+            0x0ab8a3ce15e6f905,
+            0xef078ec90304f282,
+            0x19bb04b5763b793f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xaf8842374610fd4f,
+            0x44977c689d0bfbff,
+            0x3219561f9f572518,
+            0x219ad8d001cefc41,
+            0xa788eb5936eb3069,
+            0x114906e42d3525b2,
+        ])),
+        Felt::new(BigInteger384([
+            0xc0a18965503674fe,
+            0xf6ba280d987ad5cd,
+            0x182bfd3839f74553,
+            0xba847ba0dda11ee1,
+            0x745076b098c11027,
+            0x15c43e198cffdb41,
+        ])),
+        Felt::new(BigInteger384([
+            0xa7da1a71446ddeb5,
+            0xcb35d0f311ff63f6,
+            0x87398bab2b56b4d0,
+            0x51fe3c194bf878d7,
+            0x4970a34b1716c197,
+            0x0ee61101114891be,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xe3b9df48c8a2dce4,
+            0xbb2c1b9b44487dd4,
+            0x3f7c3a95f53d835f,
+            0xf7f5035d40a06106,
+            0x092ea343ea1e465c,
+            0x0ce327ca3934c193,
+        ])),
+        Felt::new(BigInteger384([
+            0xcd50471d39f8c23e,
+            0xc0a0bcbea3e84339,
+            0x08b6545993fa0e05,
+            0x07fae58eb8c367a1,
+            0xa422b22628d77692,
+            0x13a9da8dffccd33c,
+        ])),
+        Felt::new(BigInteger384([
+            0xe12faaad3c0356b4,
+            0x99d324098586e4b3,
+            0x05813be57b17d0ae,
+            0xfee6af2366eb6ace,
+            0x2de1585c01795504,
+            0x03a54209d80cd454,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x3016ef9979d96cf6,
+            0x5856c522bae21569,
+            0x80391bdcca543195,
+            0x0b8877e14e2ab858,
+            0xe493ffb341473727,
+            0x11182fcaaa29983d,
+        ])),
+        Felt::new(BigInteger384([
+            0x53cb4d96bd4c75a3,
+            0xbda7fefa1e79bda3,
+            0xc9392cbfb1aac667,
+            0x4ac2f81ab7fd904b,
+            0x5e00bb13061ffcaf,
+            0x0d88edccad8d8f98,
+        ])),
+        Felt::new(BigInteger384([
+            0x89b6cd6e943d5552,
+            0x99d0c30240adf82c,
+            0x46eda3649c6c96b5,
+            0x07c3785863e0890e,
+            0xdb2a97f7119eb52c,
+            0x0c392a075af8a5a3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xab21fd59b9c21f9a,
+            0xcbdc24518e56b16d,
+            0x1802e4c76b194656,
+            0x91ee92ec97003e5b,
+            0x1617a16309943af8,
+            0x06a590e9f424abbf,
+        ])),
+        Felt::new(BigInteger384([
+            0x13eb741fc42a81b3,
+            0x08c118a1b0353a6a,
+            0xb457e25d6a327d2a,
+            0xeedbd59b86fcd9ed,
+            0x2c5235d5ab85dfb3,
+            0x051b72d12b934685,
+        ])),
+        Felt::new(BigInteger384([
+            0x212eadb1245ddf20,
+            0x15b0086c20c2704f,
+            0x8a828fe1ddbfe2dc,
+            0x9d4259210b28d7b1,
+            0xd3b75e3b57eca6ff,
+            0x05f5e9d2b4890128,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xa56afd4698b3e027,
+            0x82e066b3a4a90aad,
+            0x98a848c960ea604f,
+            0xdebc932ee883dd9b,
+            0xaf162edfb610ad02,
+            0x122690f274fb48f1,
+        ])),
+        Felt::new(BigInteger384([
+            0x1d4150d3b5707004,
+            0x458d72b599d11b23,
+            0x7fbfaa7ed51d15e9,
+            0x1841cf7b1f4336f3,
+            0xfa56bc8cd10b0fb9,
+            0x16c2008dabe1c052,
+        ])),
+        Felt::new(BigInteger384([
+            0x589ce841b909139e,
+            0x7f31d69a94351938,
+            0x00e392d16f552cad,
+            0x932b631e35bc9c04,
+            0x8c368f68e2b7cb47,
+            0x0ac19810dc94b271,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0ac633ec0f9ed5c6,
+            0x2d4975e1432bc04e,
+            0x886a94c421734588,
+            0x9628d9f0fe1e020c,
+            0xe540a1f286234168,
+            0x1872e7c1a537f17b,
+        ])),
+        Felt::new(BigInteger384([
+            0xa34913ab8eca284c,
+            0x77d02eb60c93fc92,
+            0x6921cb8fc167dbb7,
+            0x62976f72cb98fcb9,
+            0x7ac798b130a2d325,
+            0x0dc08d9c33429d1b,
+        ])),
+        Felt::new(BigInteger384([
+            0xea69e572fe69f9ff,
+            0x2ec259fa4a468535,
+            0xfae4d22d6df118dc,
+            0x598c2cedf50e925e,
+            0xfb747e30afd94e28,
+            0x1456c4c8b7803d33,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x1266bf394049156f,
+            0x23d6e9c0313b88a5,
+            0x996e80b65d93210a,
+            0x739ce296e4c945de,
+            0xdab1df119a7c3760,
+            0x05feecdf2b61e634,
+        ])),
+        Felt::new(BigInteger384([
+            0x06925d47888290ca,
+            0x8d0fd081430ba3a0,
+            0x28dca818963b549e,
+            0x71efac4fff570af1,
+            0x2ccf8ecb28664c70,
+            0x1726cb40a95c5c6b,
+        ])),
+        Felt::new(BigInteger384([
+            0xa9589fd3f5c10ca9,
+            0x926d547862a64996,
+            0xb4d264cb45ea6cde,
+            0xe7edcd174d15a158,
+            0x1d0bb1ec87a1e763,
+            0x01e41523b5f309de,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xc0d83808eb7f604f,
+            0x6d93e2aee6304bfc,
+            0x6596c5b8c5da2f8b,
+            0xf5ea136838bdf4df,
+            0xf9ed67ab58387808,
+            0x0a1a018aca7dc1db,
+        ])),
+        Felt::new(BigInteger384([
+            0x1d81e6c71e045f30,
+            0xc48345aad756021f,
+            0x5f1b821e399a0329,
+            0x5ee47b0cf90d5af3,
+            0x7b8717482d4a03af,
+            0x125e6dc5b55f169e,
+        ])),
+        Felt::new(BigInteger384([
+            0x0b0741b0f12bead9,
+            0x941450e185ad58ed,
+            0xa9784b10938a9853,
+            0x24316da51be17b36,
+            0xba60c2bf6002d6bb,
+            0x10eb0c3d8c4bbb28,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9becb1b618c73764,
+            0x256667fd6b696a00,
+            0x7f1157ab9fdd2607,
+            0x67817025c977bcca,
+            0x26dc483c1d3480cc,
+            0x0a8b3a522660ef70,
+        ])),
+        Felt::new(BigInteger384([
+            0xaa16221373bb9885,
+            0xe67930db105366ab,
+            0x9d80e4b6f7bd8156,
+            0x4e1caed917481f87,
+            0x839c09665b9be458,
+            0x17cf243d49039e50,
+        ])),
+        Felt::new(BigInteger384([
+            0x3e1aafdbb109638c,
+            0x3ce9d2a936e83eae,
+            0x1b05fa0a18068b73,
+            0x5aeb0b2a92c92517,
+            0x944c20da054ba2fb,
+            0x19ea48e206c7be83,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x89422710ae50060c,
+            0x0ec87f403bafe63a,
+            0x59b0f6af25e5ec0b,
+            0x51dadef6ecdbfdc2,
+            0xba19f0a40779927d,
+            0x18a860ddbb27de9d,
+        ])),
+        Felt::new(BigInteger384([
+            0x3206047cd513625d,
+            0x76224320afb308df,
+            0xf6fe09d24c392388,
+            0x7d8817abd23a5930,
+            0xbd4324a3a97272f3,
+            0x0c4d748950dfc563,
+        ])),
+        Felt::new(BigInteger384([
+            0x526117937daf4296,
+            0x4bb63823b5576b9d,
+            0xe632b73273eb2f9c,
+            0x363518360dbfbb5e,
+            0x87ccb6dfb02984a4,
+            0x0005ff3765f7fea0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xc528ae62d491768d,
+            0xf820b0da1c6d3d7e,
+            0x27c0edb2252e9c9c,
+            0x62c17bd6c5a72c9e,
+            0xb35ad2b9c4d8178e,
+            0x0507bf0ef358f4a2,
+        ])),
+        Felt::new(BigInteger384([
+            0xf8e5d7eb15d50ef6,
+            0x4ed0470ae5b58700,
+            0x3b3564213d9a82c8,
+            0x64b081202c920aad,
+            0xd3ab70f9e2fd07d1,
+            0x06b8d3e901a1bef2,
+        ])),
+        Felt::new(BigInteger384([
+            0x9f8ac2bc5d2dcaf7,
+            0x3f6785094c0c6287,
+            0x645ad2fd11973641,
+            0x0201a40c7bfd6c98,
+            0xe03fb4afffa51f07,
+            0x0f2f525460da692b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xe32ee000f77d2c46,
+            0xed46afaf8b2fdb60,
+            0x16a888f52804ef4c,
+            0x98638bb83592844a,
+            0xdd2c3cb0e15663aa,
+            0x0ef4b01c44becd36,
+        ])),
+        Felt::new(BigInteger384([
+            0x8ad96d71d509a6e2,
+            0xaaafa105e6789f55,
+            0x484f5ca2d4a5b113,
+            0x34d4f4c5e23e6460,
+            0xa2b2bcfe5b80ac94,
+            0x056ad1bbf6a3bd01,
+        ])),
+        Felt::new(BigInteger384([
+            0x8110fe4789791ad8,
+            0xa07930d72be778cf,
+            0x4ebfb1297100e473,
+            0x4b4d3f99dfc5be84,
+            0x7d67de819be8601e,
+            0x01e1c0de60a3f954,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x08db0fcf6c4b8dde,
+            0xc2a195af50242d1a,
+            0xa85e363998db58f0,
+            0xbe468471b6ed37db,
+            0xc88d958f2f5dc602,
+            0x1208ca208a34bf63,
+        ])),
+        Felt::new(BigInteger384([
+            0xad9b2d7e40bd5a96,
+            0x0a10e81b4ec85afa,
+            0x7c5ab4b0da0ebb83,
+            0x2714606fcdda8386,
+            0xb2e933c366b446f9,
+            0x019e37d1f2246a56,
+        ])),
+        Felt::new(BigInteger384([
+            0xec8e170133db1e51,
+            0x32167ee2ce000c71,
+            0x81d81db6a77bf953,
+            0x2d483b21608a17d1,
+            0xddd38ba5611cc4db,
+            0x08fd925d0c3a81f8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xbc585312cfd38e97,
+            0x261fcc33b76a8335,
+            0x924c4402ae766e84,
+            0x7d3cc3843d5abb75,
+            0x28606f15f50717c0,
+            0x19bbbd8d6982a5c1,
+        ])),
+        Felt::new(BigInteger384([
+            0xc5768d1bcee76628,
+            0x677944095cae02b2,
+            0x695e8c9397b9bf46,
+            0xf54bc481ab20dd1d,
+            0x9355b112872cc4f4,
+            0x0c947aa1c72e3664,
+        ])),
+        Felt::new(BigInteger384([
+            0xc2f418f501f8e4c4,
+            0xf80ea4ba2502ac14,
+            0x1313a6053b24c3c1,
+            0x246cde2ab76e42ae,
+            0xc1471d62decce80f,
+            0x0359ebc5122d9fb5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x041b6b988c893b25,
+            0x429d4bcf47f3d128,
+            0x5925471329191ea0,
+            0xcf1f5f4199430bf8,
+            0x4c9fecc2ff6ad097,
+            0x13cdc5feeb62d1ee,
+        ])),
+        Felt::new(BigInteger384([
+            0xf945ae366dcf4e6e,
+            0xdf7757d7f6ebd160,
+            0x15212fb4bb30ac11,
+            0xfcb1cc12b0a51899,
+            0xb09c859232616dff,
+            0x111cbf5707040042,
+        ])),
+        Felt::new(BigInteger384([
+            0xb2eb8647e82f3264,
+            0xbee54b23ec90afe3,
+            0x72be138e9000125b,
+            0xee471875c8182ffb,
+            0xad17278023e55a7a,
+            0x06c6bb67a882f51d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x7b7a27016674ae38,
+            0xc357c349616224c3,
+            0x2114788ab45a2248,
+            0x5102a4f24e373085,
+            0x9d458799a8bf079a,
+            0x14b5713b6fc8feb6,
+        ])),
+        Felt::new(BigInteger384([
+            0x0af9bdda9fc72ec5,
+            0xe137ea5f5dd4f213,
+            0xe86d0dd89c94445e,
+            0x89ce078de782a25b,
+            0xb23e890992a79876,
+            0x0e85e547b88a3023,
+        ])),
+        Felt::new(BigInteger384([
+            0x63fbd64faa70250e,
+            0x70cf2a1136556a59,
+            0xe9528f09056ccaff,
+            0x399a5bdf49414ff4,
+            0x6c5fe621f5045394,
+            0x0f091c4eaefd484b,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger384([
+            0xb35300000070ff8f,
+            0x623c01bb2dc4005c,
+            0x5a5914d953aa0e2b,
+            0xf606fef186c82c8a,
+            0x8a60e9a4e2ca1ec5,
+            0x109546d5dba1a1ae,
+        ])),
+        Felt::new(BigInteger384([
+            0xb7c6a2a2c919a7e8,
+            0xaedf622c4a682a7c,
+            0x2a4c8ab1ffef7f2f,
+            0x2403f35be651c6ae,
+            0x7b376e61b129a4bb,
+            0x0b5193fe9d38ca88,
+        ])),
+        Felt::new(BigInteger384([
+            0xd91a2d4d4d043125,
+            0x077e8f62bc1c54fe,
+            0xbca357b486c00565,
+            0xbc94b02d2d2b39a2,
+            0xcb0ce50e149c6aef,
+            0x0682d86d5e645200,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x0edfd8aed14d0eee,
+            0x4c7b3e49117fb384,
+            0x9190d375aab1c894,
+            0x5435c20ee7d640b1,
+            0xd761c6e55eaf83ed,
+            0x12b482e32d994227,
+        ])),
+        Felt::new(BigInteger384([
+            0x42fdcf875068aeab,
+            0x7928949e850bc1b0,
+            0xec327805804574c6,
+            0xb38f8a88dcc86909,
+            0x476e69c54fcff817,
+            0x0f69d510d066cc7f,
+        ])),
+        Felt::new(BigInteger384([
+            0xb1d6234f353a5dc2,
+            0x20031ee696087382,
+            0x5b29dbd4e0355957,
+            0x9140f6d4fe626e61,
+            0x17f4c361e4389a95,
+            0x191fcc3552d4ee92,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xc3b6cc6ac460a022,
+            0x33b6d050390ca37d,
+            0xc7aa0ed3955e9331,
+            0xd7baa33253367979,
+            0x72c71fdee90300e5,
+            0x06e787b65f0cc438,
+        ])),
+        Felt::new(BigInteger384([
+            0xd051e3e9aaacad8a,
+            0xb3b61c2410c99d40,
+            0x0573260e6f0ea9cd,
+            0xae30ab0ce47985cd,
+            0xb1004649b706c586,
+            0x05e8557268a7aaaa,
+        ])),
+        Felt::new(BigInteger384([
+            0x6bd10a359d518760,
+            0x5f4764d189e06264,
+            0x0227e2f6c4bce18a,
+            0xeb54207545e4345b,
+            0x36251981a5bb9507,
+            0x0677e12b3f0d1758,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xdf0be418b6896b42,
+            0x198729d410874d6d,
+            0xdcb6cbaa24bfa62f,
+            0xb956d82a9c0f6c14,
+            0x0f74e838700ce8d9,
+            0x15ce449721dac59b,
+        ])),
+        Felt::new(BigInteger384([
+            0x25c4f1c06ef29bfd,
+            0xf9630e5bec3c2a06,
+            0x9a45da044709c6f7,
+            0xbf017e0d1f0249c0,
+            0x2c26bb20c43042cd,
+            0x0a791d91684191be,
+        ])),
+        Felt::new(BigInteger384([
+            0xe3503454367dc10c,
+            0xa7eab3c6a5e88838,
+            0x17e42605a05c0c59,
+            0xc239aa1e7e27ede4,
+            0xa4b6c506e5c1ec58,
+            0x19bd7e0913d2135f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x13c51b58762fd55d,
+            0xc3f606e37698802e,
+            0xe9b046d1d108470c,
+            0xc8392d18cc8c585f,
+            0xb7950454f1315b70,
+            0x0d9c127033530639,
+        ])),
+        Felt::new(BigInteger384([
+            0x9f9341c8f58e5f84,
+            0x7b65a5e410943d88,
+            0xfa9441df0b1509d6,
+            0xeb969570d5a8f9aa,
+            0x7114b050226d9497,
+            0x044c0f4fadc475c8,
+        ])),
+        Felt::new(BigInteger384([
+            0x34763e16465c0251,
+            0x5ab3771118999717,
+            0xd0a8c4bfed32e49c,
+            0xe034c4ca0d17a2cf,
+            0x13e005b7e4e74cf1,
+            0x15baaa8e34df9c02,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x97e30dd5766cd52b,
+            0x688796158c8e7c75,
+            0x949dbff26481cfa7,
+            0xe03ce17569f74fee,
+            0x6271027b9f20ca9d,
+            0x17ace94ee4fbe578,
+        ])),
+        Felt::new(BigInteger384([
+            0x32be110d081f8d16,
+            0xa5bf4cc7f9d3c149,
+            0xf0441f1f13a81137,
+            0xe032436ab9d6aefe,
+            0x50f6a7b14965c1bf,
+            0x148273e25ee531a2,
+        ])),
+        Felt::new(BigInteger384([
+            0xf5b96b36fc527610,
+            0xb1c2920f8bafe307,
+            0x7151dcce1c709d0f,
+            0xa15382e18392bf70,
+            0xde3ca78f71256e5c,
+            0x19162fa28dbd8f56,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x4525df0da55703d6,
+            0x85b8dce787244883,
+            0x9502c02e3d3f66de,
+            0x8b203eb5fd5fa145,
+            0x4f06439f378c9cfb,
+            0x0d8976760fd1ca51,
+        ])),
+        Felt::new(BigInteger384([
+            0xbaac6e7799782915,
+            0x6976406b79fdb925,
+            0x5179c7120ed87f28,
+            0x82764565d77fb46a,
+            0xd2edf99cb4a26ffb,
+            0x151249331a5f3153,
+        ])),
+        Felt::new(BigInteger384([
+            0xcf6e02faf9b2957d,
+            0xd41b4d139dd46572,
+            0x7bf5d06b33413b3c,
+            0x5b2b632fc0b2e2b1,
+            0x03e5646806a02f35,
+            0x123b92b263425668,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x2e8317697e8b6706,
+            0x39ab42ba083f5c4a,
+            0x609389e3b7c9a834,
+            0x254adc127a0aedb8,
+            0x3c19a386dcd0505f,
+            0x0f4a62f3881118a5,
+        ])),
+        Felt::new(BigInteger384([
+            0x45b365223bbb0a6f,
+            0x1d6ed42b922caba3,
+            0x6490aebd2b6567bf,
+            0xea0dcb74adb8b883,
+            0x317c6ac8fa04f9da,
+            0x18ac5c4d490e63a4,
+        ])),
+        Felt::new(BigInteger384([
+            0x70196a6a9993cbae,
+            0xf52b3985493f7543,
+            0xf07040cc49a4f512,
+            0xa643980faeb9fa4b,
+            0x1d1ebaec6f5385dc,
+            0x13fdca6d53ca7cad,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x9a098a2d602289d6,
+            0x7ba26524bbe1e5e8,
+            0xb261465b415562dc,
+            0xfab789eac1fe80de,
+            0x64811fa26204834c,
+            0x06a949c0aa9bb083,
+        ])),
+        Felt::new(BigInteger384([
+            0x19b7e896079db0c5,
+            0x4d1c72d12524d069,
+            0x20750037f008c271,
+            0x2a2217389b6decab,
+            0x895fe6c7c660a35f,
+            0x0727d0f3d87fda0e,
+        ])),
+        Felt::new(BigInteger384([
+            0x8edd063bcb5f81ce,
+            0xef0c5f6a6af44ae0,
+            0x6abb9e86b889ccae,
+            0x35a6b5a47184b84f,
+            0xc39fbf410f2c6779,
+            0x164893a8ad91ea2e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x43b3ff031221a4f3,
+            0xb9cdc9b4d7d468b5,
+            0xa0b3b117183bb241,
+            0x4e6c9a6a99587f8f,
+            0x02dac9201be99ea1,
+            0x02462176aff78b2a,
+        ])),
+        Felt::new(BigInteger384([
+            0x74e21f0ae20c2e22,
+            0xf56b3d42f4db99be,
+            0x33b23b99ab0f9987,
+            0xfb77fec70048e805,
+            0x02dfa1d2e99b9698,
+            0x07c4265a159d0ed3,
+        ])),
+        Felt::new(BigInteger384([
+            0xd6876f8f0ff493de,
+            0xff8ec0750194956a,
+            0x49f053a843382293,
+            0xea06bb673b878636,
+            0xc3da3e9266129972,
+            0x00725d519806b401,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xbaf7d8bc5ba7297a,
+            0xb57d81f53aeec342,
+            0x1a8037194545e1f9,
+            0xe8d9ccfced7cb40c,
+            0xba40ab707f8d639e,
+            0x028de20f08b4f146,
+        ])),
+        Felt::new(BigInteger384([
+            0x40bf65d2f7605884,
+            0xb60df084d8631a45,
+            0x938d1a549d3d9b91,
+            0x3f6e76dfdf8027f2,
+            0xabca9eaef41c8558,
+            0x086e229d1aef936f,
+        ])),
+        Felt::new(BigInteger384([
+            0xe8ba3ba590967e1d,
+            0x5e00c6ea757fa0ac,
+            0x82ab9d1133801cb9,
+            0x3e53233dce483581,
+            0x71ba5ded10e68817,
+            0x0cbad1882e2d3842,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x260a92001e59e9b9,
+            0xdc2ff74a6f0ce95a,
+            0x26fcbd7a4015358c,
+            0xcdc7700834b14e1d,
+            0xa3e86b47f253c68f,
+            0x06ccb1287c06f3d2,
+        ])),
+        Felt::new(BigInteger384([
+            0x7ccc6b32d493aa30,
+            0xad6a382bb072673a,
+            0xaf0031609374a7ae,
+            0x96269afab4bc31e4,
+            0x677e21109fc34b3e,
+            0x00b7e0facd5292eb,
+        ])),
+        Felt::new(BigInteger384([
+            0xab1118c00c86ab91,
+            0x7060578cae41666a,
+            0xd80f7598d601d03b,
+            0x79af69bab76a3f30,
+            0x6f7891c8d27e5382,
+            0x19c283a32ab0a8ba,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xece5834812aadf17,
+            0x51fe2f0d8ead9756,
+            0xca72ffc9909d44f6,
+            0xf2681e718ccbfbb7,
+            0x78e8b18b481b4c3b,
+            0x027d887050a0dbe2,
+        ])),
+        Felt::new(BigInteger384([
+            0x7193c063652d2c6c,
+            0xa89dcb1313678fa9,
+            0xd7d9a38f6ee288d7,
+            0xb9c0f8ad461cf445,
+            0x2ccff11794dbd668,
+            0x0b2ed6f27f088710,
+        ])),
+        Felt::new(BigInteger384([
+            0xef6b13f50a36e5c2,
+            0x70c63c46f04e8ccc,
+            0xde33cd7279ce314b,
+            0x1670ef54f6e6f9ca,
+            0x02eb3f9ceb567b01,
+            0x1839ea51e72e2ef9,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x4ceca524fa99fe91,
+            0xae9ac237d73b90d3,
+            0x54cc06f376136fec,
+            0x038fb4403aa690f5,
+            0x1a6170dfce6c9a62,
+            0x013bcd7163aa64eb,
+        ])),
+        Felt::new(BigInteger384([
+            0xceb0727e44019e02,
+            0x8f40bf52ff50f311,
+            0x04885582e8eb5499,
+            0x9745016c5e38f518,
+            0xf31dce52d8595c9b,
+            0x030c6805481ccb40,
+        ])),
+        Felt::new(BigInteger384([
+            0xdb441ebd27b9fc72,
+            0x6af9377e64acc832,
+            0xa2bec1442e381159,
+            0x7f393c6cb0a62204,
+            0xce52ab80a5891eb4,
+            0x00fed4e326d867de,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xb80c73aa3811622f,
+            0xf6bd9715221e74ce,
+            0xad3f171aa938d5f9,
+            0x94a2a717c7b4fd7f,
+            0x9df68e169aa52750,
+            0x15697484e5e55082,
+        ])),
+        Felt::new(BigInteger384([
+            0xe42f5d5dac1b61ce,
+            0xb2a1b99b3f7f28a9,
+            0xf8e05d22cd6fb2b2,
+            0xd321cdbe4e9b24df,
+            0xac30e79bbc1568f0,
+            0x007c4c918693b5ee,
+        ])),
+        Felt::new(BigInteger384([
+            0x694cabf2cfc725ca,
+            0x1595fbae9f4bf5b5,
+            0xa27f4bf0df6b2c42,
+            0x487a933b0e2b35d1,
+            0xd58880ee29c87d19,
+            0x07d5e1f1cfb88ad5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0xbc38024bf4e4a89a,
+            0x94e04a94bc7937fe,
+            0x773ab50ca134ef65,
+            0x5376c02740ef99a2,
+            0xa118eaf2f31cb27c,
+            0x0bced46a044dd940,
+        ])),
+        Felt::new(BigInteger384([
+            0xd466f4944b20e3f1,
+            0xac45014de38e6c95,
+            0xa7c59b256e4008cd,
+            0x477952a17171abfb,
+            0xa85a9b4ab55de450,
+            0x0157e8ba62f1dc5d,
+        ])),
+        Felt::new(BigInteger384([
+            0x15ac8f61b61b0d47,
+            0x5e11d5fc70ab6ec2,
+            0x054c545bb19fe42b,
+            0x7f464ad83c276ebf,
+            0xa03b6a3abcf4c1d9,
+            0x0796090802963cce,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger384([
+            0x6ea1e2c19ca402c6,
+            0x12d27016ddfa37ae,
+            0xc7f795aef539207e,
+            0xb0ffe10ef254a666,
+            0x576bb57b2616b7a4,
+            0x070dfb659aff6a3e,
+        ])),
+        Felt::new(BigInteger384([
+            0xdb2529454aec560c,
+            0xc9e941dc03de395b,
+            0x6b0ffb150f17c4ae,
+            0x14b2b4d8984530b5,
+            0x5ac57629e29589c4,
+            0x13199c546043570e,
+        ])),
+        Felt::new(BigInteger384([
+            0x01c80476462fe70a,
+            0x0d3362f1c282d54c,
+            0x04ae7f00efcfca3f,
+            0xa63f6978b9c176f0,
+            0xc531588e17b98918,
+            0x0a2fe5ae1b5bf431,
+        ])),
+    ],
+];
@@ -0,0 +1,2736 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 47],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 47>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 47],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(47 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 47 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 48];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 47 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 47];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(47) {
+            bytes.len() / 47
+        } else {
+            bytes.len() / 47 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 47-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 48];
+        for chunk in bytes.chunks(47) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..47].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 47 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 48];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 47 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U47, consts::U6>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger384;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger384([
+                0x3171ca614e2a8ded,
+                0x8ede3b772ae6147f,
+                0xb6167cdec9e8aa75,
+                0xb470d7fbc0342bf2,
+                0x205e3f0d707f7cd7,
+                0x1656be9787ac6a58,
+            ]))],
+            vec![
+                Felt::new(BigInteger384([
+                    0x14dc8c24175721e9,
+                    0x6c53f7a4c828f605,
+                    0xea7e01f97e7ae494,
+                    0xc6775740c5b4d55a,
+                    0xf61d47125f0d0dea,
+                    0x1802e0a188b5a203,
+                ])),
+                Felt::new(BigInteger384([
+                    0x44b35f929c14e25f,
+                    0x02866761d763e8da,
+                    0x53f7649f2cfc1bf2,
+                    0x23c5bd5867f398f2,
+                    0xdf894c16455d3495,
+                    0x15aaa5775bba9e18,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xf9ef4cc32ec14788,
+                    0xe2e8054e81d02255,
+                    0xf08e65e904334424,
+                    0x80a2ccc9904b228e,
+                    0xb7ec46fa11d356c0,
+                    0x16b8e031eef4a066,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfe63b90acd6c16be,
+                    0x25b88abd197e3a02,
+                    0x4c1d9381b2815d38,
+                    0x42fc46730e28fe25,
+                    0x1e2baf7f8e0a2851,
+                    0x111f8a20496e45ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0x81384d158bc8c091,
+                    0x792aca59f1b8da81,
+                    0x940b73f6b64bcf2c,
+                    0x9dc31d64a13cef7f,
+                    0x216d4fea0f26e76f,
+                    0x193bf2c3c5eafbcf,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe5278d50036debc7,
+                    0xf9ac9e1da5dad377,
+                    0x357d11a90be1e1b6,
+                    0x34963f0cd94f5272,
+                    0x902d165b557ff935,
+                    0x07cb4770b97beda0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfcfccb547422cb20,
+                    0x5d137ed870ed977b,
+                    0xf5388a11a9a51e48,
+                    0x8f9a0c4aa096cda4,
+                    0x25eb4483268841d9,
+                    0x0335502614b35c63,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1de449e2e1ea1994,
+                    0xf6a95993c1b4e860,
+                    0xe4cb393abe02dc8a,
+                    0xebee4d6422453169,
+                    0xfce1e0f76599d2df,
+                    0x098ef327b3ffffe4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x567635dda4d606d1,
+                    0xe23a858c614c196b,
+                    0x8069e3e78afe452b,
+                    0xe97e22efa0442655,
+                    0x029d699d9b8a6f39,
+                    0x1480963035ee26ee,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xd3d22c2c583d6b8b,
+                    0x0666088c8d5213e4,
+                    0x36196ef9d6f80a62,
+                    0x4ac28445b83b262a,
+                    0xa06948338f0f2e8e,
+                    0x1871d12a9cf932ad,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f41818436e52079,
+                    0xc0e054aef19b09f4,
+                    0x9afd7b0e69faf436,
+                    0x5a52d1ed96b7f10f,
+                    0x9228ad7a32a8af57,
+                    0x14ba6bffb63f93c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8c057f19abaf5876,
+                    0x8f03ae0d59af9883,
+                    0x14b8a710c44f6a61,
+                    0x901ccee4182494fd,
+                    0x5f1e6dc0d2048714,
+                    0x0a7f39c20afcef06,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb4bedfd826156dee,
+                    0xf1524e877bc36815,
+                    0xbb726a93b5b94615,
+                    0xfac72765a7362779,
+                    0xab2cf71f875d27b7,
+                    0x027328c62d06cc13,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf7d084a04a5e8b7c,
+                    0x30f2ae3fe10778eb,
+                    0xac23dea7fcae88c5,
+                    0xba6adf2f7aa2f196,
+                    0x482c9556f292d779,
+                    0x07d06e2ccfbb7334,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x67a6ce00cc90deaa,
+                    0x34d0fc268c6deb9b,
+                    0xb7f5941af2f9ae04,
+                    0xfbdbb29350975440,
+                    0x3b2aa2430b5cd2e6,
+                    0x03df9ccfd507fd37,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc218be38d6b1e800,
+                    0x202dcae2f48049ae,
+                    0xaccee15e95f1fbf3,
+                    0x0b4576b9f4c30c59,
+                    0x38807492b7d2a535,
+                    0x15f95c6d83596e48,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5f7dcf95ce6cd84e,
+                    0x40bcaca01e134020,
+                    0x826987306267732e,
+                    0x6b6a36a7f6979f7c,
+                    0xd5af2ecf7075f1ff,
+                    0x0cc345f1a5eb7fc3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b83d0a106184b3a,
+                    0xd3f70064dc611e8a,
+                    0x7a19cc0905131bbe,
+                    0xf3dd354f57b88d44,
+                    0x5112a0d08df5221f,
+                    0x114c064c9d39d669,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe3cadb8dce62df54,
+                    0xb678b979b11f27c2,
+                    0x032574d4d081e662,
+                    0xc82a26643b7db8f2,
+                    0x0f61f81afc91c14c,
+                    0x09f3dc69d9ac1c03,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe916d72e2f6ee56a,
+                    0xa28a447d870d87f3,
+                    0xd3788c45c4573263,
+                    0x31499d9280af4a55,
+                    0x227c6d466f3bb0c7,
+                    0x09c90841fa0a542b,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x652816e62b226832,
+                    0xb9dca5f01da5659e,
+                    0x638453f74e7ad54a,
+                    0x34ae6df557bdeb22,
+                    0x68de1f6f7d53d731,
+                    0x0045a4f6763f54e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd260d4c2d5d6591f,
+                    0x6a21e4dddafbf725,
+                    0x6e016090ac90eccb,
+                    0x028b0f0fa5484bc3,
+                    0xcf6edf5e2d4dd0e1,
+                    0x17073914a9b39b39,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x949b6f030cfd37da,
+                    0x0a0660565a78b293,
+                    0x76b1bdd01bb23140,
+                    0xe359c98530678aa1,
+                    0xedc8b03a64311f74,
+                    0x19d4d89097d1a362,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa7b12d8d0127402c,
+                    0xf734116945d705bc,
+                    0x3c6980aa37b3551a,
+                    0xdf992fa728047901,
+                    0xd4fb950d98b3f276,
+                    0x0601be4ea98b6e68,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9b1e821c547d7c5d,
+                    0xdeba7557b72dfaf6,
+                    0xefe7274f1ade76fa,
+                    0xe2f3c7aa4de12825,
+                    0x74ead6bab7b4ff96,
+                    0x01964d8a4e456021,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf933e9b64edf11e1,
+                    0x25af36cb25b8c1a0,
+                    0xfde07ef56eff1328,
+                    0x5ae96446c0615c03,
+                    0x9e1f86702481c30e,
+                    0x1533b02206ff8b7a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc1c35813b4dcfbef,
+                    0x6ca3575093733f23,
+                    0x5657b0d36f4860ef,
+                    0x6589cab5e1cee306,
+                    0xab52ffb188696348,
+                    0x057b4d7787981f35,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6230746271f44817,
+                    0x9f7d8db8b17f2cbe,
+                    0x8e9c6989f5d3a037,
+                    0x2d26a032c0509dd7,
+                    0x7fd88408c8200f7c,
+                    0x01b425c43e366042,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2fd8815dc312a241,
+                    0x926b26c104459291,
+                    0x925aec2e3aea19c1,
+                    0x8b86a010ac16ed29,
+                    0x43de996927a6ec8b,
+                    0x17da3aa6e7326143,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf18da6c99f3638de,
+                    0x1fc022ce7dfae1a0,
+                    0x0169c0b85fd67d07,
+                    0x4ab379b4568a58d8,
+                    0xf281298cccbe2cd5,
+                    0x130180cda067ae4d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xfb08e57f02451073,
+                    0x3ef6ef8a237a55e6,
+                    0x7b5b652cced8264c,
+                    0x5e7f6b7d3e081218,
+                    0x1c606348768b9027,
+                    0x1723a6e7bb51d785,
+                ])),
+                Felt::new(BigInteger384([
+                    0x464f9da6359b7515,
+                    0x3be21b5251c12d0d,
+                    0x02f7e9e687e330c2,
+                    0x9a8ec8c9a97a82a5,
+                    0x6a3de734d74269c0,
+                    0x08eeadae7407bd63,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xcae40c54e16d1e37,
+                    0xbbd615e81aa1b2a1,
+                    0x5fc5683ca98d54a6,
+                    0xc1366bd6423c90a9,
+                    0xf268c89f0553987b,
+                    0x0c79f5e968c4dbfe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f3308380a2af93d,
+                    0xef47e8b8b6016450,
+                    0x30939067f5003795,
+                    0xf6a53224031868e1,
+                    0xb092c9891f5d0e47,
+                    0x186fab886bb28360,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x765e5ff5afae5bb8,
+                    0x9252c23eb5f31572,
+                    0xb971ef9020da7791,
+                    0x100b3d06052a4e50,
+                    0x6dde4853d397b3db,
+                    0x0c2a1c5129a64267,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe9e85355a9f7613b,
+                    0x8fe5110ea8c7aa9a,
+                    0x5110be21773c0942,
+                    0x2cf14729bd2bbef5,
+                    0x8617f3c28b7910b3,
+                    0x0cc931befc3b9c68,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3932304e48d9d069,
+                    0x0145e5bcdda7d28a,
+                    0xd3f8102981935d0f,
+                    0x6481e25e992a095f,
+                    0x5d02318b82293566,
+                    0x0d8b0b9ef239ffe9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x56c5708f8a9f28e9,
+                    0xfff6b56c0f903f09,
+                    0xdfdea92aff1954a7,
+                    0xe00eccd50e652d7d,
+                    0xb179e811f07e3092,
+                    0x0821b2d3376d397b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf0e331133cb44e50,
+                    0xd026c115350b997e,
+                    0x44f1d3a3edb19be4,
+                    0xbaaa26a7734bfa3c,
+                    0xc5e333b80a884184,
+                    0x058a215cb79c8904,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd706ea3064c56064,
+                    0xedc71a6c1271dee7,
+                    0xcc3b2c7d0646c628,
+                    0xd0f104423af0f26d,
+                    0x112134d16b267cd1,
+                    0x004d3b07c974f970,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x652816e62b226832,
+                    0xb9dca5f01da5659e,
+                    0x638453f74e7ad54a,
+                    0x34ae6df557bdeb22,
+                    0x68de1f6f7d53d731,
+                    0x0045a4f6763f54e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd260d4c2d5d6591f,
+                    0x6a21e4dddafbf725,
+                    0x6e016090ac90eccb,
+                    0x028b0f0fa5484bc3,
+                    0xcf6edf5e2d4dd0e1,
+                    0x17073914a9b39b39,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x949b6f030cfd37da,
+                    0x0a0660565a78b293,
+                    0x76b1bdd01bb23140,
+                    0xe359c98530678aa1,
+                    0xedc8b03a64311f74,
+                    0x19d4d89097d1a362,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa7b12d8d0127402c,
+                    0xf734116945d705bc,
+                    0x3c6980aa37b3551a,
+                    0xdf992fa728047901,
+                    0xd4fb950d98b3f276,
+                    0x0601be4ea98b6e68,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x9b1e821c547d7c5d,
+                    0xdeba7557b72dfaf6,
+                    0xefe7274f1ade76fa,
+                    0xe2f3c7aa4de12825,
+                    0x74ead6bab7b4ff96,
+                    0x01964d8a4e456021,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf933e9b64edf11e1,
+                    0x25af36cb25b8c1a0,
+                    0xfde07ef56eff1328,
+                    0x5ae96446c0615c03,
+                    0x9e1f86702481c30e,
+                    0x1533b02206ff8b7a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc1c35813b4dcfbef,
+                    0x6ca3575093733f23,
+                    0x5657b0d36f4860ef,
+                    0x6589cab5e1cee306,
+                    0xab52ffb188696348,
+                    0x057b4d7787981f35,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6230746271f44817,
+                    0x9f7d8db8b17f2cbe,
+                    0x8e9c6989f5d3a037,
+                    0x2d26a032c0509dd7,
+                    0x7fd88408c8200f7c,
+                    0x01b425c43e366042,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 376];
+            bytes[0..47].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..47]);
+            bytes[47..94].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..47]);
+            bytes[94..141].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..47]);
+            bytes[141..188].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..47]);
+            bytes[188..235].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..47]);
+            bytes[235..282].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..47]);
+            bytes[282..329].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..47]);
+            bytes[329..376].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..47]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x638342f0dd8a2781,
+                    0xfb53c0151f2465b9,
+                    0x1cc1d7ddf7defc09,
+                    0x6bf133b6c6a97e1c,
+                    0xa6f61ea3503b12c8,
+                    0x0de57d64430b6b6e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xee880bd7c837b5ee,
+                    0x44834172a553b7a5,
+                    0xabcb4c6f618c9e22,
+                    0xb911a2e8db4dd43a,
+                    0xaa926181017c01f3,
+                    0x06325fdb46cc1497,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb0de22efe3235b3c,
+                    0xa0fc16c56b84d64c,
+                    0x6574b76768309208,
+                    0xb569b9c3d5a8a692,
+                    0x4afaca0cd07eba6d,
+                    0x1312f64d3982a2f4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf45614b7469e8074,
+                    0xca4c5db80664525c,
+                    0x4dee145b998cf2e3,
+                    0x694ea0b4f95451d6,
+                    0x995158e84f734a56,
+                    0x09e0325ed6a93154,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd50862636cf9b5e4,
+                    0xdd9516a96f794e71,
+                    0x824206489cb2286c,
+                    0x3f5af40327c0f161,
+                    0x5a4cc5c8de69be3a,
+                    0x0c6cbb504c00d125,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4ddb96106e8786bf,
+                    0xa999df48e4a46d0e,
+                    0x2cec15ba0f7c2b8c,
+                    0xed7a310e895dbe87,
+                    0x70d06d4cec2aba08,
+                    0x152423418c0bd0e8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6d4d0f743e50949d,
+                    0x68899311d4b304ff,
+                    0x5dda3d9ebe820dd8,
+                    0x751f98a6a4fa1ae1,
+                    0x641e989719b13383,
+                    0x169b24b69ad7872d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2b4c4c5e5e650824,
+                    0x709d8f767610b8f8,
+                    0xd29065fc2ff5254e,
+                    0xa2cba4025746727b,
+                    0x077ec0ef2418058e,
+                    0x00d0f5b063ebe079,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x2215baec4633b3ae,
+                    0xf2fd048044bbb889,
+                    0x3e0bcf6a150bfec9,
+                    0xb56452c535e7526b,
+                    0x0c262dc791387029,
+                    0x09dc4321a3d999f3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf89fdde7c253c79d,
+                    0x9ccd0ff9bc42a480,
+                    0x2f7294056724eb0c,
+                    0xd4ccf5348e6ddb92,
+                    0xdd50c34d0adfad97,
+                    0x0ebff84f8b97132b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xea595ded600b20b6,
+                    0x90e1873893bdc6c9,
+                    0xdb2f4b26b70c4c23,
+                    0x030392259d9dfaf1,
+                    0xdb556ed2be5dba22,
+                    0x00304fbbfa0d894e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9520703fa53efcc2,
+                    0xa6cb7ae3ecc446c6,
+                    0x3eff0d269161ed45,
+                    0x7955abf5d3e30617,
+                    0x74eaf93ede2ca39f,
+                    0x12bc82b024f6857a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfe53983672ec812a,
+                    0x07de8642a124ac56,
+                    0xfabdc46aebde1a05,
+                    0xdfcab7f48dfe9399,
+                    0x23692ea3bffe155e,
+                    0x18b0833c53eb2cef,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5febf15219eb7e66,
+                    0xf1954db78f0f334e,
+                    0xbc6591303d9c9808,
+                    0xb6743b600f183483,
+                    0xd51a81c0e4d4d763,
+                    0x135f2f44315b6f96,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdc361c772a718662,
+                    0x9dabe0a069b36fea,
+                    0x01daa9f6ec398bcf,
+                    0xf4692dd088582a9d,
+                    0xbd5bcf09584a3a1e,
+                    0x1139ef6f4c9aefd4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb2748d89318f10ab,
+                    0x38a1134783b1d946,
+                    0xd547e7749a016a5c,
+                    0x6d028f406eb96854,
+                    0x13112a9212b65148,
+                    0x052148ea04f1cfca,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe5979f157b09dff4,
+                    0xdbad8c8332366229,
+                    0x75821174830bcfb1,
+                    0x1f83327d996faab7,
+                    0x71bcec980d326101,
+                    0x00bb2ef822e77b97,
+                ])),
+                Felt::new(BigInteger384([
+                    0xae91cae312feef57,
+                    0x5199ed2698c6d793,
+                    0x30433a910be7f7d5,
+                    0x310b681405389fcb,
+                    0xe3c1f67f688c7fe0,
+                    0x1293b4b878ce6171,
+                ])),
+                Felt::new(BigInteger384([
+                    0x70e175150f6b774d,
+                    0x9e09d8b859db852f,
+                    0xb33f68bcad0c867b,
+                    0x8922a712c6a604d5,
+                    0x35328d0b92a30b00,
+                    0x16e54579743097a9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc29d1ad10609495e,
+                    0xb7c70968f9313b70,
+                    0xcf2461ff19da8c43,
+                    0x447d0c12c292a0e9,
+                    0xcb107266a5750c06,
+                    0x02bec99e48b49c71,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0a63f949799d1d8d,
+                    0x5b668c947292ec2b,
+                    0x5ade7f31f2b5d272,
+                    0x18fc1b02ce58936a,
+                    0x674a81aaa236b6a1,
+                    0x127a3fa6fb8d30eb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfd46cb259eec9080,
+                    0x24c0a3754cadf588,
+                    0x1eb2121c8d34ee0d,
+                    0x9b88c53cf6134549,
+                    0x7e93c4990edcf2d8,
+                    0x00d52280bbedbec1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xed1ac3be3b0bfb58,
+                    0x20f5c7890e3f27b6,
+                    0x571b1f3f9e30cdcd,
+                    0x53e553a8d01867d7,
+                    0x788ffc607e853fd3,
+                    0x141d8423fc57bb4c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x632b2829e732cb2c,
+                    0x2e040276ea558079,
+                    0xc1de2cf9f81702c0,
+                    0x85805b1b69d4f013,
+                    0x076274c94c12fd66,
+                    0x0eb553089d31258c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x7efba9657050edb5,
+                    0x3067b08bf81fb288,
+                    0xbe688d63a8d61939,
+                    0x89b04736087eb8c9,
+                    0x5ec93c923c08fc46,
+                    0x0b540f67b26b1e41,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf0ae9be2825f68ff,
+                    0xb590cea780942fbc,
+                    0x1f43d0244009cf9c,
+                    0x3578c657f28e6d8c,
+                    0xd43e23de769d366d,
+                    0x04d27ea6eba2ad54,
+                ])),
+                Felt::new(BigInteger384([
+                    0xafea6e22403f6d32,
+                    0x5b50ebf8aa0b7b52,
+                    0x9f3122af4989da72,
+                    0xd3079511013d29aa,
+                    0x84e5e1c73974b7f2,
+                    0x10ca1cff99788ccf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x357967a1dc741431,
+                    0x48e592540cd4788d,
+                    0x20d1ac956cbe1533,
+                    0xb7bb333998141c5b,
+                    0x60e7a0427e423d1c,
+                    0x19d7fdaab26cb310,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdb7f447aa2181749,
+                    0xa4677df8dc8ddfd4,
+                    0x87be41b0dbfc82be,
+                    0x338d37426b2dfbca,
+                    0x793e37ebb00188d2,
+                    0x0b62e2bff894c566,
+                ])),
+                Felt::new(BigInteger384([
+                    0x079bfb26412d1508,
+                    0xedeec59b6c0ba78c,
+                    0x8b827333dd29cb7a,
+                    0xc134cd8dbdbb06c9,
+                    0x826b86342ffeac98,
+                    0x1652b8f50355f7d5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x46c9976a4f34df6b,
+                    0x83ea6e830f00cdb2,
+                    0x9f04d44240c8e520,
+                    0xf3be6bb2ec32eafd,
+                    0xd1aef4b586881870,
+                    0x0edf379045ab6c45,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4d2a110a0209c2f7,
+                    0x3c5e4ad598759d00,
+                    0x31a598ca1ee5b1dc,
+                    0x9b1f6ab7e11ead54,
+                    0xf3c9b6b0884e9690,
+                    0x0628ff6f7ef26978,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x67c9791821d31d35,
+                    0x8bcaaab70e329210,
+                    0xc7d51aea1dedf8b2,
+                    0x0e7c34c83e70a836,
+                    0xcbcea2744f8ee681,
+                    0x0f0664149358fb96,
+                ])),
+                Felt::new(BigInteger384([
+                    0x64a543b52b154819,
+                    0xd8ec44e587f8078d,
+                    0x7b252d132519832b,
+                    0x27b83f2135a530e3,
+                    0xd41729b2353f90d8,
+                    0x0458c6636d9269a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfba928c7217ff728,
+                    0x0a5b2dd22bffda4a,
+                    0x061a5c74388a962c,
+                    0x55c0611dc58fe353,
+                    0xa8a19673b8e3440c,
+                    0x1267430bc9534ded,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8991a1e59e69ba8d,
+                    0xfc5da87e1f95466b,
+                    0x07ffee06e1e4df92,
+                    0x18551b6dec6d4452,
+                    0x5ed8c68d22127362,
+                    0x0752986fa983d8ba,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb082f78e911284f9,
+                    0x14611a65f7553120,
+                    0x8d93548e078bf54c,
+                    0xad39408f062b473e,
+                    0x8b648927c5aafe8e,
+                    0x0937a2e22df768bc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xece27c0a4450d833,
+                    0x2ce1624b0f8bead1,
+                    0x65f0d55c4b7a0174,
+                    0x90c5a37d93f50727,
+                    0x58dc0521a4339974,
+                    0x0619ccf350b5450a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1180b462ff094864,
+                    0xb2de7e2f1cd2e7a3,
+                    0xf613396c78e89648,
+                    0x39e8876dda099d2f,
+                    0x873b8fa69bd9bdc3,
+                    0x121c651ffc57f8a4,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc5d003d61e3031ec,
+                    0xdf9377a207374460,
+                    0x8b4f7b6fd4fde263,
+                    0x2e362c8d5f624da4,
+                    0xd853a8d55e23e2c2,
+                    0x011f38f670d1ea43,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xb2f9088fe0b46bfb,
+                    0xaacb79b9958c88af,
+                    0x85c086ad2d2030f7,
+                    0xcb640dc391cc8c71,
+                    0xd80a5804aa9efcc2,
+                    0x12ca46397295c7f0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16b4e37005aaa9f1,
+                    0x82d7e1657d42072e,
+                    0x0257deccbc051b8e,
+                    0x5a040a23a48f07a6,
+                    0x0051f72edd48a5d2,
+                    0x0364980de73f3a02,
+                ])),
+                Felt::new(BigInteger384([
+                    0x493bd65ec557c36a,
+                    0x83dbf2a5b587183b,
+                    0xa78204a8d2f0bfc7,
+                    0x144ba53353174bba,
+                    0xe10bc959d44549a0,
+                    0x18894f89789fdc9e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x212a004b7b0562cc,
+                    0xef3195d16e460956,
+                    0x071922f594857c7b,
+                    0x26f364e95576dafa,
+                    0xd18ed602b3afb871,
+                    0x044bbacc83e6e02f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5c5ad6d546cc409f,
+                    0xf04d22501817f1a0,
+                    0xa788b9ced3ff0e40,
+                    0x12576255a31c122f,
+                    0x3b83b555180c4095,
+                    0x1247dcd481370f2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba9f3c5bede5f502,
+                    0x19c812dded7d064a,
+                    0x864447e1e2266701,
+                    0x6f7da15a39511ea0,
+                    0xe15021b84d4f00c5,
+                    0x0ae0fc9c26348fa5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x58b414a8c88bfa89,
+                    0xfe27decc403defe4,
+                    0x4f9a6817fcd428e1,
+                    0x6fdcee02612e7e00,
+                    0xf86cc5ef77e7bc71,
+                    0x15a70a9de256e3cf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x055008be9cf706af,
+                    0x57ef70431e370ae5,
+                    0x081871724c4e058e,
+                    0x09d85c32f09710cf,
+                    0x3adb0fa9dc3d99ab,
+                    0x01be467d5923f70b,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger384([
+                    0x815c1a6fcdb1c6e4,
+                    0x52a7b8c77b5f16a7,
+                    0xb3e02074e3768663,
+                    0x67c9de71e3552493,
+                    0x840bd9f94e5f241a,
+                    0x1922b4750f5236c1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbbf5d03576e60c72,
+                    0x226ad4d45d17ce42,
+                    0xe4864bb4b5ee925a,
+                    0xa7fd1745927e9b70,
+                    0x7c240d25181e7027,
+                    0x13c4f91a69f03401,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d25af4deac2835d,
+                    0x3bbd936f86a81de2,
+                    0x353ea0b6b448e661,
+                    0x9ac397c93e72fd8d,
+                    0x3016a4e720661b80,
+                    0x0718a14e865bd507,
+                ])),
+                Felt::new(BigInteger384([
+                    0x99f27ecc6cc2f70b,
+                    0x882ae36cf63ad7c1,
+                    0xf9c8e40123726020,
+                    0x069e3f76db119b7c,
+                    0xef984d8dfed3b330,
+                    0x10f7718868cf210e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa82cd61b0dc9bd72,
+                    0x4668f13a2322f8e3,
+                    0x35a48446dc26933c,
+                    0x4da1f9ae786f675b,
+                    0x922bde36e0e005ea,
+                    0x03c82982cff5aceb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x883b3b8857573f70,
+                    0x54ecdbb82e8a25b0,
+                    0xe8dbd158d714b5f2,
+                    0x8855eecc99d9706d,
+                    0xeee49e2c4f834c6f,
+                    0x08716804ac8cbbf6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x796a84c2d954444f,
+                    0x71c35ce4b7f38d91,
+                    0x49bce4fc972613d0,
+                    0xe8249be256145f91,
+                    0xb45cb0e3729bd532,
+                    0x0e625c31ec1fbcd4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2648415e491ca408,
+                    0x8d1a9125a55e9183,
+                    0x928a9e27907820bb,
+                    0xb20ae3d95f05e5c6,
+                    0xec3c191ddd4e03b2,
+                    0x0e5ddac5f6ef8d58,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd7a38403600c2c21,
+                    0xc8c69394c217531b,
+                    0x2afd9352279d5d7f,
+                    0xef7f550082ed1c72,
+                    0x4cec7eaacc3f34ec,
+                    0x16bd95ad430fad6a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x81a42ffddfc62b3e,
+                    0x5fae723217f333c0,
+                    0xd1058178f64b70d6,
+                    0xd9a01f9e6840a7c7,
+                    0x185eb077d1e7b9c7,
+                    0x01d5bddee1e9c476,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb47f640372856590,
+                    0x0a903b18d63e066b,
+                    0xf1b56abecc5833e8,
+                    0x202a206e106e16bd,
+                    0x1a21ef92f9a1db99,
+                    0x1482c4d13643275e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe5eef22ca2a0d189,
+                    0xb1ff0b4981dd16de,
+                    0xc629f79516bbe5d2,
+                    0x10de92cd4ab85cc6,
+                    0x07c0aecb5d7c1668,
+                    0x0a2777e500a13bfa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x67a4c9b41790fd90,
+                    0x4c7dd47938a73d59,
+                    0xd512f137bb2bd4ae,
+                    0xd0f61d9afb8af16b,
+                    0xf7580c4ecaeb6fed,
+                    0x0602f86e5d658733,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc879185e320ecf28,
+                    0x297c45cf5c84c8be,
+                    0x4300a63472f0bd38,
+                    0x831fee4c33b43f29,
+                    0x2d34f637075137f8,
+                    0x134102b719d7d8a5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x416d3ee714a30051,
+                    0xe023b7d1a9328210,
+                    0x45fcc62f523ed7f7,
+                    0x6af67735285609eb,
+                    0x3f1f364ead8d39d1,
+                    0x17f90e5ed906ec89,
+                ])),
+                Felt::new(BigInteger384([
+                    0x608f49b463c02ff5,
+                    0xdeae35aaa6711b3d,
+                    0x0d5140afb915538b,
+                    0x9ef77427fddca341,
+                    0x459abadea7f96399,
+                    0x06ae40d46e38ac48,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8512b65d7a738dac,
+                    0xca3e811ea13377f3,
+                    0xe3e20898a09f2ac1,
+                    0xc8ea2cdad028f107,
+                    0xe2e7370ea8f045f5,
+                    0x04f8f0d5ff06a7ed,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd41bd87c067c4cbd,
+                    0xc8ace6e8c7241c80,
+                    0xb591e91e6b0695bb,
+                    0x24d7530658b4e150,
+                    0x33bd86d3a10f605f,
+                    0x0f18a9f544dcdfd7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbe63edf859118740,
+                    0xd56dc9ff584fe308,
+                    0x1f6bee9168adb610,
+                    0xdada1e278588e302,
+                    0xb636cb617a348dc9,
+                    0x074298d8627feebc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa3daa437f90b50c1,
+                    0x04249ae2fa8dba3f,
+                    0x78beea5b26625cdb,
+                    0xa45f0de2ff353b92,
+                    0xf5af32719dfaa766,
+                    0x1980c2da176e3b5c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xa981f51a1ff9dc6c,
+                    0xeb6cd958b1533086,
+                    0x384f36f960c91541,
+                    0xc72e1a25fba1a939,
+                    0x08fafbab458233af,
+                    0x015f3d9a54054aba,
+                ])),
+                Felt::new(BigInteger384([
+                    0x945642223e2c46f7,
+                    0x760bfd7cccb35220,
+                    0x094313a5514bb8c9,
+                    0x6aaa8fdbd72c48fe,
+                    0x84ffb76c6942e164,
+                    0x04b4a2c48ec2460c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf17c7632ac433c40,
+                    0x6a2d9eb20b29df47,
+                    0xb3d1b2713a2e48d6,
+                    0x806409a2eac00a77,
+                    0xeac50d5137fa1350,
+                    0x12eeb776033860f2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd2c03944491724e4,
+                    0xe69037939df63d3c,
+                    0x5f165f08135ed17e,
+                    0xde3c6c647e698c69,
+                    0x81af336f11329ae4,
+                    0x0671f3adba71a0ec,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd7a6ea62dd240688,
+                    0x605f3bfbaacccc18,
+                    0x5093cc1feadcd456,
+                    0xe7e49a68ab4a064b,
+                    0xb0d44105bba249d9,
+                    0x1097f01ab8b90747,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf95e3fd604f770ed,
+                    0xac79ebb73f5c9053,
+                    0x95c8ede5056c6f41,
+                    0x989eb5330a9923f8,
+                    0xeae9534cf70bc20a,
+                    0x0c709f9121ded597,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd53e033f983c9411,
+                    0xaf80bfff06592a79,
+                    0x2a1903385ab3bb00,
+                    0x8aa603f02e0338ba,
+                    0xa85e2335e1b5a002,
+                    0x1355f98c2e1613b7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd9b77dc1e35435e8,
+                    0x49a8cf3db0ebc65a,
+                    0xb25fef1a34c9376e,
+                    0x2cb2007cc41355d8,
+                    0x0d809860060002eb,
+                    0x0dbfb88b8d41bd78,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xcaba0c7ccb6d0034,
+                    0xee5fb4eb9a3da7a1,
+                    0xe8573853dc61444e,
+                    0xf2e1e03c6406aba7,
+                    0x320ea647e85d8139,
+                    0x1786dd798803cbbc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd8748c2dfccbf45a,
+                    0x41b2acbb9f5443eb,
+                    0x50aea155069ace98,
+                    0xb9e8faa1a32ad123,
+                    0x34abeb7b430c2413,
+                    0x090cb0a5f373f34c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc9169daf338b1a78,
+                    0x5834d6506082fd88,
+                    0xaf4f201c7afd6261,
+                    0x06d01ddd1e80c981,
+                    0x4a83e61803a87363,
+                    0x11dcf7187a685011,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca9ce8f465d1f632,
+                    0xe07caa7c46379a77,
+                    0x1ae0e770025f20f4,
+                    0x8b84ff1660626e9f,
+                    0x5677383543362716,
+                    0x0bf526f29696d507,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x5fa8dce4c5c42650,
+                    0x9a0c5b9a1adf2748,
+                    0xfaef2f1a3eff1e22,
+                    0x5581836da0182252,
+                    0x6f8a238ee7da92ae,
+                    0x0d352293de7fe0a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf91c8712b41eb19d,
+                    0x6fdcd28cd5d4638f,
+                    0xc70d11d0afdadc02,
+                    0xe58e5943e83cc91f,
+                    0xe2aed65d82c35573,
+                    0x12a5c9407afd7c8b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcaa115825003171b,
+                    0xfe5e60c5dc1034ee,
+                    0xe0bc1c6714eadeb0,
+                    0xc63c241cc270d8b8,
+                    0xa1e2ca311b5a124b,
+                    0x04b2410f3b60036c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7db573da9917c3c8,
+                    0x16dc056e0fe6d137,
+                    0x4d71016787d98d89,
+                    0x65abc66fffd8cc93,
+                    0xeb2fc26e4e799533,
+                    0x053db3d1d428ded5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x6e7b3d9cd779e9a2,
+                    0x205ac5fc949b4f67,
+                    0x3feb060b318fbc8b,
+                    0x3fa4060143673c45,
+                    0xf2774eae38c06fef,
+                    0x170c74dda472c24f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa7c22a14194c1324,
+                    0xa66364e359422b44,
+                    0x0b06c2dbf352933b,
+                    0xf91a93e2eed38b27,
+                    0xb17476220f873367,
+                    0x02580cd04c95f45c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8ed60a3f7bf69be3,
+                    0xac6319714b9727ce,
+                    0x32b248a90d15b8c2,
+                    0x911f3d9cd0e1cbd8,
+                    0x71bd41d4e643d9d8,
+                    0x161443e1f8b59df9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0006256dc63e412b,
+                    0xe4ad6165816efe52,
+                    0x4ec5e1dbf835b42f,
+                    0xdf2e6707fc98d234,
+                    0xc02bd954a3a4a903,
+                    0x12b6115b6b5f50c8,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x98b07d6ca0228906,
+                    0xbe7c69032dcb9aed,
+                    0xa12aaf49ef2b163d,
+                    0xcf8c759e7ded4b85,
+                    0x5ad0a417010ab970,
+                    0x16c08db02949d87d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe0c349e3a88a2dfe,
+                    0x195cb21e28b876b6,
+                    0x23bd8f81c852613e,
+                    0xd124519c669f4c2c,
+                    0x94fd11e93ba41f91,
+                    0x08c29b8a86f4f135,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf548688071147ae3,
+                    0x02d519052fc58f59,
+                    0xd8ad9ef7e65662a2,
+                    0xfe74dacb0bfda422,
+                    0xd5ebd066e3ef9b2a,
+                    0x10c8be3c4de3e1fa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2c11dc490bbf8ace,
+                    0x152696e68212a095,
+                    0xae29aa4178ca5ad1,
+                    0x6e8f8adf39cced7e,
+                    0x242175e4a6325b4d,
+                    0x08b5d2c2750f8f25,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdba59f46b5f82dcd,
+                    0x97efb2594d34f87f,
+                    0xa568019e5aa1b3d2,
+                    0xe345e4ef77613aa5,
+                    0x0726985a2ad4dd08,
+                    0x0393446aa701968e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc98dc291e13d37b9,
+                    0x511e0ba2fbf247f8,
+                    0xfedffda5d7176274,
+                    0x19a75e0820c7ee89,
+                    0x7d93efcbd5c521f0,
+                    0x025b45de07f9ee53,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5780c6efbc7b0d91,
+                    0xa089d3f9b8b50e1d,
+                    0x114b0a87b01caf71,
+                    0xa4a9dcfaa9b4aafc,
+                    0x09172f1b22d2c646,
+                    0x0ab0f5349721d9e7,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe47e48ac2ad9a311,
+                    0xe46fdb73d450d99b,
+                    0xa31cafafecb27568,
+                    0x629dc9ee5f8fa15d,
+                    0x7af62175520885f6,
+                    0x08a37d1f398f4375,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xde345afe0b428650,
+                    0xff5a347a6b736df7,
+                    0xc34bed3faa5bab74,
+                    0xe34eb1b39c3dede7,
+                    0xf2f9f06b677fcc5d,
+                    0x0ac4ea1b89207c40,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd001e9481d025db2,
+                    0x892c2541bec3293d,
+                    0x24b8d28028c046ec,
+                    0xf7a66dfa0fafc3d7,
+                    0x67f63fbf28a01c44,
+                    0x0d816e2a439e5855,
+                ])),
+                Felt::new(BigInteger384([
+                    0x342403aecff0e9da,
+                    0x65524cc5fcd32178,
+                    0x8bb05cdc0a8c4c82,
+                    0x225121eabccd5482,
+                    0x4f7a695e8e0d5d56,
+                    0x034a6fc57f117bff,
+                ])),
+                Felt::new(BigInteger384([
+                    0xec9edc845eda9540,
+                    0xf2823030ec486061,
+                    0x61e664da6d439c4e,
+                    0xad2149add08531e9,
+                    0x0b5fc870c5282307,
+                    0x086af50d20fbf84b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xbfe35bc1c5516a60,
+                    0x469255061ba427d1,
+                    0x02e6dc882e92e58f,
+                    0xc06b86bf0dd298be,
+                    0x2a6b09fb0318ae52,
+                    0x0e09aa261f47bdaf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1b76bbab22607b46,
+                    0xdb4c8fef78e760b2,
+                    0xd4dad30c0ba5756e,
+                    0x586c40171a8204c7,
+                    0x60c52b99f80edf9c,
+                    0x05719aa7b972e343,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3ad7d6fb0ce2a8fe,
+                    0x849d686a2c99ecac,
+                    0xba27c661818b3250,
+                    0xcc3cd841905eb49c,
+                    0xf4a54b247f7d33c3,
+                    0x112ca773286c8009,
+                ])),
+                Felt::new(BigInteger384([
+                    0xff540afb500559df,
+                    0x37f766c28cebf8fc,
+                    0x9e52cc801fd81ca8,
+                    0xe21ad0197a3d009b,
+                    0x2fa3ac2fd15be520,
+                    0x03a2f5e8ccfaacf8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x01a08c2637059200,
+                    0x9a2138978ad160e4,
+                    0x5eaa65774fb3a8eb,
+                    0x08e6e396321ead6b,
+                    0x1a943cc21e208b29,
+                    0x09ed044460c54308,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd004ed51e0275a0,
+                    0xef4f4d3875fd9aa3,
+                    0x14092cd94f4be87f,
+                    0x03672aba68ce04e4,
+                    0x8d6b0fa7c4cd007a,
+                    0x0e5be1ecd07c82de,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa43aa435c421ee2c,
+                    0xfc364237437fe6f5,
+                    0xe8b824dea4969c40,
+                    0x6a4a4cb8e8d4f054,
+                    0x83d826e36fa39c6d,
+                    0x0a6542ffcd2630da,
+                ])),
+                Felt::new(BigInteger384([
+                    0xca67b491af82620e,
+                    0x8e0cfca3fb1b841d,
+                    0xcafde6f0202101ee,
+                    0x14fe467ee7b42e55,
+                    0xf321817b4191c3f7,
+                    0x015907d0bbe5619e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc08a4f7b5b838570,
+                    0x943314413d48b3bf,
+                    0x6f01084259c7dd98,
+                    0x95f22e10313c23bf,
+                    0x688b3d2877d7b4f9,
+                    0x0d2fd919aa9a4ede,
+                ])),
+                Felt::new(BigInteger384([
+                    0x79382d6ea213a3d6,
+                    0xc6f1f20b41b08d57,
+                    0xdbbf2c0b17470d81,
+                    0x8cd5040592a12b4d,
+                    0xcd726b56aaf9ad96,
+                    0x14fb2b2dc55480bd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43b8057a3ad0cb5b,
+                    0xbb899c7bab1ec23b,
+                    0x3f8a3f632f5b42d6,
+                    0x4b81491e73db1eac,
+                    0x64b060ba203e21fb,
+                    0x16b540bc98ecd87d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55bdbfc3cf3ce425,
+                    0x7751f9410410420f,
+                    0x7c1e5eaa821cf1dc,
+                    0xb7c603ad4b1d2e88,
+                    0x9b838a837975f676,
+                    0x04ed36d022392ff5,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x95de1e7bf9b98c82,
+                    0xba8b971034760944,
+                    0xa62190b09ab542e9,
+                    0x7ed56a63201aad24,
+                    0xf414321a531d0eae,
+                    0x04d6354ce7dad886,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb987dca04c5f4cda,
+                    0x0ed60f80c591826a,
+                    0xec97228c2e66c46e,
+                    0x706bc3443b72d9ad,
+                    0x17417650dcb21826,
+                    0x0eb69e0a6f28e1b2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b87ebc8154571f1,
+                    0xb8c0bb597e8c927f,
+                    0xe6a4b30183d4d4d5,
+                    0x5343e1bcdedeca3d,
+                    0xa927cac1d546db1b,
+                    0x1611d359941a6235,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1b655d4f4b148383,
+                    0xc1a0673afa937319,
+                    0x0df8391f9324155b,
+                    0x4dea76259a52cc6a,
+                    0xf729ea8aea075794,
+                    0x0376d276e1e96fb8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x97fbd7d749ac63cb,
+                    0x4001b43e28869cfc,
+                    0x953bbcd7dfbbe033,
+                    0x8346960dd9229033,
+                    0x89092d02260f06f6,
+                    0x1022bfba58ffe01d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6c178a2c3b939362,
+                    0x60eb5061eeb653d8,
+                    0x19f86913671e79dc,
+                    0xcaf2c72d7d4c9d85,
+                    0x84a1052fea1ca874,
+                    0x0934f94f5c538306,
+                ])),
+                Felt::new(BigInteger384([
+                    0x55288a9e5d55ab83,
+                    0x66a1fcd01a8ab693,
+                    0xd5978833da73fd4a,
+                    0xcc523886509cf311,
+                    0x67f027c9dd91434a,
+                    0x06c284568de81042,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5647acee53a991dc,
+                    0xa382a47907ea27ce,
+                    0xa56ef6c821c3d461,
+                    0x4bf852fcac966012,
+                    0xf92e50986304ebac,
+                    0x1292b8cb1941d495,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0xe92d85c26c634154,
+                    0xcb55ab67862b2fa3,
+                    0xac29ce73f8c8ee59,
+                    0xf6ae88be661ae024,
+                    0x9ba4fe4b903fd78e,
+                    0x16b4f27446850a9d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31cc1296e705b9a9,
+                    0x60d6e29221428876,
+                    0xfb988e7272084bd5,
+                    0xa5aa15f934190c7e,
+                    0x0268b6dd62b62f2c,
+                    0x043693c1b1ffc266,
+                ])),
+                Felt::new(BigInteger384([
+                    0x703d316e3240c497,
+                    0x00cf58acbcdd2472,
+                    0xa6a17f7405721093,
+                    0x868a72a94c1e3fea,
+                    0xf8c466df9b563872,
+                    0x118ec744ff5fb989,
+                ])),
+                Felt::new(BigInteger384([
+                    0x50916209f2ef6ab4,
+                    0x3267ace554ed1385,
+                    0xf047770eb2e19797,
+                    0x542fc506ef6c6362,
+                    0x3fc08b5d0e463b85,
+                    0x135afab3377a7f02,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6434b68f5ccb4029,
+                    0x78c63ae296415f55,
+                    0xa24af11962f6cc54,
+                    0xb9de61c4cfcb6cd5,
+                    0xc8acba65dbdc3d43,
+                    0x060df341f7225b7d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa847b5fdb034fca5,
+                    0xb90c8318de2f735d,
+                    0x207c5e632500792e,
+                    0x23e4fcdf7abf7091,
+                    0xca9630494620ff74,
+                    0x13f8f68a759361a0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x330f7c8fd7569631,
+                    0x9f7de297ba300265,
+                    0xbf8ae194ef6d2774,
+                    0x9648d853a1404460,
+                    0xf54ee534150a043d,
+                    0x07254d90857b8f8c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x388fbabb1d17075f,
+                    0x61dd3953b03c982f,
+                    0x9dfaead79dfa33d2,
+                    0x3c54fbd09b6fc654,
+                    0x79be1ee21acc6a16,
+                    0x171d8d79c756187a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger384([
+                    0x80d7e6576ef5d0cf,
+                    0x0eac6eb56062c695,
+                    0xa2f60009d1d65250,
+                    0xfe846889bc5db2e3,
+                    0x99449bb23b70f031,
+                    0x0323ff97d5018041,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcd56f95d12d36868,
+                    0x09ed51a3153d93ec,
+                    0x1f3c4e31d120f523,
+                    0xd89b8bf88bf836cf,
+                    0x82fe248bb5c02996,
+                    0x0140ac426aabf8bc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2ba344ef686e98ef,
+                    0x2d9b8d8483e41612,
+                    0x8d621451620ecab8,
+                    0x10cc88a6ff66ab82,
+                    0x5b42cd2c8b353d9c,
+                    0x137e308dd6da415d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe7717300993b6b09,
+                    0xad9e9843b26e4504,
+                    0x5607876782f09b61,
+                    0xd0d9196a1f055dde,
+                    0x5f29c2c9f6bd02e9,
+                    0x0fd08a9ca8016a7c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1961ee143558fa3f,
+                    0x051dfdcdc0a8485a,
+                    0x97ecb1eb79bc681b,
+                    0xbd3f18f7e1eddd9e,
+                    0xd3d719b6a7b7ab95,
+                    0x0d2fa27ef5057194,
+                ])),
+                Felt::new(BigInteger384([
+                    0x433a31536ae53185,
+                    0x905206efecd47451,
+                    0x46fcc4943cf9be94,
+                    0x142e5eb167705798,
+                    0x6c899cbd0819ca2d,
+                    0x0ccc65c6c572c324,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf41e44360298ff36,
+                    0x622e06d2d5554090,
+                    0xa60e30517dfdb24e,
+                    0xa0da44b62392de10,
+                    0xb58fd0b7aa78883f,
+                    0x109d210b9758dcae,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd49a9f5989a151cf,
+                    0x96c3cc091e3cfa7a,
+                    0x46e1b9bb713a6c4b,
+                    0x650dd20058aedca8,
+                    0x8dab5e627a5ef2c9,
+                    0x174b59caf274103d,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger384([
+                0x806c18bf9c1df868,
+                0xfba3047af2b1da8e,
+                0xf90c4b9f83be72f6,
+                0xe83a35eda84e338f,
+                0x89a78a26ff200943,
+                0x10f59c91f56d93a4,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x161bd7c487923a8e,
+                0x7b87bafdfdab3da9,
+                0x93970622e4288796,
+                0x0bb01cb1d3de0a61,
+                0xd68d9eae3d017e68,
+                0x0ef8b6952611cc75,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x7fb80a3154f93922,
+                0xa7ac4c2bcf7da427,
+                0xe580d1dd139afbc8,
+                0x313990d05f4a123f,
+                0xf0f67e146ead8707,
+                0x033b6c6de8de0803,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x5e1c6aadc203a7a8,
+                0xf77407c78227a366,
+                0x9cfff9094c0ed121,
+                0x9515603a6e67b842,
+                0x130fa446a12beba2,
+                0x03e926844b7d2b76,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x6888a5f7f5660ed8,
+                0xec82664ad237530b,
+                0x437343022ee670a4,
+                0xcca93fbde0a7f783,
+                0x27ccad3c2f01a456,
+                0x0ee35092872a6c19,
+            ]))],
+            [Felt::new(BigInteger384([
+                0xd5bdc9c9ec43f8cb,
+                0x7126aa68ad1e6359,
+                0x1f7629b1c30380c4,
+                0x76e214b6d5c99487,
+                0x1c90651e2de07f90,
+                0x0c99067609879350,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x78149c77447f7e0d,
+                0x69d098ffdb0a33e8,
+                0xbb217ea6e5979520,
+                0xdf6ec023fe8ee20c,
+                0x9929b5ba843f06b7,
+                0x182743afc24b93a9,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x8d4811591010b3b1,
+                0xc1c7be3db44d6be7,
+                0xd48b422de62350f7,
+                0xfcf49ef9baa528a7,
+                0x6de71cfb6f884ebf,
+                0x18fb076c8c99bab9,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x79f51849668e4ab2,
+                0xf7a8710d7ffa1551,
+                0xf25914623472f39e,
+                0x4b8e1bc74e0e48e5,
+                0x5e77ba549f5f96ff,
+                0x0b7011eaf4224da5,
+            ]))],
+            [Felt::new(BigInteger384([
+                0x6a6d831b25a2f522,
+                0xcb47bed524806088,
+                0x077b41fb0efd8b67,
+                0x1b3710c2bd229e4b,
+                0xdfdc30bd3456c54e,
+                0x13c192cdc8e83f4d,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 46, 47, 48, 94, 282, 283, 851] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 282];
+        let mut opad = vec![0x5cu8; 282];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 48]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
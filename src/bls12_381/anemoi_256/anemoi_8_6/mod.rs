@@ -0,0 +1,2291 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger384, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 384 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 6 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 6;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 2 elements (96-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 16 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 16;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0d0754812edca8f0,
+                    0xe968c258593c77dc,
+                    0x74d90c92b4054fdc,
+                    0x2a6000887d9e38ab,
+                    0x2d40ed77d874e77a,
+                    0x0a247959fe1af3bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfcb0f491666b737c,
+                    0x9701be9ab43a5a50,
+                    0xc3314731ce1f0189,
+                    0x3052f8d27951c597,
+                    0x34119fbe81d228db,
+                    0x0c90f014a90dd86b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe52b01861f134de0,
+                    0x83cb8273872f2841,
+                    0xa617beaf859bcfe7,
+                    0xfc2e438d702c79e1,
+                    0xea2d77cae4528b32,
+                    0x03e916d646330c2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb07a4ae573aad57f,
+                    0x3b2806743e23a595,
+                    0x3e4ae773b73d1350,
+                    0x23fa7fbdfbb3bf3c,
+                    0xa8b682a07e20198f,
+                    0x0ad507888dfbc7bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9f074ff4704fee23,
+                    0xc7f9ca3820e6f8bd,
+                    0xffb65cc550bf2d3f,
+                    0x8e9285605124c501,
+                    0xb9b988ca98ce91c9,
+                    0x19277597069e8f22,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8d394e110cb8731d,
+                    0x09a5314718ab6a60,
+                    0xe768920cf6e07539,
+                    0xe8b03e665e330d5b,
+                    0xabd4ba295330434d,
+                    0x0b0c95e8ab76f9ac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x16eb621168705739,
+                    0xd28476789986fd6d,
+                    0x418bab7359e5c73c,
+                    0x130f3e29532cbf3e,
+                    0x96e3c1aaae82c3a2,
+                    0x19085d2131321c51,
+                ])),
+                Felt::new(BigInteger384([
+                    0x38cf8f6d953e547e,
+                    0x567168f8d57a5e46,
+                    0x4db2d9220d8c5d57,
+                    0xb451498e405c96d6,
+                    0x8719e473c0059f79,
+                    0x14eca07c7104247e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x34e6718a9c5aab17,
+                    0x668b6203875d8c8c,
+                    0x2920cc2a59bf2545,
+                    0x7456ef0e9307ed7e,
+                    0xabae4f4a45c382a9,
+                    0x05b7d5eaff0a6dbf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x868e28157dd66528,
+                    0xd76a2089e30f5875,
+                    0xdc46e601b4ddae16,
+                    0xe06a19c1c4a2d06e,
+                    0x8702042d69bb18fe,
+                    0x04bedcc71195f31f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xacf8b56a34a223b6,
+                    0xb6ebf673e77542ae,
+                    0x67c4d4fe6917b0d7,
+                    0x11570e32356a6810,
+                    0x77dfa6541f6251df,
+                    0x15324f0d0d810d4a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdea1adcdf19c1e5d,
+                    0xc4a599df4bc3b761,
+                    0x0d46aecdf32bd2e2,
+                    0x32ff9cf5027ff9b4,
+                    0x1605bc14e28b317e,
+                    0x0364d1f721aec609,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaeda424b2bf8ca1a,
+                    0x3dfc248c7e799383,
+                    0xbd16dc181befabcb,
+                    0x9c997a44a56b707f,
+                    0xd410328aff130638,
+                    0x0b30ea6bef88bc45,
+                ])),
+                Felt::new(BigInteger384([
+                    0x18a26e155e98d959,
+                    0x8ec6106010cee8d3,
+                    0x1f6c59c24ae1d1a9,
+                    0x899fbe9c840a2804,
+                    0xf7c3be17a8d9f497,
+                    0x1506452d2203be2d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe2e3e516366d2c58,
+                    0xb62d7dc7a321a925,
+                    0xd413f1198d976046,
+                    0xb44fc09a6941c422,
+                    0x1e959f775a30ba5a,
+                    0x17326934a0580e2c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1791dfd794056a81,
+                    0x9a2a627bd59ae4aa,
+                    0x3c951cd3af53a7f4,
+                    0xa8d797ae14d9f34d,
+                    0xa05f63eb5bea86f7,
+                    0x00ca2ff994e68eae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4b446823a9fd3b86,
+                    0x84aa94071910e573,
+                    0xc9898095223b4d54,
+                    0x535a997e5df4afb0,
+                    0x600eb99c6591ce25,
+                    0x1764177fe23bb72e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x667a910096ae23e3,
+                    0x527193b520c87cef,
+                    0x4c6c518ffd80a43b,
+                    0x6dc551cce1a2a34a,
+                    0xd45426e754214ad1,
+                    0x124169dd520a8af9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2f7bc254ca6d4802,
+                    0x9b427521baa04e9e,
+                    0xae5b4e2c8e40e542,
+                    0x5fc6509d6eba63e2,
+                    0xa0b6b8dc1c1fae43,
+                    0x03a838e53aeda7c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0xde9435afe62213c7,
+                    0xfc42d1fdf05622e5,
+                    0xd10082f5a49a83e3,
+                    0x5150fd0e0a6154d5,
+                    0x6fdd87d40dfee732,
+                    0x18f8c6df4904d0ec,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa0176b67ffd4a44,
+                    0xecf187e2aea23a2a,
+                    0x48e84c4839d04a21,
+                    0xbdde211ed8e768fc,
+                    0x5ae23928a0771604,
+                    0x0080823f651d3ce3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x08294cb2e51d6020,
+                    0x0a5cfc410117168a,
+                    0x924741541c61d416,
+                    0x136e7207904903a3,
+                    0xf9402c8d2e8a74a4,
+                    0x01ce0e947bd270ee,
+                ])),
+                Felt::new(BigInteger384([
+                    0x848c5d3d26a523c2,
+                    0xbf3885c1723f3c7f,
+                    0xcc25cd6b19d86d8c,
+                    0x1225612f7a523de4,
+                    0x420d87c81edef903,
+                    0x0a736f3226ceb329,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd26a4cd2fd243e2d,
+                    0xee3620a37086d134,
+                    0xc8f75bea1a99ea5e,
+                    0xa9f1c77aea5ed117,
+                    0x9a09d2fd45206df8,
+                    0x0a328210cc263891,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf7d32caf9d9a5125,
+                    0x9709f0d8b6ebab4e,
+                    0x02733c2446420c5a,
+                    0xf24944b0778c8af7,
+                    0x0186b7014fbd538d,
+                    0x00651b97409bd7b8,
+                ])),
+                Felt::new(BigInteger384([
+                    0x395e3e0f4c86f80a,
+                    0xe2c7fdc13a7066fa,
+                    0x7b03379149bb3883,
+                    0x877423a79effc628,
+                    0xb924baa6717eef99,
+                    0x06197d42c718cc21,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0add61da13073538,
+                    0x1f561880a32c0d06,
+                    0x69221602a8848b37,
+                    0xcc368e9df9913f00,
+                    0x13807b94a331556d,
+                    0x10777d555c68992e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0e778da3ff399398,
+                    0x5c78af49e28a2ce0,
+                    0x9c5e87c0fd12a471,
+                    0x1758d2aa6c0cb67b,
+                    0xe9e0f645d58d6cd2,
+                    0x0771d47330bc53c7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1059a36a9f3ddb58,
+                    0x47cbdf811456f957,
+                    0x9e7fd037430df585,
+                    0xdc78f89ef6ab3d6c,
+                    0x1ae39a340754c728,
+                    0x0d4cd76eb07672d3,
+                ])),
+                Felt::new(BigInteger384([
+                    0xddcf06f4cea3f08a,
+                    0x9fb390dcb1037d23,
+                    0x3cef53bbb661a60c,
+                    0x43f042e4c82ab542,
+                    0x2bdac61e1c7ff26e,
+                    0x1057e27eb1f93967,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5dd30ca2f34c62cc,
+                    0x50b2fc8db157c537,
+                    0xf13c302c410ea1b0,
+                    0x1903b88b07105644,
+                    0x4a89f6a61ccc33a2,
+                    0x0686572df27a3ede,
+                ])),
+                Felt::new(BigInteger384([
+                    0x257caf0ef2a8ad67,
+                    0xe34c12bb94ca02e6,
+                    0x45da4353536a8481,
+                    0xc4263dac3d6a9199,
+                    0xc6b22672cc519edb,
+                    0x03884e6ce8eab04b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x0c3507fb0cea51f8,
+                    0xf33aca212776c3ce,
+                    0xde13e1820c01a533,
+                    0xa4e7a7190774edf0,
+                    0xd8811275a35d9b37,
+                    0x106b060fa48e81ca,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf26dabd63d45850,
+                    0xb656de366feb92b7,
+                    0x4a83faf44449a4b5,
+                    0x1895c1e6913a7f4d,
+                    0xbc5544d0acf85f35,
+                    0x0f0ad1ab0deb2b03,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3e436b96076e1f55,
+                    0x71a1e55356f0f2a7,
+                    0xa0cec578a1632b98,
+                    0x5f7ab87cdcc5fe2f,
+                    0xe77584f092d1cde7,
+                    0x102d39f595896ce6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1dcb7e6900320c27,
+                    0x51cd4584eb14f921,
+                    0x876fc3f8151c3c9f,
+                    0xad015733969c3ec6,
+                    0xdb2c4eb8ed802fec,
+                    0x04fe3374ca72cba5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x21ef732e217b5d0d,
+                    0x03a03c1719f32efe,
+                    0x6399c150cc35c4bc,
+                    0x3c2660cbbcc69d7f,
+                    0x53600cb0b2eb4ad5,
+                    0x193c399db5f7c1bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0cdfad15e7d5a0d8,
+                    0x4606a0955c515ad6,
+                    0x2555e94dccd0d43c,
+                    0x17521a09719ce85b,
+                    0x688874b0389fc639,
+                    0x184664c5f00cf62e,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe644f6ac47dd02a7,
+                    0xe9652de5acbcb952,
+                    0x685335caf28462d3,
+                    0x24961b65b2e6c0d7,
+                    0xdf3b7970ef00f89e,
+                    0x1498b040cb95ba98,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd5657126676717da,
+                    0x340cdd315ea540fc,
+                    0x0c228efded1201de,
+                    0xa9607fb1b0b4a4ce,
+                    0x98b70d6465e424fb,
+                    0x185c2c9be765eafd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xb021d06f42255e5a,
+                    0x916ae07b156438b9,
+                    0x97bfa93c5beb20c4,
+                    0xff8e16c6157f89ab,
+                    0x494ee1bf64be132e,
+                    0x108b46f10f5f5c1d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a392e6de5798b64,
+                    0x33417e89b749e2f1,
+                    0x5f34b2b9ea107612,
+                    0x3c22818d25c2efde,
+                    0x5cc44751c0c02fdc,
+                    0x0c4cb19f4632d246,
+                ])),
+                Felt::new(BigInteger384([
+                    0xce00e96daa0684dd,
+                    0xf0fe49e6baa783f0,
+                    0xac2b7cee0a9ee55f,
+                    0x6ee8d622c4da0ac6,
+                    0x2f22dbaffb707194,
+                    0x0f27f0663323357b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3367d0053588100f,
+                    0xb9a116d6ce22edf2,
+                    0xe5a0b711eabd268a,
+                    0xa1cadb9954993271,
+                    0xa36ef2180f53d509,
+                    0x022548a2811687a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x106d8bd42f66719f,
+                    0xf8ac632b5136a91b,
+                    0x6d88528e09393dc0,
+                    0xc7164b67590ae6b5,
+                    0x217702fa18893705,
+                    0x03d67bcd5051e2f1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3d6ec0de6ef2c452,
+                    0x8f84bac1306bce2c,
+                    0x19d8ff279d61df71,
+                    0x0daf1c59d9d26914,
+                    0x845a57dcc00ec5ba,
+                    0x156c63a40868096e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9de77a70c6cc4d23,
+                    0xa26e103ef5b21368,
+                    0xf5372c1ab21f1cef,
+                    0x02927c3b52e1ab99,
+                    0x331629df68786b56,
+                    0x0dd899fcfb88f483,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6ceea54f772c8ab0,
+                    0x567d9a18e981cccf,
+                    0x35e2d2d45b087614,
+                    0x9e13797596d567a4,
+                    0x0348b8bffbee6ed3,
+                    0x07dda49cea59fd66,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1804000000015554,
+                    0x855000053ab00001,
+                    0x633cb57c253c276f,
+                    0x6e22d1ec31ebb502,
+                    0xd3916126f2d14ca2,
+                    0x17fbb8571a006596,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf64900000018553d,
+                    0x40f4005f6f0c0013,
+                    0x9313f019a789cfb3,
+                    0x59fb77168f0da76b,
+                    0x951d2d06cf6bb694,
+                    0x15b1e4359a873e00,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x321300000006554f,
+                    0xb93c0018d6c40005,
+                    0x57605e0db0ddbb51,
+                    0x8b256521ed1f9bcb,
+                    0x6cf28d7901622c03,
+                    0x11ebab9dbb81e28c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x221a96dee879506e,
+                    0xf474857d61fd8d8e,
+                    0x0cb62c7142732926,
+                    0xdab2c2b279e8e05a,
+                    0x66bfd291dacd2598,
+                    0x14a7b8b22c4d8158,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8501827008bb463c,
+                    0xe000d5f0f9385e8d,
+                    0x651c1aea973d56a5,
+                    0xc16f501811680aeb,
+                    0xbd0d7da3633e27ed,
+                    0x100d54ca211c6624,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c220000000b554a,
+                    0xed28002c72d80009,
+                    0x4b84069f3c7f4f33,
+                    0xa827f857a8538294,
+                    0x0653b9cb0ff30b64,
+                    0x0bdb9ee45d035f82,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+                Felt::new(BigInteger384([
+                    0x43f5fffffffcaaae,
+                    0x32b7fff2ed47fffd,
+                    0x07e83a49a2e99d69,
+                    0xeca8f3318332bb7a,
+                    0xef148d1ea0f4c069,
+                    0x040ab3263eff0206,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x1351a71a619fcd69,
+                    0x8dce65b19cb22528,
+                    0xf414a71ddd634cd7,
+                    0xa29c6c9de5a8398e,
+                    0x88091af721438682,
+                    0x082c930714347663,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb2cb018b0849f2c5,
+                    0xa73c7f2f047e9e80,
+                    0x835ccf41bc6d2721,
+                    0xf446f7d23ea3e921,
+                    0x923aec3914dcbcb0,
+                    0x157484f985a2fdc6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x47666d19309fa491,
+                    0xb68726a17c7c37c2,
+                    0x90bc7c4d9712fd46,
+                    0x38e32ece058bd8a6,
+                    0xe62d90c0a0384d5b,
+                    0x00c4699141faf693,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0a4b91fb1ad304dc,
+                    0x592b21537501c360,
+                    0x0c587b89cf9ae49a,
+                    0x59c0d8c3a70f0039,
+                    0xff597796cf3aedff,
+                    0x02de3e6e7583ef89,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc63309e256b609e0,
+                    0xa7974edb279d93aa,
+                    0xfbf0d51e7e5df129,
+                    0x835b48b443178f5b,
+                    0x4663187b12afc113,
+                    0x0409e27ae828f567,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c19986294e23e30,
+                    0x04e1e342941d3403,
+                    0x042ea3fe167ea0bc,
+                    0xd7c48bade95c47fb,
+                    0xb8f491502a34fba1,
+                    0x1113c99b518702ae,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4cd9f0a1438d2c40,
+                    0xe0f5c1ad86b58056,
+                    0x26eefcae5e389cec,
+                    0x821287d7b3fda485,
+                    0xc0d710de7e3181a8,
+                    0x10858393b1596291,
+                ])),
+                Felt::new(BigInteger384([
+                    0x54ee0d4b17d08986,
+                    0x6c2510924bd3d693,
+                    0x8e4d1f4a01dec217,
+                    0x80c80ab1e3f6342a,
+                    0x2600e3dd0b4c4a17,
+                    0x0600656821dd41c0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x83722df8a9e333a2,
+                    0x32dcb16cd109390d,
+                    0x193c8b6782dd71a8,
+                    0xa92fdcb8c2315535,
+                    0x8ed5a2e7fb25b6a8,
+                    0x0ebc8641caba5976,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd829564cc9fda951,
+                    0x219d7af5d9e75db2,
+                    0xc175c7bcd4b2ced8,
+                    0x066adce384d37252,
+                    0xf0fb091b9b37f1d9,
+                    0x0b445604868ab30a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x51a08e09ee99aeae,
+                    0xfff9e3bc6d67832b,
+                    0xf3f5589c223f2b7a,
+                    0x1e4449618405e684,
+                    0x55d46d3438bfd5c0,
+                    0x0b3e26dcbdbcaec2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcaff8d9156a1d233,
+                    0xd496cde8a7139593,
+                    0xadaa6bab6b465d7b,
+                    0x20bca3bd91ec938e,
+                    0xfeda6c2191538f94,
+                    0x0a05787c95f80fac,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4138edcf0e97803d,
+                    0x75dad51279677315,
+                    0xa676671e04c501e5,
+                    0x379cdc26e5b7e138,
+                    0x2afba03896b7a4b4,
+                    0x0e6071f011ac918d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5caff31df89dea16,
+                    0x7da0bd0937e1eeaa,
+                    0x5cf86c3ad783c768,
+                    0xe40f0a24be8f2d83,
+                    0x9a3d3ca301bd7166,
+                    0x0b041c6130a0e1c6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8e2586db0f4f3548,
+                    0x5abda2cf4ed0f192,
+                    0x10feff4e9106b52e,
+                    0x45de8182a31ec45f,
+                    0x3568d2aaeeaca375,
+                    0x042e24b1cb737e6f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7401e13b52584693,
+                    0x20eec8d3710a34f5,
+                    0xa9c9eb11d1ea4911,
+                    0xd987f370af93580e,
+                    0x8d178dafaca6ca38,
+                    0x0f9f95984cd95b5a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x49e9bf27a12b1aad,
+                    0x65685b134a2f077a,
+                    0xafb117ff9002a00b,
+                    0xee2923bf0559b441,
+                    0xcf5304b52c1bdeb7,
+                    0x183423bda88fa05b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7200517734876da2,
+                    0x35cf1710c750907f,
+                    0xd34c4c797e874b21,
+                    0xcd0ae3a194948f81,
+                    0x9bc74cb115454aa5,
+                    0x0bebb4684a3cfc32,
+                ])),
+                Felt::new(BigInteger384([
+                    0x050c1a0c266be9c6,
+                    0xa93d5e64843e66e0,
+                    0x80d2b69942a1596b,
+                    0x39fccf5e095518af,
+                    0x0dfa9e845e70930b,
+                    0x0c4fe24415d2c5af,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaa8bfb7da2b227ae,
+                    0x748d4ce1a596130d,
+                    0x1fc64dbebb839576,
+                    0x385d26d6b370af78,
+                    0x81eeb4f729bf487a,
+                    0x17cdacf4de97d02a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xb1ac8df18ccdc28e,
+                    0x410b44e9b172fadf,
+                    0x1fbfa85c021e7877,
+                    0x44bf0a5376c1c16a,
+                    0x7d7fe8eb3429cbd5,
+                    0x006fe80a710902fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6feb7683f7e74927,
+                    0x9eb89e8126024ac8,
+                    0x328e59f3fb71e542,
+                    0xf11ceef2d4c1fc04,
+                    0x6e9df2b27314de90,
+                    0x0a6967b239a4b2c5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2abfa187697e6f21,
+                    0x4232ba3a50e25bdd,
+                    0x09aa11bd933c595e,
+                    0x6c2108929c9cc636,
+                    0xa500c5ee910318d9,
+                    0x0fab630d0e8ebba0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x10891b4385da3695,
+                    0x5c11ede25cc06c40,
+                    0x35292b3b3a625365,
+                    0x466a2164e84c9220,
+                    0xb69edbdadfccd0d5,
+                    0x007567d7a7e9a004,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x68485c2cc510e0f4,
+                    0xcae02addd3d6876b,
+                    0xdf73ce3eb68d41a7,
+                    0xe2adff4b2432a96c,
+                    0x441ad9423696ef87,
+                    0x06d91341f72eb515,
+                ])),
+                Felt::new(BigInteger384([
+                    0x059314a0fd35799e,
+                    0xb9e3425b7cafb43a,
+                    0xce47a5b52f597196,
+                    0xd3fe473bd1765e7f,
+                    0x638d38328b310cb8,
+                    0x18998ccb5308af34,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4b48560af9d9a9a2,
+                    0x09e077923670adae,
+                    0xd64d1b32881b58f0,
+                    0x101fc062596c3ebc,
+                    0x69dce87512aa2701,
+                    0x11b2c619a9631921,
+                ])),
+                Felt::new(BigInteger384([
+                    0x220bb112bfaf3d32,
+                    0x694010ca989fa0a5,
+                    0x7b631d41a776df81,
+                    0x50695d7c3f78b6c0,
+                    0x6ac91b66047fb6a5,
+                    0x160b6b40d2510b30,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9bc68384f1cc3dc3,
+                    0x0df2e10f6be2ec5a,
+                    0xca4c9bb5283f0f6f,
+                    0x3956d7d989fac285,
+                    0x85706ab694f864c8,
+                    0x0bba542ead12b9a1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1d09018eb3d8fc4a,
+                    0x008266d2c68d1cd7,
+                    0x173e3b03fd7420a4,
+                    0x22354253a0ebcf99,
+                    0xf6322006f5af9a36,
+                    0x081cf0d03584b8fd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x69679e1c5f6ee86f,
+                    0x4b6c039690f49fdd,
+                    0xbdb327ef228afd27,
+                    0x7654a55e7c929ff4,
+                    0x0e8557fd90dd9476,
+                    0x00c3ecefff6debb6,
+                ])),
+                Felt::new(BigInteger384([
+                    0x30d294d94e264321,
+                    0x8924db8934906a78,
+                    0x5300bc7a952a732e,
+                    0xde0768fb1349d80d,
+                    0x639025402c565b38,
+                    0x144b38754ccb3e6e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc6c9920bf768e128,
+                    0x90d28e1146252ea7,
+                    0x19b4b8e38166467e,
+                    0xf72f64dd72718609,
+                    0xddd5a81e1edd4cf3,
+                    0x06583a8c8e8de023,
+                ])),
+                Felt::new(BigInteger384([
+                    0x363eb8d7bb5500a3,
+                    0xc93f1698cc3327ca,
+                    0xf403a9fbcc59f5fd,
+                    0xe3786990fc164eb2,
+                    0x2551ecd6c91c9d1f,
+                    0x16ed828e3634a788,
+                ])),
+                Felt::new(BigInteger384([
+                    0x41c524cc86980264,
+                    0x782f788134bb3000,
+                    0x7dd230cb8110a231,
+                    0x13174fd45dc667f3,
+                    0x07781759a6d09598,
+                    0x05505e532f32aed2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa3726660e11b8987,
+                    0xcb4a9b1af2eaa6c7,
+                    0x34f3cbe9856ef74f,
+                    0xaec9e637a345848a,
+                    0x7fc57316ec4beb94,
+                    0x191dd362381e35dc,
+                ])),
+                Felt::new(BigInteger384([
+                    0x68662224d0ed6611,
+                    0x29ac1f90c4c746d7,
+                    0x750fdf7e386bd74e,
+                    0xa86cd9409ec1cd10,
+                    0xf84aa91d1f9fba14,
+                    0x15c26680d04a2b5f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x07dd388734257aae,
+                    0x727a136e962b5b08,
+                    0xd2fa27d46f4a3398,
+                    0xd5a18f84b06a3b76,
+                    0x950e8c26b86125eb,
+                    0x01aaaedb1f824578,
+                ])),
+                Felt::new(BigInteger384([
+                    0x223f7fd91174c58a,
+                    0x7a08c0dc8c9ff39e,
+                    0x974205e60dc36f39,
+                    0xea3d4e725ad0649a,
+                    0x5a5a980dbe2eea1b,
+                    0x061bc277e3cd364a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x953eb11c351970d2,
+                    0x79ef2826f364e4d2,
+                    0xa0da385a52756d1e,
+                    0x38e67739c07173b9,
+                    0xdfee740a88fd554a,
+                    0x11f8652e9e98a935,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x94bb8d59ea590f36,
+                    0xb9a932d8cb5bcfa8,
+                    0x37e340abab8ccb8c,
+                    0x5da75923bf4e7d87,
+                    0x535c92fa1baf6b54,
+                    0x0e6986267743f227,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0286f5e0446988f0,
+                    0x59fa7821655e9245,
+                    0xc7a8d0055bdbd000,
+                    0x5b8b98bbf2beb9cb,
+                    0xb7cb7dd2dec5bc97,
+                    0x133d0be0af396075,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9ad3605e3275fbd2,
+                    0x187f77bcf9494fd1,
+                    0xedc65a40b5fdf2e6,
+                    0xff4fd9e6442745e6,
+                    0x583fe2184c2c84e4,
+                    0x068dd05f1e9443ab,
+                ])),
+                Felt::new(BigInteger384([
+                    0x93f278e09d26d982,
+                    0x88353eebf0507b76,
+                    0xc3081427b7e8e030,
+                    0x3aecddc624e9fead,
+                    0x8bb4e9d6f9e8c6ff,
+                    0x0761885715a4fda5,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc300ae2f06f79ac6,
+                    0xa599f7dd54d869f9,
+                    0x2857ebab3ee214f2,
+                    0x689e6cd8dddfceca,
+                    0x1eea401d0c341dde,
+                    0x0233b06715367875,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4584ce330cf205f5,
+                    0xc54ee671b850d71d,
+                    0xd6b03ead592c7a70,
+                    0xdbccd215d9db0f96,
+                    0x96e877f717c5dbe6,
+                    0x0b2efe50406d4968,
+                ])),
+                Felt::new(BigInteger384([
+                    0x298662fe3c927120,
+                    0x85216b3c35b46670,
+                    0xd1016d3e12734950,
+                    0xb9c56116924157e6,
+                    0xe34b644e473df135,
+                    0x03d016d20beeadd5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0638fd293b105dbd,
+                    0x5c3a439d4a6fa020,
+                    0x1b4d153691745423,
+                    0x2eb577a42cf7faf0,
+                    0x462b4d7b08e5516c,
+                    0x028043bb6f8b331a,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8810c22f213d283a,
+                    0x88f7a6b0828ce34d,
+                    0x128d562ae7b8eeaa,
+                    0xa1969e784a224a16,
+                    0xea5d5c6d61dfdb1b,
+                    0x13b53b0a6389c397,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7a5ca9deccf0f9f3,
+                    0x70e802e62c6a726e,
+                    0x41e5f9a1b1ae33a5,
+                    0x561ecb3ee14a8640,
+                    0x60b51dac0f66bf43,
+                    0x0f1291a6276c8ebc,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf8ea4d8e83517d96,
+                    0x68c6e80c6aad6082,
+                    0xac6faea1631f07c6,
+                    0xa152821cdf9a7950,
+                    0x7b29ab83eeac4c21,
+                    0x118a1e240ee8bca2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe00981dfc683c477,
+                    0x4af6b1c61ccefe8d,
+                    0xc4d0badd43356692,
+                    0x432d28975f694174,
+                    0x709ab72e978f5f27,
+                    0x020f6b7efd029cbe,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8d00f9f695560fd9,
+                    0x1b9016cb9d16e0cc,
+                    0x41233659c1b15c55,
+                    0x149c2e246b06d5b4,
+                    0x4ab0f23b1031d711,
+                    0x17eb4d67fb0a75d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x73736363da981d9d,
+                    0x8961b75b5996bf6a,
+                    0x585361f0cc7f9b35,
+                    0x9b65b5435ee5140f,
+                    0x9161fcedd31347ed,
+                    0x079cf2c66be2b189,
+                ])),
+                Felt::new(BigInteger384([
+                    0x42eeaf5eef998436,
+                    0x5da35645d23f4df0,
+                    0xb1d77f28ea4bda04,
+                    0x0c23c10e81106d1b,
+                    0x4dc7bd7b1a952fa5,
+                    0x08ab4a237f5e9125,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf0e4f330d1f75f7,
+                    0xa345822d15549287,
+                    0x047b26967cfae3d9,
+                    0x033f3fbdd3c4cb77,
+                    0x3708fd2f947f8fc5,
+                    0x1869717ce090c0d7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x2bb65c608ce151af,
+                    0x395b476bb0ec33b6,
+                    0x803d19d8aa195a05,
+                    0x4d2d080542e17839,
+                    0xd3c3120d8d5c3a31,
+                    0x0464f87c2b2a513f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0df24a9405b10be2,
+                    0x9d9067eea95b2ce4,
+                    0x16d986ff29937ad1,
+                    0x2bd15a57507b1e1b,
+                    0x6f3aa54558f37c3d,
+                    0x0b0f3592c87df83d,
+                ])),
+                Felt::new(BigInteger384([
+                    0x7054ddee8113c855,
+                    0x11591845f5877a85,
+                    0x53c81d1cb3f86bf7,
+                    0x4157c82e90b12d41,
+                    0xeabc5d473453030d,
+                    0x16607a8424d2e7a7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x990d9f7e51c5d003,
+                    0x927147b778b147f4,
+                    0x37981c0feca66961,
+                    0x71b2d6a1872290e6,
+                    0xe56fd376753782fd,
+                    0x0449e09b18092eec,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6dc5818521497190,
+                    0xb22a0146f6ada2e7,
+                    0x1fa67f45b4508285,
+                    0x174f2ab525e61737,
+                    0xf39e28fc5fa4e1ec,
+                    0x035e4f33f2b4b92e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4c2ec5389d6e51c9,
+                    0x13faa17cf71406f3,
+                    0x64a46cc616a05abc,
+                    0x571f919593b282f6,
+                    0x383bdec76947df5d,
+                    0x156e05d3f419ea03,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd7919d69efd2e03c,
+                    0x53de7a5da03eb1d6,
+                    0x9f9bc3b597e52a65,
+                    0x97e70c1ec0cc4c9d,
+                    0x96482978efabdca8,
+                    0x008f153e0ed2183b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9fb16408b0335edf,
+                    0xdb91560947c757e2,
+                    0x89ad32bc3d6e97bd,
+                    0xaf15728e5d94e480,
+                    0xe9ca6a9bb61086ff,
+                    0x04e34efbf882ceaf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8a38a0b0adaaabcd,
+                    0x8f43599191d2b20c,
+                    0xf1c909a2eb496115,
+                    0xcfc2e89b9e879fed,
+                    0x9c371993a64f8034,
+                    0x05527942d79b2690,
+                ])),
+                Felt::new(BigInteger384([
+                    0x57245b025a1072bc,
+                    0xa2ab0b9ddb362ae0,
+                    0x6bec4458385cf38a,
+                    0x8fafaf9a3351ac73,
+                    0x372075931c7eb007,
+                    0x026f27520f42dfb2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfbae4178c5658845,
+                    0x333618a9b63a38d0,
+                    0x9dc566ecf33cf04a,
+                    0xd0dffa8a8d3c7fab,
+                    0x4e623474033def4d,
+                    0x166dd0b77ac2f64a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x61d40f9c5f723c60,
+                    0xf049dd1eb13e691b,
+                    0xddddb1910756cacb,
+                    0x090396ed8c6df812,
+                    0x4688a36c1d713722,
+                    0x08fef7df8752214f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4794ae38eabd880c,
+                    0xf2d744df748cb628,
+                    0xd0cd827594d0e469,
+                    0x5196a3420ead5254,
+                    0x82ecd9ae0d6187bf,
+                    0x0f20423b73f27407,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe7da2c76e8195978,
+                    0xd0e026cfad352295,
+                    0x1ea369782fbf513a,
+                    0x537e97635648afaa,
+                    0x7ef7292d4fd13e6d,
+                    0x0d8d514f28cca12b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0c72e6c99b6ece6b,
+                    0x34ab8a49ed6528bc,
+                    0x85c7fff640afce3d,
+                    0x826d850d65ba5982,
+                    0x892540c2cbb96e90,
+                    0x0e4176598c76b7e9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf504b48100ef8f5f,
+                    0x4dcdac9ab125f725,
+                    0x019705c3e107eaa5,
+                    0xb93acde71ac9f6b7,
+                    0xb07d40ab31826105,
+                    0x090fb73e84ae1ef6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x918ba4a6e4117e8c,
+                    0x0e9b9c6fb072a055,
+                    0x558f7b2255c923de,
+                    0xcc9cdf2715b73149,
+                    0xd0be3122054ad944,
+                    0x0f9ecae4194e4a08,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0d643afcba888e8e,
+                    0x642647a3c7d0bf7a,
+                    0xe8c42c21a1de9d6a,
+                    0x44c454335f2b828e,
+                    0xccbce6db257e5c0d,
+                    0x17a22a566f662f72,
+                ])),
+                Felt::new(BigInteger384([
+                    0x75eca0c4c67f6a41,
+                    0x8fe530c1fd27418c,
+                    0xad2b566e484eb6dc,
+                    0xef8082316ab20c89,
+                    0xc683e68304221839,
+                    0x0a43779e3e08ebf8,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc38e6f1f0d89a96b,
+                    0xb3002b7c25fefd40,
+                    0xf6692e5b45d64606,
+                    0x7a15341d87470e22,
+                    0x3724b9fed2d7e574,
+                    0x0e342c35bf3eef93,
+                ])),
+                Felt::new(BigInteger384([
+                    0x200e8129c4d07b24,
+                    0xd045cb4b059a1cf1,
+                    0x7e4a1876529937aa,
+                    0x5d63652a6aece16c,
+                    0x2eb6a91035da6e71,
+                    0x19db5cd2d7a2ac73,
+                ])),
+                Felt::new(BigInteger384([
+                    0x80087dcedce42921,
+                    0x5959aa51ded94fbb,
+                    0x7808b6bd1b984492,
+                    0x18535bf98d6101ef,
+                    0xde1a735d7aac7135,
+                    0x07db23a80252dbaf,
+                ])),
+                Felt::new(BigInteger384([
+                    0x088d0d3c1ae92c7b,
+                    0xf0c658a577a4c1c1,
+                    0x97445ec1848968ec,
+                    0x2c0a4f03b7ad2089,
+                    0x1be66d2b971704e6,
+                    0x04654fe47b34b38e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5703d57df6a2fb62,
+                    0x74b53602074f5763,
+                    0xa7b0a8eab640857f,
+                    0x06c8afbe1a675f7d,
+                    0x1be21232cda6c457,
+                    0x1069b65dc7b46f6d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xeb4eaa081d075e4a,
+                    0x78b3928bf0a3d79d,
+                    0x2962a5e444bac982,
+                    0x43f3e5a56114ec49,
+                    0xdeb3f33db3f27b11,
+                    0x193945bc9f93d4aa,
+                ])),
+                Felt::new(BigInteger384([
+                    0x92daf184937c4aca,
+                    0x3a16c1d49e467303,
+                    0xe409c7d1ffdffd31,
+                    0x56ef3d34b55f273e,
+                    0x38fb32573c047319,
+                    0x0691923a96ed7e1f,
+                ])),
+                Felt::new(BigInteger384([
+                    0x57a495d6b97e9e20,
+                    0x41e1761d633b2843,
+                    0xc51fba4cb697a974,
+                    0x89402b236183a189,
+                    0xc1d4c3f11ec7633e,
+                    0x017364b63634e10c,
+                ])),
+                Felt::new(BigInteger384([
+                    0x79624c549e6a2d05,
+                    0x01871bd7d0442a72,
+                    0xa59c95f2cbdcd794,
+                    0xc189e7329e2fb7b5,
+                    0xf340a500d39b1877,
+                    0x01daace874a5c07a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xccc8496070770d51,
+                    0x27d3ba2532ed0783,
+                    0xf10b10d048d0f773,
+                    0x42145cf67b018d48,
+                    0xb2fc40e41e10a430,
+                    0x0ef5fd7c52005d43,
+                ])),
+                Felt::new(BigInteger384([
+                    0x3ca308885383b48d,
+                    0x84db1545f1da0e4c,
+                    0xcaff42618ad85b38,
+                    0x2916d75249403106,
+                    0xf5d32c66fffdae45,
+                    0x14800f779acbbc64,
+                ])),
+                Felt::new(BigInteger384([
+                    0x2cd1d7e2eebe548b,
+                    0xf1158fff769758c8,
+                    0x81fd9dbc64f7e8e5,
+                    0xb64ac8fc9d6289fe,
+                    0x16379ce84029a520,
+                    0x13f781f93f5fafcd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x73128e05bf6510dd,
+                    0xe6eb56c08729952b,
+                    0xc4c99f07206317d6,
+                    0x81168877da498c56,
+                    0x4a522e4ad6d539a1,
+                    0x12cbc1f6a2fa1d41,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x89eae40ea12e58ea,
+                    0x1114c4485d593740,
+                    0x301c2401264009f3,
+                    0x517e174da862e4a3,
+                    0x30e76edb937140fb,
+                    0x19f03a9f675478c0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x216a2cfc6c4b50e5,
+                    0xd4653dab5f5d1118,
+                    0xc757c79cbb1b2b60,
+                    0x8d36fde341576aa4,
+                    0x65253e36bfac6704,
+                    0x185c9104571bbc9d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfd1b8e0694078d7b,
+                    0x3738828026759d3d,
+                    0x5d5599eac86ef539,
+                    0x7a121d234a2e36cf,
+                    0x0a7dea14e1df204f,
+                    0x15df88b737f966c2,
+                ])),
+                Felt::new(BigInteger384([
+                    0x897b99ac3933ea14,
+                    0x38a9b82a20a63bc2,
+                    0xc6c1f913808afe99,
+                    0xfb104c3c3998cc52,
+                    0x87721fd0dde3e55a,
+                    0x160abf29ce60d445,
+                ])),
+                Felt::new(BigInteger384([
+                    0xc285db1b2edb14fd,
+                    0x7baf8318bae60f51,
+                    0x053d20a537614748,
+                    0xec56c0d0343fa36f,
+                    0x94a313e15159455e,
+                    0x079af759331d879d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xfb0fc1b28e5a8792,
+                    0xb8a3bf5fbd8b0eaa,
+                    0x68fa0ff3895a4d11,
+                    0xa62070937ace34e6,
+                    0x36dcc62b8c082aa9,
+                    0x0d1afa4732aac42b,
+                ])),
+                Felt::new(BigInteger384([
+                    0x0589b4a7ee7c4b73,
+                    0x6a6c59d621eda365,
+                    0x1264c755898f08ed,
+                    0xd6d0250e89934594,
+                    0xcc86b22a76325cf7,
+                    0x12152ba2a5d5a6c3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6f06408a5ce45a62,
+                    0x774a4146b49c1be0,
+                    0x4cffc034667eae3d,
+                    0xb329c1286860d0a4,
+                    0x6aed1aa6417aad77,
+                    0x0850d34fb9b4d558,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger384([
+                    0x544e00000019ffe6,
+                    0xa7980065f8680015,
+                    0x8f1fd2f4d61500fe,
+                    0x63a6fd7dcd7449ae,
+                    0x1d92e6777ef1565f,
+                    0x13ac8aa27b07bcfd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66b10000003affc5,
+                    0xcb1400e764ec0030,
+                    0xa73e5eb56fa5d106,
+                    0x8984c913a0fe09a9,
+                    0x11e10afb78ad7f13,
+                    0x05429d0e3e918f52,
+                ])),
+                Felt::new(BigInteger384([
+                    0x447600000027552e,
+                    0xdcb8009a43480020,
+                    0x6f7ee9ce4a6e8b59,
+                    0xb10330b7c0a95bc6,
+                    0x6140b1fcfb1e54b7,
+                    0x0381be097f0bb4e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba7f0000002a552b,
+                    0xc8ac00a607540022,
+                    0xcec782259e35e414,
+                    0x28d1890b30fbb30b,
+                    0xbd47cc949d754125,
+                    0x19781ccd798c9974,
+                ])),
+                Felt::new(BigInteger384([
+                    0x544e00000019ffe6,
+                    0xa7980065f8680015,
+                    0x8f1fd2f4d61500fe,
+                    0x63a6fd7dcd7449ae,
+                    0x1d92e6777ef1565f,
+                    0x13ac8aa27b07bcfd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66b10000003affc5,
+                    0xcb1400e764ec0030,
+                    0xa73e5eb56fa5d106,
+                    0x8984c913a0fe09a9,
+                    0x11e10afb78ad7f13,
+                    0x05429d0e3e918f52,
+                ])),
+                Felt::new(BigInteger384([
+                    0x447600000027552e,
+                    0xdcb8009a43480020,
+                    0x6f7ee9ce4a6e8b59,
+                    0xb10330b7c0a95bc6,
+                    0x6140b1fcfb1e54b7,
+                    0x0381be097f0bb4e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba7f0000002a552b,
+                    0xc8ac00a607540022,
+                    0xcec782259e35e414,
+                    0x28d1890b30fbb30b,
+                    0xbd47cc949d754125,
+                    0x19781ccd798c9974,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger384([
+                    0x544e00000019ffe6,
+                    0xa7980065f8680015,
+                    0x8f1fd2f4d61500fe,
+                    0x63a6fd7dcd7449ae,
+                    0x1d92e6777ef1565f,
+                    0x13ac8aa27b07bcfd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66b10000003affc5,
+                    0xcb1400e764ec0030,
+                    0xa73e5eb56fa5d106,
+                    0x8984c913a0fe09a9,
+                    0x11e10afb78ad7f13,
+                    0x05429d0e3e918f52,
+                ])),
+                Felt::new(BigInteger384([
+                    0x447600000027552e,
+                    0xdcb8009a43480020,
+                    0x6f7ee9ce4a6e8b59,
+                    0xb10330b7c0a95bc6,
+                    0x6140b1fcfb1e54b7,
+                    0x0381be097f0bb4e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba7f0000002a552b,
+                    0xc8ac00a607540022,
+                    0xcec782259e35e414,
+                    0x28d1890b30fbb30b,
+                    0xbd47cc949d754125,
+                    0x19781ccd798c9974,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x544e00000019ffe6,
+                    0xa7980065f8680015,
+                    0x8f1fd2f4d61500fe,
+                    0x63a6fd7dcd7449ae,
+                    0x1d92e6777ef1565f,
+                    0x13ac8aa27b07bcfd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x66b10000003affc5,
+                    0xcb1400e764ec0030,
+                    0xa73e5eb56fa5d106,
+                    0x8984c913a0fe09a9,
+                    0x11e10afb78ad7f13,
+                    0x05429d0e3e918f52,
+                ])),
+                Felt::new(BigInteger384([
+                    0x447600000027552e,
+                    0xdcb8009a43480020,
+                    0x6f7ee9ce4a6e8b59,
+                    0xb10330b7c0a95bc6,
+                    0x6140b1fcfb1e54b7,
+                    0x0381be097f0bb4e1,
+                ])),
+                Felt::new(BigInteger384([
+                    0xba7f0000002a552b,
+                    0xc8ac00a607540022,
+                    0xcec782259e35e414,
+                    0x28d1890b30fbb30b,
+                    0xbd47cc949d754125,
+                    0x19781ccd798c9974,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xc1125ea81bbbef81,
+                    0xc87ae30d5174f8bb,
+                    0x1dfccb896ea88dbb,
+                    0xdb1527899df50764,
+                    0xb796dbfd8f5cbc19,
+                    0x001bbb9a0ba68825,
+                ])),
+                Felt::new(BigInteger384([
+                    0x796575ffbd247657,
+                    0x8514dd355a25561d,
+                    0x79754fa48cd95798,
+                    0x9b32327e4ffd6b18,
+                    0x39597c808b5c4170,
+                    0x18273aa3cc7a7797,
+                ])),
+                Felt::new(BigInteger384([
+                    0x46c283658f9753ad,
+                    0x7921a3c154ffb31e,
+                    0xe18a2ea5ca35ffe4,
+                    0xeee3dda1f66b0e8b,
+                    0x369dcb2fe6aec606,
+                    0x06d127e5d8ca7491,
+                ])),
+                Felt::new(BigInteger384([
+                    0x31f8b8a3be9ca2fc,
+                    0xc9d8e803698aa2c2,
+                    0xbd5280bdf74a4ca5,
+                    0x44be8c856d2dc406,
+                    0x281f37f282e1f650,
+                    0x13b89ca5ab95fc58,
+                ])),
+                Felt::new(BigInteger384([
+                    0x126303d3ee516088,
+                    0x869aec24be878fe5,
+                    0xf5227c273746daeb,
+                    0xf9335a9a9e143ffb,
+                    0x06a2cda51bbb4453,
+                    0x0042f56781b55153,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe99f79331a09962c,
+                    0x9843db9eb7feece3,
+                    0xd793cbf34c4364bd,
+                    0x3a8f0e229134b253,
+                    0x43b18a31594f5fcf,
+                    0x0f41ac46f5a9bab9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd39f8821f5ea2ccc,
+                    0xda01fd1c73f96a59,
+                    0x856b5e44d51708e7,
+                    0x846dde54df90e0c3,
+                    0xd11ccf3ee7f0e636,
+                    0x0545e1df25b5c57a,
+                ])),
+                Felt::new(BigInteger384([
+                    0x982a2350180b5eb1,
+                    0x32dd1e3550a28b67,
+                    0x7c39cf72792d356c,
+                    0x93f3de58e1fbf287,
+                    0x796fe54580a6b0db,
+                    0x04bc98cee0427f53,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x3a5536f643a8a604,
+                    0xfd9d3f3675733f57,
+                    0xa5f7a94c83fe92be,
+                    0x0950721c896b169b,
+                    0xe0786c362168b478,
+                    0x0ce419b451dcb352,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf4cfe83d71c5f714,
+                    0xef093cb5cfe8b389,
+                    0x3039853d94ddd6f2,
+                    0xdd5602b68f0a814f,
+                    0x632a72b69e6e5d42,
+                    0x107b8a77ed44506a,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf423583bc0afaea1,
+                    0xc857acd9b4bcd4cc,
+                    0x21587cc8e48c190a,
+                    0x4d03f2f698fc0108,
+                    0xc5afbdd363555f39,
+                    0x130b9ed28f0fe694,
+                ])),
+                Felt::new(BigInteger384([
+                    0x8cb5245dba93867e,
+                    0x1f39e5a3f15f4880,
+                    0xa48c346b214dafaa,
+                    0x0888c1f243c1c16b,
+                    0x00c8069c1ff3f77b,
+                    0x1615448317b68ea0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x33d268920fe0e8b0,
+                    0x0c60bf37a366120c,
+                    0x2eee4949cb1d184f,
+                    0x4726a4f3e9884d7f,
+                    0x6ac9daac206a9a57,
+                    0x0d9d7003bd7f5bd9,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a187911cbb5324d,
+                    0xd9f76ca981327d04,
+                    0xeebd13674cfae495,
+                    0xdbd78e2fdbbcae22,
+                    0xb9a7e1db3661f4a6,
+                    0x108fc812ea64a903,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4a06732249157ba2,
+                    0x356fc94c751f23c2,
+                    0xc40efa9930635168,
+                    0x83b786f046b33037,
+                    0x1d620f6f6eeb1655,
+                    0x18ef73212fd14f69,
+                ])),
+                Felt::new(BigInteger384([
+                    0x751aaf5c4bad68f4,
+                    0x50e4f83666112688,
+                    0x88b38444ef16b8cf,
+                    0x73947407d161de5f,
+                    0x6538c4bcab5f9aa0,
+                    0x08f3d187430c4c51,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xd6ac53e2058c37f5,
+                    0x80ec67fe8fbe7686,
+                    0x5e7262659c25c599,
+                    0x69aa835084c76f46,
+                    0xd536daa0b692837c,
+                    0x17775c92968e27a5,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6df0b749007ad0f4,
+                    0xbec747968cd28171,
+                    0x795e50142c4731bb,
+                    0x468728b6524a8410,
+                    0x8fe6afaa90aba25f,
+                    0x1223c0e8af61e0d7,
+                ])),
+                Felt::new(BigInteger384([
+                    0x78a15f1a02a9b788,
+                    0x6fc944c76a1ce7d7,
+                    0x78a13ba9b3c7c0c2,
+                    0x3c4f3d9b9f5c612b,
+                    0x8e0764309ce74f1f,
+                    0x026e04eaffb1bf74,
+                ])),
+                Felt::new(BigInteger384([
+                    0x413b5ae4747506bc,
+                    0xb6670f7d69ba997c,
+                    0x9089dd4bf4184381,
+                    0xcd1bff24c162ed5c,
+                    0x7752f4a416546e3f,
+                    0x15f883b1b21c6ee0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9e69a64791c148e3,
+                    0x757ca8a6fb79f767,
+                    0xb8012f4a02bc8180,
+                    0x5e8cdaca0c52034b,
+                    0x54cc814f2396a84b,
+                    0x00ad5fa6d972547b,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa5189f34dd8392b8,
+                    0x131737336370f2fd,
+                    0x154edb95220fa917,
+                    0x97fb6e30c593aeca,
+                    0x2edb46457f634753,
+                    0x0884dafeba4259c4,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40fd0c2dcf4ac70d,
+                    0x847a3fa7513d46e8,
+                    0x9a884318991150ea,
+                    0xaae3645281bfa1eb,
+                    0x970f8730340b9f2e,
+                    0x09a531165c9377c3,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5a9a7b01838fcb5f,
+                    0x36134fbc42934434,
+                    0x0343df5a8b09b892,
+                    0x930d1fe3abfb427b,
+                    0x51badf70e1763057,
+                    0x13690d5f44ebad78,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8ab37564bbbefc37,
+                    0x277d2bddd78d5c5f,
+                    0x5310ebf5863a272f,
+                    0x77c0cec655929092,
+                    0xbabbe027d33f8894,
+                    0x056f37fc42910e75,
+                ])),
+                Felt::new(BigInteger384([
+                    0xcc21027186ab8a05,
+                    0x70a08b568783b51e,
+                    0x334dd37bbedd2f20,
+                    0xaed8c241ffa37291,
+                    0x3c794e593c7ee415,
+                    0x140ba86dff74cdfd,
+                ])),
+                Felt::new(BigInteger384([
+                    0x6c5f6cb069874592,
+                    0x7e8d438ec591b88f,
+                    0x502ee98842e8dcf1,
+                    0x7f8a8fcc37e97058,
+                    0x890136c20d713724,
+                    0x01db41d7d2986bce,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5e24a3cb067dbfd5,
+                    0x63fd1f84da171940,
+                    0x908ef0f5d74d0d5a,
+                    0xe7328709ec0fce27,
+                    0x4b83d2fe31c6d226,
+                    0x142218e866b93d31,
+                ])),
+                Felt::new(BigInteger384([
+                    0x9fd852d2a487f0f2,
+                    0x3af2b6e19a4a7da8,
+                    0xed080b3fe024211d,
+                    0xd2ed44c1f0fc9a62,
+                    0x9a90928d39c5321a,
+                    0x0190f20dcc9f93b1,
+                ])),
+                Felt::new(BigInteger384([
+                    0x4ecd4ceed106093d,
+                    0x657d57b13f3e6056,
+                    0x398a20e49d208766,
+                    0xc8a4ab5e59bba7d4,
+                    0xb90efafc8dcb43e2,
+                    0x0248bff67453352e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x378a8527244b6de7,
+                    0x74aaa3c6fb71f42a,
+                    0x5bcf2b235d1e13c8,
+                    0x3a151199adcd2fea,
+                    0x187f48f865c987e3,
+                    0x0cf84175253b30bb,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1712ea1258f36ca3,
+                    0x80cc881c9b8cbd93,
+                    0x22bb7c127c4fbda2,
+                    0x5494b461f280ae02,
+                    0x722d54f94fdadfeb,
+                    0x19ec240f65d17806,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0x8bee42ec874e2a47,
+                    0x8f1cfbf58121d614,
+                    0x43c7cb385292cd02,
+                    0x79de766a8d1401c5,
+                    0xa8b4b47109791ef2,
+                    0x19890dbf8091ab7d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xe23a90672d97a006,
+                    0x7342c2c13c985d94,
+                    0x853f45fc5fcd78bd,
+                    0x868891a03e6eba0a,
+                    0x5eea394a65c7e2ba,
+                    0x17e8b96bd23c2bf0,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1a76e90756cd7428,
+                    0x78441b2d990cd222,
+                    0xe7b315d95b247c4a,
+                    0x4315f28ad449b90d,
+                    0x82c902b808fa051b,
+                    0x042971b94eabeca2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd3a6c65a86c0a973,
+                    0x9a34c0925b3e95ca,
+                    0xceaac4b4c3924f91,
+                    0x43c8c0c27d9ca84c,
+                    0xea00ad78f31f0d39,
+                    0x0cf634d7b5ba5dbd,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa0d33efd7f789933,
+                    0xdc3de71653295204,
+                    0xb8ad5e20bab365c9,
+                    0xdbf8838c0a64a484,
+                    0xbb8c9aee59cbf915,
+                    0x11e4bab623603fd2,
+                ])),
+                Felt::new(BigInteger384([
+                    0xa0a44a66570b429e,
+                    0xc5c25c1fd32232fa,
+                    0x65390d8bc6237558,
+                    0x022df33160f7868a,
+                    0x353251d774375f96,
+                    0x14f1ac0cb85e6b3d,
+                ])),
+                Felt::new(BigInteger384([
+                    0xaf46ebd419d507d8,
+                    0x40c91a503716480a,
+                    0x9257bce8048f5d89,
+                    0xa11fc46f49b08a4b,
+                    0x3a94d88217470d0e,
+                    0x118370dd35a9b102,
+                ])),
+                Felt::new(BigInteger384([
+                    0x5889493474750e08,
+                    0x7542ebcb91a927b2,
+                    0xa6eee4a908610ce9,
+                    0x4af2f30c84975cda,
+                    0x5a2fcf97eafc90b4,
+                    0x09c5936b43b7db11,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger384([
+                    0xe55eba698089900a,
+                    0x9754f2a8305c1c8b,
+                    0xfbfcde6d2aaeb8bd,
+                    0x2424d313cb53a799,
+                    0x7675a74f7aeac77a,
+                    0x04d30006e6dcd66f,
+                ])),
+                Felt::new(BigInteger384([
+                    0xf6f54895e89cd92b,
+                    0x14eca38db2f3461d,
+                    0xf33cbec36c97a279,
+                    0xa3556cd1efcd887e,
+                    0x357712a592257c47,
+                    0x09af87eabfca3eae,
+                ])),
+                Felt::new(BigInteger384([
+                    0x48ed9f37758d9d9a,
+                    0x25b9b2dbdca571eb,
+                    0x205226b235e232c7,
+                    0x94f717e5272a0aae,
+                    0xbed6c8fbe3c1031e,
+                    0x03272fb3245dd067,
+                ])),
+                Felt::new(BigInteger384([
+                    0xdd88762bd4b22310,
+                    0x2042aebf051ee900,
+                    0x14e36438932c496e,
+                    0x4ac21b9e0a4cc77f,
+                    0xfd92826bf1db4977,
+                    0x17730ecf4c1e7b06,
+                ])),
+                Felt::new(BigInteger384([
+                    0x40c816f5714f48a8,
+                    0x81d855a4ee5c4f3f,
+                    0x0f0fafc47db47079,
+                    0x3c2c01217639b58b,
+                    0xba3e42974a6420b8,
+                    0x152edcc25150be93,
+                ])),
+                Felt::new(BigInteger384([
+                    0x57314be0d14b05b9,
+                    0x3c056bddfb70af46,
+                    0xd9ef3a9ef19e27d9,
+                    0x34d74c07c6e4822e,
+                    0x75b4bb54361fd4fc,
+                    0x0fdbae480e5fea8e,
+                ])),
+                Felt::new(BigInteger384([
+                    0x1702ab45dcd29016,
+                    0xdd3d2f6eec8d1217,
+                    0x463e622386d30d5e,
+                    0x759be088550c471e,
+                    0xbed9f112edf63be5,
+                    0x19dcf76f958f96e9,
+                ])),
+                Felt::new(BigInteger384([
+                    0xd683dec599d903ae,
+                    0xe7a40b2d5437c2b1,
+                    0xd5780718f6d58153,
+                    0xf4fe0aa6d8ad15b0,
+                    0x74555001d64f6ed3,
+                    0x0d26ea3088ef6840,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
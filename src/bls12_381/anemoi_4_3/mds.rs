@@ -5,8 +5,8 @@ use super::Felt;
 use super::NUM_COLUMNS;
 
 /// Maximum Diffusion Layer matrix for Anemoi
-/// [1  2]
-/// [2  5]
+/// [1 2]
+/// [2 5]
 #[allow(unused)]
 pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
     Felt::new(BigInteger384([
@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 64,
+    expanded: "8dbe1c2d47a0d8a789e1c076de484794fbab5ce661f756fa3fc521e12c26807c",
+    element: "2480055673223010291973765137828026200421004627874896646310492192875822196375788584264694903857654679110068805895053",
+}
@@ -0,0 +1,580 @@
+//! Hashing to the G1 group of BLS12-381 with Anemoi, following RFC 9380.
+
+use super::{hash_to_field, BigInteger384, Felt};
+use crate::sswu::{self, SswuParameters};
+use ark_bls12_381::g1::Parameters;
+use ark_bls12_381::G1Affine;
+
+impl SswuParameters for Parameters {
+    /// ISO_COEFF_A = 12190336318893619529228877361869031420615612348429846051986726275283378313155663745811710833465465981901188123677
+    const ISO_COEFF_A: Felt = Felt::new(BigInteger384([
+        0x2f65aa0e9af5aa51,
+        0x86464c2d1e8416c3,
+        0xb85ce591b7bd31e2,
+        0x27e11c91b5f24e7c,
+        0x28376eda6bfc1835,
+        0x155455c3e5071d85,
+    ]));
+
+    /// ISO_COEFF_B = 2906670324641927570491258158026293881577086121416628140204402091718288198173574630967936031029026176254968826637280
+    const ISO_COEFF_B: Felt = Felt::new(BigInteger384([
+        0xfb996971fe22a1e0,
+        0x9aa93eb35b742d6f,
+        0x8c476013de99c5c4,
+        0x873e27c3a221e571,
+        0xca72b5e45a52d888,
+        0x06824061418a386b,
+    ]));
+
+    /// Z = 11
+    const Z: Felt = Felt::new(BigInteger384([
+        0x886c00000023ffdc,
+        0x0f70008d3090001d,
+        0x77672417ed5828c3,
+        0x9dac23e943dc1740,
+        0x50553f1b9c131521,
+        0x078c712fbe0ab6e8,
+    ]));
+
+    const X_MAP_NUMERATOR: &'static [Felt] = &[
+        Felt::new(BigInteger384([
+            0x4d18b6f3af00131c,
+            0x19fa219793fee28c,
+            0x3f2885f1467f19ae,
+            0x23dcea34f2ffb304,
+            0xd15b58d2ffc00054,
+            0x0913be200a20bef4,
+        ])),
+        Felt::new(BigInteger384([
+            0x898985385cdbbd8b,
+            0x3c79e43cc7d966aa,
+            0x1597e193f4cd233a,
+            0x8637ef1e4d6623ad,
+            0x11b22deed20d827b,
+            0x07097bc5998784ad,
+        ])),
+        Felt::new(BigInteger384([
+            0xa542583a480b664b,
+            0xfc7169c026e568c6,
+            0x5ba2ef314ed8b5a6,
+            0x5b5491c05102f0e7,
+            0xdf6e99707d2a0079,
+            0x0784151ed7605524,
+        ])),
+        Felt::new(BigInteger384([
+            0x494e212870f72741,
+            0xab9be52fbda43021,
+            0x26f5577994e34c3d,
+            0x049dfee82aefbd60,
+            0x65dadd7828505289,
+            0x0e93d431ea011aeb,
+        ])),
+        Felt::new(BigInteger384([
+            0x90ee774bd6a74d45,
+            0x7ada1c8a41bfb185,
+            0x0f1a8953b325f464,
+            0x104c24211be4805c,
+            0x169139d319ea7a8f,
+            0x09f20ead8e532bf6,
+        ])),
+        Felt::new(BigInteger384([
+            0x6ddd93e2f43626b7,
+            0xa5482c9aa1ccd7bd,
+            0x143245631883f4bd,
+            0x2e0a94ccf77ec0db,
+            0xb0282d480e56489f,
+            0x18f4bfcbb4368929,
+        ])),
+        Felt::new(BigInteger384([
+            0x23c5f0c953402dfd,
+            0x7a43ff6958ce4fe9,
+            0x2c390d3d2da5df63,
+            0xd0df5c98e1f9d70f,
+            0xffd89869a572b297,
+            0x1277ffc72f25e8fe,
+        ])),
+        Felt::new(BigInteger384([
+            0x79f4f0490f06a8a6,
+            0x85f894a88030fd81,
+            0x12da3054b18b6410,
+            0xe2a57f6505880d65,
+            0xbba074f260e400f1,
+            0x08b76279f621d028,
+        ])),
+        Felt::new(BigInteger384([
+            0xe67245ba78d5b00b,
+            0x8456ba9a1f186475,
+            0x7888bff6e6b33bb4,
+            0xe21585b9a30f86cb,
+            0x05a69cdcef55feee,
+            0x09e699dd9adfa5ac,
+        ])),
+        Felt::new(BigInteger384([
+            0x0de5c357bff57107,
+            0x0a0db4ae6b1a10b2,
+            0xe256bb67b3b3cd8d,
+            0x8ad456574e9db24f,
+            0x0443915f50fd4179,
+            0x098c4bf7de8b6375,
+        ])),
+        Felt::new(BigInteger384([
+            0xe6b0617e7dd929c7,
+            0xfe6e37d442537375,
+            0x1dafdeda137a489e,
+            0xe4efd1ad3f767ceb,
+            0x4a51d8667f0fe1cf,
+            0x054fdf4bbf1d821c,
+        ])),
+        Felt::new(BigInteger384([
+            0x72db2a50658d767b,
+            0x8abf91faa257b3d5,
+            0xe969d6833764ab47,
+            0x464170142a1009eb,
+            0xb14f01aadb30be2f,
+            0x18ae6a856f40715d,
+        ])),
+    ];
+
+    const X_MAP_DENOMINATOR: &'static [Felt] = &[
+        Felt::new(BigInteger384([
+            0xb962a077fdb0f945,
+            0xa6a9740fefda13a0,
+            0xc14d568c3ed6c544,
+            0xb43fc37b908b133e,
+            0x9c0b3ac929599016,
+            0x0165aa6c93ad115f,
+        ])),
+        Felt::new(BigInteger384([
+            0x23279a3ba506c1d9,
+            0x92cfca0a9465176a,
+            0x3b294ab13755f0ff,
+            0x116dda1c5070ae93,
+            0xed4530924cec2045,
+            0x083383d6ed81f1ce,
+        ])),
+        Felt::new(BigInteger384([
+            0x9885c2a6449fecfc,
+            0x4a2b54ccd37733f0,
+            0x17da9ffd8738c142,
+            0xa0fba72732b3fafd,
+            0xff364f36e54b6812,
+            0x0f29c13c660523e2,
+        ])),
+        Felt::new(BigInteger384([
+            0xe349cc118278f041,
+            0xd487228f2f3204fb,
+            0xc9d325849ade5150,
+            0x43a92bd69c15c2df,
+            0x1c2c7844bc417be4,
+            0x12025184f407440c,
+        ])),
+        Felt::new(BigInteger384([
+            0x587f65ae6acb057b,
+            0x1444ef325140201f,
+            0xfbf995e71270da49,
+            0xccda066072436a42,
+            0x7408904f0f186bb2,
+            0x13b93c63edf6c015,
+        ])),
+        Felt::new(BigInteger384([
+            0xfb918622cd141920,
+            0x4a4c64423ecaddb4,
+            0x0beb232927f7fb26,
+            0x30f94df6f83a3dc2,
+            0xaeedd424d780f388,
+            0x06cc402dd594bbeb,
+        ])),
+        Felt::new(BigInteger384([
+            0xd41f761151b23f8f,
+            0x32a92465435719b3,
+            0x64f436e888c62cb9,
+            0xdf70a9a1f757c6e4,
+            0x6933a38d5b594c81,
+            0x0c6f7f7237b46606,
+        ])),
+        Felt::new(BigInteger384([
+            0x693c08747876c8f7,
+            0x22c9850bf9cf80f0,
+            0x8e9071dab950c124,
+            0x89bc62d61c7baf23,
+            0xbc6be2d8dad57c23,
+            0x17916987aa14a122,
+        ])),
+        Felt::new(BigInteger384([
+            0x1be3ff439c1316fd,
+            0x9965243a7571dfa7,
+            0xc7f7f62962f5cd81,
+            0x32c6aa9af394361c,
+            0xbbc2ee18e1c227f4,
+            0x0c102cbac531bb34,
+        ])),
+        Felt::new(BigInteger384([
+            0x997614c97bacbf07,
+            0x61f86372b99192c0,
+            0x5b8c95fc14353fc3,
+            0xca2b066c2a87492f,
+            0x16178f5bbf698711,
+            0x12a6dcd7f0f4e0e8,
+        ])),
+        Felt::new(BigInteger384([
+            0x760900000002fffd,
+            0xebf4000bc40c0002,
+            0x5f48985753c758ba,
+            0x77ce585370525745,
+            0x5c071a97a256ec6d,
+            0x15f65ec3fa80e493,
+        ])),
+    ];
+
+    const Y_MAP_NUMERATOR: &'static [Felt] = &[
+        Felt::new(BigInteger384([
+            0x2b567ff3e2837267,
+            0x1d4d9e57b958a767,
+            0xce028fea04bd7373,
+            0xcc31a30a0b6cd3df,
+            0x7d7b18a682692693,
+            0x0d300744d42a0310,
+        ])),
+        Felt::new(BigInteger384([
+            0x99c2555fa542493f,
+            0xfe7f53cc4874f878,
+            0x5df0608b8f97608a,
+            0x14e03832052b49c8,
+            0x706326a6957dd5a4,
+            0x0a8dadd9c2414555,
+        ])),
+        Felt::new(BigInteger384([
+            0x13d942922a5cf63a,
+            0x357e33e36e261e7d,
+            0xcf05a27c8456088d,
+            0x0000bd1de7ba50f0,
+            0x83d0c7532f8c1fde,
+            0x13f70bf38bbf2905,
+        ])),
+        Felt::new(BigInteger384([
+            0x5c57fd95bfafbdbb,
+            0x28a359a65e541707,
+            0x3983ceb4f6360b6d,
+            0xafe19ff6f97e6d53,
+            0xb3468f4550192bf7,
+            0x0bb6cde49d8ba257,
+        ])),
+        Felt::new(BigInteger384([
+            0x590b62c7ff8a513f,
+            0x314b4ce372cacefd,
+            0x6bef32ce94b8a800,
+            0x6ddf84a095713d5f,
+            0x64eace4cb0982191,
+            0x0386213c651b888d,
+        ])),
+        Felt::new(BigInteger384([
+            0xa5310a31111bbcdd,
+            0xa14ac0f5da148982,
+            0xf9ad9cc95423d2e9,
+            0xaa6ec095283ee4a7,
+            0xcf5b1f022e1c9107,
+            0x01fddf5aed881793,
+        ])),
+        Felt::new(BigInteger384([
+            0x65a572b0d7a7d950,
+            0xe25c2d8183473a19,
+            0xc2fcebe7cb877dbd,
+            0x05b2d36c769a89b0,
+            0xba12961be86e9efb,
+            0x07eb1b29c1dfde1f,
+        ])),
+        Felt::new(BigInteger384([
+            0x93e09572f7c4cd24,
+            0x364e929076795091,
+            0x8569467e68af51b5,
+            0xa47da89439f5340f,
+            0xf4fa918082e44d64,
+            0x0ad52ba3e6695a79,
+        ])),
+        Felt::new(BigInteger384([
+            0x911429844e0d5f54,
+            0xd03f51a3516bb233,
+            0x3d587e5640536e66,
+            0xfa86d2a3a9a73482,
+            0xa90ed5adf1ed5537,
+            0x149c9c326a5e7393,
+        ])),
+        Felt::new(BigInteger384([
+            0x462bbeb03c12921a,
+            0xdc9af5fa0a274a17,
+            0x9a558ebde836ebed,
+            0x649ef8f11a4fae46,
+            0x8100e1652b3cdc62,
+            0x1862bd62c291dacb,
+        ])),
+        Felt::new(BigInteger384([
+            0x05c9b8ca89f12c26,
+            0x0194160fa9b9ac4f,
+            0x6a643d5a6879fa2c,
+            0x14665bdd8846e19d,
+            0xbb1d0d53af3ff6bf,
+            0x12c7e1c3b28962e5,
+        ])),
+        Felt::new(BigInteger384([
+            0xb55ebf900b8a3e17,
+            0xfedc77ec1a9201c4,
+            0x1f07db10ea1a4df4,
+            0x0dfbd15dc41a594d,
+            0x389547f2334a5391,
+            0x02419f98165871a4,
+        ])),
+        Felt::new(BigInteger384([
+            0xb416af000745fc20,
+            0x8e563e9d1ea6d0f5,
+            0x7c763e17763a0652,
+            0x01458ef0159ebbef,
+            0x8346fe421f96bb13,
+            0x0d2d7b829ce324d2,
+        ])),
+        Felt::new(BigInteger384([
+            0x93096bb538d64615,
+            0x6f2a2619951d823a,
+            0x8f66b3ea59514fa4,
+            0xf563e63704f7092f,
+            0x724b136c4cf2d9fa,
+            0x046959cfcfd0bf49,
+        ])),
+        Felt::new(BigInteger384([
+            0xea748d4b6e405346,
+            0x91e9079c2c02d58f,
+            0x41064965946d9b59,
+            0xa06731f1d2bbe1ee,
+            0x07f897e267a33f1b,
+            0x1017290919210e5f,
+        ])),
+        Felt::new(BigInteger384([
+            0x872aa6c17d985097,
+            0xeecc53161264562a,
+            0x07afe37afff55002,
+            0x54759078e5be6838,
+            0xc4b92d15db8acca8,
+            0x106d87d1b51d13b9,
+        ])),
+    ];
+
+    const Y_MAP_DENOMINATOR: &'static [Felt] = &[
+        Felt::new(BigInteger384([
+            0xeb6c359d47e52b1c,
+            0x18ef5f8a10634d60,
+            0xddfa71a0889d5b7e,
+            0x723e71dcc5fc1323,
+            0x52f45700b70d5c69,
+            0x0a8b981ee47691f1,
+        ])),
+        Felt::new(BigInteger384([
+            0x616a3c4f5535b9fb,
+            0x6f5f037395dbd911,
+            0xf25f4cc5e35c65da,
+            0x3e50dffea3c62658,
+            0x6a33dca523560776,
+            0x0fadeff77b6bfe3e,
+        ])),
+        Felt::new(BigInteger384([
+            0x2be9b66df470059c,
+            0x24a2c159a3d36742,
+            0x115dbe7ad10c2a37,
+            0xb6634a652ee5884d,
+            0x04fe8bb2b8d81af4,
+            0x01c2a7a256fe9c41,
+        ])),
+        Felt::new(BigInteger384([
+            0xf27bf8ef3b75a386,
+            0x898b367476c9073f,
+            0x24482e6b8c2f4e5f,
+            0xc8e0bbd6fe110806,
+            0x59b0c17f7631448a,
+            0x11037cd58b3dbfbd,
+        ])),
+        Felt::new(BigInteger384([
+            0x31c7912ea267eec6,
+            0x1dbf6f1c5fcdb700,
+            0xd30d4fe3ba86fdb1,
+            0x3cae528fbee9a2a4,
+            0xb1cce69b6aa9ad9a,
+            0x044393bb632d94fb,
+        ])),
+        Felt::new(BigInteger384([
+            0xc66ef6efeeb5c7e8,
+            0x9824c289dd72bb55,
+            0x71b1a4d2f119981d,
+            0x104fc1aafb0919cc,
+            0x0e49df01d942a628,
+            0x096c3a09773272d4,
+        ])),
+        Felt::new(BigInteger384([
+            0x9abc11eb5fadeff4,
+            0x32dca50a885728f0,
+            0xfb1fa3721569734c,
+            0xc4b76271ea6506b3,
+            0xd466a75599ce728e,
+            0x0c81d4645f4cb6ed,
+        ])),
+        Felt::new(BigInteger384([
+            0x4199f10e5b8be45b,
+            0xda64e495b1e87930,
+            0xcb353efe9b33e4ff,
+            0x9e9efb24aa6424c6,
+            0xf08d33680a237465,
+            0x0d3378023e4c7406,
+        ])),
+        Felt::new(BigInteger384([
+            0x7eb4ae92ec74d3a5,
+            0xc341b4aa9fac3497,
+            0x5be603899e907687,
+            0x03bfd9cca75cbdeb,
+            0x564c2935a96bfa93,
+            0x0ef3c33371e2fdb5,
+        ])),
+        Felt::new(BigInteger384([
+            0x7ee91fd449f6ac2e,
+            0xe5d5bd5cb9357a30,
+            0x773a8ca5196b1380,
+            0xd0fda172174ed023,
+            0x6cb95e0fa776aead,
+            0x0d22d5a40cec7cff,
+        ])),
+        Felt::new(BigInteger384([
+            0xf727e09285fd8519,
+            0xdc9d55a83017897b,
+            0x7549d8bd057894ae,
+            0x178419613d90d8f8,
+            0xfce95ebdeb5b490a,
+            0x0467ffaef23fc49e,
+        ])),
+        Felt::new(BigInteger384([
+            0xc1769e6a7c385f1b,
+            0x79bc930deac01c03,
+            0x5461c75a23ede3b5,
+            0x6e20829e5c230c45,
+            0x828e0f1e772a53cd,
+            0x116aefa749127bff,
+        ])),
+        Felt::new(BigInteger384([
+            0x101c10bf2744c10a,
+            0xbbf18d053a6a3154,
+            0xa0ecf39ef026f602,
+            0xfc009d4996dc5153,
+            0xb9000209d5bd08d3,
+            0x189e5fe4470cd73c,
+        ])),
+        Felt::new(BigInteger384([
+            0x7ebd546ca1575ed2,
+            0xe47d5a981d081b55,
+            0x57b2b625b6d4ca21,
+            0xb0a1ba04228520cc,
+            0x98738983c2107ff3,
+            0x13dddbc4799d81d6,
+        ])),
+        Felt::new(BigInteger384([
+            0x09319f2e39834935,
+            0x039e952cbdb05c21,
+            0x55ba77a9a2f76493,
+            0xfd04e3dfc6086467,
+            0xfb95832e7d78742e,
+            0x0ef9c24eccaf5e0e,
+        ])),
+        Felt::new(BigInteger384([
+            0x760900000002fffd,
+            0xebf4000bc40c0002,
+            0x5f48985753c758ba,
+            0x77ce585370525745,
+            0x5c071a97a256ec6d,
+            0x15f65ec3fa80e493,
+        ])),
+    ];
+
+    /// H_EFF = 1 - x = 0xd201000000010001, as in RFC 9380
+    const H_EFF: &'static [u64] = &[0xd201000000010001];
+}
+
+/// Hashes `msg` to a point of the G1 group of BLS12-381, under the domain separation tag `dst`.
+///
+/// This follows the `hash_to_curve` construction of RFC 9380, with the mapping and cofactor
+/// clearing of its `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite: two field elements are obtained
+/// with [`hash_to_field`], i.e. with the extendable output of the [`anemoi_4_3`](super::anemoi_4_3)
+/// instantiation, mapped with the simplified SWU map to an 11-isogenous curve followed by the
+/// isogeny, and added, before clearing the cofactor with the effective cofactor `1 - x`.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    sswu::hash_to_curve(&hash_to_field(msg, dst, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+    use core::str::FromStr;
+
+    const DST: &[u8] = b"ANEMOI-HASH-TO-G1-TEST";
+
+    fn point(x: &str, y: &str) -> G1Affine {
+        G1Affine::new(
+            Felt::from_str(x).unwrap(),
+            Felt::from_str(y).unwrap(),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        // Vectors of the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite of RFC 9380, Appendix J.9.1.
+        let vectors = [
+            ("1790030616568561980207134218344899338736900885118493183248255875682123737756800213955590674957414534085508415116879", "2715020688846377217335438104604904715810987801342572961948795328294467090945535855005782938414453837381763209848574", "2296064027963677462102732806416784924633946674250659271331705244237479823406569751054138764013769902705787390693815"),
+            ("247470258331762152370823329280394074170045058453263356372873997375166908584899100434893060702108665825589810322121", "3386118521359795452491592112474903013403563705976979413906657246155086738547458719845510093968336733362278563786652", "2084873627170452397466071646774067343162430120589753601649788355161907382097748603047706952090839756741323773801310"),
+            ("2088728490498894818688784437928579501848367107744050576780266498473771518428420173373487118890161663886009635645777", "2821082687643976727135766916971062443258871326645279750749587843733538375749732094652964213828655846393949715529088", "2236659376920053646316093788220212776293270005904218703562979195256708626056238772750953344654423383007688053099506"),
+            ("32138924938310862093169606408734331410171587925844216752733293543601988453843327878077294514665889481436558332217", "2750597138630512236035487877599745401777810867360019507011246275321400002888426276509939208262727285765214748773092", "4682543851655077553671986105462888902079061200209005646747249867045569650377881406222900116404493009231790370022"),
+            ("950597030816464821778971015673486129641410344078861474750527508537804549386058612983484048401731236595379325781716", "1310234334413593317487264068262875581538549818920455107001535749836023214862930695751111153139374341360268998651903", "1722852076857105662082042053242375388651973991909299590523535887571132809983119765637300964408506826251164830552206"),
+            ("1979385000937648348925653198641340374887185657649818450486460034420643425685140133042050299078521896600910613745210", "3311616314853778662737510896179563851269854451876665897619645808862293468684695794220925158015351701417892998090862", "3766703702372675620041179429307849718086910665790484278556322731239825005490182379500727494656175190871400799016607"),
+            ("156598384884054652954707150757138355079410210785113857376825014810441188545548595465313883035731540725116276838022", "1960900406862058500412857602917367324462862168370531753548447594707421479744654876776011197995713705303613054166800", "796026517351136405531520162316159775637212605390392276210869204954025082845902389058571934314416664833327392229477"),
+            ("1709027689043323463259398100486189187238532958310276339146988040422594808842792053521671901476006506290292962489454", "967523716990980374593313304911863073637147490481932861307341271286746892139528660513875818383069865848989972739449", "448250294062073198162023703643549476323405053402036714958949105934226798593801189109417098557293693419319880018868"),
+            ("1625704516324785166230868561544190006281306318060308039760768255839116494270087378351796462565313509233883467016390", "1996971461957439578623537515687747487245863046420668981084330901790074905661619886089326694286024766654032935736568", "2846839595784796036062288810492309337020466240068030035021962673103613107728799728335721768911299918160621520247475"),
+            ("897347619044039892426123073051050824113615337090860431730602102178645855045832565883684732229117125155988066429111", "1413547411776718946529389761582205614194561460212950211790836199210437830980262610082501088497897549157047997452586", "393458397480016172863200120609459550012818540430118884334678521929431020918265144138742970307592940315936822116031"),
+        ];
+
+        for (u, x, y) in vectors {
+            let mapped = sswu::map_to_curve::<Parameters>(Felt::from_str(u).unwrap());
+            assert!(mapped.is_on_curve());
+            assert_eq!(mapped, point(x, y));
+        }
+
+        // Full vectors of the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite, with the elements
+        // (u0, u1) output by its hash_to_field and the resulting point.
+        let vectors = [
+            ("1790030616568561980207134218344899338736900885118493183248255875682123737756800213955590674957414534085508415116879", "247470258331762152370823329280394074170045058453263356372873997375166908584899100434893060702108665825589810322121", "794311575721400831362957049303781044852006323422624111893352859557450008308620925451441746926395141598720928151969", "1343412193624222137939591894701031123123641958980729764240763391191550653712890272928110356903136085217047453540965"),
+            ("2088728490498894818688784437928579501848367107744050576780266498473771518428420173373487118890161663886009635645777", "32138924938310862093169606408734331410171587925844216752733293543601988453843327878077294514665889481436558332217", "513738460217615943921285247703448567647875874745567372796164155472383127756567780059136521508428662765965997467907", "1786897908129645780825838873875416513994655004408749907941296449131605892957529391590865627492442562626458913769565"),
+            ("950597030816464821778971015673486129641410344078861474750527508537804549386058612983484048401731236595379325781716", "1979385000937648348925653198641340374887185657649818450486460034420643425685140133042050299078521896600910613745210", "2751628761372137084683207295437105268166375184027748372156952770986741873369176463286511518644061904904607431667096", "563036982304416203921640398061260377444881693369806087719971277317609936727208012968659302318886963927918562170633"),
+            ("156598384884054652954707150757138355079410210785113857376825014810441188545548595465313883035731540725116276838022", "1709027689043323463259398100486189187238532958310276339146988040422594808842792053521671901476006506290292962489454", "3380432694887674439773082418192083720584748080704959172978586229921475315220434165460350679208315690319508336723080", "3698526739072864408749571082270628561764415577445404115596990919801523793138348254443092179877354467167123794222392"),
+            ("1625704516324785166230868561544190006281306318060308039760768255839116494270087378351796462565313509233883467016390", "897347619044039892426123073051050824113615337090860431730602102178645855045832565883684732229117125155988066429111", "1256967425542823069694513550918025689490036478501181600525944653952846100887848729514132077573887342346961531624702", "880372082403694543476959909256504267215588055450016885103797700856746532134585942561958795215862304181527267736264"),
+        ];
+
+        for (u0, u1, x, y) in vectors {
+            let u = [Felt::from_str(u0).unwrap(), Felt::from_str(u1).unwrap()];
+            assert_eq!(sswu::hash_to_curve::<Parameters>(&u), point(x, y));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_g1() {
+        // Self-generated with this implementation, as there are no reference vectors for
+        // hashing to curves with Anemoi.
+        let vectors = [
+            (&b""[..], "1485976443641042144101746639643459961256568589137229969810473524882831717731688337175246871605878457462727966935991", "1110231481293069725122030856339858281563530771360364239195368468498669983744592591073329598029299618417515287295914"),
+            (&b"abc"[..], "1719926347288009898535219085729874533053437769839369714536240053824972295455923834845014115854213603405402576106309", "1345987236784666673923472533101551130239059967429592091259921645593508403503492050256234635930171004238152017711761"),
+            (&[0u8; 200][..], "2727179651658266662204806564920824962324310734644706917429336066420101172826909004962495764766030335999077770517463", "1032717409362832270104802000045849446790570509609857171589758950369907403646173805970213840084102437174948209895136"),
+        ];
+
+        for (msg, x, y) in vectors {
+            let hashed = hash_to_g1(msg, DST);
+            assert!(hashed.is_on_curve());
+            assert!(hashed.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!hashed.is_zero());
+            assert_eq!(hashed, point(x, y));
+
+            assert_ne!(hashed, hash_to_g1(msg, b"ANEMOI-OTHER-DST"));
+        }
+    }
+}
@@ -4,7 +4,7 @@ use super::BigInteger256;
 use super::Felt;
 use super::NUM_COLUMNS;
 
-/// Maximum Diffusion Layer matrix for Anemoi.
+/// Maximum Diffusion Layer matrix for Anemoi
 /// [4 1 4]
 /// [1 1 3]
 /// [3 1 1]
@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 48,
+    expanded: "d5dd9068f852aad488063a594af55ce35aa3bcf5383d378ac1b76ccf04fc4a2e",
+    element: "17634329606135547523058025507001876187775330399530233713483944224258592075897",
+}
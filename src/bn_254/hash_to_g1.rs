@@ -0,0 +1,147 @@
+//! Hashing to the G1 group of BN-254 with Anemoi, following RFC 9380.
+
+use super::{hash_to_field, BigInteger256, Felt};
+use crate::svdw::{self, SvdwParameters};
+use ark_bn254::g1::Parameters;
+use ark_bn254::G1Affine;
+
+impl SvdwParameters for Parameters {
+    /// Z = 1
+    const Z: Felt = Felt::new(BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ]));
+
+    /// C1 = 4
+    const C1: Felt = Felt::new(BigInteger256([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x07c5909386eddc93,
+    ]));
+
+    /// C2 = 10944121435919637611123202872628637544348155578648911831344518947322613104291
+    const C2: Felt = Felt::new(BigInteger256([
+        0xb461a4448976f7d5,
+        0xc6843fb439555fa7,
+        0x28f0d12384840918,
+        0x112ceb58a394e07d,
+    ]));
+
+    /// C3 = 8815841940592487685674414971303048083897117035520822607866
+    const C3: Felt = Felt::new(BigInteger256([
+        0x7c8487078735ab72,
+        0x51da7e0048bfb8d4,
+        0x945cfd183cbd7bf4,
+        0x0b70b1ec48ae62c6,
+    ]));
+
+    /// C4 = 7296080957279758407415468581752425029565437052432607887563012631548408736189
+    const C4: Felt = Felt::new(BigInteger256([
+        0xa79a2bdca0800831,
+        0x19fd7617e49815a1,
+        0xbb8d0c885550c7b1,
+        0x05c4aeb6ec7e0f48,
+    ]));
+}
+
+/// Hashes `msg` to a point of the G1 group of BN-254, under the domain separation tag `dst`.
+///
+/// This follows the `hash_to_curve` construction of RFC 9380: two field elements are obtained
+/// with [`hash_to_field`], i.e. with the extendable output of the [`anemoi_4_3`](super::anemoi_4_3)
+/// instantiation, mapped with the Shallue-van de Woestijne map and added. As the curve has
+/// `A = 0` and no isogenous curve of small degree, the simplified SWU map does not apply, and
+/// its cofactor being 1, no clearing is needed.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    svdw::hash_to_curve(&hash_to_field(msg, dst, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+    use core::str::FromStr;
+
+    const DST: &[u8] = b"ANEMOI-HASH-TO-G1-TEST";
+
+    fn point(x: &str, y: &str) -> G1Affine {
+        G1Affine::new(
+            Felt::from_str(x).unwrap(),
+            Felt::from_str(y).unwrap(),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        // Generated with scripts/map_to_curve.py.
+        let vectors = [
+            (
+                "1",
+                "19699418584655347698258596782613050042691797047307431679640710698076539066151",
+                "10343751156573783632778856105235937123682780444565366714146603135794096447717",
+            ),
+            (
+                "2",
+                "17381839927637071501056362437774011121691119445066277389064485038161894060682",
+                "21789543218268983059319487233665025740281924852318056688907715121470174155670",
+            ),
+            (
+                "21888242871839275222246405745257275088696311157297823662689037894645226208582",
+                "2188824287183927523987808962644225046004514109990391983048327196568687142431",
+                "7399068843397001083097024496093135224919265266911805500347547396964789457160",
+            ),
+            (
+                "1512366075204170929049582354406559215",
+                "14647143831156729228548042340182482626118594370223208442770436010398187652984",
+                "18537455005299059553339330353916084546750349152205087456563980557617318563477",
+            ),
+            (
+                "7296080957279758407415468581752425029565437052432607887563012631548408736194",
+                "19362676386627051156106626172975476958691102568847371374556352667835202582700",
+                "16084463162385296208330923606228661502860323953174230193157690115092265968294",
+            ),
+        ];
+
+        for (u, x, y) in vectors {
+            let mapped = svdw::map_to_curve::<Parameters>(Felt::from_str(u).unwrap());
+            assert!(mapped.is_on_curve());
+            assert_eq!(mapped, point(x, y));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_g1() {
+        // Self-generated with this implementation, as there are no reference vectors for
+        // hashing to curves with Anemoi.
+        let vectors = [
+            (
+                &b""[..],
+                "8967414423624029554143065496856390516645235730621348670355649189353827241473",
+                "4253368140083373848026684729816477589116923235903398627941450602067117068173",
+            ),
+            (
+                &b"abc"[..],
+                "5988579555964135588993945781761302913177320873404499556169986483621207514900",
+                "19987910411412574042946330912382253243287339956380865446721051174947657833243",
+            ),
+            (
+                &[0u8; 200][..],
+                "11894989994460761038606366199295935245156509538122421752824124242007042827618",
+                "3904097093994371700950274787313657771371132719575545841713759422843971422",
+            ),
+        ];
+
+        for (msg, x, y) in vectors {
+            let hashed = hash_to_g1(msg, DST);
+            assert!(hashed.is_on_curve());
+            assert!(hashed.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!hashed.is_zero());
+            assert_eq!(hashed, point(x, y));
+
+            assert_ne!(hashed, hash_to_g1(msg, b"ANEMOI-OTHER-DST"));
+        }
+    }
+}
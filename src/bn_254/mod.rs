@@ -4,6 +4,12 @@ use ark_ff::Field;
 
 mod sbox;
 
+mod hash_to_field;
+pub use hash_to_field::{expand_message_anemoi, hash_to_field, HASH_TO_FIELD_BYTES};
+
+mod hash_to_g1;
+pub use hash_to_g1::hash_to_g1;
+
 /// An instantiation of Anemoi with state width 2 and
 /// rate 1 aimed at providing 128 bits security.
 pub mod anemoi_2_1;
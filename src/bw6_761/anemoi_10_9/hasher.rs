@@ -0,0 +1,4182 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U9>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger768([
+                0xee1287a78f4713ee,
+                0x15501b342304ef46,
+                0x12c5784f232d0f94,
+                0x5fe688b1a0168941,
+                0xd5c3acd3437dfabc,
+                0xde9c10afbe1183b3,
+                0x8b5d10f8d41bd288,
+                0xbfd1a7ebf50b6082,
+                0x1533b724868bd41a,
+                0xcb0b074a817c0e76,
+                0xf9bde411b6789504,
+                0x000b437a05175528,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x37dde71116c28b5e,
+                    0x4ea58011dcf9417e,
+                    0xb132126957bf8d2e,
+                    0x29f616f01d02fd11,
+                    0xacb7f9a77ab59bc4,
+                    0x7af2206476d3115a,
+                    0xa65d604b941a5c22,
+                    0x8dee82d478c85b6b,
+                    0x948b69f7c16ebb27,
+                    0x5502907eea84b48f,
+                    0xf44214f0879ed369,
+                    0x005e4c2a69d27843,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3aa85749355f9bf,
+                    0x71492107127ee4b0,
+                    0x3e6152c4e08e10c4,
+                    0x7af6725d84a32331,
+                    0xac2cd2ef223f59f3,
+                    0xcbc8dc4ef01d83be,
+                    0xc4c4811fcc327c00,
+                    0xa1c87f25571f0dbf,
+                    0xdbabeb4f49850707,
+                    0x6bb78530607b4912,
+                    0x7733d15f681eb497,
+                    0x00416305289b40bb,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xc06affe63ba4472c,
+                    0x19b43948b2a875a3,
+                    0xddfc17b99a173151,
+                    0x285ce22de554b1be,
+                    0x713fc99debdce886,
+                    0x06ba4378f3fd6248,
+                    0xb9ea84ccfd84b6cf,
+                    0x163e3375d8b0f04f,
+                    0x9ad39aa7ffad2457,
+                    0xc4dab061dce59a52,
+                    0x0a8f964018c181a4,
+                    0x00a000ec3887c429,
+                ])),
+                Felt::new(BigInteger768([
+                    0x982379ca48d31bc6,
+                    0xfc2ef54c57021b92,
+                    0x1d3a429fb4b638f1,
+                    0xa3fd1e3db5aec48a,
+                    0xe811d9a0dffddd26,
+                    0xcbde31409b74ed0f,
+                    0xfaf14672d1d96a0b,
+                    0x4d0f2fd44434c4de,
+                    0x6cf5f933467c6b99,
+                    0x43acbadfb9f5368c,
+                    0x2a35d78494e3e136,
+                    0x005f8d89a116738a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d70dd6febfbea5d,
+                    0x70cf1b9bfbac09e9,
+                    0x4faf3fe0cad34907,
+                    0x2e1326637a024afb,
+                    0xfba2b6f88cbf8daf,
+                    0x4c1867e7a66ed481,
+                    0x1e94703003d1d599,
+                    0xa23f522dee5b66a1,
+                    0x37574176078bd7c9,
+                    0xf6206fce21554e02,
+                    0x828018a3c39c7c72,
+                    0x00f5dfeb18eb4e1b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x34c60d7ea3efbb1c,
+                    0x7e8e187c1d9132c1,
+                    0x086e15f4cc917445,
+                    0x00b3398d23652538,
+                    0xb84e22c7bddcab01,
+                    0xe1f37e13f45ee341,
+                    0x888c7f8be8e801bc,
+                    0xaa5632e63e68302d,
+                    0x1ae00248aeecfe03,
+                    0xdc842b01afd94f6d,
+                    0x25d4da2d09ecbac9,
+                    0x005968062f535f0e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f77cc069ba260ff,
+                    0xe10399bc7ce98cde,
+                    0xdb23feef73f3861f,
+                    0x128819ea249a039d,
+                    0x46eb93f8a82d5cbe,
+                    0x4f97b5f4f6d25b8b,
+                    0x90eeca40f45b97b5,
+                    0x9b1bb09969195634,
+                    0xe2fa45e030f8564c,
+                    0xd917cf1be30db370,
+                    0xe9d41045de62d122,
+                    0x003651112b2f0b00,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12e94b0b70df2c0f,
+                    0x59ef37be49f8b3a0,
+                    0x1ff0673408aa9921,
+                    0xe9b8ebc8b8074d53,
+                    0x34bd9cd1f0572fef,
+                    0x22d86161ae67830f,
+                    0xbd9c0991ac36d862,
+                    0xdd27c3f670077d37,
+                    0xe52b39c7391f4677,
+                    0xae68972337db4456,
+                    0xbf044e064bb60b93,
+                    0x00006481f858114a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4b81c896bf878c8,
+                    0x17fef684d8e8914a,
+                    0x56b9acd8bbbf25ba,
+                    0x96dbfe43e9901902,
+                    0x56437ee3b691f5a3,
+                    0x8da3b9447c648f5b,
+                    0x4e74c06144c08f5e,
+                    0x2668fe4ea86cea24,
+                    0xe4da992b311b6aa7,
+                    0x063126771b2771a3,
+                    0xde9f84cbf04503b7,
+                    0x01180ee7140add9d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x09d921d1f0c3bfee,
+                    0xdb9f8cb532505dbb,
+                    0xf29b62476ae6b153,
+                    0x0fd5aca70f4e0137,
+                    0x2b42a50813bfd775,
+                    0x87f217f2261b9160,
+                    0x017c23ad6dda80e7,
+                    0x7938b87e89c1244e,
+                    0xa2a730e2001afde3,
+                    0xb8e18bf8d47cf289,
+                    0xfc3d54cbfc6dc325,
+                    0x00986ceed4268ca4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x714204e4479eef41,
+                    0xf7945a20bbc4f2cc,
+                    0xb855bc4de1227645,
+                    0x51f9bdee36fefc6a,
+                    0xed6949bf385bad65,
+                    0x9dda8d6cb5dd199d,
+                    0xe328edaecf1f74f2,
+                    0x4d6829e1a20d2371,
+                    0x0283efbaa77d983c,
+                    0x2f9a4d4807a8e831,
+                    0x49c813019cd04a6e,
+                    0x009c5efb24559721,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb0e3a19543d3c01c,
+                    0x4e67bbb873b3f02a,
+                    0x4a683f11c6ddea08,
+                    0x53e20baa5b11784a,
+                    0x278413bd9d793550,
+                    0xf74b52103f489e26,
+                    0x7cb3629b92465026,
+                    0xae17942840b11efa,
+                    0xd2623ffa1bb3caaa,
+                    0x863f5e846b3f91ee,
+                    0x31e35b01b0d2a467,
+                    0x003fedce341ed92f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x14b447144643c32e,
+                    0x81497212cbb2a33d,
+                    0xcb9750b04d22d2b0,
+                    0xceb7f6f242d72493,
+                    0x438d320d6e40261b,
+                    0x80590d5dc3e368e7,
+                    0x67043c80ee6316d6,
+                    0x52691ddd4c088140,
+                    0xc0d5b77c6dbf55fa,
+                    0x15347fcdd8751755,
+                    0x90c003b1de223b00,
+                    0x00b735e285512741,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1759209a41d149de,
+                    0x519a0172d88bb8cf,
+                    0x98942faa82c7da09,
+                    0xedb76be5265f0f3b,
+                    0xce46fbc7822cf47c,
+                    0xffddc070bf22c95e,
+                    0xb997141eb79a965d,
+                    0x1df0ef0f775260b2,
+                    0xce9e7116f3463feb,
+                    0x43f9e803151efdd6,
+                    0xa28b2b8311709e6f,
+                    0x010c25a5d165a76f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x6a04f787099e8015,
+                    0x19c5059280383015,
+                    0x7907b27e5d3a677e,
+                    0xf5007d77541a2f76,
+                    0x1446f5e551b14c9f,
+                    0xe59a0332e5677ef8,
+                    0x89bc64cb3689d9ea,
+                    0x30441439373bbc5b,
+                    0x4ed04f353a649da2,
+                    0x0ee180927625910b,
+                    0x4f90861ff33119b1,
+                    0x002954aa08b43a38,
+                ])),
+                Felt::new(BigInteger768([
+                    0x416fc5349722e062,
+                    0xa4ec3ea25932552e,
+                    0xf05e3e0909dbd0ae,
+                    0x5a35ec2fba81ed67,
+                    0x9f9f9ba129293178,
+                    0xcd4817fc42acafdf,
+                    0xe4f5000395c8642a,
+                    0xb176718f0bd0ec5e,
+                    0x3b5ff342ed581461,
+                    0xdfecf5516e8abd40,
+                    0xab1236ff7698dfd4,
+                    0x002ae08f8f357d77,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6bb50dd5b47bee0c,
+                    0x4ac57b1786c28a4d,
+                    0x31e0cfba358972bf,
+                    0xb4fc163ca84424e8,
+                    0x03b9882cbebe0ad8,
+                    0x9503fcde159f77fc,
+                    0x04fa73dcefd27ad4,
+                    0x90c3cc47a72fa0a0,
+                    0x6798ed2efb99aae9,
+                    0xf3609e93e0b8219b,
+                    0x92cc32f224f48afe,
+                    0x0081d82768333814,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6b56cff4413f89f8,
+                    0xdeb9ff37161bad4c,
+                    0xc858ecdbef475ba7,
+                    0xa0556c4873bbb862,
+                    0x58d4d1b1cc81203c,
+                    0x5f2b6ccd0eca15b7,
+                    0xff4a1e40585f3b3d,
+                    0x8aafbf75598bc810,
+                    0x84e56ab6f5a46fe4,
+                    0x6432c726bd999a0a,
+                    0x8268927ff6cf4477,
+                    0x0055ae640e40e860,
+                ])),
+                Felt::new(BigInteger768([
+                    0x440c313beb38234d,
+                    0x3d83ddcad29a82f3,
+                    0xa63022b5428f1bad,
+                    0x7c4b67e6eb4e1d7a,
+                    0xec5cb6327f34f9b5,
+                    0x63c603c94a1c0599,
+                    0x7af6fd9264bfca33,
+                    0xc82b68ccb9756596,
+                    0x4f29bd8cbb3cffe2,
+                    0xf5e4f97f015a0ff1,
+                    0x6919d7170ea10227,
+                    0x005750a6e290b7fd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a8b815c5ec49ed3,
+                    0xfced45e5c3905377,
+                    0x84ecd0e46184109b,
+                    0xd8de95266268ab0f,
+                    0x3015477bc19fb764,
+                    0x6ab55c5c86a9abca,
+                    0xddfc24cdf79c2b9b,
+                    0xec367f93133c0dbf,
+                    0x9b2bc1c4d66712ed,
+                    0xcc58de7df983be72,
+                    0x3b235abf840c5f76,
+                    0x00abbac67be2cf22,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x975b81b0b9916cd2,
+                0xa81048859341cd86,
+                0x568cbf36c2040c22,
+                0xa98cd14390f45e41,
+                0x073141902c89e1c5,
+                0x3d35e208ca3de2ba,
+                0x686a03f338133299,
+                0xecd770c217386436,
+                0xdca9d66d2c10f156,
+                0xfec63a33ad62c042,
+                0xc7631b53588a9097,
+                0x00a1fcaaead2f0c4,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xa4a8edce2296284c,
+                0x48f5cc51e61e8349,
+                0xd8c3105c953c09ae,
+                0xaae391416ec48834,
+                0xe8f365ef3911df14,
+                0x1d1bf3c3acf3374e,
+                0xd1a592fd9f5fe088,
+                0x810317ea8ec5af9f,
+                0x02c8f853e9cd5a6e,
+                0x19e9b40ac36a9886,
+                0xf500d65ec3f97ea8,
+                0x0066763f0d4afef9,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x4e91f317404d2127,
+                0xddbed088b9471b31,
+                0x41405f98b34b5a6a,
+                0x28ca3e61003b4d8e,
+                0xfac686f8e9919546,
+                0x7071035625823f54,
+                0x32f509780e2b4067,
+                0xc5a6f5d776bc1d89,
+                0x105fab2f95746800,
+                0x5259cc4126b6e5db,
+                0xd022343ba8da879d,
+                0x002c718fcfccef00,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xbfa0f1c499813d04,
+                0xfeb5993f384af712,
+                0x7fe40f53695501e7,
+                0x49c57d8c05ae1b7e,
+                0xa0861ab93fac4b47,
+                0x53012d69e819e267,
+                0x778b9703cb27b46e,
+                0xe3a56faae6abdff0,
+                0xcce59075e538133d,
+                0x7107acecdb4c85c7,
+                0x2494621589648c81,
+                0x006b4eb1eb752ef2,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb40a39ab76708ac8,
+                0x15fffd8799852855,
+                0xcf58863b9a1784ec,
+                0xbb77c95c0f1b9eed,
+                0x087909e0c4de30f6,
+                0xc03e23664220981b,
+                0x26ea1665d8c6fdd5,
+                0xeb75d785f1213938,
+                0xb1b3aaf4c8a0d0f1,
+                0xb16817ed564e260f,
+                0x8dd950a09c8b5ef8,
+                0x004a26a0f1f2cefe,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xd8775e2f0985ac40,
+                0xe73e2e7b4a534d0b,
+                0xfb0c0852c7935e8f,
+                0x9af406689435d32c,
+                0x76492c30597faafc,
+                0xa18b1d0ab728d317,
+                0xceee07bbf780581a,
+                0xc7bd273efcb2fc1a,
+                0x1996463ddda60495,
+                0x3910048ef2686f43,
+                0xe34065027cce7f2f,
+                0x001fbb3e86964dd6,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe07797b5faf57d12,
+                0xc0c830f977a047ac,
+                0x9917ad08bbe9e632,
+                0x59ba2893350cc743,
+                0xeec30ce7beea453b,
+                0xec715ef5845eea2a,
+                0x33fb3df05a31600b,
+                0x0bf0d4956562275f,
+                0x854f5ada1d5e53ac,
+                0x21cf55d42c41c7ce,
+                0xde6a57073d1d954b,
+                0x006e8bc7d8ceb9c0,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x6a1c3e9af7c01a06,
+                0xfb869d03285fe504,
+                0x30496361118ef9b0,
+                0x335144014d0ea776,
+                0x3c601d53df1bb4e9,
+                0xeb5c989a17cff8c4,
+                0x6e67ca75c0beb031,
+                0x679637586ebcd844,
+                0x1af71e4d7fb999d8,
+                0x38bb070d520e5e4e,
+                0x7337c90f09df62ac,
+                0x007b8044b74fd233,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf00aefbbcf6a99b9,
+                0x23852dc3b4644dbd,
+                0x16e12ac09ca3b09c,
+                0x79d57deed42abc4e,
+                0x258e78e4d77bd4a2,
+                0xf32f10da0bf42cba,
+                0x17796def316d806f,
+                0x2662124db1537793,
+                0x1ca3be9ab28de5db,
+                0x0953fc1e14d46c2e,
+                0x30c36244e1a7d50a,
+                0x0086938326449ed4,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xadb036ac60bd591e,
+                0x06f2306a94419a07,
+                0x0434caa7c5aee340,
+                0x4dcf1a166be13394,
+                0x453e3c14da525c1d,
+                0x554a85ee42e4ceea,
+                0xc955d3f78a717480,
+                0x346541ec691b0ff1,
+                0x3cacde0461a964d2,
+                0xde5868e42c9f0e43,
+                0x6d5673d721a74d1f,
+                0x008002c22cb660a1,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x975b81b0b9916cd2,
+                0xa81048859341cd86,
+                0x568cbf36c2040c22,
+                0xa98cd14390f45e41,
+                0x073141902c89e1c5,
+                0x3d35e208ca3de2ba,
+                0x686a03f338133299,
+                0xecd770c217386436,
+                0xdca9d66d2c10f156,
+                0xfec63a33ad62c042,
+                0xc7631b53588a9097,
+                0x00a1fcaaead2f0c4,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xa4a8edce2296284c,
+                0x48f5cc51e61e8349,
+                0xd8c3105c953c09ae,
+                0xaae391416ec48834,
+                0xe8f365ef3911df14,
+                0x1d1bf3c3acf3374e,
+                0xd1a592fd9f5fe088,
+                0x810317ea8ec5af9f,
+                0x02c8f853e9cd5a6e,
+                0x19e9b40ac36a9886,
+                0xf500d65ec3f97ea8,
+                0x0066763f0d4afef9,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x4e91f317404d2127,
+                0xddbed088b9471b31,
+                0x41405f98b34b5a6a,
+                0x28ca3e61003b4d8e,
+                0xfac686f8e9919546,
+                0x7071035625823f54,
+                0x32f509780e2b4067,
+                0xc5a6f5d776bc1d89,
+                0x105fab2f95746800,
+                0x5259cc4126b6e5db,
+                0xd022343ba8da879d,
+                0x002c718fcfccef00,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xbfa0f1c499813d04,
+                0xfeb5993f384af712,
+                0x7fe40f53695501e7,
+                0x49c57d8c05ae1b7e,
+                0xa0861ab93fac4b47,
+                0x53012d69e819e267,
+                0x778b9703cb27b46e,
+                0xe3a56faae6abdff0,
+                0xcce59075e538133d,
+                0x7107acecdb4c85c7,
+                0x2494621589648c81,
+                0x006b4eb1eb752ef2,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 950];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+            bytes[380..475].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..95]);
+            bytes[475..570].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..95]);
+            bytes[570..665].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..95]);
+            bytes[665..760].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..95]);
+            bytes[760..855].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..95]);
+            bytes[855..950].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..95]);
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xd328a4a5cb34faea,
+                    0x1cecdc4852e3fcd8,
+                    0xd545b675fbc45812,
+                    0x2944b07409721e39,
+                    0x7059ebba95c926aa,
+                    0x91f5b8cc53291867,
+                    0x503ffb980216bcbf,
+                    0x42dcf808c24ee128,
+                    0x78d6c9e3e7ba1f16,
+                    0xa8e3b2b3eea2bbd9,
+                    0xd2810aea86999d4a,
+                    0x00bbfdef2caa5980,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e72df30030856ba,
+                    0xfec84c8c559279ac,
+                    0x897aec5cf76ac3c9,
+                    0x69052c9081c2ccde,
+                    0x844e9870cd187174,
+                    0xdef6287fd113da2d,
+                    0x8dcc6d610c7c4583,
+                    0x10aaab8ddf41a0b9,
+                    0x63e2d62d41c5c8f5,
+                    0xf34e4c8730c81bcc,
+                    0x99cb1eb1a4eae50d,
+                    0x00f49e99c8b50d28,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e4fc66ec0c80307,
+                    0xcc80fb12d60556f6,
+                    0xf439debe6e89a8d3,
+                    0x62ee75f3eee45451,
+                    0xba4f11ea958e8762,
+                    0x404df9a9d6135705,
+                    0xaf8caf7db3e1628a,
+                    0x5c64d1f20af6db1e,
+                    0x4439ce64bb41ed52,
+                    0x7adc8255522b613c,
+                    0xf13665c77250572b,
+                    0x003c08a752097394,
+                ])),
+                Felt::new(BigInteger768([
+                    0x543ae9441c3146a1,
+                    0x92cc04b329290f3d,
+                    0xf133880324e59b8f,
+                    0xf1ebc2b4c0aac1a9,
+                    0xa62bfe80f92cbf4e,
+                    0x795496eba3899d55,
+                    0xcad7c59ab9bf8504,
+                    0xcce3ca79bb9d9970,
+                    0x7141b9fb824681f6,
+                    0x6bce800bca42e368,
+                    0xda7bd63701e54374,
+                    0x00c03491df57164d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b17891fc31ca36d,
+                    0xa67d33e2f2d33b1f,
+                    0xf1c2f628361432db,
+                    0xc30f6b29523c23eb,
+                    0xd94154faef0b9bd1,
+                    0xf832630b6eae010d,
+                    0x6b69f280f6d8ba07,
+                    0x33cc087bd0398ecd,
+                    0x21cb9babcc7400f8,
+                    0x98a6d7634247bce0,
+                    0xdc1ee711da4e52e9,
+                    0x0021291ad3967c28,
+                ])),
+                Felt::new(BigInteger768([
+                    0x23413ffb685cb419,
+                    0xeb50fcf63866d053,
+                    0xae5a29d38a0945b1,
+                    0xd2508546aa19a6d7,
+                    0x544563c795b5ec33,
+                    0x4c91777e7d5e40b4,
+                    0x678b85f52bf4c64f,
+                    0x049d4917276d91a9,
+                    0x8191cd5ac53a7898,
+                    0xc8129c7f634be830,
+                    0x494606f4148c04e7,
+                    0x0091f17747d0b553,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2eeb5e6f9ce02b96,
+                    0x096de335e4ddfd68,
+                    0x5065ac983acf1203,
+                    0xd2a07254aa793795,
+                    0x9b724167e0bcdef7,
+                    0x1300a5c3c73ce795,
+                    0xc9a389a68493e354,
+                    0x4d90a1063f58b1bc,
+                    0x741a7204f66dca0d,
+                    0x15b61290bbb1755d,
+                    0x3acd406cbcceb9ad,
+                    0x00ebe20d5f1ec6c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x98d365bc4faaab7b,
+                    0xb907641f95b7af0c,
+                    0x6796cf87b26c9aba,
+                    0x666c461f75c1fb35,
+                    0x07a6610b356015cc,
+                    0xb1e0f44a0f8213a7,
+                    0xe3fde9650ed3f38f,
+                    0x47074b78e54fe67e,
+                    0x314c86c4839833f4,
+                    0xef1d0a5527dd9097,
+                    0xc37c5473b739ac0f,
+                    0x00ef71521b284a2c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x92a20abb63759e2d,
+                    0x57deb2ffd56ae56d,
+                    0x8eb97cbb0b89487f,
+                    0xbac385d7d5eb9cf3,
+                    0x69719c906a445455,
+                    0xe8186e607ed85685,
+                    0x7154da395fa2cfa5,
+                    0x387d6ea54f5d085c,
+                    0x39b0723dad16cdf4,
+                    0x9273c57ebb19a78d,
+                    0x2bf72bd617171b4e,
+                    0x002a221081b236ba,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb5683bc9e79a13f5,
+                    0x4808d103c6c95c53,
+                    0x9a1ad0913da21a2a,
+                    0x74cef71b8ecf0712,
+                    0xc863f9fa39e84050,
+                    0xcd6955b80a57062f,
+                    0xa2b513770717b65b,
+                    0x5a97d5b4fb7b487d,
+                    0xe37bbddcedfdc324,
+                    0x33cb93be851f8f18,
+                    0x395b6391493cdf37,
+                    0x006168bc7cd058e4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x0445e8677fbabc4b,
+                    0xf581f79147bcde6f,
+                    0x0b65e1bb1f1e5777,
+                    0x6219f86c931c8a49,
+                    0xbab815ab3577f9b1,
+                    0xfd44545bd2136ff3,
+                    0x91b91973d9d82d72,
+                    0x47804f9071ec52f4,
+                    0xb59e57d87ae34e93,
+                    0x56b3b770f4de117f,
+                    0x8f5b8e7915fc973c,
+                    0x011f49a8c17b68f3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a4771e4d47e560e,
+                    0xebe02cc4d6c37743,
+                    0xe2e642c3fc02974e,
+                    0x08af01a7cf92bca1,
+                    0x95b9b170ca9cc0f7,
+                    0x6c99ab7b4270fa38,
+                    0x09d19509889bac41,
+                    0x912fb3a1d81ef2b8,
+                    0x2cf921ef35064772,
+                    0x5f5f50b30708c668,
+                    0x6478f82c76bf8b04,
+                    0x00de3d0cffa6ca73,
+                ])),
+                Felt::new(BigInteger768([
+                    0x478e2a8002689cdd,
+                    0xecb1fa3692da9e11,
+                    0x71fa68657bd9b3bd,
+                    0x76da830d9b8c6b07,
+                    0x1c191db0a897d06f,
+                    0x2c2f28a9532dda13,
+                    0x01d41d48386c4305,
+                    0xe1056f08c5e653bd,
+                    0xe3dd0e4606df1089,
+                    0x26c69de41dd2cf5c,
+                    0x9106fec9622e04ab,
+                    0x001ae524e4e39c50,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2adb1d07f76d4863,
+                    0x503be6e4f1c0ec03,
+                    0x7d7f14ea379563dc,
+                    0x09fbdf0092c90099,
+                    0xbf682b457e74e1e8,
+                    0x6c128d3e19ad73d7,
+                    0x1bce57f0238822f1,
+                    0x5fa6832fa8d51670,
+                    0x1b0b535fed0dc63a,
+                    0x59cf04aab2ac06f5,
+                    0x5bb4385e92392dda,
+                    0x00318de98c767f29,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe55eee53d9dd1880,
+                    0x326585cfe6db7259,
+                    0x0cf72f2528757ea6,
+                    0x50ab3b003c50deea,
+                    0x6389fe15bd7b29af,
+                    0x887c5f8d5b63dfa3,
+                    0x822bef30177ca3fa,
+                    0xa41cfa2f3e0cd960,
+                    0x9d1b68c635d64858,
+                    0x4b8f99bebfd07894,
+                    0xb4fa66741808a3b4,
+                    0x006f8f7910fd5db7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc93d476d6c16bfe8,
+                    0xd66a9945b16d77fb,
+                    0xdd82daeadc851fd7,
+                    0x921d89ac11929ce5,
+                    0x968cd18c3c4e248a,
+                    0x4a2795672f08dadc,
+                    0x5c084555c628cd5a,
+                    0x7c4fd2479cbdb58e,
+                    0x71602a7e872c6039,
+                    0x604cf08b64d4f6d7,
+                    0xfb45693e1dca818e,
+                    0x00499395af34724c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0204281de53131d6,
+                    0x2623e4f6ccd344a0,
+                    0xb728962aab4a0ff3,
+                    0x79cf0104438c2c7e,
+                    0x0b961eeb047fd2f0,
+                    0xa1156abddc058dac,
+                    0x8a7efa57fddf423f,
+                    0xec295e6ae5b42ae2,
+                    0x7f925624816d2b93,
+                    0xac434060bfb54876,
+                    0x2405b288434a369a,
+                    0x0057a248b2398b09,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfab0f0faa3360f5e,
+                    0x51fc4b392a099b19,
+                    0xa78acf37f9b584ca,
+                    0x59d73f53cf4e3e1f,
+                    0x03ca832bf451e451,
+                    0x16d4ddfd5e7b1282,
+                    0xfe5df86dbbbd742b,
+                    0x1174be2a7e995604,
+                    0x8a62a550e67fa43f,
+                    0x7f7e4cce4bc9a190,
+                    0x051f858a1383bc6a,
+                    0x0017ae8e0c88b899,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb5f4e1ad9d0de171,
+                    0x8d038ba324b2baf8,
+                    0x1cc5ee9a41e8fb4a,
+                    0x49c3591962438e9e,
+                    0x46359dcadc8c6ef1,
+                    0x0751c8c5566013c5,
+                    0xee530182f042746a,
+                    0x9e6a9d6f877f3cfd,
+                    0x368ca199e9046f6a,
+                    0xd17576da96429966,
+                    0xc5a484abf99a90b9,
+                    0x0041e06fac52a7fa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe6413be0af9788a9,
+                    0xec5cfdfbfb2f3561,
+                    0x57dcbf9c51397e33,
+                    0x02661903ad6c0734,
+                    0xd0b2778ebdca669f,
+                    0xa0e2c7eec2aff17b,
+                    0x7a7eb39990719900,
+                    0xfd29bb071b447edb,
+                    0x9af028ccacdf34f8,
+                    0x8cdd481a317ca4ee,
+                    0x5f9ff366dc129820,
+                    0x00f39dc7200ea02e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xbd03ecf397bd00f5,
+                    0x1ea776a6741dbe1a,
+                    0x7efddb40938611ad,
+                    0xe6511c6acb228da3,
+                    0x4a807489589ae521,
+                    0x891cc9c8a639ce97,
+                    0x73063e47e73d9a21,
+                    0x9640ff9f7b357b5b,
+                    0x65c9a445c3732312,
+                    0xc0a06977f4cc9635,
+                    0xeed0bd48c3df085f,
+                    0x00e55a0d9463272c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5575dbf10d28a618,
+                    0x780c2c50a15232e0,
+                    0xd95e2d4a9b27ec9a,
+                    0x5aa0cff9d8773221,
+                    0xd0de8a099f546e75,
+                    0x6162a006166ca868,
+                    0x710d2a9203889663,
+                    0xfbdc03900dc1a23d,
+                    0x86770054d77769c0,
+                    0xe35a6a20894226d9,
+                    0x41ad84f79d495498,
+                    0x00803a6f44351e54,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a4d9af3d87d2b91,
+                    0x6990e589d4775de3,
+                    0x8bc984169dcc8157,
+                    0xbc1c6fb7e8b70cfe,
+                    0xf653e6b90fe7a456,
+                    0x2bee1bb2d8fd0c13,
+                    0x74fc244747461bb0,
+                    0x5404c5a5a69d7489,
+                    0x99ee33baf58bdf80,
+                    0x1584f5c218a017e9,
+                    0xfcb5de9cc5a1dff5,
+                    0x001612eb7fb59d1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd20f7d4a406289cf,
+                    0xb9aa6f57f6422c60,
+                    0xb588ce16ffde642a,
+                    0x459ea8916bdfdf88,
+                    0x3f47912db4ef349a,
+                    0x303c863ddaea94ac,
+                    0xaf47701778a108cb,
+                    0xb89d72251e28484b,
+                    0xcaf10dfbb32a7145,
+                    0x998f53314bbbfdbe,
+                    0x6a3370acff9b576b,
+                    0x00aed08e0fb8b3d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4609910907a56703,
+                    0x292aaf20132c6dfa,
+                    0x08f1505cf1f75fd6,
+                    0x802ef8bd84157279,
+                    0xdd1646d83b3bc5a6,
+                    0x3295c5cb0aaa238f,
+                    0x81a59a4d99babaf3,
+                    0x7e9bc6c4d90c8f69,
+                    0x4e730ac6cfdbf127,
+                    0xab25d1811e9b5734,
+                    0x6f61ee7b98b52241,
+                    0x010066b89a5d40da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4569ebcad891a88d,
+                    0x7dfbe92f2734d860,
+                    0x82dc6c8bb49e1d68,
+                    0x111009f79f43ce11,
+                    0x2a979b71da4e7c00,
+                    0x8b4420fce4188fa1,
+                    0x79d93b218fbbfeba,
+                    0x6be3ab231bf5d768,
+                    0x1b5b5e75ef7079db,
+                    0x238a4d71ec26ab16,
+                    0x9a623747844126ea,
+                    0x011d9e4bbd336857,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe8824632b5f9f133,
+                    0x53af3b143f9f68ab,
+                    0xb033ae10a4e1e6e7,
+                    0x62a116ddc22aa8da,
+                    0xb29c0ecfcdbe6c05,
+                    0xec9d58a1af25c880,
+                    0x36f77325dd3a2fbd,
+                    0x38e860d6c9727af4,
+                    0x711ac5d3960bbe4d,
+                    0x66d768ccfdaefa85,
+                    0x58d0640fb77f960b,
+                    0x00c4460d59cfeee2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaab8be180593d69e,
+                    0x4dbf14d992bb80e7,
+                    0x0eace257e5968554,
+                    0x3cfcd45f49e8b97c,
+                    0x6c0665d1936c6c4c,
+                    0xd86441673c1d96fe,
+                    0xfd8cd88702a72148,
+                    0xb423cd8a2fcc86dc,
+                    0x5b84a90b847bf41c,
+                    0x5b51c11fd9b1f1e7,
+                    0x6c8ec92684c74a5e,
+                    0x002b2f786d18c371,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd9f5f9740ed2e19d,
+                    0xf6f729efadf94af4,
+                    0xb705a02ae3b18ed4,
+                    0x2dd7179cbd7d8009,
+                    0xa1463eb45e41fa84,
+                    0x9a2950951d8fcbff,
+                    0x99a34ae791796448,
+                    0xf5325d8200e642d5,
+                    0x3a3fc38eb1edf4e8,
+                    0x4a384c9f88bc7404,
+                    0xbda0fd46bafdf9b4,
+                    0x00605581c08477ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2501360eec817deb,
+                    0x958eda7fda75711b,
+                    0x0de8a7c34a405598,
+                    0xf8103521acfef3eb,
+                    0xb98b8661697691f3,
+                    0xa88e708ff9eaae95,
+                    0x7b39f56e86729246,
+                    0x8aaff20de7b17ed3,
+                    0x58bd605a0aa1e5fc,
+                    0xd80d0347c9472a6c,
+                    0x94baf74b949e38f5,
+                    0x0110b0328c94879a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x4d8c5655cd378775,
+                    0x56f4ff8c6cb44b0b,
+                    0x8b787440d78bf570,
+                    0xe7ccf726ff1e2268,
+                    0x87119888a44fc377,
+                    0xd162d0a72126a70c,
+                    0x0d0d01848b21815c,
+                    0x9af5218b6bb30b8b,
+                    0xfa062bb1e8095c25,
+                    0x5868bcfd0126632f,
+                    0xc6a2ced444fee378,
+                    0x001267f281db73df,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4ebdf5af739ffae5,
+                    0x80b5b509e4e547c4,
+                    0x6ee856dda247479f,
+                    0x9e57b9420e1e25ca,
+                    0x93732a7f1514e94c,
+                    0xc43260fa8c4936f0,
+                    0xccd8ac8018f9d211,
+                    0xf6b5cda774312260,
+                    0x6065627a667933cb,
+                    0x1bcd5ef781b9c19d,
+                    0x9a4def31b58086a3,
+                    0x0073a17d98753ba8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5961cc89c1dd646c,
+                    0x0b36a16d7b9a8f45,
+                    0xc1cc9f05646419cf,
+                    0x8639271d3801fd67,
+                    0x35b5a1ac7483ae1a,
+                    0x9037b04b96d15152,
+                    0xdc61a1464a2761bc,
+                    0xa3f6241a943c5c88,
+                    0x4a774f9f1b38e82f,
+                    0xdcc110c971c6658c,
+                    0xa15588d083592743,
+                    0x00e82427699f606b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc6a9cee8353191b3,
+                    0x67663186e5fa5b64,
+                    0x2fe8b6ebc986c917,
+                    0x97cf5306de8f4cc0,
+                    0x8447399506bcaa02,
+                    0x241399614997627d,
+                    0xf714f1e2add5fbf4,
+                    0x7c650b8f76ed32a4,
+                    0x188b8a12e97e8194,
+                    0x3fdc7c99c418c133,
+                    0xa156fe0ccbd1e707,
+                    0x002a47e92194d615,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d64bf90d9b42a55,
+                    0xdafd7db0dad91d2e,
+                    0x3d232f836f935d0a,
+                    0x9ea817929d010636,
+                    0x3427dc205ba61cf8,
+                    0xdda4ccd3a7021f48,
+                    0x59f85c147724f8c5,
+                    0x231547089c303b9b,
+                    0x4b1efb337777de90,
+                    0x27bdfc6625608671,
+                    0xd14838ded0b995ae,
+                    0x00669cf54758bc37,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa2a8e960fe937678,
+                    0x339e6552607f17de,
+                    0x5971ab45be394073,
+                    0x708445e11cc8ba04,
+                    0x5d4a868bd937cccc,
+                    0x9016b6c12e772373,
+                    0x034cb182e90422c1,
+                    0x2b6d7be66fa1875c,
+                    0xf5b8332c12be3260,
+                    0x5e9532be4b098f34,
+                    0xb38d90c5209fffad,
+                    0x00c39fd311e3af40,
+                ])),
+                Felt::new(BigInteger768([
+                    0x640fcfa42952d298,
+                    0x3d3d3f3ba16d4bf7,
+                    0x196f29f18a468f0a,
+                    0x5482909585d13bf2,
+                    0x7e9f81280a7bec3e,
+                    0x94ce7ed3e341c2f0,
+                    0xd38137a7503d5687,
+                    0x672cb5db82b05387,
+                    0xb521fe1e69447b56,
+                    0x308f19bd89154db6,
+                    0xd0edcff55068215b,
+                    0x001f71990a9f02da,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf845bb2221684967,
+                    0x8c76255273ca9071,
+                    0x0c2d7004a8730d2e,
+                    0x307aa10b47e48163,
+                    0x9edbe12bb40becb3,
+                    0x560c18c0733ea54d,
+                    0xcf701f0e3a2c3084,
+                    0x5fb02d4707c71a47,
+                    0xb9b366a2f3b5114e,
+                    0x5ea25d4fabd9e481,
+                    0x364900f4a2a93676,
+                    0x00002a731bf08bff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2cb7813f47759d3e,
+                    0xb031a82b8097825f,
+                    0x06d1274c776afbb2,
+                    0x895a8590b12551b0,
+                    0x2d7014db2f23e4ac,
+                    0x72c32386ec4a6211,
+                    0xe6c679dd135a8766,
+                    0xe4f52300dbbf4648,
+                    0x1304140d3746a8a4,
+                    0x7f9fd6fdd55a1374,
+                    0xe4e5a204df24ef1f,
+                    0x00bf2ec749add0dd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae3fa42f74ff777b,
+                    0x885a0af6323a909e,
+                    0x191beb5f33e28579,
+                    0x115216bcb7a8b0fe,
+                    0xfd63c77613065bc8,
+                    0x676b8b41811e1ccf,
+                    0x2da7082b611003ab,
+                    0x966d45eb9b248524,
+                    0x9fe4f3eb0cc4e884,
+                    0xf24334b9279fbe84,
+                    0x178d75908183b2d8,
+                    0x00e218da3363176e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xaf4f47b90592bc78,
+                    0xd46eca1e7c6026a2,
+                    0x3ff062c1acfd316d,
+                    0x10ccf4246b69603b,
+                    0xfb6e7d5768282bc6,
+                    0x55fd06d32a540d77,
+                    0xb41793a9d4b6edc4,
+                    0xe3d7150b90fed412,
+                    0x9ba8738eac45db73,
+                    0x54f9883011829f64,
+                    0x467297a127d2d922,
+                    0x011d36cf50c7b876,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb7e4fb02ad83925b,
+                    0x4925ee859fca2c4c,
+                    0x34c421f2e4c946ff,
+                    0x8a26f543f87c4a3e,
+                    0x31920ba45374c1a1,
+                    0x441677a991b24c0d,
+                    0xaf04cd422212d731,
+                    0xe93df89435eab61a,
+                    0x2101f3a023bdb69e,
+                    0xd0c025d0f56ba53c,
+                    0xb249a0c4ee50d7df,
+                    0x002a609409293597,
+                ])),
+                Felt::new(BigInteger768([
+                    0x954987d97ca078b8,
+                    0xedee0058607c8b28,
+                    0x7a858e6e3d45167e,
+                    0xa040374540388968,
+                    0xd36e4e2939da70c6,
+                    0xd1b97be54ccba9ee,
+                    0x133112475978e953,
+                    0x75c2784b510ee60d,
+                    0x9f972554e12fc84f,
+                    0x459033c2aa7e63ea,
+                    0x363329c19eb0919d,
+                    0x006c28782b0ec0de,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26ac4e523b0ff6fc,
+                    0x34629712c7034906,
+                    0x79b491d8cd382aeb,
+                    0xfed9b0f8823f8812,
+                    0xcbefcb69f5ffafc1,
+                    0x358b1e77f52ef759,
+                    0x49dccf17745364e7,
+                    0xb7db6f5360898130,
+                    0xca9b9644d4c02768,
+                    0xfcae8e67cb0bff11,
+                    0xbaa06f15d556271c,
+                    0x00b3fd2fc7a16e8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cee5f4260693829,
+                    0xb15f60af1cf3c416,
+                    0x354f39a28f394f20,
+                    0xa3e97149298c9d3a,
+                    0xe3477a9806c5fbd5,
+                    0xe1b43d219d2a8619,
+                    0x529003ca98f39966,
+                    0x33c4dafd520f623b,
+                    0xa82ad0f0b803fe57,
+                    0x9a53e5d857356851,
+                    0xacdb1516e3669a26,
+                    0x01024a10b3088786,
+                ])),
+                Felt::new(BigInteger768([
+                    0xffff35f137e0c674,
+                    0xb053ca52b5234dd5,
+                    0xcf694866b7b04e62,
+                    0x89414e37417d53d4,
+                    0xdebd7afad1f44bd7,
+                    0x4bb51b0e47513b57,
+                    0x3d79da0c5b47ddac,
+                    0x2a4433c0dfe897b3,
+                    0xeebea36f4289a137,
+                    0x23fa17077886df4a,
+                    0x7231fe9849226641,
+                    0x006b5b060a0c1165,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf9c9ff362b7c7cfd,
+                    0x9e2307383c3da29a,
+                    0x5dab4cfdcb1c6622,
+                    0x98020d7e1bdc37f4,
+                    0xbd89130b8fc61e4f,
+                    0x7fd1eb11e67a80d9,
+                    0xa644bd945e5392ba,
+                    0x9815be61cd6b84a9,
+                    0x2d3d24978f40f40f,
+                    0xeef3a142ec05d46e,
+                    0x167e1cb43d79159a,
+                    0x0047516eddbf88c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x62c64776f4a4f219,
+                    0xe614a34bc5444a1c,
+                    0xf532e266ead0a7bd,
+                    0x84ee5ebc6757c947,
+                    0xdf05fce0fab47100,
+                    0xf84fb34eff57d62a,
+                    0x347455295fb3f370,
+                    0xe4d62af18caf29d5,
+                    0x93ad86a60de3c7c6,
+                    0x42c4e1582fd7b7a3,
+                    0x3d5386e7d9bd5b23,
+                    0x00cb44cbf060c131,
+                ])),
+                Felt::new(BigInteger768([
+                    0x80263f237ea13c05,
+                    0x2114b67066c7525e,
+                    0xbc8f50ed77455e4d,
+                    0xbb817cb8940f0b76,
+                    0xbf2cfba4471ff704,
+                    0x650b8d7910ba1a06,
+                    0xeab152dc3af4e205,
+                    0x290bd459457891cf,
+                    0x1dc1f217664016d5,
+                    0x26db66a18255dfe9,
+                    0x2ddc48ee99718c0b,
+                    0x00eb8806ad4c54e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8211887590af8937,
+                    0x0e6d5ff9e290c61a,
+                    0x27c570e645b858fd,
+                    0x96cc89233d5eca8c,
+                    0xb725e129dfecd936,
+                    0x77eff6766e1baf54,
+                    0xad6c8aeeda108a1a,
+                    0xe487bfb1bc75e493,
+                    0x7014d2c48191d0b8,
+                    0x169e2f13cbf95a7d,
+                    0x3803341b6b88f76d,
+                    0x00269b2b38d84660,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x09a99d911fc3548f,
+                    0x9ead9a125763850f,
+                    0x4a1986495a1b35f6,
+                    0x5ea1adc465732144,
+                    0x340a51b5c6600b4f,
+                    0x639cdcf19594447e,
+                    0xeda9d8fff0fb09a8,
+                    0x42dfee5fb6610e96,
+                    0x802135dcb28cc599,
+                    0x1c31f842272cd847,
+                    0x3b5484bf519bcc53,
+                    0x00a9faed6eb48476,
+                ])),
+                Felt::new(BigInteger768([
+                    0x00e60038165b83a6,
+                    0x42561f717baf00b0,
+                    0xae2609bec23200dc,
+                    0xc31e43b57076f0e2,
+                    0x2ae2383b18197a52,
+                    0x074a913b0cc1c27e,
+                    0x0bc307a4a0e45030,
+                    0x69e05e001fdaf3ea,
+                    0x1bb5420f539aa385,
+                    0x3c30479ab0669170,
+                    0xd56a523cb0b35d67,
+                    0x0106f1d4e1880d97,
+                ])),
+                Felt::new(BigInteger768([
+                    0x76a3e4b345c67636,
+                    0xbca0acb83a1f8968,
+                    0x7cc3d277402f0ce8,
+                    0x675c7c33ae4bd5a8,
+                    0xa86e3fb0e20d2ecd,
+                    0xd6a9990a4bb6c26c,
+                    0x250e319480b603b0,
+                    0x802985a1b415c82b,
+                    0x186125bcfec3fcf3,
+                    0xc325eba6d1f7dccc,
+                    0x577ef5c2bd263a74,
+                    0x00c3fe60fc04d0ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa2677724e22d163,
+                    0x714805dcc1278257,
+                    0x681ea4bcf25149df,
+                    0x3509c74fdcb72b45,
+                    0xefac985d9e163b4b,
+                    0x92aaa78799e0a0e3,
+                    0xcbfc7b48702a6720,
+                    0xec4d5b7ab91d75df,
+                    0x273ed32e889a104a,
+                    0x91934b6870610377,
+                    0xbced21b3226625b7,
+                    0x004d4dc54993edb4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeaaff9cf404d82ce,
+                    0xd507626e75c3bd3a,
+                    0xdbba81029369bb29,
+                    0x2587be14941db395,
+                    0xdbd27787bd254dc1,
+                    0xdc8a9dc7a90f49e1,
+                    0xa74b17441032596a,
+                    0x45da9169676d95fd,
+                    0xb63e562a80098503,
+                    0x26084a1852b4dec3,
+                    0x425311271c44ca3f,
+                    0x008f5ba76be380a9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc94b4c2362bc052,
+                    0xf63fe9c4812609a1,
+                    0x3276b71796534e70,
+                    0x545b28fd1b95513d,
+                    0xab4210cb7546aa82,
+                    0xa4b0ded85adb2d68,
+                    0xcb7e48d98d6f6050,
+                    0x1eeac22444002893,
+                    0x5e633202143233e5,
+                    0x2e49d88bf1726843,
+                    0x964b47d1dcadd6f4,
+                    0x00cfeefd5f05eed8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4cb5b824116e4bf,
+                    0x3da86dc4eb55f426,
+                    0x3a0177eada9e5dee,
+                    0x94023a4db46c242a,
+                    0x96c0bfdd99e93458,
+                    0x26279d4d463a4fd2,
+                    0xfd71c1b00cfd5230,
+                    0xdf893c73d4b782ea,
+                    0x585e8aeaff69f61b,
+                    0xb4162f5e2d6c32c5,
+                    0xdbd791c00c55bb68,
+                    0x007ce0ef8824e30f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad94a1c464dc13bb,
+                    0x55cc7542f11a43a3,
+                    0xf91d852110129fb4,
+                    0x1df3a960b3f5c9ea,
+                    0x32371913402fea32,
+                    0xfa43a24212ce7992,
+                    0x672070e0d665f53b,
+                    0x102481558dce679d,
+                    0xfff216b0b560eb05,
+                    0x2aabd8a60b868238,
+                    0xd5a99f0c1d88165f,
+                    0x00b5787ddba50a5f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5fbeced7e2d6b38,
+                    0x33a0035dcee5a664,
+                    0x5c5c72dd1f713e29,
+                    0xb91e28f90cc49262,
+                    0x8ed1247b08a8784d,
+                    0x077436b61ea5b227,
+                    0xc72e0e25c66abfe1,
+                    0x1eaafc73edd7d361,
+                    0xad4139eb4511cbe0,
+                    0xed86dd4e54332405,
+                    0x0b1f4dd3a5226011,
+                    0x00a877832236df17,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc079f09662f2a036,
+                    0xe30905ddd3ac8800,
+                    0x45980547ff11be4b,
+                    0xafccde93a4a75f52,
+                    0x2cff2da02e9efd1a,
+                    0x4c519d649c61fce5,
+                    0x541de30b23462b1b,
+                    0x0bc39290bf5ba48c,
+                    0x563369f4b1677c4d,
+                    0x13dca4da834652ee,
+                    0x0765bd14b738ba37,
+                    0x0045cda27fee8f9e,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0x96d578ee1bde1769,
+                    0x758bbd2ed9a037d0,
+                    0x15db8c69c41800d7,
+                    0x801d21bbab02a774,
+                    0x9e5b6205952f2e6b,
+                    0x8cf1d030f1f0d30f,
+                    0x26f4aeceed6da512,
+                    0xc8caba8d7cd31c4a,
+                    0x8489d1eab859b86f,
+                    0x88853c8951e95d35,
+                    0x8986732f5c4197d7,
+                    0x0108149979fd2627,
+                ])),
+                Felt::new(BigInteger768([
+                    0x14baafc475bb489c,
+                    0x3ec5c65de1a0c8d1,
+                    0x1e1a93f0de5eb42c,
+                    0x8765c52570e7b8dd,
+                    0xae5dd873e3536917,
+                    0xa863eedfe99795e2,
+                    0x258638c3ea658824,
+                    0x467682c0f346ceb1,
+                    0xeebbd100e2237aa2,
+                    0xd3a2abe2e9727ec7,
+                    0xc609540d61620b4e,
+                    0x008daee1951ce601,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36e8a6d51346aa72,
+                    0x173a9c33ef233f86,
+                    0x76801f75da5ecc06,
+                    0x0e6cb4e5a25d792d,
+                    0x7c6a746172a10e05,
+                    0xea1b8276994a9a19,
+                    0x53dc8b0bb40260ee,
+                    0x466e73123b6174a4,
+                    0xe97c78ae4ecdb531,
+                    0xfd66a50af193ff16,
+                    0x915b172b956a98e0,
+                    0x00dfd7c3c42e23de,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf92ebc0cacdc107c,
+                    0xbf9df535c360e3cc,
+                    0x10b5d9944bbe65ae,
+                    0xb5efbb3a3f3423d5,
+                    0xe71afc28fd52bcab,
+                    0x6ce6045f09352e08,
+                    0x5631997c8d0643cf,
+                    0x5bdb91fa6de182d1,
+                    0x8d38618a2818a83e,
+                    0xa5f2e4a85026cd14,
+                    0xbfda6193fdc99e42,
+                    0x005db3c9744ae994,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4d3d4d4546c32f99,
+                    0x517ab6980fee55b8,
+                    0xf8d2a92deb2a36f2,
+                    0xa946843c41b16fef,
+                    0x8e8d0869e979f959,
+                    0xc3cdb452e6f66b60,
+                    0x5909e37128e98b3f,
+                    0xa3c19e3c52cfe22d,
+                    0x6155d71fb49ee297,
+                    0x335770a81fb87650,
+                    0x64d6e17281e5408d,
+                    0x00de4940d96e2604,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb754cc6dbcf05c5c,
+                    0x2631b06031fed8d7,
+                    0xba461a2b327f0fba,
+                    0x032a322cf97583e2,
+                    0x9787f85d43a916cb,
+                    0xcf71abde596457bf,
+                    0x5d956083beeffdfd,
+                    0x0df592700900869e,
+                    0xca0b6568de42a68a,
+                    0xf15c01f5f7f3f327,
+                    0xeb609d6ebaa67c15,
+                    0x0000cabc6491e591,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdadbc531b5de7f39,
+                    0x64ce29a9395b2930,
+                    0xb884b863d0cbf850,
+                    0x9cb00bd8e7438c1c,
+                    0x88d9836630250ff8,
+                    0xeedf904d6655bbae,
+                    0xd205ad7828d342a1,
+                    0x3cf1fcddfa55552a,
+                    0xb196824de88ca773,
+                    0x71900515af4a65fb,
+                    0xf8502e739c282aa2,
+                    0x006898f06bb21e5c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x94a9338b21e7e413,
+                    0x273ccae49ded485b,
+                    0x6bdc396312272ea2,
+                    0x1f259a476ea61339,
+                    0x900a847285eeae33,
+                    0xadb5b5d3cb348729,
+                    0xbfbc53999e4a87bd,
+                    0xdb87f4177edde894,
+                    0x6e0054c42c71332b,
+                    0xb7ddce92bc7e643f,
+                    0x4dd6b37e547b7a37,
+                    0x0029d756dd27ca3d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc82fcc59afb7ff50,
+                    0xaeb84bc51d0b1804,
+                    0xf34ce4fdc780f0ea,
+                    0x7aed5464aa2ab876,
+                    0x10792ac5c8ea1eb1,
+                    0x3a8d2bbdac7cd613,
+                    0x0eafe3b58f138497,
+                    0xc1e81f862057f6fc,
+                    0x76956dab858997a5,
+                    0xd8a028b81946d0c0,
+                    0xd69cf66f23fdec9d,
+                    0x009f65d5170cfa37,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b8de94706a68429,
+                    0xf3138006705da2f4,
+                    0x12696cae0c22b51b,
+                    0x390f5aaa53f94d0a,
+                    0xbe7a47bb0a7d216c,
+                    0xa4a5694b1671ffcb,
+                    0x3a33e8d32b6cee4c,
+                    0x8e7ab58841314188,
+                    0x0c50d97387310f22,
+                    0xe2aa7f5d38c3328e,
+                    0xaec179357c8edfa7,
+                    0x00a0a4bf1a24bf8c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x333837073869201d,
+                    0xe572fc02c7b2f418,
+                    0xf8d8ac52a8c37b62,
+                    0x764ca77e90248955,
+                    0xfaba14b07c2ef22a,
+                    0x87dd111ab18d4b2d,
+                    0x1906338587a1e109,
+                    0x074914e7ca7320f5,
+                    0xa81c529cbf5c5220,
+                    0xdb7b82a9b9d2b677,
+                    0x27aca934dcda7c34,
+                    0x00d926f7ee3c0b9e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa0b87a0e3114e0f6,
+                    0x968d2c5959ab4eed,
+                    0xc24134d45237b75d,
+                    0xf6f576fcdf809079,
+                    0xc765201da757ca00,
+                    0x6ac458cce6c1adcd,
+                    0xf66f7cb3cab63c71,
+                    0xfb36e18762ffa1b9,
+                    0x372b44a0cac70910,
+                    0x272baa3ee3f292a0,
+                    0x8135616ef76d9c1d,
+                    0x00ebe6181422d552,
+                ])),
+                Felt::new(BigInteger768([
+                    0x63453f0911590076,
+                    0xe904efc84803b0f4,
+                    0xe0eca0578f602677,
+                    0x4ac9e80c6e2ba190,
+                    0x858638ec371edd77,
+                    0x9ec49f931813a3cb,
+                    0x0c3653449d5df556,
+                    0x2fefe25da7bc87fa,
+                    0x718b11a9a2e38e8b,
+                    0x4a59eac41830386c,
+                    0xe47daf7d14fbf527,
+                    0x006cb86c3c44fd7e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ecb3cdc577d4eea,
+                    0xa843ee10aaeacb08,
+                    0x914fa67494b89af4,
+                    0xbe2675fc0fe3ba2c,
+                    0xfec6d2a937f35f97,
+                    0xadd4545c55a8098c,
+                    0xc02233c00735a0db,
+                    0xd9b262ad2b52f806,
+                    0x8b763ddb9b7f8ebe,
+                    0xb629314e083fe42d,
+                    0x59ca3d60312030d7,
+                    0x00bfdc717e7ea32a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x024b145adff0a2ff,
+                    0xca7d953b2e9f7945,
+                    0x62d935c5d23e0419,
+                    0xb189bdde73dcf5a6,
+                    0x73aa5e9b476e4043,
+                    0x352329a22a074356,
+                    0x6d3814969f892db3,
+                    0x69e53d5457248033,
+                    0xd845e9e32be1181c,
+                    0x619b5ae8ce0c68eb,
+                    0x6fc9e719e6ab5f64,
+                    0x00638f4851a81225,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe519b8edf5f5a9e8,
+                    0x4d2a1d11c157a9ac,
+                    0x00b3795153516d41,
+                    0x39b23e603a9de8e7,
+                    0x05417e8b5d5420bd,
+                    0x4d4cf4dbe3cc36d1,
+                    0xd88031c768a044e9,
+                    0x089e05bdc8d9ca06,
+                    0x4e60d2de47174950,
+                    0x2860b3f64f790a21,
+                    0x60d3422769bd0a61,
+                    0x00fcb3a7a05f6844,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b3b96a193318049,
+                    0xece3b5d0db6fb17b,
+                    0xaec7f47907d84a0b,
+                    0x2df59405bc6eeb8c,
+                    0x6c3eee98c85608f6,
+                    0x652b3acb1dceaf3f,
+                    0x7bc634615e99398d,
+                    0x0b1cdc2da70c514b,
+                    0xb3d51ebaab5b32bf,
+                    0x2603258c5a78650b,
+                    0xbb4f389a974ecacb,
+                    0x01214791a7ff6a9e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x340d8dfc96323c68,
+                    0x11790ce0fb6308e9,
+                    0x91dd238344520e2e,
+                    0xb3516c657f2fe789,
+                    0xef89c0a14c1959ef,
+                    0x762221cbbf4e5ad8,
+                    0x73e4977b26b8f483,
+                    0xb77f744e98a77415,
+                    0xe8416618f2731227,
+                    0x0e6290ac58119c7e,
+                    0x5a579969eb6b8b25,
+                    0x00b90b75604ae4a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7921742c74992eff,
+                    0x1313470de3c0fc83,
+                    0x00b44cebde4028de,
+                    0x7c212cfc487e5d93,
+                    0x2767c775e42bb6fa,
+                    0x8040165aebcd67d0,
+                    0x9ae5820c19f1b74d,
+                    0x6e7f33ecd75990d8,
+                    0xe4a8c2d5882197fd,
+                    0x42d6f835ff42da1a,
+                    0x531b6ea340134d7a,
+                    0x0096725c26c04779,
+                ])),
+                Felt::new(BigInteger768([
+                    0x69d0919d4b9dd740,
+                    0x75b3ebfa1edd6464,
+                    0x5d002907cf17d5ac,
+                    0xb7a6848c98b55502,
+                    0x868d279cd9b115bc,
+                    0xd6ff3c54f8ea7de1,
+                    0x776ad2b1e9dafe4a,
+                    0x9cef113709f1cca7,
+                    0xb68834db2d8b8674,
+                    0x290bd1c62e3dbdf6,
+                    0x2ef4e7d8b1fc2aff,
+                    0x00d82028f2028b50,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf26c41da32d4371b,
+                    0x827fd25d70d0df23,
+                    0xa94bdd26ff42e4d3,
+                    0x88c318b11e3ce79b,
+                    0x825bd2acee387549,
+                    0x889ea2b630da0575,
+                    0xdc794eb89b5cac3e,
+                    0x051a29b4f255b8ac,
+                    0xb78a1f208d6376b2,
+                    0x29aae822307b7f54,
+                    0x38c3eeffd4d94d34,
+                    0x0066823c5a1af65d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x68d81bb4cd67350f,
+                    0x48676a6fce894b7a,
+                    0x2f0e754a33cf044d,
+                    0x9a390c292e2762c0,
+                    0x9081a6baa5935405,
+                    0x6cb50c109c4624b8,
+                    0xa9dc2f3231beacea,
+                    0x85d4ea864d5165d4,
+                    0x870dde4d5647b610,
+                    0xa2ff9648db1d6526,
+                    0xb68d02e90a39ea3f,
+                    0x0083ab038a6e36ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4a4907a4e945187,
+                    0x3ae3c5acb17dd075,
+                    0xad7781459c36e902,
+                    0xe04a5c99d98833a0,
+                    0x997b348ee41cbb3f,
+                    0xdad74a8482f24dc8,
+                    0xcff8b285e744f9a7,
+                    0x766e872844a4c10f,
+                    0x880f819701b49230,
+                    0xae174f19ff70ca5a,
+                    0xbd70b9adb4f59b74,
+                    0x0043f4b5e9344450,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32464af8ef36a4d8,
+                    0x94364316d29026d3,
+                    0x27ee4992737d5467,
+                    0x3f43254385672c10,
+                    0x1eb487b4f8d72ddc,
+                    0x147783c9bb226508,
+                    0x59504750abb8c88f,
+                    0x875b6de4625fca14,
+                    0x7f8ab5a452162ae4,
+                    0xbb2579559e2e2890,
+                    0x200cd167b046173a,
+                    0x00e12276319f4911,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfcf566ff27afdd29,
+                    0x5fcb47aa7c3315c2,
+                    0x8278b36a29bb4556,
+                    0x30e1ff92dbb820ac,
+                    0x8988a08b91e320d8,
+                    0xaf3f81e511c065de,
+                    0xa8fda88c507c54ff,
+                    0x28304dfd7fa35e54,
+                    0xdc31573f5d59069e,
+                    0x64bf1f29be446fae,
+                    0x9b550dfae385de20,
+                    0x0003ca56c85bec76,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x30a01bdd3f450ea6,
+                    0x2b229fe2474343f3,
+                    0xcbae5d222d090c88,
+                    0xe4e789a9c850a51d,
+                    0xc4003d0248506493,
+                    0xc7fa745f477c0d5e,
+                    0x147f4e689753e64e,
+                    0x1a489552845b37d5,
+                    0x9158e5b098ebe520,
+                    0xb24c9684264fac19,
+                    0xca8d6879f713a1cf,
+                    0x00a244746351e1a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x778680711d428c8b,
+                    0xd732a3fdc0e40d76,
+                    0x5436162e75edcc8a,
+                    0x55ada280aa053225,
+                    0x8745dbe1b7ffd04d,
+                    0xd451c0e26377c78a,
+                    0xd5746b59abe373fe,
+                    0xa5153557945d8488,
+                    0xdf43b976e63e3a50,
+                    0x1049f566860e9104,
+                    0xc15d7a45d0fb3754,
+                    0x000cb77e7f954722,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3541ae5a88ebc0f8,
+                    0xf762994511222313,
+                    0x8a7b493c802f2737,
+                    0x2957ef6e3b838f76,
+                    0xd0283e15d910ea22,
+                    0xfd9fb9883d4d89ca,
+                    0x9381acea3e6062db,
+                    0x48999e9c084cd520,
+                    0xa9db9b4a0baea673,
+                    0x0399df3bf5163eeb,
+                    0x31d8a997392d4065,
+                    0x00eb30f87bd4cf1f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6df01453be20fa4c,
+                    0x14e91312c68b6f1e,
+                    0x157dc4b3100821e8,
+                    0x23bc106376662922,
+                    0x85b23ee44749dbee,
+                    0x29ce13b742f6b258,
+                    0x3898ed9efcf0b796,
+                    0x2fe4c1d5b1720096,
+                    0x794f3c5f65b7977d,
+                    0xce6190c08764a259,
+                    0x7cace5680591a516,
+                    0x008962018aa3b5a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x472e123c819166d7,
+                    0xcc8e139e0b16914a,
+                    0xbaca514c6dbf5128,
+                    0x95cb9bdb81918a1c,
+                    0x7441202d2424ad68,
+                    0xb2b09ecec96377bf,
+                    0x184801f6845fae2c,
+                    0x25d5449735fb4374,
+                    0xdd756f045efacb84,
+                    0x0087c4e921e6a313,
+                    0x1bb10f17704c7809,
+                    0x00bab0bafdf84140,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x63a77bd7b5429808,
+                    0x755222d690f39290,
+                    0x11db21082f5f03a7,
+                    0x86fde0ba76d869eb,
+                    0x0b72e7c0efd9b6f7,
+                    0xe035d8b2c9ccf620,
+                    0x4bd627f9b47107ad,
+                    0x4c90dc7b9aefe1be,
+                    0x960689216bec2d5f,
+                    0x61ac7851e29023c9,
+                    0x8e4b0a06dd79c399,
+                    0x004667304e075943,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb5820242871fbb8e,
+                    0x1c9d4f34972746ba,
+                    0x7dedc62af558e593,
+                    0xc6ddb4feba9970be,
+                    0x4ead71c4de864df4,
+                    0xc8d4734bd9628080,
+                    0xc746f5d506d44104,
+                    0xe0b7b5d71d43b373,
+                    0xf891a0107db4de06,
+                    0x4f128f188da4ec24,
+                    0xc7d1c13ded184950,
+                    0x00166332d51b92fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x17ac0616dfa50e21,
+                    0xafd9395a6394e254,
+                    0x5a12ed004a8d6c51,
+                    0x924b4d5966e0536d,
+                    0x819ddb4cab68de14,
+                    0x5b4a019a2ac2ae27,
+                    0xd1b41eda2aab83c5,
+                    0x378d1645e17a477e,
+                    0xf67fdebee692e3fa,
+                    0x290dabf3ffd6acdd,
+                    0x1cba6ee6f9395b7d,
+                    0x01019db403fbd59d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8c01a91b4e5ab96b,
+                    0x6012613f67e44d14,
+                    0x3cae1b1edc3d5816,
+                    0x6165d8435b5fbfc2,
+                    0x15433cabaae82510,
+                    0x0a2097ff0801cb6f,
+                    0x02b7aa360e92ef1f,
+                    0xe151ff45d16119be,
+                    0xa4a3c5a48d6b05f4,
+                    0xf78643dadd275d9b,
+                    0x2eda8079e4254852,
+                    0x008f9d9e70094bf5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1be6bc6c49a9032,
+                    0x728a712269f5ea1c,
+                    0xeff65a03040ba92c,
+                    0xdc5918f1115e5370,
+                    0x25e4ebd56b8b895d,
+                    0xf5ec766806a3f090,
+                    0x200cf31d774f9f7d,
+                    0xb282a8426f837ccd,
+                    0x844859b92fb784a4,
+                    0xe4fd93ec420f7608,
+                    0x2b82eef43d5c654c,
+                    0x007ab95fa4599afb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6522f31bb72e20dd,
+                    0x4cc32a8212948e95,
+                    0xfd4198c84f7403ff,
+                    0x0e4050ff8db62f14,
+                    0x962a0f1b634dd395,
+                    0xb0c4dfd3cb457b62,
+                    0x833fd777aa8e93d2,
+                    0x2f54dbd1e5189d55,
+                    0x749cdc8403104dbf,
+                    0x4abe422b32ea6cfc,
+                    0x060db53d97c3fa93,
+                    0x00c21a813e3aef5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8d17daf04a7c6ecf,
+                    0xfdade5bd814ead2d,
+                    0x7dbcde5ed86644b8,
+                    0x4cd1252b81285844,
+                    0xfc41de7f10b49ef6,
+                    0xa5dc46d1edc115fb,
+                    0x0e8ee761462711e7,
+                    0x66b346000c2ff055,
+                    0x683543fe7ce2b42e,
+                    0x22a892b4d10c2322,
+                    0x247e6fbc61e225c5,
+                    0x0045d1bf2d052e48,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6327ad8609fda226,
+                    0xc24974c00202fe25,
+                    0xb2fd03a23662a5a3,
+                    0x3a1dfdda71c666c3,
+                    0x9b1c9f1aa22f1c8f,
+                    0x95c1188095fc7b37,
+                    0x01a30d03cb6de25f,
+                    0x5da718e331f6643f,
+                    0x49a8e1864b454a6d,
+                    0x8ad6fac3372ef11f,
+                    0xe3190aeda5da739c,
+                    0x00b96a016d68a8dc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf4c5b7cb465ad75f,
+                    0xa358ef1da3343d01,
+                    0x155e943d62f0d9c8,
+                    0x2b8f8b7e8dfb9f60,
+                    0x814ed4e5f81f0c5a,
+                    0xd2227cc92852cca0,
+                    0x4469f3d5669c087c,
+                    0x4f9d9eaf4c4c596a,
+                    0x42e2edad60031273,
+                    0x43eecc0a189bdff1,
+                    0xc7ba5f93503d18cc,
+                    0x00898f2a43669ee9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44b09ce06b97473c,
+                    0x35456c79b057949b,
+                    0x411a8c5154788836,
+                    0x286f04157334ca30,
+                    0xebf236273f8ab364,
+                    0x3e419109636e2b88,
+                    0xfb31c61cb9ab1553,
+                    0x8019c27a34dffa3e,
+                    0x52badc4b9e46842c,
+                    0xa87cbdb71e762209,
+                    0xe0a7fc12c48a50d3,
+                    0x00a6ee7da9690c62,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xef29c5e33be3c861,
+                    0x2ff28d46d54f3f5d,
+                    0xfa1f5765006bd069,
+                    0xbd4f9985c2240977,
+                    0x093e41f589c0cfae,
+                    0xa6b9a1d342ff0ef4,
+                    0x417ca664652042f5,
+                    0x12e60581573bb4ee,
+                    0x9f404f92e7beab47,
+                    0x0ae8e6098968d28e,
+                    0x3adba9563bfd782c,
+                    0x0027d4381df79b18,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4e9303a9b730a681,
+                    0xaf2b436751747895,
+                    0xc3a0215409823be9,
+                    0xa3c3fdc8c786ffb7,
+                    0xec2fc711f5dae19f,
+                    0xe3089a009a443c2a,
+                    0xffe2d3e9fd195e33,
+                    0x011697ce82f98edb,
+                    0xb3b7108d43eb2f97,
+                    0xc24bf762510c6572,
+                    0xda1e34ea282fd092,
+                    0x001dcb8d355fa4db,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb165e8df9e846be1,
+                    0x9bdafcce8e866099,
+                    0xe031faadecee14d7,
+                    0x73a475322151b5db,
+                    0x022e914d64b1b3f9,
+                    0xaee679f33cdc69a4,
+                    0x8fd8d90369e63514,
+                    0x7060bcdf93f30d8f,
+                    0xd9b20258c5a22534,
+                    0x0e51610fdac6e6f5,
+                    0x35a61984ca023d7b,
+                    0x0120b157fc406926,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2470b66bb04be5af,
+                    0xb0e88dcb339d4293,
+                    0xf81c6346ca970d12,
+                    0xf6c1bc289a95ed53,
+                    0xb746f542228d2b19,
+                    0x314de8a42c89385b,
+                    0x3f9c9663ee88c8aa,
+                    0x7d97496748c24cfa,
+                    0x910d02ed7ffa9e2d,
+                    0x5584248a0dbea575,
+                    0xe0095f347dfc7202,
+                    0x00b18893f8f74276,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa11168709470c15,
+                    0x5b840f7869b570f1,
+                    0x881dbf83daa51da1,
+                    0x149e7ce1ab5c1e30,
+                    0x3c15b625686719d8,
+                    0x379f08b7b0069ce2,
+                    0x9750efb28843c924,
+                    0x2f22ce1cc458747c,
+                    0x06aa115a38f01d37,
+                    0xc4c2cc68addabadd,
+                    0xa62819941cfd818e,
+                    0x00b3bfadff7fe073,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x75f9f65c4864d0c7,
+                    0xb9d55afd5a5cab86,
+                    0xd515864aa2c77d86,
+                    0x9ed0e3d3f6607174,
+                    0x2bcd831a5cc5d870,
+                    0x42895abfb3d1692a,
+                    0x2f07957e015d04f8,
+                    0x7b69eeeae2fac476,
+                    0xf19519052d0ed637,
+                    0x7dcbe230862f18f1,
+                    0x1faab8d962fb1cc4,
+                    0x00c8c4726e074f6d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b45b9d4f4c8e372,
+                    0x7a774358de0af53f,
+                    0x4b00e6b016505bf4,
+                    0x1cb1b534438490b5,
+                    0x7bd297bddb20d3ac,
+                    0x4f077e0acf23768a,
+                    0x9723c7f3bd3e04d6,
+                    0xa632a1f36e9c154f,
+                    0x9374bba263d71c66,
+                    0xd7df82170aa5ea44,
+                    0x62fa0d9efa91c7cc,
+                    0x0073bf5fe5115803,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd023ee9af9501b54,
+                    0x11a91f9ec5458791,
+                    0x6329d9f564a6826b,
+                    0x1d5b1b362b8a8c52,
+                    0xfbe78c70a750a1c7,
+                    0x16b50210559dd915,
+                    0x90d92db94db6e42d,
+                    0x6dcbd934d24e6c78,
+                    0x7291eb77a4667d15,
+                    0xc3429e671ffec14b,
+                    0xf2dfde9913bb9c3a,
+                    0x00b5325d217d1be8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x575f737f9da9907b,
+                    0x0654ecd3a45e580e,
+                    0x250b4febad9e73b7,
+                    0xf0c0f435b3943c0c,
+                    0xbbe506c72aa79fca,
+                    0x4c4b974f0f5421a6,
+                    0xb520fec59f0fae69,
+                    0x34b8531c5f460526,
+                    0xd6ace4908a6f8dc4,
+                    0x955bf2ee1205ef74,
+                    0xa3f32151de055238,
+                    0x00ad16319d37fd09,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a69f80c655d9b0e,
+                    0x273fc990110fa6d7,
+                    0x7c97cd88c8fa326e,
+                    0x377e5274106dedef,
+                    0xc9e2f65b94c31465,
+                    0xe67c3e3309623005,
+                    0xa37a22cb2d5ca77e,
+                    0xf586d9f6e9ebf006,
+                    0x78dfb917eb9f9ffe,
+                    0xe0986de7fe989967,
+                    0x7da9639563f8842e,
+                    0x009244ac264a6efb,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x7827e93461f3027d,
+                    0x90c9754a535be30d,
+                    0xacffa6bfa96120af,
+                    0xb7d92a183c349e76,
+                    0xf5492e00531a0f04,
+                    0xc82d58ddc7dc1ec3,
+                    0x742e28953c6bf23e,
+                    0x5e93ef41e7f02413,
+                    0x4a9a791ec14a51fd,
+                    0x0d7abf40f85f36f7,
+                    0xe8da0652e22c6e14,
+                    0x00a13446806425d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1545588281e5462d,
+                    0xd4aaf04955738f72,
+                    0xce986799e3261eae,
+                    0x085293463acdb6db,
+                    0x231bbb075951ee92,
+                    0x6f186b5d542a3390,
+                    0x5caae022c5cf37f6,
+                    0xaf3eaa322cf8aa89,
+                    0x6cec8d69dcbb4783,
+                    0x6e813ff57cc3c7cb,
+                    0x331deea6549184bd,
+                    0x0096f2215115ad11,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2251a52039d42fda,
+                    0xe04c78b1e7a6c7b3,
+                    0xdfcc256ec8e71b5e,
+                    0x48e4396c9fe841f9,
+                    0xaf4f800191489226,
+                    0x00f82ab2db2a4a04,
+                    0x34dcdcb9e00362ec,
+                    0x82d5a37057024f28,
+                    0x87db005f64d136a0,
+                    0x6a881660951bc5d6,
+                    0xee91858e106d149e,
+                    0x00ea84ccebf801d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x446b3d1915b01baf,
+                    0x116f0ee527f0ee6c,
+                    0xe704b88011943933,
+                    0xb962269516395260,
+                    0xf463b19b962c30bd,
+                    0xb9d75eafc5872be8,
+                    0x37bc970f5595146d,
+                    0xe3fc53fd6d402ac4,
+                    0xa529c588ae262f3c,
+                    0x92a9cb4b3f6ad382,
+                    0x553b11002a542b9b,
+                    0x00a95d269b1ef8cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe48bda0b55e0afe3,
+                    0x0d222b083e1906a9,
+                    0xa3c8415a0080c5cf,
+                    0xb6f308d5d4f308e1,
+                    0x3ffee7e590c76b1f,
+                    0x913a5a59c50c7ac3,
+                    0x410c412c89905197,
+                    0x269a0d0c0911c200,
+                    0xf747a49f87352f88,
+                    0x674adf52a8bbb41a,
+                    0xf999d18978ebcc91,
+                    0x0068281af261688f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x734a4599e76e5b3c,
+                    0x9459b735309540b3,
+                    0xd4a8713909c2eb12,
+                    0x96efdae39279ffc3,
+                    0xc090f419b2b54552,
+                    0x6ada32b4c07c62b1,
+                    0x90d5c145ce5dde6e,
+                    0x886be42482d1dfff,
+                    0x9c847652e4155a93,
+                    0x0865a596d001522e,
+                    0x575482da46b19678,
+                    0x00fa186a7ed72ab7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x28e068de5d61121d,
+                    0xb29d6e1fbbc15a88,
+                    0x7d1254737da9c1a6,
+                    0xa9f2b9c364fde227,
+                    0x4fbdd7187217bead,
+                    0x4862e2e4849f8145,
+                    0x7b671327e447f5f8,
+                    0xc89aa8b74accf612,
+                    0xf6bfb381b1202a5d,
+                    0xe42a9dc99a203848,
+                    0x80c7e335767ec183,
+                    0x00190e7c149ef803,
+                ])),
+                Felt::new(BigInteger768([
+                    0x91896592fac41e79,
+                    0x7db0831c5893cebc,
+                    0x801b9bd8cb80b4a6,
+                    0x275a25699ef52ee9,
+                    0xca6ab073693a17d4,
+                    0xde5774c4e8afdb9c,
+                    0xec03d284f4e3deb4,
+                    0x326dd961a8d944dd,
+                    0x8c19d879429d116f,
+                    0x11ec7a34cbc0842d,
+                    0x7f8351d5ffa9b25e,
+                    0x00448864b7d5bba2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22d20faf3b2e39b3,
+                    0x39226574bce7285a,
+                    0x8104ad19c287b38c,
+                    0x7830ba01b5d2ecc5,
+                    0xf543a7320aa4db03,
+                    0x5b4ef1cb713c670d,
+                    0xe4a77372e76516df,
+                    0x5a4a0021eb7644d9,
+                    0x1926671244a527d4,
+                    0x1f342a84bc5efeff,
+                    0x4b5cc55a292fc6bf,
+                    0x0079056c6d502282,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c75827595de34a8,
+                    0xcfb0e0ed7f61c582,
+                    0xc9715fef21c374fe,
+                    0x3d5db1a33c3f6702,
+                    0xcc68ca6e6acc8c44,
+                    0x10ca2a76c9129641,
+                    0x514b37216b6076db,
+                    0x0712dfac1383ad32,
+                    0xbecdbf291406fb30,
+                    0x6a45c2aa85f37d83,
+                    0x51e2741208863681,
+                    0x00b4a5fc00672ecb,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb431fb430030bf7e,
+                    0x78c93246c2da1158,
+                    0x4c9e72c507339e29,
+                    0x6afc635a3c53aeba,
+                    0x7ef1ba5d26d6d06c,
+                    0x370e7752b7fe3319,
+                    0xd8e8252668f429d7,
+                    0x161354a1ebf7761a,
+                    0x03d1ede00040294a,
+                    0xc772799da95f3ead,
+                    0xa7c0075fb4862085,
+                    0x00ca1a2877ef7f39,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf77a2b4b96aa4859,
+                    0x3c45834f7e7ed7ff,
+                    0x2b2bca886c8c22fa,
+                    0xbb30459fe1fdb38d,
+                    0xefd03256948e21a4,
+                    0x948ba6972bbe4305,
+                    0x94c468734a635786,
+                    0x133e720bead69e37,
+                    0x16a221ef8af71a3b,
+                    0xb3a13eb012a6b2bf,
+                    0xa13eafde80f3f543,
+                    0x002ad1ea2dbf1dea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcd859d4c220f7d99,
+                    0xf53fc519ac9cdaac,
+                    0x21b1c0b70df37559,
+                    0xf4b7a45aa3bac3f4,
+                    0x9cc382d616f9c4a5,
+                    0x9e16aed32251d4e0,
+                    0xf2fbe32f01507c68,
+                    0x16d0e8917192b786,
+                    0xa6969d5069f2cec0,
+                    0x22f8a00d3b1c3ae9,
+                    0xeacec25ee724922f,
+                    0x011729e4355e1705,
+                ])),
+                Felt::new(BigInteger768([
+                    0x70543b9a9f3279d1,
+                    0x92b88dad0163b8bc,
+                    0xdd78a7a4daf82aa7,
+                    0x5080e9f61934b328,
+                    0xd5f3e2320bbc7184,
+                    0x88ec45f8a12d1f84,
+                    0x2e6edccd34cd6a8f,
+                    0x823b350aa7ae521c,
+                    0x22362306e5ac8498,
+                    0x6030a50fcb3fb3d6,
+                    0x0022e4599476bc2f,
+                    0x00180f541ea25186,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaef8d12e13804fcb,
+                    0x959e17b96ad638c5,
+                    0xecb2b339d489b216,
+                    0x3624ba01f14301e1,
+                    0xb56690fb36aa71c9,
+                    0xbcce08df6e8d4afd,
+                    0x0616fe386a42a0d6,
+                    0xb9b10d59d628908a,
+                    0x287e522865c00cbf,
+                    0x2cb26bd315708890,
+                    0x20a1cc12b7c474ad,
+                    0x007dfba91159cacc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c39546cf632790f,
+                    0xc58f931ede3cd86e,
+                    0x5d99a7623925dee0,
+                    0x32bcf74a9daae9d1,
+                    0x22b78dae6a2e1f97,
+                    0x12bc38946744925e,
+                    0x57b6a44800cb05f1,
+                    0xac182b001a5f85b5,
+                    0x0fe19b69f29490d9,
+                    0xfbe0c33b35b80377,
+                    0x4c2f7ad1d83fa558,
+                    0x001657780f0a2138,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd67379b5a274ee71,
+                    0x3771d3b050a053d6,
+                    0xd22bcfe3b9a241af,
+                    0x18ecda6eccd76eaf,
+                    0x4c9b94149a29548a,
+                    0x04639e8f4e4638a2,
+                    0xa30f7fa7b17a9e5f,
+                    0xc50f32d10730f098,
+                    0xe71246c225d3a871,
+                    0x9cc86aa57550fc1e,
+                    0x6df99bbec30d4f18,
+                    0x006ab48dd9265533,
+                ])),
+                Felt::new(BigInteger768([
+                    0x34aceb19b32a7e5b,
+                    0xb17cc7ae9231e2ab,
+                    0xbaee0939825d7455,
+                    0x2b9a7683ed03d580,
+                    0x0408aa38354d3639,
+                    0x2e83a569cc5bda03,
+                    0xc5efca2a880c48e4,
+                    0x127cdb4d55a66355,
+                    0x0195c76ba506eb6b,
+                    0x4a40696038518b91,
+                    0x5f103ecdf0bcbe5e,
+                    0x00fcd70251d7b442,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9287453c3719bd56,
+                    0x6ca9cac29c1a69ca,
+                    0xd1d151c7b3501e73,
+                    0x5225e044328b0fa4,
+                    0xa9d6a51eb618c417,
+                    0xccc64600752e14b2,
+                    0x62c0a81a34591858,
+                    0x11f16f785fe5b814,
+                    0x908dd3538ad76f5f,
+                    0xd4ff3c10b3c273cd,
+                    0x99930ba7b98d607a,
+                    0x00896c66500d7dd9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x23e83cda6f562ed3,
+                    0x211147b172055b5f,
+                    0x75b4c4e1a6b9e3b1,
+                    0xc41a47b58144635b,
+                    0xdb9da67a40befb17,
+                    0xc2361f0429ce5ca1,
+                    0xde0f334ee50782c3,
+                    0x329fedb6e9083497,
+                    0xfe700e8a23ad7523,
+                    0x284fda6de447ea9a,
+                    0x10a8139bcf25e0c5,
+                    0x00238316f052a2d0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x1e516b5e5ce9202e,
+                    0x77ba39e478ae5303,
+                    0x92a3cbdd36f8f911,
+                    0xb010e07098b8dc3d,
+                    0xd471ee097119532c,
+                    0x16ccd42f808cfe8b,
+                    0xad928650f47d0268,
+                    0x5692b2fc12edc097,
+                    0x797e7c4b11c4dc0d,
+                    0x2570392f614fc10f,
+                    0xdaf950819a699015,
+                    0x006357251dae8791,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf8cc308e885d4014,
+                    0xa2c5dca06f626df2,
+                    0x9e59c2b9c41d2830,
+                    0xcd97f9294d8062c3,
+                    0xb7fd4e2e3b616551,
+                    0x689f79e79f37eb39,
+                    0x132ce8204cd1d433,
+                    0x613a2f136f8b3c76,
+                    0x480a4232261a1564,
+                    0xa67d4f064d66871c,
+                    0xb02a335de0ffa135,
+                    0x00ff5967cb2064cb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd56a23c1e0dc41b7,
+                    0xf5a48ebc98b7e11a,
+                    0x7eb65ca45052ddb9,
+                    0x328eb8b661b810ed,
+                    0xb858718b5454efbb,
+                    0x303a60e171125525,
+                    0x60e73dae779999ca,
+                    0x404ed395a1d2b278,
+                    0x1153523a331588a4,
+                    0x2241f7ef6fdba4d2,
+                    0xb0c92dc2706d3bed,
+                    0x001cf26b6726d7a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa0ac9d98b876277,
+                    0xba03cb2b6e777455,
+                    0x3c74d321105002dd,
+                    0xffaf5953b6d454b0,
+                    0x6099dc0153c6fb94,
+                    0x3ef6876f7594325c,
+                    0x7eb9bf8006454eda,
+                    0xf25b143d812c14de,
+                    0xb1a866728a5b1e89,
+                    0x3545974a4d9734bc,
+                    0x8a9eeb645cdcfcf8,
+                    0x00a40c154d34cc4c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x868d476aaccac2f1,
+                    0xa886a99e6c714934,
+                    0x9e0b24cddcfe66cd,
+                    0xf38e5968c5fa384f,
+                    0x886c816ca79c2882,
+                    0xb57c1b0fb8d3b16e,
+                    0x853bde99aa15f8c4,
+                    0xc706ac9999db8b51,
+                    0xbfb56318044cf8ec,
+                    0x9a8593f75fa34bbb,
+                    0x5e823551e34b6442,
+                    0x004f89d1f35d55c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x94d409ef6653f2fb,
+                    0xc075a088a66c7a32,
+                    0x601f97b6cdbeb970,
+                    0x7d4aa707df81cb73,
+                    0x59e7f595228c3831,
+                    0x83f745bce66d616e,
+                    0xc25671da33144034,
+                    0x145b3b225cfe0fb9,
+                    0x3d805a11c2eed854,
+                    0xbc7a92b6baf14bb1,
+                    0xf59c02401ef7a3c9,
+                    0x0013f8fecb2a8608,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe0108a6f48fc0a86,
+                    0x5345f77450025bad,
+                    0xe8dde49c629debda,
+                    0xd8902803c3fe88a0,
+                    0x78f9feb5025a53c6,
+                    0xd0fc3ee244d5315b,
+                    0x3b8edcf6057f790a,
+                    0xed7c854d67469126,
+                    0x5a3c8a5dcff3c3e9,
+                    0x2083ce3c28eb9a2c,
+                    0x718abfda0a7f69d8,
+                    0x00668122428d6d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6450bb709cefbdad,
+                    0x0aa613935ccffab1,
+                    0xe90ee32bddadd503,
+                    0xb9d08e1d8f804977,
+                    0xdcb6484eb361e1c2,
+                    0x69789c86ac2da86d,
+                    0x1c76b08fc4a21bd8,
+                    0xebd27294110f7ef1,
+                    0xcec8266b9d862f42,
+                    0xfd8d2c45d5c5d3db,
+                    0xc0ac4a06b940c9cb,
+                    0x00e8b858b8dce9db,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd4880f3ce45de51c,
+                    0xc5d4db462028b561,
+                    0xced39b2a4d2f4394,
+                    0xa2472fec9d0acfea,
+                    0x1ebb5e70651f3d52,
+                    0x230acc06bd1855a8,
+                    0xd701d7bdafb6b4b1,
+                    0xaccaf9403aba852c,
+                    0x8aafea502d90e508,
+                    0xd9a276a85457dfd0,
+                    0x89a203cffdb117b4,
+                    0x002e8a6155144396,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc82bfd49580e4040,
+                    0x9a7168d1993a0fc1,
+                    0x9f0fa0bad731425d,
+                    0xaca9e04832c4d872,
+                    0xa4b64726b9e46692,
+                    0x2fc9a650cb8d2228,
+                    0x6e41b238ea30f64a,
+                    0x6a13be17756b004c,
+                    0xf66e091e251ee4f9,
+                    0x91b0989607ff8f6b,
+                    0x661fd18e9e9bf4ec,
+                    0x0042f4b7eaad0895,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xc69eb4fc6825df0e,
+                    0x9e3c315d682027cd,
+                    0xcaceec4c5ba6ebd7,
+                    0x02bbd9764becd26c,
+                    0x7b3450ae396ebe89,
+                    0xfb77b34bb8b11522,
+                    0x4cce32c53076626e,
+                    0xd5f9896942ac8756,
+                    0xf59a4aa24daf1a9b,
+                    0x652b30b6a48724e2,
+                    0x76b0cb52a8014132,
+                    0x0006f54ad3f478a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x63509b6051039138,
+                    0x09ebada7b6e80317,
+                    0xbfc5c5e0840a00d5,
+                    0x27d9c33d2cacad24,
+                    0xa4b7d11e2fdd9d9c,
+                    0xbb211b6eca74d950,
+                    0x1777ce059d7a0577,
+                    0x904d85721b7758ba,
+                    0x55c803369bc23d21,
+                    0x59a2be1b47fe9ecc,
+                    0x3eb26361c8f0c519,
+                    0x00281ac6870233b7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc0bdbbbea76652e,
+                    0x42b68859174cd6b5,
+                    0x86b8852d65b252fa,
+                    0xf666d19c77793068,
+                    0x6466572ef52a98ba,
+                    0x8c42d4ab91292366,
+                    0x12d22c3cb2625f4b,
+                    0x20d45199d6484797,
+                    0x6ab8eea28835f41f,
+                    0x9f87822e2a1c4135,
+                    0x1728853c713fa4d3,
+                    0x0062385fde331e3b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9224baca94e0b4d3,
+                    0xd1191175824fe928,
+                    0x06e9ec0287e434e5,
+                    0x379d4228c65af942,
+                    0x56a8cfcf8140aab4,
+                    0x5145fcb0de618a59,
+                    0xf330af34900a4c3d,
+                    0x630bed0019afa3d8,
+                    0x186b66fb764e0b9a,
+                    0xb738e0f3dec73389,
+                    0x3de7cec3872e3942,
+                    0x00d750f348dfecf4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2e54a6328abcec7,
+                    0xaebc3c826539eff4,
+                    0x52fb300478540b97,
+                    0x052eec86f99e3e16,
+                    0x3c3ed6b47dd1ec92,
+                    0x2f2db99488b72e22,
+                    0x038595cf59777581,
+                    0x6ab9c949a9d82c52,
+                    0xeb8692a524e1f078,
+                    0xe5f1c1b58012be87,
+                    0x7822930acdbd27d0,
+                    0x006759f9ff479685,
+                ])),
+                Felt::new(BigInteger768([
+                    0x55a66dbbc29f0007,
+                    0xdf11c386f2331738,
+                    0x6cc69e6475e68051,
+                    0x31214d31945944a5,
+                    0xa1a70c22b6eafe9f,
+                    0x45e2de4b71171c17,
+                    0xe47ccce8563ff5c8,
+                    0x0af8f3db916c1dca,
+                    0xaeb020f91720066e,
+                    0xe3690ff9bb378ef9,
+                    0xed5bb0b021fcf28f,
+                    0x00b4a21e0e255771,
+                ])),
+                Felt::new(BigInteger768([
+                    0x35ed31c4eb0928da,
+                    0x76ea44b7fa65cc54,
+                    0x0d0b284290b049a6,
+                    0xf62cafb9525844b2,
+                    0x16e658d6a3d32326,
+                    0xd3fa0631dfa5d5fc,
+                    0xa6c97a7a7be32d53,
+                    0x7f760e165c4df6df,
+                    0x7d353f3e937d1bbb,
+                    0x88772d9225a80c23,
+                    0x1529e40b4749961a,
+                    0x0080a871e9dfbae2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc7b23f1ca2af66a5,
+                    0xeb4524dcb42fa8f0,
+                    0xaf7e9ab3b00fc6e6,
+                    0xbebe8cec22ddb231,
+                    0xb97b9c0e6a3c039b,
+                    0x70e7dc5219de256d,
+                    0xc05e6bbfcffce675,
+                    0x0f648e4c6a73332a,
+                    0xe315e0098e5f5946,
+                    0xa003d9ddd5cadc87,
+                    0x121960a5967a82df,
+                    0x005645d2a3fe6822,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb706d3350ed25b32,
+                    0x422e42432782e957,
+                    0x108d70cf52e527d9,
+                    0xa92f53cc53dd15cf,
+                    0x2f6bc1e2d7864b72,
+                    0x4093e80485bef09c,
+                    0x39181a8bb9650dd8,
+                    0xbd91f0b2354091db,
+                    0x0f2f05f5d12c695a,
+                    0x2be3dcb43c05463c,
+                    0xbb4b903fc0a45e57,
+                    0x0103de68c9619aed,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbcfdf888a10b3ca9,
+                    0xca3dfba82c590614,
+                    0x88678a04a6e91f17,
+                    0xf52572c3fb26514a,
+                    0x9f523fd3c09c2d81,
+                    0x89393e9461622a02,
+                    0x69eb57cec389f8b2,
+                    0x3a928bc8591f4054,
+                    0xdf15e9f755076548,
+                    0xfed7bee5b17f8c21,
+                    0x6a8ee6e789b18d75,
+                    0x00eca0639020c29a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb60bc156b76c2ed8,
+                    0x2b5e3afaf8c10a04,
+                    0x9c8cf94259e5cd4e,
+                    0x6d61a69ab36eeedc,
+                    0xcfa46a7fcb10d0d8,
+                    0x91e0b6f0359fc357,
+                    0x139706c12d16bdca,
+                    0xa01f99d326aec61d,
+                    0xb1db546dccc64f59,
+                    0xdff47024c4de98ea,
+                    0x4d4f470ec4d8b138,
+                    0x0083280eb5bef2fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5967427fc375d4db,
+                    0x7d0e4c4414904d74,
+                    0xe80578309e8fc58f,
+                    0x0068d028c675dc53,
+                    0xeeb72594f27eb178,
+                    0x56fd9e680ad15058,
+                    0xc7a8f4da99961c25,
+                    0x205c2221a69c07d8,
+                    0x546e0c45735c1d89,
+                    0xe267ad77d7fd6cf1,
+                    0x2d6ccd9ad065b6ee,
+                    0x0069d4926023cf5b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ab01534eb4e1e40,
+                    0x8b22ff2f39fd0775,
+                    0x6703bcb4ec45fa05,
+                    0xcc27734669130428,
+                    0x3399e32e11699079,
+                    0xa48abb345de171a4,
+                    0xf388b59a6d6ae246,
+                    0xf86192ac2b63bbaf,
+                    0x29d8e7bbc22d5369,
+                    0x12b0dbcea8ba9a08,
+                    0x0e6288b3c6694fd0,
+                    0x003e03d976aa49d2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0646798b1aafeb2,
+                    0xc2ef83a08b4d5ca8,
+                    0x68a5e78abfe3172e,
+                    0x3542cc9633517e0a,
+                    0x4ea6b79fe60d1192,
+                    0xde3012408fc00b50,
+                    0x594aa6acff390d3e,
+                    0x0ce2b0c6450955f6,
+                    0xa9231f2be906c7e9,
+                    0x7dec61ef3caecd0c,
+                    0xcd019b255be95a49,
+                    0x00074e590a93161d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b029e04edf0e578,
+                    0x1809e9e35d48a6d9,
+                    0x32ae267bb9c054fc,
+                    0x466da312db70ef24,
+                    0xf90704755efc87ba,
+                    0xea2b28a8a1fb50b6,
+                    0xf3232b44ca2b9bbe,
+                    0x203217071c062de8,
+                    0xe60a531b469872b4,
+                    0x1f530bb41ee36154,
+                    0xd464cb28309463d9,
+                    0x005950cd3b92eae9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x48271d8310fd8ebe,
+                    0xa95e8b36668ea0ab,
+                    0xb1c4945d49323249,
+                    0x4d2aafa478c7997a,
+                    0xdff0e4f772f1d650,
+                    0xf62116b63f5a48b0,
+                    0x1619b58a622d7566,
+                    0x080cca2bfbf07fa4,
+                    0xb6739e75ffff243c,
+                    0xa652ed2a072650d0,
+                    0x41d29ec6b488faf0,
+                    0x00a0ccbd5ed73067,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0677c9c569903a46,
+                    0x0e2800d825fdaaba,
+                    0xc38eb82bfce3e38f,
+                    0x61fe60d8ae1b0a5e,
+                    0x9313757abb1ce25b,
+                    0xc244fc12258a84d4,
+                    0x591a2c4a8e697347,
+                    0x4052e8eda9bbe4a4,
+                    0x0fd7403845f842c0,
+                    0x527dd6de24fd7ff5,
+                    0xa914c4c6cba761ce,
+                    0x00b8996ac0893508,
+                ])),
+                Felt::new(BigInteger768([
+                    0x76dafaf5f8684f5f,
+                    0xab2bd4b2d1b0fa3e,
+                    0x203aedfbeade3948,
+                    0xccf53973b26310fe,
+                    0xcf7bb3a85b613c2e,
+                    0xd56ab773b082bf90,
+                    0xcd49fd50871c7601,
+                    0xfcf7dd5b1c76e0f4,
+                    0x45cab5e351d63ebe,
+                    0xa056e0d3a7e6d98f,
+                    0x0a6cc981929b28b6,
+                    0x011ee3d19d40a2da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f64359dad3c21b6,
+                    0x50c6de27cb3fbab6,
+                    0xdcd2f0d30d4ded4d,
+                    0xefec2b6b74ee51f2,
+                    0x905c521553af54df,
+                    0xcd10d3213b2ef306,
+                    0xf3b72a433273d4fc,
+                    0x869fe742ec921733,
+                    0xdcdef074f3db0c23,
+                    0x01239429635a907d,
+                    0xcfb2af6e0e5ca3cf,
+                    0x00557cf62ba148c7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ceebd6b26dcbdff,
+                    0x881976293466759c,
+                    0x9a749b8f3c12190a,
+                    0x0864867b79f0db3c,
+                    0x65c7ecf02378bc89,
+                    0x1614208337897fd8,
+                    0x94aab71ff83a51b5,
+                    0x6a78c56d2951fb2e,
+                    0x0e7e68b69810a07b,
+                    0xea4579e9be28f319,
+                    0x83304f0f859d38ac,
+                    0x00ffe4ad0db8013f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x5cb8bba9635257df,
+                    0xfa1ff8222bc0d948,
+                    0x106c143358dd15b6,
+                    0xec274ada51f5dff3,
+                    0xc8433e74ad404d4d,
+                    0x2025ac1e4a569cb5,
+                    0x7bede137e5cdb541,
+                    0xf0c275a5ced02de2,
+                    0x540153d29ecaf37d,
+                    0x03e97168ae076987,
+                    0xdc414e28d21f1e30,
+                    0x00c9019b03aae206,
+                ])),
+                Felt::new(BigInteger768([
+                    0x95fdd683d5cdf845,
+                    0x92e2eb6789144843,
+                    0x8e431b925faa81f1,
+                    0x624c2821a32abe82,
+                    0x6c864cfb47bada9c,
+                    0xdaad0a3d361c4cbc,
+                    0x0d899c2ec3e96fb1,
+                    0x17db1df5903adefb,
+                    0xa5001fb6e0a44dc0,
+                    0x6b6c16ad23834548,
+                    0x908042d40e087b5c,
+                    0x010c827da956afe5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2ca173c9c5f69e5,
+                    0xa83e35eea5d504f7,
+                    0xfd657c1ec39e81cb,
+                    0x7849b5614fc42ef0,
+                    0x92a72980cfa6778c,
+                    0x6eaeaeea2ea2c89b,
+                    0xf3a71d75215c7554,
+                    0xce5001158cfd38c1,
+                    0x44cc341e22e02809,
+                    0xd1f5feecb54ce93f,
+                    0x3888425d56b533f5,
+                    0x00ba9c98cdbdf655,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8dcf8694556a022f,
+                    0xcce9d12e5d00e4c2,
+                    0x1aea09116fd5b7c9,
+                    0xc14506be905781a2,
+                    0xefa76a6540dfe728,
+                    0x4152256e8985fb91,
+                    0x8ce1a7426a8a1287,
+                    0xe60adf97642079af,
+                    0xe1a466ac9693374b,
+                    0xa8f13eff5ae0735f,
+                    0xbab9e76eebee043d,
+                    0x009d8ac892181f10,
+                ])),
+                Felt::new(BigInteger768([
+                    0x876b7ea1e34279e0,
+                    0xb3922ebd824d8c25,
+                    0x8fff9744877d812e,
+                    0x4bfb35ee65124915,
+                    0x6662ed998ccef2ae,
+                    0xd68d544c48afa8da,
+                    0xbea8348bb87fa113,
+                    0xa15e395db5b21b6b,
+                    0xe2af105042dc2baa,
+                    0xac0b2b1b21a4fd80,
+                    0xfd3b4a584e532054,
+                    0x002cc739e9c3724a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x76e4e3cf9a645611,
+                    0xb6351f5329f56662,
+                    0xf2dd48753a02bcd0,
+                    0x1b5f8af904623c57,
+                    0xab442057475478f5,
+                    0xdf96fc4c97d7895a,
+                    0x4e83fdab37154fd7,
+                    0x20c1eb2ea683ead0,
+                    0x51e99fbf626d48ca,
+                    0xa1c31ed91137f715,
+                    0xd18f6321ec429ed8,
+                    0x008ffdb2fc4b91b6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f87eb6f90dbb2a6,
+                    0x3d4d6e040ae11d91,
+                    0x25f5e5648738ded9,
+                    0xf043dde8b6cc4a44,
+                    0x9ea47cd89aa4e211,
+                    0x29393c701098ae57,
+                    0xa9f7b9a08fbc35fc,
+                    0x881a4ddade729e37,
+                    0xbc3b50c69a70c2b1,
+                    0xdf537c94f50ca076,
+                    0xbef176982ad09fde,
+                    0x01176ff1d518f5dd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba15f5c5e5292b8a,
+                    0x1fac4c0b42d7891d,
+                    0xf44c5faf9cc0cecc,
+                    0xf4777b9f08729883,
+                    0xbb7049450e11f12d,
+                    0x7ebaa02c8ac481c3,
+                    0x3385a7b0991d4577,
+                    0x94d7a45be39c0ca0,
+                    0x9b55dbb6cadaf8dc,
+                    0x98b755135d864765,
+                    0x9e5c0223d77041c4,
+                    0x0107807ddd6c741d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdce9e4a9ff98f1cc,
+                    0x7142d700dc88f3f8,
+                    0x5c8fc503b9b3493b,
+                    0x44c3867d4c034588,
+                    0x55dd85fd9d8cb795,
+                    0x7b95960064ecc5f1,
+                    0xad9bf0a0ffb79352,
+                    0xa63e1b8604438961,
+                    0x541bc559f38cb444,
+                    0xf77ee0d7142dfae7,
+                    0x15c430ef5ed0a5f0,
+                    0x00d83442c23d25b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a4ed0c664248ea4,
+                    0x446bb2b4ecea691d,
+                    0x68652e019c81b541,
+                    0x8c98de87066ed343,
+                    0xfa79b2340383c1e3,
+                    0xa99731cdbecd7b3a,
+                    0x9c4d4c5954b481bf,
+                    0xf459dc87d502e5c9,
+                    0x42b4bef695f4f74f,
+                    0xa375dbd8d01e760c,
+                    0x83b86c298a96e3f6,
+                    0x007134b85a8dbf0a,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x4b0dd8d9987f48eb,
+                0x28f110552db37824,
+                0x71d7d885f2ec3103,
+                0xab4296f63bf6725b,
+                0xe93537d8762c5e01,
+                0xbc879f722c10a3c3,
+                0x4426be8ed0a8f427,
+                0x03d9edecbb9e0953,
+                0x53c8f275446b3855,
+                0x07669988175b4ae0,
+                0x9104c5aec3cc1d1b,
+                0x0048dfda2e75d580,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x35fa7acb4b154296,
+                0x6d77325126b004da,
+                0xce5064eefe25387b,
+                0xda5b7099f71b7fc1,
+                0x0d829eda593815e5,
+                0xc4afbe1b3ae372e6,
+                0x346c731f1ada183c,
+                0x0656b23afe3813c9,
+                0x1a060daa7f7369b0,
+                0x1cee654933f57a29,
+                0xe55e25c546dbedf7,
+                0x00b05d72e319b9e5,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xdf124155b244f25c,
+                0x0aa41e9f62ec3741,
+                0x64156c5b1b70afd7,
+                0xf67a0cddb4c21943,
+                0xd65cf745f22f3b20,
+                0x6749f59f0a1dee89,
+                0x4168d5d64b0c9b57,
+                0x95102c5c8c9cf0b1,
+                0x0f89e4c6f3581414,
+                0xf279730e889f418d,
+                0xb3e44c1af7199f37,
+                0x010f60ec17c2f7a9,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb97de355df906b37,
+                0x209a57924ac8c370,
+                0x5ce61d348c01d75e,
+                0x84ddac0d543973aa,
+                0xb968a142d3dc52ce,
+                0xec3c495b6cb32de9,
+                0xcf0f216480a2bf84,
+                0x8535a8b3394a319c,
+                0x8e20ed9418fb71e4,
+                0x9d36eaebaa0fd024,
+                0x83f30ee7cf95db0e,
+                0x00dce0c4cee1202d,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x9c3a3025aa768a88,
+                0xea8a47cfef82662d,
+                0xede5d11f1245aa82,
+                0x7a61c4f903b1f5d1,
+                0xb1526bfbec0714f2,
+                0xadeaa09d3036be59,
+                0x5b62e68ac8f1e3a1,
+                0x6536bd2aeefdde92,
+                0x5b07d70b575ec00f,
+                0x6559b4510a852661,
+                0xeca8c55d0d791dcc,
+                0x00feb10299e416bc,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x24fc6b5110536d9d,
+                0x7bb5039a1bccd48e,
+                0xc87593c10d8597f4,
+                0x765ffdf319b591ec,
+                0x183f62f5b26e5541,
+                0x8acae3f60a4553cf,
+                0xf1b1e4d8fbd2168c,
+                0x6280df15598422f4,
+                0x4e745eaa2ffbf459,
+                0xec92c3480d28f618,
+                0xbb30bf396758c533,
+                0x0110ae4f9fc686da,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe14f139ea9ded267,
+                0xa4d79063496fcf59,
+                0x95b80694f3a4b549,
+                0xdc6c70aaa33515ab,
+                0xc2e719563383c0c2,
+                0xb7976c16bc6d2860,
+                0x620c9837da4cd00e,
+                0xca3b6aacead794f7,
+                0x45a2dab3156284a5,
+                0xda6932a2d24d3048,
+                0x06c54af09f21e720,
+                0x0038854e162ebac5,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x539a75ec530d4992,
+                0xb8634c4ab1790034,
+                0x3f2ffa2f72cd5e46,
+                0xe596caad97144dc6,
+                0x01054bd60c92767c,
+                0x025d697052dc6254,
+                0x15462dbf2bc2191f,
+                0x6c26e693620a1931,
+                0xe8f87f9f905ba8e7,
+                0x5a462ddee55f8c5e,
+                0x7b1d3e421c76ba7a,
+                0x00f96e010b0bf3ee,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x07ddd6c0e32c31c6,
+                0xc50444439ea21038,
+                0x7ad64973021a34cd,
+                0xfe6b7da8d528cfd5,
+                0xe6d1b02ce61c3228,
+                0x78cbebb6813ed28e,
+                0xde8c9e2f4ebf7567,
+                0x2707c08e2ea59267,
+                0x25dac4d7e66e9e68,
+                0x2c6ef048dec5f6db,
+                0x02606f086061b898,
+                0x0091d20add1e5899,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf46178f680e52bf9,
+                0x683e252c4c09e002,
+                0x7ff12a7c7db5fc4b,
+                0x94996563d6d5d989,
+                0x344b8989c652c363,
+                0xb0944f25b4070b61,
+                0xf0cd70a65acd845e,
+                0x2e5768bb137c3c1a,
+                0xcea4555dec454441,
+                0x9e8acb7981a433df,
+                0xe93fd772670d577f,
+                0x00ca51828bf2a1ed,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 855, 856, 2570] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 855];
+        let mut opad = vec![0x5cu8; 855];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
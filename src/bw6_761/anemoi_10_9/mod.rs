@@ -0,0 +1,4545 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 960 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 9 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 9;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 1 element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x137e813ed25f43ce,
+                    0x47519086703078f8,
+                    0xfb771bd044db6538,
+                    0x845ea16657022945,
+                    0xdf155d3e609f1560,
+                    0xd5660dd50b94ac4d,
+                    0x1290052332c88920,
+                    0x05de170a07ae1c15,
+                    0x4c9fcd18d7bfa5da,
+                    0xa96bd4ab1a65e102,
+                    0x4705706157739be6,
+                    0x011a740359e625d7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd32b8bfe20fd4288,
+                    0x0bc715b9e4ad32db,
+                    0x5426b7d33912b302,
+                    0x1fd81d57e823e2ae,
+                    0x819bed5ccc44e1d5,
+                    0x462e3eda1d200f43,
+                    0xfaf0d5242c4a6737,
+                    0xad7b590c5700a8b3,
+                    0x8a97de5044c6248e,
+                    0x971677c51eb287e5,
+                    0xf8920dd6a8e8846d,
+                    0x0029b0ee5f677168,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f4f66a6b4997eb0,
+                    0x6a6b36c2bbfce276,
+                    0xb513bdb8dc9b6ee7,
+                    0x0824230b401cbbcd,
+                    0xf11fc8ba444985dc,
+                    0xfe930210391943e3,
+                    0x698c47cb0910e404,
+                    0x30304547375e804e,
+                    0x1fd169ca0db9395d,
+                    0x7b34953f17acca9c,
+                    0xa6e4454b3b2fb189,
+                    0x006a86c75c733ecf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x106436709fcdc61d,
+                    0xb896aa84edaf825a,
+                    0xedc84cc3318a03a1,
+                    0x3435fbb6556c5c29,
+                    0x68fea7d6bde9cc21,
+                    0xb90d128b48ef9837,
+                    0xf1c33f016cc75f13,
+                    0xa41a81fc2d8ecff8,
+                    0x963dd44a3d87a41c,
+                    0x1c6ae2827b3058eb,
+                    0x87091e502cb65fb8,
+                    0x003557b63e41bc40,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51f8d440c1030b2a,
+                    0x9b0b43bf65eb253b,
+                    0x1639c8cb6b5951d1,
+                    0x1519929e96b48b46,
+                    0x94c7ede99ecae210,
+                    0xb1981fa7f4d8ad5e,
+                    0x0cb8a4f60ae347aa,
+                    0x7b56fed052a143a9,
+                    0x82319c27d571d4d4,
+                    0x6df22d777e50a500,
+                    0xcee1920cd36feda8,
+                    0x011ac4cd1fbcc138,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbad4fc2a5ce0e882,
+                    0x688fb7c9f3acdb32,
+                    0x9b9cbd8e253097af,
+                    0x20db58b479a3299a,
+                    0x566042939fa4c909,
+                    0x515c2b32e707f0f4,
+                    0x0dc78e1f635fda74,
+                    0x60402f8500986f01,
+                    0x9b682618285f0089,
+                    0x8fe0ac31c190a293,
+                    0xdfa3db6c623fe52e,
+                    0x004e8b7e1cb4e263,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa861534cf0eea027,
+                    0x2e05f3818da56ae0,
+                    0x2704e7bb9d56183c,
+                    0x63db62620e9c5fd4,
+                    0x82b7f58714b79b07,
+                    0xc2723c40c757ef6f,
+                    0x2c542d2cfb396aa6,
+                    0x4bdf2d850f3bd791,
+                    0xca0b73b9b267e433,
+                    0x703abba15f069d75,
+                    0x0120c2a45e09112d,
+                    0x000b462f4ea293ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x139a22b222c70786,
+                    0xd34e087abb8942f7,
+                    0xbe51c73202558d26,
+                    0x7467c0fd5b961c6f,
+                    0x194e300b37638ca8,
+                    0xaa2d1243f38389ac,
+                    0x84faea78de9ca6f9,
+                    0xe9fbac181884771e,
+                    0x3e06f25328238866,
+                    0x7666236090e10c2f,
+                    0xf013f0ad3ebc3495,
+                    0x00fa273a150ab778,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9582a211fa84ff84,
+                    0x3479f4ec5fb15b00,
+                    0x85e3000b4dad6258,
+                    0x9bcaf9aafb7889a3,
+                    0xfa2e560a2f087cc8,
+                    0x898bb356d0769cbe,
+                    0xf41126758d80ea51,
+                    0xc1ab655b8602c1a3,
+                    0x7359fc0b0c0b7d71,
+                    0xb3ef4d9eddd5314f,
+                    0x228f7155609a67d7,
+                    0x01186c9aaecc4860,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36e5eb2dbec01450,
+                    0x6760ce131f1f0ea2,
+                    0xb23db5c15aecf78e,
+                    0x3885604a64afd9e3,
+                    0x72fa07fe60a9b229,
+                    0x624f1840150c91c4,
+                    0xf37a8ff92ab5f897,
+                    0x5b0447fe3d0c2fe6,
+                    0x858deb1b4a7497c0,
+                    0xa6d4224e31c98430,
+                    0x536e79b1f3a1175a,
+                    0x00bcdd6a5de9d58a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1cc543bbf5dd3fd7,
+                    0x2cf7868d050a8a07,
+                    0x7977ebf612e1f330,
+                    0x152e239f13ae8e45,
+                    0xffe48192b99d7d31,
+                    0x2fc2842ea8947250,
+                    0x7850ee9cf7b5d057,
+                    0x66fbaa5c7181ca0b,
+                    0x0ba1fc36254c88dc,
+                    0x5288f5fd8de87139,
+                    0xdfb00cbdd8a5fc4e,
+                    0x00fbe9d520093cc7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2989addf9ed1b3a7,
+                    0x54733ea96b572f10,
+                    0x3f578f32673a1d96,
+                    0xd28b700d28130d85,
+                    0xd09fc49ff9ced03b,
+                    0xf5627653962b049d,
+                    0x874aa1f60a12a188,
+                    0x448887f871dc7841,
+                    0x7496f6eefea3b041,
+                    0x6f5f9d75e2c1726f,
+                    0x30f79e1f282a3084,
+                    0x004d9c921689a153,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5e14ebdaa400f28d,
+                    0x6d007d440bf19c0e,
+                    0xfab9cb90772e1299,
+                    0xda091120b36da603,
+                    0x8f5397944184a228,
+                    0x575043e281c95c4f,
+                    0x6a23fd0d2206e0e5,
+                    0x225870e62ca16f79,
+                    0x08d6b23118647d31,
+                    0x67485d9d1c2d74bd,
+                    0x4fdc9453e581a4a9,
+                    0x0062870087ef54d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8abf28b2b51ce0f,
+                    0xc1464539079da5f0,
+                    0x4375bfb892f3d280,
+                    0x9a8d952e980d742e,
+                    0xf83437fa69e1aa78,
+                    0xa14b680b4c968a49,
+                    0x57e61e1522360b86,
+                    0x5fc8eef71563059c,
+                    0x857a65e6c2dc04b4,
+                    0x5704334f58c9b50f,
+                    0xf3bd7f4be0491c59,
+                    0x00673857b97317e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc63efb3bfcd99376,
+                    0x379caee07a0e4967,
+                    0x81fae74be1452041,
+                    0x652b42109a4c2f20,
+                    0xdd2fd29f6c50a2f8,
+                    0xfdfa95baaf2ec7c9,
+                    0x0a4c1798d9831321,
+                    0x6d3ca9c2e1b12f97,
+                    0x83b7132317535199,
+                    0xb6a631f2f1817964,
+                    0xd52e0c71f52f04cb,
+                    0x008e7b18037d02b6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4596660a37947253,
+                    0x56cb5d2ad05c570f,
+                    0x6e6430a85cb6a746,
+                    0x927b625c5eddf494,
+                    0xeee648b9644e040f,
+                    0xaa23ca0d0275aa4f,
+                    0x5db47fb7ae4de9b1,
+                    0xceaf5f51feee7cf3,
+                    0x992091067cec85f4,
+                    0x632d33d10bacdc5f,
+                    0xe9fb5709d418dda0,
+                    0x00ba32bc3fe35853,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcae17090d4fc7e1d,
+                    0x0a9eb829fdfe1433,
+                    0xa198c45b1e4ab4e1,
+                    0x29be2421c8c3901e,
+                    0xb55161178e553f07,
+                    0x66214566e479e5ee,
+                    0xa9d9cce2bb79aa06,
+                    0x9fafa7e08e28aa38,
+                    0x2b085e7785eefe44,
+                    0xc55a0e6520ce5da2,
+                    0xa441cd9b43f914b5,
+                    0x00dcb3afb620b7f6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40cb360d8deb8dfe,
+                    0x6d8c9647859122fb,
+                    0x3f9866908bb55689,
+                    0x9cf4928d77de1d62,
+                    0x59fb0fbbbd728670,
+                    0xb1b5ac9bc9e20e91,
+                    0x46a1eaca8718da66,
+                    0xe17b780d49090371,
+                    0xec7d7bd48a47d158,
+                    0x320d1d7bb3872bcb,
+                    0x03c3022b3b970fb7,
+                    0x0024a91543c7af9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6e9d494170645b6a,
+                    0x48bccdebccf537dc,
+                    0x4fb5bc62a883ca0a,
+                    0xd9225369a04faf1e,
+                    0x732a2d983d29e8b3,
+                    0x493e65bca383b8d7,
+                    0x051c3dad88cee74d,
+                    0x366ae9bf0e85f1b4,
+                    0x6b7216a22097218d,
+                    0xd0a3d1fd5755bc7f,
+                    0x7877c3defd83c7b5,
+                    0x001595cf77acc33e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x74297db01e481258,
+                    0x4ee5ea3b4ebb55eb,
+                    0x5c49524fcf9b0fdb,
+                    0xd26574026a988e66,
+                    0x008f73649fb845d2,
+                    0xeab3a26af56dc05e,
+                    0xac8b4099f5cd5432,
+                    0x485a9d5eee9e8343,
+                    0xc8debc9b96c13b7f,
+                    0x57e7d5da88128efa,
+                    0x3b34055263986ff9,
+                    0x00b21b028a7ba7e8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3661bdf702cc7529,
+                    0xa322066819efda02,
+                    0x2af8dca99dc602aa,
+                    0xc7d22574c36be339,
+                    0xf18a7201924c34c3,
+                    0x4930ebf43b2fd916,
+                    0x19014d995abef7da,
+                    0x183cde04701a71a2,
+                    0x4425114c53d8791e,
+                    0xf8eeff1291e7a3b2,
+                    0x69a434f9796ef867,
+                    0x00ddb408b597a7ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd61655689155f39d,
+                    0xfca83ca453a33cdc,
+                    0x34c76bb26f5f19e2,
+                    0x14e1f83034533533,
+                    0xe8954494bf6e4221,
+                    0x3f452f2a63081f2c,
+                    0xaba1ba6e6f402c1d,
+                    0xc6cd87e903698483,
+                    0x5619ee1b8ecc954c,
+                    0x94e0a2fc703df580,
+                    0x592a9290801c0960,
+                    0x009d0fb5f616873e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1efcd4ce8388179e,
+                    0x45f6fa2bafa52dc9,
+                    0x6a434a30636b27b9,
+                    0x645478f04f58e13a,
+                    0xa661f772b2b72698,
+                    0xe29b8ca9b261aec9,
+                    0x8c9b1081a822baa1,
+                    0x6f444b9a039cae28,
+                    0x6b54102d37c54423,
+                    0xb4d4cc23bcbae51b,
+                    0xbce0efa0c8240f67,
+                    0x002f46ffd638c5c7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4985b6b6c0f9818,
+                    0xed5773e8695907c3,
+                    0x140a0d7546a55286,
+                    0xdb573b9d64ba97a0,
+                    0x3f3c71fb5db2f9d4,
+                    0x75d3fde82187fdfe,
+                    0x35072eb0acc113b5,
+                    0x12705d6782e2f9e4,
+                    0xc4e65acd70648153,
+                    0xad0d29b35f153261,
+                    0x68bb16ec54ec0984,
+                    0x00154062a9b9b880,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4206223fd049d3d3,
+                    0x7a9c444a833a243d,
+                    0x15fe4694d6eff3ba,
+                    0x27d7faf13fb0bca3,
+                    0x65a945a7b4db90dd,
+                    0xa8e9058e2ae1ff41,
+                    0x0590364edf181052,
+                    0x9448f35969c74552,
+                    0x5a8401d0e9b8b632,
+                    0xbead5c8e87c9ff00,
+                    0x564b4fb2cf00db77,
+                    0x00cc7f67ed81ce40,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe10bee2a35d5230e,
+                    0xb7c0147d8c1f75ed,
+                    0xcee891da93082f34,
+                    0x63962d6e5b9af77a,
+                    0x6d60ce25d292c3a3,
+                    0xd75b631a9ca2d5be,
+                    0x4a5710c21f320e4e,
+                    0xa99810f81f55aed5,
+                    0xdee476c24147ec75,
+                    0x2fe8880fe9fdc8dc,
+                    0x688eff52736b4a98,
+                    0x008b82d8f97503e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdceeafe355cc34dc,
+                    0x3fb0d3e4f1dfa40f,
+                    0x263c1e4baefaf935,
+                    0x693b0d9698f53109,
+                    0xec8085b4d9306b1a,
+                    0xd81e0c547f945232,
+                    0xfbe355510e361f9c,
+                    0xd3a50f100ea13084,
+                    0x7818127469ff8292,
+                    0x0811080ce56bcc40,
+                    0xdb700edd30dc8674,
+                    0x00773180dac819b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5a0068c5cfd94e0e,
+                    0x6221113016be8752,
+                    0xc6feb91e95cc2868,
+                    0x19bd61b7a31cd83b,
+                    0xfdb57518810f8389,
+                    0xf090a942bd18c11e,
+                    0x6e8d9fce6c786568,
+                    0x893fe66ac65b1a12,
+                    0x137e89e4ea37dc1b,
+                    0x242971bc31f821f4,
+                    0x22ffe4779d20c6a4,
+                    0x005b018d7cc04b9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf87cd4ca07c47b5d,
+                    0xa330f2547939f412,
+                    0x6baf3acf948286bd,
+                    0x856385fd97d5989b,
+                    0x58eedf7260e6ca43,
+                    0x923b7b3f4ba3f85d,
+                    0xec4de07e3136f8f1,
+                    0x03f6f52302ca7903,
+                    0x2bf3fb1770832d99,
+                    0xfdda539a833cfc0b,
+                    0x63d45dd45bd988e4,
+                    0x004f986888d52497,
+                ])),
+                Felt::new(BigInteger768([
+                    0x326b92972f0ae4a8,
+                    0x50d7a8a889d09130,
+                    0x2227818bf06b9b39,
+                    0x5c1616baf417b5e1,
+                    0xb174235415d29098,
+                    0xaf15f3bded69b822,
+                    0x14093338fde1c2ac,
+                    0x541307c32246ee3c,
+                    0x9a07e206cc701d0b,
+                    0x5e98110b8c190bc2,
+                    0x7285d9d37575120c,
+                    0x0062271fb1fc1080,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x404980aa122d6b9e,
+                    0x6412054f10417ba1,
+                    0x228f01cef48b92e1,
+                    0xd6fbedeaa91fd258,
+                    0x93678db26081f09c,
+                    0x3fd3fa9f8cb61fc2,
+                    0x0f00b50b48b29501,
+                    0x6ab31c53904265c6,
+                    0x148c99d69ca5cd08,
+                    0xda874fcc28bf9499,
+                    0xce4a89183e76be2b,
+                    0x003e886d62cb5e88,
+                ])),
+                Felt::new(BigInteger768([
+                    0x00429e76eec85cdb,
+                    0xbe4d2b950ebd4518,
+                    0xc862d6b0216b4ee8,
+                    0x90ca429f0f774fad,
+                    0x91caa29002655b47,
+                    0xa760c4c78ba6922c,
+                    0x4f182e9d8b608fdd,
+                    0x84f982494c4e1cef,
+                    0x1a4d3dbc7c6736ae,
+                    0xaad720a9e328f114,
+                    0xee25d3d447193078,
+                    0x00c9a3a4f6531d4d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9007e713131c317b,
+                    0x94b47d71ef19194d,
+                    0x5161d0f4faff79e2,
+                    0x46656695737fda23,
+                    0x44be0a23c63fab6e,
+                    0xca5370c95beb07b6,
+                    0xe9d0c050135ceb2f,
+                    0x0f928d8840045a4a,
+                    0x4f2e73ce657c671f,
+                    0xb6d923e17bb06d89,
+                    0x1e7e4771bba4d5e4,
+                    0x007242d9ca70a8c4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x776f1cf12247147c,
+                    0xf64e7ff4dbd0c3a2,
+                    0xd1e010630a440593,
+                    0x69642433302f3237,
+                    0x8f79acb9240e3167,
+                    0x58c73aa792398a56,
+                    0xf453fa3254ca63c5,
+                    0x22677c34657ab5e3,
+                    0x0396667a1f1fe28f,
+                    0xd091b80f8b2a617e,
+                    0x4e85a29321e03cf6,
+                    0x0106973eb5e29fdf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x92ea3de686d9141e,
+                    0xe0d1c0ef817bab12,
+                    0xc0dd0d8c884586ff,
+                    0x1ef5536db88c8217,
+                    0x00efcda965629063,
+                    0x1e04bba4d789d41d,
+                    0x0e15c0e818cc1cb7,
+                    0x138db66a35d51555,
+                    0xd0366be374f65056,
+                    0x13c116d190623bbf,
+                    0xa2032c21ddddae57,
+                    0x00a490d138f279af,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5a339cc1b4a98661,
+                    0x9aab208e8afbe255,
+                    0xe7315c4345f48f53,
+                    0xd78f2f20ab38bccd,
+                    0x1ee25107cec181ca,
+                    0xcf4e41299dcd5185,
+                    0xc1771daaecadb6e9,
+                    0x513d1b48a69b7cc4,
+                    0xdae9334a950a6c9e,
+                    0x50dd10bc07872184,
+                    0x286d7011eb910927,
+                    0x00c5fd832874b0cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x39d01599ba577dcb,
+                    0xf4cd63d244f08253,
+                    0x59a0a0c80fd27d18,
+                    0x83c00dc168f423f4,
+                    0x271a9ce8aa0d8279,
+                    0x209914e4336fb05b,
+                    0x05ed5c7369f82009,
+                    0x509b232254a737fd,
+                    0x6c43994587ec0db6,
+                    0x2ab7c621a76e3f40,
+                    0x315a28e366637ddf,
+                    0x00e15bcbe389c5e2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdba43a6352f58b66,
+                    0x3d4004e0a98f1141,
+                    0x6ede27f4544fbb4d,
+                    0x8beb79ccc5a463a2,
+                    0x887fc78827fc6af5,
+                    0x625740f9a41292db,
+                    0x37d8fad62fe671de,
+                    0x42e98ffc6d4b6502,
+                    0xbfc05ac0ecaa57e8,
+                    0xd05ab51dc671dd78,
+                    0xe9a8f54486ffbc79,
+                    0x002d497a1d811eef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19b23ffbd9c4f4fb,
+                    0x761dddf9a1924e35,
+                    0x0444745bc84c6f31,
+                    0x8246f447c9c495bc,
+                    0xf4b7da512f164f07,
+                    0x33c16bfa6af55fd8,
+                    0x13d70979b4c740ea,
+                    0xcbb96038345a562e,
+                    0xd76ca6f65d15cc28,
+                    0x19c2cddcfd6f45b9,
+                    0x9f78e15ba7695c51,
+                    0x01184d5afc696b78,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8944dd1f30a31dcd,
+                    0xaa31c8afc86ca454,
+                    0xb4e976e15b5b0975,
+                    0x775bf30b06a3c2ff,
+                    0xecd99771a75ce4af,
+                    0x71d14875061d1840,
+                    0x77f66d4cf91d714c,
+                    0x37012c0301e0b5a1,
+                    0x578655c443ffcdf9,
+                    0x43884f3a2807c2a9,
+                    0x518f804b05ae0f90,
+                    0x00d1fc1d3f7c2c42,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x64faa10bce91961e,
+                    0x4dbfd64188b1a297,
+                    0xc2a768ddd5966d0f,
+                    0x5d2d0e241c11dfd6,
+                    0x6015b22ccbf94e42,
+                    0x87a202cabac4b20e,
+                    0x5a8859c5ff9d2054,
+                    0xa51b0b9320724b53,
+                    0xb521ff94e172752f,
+                    0xf41a1a31b76ac5bf,
+                    0xc7a518c9b304aebc,
+                    0x0051f03dee03526d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb787902d92ba270e,
+                    0xd6ccea1460cb8d8d,
+                    0x36c7d0824e783b4f,
+                    0x13a4d156f7a61b5b,
+                    0x97aae5346f8af240,
+                    0x82043c359d3726fc,
+                    0xe78d9dc714332ec8,
+                    0xc7daaff7d088a517,
+                    0x18eae945afd876e4,
+                    0x47f999cbce38b92e,
+                    0x7e9e4a773ef98b89,
+                    0x001ef2c9233e0348,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf545572eaba40f25,
+                    0x4178da5dd1b3d4e9,
+                    0x58454c7ba1ca3d8f,
+                    0x29607c3a1f66a355,
+                    0xc1e124b82fe0a9f4,
+                    0xc6c5f046e01a0de2,
+                    0xc5e4280048701bdb,
+                    0x5a7fdf83397a3ae7,
+                    0x24157aeddae6f16e,
+                    0xf31729023ce678dd,
+                    0x0f8f229fd026ac3d,
+                    0x00247c277967ff99,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7e8f04104e3d5393,
+                    0xf8dc8719fb1618eb,
+                    0xcb4337691c58a044,
+                    0xc5824f36d9d6b772,
+                    0x9671475ec916274a,
+                    0x1772a582974275fb,
+                    0xd48baedd1101c55d,
+                    0xb42142cdeb56cede,
+                    0xc89269bdf4676937,
+                    0xec731cbb289b14df,
+                    0x3925900d341d68cb,
+                    0x00c0e7604c9a38d3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac6d6ed40396b226,
+                    0x9d9e459b303ec8da,
+                    0xed726faa577f6a7f,
+                    0x3f86dc433c8117ac,
+                    0x25b424c9ab128af8,
+                    0xb2bae1daeb6dc39c,
+                    0xf06538b791f69a14,
+                    0xc7e82b06e8ce7f18,
+                    0x8d5310b13f872b53,
+                    0x933440474425810d,
+                    0x98a5fef97155b4f9,
+                    0x008b5b209f4704e0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2892ffa49469998,
+                    0x2f9c441866e216c9,
+                    0x7e22bb4d8ee00013,
+                    0xfd9da82410db365f,
+                    0xf45fe68286217b2e,
+                    0x929043fa39d16e22,
+                    0x4214fbfe7e721daa,
+                    0x017d6ee799aa0684,
+                    0xdcea1ff0def338c0,
+                    0xca9dc752e4716a80,
+                    0x6576d907f2375821,
+                    0x008dd6f3bc90d677,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7276cfb209b2ede2,
+                    0x4d5cad4ce96953ed,
+                    0xb2c75e646cfba68f,
+                    0x2d681afee949094d,
+                    0xb908416c2274cdf0,
+                    0xf0c542884e92cbef,
+                    0xdc756aedcccc17ab,
+                    0x0cefe4152b219341,
+                    0xa22d36b33844c436,
+                    0xf2cac049e76df358,
+                    0xdbcc88551422f97c,
+                    0x00e3a61b7377d7e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x17237f9829aaeb53,
+                    0x695c82800ba9de7c,
+                    0xf40c0cf79429424e,
+                    0xc3f6ffceea6a6096,
+                    0x3469c2f6896ba87f,
+                    0xb005c8809d87df9f,
+                    0xd3958cba63b5f67b,
+                    0xca0268f3a845142d,
+                    0xb2384e68157a2102,
+                    0x4ca5bae330d2d9ab,
+                    0xc53e89dfdca5a2e6,
+                    0x00434501c1813e45,
+                ])),
+                Felt::new(BigInteger768([
+                    0x25bf6ab0d38d2604,
+                    0x463d33beca877755,
+                    0x03a5ef247345cfc0,
+                    0x3f0bb54ccea2d9d4,
+                    0xe810f46c3d6c8631,
+                    0x8a1c6bee54e1f665,
+                    0xc3bcdf2ff3e691fb,
+                    0x1d25d78b37c2d0b5,
+                    0x0ccac529460d9f6d,
+                    0x8f03e8bfd05b007f,
+                    0xae7a9ce8174c9b9d,
+                    0x003b679b462e103b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc2823930907cf17f,
+                    0x31723dd5fb38fb99,
+                    0x3eb553ee82f2ec4d,
+                    0xeec36d729ccc7414,
+                    0x681b9e9783b94048,
+                    0xda1671e680713f03,
+                    0xc3548d5740663d26,
+                    0x8da641a46ba25036,
+                    0x38bdbc583a04ed79,
+                    0x5b302801d1e5be89,
+                    0x6faba34c27dcac8b,
+                    0x001b1579bcd1ed3c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc0d39c759df2d1b0,
+                    0xfbdfd30738c7b921,
+                    0x1ed7e0fec4c97094,
+                    0x9b33c9dba5da1a9c,
+                    0x0b67117885c5dfc7,
+                    0xd1b8a89c218a4085,
+                    0x22adc71ec415c255,
+                    0xf1568425a98a9e71,
+                    0xdb5c3322192fdc27,
+                    0x424d0042e0835bed,
+                    0x276d2bfef460809b,
+                    0x00459f5c68cf31c7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xedb6dd4b21e663f7,
+                    0xde27d7fee6da465d,
+                    0x856680d872d79a2b,
+                    0x917f3cab2b57584d,
+                    0x8968acc42f8cb9ac,
+                    0x5a227eef412cdce0,
+                    0xa28cea11fe8dab37,
+                    0x4341271504d4f2ac,
+                    0x3d9abc7a5bccc7c6,
+                    0x186e019a853f7334,
+                    0xbc847af25b01c46d,
+                    0x00618aa3fe82a86e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaee948de5a4cb2a1,
+                    0x59a12c27953b1290,
+                    0x940ec7c60b5a55a2,
+                    0x8e2be112317941b6,
+                    0xab00a335cea682a5,
+                    0x737ba80c3ddb1940,
+                    0xb5a9d2de283e426f,
+                    0xd33884155210e2b8,
+                    0xd84de46f1a487b06,
+                    0x2bd59a033b72e65a,
+                    0xcc95ddcf82b52133,
+                    0x0077d6185ac5d6fe,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03baa625b8839b33,
+                    0xb4d1b5d60eace6d4,
+                    0xc4db95ced5418188,
+                    0xbbfe91e545c4312a,
+                    0x66192ef7605fd531,
+                    0xe9e45e9d262dd9cd,
+                    0x09fb2afab62fd16a,
+                    0x811c8fc7c748854b,
+                    0x151bb7792d55fb42,
+                    0x4b1db72f34be4aae,
+                    0xe7c6733af18a9e97,
+                    0x0062a0fe9d2eaede,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd061763646190bb2,
+                    0x5f93ab38da149904,
+                    0xa6e725323090d4f1,
+                    0x3e7be9429dd3b3f4,
+                    0x03eb6912ce2d5554,
+                    0x4169ac619cf10856,
+                    0x9cb973fc93ad9115,
+                    0x4c6dfec5894139b7,
+                    0x6a3d420ab3bacd27,
+                    0x59a984ef8b13aaa6,
+                    0xe0b311934b20ea5a,
+                    0x0061ffe1c94e639a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18aafd4d6bd97772,
+                    0x166ab84e4d108300,
+                    0xdd378114ae98d980,
+                    0x98cae60fe150a20e,
+                    0x1db64f9d3157352e,
+                    0x38f8c530f8208996,
+                    0xcbfbab13e8481563,
+                    0x095e75f2049e6cf5,
+                    0xf51eec42297acc29,
+                    0xcd2ba70054d1d30d,
+                    0x340cd3a08f22ef8c,
+                    0x00947da60f4c8ec7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e76990a60546f96,
+                    0xa6822b1e431f7d42,
+                    0xf0726af3e2d93fdb,
+                    0x622685049247c45b,
+                    0x2a0d6fce8163ab28,
+                    0xe54544362661fe2c,
+                    0xe3209c4ea946f9fa,
+                    0x4c3bc6e1ba1608cf,
+                    0x4aa8231fbfc9cfe1,
+                    0x2c751673cfbe7a78,
+                    0x1fff33f6d87bb5e7,
+                    0x00579e72e4bdcc22,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b55ed800bf5de20,
+                    0xf86479f6960668b9,
+                    0x0ca39953bd74b826,
+                    0xdf57f54162ea2783,
+                    0xd055b406163e2175,
+                    0xf0d9aa0a92679d52,
+                    0x1e4ec4a0be05aff7,
+                    0x1bab2f83e001a579,
+                    0xf3a574069b3e1393,
+                    0x77d8781a0b4fdfbf,
+                    0xd397d1a17d5e2c05,
+                    0x00cc43949cf98277,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe43526e069f230cd,
+                    0xf19c09737af9ee22,
+                    0x054db0eb29717500,
+                    0x87bf219d24bf95f4,
+                    0xbf1a803c20eefc03,
+                    0x9fcc1353093e94ed,
+                    0xbd247d8b2cc45777,
+                    0xcb18a0c042d98c1a,
+                    0x2ca01d9609bd345d,
+                    0xa79583501c928615,
+                    0xcbffdf01a6de3828,
+                    0x00a3660abced6644,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26ea152fd2b43c58,
+                    0x661dff5ac112ca09,
+                    0x7699ca6529fdb308,
+                    0x22cfaca75bff7438,
+                    0x4c61b4692b1fc3af,
+                    0xe7ec59022f921642,
+                    0x95076a1c1ad6eb6c,
+                    0xccf381781f1a04ba,
+                    0xe373b3957eb54cc4,
+                    0x9968d7be2faf5603,
+                    0x0a536caf17de708f,
+                    0x002c9e7f9ec88cd6,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5e939f7f9347e4ef,
+                    0x1462ad51f7a6073c,
+                    0xc535f7c9016ec72e,
+                    0xf349ba768374d1b2,
+                    0x8877dc5509404b5e,
+                    0xd4798304e1e7a973,
+                    0x79be63313ce4b9a8,
+                    0xbea9853d43677027,
+                    0x03019b44fb808853,
+                    0xe8a39fb425ad1750,
+                    0x2b78898e73613025,
+                    0x009e557a918239ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38b48b8709b63e90,
+                    0xb698737b9b02126a,
+                    0xea65a3f1de63256f,
+                    0xcc284e64f714a9b7,
+                    0xa0ecc1b96a83d9bb,
+                    0x7c0378f2ee3a1c7e,
+                    0xd24b148b9655c752,
+                    0x684db29cfd63774c,
+                    0xbbd3e151d2e60e9d,
+                    0x9a1f03d8561f9e47,
+                    0x74bc5dc77211dc82,
+                    0x0104f97022bf7951,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa671c072e8c1dcbd,
+                    0xf0bc347f351e751e,
+                    0x5887634a9e13208d,
+                    0xa50313f745cb5509,
+                    0xfed2a796fd561289,
+                    0x466d982982e0af5a,
+                    0x4275c5b0f82109e8,
+                    0xab693166bc9423ee,
+                    0xcb7fd6377590c581,
+                    0xcf7f9938c07ca013,
+                    0x7eccb18e09f0c0e3,
+                    0x002ed22e0a11f965,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb6496890c08a2ec,
+                    0x6df9441e4c134252,
+                    0xb0284b952b13381d,
+                    0x6ce0172b4b72ac8e,
+                    0x6f11cb3f9330491a,
+                    0x301868d7e6fb992c,
+                    0x1fc82df99789c6d6,
+                    0x3916585bdaad60da,
+                    0x45856c1b9574ef73,
+                    0xb84ca67e02914988,
+                    0x762c5374c6f96e1a,
+                    0x004746ba646daf79,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa743f65d2631a0f4,
+                    0x2ee14337a6a213c6,
+                    0x0d61db1eeb357d99,
+                    0x65ce8849f8bec91c,
+                    0xe5275c9383569718,
+                    0x6dd4c13703141f77,
+                    0x2148dd3a8207ec8b,
+                    0x9af78da3049dece6,
+                    0xf5666cf8e6d3f318,
+                    0xb1f67fdf0d3e5f86,
+                    0xb1861e897abc243e,
+                    0x009ce0d2db95dba2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2194a2f1fb137f3e,
+                    0xb9b405a07e9d71d6,
+                    0x530976aac9d50f48,
+                    0x3464bc463566b9be,
+                    0xa8c6dd8304af0883,
+                    0x3c36e01524dc62f1,
+                    0x8df93b83f43757dd,
+                    0x9fe3746df3023d6d,
+                    0x6b7f17da29ef7535,
+                    0xc9a02ebf933ca5e0,
+                    0x0eee2bd063d558d5,
+                    0x00f897b98ffdfdd2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4755002c4570427e,
+                    0xfe7dc21a94bb4aca,
+                    0xff2847dc46a15828,
+                    0x9deb54e54b35f108,
+                    0xbe734e30d60e498a,
+                    0x93094285825f7525,
+                    0x31d6f859421bcbae,
+                    0x8b0854f7d047d08a,
+                    0xc4613c41196591e6,
+                    0xeb0aeb190cd329f4,
+                    0xa77bc31f3c8afa36,
+                    0x005c56346ffdf0a5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd2d779a59f8abe9e,
+                    0xfa3491fd5a7273bb,
+                    0xf1705fae2ef33d40,
+                    0xac9eb14e1bde9f6f,
+                    0xd1edef456351cd93,
+                    0x5193d2c49f37466c,
+                    0xa7a02cf4dfc5f653,
+                    0x8a20dfba4afb5395,
+                    0x3a9a9568d40b4647,
+                    0xe703961b2aecca8a,
+                    0xb69597bfb24892da,
+                    0x0048a8a4bef28a46,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6a415b7ce4b93608,
+                    0x4d119470e2845093,
+                    0xfda12b86fe736b90,
+                    0x15d8a40e96f3287c,
+                    0xb7d88291a4ea8e3b,
+                    0x7633160c6c1e0ad7,
+                    0x9e97e73942a30eda,
+                    0x02ae1cfc48f3436d,
+                    0x25f66590d2a46127,
+                    0xcf017a77e9ad9140,
+                    0x211e5626f3fc44bc,
+                    0x0015c01700f25c2e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12c5a13d41033a63,
+                    0x1b09031f4238b318,
+                    0x07b95888fb237938,
+                    0x45ede6a2cb089ad9,
+                    0xb2067168abb253ad,
+                    0x89cf5c00cfb30d36,
+                    0xbbf0cff9e38e2e43,
+                    0xbf84f8f0247b366f,
+                    0x41037e617dd0ced7,
+                    0x741b1f89a06070c6,
+                    0xf7bea2176c632e31,
+                    0x00852ffc48590d50,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2c0dc0975a95b1c9,
+                    0x812146c59780e09b,
+                    0xdc36bdf598b1a493,
+                    0x88c235444abbcad2,
+                    0xc0943d52b0a5f582,
+                    0x1425ff88050896f6,
+                    0xdf74cb8fa6313a29,
+                    0xef2c601c4c1b4acf,
+                    0x815226f7bef6c91e,
+                    0xbf622f9047c152a5,
+                    0x207406d64e90080b,
+                    0x006491bb7f605e44,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f23b10fbeb5ad29,
+                    0xfa6b386b5f614da8,
+                    0x518e930deb2e427e,
+                    0xe68c9c079f9708f9,
+                    0x13498038d7e4e5ca,
+                    0x5c43a86d958957c0,
+                    0x5c990628f7fcf631,
+                    0x64a3c6e88ffae85e,
+                    0xa9edaa598e811638,
+                    0x4d556cd38143e710,
+                    0x79ec0bea3bd3b3c2,
+                    0x0070da5ea4288b0d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f1048e34eaf3c03,
+                    0x3b8c991bb615996f,
+                    0x17371174344efb1b,
+                    0x10cddb824fe2ec9c,
+                    0xfaf57ba9ef907af1,
+                    0xd8e45dbd51900737,
+                    0x8074dd5f48f3a402,
+                    0xdfcd9b78694916fe,
+                    0x63693bee5580f142,
+                    0x6a80b25836bb93bc,
+                    0x6d4a72dd1da313bd,
+                    0x00fba418592105cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d035d0f1325ae7f,
+                    0x56a1e947adc35597,
+                    0x190b3511a3100f35,
+                    0xc76bc352384ee36f,
+                    0x7e0f13cbf9b7b0f9,
+                    0xe9a11d690c337eb5,
+                    0xa7f4d3b76d085d70,
+                    0x0d47747f19a20526,
+                    0x353bd5b8d34fe8c6,
+                    0x34d5554ef750cc39,
+                    0x5f3fa2b8ed4b1f08,
+                    0x00265c0c7ee016c9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad7f5f2e93d28375,
+                    0xe7518c3cb761de51,
+                    0xcbf87ffaab131c86,
+                    0x7aa88b0a9baa098d,
+                    0xc91e023555962690,
+                    0x8f0ad4962377ef3c,
+                    0xc8b0544bdd01c1a8,
+                    0x3832bfae9e258dd3,
+                    0x82e5cc2c34737f03,
+                    0x956d388534f32638,
+                    0x439742cb39f05b86,
+                    0x009f736dc052593a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf926b0bbb5f08327,
+                    0xbc7f535c5bb9725f,
+                    0x3ce15971ae55720f,
+                    0x11ce20bf19d0c082,
+                    0xa1ef180b81f4e758,
+                    0x9b54021f9d11fb44,
+                    0xf4231cbb7042e6ab,
+                    0xe900cfa4d52d8eb7,
+                    0xeece84c10c8bee45,
+                    0xd0dfb2f958bedebf,
+                    0xa2c1c404d4cac72b,
+                    0x002c1eb8eaca0fdb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb33938eb5a902d65,
+                    0xc1c18fe88939af17,
+                    0x0b7eb42ba4b32538,
+                    0x1481f776a0adb5e8,
+                    0x6456258a05574625,
+                    0x58d59b96a2c25b58,
+                    0xd07a7cffb58b7559,
+                    0x76403e8824772cca,
+                    0x0f77ae8f546987dd,
+                    0xff79bdd3032e3242,
+                    0x4df5e8dba9fd40e3,
+                    0x006aa5130fb50297,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ce48901995ed2a3,
+                    0x9090ac503a043ebc,
+                    0x087f42f9650e03a4,
+                    0xfce37fc3d2af48b6,
+                    0xca5faa1ece092a35,
+                    0x87f6bcaad124fdbe,
+                    0x586aaa87cfaa8110,
+                    0x418cf9557dd710e1,
+                    0x801412c1a25d8576,
+                    0x2cad0c8e2edf894d,
+                    0x06556136ea214453,
+                    0x0094237add0c19be,
+                ])),
+                Felt::new(BigInteger768([
+                    0x942408067c8d0f7b,
+                    0xc26755498e042c45,
+                    0x6838128a5dee08d0,
+                    0x0afcbfa91a622339,
+                    0x888cbf3a1298eb15,
+                    0x8cba1f836b884600,
+                    0x2783b48c76a95966,
+                    0x844508e44aa9e623,
+                    0xbd886e0329f4fd77,
+                    0x2b673e0f058a15f1,
+                    0xcb0ece0eb434f0f4,
+                    0x00a063423d127cfe,
+                ])),
+                Felt::new(BigInteger768([
+                    0x510688cd03cd9025,
+                    0x1f04e9094bf65c82,
+                    0xc75db9a95a1aa530,
+                    0xa01a7df58ead22e6,
+                    0xf55637c28e0f1745,
+                    0x4168f84c0bf21930,
+                    0x13b85ec133d63c74,
+                    0x651feb65f3a84974,
+                    0xce0ed7e27ba2fecf,
+                    0x6446ff2f08fdfced,
+                    0x8d2367f16a4678f4,
+                    0x004ae9fc4b8a7389,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xda2b207237394ac6,
+                    0x171ff8b1d78c704e,
+                    0x675c228ccb9c2686,
+                    0x6b75ebea88071eee,
+                    0x903da14ae554fa61,
+                    0xc5ea5764dcb6487c,
+                    0x0a5d8e64e917b9e5,
+                    0xc613f4de2579be42,
+                    0x444a0b9735ada6f6,
+                    0x74337bdecea3e702,
+                    0xee53325802ce08de,
+                    0x002e30e79822b143,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcbc9dd79b16859ec,
+                    0xfbbf1e0f8087921a,
+                    0xcdf2ee585b03b5e2,
+                    0xd82bd1b7d8300b16,
+                    0x4af98921eb6bfa34,
+                    0xa69edd7187dcfba4,
+                    0xa8ad2c05c2eb9d40,
+                    0x8b741efe3cb7efed,
+                    0xd7c52565c7bdd003,
+                    0x8559d309f9ada919,
+                    0xe6a2200182815173,
+                    0x00d2d7d0446eacc4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x191315d77937ba04,
+                    0xf7c1f0d576e9afa2,
+                    0x7b2236a154b5b2a6,
+                    0x5ceba95d6fc46528,
+                    0x5fc5357237156bab,
+                    0x7166057cb3aeba4f,
+                    0x26add9db001e4850,
+                    0x9274fb81bbc6613c,
+                    0x5972d4a004ca6937,
+                    0x40e3d9a68d3c53f0,
+                    0x4baa8a4c1df55536,
+                    0x00a7e57f943397b7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x140c32451645ea97,
+                    0xda19ebff660f36b2,
+                    0xa0864d91d120781d,
+                    0x816b2bed828caf28,
+                    0x58523520ca072f64,
+                    0xf4e88ea18052c664,
+                    0xcdd45f4351d1edc6,
+                    0x630e65a720046934,
+                    0x4aca6c9496d2be3d,
+                    0x6e569307a6e992a4,
+                    0x9d98f86fe3f4ac34,
+                    0x0024d14db94468c2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe8ac6ca7ae313e7b,
+                    0xb22e2466667f3cfe,
+                    0x060d5b82c276cbef,
+                    0x1de802bc404c9c68,
+                    0x2d72a558c79fb4b3,
+                    0x1f4ee511e54b7360,
+                    0x0f5954bbd91b0da7,
+                    0x7b6b08977a9af7a4,
+                    0xe6c65fd75eb8fdd3,
+                    0xb559d5891f7555dc,
+                    0x66c384c81bd8b649,
+                    0x00b20a5b951e92cf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfad33fe6476af793,
+                    0x351bb07525cf4637,
+                    0x5d1d3c758eee923a,
+                    0xe8b2b28db9dd9429,
+                    0x7df32311c5cdc5f8,
+                    0x822057a316795992,
+                    0xcc32df2d6239d980,
+                    0x59a2b61db4cc9fe1,
+                    0xc388f06ebbe201b2,
+                    0xc4c7c5222f7d8380,
+                    0x989b8ecaec93ae9d,
+                    0x00f7d15758c0e0de,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03f9d0999c427d0e,
+                    0x9ef61a21f56ac478,
+                    0xcfe8d6f1bb9ef446,
+                    0x83e9107d6b0f41db,
+                    0xca527294b2108843,
+                    0x51abd7fb500b6ffa,
+                    0xc87efcd2565db3d0,
+                    0x0bd877b3a8da468b,
+                    0x731d4fd4e0b986e7,
+                    0xc4810646a7654331,
+                    0xc7491a6cf1b2f5d8,
+                    0x00f781bb65a1440f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe0a6bf2d92d2acf6,
+                    0x93af959682f25f57,
+                    0xb35d5e3b62829011,
+                    0x7bda7e0663e8bf2b,
+                    0xdd83cc22d23e9d5b,
+                    0x79a0089fcb119e1f,
+                    0x9ec367080114d012,
+                    0x6b25b5d2af4d79a3,
+                    0x18138abf6caf7a7c,
+                    0xfa1d7eee04d4b514,
+                    0xba198b1d56171b0b,
+                    0x000149ee67da7609,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb9c549d7179731f1,
+                    0xbec99ddd8cae5d49,
+                    0x9960f26e1b84e19b,
+                    0x13a26f741eff64bf,
+                    0xf4ad43908545a4e3,
+                    0x44cafcb537643803,
+                    0x677ea40ac39f48d5,
+                    0xac23b322a8822028,
+                    0x48f3e03f68de9550,
+                    0x804ba02003750f2b,
+                    0xecf78ac9c66aca9c,
+                    0x00dea165b34f0ece,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd3e4bc25258e192d,
+                    0xc70b94704a9d82b1,
+                    0xb9a0ea6c4c92190c,
+                    0x393fe5d1f0ca3438,
+                    0x4137cc26e5b7dd6a,
+                    0x139f9c46645f737e,
+                    0x54c7c97d5997deb9,
+                    0x8abd6a44c8624363,
+                    0xf3801c20caa0a70a,
+                    0xd9775d6bde7ffa1f,
+                    0x2c48602a6ef931a2,
+                    0x0000b340c30a9f59,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbcbf4a25a55563f2,
+                    0x2fb30eb86e3d07c2,
+                    0x8a68611336055543,
+                    0xa9cf1677d6a495e0,
+                    0xbf535927942a8937,
+                    0x6a5161c2632305e3,
+                    0xd2b7a1d26b9fea3a,
+                    0x0e28209ac84e7566,
+                    0x2507fa8873879033,
+                    0x35bf378e06cefe23,
+                    0xf5af35f2dd079dc7,
+                    0x011e7f07261c7d4b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50918732cfbe4c3a,
+                    0xc065f30aba3d0dd6,
+                    0xc306056d37f0ff87,
+                    0xbe73f5f859ba2d3a,
+                    0x2d444780c1c8db47,
+                    0xc70ed10ee18b2993,
+                    0x419a334d6edcab8a,
+                    0xef63f3b6c7cd5207,
+                    0x10e4b1ae5606930a,
+                    0x7178fecbeb5de842,
+                    0x4225b8d7d7128695,
+                    0x00f95657b7a47bcb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71a8c54614169475,
+                    0x6d9bac03164ef6d4,
+                    0x8cd667c854a9b6d3,
+                    0x00b11257ec6e5a9f,
+                    0x25262d7a9e8105fd,
+                    0xe044c9579e49041e,
+                    0xdbe518a593e15036,
+                    0x5e67f90603f9344f,
+                    0xfae17a220c6cfbf8,
+                    0x891b4a1fdab2a430,
+                    0x3fecc0ce8bffb476,
+                    0x00f15383e3b99088,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb1af566824ff85c,
+                    0x7bc05a7a03097d66,
+                    0xa9bef8e49edf323e,
+                    0x081e8f78677c08ec,
+                    0x5872dc3f3115ee9c,
+                    0x77b5cbdfd6070684,
+                    0xa987ce844d97fc89,
+                    0xbd42be8850063a96,
+                    0x287e20cddccc0dc6,
+                    0x5d48340b7d0b59cc,
+                    0x6ec3b871e8e676e9,
+                    0x008b34333e11bac7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd593d8c742644b0,
+                    0xd626e51948dd2134,
+                    0xd1528b578cf2667d,
+                    0xb4acde598d0406a5,
+                    0x831b2dc78c179fb8,
+                    0x3ba7ef736b709698,
+                    0xac4fd873967c4085,
+                    0x7b2fb0e45be584a9,
+                    0x7aa27846f27bffec,
+                    0xcca2330b86861a74,
+                    0x6eb7fc07fb479ce7,
+                    0x00c1dc805e8e2767,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0475e1f84d173cff,
+                    0x99b497c42a22342b,
+                    0x5e5c7d875233050e,
+                    0x0474d36758b85829,
+                    0x0f7688652063f02e,
+                    0x9143fae8c185ff46,
+                    0xcbd9cb090e92641c,
+                    0x3742c00a456b0d68,
+                    0xd006443c42aa036c,
+                    0xe3102431ab714694,
+                    0x5e0172b6326fa21a,
+                    0x00633146947e94c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1429d184b951152f,
+                    0x7079aace4eda3054,
+                    0x5cf4475103e11a0c,
+                    0x6eb3e58acee10572,
+                    0x62ddf6b285a65f1d,
+                    0x3fc56cc004a3b047,
+                    0xe400199042bd7273,
+                    0x5fba0564aafdad4c,
+                    0x77ac8c519508bd34,
+                    0xbfe2d88043353879,
+                    0x92614b1f660915ac,
+                    0x00948804be974c7f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x65902b3cf5029ee5,
+                    0x65f9c1a19900c0a3,
+                    0x199e6b7acfb8b19b,
+                    0xd4852724bc9abbdd,
+                    0x9aeb5852ca27794e,
+                    0x8ef779f8bf34d8b7,
+                    0xa64630d35575daa3,
+                    0x13242ae68105b850,
+                    0x6a7d49fd48716b56,
+                    0xd6511e160bd34f8f,
+                    0x34d52f26a0d47bdf,
+                    0x00deb1da9723fb22,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc92ae91bb71635fe,
+                    0x98ab5defc4c237bf,
+                    0xa0fbc085d40c8483,
+                    0x047842464b333893,
+                    0x2a28598a415afb63,
+                    0x9ba18102e872e884,
+                    0x2516b2c06e46d204,
+                    0x4bd698302f1f2628,
+                    0xfd4ce0a024a4d63a,
+                    0x786315f7c0d3cb8d,
+                    0x7dc6759383a4fe00,
+                    0x0085076458842431,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2933b14efd622e12,
+                    0x658acbabbb5eb1fd,
+                    0xebf554a2e8770910,
+                    0x7b1a3fe69db3bb94,
+                    0xdd3f8b31305a552e,
+                    0x5e5531202ba07bf3,
+                    0x39deb520e1ac7709,
+                    0x1db80afd9ea14a15,
+                    0xb30303dc7dba31eb,
+                    0xca6820f9045add5b,
+                    0xd6757c2682e9bd8d,
+                    0x008ed4f4afa8dac3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd35ef7d88a6ddf38,
+                    0x502cf0f03a90008d,
+                    0x32dc7caaa69e14e1,
+                    0x83e0d7e16b49ee80,
+                    0xd268590060c35f9c,
+                    0x0d21f4dbf0f89eff,
+                    0x32969a70035c502e,
+                    0x7ea9462589d0b8bc,
+                    0xc3d6f063662c3f6b,
+                    0x0eba011b31e5b9aa,
+                    0x8bd462111e288259,
+                    0x0010a283774c9df5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe758f3ec1ac41064,
+                    0x2df46e3f0da5884e,
+                    0x435438cac55619ed,
+                    0x0f8a1ce11b34d34f,
+                    0xd19b31a68a08b6b4,
+                    0x6f02de1703cba0cd,
+                    0x718828b11ad4a478,
+                    0xc3c37d75623c1003,
+                    0xcfcdb2b756e0f556,
+                    0x86124d4d39b66f66,
+                    0x3bb90752c09e9c34,
+                    0x00f58416cb4bb745,
+                ])),
+                Felt::new(BigInteger768([
+                    0x53f7e2e353213a59,
+                    0x1885dd9b4afef8b9,
+                    0xf4e553285410342d,
+                    0x0be49881eee13d37,
+                    0x63d092563636380a,
+                    0x3cb7b65b698bb08c,
+                    0xe0767feb40b34031,
+                    0x14e84061271b0d9b,
+                    0x968effeec201cc32,
+                    0x8932a58f4e1723ef,
+                    0x0fee1337e26ab087,
+                    0x01191a55508504ce,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71932715b2fbcb1f,
+                    0x36ee58a3d7cbfb44,
+                    0x5cc856a715eb268d,
+                    0xd92d6cdd533f01c2,
+                    0x885bed8893c1c3a5,
+                    0x35cd5a1c5193aa13,
+                    0x7bbc91990169b0fb,
+                    0x81a333e2c82756d8,
+                    0x125fb5b912b9a9c8,
+                    0x903fde91353ded8c,
+                    0x384acc3c50979fcc,
+                    0x00bed5a75ed08c0a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa574c8784fccc5a0,
+                    0x03a04fe73d68c6b9,
+                    0xf7f31e9a9484cca6,
+                    0xd640a632c745432b,
+                    0x8fec9cfbcd8bd9cc,
+                    0xeb60f75c4e7925b4,
+                    0x872425f50d79547a,
+                    0xa8c658b8a19cc725,
+                    0xbdeced187ff71c6e,
+                    0x2f1c4c24251b515b,
+                    0xe06823ea757cf9a8,
+                    0x000550709a698f37,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b3141b8d7f513c0,
+                    0x0fe4a7ed3c00d4c9,
+                    0x044da9eb8601a258,
+                    0xd7a54dd7d7e64fa3,
+                    0x9f83fe012052c588,
+                    0x0e715ecc1d34eebb,
+                    0xd6c02114f179fbf1,
+                    0x0618a9d405813aa4,
+                    0xd96661261578deb8,
+                    0x65b9040896fb3a2e,
+                    0xf788b1a5c37f690a,
+                    0x0113ae449fd18f73,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcea7afe5fde8c2bd,
+                    0xa98b9e141aec1123,
+                    0x7d8d544c83918601,
+                    0x5b6578c78c5adc16,
+                    0xe8738bc14145b37a,
+                    0xe4193191d3c4b665,
+                    0x17d08dc010718f89,
+                    0x75724b6ad153342d,
+                    0xcec99073bdeb0a52,
+                    0x662bfe5a6dce05a7,
+                    0xd7332354f4a97b00,
+                    0x00b18192cf39cf49,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2cc6225b7b25397e,
+                    0xa48d2b6829b09ac2,
+                    0xc84b09f4097b34e9,
+                    0xe9413c444dbe0c1e,
+                    0x992a8ab73e86b4e3,
+                    0xca8ea16e4c23ee69,
+                    0xb8d1b01a6f5ef1b7,
+                    0x90155b84b708f0cd,
+                    0xed560cca99fb2805,
+                    0x7d7077060c32ee6d,
+                    0x759f9a2d91b2e600,
+                    0x0099b9ffd2779aff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x710f1f2b17f8928a,
+                    0x232818f68a12198c,
+                    0xa49032aaa730bf0b,
+                    0xa57eadf78284d8cd,
+                    0xb12f621047509eb6,
+                    0xfbfaf90d1b916c2a,
+                    0xb2131c775f09ceca,
+                    0x9093300a09f431d3,
+                    0x197c3095dcb4d814,
+                    0xed74bf145f482b96,
+                    0xaad09a3ce131747b,
+                    0x00b693ebb0305d2c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2863e571023028f3,
+                    0x951a17beb8ff2475,
+                    0xfb9e37486adba76f,
+                    0xb0f1141fa8d94eec,
+                    0xaf4a6498b9147c88,
+                    0x900fcd67419bfaed,
+                    0xe4966414b84ff533,
+                    0xc370cecc73ef703e,
+                    0x6e18c348170de006,
+                    0x425a2181a3bafe32,
+                    0x3ed60009b47e9d34,
+                    0x009f9f2b6f3bb952,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd409d09692bc999e,
+                    0x365339ca5b402cac,
+                    0x9a27bcf25a9ccfdc,
+                    0xa4571128bad8ccec,
+                    0x9836e21ba333647d,
+                    0x4fa629ccbf0548e9,
+                    0xcb243d77fbaa9f2d,
+                    0x9651461ab314c57e,
+                    0xfb4d613c7ec81074,
+                    0xa3b4b5c340a11e2f,
+                    0xe6d5af2c507c7c63,
+                    0x00d3ab0b5b10bdab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x409b41c64d468799,
+                    0xee15a8a5b1c40521,
+                    0xb6d97877291f1800,
+                    0xb26827861893f10a,
+                    0x9d3822f3db0229ea,
+                    0x4a69a668aee5f1c3,
+                    0x363aa7c74f0d3d08,
+                    0xb0f7c84f4ccd5465,
+                    0xd52cbba08b482cae,
+                    0xb0718c975528e03f,
+                    0xb43e32ad364f9a51,
+                    0x00a517cd4d10b7a9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x74436b25e280521e,
+                    0xbfaa28d2568a248c,
+                    0x1a0fc2504736b2cd,
+                    0x81295452564fb86c,
+                    0x7c975d44b7acd064,
+                    0x57c1b4c48167fce5,
+                    0x77ae8c2afa32cb42,
+                    0xc90437d580ceba15,
+                    0x83912a1f47e2ec80,
+                    0x0a380adee3eb9231,
+                    0x24f806416bc09efa,
+                    0x00e9a89ad160dfff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7c9d5508c8b403ea,
+                    0x854d82a71b82941c,
+                    0xf1b9333ed78b18be,
+                    0x454d494492f926d0,
+                    0x18bd8ccc5acb971b,
+                    0xa3ee20877f6dafe8,
+                    0x8cec547dfc9e197a,
+                    0x7711581542aff85d,
+                    0xc4c5c9830434240f,
+                    0x0cdd643016e914fb,
+                    0x3169f9c6ae39142a,
+                    0x00a087601db54a3f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4824a445090a5b5,
+                    0x56238bb5a7f6143c,
+                    0x29ce9c1b348fe88c,
+                    0xd83d497be25ee3fc,
+                    0x19d4dae1ad3fd578,
+                    0x4f93800a5eca2168,
+                    0x5309f2aa7629cee0,
+                    0xe3133253bf6705f3,
+                    0x2a9122c2d68c1ad8,
+                    0xbc8f8c5d6ce75441,
+                    0x68a35d1516aaaa41,
+                    0x005fce8f4f1d277d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbfe563f934778725,
+                    0x263222f429f50e73,
+                    0x3d57bf2a63330a4d,
+                    0x6b83b9e061b0b4d2,
+                    0x30c86f0249c5bd4f,
+                    0xa64bdf4d35ea3b2b,
+                    0xb68355289802d90f,
+                    0xa02631e01fb904f1,
+                    0x8369659d95c3e3a5,
+                    0xea66a93c651b03dc,
+                    0xb02da5f8c9d5c900,
+                    0x0063f91bb2ea2627,
+                ])),
+                Felt::new(BigInteger768([
+                    0x972e343ec671fb91,
+                    0x12e560a2077d99ef,
+                    0x261d7219d6e16ebf,
+                    0xf4855fb9e02a0ada,
+                    0xba0a206c75977535,
+                    0xb1ffebe3df0ef21c,
+                    0x996c023d90cb8a6d,
+                    0x9f6fa79d0293b352,
+                    0x19e70904e1285c50,
+                    0x1445b423d939e2ae,
+                    0xae97b2682150d878,
+                    0x0069731951750a53,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22c6f8ff4903838b,
+                    0x1647d199aaf1b3fe,
+                    0x90c9069f0a63e055,
+                    0x8be4802fc88af2d4,
+                    0x2a4f0d36c886309f,
+                    0xb13c5cad8efdf7da,
+                    0x8ba58e03ba3ec928,
+                    0x098858583dd2eaac,
+                    0x19015d6fb840cd93,
+                    0x703494fc649565c5,
+                    0x82c4c2ac45e001c2,
+                    0x006be9dc4bb0a9b1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x10cfa0eff45b05bb,
+                    0x4da5bf4af225f8d5,
+                    0x9fd0ebbdc8337cd3,
+                    0x3ca90801315d4d00,
+                    0x26ef3566ee594553,
+                    0x6e83bfc045e8b654,
+                    0x9409ddd757e40a37,
+                    0xf5f841adc68bf9da,
+                    0xb792507b1c739473,
+                    0xf25c716499e233e2,
+                    0x0c150de7bcdd1282,
+                    0x01101e65d60e03d4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0bbe446ac439bdc6,
+                    0x1f89afe9f93478e7,
+                    0x9ac0b44c0351467c,
+                    0x8f8fce605db6494b,
+                    0xee8e76f1f31b81e9,
+                    0xf2b594675e343503,
+                    0x6992acd4a976c541,
+                    0x759ac44ccb576605,
+                    0xbc5d3ecf4e6c3220,
+                    0xcad120925fc0b126,
+                    0x8743608858f94a00,
+                    0x00e6b6700267a8c0,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2f0325bfd41253d8,
+                    0xdf1849201ef80b9e,
+                    0xa08a12a88b6edb31,
+                    0x4160cbd326d3dace,
+                    0xc0cfe9c155391360,
+                    0xbd7bcfe529d853e2,
+                    0x5a4701853816a6cd,
+                    0x4cfc2778e6842ace,
+                    0xfe76cbb894141eb2,
+                    0x6394d3703dd8550a,
+                    0x91c71c30e35e2764,
+                    0x00c09ba6cac2ee65,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3e6847d3270da6d,
+                    0x16c615017a3010ea,
+                    0xbef87939edaa8aed,
+                    0xbc80d374ce4042e2,
+                    0xbe1958b31e5bee6f,
+                    0x1ff59c88471949b5,
+                    0x8c644c4202270ef8,
+                    0x224d9a9382446f49,
+                    0xd63173a1756bbde9,
+                    0x8b4c895c70186d46,
+                    0x8214b82094511f6f,
+                    0x01228d4249e1562d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x57ad34fdb2ab9dcf,
+                    0xa9be037e7b99700b,
+                    0x71e85536a4b4713f,
+                    0x0a06b28fbdaabc0b,
+                    0x3c8e0c62059f8f14,
+                    0x6095fd85f35d2808,
+                    0xea5785fa17cbbf5a,
+                    0xbdacf834665dc99c,
+                    0xda6c40bd2ade1c23,
+                    0xb2c181de7dddeda7,
+                    0x3793ffa659608ca8,
+                    0x00292b68765e27aa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a863b3473d51648,
+                    0x9cbf5e89a40a1827,
+                    0x3e9c8cff0e8ca5ad,
+                    0x46a51172d3315b93,
+                    0xb188c5b816ed1cba,
+                    0x9f71c13338eb2fe9,
+                    0x5e441ebffe331202,
+                    0x85a2a2fb43c4a244,
+                    0xc444c46d62c56abf,
+                    0x8fd601064847fd8e,
+                    0xdf88361b2336eb84,
+                    0x00cd0cd5cd41a4fa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4683cc5242ebef0,
+                    0x6c325e0b0dfe85ef,
+                    0xf1dba595d24f4e44,
+                    0x49f56fa17911d0b7,
+                    0xd1767c36175ceabc,
+                    0x44fb29692b03e3d7,
+                    0xa518bad9d704f944,
+                    0x95fca682d31e7248,
+                    0x3dd8e46952d08150,
+                    0x8b52a1038a5b87cc,
+                    0xc885e1db020eeee0,
+                    0x00721f3ff2672608,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9c8db2ca53daf6b5,
+                    0xe684fc21749e82aa,
+                    0xe5f626e7eeafb6ac,
+                    0x0e9f1cca8a9c3f4b,
+                    0x92af58eaf780a090,
+                    0xf7ef4fd9080235ca,
+                    0x4efd0ab38755e852,
+                    0x054ae2a13263c9f5,
+                    0x41dff25946356e29,
+                    0x8d007553fa6ea024,
+                    0x1575ac6fee750e54,
+                    0x008328d71432a828,
+                ])),
+                Felt::new(BigInteger768([
+                    0x427d390b80edbde9,
+                    0xf0ab2bef554ab536,
+                    0x749917eaa35afb6f,
+                    0xe5253ddd981cb664,
+                    0x74944739cb645f54,
+                    0x60b132d90510f0f2,
+                    0xfed1f4f019562d62,
+                    0xf1bc146253c6e52e,
+                    0xb3d1fa7f5beeee16,
+                    0x3d99bff714656c29,
+                    0x23d95f2082d5c5aa,
+                    0x009f3af201543f94,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe1505eb2662e53f6,
+                    0x33ca3fb3b9bdbaf0,
+                    0x79e594f5d70dfd81,
+                    0x386443809ecdcbaf,
+                    0x7f2a2f2b418f019d,
+                    0x4ebe6a53bb3c8f77,
+                    0x047e06d2f506f35b,
+                    0x416d39916a73c60b,
+                    0x3b19f67e76716d94,
+                    0x2a59618f561c2233,
+                    0x7ebb9fff9b70ec32,
+                    0x00e8c1035b7df7ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce8c2a4f5d29917c,
+                    0xf8e97a6c385ad7e4,
+                    0x6cd20910fb3352a9,
+                    0x1745a2a8cc3d76ce,
+                    0x4c6116c6b8827544,
+                    0xd85581f60e79349b,
+                    0x6285bfd5c68e4aa2,
+                    0x91ca6e74a2ea7169,
+                    0x90daa42348dfd272,
+                    0xc35bf208eaf5ef01,
+                    0xeb3c69ebd0bdcab7,
+                    0x00a29e12540eaae2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05703623c5122d24,
+                    0xf79dd3bddd425603,
+                    0xe779bcdd62d31a3c,
+                    0xd20d177fc31a439b,
+                    0x0ffe534c82d139e9,
+                    0xdafa9dcb83bfd659,
+                    0xa3be52cd85ff2cb3,
+                    0xc05d1a5d2f0fd516,
+                    0xf7d64e87a39875fd,
+                    0xdd42bc1b8ec74758,
+                    0x15b97808a39fd83f,
+                    0x00dfc95c430544bc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xac21c645f7b502d9,
+                    0x4302f02f1ece20d7,
+                    0xeb351cfc322aa3ab,
+                    0xc3ded0b675da4712,
+                    0xae719a4a886f8cb4,
+                    0x60e445537cdee3b5,
+                    0x3cd969de95d3e868,
+                    0xedcaf7965b4ce0aa,
+                    0x585c4824e9d917c8,
+                    0xfb51b0fe9883ca59,
+                    0x128c5840b695b72f,
+                    0x00004ef892f41439,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8470a8baa386af38,
+                    0x4a04ca3a9f52d0f3,
+                    0x68302b338aff15cb,
+                    0x4f1000a50d1b99d8,
+                    0x1f1531037e093d0c,
+                    0x6fd3d4dd93876713,
+                    0x01e1702d33bba07a,
+                    0x6d6559666cfbe141,
+                    0x2154a9ce781e3f0f,
+                    0x3e99298c8cef6dad,
+                    0x18b7a36ad597f3b4,
+                    0x00cb8bee16d8b8b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f76a3f90f2de768,
+                    0xa74e622c7f0cdff3,
+                    0xd6af4ed6cbd79543,
+                    0x5e3eb06eccb850f7,
+                    0x0281e179a040a6d5,
+                    0xac94ea1bdf592979,
+                    0x986fe528a1776cc3,
+                    0x284c97f52518ea1f,
+                    0x2cd8ccea80fdf457,
+                    0x80119fe6a57500c4,
+                    0xba48993756f73b86,
+                    0x004a91f4bf732e89,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9556be6f90e25f68,
+                    0xb6038ba25029df0e,
+                    0x94dd83c6003e1cf5,
+                    0x1e31606107934064,
+                    0x8689a4716a15853b,
+                    0xb62c11c71ae6c5b8,
+                    0x049f4ead49b5f09b,
+                    0x47469d80b6380955,
+                    0xc0a4b1a1bc99e170,
+                    0x7c75da515d62c39e,
+                    0x406772780fdf1664,
+                    0x0016770cce5b615b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9cf3e6e8c60ebcb0,
+                    0xf38aae6e2693dccc,
+                    0x530390a23c1ab880,
+                    0xc297607fbe2d7fbc,
+                    0x5785e99f96710b7a,
+                    0x37c53001a4142566,
+                    0xa5733e6e8d24c6a9,
+                    0xedf2f4069250b905,
+                    0x5005a4e9b5439b8b,
+                    0x4e260b964000f33a,
+                    0x85af201afc4fde01,
+                    0x011d0b32c3a3be1d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x867521fcf593fd1a,
+                    0xc7e3bda096030d4c,
+                    0xf64ced1f405514f1,
+                    0x91778fd09b910c60,
+                    0xd5abe3c7ee1bec6b,
+                    0x23d336b1899665a2,
+                    0xbcb171674226f428,
+                    0x313d28fe752376ac,
+                    0xf6e604ead17b661a,
+                    0xa22779a0fa92af55,
+                    0x9d61f77feefce317,
+                    0x00e302124b4a6c9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87a2e0f15cae67a9,
+                    0xeb832fcb07378ff2,
+                    0x6911ff738997ea48,
+                    0x44dbc5a8a4d8ceb6,
+                    0x090f7b7206e5f8c4,
+                    0xa766f8ba54c12051,
+                    0x4bdab01593eb3152,
+                    0x6b52b1589a762c6f,
+                    0x26dbbdff34cc375a,
+                    0xd1d1623c44584ea4,
+                    0x0bbb85a0630b5b44,
+                    0x009a69229339538e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa535112ebb2bd64e,
+                    0x49b035c0e9a69835,
+                    0x3fcd5e2343a349b2,
+                    0xf5a75df9ad4be69f,
+                    0xaab66ce8f3f5c25e,
+                    0x0b258cb113517f54,
+                    0x913498071d93253f,
+                    0x1d5ae9fee3d08fac,
+                    0x935f18c912f69005,
+                    0xcdac060b070a26f6,
+                    0x81e215d99fce0339,
+                    0x0014a133a33717ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa2965290a01de5a,
+                    0xe1b7823c52ed70aa,
+                    0x70ea768690104671,
+                    0x6b72661629d1984a,
+                    0xabf081ed7b0baf83,
+                    0xca213d2e78a3b120,
+                    0x572063dffacb0b8b,
+                    0x907094196b8d5e89,
+                    0xbd011734953de3b1,
+                    0x310d46ae46abc8f7,
+                    0x8ffd2f32faf32e02,
+                    0x0004bc0110a45cdc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97837cad3701e8be,
+                    0xe23188b9ffeb17d3,
+                    0x9566c733aa185727,
+                    0x5caca7db5dcdce04,
+                    0x826cd2cc1d8d5b36,
+                    0xf156f13f254be028,
+                    0xd35a3f581548a0cf,
+                    0x96a23e7658350e2e,
+                    0x3b7ac1605be7fc46,
+                    0x1f4e39f77a606b49,
+                    0x2d7e53b3a7d3ab69,
+                    0x00a28bb08bf8d3ca,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xcff170d94857c5ec,
+                    0xba36e9cea046b3e4,
+                    0x4156736a6a5c92f1,
+                    0x7d285d76163cf3bb,
+                    0xa5119639139f94d6,
+                    0x057f131d3ced94db,
+                    0x91d244242c00ac06,
+                    0xe3d622d041f9795e,
+                    0x17fecbbbdcefdb43,
+                    0x9a11c0c532a9ad71,
+                    0xab6160852788dcde,
+                    0x002af8ec14320629,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58f5de573877a925,
+                    0xd110f0e78fbfe8b7,
+                    0x3702321cec6e9787,
+                    0xb10bd3d4827b6ded,
+                    0x6d2e0ff8822f221f,
+                    0xe3d4015a43823b85,
+                    0xab40325567bf7ebd,
+                    0x8345fdeb180050a0,
+                    0x48153d5e023aee36,
+                    0x488a70e4a0c692fd,
+                    0xe75f7ab9887a879c,
+                    0x00d5fcfd2235d402,
+                ])),
+                Felt::new(BigInteger768([
+                    0x507ec7c627fe3676,
+                    0xaa24b5991a76448e,
+                    0x39fdd4e3ac7ff185,
+                    0x4a222e5d14c5ca2f,
+                    0x18ff614ce9c51760,
+                    0x850038aee7c04436,
+                    0x784e516cc470895d,
+                    0x3b960599f7b7f47b,
+                    0x058ec42cf6e1848f,
+                    0xe6e1f43ec2f52065,
+                    0x0643afa234f8273a,
+                    0x000cd45c45b80f05,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2cefa9bff51651b3,
+                    0xb8f028689b3a53fb,
+                    0x5e50f0d9e72e1c18,
+                    0xdb205490070bbafd,
+                    0x881347f3d45f5576,
+                    0xd303f1472e5fd80e,
+                    0x8f4dfd551917fc82,
+                    0x2eeded0a053a32e7,
+                    0xf24404f2a3b6758e,
+                    0xa6c5519a18039a80,
+                    0x73103a4cd59c109f,
+                    0x004a5277d3082853,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71769497757db1aa,
+                    0x47c98a71fffa725e,
+                    0x8dd04db8250f4a6e,
+                    0xbe75bfe952b8b196,
+                    0xe454abe777cd0657,
+                    0xe3583c2e43d3a8cc,
+                    0x7c0ac8cbe8a9cc6f,
+                    0xe000f190e27162f9,
+                    0x0cd404dd3436f9d5,
+                    0xe829ec3b5e9f1700,
+                    0x07022cd7a5fe62bc,
+                    0x00b993198206a59f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x123825377a569ea9,
+                    0x33f8f9bb910f83cf,
+                    0x4ced0ac8a39ba915,
+                    0xcbde2ca219d64b40,
+                    0x4f4b2d6dadcdbcbf,
+                    0x8678d4c93ca05eec,
+                    0xf413464bd8a354a7,
+                    0xd90bdce2e0d6eb9b,
+                    0x86fc4c5a280ad800,
+                    0xb89e511b30f93a1d,
+                    0x8f7904b779979c94,
+                    0x004baa1dc685c9cb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x48abbe1fdbdd81f1,
+                    0x07d320525ea4349c,
+                    0xc068c8359baa4c41,
+                    0x7a66fb518a0d9df1,
+                    0x1d38581102990a3c,
+                    0x5a9e79fd473d89c0,
+                    0x03999f1b77cf4514,
+                    0x8efb50c0ac1c0494,
+                    0x95c4acc1a55dbbda,
+                    0x27cc2e256068f685,
+                    0x23a4d28b85045d28,
+                    0x003b7fd2da95ebd9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa5cbeeec4d103cd0,
+                    0xe1450a5421bc6b64,
+                    0x922c7f2b50755285,
+                    0xf39127821aac83cb,
+                    0xb7651ec56fe1f1df,
+                    0x1b725143d356b676,
+                    0xe1b2b999012d2b2b,
+                    0x3eb0e758a7535e35,
+                    0xa060f544ebaa7c30,
+                    0x80e677ac461dfa3c,
+                    0xddd29bba295cbf26,
+                    0x00cd62a34e11071f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc164f15fa0f33cde,
+                    0x9a0c3553d1d009a8,
+                    0x2041eae19c5b9efb,
+                    0x80a7ae75dcd4e8eb,
+                    0xc575830ab39826e6,
+                    0xe5822612e665fe4d,
+                    0x5fd3ce927f1eaedb,
+                    0x4af540d31153456f,
+                    0x99113d18efdd8e2d,
+                    0x835cbc434419c43b,
+                    0xbc736b6b7801ea6e,
+                    0x0020b48097431a3b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12584c70d21d5a30,
+                    0xbf0767df5d48565d,
+                    0x95598ab489c9bc4f,
+                    0x06a74a5c65d21428,
+                    0xb6c09269a3759d42,
+                    0x384de2e747a7ceaf,
+                    0xc09fa0129c16777b,
+                    0x1be6bbc318eef46a,
+                    0xc2067f5906bf4a01,
+                    0x68f57bb6e25434c6,
+                    0xf089f5cfd8216961,
+                    0x00ddb120841ed140,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1f6e9158ea740fbe,
+                    0x6d61d5ab4a8a9d36,
+                    0x65bd579ac91e5591,
+                    0xa89f7a08992f6cb5,
+                    0xaad3ea8129dde8d3,
+                    0x5507689a43f0bf40,
+                    0xd88938989d850485,
+                    0xeb5be008bc081fcd,
+                    0xa649379127bc1859,
+                    0x7cd1f637e0fc0ef0,
+                    0x082823a5534e52f7,
+                    0x004b2cf7ad286743,
+                ])),
+                Felt::new(BigInteger768([
+                    0x66af72b374045237,
+                    0x46ca2181792a1401,
+                    0xd81a1292b7e3a8b0,
+                    0x2d1077714cab25fc,
+                    0x5d37ae364b6cd230,
+                    0x41caa012abde1c98,
+                    0x6b8b573ad2dbafb4,
+                    0x9433d13cbc37bcae,
+                    0x574d6545f5b79ce4,
+                    0xed8600a36532335a,
+                    0xdfe15da6e8138afe,
+                    0x00e7ab5930edd758,
+                ])),
+                Felt::new(BigInteger768([
+                    0x805b69d8ed83a207,
+                    0xccd64a531def07a4,
+                    0xd613f3663f909c39,
+                    0xe07b5e95699e9e74,
+                    0x313a791e1104fe64,
+                    0x5d8bc5762c5fa98d,
+                    0xd48661d5fbac725c,
+                    0xb2e785705ac3eaea,
+                    0xaefccaf4debe4fed,
+                    0xae43a9c05e7973c7,
+                    0xfa75fae00dbf21d1,
+                    0x00052571dcd768bc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x42130d1282135f53,
+                    0x593664b0da919691,
+                    0xba09c7c5c1f7dd5b,
+                    0xfd8b607c85d213d8,
+                    0x28bbd44be1769cb1,
+                    0xb9a2ae8da102e7c7,
+                    0xcff489ea6cc03175,
+                    0xe1e79cc4e135ce19,
+                    0xf4a610be80e80265,
+                    0x6b330b13c04a3175,
+                    0x8dda6916bcb11225,
+                    0x003a7858f64bbe80,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50e479d5e801a8b9,
+                    0xecfa38c54ca84a5f,
+                    0x7dea831c747026e8,
+                    0x35803c7708d22828,
+                    0x3de7da497b11f82d,
+                    0x1db9c6b209d03c1d,
+                    0xd5387726769466c2,
+                    0x861988e93a2a0d62,
+                    0x64e5ae3385dd0410,
+                    0xf651d65bba1f74d2,
+                    0x2ad29b5e69e1644b,
+                    0x011d3623ec788594,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa5b980850cdc38b0,
+                    0x0d19a2cbf3a1f06f,
+                    0xb64644f7be0c182d,
+                    0xc2f2dcf1c640a719,
+                    0xebc964d895c902c1,
+                    0x2690ef25276c8406,
+                    0x6cb77907b26cc2c4,
+                    0x51bb96521f97e587,
+                    0x0e3944a57599ff2b,
+                    0x5fec35678ca570b9,
+                    0x19da102e50df6cb5,
+                    0x00eb75460a35ee91,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b3df676533b2bc1,
+                    0x46f273dafaed4f0b,
+                    0x2c0400c28fab121d,
+                    0xd10eae753d5e6264,
+                    0xd5885ada77c9d359,
+                    0x7167de0f9852e707,
+                    0xc4f03b733bf16e95,
+                    0x78648dd084fbf3bf,
+                    0x3d5c66d39e53102a,
+                    0x5551eb87203c9caf,
+                    0x481f4cd8ee80b3f8,
+                    0x01118255940e2f8d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x67b9ad330919dfc8,
+                    0xf4127a8b9e2417c1,
+                    0xaee2862725b1fd97,
+                    0x3ad2933158be7019,
+                    0x47d7feda6effa771,
+                    0x9fd4c47a30f539ca,
+                    0xf4adab0f8b30b8dc,
+                    0x3ac4438b28a9062d,
+                    0x33a7c88d17acfd83,
+                    0x2a881e120f6256f5,
+                    0xf0f1fda6bbac93a0,
+                    0x0058878541541de1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x84724f80616e24ef,
+                    0xfa138e037f9f622e,
+                    0x26c5843087a36954,
+                    0x6a349f0b8224b6ee,
+                    0xd923fb789ecfdeaf,
+                    0xc8ed560630ee0f66,
+                    0x19ac54fd8a34e6b5,
+                    0x72ca64c631a2e8f6,
+                    0xfd15b03087151fe2,
+                    0x4770df8c942047ed,
+                    0x6c7eb4e7481977da,
+                    0x00f287f3aa44b567,
+                ])),
+                Felt::new(BigInteger768([
+                    0x28de2c990af17734,
+                    0x0fee3d7850f2d122,
+                    0x13cc01db425ff32f,
+                    0xc46213fd22a3b99c,
+                    0x908dcf3e99ef6e2d,
+                    0x25e3b0f5d37d916e,
+                    0xc3478b375b00a101,
+                    0xa534c63bf48c99ca,
+                    0x4e06421d4206fa41,
+                    0xf02fa36ad5d973f6,
+                    0xd3737d42bf569ed7,
+                    0x00e400d3ac395945,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xab800d88f43898fc,
+                    0x0fffcaed5e50473a,
+                    0x3817e703c1eea34c,
+                    0xefd801dadad27fd8,
+                    0x14189fd090efcece,
+                    0xb5da35a20540fba1,
+                    0xc6fb454dc8c565c9,
+                    0xa0170b6ba42b4cba,
+                    0x019f6e2b2054f353,
+                    0x26abe41bce5d0a27,
+                    0xc4f32b85c1bbd72c,
+                    0x0042091b2babeb92,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed2096d57a57602c,
+                    0xb949eb0f073aa02b,
+                    0x2c64bf5b71d22025,
+                    0xc0ee06272abd7acf,
+                    0x9f7a6bd98ffff2e0,
+                    0x24700c572c1c2c4d,
+                    0xe8f6182ab418bcab,
+                    0x963993790e12e25f,
+                    0x7b679688b9550023,
+                    0x1fce2647e56e1359,
+                    0x8ef7ee2d20517069,
+                    0x0030fc3d2e3f4843,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfcb7e1d4170a5ec9,
+                    0x52bdcc15a22c2389,
+                    0xea142f46dca8722c,
+                    0xbd773416feb5417f,
+                    0x02aeea34e5464526,
+                    0x76bec256ec8c7733,
+                    0x80d8922bf3071971,
+                    0xc20e0c794b66f536,
+                    0xff6c50b063ce6c4a,
+                    0x80728500336f5268,
+                    0x5297ad95b5076b08,
+                    0x00b4a39af9a5470e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0fd8c80a799a1317,
+                    0x1e138b54343365cb,
+                    0x8f668ef3f5202a1d,
+                    0x42b29398e08a9d90,
+                    0x7c750b8d056cef68,
+                    0x439fa1a65a678ca9,
+                    0x24d6572ae46d31f5,
+                    0x1d9e58372ab24bcb,
+                    0x23c5999ef3ec4c30,
+                    0x30274845d30fade1,
+                    0x01715d60ef8b238c,
+                    0x0115df0d14af1d80,
+                ])),
+                Felt::new(BigInteger768([
+                    0xadc7d26be557fc0b,
+                    0xbb243a4c0e4a6142,
+                    0x4a4af96129e62098,
+                    0xa2216a6fde7e1bc7,
+                    0x4ce25f0d71af9791,
+                    0x8bcace026504cd45,
+                    0x1482466170ab36fc,
+                    0x387bc8c17bcae362,
+                    0x800e1140dec562cc,
+                    0x85459092da3aee70,
+                    0xe946e850c14e99af,
+                    0x0018355ecb83b54d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x73b45f64de71b4d3,
+                    0x2e61811ca8051caf,
+                    0xfb16c7b046d603ad,
+                    0x08c1021971201d9e,
+                    0x71d823fde8765e97,
+                    0x9fd3bc9fbef41fdb,
+                    0x939b01527e003721,
+                    0x22e9baa50de25263,
+                    0x0ab0259d13f44f2c,
+                    0x11485afe15c2192d,
+                    0xa75de3ff43e245c8,
+                    0x003e7e5a171bec78,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc804b644b35ec1c,
+                    0x8d8405c316cb5302,
+                    0x5fb7f7ebf846de64,
+                    0x89ae3094af828535,
+                    0x3bc202d87c0fef09,
+                    0xc55728378654b1f1,
+                    0x427099cbce1fce87,
+                    0x24529f993e162234,
+                    0x891d02b61a7e2405,
+                    0xacd217d6a1a79d8c,
+                    0x88d1a4331f1e06bf,
+                    0x010c15044ff6c052,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde65a7747e6cec7c,
+                    0xaeec2be3e89cdbe5,
+                    0xb3bfb38114b498e3,
+                    0xdd65f31bf37615ee,
+                    0xcccda8076183c224,
+                    0x4383bab4837e8c13,
+                    0x6537c9d44e655b47,
+                    0x1cfeaac9cdd1ef80,
+                    0x9c34e87512e5a949,
+                    0xa8214eaae5ccd19f,
+                    0x6080716ced441e25,
+                    0x004c938c8cff7bae,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c0c86c84b0393a2,
+                    0x2d3fd1c1cfd70db8,
+                    0xf65aa6f1dc9cd72b,
+                    0xad83b77b1df5e6ed,
+                    0x6b702efdc3b2f9a6,
+                    0xf9609daa8643443d,
+                    0x1a7be4e21a260015,
+                    0xc4c69843b5e248b2,
+                    0x7ab3a35b7b0f00a7,
+                    0x0a09742e552dbd68,
+                    0x1985866bd61c0c1d,
+                    0x010d8fcd70f91907,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe6be49f68241c247,
+                    0x3a9e6c25fa95ecc7,
+                    0xc01fe1e6432f1551,
+                    0x749eba426183a026,
+                    0x3b5f21b0a8cae877,
+                    0x3a90aa8cb77a1597,
+                    0x7164bb402b9ed677,
+                    0x61f368e6eea9e412,
+                    0x71d66a060f4dd2cd,
+                    0xfb1e2bc39497ba84,
+                    0x0d21cfa671d23ab2,
+                    0x00767f63bcc09547,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8562c656e9d18957,
+                    0xc8b2b29d70fd7cc5,
+                    0x5c7f067525e90318,
+                    0xc992e72292901f00,
+                    0x7e2ba4b30fb5feb4,
+                    0xd0ff7e2ad49b85d2,
+                    0xa9a968e23748a085,
+                    0xab9c589921c5b8ad,
+                    0x2ecb3f29cdb6de04,
+                    0x6f223ac7b0fd7f6c,
+                    0xa6c6e2e9180cc71e,
+                    0x00c98c4c4f5278ce,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4163de6cebb3668a,
+                    0x07585fdce3cd98f2,
+                    0xf9e93d55147f2357,
+                    0xb0ca48149f30e858,
+                    0x4d963d71a4a1bda6,
+                    0x02414284197a62d6,
+                    0x2efa2447664a15e0,
+                    0x14e190d17f9ab8bb,
+                    0x0192f8ba5d06e233,
+                    0x64852eb67812ebc1,
+                    0x7dc02bae4eb44820,
+                    0x0060c6e246767c22,
+                ])),
+                Felt::new(BigInteger768([
+                    0x217e576749356324,
+                    0x9fbc0cf7e8c5e6fd,
+                    0x3d023f9e2bdd39b7,
+                    0x3e49be592b7db7e7,
+                    0xd453247cc2a01c79,
+                    0x662b1960f1b8cf31,
+                    0x5acdc66734f5d802,
+                    0x611e4861c6feb43c,
+                    0x792ba0eb04491e9b,
+                    0xe357d4cce1740e43,
+                    0xb0ae70aba4103124,
+                    0x0053a33dc017ea9b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfdf0e01af472765d,
+                    0x5dcfa483df02d546,
+                    0x3f1ab9a390bc9f88,
+                    0xe574da5c2482dd33,
+                    0xdbb71f3a0940c2d4,
+                    0x68e875169308e8be,
+                    0xad37bec7579291d3,
+                    0xa57620b6c145296b,
+                    0xc72e0bcc8f61033e,
+                    0x2151b0a878dedd8e,
+                    0xd8633cee2ba8b4ca,
+                    0x00cacb85a92d25fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdad7b83454693063,
+                    0xc484d0b82a70997b,
+                    0xe81381b39ff1c321,
+                    0x36d5861eccabb7e2,
+                    0x61fcdf3fdfc10d05,
+                    0x46fa8e8a605405ef,
+                    0xe0de281439970298,
+                    0x531212bf06df627b,
+                    0xc3c700dc170a7c38,
+                    0x503ed5e0955e5a7c,
+                    0xc1aba0bdd1d0633a,
+                    0x00f1d22b9b636f55,
+                ])),
+                Felt::new(BigInteger768([
+                    0x69cff6c304c99872,
+                    0x0bbbb2eeee59b831,
+                    0x5da708688db83c67,
+                    0x198678b854b9cf9c,
+                    0x4cc58f3545b81ab1,
+                    0xf3c968ec7d56569e,
+                    0x6e8dcf6c66be6f14,
+                    0x072a9ad73f65b374,
+                    0x6f7d702dfc1e1acc,
+                    0x599e9e13a018cb53,
+                    0xad9af61b4f2c7efa,
+                    0x004895ebb1b10f6a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x17d86cc8da3812b7,
+                    0x875a52f20df13944,
+                    0x800a10fc9f62c50c,
+                    0x259253eaa3190315,
+                    0xf4e5e438b16a5349,
+                    0xf6e0af68da81604e,
+                    0xabcd403b4314663c,
+                    0x3a7cfd0d132beb8f,
+                    0x7a1d06b02f08800f,
+                    0x9fee4722a900d0d6,
+                    0x25ab04c327b4d7fa,
+                    0x00ddceb7e3eb8815,
+                ])),
+                Felt::new(BigInteger768([
+                    0x45efd934cf970765,
+                    0x6f914c778c0155d6,
+                    0x7c9ad402adb5c4cc,
+                    0x1e307db7c75da194,
+                    0x7c3afe77f02da28e,
+                    0x84ccacc581f5888b,
+                    0x341092c29f070ae0,
+                    0x64ffddf8c08a5592,
+                    0x4ce04ba1d7224a45,
+                    0x2330c616545e65eb,
+                    0x7975b34a77f44012,
+                    0x0100a71308248d4a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x221e5200dba22751,
+                    0x32be1b4e560c3a21,
+                    0x9fd0c504ec33e1ec,
+                    0xe568bd8fb8cf82f3,
+                    0x4f06eaca36f61414,
+                    0xdcf8b19b9b1fd6a2,
+                    0xb63e45a06be92e87,
+                    0x847f15c28c2f6fc9,
+                    0x8d8adf6548d4e136,
+                    0x412c29ed3fb4851f,
+                    0x48e9ec8d03654571,
+                    0x00d97cb92c311253,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc947721929871cca,
+                    0x15234fa0cbeaa628,
+                    0x568f0e5b68572b95,
+                    0xbb6c0f4c68f1ddd9,
+                    0xe083dec54a758e8a,
+                    0xb83ba79f12360ada,
+                    0x88e3022bbf15ed70,
+                    0x2f0d7677f19b2af6,
+                    0xd18bf8f62951a850,
+                    0x5796422566ed8585,
+                    0x28f115e288520fa2,
+                    0x00f45b94a634ac72,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xce6565e1a2c41840,
+                    0x1baa4288421567e4,
+                    0xe4aa27563b51fe9b,
+                    0x148ea25bd1c4dde7,
+                    0x66d9f45e9d70bc8b,
+                    0xaea7834b93ed572c,
+                    0x41659efbcb4d4819,
+                    0x09e491cf696f44d5,
+                    0xbb2dc5b8b013ed0f,
+                    0xa8367913f18c814f,
+                    0x3a275d77fd1549ca,
+                    0x00fc09db83b23968,
+                ])),
+                Felt::new(BigInteger768([
+                    0x02ef1aebf5b255b4,
+                    0x6e7b26a634ba172a,
+                    0x266fa39868e74c81,
+                    0x4427bdeff8ac52d0,
+                    0x8a4498f8d0f3e610,
+                    0xf1ea4b5dcab147c4,
+                    0xcad849845b70134b,
+                    0xc5e4a95a54d77615,
+                    0x590dde6af402c6fd,
+                    0x4391f03916ebe7c0,
+                    0xc6fe2478a890f528,
+                    0x002725460b513c90,
+                ])),
+                Felt::new(BigInteger768([
+                    0x457453f2df8a7f85,
+                    0x5e38d805386f9876,
+                    0xfca5bd94b9c95f2c,
+                    0x84e996a694fd52b5,
+                    0x1b7536813c07690f,
+                    0xa9dc78dd0d69d97d,
+                    0x3cb2c5ae32d66810,
+                    0xf061597d37386883,
+                    0x9a77fe049cba8ade,
+                    0xa90a86bffcb87e5e,
+                    0xe46bd178c8ff8659,
+                    0x00c19d97dfa568d7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x11a904225b584bb2,
+                    0x0410489851b03141,
+                    0xad272cbf04c469f3,
+                    0x4c7a0fdf513fd5b2,
+                    0xcf90d4e1980ecc74,
+                    0xd1e0418f39d90f76,
+                    0xbcd19019f5260334,
+                    0x2cc977ae8cb3e27f,
+                    0x58d3e4ab9a2198b4,
+                    0xe0d6203b352af024,
+                    0xc0e3ca8abcaa92e4,
+                    0x00e4b41ab6b312f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d8aebf99d6b845b,
+                    0x11c7d9963e1ef928,
+                    0x1f97744aaac9a9e1,
+                    0x8524213d6e0ae67c,
+                    0xa78fdf4db1699bea,
+                    0xcd9a43967c64b809,
+                    0x06c97ad43e1e0c4c,
+                    0x58e57ba90096f3f5,
+                    0x69d934a1dc3d6dff,
+                    0x61d059ce6ad29ad2,
+                    0x9045e98e901d46d7,
+                    0x00290f549a707e2f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f0f6d2eb6284814,
+                    0x39b82342f43ad91f,
+                    0xa042b8994938c984,
+                    0x2fa1a139a9dbe9a9,
+                    0x6761259e77e2d1c1,
+                    0x47b8e2118af39811,
+                    0x2655e402f11124f0,
+                    0x80963e253304fcd3,
+                    0x362123c8962edfbb,
+                    0x1ee36f5f3c1f6538,
+                    0x9a3e31359f70c0b4,
+                    0x006786a8edf2238e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd9ee13cfe69ef8e7,
+                    0x2c6f0e4886cf2580,
+                    0xcb93225c8ebb4106,
+                    0xb4ff1f14241d52b6,
+                    0xfe4268c0ba1d8a12,
+                    0x46e8bbe4f682e680,
+                    0x69d6da96bbf8302d,
+                    0x5e49b5c6dad80fb0,
+                    0xa9fd849e8a5d0f0b,
+                    0x244f59ba2e93505e,
+                    0x27fbb56777375b59,
+                    0x003c4418f3eddf35,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7544367c206b39d9,
+                    0x352c3d8ae9582d5d,
+                    0x89a6bda693425074,
+                    0xfeb1a36ef667048c,
+                    0xd3aadeaff7d21cce,
+                    0x734ca6eacee250ef,
+                    0xe68350a3e3698e7d,
+                    0xec3d704ea05cce8a,
+                    0xb106d4b07acd7d20,
+                    0x489532755aa13e2b,
+                    0xbe2839c42c7087a3,
+                    0x00fd51a4c6544dbe,
+                ])),
+                Felt::new(BigInteger768([
+                    0x528be267063f8080,
+                    0x2752c355d27e1774,
+                    0x8283e73a284734d1,
+                    0x2100731edb39df1a,
+                    0xec12118fdde74fcd,
+                    0x355471e43875375d,
+                    0xcf503ed0e099c7a5,
+                    0xf5a816eb0904550b,
+                    0x81c58f8d3f818eed,
+                    0xd410ee6aa71a2b8c,
+                    0x9d9989737436e3fc,
+                    0x00f97c4a91577176,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ed8bfdd5554d341,
+                    0x112ec1e63ad971bb,
+                    0x3a741424afabb282,
+                    0xb69f5876f5f6954b,
+                    0x057ab67fe46602ba,
+                    0x323ece2960a2ed3a,
+                    0xf5d31e7ee4a0bead,
+                    0xcff1095c2ad2be7a,
+                    0xdb03c41bd62c8c6e,
+                    0x270cf2e7f68918df,
+                    0x714cd8298be1037f,
+                    0x008be49cefb39461,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5966d735ea989217,
+                    0xb54f012c9cd25acd,
+                    0x45ad42ddcc8aae4d,
+                    0xdf9ebe1cb7e15e42,
+                    0xf9a39a73c83e117b,
+                    0x9ec65a1adc95a7b2,
+                    0x3eef9a68b44ca0cf,
+                    0xf4096daa8ae9cc24,
+                    0xa3db942d5e7c4dd1,
+                    0xdbd0caf4c07a61bf,
+                    0x0ef87a38a03d1197,
+                    0x00c534180eafc049,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcd530303541fb31e,
+                    0xafc101e8133362ec,
+                    0x734efd67ac1de134,
+                    0xea74f5fa8680c175,
+                    0x423386761ce0651c,
+                    0x61c62255655df609,
+                    0x5b31a5751786d4a9,
+                    0x95de30cb4ae7a14c,
+                    0xed733a50128f058c,
+                    0xb77e81a4bc0d194b,
+                    0xf009df79c5718223,
+                    0x0020b1f6451e62ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x76b57be002b1f11a,
+                    0x4eae36883ac8ea76,
+                    0x960efc5c7e67fe12,
+                    0xcb3aefd421593846,
+                    0x39a0a9daae0db779,
+                    0x958a13fb49c10038,
+                    0x735d65830a5ca345,
+                    0x70acc98dbf4490dd,
+                    0xf6981b485a2fcd53,
+                    0xb6599e34daaf44a6,
+                    0x6856469944328fbb,
+                    0x00e1b144b1aee260,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfeb2cef2181063d4,
+                    0x5e5be65c9bb3bf62,
+                    0x0d6cc6f26357cce0,
+                    0x44b935f6ab5144ba,
+                    0x38f265106fa17147,
+                    0x8e250b2381910e17,
+                    0x4f4a5e3bd2156323,
+                    0x4b4496914c42ee7e,
+                    0xe2c1891b1230cf01,
+                    0x998f44824cdf6fc4,
+                    0xec5e38d5203d162d,
+                    0x0028eff079eb216a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xec7fef70b97b45ad,
+                    0xa5a24688f05f4989,
+                    0x61a61ab69414f289,
+                    0x21137bbc5a7d764e,
+                    0x467b83a05cba8817,
+                    0x0f2dd18c9387ca92,
+                    0x51922add1dc66a88,
+                    0x6792d244c1f97036,
+                    0x38f0be8eb851b00b,
+                    0x8f3e9ac1e0cfc1a4,
+                    0xc76aded1caf9d163,
+                    0x008ef874b2a86533,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6dfdbe40ddcb6a3d,
+                    0xe32b4cd9376218e3,
+                    0x9c6cab7be5d2f359,
+                    0x331c3bd74a37fe5b,
+                    0x42793510d137d989,
+                    0x62315ddca5413e75,
+                    0xc87846265f8bef22,
+                    0xe7d439200a56eb3c,
+                    0xc29876e08b56ec88,
+                    0x83bef1d5e2dd4322,
+                    0x1d4995e120100a27,
+                    0x00e506992b9e1c1f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x25a0d90a0feb65ca,
+                    0x3d2282c9a87751b5,
+                    0xbd1f0f3b16b3ff48,
+                    0xe8e137df3a318542,
+                    0x44e0948d772316c2,
+                    0xcfd6d58b45221a1c,
+                    0xb5c7d2fe3c7ee558,
+                    0x3be2c8a1ea3771f2,
+                    0x5f2204398620e1ed,
+                    0xee4e216770228a9c,
+                    0x8fd539891a8b8d76,
+                    0x009cbd398473a22b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6690217c3c5489d9,
+                    0xb93bd7be9200e6ab,
+                    0x5e41fe2bdd04c0f6,
+                    0x6114381aa02afa8f,
+                    0xefdeeb17feb582ff,
+                    0x5b98883f8f6ccc4a,
+                    0x52f708ebfd905943,
+                    0xc87165cb847fc35d,
+                    0xbcec0d9214f30882,
+                    0xe4b51a9c5c41defa,
+                    0x3652fee8020a03ac,
+                    0x00728fbc11e2367f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbead80066159cc2c,
+                    0x48417cb3a0d2679a,
+                    0xb88c91cea541c54f,
+                    0xe2adbab46af9df91,
+                    0x243cea9bfba8ab6f,
+                    0x96fe1c051cb02103,
+                    0x60a3e37efe588e99,
+                    0x0003154e9531663f,
+                    0xadee75a580408038,
+                    0x376a0aa13a0bbba3,
+                    0x50f5c3ba16f99585,
+                    0x0056418f7e76013e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc97a3a80bed6ea9f,
+                    0xe808499fe57b9da0,
+                    0x753ee25d7bcf1900,
+                    0xd1edf3fe6afa5255,
+                    0x534d7c358124b5af,
+                    0xb1bb5cc0696e32fd,
+                    0x2a33c9cded925b64,
+                    0x659f88258c366ecd,
+                    0xab3f593b5d8bd0a5,
+                    0x5920c8e2cb559509,
+                    0x0c10f8fb50755c25,
+                    0x006f58b2d8876664,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3e6234781897ceda,
+                    0x53901953bc36f37d,
+                    0xf66690c81b9cae7d,
+                    0x36435c64e0cdd743,
+                    0xb9e2221eb80076c0,
+                    0x946bc3e6e570556b,
+                    0x3c61a514143e0800,
+                    0x03305e6946a8d472,
+                    0xdd68340fd5155747,
+                    0x1d6a77e2cc631df7,
+                    0x7d30c54eca6d0e21,
+                    0x004213a3f43ede6e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x336129f97f6676cc,
+                    0xcdf9e0e9dd30741d,
+                    0xb1b06ec74ac254e9,
+                    0x95ab77cc0cd94e36,
+                    0x219e986a5ab43eb9,
+                    0x70953a75aa6c9d23,
+                    0x8b8407a95f51ff6f,
+                    0x7ce9a6145cf8242d,
+                    0x85b046c503ac0a15,
+                    0xe2246d0f1c4e27f9,
+                    0x75a44a3a8800bf07,
+                    0x00f3512f600bd923,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12b74a65b942ff4e,
+                    0xd13a62a5657a47bb,
+                    0xd5d6a306c8d35507,
+                    0x36b9d319f2ea98ae,
+                    0x59105e8b476d82bf,
+                    0x63f27c2ab500a252,
+                    0x046e53666c0d42e2,
+                    0x875762d9c3105257,
+                    0xf7a575dfb2f48636,
+                    0xc23baf97da75ed6a,
+                    0xd78ea0aa27357e20,
+                    0x008c43e4b59290a9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3217f11eed7107d7,
+                    0x2c14c6407f57d9a1,
+                    0x55eea6fbafcaa438,
+                    0xe56508f5b4a74e86,
+                    0xc5de8d7750f11af7,
+                    0xd50363ec71de8c50,
+                    0xb81d07066423eabd,
+                    0x2f88c1d64e1d6684,
+                    0x39295f9e47f157d0,
+                    0x1d1fa747593e8da1,
+                    0xf2e7bcc5090bef95,
+                    0x0091498389054368,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9c7f104ed0a4e330,
+                    0x2031edc5017d947e,
+                    0xdb857c8ddedb65aa,
+                    0x5c679e180d1b1ae8,
+                    0x15db0f8f0f3b0da6,
+                    0xad40c8c586404318,
+                    0xae21f05044527ca1,
+                    0x64b82cd8cf5afa49,
+                    0x8497da6623cf04bb,
+                    0x54a50701f03de960,
+                    0x7ac2ba9252f7c97f,
+                    0x0081d03144d3f55b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x100f6d03b7edf4b1,
+                    0x366112bf103fa6ec,
+                    0x1b218d5883b0854b,
+                    0x0abdc505a3aa588a,
+                    0x532809733c8299ae,
+                    0x077a0463bca55caa,
+                    0xb89cfd35e55e52ef,
+                    0x28f7faf0d3bf1151,
+                    0x0fd486cac12d8b54,
+                    0xd3f7e4ea67ecd27a,
+                    0xaeafb11cbdfb93cd,
+                    0x00ad8e55f5f57173,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b64111b990989e0,
+                    0x2e94c7c5f8d88bdf,
+                    0xf9fa42ee8c3d12f3,
+                    0x2084de61928e9f11,
+                    0x3712a3ca8e731ad1,
+                    0xe828e88288875973,
+                    0x52a8f62051a43d65,
+                    0xda0823a767c2b8bc,
+                    0xebc1f3ec481f3c61,
+                    0x4adb58274e0fb534,
+                    0x445929ec90502d9b,
+                    0x0030c961e7225154,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae625093d2dba79b,
+                    0x133cc8e16585d3c9,
+                    0xf4965e8590484add,
+                    0x0294a8fb3750f3c4,
+                    0xc8c5a0b1e2bce1a2,
+                    0x7db5500fafbaafb1,
+                    0x5cb8fbf8a8053e5b,
+                    0xc47b75a760ba6f75,
+                    0x79f55b429b5a4703,
+                    0xe2f9a7bf8eabbd6e,
+                    0x55ea0cb281e95e76,
+                    0x0000df987a8947a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x95ab1e97390d9f8b,
+                    0x10f4be7b7a465350,
+                    0x9f3ba5c8b9507a12,
+                    0x981ffd7d28a5882b,
+                    0xdd44af0756f306ce,
+                    0x17453c74c877a869,
+                    0x12640bf59ff10341,
+                    0xe1082d4d766f344a,
+                    0xec9e8adafa3a55ed,
+                    0x8fa3692f186aafe7,
+                    0xf8d60bebc55bb1f7,
+                    0x0006630e1d66f80b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf1e6f3cedbc48a30,
+                    0x9b899f43ce92cdfb,
+                    0xab54dd73d1613d15,
+                    0xb11543915de9873b,
+                    0x8f1ed2df48314f68,
+                    0xbbc193d38be51d53,
+                    0xc85a0ade7aac59f9,
+                    0x388aac73e3c7700e,
+                    0x3d3a866e6e6c3d9e,
+                    0x2677af65d4e090f7,
+                    0x07e66dae757ef459,
+                    0x00f528a197de75f7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe844d70eb7590db1,
+                    0xb9040e5167119334,
+                    0x99811eec0c203199,
+                    0xe50a34f15fab906d,
+                    0xc904a185de0606c4,
+                    0x03663f31b82eb2cc,
+                    0x14bbcec4851400d5,
+                    0x1b7e7be2cf17a1fc,
+                    0xe692e41457714d69,
+                    0x9aa3bdb6fa790463,
+                    0xfbc3679f4ae88604,
+                    0x00ecc7e0070f3a78,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd250c1d81c0d008f,
+                    0x8eae32e22e2417d3,
+                    0x4c5a4ba87b104b27,
+                    0x9385c2f56287ca58,
+                    0xab3baab906eb186a,
+                    0xc50fe16ec45f38d0,
+                    0x28a6ec6a207a95f9,
+                    0xfb1dcb76ee506fe1,
+                    0xc8326d7d3220087e,
+                    0xcfdf61a6470cb24e,
+                    0x6ecafbff561117cd,
+                    0x00b7825e1f3dc1f6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f5b67521581d138,
+                    0xcfeb6444e676521b,
+                    0x28ed3a52c33f6e47,
+                    0x3d4efbbd3abf53cc,
+                    0x022d4e76e4e94869,
+                    0xd9204377be1ce525,
+                    0x818c0fc332e7408e,
+                    0x35b1217c5b87c704,
+                    0x8be80f745885de58,
+                    0xca2150a3bc830bf8,
+                    0xfc914ee9b5d769bd,
+                    0x00330bab65b5196b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x46b916fd9d14a78e,
+                    0x28d7e013512d2c40,
+                    0x89b7819218717fc3,
+                    0x486f4ff1ecf1e584,
+                    0x98d2613a1952942a,
+                    0x10a71e06a51a2ff7,
+                    0xf08c49f8352bf150,
+                    0xc6ff66d08a9236b8,
+                    0x7800a3d82be89b73,
+                    0x57a69d52016f82c0,
+                    0x1d51de20eca04832,
+                    0x004315491496dcbc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1dba4c096ef211ab,
+                    0x85de9a13ff4530f6,
+                    0x0e42e4298190a95b,
+                    0x78731e8e71f94003,
+                    0xc98489ef62eb63e4,
+                    0x34566a6cdc0087fd,
+                    0x4309025f044eaf10,
+                    0xa146695e65e27104,
+                    0x4a3ebd648c2245c6,
+                    0x282b4b37fec5ca1d,
+                    0x3b9babea47f03d47,
+                    0x00ccb4178133962d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9244c727ecb26d28,
+                    0xa8cc4c39bde47ef1,
+                    0x5144bce9de9d2722,
+                    0x347adbdf26508788,
+                    0x13523ab790abe587,
+                    0x83063aeab163413f,
+                    0x0d432187d4d9cf3f,
+                    0xd43089ffbb867ca6,
+                    0xae6404479a0fe3a5,
+                    0xbb0b4cc6f0740d91,
+                    0x1e4fd2a7e263519e,
+                    0x010dddf0e4fd6f26,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf32354e25ef521df,
+                    0xccd5e0f1f5c634ac,
+                    0xd3aa781122a5ed59,
+                    0x84564febd834998e,
+                    0xc8f7a73c8e8f0571,
+                    0xe3cc9e14a2eeab8a,
+                    0xb9dda61b7d14355f,
+                    0x68c1f4ee7287114d,
+                    0xfae84282cd092cdc,
+                    0x782f4a8d64d5c53a,
+                    0xa6fa458bf70226ff,
+                    0x007c79212eb9acaa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x389039810d624f3c,
+                    0xf659b659bde1b2f9,
+                    0xb16c389abcd81b80,
+                    0x841d16d49f172f2f,
+                    0xe9a3ec2ef0f3ef1c,
+                    0x8c7312b725fcffe8,
+                    0xdbfafd6e50dfeb7f,
+                    0x20409ef25287ef5a,
+                    0x86ea20e294d19b93,
+                    0xfefe8be64d6cb8cb,
+                    0x04f1771a319d159f,
+                    0x005f6e3d345aef9e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4beed2cf1d0d660f,
+                    0x896fba693c09f407,
+                    0x050f3883a9f080c5,
+                    0x9b289778850bea37,
+                    0x5940b6ab59d7e98b,
+                    0x51c9e9fbd19414a4,
+                    0xaf9f78d24db7d95c,
+                    0x94ca340b2c3ece36,
+                    0xedca28f90ff20f18,
+                    0xdd0f95b05504036c,
+                    0x4a26dfdb0616d405,
+                    0x00e899a4f39f9aaa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6cbf9b9337d4e6a6,
+                    0x4d1dc0056c372089,
+                    0x268f3413e6c4c25a,
+                    0x658c5845b9fae4d7,
+                    0xcf6b439dc432756e,
+                    0x9dc6d7338d01431d,
+                    0x24832ebc2c71e0a3,
+                    0x8b4589089a2d5ebe,
+                    0x7a6ceb093ca5ba64,
+                    0x81314eaec86dfb04,
+                    0xaa4588a622a9dbfa,
+                    0x010a88cf90cfcd5d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7764862014cf4ad4,
+                    0x7a1ef497c450cc6c,
+                    0x4f7c7acfee158c17,
+                    0x8ad67e4bd5e2ca7d,
+                    0x82b2dc95675c4985,
+                    0xe0c06014e7dcd125,
+                    0x15876e9438c77771,
+                    0x6a792b9f4915383f,
+                    0x9b99efe82b72e18c,
+                    0xcbe5fadad296cd40,
+                    0xf6ab6a0b67f71bd4,
+                    0x00900659ae133545,
+                ])),
+                Felt::new(BigInteger768([
+                    0x62f15e2558aae29b,
+                    0x21fc00ea26bbdbb8,
+                    0x980f28ab08698e71,
+                    0x83153642db21367e,
+                    0x763cf0afdd5e72a8,
+                    0x81cb0b33775574e5,
+                    0xfa397ce6466a95c8,
+                    0x811ac59b81fc3aa8,
+                    0x51edd24392ffe58a,
+                    0x6969c0db79b2fb41,
+                    0x4c18408558f8e477,
+                    0x0023b74984c950b1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87b449717543a92c,
+                    0xccfb0e154ea4e95a,
+                    0xd48c1929cf7e3f58,
+                    0xa0b0e8e7b8809cea,
+                    0x7dfb31d6f7bcf757,
+                    0x57ba87792ea53f03,
+                    0x8085198a5feacdcd,
+                    0xa728db560ff6cea7,
+                    0x1b58659b9270741b,
+                    0x4eae8595c456815e,
+                    0x79eabd218213eca5,
+                    0x00a77bf4144031f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d29878b982d31fc,
+                    0x0b3d97067535cc90,
+                    0x57b04bc5e4b1baf7,
+                    0x7042aedd43fa1ec4,
+                    0x57a5692ca0af1cc2,
+                    0x33c220fcbf7c5e35,
+                    0xa3540200826a5d1c,
+                    0x27219061c8b4db70,
+                    0xf6e195488965efb4,
+                    0x8d273d382630438f,
+                    0xfac0f6a3e7911f55,
+                    0x0090ca0012db13a5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbddd13fa04c28bca,
+                    0xa3d0bc1e8ffc1008,
+                    0xcc7ae0df26089dd9,
+                    0x53147b05e3d5de1e,
+                    0x2988861bc3790732,
+                    0xa7c012dccd01fd67,
+                    0x337bf8bc6a581a33,
+                    0xb3b247ca211ede46,
+                    0x105dd66679b2929a,
+                    0x34b9a1903763ad6b,
+                    0x20791d993dfc2600,
+                    0x0015dbb6f3cfb90e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0dc8c040bed2177,
+                    0x2f2966a1015e978b,
+                    0x5a2b3a3390188b21,
+                    0xda18b51cb21b6ed3,
+                    0xc6687b669ea01ddb,
+                    0xd7275faec36e0f00,
+                    0x87dda3e8ad3ac57a,
+                    0x231d76adcc84a170,
+                    0xa32875ca3c5f30cf,
+                    0x5f0da6923b3f6826,
+                    0xc610a3f98b889578,
+                    0x00c25c6cb158a64c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbbb502a9420a32fe,
+                    0xbb28ae7eaa42be2c,
+                    0x1b1229ba43049926,
+                    0x8fc9355b0b2e4f0f,
+                    0xbf7c0f42024ca077,
+                    0x98d4951e46d21b47,
+                    0x53d9c9c1e21aced0,
+                    0x3581fac67707794e,
+                    0x032c34590c6fdf59,
+                    0x38b67e63eb57494e,
+                    0x3044ab08a1191883,
+                    0x00ffab003fe125be,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c3761c50d92bffc,
+                    0x2c483d4b08b9e505,
+                    0x250f6d04bb4c9439,
+                    0x2255715a4bc46ca0,
+                    0x40371904462b89aa,
+                    0x53210eecb3894c2c,
+                    0x33b29a739e06ac5a,
+                    0x0ed5201a6a87e3ac,
+                    0x4f084fbd8b8029f3,
+                    0xde0b071e23c2aa92,
+                    0x395b127d147fce6a,
+                    0x011d4f318ecf0b29,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdc43cb43b31acaa5,
+                    0x94609538a8a3b214,
+                    0xb2ade3f1d246859b,
+                    0x95eae31c3b2c370d,
+                    0xac21668a4ab3045c,
+                    0x5c83cea40bece6b4,
+                    0xc35796b97cfcab92,
+                    0x4957ced169d594ad,
+                    0x079d44f7a0d88627,
+                    0x633a721a400d2b15,
+                    0xf2cbf2cdc9d850ad,
+                    0x001530b354f9f032,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdd042e185c457c81,
+                    0x54c8b9758cf0d696,
+                    0x75bd129dd304f678,
+                    0xfb61ede9fd990251,
+                    0xcd95b84fb8600ab8,
+                    0x8c4761e2f95e03fe,
+                    0x8bf3176a7215530b,
+                    0x87b23c9eefa67a8e,
+                    0x4ad947b21c2e5be4,
+                    0xb6305f7717c4d816,
+                    0x3eb983a8997b0234,
+                    0x00c2b129a11dcc0a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc453c46b28fce058,
+                    0xc88bebc6df5b1b1e,
+                    0xe1d3ac3dc925ffa1,
+                    0x1830bc3a9262f389,
+                    0x12a189dee0a064c0,
+                    0xd7fa627759382a63,
+                    0xe3cccbe88d107a8f,
+                    0x952e715ef1a6d76f,
+                    0xf7689ea7e413a412,
+                    0xcaff14958d359c5f,
+                    0xd43b2e07f2ce6c03,
+                    0x00f244b0a798090d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc97c96a8f4fc9821,
+                    0xa2f8e8a85851d0f4,
+                    0x9c9012cfed7091b3,
+                    0x0cfd64d53e11c1ae,
+                    0xb319015b3d4a5798,
+                    0x19355bd2c1ddb70a,
+                    0x42f240c849699532,
+                    0x185f69513d16dcad,
+                    0xe2c6d6e0eb69a136,
+                    0x9621b726fe34fef5,
+                    0xa2633169086d0ef0,
+                    0x0091a990249db3b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5c29d19b7caa8ceb,
+                    0x44a6188f6e1474f4,
+                    0xed86fe122dfa6234,
+                    0x361690b2bf6323b8,
+                    0x3594dc73b5b0bb2c,
+                    0x458300daf2b9e045,
+                    0x2181395f0df56bf6,
+                    0x03424c435b5c23cb,
+                    0x0d294e0a5d1145cb,
+                    0x987e30dcaa09a604,
+                    0x233a751f0c059a02,
+                    0x01099fdff86f0c35,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5a4a1a0c2328c95f,
+                    0x5112d6ecd77fc8bc,
+                    0xd10dae084e144097,
+                    0xdfd66564f8a9352e,
+                    0x6902a19ec04355d9,
+                    0x038351881dd92615,
+                    0xf62730a8f6c0336e,
+                    0x9195051aadbca774,
+                    0x8c6e0e0995eac5d1,
+                    0x6ade873b5dde0573,
+                    0x0f9db0fa96df8821,
+                    0x006d1d7627c2b112,
+                ])),
+                Felt::new(BigInteger768([
+                    0xefc8d7f5ec66c5ec,
+                    0xa946588434fca3da,
+                    0xc782120a19e7b740,
+                    0x5acee360447e22c2,
+                    0x47c19f55eaac8a75,
+                    0x163004baa2988dd3,
+                    0xca1cb454258528c9,
+                    0xe9b95757432785b7,
+                    0x7e4eaf53562957b2,
+                    0x10ddb86004063c49,
+                    0x23a679471da95489,
+                    0x002de0af2bc0229f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5568d633fac1f919,
+                    0xe9d3bb3ef3826b00,
+                    0x5a2ed20f2024ea35,
+                    0x9c44ed0a2d106b37,
+                    0xd13a4b4b2d88f841,
+                    0x8226d4b3518e5cc0,
+                    0x3073e1b2f645c5bc,
+                    0x8927ca9a4f8674d7,
+                    0x4f2d55c46e48a329,
+                    0xdbe4e6a46a24a096,
+                    0xf56b59c72f27a7d7,
+                    0x009006bba5ccdae0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3d767c797e0d4017,
+                    0x80e1cddac4ea7d74,
+                    0x667f7533cf38d293,
+                    0x92ca39908a29c430,
+                    0xd97a989ef5187400,
+                    0xf7b31e93f5c7cf3e,
+                    0x7bbb85c704f0a51a,
+                    0x33e581fa52d66517,
+                    0xdb82489673511ddd,
+                    0x2810cf1b87b888d7,
+                    0x23b787bbefb1ee43,
+                    0x011756a885331ab2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c61998e8a597863,
+                    0x9b391c2df0cf9683,
+                    0xb80e96739d55e8ef,
+                    0xbcfa2e7187821325,
+                    0x55d68da9b5d573fa,
+                    0x293aa1296e602739,
+                    0x1c0e01d33359fd65,
+                    0xb7a6ced039b2dd56,
+                    0x2fce9863d1889d35,
+                    0x52f46589406c32c7,
+                    0x12de650243027913,
+                    0x00e7ef02c83b916d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaed5ae0e457fca1c,
+                    0x713d45b7625a7f07,
+                    0x6951bc07faabc9c1,
+                    0x432c4adb006e3219,
+                    0x156963daf0b999e6,
+                    0xb35370a370c1ee1b,
+                    0x343eeb7f0db24538,
+                    0xab556eed326a0b59,
+                    0x6d7f6d5b75ed63e1,
+                    0x2822ab737fdc3354,
+                    0x6aa7a9b20d9a7998,
+                    0x00bb58202e09d87a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c3471ab8bf2e920,
+                    0x14c676909415152b,
+                    0xa70e3a74c3594744,
+                    0xe21ea4efddd67ecb,
+                    0xe26b2d3c909776db,
+                    0x44bb51ece781f9ba,
+                    0x15e19b5478f0f519,
+                    0x09125af785c51ab8,
+                    0xe50a18cc9d2bd988,
+                    0xe24235c0357dbcb3,
+                    0x00b657de641f82a8,
+                    0x00f70536caafda7b,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,5384 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 1152 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7903b34430839eae,
+                    0x80c9786431f76b10,
+                    0x4a85ec8182b8533a,
+                    0xf8cb68cd8b9ba6fa,
+                    0xe38a4ae3a29b8f06,
+                    0xb34a4ff80c9f6f8c,
+                    0xde1d1e0ae888be41,
+                    0xdb83029ad9554189,
+                    0x21344e7ced348c4c,
+                    0x0c77629d0242621a,
+                    0x70cdf54c9b518343,
+                    0x00d7cd7e7ac5bb62,
+                ])),
+                Felt::new(BigInteger768([
+                    0x640acd13fefd147e,
+                    0xbdd6c47aa765f3ff,
+                    0x466bd9639a0d1834,
+                    0x383c3015e7a3fcc5,
+                    0x90b4f2a6c235a5aa,
+                    0xb69057c3b6093fb4,
+                    0xc93fcef4d68c7eba,
+                    0xc9092515cff2a4f1,
+                    0xe86bba7845f19dbd,
+                    0xde8380f1b45276e4,
+                    0x116cdeefd1d25e43,
+                    0x0005676b57180e63,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ec1510aee8130f8,
+                    0x8f0c2615162986d2,
+                    0x30d9b79f8af28794,
+                    0x820e109dc0355afd,
+                    0x69056432fcfd7241,
+                    0xf70400ae0b0bc32b,
+                    0xdffcf517c23da47f,
+                    0x79b77f6d24c5f56a,
+                    0x0027fea12187c337,
+                    0x07fb6f2df90b5428,
+                    0x32c6b75802c64781,
+                    0x000bc6fcb903c713,
+                ])),
+                Felt::new(BigInteger768([
+                    0x335e0109939843ad,
+                    0x64c88d6a171da8fa,
+                    0x81923c492e2c1588,
+                    0x5808e31734981a70,
+                    0xce14aebbff862c18,
+                    0x2bfb4a7d30aa4b92,
+                    0xfc9245958b2d6ad0,
+                    0x5ac053188b2a7705,
+                    0xd1e77ce56704af9c,
+                    0x62094259501d0cdb,
+                    0x67fde04c34451e88,
+                    0x000e179c33588386,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe04ea32f1b224703,
+                    0x6bcc0d038c99470c,
+                    0x587b2b69c3d412e3,
+                    0x70dc6154c29d0c87,
+                    0x3497387e03a85f71,
+                    0x5d41498e663cf0d9,
+                    0x9a7aa7ba9761b82e,
+                    0xb0900fb2c3e07451,
+                    0x43765a8208f12d66,
+                    0x22adb9c1d0124a87,
+                    0xb223907682bb0849,
+                    0x00c2226aa0a40334,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5c795a702f9aca96,
+                    0xc13e195e8172ae29,
+                    0xf88553cd5dd7ac73,
+                    0x9e006220adda38f0,
+                    0x96c264cc8ba17f82,
+                    0x155fb8c4174746bb,
+                    0xd3d0b51d165b79a1,
+                    0x1e92cd615a7e0490,
+                    0xf28a8628a2f16371,
+                    0xfcc2f9eb62252b86,
+                    0x73e50b47172bb5b7,
+                    0x00eb91a170866a75,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b5392777864db53,
+                    0x34e927e55d42a034,
+                    0xd319e0209f5aa84d,
+                    0xf52f1c149744773b,
+                    0xea1c68c02f47aef4,
+                    0xd2ca29e1aea62ba0,
+                    0xe3e770f290a93df4,
+                    0xd09480d34b22529a,
+                    0x8534c6eeae53eade,
+                    0x22009e3009a9e9d3,
+                    0x9d4e38d189004686,
+                    0x0021ad4fb8d87ec5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6650ff9f1e0d6264,
+                    0x2313139d46577780,
+                    0x4bc28fd6a7453c7f,
+                    0x9062e95e7b944a71,
+                    0xab19e0631dd17335,
+                    0x1641a238d632840e,
+                    0x2da5c2c8ad503baf,
+                    0xc8d853ac75be40e6,
+                    0x663d7cf0447e6831,
+                    0xa874c045e0a57bb5,
+                    0xe29e03019dd6e155,
+                    0x011909c3b196df0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf093159bd3a81799,
+                    0xcc2bacfcce5998d2,
+                    0xaaf8db876e50a4a4,
+                    0xc0aa05b7e8f8c655,
+                    0x88c708eb883caf09,
+                    0x0349693e4cc5907b,
+                    0xd89256f76e0e0cd3,
+                    0x79e1228efdf52a52,
+                    0xe7c06451f6e2f9e5,
+                    0x1f7e993745b3bc3f,
+                    0x7a5d054940357105,
+                    0x00830e8cc4076131,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3716c5e9cabec8c2,
+                    0x926e9e6eaf833b95,
+                    0x413c39d635099d64,
+                    0x804d2f2be860d4e9,
+                    0xd85b722e9d694ffc,
+                    0x4b2c39889884428b,
+                    0x14d3531ceb62cbbe,
+                    0xf507b2d053cdc621,
+                    0x6da9603eef0798fa,
+                    0x10ee1f79909112e4,
+                    0x6a0ff816c81fbc02,
+                    0x0027ef6c6a68ade7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x61aa3e967de8ec7b,
+                    0xea459da96b43ad30,
+                    0x2fe396e182901799,
+                    0xb00893baa921d025,
+                    0x86236df709028285,
+                    0x7d1d49edbfa96d83,
+                    0x4ecd05d39fb4ef67,
+                    0xec92047add770845,
+                    0x81d7290bff8ed762,
+                    0xd5ece2ef9d562e9e,
+                    0x0a437a67609d3e72,
+                    0x0034d2f15ea8601e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0abed70cf5012587,
+                    0x84973c6b182d437f,
+                    0xe2278fda3b0d7930,
+                    0x312c82866117e56a,
+                    0x9c1f5f409ddc01ff,
+                    0x7c7d61dcb2224f08,
+                    0x1f18dd0c785e6ece,
+                    0x4572127e31a28ad8,
+                    0xf0a9e75fe5d3f89b,
+                    0xa8be95340b763696,
+                    0xed726dc4dfd6d18c,
+                    0x009bfe66f46c656b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7813f55ad445ced7,
+                    0x6da796ceed4e43ae,
+                    0xa79c75bb3f6126c3,
+                    0x3e5bd59b5e78aa95,
+                    0x83e2dbaeabad66cb,
+                    0xcdcd7b05b79a4ad5,
+                    0x231b4724701366b3,
+                    0xd2c88b681633809a,
+                    0xac9bb6c0999d4ae6,
+                    0x0c1b336c2d9e1588,
+                    0xa9d210430ff64ff8,
+                    0x003c6eb2a80f50a5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe6b7947323c7f7c8,
+                    0x88e192f6b7e60378,
+                    0xcd0cde5fcf168637,
+                    0xa2cd7fbb7d3c9e68,
+                    0xa12c1185e1fd8343,
+                    0x4658656d2fe9c4da,
+                    0xbb893f2e73469879,
+                    0x7155016ea3faa651,
+                    0xf9c09145e233984d,
+                    0xd29872d0a88dc7ef,
+                    0xd5177109acaa556c,
+                    0x00d343038c506726,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa5514acb94db1812,
+                    0xb2994bf523820f37,
+                    0xe7bbd9e42a10c921,
+                    0x2c1fcbe065aa0c72,
+                    0xab364dcae1125ba5,
+                    0xce4fb71c1c3714a4,
+                    0x5baf17adf05967fb,
+                    0x166d4f4773918c85,
+                    0x33958c494da8fb19,
+                    0x6c97902dc4a5e68b,
+                    0xb0183f10e2dcbafa,
+                    0x001993b2b962dc7e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf5d234d8aa57d31,
+                    0x04bce3bab05cc574,
+                    0x162a700e13265ab9,
+                    0xf533c944832dce94,
+                    0x709469b3f42fa7f5,
+                    0x9a28e26db18e5e58,
+                    0x08557de860108128,
+                    0x9420733dcc3252b2,
+                    0xe70b89f5b6ac8927,
+                    0x3ebe4bcd3382b4b5,
+                    0x0ab60f3a578dff25,
+                    0x002ea0c63396b873,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd1ba18c329bca74,
+                    0xa4877ee41ccc453c,
+                    0x17ca8853f2da5258,
+                    0x56a9025e4c65d12f,
+                    0xe2bc533844db914b,
+                    0xaab5b2670651502f,
+                    0x81b6b258d01fe96e,
+                    0x42d19ebd64c38d40,
+                    0xc18923f4e73d5d7b,
+                    0x0690d6bb904c9155,
+                    0x9a6d3c211ec2442e,
+                    0x00679866e64a2cd6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6aee09d4335aaf46,
+                    0x644452dcb1690aad,
+                    0x5f6b8c0dc310c47e,
+                    0xd1670c0c5466e422,
+                    0x19104b5cf15b1ce9,
+                    0xc212891be6348899,
+                    0xd751670bca160d20,
+                    0x2657ed4e4ec45c04,
+                    0x7e29fd898672830b,
+                    0xd301718f942baf55,
+                    0x681f82bb3405f90d,
+                    0x00a5cb8a40eab696,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd908dad1d736891d,
+                    0x1cf71367ae412d9c,
+                    0xa37ded8ccdc69b78,
+                    0xbacf4aa5ce9d5f19,
+                    0x8b2b693cbcdde366,
+                    0x380c1bc3b0afba77,
+                    0xbc6fc5c2c9019a6f,
+                    0xc00b6c2237e60b2a,
+                    0xff5007adc60ad3a0,
+                    0xcae59a966a455118,
+                    0x65c06390631c4050,
+                    0x0052c5363c623d4d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1da250ef67cf96f1,
+                    0xa89768707aab587e,
+                    0x9be38a43de36cddf,
+                    0x1fe1e74317f9cffc,
+                    0xfa67d7acfb3b16b8,
+                    0x02e383238d35f5c2,
+                    0x46ebe50cb6ce702b,
+                    0x7546f43488e17ed2,
+                    0xeb3d44b53bba9ec1,
+                    0xd40f6a5d06659690,
+                    0xc68dcd071672a4e4,
+                    0x00a9326062f6013d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ae884fd90f2bca6,
+                    0xdda65b15565f45e2,
+                    0xc2eadb77b66b7032,
+                    0xb35a911b1e9efbd2,
+                    0xdba431e288757753,
+                    0x9cb5413042ffb1ba,
+                    0x1910e0e7551eebd4,
+                    0xf54e32d7ec08ee63,
+                    0x8e58768cedf5fb56,
+                    0xbe3aa3b05a416768,
+                    0xbbe8c80d69d2ff76,
+                    0x0068da8521a55748,
+                ])),
+                Felt::new(BigInteger768([
+                    0x536aa172673c816c,
+                    0x6f01ea4d4524a5dc,
+                    0xd2807474edfb401e,
+                    0xc1504776b247b98e,
+                    0x95df10d9d2f401e7,
+                    0xad43249c24abe806,
+                    0x7aaa2c673359d8f7,
+                    0x5b7c1e3893006279,
+                    0xffaa57d012999c4a,
+                    0x8d7e5a1c8cf87a22,
+                    0x37d885446ab02441,
+                    0x00869dd1a480c310,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51550d5d05367642,
+                    0xecfa19b71d811552,
+                    0x494638e43cb05353,
+                    0xc1fb6f3492a09010,
+                    0x9ef52cbc21fb9cb5,
+                    0x07fccb60f803319a,
+                    0xfcd694c9e695edba,
+                    0xa83e277475fcbe2e,
+                    0xd0c3b6e1b9aa3092,
+                    0x03c0b3f61976b0d1,
+                    0xc01abff26536e186,
+                    0x004c4d821f9f87b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc34396889d638d3,
+                    0xe506b607bcac7949,
+                    0x4cb498d730d35a85,
+                    0x23066c90c9121f0b,
+                    0xb1192cbbc5a32a7c,
+                    0xae0b2cef4dfe13f1,
+                    0xe5e7a577e6d802c9,
+                    0x58a3442737ebba74,
+                    0xede608827d842149,
+                    0xb25dedd47838ee79,
+                    0xd1602287663f2475,
+                    0x00640f72367faf29,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xea7c1e4e53eea35a,
+                    0xe391df9b125b34a4,
+                    0xaea371595362882d,
+                    0xe4cb0133601c51ab,
+                    0xdbb70e7e83f14afa,
+                    0x82ab88c245b17c9c,
+                    0x9fab61b1f2f52797,
+                    0xdb5efbbd4df07b9d,
+                    0x90bc4fcd50efff56,
+                    0x6cdd2454cc424954,
+                    0x571e2f5e541949a1,
+                    0x011b77c9d56852d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9040f2559773eb44,
+                    0x39e3af76406296a7,
+                    0xc4da334bd4479840,
+                    0xbc11ea4d50a072cf,
+                    0xe67bd9c3475de77c,
+                    0x8724388b00c49f1d,
+                    0xd37bdab9d67d89b9,
+                    0x2401b832a7ef472d,
+                    0x51199ceb131049b5,
+                    0x876c596ca1899901,
+                    0x2cef4515a36dd4e8,
+                    0x003a3ac2fb72a7c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1b877c295bb4059,
+                    0x21a3745fd3f200b7,
+                    0x01f9bb811499d81a,
+                    0x248c9b3e32f90097,
+                    0xf3d8eb4e3d5afa85,
+                    0x3369b2235ac01b98,
+                    0x2d6bb1d94f7006fb,
+                    0xf40399333283e4bb,
+                    0x874f164130bb9945,
+                    0xd09c6d57b41306be,
+                    0xe77d84d40c8a83a3,
+                    0x0077eed0be8690b1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc443a955b48132c9,
+                    0x61f486d132375d5f,
+                    0x980037458ff7a7cd,
+                    0xd59659a236b356d5,
+                    0x6b7842d5afbc4fc8,
+                    0xc3380fd2aff1ff6e,
+                    0x3318df1c2a5489b0,
+                    0x7696e4059e82efd9,
+                    0xba59830e0e20ef53,
+                    0xc064a362fdd1b0a0,
+                    0x955354541f2b4f6d,
+                    0x011034ab46b70d3b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x576242e59e2d5efc,
+                    0x76c7b942e9e052ff,
+                    0xd15f6a607c413529,
+                    0x19f9eadd269df94f,
+                    0x6e8783f5260217bd,
+                    0x019887f4f3326d28,
+                    0x64519c5d44343890,
+                    0xc6ebc34c07592289,
+                    0xff99a15f197f2c74,
+                    0x375008ad8bbe76be,
+                    0xd5559f142f76e107,
+                    0x009aaad6fe595b2f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe0a3ca05015e9d28,
+                    0x86a29c395d9795c3,
+                    0x68ee91ef47adb8e3,
+                    0x4ca1dcad0a3c73c7,
+                    0xcaf31170a7480cb2,
+                    0xb6878c1c7ce955f7,
+                    0x612d92e7b77b696d,
+                    0xe25016be0b509072,
+                    0x0a056b1750a6deec,
+                    0x92bf67d9c20907be,
+                    0x10c5dd28349120af,
+                    0x011c57f6ee6ca78b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf7724ff09ae1a3ad,
+                    0x54ee053249372a3a,
+                    0x94539bf38231eb54,
+                    0x0de4c08b533c5f25,
+                    0x7f4f9f180dfe2f6f,
+                    0x16629808d7eb0cc4,
+                    0x09f4fd55f6870a91,
+                    0xab476fa421c1f373,
+                    0x8d09184c26523d20,
+                    0xbf5307c3a535ba45,
+                    0xfe99e17a2e2fe814,
+                    0x00973f7fa4d3929b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc56746a3817b151,
+                    0xd7467d3afbbde923,
+                    0x5c206058945f6dcf,
+                    0xf104d4ca6d604772,
+                    0x6471266783e95680,
+                    0x69b77c1d7f7c10d2,
+                    0xb2cae9d4c9974a49,
+                    0x33fdde3b653d2735,
+                    0xb3b03395ffbd066f,
+                    0xc07fddf87f96b684,
+                    0x8ea05ee85102a9e6,
+                    0x0005a63747adfeae,
+                ])),
+                Felt::new(BigInteger768([
+                    0x671c57327a5182d2,
+                    0x94f8dc4229069ef4,
+                    0xca73cb89707df2af,
+                    0xb29cf1abd8b08d94,
+                    0x093ebfd334b022c5,
+                    0x2b3f5d8b85a6c231,
+                    0xce1da8619c8934a9,
+                    0xf9e8fa971b45db35,
+                    0xe20768ae6dac4f4f,
+                    0x9a551bc02afcd029,
+                    0x8da1c8190ab423ae,
+                    0x00ce96be61320545,
+                ])),
+                Felt::new(BigInteger768([
+                    0x997ac011f1b32257,
+                    0x56c13abf7c435b82,
+                    0x92501f2d8de9e5a4,
+                    0x66d845658dcf351f,
+                    0x27aa762319cca959,
+                    0x4313fb82191900ba,
+                    0x6d01b57fa2bc8dbe,
+                    0x6ebaad34b8a7b1f7,
+                    0x3cb1459740f25be1,
+                    0xcd8f05c5d86668dc,
+                    0x8b0318f80c20fbef,
+                    0x0114557dbd678c00,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3515026d1200aefa,
+                    0xaadc1b9333f9bdf3,
+                    0x6702646620bc9b5d,
+                    0x06ed2058ba00dc2f,
+                    0x5262ceb3831f6bbc,
+                    0x99bf8cce65bcee7f,
+                    0x7a4e964379c6b615,
+                    0xfea20cf93ae3efc4,
+                    0xbb9b7b162610aabc,
+                    0x6cc96cd5cebd1aee,
+                    0x8209fd56cfc9239f,
+                    0x00f3db0d1e4ac055,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5867a350dddc1d0,
+                    0xfb12ccfae9a92cb4,
+                    0x43fc2bdeda11ab07,
+                    0xa0bea0c9ef4003a9,
+                    0xe8f75c18470df1f5,
+                    0x94e9ab0d7d0236b0,
+                    0xf4a3b450e5d3046c,
+                    0x3118677cbe1d71e1,
+                    0x8725d8ce2b264774,
+                    0x6c2c42512283d603,
+                    0x87aa1f8cdbac4289,
+                    0x0092484a8db86a78,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x81c1123672607ef7,
+                    0x161adf277191dc52,
+                    0x15e3eabcddaf9a4f,
+                    0xe0b010ff5913a018,
+                    0x14b538b9c11cb8fd,
+                    0x2166eaf8f077ca8e,
+                    0x898e7e5676eb2d2c,
+                    0xeefb29a3b88353c7,
+                    0x0b2d9742ceae23c7,
+                    0x5ecda6adc1c04317,
+                    0xdb07b4c240ff1fdf,
+                    0x010733dff4272fae,
+                ])),
+                Felt::new(BigInteger768([
+                    0x46393ca1e8018281,
+                    0xcfbe847ab1be1903,
+                    0xb2a6bec93ec20ade,
+                    0xee48840b4577b5ac,
+                    0x9dd60250470cfaa7,
+                    0x9bab3427ae61559f,
+                    0x7b99c07402266936,
+                    0x8b7388bbc816cc02,
+                    0xb1e46598f1a86f5e,
+                    0xbd363c6e544c0f8e,
+                    0xf89d3bb3475df3cd,
+                    0x0005a8d6273eff7b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7bed77f6aaf1e120,
+                    0x6ba268d20b552600,
+                    0x23df1ba7e012bff9,
+                    0x1c1f9c1866ef76a4,
+                    0x5f39030be1269d6e,
+                    0xef634140cea2d300,
+                    0x33abe62362ff9c0b,
+                    0x0673a7c64719cc7c,
+                    0xb1013d50a8efe558,
+                    0xb78ac3f3eb0be0a1,
+                    0x1fa02869a870108b,
+                    0x00abb9dd6fb73b75,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc90f80d3804c85a7,
+                    0xcfa8b8326e521ef1,
+                    0xb01cc8847dad802c,
+                    0x48726b033149c300,
+                    0xe176c777d1168e92,
+                    0x94a619c3a3fd40f3,
+                    0xb972b4e5210b9d4b,
+                    0xb7ebe65efc208d8b,
+                    0xf69cc7b81d9b60be,
+                    0xaaf858d6f75c92ea,
+                    0xd9617bf3aeb3a439,
+                    0x0008b7e91d6cb1ac,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4538b0d2457a9c7b,
+                    0x18875e28a609c338,
+                    0x72ccc502053b1ac6,
+                    0xa68066549e36facb,
+                    0xe9e77f4696b4f682,
+                    0xac7c930a1b3915cc,
+                    0xb3f1bd16901bd5ed,
+                    0xb192299e9504874f,
+                    0xfbaf253266e3e48c,
+                    0x116ec94e3414add9,
+                    0x2cfea5b819030341,
+                    0x0049a2dce1782ab8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6e09218a9bc48c08,
+                    0xed255478f3a206d7,
+                    0xfda5cf0be4f75f74,
+                    0xa5ed009e8a48efba,
+                    0x0f8de511c5f4706a,
+                    0xade6b821fffa4fbf,
+                    0xa8c1853b4c40db17,
+                    0x9ac96224fab082b2,
+                    0x7457aa83686e057f,
+                    0x3efc0dd7669cc4b8,
+                    0x1f3785d11fe99ac4,
+                    0x0082398203f00ed3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x831fb618032cb8e8,
+                    0x2e88394a218e9424,
+                    0x024cf32e9a682cdc,
+                    0xed4cc73160181bd6,
+                    0x5305062a2cb20d54,
+                    0x7d710c9d60f08c6e,
+                    0xc720a8b9be9f6d6c,
+                    0xdd7b865ec9075c58,
+                    0x9d00563538b7d843,
+                    0xb37dcb98ad2c12c6,
+                    0x2be1b160d51a5d2c,
+                    0x008b676f23370b74,
+                ])),
+                Felt::new(BigInteger768([
+                    0x57a39bc9f6291eff,
+                    0x586059b615a76b1d,
+                    0x8528563701d32061,
+                    0xd7a916bf8a6cf491,
+                    0x3a2320a49a78866d,
+                    0x9eb8bc46d6fd1445,
+                    0x80acf7603635802b,
+                    0x3bf1e2eafcd7a8dd,
+                    0x320e305618494c30,
+                    0x3db202d0958c1a60,
+                    0xd3489f0d4eb2e4eb,
+                    0x006f91e0f290556a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe44357f7fa9d238d,
+                    0xdd1053eb9f3e1072,
+                    0x77e494a2f0fe27e5,
+                    0x0d66290a0bfda487,
+                    0xd17717f0f0401942,
+                    0x11d7af3ad29f45dd,
+                    0xf70d477b810883d3,
+                    0x2326527075045a3c,
+                    0xea6d4d2d0b4a86d0,
+                    0xeb4923dfda2bee10,
+                    0xe50676c9a13413e7,
+                    0x00c6ea0ca2208476,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf151ec65df1d3504,
+                    0x35ff813e91330963,
+                    0x0cc62b931811c9bd,
+                    0x12d99697606b74a1,
+                    0xe082c74b82eef00f,
+                    0x6d1e1b19e34cff4f,
+                    0x9b3b384253a9942d,
+                    0x74db5d4b721c203a,
+                    0x861b24279c9b9914,
+                    0x662b3806e383133d,
+                    0x74c208ab5e45d6e8,
+                    0x00d90289850fed73,
+                ])),
+                Felt::new(BigInteger768([
+                    0x48d2373077a19f16,
+                    0x8c962f3f3b6898c6,
+                    0xedf4ac6ebab31b7d,
+                    0x2851e52298515e04,
+                    0x7fc015e3ae6585dd,
+                    0xde1060732d27c556,
+                    0x504cf84e31e81048,
+                    0x57386abb368b3ac3,
+                    0xfb9d3875687fb754,
+                    0x5f4ac7739e9a389e,
+                    0xe7a5089682a208aa,
+                    0x00e084be871480d5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d0df22f71184790,
+                    0xdb77630cd0562e81,
+                    0x817a5b67367fd14f,
+                    0xf515c59206cdc922,
+                    0x5c789569102d7476,
+                    0x07fee328959ed357,
+                    0x8b0ed51535f8088b,
+                    0x8827442b1beae255,
+                    0x61c2aa7bad02c305,
+                    0x0ff7aa332dfefb27,
+                    0x015c5c185eb0f806,
+                    0x00ed0d96a92f1a34,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x34d1a5aad8350677,
+                    0x6f60ed595278c19f,
+                    0x07a6097a2c9f5b91,
+                    0xd62ad30042c616fb,
+                    0x6defe37f64d7ba75,
+                    0x405e6c91714b5c88,
+                    0xbfb64469250ec3c2,
+                    0xc60de7c8e5ca22ff,
+                    0x1de2c2d93586c41c,
+                    0x5de2621959263f23,
+                    0xc941678f214014e8,
+                    0x008321721ccc8fe6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfaf1cdb4499bfdda,
+                    0xfcb5b84729906279,
+                    0x08e2b4d337e50d25,
+                    0x98fd936298305590,
+                    0xa7589aa0a5f9d019,
+                    0x9966e1c94bfdd6f7,
+                    0x66026b7fdbb86273,
+                    0x00d7743b8db9a319,
+                    0xe3f29165d3e513b8,
+                    0xf0573d1dc0008eae,
+                    0x4ca97ba9f8defa9f,
+                    0x009da0555c8b08ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0x16d5c06b4a6d39be,
+                    0x4a73294ff20d5dc9,
+                    0xd01339b98b8dbe0e,
+                    0x8cea783ec867c654,
+                    0x3303b0b1b101a34e,
+                    0xec2ee44ee82abc7f,
+                    0x2127147c7cb73c6a,
+                    0x06a073985e57d268,
+                    0x526371a03daae4b6,
+                    0x074722e81a3321c8,
+                    0x824d23ec705067b4,
+                    0x0031682a7fb5724c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6389fc4665effc37,
+                    0xce6b6a1a4b39f0fb,
+                    0x30951c8e34a902e8,
+                    0x7f4c70decee9b3ed,
+                    0x19efe4fcf42599a8,
+                    0xf2facdbbafa7d3e9,
+                    0xfee427c21f2659d6,
+                    0xaf494e9452f7efd0,
+                    0x04b249c25e48ddf0,
+                    0xab665828216d5a6c,
+                    0xe92d9ebac8e3489f,
+                    0x001dfe0acb245eaf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e9d14350dab6a69,
+                    0x4e29b6689bdd5ab6,
+                    0x3cb24cda1ffb70d8,
+                    0xd3ea82003c9ec97b,
+                    0xc4f4dae6a41be3d2,
+                    0xf393fc769443801c,
+                    0xf81070853a9235a7,
+                    0x523068f63d124962,
+                    0x27c2de579cb68918,
+                    0xc42bc775ed3a9f23,
+                    0x19c251f4bfba3365,
+                    0x002d0a4e1abc73e4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed2e97b4eba8bd31,
+                    0x80cf87e141358c53,
+                    0x384ac04baa6d202a,
+                    0xcb0977cb68a087c7,
+                    0xbb2136be01ceda91,
+                    0xe43f3789b1715997,
+                    0x074e1f5b84a6dafd,
+                    0xcfdb814929f323e1,
+                    0xdeb32accc0c27dda,
+                    0xce5d87e6ae6ff445,
+                    0x098c21a84d80a49f,
+                    0x00ff923a2cdafab8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x98d35873ef579ecb,
+                    0xd24653a0e44769a7,
+                    0xd635a137cb6d693b,
+                    0x695d0bfb64ef6d1a,
+                    0x8488d58673ba0af4,
+                    0x3af582a62b8b4cdd,
+                    0xb87dcf6f6f9bce53,
+                    0x1542f35625e0cdd6,
+                    0x4c162bd004b06cdb,
+                    0x1c617fcd32c60319,
+                    0x497c2675a238101a,
+                    0x0013beb53bc0af53,
+                ])),
+                Felt::new(BigInteger768([
+                    0x964795c2d6496ae5,
+                    0xfdaf8d66f76d5805,
+                    0xd6c4df78a3c31dd4,
+                    0x6b122b5b07ae004d,
+                    0x9a782696619cd92b,
+                    0x08c6c973aefd0ddc,
+                    0x5b63e841b8ebde93,
+                    0xe891ce076223b0f2,
+                    0xe10e6ae471903bd1,
+                    0x9f46d9289b1e5813,
+                    0x2a4d783e6d1c8581,
+                    0x0102ec859900c27d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdc2b6a4fadd3397a,
+                    0x33808bdfca862f9c,
+                    0xb2ae8f9a1afa803d,
+                    0xf74cac0104def68c,
+                    0xb09ed9351561ae8a,
+                    0x7d07c67ac88a6c2e,
+                    0x2729098bf250e8b5,
+                    0x9c163fe9c37cb106,
+                    0x1d017b62b2109164,
+                    0x18095bd47a1600b5,
+                    0x6f09a4f73dfc822a,
+                    0x00ea77e4855a5525,
+                ])),
+                Felt::new(BigInteger768([
+                    0x532ba20d43699470,
+                    0xd84f715f94a2aac6,
+                    0x2ea246b506ea88b3,
+                    0xc2f24bd177187fa9,
+                    0x6ae88a58d4acd220,
+                    0x29a67782a5548249,
+                    0x8aa2e30a93764c22,
+                    0x0dd2f2db4c8622c0,
+                    0x6eb5798b02513936,
+                    0x0af5580f3be38d8e,
+                    0x544b2627c12669be,
+                    0x0018517305e70af8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x735a0e2199a7d639,
+                    0x00f69d16fbcde9eb,
+                    0x51fc0f5f2b4f7174,
+                    0x56219be51cca431b,
+                    0x6f1d6201cd06a4d2,
+                    0x1693374d41673164,
+                    0x11d0d78fe58e4318,
+                    0xb37bbdfa3c98716b,
+                    0x12d5f382023a390f,
+                    0x959555eabb943571,
+                    0x8c68a914672d545a,
+                    0x00c5e56fb5173e25,
+                ])),
+                Felt::new(BigInteger768([
+                    0x17dc30ccb1a4c92d,
+                    0x7c7b5b7914ae3105,
+                    0x3ce9b70b1a1e5f8d,
+                    0x6fcd9fc43eb57967,
+                    0x29c2c5c839a80187,
+                    0x9f0720fd86bd5598,
+                    0x7083a9407dfe18a3,
+                    0xff4e9c219765ed17,
+                    0x2670b3293afefb03,
+                    0x8daa4c13f23da8b6,
+                    0xef68cf0f03964d53,
+                    0x00af07b4b103fefc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd3c0343468af77bf,
+                    0xf202bfe920ed431f,
+                    0xaeb68993dd0e4cd4,
+                    0x61bb8863db33c576,
+                    0x6d7d7219447dda1f,
+                    0x714ef978f2946a23,
+                    0xef116b92dbfc524e,
+                    0xf6e54c0aea35e6f0,
+                    0x753e9ac5b1c43d62,
+                    0x9c51d96396b48c65,
+                    0x6b94aff0a6b1d263,
+                    0x00dd3551f8b8d1ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb4020c23ea76a56,
+                    0x1f5b8b8299da1401,
+                    0x61d2e409ba5611b3,
+                    0x7da0de8370da6601,
+                    0x846a3293c407d3ae,
+                    0x1a224fb31d611a4e,
+                    0xdb70de5be0a2c537,
+                    0xa973af3fda372bb2,
+                    0x1cd853ce6e5d3476,
+                    0x2ec940ecdbf68ad0,
+                    0x41ba1da969b9de71,
+                    0x002fcadc7a152333,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc5a0052b47b21b7d,
+                    0x0c940b42ff77bd8f,
+                    0xc48d62f4ca46e483,
+                    0xc52d4be8a96bc28f,
+                    0x93b911b305b1f98a,
+                    0x8cb235191ac63d7b,
+                    0x11f2c40582f4992b,
+                    0x8947fc454c98072f,
+                    0xe00d971fced35017,
+                    0x6119847206bef610,
+                    0xffea2e869f13eeef,
+                    0x00231704bc57f9da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x093562d6e72f7753,
+                    0xfdf2698fe7859a52,
+                    0x4a5e5c282ea4a491,
+                    0x5a6833b671b4e62a,
+                    0xe4e7e3c4f02e3d72,
+                    0x7ba239578b42c951,
+                    0x94b19da60469c6b2,
+                    0x869588dd628b5293,
+                    0xa710f0fef2ebbe34,
+                    0x753bf83893c5d568,
+                    0xa06ecf2ce2870a62,
+                    0x007ffa9c3b1fc2e4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a192226d66798a2,
+                    0x7ba054fd52cca71f,
+                    0x79bec05498db33dc,
+                    0xc0d5448926c686b9,
+                    0xe89f28ddcf1f92dd,
+                    0x6a61b870d8f09c38,
+                    0xfc6d19fe617e0751,
+                    0xe7ec7b69cea1eff7,
+                    0x8c9812b0cb01fae9,
+                    0x26fedad803df016b,
+                    0x187ec7d183c23fa0,
+                    0x0115d64167a35dac,
+                ])),
+                Felt::new(BigInteger768([
+                    0x002d7bcc61684eb0,
+                    0x1d9d959e3b22ab35,
+                    0xb0c935ee8b4f9c95,
+                    0x2a51165a914d007f,
+                    0xde01c7a1d5fcee39,
+                    0xe1abfad44d917daa,
+                    0xb02e1173da0e8471,
+                    0xde3e9423d65bee7a,
+                    0xc01a94e40e91457b,
+                    0x30a08cec568f7a33,
+                    0xbda7f370b3a0d2d9,
+                    0x00f29cede48ccb2f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdd425f690c0291f8,
+                    0xfd09181e15b02996,
+                    0x4d428bae8897f03a,
+                    0x9932daba310cbb80,
+                    0x431c240e0642370d,
+                    0x3553d47209c3ba82,
+                    0xe1664395ede9c02f,
+                    0x9315e77934044b15,
+                    0x1be0232a2dedc575,
+                    0xa51194a53e5c786c,
+                    0xa0b53c31ce670214,
+                    0x00cb6bd286ed2219,
+                ])),
+                Felt::new(BigInteger768([
+                    0x27b1d414e261337e,
+                    0x890d107b43606319,
+                    0x416f58b283ab514f,
+                    0x2b0dd6564a1ba818,
+                    0x70d2d3ae642ded36,
+                    0x8801da9eb95e00e7,
+                    0x7e0342044417a68b,
+                    0x9608481d9b46c4fa,
+                    0xfe90ef2ef7e15b38,
+                    0x2cedbd3974f662f9,
+                    0xf99c4a55842bf788,
+                    0x0067df2e5a4b58ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72d7a22f0cce14c8,
+                    0xd76fcbf7ac4621b5,
+                    0x9e84fd3def6fb028,
+                    0x4d157fd8f7aba86a,
+                    0x6aa355bad37b03a4,
+                    0x00956356a1c3afd1,
+                    0x695ec76aabecd33c,
+                    0x3dc3e8d6c0da78f2,
+                    0x12b758dba10d5612,
+                    0xce55d64601d579af,
+                    0xf165acfd258deb5d,
+                    0x00e01fbe893d0e73,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc12e5fa1fab43d04,
+                    0x36b3bbd78691a94d,
+                    0x3d8eedd71d05ae80,
+                    0x1938ecb0ac2accdc,
+                    0xebd19f16e43f5152,
+                    0xe29ff5ea723ff1a2,
+                    0x88f019dd971d6a01,
+                    0x691ede346f2709f3,
+                    0x4cf1650c364cad60,
+                    0xf251c92f4253cbce,
+                    0xb872bdd73b59f033,
+                    0x00feefa094d51d01,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed3cfe0e052b394a,
+                    0x094e67bfa8cfb636,
+                    0x9ed1a41c3f6d4249,
+                    0x282cbdf264dae860,
+                    0xeea7d6db6e1e3ce5,
+                    0xc64befc6100bfdd5,
+                    0x2241d0465fcb5395,
+                    0xc093834f39b3d722,
+                    0x7db5013766d9a5aa,
+                    0xcd190525b365a498,
+                    0xec4a569077639272,
+                    0x00906916d7f24235,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe8e203d32b1be5de,
+                    0xc76884a3cb16ef29,
+                    0x26927323cfe0a274,
+                    0xef1ede57f0cb01bb,
+                    0x43df3addba51e471,
+                    0x78200359ef285e5b,
+                    0x66e5c6bd4c2d8cf8,
+                    0xeda6c1ccb2ab6b38,
+                    0x71f40080cefb2271,
+                    0x7e34ae054de38686,
+                    0x0807bb6148791bac,
+                    0x00ec29356b113d20,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9b7117a9aa0e2d39,
+                    0x2227fe620744654f,
+                    0xe1d09cc3219ca87b,
+                    0x10f2adf2d0b8101c,
+                    0x110ebff5f4194357,
+                    0x4015f24bc9f20430,
+                    0x8b9295236f436a9e,
+                    0xc0bd80e0d90473aa,
+                    0x1b4b4df78fee12ed,
+                    0xeed4b4e857f6fe3b,
+                    0x74fb90d50468c2d1,
+                    0x001ccdf3d691b199,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4b55a4983e35893,
+                    0x206e8c1212d56087,
+                    0x72baeb869f7d2b12,
+                    0xf565b9c9cffc639b,
+                    0x7c59b6f1457a83a4,
+                    0xaed54c4004bc6b4d,
+                    0x0a66c786f9bace76,
+                    0xa3f3bbdb864344e0,
+                    0x6163df0a874e3e7d,
+                    0xe77ea5fc9de2d183,
+                    0xb1477a4b6f815a0d,
+                    0x0022419a1a4b9bbe,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf992ced8c543d326,
+                    0x0b17c9f26729ac2e,
+                    0xb1c9af0c387daba6,
+                    0xc0dc1f32e3b1965d,
+                    0x8dd7ada297ca169b,
+                    0x638c453af312da6e,
+                    0x9c20678e1942eaf7,
+                    0x89c209f09128711a,
+                    0xb44f520bc4d5c589,
+                    0x20c41ddad4d0df87,
+                    0xcd13fa83042cd457,
+                    0x00beab09358a00f2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd37114ba27c1c472,
+                    0x170cfacb86c40d5b,
+                    0xa6898545c69aaead,
+                    0xea5400bee3e06279,
+                    0xb745af5e5b878f91,
+                    0x2a72bad10fbd2c25,
+                    0x013109795e459081,
+                    0xeae90b6f2ca8ef1e,
+                    0x8263d2081fec299f,
+                    0xd3d37204d7a3b84b,
+                    0x9976e0efc648b78b,
+                    0x00588e72d9de55c9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0e64992b55d3369b,
+                    0x8a4304c466624d5d,
+                    0xed571ef17ec47709,
+                    0x8de9b4f9cc0b79e2,
+                    0x43e896c1ab2d7f2a,
+                    0x96aa617cc9682ef3,
+                    0xcf7c73b0f46003ba,
+                    0x62224c317ef217e2,
+                    0x6db7c49bac8c533b,
+                    0x74efa37bdf9803e5,
+                    0xe945f4e2c59397cb,
+                    0x00b28d20a424316e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf024c33c93c81718,
+                    0x19e0a82944f71cf3,
+                    0xc9958322be084c48,
+                    0x73c3a30dc9fb5553,
+                    0xf21aad75d3a3b23f,
+                    0xf09af35112c4a4a2,
+                    0xf117253c3c97978f,
+                    0x011c063cc59234fb,
+                    0x2457556212c40eae,
+                    0xbeac34ffce60ce85,
+                    0xf80279b6d01137a2,
+                    0x0096a9429555c5b0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8058f2f90ec85e9a,
+                    0xb12289da660377be,
+                    0xc0d5788f4da3e191,
+                    0x10956d08554b4ee8,
+                    0x87f17140181350aa,
+                    0x8d8f82a9397c207e,
+                    0xc1abe8d307a5585e,
+                    0xe9e64e26f55c7348,
+                    0xbe4a45241921cc3a,
+                    0x9870a0b738d3b501,
+                    0xf9c8958116f6f8aa,
+                    0x0052c4392e3c8d9e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1451ae4af42692b7,
+                    0xa2a6f15520f087fb,
+                    0x0fa199b6b277d9b7,
+                    0x341d6c91b599b379,
+                    0xac6b571121137178,
+                    0x39f019583e05d9a8,
+                    0xdc0788821d0a021a,
+                    0x01aad8045d8c1e8f,
+                    0x8e525973d67bdc11,
+                    0xaf64138840959ccc,
+                    0x038fbaaca89f84cb,
+                    0x005057b49871b8d5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa95704068b3b0de1,
+                    0x5b3a06b327a33719,
+                    0xaab081a11e4a5f67,
+                    0x452bca93c77175fe,
+                    0x16f10bf2a068557b,
+                    0xd79e347476c07659,
+                    0x585cacc90d0383ba,
+                    0xb4e7c558da0c47ca,
+                    0x1e0ca4f87ac20e8e,
+                    0x895ddb7eceb858c4,
+                    0x1acd3557f331417e,
+                    0x00401026119724d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2d7f1b7f55baf8f3,
+                    0xbf0ad6b5e9a4511f,
+                    0xe6cfe4ddb51af6a8,
+                    0xea0de95c91bdd062,
+                    0xfc360af55856b803,
+                    0xe3bca61b192eb371,
+                    0x6f1c56a04d77c0c5,
+                    0x359ae8c35a8fac56,
+                    0x00fd38aeafdc9a27,
+                    0xbcfbbbe4e7df5e82,
+                    0x158e7637bd9eab68,
+                    0x00fe54bf58e6310f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b88599ac18adc5d,
+                    0x5e84a46101f2f089,
+                    0x835cfd49ab0ef998,
+                    0xdeb4e85af149fe9b,
+                    0x8c38fa85ab3f9fbe,
+                    0x905b7efe192361d5,
+                    0x808e0e07c57d7d62,
+                    0x9188de59350d098d,
+                    0x8dbba0f0179ff8c6,
+                    0xbc2097fb79a5ef37,
+                    0xf4ed14064819d395,
+                    0x001d7aec7fdd0073,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2b8ae4a424a90163,
+                    0xe3ba8ce260fba3f6,
+                    0x9fed94cf4399c736,
+                    0x45e10e4b3fad07ac,
+                    0xb508466505c2b0cc,
+                    0xcf59040961400dcb,
+                    0x7acb84087343011c,
+                    0x977032c2c0b45fb1,
+                    0x36a83708f0e23456,
+                    0x353da8f0780ecea9,
+                    0xb43f3dd497a455da,
+                    0x00e1f18861a8d05c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xccbb72690833b82d,
+                    0x5a9e2370ee0db4b0,
+                    0x71fd55235801af94,
+                    0xb43334a0930ff817,
+                    0x6431595ab5c9ae72,
+                    0x55dc172f22449aab,
+                    0xfdd18b1cb153347c,
+                    0xc8e825adc0f190e2,
+                    0x48a498f4f465387b,
+                    0xd8336309c884be8a,
+                    0xef43291df280ba09,
+                    0x0041be2fb52dcb35,
+                ])),
+                Felt::new(BigInteger768([
+                    0x524027305cbae383,
+                    0x7d190240a4e60939,
+                    0x9ae00b9cff4b695e,
+                    0x1773f545c39678f3,
+                    0x836ffe92ccbfc8d3,
+                    0x87cba20b80919e87,
+                    0x8dca6864c44f7508,
+                    0x5618bb31776f6088,
+                    0x8246ccabce69e01a,
+                    0x115ab5c99028e191,
+                    0xda53902e5097bfa3,
+                    0x010c2bac8a52d2cd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x20a49659ccd09d8b,
+                    0x56ae6fcb754c528f,
+                    0xb7b49416c74ecc6f,
+                    0x6112f98804598beb,
+                    0x3067ba95eb719b36,
+                    0x97463bcdef6556a2,
+                    0x6cc8d374f9f00614,
+                    0x57ef9cfb13c26541,
+                    0x64e89fa3b7c0e64b,
+                    0xc21c4eaa816a66d8,
+                    0xea959dfdd471d972,
+                    0x007d271166b3db1a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b1715d5322217c5,
+                    0xf067618227f08488,
+                    0xe455cb4ac35d7a0d,
+                    0x57d0bae54dc0d7aa,
+                    0x1e798020424ac938,
+                    0xb05961caba359298,
+                    0x1ab8fb61212b4618,
+                    0x939845cc4a848554,
+                    0xa67afab7fb85013e,
+                    0xd2eaededfb2d5b08,
+                    0x22df3f4b765c7295,
+                    0x006e84b8581fe9bd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9caeea53b919abc8,
+                    0x410c7780f6f41d3c,
+                    0x24337c7d3f3b32ec,
+                    0x62bcaa3d19a4ffcd,
+                    0xfcf260bd7083ad6e,
+                    0xcbcafd9b4e159b04,
+                    0x215dda4013e7a8ac,
+                    0x7a1d86c1d69b77e6,
+                    0x23c4e7a2d3ba7428,
+                    0xae129e0b1412a2f6,
+                    0x69d2e51514d98e92,
+                    0x007b40929dff7929,
+                ])),
+                Felt::new(BigInteger768([
+                    0x507dc0dcc2ca3a1c,
+                    0x142bad6136acf73b,
+                    0x2c714e34f3fcc165,
+                    0x5db49ec4f7eb407d,
+                    0x909b95aeb20d9ad9,
+                    0xad3938d9cb600723,
+                    0x382ec09f8cc1f70a,
+                    0xc414c031e916708a,
+                    0xc6dfde5b9e77d89a,
+                    0xa41fbe255c2d4d7f,
+                    0x20292595181ec0d2,
+                    0x010dc0d6336b41ce,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa81ceec74dba20dd,
+                    0x4a344db044beb1ea,
+                    0xcc6fb0eb722b6392,
+                    0xb80952c5fc2ba78f,
+                    0xdb9021476de0c4a5,
+                    0x27a50af0b5815695,
+                    0x3b8b34ddadc7c505,
+                    0x535eecb5a4ba8166,
+                    0x927ba776b0148691,
+                    0x1d74f822dd78ddad,
+                    0xbbff2e51957d67b4,
+                    0x000d5ecf9387cd4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d2796f116c1f919,
+                    0x682b8a7f8df115ed,
+                    0x257001eb0c067440,
+                    0xb24ba50672c5f6a7,
+                    0x9531d526bbf597b5,
+                    0x141f230edbcc9ae1,
+                    0x5059b0102ac446b1,
+                    0x704011bfbabda816,
+                    0xb70ccae041f46c01,
+                    0xe14927eb826d2e82,
+                    0xac467389628c110f,
+                    0x009645bac83fc25b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x29916e7d4e1ef087,
+                    0xaf2f330bf65e0acc,
+                    0xa4a755505c6ff462,
+                    0xf61684d233971b7a,
+                    0x9c4f020d44293db1,
+                    0x20ee44a0fd5e6bae,
+                    0xd61e14a771b8edf5,
+                    0x5e0fa56f96b81b30,
+                    0x7f5a70c10108b32d,
+                    0xba6229b397590be4,
+                    0x0c2f493e7880e319,
+                    0x001021dec33cabcc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x79e49bb3bd2a4580,
+                    0x30e447a3275002c4,
+                    0x39fc3fb367fb7471,
+                    0x514afd207594ed6f,
+                    0x660103fc87287b19,
+                    0x27dc1153cab35ab9,
+                    0x910de21144c664dd,
+                    0x94538d7248d2a859,
+                    0x1b849888b98463cf,
+                    0xe6712b6389da5194,
+                    0x7f8638b57eeda895,
+                    0x002fa3ae2297dad3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x69da5decb2a5ac09,
+                    0x1753c1be8ff74f42,
+                    0x93d2f8fb3ea69be3,
+                    0x9006c9665bb1cbb3,
+                    0x3f955126bdfe8bef,
+                    0x45dcef1bfe5634ad,
+                    0x21b63a1fb4a095e6,
+                    0xcfb196445110411f,
+                    0xc88873d3b83d7b3a,
+                    0x71249da8153748bb,
+                    0x1828e2d144f98d02,
+                    0x000d763d32418786,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82cb898fb4462eea,
+                    0xb8eb0782cdb1f663,
+                    0x4b9549bf387e9044,
+                    0x89c02e4e7c7ed867,
+                    0x3042c0385d6169f5,
+                    0x25fd0be360403d7e,
+                    0xd722914df643e87c,
+                    0x1b06d26ba0794ae5,
+                    0x6574da5da4f8d51e,
+                    0x5ea7cf739c720e2e,
+                    0x0b5c59e943f712a2,
+                    0x008d45992f2c0fe1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2acdd33f350fc229,
+                    0x2f32a369553f25ad,
+                    0xab8549dd9c0abc7f,
+                    0xcde08c9b22ce8490,
+                    0x51a1548f7281385f,
+                    0x9bd60471ccbda321,
+                    0xe663e137bd8a53e3,
+                    0x6b1364fe3df5b944,
+                    0xe81a79e22e33b6a5,
+                    0x7f8f80e5d2fbe670,
+                    0x1cb157e60937b68c,
+                    0x00cc79cf652b6d68,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf9562474b4bea9a9,
+                    0xa67f75a79985ddb2,
+                    0x8a06d0aefef20307,
+                    0x43c237fb13d6126a,
+                    0xdf009029b3677c5f,
+                    0x8ff15cea9ac526ea,
+                    0xa90b0f47eb2b8019,
+                    0x3b2fee26b9a60d55,
+                    0xef47a0758b807b88,
+                    0x451bd3bfdb05deec,
+                    0xed61ae7a79d15877,
+                    0x004a0a37c07da36d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x02834b17aae287fa,
+                    0xe9ece6e9ef333a40,
+                    0xa8e224ee3c02ee81,
+                    0x2bbc6ffcd112c1bd,
+                    0x9c110658a7d75e98,
+                    0x1a18b59f0d6daf53,
+                    0xc36fead014b1686b,
+                    0xd09884cf0f96bd21,
+                    0x0f0c7bd285cfe634,
+                    0xb535308e3cff694b,
+                    0xbd4f9ebc42c807a7,
+                    0x005349d8fc9b5572,
+                ])),
+                Felt::new(BigInteger768([
+                    0x028c806ecbe830d0,
+                    0x0ae7355722912763,
+                    0x930ca11ea6c952a3,
+                    0xa04155070340fe86,
+                    0x2a4f065d3627a641,
+                    0x1caa0089d9831027,
+                    0x4ef744aef3cf12fd,
+                    0x7993f11f059460d8,
+                    0xf2ca440bcbb18418,
+                    0xf6c322429f84217c,
+                    0xf072c615fe1e531f,
+                    0x0068269cbd47dd62,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82fd04e9af97986c,
+                    0x44e9b74b33807a77,
+                    0xc2754b750eeba78b,
+                    0x13b8ade00ea36779,
+                    0x1b12f02a0dd45c97,
+                    0x3ba8fb63af00f20a,
+                    0x31e5f893623c0c65,
+                    0xf4e8537bc3ad2ede,
+                    0xa6183ba2c1cc1a71,
+                    0x79eac13b0e8fa17e,
+                    0x855855b551714efb,
+                    0x007a9f3ba800273d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9236587bcb068e36,
+                    0x48405292e9f907c4,
+                    0x383b14523b777382,
+                    0x3d27e45d8883bf09,
+                    0x030ad52250a5e389,
+                    0x2e02150208cb2926,
+                    0x0b7009ded7c40dba,
+                    0x02cb3acc24cb44bc,
+                    0x5d7c9e4bc470a0cc,
+                    0x98291ea7c3f3471e,
+                    0x7193a748dea6ac66,
+                    0x00e586c93ec7a045,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ca24122c6c76161,
+                    0x8de8ae6234793cdb,
+                    0xc615b009f90880df,
+                    0xcd3ff99a0d77038c,
+                    0xe9b626bb9a0758fb,
+                    0xa8365f6636911b2a,
+                    0x4581105c6bb3912e,
+                    0xad38a67e6df4430c,
+                    0x26a9080e8763cb0f,
+                    0x84d588220b0d4798,
+                    0x7edbf4b24e720137,
+                    0x000402cb0d8340f0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x91acb9f5932cf05c,
+                    0xa95c03d599f41c59,
+                    0xc759db3365147a3c,
+                    0x96347da01eeaf719,
+                    0x78b5281ead9482b5,
+                    0x594b67eb72e0968b,
+                    0xcab2fee61adc612d,
+                    0x858458c58e8467d2,
+                    0x659222a6478c4d26,
+                    0xabd0a31d2a34ca7d,
+                    0x1d10d85c4fa962fd,
+                    0x001ef195f0c31a29,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde390e9641bfbdf1,
+                    0xa8b9ec2c45f9690a,
+                    0x0d0f1f56644e5ae0,
+                    0xc5c7eafd3e2c262f,
+                    0xb53d5266a9fefa63,
+                    0xe7136dfe5f8a5709,
+                    0x02c03e1bfd0cdda5,
+                    0xd8003ed04172c517,
+                    0xddda1e8403408c7d,
+                    0xfc36f240f7c1abe5,
+                    0xdb52d3c73e1b4381,
+                    0x008d8b7dca60b1ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb440120c0f7f6107,
+                    0x38112135afae1a8e,
+                    0x6b3976748526f405,
+                    0x6f74e74ff098f045,
+                    0x4ba014562ad5c20e,
+                    0xda2461699b1a5506,
+                    0xffe3f9df4e52c2e3,
+                    0x54f3ce528b914f6e,
+                    0x0fbc3baddcb6ffc7,
+                    0x58c8ef82bbb79389,
+                    0xa9317f04a7a12aa8,
+                    0x00f7b51a71605bf1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03a7b01910842e51,
+                    0x4422b385ec8001b2,
+                    0xbb469836526c05dc,
+                    0xf1c398e96cf4c2ea,
+                    0x70d2275fd3670193,
+                    0x57164fd7faf1e9f0,
+                    0x13556e7e32c269a8,
+                    0x3000c4efb2bd7180,
+                    0x6dbd94d9ed7cc097,
+                    0xb008d9bc6d1c28ca,
+                    0x480a05d38481f5e4,
+                    0x0036af1d192e103c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfbca285b7e74f22b,
+                    0x387df11aef27eb0f,
+                    0x55d79efbff8bc870,
+                    0xe99d1b71a8a57184,
+                    0x611149cad7535bf5,
+                    0xac4f35edc37da4a4,
+                    0x0e68333e638fa709,
+                    0x6aedd6453e57c878,
+                    0x784fe9fef12c5da5,
+                    0x3652ecadf49b8dd7,
+                    0x62535095d6702195,
+                    0x002efc0dee880d28,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd821ea00160446e5,
+                    0xfbc643dd4db9c506,
+                    0x6e6f8f7d705cc3cd,
+                    0xaad9435f25e3ad81,
+                    0x86e7e7b2bc4d26ba,
+                    0xd9ef5f113ad9cedc,
+                    0x9e8f049d0a86d4a2,
+                    0x17b330f8c132c48a,
+                    0xa14ef522a954ad51,
+                    0x733f3157adeeeef5,
+                    0xf961db22f262e1d0,
+                    0x00f4d2f8fa008247,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4216bc5a8cf97e2b,
+                    0x099072ab735b66d3,
+                    0x14d7023eab0cd98e,
+                    0xe0da216c8001361d,
+                    0x4faf050d6311dfd0,
+                    0xf987a5f141e02c3f,
+                    0x081a495d253424ce,
+                    0xe279573bd0a2dc82,
+                    0xbab003f86ae9790a,
+                    0x095dddcc77ac1b1e,
+                    0xe824616f6139cc0e,
+                    0x000c588e1a878a3d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7bf13331578afa11,
+                    0x3c82814f42dfdfa7,
+                    0x2c1a23dff78b316a,
+                    0x748646846205f3c9,
+                    0x4ac17e6a1e2a9db5,
+                    0x71a1c7d03798632c,
+                    0x3c1f3cefef769124,
+                    0x03d78c867aba0b76,
+                    0x60ea6aa064d97cbc,
+                    0x04fc0ce6a04f386c,
+                    0x8e9dc04cd9dba80d,
+                    0x0010d62f6c440077,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4112aefd5f271438,
+                    0x703d780ee3747b6c,
+                    0x8503d130b85ee0f8,
+                    0xb4f6c7725f7c4e06,
+                    0x18c15985b2d81075,
+                    0xf588ac2ce5382dbf,
+                    0xcb4d02f3215ef733,
+                    0xd8aa1c5c0c36ff3b,
+                    0x38fc5b567b0872b2,
+                    0x9d5c12de959663f1,
+                    0x0020e575c1fe800b,
+                    0x01173164e001b390,
+                ])),
+                Felt::new(BigInteger768([
+                    0x660d2fab928025ed,
+                    0xc1cf1c502da8eb58,
+                    0xd00674d12dbbab65,
+                    0x783e4c4730a6b71d,
+                    0x6f599b8692a17055,
+                    0x695bc63e9f472fe6,
+                    0xc81c367e50332d2e,
+                    0x554af9b36a8582f7,
+                    0x24e537b80b63f773,
+                    0xd51ebf430e343283,
+                    0xacc2a66f266c9094,
+                    0x01116c040200a8aa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18476dd976c8d120,
+                    0xbf25005f9a04c595,
+                    0xe45ade059433a95d,
+                    0xa17998168b829188,
+                    0x7266258402c16d86,
+                    0x97ac2556ce0001a7,
+                    0x9f6e2eff58f33eb6,
+                    0xb55039d2ef9e53a2,
+                    0x33a5a326cf27dc34,
+                    0xe4dcd074399c5a95,
+                    0x2167d1248e0ebf66,
+                    0x003acab833756870,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe8f8d0c044bd25c5,
+                    0x60bbab87bd7d2654,
+                    0xda314173c204f65c,
+                    0x1419a0e3c36e5c83,
+                    0x2d0eb4cd5bfa7f6b,
+                    0x8a80ec173d60763d,
+                    0xb5170602a9a39800,
+                    0xcc43bd8b04a927ee,
+                    0x13f532a01acd51cd,
+                    0x5ee1de9210e501c5,
+                    0x7c594a4ba8252c1a,
+                    0x006af97765bd0c55,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc1aaa6880f0e4dcc,
+                    0x6061e2f6c552f5e1,
+                    0x2564f7e2280f9fcb,
+                    0xca758345b84228b9,
+                    0x91042736a10c14a2,
+                    0xa4615180901d3305,
+                    0x617ee71c45188eb9,
+                    0x5eeced50851f6eba,
+                    0x68c805cb850e1207,
+                    0xc0c172be62613441,
+                    0x0c449d4e9926cf86,
+                    0x00ed2e97402cd7e4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05b071892b8d0b1f,
+                    0xa0e50ceb42e123f9,
+                    0x09238e1cc50b406a,
+                    0x0c251f802135060d,
+                    0x3a0ebbc17ca3b7b8,
+                    0xbfdf9b2d6fa96b00,
+                    0xa7ca38a74741f6fc,
+                    0x6ce701caba5520b4,
+                    0x94787b98fe08b126,
+                    0x8b6fc9b467c547f3,
+                    0x95f9069fbc294183,
+                    0x007a3c1936feeb6d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb0cb903069b4c20,
+                    0x90a9b2c6c4e00430,
+                    0xa292b7d836de6f5e,
+                    0x113b95ec02607b04,
+                    0xa99cbf1d78ba1c8b,
+                    0x9c1024001459ee2d,
+                    0xfad55ebabdada700,
+                    0x78b9ad3a3eca6b66,
+                    0x8002ecbafff9e40a,
+                    0x5ff349f6bbb378be,
+                    0x644d48c440121d9e,
+                    0x009b308b74ab0eab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x575bfdb6f510ffa8,
+                    0xd6cbddce843e0843,
+                    0x271ff525ef5a52df,
+                    0x99b8f248383d3c6c,
+                    0xccae77701f4ff3f4,
+                    0xaf98c77ed7afc046,
+                    0x57e0b8073bc72aca,
+                    0xe3a96400d8ae6f3d,
+                    0x3a86ceee4f4917df,
+                    0x020ec5c17a756daa,
+                    0xc77157638dd2188c,
+                    0x008f13909aa2278e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf404bd9e5418930e,
+                    0x4bbbe75767c2b091,
+                    0xd6bb652839527484,
+                    0x4b565835fb5ee503,
+                    0x0d5af7c7ba1712a3,
+                    0x173c09af25844a20,
+                    0x639ae50ebc3654b2,
+                    0x10815b8bbbf34726,
+                    0x9be04a752e2706e1,
+                    0xc091d6bf1ad26734,
+                    0xdfa09dfbeb194825,
+                    0x008a172322ce472e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7a1ab8cc1cecf230,
+                    0x54e23009894a44bf,
+                    0xc15d758a0dac5c2e,
+                    0x617d2d91c7e77b92,
+                    0xf329da5420499a84,
+                    0xd95a54a6577adc10,
+                    0xf36af10f7c2d1bf8,
+                    0x552a1340eccfbfae,
+                    0xff1322b3a01bffcf,
+                    0xb1292eaac5b7380b,
+                    0xcb94c5028ceb1a4b,
+                    0x00fccdd851f1158f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd81c6c5660b86297,
+                    0xac83f5eea9479f75,
+                    0xd883cb98757a54a7,
+                    0x3f380e2cb8548eb5,
+                    0xc8cb1fb28da78492,
+                    0x606164b9fd3817e1,
+                    0xffb739d165be86d3,
+                    0x75bc48d4adb7b535,
+                    0xe495241401d615ff,
+                    0x0a7897898e4702f9,
+                    0x87fe0056085c28dc,
+                    0x010f20af67ced6a4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40ad535bfa7038e4,
+                    0xf33c1b0b440ed28e,
+                    0x7e32dab5eb35478a,
+                    0x7b1f2855c5d18b70,
+                    0xab1e28dd0180d8c6,
+                    0xc82fa989b47fe29c,
+                    0x652a9b96d6fd3910,
+                    0x8a41ba48211a9f8b,
+                    0x52a5b0da11442000,
+                    0xdfe24d1a0bc3f61a,
+                    0x15a9aed634c3a7ad,
+                    0x0002ef192ad236a6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x734ce3b1479405e1,
+                    0x1582387a266d340b,
+                    0x08351822c48dee94,
+                    0x3ddc18dab2c3e707,
+                    0x58956cd8fb50a8bf,
+                    0xee00136f5270873b,
+                    0xc04ea5221805da42,
+                    0x24d464a60b6db889,
+                    0x47239d2dbee18cbc,
+                    0x72c6585712e7d8e9,
+                    0x0474e1818d1859e1,
+                    0x005855f745a2d6a4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc757460fd8691ba8,
+                    0x30f55d9fc5f748b6,
+                    0x938e585b38645d17,
+                    0x1e6d3810d0e0d3f6,
+                    0x3a6375b8b18fb258,
+                    0x894304d81b407577,
+                    0x7e2cd99301a78587,
+                    0x2580e739370fe76a,
+                    0x2ee826ee77ec90c6,
+                    0xfe976341a9409405,
+                    0xb0cd13acb65ec299,
+                    0x00c66951cd6b9dd7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2813ce1c01968d2,
+                    0x4e662e09845d3be0,
+                    0x8f93c92a32ff9499,
+                    0x997a943eb3068b6d,
+                    0xda1e1b758b39f751,
+                    0x12e7b196764028ef,
+                    0xbf7085f489854026,
+                    0x523d24adae5cb43c,
+                    0xb231440560fb359c,
+                    0x9eb3a0eae77379f4,
+                    0xb38aeb2880a07642,
+                    0x00c92fb6214ddaaa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb878b640c004e5f9,
+                    0x1ce9c2ebd99eed17,
+                    0x75e1dbe5bd9af3f8,
+                    0x3f4dd5c32a92265e,
+                    0xbdc41d8b31dcb2d9,
+                    0x76e0b36e8779b613,
+                    0x7e3da2edeede3668,
+                    0x2c193232b6395eea,
+                    0x5ebacb8ed6efb193,
+                    0xd6818368836f0597,
+                    0x14ab84bcb2bb4e35,
+                    0x001ad54eb7069f53,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf53ee11e120ee423,
+                    0x6199259ad3d8913b,
+                    0x55b3a1892da4e9f1,
+                    0xe6d2cce26ca047a3,
+                    0x45909ffdb8e79f08,
+                    0xb307c1437910dc35,
+                    0x1daaed45ad453320,
+                    0x04945c552e413a48,
+                    0x77a3270e344a8e1a,
+                    0xbacce5a908622f3a,
+                    0x1691f5558440e3f6,
+                    0x0024b0064094c7e4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51bd229e99ca8f65,
+                    0x75b610c0b7570acd,
+                    0xcfc66f02b10e538b,
+                    0x7939d89902c54df6,
+                    0x3be7b31b660ac147,
+                    0x6080cae0990af1bc,
+                    0x1db1f25f093ee8df,
+                    0x99f7a3882370e284,
+                    0xae5082232da5fe6e,
+                    0xd19ed8994ef21fe0,
+                    0xc2b166bb0e3c176c,
+                    0x00ebc926a9141e4a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa41ed34e2a0336e7,
+                    0x640c90c1ac3f5d60,
+                    0x57cad6f97ce629ce,
+                    0xa9a0c0b59d45f94b,
+                    0xf128e66761f1be11,
+                    0xb9a53b6b318dcaea,
+                    0x70ebebcd33915f78,
+                    0xbd27271116ff049b,
+                    0xe0378a71a6bedc18,
+                    0x67d833cb0be63656,
+                    0x25afef9f3856408e,
+                    0x0097ffb5c7b9be10,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0a082bca8c1b5cac,
+                    0x53d4925524bf88bc,
+                    0x49acb2372b3ba187,
+                    0x27df73e7b856fb41,
+                    0x6897618de9fcc55f,
+                    0xae68531bf712dd21,
+                    0x40c92a1e7d24f8b3,
+                    0xd77b403ebda2b8ff,
+                    0xe1e735b20dc269fc,
+                    0x3a07916f633bc673,
+                    0x0dd058a7071f0454,
+                    0x00bbe55804e87d68,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2efca869532e2a1c,
+                    0x423defb4e5560db1,
+                    0x070b0d05539de8ec,
+                    0xf1745e9fa2aa3d49,
+                    0xd78993a7fdad732d,
+                    0x3bebc6490c31dbdc,
+                    0x164d9195137698e2,
+                    0xfd207c6491b06b6c,
+                    0x3f5a22e067a02dd1,
+                    0x3158dbea7c73fa82,
+                    0x2728f74b5d6f65b4,
+                    0x007afcb7c36f24b7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4030361af96a2364,
+                    0xdd465f86ca52ffbe,
+                    0xe7c6065e6170f64d,
+                    0xdcaa3644ef5ca742,
+                    0xdc31ef0dc1d05be6,
+                    0x7586babab9813345,
+                    0x278eef83fad0bfb9,
+                    0x5e2d55f7f2170f8d,
+                    0xe8c255a73bc0df90,
+                    0xb9a86060fd89d036,
+                    0x832fb4ccb54f29e8,
+                    0x00f85b9ef71037cf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6193a45d5fd18417,
+                    0x436b687e017a4d17,
+                    0xa7ec5b3ce148a76e,
+                    0xd2ad6aad8a45d5b7,
+                    0x818da0c0d3bb2ac9,
+                    0x9a8043ece22133ae,
+                    0x7dc4f04340717b71,
+                    0x73c6e0a865dd9196,
+                    0x6ac7004b4ae1d2d9,
+                    0xe7fd7b9e86a5ae43,
+                    0x51ae09cb38a6fba9,
+                    0x00e7e0b6dae5f1bf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5558c4995d846b85,
+                    0x10a1fc4ed3be631c,
+                    0xd1325cefa7a383f3,
+                    0xea469598b82ff26b,
+                    0xf8e1846ccc4e28c4,
+                    0xa51918d9b906177c,
+                    0xb3328de132c06804,
+                    0x7a9f1d3e02b0febc,
+                    0x33807164de93bd37,
+                    0xa693628e9b52f593,
+                    0x1204d8cb1bd79374,
+                    0x01191bed2b9a8f9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x081b0d50a24cc622,
+                    0x227295d46da70249,
+                    0xa0d3f470fe08ff2b,
+                    0xe1f1e103447a6b87,
+                    0x5ac0dfe585778d55,
+                    0x0916cc3354c3be75,
+                    0x7d26e7bb754f32a6,
+                    0x64e65751fe212392,
+                    0xa4b5e60f9c7bfe16,
+                    0x69dd8c3daf838e8e,
+                    0xb08ffabd238a7126,
+                    0x00e70c3e7a5284fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf9a38dc936637444,
+                    0xa078c768b0133b2a,
+                    0x22e5eb197f7496e3,
+                    0x8bd8a1d0302872f7,
+                    0x2e65406467c9f073,
+                    0x3904d682f1eb21d6,
+                    0xcca0c0dab30f2e73,
+                    0xff2144d82edc6c68,
+                    0x5d61a992b8e50e58,
+                    0x158f6077a727edf6,
+                    0x87d909dac5deba80,
+                    0x007da7eddf254892,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdda208f4b67d8afb,
+                    0x5566d1178cb08a55,
+                    0x57232f8ed5da1070,
+                    0x638b4941e62bb85c,
+                    0x68b5a473d888eedc,
+                    0x6984df1e7617f306,
+                    0x8cf2f2f15798095f,
+                    0x7125cd6d7e1d9d24,
+                    0xc216f439196e59b5,
+                    0x7dbbc505dcfcce81,
+                    0xe07790dfa4cacb11,
+                    0x004ed17ae5b57b78,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4203521a5751c0fb,
+                    0x39fbba5a3a88698b,
+                    0xe609f4f8097136ea,
+                    0x2255fea5393e2e11,
+                    0xd90c3b047af0b2e5,
+                    0xaf88243151a81725,
+                    0x6c99075ab581a3d6,
+                    0xaa67c4e1bb1c8d7a,
+                    0x92c79244d1561e0e,
+                    0xde13c35d90263558,
+                    0x847f5ef79a6f9770,
+                    0x00b5eff6f25a259d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ffe8e20e880f798,
+                    0x61886664ec15c424,
+                    0x69ed27dd02850294,
+                    0xa66e838cd44f3a00,
+                    0xc9b97c6ab5daf49e,
+                    0xbb67cde03c31485f,
+                    0x7595f253a5cf6731,
+                    0xe26981ca92f31af6,
+                    0x714a586c58054752,
+                    0xacefafb2b85f98a2,
+                    0xd38ad19d40909c77,
+                    0x00d77fb64ded744c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xede4dd06f021f79d,
+                    0x6fd700135656c5f0,
+                    0xfec918bf474caa4b,
+                    0x3d07f513de28dd1d,
+                    0x69f56d40c882f13a,
+                    0x8772a2d005900610,
+                    0x78e46a36e0143bac,
+                    0xc28eca61000c6c17,
+                    0x6b04ae67cd89a1d8,
+                    0xd25defb5a1a8a4c2,
+                    0xd5768d9eb8aec7dd,
+                    0x004a46d06de7799e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf07fb5e9b8f093b5,
+                    0x124b050b62e627e8,
+                    0xa99e7c311da64c1e,
+                    0x9ce22cfd0178b912,
+                    0x5964d4fa00c72ac0,
+                    0x231c265e1586f1e2,
+                    0xb65920c1b4cada8f,
+                    0xb8daf004707a7326,
+                    0x8fed59298f4c9663,
+                    0x4c0eb8dae0223446,
+                    0xc5096c7b02e0df25,
+                    0x00ee205b60dac968,
+                ])),
+                Felt::new(BigInteger768([
+                    0x93dba1e1de41bc44,
+                    0x2dcd32d6470af9c4,
+                    0xb22784d8ff9aed3f,
+                    0xee4d57020337c539,
+                    0x33df169795ad1623,
+                    0x220f8fb62b3f7adc,
+                    0xf10872fe0bf143e5,
+                    0xd5fb2bda2561e983,
+                    0xf0921c46db0cd76e,
+                    0xe1a875b3634b0ec0,
+                    0xcd66d680d8ed69cd,
+                    0x0035082f5fa22b07,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0ef22b8f1fc968a1,
+                    0x5374d2b9b971f3d6,
+                    0x1b0810ca44b885de,
+                    0x5b8cf3bd848b2e4e,
+                    0x1dda60adb77e7694,
+                    0x5aca9d18d981149a,
+                    0xbac0031abdcec349,
+                    0x1e9163d6f1a3180e,
+                    0x653208a1a047b5e9,
+                    0x14d29f60af8c7e6c,
+                    0x4a9e2c2ced8b927f,
+                    0x00f6de006f0d8549,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa17549796bf21eaf,
+                    0x4c913d732d36a825,
+                    0x32c2f5297a7cdec6,
+                    0x8263f465c08a7def,
+                    0x4ac8f8ec9fe016ad,
+                    0x696fce2f052bdb58,
+                    0xa5910e0198e2c881,
+                    0x67efab1007c09918,
+                    0x10c5106b5a954f82,
+                    0xb256032b98e11f13,
+                    0x793d6a06afb589fb,
+                    0x0006838eadf42d4c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeef3e09fd039cf71,
+                    0x40f7a0484381204f,
+                    0x027f6e14bfd733ca,
+                    0xa3e7af017c2ca515,
+                    0x2435429c617be9a3,
+                    0x27c3ca995f710b48,
+                    0xbf9ee968c7086c99,
+                    0x9692489144fe08f5,
+                    0x5915f0bac1d59032,
+                    0x12f0934afb006f59,
+                    0x2334deb2a7b2e5be,
+                    0x01152dc2d88f66b6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbbf4a761de8550c1,
+                    0x196ba8c43e43127c,
+                    0x09d61d8adc2e92fd,
+                    0x4eeb05e0de05b5c2,
+                    0x06ee6c1a0c188b06,
+                    0x7282a27b44a67a94,
+                    0x4a61f4c9dc45d662,
+                    0x1a26d8f3d94b1dfd,
+                    0x3a9861cb3869c598,
+                    0xfb3cdfcc5c0a38ef,
+                    0x8c9a8625eda47d1c,
+                    0x0057a2f90958e977,
+                ])),
+                Felt::new(BigInteger768([
+                    0x99babb9865c52c05,
+                    0x49e9d5b7102505a2,
+                    0xcb05dc606d8ff3bc,
+                    0xe87c052cd220d940,
+                    0xf1e5393f9a946d0e,
+                    0x2eb6e0e7f8bb08eb,
+                    0xac563b0fc369b81f,
+                    0x4053e94a2075d096,
+                    0x0f908d57067f2823,
+                    0x929bb63cf5b40761,
+                    0xd94ebe8591d581b3,
+                    0x01213566f76b61b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3bd5d0d10cfb4eb1,
+                    0xc58f8394054533b1,
+                    0x2c330d86cb2f850c,
+                    0xe600e33701f91f9c,
+                    0x86ff23fe3a22b193,
+                    0xa4df385da0b822cf,
+                    0x57cc0b1e9f5b07b2,
+                    0x0757ac246d139526,
+                    0x391eef249ebb66b1,
+                    0x3dbbf8881a814403,
+                    0xcdea25916bfa1535,
+                    0x00dd855ef94da8c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea143b0ec30aeb39,
+                    0xd726bde81812e64c,
+                    0xdd793610a3ac8e6c,
+                    0x6aa482539183a361,
+                    0x797ffb1d812a806e,
+                    0x2da7b3b3598b193f,
+                    0x7d4d6212fad38045,
+                    0x728fa93ac8ba3889,
+                    0x50cb61825ce2ca28,
+                    0xbd04d278b086d146,
+                    0xf4e327822099f779,
+                    0x00cc6f68095c7a58,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac982222e62e028b,
+                    0x39c4f8c5239c9a44,
+                    0xb39d039a3a295197,
+                    0x0ed87c76a2260843,
+                    0x3652b50a22fe070f,
+                    0xb2b243d6a6844842,
+                    0xf8b2c2bc95653576,
+                    0x5c1e96d6cabfa7f4,
+                    0x7234476c3296e6cd,
+                    0xc6c501edfdf1af74,
+                    0x350291e882aab53b,
+                    0x00aae1742876fe3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdca7355d31689053,
+                    0xf914921d84076a9f,
+                    0x24cf3c7904619aca,
+                    0xb216ea3251e851ca,
+                    0xe37cf4f7bd8b5a7f,
+                    0x1321431143245c69,
+                    0xf5f18357f2b3959c,
+                    0xd4c7697f23732df4,
+                    0xc8eab47b179fa087,
+                    0xcc5790f4404f674e,
+                    0x7a5a2048646cbda5,
+                    0x00e3fbf1886f6e25,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1cafef3a38a99003,
+                    0xf4a975144eebf84b,
+                    0xea8e174aa0992d00,
+                    0x4c5cc25ece64d7cd,
+                    0x7b73ebeb70ec6bda,
+                    0xf5c7368df5e31ebf,
+                    0xc639b3eb6d6ba696,
+                    0x45ead0fb213460b3,
+                    0xb368c5673440a9a6,
+                    0x493c88c2bca1780c,
+                    0xd0a4e059a5f086d6,
+                    0x002de5ecf7538632,
+                ])),
+                Felt::new(BigInteger768([
+                    0xca37e3dad8601544,
+                    0x101ab0474763c675,
+                    0xa4840ee1278ce01b,
+                    0xdbdd620e2fa80e19,
+                    0xebb9ea328779dfa1,
+                    0xba451eaa7d80f6b7,
+                    0x910a325383324a5f,
+                    0x93e87e1f34ff443f,
+                    0x3e1d51ce6757205b,
+                    0xb64fb252aba470f0,
+                    0x1a57f6fd7fd09516,
+                    0x004ad8aff702f297,
+                ])),
+                Felt::new(BigInteger768([
+                    0x074deb02c97a71d6,
+                    0xb86995a6947c111c,
+                    0x38c637f722be750e,
+                    0x7d723d8ab5eb13cb,
+                    0xf0af0b6bb5c2389b,
+                    0x72dad419568d71fe,
+                    0x867b42b7a98a7e0f,
+                    0xae8d39fabe5c70b2,
+                    0x30b8262ca21a45fc,
+                    0x930e1e55b8cbc317,
+                    0x33493d5a06067e25,
+                    0x00b06a01c7390c9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb38e7109ee24f25d,
+                    0x6d0f2b702b260366,
+                    0x29b3e8560cf5ab06,
+                    0x510811a49ae59f0b,
+                    0xeb112f24b23d1538,
+                    0x11b68f0db5072cb8,
+                    0x2c57b0fa5cbed92e,
+                    0xf86ec8493c3b89a0,
+                    0xc970e2ec40692e6d,
+                    0xa7282c635f632ca8,
+                    0x99abd9256a6630b5,
+                    0x00834783bae605b9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x920c7ea94fc09990,
+                    0x6eeb0872196135e0,
+                    0x45e6e9bfc55c3fca,
+                    0x50a70f11b5cdbced,
+                    0x7d57126d85fa1bdc,
+                    0x55ef5bfdd32f20e9,
+                    0xe5f06ee2df946789,
+                    0xeb30924d89405d40,
+                    0xc3529f81261926ea,
+                    0x8aae42b797f968b9,
+                    0x215f5b3b015104b1,
+                    0x00f0bc4675eb5181,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9df486ae0ad0dcdd,
+                    0xb94d39d7f004bb89,
+                    0x6bcaac92a1aace9c,
+                    0x9bcd9b628aa8e3ba,
+                    0x3bb1f5adfb2a39a6,
+                    0x23b4e9f0387213be,
+                    0x7e1a9e955b9484df,
+                    0x1ea7f90eaf9b569e,
+                    0xa6e0dec10dc9f103,
+                    0x5ba6e6bad0d9bb65,
+                    0xf7ed4538f0898b36,
+                    0x00eeece1374c2a4a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x804b6863ad0f0af1,
+                    0xbdc0d2ba418c126b,
+                    0xcb0046115617135a,
+                    0x5da813e586c0cfbd,
+                    0x42411d95e1b95209,
+                    0x417bbdae3a934485,
+                    0x9c21832d43feffc4,
+                    0x9a50fe286f61d0e9,
+                    0x80c3d3fde257aa81,
+                    0x4760a5d7112d53c3,
+                    0xe1b02d441207de14,
+                    0x011e0ff92ca0dd64,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdf9d7dda3113c509,
+                    0x1f072e844c8fc9e1,
+                    0x55aff376e6ced7fc,
+                    0xf73184c3911c8298,
+                    0xf6c27ee0a89ad2e3,
+                    0x0a6b81bba173aa33,
+                    0xa20a014de1c65077,
+                    0x991b1f34312467a6,
+                    0x404f407d01ffc1f0,
+                    0xeedb1a59ea150aeb,
+                    0xe25f9d742411461e,
+                    0x00af4ddcded0dda9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd9e78b8a82a358c1,
+                    0xc93ea3b61da8e958,
+                    0x495de999035f1a4a,
+                    0xc9f5b03f79dde821,
+                    0xe9d3b5ce584ac2dc,
+                    0x468e1826b550cce3,
+                    0x0de41902a8dfef6e,
+                    0xafdc6e1792c3be32,
+                    0xe4bc430c9378d503,
+                    0x5721083f633207c7,
+                    0xab4807b4a7447699,
+                    0x00c47a59f3f385ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ee93a0e38063480,
+                    0x642ae6f13cc66c2e,
+                    0xfbda8e50ad3679ea,
+                    0x15701c24d6acd5c4,
+                    0xccc5e7fc98967c68,
+                    0x09722a2947df2fbb,
+                    0x8f77d83ad506e7e5,
+                    0xf20c13715670adae,
+                    0xcaae75f6412c7374,
+                    0xa6c281b3c99cd342,
+                    0x43c81a0555d67cd2,
+                    0x002e8500acbb1b33,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d68b54b27740cf4,
+                    0x13266af0b248bc35,
+                    0x0870c0faa0635284,
+                    0xdadc23654b57dc16,
+                    0x8baaf3da70a648d4,
+                    0xfc54aeb3bb798f4d,
+                    0xc497c3481f3f0eea,
+                    0xf850a5e403db85b1,
+                    0x2a3d93d177a40a7d,
+                    0x96e5b9789cf6ccd0,
+                    0xc939df701299f99e,
+                    0x00a0ce162d1d091d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc08cbbdfdf5d3db3,
+                    0x5c9eafa661746c32,
+                    0xa74d910eef5e1588,
+                    0x14048878a14c4e08,
+                    0xa8db84aa2c7bbc41,
+                    0xe48ede6d5f766a10,
+                    0x128a51fdf6db6504,
+                    0x0561ea748f0a0c14,
+                    0x5ec2f53905448ac9,
+                    0x3c43cfbbd289c237,
+                    0x9ca49cb489f17c4c,
+                    0x0099c23908ef1917,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc96ea76393f36de,
+                    0xafc9a84b6ea4365e,
+                    0xe138a168825d846c,
+                    0xb6cee1c234d5a699,
+                    0xfbc03793fe2bc218,
+                    0x4199e56fdcf8551c,
+                    0x29db8e83983d6372,
+                    0x139048c112f7a65b,
+                    0x85782a06f229407b,
+                    0x771b583403e6c814,
+                    0x241e4b2c41a884d0,
+                    0x00fafb75559cf9c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcd3df1956362eb7e,
+                    0x6db8285282eb2d98,
+                    0xbfd23996464abefa,
+                    0x41e50e78e6e7c75f,
+                    0xd8def8a4bbe8984b,
+                    0x311b17ee344bc032,
+                    0x8a41568c745f3dc6,
+                    0xe4dcfa88910d1893,
+                    0x2201189d7b3a284c,
+                    0x0079b407b17eb869,
+                    0xaa622cdd4e61986a,
+                    0x004476847082b23e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x07ca9e0cfe4107d3,
+                    0x93c7c4ef045aee27,
+                    0x0fe904f5bfdd0901,
+                    0xf84d79290385372a,
+                    0x846e3f1e74a0beb7,
+                    0x3b7d3e86a99bc7fa,
+                    0xc0743d01a1ff8936,
+                    0x285957b715f4620d,
+                    0xb85583586307c80a,
+                    0xed19c8f7ed0be921,
+                    0xe456d802084111ca,
+                    0x0114a90e503f01a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5797ec13807e5f8a,
+                    0x82ac9ea26f9e9613,
+                    0x7bc30d071259836e,
+                    0x23341f435ca0733d,
+                    0xf9db0eaabe8c14ec,
+                    0x4e3804560a127f13,
+                    0x0b53b547cc2979ca,
+                    0x9d6fc73588221f29,
+                    0xbb66747f3d5a33b2,
+                    0xa9a090ea146c2dcf,
+                    0x61e55d75f6122398,
+                    0x00392edb59134a8f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xcbf61a1e8d61c681,
+                    0xe733e654c1a0cb6a,
+                    0x92f910cedb1da22d,
+                    0x62de60048de2d21d,
+                    0x6675b2095e434ebc,
+                    0x33bd56da0782ffe6,
+                    0x284f1c17a5f4afc7,
+                    0xced860b4cba7b522,
+                    0x4f7bd69ee549e02b,
+                    0x499bfde8a394e24b,
+                    0xd91381fdb48d6dbf,
+                    0x00aafcbb2f817d80,
+                ])),
+                Felt::new(BigInteger768([
+                    0xca19abef72a77878,
+                    0x2c3da6842b260cc7,
+                    0xf919d036a0676f4a,
+                    0xd60243607b1ebc68,
+                    0xbae6b301ede1e44d,
+                    0xaf009023baf9883f,
+                    0x641e34172445ee90,
+                    0xf2223daa3ed2142f,
+                    0x8b7d9c3af0c630bb,
+                    0xcd47c5b6d8fb5a4f,
+                    0x0ff9e335a5a94683,
+                    0x004ccd234f28a128,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44189d6e5d29285e,
+                    0x7d259b7bf7b74a34,
+                    0xdd46dcedb4076c40,
+                    0x4bce8e38b1779c86,
+                    0xf8fa93b0ba703671,
+                    0xb4d2cd113ef54094,
+                    0x1e5e98bc72bb2d2d,
+                    0xd8f40267e08f6cad,
+                    0xf71be9c831ba11f4,
+                    0x83e0b1eb3ac40163,
+                    0x9d227b91358136e8,
+                    0x00463ca23e073ccb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae10dbd49c1c9f9f,
+                    0xeb49f2c855ee51c3,
+                    0x497c2b7b79377547,
+                    0xbed41c98dc264224,
+                    0x04d323fc9341817c,
+                    0xd37cdc6fc777e849,
+                    0x8e2997551f12fc66,
+                    0x2c4cae7e3a64f5bf,
+                    0x15721ad29b6393fc,
+                    0xed877b478d50ed3b,
+                    0xa6e8c1fdc0266b90,
+                    0x0079298c3b1de401,
+                ])),
+                Felt::new(BigInteger768([
+                    0x93c8cb39bb1adfe3,
+                    0xcac6fd1bea4616c0,
+                    0x6559e67c90504bad,
+                    0x52a05daaf0fcc3e1,
+                    0x36079047df275d1f,
+                    0x92a229676360afd8,
+                    0xa2773048cb98a2e0,
+                    0x472d727e5ad87044,
+                    0x6929c2ac1551d98a,
+                    0x7713c4e22e322d4d,
+                    0x2ab4527b474efa1a,
+                    0x009c81432ebbcca7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x993250025a1c1ee8,
+                    0xe0275d2f257f168b,
+                    0x71b108bb02bae10a,
+                    0x41c7d19a50727db9,
+                    0x5a5b73d43574a0a3,
+                    0x0c0c6572e407732d,
+                    0x0e51c14887c44f41,
+                    0x07adcb333907d245,
+                    0x879474c5b5207136,
+                    0x8b836a851fbeccba,
+                    0x99bc228cae79192a,
+                    0x00d3afe9a4a5406d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4f4f29b345da1582,
+                    0x6557ff518450aaab,
+                    0xe829e506c768b349,
+                    0x677097fcf5df94ca,
+                    0xff35c614d498fb15,
+                    0xfec50840adf8a35a,
+                    0x3eb73f098cbeb4f4,
+                    0x2bb554c41b5c6086,
+                    0x8984517c9e8146d5,
+                    0x3698ae6216f80b11,
+                    0x999a7a09fa183562,
+                    0x00294c9ebef0343f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x57bd60e17a27ba4b,
+                    0x5493cac6bec7d7ca,
+                    0xd3f9c402c9fd1140,
+                    0x46056467d607e103,
+                    0x96b3acb9461c4543,
+                    0x4ff544168893cc1e,
+                    0xf78215883ac587c0,
+                    0x1cdcb329427422fb,
+                    0xe6cd991e3177159c,
+                    0x5cf5f24226dcedf0,
+                    0x804aa4ee357145a3,
+                    0x00da857e1264855f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x311e722ed7975b2e,
+                    0x9dfa959003908fc4,
+                    0x42053399fdb22c29,
+                    0xad65979f542abc54,
+                    0xe032ace97148c724,
+                    0xb724382a327c1e65,
+                    0xd3151d0382c0d4bd,
+                    0xa4c88a7db04d6a2f,
+                    0xe4ae96cafe142f98,
+                    0x8199aa4cb592ccf9,
+                    0x7db3513cc8150afc,
+                    0x002891711e5edf4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0a5ddec8b83a7be4,
+                    0x6c4d8bfdc01ea69f,
+                    0x3ee34e43ace134a1,
+                    0xcaffdddac6e88672,
+                    0x5353b1597060fd0a,
+                    0x0cecd395287ce609,
+                    0x12593da7506b3e19,
+                    0x6ec74821732170b9,
+                    0x99eb0972a2b592e1,
+                    0x2fb8f2cd1011106f,
+                    0x4bba9732117598e6,
+                    0x0095a3d86b0127ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5064d2eceb065c33,
+                    0x45890dfbd8c50e2d,
+                    0x5b7fbb484ea7d2d3,
+                    0x063fb3090044a333,
+                    0xcc539eb060587661,
+                    0x01c7c6c3d5d4d13d,
+                    0x003e44d387746faa,
+                    0x90ac1c8a78842f89,
+                    0x0d3549d4cabaad74,
+                    0xe8f82ad8f78c8caa,
+                    0x2b23e6c249d89e1d,
+                    0x00b6349a44d69a77,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9135e9194c0e8978,
+                    0x7b6a49cb1c8c8826,
+                    0x4a2ccfff9229a141,
+                    0xea61ff5ca172d47b,
+                    0x3c394aa723f771e3,
+                    0xddfa6e4f332768ce,
+                    0x5ab1e8dfa82cb508,
+                    0xd63c4b12d19b5d4a,
+                    0xb97b01261681e93d,
+                    0x07017f43f24e6885,
+                    0xe8ba9b80b75b3cc6,
+                    0x0119bf6bc6c0c630,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xff9e77ac967e793d,
+                    0x522462e22e08adb4,
+                    0x12178b386156fecc,
+                    0xcaa8207ff45b53e0,
+                    0x2a39601bf1dc3bc1,
+                    0x16e2736479ae73e8,
+                    0x9643b5ea570b0f59,
+                    0x800d7156556279c7,
+                    0x7460cc1ddd474696,
+                    0x95a40acf5d29b7e8,
+                    0x90ad4a5279eb6588,
+                    0x0100e3e93ced7271,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4cd88a8f35be7f0,
+                    0x9044477df146d54a,
+                    0x50aba4c3f2253f60,
+                    0xae201cc3032bf972,
+                    0xd15b692b06ef78bd,
+                    0x03209f99a81d423a,
+                    0x8ea5cb8d68caca60,
+                    0x604dede7f9a0928d,
+                    0x2ef54f71f9cbd65b,
+                    0x20a6fe457072ca0e,
+                    0xe0fa91ff89e00849,
+                    0x0080a983a6dacc10,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc266a51c17596232,
+                    0x37c1be7d1daa69ad,
+                    0x33144aeeed076482,
+                    0x31360c607d105a71,
+                    0xd6bd9bd7a4e5f81c,
+                    0xf8a7caedf31d69b5,
+                    0x43f98d2f864cd46f,
+                    0x83c9a5a8ab72f4f6,
+                    0x20b032d2e6177f6a,
+                    0x64cd3439972d91a6,
+                    0x6643e5354a2824ce,
+                    0x002b40add4be3571,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa8a7059eb1556851,
+                    0xf4a5693ccfa8ced7,
+                    0x5dcfc36c8e9c4b61,
+                    0x5edc381554e9a1c6,
+                    0x4ecff7b5588cdcde,
+                    0xe5a828b332dc40e7,
+                    0x2e050791a0370716,
+                    0xf746c711422186b7,
+                    0x329bbf1d4b9e2c25,
+                    0x60b2ffb526c49025,
+                    0x86df4710da4d122a,
+                    0x011cbdab3281b0c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9481315f629c3ed,
+                    0x67497b8ffc5802ce,
+                    0xb632e7d563595b3f,
+                    0x8da337f6935ab810,
+                    0x34e684dffba2cd9f,
+                    0x7167d8cc7e94cd7f,
+                    0x0937ec5b9e81a264,
+                    0x82cb3b2947f046fb,
+                    0x1d02ab41f7366cc1,
+                    0xdcd1b30d99b2b43e,
+                    0xbef8a1879c4dff5b,
+                    0x002f749aaf354d5a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda198b16d5368c2c,
+                    0x544a57c8b0eb802e,
+                    0xe57972c3323acd5c,
+                    0x416f3e466d8eaedb,
+                    0xae6a6836ea011f6b,
+                    0x8d6ab1c2790e7c4b,
+                    0x4da0da87876d6f05,
+                    0x5f4393643f349173,
+                    0x32481624c075aecd,
+                    0x57b6dfba47895540,
+                    0x4c9ed1b18a139583,
+                    0x00929de49a41b53a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0eb825bb417be25f,
+                    0x0eacb57f7b4b4ef9,
+                    0x59f7048933ab003b,
+                    0x44779d08cb8a302c,
+                    0xac3e0f6286215ed3,
+                    0xf17b3fae62757956,
+                    0x019575268be98713,
+                    0xb556b5d65fcd687f,
+                    0xe75ecbf4850f1874,
+                    0xb6a086c756f74cf1,
+                    0x7ed98e3bfdfb321c,
+                    0x00e635becbeb4334,
+                ])),
+                Felt::new(BigInteger768([
+                    0x678f6855b2c3dbcb,
+                    0xb944b2bdb69a2f8a,
+                    0xe27b64246d5fc741,
+                    0x4a38bf93a76f2c0d,
+                    0xce5f9e9f5457dee0,
+                    0x3d1f4506bd213416,
+                    0xed8b29acec1a3df2,
+                    0xe497d4a267dd1a68,
+                    0x5a4ec5544b036097,
+                    0xeee408d7910871ba,
+                    0xb1bbe7cdfe9c272e,
+                    0x00c5fd97a66aa948,
+                ])),
+                Felt::new(BigInteger768([
+                    0x76bed0044b4e5c2a,
+                    0x0051f9f4082b7f21,
+                    0xda3ca654690390f7,
+                    0xc0aff0ea79fc417e,
+                    0x238e6b406d740c71,
+                    0x81b56858503183b2,
+                    0xae9bc8088592b311,
+                    0x0ff4c7abc5ea2cb4,
+                    0x86c11815d810ab65,
+                    0xfea375ff3c6b7edb,
+                    0xc90b66c8a8a74a5e,
+                    0x005e808683e33048,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1303022649ddc07d,
+                    0xae1c9e4b0b2cc25b,
+                    0x903ca078c0bbd476,
+                    0x8295de66632aaf1c,
+                    0xeaf0e48b18488928,
+                    0x7c9fa0ea960ea4ad,
+                    0x90dbfb48a6c2e196,
+                    0xd78ab4a85361604c,
+                    0x654915ff84a74711,
+                    0xdfa1347b8707addb,
+                    0x3186a7c71f5257df,
+                    0x002736216b39c39e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x63a4a33435e0828f,
+                    0xd48e81cf96ddc5f0,
+                    0x9188585063314499,
+                    0xb7cbc8b3d2a863f5,
+                    0xfd8a9ea8a90b5428,
+                    0xa53b7e072e0e7dff,
+                    0x8e6ea3997af407c8,
+                    0xe2ad863dc82f2fed,
+                    0x2621f5d04457f4c5,
+                    0xe730a7ed221d365c,
+                    0x821e7d9dd82c334d,
+                    0x0046a0ff770cc74f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4595f3d4fbfcaf36,
+                    0x2a254234af2ca4cc,
+                    0xe7a7383407dad7e6,
+                    0xfc13b1ca8abdbc43,
+                    0x879ab07b70182e48,
+                    0x174eb06905c25aeb,
+                    0x64d7c4358b1c0880,
+                    0xd6b6a5c83e4ae5f3,
+                    0xca098cd80d5fef2e,
+                    0x7c98f5b9c54eafcd,
+                    0xb2b0b1cd95b66574,
+                    0x00b4188a6cfcb5a3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5604bd1630dc01eb,
+                    0x9b09032b78fc650e,
+                    0xdf7052ce4c829a7f,
+                    0xc0bbe2aef823241b,
+                    0x8eaa2143e1bd180a,
+                    0x9b1f4843e20f9fc5,
+                    0xf977816e6c165b2d,
+                    0xf72bacba30c1b0c9,
+                    0x4dd1e9134aa45c68,
+                    0x38ba927c0ed459f8,
+                    0x7bc05517103529c0,
+                    0x00052fd8031bf7cf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x148f636029b75390,
+                    0xdde61ffe7812d4c7,
+                    0xda6ef8dd13f96063,
+                    0x2233186dc597b92e,
+                    0x53acc6585bb6efd6,
+                    0x05ace005df3d9fe5,
+                    0xd65c9cbeaaa7fdbb,
+                    0x71473c845883ef81,
+                    0xf5cbadfc2d4ed224,
+                    0x1dd449a0cea9a65a,
+                    0x1948691e4898c266,
+                    0x00abbae8db464526,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea80d8ec1f5548cc,
+                    0x469cd493f97578dd,
+                    0xcf5fd49e62335b5a,
+                    0x9317f985a6ff57ae,
+                    0xe0e02e2f76335fa3,
+                    0x462415648899da87,
+                    0xad3e5aa0b65d19d5,
+                    0xad30acb9b4fe4ac5,
+                    0x94153e0ec6a9f5f5,
+                    0xd23ef2b572b7650e,
+                    0x909d599dfbf05486,
+                    0x00371cff9baa4c50,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdddfc5c8f77688d3,
+                    0x80bf9d15de202e7f,
+                    0xe2c8c4e35489faef,
+                    0x50baba7a8efe283d,
+                    0xe94442c298edadbe,
+                    0x6f4a18a05845a428,
+                    0x03730ba61ae47f9e,
+                    0x44bd4faa20d28ef3,
+                    0xe8bba84f9f4de6de,
+                    0x362db8d2fb43cd7a,
+                    0x8966fa19d92587f8,
+                    0x008b00e493166e66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b704bb2975a032d,
+                    0xb1cde7ecaf0803ed,
+                    0x565634ae0ac390ff,
+                    0x01e0037e4ab5c51d,
+                    0x0c335ede4a2ccc74,
+                    0xbe3e740a529f0d0d,
+                    0xa908a1ea98bf8f04,
+                    0x4cf8f7d3f6af43a4,
+                    0xa119d94bdd2e1337,
+                    0x55720245da82561d,
+                    0x46d229892cc30e64,
+                    0x00948284feea32e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7fcc6898b940b74a,
+                    0x623ad1e417e1ba10,
+                    0x3817bc75138a6ab4,
+                    0x85f8c06ade2e1356,
+                    0x23db10b0d3612d3c,
+                    0x030ecd0d95cf1642,
+                    0x26b4bb126591bc4e,
+                    0xae5d51533cf6ab59,
+                    0x69b0446c71fe4bc3,
+                    0x8be65d9843e84a85,
+                    0xf9b4b9583ea23ef3,
+                    0x001ac093345be083,
+                ])),
+                Felt::new(BigInteger768([
+                    0x344885fcfbf53a30,
+                    0xa4a0600141336372,
+                    0x7f56e9eec3bc3a33,
+                    0x1e05f2f7378e4079,
+                    0xe2e354a509df3671,
+                    0x4bf19e1b8c9323e9,
+                    0x228af098bb0ded81,
+                    0x4c9338f697ef94b8,
+                    0xe5e9c1043cc84f60,
+                    0x0c662c63d0ee316d,
+                    0x91753d1125607ce8,
+                    0x00ee5fdbbc08dfc6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b9f9b75ec0447b2,
+                    0x5eaeb0324da2cbe4,
+                    0x7f49913a8b3010c0,
+                    0x7ab6738aa60b6ac6,
+                    0xf9432a023966ba09,
+                    0x144d849adc3ac993,
+                    0x10491e1a958ce699,
+                    0x2be3a203d3e60781,
+                    0x32c969913a94e2c9,
+                    0x5c6263a7c048dcf8,
+                    0xe6f0d016b9ab16ed,
+                    0x00110da27717bd41,
+                ])),
+                Felt::new(BigInteger768([
+                    0x663c8a7b38af99a9,
+                    0xb7b67d0b05d14282,
+                    0xb081ccc0980a6171,
+                    0x81b3a48aca7edf66,
+                    0x0ee5721d20aac80e,
+                    0x03d7fb9c1058406b,
+                    0xc3991b99fc4ddb13,
+                    0x09e39ef9d92e7641,
+                    0x3bf13075e0422219,
+                    0x208a583cf6f84f9c,
+                    0xea6d88a700e4aae0,
+                    0x00c082de491a8de6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f44e1685ec6aaa8,
+                    0x148fab9fb2f6e756,
+                    0xca0fc19aa41f28e9,
+                    0x8eea14bcff84600a,
+                    0xbd91cb8d4ad2074c,
+                    0x0db84a1ce7da0336,
+                    0x1e9310da5b6e3716,
+                    0xc00306c55260605e,
+                    0xd9962cc8c30dd505,
+                    0x8534f74689885f08,
+                    0x2c85d0800a44f3e4,
+                    0x00a9a1012d471579,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6e1638a8a7adbdb6,
+                    0xf0bd62459dd9d7b0,
+                    0x1049307e11be150c,
+                    0xe7ae019f1a2a4a34,
+                    0x6859289f88895ac0,
+                    0xd95c7a893839724e,
+                    0xb28124150528ee0f,
+                    0xe8280627544321cb,
+                    0x05667deb6aa7f8a7,
+                    0x2baebad94e1878ee,
+                    0xc37a3e4ab85bb327,
+                    0x00f29491222e6a30,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f7ba22d4d7785fb,
+                    0x805c90f351cebf57,
+                    0x5770b8ce1f73fcf9,
+                    0xda7a8398cedf0cd0,
+                    0xcd8b5ff81d51cbf5,
+                    0xbd5a09871cd106d9,
+                    0xd846d7b9f8a02afd,
+                    0xb4c90ffa777810c9,
+                    0xc11fffc3f6179664,
+                    0xd737ec521542d1a7,
+                    0xcd6f31fbef73280b,
+                    0x00beebbe5c83eecd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1e5fb8b37af42377,
+                    0xac1abeb4fad7e7bc,
+                    0x2fafbaaf199bfa9e,
+                    0xebc728cf7a94e84d,
+                    0x45d6d34aaa9e4d09,
+                    0x9f222d8d7b6f98bb,
+                    0xf1ca7a8b834a66e1,
+                    0xc958e62c8448bc11,
+                    0x903c3e2c71dc57d8,
+                    0x33a7186fc123da5e,
+                    0x81cfd603d96b3710,
+                    0x005f5ed62716237e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb530640cb40af872,
+                    0x3e350fd5388820dd,
+                    0x66526a92d568a8b5,
+                    0xa58ff993d6f77940,
+                    0x8f105caecb237125,
+                    0x60e140e526cd383d,
+                    0x01b4e4b86abefbbf,
+                    0x29cfe1f2e340a95d,
+                    0x85ae6c30f104ddaa,
+                    0x00abc3af07691cf1,
+                    0xbc2d7d2a9e44e317,
+                    0x00e613f9a8526301,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa80ead665b5ca377,
+                    0x28f4e3f8a236cb59,
+                    0xa80b125ce2aa997e,
+                    0xa141abc169d7cd62,
+                    0x549c4a92670a361e,
+                    0x616684cb0dd4f3fc,
+                    0xb7efed5df6258078,
+                    0x394a58f54a7a99c4,
+                    0x340171979130559a,
+                    0xef349a3a3c22b3a8,
+                    0x92038513a7ed7c12,
+                    0x00c716c64a3f0435,
+                ])),
+                Felt::new(BigInteger768([
+                    0x986faa867e912d10,
+                    0x7b46097ee07ad2d9,
+                    0x85a40f9c597825a5,
+                    0x27e219bed256946b,
+                    0xc3ea1303887197e1,
+                    0xe767b8c9dae06ecf,
+                    0xdee21871c45d33ca,
+                    0x0c1d6716c783c86f,
+                    0x06ae9a533f613d73,
+                    0xc5c00d7f338a88bd,
+                    0x4a3a19651dd81674,
+                    0x003535c92324522e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x99d35a0d0ab7cc82,
+                    0xc5853995de6d7ee8,
+                    0x0fcda2ab74a022ba,
+                    0x8041273f00e5f13f,
+                    0x54671d0772dce9ef,
+                    0x7db0cb8189fc4d60,
+                    0x6045c7f4829e58d0,
+                    0x94e11b09d9095dad,
+                    0x1c2a033fe0b15641,
+                    0x574cfddf5ac7afa7,
+                    0x21ba70091d234057,
+                    0x008c1840b94a22ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0x20327a382bf50c1b,
+                    0x4ba9f36145a37d64,
+                    0xd4890962db44a903,
+                    0x2f92149b80196475,
+                    0x8fa6fb14d248f701,
+                    0x65e98e34e3b9e1e2,
+                    0xf4a1af6191fbcf89,
+                    0xc0833bd70b5fe3d7,
+                    0x4bba567865552017,
+                    0xf02f64711caee35b,
+                    0xf52febbce1fdf48c,
+                    0x01094b6916d3c352,
+                ])),
+                Felt::new(BigInteger768([
+                    0x469028a0db589e5c,
+                    0xefbc52a2dcbaf65c,
+                    0xba406ea23726fa4b,
+                    0x4fa989569055de7e,
+                    0x77ee9d9958cb5b5f,
+                    0x68884e09e56e9396,
+                    0x6362e78a41cd2700,
+                    0x95a4bbe3d350232c,
+                    0x6e9d509a35509420,
+                    0xc5df8fa82c25acae,
+                    0x9123260fef89a494,
+                    0x00c11016b91a6fc9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x80b702c11804e7f3,
+                    0xdf63c8d88ed6e1ca,
+                    0x544882384859970c,
+                    0xc13b498c73392149,
+                    0x35d93c62b7010805,
+                    0x9fff2a658470c650,
+                    0xa05e5293b755e8f3,
+                    0x9915ac75194e8ea3,
+                    0xdb5b57c8e3e0a927,
+                    0x2890dbc3508eb4f9,
+                    0x58e18e5efc7db0eb,
+                    0x0044330a2c1fbc3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x690d990315a760f7,
+                    0xf4d5d4e1369b1dd4,
+                    0x14e25a90087ae1e8,
+                    0x8070e139ad5d409a,
+                    0x351aa1ad069de103,
+                    0xa380e9c1f17c716d,
+                    0xa3df13ae46c27f8f,
+                    0x22fe13ed7d3412ba,
+                    0x33dc6e6b4898cd36,
+                    0xb8915af11b7747ba,
+                    0xbb3bc204e170ccfe,
+                    0x001fcc5fbc025eb7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50ef6e1558884b12,
+                    0xa6110910ca475328,
+                    0x819f128d2f8614aa,
+                    0x15bbb9410590f62a,
+                    0xceb49b2695f44137,
+                    0xa943107a97eb7577,
+                    0x573f1b2d0ebf2e28,
+                    0x309d6650a96e1965,
+                    0x91dce45fe2d31913,
+                    0x2baea82c38c8cf2c,
+                    0xf19d5109436eec60,
+                    0x00f8c18f1349030b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x45211c8ec466e24b,
+                    0x2810ec4dc08b77cb,
+                    0x84b680688a081dd7,
+                    0x340fdb00fc6308e6,
+                    0x73dd10f5f0c1e8be,
+                    0x30acceb352b000a4,
+                    0x52a69cf08c5023fa,
+                    0xe66904437d819477,
+                    0xbbe5345936ad0088,
+                    0x7a0fe7dc709f3a3b,
+                    0x5943b716aef05900,
+                    0x00e31b4d48c59522,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb11a8d168327120e,
+                    0x3b0a49741f20687d,
+                    0xa628a370ea1d7389,
+                    0x6999a6e7a384fa06,
+                    0x01ef4bd7d16f729c,
+                    0xfec50af7755a803e,
+                    0x2c9bc9336f871214,
+                    0x3c16cd24203c75f5,
+                    0xbdbc05277f4e382e,
+                    0x3f7ec9431ccad6bc,
+                    0x335b6987b68705e0,
+                    0x0096196bd609622c,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x66df850e41fa9633,
+                    0x32c908eb66e07813,
+                    0x6e0a148464e0336f,
+                    0xf2ae1fe72efb870c,
+                    0xff23394bd6a7dabb,
+                    0x18e21fd85ba5df40,
+                    0x6afbc71d15f9f9a0,
+                    0x6c1306d88876a514,
+                    0x29a5537f9fb9e61f,
+                    0x3d459ae38edefd88,
+                    0xc67d45e5066b2e94,
+                    0x00ffdd42d1398a92,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3472de316adf29ce,
+                    0x4bdb8ea8eeee7dbe,
+                    0xc5a16d52f5594cc6,
+                    0x718db3b1d33defdd,
+                    0xca6560f0e3b9948d,
+                    0xd56b6f9b3223e688,
+                    0x9c09ac4d6d606058,
+                    0xd6ab604256ee8399,
+                    0x665a65d2734aca42,
+                    0x608fb32697e7ddf7,
+                    0xe3f69be18a35559e,
+                    0x010eb75b1cc27f71,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb2774c6747d854a,
+                    0x467408e3f59b50de,
+                    0xd389b0db1a332d91,
+                    0xcfd08cea9b04e3e7,
+                    0x6ca37bbe52f70394,
+                    0xf5c656aff87ffa91,
+                    0x7f2fd4084b3d7895,
+                    0x8410aba36660d63e,
+                    0xc4f2b1d8d8354e1a,
+                    0x7aa828222bf63a86,
+                    0x02472eb966c939c5,
+                    0x003fe8bbc112d118,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9dffb2e5b3eaa36a,
+                    0xa2e22ebe7995982f,
+                    0xc5b08192c89da9db,
+                    0x2eb7ebaacbc3bd03,
+                    0x7c88c156807dd52a,
+                    0x36cd57bb761c1517,
+                    0x68a1a23d4a8e29c6,
+                    0x68093fb38042397e,
+                    0x620d45be3c912f56,
+                    0xd7615df58260fc8a,
+                    0x3731a498c4ff1835,
+                    0x0098bc6b645b9ca4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x94e515470ffd691a,
+                    0xf20edc310b2e0bca,
+                    0x96a8f12cb8b061b1,
+                    0x3d622a16fbd489cd,
+                    0xe95c99fd8dc26be3,
+                    0xb44687becf75b2de,
+                    0x1486a3339e5e7051,
+                    0xcaed62a9a007491f,
+                    0xc4d0f69c4d6341e5,
+                    0x0792a11cfa492a76,
+                    0xbfd8acd10df3c670,
+                    0x00ecc4099fd776ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4f4a52cf647d67e4,
+                    0xc34a579a59318480,
+                    0x0aacf11ddc2d3a71,
+                    0xaa0dc9caab7aa5f4,
+                    0xf603cc6c80aed655,
+                    0x43b2255e25f02052,
+                    0xc26c2f88d73fc4a8,
+                    0x4dc011fea81f36f1,
+                    0x789db5c0b013a9e5,
+                    0xc47d1b44fcac03a6,
+                    0xa6c2debc99483976,
+                    0x005f815a52181a26,
+                ])),
+                Felt::new(BigInteger768([
+                    0x690a7e3624e8edaa,
+                    0x6082eac5b878a4bf,
+                    0x1fbf416901e9b70c,
+                    0x18663f1050acff27,
+                    0xc15d845dd5c7e37e,
+                    0xd10977fad133ce62,
+                    0x18d94b738ca45715,
+                    0x9f293739b9128c2f,
+                    0xaf63fdbaf72063dc,
+                    0xeecbb7cc234bbfc4,
+                    0x47d070f099153614,
+                    0x010b98f44355d236,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd3304322cd662ced,
+                    0xf1542e1dfa977d25,
+                    0x4c923cbe88f942b0,
+                    0xcc8914c4eb206edc,
+                    0x658de17c07b79578,
+                    0x77ce51244ddacce9,
+                    0x11594b175caadba5,
+                    0xbb2b64ea6ff274ff,
+                    0x0e5ca42188666e11,
+                    0x0c9aaa6ff3ccb629,
+                    0xd1dbe97792865d90,
+                    0x00c570085ddf671a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x007050b65005952b,
+                    0x750a12b469712d20,
+                    0x99c60a39f0daea9f,
+                    0x6ebf1ac0a51dd722,
+                    0xcc4c34d025e2c71b,
+                    0x9486359bf573ac64,
+                    0xd024c8309340c241,
+                    0x734989af39dae9c4,
+                    0xce6d3fd7bfe4f917,
+                    0x8cb48b21975ad1de,
+                    0xd888d7ad7ca45e5a,
+                    0x007dc5062f4a16d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb189b2e5d5bf86f,
+                    0xaa7f6ba9f18809f2,
+                    0xd0052544a87c7fee,
+                    0xa356cf78f18f56a8,
+                    0x6d64a77289402c99,
+                    0xdf61bc4c32585eaf,
+                    0x9b9922dc99ee351e,
+                    0x5fa144a0a07dde3d,
+                    0xd618c7a845d6cb4b,
+                    0xaabfaabdb9b964f6,
+                    0x7a6f2f0b9eddebe7,
+                    0x00539640dfd6a3cf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2dee5843df1f6ed,
+                    0xd32caee776640194,
+                    0xd0c8d33f155a7e22,
+                    0x1e8ffba1eae57167,
+                    0x697fab631a1d9389,
+                    0xf515c0d3df8a9644,
+                    0xfd668162db86b9eb,
+                    0x618b803f4925eee7,
+                    0x2ad82bfe57f4f116,
+                    0x30987284b13b8536,
+                    0xab81465d2d7b1e99,
+                    0x01132ad2fc3fff87,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd22f1e3a7557afb,
+                    0x4521a8db1b476d79,
+                    0x8fd585d8627f1d82,
+                    0xa9de1d7c9998ef58,
+                    0xe1fecba16c0fc48b,
+                    0x047cdca418743596,
+                    0x117ac6a02bb15d94,
+                    0x09c60d9a1ad92d79,
+                    0x65f8f4432d11882e,
+                    0x4917d521ec7ae9d0,
+                    0x98e22ed64cd8fcc7,
+                    0x00b26fe3dc4fba73,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x07974bf503639c30,
+                    0x3c946243313c6265,
+                    0xccd86cbf0c52f8f5,
+                    0xc5b418f3cc1962dd,
+                    0xd41f3eda1a9458ee,
+                    0x394d5970aa3ae4ac,
+                    0x38f921af3a0ac8e5,
+                    0x837e795253538a35,
+                    0x99e4fde9c57ad035,
+                    0x1f74ab3f5a585db3,
+                    0x361ebaf7c8cb88dd,
+                    0x006498b5afd7f108,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0433b1094c4b3349,
+                    0xb8a7ef75160b2b05,
+                    0xe3650d4bb89d00ca,
+                    0x2e6ed497138d8a8d,
+                    0xa3176f49e79c2ed2,
+                    0x830d1d10a3625bcd,
+                    0x3b3398388a91843c,
+                    0x3b50118f22f3fe06,
+                    0x26be0404681c751e,
+                    0xa035f06f9727954f,
+                    0x76a3a0288529ab62,
+                    0x0016f0c7292b80ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcb45127915cdd22c,
+                    0x9635e2224932b506,
+                    0xb1ce2ae2b272e1aa,
+                    0x8261e1c449e0d435,
+                    0x3a3eb9d993724efb,
+                    0xf445440f975c4f03,
+                    0xe68993680dbc19c8,
+                    0xa4ccf660d6ba2d96,
+                    0x23440e5c2dc878a7,
+                    0x4b0a584c90a49e28,
+                    0xa78f7927ec4fdfd8,
+                    0x005c51ad6de8d862,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50cac5f0df3aab5c,
+                    0x5ef7124d2f70dfd0,
+                    0x3c6da00670093d19,
+                    0x8f97f0a333f64a4b,
+                    0x48544a5949e89328,
+                    0x1e69c637d826ac51,
+                    0xdc489593b45f1f6b,
+                    0x6bcd25bc83c59202,
+                    0x03b7cc20fc6e1b42,
+                    0x0247a905ea10acf7,
+                    0xb94bff19a6473dd3,
+                    0x0015ae65142bec68,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa0bf595c5cf09a2b,
+                    0x4bcc17fbe9c0f507,
+                    0x74766853da885f6f,
+                    0xee9291d194f3bb55,
+                    0x854acf1200e4113f,
+                    0x847ef6f3297626b0,
+                    0xe75e5afdf408f5c7,
+                    0xe113115a245cb81d,
+                    0xc4efd0656dd93b9b,
+                    0x31545a166f3fc526,
+                    0xa29d109bab0e34cc,
+                    0x002770933af1b122,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4eba8d261ac7684,
+                    0xacab3cf83d2a80ae,
+                    0xe11037656d0b4d94,
+                    0xe70ac7a23e8f3d08,
+                    0x18e678f69cd34871,
+                    0x5054574c0c2ce06a,
+                    0xa87a9234259a21c6,
+                    0x9867a30285120e80,
+                    0xa0866355916977a4,
+                    0xf6e06f62e4fc470e,
+                    0xcdd139fbcb1acc3c,
+                    0x0082f935cba2947a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc51cf6eaaf97c53,
+                    0xa3490f616613367b,
+                    0xc893033f34bd10fc,
+                    0x4c9b0762cc12f670,
+                    0xf59a5c7e064e67e3,
+                    0xd7215a6459330f40,
+                    0x8b18a84db37502ea,
+                    0xe92ccad67599275e,
+                    0x98ed9883f0893735,
+                    0x073ba123a0d548aa,
+                    0xc2cd01eee3eba4c6,
+                    0x003a72d0e9f8cf2d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x27ca58379ad26585,
+                    0x6d575e7a2140b7d8,
+                    0xf9559265e2641fbd,
+                    0x3689d8fa7943675e,
+                    0x8631c0e3c914c819,
+                    0x65ab911a5ec3cad2,
+                    0x42e9df990f976e8b,
+                    0x7c42a53b7cbf2bb3,
+                    0x90c0a5bcfc65964e,
+                    0x05fb3464bc19bf2b,
+                    0x6dc9a2d2e7f2b26d,
+                    0x00c3aae55f2f92d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9cfd532e353cbd79,
+                    0xdbb526734e642c65,
+                    0xfe1057c561721426,
+                    0x2d436d8fdab8f3b7,
+                    0xc7d7fd7f0713fbf4,
+                    0x810d34d824696e6b,
+                    0xad06a89abcd33c9b,
+                    0x81c363cbaf200d57,
+                    0x2484fdb5b659c166,
+                    0x496028efefc5d1ef,
+                    0xc067c7d188228e27,
+                    0x00ea9ac3e01f3596,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea13d750e51e16e1,
+                    0xc1f72e50eb5d85f6,
+                    0x4abb62e375067fec,
+                    0x23460087d8ecca5b,
+                    0x5212df250e250394,
+                    0x3e30fa2f30d0e057,
+                    0x4c7705fee1b8c988,
+                    0x1e6d872ab3c4fadf,
+                    0x9bc752a8b53e0c01,
+                    0x6760c2444cd0ffc9,
+                    0x9812ef274aad458b,
+                    0x00bbea03d060cdc3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29beb10117bc2f3,
+                    0x374e3405698ee31e,
+                    0x14f49c4f65a9668d,
+                    0x09167151e9fe180f,
+                    0x6fafd66ca5941680,
+                    0x0aa267c3733a2f1f,
+                    0xb748036a7e580d7b,
+                    0x18c477c61bbb3c8b,
+                    0x35b6980341656f24,
+                    0xb4f12f5e288d240c,
+                    0x6d1cc1132acd1340,
+                    0x00c296a2414b627d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6f1495993a55fc26,
+                    0x2748d528bed4a801,
+                    0xac4eccbe3008c8ac,
+                    0xd152c0d2041468fd,
+                    0x89ec4ecfbe7bebdb,
+                    0x277e168c51bdf438,
+                    0x5de23dc22a3cbfff,
+                    0xe1603859b7627166,
+                    0x666719ef9a43a7bf,
+                    0x9a29c01a4ac7a81d,
+                    0x0d869d6c56ef9c1b,
+                    0x009eb1bacd76938e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0694e5da4c264a00,
+                    0x6d2cdb003ffef1c9,
+                    0x123e23b8310581d0,
+                    0x49229acfc59398bb,
+                    0xd942f1d3ddc479ef,
+                    0x333d1664288825ad,
+                    0x7c5aa2e04e43c338,
+                    0x2a2ae101baf3ea52,
+                    0x18488a207cdb0f20,
+                    0xb59e29dff566e342,
+                    0x9cb86f1244e78850,
+                    0x0056ba4e179bda7b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa893685a3693ad4,
+                    0x7a1b98344300d6e3,
+                    0xfff9aa8519578dfc,
+                    0xb3fffbd4b4154d89,
+                    0xe08748ac246b184c,
+                    0xd394fa4cae529b24,
+                    0xc667b9f80e70c633,
+                    0x96d92d5140517940,
+                    0x5ec91f76ad7aaf13,
+                    0x74a3f87b54b41e27,
+                    0x62f4019ea5bec127,
+                    0x0014edd37f121547,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3130a52f05dc91b8,
+                    0xbbbc2f697d7c7b92,
+                    0x0c9855f6b0a64968,
+                    0x9e5eb1c040dfb586,
+                    0x6a437150184a3cb6,
+                    0xd245be459d4b4c06,
+                    0xcd30f816d7bf1a59,
+                    0xbc2eb2b37123cd34,
+                    0x5223d4c67be5cc5d,
+                    0xc214e2038917b7fc,
+                    0x6a8f8929c160c811,
+                    0x00cd3ecd88c6848c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x23750bd3074f3e72,
+                    0x8ae292fddd3ed2db,
+                    0xac8fe89080db8104,
+                    0x921f5f48d32fd91d,
+                    0xd13e2e30f07ebe2f,
+                    0xccdee9497dca317b,
+                    0x892b3cbb51eaebe8,
+                    0x126b1664c043598f,
+                    0x43506ea73f3baf35,
+                    0x7e0fbc1d3809a188,
+                    0x75918f893a941838,
+                    0x004ad9cf39a78a2c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71d953790273b7f1,
+                    0xb8ffac9ccb6a1cb2,
+                    0xe54deb3f94df2fd5,
+                    0xdca630351af8a64a,
+                    0x8c2cd597d3339a0b,
+                    0xc52f8170ac9b0963,
+                    0x8c5a80e7c67f92e7,
+                    0xb71bede76ca834dd,
+                    0x1fcf248656aa4c1a,
+                    0x290ff258859db2c4,
+                    0xcffcfce013a513cb,
+                    0x00e62b5d56c4efef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0bf8f22a2349495e,
+                    0x653c5e4df2bb4e5d,
+                    0x843378c1f79e70d4,
+                    0x3ac53f8e54fe916a,
+                    0xa2d60460c2f232c5,
+                    0x7d05a02fba17ca21,
+                    0x539b1afc98c92692,
+                    0x156fd42c5c479c3b,
+                    0xa41a2ddfb8443688,
+                    0xf164c39e028d4139,
+                    0xcdceab486ec93294,
+                    0x006f9cc01d9b6431,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d4f42d045ed24e7,
+                    0x1512390bae6b37c8,
+                    0x480186da62d0166c,
+                    0x77ac0ae10a6dc6f5,
+                    0x57f0bb94b21d012e,
+                    0x421f91dce1aeda1c,
+                    0x1a4db76fe8e83e2e,
+                    0x21447df2f338981a,
+                    0x6d7ac6e364da7c51,
+                    0x40b9bed11e00aa94,
+                    0x83b3600998d50b82,
+                    0x00b84c430d605192,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89bf83ecbb4fd4de,
+                    0xd67d8170fe72dffb,
+                    0x4a38556367d437d4,
+                    0x4f13e40dbcc569b2,
+                    0x292c353763137ee0,
+                    0x52ff38e271a92fab,
+                    0x2daf800e8bd96c11,
+                    0xd0ec0ae40e398f21,
+                    0x18f19f2e167d26d1,
+                    0xd25d23983d8ae8a0,
+                    0xeeb2a4bf80d92661,
+                    0x00b5773ecd493a26,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f85a54f0f278c2f,
+                    0xf91cba36df1688df,
+                    0x031c5929715151a2,
+                    0x3efaa5dba086d897,
+                    0x031942a981f9c1f6,
+                    0xc3839b617eac4806,
+                    0xc34ec005e66c08bc,
+                    0xa9a77fb52a523463,
+                    0xf127fd999201f0b7,
+                    0x60293252adcefbb5,
+                    0xad5b4571f8a267c6,
+                    0x003c24013a687e06,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd6130447895ba43,
+                    0xae76c1558feda7f6,
+                    0xc1175c88093cc9cb,
+                    0x9efb7f26768146b0,
+                    0x2709d83f53ddc715,
+                    0xc8da25188cbf6449,
+                    0x64296bf50399e6b9,
+                    0xaa3466049e29a8d3,
+                    0x7d4b1f1445052eda,
+                    0xe4c0bf45a7f0fd64,
+                    0x907a5b75ee9463f5,
+                    0x00eb5cfacfbced1a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x344749a57a07c9cb,
+                    0x13759f7860ae8a4f,
+                    0x394603a5b940c6de,
+                    0xffabc6f2ee2ed761,
+                    0x784cb2d27cc818ff,
+                    0xb31d14ba54756819,
+                    0x03645fd23608ca81,
+                    0x14c278f69119055d,
+                    0xa1e0c8082a57afde,
+                    0x83d3788d5f9c4e28,
+                    0x4830c95052ad27c3,
+                    0x00493faf17416080,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5bef17c8728d032,
+                    0xb66dce87f5693de4,
+                    0xfbb05d1a30fa4e96,
+                    0x57a3a4eaa13603d7,
+                    0x947029f77a8a8eff,
+                    0x962a4fbbec510b15,
+                    0xec8faba363547ba3,
+                    0x85034e66bd3cb534,
+                    0x42d8c598cb5f2669,
+                    0xe05817950c9785bf,
+                    0x3b9f27464f398bdc,
+                    0x005ab7a00efdf72f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7fb8a81b635440cd,
+                    0x3f55d1a2da2f05ef,
+                    0x9ca1981330ad6bab,
+                    0x76fd035c2ad7a481,
+                    0xd949128c9c99d84b,
+                    0x674a6ccc6b09fa0f,
+                    0x8682714bc55bfe53,
+                    0x1e0830a52b229fce,
+                    0xa7fd683889ded414,
+                    0x438986794b91816a,
+                    0xe8bea6c468a0a991,
+                    0x009917375dfc259e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3c11076a864211a,
+                    0xed2c89ad28194441,
+                    0x6b58aaec433fe036,
+                    0xaaa8b143f3dd385c,
+                    0xe9122ee3e6124a95,
+                    0xf6d8d2ca94c3e459,
+                    0x6166a00d5a6b43a6,
+                    0xdc6b39f2ead8e570,
+                    0x859592c50bba71f7,
+                    0x5e9b90c0e363cef4,
+                    0xba13032c2b44b618,
+                    0x012268f57222a787,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdc139aaca4a60f5d,
+                    0xae1024d6d3dda0d8,
+                    0xab5c6ec4d4c70325,
+                    0x527750edbb08c236,
+                    0xc68dd3f9f411c2ef,
+                    0xac006a5e1945ed8e,
+                    0xe54bc8decead2b9a,
+                    0xddc2b7ad4fb64ba8,
+                    0x99ff8a2f410da590,
+                    0x2f7203850b842612,
+                    0x467c5abc8cb8c008,
+                    0x00cc2eedc4849b5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xab9c22a0ce0085af,
+                    0xa62422680f46a8f4,
+                    0x0c2c6bb41472dbe0,
+                    0x0ba8ba1bf6f6c315,
+                    0x565277cec6692b81,
+                    0x8393b39753cf2e3f,
+                    0xb80011150f9ae747,
+                    0x9bf9313c7061d1d2,
+                    0x804f7604ba153ba4,
+                    0x18f4d22538ae0526,
+                    0xdb1facf43e017542,
+                    0x005445f10b4ae62c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd29f831af90ab277,
+                    0xdd7d9b589ec1c519,
+                    0x5456eb0362a5cef2,
+                    0x87d6ded1a3029489,
+                    0xcd817114279d9209,
+                    0xc548abcc25225f32,
+                    0x9256fd389048387c,
+                    0x6cb79c7177207d02,
+                    0xafbecb0bf506a1e8,
+                    0xbfa1a31703058ab2,
+                    0x694318de157213f1,
+                    0x00cc201b23c1df76,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb29bfcbded3e241,
+                    0x4e1b4cfd31ce6aee,
+                    0x09b099fbf8cef2c2,
+                    0xa9c681ee72bae163,
+                    0x6e5ff43d1188cf48,
+                    0xfa2dffa1028a8927,
+                    0x9ae044ddb6d3e5e2,
+                    0x002dd36317e1b051,
+                    0x7ace3596e4162f1a,
+                    0x70970b5d1e6d414f,
+                    0xd7b580198ac204e7,
+                    0x0034c7bb5ede672a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc06712a28043882,
+                    0x3f40aac12b661a34,
+                    0x791bca8ad53db6b6,
+                    0x02beb212b0723f65,
+                    0xe5c4ba3f7cdbfffd,
+                    0xa5ac9f1c358154cf,
+                    0x6419b7f0d7c1abb4,
+                    0x12a7d4f4c7bdfd7a,
+                    0xcec2e3421e568462,
+                    0xa470bd21bd284ef5,
+                    0x5f142e4f149075e4,
+                    0x0041503b5d7b54ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff3db9c59ec2edd0,
+                    0x8d6d33f374b9c8f9,
+                    0xdbd7f092ecfd311e,
+                    0xdfb684deaff0c413,
+                    0x7480d7e2ded16799,
+                    0xa97a7f5d11c9c013,
+                    0xed6ef9cb6f1163e1,
+                    0xfb530abadc805974,
+                    0x9dadcd5c34ca1e8a,
+                    0xbc124387c40eb23e,
+                    0xef81dedf5e1fbff1,
+                    0x00cd00811ba48da7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc26e018cbb4d48af,
+                    0xda64a60efec595f3,
+                    0xede9d76575b78192,
+                    0x416bb066c53dd41c,
+                    0x9ad82832d95990b3,
+                    0x282f20d734454bc5,
+                    0xe972f2b9379b2926,
+                    0x85965a920944595e,
+                    0x39fab1f78782e3fe,
+                    0x7dead4bb827097de,
+                    0xc6bf86f76bb90daf,
+                    0x00dadd66dd2c1c5e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9284c68cf51a9376,
+                    0xf18853bc7e3b1b83,
+                    0x05356bb4f57fe813,
+                    0xb81384cf5f717b5b,
+                    0x67e4cc6a127e9623,
+                    0xc5738966cd359755,
+                    0x5df061dbb1034e07,
+                    0x44f290986b0a918c,
+                    0x7ceb48c55cf3c10b,
+                    0xb1031ae87a30ed14,
+                    0x026e1fed419f54f8,
+                    0x0071d7661c22027e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f34709b37956427,
+                    0x6066a39f9006eb42,
+                    0x081662a0bce25a5f,
+                    0x69e2d4e10de04415,
+                    0x09f8803e6138072b,
+                    0xee45c3be7125b952,
+                    0xfd89328e32ecda39,
+                    0x5c88bf9bde3cdaf9,
+                    0xcb83766301e18173,
+                    0x6cd8d27e25fb519c,
+                    0x861513acc1e62654,
+                    0x0100acf0390b5f88,
+                ])),
+                Felt::new(BigInteger768([
+                    0x09ffedb9f2f078de,
+                    0x570930b4747bbc3b,
+                    0xb7551b8f9187b51b,
+                    0x2e211fe76e7f9bc3,
+                    0x9557f318464d47e6,
+                    0xbe765b86ec6673cd,
+                    0x48f492564546c960,
+                    0x8a41aa757ef97de1,
+                    0xa467121d8d22c6af,
+                    0x7f72229d77123391,
+                    0x51d867a70c1cfe71,
+                    0x0109eca8ab780fe8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9de3b06fe2b3ab86,
+                    0xaec311c2b062cb63,
+                    0x2f117b1f02aa7499,
+                    0x788fdd0292e4dd16,
+                    0x92fec486b363f05c,
+                    0xd2b727ef6a4b3551,
+                    0x03ea86b0205707b2,
+                    0x772107d609a19c21,
+                    0x536007e343d65d09,
+                    0x41be41f8f3c92bed,
+                    0xbfa523b5eaefcfce,
+                    0x0037e4804d5e48e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x771735f25043e151,
+                    0x61109e5e555be777,
+                    0xa866ab411fda142f,
+                    0x6eba068312833382,
+                    0x64f803e2610b0039,
+                    0x2a879e9ba8a5d2c6,
+                    0xbd3065f2023f1704,
+                    0x917dc1a609fecec8,
+                    0x61ce3efd3e4d75d4,
+                    0x13ab511e4274429c,
+                    0xe24dcf72098420af,
+                    0x00ef26065640a194,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd7a929dfb074b403,
+                    0x38dbca5dd1938e48,
+                    0x8d07c87c99f321a0,
+                    0xfe6d379602a73271,
+                    0x925d258b129f43d2,
+                    0xfed3b24263dcebb9,
+                    0x154709f56bee1fe4,
+                    0x6aee0e94537bec72,
+                    0x5fb061a0475b8ae7,
+                    0xb0162c6d245b5fc1,
+                    0xee931e5d3ca46727,
+                    0x0039878f4b097733,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f8946080a970d6b,
+                    0x41b76ac2b9ddef60,
+                    0x115076c6e6570704,
+                    0x09de6b706ab716f8,
+                    0x4a022a4b305d532e,
+                    0xc3ba08ba257dbdea,
+                    0x32b36d0373a490d3,
+                    0x8c9aea340e5d7879,
+                    0xa71b7fc604e77635,
+                    0xf71dc4220d56a87e,
+                    0x76cbdd133c5ac525,
+                    0x007b5054404e3f85,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6b9d5860f37dac24,
+                    0x5ae2a2418636b088,
+                    0x6fc0bd54b8541dd3,
+                    0x82ea839abe2fdb8f,
+                    0x5b8d6d0e5eabf6b9,
+                    0x7c94e325565aae6f,
+                    0x75a7fd503c28008d,
+                    0x017399af14c4185b,
+                    0x352ff56d7e4b7e41,
+                    0x0c41bdd39bd26af0,
+                    0x3b376bd0a318af2c,
+                    0x00f1e3bf41764f2d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb29b569c460491ea,
+                    0x2b711510cfbd7ce4,
+                    0xa7dd9b61b488beb5,
+                    0xd38ad5c19b11e1de,
+                    0x79af25dabb61c952,
+                    0x355ff6ec4945966e,
+                    0x4138ab0498673812,
+                    0x4fcc92048b4e6d92,
+                    0xa76be188a692fa08,
+                    0xad797e3a826dff9e,
+                    0xcf7541fa3ad9063e,
+                    0x001a679134c2c229,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e8afb9d4ad3cb98,
+                    0xd8a96444e3fe2d65,
+                    0x4ba6e2d1b1149427,
+                    0xe79961ef75c61877,
+                    0xfd3be6edd39950e8,
+                    0xc5a3300456e2f8b2,
+                    0xccf59cd9e6639cea,
+                    0x430dfc7a4cf336ef,
+                    0xb70f29e3425bcb38,
+                    0x19addf3bc2ad92cc,
+                    0x5b572a3b02962a55,
+                    0x0000eda14654d86c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4ab8e1473841610d,
+                    0x0d7e2468916261c1,
+                    0xa51a4541d79adabd,
+                    0xb1ab5d04edfd7aa4,
+                    0x0f1394137cec8411,
+                    0x473534593e9f3b59,
+                    0x4325113ef941353f,
+                    0x76e462cdfe1978cf,
+                    0x9f6b793245bfa9b3,
+                    0xb27b71104404c3b1,
+                    0xa8c01154d5205a0e,
+                    0x00eeb033f2915f29,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e5760bdcee2de11,
+                    0x3ee82fed9220103e,
+                    0xa0ffb0113df24fee,
+                    0xf79aa66201aa8b2d,
+                    0xdf2a9ec6ae97fa67,
+                    0x8a1afd159a46a47b,
+                    0xa5056ca99339a166,
+                    0xaac6866bb02dd77d,
+                    0xe681454f64459a52,
+                    0xbdd13830d4337fd4,
+                    0x701a00de9a618e3f,
+                    0x010dbc2c8c14c474,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6593d77eed2c0bd8,
+                    0xe9f7dae6d8ada466,
+                    0x354abe904caf7622,
+                    0xf2c7fbd4b969c71c,
+                    0x0ce0e7d4861dce71,
+                    0xefe93e456ec078f1,
+                    0x96e9e72c1e37afab,
+                    0x7ee90e5d181b8545,
+                    0x4f8bcaa7efbc4263,
+                    0x20174ba4d12859af,
+                    0x510519980ee3c2e9,
+                    0x0120195939352747,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb916df76b82bbeed,
+                    0x5c52b092851d1d30,
+                    0x6215cc3580b28771,
+                    0x808bc3aab042bd05,
+                    0xa763fa4290529f92,
+                    0x63e30a8e7458644e,
+                    0xd446f9d689373c2d,
+                    0x97c926ffc30d547c,
+                    0xb1630b89ac7f287f,
+                    0x6d46b51b715a3acb,
+                    0x5e8a1b2d8f2294ad,
+                    0x003f3eab878a7df6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb70d2ee12454cade,
+                    0x7d2f5b95bc3f4010,
+                    0x77ce8e3ecebe1330,
+                    0x3e969ce833f1f4ec,
+                    0x11e94bf0482bfe02,
+                    0x7c93a7cd08923fe7,
+                    0x549ae8bab6a522b2,
+                    0xa564e0bd0b42b7e7,
+                    0xa3ffe621774ba4dc,
+                    0x5e05689f0aac2b06,
+                    0xe7df143a008bdde3,
+                    0x00581d88d3dad1db,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6d0b6e9f78aefbc1,
+                    0x0b9ec627c5e81b50,
+                    0xfc5f50f04f4a9bb7,
+                    0x246ad229270fe9b8,
+                    0x01d4b4e7fd8924c4,
+                    0xd1d78c906ec85129,
+                    0x0a1c843e3c2fcb4e,
+                    0x65a6392409c07bdc,
+                    0x943e6e3851976f24,
+                    0x79eb243a2920efc8,
+                    0x83de58d88db3ed83,
+                    0x00e3d26a93764347,
+                ])),
+                Felt::new(BigInteger768([
+                    0x56f43088737c6104,
+                    0x7056bfb1d3263ca6,
+                    0x46ca0ea64b9500a8,
+                    0x3db707dcbb5a974d,
+                    0x7fb2e8f52ae0c015,
+                    0xe63d1d2a86afc456,
+                    0x233639732d1593a5,
+                    0xf0c7ee5943ced3d0,
+                    0xe15843cb63a485a0,
+                    0x49af128f4d5ef719,
+                    0x5949d0d964f2bc94,
+                    0x008acdbecaa88214,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee6742c248efe3f4,
+                    0x266793612df05638,
+                    0xfafdac30844df21b,
+                    0x5ce62213a11f3cc5,
+                    0xb591ff383a1299b7,
+                    0x6c03d6f283d73ccb,
+                    0x1fab1849d6c94188,
+                    0xa9e8006af14ec1f8,
+                    0xd33da855e1081458,
+                    0xd41a1e66cf1a7be5,
+                    0x35ef4b9a5e1894ab,
+                    0x00643342dfc0270f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f475aafa15b543f,
+                    0x322bd2252323cb2a,
+                    0xb90e94053e195fc5,
+                    0xc1c2f75ddee49731,
+                    0x4f430ef70f811f40,
+                    0xf1b5db3f2f10b31d,
+                    0x0eae1c912042e016,
+                    0xeaed832b40b7be2e,
+                    0x3d4bd8f62d60be3d,
+                    0xad04e53b60fcc435,
+                    0x995ec5ff11c771a7,
+                    0x00e0b667fa2aacb3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36abf0b937eb5f0f,
+                    0xbd13e52b04ac1bb4,
+                    0x9a3d890c4b1ad597,
+                    0x28b2ae40ebcdd75f,
+                    0xbdbda50c203332b3,
+                    0x5692f96323e14f92,
+                    0xdc0361633956a6ea,
+                    0x5afe3a4c2890b3ab,
+                    0x2258ff8fac9aa2b3,
+                    0xf82b1a5128468b48,
+                    0xf53027bccd5c6118,
+                    0x001173375998d254,
+                ])),
+                Felt::new(BigInteger768([
+                    0x055e8599e9a14db2,
+                    0x5e088a61abe607b6,
+                    0xf5e0b47b4d782627,
+                    0x63b661c8e7fbe1f1,
+                    0x307cd5574ea7eb27,
+                    0xe3131955cf6d05f4,
+                    0x4144698a9a97e536,
+                    0x417a532567ef0459,
+                    0xce541b5a6ac23192,
+                    0x8fbd7365f4ceeb77,
+                    0x01c9903f482bbf0e,
+                    0x002cce4594297fe5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x96a10d74fcb7bd36,
+                    0x5d7edc53ae79a2bf,
+                    0x949436e0e1bc9b29,
+                    0x198b2bfea9959575,
+                    0x297031d4ff64bac2,
+                    0x209220585013aeae,
+                    0x9a40c2d44f97231b,
+                    0x5012a2c35ba77313,
+                    0x22a1bae165b46a54,
+                    0x7f5bf5ed5be6428b,
+                    0xe342a4e05615a8f9,
+                    0x00acaf33ffb359b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x24c36cc9a0d91d32,
+                    0xe89aaad907b68fc4,
+                    0x7dbafbc2170521d7,
+                    0xf82b4b805b22c013,
+                    0x8f43d6c7335ca813,
+                    0x63213d842eeb89f7,
+                    0x329251acd48a3c85,
+                    0x1516f391e14fae14,
+                    0x04770ad22935359f,
+                    0xe5b0e072c730fc7d,
+                    0x41bd167a72a13fd8,
+                    0x001bb6c2d4de35bd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d3a78430dcba6b3,
+                    0x0a2f50ed5cf4f638,
+                    0x6a2f1582848f377c,
+                    0x61a4c61b2151f262,
+                    0x56ee32fded766333,
+                    0xbf9d08479aadde5d,
+                    0x9bafa7df2628b963,
+                    0xdddd0bdbfc047650,
+                    0x7fcd7e7c0feefbe9,
+                    0x89eaec005b3b89dc,
+                    0x8e97b02ee857312d,
+                    0x00c1d9e926a6c0ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2623c01bb9221195,
+                    0x41577e2b8febce05,
+                    0x5673ee9a4015fc0d,
+                    0x388434ef98613ad7,
+                    0x95fa7fad3a1a8148,
+                    0x574650b2264262c3,
+                    0x4775d98f0588a678,
+                    0x2612a86ed5d572a4,
+                    0xbacbe75f1c9881ec,
+                    0xcc08ab25a1c3b1ce,
+                    0x97a36959f505a251,
+                    0x01068f1242bce511,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcebaf3ca523eab42,
+                    0xe2c652cf2975e477,
+                    0x629fb2ad2db4c698,
+                    0x14d657bb92ae005c,
+                    0xcc0c785900e22da3,
+                    0x7fbf07a2c5089676,
+                    0x7ad723bfe73f2fa5,
+                    0xad2dd2d397c285aa,
+                    0x42f1b575b2fc5063,
+                    0x237c036dd42322ab,
+                    0x8ae57cbe5550ad63,
+                    0x0051662449f13516,
+                ])),
+                Felt::new(BigInteger768([
+                    0x43b38c117f61a74e,
+                    0x883a6622e5fc428f,
+                    0xdda1f1eb6d8bc5c2,
+                    0xd82391ad2e83603f,
+                    0xf25a2d011cb94fac,
+                    0xbc2920bd2ad3a382,
+                    0xcb60a0cdc464e674,
+                    0x6d6ffd1c622ff595,
+                    0xbf48988505a93029,
+                    0xb1844f3cfe1c9944,
+                    0xd3cea5821928568e,
+                    0x00bbd5ef07cfb098,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
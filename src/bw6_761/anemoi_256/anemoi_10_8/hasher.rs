@@ -0,0 +1,4400 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U192;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U8>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger768([
+                0x41cf8a4f9e0b819c,
+                0xd6f6d55ce95427c2,
+                0x44b232a8ee985dbd,
+                0x8672f17b7106b278,
+                0xd530e46c0d835823,
+                0x30300a2be46679ff,
+                0xeb6bc90cd4a669ac,
+                0xb85d1ebf674c7d30,
+                0x67d981fcfab4d6eb,
+                0x4f85e103f1b2c29c,
+                0xb51d6759a4cf3fc1,
+                0x0074902d4a593e13,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x1a8ae4cef154a780,
+                    0xa303a04d1746cd98,
+                    0x8a3749d3a673c88f,
+                    0x3caf68b0a022a3d8,
+                    0x481f45c88dbb189b,
+                    0x7fbfa9e2b6de2cc4,
+                    0xd02fe98aeb8868e5,
+                    0x3db25637434cebf6,
+                    0x0a1a8f4231dac43f,
+                    0x43a98fb23444f851,
+                    0xb5bc24cd637a7a04,
+                    0x0092e1ae755b3ba4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc38f58467a51a54,
+                    0x5783b555157d0ab6,
+                    0xd8a69a842c1ef49b,
+                    0xbc7d8e3d6e1b6a0e,
+                    0x8cb3e408103c4d65,
+                    0x2b6cbe5c10b447b2,
+                    0x9eeeb6a2c8d5d60c,
+                    0x9a5dfdfaedc90529,
+                    0xb2d61788dda184dc,
+                    0x583706b7cd6ad605,
+                    0xb41700863c90be65,
+                    0x002ae44e852aa1e9,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xab34bf946ba15076,
+                    0x989e1f6584bd9056,
+                    0x314851a4ef196484,
+                    0xa416c61666dc7129,
+                    0xcb450f9b3fffb039,
+                    0x4922dd2ce7b3dd31,
+                    0xda76c294e9309629,
+                    0x29e67df61ce483f6,
+                    0x8d411b482ef48f47,
+                    0x6977206bae8874a2,
+                    0xfc0c8dd00faf0fd3,
+                    0x0104c0129f5826db,
+                ])),
+                Felt::new(BigInteger768([
+                    0xecce32c5ca50dba1,
+                    0x74288a0b44a800f2,
+                    0x2a79cd14a3fcc87f,
+                    0x6ca718061c9edcf3,
+                    0xb7119867ae2d32dc,
+                    0xaca121d8a49f29c9,
+                    0x30c6e676055791b2,
+                    0xf83b2d25b86cd4c4,
+                    0x0811b1deca66c7da,
+                    0x57030a2248d7b57c,
+                    0x6c134700691a5fe8,
+                    0x00203293b1a4b3bf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c4c9380d0237449,
+                    0x1fa0b7439ebbc6b5,
+                    0x5bb4ec06cbda7fbf,
+                    0x5b2327ec6ce23ad0,
+                    0x5da54e22689e4fb4,
+                    0x9caa72bfa3b9daf2,
+                    0x75c019cbbc3a7fba,
+                    0xd602ee735acaf204,
+                    0x6c8c76dba83d3b99,
+                    0xb603e9e401fbb337,
+                    0xf30203b01bfc398a,
+                    0x005306b3231d1300,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x8d229528096b7de2,
+                    0xab23661fcb485b0e,
+                    0x26ead56a0379b525,
+                    0x6066eff695ebdad8,
+                    0xf8af45fa5e76a16a,
+                    0xce9c856513c449d7,
+                    0xad2b6ffb2cd272ab,
+                    0xda8a3c099ffc8424,
+                    0x02ed6c5d64d0daa2,
+                    0x7b0e8eba312c4dd9,
+                    0x622c3375fd094801,
+                    0x00fb5beb5fc39c75,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc41f7bd3df1a2db3,
+                    0x79ef16a9033f595f,
+                    0xcce505b707f521e8,
+                    0xc16f03a0cbc902d5,
+                    0xcf139aabb3cb2f83,
+                    0x73a45f0bf375e542,
+                    0xfba8283408ec6b6d,
+                    0x53b8954e62797a88,
+                    0x880923ccb30ea228,
+                    0x978ba5ee7fe62ea1,
+                    0x37647ca4bcbd104a,
+                    0x009e898d7d1d3afa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71a2bf176c6608d7,
+                    0x44d8664b380d8526,
+                    0x589f0efd32ea9148,
+                    0xf47aa3a09938d6fc,
+                    0xcea6b8cca0adb099,
+                    0x7f9b8845fdf35e6a,
+                    0x2cee6be16a01cf86,
+                    0x81004641c52ef807,
+                    0x2b9b05907ffac2f4,
+                    0x4d6ee58c52606e75,
+                    0xdf22c4584254228f,
+                    0x006bcea7d56dadec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x47ced49397ad837b,
+                    0x7bb73113bec0aec4,
+                    0xd62583b61aeae941,
+                    0x9dbedef38e204608,
+                    0x190361fe49f55329,
+                    0x6d8c139ed3771434,
+                    0xaa667e55ea2e035a,
+                    0x8bf53f5549ab0e04,
+                    0x3c1cb9fb66c690b6,
+                    0xf520cb1acdb029ef,
+                    0x3d29daa25d62eeaa,
+                    0x002248cf68250dbe,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x21ecf42d1cb80769,
+                    0x28b52cf2a93be71c,
+                    0x05d9e724abd02102,
+                    0x9ef0311eab52fc98,
+                    0x89692645de369a46,
+                    0xbbadeab74f283cf8,
+                    0x13a4b1c0da61e22a,
+                    0x98ff8a9e43d6e5ae,
+                    0x7d8e9097bd3d4212,
+                    0xc480d66e1968d4c6,
+                    0x666cbcc7c15cdd67,
+                    0x00c545cc5f93f034,
+                ])),
+                Felt::new(BigInteger768([
+                    0x66b23ffee67b3a95,
+                    0xfe22bfc9f47590c2,
+                    0x674233157870a72a,
+                    0x0737ec48a70c5766,
+                    0x9d64e0d5c43f3d94,
+                    0x8f2b48bcf4527e64,
+                    0x0803c811eb231a13,
+                    0xfea9451f8da65965,
+                    0xfb5849034b4ea3db,
+                    0xd4f5a249754da915,
+                    0xe8b3aec48ae85ecf,
+                    0x0114b5b9f99dfc50,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc514326f6ed9ecc0,
+                    0x488f487644e365ad,
+                    0xf34a06d2a8057131,
+                    0xd7edc1dbdd5eabde,
+                    0x870689ef4148533b,
+                    0x107cc81b8a952797,
+                    0x294b934c95f4c86f,
+                    0x80ee9fdb795a5048,
+                    0xa61a9fd28d268109,
+                    0x5af82597985160cb,
+                    0xe2f0f3ee064ff45b,
+                    0x00fb08d86f312c25,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd464c60aaad7e019,
+                    0x3d9a14e71ea4883b,
+                    0x561f81ecf98fc288,
+                    0x27e8834e782fdd9f,
+                    0x879782caa9411b8a,
+                    0xf91835264e88ff5a,
+                    0x2804513ecb3e586b,
+                    0x31d29600208a1e59,
+                    0xfcb1aad8b58bce0c,
+                    0x946fa60545a60105,
+                    0x0c0b92c2fee6c003,
+                    0x004a0f81fbefdf11,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a1d5ff1bd1f55d5,
+                    0x5d7d164c012bc394,
+                    0xbcd10ba92cd6efc5,
+                    0x7f098762a570304d,
+                    0x220b158ebef1f3bb,
+                    0x30b8747174c71295,
+                    0x8a3399c68ed6e076,
+                    0x7f3ca44588363aa9,
+                    0x0d499780ceea0ac9,
+                    0x8e684b7a752dddc5,
+                    0x9d27ecd6fb2cb0d5,
+                    0x0088775063f105f0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb0949e4a36c06121,
+                    0x2c3740e633127140,
+                    0x5832eaaf9f55a598,
+                    0x79f0d1aaa8e90998,
+                    0xf604e5290d421ba3,
+                    0x04b858de193e4bfd,
+                    0x469ac65ee9b182fb,
+                    0x3f09156aafd4c0f5,
+                    0x151c0a27236f6a72,
+                    0x19909ac9d5dae0e2,
+                    0xd353a44ac8a9c940,
+                    0x0088da9105caca31,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb3077af29999316,
+                    0x2cf38ef93e73249d,
+                    0x2089893e8976e5ab,
+                    0xf9bf37533bb2f3c0,
+                    0x1e08993e466a9bcc,
+                    0xdd53544fcbcf7d48,
+                    0xc0b2dd156346a0bf,
+                    0x57edb2f7d152d255,
+                    0x1d8cae6401395e60,
+                    0x932d384f8bbd98b1,
+                    0xf80018709ba5b9fe,
+                    0x003d853010ec5292,
+                ])),
+                Felt::new(BigInteger768([
+                    0x558945d0ea1bbfda,
+                    0xc769da8716169149,
+                    0x1979783592e59240,
+                    0x94ee60c9ea5fd57a,
+                    0xc00d266fa37c6a24,
+                    0xb38d5b2ae03857fe,
+                    0xa2cf0a721e1fe2e9,
+                    0xb5d1df9543170675,
+                    0x27fc8606b5592d6c,
+                    0xc4832e47aaf3cb82,
+                    0xbf310e9d4df80444,
+                    0x0058d0d86b4a4000,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe31a0227b2f1f8b4,
+                    0xf54650a70729f0be,
+                    0x6d4033d3909a606e,
+                    0xba1c005ea33fc036,
+                    0xdf9054b1e65daaab,
+                    0x84fa641d4065c682,
+                    0x5308d5d11ad291b1,
+                    0xc0feb93db068c599,
+                    0x2bf2c7c8a966f507,
+                    0xbd50b946c3ee7528,
+                    0x5ad755539088cf13,
+                    0x00767b4b55bcc88c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x196361768c6d854a,
+                    0x988ce83b5082669f,
+                    0xd46db620f46fc988,
+                    0x9aefdf96ced5e82c,
+                    0xa336e1b0d8a0315e,
+                    0x04e9e8baa090ffa3,
+                    0x64145a52f324a9ff,
+                    0x75a7c0195b5fae95,
+                    0x8ca28bc486acd6c1,
+                    0x92855f252cfbaed9,
+                    0xc5bad9b6da3b2ece,
+                    0x008cb111aa2727fd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x69ec314f4d8c5336,
+                    0x8d6a3b97ee95e4fc,
+                    0x180dd3dbf8f11f2c,
+                    0x8de7aaec2c70a9d9,
+                    0x71c7b8331c20fa04,
+                    0xd19d2416e5e41348,
+                    0x8a983739049b8e8d,
+                    0x44cdecfb8c79a836,
+                    0xd0197df45b47372d,
+                    0xa0310ceade70ad26,
+                    0x8c89699ddf7ba9f7,
+                    0x0065a736bf9e093f,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0x627e28641fd045ef,
+                    0x3978eba607174646,
+                    0x630aad0cdf0bd584,
+                    0x57c5ff22fa0af2b9,
+                    0xcae3dbd7e6584448,
+                    0x2c4c811b892f28e3,
+                    0x74fa74e2c5aeeb99,
+                    0xcb69b147496099f9,
+                    0x7abce0ad460aadf5,
+                    0xbc4163acd3bece48,
+                    0xcd239e4870a03661,
+                    0x0117c8e148306a34,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58d242d9fe63944a,
+                    0x81f2698574ada178,
+                    0xbf4c7f3092600d19,
+                    0x3d910600f6e6b52b,
+                    0xbc16ae49aac27eea,
+                    0xb3499f3b9613b628,
+                    0xc3f64243a736113c,
+                    0x2ed79a7fad06db3b,
+                    0x208851ee142980be,
+                    0x820993c9b4f0d92a,
+                    0x2935bceb4823267f,
+                    0x007870eeef1b9981,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x70d89ed117269892,
+                    0x725a4e184921e836,
+                    0x8ba7975b8c239ad3,
+                    0x92c537ec693fbf8b,
+                    0x62a2aab123863b90,
+                    0x6553d61bc04a770f,
+                    0x9c5c1c842834be1b,
+                    0x514ee75b2f447524,
+                    0xeefe584d315f6a4c,
+                    0x9548183c54d35830,
+                    0x7428b8d3e8914752,
+                    0x0115235f38d8e816,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cab00cc2e537954,
+                    0xca5a322490124697,
+                    0x2a409b91e1151e8c,
+                    0x45610260fa9e0713,
+                    0x60b0de5965805804,
+                    0x160702a5865146df,
+                    0x10e071f69db90922,
+                    0x13a05dee85246c88,
+                    0xe2897c0a42ce8b41,
+                    0x5b5ada0d70d6a92c,
+                    0x22850d68baff0a69,
+                    0x00b3fe8521317073,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8df486bdc9bbf7e0,
+                    0x7ac5472af0a3795b,
+                    0x7295eb81fd947ca3,
+                    0xdf5d70042ee137b4,
+                    0x0ef7a3ba232987d6,
+                    0x171d562135eae585,
+                    0x8d198dc70bf24cb7,
+                    0xfe2f6473ffc7f418,
+                    0x64c74a57662d2a47,
+                    0xef81f03e368a07e4,
+                    0xe37fafbe52d5fec8,
+                    0x005d1d6eb2cb4544,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe38caf9b43c932c5,
+                    0x0559f013be58c449,
+                    0x8b1e8cf053e04926,
+                    0xd148a28d1af650f6,
+                    0x34018c89511468d5,
+                    0xbb06a2f5b51c80cd,
+                    0xf5fe91627e725a5d,
+                    0xd8e22557495b23a4,
+                    0xd3966de9ed4f119a,
+                    0x28213b2099b79d92,
+                    0xbbcde5c88d6c412c,
+                    0x0098419104139931,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x35f2630ae81529d5,
+                    0x27c7dc45f4bc807c,
+                    0x062ad7bc664965a9,
+                    0x455a70a5e99e77c5,
+                    0x3e9502ece178ca78,
+                    0xe5a362982c99922f,
+                    0x401de65c7e47a127,
+                    0xb3a3e01561667271,
+                    0xff582dd4cc80fec2,
+                    0x208e0ef54f77cd64,
+                    0x03aa8c1ad3e0b90b,
+                    0x0097f3d1dffe4490,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1879b00d803e3a05,
+                    0x8448224f42d5a97c,
+                    0x7302b95ddd2aff71,
+                    0x1836e1b9f056221b,
+                    0xc1451ac43506e83f,
+                    0xa16086a9c3573948,
+                    0x257b83fd28eef21d,
+                    0x54c115f675891a8f,
+                    0x28e698d579f153b3,
+                    0x0afaacc4cb30786f,
+                    0xf4c8bcf65a0a1e96,
+                    0x0075259e2d530b1d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb744377ed13f80a5,
+                    0xa6ef4bdd74a4c824,
+                    0x3bb5b4fd80da6107,
+                    0x8909a7aefc021d96,
+                    0x6dec69a11c82cffc,
+                    0xd45d0a60cb1bc25d,
+                    0xd6f5cc923b63d02b,
+                    0xe460a65eb89e0a95,
+                    0x659e99433f0e3dd6,
+                    0x8f8a1c44cd2d18b2,
+                    0xc2f0570a1ad935ad,
+                    0x00a8d16217d87e3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0ef74faf9ccb015a,
+                    0x75fbed1a5b0371ad,
+                    0xf9f273151c5fff7b,
+                    0xfdbcd85e67c8b1ad,
+                    0xc8addd1eeeecd619,
+                    0x8f48fc430d7587ec,
+                    0x71083b8575c28a29,
+                    0xcc0800b71784cdcd,
+                    0x8dce6810949f73f9,
+                    0xeefe09ffd2c48715,
+                    0x76e571d661855cd2,
+                    0x0074b87427ac683d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa5e475263eea70cf,
+                    0x1446a8674bffa40d,
+                    0x83ba4760b5800b87,
+                    0x8b5d0f3bd84dc95f,
+                    0x913d1d1abfd0df77,
+                    0xd5c1735012d580e8,
+                    0x9d3a616375a4885f,
+                    0xdf0a4bbdd898020d,
+                    0x684d3d1fd986bdd8,
+                    0xde777d0deb303f33,
+                    0x566c2e96f6e69c30,
+                    0x001ee7d38001c91c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf54ab5506b8ab4aa,
+                    0xd970842aeb308296,
+                    0x2c9b2f6a9da0b335,
+                    0xf7c71d5f15e8e115,
+                    0xc8de534bfd1d75b7,
+                    0xccbfe976f1cb0c21,
+                    0x6b821b9bb477943d,
+                    0x6660826fe370d036,
+                    0xb1e715e4fc5f4edc,
+                    0xa9ff32990b4da092,
+                    0x6ab9df111de6c2c1,
+                    0x00a61838f439b4ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe270104ebc3431bd,
+                    0x94de8a63345057aa,
+                    0xc856c930b166321b,
+                    0xea899b95d43f3a44,
+                    0x13c16bcb1c2f71c1,
+                    0xcfc06b68761430f6,
+                    0xd5faad4daf473858,
+                    0xb0f1e6ef00d60774,
+                    0x966dbb04a18b98e6,
+                    0x09505cbae1292ae8,
+                    0xb2f0f6f949fa7979,
+                    0x00582c678739490a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9aae38adba7d5437,
+                    0x49e1374017f22320,
+                    0x63c9bc1ac11205d6,
+                    0x13f4003a5ef34b70,
+                    0x65815dab514a2e7f,
+                    0xae8dae85f4d5950c,
+                    0x58dc614e441a781e,
+                    0xf1e3afd81b2766e3,
+                    0xc1b62379e4b57771,
+                    0x60ce971e31550b7a,
+                    0xc7d5aaae624e1abf,
+                    0x00db607f43131086,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd84f24ce02ba37de,
+                    0xbfca7e567aff93bf,
+                    0x0cf3e9c72cd5580a,
+                    0x87ef5553ee4586f6,
+                    0xf7a028fb4a93de4d,
+                    0x4fddb9854e09746f,
+                    0x626c5a80301174b5,
+                    0xa6fc5df2acdd6227,
+                    0x0fb0b7a6741c3226,
+                    0x1120aa86cf9904ee,
+                    0x5eaf77313f083044,
+                    0x00413680c04ea452,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd3bf57fe0a29a1fe,
+                    0xdfc441032ad1c6e5,
+                    0x727c7543e552acf8,
+                    0x68b690bb697f9013,
+                    0x5743d80524253faf,
+                    0x5e4e79b54bb719d4,
+                    0x1c3daf2ae75c3922,
+                    0x3c64571b8c0570bf,
+                    0xcfb311564ceb5d4c,
+                    0xcc7f126c09518e69,
+                    0x489fecfb11eb61e1,
+                    0x007cd47591ccb74c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6d83a72bbe6d915f,
+                    0x09c31e4b8b104356,
+                    0x54de074092b00124,
+                    0xe35e7e8bcc8d70f1,
+                    0xc788f8b551975da4,
+                    0x91d6060e819db0db,
+                    0x4104d3a125b5706b,
+                    0x0fc9086d9dc59b82,
+                    0xfe78c0c9720d270f,
+                    0x09528fc9b6a5a1df,
+                    0x045d81cae39fe9e9,
+                    0x010e18827a98f57f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x529c4ac741d8f0d7,
+                    0xf84b37328ad773f6,
+                    0x8fffe5626c5a85c9,
+                    0x1c3ae9e61865b116,
+                    0xb8376ca612b004e0,
+                    0x6c0f9fcfa8df6441,
+                    0xf1ebce17d6c14485,
+                    0x5d074eddd9d5fc49,
+                    0x30eeb1bbb836996f,
+                    0x5dd425463feb213c,
+                    0x839eaa50a7cab22b,
+                    0x0121235351cb365c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1158a432ea41f42d,
+                    0x5eae63b68117a3a7,
+                    0x86b49dbf09b59f6e,
+                    0xe403ba7cdf2e6fd6,
+                    0x9939970af6d46436,
+                    0x063d7e2261b930d9,
+                    0x556808f6a26317ce,
+                    0xf7dd178eb3f55c09,
+                    0x84a585a2b22bda1a,
+                    0x0310c4e627fbaa13,
+                    0x8ae33bad388a874e,
+                    0x00d8e41520c5c019,
+                ])),
+                Felt::new(BigInteger768([
+                    0x74cd869a6c54476b,
+                    0xead806e4a22b9b4b,
+                    0x21eb155ee3c8909c,
+                    0x64a2a1081d316ee3,
+                    0x1e131256f04fa18e,
+                    0xb1e70f6d285d7bb4,
+                    0xb217be80f620d40b,
+                    0xe89e1d97da5ad003,
+                    0x6f1bc25ba9be039f,
+                    0x8760ae332bb6f465,
+                    0xf27bcf55270fdd07,
+                    0x00c1d9315dae46fe,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0x627e28641fd045ef,
+                    0x3978eba607174646,
+                    0x630aad0cdf0bd584,
+                    0x57c5ff22fa0af2b9,
+                    0xcae3dbd7e6584448,
+                    0x2c4c811b892f28e3,
+                    0x74fa74e2c5aeeb99,
+                    0xcb69b147496099f9,
+                    0x7abce0ad460aadf5,
+                    0xbc4163acd3bece48,
+                    0xcd239e4870a03661,
+                    0x0117c8e148306a34,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58d242d9fe63944a,
+                    0x81f2698574ada178,
+                    0xbf4c7f3092600d19,
+                    0x3d910600f6e6b52b,
+                    0xbc16ae49aac27eea,
+                    0xb3499f3b9613b628,
+                    0xc3f64243a736113c,
+                    0x2ed79a7fad06db3b,
+                    0x208851ee142980be,
+                    0x820993c9b4f0d92a,
+                    0x2935bceb4823267f,
+                    0x007870eeef1b9981,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x70d89ed117269892,
+                    0x725a4e184921e836,
+                    0x8ba7975b8c239ad3,
+                    0x92c537ec693fbf8b,
+                    0x62a2aab123863b90,
+                    0x6553d61bc04a770f,
+                    0x9c5c1c842834be1b,
+                    0x514ee75b2f447524,
+                    0xeefe584d315f6a4c,
+                    0x9548183c54d35830,
+                    0x7428b8d3e8914752,
+                    0x0115235f38d8e816,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cab00cc2e537954,
+                    0xca5a322490124697,
+                    0x2a409b91e1151e8c,
+                    0x45610260fa9e0713,
+                    0x60b0de5965805804,
+                    0x160702a5865146df,
+                    0x10e071f69db90922,
+                    0x13a05dee85246c88,
+                    0xe2897c0a42ce8b41,
+                    0x5b5ada0d70d6a92c,
+                    0x22850d68baff0a69,
+                    0x00b3fe8521317073,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8df486bdc9bbf7e0,
+                    0x7ac5472af0a3795b,
+                    0x7295eb81fd947ca3,
+                    0xdf5d70042ee137b4,
+                    0x0ef7a3ba232987d6,
+                    0x171d562135eae585,
+                    0x8d198dc70bf24cb7,
+                    0xfe2f6473ffc7f418,
+                    0x64c74a57662d2a47,
+                    0xef81f03e368a07e4,
+                    0xe37fafbe52d5fec8,
+                    0x005d1d6eb2cb4544,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe38caf9b43c932c5,
+                    0x0559f013be58c449,
+                    0x8b1e8cf053e04926,
+                    0xd148a28d1af650f6,
+                    0x34018c89511468d5,
+                    0xbb06a2f5b51c80cd,
+                    0xf5fe91627e725a5d,
+                    0xd8e22557495b23a4,
+                    0xd3966de9ed4f119a,
+                    0x28213b2099b79d92,
+                    0xbbcde5c88d6c412c,
+                    0x0098419104139931,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x35f2630ae81529d5,
+                    0x27c7dc45f4bc807c,
+                    0x062ad7bc664965a9,
+                    0x455a70a5e99e77c5,
+                    0x3e9502ece178ca78,
+                    0xe5a362982c99922f,
+                    0x401de65c7e47a127,
+                    0xb3a3e01561667271,
+                    0xff582dd4cc80fec2,
+                    0x208e0ef54f77cd64,
+                    0x03aa8c1ad3e0b90b,
+                    0x0097f3d1dffe4490,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1879b00d803e3a05,
+                    0x8448224f42d5a97c,
+                    0x7302b95ddd2aff71,
+                    0x1836e1b9f056221b,
+                    0xc1451ac43506e83f,
+                    0xa16086a9c3573948,
+                    0x257b83fd28eef21d,
+                    0x54c115f675891a8f,
+                    0x28e698d579f153b3,
+                    0x0afaacc4cb30786f,
+                    0xf4c8bcf65a0a1e96,
+                    0x0075259e2d530b1d,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 950];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+            bytes[380..475].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..95]);
+            bytes[475..570].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..95]);
+            bytes[570..665].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..95]);
+            bytes[665..760].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..95]);
+            bytes[760..855].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..95]);
+            bytes[855..950].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..95]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x7e658dbc34141d22,
+                    0xf908f67c452b83c0,
+                    0x8737730a0f5ecea0,
+                    0xd40303ded6e1325a,
+                    0x7660a69dd6c3a19d,
+                    0xa1edcf41c1d5753a,
+                    0xb3b14edac9fb40cb,
+                    0xe4535bbe5f9d323a,
+                    0x2b17eb63e3051504,
+                    0x32fd5cce44709c6e,
+                    0x71fe02607ee87881,
+                    0x00e1d32adc5fdef7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8db469630102f4d6,
+                    0x8938be55efc65a81,
+                    0x4cfced85152f10c9,
+                    0x047bc288a8457d4d,
+                    0xe97d20bce0b8995a,
+                    0xb0e761b9681713bc,
+                    0x249ced3cc923e902,
+                    0x591372223acec7b2,
+                    0x776f47fcc19d373f,
+                    0x8d37f32a6a3d1b03,
+                    0x1c756a755e1e0aae,
+                    0x00172b00a8744edf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0463bd8aba4108b4,
+                    0x46aca7edd05b8737,
+                    0x3790c559ab7bc083,
+                    0x800d795b17b6b095,
+                    0x8c5f9f960171bbfb,
+                    0xa617fb76c30470e8,
+                    0xe9dc45192ac6c2e5,
+                    0xe4723a9a8d104ae2,
+                    0xbf0baac09818fe37,
+                    0x815fc4fbd45faef0,
+                    0xd6301af20625aed9,
+                    0x00146f0729c10efd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9ee93042602447b7,
+                    0x88a8c2fe59b02779,
+                    0x5290920f988dcf3b,
+                    0xcdf27db009154895,
+                    0x74672e60801cc383,
+                    0x8923f7ac2c9aedd7,
+                    0xe5f78131dd329e39,
+                    0x2d7a9660dec99f99,
+                    0xb4b53a29bf6143c5,
+                    0x9ffc00c43367fd5f,
+                    0xa8a4e68e50699420,
+                    0x00df024c7baa3c0e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2d63597369b7b8b4,
+                    0xc38fadce47542b71,
+                    0xcabf6ea9c611bb3c,
+                    0x84ac2f3d0c38bb56,
+                    0xa0c8955a3355e19a,
+                    0xa7b8b43b004eb7fc,
+                    0xfbbd615600a6d234,
+                    0x4dd4472b9ab3bc8a,
+                    0x3342d2c26f65a567,
+                    0x4beb30d0e9619571,
+                    0xa66864fbfaa50cb1,
+                    0x00b3b99b9bcc7281,
+                ])),
+                Felt::new(BigInteger768([
+                    0x75190dce2efaee16,
+                    0x3ffc1c34a79f4b79,
+                    0xd63100a4106995a4,
+                    0x1ef3543c23f5b487,
+                    0x0885fa73a000be38,
+                    0x1f19897256f15be9,
+                    0xefd9f83c808ca00b,
+                    0x1f14eedb921e6b1a,
+                    0x6fa173598ae5526b,
+                    0xf772a74c096a2aad,
+                    0x2617ff25f18a8ba4,
+                    0x00940e03917b826d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x94620e57c8fa9464,
+                    0xd8c5f3f8ee152647,
+                    0xcb846529e0132d48,
+                    0xe089d768d11920d7,
+                    0x86d3d3e47efff897,
+                    0x6ed6653698d8de35,
+                    0x14cf7cf090a7a2ed,
+                    0xfe4af041b6415999,
+                    0x31b7583e3e6a75ac,
+                    0x9a5cab2d55c98ed1,
+                    0x1a401a9365e5b416,
+                    0x00be15ce94f58f73,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5e7040e92f6edcad,
+                    0x5f9ee17990f2d12d,
+                    0xcf1fa138cfd1915a,
+                    0x1911768b00dd2bb2,
+                    0xc87da51a7bc92c25,
+                    0x60165513696bfed4,
+                    0x505b1c6361026cea,
+                    0xc75ccc813fc6dd1c,
+                    0x2aee2b3cd7a2067d,
+                    0x1aef661ef1647679,
+                    0xcf7c0c0b7022ee19,
+                    0x000c32d3ac44a911,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe099414373efaaff,
+                    0x19a9b82967cc358f,
+                    0x48a5926e64130245,
+                    0xa63c20f473b6d949,
+                    0xcca3c7e58c27d9bd,
+                    0xd16a6d5cf650a2f6,
+                    0xf4468be9fa7d5308,
+                    0x38ff0c97cf40208d,
+                    0x6c1fa25d5eff00dc,
+                    0x8bca23ca8f0d8272,
+                    0xf8ca1900c9fe1592,
+                    0x010fce86baf10b6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xef37baaf2e955658,
+                    0x1ff53f1249b43507,
+                    0x1934c5a152e8afac,
+                    0xd95d72d44e59b754,
+                    0xe25bfc1dc63b5408,
+                    0xa2cf67182220f80e,
+                    0xb0e8e3a6a4add731,
+                    0x0b0c71510b9762ff,
+                    0x4ee05f0db3e21738,
+                    0x2cce0c40a5dfabf1,
+                    0x149c9c2f2334c6cc,
+                    0x010a28695e0826f3,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x44939c3874bbc933,
+                    0xec7a5293f2cc283b,
+                    0x3362f9dc51cb45b8,
+                    0x75e7d079341ad6c2,
+                    0x0910e3353c6133cc,
+                    0xeb473de6dcacb347,
+                    0xa06cdafe2305c84f,
+                    0xe87f59600751c51d,
+                    0xc033714ecb807ad5,
+                    0x77884839c94557a7,
+                    0x337527a4ffe32363,
+                    0x00b349680ce636c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0038935a2205aa48,
+                    0xbeffc08763aba364,
+                    0x86e02b33aca3ca40,
+                    0x32fb611eb175745b,
+                    0x8ff8735e48922419,
+                    0x1419052a7eb81492,
+                    0xfde7563968b8c8f8,
+                    0xd4c047246c661643,
+                    0xd2d4126e1e89fbf6,
+                    0x78f19975a5fa1aa3,
+                    0xca24f160e199f470,
+                    0x003047988db1323c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa075357983d21d5d,
+                    0xe72bcf4f399fc869,
+                    0xff58838428a9155e,
+                    0x1327ddf65f715c9a,
+                    0xbb987f103a0ca928,
+                    0x3878f858113d1ad3,
+                    0x9af77e223d224609,
+                    0xee0738e967256cef,
+                    0xd8ac5fec5c44b926,
+                    0x565dcb3ba9ddaf70,
+                    0x7392912c665b279a,
+                    0x005fa6abdb2430b6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b8f859a7e0e5a5c,
+                    0xe7717f0d2b8f1b9d,
+                    0xfc0d98daea538506,
+                    0xab5c207d68078d68,
+                    0x16e638a6d9bab206,
+                    0x8ae522cc735a8954,
+                    0x70c01459e65307bb,
+                    0x0c008b8b77ab3e86,
+                    0xb630bea86aeb1c60,
+                    0x946823fa8e6a18de,
+                    0x8ec0ba65ed5feb77,
+                    0x010010169c11d51d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x608a6f8f5319b666,
+                    0xa51958744dd63062,
+                    0xe04a94ab33fe36c5,
+                    0x3c637feb21db76a3,
+                    0xbb780667095b3e48,
+                    0x10f47f914f220fd5,
+                    0xc10d213b3954cd12,
+                    0xce6cb166c9e3c772,
+                    0x07bf9382e725f68a,
+                    0xf4bf398b5ebc7f28,
+                    0x3bbf82bde93dde3a,
+                    0x00d4fc7013708890,
+                ])),
+                Felt::new(BigInteger768([
+                    0xca9d0f371ec45910,
+                    0xc83cea0dd115ee6d,
+                    0xbab764f325a5f12f,
+                    0x124b7e63c04e739e,
+                    0xbe0b585ef54bc143,
+                    0xe012117736f9373e,
+                    0xa27567a6d7857430,
+                    0xfc54fd6126d64c6b,
+                    0x6768d9db50829c99,
+                    0xc443cde15eb6f485,
+                    0xf833087573cd0d39,
+                    0x00dc8c950e2669e2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9598520d961eee45,
+                    0x781a7b79a2f94089,
+                    0x0eb8ad60bb297dcc,
+                    0x501fcb9d6b3196a4,
+                    0x8f7dcaa88a442b3e,
+                    0x4571d0f6b224e03b,
+                    0x96fe0fa9a161ff29,
+                    0x0f051078e3cefc84,
+                    0x88217936aa81fc0a,
+                    0xa9b49152095099ac,
+                    0x6566335069d41b91,
+                    0x012021fea9ef09fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3cafa270e64bdc7e,
+                    0x15b2ee675a8dda7a,
+                    0x030c8cf87c522efa,
+                    0x8fcf0e83d4678139,
+                    0xcf98eeb4601aaab4,
+                    0x45929eca38051817,
+                    0xf936c8c9a0731844,
+                    0x785db39da63117f0,
+                    0xe84b6293da9408d5,
+                    0x24a71b228ef55322,
+                    0x2b7883e67ef703cf,
+                    0x00ac7b91f33fec2e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x109a0e26c7587ae2,
+                    0x7745532e7c28cbdb,
+                    0x8c176df29ab6f4ed,
+                    0xf1c687ac8ab05b52,
+                    0xe89c3547bbb49452,
+                    0x199ec62d89639f48,
+                    0x1ee4588a33b2d8b6,
+                    0x1eb516b5fae1b6f7,
+                    0x1e512785f5517f2c,
+                    0xefffaeb6e071b14a,
+                    0x8271d7c2e391fc92,
+                    0x011017325ebab5ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9154afbf2924e465,
+                    0x61f08cc7a7de9f41,
+                    0x0ce9a87c8bd5031d,
+                    0x81203873fe2a6522,
+                    0x6693ca00b3bca0fc,
+                    0x80654893b93af4f2,
+                    0x0b2776dbbb3d330f,
+                    0xca5e2e11be3131e0,
+                    0xbb72bee31888cd7e,
+                    0x024f5a63ada54d2d,
+                    0xfc253168b0c0296c,
+                    0x0042162ab226a0a7,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x7f21fe7dee486c26,
+                    0xa343989fa8b1ac20,
+                    0xfbbadb05faf28ef1,
+                    0x443b0650fd04132c,
+                    0x6ed59f5627805779,
+                    0x19a04ccacc18e020,
+                    0x7d01f102faac81c6,
+                    0xca1b72711c3522f2,
+                    0x85179982a974dbc0,
+                    0x790f6d2d0949599e,
+                    0x399fd983ccb2c19d,
+                    0x0080b8438787e41d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8c4209da5df3e16c,
+                    0xeb27efe4ab9f8313,
+                    0xca5cd9b5fd6e2af0,
+                    0x8395827a5aab5daa,
+                    0x24d5282d743dce1c,
+                    0x099291df9a8e2454,
+                    0xc12a2d77efd09159,
+                    0x3ee587455275841f,
+                    0x7b6c5065d98788b7,
+                    0xdafa690f4005d880,
+                    0x1565e3d8c2be3ccb,
+                    0x00713bcff9055800,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2dd33870d1d5eada,
+                    0xaaf9c2b238b7e09e,
+                    0x5df1e63e815a72fe,
+                    0x79511ed3a0e60221,
+                    0xdd00398b39733d6c,
+                    0x487623a76505d04a,
+                    0x1427db7db8c0dbbc,
+                    0xc75613a56e21ca70,
+                    0xe12ea14d490fe51d,
+                    0xc757c0dce7cae3d7,
+                    0x1491e58eacd10416,
+                    0x0013e6e0ece7679e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda7a81afa4599856,
+                    0x264519dbc8122a7b,
+                    0x28ba2b63f18035e4,
+                    0x1feb60997575ee4e,
+                    0x4ae0bbb9d955c036,
+                    0x4f51ea2b01a980e6,
+                    0xcb83c36a7d0b0dc1,
+                    0x65e8f06942c70eaa,
+                    0xd4e99915a4234afa,
+                    0xb8f839ce4a227073,
+                    0x555d0c7a4dff86ef,
+                    0x00fcfac7ce83fb1a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4afa4a7626894ea6,
+                    0x8a1ea47b2916b47c,
+                    0xbeeaf8c487a5da39,
+                    0xdaa14f5d55e47305,
+                    0xc6c96b39a281c765,
+                    0xd73b9d6e9c781b4b,
+                    0xe5b143914d985709,
+                    0x7c3df0fd240d8996,
+                    0xc4f5fbc983d091fc,
+                    0xbd4be3315aa83848,
+                    0x4c66fc697b86da1f,
+                    0x004acb7fc66129f0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf204253958ab3090,
+                    0x51f73793d453b8eb,
+                    0xaf4b158c26c75b43,
+                    0xb2edd9374e8720c7,
+                    0x9ef6a432c62ce954,
+                    0x459fde864ebf2358,
+                    0x7c3a5d832b749057,
+                    0xba5e1ab1419c74cb,
+                    0xeeb715873b36c5d6,
+                    0xd54a7b9067d23029,
+                    0x1fe743a58ab42fa2,
+                    0x00c1b2a7d7767f3c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2aa01a3aff8160a,
+                    0x1853629aa1ecf240,
+                    0xa0d682e13e6b4216,
+                    0xff9a9e7d195513ea,
+                    0x179e5dae5d56a5da,
+                    0x61b0a29733b815cb,
+                    0x2f2194712d6a4571,
+                    0xdfc38a14502bd59f,
+                    0x2439d20fccf650ae,
+                    0xe9efc6815bb7952c,
+                    0x2fec44f6e02ebebc,
+                    0x001f5cf204f4ccb7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfdded2ef8d1cb9c2,
+                    0x44af2113f70d6beb,
+                    0xc965f649ed968169,
+                    0x8b968670d1b5400f,
+                    0x2b479b01f2e3e31c,
+                    0x3891d91048379d27,
+                    0x6ac7c0ce046f3a2a,
+                    0xa6dd179be125fd70,
+                    0xdf81b59b109fd0c2,
+                    0xab89e09902077cf1,
+                    0xba444c471666d2fa,
+                    0x01089218cf6f9b1b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3d0ab8dbbc726a0f,
+                    0x8ba4ae44f33cf2d3,
+                    0x280f6d8fbd0822da,
+                    0x0fe63cd7dedf484c,
+                    0x6a8905bfd702ee0c,
+                    0x239f562a334a5818,
+                    0x6e14fd5bfd27fcda,
+                    0xa16ea6b77d8babdb,
+                    0xfb872cfda48b6fa6,
+                    0x3b1dadf902328cf6,
+                    0x7ad8d6dc5869661b,
+                    0x00168acd0666dada,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf126e15c44c6fce,
+                    0xb04b939e4229012f,
+                    0xf00455f1349e6b31,
+                    0xe479b6dbb512c65f,
+                    0xc2a4c555132ccbd7,
+                    0x14b97d2e7b87305f,
+                    0x2bde906b13b0b640,
+                    0x854d0a80daafaa67,
+                    0xbd312f8368c8871d,
+                    0xf6f42954e076683e,
+                    0xbc0fbe97cb4bca99,
+                    0x011ef218ed55c9d2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x0295d20b60935b68,
+                    0x6a0b6e866ec13530,
+                    0x1fbc9037fc241487,
+                    0x40eb8e2648e3ea79,
+                    0xec41d4b31c32cb03,
+                    0xb3852164807d3966,
+                    0x5e57a33915108d71,
+                    0x17a6f4abff746796,
+                    0x7e5263d3b6f58ac7,
+                    0x752e1d50b8171fc6,
+                    0x9232a45787138082,
+                    0x00935e5aad8b36b9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19d5a88ea652b034,
+                    0x3d6df54304afc112,
+                    0x1c54340ac7e17b07,
+                    0x5b0779214c2240bf,
+                    0xf514e499709cf2ad,
+                    0x371ac81259f82380,
+                    0x79dfeb6fe332f645,
+                    0x61afec5722850185,
+                    0x0845f8f3d62bdda7,
+                    0x991b3eb41ff46066,
+                    0x325f3892583caffa,
+                    0x00d2e8475d24dbbd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb42aa398069bbe41,
+                    0xfd6f19bd975b965b,
+                    0xe5eaa3091d7490be,
+                    0xd0fef07dbdc295bf,
+                    0x0a79ea8d076e7157,
+                    0x75e95fb378fb42f6,
+                    0xc0cb590d4dac50ae,
+                    0x64971e8cb79a3e36,
+                    0xc50e06535a8d70cb,
+                    0xa02a698db85e9f50,
+                    0xe38c4b6caf9b48c8,
+                    0x001b2068732c8d74,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1975926732529556,
+                    0x95b57e724f5c7f79,
+                    0x26813a3f6310bd91,
+                    0xb018270dbce857bb,
+                    0x0f04d649ae723200,
+                    0x1767cf1ea043550c,
+                    0x6a7fad608551bcda,
+                    0x2240c43cd30446e3,
+                    0x01164dc2cb1df78c,
+                    0x54597e51fc8a5148,
+                    0x3dbd96e2272478ca,
+                    0x00c47efff48126f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ed5cb6346703bd4,
+                    0x7c0cbce63d226348,
+                    0x5b7a085e7bf66c7e,
+                    0xe662b24b8b1d4463,
+                    0x643b98dd37e97b37,
+                    0x5640da3a974b0fd4,
+                    0x65c72003ac01dea1,
+                    0xd5d9c82c809e6458,
+                    0xc0c38344aba53819,
+                    0xdc9b95651e0f45a6,
+                    0x8d94bf35fafb4c4e,
+                    0x0061d5a30b462bd6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58c3bd1a09ca0124,
+                    0x13a445e462e9cacb,
+                    0xc983f44fc5544733,
+                    0xafcc284c34b23798,
+                    0xa2de532fb850e6de,
+                    0x768334bd1eb6f2bc,
+                    0xafe06938e3b67da6,
+                    0x28d210edeae8528e,
+                    0x6fc36073d564b933,
+                    0xfafebdb10fe0a96a,
+                    0x45fc6948c1cf3d98,
+                    0x007e9b94b7fc73f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x98fb657be3645dd3,
+                    0xb2db49efb429c10f,
+                    0x09da57b38f48dead,
+                    0x273927a72f5b8851,
+                    0x726e59a45175e1c3,
+                    0xc79858d09ca5dd32,
+                    0xf6b082e5279787fb,
+                    0xf5ea42d1980dc737,
+                    0x69cc55ea812520bf,
+                    0x65862cec97839dd4,
+                    0x499f0ee2756af3b0,
+                    0x00a790025dd33b9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x380279b5056107f6,
+                    0x59cf3764919a446d,
+                    0x3f54eb027bf12f7a,
+                    0xa809a01342c92172,
+                    0xf95b40b38eb45c95,
+                    0x622031f261f2014a,
+                    0x5768f830372ffdec,
+                    0x5754959ac849738f,
+                    0xf7dde033c79aaefa,
+                    0x1916f391e1c1d898,
+                    0xce66a24264dcddda,
+                    0x00c3e8b226372569,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b3af9261c6a7c8c,
+                    0x0396e52ad0d7c1e5,
+                    0xa83b3463e46bf887,
+                    0xc40aa1c892ff514e,
+                    0x1e2023ecfb74c378,
+                    0xbdb8d0c309591e37,
+                    0xcd3063a02e2c9608,
+                    0xf1866111d7dee3a6,
+                    0xaa71cc1717d01e45,
+                    0x51f6ba72311929ac,
+                    0x24f033a60f6d526b,
+                    0x00b8cc12f6f2b28a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb18f7c9e3f17115d,
+                    0x86073c8f272e3dce,
+                    0xec20dad1feb52f2e,
+                    0xdd19ca46be2c7a56,
+                    0xed0cfb6a7001da3a,
+                    0x0449c7891d2a32d4,
+                    0x99f9fe65b5e821e6,
+                    0xd17b22b9cd12c5c6,
+                    0x36c01831fde88800,
+                    0xb674eee40978ca35,
+                    0xfc91a5d2da374960,
+                    0x00e0da535627edff,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xe515193e9d1d9482,
+                    0xa212f3410b69aa77,
+                    0xbd0da5d8c2b0d19d,
+                    0x312ff067062903ec,
+                    0x1f8381f4b2c6a023,
+                    0x7ffd2c4f0f13e10e,
+                    0x6eefde9a6bd8dee6,
+                    0x710d6cda051b1ac1,
+                    0xa21d54dd4b4c8533,
+                    0xdbea8deec314bdb3,
+                    0xe3cf1480c3039236,
+                    0x00321e1cdf8c77ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad0c82a8e00d35b8,
+                    0xf04d64f52177ebf1,
+                    0x125a2b41cef538c7,
+                    0x1b2673c4587de8a3,
+                    0x4fa28a8ae0684207,
+                    0x343988cfd155d8da,
+                    0x431484025cf27acd,
+                    0x90567065705a2872,
+                    0x3ee94f41c975f72b,
+                    0x39e0bfa25bcbb023,
+                    0xca0620aee416b36e,
+                    0x0054f7108cfeabf9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd35ec5516bb5507,
+                    0x4f83cf3387089e3e,
+                    0x8715713186b41c32,
+                    0xd2241e1c8c8837a3,
+                    0x26b705cec8064c38,
+                    0xb518b839188b95d0,
+                    0x3a0c0a32d0b3cb39,
+                    0x65d226b7016f190e,
+                    0xbc0f0df7042e016b,
+                    0xd98ce9284cf63aa3,
+                    0xedac8858dfe4dcc1,
+                    0x0040c1fc30185ef9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1dfcd89e78ca72cd,
+                    0xc42a1f3b7d8d9416,
+                    0x527d8f55b4aef196,
+                    0x6e077448c17b207c,
+                    0x592f1a5ebc4a164b,
+                    0x69585bbeda0b75d1,
+                    0xa85511b6af67c808,
+                    0x0bdf26e0c2c4cdf2,
+                    0x2d7d8be151160b24,
+                    0x073ac39e545075a8,
+                    0x5b3f0e9b5cb033b5,
+                    0x0038200e02d83fb1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcbbafa286eb0595e,
+                    0xc1acd5e6e4187009,
+                    0xdcd0ad7fe69f3200,
+                    0x1106030997b4a4a8,
+                    0xa4fc9d23cf838901,
+                    0x2566ab371fc8c47c,
+                    0x7183b4539df13661,
+                    0x9ad140b60d0b60c4,
+                    0xfd3f6de757b977f0,
+                    0xcddc8415c915201d,
+                    0xace0a9f02dd42777,
+                    0x01201100835dda7b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdd4f2e9068c26017,
+                    0xda72fc505a7d8086,
+                    0x6e3929bae51ba795,
+                    0x02778152314bc328,
+                    0x84308f7b7fa53f13,
+                    0xdda7627a68ac90f8,
+                    0x43f0ca83bc80ece9,
+                    0xe8f9e8e905e2ec3c,
+                    0xfcc06d0f6ceb8d82,
+                    0x1bc884ca532b9df2,
+                    0xa28487ebc4cec7d4,
+                    0x00f00ef9724eac75,
+                ])),
+                Felt::new(BigInteger768([
+                    0x184d61612f734044,
+                    0x4f9ff1ed7fa5c30c,
+                    0x0b953222c00f188c,
+                    0x332e5474def5ab17,
+                    0xcafe8f4a485bd725,
+                    0x8abbe6c22e11b7d1,
+                    0x2ebdd1bef59fac5c,
+                    0x3351f0da9a8667bc,
+                    0x64a04c6b8d827c17,
+                    0x7d1bed819feb0a7e,
+                    0xb0adc073ef91b953,
+                    0x010418f4bcd411b8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd27355a2816a33c4,
+                    0x8e612602ca44379a,
+                    0xbdc8fb038bc047b1,
+                    0xa64790ef09d634c0,
+                    0xd367c05c7e840b3a,
+                    0x0e74f8b9b40d896e,
+                    0xadd8615371d8efe2,
+                    0x2de673aed190ff2b,
+                    0xba9faa81b0822d1e,
+                    0x26224d498269864c,
+                    0x473efed5307ddad8,
+                    0x00e34763fb17966d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x42ede5bf19fd430f,
+                    0xbcab4f3db2c467d3,
+                    0xc66d0ef4c5d30585,
+                    0x263cd3bea18abdde,
+                    0x15f3344043e50ef0,
+                    0xaf457e52a9d5e039,
+                    0xa1ef5b5f4d499c5b,
+                    0x447268c1306fb0b0,
+                    0xbb8effc34eb35aec,
+                    0x5b474f9f024e92c9,
+                    0x43d53d7a8d8a8d82,
+                    0x00d67112c74f5c83,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2e8742b2f168cc3,
+                    0x6b3b471ca61f1d5a,
+                    0xe057abfb18f27a45,
+                    0xba79dff9b150438f,
+                    0x541033c3188ed830,
+                    0x98b6981eed0f2f10,
+                    0xc2d4069202ecdc63,
+                    0xb8474d6f11f0bed0,
+                    0x510910da8d81eff1,
+                    0x0754970ecb3b2de4,
+                    0x3a5bd0dfebabdccf,
+                    0x003c422f42fb2c2e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb6ecabd14b71f9a9,
+                    0x3a1d76fc94d3faf8,
+                    0x41dfbe8b64fa1fae,
+                    0xedad8d42b9b0b8a5,
+                    0xd27f2116310ad209,
+                    0xc6a41216c044ad9c,
+                    0xf211ebc8b84c4695,
+                    0xfd53ecde15412c1a,
+                    0x326c3840e9cac1b1,
+                    0x6aa9b2a052d0c098,
+                    0xc0014b74f19daa9d,
+                    0x000d3debf1963f53,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaff8fb85980f3d29,
+                    0xb816117c13b76bf6,
+                    0x56b30f073ffc9e5d,
+                    0xec27ba011b397ba0,
+                    0x93a8088239951399,
+                    0x8c7bdb1f3f4d9ce6,
+                    0x1cc7feefd397d443,
+                    0x7d6bbb93d0957c7f,
+                    0x828f64696ed9a838,
+                    0x60f5ae4ef44b3c01,
+                    0xe571b957c1103103,
+                    0x008e80c718c692ac,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d83fec476b0df14,
+                    0x53567e759144fb1d,
+                    0x69a611560f9b51a7,
+                    0x90865005e880bc8c,
+                    0xc1817f9048f291fc,
+                    0xecad21a83d0e06f9,
+                    0x1959aeb69aebbce6,
+                    0xed8a3b8088f1da4b,
+                    0xe50cc211ebe035a1,
+                    0x92c6910beac90c33,
+                    0x687897511aa1574b,
+                    0x007e41c8d3aadf5c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x269a8877862628db,
+                    0x6bf0260556f72d58,
+                    0x6a6ca23dda84b9d4,
+                    0x5e1bf895d0326c1a,
+                    0x95ceb9dd9662402f,
+                    0x77e10aa8c5a32a8c,
+                    0x2448a7d5d32450b8,
+                    0xe89eb7225b5d613d,
+                    0xf25ea61d3802a611,
+                    0x77934ea60c39e17a,
+                    0x92a552c177e1f148,
+                    0x00eb0a2da15f6791,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e976e6e1652db42,
+                    0x820ff23da2ebe40e,
+                    0xf3b221d7426e224a,
+                    0x269f27b548f468cf,
+                    0x991744db777b2481,
+                    0x5b32d39ca9429f76,
+                    0xbaf15d9dc0dbaa86,
+                    0xbe1425ccc3dfbcab,
+                    0x158e86a9a719a4e0,
+                    0x5bd9b87936a59eb8,
+                    0xbdb7e42c8387782b,
+                    0x003de9c5efb7ba8e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7fbedf24901e9827,
+                    0xc1561eae176a4341,
+                    0x3d1d3f52e4505915,
+                    0x619eaf3497ce9334,
+                    0x48a0f2acf57f807e,
+                    0xe252b1ab1e50ba37,
+                    0xb6b3bbb1c4ee3206,
+                    0x742a378f6b4d6ea9,
+                    0xd2fc73f5cf12bd39,
+                    0x8a9994001e4573db,
+                    0x55efbbad715ba44e,
+                    0x002674f33fce2c4b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2ec316d5725f7db,
+                    0xddfd060a5edf6f85,
+                    0xc460ad8821300ace,
+                    0xc7cdef31c0620724,
+                    0xaa44f1e09c33dfc0,
+                    0xf4e8ebbf433826a1,
+                    0xc65346beb8fc3de5,
+                    0x9e19e192015ea396,
+                    0x7484d366b59692f1,
+                    0x39e40a0a01ed0621,
+                    0xae28f44d70e5c569,
+                    0x0064c9d3105e6555,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd4e1d8f2c21274b2,
+                    0x0a13c4c0a488eb5f,
+                    0x3210f2c2c991a4b7,
+                    0xa5d979739bdc8c3f,
+                    0x71ccbeb5949a3db1,
+                    0xcdd7d460d894ab7a,
+                    0x775afdbaf0863691,
+                    0xa236ec0fcbc031a8,
+                    0x08b64449da4fce5b,
+                    0xc58c601b95a3ffd9,
+                    0x80e0e85cf22ac391,
+                    0x00e250101185ab9a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3836172103b3bb0e,
+                    0x21514c9c4d844f34,
+                    0xeed3cbb088336f51,
+                    0x4bd43c8297117f78,
+                    0x477a0dd651420a4a,
+                    0x545110a9a91e9a1c,
+                    0x2f3b23429a7832dc,
+                    0xd6a9089f933c9934,
+                    0x9e99b646d22a18d0,
+                    0x3e14c39016085795,
+                    0x079dc3acdc8afe68,
+                    0x00e8951867324dc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0be230c5aa33fe28,
+                    0x91d725ea3cdb9a1c,
+                    0x066eda3e32d2f21c,
+                    0xf681c65402b8459a,
+                    0xe129fabcbd88e090,
+                    0x5e67b8bbde557715,
+                    0xe639310883a2a171,
+                    0x7c94d635f10c0e9f,
+                    0xfd1ceb84433b6975,
+                    0x1ea22b54c88c9b17,
+                    0x4b1edd1df4e87762,
+                    0x00e289fedbbe8aad,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0x518fed1dc04a43db,
+                    0xe26da686bc63d757,
+                    0x68b899901c8e2b8e,
+                    0xbf53c4bd54fbea07,
+                    0xbbc0edf701de57ee,
+                    0x8452870d47a02aa5,
+                    0xf705c524c6d9ea04,
+                    0xe68e51b7f592a8fd,
+                    0xbfc810cda028943c,
+                    0xbbbcf0b1a9a90c9a,
+                    0x5d0c77b2ad1c9386,
+                    0x00d7220337f1ffb0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3f41a6f0d7a06f7e,
+                    0x8aeb1577e400d451,
+                    0x1c2f94047873e110,
+                    0x2a80c61a1e9d518a,
+                    0x6661c018b6b3caa3,
+                    0x868c154700a40d67,
+                    0xb78f2935ec0b3764,
+                    0x42e077ca031289b5,
+                    0xeb7b9617e8921a84,
+                    0x54d70fe596701bea,
+                    0x8afd5e7eccb96543,
+                    0x00f061550adea3c7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf4c731d2fc9865c5,
+                    0x804de90d18a6e668,
+                    0x0f79ed52cd369b60,
+                    0x9083bf5b88996035,
+                    0xd97e1ab5d2957f73,
+                    0xe652e164538012db,
+                    0x539ee13d4a3c9987,
+                    0x19b1756b84983f75,
+                    0xd3a93e1f7a52f844,
+                    0x46501b875fb6cd44,
+                    0x0313eef2fb7356a2,
+                    0x009f52c419b0fc24,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d7709105a33f615,
+                    0x9cb4e73618550bc2,
+                    0x2df2c18d94023584,
+                    0x25948de7ddd9de97,
+                    0x7e417c5f7aa6a08d,
+                    0xe9edba022c21557e,
+                    0xc8582b9b76bfe905,
+                    0x7ad5a841fae004e0,
+                    0x775fd49282e31124,
+                    0xbbbc9e0dfc44aa24,
+                    0xee70c9b607276d44,
+                    0x004a27b5b86caf0e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3bb1531f176d7929,
+                    0x52ebe421c754e822,
+                    0x515174e0b7b492a1,
+                    0xe759acb087bee29d,
+                    0x86c43a57a296188e,
+                    0x1cb82195287ac5b7,
+                    0x63f5dfd9043edecb,
+                    0xe0587bdd52cc44b8,
+                    0xc9fbd50d3d574ca5,
+                    0x674e336e52aeab45,
+                    0xd0bfaa82b2aad417,
+                    0x00bdaa692be0970c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x84a842c03a2d9631,
+                    0x3a97b50aff37b846,
+                    0xe819d53c9fd3fb17,
+                    0x26f3348a428e9a15,
+                    0x0f8751bdba2bd247,
+                    0x39881f6015a90932,
+                    0xa009c857e9ea4f92,
+                    0x5a0c44a8f8a081ee,
+                    0x4b74df241f802b39,
+                    0xea6d5f90cbbb6793,
+                    0x6b0f953c1eadcde8,
+                    0x002a849afb6a9906,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4723254b1c7e0628,
+                    0xc806c3511b7218f0,
+                    0x6eb7883f58e54846,
+                    0xed629f3c22576194,
+                    0x4fe134706631af1e,
+                    0x9cc2f39d30fbdeaa,
+                    0x9039d48d8ddf236e,
+                    0xeda4457081076e1f,
+                    0xe4e01ded50bce011,
+                    0x85d25b23c8675e00,
+                    0x959774db84e8f8e8,
+                    0x009bad78a614efc4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa398efa2cd626e8b,
+                    0xfec8828797c3cbc3,
+                    0x7089113767f97ed3,
+                    0x2ef8879f1bba4d00,
+                    0x2fbb90426fb3fea5,
+                    0x0175dec483d5f407,
+                    0x7d7a84fe10d7f642,
+                    0xfeaf31f0d31706ee,
+                    0xb368d728ccb220a1,
+                    0x75e0e3e0e2109670,
+                    0x899f7f285821ba4b,
+                    0x00929cedb4d61205,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd1324d3302eaf52b,
+                    0x3619fb35d31d7288,
+                    0x4ad863de5db609c6,
+                    0x6fdea307a474ceca,
+                    0x257a70746065be24,
+                    0x6ec149794e58fba2,
+                    0x2602636a57ee9a07,
+                    0xfd654f8149f550d1,
+                    0x8c992aaec7b8633b,
+                    0xa05917caa9f600b9,
+                    0xd0c7c34bf1a77cfe,
+                    0x0106684c122757a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x879528c46f34e679,
+                    0xbcbc9a2d506670a5,
+                    0x3cff54eebbd4c9e0,
+                    0xa288e25e851992d7,
+                    0x0bed51356f0fc25e,
+                    0x05c13d9110b5259a,
+                    0x7d1ef781e559df24,
+                    0xcae55936d9a95dbf,
+                    0xce47640e1b649cd1,
+                    0xa3c8b61ecf79ad91,
+                    0x4b781186e8ceb77b,
+                    0x0109d03d694f15cd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2c3d5ca0630dfc89,
+                    0xee9ba825dc995c9b,
+                    0x6acdc215869590de,
+                    0x6446b0fc2fca84ac,
+                    0x0853beae7d6b5275,
+                    0xd1182dd0d4f529ce,
+                    0x277abab9dec324f3,
+                    0x091a015c94494590,
+                    0x1195255b034b0588,
+                    0x184e1caac91afa62,
+                    0x7c6840498c0982bd,
+                    0x00a6f238f1919852,
+                ])),
+                Felt::new(BigInteger768([
+                    0x74b45c9310c0a029,
+                    0x43549bc2ef5617d7,
+                    0x350dae9dc6a2994b,
+                    0x6f5d8c875a70158d,
+                    0x6aa465570479fef8,
+                    0xc1aa1635bbd969bc,
+                    0x3edcc1948c771324,
+                    0x4c68e2e786cbb306,
+                    0x488962b520b177fd,
+                    0x42e8b23d3d73505d,
+                    0xae66dc049d06b6d0,
+                    0x00390cf659abe53d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x02f7d20e5fd72bb3,
+                    0x668d72ff2489d4fa,
+                    0x4533a616db842183,
+                    0x653ccf3e7b47ed8b,
+                    0x6a4192ce88d66839,
+                    0xde2e8ade64611eb3,
+                    0x8c91362313eabf80,
+                    0x76fb5f884b284a74,
+                    0xba9a1c1fd047e0cf,
+                    0x3a799847a8f3cd12,
+                    0x6c58cd499ddda43e,
+                    0x0036abe9168a2964,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0635cd15b31e8dfb,
+                    0x001f5825b57861c9,
+                    0x3846d8da7f0d776f,
+                    0xe18bc56936ab7bee,
+                    0x64cd529986e86ca3,
+                    0xe1a3394c36812d4f,
+                    0xcd7c9f14dbd93797,
+                    0x8a062e87e9e26284,
+                    0x85c0b4c4eb27e1ef,
+                    0x218c4b53840f1a47,
+                    0xc207f169956e99c3,
+                    0x00059554543ba144,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd765f43b5ab9f6a,
+                    0xcf49ed7da14b7836,
+                    0x492eefb885cd833c,
+                    0xc2676d245476e904,
+                    0x7eaf8fe9d291990e,
+                    0x4944327b2cc5ef59,
+                    0x011a38fc3f535600,
+                    0xefc0d449e0e1d478,
+                    0xfc617efcfddf4ade,
+                    0x9e00a659f40fe1f0,
+                    0x725360f8dac40812,
+                    0x00f757363c054542,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd3334b1facd620bf,
+                    0x74de8b3febd76c6b,
+                    0xc8e35908cdabe0a7,
+                    0x2064aa920344c811,
+                    0x90f1568e537073ca,
+                    0x75a6af7a451eebec,
+                    0xe56d200249697517,
+                    0x2da92b21e7998e34,
+                    0x4e45e4e8363a5c8f,
+                    0xa550b9a3af071af4,
+                    0x517e1b008306ea7e,
+                    0x00410df5efa8c5ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa50034aa230dde16,
+                    0x2194eab9d0f73745,
+                    0xe24c894938399d35,
+                    0x0c94601fb906b44a,
+                    0x1b9aea94caa9f7b1,
+                    0x141f71a4fb3814ec,
+                    0x150878056bd2f00d,
+                    0x75bbe6799fcff62d,
+                    0xc6c025b8cce62bdf,
+                    0xd262a7044ce091dd,
+                    0x03a5bb4d0acc509d,
+                    0x00fc687e6a76760d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7c85f5cf49e94d04,
+                    0x11bd9a56d64db634,
+                    0xe8073c341eec2caf,
+                    0x3c8246d6ea38a352,
+                    0x1a8fb774e6069522,
+                    0x60589cf7d3de81d5,
+                    0x16918c388dabe6b3,
+                    0x1ebb683dfdf9a5c0,
+                    0xd6bf1eceaa3b2c92,
+                    0x7bd1cab0c0055c72,
+                    0x71eef273b88ee0c0,
+                    0x011bec3fb0c9055e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2e0fbb0106cc5254,
+                    0x4d7a20e6b2990d87,
+                    0xbb9c4790fab8cb6e,
+                    0xcd5bc639bc231245,
+                    0x4db4710c427d49f8,
+                    0x2d1177f65266d24c,
+                    0x3db7bb4e883b1101,
+                    0x20cc0bf2df04bf8f,
+                    0x1fd0f7b469440165,
+                    0xe69624c6c6bd9b2f,
+                    0x3481c79a5789b2d5,
+                    0x00b3fb6e3dcd9f72,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f7e45f90e5a1c5a,
+                    0x84413ec86ed8d9c4,
+                    0xc789d63acd3ec4f1,
+                    0xc730bdf2232ba231,
+                    0x44506eaeeca5278d,
+                    0xbb9fcc1fb689d697,
+                    0x4c5981884bf44232,
+                    0x04181a292e90f773,
+                    0x8706475a9a7ad391,
+                    0xaf1ea11ac7e7b8a7,
+                    0x11b0f08965dd5d27,
+                    0x00461d785d7975ab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x28d38c7a602a9b97,
+                    0x0c5f7b9ec879dc0e,
+                    0x600f42345e595aed,
+                    0xb26c137ab3467dc2,
+                    0xcac47647bb0595b5,
+                    0x6a0a15770726361f,
+                    0xcfc7c4fe8bd451be,
+                    0x2b802987ba7cbfa4,
+                    0xe0d24a2614f09e9d,
+                    0x5b3bfb2d31f0f47e,
+                    0x9128b164d5bc366b,
+                    0x0032c7977dea4a81,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0567b8d0a53b75df,
+                    0x6523cc82c42a77ed,
+                    0x193c8e8519a66b12,
+                    0x5b6507d412cc7c91,
+                    0x31ac67fa2895b6ce,
+                    0x4c7aaa712ca0e0b1,
+                    0x73d912db8027eeb9,
+                    0x57410b5c025518ff,
+                    0xf3877805293058fa,
+                    0x195b93ba2f1b74b9,
+                    0x50d72726d9187314,
+                    0x0010b0a6c8cf3f32,
+                ])),
+                Felt::new(BigInteger768([
+                    0x069deaee66c4df70,
+                    0xab67187a43760433,
+                    0xe617f961e9f5a5d2,
+                    0x47ebcca6aa0c6f93,
+                    0x95043e17ebbf567f,
+                    0xc186e93164000d99,
+                    0xc3a501402f298877,
+                    0x6d3c4ee9e1111c56,
+                    0xe0ba0fb133dd7bd1,
+                    0x85ca934837b0e00f,
+                    0x451781f62773cf28,
+                    0x001e8deab1b58f9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5c27035b30d409a5,
+                    0xcf5274e165276779,
+                    0xcfbf216c0b0ddb8f,
+                    0x197ac0feb096a7fe,
+                    0x27f0fe2d20463049,
+                    0x823d50cad0293ca2,
+                    0x861a779280e6a6f1,
+                    0x3470b19d717daadf,
+                    0xbd3cbf4b1ed98c43,
+                    0x3154a6892f8df977,
+                    0x62b0af55b4acc6c6,
+                    0x00f56803a8f67a5f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7de477f85c86ce1e,
+                    0xa3b3aa12c8822be6,
+                    0xc81a09eb63da84bd,
+                    0xacbe716a28f111ae,
+                    0x59ad746b8acc1112,
+                    0xdced8cd7ca09d844,
+                    0xb49a630d4681b2dd,
+                    0x617ec7ad1afc0f50,
+                    0xad9ad6b51e5ffea7,
+                    0x600cb54039ac496c,
+                    0x41e511a16c359f3b,
+                    0x01087acb1db9e6f4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6df0fc271bdcd18a,
+                    0xb152a9e76c5e5cc0,
+                    0x28135826718fbf1c,
+                    0xcfbde40a12b580ae,
+                    0xc3da54ebe3002aee,
+                    0x1bb7f763665be913,
+                    0x13e46c717b1b0989,
+                    0xbc399890c4e2b165,
+                    0x96b92fc3499d20fb,
+                    0x450ff75a8f63c805,
+                    0xbf073ce599b4f45d,
+                    0x00e56d84ec7416c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd7f3d1835b2285d,
+                    0xd7145a40bec999ca,
+                    0xe6ee5f83c88e6031,
+                    0x2e4a8a298064685c,
+                    0x16f3f62cf6c0b0a6,
+                    0x7cc2ed1bba963f0d,
+                    0x03a377ecf014e34a,
+                    0xed05b6f9d451446a,
+                    0xeaac58da3eed17b6,
+                    0x03fff80f0225facc,
+                    0x6a08849818c3960b,
+                    0x00d60f1b780623e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x409ecbf03bc54321,
+                    0x52714fd6e9cb8227,
+                    0x846bf3101a44be62,
+                    0xc853781eb6afb698,
+                    0xdc7cccbe354ba4b6,
+                    0xc1ad13d650c59b11,
+                    0x27894fe353629d03,
+                    0x1bdf4829f87a16a0,
+                    0x2575700d21b3fbd5,
+                    0xa7d7fe43ca57e215,
+                    0x4a5900c43bd732a7,
+                    0x00cf39999917893d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbbdb011128081553,
+                    0x6edb58337b6cd23d,
+                    0x07a158e86cc10641,
+                    0xbade66eb02396c30,
+                    0x7dcee34d1a5eb534,
+                    0xd6c1556f0763f41c,
+                    0x37d05f847e526de7,
+                    0x41692aff451dc21e,
+                    0x0d26774362fc4fc2,
+                    0x09d5c448da4a03ca,
+                    0x4856a66d8743f1f9,
+                    0x005d3fd936beda2f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb0dad86264be4016,
+                    0xf060e3d1b4d6b9ad,
+                    0x4ae5ae40c19a1395,
+                    0xdb9a4faa040fcde3,
+                    0xd4258424d9dd4dcd,
+                    0xeddea18f452b5bad,
+                    0x598d508148653311,
+                    0x243003ef508a4790,
+                    0x6d1020077e51ce99,
+                    0x864f198237e8f8b9,
+                    0xa114dc4c3621284c,
+                    0x00c6e51067c47392,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0d40ef7e29371280,
+                    0x8372c8f1858c8ce8,
+                    0x5c9b91102cdc620c,
+                    0xc06cdb526b586e52,
+                    0xbe28f3704f23660b,
+                    0x93b7d124afc08a53,
+                    0x4095c32112593635,
+                    0x1cb141460247963b,
+                    0x0ed1e72f1ba76fb3,
+                    0x236ce9e048678ccd,
+                    0x9e71641c8233403a,
+                    0x00be3f97a3fccce2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd95851bc0fd6ed5c,
+                    0xc27bb3c7820952ab,
+                    0xd29ec65453381cd5,
+                    0x3a8f291304530511,
+                    0x75bbda497fa8c076,
+                    0x22f05b4e40a6f26d,
+                    0xb879bf2707605dff,
+                    0x0075675717315d64,
+                    0xa562ea47bd5fd28b,
+                    0xd888619c0b3080af,
+                    0x193ce1fd51a0676c,
+                    0x00d346dca722f355,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0640cee27f2f5d68,
+                    0xb32c55efa7e3502f,
+                    0x16ee884d002f994e,
+                    0x67e606da0df26ef3,
+                    0x71cd0aa050f1fa1f,
+                    0x097bdef153abc190,
+                    0x3f9f78832bdd978f,
+                    0xffb9664daab3315c,
+                    0xf0cdd9ead073a643,
+                    0xc04696e572b62ce2,
+                    0x0f13960ff5cb4022,
+                    0x00717a86230cb3e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa3c1592ed743a6e7,
+                    0x15c748b1eb7ae812,
+                    0xb62a34f899976f55,
+                    0x51b587a1585cef8a,
+                    0x795dd7740a2a2e34,
+                    0xfefcdf84ddeb7533,
+                    0x08cde5d5203aab00,
+                    0x975c03a3404522d9,
+                    0x73dfcf07d97c0cb3,
+                    0xbba8b96ea96eec4b,
+                    0x2fd8e54bce382977,
+                    0x006c89acfde9fcbb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf88911d5c6dd1076,
+                    0x5e4ecafbab6c1966,
+                    0xc2376e222bc39251,
+                    0x23560d8ce51ebedd,
+                    0x54a6c204b06cc4cd,
+                    0x78f3362526e9d0a5,
+                    0x0de764df45dd2e57,
+                    0x02ac48d572635210,
+                    0x312a99d049e30260,
+                    0xaadbac3f25c6cc13,
+                    0xb08eef2936b1c1eb,
+                    0x00c9c8fc88dfe6a2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8501b5e944f5709d,
+                    0x7dbddf8eff8606c8,
+                    0x64aacb98d779e6ce,
+                    0x2c99d981f32f2791,
+                    0x3e7a2d1612f80595,
+                    0x1d23ff209bbc0e7a,
+                    0x81b7e902a7bf6482,
+                    0x9b1470730c1b036a,
+                    0xe12d059a24bf7532,
+                    0x008ae12e90fca1f3,
+                    0xe67f09f1db448ff6,
+                    0x006a516f8769dbc8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90d7de91cc0163b8,
+                    0x88a95a3751b86da7,
+                    0xcc8b7a5575461e64,
+                    0xb4bc519e7982253b,
+                    0x299864cd18ca6ff7,
+                    0x15cadbbd2300a375,
+                    0xe4d26c4318ba3eca,
+                    0x1b8a1d827d3a5930,
+                    0xa676ea79ae85a79d,
+                    0x395bc2d3e3c757ad,
+                    0xfee7da3268e8b64a,
+                    0x009d8f418c85c8f9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf73b93ff31dfb4dc,
+                    0xc55f9ea0060433d2,
+                    0xc43695984bb546ab,
+                    0x51ff4d9dcc222228,
+                    0xd80fce14e304d65f,
+                    0xc21030b3fdda7da4,
+                    0xe399212ab3162d25,
+                    0xea8d3820f72a9ac6,
+                    0xe02bee03136ddcba,
+                    0xaebcdcbd5c3c800d,
+                    0x1a67794eec1b6cda,
+                    0x00d3fbbfa1a0fb3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1491bb1750a62ac1,
+                    0xf661bf2aac916ecf,
+                    0xcd0b7550380155ce,
+                    0x77e1793bc977efd6,
+                    0x188dab601313f0b1,
+                    0x7bd48e47f2156d6c,
+                    0x497355ca39810ca1,
+                    0xea5573a5e69ad72c,
+                    0x02695f767d79e819,
+                    0x3cea5ac65ff4bd11,
+                    0x6d6e49ede9ebd61e,
+                    0x009c2fe6b9898365,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97c9c50739eee380,
+                    0x97b351e3da5fb810,
+                    0x13b46fc9c7a5080b,
+                    0xa122267505e10521,
+                    0x5c934b5f22150364,
+                    0x63cf347670b81637,
+                    0x1603d3a56bac94d2,
+                    0xe14d6971796e7d81,
+                    0x1cef3271cef757ba,
+                    0x421de8ee77cd722c,
+                    0x1b557641400ca5ba,
+                    0x00d3beb1d976bba9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4bcc8d1d43547b6f,
+                    0x16e06c9dbb2252aa,
+                    0x520b6a3fc924fb0f,
+                    0xa9ec20e791edb0f1,
+                    0x6f4ed5761a164d1c,
+                    0xb7f9619c2f4461c6,
+                    0x66ed8cb8a9578e30,
+                    0x40b542e20d59ac7b,
+                    0x74ee206531802e59,
+                    0xf6b0b0c61eeb7633,
+                    0xa696815f628af1e8,
+                    0x0096fe1eba4027e1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b6de581f98a144f,
+                    0xd83d342cb3ccb96d,
+                    0x4aeff9645d244592,
+                    0x07bddde412adb038,
+                    0x1e92a44175e581f8,
+                    0x9b525d8df4ddc883,
+                    0x9aa60f4bf3c8bca8,
+                    0xb8791a93f1a9029a,
+                    0x35fafdb6d8295d94,
+                    0x69da5d9520b3148d,
+                    0x7fb86547e33f2b06,
+                    0x009844c5f6f0090c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe7106fc24ccc02b9,
+                    0xd90c8af3ab196332,
+                    0xe3698d51704cffd3,
+                    0x4db3f6c311eb2803,
+                    0x9d14993e8f7d703a,
+                    0x6193854c1ac0ba5c,
+                    0xc6088c01ffc4800c,
+                    0x939a833e785a8d3d,
+                    0x5e4d7c8f5844870d,
+                    0xcee914fcc6f3f43a,
+                    0x41e3594804cf8239,
+                    0x011b4d3af160b6ad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26c6cd023117a63a,
+                    0x829a3d59cd18a58b,
+                    0x6930087b54c36dcf,
+                    0x2c9c4e6d95e754f9,
+                    0x9f4760ee08d4df36,
+                    0xd408b581943e40d0,
+                    0x0076495add95ccfd,
+                    0xb35b484285a2d9bf,
+                    0x1239277ed0447482,
+                    0xc2929f575d286825,
+                    0x31fac58294d48794,
+                    0x00dbad746029c6d0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x30deeabee3142643,
+                    0x5f297a2a79c95c6f,
+                    0x2683d00c032bb7b1,
+                    0xc77e5ddeef730d8d,
+                    0xcea9a53eff34523c,
+                    0x3dd8f3308ef32b9c,
+                    0x3b7beb8f133bf02c,
+                    0xe85cf3c0ed40cce0,
+                    0x154367a19906f02f,
+                    0x4b1a27244752cdbf,
+                    0xee0a60a6addbc43f,
+                    0x009ff63e4cef3d60,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x555ffc41e64c26f4,
+                    0xed3cc161248302d4,
+                    0xabe353311f2687dd,
+                    0xf468c9e52fcc07fa,
+                    0xf3f07a6e2bd201e9,
+                    0x3a4a82251553e2cc,
+                    0x7637f07c4a7699a7,
+                    0x69a296026d246b5e,
+                    0x5a436559e6b447f2,
+                    0x9ec169daf86dccff,
+                    0xe24b345718097bda,
+                    0x010b6dea1125f800,
+                ])),
+                Felt::new(BigInteger768([
+                    0x974f7ee9d89b91ce,
+                    0x2829648fcb156de8,
+                    0x0e24f8ef989f4719,
+                    0x60bdbc284ed6745a,
+                    0xdd0e100b663b9056,
+                    0x29c2c097513c03ff,
+                    0x8064cdafc9fc6507,
+                    0x26681185dc245c00,
+                    0xe760cc9a8309065f,
+                    0x73f17b5d73823d85,
+                    0x19edb8ec5a92c8c2,
+                    0x0059b6ccd5eee9df,
+                ])),
+                Felt::new(BigInteger768([
+                    0x68054940848904d5,
+                    0xfd9314494ecfa488,
+                    0xf9549ebd2d3c53a4,
+                    0xc99956bc26496da7,
+                    0x3a2370657eda76b3,
+                    0x8c4f4ab8a9202b58,
+                    0x84a3c469064d33e8,
+                    0xd0b06278e026e51a,
+                    0x09cc82f0cac4e8e0,
+                    0xe2b0872515e1c4a9,
+                    0x899cd865169fbf23,
+                    0x0056f18dcb815b59,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaf3976493dde56c5,
+                    0x8c38bab925672a45,
+                    0x67e2ee520485a5c2,
+                    0xbfa868c6e5105469,
+                    0xbf6c4a458c9b8d34,
+                    0x605b8c3ee1b66897,
+                    0x87b2aec40f057a4f,
+                    0x959cde3eefd5150d,
+                    0xdebe35a4398b71fd,
+                    0xe8e58b4091973e7c,
+                    0x1919b341185ac570,
+                    0x002cce83b5e74276,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9c4337c8b21d731f,
+                    0xd6e37f75dd3ab499,
+                    0x4bda18ee1e5ada81,
+                    0x01c8e8e4319201cf,
+                    0x4b14fb1218a2f163,
+                    0xdbedc687b16500ba,
+                    0xe96efd22892ff0e8,
+                    0x1a9d3bfea68ced96,
+                    0x891bae2d276e5c14,
+                    0xc03eb28da96e7207,
+                    0x6991b8fa464b7423,
+                    0x008bfe28dec717cc,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x56d20fb84ad3f3ee,
+                    0x65b77453f51686df,
+                    0x10c7faac951e0922,
+                    0x365c2a40d168e127,
+                    0xe7d98bf19f0fdca8,
+                    0xe92916ac3f25c82f,
+                    0x449a7994b6a6154a,
+                    0x6b662bea0e02ebed,
+                    0xadb81a6cd821123b,
+                    0xbc45957b31feb379,
+                    0x6454edcba05049eb,
+                    0x008e6677fff8781d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe098aed79af7efdf,
+                    0x23b7ab6eb7e459d3,
+                    0xd02d670246589b24,
+                    0x83dd800edc78a95f,
+                    0x014f525d4c6b4130,
+                    0x54dc616bf6a20460,
+                    0xd12787b661d41120,
+                    0x336c7879260371f6,
+                    0x1871342186419a33,
+                    0x703d7f63bdd41bda,
+                    0xd714572d4976f86d,
+                    0x003ff6f39af95892,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc1f1bac216e7eb0,
+                    0xfc507c074a85a5c5,
+                    0x9b22d234e9b55a3a,
+                    0x447782f49d6c2423,
+                    0xff3566b2431d150f,
+                    0xa499461eb47c4cd6,
+                    0xef220c98d50af655,
+                    0x7d97ed228c73d4e1,
+                    0xb6ab9107e88ecf66,
+                    0xa0751e98575099d5,
+                    0x6508eec9ddc8e0fd,
+                    0x0062e7fad88b9980,
+                ])),
+                Felt::new(BigInteger768([
+                    0x27110ac9c014235a,
+                    0x5beb4285a0107116,
+                    0x0ecc588cc8474691,
+                    0xe8d50edbd5e94535,
+                    0x6670e175c1dc0002,
+                    0x23930ac3b56b9682,
+                    0x0eeecf892e14bb1c,
+                    0xb7d1cbbbe5855b6d,
+                    0x3d7e739b0b54630d,
+                    0xf1229282feeab78c,
+                    0xf1bc4a4c2c44263e,
+                    0x011859950673b165,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d57f92be35080b0,
+                    0x4f94cc0aa7e17a8c,
+                    0x989afe61e9349575,
+                    0xabffae9ea4a75927,
+                    0x7a9621edf69c8c42,
+                    0x5e57c8122da6b38c,
+                    0xa547219b8027ea26,
+                    0x34025c5e8d76dcb5,
+                    0xeae535940f070b45,
+                    0xf79e60aa8efa12b9,
+                    0xf2fa2f485b2173b8,
+                    0x00622b32f34cc97e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7e7dc5a4c4622d26,
+                    0x255ff5eead966641,
+                    0x1d672446bb30ba58,
+                    0x078c9657d5c06dd7,
+                    0xdfcb68ca194f7180,
+                    0x769dadf9360d1ffa,
+                    0x25021c627da9bcef,
+                    0x1621bc7e5e0e4f51,
+                    0xa6e4c8799483bf2d,
+                    0xed37c4ed1cb906c6,
+                    0xb8e7d063eb905e93,
+                    0x0030115b2eef4e23,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf78469e9b8af5fb6,
+                    0x508f975c365d924b,
+                    0x2f61eafdeddc204e,
+                    0xa55064cbfb47ab74,
+                    0xf4c864a8ae27e79b,
+                    0x1208c06dd9bd936e,
+                    0x34ab034e84bfe31f,
+                    0xee0f9a1d5524743c,
+                    0x0d52f4caabc6bfd8,
+                    0x58278a811dacc26e,
+                    0x503818e3991972f6,
+                    0x005b1366d5addf67,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa110f6047701b877,
+                    0x0ab65c964c119168,
+                    0x102cf4825741ccf5,
+                    0xd9a80d2c82a8beb7,
+                    0x0bd8d5514189d32a,
+                    0x06cdb30419d810c0,
+                    0x67b4a61dfc49d22c,
+                    0x584403f300549c0b,
+                    0xd57226be04c7bf22,
+                    0x4e85759677f34c9a,
+                    0x51bc08b6ac4d9ded,
+                    0x00e267fb3cc2f077,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce87d338b3cb3733,
+                    0x27ef7c06b9c51b77,
+                    0x00df2507ec15424f,
+                    0xd7ab6fb47839ea13,
+                    0xc773f6ddde832e47,
+                    0x3cfe58691a1cb95c,
+                    0x881713b31be87479,
+                    0x8cf7415c9822b6d5,
+                    0x70471f637d0d813f,
+                    0xb884e9cfc89beb42,
+                    0x14b50fb5fd96465f,
+                    0x005d1ed4a2416d09,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b6bbe01d5b6c320,
+                    0xa42bdd201882620e,
+                    0x9020342d660e4e33,
+                    0x1e1a4ba8163b010f,
+                    0x9f7a121021d36415,
+                    0xa9110c6de02f5944,
+                    0xf2731794fbd4329e,
+                    0x6c55c02252aac16f,
+                    0x2286b999f4e5bb98,
+                    0x77db1008c30c9aec,
+                    0xba1529fd38f316af,
+                    0x00088f1f56802f41,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x0ab3e26505b301ae,
+                    0x85effb1f74875d7d,
+                    0x057e5575fa314e82,
+                    0xbbac09f1ea3e993e,
+                    0x06a9d012c76f8f8f,
+                    0xd5fda2821b31faf5,
+                    0x5599cdbe6bf1e66e,
+                    0xab7dc757679e09a7,
+                    0x0609e1a146eb2f62,
+                    0x23b204c0bdda9d27,
+                    0xb6a34e89a1c18189,
+                    0x0064bbbd2d871253,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1849bd5cdf862cc5,
+                    0x2a8f8c452db813e0,
+                    0xf6bca0e822e273b8,
+                    0x8fa3819b111e60ea,
+                    0xee50738b3a3954ef,
+                    0x9b1e1828a2a62291,
+                    0xf4b550f75abafeb8,
+                    0x80c6a03d7539baeb,
+                    0x7aea06591185f81b,
+                    0x66137ff22e04d538,
+                    0xf5436148ef7c6562,
+                    0x00e94a68f8cffbcc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x27c0f15b50babc2b,
+                    0x9a345e3e1440ced0,
+                    0xfae101e65e8cd9b7,
+                    0x4faad99814c5d359,
+                    0x66f08017688cc104,
+                    0x89886ca3b9b7e734,
+                    0xbfa9268563505499,
+                    0xd5e6fc16e7308908,
+                    0x27949083672e1858,
+                    0x8394eb1e1e1caf35,
+                    0x347672d09cea5a08,
+                    0x000a3fdc00993d38,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf94c0ceb4a76842,
+                    0xbde12bb2121340e0,
+                    0x841e4e98b9d531cb,
+                    0xbfe23b993dfc93d2,
+                    0x7edadcea305a8f4b,
+                    0x341276ece8b54873,
+                    0xe7855ebaf96cb64a,
+                    0x1c30430d003b85bd,
+                    0x308022e5791a446f,
+                    0x1ee951bef1734efa,
+                    0xd166f8f8539867d6,
+                    0x00cb3a31ed1e9499,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4aa78fbf376d38c8,
+                    0x3388c69f6a6d63a3,
+                    0x691581dff401beb0,
+                    0x60f046b969866b72,
+                    0xf9704744f7560a07,
+                    0x4b0a0c127ad52583,
+                    0xcd6d903de5b8951b,
+                    0x120bf8d6feb8f88e,
+                    0x732256f1bef7a6c6,
+                    0xaa8e5dbcbccb12c1,
+                    0x8a8e529b84ef3fe5,
+                    0x00f59b9dc642b1d5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed28596811fcf2ee,
+                    0xc6464ed07b32a833,
+                    0x1b115c8fa16f96ad,
+                    0xd01fc184d0ed6e7f,
+                    0x14cfb9689724a762,
+                    0xeb7bbcc36cec3c8f,
+                    0x7b442b5feba8349f,
+                    0x1b8aa7a1eb1a537c,
+                    0xf6c4afd8c87c439a,
+                    0xe27c16e2c5f0e083,
+                    0xb836fc9b874db02c,
+                    0x006ab95cfd076f32,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe04142d0f2bc817a,
+                    0x9c7ccc77f90056b7,
+                    0x14ef82baed2d2fad,
+                    0x7961d8fdb0a85f87,
+                    0x22a25e68668d525f,
+                    0xed95b42c5b8ad805,
+                    0xd98add02a2fe3137,
+                    0xf36950011aee5865,
+                    0xdcbe5627860b5d4b,
+                    0x53b28ca58ded5ae1,
+                    0x33035a2a92455061,
+                    0x0086c116f40e0ddb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44b8bff3e8e1ef5e,
+                    0x5d47305d4dc42f6e,
+                    0x5972e5a55f082900,
+                    0xf96060144f960232,
+                    0xa5d667c7d36e431f,
+                    0xec29cd85d046626f,
+                    0x8f635f04b19aff0e,
+                    0xa41f591c3fab1831,
+                    0x069da18e5c5b1dbc,
+                    0x67c1474b742724fb,
+                    0x96a4c4ae5262d46c,
+                    0x0006b608f908f680,
+                ])),
+                Felt::new(BigInteger768([
+                    0x415e8ab561ecb56f,
+                    0x37ff066049bbe34b,
+                    0x940550cfdb9b4580,
+                    0xe5c842ff1e0f3a59,
+                    0x65146c646acd2990,
+                    0xc06009a95cc7e772,
+                    0xcff8b922b85531b2,
+                    0x5dc68b8ea662a1ac,
+                    0x7c70737f922201c3,
+                    0x7d499828ebb2ccf4,
+                    0xeae260b721c7f1ab,
+                    0x000958c3c7539896,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc6334b17706cb4bd,
+                    0x47939e942714a0c9,
+                    0x35717698ff2ddc08,
+                    0x1068c4e215a1201d,
+                    0x1fbe40d3eb3a1d08,
+                    0x4b5e187ae6e47bb3,
+                    0x0045f54ec3f510ac,
+                    0x647e5d980ba89628,
+                    0xce681f4b4dd0710a,
+                    0x9922f9b1be457232,
+                    0xb6f968beac6d7337,
+                    0x00b8b14a278bb9a6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x0bd760f0c3baab72,
+                    0x3719f0e69803c80b,
+                    0xa65a14a73cb2b11f,
+                    0xfe995223be74ce3f,
+                    0x4b4a7c5708add603,
+                    0x0e30bd2e0e0e4384,
+                    0xf41453a161f261fd,
+                    0x5286a9cf8d15407f,
+                    0xfc0a8f55e6e48482,
+                    0x9e2cc6a5ecc5d8f2,
+                    0x01cb754411113e0b,
+                    0x001f6715689f8aa2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01f7960ce112e178,
+                    0xea48a3e306ed8e13,
+                    0xf9f4f28ecece2173,
+                    0xf97c70c905017924,
+                    0x3bb10730ae1f5a7f,
+                    0xeec9245e768b0622,
+                    0xb341d3ac41f263e5,
+                    0x66fb76975f25afdb,
+                    0x553a3f11b3deec49,
+                    0x2afeb74ffb3bf8d8,
+                    0x1ab91219ec5d5f37,
+                    0x011369bef45c7ad4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6392c6098343f8d0,
+                    0xb6d262eaf5bd6a8b,
+                    0x75d5082e3438d3f7,
+                    0xf2037e265cf560ea,
+                    0x196a4ee2b2f264f5,
+                    0x010c127e2ad49470,
+                    0x773485448ea1c72f,
+                    0x14cac5abdac21549,
+                    0xb3a272460412c993,
+                    0x4a033750212430a2,
+                    0x88f5f57a1643ca5c,
+                    0x00b49c97fba986ce,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbf7bb6a5878563b9,
+                    0xda23752fc55de7b6,
+                    0x583b4c85e6519f0a,
+                    0x0c55d8fb832e841d,
+                    0x592310f935bac121,
+                    0x5b1d0fd5229295ab,
+                    0x1ab1fde7eddb74b0,
+                    0x19fdc76c26899ee6,
+                    0x891a57f78c8cc540,
+                    0xebb5eb1e532b45ac,
+                    0xe11396d0d62cc0de,
+                    0x0067c595761d6575,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc59988764ef54a64,
+                    0xd2465ca579b1b0f6,
+                    0xbad5749484a99ba6,
+                    0x499621b0623d44ec,
+                    0x6fa0860c80316404,
+                    0x60377bb8eb3d2973,
+                    0x5bbf67d2ab8c0b6a,
+                    0x2f8430c279b4ca0c,
+                    0x063eb6282f04aabb,
+                    0x91a49038005c2aca,
+                    0xdf1905552d53e3f4,
+                    0x001f8eff74313067,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8779c33ad3ed5fe6,
+                    0xf92eaf1b1db45bf3,
+                    0x1588b44e1d631c86,
+                    0x91029fe6b37286ed,
+                    0xd2fdfe061c71095c,
+                    0x6fb26ba3ba32b92e,
+                    0xd54448328cf41705,
+                    0x60f1a138d12f57b5,
+                    0x8ef3c1732c8d612c,
+                    0xa1f3f092d6deda72,
+                    0x4afd1dc63b1334aa,
+                    0x00e0437a310c2657,
+                ])),
+                Felt::new(BigInteger768([
+                    0x538c3fc816464220,
+                    0x901858ebeeb27d2e,
+                    0xab00107a70be5674,
+                    0x54d5a0c7110587f6,
+                    0x9b5b732e4025f413,
+                    0xe4637a8144102735,
+                    0xb93f26c9fa15e5bd,
+                    0x2ea63bf93774df23,
+                    0xeabc404e5f6b2a3b,
+                    0x82f83dacd4ab01f8,
+                    0x376bfe740de41fbb,
+                    0x0053a9c6b5c93418,
+                ])),
+                Felt::new(BigInteger768([
+                    0x92e4019eea565b15,
+                    0x6db090f0e6381988,
+                    0x72304975edf13623,
+                    0xfb0b6452822de844,
+                    0xa656a786289dc4ad,
+                    0xf7bc17b5f3c6332e,
+                    0x9f9a85027dbcadf5,
+                    0x8fb3ea27f5786856,
+                    0xb23886d46defad9f,
+                    0x342d094032b93b38,
+                    0x39c12a21338f54d4,
+                    0x00587221cb7f7f6a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0ddd72a1665286ef,
+                    0xf70b7633b3fda5f4,
+                    0x6eb435dba034dbea,
+                    0x5749495b4f58f410,
+                    0x8b2174a16af67461,
+                    0x3c1fd693b0c5005e,
+                    0x89a55de0132910e5,
+                    0x23c3d2ac6bbeb212,
+                    0x9f0242f3801c029c,
+                    0xcfc02e5fc4727ef4,
+                    0x087a0ab8279cfac7,
+                    0x00f4fad7f7bfa827,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c9a282e2071164a,
+                    0x88bf96a2b44bca88,
+                    0x65d34959799ab3ed,
+                    0x147104c45d509206,
+                    0xadfc15c78b7e002d,
+                    0x468f135526b15050,
+                    0x20bed79f9b3dddf7,
+                    0x3e65b330e35096b2,
+                    0xfdcc2764929de26d,
+                    0xb97d66f12421fc03,
+                    0xc036e9a640ad2682,
+                    0x0118b537a371c62f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x543e1dc7e4410861,
+                    0x361f7a8e3f9a8c7d,
+                    0xbb0cf14c26b2d417,
+                    0x5bafb42abd2d2540,
+                    0x9964c7e0b97e8355,
+                    0x37911f61267da953,
+                    0x9ea0fe65560fe461,
+                    0x95e96ab3ae998742,
+                    0x991523c1d3c22f79,
+                    0x0c3d39c585bf6eae,
+                    0x371320a9114c71de,
+                    0x00c3ed69047db3a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc3e9d7e337ada11,
+                    0xbabfa7bab2dd7627,
+                    0x3cea0d3a052907e1,
+                    0x32ac4d9e1303ab51,
+                    0x72c854db2dddeeec,
+                    0x3754fe4887ec2d90,
+                    0xcfa28697163a42d8,
+                    0x296dc69ef3f0d696,
+                    0x5cb56f470575d48b,
+                    0x38b25d1288a87965,
+                    0xb2160da655990dcd,
+                    0x0026bf1cbcdc0650,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cede4b71bfb3351,
+                    0x5120c8f4229f9cb2,
+                    0x70132a1eb886c7b4,
+                    0x944a7290de06190b,
+                    0xff782a39103325fc,
+                    0x3d0da35c90fc3139,
+                    0xca56b571ff0378fe,
+                    0xa7b4f841a3729807,
+                    0xbb8b12b00ecc6641,
+                    0x362f742c1fbdc8dd,
+                    0x6f09f5d603ac6d6f,
+                    0x000ec06b7f477787,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeb3f075b87144293,
+                    0x4b2cc630fb200d15,
+                    0x97a04fba388826a0,
+                    0xe9dcaff20ff203ee,
+                    0xdd4e3df6e8ff3c35,
+                    0x5ff73cfe5bc476d6,
+                    0xa67588f80c29053c,
+                    0x478403b7d12da0b1,
+                    0x69013eeb120d6e72,
+                    0x1951d17c89f319c6,
+                    0xf3b096be940e2d71,
+                    0x0003411272a0f344,
+                ])),
+                Felt::new(BigInteger768([
+                    0x327e412032f093a7,
+                    0x69e68b6e74c8d5df,
+                    0xdd1f0856cba3eef6,
+                    0xc6d0f7a9d2a5bd50,
+                    0x98be2ec68272d860,
+                    0xf35b1713dd21ba1e,
+                    0xa687cbe3de39f494,
+                    0xf415d36451215abe,
+                    0xff12e23d91e2e075,
+                    0x523af20cec00e1ad,
+                    0x98ce8b76960b6dda,
+                    0x00ba77b4bcc6f928,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5b834c00b8dc557f,
+                    0x763a15e6ab5750c9,
+                    0xf2c9a2d2cdb2a3dd,
+                    0x703971eed29804a0,
+                    0xcf7c3fb179e64f91,
+                    0x27ce410fad106c46,
+                    0xbfd39c53dd13e4fd,
+                    0x006b1c1da2a452b2,
+                    0x88bd52e429c3ee7b,
+                    0x4e2c24d5231df104,
+                    0xd71b5af22e185d4e,
+                    0x00a083b52b5fb522,
+                ])),
+                Felt::new(BigInteger768([
+                    0x46c091a3e79f2da7,
+                    0x02316c82b38ee114,
+                    0x6b0b2174487693c8,
+                    0x60297d01da86aa59,
+                    0x410937cbbcfa7bf0,
+                    0x6f9978ac25d7f9e1,
+                    0x8cf6b9408501721b,
+                    0xad0d6a7583a21177,
+                    0xab7d49ee7216ee8e,
+                    0x204e8d9d7c3c6d66,
+                    0xd79d09590aed1cb6,
+                    0x00b697eb2df1b7ad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4f63e36c1f84209b,
+                    0x877cda28b597144d,
+                    0x07439bc62495b64b,
+                    0xa90d8f053e71d08c,
+                    0x991d7833499ec723,
+                    0x745e7e0a81ff5810,
+                    0x63ad468624be8e25,
+                    0xeb23d868a30d5bb7,
+                    0x776ed6a7c143e21e,
+                    0x24121c05ad6a1c39,
+                    0x9dbf4af1dedf194a,
+                    0x00ac766d553ca83e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4f08a4126e232c,
+                    0x7ff5b0e6ac15132c,
+                    0x538b39315932e54f,
+                    0x102bfdd557e4d56d,
+                    0x8c2b48a102c4588f,
+                    0xec3dc3ec8b20717d,
+                    0x50461fb8c5b85988,
+                    0xcdce17aefb3ca8ad,
+                    0x148e3b8c26d4c5bb,
+                    0xa04ebcb234938d4e,
+                    0xaa388ff66dd3f839,
+                    0x004a7c13d8d7cd4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd9e9fc283d48086d,
+                    0xed3f38adf3f2cb79,
+                    0x7f153d6189b507d1,
+                    0xb75a596a08a0145c,
+                    0x3da93d5640c2c5b2,
+                    0xde35613ebf9752ee,
+                    0x5e14f26e82cd682a,
+                    0x38ad7fb5791a62a7,
+                    0x1b73c7a8843f9604,
+                    0x36f5a425b8147ce7,
+                    0x977886039137f135,
+                    0x008836d049a9eee0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x27fdf34f3915dd5c,
+                    0x1fbd26e94d48c707,
+                    0x7bd67dcda45ba11b,
+                    0xcfd719b754fda617,
+                    0x8dbe66eecd63b4be,
+                    0xd0929ff3d67a2475,
+                    0x19fee59aac9b558d,
+                    0x266d41c635f5e725,
+                    0x6b26e7f52f889a38,
+                    0x73a491a7b52f06bd,
+                    0xbb2146271ae6e506,
+                    0x0103d802c3817f76,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf9dd196159b89717,
+                    0x6a03d0362e25a619,
+                    0xc2874aaa752fba7d,
+                    0xcf1eec3ecfdc53c8,
+                    0x8851708d19250915,
+                    0x998f6e9e86de3b81,
+                    0x75aebcbdde518a66,
+                    0xf30cf32db85e89ec,
+                    0x0ad1e1ec3b427bb3,
+                    0x0b98c91cff8c2e55,
+                    0x131081fc15dba5b0,
+                    0x010ae8459dc38a0a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x251925a587a41f22,
+                    0x127106381fb243c8,
+                    0x27e94ed8c0b5dd38,
+                    0xbc2ad71cb81a010d,
+                    0x3f476ee2cd21ed57,
+                    0x6da2549be291d5fd,
+                    0xf3ea9707358fbed5,
+                    0x9f6a7c9bb68e3c4f,
+                    0x8602bb941ac7b120,
+                    0xb249e54de8151139,
+                    0xbbf9237f5abb9fd1,
+                    0x011af30772f84110,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb83457474a376a28,
+                    0x5e278677be396bc9,
+                    0x375dfa561fb78d8f,
+                    0x51ff3d5e1b85c0dd,
+                    0x6f1a0effc077c7a8,
+                    0xa5791a6b200f52ab,
+                    0x346d9d34d1eae77c,
+                    0x9e060e0f6982acdc,
+                    0xca779caaeeafd883,
+                    0x967772c559947856,
+                    0xeff7b5ea9795bec4,
+                    0x008b28a4857e3061,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7bd1caf6a083c7a8,
+                    0x32d9456e8e15a19c,
+                    0x2a75994facae09da,
+                    0xb33c09330f9b4d63,
+                    0x6d2b30208cd416a4,
+                    0x62eed75c140a869d,
+                    0x2c8b97e758e30634,
+                    0x54207fdcb1989a7b,
+                    0x9a4b60463535a0da,
+                    0x1ba45cd6b14dae66,
+                    0x25e5a49266b6177e,
+                    0x00fbaa2807a74344,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5a6c71e54998bdf6,
+                    0x24ee39a26d31d114,
+                    0xdc614f0a805d46d2,
+                    0x6bd65842fcba3725,
+                    0xe14e8e6f4868d9ad,
+                    0x669c0604fa0037c7,
+                    0x95128009a67931d5,
+                    0x3326fb755f850965,
+                    0xea1b3a72731e71ba,
+                    0x6484de78badc888d,
+                    0x88fb9d167dfcc931,
+                    0x00d6522ef695ab23,
+                ])),
+                Felt::new(BigInteger768([
+                    0x499db996c621a2e9,
+                    0xb063d484c7fb12fb,
+                    0xf249c1ff9ed3b209,
+                    0x30d9e662c6770864,
+                    0x68ecdf428ef1609a,
+                    0xd8e9fc1067cdc6ed,
+                    0xbabc6fa24372950f,
+                    0x7e849e660e065ba7,
+                    0x8b83a77e3f96e08d,
+                    0x71139498e264e2aa,
+                    0x34fcfa520f1bbf52,
+                    0x00ec9e246e83e944,
+                ])),
+                Felt::new(BigInteger768([
+                    0x35ed60dfa41d8886,
+                    0xc4a876dca32a3a38,
+                    0x2aa3931c03786a6b,
+                    0x44c709cba80da051,
+                    0x68951620a3da69c1,
+                    0xee1610de80df01a8,
+                    0xc707330d8c7daa60,
+                    0xa3f33cb91c330b21,
+                    0x66e03622cfc47e4f,
+                    0x5f230f0ece1a7739,
+                    0x6b5e746c171cfe5a,
+                    0x00b9ce1110a7166b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd590f21bb5f9bfff,
+                    0x06ca37605c9d7915,
+                    0x94a0235d009efde8,
+                    0x9610ee1796255f63,
+                    0x1b6e5fda2cae862c,
+                    0xf14d6ab4c576ee84,
+                    0xc8047df9476ab472,
+                    0x95d9fce022b59e5c,
+                    0x68f2aed046f56683,
+                    0x37307445de6ca37e,
+                    0x36c6d32de8899162,
+                    0x00f0557662681c74,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8e84b9b80e5be6d,
+                    0xc53183c66c50934f,
+                    0x9b43b0550ff4a78c,
+                    0xd02cf14aec848f2b,
+                    0xea1381d532ab7ea7,
+                    0x102d784d86412966,
+                    0xe98bbd30283f70db,
+                    0x7db6c5ae9707cf85,
+                    0x93fadc92aff13172,
+                    0xb2574cc20574e67d,
+                    0x2df33c0a396843ff,
+                    0x009cadf02cca813d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xfca075033c6f3140,
+                    0x01613712195725df,
+                    0x0f517fac1bb9302e,
+                    0x5861e228bc0df00e,
+                    0x44d188adfd059959,
+                    0x1688af71f72f086f,
+                    0xc0113e52d3e4823e,
+                    0x298984c719a97953,
+                    0xbc3f07e0d6f27559,
+                    0xc82494698c91bc8b,
+                    0x7965d5c2e76f38a0,
+                    0x00588be9068ebba7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2736fe831bf6978,
+                    0xb5655e3e1cdb7785,
+                    0x91564088de6c43ce,
+                    0x42f91ddb0675f75c,
+                    0xbffcdf59d7ca193a,
+                    0xeb47602187771b4a,
+                    0x99c68e2cea3b43ab,
+                    0x4026b167db381f08,
+                    0x47b84824c23a2ae2,
+                    0xa7daf44355d1c395,
+                    0x85bff21fd4b1eb84,
+                    0x0098542f6cbda08d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde7b733a38ff55f5,
+                    0x4246acea39217fd6,
+                    0x07769f34576ee766,
+                    0x92f9f0e8a227aac8,
+                    0x69121853e77f1b3c,
+                    0x69377093f6d9b035,
+                    0xc65348268dff52db,
+                    0xcf064c3b0a272625,
+                    0xec3b96dca80cf975,
+                    0x94048ea87879188c,
+                    0xf03c25d2c80762e2,
+                    0x00b2e3690d4575cd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x018a71f1f2b349fb,
+                    0xe7deffa8f2e1dd0f,
+                    0xecf4ec2545a1bcdf,
+                    0x42eb8cd9768fca33,
+                    0x270cd1b30fe60b75,
+                    0xfd695817f698c07d,
+                    0xbbc0b7fa05431eac,
+                    0x22f2a1ed3167d1d2,
+                    0x64072755274cca4c,
+                    0xbe6e2d5acd076259,
+                    0xb76d7eeb0fb84f26,
+                    0x0033a111d6349631,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6b822ca7b9798c14,
+                    0xfaddd7bc6c7b4443,
+                    0xf4f4c7f00369b7a1,
+                    0x179524b6050279fc,
+                    0x4db3bb7f954c0af8,
+                    0xa9e0ad09bb42d450,
+                    0x4af04cc4c60fae90,
+                    0x51d3bd37dfb2e373,
+                    0x95c436b116716c57,
+                    0x953752e7bde83bde,
+                    0x297888ae8d221be0,
+                    0x0035bc6d01603ea3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3856ecf2f10fc4ac,
+                    0xbc0bade4bc61d8d6,
+                    0xb3ee1b332e75c799,
+                    0x108b12452d229985,
+                    0x290d2d509e55fa71,
+                    0x6159246964f19fa2,
+                    0x431aede85c46b8cf,
+                    0x9b86a77b2902f1d0,
+                    0xfa4365a29a77ce19,
+                    0xd7f78248afebba1e,
+                    0x3fce742610524414,
+                    0x00b4810d55c8193a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b59f687197b4857,
+                    0x9b08f119dd83b69e,
+                    0xa2f6c7eb6b2d7ecb,
+                    0x02e579bd77ca21e7,
+                    0xb5ca77c9e4cdba01,
+                    0x767ca22978385dfb,
+                    0x8cb64694bf322322,
+                    0x31050c56944f0d2c,
+                    0xf5236d5102bec887,
+                    0x2ba3fec4419ea0b8,
+                    0xa6b3fe4ee5a30610,
+                    0x003635867ee8ba6e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6a6d1bb4f7b68d1c,
+                    0x0f29fba68eb5d86b,
+                    0xe692aaf1e149635f,
+                    0xfdc17e4158682f7a,
+                    0xfd62ca96797dc3a6,
+                    0x4880418601ac4011,
+                    0xc2195063ef843437,
+                    0x71fad9f6322cf37b,
+                    0x1e043ad20cfda4fe,
+                    0xe95e840ac1233abc,
+                    0x92bdeacb05c5427b,
+                    0x00008359fc4d98bc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x854f578a35e19cd0,
+                    0x7dc948a779c0a6a2,
+                    0xe3f38ff873af4adf,
+                    0x195595a5d035c042,
+                    0x8e78c02ee68e1f51,
+                    0x9b6d77d047c55b5e,
+                    0xff4bce02b1c3d070,
+                    0x2bca26ff431c282a,
+                    0xb55ad0974d891f36,
+                    0x210c1d3e7bbd2e58,
+                    0x8987aa8e191d6d39,
+                    0x00d987c1fa0bd842,
+                ])),
+                Felt::new(BigInteger768([
+                    0x722052d5254f5ef9,
+                    0x6df827cc33314949,
+                    0x3b6ff29a2cd7b33b,
+                    0x29761c6226a7f1d5,
+                    0x5389ccecd1ea2bc9,
+                    0x13874b5a43d6985f,
+                    0x7e94eeddc9e67835,
+                    0x463102ae9fea7160,
+                    0x202e495b5ecbdeda,
+                    0xfa78a6a58acdde2f,
+                    0x359bfa0ff360cc12,
+                    0x00abbb0c6ef5283b,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0xf0ea2211062486bb,
+                0x2993b52a48b5846c,
+                0xd17f6748ed1d837e,
+                0xbd6340845e946212,
+                0xab1003e74ca05d95,
+                0x6439fe88b7726d6d,
+                0x2315aa0f070419b4,
+                0x4cdb70621a5b0077,
+                0xc8c12cd641b0ca0b,
+                0x4e7ca45b694f779b,
+                0x35b6dd9d202a930c,
+                0x0005efcc4e437b97,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xea54cda5962de4e7,
+                0x4089d50d85f17e9f,
+                0x0d0b3d73b96ba931,
+                0x8bd29c84a6f7af64,
+                0x6af55c8503c30302,
+                0xb8a61e04f09b046e,
+                0x45734bd254cd7960,
+                0xbd377217bfceea42,
+                0x471701289e74f136,
+                0xfed0233f6a2f36b7,
+                0x31ef0252c73db7da,
+                0x00004f1bdf409e1e,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x72f8b79b3c6ff53f,
+                0x8155be22d73d22e9,
+                0x5077e6ae42a6a221,
+                0x4433288d3a3d43bf,
+                0x4ed9c57af049c02b,
+                0x154e71bf457cd156,
+                0xbdb0cf83749d622d,
+                0xd5c9a0654b7c90ee,
+                0x445862025cc3cce1,
+                0x9c174072a5cfcd82,
+                0xf9fb72ba32258062,
+                0x00f0af7df684bf70,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xd90d76932ef3b971,
+                0xacc9f32ed48e402b,
+                0xea434af416e7f27b,
+                0xccc5a82fd9038236,
+                0x7567309a4b6b73c6,
+                0xc5bc7052f7323070,
+                0x937aeb18cbaf5902,
+                0x060d5383c7ef0ef2,
+                0xed977c9fb00b0d74,
+                0x16edc06546efd00a,
+                0x6a35ee64f9d32d5d,
+                0x010dab50af27ba5f,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x9191e06042b71feb,
+                0x3d9c82a60044b485,
+                0x36fef5cd96cf8fa4,
+                0xe8d9e66aaa4e11a2,
+                0x8e95ca74d8a58541,
+                0x60c73097c4280754,
+                0x1400b6b82a04fbbd,
+                0x34764e0e254b7d8b,
+                0x4d7ae7c8bae9a679,
+                0x225f8cace60e6dad,
+                0xb62fcdba51c402a2,
+                0x0028a16768a31bc7,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xabfb45399d46e5ff,
+                0x2d5a3ea8b9df0109,
+                0x7248f613c80da93d,
+                0xb2546b745fbcba8c,
+                0xd1c29127c4edc4f6,
+                0x033343c31c4cc062,
+                0xd3a87a75b86cfd2d,
+                0x9c532c9d81d3891d,
+                0x391c7ff8712de39e,
+                0xbe8d5a78ca54a272,
+                0x04e0b3bbca4fa915,
+                0x00ee602371c7a4d8,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb543e9aa4be09733,
+                0x4b06964ce108d11e,
+                0x9c44065c8432e26e,
+                0x6cddf0871cc14178,
+                0x4d07db51742369a4,
+                0x73e7af0249f62191,
+                0xd63c5a00ce84f264,
+                0x6272c8b1507e213f,
+                0x3f06e492b506faf0,
+                0xe0dbac9195e92cb8,
+                0x4c60d1541015f4b8,
+                0x0114665946695390,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x67ce0714b43fb545,
+                0xbecac357daec1fc0,
+                0x1c476b2039eda909,
+                0x913a10af635695da,
+                0x28d74b6e1fab0829,
+                0xb71fb854d3ec55ec,
+                0x3146a663635df247,
+                0xde5ff439ab304b4b,
+                0x620749836eb8c51b,
+                0x891fe8fe6c243e40,
+                0xfbfddcb0f3cd7c4a,
+                0x001c843df1e46b47,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x2995e818081e3977,
+                0x3f536c71374a724c,
+                0x5403f912469108fb,
+                0x1eef3bc492d02705,
+                0x33f280cca68bed8b,
+                0x16b3b90dafd1fc4a,
+                0x58a7b6a6bd97e963,
+                0x2f1f2090c7faca86,
+                0x295fb428171a4f80,
+                0x2eabf02b6bed1117,
+                0xf0a5451a2c6a8d53,
+                0x0066a512768f9cb8,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf6229d5def9a7470,
+                0x062d73c1ecc276c1,
+                0xd7ea793b85f870ae,
+                0x6b29670b97344f51,
+                0xa6c3f06c9dd072c9,
+                0x100e61822e4f168d,
+                0xb7e88be4efab2aad,
+                0xa694f623de5f5811,
+                0x8b3ec6460e79cf9a,
+                0x374bacf672d44492,
+                0x3d4f8c207129a8b1,
+                0x0074e7f71ee31039,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 760, 761, 2285] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 760];
+        let mut opad = vec![0x5cu8; 760];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
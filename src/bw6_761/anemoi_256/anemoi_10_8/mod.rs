@@ -0,0 +1,4545 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 960 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 8 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 8;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 2 elements (192-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 14 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 14;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x827de758b449be90,
+                    0x69d4fd686adc613f,
+                    0xfddb33ab69a90ff2,
+                    0x85b1ce1abb36b835,
+                    0x76e27504b0f7cef0,
+                    0xeef94e0a66a2f37a,
+                    0xfb77cd2262c8f1c4,
+                    0xb836a6a5c82c0889,
+                    0xbe460beeca723a88,
+                    0xd510d60890b85885,
+                    0xa008fc6e1092860b,
+                    0x00561a3a7addde26,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6e252cd7936c5fea,
+                    0x888a0a07e38215bf,
+                    0xecc6d2f0ca623930,
+                    0xea40ef027dfd3465,
+                    0x36f0ce381856687b,
+                    0x6b23d234eea5717e,
+                    0x6a8e9558862199ce,
+                    0x1cbd0a668d61a51a,
+                    0x459eb63040470357,
+                    0x0a027fe042eba549,
+                    0xe7e5d0dba11454d8,
+                    0x00812adfb14831ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3245404e9ba1996,
+                    0xb5891cb6693a3829,
+                    0xc5a77309483904a0,
+                    0x4749dec9b3b7f991,
+                    0xcf0a1546801964bd,
+                    0x0e1b8911a8053c07,
+                    0x0c4aacce7c0df859,
+                    0xab17a13e25edc7fb,
+                    0x2d7db03b88e9f2f6,
+                    0x60f849facb8109e5,
+                    0x15dea9ce1163fb56,
+                    0x009448658f10de25,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb8b11da1a4173eb,
+                    0xdd216d3fd12cfaf7,
+                    0x28376b014d3abd2d,
+                    0x53ffe711a025c4d1,
+                    0x63bb7486f081f174,
+                    0x75a89e2b6ddbb858,
+                    0x09228a764225aeb4,
+                    0x1e5c640309edc6fb,
+                    0x290f6c80a465c038,
+                    0xacad2fdfca424bd5,
+                    0xfab8d2dd07757e5a,
+                    0x004ccc7465f38743,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa3f77345c061b37b,
+                    0x13af8d21da9d3c19,
+                    0x60300ce71c871ae5,
+                    0x9df0fddddf0c5dbd,
+                    0x86226faa45d3a0c3,
+                    0xf2376ff982da111a,
+                    0xf7400d35f00e12fe,
+                    0xef1f234af9791558,
+                    0x3b3e7f9629bb16e0,
+                    0x12c1db2c2a5314e0,
+                    0x026e707c57c367d5,
+                    0x0045541798a842db,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc65bbbb5dd91783f,
+                    0xaea147bb66110878,
+                    0x87c2d2a1ad1a2f2c,
+                    0x91b02d65a35a22c5,
+                    0x492a211d31f350b9,
+                    0x34bf9ea574ae14c0,
+                    0x8b5d876729e001a7,
+                    0xd3011acef3a0c9f1,
+                    0x0a7e0dce1fa2a32e,
+                    0x6fddf5923ee2dbbf,
+                    0x137985f8d6b55bd1,
+                    0x00dcd41712e6f5eb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcb7a33d51cc452b9,
+                    0x28c25a2a1f1e554e,
+                    0xdc6530214718f448,
+                    0x6a3f97b4e5759d6b,
+                    0x209102e26fa100cd,
+                    0x12c7c87f40fad878,
+                    0x838393698ce05ffe,
+                    0x06af3dd8d1e61981,
+                    0xc8495c43b2c68aea,
+                    0x9764fb0c6186d9ea,
+                    0xcb664da1c7974509,
+                    0x008ea441bb0811dd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0952cea64032a8ce,
+                    0x78c2657b1a6e378d,
+                    0xf484a5d174ec6483,
+                    0xc2ac6821fd2afe87,
+                    0xd3add425fbc7db1a,
+                    0xecddedf3020e2c70,
+                    0xbc69eef739b08a6d,
+                    0xe8ade0370f1eb832,
+                    0x6b6a579102cc4c90,
+                    0x0b1fa83925351df7,
+                    0x50dc2f4d65a7828b,
+                    0x005223b31cce0029,
+                ])),
+                Felt::new(BigInteger768([
+                    0x06e93c129ec575de,
+                    0x1642e4855451a381,
+                    0x565a5f809a2c8ac8,
+                    0x356cdbae8ab57bd2,
+                    0x1573c3a407ad163d,
+                    0xdf67b75ca91677e2,
+                    0x701b6176ee15a048,
+                    0x3f12b18b3e3672ac,
+                    0x8d5afbd588b54e6e,
+                    0x8fb2805852992e45,
+                    0x001bdaa40dbd6bde,
+                    0x001577aee22d3211,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7313abd41f497f23,
+                    0x8feee106c0d2d96a,
+                    0x72f334fb9b6d2335,
+                    0xeee8e5dd47b7452a,
+                    0x41c998cbb79a78a6,
+                    0x28945491e4eaa59b,
+                    0xd2244d6feff4bfa5,
+                    0xdcd03561a6e39103,
+                    0x061f5fb9f2c53b17,
+                    0x57dbbec06d809aaa,
+                    0xccbbcdbf409fc54f,
+                    0x0106d71f5cde5f86,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x16e4f05e985752bd,
+                    0x94554945492797db,
+                    0x0249c0d40706ff47,
+                    0x93ee9be7eb7e9676,
+                    0xdf3aa4b106655e8c,
+                    0x56925861c586bc91,
+                    0x9eb507c96cc5f839,
+                    0x3d0df52c4d28cf88,
+                    0xd1c972e1df5b6007,
+                    0x8550a53c1b69d1fb,
+                    0x98b026701b412357,
+                    0x00799ac105ddc390,
+                ])),
+                Felt::new(BigInteger768([
+                    0x48e940136531d0b7,
+                    0xa03faaa767097036,
+                    0xf34733023041c3a6,
+                    0x7ce02ba01a4d33d6,
+                    0x021907625aa9b303,
+                    0x56e234fa4cd8e539,
+                    0x257b2bdd78a065ad,
+                    0xb29a802c31fb6f45,
+                    0x23c8ffe022da81e2,
+                    0x19cef9dc467e7ea3,
+                    0xe28f41501fe357dc,
+                    0x00d71daaa88bc79d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x85193019b2ddcc3d,
+                    0x13406ef616b9fdc1,
+                    0xabc0844eb4dbac65,
+                    0x618a7ab3167d2703,
+                    0x7127fcdc4909698b,
+                    0xe789e232dd3f8906,
+                    0x47f81227a1341196,
+                    0x9d59f28fbb9d219d,
+                    0x7eeaa237dc86d01b,
+                    0x54a2f1a3779a9488,
+                    0x0f682ac158c50a2c,
+                    0x005dadb5833c2869,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0bb3e2f2f45f3f35,
+                    0xf4243d1baa9af375,
+                    0x341dd04f944d5f02,
+                    0x7baa576a2e86053c,
+                    0x93feec3b589346ea,
+                    0xb3263c294521de01,
+                    0x8af9ffce87105e61,
+                    0x0f09031e19386d98,
+                    0x72793ec01600cec5,
+                    0x5228da75f4654bba,
+                    0x6c4869af0c3e5b78,
+                    0x0026dcbeeb95c252,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2bba0cb31a8a6f11,
+                    0x1773e8b33d1b1a36,
+                    0xaa6e55a4dc919c7a,
+                    0x4007d189722f1baa,
+                    0x9dc4c717d6e268c1,
+                    0x2ef989a322bcbf68,
+                    0x2935739c171a9a21,
+                    0xa477dfc8f81bf392,
+                    0x81e5507e70e85aaf,
+                    0x1d6f9cf395a46c50,
+                    0x8e51b43c2c0834ce,
+                    0x0003c4f9a578294f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x99bf53732b3e300f,
+                    0x4a07d89a1432c6bc,
+                    0xad16fb309ec55272,
+                    0xada802bf0dbf1722,
+                    0xd9f9606f9689ed41,
+                    0x92b2186a587e4048,
+                    0x01d47b8cf217c034,
+                    0xaf00fe8dca51d78a,
+                    0xdf35dbdd241b93e0,
+                    0x1d668e7a08829fd0,
+                    0x5f3ab125db1ce593,
+                    0x00ad1cc0692c37a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x66f07fd3b667aa8f,
+                    0x4b6c9c8c54834c48,
+                    0xa321afc8c743c5a9,
+                    0x400db1879efc6408,
+                    0x5f5905c907c9fcbd,
+                    0x2a57ace917a213fa,
+                    0x45d6fff90febd127,
+                    0xf2dc2672e85cfb76,
+                    0xd9e8c7236e458122,
+                    0x11de9ec0065b7ccf,
+                    0x7e32bc6417ef1857,
+                    0x006bf6f16a58934a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f588b238efb6404,
+                    0xd79af492c121b026,
+                    0x6b3bb235afbf113e,
+                    0xf94c094b70a9da59,
+                    0x9d3ba2f01e91966d,
+                    0x9a318545b120708d,
+                    0x575d0025b1aa4c3e,
+                    0x096fefcc6a3989dc,
+                    0xbd67f2abbc8fde0d,
+                    0x869520baa2349c84,
+                    0x52358329b9434939,
+                    0x0079257c18a0db9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1336ebe0c888857b,
+                    0xa2587c8264e2c165,
+                    0x80ce56891ccbffcf,
+                    0xcbfb29dd02d01321,
+                    0xa6440426a710e191,
+                    0xf8c033fd42fac4ac,
+                    0xe67e0b8f50e9a783,
+                    0xfd582ebb1b58cee7,
+                    0x61250aca4f7267a4,
+                    0xa919be30d9444a3a,
+                    0x790363c1dcef12e4,
+                    0x002f065a67ff791a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x09443fa5e1b95831,
+                    0xf815f62fe430f29c,
+                    0xd5e1d7eca6fa4a80,
+                    0xa48036a1df9dc128,
+                    0xe6dacea571031222,
+                    0x922de05f272df0fd,
+                    0x34f023e8eab9dd4f,
+                    0x6d8c1845dc45cd49,
+                    0x274f3724129b6c7f,
+                    0x3be5a8b5d206812f,
+                    0xb4d54d97ca0aa32e,
+                    0x005c81830e2c2cb3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd8018bc235a99889,
+                    0x83933ac3f2030c4b,
+                    0x8d17f6715b47d54a,
+                    0xbcbffe38ce123595,
+                    0x1a7294177d0141c4,
+                    0x2419281abcf0f304,
+                    0x58d69cad34ec760e,
+                    0xd41df69be3b6aa20,
+                    0x0dc8e94b30050534,
+                    0x79ab691481805929,
+                    0x49cc903268794fc1,
+                    0x00456dee4ac97204,
+                ])),
+                Felt::new(BigInteger768([
+                    0x02034dc17acafcdc,
+                    0x8c3dc5874930075e,
+                    0x24df10d84e3344d9,
+                    0xf74bdb0435a0930c,
+                    0x771b21e9055472a6,
+                    0x053a1bcbfa991356,
+                    0x2db296a105ab11f0,
+                    0xf774cadc75f3e409,
+                    0x76eff38e1bf0cccc,
+                    0x30195579e5fc6c3d,
+                    0xd5120c2d3904ebc1,
+                    0x00e0a45bfc1f8f59,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac9214e1613e2a6c,
+                    0x666b62b5f0ff08a3,
+                    0xf67032be43b45cb1,
+                    0x4593aa4f855cf2ab,
+                    0x04018389f3d557a3,
+                    0x71f4af7db261a58f,
+                    0xdb4bc6e65a6ad452,
+                    0xe033d928634afee9,
+                    0x7996f22cfbc55568,
+                    0xc8e16a6a620d3c66,
+                    0xffa1d75262f7bb24,
+                    0x00233347c4513e8b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xddd253d31a51c4a0,
+                    0x35c79fe94946b688,
+                    0x7c21fa89997400a6,
+                    0x8b66d6835019778c,
+                    0x50d19d140fc32e98,
+                    0xbd3fa33a5f3f55eb,
+                    0x4bc0cdf231c418a6,
+                    0x54c25a1ff44f4554,
+                    0x045f1dbe203750ad,
+                    0xb49ba305f697f1c7,
+                    0x7453a06c950e2802,
+                    0x0088127fd1562d52,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe9ff8454095babd0,
+                    0x471f52aaca6e29db,
+                    0x3e7c6a49123a70c0,
+                    0xbba1deddd6e3286f,
+                    0x63eb0e57ab4d1ca2,
+                    0x841b678b67129633,
+                    0x6dd43bf87da76e52,
+                    0x78127eda3fe8d1ad,
+                    0x68c87d4c5228090b,
+                    0xb10e89b62ed366f0,
+                    0x6248ff5f0f369d6c,
+                    0x0099eda07ac4772d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e484d30b6d3d602,
+                    0xae59dd38721c89c0,
+                    0x70f53dc928b87cbd,
+                    0x6a6d2fff93153fc7,
+                    0xe1d1829902da61aa,
+                    0x987e8e1b7c0edb92,
+                    0x5c882d85f75e42f7,
+                    0xb4fc16fad5e493bf,
+                    0x870f355b7017af76,
+                    0x5421cca47cf0f7aa,
+                    0x55451a3672a0cd3d,
+                    0x002ea47a531eb4c9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa891b45f64a31f93,
+                    0xdb3a6d208761a5fa,
+                    0x2ab86ca4c3fba08a,
+                    0x70b10f86238e5a25,
+                    0x9568f7f7c4dcf02c,
+                    0xf1f166c2071a2e37,
+                    0x63cb728c91d523c0,
+                    0x2b71aa8b50627b9b,
+                    0xf30f9e71721018bd,
+                    0x4266da488de25e3c,
+                    0xe0ea04502a2a3ca9,
+                    0x009953858448711a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ebb7dae0b47d945,
+                    0x929a4a07b0c3eb27,
+                    0xf236c11f93ac909f,
+                    0x10efc4cd89a35d41,
+                    0x5bc093e5527b7110,
+                    0x3e06f4434fef00ea,
+                    0x7b87057bb48507c6,
+                    0xb5ffe539efa5588c,
+                    0x8c04ffe351da6ad6,
+                    0xea2883e08123b550,
+                    0xf9369bc1e625b04b,
+                    0x00105b5d5ba1e0ce,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a465dad477f8716,
+                    0x8fdc67592c659a35,
+                    0x661a70bb07679d0a,
+                    0x4690e69da8f5e58e,
+                    0x9c9eab80e06fed74,
+                    0x3bae0c41a28248c1,
+                    0x7f4c8bd00b338367,
+                    0x7e8ac0daeeaf8063,
+                    0xe5a8c8c082733b76,
+                    0x2a6d70f84c1aaa2a,
+                    0x0cb229a069afa24c,
+                    0x012258c13f0b6b2d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x52e1c95d9eaf5080,
+                    0x4df7b2e8e16d4f61,
+                    0x1ba7ba42167fdef8,
+                    0xd4796c8957000057,
+                    0x276659bbfd8ee47a,
+                    0x6f304d6e6f502dc2,
+                    0x509c597e1342b18c,
+                    0xbd0599fe361da4db,
+                    0x44fe4e1884f24bec,
+                    0x970c72abb4e7226a,
+                    0x42a883d47870fc0e,
+                    0x00c64388e30c1792,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8658a688596fad28,
+                    0xcbdd02fe0a73a24c,
+                    0xde3fd63a3345db17,
+                    0xe0e1d97bacef304f,
+                    0x1c26b1da1f48c693,
+                    0xd64495521baaef99,
+                    0xedeacd931989c9d3,
+                    0x41cc066afe1d76ef,
+                    0xf8caba2ddcb0f9dc,
+                    0x216fa70db680a43f,
+                    0xfb5ba496ec0b2c3f,
+                    0x006f8783e5befe14,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed4cef5ad3a54017,
+                    0xca102b57b2da6e2d,
+                    0xba4c6fa453efb95b,
+                    0x50ef437501a839e0,
+                    0x0872088355b2f664,
+                    0x6dab22b768dcebb4,
+                    0x247926f80605b796,
+                    0xa4bf3202750a4188,
+                    0x2f83e4bb8c01a9de,
+                    0xb567cc6e037214e4,
+                    0xa2d2c547006bdde0,
+                    0x0076ca5374c7feab,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1096e807d508826,
+                    0x13abd57d67cc1f2e,
+                    0x9843cf94b10d0af2,
+                    0x6edb752c44d78568,
+                    0xc2a0fe83bac408c8,
+                    0x47ad1ec0155e31e4,
+                    0xc4c87b3a7560406b,
+                    0x3f7b757568bd2705,
+                    0x29a5b408ae7a2e68,
+                    0xe8aeae887b918e8a,
+                    0x6b3dde10918b50a2,
+                    0x003a806b57bb5674,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd2286903f6df594c,
+                    0xefd76cdb72df7f72,
+                    0x7930685f567e3ae6,
+                    0x2e8f178acf9abddd,
+                    0xcddcb868787621f1,
+                    0xc2de4889cb5bb920,
+                    0x2282645013d77f1b,
+                    0xe2ff7d9d7255ced1,
+                    0xd601a3abeb4b8c9e,
+                    0xfe5dd93925e3a817,
+                    0x1538e87037c53559,
+                    0x009848fb1fddc2be,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc5efd90ac07a63b7,
+                    0x1a766ff5efc98ffd,
+                    0x73a7c1ca4d74956e,
+                    0x63e60901a1acf43d,
+                    0x80a94304904d3aa0,
+                    0xc631e03de3aa7d6c,
+                    0x263cee6f50cb7352,
+                    0x8a4c5f1e4009a11b,
+                    0x298a2658d949c14a,
+                    0x395a2dd10e9c45e3,
+                    0x75d1e5038bf665d4,
+                    0x0080da0d1448f760,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1710600e5dead01e,
+                    0x597fa92832b1248d,
+                    0xdd38ea38ae2a2b4e,
+                    0x09a28927244183a4,
+                    0x23742efef41e1b52,
+                    0xe97c1c6f17e2878e,
+                    0xe0a7a4604511d96c,
+                    0xdf810b2715cdaeff,
+                    0x56e6c3b846176641,
+                    0x86cb4d827cd1b401,
+                    0x006076b2c35a238c,
+                    0x0021caa809903fad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x08bd8c56fca44134,
+                    0x9f7ebf530acddf5d,
+                    0xa8a38ef62ddf67a3,
+                    0x05cab00d6b021be3,
+                    0x8227ab34dd5a0ba8,
+                    0x375fb7b24f5627e4,
+                    0xb2a25aec32591214,
+                    0x43526f547b692341,
+                    0xefd63d6d05fcaf37,
+                    0xd4cc8d86932e8c82,
+                    0x99762910a060982e,
+                    0x00022d27fb7b36f5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6db7727fcd25c8e9,
+                    0x1a613987573ff4f3,
+                    0x5fa85bc15dda2b8a,
+                    0x67814f07ec7a7c31,
+                    0xf57fa5cd1e89e305,
+                    0x30de0b9c21b11832,
+                    0xb68a4ab2c2ecb8b8,
+                    0x962d904059cdbedd,
+                    0x282e2e288a4afd39,
+                    0x7bd9fc71c2018da0,
+                    0x158cde325ab4245a,
+                    0x011e7078d2371064,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc45e983ea2bdda7e,
+                    0x4d23ebb091143c0e,
+                    0x367a1c3cd0045c3c,
+                    0x8bd61ec6ebf7174d,
+                    0xcf59cb15b6bb1273,
+                    0x09160c37537f9597,
+                    0xd8bedcea21753b46,
+                    0x9df53ca99bd440fd,
+                    0xb97a41dffc37f7fb,
+                    0x23ab31bbfe6b0b94,
+                    0xfbaaf1a7c27f945a,
+                    0x00ddb4cd2ee42fb2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa7706d70c1feb7d3,
+                    0x03a3efb93705d7ff,
+                    0x24c4d6e0a1c64f90,
+                    0xb6f2cda9d5194c6a,
+                    0x528c8ba3de40960c,
+                    0x9616e7814329dc1d,
+                    0x68f199ebb5142acb,
+                    0xbf410ca7a5207c2e,
+                    0x1c10b6597ed39d88,
+                    0x797ea025faf12c89,
+                    0x327cdf1321937141,
+                    0x00360b68ce43e63f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5ef938128fa398da,
+                    0xf2fa7b76534d3dab,
+                    0xcd43f20709273cdb,
+                    0x71c1b6f0151897d4,
+                    0x1a61ff31c4c34b70,
+                    0x454ac6cf9f2d2315,
+                    0x6b2f14413e4b86c3,
+                    0x8674d0b09cbeaf1f,
+                    0xe0b0bf1aec381840,
+                    0x30e433c7e94d590c,
+                    0x3151da808e9ff9f1,
+                    0x0054f00a05d08bd4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc262016c6cf2f155,
+                    0xedb99534cdfc50d4,
+                    0xf9688edf15d8418c,
+                    0xe35e70c6d1b8f5e6,
+                    0xcf388d0bbeaad4a9,
+                    0x7310b26ac875f175,
+                    0x9f5a486f5044242f,
+                    0x6748b78bc2efd0cf,
+                    0x7830d4589de0c2e1,
+                    0x79807f5f81743a4f,
+                    0x23681faeb2a1b562,
+                    0x006fb4ceaf9cd817,
+                ])),
+                Felt::new(BigInteger768([
+                    0x719d598c548da1ff,
+                    0x7ae42070dd6be264,
+                    0xd16697bf9482f029,
+                    0xaa531b765ea6dcc7,
+                    0x1f31fbfd08d2c883,
+                    0xe79287c9c981b313,
+                    0x894a75c7c7d97de2,
+                    0x8625d2ee3cfc2739,
+                    0xf8db15eea417aa68,
+                    0x2b31a915b09be0e8,
+                    0xd100462e0e976f3f,
+                    0x00550f66d78a826a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x337567f9840f3172,
+                    0xcfc046cbb071934e,
+                    0x2d1a32d88e223d5b,
+                    0xcf207bba50bd1d12,
+                    0xb9bc640f2fab1e5f,
+                    0x339d818f4b8363a2,
+                    0x32e6384108d87383,
+                    0x4e878e28468d26d1,
+                    0x1b6d3037b393e1b7,
+                    0x21a9ef832767da04,
+                    0x5dc78ae94c045643,
+                    0x0074c4ecd6bb19e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaf1583f076adecae,
+                    0xd1320effaa59c91f,
+                    0x4f610ff180e6d666,
+                    0x99731d2fb88920c9,
+                    0xae16bec83bdf97d5,
+                    0x872f006e4595e4ca,
+                    0xc73eeab71809046d,
+                    0x8fb91614a721fcf5,
+                    0x5d0b1961a8dde775,
+                    0x00e5e85e4d0897ec,
+                    0xad9146a30e43ae8b,
+                    0x006566ad87fd3d09,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb80f9035f30f2848,
+                    0x3b3869078f2d0abb,
+                    0x2d9875146d1a694e,
+                    0x34ac724bdca9a312,
+                    0x4eb7d70f7421b2e3,
+                    0x95102b70d3925b35,
+                    0xda21353c51fb9e68,
+                    0x3cabf943639ae213,
+                    0x797e62f6ed0940b4,
+                    0x6461c6aa6497d5bd,
+                    0x84c702deb26b3f35,
+                    0x011f23cd74b7464d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8969852d432e890d,
+                    0x16e1fcda57249e1e,
+                    0xd89cd22bf0758f44,
+                    0xded30eba711b6549,
+                    0x3e5250ebe813ecb8,
+                    0x637e5a41b57a691b,
+                    0x934b247a8df39e5c,
+                    0xf700b00da026aee4,
+                    0x721da79614379536,
+                    0xefedd59a767ebf3e,
+                    0x12ee7048f58362c2,
+                    0x004529ac1e064b0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2459e3812e9f157c,
+                    0x46d4927e26add78b,
+                    0x5e4a7ed554b40bd4,
+                    0x6d43a86fc5859f9a,
+                    0x46e1e15cbfb3a285,
+                    0x90edc8a104c5aa72,
+                    0x3002252a653f7c9d,
+                    0xf5b170a48bcaeeff,
+                    0x1de5b26aa8b47a50,
+                    0x06a9bfb9f9e35a4d,
+                    0xee072431ab6b88ad,
+                    0x003f4d0ea8533a15,
+                ])),
+                Felt::new(BigInteger768([
+                    0x59eee7de02b387dd,
+                    0xb376ee4ae2755f2b,
+                    0x786186fa9930c204,
+                    0xb9c6e3ea7ef5c705,
+                    0xec73a7f633462704,
+                    0x51a154564ecfdcfc,
+                    0x2366ca98076c0cd1,
+                    0x545d255c7f25793e,
+                    0xe4f76879872cbfa4,
+                    0x59f5d31daddaff3c,
+                    0x605a47885761f578,
+                    0x00440ef4e19e1622,
+                ])),
+                Felt::new(BigInteger768([
+                    0x875370aa7cfdd366,
+                    0x6c47c4477552c71c,
+                    0xb2b0daccf0700ea7,
+                    0xa785a50ce7f083ac,
+                    0x149c5ff4104f8d38,
+                    0xd6f8607694f7528b,
+                    0x662328ad3fe39f72,
+                    0x5dc06fb7035bbab8,
+                    0x98d82a2ab0ed5d39,
+                    0xa78951a4b93d85f6,
+                    0xd1ec1573c4b736d5,
+                    0x0033bf7ce7ecc5f2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x902db8d33dd01e2d,
+                    0x887c5426a3a23b8c,
+                    0xbaaf82154c38aba5,
+                    0x1fea20762c524e69,
+                    0x2705b514d00f214e,
+                    0x801452367ba20bfc,
+                    0xe31725f5cb15a08f,
+                    0xa8cb87081cd636dd,
+                    0x5293ff30d7c3ca8e,
+                    0xfcae0131b0433411,
+                    0x17d1e424116c9116,
+                    0x00ee933cd286e7b8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x182661f1891fb064,
+                    0xab32a843d22a0ca1,
+                    0xee82dcd665a4fb1d,
+                    0xdcab14e207e88542,
+                    0x7726086c764822ec,
+                    0x797d6f18dcc90973,
+                    0xad177a8273340a8e,
+                    0x82287bf57a483ea5,
+                    0x868c0f980126f308,
+                    0x2a016d0d2a6adee2,
+                    0x5e0eb7a96c9d1a67,
+                    0x003800bb16eadc1d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb69e232b3fcf1e7c,
+                    0x9c6b70c1fa382753,
+                    0x9d7245794ae19cf0,
+                    0x3a40dc925953d17e,
+                    0x97e3ea383f4aa0d6,
+                    0x102f40db3832b5c4,
+                    0x764cf71fd9f6e0ef,
+                    0xeed5c36ecbaeedcf,
+                    0xf70bb2e218a57302,
+                    0x2136460a33301a1d,
+                    0x43c43adb22edd8a5,
+                    0x00c715b5ad35a660,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf382451635936ee7,
+                    0x287e54a8bf2ce4c4,
+                    0xc6ae4bafca278434,
+                    0x1e7e9b10d81e5aa8,
+                    0x206d1be35c47c200,
+                    0x9183bc33e4839b1e,
+                    0x8efda692b815abc4,
+                    0x138372f9732ef7d0,
+                    0x352d268312ece007,
+                    0xe95e562927205d99,
+                    0x2a59c03262b26e5f,
+                    0x00bc5555c0945523,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6138e7c0964df18,
+                    0xe0d9de7845cc1a76,
+                    0xa9549a66ba45cf6d,
+                    0xc480788cbde8e7e2,
+                    0xeb963a73d6ee5e04,
+                    0x3a8fece62c04ed35,
+                    0x13ee20c691373338,
+                    0xcfa82ee9d16a3d41,
+                    0x15e12d124a256f6d,
+                    0xf16c4beec8cbbb21,
+                    0x5493058ebb8fcd17,
+                    0x00466e9affef2791,
+                ])),
+                Felt::new(BigInteger768([
+                    0x050a33fc5f110a2f,
+                    0x5dee286d78ca1fa9,
+                    0xdbdb2d748e18117f,
+                    0x123f7613133a8808,
+                    0x92dd132e26c46830,
+                    0x9916ca41dc717c1f,
+                    0x7419b4628c2d1ef4,
+                    0xab66aa48d339ff21,
+                    0x6095378bf2321360,
+                    0x8f9088bd91d88820,
+                    0x97902eaa13fbd619,
+                    0x001cad896795bba8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe8ca9b14a8e6570d,
+                    0x3aee67d144c4ef73,
+                    0x6866dc9c8c39b65d,
+                    0xa94c53140f8a0006,
+                    0x0a2177a1c5366f58,
+                    0x664c9217b416ed51,
+                    0x73f756271a6214b9,
+                    0x6b7f3adcb9a14bd8,
+                    0x89e8bda2fc884fd4,
+                    0xb3ec5e10db3db28a,
+                    0xd2b19400cd4bd190,
+                    0x006a54fbcb20a848,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd25c39584411c254,
+                    0x53ba2cf42b76cad2,
+                    0x4e709bd641442d05,
+                    0x8ad21d64f05b573e,
+                    0x42b44b50e6fe1ae4,
+                    0xced139a4a7f0b46e,
+                    0xb69853f67a8403eb,
+                    0x37ae6e01e588da4f,
+                    0xf7c48ecc1dd79dbf,
+                    0x14a7ebb7bfa5e5b9,
+                    0xf12ef7abd10931e3,
+                    0x009c15dea44fe254,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc3810a67326ccece,
+                    0xf45eb260c86b72b8,
+                    0xf55bd5c83b453821,
+                    0x099dcb6e84bde1c4,
+                    0xfb95db029a43acf0,
+                    0x07c6949d54b07384,
+                    0xf8b0c680294f7c34,
+                    0x1f47913a66bdfe05,
+                    0x5c0f416949f085da,
+                    0xc75d619d13795d88,
+                    0x38f407a99383bc0a,
+                    0x002cb6f70da859bd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbeff71a695ca341d,
+                    0xa8f4dac284081504,
+                    0x29ed60682a7b8f9e,
+                    0x9e799a8a1f097cb6,
+                    0x3d97c13502ef86a0,
+                    0x76e07727df46ea30,
+                    0x21ad4f21c7e81cb3,
+                    0x2d9a7f53805dd6e5,
+                    0xf1ac9e18131cd9d2,
+                    0xf2f5130eded7b6cc,
+                    0x3c6c9b65e44acf0a,
+                    0x00e292fe33ca8d6c,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x57bd3e7d99b1fa3e,
+                    0x9cf38941c4b99fae,
+                    0xffd4d2e1e8adfece,
+                    0x13d9b9e2940ac6dc,
+                    0xbe4e9de9d34a1730,
+                    0x343ad28e6f24908a,
+                    0xa3cd0c597e672c55,
+                    0xab272832e1589845,
+                    0x656a8e9f7bea2ce3,
+                    0x6e632200065c9d3a,
+                    0x565fe5ff0a285745,
+                    0x009d9b155870326b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x100bcecbbb6b66c0,
+                    0x3c72f542afa17b3a,
+                    0x704e845f53dcd0ec,
+                    0xe9a922db59e4f49d,
+                    0xd8c830c3c76c0be4,
+                    0x2bd1e1ef3c7c6daa,
+                    0xfa822fe85fa8f6ce,
+                    0x74f5a13fce22b522,
+                    0xc9e619a8d4e216b2,
+                    0x3e1dc0d99a2d03f4,
+                    0xf1c2b2d42e52462d,
+                    0x00acbb61e885f34c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f59804723e6dda8,
+                    0xd0b1ceec9e4e17c2,
+                    0x9b6f7d75eed6fdcd,
+                    0xd45ef4e7eee6d797,
+                    0x811f69758cde82bf,
+                    0x94087cb9e63de416,
+                    0x8ad89663beb9f8ae,
+                    0xfb8fe66ffaf1a4d1,
+                    0x75126a4c00ceb6c9,
+                    0x3062ccaccb05bc9c,
+                    0xaa17351ffae5734a,
+                    0x011cb74547055de6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2b954533354fdcf8,
+                    0x8ba24e1af3c6fa17,
+                    0x8221000cae31471a,
+                    0x81ba524d9a76c563,
+                    0x8062f7104b4c9c78,
+                    0xfb4c1e9e96f02512,
+                    0x8ab7b442b9af7ef8,
+                    0xf520a51789adc210,
+                    0x08e3bb7b4d26977c,
+                    0xd227195184783bf0,
+                    0xf72facf92f1bbb77,
+                    0x0077fa0a39056b42,
+                ])),
+                Felt::new(BigInteger768([
+                    0x329896c053b7602b,
+                    0xc49e2f70c15750d3,
+                    0x5e41455165f36041,
+                    0xae7682218b1fee70,
+                    0x4fd20f10084db0b7,
+                    0xe72738ed338449e8,
+                    0xe95074bf973bd2bd,
+                    0xbc69d84e7e847602,
+                    0x5e1a40aa435ccff9,
+                    0x23a363991aa237d6,
+                    0x0397786d343012b5,
+                    0x00e549ab37cb1bf1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x000bfedd9f56b67a,
+                    0x83fd4d6ceb094773,
+                    0x8b083ba1eda94fd3,
+                    0x8d7837d84b3ce294,
+                    0x1d5c76cc3d1482dd,
+                    0x7220512d50fd2959,
+                    0x832322dd5ff2133f,
+                    0xd28b90d9f58b9ce8,
+                    0x55cfe3d566b540bc,
+                    0xf3d22024437944ca,
+                    0x6d197b06076076b4,
+                    0x011826f7cb18a61d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc1fd1cf76215619e,
+                    0x067182403d1c3201,
+                    0xe9b6812d66bf1a4c,
+                    0x8b63ccfecbaa47b9,
+                    0x1df65d4711f55dea,
+                    0xb6e1c88828e32719,
+                    0x236e17434cf32876,
+                    0x1f7e66a5eea450a8,
+                    0x4a3bfbd4e2ffc425,
+                    0x9bcf93e22ec72d0d,
+                    0x377b29c73206b30c,
+                    0x00b692614e074b00,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1bfd84c5d772461d,
+                    0xd42f888dbc64ef3b,
+                    0x2fc1cca7d8935f19,
+                    0xcffa4ca4ad9f8b48,
+                    0xe5cdb4ec38f2cc1a,
+                    0x65b53190a5c70f3d,
+                    0xfc426af7f8938a24,
+                    0xb10a2d5da433f523,
+                    0xb1fb637587229477,
+                    0x279cd93e583ba138,
+                    0xdde6ab8ff3618623,
+                    0x000fdb6a3901fa63,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e2c1bb25d1ef45f,
+                    0xf61eb02ab6d4fb0a,
+                    0x287baf6cdde87fbc,
+                    0xa97d8954f48b5479,
+                    0xa9a85e601f2ae6bc,
+                    0x005764a8658b9ac7,
+                    0x37d014a3453849aa,
+                    0x4c347649ef56f92d,
+                    0x679ca2d629081f30,
+                    0x7f641d8c630656be,
+                    0xa09092ffb82ff4fb,
+                    0x000e49b992653304,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44e0830b07a334d0,
+                    0xbfa8ae9203811909,
+                    0x23d7d2d8d6d5041f,
+                    0x348131a84ec42723,
+                    0x948fb7ee5ac73a77,
+                    0xb45471bfd59fd76f,
+                    0x6010ed57c5626715,
+                    0x09170cb1ab89c70d,
+                    0xc79e3e9ce3eed9eb,
+                    0x3563644905821f1f,
+                    0xc4465bafd0f5597c,
+                    0x00a97f24cf73f0ed,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa982f248298006ba,
+                    0xa332330a387584c7,
+                    0x95d1622ff4257c7c,
+                    0x753b74aa29991ca3,
+                    0x4c0248990ecb9d53,
+                    0x5b555079c6b147af,
+                    0x5499f736406ff742,
+                    0x49f677b20a1164e1,
+                    0xce8a136edee28cb6,
+                    0x269264042cc07fb6,
+                    0xb41f40364eec3f22,
+                    0x004204cde658433c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa31982bfde329805,
+                    0x78f2a755c4d89fca,
+                    0x25c88708944d4112,
+                    0x6d3062f94d5e4d44,
+                    0x11eb6387e24129ec,
+                    0x6e1dcdcf7366cd56,
+                    0x0a5770543408b0d0,
+                    0xbb65f26afff0b8cf,
+                    0xf5fdc56837cb96dd,
+                    0xe21240a6f285da40,
+                    0x0d6f3ecbfd0ad333,
+                    0x00706dc8c56afbe7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x07b8edd4fc1e39c0,
+                    0xed8df7f41f56014e,
+                    0x1dd2a645a3d5b03a,
+                    0xe615692cb7c0e279,
+                    0x3e3ed1a74cb4d11d,
+                    0x5b714bd6b7c62c5a,
+                    0x3b1f50bd0ad7e688,
+                    0x3f3cb6b318e3519f,
+                    0x763423fdac371faf,
+                    0x238c7bdba6da856b,
+                    0x515cf63e6471ae91,
+                    0x005c2e04b16182a6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf8c798042e18417c,
+                    0x3a0c5f51bd0c360f,
+                    0xcaaeee7691cf8afe,
+                    0x925e8ca93c0aa367,
+                    0x0c3ed5b82a0746fa,
+                    0xbb90c97d13e7ee12,
+                    0x333f7b52072c5e92,
+                    0xac9b3a7a7d13a9cc,
+                    0x4f590101a6a2eb97,
+                    0x97bc8087766e676f,
+                    0x4c07cfb4e0101a10,
+                    0x005fa6a38e603d1a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa8f7a2645135204c,
+                    0xb902b3e096a2dedd,
+                    0x4f9d6e223cb305cd,
+                    0xada955fe8f14a54e,
+                    0xb2ab17068396ce94,
+                    0x540dc08d8e001c65,
+                    0x48fc7df533957933,
+                    0xff1291d8776d7292,
+                    0xe8bed086b46931d1,
+                    0x80156043d4e8b7c4,
+                    0x2f53890dae039fa3,
+                    0x01155e44da610a88,
+                ])),
+                Felt::new(BigInteger768([
+                    0x78a216da668eccab,
+                    0xa2dfcf0abf3543bc,
+                    0xa5511a709be51668,
+                    0xef29c5e013e6a5d4,
+                    0x46a2026da6709b44,
+                    0x0d68b817a4baec00,
+                    0xb7bafaf5b3a2ea9e,
+                    0x0a280952c06e1b26,
+                    0xf4253abcb5d41ab7,
+                    0xcbd2a5ecf8f8ff7d,
+                    0x929f68700bca440d,
+                    0x0049d616d446fe2c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5fe34871437c9a93,
+                    0x71550d9e2a803d2f,
+                    0x81dbc4757ea4cafa,
+                    0x3a455ae878bb5419,
+                    0x4bc35533cb6024e8,
+                    0x30232517acf2aa2e,
+                    0x032b071a6beda47f,
+                    0xbff6049833479ab6,
+                    0x0875f38a224972e0,
+                    0x084f1baa3313b8fb,
+                    0x8b9e26b20d433d25,
+                    0x0029bfca1594d5b2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x48ee09986762821f,
+                    0x42aee38c98e53865,
+                    0x9cf10039c56b95ee,
+                    0x50b093392d4b7f79,
+                    0x52bbff07bee224c3,
+                    0x9d6c206d0fc8cb18,
+                    0x4280cbc7acbe586f,
+                    0xe2934db6e3b1f57a,
+                    0x1bfb5d335d893647,
+                    0xfda276932c1d9dd8,
+                    0x95a2dff9c154bffc,
+                    0x0024454b6d7df9a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51ea9534ce2bb867,
+                    0x78f242cc80bb657f,
+                    0x84c5ad53aa2986d4,
+                    0xe1d959e1e3161a92,
+                    0xbbfd3a78dd3fb444,
+                    0xd7921a08fe8e2056,
+                    0x57902d45e4b6a673,
+                    0x3cec980df600d0cf,
+                    0x7680d88f9e8621f1,
+                    0x65c6299d452a5028,
+                    0xa776c9fb47a41d58,
+                    0x00cd707ac8275916,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05acdca193fba8cc,
+                    0x26dd967ebad04bb0,
+                    0xabe47ef2611191a4,
+                    0x68ff29312f5c94d1,
+                    0x427a8d7a119ce8a6,
+                    0x95f85c8abc888216,
+                    0xbff58a2e91afddf7,
+                    0x75a25bd4f289c739,
+                    0x63eeaefc36c07e1a,
+                    0xf8fe74cef47e782e,
+                    0x0a8227479231122f,
+                    0x006bfdda7ca60d4b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xab83a6e84c5e157d,
+                    0xf1509c43e4c1d218,
+                    0x47c741936f77641d,
+                    0xea179bd2d624c536,
+                    0x71bd4c62e8679348,
+                    0x21c25a38a1b79693,
+                    0x2f5fef0aad339696,
+                    0x02cf4796e46562d9,
+                    0x8d6a6b607515956c,
+                    0x1c3de39525f23ca5,
+                    0xd6882cd1827c24e2,
+                    0x00496b8f12155c03,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc293ca75c145dd22,
+                    0xaf948f70278cf2b9,
+                    0x62315123c6f4a044,
+                    0xe16cf633bb157c3f,
+                    0xf4c5f92b9262f7ff,
+                    0x7d99fe2558f31883,
+                    0xb58b60648fc16c0a,
+                    0xddc52e63e0f5d130,
+                    0x1ae1515dac7824c3,
+                    0xb534b4e52e433c82,
+                    0x3c5f9e62ba16a407,
+                    0x00e35d958cdab557,
+                ])),
+                Felt::new(BigInteger768([
+                    0xefd5fb86b6e9cfcd,
+                    0x86377bb15e3dfa05,
+                    0xa3f5fa7f47b258d0,
+                    0x359a2fa7618818d4,
+                    0x927aa3dfb8d7e6bf,
+                    0x8db6dcfa4140dd6b,
+                    0x9e67c4adbc291713,
+                    0x0384215062139b16,
+                    0x302b72d535947848,
+                    0x6c7ee7d3d9a2fb06,
+                    0x6bfecc1854034ccb,
+                    0x0093ad9d3be198f2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf887c9c98d7086e8,
+                    0x5eec28852fa1b1e3,
+                    0xd732931c67d958d8,
+                    0x4f4f377d25171448,
+                    0xe1a809b695091250,
+                    0xec1074cab91f4cb3,
+                    0x45046588c668e907,
+                    0x2c090ed8b3859fa8,
+                    0xeda76d04d74ef909,
+                    0x767126bcdf6f70ff,
+                    0x5c8ddcc1fe26b5cd,
+                    0x00706de2b4929aad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x64197558b6fc8872,
+                    0xb9cc0fe6e688afcf,
+                    0x4d6407e82bf97c30,
+                    0x0dd6be822bf7bb49,
+                    0x782eaec037e6eb9c,
+                    0xd14b152ad4110522,
+                    0xf7968df1b8f42c90,
+                    0x66edb78261517640,
+                    0x35e0078e04409d15,
+                    0xcaebf842ac0433f6,
+                    0x384a96b3f1807c2c,
+                    0x008bd0d2c69b3941,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5bf4f47acc23f040,
+                    0x068a34392e15619f,
+                    0xc1dca9a09ea677e9,
+                    0x0391fca5ec77b5ef,
+                    0xa2519f9be4ed9de2,
+                    0xc5c2985312b097af,
+                    0x77c6469207cf5ce6,
+                    0x6d989ca9439cf2d6,
+                    0x787a343b03d4d52b,
+                    0xd3227a5b335e466a,
+                    0x629b8a081f644f1e,
+                    0x011b420ee6c530c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e8f849baf214e2a,
+                    0xbd69b5b9da9aa466,
+                    0xc33aaffbe3f35a6c,
+                    0x11ff2fd345f1c376,
+                    0xd8623c50fc000297,
+                    0x5fc22d61d7eea004,
+                    0xac8c3207850cdf77,
+                    0x694ec0afac8df876,
+                    0x2180783cea0661b1,
+                    0x23ca016676672e3a,
+                    0x7cdedfd3fc940e91,
+                    0x00c85757ffacceac,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd69f25fea212a9db,
+                    0x9bdc9429d08834c9,
+                    0xde7f59cd0f71e6b6,
+                    0x0bd4264d97b4b8cf,
+                    0x635ebf5864ab4827,
+                    0x3a7d66340bef31b6,
+                    0x190e527fedcb0335,
+                    0xddf96da2336d4b4c,
+                    0x4bfae174e5992996,
+                    0xcc067a4600cd813d,
+                    0x53009c204fed9d81,
+                    0x008f8d20dcf547b1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89194ec4119e372b,
+                    0x403c42db05b1d196,
+                    0x46be684cc9aba3cc,
+                    0xd3d81db8f971281c,
+                    0x1b076abfcc4ed5aa,
+                    0xbae1551ff3b518da,
+                    0x5cac33a1dc625959,
+                    0xd6699329204bf079,
+                    0x758448041f7d96ae,
+                    0x2721e0de720e1a9e,
+                    0xdde7a56ed133409d,
+                    0x00178a258d54b625,
+                ])),
+                Felt::new(BigInteger768([
+                    0x914976aa18828c77,
+                    0xab177e5eaea261a4,
+                    0xc71ed77f3770bcca,
+                    0xa4d35b5da6fd4e95,
+                    0x4c8a2b10d2b93ff0,
+                    0x956435cdfdcb1d0e,
+                    0x95e20ee927b6876c,
+                    0x8b6cce22153011de,
+                    0xb431fc5b218f58a3,
+                    0x4f3aa50988678fa9,
+                    0xf16c14cf586b5643,
+                    0x0017e2631cd1aa40,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5f5c30b6cfd907d5,
+                    0x0ce92c6bd5708835,
+                    0x2c62bc96b61e46ae,
+                    0x5401d0901429bacc,
+                    0x85c9eba07adcd1f4,
+                    0xb30aaa54f231ab84,
+                    0xd2088e144171d26e,
+                    0x2a5c1006ee669082,
+                    0x661abd6b870d8cd7,
+                    0x14e2040fb35e5562,
+                    0x8bde5fdcd0027989,
+                    0x008c2ed96b90f6b0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1608ae1ce766cb14,
+                    0xe59ed92c5fcb5f2f,
+                    0x3e9e199ff2dedcf6,
+                    0x24448a70aa823a61,
+                    0x65ba29212a41e1a4,
+                    0x011c58f978ebf808,
+                    0x0541069c024bfaf8,
+                    0xdf7011746a7dc583,
+                    0x27af2699afc1c585,
+                    0xe13479e32f1f60a3,
+                    0x6143fe66b815528a,
+                    0x00319eebabd7f4ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9c2a0f2c41703a38,
+                    0xfde12ddcda11c0fd,
+                    0x0e0964e5ae120b6c,
+                    0xabef39e302b189e9,
+                    0xac70e5d5bb3c7edd,
+                    0x33b09ab69708b56e,
+                    0xf238c282fd46970d,
+                    0x78ea490fa0e7330f,
+                    0xc85a81bff8151bdd,
+                    0x255221ad991db38e,
+                    0xb4885228c97cd590,
+                    0x003cbae3ec542f7b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa490dbaa826ac9e5,
+                    0xc84f4b1d6eb6c2be,
+                    0x81d96f0c5fbda49a,
+                    0xad657c7e5d9c7889,
+                    0xf4ca8759bc97c30f,
+                    0x1a28b6d087ef2599,
+                    0xa931033d532e7c49,
+                    0x1b3ecc2cf5908b4b,
+                    0xc68255f600ea825c,
+                    0x1a94941b648b83c2,
+                    0x8490ca37e4428bec,
+                    0x009a0e8066fc1c6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4763aa7bec359f2b,
+                    0x79f9e7ab23fc8f07,
+                    0x1e262dc9057a9611,
+                    0xef1b74a2a072d3d4,
+                    0x77201f381b22d45f,
+                    0x5a7c1312ca252cd4,
+                    0x000572617da7cc5c,
+                    0x29e510ec07316762,
+                    0x813ff197481e32ad,
+                    0x3518e0be51137419,
+                    0x47ba6336693833bc,
+                    0x010dc30fe0dbbfed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x272e1691025c21f7,
+                    0xd3f6d2bf911d1067,
+                    0x1a8304209cc882b6,
+                    0x9db24a4273e334da,
+                    0xa3c4aecfe680a6fa,
+                    0x09eaed61597af777,
+                    0x138a6153f7eb462d,
+                    0x090f708d81e85317,
+                    0xe2a1b4f8512b01e7,
+                    0x2efad070bc397cc9,
+                    0xf1322e1fb3bbb816,
+                    0x0003782b87a89ee7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x83ccc6cef8c5bfab,
+                    0x640fe105ebc12cab,
+                    0x340f5b5a10a1c173,
+                    0x3b132a5893345efb,
+                    0xee553b6faf433cc0,
+                    0x0dfc0f9e3f67133d,
+                    0x1b931aca742d813d,
+                    0x305d76b265397aab,
+                    0x824577b7644dccde,
+                    0x6d08b971977f60d1,
+                    0x5defeaec24f8a718,
+                    0x00d9fe32ab746846,
+                ])),
+                Felt::new(BigInteger768([
+                    0x012a3c020705ab34,
+                    0x7303b70bdcf201e8,
+                    0x15d9855768e18b90,
+                    0xd4319caaafa5d594,
+                    0x829bca467a37607d,
+                    0x54bb482f14c2987f,
+                    0x729cebc741242c6a,
+                    0xf22f40c94a37b92f,
+                    0xee2a944c220824ef,
+                    0x10d0832b725224b0,
+                    0x11b0e7b31d16304b,
+                    0x0091ecfebd714531,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde39911a12105055,
+                    0x8e0de984826d13da,
+                    0xbe7e9aef442a9ac6,
+                    0x3b377f5a34dbeeb6,
+                    0x7cf027f7fd1d7ea2,
+                    0x8778763689d9d97a,
+                    0x399496957917e226,
+                    0xd81eb299fd0a4c69,
+                    0x3ed24b97180b6e23,
+                    0x28949b50ce779276,
+                    0xd1da0410a14324fb,
+                    0x00d990bddd6298a6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd3e903cb0c1ab34a,
+                    0xbfa345107e4b9d3e,
+                    0x3ebf5ae913aac69f,
+                    0xb737a88a5c08fea7,
+                    0x68b40904dd2b66a3,
+                    0x204aaae322f22f89,
+                    0xc6961e35775bdfd5,
+                    0x1bd428ad367b0f2d,
+                    0xdf191f5db4881c7b,
+                    0x324dfc0a1565598f,
+                    0xee2997d9a921bba2,
+                    0x0023048e09b77d98,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa6eaa9c685d467c8,
+                    0x2e5c1d6ec89571d2,
+                    0xdcf097073e46acab,
+                    0x73cdb379330109e0,
+                    0x19333f31ac7ae93e,
+                    0x202a2f2ad56f3342,
+                    0x9748c987e9688186,
+                    0x515a4e8ab334bf2e,
+                    0x3728ddfddce4c463,
+                    0xffdca8777226f070,
+                    0xf69d93079efe94be,
+                    0x00aa10870e114969,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe601453d05d169ca,
+                    0xb2ae4f3996163f4e,
+                    0x48456701ce83b182,
+                    0x5a5ba0d0d44daef6,
+                    0x060b1efd946c48b2,
+                    0xa43eff79dda18d3d,
+                    0x6cf25b44791bc9f9,
+                    0x20123a95728648b1,
+                    0xf0fbd4674c2ec043,
+                    0x0d7cac2943eb3cbd,
+                    0x224b9b11a5747f4d,
+                    0x010ba0738626c790,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44e9fe8d257dea01,
+                    0xfbbbb5b9e47d8436,
+                    0xfb0b7ab79501c60c,
+                    0x79f8bd1d845052ec,
+                    0x693241cb2c1f8c6d,
+                    0x1a852c05d024289a,
+                    0x393fa79b86fb5531,
+                    0x798cddebda0a0fdc,
+                    0xc729439955b1bbd7,
+                    0x6bd8a2ce6d69dceb,
+                    0x50507d81c641f5d7,
+                    0x003b78e0e8c53ddf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x29f655f5c6024ef7,
+                    0xa983114841bef7d9,
+                    0x9bfff2d878edad51,
+                    0xa3a0a86187e44140,
+                    0xc820abb4f386e123,
+                    0xc7759b4647cb291e,
+                    0x1ea1a9862232296b,
+                    0xee40a3fd86d7e8c5,
+                    0x3add3403ea81f3cb,
+                    0x24ddd8448acbe6b3,
+                    0x28fc01751530dca4,
+                    0x01179bb732c3253e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x076cb240e7e43663,
+                    0x40a5b949248a7aec,
+                    0xcbb42e3850b375f3,
+                    0xb3610b23badb3c34,
+                    0x4ab81f352bcc738a,
+                    0xab712d72cfe65e6a,
+                    0x9615ca3de9540c65,
+                    0xbe332bb688484c18,
+                    0x87c2ec53557dd1f5,
+                    0xce6e8a5de810baba,
+                    0xe80c48a174aa4046,
+                    0x0000120d5fbc5e34,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4526eee1f5e8ae4,
+                    0xf823d067655a87f1,
+                    0x34165805e5a19cc6,
+                    0x6199607f83cec2b4,
+                    0x6b7505bf2feed56d,
+                    0x54b50356f909fd1d,
+                    0x5eefdb26b6df2561,
+                    0xf33f474a8f43b5ac,
+                    0xc590bec5b5f0bdd7,
+                    0x55aee53b16becce2,
+                    0x0447f090f498ffcb,
+                    0x00b6bee47dc31dcd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6ab2ee63e4ecfb39,
+                    0xf8111aa2e8c39b0f,
+                    0x0d0127e01bca5b7b,
+                    0x7bceb83a7d1e89f8,
+                    0x2d8d9451b6d0cdb1,
+                    0x45db3203ac2f8d7e,
+                    0x03415d6a982f2e28,
+                    0xd28777c72c3e7243,
+                    0x3e0f43ba252e693d,
+                    0x3e8d669f35d82f76,
+                    0xa68333e28da1edd8,
+                    0x0088b176ffb0792e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe064947988f601de,
+                    0x4b72554e69079f99,
+                    0xaad15d7c3625e9eb,
+                    0xf41c13e0bf10d74b,
+                    0x12e9acec9cfad49f,
+                    0x5a3b3416d9b72ed8,
+                    0x66127236c7d1aedc,
+                    0x3157d772be9e86d2,
+                    0xf1fed39b35c9a235,
+                    0x7579ef30696c48a5,
+                    0x5c0a2353ec3f9e77,
+                    0x0056c098c78c461a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb15c9e7d0de1ed8,
+                    0xcd3770ddbac3decb,
+                    0x40e3e06cc8d30a6c,
+                    0x36a8b9087e986f94,
+                    0x03dfd7019c663f95,
+                    0xb5833f8c90eec07c,
+                    0xb9e8d9ba5eed8b47,
+                    0x12b864e9e3651130,
+                    0x4c514a7aab448eb4,
+                    0xa746b59c599a9113,
+                    0x790301afa2fe9c61,
+                    0x00b4fb53225ce2a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9ee30cc0e72635c9,
+                    0x24328e497f807e25,
+                    0x41e0bcdfe4b55b9d,
+                    0x5c455be56634e4ad,
+                    0x6a5937904dd99307,
+                    0x7124e74631a0df22,
+                    0x34511211192e86b7,
+                    0x4ee4535fbe8b9ceb,
+                    0x144b52d19250f2dc,
+                    0xeeeff3283aef3038,
+                    0xcece9f1e6c9637ee,
+                    0x00a88e8d31466054,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe13b1d66bb1225f8,
+                    0x6e49d4bc419a6467,
+                    0xae26461af0f9b559,
+                    0xb03773d4e66b32a3,
+                    0x5d47729295871c30,
+                    0xb1e609107257687c,
+                    0x1a4a2cebc04f8df2,
+                    0x1c90bd99482b8635,
+                    0xbd0354be81c99745,
+                    0xe9c8220ad958eef1,
+                    0xdd5237cdbdc0d0ac,
+                    0x0111f7b0a8bbc8d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae37506d4c05c313,
+                    0x05f2e474774025ed,
+                    0xf3ebeca0e365ccd9,
+                    0x50254e91befbddcb,
+                    0xfb82c7211809d176,
+                    0x80ab34467aad0980,
+                    0x15ee11e85acaad13,
+                    0xefc06874d32dca74,
+                    0x3a6b4ae8253b0711,
+                    0xb9a5a593ec45fdae,
+                    0x2c4131abb87f39ec,
+                    0x00d49ca07d46e86d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ee79eca1cb0fa95,
+                    0x43ece8db1065ac17,
+                    0x7d8b4139eeca5322,
+                    0x8bf2a4b41f1e110f,
+                    0xccb6656fbf6dd747,
+                    0x4db05a40d526f593,
+                    0x6922ba1b4186939f,
+                    0x114e4f89114fe931,
+                    0x9ced6f2da01fe5c9,
+                    0x69a7bdf78781fc06,
+                    0xd35f211fa3bd0e17,
+                    0x006451f31bc94e1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xffa5150806e853e9,
+                    0xb2f97608f095e31a,
+                    0xdd74de8a0265d758,
+                    0x3dda1a6374a18fb2,
+                    0x91bc9b7356bcf7b5,
+                    0x7e68cefa47fbd224,
+                    0x550dd0881a314e05,
+                    0x89150dba05d87071,
+                    0x2ff1fe8ddf87c130,
+                    0x07a44cd448fcdeda,
+                    0xe07092e27b8ea0f8,
+                    0x00b4e302133bcfd2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda7a1498c2b8f161,
+                    0xef627dc36c182829,
+                    0x4a50082138873da5,
+                    0xd21151da52d0dc2f,
+                    0x3bc3c500d3568451,
+                    0xe90fb071c2c9ed0b,
+                    0xb8265dabb8ee6409,
+                    0x374eb3765a7996a3,
+                    0xe984f94c70623aa6,
+                    0x54152b1870fafb79,
+                    0x33f902336d6ef81c,
+                    0x0037e864550bac28,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f1fac3d22d6da3a,
+                    0xaf24982b9680517f,
+                    0x9ec72dd5e43b7e87,
+                    0x834fa7527b5eeb18,
+                    0x2b727358ddafb5c4,
+                    0x8ca53009a16b2890,
+                    0x6497a72587b83012,
+                    0x85327ecdd909f255,
+                    0x81b3d3541daebaf3,
+                    0xd7e0abf607cae396,
+                    0x3fafe2bea1eac6c6,
+                    0x00a4b01e0c5fdcd8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2b711fbe52d1e46,
+                    0x3a501142916a8ec1,
+                    0xa14cef80081bc1a6,
+                    0x45a5575dfbd4a6ab,
+                    0xc2c09e86ed37c0dc,
+                    0x3f3d097d4097a405,
+                    0x54a86227f525ff82,
+                    0x61db77e82b01faad,
+                    0xde035ff64fc5a9de,
+                    0x355ecdf5a5bcd7ae,
+                    0x6de16f6973fa01be,
+                    0x00bb5dd7ae7fa83f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad3c164146e493c9,
+                    0xef3de488181e751e,
+                    0xc7b0d45262250b5d,
+                    0x69d078679a37c04b,
+                    0xae63b08551fde109,
+                    0x010d8c643d6d6bba,
+                    0x221f74870bad35c6,
+                    0xf8a64ba21d3371de,
+                    0x6afae928d25bf66f,
+                    0xe14751c62ed801e5,
+                    0x5f6ff512c354b92a,
+                    0x00268492ee625886,
+                ])),
+                Felt::new(BigInteger768([
+                    0x56fd2f755892fd07,
+                    0x4d69bd5056c53b30,
+                    0x5be6bb35883b1985,
+                    0x87687fa6e382d2d5,
+                    0x5fee92849e8026f0,
+                    0x491bed45a9a38cde,
+                    0x86033f437f9c11f7,
+                    0xb7f5a516e2eb3e78,
+                    0x0143801d30d6085a,
+                    0x1464ef33d49c68e9,
+                    0xc63b1b21f3ad1446,
+                    0x011e284c58ded35e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x660e6e32a451911b,
+                    0xa7c6b8da37451f51,
+                    0x83901007390ed84a,
+                    0x0ffd5ce68343e579,
+                    0x4233b9cd66b2727e,
+                    0x3d63072451475be9,
+                    0xa2a9787b4adba336,
+                    0x2bb1767667723dce,
+                    0xed95a4155e73da45,
+                    0x030448fabcc86414,
+                    0xdcbb3cf0f6c76087,
+                    0x007d0e2d9b1fbf26,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe5bfe34482d7dc3b,
+                    0xfb4c5dd3be3d7163,
+                    0x1a2ce7459bdce88c,
+                    0x226b745136a53992,
+                    0xf2f2a716e6d6b0e0,
+                    0x0d2a82410031232b,
+                    0xcb8092b0802b051b,
+                    0x137dcc5d5aa5f158,
+                    0xfd9162664ec0b1d3,
+                    0x5a8acf2f724fefc7,
+                    0x8ab76f72e50f8e67,
+                    0x008796bf720ccc2d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x546cc118e391ac81,
+                    0x5151aea67ebec0e6,
+                    0x3c56f0b3518b0a46,
+                    0x8a4890c429fea1c9,
+                    0x1e11d30c45c9e57a,
+                    0x0eba5cace3f20fe2,
+                    0x3d188651cc68fa4e,
+                    0x369803b55a414b34,
+                    0x90b7a2ca5bb65de6,
+                    0x0e238c2a7234d1d8,
+                    0x009cd395ec939c06,
+                    0x006dd934b0d1f7f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x518a4ba0cf88cd1f,
+                    0x5c7f52abe0e7587e,
+                    0x3621f55dd04b1c20,
+                    0x6293d28cc70e8039,
+                    0x554c581adc056854,
+                    0x69fdd0d7790044ed,
+                    0xd8df89b3d3e8033b,
+                    0xf6b2e6bb6ef948be,
+                    0x01d1de4fdeb12b2e,
+                    0x437741de17d241f3,
+                    0xeb59f1298addecb8,
+                    0x00c6f262defd916d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4634b07aaf62d1e9,
+                    0x88e3bd19e99e88fb,
+                    0x83d03b0b89da6519,
+                    0x915aecd0e18a26b3,
+                    0x0963c1173010e9f3,
+                    0x5856653b09780b65,
+                    0x0cc3eec6a32b18c2,
+                    0x542d2a6be843ffd3,
+                    0x4e3da95713112b8a,
+                    0x08e6b279ac869e88,
+                    0xf4d45ea3560ae4df,
+                    0x00c2a4bf573cbdac,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c0ec2ce84e69a9e,
+                    0x7565a2cc328a2876,
+                    0x18f98ca46adc8660,
+                    0x9905a143cc75975c,
+                    0x1f4e25ffb3b15c15,
+                    0xb07a559426d70dcb,
+                    0x1f0ac8614303129b,
+                    0xd4555786f09f342b,
+                    0x2e5cdfee5ae06781,
+                    0xf5f5cb764b0a851b,
+                    0x816cc930248a340e,
+                    0x0068f00bb4c392f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf52920aa61b9ecb3,
+                    0xf21634ba551e0702,
+                    0x7df8b0f1496cba02,
+                    0x4c64c3d50b3ce041,
+                    0xf0775fc8eee0bb18,
+                    0x0e5d20ae52dea13d,
+                    0xf00b9628dc73f93e,
+                    0x6bc276592df16628,
+                    0x57aa215a4735f3d9,
+                    0xffd4e6acc4c85654,
+                    0x1c6d4e66e01d4d3b,
+                    0x009c6b129981a4ad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x67eebcfa856fe18c,
+                    0x3b38132f1ec35c9e,
+                    0xbab00a8627751718,
+                    0x286e624fd938f43f,
+                    0xba1bdbe426f98fc0,
+                    0x1640eff38125970f,
+                    0x16207b6727478fa5,
+                    0x2c6ce769d8b454ee,
+                    0xdbd594515db55b14,
+                    0x3d9b9fa77b391c92,
+                    0x4ad8efb6954f52b6,
+                    0x00e4ac5d2255e2e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb00f857b93704c7e,
+                    0x9d3b5e0c016bd58c,
+                    0xe747767d1f24d9c2,
+                    0xd919cb0032a324af,
+                    0xf6a601db0671c84f,
+                    0x1bb00c5b9d389396,
+                    0x84a1377e034b0cab,
+                    0x067ad8fa943052fb,
+                    0x163ad39b1995d620,
+                    0x65e30d219fc22146,
+                    0x1e9ce58a78de5042,
+                    0x005ec004e6aed220,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb8aaf2767bb5a75d,
+                    0x1fde8493babc3590,
+                    0xd4a442890f91fa1a,
+                    0xfee2b7d7ef0779d8,
+                    0x0b485b263c2bce46,
+                    0xe434e6882cb7b2aa,
+                    0x00972383460561fa,
+                    0x19eb2ecb9fb63d26,
+                    0x0c303770b4c479fb,
+                    0xecd20425ba452d15,
+                    0xd4e348e0b66c3920,
+                    0x00c6667539fe5a08,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5098c0aeb0501498,
+                    0xbe4e0d2b4e047d53,
+                    0x8942fb3c0d11a0ac,
+                    0xe0ffa2bc55ad18f4,
+                    0x436aadd918428423,
+                    0xbb45d949a4e51a3f,
+                    0xeaecfa22bda2a7a7,
+                    0xc3ef8393857ee06c,
+                    0x33ca4d6662868668,
+                    0x5eb6618960dae91b,
+                    0x29ac3b6fd0d09a05,
+                    0x0014dfed80415613,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4d93d37f0ddb51b5,
+                    0x142a11db503f4995,
+                    0xeba76952dbd69566,
+                    0x2a6913efbcaf9035,
+                    0xe391fc5170b2090f,
+                    0x2a16bfb9fe312bcf,
+                    0xfb0de849c434ab9c,
+                    0xf4954643543c1032,
+                    0xd7640968ac9b46e5,
+                    0x45acfa0ac24a61df,
+                    0x7d86bd4c98489374,
+                    0x006c2f41dc07764d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1baa95d93cc6a2cb,
+                    0xe5a82a994ae707b9,
+                    0xa51d40aeb3e6ec6d,
+                    0xf92c40d33fcab347,
+                    0x8bef1f608e1639a3,
+                    0x2db3053dedb2ba06,
+                    0xb602bb3f7ebdc4e8,
+                    0x5f9d0aea9ae8f4c0,
+                    0xdf83da656550eb53,
+                    0x4de07afddc0cc08a,
+                    0x522528c2e506628a,
+                    0x0114430a1754ebbc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d1b4d0014ace193,
+                    0x41a9622e32ae7151,
+                    0x00fa6cffbef803e7,
+                    0x6c04c1b540a13534,
+                    0x84076d1891667609,
+                    0x7528ee05751eff83,
+                    0x3a234702cdfa768e,
+                    0x48d9abf63dccced8,
+                    0xc590d4620e6d41ec,
+                    0xcbe6a8b5a2d3a741,
+                    0x5ac3d81f2f55cd8f,
+                    0x007533fc12c2e89c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea86bcbf6b6d1fd2,
+                    0x8ced21f63e60f9ac,
+                    0x01f17110be4133c9,
+                    0x8387c0cbbfbd8e1b,
+                    0x2d6f77a1624cc3c1,
+                    0xfb6fc36aea9e265a,
+                    0xb0b5a9bdc4e16e34,
+                    0xeda6f38b68cd0ccb,
+                    0x836d28b3f6b0c158,
+                    0x98f7ea60945109d4,
+                    0x78349b879986bced,
+                    0x00826763971c7bd2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x78572c715af4fdcd,
+                    0x37cc958652fc4b87,
+                    0xdeb7e18107587a23,
+                    0xdf6fdb1421a97598,
+                    0xff5b2231e66204ad,
+                    0x8698a62d6d9f2250,
+                    0x5dbaf9768379e5ae,
+                    0xa63c8d3de91fe120,
+                    0x7dd713fb017b950d,
+                    0xf0120f3166b5f828,
+                    0x93329f6b8b66d9f9,
+                    0x0093e60faabfb2f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa28e9e2c69dda5e1,
+                    0x2362ad8496ee9c81,
+                    0x7dfff4daea26a372,
+                    0x9a1fffcc41e5c7ff,
+                    0xfa3f74617556d5f0,
+                    0xeabaa297070f5918,
+                    0x7ed9bdeb12c6cba8,
+                    0x38954e5491d9021d,
+                    0xde6e6b67a2834c44,
+                    0xbb9aae00a5936530,
+                    0xc87f205385d5924b,
+                    0x001a497759727833,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ba56ef55eb06c34,
+                    0x4f339c4cf0dec264,
+                    0x3c3b4659bf6a7832,
+                    0x4bd7730f3967071f,
+                    0x3176b87e5a7b82ca,
+                    0x8ac1b666e9808181,
+                    0x1efec10ec9f950f6,
+                    0xf4a6467093f86e36,
+                    0xc7bb59c4787beaef,
+                    0x01a44cd2f481b705,
+                    0x3e6da79c9e6ad535,
+                    0x00e32474df8df705,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3d7a39b7e6797fe5,
+                    0x2aeb765f15dc5b3d,
+                    0x2daa4c64a2fea3be,
+                    0xec74681d26ca3e2b,
+                    0x1c34f1e433be8ed2,
+                    0x3fd0a30f34e89c4d,
+                    0xda4fb77a8ccf5d15,
+                    0xb6d67d87836b0ed6,
+                    0x9e58abc71e51fe86,
+                    0x73c9b4a971c77814,
+                    0xec3b5866cde4b81b,
+                    0x00f903098da16642,
+                ])),
+                Felt::new(BigInteger768([
+                    0x35f48bb3ebda0423,
+                    0xf745e70206eff2a0,
+                    0xb2c23e7b4156daa0,
+                    0x44c329f45bdde5fe,
+                    0x66152136f113d3c2,
+                    0xa90b93efdf7c751f,
+                    0x61b00686477ad04c,
+                    0xa1bb2f0730ed587d,
+                    0xd2d2768671f232ae,
+                    0xbde9f0f2bace2ebb,
+                    0x68dd1dd1e32b5698,
+                    0x004dfc53416851fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x29cbf968e307fb5d,
+                    0x403bb485bd5bed30,
+                    0x8f908a6a48772289,
+                    0x88089bb319ca1422,
+                    0x7f9835b54ea0ef68,
+                    0xb7807a2b40ca2c64,
+                    0x5545bcbaed01a750,
+                    0x542ed603d883ebb3,
+                    0xc57fa04a131d55ef,
+                    0xd77d86f890608de1,
+                    0xf21f6c85df52026f,
+                    0x00da63fdfeecf7fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x106c9f4662a20891,
+                    0x9ca8837f0aae9369,
+                    0x51f25e6dac8d1146,
+                    0xd0702a4237176bc8,
+                    0x29ccdb19d3efe64f,
+                    0x396fde5eaed4f010,
+                    0xaddc3306ccb32281,
+                    0x6e5dbbe522567203,
+                    0xa7efbc00ea2bc054,
+                    0x8ae5e9101cb19713,
+                    0x0edccde133df1024,
+                    0x004105626d090318,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d7499360e435494,
+                    0x0135e08a541a2d94,
+                    0x8acc2f78799df979,
+                    0xedb488cad34c4fce,
+                    0xe5f9847a48cb98c9,
+                    0x9a0667e49feed418,
+                    0x1d68f547b764e296,
+                    0xd1f287d4382dd971,
+                    0x55c968bd8a00d5b5,
+                    0xdbe5fc5d1d74a2d0,
+                    0xfa3d4717bca4b2e2,
+                    0x0002150ee2486d5b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x729f58032892d5af,
+                    0xc310a71b96d4954a,
+                    0x6ad5307f4f031f8b,
+                    0x146315d35d5be2c7,
+                    0xd5acca305f823f88,
+                    0x283dc011a8afd43b,
+                    0x11b24b45dc4943db,
+                    0x81f4d688e6344167,
+                    0x80d32c010616347a,
+                    0x3fe84338c9b26b9f,
+                    0xbf267f48ebf44b15,
+                    0x004f6bb55cc077b2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1462188e006acea,
+                    0x855dc0abac0da23a,
+                    0x37fd1687886a1388,
+                    0xbbd66245cb7a234a,
+                    0xdfa9ffe034860e1b,
+                    0x659fe4eea74c08b0,
+                    0x8abcd4fe3d773a70,
+                    0x4402b63577bbb2bc,
+                    0xc3ac6821ce35652a,
+                    0xba129c7549e505c6,
+                    0x464d8a58c9507f67,
+                    0x0091df2a4f14e752,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8646fdced4e2ed9d,
+                    0xeb25d6c5ba12d75a,
+                    0x5486543d8f3afc78,
+                    0x4fca5faa930aa9ee,
+                    0xbad2eb7204d520f1,
+                    0xe24098365f8e8306,
+                    0x65910fa00e7838df,
+                    0xb3346557861ef7f8,
+                    0x3ee83b0d474b1eb1,
+                    0xfab41c9cbcdad9e2,
+                    0x13a7eede22c7cc07,
+                    0x0078eb4bb0ac0358,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe3dc3aef4f0d87b,
+                    0x947a6b56fad662e8,
+                    0x4ee9eb1863f2bef6,
+                    0x0e8fd07327ef7480,
+                    0xc8de1999fd8a13fd,
+                    0x3c0efbbfbd15d983,
+                    0x618662a9630663fa,
+                    0xae982091480ad3b4,
+                    0xef2ef421ddf9cc63,
+                    0x5e8db8d1b0458261,
+                    0xcb5f8948e69a5325,
+                    0x002f88caa588005d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdd0e2d8f6f7dd0e4,
+                    0x5086cf57a3db48dc,
+                    0x02c912f92dd20a01,
+                    0x07c0492c9ecb3a5d,
+                    0x1af15d8a594dc524,
+                    0x68f55a348dd5e700,
+                    0x32aba452ba888f0a,
+                    0x2e500f1ee698cf57,
+                    0xe335059fa4db6630,
+                    0x2ef8196c9b4c96ba,
+                    0x7ee11567bd3fc3f9,
+                    0x008e52ddd1b3983a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf2223fed2bef868b,
+                    0x840d1cc282055420,
+                    0xc49f23b8327c2b8c,
+                    0xed3f1e0e9a154da1,
+                    0xe2e4f2cc8492450b,
+                    0x767d320e1832439e,
+                    0xde81887cb611ed37,
+                    0x6641d848d8050de3,
+                    0x180d06aba746ebf7,
+                    0xd76bc7ba4939b6a0,
+                    0x357e2f30f494997a,
+                    0x00fd3751da704771,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0d1e45883db8b33,
+                    0xa4dd3619355d31da,
+                    0x7f0c4619e9ea4600,
+                    0xd1b044083d801016,
+                    0x1b79a57a6dee2aad,
+                    0xb5b74ea0c90b1565,
+                    0x097e7c506a9c732d,
+                    0x238c4903a610e4e2,
+                    0xbe2aca663e790657,
+                    0xeff3940e46741a45,
+                    0x279bb3022f679c38,
+                    0x00456209cd60f82a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2e42f60dc8418812,
+                    0x7ca8e03be8419e9e,
+                    0xea34a09219342a0a,
+                    0x7d29d6601bcd9be9,
+                    0x829167f658015d04,
+                    0x5456bf73b31a2bb7,
+                    0x209621d1c67e58d1,
+                    0xf88d3ed2f9bb2ca9,
+                    0x175c2bcfb91585a0,
+                    0x47ef1ab86498c3fc,
+                    0xd22882002824a7fd,
+                    0x00dde4662ab5fcab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc61fe37780146928,
+                    0x9a4db7a82ecdd24c,
+                    0x34cb85499d052224,
+                    0x20d25d65d3616c3e,
+                    0xcb682f8ccbda575c,
+                    0x59767f9e4fd4fe35,
+                    0xff6e4099b678f1d0,
+                    0x76d8ef79ca0a03a9,
+                    0x6218b2ba26e47d96,
+                    0x796dc5bd2afa369f,
+                    0x170573dc31dcaeae,
+                    0x00e4b6914e05db4c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8d3a52b85bd13de,
+                    0xb1d559ca02b98d3f,
+                    0x4e4c08454dfe356d,
+                    0x44ad984e7c18502d,
+                    0x7d789cbe47fb992d,
+                    0x8c71df496ead5cc9,
+                    0xbf0d4ce049a2d200,
+                    0x2d14d018be24d5a6,
+                    0xcafd4db76ea020c4,
+                    0x5606aa293bb11dbd,
+                    0x5360aec6672a2be1,
+                    0x00e01857c6646048,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd9cf63a38c9be6c,
+                    0xd31f9fb1d025c9a4,
+                    0x0c7197d31c42b20f,
+                    0x6a098acf0c9824b0,
+                    0x45c90a461144cee6,
+                    0x14c5b40f655550de,
+                    0x5215bf3a7315b5e5,
+                    0x5bc0a1a816e8ec59,
+                    0xfff0f4a3939e86fb,
+                    0xb7aad8c643f202e0,
+                    0x91139d21e30c80be,
+                    0x00f13165604c54ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32defe611e46935d,
+                    0x2387b07fc5158365,
+                    0xe685a9ce52c6b908,
+                    0x9b3f96bb5b3fee6f,
+                    0x73c66da75719f7d7,
+                    0x45afdb655bc5d1b7,
+                    0x35304dc1a935ab9d,
+                    0x3fb91a2f279813f3,
+                    0x294fe2a4161bb2b9,
+                    0xd0f021830df2a90d,
+                    0xe1fcce6b15587bd9,
+                    0x004d5519e8bab7c8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8a09c4ee3e9571f2,
+                    0xdf488eab4989c4a8,
+                    0x7f163604753f1ee8,
+                    0xd806fc3207852043,
+                    0x67e53c2931f63001,
+                    0x019a7d8530f0769c,
+                    0x4d6dd4413eee87ba,
+                    0xc5964dc8a65821e6,
+                    0xf4d75a01119ab4bb,
+                    0x79602b21377a273b,
+                    0xff73940f0241ca2d,
+                    0x00257e561ff8b3f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f6f5ec58dea09ba,
+                    0x826bcbfbff7152dc,
+                    0xb283f13fc9078fe1,
+                    0x2c179042374730df,
+                    0xf85c58c57c85449d,
+                    0x9a1232f079b92ac8,
+                    0x551be0ddcc08ba89,
+                    0x364e99ac67f77eaf,
+                    0x31a9b2e11625b324,
+                    0x9951087ac3ddc4b8,
+                    0x2d1c186989b45760,
+                    0x0121fd5a541aeca0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x901ae4a8f1d50933,
+                    0xe2e5fdf8f2d95d12,
+                    0x746886fdbf2b37f3,
+                    0xdf1d53d94fb0de28,
+                    0x9db59db6a9857041,
+                    0xed0b04dee946b47b,
+                    0x5f9d3fbd7834d7b5,
+                    0x0bd615165f299dca,
+                    0xf123d47d5a6d3177,
+                    0x9b1c4469e6c9f9fe,
+                    0x9874c3f7beca5aaf,
+                    0x004a174dfd3e86f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c28ab2ad6458222,
+                    0xa0af078970e7b21a,
+                    0x38e1d692509f0b3e,
+                    0x8ab7cc3417ff592c,
+                    0x6d937bf838761a77,
+                    0xe8a958d49db4d9b8,
+                    0xfb4a5c86abff5e98,
+                    0x86a3ee402effd127,
+                    0x0075b10510851f70,
+                    0xbfe65fb4c52eefce,
+                    0xa627410034fe295a,
+                    0x005880072eea2fc8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe43eb806bd57070c,
+                    0xd23cd94ffc643c80,
+                    0x80e540e4aba68312,
+                    0x875d99f40815ba9f,
+                    0x1b3ca888767025ce,
+                    0x64fdd73daf1787dc,
+                    0x16688c5fb464fafe,
+                    0x96d383e761644676,
+                    0x00320c2f2014b2c2,
+                    0x559daafb663c688e,
+                    0xa405fc6d90425b9f,
+                    0x01214e24c50ad7e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4fea282837d29f01,
+                    0xb61b0d8885ae4e42,
+                    0x001e24c305f101f7,
+                    0x95473377ca97f2f3,
+                    0xeece6b4e56a37e43,
+                    0xce6ed9eabf378d90,
+                    0xaf6034c32920d727,
+                    0x32d2e1c36149b12a,
+                    0xe1c95724dec692e5,
+                    0x884a058c8d6d73d4,
+                    0x376538008bd97bda,
+                    0x01187bed56ae42c6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe280ce4352963760,
+                    0xbd91ecbbf4bf6a2f,
+                    0xede873c0d3e6903a,
+                    0x60d1ff690e172f8a,
+                    0x9f8da8f5fc4cb562,
+                    0x8777a2c1489b118d,
+                    0xe1905d7dc217a46b,
+                    0xb5bbf5df24caa4aa,
+                    0xb1d37e7c98c1a73b,
+                    0x60acde349f2bd71b,
+                    0xc97ee27797dfa378,
+                    0x000624730e091418,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9863c2510ec7c1b,
+                    0xc7b58ca14fae5085,
+                    0x69239d24a106aba0,
+                    0xb49ac4ff48b44ce4,
+                    0xbeb0727ce9b1b125,
+                    0x1d76c4a7541c2b19,
+                    0x06ffcaa26e767820,
+                    0x8576623de2230c01,
+                    0xb0ec7183bd95cfb5,
+                    0xdf406fd4a6d687ff,
+                    0xe2c71fb8d1cac002,
+                    0x0028afd8b3994044,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4408d93efa02978,
+                    0xcf39256a3f6fb603,
+                    0x517993c66d6157ab,
+                    0x354cc64c55b930bb,
+                    0xefcabb246270c779,
+                    0x5b27211c559dd0a5,
+                    0x37054367653522bd,
+                    0x8646125a4aa8830d,
+                    0x0bd061680120e01f,
+                    0xe24a861f12aec2b7,
+                    0xabb2dc0ba624c96c,
+                    0x00b0464b496f1747,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2aea62cd84efb22,
+                    0xf2a8ac9e3eb57a09,
+                    0x80dbd8c384b1878d,
+                    0xa1624473f2cbfc99,
+                    0xd10f21726830e106,
+                    0x5203ecdd838370c7,
+                    0x55e1766aec07df9e,
+                    0xd8404963d40b599f,
+                    0x40f224137e81bdf0,
+                    0xdb105d5e8709ec89,
+                    0x70d1dd5f23fafd77,
+                    0x011d2a9174c6b53c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x96dd21d272df8fc2,
+                    0x5a4eeac34a09e8b2,
+                    0x493417b2d6dccdc5,
+                    0xa94f84f9e914ccc1,
+                    0x684b6be4f2e9b8c1,
+                    0x0bd48d861c0d19b2,
+                    0xdc279da11230c0c3,
+                    0x8cf8ebe4a77f7fe3,
+                    0x3e5d97c121816dbb,
+                    0xd66a1294898b213a,
+                    0x9df9cc557208fec6,
+                    0x0113b44a25ae7ac3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x502aaaa51c3b7946,
+                    0xffdaa0a338917ad3,
+                    0xde061fdd246a3272,
+                    0x0d6afe92b0fee8fd,
+                    0x6d017598d3b87ecc,
+                    0x50a961642bcc8ddf,
+                    0x256a76ee28fbdd72,
+                    0x76f9b501f44c6fec,
+                    0xbd331b939498c94d,
+                    0x826e0e029d114bb2,
+                    0x03ae956442ecd5eb,
+                    0x00b0594428d42fcf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xca5749435a772668,
+                    0x1f2840d2328686ce,
+                    0xfe23ebac2ee74caf,
+                    0x627cf0e0eeea36a9,
+                    0x684b58d7879aaf2e,
+                    0xe815ee70005232aa,
+                    0x6713086b3de882af,
+                    0x042d0041a08e16ad,
+                    0xeab157c8210f2d3b,
+                    0x2a96b6ee29fcd734,
+                    0x6c10e777753ef5d5,
+                    0x0070e2774aade03e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf4dce2e7ea1c70ba,
+                    0x5459410648367d50,
+                    0xe1499711a8448ada,
+                    0xb5496f3ce4d6e612,
+                    0xc9f1102d1a16a78d,
+                    0xb643af3822785247,
+                    0xaaf37b4575adf05b,
+                    0xdb499e4feb72abef,
+                    0x9eb45b522a836cd2,
+                    0x4edea81c759787ed,
+                    0x3d17a60e2b2a4548,
+                    0x008ca2ef04ebad04,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7db7a22eac1d0331,
+                    0x82c9f165b392260c,
+                    0x68d1602e09ec592e,
+                    0x458a92e1ca0a686b,
+                    0x36883dbe47412c6e,
+                    0xba72c4f5cb9a272f,
+                    0xa823415778bb6ca6,
+                    0x06afb2f4ee543876,
+                    0x4923968c8e1fae28,
+                    0x765a30ede6aa5c67,
+                    0xe557e32dd1854d1e,
+                    0x001471e779f3c1d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce014298ebc464f3,
+                    0x7a71c87c5759a91b,
+                    0xccc2041f23a0e421,
+                    0x66543c8548a60d1f,
+                    0x51dcda5f7097cf5e,
+                    0x39a758c71ae1b041,
+                    0x34ab0ac271604a68,
+                    0x38d48050042840e9,
+                    0x183cf07043d9d9f0,
+                    0x6f63a46c6e0b6aed,
+                    0xde0e33c8cfd3d3cf,
+                    0x00b12b2b64f1174a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3c53297b5485d476,
+                    0x6d59a6863ae5cb1a,
+                    0x061c6a78b1e1e554,
+                    0x00123ddd565f8cca,
+                    0x332d533d757667f5,
+                    0x80af165778c6fe16,
+                    0x55d186fdc08f0bb5,
+                    0xe3043abd9e50a864,
+                    0x2185f4f39eb0a192,
+                    0x83fac36995e1b421,
+                    0xb883177d8edb5606,
+                    0x006561843907e3dd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x94fee7cbe4fb6479,
+                    0xf05f4468b2df3107,
+                    0x8293deaed418bb83,
+                    0xf59459b95055b3c4,
+                    0x2d0aaf01bc82f7a9,
+                    0xe56c6463b3f15c58,
+                    0xfb4d222c3ea12e3d,
+                    0x1ca41057b506606c,
+                    0x3bcdaa1b4859fba6,
+                    0x94ccfd22cf1ce72c,
+                    0x086889c8f58d2ae3,
+                    0x006d3f34236d9571,
+                ])),
+                Felt::new(BigInteger768([
+                    0x08f4f3a7b07d1549,
+                    0x482cd8289ba54bab,
+                    0x7759ddf8905865fa,
+                    0xc46ad9ac83b4c7a3,
+                    0xa2f9b6f975d51643,
+                    0xfa0e8ab3e4acf59d,
+                    0xb1e5973be75f202b,
+                    0x11617a4a395e1918,
+                    0x45b2c3c8bb474971,
+                    0x95ce058fe9dad43c,
+                    0xc4b01857711a59d8,
+                    0x001ec45007f847b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6b6d806dae65ae50,
+                    0x1975aa71148c8732,
+                    0x26fca3e6d4b913fb,
+                    0x2c4bb6191d968698,
+                    0x701e5215419424b3,
+                    0x92c29632938a829f,
+                    0x1b3d12cfa02392fe,
+                    0x23fbc9a96adaea60,
+                    0xefcaac31cf62cdcf,
+                    0xc9966a4c8e5eb9a3,
+                    0x4d61057081b4f06e,
+                    0x0094ccde47754634,
+                ])),
+                Felt::new(BigInteger768([
+                    0x774c9c1a8176a2ce,
+                    0x94f84379cd7ea2cd,
+                    0xea45fdcbe405f251,
+                    0xf388109365579960,
+                    0x943ba46e53178b14,
+                    0xb4860bbe3849b727,
+                    0x4ece99dc066efb26,
+                    0x18f0fc99c3247375,
+                    0x683468c43ab35210,
+                    0x69c9e96e8cbb8ab1,
+                    0xd69dd823c1aedeef,
+                    0x00b7309ea8e71176,
+                ])),
+                Felt::new(BigInteger768([
+                    0x63c3243032fae92a,
+                    0xbf2249827271bfd4,
+                    0xd72ea77511bcfb0e,
+                    0xffb33de74181277f,
+                    0xf798d230d982af48,
+                    0x3e1b66e6a25efe2f,
+                    0xaec5a323b996d610,
+                    0x2c65a44decaf1201,
+                    0xf755d8e6de8c0e65,
+                    0x621b7a84d249eeb3,
+                    0x5c722571201e1a3d,
+                    0x007da67737b057b9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x23aa782359506a4a,
+                    0xdce2b057be22ebb4,
+                    0xcdf07d83b98eda14,
+                    0xdda6e901a8c96316,
+                    0xee8d8d33bcdf5bc2,
+                    0x63eab50e3be28356,
+                    0x4216812bc1023917,
+                    0xd0d9b715e66b191b,
+                    0xb013afc5b10ddb18,
+                    0x3535102fe157a266,
+                    0xfec3f80b2ef5ff7a,
+                    0x0015ef1fe208d8ba,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8c4d487680bd512f,
+                    0xc727aa216d3f31f6,
+                    0xe18a958cce7e6cef,
+                    0x1411f69ed022be76,
+                    0x3ee25bb25e809843,
+                    0x776d90b6942d914d,
+                    0x4c7a24b802ffb130,
+                    0xd23512a5c73cb1e7,
+                    0xf19981e97c0d4e47,
+                    0xcb5ff4476a5e8a2a,
+                    0x421bf7116518ee1d,
+                    0x010ba0cd9be3c72c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x85374a2b86380b6b,
+                    0x0e5879d20dbc345f,
+                    0x9e5e732a230d4f0b,
+                    0xf110c84336cfad91,
+                    0x56f46af30d485b35,
+                    0x23cbdcc2a242aa64,
+                    0x453b0bb86b118542,
+                    0xdbba9abc33fcab48,
+                    0x94bf477b7962f76e,
+                    0xd48fc3adb2c05521,
+                    0xbd8a63d13ebd8783,
+                    0x0027d8ba73c82505,
+                ])),
+                Felt::new(BigInteger768([
+                    0x694bdf68fb1b3b2f,
+                    0x0e7180e6c4241523,
+                    0x6317868932e3e386,
+                    0xefa81831e215e344,
+                    0xb8ae13598c665be3,
+                    0x246470b608b2e547,
+                    0xdd084185efdf8c9c,
+                    0xdf261f2e28548ade,
+                    0xbc3b9019cebed3ac,
+                    0xcc5b4fd5cbb007dc,
+                    0xa30bd6ac79c2113b,
+                    0x007d982f422f3fbf,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e52fffffff95005,
+                    0x3d1e5bb48ff9b319,
+                    0x6a3d1da462296b81,
+                    0x27f6b075cd47e878,
+                    0x9f32a1d0425e131a,
+                    0xf8fc50194703fff5,
+                    0x1ad2df80a05cb542,
+                    0x9d15bd3d260512e8,
+                    0xc7317531f821bc05,
+                    0xa1cd48371d7a4aff,
+                    0x569a5282a89e4c79,
+                    0x0112d0823da34cc1,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x239d17332b103906,
+                    0x82ae720772df266c,
+                    0xeaaf96ae2f6cfdf5,
+                    0xea8fb701dd6e4cd8,
+                    0xb5f579c7d7f7494b,
+                    0x7fd713c9b261856e,
+                    0x46d63e249c1ad40f,
+                    0x912c58090f5cbb3e,
+                    0x72f46b97d57b112e,
+                    0x14713940c891ad68,
+                    0xed1cfe435e3e8da6,
+                    0x006d3151c401ab8d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9e199ba6314a3b8,
+                    0x9f292280fddd8a10,
+                    0x60627a07f48420a6,
+                    0x1d566bdad24e1d97,
+                    0x205252b34b70f549,
+                    0x5e3e893ca3d06ca4,
+                    0x9b197956347cd9ab,
+                    0x39b6e04da8a3a43e,
+                    0x3b7e253b65b1ad52,
+                    0xb1f78cbcec6d0b87,
+                    0x9c8d7b286726d423,
+                    0x00f4fae3bdbd36d4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d5c97158ad73b11,
+                    0x61f6624734d3e1d9,
+                    0x16f752d42042109f,
+                    0x6851bfb4b4198667,
+                    0x73914f9f67eeaa56,
+                    0x2ad659a4f9a0646b,
+                    0x8b6859fe5c9c79a8,
+                    0x83e97666f5dbabe5,
+                    0xb5f31b6204c7b481,
+                    0x8837bdcd9ed8a0f5,
+                    0xa933d39663c5b807,
+                    0x00368f6cabbb3c37,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c9e9b9e58c3b10a,
+                    0xb5f91c7ba660c019,
+                    0x50fb3e5ab35cdd54,
+                    0x7b40ab88805cd440,
+                    0x0745160bd014fdf3,
+                    0xcf6a49ff98d62bec,
+                    0xa4691c09b3ee3ef0,
+                    0xfb78ebfb361640e4,
+                    0x7fb559a49eccc43e,
+                    0x8854ba9501b69662,
+                    0xf6e5f9936f8ad91e,
+                    0x006820c79fc7c9dc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff5d8a465d84c60d,
+                    0x4e8d158100b9e756,
+                    0xa35c7d3d9645d602,
+                    0xf657fc1651c630ef,
+                    0x61b3cd372ceff703,
+                    0x172ce3f84e67c0c8,
+                    0x26b0e6be7a3f7736,
+                    0x876b6c46731ae073,
+                    0xd87d432a92a0c76b,
+                    0xec18ffa9c358d69f,
+                    0xf74a073b812ac7ce,
+                    0x000bd2898d4dd0c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9281bfe835e312be,
+                    0x844ec4d4920be827,
+                    0x367dd62b97122a9b,
+                    0x6c602a64616955c3,
+                    0xe76d8e5835d47fd6,
+                    0xec3e02e76496e074,
+                    0xe19c92cb5f386cfa,
+                    0x084dc57de69c6945,
+                    0xad0e26fe8092067f,
+                    0x8d122e409a3871f8,
+                    0x33c46e24b7653d24,
+                    0x0026c5f6d2767e8e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8687ed8c422d485e,
+                    0x9a7c881b847e6aa6,
+                    0x5aacd655d45393e0,
+                    0x4a4ec6a030ec90e2,
+                    0x138d661dde53e3de,
+                    0x2cab32c005001e52,
+                    0x5a28ee16b08123af,
+                    0xe5fd6568710c1303,
+                    0x260d24b2639a4655,
+                    0x922b43f26b1e0422,
+                    0x9dc04826e9e12f3b,
+                    0x0058775bd28a8d4a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x487ca478482482e7,
+                    0x657f9e0b7a436291,
+                    0xac9bfe5ac5e1e876,
+                    0x77e387036ba91d37,
+                    0xbcbcd62ab1bbb1fd,
+                    0x0041a0f95c13ffe1,
+                    0x90a7c6393eac7e29,
+                    0xdfda6468b029e300,
+                    0xe3e422a65ff94316,
+                    0x2eda2f11e86f441c,
+                    0x5d952953b64c9c48,
+                    0x0028a024b0eba211,
+                ])),
+                Felt::new(BigInteger768([
+                    0x28d7ea875aa718eb,
+                    0x2859d5aa9ac8f8a3,
+                    0x614425992ab66bfe,
+                    0xd91cb16905712b28,
+                    0x582da572973e1cd8,
+                    0x4269fd6e4ad75d67,
+                    0x182b750f73477748,
+                    0x3d1a7280663b635d,
+                    0x734055c82d54121c,
+                    0x3ef9c5862cf80def,
+                    0x0ed1dac7c195435b,
+                    0x00c5f7725cdd0bd4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1bdcfb5b01e1b56e,
+                    0xbb2a7e2ab92c2a9c,
+                    0x811e35d4b73d3513,
+                    0x6584226734d859e2,
+                    0xd36befc64f8f75ae,
+                    0x102e21a438244d24,
+                    0x1a0381c91d5ace00,
+                    0xb48ff6a661f17d45,
+                    0x0d5843922eb8a1fe,
+                    0x5e332bea638cb7ec,
+                    0xcf604d2eb4e47b1e,
+                    0x0070c83def910e40,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x88b1218cfd260a40,
+                    0xa21a60052ba3028a,
+                    0x70c348af68c0e610,
+                    0x3f2b5be85f3571b1,
+                    0x02eff5500490d023,
+                    0x1ec42608a7f937fc,
+                    0xe01f9cde6f903751,
+                    0x81a4001e784193b6,
+                    0x9b73a40e1c32711d,
+                    0xb496d223cebc711a,
+                    0xb79842250c8b98cd,
+                    0x00fbb93ef8beb5ae,
+                ])),
+                Felt::new(BigInteger768([
+                    0x21ebec5844d7704f,
+                    0xaab62b9975e60108,
+                    0x3cc2c169949185ed,
+                    0xadce8c5cef4a446d,
+                    0x5c5e72915bdd3fdd,
+                    0x5ac4df4d5e429f89,
+                    0x49a00ccb9c12a4c1,
+                    0x2e624075fbab48e6,
+                    0x6c9de43db998b276,
+                    0xaa736f519737a819,
+                    0xecc44c25ea14b497,
+                    0x00abc0db017a8ee4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c63ca4df8476b3f,
+                    0x0e636ddfa1849670,
+                    0xd6dba5d12e9a7819,
+                    0x5054f189e3d19d96,
+                    0x6a953a9490b092d0,
+                    0x5cee75adae0cf1ad,
+                    0x5fd94e8f7a3004a4,
+                    0x55bf65168d4f3ecc,
+                    0xe992f328b254849b,
+                    0x07fa4c76b225af82,
+                    0x9126c8ea1d7b58fa,
+                    0x00b218a0b2c680d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b813c134a6f01fc,
+                    0x922ecd7f9b1f73dc,
+                    0xa29de57b76fcb5d3,
+                    0xb6940435fcf0ecaf,
+                    0x7e5862bd7eaa1e15,
+                    0x5320e55dce60c2d3,
+                    0xdfae8f238de7c601,
+                    0x48f64f7ea8a29c11,
+                    0x9fc2aedc57adee39,
+                    0x7d09744c53828056,
+                    0xd7accdc04bb0e485,
+                    0x0101924ab07a2962,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71aea5398af9597c,
+                    0x763da4da768f4e5a,
+                    0x2cfd296a457569d8,
+                    0x0aafdcfd021c1c34,
+                    0x5cfbbc2706fc6b50,
+                    0x49d6805219a54b12,
+                    0xbc436344cc4bec26,
+                    0x6df0da44caa8eb41,
+                    0x599a9013657c3891,
+                    0x3bc80605a1517c1c,
+                    0x1088c0636cde8139,
+                    0x00a95a4f2ac557b9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x47e5484aa62c20a4,
+                    0x90f6c555d3a3b63c,
+                    0xc82d64b54458321b,
+                    0x2e3255077469e2f5,
+                    0x28390775a30f7c50,
+                    0x98ab3e448471f869,
+                    0xdaac8da3829d076a,
+                    0xc9e9f1a769dfb268,
+                    0xb025b59fe3039f4d,
+                    0x1968dc258b008c1f,
+                    0x0092bef2175e7a1b,
+                    0x0104930c02c585f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x94fd5522fc5426da,
+                    0xae7889aa54f44fd5,
+                    0x19eb9082b6d54f37,
+                    0x809f045799cb0563,
+                    0xc45855317f061c3e,
+                    0xc24c4163fdad9390,
+                    0xb78a942dd05bf1a8,
+                    0xeb513c91227ffa43,
+                    0xe07b38142a5ec6f9,
+                    0x9ec1c7bdadd48a9b,
+                    0xcae102675b958e84,
+                    0x00baae79aec5b1cf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x447d3c0f079c1d14,
+                    0x808771e139e08301,
+                    0xe12b795d1d5fd941,
+                    0xa3c6e3c82fab5db3,
+                    0x9868b09b6d0020cf,
+                    0x5687b75f4c31d3d1,
+                    0xd496bea92721d7b8,
+                    0x80964ba973ce04cb,
+                    0xd6326b80e1e51fc0,
+                    0x48887934384aec6b,
+                    0xbf1af9c6ab490353,
+                    0x00c99c92b2ed1d9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32af85d89e1ef650,
+                    0x834bde9c9107f760,
+                    0x5c5bef84d6f58528,
+                    0xe2e8c797118dc95d,
+                    0x606f762e2c53b188,
+                    0xe1383167b095d5df,
+                    0x68239f61ee6af691,
+                    0x5b574d5de1f2ce92,
+                    0x510ddf2f4e6126bd,
+                    0x0cb2f7ebf3e4f467,
+                    0x786ff9a3c4f6ab0d,
+                    0x0113bb9793fde5e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba88c8259a8a86c9,
+                    0xdd10dc5017edd8f7,
+                    0x2bae08ebaab6fc51,
+                    0xa183a7712cd83e87,
+                    0x07bbf50f192207d2,
+                    0x3a50ad5ab95e7572,
+                    0x3f6e97301480a181,
+                    0xf9b8c5c482345086,
+                    0x13e814919912b923,
+                    0x7c86bc219dfcd08a,
+                    0x9d721d094f5dfc6f,
+                    0x004d1b2406085184,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6329bbb393273334,
+                    0x136e55dd45db3bfd,
+                    0x3591434b6a5070ca,
+                    0x7e75ea277f8ef5e5,
+                    0x7c433391e1e81eee,
+                    0xad4126a164e9feef,
+                    0x141b053d9aa527b3,
+                    0x23d55bc30f1224f9,
+                    0x2ce122dda115aa54,
+                    0x569535e7e601749f,
+                    0xaa64784d6ea2a1d4,
+                    0x0026e9fc2baf7f57,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8cad696f17a0101e,
+                    0x75fcbc994ccbe5fa,
+                    0x3139e9ed51ca0b03,
+                    0x2470bc5b9917bb37,
+                    0x4f7cf422ad34093f,
+                    0x812e6bc0dafd8891,
+                    0xbba8112deea3d9b6,
+                    0x52a728f1b3ada89d,
+                    0x1ae78d9fa89f7d16,
+                    0x06bbb30f4074608e,
+                    0xdf1d01497f19000b,
+                    0x00a445ca22157fb2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1279ee20591b1382,
+                    0xb56cdff76057ee1a,
+                    0x7211b972c1870486,
+                    0x749dbe933201f016,
+                    0x2728e7787669474b,
+                    0x0f6b5cbb23b14f9d,
+                    0x10026fa7c185d9d8,
+                    0xd1d1070cc782111f,
+                    0xa8e1552303d0a715,
+                    0xeee096c14c92fc5c,
+                    0xbc01deb79259ef17,
+                    0x0114bb4ce65ffc23,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22881a12e0bb31df,
+                    0x4d856ad0e8bbd391,
+                    0x979b450eb8f27f04,
+                    0x6870a37e76f86bc0,
+                    0xf86fd9c1ce0c846f,
+                    0x186ae651b88fd655,
+                    0xc064553091a2726d,
+                    0x32eb52c7f990db55,
+                    0x30a46a4e1966a592,
+                    0x924d84fdbf80bd32,
+                    0x8831e9260c17c055,
+                    0x011d7919a8b863ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8d79707064bba90c,
+                    0x9842620ab8a671c4,
+                    0xb1ef90fbe2fe4924,
+                    0x83900bee593a9cd2,
+                    0x841e2cab681f198f,
+                    0x6cb3037e89837583,
+                    0x58e68c900f02aab1,
+                    0x315c0904aa66d536,
+                    0x73d51235ecbe32df,
+                    0xd6b1b447c2e80aa5,
+                    0x273b1de6170ca135,
+                    0x004b99efd4e979bf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4ad5c52810ba5100,
+                    0x1b5ceffe1d2e9904,
+                    0x09b04756dfe83573,
+                    0xac612d48783e2193,
+                    0x0d8f3d5337c66653,
+                    0xdd8d7411201d656d,
+                    0x84976f5afd047ddc,
+                    0x17fa2178845ad38e,
+                    0xa29191b8bf1dc25c,
+                    0x31012a37328ad75c,
+                    0x550b25cc48ba7595,
+                    0x011265221133b678,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc4cf8f809c81d12,
+                    0x1c09372eeec956b7,
+                    0xc79082ae5b44443e,
+                    0x03a70c54de463177,
+                    0x8515131af7a9b558,
+                    0xff04bb814234d3d7,
+                    0xb65dc88ac2b4f3da,
+                    0x7280c64fab515db0,
+                    0x5a027d89be023e28,
+                    0xad577bd87c2570d6,
+                    0x924840ddcb3052a6,
+                    0x0090972ff17e5bf9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd8671b52c4947d30,
+                    0x675970aa17a9bdb6,
+                    0x9de3d9aa7617312b,
+                    0x0f5ea76cf1ddd855,
+                    0xb5d9c35f8f2c372f,
+                    0x04bde5f0dee3cccf,
+                    0x5736e4decabce10d,
+                    0xcd09d3e403c6afc9,
+                    0x6697d0e7e595f1ee,
+                    0x347c7aa80a7763b9,
+                    0xa82791b0a464523a,
+                    0x00e8e7dd931419c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5002b7da4eb3a197,
+                    0xfd458646d1912253,
+                    0x34d8e52ff58c95b2,
+                    0x4400af6c5f1ea7b0,
+                    0x942dd5be6df0afbc,
+                    0x840a9cb1e2dc44bd,
+                    0xa8f6e3fe3e87698d,
+                    0x6978cf684d56bead,
+                    0x85a305f1d764205c,
+                    0x30cb7816df367ab1,
+                    0xfb78068ee2f9bf5f,
+                    0x0013d43fd1641a04,
+                ])),
+                Felt::new(BigInteger768([
+                    0x776110ace0f30dce,
+                    0x3f5fd743037e8493,
+                    0x7e60df04f7621415,
+                    0xd196fdba62c19bed,
+                    0xf8554e5fb4ee2a9d,
+                    0x50bc9c7b9f61cf06,
+                    0xda2962d39d0d2b54,
+                    0xf4c3e4f10376f6c4,
+                    0xf3ef62f00bbbb9ef,
+                    0x72123bec298145bd,
+                    0x17b6384ce015a26f,
+                    0x00093e9c357fce99,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7e663d7d62343cc1,
+                    0x2f3543d4f5ff9ecb,
+                    0x4fb12eed3e6eb757,
+                    0x4d986c3d57d538b4,
+                    0xb77fab3a5a3f30bb,
+                    0xa24c6fa3f369f1b3,
+                    0xf999807a7c578b80,
+                    0x5f9953162f6a0a2b,
+                    0xfdd0dd447e2dce95,
+                    0xfb57ddd6f69864b6,
+                    0x8b2f29bf89f042dc,
+                    0x00daa6768e8296be,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6a261b9794165eaf,
+                    0x08cb657e924c0c24,
+                    0x8e533bebc7075fa1,
+                    0xf0e72286d2fde8a1,
+                    0x0c05d7346a22c956,
+                    0x10c6e426c86302ea,
+                    0xccba276a6237909c,
+                    0x0db0bf7c764b212e,
+                    0x15a80c514a449142,
+                    0x6e42de30535f22a1,
+                    0x122593326eb4b6d3,
+                    0x00f5625c01ca208d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee530b1dafd3ab3d,
+                    0x4258a882baafa25d,
+                    0x1a7c5cb8714d39ca,
+                    0xa302cf2d85296e05,
+                    0x03545b6ff634b8a1,
+                    0x4f0a1b8424d362ae,
+                    0x0a15348ef587c1d0,
+                    0x252b41cce4425821,
+                    0xf308854c9eff000b,
+                    0xbf63221bc0275c91,
+                    0xe226170aa297f74b,
+                    0x008b1455e4e006c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4d22d1870ffe4378,
+                    0x9d91ba18c6abdf90,
+                    0xb4f1c9be21e5c86d,
+                    0x9cf9f58f029e5ada,
+                    0x8ff06c0e4f99a47f,
+                    0x3279c8e286cb605e,
+                    0xf546407d1e31a31b,
+                    0xf497c7108582034f,
+                    0x89852d77b43755e4,
+                    0x22b3a6a1787e7eb8,
+                    0xeb9f77918c448c1d,
+                    0x011d26311631e85a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xef2868b6ca68ed68,
+                    0x3f42901c8a86f2ac,
+                    0xb6cdb7a99e96c937,
+                    0x4adea017ecfa1930,
+                    0xe8b1c446a2e46b6f,
+                    0x022835bca829f849,
+                    0xc1a56033e4c62c13,
+                    0x5dec2b8a85ca5bfa,
+                    0x44a9fc17bdadfa60,
+                    0xd8334f18de542220,
+                    0x0423101d3dac7004,
+                    0x00fd9402ed67f7e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee793264a4fc469b,
+                    0x9003d69b2cdd95e3,
+                    0xa46ac156145ff112,
+                    0x09f37bf7b1d93bbc,
+                    0x9bac8e079f645616,
+                    0xf24edead0e064f33,
+                    0xd77cb4cf1da08462,
+                    0xebfd4bcd7d17933f,
+                    0x9387fdceaeaa4967,
+                    0xcdcfc0aad1e97bff,
+                    0x1771e55248b74444,
+                    0x006f8280b05b402d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x64e4ce0d2be5b635,
+                    0xf24efd93ca5af196,
+                    0x03c72f8dab99258a,
+                    0x80321801e1c91635,
+                    0x4a756b52e135062b,
+                    0x079b919954ef51c1,
+                    0x3010b662119cc49a,
+                    0xd6000f931e4308ee,
+                    0x3bc78f2e64e09e79,
+                    0x98dcb793d4c12deb,
+                    0x414f07ac1cbf2245,
+                    0x008382966301e6fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff62e6f995976ee4,
+                    0x70a5b38470526e90,
+                    0xec3fb9712fe1cf30,
+                    0xa6902b4bd26dd7e7,
+                    0x70ce35d3d793bd00,
+                    0x93599b211047b95d,
+                    0x115e145976834477,
+                    0x29bca2bc1b97a0f1,
+                    0xfc43097979e48e59,
+                    0xc5c10f3e279cd3d2,
+                    0x48a051ca9a7c1c68,
+                    0x008e467a16fc490f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd09e79b5a704ebc0,
+                    0x4a9b3ce18f3fab2b,
+                    0xc775844551b1d44e,
+                    0x69613bc7c61e8059,
+                    0x6fe238df62624118,
+                    0x96ee6b963f3df777,
+                    0x62122cd9661d620a,
+                    0x1577d5715d8b655f,
+                    0x559bd901e271e3f2,
+                    0xd56cedad0b08bc45,
+                    0x6e5c4475cf5958db,
+                    0x010a04de5b454a9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x066fedd30f08cb1a,
+                    0x1d2d196772fa3a6f,
+                    0xf93c008118f89584,
+                    0xbcebce4784382a18,
+                    0x8326de71d3271aff,
+                    0x9bd90796f6ce680e,
+                    0x470821866e6a6f9d,
+                    0xcaa873b8c8f104a5,
+                    0x54cce38777087de2,
+                    0x388c3f86441cb22e,
+                    0x2dcacc9ab1f40f6f,
+                    0x01182781d084a400,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x67b2f4f3d1fce5c7,
+                    0x7f73c1d10fc44b47,
+                    0xe06aeeefd4c0fbc2,
+                    0x931ede675082671c,
+                    0x08b3a785e56ce504,
+                    0xf838688f7a1c0a90,
+                    0x0bd8922ff23768de,
+                    0x8b0dd1f9856104ca,
+                    0x88b4fff0f5fac52e,
+                    0x47a3be9e37c13e35,
+                    0x59392cfaf463b710,
+                    0x00b9c5745a4cc88c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd804ad9f1e6e714,
+                    0x3e054dc42b2326cb,
+                    0x6d308ffcaedc24aa,
+                    0x2afeca4c04c2f55f,
+                    0xc390c35312678f5f,
+                    0x958d91bf8aaa1db1,
+                    0xfd729e49a30f7dbe,
+                    0x41ab4223b83e7322,
+                    0xb099dd0d3853b0f6,
+                    0x7fba047f03f1db0b,
+                    0x8d9ab0cd8085b395,
+                    0x00a9a43d57a92480,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6e17fefebf851b30,
+                    0x2ccbcc5e8da822f2,
+                    0x0504b8f254b1ae81,
+                    0xe1c5fe82f79f8562,
+                    0xf814dd3fe16e2221,
+                    0xe1282c34955fb869,
+                    0xb79456dcfbf97fdc,
+                    0x4546a30197739d8b,
+                    0xa73b30967873db76,
+                    0x81d049206c23b6b2,
+                    0x72aa58a57fc814ef,
+                    0x003e0609ffd0c854,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4558e74134c3ee11,
+                    0x2fcb1323b2e56ee7,
+                    0x6176c7c0e79035ca,
+                    0x4eed4958258f325d,
+                    0x886ad6949f5f67fb,
+                    0x912d2e3117058af5,
+                    0xf2ca01183bde6cc0,
+                    0x093abc846c28f94c,
+                    0xf70196b3e39d098c,
+                    0x1e38ee6a5dc61ffe,
+                    0x393c2df8ca9ed99a,
+                    0x00a8e257188ddb8e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdf7419b4f046c4a5,
+                    0x34a77edfe51e7a9b,
+                    0x8c512855834f4398,
+                    0x5f078cc332ec4d25,
+                    0x897a613b9fa30b26,
+                    0xbe125b99b1353688,
+                    0x3dbde0c123069e77,
+                    0xa1948f129566cc1b,
+                    0xe2def54e6bd2c83e,
+                    0xdc236847fc9d059c,
+                    0x66748701a3eb015c,
+                    0x00cf9523e002c6a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4587725485258f9d,
+                    0x7d8424b86db17dc3,
+                    0x1eba7efcb7e3968a,
+                    0x31077b27c30521a3,
+                    0x9322e08ea4e69865,
+                    0x2063810c5a428f7f,
+                    0x8158636ccdfc63c1,
+                    0x86d25c64cbff28ac,
+                    0x88b4fec5e9268541,
+                    0xcf543714122ff358,
+                    0xfaa339a78506a3d7,
+                    0x009fd54138474abc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x554965c68ec844a2,
+                    0x7a44dc1640bc28f6,
+                    0x9adf4955179d0055,
+                    0xbc26ddcff0f43556,
+                    0x08617877f07eae17,
+                    0xc184f6b57da8cdaa,
+                    0x734880ba39cb6d58,
+                    0xda58c22f791a619f,
+                    0xcbc324c978b13a9f,
+                    0x9b28fe81e1b1e0bd,
+                    0xfe7a47fca4373734,
+                    0x00d8f6dfe15200ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7238784be8fe9df6,
+                    0xb7c36dc147caca60,
+                    0xaa5791d41e1be459,
+                    0x54bc3245531a93ba,
+                    0xb71f35fd1c567f97,
+                    0xee85b233a5a7f5bc,
+                    0x2942d8f1c6de39d4,
+                    0x5aaf858b89ba08f2,
+                    0x6e363dd5e5634382,
+                    0x4ad7912a14eb07b2,
+                    0xc6fcfbc7f90cd8f6,
+                    0x0087f089b79c765c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf2c77f911c682222,
+                    0x1cd6ed0a0a3369d1,
+                    0xde3d4171fbae5514,
+                    0x698c4e0df254989f,
+                    0x68ba8078d810db59,
+                    0xa5b7f7747fa0ece9,
+                    0x7491e2c0efa60488,
+                    0x88fb953b997b1a89,
+                    0x33fd99a5aa2d4bfc,
+                    0x183b0aa6d9800fd8,
+                    0x4ee662b122d524f9,
+                    0x00c15082f5312ced,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2eec72578051f4a8,
+                    0x0a991c8296c45cc3,
+                    0xb4d58aa78f41c3fb,
+                    0xaa6e458031cf6854,
+                    0x6d41167fb5f6e74b,
+                    0xc4e94095e976d192,
+                    0x223285582538a8c2,
+                    0x117e9e7aa9b42354,
+                    0x853444083e4b800b,
+                    0x023d3d075fa0e245,
+                    0xfb06106975fbf6e7,
+                    0x00e03b4679368cb5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x32b7fa798be05cce,
+                    0x1d87f81b766026de,
+                    0x99c0938e7c6204fc,
+                    0x21f7c7539c75baed,
+                    0x439bae7d81b6f188,
+                    0xfeb4f8c9d07de828,
+                    0x46f4596d73cdfc17,
+                    0x9d8aea451975c850,
+                    0x078de36878bdd590,
+                    0xba98771810cc0cd7,
+                    0x8c6681418913af63,
+                    0x0044a35cf99fec4c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f609c8f4d2132f6,
+                    0x9426f3f848a191f1,
+                    0xb23bdfcd6a79c80c,
+                    0x799744c36b9c3ed5,
+                    0xc60396fcd73ab50c,
+                    0xc60b9acf1e6bbb7e,
+                    0xd0639a4100b95c70,
+                    0xc9ec1a63f1fcd9da,
+                    0xaa411aaf72dba91a,
+                    0x6b8725e37982f08d,
+                    0xc113d0f49ee8846e,
+                    0x0050a350dd7294b0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed7e754dae46f181,
+                    0x06f8052152a0da45,
+                    0x943ca32566735ab8,
+                    0x9c5807c80c768384,
+                    0x44658b09f17c5672,
+                    0x878ca6d029056ff3,
+                    0xe64c977819ad0b93,
+                    0x690b37517470540d,
+                    0x82aa82f09ca1c6b3,
+                    0xf6efb167f86d21ac,
+                    0x8a5ac6d626241967,
+                    0x00e25c66f5d336c2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4603fa7033ccc8a5,
+                    0x86933249a873c7b8,
+                    0x2a74cef744bf115d,
+                    0x69edcc7f004cd7ba,
+                    0xb979efb0649438e7,
+                    0x2729085531677b3c,
+                    0xc2f5d284d0cf7f07,
+                    0xed82826a65cdb1cd,
+                    0xbe3b976badd5695f,
+                    0x1af24ca8a3340875,
+                    0x007b9fe5e7c24140,
+                    0x003f53d662f48760,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe882cdbcaedf5eac,
+                    0xf2993407c655d117,
+                    0xaa33d44ae47dd0ea,
+                    0xc857df009f75b426,
+                    0xd1b757b2d9b3eead,
+                    0x0ad07c6be26b3a07,
+                    0xd3459b60848b4228,
+                    0x18e802ba00aea7e6,
+                    0x66577a7829d65a1e,
+                    0x559553ea762284d2,
+                    0x26591b4c653cd2f3,
+                    0x0018d89de7d02d46,
+                ])),
+                Felt::new(BigInteger768([
+                    0x108bd1b158095591,
+                    0x43af030c715fcbee,
+                    0xdc9cf0927ec27470,
+                    0x86e65cea3d322ffa,
+                    0xb1b16ecb70c3e5bb,
+                    0x6e25b4c4a69cac0d,
+                    0x293557dba8222bb9,
+                    0x5e690d120ec2c4e2,
+                    0x88fa375daa54ef13,
+                    0xfc7c795b25129443,
+                    0xbc424237beff500b,
+                    0x005532750ee0be5f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc551a4e82c4c415,
+                    0xb38644c687dd3d8a,
+                    0xb368cafdc5330450,
+                    0xc4a63113a588ff66,
+                    0x7f94bc0657b3925c,
+                    0xda42c0c8b9897eb9,
+                    0x1f1a1b6f5245c5ea,
+                    0x4f4a96ae10f71ef7,
+                    0xbebb746c4f0348c8,
+                    0xb2e080cf28c7c392,
+                    0x0e4727e4687712b2,
+                    0x0084e6fe31180dd2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa4ae10303383de9,
+                    0x4a1a74d3a3fd2e8f,
+                    0xa22afdc7f837d370,
+                    0x270186bd8ace5632,
+                    0x3fe1fcff3aaf3238,
+                    0xc0167467f4a47cb7,
+                    0x9e7890f5b8841c43,
+                    0xfb9bfb133f1cd387,
+                    0xc03be7531111748c,
+                    0x3e0cf6b7839dbe59,
+                    0xd259a6d4b04c907d,
+                    0x00ded3f50b87de79,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4700b6dc14de53,
+                    0x47bead063aa8633d,
+                    0xd3d96124c3d676b2,
+                    0x92c6631dc08644e3,
+                    0xf0eeef6015574ba6,
+                    0x7e409ccd7bdb12d4,
+                    0xcb178eab534ebde8,
+                    0xdf27f9e56d8e7abb,
+                    0xd56297b38cf1ca5e,
+                    0x84a62457e0264f6e,
+                    0xa046c55adbf28bb8,
+                    0x00844227e8def141,
+                ])),
+                Felt::new(BigInteger768([
+                    0x889cfb720eeb99e1,
+                    0xf23f2bfe53f77a0c,
+                    0x446d778b2abc94a5,
+                    0x90f632fff0589fbf,
+                    0x60b55bb961691037,
+                    0xacc3880ff0716594,
+                    0x295661fb22b747b7,
+                    0x0c1d5b1046d116d1,
+                    0xdfd0af2a70611d2a,
+                    0xabb98b5a2f184ac7,
+                    0x2f47d513c1385c11,
+                    0x011b4b1d1dde5298,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2029 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger768;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger768([
+            0xaae0ffffffefc167,
+            0xe499e1712ff0b213,
+            0x49ebe6d1127eeb90,
+            0xa48c17d0f0b3c9c4,
+            0x76498b7fda7478ef,
+            0xe3c1b33942cadf81,
+            0x160b7082fbac579a,
+            0xbca9f7c54096309b,
+            0x7ae19b95a2d6f02f,
+            0xb94385861fe6cf83,
+            0x468e01aafead9672,
+            0x00a8b58f2faaa255,
+        ])),
+        Felt::new(BigInteger768([
+            0x612d1a3cf93c09c3,
+            0x02700df3b9979ffd,
+            0x803d481f0663068d,
+            0xab06fce1abe17024,
+            0x299957b13c3e1e4b,
+            0xa0b3a5dff4009ae0,
+            0xf0d8566a3e1ca175,
+            0x52c0d8a6f5fd6cb9,
+            0x6f8d835e6de73fb6,
+            0xc545c86abf1e4de7,
+            0x9f274d9f661b6470,
+            0x00896c63a08b5825,
+        ])),
+        Felt::new(BigInteger768([
+            0x9983c7e27d93ba4c,
+            0xb52a2067e079b442,
+            0xa9e11c851fff8c8c,
+            0xc416b3ab2abb4e90,
+            0x4f032cabebba9680,
+            0x37882a21d8b0f830,
+            0x03a53ad2b00fd102,
+            0x3746cac800b7ef7a,
+            0x879ff08dc1a4c2f2,
+            0xbcef014d56855287,
+            0x2052d837a7f4fa38,
+            0x003c4790f3a595d6,
+        ])),
+        Felt::new(BigInteger768([
+            0xa05a6627af6a2d53,
+            0x4b57b748218755aa,
+            0x1f3e552aa31fcf8e,
+            0xed6668ceeeb69183,
+            0x47790884f143f8ba,
+            0x045ebcabae463414,
+            0x81fb829cfaa1dd71,
+            0xa0411b14a705e176,
+            0xab83f8b5d4cd0f49,
+            0x0b5756453aa5f28f,
+            0x91f47712148cd947,
+            0x000c280ea47d7260,
+        ])),
+        Felt::new(BigInteger768([
+            0x140112ea81c16209,
+            0xeb166055ef6890cd,
+            0x6bb990b0f6243d72,
+            0xd6d6c6c58dd471d9,
+            0x72a02f832b572988,
+            0xba0382808b4c96be,
+            0xf51df0b1dd485111,
+            0x0a0fe67fab61a1c3,
+            0x363b6d3bafa8339b,
+            0x5997ffcd20f1de3f,
+            0x8cf71cfc8a43796f,
+            0x0121fa846fde96c0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xd9fdc16fc7337108,
+            0x2bdecc643f94d863,
+            0x050eb307c69559b4,
+            0x1b1b4c4a84c1c2ff,
+            0x9e739df3ed5be7f4,
+            0x26bf184b252f0902,
+            0xa8846c1670f7859d,
+            0x86de6a5cfe75cf28,
+            0x82d6422754a34400,
+            0xe848c14ccdcf9d1b,
+            0x2a53b6eb1e9f47fa,
+            0x00c815a73e2b0ac3,
+        ])),
+        Felt::new(BigInteger768([
+            0x9ad1427a13241a29,
+            0x43f53b83d119964a,
+            0x7fc2c52ed666a2e6,
+            0x3d8d4969060fd215,
+            0xf9b80b7b221be589,
+            0xe273ccb47c1e7e22,
+            0x1d6ef4d2c0b3ca12,
+            0x4a50aa323f70463e,
+            0x34c5dadb33c28b69,
+            0x9dbf3b70ef2604d1,
+            0x85e228a6474af7f7,
+            0x0030d72d470fc00d,
+        ])),
+        Felt::new(BigInteger768([
+            0x3aad823aef05554d,
+            0x83047ba43f79c8bf,
+            0x6b005297dfac6de3,
+            0x3948fa893d851eb4,
+            0x0113c84ed148fa7a,
+            0xc064d3235dafe0d0,
+            0xf3f1d9794d436c04,
+            0xe30acb063a561443,
+            0x551ae75b7288922b,
+            0xfdcdff6b8a003b20,
+            0xa4aa53a36ecd1df9,
+            0x00cb3c642b4aeba2,
+        ])),
+        Felt::new(BigInteger768([
+            0x7388746d13fcd770,
+            0xa44b151faa670289,
+            0xc4a2304e5041bf3a,
+            0x69127b6dd4fa3fbf,
+            0xe79baa7b562fa339,
+            0x771a35ec10439145,
+            0x3e659bf51416ea9b,
+            0xea2babc798a424cc,
+            0x5dbd898b936d576b,
+            0xdb346da11e806c55,
+            0x53b17e9daa185bfb,
+            0x011dd7bd32334c5f,
+        ])),
+        Felt::new(BigInteger768([
+            0x06ab66452fe8bf73,
+            0x577d5117229670a7,
+            0x226fc39363f90732,
+            0x6746554c4cfb41b6,
+            0x5936880ed3513c47,
+            0xfc828955b0a8b78f,
+            0x9a32c7af39405bb6,
+            0xa3b788a15b2afbfb,
+            0xd1094a3ffb75c8e1,
+            0xf45654a0dc1002de,
+            0x77f1aea898a0be97,
+            0x002fe5ccdb183a9b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x790a228eb18f81ed,
+            0xc5cc55dcc163b1d6,
+            0x611360bad7f25451,
+            0x08f564b96198b6af,
+            0xadb91803b2eff750,
+            0xfb494673a466ba88,
+            0x2f699016985006e3,
+            0x3198ed23c4cc953b,
+            0xe671e8184de1bb36,
+            0x471ccc05413f6984,
+            0x0d9941911cf496bb,
+            0x006f6dbc446003ee,
+        ])),
+        Felt::new(BigInteger768([
+            0xd474cdeb6f9a29f1,
+            0x59ba32da5e56375d,
+            0x458f57a25604a355,
+            0x5ee537e0b3ddedf8,
+            0x8396e75f29b47524,
+            0xa4ee36ead0ea8e59,
+            0xd1022e61246f156d,
+            0x8c4e287da0f317fd,
+            0x890b7e89ecd9f2e4,
+            0x17ddb65f9cf10387,
+            0xb2a663a7757de8e8,
+            0x01229363bdce3f43,
+        ])),
+        Felt::new(BigInteger768([
+            0x8de8ba2121ff9185,
+            0xc23226fd000b4a21,
+            0x8e8232e77aef428b,
+            0x34b15ef016f45c8d,
+            0x54f8bb3a569bc689,
+            0xffe100f1c25099b2,
+            0xeb4ce080df00e721,
+            0x923b7d229a403c30,
+            0xbbb1a34d55dad4f0,
+            0xf787fa612608d8fe,
+            0x78928c98cfa4e136,
+            0x003626b37f70bf00,
+        ])),
+        Felt::new(BigInteger768([
+            0x7636178ec958e457,
+            0x79fe6442d2f3d1cf,
+            0xc78a0907f46a93f9,
+            0x8086c15dfc05e6cd,
+            0xad64941491a0e563,
+            0x178d321336c615ef,
+            0x10d19f3596c6fb1c,
+            0x98d930da7d3859a7,
+            0x004184e946588845,
+            0x1b256f35a98b9c90,
+            0x66a0d7212eba7492,
+            0x0023e48d6fe7ccf2,
+        ])),
+        Felt::new(BigInteger768([
+            0x8822ac06e4a3766f,
+            0x6ce829b940cc1f11,
+            0x1155ccb3b5164d53,
+            0x07db0c5fba33fd46,
+            0x0bba7b2e1db34fd0,
+            0x5a1b3a8501e1e66a,
+            0x708121f1a8268868,
+            0x2177793d71a73d32,
+            0x76966daa9a2201cb,
+            0xdb3201783d7a4a15,
+            0x4838ead5c68d1ab9,
+            0x011439f4f22e832e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xf5c0c593afc99597,
+            0xea14576c69d253a5,
+            0x1630d229aa9fb72e,
+            0xab8b1554e6fac3b8,
+            0x442620bd39a8906a,
+            0x6e4ca2142b847138,
+            0xd8d8653450fd7a5b,
+            0x673ec576e3e83111,
+            0x72101f77c9bf0195,
+            0x5b9578f2f475ea2f,
+            0x089f830f8ce2d7b9,
+            0x000c93212d33a0f8,
+        ])),
+        Felt::new(BigInteger768([
+            0x612493648a765d8d,
+            0xdb5d96d0c2d661c3,
+            0xebeb5de72f997e35,
+            0x616807d9e42f1707,
+            0x4cd5f37d1bb20d26,
+            0xe56efb13ef61f65d,
+            0x8c92c698890863bb,
+            0x03b73f506ed95a6a,
+            0x37cf5e658fdf8fc0,
+            0x10ec4a8e2dfcd750,
+            0xfc5de643b686fe46,
+            0x00954d619547156f,
+        ])),
+        Felt::new(BigInteger768([
+            0xb82c8fb206e8e881,
+            0x334126c4f9d4e9e6,
+            0x66ef57272ba6b0cb,
+            0xbfa6e5fbf6df2360,
+            0xe00912ad9a30879c,
+            0x9cbf8e895ad20922,
+            0x0fb5cf883c2731c1,
+            0x16fe37d37f586e54,
+            0xe2ea114f6ac0a2d5,
+            0xfb1ef07b0b09383a,
+            0x3ab0b96612d1e8df,
+            0x00640fba764fb1c7,
+        ])),
+        Felt::new(BigInteger768([
+            0x6ea37beaec604afb,
+            0x844569e4167ec5f8,
+            0x0c815286807f0dcd,
+            0x0236eb340e284c0f,
+            0xb90b9daffa626bf8,
+            0x3141ecb874194a68,
+            0xb4eba94241723ccf,
+            0x6fa8d0a3d4727e1e,
+            0x18b92921b434b8a0,
+            0xdb826bee036e5e6c,
+            0x9c2b3f189926cb81,
+            0x00d67cc23007db03,
+        ])),
+        Felt::new(BigInteger768([
+            0x392db551f37a4f10,
+            0x8136cd0ae2be2cac,
+            0x543eddf7e93d1c3b,
+            0x40e0c76216b319b1,
+            0x2a9afd34495f1c2a,
+            0xbb69fab5d6d80c2e,
+            0xb14e7f1d9a0d029e,
+            0x7b4bd5e9dfe23d4f,
+            0x46538d6796a21786,
+            0x64dfcefff78de175,
+            0x17f6b050b9e24935,
+            0x002003664e9e4d74,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x9dcce12fc3b230e2,
+            0x482327e32463c42e,
+            0xb0ee98c5401da783,
+            0xa63bd340162fdfdb,
+            0x7a1deba7262a02aa,
+            0xa9dece19ce4edd5a,
+            0x8bbfec540a6c8a20,
+            0x3a9cf3b931fcbd42,
+            0x247d75213e7949fa,
+            0x8ffaf3c8e3096319,
+            0xbfef5223056ac774,
+            0x00bebf400fcc5b30,
+        ])),
+        Felt::new(BigInteger768([
+            0x415afc126ce13156,
+            0x5bf8b93c13d6238b,
+            0xf1b428c0459468cc,
+            0x1983c4aa10bf9014,
+            0x3050199f357de82a,
+            0x22c2ad1aa77cd47b,
+            0x04def0d1bb0d21a9,
+            0x9955e7666a36c511,
+            0x0b70568286ac0228,
+            0xdf33eac6e07a2abc,
+            0x6e35a03f9370270f,
+            0x00350230674fc368,
+        ])),
+        Felt::new(BigInteger768([
+            0x7c4e01463cf5becc,
+            0x61f4e26f545e4eae,
+            0xb8fdfb14f1e6fdbc,
+            0x54f536bf34d363d1,
+            0xa91b5fbb7c964166,
+            0x9116b1550bd945cc,
+            0xf62114677a109963,
+            0x8391f61c00e25572,
+            0xc830ce900ef9ad93,
+            0x58aba008e28f9e22,
+            0x981562df01e0184c,
+            0x0038ff579ed9eeba,
+        ])),
+        Felt::new(BigInteger768([
+            0x9d59dd24bd0ebb8a,
+            0x2b93dfc54070b716,
+            0xeec76afe68375609,
+            0x2fc07ccec53b5600,
+            0xe5b8ba464bf27a8e,
+            0x335db42ad165e8c2,
+            0xfb85c369e34f2e02,
+            0x79c39f29a8d24893,
+            0xcfd29d3b0c0f9d1b,
+            0x2588bf72ff395e5f,
+            0x9dde23658ef412ca,
+            0x002ae2156a6aae36,
+        ])),
+        Felt::new(BigInteger768([
+            0x269b8588a2e64418,
+            0x8d79b6df200319e5,
+            0x20c8f522b841faf2,
+            0xd3d473adf4e99f0a,
+            0x717ddc73d744952f,
+            0x8be2e4879ed48fbb,
+            0xc11bcf5cdcc12927,
+            0x4c422f1822ab871d,
+            0xea69ef44ab418c65,
+            0x7d2cef5fdc157385,
+            0x1f05de9dc5480b47,
+            0x00cdd6b55e5dee79,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x84de48ada811211c,
+            0x62d41b2e34bf5732,
+            0x752cc0aeeddde33a,
+            0x35f558ab68ae90a9,
+            0x8365e1f81965ea09,
+            0x5c0936dd959ea0e4,
+            0x5e3c827c2a6640b4,
+            0x7ac92d089c400ef4,
+            0xd42b15d1aaf63e8d,
+            0xd3d0a0db57e67772,
+            0xfdb7189efd805687,
+            0x002efa6deec9da0b,
+        ])),
+        Felt::new(BigInteger768([
+            0x181142fce22596da,
+            0x2f49ce59079992a2,
+            0x0e53812e39eb211a,
+            0x5e6cf260ff26a95e,
+            0x661cd97a57cde18c,
+            0xcf9377e747c8b0d6,
+            0x39f72d5618aa3033,
+            0x4e087cf79b62cbf5,
+            0xf2bda575128302bc,
+            0x2c02840ae73fa6e2,
+            0xd6ca691adee6fb2a,
+            0x004f314f9f005e57,
+        ])),
+        Felt::new(BigInteger768([
+            0xc366d53960dc0716,
+            0xb028cf759231af82,
+            0x4cb5ac02a7edb8d1,
+            0x742b008799431866,
+            0xd882cfd848865222,
+            0x3f580728d4f75c87,
+            0x500b31d829dff121,
+            0x588036e9650433ed,
+            0x65fc973443d8143f,
+            0xec4abe70be347b24,
+            0xf308f64502e75ae7,
+            0x0003da912c16810a,
+        ])),
+        Felt::new(BigInteger768([
+            0x9fb2d0e5cc324bc0,
+            0x4fef61a39df9662d,
+            0x12676e980cf6b0a6,
+            0x2ae7482717b7e79e,
+            0x316c136f85da8666,
+            0xe704644a32d469f5,
+            0x8e59fe22c01b413e,
+            0x95a5f22fab3a5580,
+            0xcb75cc99b40cc5a0,
+            0x0cce444b900c962d,
+            0x051d08f90eead034,
+            0x011d9c768dd5d731,
+        ])),
+        Felt::new(BigInteger768([
+            0xdb441d9548891ccb,
+            0xb34ee8bc3de70fc9,
+            0x9c67fed02d49b6e1,
+            0x34f98560cce78e63,
+            0xce8a6829aa5d92aa,
+            0x920cfe6674ec00f6,
+            0xcdbff0493bdf273f,
+            0x643cd4e768ef03ba,
+            0x6d303afc9d3408ce,
+            0xf3ed60452e7b83cb,
+            0xabce495eed502e3f,
+            0x00ab39449b4a2419,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x54428e1d92d16362,
+            0x496b58bb84409140,
+            0x2014031d06bb1b88,
+            0xffe968ac046b9882,
+            0x1ecef239cac8a2a0,
+            0xe28331115412a7eb,
+            0x8f2e1f6435e6f2c8,
+            0xeb3373a022dc7924,
+            0x62dd8c5fe862b2de,
+            0x50b563787e138444,
+            0x252f1e3e85733abc,
+            0x008cb31e9b873b5a,
+        ])),
+        Felt::new(BigInteger768([
+            0xa0728bdf597ec91f,
+            0xafdec06e26f94e21,
+            0xd6aef65514fdaa27,
+            0x550a5a5995fc6dda,
+            0xa1da6b9047f3976a,
+            0x1dbb06ae9705313f,
+            0x688b7ce716d9e6a0,
+            0xe63c261809efb848,
+            0x581cbe07e3e41a72,
+            0xe644311819700b72,
+            0x4bad6e27f8739be6,
+            0x0035fe2a3756723e,
+        ])),
+        Felt::new(BigInteger768([
+            0x3bbacf2a73d2ab31,
+            0xc2488ab59d60f8d5,
+            0xc7aee892913bc746,
+            0x80eb326ab59f0e65,
+            0x4e1c2c2f02842ac2,
+            0x58d95586d0e8a95a,
+            0xb87056b34a9e52ae,
+            0xfb29fc3f06abd25e,
+            0xa576935f17cef2ef,
+            0x0d8f748cd481a0f6,
+            0x911214a1c513609e,
+            0x01052dfb4ccd8850,
+        ])),
+        Felt::new(BigInteger768([
+            0x19d80ad4e6dccc9a,
+            0xb6df26202273f3b8,
+            0x45b69a345d1a3c99,
+            0x19eabda7ee48d7f7,
+            0xa51ce0aae071dfdf,
+            0x44ff9713ccf81ae0,
+            0x990f59fe0f8c01bb,
+            0x2eddb69615fdbb9f,
+            0x42b5b3aa2a5fa95c,
+            0x1ebb6cdecdfd0a1b,
+            0x7c4c80212464f9df,
+            0x00e8a7ea8f6520dc,
+        ])),
+        Felt::new(BigInteger768([
+            0x8533aae8e016c95c,
+            0x6428c27fba6f638f,
+            0x8fdb8331bc15aa22,
+            0x57e568ae36fdb47e,
+            0xc00f051c50ee8840,
+            0xd5ea572b0c6b76b5,
+            0xc01cef0c9dd3daca,
+            0xd9cd03e78a23ce19,
+            0xe979cb174dee43ad,
+            0x1d96f4d736c53098,
+            0x79df1340114ef59a,
+            0x01128a15a9a961f6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x2a643f949ca6b0fa,
+            0xa12b3a449dbd0f89,
+            0x703acbcaab89447f,
+            0xf62588d4f50232a5,
+            0x7cb210affcea57ea,
+            0xdbb684c9e6d55aa9,
+            0x41f31182e6ff2e9c,
+            0x6036ce06c1691370,
+            0xcb93d3ab99d0dcde,
+            0x7fcff5d16012e67a,
+            0x47e2c320e94e83fc,
+            0x0031bf6d9aa59a76,
+        ])),
+        Felt::new(BigInteger768([
+            0x6b642884555b5104,
+            0x10c179b3defb2468,
+            0x950ead621c0112ad,
+            0x3f95b389c827b3bf,
+            0x2e6b1485ae986f3a,
+            0x65c089a68cc2db6e,
+            0x6533780715498d58,
+            0x337b9107e75de23a,
+            0x99b55ad588118901,
+            0xea2e8197700e63b3,
+            0xa43cf0cdc38292b1,
+            0x010a2c7df66964fb,
+        ])),
+        Felt::new(BigInteger768([
+            0xa4b4ae09cbbb6eb1,
+            0x68e171e30cde7c68,
+            0xe2aaa8eb68ed930b,
+            0x6492ff7f973d4f8f,
+            0xd401217a5f93399f,
+            0x37455f7d47ba97ee,
+            0x9960249f03ce3909,
+            0x096a154f92805d03,
+            0x0ae1f60f5216aeec,
+            0xf80903a750a43435,
+            0x511d13da596385e0,
+            0x004360231a20851e,
+        ])),
+        Felt::new(BigInteger768([
+            0x12b8b22bf62203e7,
+            0x419a8e4bceb6a744,
+            0x61643224bf4f62b8,
+            0x855b0663786f211a,
+            0x077f183c67ac8e24,
+            0xc499f89983635775,
+            0x14b09576970646b3,
+            0x478e54a963034c31,
+            0x13a076675058101c,
+            0xaa7dce547af8b006,
+            0x98aa7299f536cc32,
+            0x00ad121bcb9625d7,
+        ])),
+        Felt::new(BigInteger768([
+            0x4ce3de455aca9757,
+            0x85982e6543b58262,
+            0x28e7dcaa24ca7d7e,
+            0xa6b140826df39019,
+            0x9b893a2d7bb417fd,
+            0xd199f883c319987a,
+            0x03c67ed38c965356,
+            0xa0a9e7f0df16c4a6,
+            0xa74a963af321cc93,
+            0xee9cfcf241fb7cd0,
+            0x2013ea0d30352cfe,
+            0x002820652473f642,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x23b69f3ce571eb9d,
+            0xcc94ec6cebfc6af9,
+            0x395a4784850e55a7,
+            0xa531d84144eb2d47,
+            0x10597d0d8e8743a0,
+            0x12cd36ba1ddde408,
+            0x2c7f462200750349,
+            0x90d85e936e11b44b,
+            0xd6bc6cfe0d09d937,
+            0x1de368948b17027e,
+            0x2978b876164bcba7,
+            0x006aac39e754ef75,
+        ])),
+        Felt::new(BigInteger768([
+            0xf1bb5ec32c025b22,
+            0xea0550496cee7f9b,
+            0x62fdb383bc4b5f3b,
+            0x18bd6a615c2db4b5,
+            0x2c0e48f81602611d,
+            0xc5dc2378a133e195,
+            0xfcd04e94628e06b0,
+            0x2d267c609c24b3ac,
+            0x41bbaebf3f14078b,
+            0x222f58b60d8e3b39,
+            0x55b979d46e8cecd7,
+            0x0036d08480037594,
+        ])),
+        Felt::new(BigInteger768([
+            0xcd967d7b71948cca,
+            0x0c4d3d1c14ea2a48,
+            0x7cc5131684482c44,
+            0x12d05f48fc707cc0,
+            0x15dced73b3e9120b,
+            0x9f70f5e8de7d0f24,
+            0x2cbce1df2aaba403,
+            0x2edd500db7ee8149,
+            0xbbaaa4b6154c3889,
+            0x2e35e91dee757018,
+            0x670aae2be609e4f8,
+            0x009e414b69d88a26,
+        ])),
+        Felt::new(BigInteger768([
+            0x26e31bceef54307d,
+            0x3dcc90a0324c34e8,
+            0x9858ab98ecb0632f,
+            0x5464855e3fc12cc9,
+            0xa66b5a27ab15404c,
+            0x2a4f1779b30c313d,
+            0xbca6430826c9d6de,
+            0x28f994dbd474106f,
+            0xf4d0427501cab4af,
+            0x0a53f06aef2c1003,
+            0xb14b3c693a4e2969,
+            0x00ec3d22a4f9e3c7,
+        ])),
+        Felt::new(BigInteger768([
+            0x33fe48673dae4125,
+            0x089a37d7cee5cc83,
+            0x1fb4deda321ef8e0,
+            0x552ada96f1ea2db4,
+            0xf7f730f462ef4c88,
+            0xc5bff1a3a0a8fc1c,
+            0x8170a03cbb371487,
+            0x5e98c13e99730c50,
+            0x13205cfd3c47bcb5,
+            0x86d1b7f450d3fb8b,
+            0x9a3c208a1c6e9f12,
+            0x00d3c7c0323ff687,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x054d4fb1836d76e9,
+            0x804114596826f73f,
+            0xc639c96b279e26c4,
+            0x33bd8b669b573f91,
+            0x3fd05d25c145ddd9,
+            0x685b06fd66cb9682,
+            0x3fa3971270e16e6c,
+            0x02dffee42a8997ed,
+            0xe08dffa8c10bba27,
+            0x200599ff5c918c38,
+            0x9a888595a3f4f1a7,
+            0x00d8c801fa610469,
+        ])),
+        Felt::new(BigInteger768([
+            0x50801d22b055d5bc,
+            0x99a1db9362b4186b,
+            0x3f44c6e1e8387099,
+            0x0c582ad88470a6d0,
+            0x78932e48e63bffb5,
+            0x350a2e0a071292c4,
+            0x3bf98c79a0ff0a73,
+            0x939283cde7eaea86,
+            0xe4c506604a0faf8e,
+            0xc72d94e52f3e9128,
+            0x2e067462c931dbbc,
+            0x00d8d7081d8a04df,
+        ])),
+        Felt::new(BigInteger768([
+            0xf4d7909adfe36990,
+            0x633f04fc485e4fce,
+            0xecef23454066f15f,
+            0x7b6fb72849a445fa,
+            0x1f5fd501a7dd7282,
+            0x65114e6b06d15440,
+            0xd38df82c1da072ba,
+            0xbbe38b1e0236b1bf,
+            0x68dd0fe3e504c56d,
+            0x84c2944b58399bc3,
+            0x3fbaf59aee5c1d7a,
+            0x0023fa2baa5156d6,
+        ])),
+        Felt::new(BigInteger768([
+            0x04952148a5078295,
+            0xa59144430597d0b5,
+            0x99f673491ad5101e,
+            0xa9d4fcf52038a6ba,
+            0xe252ff0c79ea3f30,
+            0x45afa391c1cf902d,
+            0x348a84b1871e2774,
+            0xa6a497c0fbcbdcda,
+            0x7c42099a73c35cc7,
+            0x27b8ced861bac562,
+            0x47d8f5b6fb466830,
+            0x0027826dc040858c,
+        ])),
+        Felt::new(BigInteger768([
+            0x28cad79104a343d9,
+            0x47875ec6765511d8,
+            0xe2ad696f19e3a688,
+            0x84ba937314831021,
+            0xe10a6efc4ef574af,
+            0x3085488ff108a659,
+            0xaadd8b1b16fc6dc0,
+            0xfe90a6d0f64a111e,
+            0xb95082b2d8225fdf,
+            0x87ffd44b08360b68,
+            0x74fe8a97ccde2395,
+            0x0117ee4eb3eb045b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x701845e4b61296fc,
+            0xecdcfba854f5c929,
+            0xd0ce4970f0234193,
+            0xdc2d5b3178e857c4,
+            0x43d506a964d93634,
+            0x7b3f1f562fedd427,
+            0xfabdc13c4fc24b1b,
+            0xff08a63ba30bb610,
+            0x59c331393b195f50,
+            0xf0cd74f3c1a77dd6,
+            0xfcce02ba4c53bb39,
+            0x00d3c8352dada596,
+        ])),
+        Felt::new(BigInteger768([
+            0x6009ec02b55bb7c6,
+            0x303131e415c1d184,
+            0xc1be34577a9be66e,
+            0x278a5aa1b3bbfe07,
+            0x3c34916c44c80cc0,
+            0x9445a8df4e0aaba6,
+            0xbc5860e95ebfa6fc,
+            0x798faf90f74d1ec7,
+            0xb0e31ca130d31402,
+            0x7d5ad50b9ae03fec,
+            0xfa96eeeece700355,
+            0x0072cbb6f33d9b1f,
+        ])),
+        Felt::new(BigInteger768([
+            0xc15b9af7b841437d,
+            0x168c2d99a0cdde83,
+            0x8ad32454e774dec5,
+            0xab55f04cb07606f7,
+            0xf671b645f9313ed9,
+            0xa66e6fc4febced2b,
+            0xe71c979fb59c13a3,
+            0x5bc8a42e671bb4be,
+            0x6f72b1a539035cd4,
+            0x8325b8d166d26ed4,
+            0x0f08342e5ad61255,
+            0x01052a8faa30f1c3,
+        ])),
+        Felt::new(BigInteger768([
+            0xd26e4eb1d16e4eb2,
+            0x85961b9ab1419ada,
+            0xa146e286c8f8a10a,
+            0x94f250f1606e34b2,
+            0xb58e118295067d1d,
+            0x7e0674a3d8c8e4ed,
+            0xfd6dee8a935a0923,
+            0xf3b0e91c0698a834,
+            0xa6e693aea651cfaa,
+            0x5043490973294d01,
+            0x8d055887fcc2e632,
+            0x0069936db8da4219,
+        ])),
+        Felt::new(BigInteger768([
+            0xa0172181c9fa1077,
+            0x484fc88dfc242462,
+            0x7433913fe93c849b,
+            0xd9b43513d0c7e9ec,
+            0x396097dcd25575f7,
+            0xa61c369a465be4b5,
+            0x81b1625f7c188aba,
+            0x9b2cba61cd6d7cd2,
+            0x77615e416f190917,
+            0xc451d810790e1f89,
+            0xe4948d11cdd579ce,
+            0x00ed7bfa272b2a63,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x069e7574ba98912e,
+            0xfa83c662abb9acaf,
+            0x1f2003522ec74257,
+            0x469329b0e22058d2,
+            0xb559fa705cb76ce6,
+            0xaa63e2c05a003c3f,
+            0x1b465d1b9ed5c241,
+            0xf4ee69edad46ed24,
+            0x7532cc2b34fec0af,
+            0xf2d40658e9176c6a,
+            0x401dc5c5898b2ddc,
+            0x01100a4d20ab30bd,
+        ])),
+        Felt::new(BigInteger768([
+            0x919e1edb444d59b1,
+            0x172581d2b4ee2910,
+            0x01fdf734fa89ff64,
+            0x2113124885ae5d3a,
+            0x603accad9d3e12a9,
+            0x591e1b3dac9b92d1,
+            0x3add41c5e2234503,
+            0xb291193666884a2b,
+            0xfe2e337f9d38288d,
+            0x406bac38217bfd1e,
+            0xf340ffa2fb6c3d74,
+            0x00fc1a36a2fcef35,
+        ])),
+        Felt::new(BigInteger768([
+            0x8109c518768d90ae,
+            0x85bb805cab89863b,
+            0xb81f8370a353543e,
+            0xf638aede81f84b51,
+            0xa43754acdb0b2c26,
+            0x79da8a41d25f283e,
+            0x37eb24706abc9301,
+            0x0be7d71a1432eb87,
+            0x5bde2832aab111ea,
+            0x7e39dab24a775674,
+            0x4d9f0e9738ea71d5,
+            0x0018c88d13f3bb1b,
+        ])),
+        Felt::new(BigInteger768([
+            0x29668d42009e0c0b,
+            0xe0fd5c00001278bc,
+            0xa4f187e2d6e7a1e2,
+            0x6aaf9dd70f1a85cb,
+            0xd23b2a0fa0e4c96b,
+            0x015349cee924be72,
+            0xaed95b376d4c9114,
+            0x7e5bc6c4ceedf42c,
+            0x179c7bbc613d624a,
+            0x4fa1f17cceb55148,
+            0xab67eac4bdae30ab,
+            0x00836cb959ebbc7a,
+        ])),
+        Felt::new(BigInteger768([
+            0xc7211d81f113eee0,
+            0x7c964c892216f37c,
+            0xb3fae2b651984cd0,
+            0x1e6e8d63a9067343,
+            0xfe037d73b4da24f1,
+            0x2a8c69d58def81f6,
+            0xc39195259a00fa8c,
+            0xf216a4c97e4622de,
+            0x5210401f2081ccc2,
+            0xf1a0fa41f31661c8,
+            0x32157cf387dbfc43,
+            0x00b601be2d709090,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xc324ffd73211b077,
+            0x41ba020503c5afd0,
+            0xc25d81b57bc3000c,
+            0xff919483362c85fa,
+            0x368b0cad4fccd6d6,
+            0x4827b956f9e1b1de,
+            0x7e3cf33ef3c08f38,
+            0xfc9b35e07344c29b,
+            0x135bab6d577c07f5,
+            0x9ea561f5f43a797f,
+            0x86ad42844738647f,
+            0x00aeaca69d386a09,
+        ])),
+        Felt::new(BigInteger768([
+            0x2f029a990b8fb76f,
+            0x9e6712895dcd73c0,
+            0xb6268decf99c6fd3,
+            0x61227c716996502a,
+            0x1e429afba2901524,
+            0x42e5f34c32532e29,
+            0x765522408d5a66e7,
+            0x90b5b61af948968a,
+            0x661eff651209df1b,
+            0x0c78abc802d0433b,
+            0x62633f957b786aa2,
+            0x00c141ccb0eb6e6e,
+        ])),
+        Felt::new(BigInteger768([
+            0xe7c19d55a1b603b9,
+            0x381552ac55cac4d9,
+            0xed3fba1ad1acf0fc,
+            0x376296417e860668,
+            0x094fb65cec1f73f8,
+            0x08171677973561cd,
+            0x61855a044974bd47,
+            0x16f611bc81ff7aea,
+            0xa14a00abe07bedef,
+            0xeb4d9ee54cfa56df,
+            0x212d928bfe7fc4b0,
+            0x0107741a648bf2a2,
+        ])),
+        Felt::new(BigInteger768([
+            0x707ab4c20dff3b80,
+            0xc818d0e6a4fb7590,
+            0xa917181da8c8d2ab,
+            0x53610c167f879a80,
+            0x1d6f8c74ce40966d,
+            0x569a2903a79d23f5,
+            0x8089a8a50cf6a440,
+            0x6c8c9a20611cecba,
+            0x8daaade18a48fb8b,
+            0x6033b1930e3aaf7e,
+            0xacc173c4b3102ee9,
+            0x000169a5db625d7a,
+        ])),
+        Felt::new(BigInteger768([
+            0x9615fab099364c7b,
+            0xec2097d914cf9b73,
+            0xfd15268ed4780bb1,
+            0xf59bd11c8db628d2,
+            0xd82119b23199a427,
+            0x36fb22b45faa5619,
+            0x601fc4b0e060b2d9,
+            0x8210f3b5cf9d5c7e,
+            0xa809145beff29a72,
+            0x5d37b881ab372eb7,
+            0xb2ddd031bdd1e915,
+            0x004854775a7f57a5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xdcebd61bb1ab35ed,
+            0xae96ee82263cc5d0,
+            0x395d57cf159ba2ef,
+            0x8d2845ced0a47a42,
+            0xe258971f35f2d4e3,
+            0x1ae11bcffe30cc02,
+            0xe8536a4b3fb30a7e,
+            0xc658fd04daeb315a,
+            0x8754407e2b6eafd3,
+            0xecd299d6c84b3d71,
+            0xceb14af8f51741da,
+            0x00731af14971cbe8,
+        ])),
+        Felt::new(BigInteger768([
+            0xaccd1dd70ead8490,
+            0x84bc893f6f1a8af8,
+            0xb43be7ae1be520e3,
+            0xdb879dfbc38ef032,
+            0xc7c5d14b5749317e,
+            0xd8d89aced4e6371e,
+            0x2f65acddc3e76864,
+            0x7705d26308d7e352,
+            0x1fd287988250d20c,
+            0x9e85c3727d4c3a62,
+            0x74d4ffa1efd5b1ce,
+            0x0112a9548d61decd,
+        ])),
+        Felt::new(BigInteger768([
+            0xbce60da68ad475e0,
+            0x15b3cc750b46a5fc,
+            0xb3bccbd7e790fd8a,
+            0xbb3bf3f66d272f5f,
+            0x9e400a276bd3df09,
+            0x31d6bef281c67077,
+            0xf13f2bebadeaa6e8,
+            0x27fa4f95feed959b,
+            0x2f841651c2940455,
+            0x3d514929ec5925f9,
+            0xb9219caa0f82a78e,
+            0x00f543579dd6486c,
+        ])),
+        Felt::new(BigInteger768([
+            0x3d0c70812e5ce91b,
+            0xd901716cad390401,
+            0xabd5f5c503b5a56f,
+            0x317f8701d1221626,
+            0xd95b27b40d1800d4,
+            0x409544e44edae3ad,
+            0x5ca592adddce0895,
+            0x5f0f28b580d9ea11,
+            0x6e9ff2f5e67d80cb,
+            0x43a4bb84bb5243e2,
+            0x4aabd20c657cb481,
+            0x00bcf567a88e499d,
+        ])),
+        Felt::new(BigInteger768([
+            0xce9b4cea49fa60ba,
+            0x06ec63681462ca55,
+            0xcd1c8dd5acd81b06,
+            0xe93c0a4fab4fe1dc,
+            0x24acce1527459c53,
+            0x41b2ce610bd57024,
+            0xabb36b885830c760,
+            0x2a15d86a75c455cc,
+            0x1327011e65e447fe,
+            0xb0523cd4b9520a91,
+            0xec41926a0432ee09,
+            0x00472733d635b2f4,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger768([
+            0xb193ffffffef840c,
+            0x1e7d5557bff07845,
+            0x8e32219b5e466ee4,
+            0x45710c08ccf23442,
+            0x33d00730af48d55e,
+            0x80f00293e271f2bf,
+            0x67cae5e35728e29b,
+            0x161598a7f0eb4c90,
+            0x0f4b8cdde6ebf54d,
+            0xff72be82d286ad57,
+            0xc99e2c68cbe2ebc2,
+            0x00403d3c2a7caf8e,
+        ])),
+        Felt::new(BigInteger768([
+            0x5c3ff79e2cee7224,
+            0x1514222ca33b5845,
+            0x690fbabf5a309443,
+            0x9ac3cd6b7b57f280,
+            0x01bf08472303226f,
+            0xbc454852e8d30275,
+            0x3ada4b55d93668e5,
+            0x7d0ea707478f732c,
+            0xaba927e3b16df79a,
+            0xfa53b309aad08a90,
+            0x65d9f3fdf20f89fc,
+            0x008081d0788530b1,
+        ])),
+        Felt::new(BigInteger768([
+            0x9014c778b7f67f7e,
+            0x2c09e35fcaffa1ff,
+            0xf5ace3373839a64b,
+            0x5b6ca2e971e52b8a,
+            0xaf0e9ee16ab6ae7a,
+            0x4af35f4c0d664fe3,
+            0x52aca1d98067fe3a,
+            0xad633dc75e5d62bf,
+            0x01b905caaa2196a0,
+            0xe56c837443c81da8,
+            0x032b5be47e800df5,
+            0x0118f8153ef43990,
+        ])),
+        Felt::new(BigInteger768([
+            0x4291b9784b9cc314,
+            0x8662f6d0ae4995a3,
+            0x8f838ff5d14f2bed,
+            0xbc3c2b5356bc0ae4,
+            0x566e49eae39a7193,
+            0x62418e73ed411849,
+            0x3dedf7b55826b876,
+            0x38cb8ebf2e2c3981,
+            0xca4c0e786b625ea6,
+            0xe63db2ea17e6804d,
+            0x300861caf99c6345,
+            0x00d95f238836ec31,
+        ])),
+        Felt::new(BigInteger768([
+            0x512cbbdcf99e70c8,
+            0x757e7bbaf030c705,
+            0xda4f28a7a543d9d9,
+            0xa1e0d161f9abe1cb,
+            0x3a7e5c3e17aa961e,
+            0x558847b31484ae7c,
+            0x2f7ef9ea2fd9fd33,
+            0x66d34c7a9a30a87b,
+            0x25f94fd0ad4f3cf0,
+            0x08d1bbf62111b49e,
+            0x2d1c9721f85b36fa,
+            0x005fba68278f1b92,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xdbbd1e24ca6628f1,
+            0x4cb73fe87174abc9,
+            0x4b8133813e3118ad,
+            0x04c4bd6d65567ee4,
+            0x19bc660995f1505a,
+            0xc12a6a2f1f69e9e2,
+            0x2390ea56ee70488f,
+            0x7a85417a1d245d23,
+            0xc969e11e9531c619,
+            0x108bdc396f35bb47,
+            0x16ddecb28b336684,
+            0x011df9c25c90a567,
+        ])),
+        Felt::new(BigInteger768([
+            0x90f07c904a0977ce,
+            0x3d8e4f5a5c9d5bc6,
+            0x6ac17d7e56086c42,
+            0x760e96ddd9dca5d8,
+            0x8fa00875dca1f5a4,
+            0xfb4271b0cb84b359,
+            0x90bdf29e7dc9c974,
+            0x0ed9aeccff5bbeb5,
+            0x230b2d0f73c2c049,
+            0xb4e107ffc99e81d3,
+            0xb60eda0e729de6bc,
+            0x00e64908429d2603,
+        ])),
+        Felt::new(BigInteger768([
+            0x4310de862c9b0ead,
+            0x13b6c168ebdfc2aa,
+            0x8cde6d9b4dd97ad8,
+            0xe821392ddc35fb25,
+            0x3b27df303c2e200d,
+            0xc3f978fcc7050b04,
+            0x64a8d361f42f8b25,
+            0x126b27ce3b4b275c,
+            0xdc58be68566f6653,
+            0x961332ad6266b988,
+            0x4ded4fd9dac0fc72,
+            0x0020790ca32580b1,
+        ])),
+        Felt::new(BigInteger768([
+            0x27922472b362615f,
+            0xf928d774f9094eb0,
+            0x0af9bf6ad4640ecf,
+            0x4f6a8d589486b898,
+            0xd099908d346f29ac,
+            0xc526786383de47fb,
+            0x1c07a3ef482fb789,
+            0x3bfa093ac31a1caa,
+            0x89aa611e256ca741,
+            0x25e27b60e6e4ad5b,
+            0xb82fe1e02490b0b6,
+            0x00639af64278b784,
+        ])),
+        Felt::new(BigInteger768([
+            0x3ee36becaaf8c376,
+            0xc8da6c19c53eb413,
+            0x9331a1393eecdf3e,
+            0x7b14dcd3bd29030f,
+            0xded7012e9365b4d4,
+            0x9544511194749cee,
+            0xfde0d9c7adce3fca,
+            0x9ab624d6b85374b7,
+            0x72f0da83f5964f32,
+            0x85a3f2b9caf61996,
+            0x819133d7a617455b,
+            0x002c021eb65c4cd7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xbee3f32bb2b14db9,
+            0xac2db8201ebf59b0,
+            0xc6b3c6f2c6f8f2e0,
+            0xafa307796c242dc7,
+            0x23cded6e1a534bbb,
+            0x5bef14063159bc5a,
+            0xd983c8b39cb8fa80,
+            0xa7ad4e5a91924a59,
+            0xd0dea2988cf88e27,
+            0x08e915f0d2893891,
+            0xf269bdb12dc5711b,
+            0x00f91dee042a2bbf,
+        ])),
+        Felt::new(BigInteger768([
+            0x1a117be9a46e9aee,
+            0x324af707a555d0cb,
+            0x39aefd016220a80f,
+            0xbbc9a0305b39d3f6,
+            0xa26e1dd22f1c7216,
+            0xfd6d8ea8a00ee6f1,
+            0x6f902b8a42c12274,
+            0x62c910f92970ce80,
+            0xc8a77657aadaba5b,
+            0x0f629982e57beae1,
+            0x0991922840129445,
+            0x00e8e9305f3c645d,
+        ])),
+        Felt::new(BigInteger768([
+            0xcf038a545d845f53,
+            0xfefe99e947ed1903,
+            0xe59b2c584b77d34d,
+            0x392ee5f436039d29,
+            0xfbb5b34cf43ad751,
+            0x5039eb66d157e268,
+            0x8ee04fc5329159f1,
+            0xb4850a3d2ed15f86,
+            0x394b0bd2b8d1b831,
+            0xe27c750c70244ed0,
+            0xeba39867e4d07ac4,
+            0x00e217979433af47,
+        ])),
+        Felt::new(BigInteger768([
+            0x62f73b7c66ad82b4,
+            0x7af653bfbd11f2ed,
+            0x431c7691dae8675c,
+            0xbc841ba83bf0c3d1,
+            0x030b5b57a29a570a,
+            0xb25db9265012eb28,
+            0x21501c8b77841bb8,
+            0xdd90bea03b4a61c2,
+            0x2289edf45767e734,
+            0xb882c45ee3a4d4ff,
+            0x94ed49fc526a8461,
+            0x00c05c021d159350,
+        ])),
+        Felt::new(BigInteger768([
+            0x0fd825965da28dca,
+            0xbd3cf5129ef0366f,
+            0x8b3897691c8460bd,
+            0x400caec1fdf0d0da,
+            0x1a4a2dc628a9b534,
+            0x328db502656bef2b,
+            0xff6e33677df07a21,
+            0x2a67f9539761f3f9,
+            0x69d4a38812431ab3,
+            0x4ce2b6259a72cb25,
+            0x7896ed1d73455e15,
+            0x00213a387353548d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x41a01cd5122e50fa,
+            0x97bb62e703908e31,
+            0x4e239f7c2e777b06,
+            0xdfc02325a6109280,
+            0x937b66a78ca7c7ec,
+            0x34dc4df236747754,
+            0xbd133402465c494a,
+            0x0dcfa6e66d92c1a0,
+            0x6546e7cd9d647dd8,
+            0xce06bb869be192d3,
+            0xff2ccb0e4e932f1d,
+            0x009ad8bda1e12fde,
+        ])),
+        Felt::new(BigInteger768([
+            0xacc6c807208dbe21,
+            0x7b34043546388de1,
+            0xb25d6a60d086a838,
+            0x4bd3db3a401554b5,
+            0x44ed9a700cb5ed2f,
+            0xa3d8311d3c835340,
+            0x654159f298504c15,
+            0x0aaea804b43bec5d,
+            0x80356408e26f0088,
+            0xb91626598ca99841,
+            0x6505e0a331fb26a7,
+            0x00603898eb98a19e,
+        ])),
+        Felt::new(BigInteger768([
+            0xff4ce689a3b0a5e6,
+            0x375342e87e194b79,
+            0x905ab7b2910066d6,
+            0x51abd810ca78bbac,
+            0x60067b40236b7b7b,
+            0x53025749e7d65624,
+            0xed69d4fd80ad7fe3,
+            0x69c44526d0ce6d19,
+            0x694d87aa62462f67,
+            0x96b863ce6b4687a3,
+            0xbf7e9113d8dcff72,
+            0x011496093ff60923,
+        ])),
+        Felt::new(BigInteger768([
+            0x6ccd267ceaf7d864,
+            0x65f1c42fccff7944,
+            0x44592e7c10dd6241,
+            0x331a99ccac35d7ca,
+            0x76160196830bc187,
+            0xab728929f869265b,
+            0xfbbc01c9ed7115b9,
+            0x7461386969e47890,
+            0xf1492a12d94b029f,
+            0x705ea154d1d829e9,
+            0x0aacb49268bb5917,
+            0x0054a17c96953a6c,
+        ])),
+        Felt::new(BigInteger768([
+            0xbb85b585cdbc568d,
+            0x7f628003ed44d73d,
+            0xb6810876d06cf926,
+            0x9f3aeb976561eded,
+            0x8447f428b3dd63d3,
+            0x80501c6bcb5916ca,
+            0x7e2ae1c38680f2ae,
+            0x25347c71a806b8a0,
+            0x94de470a23d99801,
+            0x405b94bfec7982a4,
+            0x2b9947b71c7508d4,
+            0x00548139802a53f3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xd2449d72cbe356fe,
+            0xde885cbd79cd41a0,
+            0x8fc35ddf7e1241ca,
+            0x99461e76687f1561,
+            0xe66a7dacc264a951,
+            0xc98d3e4401daf3e9,
+            0x4fff9015ae693c06,
+            0xffc23d8fdfef1bc7,
+            0x79e40fd5ffd5591c,
+            0xadbdc4ef2ada34e0,
+            0x6f0410bc841bf50c,
+            0x0072e8157f34d108,
+        ])),
+        Felt::new(BigInteger768([
+            0x6a3295b6a8c4fd2e,
+            0xcb1e8e68c2e39313,
+            0x751525b08b8f0d76,
+            0x5b65ec325646dd78,
+            0xb73be089e3a93686,
+            0xc0d4705d30343f61,
+            0xc161141e9ea70ffe,
+            0x2f5d5ebab9660e12,
+            0x0888a4744779c412,
+            0xebd56d8f615d5b59,
+            0x60ecda79d0e024e3,
+            0x0048b8c5b3e00492,
+        ])),
+        Felt::new(BigInteger768([
+            0xac06bd1f7f89e6ea,
+            0x4ec527f2944df328,
+            0x894b5368115d8a37,
+            0xa5cf66399ba662db,
+            0x461014694f0ca469,
+            0xa0783e61c179a341,
+            0xb3d9eed06d34ca7c,
+            0xf4ec6bd676a0222e,
+            0xb0c41749f435cd05,
+            0x9f96a1eee33216ae,
+            0xd56ab12784ec0512,
+            0x000f68df633b2d07,
+        ])),
+        Felt::new(BigInteger768([
+            0x6d55ecb86172b4c2,
+            0xc320e041929cae4f,
+            0xf99b30198893c95c,
+            0x50bb9651a3519a69,
+            0xa374120105af3d9f,
+            0x13c0a6c2a445d049,
+            0x29f866e389542fed,
+            0x7e07bbc82d95e32e,
+            0xaf975c6a1febf67d,
+            0xd802b44171aae011,
+            0x67f6a1fa257f7510,
+            0x0114ba52c2ba90a5,
+        ])),
+        Felt::new(BigInteger768([
+            0x918beabe22f4b64e,
+            0x74639337e635075d,
+            0x29ed1769598eae4d,
+            0xf103d548d6d1da04,
+            0xe8221f838afe4bfd,
+            0xa9e7ca89bbf1aaca,
+            0x6dfd06f677d2fc2e,
+            0x14bf3e070f17d064,
+            0x9b247b46262f9fc0,
+            0x03fa43b271663dd8,
+            0xeb2fec9ee4dba11a,
+            0x002837c18aa4dbe8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x73b756ce26518e4b,
+            0xa48266a6aba933fe,
+            0x61b08ceb5cbeed15,
+            0xb7666c9dba693f55,
+            0x957a44ddd3525958,
+            0x9a7034922dbf05ae,
+            0xecbdcc3aad7129ba,
+            0x8c4cecf0f4aa1011,
+            0xff4b7de864aad513,
+            0x7a82f0d3dde13730,
+            0xc283c8b1b45720e2,
+            0x0117a78e620288f6,
+        ])),
+        Felt::new(BigInteger768([
+            0x06ad2e7e9418a93a,
+            0x63277bbb68276101,
+            0x89568c91c5e19120,
+            0x9614cbe2edb1c6ef,
+            0x20f39d68afbef962,
+            0x05d3ffc7221a6c67,
+            0xbcec3ba0b59e32a5,
+            0xbff2c424af84ce76,
+            0x730d4ad94b218dc7,
+            0x086d6d3b247b7eee,
+            0x0db1cb8e4f819682,
+            0x0074840af3dd0a8a,
+        ])),
+        Felt::new(BigInteger768([
+            0xad80e2f0197f7647,
+            0x48422b96f3a1b356,
+            0x2ab20b77f850b4e0,
+            0x536bf8bdff819096,
+            0x1b3f556638ae7dce,
+            0x6d7221bfef2c0837,
+            0xd805b23dfc12595a,
+            0x163922b58539a341,
+            0x2449ad50216cbb15,
+            0xbc253f28fd00e1a7,
+            0x46163606be18e470,
+            0x010ec863f447f86b,
+        ])),
+        Felt::new(BigInteger768([
+            0x40d63256e6a58af5,
+            0xc5a2fbed31a5bbba,
+            0xfed04977885e4b1d,
+            0xa906fce1486a533c,
+            0xb1359451757113c2,
+            0xd90c52924454a495,
+            0x7f70d19af9c63440,
+            0x0551386e0f6bc081,
+            0xdbef6d4bbf3229e3,
+            0xd5eb87173d0578c5,
+            0x41dde686d3a5d0bf,
+            0x00f628b4f2ee569d,
+        ])),
+        Felt::new(BigInteger768([
+            0x0bf8d4a83ea6d589,
+            0x5ef09d4ab5995c19,
+            0x9d2e2f8a14923598,
+            0x47ee98f557d3998b,
+            0x7919a83d07dd12f2,
+            0x4840a905e3a36cb0,
+            0x411412e0904b0e62,
+            0x089cb3af1a4e0681,
+            0xa1221e708ff2e54b,
+            0x4a841aff8024f58c,
+            0xbc287f99400e6196,
+            0x00173676cfdd7c91,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xba0b9c463c462f18,
+            0xbaee3370626a129b,
+            0x9801d97600c6df8b,
+            0x1a01d00975fc3238,
+            0x8be92a5e62d157c2,
+            0xba42f82a7fc2b614,
+            0x776de52b9100bf44,
+            0x3fc3b2c306e6c070,
+            0x95203299c0262178,
+            0x3ce5b2d34d656fea,
+            0x42daaeab18eb7dc0,
+            0x00c3308dfb292717,
+        ])),
+        Felt::new(BigInteger768([
+            0xfa9b776936a63a91,
+            0xfa223b755ec6c192,
+            0xf3290484170f788d,
+            0xbdfa9e08fac51f6e,
+            0x2993d899f1ecf440,
+            0x73de20e017e093c0,
+            0x490dc239b190ef8b,
+            0x0bae92b88f36ea11,
+            0x3211177ebb193bd3,
+            0xc153321521d455ee,
+            0xacfb7a354aaaaf26,
+            0x00cc09597420294d,
+        ])),
+        Felt::new(BigInteger768([
+            0xa827dce957aa785e,
+            0xa3a537aaf610a0b5,
+            0x1b08597581d8d945,
+            0x60326749e54bc8a8,
+            0x7a01b31f5cdc9d11,
+            0x99c270956bb300d8,
+            0x965a9822cf2b7b58,
+            0x8b73c10cccfc9ec8,
+            0x186371ae92eab397,
+            0x69c1dc3cdad3ecd9,
+            0x6b766b7d57eb6391,
+            0x003b03f805e46e78,
+        ])),
+        Felt::new(BigInteger768([
+            0x31eb6c4e2c846a56,
+            0x5c674fa61d5fedd4,
+            0xbd897f3063ac9139,
+            0x30b1c5cd3ed12ea0,
+            0x7fec36cbae24b30d,
+            0xd0604ec07207fee0,
+            0xe3e4a97f2145d832,
+            0xe1957c0f05cf6cce,
+            0x5a51927f5393e5b1,
+            0x2d56af0cc44d189b,
+            0x11ec3e08c5c17314,
+            0x000f0477e0e6dd1b,
+        ])),
+        Felt::new(BigInteger768([
+            0x217562040168e12c,
+            0x263044b3096154f1,
+            0x3218b6b719798739,
+            0x9c22e670382753a9,
+            0x3780ee4b00764d88,
+            0xac00941c21ac895f,
+            0x90fe48abf002c867,
+            0x31b50822aca80027,
+            0x7710629ddf43b67f,
+            0x72d1c75e539714ca,
+            0xb49ff31445a9a0d8,
+            0x00ef3fbbc629c54b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x93b86200f0391517,
+            0xcc7c1c697d5c964d,
+            0xe3923441faed0f5d,
+            0xd2047a952aa415f6,
+            0x10dd14c68bd21593,
+            0x2b255c9553c13b37,
+            0xe4424b186dc3a388,
+            0xdb53462481549006,
+            0xe4ab6c43ccb37491,
+            0x1d5838c05b7af1eb,
+            0x9ebe0b1c49040fc9,
+            0x00eabeb25bbb629d,
+        ])),
+        Felt::new(BigInteger768([
+            0xd47b2851dca05a52,
+            0x2e41bdc2a83e9cbf,
+            0x96e55500887a43b6,
+            0xd1ab6ad99a9a05f6,
+            0x6b5879a4db84d569,
+            0xad08eb9d3be012c2,
+            0xfbf67628b5f71bae,
+            0x0efe906a63016034,
+            0x07fc30bb39de153a,
+            0xbd6f5dbe22b78772,
+            0x6d32eb29dcfbef7b,
+            0x00ffd15d99232a6a,
+        ])),
+        Felt::new(BigInteger768([
+            0x0949d00c59b0d4d0,
+            0xea9d64b0d7042a35,
+            0x477aa49b99d3501c,
+            0x9e41d583e162fc65,
+            0x98d4483924b6b3a4,
+            0x7667542b36babf61,
+            0x352894dbd9ba2d27,
+            0x30bbb9511a3747d1,
+            0xb7263caca8d956ef,
+            0xbeb9775604dde66a,
+            0x3638eb84bd73d0db,
+            0x011ea01a302f15bb,
+        ])),
+        Felt::new(BigInteger768([
+            0x2e5727e8e5e73a0a,
+            0xa0f0bf41cb18a6c9,
+            0xd4a0a93f1b39be32,
+            0x5de898eb8d08c15e,
+            0x095f3a4f2c3e69da,
+            0xc7a9c0f869af0dd9,
+            0x199558c5d46ac59a,
+            0x20d2531d2eb632ab,
+            0x1211479ad4ab758c,
+            0x6a7104169d5ede51,
+            0x6779e81062d08e30,
+            0x0055f07e7e8bbe80,
+        ])),
+        Felt::new(BigInteger768([
+            0xecb0a9a4263a478e,
+            0x016db808941d792c,
+            0xc68ea24dd7866170,
+            0xacb548a7332e78de,
+            0x3a0bef4e221ae5cd,
+            0x1f5f462719967d88,
+            0x8eb74c410a6fe963,
+            0x1f1e2526dd7c2bfe,
+            0x1bb6201fdf96687f,
+            0xf52fc30d8ae380cd,
+            0x9404817030cd2105,
+            0x008757e0a2683601,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xee331fb7de22fe18,
+            0xf5e901d4574ecc11,
+            0x0aadc65724148034,
+            0xa6e7f656318c7f92,
+            0xe71b8b137de399b7,
+            0xd3bcf6be5ed1c9ab,
+            0xd2ea5f0473290d72,
+            0xc721bceff425db64,
+            0xc44b690834cdb92b,
+            0xefb1a36fe2c38306,
+            0x5904d920eb2dd2db,
+            0x0055d158cdff1a91,
+        ])),
+        Felt::new(BigInteger768([
+            0xb097bc9f58661359,
+            0xec1a060331e4d2ca,
+            0xd8dd6a2c6357942b,
+            0x694b64c83c071edd,
+            0x1d6f8be3174f5ee9,
+            0x052f369537531b90,
+            0x9b7de70214df4d4a,
+            0x3452083ac375c543,
+            0xd6fc5e0666499a46,
+            0xe2dc45339e4d1a96,
+            0xc8e81620022dc447,
+            0x0081836343d56c02,
+        ])),
+        Felt::new(BigInteger768([
+            0x9353fd8ca4a8a147,
+            0x8c0c632c6ac2b269,
+            0x3f91252204d04904,
+            0x725661a1fd95988f,
+            0x1d471e21d981247e,
+            0x5013d2cfa1853bad,
+            0xcca13168ec872d60,
+            0x115bda4e05ce1699,
+            0x3c664ec560f028cd,
+            0x292c54b8fef39765,
+            0x24d75e85bf46ab5b,
+            0x00aba71ca57b7db8,
+        ])),
+        Felt::new(BigInteger768([
+            0x9846ef9a84381589,
+            0x81b733412a610f44,
+            0x7f9e31bd832dc290,
+            0xeb6a5afd61c1e4ff,
+            0x5cbf5a064407b39e,
+            0x256990fe8059ea48,
+            0xc975a0a375d20e08,
+            0x2de61fc74bd48a85,
+            0x1a3aed09fb8720a1,
+            0xb7b33683efa7f9ee,
+            0x2a5353cf220f660d,
+            0x00ea29847907ad70,
+        ])),
+        Felt::new(BigInteger768([
+            0x34f371d4ae3c9fba,
+            0x8272f4bdab009da2,
+            0x1b57bad9347d3c80,
+            0x81060f106e248573,
+            0xd910f00469cab2c6,
+            0x8506277fcb2de640,
+            0x107d4cf725003fd2,
+            0x9839e4b35e011e2c,
+            0x5c034a541d9da0e1,
+            0xc1aa75fbf600749e,
+            0xe6dd909c92330e81,
+            0x00652515d5c8993b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x8733a630e79c215e,
+            0xd51848a1ba8c4393,
+            0x2fae8ca270ab3c98,
+            0x4c9a7e0d423d2000,
+            0x0cf25a77560c22a0,
+            0x67979328098c1f77,
+            0x3fc7f9e8c2c241b8,
+            0xf56224a26ed03a06,
+            0xcb79d1f33e3ec970,
+            0x59f25b56574a6be0,
+            0xf8c7257bccde4a86,
+            0x00d798579b9d781b,
+        ])),
+        Felt::new(BigInteger768([
+            0xd229510348372562,
+            0xe0a871c59ebd5652,
+            0x3738c9404e5aec98,
+            0xdb6be30ec826f624,
+            0xee778ca31906ed02,
+            0x2c20445fec04727f,
+            0x3091b3dc0cc8f72a,
+            0xe67b30d0e7e98e03,
+            0x24e015f8462cb35d,
+            0x36d2ef73e138891e,
+            0xfe5fc6a9abdf0599,
+            0x00144cf8a06a75d8,
+        ])),
+        Felt::new(BigInteger768([
+            0x71fee6b07e751607,
+            0x0e8149ed8549c32b,
+            0x47dc79b56af5f967,
+            0xf21c8e13050defed,
+            0x1d29f4fb72df73a5,
+            0x5400f7782ba6241a,
+            0xcd2b91a9bea8c539,
+            0x5a9adcc00e48c20f,
+            0xe6f590338617e507,
+            0xe7d786620bc4222f,
+            0x2c3a25301ba03587,
+            0x00450b33a08692fe,
+        ])),
+        Felt::new(BigInteger768([
+            0x2d62cb18a568ff9b,
+            0x14ff05c4e4bf964d,
+            0x195d3dd25b595ac7,
+            0x5801a725fc7ded14,
+            0x8f06ee36b846a133,
+            0x7f16e93cf0e9ec89,
+            0x9b132c40b55327c0,
+            0x67c9ea0e315ed1ef,
+            0x9f098a6fc2eef80f,
+            0x3d369b6d05430e6c,
+            0xef938c58370ef67f,
+            0x00391a064ee097ca,
+        ])),
+        Felt::new(BigInteger768([
+            0xe129d702e0af3a68,
+            0xece33a8d3982ce32,
+            0x767189d2c648d56f,
+            0xc7bc9c7e4b01f604,
+            0xb8841d57fb3ac99d,
+            0x2e184a927d5a33ce,
+            0x93a381c95ff2622d,
+            0xf46a91a7790a9df9,
+            0xff90464a0ee77361,
+            0x2af718ce506ee39b,
+            0xf05279e596a9e4af,
+            0x00bcf6db1205efa5,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x12258d524c89c08b,
+            0x87162f2812111c61,
+            0xba780494d5a93d36,
+            0xa31d3309d603081e,
+            0x9be97f752af4cab3,
+            0x75658b657f2d121e,
+            0xf26e6ba0550a4fe0,
+            0x5713312274f2895c,
+            0x297f462cd13677bc,
+            0x355aaf2fd5d1ec1f,
+            0xcf8a791bc3f9258f,
+            0x011b011252b104b0,
+        ])),
+        Felt::new(BigInteger768([
+            0xf67710d17f858711,
+            0xa32b05b62c8116d1,
+            0x4ff427516827ec73,
+            0x3d520ecc040ec63f,
+            0xaee83f1d1cd448f4,
+            0x0ccf6806f2753df4,
+            0xac4b8ad8a3a4e831,
+            0xa27c67f56a70dc90,
+            0x2850e4d1c661df34,
+            0xb0c6c0e9e81d0d0b,
+            0x10f5e0f104d43de6,
+            0x01199253f568c58c,
+        ])),
+        Felt::new(BigInteger768([
+            0x6a0ce1fb891b6e83,
+            0x209f3359d86f5840,
+            0x4fe87a02c38c2863,
+            0x377495a6cbacd7dd,
+            0x0d517ddd819c9086,
+            0x09be2fc9bd167478,
+            0xd477bdabe4577497,
+            0xef8cb4c01b490d28,
+            0x7fd8feae7278c74d,
+            0x37b50b62b1fd3d59,
+            0x9e7d70fed1db3c9a,
+            0x004bbbfa28a94b47,
+        ])),
+        Felt::new(BigInteger768([
+            0x1b62e97004184ad2,
+            0x3a65dc53fb1f6756,
+            0xa0e2a4fca5f5d181,
+            0xf131e053f2e84af7,
+            0xed347c2704b82ed7,
+            0xb2579a33b461f64b,
+            0x5b82dda774f63ae8,
+            0x1a5ea091c9cbce7d,
+            0xae7e572d0e677414,
+            0x70618e83302324ac,
+            0xa93dc5a4874785f7,
+            0x00c3938dcb413fbf,
+        ])),
+        Felt::new(BigInteger768([
+            0x789d11e1d84e8620,
+            0x330da38c2a07e7a0,
+            0x882ca990321a9951,
+            0xcac92350bd7b9fba,
+            0x9bccc1b2aff01a9d,
+            0x9e9918817f2c272c,
+            0x6203a09b7875b0a0,
+            0xf68f0a60ddce3ae0,
+            0xa2716481bec825ba,
+            0x71e99578dab8865d,
+            0xd46652dae65d4c5e,
+            0x00daed0e090d0115,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x097c6269b56fee54,
+            0x73c283fc78246418,
+            0xdcacdd2f9c9d45d4,
+            0x80f1b0c0ac38220a,
+            0xb781ea89ff4c8356,
+            0xa6773bb35daeaaf5,
+            0xd8e465ddcab418b3,
+            0x04f564ccc65a6679,
+            0x3c32622ac1f15935,
+            0x9551665084b7df04,
+            0x90cec6f71a1e0dfa,
+            0x0099c354b8dee114,
+        ])),
+        Felt::new(BigInteger768([
+            0x88dbe93172d75c93,
+            0x6924dfbedafcd28f,
+            0x641708e870660d43,
+            0xaa4975aa42fe3e50,
+            0x7d01f1ac51c3d0ce,
+            0xd394c749057555de,
+            0xf0bdca134d9ed7e4,
+            0x937a419320d8adfd,
+            0x6cdf7cbc299c73d9,
+            0xd1c7bdd1f62ece8e,
+            0x87947c754abdedcd,
+            0x00e560fe18586adf,
+        ])),
+        Felt::new(BigInteger768([
+            0x73c5b1a3abc7f061,
+            0x3bf68d07d27a652f,
+            0x7d31e935dd9bee26,
+            0x270830f4b6fb8706,
+            0x48e43b4b27c7fe22,
+            0xec2ac9046b1bdb6a,
+            0xf2d11ed90b768baa,
+            0x389fa415da96bc2c,
+            0x088ce226dc0b7900,
+            0x030583d420bab65b,
+            0xfe1868b7d2d31060,
+            0x00e7aa6bfca401f2,
+        ])),
+        Felt::new(BigInteger768([
+            0xd32bcd8797a83bc2,
+            0x74d2a6d3593fa968,
+            0x787069123c34da33,
+            0x3a5ddc710e91b4ef,
+            0xb3f50c01ed0ffd17,
+            0x37915c42792d008f,
+            0xd2dba8b2757f1486,
+            0x5d05ee32d94dc07e,
+            0x1677c046c02886cd,
+            0xcdb05cb213252d44,
+            0x4594e2b161204638,
+            0x001fed03df830b5e,
+        ])),
+        Felt::new(BigInteger768([
+            0xf514b36963c898ab,
+            0x2ceaf009cf4a1b6e,
+            0xb1e4126f0db70d98,
+            0x1b93419a591eeae8,
+            0x7c5ff273e2da4ab7,
+            0xab80018d8e28f2bd,
+            0x6d9fecbee2a89523,
+            0x75f10af9bb58700f,
+            0xc6e63d5ae78e27c1,
+            0xb64ef5d05e081375,
+            0x716396ccbe4c43da,
+            0x0108db217e06868a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x5c98311efae36bde,
+            0x02d3412dddf84c03,
+            0x9dae2ec2c1cfdaaf,
+            0x7395e6f73d8e7a82,
+            0x235cb1da76efada2,
+            0xb3893fe43b5a1a22,
+            0x72ca4475976cdbcd,
+            0xe3f5e34e0e647a1c,
+            0xba8c0d6b1facc42f,
+            0x8dbf6e992728bbf2,
+            0x54eb14d35905d9d9,
+            0x00c0a3ca14ceeea0,
+        ])),
+        Felt::new(BigInteger768([
+            0xc838a94208141807,
+            0x51afb39c21a40185,
+            0x1ff67a215cbeb0a1,
+            0x8b5d94750dc8b398,
+            0xb3d6a13167b79476,
+            0xa6210404b5fced33,
+            0x5f5638034aefcce7,
+            0xd876eacd50204f6f,
+            0x627e9eb059248fda,
+            0x314b51a2ecff9dfc,
+            0xa2bbc4454709b0f9,
+            0x000fde8b0a25f04c,
+        ])),
+        Felt::new(BigInteger768([
+            0x87d8ce33a4eac097,
+            0x69086415aa838791,
+            0xa3fc01b20e4b199a,
+            0x7095b63744041b7c,
+            0xb4ecaa55d59207f1,
+            0xdca1f0fa47c81365,
+            0x4bbd26e31694660a,
+            0x3a0a44d4ff65b789,
+            0x89249abf4c04fc37,
+            0x4a69c3ddb6e8f98f,
+            0xac242b4a0facf9fa,
+            0x0018c3cb359771a3,
+        ])),
+        Felt::new(BigInteger768([
+            0xb0d5395a7303c978,
+            0xa3c89d490bf08b06,
+            0x9a59cc7ce64ce222,
+            0x5cb51614bc48f4f3,
+            0xe9d3237a9ef98a74,
+            0xfc266911756f5f9f,
+            0xdb7b3e948cf71dd5,
+            0x228a741ced88f737,
+            0x6cb6be6a247243c3,
+            0x2adec973e9f85b54,
+            0xc47b3cced7bcd9b3,
+            0x0026280c405c809d,
+        ])),
+        Felt::new(BigInteger768([
+            0x6601d4ead9e553fc,
+            0xfdbe7e805fcaa7ac,
+            0x02b530c87ddcff67,
+            0x93c539f524b122cf,
+            0xcf4a6fe9703b8b1a,
+            0xa0b31f198ab3c2dd,
+            0x3d4ea9bf7b22208f,
+            0x6cc3663ba936fec1,
+            0xaa8d67a671b55ae4,
+            0xb55c48512ba569b6,
+            0x9e30f1e87081c6a9,
+            0x000083d18ef453d4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xd954a9d3a314b391,
+            0xd3c7f496a4fbdb6d,
+            0x8865545025b96443,
+            0x724d185eef9833f4,
+            0xe7064032c6c2997e,
+            0xd7931178e0b53e68,
+            0xcc9550cd9b8b6461,
+            0x0cc23980a6789b7f,
+            0x1b49d2482ac351e3,
+            0x5b8933ffb63b82bc,
+            0xf53ac0fce1a76905,
+            0x006fb2337c2e6b2d,
+        ])),
+        Felt::new(BigInteger768([
+            0xa8f8cef033c9a765,
+            0x9c1cf13dd77d9228,
+            0x91c3235649184862,
+            0x76e3361b7f5318ca,
+            0x7535db67861d9ea0,
+            0x8d641aa2f99c004b,
+            0x081b57ec39a8dbb3,
+            0x1dd59623901d4edb,
+            0x08f756b0008f68a1,
+            0x42f4f6d3227d97fa,
+            0x0d7928069629a9f6,
+            0x004be631a1c27b5a,
+        ])),
+        Felt::new(BigInteger768([
+            0xb48fe0f4b6a0f586,
+            0x914fe332748be2a1,
+            0xf43d5b91d930b111,
+            0xfe30aacaa09eb295,
+            0xd395d5e332df6000,
+            0xde3bd17de65f29c2,
+            0xcefa491558ea7ffe,
+            0x1a98b7f592466df7,
+            0x56a65620e5c8b6b4,
+            0xd5301412931b1208,
+            0x6deba25d006d8de6,
+            0x01141b4c258bb027,
+        ])),
+        Felt::new(BigInteger768([
+            0xe05c9789bbf93950,
+            0x18c904bab8ba92e1,
+            0x10cff9980b4a9b98,
+            0xabf4111c257535c4,
+            0xbd9ac2a0477de2aa,
+            0x3771f40dbdb9297a,
+            0xa74bbde915fa8f79,
+            0x741b91c03db3a732,
+            0x3a71334ab7caaed8,
+            0x8dec60eb5211f28f,
+            0xbab13ecb64ec111b,
+            0x00cc53ecc8ae876e,
+        ])),
+        Felt::new(BigInteger768([
+            0xf619c994b3412b03,
+            0x63334f6373ea507b,
+            0x5c80e0320b3e99a5,
+            0x91270a06b04449fb,
+            0xa58efc0f43807044,
+            0x834502ceeae4e49a,
+            0x7c65a0e1d0d26569,
+            0xe4528037655093cc,
+            0x54f2fa249f52ac86,
+            0x4139729476938eef,
+            0x0168211596a07cad,
+            0x010cded1c15497dd,
+        ])),
+    ],
+];
@@ -0,0 +1,355 @@
+//! Digest trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::DIGEST_SIZE;
+
+use super::Felt;
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+use crate::serialization::{self, DigestError, Hex};
+use crate::{AnemoiAbsorb, AnemoiDigestTrait};
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+/// An Anemoi Digest for the Anemoi Hash over Felt
+pub struct AnemoiDigest([Felt; DIGEST_SIZE]);
+
+impl AnemoiDigest {
+    /// Returns a new Digest from a provided array
+    pub fn new(value: [Felt; DIGEST_SIZE]) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped digest
+    pub fn as_elements(&self) -> &[Felt; DIGEST_SIZE] {
+        &self.0
+    }
+
+    /// Returns the wrapped digest
+    pub fn to_elements(&self) -> [Felt; DIGEST_SIZE] {
+        self.0
+    }
+
+    /// Returns a `Vec<Felt>` from the provided digest slice
+    pub fn digests_to_elements(digests: &[Self]) -> Vec<Felt> {
+        let mut res = Vec::with_capacity(digests.len() * DIGEST_SIZE);
+        for digest in digests {
+            res.extend(digest.as_elements())
+        }
+
+        res
+    }
+
+    /// Returns an array of bytes corresponding to the digest
+    pub fn to_bytes(&self) -> [u8; 192] {
+        let mut bytes = [0u8; 192];
+        for (chunk, element) in bytes.chunks_mut(96).zip(self.0.iter()) {
+            chunk.copy_from_slice(&to_bytes![element].unwrap()[..96]);
+        }
+
+        bytes
+    }
+
+    /// Returns the digest encoded by the provided array of bytes, as output by
+    /// [`Self::to_bytes`].
+    ///
+    /// Returns an error if any element of the digest is not canonically encoded,
+    /// i.e. is not less than the field modulus.
+    pub fn from_bytes(bytes: &[u8; 192]) -> Result<Self, DigestError> {
+        serialization::elements_from_bytes(bytes).map(Self)
+    }
+}
+
+impl Default for AnemoiDigest {
+    fn default() -> Self {
+        AnemoiDigest([Felt::default(); DIGEST_SIZE])
+    }
+}
+
+impl AnemoiDigestTrait<Felt> for AnemoiDigest {
+    const BYTE_LEN: usize = 192;
+
+    fn as_elements(&self) -> &[Felt] {
+        &self.0
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        AnemoiDigest::to_bytes(self).to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, DigestError> {
+        Self::try_from(bytes)
+    }
+}
+
+impl AnemoiAbsorb<Felt> for AnemoiDigest {
+    fn absorb_into(&self, elements: &mut Vec<Felt>) {
+        elements.extend_from_slice(&self.0);
+    }
+}
+
+impl TryFrom<&[u8]> for AnemoiDigest {
+    type Error = DigestError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = bytes.try_into().map_err(|_| DigestError::InvalidLength {
+            expected: 192,
+            actual: bytes.len(),
+        })?;
+
+        Self::from_bytes(bytes)
+    }
+}
+
+// Digests are encoded as their canonical bytes, which are also the arkworks encoding of
+// their elements. They are decoded through `from_bytes` however, as arkworks would read
+// the top bit of elements of fields with a modulus of a whole number of bytes as a flag.
+// Digests have no compressed form, and are always validated on deserialization.
+impl CanonicalSerialize for AnemoiDigest {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        writer.write_all(&self.to_bytes())?;
+
+        Ok(())
+    }
+
+    fn serialized_size(&self) -> usize {
+        192
+    }
+}
+
+impl CanonicalDeserialize for AnemoiDigest {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; 192];
+        reader.read_exact(&mut bytes)?;
+
+        Self::from_bytes(&bytes).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+// Digest elements are stored in Montgomery form, hence the bytes of a
+// digest cannot be borrowed and are only exposed through owned arrays.
+impl From<AnemoiDigest> for [u8; 192] {
+    fn from(digest: AnemoiDigest) -> Self {
+        digest.to_bytes()
+    }
+}
+
+impl fmt::Display for AnemoiDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Hex(&self.to_bytes()))
+    }
+}
+
+impl FromStr for AnemoiDigest {
+    type Err = DigestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = serialization::decode_hex(s).ok_or(DigestError::InvalidHex)?;
+
+        Self::try_from(&bytes[..])
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for AnemoiDigest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialization::serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for AnemoiDigest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: [u8; 192] = serialization::deserialize_bytes(deserializer)?;
+
+        Self::from_bytes(&bytes).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::Zero;
+    use super::*;
+    use ark_ff::UniformRand;
+    use rand_core::OsRng;
+
+    #[test]
+    fn digest_elements() {
+        let mut rng = OsRng;
+
+        for _ in 0..100 {
+            let mut array = [Felt::zero(); DIGEST_SIZE];
+            for item in array.iter_mut() {
+                *item = Felt::rand(&mut rng);
+            }
+
+            let digest = AnemoiDigest::new(array);
+            assert_eq!(digest.to_elements(), array);
+            assert_eq!(&digest.to_elements(), digest.as_elements());
+            assert_eq!(
+                digest.as_elements(),
+                &AnemoiDigest::digests_to_elements(&[digest])[..]
+            );
+        }
+
+        let digest = AnemoiDigest::default();
+        assert_eq!(digest.to_elements(), [Felt::zero(); DIGEST_SIZE]);
+        assert_eq!(digest.as_elements(), &vec![Felt::zero(); DIGEST_SIZE][..]);
+        assert_eq!(digest.to_bytes(), [0u8; 192]);
+    }
+
+    #[test]
+    fn digest_from_bytes() {
+        let mut rng = OsRng;
+
+        for _ in 0..10 {
+            let mut array = [Felt::zero(); DIGEST_SIZE];
+            for item in array.iter_mut() {
+                *item = Felt::rand(&mut rng);
+            }
+
+            let digest = AnemoiDigest::new(array);
+            let bytes = digest.to_bytes();
+            assert_eq!(AnemoiDigest::from_bytes(&bytes), Ok(digest));
+            assert_eq!(AnemoiDigest::try_from(&bytes[..]), Ok(digest));
+            assert_eq!(<[u8; 192]>::from(digest), bytes);
+
+            let hex = format!("{}", digest);
+            assert_eq!(hex.len(), 384);
+            assert_eq!(hex.parse::<AnemoiDigest>(), Ok(digest));
+            assert_eq!(hex.to_uppercase().parse::<AnemoiDigest>(), Ok(digest));
+        }
+
+        // Elements not less than the field modulus are rejected
+        let size = 192 / DIGEST_SIZE;
+        for i in 0..DIGEST_SIZE {
+            let mut bytes = [0u8; 192];
+            bytes[i * size..(i + 1) * size].fill(0xff);
+            assert_eq!(
+                AnemoiDigest::from_bytes(&bytes),
+                Err(DigestError::NonCanonical(i))
+            );
+        }
+
+        assert_eq!(
+            AnemoiDigest::try_from(&[0u8; 193][..]),
+            Err(DigestError::InvalidLength {
+                expected: 192,
+                actual: 193,
+            })
+        );
+        assert_eq!(
+            "00".repeat(191).parse::<AnemoiDigest>(),
+            Err(DigestError::InvalidLength {
+                expected: 192,
+                actual: 191,
+            })
+        );
+        assert_eq!(
+            "0".repeat(383).parse::<AnemoiDigest>(),
+            Err(DigestError::InvalidHex)
+        );
+        assert_eq!(
+            "zz".repeat(192).parse::<AnemoiDigest>(),
+            Err(DigestError::InvalidHex)
+        );
+    }
+
+    #[test]
+    fn digest_canonical_serialize() {
+        let mut rng = OsRng;
+
+        for _ in 0..10 {
+            let mut array = [Felt::zero(); DIGEST_SIZE];
+            for item in array.iter_mut() {
+                *item = Felt::rand(&mut rng);
+            }
+
+            let digest = AnemoiDigest::new(array);
+
+            let mut compressed = Vec::new();
+            CanonicalSerialize::serialize(&digest, &mut compressed).unwrap();
+            assert_eq!(compressed.len(), digest.serialized_size());
+            assert_eq!(compressed, digest.to_bytes());
+            assert_eq!(
+                <AnemoiDigest as CanonicalDeserialize>::deserialize(&compressed[..]).unwrap(),
+                digest
+            );
+
+            let mut uncompressed = Vec::new();
+            digest.serialize_uncompressed(&mut uncompressed).unwrap();
+            assert_eq!(uncompressed.len(), digest.uncompressed_size());
+            assert_eq!(
+                AnemoiDigest::deserialize_uncompressed(&uncompressed[..]).unwrap(),
+                digest
+            );
+            assert_eq!(
+                AnemoiDigest::deserialize_unchecked(&uncompressed[..]).unwrap(),
+                digest
+            );
+        }
+
+        // Elements not less than the field modulus are rejected
+        let bytes = [0xffu8; 192];
+        assert!(<AnemoiDigest as CanonicalDeserialize>::deserialize(&bytes[..]).is_err());
+        assert!(AnemoiDigest::deserialize_uncompressed(&bytes[..]).is_err());
+        assert!(AnemoiDigest::deserialize_unchecked(&bytes[..]).is_err());
+
+        // Truncated encodings are rejected
+        let bytes = [0u8; 191];
+        assert!(<AnemoiDigest as CanonicalDeserialize>::deserialize(&bytes[..]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn digest_serde() {
+        let mut rng = OsRng;
+
+        for _ in 0..10 {
+            let mut array = [Felt::zero(); DIGEST_SIZE];
+            for item in array.iter_mut() {
+                *item = Felt::rand(&mut rng);
+            }
+
+            let digest = AnemoiDigest::new(array);
+            let bytes = digest.to_bytes();
+
+            // Human-readable formats use hexadecimal strings
+            let json = serde_json::to_string(&digest).unwrap();
+            let hex = bytes
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<_>>()
+                .concat();
+            assert_eq!(json, format!("\"{}\"", hex));
+            assert_eq!(serde_json::from_str::<AnemoiDigest>(&json).unwrap(), digest);
+
+            // Binary formats use the canonical bytes, after a length prefix for bincode
+            let encoded = bincode::serialize(&digest).unwrap();
+            assert_eq!(&encoded[8..], &bytes[..]);
+            assert_eq!(
+                bincode::deserialize::<AnemoiDigest>(&encoded).unwrap(),
+                digest
+            );
+        }
+
+        // Non-canonical encodings and wrong lengths are rejected
+        let json = format!("\"{}\"", "ff".repeat(192));
+        assert!(serde_json::from_str::<AnemoiDigest>(&json).is_err());
+        let json = format!("\"{}\"", "00".repeat(191));
+        assert!(serde_json::from_str::<AnemoiDigest>(&json).is_err());
+        let encoded = bincode::serialize(&[0xffu8; 192].to_vec()).unwrap();
+        assert!(bincode::deserialize::<AnemoiDigest>(&encoded).is_err());
+    }
+}
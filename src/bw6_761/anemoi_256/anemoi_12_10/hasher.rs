@@ -0,0 +1,5054 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U192;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U10>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger768([
+                0xd795e7241b7bdf2c,
+                0x2fce6d371763e6e5,
+                0x25cbbfa105fceb86,
+                0xbf35b7f981f5b508,
+                0x0ad8f3971839885a,
+                0x5b0f934c15d2dfa5,
+                0x3a71d217ddae97df,
+                0x625d00540cb12812,
+                0x659216b9c1726ac3,
+                0x9fcd5c0be5e414e4,
+                0xce823d1c10cf08e2,
+                0x00ad7251c436f025,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x5eb9bdf30402b979,
+                    0x455d34811eb431b5,
+                    0x53b3549678eabba4,
+                    0xa98c1eb63f12b169,
+                    0x75fa52bdceef5b77,
+                    0x33455b683a6a3089,
+                    0x8206f5e62f406573,
+                    0xdd3273f1cc29e181,
+                    0xbc0e76ebd63c7131,
+                    0x1c1521be4d0d6b89,
+                    0x17b3eb9cda59233f,
+                    0x007b604c1b39572b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90f6065d4a6bcccc,
+                    0xc436355116a2415d,
+                    0x6112f6913f4171ed,
+                    0xfa70865d7b41ef51,
+                    0x1835dee7641de996,
+                    0x0911bc33a512d2b4,
+                    0xcd02fff2a3d98fb8,
+                    0x980d09453aeb6f2f,
+                    0x31c40a5353237302,
+                    0x698ea721c5cd567a,
+                    0x4594896b56ea8bb6,
+                    0x00fc6b842045c99e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x2d0eea5ba8721cb7,
+                    0xce90f149fe4f2c4a,
+                    0x0124f158748641ad,
+                    0xee3b77fa6f4b7c53,
+                    0x06506c810709e9da,
+                    0x9b877966d5c2b089,
+                    0x3d27cd7df7cc8e3d,
+                    0x095a4355a8ada8df,
+                    0xaa437552e1df53b9,
+                    0x6954f8f5cb5bf328,
+                    0xb0e3c8ae05399b29,
+                    0x00f4b56b5a2dd495,
+                ])),
+                Felt::new(BigInteger768([
+                    0x491eb05d6ec65891,
+                    0xa1a0e1e39936bc2e,
+                    0xe236eb9ea0e75ef1,
+                    0x141406a19ee87197,
+                    0xed63104287940265,
+                    0x3a6006da77fa0b42,
+                    0x3b488d8c7a581171,
+                    0x65481d2f1bb94703,
+                    0xb76126d224338954,
+                    0x5b1cc1745ccc6775,
+                    0xe1c12d1673abc0c2,
+                    0x00ff80698e109f03,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0008987a2ae5f914,
+                    0xf739be6ef497a27c,
+                    0x0384fbf5374a6be8,
+                    0x1a6ec8293acf1f12,
+                    0xfcb29b0e6a3ccf74,
+                    0xaa4e2f50a8c19675,
+                    0xba66a213aa1a7574,
+                    0x7f6ac7cf17367d39,
+                    0x27c497f4dd9aa42f,
+                    0xca39f6d0e4e90df7,
+                    0x14dc81590059b9bd,
+                    0x0044a6103fec45e6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x71f79d4efcdcee8d,
+                    0x434f93ccf9567e6f,
+                    0x7fa3d960e537a836,
+                    0xec02c8dc5f0e60b4,
+                    0xf6c6800a024f98ed,
+                    0xf676548c25b869cc,
+                    0xf9d67d029f95a787,
+                    0x812b21b81323ab47,
+                    0x08f605e21d179211,
+                    0x45d8094e9b5d049d,
+                    0xfe1b40b206eb3947,
+                    0x0070edca6f7c0c5b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82c90f63a546afd0,
+                    0x9670ed6c44286d8c,
+                    0x7fd15ad7a5a106c2,
+                    0xa4a151de09738f4d,
+                    0x4962a43f87da7d61,
+                    0xb07e415aab0549fd,
+                    0xc6d04e3229bef943,
+                    0x53ef705ef764eee9,
+                    0x1987843a05cbb4d8,
+                    0x0add27127675459c,
+                    0xa3f46d83a0e5f96d,
+                    0x009d36d6f19c4c23,
+                ])),
+                Felt::new(BigInteger768([
+                    0xecc56590ffa2686d,
+                    0xacd848bc791adb14,
+                    0x06e2c21054820890,
+                    0x2b52e835805de9f0,
+                    0x2b4ebe3b8d892fdd,
+                    0xaf1ef46eb5f0d2c0,
+                    0x98549afc1eede8f3,
+                    0x7271ccf879e697c7,
+                    0xf773136295be3d9a,
+                    0x172ef85dac0711af,
+                    0x7467fe28d3e5ab2c,
+                    0x007ffbb86bcb49a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd241af67a2ebc41f,
+                    0x41df647295141225,
+                    0x2b9acb907cd3267a,
+                    0x97965591dab350ba,
+                    0x98478eccb79974cc,
+                    0xeb1c4bdce7c96143,
+                    0x64459b185e149c3c,
+                    0xc37c5e85c30c7d6c,
+                    0x9eb8e2f895ee1799,
+                    0x3db8a4ceea80a472,
+                    0xa154eba568abfabd,
+                    0x00d21aa0779a355e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x94815fdca11b6f21,
+                    0xa3bdc2c364196d42,
+                    0xb75662df964b5cca,
+                    0x558e0543eb000b60,
+                    0x2b52e3a65ee115d2,
+                    0xf4ddd77eae60d04a,
+                    0x69ba7bdafe882efd,
+                    0x2bcefd003043a3a4,
+                    0x407aa22ca4f10c1a,
+                    0x61701e7719f2c743,
+                    0xbd42de835da8eb93,
+                    0x009c01d2229f147a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x329ba99c15e11e4b,
+                    0x41884681cd10adc1,
+                    0xfd6256e0f1937047,
+                    0xc42f982b8ec6d9b6,
+                    0x147aacdb32a8b0b4,
+                    0xc02e8af61ab75f26,
+                    0xf3e8a2abbb7b1fee,
+                    0x8a24be69cee65081,
+                    0x817044cf76ca1f75,
+                    0x7cb5db3b77cd5517,
+                    0x44c4360b4ec52f4b,
+                    0x0051ff8246ebed91,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b0a5920bbaf03c0,
+                    0x44be959b0c92cb58,
+                    0xc20ec377f6482644,
+                    0x87019f7ef4fe55a9,
+                    0x741d2413f6edb972,
+                    0x21e7573f1cba06e0,
+                    0x090339a7377329a7,
+                    0x281377290512fc76,
+                    0xba5c54639b5a0668,
+                    0x9d4604277a2c03e4,
+                    0x4e3ebd41fa41fe75,
+                    0x005c30d12ecf9c0d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd4ab3a18e0e329fe,
+                    0x32f70e62f725a532,
+                    0x8be706798ab72889,
+                    0x63cd47d0c7aec41f,
+                    0x9548b1cd6cfdf4e2,
+                    0xddf36e25d2119a5d,
+                    0x34fafbad7647bce5,
+                    0x93cf643375c38399,
+                    0x90ff627dedce2c55,
+                    0xa20483f85297290e,
+                    0xa5c69695fa5928c7,
+                    0x00bcb9538a0af9fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaf88e482bfe295db,
+                    0x81705e6d6bf62fe8,
+                    0x4f3504453b607020,
+                    0xb2108ac140104e12,
+                    0x05e3557d63781c68,
+                    0xc53ade257c4040c1,
+                    0xdd7509550e9959ed,
+                    0xbcd29a893911a671,
+                    0x3415750c6e7e114e,
+                    0x1dce163a2f1a4bd9,
+                    0xbd6e75553d1c5ae5,
+                    0x011ffbe9c2f37bb0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x7f7e86495a71c86a,
+                    0xed91cff23c00177a,
+                    0xafb6e68d9d6aac0d,
+                    0x5e9b76d7d713a52e,
+                    0xd0af1c08a948b40d,
+                    0x39aac65df0ded2b6,
+                    0x116b9e1e58f9e4c7,
+                    0x9bec3aca07502a63,
+                    0x0865686706caf172,
+                    0x750eb4ba0c2fab8b,
+                    0x145002cb9c43fe3e,
+                    0x0115016255b65bd6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8041ad76fe595d09,
+                    0xa5ac4d7697be3f67,
+                    0x262059fa8c6eac67,
+                    0x8da2f23193061e32,
+                    0x6ad02a026f2a4685,
+                    0x3f3ce6e91e091280,
+                    0xe57ade6ffcfbe519,
+                    0x98289d90933d7f89,
+                    0x071e630ee1c1c79d,
+                    0x2900097ecd69e240,
+                    0xa10cc23d25a19ea4,
+                    0x002c1f63e9b4da24,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44d73d6848af4d0c,
+                    0x528a5f2b14e0151d,
+                    0x0006052907cf4b8d,
+                    0x0e11e97f1e8b8db3,
+                    0x7415bf1ddd8ba174,
+                    0xbd53c0b6a9afe2e0,
+                    0xeebca9672e32d35d,
+                    0x107ef2b2a8574075,
+                    0x79f4bafcbba67623,
+                    0xef88299c6291485d,
+                    0x0eb4dd4131308cd3,
+                    0x0046c3419de702e5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf310adaa4457fd27,
+                    0x51bb8142b68aa8c8,
+                    0xed1f4424e7547afc,
+                    0x5fec20a2aa90c6e3,
+                    0x528af30d35853779,
+                    0x13cc68d501c07dd3,
+                    0x474ea57b3f819672,
+                    0x8244c8c7c00f0591,
+                    0x3f31f6ba0b90ec63,
+                    0x82babe8054d2bc01,
+                    0x3de7936166fa3c59,
+                    0x005835dfcdf1bb40,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc57d50f6f040e817,
+                    0xd85d2657247bf691,
+                    0xd387ee7714f416dc,
+                    0x96e9cb34fff8a6b5,
+                    0xfe5da9d99597c5ed,
+                    0x9807105f23423e1d,
+                    0x92d669aff828efd5,
+                    0x550a395a9f139c45,
+                    0x6c099a218f4a58f3,
+                    0xb1544c792620dca7,
+                    0xface332f98cf8066,
+                    0x011a45e2e7aad297,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f7ef847ecc9ecd0,
+                    0xe455165c52dbc121,
+                    0xa07d176883582dad,
+                    0x7dd66f4d3fd4ece6,
+                    0xce8d47f57578e25a,
+                    0x4a7534f3638a296b,
+                    0xb5dcb11b682a6167,
+                    0xb8575d578756559c,
+                    0xdeaf94d45536b021,
+                    0x199c83ad9e3c88f2,
+                    0x53885c92a400b046,
+                    0x001fd1c6566070b4,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xbd70dff37bd0fcf2,
+                    0x9e156b39e6155ddf,
+                    0x6c164f3c56d541e2,
+                    0xb399c23c34319450,
+                    0x2e22f081e9e650a1,
+                    0x794e91d48d02557a,
+                    0x7a07aa62bd245cd5,
+                    0x492bbf0661f8f126,
+                    0xc55119798510ef94,
+                    0xdecf9fb18f68e485,
+                    0x8a9c128f37121320,
+                    0x00ded2a696b3bb6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcae31c6231a67f93,
+                    0x8ec285adb6c7d2c0,
+                    0x6ddfea2e4e662620,
+                    0xbd89ca25133359f8,
+                    0x44d264fd23788006,
+                    0xd001ae59909d0846,
+                    0xbac334e065bafc92,
+                    0xb30f103d289daad2,
+                    0x418e0f2c696038aa,
+                    0xed3648b3e324310a,
+                    0x803de2fa6cb8af33,
+                    0x012142ce87f81dbc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x66d434a1e2c2d706,
+                    0xa154ef9fc77b83e3,
+                    0x13ad52cc832db2ab,
+                    0x34d7e30813d27e4e,
+                    0x2af212ee9987d7f3,
+                    0x490017ec7fcbc1e7,
+                    0x0be5444e16bdc4a5,
+                    0x33e828f1b5cad745,
+                    0x1422d7998749d510,
+                    0x794064e5256f3202,
+                    0x5400e0605b27568c,
+                    0x004a79aba3a891cb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38962bb5f1f3809f,
+                    0xed8a4f2d4ce87f1c,
+                    0x7ba90789d6c94f79,
+                    0xaba8c3124a20dbcc,
+                    0xac5fe838b95854bb,
+                    0xf2ecee825408948c,
+                    0xb6c9d56ebd5a2995,
+                    0x498cbb89428ce6a9,
+                    0x7c25cfac5aa3f7e6,
+                    0xa281a9d9f423d42e,
+                    0x968acba51fbe39bc,
+                    0x00ff36ab31d16e6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x71a2f55d3c6da4d1,
+                    0x7e22040ca3aedbdf,
+                    0xaa53f016ea3a816c,
+                    0xc88d34978db304c2,
+                    0x907f82739746466a,
+                    0xfa4986453db8197c,
+                    0xf9da5106e704ef9c,
+                    0xa68e81e9350891b5,
+                    0xd1493ea153f07575,
+                    0x681e52651cbae185,
+                    0x4bcfd02e2a28415e,
+                    0x0060345784a1c4c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a859255c375db4e,
+                    0xb2dcaab195c40eb0,
+                    0x37a849a7d547928a,
+                    0x3d68c9cf0c488183,
+                    0xe6fa3fbdc33b589f,
+                    0x937b566511a3f928,
+                    0xf9088877c912de95,
+                    0x927da05fa1d3d42e,
+                    0x7752accc8bcfb2d7,
+                    0x4f9f49bb889030e6,
+                    0x7b61485cf98331f7,
+                    0x0009906ecf0f44d4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2811e892a52f64b7,
+                    0xb29434e68a76de45,
+                    0x4c8c3d44ce676cb4,
+                    0x2092d096de513952,
+                    0x99e33ca52f39f027,
+                    0x2a0bd9f57255dc8a,
+                    0xe7da7a696b52c30b,
+                    0xfa6573585082a83e,
+                    0x3b6529a5eb50826f,
+                    0x37024f72d4526ab5,
+                    0xac8568f285cbf716,
+                    0x0080409bc27e37dc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc3e1e14b49a299f,
+                    0x5839b1b4b0ade1f0,
+                    0x74efda2307ab0435,
+                    0x7933506d2d01ab26,
+                    0x7aad953a26910bd2,
+                    0x1aeaf67a4e9665eb,
+                    0xa7572b68dcdeaad5,
+                    0xb4ee67ae99034d01,
+                    0x08f234a4008527cc,
+                    0xcd628686be12334b,
+                    0x6ab4c10a198d0332,
+                    0x00c9f397464bce1b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xddf09d4a248ed616,
+                    0x957d0fd15be84e91,
+                    0x45548fe3d4a70d83,
+                    0x313829bf69114031,
+                    0x3657558eb5fca1b0,
+                    0xba204ce0ccf9f740,
+                    0x9bb50b6e7f970bb2,
+                    0x797b7b4c7c282ddc,
+                    0xbc970c9cff479361,
+                    0xde8bd76d60842d6b,
+                    0xb7057167b2111bb1,
+                    0x0082ba77fe84b091,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9377fe2b7f5ad732,
+                    0x53cc4b2566185462,
+                    0x38b7e4d54d738bf9,
+                    0x183dd56c680c80a1,
+                    0xb4b439617367f757,
+                    0xa7dca14a2dafd891,
+                    0xb21d3854fb8a9c82,
+                    0x596b34d33e1e48ea,
+                    0x6c046ef8d9960765,
+                    0x78fe5079844a8f6c,
+                    0x9eb6195cf8738c06,
+                    0x001d1a08d7cabc9f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xacf7cd32e3e6a3d8,
+                    0x7047960ec5c486f3,
+                    0x237dfbc673c56345,
+                    0x543ebdada2c0a8ea,
+                    0x8c62c27249491ef1,
+                    0x3bd800570c0dc46a,
+                    0xe0081df22a83d920,
+                    0x1ca91d3da1265375,
+                    0xabf4768c16ceab7e,
+                    0x99b374eab7f00e3d,
+                    0xd6ec73784536fada,
+                    0x00984a34ee0291f2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbfd521d95277ac3d,
+                    0x1da1173668aaeee4,
+                    0x875511cdc4be09ce,
+                    0xbfd57aa94f428107,
+                    0xa312853e48060ca3,
+                    0x34e4dc4cdacd3bb2,
+                    0x5a76a35c030c0fd4,
+                    0x72e3fe4ad10f8e0b,
+                    0xc4b68dacf371919f,
+                    0x4971e5f8440aba6f,
+                    0xc2a98a68027d4b24,
+                    0x0109f5d26b30d4b7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x204d78c974d084d3,
+                    0x7ca2afb324982fbe,
+                    0x9a41f18fde7312a8,
+                    0xed84361856087216,
+                    0xa7552e67905a958b,
+                    0x0eeb7be7ee7a9751,
+                    0x782485b83e234c46,
+                    0xbc7d55623d797da4,
+                    0xe2626180b1b97a9a,
+                    0x00fff5f4f6aac0ef,
+                    0xe1315f1c3ea33554,
+                    0x00319e040f53413f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad63dfd8e3d63122,
+                    0x8cca9b0469c389d3,
+                    0xbc8f0e0fa3cc33de,
+                    0xc3c1fa24f0829dea,
+                    0xc727410616bd1960,
+                    0x774a08cde18943d0,
+                    0x7aff8a935ea3f487,
+                    0xf3faa0bd8037fb50,
+                    0x76778dc3e3162bad,
+                    0x0989cec6f6c45733,
+                    0x449298d2e4b3a03e,
+                    0x006c73d7cf485198,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x64bd2728b1c23fc8,
+                    0x748690c5a25157fe,
+                    0xbb335d7844e0f0f9,
+                    0x5fc5dc55345c25bb,
+                    0x18befe6c9bf9d15f,
+                    0x902c6b846be4ae27,
+                    0xa34ce95fa61d7b5b,
+                    0xb946ed68cdcfad99,
+                    0x23ac3b3481661a21,
+                    0x0ba04b5aa9fc90f9,
+                    0x12f403bcf84bb32b,
+                    0x0072e5c773798473,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4225d01dc9ddb23,
+                    0xb615909c8db6bf72,
+                    0x23e6fa69216b37d8,
+                    0x4b3fa70f3e72f53d,
+                    0xb31da32d5e3b2c4b,
+                    0x2f7bd5c07d923e80,
+                    0x0ac3219c7de61586,
+                    0xaa70b5bd79a96943,
+                    0x922f17a9e22bc547,
+                    0xb357a7a88b567e31,
+                    0x30d939fdf13839ef,
+                    0x005d254d62c54bb5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf579a06e02add62e,
+                    0x133925e3de324a49,
+                    0x77a1aff7cbd8e9f1,
+                    0x83666e79a65f35b0,
+                    0x94beee931a4b0e66,
+                    0x9653c047d41680e3,
+                    0xa87ec8981a54477d,
+                    0x17c012dfb81cdc31,
+                    0x6589a8bbb1f85e5d,
+                    0xe0ed32064de563f4,
+                    0x084f6625aed7de8c,
+                    0x00d587484b59a0d3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a6ee00ea9607bde,
+                    0xb924cdcaa5f05915,
+                    0x8f1bb2318e241e51,
+                    0xb7ed23e45f6efe7d,
+                    0x480dcdfeb2ba47bb,
+                    0x2a3465be55a6a843,
+                    0xb347bf4b44494d78,
+                    0x8bb506a9ff305a51,
+                    0x00cf765b2dc93360,
+                    0x36b0cc4fe8ba69f9,
+                    0xfd83bd4ae327115e,
+                    0x00109bd6457b2594,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x407ef951a591bf9e,
+                    0x2c204ae81a8a4890,
+                    0x0503728f76fd1ba5,
+                    0x052131601c421846,
+                    0x752eae90fcf1bf58,
+                    0x2c5ad1dc758f00c9,
+                    0x81817ba9fe58d620,
+                    0x713e13dfdfd0dcb6,
+                    0xb58c6196e658c442,
+                    0xd590bbcebb6cb8e2,
+                    0x76ce93189dec55ad,
+                    0x00c1a5aa92d7677c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb0229610e88bf7ee,
+                    0x6ab74130d8a3af14,
+                    0x6ab5a0015a8b8074,
+                    0x79eaa9e72a4f7f90,
+                    0xa042b6116dc67d77,
+                    0x7fc8e47f015a5a86,
+                    0x5d06f0215b5265f9,
+                    0x94de2fb540e4a091,
+                    0x16eefc3bf6833803,
+                    0x77e506951f268770,
+                    0x7417a1dc36577cf1,
+                    0x007b84238c4947f1,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xbd70dff37bd0fcf2,
+                    0x9e156b39e6155ddf,
+                    0x6c164f3c56d541e2,
+                    0xb399c23c34319450,
+                    0x2e22f081e9e650a1,
+                    0x794e91d48d02557a,
+                    0x7a07aa62bd245cd5,
+                    0x492bbf0661f8f126,
+                    0xc55119798510ef94,
+                    0xdecf9fb18f68e485,
+                    0x8a9c128f37121320,
+                    0x00ded2a696b3bb6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcae31c6231a67f93,
+                    0x8ec285adb6c7d2c0,
+                    0x6ddfea2e4e662620,
+                    0xbd89ca25133359f8,
+                    0x44d264fd23788006,
+                    0xd001ae59909d0846,
+                    0xbac334e065bafc92,
+                    0xb30f103d289daad2,
+                    0x418e0f2c696038aa,
+                    0xed3648b3e324310a,
+                    0x803de2fa6cb8af33,
+                    0x012142ce87f81dbc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x66d434a1e2c2d706,
+                    0xa154ef9fc77b83e3,
+                    0x13ad52cc832db2ab,
+                    0x34d7e30813d27e4e,
+                    0x2af212ee9987d7f3,
+                    0x490017ec7fcbc1e7,
+                    0x0be5444e16bdc4a5,
+                    0x33e828f1b5cad745,
+                    0x1422d7998749d510,
+                    0x794064e5256f3202,
+                    0x5400e0605b27568c,
+                    0x004a79aba3a891cb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38962bb5f1f3809f,
+                    0xed8a4f2d4ce87f1c,
+                    0x7ba90789d6c94f79,
+                    0xaba8c3124a20dbcc,
+                    0xac5fe838b95854bb,
+                    0xf2ecee825408948c,
+                    0xb6c9d56ebd5a2995,
+                    0x498cbb89428ce6a9,
+                    0x7c25cfac5aa3f7e6,
+                    0xa281a9d9f423d42e,
+                    0x968acba51fbe39bc,
+                    0x00ff36ab31d16e6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x71a2f55d3c6da4d1,
+                    0x7e22040ca3aedbdf,
+                    0xaa53f016ea3a816c,
+                    0xc88d34978db304c2,
+                    0x907f82739746466a,
+                    0xfa4986453db8197c,
+                    0xf9da5106e704ef9c,
+                    0xa68e81e9350891b5,
+                    0xd1493ea153f07575,
+                    0x681e52651cbae185,
+                    0x4bcfd02e2a28415e,
+                    0x0060345784a1c4c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a859255c375db4e,
+                    0xb2dcaab195c40eb0,
+                    0x37a849a7d547928a,
+                    0x3d68c9cf0c488183,
+                    0xe6fa3fbdc33b589f,
+                    0x937b566511a3f928,
+                    0xf9088877c912de95,
+                    0x927da05fa1d3d42e,
+                    0x7752accc8bcfb2d7,
+                    0x4f9f49bb889030e6,
+                    0x7b61485cf98331f7,
+                    0x0009906ecf0f44d4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2811e892a52f64b7,
+                    0xb29434e68a76de45,
+                    0x4c8c3d44ce676cb4,
+                    0x2092d096de513952,
+                    0x99e33ca52f39f027,
+                    0x2a0bd9f57255dc8a,
+                    0xe7da7a696b52c30b,
+                    0xfa6573585082a83e,
+                    0x3b6529a5eb50826f,
+                    0x37024f72d4526ab5,
+                    0xac8568f285cbf716,
+                    0x0080409bc27e37dc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc3e1e14b49a299f,
+                    0x5839b1b4b0ade1f0,
+                    0x74efda2307ab0435,
+                    0x7933506d2d01ab26,
+                    0x7aad953a26910bd2,
+                    0x1aeaf67a4e9665eb,
+                    0xa7572b68dcdeaad5,
+                    0xb4ee67ae99034d01,
+                    0x08f234a4008527cc,
+                    0xcd628686be12334b,
+                    0x6ab4c10a198d0332,
+                    0x00c9f397464bce1b,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 1140];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+            bytes[380..475].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..95]);
+            bytes[475..570].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..95]);
+            bytes[570..665].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..95]);
+            bytes[665..760].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..95]);
+            bytes[760..855].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..95]);
+            bytes[855..950].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..95]);
+            bytes[950..1045].copy_from_slice(&to_bytes!(input[10]).unwrap()[0..95]);
+            bytes[1045..1140].copy_from_slice(&to_bytes!(input[11]).unwrap()[0..95]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x5c407cb01fef08db,
+                    0x618a286ee0ab3cd9,
+                    0x3dddbe828e709c10,
+                    0x424ad86351f976ca,
+                    0xae58145795dd19f2,
+                    0x47ae9eb52d9edca1,
+                    0xa7a3ff9013b36050,
+                    0x75a2ef81870ae7e8,
+                    0xa84d30ed2d829b73,
+                    0xe99b8af30bd6c430,
+                    0xe7f727bef1f0f4b0,
+                    0x00a0629b5f6abdda,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe722e0696b6e537b,
+                    0xf8ae9d354854ba44,
+                    0x6a7d452655ad70d7,
+                    0x9fa03884bc339b37,
+                    0xb07733ded7592caf,
+                    0xe628b87994629fbb,
+                    0x4d0cf4c80569ce7a,
+                    0xfe6f5453574b6510,
+                    0x9748caaf36ceda49,
+                    0x64e9f08f1b7fa9ef,
+                    0xe97b2a8a28429a5c,
+                    0x001a058a067f7d61,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa46466245b948a9d,
+                    0x0361112f0d6e5eb2,
+                    0x84ca2ff033200036,
+                    0x5865e577a0dd2979,
+                    0x9cef9ad831be80ba,
+                    0xd455c12284f30193,
+                    0x8a40ae3e415c2b27,
+                    0x914d4eb93c3eec77,
+                    0x7552ad8359799db7,
+                    0xb48fd904774eb194,
+                    0xe44dc62030fdc4a1,
+                    0x000ee53ac4ddac2b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1873ea69c5e1361,
+                    0xf341591b7ec27226,
+                    0xf48a34882f2c70c6,
+                    0x07ca2e457c7e0563,
+                    0x8e2e2d8811573d26,
+                    0xe9ed8159462812d2,
+                    0xf6f7eb6fd78816ed,
+                    0xd854bc214e27cbaf,
+                    0x71fe490a84330d03,
+                    0xca3bf78f681b7594,
+                    0x7b880d6b2756cf1d,
+                    0x008bf5249fb6ea33,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc9520e930da6c8c4,
+                    0x8f4e3806fbda48c9,
+                    0xe8d38e7db380a8ca,
+                    0x8328af8f9c250687,
+                    0x4113236e000de9f4,
+                    0x8a2695fd053b34e6,
+                    0x350bc3b506104dac,
+                    0xa70c6895135cf227,
+                    0x51c8adae1729ff3e,
+                    0x25cd2915497a2862,
+                    0x78b024f6da17f6ab,
+                    0x00c2235def02d70c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf2354097526deeab,
+                    0x589f79b823b01611,
+                    0xf1a0c5c5bde28131,
+                    0xcde44af4714fd891,
+                    0xb9a3e6406262ecd7,
+                    0x028c02d77301cf25,
+                    0x7653a8772cfc910a,
+                    0x287b11485b9c30e1,
+                    0xda90e4b4dbb107d3,
+                    0x13578c2099f08a7a,
+                    0xf43ce5afda0fadf7,
+                    0x0070c3a1b836a999,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6e25a68eab2bd9bd,
+                    0x987efbdb867df3ef,
+                    0x3c7996217dcafd2b,
+                    0x29cf982660ce7479,
+                    0xc96bc4c5e7c48354,
+                    0x6214c40ee17c309a,
+                    0x4b9cc5c74b3a7133,
+                    0x85150d59cf932aae,
+                    0x019371bc0646a330,
+                    0x22b505ab22202269,
+                    0xa059f79e2982dc42,
+                    0x0087c0cfdf1ab65e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb72f8738fb0117fa,
+                    0x652e625dedbe4c06,
+                    0xcaf9fdfb7d4c9adc,
+                    0xa7c1d897a2140dda,
+                    0xbcec945093790de2,
+                    0x2ce3498d51e95539,
+                    0x39c44dec33b5be44,
+                    0x5a6e74187fbb8631,
+                    0x32c7c69817f2993c,
+                    0xaa215c3b288fa306,
+                    0x73b3cb6a01731118,
+                    0x00e7e718169f89fa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfe4aa70eb8b102ec,
+                    0x9428ff6a77390690,
+                    0x5488c804dbf38227,
+                    0x9c7edb400d498641,
+                    0xcaef2bd5287859d0,
+                    0xd904ce1e53f6bca9,
+                    0xd53a52044f6b56ab,
+                    0x2987bd7703630adf,
+                    0x931328dbc5df722a,
+                    0xe1c22f7432f7765d,
+                    0xb3a1e8200d01ef3c,
+                    0x00a36e89887c7025,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5a37d179b5efb48d,
+                    0x3f13d9f67933a73c,
+                    0x6e7ba8ce88bab31d,
+                    0x046e36e2b1458d15,
+                    0x5dae90a80fb77d87,
+                    0x17049e712572bfcc,
+                    0xb3541784ad86976e,
+                    0xec06695f404b75bf,
+                    0x8b2886669dbbee80,
+                    0xf4452f8a665cc6c2,
+                    0x56d60c618f8233b6,
+                    0x00c4137b6963755e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x461cb11cfc0c94ec,
+                    0xe9128453bed2eebb,
+                    0x3c7fbb20d5127c39,
+                    0xacee534e63c500b0,
+                    0xb8b8692b866a3cca,
+                    0x1e4a19fae2410de5,
+                    0x4d234cbf979bced7,
+                    0xcb0aaa58539cb242,
+                    0xb3d9aea5a60168d5,
+                    0xb87fa7da406c6ad1,
+                    0x619b41856c8f144e,
+                    0x0084f947e7ec2270,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5a1ce5887bf80a27,
+                    0x39a603d27697b76a,
+                    0x7e2804eaa18e3f56,
+                    0xfcf2497114a64057,
+                    0xe592fc299695e3d4,
+                    0x54b745fa924838b8,
+                    0xa20255673e46129c,
+                    0x366083f4bf78f508,
+                    0x85c08823a1373b70,
+                    0x5b1779968fb24e60,
+                    0x5817fffcd76c645a,
+                    0x0115d3593e8a7067,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x9390d3225e0619ec,
+                    0xaec3d2035cf88ce8,
+                    0xf879f918f28a650e,
+                    0x6836de1762fc453c,
+                    0x4061870e81b777bc,
+                    0x2e56decaea0f80a4,
+                    0x902e3df593a7a0f5,
+                    0x453c49531ca7326a,
+                    0xf9cfa7b0744c92fa,
+                    0xa8d2ec81808d52b4,
+                    0x75ceb170c482df51,
+                    0x0056b65584b7058a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1487719c7126be3b,
+                    0x8ab6613151fb6e61,
+                    0xa3d4f442e7a1f87f,
+                    0xbd393a46fb0cebce,
+                    0x1d0f6c9756c80de1,
+                    0x137542702ab9a9a6,
+                    0xef37b43742f07e09,
+                    0xaee5c64336bff03a,
+                    0x6d499a11a7f7ba1c,
+                    0x1f0fa63570c682e5,
+                    0x9ba07616097bbb4a,
+                    0x01196b1be892ba0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf33d53e8710b8834,
+                    0x7dba6dbb139df207,
+                    0xfccb8fe166c54676,
+                    0x2c380c21b16cbafa,
+                    0x0a944c5e69c9d52e,
+                    0x5075e2e4976fb1da,
+                    0xe46113096041afe0,
+                    0x22331dcb4d75047f,
+                    0xaec490d296a90aaf,
+                    0xcbe300df3bf05b80,
+                    0x7a4a58a60f4863dc,
+                    0x00c8b3ada08da8fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c036c071efcad5d,
+                    0x30367a7b1b8929cb,
+                    0x8a911641473496d6,
+                    0x40f85cf5d2384dd9,
+                    0xfa5ffd4aa742c932,
+                    0xb840ce27d6032d7b,
+                    0x6109f81dff7c3ded,
+                    0xc4146b0776e9636e,
+                    0x676f1d0ba7a9b16f,
+                    0x6673f0fa1a62ca78,
+                    0x88f6cf00740e2efb,
+                    0x00eec17ff790edba,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8d1688444849ee7a,
+                    0xa38690a5280bb8a8,
+                    0x5eaf4150f99a48f7,
+                    0x33d3bb661483628d,
+                    0x59dfe42dfc8fce1d,
+                    0x50cd372481a9587b,
+                    0xa2cea417cb257d3a,
+                    0x1108f91f4131e9ea,
+                    0x09daac44bb0c2566,
+                    0x7a4778603dbb3d5f,
+                    0x75d12e23cefc1300,
+                    0x0060f6b737b14594,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce8f735d12462e69,
+                    0xf7fe9106965914ff,
+                    0x2c4ed997c74a5bbf,
+                    0xb0e8cd4bb2f3a88b,
+                    0xac3ad8cff6c423f7,
+                    0x994775cc8229feff,
+                    0x8aa3651449d9b17a,
+                    0xe6287f5f62274d3a,
+                    0x320f63bcada76d5a,
+                    0xdfd5624d45e38dc2,
+                    0x5bdd86a009f42cde,
+                    0x0080db62eb4642b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3d01141577aa4032,
+                    0xb9370c9010dc6720,
+                    0x247564fb14078981,
+                    0x42fc224e5b9b7d83,
+                    0x312917ed824b9c44,
+                    0x4830a88d70f29b2c,
+                    0x4039dc3ca670a9f3,
+                    0x59a2220dddbbaa00,
+                    0x51a77761a52bf369,
+                    0x10e63a5cfb9bef11,
+                    0xf772dbaa484cb15d,
+                    0x010dfee9e3361882,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1062559391e1841b,
+                    0xe0f4338d0dd41820,
+                    0x70993bf90406eb6d,
+                    0x8ca3b4d9d1d837e5,
+                    0xac347c93306914c6,
+                    0xb9c5754b11070b67,
+                    0x55ea90fef0bf1a2b,
+                    0xf44ae9364f189f4f,
+                    0xe17dcf50c0859759,
+                    0x4079b94da01716b7,
+                    0x68dc8d0c6f5e363e,
+                    0x006cdfdfa63d8a0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f87b6863f348457,
+                    0x3ba8365571a919de,
+                    0x980f815e01480fd0,
+                    0x3195b7714792d094,
+                    0x52297c5444337ad4,
+                    0x21f78f447b442bdb,
+                    0x549df15e0883bc13,
+                    0x56925b48c8167903,
+                    0xbda84413f896ef9f,
+                    0x603d315349fd2866,
+                    0xcee55bff918db7fd,
+                    0x001a511bddde7894,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7148d4efb5e07b29,
+                    0xa305f09bbdf45c69,
+                    0xbedeeeab45707174,
+                    0x214c126664fe1d95,
+                    0x7faf93b8d9f3eab1,
+                    0x500bb97cb3603d1d,
+                    0x140287b1c0e7c410,
+                    0x3352e9942afdf2c7,
+                    0xcf3584f171dd57fc,
+                    0xa67993e3636f3459,
+                    0x07c048aac762312d,
+                    0x0109e4cd34815226,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0ab9599246d67d38,
+                    0x2104ba6f98641d89,
+                    0x66d5d05f37e640f4,
+                    0xbe2e58fdb439a725,
+                    0x66bfafaded78b5fe,
+                    0xc9d5d34ce42e38c4,
+                    0x0f970c796d1c31dc,
+                    0x111fcfccbb3c4981,
+                    0x6ace410fd7796653,
+                    0x6c47e70b89eaed9e,
+                    0x4ab46a769f2264f8,
+                    0x00fd60b5ba5abc90,
+                ])),
+                Felt::new(BigInteger768([
+                    0x66466e982713d373,
+                    0x2d575f92b09a273e,
+                    0x72bc79357c73e6a4,
+                    0xfb709e9856802a70,
+                    0xfa771fedd4655891,
+                    0xa797ead45815ad4a,
+                    0x2a83c001e063b221,
+                    0x64ef1dc1aae44f53,
+                    0xf8d831eeb6fa8f69,
+                    0x4a244dba6cbf70da,
+                    0x871505f4f60464e2,
+                    0x00b0c28c9714c08e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xcf5a7b72974c8934,
+                    0xff1d3e161451a439,
+                    0x01f2d81774f84504,
+                    0x6a0e0c4c11f07dc8,
+                    0xd93ea71ccab23843,
+                    0xe993bee82f9427ee,
+                    0xcb7eb03ed07b81d7,
+                    0x9c14a3e65f3b670b,
+                    0x7f45e8e13e9533d7,
+                    0xb60bbff26f3a867c,
+                    0x534d5028afa65b8a,
+                    0x00f4ca394c132410,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfca0d1cf12491951,
+                    0x0f4b314fc594d9e9,
+                    0x7a523c674550ef16,
+                    0x53953563f4198b49,
+                    0xbbeb4e2ced5dab02,
+                    0x011b855610d32a64,
+                    0xff5d0d91a51c95c0,
+                    0xade12c2c8ef926d3,
+                    0xdc35a6f7c8ed567e,
+                    0x1cb3be67d0bb25d5,
+                    0xad7e46f0a3730ca9,
+                    0x00674130d76289e1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d4a2757a53e5319,
+                    0x1d50632fa98c5c37,
+                    0xc2550b16fe87a301,
+                    0x513e1cb020c6271c,
+                    0xfcafda3a8432508d,
+                    0xc70144280ab542a5,
+                    0x3d9e7e256dfce322,
+                    0xbb7f6f128fdc269f,
+                    0x103a34247aa91539,
+                    0x0f15e03e6c2c8c0f,
+                    0x0ddf43a10451cd2e,
+                    0x001c5e3fedb76216,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa58cbe7ccbe31875,
+                    0x94d89bb35b85eb60,
+                    0xb5e33a3941ed2b9b,
+                    0xe1aca61c8d3fd889,
+                    0xce3510e191752a6f,
+                    0x8ee74c4019c2daef,
+                    0xf08694a5b288c2a0,
+                    0x58928fa54e5ebc84,
+                    0x8896edc6d783758a,
+                    0x24b9c6b42d82abc8,
+                    0x8e49510a76693552,
+                    0x00e8d33e1ab2ae98,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19d93dd37a50b3c3,
+                    0xc4d12be1d9891fe9,
+                    0x2c6f8c2b2f2cef6c,
+                    0x0f751709adea487b,
+                    0xf6166bf2a4749b79,
+                    0xc4044acb6406cfc9,
+                    0xc51fbb2a6cccb71b,
+                    0x5d81b5c2738ba7f2,
+                    0x75f69b68f8e55020,
+                    0xe023569a336664c7,
+                    0x47f0d81b15d0f7e7,
+                    0x00b870397d56c47f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8df6d07852e0584f,
+                    0x53cd3db435fc36b2,
+                    0xa4ef65703c84aedd,
+                    0xcd9bb87f2a65152a,
+                    0xea6f767b3fbb6157,
+                    0xfba4e4aba73d8028,
+                    0x7520810622c64ae7,
+                    0xf300bcce2ddecae8,
+                    0x7cabefd1f7ad4fef,
+                    0xde9117fbbb0f6757,
+                    0xe80d69f54c02b830,
+                    0x00f1235678f83ba9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x54f71d795f83390a,
+                    0x497c8de9b1b5bd97,
+                    0x0649557c9c3fb58a,
+                    0xcd496217b87a07b2,
+                    0x51b894e4bb5f5dac,
+                    0x257b4e8da5fe09fb,
+                    0x8cf190590a151ec4,
+                    0x2a92b74e6dc97d87,
+                    0x4c59018084f22545,
+                    0xe35fbc3cfb973cf1,
+                    0x5e650def8289f7ad,
+                    0x00f145a3f29c57eb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87cffd19c52fee99,
+                    0x00ae7cde98a1ca04,
+                    0xcae84f873c0a7f18,
+                    0xa42142bbbc9dffce,
+                    0x387d5879d61e303c,
+                    0x79b2e5df475b45cc,
+                    0xb83b1f477365e2f4,
+                    0x699a2a2207966a0d,
+                    0x5ef9e9b8a9de4535,
+                    0x2543a56631bea2cd,
+                    0x71ea3a8eaf388e00,
+                    0x00c539666cb35c71,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc5e055f0a52ca908,
+                    0x6947ec04678d9181,
+                    0xd1437102cb4507a2,
+                    0xa7bcfb6b0d410807,
+                    0xe355374d0cf8541c,
+                    0xb7b810240f12ebad,
+                    0x03246feadbeee03b,
+                    0x55f006d71eb66006,
+                    0xb040d9411d4cfb27,
+                    0x0dc9def648314290,
+                    0x38aeb247833c7bb4,
+                    0x011eb5184e534718,
+                ])),
+                Felt::new(BigInteger768([
+                    0x80423eb98615d83b,
+                    0x3321195f0a23a716,
+                    0xc21b938028a84aeb,
+                    0xb6c66c840e1bffe4,
+                    0x56f34233c2058120,
+                    0x8dfbf9c5776bba50,
+                    0xf99d3f4cc65dd790,
+                    0x98e7bab3d5b60f14,
+                    0xc33fb488c22fecf6,
+                    0x235fb6e0cc1089d3,
+                    0x552a6d4dc898ecfd,
+                    0x0101c097fc3bd06a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x493b8c2aeb8e2c10,
+                    0xd8529421f78151be,
+                    0x0989653df25c3c0b,
+                    0x023314ae685978c9,
+                    0xb6c8ec18a845a8b5,
+                    0xbe0db70ad4f045c7,
+                    0x6cdc7429d2677373,
+                    0x3e3076fef238d17c,
+                    0xc0ee370ab09a1142,
+                    0xf5e6dba7faa1058b,
+                    0x1898f66898bc9722,
+                    0x00d8ba596f74348d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4bd3b75485a5fcbb,
+                    0x18a44de4f91e8069,
+                    0xef1abf31a0ed106e,
+                    0xe6a09275b2d05b9b,
+                    0x0071061bde670818,
+                    0x081af024a13f3f6c,
+                    0xb0a40eb006b551ef,
+                    0x4122b73b136de6c0,
+                    0x935381b2ee165b96,
+                    0x77557777fc6d7cfc,
+                    0xe3a198cf4dea3b27,
+                    0x001eb3edefbb1b2c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xcd7766b1fa4ff04b,
+                    0x22d2d50e81102484,
+                    0x26bce1544b9e5ba2,
+                    0x61f4cdb8a40860a7,
+                    0x27a868bc7065a657,
+                    0x2ab169bb6bdfcda4,
+                    0x9c81417cc3d87223,
+                    0x6bfa26c79f094165,
+                    0xfe9227c8252272cc,
+                    0xf61e7d34beee4236,
+                    0x0990ffa0326e834d,
+                    0x0013191c9187188d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbfd2a60787aaf7a8,
+                    0x5a2990534b164be1,
+                    0x9e1acf1ef4b5825c,
+                    0xf162aad89c64a7da,
+                    0x911a80d34f1360e3,
+                    0xdcc66732c9fcbb8b,
+                    0x35959b57bef05365,
+                    0xe5deeb4dc0431322,
+                    0xa5c134b3f0796eea,
+                    0x1c01e35e0a4437d8,
+                    0x4943bd71fa160646,
+                    0x00088f0e0b450377,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe78057c77c92b059,
+                    0xa2da49d765013ede,
+                    0x8fa2c837dc0d2355,
+                    0x3079f360ce771560,
+                    0x69698033859d738c,
+                    0x09a92eec368f4374,
+                    0xa766c265d23e524b,
+                    0xc14a399f5f116d29,
+                    0x0b92fbe61d0e0bd1,
+                    0x882aa076a55009a2,
+                    0xdd3d0d8e4730160b,
+                    0x002e90fa34ed68ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x666aef96426d24c2,
+                    0x3fc6a42d083495b4,
+                    0x3015edc48bfd3a85,
+                    0xa8ae5dceba934f4a,
+                    0xfb03c282d2a18e50,
+                    0x8aaf6122e8de9482,
+                    0xa147694a012e131e,
+                    0x8df8bd93b54061fa,
+                    0xcca7ab567959a40e,
+                    0xffacdddea1691ca7,
+                    0x88fb080ba7ebe7c1,
+                    0x009e7c66907a7365,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51ed2ac84e48a1fb,
+                    0xa26a927510a5d190,
+                    0x38f247b6840a4580,
+                    0x13a17be9e87dab36,
+                    0x726184c5842fdd59,
+                    0x276c683cb7a44a19,
+                    0x672401932d954c6c,
+                    0x7fd83995b123e8c5,
+                    0xba3b79239a3e8d1d,
+                    0xdde2154d9ef9b219,
+                    0xa6e57759a8aa7610,
+                    0x00d7ec1f7dd81bd2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf748386d4c499006,
+                    0x999a0abd2a5c162e,
+                    0x5432e2f73a8c9bd5,
+                    0x131aa9f35a9f4fbb,
+                    0x37093f71d0133caa,
+                    0x1bf1a2f2863346b9,
+                    0x8bef82b915e3697d,
+                    0xf203643ef2710aff,
+                    0x54edafb9c7abfd61,
+                    0xdff645ffbc63783a,
+                    0xaff9261550e5a847,
+                    0x00a3784ac5db6836,
+                ])),
+                Felt::new(BigInteger768([
+                    0x17ebb1f29f5117a6,
+                    0xa6709ffa680fbfca,
+                    0x1afa18f86b0af16e,
+                    0xc1d8170744b66f6e,
+                    0x88524bf7490dbd04,
+                    0x80a76b87458a802d,
+                    0xc6009d2353930b5d,
+                    0xc36acce82b34d69b,
+                    0xbf2a973968f1a41d,
+                    0xe4d72e242faa3003,
+                    0x5866a6b27ce1b7ce,
+                    0x0112fae18e307fb0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x396353e90cc68ecc,
+                    0x4daa9b2ca8415a20,
+                    0x700133ccdb6c0f1f,
+                    0x22e8563df4d22902,
+                    0xef8121ab3e85936e,
+                    0x73115c4bc0bde397,
+                    0xc985cf20a15025a7,
+                    0xb71325ad5e0f7f02,
+                    0x0726ad383abb2439,
+                    0xbe5347807b0c92eb,
+                    0x62668d8f1ebec4c2,
+                    0x00efdcb472760f7e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7ebbfcc74ea0173a,
+                    0xa317763ffc9cdcab,
+                    0x19d787b78730a26c,
+                    0x2ecedb8f1a94fbc9,
+                    0x35192eb0f3f45859,
+                    0x20d25a445217ed42,
+                    0x60f57e06c38fda23,
+                    0x62fefa816f8c9d98,
+                    0xf3298909b6e5bfa3,
+                    0x7b5c09dffec2fca0,
+                    0xfe4298d1d94050e7,
+                    0x010c8f63cae793cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2e06021497f94c2d,
+                    0x3165862c37027b1f,
+                    0xacdf5e8bf490655c,
+                    0x7673238042a520fd,
+                    0x1457e76a03841f29,
+                    0x48f090fb5f48c2c3,
+                    0x30086393b3a1d380,
+                    0xa89b41d9835b18cf,
+                    0x1a1f6cd41121de01,
+                    0x67308ed6da4364ea,
+                    0xc4251d5c36287566,
+                    0x004bc507a3316f69,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd90586b1a107cb65,
+                    0x8c980d1860447c4a,
+                    0xa49ed3ccad9925aa,
+                    0xa7ba2e1d412ffc50,
+                    0xf948dcc31a8ec568,
+                    0xc4e93031f086f7de,
+                    0x38b7adabd7f3a77d,
+                    0xba262ea6cde439e9,
+                    0x641abb6c5258e028,
+                    0x1e4390236a2c62cf,
+                    0xab48461b0bcaa800,
+                    0x00d915e655230bde,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7fb0804cd4ef3594,
+                    0x59810b06dffe4746,
+                    0x036939180322ef7f,
+                    0x563add3924282ba1,
+                    0xbd55cb27552095b7,
+                    0xd17ba94d7ddbd41a,
+                    0x2d73c4d66a6f6659,
+                    0x0da6760e666b6ac3,
+                    0x07934f5753b76fe9,
+                    0x812959536804dea9,
+                    0x6e456fff1e64ddbe,
+                    0x0048bf41e807f6d6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x95408f80bf885bca,
+                    0xd9956ff2f6e87e64,
+                    0x8d75df0cfb160e4a,
+                    0x0c04ba1b625dcff8,
+                    0x4c3ede6842b163d8,
+                    0xdc821255f7eae746,
+                    0xb03c3f5c938daa97,
+                    0xc37da8dd8be58ee4,
+                    0xecdfbe495c9321ed,
+                    0x9fd4c9522716f724,
+                    0x1a92118ab10f90e7,
+                    0x0018f5db2ed08132,
+                ])),
+                Felt::new(BigInteger768([
+                    0x448d21bca9bb8eb5,
+                    0xee27749109a9b797,
+                    0xda8c4e3632241240,
+                    0x9d7469ff4b43afe9,
+                    0x0830eb88c46b433c,
+                    0xd76baa5927d8cabd,
+                    0x8561f4e4f391c5d7,
+                    0x628b2f3eafb5c7b7,
+                    0x2461c315ab473e62,
+                    0x3118426f3dd819fe,
+                    0xc999f46a489bb7b5,
+                    0x00111b9b4834c9bc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c4693e9be72b601,
+                    0xdd640ea37820dc07,
+                    0x2f08d1897ea04f91,
+                    0x0eb3de4d8adcdb39,
+                    0x7697f9fa523d3d08,
+                    0xf523d018cb228e12,
+                    0x295b61113fa13b44,
+                    0x1cbad98bbaba3a30,
+                    0x795a7cca76fc7ad8,
+                    0xefd8528f60b6014e,
+                    0xe5c74afd2ba2388f,
+                    0x00eea6cf3164b968,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd91e630fdd0bc59d,
+                    0xf0218b0bafa51a4c,
+                    0x38f23dbb7392b6a8,
+                    0xb756860f9b4aa321,
+                    0x1857165386694c76,
+                    0xbc5a1ce2b63018d5,
+                    0xbb9043f3bc89b9ce,
+                    0xd7b4a2727c1850b2,
+                    0xebf7a9b8aa542fd3,
+                    0x02be30fb4b5289c5,
+                    0x1f7ef3c89aff7ec3,
+                    0x011fbc93602873ad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x64d578c7cd4d224c,
+                    0xff372e354f0c8cb1,
+                    0x3db63d3f736d2796,
+                    0x6f157b1e8d47df7c,
+                    0x07d899a0fad8db7d,
+                    0x2a744c6eeeac9ed2,
+                    0x3504505ca2ec22df,
+                    0x0c8b9e56bf2895af,
+                    0x43159b1625f6377d,
+                    0xa998a55d09af4b1c,
+                    0x59388906d86ce310,
+                    0x007b975a5d270998,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd436817faefad2ae,
+                    0x3be75db9c29d3a9b,
+                    0xec0f4515179461a5,
+                    0x7ba289f5330367b7,
+                    0x5b3a9911d5540116,
+                    0xd537fa8a479d4017,
+                    0x1c710aa907c920c1,
+                    0x7e00a46c537b8f18,
+                    0x0f1e5ff0a2dd30e2,
+                    0x5e4fe5707cf8715a,
+                    0x06c398064d4cb371,
+                    0x00a645806854be79,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12cfb97b08da0933,
+                    0x484b31d4c00d7510,
+                    0x76c2ed2b4d927c98,
+                    0xe67dd7e4984d312f,
+                    0x27b1f18fbd05b2c8,
+                    0xd18ed411285c86e2,
+                    0x827b058937ecdbb8,
+                    0x6e9c2dbc176a37bf,
+                    0x6a14aec502955f1a,
+                    0xabe30b1139ca9899,
+                    0x1fa35fae8a57d167,
+                    0x00b8843880edc776,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc941c62866ef7c2e,
+                    0x0d836d79653dfc5f,
+                    0x0770a6609f34dd59,
+                    0xe5fba9a76b002b4e,
+                    0xe99bc67f4b033baf,
+                    0x66cb7f8d139ec338,
+                    0xdc3a3a56657e0898,
+                    0x8e76cd6bf76d269c,
+                    0x8f5ed9c72c769302,
+                    0xd32597e7b9ec6186,
+                    0xfde29905e6d13314,
+                    0x005db9e76acf5333,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd834c91832b67043,
+                    0x72ec1516edb20af8,
+                    0x66b2107f80969d98,
+                    0x70d38391c56a0751,
+                    0x362e49ee11c57615,
+                    0xf4c9c6687fbcbd5e,
+                    0x94d47fcb5c2e4262,
+                    0xe04e871a7a5bf5e4,
+                    0x6cef856383a83268,
+                    0xf80a5fa66dc7dbee,
+                    0xd9521d09a6fc392d,
+                    0x0106ad0b2ee4fb01,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f8fea7b3cba9eaf,
+                    0xfcde2356f2c4e526,
+                    0x91cab436b18e5795,
+                    0x8612d1e0382fafad,
+                    0x9bbd5d3f4f49d2ad,
+                    0x800667f80ca78c04,
+                    0xb460d4c370db3474,
+                    0x4a087a90d55d0d24,
+                    0x5c02821576b19dd7,
+                    0xa3f3e02b3f83d4b5,
+                    0xd125ec01864b53aa,
+                    0x00e14500102d5f85,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf374c73b1593a36d,
+                    0xa21f2bec9b50c7b5,
+                    0x61b7f00be995da7c,
+                    0x8e23ac22a2b9c429,
+                    0x36b66d6a350bf5bd,
+                    0xaf38bc2eae2495ac,
+                    0x3f86e1dbccac711d,
+                    0x312873c7936589ed,
+                    0x5ad3d4713c40dd3d,
+                    0x60588fe46eb256bd,
+                    0x0a0a3c0a08633d00,
+                    0x00ea517c95fe8ba4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x735bdd323570ed1a,
+                    0x62335cc8f1054373,
+                    0x2239c0d79c8463c9,
+                    0x9c368e65955e6c3e,
+                    0xbf1d371f3eaeac06,
+                    0x5dda78aff88bd188,
+                    0xd98948c6ce5ef58f,
+                    0x26cfc35ed85906e5,
+                    0x53daf0c9bcf3100a,
+                    0x9fa27511583d16be,
+                    0x8a06d95342cbb1dd,
+                    0x00d66189d9779bf7,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x19bceb5b4fd114e0,
+                    0x11068ae523e7abf4,
+                    0x96e671106361bced,
+                    0x4691803546aedca8,
+                    0xe30f0dabd11b0424,
+                    0x4b718069e9e734a5,
+                    0x4a97778f95111575,
+                    0x02834b82d5fc5e27,
+                    0xece0a903e0542367,
+                    0xba93a1abf13672db,
+                    0x92d6716ad8146571,
+                    0x004509f6063b636a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f91ea4c1f15421b,
+                    0x47e5cda00043c00a,
+                    0x4b33a6b67467f786,
+                    0x34136cd948a25913,
+                    0xc5840ff6410e9e2d,
+                    0x7f545f76ef191e38,
+                    0x951da86551bc9ae9,
+                    0xacf76e9594d68478,
+                    0xa517bf6a3ff35fc8,
+                    0x7684c03195765972,
+                    0x19e2b0f41f4d0ce1,
+                    0x0069c7ad71311674,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4f31af7054fdc80,
+                    0xb35baf9609f9fb2a,
+                    0xc16f6aa6a7cabf77,
+                    0x1d90533a0417af46,
+                    0x4759ff6e624580aa,
+                    0x1f3d9d07f3a03579,
+                    0xc91990709071d278,
+                    0x9728fe24a38ea05b,
+                    0x43fe62733ad01321,
+                    0xc865045b5d150237,
+                    0x61a11891d5db7521,
+                    0x003e3f87b01606b8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5777c27f3e361ebe,
+                    0xa9f81e31b0c8afb7,
+                    0x7f34608d770031c9,
+                    0x25b8d6789bdc8034,
+                    0x4ae48e96119291f5,
+                    0x0c4bcbe92b4ce44b,
+                    0xa494050d8d4cc2a7,
+                    0x845609c40f8518c3,
+                    0xd519fec9b79bddf3,
+                    0x67185668d676f8e1,
+                    0x8b6c9bdcc919053d,
+                    0x00a924757bd38dc7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfef79d8c262a6dd0,
+                    0xefc61cbfbf7dfc6e,
+                    0xa98b6b4776108bdf,
+                    0xb241742092fb1d94,
+                    0xf581a982f6d842c3,
+                    0x549c4a440516f8c0,
+                    0xfedb8dcedb86f704,
+                    0x0a44efbeac086c4e,
+                    0x4ebd2d725d60a331,
+                    0xcc1964cb01e6924d,
+                    0x9b765af3c1c244ec,
+                    0x0116b7123af99377,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4f6b1527c2b71cb1,
+                    0xc53c83381500e64d,
+                    0xd6d51869599a3d00,
+                    0x3943037ca047b5e1,
+                    0x38fc29a81df9f6c8,
+                    0x94905ce4d9c54730,
+                    0x33172a84606d85ee,
+                    0x001325cb6e3a5984,
+                    0x40d7e656b6fbce63,
+                    0x4faa652e8696c8a0,
+                    0x4a09bbefb06a6a81,
+                    0x0089127228dfb9ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x79233aeabd4375f6,
+                    0xf5034892d4992027,
+                    0xf3bdba1991e9fff6,
+                    0xd8547ac563228ebc,
+                    0x4ae936517e779e85,
+                    0x7ddb97ce2da12f5b,
+                    0x3d1f596e035e052f,
+                    0x33e15f0e50991032,
+                    0x7e2c61032e4ffc5d,
+                    0x531111de68df16ea,
+                    0x68df959350538041,
+                    0x00495ecde04378a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x891ffd4e64e2543f,
+                    0xf5d0562d686a9ce2,
+                    0xf506a0416e8780a8,
+                    0xa8865e22859cc2b5,
+                    0x0132ba98af920c77,
+                    0x7705b9774bee9191,
+                    0xcd14a930a66adafe,
+                    0x652131820a6a18a1,
+                    0x988913fffb186cc7,
+                    0xbda316e7dc663435,
+                    0x8a50622f4c56f228,
+                    0x0022179436d398c4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd41bd7cb316f0f5b,
+                    0x4790e18522fe9287,
+                    0xb0e1cf0b32e40cff,
+                    0xc5c539192c1b5471,
+                    0xfd5a6fb1e5f3c64f,
+                    0x80bcb1de343d357d,
+                    0x72e165b08dfd6d43,
+                    0x62654ee32e115d2e,
+                    0x7bdfdc15716f05ad,
+                    0x9b2a8ad0f36985c9,
+                    0x03bc6ec7ef2e57d0,
+                    0x00a43cd1716e6c28,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea253878faab2c57,
+                    0xda2d626965515e4c,
+                    0x43d015e9673571bb,
+                    0x509ef8cf3cfd6dcf,
+                    0x7419ae339e34dcb1,
+                    0x77745adcf5a84637,
+                    0x7d11add9d291487a,
+                    0x033f08a8145ac19f,
+                    0x8231a887732fbd89,
+                    0xd0814158519af39c,
+                    0xf35215b735e9f203,
+                    0x011c862341e0ed2c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb63d1b3231dc1e57,
+                    0xda969a4dfbf5a644,
+                    0xde8f7c725cd3a470,
+                    0x7df57025a5e3f9f7,
+                    0x0f6e635f0efa0a41,
+                    0x445926e7d893ad99,
+                    0xe42c88e551db19a9,
+                    0xc1b97e4681cdc801,
+                    0x0ef6fcf1e0d89440,
+                    0x2f65ffe3e82861bb,
+                    0xa501ce0a9565215a,
+                    0x001b6b14e21a6007,
+                ])),
+                Felt::new(BigInteger768([
+                    0x77fbc55016fd50d6,
+                    0xa42c7d25f36a2de1,
+                    0xc08a01a036fb3245,
+                    0x43cb6867829f9ac1,
+                    0x73e146cff13911d2,
+                    0x9f7dc093fd3dae88,
+                    0xcab42d86826e26a2,
+                    0x36c0f4d6d1f80758,
+                    0xc78dc3cc5b8ab780,
+                    0x91fac900c2e9582d,
+                    0x90b795ec57cae7a5,
+                    0x002f8344de2489c5,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xea73b4acae7087b4,
+                    0x2bfb90e863a594ec,
+                    0xbfccf1001f1a7ef8,
+                    0x2902259d24f52e88,
+                    0xdb5a7b842b39cdd1,
+                    0x0d64b0ece043064a,
+                    0xd76f830237449f13,
+                    0xa527cf5d30503b3a,
+                    0x77f8f8caf1bee014,
+                    0x4129d012e04de316,
+                    0xb23fac1f1d5052b4,
+                    0x00e274bf5c391775,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32159ba710d60043,
+                    0x5e35c99dacd6e074,
+                    0xf176f44b192dd7fc,
+                    0x782a368f6efee54b,
+                    0xe5a3f3df5b90bfd5,
+                    0xd3062c8b4d9e7012,
+                    0x60c100e03766db18,
+                    0x500cb83584896f12,
+                    0xd5159008ae815c9f,
+                    0x339d90b68f14f76f,
+                    0x2356d49c9d85cf31,
+                    0x00878b6e0e828a6c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82491ab55a54124c,
+                    0x9455ead187ee739a,
+                    0x6902f7dabeaaf620,
+                    0x1cd5b5629d1982c5,
+                    0xf190cfb29b383538,
+                    0x52f061348fa793f0,
+                    0xa40074b14350ee67,
+                    0xe777b422cd93e3f4,
+                    0x753bc07b58a7aa72,
+                    0x12dd2e69f676a284,
+                    0x9206945b50e0ae98,
+                    0x00685edd18b7c34d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x718c3bf495664483,
+                    0xd3387a3097070aac,
+                    0x59fd7b02b9b64328,
+                    0x592b683a627d35d9,
+                    0x32e1d5b4be13bc4a,
+                    0x0b9cf73090088c2b,
+                    0xf564ed695d6e2d07,
+                    0x7582671c45e585cf,
+                    0x78223bce55ba523b,
+                    0x647d190e08e22dc4,
+                    0xe0a0272e01956814,
+                    0x0116334748adc686,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6feb6fdd4e57fad,
+                    0xf52ec68352fb8327,
+                    0x2cd83516ae14c8f4,
+                    0x867466877457c93e,
+                    0x7304ed4b08db91ff,
+                    0x108ed363ad230912,
+                    0xeddc37054a8c2a68,
+                    0x9a44b66daa0c64ad,
+                    0xf0c2536a5e5c6086,
+                    0x20cede089aa804c7,
+                    0x4d97de206ff03e2f,
+                    0x002927424f218341,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6fe2fc4ccc84854b,
+                    0x6a0bb66320923a78,
+                    0x9e43df77328909e5,
+                    0xe99d6181396579a4,
+                    0xf463897755c7d7a0,
+                    0x54b1210cc5aa9784,
+                    0xc9ec3a0b0cc557f9,
+                    0x6adee1f966ed70f7,
+                    0x9f86d3f7957425bb,
+                    0x13a87462bc24d073,
+                    0x77bdf27872b00b2b,
+                    0x0109b55eddb538fd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa18af7a34f32f6a4,
+                    0x77f5247ce8f8d3c5,
+                    0x329406612ddbff5c,
+                    0x188a1fb6382c49ec,
+                    0x66271ec79c166686,
+                    0x7873f7256159583f,
+                    0xd3751e856dd2adf0,
+                    0xc83efc0d0886943e,
+                    0x9b0b939497256f64,
+                    0xcba1bb25da426454,
+                    0x2da73e8d9b7c6e4c,
+                    0x00c021225b44d6d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5dbe99488874fa1d,
+                    0x6a67dcc859cee1b8,
+                    0x0fa95fe64ea868c1,
+                    0x22866c106d1090b1,
+                    0xa122385d13eb407f,
+                    0xfd778aa657c329a9,
+                    0x3c0b6a1b98d6433c,
+                    0x8c752c26fc1fc57d,
+                    0x64460bfc4bc17cf2,
+                    0xda8faed41a7def20,
+                    0x11cb7550640abbaf,
+                    0x00bc6a5210240df5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb224d14f80604f7,
+                    0x4ea7db2533b7ee26,
+                    0x3aa8ec6880f45792,
+                    0x35dc58afe9c6f79f,
+                    0x9f6f45f7e97dd7b3,
+                    0x66b56bbda6453d66,
+                    0x14930a7469c74646,
+                    0xd0bd467c15c8c502,
+                    0x0250b30de1f796f7,
+                    0x0792cb0da2eccdb5,
+                    0x9c3b000a50dce4ca,
+                    0x00bbbb697b68e399,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeebeff0eb0199f73,
+                    0xa54ea7546c9b326c,
+                    0x4af9dee03dcec218,
+                    0xfcb1dfd5a6cbf653,
+                    0xdc80346d280436b4,
+                    0xee63547b9ab57e49,
+                    0x47b65e786c44096b,
+                    0x57cb6f44589d797f,
+                    0x668ff8bf12d6dec6,
+                    0xd667b5d701bc2fc4,
+                    0x6bbf0f8b6b82ac9b,
+                    0x0061f3a5cb1ef491,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeae7358040f623ba,
+                    0x1540287c2e28ec45,
+                    0xe3d5cd26649991b5,
+                    0xf25708da65eb04cc,
+                    0x30db4d971de79bfa,
+                    0xcc3dc82db756147d,
+                    0xe02a6b8cdaf84f88,
+                    0x92f7ee1a783a124b,
+                    0x93196cc39c6588fc,
+                    0x555634dbdcb396a2,
+                    0xe24c85ca046aa79e,
+                    0x004c486f0a565c9a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0eb58f2a70d810fd,
+                    0x24a23d52745c739f,
+                    0x6c919a33fc3a4df4,
+                    0xfc87ab80ce430ff1,
+                    0x2f70f7b46c7f767c,
+                    0xab391908ac5ebb35,
+                    0xce7b7142c2948c7b,
+                    0x8f262da152d767c4,
+                    0x6723bf8fe65ef236,
+                    0x54b3ea5ed794769f,
+                    0x929e53c636252799,
+                    0x00d28de5cd21b0db,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8989b0a90f462b04,
+                    0x842ccb20e916bd94,
+                    0x637cd73eeae5d91e,
+                    0x9c909a3fe7a50357,
+                    0x3a9a784cdd5d7f75,
+                    0xff71c94fdf43de7c,
+                    0x90c1655021425641,
+                    0x72413dccd5bc7a29,
+                    0xdb63e234286701e1,
+                    0xd7f9e3c870b0d46e,
+                    0x6d2fbe9cf0363dae,
+                    0x00a4e91e46d91137,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a3f7450da6fd29f,
+                    0x079161eb8cb8dc3a,
+                    0x77f07286209408af,
+                    0x9bf76d834dcfcd6b,
+                    0xc412777a11bb4e1d,
+                    0x00705dbda79cf4fb,
+                    0x7c91fe1b2992df61,
+                    0x8d5a17767b0ca4c0,
+                    0x6f0a99dde68e4d57,
+                    0xae189616ca626626,
+                    0xbc06685d4bc2c1ad,
+                    0x0061d2e9d49fa764,
+                ])),
+                Felt::new(BigInteger768([
+                    0x931b71d6413a79da,
+                    0x65a4a7653e89d63f,
+                    0x36215c768a7407de,
+                    0x6fc3c85832ac5b77,
+                    0x506fddc702ef2d1b,
+                    0x36b4cede97ae6ff7,
+                    0x56c4bbf3a6e664ca,
+                    0x5f846e45e1a765c9,
+                    0x43e3c82489311c28,
+                    0xdda916987d256a21,
+                    0x5f1c549f60e6a16f,
+                    0x006d9ad3d890a436,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82d0c896305d03cb,
+                    0x0cce64a44dcd6086,
+                    0x35d4a177b2970dde,
+                    0x1c87731af36af5f2,
+                    0x22db98ac5de9a095,
+                    0x126d17d047ffdf0b,
+                    0xf72505415e8488d9,
+                    0xbb8d8dba884ed653,
+                    0xa8b3dea94e11b8f2,
+                    0xba029145a739c797,
+                    0x7afda53657145675,
+                    0x01148dce4351ff25,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3168c2a16b41e4ff,
+                    0x845e69356399e0ae,
+                    0x54b4e6e4733e68c7,
+                    0x5309e34544ba0e71,
+                    0xc252488623f20f7b,
+                    0x81733aa55909c308,
+                    0x940ac331d22cce0f,
+                    0x1809548dba995463,
+                    0x55e75db3ba3c148f,
+                    0x93008167f7b4d903,
+                    0xeb3eed96552bc835,
+                    0x008ad6636f64a2e0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xada881b48fdf33fb,
+                    0x3b525298cabe0dff,
+                    0x85b5e07e4006f5a8,
+                    0x23ee6ff9759fc57e,
+                    0x4e96c008caab3529,
+                    0x5a283905df3a1bea,
+                    0x4253324998d659a8,
+                    0xc9809c2f8178ccac,
+                    0xeb64bb2d25ac34de,
+                    0x52aaa8ea583092eb,
+                    0xd9bb7413bc0b2565,
+                    0x00a3bc142967b66c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x67667a7214c15652,
+                    0x04cc2cb25296a705,
+                    0xf53da161b377315d,
+                    0xa3009ab186c96391,
+                    0x9f65029139c1ca62,
+                    0xe1c4c9668bfc5725,
+                    0x72183e7c6af40a3f,
+                    0x2b04d5857d47f69d,
+                    0x210a887c45bfb7bf,
+                    0x7e7584b39a29c0d5,
+                    0x7534b805f6efa4a7,
+                    0x00a9ba33446ea448,
+                ])),
+                Felt::new(BigInteger768([
+                    0xefd3ee3b679402ef,
+                    0x1fcf246129ef4273,
+                    0x867220aa4ded9763,
+                    0x34d4be11ebefea34,
+                    0xc5c3c2f3f764ff07,
+                    0xef93800185f94793,
+                    0xc8af72bb2ce6a1fd,
+                    0x21165eb7e685785d,
+                    0xad96f5ae9faa76a2,
+                    0xbd2568d80b801fa8,
+                    0xeb481cd03403702e,
+                    0x0084637c6659a921,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8cf7148666d08c66,
+                    0x6f83685a64b2f505,
+                    0x03b45595739dc569,
+                    0x943b3fc0ab5bb6d3,
+                    0x8c26e3904bc685c3,
+                    0x5b036610e2931d16,
+                    0x958be8eff3fbf78a,
+                    0xf3f033d26d68ba85,
+                    0x2e2c048937eb34b3,
+                    0xd7f304d662ede3b2,
+                    0xf9ccfd7b2df9aa25,
+                    0x00ef2125e8e6bced,
+                ])),
+                Felt::new(BigInteger768([
+                    0x906b79c17e8ac619,
+                    0xa7b706ba9671d5d8,
+                    0x4c98239fd5f923d0,
+                    0xca5979d3903af9b2,
+                    0x312828c00a7bcc61,
+                    0x67182f26c04acbe0,
+                    0xa85a14b4fd32763f,
+                    0x7dbfdb58c82eb179,
+                    0x9286a1000d0bb129,
+                    0xb1e2ad49ac6f2c78,
+                    0x59b66963936e4ecb,
+                    0x00b3245b9265f86a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeffda02277acb6f2,
+                    0x57ad6efd51392397,
+                    0x5ce3cd99adf4791c,
+                    0x6611a56b8506d705,
+                    0x1d2a200b0403de25,
+                    0x8e1c0a65d780b379,
+                    0x5a99a91952eb8fc6,
+                    0xe54c0d88d36a850e,
+                    0x022744cff35dbf36,
+                    0xb75d6fcf7309d120,
+                    0xa86e6ad19349b5db,
+                    0x0068d1f26817767d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58a8211b71871bab,
+                    0x0caaaf3367871f61,
+                    0x6c0a048e946eec9c,
+                    0xc7df69e17d712e3b,
+                    0xc04cf73f1d6cda40,
+                    0x90b9553568a82078,
+                    0xdf7ccbc75e185794,
+                    0x31f73ecfcd987f90,
+                    0x64df99952b4a2b53,
+                    0x62698610fa6922da,
+                    0x3925bc0a57b67054,
+                    0x00a1bde2cddfbcad,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5f207ca8e3931ea0,
+                    0x3d483ae871f3b7cb,
+                    0x2f5476461f0c2241,
+                    0x0a0fa03f1e04e107,
+                    0x9638398f721337b2,
+                    0xb013b6f64c530c0e,
+                    0x7eb1bb39254d0c03,
+                    0x8889e2aab1496d88,
+                    0xa8c24bab235929c2,
+                    0x6953236e9a59fc24,
+                    0xac19ab13fa619cdd,
+                    0x007d7821268ec125,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22ac35789d7e4ac0,
+                    0xf991a11d52e98db8,
+                    0x845df22ab8b346ed,
+                    0x89a96ad2b047cd71,
+                    0x9eb06c59e47c8ab8,
+                    0x9792c6809ed1943a,
+                    0x7c3cecb074af3105,
+                    0x6742dc8baffc44cb,
+                    0x2f3d75841e04fc5e,
+                    0x3a79caf37f183a54,
+                    0x69e5185c7f551d9a,
+                    0x00696622728bb5f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xecb95c0b576a1c20,
+                    0xdac74dfe114b11e8,
+                    0x84688e540c1efe77,
+                    0x6dccd3e255161fb3,
+                    0x8069527b43820abc,
+                    0xcca6449398d2085a,
+                    0xebe827f1a5aa0968,
+                    0x02a7fb6db6c98b9a,
+                    0x10570b63c24532ed,
+                    0xf818bbb7cf556bd0,
+                    0x1c70c6edb3b2f0e7,
+                    0x0002a17756471b4d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x24c115d6a00bc7d1,
+                    0xc4791ba49dcc1859,
+                    0xdfc52dc600a513b5,
+                    0x30f33be23be49ece,
+                    0x4934ec3807125001,
+                    0xaa91a3d5fee1b2e3,
+                    0x52670b94666b8b11,
+                    0x23e25034854b9e9e,
+                    0xa7e1863447745306,
+                    0x1e1c81757b31830c,
+                    0x69f7246dd0870280,
+                    0x00daddc401a9d379,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1801f7e2552a1a09,
+                    0xa68c66e8863dc557,
+                    0x147ca404e3e4bc93,
+                    0x5ca12e67122b680d,
+                    0x2aee5b0d9edb833f,
+                    0x1d8d7c9194acd7df,
+                    0x79bd412ea1d0f221,
+                    0x7bfffcad69bd815f,
+                    0xdfd0f09aa5914049,
+                    0xf3b82a7c2d845298,
+                    0xe447de05865d03e2,
+                    0x004cba5926aebe09,
+                ])),
+                Felt::new(BigInteger768([
+                    0x96aea9508a20de36,
+                    0x1b10f6937da6a9c6,
+                    0xd0d4761985992fb0,
+                    0xe3df3e7c0c387baf,
+                    0x9e297e31d9221d9b,
+                    0xdd1118ad54750e28,
+                    0x98cd387865d5fb36,
+                    0x059fc5160569bae6,
+                    0x673b7a441d1f78d3,
+                    0x86cc9eee4290c1e7,
+                    0xf040c4fcc321cb4d,
+                    0x002ee431efe33a98,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x35739e1b66c220a8,
+                    0x40269b09f89e3a82,
+                    0x3230ceeca79ec45f,
+                    0xb037340d1cb7ef21,
+                    0xe39d73527c3e92a4,
+                    0xce2ed0f1539f5d7f,
+                    0x298f06d903c2a26b,
+                    0xe9d55d8afd2cdfe1,
+                    0xcaece541410b98c5,
+                    0x1b23584485b05dee,
+                    0xdddf476d785fe930,
+                    0x000f13afca48535a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ef2050ee63b583f,
+                    0x3d75a87bb40a9fc3,
+                    0x7fec288a25afe460,
+                    0x401e62dc9ac8c40d,
+                    0x5da149603fa9332f,
+                    0xa38d7cdcd6afbb9d,
+                    0xf3f28a370f216b7a,
+                    0x800a8108446a348f,
+                    0x1798058bdeeb8a3d,
+                    0x6d2fb6cd5cb0b625,
+                    0x148c48ee74a7a1f5,
+                    0x00f5140a1813c3e7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8159516ce72b5ed4,
+                    0xa8603926ced0d867,
+                    0x3f044bbc52986d1b,
+                    0x28c6f9f9f647e290,
+                    0xabc70f71674e820a,
+                    0x1e94ea8b05828053,
+                    0xae7bcea7df247b48,
+                    0x7f29ffb8613660c5,
+                    0x6c9699b2d2d9aa5b,
+                    0xb387cb47b12794ce,
+                    0xca0bf72f6ab34200,
+                    0x006b109a61a8e76e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9de4e3c86a9abeb7,
+                    0x39942e9e1e3bbf48,
+                    0xfd4c8fa469e9d128,
+                    0xd91ae7d99ae286eb,
+                    0x65e1e5d0a5d37414,
+                    0x796c219db3679487,
+                    0x83879e6def377b53,
+                    0x8dbcb4793b889c78,
+                    0x82563f9d79bb4470,
+                    0x26aa8c3bcc930804,
+                    0x5de213ece5c057c1,
+                    0x00914a9919fe4d3e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8dc8d992efb70432,
+                    0x4db476a3158661cf,
+                    0xef42784703f88a17,
+                    0xa0c2b55874740c86,
+                    0x193bdc83a2165cf6,
+                    0xac601d098a5f674c,
+                    0x5539c7daa1e47322,
+                    0xab039e4be6f98722,
+                    0x079431e7e6ab7a3f,
+                    0x3abe2f2597f0c9f1,
+                    0x4018eb15f5b6679a,
+                    0x0055033723af1bc9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x70455717ac43bab0,
+                    0x2a3908b5aecc7856,
+                    0x4dc834e4cdc2bcdc,
+                    0xfd0c5ce2f6978e0c,
+                    0xfbff183223cee74b,
+                    0x3c604595c1555d98,
+                    0x2acb154ecb4547cb,
+                    0xc368bea9bc83f0a4,
+                    0x176f9696138cf108,
+                    0xd63d851c9c3b6b2c,
+                    0x9f0345c4738dbe34,
+                    0x010b90a096d76540,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x40b02a5e115e73e5,
+                    0xd4ff92631981f2d5,
+                    0xeed3ab6fc73688a9,
+                    0x763a2501bf0a7337,
+                    0xe5fe137b19afb0ff,
+                    0x6f4b7bf0e7a0b8b8,
+                    0x59e871e2613c2da6,
+                    0xf2736aa882bc72a3,
+                    0xd3d467591ef7637e,
+                    0x90169a96702bde9d,
+                    0x1353651446f424e2,
+                    0x0058f03b42605471,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7659c79666584612,
+                    0xe81c472c8e22dd48,
+                    0x813165ec59040abd,
+                    0xa34e50f8ce1dc2e5,
+                    0x120081154efe6446,
+                    0xd0ac03eb621db484,
+                    0xded0706abc8a3250,
+                    0x19a0ab8ffba98240,
+                    0xf1f639e28c057ecb,
+                    0xf3b6ae8ecbd518d7,
+                    0x34246b850ce8b5e7,
+                    0x0079980e05963323,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe7c0a62fc83243d5,
+                    0x15420ec86055f73d,
+                    0xe01532f18e17f746,
+                    0xcedf9ae3e38f05e9,
+                    0xe4d0330716605e93,
+                    0x0deb611aaf81e22c,
+                    0x3de970987cff478e,
+                    0x839172d0ba4364c1,
+                    0x763b879d2de337af,
+                    0x3b8683a7a43f9f62,
+                    0x4cb57f1448b1857a,
+                    0x001655ecab1d0882,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf04846cd031c8cc5,
+                    0xe7a0526d39fbffdf,
+                    0x7cdd26dd8598815c,
+                    0x528f3bc652a64fae,
+                    0x21b47186eaf3d1d8,
+                    0x1b03f64d4db6c4d6,
+                    0x8f20a226e9c0af03,
+                    0xeda56fdc80b73eb1,
+                    0x0237c3284e418d02,
+                    0xcd8dca4eef9e0047,
+                    0xce14922fc8e181ad,
+                    0x00d80dfb68c41d02,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2b6b4bbfdd9f1f43,
+                    0x418ba6262cc63757,
+                    0xa0973e0fbc14ebdf,
+                    0x145d7e6809e7f2e0,
+                    0x163cc333aecfa2e7,
+                    0x11b60e1f74d2fb44,
+                    0x698e9282b43b6bc1,
+                    0xe8e180be2cac1e96,
+                    0x5f4ee4f1deebe841,
+                    0x35e75fd278f0ec81,
+                    0x3aab63899591c7df,
+                    0x00522ee690777fc4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbaa4f4b99ddb4120,
+                    0x622daf8670d9cdd0,
+                    0xc7582c04015da808,
+                    0x417f7111f86fdd39,
+                    0x8d3335440c1e15f2,
+                    0x2ad34028bc909ea6,
+                    0x67d1e2aa26cb3700,
+                    0xecdf7c2ea4de3404,
+                    0xab46d446372554e5,
+                    0xd00e2adb90a85ace,
+                    0xe826edcf4281d877,
+                    0x012173cc06d241cb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa6cbfa17bdcbef5c,
+                    0x86cf2ffaf498fdd8,
+                    0x90a40a65157b54c7,
+                    0xdc22b7ffc6f42bae,
+                    0x86b6d3207653cc3e,
+                    0x39a8dfc6386c444c,
+                    0x93f0cd5a2a79eb88,
+                    0x7c41ae92e92a409b,
+                    0x82073976fb0c3169,
+                    0xda43eb1205d385ea,
+                    0x54d106ac5bed5eb8,
+                    0x005c7714883855b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x85ef340f1d4abf63,
+                    0x3b977d9a5990b514,
+                    0x53fe5f643853adf9,
+                    0x355a1e4169f8a421,
+                    0x0b722e2b1272fa27,
+                    0x5545d63c43cc74e6,
+                    0x206a6fddb05c5b37,
+                    0xd4332a45c8362c1b,
+                    0x1b99e9b50ef09bfb,
+                    0xd054a800bc6c07e5,
+                    0x1f0a77d095f5d79d,
+                    0x004c3abb05f5848a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xca8cdcd4964eed8a,
+                    0xf4e315319ba8fc21,
+                    0x40e59b707a76cfff,
+                    0xe4b5f633950303bb,
+                    0x7f2d169f8f4d14e3,
+                    0xbcbb11471d388f66,
+                    0x14f75bfb8129a1f8,
+                    0x368eb8e15a98a207,
+                    0x745741048079d4fd,
+                    0x028d86b9ac7dd9c8,
+                    0x7da569ed8f8c48e6,
+                    0x0013ff00d063cf58,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe21b6abdb1e28841,
+                    0x34608f15e55c3d85,
+                    0x6e9ebaeb43060fa4,
+                    0xe380217f47ffd1f5,
+                    0x745561aca56de4c5,
+                    0xf45059b93a3110e5,
+                    0x37e7c27d96148ab1,
+                    0xdc50ee999f470356,
+                    0x03942885129ada8b,
+                    0x967b3db7564aaee6,
+                    0xe5c69e134c49b8b3,
+                    0x00684455c24cc006,
+                ])),
+                Felt::new(BigInteger768([
+                    0x184af6b9489c767e,
+                    0x82f1a56e8d71902a,
+                    0xfce3f9674ec4c915,
+                    0xe4c99246e9033cd1,
+                    0x9ad197a1cc021f4e,
+                    0x844084c6ce4013c5,
+                    0x7369032c44f41467,
+                    0x50dd33165803f580,
+                    0x4c13889a181fd19d,
+                    0x63bd7f5e83562c05,
+                    0x482b605f562e72a6,
+                    0x001e5b1e019f58f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x564928c4f55cffb2,
+                    0xb3edae0d35b9b21a,
+                    0x528b713c421c5f9a,
+                    0x9e5d5a43a7d08f2e,
+                    0x0a0fbb1f007d8638,
+                    0xc4e2cd745f577ca6,
+                    0xe7be2bfe6a9893b4,
+                    0xf584ecf8e1b87448,
+                    0xb373f863a00d1a44,
+                    0xa4f1d3ae76566221,
+                    0xc7d3ebb415547d5d,
+                    0x0040d893126b9ad5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc9bf06a0f1ba0edd,
+                    0xe0590f715e9293e2,
+                    0xcf58e6dd473e93f0,
+                    0xb3732943f0e00d83,
+                    0x014f46cfd803f936,
+                    0xf7bfcb9c451f56e4,
+                    0x20894eb8aae22daa,
+                    0xcc7c5f457edcbfa0,
+                    0x68e9ae6492a13c3a,
+                    0x88b583d1c1417e32,
+                    0xd5c76aabc17d02d5,
+                    0x003954084043db93,
+                ])),
+                Felt::new(BigInteger768([
+                    0x52c40d034e82a2ab,
+                    0xa3b0eb2e7010564d,
+                    0x572c81c496f9d207,
+                    0xd4633cdfd299b403,
+                    0x3b0bf7b44854aeba,
+                    0xe2fd6eb346da5450,
+                    0xaa5722fa388a917d,
+                    0x7a28e0bda1c80038,
+                    0xd9dbecbc36457b44,
+                    0x5cf5c4ed45baaccf,
+                    0x63a448c33558262d,
+                    0x01005ffbe5a7df14,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9aef481738da3d09,
+                    0x4ef404d392fd3b2a,
+                    0xf8b4feefe66b7c7a,
+                    0x95cfe049468cbe31,
+                    0xf9ffc7c08b3f6d28,
+                    0xcd1e898632a326f4,
+                    0x982cc3b5ba051451,
+                    0x66f84b8851df1d6f,
+                    0xfcd244ea2afc17e8,
+                    0xaf76fed9e2f693b5,
+                    0x6598ba4ac2e6da94,
+                    0x00d47256bbd45425,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdd69f5f3ac31b4e5,
+                    0xa2937aba462286c2,
+                    0x42283ec30bd7cb6d,
+                    0x9c4ac151d17b961b,
+                    0xc8beeba7f740b2bb,
+                    0xc70e45a6cfe3baef,
+                    0xd58809e2b9627af9,
+                    0x992a6ba47a2bb45e,
+                    0x15dbde2e0ea0e834,
+                    0xfa377016de5f4224,
+                    0x4574bd62830dad83,
+                    0x0013c378909d7197,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc10c78993a183d4a,
+                    0x1c83ec7b43efcdb3,
+                    0x17474483b39222c3,
+                    0x51550272113e587c,
+                    0xd85ac6da3cc76e2a,
+                    0x83e7a52ad91e2aff,
+                    0x00f909fade42b91b,
+                    0x264aa592c2588a55,
+                    0x654dcf584693dd45,
+                    0xd7d7c3fca95131e2,
+                    0xf49872f92d934cf9,
+                    0x00c6f7115b67cbc3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd0b032bd28ce22d4,
+                    0x2e1f6fd9339d98bc,
+                    0x60a096bca2491a5e,
+                    0x634ee538adcc3c61,
+                    0xccc33494c8812cb3,
+                    0x4b9b8b95019cf3a5,
+                    0x01a05500e03b0057,
+                    0x4fc5ef29aaae09e8,
+                    0x5fc1f82192282f27,
+                    0xf2641f33ece75934,
+                    0xc6de577a4c2a9123,
+                    0x0064578e1dd40ea7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x90c93bf00853261d,
+                    0x8b73ce2498acc3fa,
+                    0xc588d7689a8338d8,
+                    0x0181c740e54cbcb8,
+                    0x4e5ef7abd3ea166a,
+                    0x7493aa35a7a545ac,
+                    0x4b1e581414a8e69e,
+                    0xf30b6a4dbe80faa1,
+                    0x192e6751d0c1ce94,
+                    0xdde192f0bf500d5e,
+                    0x9a17fe9bcf2dbdc0,
+                    0x0066c588f039339d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa98ccb19830bc892,
+                    0xf55c5985a25bc89c,
+                    0x26de074cd4ba8e1a,
+                    0x6bba70d6c9a7fbc5,
+                    0x7db77eb87dbeca86,
+                    0x4339500484392437,
+                    0xbc4d787c35b1d3ff,
+                    0x09d45fea88647764,
+                    0x3081cb10b84a0577,
+                    0x08a874c982a32c96,
+                    0x4fe398d992edbef6,
+                    0x0048a28dbc5273ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x49c446f18efe8537,
+                    0x714ac05be82093a6,
+                    0xbcd25a670d20c234,
+                    0xe1c426acc09bd988,
+                    0xfe6e53d1499f4756,
+                    0xa13fe164d213eb38,
+                    0x861cea8b6f33b92e,
+                    0xe230c91a7e49320e,
+                    0xf7d26ce7c1a21a72,
+                    0xf428bb5c40158b84,
+                    0xd860363436b31635,
+                    0x00fc659c204bb282,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe964d32f675a4c61,
+                    0x6f052eb06fa67a77,
+                    0x331551f194aa9558,
+                    0xa7f43611219fa75a,
+                    0xcff7debfba95781b,
+                    0xb9f05d4a9b59f88e,
+                    0x11ea0a9e701dfaff,
+                    0xf4dd04e5aae66176,
+                    0x16c00b9d82f6b365,
+                    0x6454ef9d90fc794b,
+                    0x8a34adc8c54fb285,
+                    0x00d639836c2abc37,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc7a061b214d5872a,
+                    0x9c1fa2a5f16dc305,
+                    0xb4d8dff3e49b5645,
+                    0xcbb2ed92bacc9f81,
+                    0xf08f4d445b179d83,
+                    0x573abe397a97971c,
+                    0xf9d3800acd2c5dd8,
+                    0xdd14a085c64c81cd,
+                    0x13673b5e1b07e82c,
+                    0x852aad1a196e0de6,
+                    0xe0de3b04efcd80fe,
+                    0x00349e1e4194ccb3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e14dc590725ce80,
+                    0x6ee5fc82891f5bd8,
+                    0x991db312d8fceaa0,
+                    0xb3a890ad647e5c3d,
+                    0x9fc8bd141df6897f,
+                    0x5c4ded76b9ec6d15,
+                    0xcc0e0f3e006a7c62,
+                    0x2ec6ea616ae902d5,
+                    0x670ceed37c789621,
+                    0xf4693db7b0c7a0b8,
+                    0x71839b43c69b4693,
+                    0x0085b3317760363a,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xec4a5f83f379fc43,
+                    0x30fb67965471909f,
+                    0xc38655b0de2a3936,
+                    0xedc17f4ec524311a,
+                    0xf30a43a56a280329,
+                    0xf6090a7f3de4ca2e,
+                    0xc41961e2f069bb71,
+                    0xc43487277aaee2a4,
+                    0xb15551762d7bad0d,
+                    0x097faa7f23367225,
+                    0xa2d40b0cb70a4c03,
+                    0x000e9f003184688b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd18deadc84885847,
+                    0x32738c7870d1b79b,
+                    0x2705f89ec0f2670e,
+                    0xb70f0f9d125c9b5f,
+                    0x91034332f714501e,
+                    0xc77c67262442458c,
+                    0xa5396a325e0942a5,
+                    0xc23463c8ecca15b3,
+                    0xe6da45dacc3baf20,
+                    0x3dae663b13532e52,
+                    0x31201f78ea637fff,
+                    0x0044e4d3e34188ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfa595a68e9673929,
+                    0xd822afd1b72b8353,
+                    0x8eebd193ade58c33,
+                    0x74e94898732645af,
+                    0x07af1d670b420645,
+                    0xca0c7e6e501530be,
+                    0x7ffd5c90b005e66f,
+                    0xa40834e03aac0044,
+                    0xe4a6c7ea609ef55c,
+                    0x2071e6650be698b5,
+                    0x3f6bffa48840c42a,
+                    0x00d7b0edb7860b0d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x81390a1e9e202d2c,
+                    0x0210695a5202399e,
+                    0x92214f2050d7667d,
+                    0x2e8a595c5c1cbf19,
+                    0xa0ddfed031513bca,
+                    0xb3517706b68cadb4,
+                    0x149ae08a319bc931,
+                    0x228f080d4a2d2ae4,
+                    0x300cd2dbb4a438ae,
+                    0xd8a673810ed55e98,
+                    0x213dcb852acf061e,
+                    0x00500a467ddb7819,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82b24499563611b5,
+                    0x12893a33fc0b8b32,
+                    0x18f5163b551d6ab7,
+                    0xfb486daaf472fc60,
+                    0xcdc02ef54ae20074,
+                    0x89946889aa5fd44c,
+                    0x1f8191abb828b558,
+                    0x16f6ddf91494163a,
+                    0x6a23841022ddfe5e,
+                    0x5bc0e791ae66116e,
+                    0x45b7c02327cd2f24,
+                    0x003b019f2e1e2614,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6abe1c0fcdd81811,
+                    0x14809c24045dc666,
+                    0x598763f8b08d2f6f,
+                    0x2b5352a5737e7c41,
+                    0x9eb037822513fee0,
+                    0x0ea46312577f0f11,
+                    0x16d79fed3037dc91,
+                    0x830cec35e90002ca,
+                    0x4bd35d18b0cd9257,
+                    0x7db0e9687d5ddb9b,
+                    0x913024ab1a3fff52,
+                    0x00b85dc95527acdf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xab16340fb0832391,
+                    0x120888f3401f4255,
+                    0x6d13d55d321504e1,
+                    0x16192376aa2f85c4,
+                    0x6860f2613d6a6c80,
+                    0x755933e1510f64c2,
+                    0x350c4f2f5a009142,
+                    0xd606c01386cfc4be,
+                    0x79bf9bbbcd5ac5be,
+                    0x2204d58a094d7f7e,
+                    0x795e99b2618d57e9,
+                    0x00a2a34824eef211,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfa70866cfaa1a37e,
+                    0x74dc8e018585fd72,
+                    0x237a323a9d417389,
+                    0x2baaa98a00ac73e7,
+                    0x7614e11d669dd653,
+                    0xb3e85a2293cf3a46,
+                    0xf089dcc70f9ff84b,
+                    0x899df1813790e496,
+                    0x6b6b1ec0921c0d4c,
+                    0x8d39d221c939a075,
+                    0xd1c1f04f387c861c,
+                    0x011b7a91a797ffcf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe8bd89cbf73ab76b,
+                    0xaab00a9585345f41,
+                    0xdcc31f13236bc355,
+                    0x1674ace79a8cfad9,
+                    0x78c0eec3029cc702,
+                    0xcc6c4c80ae1e4ac3,
+                    0x51e8ce780a554bbf,
+                    0x2e4269ca2950c04a,
+                    0x1334e0efaed04437,
+                    0x8e1972def75a5d9c,
+                    0x66e6d1186aeaffea,
+                    0x000eb387c4ce10e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc3f2a1c4dc1874e8,
+                    0x6a1e8dc8c500db5f,
+                    0xf0623f2f14bf1f21,
+                    0x23cec2a427df5d36,
+                    0x613f160ba0d17782,
+                    0xc4826c654f8b68d0,
+                    0x30caf7cbaac806d5,
+                    0x999f611aba966189,
+                    0x7c292460343a2438,
+                    0x82d66be9ee5e8b6f,
+                    0xa418407062801971,
+                    0x007549211ec00bd7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb6dd5605ee81a60c,
+                    0x53cca2d7410a7da1,
+                    0x34d44049e726b656,
+                    0x41bc17104453e51a,
+                    0xa0d6a06b235f4791,
+                    0xb6729abd1092c67b,
+                    0x04570faf059d95d9,
+                    0x72ef1d1fa4e243f0,
+                    0xc9d44d1ae6a50ff4,
+                    0xbf3a9b991d94f557,
+                    0xaace2310794aff32,
+                    0x009d08f46ba694d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c7666972e498c34,
+                    0x82f7c194b281cec9,
+                    0xb7dc1474fc4e28aa,
+                    0xd81361f90d8a846e,
+                    0x7f7a7ecffb5189cc,
+                    0xb00c3cdfc3d116a7,
+                    0xfcd6f933b84b3ca6,
+                    0xd6a3710111be8444,
+                    0x4ef767c0dc7efb53,
+                    0x16462122b1cdd97c,
+                    0x37d3d77cab6d4468,
+                    0x010433ab5731b7b3,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xdd8c83a6552dbda2,
+                    0xafaa8f63aaf01b8b,
+                    0x182d1ac50099b164,
+                    0xcb231a3194ed1a74,
+                    0xc578b4f3c155e4da,
+                    0x2bf929b7259e1cf7,
+                    0x355bbdf668c8afcd,
+                    0xeaba7b35db5d79ef,
+                    0xb23f1aa11f7285d4,
+                    0xbc046a121078c655,
+                    0x4a79956c558e4a8f,
+                    0x003ea9bcb543137b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb795d6c1169f85f5,
+                    0x3949fe2da60342bb,
+                    0x99b6692aec001c29,
+                    0x9e1b088c6b1ed6ea,
+                    0x6d8ea5e98586c2de,
+                    0xa7716c7671382c70,
+                    0x5514e84437f7d8fc,
+                    0x5b2a9c0f43b545bd,
+                    0x42b5ec04aa92284c,
+                    0x2c414fb705bdb942,
+                    0xd92bd9c95a4c3125,
+                    0x00f7a62497f417c4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb78f4a1b21e9ae0b,
+                    0xb5159621a975375e,
+                    0xdcf476d8abb6f32e,
+                    0xe2d2e70ceb8fb9cc,
+                    0x162ad02e70231838,
+                    0x61d9237ccaf74478,
+                    0x03ca51127dbcc4dc,
+                    0x851d8b34cb8b648b,
+                    0x04c4b1727d062513,
+                    0x230310949787c32f,
+                    0xcd997f22c68be54d,
+                    0x0005e157aa64b68d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d45173b2d083d29,
+                    0x00114083d0b124e1,
+                    0x8d55aa8fbfabcdbe,
+                    0x0384e4c841a12eb8,
+                    0xf97dbe577982cd2a,
+                    0x86d2f0ba1948ec38,
+                    0x2cb1edc701db4f6d,
+                    0x10a55ee7118bbb3d,
+                    0xaffa59c666cceb69,
+                    0x75f701b57edf1cde,
+                    0xa691821d01760350,
+                    0x005d29ff7931ef8d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd4272f9c8bba7388,
+                    0xb7b7c923790f0e4c,
+                    0xfaba61cb51c02000,
+                    0xc36f2651ee07e527,
+                    0x495413ba9219295c,
+                    0xb055c794a2a4d594,
+                    0x5d8afe75cfd7e2de,
+                    0x1574aeab59267eb6,
+                    0x45d94680989facf7,
+                    0x30ed5b78a53a8952,
+                    0xdea4bac13bb1b2de,
+                    0x005854361d19de1d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x100f2fc53f90b484,
+                    0x6394ac833a22c88b,
+                    0x8887ca2ef7f7a524,
+                    0x4528f70dcc475a3b,
+                    0xdb405a04af2a4e2c,
+                    0xae004262f7c74a09,
+                    0x64145df94f49d9c9,
+                    0xd43ff22d1352c6b0,
+                    0xfc58aceccc3ec391,
+                    0xa024acb3425dcbaf,
+                    0x7249fdb5e0da8d6b,
+                    0x003a10e97d33fb95,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa6b727dd5cfdbae,
+                    0xa247da190ebcaf96,
+                    0x03214095cf46d812,
+                    0x758f0f8d1915d99e,
+                    0x3bf96f88953d45c7,
+                    0x0a444c942756a933,
+                    0x853c60e1c3ed4a59,
+                    0xd734b362f1bbe752,
+                    0xffeb719d46ae0fa9,
+                    0x9d58af44f1eab437,
+                    0xddae9897f330aab7,
+                    0x00371a5492e0dd74,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb95e4782e61777ac,
+                    0x36ec51c83a3f24cf,
+                    0x9e5fd2e349d820b6,
+                    0xe440816ca01abc2e,
+                    0xf5d7ce3eecd720bd,
+                    0x92f563917cdda850,
+                    0xa345660f1caa29c5,
+                    0x30f50504155f8c5d,
+                    0xbc47694fc6ada9bc,
+                    0xcb0612ea695ef305,
+                    0x3df54129135b5012,
+                    0x0009bb596320744a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6096245b2b23b56e,
+                    0xda37c3bd36427f82,
+                    0xb4085f8af773a073,
+                    0x63511988eeaf3f64,
+                    0xea21cbab4b33de2e,
+                    0xb3eb51334f5a676e,
+                    0x993611791b83be15,
+                    0xcdba8f6d6d8107ee,
+                    0xcbc135dbf37dff16,
+                    0x0129b9d19185dd43,
+                    0x37b6544ed2984457,
+                    0x00ad80882db6ece7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x98d363c23e87fff2,
+                    0x899de38c343392cc,
+                    0x722e2051a48d8a0f,
+                    0x1520e78d411738f8,
+                    0x9e7074beeb22001e,
+                    0x96b8119ddb42aaa3,
+                    0x58a8f56c363cc9ab,
+                    0x5dcdbe7ffb77f47b,
+                    0x9c956187db086ed4,
+                    0x181901a171ba40be,
+                    0x17fd5cdd9fff6b61,
+                    0x00583fa3cae0d8d7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x56b420f4f81266f4,
+                    0x9ce6cbf51daf1fcd,
+                    0x4e7fad76df4eccc5,
+                    0x0a24b6297bee1274,
+                    0xcff6290a7613e65d,
+                    0x56fb3f9bac8b39a3,
+                    0x6f4a8b17a708e87c,
+                    0x3c0a2fddebac3714,
+                    0xc3dad5aa2928f92c,
+                    0x06dbca799025b2ea,
+                    0x7e8c948b49f4da19,
+                    0x010470432fc42755,
+                ])),
+                Felt::new(BigInteger768([
+                    0xafb8511f12d16380,
+                    0xded48b6a72fa10d9,
+                    0xb6d9a523e9f732f9,
+                    0xb5e231e09f3a4674,
+                    0x7249e2232a643399,
+                    0xdef043a211510c7c,
+                    0x8b73d73279a46cf3,
+                    0x32e516e70ed1e21b,
+                    0x81404b476e6bab99,
+                    0x866b61a4fe535176,
+                    0xb27ee41945d58199,
+                    0x0037f750c269b8e8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x26656cae031dda1d,
+                    0x60c35010f89942f6,
+                    0xc4d5cb535ad22e45,
+                    0xee07ab519005ca73,
+                    0xc540cb79067a4a30,
+                    0x9755e751d77226fb,
+                    0xd3f8a2a51016b944,
+                    0x6de14c7f3178cc11,
+                    0xfb3a68aa4078915a,
+                    0x4b2f6796d921ae5f,
+                    0x3066ee3b39dc7852,
+                    0x0098b517bd0e6663,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb16f9c565ca89e0d,
+                    0x2ff70457aacd0eff,
+                    0x57b58d40dd004398,
+                    0x2f807cdf48b8643f,
+                    0x5bc05e2418a51168,
+                    0x1d7891eb1dd161b0,
+                    0x9c04d84e7829df9a,
+                    0x6769cacd1466ebe1,
+                    0x62a6c79984247085,
+                    0xbad3c177d94e2c40,
+                    0xb6941a6a62a25f92,
+                    0x00919d35d5c319e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc30c7a55e3f273d6,
+                    0x013b0a59fd6d790e,
+                    0xc6a130a1f59d805c,
+                    0x6791e0e7d2dd172d,
+                    0x527330cd6fa2b697,
+                    0x4dbc8daf0fd3817c,
+                    0xfc1f38e606a4bc38,
+                    0x63b3d89123d56a5b,
+                    0x6cd44e931611557b,
+                    0x233f0f8104f1ca4d,
+                    0x39176e31fde1e5bd,
+                    0x0082e22bf109217b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c98144a3d9e4c1c,
+                    0x1ae2e9ed4b4c16c5,
+                    0xc03b836d9cd6d2e6,
+                    0x96ce092b57553da3,
+                    0x5f6cfe72cb5661e1,
+                    0xdcda5e997c8980e7,
+                    0xd52ac299f45c557e,
+                    0x6ad84161eab774a1,
+                    0x2ffa8ca9d4d77489,
+                    0x7fccc85e5e49e201,
+                    0xd778a868014b09ea,
+                    0x00bae1b69e1455d0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd0923e9caae36d23,
+                    0x0f297b861f3fd1a6,
+                    0x77339dc1e3cd7c09,
+                    0x67975c1698bb68a3,
+                    0xaabf400ce39f69cf,
+                    0x32e76ebfee6fadbb,
+                    0x4cca0adeace1a312,
+                    0x3340ca508795fc37,
+                    0x135516072de13274,
+                    0xa368c45a43c3ac2c,
+                    0x3237aa55215a94cd,
+                    0x010b5f4f54b799f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd3a106e7873af274,
+                    0xcbb960352b3ea65d,
+                    0x549508ee97f45db9,
+                    0xc42daaf5c6e97dbd,
+                    0x8c5fab50ce5c3453,
+                    0xfc4cc70335357632,
+                    0xea71b6cf2ef94190,
+                    0xa1b8ab644ce5f6c9,
+                    0xe26e17dfed56f01f,
+                    0xe696d3797755bc92,
+                    0xe18ae7b792e82734,
+                    0x00da349ef855e727,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2727bbccabfe7d2,
+                    0xd07c504c42346542,
+                    0x393ad63b80f6c6c8,
+                    0x71cab0908f8c72a3,
+                    0x54699b562eba34d9,
+                    0x90e742a28347d5bb,
+                    0x77e15d67d0944c57,
+                    0xf973c9a9ba23d151,
+                    0xf1729ed55d0fcc6c,
+                    0x00e0a9f0f490b9bc,
+                    0xe3d0b94bda6185ac,
+                    0x0025790591691975,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f68e4c9350987ad,
+                    0x9ba0cb687e598054,
+                    0x4fb1dcb48b542eef,
+                    0x58114419df661b59,
+                    0x52aeabed3aa6c498,
+                    0xd38a426bb4979fb7,
+                    0x0620759061f90b62,
+                    0xf747753bb51112b8,
+                    0x33c2642f6afd26f3,
+                    0x5075ad5ed3a57d2f,
+                    0xd288e63400d507f2,
+                    0x011b8fc6ba9ef696,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f7a5aba315ec0a6,
+                    0xccf8392606baec40,
+                    0xfcc5a826ef74e3ea,
+                    0xffda0ce4b493cc42,
+                    0xf000956e8e3c1074,
+                    0x420fbe229e83e893,
+                    0xe54a5f581b4ee1b3,
+                    0xf5589849aff47bd5,
+                    0x533e8f6e4c30b208,
+                    0xc93dd677bee291c1,
+                    0x978be2c548e06e80,
+                    0x00a707675760bad8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb3c3c0d56a541452,
+                    0x4679e8142f4c846f,
+                    0xad78702ec88be2ed,
+                    0x5dbab2ffdc4b175e,
+                    0x321efa80e71d2e20,
+                    0xcdc08d2e71857b34,
+                    0x8c5895bf163d7a14,
+                    0xf17b5903b5340a4a,
+                    0xf322022e2e3f365f,
+                    0x9e3cdf3117f56031,
+                    0xa6fab415b2c8ed2c,
+                    0x0016e0fc62d93305,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb041db3cd6268157,
+                    0x8bd9c61ed6398604,
+                    0xaae7a6765e805a72,
+                    0xdb293908978c98fa,
+                    0x7859c7676e6cc569,
+                    0x23703e2619f52cc4,
+                    0x51a12c1b2b5e2718,
+                    0x29aa52683e99a4b6,
+                    0xb16bc96478539d08,
+                    0xa35872a9e8f1cb12,
+                    0x9482e822c93aba83,
+                    0x0034b7682b5aee13,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc60a46d7f8f50d94,
+                    0x5296982f4b156bb6,
+                    0x9472d4257c995e1b,
+                    0xbe7bbb79353a7a3b,
+                    0xa557c992cfafa1f7,
+                    0x1582da3a3fcaf0a2,
+                    0x063e68963768ed3c,
+                    0xddf6e78d539e29e7,
+                    0x2e6f84226fabb345,
+                    0xbea75b61b18ae790,
+                    0x928905cb94372ae9,
+                    0x0057bc5f3effb624,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xcdb48e183d116c93,
+                    0x55368da48559ecf1,
+                    0x57c5197bf460158c,
+                    0xa8379b90334b4215,
+                    0x3f65ed5bbe79fa5e,
+                    0xaaf655ba8656e03f,
+                    0x0da5780043131148,
+                    0x0ef7de07fcb4d900,
+                    0xabbdd12870144e99,
+                    0xc1a4531e18b2ea77,
+                    0x93782dc64308c053,
+                    0x00420da4637dd687,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6ddcbd9aca1cff81,
+                    0xd933e2b6515bac63,
+                    0xb9e0ef019502b0e8,
+                    0x7d7ac260ef886f8a,
+                    0x446e72604347bfd1,
+                    0xf9575e2a562580c5,
+                    0xe091c92f4a3f68c2,
+                    0xe2ab328456dbdd60,
+                    0x0cee285c323d8300,
+                    0xfa798876b6f837fd,
+                    0x30a41dbb9b09c4a1,
+                    0x01091a72bea4518f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff4117ae330fc253,
+                    0x00efb94ded26ac01,
+                    0x72b1dfad3dab608b,
+                    0x5c400a902724b954,
+                    0xb804cdbe2506de9f,
+                    0x91b73b9d1819b88d,
+                    0x03ca2930da4a35f4,
+                    0x462dfb4aaa2f3318,
+                    0x8531f75039589092,
+                    0x2a7571861a1b7b6a,
+                    0xf6a7ac26b347e15e,
+                    0x0072e0f37bbabc0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc37eee00faf5d405,
+                    0xe300f9249e335fa3,
+                    0x769b5dc753a3bc5b,
+                    0x7e96bdf483bd151f,
+                    0x3f18bb67107f84c3,
+                    0xa91372c575768da5,
+                    0xb46901db97d6b337,
+                    0xdac2b57eebd4613c,
+                    0xf92d945b26138568,
+                    0xa896276bee90e577,
+                    0xd713ca4dad6ea8b0,
+                    0x0025bb419dea47f5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa996e172ad7fdc4d,
+                    0x4bfcfee6d6b794e2,
+                    0xa0343f5b67aee28e,
+                    0x8569c84b5699a6fb,
+                    0xda772965431f96b9,
+                    0xfdef443bf627c282,
+                    0xe3ead14d3e318912,
+                    0x71c007f12fc4db4c,
+                    0x96457c41b9b5f891,
+                    0x5b7137e13436aa2d,
+                    0x23729a255031d7e9,
+                    0x0053967d51add519,
+                ])),
+                Felt::new(BigInteger768([
+                    0x718901f5ee5b2610,
+                    0x2d6c1d0d9c504c17,
+                    0xf22ebdf0f307eb3a,
+                    0xc4b41d3f54373735,
+                    0x81741a8ca80a0d8d,
+                    0xd4f534c125b7c0e4,
+                    0xe5020aec7ead0d8b,
+                    0x933d16c79151617c,
+                    0xb95493c9bf4df094,
+                    0x0a02d10f06543a4e,
+                    0x6677cefcee8f6f38,
+                    0x0108c6072386370d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb8eb9b627e90afa8,
+                    0xe124a7017ec2e630,
+                    0x120d2b50901196ab,
+                    0x04e994d1574ff779,
+                    0xfe593cfe00f011df,
+                    0xedfbcd3e2533d82f,
+                    0xc8688ed31b7cffc4,
+                    0xf9ce78a3ac544064,
+                    0x421a1bf80f5f3bbe,
+                    0x3f27e3fea1f7f287,
+                    0xc571c4cec7b8941d,
+                    0x00b375b4c6685ffc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa350123bd02dd8bd,
+                    0x90aeed45c1dae961,
+                    0xf63c3aaf3549e978,
+                    0x8530e7694daf6e22,
+                    0xeb50b2a7f80ed5c7,
+                    0x054d86f1732f33de,
+                    0x734655657394aafe,
+                    0x00372412a95cdf98,
+                    0x3bb10bfb41796949,
+                    0x60949e10e40fe616,
+                    0x46335c0e9a3943d1,
+                    0x00ded569f4702611,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4fee7a4dfa7e00c,
+                    0xf33ebec313f2edcd,
+                    0x520a4718194c843e,
+                    0xd664242d717ec036,
+                    0x41638743cabbcaf5,
+                    0x89c5f7b960423fd0,
+                    0xb6558cb2f049c064,
+                    0x1b96d92c4f27c4b1,
+                    0x4a2b5eb2399d9961,
+                    0xf845a6c1d0188ad3,
+                    0xa025495a850588af,
+                    0x00de299f97bf4fa9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x88bc5fb56bfc8b3c,
+                    0x8d2095d148bd3a00,
+                    0x1bf7e956120e908e,
+                    0xd5cfd871b4fbc17b,
+                    0xfe68b59e830d8ce6,
+                    0xc73078464f804457,
+                    0xd0a9b8ed4de2d87b,
+                    0x60088815f8de8b24,
+                    0x89fd7bea8d4df3f4,
+                    0x8c8598faeae459da,
+                    0x8fb65f52cb616e65,
+                    0x00340eb4290467d7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9a1923e6aa24efa0,
+                    0x272473da09f3f4ad,
+                    0x139b6f8271a03766,
+                    0xea031b63a7ec8680,
+                    0xeff334533b3bba2c,
+                    0x2e561c5f07eb6d75,
+                    0x07299d48b0121b7f,
+                    0x7394ee032744dc80,
+                    0x4727a1c2a5beb5cc,
+                    0xb12a72462c2579f2,
+                    0x62388f254c8c17de,
+                    0x01085aba5ed81814,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd9b7a5a3d4998f84,
+                    0x967c21303afcf915,
+                    0xd7d90dc9d54473f1,
+                    0x5225c408325482ec,
+                    0xfc2e2804a20661b7,
+                    0x34e692ec2eb7231c,
+                    0x95b474fca271221b,
+                    0xbc5478ac299ef271,
+                    0x1dbbb3a224448b23,
+                    0x1b9e8d1c4c0cb472,
+                    0x9d649bc9f053a9fb,
+                    0x0027525ce9680c04,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x6ceb92cd8795b1d0,
+                    0xdf8f73cb13efd37c,
+                    0x38e681f25bd9cf73,
+                    0x1757084ec7decc33,
+                    0x8681bec47c3accf5,
+                    0xa9f1a593f3be6424,
+                    0x3d58a5d018287dff,
+                    0x9d7a0fdd596625d8,
+                    0xb4db74cb5b7bd4ab,
+                    0xfdcf28afd57e7d90,
+                    0x82c483fd137d33ad,
+                    0x0044107dc858a0f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f9753c60d1d6ea3,
+                    0x783aef1b51d2f77f,
+                    0x47333f449369568b,
+                    0x1d1a163b4fe3a8dc,
+                    0x46a88731b410e4c2,
+                    0x319799bed140302e,
+                    0x8ecb0a2e24482754,
+                    0x9aaaf0834fa769bd,
+                    0x53f234600cd5f431,
+                    0xbf9944bf8b4beb9d,
+                    0x5a98316065b68027,
+                    0x003172fa5d3d20fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x16abe453af69c5a9,
+                    0x1d65cd97dac8e59a,
+                    0x061e2d25d2934e51,
+                    0x5f302b11df7d62d5,
+                    0x350fd2d4f00b8b9d,
+                    0xbeb92ccc04371259,
+                    0xf1c05a48ad287484,
+                    0xc42a5e3972c9d38c,
+                    0xe221f7e9192f5edd,
+                    0x11df9d37ef4127fa,
+                    0x97c0fe31802b5f32,
+                    0x00397c4cf2477255,
+                ])),
+                Felt::new(BigInteger768([
+                    0x401417a49f4f692b,
+                    0x4462b28967aefb48,
+                    0xbfa901f7c4a81873,
+                    0x12c02692506c5cb7,
+                    0x7d1995f6bbd879b1,
+                    0xcae9fa09ce77a70b,
+                    0xc7880a84a31048fa,
+                    0xdecfad5de604538d,
+                    0xdc7178221f113987,
+                    0x5cd0c18baabcd2a5,
+                    0x6ec9a0cc067288ac,
+                    0x00c6edfd5fdd1ec8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x41c05f53f44c512b,
+                    0xe8fd0cf18bf1dae8,
+                    0xd943ed0c70361f25,
+                    0xa870a4c3ea19209f,
+                    0x9824766d70c4dec9,
+                    0xf7a5cf7eee82de07,
+                    0xa210a589762d860e,
+                    0xaf8d55f68cf79c49,
+                    0xa307d6cfa207cd1d,
+                    0xa8f80e87b6a25551,
+                    0x41b6127729a62a1c,
+                    0x005a668f046e2725,
+                ])),
+                Felt::new(BigInteger768([
+                    0x47b59966f912ad63,
+                    0x6e14b41ded1df687,
+                    0xb84ed50f3fde735c,
+                    0x138403a51ac57b3e,
+                    0xcb33dbc26b969dc9,
+                    0x4b751e99c6ec24a9,
+                    0xbb3955c9fd54dc7f,
+                    0x20faa6301f71a6e1,
+                    0x6f10a8172c49cb55,
+                    0x6fbcb88ccbbcae50,
+                    0x41e150fe4fbe6288,
+                    0x00098caab30dd6ae,
+                ])),
+                Felt::new(BigInteger768([
+                    0x166d4f62b52b6ce3,
+                    0x85c2f0ca9062b070,
+                    0xf2975cff07c5431f,
+                    0x00b71e500e7974a6,
+                    0x4d742d113b7a62d5,
+                    0x8cd4cb52fd851979,
+                    0x791f1f1dd2825a42,
+                    0x250872bee3523aaf,
+                    0x9863b6f5512e708e,
+                    0x9b38f278c3ab57cc,
+                    0x07b5c4fc9ffad28d,
+                    0x0045d6f45c711345,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcbfad49b11ea9ddb,
+                    0x98d1346abaa451ac,
+                    0xa95fd013ccfecb50,
+                    0x5543529c4172e3ef,
+                    0x846f228cfd2c20c1,
+                    0xfc648a061010d60b,
+                    0xb5904ef5616fef69,
+                    0xe43fc638704c8185,
+                    0x00d6e7b6a390e801,
+                    0x39a94acec7584bd9,
+                    0xdeee4d35ba059879,
+                    0x0046802f839ac5d0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2b1a2697693b728f,
+                    0x56d3463f8d325ec2,
+                    0x3abcfc769cc98562,
+                    0x58a291987ad053cd,
+                    0xa052a55430b98850,
+                    0x786f8404b88aaed8,
+                    0x3f1d824e0b5a7e72,
+                    0xae703356f9e73752,
+                    0x930fb970bd178831,
+                    0xf3cfd7f3761441e5,
+                    0x22869bf55ae528ed,
+                    0x00968bf4e4a74cba,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae3f74f39f0ca22f,
+                    0x03685a437fd68f0e,
+                    0x67295d3bb865c3d5,
+                    0xd0660370b4454f42,
+                    0x4673deaab617bd96,
+                    0xf2d918543cd440fa,
+                    0xe5779afe09583cea,
+                    0x69638dfb64dcd799,
+                    0x99b49c05aa5ca7f4,
+                    0x5c81029949771cf6,
+                    0xc6c93ffea5aa3b21,
+                    0x00bf281dd2ef9b6d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb11fc51a41bbb5b1,
+                    0x472406b11e78d964,
+                    0x164ca1d716b4a2ed,
+                    0xb6445689a3976f1d,
+                    0xf81000f54de37962,
+                    0x054205d785b89cbc,
+                    0xa4420b1df3bc7758,
+                    0x64038b4c36758890,
+                    0x07ed7e3aa8bc5cbf,
+                    0x938f701287afc09e,
+                    0x661155a748581b8e,
+                    0x00ab353217cb80f3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xec3f5e99d2047865,
+                    0x0a5b646832f5187d,
+                    0x1e02b8079b4867c3,
+                    0xe434216181b2aea3,
+                    0xeabd3023d4c7454c,
+                    0xe8ee370c49c046ca,
+                    0x0e4b2b1267fe6c72,
+                    0x2d39cf75c4a8c623,
+                    0x5264107831668024,
+                    0x8657cfd5fa5da12f,
+                    0xe330a8d9235f4253,
+                    0x00c739bdc96032ca,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xd573bea5583c4bb2,
+                    0x7f44f190e4934c6b,
+                    0x226b9d4744c7ce25,
+                    0x67618e1678ed7282,
+                    0xd1f43f72e0e2fc5a,
+                    0xd6722cb9a75e5c1a,
+                    0x0a09f2ebaa05c90c,
+                    0x5a0d6d877fd72328,
+                    0xca6a02981be28957,
+                    0x0debf87d5f055004,
+                    0xa2d0d8caadcd890d,
+                    0x00c3eb4f6e4ceef3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf803740d9a5a9331,
+                    0x0c84b11e3c93680c,
+                    0x3ba965f20e739b00,
+                    0xfe5a7ad558df8ee7,
+                    0xa4948b8e7fe5d347,
+                    0xf3a5fd000bd58376,
+                    0xca05a34ed5cf8354,
+                    0x6bc9e0befda989c7,
+                    0xe11878d6c5ee0043,
+                    0x0098705a1bc85563,
+                    0x2cf2dcfd5f629943,
+                    0x007831b2762e838b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b6b84e0a87d4e73,
+                    0x0ca3ed1dc21d01e2,
+                    0x5d505c6d8d1aa478,
+                    0x5171d3e647da47e3,
+                    0xd1e83f029d23a528,
+                    0x105667f5b63b5854,
+                    0x640467869dd6d72f,
+                    0xc13fe35bfa4129f3,
+                    0x240806a47db7d8ad,
+                    0xfffa99c2efb2f637,
+                    0x59216ed91685fcdd,
+                    0x00a96bdc5b57d26f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x918fc0cbf8861d01,
+                    0x2a462025a31063e6,
+                    0x058b53e1d0885fcf,
+                    0x1eb61ac74771a650,
+                    0xa54328080de3bc7f,
+                    0xba885dd1fe4cd8eb,
+                    0x8dab52fe9123418b,
+                    0xc7ff64ae9ce5aeff,
+                    0x4e9de7ff99c3f945,
+                    0x203d38dc5d21639f,
+                    0x8725602edb0ad102,
+                    0x00dd7099b7bbac6d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12e1ad16ce1a69cf,
+                    0xe9c79b5cf2e5abae,
+                    0x050994909d855c68,
+                    0x1352adf68294c016,
+                    0xde65592096672277,
+                    0x2691585aa5098bc2,
+                    0x44a4aa498914059d,
+                    0xcf87a3b03d69cea5,
+                    0xd83c2d3ff180e303,
+                    0x547a354018876169,
+                    0x36b261c57a58fef9,
+                    0x005843decf397727,
+                ])),
+                Felt::new(BigInteger768([
+                    0x55711e8f59ab50f1,
+                    0x9b0ce782853ba03b,
+                    0x9ba12e9df47ec9ee,
+                    0x103cbafe7e40e18d,
+                    0xc67381d45eeaec0f,
+                    0x6adb9419210b7ce2,
+                    0x8bdea5226ff4c0de,
+                    0x3fefa999a37d383f,
+                    0x088f82f2680cddb8,
+                    0xf6667038ca5f8c9b,
+                    0x471b3c6a7b2bc830,
+                    0x00ff672aa025a493,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9c6454d39caa79bf,
+                    0x81678d5b198da527,
+                    0xab7bf797b6d27f4e,
+                    0x281eaaadbfe2e67c,
+                    0x75c31686fed4a55a,
+                    0x30d90369d7bca8bb,
+                    0x5de875fdb65788dc,
+                    0x54a9a32a4e2439d9,
+                    0xf7030df72af69e69,
+                    0x86ae7e85d982b560,
+                    0x6bcf491ee9cd1b3f,
+                    0x00094fc463a03d8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5e6789ae0f77a0e3,
+                    0x009242ea6198cc68,
+                    0x7716d0bbf7d606f2,
+                    0x1bb0131c90318e86,
+                    0x0d7b2524103567b5,
+                    0xad1f6903719930ba,
+                    0x6070f153d5c58317,
+                    0x2ae6709584a7f4c8,
+                    0x713fc3eb1057c290,
+                    0x10dea21a7df949da,
+                    0x3cac7be0207fac02,
+                    0x003aa5038e7b4670,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf41d1178f5a8c9d0,
+                    0x449f4d38d0420918,
+                    0x2ffbad1622ea1255,
+                    0x2aef047a602f8ebf,
+                    0xf99c476a0a1ead67,
+                    0x69da7094341e0294,
+                    0xe569e95181177dff,
+                    0xaa45a8c4187cdc74,
+                    0x5bb393274a2f8e3f,
+                    0x1216ec8c4c930918,
+                    0x519eaa893f351492,
+                    0x0098ac4aa5ea8bcf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7824d388a6d138b3,
+                    0xe6865a9aaa2ef1ef,
+                    0xcae959ca157caa9e,
+                    0x068e072a7b5230c3,
+                    0xc690a4d1fc88068c,
+                    0x7467f30e511fc374,
+                    0x0f9c7da1724e679e,
+                    0xfde5d66ffdd0ac42,
+                    0x98ee2b00457bf663,
+                    0xf5af99534e45325f,
+                    0xb8865830039009f5,
+                    0x00e63805c2636750,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc8c45ecdbaa4727,
+                    0x7641c6bba7b89203,
+                    0x021a4362c28cb257,
+                    0xbe7b9a2a71c8604b,
+                    0x4c78f5f394e8042a,
+                    0x9a47dd4c8a7693e1,
+                    0x7bdf0fa1a3e8f56e,
+                    0x1987a1d5b0dba5d6,
+                    0x501338c5ef2d2524,
+                    0x8c534eb6d0f21abd,
+                    0x6a768d4ffa2e54a4,
+                    0x00b3276b7dc2f872,
+                ])),
+                Felt::new(BigInteger768([
+                    0x908c94867fb419dd,
+                    0xb13a26ac448aeb0e,
+                    0x57b39c24a1d9d476,
+                    0x4d513a0dc0df4d38,
+                    0xb5311a03696fd147,
+                    0xcc1427ec096c39f5,
+                    0x48d3ee1f918bcd22,
+                    0x87b05d59506e2801,
+                    0x8041cf24bb8f01aa,
+                    0x3f70aad97ee8c960,
+                    0x4cddf668bd41c112,
+                    0x0039be290ea79c58,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0x1f1e865fddaa1922,
+                    0xceef03d4ce8f8b2c,
+                    0x3f9b2542a0e999d5,
+                    0x33ab2ac4dffed194,
+                    0xce1364a55b6195da,
+                    0xea5a1c980a13a5bd,
+                    0x657d73b99f6d94dd,
+                    0xb66893b4c26b9ac4,
+                    0x8b7496c1283b2ccc,
+                    0xbbafc41aef9b43da,
+                    0xc056555ad926403c,
+                    0x005112b9c88e8ff9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a4ad3e872c0c8fb,
+                    0xce577d6084702493,
+                    0xbd9e5d672f8bdc9a,
+                    0x89b0d2c65e1242d9,
+                    0x292fab5287945563,
+                    0x2640b2ee7d5d98a2,
+                    0x1874b25656321a10,
+                    0x4f76b4d9665293a8,
+                    0x47b9b3ef98a05813,
+                    0x3976ed5250796897,
+                    0xd8a55bc307d543f3,
+                    0x0061a3ca3dddedda,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x92f77a38882f1eca,
+                    0xf54be9b5dad9adaf,
+                    0x3b05c7412879446b,
+                    0xa81c6a7e31769d60,
+                    0xc494de7a2f8fdb05,
+                    0x24dd6223abfaac92,
+                    0xc463d9878cde20bb,
+                    0xbb788a6ab1048273,
+                    0xddf33d7694fad117,
+                    0x6f64a2a4d8118223,
+                    0xdaa6fb21ebc8fb76,
+                    0x00a53cd5e5804901,
+                ])),
+                Felt::new(BigInteger768([
+                    0x66962781a966aa02,
+                    0x4dc78306cac68741,
+                    0xb127e04b9dc0d496,
+                    0x831ee0a48bb7edfd,
+                    0x3b3690a23482ee82,
+                    0x108a2f3d8bdd6598,
+                    0x4e6e7ed7a1fab620,
+                    0x02eb22d3c20fbda8,
+                    0xdf774e5bc46f8fae,
+                    0x4c85369f71fd4efb,
+                    0x3ea10f6200b790a6,
+                    0x00ce03b8ace0e557,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5970e520bbc28952,
+                    0x879e9d531b3a73ff,
+                    0xd84621eafda7a58c,
+                    0xc6bd2f1b08a3c447,
+                    0xdb7fcabd9052bcdd,
+                    0x311009e6bd0213eb,
+                    0x77c2297674a16617,
+                    0x79535a678c784b3d,
+                    0x22ac921ceb4c7457,
+                    0x8f7d635e63b9d10b,
+                    0xe6033792a14da815,
+                    0x007a7230934a8a43,
+                ])),
+                Felt::new(BigInteger768([
+                    0x861bbe9b9aac09da,
+                    0x6920dac035444a3d,
+                    0x1d6dfbcd284167fd,
+                    0x43cc39d56072dfe4,
+                    0xc3a7fc52c66424ad,
+                    0xe67be5a6bbdcf260,
+                    0xb23b7aa6fb399edd,
+                    0xa1ec9b279f4f5ea7,
+                    0xb0a0bdc4d9c47ce7,
+                    0x019fb7dc47fb7a21,
+                    0x3f37b86759e73e4a,
+                    0x00f734a745d58eec,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xefbe2f1af33e991f,
+                    0xe56bc5a19882bf1f,
+                    0x3fc8cbe1ea18cbaa,
+                    0x04ac691b60c44872,
+                    0xd6d9325015a02f1d,
+                    0x445a70f033162a24,
+                    0x5e6f15868c276e8c,
+                    0x93c570a7d8964d18,
+                    0xfedb5be5f080ed68,
+                    0x549fe0eeee502f1e,
+                    0x45e85712b338056a,
+                    0x00dec52699e909a9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe44a891857a5e428,
+                    0xed9f9be6b7e8372a,
+                    0x29075029cd650397,
+                    0x2e6c8b04a334692a,
+                    0x455c0f16ab61a67b,
+                    0x60db3b709bf2365c,
+                    0x4cb79838627d4ccd,
+                    0x6051d2a796c7c04f,
+                    0x527aba54577894dd,
+                    0x184b83c830872873,
+                    0xac9c78fe1a2d3010,
+                    0x00b65d95cb1b902e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8fd19b4517f9caf0,
+                    0x831f51e0a3f10ef5,
+                    0x3f31d3c5bfc46351,
+                    0xffa3936227e509e3,
+                    0xd6fa0164223b1d01,
+                    0x314b1ea53b72ff38,
+                    0x3a2e3c7b92ec4977,
+                    0x0ff2ff9c2cf5b3d2,
+                    0x4172536438d48aa5,
+                    0xa2d5d68e95b766c7,
+                    0x8588fc2a44c2fa6a,
+                    0x00568005cedf9c68,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe8f6e42752540073,
+                    0x08fc43c00d64e4a1,
+                    0xcbb94d6e068a4582,
+                    0xc8b1ef64993c2b08,
+                    0xfd32a5a8740b9fe0,
+                    0xb9c6f0387acd3bba,
+                    0xe68af7eb2fede8a6,
+                    0x530517fdb0cf3ecb,
+                    0x81221a8559bc7c6d,
+                    0xb99be7c5be6d657c,
+                    0xeb0a1249662d777d,
+                    0x0049a8f8c6b142f1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x20701e84e830b73a,
+                    0xa3f06b9b6915df96,
+                    0x76d1422724c6c7ec,
+                    0x9ddeaf9efeca1d48,
+                    0x6d09609d2e5de3ac,
+                    0x528b13b848a9b0cb,
+                    0x7c0ab6837041d1c5,
+                    0x4a2262ba3d88124f,
+                    0xdea9b8ff4738c5d7,
+                    0xa3873e56745217c1,
+                    0xf91de358d48c048a,
+                    0x00a82593bad3f97e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2decf26b9777c8f,
+                    0x13524f6f4b1ea10a,
+                    0x7888a9c91526fa72,
+                    0x01783681c81c2ab9,
+                    0xead381b08ba71a56,
+                    0xe49e2e6d52870232,
+                    0xeeae9fd1ad452ef8,
+                    0x94f3305db530d3c1,
+                    0xc4c1117b01952f07,
+                    0x9fabba052a2e6784,
+                    0x7f49cc75e85985cb,
+                    0x0109390c91003c5d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa03c593769992ccc,
+                    0x07e448ea0dd564b3,
+                    0xd06d15cfe72c5e6d,
+                    0xcf9bf850f24c58a1,
+                    0xfd3be2ada32bd263,
+                    0xd3be939728c7aae1,
+                    0x818d9da69d6fc689,
+                    0x4a39f2ed81368ffa,
+                    0x81b0fe3590a7556a,
+                    0x01a42e11455dd76b,
+                    0xadbcfe7628c2cd15,
+                    0x004c954804accc73,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd102228582fa5f68,
+                    0x3a0f44b9e341a456,
+                    0x0e543ffe338c5ebb,
+                    0xdec305c0cbf190bf,
+                    0xba9928659fc15a25,
+                    0xec0cf2da4f892fc5,
+                    0x52b2629c782c96af,
+                    0x8b5b64d07f2bfe6c,
+                    0x330aca1a50a15aa0,
+                    0x7602453df36935b9,
+                    0x1a090ecbaf156e68,
+                    0x00ed5c5ac06bf315,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc9bba99b03d6a291,
+                    0x5eb6b7e48880c2c5,
+                    0x8690497e080b80d3,
+                    0xae48af51deae2043,
+                    0x0b2946eb64958e41,
+                    0xac5530799b3ea1a9,
+                    0x846a1b936d20f97f,
+                    0x649252b5c17d0d06,
+                    0x057adcae309a7ce3,
+                    0x48d7204f1ccfe31c,
+                    0xe30305b127dbb026,
+                    0x00f585b8e555b751,
+                ])),
+                Felt::new(BigInteger768([
+                    0x376ad898d79d79e3,
+                    0xf2d84b96b3205a5a,
+                    0x676d95e53887ad3f,
+                    0x7ea804995f4b3498,
+                    0x941e0885001fc4fd,
+                    0x066f24eb55ffee83,
+                    0xfbb3bbe588fbce03,
+                    0xb668ee75b7b175d3,
+                    0xd0e4a041311958c0,
+                    0x7857eed151b82124,
+                    0xdbf1242d7a192f39,
+                    0x00395f1ac44f1327,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x43bfbd4441cf639f,
+                    0x01c8ab4a72a1969f,
+                    0x2a796c600d1c191d,
+                    0xd9b1522bd691b9c6,
+                    0xc08d411990a0e569,
+                    0xa03f092ddc80e12a,
+                    0x376e50a1067b91c5,
+                    0x8bdd441f6b2967ce,
+                    0xe437c383a8e70523,
+                    0x38d706cd89d8283d,
+                    0x7cd4c2c3de941230,
+                    0x00977440b0dabcb6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x137ab0647d0b619c,
+                    0xc39459a72f847659,
+                    0xb376b387e0315b6f,
+                    0x0643e3d41467fb92,
+                    0xb985290b32b88bcb,
+                    0x70e6e92ac3542686,
+                    0xbfb1e5f7fd15e8dd,
+                    0xd5034f2b8f5c3da5,
+                    0xad44229734653ef6,
+                    0x4acc62b9c790fb6f,
+                    0x5237d4c5a6a84c5b,
+                    0x00c9815e0ba7c2c8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6692e7dbf4e2d614,
+                    0x662b58fb2b30a50e,
+                    0xeda44702878fbf46,
+                    0xfc40d4cedbf7fd7b,
+                    0x187d4f0f7f750d6f,
+                    0xbb392b6c8539820f,
+                    0x8c734438474c1e7d,
+                    0xfa4fdf72249a66bd,
+                    0xd53f62b8ea8e458b,
+                    0xcf0403be3ec824cb,
+                    0xf42bdc2882da0ace,
+                    0x00e88ed9e31eaf57,
+                ])),
+                Felt::new(BigInteger768([
+                    0x70911271388a40c6,
+                    0x3e0943a38096e246,
+                    0xd8093e376d811a99,
+                    0x296727d4d6e15132,
+                    0x3b2a91c4b4ffdf38,
+                    0x444a05773e73e5d0,
+                    0x66d4f1d4efaa21f5,
+                    0xba0ebc79546b73ab,
+                    0x570ee8a4c2158168,
+                    0xcba35f93760fb2e1,
+                    0x57100e80a0caabc0,
+                    0x00c64673b9e2fb5f,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 950, 951, 2855] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 950];
+        let mut opad = vec![0x5cu8; 950];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
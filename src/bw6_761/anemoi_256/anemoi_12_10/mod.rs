@@ -0,0 +1,5384 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 1152 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 10 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 10;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 2 elements (192-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 13 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 13;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2830f218846ce11c,
+                    0x5b21a0eb5dbddb29,
+                    0x093c23f210c094de,
+                    0xdc403ec50dadae07,
+                    0x5130e8565a7a7ad7,
+                    0xa0a7fb112e04105c,
+                    0xc9b2aa1b653a10a5,
+                    0x7a0b3169f8030c9d,
+                    0xcc6bf50783be1c9b,
+                    0xa99604a7ef12a92a,
+                    0xe76e278a1c048d80,
+                    0x00c6da9d0edf0561,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2d9a23f0a8bb2e63,
+                    0x8a9278ddb95eb67e,
+                    0x8a3eacb988d52c3b,
+                    0x6e67f179f29862ac,
+                    0xe19a07014e0d7b66,
+                    0x0df7fcca9dd9d7a2,
+                    0x71e0ac25d563fd2a,
+                    0xc92b824c08de1ce9,
+                    0x861eec8ec24ee939,
+                    0xf053b6a933b49e54,
+                    0x24f6e2ceac4c96dc,
+                    0x00de47e3e199ae3b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ceb6ba1b9850528,
+                    0x201afa11a3d5147f,
+                    0xa16b2e625502a17e,
+                    0x1dd21ef1fe347e36,
+                    0x4363ba888c9f65ba,
+                    0x9bbdaeebf1e66278,
+                    0x57984caa3172b469,
+                    0x70c268d64c10dd87,
+                    0xd8db368a9a416481,
+                    0x78c088ed8ae8fa51,
+                    0xcd78d3e5c1ee3401,
+                    0x001799eaf5d13d1b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4235b1e26c2cdd3a,
+                    0x104fd877ef76f33e,
+                    0x960200c1b4c604b8,
+                    0xba7c3ca83930fc52,
+                    0x40106c7c3b7d47f9,
+                    0x7070f8a57c05a779,
+                    0x0fd0b15966952944,
+                    0x2a090e3fb05c1fc3,
+                    0x60ed05a0ad050dea,
+                    0x91b2ac073ebb19d0,
+                    0x5608da08da5d4af8,
+                    0x00fbd3814f630b18,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8155751ca185a06f,
+                    0x6efb5e77862995b6,
+                    0x7033bfe80cbd50af,
+                    0xb35994a5f09b6ee5,
+                    0x2c2878ae0b046269,
+                    0x029529c9d92e6484,
+                    0x56dc64c40a18318d,
+                    0x777631de35ed5bee,
+                    0xc4c512580cc643b7,
+                    0xae1ea6fe18aeda8d,
+                    0x6d61a25c8a9125fe,
+                    0x00797bec49a5c9e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x747b492598c129e9,
+                    0x09255c3aa8e7a06b,
+                    0x158750e0a219b5b1,
+                    0x444b24ae15aa7192,
+                    0x92526d3faf1be171,
+                    0x11774b85e9f21803,
+                    0x3375ea25075aa293,
+                    0x4b9a7297e19afa5a,
+                    0x31ac6b8185cf1215,
+                    0x9ebd3ce628f33efd,
+                    0x3b5f8e8f280b7c92,
+                    0x00c815d0ef73c4cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb3d6190845f70c85,
+                    0x614837549abedfbf,
+                    0x95fe67ee53639d94,
+                    0xf3cd02f76d0137b8,
+                    0x0e8cc6456cdb89c7,
+                    0x676306aacc89c7bb,
+                    0x124e8d7efdf1b9ad,
+                    0x21eef9d8e3bd6dfd,
+                    0x5ba1d0403ed04145,
+                    0x160d67d31edd47f8,
+                    0x2febececb9039827,
+                    0x0107d9cb79de58f2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b5df9be1442dbd9,
+                    0xdee0ff470ccc15ac,
+                    0xe328736efe2fee0f,
+                    0x324a28f86e27e0f4,
+                    0x9754d9728081290f,
+                    0xb7f2deacb2a079bf,
+                    0xf494531114636054,
+                    0xee7b2a2d85141d53,
+                    0xa364edc8d1c9be70,
+                    0xa8cbe06eb2e08b5f,
+                    0xe38ea6ef10739876,
+                    0x00670f179645d837,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1cb6ad7a5712cd08,
+                    0x5c0b026c76a20670,
+                    0xf7aefd7328f8ea76,
+                    0x339c6d53dc4a9fde,
+                    0xac36b29ceb2c01dc,
+                    0x594f1b2919236e32,
+                    0x08bb86c8968ba503,
+                    0xdb9a4d531aee2c2a,
+                    0x6bf7f63d1b05c810,
+                    0xdcb49ad31a535d28,
+                    0xe8f9f45488ade092,
+                    0x00017f3494fd8925,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1937d22573c3601,
+                    0x1863791501bcdade,
+                    0x048e7d8deafb7621,
+                    0x59a761c571575fcc,
+                    0x3aa9afbdc14158fd,
+                    0x726c4e0dd40012fa,
+                    0x164996619cda7b90,
+                    0x1b7d02928ec4b911,
+                    0x6986569d99d802fb,
+                    0x6e9594e9ae4321cd,
+                    0xd8eacea806a83a94,
+                    0x0020198eb7af5841,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c1da6d67653884d,
+                    0xa20dbe4c429f2311,
+                    0x9007bb5f1afbf1e1,
+                    0xb8f140e8ff8cdee7,
+                    0xfd40badc5453ff83,
+                    0x3e25405a358decaa,
+                    0xf30b8da760ef2df6,
+                    0x41089084cd254c17,
+                    0x86d85529e3e4ac01,
+                    0x313c3c90fbe6de10,
+                    0x57bfa7d9896ccc2e,
+                    0x010f96c88c493e20,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf7bac07aaf45117,
+                    0x3f3d03e3218f2d98,
+                    0xc5583f838d6628b2,
+                    0x3cdd2345fb8f0fd3,
+                    0x97fead299b6b873a,
+                    0x61db9ec4196b6886,
+                    0xbde565ce78a04b80,
+                    0x9549e81aec801b42,
+                    0xda04e2bb4d108012,
+                    0xaea14857674e3bf1,
+                    0xeec7636f830012c2,
+                    0x00a419e2007391c1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x160eab13aad56f29,
+                    0x786487244796060f,
+                    0x6f34f775876794ae,
+                    0xdd408cefd347baaa,
+                    0xc8accb50ac50b3d5,
+                    0xb6b9529309ee4541,
+                    0xd6d903517179a2c8,
+                    0xac118773eb9ca1ae,
+                    0x4306e1cca1e6ab19,
+                    0x8167b607d5b6b5eb,
+                    0xce753486ce395ac7,
+                    0x003c9888f61c99a5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x860ad38e60ca0e9a,
+                    0xa038d1d43af3ee0d,
+                    0x1b11bec92c9287ef,
+                    0xe86ff6bf93031007,
+                    0x0a7b145f719f7e79,
+                    0x751f6038d5e8b850,
+                    0x9015cff2090de9bc,
+                    0x9d84d09a902d898f,
+                    0x5adf0baa34198b1c,
+                    0x7433db734ac836e9,
+                    0x4e1a3b02b44cbfa7,
+                    0x0060f1842c66e10a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa5b7938c38302e24,
+                    0x3e0e0b3d1d9af809,
+                    0xf92b6264df5e5d4e,
+                    0xbce8766335b3c66f,
+                    0x2bfe5bd4c1f3ba28,
+                    0x70aeab140d0db0ec,
+                    0xed368be71b10ffa6,
+                    0xa2a51fb3c69b6fa7,
+                    0xa3543f05d7eb1761,
+                    0x4dec53ac1b9d2754,
+                    0xc43a400cae26708d,
+                    0x00ad1ca32a47ad05,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8450a5e308a73cbb,
+                    0x6ea37e92517b31c7,
+                    0x283ca5a7cd1f7953,
+                    0xb4a858581023b076,
+                    0x6553ffb12988276e,
+                    0xd83fe8d03d92bf6d,
+                    0x28c7544b9441829e,
+                    0xf59cf22d2e6f22a7,
+                    0x7aa730f160d9d35c,
+                    0x13ff6eac49f5b0b5,
+                    0xc043eb0f3a91961a,
+                    0x00a72c118163a101,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeaac7c164775f44e,
+                    0x1acbcda91cb06fcc,
+                    0x94316a9dce6d7398,
+                    0x2d9ca517a4c6be80,
+                    0x945199a595aa5b2c,
+                    0xef445d761d8da765,
+                    0xadcfb89b2a8627cc,
+                    0xbe157c942632d9e5,
+                    0x9fbda7d2977c86f0,
+                    0x70035c30ac0bed8f,
+                    0x9c1ed139052d12e4,
+                    0x00eea8554c3915a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x159a3a80f5954cf0,
+                    0xdf64c10c70ce81fd,
+                    0x9e85a4572aa21877,
+                    0x794e525ca7284bba,
+                    0x532de605334793b7,
+                    0x1bfdb3843d6fcaf4,
+                    0x891e94fcd5286125,
+                    0x200258fce9d1cb28,
+                    0xfbcac18f128b7e5b,
+                    0xa7e45c46bf9d9ceb,
+                    0x900a492a6a41385c,
+                    0x010d1bdba159bdc8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x83d3597558bb9c22,
+                    0x3183715354c442b8,
+                    0x3549b57fd9a4fb53,
+                    0x133aef06a72ff6c0,
+                    0x314e70a1cc789f8d,
+                    0x9a2b86bda885e87d,
+                    0x6c21c10671609a73,
+                    0xacfd75605f88bd67,
+                    0xabadf1c4d5b3ca0f,
+                    0xcd1da377c9ad087c,
+                    0x8f4c855c72ea26c8,
+                    0x00616b55ada7efeb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc1aae4b9187f305a,
+                    0xae2443b2e7a6d3bd,
+                    0xa61ae175c7ec4667,
+                    0x70798e4f944dff49,
+                    0xe4153565f8e11bde,
+                    0x2670769403e1b7d9,
+                    0xceb071e015f9cc4a,
+                    0x9335ad632dd551fb,
+                    0x5a0c1503c2b1171e,
+                    0x3908216466ba1459,
+                    0xddb3817651500ebd,
+                    0x008923cd0f0430c6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda45f2e5e9b0748b,
+                    0x1dcc91d78268dde0,
+                    0x72770a839c721285,
+                    0x469b229bf96790ea,
+                    0x900d55b22592fa3a,
+                    0x4dc7cfa0f3b5a798,
+                    0x12bf245231ce14ec,
+                    0x505a747f82bbe3f7,
+                    0xe7cf0f5ef82869f9,
+                    0xa57e6ac470b48292,
+                    0x6965006e480b9777,
+                    0x00586928071c168e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x740a3e6a34dfc71d,
+                    0x02d3363df2c3e5c1,
+                    0x743e5c47af2c1529,
+                    0xcf103973a44574ed,
+                    0x156516984876ca5e,
+                    0x883ddb8d91e98838,
+                    0xb4eb2a02d44dbfa2,
+                    0xca447514a69038f7,
+                    0x48b89a3f5378003a,
+                    0x764574e88d842b86,
+                    0xcbc4412561f8af14,
+                    0x0035f7a1d1434c54,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbab730cfbb0bf0e1,
+                    0x62dff99988638933,
+                    0x3acb120a39724714,
+                    0x6dcd1d963031c7e5,
+                    0x22423d6ac7c844e4,
+                    0xef80796f3e09e62c,
+                    0xa0e9d8693ab1a682,
+                    0xad044043dfe1a77a,
+                    0x1d940bf28c71a885,
+                    0x80d933ced81c69f2,
+                    0x5ff1ff5cdb59fb87,
+                    0x005fcfaa027de76d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4ea3a16974ee4b51,
+                    0x52e4356f29b19e0c,
+                    0xb809f8445ea9c0c4,
+                    0x86938e7353d1c583,
+                    0x67d397b01f2daeec,
+                    0xbe93c0eb8259d3f8,
+                    0xf3f01ed5dc05272c,
+                    0x968f7395d34ecb40,
+                    0x44ee8b870ae5dbbc,
+                    0x188090d63355fd66,
+                    0xf31708ad1efe2706,
+                    0x007bdefcb971693d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbca376efc3e0609b,
+                    0xe29ebd6a7e6cd280,
+                    0xf5096a36872aa2c5,
+                    0xb496838189ec1c85,
+                    0x61448cd1578a917e,
+                    0x6281684a7704c7a0,
+                    0x389132efc376afc4,
+                    0xcacb533cd10b8e2e,
+                    0x5569d29280ed1536,
+                    0xb0cd4afd19c643c4,
+                    0xe5c35e7a5badd430,
+                    0x004d19d659b8a248,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3d1f1169a4006566,
+                    0x07da81e4ee6f912b,
+                    0x02f62d2443544445,
+                    0xcdd2af2d657ed4a2,
+                    0x2e3bb5a0537be329,
+                    0x94bf1a66d2ab0c9d,
+                    0xedbd1267c8fe7316,
+                    0x76835445d7f30ee3,
+                    0x3cdc981f9b6bb8a2,
+                    0x1f1cf975e4d298b2,
+                    0x0675e82c183a44ba,
+                    0x0078b8f29a4f313a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce302ecc591b1d76,
+                    0x9df1dfec8750e8b3,
+                    0x92e55bd76679b508,
+                    0xb10d93964f175646,
+                    0x6a847e3684279a2d,
+                    0xe476224f72841d78,
+                    0xfaa7f4694000ba6b,
+                    0x9f69b3e0804569a7,
+                    0xd1b8907b963d56db,
+                    0x45e4f672655b5801,
+                    0x685df6fd270e3fe0,
+                    0x007632e197f18213,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8fa185e01caf2710,
+                    0xae5c64c7b66d03fc,
+                    0x8e27d1c80aa9f69d,
+                    0x379ef270f2ce41bf,
+                    0xd6187c40dec8fd19,
+                    0x91bbe0c40948a8be,
+                    0x2284db1615d881aa,
+                    0xa910d7ebe6a49e15,
+                    0x7b14bb134a03e563,
+                    0x5e1a879409abb623,
+                    0x06d0e7478a8c0aa0,
+                    0x003868441e8d03d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd9b50bda7f0c36d,
+                    0xc09b46576eff9fad,
+                    0xbf42e6c71a30a4c4,
+                    0x21524cc41d044043,
+                    0x207b743de02ea3bc,
+                    0x0ea043ffb8be7068,
+                    0x032fb438e5fc40b9,
+                    0x06400bc124f2694b,
+                    0x67f7faec90c51a8f,
+                    0xb4c8cbcd57236e80,
+                    0x5c63918666188254,
+                    0x007c4f81cc200de0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9ed8aaf1434ded85,
+                    0x358fadf5e34f2293,
+                    0xf6d1bb55d47a4aee,
+                    0x71aa4bf9ce2decb8,
+                    0xb81dbbf7ec97fe20,
+                    0xdd000189d74971bf,
+                    0xbd5e3eff0e9ed5d9,
+                    0x5361489db69c5e0b,
+                    0xa9c79392ebf9c54a,
+                    0xbb598a973b0aebba,
+                    0xb607b4480d816eab,
+                    0x0095f301619cd9fd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x749b61928920a1a3,
+                    0x019eb49892747a43,
+                    0x17172469fe6ef18a,
+                    0x7c2def65937c7171,
+                    0x8e68a58d8870c7b4,
+                    0x06736146ac32635b,
+                    0x55afbc4ad5b0aab2,
+                    0x1e34c4d34b7eb865,
+                    0x1c8e1d8e8e489caa,
+                    0xbc548684c9be6527,
+                    0x6c79c08b12f7bc1e,
+                    0x0067a5a7ce165461,
+                ])),
+                Felt::new(BigInteger768([
+                    0x466781b59934f62d,
+                    0x670d4cccc3d193b7,
+                    0xc53749f314a7cfa5,
+                    0x5809c7ed481c9977,
+                    0x07933b8466054a2b,
+                    0x2036899bf83cd9ae,
+                    0x556149ff1213922d,
+                    0x4048bd73f63620b7,
+                    0x52554d06758848e7,
+                    0xe17551ee9b2ac7ae,
+                    0x97cf6df20537a097,
+                    0x005f138f77111086,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb413c47445be0470,
+                    0xe043e440344dae4d,
+                    0x0ab136b7348b7991,
+                    0xcae2d120e07bb26e,
+                    0x0a008c18fe991a67,
+                    0x380140ed0a881bd0,
+                    0x36735e851dd58a69,
+                    0x243f0b52c1da3047,
+                    0xc600196e8f4732e4,
+                    0x49a20c0051222d95,
+                    0xc178a324fec02b3d,
+                    0x00683af4a636df13,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97ee9981eeb1c621,
+                    0x1ba72dd4f2a95496,
+                    0x596d20ecde9178ec,
+                    0x451e3ad52c326a11,
+                    0x3b361d59476c6d2f,
+                    0x7f46b219115fa2ce,
+                    0x0c35512777f5eacc,
+                    0xe08cb0ad1879657a,
+                    0x1313efe2b45f4f08,
+                    0x0ab659a22a5d2418,
+                    0x90ce1ba3b57b6077,
+                    0x003aca33762d093b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0b533a799c104c7e,
+                    0x02ee4b095de74736,
+                    0x73bc04339d785fd9,
+                    0x89d50d344cfb60a8,
+                    0xf5c063506d667ff4,
+                    0xfbee30dc2e3760d8,
+                    0xe1072978c2d8dc8f,
+                    0xaccd8da248c8635b,
+                    0x4ddb6c81d6b2df17,
+                    0x6ebcaaf009c654c6,
+                    0x23080c4618ad95c7,
+                    0x009c9c11459d94d3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2e5b3722cecbf214,
+                    0x9e11e3787eb14817,
+                    0x36fb3d5c4640d663,
+                    0x61ee28dbcb71989b,
+                    0x19692a46c6e097f8,
+                    0x81d1764358cfb44f,
+                    0x86f4b9b49ddb30e6,
+                    0xf5da7a66e06df604,
+                    0xeea5922f0519dbf4,
+                    0x3f535b082d8626f1,
+                    0x6453a5f7ed1cbf72,
+                    0x005aae06b089ecae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x406bfb9e7477b0c1,
+                    0x9a667efd14a4d2de,
+                    0xfd03a80d4d4cb399,
+                    0x95027c5487fbfff4,
+                    0x919ead71ac70443a,
+                    0xa5b5c5234b17ea56,
+                    0xe15839bafedf73c9,
+                    0xe4fb11a77dafe946,
+                    0x886e5693baaf33cd,
+                    0x7669b81bd88642f7,
+                    0x2facd5b2f881d5dc,
+                    0x00465719e745dc7e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb767ff093840acb1,
+                    0x4b3f25bfaf52f36b,
+                    0x8392c51702cccc6e,
+                    0x8c7b1bdd8354144d,
+                    0xf2a975f05b0b4ee4,
+                    0xd45943315f27df29,
+                    0xd2ede52cfdff6914,
+                    0xef363d5a95ff1a3f,
+                    0x81e9e3fb022405a3,
+                    0x44e1f8aea94d178b,
+                    0xa4901478c421dd8a,
+                    0x00d065bed1ed7002,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3f6f312eb1fcf2df,
+                    0x1a4bcccee1afaa3c,
+                    0xc0a7ac01efaf3baa,
+                    0x684fca106deb84a6,
+                    0xa5c0805b704fc949,
+                    0xb39490eaddcda5af,
+                    0xd732443bbc067453,
+                    0x3cd303ed724cee76,
+                    0xbae631d933757f07,
+                    0x05ff80f2cc05d2fe,
+                    0x12721a8ba68d64d8,
+                    0x008b7aeca6587e99,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe8a8373678936fc,
+                    0xee8d0051e8616b5f,
+                    0x88cf565cc70135e7,
+                    0x81d0fd19c02afe09,
+                    0xcbdb896785350db0,
+                    0x3ddd9080421b88f4,
+                    0x716d48d081385cf8,
+                    0x6696a6b3e3dfa51b,
+                    0x1802fff48cadd342,
+                    0x0476a91e70412b6f,
+                    0x2b8f2fdc2c0fa63e,
+                    0x0115d3a58555188a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b8c8bcc6c34aeb4,
+                    0x1841cec09e0eeda5,
+                    0x46d8b768b05af5cd,
+                    0xe31c5f8bc21b8ce8,
+                    0xac1b6187f060de4a,
+                    0xe6bacb9ca5421181,
+                    0xe7b4665774b90645,
+                    0x7459d48aeee84c6b,
+                    0xa82e05b5a83dd82a,
+                    0x036a633d78110b62,
+                    0xf6939bc7f68171ef,
+                    0x0071df228fb63be5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x07c1f94e8f000a6d,
+                    0xab048b94ccec490f,
+                    0x201a1d40561aa91e,
+                    0xf8f5e3522e465baa,
+                    0x4713e7faba254cc6,
+                    0x8a44f2c5bc67ae03,
+                    0xe8a4a3973c9b156a,
+                    0x6d5499005752d034,
+                    0x2d6ba314e28ee6c8,
+                    0x4b87034a99d7cf8e,
+                    0xbadd9914aa24843b,
+                    0x0046984b14ac614b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x20a93e6e9d7e779d,
+                    0x57653d68a2655c41,
+                    0xaaaa20b1cf2f6ab5,
+                    0x229859656b712e59,
+                    0x8e75911fd7fba768,
+                    0x0f9fa9ec6914ff23,
+                    0xceb0ae375ee5114a,
+                    0xd418e9eff8558550,
+                    0x03dc047e4a0154d8,
+                    0x8b67833bd4bfb107,
+                    0x7603a3698ebaf965,
+                    0x000d9367491fa3b8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x86a6d600e7f65b66,
+                    0x20607ac21a2ec0d3,
+                    0x59e6a5255ee7b294,
+                    0x50b90f773bb48777,
+                    0x4f86aeccacfca2ff,
+                    0x9aab526e78e4ab76,
+                    0xce790efc852d2e94,
+                    0x2d18e5fc07c6b7e4,
+                    0x5d7e8b3596d3ec44,
+                    0x000dedfcbdc82aca,
+                    0x18a6e54dfcac1f61,
+                    0x0083edeb15e7a13e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe56414a36d2b5573,
+                    0x1d5f18a1835c34db,
+                    0x6cdaa729c2123bbb,
+                    0x00d7c7768982f894,
+                    0xba699f32734433e0,
+                    0xd3c8799351d82d3f,
+                    0x2e485df959ef5d94,
+                    0xa77594f79b25a998,
+                    0x5014f95889d16744,
+                    0x02a2d3af1004ddee,
+                    0xbe025f037748e20c,
+                    0x008cf0c98215390b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2806bf2ce154ab98,
+                    0xe88ebf67522ab2a7,
+                    0xcc15934eef87cc3b,
+                    0x893248b5cd6d0fee,
+                    0x11f64f7e107fc930,
+                    0x65feb8da630ded82,
+                    0xc051b024593f51bc,
+                    0x715a22bc3165d87d,
+                    0x964930154f40d870,
+                    0x21b2aff45bb610be,
+                    0xc97af649dc980bf8,
+                    0x00fdcdd6152afd45,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b34eb536b34ab34,
+                    0x11b2cf77fd998995,
+                    0x99ddac6e2347760d,
+                    0xb801a87eeaa171f5,
+                    0x1cc155f4adae5e4a,
+                    0x020ca9b2d9029128,
+                    0x1d192632166df23b,
+                    0xe64591ef4779a1d6,
+                    0xbd18d5e774ad6bfe,
+                    0x8f2e31d38a7fffa1,
+                    0x25e7b55fca56a013,
+                    0x0007476ea15204ae,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87fc4ce4b13ff16c,
+                    0x77c73d56f747ed37,
+                    0x7d3560a7a67cb95c,
+                    0xe1694fdf52aa5db1,
+                    0xb3c87521e4a162f4,
+                    0x01375758df67bdc0,
+                    0x8b0a05fc5dc7e0af,
+                    0x438893c96d004771,
+                    0x14c658f9a5b7f037,
+                    0x93fa69a7bd12abfc,
+                    0x010b0a6a1d99ac78,
+                    0x0014bb253df4c0ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xece3df3e7c9285fd,
+                    0xfefe641881333d03,
+                    0xd46471970c4e6c46,
+                    0xc998145996788c00,
+                    0x1aaf7e566bbd65c6,
+                    0x5955ac6e44e7a7b2,
+                    0x63ab9f6e0a674b52,
+                    0xff3edc1c526814fb,
+                    0x1d50e991821c0c3b,
+                    0x3454739bf2420163,
+                    0x791629247a6fd011,
+                    0x00ac460deb0570c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ebe0290136b9d2d,
+                    0x36825fef2e599923,
+                    0xc26df9562f185d9a,
+                    0x8677e30d9f1c0c77,
+                    0x2e98b23358fa95ee,
+                    0xbaec0e4e3731a0cf,
+                    0x48d2bc113e4159fe,
+                    0x6cacaa45cc90c14e,
+                    0xce3ee6e393eb4010,
+                    0x7539759889b64920,
+                    0x5fc0662f2e3dd5fe,
+                    0x00b5a570105640e4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x62807fbe8a970cdf,
+                    0x4efd984c91b613c5,
+                    0x288dae1576f4cf9b,
+                    0x956c185ba51462a8,
+                    0xe160807f3625d55b,
+                    0x84c03027ea3437dd,
+                    0xb7bcca81d67a6660,
+                    0x35dea15a69ef475e,
+                    0x50d2864ec01ed647,
+                    0x6a816f3b86cb219d,
+                    0x98170f0881e09e64,
+                    0x005034785d06b33f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6be3094c1a820c80,
+                    0x4103418ee88f7cbf,
+                    0x24cd76fef17252cf,
+                    0xf3be9fdb387710ad,
+                    0x90526fed4e76ed85,
+                    0xaa72c72f96e4d972,
+                    0x6234593bfbddddac,
+                    0x9adfd113b92d8a72,
+                    0x514176620d339d88,
+                    0x7b263903ffc2cb30,
+                    0x31872c1bfbf9fd0d,
+                    0x00ae5f0ef6335f0e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e5e3d5bd098746e,
+                    0x049bdd39bbefdecd,
+                    0x49405f8398ec81a4,
+                    0xff560a093711a3e6,
+                    0xb348f618529cfcd9,
+                    0x7bc8f98a3a8faa28,
+                    0x0b1d6f7fe9915d95,
+                    0x9caebb5b1a542c66,
+                    0xd37031a277b4522f,
+                    0xdd312f1b6bc8227c,
+                    0xca47d2a8c7b77435,
+                    0x00d10049b811a8f6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a670c4bf6660760,
+                    0xc1711fbcca9879cf,
+                    0xc7383b71c4c542e5,
+                    0x8d38482a68d4047c,
+                    0x5315c8fa1cc7814e,
+                    0xc033f68ea1599bea,
+                    0x80c70d274f675b65,
+                    0xdc73d43af4a89e5d,
+                    0x54d6965f72762a4f,
+                    0x1960ffaa196e5e52,
+                    0xde88145690d5e239,
+                    0x00b339feb7735024,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38359ab0559604d1,
+                    0x36d239553d2eb3f7,
+                    0xd0889c9dd4957914,
+                    0x1a3fb0e859512f82,
+                    0xe2c4a78411a45cef,
+                    0x9d2a183a66a80006,
+                    0xbaa6fd9a8f8a3e9d,
+                    0x6238ed2d127d2f86,
+                    0x05cd6e5f87b0f9b7,
+                    0xfcd8c773bcb3d87e,
+                    0x9a8a0b2c5d3decf3,
+                    0x0079075151bfeb75,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c559e654e18b376,
+                    0xa8bf587f19131791,
+                    0x17180a676e1dc3b6,
+                    0x2f042fde8f024324,
+                    0x241e83ae6b82aad5,
+                    0xf2453563697c495d,
+                    0xe75d752b6d75cf2e,
+                    0xd3f5e11df1cd9c58,
+                    0x99ca3dbce3df8911,
+                    0x3223dbe2a751f6b6,
+                    0x364b789621b45696,
+                    0x010754894e0019b6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd07180738c6a5dfb,
+                    0xacd0da3690ce1d91,
+                    0x1012add69eddce80,
+                    0x4122f9787b09d0eb,
+                    0xc7926345e2b23d24,
+                    0x3b9e31758204fab7,
+                    0x2d6fe4e1076365ca,
+                    0x5cd38474e8233961,
+                    0xd6cff71ce023b954,
+                    0x5f693de56da7cd45,
+                    0x959dd11f429ffdc5,
+                    0x00cf1817916138c8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40a6074ab73283b3,
+                    0x9d8c9a62b45514dc,
+                    0x8ff829b3c28557b8,
+                    0x9ace51d2f47682d3,
+                    0x82722dfd413ddce2,
+                    0x613231a0695f8712,
+                    0xc35222cc0ec25f0b,
+                    0x157fdaf4247a1ef7,
+                    0xefca4c32109562c5,
+                    0x04049cdea23462e4,
+                    0x29553888f4cfcc61,
+                    0x006c42359cacb037,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22bed57131a265f8,
+                    0x6e0c23ae31eafd48,
+                    0xfe0a903976a2fe12,
+                    0x0f6a3559d5482405,
+                    0x65056d2db291dce2,
+                    0x7e52dd3a76ef648e,
+                    0xfc4ef5edf88a9258,
+                    0xe436e1efc09986ea,
+                    0x00f2d86457b6857f,
+                    0xad6bb496a16e42c2,
+                    0x9c7cc393d8d1e11d,
+                    0x00065b5817993e04,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4afcb3450aba259,
+                    0xd3bab1cebc95af14,
+                    0xa6375839b5ea9aeb,
+                    0x6eefaf37f9816bd0,
+                    0x4534550d9664b972,
+                    0xc384060e4b9d459b,
+                    0xe8302763a2e1f11b,
+                    0x5f9b56df87f59248,
+                    0xc8d00e752bdcfd8e,
+                    0x2d1992e1f1e81e3d,
+                    0x3f4e54a83dc9c469,
+                    0x00b176b05e906f63,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4639309a7a5cb13b,
+                    0xdbc41108c34c804a,
+                    0xe32d42ca384911d2,
+                    0xa8ab7d6d50427dda,
+                    0xb825205a4ba450d9,
+                    0xa0f87b9e4bcca9f5,
+                    0x19d7a1432a53b628,
+                    0xdbf5ecaa76467d7c,
+                    0xe08928eaaff697ad,
+                    0x15674e53d839dd82,
+                    0x3ad95236dbccb3ed,
+                    0x009dca0951b341e7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x57f2a07eec5d2af7,
+                    0xc9ac120868b12b51,
+                    0xa1d2c95f32782e85,
+                    0x73505cad9c5219ea,
+                    0x859e2bbbd3d51bc7,
+                    0xeb16bffd9fa1c36d,
+                    0xdcc1f1733adef02e,
+                    0x9e55ccaedf3003c4,
+                    0xa5061713e30b4aef,
+                    0x9d65c4ae422c2e3d,
+                    0x74b5fd9b848fabaf,
+                    0x00c96fce53b130bd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0612ee1f4c1641f8,
+                    0x8d34b14484ebbd2b,
+                    0x40311c7474bcf043,
+                    0xf83a04e3e83c05f2,
+                    0x24b44181f465f402,
+                    0xdeac86106f892014,
+                    0xeffe9dc178e1aae3,
+                    0x4c4f8054e86e9222,
+                    0x9f584434ee4a3114,
+                    0xcee6af14ea9701ba,
+                    0xe47a652122f12d15,
+                    0x011a0e182b15da4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9cebdf4803f3d63a,
+                    0x6a45fad7776babe6,
+                    0x6e56cf6b1ba9693e,
+                    0xf1d6a99db50c42b9,
+                    0xa9a3df47756f48e6,
+                    0x4ae63160d599de50,
+                    0xbe5127f853a31298,
+                    0x1fe4c256ccc5f7a3,
+                    0xa84376add8d79325,
+                    0x3a74ab8f89c45c04,
+                    0xedb1d9bec32a9b8a,
+                    0x00a4417ea48bc818,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72445f70ec0c4c48,
+                    0xe1902e18b8d132c6,
+                    0x556930d66e5c0e59,
+                    0x603a844ad89ad49e,
+                    0x79e05d979b35d976,
+                    0x3cbf69423e5ccf6b,
+                    0x2877376c1f3b1cb3,
+                    0x4a471c76dd2cc7fd,
+                    0xaf8fb06707e741c4,
+                    0x1fe830d8acfedd83,
+                    0xb85ff1e7044b427d,
+                    0x005fe19aa32fbf96,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb67b8b7fb04a1ef,
+                    0x3553a8a492030f41,
+                    0x3ff6b09760670d26,
+                    0x81de9832afe7563e,
+                    0xc5d0f005d9912982,
+                    0xbcfee71710aa0980,
+                    0xaedd7819b120000d,
+                    0x9ac2583e7f6f05c7,
+                    0xf77c5af1a1fd14e5,
+                    0x7617c42bda83c190,
+                    0x51c1166b6bb0ceb2,
+                    0x00b7a6b28e6f2ecd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9e35faa69fa8fa96,
+                    0xed4e65682846b3ea,
+                    0x2f7b6bb122b7b6d8,
+                    0x37d76c448d6566d8,
+                    0xef8467984d0b1220,
+                    0xe5254c00c5e662ad,
+                    0x3e93144dfe5f70ba,
+                    0x761eccd7c0f5817c,
+                    0x20d62ee2f5764f01,
+                    0x4aaf47ec16d2ebeb,
+                    0xbdbbb3fab34b6cc7,
+                    0x00e850b7dd6727b4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf61391b6144680ca,
+                    0x6473a84d24e83660,
+                    0x5379bf42b99076cd,
+                    0xc9d568df7a5cf72e,
+                    0xfa6df71d516fcea7,
+                    0xde95313f4fc74d07,
+                    0xefb89ca36b011e78,
+                    0x338a971b39dc66d2,
+                    0xef4bb6ef29b93986,
+                    0xfb8cb6b419466f51,
+                    0xdfb499dcbd3db925,
+                    0x003bab130390bf52,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcbcbfc5500bd38e0,
+                    0x2254130217a99592,
+                    0xda3b924bbc07a143,
+                    0xd84aacc826ae9f51,
+                    0x899b5eef795b98f4,
+                    0x284a7fff8074d042,
+                    0x186410bed1d5177f,
+                    0x02ab97c58ea47dd4,
+                    0xeb43c9fb817146e4,
+                    0x92d55b146f5ec1f4,
+                    0xb30353529e09e536,
+                    0x00cc3cbd85b63006,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa35d4b26e34907aa,
+                    0xe3783f4929530489,
+                    0xccf196d0cb0f2393,
+                    0x31c0b57ca15aad65,
+                    0xe99aab01013d6e4a,
+                    0x244fcc35228fda2d,
+                    0xf6c8ca1b802142aa,
+                    0x17d4ddbbd0dfe336,
+                    0x872589b5521d1512,
+                    0xbd83d322e238ce50,
+                    0x2576e9efac8bef95,
+                    0x0021dd46e9e89953,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2e2cd165056b7b60,
+                    0xc9da984814c8d664,
+                    0x5f2c05a901f0c785,
+                    0x1dd42ada62618f6b,
+                    0x4d66a4f07c4c9a59,
+                    0x6f59a0577a774b4c,
+                    0x215364a9b15f304f,
+                    0xe58dd168df1aa376,
+                    0xa3e56f6e25043c1f,
+                    0x42e372252411014c,
+                    0x80e507506e95b2ae,
+                    0x00841f436f073ee3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6df37a1a064f5631,
+                    0xdab3688c03b2d9c6,
+                    0x3ebde6ed54c1320e,
+                    0xa42ae85f84caa1a0,
+                    0xfb88314a467fc27e,
+                    0xe0043dd4c2c33fc2,
+                    0x7532f7761e7cbf50,
+                    0x7df6652ef13da546,
+                    0xbf3c6573a2cd30f9,
+                    0xd817ec393c0194cb,
+                    0x06d6f96b5ef63ec2,
+                    0x00586a1c3bb368b8,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb4dbec106f5b2d2b,
+                    0x21ab442288965b24,
+                    0xbb6aa4dfced05301,
+                    0xce7a1d37cef40547,
+                    0x91030f0f871ec4aa,
+                    0x681dc228eccc1dcc,
+                    0x83111c47e6394a1b,
+                    0xb304fbac16e53b7b,
+                    0x98e747cbdf2b6d5f,
+                    0x9393364dd7a43bf8,
+                    0xb886c4c4878cfee2,
+                    0x001e5a693eab898b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b144d42c771d73d,
+                    0xc20f6722d84711b2,
+                    0x75487b8da251f836,
+                    0x5fa3f315d4f40c5b,
+                    0xbcf87c7d1c3ff45c,
+                    0x2fc150f0f1742109,
+                    0x2661515f2a1eeaae,
+                    0xd4f584b20fe52414,
+                    0xa32d6ac25a79edea,
+                    0x4beef03c050510bb,
+                    0x929e8f8ba2e98d55,
+                    0x0036b40c66373580,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc05382dcb586418a,
+                    0xc1316b3274f6fd9e,
+                    0x483183d92b93d8c5,
+                    0x1a0314ed6eebe60f,
+                    0x4b41c3f4fd39db16,
+                    0x804c089ae150721a,
+                    0xb88d52b99149e1fb,
+                    0x8ebe09c266e1dcbc,
+                    0x1e61f1d991058a30,
+                    0xdd4ca5161ca9d039,
+                    0xeabbcd881e92db91,
+                    0x0013d8bb17dcf5e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2bde728f07b1acd6,
+                    0xa3daa20f05416678,
+                    0x7fe844b7532716c8,
+                    0x254cfdea9f88c482,
+                    0x5cd976d9c17f6fb3,
+                    0x342e047fc8f34748,
+                    0x66f080e37a84de7b,
+                    0x12f3281c0ce21c5f,
+                    0xb3abfee9be065aa4,
+                    0x38a6b4dcf7ee8970,
+                    0x1cd7c2697d76007a,
+                    0x002d91959d2d4c9e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xedb58123f3cdeaca,
+                    0x29c7d63c6c0a15e7,
+                    0x5b3d008a75dc23eb,
+                    0x589a12ae4c7e33c5,
+                    0xbe8cfac415f4b645,
+                    0x3c9d452a8301c0d8,
+                    0x948fadea40683007,
+                    0xf20c5efccf9eab62,
+                    0xc3bfd3d943164d8c,
+                    0x62fd9a8155851c55,
+                    0x23c391ac422b5faf,
+                    0x0081f40783983827,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae01fff182654eed,
+                    0xf8ab65c017f138e2,
+                    0x447c8fce97f6413c,
+                    0x9ed3a36f35ff1d12,
+                    0xa9f9d3f10e063766,
+                    0xa8a2c7b62c638705,
+                    0xc5e31409ba76331e,
+                    0xe9ebfddf568e779f,
+                    0x761bde2e941d12e1,
+                    0x9758320451190ab6,
+                    0xb037f4ad5407b7bc,
+                    0x0074ed97e65ce025,
+                ])),
+                Felt::new(BigInteger768([
+                    0x349456c569b2a949,
+                    0x57b2eb6a5decd7dd,
+                    0x69c1ef5198ee235c,
+                    0x4cd4d40463099c0c,
+                    0xf9fde7a45df393d4,
+                    0x481c4b3ca35b45e3,
+                    0xe08c3a93af468efe,
+                    0x77b4c6db8b222b28,
+                    0xd86172102b59aef4,
+                    0x3a863840f4592382,
+                    0x2c6d267f50782473,
+                    0x000c9bb22931cda7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc131a43803ff3a52,
+                    0xf3c042256a57aa8c,
+                    0x67d66dbfa94062a1,
+                    0x3e68e519b549c142,
+                    0xe791513d7420f90d,
+                    0x115edc2fe4888d12,
+                    0x4d5bb4bb1a32e3a5,
+                    0x1d873146cadf481f,
+                    0x06cfe89661484a34,
+                    0x6bc0d7d23450114a,
+                    0xe92a4f0db9c61ef6,
+                    0x00401cbaf0266436,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea67c3a6bbaba1f4,
+                    0x147facfb4248c398,
+                    0xf8b33d86c92f02f1,
+                    0xffd157066e09f4af,
+                    0x3d4c610f53b7208b,
+                    0xd705c6a1eee76c8c,
+                    0x40bc6725a5961f87,
+                    0x8df17e84572a34b5,
+                    0x24d75b2839857e41,
+                    0x74ba51be566b1334,
+                    0x3d7a1eb043379969,
+                    0x00947139e26cd810,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd4f7ebaee8736222,
+                    0x3aa175e2025cda84,
+                    0x8416ac113b7943c5,
+                    0x8afb4f66d196964b,
+                    0xe702a4db12bccbb4,
+                    0x6bfa3e09654958c7,
+                    0x1c243c1beafd316f,
+                    0x43d594cd00f2cd87,
+                    0xa7cce740b5dbe104,
+                    0x5d83df75f7ff8af2,
+                    0x4082a8ad7b09773a,
+                    0x005046c0070ab607,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4be6b394d2603dfd,
+                    0xb41ad9c760f8657c,
+                    0xe8c4d8b7bb02e81c,
+                    0xdff1be300120a22a,
+                    0xd10d7914cf983637,
+                    0x0ad877329c5d6aa8,
+                    0xf9052f30ca51f27d,
+                    0x33984c77571e9e34,
+                    0x89011f0d2886ffa2,
+                    0xcf5c8e43c59514bb,
+                    0x479f268f18759d69,
+                    0x00d8d19606624dce,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf330001265d1abb5,
+                    0xe364b2dba9414b69,
+                    0xc13bc8b68b015a44,
+                    0x32cf60049bb009db,
+                    0xb76c4de740d448df,
+                    0x73e682cb8432e458,
+                    0x4066e9277cc8a2ed,
+                    0xc49b15c615678f77,
+                    0xc008b5c345056b87,
+                    0xc67abd47c2248db0,
+                    0x39b0cf918b7aa877,
+                    0x005667e7956d6ded,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x585bddda99cbc1e0,
+                    0x8c69cbed301c7077,
+                    0x7b08d939e5d373d2,
+                    0x555fd6e581fe9017,
+                    0xda6f541f6e8403c6,
+                    0x5d77c07614178067,
+                    0x1a7e5f4fa7f29ead,
+                    0x29f9ab4c4a5baded,
+                    0x045c9039949c7dff,
+                    0x34d005db5384c085,
+                    0x468ed91752f4e17a,
+                    0x0068cc74b11efbc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf9b71dc5b9aa47df,
+                    0xdbdb6ee0dd97334a,
+                    0x44c891daf1f9d1b2,
+                    0x3a48cb9a71b49500,
+                    0xf0ce310738cd1acb,
+                    0x364a0c6b62c922a9,
+                    0x8e54683a509a6e90,
+                    0x1c45bf7bc97ce3e6,
+                    0x4f054061dcc9c033,
+                    0x04965a15cd145e6d,
+                    0x531d65303bad9078,
+                    0x00481804d79abfcd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x45ddfd2b0a2be871,
+                    0xe0c8aa9a5d0f0e16,
+                    0x80f027f4c63d0932,
+                    0x945fb6193316dd05,
+                    0x2ab1645a144b48d3,
+                    0x1501d0420fe19465,
+                    0x5af5f0b3fb585dee,
+                    0x86fb87127d5846b6,
+                    0xcddf2d1e035d69bd,
+                    0x271861adfb5ffb94,
+                    0x0a9aa43f8cb07218,
+                    0x00f7ddb58a66526d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4d7267c817062181,
+                    0x2b62fa25134438bc,
+                    0x133d0a28b378b594,
+                    0x30b0af06b5662bda,
+                    0x4d23430f78906b39,
+                    0x564e47e72da7a4c5,
+                    0xaeda1dfff258c288,
+                    0xd239c146b9b995b0,
+                    0x826d7b39dff23435,
+                    0x06149b33d8ea1656,
+                    0x15fed97b9660b2a5,
+                    0x00181b23e1786253,
+                ])),
+                Felt::new(BigInteger768([
+                    0x14fe8417c3dcde41,
+                    0x97c3677d3df65655,
+                    0x5c8170f1f9300bea,
+                    0x7f45a15b79f432d1,
+                    0x395bee639cc94e37,
+                    0x9971ab139a7224c4,
+                    0x54290e15ac22f8e2,
+                    0xcaa3ca3b5ae043d8,
+                    0xc9b31103c5b7884b,
+                    0xa75b9432ec4e18c2,
+                    0x85fadf845e7045f9,
+                    0x0108894b4b88ffc8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0488834bef40b487,
+                    0x92eb58359520a072,
+                    0x50772785e9ca56f4,
+                    0x38048e4980f5824d,
+                    0xe1403183935d329c,
+                    0x55e1c23762b6c34a,
+                    0x56fe603101e0d53c,
+                    0x8e7b10d5610c2c82,
+                    0xab54dea8ccf455f2,
+                    0x37c6904ddd56d9dc,
+                    0xfba4ab8c973444a4,
+                    0x004ecb2650e89f78,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdc5b8d9c4a49b24b,
+                    0x29786856e6c09338,
+                    0x61c9568cd2f151e7,
+                    0x24eebec1af24e5b0,
+                    0x545c5440a96cc555,
+                    0x4a0f574f069cd1c3,
+                    0x6897b1df429fbdd6,
+                    0x591eb3ac3fb825d4,
+                    0x2c1e2b693fa30086,
+                    0x14e8b2885aa40eea,
+                    0x6d73108e9909285f,
+                    0x011cb75513239a09,
+                ])),
+                Felt::new(BigInteger768([
+                    0x044572ed7553dc23,
+                    0x647cd3fdae55abae,
+                    0x2c894b02b47fdab0,
+                    0x477acc5c34751c3d,
+                    0xd9bf65be2a548126,
+                    0x479f6498557673a8,
+                    0xdd12734c72c858b1,
+                    0xdb814e946e9e12fa,
+                    0x3982842d9da848e5,
+                    0x6f9f3abc1f797d1f,
+                    0xd8804e7aa0ecf3cc,
+                    0x0000165842dbd633,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb8441b3474c0621e,
+                    0xc4d7ab89abc4052b,
+                    0x5bc94a11395fe5e1,
+                    0xe52b227be52b9526,
+                    0x1c7a2b12de14b010,
+                    0xcc984210138972e0,
+                    0x888f4ba660d37f5e,
+                    0x1a31eb49fbba4e6d,
+                    0x6189b31ac6e1c2c0,
+                    0x014aa42fa7c8b474,
+                    0x14e629414d8fdd80,
+                    0x0013374ad6d7cdb1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x729fc91e009418bf,
+                    0x3785ad847b916ce0,
+                    0x3bd809bed7b93862,
+                    0x6f31a7b9d3623e4b,
+                    0x7ef2f13d56187e3a,
+                    0x86aa8ad015548db1,
+                    0x406811704f6b8b93,
+                    0x1eb55e9876d688ad,
+                    0x8715a5a97526061b,
+                    0xcfbff2d61b2dc043,
+                    0x3a67f9d29f420b30,
+                    0x002bd0d2e1e50506,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2998dcdd1f147ad1,
+                    0x5235949cd4c890de,
+                    0x1e1016d0ada13630,
+                    0x440cac0a5988ccd7,
+                    0xc07639e3b8813237,
+                    0x3b0e74a192b226d2,
+                    0x9247df01d4a812dd,
+                    0x6a96fc1744754e1b,
+                    0x0fd4918f7354c7d4,
+                    0xf256ca017b4c9f9b,
+                    0x3e9ff2898e5d4952,
+                    0x000b963990b71cdd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa502430eef46deac,
+                    0x29ca535fb8c99dee,
+                    0xda3e92a13a6da0b4,
+                    0x1cf511e0f7d8367d,
+                    0x9aa04c04ca0d9582,
+                    0xa2b86a79682ad0a7,
+                    0x5c8ee06aa98f9b51,
+                    0xf1e06dcbfeb25db4,
+                    0xa0007a8fb7cfa580,
+                    0x45a284b6e3e48538,
+                    0x739901dbe2321a6c,
+                    0x00d980f5e707d87e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x69f9ff30987df6be,
+                    0x2353646479b0bd10,
+                    0x2258f6db7936248a,
+                    0x88e1e2ba92ffc685,
+                    0xb75d7b7644b3e378,
+                    0x17554a5235bc68d2,
+                    0x5f99b98ccfba0ea5,
+                    0x3389e854eb766d83,
+                    0xbe6ff8ead833e06e,
+                    0x4fb1de721622c12e,
+                    0x330738d6a17c9907,
+                    0x0072b384fd828465,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd49ab07b363bf45d,
+                    0xc8ba25bf0767b72e,
+                    0xd7b19fd66e4dc9b9,
+                    0x5f247a0eb59d0791,
+                    0x7481a6c808e86345,
+                    0x09bc0341f23e14e5,
+                    0x2f3b6a1bfae3ee09,
+                    0xfaed01d4fb004e6f,
+                    0x2885bc0b056189a3,
+                    0x337de5a29a233826,
+                    0x2d83fcd1b6682ddc,
+                    0x010ba8041d270bd6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1f5ad0a95ba41d7f,
+                    0x399588eed4ad950f,
+                    0xb727a0cb55f3f257,
+                    0xcb374ab583cf5761,
+                    0xed396968aed9f0a2,
+                    0x4ad9cb2678a4d2bf,
+                    0x47d33cb870805723,
+                    0x72ad979416cd0788,
+                    0xff7698783117b7ea,
+                    0x3f72c50f38aae0a2,
+                    0x2f339a3e55760286,
+                    0x0095c2cfb4dc3ce1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfe6552131e2de92d,
+                    0x989d20b9e02dda75,
+                    0x3dea2577cc738686,
+                    0xdf150b868c148572,
+                    0x40f1bd6107206100,
+                    0xe2d35017b01ebc9b,
+                    0x8eeec0e1d446f5e1,
+                    0x905048cd3e666a21,
+                    0xa5d693c0b6a5ecf0,
+                    0x90e76497aa921a05,
+                    0xffba1fd1423217b8,
+                    0x009905a634cccc3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x672a66d9339c5c0b,
+                    0x3cba8439e1c66a64,
+                    0x8483093de4b2eed2,
+                    0x01e5020093e30a1f,
+                    0xdf7cc36769a57c46,
+                    0xd9bd78b4e916d964,
+                    0x4de8bbf2c4b76009,
+                    0x151a59f9b51f8e16,
+                    0x3ee74f621567bce8,
+                    0x8d6f8883d323d37d,
+                    0x7e099189d57877be,
+                    0x00ced259d58fc255,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaec6fb1972fb732a,
+                    0x1449172302821765,
+                    0xb95df22cebe9783e,
+                    0x14cfcaa656f9696b,
+                    0x99be0a503774a0dc,
+                    0x5be506273a4b66be,
+                    0x419b93401f1a4e2b,
+                    0xf74f8a18d3d497c6,
+                    0xa390b2f38083bb87,
+                    0xb0f891ba32331231,
+                    0x5ac9c7465f7b4dd0,
+                    0x00f880847b3f4170,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d19bbad01f2603c,
+                    0x6e8622d3637ae610,
+                    0xfb41c13d03f16b69,
+                    0x30a5d75fec8aaa03,
+                    0xac2633d3e17aab2e,
+                    0x5da6e7bcd3fbf593,
+                    0x35e7f764bc9af37f,
+                    0x399ea3df52749379,
+                    0x546245a44e38b16d,
+                    0x536c8d57fb7032b5,
+                    0x5ad5509a243b483a,
+                    0x00fb60ce2774e914,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4ad852eb152bcabe,
+                    0xe644ee29ee9fc58d,
+                    0xbb00f6085e9f43c8,
+                    0xbcbee78b2df0d82b,
+                    0x1c1ae797df597721,
+                    0x2443b2a8be8b2eea,
+                    0xc22d6b76a3644314,
+                    0x3522dbf3e3a14b87,
+                    0xb8fd76f3695cee19,
+                    0xd352097b165e338d,
+                    0x5d3805c41b9d725b,
+                    0x008a7f090bf0af36,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc18a787bd3879dd7,
+                    0x7fc04334a2cc5b28,
+                    0x53cc20a1cc272877,
+                    0xb4bfab156ff8763f,
+                    0x6dce716ff401d943,
+                    0x3d7357d15da8623a,
+                    0xa3029009f8e2ece4,
+                    0x336de1b1ca29ba6d,
+                    0xe860ffc3fea5fd50,
+                    0x88fdfdcb823072b0,
+                    0x862b67fe44d5ad74,
+                    0x00c9649d9033940f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd6cbb8be67f067e9,
+                    0x80b7e4f1854cc40e,
+                    0x71578d95535d72f1,
+                    0xc322d3d9dad3f4bc,
+                    0x01414ce5fccb4546,
+                    0x63ef9eeba741430e,
+                    0x5948a3f00031e17e,
+                    0xf4161ec15c66fb22,
+                    0xb6f8ec02b93b7ac8,
+                    0x46afb4de2d653808,
+                    0xaab33b8f18e8af24,
+                    0x003061424f2eee6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3f6f577a855367a9,
+                    0x7813bef50bce7e75,
+                    0x0009a92dc0163c7a,
+                    0x230ed12901ecc771,
+                    0x1c339429a4dd2adc,
+                    0x59c88d4e9fb939e9,
+                    0xc7be4b7d518ebaf6,
+                    0xfe0695c60914ad77,
+                    0xdccb8157e4091b6e,
+                    0x09d02fea523eccfe,
+                    0x3e32b7bd718ce62f,
+                    0x00bdc4e7cbbbf8f3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x60021a64d000fd2d,
+                    0xf1bdeee60d2d10fd,
+                    0xa84b55f1ad780f4e,
+                    0x7770accc0da45f1c,
+                    0x0226e6710829b502,
+                    0xd208180fc312cb44,
+                    0x58c79adf397eb8a7,
+                    0x547a464abbaafb17,
+                    0x59c531efc5fdd279,
+                    0x2ab3b3b38a16bd09,
+                    0x7cb7453d0e165fed,
+                    0x000e841f99b1a384,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5596826173490e10,
+                    0xbb6c9bf00022440f,
+                    0xe457df87eb24af50,
+                    0xfd7c7c253d57779d,
+                    0xbc159152f8043179,
+                    0xaeacbab828dfe870,
+                    0x525db65969de64c7,
+                    0x2dcf3fb7ad623ee4,
+                    0xdc47383bf1cc9b04,
+                    0xf8c84d62d9c7a900,
+                    0x2a0229c97ff63a55,
+                    0x00ac992d8299d088,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe953bb550801eb3d,
+                    0x716ca41292afef97,
+                    0xd0373c7fa1078e67,
+                    0x2cb7fb62b3edb5bb,
+                    0x5a44a08be971d000,
+                    0xf69645097cdafafc,
+                    0x69cb3ebe095d4426,
+                    0xae85d1fbeabdcef8,
+                    0x5e5dc9b6a50f1ecb,
+                    0x8674953ea7ea91c5,
+                    0x7bd01503ff37287c,
+                    0x004b84fec3a8e104,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5ea341ca0e80690,
+                    0xb537ab16c0fa233f,
+                    0x561bd815e5c36b5a,
+                    0xd2fbfb49c7b110c1,
+                    0xc0ef96c9f942b1bd,
+                    0xc5a61021779c8e53,
+                    0xa4e185912446ba92,
+                    0x51e2803b93bcfdc5,
+                    0x670c4b5be2c3159b,
+                    0xb225349a574c06f4,
+                    0x04d940d5c6f00cc9,
+                    0x00b7e7fcbe13a026,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ac6a931d1ed4c7a,
+                    0xf9f70063f41fcd83,
+                    0xc9995cc191fb8a68,
+                    0xded3fa19d5ddd262,
+                    0xcca190b652d35dee,
+                    0x26362e48d3777558,
+                    0x4d660163dcbce354,
+                    0xa0c4662df388ef8b,
+                    0xcaf2d7ffc326300e,
+                    0x54408a1dcadc83df,
+                    0xa805cc67469c32a3,
+                    0x00713583031f15a9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7dfb93e216873923,
+                    0xb9051e8d57c33646,
+                    0x331d19fbba5c7a52,
+                    0xe403feece4a4f7af,
+                    0xfce5dc0d697755dc,
+                    0x22ee5ec78eb98bf7,
+                    0x6ec2c36e5262f6b7,
+                    0xac111e5e8222faf1,
+                    0xadba2288c54b4f44,
+                    0x5c22bb339f36ef65,
+                    0x75d430d8e795bc24,
+                    0x00ab713a05e090f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x73d79eb8f0a3289d,
+                    0x53263ec82ef0f5ea,
+                    0xabae05cd32354b07,
+                    0x42065f15292f7375,
+                    0x19c7e7d6fbf50691,
+                    0x9f0a81af17cf86df,
+                    0x0cd2e4ad6e857a04,
+                    0xbd48da229d19618d,
+                    0xa6ae662bad8332a8,
+                    0x58def0f8544bdfd2,
+                    0xb481921ceb1ba19e,
+                    0x0016c2b90447743d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc58f5c5cb882781,
+                    0x2887bd5f214a5ff8,
+                    0x7dec78c306db0dfa,
+                    0x4c486e58e69b2cb8,
+                    0x6648f7c19b3faef9,
+                    0xea74b954b8ae9167,
+                    0x616414b4c72d4ee1,
+                    0xb1e9f84e234d04eb,
+                    0x03cb860e1dd7c310,
+                    0xb684f6834642458c,
+                    0x9cf70d6269703d78,
+                    0x01051456de8a79f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3ae785996c83685,
+                    0xa3476942c3c882da,
+                    0x4e4bbe1457a9af92,
+                    0x60f8bb658ba2f3ba,
+                    0x983b1e698c74e4d3,
+                    0x821201e8d3355ca4,
+                    0x134c88969afe6868,
+                    0x28cf84813220452e,
+                    0x79f9de19f8d971b9,
+                    0x2baadc56d3e22d18,
+                    0x7dbde810d50a25af,
+                    0x00eab7dc09067212,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9fb3870d3c2e5e3e,
+                    0x483beed0de9ab6ef,
+                    0x33d9589a9d14932a,
+                    0xb34bb583c423f8e3,
+                    0xdb119e0415fe12fd,
+                    0x7f6bac14b432fb8a,
+                    0x8f28768f66495429,
+                    0x7ec0186b47a558bd,
+                    0x8fb881b98d7b8f97,
+                    0x2bf398eebac3182c,
+                    0xcc449bc5a7d66601,
+                    0x0028eef3f13db8a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x056e7da762bb15f1,
+                    0x84ef4bb91f8a0fee,
+                    0xf881dffab8be7304,
+                    0x8868714de4467461,
+                    0xacd702e4984e714e,
+                    0xcb98d7c221972eb0,
+                    0x68b6410bc93fa8f0,
+                    0x91b061e503c9e987,
+                    0xcb7361b184fd0cbe,
+                    0xa231459693449f84,
+                    0x4f66d9230f39b878,
+                    0x00294c78d982ed17,
+                ])),
+                Felt::new(BigInteger768([
+                    0x827f065d1d6b2665,
+                    0xad0db18f88dffa90,
+                    0x22960c0ad47c7b93,
+                    0x42c124d031101fc9,
+                    0xf398c2bb740bc104,
+                    0x5ac9e02beeaffe4e,
+                    0x818a0f92d7e1787d,
+                    0xd6c0b504326b50e3,
+                    0x91731f347b5a00d2,
+                    0x3d6ec87bdf752a3f,
+                    0xc6ce95edb83d5132,
+                    0x005e8629d44dc72d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x942a0a6c743754be,
+                    0xc0ffaf42c333103b,
+                    0xc88b070eca00da00,
+                    0x669f4d9233a88e13,
+                    0x6e1329cd75e446fe,
+                    0x4bcf2ec4e27219d5,
+                    0xb59323e4e48e85fe,
+                    0x5fd6497f680351a6,
+                    0xa78e870b44dd0761,
+                    0xcb2a43db571f73fa,
+                    0xa255725024575943,
+                    0x0034e8d34d1453b3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xec1bd1661c79d760,
+                    0xb3f9e11e9f3461b1,
+                    0xdbe6cb822a612328,
+                    0xf0f0f16864606697,
+                    0x4a6c91eec1ce1273,
+                    0x52a243b059732a9b,
+                    0x1b74c54553e9ceb8,
+                    0x384def2ca40ca7e2,
+                    0x4db4b3e56d824e85,
+                    0xb5df73c4cd3e153b,
+                    0xfcb540089002d67f,
+                    0x010b185456614838,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8c86b68f6aa9bc35,
+                    0x35e9c411be3706a2,
+                    0x5024ad1ed9135c0e,
+                    0xb64b0d4e6469d447,
+                    0x241033a54557fe6a,
+                    0x4f82ea1322e2850c,
+                    0x63ee54a4fc477eed,
+                    0x4f0159716c6a3ba7,
+                    0xb3098b33a73d326d,
+                    0x8c4bc97faf453b32,
+                    0x51a8646dadbe7766,
+                    0x009bb54f25c975b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50094407dfa746e5,
+                    0x939ffdb3b5aec40a,
+                    0xbd142cf50b9e9135,
+                    0x11e23e27746dacd6,
+                    0x73f1be72a392e4e3,
+                    0x561f03eab4aab75b,
+                    0x0bf9197c7b9fad6f,
+                    0x3c9515f636c93d27,
+                    0x806f4365cb5fb8ad,
+                    0xeac6440b54398e0b,
+                    0x469a41d5701fb833,
+                    0x00ca02b5607d3f10,
+                ])),
+                Felt::new(BigInteger768([
+                    0x947149b0138fa05c,
+                    0x37f772adb73ed65a,
+                    0x055a0ebd207fe64a,
+                    0x947b8d37f989f9e2,
+                    0x2222400ff01e6b80,
+                    0x7a557d1601047c77,
+                    0x16876ec3220ab025,
+                    0xde3fc163367f8db1,
+                    0xe4d543b72bc11126,
+                    0x20a577ae240c2ae6,
+                    0x4cdd3696694d19ee,
+                    0x009776de330f003b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbbfb9fc6714b4976,
+                    0x88caa9dd8b9b5f2c,
+                    0xecdaeb207a47f800,
+                    0x5820b3ac9216757d,
+                    0xcec103ab04fe8c48,
+                    0x28adfda0508b6238,
+                    0x851a2bd9dd587c73,
+                    0xfa97b3837eec7610,
+                    0x164e8836ed8d3aab,
+                    0xe1dfe1e04b1d3ede,
+                    0x00be0a52210e4af2,
+                    0x00691a7163c51b3e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc600b92046a7375a,
+                    0x25a789ec845a34c9,
+                    0x35a2c67ee13a3921,
+                    0x2486a35c7d7ed003,
+                    0x1332e75ea93855a8,
+                    0x547205ed6697c10a,
+                    0xc1a322368caf2eaf,
+                    0x254697889897ed0a,
+                    0x8022f510ac421f43,
+                    0x9a877b0778fc2fd5,
+                    0xd6e8c32cd73ee64a,
+                    0x0079397a45568379,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03c0defe7013f1cc,
+                    0x575afd3330079da1,
+                    0xa36fc38879a723d5,
+                    0x874a13503db74353,
+                    0x2d743c40ae657340,
+                    0x74350f23fc73740a,
+                    0xb4de41406dc81018,
+                    0xcb7e2c87a1fbcc3c,
+                    0x99de619d1b52235a,
+                    0xd54d85da04c301d4,
+                    0x95f25e72639469f0,
+                    0x00c2477099645ade,
+                ])),
+                Felt::new(BigInteger768([
+                    0x942ffc73bf64f6a8,
+                    0xae0cf36877ad05e5,
+                    0xc19a392e8de66ccb,
+                    0xa5ecee54b2418dae,
+                    0x953feabec2563d2d,
+                    0xb7b3f2cbed6f8f0f,
+                    0x18a8c7e036816254,
+                    0xf6f3c120b59741c5,
+                    0xfff32a27333067b2,
+                    0xb404432d8b0566fc,
+                    0x98485c48e414164d,
+                    0x010236926e45e829,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f4f76aefefd3037,
+                    0x13c586de69c7e5fb,
+                    0x4810c02ca4e31295,
+                    0xd6f33c1cfe86a8cc,
+                    0xb77d379a1500aa87,
+                    0xe430f3cd84a5d959,
+                    0x6c74e39dac32bdd6,
+                    0xbf9f8a7219320af8,
+                    0xf55f6c5be34c8fd6,
+                    0x2dd16637acd13d42,
+                    0xc57a607189d8caf0,
+                    0x00d1dbff486cfd63,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeb65b7c0dd057b30,
+                    0x71a7740aa15b7784,
+                    0xcda4e301b5fd16e9,
+                    0xccb36531a6b963aa,
+                    0xdf82fdc9702b2661,
+                    0xcfff8b27f724f18a,
+                    0x84c5ab35740d4be0,
+                    0x788421daabbea311,
+                    0x083feb0ee2d16c25,
+                    0xf4eb7f46b9c853a6,
+                    0x9bacbe7aa9208539,
+                    0x008e230e2492cb31,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1f7c4979bb798357,
+                    0x77df7c2d3e59308c,
+                    0xd482c4cb56a353e2,
+                    0xe620f6e4965148cf,
+                    0xb3c66e1a3d4076de,
+                    0xee14ee4fa32c9fda,
+                    0xad97ef61eb3216d9,
+                    0x045996d894eb26a9,
+                    0x18d9ceecc936dfca,
+                    0x53e5d1897c60df4b,
+                    0x6304392ab1a1b9aa,
+                    0x00ddb6fe3d7f187e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5017b0c6207ecd8a,
+                    0xc38bee81812468ea,
+                    0x528c9a03921a8f04,
+                    0xa6b94eb071738cb8,
+                    0x9c5055faf1ba1448,
+                    0xda68f348c3cde37b,
+                    0x6071f4481cfa3a5d,
+                    0x7de97cdac60d5f5d,
+                    0x3bb49e41263437ab,
+                    0x7da704b94d03e89a,
+                    0x7a883f69a25c358b,
+                    0x00995d57d29b665f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6f33113add613872,
+                    0x0ed67fb796881dce,
+                    0xd8f785af3eb524c8,
+                    0x2a03ad247ce3f34e,
+                    0x1217ba37d59b3b09,
+                    0xcbff88ec2388437f,
+                    0x04051856a8c4d600,
+                    0x2538f782944598c5,
+                    0x32050e8b4a2387ec,
+                    0xbba7488d60b682c4,
+                    0x845323024554dfe5,
+                    0x00993bb8f2439a9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9ea1e2281577d344,
+                    0x114a64391f0a474b,
+                    0xa2683243bdee79dd,
+                    0x4240f29b74a8340a,
+                    0x0246a3b405bf2491,
+                    0x5f2aee0960e4fb0f,
+                    0x2be2ff13b857e03e,
+                    0x9c86bea8d0855565,
+                    0xd58fead7985d32e5,
+                    0xd6b4bfa2e33ed1f0,
+                    0xf539d39275f9bbe3,
+                    0x0057a418f812e60e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x67ea0a70a270534b,
+                    0xb690f6d75caccc60,
+                    0xcd9870b5bdd14b63,
+                    0xc6225672986c17d7,
+                    0x58befb29dd264f73,
+                    0x2f50725743d13c77,
+                    0x9668ef396eec508f,
+                    0xa2eae79e133f6632,
+                    0x417b369c5e9ccbb2,
+                    0x0e5cea510a7bfe15,
+                    0x1d6d5824db0b9dbc,
+                    0x006d2b6f1150f233,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36da84a3e9ad5edc,
+                    0x830aec5bc375d2c9,
+                    0xeca0f61d7cfbd8c8,
+                    0xfdc836cbb164d339,
+                    0x1dc15701aec3161e,
+                    0xf1709e867e8fe529,
+                    0xdbd7cdad6484fa5c,
+                    0x9f533695819f8265,
+                    0x6cbb921410d255a0,
+                    0x6eb80cb92321dd1c,
+                    0xa3ce81acf6e79ef3,
+                    0x00fcf61d52766408,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd02800a2158c5dc4,
+                    0x3d1e5477cd9a7beb,
+                    0xbc977fd60e211b9a,
+                    0x6a3b8aef1bdb8eba,
+                    0x6305e1517fa01d30,
+                    0x28fd193b65c7542c,
+                    0xb82332aed4b6ad0b,
+                    0x12e59dc0f221469c,
+                    0x5f34cf7321a41b42,
+                    0xca5bf53eaf05e5ee,
+                    0xe17668e63a8dd574,
+                    0x006fa45a4efe68c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4e8ae864d460528,
+                    0xd3eb799ec2d685b1,
+                    0xa12d2d7d13e0823b,
+                    0x5b6ad68c71087e54,
+                    0x086bf116bbda02e2,
+                    0xf27925cc93826440,
+                    0xa98c0ccd74d6e2b3,
+                    0x78c401bb4f336fde,
+                    0x0786fef64aeda4fc,
+                    0xd86778a20b4874dc,
+                    0xb1d4e218b202f7f9,
+                    0x00c496922d64c0ac,
+                ])),
+                Felt::new(BigInteger768([
+                    0x47bc397be614481e,
+                    0x1a164d78a5a5e36c,
+                    0x4e95f1403551a427,
+                    0x8496c05075cfe685,
+                    0xe67017130173d744,
+                    0x0a9526802d83997a,
+                    0xaec645117264140f,
+                    0xbfac4ef7058d592f,
+                    0xb2c87d3c61473c1a,
+                    0x11283639437dee7f,
+                    0x967650055153a085,
+                    0x002df627e1bd5960,
+                ])),
+                Felt::new(BigInteger768([
+                    0xddf63ce51995834f,
+                    0x92c85dd1571bcc02,
+                    0xaa35eab3cada42d8,
+                    0x75d9c2eaf138ffd2,
+                    0x8ce1b299397e1937,
+                    0x2c9d577cef871725,
+                    0xaeea301ad7abe44d,
+                    0x0562950e7e63af83,
+                    0xb5fa77c141b1e376,
+                    0xafe3df52add60f9d,
+                    0x6c271a636a5f82bb,
+                    0x00098721e54153da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7618c75ee10f94cd,
+                    0x0c6427987badbca1,
+                    0x454bcc090481a303,
+                    0xc775f6eb57bd1021,
+                    0x0b12ab7810bba212,
+                    0xe93359e1732c5e67,
+                    0xc79169bc07577407,
+                    0x8bedf51161268dc9,
+                    0xe90016162040aef8,
+                    0x7ba0587b40274217,
+                    0xd40df54dfaa462a8,
+                    0x003ea0ebbb4670ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0f5fd275b12543a,
+                    0x19d27dd1befa2889,
+                    0xa28a4797eefa54c6,
+                    0xb152d313204a45b3,
+                    0xa7eb0d4b11c91825,
+                    0x2a4a6c51972243d1,
+                    0xeef585f901c3a166,
+                    0x86a86078c5041c8b,
+                    0xd4da4e590263ae0d,
+                    0x6b06cdaaadcb1326,
+                    0x1a3a8898c45675ec,
+                    0x00f9de31b4f9be1f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa92b1a19175abcb7,
+                    0xf10aa53e57fea6e5,
+                    0x62d434e4164b3da2,
+                    0x30009de3982a4959,
+                    0xc52aaadbf089912b,
+                    0x5b3a3464abf2d0d8,
+                    0xa1f9f576902283f9,
+                    0x92add1f9e0c5212a,
+                    0xb71e816b33702b3b,
+                    0x4de87b63ba7eab1f,
+                    0x497f229099e6936b,
+                    0x002b3e1c99dc97b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x895159a676895969,
+                    0x3675aab0d875bee1,
+                    0x58c6ee5502d3d279,
+                    0x1806ac41218025a7,
+                    0xdb08e23736989936,
+                    0xb7cf1fa0d24d2c09,
+                    0xcd5d7b5e74bc6e89,
+                    0xfe0f1f35847026c8,
+                    0x7d0af8d98489a1b1,
+                    0x3d4835a35dca35b2,
+                    0x289517f7b7bb1f7a,
+                    0x00ddcc36dcfc5adb,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd0d71cd514688f2f,
+                    0x55a05582cadddc5f,
+                    0x8a8a9145ab95176d,
+                    0xbe0576b91e94b5c8,
+                    0x23f127dfd13164a6,
+                    0x14be15bf6d66a494,
+                    0xebbd2d31a7658ec5,
+                    0x252d2822951f9768,
+                    0x89ba86d9e2798d1e,
+                    0xb323098fa753754a,
+                    0xa6a8d33e35c696b9,
+                    0x00bd881de26948ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f8368c6555c5bb7,
+                    0x48d20922f5fa6b9c,
+                    0xb52fb64f3336029e,
+                    0xd61f85619a4e3da8,
+                    0x1fa9ca4ab3683d9c,
+                    0x1789058c43209b7b,
+                    0x549c36fda8e43ed8,
+                    0xccab52553a3143f6,
+                    0xb2db6579efd5cd5a,
+                    0x8113ad9a36b7732e,
+                    0x6bb2719eb283d0a1,
+                    0x00ded50793334d7d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2880914c5fbd3cc7,
+                    0x4c78561f032845b3,
+                    0x809b5c664fb06529,
+                    0x57acb003b3a6d4ed,
+                    0xc3e846622f8d2b8c,
+                    0x2a823e39e39d15cc,
+                    0x8109c062668860f3,
+                    0x5c45d7eaa1b9ee58,
+                    0x773b00bf9027ba81,
+                    0x73db801351999193,
+                    0x68bd84fe4238d447,
+                    0x01195c3a60ac12d0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ba2a7d4b9151456,
+                    0xb0cee29570b5ca99,
+                    0x6edcab48eb5465f2,
+                    0xbb272541440da6dc,
+                    0x6ecb3ff8cefe64f4,
+                    0x31b6c1185a51e2b3,
+                    0x4ae86b801deee338,
+                    0x216239de760d1199,
+                    0xb7cc8cc102583c65,
+                    0xb16029b18cc8e736,
+                    0xf8d37fd4afa63219,
+                    0x002f38e5440e1b31,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90e732aa4804d210,
+                    0xa142f578f5c4a4bf,
+                    0x1305f8e821ca92a0,
+                    0xb88940955b4cfb0c,
+                    0xe32d80e6b2f284e1,
+                    0x8d1b469de443cc0c,
+                    0x800fcfd4b28d7d7d,
+                    0xd81d3f1317d6b648,
+                    0x65faffba50cdfceb,
+                    0xf3112e737bf8eb8a,
+                    0x41d9daa83dfdf96f,
+                    0x00e9bfa948cea203,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b689a7c259d7f1b,
+                    0x693f0a0e7b722526,
+                    0x5e2b9f4b763477d0,
+                    0xc017209737ee1974,
+                    0xfadb13a139fcb76a,
+                    0x54e51ed82c35cb3a,
+                    0xd02c58a905a70616,
+                    0x43f56f31399a8710,
+                    0xae774e7f5ce1d00f,
+                    0x909755fa64a84748,
+                    0xe028a3dc1a3bfcb4,
+                    0x00281e37df05c629,
+                ])),
+                Felt::new(BigInteger768([
+                    0x57bce9b530187983,
+                    0xafa446a9860f335d,
+                    0x89c49aa41a1a9a19,
+                    0xf43b65bf79322742,
+                    0xf2ea6799aca8e85d,
+                    0x67755c665d23000f,
+                    0xa76b4577fb3b35fc,
+                    0xa3452d53329cc7ad,
+                    0x6f43dba796804dab,
+                    0xde5b673c325b283b,
+                    0xca0639d7f88eeaed,
+                    0x00953f3110dcddb5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1abeb7e07ba886ad,
+                    0x0ae9963da65b10a8,
+                    0x3ba6e9524794a62f,
+                    0x8023e5ec52071620,
+                    0x26ac28f9509b2219,
+                    0x11313aa0b95b05c9,
+                    0x9877d4e4c62aaf45,
+                    0xe9948b09f1990b13,
+                    0xc6963ac790148f37,
+                    0x3def6b29f1e11389,
+                    0x0acdb7acb3ef58f4,
+                    0x00f57827b23c344e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x13cacfbbc6656ba0,
+                    0xb56dfe691c747abd,
+                    0xe5f87f2e2b15273f,
+                    0xfc2394a898e28c55,
+                    0x94998b0e2beae024,
+                    0xbf4fcd03e34112ab,
+                    0x7f49d6c10f188b23,
+                    0x84ba995ea3826a17,
+                    0xe1c1451691b85ca7,
+                    0x6600f3d5fdb0c54e,
+                    0x27111bd71646da35,
+                    0x0095f674e76e0257,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8cfe84f9726f3283,
+                    0xf384447fc6419baa,
+                    0x4937cfdc315c4cfd,
+                    0xdad3536affaad226,
+                    0x3190fb6c19738890,
+                    0xb04f070d2c184614,
+                    0x0824aa77f4e9e6a3,
+                    0x83f106decbb4365d,
+                    0x0c8721e2cf3dceda,
+                    0x5a92af452cc5dd39,
+                    0x2af7aade96941082,
+                    0x00ae5dee5b62c730,
+                ])),
+                Felt::new(BigInteger768([
+                    0x06951d93a0a5442c,
+                    0x0469fa6581df5fa2,
+                    0xb7ffa70993881047,
+                    0x8fa548f81bfcf7ac,
+                    0xd28c71b98ea70c5d,
+                    0x0aac5552f308bc4b,
+                    0xda766cc3ae455dd5,
+                    0xeec5ac25ab0e6dd0,
+                    0xbe85f34865a2788e,
+                    0xc0590d89bd7808df,
+                    0xb7b852c7d1661728,
+                    0x011315fd45b58168,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8685134d4b306cc0,
+                    0x2c2bcbc14a92335c,
+                    0xcc467199a2651f85,
+                    0xb9baba73be21701a,
+                    0xfd16aaf29bb840ef,
+                    0xeae947554d8cb932,
+                    0x897e0ac4c1463f2e,
+                    0x56420b5b9dbbf5ab,
+                    0xfe402f8d706473cb,
+                    0x0106590d5bad079a,
+                    0xeed1b61cc2cb6497,
+                    0x00168dc0bc82df74,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x31b0368707b4dbb2,
+                    0x7eae8d0996679f88,
+                    0x9c62ebd1d4d6db88,
+                    0x83c86746bae27cdc,
+                    0x6b378e3065832b82,
+                    0x7b0eff9305c1f346,
+                    0x3952633b325b3c24,
+                    0x36557bf14d4dd4ae,
+                    0xbee094ef0dadb4ca,
+                    0xfa812b665543843b,
+                    0x9cd606b426c85c57,
+                    0x003a12c5381acdf3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c5080cec4909e4e,
+                    0x013354327e3c5442,
+                    0x19f7af6295de617b,
+                    0x521fc48a893a576e,
+                    0xa1a2379918742b4b,
+                    0xf9638d2270b65f73,
+                    0xec8657505394650a,
+                    0x8b4f6ff59f663dbc,
+                    0x390d8c6ea44c4a48,
+                    0xce4773b2db996e50,
+                    0xc1cde25780d2ce74,
+                    0x00571565fe48b4a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a1e259d5e7d26aa,
+                    0x1c22d438e046edbc,
+                    0x2968684627eda5a4,
+                    0xcca82265d7d8b4aa,
+                    0x4426c8dc9ad366e2,
+                    0x251d8d1ce8c1f276,
+                    0xc3e9e5f5c4d9bfc8,
+                    0x47208d7f273501fe,
+                    0xf470ca709f62943c,
+                    0x5c03d7147d550591,
+                    0x10b90e0b59fa4443,
+                    0x00a65be80f09468c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa5ef8e68a0ba2fad,
+                    0xda8819c433223d71,
+                    0xd75688408e8eb9e2,
+                    0xe57f4d25acb127d8,
+                    0x9c0984418be81faa,
+                    0x4139c8548f4ce4e7,
+                    0x3e15d9153a435f50,
+                    0x5f99070a8252a4e3,
+                    0xd0ef06bcd0bd0f43,
+                    0xc356b5cbfe28cb9a,
+                    0xff236c647ef0a5b0,
+                    0x00d0c258b18163f9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ceccbec0c41a273,
+                    0xe3a8e8b57e6ad9a2,
+                    0xc777e66ef90090ed,
+                    0x828ff372e0cd330a,
+                    0x252f643708fd15e3,
+                    0x4facbad02afa9fc9,
+                    0x5f9c75f2c0c086ef,
+                    0xfccf40be2aadea9b,
+                    0x48fadfdca498814c,
+                    0x43fd9f1256d5be69,
+                    0xaa4a5a90b723d958,
+                    0x004c1e850a02e854,
+                ])),
+                Felt::new(BigInteger768([
+                    0x88d937cebaa8a45e,
+                    0x3fe5819a36f428cf,
+                    0x89732efae294cef6,
+                    0x1bf88483893d1870,
+                    0xc7b0aab3a98d67ef,
+                    0x4089b3216e95bcb6,
+                    0xa2d63a62d91e1218,
+                    0x0b0fb05d70f0fdb9,
+                    0xe17ea584889f9b92,
+                    0x2168b32083f80144,
+                    0x290cb9c24ff70f8b,
+                    0x00999cbfbf7d7f55,
+                ])),
+                Felt::new(BigInteger768([
+                    0x07603e4a23230361,
+                    0x8cbaa55e0782c0c9,
+                    0x0a283492963d8486,
+                    0x651ad7fd309962d6,
+                    0xb61ca65c6187189b,
+                    0x96e96cb21d5803d1,
+                    0xcfe257cef9d3c3ad,
+                    0x2bc4cf5cf5e07e3a,
+                    0x9de86771440ff77d,
+                    0xcca84b2e9266cb51,
+                    0x58659a5c38c2b305,
+                    0x0014d4883d9f183a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf65e976a591998d2,
+                    0xe05f29b4323a4d66,
+                    0xf5dcbf73845286b1,
+                    0x58a71f6df3c6fdcc,
+                    0xb20bfb2f70954cca,
+                    0xa5645aa8f4a6122b,
+                    0x131fdadf443efbcf,
+                    0xa043832a2e943e78,
+                    0xb8698678e258b984,
+                    0x5ecc5c9f5d249e5c,
+                    0x3bd02fb4a06cd3d4,
+                    0x00c306b8326018d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9c1898e0f14f5c56,
+                    0x293df8fb814cea0f,
+                    0xb8b9f0c840fb7ffb,
+                    0x65c2834be8761d27,
+                    0x35579882cca8e701,
+                    0x3d8ad5295344fc00,
+                    0x57c1f1bc05bbd64a,
+                    0xd0552e40568f3b03,
+                    0xb0446afdb0d549d2,
+                    0x175f1099ced8d998,
+                    0x444802c85c42e004,
+                    0x0071f351b988d128,
+                ])),
+                Felt::new(BigInteger768([
+                    0x69131e2628ba1f3d,
+                    0x03a86f724451b61d,
+                    0xa2288653743e19ff,
+                    0xed470e13827e1527,
+                    0xdc133f7697133fbb,
+                    0x39c94b111860d548,
+                    0x9d103e2e336fa286,
+                    0x1d2189394d2d7e89,
+                    0x8739c50a830c7ef8,
+                    0x4392b0db05127386,
+                    0xa0752b0464acd5dc,
+                    0x003be460c0600b0c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd1023c5d9773538f,
+                    0x8b8e02d81dbeaa61,
+                    0x92095349545ad345,
+                    0xd83b82e564d14ca6,
+                    0x2b24fdd36315e9e5,
+                    0x0bef80d163cc4cf0,
+                    0x6df7b6e4e3a71673,
+                    0x5256657fd0486671,
+                    0xefcbf01289cd1977,
+                    0x1611be63c90831d3,
+                    0x8c020343f8e816b3,
+                    0x00deba2795a0891c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf2ba34bb146fc27e,
+                    0x0518c0d49d8faf69,
+                    0x250d58fee4478c55,
+                    0x851f39862393c6d6,
+                    0x3a294e9003476125,
+                    0x01b2eb83eff065aa,
+                    0x959b4bfcec946600,
+                    0x3835cb825369faa9,
+                    0x9d08bb2ebc3a173d,
+                    0x9bfaf7ea329febbe,
+                    0x9c9fde47636bf7e9,
+                    0x00b3d82d9bf62906,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x965bc9bb811c8451,
+                    0xf0d7bfd23a1ffd2b,
+                    0x7da53db485917fa8,
+                    0x8a614f6080c72a14,
+                    0x4db6723886c243b8,
+                    0xd1505ff709552af4,
+                    0x02101f6d9ce6a2b4,
+                    0x0a6b7e74942c3441,
+                    0x670048be8cd4518b,
+                    0xe4e320735ebd3369,
+                    0x538a4c9bc87b19fb,
+                    0x0049c482a444e451,
+                ])),
+                Felt::new(BigInteger768([
+                    0x104f2f33e582b7d3,
+                    0x05f4cd35ddddc4c7,
+                    0x85bbdd2304858e90,
+                    0x5d88b7c78176a61d,
+                    0xb417b9d4e2d707c2,
+                    0x3f87697431ca0c86,
+                    0x4eb1075eab4d9474,
+                    0x3427b5833ed0c39e,
+                    0x91af5ed120402eee,
+                    0xab1a332ed4e71298,
+                    0x406e98eae5321664,
+                    0x0076c2de575322ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0c744dcfcdb6957,
+                    0x9423e0aaf8713f3c,
+                    0x459f2efd63f3d610,
+                    0x9251b66d8ff73f21,
+                    0x31798b1d1d4fcc05,
+                    0xf52ad4f21276d882,
+                    0xc271070878da91d2,
+                    0x673de1286d4ec005,
+                    0x893674deb40c8fa2,
+                    0xdffe821c41a39189,
+                    0x8de8cb816a777774,
+                    0x000b71082a5f3c90,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba839784def093da,
+                    0x760b83d6d4dd48f8,
+                    0x57f69b98bb73892a,
+                    0x00acfbf31595c305,
+                    0x5bc46c1df0d500c8,
+                    0xb9381d9738e07cfb,
+                    0xcd64ab580b6e72ae,
+                    0x6297a79f7671b0e8,
+                    0x866503ac38556d2c,
+                    0x0519bd005b5d32d2,
+                    0x090d8194f8eae413,
+                    0x0052c3b9c0e983df,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2d066920aa20c556,
+                    0x19b044e4b04a3b9e,
+                    0x4a9f5428278cecf6,
+                    0xbf3f2f5543cecacf,
+                    0x3c022de349d79677,
+                    0x80f1ec7e2b4197e9,
+                    0xd5be6e151d7539e9,
+                    0x6934a56b6f2ec93a,
+                    0x23f1c715e4d9d79d,
+                    0x0d9f98ac0d99c9ee,
+                    0xf0f2b3def304826b,
+                    0x00f506cd63f7dcd1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc4a03590881a820,
+                    0x8a849614ed2c8dd8,
+                    0xe2230e7e6c1e2c3f,
+                    0x43391c5f16b32b6f,
+                    0x981d6cc3e545ca9b,
+                    0x8031c207939cf360,
+                    0xb32fc5bb4bd91727,
+                    0xf1473376eff0666f,
+                    0x0fabbb2d8cc1ace5,
+                    0x83f3409076c9c922,
+                    0x63e9785110a218c3,
+                    0x00a90d0d41b0e6f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaf8ddbf22d8587e4,
+                    0x2e2356cd10e6f015,
+                    0x7e4783dd9a056eaf,
+                    0x03cde93401a31e6b,
+                    0xe71eb1d33c79e78e,
+                    0xddb56aba06ed60e4,
+                    0x87f9ddbdc9e52af9,
+                    0xe2f1989550854d21,
+                    0xd2f656c78fa4b726,
+                    0x8b4f18f88b1997a1,
+                    0xf4c54973bee718b3,
+                    0x008ba33bfedca60f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6134d90f63d948e,
+                    0x8e71351f85d02aeb,
+                    0x93714815df7f5c6f,
+                    0xc45ea5aab9090193,
+                    0xd63c831c1645982c,
+                    0x86da6571ed9bb0fe,
+                    0x782ef65b089c6a12,
+                    0xb8dc985026cd6b5a,
+                    0xc680ad44153183ba,
+                    0x9c54c59bec569159,
+                    0x5e8b915d1bce17d6,
+                    0x00025595cdc64df9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd36f7c14e391c4a7,
+                    0x80746926c782ace2,
+                    0xe48db2bf62145f10,
+                    0x78fa165faaa08326,
+                    0x8dfae2dcf645ba3b,
+                    0xf863e16c65a7c2de,
+                    0xe8dd749c6af1fe21,
+                    0x21ed819cd319c2f1,
+                    0xe71be09fe1fa7e31,
+                    0x8f57688e691dbc45,
+                    0x1055669c59be8f3e,
+                    0x0073fd8eca1a9fc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb043d637d8fe3ee6,
+                    0xf5404a1577788fab,
+                    0x0a45312a79550417,
+                    0x0b717ad42d71abc2,
+                    0x32e1824e22def5c7,
+                    0x4e3038e63efdaa24,
+                    0x8a2f65fa23510e8e,
+                    0x44d664d0461d36e4,
+                    0xc1092b74914617e0,
+                    0x70eed2f48c82e415,
+                    0xa0c56eff139b7834,
+                    0x00fc499e31d40409,
+                ])),
+                Felt::new(BigInteger768([
+                    0x70ab76a29ea0258c,
+                    0xe0f10ecbaf592acb,
+                    0x7cab50614b901180,
+                    0xdfe202cbdbfcd13c,
+                    0x4401b455c866e790,
+                    0x5dd828b8b9c318ee,
+                    0xe8f7f066b5d5cfcc,
+                    0xc6682ca5f9f91218,
+                    0xc2ecb84f6e839100,
+                    0x60f21d9df4179ead,
+                    0x735ec6862ff4b789,
+                    0x008a704ca7ba19a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6eae7433f3a58e3e,
+                    0x4440ff57b6d8334f,
+                    0xb05dfb6b2c7f0762,
+                    0xfdb7facf64d6c5a7,
+                    0xed73884e37fa4385,
+                    0xd3c433e93c877029,
+                    0x2746e01ed3f121e5,
+                    0x84f1c5880f42e24b,
+                    0x80b5ff0bbd48ca36,
+                    0xa4e200cb841c8209,
+                    0xa3dfb77baeffa60a,
+                    0x001ed94fe9253325,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xaf7bd6dc663ed9c0,
+                    0x08e7e9c39d65075f,
+                    0xa12ffc05b4a570e9,
+                    0xb7b61603fbdfc80f,
+                    0x0a155edf0ae2d92a,
+                    0x2e1f3e9c20063af9,
+                    0x6116a60ebac59dd9,
+                    0xdfde8ade252bd989,
+                    0x3f4474af97747e98,
+                    0x9d23aeb400ef5fcb,
+                    0x5703c1f0e8b95e15,
+                    0x0103e9e49b74f42e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x832fdff159e9b493,
+                    0x39ad72ff293b56f9,
+                    0xf931bf6dc3af0441,
+                    0x6c68a5481f92276c,
+                    0xc991c56f14a2e2cc,
+                    0xe40907957219527b,
+                    0x9045e8169c79ff64,
+                    0x24f282d67c6d024f,
+                    0xca32ae72ecdf5447,
+                    0xb796358d9feb45a3,
+                    0x6f60ccada676285d,
+                    0x0004eeb8b323f272,
+                ])),
+                Felt::new(BigInteger768([
+                    0xddd1cd81cd731e2d,
+                    0xe8949c1691257ae7,
+                    0xfb7b6597785c9f46,
+                    0x178ccabcc6cd623b,
+                    0x0d65555da9dc1ab2,
+                    0xfddaaf00e99e6d42,
+                    0x3705124deddd599c,
+                    0x4c3d093da5a55f7e,
+                    0x14bc8ec80febb6a9,
+                    0x46e7b8b640574e8f,
+                    0x42985e2d53d6893f,
+                    0x010eac5387d20c4a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97761b4c9685c053,
+                    0x52debe44d368fa47,
+                    0x89625049542a64ad,
+                    0x1172528c04614f98,
+                    0xeba7d74f94757256,
+                    0x4e1d3110f9e3349f,
+                    0x51a61d27d60e86d9,
+                    0x4ec68a1925eb206a,
+                    0xd2521ff02251dee7,
+                    0x71a862892b380f6f,
+                    0x2f3a7a4da0825325,
+                    0x00e7ad5a20188518,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb298249ec2f301b,
+                    0x28926e8aa33a6c6f,
+                    0x2225c01c929bb0ae,
+                    0x02ed25b765a51e8b,
+                    0xd2fc9425f210c29b,
+                    0x83a7eb9225e0f879,
+                    0x1e9790aef0fc1f13,
+                    0x7470cdbd0771c901,
+                    0xad49323a17f2a279,
+                    0xec4a8a601f1de7e8,
+                    0xf81b5f7f3444b944,
+                    0x001bd4666784930e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x20886fd91876f312,
+                    0x7a3dc150a79e57c6,
+                    0xa6a877aab9b4bb40,
+                    0x1e7aed348f712676,
+                    0x172f309bd4351d60,
+                    0x6ee3e6259aef96e7,
+                    0x1d370ea13024f079,
+                    0x2aa7cde8bec279c4,
+                    0x21cfb8bed5bb5d61,
+                    0x523cdcc751f9829d,
+                    0x61dcd1acfc076380,
+                    0x00b24d5477e5f48f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf5f5f5ea5f521357,
+                    0x089411cc334b18a9,
+                    0x23e472a058cd84ba,
+                    0x9eb734e7aa5789b0,
+                    0xdfd36c6a34b811e0,
+                    0x8dcea12e5e9b9bd7,
+                    0x0acc63c5f2922ee4,
+                    0x7a221fcba2e94b92,
+                    0x750af38539570513,
+                    0xe0eb17b1d8cfb779,
+                    0xaa7c356649ec262f,
+                    0x00550a78a6c5a134,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb30b91a02023b2b8,
+                    0xd822bd5f708af9f5,
+                    0xd979589fb3775dab,
+                    0x623aa32f228c8a72,
+                    0x2f3808042ad63c65,
+                    0x8f081214d9fc666d,
+                    0xe7d01e32ad38efcd,
+                    0x2e0383158654e7a3,
+                    0x4de37f9e8ab2d23a,
+                    0x9a68a22cfa39df51,
+                    0x6350fe6564db9bf4,
+                    0x00e16febb0d15c21,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c9b53447e1611aa,
+                    0x14345bcd290e8343,
+                    0xdb98a3df69dfb4a1,
+                    0xfbfeb662cb50adf6,
+                    0x86168fc3fee948f0,
+                    0x29bb11a06a833b56,
+                    0xac52f8c95441c6c2,
+                    0x3c37c44a0700ece4,
+                    0x85808588618bbf0b,
+                    0xe5b77f3c083083c0,
+                    0xab6652ad839cc752,
+                    0x009d8bdebddc59f3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7562b551975d2c03,
+                    0x1377518f278b129c,
+                    0xa5df720f1dd75697,
+                    0x3d44fdef532a8802,
+                    0xb510ebaa9a4fc557,
+                    0x5c09e07a24248265,
+                    0x407d69a11acc9767,
+                    0x9e59873b8ee70333,
+                    0xf567ce378c6fe4dd,
+                    0x01c83fb0e692e7c2,
+                    0xaeae678a5a64a6b3,
+                    0x0033f0f9af2b9dd3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8fd810d0f67f32e2,
+                    0xd96ba08882d5ab8f,
+                    0x35aa8f7eba1d44ab,
+                    0x2c89c529d1b17a88,
+                    0xa8f3d660698cbbea,
+                    0x585b8f45391ae99f,
+                    0x862feeadbe25b0e1,
+                    0xe23f25126645cd15,
+                    0x8e3dbb97a65535c9,
+                    0xab855b0885dc7e62,
+                    0x1ccfc0665001ba73,
+                    0x0035776052db9f0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0739249bf4d649b7,
+                    0xbcb9e75b632ebd67,
+                    0x0c6b2cd3cb934279,
+                    0x203c21472f4977e0,
+                    0xc8c56f33e86fa1b4,
+                    0xd099bd4f92f8a67a,
+                    0xc03fa0c29d3b975c,
+                    0x7734ad75a501172d,
+                    0xd32d7d9f2af29e90,
+                    0x61b681aa071dd969,
+                    0xaba6aedb45e1b25f,
+                    0x00a3a52e29b3ac3c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x45e555a1d9808518,
+                    0xa6647ad7f56c34b3,
+                    0xa4e6a3cc67806187,
+                    0x86f4feee12aea312,
+                    0xb878885be1042acf,
+                    0x460d7ba31a0208ad,
+                    0x4ecb965a805126b4,
+                    0xe5f70fa3d8fa880e,
+                    0xa5e2a5afae7aeb3a,
+                    0x8f5bb757b1dbefa8,
+                    0xbb10063da373573a,
+                    0x00db69a6ac18952b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe6687915ccf36de7,
+                    0xf01729f0939e2c0f,
+                    0x5f71e19933417456,
+                    0xfabd6ad602e1c83d,
+                    0x82b430444ae43994,
+                    0x8e01cf85fd3f58aa,
+                    0xd593c03c4ccf803a,
+                    0xb131bc6ad751cf2a,
+                    0xb8e24c4891312ef3,
+                    0xe65b05a4e822d233,
+                    0x7f3eaca84e84ece3,
+                    0x00dc1e43fa0cef19,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb8398132a4961af4,
+                    0x20042abc324387de,
+                    0x28df85ccc0c43125,
+                    0x89d360ab0b6ab89a,
+                    0x993d3ba0dbbbcb42,
+                    0x86bbc8d816bb5100,
+                    0xa6382de1ee5af717,
+                    0x2b059f7af452dae0,
+                    0x892ed43d550f01c1,
+                    0xf49299aeec51a667,
+                    0xbab76b5e50a6aa4e,
+                    0x0051dc2df22ad508,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1717dd36e08180a5,
+                    0x9a5cd48ad294e50e,
+                    0xd89bf1200e0e84a4,
+                    0x14a1f101422b2f9d,
+                    0x2b1f0646587595e0,
+                    0x3a56059f635e7c25,
+                    0xd9599363eae9d139,
+                    0x50f78253ee51aa33,
+                    0x38b57bee30663378,
+                    0x192f12157194b3c6,
+                    0x34336e0d9d4a8af4,
+                    0x01161a178037ad7a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x856972101b70d86f,
+                    0xb0cf66d5f174530b,
+                    0xe6bbc09d02535fd8,
+                    0xc2c8e001e8cac953,
+                    0x681e7f9687025f29,
+                    0xfaf9585570048f50,
+                    0x78f68465fafe6534,
+                    0x3df1d6d711ea1125,
+                    0x70d6dea58342d5fd,
+                    0x381c089e3f9cf154,
+                    0xf17049ce6f31eaac,
+                    0x009f53b023a1c2ae,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa4a8770900a03fa,
+                    0x942db3f26b6421b2,
+                    0xa3a16b274c1d3dce,
+                    0xaff9c10fc4e86b39,
+                    0xce8cf9b1ae1242f7,
+                    0xca7fe31f79c3c4da,
+                    0x0570a5e2b69b4773,
+                    0xbf19fb7754193774,
+                    0x26f477c83288540a,
+                    0x7aa7e9588642867d,
+                    0x6bf223dca2e02dd9,
+                    0x0104d0d29bdcc637,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2bdcf2b0f40cd988,
+                    0x17ad201488e87547,
+                    0x218893568b1d86f2,
+                    0x851d611450742ef4,
+                    0xc3eb119145d4235b,
+                    0xd2ec5759f00e879d,
+                    0xa92f84267beda5de,
+                    0x0f1fdf5c28bb7500,
+                    0x88f71f93d58f258c,
+                    0x88d4738edf894ee2,
+                    0x67b3893361db8e3a,
+                    0x006447f08f18d1d2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc651b57793c050d9,
+                    0x31d224c855d272dd,
+                    0x267808eaa2d95d7e,
+                    0xa1b7f4776218158e,
+                    0xb6859766523376ae,
+                    0x646d25800ce44f25,
+                    0x8eaa067667f503ea,
+                    0xd3dda46a123d6e43,
+                    0x4d53eeb0ad4839b4,
+                    0x5afec5b8d530cf9c,
+                    0x4ecea6b278ec1c05,
+                    0x006732a949b989e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd03f322f3136192c,
+                    0x895eb87d8cbc0527,
+                    0x53821c8b613b34c9,
+                    0xe061e87e81414660,
+                    0xee2cb7483d782de5,
+                    0xf25ecb7faccfca0b,
+                    0xed7bba9777cabbd0,
+                    0x7cb14c67117aac9f,
+                    0xc5022dbe898dfed3,
+                    0x7c4128dd2e705fea,
+                    0x73532468b319c9ce,
+                    0x004bbb2a07bd2668,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb3875f2f79916ee5,
+                    0x658f8832508ffcad,
+                    0x3dc2aa89aa8e9352,
+                    0xa84b5086e4f4a7ad,
+                    0xf6c6b961d0c80412,
+                    0xc5e58156c139dd30,
+                    0xf7d1d3659f42084a,
+                    0x00d34a1271b1517c,
+                    0x8693a4eaefe99c39,
+                    0xc78e4afbd04287f1,
+                    0x323d581a3de458a8,
+                    0x003c28b7d09f4925,
+                ])),
+                Felt::new(BigInteger768([
+                    0x68bca9dcfe7212af,
+                    0xeb1860176eaa6b5d,
+                    0x1a751c4f0aedd4af,
+                    0x3b5d73e553f37e06,
+                    0x811ab2ef613d3040,
+                    0x2f852c145f2a06b1,
+                    0x9c8f87ee88210b69,
+                    0x1140f85b31ae3f27,
+                    0xfdbf3c41640c9115,
+                    0xc7b7058c1e387baf,
+                    0xebc3a1618c24a6e2,
+                    0x00a450a5c14996c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x825431abcda56122,
+                    0x161c8d009bf8af8e,
+                    0xc41b03dd5f0484dd,
+                    0x7ef93581c1609de4,
+                    0x4a952abf6e4de28f,
+                    0x6a7a08394dcb406f,
+                    0x9a46bce1d5f180ca,
+                    0x4d6b039896569a62,
+                    0x6c79e54863d401af,
+                    0xc8b4a9e57e81e7ae,
+                    0xc04bcd34734d9b69,
+                    0x00c69b902f5fb9c1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x745ac0ce0f411f70,
+                    0xd883fcc6daf60ea1,
+                    0x15f61f9b8afd9b0a,
+                    0x49baaaa4287cb624,
+                    0xbb24298bb49a4055,
+                    0xb25ad22837d13196,
+                    0x98ff67820215de05,
+                    0x6c5b25de4be8c449,
+                    0x08d4ef2586dc35f1,
+                    0x68abe68fa792bf15,
+                    0xe1641065b6365b5c,
+                    0x011f6862b1fd30c7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x37ac5ee66cbb19c6,
+                    0xa877c8b1cde633e7,
+                    0x6ea80e9ee4abc23b,
+                    0x343f98d57640523d,
+                    0x1ff37e976c05bb8d,
+                    0xf04a66f10b81e25b,
+                    0xf3f425227eb7b215,
+                    0xe82c10b47770c892,
+                    0x1926d59098275180,
+                    0x82c1cfde2dbd9472,
+                    0x2668e4683a4799c3,
+                    0x0076a81018d2bfd7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1788265c4c718c0c,
+                    0x5c7465e8e1c720a0,
+                    0xea7884f974037990,
+                    0x6d9ded2e6570f90d,
+                    0xfb21b35de25ec47d,
+                    0xe1d5ee64f70d68bd,
+                    0xe810059f3419b6a2,
+                    0x5231ed0d9ff30dad,
+                    0xb036949d93914d22,
+                    0x67b3bc263b6b1346,
+                    0xc255c9ec279ee18a,
+                    0x00a13cbe76942038,
+                ])),
+                Felt::new(BigInteger768([
+                    0x432da5a64368832d,
+                    0xdfd9017c398a36b5,
+                    0xf94350afbfcacbed,
+                    0xa60ccd6629f0072e,
+                    0xca7274cba3c56687,
+                    0xe74bed67a00f6dfd,
+                    0xc004d67f429f2cfb,
+                    0xfd18497d3bc988a8,
+                    0x5416f311cb0e0a9d,
+                    0xc5a4c42bf4124749,
+                    0xdcc835d0fcf61d8c,
+                    0x0115ea7bcbd1a328,
+                ])),
+                Felt::new(BigInteger768([
+                    0x92c81d13fc3c554d,
+                    0x01e376545623a5cb,
+                    0x52e901e03b858985,
+                    0x2fee96b59a5b2bdd,
+                    0x8dbdea5371bcd562,
+                    0xa5e48876d8a5dce4,
+                    0xf5e3c598790be907,
+                    0xd39342b35bb745e2,
+                    0x43f43e8103be0a61,
+                    0xee82185824f9f374,
+                    0xc96a67a1c48e5d87,
+                    0x00e6b7de34611b90,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc356c319d9ed2bf1,
+                    0x2fc597576d458a5d,
+                    0x2097b85308139910,
+                    0xecbecc86d9cd6de3,
+                    0x9c25d2be81c36dfb,
+                    0xc956f97292097f50,
+                    0x9e74083f5ddc1335,
+                    0xc4c2c6eec84d4cbc,
+                    0xd809a371ab6c2d7d,
+                    0xba50db379add8e0d,
+                    0xd871fac61512b1fd,
+                    0x001ad282e9c92c84,
+                ])),
+                Felt::new(BigInteger768([
+                    0x247db74781d5c8a0,
+                    0xbb03a21f2c4d5867,
+                    0x3a0cb1e226c20871,
+                    0x282e6a2dda27468d,
+                    0xb2bf60fa975e8a25,
+                    0x70d87a87c9de29c4,
+                    0x045e4d949633c1fd,
+                    0xc74ccaa22833d2ec,
+                    0x016da117a9c272e3,
+                    0xcd196817858edae4,
+                    0xb3febc6f50502cc0,
+                    0x00320ec88f49720b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7336ef615f729ff9,
+                    0x33e4efa1fd6c0a74,
+                    0x254f9b7097cb616d,
+                    0x516cdac522a89ad4,
+                    0x102d16bd8dd0287f,
+                    0x370b4fba233fae2c,
+                    0x93075a98d96c3acc,
+                    0x97184eea0602b402,
+                    0x3d1195ebd0135f0b,
+                    0x05d72fcbccf55cb0,
+                    0xe490d499cfb9b18b,
+                    0x00ce441f3a59aa3e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1f3b9b1b586259d3,
+                    0xb0487615946123d8,
+                    0xdd190d32d1cde38c,
+                    0xaf52ea694cddbb84,
+                    0x9dfb7915c6f7e311,
+                    0x0b2750f5b0205b54,
+                    0x44463e9b59bcb03f,
+                    0x7098ad22852115d1,
+                    0x6e5b3c105e20a785,
+                    0x18a18e3c54920df2,
+                    0x42ead4ab729e004f,
+                    0x000769d5d4fc93ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x80fc82934096f44c,
+                    0x15b767f84ae35417,
+                    0x85c22cbd40be3623,
+                    0x7e78fcd0b1541a50,
+                    0xe7a0a3da57c82c76,
+                    0xdfaa1e7e106f8dce,
+                    0x9516257323b04536,
+                    0x8996dc7a9e9038bf,
+                    0xc9980b429922ecab,
+                    0x56a89f4925412769,
+                    0x6d99bb736aaea16c,
+                    0x00b2d73e6791bc95,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb9f4e3d153a28c52,
+                    0x1411ba123cd9ae90,
+                    0x0c2dea188fb02db5,
+                    0x61584064b25a54ef,
+                    0xde6b7c68796d0785,
+                    0x010fbfe0675ef88f,
+                    0x5bf814aa2b0428ea,
+                    0x360e2c5fb92a0f09,
+                    0x84f4734bc54d32fc,
+                    0xf93dc206bbeabfac,
+                    0x2d3c6a15f92de47e,
+                    0x00eb9290b6988c2e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71b3341724f3e080,
+                    0x338f148c2ccc242c,
+                    0xdab7209b1940bd6e,
+                    0xb9896c7b9b45214c,
+                    0x5ebc501fbb669822,
+                    0x97f848236f72bb33,
+                    0xaabb863746976a60,
+                    0x7eaa54017dbed179,
+                    0x4589cd6bb01895ed,
+                    0x0b18a5f9ac82f63c,
+                    0x13f2d997c8faea0d,
+                    0x00d4eb5e60c74752,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x612afffffff671ed,
+                    0x8e0cc4250ff6ff7d,
+                    0xf5bbc1db9b462447,
+                    0x15367e1d79d38a43,
+                    0x48f3bd8c0c026504,
+                    0x714f2c0d0ec0dd1c,
+                    0xff074c018103c55c,
+                    0x900fe0c1001606cb,
+                    0x06329c532b3cf26b,
+                    0xd09c55b9843dcb0c,
+                    0xc17b78685b0a4937,
+                    0x00b8cd31ed8b239e,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x37d9750fb71a02a2,
+                    0xc9c4c1604eac5584,
+                    0x4aade412a6e7c760,
+                    0x4556dacbf27e9ce9,
+                    0x9ac34cbd1697a501,
+                    0x76b7e42b9871e1d1,
+                    0x2e2ce37c46f6da28,
+                    0x682ce8384bd774bc,
+                    0xaf95d1cac8b29c3b,
+                    0xf5d4ee8b3d6e04ab,
+                    0x5c50dc5c14187ece,
+                    0x00ec9fba7662cc4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce7c6da536f2eef3,
+                    0x3b13a02aecc482fa,
+                    0x0810c576f32c5d95,
+                    0x7fb02f31f6681ba4,
+                    0x07f7defa9d9aa4ba,
+                    0xef28e4f976737591,
+                    0x27d7f5b2c30a584a,
+                    0xbb9272b444fe550c,
+                    0x530321a2e433212d,
+                    0x9f9e57a8efa0cb67,
+                    0xdfc42afd8faca063,
+                    0x000990efb9b2c1e7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf3043fdfed8baff5,
+                    0xfedc753c2d28610d,
+                    0xa27cdc48ee0cd8b0,
+                    0x68b890ef699c8ef5,
+                    0x54dbc23a4e9a6ebf,
+                    0x84203a6380988025,
+                    0xf94c20f84e5396b5,
+                    0xc66b1e01afc4c7cd,
+                    0x7103b87f7dfe92ce,
+                    0xd24ec1e0ddde4e3d,
+                    0x6e66486b19f7c33a,
+                    0x0112fa270bd58fd1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x16bb90f1acd1d0a1,
+                    0x65dc5360351e05e6,
+                    0x3579e03a0e08d2f2,
+                    0x288735a097a462ab,
+                    0x3fda72d33008e6ae,
+                    0xb035184b27714ea0,
+                    0x663a1064488966bd,
+                    0xccc8a155d4c3ce55,
+                    0x692296167ce89672,
+                    0xf85ba76137969237,
+                    0x60d2b0d8dc9b9332,
+                    0x00b3fc5111eab132,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba768c39357e1182,
+                    0xe9a0743a8dc623ee,
+                    0x0f36477261abaa49,
+                    0x958d301ebc51fba2,
+                    0x5052fa98fab8a12d,
+                    0xb546d869246ecaf0,
+                    0x82a5c9ff677be222,
+                    0x7ea1de5b09cf20ac,
+                    0x9466b7a86d768bd6,
+                    0x1847c57c02309abb,
+                    0xa499b0ecf5d55725,
+                    0x00dff345c8e49fb2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf508adfb06966015,
+                    0x38041451eb092680,
+                    0x9e8a4ac25a4ae6a0,
+                    0x329c142e5f3f14e2,
+                    0xb41a0ad8b132a2fc,
+                    0x530740342aeaec7c,
+                    0x071d60e442649204,
+                    0xb20abf1e1f445444,
+                    0x4a733fae39abdfb2,
+                    0x11e22ad4887b1759,
+                    0x01be100816e032a0,
+                    0x00ab4f0c3ca9ee5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x10be6798b4758dea,
+                    0xa5947e6756480cce,
+                    0x38bf953ef011c589,
+                    0xaf15bbde87a57a00,
+                    0xca79eaec92d38c77,
+                    0xce0c27e5e1d5929a,
+                    0x0fefe6cc9dcf874f,
+                    0xd34db41ff1992ef7,
+                    0x21c42afdd9bae11a,
+                    0xb4d3d779521aea1b,
+                    0xc8972c5e430f4166,
+                    0x00760ed5033e14f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa306da721df39f4b,
+                    0x2e509963470b61e2,
+                    0xac7ad82bb847fc5b,
+                    0xa3e612b878bb0c52,
+                    0xf44a68d0a7fff0fb,
+                    0xdfcf7cf377b9b040,
+                    0xeacf42550256305c,
+                    0x0289d286b141e55b,
+                    0x6b1898b11e971736,
+                    0xdc7aaa82e4a3121b,
+                    0x6694610407d8773a,
+                    0x0102e0aab90c7f7f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xffd1f912c5b2c423,
+                    0xc9e8f3fd5a9690ed,
+                    0x28a4106f648d403d,
+                    0x433361f3b3d32d29,
+                    0x1f84011ecd4bed92,
+                    0x2375f5049de2d5f7,
+                    0xe8f275716e9a07f5,
+                    0xd987b55bcabf53c2,
+                    0x22b8c59494f2a4ae,
+                    0x3c7c2729de3d9786,
+                    0xf6d39f066524e37b,
+                    0x00293fdf332b96e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38dcfa05f9577ad7,
+                    0xdc999940ebdb7544,
+                    0x3cbd40b59edb7668,
+                    0x0cba970c7b62f502,
+                    0xfd1ea4d878022cbc,
+                    0x00ae5ca3d289abe0,
+                    0x3bb20822be6c7760,
+                    0x3ea8c909dc8f524b,
+                    0xfabe3e728789cf90,
+                    0xc3db02c2630292d0,
+                    0x2299b28a08b24f82,
+                    0x00206412dd08d98a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfa27ade807e3b752,
+                    0xf8f5d85a7008c520,
+                    0x404e1d91bd57d4f3,
+                    0x02c253a3d3f35f48,
+                    0xd5548af4de0e0fd6,
+                    0x5fc776d6fbc0fd22,
+                    0x0d768be83f6f9c7f,
+                    0x3f3ef99f88495363,
+                    0xcd732fdce5231608,
+                    0x1138514b137b721e,
+                    0xf6c8951ec8cdaa88,
+                    0x00f89ef32749d949,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9bbc2c60af0ae90a,
+                    0xc65524908398301e,
+                    0x215776e62b008e52,
+                    0x06673f493539084d,
+                    0x364d1320cf2f98ca,
+                    0xed4597a343567335,
+                    0x35845af7384e1350,
+                    0x7561c29d500cb69b,
+                    0x8240af9db1eed144,
+                    0x44fed0d4979f9943,
+                    0xf61bf697bf97eb6e,
+                    0x008fc175db64ed26,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd04bac4507e1ae42,
+                    0x14fd287453d0e335,
+                    0xe783d0ef06c2fdef,
+                    0xa8d6d718103732cc,
+                    0xb0c1b3ee9107ee8a,
+                    0x3f741de2318f5edd,
+                    0xf6bce9d844ac31de,
+                    0x537c3e75d4fd0175,
+                    0xe844b6a1b9087475,
+                    0x84406de6db20768d,
+                    0x7956d0e0f7f73121,
+                    0x00a3611100e22d65,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0204df8a09cb3600,
+                    0x252c39ef7ac7e688,
+                    0xa679ef2f4ad72afa,
+                    0x4faaff169d061863,
+                    0x9b59fdb400565b31,
+                    0x98275d1b79ebf40a,
+                    0x5499faa82e072cb5,
+                    0x4e65e379b63069d9,
+                    0x63626a2cc1c22450,
+                    0xfa4dc193b574025c,
+                    0x38a7e81789d6e63b,
+                    0x010859c7775b7681,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90df0c80d08acc34,
+                    0xba70f272afea6f78,
+                    0xa262430e55960766,
+                    0x959b730523425e78,
+                    0xa53741efbea94a2d,
+                    0x6fe9e9870289b547,
+                    0x3f17a47db4e0b524,
+                    0x7a083e5ccb2280d9,
+                    0x47f0767b9b6765cc,
+                    0xd444334b54e19556,
+                    0x64c1293ce72b5d25,
+                    0x011d5a2f05acce1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf9884197098b292c,
+                    0xf2e3667ce8df4341,
+                    0x7694c402080dcd55,
+                    0x6ca15a431eba410a,
+                    0xf7564d9aa37363ba,
+                    0xc81463c62d5c2772,
+                    0x8323cb78612a6cdd,
+                    0x366097a61120d345,
+                    0xfdbcf834337a3138,
+                    0xbbdea57e6167b37b,
+                    0x39098506393ec6f0,
+                    0x005453784fecc032,
+                ])),
+                Felt::new(BigInteger768([
+                    0x48c7bd118d1a2d9c,
+                    0x352af04015b742d1,
+                    0xa28f13258e1a49f8,
+                    0x74fb6dfef958a578,
+                    0xb1472af87f2a7553,
+                    0x6d08046aaa7ce3ca,
+                    0x8653389c61c1a142,
+                    0x84fd3a80c16e12bf,
+                    0x10896d1d6407f4d4,
+                    0x6bcc3da120406c52,
+                    0xa1bc6f210d7375ff,
+                    0x00da797c07881e3e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x49a916caf730add2,
+                    0xb23c1f88cf58c47d,
+                    0x346a1bc37514a68a,
+                    0xf5f32c99d9d17704,
+                    0x12f27ef3a1e5d9e6,
+                    0xa58ec8c18d18e55a,
+                    0x0336a9886e1c4782,
+                    0xd65581d5b34a19cd,
+                    0x014144f26b3b23f8,
+                    0xdd1c019cd0cedc6c,
+                    0xe9d9c9c4f9d8439f,
+                    0x0014b8e40a8dacd7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x56b5f9985b657d7f,
+                    0x74202d7eb855f106,
+                    0x2301c34e16c558d1,
+                    0x1a615205193a7878,
+                    0x00c5197a48ff1a82,
+                    0x1db1f2a2e830e881,
+                    0xa6d2158abf218f66,
+                    0xad238707e5fb20c6,
+                    0xee1565f584cc3532,
+                    0xa7d250afbc53c438,
+                    0x26c26c282c2a9ff6,
+                    0x004c82b824f61dba,
+                ])),
+                Felt::new(BigInteger768([
+                    0x94af02fcf86b2126,
+                    0xcab877abf804ecb6,
+                    0xd4b49a5f0a153df3,
+                    0xa00027d8c7bb23de,
+                    0x3592bbde11f2b828,
+                    0xb9ff7fd8fa9ee4cd,
+                    0xf50cc9dabdb9d242,
+                    0x6d655dd0356d939c,
+                    0xfc71f39adfcff066,
+                    0xc2cfa42f7405c58e,
+                    0xbe59bf7eeadf5682,
+                    0x00d98c8d355ceb4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03497c36ef64e990,
+                    0xf630fd66dbbb6d2a,
+                    0x3d3e535f926223fa,
+                    0x4215985a96da9dd7,
+                    0xb9899e85e99ba007,
+                    0x1e5fbe12e46ab8d6,
+                    0x5b1851110e23d257,
+                    0x46a00241b34f1f87,
+                    0xed57f740de452498,
+                    0xfd3df31b4bd792fd,
+                    0x9c6fe7c88ebd09ab,
+                    0x00ed0d8d995662d7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x85fa3aea249c08c7,
+                    0x4e831f53ab2aa52c,
+                    0x7b3c54e39e6ea650,
+                    0x4f5707bb25baa51e,
+                    0x65f3d884ab93cf2f,
+                    0x48998ac92c250381,
+                    0x0a3d8693180ec033,
+                    0x3bfa3eccf8df9447,
+                    0xa353771f9e593463,
+                    0x8d77d790c3610062,
+                    0x7e83feb68ecddf86,
+                    0x0089eca8f54613e0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa7809db860738e55,
+                    0x146bd3dd590588e9,
+                    0xc4416440084c5483,
+                    0xe15807e9b113dca6,
+                    0x2a43060b1bc9bd90,
+                    0x2ca828be5e329df9,
+                    0x80bfe8a5cdd7ee6e,
+                    0x116f147c812dcdd1,
+                    0x8191b639aff0c5eb,
+                    0x3675aa3d5709ea56,
+                    0xc625ea508e7e62c8,
+                    0x010337a9c2d9ffde,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad9b832662f2597a,
+                    0xce239aedb502101b,
+                    0x8ed1df3f8087f350,
+                    0x05bfbda5553b9b4d,
+                    0x3a8ceed31045f9e6,
+                    0xe090a307422c5a2b,
+                    0xdff00d09a1b94286,
+                    0x39ac44816b8d2d19,
+                    0xa81dbb275a2b2690,
+                    0x5a9a304e7b1b82da,
+                    0x1b5de7fb7fedc6dd,
+                    0x0050e91e5edb6801,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x946647535ea18e1c,
+                    0x2fd7e9ad65451c1e,
+                    0x2f9353cbafc09969,
+                    0x937be44cda12a39e,
+                    0xc1447d2442696a02,
+                    0xa044d8ecf93176ac,
+                    0x0b391f1ae78928b0,
+                    0xc0050dd1ae901e77,
+                    0xd91035efea95773b,
+                    0x1dda70f8e263f390,
+                    0x1059905fc6cf4e83,
+                    0x00b3306624b647ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbeceaa4458e64fb3,
+                    0xa18688a224ae7d0e,
+                    0x93d62c183491c934,
+                    0x1f99bcaccdf03e7d,
+                    0xb2fd9b3f89a2c2b7,
+                    0x122542bf0b2e9428,
+                    0x3a54cf0591986ed3,
+                    0x688f4345e33ec31d,
+                    0x0fa231fb98b0fdfe,
+                    0x3fa078c4590e0baa,
+                    0x3851caa2dfb66243,
+                    0x001c4d4e9ebdfc9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd0443108e2d3cde2,
+                    0x24bc269e68b1fce0,
+                    0xec399ee9a5fa8b09,
+                    0xfdb2d759a5347faa,
+                    0xcff22b28d4384255,
+                    0x916fd145dd262025,
+                    0xa1d104eff2be9ef9,
+                    0xb3bace7922a8b890,
+                    0x9329afa64b5ef67f,
+                    0xcfa01d686987830e,
+                    0x91811483a0b45428,
+                    0x0103a3fa8320c968,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7060bad00fba6ebb,
+                    0x21e059dfbd29730f,
+                    0xf9aca9942a4fed67,
+                    0x60ec12d081497c00,
+                    0x991b95db6c10f9c9,
+                    0xd56c276b3a2cfb23,
+                    0xc635d566cb73fbfd,
+                    0x96a3ce6f6ef15a1a,
+                    0x95fff2f5cd236494,
+                    0x72b5133fefba44ce,
+                    0x2054daf51aa759f4,
+                    0x002f076448cbec80,
+                ])),
+                Felt::new(BigInteger768([
+                    0x79896be9f5ef5ccd,
+                    0xb6b1919d6ab3c38c,
+                    0xba44456cf83b02aa,
+                    0xfc401efe84028de0,
+                    0x724bf59b42b15028,
+                    0xf3a861dff4b972de,
+                    0x536a2fd2d18f6520,
+                    0x6bb63eb7848232fb,
+                    0xe73de878ecb1dacc,
+                    0xcd7985ccd7821fa3,
+                    0x356b8a73c2cff435,
+                    0x00d1afa65f863c37,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9480da80850b7834,
+                    0x919b7d7f6cbaa4c6,
+                    0xce9ddd06ce19c9b4,
+                    0xe94ebe78ab438c97,
+                    0x6bfdc9f559a2e6f2,
+                    0x543989a40447b9ce,
+                    0x920f2ca48a593f43,
+                    0x15764bf1b4f3c8c7,
+                    0x3424013ebd110748,
+                    0x8c2c84b766acffea,
+                    0xf71832d5996adcac,
+                    0x00c99894d5ac7633,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeecf9388b2a79e4d,
+                    0xa7f9c9f6e8c3cef6,
+                    0x40970aabfee9a912,
+                    0x2597dee8aab917fd,
+                    0x8bf5f3d17a22977e,
+                    0x01f6d0f5b7ae5fae,
+                    0x79421994664c6645,
+                    0x29733856b76a0cd6,
+                    0xddab45eb7116d97e,
+                    0xb554f3f893f9584f,
+                    0x826c63302cc2c64c,
+                    0x005dc201770cd422,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b97a4251013cd3a,
+                    0xe60d95b26fcb3992,
+                    0xa4192b271879d425,
+                    0xcd09830dadc30df3,
+                    0x92640bdfc2a98fb6,
+                    0x8c25edce19738f14,
+                    0x3101a0216828a7e6,
+                    0x5f3cd02e44003197,
+                    0x1d0aa5027ea864c9,
+                    0x7b6e3bf89aca8225,
+                    0x822ef04e01a7d489,
+                    0x00a4a6487b13c938,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa28185912d43ae2a,
+                    0xbf0abc303916f595,
+                    0x49634813dacb15bd,
+                    0x0ba24ce3d5016c9e,
+                    0xb39b806b0292a14f,
+                    0xeb8b36785e29abf2,
+                    0xc026972b47102500,
+                    0xead4624c0374583e,
+                    0xae0d1e7c28def663,
+                    0x009313ba42d20099,
+                    0x246408c2d3c75bdc,
+                    0x0003ff326cd8bf97,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f9026a87b9ae972,
+                    0x100d7f9f728793e4,
+                    0xe393de52440b049a,
+                    0x3e5fd3e486089e0a,
+                    0x362f67f39c2fafa4,
+                    0xda62dec58cf050e0,
+                    0x30135b1da1332dc0,
+                    0x29f22a2048856f66,
+                    0xf9b4b3317c43e581,
+                    0x090ba0879325b818,
+                    0x28422799946cab48,
+                    0x00490dda0a696f8d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda07201386e2fd4b,
+                    0x145633a3f8ac2237,
+                    0xd4193c7dcee7bf28,
+                    0x4d63d8993d9746e5,
+                    0x24e1a1b2ddd41e2d,
+                    0x26c255288da3edbc,
+                    0xc1bb80b203b67008,
+                    0x247759764953ca6e,
+                    0xb3c1e5d6fd1a326b,
+                    0x3a8d018d5f717084,
+                    0x6feb0e587d3df046,
+                    0x00735c1e899db1c7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb7870106017372d7,
+                    0xdca4427c00f06616,
+                    0xc99b59cdb25b5ac2,
+                    0xed91f613660fc8d7,
+                    0x88a8ff5d5b8ca70c,
+                    0x1e0013b495a3342f,
+                    0x3d89e497f7ce8be8,
+                    0x8ec697a41a5c3c5b,
+                    0x91bf381523e6b535,
+                    0x923b3998b36bdf7f,
+                    0x9340ae27184c3f10,
+                    0x0031202dd3a6262c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x523cb70db24e8720,
+                    0x07df2c36158edf63,
+                    0x527e0c6a5103ae37,
+                    0xe725b72923cb9169,
+                    0x70036f91275e5bac,
+                    0xa7e07404c4cc2651,
+                    0xfb625be9b01e8282,
+                    0x95750585eb473f87,
+                    0x32a68d8c31aaa24f,
+                    0x86516c8202315e3a,
+                    0x6df7b426c3776a6f,
+                    0x000c8f646cc1e010,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5c3f40e87b76a77b,
+                    0x86fd585277fb4f71,
+                    0x1939ac985c1a2f62,
+                    0x831f138931b077ad,
+                    0x3ff32f433b6d129e,
+                    0xcb948359c400b119,
+                    0xe185fe205d5c2b1b,
+                    0xfdf8dd69883373f1,
+                    0x51963691769210f5,
+                    0xa0de827e680edf96,
+                    0x411f4cc6d19fbf33,
+                    0x002a10fa6b5fb1a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcfeba106a516b268,
+                    0x099ebc5f071443b4,
+                    0xbc967f6a460c8b1e,
+                    0x6f0ac430d9098b73,
+                    0xb9d1b3c4044be056,
+                    0x1679919662d0129b,
+                    0x7e1e37e054d6d609,
+                    0xeba4238e6facdda7,
+                    0x89196115cebfb4db,
+                    0xa343fc9444a42631,
+                    0xd329c032c6d5659e,
+                    0x000a0cb1228e5201,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf2437954f7586698,
+                    0xb96b50d6481c1a36,
+                    0xf48fd6aa72b9cd49,
+                    0x6b14986c980fd1a5,
+                    0x884339a995e408e6,
+                    0x764f674554232475,
+                    0x3dd75f4345648432,
+                    0x6f838e39719f014d,
+                    0xc5e9fec993565473,
+                    0x13f2918551a62a0e,
+                    0x649be54b6c4fb8e0,
+                    0x011acb4a3d18f211,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1669dd2296b9508,
+                    0x518e4e4b5226744c,
+                    0xaa405f09c8829582,
+                    0xb10dbdf4566d1802,
+                    0xa142bf77967733fc,
+                    0x888cd22f70fc057f,
+                    0x6f777855a8922b9e,
+                    0xe28ae7ba84ee8b68,
+                    0x2d2f05227eb1ac6a,
+                    0x52fb0f4222c8377d,
+                    0xb281ef69aecb82a9,
+                    0x0102264e1bd8e175,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0f9db2cb3bf7409d,
+                    0x492d915809b6b91d,
+                    0xd6b49c4b6cde0b89,
+                    0x84bed037735d4241,
+                    0x3efe6ff043d4dc65,
+                    0x223261f1d1a76e42,
+                    0x38bb1913f8aa1480,
+                    0xfa7eb32cc860fe6e,
+                    0x575a1b892a002c84,
+                    0x71745e2d36782f68,
+                    0x7066ff9b56d25dd0,
+                    0x00f2e88d8202beaf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6c5c2a74269f4c3,
+                    0x218ccab32f3da6b6,
+                    0xf87253c4016f13f7,
+                    0xf53fac88bbc9ad4a,
+                    0x86169fc339ec5e86,
+                    0xe662fd612703c7f5,
+                    0x55bc6c0d04215956,
+                    0xebb707df96fcec1a,
+                    0x89264ddad5f41a82,
+                    0x5196b831794fb214,
+                    0x2c75b3392dc1a774,
+                    0x002ccbf0a0288dec,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4f904cd69ba894f,
+                    0xc76fc3f1198ee0a3,
+                    0x59fe0a7392b5e6d4,
+                    0x491b97b86a11837b,
+                    0xd2c696cb6cef93f7,
+                    0x35a01737d0059c38,
+                    0x02f8c69397491c60,
+                    0xe0884a0e9f9d69e0,
+                    0xa84d16e966e415f9,
+                    0xf19e3e7725dea2ce,
+                    0xa9aba4f7877cd454,
+                    0x00b822469b8b9655,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f79d75b7ede6b8c,
+                    0xf59c58f26a27494a,
+                    0x18ce2e106d111b8a,
+                    0x22328d326cacbf42,
+                    0xd5a2015fd87a99af,
+                    0xe196174f93d54b59,
+                    0x9d8ce76c5d8bc354,
+                    0xb94adecc42523caf,
+                    0x3eb5d4b19270317e,
+                    0x80f8b09ee71f820f,
+                    0x3e1015a77fe4d049,
+                    0x0063e7282e53a25c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xef923dec8c77dd8a,
+                    0xb5b473df7c9c96ad,
+                    0x907890f2cadbf8f3,
+                    0x4864df5bc2890765,
+                    0x1c0360d3948a9821,
+                    0xd315cc09a57347e6,
+                    0xc82eb149006eea34,
+                    0xdf3b0d515d9dd06b,
+                    0xbff7d3bbd63452db,
+                    0xd7b5e379822ac88f,
+                    0x3a06c9ab8faa220f,
+                    0x00921bb3285aa3d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82720bf668b8c56c,
+                    0xca69469e530e2fd5,
+                    0x1020034c6b95822e,
+                    0xe6ca0e9e9bb1413a,
+                    0xb09d084aeab6d473,
+                    0xe2ff1ac4b01879bb,
+                    0x80b3cd83db73d5df,
+                    0xbe195eb3e0704c10,
+                    0x26cd3938ab099dc4,
+                    0xa6b8329106adcd3d,
+                    0x893f31814df86f36,
+                    0x0063570456c3f6d5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ea2865ae4ae6000,
+                    0xc8ae02c38e72ad7e,
+                    0xe4228cc2bcb2c353,
+                    0x5db3f52852dad332,
+                    0x59ecd2771c72b9fb,
+                    0xf9cf27c9133845eb,
+                    0xff5163d365f7dc09,
+                    0xf9494b3d109e337f,
+                    0xa1c39ebfd810386f,
+                    0xc7725c93c12f63c5,
+                    0xa5fa2460b3a68ab0,
+                    0x00cbbcdb05c004eb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe9922e1effac90dc,
+                    0xb129474e85c6eed7,
+                    0x042487b8106fd2e4,
+                    0x06abf791b03a8802,
+                    0x593346a8818396bf,
+                    0x9befcb9fe9eed0bb,
+                    0xc4946d26629a8d9a,
+                    0xe5a9b3b20b7073bf,
+                    0x77f2133cb885abf1,
+                    0x90911fb66f74864c,
+                    0x04b3f3eb109721e3,
+                    0x00565a2d15a4e768,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b31012b6a0696d3,
+                    0xeb2c2a524cef8c4b,
+                    0x71e099894cf991bc,
+                    0x75c8880cb0883907,
+                    0xed6c25c72daa9f17,
+                    0x66629a1dde618c3d,
+                    0xa68e887b4542c00a,
+                    0x4ef2c81425d58a5f,
+                    0x7ffe5ee5101524d4,
+                    0xef1d649856d775a8,
+                    0xd3779769ec32deb1,
+                    0x001e8b53529022c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c0f550824c95a16,
+                    0x6215fbb7a797f590,
+                    0x84ab48656623732f,
+                    0xd12132fad0a09456,
+                    0xa76bdcc85107c57d,
+                    0x32c2ae521910efff,
+                    0x565a1d3ce345ee64,
+                    0xb07535c924525a3f,
+                    0xbfd35e45a18051a4,
+                    0xb38ccbc20672082c,
+                    0xa05f6fef89abf8bb,
+                    0x00e24873c44e2349,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cfda0bcee583a19,
+                    0x430527ceab927563,
+                    0x07b164fc1453ddfa,
+                    0xfc4658ff0a771818,
+                    0xaf60fd269b8b5677,
+                    0x4cff4d713f9f3b4f,
+                    0x8153735f5cbc4f16,
+                    0x96622a6d8cc0c1dc,
+                    0xab5b484bd320bcb9,
+                    0x8c1f45700831aa40,
+                    0x5fe2b0e7b3a70373,
+                    0x00c66585ece618b0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x52bb9403e5c1a6e0,
+                    0x8168d8b2c664c21f,
+                    0xb835406c7beb4e58,
+                    0x5f55aab4541b62d7,
+                    0xa39b61057e228f13,
+                    0xfa58c660fbf96e6a,
+                    0xe6cbc915a4e71541,
+                    0x7a59ad6002028ac4,
+                    0x3e87883d4acc3bcc,
+                    0xf14b5e142b727159,
+                    0xc671a7ee38c170d8,
+                    0x00fefa18a26ec84b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac464b9169e9cd5b,
+                    0xfe34bbf8fa5b5ef5,
+                    0x8f25635eb6774063,
+                    0x8625505f6a3c5ae0,
+                    0xf4325c12e0107a8d,
+                    0x0608eac880b303d0,
+                    0x50dfb0dc65287957,
+                    0xb6f5e211efa97c24,
+                    0xac2df9a5677e2f1b,
+                    0xf7036912bfc21c4c,
+                    0x99598666e15e3599,
+                    0x0020b4b28ff6f9c2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x42045529e7fafbf2,
+                    0x60548475503644e0,
+                    0x8a695831f5ba3f3d,
+                    0x3cf73a0fd864327e,
+                    0x0af6e6d2b216b94b,
+                    0x382f132707894ee6,
+                    0xb1e24c4fc5b25549,
+                    0xf205ccd1f7738521,
+                    0x5d26849a87064930,
+                    0xa418293a9481e190,
+                    0xab5a482a7a16efd3,
+                    0x011c22de33d00230,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa3a053e71775650c,
+                    0x756e1ecdc8a6f9a6,
+                    0xcf237c30d9cc0b90,
+                    0xd58454db932b040b,
+                    0x1e6a79ce9628cc63,
+                    0x359a405228875182,
+                    0xab8afcddb4f72635,
+                    0xa5e7936f7e83baee,
+                    0xdf72a31a6c2c78f6,
+                    0xc2b54cdea1ad2d79,
+                    0xbd25e1a8f99cfa15,
+                    0x00d89a96836eff17,
+                ])),
+                Felt::new(BigInteger768([
+                    0x637d2144bb2d3292,
+                    0xd50c659da04aa582,
+                    0x45b52adc080da50a,
+                    0xb385833e54052e72,
+                    0x6feba87e749dd3f5,
+                    0x77202581927e1cb8,
+                    0x47850576678ab2e8,
+                    0x32ef3f9686c20731,
+                    0xccbc91b2edb5ae3f,
+                    0xad2b43559c03560a,
+                    0x80f8211717ba56ed,
+                    0x009b73181adf4a28,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12a8bf82c6b657c5,
+                    0xb85435a5ffb24900,
+                    0x6cd6b7b3fa8c06ea,
+                    0x907583956ca55720,
+                    0xb5e265854a565a72,
+                    0xdc791c5426404a8f,
+                    0xc88a2279a1f89833,
+                    0xad0680cd2b9cc311,
+                    0x4be039a549c0182a,
+                    0x5a9b1cbb4ef2f684,
+                    0xa345b422ddae6696,
+                    0x0046b9efea309046,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87e8e49b984f28ab,
+                    0x192232117417020e,
+                    0xe04555e3816256a4,
+                    0xec07c0a850c8c2c2,
+                    0xa63be522d0805d26,
+                    0x80ff0ec2940d68d8,
+                    0x0d327262ae0c9a79,
+                    0x232dbac0579ebf5d,
+                    0x4341f07aeb990f79,
+                    0xd790ff5dd145c52e,
+                    0xa8c9dc2e67c94ed3,
+                    0x00a1be9b74d516f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb12d36cbcbcfd0cd,
+                    0x7ce38beba8571be2,
+                    0x9008829f2a2d4ebd,
+                    0x2e2908d2ec82ef1a,
+                    0x6452c0c0fc497c01,
+                    0xd7a9e21c0c1563aa,
+                    0x69c589f52144010a,
+                    0x8ecca8a0a2f0d74c,
+                    0xd8a192b9a598fbd7,
+                    0x8f481b2d7edfbbc0,
+                    0x9843e06b2f5c5802,
+                    0x00425e483d930185,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xee884d6177af963c,
+                    0x55922a59a2adf4c9,
+                    0x98a1abb50c993142,
+                    0x4c3d02f8def43c43,
+                    0x7f61ff0a9ef2ab4b,
+                    0x12eb9ab87f716d48,
+                    0x9f7ffbff193de72b,
+                    0x289ef6583206fe50,
+                    0x5b5f4aba9759b15d,
+                    0x4ae88c031defb83d,
+                    0xc2d96ae379a217da,
+                    0x0073fd6f0fc59866,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82b73edb118015d3,
+                    0xf898c27148fa94cc,
+                    0x98a1438b02410e64,
+                    0xedaa1fa99399e5fc,
+                    0x58c38e72e21abbb7,
+                    0x676ad78c77b9ce6a,
+                    0x7062dbf5568860bf,
+                    0x2b9d3a0342d4caea,
+                    0x12010c57973c65cf,
+                    0xa618abf4356af73d,
+                    0x836dfe4ed8e6f203,
+                    0x007148b8bb8bc488,
+                ])),
+                Felt::new(BigInteger768([
+                    0x317a2d12c4ebf0bf,
+                    0x46331d501d05ed21,
+                    0x720602d50150cc83,
+                    0x71a8a0ac9f2574dd,
+                    0x6251deebf66a1314,
+                    0x35c43d3af497cb8b,
+                    0x8e642947bd4aecfb,
+                    0x968f17807b921fc0,
+                    0x67c76ccdc4edf6c9,
+                    0x096589a8ce329b5e,
+                    0xa9b928687c7f4b57,
+                    0x00bb9997c6e660a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1234cfe9f4c19d3a,
+                    0x5ff0d163c38077c4,
+                    0xbb58b166569a22f4,
+                    0xcb280528ed198423,
+                    0xb7411038dc66d4e4,
+                    0xe72ca1ba41328a35,
+                    0xff85254ef10a08c8,
+                    0x711835dc583bfec6,
+                    0x56ea6eb7d4d8aec1,
+                    0x8d2574123d283342,
+                    0x02525b2db3ec50dd,
+                    0x0063769dfa6fad79,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ea23fe4c9840ee1,
+                    0xb51001ef9e051064,
+                    0xbd492f9d4bdacd32,
+                    0x131010ed5a3178e8,
+                    0xb93eaf7c20e43ad6,
+                    0x476b9882dd1839b8,
+                    0x06143d0a821e3681,
+                    0x515ba75ec43aa3dd,
+                    0xd412a1fac1a237c4,
+                    0x2f4def15b76a87b6,
+                    0x3425217c5882ab7a,
+                    0x011e4887e989d74e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9cd624c39b9a50f8,
+                    0x4222e9883d93a45b,
+                    0xc7791cd05c109ac4,
+                    0x8eb84304b0f6ec80,
+                    0xb04775688a49ff55,
+                    0xe03692d9f31a705a,
+                    0x01b08dc0c0094ee2,
+                    0xf293bbaabe4b30b6,
+                    0xa63729054f519847,
+                    0x9d403314421a9547,
+                    0xc96259745f06304f,
+                    0x001057aa34b8ae4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x828bf19c8bea18ca,
+                    0xd7cf494cefaaa701,
+                    0x89c4eebca3fe19d9,
+                    0x9c0675191224ad25,
+                    0x7641fce5efd4ecc4,
+                    0x40fa6a48ccd95025,
+                    0x503c7eceb76c9ffe,
+                    0x3cfa2896ba3c9e26,
+                    0x4730ef40748dc8f0,
+                    0xd2005e42eeb10ec4,
+                    0x91a3520a0414ce6d,
+                    0x00945e0c8ce4a91b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7be2ca2ced8d1a12,
+                    0xc6a37860470d0771,
+                    0x01cfe9305ed6fce8,
+                    0x5a839380de9918e8,
+                    0x961e2585c5373558,
+                    0x04747076a88ec04b,
+                    0xf654556f81a5c341,
+                    0x30c508f75688142b,
+                    0xc6ea6e97b2f8d0c4,
+                    0xb74d2629352bb80c,
+                    0x668e38c725e408c0,
+                    0x00208f4f3f546f94,
+                ])),
+                Felt::new(BigInteger768([
+                    0x78922f52f9f77b15,
+                    0xa1266793acb9d809,
+                    0x4304d31c417fd898,
+                    0x9b30399e655b10dc,
+                    0x231e3ddf3063486a,
+                    0x8ddacde426cffd60,
+                    0x5d0e67bca731e48b,
+                    0x6c0797018272c612,
+                    0x584811b156aa834e,
+                    0x8d6274abea9d2438,
+                    0x22c07d13968a76b1,
+                    0x0001f583c3334168,
+                ])),
+                Felt::new(BigInteger768([
+                    0x632a1240b957bd25,
+                    0x076c3e7e0b77ad72,
+                    0xedec1712c25a1f00,
+                    0x778c215e5bc7b4ee,
+                    0x8ae0e015845860bf,
+                    0x0931badbfcda18be,
+                    0x155a2fc50317bcd4,
+                    0x4fa776b4ec6ea21d,
+                    0x10523c131c317d58,
+                    0x3cea1f06a4c6b74a,
+                    0x43610d708755eb1a,
+                    0x00ce964168ddb175,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcab109b85f6cf9c6,
+                    0x482513b1b66ce18a,
+                    0xec66865bd81d8ae1,
+                    0xc4cc88b3ff2b64ed,
+                    0x921bdce7ac683c7c,
+                    0x77556b39513b1003,
+                    0x60a60c49e5cf6c1b,
+                    0xec99f41d5f968995,
+                    0x39b0fb515a40687c,
+                    0xe058726879025a3b,
+                    0xb3e4e68708a3b710,
+                    0x001317e171d22560,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc2962de96caf41e3,
+                    0x0815030a7f735aff,
+                    0x7851ce79848e33c9,
+                    0x327dc9f511a7ab01,
+                    0xe767a6b2a19bcd1e,
+                    0x15370522100a79f8,
+                    0x5aec7d73eb7aec06,
+                    0x077f4453eb6fdb56,
+                    0x5e5717f56e03d0dc,
+                    0xa39d612507e87aff,
+                    0x70c083157f9c6001,
+                    0x00f954d5eddc58ca,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2873 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // We use internally the Jive compression method, as both digests do not fit
+        // into the rate registers at once.
+        let result = Self::compress(&Self::Digest::digests_to_elements(digests));
+        Self::Digest::new(result.try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U192;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U2>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger768([
+                0x7bff0fd77b3a826d,
+                0x8335f8c8c82d9b21,
+                0x997ccaaadf5a977c,
+                0xa0ad0cd028b2e9b1,
+                0xe0a46fff7dc04fe2,
+                0x4cbd1c783dd47e14,
+                0x273b1a2af21e0c35,
+                0xad92ad0de6edb8a8,
+                0xde9276247faf8b07,
+                0xca52ca0a59cffa8f,
+                0x225e47330a498e0e,
+                0x00965717beefab08,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x83aea896c20bf9dd,
+                    0x1cbe559a93af5e48,
+                    0x839ca318d8f09f8c,
+                    0x050ce4cae4dd65e5,
+                    0x41d6245669606b24,
+                    0x275c2a8d45659e28,
+                    0x126fda251d47e84d,
+                    0xdac04050575f6b23,
+                    0xe809c567c7d06051,
+                    0xf5cb1ee959afd475,
+                    0xa282b0ccf9037446,
+                    0x0103e335d0e3fe5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2c3d984ffcada349,
+                    0x01fc3f74beca864c,
+                    0x812da2fe4276501a,
+                    0x7fa730676124fd89,
+                    0x042381f0a62cc5f6,
+                    0x50c0d843e8ad1a4d,
+                    0x56adb9537c9259f4,
+                    0x5d910c758fb4f480,
+                    0x7d8862290659c504,
+                    0x6ee5e7a70467fcfd,
+                    0xf5fb83626b9e296a,
+                    0x0040e2d5ddd07f82,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x51035b02af492a2b,
+                    0x8628d8b702f5465f,
+                    0x3ae47ab3d0498f8f,
+                    0x2addf390833da5e3,
+                    0x50fa858b134a5223,
+                    0x26364245485f1da2,
+                    0x996d5cfacb291d37,
+                    0x131fd020164a79e6,
+                    0xf8f0a9edd2a0c639,
+                    0x667d7cd5cec39967,
+                    0x8a26419e3e8c2311,
+                    0x0002c50899b800d3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97d32e8dbabf32d9,
+                    0x592e4695d4302c2c,
+                    0x6afa7c405c086a4f,
+                    0x071824bce255019b,
+                    0xf0ee6c9d1e612d5b,
+                    0x72c1bfb8dd0ae374,
+                    0x3d0701046d7f7ab2,
+                    0xcfe36f6c6a3918e1,
+                    0xfb0104e5718faad2,
+                    0x28083e284113a89e,
+                    0xa06088687519398c,
+                    0x0107069b5eb5307f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd69ea8be9921a8d1,
+                    0x08fc2d0decc38c74,
+                    0x3116bd74cd19fb78,
+                    0x4c1fb215b1b38d19,
+                    0xd6fc789123328fcc,
+                    0x36047d7153230c07,
+                    0x5c23123b77eeffaf,
+                    0x6c84fb5ee1aefbec,
+                    0xe7be0908f74494cb,
+                    0x12dba1f44124d08b,
+                    0xc5ee19eff1d8b1af,
+                    0x0027aa8a671fa971,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xf2e1ed493e4e4305,
+                    0x62a501ca0da95dca,
+                    0x3373f3da75a46d60,
+                    0x8ab00b3d4232e849,
+                    0x6d8f34152ba8c2de,
+                    0x2495cec4f32659b5,
+                    0xdafa09384ca4919d,
+                    0x2bfdd6cdc3e9f00d,
+                    0x6b36c7774e623b4d,
+                    0xe15da48bba3bcccb,
+                    0x7b56f153c0bfabf1,
+                    0x00553942b08570f3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3bbb1b6a479ab560,
+                    0x06e37481ffce57eb,
+                    0xbd35a50ca8cc04ed,
+                    0x7a490d62a4e430d8,
+                    0x56423a629ef2ed75,
+                    0x28d55b2b87f6934b,
+                    0x069fef9762cfb601,
+                    0x0b081cee96e9e7f7,
+                    0x9da2127381fde7b5,
+                    0x40112c4ed19a8938,
+                    0x5a740ddf3e6feef4,
+                    0x0071f3c1d196dc69,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1441a236aecba3eb,
+                    0x072fcd698e9691e2,
+                    0x08a394a68bc807ef,
+                    0xd1cedb86d53fe872,
+                    0x701ada14a7954086,
+                    0x8f46543c1cbc8f89,
+                    0x5dde2da015051ee9,
+                    0x2128af843cb58749,
+                    0xbfa6b3bc2c4e6f78,
+                    0x2e5f8710b2962a96,
+                    0x291708a592c669c4,
+                    0x01225a024459188f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8207b162272b978f,
+                    0xd1938bde47d796d2,
+                    0x25ace014291fa199,
+                    0xc301f2926c534fc5,
+                    0xc527a434ab3dc716,
+                    0x9b16fb54ddcb9892,
+                    0xe24ec0b8e8749b89,
+                    0xa8891c714dd51093,
+                    0x5777f3e90eff7f78,
+                    0x8e85dbe0ab1c7a8d,
+                    0x5e56ffa35aabbc25,
+                    0x00f4e942c3f4ca49,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x57bf4a164a3fcfea,
+                    0x2f1c39d7a793a1f1,
+                    0x2de125c1d4bed2c8,
+                    0x40139d4cae15937f,
+                    0x91e0c047aee45722,
+                    0x0de7dabab69b4c42,
+                    0x97bc2020ae47d5eb,
+                    0xb14088b2611edef0,
+                    0x8927a9efa47bf5c1,
+                    0xd2a56a537ed0122a,
+                    0xf52741ce817f48ab,
+                    0x00716ea71c16c8fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4fa9b824374367a,
+                    0xc56df54c23594492,
+                    0xaeaf5684a354e41d,
+                    0x3356feaa6ea969f5,
+                    0x27575b1b9fcb4d17,
+                    0xada8048dddcb877c,
+                    0x7fd6a968b9fa8357,
+                    0x0eb4e2715d0241e1,
+                    0x741862b2935523d8,
+                    0x3f7318cc13a6cd7e,
+                    0x3f4eb896638ae852,
+                    0x006b742bdd1e5857,
+                ])),
+                Felt::new(BigInteger768([
+                    0x715bbf5587561a75,
+                    0x66fe622dfbfa36d7,
+                    0x19da159cd5fe785e,
+                    0x724a969e37370d66,
+                    0xe512f7f89f795803,
+                    0x84c59e5c335fa73b,
+                    0x77e5a86f47a3049b,
+                    0x8daa03402d0dfd62,
+                    0x6e2a6bce05f88557,
+                    0x887fe9eeb369e53e,
+                    0x559fe1fe287f755c,
+                    0x0037e28700df59e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x88c51f64300f2f03,
+                    0x9ac62fc79e10bca3,
+                    0x3447263b90929254,
+                    0x078ba8226723bd2d,
+                    0xae03dc5e08c53ab4,
+                    0x4101470aa350097f,
+                    0xc1f7e7e6fb943776,
+                    0x32101a094b570196,
+                    0x99914f2dfa39574d,
+                    0xdc20ee6349cfdae6,
+                    0x1bc5a985a64478a9,
+                    0x00f147aefbbc5724,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbbe37c33f86337e8,
+                    0xdd84ceab9f572932,
+                    0xcb076615a8da24f3,
+                    0xb63ed60e176260a5,
+                    0x6340722b5737e9ef,
+                    0x53b819d3c5cba643,
+                    0x2c930afc1663f284,
+                    0x3adbb80ff26c03e6,
+                    0xf7fe879a36bd3217,
+                    0x5d3e94c9a7c429b3,
+                    0x029e2e3aac69ae8a,
+                    0x006a29359a190eec,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x8ca1168a9077e0f0,
+                    0x09e531ae96371f09,
+                    0xa11ad380774d3950,
+                    0x369b679579c0ae60,
+                    0x72f1cc999cf7e295,
+                    0x5908bdd226a5ed12,
+                    0xfd01fb9eb1d8a815,
+                    0x79d1bd84a64b3f1f,
+                    0x36331a979db49e03,
+                    0xe574767d09ff53a3,
+                    0xae55a7f90fe71e05,
+                    0x006c1037da87247e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbfb05b448ad4282d,
+                    0x3d3260c9e57da1c6,
+                    0x7cdd4c6879d64805,
+                    0x1bde5c26210acd3c,
+                    0x5cd011dad35d206d,
+                    0xfe1d4fc468650c2d,
+                    0x1f4df363d6c3566b,
+                    0x72fedb39853fd6ea,
+                    0x64754ccca76165fd,
+                    0x9d3fd4520a877d95,
+                    0xe73e60b15e5b1b89,
+                    0x00a42b8007106f61,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7e996e5380eda03b,
+                    0x52aa441496e613a3,
+                    0x7013e5792cdb29ba,
+                    0x2a021ef95db881ad,
+                    0x33134c4ffc4ff1cf,
+                    0x661ebc7726107331,
+                    0xbd536913f4f49485,
+                    0x12cfb781528a07e9,
+                    0x20e2735e864a901b,
+                    0x474cecc0b8f56d62,
+                    0xbbd252e4a2836fec,
+                    0x0037ce277622bc8d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x477c02562e2a753d,
+                    0xdf2e2dcfa5dfc539,
+                    0xe96af2e68f55199e,
+                    0xd3666c1be2a6f142,
+                    0xd06990299f7eb865,
+                    0x8c65aeb2a1c4ed61,
+                    0xda415504cece93b4,
+                    0xa4e947605e17f6ba,
+                    0x706fd5e8bf75d0b7,
+                    0xa4fc4a6e2d598a2b,
+                    0x3f3fd2bb992d9fa8,
+                    0x00d0b877fffe3544,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed8075558ec6dbcd,
+                    0xd4de38528a31060a,
+                    0x5eadc7162be6c819,
+                    0x8769e67e563413fb,
+                    0x3e6f42c665a271a0,
+                    0xa3c12aace7f7b36b,
+                    0x50e828d2c2cdef35,
+                    0x09451e2d41b6f74f,
+                    0x5a6bbbe42ad09588,
+                    0x282c944bfb1f64df,
+                    0x75ac1cbf67f89fd1,
+                    0x00af8aeadf727d42,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72ede42aa678ce4b,
+                    0xebc2fc2a01396efc,
+                    0x6a9ce8c23e33ec50,
+                    0xf337ef17c8abfeae,
+                    0x4cf9645512a1ef69,
+                    0x3121b53c4b230235,
+                    0x7a51d5260f6a1dea,
+                    0xa3f98f9f561717ab,
+                    0xcd4b228be641dafb,
+                    0x64dd166ac85920a9,
+                    0xb65db7702f0c8aa8,
+                    0x00d8dd4ee62fc497,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0x4cce7622aeb0bdbb,
+                    0xc253c2b232ce9c45,
+                    0x7d9b0a5134fdc253,
+                    0x494db970f905d8ac,
+                    0x3ac1a127af85062f,
+                    0x0ab1053730d88cf9,
+                    0x95ac99a513f158b2,
+                    0x1661eb79d905c417,
+                    0x554bd11b8dad807a,
+                    0xa044b4c8c63cd842,
+                    0xbf6f95e323e10674,
+                    0x0039285139aa027c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb280bffc2e8753d6,
+                    0x3ea91ab4d1ee5a40,
+                    0x0ba270a9fa1ac1e0,
+                    0x20bbdb94ac62064a,
+                    0xe1d58e11465028e9,
+                    0x048646aa0379a7d3,
+                    0xfcc39de12e71a76a,
+                    0x45bfa1dd9e1384fb,
+                    0xf9365f61a54fb4e6,
+                    0x8434c3f352a9c8b4,
+                    0xcd024e57c7e5e425,
+                    0x0086a91f40e75e38,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x346e50236be16e92,
+                    0x8f3ad8cfcda91aaf,
+                    0xc16fdd9ac34d0102,
+                    0x271134fde69b4ae8,
+                    0xcb2ac784f3eda2c9,
+                    0x3c72463779e10fab,
+                    0x69d13b03689d3496,
+                    0x15a1d4bf3ed7b9ed,
+                    0x208f98747e9ebeec,
+                    0x57f900bb8b2c202c,
+                    0xac2c87a4786b3263,
+                    0x00cc130682910e37,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8bc6322807892e8f,
+                    0x5b9f3538bdceb2f6,
+                    0xce8242a840b2238b,
+                    0x426fe279f7ea605b,
+                    0x6fdc27f46df109f3,
+                    0xb157fc1d95db9df2,
+                    0x16efdcd9c1502c64,
+                    0x074e0ebeff1d0cc4,
+                    0x25deb090846cc7fd,
+                    0x155e0da3d023fa10,
+                    0x17bc0afdc7647f18,
+                    0x0038976bcbf26f2f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x84f8cf0778f704ad,
+                    0x9ed3a62b3eb203f1,
+                    0x0982f945a41f11e7,
+                    0xb818f3f8cbfd32e1,
+                    0x9a845d20d8307037,
+                    0xe9a23018828e9b04,
+                    0x01a96e2306dec5b2,
+                    0x0f25564f502f589d,
+                    0x41b4a49cdd326fa8,
+                    0x5b4ccecf620aec47,
+                    0xd7391cbdf3c17cc6,
+                    0x00483eb154c4d24a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19587e58bb3c0d8f,
+                    0xa5897dd0ce3e5e9e,
+                    0x446510b0e126cd92,
+                    0xb323d603b465a0a8,
+                    0x98a188d4c764d846,
+                    0x3b773cfac22695ee,
+                    0xab8443fe5faf05c0,
+                    0x7cb9723dac08e2c8,
+                    0x338f55ff522de6f6,
+                    0x174e784fdc839ddc,
+                    0xcec21db010df8bd6,
+                    0x001b5eb0dbeb227c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbbb85de4929fffa6,
+                    0xa2611bab5b71c6d7,
+                    0x3c06ac08ccc1d6ee,
+                    0xe95f877a200d2987,
+                    0x00974dbe8959d930,
+                    0xf7de176faf3c1046,
+                    0x7493e49e6a103553,
+                    0x09b350b49b9720b3,
+                    0xc9aa1e934139d057,
+                    0x61d0e46497aba40c,
+                    0x6971182d2fa360ab,
+                    0x00845cb7d44a9122,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd88766cc33a1c812,
+                    0x115761a5b8088b94,
+                    0x159033082b652ea7,
+                    0x4fbe0096b121bbc5,
+                    0x98554b3fc8dbd3a8,
+                    0xc8fc825b8521a0c1,
+                    0x9ae505de57ab03c9,
+                    0xa87c3a09919a4b13,
+                    0x5946ae7ef77a4a9d,
+                    0xe615ee713a658dd6,
+                    0x823df0a7e5208400,
+                    0x00208b07870563ae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0c92c86d0bc68110,
+                    0x74c298e25c3f709f,
+                    0x3dffb670d2721e89,
+                    0xd9d305a773412c70,
+                    0x1863f28f5ab8fb81,
+                    0x79b971c788d04ad6,
+                    0x4f1530e93ac19d73,
+                    0x3beceaa73bbb644d,
+                    0x52a3bbb7153125e8,
+                    0x46b478be07f6704f,
+                    0x404958fc1df9d1d2,
+                    0x00220a4c29fe7d52,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa44733904d0bd8c6,
+                    0xc63d4c4dfa7b1d48,
+                    0x7d1280f8c55caeda,
+                    0x613053413c08ff60,
+                    0xf006177973a8d24f,
+                    0x31be4eea7c006d40,
+                    0xfc2205925fd8ac62,
+                    0xc455bb8ac0dd6327,
+                    0xf4dc229a6580068f,
+                    0xa380a3a63f866e42,
+                    0xfe8b92c11a0ff94e,
+                    0x007716a20e74085d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x558cb720bf085888,
+                    0xffd3b7b3612db2d9,
+                    0x477be13e4c21a2c4,
+                    0x7e37358869e2e928,
+                    0x6bc58c5304d385cc,
+                    0x4324bd81c55d340d,
+                    0xd931064b1c713889,
+                    0x75990f12ee1c2df9,
+                    0xf34c008af56d299d,
+                    0xf7250e355ba52ae2,
+                    0x18d81fc7863003b8,
+                    0x00ad4de5fabcfd98,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb0579d4cff890c6c,
+                    0xc07aedfa1cd0b978,
+                    0xb42873ba29ef68a9,
+                    0xf42b60bf595abf94,
+                    0xa45bf01329f7cb97,
+                    0x72d4bac14d9701d4,
+                    0xce44a9ebf76c80e3,
+                    0xcd647cadcba3e9ee,
+                    0x2089408c070e16e0,
+                    0x2ccb4551e6fd0d5d,
+                    0x3b746027602e5b6d,
+                    0x00ffcf009026928c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3bd67e4fffef8883,
+                    0x564c1e6a6158eeb6,
+                    0x17cb24f5a5c3814e,
+                    0xd9a1215f3940ae6c,
+                    0xe865ebaf5537b430,
+                    0xe473c6753ac2c50a,
+                    0xef0d238368da2c37,
+                    0xccf6d0197af84371,
+                    0xb55ef4d316061935,
+                    0x20f1fae893f2f6a5,
+                    0xd1dfd1c7410867ce,
+                    0x00cb2da1fceb3cb0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa58694159467bab5,
+                    0x37e360b989d91d3b,
+                    0xdcbab0e68e841910,
+                    0x51cbdf94a25cc329,
+                    0x7c2a91e0b4584f0d,
+                    0xc89bc9387b5011a3,
+                    0x0f61355590b3e041,
+                    0x61a5c388df6aabb9,
+                    0x8e89d9bc27a9557b,
+                    0x8a48f9ba3a179ad4,
+                    0x76ad9922aeba821d,
+                    0x010bfbb4cbf2ba67,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x762a1bfc2b769611,
+                    0x45ea648862431e5a,
+                    0xef6ffbdc29ec1831,
+                    0x1c6d88554a8f6003,
+                    0xaf8f32c517b83942,
+                    0x962d7c56800eeaea,
+                    0xd9335d0ae4fe93c8,
+                    0x76de0d474aefbee0,
+                    0x2393a6816a23b8b7,
+                    0x800be8b76aad6e98,
+                    0x6a9ba489fc97c07e,
+                    0x0043773fae55c36a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2bac716f8e0ea42b,
+                    0xfa0a0812ce669272,
+                    0x09a9622faaad0d18,
+                    0x8bb7fd3a669d756b,
+                    0xdfebd029e14235c4,
+                    0xa328f166b30601ff,
+                    0xec873cdca1ea8538,
+                    0xedbb5d7d6d96dfdc,
+                    0xf7a159d902693b18,
+                    0x174513c47989ac22,
+                    0x7c1a70439bfbd33e,
+                    0x00143c9612593626,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5b3470cb095d32cb,
+                    0xbf47e14a261f4214,
+                    0xb67932855606214b,
+                    0x90c4290f89b9466d,
+                    0xbb5db2e918d3b51b,
+                    0xa9fbb6754f588657,
+                    0xd010e912016d7059,
+                    0x8e85f131d8b92921,
+                    0xd94916e263dfcbee,
+                    0x64cced8a5a0db199,
+                    0x99f56962ff0cc5c3,
+                    0x003a18083bef44c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf7beea8ef2710946,
+                    0x1cac85cd9dd2d276,
+                    0x55d0d5ae6c2b8271,
+                    0x6425f5e34252c56e,
+                    0xe7c808c8015d8c91,
+                    0xe4780347ae561f31,
+                    0xf3b5864facc213c3,
+                    0xe5bdd4d9221be9bc,
+                    0x3ae288824aa023cb,
+                    0x83ded6d5b9224ab5,
+                    0xde575eb47cda1650,
+                    0x0120e1a909e4cef1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd486b8114f375e57,
+                    0x2abf2a97adab8503,
+                    0x91d808f6236e802c,
+                    0x95fdf3b672ee798f,
+                    0x0a7a3673f86449f3,
+                    0xc7df732c70a41103,
+                    0x62cb952554edc97e,
+                    0x51294ef391e51f6f,
+                    0xe50ec96a026b1f69,
+                    0x1305ece9c3558796,
+                    0x147462b1ec59c4c4,
+                    0x00ae42a83c7d6143,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeaccff7d4ee59dd7,
+                    0x77d5cf9816f3c98c,
+                    0x2f12c23893e485ad,
+                    0x0b8684e19082d28e,
+                    0x56c6912663ad50fa,
+                    0x3e5599e0744721d3,
+                    0xa19c720bb4714058,
+                    0xd00b77888e2cb038,
+                    0x5db11f98a9bfd817,
+                    0x32b5f1c554d63447,
+                    0xd2f44a83d6718825,
+                    0x00d997d14e38939c,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0x4cce7622aeb0bdbb,
+                    0xc253c2b232ce9c45,
+                    0x7d9b0a5134fdc253,
+                    0x494db970f905d8ac,
+                    0x3ac1a127af85062f,
+                    0x0ab1053730d88cf9,
+                    0x95ac99a513f158b2,
+                    0x1661eb79d905c417,
+                    0x554bd11b8dad807a,
+                    0xa044b4c8c63cd842,
+                    0xbf6f95e323e10674,
+                    0x0039285139aa027c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb280bffc2e8753d6,
+                    0x3ea91ab4d1ee5a40,
+                    0x0ba270a9fa1ac1e0,
+                    0x20bbdb94ac62064a,
+                    0xe1d58e11465028e9,
+                    0x048646aa0379a7d3,
+                    0xfcc39de12e71a76a,
+                    0x45bfa1dd9e1384fb,
+                    0xf9365f61a54fb4e6,
+                    0x8434c3f352a9c8b4,
+                    0xcd024e57c7e5e425,
+                    0x0086a91f40e75e38,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x346e50236be16e92,
+                    0x8f3ad8cfcda91aaf,
+                    0xc16fdd9ac34d0102,
+                    0x271134fde69b4ae8,
+                    0xcb2ac784f3eda2c9,
+                    0x3c72463779e10fab,
+                    0x69d13b03689d3496,
+                    0x15a1d4bf3ed7b9ed,
+                    0x208f98747e9ebeec,
+                    0x57f900bb8b2c202c,
+                    0xac2c87a4786b3263,
+                    0x00cc130682910e37,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8bc6322807892e8f,
+                    0x5b9f3538bdceb2f6,
+                    0xce8242a840b2238b,
+                    0x426fe279f7ea605b,
+                    0x6fdc27f46df109f3,
+                    0xb157fc1d95db9df2,
+                    0x16efdcd9c1502c64,
+                    0x074e0ebeff1d0cc4,
+                    0x25deb090846cc7fd,
+                    0x155e0da3d023fa10,
+                    0x17bc0afdc7647f18,
+                    0x0038976bcbf26f2f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x84f8cf0778f704ad,
+                    0x9ed3a62b3eb203f1,
+                    0x0982f945a41f11e7,
+                    0xb818f3f8cbfd32e1,
+                    0x9a845d20d8307037,
+                    0xe9a23018828e9b04,
+                    0x01a96e2306dec5b2,
+                    0x0f25564f502f589d,
+                    0x41b4a49cdd326fa8,
+                    0x5b4ccecf620aec47,
+                    0xd7391cbdf3c17cc6,
+                    0x00483eb154c4d24a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19587e58bb3c0d8f,
+                    0xa5897dd0ce3e5e9e,
+                    0x446510b0e126cd92,
+                    0xb323d603b465a0a8,
+                    0x98a188d4c764d846,
+                    0x3b773cfac22695ee,
+                    0xab8443fe5faf05c0,
+                    0x7cb9723dac08e2c8,
+                    0x338f55ff522de6f6,
+                    0x174e784fdc839ddc,
+                    0xcec21db010df8bd6,
+                    0x001b5eb0dbeb227c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbbb85de4929fffa6,
+                    0xa2611bab5b71c6d7,
+                    0x3c06ac08ccc1d6ee,
+                    0xe95f877a200d2987,
+                    0x00974dbe8959d930,
+                    0xf7de176faf3c1046,
+                    0x7493e49e6a103553,
+                    0x09b350b49b9720b3,
+                    0xc9aa1e934139d057,
+                    0x61d0e46497aba40c,
+                    0x6971182d2fa360ab,
+                    0x00845cb7d44a9122,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd88766cc33a1c812,
+                    0x115761a5b8088b94,
+                    0x159033082b652ea7,
+                    0x4fbe0096b121bbc5,
+                    0x98554b3fc8dbd3a8,
+                    0xc8fc825b8521a0c1,
+                    0x9ae505de57ab03c9,
+                    0xa87c3a09919a4b13,
+                    0x5946ae7ef77a4a9d,
+                    0xe615ee713a658dd6,
+                    0x823df0a7e5208400,
+                    0x00208b07870563ae,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 380];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger768([
+                    0xa2c28093fac77f71,
+                    0xa3ddd363943e40b5,
+                    0x7908fbbf428c8a56,
+                    0x50a746c3d7c1f7f9,
+                    0x1b9bf6467d89b576,
+                    0xf14d05dbafe82596,
+                    0x78f2f7c9e6f1eef3,
+                    0x6b34a0e1d810b439,
+                    0xc246a5bf32ec3d46,
+                    0x7a92cf15efee1581,
+                    0x39eba9d91c2cf11e,
+                    0x009740b3537b60bf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc40e6582ec8563d,
+                    0x3ecf67a361b00300,
+                    0xb6b5f53a45afe596,
+                    0x207cd55321a0a88c,
+                    0x5d855b474bd06e5e,
+                    0xd9ab4514956d9e72,
+                    0xa113498373533510,
+                    0x168c43b74bbe5a03,
+                    0x30ec60214a321e75,
+                    0x1e72ec19ddb46de0,
+                    0xffe26b20a9910478,
+                    0x00871fc8b8bd5370,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce324544989ea50e,
+                    0x634d9bf412a9a4d8,
+                    0xcf91d4edb0fe7510,
+                    0x8c764b6cad1643dd,
+                    0x6a050c1c995224de,
+                    0x2fc96aae05d0f0f8,
+                    0xc370b197a9a9f57a,
+                    0x6451b3b95e78303b,
+                    0x7c756dfc301d6264,
+                    0xc11767e19439090d,
+                    0x8d2d2dc96fc1ab3b,
+                    0x00ca0ec5a67bee5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7053ba4ae6a0e92d,
+                    0xcda5490a4a6c5ece,
+                    0x4fff8d28fd248ce0,
+                    0xed1ba825d2f17ab4,
+                    0x86cbb3bd5bc4236a,
+                    0xd061f0ef3dd602e1,
+                    0x48067f19c8873acd,
+                    0x8608a324d66667d6,
+                    0x1a10ec153d22696a,
+                    0xa7cff1acc5c605de,
+                    0xda717fe2ccec4800,
+                    0x008d26dc5f7b1898,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xe9594b128f1a9287,
+                    0xda96574e2dce64af,
+                    0xf9a2a207369b22e4,
+                    0xc80ee4321b4a7d70,
+                    0xebabbb9224309bf8,
+                    0x41d3d1aa34e6658a,
+                    0x1b49fd8a7b23ca3a,
+                    0x56d2079937e2f341,
+                    0xe0412365f4fac6f7,
+                    0x40c7a4dc37a6ddab,
+                    0x529c10ad6ba8744e,
+                    0x00b8a00d29872c97,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4ac0187b9efa995,
+                    0x2a261e671eb69368,
+                    0x517c79d464d66519,
+                    0x5a714519f28682bc,
+                    0xc282a2e2edd46444,
+                    0xd9b11f33755f8407,
+                    0xfeb6d804bce06c75,
+                    0x71e232bd0f47f038,
+                    0xfda41144592dcf18,
+                    0xfbe7e528ccdfce17,
+                    0x77a19dc290ac44fd,
+                    0x00bdeda3a7f48ae1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f4ceee95b35c546,
+                    0x64a16c67aa782b8a,
+                    0xa0b6bc1a7b43c84f,
+                    0x2f02a4f19fbffe77,
+                    0xe4b660d248b154c9,
+                    0x822540bc20521bd5,
+                    0xfe92d8dead74ed73,
+                    0xf5ac51cd2c2272a8,
+                    0x4d22c1b170b6b932,
+                    0x518eec29ce785853,
+                    0x1472931d16d9c8c1,
+                    0x0101f4cfa1e6dc66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a858db188628704,
+                    0x52170e1c1edda7ba,
+                    0x2f80787641c61220,
+                    0x0aadc6f2644123f8,
+                    0x7e11058ac590795a,
+                    0x4d3a6dc47a03bc6a,
+                    0x4575455e853b663b,
+                    0x133b931784cd91ca,
+                    0xe97b662f08154d8d,
+                    0xda9c85ebada11ce3,
+                    0x2b8b66990438205b,
+                    0x00830ca97d9724f2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xd6444fbd87884faa,
+                    0xff52d376db0080d0,
+                    0x39dc30db20c5068d,
+                    0xbf01f6a13c546a42,
+                    0x15f778577d0c7e50,
+                    0x6fcf26a64109809c,
+                    0x0c5082f823e9aed0,
+                    0x5d764d6af9b7eff8,
+                    0x18faa563a6c1cf98,
+                    0xc1aaa0b35a53e918,
+                    0x549c1cdd52d5a78c,
+                    0x00cce579a730c7e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x390339ea24b77ba8,
+                    0xc6e24158696b259a,
+                    0x48b1cc8d3bd27e49,
+                    0x38ce5eaaec4996ca,
+                    0x47248041837efdef,
+                    0x21cb394801797e46,
+                    0xb0f6aea01da960f6,
+                    0x4b2a91a90875cfbe,
+                    0x3a9b3f6a0cda6806,
+                    0xd68c42de1c5920e1,
+                    0xfd5367a616dde3a9,
+                    0x00696d6e0f27eda2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d277d57799ebafa,
+                    0xe242d78b4a16f564,
+                    0x0c440499d15bbee1,
+                    0xc754a3e9fb07fee1,
+                    0xa3e951e85ed1ecc0,
+                    0x70b7ebbd42ab2ead,
+                    0xc4a13cd61775ca09,
+                    0xf5bd0d56c7701ad3,
+                    0x10fbbd19b4247e64,
+                    0x9a1de060177f3ac6,
+                    0xa8d362b83614befd,
+                    0x002fbc2b77273e6c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26df3eb5f29629b7,
+                    0x8e6546a28b22df82,
+                    0x00ba5826ce46040e,
+                    0x01993cb628d8b507,
+                    0x6b6f8b8f3e05ef0c,
+                    0x1a37564391512c60,
+                    0x1c6e99474a881618,
+                    0x036ccc8ca737b67c,
+                    0x88951ba15d4e9a03,
+                    0x4add65318c4f3d82,
+                    0x4bd1e8396bac5a0c,
+                    0x010128c9c3542fea,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x480da20f0686595b,
+                    0xfb2e621bfc894fea,
+                    0x226cca4d6dc613d0,
+                    0x882725b199ca8b7e,
+                    0x7902508ed38cb884,
+                    0xa3b16875212392a3,
+                    0x406fa7d5ab9908e4,
+                    0xac0b869b98a7ad34,
+                    0x46559d72b32717d5,
+                    0xd468e8c36891fb5a,
+                    0x90fa5930a8a3fff0,
+                    0x00441a705b7c1eea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6106ccc7bc829b9,
+                    0x1fee1465523b284b,
+                    0xcdabc609934779cb,
+                    0xa45e9db12db6c975,
+                    0x8f8c2dec098dc129,
+                    0xf806b3dcf4efc5d0,
+                    0xc91cb78119eb250d,
+                    0x656c2b2a5c8fecec,
+                    0xa443a77e2190ff69,
+                    0x0bb61d2bbf2c2778,
+                    0x6e582fa63b8be1cf,
+                    0x00a8841882a93557,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a31c9c3168fd1d6,
+                    0xb79ee26e7758294e,
+                    0xf3562cbc32b7674f,
+                    0x6c2ceb224eb67cce,
+                    0x8b7cd192d13949eb,
+                    0xe00dec1564cb7611,
+                    0x587d8f8bcc2dea32,
+                    0x161d07f0a7af7c4f,
+                    0xe8c65a8dedddf2ec,
+                    0x3c17c5f3a07c746b,
+                    0x9d8815069f9bd85f,
+                    0x001dc63b549f3af8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x392880b72c2f68db,
+                    0x402cbeb31e660527,
+                    0x693ac357465684d9,
+                    0x8d904ae04e7d3d08,
+                    0x4c7ad016d3339655,
+                    0xbe623a32d31e7f85,
+                    0x7b3c94d9a04b37b3,
+                    0xff00ca83c3376fca,
+                    0x1281a432538d6921,
+                    0xfc0a64bbe5a9cfd2,
+                    0x8c8495c7ca803606,
+                    0x00dc8c0b37f3846c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xf4392f23874f3e01,
+                    0x3f169c0a5f24ffc5,
+                    0x2b6fe6f816af6e02,
+                    0xf852448d0e7cb332,
+                    0x4559acf10c6a7b57,
+                    0xcd5e178fa788c532,
+                    0x505c8b2c5c2cbea3,
+                    0xe365e41a3c7c5398,
+                    0x97a93c7b1d2fafe6,
+                    0xb9cb9c231a5e9da5,
+                    0x2212286814eb0e0c,
+                    0x00d109be9629cd8a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe2b4a020567ef0f1,
+                    0x1a5039bb360ac2fe,
+                    0x4426640227ea8379,
+                    0x7ad3e7aa4785dd23,
+                    0x8a985b1459935c10,
+                    0xbe0b6f1c04a1021a,
+                    0x3e7bcb76ffc0f794,
+                    0x6e05f6ef0e5fb613,
+                    0xca040c0e366eabc8,
+                    0xf9ff89d42188b28e,
+                    0x05c696ae52187f67,
+                    0x003c821ca8e75607,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1eb6156c80aeb714,
+                    0x22bcbf7754f13952,
+                    0x693cca964a8d48e6,
+                    0x240ca00ceb39d300,
+                    0x32e0d99bc5a27355,
+                    0x67b92c0f20d9547e,
+                    0xe56c04c3ae1e4342,
+                    0x327deb6314e3bdc0,
+                    0xbf87ef69e48fd3cb,
+                    0x8fd94c8da86a931d,
+                    0x1518d45efd90a1d9,
+                    0x0022ecbd92483ca3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9fdc8957ae71ddc7,
+                    0x4280d3a0e6ebdae1,
+                    0x22b0dc1996318102,
+                    0x420a6199d700544c,
+                    0x1d51356c50e9a9e6,
+                    0x84a12afdfc0db1c9,
+                    0x90abc05b1d390e05,
+                    0xa61c5100316eb2e7,
+                    0x74a6c44c5fd8c74a,
+                    0xecd08b0835806e20,
+                    0xf89654e9d02b8d85,
+                    0x002d4eaa151aaa8c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x1a20e7cb26986586,
+                    0xa7be5a5bf6ad5822,
+                    0xd137b5fef16a4591,
+                    0x358dfbadf8029fe8,
+                    0xed65bdb8c1923527,
+                    0x741b50eb5a9fe8db,
+                    0x18fa824bdfca7882,
+                    0x248669acb1a4520c,
+                    0xed06d76a6db9270e,
+                    0xaaa56278d2156eb7,
+                    0xaa456083b3452601,
+                    0x007395c589c99c56,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd97b67cb937cbe80,
+                    0x7392c75eebb86136,
+                    0x84751bf069e5633d,
+                    0xe59ca22760d01746,
+                    0x20007936656b20a1,
+                    0xc6ec0590dbd82be9,
+                    0xd1e9bd61e0e87c3b,
+                    0x706359f035d01dbb,
+                    0xdfe70399dbe51ad4,
+                    0xc25344cd4f6dffb3,
+                    0x9cff2e8a5f36fafc,
+                    0x0083a834b2951521,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1bbf6b343346a78,
+                    0xb682e4036eec58cb,
+                    0x8b7d801af9b1b4c2,
+                    0xc5b169ed3d2148b4,
+                    0xbac6c5155c3803fe,
+                    0x4b0ac5ac02303f56,
+                    0xa6590e6b5c6895ae,
+                    0xfa4a1c37d42b919e,
+                    0x798e914a5e30e20b,
+                    0x2b71d9910386f905,
+                    0x6b155e2318229cef,
+                    0x00028467553e9ba5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae6a634adc465f2d,
+                    0xa23bef8bb533c66f,
+                    0xeddf0ca2375ccedc,
+                    0x00381cc74836e729,
+                    0xa3dbfe76a95dd1c1,
+                    0x1b357c01fd3f2893,
+                    0x720cae84e2fd36e9,
+                    0x039bdea60fa13745,
+                    0xd950138572ebf5ae,
+                    0x08537665260a1945,
+                    0x58596c832682e28e,
+                    0x0067c312a83fc21f,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xc2800da85952d574,
+                    0xa79bd4d050e9e1e8,
+                    0xbe399e912b0d0198,
+                    0x921f737f5b0f6e73,
+                    0x05cc0ba1bbae9a2f,
+                    0x9a16b8563a10f180,
+                    0x3e21eb083d27f520,
+                    0xee70442ebae4ed83,
+                    0xc5a8853b12ebc1e0,
+                    0x959277a826408fb9,
+                    0x95cc8a364fca10eb,
+                    0x007b64900a0e65da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x59d3a0b9db187e31,
+                    0x30d49ac799f2bdbb,
+                    0xed4ea76db4ab0ca7,
+                    0x7781d54ac58212f7,
+                    0xa73bd09eb7f4e6f0,
+                    0x8f18ff08c7890019,
+                    0x3a49e86c1b5d93e7,
+                    0x76323d824ea0793e,
+                    0xe9f0d2403f1574f0,
+                    0xf5493b401ab6ff29,
+                    0x53efe9a9906744d0,
+                    0x006850318014844c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x16032eb88d54152f,
+                    0x297a7ec70787965d,
+                    0x1ef7a9801ecd564a,
+                    0x6036dd31e44c47e8,
+                    0x86df367bac9f0bf7,
+                    0xe4983872cf1f7ce9,
+                    0x44921ce95ece4af4,
+                    0x08792fbb3ce519b3,
+                    0xf3783e782228d1e1,
+                    0x230dd8317630ae55,
+                    0x2439010c4f367d6e,
+                    0x0071485b6f57384b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x63a61ec94a0fa60f,
+                    0xbd60a5d66f27c3c6,
+                    0x70b5e6c1aab21325,
+                    0xc2630da9f19e373e,
+                    0x690611e3b843d567,
+                    0xa04872d8144aafc3,
+                    0x164c516085f1b025,
+                    0xc4687f41cac63669,
+                    0x6be73777d07a2a5d,
+                    0x95111aab6d461c30,
+                    0x31a28dd825fc60e8,
+                    0x007a4e4f566764dc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8c423570bf110527,
+                    0x1e89954159d664f3,
+                    0x3681fb4e29e1fe07,
+                    0x7f53ddb10843e5db,
+                    0x7c1449b99b4fd207,
+                    0x18468d6920ae23a1,
+                    0x2e003fc6626b9d1d,
+                    0xac98a24d035c9b02,
+                    0xd34a8c352be77949,
+                    0x8d737213db95f28c,
+                    0x693448eabc1cbc75,
+                    0x005755799c28c969,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51fd21e4cabbfa0e,
+                    0x1b9663af41eb60e1,
+                    0x3aefd82b8246bf6a,
+                    0x3d864cc9a8f60546,
+                    0x5b27ce513b2a83fd,
+                    0xca354818778e3941,
+                    0xb141ab8396f30bc5,
+                    0xc2b665c35f8a70c6,
+                    0x82199017234e9a34,
+                    0xa85f196731beeb4e,
+                    0x6abfd503ac80d624,
+                    0x0075a8e654f17fb4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc296d40a0a835874,
+                    0x6dad31a480184b77,
+                    0x480488d6fe66c9a9,
+                    0xa87d74c0c2aa1239,
+                    0x63caf63a5081fa93,
+                    0x5a02aa7aaacbd9b2,
+                    0xb08b0aa940200ba2,
+                    0xd8330fbf01b2a8f2,
+                    0x95102280e9491907,
+                    0xa0ae8ae9c39864b6,
+                    0x7ebde2eccfc0fe19,
+                    0x0036b2b22c5c8dfa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbf3699df5e9c0dff,
+                    0xe09dcd6de9bd7d16,
+                    0xf9f3a4d742a59ced,
+                    0x29cc595290d4ade4,
+                    0xa942b0da9c9623df,
+                    0x754959084d6eb0c8,
+                    0x9962f616d704331d,
+                    0x38aae5324f327f13,
+                    0x6db1cada75007f40,
+                    0xa6278ef782b39a28,
+                    0x516fae8047ae5361,
+                    0x00e270faefe08ea1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0877f5544c6034bf,
+                    0x6ad940052fed68dd,
+                    0x8d91ca399d930d9d,
+                    0xee1a79f1d17ef139,
+                    0x0ff9f54473850aa1,
+                    0x1f3293ba6fec3f19,
+                    0xaa7a386d4ea6c0fd,
+                    0x3d5f72cd1ecd0a43,
+                    0x7c5a3d96ded04025,
+                    0x06ca310580c56053,
+                    0xdf74a1a6f9a064cc,
+                    0x00065eb9e46d9435,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda28cd038194922e,
+                    0x68a544091f83d24d,
+                    0xba524d831965fdcc,
+                    0xc49c02c0a7e8a301,
+                    0x9547086ac98ba131,
+                    0xacdab0feb55c97e5,
+                    0xd474736978bd6a26,
+                    0xe23b36b6459c8805,
+                    0xb85758389fca4e33,
+                    0x97910b092cf759ff,
+                    0x5492e34c3227aca0,
+                    0x001a2997bba81918,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1fb5c66b7e2756ba,
+                    0x05bae42d95763e66,
+                    0xf53c2f5e3292d189,
+                    0xc2d7c6062347281f,
+                    0x9c3bd0979b70838a,
+                    0xb0ddbfe2801282e8,
+                    0x38ababa4dfe506a1,
+                    0x143d2e23902a56c2,
+                    0x364353888b9250d5,
+                    0xa7c5ae4fe91f36b2,
+                    0xf6cc3c252dc80e6c,
+                    0x0044a2534a9adad4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7d2950ad2f5b26e1,
+                    0x4e39ea51b61bed99,
+                    0xae7337c6e9bc56f2,
+                    0xff6fa761f8a400b8,
+                    0x435ca09e88dcfd63,
+                    0x597af7c14b7a3a89,
+                    0x509b92e0df127f32,
+                    0x062a91091c192c0e,
+                    0x3326cd177bf8d9e8,
+                    0x932135047db8d401,
+                    0x5a7d4b51ca675f8f,
+                    0x005d35a6f74065c2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xae2bf4b1af23cab9,
+                    0x70b5a4f2edf7270a,
+                    0xd334bd08cc293637,
+                    0x01c2df29949382c8,
+                    0x99b4e34f31e188d7,
+                    0x198288a402c6391a,
+                    0x427461e5e3695169,
+                    0x002d6a3a9f1361bb,
+                    0x6cf919a2ed2508e0,
+                    0xe4e220bc73a48ab2,
+                    0x28dc74959a33bc4a,
+                    0x00be21aa0ac17c53,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd2af008030f7e176,
+                    0xbfc5438b8726011e,
+                    0xde0860f33427c333,
+                    0xccb926a2897998a8,
+                    0xde88b54feed7f11b,
+                    0xc276541c8b2fc00d,
+                    0x22d4fcfee5df4caa,
+                    0xb61ab4722c4f7f15,
+                    0xad4cb42528de606f,
+                    0x8cc07bda99dddaae,
+                    0x91c7e4ab1a586a9b,
+                    0x0102af0c126fd099,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5a209ea959e1067e,
+                    0xfaac4822dc3f5038,
+                    0xf7574557d315537c,
+                    0xae2a3bb8c3189982,
+                    0x30ec57bf09a939ea,
+                    0xa15d20427604b77a,
+                    0x74ff7e78110b8bcd,
+                    0x5ac459c254b5fabc,
+                    0x0918c50ac64166cd,
+                    0xc5eb0314868f819e,
+                    0x1fb133c975cbd014,
+                    0x003ebe8c7df95ca1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa94c1b137b739d8f,
+                    0x7e832830854cbe2d,
+                    0x74dc26ea7ff72297,
+                    0xe593fd3ba5b8dfa7,
+                    0x76a031724b3716e6,
+                    0xdc600d116e804c4d,
+                    0x76c5b9bab318c36a,
+                    0x0a60b58792087de1,
+                    0x124ef2a7f6b65a26,
+                    0x75538019526443ab,
+                    0xea67f1875b40297d,
+                    0x000542dae97e19d1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe371f49620623ec6,
+                    0x280da259000f2fa1,
+                    0x064e03540a4beb46,
+                    0x93fb677fc8a6c1bb,
+                    0x00c7aa65bc4938c5,
+                    0x7e38c5e9600fef7b,
+                    0x4f2d0effa2171fe3,
+                    0x5e100b1375989a6f,
+                    0x5d7918959e953d3c,
+                    0x94c4402f39112660,
+                    0xf51f706e3fe6cf4e,
+                    0x0015ee4cf200a081,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde089a690a0bb537,
+                    0xe6d0e220862d5780,
+                    0x087b80dcbf15fa8e,
+                    0xf4fa45e0df8d309a,
+                    0x934e24633887b17d,
+                    0x37b158e9a7955759,
+                    0x820052588de83329,
+                    0x4c7de8f1c91a29c9,
+                    0x452fc6c03b5c65c2,
+                    0x48ebfc050d698e08,
+                    0x4acceb8c24fdc667,
+                    0x0083e6e1e240c199,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x56cea1d6f84ab9a4,
+                    0x237c5618d4dacd01,
+                    0xe6409e7f498081c6,
+                    0x218ccd20cc2056dd,
+                    0xbe15658c51327446,
+                    0xfb7f413ddd9cca58,
+                    0x9670b484d1f49b73,
+                    0x2791357fa678aef7,
+                    0x66c4cd7c6c5e75f5,
+                    0x994864f1ea5dac62,
+                    0x4672341e9eca16e4,
+                    0x00ad6b2fb6ea5892,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72b2fa418cb76d0f,
+                    0x213b1400da65a01e,
+                    0xb1faea4ac39c32f3,
+                    0x79c88d301b7452c8,
+                    0xf469d8ae5807acb3,
+                    0xdb287d012d1865a9,
+                    0x4749903087a714e2,
+                    0xe95a4c3661d66c0f,
+                    0x5cdb814c00bbd455,
+                    0xbd47ccc4122df10f,
+                    0x55c9929889d607ad,
+                    0x003051b6807297eb,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger768([
+                    0x73be91afa666d14e,
+                    0x90879efe798cbad4,
+                    0x2849ecdf476a0031,
+                    0x0583c8a6fa45c947,
+                    0x4bed327af5c57291,
+                    0xf5c3e8d4e0410dd9,
+                    0xe38dbdf74b3cd9fa,
+                    0x09d4beb5e352eb35,
+                    0x9ad8d7d37a9215ab,
+                    0x76fa5a74a203b61a,
+                    0x546a2defc12b36c8,
+                    0x0013ed087237085d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd12edb6b8797039b,
+                    0xa4586230bf300fda,
+                    0x838e6e8b3c757ddf,
+                    0x90d3d66a0fda9b5b,
+                    0x58512fe3b9884864,
+                    0x65a3458ea980c123,
+                    0x8eb3e372a0938d51,
+                    0xd107b81a59968cd7,
+                    0xcd522c249f40c0fe,
+                    0xc595520dc5a2cf33,
+                    0x7f3d4302021d434d,
+                    0x00cdb411ea3ba26c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22f231404e6db54f,
+                    0x5853fd8262703353,
+                    0x573fe12923314cba,
+                    0xf091d381cefcec52,
+                    0x31de561fc227d26d,
+                    0x8fb038dd5cf9b275,
+                    0x17472e3e925d24e2,
+                    0xac735a752b8c6e3e,
+                    0x06e4ed531fca01f3,
+                    0xe2def02353feb6b1,
+                    0x31f77315815886ad,
+                    0x00db49c51b327377,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b262b6384bdb5b0,
+                    0x6be6b1497327666f,
+                    0x2a987921a2be0f2e,
+                    0x7564b524c3aded71,
+                    0xc4824d30d625f352,
+                    0x194bc3b0e8d3f5fe,
+                    0x19d6f7b9fdce3453,
+                    0x1583437a181513d7,
+                    0xa284d507351a2e30,
+                    0x450ddd09e65b7dc5,
+                    0xbb703614f31077e1,
+                    0x0033d865f491af1b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xab8e224c0725c27d,
+                    0x2ca98bab4a09d9fc,
+                    0x191bbfe6dfd0d446,
+                    0x19451e4d3694fc66,
+                    0x57d0ee2392235826,
+                    0xa9a8b1452aec1486,
+                    0xf2ffffe65b93c4e2,
+                    0x8430524f7e26e960,
+                    0x5cfea057b18d84ce,
+                    0x071c0a806c60baf7,
+                    0xa7be50bf6e646a3d,
+                    0x002e6c9283ebcc55,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5b22bb96e9b37a02,
+                    0xd5e972f338bd5a9d,
+                    0x7c0900318d28166b,
+                    0x76a768fffd39895c,
+                    0x1854442099f51537,
+                    0xa5fe08cda12c0b75,
+                    0x25066d4b37262df5,
+                    0xd4f8e71fbe571662,
+                    0x78829b28fc8de7fd,
+                    0x102d7c8767cbd856,
+                    0x69a7f6e647e7eb7c,
+                    0x00e971b6dfcc0552,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc0b1e37f8e500e7f,
+                    0xdc34453429afa125,
+                    0x103a1bac76be91cc,
+                    0xbbc8a33991ab3f33,
+                    0x40d64e7c71e67117,
+                    0xf5d9800542f72b51,
+                    0x058e2f59ddf0b42b,
+                    0x11cc0f23072d9e83,
+                    0x395fd1b03fa79e0b,
+                    0x8eb96852cfa6ecdc,
+                    0xc183355b80e617c1,
+                    0x0104858d7e007e63,
+                ])),
+                Felt::new(BigInteger768([
+                    0x896d1c73fdf0c627,
+                    0x2d3421e253ec3756,
+                    0x433b29a181f4f103,
+                    0x33432b4432c8cb9c,
+                    0xfa5e70caac740547,
+                    0x5679eb07e6643ed1,
+                    0x03ad4eeb211e8680,
+                    0x6e9e4bfe2ffc39b7,
+                    0xac17837e8f453fe7,
+                    0x7a43ff233964f4f1,
+                    0xa0a39740957bfc6d,
+                    0x00ddfbfb3678ab11,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x89a0603f79435e65,
+                    0x61dedd28c1a5b171,
+                    0x3d0248d00614a70c,
+                    0xae3617b970c79b9c,
+                    0xe068743513ba6a99,
+                    0x943216f2ef0ef99b,
+                    0x3b7470398d53844e,
+                    0xee0831656077c285,
+                    0x57c9e4d6c44b224b,
+                    0x35d979e67ab67d92,
+                    0x2e15951e348ab279,
+                    0x0085f5dd3fded0a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x812ab853f6864d6b,
+                    0xb5589ba81dae7748,
+                    0x7b1a6aa386486f45,
+                    0xfe08f0d9b67b885a,
+                    0x894c5d6105df3816,
+                    0x3a61df92a39e8fdf,
+                    0xb14909589dbec08b,
+                    0x84bb39fbbb64b193,
+                    0x11e0e1d12e48f875,
+                    0x2c1327469c138430,
+                    0xd4a1d1f701a99c7e,
+                    0x00b56fc4a3b12c90,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0b97f2af21f66a28,
+                    0x80d7b2a334659bf8,
+                    0x84db5a90ec4be1e8,
+                    0xe71f60691099188a,
+                    0x004e5865007687b7,
+                    0x29201c21294d7fc1,
+                    0x8bd686b0132dd214,
+                    0xbb66444175fbcbe6,
+                    0x4c97b2d426d467ec,
+                    0xe20bf0be6fbf0b57,
+                    0x1cb9cd099e159c34,
+                    0x00b335fc4a37dd96,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51f5458a8e301e91,
+                    0x69df8e2ae094956c,
+                    0x9f16b62282f79f25,
+                    0x64d91ce8b516106d,
+                    0xcea8154b74941bef,
+                    0x038aac81d3368481,
+                    0x5fd2a3cc15260733,
+                    0x1562cb44d4b07a22,
+                    0x16ebe87519c48b95,
+                    0x1fdc685bb72bb746,
+                    0x506eedd63eda065f,
+                    0x0033f5852ec2bac7,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x2f40e7aba18fc666,
+                    0xa074c07d8d2f6d91,
+                    0x8d9685585abc4b95,
+                    0xdaa3e751c346e12a,
+                    0x67855d3cd58c3139,
+                    0xc170bf57c3d7ff49,
+                    0xeeef438b5977d8c0,
+                    0xcbe81d13e0175e65,
+                    0xdb939ce9a68c85f8,
+                    0xa827a5566e16b425,
+                    0x7a66e858974bf438,
+                    0x00dc3a4c63632ba0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d86bd13ce348377,
+                    0x299483bd478416ac,
+                    0x6ff88d25bf4e6e55,
+                    0x9fb08f7b190d2e19,
+                    0x8fa6eb942fd81213,
+                    0x58c6c3ba25593d9d,
+                    0xa03853f8f2fe90fb,
+                    0x65b579a7a8db49b0,
+                    0xf9fa0a03e68537b7,
+                    0x3bc491f127698340,
+                    0x273a7355937ed00d,
+                    0x00bf3c45466030a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12bd6aa0d0e8d64c,
+                    0x336a04a680b4d855,
+                    0xc73cb978977384f4,
+                    0xf532a51bf98c720f,
+                    0x814bfeaeb86b352c,
+                    0x24731dcaf4d16a13,
+                    0xff9b71022e44f977,
+                    0xf37b01364d39f372,
+                    0xbc36dee4c191b70e,
+                    0xd04f6daa65b04168,
+                    0x6a41d7f81fc5dc9a,
+                    0x00a83cc1cb352bfc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd6e4d5df1a8789ef,
+                    0xc94f2ffa3ad7861f,
+                    0x978debe8f50d7847,
+                    0x25644acb5dccc2ec,
+                    0xe7c02a70d504f3f8,
+                    0x75e857f4f5415a52,
+                    0xf93ce19824633610,
+                    0xcd91dda9ea63b991,
+                    0x604d5c3a9a02653e,
+                    0xa34afb442f58b8d5,
+                    0x4195807dec64a0d8,
+                    0x00da9a34a71a85d3,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xca29bea1b143c191,
+                    0x0c05dfeb9ef5806f,
+                    0xf3b9d63fa1400df2,
+                    0x5372d6aa32f5e1e3,
+                    0x08397790bcb0af49,
+                    0x6f6e1310ee1b345a,
+                    0x2a7ce61cdec1d012,
+                    0x6b22979fc17b51b6,
+                    0x26772b71ee6fc2cd,
+                    0xf805ed9653e44797,
+                    0x3febdf69426b0209,
+                    0x004a8d31d3518588,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c8f3f90557c51ce,
+                    0x5392058d860f256d,
+                    0xf05d26bb43573ef8,
+                    0x9bc36aec7e34eb01,
+                    0x413388a3ddb78870,
+                    0x687b2fec72af71f5,
+                    0x1c6e9068789a6008,
+                    0xd4b045f0ce80b3da,
+                    0xe3b582318a4c4f5b,
+                    0x240e7635d703f747,
+                    0x72b7cc4430e9bb68,
+                    0x0023a73afc9dff1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xadbef99522cdf1d9,
+                    0xce315bb7d1b347cc,
+                    0x57a51c185ea99ef7,
+                    0x679fb81646ebb479,
+                    0x4faa134bdadccf6e,
+                    0xb315ede216deff93,
+                    0x2393ae65eeba5807,
+                    0x8d157bece412f131,
+                    0xd34d152a2c6d85ac,
+                    0x99201e3efa5acfc2,
+                    0xebbc8334f7fbf0fc,
+                    0x000b8be4af31825a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6863dd08b2a0a8a7,
+                    0x216051645b7ad9da,
+                    0xd4eb2a0bdb2c1333,
+                    0xae130e301e5b7de8,
+                    0xf754c6d1b9945b50,
+                    0xbdf0a653b84a15a1,
+                    0x279a3122dd78ddea,
+                    0xd0e69038ae845086,
+                    0xc731000c8e40d7e4,
+                    0x117b8dde0afe0249,
+                    0x7e5b7342a439ac04,
+                    0x00107c2514531140,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xc986ab5427ee7c09,
+                    0xe14302539ecf89fc,
+                    0x7197adff5a3f4835,
+                    0xfd468790dcade79f,
+                    0x66a35a18cbb0b914,
+                    0x268acde2d2d9b2ad,
+                    0xad0e964659970ac7,
+                    0x852c554490eb8194,
+                    0x4e38077debbe5702,
+                    0x402a899cfbee60a8,
+                    0xf6e08a1f7a439569,
+                    0x0023422063334aa9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x015cbebbb1babcc8,
+                    0xe872e5e39093168f,
+                    0xabd1a71f1662e80f,
+                    0x8a3eb629f30036b2,
+                    0x0ba0fecc7c0fd6f7,
+                    0x154d0843eb94b167,
+                    0xea61c9c4d0ccadfb,
+                    0xa7aa7f530dcad5d1,
+                    0xe683c948658ab62b,
+                    0x74edbb6e70f4841f,
+                    0x09c65dba0924827c,
+                    0x007c9fd8c6bfa239,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe743d60de0ca6328,
+                    0x1f9e73510a265032,
+                    0x0aff0ea98d285104,
+                    0x7e7a1c27f93c372c,
+                    0xa8a2199567f642f6,
+                    0x45fa828e01cbfacc,
+                    0x41dc958f9b693221,
+                    0xd744afb2c240902a,
+                    0xc97e196f4d3c7868,
+                    0x1d956ed81e5a4825,
+                    0xaf80ab557a6906db,
+                    0x00dedf42632dc0e8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x428f0060c51d5e37,
+                    0xb44640d200c1b315,
+                    0xc6f24ae70e76d978,
+                    0xaaaf1710274c5137,
+                    0x337f52f4187ebfaa,
+                    0xe6baa12a5de69c85,
+                    0x92eda86133724c98,
+                    0x8542e77c228a715a,
+                    0x30f073e89dd817d9,
+                    0x77c05a979f53cd5a,
+                    0x1ecf738de9ba3fbe,
+                    0x00f44dada256361c,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x1c53ae62346b53a5,
+                0xd8706f97eadc9fa4,
+                0xab8845fedfb80e3f,
+                0x09a148ca2091816b,
+                0xad07dc4073a38120,
+                0x292fb75f0199f199,
+                0x786bd37458858908,
+                0x64a281b1098566c1,
+                0xaf99577b520136d1,
+                0x8adbb2e840f78ee3,
+                0xe9bc73dfe03155bc,
+                0x00e3b4c18a22ea26,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x79a94d81d763bb3e,
+                0xe6db249d76af5a23,
+                0x8fad9041c97f696f,
+                0x2299eadbd5ea7f26,
+                0xefe5485f64e2e15f,
+                0x84e0ab4ae36a2cac,
+                0x5ade6e49e4bffb1a,
+                0xcce1aefd07ab501c,
+                0x5f5f75eff2a2fc3e,
+                0xb81ef2dce376ca86,
+                0x55db8ee47532de56,
+                0x00eb96aac5be9d27,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xde3f575589ccff35,
+                0x3a1ff8f09bc1c5d4,
+                0x7171d379ac28bd71,
+                0xbcda2a7ab139eb21,
+                0xd73c180ad67a5604,
+                0xe27bd581983c5ce2,
+                0xdf41eb49f95ea8e2,
+                0x6f4f081062e70bc8,
+                0x55641c4c4f36137e,
+                0x35d28b7b0d54dddb,
+                0xd3f41dee689d929a,
+                0x00ccfe5ff11a491d,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x81cd6de9691f6673,
+                0x4e4aff1269d5c88e,
+                0x41f82dae410c6697,
+                0xd249ce13537ec01e,
+                0x0d0da714ed181e72,
+                0xcf4c0382f83a8a7b,
+                0x49ee00c017243ebf,
+                0x10ddf4f150e52806,
+                0x02c1ed5b5e499848,
+                0x46d619e1464bfedf,
+                0xd02d916d176f517b,
+                0x011923ad1c3d1c9b,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x2e01ccd947286b42,
+                0x9de68393f1dc8bd6,
+                0xc2f252c92a5c94e3,
+                0x12027794a16a24b7,
+                0x91487a617aec7241,
+                0xf3d49c54451395cf,
+                0x37d66db8709c4a6b,
+                0x6f8462ff7adc118d,
+                0x9ec34b41bb6afce5,
+                0x4da3706db10642f6,
+                0x2e265be00cdf6ebc,
+                0x005e46a3c9edacef,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x21ceb3c6307f6383,
+                0xd29d2cf359316970,
+                0xa7312976b891b71c,
+                0xc3e6ff1500c4a89b,
+                0xafb7ffed85e3a770,
+                0xd0d7d91f154ef421,
+                0x7b0f8cd24a575927,
+                0xcd2e8e916c108548,
+                0x087f564ee4697518,
+                0x4dee317c6349d5d3,
+                0xb9c0c72053526d36,
+                0x005b5259459ee2c5,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe4fe54e691e0e3b6,
+                0x479c3ef5d155b1e0,
+                0xc41723e1613529b1,
+                0x473de59a26ed19eb,
+                0x245dc3d341e28422,
+                0x67c6730ea386980f,
+                0xaeba14cd0d297431,
+                0x5aa1e33dc33b5f75,
+                0xc0039fbca191b451,
+                0x14283e3bc2c87235,
+                0xb290d80718f2f702,
+                0x00990682ea5de093,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x5adfd9a5023fc905,
+                0xf1eacdab3528007c,
+                0xb5e04fc63c891fb1,
+                0xe3a79b2ec5820158,
+                0x95c44512814b858d,
+                0xa6ec1b2d6ec5c82e,
+                0x67a9567a79d99c50,
+                0xe9927867a1ba9e99,
+                0xfd6b08f419ebf947,
+                0x33a264d785600d0d,
+                0xbba18353feb7e443,
+                0x006ac357240f84bc,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe9ebd93c84f4ed58,
+                0x4a9172c4dee1d756,
+                0x825d380e7cf63721,
+                0xb424fae466d01046,
+                0xf8edd1335b5bf2d2,
+                0x2f5bb5455c1c8e65,
+                0x2636f39983726772,
+                0xc135fa4aed9b9a4b,
+                0x9e29d2aa7991a9c1,
+                0x0be5f1d3ab76ce04,
+                0x5e411e7571883617,
+                0x011dc815c96cebb1,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x60acaba97476645c,
+                0x702950e6744ea9a6,
+                0x47da0be984591d77,
+                0x2bfef1342cde154e,
+                0xb845c26d833f408e,
+                0xe71d1ef4abe1bcd4,
+                0x91854f6be8587e7a,
+                0x0ff3c0759fd8f071,
+                0x67ade7207ad0a00e,
+                0x6ac3457abf27674c,
+                0x16f0efd4ad158d2d,
+                0x003cc36f4017ce29,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 190, 191, 575] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 190];
+        let mut opad = vec![0x5cu8; 190];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,2004 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 384 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 2 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 2;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// 2 elements (192-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 22 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 22;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x27d7f1a8b32a85e1,
+                    0x3a8b0f66aaeabda8,
+                    0xb011b0ec4da14c07,
+                    0xd4587bbe4d00e3a6,
+                    0xbd6960b46a75226d,
+                    0x3ae5b68a21e4b9a7,
+                    0x0894c96ee84d9963,
+                    0xa4222f0b1662c127,
+                    0xb08e020a44f4baef,
+                    0xffa3077530b113a6,
+                    0xdf534a64c9de72c4,
+                    0x00cb769eafe58661,
+                ])),
+                Felt::new(BigInteger768([
+                    0x64b6465aba3b9467,
+                    0xf1cfeec241d9b103,
+                    0x8cc3648678a0af92,
+                    0x1eb80520440b31e8,
+                    0x0510f861e69add2d,
+                    0x74594421edbead09,
+                    0xaafc06c19b3f964f,
+                    0xadddb0d105798862,
+                    0x3990b58425397344,
+                    0xf4c7e1f4b69e6555,
+                    0x5a66c7a52383a5b5,
+                    0x00e088f89b02de75,
+                ])),
+                Felt::new(BigInteger768([
+                    0x92d863765211e97c,
+                    0x848feabadb5b41f5,
+                    0xa8ce024cf9373d89,
+                    0x39b9db5a8e11e0f1,
+                    0xa1946db45468c7b0,
+                    0xc816d4b29e2ef736,
+                    0xbc954d0f33407786,
+                    0x44d4eab28580951a,
+                    0xcc2c9d1b58c9747c,
+                    0xcf444456dbb2150b,
+                    0x1178b2d9c3a8b41e,
+                    0x00f978329f072048,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc7759c610525a6a0,
+                    0x307ec827aa039a19,
+                    0x1d0c39967d667cfe,
+                    0x4f0f6839a79d04a9,
+                    0x3b97f84212183843,
+                    0xc9a89eb56c46188d,
+                    0xc26568a2f2444b8f,
+                    0x6585950fd54cab18,
+                    0xc4e78fd2a3c58f10,
+                    0x5e6a6f49428e026d,
+                    0x24bc3e5093afc31f,
+                    0x008bed0ba49e2391,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x459934b23b60493e,
+                    0xaaf67c5b41eb3f3e,
+                    0x45c2bdd7a970ebfc,
+                    0xf405370e5e30f59b,
+                    0x32b2f354830bc3f3,
+                    0x5ff316a144932cd4,
+                    0xcc015801a23c49eb,
+                    0x2c82b5758f25eccb,
+                    0x1f8613d075db85df,
+                    0x0df0a4c783bb8195,
+                    0x175d64630c5792b1,
+                    0x00c39e60679c927a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9da73dd4b0789589,
+                    0x455ffc536c710608,
+                    0x671ebdbdc4b5aa43,
+                    0x8c40f9a6b8d3371b,
+                    0xbbdf16ec311e8794,
+                    0xfd4e3d259d4b7664,
+                    0xf5e2116b81b21e1e,
+                    0xf66e01239a2de39f,
+                    0x1f79953d3bb74c00,
+                    0x8e9d471d3d6c46d5,
+                    0xe40d1e58ee7edef2,
+                    0x00cc5ad0ce325fa7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4db2a117f154b270,
+                    0xbf41674034f1a9a3,
+                    0x925700c2c3029ee1,
+                    0xee18fe81f2bae6ef,
+                    0xb6847ff47fd05517,
+                    0xc6a59ac5a2368d27,
+                    0xa0df0278cd3069d4,
+                    0xe6405ae2b24aee2c,
+                    0x02726c69cdc4e4ee,
+                    0xbc82522edbf5d648,
+                    0xc85c91e17852063a,
+                    0x00c8db697e10b6fd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x46214333db33ca4f,
+                    0x2689668b321ce6f1,
+                    0x859ce208a8c2714c,
+                    0x41b64a98777f47a9,
+                    0xe364bd88fb5c9b37,
+                    0x3c8706aa0bf5bae3,
+                    0x3e82a49e0b29ce3d,
+                    0xa5bfd7debace2eaa,
+                    0x99f9787455cb945e,
+                    0xa025b80ed59af6e4,
+                    0x7aefd3b2060c2df7,
+                    0x0060630b1b4e0f35,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbaa8714b13fd0c07,
+                    0x13a03953b7fb38af,
+                    0x06f02874809b851f,
+                    0xc268f37e633f7df1,
+                    0x54becd1eb5a214c7,
+                    0x5002074898b34164,
+                    0xf3132e64e6efb212,
+                    0x1ccb82cc26833bdb,
+                    0x3793c443d0b57d31,
+                    0x01cfae81a755f5c9,
+                    0xcb8bf59a77b55a41,
+                    0x007a4bfa905b5874,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ca4d40fdade5ed5,
+                    0xd01717e7fe0b6c1d,
+                    0xadfd163648cbe83e,
+                    0x92374ee21d15819b,
+                    0x71d720b91a76f1f4,
+                    0x057a0afd54f3645a,
+                    0x8d7d718a33e48eb5,
+                    0x75dc6108fadb013a,
+                    0x884a59045a69cc0d,
+                    0x9d7f2fe12ba87a83,
+                    0x8522559ea2dd08a5,
+                    0x0082fd59a2715abb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf27f93f3b95575a4,
+                    0xbd4088562764ecc4,
+                    0x5d5517215dd37b8b,
+                    0xdd99f8ea24cdda4d,
+                    0x2efd71ed04ebc04c,
+                    0xc501f1a85bd1124a,
+                    0xcb49cae0067e29ec,
+                    0x503ec1d168276a33,
+                    0x72907399a5d40d05,
+                    0x80440cc4bf95b78a,
+                    0x55ebbf643baab726,
+                    0x0020c8ff2c1a2b42,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfecd3db7abc921fc,
+                    0x56dfcba578f08924,
+                    0x77d58eb2ee487c4f,
+                    0xbdd1e27738e45c20,
+                    0x6368c742acbf4c23,
+                    0xc59b2150f82a8a42,
+                    0xa63160c843b43c5a,
+                    0xe91249d9080befb1,
+                    0x9e601de192fb89f9,
+                    0x839a0c2835bb5f3b,
+                    0xeeb1f21a17ac71f5,
+                    0x003d3727020ede8b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9beeea6118432180,
+                    0x14888194b59d4114,
+                    0x7deb6ee6819946c3,
+                    0x0a10a0e6071d2aa6,
+                    0x8a9a86198095d186,
+                    0xee86be1aed070a9e,
+                    0x4218f1f3936c7f19,
+                    0x778e15b918f356d6,
+                    0x72e8b2d390522be7,
+                    0x0545300fdb0f780e,
+                    0xe0f8ad1bff1b2de4,
+                    0x006bc39a2f398fc8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50ac7aef06f78cce,
+                    0x0b569311348d9737,
+                    0x3be9f2eff8b496a9,
+                    0x5ed422a1559ffca0,
+                    0xd5b6b842519340b3,
+                    0x96fa7d556f276a68,
+                    0x4917dc07801fcd01,
+                    0x4ddea7323711a4ae,
+                    0xe56ec8fa0505a427,
+                    0xa56ece620ae96135,
+                    0x1716749694c6f499,
+                    0x001ae7e1dc3d8fe5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x616dbe944cf5f827,
+                    0xeefc8090d19cd9bf,
+                    0xad778c66296023f3,
+                    0x409e449ec37b325a,
+                    0x0411536cd2f73aaf,
+                    0x834ce0e601faf2a6,
+                    0xf4f80b8a9f4d84be,
+                    0x1d8637b4a6caebe3,
+                    0xe65f545d43de9f63,
+                    0x81928157f1a86137,
+                    0x417f706bf73ea77f,
+                    0x00e2b61dbd20be53,
+                ])),
+                Felt::new(BigInteger768([
+                    0x355b172dd2bbd485,
+                    0x8e0e656b9d984db9,
+                    0x8987a7467c8fbf99,
+                    0x4ae9b91591eb0131,
+                    0x237713e55d385a74,
+                    0xfc8443066d1f28e1,
+                    0xc88451c567047673,
+                    0xdb555ef7d41d7941,
+                    0xbbc820fbf0ecd08b,
+                    0x20e2688f866f6ba1,
+                    0x8489462802b0f827,
+                    0x010135cbf8624b28,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbd62eb0bed0555e0,
+                    0xc059d6eea7cc01cb,
+                    0x41ea31edb1a96ff6,
+                    0x4c980b99e8e48624,
+                    0x29bc13cc82b6b7b1,
+                    0x174dc49ae3b3842b,
+                    0xdf8d47aac22f1fdc,
+                    0x1a9dc93674bbe3d5,
+                    0x1eb54797ec3a5151,
+                    0xf50272991620e46b,
+                    0x3849ee9439291e49,
+                    0x00a9cc147e4e4b20,
+                ])),
+                Felt::new(BigInteger768([
+                    0x59c149f94cdb540c,
+                    0xee1d3dde91a45884,
+                    0x1f6fc4f815710168,
+                    0x58fc1989dd832364,
+                    0xcf3339cf0708e28b,
+                    0x3f3d31f35cfb033e,
+                    0xba8d93b7702b52c7,
+                    0x4d5a161e6edf337e,
+                    0x1afb8220a6b5acb1,
+                    0x7d4d01d1d653fbb0,
+                    0x3825621d9f85d660,
+                    0x003679bdee592ef4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7ebaccdcbe3986ab,
+                    0x6395be74e7c8ed52,
+                    0x9455c91498f7dec0,
+                    0xf08b5cebfb94992d,
+                    0x90d7fb9b6dfcbe6e,
+                    0x63200eb795b1b05b,
+                    0xb50e46baaaf730c4,
+                    0xfa34a4c749d09b4a,
+                    0x92e10fe801689988,
+                    0x9c910bf7ac0c5015,
+                    0x402a7cf7dfe158c9,
+                    0x00988f98a6119b59,
+                ])),
+                Felt::new(BigInteger768([
+                    0x907ca724bdd4736e,
+                    0xfff7dfd07b8b263f,
+                    0x986f8a8b8cc77130,
+                    0x1d76fe2b12beb748,
+                    0x41eca405b0e63eaf,
+                    0x113dd9530ffd7b54,
+                    0x3a40bd24d6d31ff7,
+                    0x48eeb171863d56cb,
+                    0xe1b890e8fded52ba,
+                    0xe41dbc52fb027c7d,
+                    0x8bc07b51dd28e938,
+                    0x00e968435779b33d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x72f8bd73f18d49d5,
+                    0xdbaaac9d4943936c,
+                    0x14538d1331b50529,
+                    0x0bec29613b0a50a1,
+                    0x142ee75a912dbbfd,
+                    0xae85fa804ec5f7d3,
+                    0xe0fc89c62ba8fe42,
+                    0x2955fcd75c08e2fe,
+                    0x276ee38ede5a030f,
+                    0x455cae0dd2422e9b,
+                    0xe8ae1d474dbba637,
+                    0x00e10a3e35f79882,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe1b60a55b1af2e7,
+                    0xf167fb10d45a6526,
+                    0xe6296169ad8b915a,
+                    0x029a72eed9a133d7,
+                    0xe0c5c074050da027,
+                    0xbcb4fd3f5b627877,
+                    0xad02f28dc5226435,
+                    0x85384e173fa09088,
+                    0x101f5a4b4906dd3f,
+                    0xcae4758815b77db5,
+                    0xd08693aed1a9bfed,
+                    0x00748e1d9d394a07,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf492b31bc8162d5f,
+                    0x1c774c7946d72403,
+                    0xb1f3044b32a6bbfb,
+                    0x2e8df3aa07dfda49,
+                    0x4c479c1f5ed559af,
+                    0x4d60f5f6c5ba4ca7,
+                    0x3f3d180835fcdfa3,
+                    0x91894502643b7119,
+                    0xd3f5c35f6f3adde9,
+                    0xdf2b4d7717f7008a,
+                    0x1121e555d8f770c8,
+                    0x0016bfcfdfd17c4a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd5184e87293a51df,
+                    0x61ac9f7d8dc43b3c,
+                    0x46bce9960e463a20,
+                    0x5309dc0511fd3bb4,
+                    0x26c97dd4946a3b38,
+                    0x0b9417f318f01ecf,
+                    0x12ff80f039941932,
+                    0xb1aac7114e28379e,
+                    0x3f167d96031acea1,
+                    0x9eca63437ac3c6e2,
+                    0xf5eb1a34fad93ee2,
+                    0x00d13c6d8bb3b5ee,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x00481eebfcb3141c,
+                    0xac2985c450a09e7c,
+                    0xd412e679134035b8,
+                    0x7d4e25c292768154,
+                    0x52f996188681313f,
+                    0xb49c020f9cba7c8c,
+                    0x6b622aab21a58c28,
+                    0x66b7431b947d4fec,
+                    0x2d0620bfa43da49e,
+                    0x651b6b5e6e0b75ad,
+                    0xfdfaf67160cc73e5,
+                    0x011e855f5081a972,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8d42f4d6b49cda54,
+                    0x9d279528fe27b2ef,
+                    0xfdf9136e73bc7f13,
+                    0x8cb7f65a0fb4b53b,
+                    0xd139724c0688b172,
+                    0x1d36581d2de56662,
+                    0x1aa5b826d402acdd,
+                    0x05b38fd9816a5400,
+                    0xfd9b5e608d941ce9,
+                    0x27129fc1a3a7a470,
+                    0x4883f1ae401c8019,
+                    0x00322384e5cd3254,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1567e3e204a59205,
+                    0xaed6342f56997c06,
+                    0x91b1dd7dafac133c,
+                    0x005f50be8c5318c5,
+                    0xeff4b56a8f241fd2,
+                    0x1f95c2ed48fe0a2d,
+                    0x6b38f1458cdc6774,
+                    0xb84f728f9cd68668,
+                    0xd0cfe0819cadbb8a,
+                    0xfc56216b06409e72,
+                    0x84ab5ecf2b69c399,
+                    0x0024c6e5bd3808d3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x53708d2de7282330,
+                    0x14bee51b19c73a46,
+                    0xd47b185fff8ac42f,
+                    0x95b24f831bc20c36,
+                    0x3b1fe4a2c4213658,
+                    0x3d5cf8c3f506ed82,
+                    0x672e6f9af59f3741,
+                    0x8248384fe0b8542c,
+                    0x947180eeda15297d,
+                    0xa180961347a8ca37,
+                    0x056b9ce9147ed449,
+                    0x0038726a6c3b16ea,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xdb3764ba80a135e6,
+                    0xccc6c76c59e5f86b,
+                    0x8e6e0e8524856ab5,
+                    0x6ec0fd2a529ac606,
+                    0xace3129a7784080d,
+                    0x1e8e611bbad02625,
+                    0xd94618a44cb296b8,
+                    0xa17ebeea7f070045,
+                    0x9535aaf1a1e79f1c,
+                    0x984e528b5d7cd8a4,
+                    0xcefedc3ee4b6dc89,
+                    0x006e44837a79aa3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf916ec1583c57b1e,
+                    0xd758cafdf3948c1d,
+                    0x3ebb716913573d66,
+                    0xd516294de36163a6,
+                    0xf5864fb980fc3383,
+                    0x6d93028b0c04f05a,
+                    0xf7680df2005cc197,
+                    0xec3cffd0ea986bfb,
+                    0xe937f82cae354d63,
+                    0x32faea8a5e7586ed,
+                    0x5d24eeca8e583a97,
+                    0x002fb9a5cbf5a365,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd0d0cf117a4f3e06,
+                    0x315e4828b5bbe85b,
+                    0xacec60047aae09c4,
+                    0x02e87b7d53494430,
+                    0x6a832a13e1a9d930,
+                    0x94d8b35c69bc02ea,
+                    0x95d7edf7363b2a77,
+                    0x3164c9c45104d959,
+                    0x99c619b5ef09bc74,
+                    0x5d495ae31c990c39,
+                    0xa413b9949239fa4d,
+                    0x00c1006e396b4cb3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf8c0babee75db1f7,
+                    0x678ac601435ada46,
+                    0x79c46dc88b89a22d,
+                    0xe1c1b0f5df08a490,
+                    0xcdfd9492b5838f43,
+                    0x512adeb61fda8435,
+                    0x0b488e40eea74ad4,
+                    0x2c563aa2570b73dd,
+                    0x70636fee143a13ca,
+                    0x43b2b2a128c7712b,
+                    0x8708902236c0373f,
+                    0x008b2bdaefa934d0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xad70865dff391931,
+                    0x96b070f4e52f7d32,
+                    0x8e947d32bed0a22d,
+                    0x4e6f6d6cc8e655af,
+                    0x5e3e67a27b694f4d,
+                    0xac01d2fe3530ce20,
+                    0xecffa6620325c29b,
+                    0x39a362b099578d6e,
+                    0x5cbdb6cd419a031b,
+                    0xeb9b0cf43639ff1b,
+                    0x902cdbd6e9b1110f,
+                    0x0101788e0e60ee53,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf497aba9a26b769,
+                    0x17b9112bf80ac117,
+                    0x6aadfabe9a58333c,
+                    0x01ec77c7f426c772,
+                    0xc7d5c31e14e67d6e,
+                    0xe87222638117b608,
+                    0x4e7ca981925641d2,
+                    0x7ea933ca87a9ef2c,
+                    0x0b89efd0666b6aa7,
+                    0x1c4d96585efd1966,
+                    0xfe37484704b5a7db,
+                    0x0111a8bd801e7a10,
+                ])),
+                Felt::new(BigInteger768([
+                    0x912fe6d105df2ca0,
+                    0x70fc9e81f0aaedc8,
+                    0x15feb0bbcabe5d1c,
+                    0x6506f96d03450f42,
+                    0xbbfd3973307b8511,
+                    0x8785b549b26498db,
+                    0x1ea7c623f1e9dd3a,
+                    0x8c6e156c470d25d6,
+                    0xe89562783c5163ba,
+                    0x41df646a5062c350,
+                    0x51cce31032fa8f79,
+                    0x011e066293a7a3c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x869fdbd7c15973fa,
+                    0xa91d43e940de3ec5,
+                    0xb6f50ff997cd8e30,
+                    0x2f1412696fd3dc49,
+                    0xcc38fbbcb8fb7388,
+                    0x68af9a098f83f9d7,
+                    0x536716bcb5c8cb63,
+                    0x428b38c51fe9bbd7,
+                    0xbd4b23786d7f6612,
+                    0x0adb898ab030fb45,
+                    0xf31922c713e6b5a3,
+                    0x003e08418090940b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x73b79e26218d1c56,
+                    0x74b3f51a65186613,
+                    0xbcede6bba2795562,
+                    0xf596a14c7ddefeb5,
+                    0x2bbcc679bc9202aa,
+                    0xfdb4133e67d68233,
+                    0x0d24a9dca3200b36,
+                    0x155ee554672ec80d,
+                    0xc6ee67f72362753a,
+                    0x22e9624e8d6b79db,
+                    0x7dcef3909efbd2d8,
+                    0x000376a6f6fa4329,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8d8efe5e2ba5d631,
+                    0x31fd930bb5393385,
+                    0xd2f21c2302ba6db9,
+                    0x56bae47bf5fdf542,
+                    0x76808067c725218b,
+                    0xad32ba983952d9ea,
+                    0x06b8af6aa8e8780f,
+                    0xd96572388085c743,
+                    0xdea9cc036b973ca7,
+                    0x1a9cdc2a0519af13,
+                    0xd05f12397123f325,
+                    0x010fcaf8626ae7ae,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa7d9169d2040218b,
+                    0x34c8f90e43e64e1c,
+                    0xb722f11623035a3c,
+                    0xb8b56ea51ea6b17a,
+                    0xf14fe898c22a7203,
+                    0x7724dd21448bf0cb,
+                    0x3b7c04a77eb48f00,
+                    0xf28b0d14d39090b3,
+                    0x64649b9f18a2bd21,
+                    0x36d1a4d37df602a7,
+                    0xa2ff13e4c76f5b13,
+                    0x00108ca287860700,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee5039c8021f5251,
+                    0x6eb2d0ef9f5a03f2,
+                    0x2218ee41c1925bc2,
+                    0xe2ff010ad6bfc024,
+                    0x71d155f176e577db,
+                    0x5e1586ec6a58c8aa,
+                    0x6c9ed7498fcfdcd2,
+                    0x67e848a6f448304d,
+                    0xbda2e61db6123a21,
+                    0x156a27c416a220b7,
+                    0xaeb9541d4f5fc976,
+                    0x009fb172f6526d0d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5cd5de74fcd57ecc,
+                    0x71d47991d3b44b99,
+                    0xf6abf7c47c32ee81,
+                    0x66dde38d2b3122a2,
+                    0xa8de28c0fd5124f5,
+                    0x249817b2a00b4b89,
+                    0x652090d56f55f230,
+                    0xb1c7b21073ba1d87,
+                    0xc7eca8b81b682eb1,
+                    0x794baa3a8280a953,
+                    0xb0921d4b8b79688c,
+                    0x01132326405e1cea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3cc902f3469c3c70,
+                    0xe8cce9d0784cedff,
+                    0x34e8128e67d6fb2e,
+                    0x5c8b51371155808e,
+                    0x429aaef808f7793e,
+                    0x00b44311ac6cc5da,
+                    0xa9cae8013c079f71,
+                    0x8e18e277d2b6c308,
+                    0x284daed04d687a09,
+                    0xd03b0ac708117a4d,
+                    0x3ee0293f54d1fe32,
+                    0x009cc34381f1e211,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b3b05287a389507,
+                    0x9032a726986b1757,
+                    0x49f8e5d27ebe33a4,
+                    0xe92bf991113312ba,
+                    0xec95e2724ad54ab9,
+                    0x4bdd14df2b6cfce7,
+                    0xf54618b82202de1e,
+                    0x0ffe3a51db00aab7,
+                    0x7931a630c7e7a53b,
+                    0xd7c73d5a207f2839,
+                    0x502adcce3d14bdff,
+                    0x008d886100cbbcb7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5fb50db9d0960ee8,
+                    0xbf8781faa1cbd668,
+                    0xa8d1c81dea00cb22,
+                    0x3ec5d7c4e912a9cc,
+                    0x9e24e2b19bf90b42,
+                    0x0d0a453ae7410b72,
+                    0x4df27b8e5c735663,
+                    0x66f5c8e0819e7a6d,
+                    0x64cb4cd15c1dbac8,
+                    0xc75958a4a4ae911d,
+                    0xd30e6bc1ca4cdde4,
+                    0x003b709ac05ff22c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x443309681430f89d,
+                    0xf300976883e99014,
+                    0xb1eec7fc8f6ca52f,
+                    0xdfb168472aa9506e,
+                    0x5a3be73b3d16d4fd,
+                    0xbe12e2a9b45657fe,
+                    0x9c5640a249f9ef28,
+                    0x644f7b089b185ae3,
+                    0xd3f28dcec10988a9,
+                    0x8ee4fc02fca109aa,
+                    0xaa69fc1b6741eb52,
+                    0x002b6aaa0d8effc0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03cf9701d4a63a70,
+                    0x1d1afb2f16e483eb,
+                    0x1a8cf7f6674b381b,
+                    0xe775495438734712,
+                    0x700c82dd54294d3c,
+                    0x454794f8dd0c2841,
+                    0x0e5a8d52d97a4b08,
+                    0x37977f875192528c,
+                    0x3e2ed6d1e4f023b7,
+                    0xb3c139fd55a7b97e,
+                    0x5dcea77357b9613c,
+                    0x00ad93bc799b6f56,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8cc5a4790a57a096,
+                    0xbd161d33456d2e06,
+                    0x2b5b41baad8b272b,
+                    0x5f97048509a59854,
+                    0x1c37bbd2baa538e1,
+                    0x5d6ee1912f716d95,
+                    0x6281e2bde0c929cd,
+                    0x76cb6fe2da890a68,
+                    0x583a4a3040469f44,
+                    0x44021d88c3b5aa72,
+                    0xd158865d72589497,
+                    0x0045c2a6dd888880,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97b60ec98086abbd,
+                    0xbbe49607ee683efc,
+                    0x9941ed4dcb0ed1de,
+                    0x536398d6c9e65c7b,
+                    0x90586529eea759f7,
+                    0xc36a685caf90fb80,
+                    0xeea48465c38f5957,
+                    0xcba2586d61eaafcc,
+                    0x0f6f379ace9e738d,
+                    0xdf34af191262052b,
+                    0xd2972e027a81393b,
+                    0x00ee1827d250da4e,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0xe44d587f61ac65d2,
+                    0x6f2d166bdca425a4,
+                    0x2f81470d57e20458,
+                    0xeb5c58e73d6f1c69,
+                    0x517a5b6928e253b5,
+                    0xbc9f704021111f57,
+                    0x1258095d766efcb5,
+                    0xcd616269e3e5181d,
+                    0xb5abfce4c28109de,
+                    0x699068a1da5c1aee,
+                    0x75abde8b062859cc,
+                    0x00e7259f5a6121ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0x34aacf30fc9fac49,
+                    0xd381d67616ffed23,
+                    0x276142b40cdd5537,
+                    0xd4988da524bcec1d,
+                    0x7155d782f397a5d4,
+                    0x131f970384b6309b,
+                    0xeb557faa708b0438,
+                    0x6269892084abfd6d,
+                    0xda8cf8cc257c0348,
+                    0xf538b2b634e00ac6,
+                    0x58604f2f9df82ef2,
+                    0x0042ba174387d60e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26b619f43e3cd9d9,
+                    0x051703f85c0e6c21,
+                    0xd940f14488b91e99,
+                    0x02f8735c152fe5ef,
+                    0x5cf9c62eec5ee1b4,
+                    0x908b76f3d7d7557a,
+                    0xe346d8dbdb29ba99,
+                    0x93793878a99dc9f8,
+                    0xb2bba4d80f5b6a50,
+                    0xe1c2a5e1c4a1d48c,
+                    0xcc7c4ccb37a01514,
+                    0x00101764e662f298,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf407ea6879c911c0,
+                    0x8c2ef55acc692d54,
+                    0x1ec7c3b1a0c13ddc,
+                    0x894bfc8eaefaf989,
+                    0xc063162d43a5ea68,
+                    0xfcb40d729a380335,
+                    0x86ce8af155dbacc6,
+                    0x35f6a233aa2b0c4c,
+                    0x9791b9cbaecce723,
+                    0xd6c77e5a0a57751f,
+                    0x6e4c6df09d7b03e7,
+                    0x00f0c244ec31af72,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x48396747a01685c3,
+                    0x2d261937edc60eb3,
+                    0x07196826c33f8e26,
+                    0x4e0a5c4a14bc4d0c,
+                    0x969a785ed4e8d544,
+                    0xa37cb2c5c52b7de7,
+                    0x5aecd0854632d652,
+                    0x0dd081a7241a2184,
+                    0x9a6515c4700d6572,
+                    0x418c3cbcc23ec053,
+                    0x491f12788880b1d4,
+                    0x00a457457cd9219c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x76ff9efc7ac1974e,
+                    0x18333a826ae9b1fd,
+                    0xfe61ad234e28048a,
+                    0x61e6d69ed692f88e,
+                    0x68fdef082b13a310,
+                    0x6529b4c282991fe1,
+                    0x42b7d01e5137a084,
+                    0xe94f494f87ad3443,
+                    0xa8873e434d03de39,
+                    0xe00ae6c65c48c6f6,
+                    0xd04c67a8592a5ea6,
+                    0x0015c9948624cc7f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72107aa5e5b9eb31,
+                    0x93a18e5b0a606f76,
+                    0xabc029a682f3a8c0,
+                    0xf9f8830fb7dbbcc7,
+                    0xf6b53dd719c0b2f7,
+                    0x2fe57173ffd3e5cb,
+                    0x229fb4a0e80637b2,
+                    0x2714cdd503d68481,
+                    0x56cb4d4d5bf05285,
+                    0x639c6c109af201a4,
+                    0xb397d4259ea0debe,
+                    0x009bf647f77f0a64,
+                ])),
+                Felt::new(BigInteger768([
+                    0x020b86aaf590d2e0,
+                    0x695529ac1b025ce9,
+                    0xad1cdd754ee92e08,
+                    0x27468634514606f8,
+                    0xcb19d4d3ed7eaf95,
+                    0x81f36b25fd735efe,
+                    0xea733233f65a4338,
+                    0xc84bb68c6614732c,
+                    0xb3154283c1e19c8c,
+                    0x4be570a30b5ca36a,
+                    0x80c8f5ecf092862b,
+                    0x00fb81664a8cb76a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x36a699e09cae4abb,
+                    0x2bdf1a63fd8f6ebd,
+                    0x1781ea8b4e6dd68a,
+                    0x56e1f19ef3957412,
+                    0x4e11b196db6ccdec,
+                    0x93d1acffd74e97a0,
+                    0x08587a2325dd8063,
+                    0xf918bc161bb7b83e,
+                    0xb35998f8ffcba77d,
+                    0xebb117e430e9b57c,
+                    0x11987bdb84979650,
+                    0x0024dfdd1119fc9c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12c8cb2f2b852b7e,
+                    0xfe9940ffd2d0c9c4,
+                    0xd02da88e066e1f9a,
+                    0x943882edf56ca1f2,
+                    0x4434e828d20dd2a7,
+                    0xec082cc5a09e003e,
+                    0x53414546f309ff45,
+                    0x60b20061c5eb64e6,
+                    0x89a4eff7dead7811,
+                    0xb0492df6e6ac6537,
+                    0xc4c51af9abcd9827,
+                    0x0082a5717df51557,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6984c5dc9aef62fe,
+                    0xbccc2fe100d674c5,
+                    0x52345be704d2d9fc,
+                    0xe64f142736594585,
+                    0x29f8c3833ac5cd90,
+                    0xc4309360952a2f66,
+                    0xdaca9d767babc945,
+                    0x96a1817a2c3e9e7e,
+                    0x943b1188408a08b0,
+                    0x1d63286fa194a5b7,
+                    0x3eb1c93d3ba7cfb6,
+                    0x0008803ce50dc53b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a01210379275df1,
+                    0x58de093a65cd2019,
+                    0xd2b576c3d040451f,
+                    0x9f3cde575db89e4c,
+                    0x0d5660e396ff70c8,
+                    0x35c6035366bd9695,
+                    0xd0de2a0eeb25498d,
+                    0x106873ea5cf23c86,
+                    0x0c1cc6442260838a,
+                    0xb042f35d7ad69a7e,
+                    0x5f54c82892f8f038,
+                    0x01223a2f5d244306,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf7cf537ed5c5ff20,
+                    0xbb61ecd6a7778a12,
+                    0xd472e1f6168cb35e,
+                    0xb477cabd5725c01f,
+                    0x0d58ddf0dd974d80,
+                    0xaa3cd35d50826279,
+                    0x1eed3106afdf4bef,
+                    0x2a420188461c3e35,
+                    0xd2855e8a6ae0b822,
+                    0x6de91fb8022c9348,
+                    0x9cb78f4bbb4a988f,
+                    0x000ba43296d90c97,
+                ])),
+                Felt::new(BigInteger768([
+                    0x368a78fdd89122c0,
+                    0x02c81f2676d8861c,
+                    0x680523a71f08ddfa,
+                    0x2e05b4bd62fc716d,
+                    0xfff01950737300cc,
+                    0xcc2c532b91adbcc7,
+                    0xf8c7fca7711e0d1f,
+                    0x0b2948773cf97460,
+                    0xf06155560243b154,
+                    0x78285882c5d47022,
+                    0xb84a23f1030a3faa,
+                    0x00252f235a3abc99,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfe424921cc114974,
+                    0x8cc09b287ffb9c87,
+                    0x0d1d79d84291db60,
+                    0xcaeda3b3ccbb9208,
+                    0x9187c6cfe89d3748,
+                    0x6cc9f106327f82e0,
+                    0x2226d01277230ca1,
+                    0x48bf09193cf5b8ab,
+                    0x749ae7619ba0854a,
+                    0x278d263dd9df0254,
+                    0x1072be2de05c3de3,
+                    0x00eb25d1a4dc2a2b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd1f422fbfaaec476,
+                    0xb5cf71b769dc9508,
+                    0x67c555a8ef1f5546,
+                    0x854af325eaf449a0,
+                    0x83e4aded8ac76738,
+                    0xff339d86d97977f8,
+                    0xc998e4fed46ab644,
+                    0xccf0021cb238a716,
+                    0x3feb334003e9a892,
+                    0x2fcdc438610f76b0,
+                    0xd7d27c2bae977e69,
+                    0x008329f34f6eb0e2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe947bb5646d068fd,
+                    0x26342e0bfa2d6d97,
+                    0xd708b80d922663c5,
+                    0xcb1eda5dd60bfb1f,
+                    0x83f40605984efdef,
+                    0x6f75b49ad6722215,
+                    0xa64e4672ba65291b,
+                    0xe8d65fdf17371c1a,
+                    0x3b16fac3b6561836,
+                    0xf4b70c52b123c8f0,
+                    0x0fb48a3791665c49,
+                    0x009d4cd9d8306dc2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x77c6a4d6b822be84,
+                    0x6f7d276a5d15e223,
+                    0xdadabf450242b596,
+                    0x65171d65856934dc,
+                    0xc31201d9dec0aab4,
+                    0x9730ec10fcea924f,
+                    0x12e34ac52e4ad02d,
+                    0xeb1e33d3f0224bd8,
+                    0xa1a0b72c78d74aa7,
+                    0x99f4eaaba58a4891,
+                    0x5377152b641fd535,
+                    0x002fe0ce18df99b2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe26a5bf90049cc15,
+                    0x3313b48a822cfb9d,
+                    0xcf0e301585ffcf92,
+                    0x468347a5a9dad549,
+                    0xad4acb2c459d01ae,
+                    0x23d595edc4f9e1da,
+                    0x18164b9b8494bbd9,
+                    0xadf0afc39661365e,
+                    0xa73dbcae635c5137,
+                    0xf8c5b5003e8f9c61,
+                    0x9e1f66e1c99ca5ef,
+                    0x008cc5a1a197e81e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf7d8b7f923c498b8,
+                    0xe59fec9cff69f0e6,
+                    0x176e8a031df5ba11,
+                    0x22e48149b039a448,
+                    0x7120a5ffefd5842d,
+                    0xfdf31724690e7686,
+                    0x3fe7dad24b58f43f,
+                    0x3ffac6b9172ac57b,
+                    0x3d5e98e8deb426ab,
+                    0x121420acf67c664c,
+                    0x014c825d52e75034,
+                    0x008521fdb96e5e6a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf188f8d5f5193245,
+                    0xd681143a4d8c6cd8,
+                    0x0e07dc744638de27,
+                    0x97a21c5848c4a5ea,
+                    0x4def6651f1a1305a,
+                    0xa910e13785f2a5a3,
+                    0xa15799efd833b0d3,
+                    0x5a3f20b1540c1bf9,
+                    0x831cf5221d0d6992,
+                    0x372d086cf98f9296,
+                    0x0c458e3a50da9e24,
+                    0x00e67c58f56850ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c5d1e0a2224de9c,
+                    0x1caff906fb8a85a9,
+                    0x8e1e98dd88057bfc,
+                    0x8eef1d5eadda5c7f,
+                    0x6c2fcc471be81b9f,
+                    0x9371102348a280ff,
+                    0xe6a04b597b161396,
+                    0xe46b314f02cb7d31,
+                    0x75dddbcf61a1ce79,
+                    0xf6557c9edc2a8678,
+                    0xdfa6ae348639c9ff,
+                    0x00dd87ac2dbe336d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6ed1666949fd1b0c,
+                    0x79393c112b5429b7,
+                    0x1a72c95451f6344f,
+                    0xbe6bfe0775885d1f,
+                    0xd7a1ffb6b395087a,
+                    0x49a21092d3927487,
+                    0xaa468b1c5d78e977,
+                    0x690834d7d5496b80,
+                    0xb43714b03f2e0de6,
+                    0x16b238046ed43808,
+                    0x3c95438fbf65e6b5,
+                    0x01054c723606df07,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba67d05fb7a210e3,
+                    0xb6bb0f8de5437eb7,
+                    0x2a98b4e41444795c,
+                    0xf0baaa6b7fefe90a,
+                    0x827f0a61d77614f1,
+                    0x57c9694b1fa5fed5,
+                    0xc57f62490d17857c,
+                    0x2483b66623b20dd1,
+                    0xdaece0c793842587,
+                    0x285d58f200ff97d7,
+                    0x4d9ebf6958edb1e0,
+                    0x00564ae0afcf969f,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5905f6e15aebbdd9,
+                    0x2f9f84ef9aa3ff68,
+                    0x6836d3c686ac0a90,
+                    0xfbec5d6f30814bab,
+                    0xc24936929c25a030,
+                    0x5c54d55a178382fd,
+                    0xe5344db331d0e221,
+                    0x21b8ce7207b829df,
+                    0x1843788d8cf1522e,
+                    0x9adbb5a3c24ae9f4,
+                    0x54e4b3aa3d1db873,
+                    0x0049b1a8e5ed0000,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe6b6bcf3b27727fb,
+                    0x32c0e0554c47ebf3,
+                    0xf7ceea411a356a58,
+                    0xcc71488385bf8373,
+                    0xf5e844a82be2e636,
+                    0xcbc941b7b3bd3696,
+                    0xb5be1b10d42cc87a,
+                    0xa5db2604941c512d,
+                    0x0b13e9e73f5ea7a4,
+                    0x2af01dfdb975deaf,
+                    0x0229b68418339fda,
+                    0x00d61d690f61d60f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x41741e50f642c572,
+                    0x965cfd4b84860597,
+                    0xd149a63ab2337b0e,
+                    0x8f3ce346d95ac568,
+                    0x7a56a28b1c63add0,
+                    0x1dcafb5a49e6ae2a,
+                    0x4d5c3ca90c2f21fa,
+                    0x5ce91324fd66a03e,
+                    0xfd09037bcd83bbc4,
+                    0x9a4cca1d939b1e38,
+                    0x074507870cbb2e11,
+                    0x0110f10eb8f794a4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb50156962ac26432,
+                    0x4b3fc026f51a76cc,
+                    0x65c7450b022f707e,
+                    0x88d12327717dc7c9,
+                    0xdfca3768b13a3e26,
+                    0x4597a4bb58aab43e,
+                    0x7a30972ecdd3db89,
+                    0xdccfb889bee6215c,
+                    0x5a4b35e029db2397,
+                    0x5d3621b26a06ca76,
+                    0x097e92994c1b71f9,
+                    0x010f115d5cce4dd6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3638a5409599b45f,
+                    0x5d8c8e3cc39972ae,
+                    0x03dcc26d5f8f973a,
+                    0x11d80987c1e23e2a,
+                    0x6896566f2b101b65,
+                    0x6dd01c4aca5dbdaa,
+                    0xe05c70c1e8a2175b,
+                    0xe06f144633748a0a,
+                    0xeb73924b0a1521e5,
+                    0x01a20a497ad04e40,
+                    0xe9b7e1c93ad56f22,
+                    0x00cfea6e8922ba9b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeed3e97da5f4ff81,
+                    0xecbb1893821c96d6,
+                    0xf00e394f22568ec6,
+                    0xecf5d2ec03efcbea,
+                    0xc84dc80a0d47daab,
+                    0xba40246b045a9da5,
+                    0xffa1f6a2fcc7ac36,
+                    0x39b1cba309115f3f,
+                    0x2cebece9e0a663c4,
+                    0x2a28e2eed0181cf0,
+                    0xd23461fac9da3dac,
+                    0x0092b64c9ce673ac,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf18f7bf6c104a8b7,
+                    0xaa0707f9bfc33b52,
+                    0xee90381369dfdb51,
+                    0x829675916a95d78f,
+                    0x46a77ca5ad141656,
+                    0x5b348520ea212d06,
+                    0x2be3e076a0b28f98,
+                    0xa5f9abfd883c9316,
+                    0x0e29672ef93a8355,
+                    0x59f83059bf6f602b,
+                    0x1670d4f828d94469,
+                    0x011085d13e06fe29,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6bf5729367c33b89,
+                    0x1a8d217da9e6e516,
+                    0x5cc6a86f80d216f4,
+                    0xcde340ade03819f7,
+                    0xa04a8f698c10e147,
+                    0xd93ae9dbae2244b7,
+                    0x72c9ff8fde0310d9,
+                    0x9210d95e4945d87b,
+                    0xce192fcc4d55dcad,
+                    0xa5409bef162e34e9,
+                    0x3d69eb95e65d6913,
+                    0x007731a07c856aa1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x679b303ef3b8a12c,
+                    0x42805dfb333101c2,
+                    0xa1d042f870597188,
+                    0xe6b1e0b888070eff,
+                    0x649eae0c0b9c740c,
+                    0xe5583d9e05909a8c,
+                    0xab0c49b1e63d5bea,
+                    0x4a0116a0c20998f1,
+                    0x742102f93c9ed95f,
+                    0x931d5b677c713963,
+                    0xc99ae88ed737c761,
+                    0x0007429b11805940,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe1ff2bad12f66dd6,
+                    0x8399fcf63932cd48,
+                    0x13ce2e7ee72102ab,
+                    0x619c445f057abff2,
+                    0x0d724440e946bac1,
+                    0xb6b8a801abbf3557,
+                    0xa959d8aabf84b71b,
+                    0xf4b42da349fe96c9,
+                    0x71e6f5ea57eb2acf,
+                    0xd683e4c5df8ed7fe,
+                    0x57faec175a3d26ea,
+                    0x00912aa7a0f5c7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x286dacbcaf062362,
+                    0xebe52a93f77a0921,
+                    0x611135e2eef554e0,
+                    0xd339efe37403805f,
+                    0xef6b140d989f0cbb,
+                    0x379d61277e17f7d0,
+                    0x82a4a9fcbcc8b914,
+                    0xcd2fd0a5cfea906b,
+                    0xe210736522ecd6a9,
+                    0x31e32bd23c2e9f64,
+                    0x0b309163054d9066,
+                    0x001052842bbbff72,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba601f55f8fba9c2,
+                    0x94968508efca8707,
+                    0x1456c7ace2bd83be,
+                    0x8cc2f3ee1e604644,
+                    0x08ceeb9e6c03e708,
+                    0x336b55af915a1f08,
+                    0xe013f16ff53d3b6e,
+                    0x310122c5cc13bf55,
+                    0x585bf8528663b604,
+                    0x8129801419f1e481,
+                    0x5512ec034642f082,
+                    0x002925453c85c41f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x64e4457a86e844a0,
+                    0xc0f22b239528964b,
+                    0xa47d2944549e6f52,
+                    0x108334381d1ea2fa,
+                    0x0d391091c47d4f19,
+                    0x429579b473dddc09,
+                    0x107d2a55921b662f,
+                    0x40949276c00f26f7,
+                    0xb34809366f681aca,
+                    0x5e39d0bd8dd5738e,
+                    0x0d4bd72dc15f17e4,
+                    0x005602794b4e85cb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x005303f2e661ac00,
+                    0x84ac756da129b2b3,
+                    0xb0ff762fc845bc9f,
+                    0x4f0c1d2d9d39b762,
+                    0x1a623a73fc6d9efe,
+                    0x51574694796974da,
+                    0x19c251529554d97e,
+                    0x8c526d64bd17c24f,
+                    0x56f167c2e113e6e8,
+                    0x349bf9fde17f5740,
+                    0xd2e1d24c85c86f73,
+                    0x00d13415f0d7c82f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe53eb53f92d15648,
+                    0x022e2b3789d3cd12,
+                    0x55e657fb9e61c398,
+                    0xe9e40d08d79c1bc0,
+                    0xc33a93d47429d76c,
+                    0xcbb3edb918848298,
+                    0x06490f257748897e,
+                    0x7d76c7dd611a463b,
+                    0x841c16243a1b36a4,
+                    0x0c9bdfdf112aee48,
+                    0x55a866076559fbb2,
+                    0x0013a54ca21f6923,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8bfb3a0f1b3f604,
+                    0x911cf19793a336ad,
+                    0xb8ea29cf7f556290,
+                    0x9eb5bdc57bf3c988,
+                    0x17fcee78d0f0e622,
+                    0x0431cc7863888812,
+                    0x2eb8ee5d65b41f9f,
+                    0x43ac98d3ff2a4521,
+                    0x7cd313aa0fd6f293,
+                    0x40c4e5fbfc34dee5,
+                    0xbbc38a3cab103547,
+                    0x0112706ae91afc71,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd8d50503b715e605,
+                    0x052e7ce0b45931de,
+                    0x8cbe369796abcef2,
+                    0x954d1528e0de64d9,
+                    0x0a1809b955d05358,
+                    0x9dd78cbcd04746b5,
+                    0xcc14dbfd16fac976,
+                    0xbf12c786f77bb3ca,
+                    0x7e58691ca804ad86,
+                    0x28a0e1a9fc385a13,
+                    0xb6a2b48e59a606b5,
+                    0x00fd0e7609efa126,
+                ])),
+                Felt::new(BigInteger768([
+                    0x34d3aede264e8a03,
+                    0x9348e2c355c8455e,
+                    0xde4a33c544a9af42,
+                    0xf71030f4f0be5597,
+                    0x6565417016755236,
+                    0x4c563c9d8a7f2229,
+                    0xa73e47c0368c4016,
+                    0xf8c81ca8f994ca54,
+                    0x4bcf13764858e773,
+                    0x321095951c29b630,
+                    0xef34b5081270e361,
+                    0x01071595313b0df4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8106feb24583057,
+                    0x6536174993c3e79f,
+                    0x9f7df17f3f04b4fe,
+                    0x1749f9d2ad87a5e3,
+                    0x59d9687c0723885b,
+                    0xbf147a12e0083cab,
+                    0x6c45b70a2ece48ed,
+                    0x2b6080075e68491e,
+                    0x39579c5624e50ad9,
+                    0x4a797242f1ca5887,
+                    0x6c0386e0e1259cd5,
+                    0x007bc51c011a609c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7dee3bcf48fa2c38,
+                    0x16eea4b539b4ca5a,
+                    0xf7fd1a6519189557,
+                    0xdc762488ae827818,
+                    0xef20c847dff81335,
+                    0x1b74c12672105da0,
+                    0xecd2feb0bc7d2ab0,
+                    0x943609996dacdf81,
+                    0xc76a7f6b2c9ea8a8,
+                    0xd492811ba05306e7,
+                    0xa49eab6386ece05b,
+                    0x006167b4a848db4c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe10934ea3962ee67,
+                    0x42be897764a17725,
+                    0xfe2b1cd180628db0,
+                    0x843e2990f7aa0cf8,
+                    0x429557274199693c,
+                    0xc2df6fa3f143ac81,
+                    0x66fabaa482f791f7,
+                    0x421e36dd8e99442b,
+                    0xc9d3c0e1df418a03,
+                    0xb18bd0d5ae421276,
+                    0x28835823535833a6,
+                    0x005bbb6759dd1bb4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x39d287de94eabadf,
+                    0xbb9bcd8d54cd7372,
+                    0x7467d9d19dd9f324,
+                    0xfeca59be46c6cd79,
+                    0x7f7da6b92b2eeee9,
+                    0x92a6267e182fdc71,
+                    0xb0c705a35b511481,
+                    0xf82bf8d13a791c6f,
+                    0xb702e7a39f9d243e,
+                    0xa04705dfb6dd64dd,
+                    0x5f25953b27ef320e,
+                    0x00721655e5f49ccb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaed09d324b9c45e5,
+                    0xdc0b0adf5bebd120,
+                    0x3364562ee24f998b,
+                    0x3c5078f5be315158,
+                    0x4e09621656c8278a,
+                    0xddf9f896a0d6ecc4,
+                    0x126f02837ca11261,
+                    0x159cd3a4033b12a1,
+                    0x9e561e4910d0c4d1,
+                    0xe3759825db057ad8,
+                    0x23b9b408cdc380cc,
+                    0x001b137b24d5b898,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd7d5a0cde135a64b,
+                    0x4abe1367732bcb75,
+                    0x6b2e7d032ba4c32f,
+                    0x9e6bd9309b8356d7,
+                    0x01d7f006ed395860,
+                    0x7f0c38d302465080,
+                    0xcb55d5243106eb36,
+                    0x23c635e6f63aa7a9,
+                    0x9e60db52e047d947,
+                    0x24774fe5a30de731,
+                    0xb280e26155f1e910,
+                    0x00188b43842e822c,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
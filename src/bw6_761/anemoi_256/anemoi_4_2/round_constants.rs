@@ -0,0 +1,1333 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger768;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger768([
+            0xaae0ffffffefc167,
+            0xe499e1712ff0b213,
+            0x49ebe6d1127eeb90,
+            0xa48c17d0f0b3c9c4,
+            0x76498b7fda7478ef,
+            0xe3c1b33942cadf81,
+            0x160b7082fbac579a,
+            0xbca9f7c54096309b,
+            0x7ae19b95a2d6f02f,
+            0xb94385861fe6cf83,
+            0x468e01aafead9672,
+            0x00a8b58f2faaa255,
+        ])),
+        Felt::new(BigInteger768([
+            0x612d1a3cf93c09c3,
+            0x02700df3b9979ffd,
+            0x803d481f0663068d,
+            0xab06fce1abe17024,
+            0x299957b13c3e1e4b,
+            0xa0b3a5dff4009ae0,
+            0xf0d8566a3e1ca175,
+            0x52c0d8a6f5fd6cb9,
+            0x6f8d835e6de73fb6,
+            0xc545c86abf1e4de7,
+            0x9f274d9f661b6470,
+            0x00896c63a08b5825,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xd9fdc16fc7337108,
+            0x2bdecc643f94d863,
+            0x050eb307c69559b4,
+            0x1b1b4c4a84c1c2ff,
+            0x9e739df3ed5be7f4,
+            0x26bf184b252f0902,
+            0xa8846c1670f7859d,
+            0x86de6a5cfe75cf28,
+            0x82d6422754a34400,
+            0xe848c14ccdcf9d1b,
+            0x2a53b6eb1e9f47fa,
+            0x00c815a73e2b0ac3,
+        ])),
+        Felt::new(BigInteger768([
+            0x9ad1427a13241a29,
+            0x43f53b83d119964a,
+            0x7fc2c52ed666a2e6,
+            0x3d8d4969060fd215,
+            0xf9b80b7b221be589,
+            0xe273ccb47c1e7e22,
+            0x1d6ef4d2c0b3ca12,
+            0x4a50aa323f70463e,
+            0x34c5dadb33c28b69,
+            0x9dbf3b70ef2604d1,
+            0x85e228a6474af7f7,
+            0x0030d72d470fc00d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x790a228eb18f81ed,
+            0xc5cc55dcc163b1d6,
+            0x611360bad7f25451,
+            0x08f564b96198b6af,
+            0xadb91803b2eff750,
+            0xfb494673a466ba88,
+            0x2f699016985006e3,
+            0x3198ed23c4cc953b,
+            0xe671e8184de1bb36,
+            0x471ccc05413f6984,
+            0x0d9941911cf496bb,
+            0x006f6dbc446003ee,
+        ])),
+        Felt::new(BigInteger768([
+            0xd474cdeb6f9a29f1,
+            0x59ba32da5e56375d,
+            0x458f57a25604a355,
+            0x5ee537e0b3ddedf8,
+            0x8396e75f29b47524,
+            0xa4ee36ead0ea8e59,
+            0xd1022e61246f156d,
+            0x8c4e287da0f317fd,
+            0x890b7e89ecd9f2e4,
+            0x17ddb65f9cf10387,
+            0xb2a663a7757de8e8,
+            0x01229363bdce3f43,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xf5c0c593afc99597,
+            0xea14576c69d253a5,
+            0x1630d229aa9fb72e,
+            0xab8b1554e6fac3b8,
+            0x442620bd39a8906a,
+            0x6e4ca2142b847138,
+            0xd8d8653450fd7a5b,
+            0x673ec576e3e83111,
+            0x72101f77c9bf0195,
+            0x5b9578f2f475ea2f,
+            0x089f830f8ce2d7b9,
+            0x000c93212d33a0f8,
+        ])),
+        Felt::new(BigInteger768([
+            0x612493648a765d8d,
+            0xdb5d96d0c2d661c3,
+            0xebeb5de72f997e35,
+            0x616807d9e42f1707,
+            0x4cd5f37d1bb20d26,
+            0xe56efb13ef61f65d,
+            0x8c92c698890863bb,
+            0x03b73f506ed95a6a,
+            0x37cf5e658fdf8fc0,
+            0x10ec4a8e2dfcd750,
+            0xfc5de643b686fe46,
+            0x00954d619547156f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x9dcce12fc3b230e2,
+            0x482327e32463c42e,
+            0xb0ee98c5401da783,
+            0xa63bd340162fdfdb,
+            0x7a1deba7262a02aa,
+            0xa9dece19ce4edd5a,
+            0x8bbfec540a6c8a20,
+            0x3a9cf3b931fcbd42,
+            0x247d75213e7949fa,
+            0x8ffaf3c8e3096319,
+            0xbfef5223056ac774,
+            0x00bebf400fcc5b30,
+        ])),
+        Felt::new(BigInteger768([
+            0x415afc126ce13156,
+            0x5bf8b93c13d6238b,
+            0xf1b428c0459468cc,
+            0x1983c4aa10bf9014,
+            0x3050199f357de82a,
+            0x22c2ad1aa77cd47b,
+            0x04def0d1bb0d21a9,
+            0x9955e7666a36c511,
+            0x0b70568286ac0228,
+            0xdf33eac6e07a2abc,
+            0x6e35a03f9370270f,
+            0x00350230674fc368,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x84de48ada811211c,
+            0x62d41b2e34bf5732,
+            0x752cc0aeeddde33a,
+            0x35f558ab68ae90a9,
+            0x8365e1f81965ea09,
+            0x5c0936dd959ea0e4,
+            0x5e3c827c2a6640b4,
+            0x7ac92d089c400ef4,
+            0xd42b15d1aaf63e8d,
+            0xd3d0a0db57e67772,
+            0xfdb7189efd805687,
+            0x002efa6deec9da0b,
+        ])),
+        Felt::new(BigInteger768([
+            0x181142fce22596da,
+            0x2f49ce59079992a2,
+            0x0e53812e39eb211a,
+            0x5e6cf260ff26a95e,
+            0x661cd97a57cde18c,
+            0xcf9377e747c8b0d6,
+            0x39f72d5618aa3033,
+            0x4e087cf79b62cbf5,
+            0xf2bda575128302bc,
+            0x2c02840ae73fa6e2,
+            0xd6ca691adee6fb2a,
+            0x004f314f9f005e57,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x54428e1d92d16362,
+            0x496b58bb84409140,
+            0x2014031d06bb1b88,
+            0xffe968ac046b9882,
+            0x1ecef239cac8a2a0,
+            0xe28331115412a7eb,
+            0x8f2e1f6435e6f2c8,
+            0xeb3373a022dc7924,
+            0x62dd8c5fe862b2de,
+            0x50b563787e138444,
+            0x252f1e3e85733abc,
+            0x008cb31e9b873b5a,
+        ])),
+        Felt::new(BigInteger768([
+            0xa0728bdf597ec91f,
+            0xafdec06e26f94e21,
+            0xd6aef65514fdaa27,
+            0x550a5a5995fc6dda,
+            0xa1da6b9047f3976a,
+            0x1dbb06ae9705313f,
+            0x688b7ce716d9e6a0,
+            0xe63c261809efb848,
+            0x581cbe07e3e41a72,
+            0xe644311819700b72,
+            0x4bad6e27f8739be6,
+            0x0035fe2a3756723e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x2a643f949ca6b0fa,
+            0xa12b3a449dbd0f89,
+            0x703acbcaab89447f,
+            0xf62588d4f50232a5,
+            0x7cb210affcea57ea,
+            0xdbb684c9e6d55aa9,
+            0x41f31182e6ff2e9c,
+            0x6036ce06c1691370,
+            0xcb93d3ab99d0dcde,
+            0x7fcff5d16012e67a,
+            0x47e2c320e94e83fc,
+            0x0031bf6d9aa59a76,
+        ])),
+        Felt::new(BigInteger768([
+            0x6b642884555b5104,
+            0x10c179b3defb2468,
+            0x950ead621c0112ad,
+            0x3f95b389c827b3bf,
+            0x2e6b1485ae986f3a,
+            0x65c089a68cc2db6e,
+            0x6533780715498d58,
+            0x337b9107e75de23a,
+            0x99b55ad588118901,
+            0xea2e8197700e63b3,
+            0xa43cf0cdc38292b1,
+            0x010a2c7df66964fb,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x23b69f3ce571eb9d,
+            0xcc94ec6cebfc6af9,
+            0x395a4784850e55a7,
+            0xa531d84144eb2d47,
+            0x10597d0d8e8743a0,
+            0x12cd36ba1ddde408,
+            0x2c7f462200750349,
+            0x90d85e936e11b44b,
+            0xd6bc6cfe0d09d937,
+            0x1de368948b17027e,
+            0x2978b876164bcba7,
+            0x006aac39e754ef75,
+        ])),
+        Felt::new(BigInteger768([
+            0xf1bb5ec32c025b22,
+            0xea0550496cee7f9b,
+            0x62fdb383bc4b5f3b,
+            0x18bd6a615c2db4b5,
+            0x2c0e48f81602611d,
+            0xc5dc2378a133e195,
+            0xfcd04e94628e06b0,
+            0x2d267c609c24b3ac,
+            0x41bbaebf3f14078b,
+            0x222f58b60d8e3b39,
+            0x55b979d46e8cecd7,
+            0x0036d08480037594,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x054d4fb1836d76e9,
+            0x804114596826f73f,
+            0xc639c96b279e26c4,
+            0x33bd8b669b573f91,
+            0x3fd05d25c145ddd9,
+            0x685b06fd66cb9682,
+            0x3fa3971270e16e6c,
+            0x02dffee42a8997ed,
+            0xe08dffa8c10bba27,
+            0x200599ff5c918c38,
+            0x9a888595a3f4f1a7,
+            0x00d8c801fa610469,
+        ])),
+        Felt::new(BigInteger768([
+            0x50801d22b055d5bc,
+            0x99a1db9362b4186b,
+            0x3f44c6e1e8387099,
+            0x0c582ad88470a6d0,
+            0x78932e48e63bffb5,
+            0x350a2e0a071292c4,
+            0x3bf98c79a0ff0a73,
+            0x939283cde7eaea86,
+            0xe4c506604a0faf8e,
+            0xc72d94e52f3e9128,
+            0x2e067462c931dbbc,
+            0x00d8d7081d8a04df,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x701845e4b61296fc,
+            0xecdcfba854f5c929,
+            0xd0ce4970f0234193,
+            0xdc2d5b3178e857c4,
+            0x43d506a964d93634,
+            0x7b3f1f562fedd427,
+            0xfabdc13c4fc24b1b,
+            0xff08a63ba30bb610,
+            0x59c331393b195f50,
+            0xf0cd74f3c1a77dd6,
+            0xfcce02ba4c53bb39,
+            0x00d3c8352dada596,
+        ])),
+        Felt::new(BigInteger768([
+            0x6009ec02b55bb7c6,
+            0x303131e415c1d184,
+            0xc1be34577a9be66e,
+            0x278a5aa1b3bbfe07,
+            0x3c34916c44c80cc0,
+            0x9445a8df4e0aaba6,
+            0xbc5860e95ebfa6fc,
+            0x798faf90f74d1ec7,
+            0xb0e31ca130d31402,
+            0x7d5ad50b9ae03fec,
+            0xfa96eeeece700355,
+            0x0072cbb6f33d9b1f,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x069e7574ba98912e,
+            0xfa83c662abb9acaf,
+            0x1f2003522ec74257,
+            0x469329b0e22058d2,
+            0xb559fa705cb76ce6,
+            0xaa63e2c05a003c3f,
+            0x1b465d1b9ed5c241,
+            0xf4ee69edad46ed24,
+            0x7532cc2b34fec0af,
+            0xf2d40658e9176c6a,
+            0x401dc5c5898b2ddc,
+            0x01100a4d20ab30bd,
+        ])),
+        Felt::new(BigInteger768([
+            0x919e1edb444d59b1,
+            0x172581d2b4ee2910,
+            0x01fdf734fa89ff64,
+            0x2113124885ae5d3a,
+            0x603accad9d3e12a9,
+            0x591e1b3dac9b92d1,
+            0x3add41c5e2234503,
+            0xb291193666884a2b,
+            0xfe2e337f9d38288d,
+            0x406bac38217bfd1e,
+            0xf340ffa2fb6c3d74,
+            0x00fc1a36a2fcef35,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xc324ffd73211b077,
+            0x41ba020503c5afd0,
+            0xc25d81b57bc3000c,
+            0xff919483362c85fa,
+            0x368b0cad4fccd6d6,
+            0x4827b956f9e1b1de,
+            0x7e3cf33ef3c08f38,
+            0xfc9b35e07344c29b,
+            0x135bab6d577c07f5,
+            0x9ea561f5f43a797f,
+            0x86ad42844738647f,
+            0x00aeaca69d386a09,
+        ])),
+        Felt::new(BigInteger768([
+            0x2f029a990b8fb76f,
+            0x9e6712895dcd73c0,
+            0xb6268decf99c6fd3,
+            0x61227c716996502a,
+            0x1e429afba2901524,
+            0x42e5f34c32532e29,
+            0x765522408d5a66e7,
+            0x90b5b61af948968a,
+            0x661eff651209df1b,
+            0x0c78abc802d0433b,
+            0x62633f957b786aa2,
+            0x00c141ccb0eb6e6e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xdcebd61bb1ab35ed,
+            0xae96ee82263cc5d0,
+            0x395d57cf159ba2ef,
+            0x8d2845ced0a47a42,
+            0xe258971f35f2d4e3,
+            0x1ae11bcffe30cc02,
+            0xe8536a4b3fb30a7e,
+            0xc658fd04daeb315a,
+            0x8754407e2b6eafd3,
+            0xecd299d6c84b3d71,
+            0xceb14af8f51741da,
+            0x00731af14971cbe8,
+        ])),
+        Felt::new(BigInteger768([
+            0xaccd1dd70ead8490,
+            0x84bc893f6f1a8af8,
+            0xb43be7ae1be520e3,
+            0xdb879dfbc38ef032,
+            0xc7c5d14b5749317e,
+            0xd8d89aced4e6371e,
+            0x2f65acddc3e76864,
+            0x7705d26308d7e352,
+            0x1fd287988250d20c,
+            0x9e85c3727d4c3a62,
+            0x74d4ffa1efd5b1ce,
+            0x0112a9548d61decd,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xe4f780380530da53,
+            0x0734d79746bed600,
+            0xb2fbf70c5dcc6c2a,
+            0x73a4f238722863ea,
+            0x249e5b4439f6f581,
+            0x40cbf077ce034034,
+            0x63a09f25dcaa5cbb,
+            0x948ef30429b78f9f,
+            0x6f45806e3b86edfb,
+            0x99ab7ba8c032436f,
+            0xbe66445ed2050645,
+            0x010e01c5d9fdaf13,
+        ])),
+        Felt::new(BigInteger768([
+            0xebf7b10dd1780685,
+            0xb3d2bb2a69c46694,
+            0x8982cc9c555d4a07,
+            0xa246dd5913af875b,
+            0x73083f8d3fd98bf5,
+            0xa94dba774bb532f6,
+            0x04910848ac0a8f76,
+            0x85af2d0e4d242811,
+            0xbc0b747980bd8577,
+            0x29ea3528c99cc655,
+            0x712be41e4dc76803,
+            0x003710354891ea9e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x190bd1c481d72bba,
+            0xd764698d3df9f088,
+            0x21c9ef0a8d75b040,
+            0x3a61238b6971c700,
+            0xeb29155411edaa04,
+            0x431f04ced7733120,
+            0xb7ccf3d958a14841,
+            0xd8ba156da802d781,
+            0x7e558bf3a98071cc,
+            0x46a94558fb776358,
+            0x3f52332990f5d831,
+            0x00fc343bb45ac36c,
+        ])),
+        Felt::new(BigInteger768([
+            0x9e6bb6d5c3464fbb,
+            0x4a77bed0dcaa9226,
+            0x19d95df08a2d27ae,
+            0x586fec496ec822cd,
+            0x95a4a9e134a8b509,
+            0xac0d2363ca3d1024,
+            0x52d35ce34a3b072a,
+            0x2bbd6939b9b02601,
+            0x21e2f5a9c7565e3d,
+            0xb450a53bf3a9bd8d,
+            0x1adb72a294ba5e0a,
+            0x0006664bc2ba9094,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xb0c8a032967c2fc1,
+            0x518353bfe6a2d3d9,
+            0x71aaa2ff7069376c,
+            0x97b0774e64b98a2f,
+            0xeb284d1e31f6a733,
+            0x29f1c0de2910d6a5,
+            0x2ac5697b77ce3133,
+            0xa30ebbdc94d17fb3,
+            0x31e228d8399cd69b,
+            0xd4213899e8b6a42e,
+            0x934f9a73031bec1e,
+            0x0064b26576239330,
+        ])),
+        Felt::new(BigInteger768([
+            0xc234d596af64d05e,
+            0x4e5b83c7b06bb8ed,
+            0x4b168da8d09f3792,
+            0xe9333825867be762,
+            0xe0d523f7ae572c33,
+            0x40684f6150e6eb61,
+            0x11117fed2a5bfceb,
+            0xcb2bc56036f8c0d4,
+            0x944b538d49c0a7bf,
+            0xdf63e2f4723d675c,
+            0xd4d0c1d4d4e76788,
+            0x00e530ca70013025,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xffe0f5d3df90565f,
+            0x5aef821df6b7d7e3,
+            0x18134ad0c2f6ef90,
+            0x905b70ad18ad5e39,
+            0xc4af96fdb311bfa2,
+            0x1f60c9bb4fb75764,
+            0xe7a595fd3d9cb504,
+            0xbcb23a5c503a29ea,
+            0xea48cf6b9e990845,
+            0x29f168d9b49db204,
+            0xa997e7bafb036508,
+            0x00a62dbc7d5aca3a,
+        ])),
+        Felt::new(BigInteger768([
+            0x8575e4f84df2754d,
+            0xc68b646da021c29c,
+            0x7a314939fdc41bc4,
+            0x00a22de2e1991052,
+            0x56a9f6a46af7e947,
+            0xe7a4ca4793be9cb0,
+            0xa4d0326ba81d4ef6,
+            0x7e9d57ddcaf918af,
+            0x8a6d34f025c81a38,
+            0xed252d060377bd8d,
+            0x1313212909106393,
+            0x003bc77460c59d39,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xa0ac7baef48f02ab,
+            0x3ad91658098d7f3a,
+            0xd773320ba477fff4,
+            0xb8060135bda87d02,
+            0xd27f5b5a5098e0f9,
+            0xf70a0f48584bda90,
+            0x79900dc15d4d32b8,
+            0x692004d23475c5e5,
+            0x9842e896372c2d4a,
+            0x898a750ea67cc51d,
+            0x30474fd9ee9bb454,
+            0x00c4d900e0a804c9,
+        ])),
+        Felt::new(BigInteger768([
+            0x4727b0575ba20336,
+            0x3663105d22aefb0a,
+            0x622b613faf1e7bcc,
+            0xf3ef5afdda03093d,
+            0x2374a758ff964bde,
+            0x11ac7923fb9ff2fb,
+            0x3cfff9396a471c9e,
+            0xa2cb6bf3e5b036ff,
+            0x295be79f1a726241,
+            0x430eee4d722db416,
+            0xf9e9924b272e1566,
+            0x0098c240ab142e30,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xee1541286cef8833,
+            0xee6c40d62d4bffa8,
+            0xa00cd17959d7a3a7,
+            0x727531e6130cc398,
+            0xa5ca6defaa34dfa1,
+            0xb3d1d49785ddb501,
+            0x4542593ff8aef34b,
+            0xc5eed1e641f9d9d0,
+            0x0deaa369dcc00e3b,
+            0x8d899d2671d12563,
+            0xaacfb1b323761a13,
+            0x00e61480e487f77c,
+        ])),
+        Felt::new(BigInteger768([
+            0x2a61df82c562bf4f,
+            0xea6127c40cc591ba,
+            0x7831b22985513efb,
+            0x72c036f983589d25,
+            0xa632c858164323ce,
+            0x8a791573b3da84c8,
+            0x77a2369e5837a298,
+            0x7c3ef1df72402392,
+            0x7ce9e9b7f4c46cd2,
+            0x12fa22a22465ae4b,
+            0x13eeb6c7b647bed6,
+            0x010398db393d28ce,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x1623389512ec4645,
+            0x2e42bf57660075a3,
+            0x066d207da5f412b1,
+            0x416b4b264585b44d,
+            0x81172b690fa2ce7c,
+            0xa2c31ece5e65f789,
+            0x14b7ea49cb380f6a,
+            0x835551292ed057a1,
+            0xae820dcee5504f91,
+            0x3834a9df44fa938c,
+            0x990184e8fb52c93d,
+            0x00a970c37171da8b,
+        ])),
+        Felt::new(BigInteger768([
+            0xa54c3ab2315213d4,
+            0x0ad2b178915cbc45,
+            0x9001f54619afd608,
+            0xa18a09741d5ab67e,
+            0xfe541b4deeb072da,
+            0x8b12a3d196751670,
+            0xe3f1b5f9e7a49e58,
+            0xddb8f5e12c94e0bc,
+            0xb66c5d9502a758c8,
+            0xd8f666561fb22bd6,
+            0xadbbc65bd68348d6,
+            0x0084b676185ebcd8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x197a76f4a14bf633,
+            0xf51a2d9162cc0678,
+            0x86022aa556e78fc6,
+            0xba6771916da78112,
+            0x0ab8db13a33c609c,
+            0xf5e8b6b289dce8bc,
+            0xea12ae1af29017eb,
+            0x6cadc6604f8e225a,
+            0x062be030f55cad93,
+            0xb095ddc7a0846c36,
+            0xf23d6fd141018fec,
+            0x01206d6bcce6313e,
+        ])),
+        Felt::new(BigInteger768([
+            0x486e78bfdf9be4fe,
+            0x15df4dec3ac480f5,
+            0x93ab879ce899e2d0,
+            0xb41f9517224190c0,
+            0x53a948de35322804,
+            0x8546a896852c18c0,
+            0x7820d64ad4cbdcb3,
+            0x89363a0c6d8227c2,
+            0x05e2e9f30df576cc,
+            0x9ebf0a60618aa4e1,
+            0xb29987d12d79d010,
+            0x0102e268197f6955,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger768([
+            0xb193ffffffef840c,
+            0x1e7d5557bff07845,
+            0x8e32219b5e466ee4,
+            0x45710c08ccf23442,
+            0x33d00730af48d55e,
+            0x80f00293e271f2bf,
+            0x67cae5e35728e29b,
+            0x161598a7f0eb4c90,
+            0x0f4b8cdde6ebf54d,
+            0xff72be82d286ad57,
+            0xc99e2c68cbe2ebc2,
+            0x00403d3c2a7caf8e,
+        ])),
+        Felt::new(BigInteger768([
+            0x5c3ff79e2cee7224,
+            0x1514222ca33b5845,
+            0x690fbabf5a309443,
+            0x9ac3cd6b7b57f280,
+            0x01bf08472303226f,
+            0xbc454852e8d30275,
+            0x3ada4b55d93668e5,
+            0x7d0ea707478f732c,
+            0xaba927e3b16df79a,
+            0xfa53b309aad08a90,
+            0x65d9f3fdf20f89fc,
+            0x008081d0788530b1,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xdbbd1e24ca6628f1,
+            0x4cb73fe87174abc9,
+            0x4b8133813e3118ad,
+            0x04c4bd6d65567ee4,
+            0x19bc660995f1505a,
+            0xc12a6a2f1f69e9e2,
+            0x2390ea56ee70488f,
+            0x7a85417a1d245d23,
+            0xc969e11e9531c619,
+            0x108bdc396f35bb47,
+            0x16ddecb28b336684,
+            0x011df9c25c90a567,
+        ])),
+        Felt::new(BigInteger768([
+            0x90f07c904a0977ce,
+            0x3d8e4f5a5c9d5bc6,
+            0x6ac17d7e56086c42,
+            0x760e96ddd9dca5d8,
+            0x8fa00875dca1f5a4,
+            0xfb4271b0cb84b359,
+            0x90bdf29e7dc9c974,
+            0x0ed9aeccff5bbeb5,
+            0x230b2d0f73c2c049,
+            0xb4e107ffc99e81d3,
+            0xb60eda0e729de6bc,
+            0x00e64908429d2603,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xbee3f32bb2b14db9,
+            0xac2db8201ebf59b0,
+            0xc6b3c6f2c6f8f2e0,
+            0xafa307796c242dc7,
+            0x23cded6e1a534bbb,
+            0x5bef14063159bc5a,
+            0xd983c8b39cb8fa80,
+            0xa7ad4e5a91924a59,
+            0xd0dea2988cf88e27,
+            0x08e915f0d2893891,
+            0xf269bdb12dc5711b,
+            0x00f91dee042a2bbf,
+        ])),
+        Felt::new(BigInteger768([
+            0x1a117be9a46e9aee,
+            0x324af707a555d0cb,
+            0x39aefd016220a80f,
+            0xbbc9a0305b39d3f6,
+            0xa26e1dd22f1c7216,
+            0xfd6d8ea8a00ee6f1,
+            0x6f902b8a42c12274,
+            0x62c910f92970ce80,
+            0xc8a77657aadaba5b,
+            0x0f629982e57beae1,
+            0x0991922840129445,
+            0x00e8e9305f3c645d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x41a01cd5122e50fa,
+            0x97bb62e703908e31,
+            0x4e239f7c2e777b06,
+            0xdfc02325a6109280,
+            0x937b66a78ca7c7ec,
+            0x34dc4df236747754,
+            0xbd133402465c494a,
+            0x0dcfa6e66d92c1a0,
+            0x6546e7cd9d647dd8,
+            0xce06bb869be192d3,
+            0xff2ccb0e4e932f1d,
+            0x009ad8bda1e12fde,
+        ])),
+        Felt::new(BigInteger768([
+            0xacc6c807208dbe21,
+            0x7b34043546388de1,
+            0xb25d6a60d086a838,
+            0x4bd3db3a401554b5,
+            0x44ed9a700cb5ed2f,
+            0xa3d8311d3c835340,
+            0x654159f298504c15,
+            0x0aaea804b43bec5d,
+            0x80356408e26f0088,
+            0xb91626598ca99841,
+            0x6505e0a331fb26a7,
+            0x00603898eb98a19e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xd2449d72cbe356fe,
+            0xde885cbd79cd41a0,
+            0x8fc35ddf7e1241ca,
+            0x99461e76687f1561,
+            0xe66a7dacc264a951,
+            0xc98d3e4401daf3e9,
+            0x4fff9015ae693c06,
+            0xffc23d8fdfef1bc7,
+            0x79e40fd5ffd5591c,
+            0xadbdc4ef2ada34e0,
+            0x6f0410bc841bf50c,
+            0x0072e8157f34d108,
+        ])),
+        Felt::new(BigInteger768([
+            0x6a3295b6a8c4fd2e,
+            0xcb1e8e68c2e39313,
+            0x751525b08b8f0d76,
+            0x5b65ec325646dd78,
+            0xb73be089e3a93686,
+            0xc0d4705d30343f61,
+            0xc161141e9ea70ffe,
+            0x2f5d5ebab9660e12,
+            0x0888a4744779c412,
+            0xebd56d8f615d5b59,
+            0x60ecda79d0e024e3,
+            0x0048b8c5b3e00492,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x73b756ce26518e4b,
+            0xa48266a6aba933fe,
+            0x61b08ceb5cbeed15,
+            0xb7666c9dba693f55,
+            0x957a44ddd3525958,
+            0x9a7034922dbf05ae,
+            0xecbdcc3aad7129ba,
+            0x8c4cecf0f4aa1011,
+            0xff4b7de864aad513,
+            0x7a82f0d3dde13730,
+            0xc283c8b1b45720e2,
+            0x0117a78e620288f6,
+        ])),
+        Felt::new(BigInteger768([
+            0x06ad2e7e9418a93a,
+            0x63277bbb68276101,
+            0x89568c91c5e19120,
+            0x9614cbe2edb1c6ef,
+            0x20f39d68afbef962,
+            0x05d3ffc7221a6c67,
+            0xbcec3ba0b59e32a5,
+            0xbff2c424af84ce76,
+            0x730d4ad94b218dc7,
+            0x086d6d3b247b7eee,
+            0x0db1cb8e4f819682,
+            0x0074840af3dd0a8a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xba0b9c463c462f18,
+            0xbaee3370626a129b,
+            0x9801d97600c6df8b,
+            0x1a01d00975fc3238,
+            0x8be92a5e62d157c2,
+            0xba42f82a7fc2b614,
+            0x776de52b9100bf44,
+            0x3fc3b2c306e6c070,
+            0x95203299c0262178,
+            0x3ce5b2d34d656fea,
+            0x42daaeab18eb7dc0,
+            0x00c3308dfb292717,
+        ])),
+        Felt::new(BigInteger768([
+            0xfa9b776936a63a91,
+            0xfa223b755ec6c192,
+            0xf3290484170f788d,
+            0xbdfa9e08fac51f6e,
+            0x2993d899f1ecf440,
+            0x73de20e017e093c0,
+            0x490dc239b190ef8b,
+            0x0bae92b88f36ea11,
+            0x3211177ebb193bd3,
+            0xc153321521d455ee,
+            0xacfb7a354aaaaf26,
+            0x00cc09597420294d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x93b86200f0391517,
+            0xcc7c1c697d5c964d,
+            0xe3923441faed0f5d,
+            0xd2047a952aa415f6,
+            0x10dd14c68bd21593,
+            0x2b255c9553c13b37,
+            0xe4424b186dc3a388,
+            0xdb53462481549006,
+            0xe4ab6c43ccb37491,
+            0x1d5838c05b7af1eb,
+            0x9ebe0b1c49040fc9,
+            0x00eabeb25bbb629d,
+        ])),
+        Felt::new(BigInteger768([
+            0xd47b2851dca05a52,
+            0x2e41bdc2a83e9cbf,
+            0x96e55500887a43b6,
+            0xd1ab6ad99a9a05f6,
+            0x6b5879a4db84d569,
+            0xad08eb9d3be012c2,
+            0xfbf67628b5f71bae,
+            0x0efe906a63016034,
+            0x07fc30bb39de153a,
+            0xbd6f5dbe22b78772,
+            0x6d32eb29dcfbef7b,
+            0x00ffd15d99232a6a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xee331fb7de22fe18,
+            0xf5e901d4574ecc11,
+            0x0aadc65724148034,
+            0xa6e7f656318c7f92,
+            0xe71b8b137de399b7,
+            0xd3bcf6be5ed1c9ab,
+            0xd2ea5f0473290d72,
+            0xc721bceff425db64,
+            0xc44b690834cdb92b,
+            0xefb1a36fe2c38306,
+            0x5904d920eb2dd2db,
+            0x0055d158cdff1a91,
+        ])),
+        Felt::new(BigInteger768([
+            0xb097bc9f58661359,
+            0xec1a060331e4d2ca,
+            0xd8dd6a2c6357942b,
+            0x694b64c83c071edd,
+            0x1d6f8be3174f5ee9,
+            0x052f369537531b90,
+            0x9b7de70214df4d4a,
+            0x3452083ac375c543,
+            0xd6fc5e0666499a46,
+            0xe2dc45339e4d1a96,
+            0xc8e81620022dc447,
+            0x0081836343d56c02,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x8733a630e79c215e,
+            0xd51848a1ba8c4393,
+            0x2fae8ca270ab3c98,
+            0x4c9a7e0d423d2000,
+            0x0cf25a77560c22a0,
+            0x67979328098c1f77,
+            0x3fc7f9e8c2c241b8,
+            0xf56224a26ed03a06,
+            0xcb79d1f33e3ec970,
+            0x59f25b56574a6be0,
+            0xf8c7257bccde4a86,
+            0x00d798579b9d781b,
+        ])),
+        Felt::new(BigInteger768([
+            0xd229510348372562,
+            0xe0a871c59ebd5652,
+            0x3738c9404e5aec98,
+            0xdb6be30ec826f624,
+            0xee778ca31906ed02,
+            0x2c20445fec04727f,
+            0x3091b3dc0cc8f72a,
+            0xe67b30d0e7e98e03,
+            0x24e015f8462cb35d,
+            0x36d2ef73e138891e,
+            0xfe5fc6a9abdf0599,
+            0x00144cf8a06a75d8,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x12258d524c89c08b,
+            0x87162f2812111c61,
+            0xba780494d5a93d36,
+            0xa31d3309d603081e,
+            0x9be97f752af4cab3,
+            0x75658b657f2d121e,
+            0xf26e6ba0550a4fe0,
+            0x5713312274f2895c,
+            0x297f462cd13677bc,
+            0x355aaf2fd5d1ec1f,
+            0xcf8a791bc3f9258f,
+            0x011b011252b104b0,
+        ])),
+        Felt::new(BigInteger768([
+            0xf67710d17f858711,
+            0xa32b05b62c8116d1,
+            0x4ff427516827ec73,
+            0x3d520ecc040ec63f,
+            0xaee83f1d1cd448f4,
+            0x0ccf6806f2753df4,
+            0xac4b8ad8a3a4e831,
+            0xa27c67f56a70dc90,
+            0x2850e4d1c661df34,
+            0xb0c6c0e9e81d0d0b,
+            0x10f5e0f104d43de6,
+            0x01199253f568c58c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x097c6269b56fee54,
+            0x73c283fc78246418,
+            0xdcacdd2f9c9d45d4,
+            0x80f1b0c0ac38220a,
+            0xb781ea89ff4c8356,
+            0xa6773bb35daeaaf5,
+            0xd8e465ddcab418b3,
+            0x04f564ccc65a6679,
+            0x3c32622ac1f15935,
+            0x9551665084b7df04,
+            0x90cec6f71a1e0dfa,
+            0x0099c354b8dee114,
+        ])),
+        Felt::new(BigInteger768([
+            0x88dbe93172d75c93,
+            0x6924dfbedafcd28f,
+            0x641708e870660d43,
+            0xaa4975aa42fe3e50,
+            0x7d01f1ac51c3d0ce,
+            0xd394c749057555de,
+            0xf0bdca134d9ed7e4,
+            0x937a419320d8adfd,
+            0x6cdf7cbc299c73d9,
+            0xd1c7bdd1f62ece8e,
+            0x87947c754abdedcd,
+            0x00e560fe18586adf,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x5c98311efae36bde,
+            0x02d3412dddf84c03,
+            0x9dae2ec2c1cfdaaf,
+            0x7395e6f73d8e7a82,
+            0x235cb1da76efada2,
+            0xb3893fe43b5a1a22,
+            0x72ca4475976cdbcd,
+            0xe3f5e34e0e647a1c,
+            0xba8c0d6b1facc42f,
+            0x8dbf6e992728bbf2,
+            0x54eb14d35905d9d9,
+            0x00c0a3ca14ceeea0,
+        ])),
+        Felt::new(BigInteger768([
+            0xc838a94208141807,
+            0x51afb39c21a40185,
+            0x1ff67a215cbeb0a1,
+            0x8b5d94750dc8b398,
+            0xb3d6a13167b79476,
+            0xa6210404b5fced33,
+            0x5f5638034aefcce7,
+            0xd876eacd50204f6f,
+            0x627e9eb059248fda,
+            0x314b51a2ecff9dfc,
+            0xa2bbc4454709b0f9,
+            0x000fde8b0a25f04c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xd954a9d3a314b391,
+            0xd3c7f496a4fbdb6d,
+            0x8865545025b96443,
+            0x724d185eef9833f4,
+            0xe7064032c6c2997e,
+            0xd7931178e0b53e68,
+            0xcc9550cd9b8b6461,
+            0x0cc23980a6789b7f,
+            0x1b49d2482ac351e3,
+            0x5b8933ffb63b82bc,
+            0xf53ac0fce1a76905,
+            0x006fb2337c2e6b2d,
+        ])),
+        Felt::new(BigInteger768([
+            0xa8f8cef033c9a765,
+            0x9c1cf13dd77d9228,
+            0x91c3235649184862,
+            0x76e3361b7f5318ca,
+            0x7535db67861d9ea0,
+            0x8d641aa2f99c004b,
+            0x081b57ec39a8dbb3,
+            0x1dd59623901d4edb,
+            0x08f756b0008f68a1,
+            0x42f4f6d3227d97fa,
+            0x0d7928069629a9f6,
+            0x004be631a1c27b5a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x352fe4607e32dfeb,
+            0xfc536bea275c1769,
+            0x8859f4bc82298953,
+            0x1694e531cb749467,
+            0x48c8e8d841ab4053,
+            0x4f917586bc731113,
+            0xf07ca93a75305a25,
+            0x938715189752a7bd,
+            0xaab8eabb86928271,
+            0xa5eb052cd713c955,
+            0x9c02595b773a6082,
+            0x00cb4f2bd2ac5c19,
+        ])),
+        Felt::new(BigInteger768([
+            0x308ff2977e2cb1d9,
+            0x81b1efcfa4059a13,
+            0x036d022281c07194,
+            0x940eaca46033cfb6,
+            0xb1d20206597e7e7c,
+            0x3676929e8f50582c,
+            0x89af91e8842dc950,
+            0x55897ca05bfc2aac,
+            0x9f309203cb3accb4,
+            0x2508704f1990ab11,
+            0x926a74bbb1bb927c,
+            0x0053eb5b1e6862f6,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x9a22fff9a8af80ad,
+            0x20db91b93fb78985,
+            0x854fc54194385eb2,
+            0x505634f2546fa9de,
+            0xd8f059168c93bef5,
+            0xe22007ad933cc8e7,
+            0x6bcfa5a69546cce6,
+            0xd4cf051be38c5dc6,
+            0x6bd34e66e14396cb,
+            0x790196f59bfc9ad2,
+            0x952d88e0a2c4e643,
+            0x0048046884aa332f,
+        ])),
+        Felt::new(BigInteger768([
+            0x087fc26c1dd14af5,
+            0x5340c5b7a80c1dbd,
+            0x37f864ac83e684ba,
+            0x55ddf0c4a365c681,
+            0x0fe7f665352b70de,
+            0x4ffb4247ee2bf9d3,
+            0x02e7493aec31eb44,
+            0x69302c9e7bfb7fdc,
+            0x0994e1497f12f444,
+            0x8eadc0e54f129a65,
+            0x85e10d3a6a433b97,
+            0x00d4ac5d6bb599b4,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xb173256532a5ef36,
+            0x4f3a1ef56c8ac646,
+            0x091803ede79be0a7,
+            0x56338fd1b0536dcb,
+            0xc1202e4fe105308a,
+            0x32aff8bafb4e6678,
+            0x3815ba20e9c7952c,
+            0xb4308947b799c6fe,
+            0x884ed5db93610447,
+            0xf1e1de5a69e2ed70,
+            0x712d9746d788f336,
+            0x001eaf21ab04a898,
+        ])),
+        Felt::new(BigInteger768([
+            0xb73f382a7f41358f,
+            0x24d2ef4f8ff79d70,
+            0x8710266d4fd7eb30,
+            0xf68e2cfac54de2db,
+            0xd16c3a0e6f565d3f,
+            0xc789da56784fcf8b,
+            0x16a4501ddbf29d53,
+            0xad2fc048fafdf29c,
+            0x9269b3cda2f68832,
+            0xec033a572c7c0f74,
+            0xf6513a4968133edc,
+            0x00febb46820a10df,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x925feccb1a8245bd,
+            0xb7de4196f6ec0e68,
+            0x89f139e56c525ec0,
+            0x3b604dc50c0ba67b,
+            0xd03efa2c27c72fdd,
+            0x19f3a2e02bba93e5,
+            0x125ed3860998cb3c,
+            0x1312ccc0fd6688dd,
+            0x6e5912b33d896011,
+            0xa7090deaab37f324,
+            0xf2e04b402db2f98e,
+            0x0089607e005a74bd,
+        ])),
+        Felt::new(BigInteger768([
+            0x0c54b950bc970a67,
+            0xfc3ac438f9f9eb37,
+            0x909b7024af259557,
+            0xfa7ee74cc82f7072,
+            0x7cd88eb7f19e0136,
+            0x609af684c4ed2d88,
+            0xc7cbef7fb3b6a19e,
+            0xa5e017c01962621e,
+            0xb62f2b74c42a24ca,
+            0x591b83b933245d82,
+            0x9ffe004efa7ec856,
+            0x007e87f5c0ed130e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0xaee04297a80f6c89,
+            0x2c4951ec7be384a8,
+            0xbec15626a0602a35,
+            0x8305f657ccfc10de,
+            0x94a9313596c83e20,
+            0xc095ffc9c6993f8f,
+            0x85fca9ff53be4d83,
+            0xadc4f0835d746625,
+            0x2617aa8d184096f7,
+            0xa9b754d0fadb2d5a,
+            0x1b1934fe05151c45,
+            0x00c790a1c5d60092,
+        ])),
+        Felt::new(BigInteger768([
+            0x49bb54a142d512d0,
+            0x0093ec43eea8f28e,
+            0xee05bd30b30cb070,
+            0x0dc72c71dc8eb4f6,
+            0x003db21957b650bb,
+            0x599bbcbdbf18ac51,
+            0x41af1502a05573d8,
+            0xb8528522afebc1bc,
+            0x5ee25cd2faf87eb5,
+            0x521a7fb1ff9e7b28,
+            0x285df30ffc664d93,
+            0x00fb07a16d69f54c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x6f4929b0901aec83,
+            0xdcb7849530deaf3e,
+            0xb2de2e03ba69ef17,
+            0xdd336e159cee4dc6,
+            0xfe940ada8181ed11,
+            0x68f873247672e5f1,
+            0x5f8186f3d37bb511,
+            0xadc82416940fc6cb,
+            0x3d70a989fe37af31,
+            0x139b1335b15d5936,
+            0x875af284f5ff2431,
+            0x01218428cef449b0,
+        ])),
+        Felt::new(BigInteger768([
+            0xab58a56c1c40c8d0,
+            0xcadbcd6cf9fc32e2,
+            0x19824ddb02f8f096,
+            0x93b538b8aa0a9639,
+            0xa7bec64b8b94d9c5,
+            0x37793e2be6a10c7f,
+            0x865528de4ced7dc9,
+            0xc47ecb54800e11f1,
+            0x019f2d259526024d,
+            0xcec431e91b32fa6c,
+            0x6294a9fa429499f0,
+            0x007bae1e054d7849,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x150ec03d919b6262,
+            0x904053039773be66,
+            0x0ab73ec10d7417ae,
+            0x46d57e2a93d6b1cf,
+            0x1f7150b9f20abf05,
+            0x3b50d8f630803d45,
+            0x2168d6acad0ff924,
+            0x91d271de34a9df75,
+            0xf9529fbe25aeccd7,
+            0xa72184e9ff3b1a92,
+            0x0c4e44cf209363f3,
+            0x010809597d8847a3,
+        ])),
+        Felt::new(BigInteger768([
+            0xa3fa9fbbe3b3d522,
+            0x5effa70eac73f69b,
+            0x22cb52b09e454130,
+            0x5d2b0208087c22e6,
+            0x4570a1a76f1d0bea,
+            0x1b79e824aac0b2f3,
+            0xe51666e8e365a17d,
+            0x4c9c9ddaee2669f4,
+            0x566c2cd1c1efca94,
+            0x7d9bda989133cb2a,
+            0x932338a2b587b48a,
+            0x001ff4a706192737,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger768([
+            0x62c0975dc122ae9c,
+            0x0964dece56a541e0,
+            0xbbce37cfbc3985ee,
+            0x39f74feb02466664,
+            0x0215021d337f824a,
+            0x5cc936a88ee0c069,
+            0x838526ccf9058687,
+            0xe199d6de7d2402e2,
+            0x1912d54144bcf841,
+            0x0de79ca7941c227b,
+            0xa8a2a6543ad8a78e,
+            0x00fabe3303bf1463,
+        ])),
+        Felt::new(BigInteger768([
+            0x9177768a33254298,
+            0x1c5961131841adf0,
+            0x57f6d3ee6b013f22,
+            0xe9e6390053b0e4f8,
+            0xf3c7d0f06379f238,
+            0xe400b2b7cc614733,
+            0x06071388f52a64b9,
+            0x5e88d1cf56d009ae,
+            0x6df91c50dc3fb600,
+            0x31c962780c637373,
+            0xdb1970b4e114b8af,
+            0x0019d8ca31fc49c1,
+        ])),
+    ],
+];
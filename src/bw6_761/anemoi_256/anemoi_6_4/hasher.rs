@@ -0,0 +1,3415 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U192;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U4>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger768([
+                0x7adff3f9cca73c86,
+                0x63bd3b0892493855,
+                0xea5e20cadd13f9d0,
+                0x84e60c2849b80e10,
+                0x81c398b847929a08,
+                0x0f0493ba0e18b121,
+                0x443ac8f22dff8e3c,
+                0x2f96b4b86d426ebf,
+                0x924145b2413bbea3,
+                0xf128f2f3c40148d7,
+                0xa65890a858f50014,
+                0x00eecb3143e5703e,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0xee87330fc8dd8e9e,
+                    0xf231f01bb2f6474d,
+                    0x36f4dd5343b085d8,
+                    0x138e491d0e1ae062,
+                    0x76a5cbabc29061d7,
+                    0xdf2bd4886a56aa8b,
+                    0x56690c11ab99653b,
+                    0xc2208e9a6cca15da,
+                    0x1e0aaf2b4b946630,
+                    0x09cc70713aec732c,
+                    0x9af4be7acc829bf5,
+                    0x00f6caee60e6839d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b2c87186daa103b,
+                    0x488b0d152be99909,
+                    0xdaa09edbc06b8b2a,
+                    0x380293c8802c2ff4,
+                    0xd9ef7ac6031ab26d,
+                    0xe893b87acd12893f,
+                    0x541365d2d740c45e,
+                    0x6fab5ad912a7b901,
+                    0x5709b811cca9bfbb,
+                    0xb9ceeb5f0648bfca,
+                    0x6cb47edde4a81830,
+                    0x004ef5b6e76ee3e8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xe0489cd313d05fd8,
+                    0xbfadad24bb94656a,
+                    0x163cdb66e3a38dc7,
+                    0x81718f6624c95714,
+                    0xc2d3883906549ee7,
+                    0x79bc6f3af4cbcb6f,
+                    0x907e348d35e5f227,
+                    0xb35dceef0aac10f0,
+                    0xfa465983172ad40c,
+                    0xbbac658f78f37af8,
+                    0x35eaa3b7fb96f6ab,
+                    0x011a742ab9166a44,
+                ])),
+                Felt::new(BigInteger768([
+                    0x11cbd668c462a04c,
+                    0x60b9c15352b47880,
+                    0x7c62c70b6c5a6795,
+                    0xafcf718c4504caa5,
+                    0x008a9214b78c47d6,
+                    0x1ea7804270da6a7d,
+                    0x2531568ae2191a42,
+                    0x55ebfd0450943c86,
+                    0xbe9e0626c39c8828,
+                    0xbe40a6faf96ccf18,
+                    0x58148e4997b64f46,
+                    0x010bf2e76b53fea8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa7bbfca48d08d450,
+                    0xede08133ce2d8841,
+                    0x31b1fd29c9d24a23,
+                    0xcdee4b6144648fde,
+                    0xcfa69b4d2646197b,
+                    0x5bb61ae163d80a34,
+                    0xdeeec915bfb47aad,
+                    0x03aec44b8870cf63,
+                    0x7f1e5c5787965848,
+                    0x0969391344cb353d,
+                    0x78e471dc34628530,
+                    0x00ebad94e70ca1d4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x075aee4a6b3101b2,
+                    0x588d7e03c502bccb,
+                    0x2ed88dfa03b59529,
+                    0x0ef60a165f01dc0d,
+                    0xdb8ceb42fa1cba5c,
+                    0x9214892b9a85b17e,
+                    0x02b0ab816b0adc28,
+                    0xc42583832b869741,
+                    0xcddafb4b76b00971,
+                    0x8871ff3f09f637b4,
+                    0x27873bebb692b13c,
+                    0x0082b27ec3894536,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc0e0f8cd893f0d5,
+                    0x08390f4a2d4c19fe,
+                    0x852fed122bf12882,
+                    0xfcaced5736caf886,
+                    0x57f82418b47c3434,
+                    0x24f6e295d62383cc,
+                    0xf8cf919a4a2b6564,
+                    0xda96051f2cb1e70c,
+                    0x3b3b26e38ccefaa2,
+                    0x37905d2afe7ff0ea,
+                    0xda9fe81b689d2768,
+                    0x009e7b4fe64fe50b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7924dd078e943ae3,
+                    0x0c01a8a180bfc514,
+                    0x91d24945d7f0756f,
+                    0xbd5683ff7206fc64,
+                    0x45ac9eff23ebe4ec,
+                    0xc17c3d4b0a2f73a8,
+                    0x3d477c938eda5af1,
+                    0xa94369ec8446f82e,
+                    0x92ef1d0a1a8e1440,
+                    0x47002a5b308599ce,
+                    0x9439481c4ef526c8,
+                    0x002e9f017a189127,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba8b83e46ff4942d,
+                    0x59c894c588714132,
+                    0x64cc41af719a9b36,
+                    0xab082f439be85b56,
+                    0x0f711101f297c37a,
+                    0x86dc1d7ada5ffd56,
+                    0x2b8d2716a941b12e,
+                    0xc148cec5bcceedee,
+                    0x71371c351e810be5,
+                    0xcedb57a5996a5dcb,
+                    0x1b0f95f9940f2ff1,
+                    0x00dc43caaacfa37b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x069a9e19e40f13c7,
+                    0xb7eeb3bb82eae12c,
+                    0x3b4e77aa00aff3b4,
+                    0x0393c11387232628,
+                    0xc4ef758afaec0a5d,
+                    0x88c5572f6e400a52,
+                    0x6e514a5cfb16c51e,
+                    0x9e32a30703d8108d,
+                    0xb504b887727705a9,
+                    0xc80c5f3b33065b42,
+                    0x5f84bac2cb20e8b9,
+                    0x0067c8513d6262ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x841e4eab69647d9e,
+                    0xe9fa744b7335ee7d,
+                    0xe74e759d782f3857,
+                    0x666385d6b3c49f1a,
+                    0x7c4b3e58f24107f7,
+                    0x767d0f107a05d466,
+                    0x35d6f38cf9e70b26,
+                    0x75521d9e1f4579f6,
+                    0xed7e355b18968ea7,
+                    0x994646e0a20b6b49,
+                    0xfd960bd8873b99c1,
+                    0x00ede4392cf169df,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72e8b299a6b4b0ad,
+                    0x2a3d9f3afffd0ab4,
+                    0x3fec9a67365ce708,
+                    0x00c224d6a41c1ef4,
+                    0xf0c56d3b6c4ef73e,
+                    0x7862e6d271e8c60e,
+                    0xd1d9348f42d73727,
+                    0xaf9d87934cc7384d,
+                    0x91156324a1740429,
+                    0x3afdf9a01ca613d9,
+                    0x94e27f81e51be7d1,
+                    0x00330e7bdda9222c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51cb3f4018534980,
+                    0x960b01773f9ab803,
+                    0x77d7f443089c9ca2,
+                    0x2c74febfb4169f0c,
+                    0x05570f358aff3a8a,
+                    0x5b6cb76585c32b3d,
+                    0x713596a13422fd78,
+                    0x9516d6ba8cfe439c,
+                    0x67044affda13a021,
+                    0xc2943398bb4cbc0b,
+                    0x004716740ddd0be6,
+                    0x00c1326fc3642a44,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9ed55477ae3287e5,
+                    0xf74aee2f2e711097,
+                    0x91eac9d15f3e2fb8,
+                    0x55b59d9b5feaf5ae,
+                    0x2540a6a7b76e168a,
+                    0x2e292672ff155a05,
+                    0xf39a0d8860b0ab00,
+                    0x483ab28941328103,
+                    0x3142fcdbc0bd4794,
+                    0xafbca6b409c96401,
+                    0x3c8c628983b1ff1b,
+                    0x009b8360c82fd1f2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x651f1c236b9ad786,
+                    0x7969b3e0ce8f4446,
+                    0x6abbfbf3bf1c2953,
+                    0x0a0266f2b562d0a0,
+                    0x7228b18673594341,
+                    0xefb42e7c3a994bdf,
+                    0xdf2a0deb7c12bd23,
+                    0x7686883359796553,
+                    0x85d6a1e43a5a1344,
+                    0x2ab050ad6ee3e25a,
+                    0x8a0bf27d21cc4d03,
+                    0x00738f5c5e0c52da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x500366df70bb2c08,
+                    0xfb7b48cdc9df2b9d,
+                    0x49b2adf2006a9f33,
+                    0xb0fa5e0f55b7115e,
+                    0xf9528f12d797b7ca,
+                    0xdd0e72f4f3d9efde,
+                    0x2bcde0fe70b00a1d,
+                    0x440346bc7884f96f,
+                    0x72eddc176d576932,
+                    0xd6533cd333df728d,
+                    0x8e5d0e6869649f03,
+                    0x00aebf054ac3fa54,
+                ])),
+                Felt::new(BigInteger768([
+                    0xacd76aeab86355db,
+                    0xbd4a7cc5e436926a,
+                    0xcd6038ffd801965d,
+                    0xcb7809f87218d271,
+                    0x3847b7cc03d48412,
+                    0x762375d05b31182b,
+                    0x12b6001d6af5668e,
+                    0xd14dcbf82497442a,
+                    0xbd895229045e4b98,
+                    0x5e3f93b4c112746e,
+                    0xd4fe8203e61efe7e,
+                    0x00dc146d23c14f19,
+                ])),
+                Felt::new(BigInteger768([
+                    0x075911da2163a537,
+                    0x35d330726c8af435,
+                    0x1684c2e79effd4ee,
+                    0x19e13d3e6ee1da10,
+                    0x365e90f18e423702,
+                    0xb3b8b1d2d973f7e1,
+                    0x734490d0e4be1fd7,
+                    0xa6cb9334061801a7,
+                    0xbaae209437bdde31,
+                    0x6207cd71b59609d3,
+                    0x21fd747d9d567569,
+                    0x00f5c6848e8a4d62,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf1826f07e43800d7,
+                    0x38091d415135bb2d,
+                    0x854e06fcca7dc76a,
+                    0xb11bdd8ab64842ba,
+                    0xfb2d7d25b560d95f,
+                    0x08c47dce88015829,
+                    0x85ce8496e2091b1d,
+                    0xa77ee25396da65e5,
+                    0xd6056f83706cff37,
+                    0x19f908ae832768b8,
+                    0x454b00be708df3e5,
+                    0x00e3ff5f06dfdea7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2cd58fe9545b858e,
+                    0x67ffa5590be39818,
+                    0xab5e79af45e6ac7c,
+                    0xfd506a4e3433042a,
+                    0xf54a9b1731b2b3dd,
+                    0x89ab889b61e8c0e3,
+                    0xf7b0f5cb30293a88,
+                    0x7b080d07da9c768c,
+                    0xa1576841c8191294,
+                    0x22aa7c04fba2b2cd,
+                    0x7e953bcfed04d0b6,
+                    0x0112bdcfbcaef5f6,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xc561abcc63b5aa5e,
+                    0x27aa64f8dd7ab167,
+                    0x09740f0231af64e8,
+                    0x5eb585de1949de3b,
+                    0x189703a1ae9b6806,
+                    0xe2d33090a7d9e54f,
+                    0x3cd8aeca997fe437,
+                    0x8c6108f2bb40cbbc,
+                    0x0bb6d0c1e4c10251,
+                    0x6889773d98490d10,
+                    0x80449fd88079adf3,
+                    0x006464dc243ef916,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18061caf9b3fa890,
+                    0x94fb491f81771a34,
+                    0x47d1ed0a68fed73d,
+                    0x662236beec8322ae,
+                    0xb4ba027c39e7c8ee,
+                    0x91b5b8ae5dfa0dcc,
+                    0xafc30f43ccd2bf57,
+                    0x6d7d6699f1cfc461,
+                    0xc1f8ac2f580edb82,
+                    0x689a10cb553915e1,
+                    0x5985d45bfe58552e,
+                    0x000a076de8484de1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x230fc36200a554e0,
+                    0xf0d66beaa5796e03,
+                    0xa1a52164f499fd0b,
+                    0x45f063a45a8a4750,
+                    0xad49609f949b5339,
+                    0xc6c3cd7d4517b7a8,
+                    0xcbfda7f6649c2ceb,
+                    0x6693e69b881e9f91,
+                    0x5367a5fd39de91a0,
+                    0x64e0b6a48652a40b,
+                    0xddf689bb5a93a773,
+                    0x011c669d47a7e853,
+                ])),
+                Felt::new(BigInteger768([
+                    0xca631b1745192da1,
+                    0x4b4b5cb4479f3279,
+                    0x87bdc9c650135ea7,
+                    0xebd30e92922f7e33,
+                    0x5817a2e583f3069a,
+                    0xa07170690b3d1351,
+                    0x940d277fc2888977,
+                    0x7bac1b4ba084b480,
+                    0xe595e95b0d57cc24,
+                    0x0194fb0c1796410e,
+                    0xc398b178b96f13d7,
+                    0x00873010d040089d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa21ac228c3d6afec,
+                    0x652b446b9ca61e65,
+                    0xd9d9bfdf1094e79e,
+                    0x68bb655e2ab81238,
+                    0x3141b7052d30803d,
+                    0xfc6984169e36460b,
+                    0x0fa33f2529e5d4af,
+                    0x7349805ed54ad586,
+                    0xf852735f70137f6d,
+                    0xf9ed96cd77c37671,
+                    0x886a138c2eb3efe3,
+                    0x00ac4c66a8c6d58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32019c99ad3fb1a4,
+                    0x4ae6138e11640a1c,
+                    0x91be3c2d6312dd98,
+                    0x205e472a7277d4b1,
+                    0x13ef1c5608873f64,
+                    0x5a38f5f56852e78d,
+                    0x2155c0c7f1ef6f1a,
+                    0xaf8491d933d19b02,
+                    0x5b32533bb567a826,
+                    0xfe0fed90658d825c,
+                    0x79a8c7a2b9c9c548,
+                    0x007f6fe6ad7184d7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x20331b452ad3ff0b,
+                    0x0bf14c5db006848f,
+                    0x4f0fa110ad44d9dc,
+                    0xbf86481c8335dfc5,
+                    0xe02c35a5c99124d0,
+                    0x36605756dd201a9d,
+                    0xfc88fb888d667d11,
+                    0xc87ba8cb424742d4,
+                    0x02f2961d2443c37e,
+                    0xdd55366d001c66b0,
+                    0x09578a2499a7a79d,
+                    0x001f327e6bab4876,
+                ])),
+                Felt::new(BigInteger768([
+                    0x37e8297082fc0d7c,
+                    0xcadca81fdfbeb7eb,
+                    0x7bfeaa26316b9b19,
+                    0x37e304b483254330,
+                    0xb16e5aea012558ff,
+                    0x6ad60b8ad721d1d9,
+                    0xa9bdf72c964e8833,
+                    0x16ca7d6ac0a2e169,
+                    0x52b26c5656d08fa9,
+                    0x0d3039c6be227ca3,
+                    0x2e2d00762c11cbb3,
+                    0x00d0178972cb79ab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x936876b51c50e8c1,
+                    0xeab2ecc792dc03fa,
+                    0xca938faac6f8bf67,
+                    0x3db23041b1bb3f30,
+                    0x1845cbb79ff79783,
+                    0x597d6f635e5cc406,
+                    0x6a594e3bcdbcbe3f,
+                    0x073964808974aabc,
+                    0x0123740c61c91ab2,
+                    0x48e3f127c4b23be7,
+                    0x0434323e634df8ed,
+                    0x00aef2bf56b68adb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18de6f208849ded1,
+                    0x46d902d6743c3d61,
+                    0x489f40c9f6b5e7f9,
+                    0x774be7646ba700b6,
+                    0x966f7c12bac9a3aa,
+                    0xe29893c2738a5749,
+                    0xb9fd5379daad1c34,
+                    0xec831d86ee7eea75,
+                    0x5dd01b44f8b24486,
+                    0x188df911e3f1df6e,
+                    0x31875742f9bbc304,
+                    0x01186473baff76ea,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd7f7f4b86158b4f9,
+                    0x3d793a5ebd80e20e,
+                    0xd89a282020056de8,
+                    0xbb6ab6f47f4bc8d8,
+                    0xaaf4f38c76084ac9,
+                    0xe93363134a04ec9f,
+                    0xf0bf94679ce918b2,
+                    0x3316866b4621d92e,
+                    0x64ef1ee341f20d60,
+                    0x98705a882c8e0394,
+                    0xda1db9d1c4d99318,
+                    0x004cf082c39282a9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7d8310526d50c6d4,
+                    0x1c07d7c614c9d83b,
+                    0x290432c493184312,
+                    0xef30f724291fab81,
+                    0x7a34b3332015455a,
+                    0xbbf0f094c2a49b60,
+                    0xfe96a9f2fb91dfee,
+                    0x8d50f3b84050e46a,
+                    0xdb64b03743ec3f07,
+                    0x67389d42318cd5aa,
+                    0x88d6ca732275a64b,
+                    0x00a04f0138e89b16,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xcbbe3bc2fd853f62,
+                    0x4b196d21a3d7fc56,
+                    0x7276065169931cd6,
+                    0x909d87dd92914760,
+                    0x3f06d27d390fe9a1,
+                    0xae5457d41a9a25fe,
+                    0x5dfd7689514339b4,
+                    0x9e9d66350a2c5a76,
+                    0x8d4c589abf003415,
+                    0xbf85d69b14594337,
+                    0x1aa0e50e4dc1ee65,
+                    0x00932b65c0104c23,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa0275606cadd5c68,
+                    0xbbf6beaeae4f0c0f,
+                    0xfb3b88694d16c0d8,
+                    0x15bda2277265f2ec,
+                    0xeda25d5eed0c48c3,
+                    0xdbede31b7fd14453,
+                    0xb86e7059dea13104,
+                    0xbcc2fead9bd2d5fc,
+                    0xd4ff450fbaf26685,
+                    0x8d3ba80e469f9311,
+                    0xb2a72e794c2e4731,
+                    0x00a9d50b0dd8edf9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9b13448f8a7e7082,
+                    0x078dffc7dc856989,
+                    0xbdf55269d2bba44e,
+                    0x4ebff1c7ca1d1045,
+                    0x06a37c7a0bf62343,
+                    0x1e736fdb271e5d26,
+                    0x5ed5c92f57c0ade3,
+                    0x236fbfbba7a58074,
+                    0x616cac4d58172fd7,
+                    0xfd4dcc4e7a9f7f70,
+                    0x2385f14eebb406cd,
+                    0x00bbbae55dfa07de,
+                ])),
+                Felt::new(BigInteger768([
+                    0x31f44c4749effc94,
+                    0xf144d2289cd741c6,
+                    0x48453bb8ae0f419c,
+                    0xb2c95c78828f8fb4,
+                    0x3b0b13e82f9facfa,
+                    0xf36d5acad8cf8fc0,
+                    0xb25ccef84f4429fe,
+                    0x888128f1c25c7a06,
+                    0xcc8fe1ee7da381e5,
+                    0xd34bf9b1d283f018,
+                    0xc7012efbf497ee84,
+                    0x010af693b7d8ec1d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1db417784efb5afc,
+                    0x21b9345aee17237e,
+                    0x816a261393732e1b,
+                    0x7beda8bcd1644457,
+                    0x9300e5c72ebde221,
+                    0x5ee8fe98b9c8501f,
+                    0x9cbdd50bf64f21d6,
+                    0x94167864d87f991b,
+                    0x786869f97d3b2f36,
+                    0xdd2fe934ff41104b,
+                    0x7d9c541e2c0c7ab7,
+                    0x0022898d0daf491f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd84fee79fa0faa0e,
+                    0xa7e2b9b0aade40a3,
+                    0x2ba4f16a3e3d693b,
+                    0xc6b7b6ce2704cf84,
+                    0xe14172fe948cfb4b,
+                    0xf02006c5f2b350a1,
+                    0x37c230e54730e65f,
+                    0xf455afde85b495a8,
+                    0xfb6732ec99ce8327,
+                    0x3e8efc87b0402aa2,
+                    0xeb8559c3e95c7d7d,
+                    0x009efa6642885a20,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc50a2cdee9783787,
+                    0xd7b9db107cce90f2,
+                    0x2a1c06848ae79579,
+                    0xb3dc3455fcb2b372,
+                    0x85737debda8b28e0,
+                    0xe55aedea7615d5d0,
+                    0xdddff4da65bd733f,
+                    0x2d1430eb6401de32,
+                    0x1440a47595adc465,
+                    0x494e97365bbdb419,
+                    0x8f39e5f82eacc11e,
+                    0x00ae1224e27bd22d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5136354dd22cead,
+                    0xa8f87e4757a93cc0,
+                    0x1938064bf88d6bb6,
+                    0x6c7223552d2db469,
+                    0xccde9643d7c0b09f,
+                    0x4c4531587b219b92,
+                    0xb8785df3b3123d0e,
+                    0xd4690140db7217a0,
+                    0xc9620dbca9b18bf9,
+                    0x6417bbaeeae421d1,
+                    0x3f815da4fc9942b3,
+                    0x0014653745f7c8a1,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xc561abcc63b5aa5e,
+                    0x27aa64f8dd7ab167,
+                    0x09740f0231af64e8,
+                    0x5eb585de1949de3b,
+                    0x189703a1ae9b6806,
+                    0xe2d33090a7d9e54f,
+                    0x3cd8aeca997fe437,
+                    0x8c6108f2bb40cbbc,
+                    0x0bb6d0c1e4c10251,
+                    0x6889773d98490d10,
+                    0x80449fd88079adf3,
+                    0x006464dc243ef916,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18061caf9b3fa890,
+                    0x94fb491f81771a34,
+                    0x47d1ed0a68fed73d,
+                    0x662236beec8322ae,
+                    0xb4ba027c39e7c8ee,
+                    0x91b5b8ae5dfa0dcc,
+                    0xafc30f43ccd2bf57,
+                    0x6d7d6699f1cfc461,
+                    0xc1f8ac2f580edb82,
+                    0x689a10cb553915e1,
+                    0x5985d45bfe58552e,
+                    0x000a076de8484de1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x230fc36200a554e0,
+                    0xf0d66beaa5796e03,
+                    0xa1a52164f499fd0b,
+                    0x45f063a45a8a4750,
+                    0xad49609f949b5339,
+                    0xc6c3cd7d4517b7a8,
+                    0xcbfda7f6649c2ceb,
+                    0x6693e69b881e9f91,
+                    0x5367a5fd39de91a0,
+                    0x64e0b6a48652a40b,
+                    0xddf689bb5a93a773,
+                    0x011c669d47a7e853,
+                ])),
+                Felt::new(BigInteger768([
+                    0xca631b1745192da1,
+                    0x4b4b5cb4479f3279,
+                    0x87bdc9c650135ea7,
+                    0xebd30e92922f7e33,
+                    0x5817a2e583f3069a,
+                    0xa07170690b3d1351,
+                    0x940d277fc2888977,
+                    0x7bac1b4ba084b480,
+                    0xe595e95b0d57cc24,
+                    0x0194fb0c1796410e,
+                    0xc398b178b96f13d7,
+                    0x00873010d040089d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa21ac228c3d6afec,
+                    0x652b446b9ca61e65,
+                    0xd9d9bfdf1094e79e,
+                    0x68bb655e2ab81238,
+                    0x3141b7052d30803d,
+                    0xfc6984169e36460b,
+                    0x0fa33f2529e5d4af,
+                    0x7349805ed54ad586,
+                    0xf852735f70137f6d,
+                    0xf9ed96cd77c37671,
+                    0x886a138c2eb3efe3,
+                    0x00ac4c66a8c6d58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32019c99ad3fb1a4,
+                    0x4ae6138e11640a1c,
+                    0x91be3c2d6312dd98,
+                    0x205e472a7277d4b1,
+                    0x13ef1c5608873f64,
+                    0x5a38f5f56852e78d,
+                    0x2155c0c7f1ef6f1a,
+                    0xaf8491d933d19b02,
+                    0x5b32533bb567a826,
+                    0xfe0fed90658d825c,
+                    0x79a8c7a2b9c9c548,
+                    0x007f6fe6ad7184d7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x20331b452ad3ff0b,
+                    0x0bf14c5db006848f,
+                    0x4f0fa110ad44d9dc,
+                    0xbf86481c8335dfc5,
+                    0xe02c35a5c99124d0,
+                    0x36605756dd201a9d,
+                    0xfc88fb888d667d11,
+                    0xc87ba8cb424742d4,
+                    0x02f2961d2443c37e,
+                    0xdd55366d001c66b0,
+                    0x09578a2499a7a79d,
+                    0x001f327e6bab4876,
+                ])),
+                Felt::new(BigInteger768([
+                    0x37e8297082fc0d7c,
+                    0xcadca81fdfbeb7eb,
+                    0x7bfeaa26316b9b19,
+                    0x37e304b483254330,
+                    0xb16e5aea012558ff,
+                    0x6ad60b8ad721d1d9,
+                    0xa9bdf72c964e8833,
+                    0x16ca7d6ac0a2e169,
+                    0x52b26c5656d08fa9,
+                    0x0d3039c6be227ca3,
+                    0x2e2d00762c11cbb3,
+                    0x00d0178972cb79ab,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 570];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+            bytes[380..475].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..95]);
+            bytes[475..570].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..95]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xe2a891e6fc6e1cd0,
+                    0x91fa1c9f83c31c48,
+                    0x7039e31c3f70792b,
+                    0xf2aa137bb90fc6b7,
+                    0xd3b3b729bdbfb4a6,
+                    0x2fd046aaa2b38121,
+                    0x9b18c61a7143a5d7,
+                    0x8054cd049656fa7c,
+                    0x540972da46baf676,
+                    0x821d822a85f6b757,
+                    0xeb661a7590112565,
+                    0x0084e9916a3141ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaf11f50251ae3e6c,
+                    0xa8403f629ffa6490,
+                    0x60cf6a8cbf522cbb,
+                    0x424cd03af9a7c4f0,
+                    0x92ff3d8ab2538e52,
+                    0xf28efb791f3bda10,
+                    0x4f6a25c81738d92d,
+                    0xbb9fc67041b62b6d,
+                    0xd764d9bb8b4229c3,
+                    0x6dd6302a5a986e51,
+                    0xf55bc6e8c3d26b7e,
+                    0x01218a72a19011a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x31c2f187b8170ac9,
+                    0xf5e74efac6916472,
+                    0xd5e5e08bf880f09e,
+                    0x0da87c3b2b76c87b,
+                    0xc00ec34f1b6e28ee,
+                    0xb258380f684ba357,
+                    0xf040a507e6ba0281,
+                    0x036079f485240715,
+                    0x43364c1895590f04,
+                    0x36c91c8c692d43fd,
+                    0x9bfb28b8e6e05ae8,
+                    0x0053407e3e92324b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x855af2e93e44f6fa,
+                    0xd44efb38dd363d56,
+                    0xb3cc337bb48de481,
+                    0x5da0e5b6a60be2a9,
+                    0xd2d528670a5ba30a,
+                    0xb507934d9ebd85b8,
+                    0x15dd509204fcff0e,
+                    0x117a76f741ea6070,
+                    0x727eb470c1b308af,
+                    0x8757b4f0b6367ffc,
+                    0x73beb7a6920a5c93,
+                    0x004ee51a7d6ee985,
+                ])),
+                Felt::new(BigInteger768([
+                    0x09346a3b3dd81f6f,
+                    0xdce24f6f8404fb39,
+                    0x61d2e71cd6daa873,
+                    0x1e9b53b36158f4ef,
+                    0xf7424848bded213f,
+                    0x74c5aa99afbe549a,
+                    0xc47180cdcde22bcb,
+                    0xbe8f217786474b48,
+                    0x83128162cf915017,
+                    0xfc914954cf3821d8,
+                    0x7c5ed72f7da8d7bc,
+                    0x00e0ad4c47a7536d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e568a60aa7335e6,
+                    0x7403c484deaf0cc0,
+                    0xa428ab82e35499da,
+                    0xc76254181ad29fd2,
+                    0xe22b3e5f099bec31,
+                    0xd038ae335c49c2ec,
+                    0xaec4f81039082b22,
+                    0x55b676b48b6acfd0,
+                    0xe62519188393218e,
+                    0x865f366dbb5d1a2b,
+                    0xb19aad85c5a3a969,
+                    0x006e5b24c96f062e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb09a33f6a22e8534,
+                    0x7421331c4d815b2e,
+                    0x9b38031c4e1d9efb,
+                    0xc57c08f473c39abb,
+                    0x2d8cc533a16ad8cd,
+                    0xb1dceea8ad404ac4,
+                    0xa8f286be4745e045,
+                    0x66b6bb277a271726,
+                    0x437249f59993db5a,
+                    0x27077cae2a0e3d9d,
+                    0xad694f015f6c3b91,
+                    0x00272d473ec09a39,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4cad60993eab169,
+                    0x8718affd759bb3e9,
+                    0xf21990c059da5006,
+                    0x5167a9ce3c84b516,
+                    0x31fedeb438ef52df,
+                    0x263a1b64fdd15baa,
+                    0xe470b71c08916875,
+                    0x898269624b9cb248,
+                    0xa62ff71a4550bfd4,
+                    0xf65706c7a49039e2,
+                    0x604bbb275474ed51,
+                    0x0066d8562461f6ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd4b99143da3d59d6,
+                    0x2bba8dce4c81139a,
+                    0x528ba00ae6ca3f44,
+                    0x0673b542161c7fe2,
+                    0x77d3f3032e429446,
+                    0x61b48377e76100ff,
+                    0x2415cc4ca31c1db8,
+                    0x90169a3a67827bc1,
+                    0xed0564b62cce0825,
+                    0xc54948f3dd9a9713,
+                    0xcf3a74c595b9a485,
+                    0x00f71e809c94b627,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a9be4be5dfd379f,
+                    0x99777a05d3808883,
+                    0xc02646c41be92916,
+                    0x9b6e2a7e5a7edb6c,
+                    0xb84a59eb961ff991,
+                    0xa8b8a3e753c4f05d,
+                    0x693cfbde715c0e33,
+                    0x5d838efc1353f6e1,
+                    0x506400fa374a44c0,
+                    0x01748fc9e2b925b6,
+                    0x2891f6fa6a60ae98,
+                    0x00cef0f04b231d15,
+                ])),
+                Felt::new(BigInteger768([
+                    0x655f1aa83cc94859,
+                    0x68022c8b9287f647,
+                    0x9b61cae1093b145e,
+                    0xbfe0bfffb3b7a015,
+                    0x9387290051a2d41b,
+                    0xd047b91c51500a57,
+                    0x60bc20eb746b8ca4,
+                    0x92d1c76c2b6ad4ef,
+                    0x8cf216e1e58a3136,
+                    0x7c5bcb714816c8d4,
+                    0x249a768fb2491882,
+                    0x0030f6ad5fa12702,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6184341133c94fd9,
+                    0xd12e3f4bbf415ed5,
+                    0x738fe7294fbcddbf,
+                    0xa6d6a7db5b0ab21c,
+                    0x5e097264770d1d39,
+                    0xa0764899e3aba5d1,
+                    0xbe362e2b010fd06c,
+                    0x9ea4a51d56c16adb,
+                    0xef599bfd4738125d,
+                    0x7da9f71fb529949b,
+                    0xf6b50e347d23d610,
+                    0x00b0c0a88120fb96,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x82e379ecf493df6d,
+                    0xc7e984a17c94d62f,
+                    0xc0058f8042dd9d16,
+                    0x38d10fb44416a4c7,
+                    0xa8ba401f2cd4b21b,
+                    0x7993d0450ae52dbe,
+                    0x0b23c38ee98b5768,
+                    0x1470230d4f348d30,
+                    0x73d105e8e67beeb2,
+                    0xf957be0b0bf5265a,
+                    0xdbcc7e43fe443374,
+                    0x00fe977406379105,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ec94fb211628a64,
+                    0x52f5a1e011a3e220,
+                    0x241f4522d18dad0f,
+                    0xde38418f580a881d,
+                    0x661ddf442d7bf4ff,
+                    0x5dab8bfbd9e6a1b5,
+                    0x85352fbbd4bc6bc2,
+                    0xff546efaad55aa7f,
+                    0xf2db99f994321261,
+                    0xe8df6cc052b92c2a,
+                    0x4825b5c0e24cbf3e,
+                    0x00902075c663a248,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f9eb898aed27449,
+                    0x835ab7eef2ed8fe7,
+                    0x77b7a503e6718d14,
+                    0x8f8103213efca353,
+                    0x11b33fbc3bb9ea83,
+                    0x5095395c0229380c,
+                    0xb064b250e259b83b,
+                    0xd7b40012522775e4,
+                    0xd26ac957826419c4,
+                    0xfb5540539d7242b2,
+                    0x2fa6988bc42cf620,
+                    0x009c32d3afe679b6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa13b6e871b695af5,
+                    0xfafcc3060117fd80,
+                    0x0736003ce8cf95a7,
+                    0xe01b2fd50d8f7dfc,
+                    0x15d62e1a3cb0e0dc,
+                    0xa20714bfa0408baa,
+                    0x3693c9100d8e04c1,
+                    0xe96f429f111791a8,
+                    0xd291b54478b89e4f,
+                    0xe8112781a6650f8f,
+                    0x2d60b2820dffb1aa,
+                    0x00b719dc4233d77b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ee4fc3c9ea7ccd2,
+                    0x54aaef5a74fadb03,
+                    0x299cd2cdabad09a5,
+                    0x21ae381d7a49cfc7,
+                    0xe5798fe754fd94ba,
+                    0x15fdeb37442b7c2d,
+                    0x774f4e3d6d1ecb53,
+                    0x93832c363278df61,
+                    0x375f764dc7c1bdf0,
+                    0x264cf3a55c0451e1,
+                    0x784c676f7844ae74,
+                    0x00897122834b4f51,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5107d1e47fe33aca,
+                    0xd731db25fb597088,
+                    0xc0a345f2bdff8a7b,
+                    0x825658a58b20ba72,
+                    0xd05038f5bdf87931,
+                    0xee05f109c7b3546d,
+                    0xc8ec3055c79747cc,
+                    0x4d64ff030b757fad,
+                    0x23bd6cc7afbe7413,
+                    0xb20f17dc728f99b5,
+                    0x690fd4ef51cda00e,
+                    0x00508e0183af6bc0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x4e354426a7212a4f,
+                    0x88d04de05008bd91,
+                    0xa0267e7ab057b968,
+                    0x5fdbbbdb90440af8,
+                    0x80f87999b1a03367,
+                    0x822556addeb1d847,
+                    0x2ff04c8dcb27a53c,
+                    0x05c2795508b46b54,
+                    0x358724d92ab2038e,
+                    0xdd1834aa2d10b2d7,
+                    0x292a0ada7c08313e,
+                    0x00933032d6bbfd16,
+                ])),
+                Felt::new(BigInteger768([
+                    0x952ab415e407b4cd,
+                    0x63a709fe5eb839e4,
+                    0x0324a9cee7eb2699,
+                    0x37942b88f6c80cfe,
+                    0x7b602bf38850829c,
+                    0xcd7b66f3482c27e4,
+                    0x0ff7ddd5f81d5936,
+                    0xa27ecb4d36737f9d,
+                    0x0786c7cb9243f7ab,
+                    0x94aec50d45b14ece,
+                    0x8afd9df5aea04aad,
+                    0x0063f5a7279c4360,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1805c56c9c95afe0,
+                    0xe77e5640fcab0827,
+                    0xabe3b4c1a6fdadab,
+                    0xc92c5f028ab08e42,
+                    0x84732a72592b0c09,
+                    0x7928402f6b37614f,
+                    0x17d943db9fb34dad,
+                    0xce7a23c6a44eeae1,
+                    0x82bbb273c5957417,
+                    0x8facc4751acf0c1c,
+                    0xd9f1cf3ed6a0ccb3,
+                    0x00b2515043862374,
+                ])),
+                Felt::new(BigInteger768([
+                    0x050ae9abdbd98503,
+                    0x1727e64dee6c4a7b,
+                    0xc029ad18fd5dc9a4,
+                    0x08d644d39aee67a4,
+                    0x71f3f2e823c585fa,
+                    0xba4a5f2c340b4727,
+                    0x40f45b1dbe318da3,
+                    0x4930fae272f3d204,
+                    0x7dc4795911dabb1c,
+                    0x03f33b46bb5898c0,
+                    0x9fd291a8a45db4a2,
+                    0x00bd7aa1c99a91f9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc548e8cdb96d04dc,
+                    0x253ea53cae5f2a38,
+                    0xe2fe0787524bc91e,
+                    0x5bd43dc7a02a0491,
+                    0xf5cfcd0ddd3c49b5,
+                    0x12644d8408199605,
+                    0xbc0b71395c4bfc25,
+                    0xdd659ab5a89f5697,
+                    0xdae0e7e16d673980,
+                    0x4b75565f06592501,
+                    0x0521fb3249ddbdcc,
+                    0x0064916949394215,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd168e9ff60fc3c3,
+                    0x739a478d69ea366e,
+                    0x397b2f294d57341c,
+                    0x916ad7847bb00c8f,
+                    0x05132a6a48a6524d,
+                    0xcf792c1dd8261c2d,
+                    0x30df11f91d655c89,
+                    0x5fee438677c70fd0,
+                    0x81b6fc6a75fe6f11,
+                    0x1c9279da3acfd2e2,
+                    0xfd65daa73cbace9a,
+                    0x007251e4beec84be,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x588755f9f94c1504,
+                    0xe3931a029a8ca308,
+                    0x39aec41fe08c29be,
+                    0x9d92028a00e70309,
+                    0xbfc482918b331c19,
+                    0xe58902a4efa478a6,
+                    0x64cb4d598deff9ec,
+                    0x66ad0471863d66be,
+                    0xbcf52dfd51716d77,
+                    0xf02b3045e197326f,
+                    0xa61eb78e36e21e47,
+                    0x00059853b619c5ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0b472cfbd3cb1ed6,
+                    0x161d7fbba174376f,
+                    0x868fa58f5239526c,
+                    0x96a4acf4a00f8658,
+                    0x75c70435a3702a66,
+                    0xcb15c1d5f936b80c,
+                    0x6184f3bb5bd549b0,
+                    0x21dc5ad8c9411627,
+                    0xa3b10fb648fa5ff2,
+                    0x945f8b0a92bb017c,
+                    0x28ba967561eb1ab2,
+                    0x00daf4848a219335,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50be112ccd4dcf4f,
+                    0xcdc62ecd1cc0523b,
+                    0x5374b2f711532540,
+                    0xdec694a5ade3f86a,
+                    0xa97f86b896489ab7,
+                    0x7c4992ed027f7716,
+                    0xfaecbfe9eb24ed1a,
+                    0xefa2b669c439b111,
+                    0x1faf4a7f730b0c82,
+                    0x777df7c5f68c9b35,
+                    0xa52696cfffa5d881,
+                    0x00961497d5e34295,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9a0a6cdcd682365e,
+                    0xe97cfc320be440f0,
+                    0x6a5253b67e1458fa,
+                    0xdd2746f95ac8f7d3,
+                    0xebaa639ef944d85d,
+                    0x9f1e55c0b75a9d21,
+                    0x967c3d1865ea3967,
+                    0x7e83db05fc4a5694,
+                    0x7b1ac06139fcc760,
+                    0x93156f6a6de352fb,
+                    0x77b54998434af68d,
+                    0x0040e0724d6f3443,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee3581b03ea18c9f,
+                    0x2cfe9e545f16c8fe,
+                    0x4c2dfd13ba4df2a4,
+                    0xd378d75f6ae9c1c9,
+                    0x7b254fe04ab81305,
+                    0x5c57d47849206a06,
+                    0x30e1d273e6f0e0ab,
+                    0xd6b6270cafbc5323,
+                    0xe9a660b904675ff0,
+                    0x0c5dced0624d709f,
+                    0x0f07e6bbbe502b65,
+                    0x00ae5d66352956cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb6b70619392a91bf,
+                    0xbd499dd1944f8168,
+                    0x9f9697b09edf1213,
+                    0xe61029e5eeb7774d,
+                    0x3ad4ff16c420c546,
+                    0x0323d267e23a8626,
+                    0x7a3a5cb34da3685f,
+                    0xe5b39d7c9d0bfd7d,
+                    0x843e5c7435365eea,
+                    0x3bda593da7ca09fa,
+                    0x9226096380319b4e,
+                    0x007e9958dde6a6af,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x530871890ab6abea,
+                    0x7accb5da3e563255,
+                    0xeb2fd66057203c60,
+                    0x1afedb83d5706d80,
+                    0x6439f9749fdf373e,
+                    0x7d19c194e16dd855,
+                    0x7453e55584a2ea30,
+                    0xd474aa5153433e8d,
+                    0x0aa4d60fbe40cf73,
+                    0x33ccb6a31cfd799a,
+                    0x3d68ed2507b8a335,
+                    0x0087dae674f3c65f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f43f9e6c8780634,
+                    0x4eb116956a53a4bf,
+                    0x00bd51c17c78cbd9,
+                    0xe7cf7e466a9b4a54,
+                    0xcc3f8cc0df4f8fa1,
+                    0x657e7b6598d688ab,
+                    0xaa1c4c287afdba2d,
+                    0x1eecf02a7c108ebc,
+                    0xb2e926246bc4184f,
+                    0xd63794e58787257d,
+                    0xeac84c22cdead4ca,
+                    0x001dc07ce3af9899,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf9ece768c81e88b9,
+                    0x121471a904c71b9a,
+                    0x39b11ff644165821,
+                    0x0620f8264a74f8c7,
+                    0x78443c1f544fdd3d,
+                    0x03fdb3f4fcd33d60,
+                    0x720ec4eef1b490c9,
+                    0x680c9531244d775a,
+                    0x7f7ff40f1c6a4567,
+                    0x559de0fd1085cc07,
+                    0xc2ccade8b0b11e43,
+                    0x00033e701c0857a4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3955fc982df19c9a,
+                    0xc0ac8ecbb6b14400,
+                    0x87bbc57db2e5f317,
+                    0x63158f03b2a41ce4,
+                    0x65aa0c6491b80516,
+                    0xaf733ac5d3100a41,
+                    0xb7ad17858f90e91b,
+                    0xfad2377ae8734ee9,
+                    0xe371430609f315db,
+                    0xdcc33b5072e251e9,
+                    0xd068aa06081bad14,
+                    0x00584708914a8cdf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3502644e96878092,
+                    0x9f89408bac9fb793,
+                    0x16c409d149562fcf,
+                    0x810e7df0f2da54af,
+                    0x96f3c25a7dad7d38,
+                    0x2dbc0f4dbe2528e0,
+                    0xa5a48e528d50758e,
+                    0x590f7bbf85b04359,
+                    0xec1ae36ae14a312f,
+                    0x4e42bace9a631289,
+                    0xa68a9abb24bc3b4a,
+                    0x00f6e9e504c43bbc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x740b862d478318ac,
+                    0xc1a607c7bfa77d76,
+                    0xb5ec7428b8e3a0be,
+                    0x3987eee6d8ba003d,
+                    0xf8fa7e960c12b090,
+                    0x3dfed2f12ffa4f58,
+                    0xcac7ec99896d22c2,
+                    0x772f80aa462b9a86,
+                    0x1e47613373dd3711,
+                    0x467ecfca1298f0e3,
+                    0xbb1491ae898a517b,
+                    0x0119993b9991a1e7,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xa2bce57883707c4e,
+                    0x1b34bb3e97ad9e89,
+                    0xb1558b9d254064f6,
+                    0x324ced4d3755cf31,
+                    0x91b25e03989e9122,
+                    0x6996fca8ed1a408a,
+                    0x67c7e7a45e2abebf,
+                    0xdaf0dda649bd66ee,
+                    0x0f6a8463ff878766,
+                    0x490d2cb724984c56,
+                    0xf394efb89b65d782,
+                    0x00ef6a9b8fc93e8f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x85bd88bd5bebf276,
+                    0x61d8d9732ac6c3a8,
+                    0x2cab3c254c4fc109,
+                    0x22bc62237615e18b,
+                    0xfca67847c3d9da6a,
+                    0x6a2e7f3fa4e36a19,
+                    0x566c8890acbc2571,
+                    0x02c9f3c38ecfc87c,
+                    0x3cd7bb5af257f5bc,
+                    0x88a2f36dad48d7e5,
+                    0xeb0530f6d5e08754,
+                    0x00d0f770d697e247,
+                ])),
+                Felt::new(BigInteger768([
+                    0x10480610a1be10a4,
+                    0x6a972509717d6f1e,
+                    0x130d1692ecd2a6ca,
+                    0xdbe3f93313835307,
+                    0x72c13bb7161ae868,
+                    0x3e22963fc6301fff,
+                    0x71fd19c6dfef0681,
+                    0x878a0500313ee960,
+                    0xd677c14b73dbcde2,
+                    0x7ffc5c6088b22336,
+                    0x91eed33c840eaa8d,
+                    0x00f5d42c92b588ca,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc840ad6b344632f1,
+                    0xbeb3f2b7014e1645,
+                    0x421b988b78525f94,
+                    0x48e69d605b2c8ebe,
+                    0x66839a74eb8d7c0d,
+                    0xceedbd77be5d888d,
+                    0x0596ea5ea92aa6e2,
+                    0xce927d9d504110a0,
+                    0xb470a4de40e7f373,
+                    0xc0fed96753afb58f,
+                    0xa5315ebb4b6dd14d,
+                    0x00beabcd4335bb0c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcea73e82d5d912f8,
+                    0xf95ab14dfc55b1de,
+                    0x433e680de65e063d,
+                    0xe39c7b9c127d5041,
+                    0x7ceaa03609b8ba48,
+                    0x4cde4e2d0395972d,
+                    0x73c57b37ad3c296b,
+                    0x41209a2046c6a100,
+                    0x3d38dd49c2eac03a,
+                    0x6e846cd2a7f94751,
+                    0xd586ef91f79fe2ee,
+                    0x0059f0530d56cec8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x157f569f0302eba5,
+                    0x49747cf6beca254e,
+                    0x0d9fb987bf502318,
+                    0xc889869a06aa545a,
+                    0x3c220a147614131f,
+                    0x33a37de1df9dd7f0,
+                    0xc01e5aa98364480b,
+                    0xfa0a3be0de9f05cc,
+                    0xa8e327bb3dd339e3,
+                    0xacca18cd70337f0c,
+                    0xbe9c42b8eca6046c,
+                    0x00c7d7bf569f7c17,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x96fa94f8c1984569,
+                    0x5cbc855b4905726d,
+                    0xe59580513bb95f98,
+                    0x6475619becf18a53,
+                    0x8a6403289e43f30c,
+                    0xcfd71260081c160b,
+                    0x01df4d8b97e41073,
+                    0x7f895898cabc0664,
+                    0xbfa428d3bf379c86,
+                    0xc2369c5763734af5,
+                    0x5a16885e09ccaaee,
+                    0x00794d84618edec7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x46148dc9dd72d828,
+                    0x68b982eb6e10ae18,
+                    0xf261120520a31afd,
+                    0xe160b1c8a6ab3dde,
+                    0xd1b9cc7b513a112c,
+                    0x468c964ab8f91dfc,
+                    0x8c1840a2c5518bcb,
+                    0x0ecd4079ffd03105,
+                    0x021ef8b62649e272,
+                    0x1da7b351347cdf20,
+                    0x155e99834f382a3e,
+                    0x00960fc57d200724,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9e94743d3571fab5,
+                    0xbc4e4a213265a290,
+                    0xfcc6bb98f77fdef8,
+                    0x582b788ac7b8f379,
+                    0x6bcd0814fa5a67cc,
+                    0x958c94ec086e94a7,
+                    0x297ced14078e2d0b,
+                    0xeb865e25a040cbe3,
+                    0xe46b6c3eba216df6,
+                    0x0cc3e6ef04e4887f,
+                    0xafec36a5168c6c0c,
+                    0x004192115ba20f41,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x074a62cfbd03a231,
+                    0xd0a0a10523b834db,
+                    0x23cc431aa48c1a80,
+                    0xb345d8591c312ada,
+                    0xe646b35bf1fedd89,
+                    0xb96e8c3e2a3f9d55,
+                    0x65f6685209742206,
+                    0xb9854ee705015937,
+                    0xba247de60b201e3f,
+                    0xf36a9656f06edbef,
+                    0x7f0dc7af6deaa2a0,
+                    0x00f01313cdc6e393,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c39e8192e5c6f1c,
+                    0x23405cccbcd3fbbe,
+                    0x22c1ba7acfa62d3f,
+                    0x57631226f4da1db0,
+                    0x6843209a0a6fb078,
+                    0x6d2a340eb934bff9,
+                    0x90c6e9abb12801d2,
+                    0x53eb058ab06f1436,
+                    0xf5338690f084a586,
+                    0x2ddaf8e411c86eb2,
+                    0xdd547160c97fbd28,
+                    0x00ce87c605740f28,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd135c41164e66f70,
+                    0x69122c587132ea86,
+                    0xc09ecf6a089db25f,
+                    0x59b1103a0b7ab62d,
+                    0xe762051bf659414a,
+                    0x8c554b44febdf9fd,
+                    0x349b950202b645ed,
+                    0x4e1765dd916e4a6b,
+                    0xcadebfaf691b38f1,
+                    0x9ff045d597e96463,
+                    0x9959523f607b4724,
+                    0x000f1b0a8593ff36,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5ac5a8255acd2a0d,
+                    0x33015786408f0844,
+                    0xfd22bfba3bd1556d,
+                    0xeb17ff41830c7bb0,
+                    0xaae5bceded463019,
+                    0x564aeb9c3ef6dad2,
+                    0xbcf16cd6a954d8e8,
+                    0xde473e1b37c7d3cd,
+                    0x8a3deb00b145455b,
+                    0x87c1ffa0bee5115a,
+                    0xfd0620bcd7d812c9,
+                    0x007068d336cff0ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4da8397c6dded62,
+                    0x87ebcaa5114c1e00,
+                    0x8aa2c25a4c649aa6,
+                    0x9aa8ef8b55ad6446,
+                    0xd11bf0b1bbd38d4c,
+                    0x072536a68eec8157,
+                    0x783b8c78fdbad3dd,
+                    0xa26f414bb3814024,
+                    0x44de1cad143069f2,
+                    0xfc054919db2f1bda,
+                    0x3841e34b210db6b8,
+                    0x0049b2d5fe0ae180,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7150073c8073e386,
+                    0x3bc6f012f9d8ada4,
+                    0x3ba1311fe6f1e08e,
+                    0x7a4e32d118d2c4dc,
+                    0xd154e49bf0c708b8,
+                    0x519c73f1dd511b20,
+                    0x15c1eeba70b00e4a,
+                    0xf0227ad1d77159dc,
+                    0x0f5bb8ca208d691d,
+                    0x26a51a3dc8422048,
+                    0x298dbb72680025d5,
+                    0x003f3a5a548c6007,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x575018ff5f52ef4a,
+                    0x486999f5276e67bf,
+                    0x947b02228df0ba42,
+                    0x0be28e244d4d04ff,
+                    0xfc23689ec49424f9,
+                    0x25e44c219f0fc2c9,
+                    0x50618dc458700708,
+                    0x6e78f0736061dc49,
+                    0xbc6eea7ca6650a24,
+                    0xc6246dc1ae9aa0a6,
+                    0x932ee9965ac2bb4b,
+                    0x005d0d1cd8e16d9c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0613a8fa39ce0dfb,
+                    0x4e22c532f92de9a8,
+                    0xb5b3e3ff84b6675d,
+                    0xc0d09f5eade87a64,
+                    0x8bad9a22bf48de49,
+                    0xff6440d7d525b963,
+                    0x983b58ce0ccc2d28,
+                    0xb8a46c18d3ef129d,
+                    0xa9c27d0bbeed4c91,
+                    0x4b9a1d9945ea8cb9,
+                    0x562c3b4008bef9a1,
+                    0x00babc818590d4d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf1a5e4c5891c1e92,
+                    0xe7b70ff68a50c228,
+                    0x08527e8eac9523d3,
+                    0x5bd3cbbf2d8389d5,
+                    0x5787ea8f5f0708b3,
+                    0x7590f10dc0626560,
+                    0x5ff05b516508866e,
+                    0xebd85cc3d4daaa57,
+                    0x28726ff8f95d0222,
+                    0x1e0b7e42b84b453b,
+                    0xc4a854edcab15c0c,
+                    0x00fa77e6611615e4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xfb393d590c66bfc0,
+                    0x471ed9862a0166ad,
+                    0x5e87fd90a3e819b3,
+                    0xc2537f022e930c51,
+                    0x2130bd2993546fc8,
+                    0xf709c4c6ba61a636,
+                    0x989ec7a7735fe308,
+                    0xf917bb9e94d86cae,
+                    0x71ab29735f6c3fcb,
+                    0x711706ee71741e04,
+                    0xdaa8077768df051f,
+                    0x00c6abd08fe95743,
+                ])),
+                Felt::new(BigInteger768([
+                    0x30a58b53bc5618bd,
+                    0xf9b184aade8afb78,
+                    0x49edb894a07d05be,
+                    0x631ee9b0868b9b29,
+                    0xf216d0a88609b77b,
+                    0x0cff036f5d54c52d,
+                    0x11c792647c11c102,
+                    0xf78643a741e722c9,
+                    0x7bdb8278ed87dd5c,
+                    0x6bf4d1cadae9ac07,
+                    0x51cf474203eb0e56,
+                    0x002ba9cc07940d1f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c83166d8c64fa97,
+                    0xd70ad238a0ecc7bc,
+                    0x9956ee628efaebdf,
+                    0x8f462d2aa95a20a5,
+                    0x89a340090ed50022,
+                    0xbad1f51fd7c87327,
+                    0xb556eb5dac121515,
+                    0xb33670ba815b04f9,
+                    0x524fbcc6ec0560ce,
+                    0x4205ed1c0c98d7ce,
+                    0x4494c5758940199d,
+                    0x00a392ce7e494c6b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x31eeb5cc13273851,
+                    0x2862b64a902d6628,
+                    0x56ea6492dd33fd7d,
+                    0xad9547ae590faaec,
+                    0xd70046fd6fdfa34d,
+                    0xc02fc8592b9cbe24,
+                    0xc159f3cb7111fba3,
+                    0xbae27b356e547440,
+                    0xedc4d3adb44d76ba,
+                    0xa23f38a4f462b093,
+                    0xabab6d2c675e118b,
+                    0x0089906a76fe100b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe21c3259861d6fcc,
+                    0xb8868a807e3f800a,
+                    0xdc474db6702b934b,
+                    0x0af02ff269a73253,
+                    0x0126a3bf69fd0d7e,
+                    0x2b40bf8bdea1e2bb,
+                    0x68d17bae4c3847fb,
+                    0x7e54982c9214f0af,
+                    0x2dd6f00c43edd8da,
+                    0xf4bffd71932ad1a1,
+                    0x4b7d451727ec61cb,
+                    0x00956e1f071bc984,
+                ])),
+                Felt::new(BigInteger768([
+                    0xef6fe21a13e06fbc,
+                    0x70c5521a04d7e7a1,
+                    0x6788688d6bb47daa,
+                    0xf53286a9c4f03c74,
+                    0x67e2d6aec139e4e4,
+                    0x4b131c23ef804bce,
+                    0xe42fa1c75ea5157a,
+                    0x42e4b9f9f5e9e27d,
+                    0xf5f15a30d562b8bd,
+                    0x7eec2444ec8f54a3,
+                    0x434b86b442994bdd,
+                    0x007f8079b797afae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xfa1bd15289023e70,
+                    0x0181c569a8be99f9,
+                    0xce437d5f0331bfbc,
+                    0xd16c866fcb132729,
+                    0x9652ae323214bbb4,
+                    0xef047b1d1864b2c0,
+                    0xa5f1c93e5c2376ba,
+                    0xed29a0417ed0d2c5,
+                    0x387663d5a6170205,
+                    0x9b0884897ca63168,
+                    0x35a930c3e298c43d,
+                    0x00d4468997b5ab9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb1dae5f80c2bf639,
+                    0x673afd0488a254f6,
+                    0xce1373d7bde50920,
+                    0x7d3e238e1d48a068,
+                    0xb42b7cdab0b8cb93,
+                    0x451390744db9efb2,
+                    0xd31a59964ed7dd01,
+                    0x6792b96c75ff799e,
+                    0x89e7a761226e70f6,
+                    0xc718376c2ae0a4d0,
+                    0x2164110d63e33a21,
+                    0x003f077af6ed4330,
+                ])),
+                Felt::new(BigInteger768([
+                    0x61dc9b52ca907108,
+                    0x533aac45d3096f9f,
+                    0x693c484b20f5010a,
+                    0x481f202a4762d541,
+                    0x54b8d3d485b2f23d,
+                    0xe072856aa1ae3c44,
+                    0x253e6b46aafa2776,
+                    0x60ed5b9ba73d1db1,
+                    0x981f2d3b2ced8075,
+                    0xd9a25c83e8c9fc8d,
+                    0xed381fae4dfb5fad,
+                    0x00b144ce8d0fb295,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc057804253283e9c,
+                    0x00791bed844fcba8,
+                    0x264cf2d16948f4cf,
+                    0xb8934e7b17eb51f0,
+                    0xcb66a2d07c84041e,
+                    0x465674720595436a,
+                    0xeff9a62b52460838,
+                    0x062e5862fe6f864c,
+                    0x79ae1f02a04d947b,
+                    0xb51a3411eed35310,
+                    0xa25f90c93d12bdeb,
+                    0x011b870d148928f6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdcc6bbf3e208f66d,
+                    0x1b01f414847736c0,
+                    0x8a807afb4aaaeb6e,
+                    0x9e3722877a31e296,
+                    0xdd103bb8c5f01a88,
+                    0xc80ea73a60d3a0d4,
+                    0x4e9758d359ddfcc6,
+                    0x63e5e613f7870b26,
+                    0x8e12f989fea25af5,
+                    0xea8d6b5012f4222d,
+                    0x7f8aeda42836f51d,
+                    0x001d9af317e121f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd03a3ff2568d52c,
+                    0x3753ca468956e8a2,
+                    0x28f374d0fb7407c0,
+                    0xc39bfe3478e4b4d0,
+                    0xf5ee4c782cacc9f4,
+                    0xc10446e22aa08aff,
+                    0xd27e6f7a2515c96c,
+                    0x382178b5230f0ca3,
+                    0x397a9c02d0447d8c,
+                    0xb7901b770bd30cc4,
+                    0x48549316bb832a64,
+                    0x00f82f475603e876,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xa01222b65b44cc92,
+                    0xb070fb10e32b4991,
+                    0xd3c5fdee6470e2c2,
+                    0x60f73ea426387b80,
+                    0x455afa0df3ab1452,
+                    0x23e97ecbd1dbdcc0,
+                    0x48d39ab1d5cfc386,
+                    0xb43ae589c1412351,
+                    0x3739caacf0d33f91,
+                    0x1e55834851beb409,
+                    0xc6d1b3305430248b,
+                    0x00ae71108fe3426c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf3823300a0529ea1,
+                    0xb98ca6d1245b8adf,
+                    0x8029d03f31568122,
+                    0x07e2ba9cf9a79ab2,
+                    0x1bb647b8c2c64066,
+                    0x9f2029af02491e8d,
+                    0x3918ba0db001f5b8,
+                    0x52172e40dc12cee3,
+                    0xe0f9e44a385c4bdc,
+                    0xc77de27b165671dd,
+                    0x4678fd8889ebbe5f,
+                    0x00ca48651cd43178,
+                ])),
+                Felt::new(BigInteger768([
+                    0x895bd03dbd7295ca,
+                    0xe57ccd624594e472,
+                    0x28e95fdd690a40e7,
+                    0x67feecd89b910c8b,
+                    0x9e39c937675d9a3a,
+                    0x3587368d15abf903,
+                    0x933b6d5322cda92e,
+                    0x13b708165e08dec1,
+                    0xcb27e648b8de08b5,
+                    0x81286caba7480670,
+                    0x78455179acaebf33,
+                    0x00419a871fb2865d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x91fa23b53b1dc666,
+                    0x67804c08a2c6cd7f,
+                    0x24c83f6558b3375e,
+                    0x6cb205d22c3f768c,
+                    0x4ced14850d56c2e5,
+                    0xf95629cc670c04d5,
+                    0x4cb9dd87c5ea3892,
+                    0x8a442eed5eeea840,
+                    0xb312f69430a63e9f,
+                    0x70fcac5de19f4628,
+                    0x99140f32133f46fc,
+                    0x0104e56667e92654,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda455d3dae877bf8,
+                    0x17de3a1fdf12fda0,
+                    0x76a94a7404bf1767,
+                    0x204e72995b57270b,
+                    0x34c4afadbe8c5500,
+                    0x3c49b2d01816dcf3,
+                    0xd8be1026d8e9a771,
+                    0x88219d1c0a9ea6a5,
+                    0x48dc91f35ada179f,
+                    0x3a8296641c140aed,
+                    0x394ae10f06e9729c,
+                    0x000c2140cbee39aa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8a861ea3a9bfde43,
+                    0x03dad1e3d7fd175b,
+                    0xb8780ef0a56fdda5,
+                    0x7a84e4c424686647,
+                    0x3c1baa81a9aa3785,
+                    0x01032320ef03fdb3,
+                    0x97e776cce6cc6796,
+                    0x2763f8688cf50587,
+                    0x1d6abdbd731262d9,
+                    0x4935d7a96d901bee,
+                    0xed68faef2662fc4a,
+                    0x0084cd36e65bc591,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x2377cb80b75a6eaa,
+                    0xae89950e55b5d866,
+                    0xa2f65137d09aecf0,
+                    0x8de4edc2b4b5e86d,
+                    0xa6378fd26d07fab4,
+                    0x2487cc0fb324e0d3,
+                    0xf461318f30b5e426,
+                    0x846538bf28b94a13,
+                    0x0c7f15fef6febad0,
+                    0x105360d6c36ab993,
+                    0x2302b4a92b0d6c6a,
+                    0x002e5b9743c1587d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x41088d590423998b,
+                    0x554ad609038971a1,
+                    0xf12990dd7de1295c,
+                    0x12d50fe884611353,
+                    0x7e37eba3eaee4724,
+                    0x0001764e99603a05,
+                    0x1f2e10cd65754269,
+                    0xa58eb388cdd348e5,
+                    0xa2fbdfdf5ea2cbc8,
+                    0x53cf662d811a3359,
+                    0x8f2cb192ae86d818,
+                    0x001d1410183f18f0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a0e0908261d185c,
+                    0x6e167c9c47b32ae6,
+                    0xdadebcbfb573860c,
+                    0xda5bb221d25e3fac,
+                    0x4ba8841ec079c713,
+                    0xdb16257afe7a1419,
+                    0x5e62c58d6f8a4e65,
+                    0xe3fb36152c94b281,
+                    0x5aa2b4e919b9e7df,
+                    0x02b5bac1c57752d7,
+                    0x2159ddf6dac5dea5,
+                    0x00308797f2691375,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd4e57575b4cd3ee9,
+                    0x5f7ece897753dc88,
+                    0x0fcb39f9541b42da,
+                    0x86fd3af5635efe3b,
+                    0xd716824c34ccd4e2,
+                    0xd9314b5561353b38,
+                    0x1b582609ca2c290a,
+                    0x7eb15aa8d311d9e3,
+                    0x4da724ea1783d15e,
+                    0x25e211be6c87a93c,
+                    0xf9de5633585fac32,
+                    0x011c864c789bc540,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0ee0761145479b92,
+                    0xc196a4d6b988909a,
+                    0x3ded7e72c0949163,
+                    0x7418e385d9a661cb,
+                    0xa55b2eeb75ff112c,
+                    0xda8f68ff0d8c6626,
+                    0xbea195d4038ccf57,
+                    0x43e4ba1c706c0b70,
+                    0x05af34cd54370834,
+                    0xfc6644b25a0ce197,
+                    0xbfe630ab09b21dea,
+                    0x00468b3eed4b6e30,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4a9a7f8fd5c4abd,
+                    0x9624b1d55a2d295d,
+                    0xe48cc32ead4520f0,
+                    0x9223d7ae0189f68a,
+                    0x256f70af451eaa89,
+                    0xe8a12c681931d1e3,
+                    0x8ad93dac9ce35696,
+                    0xded4623a41f942ea,
+                    0xa5cfc8d48e15d322,
+                    0x45a0bef6c2a8347d,
+                    0xabd18f77b2112e91,
+                    0x00ea4c099049d5b1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xf9dde592c7ec08a9,
+                    0x3c25059f2cec6b62,
+                    0x4fe0bf617dcf2e42,
+                    0xf81b8d502afc1443,
+                    0x5b8e40a87b40c809,
+                    0xd83a92b77dc3b4cc,
+                    0x92a775e2ace21565,
+                    0xfb4a08e399f47e2c,
+                    0x7c03a625a2adbd49,
+                    0x95633dc6152276d9,
+                    0xc4a1569c91af4901,
+                    0x0116796df2110c26,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6dac95e2c2d4b11d,
+                    0x85a930df9dbe294f,
+                    0x611c7e8f9c59fe79,
+                    0x95031dd242bcaf06,
+                    0x23b28e3b12d9dea5,
+                    0x2ab92646ab04d14e,
+                    0x0cf4426ba2d9a046,
+                    0xda1ae19b87c02852,
+                    0x3101e4b0c3f185b6,
+                    0xcc9d1aac20578f79,
+                    0xa457892f2b91adc6,
+                    0x00f83a3c5a62ec26,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01e466b92ca9508d,
+                    0x74138abe7deea872,
+                    0x398a7a0911b7971e,
+                    0x050f5dca834fc649,
+                    0x886b75335e72a517,
+                    0xde01c59ec893b618,
+                    0x21d8cb3f2a4c8ebd,
+                    0xffa98f203439e5ba,
+                    0x49e5fb57f79cb6c8,
+                    0x2b0cb124eeded28e,
+                    0xed7235954dab01cd,
+                    0x00031a64d34f70c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x273a4a93f31be082,
+                    0x77225476530a4016,
+                    0xc7fcd598db71a9fd,
+                    0x8b9013729c6de673,
+                    0xddd825426a0e4d28,
+                    0xe08fc27f3113ed58,
+                    0x405bc90b49bdf39e,
+                    0x3ecb55196620f58d,
+                    0xa05b17526ea323b4,
+                    0x684fce230c449388,
+                    0xdfbcbb6a6bcb1ba6,
+                    0x003efb6f878a8697,
+                ])),
+                Felt::new(BigInteger768([
+                    0x974af7a0ffbe0641,
+                    0xc775e595bf1ea48d,
+                    0x298cbd5e51cb2a45,
+                    0x6321984cfc0785ab,
+                    0xb4b3773413169030,
+                    0x6214b1eb9648c91d,
+                    0xeaef53a48da3e63b,
+                    0x90292ec176b1550e,
+                    0xc55aa9267a916210,
+                    0xc95569b21855d159,
+                    0x07257fdef9cfd7be,
+                    0x00b3dfe7d0b482a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcba39a3bc8ecf9a9,
+                    0x4ff218caef535b37,
+                    0x342bba73cd42f6e2,
+                    0xd26479883aa0db51,
+                    0xb94fcf75f663904b,
+                    0xf70d2668d21d2997,
+                    0xdbf0e6ec52d5cf55,
+                    0x92e1e8844f2ecddf,
+                    0xbf29e575d685b863,
+                    0x45e8a78c8053bf6a,
+                    0x6ca79281066839bc,
+                    0x00d5a910f4f3a8cb,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xe1d0f80d298b7852,
+                    0x3880a11d0043a92a,
+                    0xcc6242b0fcc5ca6b,
+                    0xd6005e6b5df271a3,
+                    0x08cb589fda60b642,
+                    0xab74c079664b4bec,
+                    0x648bfa4c252ee038,
+                    0x70f99088e925192f,
+                    0x36466d063fc5a52b,
+                    0x1c2d0af9eb6239ce,
+                    0xb98077a2689ed17a,
+                    0x001353fbb54d9836,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9cb222ede08b7a8b,
+                    0x1213a81217ff8bfd,
+                    0x7cf60d47b6990bd6,
+                    0xddc5e71ec340bac4,
+                    0x24e2a385a01ea6a6,
+                    0x02e3e20420aab618,
+                    0x21dccb7e22eea2e4,
+                    0xcaa25e8cd47abbe2,
+                    0x4a6bfbad4625935b,
+                    0x3725e03e4bacb1f9,
+                    0xf40679723dced8e4,
+                    0x0087921416e7619a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b2cab368650808e,
+                    0x2c0a22f5726dec12,
+                    0x449bbc6e86036252,
+                    0x6ff779f54755e8fa,
+                    0xfc30a6542fe56dfe,
+                    0xe78eb02935a3bc5c,
+                    0x7b2b812fffcd84b2,
+                    0xf26cc5f158e3f4ba,
+                    0xcc81b03ff0facf6e,
+                    0x80840877d6e6a0c8,
+                    0x5d1fa0129da48094,
+                    0x006a69d7a58669f5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x64c1f363814b7d89,
+                    0x59eefc76c5c33abe,
+                    0x66ae10b35625a5d7,
+                    0x840b2384a6e91093,
+                    0xe4ba3fb6be5eb23d,
+                    0x360d6c9957dba889,
+                    0x8f27a88a71b918ce,
+                    0x49bb3fa958057ce6,
+                    0x149686108d992e5d,
+                    0xa36e0370924ccd36,
+                    0x5adc0b7762973009,
+                    0x005ed5cf14817aa0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdc09112aacf9179b,
+                    0xf2892d079a86449f,
+                    0xebf13ef026f3fb3c,
+                    0xf658d4e2eedf447c,
+                    0xd69b24a210595f05,
+                    0x5e49140ba9ad219d,
+                    0x33eb3690083ea110,
+                    0x734d0fd139ea26ce,
+                    0x1e3e01af8833e55e,
+                    0x7a1f689f27b14154,
+                    0x9b1683bf83ee2e0e,
+                    0x0078c3f3b1020038,
+                ])),
+                Felt::new(BigInteger768([
+                    0x396a2a7653a3f2f9,
+                    0xa09cbb68b2b16bfd,
+                    0x520fe14ed086fb3b,
+                    0x0dd0660980136477,
+                    0x9d664c453fc77359,
+                    0x769e2e1c3352a002,
+                    0x3b0a35e200908f12,
+                    0xb1cdddce93f6d2ef,
+                    0x2ef2466455bcb03f,
+                    0x890b5f037c3bf0ab,
+                    0x49a44d24c4a19a40,
+                    0x000644bd619a5f2b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xfe6638af1e03f6a6,
+                    0x72d94294401d2fe7,
+                    0x1ff1c7b066dbab77,
+                    0x24e74069749861db,
+                    0xb1f68fe6d0b5111e,
+                    0xb734c09b4a836d89,
+                    0x8b23b6befef0eda0,
+                    0x6f7829c7868b5692,
+                    0x8b291facec888644,
+                    0xd11b2f1d0840aaf4,
+                    0x20c0501c81fe8a8b,
+                    0x00bdb66ae0339a87,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde7dc14e980e3966,
+                    0x77a9f4e3d1a6550f,
+                    0x0f1dea6011f94689,
+                    0xf53f805fdd8cc498,
+                    0xaa4178043145317d,
+                    0x908aa0eed149b3af,
+                    0x375c939ead04e66a,
+                    0xf4d6ca680ddb4276,
+                    0x7200ffa68dbae968,
+                    0x74b641e84e9e8689,
+                    0xab33e803f20a6ce5,
+                    0x00e52aa52a0b22b1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x23034a3862d8af16,
+                    0xe8dd254a9e13e387,
+                    0x0623f78887108353,
+                    0x3eb737f2ecc1aa12,
+                    0xe19843407bd73138,
+                    0x2bd9d8e46ec17829,
+                    0x46e65b8a00c56baf,
+                    0xc8558b319fb0b3d6,
+                    0x0908cb9a17ea704c,
+                    0xbfe64f5535eaa4e1,
+                    0x653841bc5cb03322,
+                    0x009891a37aa12492,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19448c58539e3168,
+                    0x144c11a0530affbd,
+                    0x98e18cfe5ec82ca7,
+                    0xd653ae108123db7b,
+                    0x4c77fea617116852,
+                    0x58d036cb85d2c448,
+                    0xa81b0ff0243ecba5,
+                    0x228a740928161fe4,
+                    0x91a3ec8c4d7c41f4,
+                    0x18137e34fb7fe48d,
+                    0x1726279b8d03f831,
+                    0x001427fbd73440aa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b8f34793da2ec58,
+                    0x6a487911d2048088,
+                    0x1acab0cc27847e6a,
+                    0x4cccb46ae2c6f395,
+                    0xf0ed7239bb2d294c,
+                    0x78f3f2ee5a1b7fe3,
+                    0xac4296af2d06c961,
+                    0x65fcc8ec16a062a0,
+                    0x1a0192a5928dbdce,
+                    0xed35495771b948ef,
+                    0x648e439c953a8b74,
+                    0x00f8d6a51091124d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd9d2db2d9f5da83b,
+                    0xa4c2657f28439a39,
+                    0x97175cec94d212aa,
+                    0xb0cc16ea56aadacd,
+                    0xbc657b4aede68462,
+                    0x9ff1c4334a6eb500,
+                    0x3300093737d0dc22,
+                    0xfaefa555dd75ad24,
+                    0x03f65c97afab8384,
+                    0xbb84a874885df421,
+                    0x7bb0bc0463e0053b,
+                    0x0018ccac39133a58,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x5f54b9e914edbaee,
+                    0x3a94f0472a00576a,
+                    0xb34152802b4764c4,
+                    0x6082bf2d33660c86,
+                    0xc3343604a2b11b45,
+                    0xc0409dbc3c0fb627,
+                    0x21a0b3d745cff385,
+                    0x64b2b2460559b8d6,
+                    0x6f5204ea4ce767cb,
+                    0xddb7b38b45fe0419,
+                    0xa9a09d6b68017839,
+                    0x0017df53f91e6c96,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd1099ea20374647d,
+                    0xaae3c4f5bebada88,
+                    0xf573bb9aa2a27b19,
+                    0x86287232840a0c86,
+                    0x91ea6f6398d517de,
+                    0x41bfe408b8caacc3,
+                    0xac99232ba4fcb441,
+                    0x1698f08c436d33de,
+                    0x5a15d53f7b8ed846,
+                    0x6fd27f1d8d0a7752,
+                    0xe46db5729a203a13,
+                    0x005e11a9620de71c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9a1cce54cd00f0d8,
+                    0xefd02a5446010649,
+                    0x388ed46bb4767e08,
+                    0x4f3cd80d0b7d4ccb,
+                    0x50e43b3d6ce417a7,
+                    0x7c2fc00c71480ec6,
+                    0x92d3cb3aac3ec9e2,
+                    0xea8f1160c4fa90f3,
+                    0xfa44544f2e560ab9,
+                    0xc24f0a5dd0a71a1e,
+                    0x7ca4e9436b03f05a,
+                    0x0065d393aa24a2e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x55435fed5b832fa2,
+                    0x136a1d8e91d44630,
+                    0x731c56d6ac529eb8,
+                    0xf0db4e8082e65a52,
+                    0x7f21c727458a025f,
+                    0x05851481b677204b,
+                    0xd6f60fc2895f354b,
+                    0x1e6cbc744a8586ec,
+                    0xeb1380a8844b0e14,
+                    0x8b7c1cc823318c5a,
+                    0xbb12a34cb13714a4,
+                    0x00dc98818f4b5f1f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4e6253ee72f0cb17,
+                    0x077a7b89d0cf1099,
+                    0x22af0f2eae1d2de9,
+                    0x9c0f610813e25b2d,
+                    0x16ae3730f7916071,
+                    0xacbb66d3a91fe251,
+                    0x06662900fb86fac3,
+                    0xf7fb4b98435eb33e,
+                    0xe24c05aa9eef82ca,
+                    0xdecbd4f10686a104,
+                    0x23418733ea93410d,
+                    0x0031eb180cce43fe,
+                ])),
+                Felt::new(BigInteger768([
+                    0x345ad9aee9d0a733,
+                    0xbb674d822aefa800,
+                    0xc76993d0196191c1,
+                    0xb81559d7393fa9b1,
+                    0x8967b081fae497c6,
+                    0x5b43d5c9691e5ffe,
+                    0xa42fd5326bd9388a,
+                    0xa8deb6562859d6fd,
+                    0x2adad027ee667566,
+                    0x88238a61feb1c0ad,
+                    0x6e74f72b169d9ba4,
+                    0x003c31768e4d7df6,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x4f887446811a7e52,
+                0x1a823cea53f1d04a,
+                0xc4f3ecf78881845a,
+                0xffab1b1f141fb1d3,
+                0x1d606a498abb5597,
+                0x04d4804e3239cf83,
+                0x289413fd9f6da4a9,
+                0x844d89f83ec24699,
+                0x7db5152b64abce82,
+                0xdf604bb056f0ba61,
+                0xcd79616beb5f0ae6,
+                0x007065ef020f0d8c,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb7ca428d0d223103,
+                0x1af1e2934c6decef,
+                0x7cecc172330fe4b3,
+                0x5c6b88d41dec8a61,
+                0xadb370e2f76e4a47,
+                0xc8e5c0998e96fa1a,
+                0x35ac0540b416f554,
+                0x9941ad659021ce54,
+                0x480a33f3c11e2f50,
+                0x2327469cea0b3565,
+                0x67ccc7c62ab8b96a,
+                0x00bd8bbaaba837e2,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x870696ffd47d17bb,
+                0x9b3313ff797bc293,
+                0xbeb0554068ebb556,
+                0x0560752d04ee12b4,
+                0x560e03dc75ec6cd7,
+                0x256674a9b689cb1f,
+                0xb3a5c0433f0fa646,
+                0x096150ff85481a33,
+                0x53ac17d91f1b2eae,
+                0x337c1e2d1b036c0d,
+                0x4dd98f466a9641fa,
+                0x002e07363ecd2722,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf01d0efa50468032,
+                0x7661ebc1e1bf1a9c,
+                0xf11fd45091df55e7,
+                0xcbb8e3f7c61e55bf,
+                0xc40f05357edbd01d,
+                0x2c6442a4cf3859bc,
+                0x278a2c00979e46c2,
+                0xeb0c14166159cec0,
+                0x27b44e36e4383e75,
+                0x080fbca6184f687e,
+                0x2433c20f92eaa7af,
+                0x00aacdbf5d4b23e8,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x831aedf1d8b87a07,
+                0x1e472484e0bb3087,
+                0x714149284b734e6a,
+                0xa32d4d1a9d07842b,
+                0x739999d9d0e4e98f,
+                0xcdde7d52888410d9,
+                0x0b668a0df7da1d14,
+                0x8f1dad5f8a493876,
+                0xb9736ede36388c56,
+                0x73b06ab448aad58b,
+                0x02c7fc7fbf96e82b,
+                0x00927260dde79cc9,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x9896ff0c095d5561,
+                0xa0c22454c47f9756,
+                0xfa327cd301afe76d,
+                0x858804f9863f82c6,
+                0x995fa94dc940e8e2,
+                0xb4ea55c9f20e50fc,
+                0x566abdcf7f1ad8b8,
+                0xcbc25965ab220e0c,
+                0x523f8baf9949ce74,
+                0x01d87a1545b6ce83,
+                0xd1883090f3051979,
+                0x0069623dc23aa03f,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xed40df5bde24c063,
+                0x75055ac328ca1623,
+                0x81c2ef1beee56ddd,
+                0xdb449816df875cb3,
+                0x5981388cb3253f0a,
+                0xb79d06795c56e67b,
+                0x45ec9fad5e1189e3,
+                0x57ff6859483bbc87,
+                0x764cfdd7b9f051d3,
+                0x4230c31fa024aed5,
+                0x1ac27fa8399da055,
+                0x00971f842df123bf,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x359f509b41688e0b,
+                0x5ee530b6be3eab99,
+                0xd6eafd4c3413603c,
+                0x29ab31d99bb8f7c6,
+                0xb9132f9736323946,
+                0xb18bd0c420ccac84,
+                0x00b1a0c8dad005b1,
+                0x97db914e77e49750,
+                0x70274f0ae16e650d,
+                0xc37b80d83fe4f09a,
+                0x7252d65b86e78eee,
+                0x00900eb0a06fcbd0,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xd49140ba08e554cc,
+                0x8a161f2b37702f35,
+                0xcd48781cab065b0d,
+                0xd72d5083347f7168,
+                0x2611a3702cd8644a,
+                0xd6d26760426f8dc7,
+                0x99e4b30362cb83b2,
+                0x425341dd92e595fb,
+                0xa3dfec9891e78d19,
+                0xfb2297cdce2d0dd4,
+                0x05353a3bfc57ce60,
+                0x00ac1fb40719420b,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xeff6e99852d68c82,
+                0x33799cafe59b26f5,
+                0x0354d37f656c8b7b,
+                0x9cd746fa6fe05ebb,
+                0x854e6e43c93c0a8c,
+                0x9a8203eac821c9a3,
+                0xd36ae0c80affad4d,
+                0xb9b36b5ab48d56b4,
+                0xf7e4b05406b788a0,
+                0x65bc4ffbb3523076,
+                0xa5f6f8d8553efe35,
+                0x004e5bfc89dc9af4,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 380, 381, 1145] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 380];
+        let mut opad = vec![0x5cu8; 380];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
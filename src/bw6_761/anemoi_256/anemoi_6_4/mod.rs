@@ -0,0 +1,2868 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 576 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 4 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 4;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// 2 elements (192-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 17 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 17;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xee689787cadd15dc,
+                    0x5576206dd6d6614a,
+                    0xe60501a073b4aa01,
+                    0xfdd0d02375a09d57,
+                    0xe43ff291aecd9926,
+                    0xbe81cbdf0bc5a684,
+                    0xb93bc1283ef57be0,
+                    0xf062e955701817cc,
+                    0x4167d6dceb8aa1d2,
+                    0xb44528483076716e,
+                    0xe9802dfb5c35fe3a,
+                    0x00c007d97474ddae,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e3dae02a7efff13,
+                    0x82a3682c7a096404,
+                    0x8785a72ee8e7f107,
+                    0x0439f5379f8534fc,
+                    0x3dbdee522305d4af,
+                    0x7c2635fedef4fbe0,
+                    0x9887c87fd4d9f23e,
+                    0x9fc1c6cf79096d7f,
+                    0x28af4e6174e62b3d,
+                    0x9614dee5799770db,
+                    0xb48f6d9d389ccc55,
+                    0x00921d17d841a978,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd9131549886e6fd7,
+                    0x4d50f864a139336d,
+                    0xae6a77b1686c6956,
+                    0x3fb258c729c9a882,
+                    0x4fc097275bb7bd1d,
+                    0x03287f954b5616cd,
+                    0x49d69bfd584f3d16,
+                    0x8f20f9af1a79173d,
+                    0x586a1b8f5a641b16,
+                    0xe4daa09830676c72,
+                    0xf78a39be55caa6ed,
+                    0x006570d2f771c0ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x723bbdc013204e46,
+                    0xb7168c83145558a1,
+                    0x591a95431d272b54,
+                    0x4749eed59e64d844,
+                    0x3d216f08417acf83,
+                    0xd35b8cbb990c715c,
+                    0xb3db71216e3f065c,
+                    0x72089d869d9be40f,
+                    0x0f6c8a084522b8be,
+                    0xda048553f4263583,
+                    0x754adad8195c6c35,
+                    0x00226dd18476c653,
+                ])),
+                Felt::new(BigInteger768([
+                    0x47200457e734d5a3,
+                    0x84d23fe0c54825b6,
+                    0x4ae88634024bfbe0,
+                    0x7f0663100f88cdc3,
+                    0x734d2c5ad093dc03,
+                    0x372390cdf1aa2b2d,
+                    0x6bfd990390e826b9,
+                    0x725a207502f99d68,
+                    0x17f14787670d3b0d,
+                    0x2e359b62dfa3e945,
+                    0xb0a232e3a4f75573,
+                    0x003e489c7c26439d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x375d9911b0331f37,
+                    0x88df23bbad8dfb8b,
+                    0xce03f98546c57571,
+                    0xadd26e5e70bf4c32,
+                    0x27e6f1d4370cb98f,
+                    0x994e8b02bc48d98f,
+                    0x48e4a65e4ff101bf,
+                    0x96991c9435545c16,
+                    0x049059609616c122,
+                    0x72f7d5f3e635016e,
+                    0x5612400607517873,
+                    0x00ba9fd71ff31c79,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb01a2b0874e0abe7,
+                    0x489d26db9c338a24,
+                    0x796fc28c67924b63,
+                    0x0ae828163753488f,
+                    0x2a872aaacb5bcd65,
+                    0x9bd9d7eb3b95cd0f,
+                    0x3a7819d4c09287fd,
+                    0xa97827f1652e9558,
+                    0xb349a6c24138c57c,
+                    0x636594145a57bad1,
+                    0xf547e01ee2ac75dc,
+                    0x008c84ae16fe091b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeed27544b80e6308,
+                    0x468c263ef115be96,
+                    0x673e542afbd1f0a1,
+                    0x59ff142d1bc9d9e3,
+                    0x5a9b610c14ae0330,
+                    0x8b67b8240c225922,
+                    0xa419cede3ef6fb14,
+                    0xd65b98b24260c3b8,
+                    0x847737c7f5913de3,
+                    0xb40726258d8b3643,
+                    0x2ca285604dd6c2a6,
+                    0x00a477473f92953a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdbd6eaf9b3201f75,
+                    0x264893cb9867c648,
+                    0xc4680f29f8c6d652,
+                    0xe16f3c00d3e6d181,
+                    0x8976fe0f2f207151,
+                    0x6dabdc82d38e93d7,
+                    0x401baacb04b7f832,
+                    0x4d2cbf9726fa1dc5,
+                    0x99028bec4e4f6715,
+                    0x831be39184e68667,
+                    0x209cc22796e694f2,
+                    0x0122287819474530,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcdf338b9b845be8f,
+                    0xed71ae9aaa65599b,
+                    0xd3241841ebd9545f,
+                    0x3a24573687c95dfa,
+                    0xbbb51b09748f98c0,
+                    0xacfa2d7a082fae1a,
+                    0xb295965d08d70f58,
+                    0x067da3559105281c,
+                    0xd375e65c79e74602,
+                    0x43c40d01a6e98381,
+                    0x42edfd9763df430c,
+                    0x00cf44db27c83ec3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x517713e7bdae6ee8,
+                    0x8b8e04c2003b9229,
+                    0xce886a837021cd27,
+                    0x64b75422b0864df0,
+                    0x5950345ddd9725ee,
+                    0x63bb04fa1deb4056,
+                    0xa4be249243ab3972,
+                    0x269f75fa0dbd9f5c,
+                    0x60aee54b44f92311,
+                    0xefcf4b0fb55e58f3,
+                    0xa2d876ef413b710b,
+                    0x0005b2d388a1b63b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x980b77abfd8abe8e,
+                    0x22928fac21228aed,
+                    0xa40866357658971b,
+                    0x7aa9c1bf30e79d32,
+                    0x6f60d312dcf2fa12,
+                    0x6dbfbf6c0290a1c7,
+                    0x2da2325d1518445e,
+                    0x6aba11d6565d7671,
+                    0xe067f5fd0de94875,
+                    0x4c384d48616add85,
+                    0x8a649210da7fa544,
+                    0x0021c6f136738b33,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0b24b39aa25000ff,
+                    0xc638be131342f753,
+                    0xbcfb8bc38ad7be65,
+                    0x919d4108ebf71a52,
+                    0xdf1e06ba3622a3ca,
+                    0x3e873fc1ba5d4270,
+                    0x987d0ffbb05bdd48,
+                    0xd649cbf7b6191a8e,
+                    0x54c6b15f4f991efc,
+                    0x266b45e972f2bc62,
+                    0x4929a87a0867788a,
+                    0x0093b3dc335ec30a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x39a3cb584a2af209,
+                    0xce287615a14c0862,
+                    0x4f31f6d234a7ccbd,
+                    0x72242ce4e1dd8447,
+                    0x087ee42103184517,
+                    0xdcea1057eb2df1b9,
+                    0x82613e3b262576b5,
+                    0xc94160b91316ad2f,
+                    0x9154cc4f05827930,
+                    0xb437eccb68054188,
+                    0x8cd57d2685f66d4d,
+                    0x002d00749b569493,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd2df39cd18fa7ae1,
+                    0x42b77ee1858146db,
+                    0x393c405f28cf4393,
+                    0x251e4d2bbc570d2e,
+                    0x14a7faafefa5adff,
+                    0xbb906cb3290e748f,
+                    0x2e91dc3b0ecba448,
+                    0x6a6644621adbf713,
+                    0x4b91a58f18c897c4,
+                    0x13b82958a9912c2d,
+                    0xdefc8e65ac86fe6b,
+                    0x0082091cd5f40454,
+                ])),
+                Felt::new(BigInteger768([
+                    0x993b9100155e92a1,
+                    0xfdf091743266d1ec,
+                    0xe9af6ee717802be2,
+                    0x94e24baba19b5d1d,
+                    0x662774212685eb01,
+                    0xd2ee086f0f9ecae1,
+                    0xa3a9b58ef1c9c40c,
+                    0x1f94ee1471692929,
+                    0x91bb69a93a202821,
+                    0xcad23fdf897614f1,
+                    0x711c2415fa787455,
+                    0x008b15063de1089b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd8d0e0007d0f0bab,
+                    0x8fb66513788e0860,
+                    0x08df3d9c9bc71706,
+                    0xddf30cb18a49929c,
+                    0x0ad35ca90d21d81a,
+                    0x754389133ec383e9,
+                    0x7879e9b04152670b,
+                    0x799f23daf1f5d20f,
+                    0xd0e1b2930b1c889f,
+                    0x56e7aa878854ba49,
+                    0xa7acae2b99955f0f,
+                    0x00428c0f6cdde13f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58f514e356da7709,
+                    0xf5e94156d6ee09d6,
+                    0x80d1b167b3baad1f,
+                    0xf346a1f4ce28dbbd,
+                    0xfe20b24e86818bb2,
+                    0x022f206185e4b5dc,
+                    0x7f8d7ad45d1e6169,
+                    0x79513a543955adcb,
+                    0x6a81ad72d7cb7ada,
+                    0xc9f8e77a4f07affe,
+                    0x0cce1461da99cb39,
+                    0x00a51194e19b4bdd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x49ac4fd6c6c33288,
+                    0xd5f1c79729a38687,
+                    0x7ab19315eb81cae7,
+                    0x1a81a0f6f9249443,
+                    0xa04817e9fab082d2,
+                    0xfa78f5cfc18447a4,
+                    0xe32b505bb971e33c,
+                    0xeca025f34b6ef141,
+                    0x3f087abd3af1daa5,
+                    0x9382fa6673b26fe9,
+                    0xd6f1005537d95fe1,
+                    0x00b18c19e383d60e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x79ce9dc50d2f4bf2,
+                    0x1d2668a14181f312,
+                    0x621f8533a2d32fe0,
+                    0x707bf9a6de2fcfec,
+                    0x819c46bbd9193eb1,
+                    0xf08c351cd3df2908,
+                    0x0dda578582ba58c5,
+                    0xcfb2a88a2d89be03,
+                    0xa65393d77df42631,
+                    0x373e290f41536a6e,
+                    0x4d3141ae1c06e6ac,
+                    0x008a50469ab94660,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7f878583109eb2d1,
+                    0x5e2a7d035e49d072,
+                    0xf8c932be8c495945,
+                    0xad0e83f19c82a210,
+                    0xe93053c7a2658058,
+                    0x8424f6f5e9581711,
+                    0xc4610533765e4001,
+                    0x8ce1f98a0ffcf7cb,
+                    0xee28b1a30710aab3,
+                    0x25addfc07bda3278,
+                    0x28b0175a9989483e,
+                    0x006e733383a965c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcb5a33dab3f2db38,
+                    0x95006607d5cf4650,
+                    0xa5fb4a45c43ce096,
+                    0x55167b5b5e7de438,
+                    0x5e392d1e37bae625,
+                    0x887ec141c64912dd,
+                    0x3adf9efd459cf64f,
+                    0x4f8384fb9c207559,
+                    0x3b1d03df0fab60ca,
+                    0xa0063a728d94a13c,
+                    0x2eb1f3f83f510271,
+                    0x005db81aa503ce5a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b9afdd3801d0d57,
+                    0xdc92e853b07cb67e,
+                    0xcfacbfc21c832e57,
+                    0xcf29e18ea35a30a8,
+                    0xd62ec2e60ec5f51a,
+                    0x9fb9fe85b4c2f90f,
+                    0xc236baceb43f99a0,
+                    0x2523a287de8442e3,
+                    0x44601b3df163d5e4,
+                    0x5cf6a032ecc421c9,
+                    0x980ea5ea326d19b3,
+                    0x00cf63242b1b0e1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7aa22a63cd499051,
+                    0x5f60de306dc3f72b,
+                    0xbbc9ae3f7710f323,
+                    0xaed244b69e91cb75,
+                    0xd3a388b113eb58d9,
+                    0xb472ff3a37e459ea,
+                    0xc56c571cb8ad57a1,
+                    0x0500934780bc7f89,
+                    0xf8ee64877704369c,
+                    0xa6126059d0258676,
+                    0x5d03b64af7f0f49e,
+                    0x003fdddf339bbc7c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbc8fc2f57fcfba54,
+                    0xdee486134cef750c,
+                    0xe77f604fb75ac27a,
+                    0x4eceffaf60826a46,
+                    0x1e1dc9b9011728f5,
+                    0x2f1e9be60c5248ea,
+                    0x938d0a81889006a1,
+                    0xa722842302834676,
+                    0x0e03be56a139df61,
+                    0x348fa9e32b750f04,
+                    0x64ad14ab0a338367,
+                    0x001b2fa8a638c1d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d4fe725359e2b61,
+                    0xb0509f09c87cb3c9,
+                    0x1dca9a7ff59c0366,
+                    0xb58a0ff80d0401cf,
+                    0x46daee8101ad86a7,
+                    0x4b2cfc8aa23f1023,
+                    0x7308429cb9e55915,
+                    0x8b99686a740f80f5,
+                    0xce15ccce5198d2a5,
+                    0x056362873881da5a,
+                    0x8d57f74163108a18,
+                    0x00d3b0a1ba2f689e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe1b482c1e0c09ecb,
+                    0x114e5f77a5b9cec5,
+                    0xa0c773d53f609361,
+                    0xb2c5aafa0214eaf4,
+                    0x4d6445038cc5e613,
+                    0x3e5f1eb6a4f2e434,
+                    0xdbcc1d638f7720ed,
+                    0xb2d047851f25a6bf,
+                    0xdd87e9cbba3289a0,
+                    0x9baba142ddb0732c,
+                    0x9391238cd5d39f38,
+                    0x00edf8090862fad8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd7a8ca20996aba1c,
+                    0x0bd1ed711b43c069,
+                    0x67ef89a0ae6c1a3a,
+                    0xfd6653b31bff5a35,
+                    0x62ce5f9f03374a76,
+                    0x2cd201bd2efed65b,
+                    0x14670fe812144751,
+                    0xdd575a885afa7099,
+                    0xe1b4b7078e8dddbd,
+                    0x7862aa00b078f8ba,
+                    0xcbd4622351071b55,
+                    0x00d3fa4a0b68142a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x321e240677439180,
+                    0x049d144115fa009c,
+                    0x1c5922120da21646,
+                    0x60744d4bea89f5cb,
+                    0xc7fe6c4ff00c86e5,
+                    0x68253f1354ba11c0,
+                    0x0e604b62d498bfde,
+                    0x5a2493fe28601a73,
+                    0xedcbb49481009029,
+                    0x91756d4266483913,
+                    0xe2f6afac1a072d9d,
+                    0x011056da5a1cea99,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6d228731f1a6a109,
+                    0x8a773a63dd67d37a,
+                    0x9c9969c540fef193,
+                    0x4386dd8b5ca3e12b,
+                    0xda6124b6d66e0129,
+                    0x27f36e257bd50749,
+                    0x909d3e6e409c2080,
+                    0xdb1fb3850641ef09,
+                    0x546f92fd8b756d00,
+                    0xba8d9917095579cd,
+                    0x15731fa2b208d1d3,
+                    0x003230f9227899ef,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb59da7c4d925f6ae,
+                    0x7589f46dfb1a6c86,
+                    0xc24dfa077ba7e09a,
+                    0x79643b76fb472c04,
+                    0x3af18a9ff59db296,
+                    0x93be6d1f646a3e43,
+                    0x6e84bfda089baee7,
+                    0x2ba6cd89ea20eb38,
+                    0x16c8c30af16dcba0,
+                    0x04761f5ea699835e,
+                    0x9c2ad60890886330,
+                    0x00b2eeab5e3d3d7a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd0367ac183b382cd,
+                    0x9ec9f5ff99433327,
+                    0x37b0e088b2aaad1d,
+                    0x4179c457c139db6b,
+                    0xfd7cac45dfa2844c,
+                    0x711353c4531b0f36,
+                    0x562f5cf51faaecaa,
+                    0x6b8744535fa04151,
+                    0x426bf4101ab14e02,
+                    0x66161e811a46ff0c,
+                    0xc5cb11248db97be6,
+                    0x004d15543c8c4218,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba2feb03260f2749,
+                    0xa48730014dc4de4f,
+                    0xc1e9a7ac401229f8,
+                    0xb2e91c9438c25015,
+                    0x1a15502f7eb1621b,
+                    0x0ccba179efba2d1b,
+                    0x487aa44359171ba0,
+                    0xc52ba22cc78e9050,
+                    0x835ad76ddc5d3d8e,
+                    0x3bcf5aad30415615,
+                    0xa1802f46f79d1ef5,
+                    0x00b0c0b3d8ae2915,
+                ])),
+                Felt::new(BigInteger768([
+                    0x59b286e39bc011be,
+                    0xaa550aa630fdb979,
+                    0x3b28225b5559caa3,
+                    0xf0ba263db2fb930c,
+                    0xb8846e925fc0e035,
+                    0x83b799683966b76a,
+                    0xa92e160af7f4a952,
+                    0x575001430a527a67,
+                    0x74c10d4a00ce88cb,
+                    0xc36b406b1fc0f3c6,
+                    0x4c182918d77ea632,
+                    0x0085bb49e725c9ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff5d5b0a244011f8,
+                    0x7fbcf6be3abbc74a,
+                    0xcc9bbd982f099afe,
+                    0x48e01fd3d38a4954,
+                    0x9fcc26ab50192d23,
+                    0xd5d07e6d719b753d,
+                    0x9779cfccb81b5959,
+                    0xa3402e677c62bbbf,
+                    0xdb9a59903945d37e,
+                    0x91c7101bd559bf7a,
+                    0x134b63517d618022,
+                    0x003e06dcafbdffa0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x377787fe541be095,
+                    0x5a4102d1fa156157,
+                    0x40cfa768d2580bac,
+                    0x2d8cdea955d5f8d5,
+                    0x561394f4dafdb047,
+                    0x00e71e86abf8900e,
+                    0x38d4ac3f0a14eaba,
+                    0xe792e1cee0f1a2e2,
+                    0x3ba5bfeb99e97c52,
+                    0xc66b799900129d8b,
+                    0x6918e112185bae01,
+                    0x00d50e5416c1bcf4,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xeff98f933124e5d7,
+                    0xf9797a166ef68885,
+                    0x37b43661afa08dd9,
+                    0xb90774ae208e8de1,
+                    0x88748e94e80f0d7d,
+                    0x3400a1117ad3dab3,
+                    0x3a174c79073111f1,
+                    0x84517f570b1a715c,
+                    0x1ee92b5002ad9880,
+                    0x577ba496e74f8314,
+                    0x1caf272f128b981a,
+                    0x00c26475cf76b304,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe557cf3bd9266165,
+                    0x55b888cbae3926fd,
+                    0x13b964cd14a4c9ac,
+                    0x4479781685a3b8b3,
+                    0x288cc9df6f652651,
+                    0xa5585c4200493887,
+                    0xbfccc5bf24d1afe3,
+                    0xe1ab49437af34ec8,
+                    0x20dcdfa5bffad64c,
+                    0x4e6b56c1ea985d3c,
+                    0x0ec5121ca02eb53e,
+                    0x0017011adc063fbc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc1a799b46956bca9,
+                    0x624c83d5b9772438,
+                    0x3e9b042de76d976f,
+                    0xe34fa40a1a52c514,
+                    0x9dcd02e8c1c4d6c6,
+                    0xaa0fe31017f11ac2,
+                    0x8000cdd85aa0d82e,
+                    0xe5c9a638f94a27b2,
+                    0xa268575a25a14bcd,
+                    0x6c6b6a716aa075f7,
+                    0x369f9865d24cb2fb,
+                    0x00891241d9f9920f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2af787f9d747d5ab,
+                    0xf2a7496e4515a1dc,
+                    0xdd9ce0d6fac8da01,
+                    0x4aee1b3a6edb76d8,
+                    0x0e9b59473bbca285,
+                    0xdadbe842c1e0231d,
+                    0x8db794f43a6fb318,
+                    0x684b1ddc847a3bf0,
+                    0x01d0527d17096284,
+                    0x25085d5e76721029,
+                    0xa81cc5142323929f,
+                    0x008b820bddac40b4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf369f056ffc9c444,
+                    0xe78774a54ac53391,
+                    0xfded2636340930d9,
+                    0x47b04b4e387097cd,
+                    0x5ee6c15756abe979,
+                    0xbcf16d5597501e0b,
+                    0x33bd89e97a817048,
+                    0x504b078746335c94,
+                    0x10c90bb58caf51fa,
+                    0xae7ce058d9b3fe72,
+                    0x38838d234a18cf6a,
+                    0x008fac70e4a05860,
+                ])),
+                Felt::new(BigInteger768([
+                    0x389b79f25f99494b,
+                    0x34db5f76e227b5c1,
+                    0x99d48cb5c0764219,
+                    0x204301ada8828d73,
+                    0xa13f9a9bd4eb60a3,
+                    0xccd8f23aef1b7e9a,
+                    0x27bc639fdade28e1,
+                    0x214eda2c01d53496,
+                    0x9e97fe8fdf0d82bc,
+                    0x2b160e693e8bc02c,
+                    0xe695cd94068bc21d,
+                    0x00369f4aa2e68983,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe98235cc77224da7,
+                    0x60d651080619d02c,
+                    0x61dc83e7996334c0,
+                    0xaafef10e22fb0922,
+                    0xa20f39e9f94c3656,
+                    0xb8231b67fafbd1a0,
+                    0x2278a9194e4bd600,
+                    0x11b0eb6b9b72d616,
+                    0x1148a2967c8470ac,
+                    0xf46576a63a90b418,
+                    0x11c886e7390a43c3,
+                    0x00eeb0e96e6915c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc70234cfe5b6bc8,
+                    0x1103a365e2f99e36,
+                    0x755a55c93e9a9e06,
+                    0x9771034c2e3d049a,
+                    0xc4027727e7027f18,
+                    0x36b453dd3e331421,
+                    0x7d5c1308f5e6b2f5,
+                    0x388ddbefead7ceb8,
+                    0xd5a46f06e623d061,
+                    0x261fbeb2ba1a4d69,
+                    0x86538ee26486ac23,
+                    0x00e5a9fd1ef6161a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf4bf98974fe751e2,
+                    0x5c5a9dea5f34c2e7,
+                    0x7b2031474079f038,
+                    0x90beb148998339b1,
+                    0x94f3078fb0b15108,
+                    0xf41d9b11fc168013,
+                    0x65eeb62d684b2ea1,
+                    0xb658cadf4c74cc1e,
+                    0xc355e8d00207b6c7,
+                    0x70b50c21c43b6931,
+                    0x5ce0775c83fc20b9,
+                    0x0085c928948ac382,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeb05e790ef3d218b,
+                    0x34d460a6f2eabd0e,
+                    0x6b2987fe42e020bc,
+                    0x2714752e91717050,
+                    0x754208714544af5f,
+                    0x3f1c5d60154513a5,
+                    0x3deb4f3785e887bd,
+                    0xf781061cef12a4d4,
+                    0x76becc0fcba5126e,
+                    0x39115198dab7924d,
+                    0x2db4bf5ae7633c4b,
+                    0x011167c11f81fccc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87cafe3b83e9c1aa,
+                    0xef1e85c53255303d,
+                    0xab5f923e7586bcdc,
+                    0xd4ce1b241837b810,
+                    0xb144e2635bebe112,
+                    0x9090c4858f4e592e,
+                    0x3616cbf788339edf,
+                    0xe100bca9459a050e,
+                    0x1a45bc1603f9817c,
+                    0x7af44cd1fb0da325,
+                    0xf05419faaab334a3,
+                    0x00b6edf28acd4c8a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ee8749825213a67,
+                    0x774918dfbb66d4aa,
+                    0x66a99521d8d01582,
+                    0x71b6b96cfd9add25,
+                    0x3d0029159614d356,
+                    0xf7c7d7e4364e779d,
+                    0xe18df7dc63b3df1e,
+                    0x68488a6ae334c4ca,
+                    0x30c0fb49e2eab9b9,
+                    0xf489e2272727c577,
+                    0xc29f88b15bfec325,
+                    0x010d8245b5b62381,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xaeca581aca229015,
+                    0x90efabe6b1ac6164,
+                    0x5f13e52654b1b739,
+                    0xcca937e002cdda24,
+                    0x5ff4264643eb6e4b,
+                    0x92b61ba47c073141,
+                    0xa16c5c8dee1932b7,
+                    0xe2c0fcf6c9ede055,
+                    0xd92daa786311566d,
+                    0x2d0970457b83cf09,
+                    0xd434faed23007b12,
+                    0x0059721c63d2a156,
+                ])),
+                Felt::new(BigInteger768([
+                    0xedca19642e3983ad,
+                    0x237e281c9ef465e5,
+                    0x0ec783e8e44f1601,
+                    0x74fee69268e1e4fa,
+                    0x23ca52929ac09ea4,
+                    0x58d2425d991dc362,
+                    0x021a40df86db292a,
+                    0x3413112cb24e5bf7,
+                    0xdd04bef5c786cf2f,
+                    0xd7cbb4629c51e761,
+                    0x8b2ebd1d699e418d,
+                    0x009eb3587622dc6e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcb82c43527d05a51,
+                    0x82c81989ed74a442,
+                    0x0d06531531d8f840,
+                    0x98ce16733b47ad31,
+                    0xff381b6aa6b1a233,
+                    0xc994ac346cfba391,
+                    0xc690a87431ec8ae1,
+                    0x148293b9b187046e,
+                    0xbb46129ffe122b47,
+                    0x91eda0a8bc6cf4e0,
+                    0x5ef932a79610275c,
+                    0x005881b614ee4660,
+                ])),
+                Felt::new(BigInteger768([
+                    0x99d51a62f5119968,
+                    0xf5ac80cb2b909d28,
+                    0xfad26c4ef73833e3,
+                    0x7fa60c91ac2fd7f6,
+                    0x8aff29f2fd5a80d1,
+                    0x8d9b7c3741e50b60,
+                    0x6c55732df5ee1972,
+                    0xc02b32a74ad9d1ee,
+                    0xd33c5384e44bb3cd,
+                    0x4edbec6304af2af6,
+                    0x3cfd42fe33bf28d7,
+                    0x00b92480b23088ca,
+                ])),
+                Felt::new(BigInteger768([
+                    0x928c954cc7f6c780,
+                    0xf0966e033958108a,
+                    0x8405c6a1c5c1daba,
+                    0x3134c596b34dc6fb,
+                    0xe3801a78098de767,
+                    0x3ebe9ac593d0ae6a,
+                    0xc858c89726391ea1,
+                    0xa9ef797aceac2271,
+                    0xd786fb934b9a9b11,
+                    0xcea6885e5275b8f6,
+                    0x8b850cf49d01f5d5,
+                    0x0035208d5cebdf04,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12951474e72e22e4,
+                    0x606015aa7abd8783,
+                    0x956b0fb151cb7218,
+                    0xd0ccbe2580a1b7bb,
+                    0x8dcfa3502a1b3c0d,
+                    0xceaa7ee7641e8f58,
+                    0xfb45fc911a130be6,
+                    0x234fe9f1e10a8cb8,
+                    0x85fcfb1e1e7376e1,
+                    0xb5b3d0f610d29649,
+                    0x9b7aaa81a2a2da6e,
+                    0x0090e70870510aee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x809cbe78ac5fb060,
+                    0xb0da72e36ea83d8d,
+                    0xadc089f280fd8b5b,
+                    0xf228d8b18afc377d,
+                    0xedff9c4f459508fd,
+                    0xacf0080f3647ba35,
+                    0x80a5a6840962aedc,
+                    0xe9c0454ca20cf496,
+                    0x7d9a334e650ed53e,
+                    0x8d19aaa738715102,
+                    0xe2ebeccb82abe25a,
+                    0x00b9ec80ec140cc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c02a78fabb7b4f7,
+                    0xa6a31b045fba7580,
+                    0x0af7e9a790c2efab,
+                    0xb99355556e7ec6e9,
+                    0xdb790633878cea98,
+                    0x87c81ef1480d6a7a,
+                    0x9c8a9d5f68ab5ac9,
+                    0x6c2e051da2a20f8c,
+                    0x461d32b54883f8df,
+                    0x918cc6dee5f05151,
+                    0x6c9324f82dfd6e38,
+                    0x00ab0fe4d4e78dd8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8d9ce3bf22c3dc30,
+                    0x022d9fce000330eb,
+                    0x59da880fcbabcd81,
+                    0xaa9a006fde590a31,
+                    0x148994845777c95b,
+                    0x3f8b322afed15c24,
+                    0x6dc332ee793a045c,
+                    0x1c978e50025198f1,
+                    0xc0c80e34d121a857,
+                    0xa80b476bd645c0f5,
+                    0x483e26038a0aa6b7,
+                    0x005fed08ff65b849,
+                ])),
+                Felt::new(BigInteger768([
+                    0x24464175152d8814,
+                    0x880a6fc9ea548829,
+                    0x601e580cd7465545,
+                    0x894dd34dcdf4a9b6,
+                    0x79c846c326bcdb01,
+                    0xa00dc4054c98b8c1,
+                    0xfad2d940723f6bc8,
+                    0x8db482d0d450dbce,
+                    0xb7cd112eb845d42c,
+                    0xe2a142a199f24d43,
+                    0x3d62d70233c763cb,
+                    0x0091841760e9d320,
+                ])),
+                Felt::new(BigInteger768([
+                    0x49186e27dc5c9cc0,
+                    0x119f4f6e536224bf,
+                    0x0f4a34560c6d2b46,
+                    0x9120c05b42eba580,
+                    0x297778e249ac9248,
+                    0x21f7d68528d6ad4c,
+                    0xd91bca3b34d673f0,
+                    0x93720b33eb90b7fe,
+                    0x120deece1a59a8a3,
+                    0xa3324a96781b5db8,
+                    0x8d8c6c5d63eae764,
+                    0x00fc6aa2030e0c63,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7acad407805b5e24,
+                    0x5684e81fd2f3d72e,
+                    0x05497009ac0e1906,
+                    0xabadcf47e1a7ea57,
+                    0x988011af0b51ef42,
+                    0x6e85592ba101985a,
+                    0x10e674d1a8ac144e,
+                    0x38d45d2b220493a4,
+                    0x4589a69cab39dc66,
+                    0xc9e0317ff5bf9bce,
+                    0x20d573ad14b151d3,
+                    0x0061c6dad4272dd6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc3235b1ba1769c3d,
+                    0xadfda1e72e21c415,
+                    0x0e55bd4a34326d94,
+                    0x70bf1e5d7d316b9b,
+                    0xc90ae5c459bab569,
+                    0x4c48b3507a38d675,
+                    0x1638bf6ba4f1b488,
+                    0x9072e63341b85178,
+                    0x67bd4dc3065add6d,
+                    0x828f28ba24e890d0,
+                    0x4abbde5333429b2f,
+                    0x00fe682c89989780,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d34db032c527abf,
+                    0xc5978f6075b3dbfe,
+                    0x8e63412b48dc330a,
+                    0x0bf5995e99dd514a,
+                    0x95a3fb7e2e8c19d1,
+                    0xc6176c0bec876660,
+                    0x3bb7c24fcf5a9b05,
+                    0x9b5cda6eb72c7555,
+                    0xb41a79bb19c6dcdb,
+                    0x5df2d38a4c149bef,
+                    0x7449cce8d3bb8b78,
+                    0x00fd9ce7d4778443,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc016bf1dbe889d85,
+                    0xad6776a0754a9af6,
+                    0x1877d92d2fc49b08,
+                    0xcb13aea54c5968be,
+                    0x914ec235207e121c,
+                    0x705926672884bcd8,
+                    0xbebffb0b8900d3ad,
+                    0x609dbed63e8048f5,
+                    0xfd27ac6397d60358,
+                    0x3de27eba45fe19b1,
+                    0x94d270d73317723d,
+                    0x00444d61fb620a8e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2868f9e4243d27e,
+                    0x35713c6c097c4e85,
+                    0x7a95bfa6639666ed,
+                    0x39dce9ed2074463d,
+                    0xb5cde24963c456b8,
+                    0x777b3ada4bcb8914,
+                    0xf7def2bd0a512aab,
+                    0xc3672d17d4d79640,
+                    0x66769b8502464c60,
+                    0x842ae9877113dad9,
+                    0x54d145e2598977ba,
+                    0x00c194f23a1359ae,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf766e3fcd1a6df52,
+                    0x25155a23c07f6785,
+                    0xb879a126aeddd7be,
+                    0x95b31dda4c5dbd5e,
+                    0x0fda525fe056b959,
+                    0x175f53f4e93c7013,
+                    0x0dd8e6884567b78f,
+                    0x2cc4bd9686cbfbe9,
+                    0xe1cd937e978d92fd,
+                    0x6bf1ca51250d70f1,
+                    0x30d0446b7e6ba3cf,
+                    0x01097388ec290765,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d508aaae156b97e,
+                    0x68e2e02498b02be1,
+                    0x123de8684f3549e6,
+                    0xfc95a885646a950f,
+                    0xdf129ccee64d81f8,
+                    0xbec39ba948f122ed,
+                    0x399843109b5cdee4,
+                    0x9a168527f9bfa976,
+                    0x374762cc2ff16922,
+                    0x4467ca17388990d1,
+                    0xb54ae68062bc0ab4,
+                    0x00b61057b6b6d99d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1cd69a1290b65b4e,
+                    0xeffcc362ea77360f,
+                    0x7dfeba909c2fbbcb,
+                    0x1e652e609104e207,
+                    0x766dff616a3c5725,
+                    0xcdb3e1d1a71d8ff5,
+                    0xdce46f65dad04f1e,
+                    0x4af595cff522223d,
+                    0x8858292f95e3c710,
+                    0x9a2091c7cd839c7d,
+                    0x157615aa2d5ebcd3,
+                    0x00638bac35afd2c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0197731f8089799e,
+                    0x5d6f461625852960,
+                    0xcc991a954e5bc969,
+                    0xf9025b3cac7d5499,
+                    0x8200e64fc38c48d8,
+                    0x9eab27b503d818b9,
+                    0x735013e881831e13,
+                    0x2ad1b708285392cb,
+                    0x42f0907cd16dc139,
+                    0x2901790221df8544,
+                    0xa8049bfbcd9eb06d,
+                    0x00f3f87750a856a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5744afc17f17952,
+                    0x56f738ad1d0a96b1,
+                    0x2dc2030c82dbb640,
+                    0x255629cb24bfdf0d,
+                    0x16fdc66363ee0214,
+                    0x3de7adae8419eb0f,
+                    0xf4374aee70069153,
+                    0x28e78c2a4c13c81d,
+                    0xa4c8b6525e9ca321,
+                    0x9dcfd5c4adbf6e8d,
+                    0x4fba9a39aee07a44,
+                    0x00f0c61d1b4786b9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff47b154a40ba5e1,
+                    0x520e5a005bdde462,
+                    0x96be4eca43fdacb8,
+                    0xb39f334369aa5a8b,
+                    0x0e4fb2fcc0e785ac,
+                    0xb55ac335212f7353,
+                    0x9aa6c8f1e061d3b7,
+                    0xfba163c2c5f4e980,
+                    0x98097aebb0af372f,
+                    0x7ed198ae00680221,
+                    0x53decef746b1af36,
+                    0x007ffef9b852afff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2fb2397de3766919,
+                    0x0b6ec1945fcca155,
+                    0x632469d20e9cbc5c,
+                    0x98e44885213656a7,
+                    0x39ceafcb31abc8d9,
+                    0xf914a0f24112563e,
+                    0x65b42cdfbcf02e16,
+                    0x26b0134195a445bb,
+                    0xcedbb839bbd53e40,
+                    0x434544196abfcda4,
+                    0x0396deeb7c8c9834,
+                    0x00869a87b0458fed,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4400b7faa3482f3,
+                    0xbd60f286559769dc,
+                    0x1e5e14569aaaae5f,
+                    0xde2938bb94494818,
+                    0x695213ed010cf73a,
+                    0xbf9ac85ea0def529,
+                    0x7844b20eac6cbd99,
+                    0x81ba0c34a21ea4b9,
+                    0x33b65481360e6209,
+                    0x368c44c8cc3355b9,
+                    0x35f9ca562fda08a0,
+                    0x008eb70541361a61,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xce43f96b058da230,
+                    0x6eabdb55063aa8c8,
+                    0x8abd76aabf8c3f26,
+                    0x572b5790a37febf9,
+                    0x947cb9bda6da608c,
+                    0xa2245ad3829b76de,
+                    0xb34407907ef180ac,
+                    0xd29f6a711a8f8b38,
+                    0xf2f076b1fa66b28f,
+                    0x4cdaa13578a063cb,
+                    0xbcbf4dce7c3b0f00,
+                    0x005f6f74730b3b92,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26f843ad27057b55,
+                    0x385c322fc430b142,
+                    0xe380c299148b0917,
+                    0x5ae49d88ea54782a,
+                    0xec34ef44c735be63,
+                    0xdb6f3152b79d3a7d,
+                    0xec919d8207b81b6a,
+                    0xa4041bab9a5de06e,
+                    0xb4241b5b6909331b,
+                    0xeae1e037ad701dc2,
+                    0x84448aed165682e4,
+                    0x00cdc14a6767de16,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd05ae9dd8b33f2d3,
+                    0xcfda26efb6ee869e,
+                    0x1dbb36c25f064518,
+                    0x29cbd7688a3359b7,
+                    0xc6489fccb04131d5,
+                    0x74f5826721e49028,
+                    0x529bf8df0d6439f8,
+                    0x7532307c766321ab,
+                    0x149fbd6fb42b912d,
+                    0xbe9f7541760b7fcd,
+                    0xa404ce4145ac7840,
+                    0x010b6a38895c504b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d868bad1bd7d121,
+                    0xcf04d93d78897acc,
+                    0xa9243de11eb7d471,
+                    0xe104aa6c76ed59d6,
+                    0xb2e0b174c647fa2a,
+                    0xc939947707617a40,
+                    0xb23d6f2e11f6f250,
+                    0x5c0d1f24267b3410,
+                    0xef102f787afba375,
+                    0x61890a801ee9e0c4,
+                    0x46d4b259d727cbc9,
+                    0x0010c086d8e172c8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2e12687713fe3318,
+                    0xee50c1869b211f11,
+                    0xa286bf4a4051ccd7,
+                    0xcdd77956627480cf,
+                    0x93bba30c41a5f1db,
+                    0x882875273af56596,
+                    0x2a6ad10d8c73e970,
+                    0xb1b7972144dbe5ed,
+                    0xea94bc2cf0cfd6cf,
+                    0xb925b7eb6897f831,
+                    0xbb0f837bf61fe245,
+                    0x00370b96601992ad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9fb87e246aeb8318,
+                    0x18525040ed2497e2,
+                    0xc976e188e74306ed,
+                    0xc9e18a966cf37ea8,
+                    0xc4a9d7953b7637f5,
+                    0xe9ad759b3d016640,
+                    0xa69f17fde3068d41,
+                    0x467cc44c162d3f82,
+                    0x85cb7353adcad4b9,
+                    0x4f80a22bcedae124,
+                    0xf149f426c1468da9,
+                    0x00875714412999d6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf4f92aa43b7d67ab,
+                    0x667a9fc1e6921947,
+                    0xfb224fefbb96a009,
+                    0x268c26abe261420e,
+                    0xcfb99d4c3c7a8dd2,
+                    0x32ff7c41fa637521,
+                    0x224ec45a63073bce,
+                    0x2b21b7e7ecceeb03,
+                    0x0654550e77764d67,
+                    0xaccb59ea4edb7038,
+                    0xee5e6780396d1f72,
+                    0x010977b192357bfe,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58b79ce09bb81aa3,
+                    0x6ec0db02becc667d,
+                    0x586e91804bfb6f75,
+                    0x80c44f8ba712ed88,
+                    0xffe965c97cf762ac,
+                    0xe3c2ddaee4e3f48f,
+                    0xa68a6392697cf554,
+                    0x05a97fdf5530fa6a,
+                    0xf9cc5563b6d2427f,
+                    0xd2daddaaf205bb8a,
+                    0xbd27d1a883631e20,
+                    0x0107ad58bdc4fbfa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe49f46789574e12b,
+                    0x8d82f36d7ff723fc,
+                    0x3b768b768de0d60f,
+                    0xfb54d1dc6fe61de9,
+                    0x6454163cad759e02,
+                    0xce310e45edf5f61c,
+                    0xe25f606ce1aac8fe,
+                    0xc083aedb1ec01693,
+                    0xcf24680d36329a44,
+                    0xf8b40b2aff59a661,
+                    0x4acdcde09280d1c7,
+                    0x00a3c00e27857fd8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdadf11e36c04ac2b,
+                    0xcd5a19d38d3b839a,
+                    0x997e70f835cf12bf,
+                    0xd99163a09d189955,
+                    0x358456a8052b9889,
+                    0x20de68f0c6e8f2f0,
+                    0x131d5a1ff7c73fb5,
+                    0x1b69c031de8441a0,
+                    0xf5ed44cf02b4122f,
+                    0xcd8997eb72c41048,
+                    0xacda383f2e63a3e6,
+                    0x00176b9676e1b705,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c0d885c04430a55,
+                    0x358e046061b0ea5f,
+                    0xfbc2a9971c2430fb,
+                    0x6ef11a8e33fa0e9f,
+                    0x03d2e78267868473,
+                    0x8eef5ecddc3df112,
+                    0x59a0e2a2923334ad,
+                    0xb5ea7bf83606159a,
+                    0x61d1691e991b691e,
+                    0x0b2244cd4bec7486,
+                    0x83f2e4e6634efd82,
+                    0x0073f35488a82819,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05ea1ea004d68d73,
+                    0x4514337006cd0849,
+                    0xe8faf132b3b997df,
+                    0x98fd9cd9abea827a,
+                    0x90843cf824134d6b,
+                    0x3766f2d009f36cb3,
+                    0xc226020e35fad15e,
+                    0x15d4d1f4435c6ab4,
+                    0x05acab47f0fb48bb,
+                    0xfaf391e918558dc9,
+                    0xa3ec9b566d6e8504,
+                    0x00a555b95c972635,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe3a75c9d3cf29ba3,
+                    0x6d56609a43ee7cf7,
+                    0xe9df49ec772b314a,
+                    0x52a73860c181a842,
+                    0xcbffca5ead6cef0e,
+                    0xf57b2e17f4bf7c0d,
+                    0x1fa8ddb777644587,
+                    0x6d1679c672a3c34e,
+                    0x79d33b3b14e3d473,
+                    0x1778eec8a80f2bcc,
+                    0xfeed39406130afd0,
+                    0x01157b2482a7dbf3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x74b4a2bdb1cb3352,
+                    0x53c87edb36118d8f,
+                    0x4d83ef40814e7158,
+                    0xd29c63245a7d29bf,
+                    0x775e90e2e2ae6842,
+                    0xc1b875629fa83f05,
+                    0xb856aeb1fe5e4629,
+                    0x4944ac0b1cc021a3,
+                    0x71d4deebb3ccd7df,
+                    0x5a94ba3490c1dbd7,
+                    0xb61f517cb527b769,
+                    0x002ec279600f9a16,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3f4c3cdec9e29507,
+                    0xdff0bc0627ba060f,
+                    0xf0e6a6bab86667c5,
+                    0x22d69c72e3dee783,
+                    0xefc85b6c6c4eb213,
+                    0x25c3a1f0eb79ce9c,
+                    0x79a1b9c351035c63,
+                    0xfa60e13aa27bc094,
+                    0x5a74c74a3be568b3,
+                    0x31358d075e461443,
+                    0xed8c6fb58b653912,
+                    0x0087b81eba78be84,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa046a90f68493d72,
+                    0xd4b8fbc13df2de0a,
+                    0x075e0bf99522cd6c,
+                    0x70085fa6bfcef4d5,
+                    0x57093252d9af57a2,
+                    0x24aedaa21c9dce4c,
+                    0xd7c13a56b1c11dcb,
+                    0x41cb87bbdddc278c,
+                    0x6ab9b4d57c61dac1,
+                    0x1541db14545879ad,
+                    0x2df9efe406209ea5,
+                    0x0033eab9b268a19f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x30308567c4409608,
+                    0x58387f7b3d1301d9,
+                    0xf9cfac705f0f05e1,
+                    0x25aaacdf5d858912,
+                    0x3176bfff923c6455,
+                    0x641ff6405fdc779c,
+                    0xcbcedcf5ece897d4,
+                    0x2a2c83853cdfca08,
+                    0xb32b2b9d7ecf90e1,
+                    0xf4d04e7be1da6d1b,
+                    0x36686ae651df31ba,
+                    0x00821bbc758118fd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x80ec1ce58f6ad09e,
+                    0x4170c8eb2dc94bbf,
+                    0x62ea263910972601,
+                    0xf723738deddd0e28,
+                    0x1551815d08d2b6c9,
+                    0xb3b76bb487f66cda,
+                    0x8efcdf0f426fd2a7,
+                    0x6f0e9c4d70196abd,
+                    0x639bd37203c2e068,
+                    0xe6e1a2fdd7cbfc55,
+                    0x6a0630490963a266,
+                    0x011a6fcb9f9deee9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x608bdb78220c8ef7,
+                    0xc0d366ec67a98ab3,
+                    0x5c04d5fbc0bf2982,
+                    0x41b40d3e4d10490e,
+                    0x29c840c280b670a5,
+                    0xdc7a6f66881c1708,
+                    0xdc92f814d4f9d201,
+                    0xc732324d727bdb26,
+                    0xbe55198ce10ae411,
+                    0x97f3b8108e1f9942,
+                    0xe23ea8bd053bf7d7,
+                    0x00bffa54c8518318,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd3ecbe99bdafcea4,
+                    0x67ac74e4673b1372,
+                    0x90a7b2eb39a7ea87,
+                    0xae9c1c292a73958d,
+                    0x417967f5316e7586,
+                    0xad31cd290438ef2b,
+                    0x094ad5b45eb34878,
+                    0x6db0307778edd1fb,
+                    0x899d5d1cf860177a,
+                    0x95da733b873230e8,
+                    0x0468fa19c98bf91a,
+                    0x00b2ab592a61b9ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a56b272f17e80ea,
+                    0x477a9cdcf432c008,
+                    0xf26ebd25e410c250,
+                    0x766e660e244533dc,
+                    0xf05c0443e42820e4,
+                    0xe01364312d24355f,
+                    0x8191d4205aa40172,
+                    0xcff59ca743620eba,
+                    0x67c835954c0aa173,
+                    0x0946e9a3068a98e0,
+                    0xa43ece7fef932ae4,
+                    0x00e8fe3e5dc9b704,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd3da4c7e34c76d2,
+                    0xed0f8a64f43ebbbf,
+                    0xbc16a68ce8bf29cc,
+                    0xc7b44f48ff3889f0,
+                    0x51cfb41c1942b956,
+                    0x0730278c90e6a520,
+                    0xedb16e58a6b29a8d,
+                    0xa0848a4df6717c9c,
+                    0x0cc609fb57f6c31e,
+                    0xef6255f26d2bdc7f,
+                    0x42c78637a316bc40,
+                    0x00582e7e37abd752,
+                ])),
+                Felt::new(BigInteger768([
+                    0x85c984dc30dd8200,
+                    0xdce4265788724b39,
+                    0xc7c42df5929c826e,
+                    0x70c1118c7475569b,
+                    0xb1fea018684381d9,
+                    0x7b38c7e6b75eee0e,
+                    0x36b8271621ff9fe5,
+                    0xe5e113cc47b483e0,
+                    0x798b453142ac3c18,
+                    0x098fc428bf211623,
+                    0x3711fd94c780cf40,
+                    0x009f9d02e69a3e7f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ebd822975dd019e,
+                    0x6fced2007d22fd0b,
+                    0x7ee0bb1b283153aa,
+                    0x036485f67bb9f747,
+                    0xfb379d5135c73f57,
+                    0xb1676d7f5d88d766,
+                    0xc68bfadac92c5888,
+                    0x9208bf7858a3724c,
+                    0x9a7ce02c2937ae0d,
+                    0x2c624cdae7a4515a,
+                    0x04d3f36591d53bc0,
+                    0x00c80ac2b0294a27,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x154d629e73ec71e1,
+                    0x108e2be0619f1d4e,
+                    0x60bfebbcd998c1af,
+                    0xb875ceb70b2e27bc,
+                    0x583465ef486ad5ff,
+                    0xf15aa3e35485cd89,
+                    0x6eb48fa510aff109,
+                    0xdd10003ef64605d8,
+                    0x4d0974b8a30e241d,
+                    0x06d44c5f86a0a3f8,
+                    0x2a7510a6fb4c44a8,
+                    0x00bd8f69b13f28d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x88c847f50815ba51,
+                    0xf05cad2f5d242d9f,
+                    0xa989edd110726403,
+                    0x8c179bf29d222d02,
+                    0x0f4a98bd4093a9de,
+                    0x1f936bd3b4b3b537,
+                    0x46e0276c17e002c8,
+                    0x4642c5be0e2aae9a,
+                    0xe7c6b0d26c86c0ac,
+                    0xce1a441f4334b275,
+                    0x0e7ceb590f63de82,
+                    0x00d12587e850e407,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1df818c62e795de5,
+                    0x8a6fb4a9173675d2,
+                    0x2620c82217b16152,
+                    0x140580e0c17800ad,
+                    0x95ae92f07eca0096,
+                    0xa0123ba79a21662b,
+                    0x1377c0c132e80b37,
+                    0xf552b21a5c4e07a9,
+                    0xf30dc5c14905fa20,
+                    0x1a2592bdf8778196,
+                    0x7af1eff1da386a8f,
+                    0x0049ef3858730fb3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x512444236b9ef2c0,
+                    0xbe45979d2f96bb31,
+                    0x8e08eb04562b14a9,
+                    0x0188858b94fbbf5b,
+                    0x54c8f8a4f0cf655f,
+                    0x8d1efcb9d2bd9596,
+                    0xe5dba5c5c124d4b9,
+                    0x9ba06ea7e469c608,
+                    0x6e0e461b07ee34f1,
+                    0xb1c6739acde22f6c,
+                    0x5941bfa22e5904fb,
+                    0x0065edb1b4bb551f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0a2cc8202f34e57e,
+                    0x654138e276358e32,
+                    0xe7d38b8ba9a617e5,
+                    0xf49b5f9f1ee20925,
+                    0x57642bb2b060fec8,
+                    0x82dac9250166cb2b,
+                    0xeb9fff315d244faf,
+                    0x0873a6841500d60d,
+                    0xdb69473f1e995566,
+                    0xf61673bc9e7403dd,
+                    0x1f4f76d6f92ba8f2,
+                    0x010778fd2a62beb1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72cf05e37da883f3,
+                    0x10c57de47c83f1ac,
+                    0x745faaf5e537e3dd,
+                    0xe6343f3ba48e60d1,
+                    0x5e14e0a3fd7a453b,
+                    0x6fc1d39221ce1792,
+                    0x6c63a77fcca3669a,
+                    0xfc03544923c3f9c9,
+                    0x70ba76c03deac325,
+                    0x00b8009ccc0dc55e,
+                    0x541c27f230f00d8a,
+                    0x00857051aeb76f17,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb063950d07678ad4,
+                    0xbf893ffbeac49a57,
+                    0xccb791982891bdfe,
+                    0x5ec7beaaf9ca018e,
+                    0xf7630f86556243a6,
+                    0x8724a5c5fd904b65,
+                    0xa97769a8bab9332c,
+                    0x338ee75a65230281,
+                    0x3a12e99628eb6712,
+                    0xc7aa47ddc6de391c,
+                    0x7370bf97ba3d90b7,
+                    0x00ceb17c9a1caf5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe174400c52ffb721,
+                    0xaf6ebc870e998324,
+                    0x22d11c56c5dd8c6b,
+                    0x4d5f3ac2e5efbb0b,
+                    0x90d4e05730b04820,
+                    0x04da4c19211ab72e,
+                    0xba97580b25f2d590,
+                    0x384a87fa1bf9becb,
+                    0x5b6253b4787bc4a6,
+                    0x676f473971326565,
+                    0xa4180b622149b825,
+                    0x0006975820100ead,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4e791bda2791afa,
+                    0x296c884c9bd8a819,
+                    0x0d5b5b252b826848,
+                    0x21bf71b7d640dd9c,
+                    0xd50368e567b64cc0,
+                    0x82236a2544f25607,
+                    0x6336abfefa7b1e2c,
+                    0x778490bdc95508c1,
+                    0x146787f7b6c810ae,
+                    0x9267612bc408a15f,
+                    0x8f6c35ea7fba7fd4,
+                    0x00cf87e9608a9ae3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x82f2ca1d4ab7ac6f,
+                    0xe0de4c990af942d6,
+                    0x0ca5622dcb1a192f,
+                    0xb82b403671df73af,
+                    0x70175e9adf9bf246,
+                    0xc060ca07ebc34d0c,
+                    0xc01c1956bbc6aea0,
+                    0x819e9291ba2a2838,
+                    0x396d84af2e8f69e5,
+                    0x5f33457f084bff44,
+                    0x95edd75614e6e11c,
+                    0x009cef2dccc2a9aa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x43c589d8aa10a14e,
+                    0xae0223977bf767f9,
+                    0xf143647ff66fccbe,
+                    0x9078114cb7bc99ee,
+                    0x641cbee359db591a,
+                    0x18cbeea97551adea,
+                    0x05cdc9dd0318f961,
+                    0x9f271fec56dcabdc,
+                    0x21561d4b2898d906,
+                    0xd0fe9f850180c775,
+                    0x121ddd0627c9382d,
+                    0x0022313b8bb7646c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90631d91b508ac76,
+                    0xf3864873764d29b8,
+                    0x38f336459ba3b488,
+                    0xfae85bf47c55a413,
+                    0xe8272277aef2fcf3,
+                    0xc5ea521a4691205b,
+                    0x6ba53e5750c8e394,
+                    0x1d8235788af9b3ba,
+                    0xb1b181986b60895e,
+                    0xec676c49d708a3e0,
+                    0x37d546e619bf7eb2,
+                    0x01013fc6e020c36a,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3060ed86d94a3832,
+                    0x59a93f5c3bac3d6d,
+                    0x84b6e824c48004f5,
+                    0x7b45cf6b19cfa55c,
+                    0x3511ac71fcd878cd,
+                    0x0695a54e59355952,
+                    0xeef6b2d415e8bf48,
+                    0xb98a5390b72242b6,
+                    0x80cae00272a1054d,
+                    0x28b7c1f2722eae6c,
+                    0x6071ba1c48211bad,
+                    0x0082adbd6e8f4986,
+                ])),
+                Felt::new(BigInteger768([
+                    0xacb810d342fb0215,
+                    0x7999de9358486642,
+                    0x7d9ab56abc4289ff,
+                    0xd4657665f56bc5a2,
+                    0x298940e2e6ba843c,
+                    0x5a6aff1a580dd68d,
+                    0x3d7020d25609c9ff,
+                    0x80109aa3d6a9dccc,
+                    0x2b4f210dcab78b83,
+                    0x42af3b1b0e84f418,
+                    0xe5fde2be13f483e9,
+                    0x00fe34e5f6241e2a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaaa12060bd54b172,
+                    0x186b92f8a794886c,
+                    0xea9cf5531cc8840d,
+                    0x01c4f68e0eb857e4,
+                    0xf7bd5ad3dd53b2f4,
+                    0x8799d786b8c4bd42,
+                    0x9e182f83c79710b3,
+                    0xdd7dd4987ad64659,
+                    0x099fda5010f2ace5,
+                    0xd0ea670f1119d817,
+                    0xfeb8624b4a831aa8,
+                    0x00523a21dfd30971,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8de65a90fa6d8f38,
+                    0x69c1e224b82464f7,
+                    0x966ae05c196f4692,
+                    0x3dd4df1ca2b165a3,
+                    0x26a2013bdf53fcdb,
+                    0x5749c988f10c0836,
+                    0x38c91db19892fd81,
+                    0xff4f40e372ada463,
+                    0xc037c05470a58546,
+                    0xe666d103e38f2580,
+                    0x256ecc6824229897,
+                    0x003bd346f096dc2e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x08d7fdf5b6995872,
+                    0xa4acc4427958ac8c,
+                    0xbe461c9565047ca8,
+                    0x59c258c5bd42b325,
+                    0xbe26dd8b09ac1e27,
+                    0x044913b086b9c058,
+                    0x3584c76793685b54,
+                    0xb04e99b5a7ff8d91,
+                    0x4e808e719491f273,
+                    0xcbb86f1775469ae0,
+                    0x3a02dc5665b60781,
+                    0x00dfe3b853061215,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1963dabfaebfba69,
+                    0xc3f8ac8b9bf050d1,
+                    0xb7a89dfe869e750e,
+                    0x469527afa8c9da1e,
+                    0x9f01cf22850a15d8,
+                    0xc337f460ba4dabae,
+                    0xadb229470de55273,
+                    0x05f911b2c6603f6d,
+                    0x4a051b260a6625ce,
+                    0x23551c82bef4b24d,
+                    0xae3dad7884ae1dfe,
+                    0x01062ec7da3e31fa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1e6ff0370e8ef26e,
+                    0xc9e35c86c2681bbd,
+                    0x8df8484891ae9ca8,
+                    0xeb41c758ede2c098,
+                    0x62c25d15f72bea42,
+                    0x46a390a53f0e4278,
+                    0xa18b2aec7b0e54a5,
+                    0x962f750bfc4571b2,
+                    0xddaa3f093d26423b,
+                    0x259a92d6538e9eae,
+                    0x5105838ace45f597,
+                    0x0060cbdf0162e94a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2db55476021f438e,
+                    0x231ee4cec54cc6b9,
+                    0x9e6406ff4d72732e,
+                    0x6cb7ec6bbc711979,
+                    0xb49187d62c852e27,
+                    0xa610e4a2953f5ac9,
+                    0x85fa72c844717d17,
+                    0xd0db490bb4764063,
+                    0xf9648ead999e47ec,
+                    0xfec21d163bf1eb75,
+                    0x9e124269d7dbe2a5,
+                    0x0112d4dca7fddb94,
+                ])),
+                Felt::new(BigInteger768([
+                    0x497238a1a827c983,
+                    0x158552babbe7bb81,
+                    0x4802d2c9903798f0,
+                    0xff4e2a78d88494a7,
+                    0xae1a3b09479e1ec7,
+                    0x845589b18eb2dc3e,
+                    0xc21b1bb6a019cce2,
+                    0xcafdabdf9d002bb9,
+                    0xde1205bf5a5a3ccd,
+                    0xf9b3536b09a26ec3,
+                    0x701b12c979cd3111,
+                    0x0055a45b172a09b0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9175ed5e55adafdd,
+                    0x80aa113af3925131,
+                    0x7ca44f82d3b21aa0,
+                    0x4142e9e17253286b,
+                    0x58d04fbe8251a606,
+                    0x39bcb831cd741d99,
+                    0x00c342578881e87d,
+                    0xa8da3a00b5f19e32,
+                    0x67e3c931c35a3e21,
+                    0x12aaf73e50c48f26,
+                    0x9344604718906ac2,
+                    0x0001a230642d277d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1318cac2e122ef93,
+                    0x2ec52d0f12f4f95b,
+                    0x01ad840b508b4a22,
+                    0x227067e6c3ae1ace,
+                    0x8d82fdee220503c4,
+                    0x81895a9261094615,
+                    0x3e32ddf192086271,
+                    0x9217281750e61a76,
+                    0x00d628150ef717f6,
+                    0xe802ee22c7f8dc59,
+                    0xb00c277b2889cb15,
+                    0x00253815d77eee4f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8447413b79614dbd,
+                    0x96f9179be76a601f,
+                    0x63f1bcaa36e0685d,
+                    0xb7d01ed45a8f9018,
+                    0x5bd18ec8845fefad,
+                    0xef9a506f765e4437,
+                    0x84b666742c745769,
+                    0x2c97e3dda867ee70,
+                    0x6cba4c64a55e6ee6,
+                    0x259b9b04a1214096,
+                    0x8724d4225d7524b1,
+                    0x0081bfd3e9455f63,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0b1b6f31c64ac324,
+                    0xa158db1ab90b1499,
+                    0x85a1de7a6440aba9,
+                    0xd0918695f1003533,
+                    0xe343b2d260314eec,
+                    0xf94dc1c8f46c28c3,
+                    0x74fb8925c0c49fd8,
+                    0xbdbc242ac60b08e7,
+                    0xa4a30ebda409964f,
+                    0x5007cbfa9c7c81e5,
+                    0x356d275e66fd7515,
+                    0x007abbaf29623156,
+                ])),
+                Felt::new(BigInteger768([
+                    0xedba79188282f7ed,
+                    0xb3dddab0e974159f,
+                    0xed169544936529be,
+                    0x39aea6e636ed4f19,
+                    0x3f356a6180e0bd1a,
+                    0xf5a7558245675d2c,
+                    0xc3a589f1cc60fe6e,
+                    0xca259bd5095193e8,
+                    0xfb8cbcdc3f6c0137,
+                    0x622c9236f1baa319,
+                    0xed15d7a8232cdae0,
+                    0x000ddd9160a156fe,
+                ])),
+                Felt::new(BigInteger768([
+                    0x921598d6f592fe89,
+                    0x41437f4505a88c88,
+                    0xe60f38765229f343,
+                    0x697f42d414900fd8,
+                    0x1b6cd953c1fefa15,
+                    0xc55ee1a94ead0a9d,
+                    0x69acade5f2c1e793,
+                    0x3cdb3460d97996a2,
+                    0x1aeb30cd186a6cf7,
+                    0x486ff3f83b83baa3,
+                    0xfe76a132f8f8519e,
+                    0x009ba09728d0746d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0917a84b150849f6,
+                    0xfb22bdcfbedaea65,
+                    0x3a595e19174b577b,
+                    0x86fa6b9b990b35f0,
+                    0xcb17b09b64bdec53,
+                    0x41104c81d7714372,
+                    0x720faef6d304ad7d,
+                    0xd1ff719ef5436d4c,
+                    0x184588ebf447a6cc,
+                    0x92cbeed976c223a0,
+                    0xf41dae28076d1509,
+                    0x00f6b2e4205382a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd0cf46c243de0ff,
+                    0x5c8a018076c2092a,
+                    0x5568f1edaefb2284,
+                    0x643dc8f87498d7ed,
+                    0x82fdd225da81cb35,
+                    0xdaab4e4c0e14837e,
+                    0x067f75b36d26830d,
+                    0xac568911832c9ac7,
+                    0x99b7f2caface688a,
+                    0x4d0f8f37e0861643,
+                    0x2adab1b76289122d,
+                    0x00e178d67e6c7d1a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9859d0c48035390a,
+                    0xf97846d205e22c76,
+                    0x31cd99b58df6b6c0,
+                    0x813eff6ebbe7c333,
+                    0xeb8e8bf61f22d8b9,
+                    0x9392a0fd3e592f3d,
+                    0xf6be5d538299da12,
+                    0x243bdea1fcab5429,
+                    0x8fa6f3a37cb46069,
+                    0x7377ea34ec36c329,
+                    0x61c16379a94ca604,
+                    0x000cc1b44601266d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa220685af850fc1b,
+                    0xe651869ebacff199,
+                    0x23ce89947c551d20,
+                    0x747f1b0524d5686f,
+                    0xc872e796905a2d69,
+                    0xc8b4243bd811de21,
+                    0x147e4e5756bc36c4,
+                    0x713904720473eb3b,
+                    0xb1eb72c57d81af05,
+                    0x94f1f6ac3debad30,
+                    0x51c23ad0940d6452,
+                    0x0121f47eaea4301c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9febfef7c2b95e59,
+                    0xea5298b9d74a1d30,
+                    0xa57011d4eca7503a,
+                    0xabeac7ff133ef465,
+                    0x68400986929d2997,
+                    0x3cbf7317c0a975d2,
+                    0xe164000b9d8cd757,
+                    0xf3d64fa1a723f864,
+                    0x727df5f57070c1f0,
+                    0xce0fcdbd1ec46edb,
+                    0x8c15ad56a3f8483c,
+                    0x00fed1e0b73f0efb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd62127fcf3476c66,
+                    0x63ab62c94ac0e7db,
+                    0x0f062aaac955b76d,
+                    0x77306f2f3c0a0997,
+                    0xa1ac46052f2b7958,
+                    0x39267e4d1ba1577a,
+                    0x3c65240017e2a7e6,
+                    0xeb12e547d63dc4d1,
+                    0xc90ad9ed0571048e,
+                    0x5cbc9c2aa6596f3d,
+                    0xca158793b9a11530,
+                    0x00d5cdf721c6db0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x09fbff15b25aea73,
+                    0x19f628fca336106e,
+                    0xc84a4e95d9726bb6,
+                    0xe2e1642fd38c9e04,
+                    0xb10c1e595e95f35b,
+                    0xa504e111fd6b9842,
+                    0x285c8a29b2269ed2,
+                    0xd3c6a71c6286b879,
+                    0x35e96be3778970ef,
+                    0xebc651ece71755a9,
+                    0xfdc9230ac2aae086,
+                    0x0046b4d71870217a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a6550956d5370b7,
+                    0x4040ceb97e12bf41,
+                    0xa8c53d7ba15b857b,
+                    0x6aed1f529838b8cc,
+                    0xdef8d1c55ca434af,
+                    0xb476bb9223bb1225,
+                    0xd4d843a312dd0a83,
+                    0x487741a7a7b6064d,
+                    0xdb11c3649b49b222,
+                    0x5b90a47dff4bd9f3,
+                    0xefed34299a888443,
+                    0x00f51c9d0a97693f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfe5430a9bae47b5a,
+                    0x49842c43a2464e37,
+                    0x9e65cc35604839e5,
+                    0x7b58cad3b70ddc7f,
+                    0xcd4ae9e537b8fe03,
+                    0xa1f592ff37395d83,
+                    0x1a1041616875ecd7,
+                    0x1d5824ed13742478,
+                    0xf55488ab05776cdb,
+                    0xbc97fa49cf6fcd0f,
+                    0x12afe246b9f79803,
+                    0x0019a2fcbe020262,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x44c1ba22ef472802,
+                    0x0da2939277a4e577,
+                    0xfc051f61237ee061,
+                    0x27a64672ffddab56,
+                    0x835d07c73a6e3014,
+                    0x403cafad47bb57a4,
+                    0xc1f8e7a1718b8e7f,
+                    0x6bf7ea1f55581bd3,
+                    0xb084fe71af2be0a4,
+                    0x059598383b094f8a,
+                    0x8a1a91638100ee6c,
+                    0x007ee8ff12dc2389,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe568dc1fd8f4e771,
+                    0x2f3903f97d30360e,
+                    0x407e71232e7d4420,
+                    0xd3f755a8d4d8ad21,
+                    0x20ff50b112a681db,
+                    0xca88be192a825187,
+                    0xd8b57d9468abe73c,
+                    0x9d7c83f8d787daab,
+                    0xc8693b1e21191aca,
+                    0x50139d9038f31313,
+                    0x5d4e12a3ba25f90a,
+                    0x00ffab3d4ef25e3b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe82125f81e67fae2,
+                    0xcec63cc85798dd07,
+                    0x65109c0f05740044,
+                    0xdfc68cbcc8272b38,
+                    0x71a84fd3685b5816,
+                    0x95475d67d1ecbb54,
+                    0x3023917920bfaa0a,
+                    0x14be2be48bfaefc2,
+                    0xcfe27425fa998686,
+                    0x839c3ec7454aeeec,
+                    0x3b496a18d63ed3e4,
+                    0x00506349ac3aa955,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb24e2aae4e240a81,
+                    0xe114f5c1ade8cbca,
+                    0x7dc12bea38e8d8aa,
+                    0x661b93b266891675,
+                    0x9b28fe39115b74f9,
+                    0x8588017a52534395,
+                    0xd19baa6890ae47c3,
+                    0x2650fae979f029a9,
+                    0x03174710af626927,
+                    0x13b654f909cb451c,
+                    0x77b0a65d93921a5b,
+                    0x004203ca5e02725d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x360a564a841b4ddb,
+                    0x256f693071e6f53b,
+                    0x4c2b1b2c6552dca6,
+                    0xac9e7c6d409896be,
+                    0x7b5155e7a7a21065,
+                    0xffc70450a2bc12c9,
+                    0x1c1d7c3e60a919b3,
+                    0x5ac08bab368e89b7,
+                    0x833b5e566b5105ec,
+                    0xe80f2aba02a39b04,
+                    0x82df8b8d7cd7c1f6,
+                    0x0012f4684b893bf1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xef12da4747b14099,
+                    0xcc6b0a75b885c83b,
+                    0xa5f5bbb3b8cddfe1,
+                    0x9fb15680ca7ee088,
+                    0x7dec88f56733a9cf,
+                    0xf582d0bbd165485e,
+                    0x21e1d5a9fca894a9,
+                    0xc793c387672599bc,
+                    0xf0965f7a81fc2660,
+                    0x2c5f2adbd3356f75,
+                    0x48ed42c247aa65ee,
+                    0x00b47fb3c130a583,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3ee1b46c50a1a65f,
+                    0xd00b1bbee271486d,
+                    0x58d5ffd316576e60,
+                    0x6c7070e1fc71b4ab,
+                    0x2e94fa289849fc99,
+                    0x068b58269cbaa536,
+                    0xd166ad05aebf3d89,
+                    0x7796575f114e3c30,
+                    0xfcc7d0a0157732e2,
+                    0x910be0ac0aa1dab5,
+                    0x668fc6e8bb45eccf,
+                    0x0055a2f621f6b545,
+                ])),
+                Felt::new(BigInteger768([
+                    0x126cf8949f5976d3,
+                    0xf4ae904b510f6caa,
+                    0xde2572db6f92d28a,
+                    0xbe63af58df6c25e1,
+                    0x4e8519ef6da726e9,
+                    0x8332344f829bb383,
+                    0x22dea3b38a39ff0c,
+                    0x79eb445e48bd009e,
+                    0x193f615b0de7d092,
+                    0xe19c2099bc7f542f,
+                    0x9351a44ed1064a08,
+                    0x002e885d843a57bc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0de8fbe40447e6ad,
+                    0x8acb47fa9ffb5ee8,
+                    0x9d81a94e6ca22841,
+                    0xfb6bfc4c02f549d4,
+                    0x70e4c0905b531dcf,
+                    0x88336ff03b39a8e1,
+                    0x691f615d4a78140c,
+                    0x35f59afae48afa5e,
+                    0x3eeac2f9800b26f6,
+                    0x16df074bbf54ebec,
+                    0x77562dfc0b895aec,
+                    0x002db1f0bdcc6c36,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb52197393619497,
+                    0xd3051c342b1f2921,
+                    0x069398f10a601de4,
+                    0xa39022f94c5a7afd,
+                    0x8109d3397380e0ba,
+                    0x445cea5443dc161f,
+                    0xb5c571f441ff9591,
+                    0x9edc0080f3045dc6,
+                    0x1cf77ba86fc9d59f,
+                    0x0ab0ea80f0cc6afc,
+                    0x8ce8d17ac5d9cc14,
+                    0x00f8d0b8f2875198,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0d43ba4f488a58c,
+                    0x9622886c28371658,
+                    0x17676dc352666b37,
+                    0xca74c0296b01d370,
+                    0x48b8f6d7e02e3c3e,
+                    0x526442f96d756d3e,
+                    0xea0dc4e38006f42e,
+                    0xdeef2e168b20ddd5,
+                    0xa0ddbc62f008b9ac,
+                    0x098065f7e57edccd,
+                    0xd2bf401861607a9c,
+                    0x00177f140e55df15,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa643fb6053e19af6,
+                    0x49d79dd309353bfe,
+                    0x121268c468acc2fe,
+                    0x3b62a8020746a2a8,
+                    0xae9b2afcce59a241,
+                    0x3159308809fdcbac,
+                    0x338e3068ed0d61f3,
+                    0x6cf361aa0d584a92,
+                    0xdb48caee6a99e70f,
+                    0x3471d8686084eb86,
+                    0x20770f08793dd0ec,
+                    0x00bfa946c8ce67e2,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,3906 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U192;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U6>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger768([
+                0xe35bfd7a5a37bf6b,
+                0x6a5c43b192aac7e3,
+                0x4d00d4c10d5cb04e,
+                0x4a967d1dcc4752fc,
+                0xb8f80ebe76a6cccf,
+                0x4a0d21eb4c2792cc,
+                0x0e40d52fd29ea6d5,
+                0x11aed9ab84d1c1c1,
+                0x734e01a90f9c6369,
+                0xcbab43c5012b4453,
+                0xf9c1cb47faa5ceb9,
+                0x002b221d9e92cd38,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x9286b935de3f34e3,
+                    0xf8c85f4ba5bd2a78,
+                    0xb97428cad3957b3c,
+                    0xe6b841e01ecaf367,
+                    0x7e9c44139993a46a,
+                    0xa4a6a1220b7318e7,
+                    0xd3977bb1b18d4d95,
+                    0xadaa626d9ef098c1,
+                    0x4945b5b784b64818,
+                    0x857a79e3618fa631,
+                    0xd4799fff283e8501,
+                    0x00753ad5e221b328,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f39ded20b814185,
+                    0xb79d539b630fe4bc,
+                    0xf2d027ce46c212eb,
+                    0x0832632ef466deed,
+                    0x0b2072c8772a475e,
+                    0xff93d8d6a4519869,
+                    0xf33562db88daebe5,
+                    0x5504fa3e3baf7718,
+                    0xd519d379a280961f,
+                    0xc74700a43d05e47f,
+                    0xbbfe7608e397b79f,
+                    0x00ec7be8061d4f44,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x4b478407e5e1b96c,
+                    0xe9659c869c31268b,
+                    0x2411a4e3d50a5053,
+                    0xf85c6d2ca5ac0c3a,
+                    0xf089e87332df2ba2,
+                    0xc74ae915b34f5d85,
+                    0x764dc9d67fab59c2,
+                    0x735acc7ef8975c17,
+                    0x5e9098419110a256,
+                    0x8c4d010b50548ec8,
+                    0xb3b7b2c62f88ffa5,
+                    0x004cdb1295cfdb58,
+                ])),
+                Felt::new(BigInteger768([
+                    0x369844cac5236e12,
+                    0x38f3d4f3e844e487,
+                    0x2bd6e226119e6e51,
+                    0xe33eb2b2035b8821,
+                    0x356d240b465ea60d,
+                    0x53095f9f7d23b61e,
+                    0xc3ce7ef79448557d,
+                    0x9e1b5e2341ab3899,
+                    0xd70d813053d85e4a,
+                    0x9dd7e8f0f0d1ca02,
+                    0xea75d30443dec992,
+                    0x00a85130f8bfee26,
+                ])),
+                Felt::new(BigInteger768([
+                    0x293bdb2117b5c8f9,
+                    0x5d473366a5723b0b,
+                    0x7962761ddd66935b,
+                    0xad7e21dca0d96605,
+                    0xe48edb38d98a07b6,
+                    0x169b403bbf777120,
+                    0xf9634259fb688d90,
+                    0xa20955739b8ed575,
+                    0x13776dcf523e61a1,
+                    0xc0f8656787143cd2,
+                    0x8c4e6cc453399d5e,
+                    0x00cc7d8446505180,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x186b5373066741dd,
+                    0xad13f2241285b068,
+                    0x7c5bdbcc19a69f68,
+                    0x60a07a25baa7d06c,
+                    0x97495b443b6c3ac3,
+                    0xab54d8bb2e96dbe8,
+                    0x3bf47833826d1cd9,
+                    0xde1a3985bb13db04,
+                    0xbdca508149ad6298,
+                    0xa9c9b08a00a21b74,
+                    0x3364df64b5df1895,
+                    0x0065c1caf9061399,
+                ])),
+                Felt::new(BigInteger768([
+                    0x933939cf21427561,
+                    0xd7a7aec955963276,
+                    0x8c940f6883128c09,
+                    0x9d77455ffd994d6d,
+                    0xbc53fa89fc8c3df7,
+                    0x988acdd29ed81a20,
+                    0xad05913942b4a839,
+                    0xee9557d164c18dbd,
+                    0xe424c2bf71a1a394,
+                    0x4603498455a1384c,
+                    0x47affaba59267c2b,
+                    0x004e2c1cf99bd404,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36042291e21966ce,
+                    0xac9d71b538724ba8,
+                    0xe33b188c3d32b8a6,
+                    0x3f865a5066830f3c,
+                    0x26faa5fae0c0cbf6,
+                    0xe7e89e76a1aa701c,
+                    0xb9861a2319dc5c7b,
+                    0x19442a7afa036526,
+                    0x74ef1366fe3ea3cd,
+                    0x085dae4c507b3f50,
+                    0xe6131623abb6e09c,
+                    0x0045c9953b4e56e7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x60efe44be532cf22,
+                    0x552b6c498388f37a,
+                    0xb9473d7a81972c0a,
+                    0x855c1f5f819feef7,
+                    0xe1efd571ce621d07,
+                    0x173431e06b3e936e,
+                    0x629537662f3a9855,
+                    0xacfcebf4ad346acb,
+                    0x09ef16872a0971c3,
+                    0x8ac4be839f8c143c,
+                    0x428c8972b266efb5,
+                    0x00d3cc3796eb7fc8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x1ab4f71ad15fc2fc,
+                    0x5bb03c909dd75e2d,
+                    0xe98d051cac6ae380,
+                    0x87b69d83b4ef1200,
+                    0x5918039f96b71d17,
+                    0x4a2796af937d3870,
+                    0x8bdd6b54f8a62cce,
+                    0x90078145a3723374,
+                    0x51b2530e5947facd,
+                    0x784433eb0b659c7d,
+                    0x3129007d3670d935,
+                    0x00e1b820a10d395e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38e74fb7657fdb51,
+                    0x915c5732e1a07b39,
+                    0x456fa1216a623cfa,
+                    0x7d44dee1e9402213,
+                    0xc858f45b7a7db79c,
+                    0x52837d1e440769f8,
+                    0x1e1bbe7b1099c1b2,
+                    0x7381facef61fe67c,
+                    0x6230e5d172076028,
+                    0xa785a7b8a3a99e79,
+                    0xd153db3cc9d6685f,
+                    0x0016718d032584c2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1afec1c37d2de2e4,
+                    0xe8d2f44bca8c2c16,
+                    0x7ff2e1a451b9036d,
+                    0x221fe18760cac60a,
+                    0x43ebf9e1f06df242,
+                    0xaa2212103a683e61,
+                    0x4cfb3c5c1cdc3e46,
+                    0xad4b8e3324b0d118,
+                    0xcb9c37470cc66ca8,
+                    0x3384f1dc8a1c0aa3,
+                    0x179ed3afd23540de,
+                    0x005691012b18dd08,
+                ])),
+                Felt::new(BigInteger768([
+                    0x721608222587448d,
+                    0x70c64e52fa31462d,
+                    0x31b1c17ecada81aa,
+                    0x3fdeabad9c21ddb2,
+                    0x4ee5bfc1a7a23ad3,
+                    0xb7faa1903eb375d1,
+                    0xbcdd7e45d5e7a4f6,
+                    0x80840733d2d2add1,
+                    0xfb3ea2f92f309e06,
+                    0x321903ee1c00bd88,
+                    0xdb1513db2bb1d517,
+                    0x00f7efc9227c1250,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8887f991fdf7a7f1,
+                    0xa8b217de2b6a224c,
+                    0x221730005767374f,
+                    0x2d9a49da0cc5db24,
+                    0x7d7bb69960ddd8af,
+                    0x8bb41d0ca17e6e55,
+                    0x3e0e7c7539ef33a0,
+                    0x1fc411046145f34f,
+                    0x770894d38058100b,
+                    0x9a8413d36ebc0ed5,
+                    0x3513c91d822f9d40,
+                    0x009122600c20ce6a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x73ae64989cab724b,
+                    0x824e6d5e20a10418,
+                    0x78827d946bf2359f,
+                    0xf81eae3a2e47425d,
+                    0x09b18f841ae34487,
+                    0xc8889afb129cb399,
+                    0x0476fbec38dc11dd,
+                    0x2e2cea0bb937b0bb,
+                    0x3e7d48a9654c4b80,
+                    0xcc0b9bfbaa7211f1,
+                    0x88af5d08cc19f3de,
+                    0x00e42ef903158377,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0924e02d6e26dc0c,
+                    0xe7e78271f42e861c,
+                    0xa2cb283302da19a4,
+                    0x1b7ff69985c02d49,
+                    0xadffde40bb18aeb0,
+                    0xe13e77d66d42fea7,
+                    0x2ac626eafa1b4392,
+                    0x3b21a96b560ba1d6,
+                    0x9364ad8721a5f0a6,
+                    0x1eccef539c2c506b,
+                    0x6c6f0aaecd03e9ec,
+                    0x00eea80bbc45d2f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f5e2dc939301bf0,
+                    0xb2707f13b6417808,
+                    0xf18b13ab8692c0f7,
+                    0x3c7ec3574ba9f601,
+                    0x086d1b82a3531b18,
+                    0xa89ece584762d3a0,
+                    0x51973887ad730108,
+                    0xbb48fe0b72fc5efb,
+                    0xc2da1eb683b57f7d,
+                    0x836b26e4a8abfdc4,
+                    0x37add3e4738ced6c,
+                    0x00ed1350b0a6461e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x60de178d29d7a79f,
+                    0x85793b6c5c365a2f,
+                    0x12d0e4c658ea0066,
+                    0x3ee4694039b20c7b,
+                    0x281c7c63e257aab6,
+                    0x65156feaf8a63993,
+                    0x6402a3f03cfd3b98,
+                    0xc090aa0b4c30dc59,
+                    0x62baea5a1923e493,
+                    0xba6c39ac5ba0e82d,
+                    0x35d05f83e821aee3,
+                    0x0052c9f7c6c5442d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf9c32dc6c3063d62,
+                    0x52dae6aa13e25d61,
+                    0xeff4df584455aa09,
+                    0xfb2cea59aa0e91fc,
+                    0x15ce662ae8dcf980,
+                    0xeb3fc4c867c7de42,
+                    0x3f7d8a1b8ca90c96,
+                    0x71ce82c71b20a658,
+                    0x15c2ecb7319fe335,
+                    0xdd0afc46defc4a08,
+                    0x571a0880590ae634,
+                    0x003abad22de11a0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff6fc3d24110b2c8,
+                    0x04462d028c8b8921,
+                    0x1d7d80cc1375e60f,
+                    0x832c8941a0b2f208,
+                    0x27f7664c955eac9d,
+                    0x5e08b675893bd9a7,
+                    0x6353cc1641949a5c,
+                    0x48c725149e0d3b77,
+                    0x0716f36267fe4f7e,
+                    0x899c320ab47fcea6,
+                    0x4ac2a109a7b9c773,
+                    0x00c4c26c829becc1,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xb8f14e60ad1957a3,
+                    0xe9b68c7dc7333f64,
+                    0xf6bd5b351ae70043,
+                    0x89bc6894d61d0d69,
+                    0xe172be07604a513f,
+                    0x1d3e552f3363aeb5,
+                    0xd696188bc07c3d46,
+                    0x3a6a623d6d2a2571,
+                    0x80de09dc2ee7abd5,
+                    0x026d974bc9e3026e,
+                    0x5651cca9a3a3d2bb,
+                    0x0002a2ecccf2b837,
+                ])),
+                Felt::new(BigInteger768([
+                    0x067913c1ba80f1d1,
+                    0x1414e8ca56c67dfb,
+                    0x94649409efc2623e,
+                    0x6d5acf7b862f721e,
+                    0x59bbe0005c5aa0f6,
+                    0x898333ac9ec4a185,
+                    0x18acdaa6dfcf43af,
+                    0x97a81d02417dc31d,
+                    0xeb129cb8c6b1042b,
+                    0xd7a338de8b7e44f9,
+                    0x83363b08c383aa1d,
+                    0x0066aa3673acfad8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x39b33375bcbaeff0,
+                    0xe8f701cca22b1aa0,
+                    0x2c0826b5a8db1475,
+                    0xfd793ea37a234b8f,
+                    0xe0a128a6e32c9486,
+                    0xbe938c274ebabe32,
+                    0x3bf5c34aab3175ef,
+                    0x97c20d3be51e6224,
+                    0xcc3f516d4593e08d,
+                    0x4c4df5feb24281b9,
+                    0x8ca62a952dcdd1c5,
+                    0x00f2c18b9fcf04fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2bd4175b7d85a9e7,
+                    0xfc81ea2675d56e51,
+                    0xcc9ef5f3c4c42952,
+                    0x48cf2cf6cd023b09,
+                    0x73a1f3f8b2c5145a,
+                    0xd94eea23ce1014ee,
+                    0x4f754f8cf993f110,
+                    0x304c35bcab7d5b8e,
+                    0xbcaa69d552178137,
+                    0x001aea1b4c43d641,
+                    0xebb4d049ff8811cf,
+                    0x003b84a701859e87,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4f9bbfee632995c7,
+                    0xc4437bbbcc6e021b,
+                    0xec4e1fcfc79d9454,
+                    0x63905e8d1b95ccef,
+                    0x54eba1430fdbebef,
+                    0x8d28ac720433bbef,
+                    0x86ea2f44b7397983,
+                    0xa63364da73731ef8,
+                    0xeb5d6ad2fa2f2892,
+                    0x4cbcf36408ddea27,
+                    0x48b5eebf92ded14c,
+                    0x00d415ab6d2447ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd73a5606d3ca8b90,
+                    0x3756a237995945ec,
+                    0x8502aedc188a21a8,
+                    0xbf03a511bac8a5f0,
+                    0xc8a3879117c9780b,
+                    0xf0b36ae61962a879,
+                    0xa8e77b244740f9af,
+                    0x7af1323e182106af,
+                    0x927aa1a68052d672,
+                    0x3f57a919362d202f,
+                    0xff91c8ada2f14b37,
+                    0x00c5c67381d29bd9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe9d80e51b0814051,
+                    0x0aa933927c6cdf02,
+                    0x028d7c04cb89c555,
+                    0xef4f4d5cd921f496,
+                    0xf32089d681c93ff8,
+                    0xf5db94556793862d,
+                    0x3a1426ca7fdb1454,
+                    0x98e3d4ae7928a8fd,
+                    0x8868e35e8dca35ab,
+                    0x1b64bd2aff4d2ca5,
+                    0xa1a036281234d510,
+                    0x00646d4d53c31ae4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x102347009c577392,
+                    0x73312c0dd6ad4625,
+                    0xd63a3c8c72b2bffc,
+                    0x217394b6e444555b,
+                    0x2a50ac1ba1817c1c,
+                    0x0a543cf60688464d,
+                    0x0be7a4d6eb4e11cf,
+                    0x3f687f71570de730,
+                    0xbd1c37a97f11a176,
+                    0x78d8ea9afc4d7d0a,
+                    0xb1b07e8352159ddd,
+                    0x0030dd7883e1b34b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x98a3b882471f464d,
+                    0xc06af794fafaca71,
+                    0xfe51430bfafca729,
+                    0xe171428a9c547f33,
+                    0xb3b68e62d92e63ab,
+                    0x9782b55039e4192d,
+                    0xe850773b6b0d52a4,
+                    0x389561433cab86df,
+                    0xa031bcb1e0b84584,
+                    0xbb39bc60d1307789,
+                    0x334172161bf299b7,
+                    0x00cc09afe13e8102,
+                ])),
+                Felt::new(BigInteger768([
+                    0x971d768df42d75b4,
+                    0xed2fb238b389cb54,
+                    0xd6015a3d94bcca7a,
+                    0xe6c84998acba3713,
+                    0xf01c4a4f96e2f574,
+                    0x9ab164998cb98a97,
+                    0xed44a72c60391ccf,
+                    0x0f9cefe1c06828c1,
+                    0x3ff49eb00cdf85c6,
+                    0x34a6dab149ca50bb,
+                    0xa1aa35c4d97520aa,
+                    0x00953d78041fb339,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4693a0a8ae9346c8,
+                    0x01b69798fef6524e,
+                    0x501765dd32d71b69,
+                    0x4432ca3bfc504f27,
+                    0x3211dd548807be9b,
+                    0xc9062257d1ccecb8,
+                    0xe0136cf2f720adf2,
+                    0x41828cae2c1f8085,
+                    0xdc364cd5e195a578,
+                    0x02abaa3d2728f016,
+                    0x2bcde8f500a7bcdd,
+                    0x00c4724ade7df268,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9aa08a6d63c41569,
+                    0xcf0bd41d45635eed,
+                    0x88d905031ab895af,
+                    0x5d4c5552adeaedc0,
+                    0xbd13f133d07c755d,
+                    0x2f90eb07a1daff16,
+                    0x51b90a31ec45b19e,
+                    0x92e050f2a55347a2,
+                    0x55c4d784b8da20c3,
+                    0xfbb7dc4e93484a2a,
+                    0xbae4dacfa65e4c56,
+                    0x00734f76190413e5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8c82e050de7d22f2,
+                    0xe362e32e9fbb1ac8,
+                    0xd5f748f2fb897631,
+                    0xa52d5da4f0bfdcdd,
+                    0x1e0f8a6e587b349b,
+                    0xee6644a572850041,
+                    0x389eee0a76e9664b,
+                    0x498ff95bfc977230,
+                    0x56f7a54c712a1a6c,
+                    0xed6b40bf388be4e7,
+                    0xeb9a68dcaa73c728,
+                    0x00a8e1253c714c42,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf941bc7f6167a5d4,
+                    0xf9dbd048257545a2,
+                    0xc0f08ce916975482,
+                    0x84d5e9b052a37bd1,
+                    0xe310c859292913d5,
+                    0x25fbc997acf8a99b,
+                    0x48db194b59de21d5,
+                    0xfa4a1ff17e195995,
+                    0x3c50f8027d333101,
+                    0xf9eed7ebcf76909f,
+                    0x093512795bac9f12,
+                    0x0085d6e6ffb6cd85,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8b0f6fc79eba210b,
+                    0x21e81d439111ed05,
+                    0x51156210bf6941be,
+                    0xcb5ddec52c4a4823,
+                    0xdec123c8fda03d2d,
+                    0x10b1ccf5a5974939,
+                    0x5fc269d8137ee831,
+                    0xe8c0672b09a082f2,
+                    0x2daebeb27241dc6b,
+                    0xaa41bb8859d15554,
+                    0xecd6f53910e82d36,
+                    0x0100a39fca6dd9a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0b9addc13defed18,
+                    0x0e30f7e549f98a61,
+                    0x45965b9890232471,
+                    0xf26a9438397e2ef7,
+                    0xec58280519777d5e,
+                    0xc5fefd575b214ca0,
+                    0xf7268a5eeb555814,
+                    0xa8d9cc43ba0878a4,
+                    0x2396d2cad4de066b,
+                    0x56ebe916c4ff3b79,
+                    0xe0c284642082ac36,
+                    0x008c6174d61bb051,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa41ba5c6911fee96,
+                    0xc49fddb2f50c3fd8,
+                    0xa8837bd2d082e6bf,
+                    0x915a9b491dfcbc76,
+                    0x2f654a5429aebc5b,
+                    0x4766322709d2ca44,
+                    0xf473a21cb0cc12fc,
+                    0xc704e0cf72cf5016,
+                    0xea05e098fa218e82,
+                    0x97fe3c4720151fa1,
+                    0x2f093a9479d92ff9,
+                    0x0033d25d7f5177a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c1e4858f490ffda,
+                    0xd3fdb48bf63e054d,
+                    0xa848a4635336b214,
+                    0xe3076f9b3a952e59,
+                    0x5069b8ddb8dd40a0,
+                    0x8e5007836d7d77f7,
+                    0x5c9039ea135772ab,
+                    0xd514fda84d1e1b97,
+                    0xd8f547f3f4cb5ffc,
+                    0x36ff015e7717952e,
+                    0x736c401db084ae9b,
+                    0x005f5ad6a4ddb4a0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2e1ba1cadf1d4fd6,
+                    0xcc4d9163a57a81ba,
+                    0xbaef027cefec9989,
+                    0xc6d20207548125c0,
+                    0x9321d23543f1c53d,
+                    0xde1eb7469b61f6cb,
+                    0xabf1c418c4d83d5e,
+                    0x5f72ef4159729fb7,
+                    0x4949ddc41680f050,
+                    0xa1bc0ab4fa16e8c0,
+                    0xd681cc7a42ef772e,
+                    0x004caa342a72a8a2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d13413537676ade,
+                    0xbcbd976a53b06e7e,
+                    0x5aeae89c43354c17,
+                    0xd8ca4c127fa4512d,
+                    0xd74ead6f46e82557,
+                    0x031323cf98ea840e,
+                    0xf73db8098c3a6c9a,
+                    0xa0a9d8c22be5a5dd,
+                    0xb6457bbfee441fbc,
+                    0xaa996d01aae063ba,
+                    0x857f06673cd5945a,
+                    0x0031c91349f63e91,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xb8f14e60ad1957a3,
+                    0xe9b68c7dc7333f64,
+                    0xf6bd5b351ae70043,
+                    0x89bc6894d61d0d69,
+                    0xe172be07604a513f,
+                    0x1d3e552f3363aeb5,
+                    0xd696188bc07c3d46,
+                    0x3a6a623d6d2a2571,
+                    0x80de09dc2ee7abd5,
+                    0x026d974bc9e3026e,
+                    0x5651cca9a3a3d2bb,
+                    0x0002a2ecccf2b837,
+                ])),
+                Felt::new(BigInteger768([
+                    0x067913c1ba80f1d1,
+                    0x1414e8ca56c67dfb,
+                    0x94649409efc2623e,
+                    0x6d5acf7b862f721e,
+                    0x59bbe0005c5aa0f6,
+                    0x898333ac9ec4a185,
+                    0x18acdaa6dfcf43af,
+                    0x97a81d02417dc31d,
+                    0xeb129cb8c6b1042b,
+                    0xd7a338de8b7e44f9,
+                    0x83363b08c383aa1d,
+                    0x0066aa3673acfad8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x39b33375bcbaeff0,
+                    0xe8f701cca22b1aa0,
+                    0x2c0826b5a8db1475,
+                    0xfd793ea37a234b8f,
+                    0xe0a128a6e32c9486,
+                    0xbe938c274ebabe32,
+                    0x3bf5c34aab3175ef,
+                    0x97c20d3be51e6224,
+                    0xcc3f516d4593e08d,
+                    0x4c4df5feb24281b9,
+                    0x8ca62a952dcdd1c5,
+                    0x00f2c18b9fcf04fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2bd4175b7d85a9e7,
+                    0xfc81ea2675d56e51,
+                    0xcc9ef5f3c4c42952,
+                    0x48cf2cf6cd023b09,
+                    0x73a1f3f8b2c5145a,
+                    0xd94eea23ce1014ee,
+                    0x4f754f8cf993f110,
+                    0x304c35bcab7d5b8e,
+                    0xbcaa69d552178137,
+                    0x001aea1b4c43d641,
+                    0xebb4d049ff8811cf,
+                    0x003b84a701859e87,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4f9bbfee632995c7,
+                    0xc4437bbbcc6e021b,
+                    0xec4e1fcfc79d9454,
+                    0x63905e8d1b95ccef,
+                    0x54eba1430fdbebef,
+                    0x8d28ac720433bbef,
+                    0x86ea2f44b7397983,
+                    0xa63364da73731ef8,
+                    0xeb5d6ad2fa2f2892,
+                    0x4cbcf36408ddea27,
+                    0x48b5eebf92ded14c,
+                    0x00d415ab6d2447ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd73a5606d3ca8b90,
+                    0x3756a237995945ec,
+                    0x8502aedc188a21a8,
+                    0xbf03a511bac8a5f0,
+                    0xc8a3879117c9780b,
+                    0xf0b36ae61962a879,
+                    0xa8e77b244740f9af,
+                    0x7af1323e182106af,
+                    0x927aa1a68052d672,
+                    0x3f57a919362d202f,
+                    0xff91c8ada2f14b37,
+                    0x00c5c67381d29bd9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe9d80e51b0814051,
+                    0x0aa933927c6cdf02,
+                    0x028d7c04cb89c555,
+                    0xef4f4d5cd921f496,
+                    0xf32089d681c93ff8,
+                    0xf5db94556793862d,
+                    0x3a1426ca7fdb1454,
+                    0x98e3d4ae7928a8fd,
+                    0x8868e35e8dca35ab,
+                    0x1b64bd2aff4d2ca5,
+                    0xa1a036281234d510,
+                    0x00646d4d53c31ae4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x102347009c577392,
+                    0x73312c0dd6ad4625,
+                    0xd63a3c8c72b2bffc,
+                    0x217394b6e444555b,
+                    0x2a50ac1ba1817c1c,
+                    0x0a543cf60688464d,
+                    0x0be7a4d6eb4e11cf,
+                    0x3f687f71570de730,
+                    0xbd1c37a97f11a176,
+                    0x78d8ea9afc4d7d0a,
+                    0xb1b07e8352159ddd,
+                    0x0030dd7883e1b34b,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 760];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+            bytes[380..475].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..95]);
+            bytes[475..570].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..95]);
+            bytes[570..665].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..95]);
+            bytes[665..760].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..95]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x2744ebe0b30627f6,
+                    0x2c973069603d105d,
+                    0xf2c7e51bb69fa47d,
+                    0x0d76db2d9435f442,
+                    0x6c2e5662c5a556cb,
+                    0x533d1b4a9bdd854f,
+                    0xc885344ba019f8ae,
+                    0x35578d2391fbbbde,
+                    0x3048537a7949d650,
+                    0x22eb1652cd623b7d,
+                    0xf64e02237bfccca1,
+                    0x008976b3123a9df3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1b054467c428407,
+                    0xb3b6a3c2bc07da07,
+                    0xe4b3dcc495c1ea0e,
+                    0xb8dbf8ce71ca7a2f,
+                    0xf3a0c50d5d2b711e,
+                    0x61dc1c583f874854,
+                    0x8492ef880fbc372e,
+                    0xe6b7b29dd83a6723,
+                    0xcaa37b42efcc14b5,
+                    0xd82d0ae3e1ec05a9,
+                    0xecdee5440c3027ae,
+                    0x003b09259b0a09ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf64760896277973e,
+                    0xcac03d665920255d,
+                    0x63cde38f0e7eee06,
+                    0x160a13d773792ce5,
+                    0xe4715ef391e88d24,
+                    0x6e0feef58e008d90,
+                    0xf5536fe5c51028e4,
+                    0x2ddb971a706dbee0,
+                    0x4f2edfa5b50f2b0c,
+                    0x3090b757a06d8f4e,
+                    0xb05429116bad582d,
+                    0x00b44bf9ddf73c0a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8680cf2c80205737,
+                    0x90e29df4d874698b,
+                    0x14e697069667090c,
+                    0x9c34b11d8a360dd8,
+                    0x45370317bbb6648d,
+                    0x5a3f415cc64fb486,
+                    0x2fc88c4e26fe68a0,
+                    0x493f87d29cc1005f,
+                    0x610d1fb20ed92607,
+                    0x736f77e4b96b9750,
+                    0xf198e7c7c37b315e,
+                    0x002cee857f1b0648,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3f5b5385cf47d0e9,
+                    0x9e42fc51cfbde399,
+                    0x6ee9db78b2fe4977,
+                    0x355e233e9224981a,
+                    0x2a8bbd97a2d6b108,
+                    0x3fdc0f2b82fd83bf,
+                    0x42eaf92dad25239e,
+                    0x271b6d643bd3e629,
+                    0xe796ca54219f4163,
+                    0x1a528fed21563592,
+                    0x428a75eb12d29e67,
+                    0x00ade9b01f4071ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbcf17d6fa2dc06f5,
+                    0x5f9c30f13ae84e81,
+                    0x8b2b26a312e525d6,
+                    0x1680c5dd43599646,
+                    0xf7a983708afdc43e,
+                    0xe236bd75968ac2b2,
+                    0x890157e035474c16,
+                    0x1b6bc91b1757409b,
+                    0xdf20df35a5b76154,
+                    0x6537d7e5413a1404,
+                    0xf72acd32be8ff8d9,
+                    0x00ef5a21df9047e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xefcf7ab62979dc6d,
+                    0xc0143744bdf9c43e,
+                    0xa75dbe8785f104c3,
+                    0x8b385e93ed510b61,
+                    0x22922f0cb5045826,
+                    0xab18615d7c9f620f,
+                    0x396358c1d8660200,
+                    0x28a985d3d1fc72dc,
+                    0x304d3f912496fb0f,
+                    0xd8b90d1c9128ac2c,
+                    0x9c82cdc7406ccd88,
+                    0x00a232ae362434fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfde0caf005278eeb,
+                    0xdea9503c613ab2f2,
+                    0x39034f4ea809669a,
+                    0x8d2f36af5d546dc8,
+                    0x50da1141740cf4c1,
+                    0x04e16cec3440b075,
+                    0xd96f96fe38577d0e,
+                    0xa2cac67df8dbc80f,
+                    0x75d9bb4dc2ca7ffb,
+                    0xd7b7d8b7a7a5e2d8,
+                    0xd8bb2080e5df0641,
+                    0x0066d5233b613239,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x4c39b9f4f6b09b18,
+                    0x86a3c38bf215c242,
+                    0xcce58863ebd0a299,
+                    0xe7560218c4e3efb4,
+                    0xd37c73700e8b77e3,
+                    0x36978207f7c894fc,
+                    0x4fa53a6a3a2471ad,
+                    0x336e59d2eb0e3c08,
+                    0x3e4a95a01f602312,
+                    0xbdb9c271cba2aca7,
+                    0x94d284852aafeddc,
+                    0x00b16583981749fe,
+                ])),
+                Felt::new(BigInteger768([
+                    0x137d618f1fa5a9fd,
+                    0x5c1e12b7c782707c,
+                    0xee0df987a3766cbb,
+                    0x537ba25767898826,
+                    0x635f27bd16b6ed27,
+                    0xeb171789f2765206,
+                    0x56af41dd3779f49a,
+                    0xc92395e662cc7eeb,
+                    0xe0ba0b94674c66ba,
+                    0xdde8ac1dbd08a180,
+                    0x31d3b7293e8167ad,
+                    0x002cd343d3043e1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b386b4c89a13b0f,
+                    0xf00bc321c345c46e,
+                    0x59504d83265f4206,
+                    0x8fd6787d005632dc,
+                    0xdae080373c76164e,
+                    0x15818ffae935aaa8,
+                    0x61043c9ff9ea0ddc,
+                    0xda71cf52127653ef,
+                    0x6402a848eab8258b,
+                    0x77c8672166dc6111,
+                    0x232b9023eedc3490,
+                    0x0096ce9d82af2825,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2d9e04f377327dad,
+                    0x446beceb1d9c65b2,
+                    0x1a68ab270db5e4d3,
+                    0x9e7b0b44001995c3,
+                    0xb3b9c0b2969f090f,
+                    0x1a0186845600cb10,
+                    0x6f301da40e028476,
+                    0x9a1d96ff33615a4a,
+                    0xa4360168036fe7eb,
+                    0x41cf14db4616b243,
+                    0xaa755d550d4d36c7,
+                    0x010fdb813dc7ad41,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90414f56470afbe0,
+                    0xa3f1d6dceca37072,
+                    0x0d5d8b929a1a8e37,
+                    0x7a22e12f970f25cb,
+                    0x931a5f645ca0e5d4,
+                    0x0f165bd2b33f09f6,
+                    0x95865af81f9b3322,
+                    0xe2ab8fcfb8642ec1,
+                    0x5e110415a5ca1cb5,
+                    0x2d7ef99ed11ad140,
+                    0xf83905c5a074a578,
+                    0x0013f239a1b0ac32,
+                ])),
+                Felt::new(BigInteger768([
+                    0xecc0972cc940049b,
+                    0xdd4da562ccebb071,
+                    0x27ada07c6b16042b,
+                    0xf3c4c9681bf9ce4f,
+                    0x4560f434a907b132,
+                    0xa11393d713dce831,
+                    0x1e4de169f67c5edf,
+                    0x049d329933435b21,
+                    0xe7403501ffb41b80,
+                    0xf6bd94c6a0e0d6c8,
+                    0xe406bd1b5a223d9f,
+                    0x007ad60432e2901b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe9173cad7a75c3eb,
+                    0x906f8d9a2c148edc,
+                    0x4d43ea7247a25113,
+                    0x8afe2177ba46631c,
+                    0xe4b0b32955379f1e,
+                    0x8b6f31a286572543,
+                    0x63fb2a0d4817bc04,
+                    0xa57cc820517c59e6,
+                    0x8c2c12065138905a,
+                    0xe9360c169d89e3ba,
+                    0xb97fc9de19229c18,
+                    0x00265b3a29be1fa7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc5e4ea2051262a7a,
+                    0xc7b49ed09a26b1f2,
+                    0x693ef1d89c51d994,
+                    0x0fd8b20994206811,
+                    0xcbb411a1042cd3f6,
+                    0x23f8ef2dedac71ec,
+                    0xf37b03bc43a6892b,
+                    0x76f6022edc91f7ad,
+                    0x6e13740c1c55d7eb,
+                    0x0b629ea7186c9721,
+                    0x84fb2a5af09e9c77,
+                    0x00be92c856451907,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xcc733418b676df47,
+                    0x427c9bfef6005b31,
+                    0x311a25cc14aa5498,
+                    0x14677a81a1af023c,
+                    0x6c49beec7eac3b01,
+                    0xe9e3218725fea31f,
+                    0xfb9ee2dab8526068,
+                    0xcfff4114225e436c,
+                    0x089c8566866ab912,
+                    0x6058a2961f6d028b,
+                    0x53ffa5caa738211e,
+                    0x0034fc7ff003960d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4362c7274c7a6b61,
+                    0xb0002e3b19a83d02,
+                    0xa151a596c6081293,
+                    0xf6001518f9b9b61c,
+                    0x396eac0178094d79,
+                    0x59b09af1dad84e2d,
+                    0x6a4bd4d70fba163e,
+                    0x40394a913e440b1d,
+                    0xdc15be4fc0623624,
+                    0x5bd891624b0b4503,
+                    0xed0a02042cd6aa53,
+                    0x000b2667485aaf1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b2ad860ea7e2642,
+                    0x65c53ff231340c13,
+                    0xeb1758d5c86e9a2f,
+                    0x568fc0bb1b1f6844,
+                    0x311f12213879886b,
+                    0x3d60b1d09385f7dd,
+                    0x9b9cb4bf3b057814,
+                    0xf4c6fc46c91c8219,
+                    0xc5afa95bae1f8ff0,
+                    0x8cf948a9bd2259fa,
+                    0x8b2bff30ef5a8342,
+                    0x00b0b0f3eddbf6ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1bf661fac366bce4,
+                    0x063ca3f7b85cc3b8,
+                    0xfb4d820f98eaacd1,
+                    0xb24dfcddbd8e74c2,
+                    0x0847dcc32075b4bc,
+                    0x008dccc8e6df8e58,
+                    0x82d487bc58f58ddb,
+                    0xef7173cc984bdc16,
+                    0xf5af5289cc5e27e1,
+                    0xfaa5213b1d2284bd,
+                    0x42e96f2c6b80476b,
+                    0x000c8f017019c124,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1644318aa51c2d63,
+                    0x6258cd28bfdf405b,
+                    0xf4dc3cab8273dba9,
+                    0xb323b904ad362b7b,
+                    0xc71ab7b5b41c962e,
+                    0x52dbc6b857e72071,
+                    0x74746076f5c520aa,
+                    0x412546cc204d2c60,
+                    0x9f5084d2646176ee,
+                    0x187f8d4839e830e8,
+                    0x8d998709c4d957cf,
+                    0x011bf829bff45849,
+                ])),
+                Felt::new(BigInteger768([
+                    0x814b55aced700345,
+                    0x40f1bd65134623ca,
+                    0x0b61ddbef4a70ee6,
+                    0xdc0cbc2c424f914f,
+                    0xa8a4da077cd6f4b0,
+                    0x970b71e5f9dc716f,
+                    0xc7aa93060ff6334e,
+                    0x0608e80759862007,
+                    0xac48bcfe2bdd20bf,
+                    0x772b2eb550cd7981,
+                    0x1fe50cca83e3a8e0,
+                    0x00f514d37cca7465,
+                ])),
+                Felt::new(BigInteger768([
+                    0x21ac87dbd339147d,
+                    0xa02e129451c91ef1,
+                    0x04e57e51813257f5,
+                    0x6aec85dc29dfca36,
+                    0xe2eab3d3fb4f0800,
+                    0x0b9bf9ca23d8c20c,
+                    0x30ad7246ccb3ca05,
+                    0x3fbb050e1191d0f7,
+                    0xd5ceeaa3440e052b,
+                    0x149b4e88dd1f54bd,
+                    0x3037cedb4bd14650,
+                    0x00dc6e6e00fc8dfb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5bccd7201a74fccd,
+                    0x531c631c07a0d9d0,
+                    0xcc84bc5ce1ce84e8,
+                    0x042f9b17046fa283,
+                    0x9bb3c199ac803a38,
+                    0x40bac8ed6d742ecb,
+                    0x609613eca0370ca3,
+                    0x6fc382f23c715a94,
+                    0xa0b82f6f7830d4b6,
+                    0x46f3b7cf5e6f87b8,
+                    0x0b00d846a24bd775,
+                    0x00fe9b422cf5cc1d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x63d8bfa5fbbd71af,
+                    0x3b3a517e8d761197,
+                    0x41f5f7ca357f933b,
+                    0x169e0e2c835fe064,
+                    0xc081e5207ce4b9ca,
+                    0x7c645dfc44e58058,
+                    0x4e2a51d4db447d4b,
+                    0x1f043eaa361f1113,
+                    0x6e52d4dfdb959b89,
+                    0xfd7325d4cb18da13,
+                    0xf239c24a17ccf16c,
+                    0x001322bf2d197b97,
+                ])),
+                Felt::new(BigInteger768([
+                    0x418d82dbe73b16ce,
+                    0xda3db2412107388c,
+                    0xa2fb1df4a4f005c9,
+                    0xf29c64b101086ebb,
+                    0x110ca5e4c47be481,
+                    0x78f74bda61c1e474,
+                    0xc3baeae3361360a3,
+                    0xf9e3f0f924ce3363,
+                    0x6ec36b708c0a6ece,
+                    0xe3cbcb72c5ea14ef,
+                    0xa819b204e72b6cfb,
+                    0x00d7a8acb3d4838c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x558d9eda489a3c52,
+                    0xc304bd88cc1a5186,
+                    0x96b13cb6ed790b53,
+                    0x21f4bebd49d2fd76,
+                    0x7684cfed6d63b842,
+                    0x6a9d24a146c74a11,
+                    0x21bfc224e94b6dfe,
+                    0xd7dd7b9c8abce5dd,
+                    0x79deaf0df0fec775,
+                    0x2649de5e3c250562,
+                    0x8e8956a86f48a315,
+                    0x00a7e8155f454785,
+                ])),
+                Felt::new(BigInteger768([
+                    0x312dde1c527f5cbc,
+                    0x0ceb66d83d42d965,
+                    0xd59775e79998c0a8,
+                    0x02235e647191fcb4,
+                    0x4d65f5639d326894,
+                    0x11bfeb6f4e009d0f,
+                    0xeefb0943524826df,
+                    0xb8f143eeca93b49e,
+                    0xdf27b1978c2cf80f,
+                    0x76d67c37e94d7671,
+                    0xd148e023cbedbadd,
+                    0x00a8995a54f7a864,
+                ])),
+                Felt::new(BigInteger768([
+                    0x203a6d00e4d71cde,
+                    0x83bbccfc9447747a,
+                    0xda0e1a345504fa19,
+                    0x674cd23cb3cb2e07,
+                    0x3ff277e9008e983c,
+                    0xb0ce829afda7f7c0,
+                    0x1deaf38540265a86,
+                    0x30ec5fa5be266700,
+                    0x199aab14a2829b6f,
+                    0x026ea4a8912fc699,
+                    0xdfb2c7a1173186cc,
+                    0x01160c5a1c4ebc08,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18d958b4f7c7b1c4,
+                    0xc3afc9ffbc8c69a0,
+                    0x7efce7f2441915b4,
+                    0xfb15c4f9626247d6,
+                    0x75b156894b732476,
+                    0x8bbd881728bf9764,
+                    0x12aff7753821b00f,
+                    0xc2122d97c346621f,
+                    0x4dc4914cfa0c24fc,
+                    0xd4915ac6c6c5f3b1,
+                    0x36a3bcacfa1e55d9,
+                    0x004672b2c4a911c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71eb5ff837a90439,
+                    0x4a87734b738f34b0,
+                    0x92171f0305d99f02,
+                    0x610ed09403f81421,
+                    0xd9c68271bbdf0c47,
+                    0x7e90656f03c89602,
+                    0x225115cb9feaa191,
+                    0xbc99aa56431ffc46,
+                    0x771597237181b72b,
+                    0x86db406ed75748bc,
+                    0x359ac5f41cc6d854,
+                    0x00d5304ac4d67df7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdf7ff4f20fd6e7da,
+                    0xa623902c8c8a864b,
+                    0x65b0a14accc6caac,
+                    0xd48bfe47c4b9566e,
+                    0xc867cabc36878d52,
+                    0xffcb2ed394bce56c,
+                    0x5df4aa804700572c,
+                    0xfd4355520efb6bb1,
+                    0xd2a2977d9cfc7ebc,
+                    0x7912555bad816c1e,
+                    0x23408de439c4ac93,
+                    0x010840d14ab3864c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x2e428216399c13bd,
+                    0x9b37f7920e66b86a,
+                    0x9bcf356bb741bdc3,
+                    0xcb8008b535365f2c,
+                    0xa1551a6b67f550f5,
+                    0x5f192612d041e81f,
+                    0x68316121598c7523,
+                    0x9398cdb7e1fd73ef,
+                    0x805fb4c8b402f269,
+                    0xf3638f5baf31dbfe,
+                    0x80b15c1a144d5ed4,
+                    0x00a7638f60ef1b2a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x795d84c173902e90,
+                    0x7142b66d9ba75643,
+                    0xdba535af5f78dbd2,
+                    0xeb325db7fcf57b5b,
+                    0x925a1898208fc23d,
+                    0x1c77e6eea52e3e2e,
+                    0x7d8f1d181f85f8d8,
+                    0xf0709d5f20114186,
+                    0xae4806d3a1db1f5f,
+                    0x7683787a53e3e53e,
+                    0x8510f992c30b8364,
+                    0x0005afaa71c97c3e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x10b1dfbeb1755ae0,
+                    0x01e13b6588832d6c,
+                    0x54bb15959a673055,
+                    0x3ab93b473d6be6b0,
+                    0x753ba69f1205db88,
+                    0x5402d51aae028953,
+                    0xc052a4381428dfbe,
+                    0xa3abefbf8ddb0af8,
+                    0xe947ca4984be708d,
+                    0x63dd05a4b4a61576,
+                    0x2ca63aecefd1b78b,
+                    0x003ed8da85d13e94,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf1a1383104752bde,
+                    0x7634ba96b9300007,
+                    0x5ac85ff246987a51,
+                    0x3e08d208f701b869,
+                    0xb4966604f7495647,
+                    0x1d1e6a0ca5dc0930,
+                    0x7a403ecbe079186c,
+                    0x7858546a5239f9e2,
+                    0x9252f3cafeb5f3e1,
+                    0xa10d565f44651ce9,
+                    0x1c2f4ed5f15b0e8a,
+                    0x00fd22b77765476b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf7cae08d7ba9aa0,
+                    0x81ffb035b0a787dd,
+                    0x537b52e31bff8c72,
+                    0x6f5b3d5bf7542a66,
+                    0x9a985d1a1f4f6801,
+                    0xf2d369cd19d9e736,
+                    0xaaf9c7952c5be93d,
+                    0x63c9c052dfd76605,
+                    0xe6023e5091355c2e,
+                    0x8e26004b18d9bc95,
+                    0x7e5c1389e020d2f5,
+                    0x0015bc46b1f045cb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb84f3a958bf2d57c,
+                    0x39c838228bbaac24,
+                    0x6fdff324bf236966,
+                    0xb22492d0fe181646,
+                    0x0a1e73a4bb46cc5f,
+                    0x91ccbaee4d22f2f7,
+                    0x392149bb78abb7bf,
+                    0xbfceadbe4ce505fa,
+                    0xa1f1b363d0aa1d63,
+                    0xec4c3ced961f0dcf,
+                    0x47a63984740d1c43,
+                    0x010833372d15cba4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x972a15d84edd060d,
+                    0x38032dba57772cd4,
+                    0x4677d45fa2ca20c5,
+                    0x7260ebbd0c1521f5,
+                    0x9606e783269343d2,
+                    0xbadb7b48ddc92c41,
+                    0x47b45e310eddb6c0,
+                    0x4d1c4651bac2e134,
+                    0xad642982fb704d69,
+                    0xb970441c49ed8099,
+                    0x9d8a413744f46265,
+                    0x01119cd1b2baa525,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7694bb54d51b4a35,
+                    0xdc9a9a917f176f59,
+                    0xabc2124b9aa2a4a6,
+                    0x95e9cb15d30374bd,
+                    0x411bed16ddf59cf4,
+                    0xaf5f7693001df018,
+                    0x1bc96715707a5fab,
+                    0x39587717d516bf9a,
+                    0x186588bf058203a1,
+                    0x30c2e96b560ae7b0,
+                    0x429e8f1f0d6a064f,
+                    0x00a5476df6cd39ce,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x7ed1dc8841c57f76,
+                    0x44e045b198158011,
+                    0xe2cb1e4f7fcd00e4,
+                    0x93bf6640e7312013,
+                    0x8962f1825793260b,
+                    0xdc1fb36e0ecce235,
+                    0x778212ff47571957,
+                    0xc27283ad44754a83,
+                    0x24c5b6fd10f5f178,
+                    0x67dae3dd068b1805,
+                    0x414fdaa6edcc5e1e,
+                    0x0101aa7fe22cfa8b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x69a67e5043ac3ad4,
+                    0x2199d2bb1bdc94f8,
+                    0x6fd1927ebd59c5f6,
+                    0xdd20c2944297afd6,
+                    0xdd9a2f2fd9aea877,
+                    0xfcbe9f76fcf94105,
+                    0x7662488d5ef02929,
+                    0xb202c23579b00516,
+                    0x3f4e0e0bed789566,
+                    0x88b9d0488fd874b3,
+                    0x69dc22664e0ae487,
+                    0x002027165ca24423,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0b3b8fc81fb53b9c,
+                    0xe238b457d48cea4b,
+                    0xe9b86b034f1ac3f2,
+                    0x099dbfcafe1f8433,
+                    0x85d05f8f1fed92fb,
+                    0xe303a604655f8ca7,
+                    0xc04d1f5f688d8093,
+                    0xb58f500c51240be3,
+                    0x0f2408012c6751a9,
+                    0x5a1323615e785830,
+                    0x9f256b7acad53de4,
+                    0x0045915a2761e407,
+                ])),
+                Felt::new(BigInteger768([
+                    0x95f91266b14df427,
+                    0xec96c00e20468862,
+                    0x50381cbde899d169,
+                    0x803bc8a179c23c6c,
+                    0x3e233e880f772f17,
+                    0x0f65a7153e7d734b,
+                    0xf2577565736f063e,
+                    0xf2244aa15fbd9349,
+                    0x91782ff46f1f91b9,
+                    0x7e722f9776d28ce3,
+                    0x25cfc7c7c1dee158,
+                    0x0002c8a814bc921d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf28afcd9f34b6522,
+                    0x911c0af0869c684b,
+                    0xd3d19d638d253139,
+                    0xa1bb85c908d296f3,
+                    0x66bf04553b8cc8d9,
+                    0xbae226d9ae8ed663,
+                    0x1b38891ec18fd5da,
+                    0xc29fef46a141e69a,
+                    0x9a4661d1d33b7ecb,
+                    0x8be6e8eb7c16ede2,
+                    0x5f49040c768e1447,
+                    0x000243c44cfcb572,
+                ])),
+                Felt::new(BigInteger768([
+                    0x65eae996f72190a0,
+                    0xeb660216de6af318,
+                    0x2e20c749eba9d9b8,
+                    0x3f7bdfd210117d03,
+                    0x63bdc6e34812e1ea,
+                    0x52e2e468c60bcce1,
+                    0x26fba43fb51ad9bd,
+                    0x131f13dbcd027654,
+                    0x6dfb047ca4739cab,
+                    0x218c4c5c37826865,
+                    0xd90b851521afb195,
+                    0x00215780a2af6d46,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5067528b8e89427c,
+                    0xa62c779ba461052f,
+                    0x24329ef0960821d9,
+                    0x4b873b9cc7ce4898,
+                    0x5d34bd801b53b317,
+                    0x240f7fdd5ff0e828,
+                    0x3f45b9f0f95b792b,
+                    0xdfbfabdb7b2c3679,
+                    0xeb0414e6ebf41bb0,
+                    0x9e8a91a86d268d4b,
+                    0xf910ac0b68dfe9b1,
+                    0x011833c7d8fb4066,
+                ])),
+                Felt::new(BigInteger768([
+                    0x067de7f2bc1d85d7,
+                    0xa23e1b1f7df78135,
+                    0x8f671fc3e7714ce8,
+                    0x85cce775a95781fb,
+                    0x689e251a5e36ed24,
+                    0x807a0d4c9882d336,
+                    0x9e16142789b632e9,
+                    0xb19be355c358c10e,
+                    0x17dafd50f6058f18,
+                    0x391ef91f70a0b268,
+                    0x0a09594ef565274c,
+                    0x006c53c9aa31297c,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xde0b9cc5538c2842,
+                    0x210a492ec893c0a5,
+                    0xe6189999c72d135c,
+                    0x6b3b7e6f9ae658a9,
+                    0x27a1c3cc66adab06,
+                    0x1be8036af6008a89,
+                    0xab6ffd91adeefa74,
+                    0x0c08dcf43b258640,
+                    0xe06d9da3387e3ee7,
+                    0xf44a8b4fc5fd47a1,
+                    0xbd58ae5861f63007,
+                    0x011b0b1fdc1aff6c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7d2a11a4e8924332,
+                    0x363e95b141a3655c,
+                    0xc015c40100ef7244,
+                    0xfe63731dd478e55e,
+                    0x061f99bc9bf28a91,
+                    0xe376e52a500a1b92,
+                    0x3161d90a091c04f1,
+                    0xa9f600c21faf3c4b,
+                    0xc5e9079dc95c663f,
+                    0xe1c34f1d6b5b1359,
+                    0x64e7be0b5b2ff8e8,
+                    0x00080fc1d6a4225a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x66280af3bf94b496,
+                    0x3e46f0d7690214b6,
+                    0x4e7c80157bf54840,
+                    0xb015a979cd71e5b7,
+                    0x114fe945dead8b86,
+                    0x77b6eb44e1f8daed,
+                    0x1b459820d4faa1e7,
+                    0x49085281bb4ab4e3,
+                    0xe725ef8045f774ae,
+                    0x28afbb8eb9b8754f,
+                    0xfc2e3bc957ec9e90,
+                    0x011011d7005abb52,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa09fa9085edcd37b,
+                    0x9db3ff487d5372b2,
+                    0x0c0e21956f02fe57,
+                    0x0a80837f6db88a5c,
+                    0xe5b912fdc0bd1401,
+                    0x0d7b44c4b9ba95c7,
+                    0x462c8127d99a5788,
+                    0xbec4de69e413fdd6,
+                    0x8a66b3b79c685d72,
+                    0x656cfa4e8f1b472b,
+                    0xa48e5fbf17e6be44,
+                    0x00f0fe036ff1aa09,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x50f0bf747bcc0fc1,
+                    0x6bdd4edf401c1ad8,
+                    0xaa2d1a2e379058cb,
+                    0xad9a55156a9aee79,
+                    0x29f9fbcdc437e4e1,
+                    0xf11691c62c1bdecb,
+                    0x99bc16b930abda8f,
+                    0x8fb6aa1bf6e2e2be,
+                    0xd3411e3cd8ff1680,
+                    0xb4c4a0bc4c0ac2dd,
+                    0x76169aaadfd967ac,
+                    0x00fbf3454eda3f54,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2eed67d86d99058,
+                    0xe74f00116b8a3d33,
+                    0xab804fe3dad3afb6,
+                    0x63ed3fcb83e10095,
+                    0x7f3dc4be6c67dbef,
+                    0xb6eeffb36b9e8041,
+                    0x7d35dade917ae23f,
+                    0xffbcf99a48c86a12,
+                    0xdff693f790e66c0d,
+                    0xf22a940661137ec8,
+                    0x11674236de0fc90e,
+                    0x012108bb648c728e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbcf94de02d282850,
+                    0x8051a3a019f93b9d,
+                    0x3e803455ffadd2c0,
+                    0x7b549a2c90663173,
+                    0xeb9609e9da008611,
+                    0xa9d2accdd73e26dc,
+                    0xeeacbca50ff77235,
+                    0xdc6f2026d678224a,
+                    0x641d22cfe091c335,
+                    0x0c47871e3262d4d0,
+                    0x25ce3c5383f438f9,
+                    0x00dd6974b50bb565,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd91cf6149eafcddf,
+                    0xd0cc69867c9e3440,
+                    0x4353dfc06cf4a6ac,
+                    0x3766f2e67a1d95b2,
+                    0x5056b417901ef4bd,
+                    0x4464ca17d0aa3046,
+                    0x9e7ad3cc574e726d,
+                    0xf9b6600adf93b8f1,
+                    0x72623d93527cbe6d,
+                    0x0e8e9d2bd865605e,
+                    0x6dd8889dc4214ad2,
+                    0x00bc9cecb925e0d8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x77e4a1a6771e3309,
+                    0xcbfdfdfd0c8791a7,
+                    0xcb28ace22e9381ef,
+                    0xbabfadec258fbb34,
+                    0xb74f7d31cfe04701,
+                    0xc9de901097b4ccba,
+                    0x9165a1b1f9a03027,
+                    0x004344bf9e983442,
+                    0x741e500dad2ee1c7,
+                    0x4de0ce4a90513b16,
+                    0x9c55084147239bb1,
+                    0x0054702a0cd3e189,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71ce885b8b29094b,
+                    0xb740d2a5aa6cd0df,
+                    0x89584dc5fbd20d8a,
+                    0x0b6493d81ba69d3c,
+                    0x1ad4a3cc1aea9589,
+                    0xbe6eb3dff4a28c80,
+                    0xee96f56cebb4a4b3,
+                    0xd197518065b1fb42,
+                    0xb855489ad9b7fb00,
+                    0xa419865ed0cf3f3d,
+                    0x2f8be43300f9a380,
+                    0x002abef88e59dea2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x832246e1bdc01cb6,
+                    0x3fd35dbba89343c4,
+                    0x9554728cec1a35f0,
+                    0x3d697dbc35aa45b8,
+                    0x38545cb85647bd38,
+                    0x91162a263604db38,
+                    0xf23dfdc5ce9ebdd5,
+                    0x4b742ee12bc81fb1,
+                    0xa24840e2479eb438,
+                    0x50e70774e4322814,
+                    0xb03e32b428123673,
+                    0x005cc70994b4fabb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ba3cb27b2a33695,
+                    0x57dc6935299cd3e6,
+                    0x7ae28a3fb1f1e067,
+                    0x3cba8b2bbb1af33a,
+                    0xd069deacad79c1d4,
+                    0x6171a69e461f40d8,
+                    0xd3b646de426611e5,
+                    0x191074368c939fbf,
+                    0x52fa870ae08a82ab,
+                    0xa71bc79db6b39237,
+                    0xfe470a7e31b66ccc,
+                    0x00e32073b0bda82a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5c047d01cd168a75,
+                    0x978f352237e97d55,
+                    0xda484c19793ea7be,
+                    0xb0dfa398b6b09be3,
+                    0xb62002a13cadae9d,
+                    0xceee0abb3b96a559,
+                    0xa714a88435701ff8,
+                    0xe544e9eb197f0eeb,
+                    0xd2cd2dc1793fe04b,
+                    0xcb95a78e9a3f641e,
+                    0xf15e9b91d26a1e0e,
+                    0x00d76550385dc606,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f7bf234e5cb2bb4,
+                    0x8f5ca38980b92113,
+                    0x6ac555c5c1d8169b,
+                    0x1b3319159df0dd97,
+                    0xb0aa7c6b6aab95a6,
+                    0x7d6a76742cd94885,
+                    0x5f256fa26a09070e,
+                    0x20371f0f605663c1,
+                    0x6ebb487683ba79bb,
+                    0x5af10feceeca64fe,
+                    0x8f874a62ef804033,
+                    0x00c12f5d3adc778c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcaffe8db2c4a3c49,
+                    0xd050b47dfba7ab0a,
+                    0xd307a9c343598c1f,
+                    0xa486c436ec8e2656,
+                    0x61de87f54e3d2604,
+                    0x2f12143541da1d26,
+                    0x1590d04de67cc703,
+                    0x355e6ea6d974f3ae,
+                    0x57f805907c9aaccf,
+                    0xdfac09bed391c02a,
+                    0x7806dec1b37e46a1,
+                    0x00b7a8d854938c65,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba6705db65626ff6,
+                    0xc8463d155caa275e,
+                    0x7c3318f743519561,
+                    0x5afe5bd6481215fd,
+                    0x2666afa101da99a6,
+                    0x303055a209a48067,
+                    0xe50d9cc0b127b6a0,
+                    0x831516f109564779,
+                    0xd66db54e55214eb3,
+                    0x1c1aaf3298e97d92,
+                    0x3f8c71b43949ff2b,
+                    0x005f72f689c3d800,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0242d3d04bb39a92,
+                    0x0999e9503a5783b2,
+                    0x2161b02aec8c1c02,
+                    0xcde4c9aefe1fef21,
+                    0xbe2ebb808b6f9bca,
+                    0xdcaa1ba2805fed94,
+                    0xa970a935ff1d3fbd,
+                    0xcde12b48e818b891,
+                    0xbaf7c3b876bb2d27,
+                    0x0970bb2e1cb32084,
+                    0x8464d8a6d49bcfe6,
+                    0x00d3fcf4966e3c51,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32e913232d5334b3,
+                    0x4df93e3c4607a08d,
+                    0x1304d4d73b929e35,
+                    0x2c1a6596dbb51a6b,
+                    0xd19143723e7e0400,
+                    0x2d1420980f1f2361,
+                    0x40e577a9afa3220f,
+                    0xa2443c9ca006a4d4,
+                    0x13db046b38586f18,
+                    0xf4e05c522d3c49b9,
+                    0xebd7fccf22d7823a,
+                    0x006fb7d5033a54e5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4bc5804b9acc7d0a,
+                    0xdb4c9a6128fa4ad7,
+                    0x85510b6a7b836136,
+                    0xb2ec8a536d95f3cb,
+                    0x2dad78110a65e9d3,
+                    0x40886857126f474d,
+                    0xa3e1438f4abf291d,
+                    0xeacf23b7c152f246,
+                    0x3b72cdeac39e163f,
+                    0xdb4b7922355e1dae,
+                    0x694fdde084718e67,
+                    0x007e7865fee23181,
+                ])),
+                Felt::new(BigInteger768([
+                    0x88f89913c222e0fc,
+                    0xfb57db59e210b064,
+                    0x930f12780f747333,
+                    0x82f345659bc1e13a,
+                    0xb719706fd1678de4,
+                    0xe7cf9ed2f41cf6ab,
+                    0x73080fd7d864bd69,
+                    0x3e237aac082045a4,
+                    0x141d472a6f1c5ec9,
+                    0x6b0b7f3cdb1da052,
+                    0x87467c71237b267c,
+                    0x00a9c3ad8a65918a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x39ed6f8e09173462,
+                    0x32d8d8a66cc9d385,
+                    0xc2272d02c5a4b0b7,
+                    0x7610023aed4ec7fb,
+                    0xf17cdc00880fc81b,
+                    0x7666b93c55e020f5,
+                    0x1114aae5654d6d5b,
+                    0x1679c5c6fba5391d,
+                    0x90257f18f564efcc,
+                    0x1541d9dbb33e62a0,
+                    0xebb09f52b98d777b,
+                    0x005212d137784450,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3490d8be62baa948,
+                    0xf8e3c6fcc4a01941,
+                    0x89c1c5db5bef5669,
+                    0x18fb631a359018fb,
+                    0x96eaa5e247eaf23c,
+                    0x90964cc65ca48d10,
+                    0x6c1d119bd3e0d0b8,
+                    0xb7be29cf602339aa,
+                    0x560e0fba44d0255a,
+                    0xb731e1f3e4399a25,
+                    0x7f7dd0a9aff4b81d,
+                    0x00a3c04fad132bd6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x637ff70103e33e19,
+                    0x7b64a14249fb5360,
+                    0x3165dfe041d2c5f8,
+                    0xd5613088da6fd120,
+                    0x94c30bff57b22ff2,
+                    0x681aea7abcd5e50d,
+                    0x5f4515d430c92441,
+                    0x2442432b6d75a0d8,
+                    0x111f857480810013,
+                    0xed8372a201be5098,
+                    0x5eb1ba4e6cf8d94e,
+                    0x00a8d4f4792b5ab1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1fc02d16186c74a7,
+                    0x2fdb3f19a594540c,
+                    0x171c396d42c9b5e6,
+                    0x960ad5bfc4c26a59,
+                    0x75e30b4d957be6b9,
+                    0x098e5a42cea69e77,
+                    0x2e30dcebba0dfb6d,
+                    0xc82af4ebbadd6def,
+                    0x36e330e883006005,
+                    0x22c9c3b1a331f739,
+                    0x924afeef42166400,
+                    0x00ec6e8d3221894b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6d4e43f18082261d,
+                    0xb85920759a8fb819,
+                    0xda8feeff0de81156,
+                    0x6c5d756c425c344c,
+                    0xf11ccf0da3215327,
+                    0x88c616a1a50adb27,
+                    0x11282a4ff9e259a2,
+                    0x0f86d463609828e3,
+                    0x972906bad9cf18c7,
+                    0xef8a4c6ddd9a9ff1,
+                    0x0a7308d5d42ee214,
+                    0x0038826c7b4c73e5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf70a33d4d316dc7d,
+                    0x5a92a2455fa648d3,
+                    0x45fd1f4514d322ab,
+                    0xb19b960a346ed4fa,
+                    0x0e4e5539d1570810,
+                    0x4aa5488d32d6f32b,
+                    0x948018333741d1b7,
+                    0x22ed5cdce0e1707e,
+                    0xe1c19fccda7e01b6,
+                    0x555c08be1febfa02,
+                    0x59d2e1850156b67c,
+                    0x005745f3242f905f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x036c504398522822,
+                    0xeebe01da11e150b6,
+                    0xb6360e39622f70d7,
+                    0x2910e00d33f66eee,
+                    0x7e10587a7fc3368a,
+                    0x445bbfcebf6f9a46,
+                    0x5627347bd1900710,
+                    0x1b47cc734287be54,
+                    0x78a127270e621af0,
+                    0x77301c0f9eff65db,
+                    0x2eaa102c754131f6,
+                    0x00894110bbdfd145,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40e4cf107f73aee6,
+                    0x44cd9d5782eeec91,
+                    0xe487a190cba0b105,
+                    0x700db0fc3be67974,
+                    0x66b1d53f9ec12fa0,
+                    0x0ad73a0aaa016439,
+                    0x89744a8689085aea,
+                    0xef0c6a9489379e5e,
+                    0xa6364851ca8496fd,
+                    0x35c4834734eed04c,
+                    0xe0600ba8b3243cea,
+                    0x011426e818ddf00d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd8524b5b1eb9d39f,
+                    0x129af74363404586,
+                    0x9aa0f9d95c8b2182,
+                    0xd5020511f839b951,
+                    0xf6536b988be5bc6e,
+                    0xe540567331051611,
+                    0x15d09965fe5ec89d,
+                    0xc8c42d4f8df01df7,
+                    0x9ad2a756bfb5749f,
+                    0xde5391d116790b75,
+                    0x331abf737e106fb6,
+                    0x0070030dd61cdc88,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdf7c6ff89c7ffdcc,
+                    0xcce0c6367cce71fa,
+                    0x3442675bb4a14e49,
+                    0x30f1e0a797cdef1f,
+                    0x55b848b5a1caeca7,
+                    0x673d980410b48a2c,
+                    0x985293698b405bd4,
+                    0xdd327cf8bdd4a77e,
+                    0x96c34fb8bb1ff51b,
+                    0x8792978b86c4808c,
+                    0x615ca56d86b4a8fb,
+                    0x00c71250cc2e4fde,
+                ])),
+                Felt::new(BigInteger768([
+                    0x69eeb31942ba3594,
+                    0xec8698a7df1b17d4,
+                    0xff4cbe52a0a5b6cc,
+                    0xe3be28e2c4786d8e,
+                    0x19ac41d2e1470baa,
+                    0x178bbf29f5c2940a,
+                    0xea3e37d33300d09b,
+                    0x9a4f0d9e7e8a5986,
+                    0x4d64e7b5266f9b08,
+                    0x9b40c7e12edc1cab,
+                    0x3af6402386ee1487,
+                    0x00f4cc1d2b416e20,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc6ad76d8f9fc4e7,
+                    0x9b18c80fe7eeb0bb,
+                    0x734eb4a0d68e59be,
+                    0xfda4ff5e0599d1bf,
+                    0x05e9e6d774f046e5,
+                    0xc5aad9ff9f75d581,
+                    0x034de9df3f69a6c5,
+                    0x552682e27a5bb84b,
+                    0x9af98f3981b2b9ab,
+                    0xf8c461e9d5b1ac05,
+                    0xfbeefbafc377cdd0,
+                    0x0077663f48653597,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd7bef8f71ea1a5ba,
+                    0x7a643bb58462a5ad,
+                    0x3ed9fc48a10ffddf,
+                    0x4e26c29115fc8be8,
+                    0xd6d4af6a18c9abdc,
+                    0x09eb3ad11b866e9a,
+                    0xc547c0c37fbb8700,
+                    0xa6fbe8f1eace96e8,
+                    0xe1f31011d9ef1f2b,
+                    0x8c6cc666c59f9340,
+                    0x59cce6c0d0598672,
+                    0x0019480ec3d16975,
+                ])),
+                Felt::new(BigInteger768([
+                    0x251637aaba225823,
+                    0xf163d2427d4c2035,
+                    0xf9ec7cb1703dc22a,
+                    0x7132b705c2ac94e5,
+                    0x776eb1426427210f,
+                    0x2fdfc091084eb647,
+                    0x2477c213cfea7d8e,
+                    0x7e2b89a3beef75da,
+                    0x498d191fd1dc7220,
+                    0x4b1e71530e2dcfde,
+                    0x779fee76f706f128,
+                    0x00467659f7edc3b6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0b75b99f885ecc97,
+                    0x44f1c24810509633,
+                    0xf24d449214e1144f,
+                    0x6038c0cbdd7eb6a2,
+                    0x5fba44aaacff6630,
+                    0x47e3a77335f82f65,
+                    0xa9a692887965fb77,
+                    0x2dd74dd3e5e3b3b1,
+                    0xb9741e36d1a84f24,
+                    0xcb81d66a29c678e6,
+                    0x0665b36a7eb779e2,
+                    0x00ed6c2068bda709,
+                ])),
+                Felt::new(BigInteger768([
+                    0x360d471a145f8640,
+                    0xcfb5c0f0df74d360,
+                    0xf6957f8bef4cfd24,
+                    0x55494db4f149f661,
+                    0xb4c10ee5d548a56a,
+                    0x7c5c21d8df403f20,
+                    0x7260a692e384186b,
+                    0x15db972932bd9dac,
+                    0xd826009ca6a9ea6b,
+                    0x4b102708f42b1064,
+                    0x6beef37e2a3f1fc3,
+                    0x00fc32ef2fa545fa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa8f91302a29ee89b,
+                    0xe224131d7545f124,
+                    0xe88e8d94053a427b,
+                    0x328c0b40655e535b,
+                    0x1bbabb93d3092832,
+                    0x85bcb7356928bc88,
+                    0x82af869ef3b207ea,
+                    0xfb60b7a81b07a9b0,
+                    0xa6068a0809d88cd8,
+                    0xdbdccad3c5519afc,
+                    0x782302facf6c8964,
+                    0x009de61de8b54853,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8d9ea7e415354d2c,
+                    0x2810c0b7ee96a5cd,
+                    0x91288a1b247093cd,
+                    0xd2ebb3c0d4578ec8,
+                    0x013f42719abfadfd,
+                    0x3b93bd58bf3af187,
+                    0xc7df6742c211a90c,
+                    0x7c6c930b65d2d724,
+                    0x1dc85e5d6fb358cb,
+                    0x0da2903503903c52,
+                    0xfb439c3b587866ab,
+                    0x00c78aa52df5fbe3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a6c3ae966d5b3e7,
+                    0xf2e0ac9fc09b0a5d,
+                    0x3eb1a27296412a79,
+                    0x090ec20e6260078b,
+                    0x46cfa253ea5b64a7,
+                    0xe505b46726fcfa29,
+                    0x7ce48b4352362040,
+                    0x02c7c2be399d05dd,
+                    0x932dc6c308146003,
+                    0x82ad212f6efeb661,
+                    0x86758c921291225b,
+                    0x0101b0767eb8f1a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7d78a55d3c6783d1,
+                    0xdc918df53617c21c,
+                    0x3144a468e72cb49e,
+                    0x2457aa611da969d8,
+                    0x7e4086f27dd97c41,
+                    0x571991359ac84589,
+                    0x06fe6d0ec53e24f8,
+                    0x36c7cfe770f906f6,
+                    0x8675ae47b2ca510a,
+                    0x669b381091c7d221,
+                    0x2af94857c2f322fb,
+                    0x008d7ccfb4c6fa42,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x5821fa6794ccc4a9,
+                    0x5c28f7b14de39e24,
+                    0x8a061369f8b39056,
+                    0x91a8cc3d0e29530f,
+                    0x8ad0e74c195ec3c1,
+                    0x1dfbd7f9fb9cf3f1,
+                    0xb6fa852091cd0467,
+                    0x9c4294a4f57295b8,
+                    0xc02918f362c9bc5b,
+                    0x57530d6f0ad85d53,
+                    0xe0c53cfa2736b5cb,
+                    0x00da6bd6842e6347,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d0cb1c73df00c82,
+                    0xacaf273df58da668,
+                    0xe9a3111398ad7092,
+                    0xe0302fc0ce129582,
+                    0x1ac85a5fa613d0ab,
+                    0x952e055691766317,
+                    0x34d56eaedc843898,
+                    0x13cb06398400a64f,
+                    0xde58131d792617d8,
+                    0xf6139246bc6a4fd1,
+                    0xf6880bfc167076c5,
+                    0x00cfa1f03728386b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x671877c13e95b086,
+                    0xc27e23feafdc3cd0,
+                    0xaca6e6d59c1bf9fd,
+                    0x7e2aa421a8023dfc,
+                    0xe3dd954188575157,
+                    0x2ce401ffa3ba4ebd,
+                    0x2c1f5b98856bd373,
+                    0xef6fb52dd2aa1618,
+                    0x265d5dc09745ceee,
+                    0xa868da65e66db377,
+                    0x81a15ad7fe9214e1,
+                    0x0122139906f58bf0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x685b03c536f0e453,
+                    0x4b67262d76be5ed7,
+                    0x9ab438edc9097014,
+                    0x0016cbb07b7d43f9,
+                    0x469baec5788d0094,
+                    0x474fe18f3c289e01,
+                    0xb495d77558858985,
+                    0x83a89e04e3494511,
+                    0x22e8ebddfc1b574b,
+                    0x54685075f35a97b3,
+                    0xad540949fe8d7fce,
+                    0x00ec27c2fbd90ad6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe39ff605d8be98d8,
+                    0x49df870187bf12b5,
+                    0x0602f78ae2a8f004,
+                    0x00377f3eeb2d9cba,
+                    0x6767c29569e3dfe3,
+                    0xd0b5354278ee8379,
+                    0x540f3ad333b2e7f2,
+                    0xa41401456ca16fae,
+                    0x19baf3cd5c9609ed,
+                    0x81bc432c348801e9,
+                    0x18fed05dd612ddfb,
+                    0x008fb45e7f4f8fbf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0ce756fee7b27fe0,
+                    0x414d75dadbd88718,
+                    0x91b88b19ec7b2400,
+                    0x443d5b7a8fba44ea,
+                    0x4bebb438340c792a,
+                    0x3e3513f560ed7c82,
+                    0x373b8bbb1f86885a,
+                    0x905ffabe2d730601,
+                    0x7000c78d1947fad5,
+                    0x947960073fb3013d,
+                    0x46d8be47ad16679e,
+                    0x00491cc12ec6f043,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6aea217cd7c63a42,
+                    0x63f88a4bf3231ced,
+                    0xa5ba69fb5fe09379,
+                    0x427bf6c99d8ddf90,
+                    0x76c0f5b209d2e9d3,
+                    0xb45fa2a688146376,
+                    0x534bf42a91eea028,
+                    0xbdcb58a41e173e02,
+                    0x0f1e567b409989cc,
+                    0x6f5babc633f4a991,
+                    0x7aa11e9529b0b5be,
+                    0x001bc16fac618962,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf89d469455010015,
+                    0xb3eb39da926dfde8,
+                    0x8579bf6ae95c2eb6,
+                    0x979952a43cca30be,
+                    0x1023924d3dc3c494,
+                    0xe4f9124b975324ad,
+                    0x0d3bf5e5a82ffe4c,
+                    0xc14f12f8a36d7b4d,
+                    0x1137de20b21b902e,
+                    0x1a96067445451bad,
+                    0xbcb4aac1266cc632,
+                    0x00818df470aa2714,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x6ffe998120c438ce,
+                    0xfbfdd539db8cc1d3,
+                    0x9966310cd88fe3ea,
+                    0x48608ab94ea0edec,
+                    0x1b022831222cf094,
+                    0xa6251e270c6c3530,
+                    0x0453a24f1fd5f455,
+                    0xf3e3473d15be5611,
+                    0x173fcc459ac50455,
+                    0xfa1ceb057e2bdde5,
+                    0xd0be8a777f3ecf83,
+                    0x00a8c7977e7da67b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4d8686f7d41c28b,
+                    0x31ba87dc47ff65bb,
+                    0xe9792d7b800f21e2,
+                    0x636af619c5191da8,
+                    0x2264e91e5310893d,
+                    0xa870e552f8327246,
+                    0x5a9753297af5fa1f,
+                    0x1f0ba7bdc780b866,
+                    0xc5779fd2f35099b6,
+                    0x4fa89b17b10f543c,
+                    0x5454b7fb7cbf3e6f,
+                    0x00a8e2db90835da8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd502250c306020a0,
+                    0x8ab6468a86357c5e,
+                    0x4a66bdd5bfd73c40,
+                    0x38423e6d4ec33b18,
+                    0x013a53f41d8a1ddc,
+                    0xf5cd8d64c6d9c383,
+                    0x69c54924064c14f3,
+                    0xaf10b9050f04fa43,
+                    0x24870252024f39d4,
+                    0x67e2c62b085bd2cd,
+                    0xa7843b49b1835126,
+                    0x012297774388ab1a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x197d7485162105e6,
+                    0x9f103f785baefb87,
+                    0xff5f61fbe4703757,
+                    0x550b9895c3b84bdb,
+                    0x83d06d0c7cfcb8c3,
+                    0xdd766cd546ddb600,
+                    0x7c1825b4ab25ac7d,
+                    0xcde915e0f9a7368a,
+                    0xe82ea00c772282ba,
+                    0x4832f3921dd4d7de,
+                    0xb62d8ee1da977777,
+                    0x00bfd0a51c5097d4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b154b05f1d6a2c7,
+                    0x27ec3d0bed72915f,
+                    0x08033428b1398deb,
+                    0x6428ee5373d82895,
+                    0x13286a9edb3f73ae,
+                    0xf5ee28c7fc5e2a46,
+                    0x6d04c31e70eac54e,
+                    0x8c4d523635ef0ca8,
+                    0x2cadbfada0c5fc84,
+                    0x5d3f9d1b75e1ded5,
+                    0x42e9f54f725cf931,
+                    0x00838e3dc1d8ffbd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb40a2d9996bd8159,
+                    0x60f7b651487abdca,
+                    0xcbcc51bc80f18fba,
+                    0x5d290ff42156e74b,
+                    0xb4d3fdfb3d7e1ca6,
+                    0x2e1b9567e68adf93,
+                    0x610f9bd050544170,
+                    0xc0b291d2b16a7cd5,
+                    0x75f5eb72a235d976,
+                    0x523bcbdee6461def,
+                    0x6708263d36844a59,
+                    0x0078581a555551d3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e7db33835161e46,
+                    0xbfca91b7a7f631f3,
+                    0x26edc4e9bcb089c2,
+                    0xeb30968301b8d923,
+                    0x9d3a0e3ee44c7f07,
+                    0x98f3b7d1be45f993,
+                    0x2b466dd4791ca1c3,
+                    0xd6c29f06eb286ee9,
+                    0xc2a2f70d9425902e,
+                    0x0c55f71dd237abf2,
+                    0xec07f50f6fb7b72a,
+                    0x004bd247f9150457,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4fb8bd2b7db3075,
+                    0xc49f3a5674c0eb0a,
+                    0x03041ff8347ef1ce,
+                    0xe905aeba3f158e83,
+                    0xe403e7236756bc48,
+                    0x5f5c8d2a1f7f1c6d,
+                    0x867442c4b04c9fb2,
+                    0x6bec3655b1f5ec44,
+                    0x2f4e43ae8408a2a8,
+                    0x4d60f3bb99991912,
+                    0xfdf7bc0a384ff364,
+                    0x0018326d3ddc7a88,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xed9ec6553cd673db,
+                    0xf5a5531f5ba9e707,
+                    0xfcdb08d7b01ce8b6,
+                    0x5318ee84a7b62d21,
+                    0x3858c2867b0a1757,
+                    0x7f6bb5e170802a91,
+                    0x68fb3366e0ae1a87,
+                    0x3464cb4139625961,
+                    0x436a0abf75d696b3,
+                    0x689194ab47048ec9,
+                    0x79090b6546fcaf6d,
+                    0x00223312ef2a0376,
+                ])),
+                Felt::new(BigInteger768([
+                    0x50e96ac162abdb06,
+                    0x84ca470baf894f86,
+                    0xaed5b0a7b11355f0,
+                    0x2f7535174533f51b,
+                    0x80f507a8ba21a167,
+                    0xcdb780a52bc4f46c,
+                    0xd6d6d77fce614034,
+                    0xc7ac1463d70ece82,
+                    0xae76c7f22ca11bac,
+                    0x71b3bfb5dc3b7720,
+                    0x9c7bcd0f027d8d39,
+                    0x00a8fc8754dac283,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfc48dda6bb4e3b93,
+                    0x8921fb61d261438e,
+                    0x42a3fb3742b38bfd,
+                    0x8f19ce415d3eba4f,
+                    0x2584984118368a2f,
+                    0x6d2b3bcc4c8eca68,
+                    0xd7b6dcb5339474d8,
+                    0xa3da544756eda313,
+                    0xfcc2b5213d52ac8a,
+                    0x9c81ca6e778c8dba,
+                    0x9bd33958950a51dc,
+                    0x00e11e5c28fd26b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3cdd21fe625cc461,
+                    0x4e4300fcb3c2124f,
+                    0xb733880b4438b34e,
+                    0xb7672caf0de64345,
+                    0xde78919529617d62,
+                    0x3750511bf1916c29,
+                    0x6959bfa4a726a77e,
+                    0x78b047d923e524f1,
+                    0xcc04d19b87d7fc4f,
+                    0xc080da031647d61b,
+                    0xe54aab023764be7f,
+                    0x005df34c5e00b236,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a5fc935bd385b77,
+                    0x5d4864808c1ab13e,
+                    0x979e6f187a3d95a6,
+                    0x071a43b58907514f,
+                    0x367dce8668258ad1,
+                    0xeb84e56bd49639aa,
+                    0xf179c5e55197046f,
+                    0x1f538591271bbe2b,
+                    0x5a7e1d7e31f4baf8,
+                    0x706d21b9ec063566,
+                    0xfe3b940cd289da4e,
+                    0x00e76258b8dfccd3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbcc53995645884c2,
+                    0xaefeb5f0fabdd37b,
+                    0x9e04e095aa380445,
+                    0xe7d87a3e2a1c9025,
+                    0x2d62710dc583e074,
+                    0x3e41a99fbae44bee,
+                    0x71c7795df1327722,
+                    0x2d464b1b797ec7d9,
+                    0x406ba74368cdf4d1,
+                    0x506ed7f73ad276dd,
+                    0xe2629d2c2c61afb4,
+                    0x007920c8bbd3fe2a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8dde7b26b46156db,
+                    0xc9046da66df7faca,
+                    0xa230f29111b56121,
+                    0x3772418df75c7c43,
+                    0xd5a1d4d5388da2e4,
+                    0xa6672127bf695390,
+                    0xd1a5f35bceac7062,
+                    0xbbc3d14750df2b41,
+                    0x56e692cddf3ba1ca,
+                    0xc88a6659ed9bcdc2,
+                    0xbeab2edd147fccd0,
+                    0x004676e44460d165,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcb611221e7c5f705,
+                    0xc87301821cd7a13d,
+                    0xd184887001970aff,
+                    0xc0207c71d922ae0b,
+                    0xa93bef883822c873,
+                    0xeb1d30ad050fcf36,
+                    0xdd62f66e711ec85c,
+                    0xc9a21672b32810b1,
+                    0xd3a9dd096be46160,
+                    0x9ac0093df1296c04,
+                    0xfb989e5c0e9e1484,
+                    0x0121c5b0a745a79c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x937c407b63096901,
+                    0x0814bc87c66f7ad7,
+                    0x4250a686c4dc9ff3,
+                    0xd7d7b13b3951d152,
+                    0x949726bf714c5990,
+                    0x907b28ed08a65364,
+                    0x0f000613af53e39e,
+                    0x6d9a740da601d487,
+                    0xa449b443c2367794,
+                    0xc7f22b6bab2e32d8,
+                    0x81e49716a7064dc1,
+                    0x00dedcd66e9bd288,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeafd03b2ef4acdea,
+                    0x0a32373d5a09b2a0,
+                    0x04bc147a40bdc226,
+                    0xecd71978eca27d6b,
+                    0x400108c4bcde55c0,
+                    0x7162aad75d60b25c,
+                    0x755bd7a5d2ae045a,
+                    0x83009c376539388a,
+                    0xa028c8004993aaf8,
+                    0x412d6eadfccb4690,
+                    0x2f54e4ed1f2b66d3,
+                    0x00624cee9caa1e10,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd1636f01ed8e34e,
+                    0x874980c628c1bca0,
+                    0x6cf9fac48487f92b,
+                    0x6f56481fe656a3ee,
+                    0x1c86f18cea7c1ffd,
+                    0xfd17a9d40a992ab7,
+                    0x96c9e234a792b191,
+                    0x8b7f1044d618778e,
+                    0x57de3f6f125bc76d,
+                    0xa189a098abcab2a1,
+                    0x01f678abb7d8db13,
+                    0x00bac660adb3dddf,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa0afc8fd71c29b2,
+                    0x69c836aee4f23f16,
+                    0x86f6e7ef3b8cc6c4,
+                    0x5c71cac641e2b18f,
+                    0xe364c5d34b7dea4b,
+                    0x66c1eac4327b14a6,
+                    0xefbd7bb203bdfd23,
+                    0x23be763c84c8f953,
+                    0x47b447628b81d037,
+                    0x03ca3f52ae9853db,
+                    0xcb5fa4ccfb6a068a,
+                    0x00df27fd8a1ce57d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90ad68e248c2a0f4,
+                    0x970c032777dba5ad,
+                    0x285460ce4cca295e,
+                    0xa871580687161b09,
+                    0x4ada4d36dcdcf37a,
+                    0x069208fcc8d9b8e8,
+                    0x4fc17d4970ec9cf9,
+                    0x28f45812744999b9,
+                    0x42c0c0884d548f76,
+                    0x90ce07ec27d77ecb,
+                    0x2fdac347661ff9c3,
+                    0x011d3ff824461650,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc69f0f920b83a7e0,
+                    0xadeeb093e7af02d9,
+                    0x9b66c390eac7152c,
+                    0x9352cd22d22b7601,
+                    0x962e54203ed90590,
+                    0xd4c7447629c5bc62,
+                    0x5745cfad84f6b151,
+                    0x0f5f32902de9035c,
+                    0xce57e5ccaba9ea7a,
+                    0x459d5dc32438ca48,
+                    0x67336ec79b55b002,
+                    0x005ab262788b8f5b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb86ba3a4fe0603c6,
+                    0x970da2cd08d346fa,
+                    0x2dbbfa47587d8d82,
+                    0xfb868663467450d0,
+                    0x52cb5fd688f1faf1,
+                    0xf84fd07af03b4222,
+                    0x1c1fce782b4e7019,
+                    0xc2591d1c6e492052,
+                    0x160327d711049907,
+                    0x3f44d79877e357c9,
+                    0x992fcec3a1c7c919,
+                    0x0027f16e876dc335,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ff44ba288054f04,
+                    0xc44cdce16820708a,
+                    0x53a56c951ec117ed,
+                    0x6074d0bcdf61a50a,
+                    0x623af5caf0352f2d,
+                    0xf9b0646c5886291d,
+                    0xbd48893fe7ac29b6,
+                    0x2ef6ea7988a3bde9,
+                    0xd864b6bafd109e03,
+                    0xf3571ccbebf088c9,
+                    0x32610293aed2cb58,
+                    0x00861d98184a3581,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x378a8d08e255698f,
+                    0x8966087ba1fc63ec,
+                    0x31595b31ac17947d,
+                    0xd905d784c5f09a85,
+                    0xb98a6ed1bc9ea0dc,
+                    0x26e1086263ecc6b1,
+                    0xa4e53ef4626780ad,
+                    0x4a954e102074b740,
+                    0x0a552be87ad56ac7,
+                    0xf93414f2fed65857,
+                    0x1eb4b61fc8c67ba2,
+                    0x006495a1eef2816b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa09007441d08df0a,
+                    0xab0172613b1b29eb,
+                    0x046fb18558eb5838,
+                    0x75c728d7cfe1e196,
+                    0xb199e4d8837265f0,
+                    0x1c7f91f2a0dc4dc1,
+                    0x8a6c83604add9c03,
+                    0x78ead39562fe9cc7,
+                    0x607dfeea2f0cec2b,
+                    0x47515aa9b0c0cc3c,
+                    0xcad298429f70a319,
+                    0x006108557815fde3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8807af2c393a97f7,
+                    0xc321f841cfb17842,
+                    0x3fe73813f76659da,
+                    0xc3de13e4a267f63f,
+                    0xbbb31a4c18b01b42,
+                    0xf85a0fcbdfadd60a,
+                    0x0a0d6fa02512b459,
+                    0xb7ea53fe6609416c,
+                    0xb10137c98ea22609,
+                    0xb258a76380028c14,
+                    0xa99bbfbbba1d9e5c,
+                    0x004be6c704a549ef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1151c63b672e5bf2,
+                    0xd9b87658ef9e2d1c,
+                    0xb7d0ed45e0436262,
+                    0x2a36c45e2917aa34,
+                    0x9a7528eb96115fdd,
+                    0x4bf7ca71d9880aae,
+                    0xe4453c2b5ee12212,
+                    0x4292d4278560bf45,
+                    0x57e0ba67efe8d2ac,
+                    0xdf58b1a530cf238c,
+                    0xc660c08c190f09b0,
+                    0x00a57c4d9fdb7d01,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7d7ce32117146aa2,
+                    0xb3ee06820912461a,
+                    0xda5e2d555d73679f,
+                    0xc8fcce456fc9810c,
+                    0xc3c3fc0e531a3f80,
+                    0xecff9c5933e21eb0,
+                    0xd3e464e9b3f13488,
+                    0xf76448a861838884,
+                    0xdb62c68af904f76d,
+                    0xfeb335fabd4066b4,
+                    0x3640efccaa13ec39,
+                    0x0114d7fbcae511f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x253ab1c665aae4ad,
+                    0xf91be112e9840da8,
+                    0x82f8a41ea6060d18,
+                    0x860b712e28474dac,
+                    0x45b27c7ff98aecf0,
+                    0x48659d2671ce3a8a,
+                    0x2cbc4df7002c9dd2,
+                    0x628dbb8c54a152a1,
+                    0x79fae0051a8ed192,
+                    0x75c8fa0a26bc9413,
+                    0x35e82eacaa620e88,
+                    0x00bf05ef72030249,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad48d1075510ae7f,
+                    0xa3fa419ca7e90050,
+                    0x98a6425d96e2f706,
+                    0x9e3c8a217765b27a,
+                    0x33f8c9cda11db3bc,
+                    0xc55d3913c7413bea,
+                    0x660e44230d91ac3f,
+                    0x40472f218709c0bd,
+                    0x18aa8c344828ab2f,
+                    0x4fcbeb0fae2dadfd,
+                    0xc25d6c3324781503,
+                    0x002aeccf6134d3e5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x509d4fcf95eec5ab,
+                    0x4bdfca52d85e8839,
+                    0xa007208a01905812,
+                    0x7e95e514e7b74b3c,
+                    0x4218b79351d47a60,
+                    0x72f0c0248ea21ace,
+                    0x27f7f9c47c514735,
+                    0xa7d2e03f8f2ef504,
+                    0x8861ec7de9d42a9a,
+                    0xf1e409e8d42c0049,
+                    0x2375767f3f367884,
+                    0x009da14a97f44249,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x6f3ded0b174085c9,
+                    0xa0d9c5da42f7bb80,
+                    0x26ac95a0afd62e87,
+                    0x583b95ef45383b8f,
+                    0xeb52d0f24e3107e8,
+                    0x38cab95cae27ce56,
+                    0x389fce8cedcc0c46,
+                    0x2fc43abb22e65dc2,
+                    0x7bfa661bec49bda6,
+                    0x987783b27da9d255,
+                    0xc219b452e33d1a57,
+                    0x003385f3fba1f68d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc72eda5e2d854203,
+                    0xd24dfc7e97d7739e,
+                    0x7f0837adb0b5c03c,
+                    0x713f0ec332533c2d,
+                    0xb5ea9c181f5be5d0,
+                    0xd74d65228fcd6b5d,
+                    0xd786d4ef651d5e04,
+                    0xad7fa5663f17efa3,
+                    0x76fa0ca9ea66d2e1,
+                    0xe0b11929da1c498d,
+                    0x2e1e3c98e48a3ddc,
+                    0x0051b2b91ed24571,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6e71ec1fd1fe6f04,
+                    0xfbcb7d219a1aa5c4,
+                    0x3d3bb089c6680902,
+                    0x84e00180f8579f5c,
+                    0x6a2060395f88b2d2,
+                    0x96d8593905a9d398,
+                    0x98f5dde5e091b5e0,
+                    0xf9698ec43c1d5cac,
+                    0x5bfcfb3f012acb03,
+                    0xc99e974c216ebd6d,
+                    0xbad8d938da4ad772,
+                    0x01104e8ecf5a6a8a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe20a2f0544c14a1b,
+                    0xc04d289b4bcafa72,
+                    0xb9eef6b5720fadfc,
+                    0xe6579f3875ab461a,
+                    0x08b9b274e3491324,
+                    0x0f169705215d7a26,
+                    0x465b916e044b5cc8,
+                    0xd3eb8f017e058b1e,
+                    0x4d843d736b2ccce3,
+                    0xa6ff4c12767bd02b,
+                    0x7e2a336ee384fcb5,
+                    0x004e02cefe514c97,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf64d94c75e5438d6,
+                    0x257b834860f2ade7,
+                    0xb13753bf2a023d98,
+                    0x9b68100dd13238bf,
+                    0xb46c2a3802159512,
+                    0x94ac71f048882bf7,
+                    0x3bd232af34362e5d,
+                    0x04d745e1a591a1bf,
+                    0xf17e5d8b29c0e52f,
+                    0xe9a359f3204dec68,
+                    0x18bbba91d6a6b3d0,
+                    0x0063276a2e198d0a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e2c3f14103f3914,
+                    0x35694cda8a7da607,
+                    0x06f558eb2fb5265d,
+                    0x8dabb5991cb8b78c,
+                    0xdf1d7328bc110b7d,
+                    0x9f15cbf08106ee90,
+                    0xce65920a3eb14c25,
+                    0x9c78dddf2553c12d,
+                    0x6683f5366481b427,
+                    0x3a4c75736697e674,
+                    0xc4d218edfa822929,
+                    0x006d85618ac965c4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xedc25b60384927dd,
+                    0x828fb02511c2f3c0,
+                    0xcfe890c0c8090ddc,
+                    0xe5dcfa0983e02caf,
+                    0xde6fd28181725bf4,
+                    0x6af3ab18825f74ef,
+                    0xa1a2795d80ad0c2c,
+                    0x8f894cc9b36b21c8,
+                    0xe92d5f449d511596,
+                    0x877ac83b878a235e,
+                    0xec4a1df3dd983f33,
+                    0x00b4f9a632c2b800,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ea685f5b1a06b57,
+                    0x691f77fc93164fb8,
+                    0xe37cc11e853c702d,
+                    0x24b672ff7163200b,
+                    0x2cb9f8b336aaed23,
+                    0x693970b5bbc57049,
+                    0x43619f35345ff5c4,
+                    0x2c6eb783529da09f,
+                    0xb9fd767a2b1c2654,
+                    0x3430914f776e1f3e,
+                    0xaa895a858563249b,
+                    0x009d7ca153b627ae,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x78c362665a8ff26f,
+                0x6621522f108cac65,
+                0xd49f0de7dd3383c8,
+                0xf2f2f101fdba5c2b,
+                0x4110b213ba32d6c2,
+                0x777d86c4bbca1baf,
+                0x36a679e61a37b2cc,
+                0xdcd4c2302f29a313,
+                0x72de5c99e32afac4,
+                0xf1de5f7576898a66,
+                0x1fed756c23038747,
+                0x00de5a722c03eb0e,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xbc1ed9e6ce7d94a7,
+                0xf096a0ddf23dc661,
+                0x955a941bbe349547,
+                0xfa5fddacf5c8bb4c,
+                0x8f8e02f83efb3e13,
+                0x029fad9806b4bd7e,
+                0x98ad510bb8503865,
+                0x1426313d45286cc2,
+                0x922fb0c91b5cc96e,
+                0x96530fcd3272a33c,
+                0xa68d4612f70db6ca,
+                0x004e49f32f0cddff,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xd3dc3c0b72aa8f14,
+                0x345d592b192479ae,
+                0x4eaafec5dd81019a,
+                0xa7a733e9db93e86c,
+                0x690588867aa05c68,
+                0xf44b4bc7f58177bb,
+                0x422220c3d0a58191,
+                0xc5e3931ed72105de,
+                0xcf33eaa62e885569,
+                0x30d70b517a34ee17,
+                0xa8de60669ceae333,
+                0x009c2e7ae51c9507,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x87ad5ded448e6152,
+                0xf2604d6e30f46fcc,
+                0x682e733bebe0976b,
+                0x9a55af36dc7263df,
+                0x0b560eea0f990591,
+                0x9e87592c8dfa904c,
+                0xf93b0f36ebb55ea1,
+                0xdcf842209196dba2,
+                0xcae94537cda6d906,
+                0xb0013f97f1e279c9,
+                0x9569a3eaa4bca591,
+                0x0069e0325a8a05e3,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x1f25223997451b45,
+                0x3bd3ecfb2d2fbfeb,
+                0x8b386c69fb39288b,
+                0x3a0fe6e57025f187,
+                0xbef05f5b80313a00,
+                0xcc42f91c58bdfc31,
+                0xfd0d5a8288c821b5,
+                0x46cb7197f76b807c,
+                0xb73888017299e255,
+                0x27781fa0b998d819,
+                0x48653e076abcb314,
+                0x004901070c5bc94c,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x1583416668520561,
+                0x2c8fcebd77c7d7a9,
+                0xa99691f64c39db5d,
+                0x9b5cba031122fa69,
+                0x17413c9e805a0054,
+                0xe8965adae576eabc,
+                0x714538c2b0d96285,
+                0xab58c0b69d435a67,
+                0x84deb502278d5a17,
+                0x8d1eefc56d040762,
+                0xd97b3b96af55f67e,
+                0x00265c781b47a729,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xfff5df35fe1e6a4c,
+                0x4e48df87bba18b31,
+                0x53d08730737cf0ae,
+                0xb6c0199a90021910,
+                0x82dd0b0148118691,
+                0x85bcc8d466d1d3d5,
+                0xfdf8641416e1de16,
+                0x8c0f37493f113d9e,
+                0x7d180a0f6d83b445,
+                0x027f473886425715,
+                0x3805f6dc1e0660ca,
+                0x00bae2f61c7a2511,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x2586e0768d870f64,
+                0xdb8b15f7a5fc2a5e,
+                0x4c2451c8da561d88,
+                0x8742ef7ccbd08ceb,
+                0x7d2ae833589cf50f,
+                0x5541de404d37ccf7,
+                0xf9a91da9786e9443,
+                0x1b102f7b88c01b38,
+                0x34b2ea147111b5a4,
+                0x7f712ecf11943135,
+                0x48f24be7fd13ccfa,
+                0x0103a15d4b0c06c4,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x62fc2310fee7c00f,
+                0x1d2617687fa10b30,
+                0x902b35b553b0ea59,
+                0xe7becde0d84e0b21,
+                0x94706fe97b10c90e,
+                0x82e02863033107fe,
+                0x89ee1ecdd5bb0a3e,
+                0x5975b83da9df6177,
+                0x44e17f93742d9183,
+                0x9d08e3762c1b794e,
+                0xa5e3d4de7122c47d,
+                0x00bfe256980cb6df,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x1cc618c40e590af0,
+                0x271e66a1d2d1b69d,
+                0x6dcc004ffddf5d7e,
+                0xc58b5d0ac7b66ec7,
+                0xd53989573ee9a60b,
+                0x72477df2370b3fbf,
+                0x6211d5dc6600bad6,
+                0x2906683862cb143e,
+                0x062d2518202b2d98,
+                0xc37a38bc1584fd4e,
+                0x88b0c3ed9799fb71,
+                0x011ff1aef65eb704,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 570, 571, 1715] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 570];
+        let mut opad = vec![0x5cu8; 570];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
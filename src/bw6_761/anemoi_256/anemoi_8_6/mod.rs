@@ -0,0 +1,3707 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 768 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 6 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 6;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 2 elements (192-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 16 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 16;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xdd91836dd7a699e1,
+                    0x1aff6216ffedb308,
+                    0x54bc55dcde732138,
+                    0x553f05b91036889c,
+                    0xb583f40a8833b059,
+                    0x54a5f37eb4927816,
+                    0x0ebf275bf069f336,
+                    0x4c7b7546402a6f21,
+                    0xcded4cd3615f6dcc,
+                    0x5108c689a51ae952,
+                    0x837a51b1ca839c5a,
+                    0x00610c0317c44752,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5a9128fe60425213,
+                    0x64c821fcacb6b08c,
+                    0x18d75043efc038e0,
+                    0xf8e5303ab1aa552a,
+                    0x7bd401dd04024598,
+                    0xce860eddbed53018,
+                    0xefc484350661718e,
+                    0xb8e22b462aae3426,
+                    0xfca93e057676c0b6,
+                    0x7ef64255530c0bfb,
+                    0x94429679396dec81,
+                    0x01085243c5915602,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2c093f8334578cce,
+                    0x463ab9c8a3bfbcd7,
+                    0xe021f0d8027523e8,
+                    0x00af47290e698a04,
+                    0x8250d9fff797868d,
+                    0x6898edeb3b3ff695,
+                    0x80b799b24b19d9a9,
+                    0x2f84c341f190717c,
+                    0x7aa933eddebd70dc,
+                    0xe0a12a3c56f7bdd3,
+                    0xce658cec04c50fcb,
+                    0x00c52fa4ff3f564b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9b6ee8f6e701f19,
+                    0x12a605da16965267,
+                    0xc778eda059f29946,
+                    0x4a0f627eac720700,
+                    0x01e9de48aeae47d3,
+                    0xb223b0197f03637b,
+                    0x5572f2014ead2f44,
+                    0xcaecb7c015ff6ea4,
+                    0xdad3d6c4a7066f88,
+                    0xc59b7f893942516d,
+                    0xfc5bfd0afc163e46,
+                    0x00defe1bf69581c2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf467b9d75ae1fd28,
+                    0x2705de3bb039b0e6,
+                    0xe1c68937bb04c679,
+                    0x8fb4f0b8b751de37,
+                    0xf28c957e6e868406,
+                    0xc0939127ea71f073,
+                    0x32e97506bb89c9aa,
+                    0x6c7b913f71914433,
+                    0x531768890577f9c2,
+                    0x5c1ff71994b5c910,
+                    0xb3cf2e646ffb4c81,
+                    0x010a4ab74afeefa6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9d60352de63ebce,
+                    0xc1c255ceeebe9391,
+                    0x21ec2f2c8ddf4e3e,
+                    0x686eb120902f4adf,
+                    0xe82ca443a9f00c48,
+                    0xf8e0dd43c7dc4a83,
+                    0xa0da7f194bf155ec,
+                    0x8c314a96041ba574,
+                    0x59c9039212d166c2,
+                    0x8dc3efacce056d82,
+                    0xcd356bc264de4ddd,
+                    0x00ef3b69ca6ddc77,
+                ])),
+                Felt::new(BigInteger768([
+                    0x881491415aa249e6,
+                    0xceec42c38a721000,
+                    0x264e2dbc407d52e0,
+                    0xf2e64438b96facc0,
+                    0x77a845c0bf3b37d9,
+                    0xa5627bf63c843e81,
+                    0xa141390d18f447dc,
+                    0x39fb389a89b865b7,
+                    0xaf792804e204cf0a,
+                    0x078a3af7943d9e97,
+                    0x85cea900543e4bbb,
+                    0x0045734ed8045a29,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f4012af07b58c48,
+                    0x68e5b8678d42d71c,
+                    0xbffd73b04d426961,
+                    0x2a337742844bb570,
+                    0x29203eb39cb138b4,
+                    0x9a60c8165788a6ef,
+                    0x2ced216f9d77b3a0,
+                    0xb2c8b1c46436a086,
+                    0xaf117ef8afd9394e,
+                    0x4db32c80028a473b,
+                    0xc38e6857b3f1eb3d,
+                    0x00aeaaa5d5d6346c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbcab69bff25b98cd,
+                    0x42c222fb560a0490,
+                    0xfb9b93895e75d58b,
+                    0x3f5619ba2fa8623d,
+                    0xf7a49261c5570c8b,
+                    0x6c9ae958611948b1,
+                    0x461196c83123e72b,
+                    0x28aa24c3a7c92549,
+                    0x40d4449e3b996548,
+                    0x71fec525de5e4989,
+                    0xb2dfd459a2caf49c,
+                    0x008d31e8c824c6c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4d07d2fe2b37b0a,
+                    0x3cff4c80c8a3db3e,
+                    0x803511d519507365,
+                    0x4d86c7c70a7362c9,
+                    0x32ff335c2a619835,
+                    0x57567ee0d6c46c78,
+                    0xf7cc4e9617c01638,
+                    0xe9d99e0a710fa66b,
+                    0xda802f1f4b9353bc,
+                    0x6476b848b462b40c,
+                    0x48eaa593ebb3ac96,
+                    0x009e2bb77d52f254,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf01f01667629ef6c,
+                    0x825255d554c4e520,
+                    0x88afb6d39c1d75d7,
+                    0x1db5049c08e87068,
+                    0xf729e14d70d9e68a,
+                    0x8b4438110cf65731,
+                    0xba5227d943d58218,
+                    0xf2648494c5b1a357,
+                    0x3650b985c79575e0,
+                    0x8071eba5f2edd6e5,
+                    0xf295e373cda2a515,
+                    0x0010f21865509cc3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x150842b5669c8e11,
+                    0xcf9cfc6b3a86d44f,
+                    0xd01f03eb95e96f7d,
+                    0xcb514f7d292c9810,
+                    0x751ba775a86f954e,
+                    0x4f3868b05d67cb8e,
+                    0xd4a01bd3edce87ec,
+                    0x8cdd9c6649b294c3,
+                    0x57f62181be9725ef,
+                    0xb4b38fdd2d09f0cb,
+                    0x9e33bdb30db4527d,
+                    0x00ed788c31cae38a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87529157ed03a85e,
+                    0x93b5dc663c782135,
+                    0x695aee84d98f3a2f,
+                    0x0cd8dc32c6995a7d,
+                    0xd36f0553eca006c4,
+                    0x20ddb31ac2d2d651,
+                    0xae74b46b47a24479,
+                    0x56c5cae993571598,
+                    0x4bba9000b0289747,
+                    0x4cb4ad2b6df2d5c6,
+                    0x9e5331f9f369c9b3,
+                    0x00e8e50d96c97a01,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc2f9672f1c63d964,
+                    0x60c58256bb904b37,
+                    0xe4fcd565e42c1a8c,
+                    0x9c4ac4578e079101,
+                    0xdec61b9adac121e3,
+                    0xa51831321e73cb90,
+                    0x4039ed8a704bd912,
+                    0x85dd81db85b602ad,
+                    0x439fda3883d338c0,
+                    0xfda49ca9c951e747,
+                    0xea8b0c1a669714dc,
+                    0x007d3968b166fafd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd289234f8724b4fc,
+                    0x626e2519f2762f51,
+                    0xdb482a63f44ff57e,
+                    0x874c6795fa379d3a,
+                    0xd329f79b59256782,
+                    0x32a5a6c31c0fb932,
+                    0xede7f2d2bf88407c,
+                    0xdcd5dd4fe5aa973c,
+                    0x912f444577f2936b,
+                    0xc44933d106dc992c,
+                    0x98729ba70009d1b0,
+                    0x00533fc1055766a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x86afeb8a631a6647,
+                    0xdbb89c4622acc8f1,
+                    0x3b3faa88ed411813,
+                    0xe9d119b94258c666,
+                    0xc4f0c398f8843d76,
+                    0x9c92b045022ae4db,
+                    0x763b99bc4ca3f4bd,
+                    0x0d6b6598f12f05cc,
+                    0x130fa324f3d4300c,
+                    0x15ae91e3a1153240,
+                    0x621b1e2a06333a35,
+                    0x001dcb0a6c9d2fec,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x40fbb60d0b952eed,
+                    0x1ad9f413e1ce1ca4,
+                    0xd3571f10db4c066e,
+                    0x57767fee71f87a42,
+                    0xedd28102f97929aa,
+                    0xeebcff15d8ddafad,
+                    0xfecf2b024e6751a8,
+                    0xba47c701855322ab,
+                    0xa9c433b22b61005a,
+                    0xf0d3e7c994593005,
+                    0x6c05359a57bc68f7,
+                    0x00a426029227db54,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdfdb4dc61af12e18,
+                    0xa24a94659ba95bc5,
+                    0x6f8cedb2e7a226cc,
+                    0x352fa4c612edddc7,
+                    0x399f7f4ef6ab1d61,
+                    0x49cae173a554fc45,
+                    0xd14bd02bef2adbee,
+                    0xdefe721297bbfbd3,
+                    0x14e2dc47b28fc6e9,
+                    0xf52c3ee7b39f906f,
+                    0x9df4f8a7f5ce8c8c,
+                    0x00f0def23e37184c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x595ad0bec09e456e,
+                    0x6219e2824d30493a,
+                    0x4a33f131dd39fea3,
+                    0x02967c1354ef96e1,
+                    0xaffe96235265f7f3,
+                    0x7d88fe014f9c71eb,
+                    0x079412e51df3f104,
+                    0x7253ce8e3f73c0ab,
+                    0x1b9b27c1818d07b3,
+                    0x7cb8bcacfabf598a,
+                    0xd94743c02b29f33d,
+                    0x00e234589b96b0bf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x31f577afafb54e2f,
+                    0xda0e587ed6b2edbe,
+                    0x23283fe0ac2794c8,
+                    0x67be3924e8788312,
+                    0x7b3cacae44fa78db,
+                    0x5fc29fbd42c32f53,
+                    0x0c3992dfdb82b414,
+                    0x308715d6065b17e1,
+                    0x4e0c467ea17f54ef,
+                    0x9475d79552d12681,
+                    0xdeffcbede3f2d7df,
+                    0x00767d2b70352535,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51508bd723eed088,
+                    0xe4919208ee19e992,
+                    0x0d734c5d663eb270,
+                    0x55f3b6b227e3a60f,
+                    0x6bcf89977224d8b4,
+                    0x27e89ef2b1ed14ed,
+                    0x825ece5d27a890c7,
+                    0x93eb2b0ebbd4479f,
+                    0x83c234f67213e995,
+                    0x4428b80145ae4c93,
+                    0xe9d0ec2879beabe0,
+                    0x00a8b7f9d8ae9672,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0db83b8e35df713f,
+                    0x1a2a7eca9cee989f,
+                    0xedd3ea3a63301563,
+                    0xdb9926e8c4ecc397,
+                    0x97dd78b7ddf4b524,
+                    0xe0fed1ae1badba1a,
+                    0x90f2eb2eeb03fd4c,
+                    0xc527d3b7cc479b16,
+                    0x1147f8c8e5e47159,
+                    0xc64d860832894657,
+                    0x183384e1603b5aaf,
+                    0x003d6f4e2cba878d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x93fa644f66b88de5,
+                    0x0b8a630ed8e9f2aa,
+                    0x08061ce72ab2a9e3,
+                    0x6bd09106773bdefb,
+                    0x1c7b2b62ef94d65a,
+                    0x6dd40fa52b95b5ac,
+                    0xfbbaacab7696ea7e,
+                    0x9ff9b638817f4d6a,
+                    0xecfe03c018b2bdb4,
+                    0x7d9212a1052c179a,
+                    0x2b9c4ee920554757,
+                    0x0071648d874a61de,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdcf3a9286dccf709,
+                    0xd2fc722514f44682,
+                    0xb098c0a52e6045ad,
+                    0x3937cd96f82abac4,
+                    0x9660b3708677d8c6,
+                    0x2b3960ce992ae925,
+                    0x005818d6d7eb1670,
+                    0x8b94641396b353ce,
+                    0x11b165e7a5511ee7,
+                    0x9e355b54182dde29,
+                    0xd83e55c2bf6e60f4,
+                    0x00ef6e7e0224c63f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf722eeb37cb9efb8,
+                    0xf1523f73a579df49,
+                    0x5d12bbebdeb904b0,
+                    0xe200e56352782b91,
+                    0x557d8519c3aabfc8,
+                    0x765bc790018ea34d,
+                    0xc94bda865f79a9e1,
+                    0xea5c131a0b3c3f9f,
+                    0xf183f02d40b8a7e1,
+                    0xa2eea86aa28811dd,
+                    0x91bf797bb7b7abd5,
+                    0x0120cb937c0cf5b9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26eaf7a437aa1b38,
+                    0x3670b2d6a04e4c18,
+                    0xf5b4f434536a9064,
+                    0x6528ae30e8495d14,
+                    0xa4edd44307933171,
+                    0x7cce53746eb26cbb,
+                    0x3e4929eed1d87b0b,
+                    0x4c01234cf2d2db3d,
+                    0x15aa6534c706391c,
+                    0xf54cb2b4b9dbb4b5,
+                    0xfe0ed8205460460c,
+                    0x00afa690a9b4e84b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa2cea944d57726ab,
+                    0x46683b1d6f4c878a,
+                    0xbaa5bf86b4da5860,
+                    0x16f98c882bb78d9e,
+                    0x0ccabeb7163febd3,
+                    0xb8ad065ab0efead1,
+                    0xe38a52063d6b9550,
+                    0x9d3cad2e47539470,
+                    0xe06d6c9ea6b1d5a8,
+                    0x5845dc3e31b3620f,
+                    0x99b7123c23431e36,
+                    0x004459143b9122da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x625765688119b41d,
+                    0xc27200c39e85abfa,
+                    0xb96b8fa7ec324df1,
+                    0xb111306912279ed7,
+                    0x8bb314c9183dde2c,
+                    0xd498a4bb24934cfe,
+                    0xd94488d49c8a9745,
+                    0x30bf7004aa94b0a5,
+                    0xb6ec9d142cb0b40a,
+                    0x50e3a2bc6652c73b,
+                    0xc5e9923d19f6a874,
+                    0x00ffc52a876ccc8b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4ef774a0565b9a23,
+                    0x7b0aa45c60fbd578,
+                    0x055087a2f822fc66,
+                    0x353117d4ffc4dec9,
+                    0x9f8ea9953b0b4cc8,
+                    0x41671cd8839ed1f8,
+                    0x784e29f68568e132,
+                    0xa1152fc5852f9c3b,
+                    0xac74825fcdccb4b2,
+                    0x25bea5a4947e212c,
+                    0xc6466483149c52cd,
+                    0x0109284e21aeb427,
+                ])),
+                Felt::new(BigInteger768([
+                    0x323828efc9021d0a,
+                    0xf1a16aee6df4d71d,
+                    0xb8cb491f4fcf4b00,
+                    0x902976487e4483ae,
+                    0x0c07d40c67f82fb7,
+                    0x3780d480569006bb,
+                    0x03386d60b2c990c2,
+                    0x85f511cdc07c7859,
+                    0x8250a0d5044b0f8d,
+                    0xbd1fc2fa78b0d77f,
+                    0xe8dbc26486beb487,
+                    0x004861fc10f6ca9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3164ad196e270aca,
+                    0xde33b37ac3cfbad9,
+                    0xbe796c1c9217ffe4,
+                    0x36d657f3c650dba5,
+                    0x46bfe19c42647aa6,
+                    0x831b4e307edbe0a8,
+                    0xd235526ea406631d,
+                    0x71137b590665f2e9,
+                    0x386bfc3812cfc996,
+                    0x498bd8ccd534a343,
+                    0xadc22f53a5e09a6a,
+                    0x00021cdabc6540b4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x49ab9618087d39c5,
+                    0xdea39b0a1f0d89c3,
+                    0x7cd29e33726c7340,
+                    0xfd3e80049f5e74a7,
+                    0xa61705c94d521f3b,
+                    0x3b0f3716456cc772,
+                    0x16cd2be5bf41f99d,
+                    0x76c14fa0618fd324,
+                    0x7d57c749c16ffe1d,
+                    0xd639459c7b7ded9a,
+                    0xf528d88db102fe0a,
+                    0x011f39ff6cbc2e97,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8fe7e70361c583a6,
+                    0x01a77ef0ad77eed8,
+                    0x536f211e9794fa4f,
+                    0xb16189e9cd5602ca,
+                    0xc2cac1b4b18faee7,
+                    0xe8b28221b3438a3c,
+                    0x3aa0204791ec1c18,
+                    0xe648d4adf74b0bdc,
+                    0x85bf7ae45087f314,
+                    0xa09a1ee4d118b25d,
+                    0xa87aa1f14128598f,
+                    0x009d4774157ee9d5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb097511fb560e28f,
+                    0xd988eddc6e8b3753,
+                    0x0aee32f9ecebf6ea,
+                    0x577fb3c0a8fbfae1,
+                    0x8d229ec2b7d7aefb,
+                    0x80cd67b7cbdc6cf5,
+                    0x90c8f008c1d06449,
+                    0xb90ac6610801c6b7,
+                    0xb2d2dc7ec7b9e5c9,
+                    0x47d1a7b9fc81c7e6,
+                    0xb79a26b97f294db1,
+                    0x00ecdf29a64fd4c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb44091ec897c56e5,
+                    0xefc09728dfb32b09,
+                    0x47efdbc4aaa7e2ec,
+                    0x176607d275f283ee,
+                    0x74b646fa85be0038,
+                    0x2d62d50ef0827824,
+                    0xc88ae73f9664d403,
+                    0xf0a1883290f68594,
+                    0xa86668c0802f417a,
+                    0xa9891b3c7bdaaa4d,
+                    0xc04487b28d8637a0,
+                    0x00ab6ca58de19917,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc30da612b86cd7c3,
+                    0xed64f5429d272032,
+                    0x4c5ffd4da003c458,
+                    0xcfee1d5bc593841f,
+                    0x9b985dbefe02b1a3,
+                    0xdb0b08e3e94cd6ab,
+                    0x74e4616c3737226a,
+                    0x057784455651b45a,
+                    0x5ed63d1eff1c2418,
+                    0x5a076f5d1af45b2a,
+                    0xd1ad0d77a90058ad,
+                    0x00d7dd5c34790b49,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ab9938385132fbd,
+                    0xac1a64240e488042,
+                    0x17a7b8fdfb40c227,
+                    0xb4e01f86aa9cd463,
+                    0x726bcda22e0ddbde,
+                    0x72879faef7e2f0b4,
+                    0xa695de06bbc898aa,
+                    0x44303bc44052cedf,
+                    0x08b21b19ee13229d,
+                    0x8dc6f8fdb56745a9,
+                    0x73d32263f7a48a99,
+                    0x00a960866993fc69,
+                ])),
+                Felt::new(BigInteger768([
+                    0x42a2618b3d6c5ee2,
+                    0x5a72e0941541e4d8,
+                    0x2aedaa391ec6158b,
+                    0xb3c0f56441c442a0,
+                    0x292badba7c71161d,
+                    0x45c71ae571491752,
+                    0x555d6759996e60fe,
+                    0x10a0a7ded9e82eb9,
+                    0xf40460db9e6b66ce,
+                    0x455635f4ffdbb6c1,
+                    0x57aa3ac519ba9377,
+                    0x010ebc2436662576,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaba79d701f29b228,
+                    0x19e863236e4feecc,
+                    0x3daa871c25570847,
+                    0xd2e0efa3707ab7b8,
+                    0x721bc553552820da,
+                    0xa8fe21e7508edd5d,
+                    0xe182af49b4d5ee3d,
+                    0x1bb6bc0e54b7a017,
+                    0x0fd2376b083183d2,
+                    0xeb4eac140a2b0025,
+                    0x988733c03b1b45e9,
+                    0x00bddfe6b79e2b7c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01f6b87a71796da0,
+                    0x0d181b0042c2fe84,
+                    0xc6eeef7915ba417c,
+                    0x907fae1366ea0ab2,
+                    0x7dc7c9effeb4d784,
+                    0x7c9a9575b57d4a9c,
+                    0x9c5020ed5bc94597,
+                    0xd61c643135170d3c,
+                    0xd6681c36316cd74e,
+                    0xc66c6f6c6425cc05,
+                    0x1bea498132b55534,
+                    0x01038c96c684a7df,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x484aa0ff7505f3dd,
+                    0x3d8bab06431afa94,
+                    0x8f46bd7ac1231911,
+                    0xd5f8a5edaa408fdc,
+                    0x74bf07d7a040835c,
+                    0xf5151523ddad6a58,
+                    0x182bfeaf982174df,
+                    0x9176ca5611012fe3,
+                    0x8fc8b7cd255cbcee,
+                    0xfd94165e57f0b347,
+                    0xe8b2d2d88011ca28,
+                    0x000e4b4375562018,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6a62340f0289f4b2,
+                    0xdc62f34be282b4ed,
+                    0x7595e2184175d4db,
+                    0xc7a47c3873f720eb,
+                    0x63dd83f527d5b23d,
+                    0x80277503cba27a7c,
+                    0x4d6bffe320b7ebde,
+                    0x1d2d6b758216209a,
+                    0x3b06c2285149e04a,
+                    0x6965366f3484ab20,
+                    0xe18e3498a02bf4ca,
+                    0x0038c204c8a13732,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7758ce3e1e4bf3e4,
+                    0xebb405027bd88703,
+                    0xe317d8561c2c667c,
+                    0xfc0026d0871ef9bf,
+                    0xcd1f06f73e0304cb,
+                    0xf7a6c271ed6073db,
+                    0x290534ac05919eac,
+                    0x32c5f3d9edef4476,
+                    0x2829f58e4028ebeb,
+                    0x64ba589426e076f9,
+                    0x8106a9d63b0ba41f,
+                    0x0027edb734c400fd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbeb2ae1184c81dad,
+                    0x35fdb5d9b0673821,
+                    0x0a6fda6c3b5fff80,
+                    0xdab4ea6265c66bc7,
+                    0xf574bcb5a45a01ae,
+                    0xe6e8472d80b725ea,
+                    0x020b33e43ce78b90,
+                    0xd60f8edb5c0b271f,
+                    0x1563fc73acd24cc3,
+                    0xc98b0702028a1d4b,
+                    0xc7c3faf7a4cc469c,
+                    0x00c0eacfc3b3d83a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe62a98dbc09a7022,
+                    0x9cd55ae59a9285b3,
+                    0xb515fdec56cdbe12,
+                    0x62891e8a6fc378e7,
+                    0xdc0fc3cb99f92542,
+                    0x62c946ea54fd6510,
+                    0x68e54685f22ff6f3,
+                    0x2942dd041dfc1b79,
+                    0x35703f8ba2d25678,
+                    0x398fd3c629b17da2,
+                    0xf41d29af59de6496,
+                    0x00bdcdaf05ce2c32,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbc5997d88136072a,
+                    0x9cfab130ae8e9c88,
+                    0x3ec1e64f740d264c,
+                    0xb554e9c1a88b685c,
+                    0x86e004ba7dc3c572,
+                    0x92ba0b4b7beef067,
+                    0x7387c78ad07245cd,
+                    0xc3d92fadb9703edf,
+                    0xb9b9572dc9bea298,
+                    0xcb66cbef1dbcfe53,
+                    0x12102143f373ff33,
+                    0x0079a599e9b4fd3d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd19ed825455b7b86,
+                    0x9bde4f1dba634f4a,
+                    0x140796fe4961d370,
+                    0x0173b3802611a8cf,
+                    0x8671a947715ec41e,
+                    0x87a51fd719fa24d8,
+                    0x866b458ada2ee4d4,
+                    0xf1d6005b31fdeba1,
+                    0x022246fe5897e4cc,
+                    0xcfc53da00590050a,
+                    0x8a7855d386b75d9f,
+                    0x000a139c66bc66cb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb19d0bd0d42b4bec,
+                    0x87e12328a52bbab1,
+                    0xc0b39ea4becba3b9,
+                    0xa07cc5ea57059303,
+                    0xfc2a25514f118864,
+                    0x8999f515e73c75e5,
+                    0xf2212da045c10c40,
+                    0xbd793caf9d6837b7,
+                    0x8eccc233921b4468,
+                    0x0406fe4cbfda93bf,
+                    0x4f87ccac2dede4b4,
+                    0x0019536672f71c99,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe6754f1dd7ce4472,
+                    0x3fbeeda2f35e628f,
+                    0x56964993279463d0,
+                    0x839b2ddeab053ced,
+                    0x3c1136458cdf4ba9,
+                    0xa145671ffc4fde58,
+                    0x7ddbb0005f47d7e3,
+                    0x4e3fc74fde86fe27,
+                    0x4a5a68badd43bb72,
+                    0x6a6ee99e59a94523,
+                    0x5f5e98d25f7de0fa,
+                    0x007d07f96d2e7e4e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x24dabe3f0b597f5a,
+                    0x3735eca3c0488f01,
+                    0xf5c839f0af7709ab,
+                    0x4266db333c3a3270,
+                    0xa1473e83ce06c813,
+                    0x0b39fa668f6425eb,
+                    0x840dc06b74b998bb,
+                    0xcb748e061fcef2ef,
+                    0x7e6c9f5b60e963f2,
+                    0x221f046eb511dd9f,
+                    0xf69c105e801d9a7b,
+                    0x00faab0731b79ba0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x416da7ce7b604f62,
+                    0x0aadcdb0bf25f921,
+                    0xd900970b3c15a62c,
+                    0x431ae9be2cc2cdaa,
+                    0xaf487b08c0e60924,
+                    0x956c3a5d379181fe,
+                    0x8517bc86c2836d49,
+                    0x7324b679e415f277,
+                    0x1c0de7e1d75ddf61,
+                    0xf1bc034ede4c6437,
+                    0x1b0ac83b6cad1659,
+                    0x00cef6cb4217aa50,
+                ])),
+                Felt::new(BigInteger768([
+                    0x93818b53c6e4c408,
+                    0x6aa2238790e42142,
+                    0x644247a5eaa79754,
+                    0xcba6cfada97f4cbf,
+                    0x9c09e201ad18f42f,
+                    0x51bdcf95df3ae045,
+                    0x2fa05cf7f37c105c,
+                    0x56cb2976114156c4,
+                    0x004d85d975bae5d9,
+                    0x57923db86525df27,
+                    0x65754a0917a8d46f,
+                    0x00299d5dac7dd898,
+                ])),
+                Felt::new(BigInteger768([
+                    0x21005ae90508d2c2,
+                    0xf4517600f9615867,
+                    0x4e47d9550ce59321,
+                    0x2fdac45c36d10cc0,
+                    0xde43c9e016aa0553,
+                    0xef9846a43420a104,
+                    0x1dd34446210abfc9,
+                    0x699752f2a293af3a,
+                    0x862d03289dca1fce,
+                    0xf3b4cc29580f8e19,
+                    0x40058b1f3980fa6f,
+                    0x01226d63b4bbe8c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe77e552bcf211b37,
+                    0xd7bc2ae149d51bb0,
+                    0x596e0a1230b3c692,
+                    0x5ae95d5503446457,
+                    0x9c2eb22011c18c0f,
+                    0x9036cc1ca60ee884,
+                    0xfd69f95f8d9a2938,
+                    0xddde5dc73efd2957,
+                    0xad5ff798d68d3960,
+                    0xa428dfdae1457462,
+                    0x3b435e51af935f67,
+                    0x00aa312290e374ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19e8d2bb77125dd9,
+                    0x2901bf388098a105,
+                    0x787f11b89667b710,
+                    0x77d37d03d2656c52,
+                    0x20a2e9b42ba407c1,
+                    0x988a294c2f7b52a1,
+                    0x4261eb567fa93896,
+                    0xaeb37e13e4996bcc,
+                    0x66ee17ef3cd84005,
+                    0x09e4dfed2eeb672d,
+                    0xd7967ab331758ac6,
+                    0x0064360af2794aee,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd67c6821c42ad833,
+                    0x5c946f1459907d80,
+                    0xb088a33e6311f117,
+                    0x08ef896a02fd78cd,
+                    0xaa959468ffb225ff,
+                    0x1a1979db5e856d93,
+                    0x915e9ac657542cb0,
+                    0xaceca16b1c2f3ea0,
+                    0x053b194b07e64a50,
+                    0x430f9e6c088e016f,
+                    0x76c726d2f5ce3a22,
+                    0x002f38b8f6afe242,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3a6829ea1f27ea93,
+                    0x8a114028ed47946d,
+                    0x8ba13e3c20c459aa,
+                    0x76da53d74fbe74d7,
+                    0x002455d2f9ac0da2,
+                    0x1956a0c274954f54,
+                    0xf28511d6c5a9407b,
+                    0x58267fb39dd7f42a,
+                    0x93d5e3bfbe263d7c,
+                    0x748aacca445dfd4e,
+                    0xfc4f94f26372efb7,
+                    0x003c9331f0332d67,
+                ])),
+                Felt::new(BigInteger768([
+                    0x49992db5ac53ccd3,
+                    0xffe44bc06860c174,
+                    0x4e46f059e41af6bd,
+                    0x86920a6bff54d3b0,
+                    0x7db295be015060d7,
+                    0x51feb573ef62913e,
+                    0x392bb2b4d1b8afe2,
+                    0x8aaea5184c7c1f79,
+                    0xcc5042e6138b4cb6,
+                    0x16c1828510c40d34,
+                    0x06563b65717769da,
+                    0x001ee6e58d8c4fe2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf1922aa6c23a31a8,
+                    0x074e894846f44945,
+                    0x1389520f1ff6a82a,
+                    0xfae17b5377286b7d,
+                    0x3ee48cc692b60051,
+                    0xbe5d95d24a3bf43c,
+                    0x2e2f7095df702b1a,
+                    0x24ffae0a42600356,
+                    0x99f4ce52a614c153,
+                    0x633ded2ba39a9874,
+                    0x641a40fc643e1386,
+                    0x007d8df93cd55106,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c73c1f80beb8f21,
+                    0x0f1b8794fb318e6f,
+                    0x9d13acecc3786df0,
+                    0x98d43fa98dddb412,
+                    0xc88f13c72c4408c8,
+                    0xd2d995bd8faf59ca,
+                    0x114e2eea9474ed7d,
+                    0xbac46c76b35583c3,
+                    0xf2d45372a1e3b7d6,
+                    0x8d165282514ec087,
+                    0x6008c54ed5b2c2a3,
+                    0x008e74760be3db12,
+                ])),
+                Felt::new(BigInteger768([
+                    0x67cdce93568f5d5e,
+                    0xbd599aabc66bebd6,
+                    0xc4994f9d840bbd02,
+                    0x1c73e46c92a28e94,
+                    0xcc89833ddb1c3c61,
+                    0xe687b764eadd714f,
+                    0xbb402a89daa449cc,
+                    0x084929cfe51e05e4,
+                    0x2e3cfa580852f037,
+                    0x1560782565006603,
+                    0x85fa9f976e8b8c73,
+                    0x001d681a2ee4c3b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1286a38217c80020,
+                    0xf2c62399e6f5c610,
+                    0x93e6841590257e02,
+                    0x1d5293879cf5f88a,
+                    0x38adb1fc3fd2339a,
+                    0x2c97f43e0abddd2f,
+                    0x189d117dc2809a57,
+                    0xcc8d3e81df8b660d,
+                    0x55ed84a0df04804c,
+                    0x52780a48b1a2119f,
+                    0xac812fdc7cf2bd4d,
+                    0x00232e8741c4eff8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdfe0c4a5d8086200,
+                    0xd3c5b20a4414d903,
+                    0x7bed4a0048bb3241,
+                    0x5c71f61886b4134c,
+                    0xc15eb0a72bf5db02,
+                    0xcfea0cbbf193d468,
+                    0xe3c741b733459f6b,
+                    0xf106863d34762802,
+                    0xfc3aab4b4b2f0284,
+                    0xe6c594aba9f74be1,
+                    0xfe42e4863cf3b317,
+                    0x00dedf05032e6f5f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba8efb6b6f5661d9,
+                    0xe86c3dcc8fc01ed0,
+                    0xcc0333b218ebb9cb,
+                    0x34fa81d80f1d5b6a,
+                    0xbebd4b4d0e40330c,
+                    0xcfbf3624897e0a6b,
+                    0x191f46d65ce9b294,
+                    0x44d176be88b066cb,
+                    0x0db71bbd80b23c05,
+                    0xf0989f27049f9749,
+                    0xea84220be163148d,
+                    0x00c7a207429044fe,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5c1170d48b7e9411,
+                    0x80b36ca1a564971a,
+                    0x49f66ea6a712dacd,
+                    0x727258830b31eb9c,
+                    0x2845ff02dd59fe26,
+                    0x2c6ad313175f90d9,
+                    0x59820fa267b6e75f,
+                    0xfc4cc5a7afb72898,
+                    0xd1eadbf15fdc5a18,
+                    0xdd7b958b9fab8051,
+                    0x41ea911bcd687087,
+                    0x004db7cc323e3793,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89cea8bac4337487,
+                    0x973c95e2eeb820ba,
+                    0xe6d3968abaf0ebe3,
+                    0x231e3012e2769116,
+                    0xf28164d6b569dbe0,
+                    0x5b356cd4a5243f05,
+                    0xd1a7a6609d6bac63,
+                    0xc2cde5334e70b1aa,
+                    0x188c5b168ec9c059,
+                    0xea6985be95651f74,
+                    0x68cad90687702dee,
+                    0x0081fc3a14e6d6a6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a858db725337754,
+                    0xea68de24efe5bd6b,
+                    0xa2c8fc56c23ff248,
+                    0x25cb4cce9b9e4eab,
+                    0x76efdf154249656f,
+                    0x96c29aecb953f0a0,
+                    0xb12e465c71766e96,
+                    0x67c6904009c877f8,
+                    0xf08b8a617ca6ceda,
+                    0x2a2ad32e735b2799,
+                    0x1d14cf7c689ec3d8,
+                    0x00a01646355921fa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x49a57e21a4b14fc9,
+                    0xdc52c62ec4a671c8,
+                    0x17a1759081786c79,
+                    0xcbad867974c28b67,
+                    0xc7056516f9f1820d,
+                    0x0442af1887453e12,
+                    0x60e63d994ef3d487,
+                    0xffa886ccd447c7dc,
+                    0x29a8c7173694418b,
+                    0x946d4341d81b6527,
+                    0x9d44241eec666b81,
+                    0x00ec9913fe26242c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb534198538581e66,
+                    0xdcea7a21506588bf,
+                    0xfcac2713749c3ea1,
+                    0x211bcdb2694ad466,
+                    0xa2e7001afd5fc443,
+                    0x7da63e9ac423c8a3,
+                    0xd54e23ecec548a06,
+                    0xfa78cbcf8ea3dc8a,
+                    0x23532d963d377489,
+                    0x2f6f72e867ba2b13,
+                    0x180f7326312ea14c,
+                    0x003c3585ba6a9849,
+                ])),
+                Felt::new(BigInteger768([
+                    0x08856b14a7a22801,
+                    0x3bcb8e2b152e57bc,
+                    0xd9e1881a8c0e49f2,
+                    0x12f0a8eb0f8362ca,
+                    0x803462ba2015dac1,
+                    0x042ba1bf3e121dc3,
+                    0x8298850adb66d20e,
+                    0x986339dd9f78d3b1,
+                    0xaf760d815d295886,
+                    0x00152b6557dd3639,
+                    0x113d2b30b84438bc,
+                    0x00765b253015d689,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf64c5b145e72e603,
+                    0x279784ccbebdb183,
+                    0x83064aa59f4763f2,
+                    0xe98a254ac9e32971,
+                    0xb865bce6a61a1014,
+                    0xa5008930767cda70,
+                    0x87bf67db0368ff8f,
+                    0x45c7a9943d85e2b1,
+                    0xfe9f86ddd452733c,
+                    0x55dac7cae342272c,
+                    0xf07f53bcb35c06d6,
+                    0x00e273c882a49451,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c229b8aff417a01,
+                    0x00269a31277d728d,
+                    0x6428064a1cbe152a,
+                    0xda0dd5dad4f8da0b,
+                    0x4604ac04ca9be01d,
+                    0xff4b59b9e21d15cd,
+                    0x4acbc57d4565a4f6,
+                    0xd94ab0135ef1b4d9,
+                    0xc1c8f0345c6beabc,
+                    0xc672fc77058d50ea,
+                    0xbfd94a21d0657dd5,
+                    0x00397b67b8af0ca8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa182ef6882b69d77,
+                    0x7c48daad643f33ed,
+                    0x3a034ca00228bf68,
+                    0x78408b37b436c3cf,
+                    0xad74b7f912f4289b,
+                    0x62fee0f42886269d,
+                    0x741422da0ea48208,
+                    0xdf8f912a5e96068d,
+                    0x400877c82b50df8c,
+                    0x5d777f6d90ff4bdd,
+                    0xd9aef12c1496047b,
+                    0x0067ca542b5951ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2eaba9cd6875e510,
+                    0x282b3920bc76f6bc,
+                    0xce47b03bcf099b06,
+                    0xb4e1ee7334df4315,
+                    0x0bf18ebc3101e1a1,
+                    0x27eed25c713fcea6,
+                    0x136cd3100041cb2d,
+                    0xbc900f08bfbccef6,
+                    0xc8b5cd3fca3000ab,
+                    0x0d6a5c9a6e49d8cf,
+                    0xd0af35a46e9a50f1,
+                    0x00207b32d629d07a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x42feac89e6fbd47f,
+                    0x7895e8f27ae6bd65,
+                    0xb3b4f97f05e72293,
+                    0x834b838054b9c62c,
+                    0x1656e20c8f716181,
+                    0x5404b0c75eda7829,
+                    0x6ef23f50628f30cc,
+                    0xe9ba118e6fa4a606,
+                    0x5d80cbab8a6442b2,
+                    0x13d40d7b990e3a1e,
+                    0x37bc2b758d484f33,
+                    0x00fe1a09affc9733,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6896f6d7fa56c5a3,
+                    0x26d250a55fa1298d,
+                    0xd243e1c98a409399,
+                    0xcbfc1ffe7acc9ce1,
+                    0x701041422d3d9e88,
+                    0x95dab7e43395ac3b,
+                    0x29fbd56f2641f189,
+                    0x50176404ffbb6bcd,
+                    0x9464e4ff47eaf1ca,
+                    0x21d262396a541b0a,
+                    0x6f4429043383c13a,
+                    0x00d37b5ed66124c6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb6714de070be750,
+                    0xf8dddf4255c96bad,
+                    0xe61932c22c9116ad,
+                    0xff41a809bbb8b0c3,
+                    0xa791b2a37ecb4235,
+                    0x1ff1cd815c0355dc,
+                    0x05a1e1976c72f4e0,
+                    0x060f449bef988018,
+                    0xe295f38889896002,
+                    0x26a1136d1347a74c,
+                    0x76c382b9a6ed0033,
+                    0x0045c177f87e1fb1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89a7e56f83207f7a,
+                    0x1c8ee64f331cf5c9,
+                    0xd3f2097d41eff462,
+                    0xca124e2e5d568112,
+                    0x41c1c73d7002d238,
+                    0x5cda33a8416dd50e,
+                    0x43a3e69c6811bc39,
+                    0x77d5c86d1b407684,
+                    0x468dff0b092ffafb,
+                    0x0dc6232abdd16a5a,
+                    0xa6efbac0ae4a5134,
+                    0x0014fab2b82a91a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa0c76993da562f17,
+                    0xe08f89b4032b55d2,
+                    0xed05fc0f9f3f8ce0,
+                    0xf4496abf2a204506,
+                    0x2ed0bfd05d56b574,
+                    0x9d6ea198b38d78b2,
+                    0x0489d32b9d6ae7d8,
+                    0x5da87fea52fbb233,
+                    0x414e897d27a6b541,
+                    0xc649e001ff6ad9ad,
+                    0xb300ee158eff8d72,
+                    0x008369a2818b74c4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3dbe57679ebd18e,
+                    0x0a5f869e3987eaa7,
+                    0x18a5b47716dccfb8,
+                    0xe2923d6a1c542eda,
+                    0x9e0ceab87fccf0d7,
+                    0x8071a252e5d7d409,
+                    0x0a8dc4dbb81a493a,
+                    0x5fad39ec61655f18,
+                    0xbba18278f72f9035,
+                    0x3dd95849f56a0da2,
+                    0x5f7b81482119764f,
+                    0x003496dfb8861706,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc65991747c591c39,
+                    0x413044d06a1c8772,
+                    0x31b45b77419073e0,
+                    0x928a6e3a887d3b4b,
+                    0xeb6182d6fb93cc08,
+                    0x58506ab927f50672,
+                    0x65e8a82c5556e02d,
+                    0xccad17ecdc662a21,
+                    0xd07fd9a934942243,
+                    0xe2189d114bf47e60,
+                    0xa915c1b1f00a786c,
+                    0x01083d818a0f7389,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe07ca84765fd7f31,
+                    0x3727ff32ff79fc0e,
+                    0x87580821d8235bf3,
+                    0x62efda05fbf56cdd,
+                    0xac329bff9695516d,
+                    0xea0170b6ce819c14,
+                    0xc391e3782e2a9955,
+                    0x373c3b0a977d3a46,
+                    0x4d97dde88f146903,
+                    0x9cf168d0f300517a,
+                    0x073c9af27d876790,
+                    0x000c916acdb75772,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfa845ac5670f2d58,
+                    0xcd00f0b1e730c7e0,
+                    0x222572326dd904b1,
+                    0x23a3da02fa0173c1,
+                    0xc4eaf894e1208709,
+                    0x822efc42bdad657d,
+                    0x2b362bb8f460434c,
+                    0x08b28acb014265b7,
+                    0x3412230da052c703,
+                    0xe6e26cb89e85bb91,
+                    0x11228c5d57c7fc87,
+                    0x00901a30c4d5bf8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2d03dc8355615e95,
+                    0x92d627f123fc356d,
+                    0xada3c05a3bb7c907,
+                    0xd1d2b8327395c80f,
+                    0x0c2c54b2c62e2b31,
+                    0xa69d28943f720f51,
+                    0x292ce70108085b91,
+                    0xe7a032999087e3f1,
+                    0x2c9f20a2c48d6f1a,
+                    0xa8f9b13483d9a9ab,
+                    0xb42ff46f98623676,
+                    0x00852f06dee86542,
+                ])),
+                Felt::new(BigInteger768([
+                    0xccf45e035c49b175,
+                    0xf10d63912458fa9c,
+                    0x741bcc7dda6ef809,
+                    0x1f2f7d0fc011b911,
+                    0x86c248939dbd5f59,
+                    0x56ac6c28007ba444,
+                    0x9d733b9cfcb9edea,
+                    0x67ef4de15f41390a,
+                    0x65b63d47e3b9d310,
+                    0x35d7a1d08a50317d,
+                    0x871e293d7834b68c,
+                    0x00d2b98540d037f6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcd2afd35c4459a62,
+                    0xecc020f680cd873f,
+                    0xdae638199b601721,
+                    0x731ee7491bbf2974,
+                    0xb9432aecb22b0e38,
+                    0x6fec0e6944633331,
+                    0xdd63efa37759dac8,
+                    0xa107f2a7d0e196ac,
+                    0x36185ec082cacaf0,
+                    0xe81199548f0d479a,
+                    0x4a8af8fa4aa22bb2,
+                    0x00fdf64a18d12356,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb00fe1a46dbacac2,
+                    0x67d7126fdf66fe8a,
+                    0x5ca98297d8a34bab,
+                    0x0e533a503ac90fe5,
+                    0x070690c9cc625a7c,
+                    0x12915cd9affb4575,
+                    0x802a50c3f425b4c9,
+                    0xb62ee9d767670e50,
+                    0x480f13bd08dfef1b,
+                    0x7256ea0dd3f286c8,
+                    0x66a14fd1bd7c1f7d,
+                    0x0106996d42378159,
+                ])),
+                Felt::new(BigInteger768([
+                    0x62330b135468366a,
+                    0xe97c3820923bd059,
+                    0xf890101502e3684c,
+                    0xdb82f943cc001e62,
+                    0x0897c05226d1a855,
+                    0x8bf81b8d31fa2190,
+                    0x7cbd8f3ea184aa7b,
+                    0x3a46e21994b48347,
+                    0xd6cb5c442f366fe8,
+                    0x2717960015a7cafb,
+                    0x280301ad4758db19,
+                    0x0121af658252d2b1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd194eb856907e625,
+                    0xb1145ee0506e4e93,
+                    0x09c6336e49beeca8,
+                    0xfb8e56d4ec2e92a6,
+                    0x74a2bf7853ce7a16,
+                    0x4fbc6788363966f4,
+                    0xb264d23c55ef5606,
+                    0xf929ea1f783a3408,
+                    0xcadfdf1260852acb,
+                    0xca50b60b22888fce,
+                    0x1f20fe7e0ab783df,
+                    0x008679fd51a7e41c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2965d6f229dd11f7,
+                    0x6a71e9435703ec73,
+                    0xbcbee3af30e024da,
+                    0xdf6a26bfff436be8,
+                    0x3a24b25c5ea88670,
+                    0x657177943f902577,
+                    0x5dbc18d8bbb2737f,
+                    0x6d07ae8a7031ffe4,
+                    0x2c1ada65fac0b01a,
+                    0x47a94efeb571dc26,
+                    0x7004cdfb7d4768b1,
+                    0x00c06f29e9a240e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87ea71a82dce3db5,
+                    0x9d5fc15d5cadf6a1,
+                    0x9adc53a64741132a,
+                    0x33a7740cfcf40582,
+                    0xdfd177b603e20c8a,
+                    0x4b52f3363bd34b60,
+                    0x00a7c90bf3b6061e,
+                    0x4f95f2a992021524,
+                    0x147a5ed7068edbc0,
+                    0x7fce49cb6f520398,
+                    0x848febe73cf2e3db,
+                    0x00f5cecf57fcb664,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12312af107aea4fc,
+                    0xe97af13e733ea8c4,
+                    0x9db3b52627396f03,
+                    0x18a2a33396a47b9a,
+                    0x0f9c6c322e6bc3d2,
+                    0xed59e3f2949f098d,
+                    0x075de5002bf94a17,
+                    0x4efacaddea16014b,
+                    0x91b5fed868ca7163,
+                    0x748504bf4fd58da6,
+                    0x3865305e3f08442d,
+                    0x00f488efc35369d7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa2ccbd2cebff26c7,
+                    0xc0c91e897bccceb6,
+                    0x8ae9067b3ceadfc8,
+                    0x08177bcf41a84555,
+                    0xd21179138491c610,
+                    0xd61ef909f694c1eb,
+                    0x748c82381e02bd6a,
+                    0xcaf6296f7c2d324e,
+                    0x11d1011851a86cd5,
+                    0x8d329e9f773672da,
+                    0x465e475ad6d41612,
+                    0x00eaa62316b12b8b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeca54706007bcddc,
+                    0x7bb1123ddc68225b,
+                    0x23517e9919c83dfd,
+                    0xa104033ff564295f,
+                    0x8507ea2648943dd8,
+                    0x20470311cdf67e10,
+                    0x49a725362a5f3546,
+                    0x51225983129036c0,
+                    0x85b39115f141c129,
+                    0x3894fd882c3200ba,
+                    0x2f151f2ac5f0802a,
+                    0x00e670aa3864ed02,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0007f27b476491ce,
+                    0x7e5546138263746b,
+                    0x4fa69e6641d6ea9e,
+                    0xe28414f81e4b3626,
+                    0x8316161abd2431a9,
+                    0xa8e3d001fee7830a,
+                    0xce2d61b9a0403eb0,
+                    0xdf2c5c464a8f5130,
+                    0xa8387d58c31d0533,
+                    0xa358bc07f09a1a46,
+                    0x33c02725b99f04bd,
+                    0x010632bf368ab7e3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa3b9715797b132c9,
+                    0xcf2e8d33563369c7,
+                    0x816588a3345dc7ea,
+                    0xd0158b85b65ac86c,
+                    0xca97cdf169ceabc1,
+                    0x9d911f51702cbefc,
+                    0x332e4ec24e95c793,
+                    0x62c59b26e0b0cdd7,
+                    0x16425ae90dd73275,
+                    0x44abed91972b9d29,
+                    0x3409405bca72ad6f,
+                    0x010852caeb21bab2,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb1da4aa99ef46e07,
+                    0x2a3471b72e37fcad,
+                    0x358cad5a9a18684f,
+                    0x6e076b521f865480,
+                    0x3639154e4a0252de,
+                    0x63eaf4dc0d3d9be7,
+                    0x814dbe250127a4df,
+                    0xc54902748c76ed40,
+                    0xa2b600dac435f3de,
+                    0x6070a8528ab52f27,
+                    0x377e5724cb5185e2,
+                    0x0039d23df670274d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a11939e9c9f8d01,
+                    0x748ccfe6ac460945,
+                    0x31fc09ab06bf0df7,
+                    0x728476d86a4afefb,
+                    0xc507029a696dfb47,
+                    0xa5084d9fddd235ba,
+                    0xb00e3d9ce5be7381,
+                    0xfeea47cfaa005e28,
+                    0xd2692ad22e3f5d15,
+                    0x07fda1a9f327a91d,
+                    0xd93301e4c969705f,
+                    0x00094226b034763e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ea7cb45ac1726d4,
+                    0xcccd77eaba1fc596,
+                    0xa399232c75923149,
+                    0x3b78bdbee5edb1b3,
+                    0x6031a70465246aee,
+                    0x2eaf932e78d4cbcb,
+                    0x103dc5bb4bb65bdb,
+                    0xfd64fa0a4ca24263,
+                    0x20fd30d80b4b9ee3,
+                    0x8c616d53d338f500,
+                    0xee391f0d948b7296,
+                    0x00ce294c21870167,
+                ])),
+                Felt::new(BigInteger768([
+                    0x15165b3678b0577f,
+                    0x01d55e8a152544f5,
+                    0xadd478a5d1987dd5,
+                    0x7d5e60cbe700efba,
+                    0xa59f0454c0d4716e,
+                    0x0b46aed4106f42c8,
+                    0x497f7353ef26ba3a,
+                    0xb4d91e07ef75026a,
+                    0x21b4ae560d9138f0,
+                    0xcefb2e2d03e6ab7f,
+                    0x51cc82839ce75749,
+                    0x0086ff10c69f807c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x913f9bf44f695c42,
+                    0x79db3512a70a08a1,
+                    0x3c65e289f2503263,
+                    0xc4315b9f31699d9b,
+                    0x5989b4120e2100b2,
+                    0x1ce3492ed4b77cdd,
+                    0xdb9cc35506ade294,
+                    0xe201c0f424b31bf3,
+                    0x3b84b15a8f89bb9e,
+                    0x9563bd2fdea41d71,
+                    0xc594a0a7139668a5,
+                    0x00e8e5b62ee122ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x116b052d9cbb30c2,
+                    0x88b225bcb6b0dc99,
+                    0xdc660af96ee98138,
+                    0xfb4ffc2b1a2139b7,
+                    0xf14c9f0a2a64b428,
+                    0x0aba41e2e420cd2e,
+                    0xe65e960ac564f45d,
+                    0x4868a20647753693,
+                    0x0f61d6499d108ef9,
+                    0x85f8eb987db11aa7,
+                    0x3fbf174057f97ad7,
+                    0x00db94b7462377ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38e8e828af3daca2,
+                    0xcf61490c4dd525a6,
+                    0xab4514c2bc6d23a6,
+                    0xeec2b96e73b9fafb,
+                    0xc6a66ad9637d8630,
+                    0x7f3a9b1ce9c53982,
+                    0x8c6c8e4c83389c10,
+                    0xcec3b36729f85632,
+                    0xecf61d18930fce86,
+                    0xa59051e19822d83e,
+                    0x9a3a87fb504b51f8,
+                    0x00a4c8270eccbed9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xceac7fcf61a65ffa,
+                    0x36044416e5d0d1da,
+                    0x6126b2a7d34b7898,
+                    0x6d1eef75d85b3418,
+                    0xfaf745c7880c263e,
+                    0xda2bf349e3469557,
+                    0x02b984b2fba4d314,
+                    0xa6c22f624b5daadc,
+                    0x5e547b69dd25ce41,
+                    0x753032103ed90876,
+                    0x8cad0b9ace0da6ad,
+                    0x00e7db5d00378818,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe599213cc1f699c2,
+                    0x155972ddeccd7c78,
+                    0xffb1c311b76203d5,
+                    0xdf2d9ac4b8c26fda,
+                    0x5cda30a567e91cd8,
+                    0xfead26a7f1afc3d1,
+                    0xa2deafc375a1110a,
+                    0xbe1312ef8c45b398,
+                    0x9c7d1675ed075c96,
+                    0xd326b763e81a5bd5,
+                    0x0f2fb9f3adb34ec4,
+                    0x00e381e659162212,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d0b4892b349e9db,
+                    0xad7ee243e7cc9fda,
+                    0xee3c0917a32cbfc6,
+                    0x896cbd31d9ee0f9b,
+                    0xf72cb3225e1248d1,
+                    0x6489da8c52af233e,
+                    0x09f88457ba5592fa,
+                    0x791e6cfe9a0ba0d0,
+                    0x1a0cd176fce4c9ef,
+                    0xd0536e1d325d3763,
+                    0x7165cbe28ef28564,
+                    0x002bb41bca4a414d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0edbe3ea59fca628,
+                    0x77bbf37ac00ad55c,
+                    0x5c017797537e8d26,
+                    0xce4c5dd5631e8362,
+                    0x74807bf893d26d9f,
+                    0xa76da56fd6c48b30,
+                    0x104b5d635929107b,
+                    0x0f2a49d33259c26c,
+                    0xc2e1b534a7e221c4,
+                    0x9351517086527c24,
+                    0xa3cc866bd13feb2b,
+                    0x00d74a6b0f0e94f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c839c896ca73d0f,
+                    0xf11087b72e33564d,
+                    0x07df44de91ce9e40,
+                    0x1760bda5046c54d3,
+                    0xdb9547c6e740665d,
+                    0x50994171cc7e636f,
+                    0x8d3a67fc3a0b32f8,
+                    0x851a8727c5c225ec,
+                    0x6909c1515bf9bff1,
+                    0x86b4c062dd2af8df,
+                    0xe4421deb5eca5609,
+                    0x00073442ab14b01b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x267bf1cc68df6261,
+                    0xcd896120f9e32acc,
+                    0x150ce4a663dc257e,
+                    0xfd5b3aa80b8b1cc0,
+                    0xd72bafee3d97bc6e,
+                    0x580120493af75284,
+                    0xa8bdf9c32759e925,
+                    0x57b7fbddfc6d9d8f,
+                    0xb90e23e0e19b8e70,
+                    0xfc8aa0e8e20fb32a,
+                    0xd02ca9c25ddb32da,
+                    0x01010aa175861a44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa081f7ed6cb4c872,
+                    0xee21b3733d65b3c0,
+                    0xde7ec5dfb4464e92,
+                    0x91af8809c8937dae,
+                    0x30efd764fc59fe06,
+                    0x4e215bc271ae9c8a,
+                    0xa05bc3f0196d68d8,
+                    0xd6049b653d4a88fa,
+                    0xeb51f7777424b376,
+                    0x7ed65ba6540be56d,
+                    0x14320ae32834ef48,
+                    0x0087fa145bd9f6f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0e8f4d7000a7efd5,
+                    0xf0e96eb13b74dd2c,
+                    0x84c77ab7b2ce762d,
+                    0x390c53a684f2543d,
+                    0xd390be1fa2047b2e,
+                    0xe0569a92c6e049a8,
+                    0xf49edfb034d467c8,
+                    0x0d1dc0ce8f3c1b3a,
+                    0xc8eb5001878c6a97,
+                    0x802620b4848d8e36,
+                    0x496e96a26493c314,
+                    0x00a5bd93d89663cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc5bff7cef6d1865f,
+                    0x423bd862b5488dcc,
+                    0x4fae4b0b00c96a4c,
+                    0x52de48418172087f,
+                    0x3f1d8151c9a2deab,
+                    0x1319d8587c409fc9,
+                    0xb5ca7d9dba69a3b3,
+                    0x02c9a8e145535583,
+                    0x22efee071a9120f7,
+                    0x17e600fe284e6b54,
+                    0x0b496edf6907c96f,
+                    0x0072aef9ae807aff,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb66cc49c11fe7d75,
+                    0x2a9004364fa6025b,
+                    0xf1244c6735398e16,
+                    0x9a31ddb580ca3f8a,
+                    0x1e4b2e1fbcee3218,
+                    0xf38335c52a7b16f0,
+                    0xfe1b4d3a83bb5fe4,
+                    0xed94c0d2151e7db0,
+                    0x2c1bf289097e8684,
+                    0xb6017f53c52e1543,
+                    0x19e915f99b622ee6,
+                    0x006ec51795f31e83,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf82e5e9186ee2485,
+                    0x35860e214d9fb8d6,
+                    0x2ca10b10ab7d7cec,
+                    0xe6ce11d8b6f37e7d,
+                    0x39c500420ecf05b8,
+                    0xc3034fdbc878ec1e,
+                    0xd21b73d2195b6b9e,
+                    0x1d9e9b243c8c1eac,
+                    0xf0165a193e9a73b7,
+                    0xd4182f4204c1bf0f,
+                    0xc07a5d29ef425089,
+                    0x007c6451fe24969d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x486d043492583916,
+                    0xbf0887eb8fcd1128,
+                    0xb0ec3a71693609f8,
+                    0xab9f295e0f024065,
+                    0x4698de2407829a61,
+                    0xda4699679a95ac0c,
+                    0xf3e3fa73ee43459d,
+                    0x8287dd857c21859c,
+                    0xbedd580a1c40809b,
+                    0x7bcf219cbe347c58,
+                    0x7435d4d686eb6320,
+                    0x0022fa115c674e22,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7693dc688b3ad6b3,
+                    0xaa70d1bd5d3b1376,
+                    0x8e777d438c64bca6,
+                    0x1447430ef3424bb7,
+                    0x7899491daa8993df,
+                    0x002c4f5ba553c0ed,
+                    0x2fa87035d81575b1,
+                    0x379553ac40e6f80e,
+                    0xa4dac4221ed1fe8d,
+                    0x2620b1f15dfc6d65,
+                    0x1874edfd44730221,
+                    0x00dbb21aba03b740,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c8b21636d151137,
+                    0x54a1deab028a01ce,
+                    0x65660cf4677cfe50,
+                    0x26547b49f45c5e92,
+                    0xb2f91ef7926d5c12,
+                    0x57732d75c5e939ff,
+                    0xcdba10a43eda2a67,
+                    0xf38787aa942f847f,
+                    0x76235180b6f8b348,
+                    0x1ad254c64a6bff08,
+                    0x35c5d0a572183bfd,
+                    0x00e58d4898bc5c7c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x35557a097d8c4924,
+                    0x6dce566295b80cbd,
+                    0xa27a2650ecdcc132,
+                    0xd0b5a1626fc07deb,
+                    0x23725b1edc13a889,
+                    0xbcfb82351254e275,
+                    0x3d99fd91879bd372,
+                    0xa7916e9edd16ab65,
+                    0x561a466ce18a455d,
+                    0x1853de040f34a679,
+                    0x26411a971ba7d1db,
+                    0x000d201edf4c5c9e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8713c0d93ef1b736,
+                    0xcb4c9e077b9ad0ce,
+                    0xa7bca6f4ba954cb0,
+                    0x837cf2b2b797e2ba,
+                    0x733ba6aa30c0e430,
+                    0x41ab2db844b11256,
+                    0xb0ee773af4d8b231,
+                    0x197c331131b0acc4,
+                    0xd3256e7173832df0,
+                    0x9203f3a760f56289,
+                    0x917ef22166840cac,
+                    0x00d54701a1cfe1ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdacd86e25c07f446,
+                    0x0937a45d82359d18,
+                    0x089a81ec1dfa594f,
+                    0x29c54e3fff7e58c0,
+                    0x3fa886d90d2eb3fc,
+                    0x3b7853a2bec7a8bc,
+                    0x49fcd917dfdb66a8,
+                    0xd6f1ed1ea4379744,
+                    0x818ed0fb908a48f0,
+                    0xdb25ac4cbd70c52e,
+                    0x0ffea38d31171783,
+                    0x0089e7f6232cced2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x218a82533b9ee62f,
+                    0x5f64e638cf27659f,
+                    0xaeded730a00267ee,
+                    0x2412fca4cf5ca556,
+                    0x386554a65fb3a4ce,
+                    0x17f40e54e2326177,
+                    0x8b0c68dea3e0344a,
+                    0xe2fe8aaba9859461,
+                    0x9a0c0013c4fc42ba,
+                    0x2725f73c29eb2e61,
+                    0x0f43d7b69cb52251,
+                    0x00927a601250701f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x395e86d90838bffd,
+                    0x1e4fd3caa80f71f5,
+                    0x36edb14284bdc16a,
+                    0xc3f0066c9c498f7c,
+                    0xf479d8ece735dc58,
+                    0x19d218295cc93e12,
+                    0x244c77eeb4c0e13e,
+                    0x25d98fb0f9c36380,
+                    0x31f9f7d639bc1d24,
+                    0x5ec283d3254a0cfe,
+                    0x7d3e9e75a80d9ca8,
+                    0x00c110278c5e465d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x721b2946d8fd57de,
+                    0xcafe2c2445fc55d4,
+                    0xa220f0c59432bb05,
+                    0x9bb19d893142c78e,
+                    0x5a2cc5afc79d8f4e,
+                    0xd0c35216d80f2de0,
+                    0x1404e46610e6f6d3,
+                    0x01a6cdf87a64afb6,
+                    0x3ce606a0985ad30c,
+                    0xe53cd8b03ef6c018,
+                    0x605d00b277c36f10,
+                    0x00621ef9e0b0ff13,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa568b0fea54f010e,
+                    0x9359268c17e3f1dc,
+                    0x623c130e26f9c02a,
+                    0x55332db6c91ffc44,
+                    0x9347f1e4f9acd2d9,
+                    0xae28a43f617dc6f4,
+                    0x4116c9340ed7b4ec,
+                    0xef955173c3b6b8f7,
+                    0xed6158c2dbb18dad,
+                    0x3f853daba0726788,
+                    0x1615e29c28a2d0f6,
+                    0x00be59ce9a26864f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb46b639abf5585c3,
+                    0x92a8bdec0d17ab49,
+                    0x212cd3bf47e0d4e5,
+                    0xa5cf119ea4f95bbb,
+                    0x158aa6919ffc26de,
+                    0x70c0ea0196c5ca3d,
+                    0xd44f9c80a2124703,
+                    0x0973e6f5edf1bd22,
+                    0x8236ef5a73938941,
+                    0x5c5164bd31520502,
+                    0x884895fcb449049b,
+                    0x0018585ce8e1b843,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa5cfd92fbb701724,
+                    0x3625f4bbbadf78dc,
+                    0x6ceafdc6fd122d55,
+                    0x2da0dab8acd1e255,
+                    0xe8f73c22c8e78b00,
+                    0x1fb127757319f14c,
+                    0x7af319c38108dafc,
+                    0x6adcd8be539afbff,
+                    0x042308727be47a33,
+                    0xd050f35bf5117026,
+                    0xf6c5ef874f700688,
+                    0x0079a8f28698cda2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5fb86100067fb784,
+                    0xe46236c66b235e97,
+                    0xeb43094a93bbed20,
+                    0x3d4497a3bde24193,
+                    0xc5a7e93dc9f921ba,
+                    0x108353c8bb7f1637,
+                    0xe134e6d35edb127d,
+                    0xb9c215ea9e847d9c,
+                    0x9283354f3020819b,
+                    0x0f0529872bf1eba3,
+                    0xb572a87876a06bf2,
+                    0x0073176731ef1c3a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda55f602152cb42b,
+                    0x3bf4cd234b0f6b82,
+                    0xe78c57fac43e5c55,
+                    0x7f8aaf7ec3f2856e,
+                    0x0e2803d64c532214,
+                    0x7d6751f8730d0e1b,
+                    0x8920ae4a1cb11c61,
+                    0xa780f711249e07c1,
+                    0xce7456dbbe3c546e,
+                    0xf8d4eebd43a63094,
+                    0x84284a3153ed549f,
+                    0x00c2e1d33211c7bc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x955515bcac94332e,
+                    0x18083c3a510be404,
+                    0x339e2dcd7c171bb0,
+                    0x1563b749cdbf4327,
+                    0xeddebc88313ef28e,
+                    0x6a50b62c369e896f,
+                    0x8932885593967ced,
+                    0x59723e80a9fe1ff9,
+                    0x89ed81ea5407022c,
+                    0x7643f7bcb88800a4,
+                    0x8336528bf06622b1,
+                    0x00640dc4c17b617a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4054628da057c68,
+                    0xda7d0c9e4425d926,
+                    0x9ba11248ed4abf6c,
+                    0x8a3227cb56c9c2c3,
+                    0x3b66939341af5d67,
+                    0x914562d9fc30c473,
+                    0xae3b2e1af9da9448,
+                    0x6944da25ccd69a5c,
+                    0xf10084c7a0a9cf58,
+                    0x9e1b6a59185c25bb,
+                    0x73f5f3d12f1f5ba7,
+                    0x0054398340cdd34d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a49ceb41d6827b3,
+                    0xc185a769236c78ed,
+                    0x8419a015c12dfd02,
+                    0xb3b4954741dddae5,
+                    0x01fa7515abfc8f35,
+                    0x8d64ddc54b2d7a67,
+                    0x5df8396edf1927b6,
+                    0x1eb2b5f996be49b3,
+                    0x7ea983d1e0da3dae,
+                    0x320fda602fb3b844,
+                    0xbbefd89dd0f67cbb,
+                    0x00d6d80710b841b9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3bcedc4000a35f6,
+                    0xfd1bc5076d8492f2,
+                    0x17857b521703cb4d,
+                    0xf88d61c34b95b430,
+                    0x84fbc366710502d3,
+                    0xcc9722a5468e577d,
+                    0x3e207b39329d9243,
+                    0xfacfc36534810191,
+                    0x8849dfc55d90f186,
+                    0xfee4a4fd47ca2b4f,
+                    0xc5f67d831483971f,
+                    0x007e100201950f45,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdeacbc7f2d6daead,
+                    0x66f05d7194d1bedd,
+                    0x60141b17e1570ade,
+                    0x1534952e9d8c957c,
+                    0x384545c706f27b5f,
+                    0xeda422ef1ab2fb74,
+                    0xa37b4651abff2e33,
+                    0xc140fc15cf987ffe,
+                    0x430db25e81247263,
+                    0x36bf30743f6db592,
+                    0x9c665791699a2aa6,
+                    0x00290bfc5366fa7e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd1ffd760cc80f640,
+                    0x171af47c24ff2be3,
+                    0xbcd6774ba766b2a9,
+                    0x565b2dc4f78138b7,
+                    0xc7a71b25c3b00e11,
+                    0xd90e7e2c49258d62,
+                    0x2a8e89c775ccd198,
+                    0x83c7f7793e46bf69,
+                    0xb05640febde6b537,
+                    0x9b46958353558745,
+                    0xc64930e332062f90,
+                    0x0110704af767ebba,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c881a6add70360a,
+                    0x3ae872023a944846,
+                    0xe72d9a3441e427ad,
+                    0x3273f6928808609b,
+                    0xb5665cdd184063f7,
+                    0x91e13ea6a99414f2,
+                    0xcb61ed5af3f03cbd,
+                    0xb777b7a2f9dd14bb,
+                    0x30a424355c3c355a,
+                    0x65bc69170d60036a,
+                    0x8edbce020371f95c,
+                    0x0023d81f82d7ec20,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe9dfbf06f1883fe4,
+                    0x84ad9ecb457659b6,
+                    0x391b7161834329e1,
+                    0x004a989c65548cde,
+                    0xfafc847a9cbe9205,
+                    0xe07a2701003286de,
+                    0xca60c2f1ae0002c9,
+                    0x949af5daeb2317f5,
+                    0xf76fc6a337582290,
+                    0xa0d9ffbf7e663c6e,
+                    0x19f3f2b2ffb30101,
+                    0x00f4297f34ca3dd1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3f7af5545bb39947,
+                    0x61ff6aa87a98b0b1,
+                    0x4a868c1d6850cda8,
+                    0xed5c6c679e519992,
+                    0x7d07a293b97288a0,
+                    0x9fde1d475c334d6d,
+                    0x6708f2c787dd16f7,
+                    0xd5e31e627869fef4,
+                    0x67a88adf460f0639,
+                    0x04cb1d0e9c215397,
+                    0xb68bba173f13faca,
+                    0x00c0ecc5af622293,
+                ])),
+                Felt::new(BigInteger768([
+                    0x370ce925f805efb6,
+                    0x37024721fbce972c,
+                    0xa534099da7e397a8,
+                    0x2330bc4ccfee7e18,
+                    0x792373af961b8584,
+                    0xe382f261cc9c3997,
+                    0x55fa0651c13933e7,
+                    0x726e38a8f52da1cc,
+                    0x32164767704510ef,
+                    0xe9d3a2dc1c7b45c2,
+                    0x21db9afcf87f2254,
+                    0x0003cd9675c7bb27,
+                ])),
+                Felt::new(BigInteger768([
+                    0x53e42e82ea891f0c,
+                    0x969e8a8e55411b6c,
+                    0xe734f149a9f09491,
+                    0xdbdf5866b8f65f00,
+                    0xce447540f9a53f22,
+                    0xa36dec0dae1968c8,
+                    0x825c6f8220669660,
+                    0x522dd021f551e7cd,
+                    0x5139adb51758cefd,
+                    0x9a44dcfb69e94ce0,
+                    0x705c16a1d8c1fe21,
+                    0x0050f72aab5481d2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x65339f9dd22e53d8,
+                    0x08b48e0a5979370f,
+                    0xf516458f4fee060d,
+                    0x22fb7454c8357c66,
+                    0x980d9a9e8aaa442c,
+                    0x3b4380c1fc0ac481,
+                    0xacd44e882fe234eb,
+                    0x71a3eec55f40f9ce,
+                    0xaa0161b562028918,
+                    0xcce51ca9c864cf21,
+                    0x6ea26faacb3e93a4,
+                    0x009f75e33e9da58e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a5a6c5fd1712a8d,
+                    0x50df8534f186ec24,
+                    0x6f408a0aad2f3aae,
+                    0xe491a652af93f346,
+                    0x4a91d48462899b66,
+                    0xf0384840eb319bb7,
+                    0x382053db08f3528b,
+                    0xdd06b046ac838fd3,
+                    0xce56717102b6fc28,
+                    0x9ca331a35f342509,
+                    0xfc03b53822e295d3,
+                    0x0051fc8ef382abb1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7013e3119367d9af,
+                    0xcdf79f309efc6cac,
+                    0xfdbe22b9b211814b,
+                    0x56f6d1a73d3aa46f,
+                    0x4112e547266c7d5b,
+                    0x0e51261335554cf8,
+                    0xe9875d88d7ab41ca,
+                    0x0d34d0fc210d7789,
+                    0xc6d54c3759695026,
+                    0x1bf3dd10a553411b,
+                    0x38f1556c4c01575c,
+                    0x007e3868b39866ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb65dade874f0878e,
+                    0xda1491b79e7e2d2a,
+                    0xded9783fd369bdaa,
+                    0x9b9effdf46dcc89e,
+                    0x49f5a7cb5a2692bc,
+                    0x4acb67ec72146027,
+                    0xf23387cdbe653c73,
+                    0x006a2c6dd7d72864,
+                    0x29913bc3cfd8079c,
+                    0x33d0c7c0b863f1a5,
+                    0x3317676510288233,
+                    0x009f21214fbb422f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x94d6c433c37b7ab1,
+                    0xc67f68b0a73c2f98,
+                    0x1547fff793876196,
+                    0x1245367b96236c45,
+                    0xeba83b3b2bdd74b1,
+                    0x5a8b58de49a047a3,
+                    0x564cff6c78ec1163,
+                    0xd6c20ae97a77af78,
+                    0xe79f92e75e67a40b,
+                    0x9180ca8b0eb2c809,
+                    0xeb0272757fd8ae38,
+                    0x00e28c4b0c84e7f8,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2e51527dbe62109a,
+                    0x57fe1183f1942c8e,
+                    0x424210a266c9a803,
+                    0x0600df6c4b75425e,
+                    0xad35cc16ea65ff11,
+                    0xb9dccfe693485f1e,
+                    0x3d55731bdcb4e585,
+                    0x458cbd96379cbf23,
+                    0xfa5053ce7f9e3e47,
+                    0xbcd6937d0ec8de6e,
+                    0xa0130d75807d6c42,
+                    0x00ba1921e052f1bc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb228ca3f87462d62,
+                    0x52dbaead2a9dd153,
+                    0xe3cbd8c472d9c8f8,
+                    0x915521c780a2c2cb,
+                    0x9a6e4c3e5094ab30,
+                    0x919cdc3dc5ac6f78,
+                    0xb2b9551b39755024,
+                    0x5ca3fdda45752fb5,
+                    0xe841b0e109664017,
+                    0xc402200de1d88b23,
+                    0x412e636a9afec9be,
+                    0x007dc45f571ae31d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7fc3560a047817d0,
+                    0x999edec71387aab8,
+                    0x09127f27ace7973b,
+                    0x6be02485befed3e9,
+                    0xe8789810199efa45,
+                    0xe0b34ed31d73e1fb,
+                    0xa6bfddc143a682ff,
+                    0xdb4a8a88444fbbfb,
+                    0x631088bf7c3d52c4,
+                    0x6f99d68d50ec84fa,
+                    0xb1ccaefeaf3c53bd,
+                    0x0006bfa21d6c8eec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x46a26a44ae3a5d9e,
+                    0xb0c4b4e14bb57ce6,
+                    0xa91afea48f206e2d,
+                    0xabd5edcaefb13017,
+                    0xd7782c609e2f0d9c,
+                    0x50803ac566dd479a,
+                    0xe5be22f473f3ad7d,
+                    0x1250c9edae7b6f46,
+                    0xb3c0e38ef1925ae0,
+                    0xa3883d388c97f3f0,
+                    0x41ba78cc41dfb0d0,
+                    0x00a458d6f8b7df46,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4ecf52f0c93ac69,
+                    0xd5bebb2a89e5fff5,
+                    0xab0d983a98b50d0c,
+                    0x2f134cd4d8d28139,
+                    0xb4f1affb3d9b98b5,
+                    0xd5d79f89b28aaa4f,
+                    0x35472a04972f84c9,
+                    0x93d15dcc29e7394e,
+                    0x6d6a396eacf85707,
+                    0xee1345a8f7fd8e89,
+                    0x9b4b1975f33c932d,
+                    0x00bce699e72803de,
+                ])),
+                Felt::new(BigInteger768([
+                    0x660910de4060b588,
+                    0x339635f96266eede,
+                    0xbe0c84ec58608de6,
+                    0x7a96d0a492268d75,
+                    0xc755649c6e3d75d3,
+                    0xf6c786e3f8d3db61,
+                    0x089148c278c9bbc3,
+                    0x12bc44c4f1047eaa,
+                    0x31c3698245663cdc,
+                    0x95ffbccd8785da4b,
+                    0x4bec63f8d1fedf85,
+                    0x00ec30b19238f639,
+                ])),
+                Felt::new(BigInteger768([
+                    0x06d309057fc5e5f8,
+                    0xcf59ab2f6d06f0a5,
+                    0x849d13bf00118180,
+                    0x9cf522a6f0c797f7,
+                    0x5879eaa84a981849,
+                    0x245afb6d01353a33,
+                    0x424688160483a463,
+                    0x259b962d5305e909,
+                    0x6e2ead8648068513,
+                    0x1a4f9c34238af81f,
+                    0xb913207e820c19e9,
+                    0x008cbed1047d5b28,
+                ])),
+                Felt::new(BigInteger768([
+                    0x08606bcff3eec147,
+                    0x657bd42d79fc0b0a,
+                    0x9e95d78135eff84f,
+                    0x64e5b89dcc53e45b,
+                    0xb6ee286297d11161,
+                    0xec7d7f03d7263e4e,
+                    0xe73f4a0b9847fc26,
+                    0xe23d30b79b61ca6a,
+                    0x5794f9cf9aa3d4a0,
+                    0xf5f11c22fb0477a5,
+                    0xceb3d39443709878,
+                    0x001ae578d1da5c10,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0a3ffbdc691c1cab,
+                    0x224c933ca0afb255,
+                    0x660d65e695a15188,
+                    0x158bdbca68d2fd29,
+                    0xfed229d2906da0ea,
+                    0x0f44f2360a4f0fad,
+                    0xf436518b7fa20ad9,
+                    0x7100af6f7f969487,
+                    0x9d758c07ea5e014d,
+                    0x05e0f48d428a5ef6,
+                    0xd06ed3c9b0a962e0,
+                    0x00ddcb4b3533d405,
+                ])),
+                Felt::new(BigInteger768([
+                    0x541ff2d168d002ab,
+                    0x1b7e23a0a922dd87,
+                    0x4b422dda02757365,
+                    0x01451f6937a95989,
+                    0x3ab3d9055c4375e6,
+                    0xb7fc3be3c7c3841d,
+                    0xa37b97388000143b,
+                    0xf32075a0e017218f,
+                    0x7508653961d2dd72,
+                    0x332695ffa87f6542,
+                    0x5ca3207a404dcfaa,
+                    0x006cb3f0c1926b41,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8c8460c474f4699d,
+                    0xbc09e585dd0d4785,
+                    0xd322941cc762e578,
+                    0x54539b956186dbd6,
+                    0x8fe892fa91d13aa6,
+                    0x0bee4ae201d35b7f,
+                    0x5c1c79c83054ad68,
+                    0xb951461f86d1e170,
+                    0x8e1cc11e616102ab,
+                    0xd0bfc6f3806c9984,
+                    0x0cc9d286cc4a40f3,
+                    0x009e1ea7afbefa9b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x12d26c04c0445fde,
+                    0xcbeb486698174043,
+                    0x1dae51ba06ac2e90,
+                    0xccdf1bd7e5d0fefa,
+                    0x9ebb91701bccd43e,
+                    0x1938a4363997461d,
+                    0xd381abbfce323fd3,
+                    0x8386ea01c6f19113,
+                    0xce8e3154192091c8,
+                    0x613dcf1d5101f136,
+                    0x12cc6ae17ec962bf,
+                    0x00bd9b483001aecb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd233b3742e0e6745,
+                    0x462378ec8e1bba6a,
+                    0xde17b2adea3a3fd3,
+                    0x7ac32dc717c62105,
+                    0x10c124d2212ca90b,
+                    0xab33f909e8d767df,
+                    0x2c0e65c5e4a12dd1,
+                    0xf0728e6bd86d1c6e,
+                    0xb406338e01045904,
+                    0x43919fe7ef2bb2ee,
+                    0x614adb0dcfca341f,
+                    0x00d505723f9b14b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ced50c0a7943a5a,
+                    0x26b564acfb9d7850,
+                    0x12719958ca328f8f,
+                    0x922030839dfcffba,
+                    0x37b67f51c72a2d8a,
+                    0x2a7e5faf4dcec06d,
+                    0x842a59931da52cbd,
+                    0x00e55a2d177fc0ab,
+                    0x2fc9a005b04d09ee,
+                    0xf72866323c8deb7f,
+                    0x0ccd7444dadbb1bd,
+                    0x01028a6b06e81a83,
+                ])),
+                Felt::new(BigInteger768([
+                    0x452de2a9e6e28ad1,
+                    0xd90bcf7c765c4e4f,
+                    0x81932e935c1c7044,
+                    0x4977c7aa7d5507a8,
+                    0xb9991c27d06ffe85,
+                    0x2738940d8a8c4130,
+                    0xe947dc6e6191ac88,
+                    0x831981bc26145f59,
+                    0xdd7e3e012a7210b5,
+                    0xf96d2a3aa42a65ac,
+                    0x379b717c92df30bc,
+                    0x0038547c00399283,
+                ])),
+                Felt::new(BigInteger768([
+                    0x78fb2ffb71a73893,
+                    0x1e0f631d29b40587,
+                    0x778a2e765b6e022c,
+                    0x43b2db88bb25e6b6,
+                    0x9b5784343fbd5a58,
+                    0xcc87ab365bfe8e18,
+                    0xf35575cc37624f2b,
+                    0xbcfc5006bd59471d,
+                    0xde078492586ae8cc,
+                    0x12a2f046fcfa79c9,
+                    0x78216c1b3845cdf8,
+                    0x00c8c6f52b767a5a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3f22a18ae1ef08f5,
+                    0xea6126b2c2957495,
+                    0xb3a7f2f66215a571,
+                    0x0485a7d2a6f6d854,
+                    0xf46801d3f1afa239,
+                    0x5dd59c17cacabc8c,
+                    0xb01a4d06eb62b051,
+                    0x693801f7944519c2,
+                    0xcc47d75eb9dba537,
+                    0x4ab76af686615256,
+                    0x0016575ef0f4ed4b,
+                    0x007891f6caab8301,
+                ])),
+                Felt::new(BigInteger768([
+                    0x300ba481e0bda95b,
+                    0x482822d311e4ef2a,
+                    0xfb09d02f7bebd14e,
+                    0x74d4f73c86f2e920,
+                    0x73ad2328186aa3c3,
+                    0xbb83c7d3cf55c0ba,
+                    0x909234467ca60a76,
+                    0x81afaf484c82cf26,
+                    0x31c01408883658e7,
+                    0x3977341433443330,
+                    0x352e6157cc3fd8e7,
+                    0x004195dc27d21010,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f84262497bb4260,
+                    0xbddd0e77ec9535c7,
+                    0x652734b0fa8d36de,
+                    0xf14f3ef817b9ced4,
+                    0xd7554fb5f278395b,
+                    0x1434b15cf77f073b,
+                    0xb0aea84c2e5a25a9,
+                    0x23ab094709e851c1,
+                    0x81aa894c1673adec,
+                    0x78d977b376d2b52c,
+                    0x0f7b7d1c65ef6b0c,
+                    0x00b42d4ef13e4230,
+                ])),
+                Felt::new(BigInteger768([
+                    0x29b2c44e9f032286,
+                    0x87dba04e7a5bfd6b,
+                    0x51902dcbda072fad,
+                    0xe98a9c795ce54e2c,
+                    0x3967fffc4bdba75d,
+                    0xbdfb64db30128876,
+                    0x7892c957b23d7700,
+                    0xd63cc11ead77785a,
+                    0x048248e667d01bb9,
+                    0x12bf493e749adb52,
+                    0x2a42992340baefd0,
+                    0x00e4c69ab5d7086d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x86ce0d20cc9b7794,
+                    0xf1223ce85207baeb,
+                    0x1d7d56347bc8c5a3,
+                    0x98dbb185249ef0e0,
+                    0x9af54b4cddde787d,
+                    0x8dace9da9de1e8ae,
+                    0x70984abe0cc08013,
+                    0xc70a58814ce344d7,
+                    0x74e4fefbc8face9b,
+                    0xd5b759763a893c63,
+                    0x2027b46081a6a1e7,
+                    0x00e03f0d4e7f20d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1567a8d5a55c9dd6,
+                    0x22ba227adfd3f384,
+                    0xc6ad8b642fdd0e76,
+                    0x3710bc16c7353a71,
+                    0x7e4311198536eaf6,
+                    0x2ac1e905549529fa,
+                    0xdc530d2c620347c7,
+                    0x895182935e66e49b,
+                    0x38109566faf0d409,
+                    0x629cff039ea5b90b,
+                    0xe11101c776a30cc0,
+                    0x01137b85d5a2ba8b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b02d697953f269c,
+                    0x6ab2d9fc9f1f1624,
+                    0xf36702755b8656b5,
+                    0xf2679ea6c9eeaa37,
+                    0x79fbc795b42cff32,
+                    0x9bbbaf180db93617,
+                    0x5a43373ad1b74d57,
+                    0x8354cbc2204af7ee,
+                    0xec6b4b49870378bb,
+                    0x373d7738d44685aa,
+                    0x78a95a1f77284960,
+                    0x0115a399083fd83f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e54a449b2ac87de,
+                    0x0446492e27377648,
+                    0xd52a13a77157f313,
+                    0x9db210b2647515d6,
+                    0x25b3f2412e30f078,
+                    0x4ed01c63d8e91394,
+                    0x0ff713fdd3674cb5,
+                    0xeb3a30e14e12be60,
+                    0x4600586aa8772dbb,
+                    0x19c70853dfd579d9,
+                    0x50d642b6e9259d38,
+                    0x0021e227f6e8904b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2a39cb6950e1d5d2,
+                    0xdcbe0d57c31648d5,
+                    0x042e0fe3f8d211ad,
+                    0xef284b613f603791,
+                    0x2948ff24c83a016d,
+                    0x491b1fc91fc00f18,
+                    0x92ee3fe26acfa373,
+                    0x7514dfb37cf2ebd8,
+                    0x3a7ece9f582a6295,
+                    0xa8594dc351568a7d,
+                    0x2dc654b4c1be7b5d,
+                    0x008afbd3bf0b15d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe19c2330e553201c,
+                    0x737e64b81662f7ea,
+                    0xfa1aff13ee8eafed,
+                    0xaeb5f2b508879c94,
+                    0x18be2c129a1291a5,
+                    0x92701c8a675f7f12,
+                    0x6169c051cfe8278a,
+                    0xbd1e0bc7cb393783,
+                    0xb48c03a97c9488bf,
+                    0x86fabe25545c8c1d,
+                    0x8cbda84063d09ec5,
+                    0x005156f26524f706,
+                ])),
+                Felt::new(BigInteger768([
+                    0x124b60f3d848f076,
+                    0x14751163ca8386aa,
+                    0xdbb977a31a7cc798,
+                    0xa790aa6534cd99d6,
+                    0x1c545a8118c2115e,
+                    0x7b1b233f869b0f84,
+                    0x7dd4613cbdb9999f,
+                    0x50e14670f18f438c,
+                    0x46132cf423228609,
+                    0x6489eb71523b3d00,
+                    0xeb79c9b7e6d4e261,
+                    0x0119e5a644c897b1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa79d8ec07de02197,
+                    0xb7ccecb17967e851,
+                    0xefdc49cba87a37e1,
+                    0x4a7e3af3dc95a292,
+                    0xc0b8d09af3f5d909,
+                    0xc732b63dc6471c6f,
+                    0x274a99b3b9cd1a62,
+                    0x24e77335d6acd948,
+                    0xc864b567afb5753d,
+                    0xc83d384e0abf952c,
+                    0xe849c7e964f5cc8a,
+                    0x0087b242a4039ef8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x04a4af6977f3afeb,
+                    0xccd3f3f45c97c0b3,
+                    0xfdff870e0fb26a85,
+                    0x663edf97b5196e7c,
+                    0x2bbdd116a3ad8489,
+                    0x99ee3ffc804eebf3,
+                    0xce0632d5aa04702f,
+                    0xa899e3e1a3b94353,
+                    0x657bd2fdee4e7fa1,
+                    0x7c3acda6dd63caa6,
+                    0xbb684d8cb4ccff19,
+                    0x0020ab195fc1b832,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2efe7ecb10f05fb6,
+                    0x5ca7c228f0f6b2e6,
+                    0x0f75856e9d2ba846,
+                    0x4e03d6c0a4dd77f1,
+                    0x30ccbd2e2cd323dd,
+                    0x669866ed0a8a76a2,
+                    0xdd218768db7bc0f9,
+                    0x88e06924b7fc3407,
+                    0x87004f5f89bd287c,
+                    0xfc93f80592964a06,
+                    0x687cc5f368d5bb3f,
+                    0x008a1ea18384fc32,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b4509915aec7de8,
+                    0xc3cabb4e4a61c924,
+                    0x53970cc33356b3fe,
+                    0xba0952c304108465,
+                    0x41d13d9ba81a4cdf,
+                    0x7654d941a5da94a3,
+                    0x67749a2aeba71b3e,
+                    0x9469cdb320dd8137,
+                    0x65a84433c6ebe668,
+                    0xa6892ed7546494a1,
+                    0x87c54726751e2f62,
+                    0x00334bbce0c75fd2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf838763bb28d273,
+                    0xadfbca7c45b51a61,
+                    0xb97b70418e3ed01f,
+                    0x4937bf2cd9136912,
+                    0x2ca9ee8cf57f05ae,
+                    0xe48e578d614cc7a9,
+                    0x56d71584e62a6f20,
+                    0x8ad001f090650a96,
+                    0xc27cc7ec3964423f,
+                    0xde1253bfceeb40b3,
+                    0xca9a4a48f03be96b,
+                    0x00ef9c04be2f7626,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5f9b612775896217,
+                    0x710e7fbcef5f85b0,
+                    0xfb98b16b3388fdf0,
+                    0x429ad879e9ccaeb4,
+                    0x58686ca4d48c314c,
+                    0x3e7b84c815ba81e1,
+                    0xc0a94af9337744ae,
+                    0x76d2cd04f671ca4d,
+                    0x734e75a1314393db,
+                    0x870cec436b246567,
+                    0x6e4d7e8139c75fa2,
+                    0x00a1d1f1b5f41341,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5eb70cd826dd3842,
+                    0x673257245aeeecec,
+                    0xdbcca0feca255d54,
+                    0x5c34d5412268140a,
+                    0x9db2ccce5cb2bc24,
+                    0x0a40f1db8118f628,
+                    0x70112d68bbebcac0,
+                    0x0c2b7e3df91da353,
+                    0xb195d51b06c421a7,
+                    0x0e507a32dd9df89a,
+                    0x1b867dbf92ed1fce,
+                    0x00900f42e437e8f9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3876079637ed85c1,
+                    0xe8a9204000c12467,
+                    0xaf732fa9fffe3a80,
+                    0xb92fdbdc5d246b40,
+                    0x6e8fb044fb14dacd,
+                    0xc75b86199e2dc22a,
+                    0xe6d598e015e59747,
+                    0x580fc9df7980935b,
+                    0xb935662bca315f50,
+                    0x9ba2f005435db993,
+                    0xa465459b78ab3e84,
+                    0x00a68499308d0a7f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xccd306b682c6f623,
+                    0x861259a4a74791f3,
+                    0x8582bb8c476f3708,
+                    0x4911cae2d1a51cca,
+                    0x4c3528894395d5ae,
+                    0xf52b479f9bf37a04,
+                    0x6607bd15358eb354,
+                    0x161975454d926e59,
+                    0x4c6f76eb7726abe9,
+                    0x3c64249751145067,
+                    0x7f8cf63e835ff4f7,
+                    0x004aa039c513f001,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdada1dada2bd73df,
+                    0xeb5c87c3394c3456,
+                    0x629774ce7575a688,
+                    0x4ed228cb9225a458,
+                    0xf8c74aaaf80f5733,
+                    0x9751733942bed82f,
+                    0x5d003361947f80bf,
+                    0x04740f98f0c6723a,
+                    0xbfb63dd3c1fd446c,
+                    0x503be7f071a9a1ed,
+                    0x6af9d3b200057539,
+                    0x004dab319dc6b69b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x59e3e7951a62427c,
+                    0x00a078bc9bf22519,
+                    0x4fc7893bacdbef29,
+                    0xc6a392f0f51e19c6,
+                    0x4c48b9116dc6b4b4,
+                    0x676015cc1f4f422a,
+                    0xe46942b2ece3ad16,
+                    0xcca26e265d0ae236,
+                    0xd9347370c90c84d8,
+                    0xb3c41331c9348501,
+                    0x1370a4c8c90373f9,
+                    0x00ce31514d76334f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x78f4bbb32195fa5c,
+                    0x80b6190f52396469,
+                    0x7b272d3e35c07ebe,
+                    0x67ff77ba8c1a65c9,
+                    0x5eb2128cddbbd05b,
+                    0xb4749678bc500ebf,
+                    0x798acb749c6d17a8,
+                    0x92f26770bee35b08,
+                    0xa7f624e1a0aa6cbd,
+                    0xab651171366f273b,
+                    0x2c1406bbf97b286e,
+                    0x0091e6652e6b8684,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad5cbdc4240d7a0a,
+                    0x29313560ad44da82,
+                    0xde99c18853811da4,
+                    0x4462d92481f61a8b,
+                    0x476dd8becf0ab846,
+                    0x4dc17df41cf11d2e,
+                    0xe4bef1f9c8908597,
+                    0x5b3d1d75659cc850,
+                    0x1fb3cebb76580f71,
+                    0x16b4e4c46edfdfcf,
+                    0x0e9a968dca99f33c,
+                    0x009977b8eea5c4bb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6d974d07bd344d1b,
+                    0x7889f46eeb791a27,
+                    0xc69f254a7dd7816e,
+                    0x23620d4063a578bb,
+                    0xd15c75a89c8c216e,
+                    0xfab63c31f05c59a7,
+                    0xbfbb0bd320b2033d,
+                    0xd3d9f9ba360ed55f,
+                    0x4f5c940b88856cb0,
+                    0x1e4dc818528ccfd1,
+                    0x370c0527669c86d8,
+                    0x00675f5aed9606b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8239f5d31975007a,
+                    0x5a0644d9f05bb04e,
+                    0x0412c5b79c6cc739,
+                    0x3756e1b9665df012,
+                    0x82d7ae1d552a5aca,
+                    0xbfeab5ccc11caec2,
+                    0x7062663d15318cec,
+                    0x91f9026afbd6e877,
+                    0x21cd05f6e6c3236d,
+                    0x0685d92738353c02,
+                    0x3666450633e79a4a,
+                    0x005946afd631da38,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b2a8745affa3c5c,
+                    0x7a7e02357b49de04,
+                    0x2d2e36282ec9ab1b,
+                    0x2481fd2d70f88f01,
+                    0xa7a64eb8082c4781,
+                    0x48958943f98fdc1f,
+                    0x6daa53833d95d48e,
+                    0x06705fbc335fb3d8,
+                    0x6c2f44321491cde6,
+                    0xd6d6d0f99e45058a,
+                    0xd7c194f304de3090,
+                    0x00b6a1b10dc5b13d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd6a0a99a36d5b1d7,
+                    0xe61f693d51c0dfeb,
+                    0x6f10a4f2184d09ac,
+                    0x3635a5795b8bd288,
+                    0x72069514de7a8387,
+                    0x2fa84a6c18123112,
+                    0xdef7d68f975887df,
+                    0xf5294a24a5753e7d,
+                    0x87d6ae6f5620a798,
+                    0x562433c288884495,
+                    0xb36d8c7a970b563d,
+                    0x004a79ac7f7fcba3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdf1b4df21c12b8a9,
+                    0xf0ae3680ebfd299a,
+                    0x4b27bcc201f961f6,
+                    0xb4fd2fd843a11983,
+                    0xc3f4acb681fe5906,
+                    0x6a137c62a94e3de2,
+                    0xcdc1af847fc932f0,
+                    0xb41633c26075caeb,
+                    0x656b3075890fae5d,
+                    0x2f15cb05a307d611,
+                    0x5a24b5f6ae056913,
+                    0x00390cecb4ca1ca2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x83e20a336cead48c,
+                    0x07abc7e7603de91a,
+                    0xebc4490a81bd9f9d,
+                    0xea3472d61b2802e7,
+                    0x68f8bd448781d379,
+                    0x8676344e5ed869c9,
+                    0x50656e893ef02a9d,
+                    0x88daaeda35126546,
+                    0x3f37d7e369415524,
+                    0xa135b744e1378b5a,
+                    0x372d598897176879,
+                    0x00ddfaeeb7c2cad2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd69b4d3b59307c95,
+                    0xd87883e641bb58d3,
+                    0x67272e931a4ed864,
+                    0x8f2bfdc20536ad7b,
+                    0xbe2fc9c4122a8b5c,
+                    0xeef57cfe49f9db75,
+                    0x5a8fe95c2f8352e5,
+                    0x71e7d248e7fc5130,
+                    0x77b0b97988eb4047,
+                    0x08be78a61a15a181,
+                    0x778997234040d9b7,
+                    0x009f5f670ac8893e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd1edc45fe34a49a,
+                    0xc483a0b578794867,
+                    0x477d8b61e43dd222,
+                    0x4d784bb34e9494f7,
+                    0x40ddf3a6fdd61640,
+                    0xff714ec8bd5f38b8,
+                    0xb5ba580e69753bed,
+                    0xcb63926b33b8c86a,
+                    0x6f89c5fddd39dd87,
+                    0xf8f61576ba07d38c,
+                    0x8b2a52517f45b387,
+                    0x007fec45b7ec7e71,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,21 @@
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+
+/// An instantiation of Anemoi with state width 4 and
+/// rate 2 targetting the 256-bit security level.
+pub mod anemoi_4_2;
+
+/// An instantiation of Anemoi with state width 6 and
+/// rate 4 targetting the 256-bit security level.
+pub mod anemoi_6_4;
+
+/// An instantiation of Anemoi with state width 8 and
+/// rate 6 targetting the 256-bit security level.
+pub mod anemoi_8_6;
+
+/// An instantiation of Anemoi with state width 10 and
+/// rate 8 targetting the 256-bit security level.
+pub mod anemoi_10_8;
+
+/// An instantiation of Anemoi with state width 12 and
+/// rate 10 targetting the 256-bit security level.
+pub mod anemoi_12_10;
@@ -0,0 +1,1816 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut buf = [0u8; 96];
+        for (i, chunk) in bytes.chunks(95).enumerate() {
+            if i < num_elements - 1 {
+                buf[0..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[0] += Felt::read(&buf[..]).unwrap();
+            apply_permutation(&mut state);
+        }
+        state[STATE_WIDTH - 1] += Felt::one();
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        for &element in elems.iter() {
+            state[0] += element;
+            apply_permutation(&mut state);
+        }
+
+        state[STATE_WIDTH - 1] += Felt::one();
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // We use internally the Jive compression method, as compressing the digests
+        // through the Sponge construction would require two internal permutation calls.
+        let result = Self::compress(&Self::Digest::digests_to_elements(digests));
+        Self::Digest::new(result.try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        vec![state[0] + state[1] + elems[0] + elems[1]]
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        // This instantiation only supports Jive-2 compression mode.
+        assert!(k == 2);
+
+        Self::compress(elems)
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        // This instantiation only supports Jive-2 compression mode.
+        assert!(k == 2);
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U1>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(), Felt::zero()],
+            vec![Felt::one(), Felt::one()],
+            vec![Felt::zero(), Felt::one()],
+            vec![Felt::one(), Felt::zero()],
+            vec![Felt::new(BigInteger768([
+                0x342f631af753135f,
+                0x1de7813d5b2258a9,
+                0x35d1f450b7169afc,
+                0xbcc7c1509e681335,
+                0x0645f2429a979c2a,
+                0x588ed64c61ad4b6a,
+                0x1d5b2163805ffa81,
+                0x7ba89b18ed197399,
+                0xece5faa55d2aa572,
+                0x321970ba406425bc,
+                0x03f41b3ba3d81640,
+                0x00d4b57f7e3da99c,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x34242503f39c5ea2,
+                    0xfe392b065dbdc603,
+                    0x444b47ae9dc66570,
+                    0xc04b748d49d4de57,
+                    0x11817e7ec071d883,
+                    0x79bbfe252c9384d9,
+                    0x36ce6511b33436fe,
+                    0x40782e791af6e6da,
+                    0xba1a4d1e83206b57,
+                    0xc57390cd062c3a96,
+                    0x14efe6b995fb256a,
+                    0x0078c34fc6ed261c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9cd44cadcd538bba,
+                    0x875c82b727093420,
+                    0x282f3a2901a9d0c4,
+                    0xb6e077388fa3cb92,
+                    0x68835c2abb7b58ae,
+                    0x5e3c9171188ecc3d,
+                    0x190d6977797f76f5,
+                    0x320cae58546d9bcd,
+                    0x16ff290309db7706,
+                    0xd00332437cd71701,
+                    0xf6e56d2c084e3d2a,
+                    0x00a412a7555dd33f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xd8b439972282f1b8,
+                    0x3f1e2204119761a4,
+                    0x9933af38bf1de5b9,
+                    0xe61fb9c266d99560,
+                    0x424f3bcffa79c590,
+                    0x924ec322fb23562e,
+                    0x04c787d3a8eaba79,
+                    0x6b4845d290cff329,
+                    0xc6845c69d650f364,
+                    0xf0d01fff3130a4fd,
+                    0xcd659ea0921a63d9,
+                    0x010d6979756a49a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2964062eebcc5279,
+                    0x662cec2cbd2ea3ae,
+                    0xb67a2ff61e11fab5,
+                    0xb95978d6539c0567,
+                    0x1ab8ac1f8b5c6952,
+                    0xbc83b6e5f3cf1518,
+                    0x30f6b00e65694591,
+                    0xacfc75d00297ad58,
+                    0x3063345a2a85ba20,
+                    0x6d4cc64a44c5641e,
+                    0x9c93ac071333aefb,
+                    0x00468518fe6d17e0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x07d37334b943f770,
+                    0xdbed8d53ae71c995,
+                    0x6dd4e1658a7bb619,
+                    0x3e3e6bdcd441ccbf,
+                    0x3bc9a812394fb670,
+                    0x515ed09cfe3d6d5c,
+                    0x4bef18ae745eda8f,
+                    0x93ad09461272c31c,
+                    0xa894cfe6829547a6,
+                    0x3b1b062a475ce340,
+                    0x92b6acb4e470b595,
+                    0x00537e252d721ea1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xe7ca31207682a88d,
+                    0x9e6806ac96069aea,
+                    0x5007243c4fdbc993,
+                    0xa36b7a8ccf93b1b1,
+                    0x38f26a6cdf4550c4,
+                    0xd84147e098f48355,
+                    0x14e3ad46c5ff7c22,
+                    0x17f46d2399c6abe0,
+                    0xb662b26f8a8323b5,
+                    0x1bc8d7a20475a8e2,
+                    0xed26b3605a19ad0f,
+                    0x005c7bc0dee00038,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc109b63ad54e5746,
+                    0x494fe2fe9571626e,
+                    0x4f726754687d07ab,
+                    0xe20351637fe7e2e0,
+                    0xc39a18a438a9720c,
+                    0x701d8157db11efe5,
+                    0x637f4203834c974e,
+                    0x3770438614fe5d89,
+                    0x7e36eb88992dfb53,
+                    0xd7cd7e9a97f21787,
+                    0x36ec3d67b6ec3abc,
+                    0x00fd196433e24c13,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e1a25fe7d34e6fa,
+                    0xaa6eeafd95d21375,
+                    0x57542f91e49cff7d,
+                    0x62922b97d25df7de,
+                    0x6456e73af29aa96a,
+                    0x30655859fa1d17e2,
+                    0xd2e57da9ca836508,
+                    0x9264b74ebcf2100a,
+                    0xde4f66030046a922,
+                    0xc6ea355f96edbe0a,
+                    0x981ac04803f97748,
+                    0x0115674d8b86a936,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb8dcd28b4aea6236,
+                    0xfea8b1664435d424,
+                    0x300e5dbceb1f17ca,
+                    0xe3208f3056cb96e2,
+                    0xc165eaaf65e6220d,
+                    0xe4062516baafd4d8,
+                    0xae613246348d4236,
+                    0xdfd1eed6baebd8f6,
+                    0xfa7df66a16728583,
+                    0xcdd069a28ee42692,
+                    0xd640004ea717dfb7,
+                    0x010ff177b7a3f919,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x40d14f04f81efefc,
+                    0x295dae90ce088c89,
+                    0xa31ceb2368e456a3,
+                    0x8f955373e1ca9415,
+                    0x397e66cf933f6c7a,
+                    0xc6568fd2734ab542,
+                    0x991d1417dcd402fa,
+                    0x5e0403a67c75f830,
+                    0x4d9bce3ac8687815,
+                    0x539a619885dcb14c,
+                    0x8827bd8d032dd8cc,
+                    0x00713e5b8fb2ddf2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x479b7f3af15dd6b2,
+                    0x37b62fe9f3362f8b,
+                    0x5b6ef9f46a2dbc6d,
+                    0xf00b3266c6361d24,
+                    0xe72bd710453ccd9a,
+                    0xa9c54854efa8cc34,
+                    0x1e829549f18d193d,
+                    0x15651e0738b68de1,
+                    0x5a59133432f58d9f,
+                    0x6921f837e43234ab,
+                    0xebc1deca4c23df5c,
+                    0x0113dc9f6edb0645,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3df9278398fade43,
+                    0xcf7f30a565c71cd5,
+                    0x6c27d920694fee6f,
+                    0xca5d8944d17108c7,
+                    0xb2a6da887e77c08d,
+                    0xc8bf72ba341f766f,
+                    0x945befa39733e31a,
+                    0x3c4dba63cf622641,
+                    0xf0f76fa345e87286,
+                    0x863c9f8d6204ac5e,
+                    0x39796f4c70700d78,
+                    0x01073f7fc5d443b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3bba3c4ccb0c4cdf,
+                    0x8ad137035e966f6c,
+                    0x3c900d03f686ed70,
+                    0xa5a2ae0290848382,
+                    0xd44100f9c45d7706,
+                    0x684e43456a9ac226,
+                    0xe2c53dc336ad1a0c,
+                    0x637d1c2ffd006f60,
+                    0xa4f23c759840c8ac,
+                    0x017fa5e4260849c8,
+                    0x0b525b51edb9e877,
+                    0x0095d2f0bc785635,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe93f75ab38cb7edb,
+                    0x044aeab9039408e3,
+                    0xb2df5b1db0373712,
+                    0x6df35f4cc4f3b053,
+                    0xfc87023cf7b7a1a4,
+                    0xc6e7d6931d3ad39a,
+                    0xabb2f5926b93b062,
+                    0xab4296f27591953e,
+                    0x81bad205b7278f78,
+                    0xc940488561a2b9b8,
+                    0xd69e6668219a8a83,
+                    0x00fb4485e12747fe,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x34e6ae24600815a4,
+                    0xc84628723f83c1a0,
+                    0x7537608da45dc1f5,
+                    0xfa22cd65431259f9,
+                    0xeab6c4efa8376770,
+                    0x771c04e42bdab5aa,
+                    0x840537eff6a1acb7,
+                    0x4410c1125446669b,
+                    0x55f9b6eb2842f3b5,
+                    0x57a85b702e3522f2,
+                    0x641864e6eb1637ef,
+                    0x0032bb74ccb08a32,
+                ])),
+                Felt::new(BigInteger768([
+                    0x65108a7603ac7f86,
+                    0xd7bcac4e68d21252,
+                    0x8d9b8f2e5722245a,
+                    0xfc635cb3d952ec49,
+                    0xd58f65f3c600cf18,
+                    0x6da2abf731d80833,
+                    0x4008e9f41c298112,
+                    0x8ce39804d704113f,
+                    0x54e2d2445bfe65e6,
+                    0xe7271b4e2e307e6c,
+                    0x12755194d01d75bf,
+                    0x0054ed41cf7b1e78,
+                ])),
+                Felt::new(BigInteger768([
+                    0x408910e8212333d9,
+                    0xf2dab6aff7204c29,
+                    0x8dce62b137be3516,
+                    0xabbe4fbee31bb48d,
+                    0xca43f5af7c2a6194,
+                    0xdfb3b23897ec607a,
+                    0x9dafebffcc4c0c27,
+                    0x22825e029b606d5e,
+                    0x7280e45f9d8c6894,
+                    0xb0b5b60dc7037d9d,
+                    0xef8aaa28f1da5b91,
+                    0x00ddee619ed0797f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a2ebda61a69d21e,
+                    0x2502b05b6f69b14f,
+                    0x0b197bceca46dcdf,
+                    0x2fe01752c714aa95,
+                    0x5fb0f523f3506cbc,
+                    0x9a3d88bffc77baea,
+                    0xadf392cd8289e401,
+                    0xc072d83d1513d619,
+                    0x0ffd91840900194d,
+                    0x55c8d76c39b3670d,
+                    0x198f7a3ef4799380,
+                    0x009b4bf4b6bcc269,
+                ])),
+                Felt::new(BigInteger768([
+                    0x64079b9a47b46924,
+                    0xb9bc785f4d56ee3b,
+                    0x1359d1629d207b44,
+                    0x1a2ab7e1c1842209,
+                    0x532a2b7a2bc660c4,
+                    0xec4390ba8d4da3a8,
+                    0xc968190c1fba15b3,
+                    0x7c1a18630904540e,
+                    0x0018779abd764d86,
+                    0x0ec15ade6371c361,
+                    0x95db22d3a86d0b7a,
+                    0x0029734c42f07540,
+                ])),
+                Felt::new(BigInteger768([
+                    0x95d08670e53c530e,
+                    0x97805b72c5d32a6e,
+                    0xb9ae9d150782c6a1,
+                    0x16c3cb8f889871b7,
+                    0xb8090672eb686794,
+                    0x1aa9e656d432775d,
+                    0xd5799f4c5c727ec1,
+                    0xa12318c4bad320ec,
+                    0x6ac7d6c065bf49cd,
+                    0xdf0f9a27a6da57c8,
+                    0xed65a326db94f776,
+                    0x0106ca6e6fd55d2f,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0xbcf46120e3533431,
+                0xec58cc9ed2806b1b,
+                0x6037bd9a0fd17959,
+                0x17263d7fc1299ff5,
+                0x43a1e752edd2b0f1,
+                0x79ba472f8c7730dc,
+                0x2a8f11edf04a47a7,
+                0xa62277c98212fe9e,
+                0xfa6476383d8b63d7,
+                0x32d46cbd911c28b4,
+                0xcd1fa4ff3020a142,
+                0x006d4e2eefa14255,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x79083aea47742815,
+                0x9e57f5961f2ef936,
+                0x881d0b537af4b5ea,
+                0xde62fbe5c0e70bc1,
+                0x2ad0fdb40f9b5a08,
+                0x1b211b1f9b6ca617,
+                0x6c9d70657dd923a5,
+                0x78388bb5dd8c9f3b,
+                0x427a607713a16b44,
+                0x04ec959b5e5b4af0,
+                0x706daf232a515c17,
+                0x0090d24051c9a0dc,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xacde02a432720ead,
+                0xba550ab2e537b773,
+                0x8df17ba1a09c1c6e,
+                0x09d592381dd97e1e,
+                0x60a9a53029c0d08b,
+                0x3b15a0d28b24dc7a,
+                0x136e85474813bd32,
+                0xfc4e95a1bff70d12,
+                0xa7c2d0cb955c7e23,
+                0xc6ba8e2666084c1a,
+                0xd958adaa7fef4276,
+                0x00c16a4b728335ac,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xa9b95a3c454458f5,
+                0x9efb3d3c0827fc94,
+                0x0506daaf6f44c2dc,
+                0xb0b1d05da2df872c,
+                0xcd3b2cad269cccd5,
+                0xea23018c8c5dc17d,
+                0xb1ca853cdddd736b,
+                0x002e63cc00814764,
+                0xc2b0549ab0d1d4fd,
+                0x0bdca6a86ef59c5f,
+                0xd8bae60931fa2b41,
+                0x00f0efba0a28def2,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x14617c56c27a23b3,
+                0xc0b1aacde47b5c02,
+                0x79d6ad49eef4b208,
+                0xf64dc7a75a1b2bac,
+                0xa9a2d3e11e3424a0,
+                0x49aa49ed99de6c64,
+                0xa7353eccd7e20526,
+                0xec2f191a20e3381c,
+                0xc79dae8097ba1bd7,
+                0x5486246a07dd53c6,
+                0xb55040ed87a92d78,
+                0x01075ecac7642cba,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x3aca569931f1b89a,
+                0xe0eebd118b1a2283,
+                0x2141f3253bf81b12,
+                0x27bf8ddef0765d90,
+                0x88413d7a9ef47715,
+                0x68c80944016d0fe6,
+                0x55b4f14c3ff874b5,
+                0x775d848e12fd6ed6,
+                0xd38beb3ea7714fd4,
+                0xb605d75158fb7abf,
+                0xd50befdfd63daf87,
+                0x003064f4bcded624,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf8d2f855232a54c7,
+                0x09a7008af9c10039,
+                0xd5d1f489f94220cc,
+                0xcff3383c7a8a9379,
+                0x1a895e9ea0ee9b5b,
+                0x269dac5ceefd1512,
+                0x958bc73945ca5aee,
+                0xcfa4d6c7958122fd,
+                0x0da61fd412c2cd1d,
+                0x705750e82288a3e4,
+                0xfb383c3bc1b7f878,
+                0x0026c8e64363ccf5,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe7b4d3063bf3b351,
+                0x0002118440e9d6eb,
+                0xa9acc59b4b3259c9,
+                0x37e6bd03a084e72a,
+                0xaef9c989f3139f3a,
+                0x09ebe397b9eb13ce,
+                0x53124b46422e6f42,
+                0x306966a0b9e5604c,
+                0xd272b3940df93e2c,
+                0x56ea07f02f268cd1,
+                0x86db1eb41fccea5c,
+                0x007cab5772b2e2da,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x275ee973cab2c704,
+                0x5123a3b1302a7027,
+                0x8eb8c55608cb7efc,
+                0xa96e48e5845c90d5,
+                0xf139c95f55029d24,
+                0x4ee26b8922c7d33f,
+                0xec7630f75881d7b9,
+                0x27d598b880d18832,
+                0xbb502a4ea9fcae70,
+                0x4d4b95b9ed76c9e5,
+                0xb27c91409cce42c4,
+                0x0006c21f04ac93d4,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf5688338b350774d,
+                0x5a6d0c61a5773207,
+                0xe196e50cfafd38f2,
+                0x0a65268aeabfeb3d,
+                0x276de3f4daeb0666,
+                0x6bde73839cef0a22,
+                0x4f102aacab094a6b,
+                0x133035bffe6c2c5b,
+                0xbfb9f9f9e50b3b9f,
+                0x2f98a71a02f0c761,
+                0xf67f3c850cac23d3,
+                0x0023b952a99c07d8,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(), Felt::zero()],
+            vec![Felt::one(), Felt::one()],
+            vec![Felt::zero(), Felt::one()],
+            vec![Felt::one(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0xbcf46120e3533431,
+                0xec58cc9ed2806b1b,
+                0x6037bd9a0fd17959,
+                0x17263d7fc1299ff5,
+                0x43a1e752edd2b0f1,
+                0x79ba472f8c7730dc,
+                0x2a8f11edf04a47a7,
+                0xa62277c98212fe9e,
+                0xfa6476383d8b63d7,
+                0x32d46cbd911c28b4,
+                0xcd1fa4ff3020a142,
+                0x006d4e2eefa14255,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x79083aea47742815,
+                0x9e57f5961f2ef936,
+                0x881d0b537af4b5ea,
+                0xde62fbe5c0e70bc1,
+                0x2ad0fdb40f9b5a08,
+                0x1b211b1f9b6ca617,
+                0x6c9d70657dd923a5,
+                0x78388bb5dd8c9f3b,
+                0x427a607713a16b44,
+                0x04ec959b5e5b4af0,
+                0x706daf232a515c17,
+                0x0090d24051c9a0dc,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xacde02a432720ead,
+                0xba550ab2e537b773,
+                0x8df17ba1a09c1c6e,
+                0x09d592381dd97e1e,
+                0x60a9a53029c0d08b,
+                0x3b15a0d28b24dc7a,
+                0x136e85474813bd32,
+                0xfc4e95a1bff70d12,
+                0xa7c2d0cb955c7e23,
+                0xc6ba8e2666084c1a,
+                0xd958adaa7fef4276,
+                0x00c16a4b728335ac,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xa9b95a3c454458f5,
+                0x9efb3d3c0827fc94,
+                0x0506daaf6f44c2dc,
+                0xb0b1d05da2df872c,
+                0xcd3b2cad269cccd5,
+                0xea23018c8c5dc17d,
+                0xb1ca853cdddd736b,
+                0x002e63cc00814764,
+                0xc2b0549ab0d1d4fd,
+                0x0bdca6a86ef59c5f,
+                0xd8bae60931fa2b41,
+                0x00f0efba0a28def2,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 190];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(), Felt::zero()],
+            vec![Felt::one(), Felt::one()],
+            vec![Felt::zero(), Felt::one()],
+            vec![Felt::one(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger768([
+                    0x3498db69ee9627fc,
+                    0xf2ad8a68ed9e845d,
+                    0x6e42b8de4b62c6cd,
+                    0xd10b5fd0c139a6b1,
+                    0x89a560821865456b,
+                    0x502c1ef261773a9c,
+                    0xad8cb14fd79e7835,
+                    0x05af2bd04745a0de,
+                    0xaa3ac7adf6d0f7d0,
+                    0x826c5473927240c5,
+                    0x0dcdc1ce5128379c,
+                    0x0073461a64d38563,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4227071d2aca9c80,
+                    0x8b65d711b97a1fff,
+                    0x5b18bd67503a6d9d,
+                    0xc66285bae2c81672,
+                    0xee028b214f9d3fb7,
+                    0xb895c5f0abc6a479,
+                    0x18f119d0c9e90d6a,
+                    0xbc0aa67bf9fc6a24,
+                    0x76af33728849c51a,
+                    0x42bccb1fa216c16e,
+                    0x1fd9477fd48fd923,
+                    0x0094dc6f7661ffe1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xca12722415608186,
+                    0x03bac76002e75f9f,
+                    0xd6506b1cf62008ea,
+                    0x41da69e3fa61b213,
+                    0x96c328c0dfab2421,
+                    0x145d301b8a58c598,
+                    0xb0c60c3375e52651,
+                    0xc8405200dff92978,
+                    0x2f19bf778bd95ea4,
+                    0x07435aa5a3701035,
+                    0x9268d116994f2f75,
+                    0x004cb900681809c6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc08841df860408b7,
+                    0xaa18781980ecea4f,
+                    0x892839bfbc721bbb,
+                    0x0b7c7efa73f5f2c6,
+                    0x2e2ad9c79e84f30f,
+                    0x63c2fc7e84132d5d,
+                    0xb73d815997222079,
+                    0x34cb55512be4f484,
+                    0xf477df5f5c94abfd,
+                    0x51cfcafbafabf42e,
+                    0x0368c30b234bd379,
+                    0x01118f003d2336ed,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb45a34d0b041d813,
+                    0x0d01c783f4ad479d,
+                    0xc4631c39868cfae0,
+                    0x781c11edf5f3f14e,
+                    0x5c5fe42453162a34,
+                    0xa456f40579845311,
+                    0x003924da49bc2125,
+                    0xc0f8683ac0ac497a,
+                    0x86b8fee25c712310,
+                    0x414476c77674ddc1,
+                    0xec58a9bb2da34b8d,
+                    0x00b62b2509dbb3e4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1f065314f723276d,
+                    0xd7a3d3d6b3515b61,
+                    0x5804a80fc597eb75,
+                    0x6f0c2bf12047c7e5,
+                    0x781cb57a67e5ffee,
+                    0x0607f8cf61611f66,
+                    0x9f5112852485efd4,
+                    0xe678e457d7dfce10,
+                    0xce36847c9f02e246,
+                    0xffc7cd6a79d56f00,
+                    0x27342f492118680f,
+                    0x00885ee622f91b9e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xcd2f1cd6942923e2,
+                    0x72832e998cd2967c,
+                    0x11b603725604549f,
+                    0x9cf1ee29bb9674a3,
+                    0x88711cd0a2840de9,
+                    0x7ee93ed602d3326a,
+                    0x25305aaf878e1e7a,
+                    0xbad800fe6eed4f26,
+                    0xabba176123a081ba,
+                    0xf59ba3cdcdd9406d,
+                    0x2ac87ed3d657e738,
+                    0x00957bcae4d68a93,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c883ec43dd8f125,
+                    0x4ff7a67658f47948,
+                    0x709c12cc8c16ae57,
+                    0xc8a4b36c712746bd,
+                    0x56620991f0288f9c,
+                    0xcd46c426c7830272,
+                    0x08f9aa0f4a1be1cb,
+                    0x3cd1929e953f940b,
+                    0xced62d36f64fcd66,
+                    0x91e1f4195b7bdc78,
+                    0x014ae594381e29b8,
+                    0x006393f0bd7cfdf0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x271bfcc395c1a907,
+                    0x9b93606920988b67,
+                    0x8d1973cb3072de51,
+                    0x7ebe2b584a841356,
+                    0x30aa913edd49f38a,
+                    0xbd7d243deb2734ec,
+                    0xe9b97f0af237cdfa,
+                    0x7373f1bcac126333,
+                    0x99fd2dccfa819a76,
+                    0x98afd7a5689bc00a,
+                    0xa369c37b6e9cf157,
+                    0x000f5e280b64fd65,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01a7d080a2c4ed8c,
+                    0x39d8921c059f60ef,
+                    0xbf872d90906b500c,
+                    0x922205987fe2c97a,
+                    0x3ed026ef4eb4c4ad,
+                    0x5658cdbdffc3f09d,
+                    0x73de4497b75f9b3d,
+                    0x2dc8e1e8e9a0749c,
+                    0x03a887ff7df30ec7,
+                    0x0374d29d14f3dd95,
+                    0xce58b38d3d12338b,
+                    0x00536a8aac4cbb88,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xbaf4a60ac009f9d5,
+                    0x744e0f0b68ca2c13,
+                    0x7c5afc6207715d72,
+                    0x6a10efa0a7ac1383,
+                    0x136ab1010f347f71,
+                    0xcb25322d64a983f9,
+                    0xf80cc3ff509bc167,
+                    0x1f6ef30c9654957b,
+                    0x4f4302a778fdd68a,
+                    0x2fd28f25a56f86c5,
+                    0xd0a6e790386c8459,
+                    0x0075cd28ad2d0146,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf208d55880f26f3c,
+                    0x16d6df7ebc77478a,
+                    0xb3fce1fe05c6a7b5,
+                    0xda3a1c145e37b266,
+                    0xcfa972e4e2b7d247,
+                    0x9bbf0a50468dddd2,
+                    0x78a2f139b2c9cae9,
+                    0x76c13b5b320a550a,
+                    0xa6be2450b07836c7,
+                    0x404d6c4e3e2599fd,
+                    0x7ae8bab4e02c0654,
+                    0x007f916f3a2f6819,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x6a9bad498c44b480,
+                0xe3f89fbd397e1f34,
+                0x27069d8d5ac4e4d8,
+                0x90f13cfe3725c9bd,
+                0x02883e55d2832940,
+                0xacfbc51977e7c32a,
+                0x7f56ff260f8e628e,
+                0x38a66f5f378d5696,
+                0xd19d0b6281782376,
+                0x92cb97f5324c127c,
+                0x3430d81081ba3922,
+                0x00c5870f48013fa0,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf74fa8032427e83a,
+                0x109d68afdbd56ab5,
+                0xfc89803a7a206784,
+                0x1b9dbc800e4e80c2,
+                0xe39b49f886f7bfb1,
+                0xd993b79c8c618398,
+                0x8148c040549a1c89,
+                0x732087dfd2034199,
+                0x1ac74749f788a8df,
+                0xcaea7a124f02f169,
+                0x22bd36d740ca62d7,
+                0x0089e93796656f02,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xbf80a1a749e52ab5,
+                0x442b67b8c76ad9be,
+                0x9bc8bfc92ab798f4,
+                0xf70fa8bb4e704a64,
+                0xdc0414585372f85a,
+                0x239a2c9168949d56,
+                0x7d7e16c1de87ecda,
+                0x154f776207c385c1,
+                0xb88af8e75fbf7a05,
+                0xe59e87a66f0b5d7c,
+                0xd74cd47bdcbb248c,
+                0x0067a67c91db5123,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x7e1c6514baa9040d,
+                0x774bae5b24136d34,
+                0x060f86c56cc21e92,
+                0x68efe2fc81c3f82c,
+                0xed8938d3655a432c,
+                0x14852f9649622581,
+                0x3a17d6be3483fed2,
+                0xc00d3efb79a41037,
+                0xd8c309a71ef0db43,
+                0xd2d158024eef7b96,
+                0x6a71037dee1eacab,
+                0x0061e78b4d197a2e,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xcb23f2cec0a3df26,
+                0x343a3eebefa26246,
+                0xc510883686d9324e,
+                0xd5a8510aac695a14,
+                0xbf36dcc55ecc3db9,
+                0x136d2c9c97d8b258,
+                0x6b26a7e10ac3bdad,
+                0xea374fa3124f7ed9,
+                0x9bfc425454891592,
+                0x03211b887169396a,
+                0x5cf88a67d0f3a9d9,
+                0x011349c2f6f002d6,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xaabfc29d580a48b0,
+                0x8bf8ce40fd9144f5,
+                0x183858c13708a831,
+                0xd5f43b8248c1e998,
+                0x8e43dc5d8df5d4c5,
+                0x5956d9a9cbec03f6,
+                0x0848e16b040a6f5c,
+                0x874d6309a8c7b6f8,
+                0x54140412323731b8,
+                0x2fc39c11428b271f,
+                0x6da49fe027bbe559,
+                0x00ccfce42475fc57,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x3deca60ce23c86e3,
+                0xcd649d4a54e0c31b,
+                0x5eb59b9071ee83b6,
+                0x66c15a2ddbbd9161,
+                0xaad5201f19df64cc,
+                0x8f247edb8ab8f4cc,
+                0x2cc80731d7c54827,
+                0xeede1d8a1aeaea15,
+                0xf5cfde026648b13a,
+                0xef20cb0a3e43af29,
+                0xec4d592c921a9bb1,
+                0x0036260eebbfaa8a,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x764801f87d1bd0a0,
+                0xc2afb09a7b90b802,
+                0xc80e8712de2bfa7b,
+                0x1a265a6d53ab30d4,
+                0xbd3c961010de30bb,
+                0xd3b3e414615f22c7,
+                0x307c4c66aaf9b996,
+                0x0cf1488d214712ab,
+                0x1e558a2ccdd8a430,
+                0x0d2ed89bada3296b,
+                0x0eadac12fd187304,
+                0x008347f058fdd505,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x5a3b79c2b13b5c3e,
+                0x87fc28b9c343d7fe,
+                0x7472a89ee7d7df3e,
+                0x008f75a6ada3f814,
+                0x5317c80869b47808,
+                0x4d82e505b0c329f1,
+                0x1c5f4b42a3d61440,
+                0x580bf1130da4b70c,
+                0xce8d679ac08f749f,
+                0xa46e23c6dac2139f,
+                0x4cb683e8da14e99a,
+                0x00ac0fa3c980daf4,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x75d03efdb5cbff56,
+                0xe23f16eb88525ac9,
+                0xd1199ea722cf3f67,
+                0x09ce97b6676719e1,
+                0xef4fa61e0bc4c879,
+                0x81462c917b899d11,
+                0xbaba4d071ac3afa9,
+                0xa02ccdd1b5fcd063,
+                0x6a2cb3d248b3f128,
+                0x182b1ae83d821c47,
+                0xf627d63d97adb6de,
+                0x00064ee85aa34ca1,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(
+                expected,
+                AnemoiHash::merge(&[AnemoiDigest::new([input[0]]), AnemoiDigest::new([input[1]])])
+                    .to_elements()
+            );
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 95, 96, 290] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_xof() {
+        use ark_ff::{BigInteger, FpParameters};
+
+        // (760 - 127) / 8 bytes of each rate register are squeezed
+        let capacity = <<Felt as PrimeField>::Params as FpParameters>::CAPACITY as usize;
+        assert_eq!(XOF_BYTES, 79);
+        assert!(8 * XOF_BYTES + 127 <= capacity);
+        assert!(8 * (XOF_BYTES + 1) + 127 > capacity);
+
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"anemoi");
+        let mut state = hasher.finalize_state();
+
+        // The output is made of the least significant bytes of the rate registers of the
+        // successive states, whatever its length
+        const NUM_SQUEEZES: usize = 256;
+        let mut output = vec![0u8; NUM_SQUEEZES * XOF_BYTES * RATE_WIDTH + 3];
+        hasher.finalize_xof().read(&mut output);
+        for block in output.chunks(XOF_BYTES * RATE_WIDTH) {
+            for (chunk, element) in block.chunks(XOF_BYTES).zip(state.iter()) {
+                assert_eq!(chunk, &element.into_repr().to_bytes_le()[..chunk.len()]);
+            }
+            apply_permutation(&mut state);
+        }
+
+        // Each bit of the squeezed bytes is balanced, up to the most significant ones. The
+        // input being fixed, the bounds below are met deterministically.
+        let samples: Vec<&[u8]> = output.chunks_exact(XOF_BYTES).collect();
+        for bit in 0..8 * XOF_BYTES {
+            let ones = samples
+                .iter()
+                .filter(|sample| (sample[bit / 8] >> (bit % 8)) & 1 == 1)
+                .count();
+            let frequency = ones as f64 / samples.len() as f64;
+            assert!((frequency - 0.5).abs() < 0.2, "bit {}", bit);
+        }
+        let mean = samples
+            .iter()
+            .map(|sample| sample[XOF_BYTES - 1] as f64)
+            .sum::<f64>()
+            / samples.len() as f64;
+        assert!((mean - 127.5).abs() < 24.0);
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 95];
+        let mut opad = vec![0x5cu8; 95];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,1168 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 192 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    state[0] += round_constants::C[step % NUM_HASH_ROUNDS];
+    state[1] += round_constants::D[step % NUM_HASH_ROUNDS];
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j]
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x369b34725ae4d451,
+                    0x2bb329c7043e8cee,
+                    0xa5ba20b95c9686aa,
+                    0x38d5bdb8299ceb3b,
+                    0xc4607752954b98e4,
+                    0xa82ff65846a60176,
+                    0xd07152e744dc1011,
+                    0xb1567c2c457d8c44,
+                    0x725841dd938c0862,
+                    0x1cfff796a6d8aba5,
+                    0x615ffcf3deaefdd7,
+                    0x00d09426f03db8d7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcb31101b438cdd19,
+                    0x3a9b10b1965d97fd,
+                    0xec16a1bf2c4449f7,
+                    0x490cbee3c80a9d9d,
+                    0x00d2b3a281f162c3,
+                    0x5a751dcddf1aee00,
+                    0x0cecf25be7408095,
+                    0x0f6b2fac96b4aaa7,
+                    0xa0d67cac971ebf46,
+                    0xabb6711bd691f6ef,
+                    0xda3dca6c938567d1,
+                    0x00b880e605d96e7b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x38d7d7cf6fd03e0a,
+                    0xf4e181e2910fe534,
+                    0x8c4e84ef7b4ab87b,
+                    0x8a54e4eedae5bac4,
+                    0x029198fe8474c716,
+                    0x1960cae2fd873fb0,
+                    0x36b98d98d3863406,
+                    0x35f5c2f779d8bfd6,
+                    0xbd8a3868bf637a25,
+                    0x660e0c6c05555522,
+                    0xd6fa692fa9faaa1c,
+                    0x0033b6e21d88d2c8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6aef16254776d01a,
+                    0xc622edb0b5cf5bac,
+                    0xcd626c1f2b9b81a4,
+                    0x38dd4ac376f81729,
+                    0x5f92fd8ce8a1e967,
+                    0xd753b04e6f0a4386,
+                    0x30689c66ebcea9e9,
+                    0x8084626e12f5b3a3,
+                    0x34b071d616181c5e,
+                    0xabbb43c1300c70e0,
+                    0xafb25b80f33d642b,
+                    0x007335a0accf9b2a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3312764d3a6f20e4,
+                    0xe0bdc00ae677e326,
+                    0x8493a70ce9682895,
+                    0xfd9ee10fe195ef60,
+                    0x8714edf315e04d25,
+                    0xc7e81b7934411bbb,
+                    0xef8e3b5b481dade7,
+                    0x3ff175c5b98eec04,
+                    0x24b8c9d7df3af937,
+                    0x155797c2c9732bb4,
+                    0x21b2501cb326163b,
+                    0x00b2224038b7257e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9a0a8f7c7deacc6b,
+                    0x7181d5f5ff01311e,
+                    0xf196700e62e8f3b0,
+                    0x1628a54f3880226d,
+                    0x8cc5c1aefc1e1d37,
+                    0x4daea243a226616f,
+                    0x4d6bc96602988c2c,
+                    0x7e750613a458fbc6,
+                    0x8f4603d58b314de8,
+                    0xba4d8c2f0bcac723,
+                    0xbe5da2f77acaf7b4,
+                    0x0004442940f3a5dd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9d873e15e3bc1f6c,
+                    0x4a3b53ead299432a,
+                    0x19d6fd266c3d6301,
+                    0xa717c126f1d9065e,
+                    0xa0d6a8458c5710b2,
+                    0xdac5ea1ecf62d55e,
+                    0x495a00f84b27589f,
+                    0x521465c1391c95f8,
+                    0x5f69099f7722d226,
+                    0x2d651652a06f3e33,
+                    0xca2d1889babd974b,
+                    0x00341a01871e6dd1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3f41de60607f37da,
+                    0xea68b9080a2d7260,
+                    0x32a96330e358e2df,
+                    0x055b35eb68ab1379,
+                    0x3c84d88e9edc77c9,
+                    0x38d94f6cbb011be4,
+                    0x079d1ec974e5a4c7,
+                    0x42c2b5cf631f9084,
+                    0xd4caea030e8b86a0,
+                    0x39683f10ff1161f6,
+                    0x8ec24a50d27c1ead,
+                    0x0034b824d41b6af3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x941b1dfe43c4431b,
+                    0x844eb773e763a7f4,
+                    0xb9aba2efc3939c90,
+                    0xcba818c082a466be,
+                    0xf7e77c56087eb9c9,
+                    0x619cd716730988fb,
+                    0x3c5bf44e9fe2225e,
+                    0xc3bad0b1df7d734f,
+                    0x5eaf9aa01de48baa,
+                    0xde1e1c0472f68d79,
+                    0xd12d3a985f5d1568,
+                    0x0062d26f0733207c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9927275d500c7df8,
+                    0x6d46864f1e3dd54b,
+                    0x63850d925fe52e99,
+                    0x9762899b129b4e87,
+                    0x794f9ce5e7e5732d,
+                    0xc64554c8c08fd704,
+                    0x121dc549739d8fa2,
+                    0xa13ae1aa47e86aa0,
+                    0xa99d89a507a06ece,
+                    0x886dd22688495f27,
+                    0x0ebc2e686211a14a,
+                    0x00661d57134762c4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x13f991f23538c029,
+                    0x4c05cbd96d385548,
+                    0xa0c47d1a810270eb,
+                    0x61337fc87cd6c555,
+                    0x9493dd12bbea39e9,
+                    0x323d5242746018b8,
+                    0xe9761eb02d4ff284,
+                    0xb7b3b1fa44b30633,
+                    0xdc4ceb28546a5440,
+                    0x64bf170f14078ac5,
+                    0x2e4b596c0f35e3fd,
+                    0x00668c9e7d690c99,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51352f9133525dae,
+                    0xc5265d79b4a96e1d,
+                    0xb45aa1bad45bdfda,
+                    0xcddbbcb25418f886,
+                    0xa8dcd8c846e7db1b,
+                    0x90f561abfd7b62e1,
+                    0x40fe871440b156cc,
+                    0xc33f61c6a8a648bb,
+                    0xc5e2b90ceaa0fd4c,
+                    0x5a5bbd4160e144ec,
+                    0x600b20369ade3bf4,
+                    0x009c5f8248f11e79,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2246c1144d1bb03b,
+                    0x1f61d6ccc4dc14b8,
+                    0x5e92cff14a4b8bd5,
+                    0x46bbd71bd2557baa,
+                    0x4fa63e76a2c8a9ca,
+                    0x4627fff336981007,
+                    0xa8815bc7b39e58be,
+                    0x8db600e32d0a1ebe,
+                    0x3187420ea3ff8d65,
+                    0x65275f1ed38d6526,
+                    0xd288109b07df9bbe,
+                    0x0109641aafda70af,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b12000a4d596f58,
+                    0xa746327f7abbccde,
+                    0x86101363b1df5f68,
+                    0xa55698e18929c2eb,
+                    0xc79202d3f871409c,
+                    0xe12a36a6462f01ff,
+                    0xfafc2c4ac0608575,
+                    0xc6af3d1f63768351,
+                    0xf2c76287abe9c20a,
+                    0x3da7aee7612ed5a4,
+                    0x17721f20de488d62,
+                    0x00c907afdb8e7908,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2bdac4c28ffd57fd,
+                    0xd9436dfde69d9e24,
+                    0x5a5f10d034d1b078,
+                    0x037dc743eb1c07bd,
+                    0x53bbfc5cd67e3570,
+                    0xfee6095c4655f51d,
+                    0xd2631a22d3b89cb8,
+                    0x7f862a218d6e14ae,
+                    0xf8173e89e350b9f5,
+                    0xb927b5db340ef662,
+                    0xc3ed0240a90d8a1c,
+                    0x00e27d7812db8adc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee1769424353a71e,
+                    0x0346d86a7f0ac2cf,
+                    0xbd1e53ce1975d8d8,
+                    0xdbe74a138a36b514,
+                    0x05832680d792478a,
+                    0x626abc8317afd665,
+                    0xec4f20008c9fa0e5,
+                    0xfbd743e00f3e2c5a,
+                    0x65659ec913163dd4,
+                    0xcf7454253df5e756,
+                    0x45b5a110eb11a23d,
+                    0x00847ef70896a1b8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xfc7459e13913b924,
+                    0xdc4744c66b844c07,
+                    0xb60f047770fdd42f,
+                    0x6ffb01806691af40,
+                    0x29ec14bdbd428e8b,
+                    0x2c64f20fca137d95,
+                    0x0b3faed807fc5f29,
+                    0xfbba80d00b85905a,
+                    0xde3696fef08a2f49,
+                    0xe4e2be68ce0fcd84,
+                    0x577a7bfdebe49064,
+                    0x003e8c17d5b3039c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7cb77a5fd2173702,
+                    0x29c26c5eb8db47f2,
+                    0xa205e5b973b586d7,
+                    0xa65b0ff4dbc14c17,
+                    0x3654248053432602,
+                    0xb6a7e2ca81103c04,
+                    0xfaa6a4322ac0955c,
+                    0xa5a538be1738588c,
+                    0x12b83054b3de8cea,
+                    0x96f9817e3c9d1cca,
+                    0x02a82aa4f97081c9,
+                    0x003a0033a04f2f4d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x72868e77be07c091,
+                    0x6687540884da2bfe,
+                    0xd118577d2bee28a9,
+                    0xefd40dc5f35e7608,
+                    0x9db7cfe4c5c69271,
+                    0x78275d1d886931c0,
+                    0x739819e0c69b0d34,
+                    0x0908742c4cf1f191,
+                    0x7033d24fb6a02396,
+                    0x83a3ed00dca0d807,
+                    0x46fa4856a8b577ca,
+                    0x010cfd44041e657a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0c30d5fd0ee01dd7,
+                    0x5bca5a78fef87dca,
+                    0xc9c4d9bbc87a2f90,
+                    0x257f2ed75e86bf7b,
+                    0x0b0a8f14d7058e9b,
+                    0x3369109f668f9617,
+                    0x2f6ede65af7ed31b,
+                    0x3173303913344780,
+                    0xda1ab05c545f3be1,
+                    0x6de70dc844843bc3,
+                    0xb4564097100090e4,
+                    0x007713d3ed300223,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9566beadc5addbe4,
+                    0xb41a3054e901bfdf,
+                    0x5f25c46780db3232,
+                    0xea42ed3cd19e08b2,
+                    0xbd2db66ba0e9536c,
+                    0x5b4d9ce4e1074e92,
+                    0x27934c0e9c707e30,
+                    0x7f3efa042b185c44,
+                    0x2094dd14b994c724,
+                    0xa12bc767b1a8fc9d,
+                    0x6c0081a986ee06cd,
+                    0x00d4f189295c6a19,
+                ])),
+                Felt::new(BigInteger768([
+                    0x081e3c1587cd43ed,
+                    0xb8199c24151f0d80,
+                    0xe71984b5eff73013,
+                    0x997af263f574a394,
+                    0x721b0c5bb7b7121b,
+                    0xc9563e35b6a1395e,
+                    0xd80933e09bdd2a46,
+                    0x0528de08a68a58c9,
+                    0xa1d31412b9d52d7c,
+                    0x7237ce24d0135f7a,
+                    0xd5d4eaf91b30d35b,
+                    0x00e3cf8a5d329568,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xec47967ff5fd5cf8,
+                    0x9fadb48e1f925fc4,
+                    0xca6a35098f052b09,
+                    0x09a94a4f27e8584e,
+                    0x44e3cdf07f112252,
+                    0x40fb6914860fe982,
+                    0x3120be1ee62af9e7,
+                    0xb4834023557554c6,
+                    0xc090780b51a36972,
+                    0xfd86e4915d7037c6,
+                    0x1591a228a5af0cba,
+                    0x00352cf60fa2fee2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x37eff3b0120d2d38,
+                    0x33ff9cc5c8708d75,
+                    0x9712788507443e18,
+                    0xe53d27ebc86bd810,
+                    0x091546d556d7b1cd,
+                    0xb1cf8b62c0fa0b37,
+                    0x65c7d44ed83f75d3,
+                    0x99a63b2b1f34f570,
+                    0x704ba21e67a77e80,
+                    0xd7bc823bdac73437,
+                    0xa675a3d3cd5d93ff,
+                    0x003da825f151d0d2,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0xd422b891c7cc33f2,
+                    0xae8ef752715cdfd3,
+                    0x026d9a61cdebf34d,
+                    0x3b1ec68e4f7f0824,
+                    0xefe9662958f2b5d2,
+                    0x1f16925f2c952957,
+                    0x613f012abfc66f70,
+                    0x38b1aa552a6846f9,
+                    0xdc8f5180aa6e456d,
+                    0xfeb1a86d8559ae09,
+                    0x470d482d01046af1,
+                    0x00414f9f1979f7e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x838c734eec0ec238,
+                    0xdfe739f0f33b8de7,
+                    0xa537b2e3bab5b67d,
+                    0xf88c1b68d1ce8aea,
+                    0x0172ffe7ad52250e,
+                    0xaa02ab2be8579253,
+                    0x323fc22872dd81bd,
+                    0x462b93fcc099bdc1,
+                    0xb61923279f709d8b,
+                    0xafbf1bc5f5d57259,
+                    0x3f685d558bef41dc,
+                    0x00b0be8cc51c8f61,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc75a63e2e74aa586,
+                    0x8f40b1dd074d791b,
+                    0xa57011502526c5f4,
+                    0xd4bce0b57e44d775,
+                    0x3625dfa4c77b4c9e,
+                    0x30821ae91292d8f3,
+                    0xd5922290aecf8d32,
+                    0x8c65935f277e740c,
+                    0x213b03414a75d82b,
+                    0x2e6a65b25299d610,
+                    0x792bbe28a8365ff7,
+                    0x009c10a107b1b7dc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa516b7bcf7f3a798,
+                    0x3f3b0f675ddc59d5,
+                    0x37c8980070d49bcc,
+                    0xa2e44f434384597e,
+                    0x4f8a6fc382bf969f,
+                    0x4bfe9b9e36117123,
+                    0x9a863d4ca86438d5,
+                    0x19aeaf9ce7a902f2,
+                    0x55b6ac039fee5290,
+                    0x8a1a22d4564efecf,
+                    0x25dbb2ee24b94cbf,
+                    0x009c0876923f605b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x66dec3bd44801263,
+                    0xc984ae9382971d0b,
+                    0x54e19af9c69553cc,
+                    0x5b22e05bdf0ac93e,
+                    0x76979e602ca15c03,
+                    0xaa1938850efedb78,
+                    0xb4af9a022d75c7a1,
+                    0x96af0c65f68e91d4,
+                    0xe0321f6538a06868,
+                    0x5fe9dcfafbfcc64f,
+                    0x04b07edeb3c68f4f,
+                    0x01188a8c4c99414b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7253ea9cd3eca0c9,
+                    0xe0c34fdda745ca83,
+                    0xf74e4291a163b626,
+                    0xea1b1fdc8a036196,
+                    0x9380ab5e5707d3d8,
+                    0x557cd7025de15f13,
+                    0x920ff2b792298baf,
+                    0xbc67133b62444ad6,
+                    0x7cb15f7430df5b44,
+                    0xd7c674c1c6165ce2,
+                    0x35d0976ac0a2ebf7,
+                    0x000fac4d7139b0c0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3a153b09a072dbef,
+                    0x52301161bebbbf93,
+                    0x580380ddf2092d0c,
+                    0x23d72ab92771435f,
+                    0xbff3d9e2c05c7ded,
+                    0x4af045527ed4a415,
+                    0xb4d9966a08f1b0cf,
+                    0x011a24f5d03f3670,
+                    0x87e6b3ad4e751ff3,
+                    0xb9be82a0c31a419d,
+                    0xf5d3b23242a6593e,
+                    0x00b27eacf3f5d379,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ebd272f8b17881a,
+                    0xc4efec50eed163d1,
+                    0xecd94f2d66009217,
+                    0x7d5e8b563df81b1e,
+                    0x12a1d4d9c31d81f1,
+                    0xb6a9c4f2435f22b9,
+                    0xe07ff3d9bf26fbee,
+                    0xd4b839a67147f57f,
+                    0x065a4313aee30db1,
+                    0xf938c18de2b98d08,
+                    0x6942aa12eefb57f4,
+                    0x0035affaf1e81336,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x50033d25f1775d68,
+                    0xbe39e4616f3cba70,
+                    0x01d9c53607c41e31,
+                    0xc4b3a8f578f8b539,
+                    0x905dc999a5f1c136,
+                    0xb8c8bfe3c3076a85,
+                    0x040e358d4cfa55e9,
+                    0x90b6734d130009d8,
+                    0x5a08b54e3ef1ac55,
+                    0x5b983cb97031d2e8,
+                    0x73bd2e176daa1555,
+                    0x004dfe9a30374ed4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1dfeb26a84ad4ea0,
+                    0xb4b5411d534ff26a,
+                    0x25ded9b39536dc68,
+                    0x73f7dde11ca165ed,
+                    0x6671c1e26e3b513a,
+                    0x127fa5c81fbd3006,
+                    0x6dff13dd63c7b5df,
+                    0xbac55b5248e173e9,
+                    0x55084ea7efb2c7dd,
+                    0x529b2a6b3fdee609,
+                    0x0884b93a70902f80,
+                    0x00a1238675844292,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x64246438c9148b4e,
+                    0x286f6cc54b6006dc,
+                    0xa2c1dce39df4a0fd,
+                    0x9de576e1efb7ee65,
+                    0x03bf74193391e98e,
+                    0x8f63d3a0daa3cd7a,
+                    0x7a9b031f74f18d4f,
+                    0x23efeabb6cd54240,
+                    0x0554e2319dbc0c52,
+                    0x0678286df0fca23c,
+                    0xc5a8d2f237cb10b0,
+                    0x009407e9f71dc919,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6c058c61c06f4f85,
+                    0xe5583dc454f313f8,
+                    0x75ef33a2b3c51f06,
+                    0x467bb034de38e1d6,
+                    0x0bd9f5025f259321,
+                    0x7283900e9f0d34c2,
+                    0xcbab1918c9dabe8c,
+                    0x6b256653f1fc825d,
+                    0xbd01a92c26a8173e,
+                    0xdc1188e5a6074470,
+                    0x6e10000682948c7d,
+                    0x003fd70000bd6df8,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1571fffffffd9c9e,
+                    0xdd2780a35ffdc000,
+                    0x02f22ea2a18db21f,
+                    0xab75e537f40ecccf,
+                    0x2eb4245a1ffb990c,
+                    0x3df63d3e886eb6ab,
+                    0x40b581c029adfa18,
+                    0x4022e1be7966bbf9,
+                    0x162d4490aaf12c2b,
+                    0xa2709b890183c465,
+                    0x64c0d06a1801521d,
+                    0x0076ed55ba43bc6a,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe69e9f2f9fe9b7d7,
+                    0x87cc2ccbe7d3fb1f,
+                    0x36d0077a5866bc11,
+                    0x9395e69d9cb47501,
+                    0x80f2921c3fab00c1,
+                    0xec921fc9ea4a506d,
+                    0xc1efca7c7fcd4fe6,
+                    0x548d2c15c616d962,
+                    0xf63f21e068c7c242,
+                    0xa509c78eef334c34,
+                    0xf456399813e7ef6c,
+                    0x007fe493a82f48a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5c2cb1ae2be2315b,
+                    0x08ee552052e383a4,
+                    0xfccac92980928a69,
+                    0x8716d1e1b4cfcbf4,
+                    0x917b5043b8bc2763,
+                    0xfc9d21d2a9f2359d,
+                    0xb2509c224cc3fe86,
+                    0x7eca45ef6742876d,
+                    0x5014f0f8f07863ce,
+                    0x40ac9279526ac43b,
+                    0x568d0745aec42177,
+                    0x008d9f8f19f75298,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1cead35cd731f42b,
+                    0x2725924353062c44,
+                    0xfef448a21bdf5955,
+                    0x81e46879aee5e179,
+                    0x635deb4f590e7aaf,
+                    0x41f589386bbbbda9,
+                    0x06cfe22ad9e3dbd8,
+                    0x4f474c60114b90d9,
+                    0x7a25e55909cabf0a,
+                    0x897892f07d668d26,
+                    0xf35b5ac4ecadfa37,
+                    0x00b1396930acaa87,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea4f5e76a6578f63,
+                    0xa6f4f58593e8b1da,
+                    0x1fa43095bda2aa3e,
+                    0x0e0c09744ae8737a,
+                    0xa4697285c0f08cd0,
+                    0x495fe521fa8eeee5,
+                    0xa45746a33677cd81,
+                    0x47c1a22424bb3b8b,
+                    0xf78000c632fc1263,
+                    0xe3e5304acf4ad293,
+                    0x3b0a9f37f91a41ef,
+                    0x00db9323f814e760,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x56e998f6ec59536a,
+                    0xa47a0fe66122b18f,
+                    0x2d71276e1e6c1be2,
+                    0x96b809529ca9e374,
+                    0x2bbc214066c50486,
+                    0xce891d9cb7c79c0f,
+                    0xd500c4722c14bbfb,
+                    0x9f018ca3d5923e68,
+                    0x8712685e19d760b0,
+                    0x5650ae140658398c,
+                    0x9ec9e47430116800,
+                    0x0014fb023388d4c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x20271c8aac9f479d,
+                    0x29b76faa698b2da2,
+                    0x5230916dde3bedec,
+                    0x178b3281c357287f,
+                    0xeaf8eddf2491dce6,
+                    0xf28f123bcd709731,
+                    0x3c117b9bea5303a6,
+                    0xfa6a2c830d68c7a8,
+                    0x8ad63030648e1ca5,
+                    0x8467d0e9d2c6cffb,
+                    0x7364605320c5bbf8,
+                    0x0039a251d84b5a41,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb78f8968b6a1ec23,
+                    0xdc0fea039c5e8735,
+                    0x31b61f38be0a513b,
+                    0x1e944165a361799d,
+                    0xe5378396469781d0,
+                    0xb843cf370592e987,
+                    0x75d97e1d873fa8ac,
+                    0xaa8a9842b2cf2170,
+                    0x949b39d4ac3b3b56,
+                    0xac3005bc888d5bad,
+                    0xc8590658209d0928,
+                    0x011ddea2d7c5f9e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4a23a00f85b5f4a,
+                    0xafed4387478e7136,
+                    0x242b9c410c33ec1f,
+                    0x8944e09cd7ebbc69,
+                    0xf957344d68748734,
+                    0x1a1dd1862890faa7,
+                    0xc4957a16823e073f,
+                    0x48d61dba0bdc662e,
+                    0x8a8bcade064a07dc,
+                    0xdf4c9c31f031b752,
+                    0x56e52443263f6bc8,
+                    0x002b9cf6aa6c6aee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9763a1fafad1fa1d,
+                    0x41132833a5dc9ec1,
+                    0x378a7fee474132cb,
+                    0x14024df55bd3d81b,
+                    0xeb6479820e7c647d,
+                    0x573e4286ef87cd01,
+                    0xdc3da248eed59ea3,
+                    0x95c583b8bf3e0891,
+                    0xb196dcae9dcf7dcf,
+                    0x47a879256e1e5872,
+                    0xb33ed74c49cf7517,
+                    0x006d5d821fbc05ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5828f6607a51424f,
+                    0x504a531c2f092f6a,
+                    0x7ee6e0e138c89dc7,
+                    0x035b63097de16d2b,
+                    0xcb5de10a17481b06,
+                    0x3a7261e8ebd2cc79,
+                    0x22ab9d701bbed021,
+                    0x75d4bc8ae1d89bf4,
+                    0x65b39215aaca053b,
+                    0x28c6044b9a4a5066,
+                    0x9d7a9e92ff341a70,
+                    0x0058f665b9788062,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3c2f7cfc49f32a58,
+                    0xf31fe84df5462ecd,
+                    0x8ea04429057edf0a,
+                    0x2adcd74bac29b212,
+                    0x1b735e1df1dd0fd1,
+                    0x746af3be18be36fe,
+                    0x11f1355108a70a68,
+                    0xfa3ab76350ce46fc,
+                    0x7f583489eb0c3ace,
+                    0xbe9b3a393d0b2b1d,
+                    0xa1c8d2ff3cf429ab,
+                    0x0113b5e9f898a50a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb2a865a5455a31f,
+                    0xfe75918f5f7f708c,
+                    0x6715ca96e8e194ac,
+                    0x6af3314789bcf40b,
+                    0x5f0709855b07b466,
+                    0x4e45e5b0aa95a79d,
+                    0xe7f00dbc8fc08d54,
+                    0x7ea388a8c88f3e23,
+                    0x16ad2660fbb11059,
+                    0xe6fbcc831c7b0d9b,
+                    0x0e921384f286e157,
+                    0x00217289fae71bf8,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,545 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger768;
+use super::Felt;
+use super::NUM_HASH_ROUNDS;
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [Felt; NUM_HASH_ROUNDS] = [
+    Felt::new(BigInteger768([
+        0xaae0ffffffefc167,
+        0xe499e1712ff0b213,
+        0x49ebe6d1127eeb90,
+        0xa48c17d0f0b3c9c4,
+        0x76498b7fda7478ef,
+        0xe3c1b33942cadf81,
+        0x160b7082fbac579a,
+        0xbca9f7c54096309b,
+        0x7ae19b95a2d6f02f,
+        0xb94385861fe6cf83,
+        0x468e01aafead9672,
+        0x00a8b58f2faaa255,
+    ])),
+    Felt::new(BigInteger768([
+        0xd9fdc16fc7337108,
+        0x2bdecc643f94d863,
+        0x050eb307c69559b4,
+        0x1b1b4c4a84c1c2ff,
+        0x9e739df3ed5be7f4,
+        0x26bf184b252f0902,
+        0xa8846c1670f7859d,
+        0x86de6a5cfe75cf28,
+        0x82d6422754a34400,
+        0xe848c14ccdcf9d1b,
+        0x2a53b6eb1e9f47fa,
+        0x00c815a73e2b0ac3,
+    ])),
+    Felt::new(BigInteger768([
+        0x790a228eb18f81ed,
+        0xc5cc55dcc163b1d6,
+        0x611360bad7f25451,
+        0x08f564b96198b6af,
+        0xadb91803b2eff750,
+        0xfb494673a466ba88,
+        0x2f699016985006e3,
+        0x3198ed23c4cc953b,
+        0xe671e8184de1bb36,
+        0x471ccc05413f6984,
+        0x0d9941911cf496bb,
+        0x006f6dbc446003ee,
+    ])),
+    Felt::new(BigInteger768([
+        0xf5c0c593afc99597,
+        0xea14576c69d253a5,
+        0x1630d229aa9fb72e,
+        0xab8b1554e6fac3b8,
+        0x442620bd39a8906a,
+        0x6e4ca2142b847138,
+        0xd8d8653450fd7a5b,
+        0x673ec576e3e83111,
+        0x72101f77c9bf0195,
+        0x5b9578f2f475ea2f,
+        0x089f830f8ce2d7b9,
+        0x000c93212d33a0f8,
+    ])),
+    Felt::new(BigInteger768([
+        0x9dcce12fc3b230e2,
+        0x482327e32463c42e,
+        0xb0ee98c5401da783,
+        0xa63bd340162fdfdb,
+        0x7a1deba7262a02aa,
+        0xa9dece19ce4edd5a,
+        0x8bbfec540a6c8a20,
+        0x3a9cf3b931fcbd42,
+        0x247d75213e7949fa,
+        0x8ffaf3c8e3096319,
+        0xbfef5223056ac774,
+        0x00bebf400fcc5b30,
+    ])),
+    Felt::new(BigInteger768([
+        0x84de48ada811211c,
+        0x62d41b2e34bf5732,
+        0x752cc0aeeddde33a,
+        0x35f558ab68ae90a9,
+        0x8365e1f81965ea09,
+        0x5c0936dd959ea0e4,
+        0x5e3c827c2a6640b4,
+        0x7ac92d089c400ef4,
+        0xd42b15d1aaf63e8d,
+        0xd3d0a0db57e67772,
+        0xfdb7189efd805687,
+        0x002efa6deec9da0b,
+    ])),
+    Felt::new(BigInteger768([
+        0x54428e1d92d16362,
+        0x496b58bb84409140,
+        0x2014031d06bb1b88,
+        0xffe968ac046b9882,
+        0x1ecef239cac8a2a0,
+        0xe28331115412a7eb,
+        0x8f2e1f6435e6f2c8,
+        0xeb3373a022dc7924,
+        0x62dd8c5fe862b2de,
+        0x50b563787e138444,
+        0x252f1e3e85733abc,
+        0x008cb31e9b873b5a,
+    ])),
+    Felt::new(BigInteger768([
+        0x2a643f949ca6b0fa,
+        0xa12b3a449dbd0f89,
+        0x703acbcaab89447f,
+        0xf62588d4f50232a5,
+        0x7cb210affcea57ea,
+        0xdbb684c9e6d55aa9,
+        0x41f31182e6ff2e9c,
+        0x6036ce06c1691370,
+        0xcb93d3ab99d0dcde,
+        0x7fcff5d16012e67a,
+        0x47e2c320e94e83fc,
+        0x0031bf6d9aa59a76,
+    ])),
+    Felt::new(BigInteger768([
+        0x23b69f3ce571eb9d,
+        0xcc94ec6cebfc6af9,
+        0x395a4784850e55a7,
+        0xa531d84144eb2d47,
+        0x10597d0d8e8743a0,
+        0x12cd36ba1ddde408,
+        0x2c7f462200750349,
+        0x90d85e936e11b44b,
+        0xd6bc6cfe0d09d937,
+        0x1de368948b17027e,
+        0x2978b876164bcba7,
+        0x006aac39e754ef75,
+    ])),
+    Felt::new(BigInteger768([
+        0x054d4fb1836d76e9,
+        0x804114596826f73f,
+        0xc639c96b279e26c4,
+        0x33bd8b669b573f91,
+        0x3fd05d25c145ddd9,
+        0x685b06fd66cb9682,
+        0x3fa3971270e16e6c,
+        0x02dffee42a8997ed,
+        0xe08dffa8c10bba27,
+        0x200599ff5c918c38,
+        0x9a888595a3f4f1a7,
+        0x00d8c801fa610469,
+    ])),
+    Felt::new(BigInteger768([
+        0x701845e4b61296fc,
+        0xecdcfba854f5c929,
+        0xd0ce4970f0234193,
+        0xdc2d5b3178e857c4,
+        0x43d506a964d93634,
+        0x7b3f1f562fedd427,
+        0xfabdc13c4fc24b1b,
+        0xff08a63ba30bb610,
+        0x59c331393b195f50,
+        0xf0cd74f3c1a77dd6,
+        0xfcce02ba4c53bb39,
+        0x00d3c8352dada596,
+    ])),
+    Felt::new(BigInteger768([
+        0x069e7574ba98912e,
+        0xfa83c662abb9acaf,
+        0x1f2003522ec74257,
+        0x469329b0e22058d2,
+        0xb559fa705cb76ce6,
+        0xaa63e2c05a003c3f,
+        0x1b465d1b9ed5c241,
+        0xf4ee69edad46ed24,
+        0x7532cc2b34fec0af,
+        0xf2d40658e9176c6a,
+        0x401dc5c5898b2ddc,
+        0x01100a4d20ab30bd,
+    ])),
+    Felt::new(BigInteger768([
+        0xc324ffd73211b077,
+        0x41ba020503c5afd0,
+        0xc25d81b57bc3000c,
+        0xff919483362c85fa,
+        0x368b0cad4fccd6d6,
+        0x4827b956f9e1b1de,
+        0x7e3cf33ef3c08f38,
+        0xfc9b35e07344c29b,
+        0x135bab6d577c07f5,
+        0x9ea561f5f43a797f,
+        0x86ad42844738647f,
+        0x00aeaca69d386a09,
+    ])),
+    Felt::new(BigInteger768([
+        0xdcebd61bb1ab35ed,
+        0xae96ee82263cc5d0,
+        0x395d57cf159ba2ef,
+        0x8d2845ced0a47a42,
+        0xe258971f35f2d4e3,
+        0x1ae11bcffe30cc02,
+        0xe8536a4b3fb30a7e,
+        0xc658fd04daeb315a,
+        0x8754407e2b6eafd3,
+        0xecd299d6c84b3d71,
+        0xceb14af8f51741da,
+        0x00731af14971cbe8,
+    ])),
+    Felt::new(BigInteger768([
+        0xe4f780380530da53,
+        0x0734d79746bed600,
+        0xb2fbf70c5dcc6c2a,
+        0x73a4f238722863ea,
+        0x249e5b4439f6f581,
+        0x40cbf077ce034034,
+        0x63a09f25dcaa5cbb,
+        0x948ef30429b78f9f,
+        0x6f45806e3b86edfb,
+        0x99ab7ba8c032436f,
+        0xbe66445ed2050645,
+        0x010e01c5d9fdaf13,
+    ])),
+    Felt::new(BigInteger768([
+        0x190bd1c481d72bba,
+        0xd764698d3df9f088,
+        0x21c9ef0a8d75b040,
+        0x3a61238b6971c700,
+        0xeb29155411edaa04,
+        0x431f04ced7733120,
+        0xb7ccf3d958a14841,
+        0xd8ba156da802d781,
+        0x7e558bf3a98071cc,
+        0x46a94558fb776358,
+        0x3f52332990f5d831,
+        0x00fc343bb45ac36c,
+    ])),
+    Felt::new(BigInteger768([
+        0xb0c8a032967c2fc1,
+        0x518353bfe6a2d3d9,
+        0x71aaa2ff7069376c,
+        0x97b0774e64b98a2f,
+        0xeb284d1e31f6a733,
+        0x29f1c0de2910d6a5,
+        0x2ac5697b77ce3133,
+        0xa30ebbdc94d17fb3,
+        0x31e228d8399cd69b,
+        0xd4213899e8b6a42e,
+        0x934f9a73031bec1e,
+        0x0064b26576239330,
+    ])),
+    Felt::new(BigInteger768([
+        0xffe0f5d3df90565f,
+        0x5aef821df6b7d7e3,
+        0x18134ad0c2f6ef90,
+        0x905b70ad18ad5e39,
+        0xc4af96fdb311bfa2,
+        0x1f60c9bb4fb75764,
+        0xe7a595fd3d9cb504,
+        0xbcb23a5c503a29ea,
+        0xea48cf6b9e990845,
+        0x29f168d9b49db204,
+        0xa997e7bafb036508,
+        0x00a62dbc7d5aca3a,
+    ])),
+    Felt::new(BigInteger768([
+        0xa0ac7baef48f02ab,
+        0x3ad91658098d7f3a,
+        0xd773320ba477fff4,
+        0xb8060135bda87d02,
+        0xd27f5b5a5098e0f9,
+        0xf70a0f48584bda90,
+        0x79900dc15d4d32b8,
+        0x692004d23475c5e5,
+        0x9842e896372c2d4a,
+        0x898a750ea67cc51d,
+        0x30474fd9ee9bb454,
+        0x00c4d900e0a804c9,
+    ])),
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [Felt; NUM_HASH_ROUNDS] = [
+    Felt::new(BigInteger768([
+        0xb193ffffffef840c,
+        0x1e7d5557bff07845,
+        0x8e32219b5e466ee4,
+        0x45710c08ccf23442,
+        0x33d00730af48d55e,
+        0x80f00293e271f2bf,
+        0x67cae5e35728e29b,
+        0x161598a7f0eb4c90,
+        0x0f4b8cdde6ebf54d,
+        0xff72be82d286ad57,
+        0xc99e2c68cbe2ebc2,
+        0x00403d3c2a7caf8e,
+    ])),
+    Felt::new(BigInteger768([
+        0xdbbd1e24ca6628f1,
+        0x4cb73fe87174abc9,
+        0x4b8133813e3118ad,
+        0x04c4bd6d65567ee4,
+        0x19bc660995f1505a,
+        0xc12a6a2f1f69e9e2,
+        0x2390ea56ee70488f,
+        0x7a85417a1d245d23,
+        0xc969e11e9531c619,
+        0x108bdc396f35bb47,
+        0x16ddecb28b336684,
+        0x011df9c25c90a567,
+    ])),
+    Felt::new(BigInteger768([
+        0xbee3f32bb2b14db9,
+        0xac2db8201ebf59b0,
+        0xc6b3c6f2c6f8f2e0,
+        0xafa307796c242dc7,
+        0x23cded6e1a534bbb,
+        0x5bef14063159bc5a,
+        0xd983c8b39cb8fa80,
+        0xa7ad4e5a91924a59,
+        0xd0dea2988cf88e27,
+        0x08e915f0d2893891,
+        0xf269bdb12dc5711b,
+        0x00f91dee042a2bbf,
+    ])),
+    Felt::new(BigInteger768([
+        0x41a01cd5122e50fa,
+        0x97bb62e703908e31,
+        0x4e239f7c2e777b06,
+        0xdfc02325a6109280,
+        0x937b66a78ca7c7ec,
+        0x34dc4df236747754,
+        0xbd133402465c494a,
+        0x0dcfa6e66d92c1a0,
+        0x6546e7cd9d647dd8,
+        0xce06bb869be192d3,
+        0xff2ccb0e4e932f1d,
+        0x009ad8bda1e12fde,
+    ])),
+    Felt::new(BigInteger768([
+        0xd2449d72cbe356fe,
+        0xde885cbd79cd41a0,
+        0x8fc35ddf7e1241ca,
+        0x99461e76687f1561,
+        0xe66a7dacc264a951,
+        0xc98d3e4401daf3e9,
+        0x4fff9015ae693c06,
+        0xffc23d8fdfef1bc7,
+        0x79e40fd5ffd5591c,
+        0xadbdc4ef2ada34e0,
+        0x6f0410bc841bf50c,
+        0x0072e8157f34d108,
+    ])),
+    Felt::new(BigInteger768([
+        0x73b756ce26518e4b,
+        0xa48266a6aba933fe,
+        0x61b08ceb5cbeed15,
+        0xb7666c9dba693f55,
+        0x957a44ddd3525958,
+        0x9a7034922dbf05ae,
+        0xecbdcc3aad7129ba,
+        0x8c4cecf0f4aa1011,
+        0xff4b7de864aad513,
+        0x7a82f0d3dde13730,
+        0xc283c8b1b45720e2,
+        0x0117a78e620288f6,
+    ])),
+    Felt::new(BigInteger768([
+        0xba0b9c463c462f18,
+        0xbaee3370626a129b,
+        0x9801d97600c6df8b,
+        0x1a01d00975fc3238,
+        0x8be92a5e62d157c2,
+        0xba42f82a7fc2b614,
+        0x776de52b9100bf44,
+        0x3fc3b2c306e6c070,
+        0x95203299c0262178,
+        0x3ce5b2d34d656fea,
+        0x42daaeab18eb7dc0,
+        0x00c3308dfb292717,
+    ])),
+    Felt::new(BigInteger768([
+        0x93b86200f0391517,
+        0xcc7c1c697d5c964d,
+        0xe3923441faed0f5d,
+        0xd2047a952aa415f6,
+        0x10dd14c68bd21593,
+        0x2b255c9553c13b37,
+        0xe4424b186dc3a388,
+        0xdb53462481549006,
+        0xe4ab6c43ccb37491,
+        0x1d5838c05b7af1eb,
+        0x9ebe0b1c49040fc9,
+        0x00eabeb25bbb629d,
+    ])),
+    Felt::new(BigInteger768([
+        0xee331fb7de22fe18,
+        0xf5e901d4574ecc11,
+        0x0aadc65724148034,
+        0xa6e7f656318c7f92,
+        0xe71b8b137de399b7,
+        0xd3bcf6be5ed1c9ab,
+        0xd2ea5f0473290d72,
+        0xc721bceff425db64,
+        0xc44b690834cdb92b,
+        0xefb1a36fe2c38306,
+        0x5904d920eb2dd2db,
+        0x0055d158cdff1a91,
+    ])),
+    Felt::new(BigInteger768([
+        0x8733a630e79c215e,
+        0xd51848a1ba8c4393,
+        0x2fae8ca270ab3c98,
+        0x4c9a7e0d423d2000,
+        0x0cf25a77560c22a0,
+        0x67979328098c1f77,
+        0x3fc7f9e8c2c241b8,
+        0xf56224a26ed03a06,
+        0xcb79d1f33e3ec970,
+        0x59f25b56574a6be0,
+        0xf8c7257bccde4a86,
+        0x00d798579b9d781b,
+    ])),
+    Felt::new(BigInteger768([
+        0x12258d524c89c08b,
+        0x87162f2812111c61,
+        0xba780494d5a93d36,
+        0xa31d3309d603081e,
+        0x9be97f752af4cab3,
+        0x75658b657f2d121e,
+        0xf26e6ba0550a4fe0,
+        0x5713312274f2895c,
+        0x297f462cd13677bc,
+        0x355aaf2fd5d1ec1f,
+        0xcf8a791bc3f9258f,
+        0x011b011252b104b0,
+    ])),
+    Felt::new(BigInteger768([
+        0x097c6269b56fee54,
+        0x73c283fc78246418,
+        0xdcacdd2f9c9d45d4,
+        0x80f1b0c0ac38220a,
+        0xb781ea89ff4c8356,
+        0xa6773bb35daeaaf5,
+        0xd8e465ddcab418b3,
+        0x04f564ccc65a6679,
+        0x3c32622ac1f15935,
+        0x9551665084b7df04,
+        0x90cec6f71a1e0dfa,
+        0x0099c354b8dee114,
+    ])),
+    Felt::new(BigInteger768([
+        0x5c98311efae36bde,
+        0x02d3412dddf84c03,
+        0x9dae2ec2c1cfdaaf,
+        0x7395e6f73d8e7a82,
+        0x235cb1da76efada2,
+        0xb3893fe43b5a1a22,
+        0x72ca4475976cdbcd,
+        0xe3f5e34e0e647a1c,
+        0xba8c0d6b1facc42f,
+        0x8dbf6e992728bbf2,
+        0x54eb14d35905d9d9,
+        0x00c0a3ca14ceeea0,
+    ])),
+    Felt::new(BigInteger768([
+        0xd954a9d3a314b391,
+        0xd3c7f496a4fbdb6d,
+        0x8865545025b96443,
+        0x724d185eef9833f4,
+        0xe7064032c6c2997e,
+        0xd7931178e0b53e68,
+        0xcc9550cd9b8b6461,
+        0x0cc23980a6789b7f,
+        0x1b49d2482ac351e3,
+        0x5b8933ffb63b82bc,
+        0xf53ac0fce1a76905,
+        0x006fb2337c2e6b2d,
+    ])),
+    Felt::new(BigInteger768([
+        0x352fe4607e32dfeb,
+        0xfc536bea275c1769,
+        0x8859f4bc82298953,
+        0x1694e531cb749467,
+        0x48c8e8d841ab4053,
+        0x4f917586bc731113,
+        0xf07ca93a75305a25,
+        0x938715189752a7bd,
+        0xaab8eabb86928271,
+        0xa5eb052cd713c955,
+        0x9c02595b773a6082,
+        0x00cb4f2bd2ac5c19,
+    ])),
+    Felt::new(BigInteger768([
+        0x9a22fff9a8af80ad,
+        0x20db91b93fb78985,
+        0x854fc54194385eb2,
+        0x505634f2546fa9de,
+        0xd8f059168c93bef5,
+        0xe22007ad933cc8e7,
+        0x6bcfa5a69546cce6,
+        0xd4cf051be38c5dc6,
+        0x6bd34e66e14396cb,
+        0x790196f59bfc9ad2,
+        0x952d88e0a2c4e643,
+        0x0048046884aa332f,
+    ])),
+    Felt::new(BigInteger768([
+        0xb173256532a5ef36,
+        0x4f3a1ef56c8ac646,
+        0x091803ede79be0a7,
+        0x56338fd1b0536dcb,
+        0xc1202e4fe105308a,
+        0x32aff8bafb4e6678,
+        0x3815ba20e9c7952c,
+        0xb4308947b799c6fe,
+        0x884ed5db93610447,
+        0xf1e1de5a69e2ed70,
+        0x712d9746d788f336,
+        0x001eaf21ab04a898,
+    ])),
+    Felt::new(BigInteger768([
+        0x925feccb1a8245bd,
+        0xb7de4196f6ec0e68,
+        0x89f139e56c525ec0,
+        0x3b604dc50c0ba67b,
+        0xd03efa2c27c72fdd,
+        0x19f3a2e02bba93e5,
+        0x125ed3860998cb3c,
+        0x1312ccc0fd6688dd,
+        0x6e5912b33d896011,
+        0xa7090deaab37f324,
+        0xf2e04b402db2f98e,
+        0x0089607e005a74bd,
+    ])),
+    Felt::new(BigInteger768([
+        0xaee04297a80f6c89,
+        0x2c4951ec7be384a8,
+        0xbec15626a0602a35,
+        0x8305f657ccfc10de,
+        0x94a9313596c83e20,
+        0xc095ffc9c6993f8f,
+        0x85fca9ff53be4d83,
+        0xadc4f0835d746625,
+        0x2617aa8d184096f7,
+        0xa9b754d0fadb2d5a,
+        0x1b1934fe05151c45,
+        0x00c790a1c5d60092,
+    ])),
+];
@@ -0,0 +1,2664 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U3>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger768([
+                0x86372dd215bac2a1,
+                0xebc17ba3934c021f,
+                0x6048fa81ee969d0e,
+                0x15dda56950234cd7,
+                0x0fd8e42a9a1c25d9,
+                0x0ce7f7abf104abfb,
+                0xc0d348d6087c35d1,
+                0x062f0651afb39aa2,
+                0xfa7542d14037dc23,
+                0x658a75da8b6b307d,
+                0x1c3aed2707275933,
+                0x004e24fb8e5b257c,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x6cfb193a8e063ee5,
+                    0xd60b9a217557e4f7,
+                    0xea3429d5a3b586ea,
+                    0xa7888c63c1168471,
+                    0xf8c133f24639e90d,
+                    0x2279d6426eb2b8db,
+                    0x52288c4c7c2bd141,
+                    0x1afeda2fe344ccb4,
+                    0xc4f01768362d5d18,
+                    0x11779739107712a8,
+                    0x3082f3995ae03240,
+                    0x008c8854cd1c2b48,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9374513ab6c5fef,
+                    0x525e4b24dd318780,
+                    0x11f0a98f2673d115,
+                    0x84b97cedbcb746da,
+                    0x7d31d7a675ba2b8d,
+                    0xd34a8eb3da6a43d8,
+                    0x3daa9bf9a2838bd8,
+                    0xac57bf0e9f9a5e41,
+                    0x0d61fdd9b9c91818,
+                    0x06a813cec42da69e,
+                    0x7f6f2d62a6a8b065,
+                    0x00304aa1a0860623,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x27c9695e71152b37,
+                    0x94f6739dd69a57db,
+                    0x4e3f16537be4ea05,
+                    0x643b9a4b6b81c160,
+                    0x02722d3c96f14ea0,
+                    0x4ef739a96287e455,
+                    0x065761993a9dc0f6,
+                    0x14223b8082d345b5,
+                    0xaf3491f403ef4e11,
+                    0x07df576d4f4002c5,
+                    0xa35097cb2e5cadd2,
+                    0x0090727117388c78,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0147787b0bf9631,
+                    0x80e62c24f1da8cb8,
+                    0x361b55d9dbb2aea7,
+                    0xbdcc446217215a8b,
+                    0xca86c99b4fafcb42,
+                    0xbf1c8c6900db4d62,
+                    0x010720c195ec1220,
+                    0x1fa099a32bb3d0cb,
+                    0xff2c22b2647fe048,
+                    0x5ddaf7ace422ce2d,
+                    0x51d34e95a0969ad6,
+                    0x00a381b1e2c32061,
+                ])),
+                Felt::new(BigInteger768([
+                    0x63dd13195fc86a92,
+                    0x34af3c982a5185ed,
+                    0xd9483fe6e4de459e,
+                    0x1983c94964ef278c,
+                    0xb621ea552ac8d9a6,
+                    0xdfcfaf88046aab51,
+                    0x3c1d3b3e98a5ee30,
+                    0x87d2f3cdd8e0a13c,
+                    0x72d4bb9fa380228b,
+                    0x8e05fb9a5f9f6e3d,
+                    0x32d58cbe7c8f1894,
+                    0x0115aa08ca17b6a2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb3de93e5f215b6b3,
+                    0x463a1d8221446944,
+                    0x0824cad9caa50df0,
+                    0xa5ac882421af1c02,
+                    0x34c721a85ec00fb1,
+                    0xb0b62751d9eb3def,
+                    0x159d7a83e2140247,
+                    0x355a295bb5171e53,
+                    0xd94bcd2c53706c76,
+                    0xf5bfdb4b2db50c19,
+                    0x7b9a9ecb4cf4142f,
+                    0x00b79e99979f6ba8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x073ed92e90cc6dfa,
+                    0x6a672beba76c56c4,
+                    0x6bfacb7686feb6c1,
+                    0x9aafe059910a1296,
+                    0x5af131dd62fbe706,
+                    0x8d51ef6829cd8344,
+                    0x5e98907ca4b9ec2f,
+                    0x6864d562ddfe9615,
+                    0x1feaea2aece1533a,
+                    0xda18ff2acb36b149,
+                    0x39780d5864b1c5c4,
+                    0x00a25fd690d2f193,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b6b7aebb9004485,
+                    0xa517b079c885f308,
+                    0x1a2742da8f152939,
+                    0x0ecec17e4cf06565,
+                    0x4797cda1db3852c0,
+                    0x546330bcff9860fb,
+                    0x94ebec88c725ed25,
+                    0xf45c65debdbfabdb,
+                    0xf377d051ed7921ef,
+                    0x7d50339c84e6ebe0,
+                    0xeba4f6d9c52e7981,
+                    0x00225f74f0a30d22,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd950d0a350fdc4b4,
+                    0x67cfa78149aacd84,
+                    0x846676ca1d20b5ea,
+                    0x4b9cc0334f8a1b0c,
+                    0xd86ba04bb54a43bb,
+                    0x0e02524724975d65,
+                    0x47f7485ddb2941fb,
+                    0x50bbc193f06ecc21,
+                    0xbdcc912ef58bff9c,
+                    0x70f2b591de5b2183,
+                    0x022a552598ea3d1d,
+                    0x0062db90dee83e0e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x39ecd067ca435014,
+                    0xbb7afb862c447a5a,
+                    0x279050301f484944,
+                    0x3900c6ecc49fd105,
+                    0xf376817293fa79bf,
+                    0x8b1b9bca827a1eec,
+                    0xf67d27576da9b756,
+                    0x7053b14e7ca45175,
+                    0x389027fa6ac9c05d,
+                    0x4de6bc84dc868c81,
+                    0xf37b3c4a6faf91ed,
+                    0x00ed78cb5713a70c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5485fbcb4e229cd4,
+                    0x162d57e3ef80fc20,
+                    0xd1b250284fbe04e7,
+                    0x244648d3922fd352,
+                    0xf5f4cf2b1a4e0674,
+                    0x5bd84c7fda77f1d0,
+                    0x8f2215b0773165bb,
+                    0xfde3b73a1df8bd0f,
+                    0xb0d42024a2b3eabe,
+                    0x8cdc41506c72579d,
+                    0x860e0fa4f78c6315,
+                    0x009017f6c6583a89,
+                ])),
+                Felt::new(BigInteger768([
+                    0xeccdc72f4ae0ecb2,
+                    0x9ca9a1d61fa98d65,
+                    0x21497b43b0d2e09d,
+                    0x9e43d26f0d0a405e,
+                    0x6478a5a7589a923b,
+                    0xc93f267e42078fc2,
+                    0x5af0cd0fa9c6bd5c,
+                    0xbc5fbecda5c8b25a,
+                    0x9512540b9aa2d3a1,
+                    0x9145133d16995b5c,
+                    0x5bbaec620d6c1e55,
+                    0x004e267c7e204678,
+                ])),
+                Felt::new(BigInteger768([
+                    0x67e1653179be53c5,
+                    0x07b381230f66add3,
+                    0xc2cf44a16545cffd,
+                    0x473ded77ade619ed,
+                    0xa873316a0b869e2b,
+                    0xeb8fea5272c8c37e,
+                    0xd1f7340627487771,
+                    0xc9d6f13aafd887e2,
+                    0x69ee16e210507cee,
+                    0xcfe70f15699309dc,
+                    0xa8fcf73802693f18,
+                    0x0054e0dd082cebd6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x441bc9799ea8bfa4,
+                    0x48b59c35a55fafe4,
+                    0x8e703f9ff86a11dc,
+                    0xbb1f1f95bdcb3a00,
+                    0xe181beccc481ba40,
+                    0xf42dc4022410b4af,
+                    0xec77a27e012211ad,
+                    0x51db24a76c813666,
+                    0x3eff2c2095b5c8ec,
+                    0x24d0377b25d0defb,
+                    0x3aea06a017f71e12,
+                    0x0017830ad8f28617,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x9d9ffff4d1ed78e3,
+                    0x848ed43e868d3710,
+                    0xdd48e69e3f96ae9a,
+                    0x93358672a3b6d023,
+                    0x898e84f705413ca8,
+                    0xf2740fa67e71daa6,
+                    0x969fca1ae622b2ee,
+                    0xb032d18a5b948ad8,
+                    0x22f52e1e1b220044,
+                    0xa21149f3f582d1e6,
+                    0x6b77afac6178291b,
+                    0x010f417a227619d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x069b01c28b17b196,
+                    0x837fdf9304a1ad44,
+                    0x7c86f5e6bac36374,
+                    0x0e427683af607842,
+                    0x496278990420482c,
+                    0xef0902c735ce5b52,
+                    0xb6fc8f50a593cae4,
+                    0x06e0789839d19772,
+                    0x3c4ec15a894220ec,
+                    0x3d0865534ec23213,
+                    0x789568009714af3f,
+                    0x00d415957b401cd6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd42d45d1f944ac09,
+                    0x1040c23bc94ebef4,
+                    0x6fdc8204917c2048,
+                    0xcd32016bc0cdabb2,
+                    0x16e503de267031a9,
+                    0xba79ecbade14bc86,
+                    0xe1fe509c72c96aa6,
+                    0xa5c4f69098f19a0b,
+                    0xa251a652cf44babd,
+                    0xa2ccb22d6810dd2f,
+                    0x8e6bff4892bd57bd,
+                    0x005b07a7ae995bb6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x45684ea5744e751b,
+                    0xa95c3c479fdf66fe,
+                    0x94989621da27dd68,
+                    0xab0fba1a1e312793,
+                    0x7b9c512fa65c40bb,
+                    0xc5b6431fdb17ec60,
+                    0xadafd1e4c1a43c4b,
+                    0x7527fde9a758044a,
+                    0x9f19d0ae64bd608b,
+                    0x41320537ad652095,
+                    0xc3f16cf0c81abbe0,
+                    0x00414dcdaea988dd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x829805489a468bec,
+                    0xae2397d50be823eb,
+                    0x2b4a74c144917c3e,
+                    0x2e9b9a03db497adb,
+                    0x88c985abbd836802,
+                    0x2e4ce5bc278e4feb,
+                    0x24d93fd5e3eb613d,
+                    0x360f004b503c74d9,
+                    0x8de4505f9b6ee7ed,
+                    0x654750ad431cf567,
+                    0x93ba305d743c53d6,
+                    0x004a82f5a0132b66,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcfac692920353812,
+                    0x8f3e7ddf97d11a97,
+                    0x18f334bd34e172ef,
+                    0x0ed1e989c52b53c3,
+                    0x2ddd88f4748495f7,
+                    0x7b4b9087d50f2c87,
+                    0xfc7a199fcb06800f,
+                    0xd3f514f6170a71bd,
+                    0xa0e8f64969f5e430,
+                    0x6399f8728a8bddc2,
+                    0x730932add62023f0,
+                    0x0001a54aeca385ae,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0xe0b0f066b3e53d94,
+                0x96bfc8a849ca317b,
+                0xde833cc121d4af85,
+                0xae57eb6c7856c713,
+                0xa491ad41d6c3be4c,
+                0x1c8176990fa188f0,
+                0x874eaca01487b47c,
+                0xf16a849702509675,
+                0xc8be8e109782a3ec,
+                0xfa91c26c53022c4f,
+                0xccf5194c0b979980,
+                0x00f53f92c5842e42,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb1d523498e989980,
+                0x7a2069f40de23c8e,
+                0x1835a74e85ec91ae,
+                0x964bfd522ccc2cd0,
+                0xc0452ce4366c776d,
+                0x6bff85a64450b332,
+                0x2d0e765c757a00f5,
+                0x6aaeffa607651a70,
+                0xe66a65ae9dc6ed90,
+                0x71ba9ccdc774dfa8,
+                0x6f6222c2cc353fe5,
+                0x004f5c83f83615c6,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x3f967451bac52797,
+                0x106f455afc83154d,
+                0x038ad9cef7d28660,
+                0xf521d9095b75e733,
+                0xd49ec7924a07b48d,
+                0x35ecf12375b049b3,
+                0xb80abdfaedbe467b,
+                0x17c83bc60e8a29bd,
+                0xacb0edf13fd1c39a,
+                0xcabc06fd3e983fdc,
+                0x424c7782afd137ee,
+                0x00aab334eaec049b,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x4230da3b74ba0ea3,
+                0xfad05501ad1f3c0e,
+                0xcb463267730a872c,
+                0xe57552587ffc5e34,
+                0x789cace1cec52a96,
+                0xb06c9851f9e0d72a,
+                0x65096752982a1a5e,
+                0xc7fba76d54e34109,
+                0x2488e8b0331d772f,
+                0x1861cb03bd6528e4,
+                0x88703aa4f27208fd,
+                0x009dc7d40e4fa823,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x647c305581eccbf2,
+                0xe9bcb57e3cf9e77d,
+                0x267d147e886153cd,
+                0x77334ae83b524339,
+                0xacd0b7346a7778b0,
+                0x04f048be7223b51c,
+                0xf9f3475a970cbac2,
+                0xaa4e2c1ad72779e4,
+                0xa22861ae053a2af1,
+                0x1a3e269c20a5d63d,
+                0xa8fbd2dc8bd083cd,
+                0x0120810658d130b8,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe86fa7f459cb0cf5,
+                0xf298f68997c5f857,
+                0xf7f790eeca0a6cc1,
+                0x1f2dd3945f3d4cce,
+                0x7bfa18653578d828,
+                0xf1fd44a553ea30e1,
+                0xd3235c49992bc0f0,
+                0x2523e829292bf003,
+                0xdb638a02ad58f933,
+                0x23f26237ca0fcf4b,
+                0xc0799853c6e306e4,
+                0x00625cc5b9521f4e,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe9395fb99554ce58,
+                0xf33f1478b7cdc7f9,
+                0x489d0f4babd28470,
+                0xd34ef371488926bc,
+                0x26a7ee4201eeafef,
+                0xcb92d6d9b06e223e,
+                0xad425d4d01929817,
+                0xf27f6faeca3d4796,
+                0xfb1cd98b9ec63c43,
+                0x4f8060fd944f4207,
+                0x39082b872ff32476,
+                0x002df7601b844a5f,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xfa4b22bfde75effe,
+                0xaace82370e063007,
+                0xa4170ab34c1027d7,
+                0xbdf3ab753a34f1d9,
+                0x204946f19294d7cd,
+                0xc9e1ebbc78564ef7,
+                0xacbf3731b69a67c0,
+                0xf4c5380d52da736d,
+                0x38e1729bba87d3e6,
+                0x15e6f666849d67c5,
+                0x9f701fcbe43edda9,
+                0x00eaf9bb283124ec,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x98e60e008c74e3ff,
+                0x801a8863f43d3d7c,
+                0x60bd03fac8a9a1f4,
+                0x9d1fc494b056eeb4,
+                0x4e8f103300fc0cb6,
+                0x9b30a6b4acb5ec85,
+                0xe71253b68735c316,
+                0x07a8d74c43e8ba71,
+                0x8814ce45198d947d,
+                0xc69c7a077b750b8e,
+                0x065310d44be64763,
+                0x00c4890ee666f5ff,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xce70f602bbf57624,
+                0xea903780aa72376c,
+                0xf9e977a4930d6c2e,
+                0x8e9a0615c2adcc8f,
+                0x70b898582f369919,
+                0x734c9e996ded6051,
+                0x2fa87b5bb1e4b525,
+                0x06e644c6e01526ff,
+                0x42fdba64b1b238d0,
+                0xbef386501418fa17,
+                0x4add042166f1d268,
+                0x00b8da596e7c67dd,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0xe0b0f066b3e53d94,
+                0x96bfc8a849ca317b,
+                0xde833cc121d4af85,
+                0xae57eb6c7856c713,
+                0xa491ad41d6c3be4c,
+                0x1c8176990fa188f0,
+                0x874eaca01487b47c,
+                0xf16a849702509675,
+                0xc8be8e109782a3ec,
+                0xfa91c26c53022c4f,
+                0xccf5194c0b979980,
+                0x00f53f92c5842e42,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb1d523498e989980,
+                0x7a2069f40de23c8e,
+                0x1835a74e85ec91ae,
+                0x964bfd522ccc2cd0,
+                0xc0452ce4366c776d,
+                0x6bff85a64450b332,
+                0x2d0e765c757a00f5,
+                0x6aaeffa607651a70,
+                0xe66a65ae9dc6ed90,
+                0x71ba9ccdc774dfa8,
+                0x6f6222c2cc353fe5,
+                0x004f5c83f83615c6,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x3f967451bac52797,
+                0x106f455afc83154d,
+                0x038ad9cef7d28660,
+                0xf521d9095b75e733,
+                0xd49ec7924a07b48d,
+                0x35ecf12375b049b3,
+                0xb80abdfaedbe467b,
+                0x17c83bc60e8a29bd,
+                0xacb0edf13fd1c39a,
+                0xcabc06fd3e983fdc,
+                0x424c7782afd137ee,
+                0x00aab334eaec049b,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x4230da3b74ba0ea3,
+                0xfad05501ad1f3c0e,
+                0xcb463267730a872c,
+                0xe57552587ffc5e34,
+                0x789cace1cec52a96,
+                0xb06c9851f9e0d72a,
+                0x65096752982a1a5e,
+                0xc7fba76d54e34109,
+                0x2488e8b0331d772f,
+                0x1861cb03bd6528e4,
+                0x88703aa4f27208fd,
+                0x009dc7d40e4fa823,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 380];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger768([
+                    0x9b3f2d52e924ee78,
+                    0x6c35756ae9e12d86,
+                    0xf84e8d7810713a88,
+                    0xfc6cfc9eee0a4391,
+                    0x4061d995d94cdf0b,
+                    0xac593e941c1ef20d,
+                    0x419ecbbbeb313e06,
+                    0x9241395226b2f2e5,
+                    0x731c6185d42bcb20,
+                    0x2c4b50bec9349a37,
+                    0xa9aa560b70445409,
+                    0x01222b6fcf861bfb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1fc8bdcef4433d44,
+                    0xa482980d904bb420,
+                    0x9106d32ba7372e18,
+                    0x7e7b314af9547eca,
+                    0x8d463c64065421d8,
+                    0x710dca5e850c36e3,
+                    0xf8b809a8571a1fda,
+                    0x4a97ad67d59a6602,
+                    0x0f02c54846e8d210,
+                    0xed287554863d7cd5,
+                    0x671ea887b2efde0b,
+                    0x00403dbdcd48d274,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40b3a9a585886015,
+                    0x30f44b56bd6a4909,
+                    0x85762ccf5a0a9b87,
+                    0x91514ce3d03c3c72,
+                    0x742407b5534849b3,
+                    0x55410dfb42054b88,
+                    0xf6d5a2bd6e5ffbd9,
+                    0x1f3e148967c92e7d,
+                    0xd4bce35134c14fb5,
+                    0xf41762488c363ad0,
+                    0xe485f3871a9ea6f8,
+                    0x00e355259d318ba3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x27085e67305adbaf,
+                    0xe2c30ad4d96b63be,
+                    0x744d77d03eff89d2,
+                    0x52938ffa578cfbfb,
+                    0x11b885f7e692188b,
+                    0xa9331b7e2b035f9a,
+                    0xe1474195a82e9ce2,
+                    0xb4f842bf68e7788a,
+                    0xbf6c2a47ae366cc8,
+                    0x1dd05682fc124f10,
+                    0xe74b78863e480ccc,
+                    0x00e69d0c63513be5,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x08f28c75a16c45fa,
+                    0x31f62e483e0b35ca,
+                    0x50f19f91d9799978,
+                    0xf3da036e63a94606,
+                    0x893179ef028efb10,
+                    0xf24b1bef60c75e5b,
+                    0xe6d1e07e20453ec6,
+                    0xab2e303adfac7bdb,
+                    0xa740a1a021f54f45,
+                    0xfad40932f7548d2b,
+                    0x2f20c28c9fe3ed6b,
+                    0x003a3c80aa27142a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb8250d4abe5a5c1,
+                    0x2d7dd797fd99b6db,
+                    0x8a496addf5847cbb,
+                    0xeb5bbe50755fcfbb,
+                    0x0d583316d322d1de,
+                    0x7955fe5a01aa6f18,
+                    0x8372b356078dd60c,
+                    0x7d9baabb7a36ab9b,
+                    0xcb364f1206b93105,
+                    0x471adebdcd3e09d2,
+                    0x6a1543c9a9782e8d,
+                    0x00fa7055e3dff8ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x75329fb822a949ed,
+                    0xc942f40efb03d9c7,
+                    0xa2a5cef96ea90166,
+                    0x980a078291ca4602,
+                    0xa1e4cb2eef2a4bec,
+                    0x2339822fd6342a55,
+                    0x1b18dce3f911d011,
+                    0xba55a7e6bb800fbc,
+                    0x9c54dcd914e83788,
+                    0xdf3b59677dc6844d,
+                    0x1cf83fc67ec7b03c,
+                    0x00bcbf84c779ceba,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0315f741223788a0,
+                    0x5ee7a2a66450fbb2,
+                    0xc7121618119dcc60,
+                    0x8711f6d129928137,
+                    0xb7c2b2bb51619bd2,
+                    0xad7f151b9fe3b803,
+                    0x0c6b579d49818c54,
+                    0x4fcf92fe89ece949,
+                    0x03e891045df20bbc,
+                    0xd287449a8f6d057c,
+                    0x76ace0c43a36b1ca,
+                    0x008837dff43d283f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x3485cf999695131d,
+                    0x027245a8a0acdee0,
+                    0x200ddd65b19fcad4,
+                    0xdb3bf396bbbfc646,
+                    0xab7b1e9a7fa9dd3a,
+                    0xc9982acb6a0a8d4d,
+                    0x9c2fd9f5f0637829,
+                    0x7954f36b75d95918,
+                    0x599c981299b38ad0,
+                    0x2c9d302bafe5e2f9,
+                    0x64c7e4437ffc5f13,
+                    0x0009eda8bff4a916,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18d7d3f4e4404799,
+                    0x8b56e651699b702d,
+                    0x1052f4c9f577abec,
+                    0x53c3e45d12d5e634,
+                    0x533a388e4438c7d8,
+                    0x9b7b622908e7f48b,
+                    0xf1e2e2e786a84473,
+                    0xa954eba256652078,
+                    0xaca3119bfcf946ab,
+                    0xc897b21bbc28d02a,
+                    0x3a3ec315a6452b86,
+                    0x0053131b4dd38489,
+                ])),
+                Felt::new(BigInteger768([
+                    0x528d920f3e1cbdae,
+                    0x2ab59902edc6f433,
+                    0xa2f5b3013bcb1951,
+                    0x33e0ec0f8984d16f,
+                    0x4a4d09ddba5230f5,
+                    0x8023c960d549b60a,
+                    0x2c7717307e151ac2,
+                    0x1d213e27727766c6,
+                    0xde616e2cc5c28f21,
+                    0xd35da79f0fc919bb,
+                    0x0a5acfd09f93ea38,
+                    0x0011071332b59025,
+                ])),
+                Felt::new(BigInteger768([
+                    0x53f293cf2e03d28f,
+                    0xd3d82be26f0c52f7,
+                    0x24a2bc508bb98328,
+                    0x2525eda5ee22bdaf,
+                    0x921fca34380b1fd3,
+                    0xd7cb4096fb5cd019,
+                    0xc4be5bbd4f2a288c,
+                    0xed475d78157c9ed4,
+                    0x447183c93f33c615,
+                    0x8b002a9a75a0e442,
+                    0x795e54a94d08c570,
+                    0x00492f316e464f1d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x68e04f3273cf835f,
+                    0xa3f9520c9fb12098,
+                    0x18139931917b3563,
+                    0xe7351aba941ee150,
+                    0x728d4610cea56d88,
+                    0x3e49cefacf7b08e1,
+                    0x97a85d8da6b9f1d6,
+                    0xfde87ba03a77ae56,
+                    0x3f946fd5ad1c80af,
+                    0xce591ad62443bf61,
+                    0xd0f1761eae04240b,
+                    0x005254e8a8c67548,
+                ])),
+                Felt::new(BigInteger768([
+                    0x817ef03db93de2b4,
+                    0xa04b721d0c0e4248,
+                    0x7303b5d2a31ee930,
+                    0x14aaf267c4b91a11,
+                    0x4cc2cffb296bc5f8,
+                    0x25d3e11992f44dc3,
+                    0x050708cdaedb5425,
+                    0xb65a71ca10f5446e,
+                    0x115e29397908bbcf,
+                    0x17a37e077e0a7383,
+                    0x402e0e8638702be1,
+                    0x004ecc007bf259df,
+                ])),
+                Felt::new(BigInteger768([
+                    0x33c6e2fb709fe5ce,
+                    0x3530a0da6017b4c8,
+                    0xc870ac24c726a28c,
+                    0xf4122f1d871c72be,
+                    0xce4d68e3439a3b9c,
+                    0x78f3ab706fc50d5f,
+                    0xe95d7dc453200af8,
+                    0x79bf865deab3b098,
+                    0xc05fbbd1a22ccba9,
+                    0x7f0861319bfafc56,
+                    0xfdd6454bde9bc64f,
+                    0x00b16c45c4d7ff39,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89737ae70bc040c2,
+                    0x6092eb4b5c58c060,
+                    0xd4de83d14f636aa1,
+                    0x9b6952e95d4ad0a7,
+                    0xd6b21c0d476103ec,
+                    0x2709a4b095ba42f9,
+                    0xfed381c4e6ad613f,
+                    0x13338dde9bb282ae,
+                    0x77a41b2ccb8f500a,
+                    0x47626ae42b46c9da,
+                    0xb9f5b6d978a10da8,
+                    0x004e07d6e2512db0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x822432615d4b42a4,
+                    0x7f8e0a26af82f24f,
+                    0x9b355ccb90d81324,
+                    0x7283f890d4bf7956,
+                    0x096354179a7a1339,
+                    0x6c04d755451097df,
+                    0xd56afaa4c4b22822,
+                    0x402cbe8f8f5ac1f5,
+                    0xcc7c07bc27723bac,
+                    0xd355ce6222646631,
+                    0x64ddfda36474d565,
+                    0x00080f16b8182e93,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad8e2c2144fb5828,
+                    0x610516ede2e16eac,
+                    0x3d52a15040073dc1,
+                    0xf48c458135ae26b0,
+                    0xd798c064e201f2ea,
+                    0x29832fe70d7ae93a,
+                    0xa7a23b397dc174cc,
+                    0xcdacf39122ee5aa7,
+                    0x348e853f75b7fda6,
+                    0x4451fab07287a7bd,
+                    0x156cdeb665a3e9e4,
+                    0x000be77fc08f330b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7087cf59ffd4a22c,
+                    0x5ea0342a2d1c05d9,
+                    0x3ee03a18982f41cd,
+                    0x547903a69a1c8c5c,
+                    0x6c13f7712dd4878f,
+                    0x7de2b3613954a8fb,
+                    0xacc8661c0d50f8a5,
+                    0x764ee5bc4691a31d,
+                    0xc985005fcea70859,
+                    0xc4c996ab0b9e7e57,
+                    0x81ec6ef391b5a817,
+                    0x003d7b7e91e3dd8d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4a2dec8cb5cf2cb,
+                    0xbf5f27e84e28bca8,
+                    0x3fe420fec09dbb94,
+                    0xf0d5c8e25bf865a9,
+                    0x18614dd667b75c92,
+                    0x7f639b12b237d7a1,
+                    0x963b32bce6c613d6,
+                    0xabff4b92eaa493e5,
+                    0xdbece4968dbf51a3,
+                    0xbb2e63390605978d,
+                    0xf9448d6f1a85dc41,
+                    0x0078790e9527c280,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xe47c9066f94d0b56,
+                    0x23cfabd330f55bd3,
+                    0xe73ecad92645f818,
+                    0xa384eb8b2df49c9b,
+                    0x64648ac2400b805f,
+                    0x4ef7f3edd096622c,
+                    0x73b750e584e77514,
+                    0x5f73de7ae818fb2a,
+                    0x6689d71318ccef82,
+                    0x48a1875e7bd6a7d0,
+                    0xfe5413439ed48e66,
+                    0x008e12f3f808e190,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe0862ecbf240b147,
+                    0x557a59cb2d9cde88,
+                    0x35225db026ce2551,
+                    0x46a1be0bd77e258d,
+                    0xf5eb06ece77ea57c,
+                    0x6b4903080339d05f,
+                    0xe666d4779d933e2a,
+                    0xd3c897b1e1dc6bd2,
+                    0x3f8d0f8701492fac,
+                    0x0507c21bac540050,
+                    0x95d7bbd82f319bdc,
+                    0x00012a75789c9823,
+                ])),
+                Felt::new(BigInteger768([
+                    0x80743fb215ae9114,
+                    0xd76df022f515ca9f,
+                    0xb339dcdd031dd406,
+                    0xb7a1a3347e3573ed,
+                    0x3d178b566bd7e063,
+                    0xc23605f3fbddeedc,
+                    0x0152d84c889e0c4c,
+                    0x09031d28af7750e6,
+                    0xb161ee318ad61972,
+                    0x3ffb5a32e23338fc,
+                    0xbb9620c0a59d4822,
+                    0x008edcf2bbc297dd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1caea8eb998d2af8,
+                    0x331b1e95d62edee3,
+                    0x63fce86c87fc2a0e,
+                    0xd6f219d01cb3f3bc,
+                    0xff1fdf09f3af56ef,
+                    0xbc547aa13cc03e8d,
+                    0x462dcc71be62c722,
+                    0xb5dc2db028767688,
+                    0x825eea6b74020b3b,
+                    0xe21f77e6b03be1ce,
+                    0xa876e31ac778e389,
+                    0x0017a88758c70959,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xd0727d88fcaaef5c,
+                    0x1a6be5d710bc5fde,
+                    0x69372d6d8efc0b6c,
+                    0xb17fc70c6733ed6f,
+                    0x3f90d620d501fce0,
+                    0x6c91bbf8b1925d03,
+                    0x9cabd9e7f999683f,
+                    0x145944c9a71f40d7,
+                    0xfff138ed76d2631d,
+                    0x350f872341525bf7,
+                    0xa4f96b1ab0e9b593,
+                    0x0008b6c409c5feb9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x15ab0a89781c1aeb,
+                    0x6c3e95716af7e5b5,
+                    0x2bab3d1927eb3e82,
+                    0xeb9ab7c456bd1ace,
+                    0x9d0ff9ac61e7621e,
+                    0xd99ccf963e25bd15,
+                    0x6a37e339f58341b8,
+                    0x0ce40b58124134b0,
+                    0x96ac9fe53691911e,
+                    0x07e9560cb22b62aa,
+                    0xeefb61784fdf5cd6,
+                    0x000c6561023ec8eb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd18308505e950aca,
+                    0x449c3f7a49ea2506,
+                    0x051316863a8c682a,
+                    0x47413320d7c09565,
+                    0xe84ea263d4b0ce62,
+                    0x69879c14a89a0168,
+                    0x8aec937d3d488a58,
+                    0xb1df0797f819919e,
+                    0x7b5a21a9d4e291ef,
+                    0xc1aafeb2eb3c9216,
+                    0x1472ddd2c8e6630b,
+                    0x006536672ce8db9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf5073cfb04e1d1d4,
+                    0xb830890366b26ddd,
+                    0xc3f9636e16018db6,
+                    0xa5492c3feb8ea8d8,
+                    0x13cb07e9ec9055be,
+                    0x073bc44c92d84e33,
+                    0xea55e5725acef962,
+                    0x9eec4c8273a118e9,
+                    0x3478fb2b743aefdd,
+                    0xa671101294b6b3fb,
+                    0x1cec0c3d6f34636e,
+                    0x010a6700588d98d0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6d4fa2b67a20155b,
+                    0x902713384b2dbd64,
+                    0xd70b6f66fb271463,
+                    0xd39016f764b2cd6d,
+                    0xc8850aaed141aedf,
+                    0x6b5cd0bcf24d07d4,
+                    0x61b3b1f03e298b06,
+                    0x72e375056074bddb,
+                    0x7e3811155263146f,
+                    0xcebe17c346f92538,
+                    0xe1310ce78e0931dd,
+                    0x0071bd9b3972bb13,
+                ])),
+                Felt::new(BigInteger768([
+                    0x63619dab860c333e,
+                    0x8a0738ab6e41734d,
+                    0x4b989fb8b05edccb,
+                    0x3620366898dfe0f2,
+                    0x047ccc184367e877,
+                    0xbad9e925c24bd844,
+                    0xff54e6f812724886,
+                    0x7bed78259f162d3a,
+                    0xa8b9faa6da1bc890,
+                    0xacf1bd3f5dea2c2f,
+                    0x02b4192ef9f9a6cd,
+                    0x00c98456645587c8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x32f681701d1f369b,
+                    0x749c0a7428b1c7cc,
+                    0xd7c2e8546697a2cf,
+                    0xa3ac10f13191d00f,
+                    0xde2920eb88655cab,
+                    0x4281f6c20b07910d,
+                    0xdf92dedccdbfcea7,
+                    0x0d5a4c1330032d4d,
+                    0x030ff08aa538c21f,
+                    0x58d124fc31c626fd,
+                    0xc5b8e92ecb3cb2c1,
+                    0x00447da5677fba86,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb95894f0d235aef0,
+                    0xdc8f711fb4509045,
+                    0xe425a6e17ff61fac,
+                    0xc766431ad731e1e0,
+                    0x8b4e0877b1e2df6c,
+                    0x71e7a7f4cefb17ec,
+                    0x0fe554c5ac404304,
+                    0x4070d293aa3163cc,
+                    0xdb6ac137a76aaeb0,
+                    0xc3614b61f438849b,
+                    0x93c3a2a2a6333889,
+                    0x001c03a0f083c8ba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd6c241c5dd7079d0,
+                    0x23645a4c2d557588,
+                    0x76362513913d9452,
+                    0xf6df8b123398be53,
+                    0xb06caea751f63b23,
+                    0xcbcc450db28cb54f,
+                    0x5163435dfe2b16bf,
+                    0xb38df2279fd7fde0,
+                    0xbbd27bef938827bf,
+                    0x5d9eae734ccf148b,
+                    0xb8e96e9cf2315d57,
+                    0x00d91c180f381e9d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x97fc847432d9d72e,
+                    0x22fdef1d99ca67cb,
+                    0xc66bbb5e40babbc8,
+                    0x67613d944e0fd49e,
+                    0x29ec5fb393dbbe34,
+                    0x450b57f21c3c0431,
+                    0x80727efd4ba348ec,
+                    0xf13ad93ed1e3863d,
+                    0xf55d20f01ad7b6bf,
+                    0x7c0866def4f40a22,
+                    0xaba86c8d4be0f542,
+                    0x001caff6174b09a0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbd920d3f8c470065,
+                    0xc3e1aba17ad21769,
+                    0xb9233443e2d6db52,
+                    0x3c791eea26332baa,
+                    0x1cee5c05d0f8255e,
+                    0x75d2c7df11b7854e,
+                    0xb7bb4b7cf9a4dedb,
+                    0xea32ca514dbb2d20,
+                    0x629c44e0ad39b26b,
+                    0xa26aa3914358b76f,
+                    0x990f91f9836af7a2,
+                    0x00a088e62a3cca1d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd4d5399d9de0c003,
+                    0x7dce9b2dd37be168,
+                    0x1566078f1cb0b3a4,
+                    0xdf79d224b5bdd722,
+                    0xed3522ec31e93e4c,
+                    0x3ea691cf47e74fa2,
+                    0xe0a635b727c7a7a4,
+                    0xf9cb55f8c20b4c3b,
+                    0x130eba0c39f581e1,
+                    0x2b231971a6d5edf2,
+                    0x68fbbc28411736bd,
+                    0x0006e973eb5a35af,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9ab984f0c1c59ee6,
+                    0xf819885d275ff6d8,
+                    0x401a035b7b8f3f7a,
+                    0x9d5cd8e7fefccdb4,
+                    0x276359b63c54b75d,
+                    0xb7d8177d9f0c9b97,
+                    0x7d03d5db9e5c5997,
+                    0xce02393eaa663dfb,
+                    0x90dc79d6e36d1d45,
+                    0x22d210c7ce55124e,
+                    0x6b6f0d416708ce2f,
+                    0x00b3008bce839fd9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf5c52aa441f3c27d,
+                    0xfb5c82eec845efcc,
+                    0xed997e6f9e536234,
+                    0x625b90950078baf7,
+                    0x0c1532ddf00ff933,
+                    0xf3187f36da9ba182,
+                    0xc2e56ed514460764,
+                    0x5200323afe72f121,
+                    0x77b899ec43024571,
+                    0xdb870f36ec5b0c37,
+                    0x19b14fc5c63abf04,
+                    0x00c7532585750745,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x93a08ef2672c846e,
+                    0x055521aeaaad1053,
+                    0x9aa422487982ce31,
+                    0x03f976c6f0c196a0,
+                    0xa94ca5d043073f01,
+                    0xfccf464267cfffd4,
+                    0xde965f4ffc4b2c52,
+                    0x60b04d266522cc72,
+                    0xb2894b9bc91ed0f9,
+                    0x6fc92a8e8b0ea93e,
+                    0x748d3338c5307605,
+                    0x00b7facfce4b25f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e89538b7e1c9236,
+                    0x67792c2adb7497c2,
+                    0xac34dc01d999f6da,
+                    0xcd8a85bda24d875e,
+                    0xb8488546633a7d43,
+                    0x44a764d6effe6b9c,
+                    0x62256d738422033b,
+                    0xef2ea1768080d99f,
+                    0xb358292bea16769e,
+                    0xaf8255916f310880,
+                    0xd7dd459673a3ae02,
+                    0x00444fc95c99e02e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x853f12060039a5d9,
+                    0x6e0617dec78522f1,
+                    0x6c53f4f5492cc107,
+                    0x57493e678b8c3358,
+                    0x0e3e25122a927bc7,
+                    0x2642da7931da8cda,
+                    0xbae66b1fff35f222,
+                    0x9caa8c9075cad5c3,
+                    0xd1258b57ad08f549,
+                    0x8b9b0fa5f1df85a1,
+                    0x06e667295dfc58d8,
+                    0x00d2d807c471af42,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4b9f2b3e934c785,
+                    0x71ace67c29649842,
+                    0x27e0049e13b0b6d4,
+                    0xec1f31975d67b613,
+                    0x37cff66afa8af432,
+                    0x4489f573d3bea1de,
+                    0x0a9ed82b434a13db,
+                    0x7396a36a7a13252a,
+                    0x1a18b5625e2bb9ee,
+                    0x0471fc64cf14df86,
+                    0x555b01961b7ded9c,
+                    0x0054da49f7253419,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8821ed7c858e00e9,
+                    0xc7394f055f1a4ab3,
+                    0x198d073e524ff9c1,
+                    0xc2cf808bdf9e848c,
+                    0xfe86d5dec4165107,
+                    0xf76130d8fa49f4f0,
+                    0x85ff8ec374c72e05,
+                    0x41f1f256ba17b118,
+                    0x5e0c230542584bbd,
+                    0xb48e557455eb1f15,
+                    0x020d70e69568f220,
+                    0x0048fb3a012420fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfcf187b7c4cac7e7,
+                    0x977560f27cddc00d,
+                    0xc6d3058adba7d956,
+                    0x9c48e43d5890196b,
+                    0x25ff0a65bbf9baed,
+                    0x7de9afef3f1e344e,
+                    0x9ab783a4161c4e6b,
+                    0x0533b0c1fec7b9f1,
+                    0x9edadd737a937d9f,
+                    0xc8f276aacf813881,
+                    0xd425b84ebaa4a6b1,
+                    0x004453a45d502b8d,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger768([
+                    0x188e2b6d15e8a9c2,
+                    0x7166b2baf0e3df00,
+                    0x003a485ddf51bd03,
+                    0x2d350a1ef9d14471,
+                    0x7ce49a8d5da7f9a5,
+                    0x69c0168804832a71,
+                    0xd9098162984acdda,
+                    0x408379586b52ea8f,
+                    0x861dd31ba31ce4b8,
+                    0xb0b33593539497b9,
+                    0x1772f4e1e64ab7ff,
+                    0x00e6cf1f08568d98,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc1a1da38f6bcea9,
+                    0x7b8ff16857947f28,
+                    0xe85961c22ddb1cf1,
+                    0xb12fda3b6523b6ad,
+                    0xfc898dd0237dd8e6,
+                    0x22f05cf02eee9e96,
+                    0xcf5623b88a1a16de,
+                    0x1fae959f23ff6113,
+                    0x395587338ec76b49,
+                    0xc21cf8d7bf90e51c,
+                    0x5af99ae02f81a170,
+                    0x0115e18b27a5ba66,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf792698ac5f636d,
+                    0xfb232acd3084ec09,
+                    0x487dde8422e572bd,
+                    0x28bc04c48989b0cd,
+                    0x6690b052328675fa,
+                    0xa8772ffd252efa8b,
+                    0x755a877eac2c54b0,
+                    0x4839c641dca93f8a,
+                    0x0899363d24d76b9f,
+                    0x4b3629a2ed94ae20,
+                    0xd0ba28d9d9a718a8,
+                    0x00df419491df39f5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d79cb2bf41c7e88,
+                    0xa8b2d9ffcd7450c8,
+                    0x4294c98a61b9c918,
+                    0xbc2e0de3d6a6c93d,
+                    0xe6b6dee1a8d6eef5,
+                    0xedf4f970a44eec08,
+                    0xcd831ec16d84401b,
+                    0x78900a9db4f1fb1f,
+                    0xcfd766ab6a492eed,
+                    0x0bdefb482bf4cc08,
+                    0xb842afa87cb9bb27,
+                    0x0017e01b071582f2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x1cc0e623be85747c,
+                    0x542fb1dbb667bfdf,
+                    0x4966b230c7d1b9db,
+                    0x77363e99a98b9809,
+                    0x7094b350d80e9e26,
+                    0x1c4bdb520b0d72c5,
+                    0x957f06cef1c738d5,
+                    0xe2cae5610f0d64be,
+                    0xe67d029a26feca5c,
+                    0xb44ef9210e87aeed,
+                    0x445c48c4f43417c6,
+                    0x000bfa1cd1259cfa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x686834c1b1a52700,
+                    0x5245f410205ce87e,
+                    0xcdabd12b9546c5c9,
+                    0xdd1c557df11335d2,
+                    0x2e44d58774debfa9,
+                    0x4e8bd77d4138ea76,
+                    0x908bed8a9bc1ed95,
+                    0xc9bba03baf0ae60d,
+                    0xe903548bb05374ef,
+                    0x8157b07f9c8ed077,
+                    0x9a9793b1a4dab515,
+                    0x00d6f3ad730a3925,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03f959d1a93d8554,
+                    0x38435f0bdef80e79,
+                    0xa003c11cfa05e7eb,
+                    0x9f23156e1b44dfeb,
+                    0x9c2d0f62b2a191f8,
+                    0x4621c5543503c03c,
+                    0xdfa8972c9c50ff75,
+                    0x29e29d8c862d98dc,
+                    0xaf9d7cd76940ffd1,
+                    0xeb64db07e5f8a5be,
+                    0x83a9e8923bb81547,
+                    0x011f6478cdd9ef89,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa591dd6d74eae5b2,
+                    0x5371023298f697c0,
+                    0xfbf584b55c2d2333,
+                    0xb844bbc8be22a989,
+                    0xf2c3fc3df70d0974,
+                    0xc9437dafba6face2,
+                    0xea3e472ead9cc578,
+                    0xea03d9483b1c9ead,
+                    0xfcfab4adc79487c9,
+                    0xa8a49f2054620f6a,
+                    0x39b075dc9b610a80,
+                    0x0073a316f8e3c28d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x7708d013e3188bd1,
+                    0xe3cb9afefb0c48c3,
+                    0xb9e7a1e35e24392b,
+                    0x08ab76ad303ac0a9,
+                    0xc2a7b52bf74071b3,
+                    0x1063b7938518946c,
+                    0xf207f73e62400571,
+                    0xc0f3afc4cdb81344,
+                    0xaa9b6ba6f553cb9d,
+                    0x5553fe86e71938a9,
+                    0x6aba5d7302f6cc52,
+                    0x00a6169efe0b8519,
+                ])),
+                Felt::new(BigInteger768([
+                    0x573bae59034a1b9d,
+                    0xd7314449053ef2d5,
+                    0x698cb185ba2e962e,
+                    0xf123ffc32099dbd1,
+                    0x80cf6a2eabd38c2a,
+                    0xa9f518c094afae25,
+                    0x6c548bf5298a175a,
+                    0x01cdbd7938fece9d,
+                    0x0cb050ad9445cc90,
+                    0xefeec82247490d51,
+                    0x76a2c9e1812a0ee0,
+                    0x00e72ede06946466,
+                ])),
+                Felt::new(BigInteger768([
+                    0x77759cbb226e8c1c,
+                    0x46df208abeae7f50,
+                    0x0fe3a59ba86e7ace,
+                    0x7e22c94ff88f8b96,
+                    0x78433bb41068e5fe,
+                    0xcc209d0b65cac8aa,
+                    0x9b26de0f9cee3ceb,
+                    0xe12b234946c82516,
+                    0x4230aa51fd5defbb,
+                    0xa0381d30fcefac9c,
+                    0x970ec98d7457d87d,
+                    0x008b3b12e7ba5a6b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa32a4043c4d85d58,
+                    0x5dbb25f4e0b544ce,
+                    0x551a5bb49e0e4a16,
+                    0x7adafb0c7beb5a5d,
+                    0xa9040bd3b184c780,
+                    0x28f69f24ca8f172b,
+                    0x40da9e9712f3c060,
+                    0x767ff9caf0b025d5,
+                    0x89a6b1d97b56660e,
+                    0xd8e11404be088072,
+                    0x21a6d4d0dd64a0e6,
+                    0x007c6d948d1c9cf3,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x8dbb79c98a031716,
+                    0x808fa4988375519b,
+                    0xa19f7884815ee5ab,
+                    0x2d268abef5b2a6d0,
+                    0x00f1226b26627b86,
+                    0x8d159b1e8ea27744,
+                    0xe08d9ea40c1fe875,
+                    0x9f50936fbaec588f,
+                    0xf8bba6aedb33a655,
+                    0x17752e3757e6a574,
+                    0x73ddf8eca7c824d3,
+                    0x00b522b451db2fe0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x455153f3485a0db6,
+                    0x36697f8c24ed2188,
+                    0x12997718f7dd027c,
+                    0x6eef0673e8979ad1,
+                    0xf1d33338a6b75387,
+                    0xdd35e604b02eb50c,
+                    0x76b4865fe53bf3e9,
+                    0x114936b4a876b9a9,
+                    0x7fc5e19ff3eed4d5,
+                    0xfc73b1e433b3207d,
+                    0x5b382cceecba8506,
+                    0x00d1d393e4412ca1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb78ce07f4f6809b,
+                    0x7e88acfd9ed53507,
+                    0xd3dcd4d07a237c17,
+                    0xeab99a9db46f5162,
+                    0xd5c997d5e3c184db,
+                    0x832907d7257a239f,
+                    0x9f4d640bd0e2288f,
+                    0x94a40b2b89bd4868,
+                    0xfdf2e0a410eeef2c,
+                    0x62723eb208496958,
+                    0x86f614ddf838da8f,
+                    0x01195830ee80e0b0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22792b1df8c81e04,
+                    0x40969a61b48fa99d,
+                    0xa8c7e6f5ff8cc37f,
+                    0x9d580a7250a8ef14,
+                    0xf802e32703ef0c6a,
+                    0xab8172ce195bcd55,
+                    0xcb42f04a4da63710,
+                    0xa5414a1dec7227f2,
+                    0x8f94c0cfb1a76eae,
+                    0x850ec75daf3a70a9,
+                    0xe99490c4d833ca6c,
+                    0x00ed333e97a6052b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xed24cc45b2c24dfa,
+                    0xbb05afde73720c1e,
+                    0xc5d674d0f72a6350,
+                    0xfbf0305d79908804,
+                    0x0f9b4943db18bef7,
+                    0x6c54e770fe02df64,
+                    0x25e456de84cbbcbf,
+                    0x4d76dd1a2066fc79,
+                    0xcd2b551fe9b41324,
+                    0x1f4d712d60ef1615,
+                    0x725bb53f50ee8374,
+                    0x0020bf0565b0157d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa0ad9672936f6fd8,
+                    0x63afb7149da7baa9,
+                    0xb12ae39480eaf89b,
+                    0xed686e76e631d42e,
+                    0x26785bec1f360f32,
+                    0xfc2ba919edbff9a3,
+                    0x7d7457002ab7259c,
+                    0x024da0316da12108,
+                    0x4dac55beed855f5a,
+                    0x64e55bdb0d9bac88,
+                    0x5a7280e4f6fd287c,
+                    0x0020d570ecd97592,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9ecdc7a34dabe751,
+                    0xd39f99dbea3309ce,
+                    0x08c7d9e9b5c4ed02,
+                    0x60615e882c3ea5bb,
+                    0x6d225bae7ec21636,
+                    0xb910f11e03944bf0,
+                    0xf42af1ba25ead915,
+                    0x53e46c26a3049202,
+                    0xccc0382f9bf6cb7b,
+                    0x50600b62f56f39a2,
+                    0x9a2e9d129f6de1b2,
+                    0x006cfa65f60bcf34,
+                ])),
+                Felt::new(BigInteger768([
+                    0xba8e3607baaf95eb,
+                    0x2f42e9de40f9d5fe,
+                    0x23e1e390530e9ffb,
+                    0x1a75ead6569f2076,
+                    0x34940ce20554e598,
+                    0xb364e40a5c550e39,
+                    0xf824f2bffbe160d0,
+                    0x0046f3a13cb611b5,
+                    0x7339827b2123981e,
+                    0x990422ced076c160,
+                    0xff75d5284aab6891,
+                    0x003f6e71e47b4f20,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x40f80e0c9086e562,
+                    0x4f0c77659aed9edf,
+                    0x3a093b192092a286,
+                    0x127bcef97a221cc9,
+                    0x79234e6bb4e88ad1,
+                    0x0acdcb10e43145ff,
+                    0xd1a36f08ce6c32ef,
+                    0x504f99903c720ef9,
+                    0xd69e65c105f51fc0,
+                    0x1ce49f67b4faf02f,
+                    0xa30b07ee389c3ef0,
+                    0x00ccefea61925cfb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x51b1c232d0b72a3d,
+                    0x5b1b534b4c29a40f,
+                    0xf96c6228204ae8b1,
+                    0x4aaf4fa9dab7b847,
+                    0x927ddd250ad8ecdc,
+                    0x45f8f7018f03ff04,
+                    0x378f107d7b4a028b,
+                    0x5220ac9768ad9969,
+                    0x6fe9b6afd39c78c8,
+                    0x8d8524212acdbffe,
+                    0xfee8f5fb8b2856e8,
+                    0x0011b80d05d729e1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0bbd99560578c0ce,
+                    0xfbe7987f4a954967,
+                    0x68d8e6b61362532a,
+                    0xf0cc3e92b13fe32b,
+                    0x688032a1d90af279,
+                    0x19a35a814775ec1f,
+                    0x11d52c8a8a24a1b5,
+                    0x120bb56278079064,
+                    0x7855c6eecc57b634,
+                    0x8d23fbd857ac51f7,
+                    0x79fdc912afcf4c73,
+                    0x004dd84d92af0928,
+                ])),
+                Felt::new(BigInteger768([
+                    0xceb70dbf59c06cfa,
+                    0xdd0a2bc397e06562,
+                    0xfcc8945c2efb5580,
+                    0x7e5d3bbfdb729768,
+                    0xf6d2d2648bd0969b,
+                    0x8d6767297730e04b,
+                    0x7782ba463b9ea561,
+                    0xd03dddece39f26c8,
+                    0x628d9dfb822b7f5b,
+                    0xd7c1919d44dfadcf,
+                    0x5fb7b7140e979a6a,
+                    0x008f0aacf597c643,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0xe61d881274c70a47,
+                0x86aa7b487bb44593,
+                0x94e26a86b6e749ee,
+                0x9d1a7ed0bdf1083d,
+                0xdca0cfcd36e95eff,
+                0x462e8b8eefb81a18,
+                0x06e3bd21ef1ca9f8,
+                0x213d5021b9607588,
+                0x969dd8d2ad63f43b,
+                0x3cf8dd2ff37dbea2,
+                0x93f4cc9300c91269,
+                0x00151c250c04c7a5,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xd1ed454b6376dc13,
+                0x163b8a15409c9261,
+                0xb2ff8145659d51a9,
+                0x53e9489e6ce79545,
+                0x8a3cd6714d5524f2,
+                0xea3997742878520b,
+                0x7173bdf0726360b5,
+                0xe04fade18635c16f,
+                0x5d50a6e5c895c38b,
+                0xaef5f65afe21ff89,
+                0x5fd720d0331fc73b,
+                0x004cb54289f2a664,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xdc144062002c480e,
+                0x339d0d7b496f302e,
+                0x0c971670c0954cf7,
+                0x710f369da72b0568,
+                0x5b2502eaa0bd9828,
+                0x9facf0f5a19ee288,
+                0x5d39dde92ae7b088,
+                0x7e5546f21a0601fd,
+                0xd46f95ccabf71ebe,
+                0xc289bc9823120adf,
+                0x125d5cd68307d96c,
+                0x001859cca24474d1,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xec4f1660ef54e58b,
+                0x512b7b93dd025811,
+                0xbbe88f35e68dc27c,
+                0x6b12540c08c3b1f0,
+                0x697729633a483c18,
+                0xb4699eb6da02a8fa,
+                0xef7833a27a0011ab,
+                0x4dcb1ea6da349119,
+                0xde7ab1c24ca370cf,
+                0x1c32705e25feab98,
+                0x597c8bd1716feb4b,
+                0x0060814658038341,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x9700e97ce18d417c,
+                0x1084c6133ee21440,
+                0xf4ecbe34f366be0d,
+                0x7dc50b89121e4a96,
+                0x301630e94965d3d8,
+                0x1991a9987ade1498,
+                0x5c5ee6c2a93a8cd3,
+                0x6cb3ea1fb4bce752,
+                0xc6bde395464a3e0d,
+                0xcba7869697e3d8f4,
+                0x87e77d5823bcc07e,
+                0x00695af3c4b9474c,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x459e523e01220feb,
+                0x944e114e3435042f,
+                0xfb08ccbc616d8d47,
+                0x92c7aef5eeb654b0,
+                0x6ee94445e2cb4db2,
+                0x39844739109003d1,
+                0xa16edb45df9d1c6e,
+                0x4bd75d4f0887d887,
+                0x3b2f7c0124de5317,
+                0x2e751f00e798c647,
+                0x27d57149f63cdd84,
+                0x009ddf4b19a1cf9b,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x33720548411a7834,
+                0x0263d6667e3ab7f6,
+                0x3994ec790687c44d,
+                0x1d0edcdfb52d4946,
+                0x1340449f258d4c27,
+                0x0d9814e9e31e40a6,
+                0x559afa7f5c1fc768,
+                0xaa6eef019f2c0598,
+                0xd89c46dff7f42ce3,
+                0x4cf1e87b4abefc4e,
+                0x5e28345e4239b13f,
+                0x00a93e58e80af49d,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x3b46a13cd9b029b7,
+                0xe275921f1c2ba595,
+                0xd2a09708f9f6074f,
+                0xe8a1c20352d57d9b,
+                0xc542884f094a4b70,
+                0x6bafe189ea13a82a,
+                0x3757353076d83a53,
+                0x1e894480779a785c,
+                0xe40fe026c46c8be1,
+                0x6a25632998651340,
+                0x6c5760e86b3fe46f,
+                0x005c2de98a86d486,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x65feefdc04f82301,
+                0xd5012b4da1e6c2c5,
+                0x61af764d93f9fdb8,
+                0x15e9e8cd678b33db,
+                0x056b039f6d1eb1b0,
+                0x5bde7f152bca3b72,
+                0x3551d59f1ca6d418,
+                0xbb448368f6d331fd,
+                0x00b6b6115452a651,
+                0x35d368dbd78b8fe5,
+                0x99aca8fc69440380,
+                0x00dc275b10dc2b87,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x24b5fde31b28654d,
+                0xa0afc391f6a6c1a6,
+                0x77e59e4319380123,
+                0xa48988aeac395d2d,
+                0xf9ea080637d6943a,
+                0xccabf2b5bd2dfed4,
+                0xadb7821c7cb859d6,
+                0xc3492c93c55d9f9d,
+                0xc445ead7a6fabd12,
+                0x463fa9bc1aafb412,
+                0x0bf61f20331c2b97,
+                0x0028bd6cc6fb810f,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 285, 286, 860] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 285];
+        let mut opad = vec![0x5cu8; 285];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,2004 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0x2a7d98ec456bf6d6,
+                    0xa039347ae30e94e7,
+                    0x2c4180b08c0e5b03,
+                    0xefbc183b2fd6770c,
+                    0x384c16952af28061,
+                    0xc5003a3b6cb9825a,
+                    0x1a3526a364df053b,
+                    0xac60a2c75838524c,
+                    0xd6e9b90300bd833c,
+                    0xc847a0c55ddaf1aa,
+                    0x43a2889d1895d716,
+                    0x0114951c326d8654,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf7270b726511fa75,
+                    0xd2d528745d0fc451,
+                    0xef1dfc29f32f6316,
+                    0x2ff6c8dd5af423b3,
+                    0xde341a3cffeb3980,
+                    0x01e72b32bb15793a,
+                    0x45e9101fa7416d10,
+                    0xe9cea28a0d243095,
+                    0x0b2c4eec30e09fb4,
+                    0x6448113e6509dbd9,
+                    0x6df3a98291c957b0,
+                    0x00772b7dfa6ae47d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2c3fed233afbfdca,
+                    0xe681e189090615a5,
+                    0x9b7954cc3eba9d94,
+                    0x27dff6a6b49db755,
+                    0x0fa46e4adfb1b635,
+                    0x0d3906b65ad0569d,
+                    0xd6ef7f3ab5ebd442,
+                    0xe6507662b0056147,
+                    0xf0fb8c5c3142312d,
+                    0x55fa4323de1af5d2,
+                    0x5a8843b08fe534f0,
+                    0x009fff0051f9d0ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe3054cc31c911791,
+                    0x37cd6f985716dce9,
+                    0x3b07cb6d090856cc,
+                    0x0f1e0de2b761d9fb,
+                    0x49670bec3c408472,
+                    0xa126deb11c079f30,
+                    0xafad65b95c65d788,
+                    0x989b8d8513653d88,
+                    0x7d7cbcde967218ba,
+                    0x08f7da1b55b7e8ae,
+                    0x70c9c06b2e33f70d,
+                    0x00b337747603f233,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9f60791ae5c5fbff,
+                    0x118f7f3a12e2baf8,
+                    0xfe4ec275d0f4d4ab,
+                    0x1f6cc14f3438c618,
+                    0x1e3183b82afdb32b,
+                    0xb0e9c908c21b91c2,
+                    0xf9caec58ebcc6ad3,
+                    0x8921b09eacd94628,
+                    0xac8c2a3891332843,
+                    0xac99bef4285b19b6,
+                    0x52ba5568248187c1,
+                    0x00e74650394aeb8f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3890c0714b8112c2,
+                    0xd7eca86b9f8a79f4,
+                    0x2be7cb1fae3fcd43,
+                    0x23df9891ffe9bbe9,
+                    0x445cac29a8142f38,
+                    0x214fed99ba841f67,
+                    0x62e26ca58cb803f4,
+                    0xa0b9751655992c00,
+                    0x936dd6512d664778,
+                    0xaa5bb97fe63fed10,
+                    0x37e2197dc4a8e0a0,
+                    0x00ca304d9817588b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8cc40052a0026907,
+                    0x518cdf8a9a129957,
+                    0xafc498414180294c,
+                    0xe36368a2e08b7d26,
+                    0x327da4c12e9d7cf9,
+                    0x0a3e886bfadb1d97,
+                    0x49fa84ea7f565c54,
+                    0x3d4ced575b13d5fa,
+                    0x66e184c827e64eb1,
+                    0xee625dc13a0d550d,
+                    0xf7e526d1c1551325,
+                    0x00e21a8b25fcc516,
+                ])),
+                Felt::new(BigInteger768([
+                    0x694c8392c768123a,
+                    0x6b2d585d27ef4213,
+                    0x34e5a3101ccb57b4,
+                    0xbeb4f0e1f0af568b,
+                    0x70cea988498a447f,
+                    0xb59929da6fe4928e,
+                    0xc1b3e67f58cc7992,
+                    0x56e873b7c61afb65,
+                    0x6a159b0de3a47f74,
+                    0xe548dbd871419d74,
+                    0x2e63f30ee2bed643,
+                    0x00a414caa74b7e6f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x672a1df4107508e6,
+                    0xd09f0b0cb447d1ea,
+                    0x08114e235c2f9d30,
+                    0x4cd8f661417dd80e,
+                    0xca430b5966f70fce,
+                    0xdd92e9850e8beb83,
+                    0x9838b22f2a5a82af,
+                    0xd5023575b311a76e,
+                    0xe44916830ee2fd4a,
+                    0x6b1b2a37e26ae1d6,
+                    0x4f74bf948d3cd32b,
+                    0x00a25f8547816c8a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd067f30910f9a25,
+                    0x414049000d6815d5,
+                    0x0a4717c8e6562ec2,
+                    0xabd95568ac4a83f7,
+                    0xcc8c6367f8e629a9,
+                    0x27101c1e279c09a9,
+                    0xd42f9d5ff60d48ce,
+                    0x4b4f5fe23b534b43,
+                    0x42964653f7c273b1,
+                    0x954ed0a26ce45dfa,
+                    0x10d701a0609b764d,
+                    0x00fc4c6be01d98a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f736c14f453af20,
+                    0xc830e2009e0de36c,
+                    0xd7459a5e88cd89bc,
+                    0x615b72bc59dde858,
+                    0x4f32f5fad9d297a0,
+                    0xa4a7f802683dc52c,
+                    0x258ed3d17941dc71,
+                    0xb143ff3f2290f66e,
+                    0x7bf443cf644eee1d,
+                    0xb15f6ea52b16d72c,
+                    0xc1b72a168511b323,
+                    0x007828f7167837c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4b449c88702c0d8,
+                    0x459506292547211e,
+                    0xe0efb712f642b9c8,
+                    0x6ddcbbdd9a76fa53,
+                    0x3a6f3d1c64e92024,
+                    0x2c583da40ee9838b,
+                    0x8dfafa900b0ef3b8,
+                    0x86427d6a25c2dd91,
+                    0x2c9c9a21eaf7d790,
+                    0x75eb1e82ca00bc85,
+                    0x6438ef41127f9aad,
+                    0x0036c4de3874f989,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd011c8b3eb8e93e0,
+                    0xd9429583076a13e0,
+                    0xfec0f194af0df425,
+                    0xa317fa0264d9ff11,
+                    0x343d5ac236f8f187,
+                    0xd93c2975a061f3e4,
+                    0x1c1a01b069751a4a,
+                    0x6b8f4857f61f1a19,
+                    0x19c5260d111b6a7d,
+                    0xe5d81581a6488e08,
+                    0x6844e18f4c835ebf,
+                    0x0079e79522a29bd4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf1b924e44855866,
+                    0x89655cda2c4b91ec,
+                    0xd9b63707b21dde51,
+                    0x0cd34014124de34e,
+                    0xf53a592efd21fe33,
+                    0xe2c1e6b412f53b24,
+                    0x175745af5920269a,
+                    0x3ddab71121c76220,
+                    0x4727cb9c7d263882,
+                    0xb8c981c7d2979cba,
+                    0xaf2fb3fe7805a964,
+                    0x003cfdb2aa7135ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc33836c222f8b365,
+                    0xc985dedf7e165e2b,
+                    0x75a724d46b36142e,
+                    0xc9622676930ce242,
+                    0x7ead87af30430aaa,
+                    0xdef8068de88fa460,
+                    0xdab7eea160220123,
+                    0x3ea43822cc88d3f6,
+                    0xe9772fe11573253b,
+                    0x8396c4c65b3db6c4,
+                    0xf8a3f2b19645c3fa,
+                    0x011877b1926d0ec8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4bb9997c0dbb6cfa,
+                    0x251089ae0d204b3d,
+                    0x86a1916ac39275b4,
+                    0x938f0f462f152a24,
+                    0xd016c38f75c5d9a3,
+                    0xe6afbbdc009a8161,
+                    0x18ff5c365a3bea7a,
+                    0x3e7eb40da685e479,
+                    0x56a33471e506b977,
+                    0xe4ca689d362f8be8,
+                    0x14583a26cc846029,
+                    0x00f36eec7606df1f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc4ee053033f38354,
+                    0xc72561ad87b8e6a1,
+                    0x781a0b27610fcb72,
+                    0x6abacaf48909793c,
+                    0x7cbd10fdd2d3bee7,
+                    0x801db2ec18063d6d,
+                    0xf6bfc80dc261a8fd,
+                    0x6a96432e3fdce122,
+                    0x4bafe32f834519e1,
+                    0x6e5a74b62e41ae37,
+                    0xfa2371e4fbccbb3a,
+                    0x00e406eebb830371,
+                ])),
+                Felt::new(BigInteger768([
+                    0x571ee7199ae875dd,
+                    0xfb254b3b87f41599,
+                    0xb5c55ff77f5d7252,
+                    0xe06de6c9e2f5b540,
+                    0x01322166207ec3c4,
+                    0xfc667d52507bcc49,
+                    0x235940df9ed71b75,
+                    0x8a234aac21e9db4d,
+                    0x3aedda1cf580d2cb,
+                    0x1ffe703568b00b2c,
+                    0x1de470d9d6d0fd58,
+                    0x005ab3b390da89e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x33a1b361c77ea4a4,
+                    0xa0234b3b1d70e87e,
+                    0x80ba02977de09756,
+                    0xc2a33514d119f5bb,
+                    0xe5ecc86c3ce32898,
+                    0x71d680a853e86af1,
+                    0x8b0ad13a29559ae0,
+                    0x344cc925a10b7e87,
+                    0x86ed70b856b6ef74,
+                    0x3adc17a40e48659d,
+                    0x5cd9e892c18dd7c1,
+                    0x00bb548da7605c3c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x286b149a472522fe,
+                    0x291c94195d563e53,
+                    0x48484bd2acb49293,
+                    0x027d47283cd22ccf,
+                    0x11d1161522529f37,
+                    0xac17a656b6a07ad4,
+                    0xe506d1a00270a0cb,
+                    0x9b6312ec03f31064,
+                    0x783f01faf674f0bc,
+                    0x3eb9d608a5a2b474,
+                    0xbce59ee0cc44c9eb,
+                    0x007258eb9c63772b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb4018fe187d53d56,
+                    0xc1491961df72cd75,
+                    0x41c4279237a87726,
+                    0x9417738829dec750,
+                    0xcd95e8822bf3fbe3,
+                    0xe8aed0a5bc0a5859,
+                    0x8182f3e82ec381d0,
+                    0xe145e69c1e68dca9,
+                    0x947a072224fceddb,
+                    0x2dd0bdb541c19dde,
+                    0x4fac0f7f12892f29,
+                    0x0107597097a400e0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x784fa01b7fba0f92,
+                    0x7eed2d08088594c1,
+                    0xadc358bd3872f186,
+                    0xe2cbd58bf63085bc,
+                    0xe016b6a8335fcab8,
+                    0x0d83f8b6300ca476,
+                    0xfd54adbfadd6654e,
+                    0xc25e8076a5ab91b5,
+                    0xe74754bf4de134e3,
+                    0xcf2dbfe699180511,
+                    0x6649ece6eda49637,
+                    0x00f64f8a3fac836c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4dac1f900fa1208,
+                    0x8880f8ce1bfbc5e9,
+                    0x36914d9ae7e7871e,
+                    0xdd51a987b4049873,
+                    0xaac48a034bbfe066,
+                    0xbc8eb992e4a21135,
+                    0x9715cc1a01cffea5,
+                    0x86f76c74df4a3f89,
+                    0xccae26850a798f89,
+                    0x673c3b346caf0ca1,
+                    0x04eb9a4e358d8754,
+                    0x007e56d62e20d886,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2d3e666b8d505ce3,
+                    0x4f27792334a2803d,
+                    0x357d2068d9a97633,
+                    0x8c5d416e15562b95,
+                    0x87fc58d0debe739e,
+                    0x99e5562a3f2373eb,
+                    0xf1db341372a95e6f,
+                    0x8bd3932a7e3b320f,
+                    0x72ecdbe046567342,
+                    0xeee973b7c519fba9,
+                    0x252227a254f72c4e,
+                    0x01109bd7b9cc8966,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7d97d3bdec881e49,
+                    0xee8336a828ce5fb7,
+                    0xcaa68ed17e132cbb,
+                    0x45dc3ecf7be0c185,
+                    0x73fe9389ffa1547c,
+                    0xe5c4373b3d7f9536,
+                    0x879009f05b1c602e,
+                    0xc673ad6ae912c3d2,
+                    0x19db7e3788436ef8,
+                    0xc3ea024750743cf8,
+                    0xb67a04f6ca0f151f,
+                    0x000b8f2837381464,
+                ])),
+                Felt::new(BigInteger768([
+                    0x99ba2cd434c312af,
+                    0x9f6cc81f53666313,
+                    0xa35271378269451c,
+                    0x5906e1d26b8477ff,
+                    0xe795e048cfe53284,
+                    0x08b4e8601e3d3577,
+                    0xe1aa4ce0091ae678,
+                    0x86bdee8d0d4af2f3,
+                    0x01bab1ddd941e428,
+                    0x4c381311d441e954,
+                    0x7e0f23482bb5d553,
+                    0x01203b8f0ed5dab1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x86fcea328baaef6d,
+                    0x3b8d937b69741aff,
+                    0x145b4a5b5ea260aa,
+                    0x960449df21efaaac,
+                    0x2f13da80e4646c6c,
+                    0x390109b7947001d3,
+                    0xb9cf485737447bee,
+                    0xb7cc20ec44c9e04e,
+                    0x77654e6f768f348e,
+                    0x786af8cab7dd5ed3,
+                    0xd8b08cdd7e75fef9,
+                    0x00044470acb910db,
+                ])),
+                Felt::new(BigInteger768([
+                    0x702f9964cc3cca44,
+                    0x2c4cf9604ccacefd,
+                    0x531bea13a0676849,
+                    0xad23a3921cd87544,
+                    0xce51b81f86c25c57,
+                    0xd5140db351baeea9,
+                    0x8aa151ab1ae06a9e,
+                    0xc312736c0d54748d,
+                    0xa9c172bbb558f3c0,
+                    0x846e07e76a9e44c5,
+                    0xf6e76ea59b15bfea,
+                    0x0074ed62932df337,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4333c555f48a1002,
+                    0x7535580463734d89,
+                    0x0d04439826a014b8,
+                    0xb16e28bd5ef51e7d,
+                    0xf0d001266819b74f,
+                    0xf919757fecb500f9,
+                    0xdadbbdc53aedb8f2,
+                    0xcf0f65026915cdc1,
+                    0xe9c31409f173b798,
+                    0xd01b3cf36422b51c,
+                    0xb430a28e302773c8,
+                    0x00e50fcdb598d58d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0de74be741427cf4,
+                    0x40f823d1adffc463,
+                    0x05455098e3fe705a,
+                    0x64e34a5408aebcc8,
+                    0x7322edba29c47d5d,
+                    0xd25ffe9c89c3d5ef,
+                    0x9abdc21e0571d9c1,
+                    0xbdef622f721ff7b4,
+                    0x13446241e09c1504,
+                    0xbbf692c140d4bd02,
+                    0xb0cb73134a1f1dc3,
+                    0x0083e5fce361f832,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9315c082c3db6228,
+                    0xe4daf46fd529223b,
+                    0x353c20eff377c282,
+                    0x3f39e23e6642bcf4,
+                    0xc4b44e84e500df9c,
+                    0xf3d8cdef97cd6b8b,
+                    0x1f43a621d0d9e1ba,
+                    0x6ad5cf89fb935e22,
+                    0x689b18ea3cc24cc2,
+                    0xef0e5761ac6b47c5,
+                    0xbcf47135aaf56ae0,
+                    0x00c89b958976b194,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa8cc0af124690d2,
+                    0xc02ff68df35ab17d,
+                    0x663976826b5900e5,
+                    0xbf47492ed8e17a34,
+                    0xf668c28c0ee97a91,
+                    0x7b60f67984a7de3b,
+                    0x58ba772137ee70dd,
+                    0x7771ddfe81202f80,
+                    0xe29d474f4a6be64f,
+                    0xc88a7d8f7ce83772,
+                    0xb89caa647bc27b14,
+                    0x011600b7f02ed464,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5fac102b08d2adbc,
+                    0x7ed677756a70b466,
+                    0x752a1d0898819cf9,
+                    0xa79f2ffc8937e611,
+                    0xadf28189abf0bb34,
+                    0x6180dd7b8f142c37,
+                    0x6201328a41327c8d,
+                    0x0f4933269e5f2e77,
+                    0x6990450283a209b2,
+                    0x285d85541e353500,
+                    0x489dcd62d06caf57,
+                    0x000eb287deef99a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb495633a32301903,
+                    0x0be2fec6952fcf95,
+                    0xde43dd2df1d25ad6,
+                    0x76ef3662e41d3bb6,
+                    0xa2de6943f356a333,
+                    0x7ce4cdc9e19ead2e,
+                    0xf49e6c8980401bf9,
+                    0xbb68f5544cb34460,
+                    0x29d4f83c0b5e4219,
+                    0x25fa60af7c51c500,
+                    0x86973a4cac7e1fc8,
+                    0x009487b2b8541326,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf5b6d46f2d96f05c,
+                    0x4a651b5c296e68c2,
+                    0x5876069725a03b6d,
+                    0xd998625d49ad916c,
+                    0x62010188846e616b,
+                    0xb284dbc6be661ab5,
+                    0xbc3e489eb5f88409,
+                    0x75a23a52b528b23a,
+                    0xf6e16214c908fb46,
+                    0xcdde76e5a8364108,
+                    0x29c0182cb9f90b52,
+                    0x0002e2783b550125,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4f13e0c4685d8c38,
+                    0x10af78c080381fad,
+                    0xa62aed1c2481bf73,
+                    0xd0205cdbcd073d57,
+                    0x6ebdcc57934506fb,
+                    0x94b434349334efb9,
+                    0x509378b36ce9b358,
+                    0xa99b978e88da3a49,
+                    0xeb15b0f4306b0525,
+                    0x9881da2cc3166ddd,
+                    0x83ba38e8ece4268f,
+                    0x000716618c12064d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x968c2d2603a95922,
+                    0x935bef739241a853,
+                    0x08bc333ade479ba1,
+                    0x49a88564f152acba,
+                    0x6c96c5f00a32f572,
+                    0x236c005159f22d64,
+                    0xfea4969cd6989bf5,
+                    0x730076fff031a4d0,
+                    0xe929222858e51eab,
+                    0x9db3b9ef4d4e15c4,
+                    0x1836490aecb16dcd,
+                    0x00d9f71d7847ca09,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9efa6920b458171e,
+                    0x40283e5287669d70,
+                    0xcbab9b6fb7454fc8,
+                    0xea2c6b2caa776e8b,
+                    0xcc2f6d772497f6dd,
+                    0x98f15482b7625445,
+                    0x23743ef75364e75b,
+                    0x7aefdf6206a86e0d,
+                    0x561ac25fd6e6d338,
+                    0xb8d8fdb3b74ad7b0,
+                    0xb83c990966838a66,
+                    0x00a39ab0ed7e9a9a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc708daf3aac2ade3,
+                    0x3b2b8aa2b597c678,
+                    0x3a93a8ad3a68418e,
+                    0xc0a5fd3cc4f3c5ce,
+                    0xc6d1f7b076310028,
+                    0xa18843399d16368c,
+                    0xb5793556f0a743bb,
+                    0x8d58bec37b6929dd,
+                    0x615196ae0920995a,
+                    0xa42f3bee2bb50e21,
+                    0x908dbbbb16f6d6a7,
+                    0x00b3c4e9d2bf2118,
+                ])),
+                Felt::new(BigInteger768([
+                    0x24d2e5bbe469cf50,
+                    0x9981b5681f3a951f,
+                    0x29b0f08cf3627e34,
+                    0xc13c380d0343ce85,
+                    0x89a647fb38b9cf36,
+                    0x54468e0c52fe1c8c,
+                    0x4e7789cb2fc1aad9,
+                    0x523008bc33dd8fd2,
+                    0x0a31f97ec21f2476,
+                    0xe1ff19b48c4fbd9d,
+                    0x900c4c16a6658344,
+                    0x00b86fa505be633f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4324ea2238bf7420,
+                    0xb9f6375c18f33946,
+                    0xa0af0dc4cc4b19f0,
+                    0xc7790fc59c727137,
+                    0xaa635c83c73b0b50,
+                    0xc6f9e0824f073e24,
+                    0x30227719354523bb,
+                    0x1ebd43c303365094,
+                    0xb5308d9b71b55ca9,
+                    0xc7c7a22a485e35b1,
+                    0xbb46a327d28fd710,
+                    0x002533db83bc794c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfad9921a5e389eff,
+                    0x6031ee8728ba2c5a,
+                    0x25a85a86a0e08279,
+                    0x3d2ed1bdd982901c,
+                    0xa87c49e237997b8a,
+                    0x55ff03d19672f1fd,
+                    0xb56c340771b40094,
+                    0x37eab4c4205dabd8,
+                    0xd393d1333f1e8ecf,
+                    0x8f8b4faef5048858,
+                    0xe9b3505be8e79763,
+                    0x0054b99704995b83,
+                ])),
+                Felt::new(BigInteger768([
+                    0x54ef28bc4d3d164a,
+                    0x170770abbd0c1597,
+                    0x7b662b6025a97d9d,
+                    0x4e228674b16dccba,
+                    0x0422a33c5dbd9f34,
+                    0xd8e794ddc9cac49f,
+                    0xc4282f7011869a57,
+                    0xcc193fe3f807e7f8,
+                    0xefdfa7229042e315,
+                    0x0d23ea18c5b81e87,
+                    0xe4edf781eac747ac,
+                    0x009236334ed04e38,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03aa58439875247f,
+                    0xe567d2bfe0d9361a,
+                    0x21d0fbdad55d22a3,
+                    0x1a7c32a2e96d1783,
+                    0x28c19c027b47ac35,
+                    0x8f279bc1d9d872b7,
+                    0x215fce46c92a1f2a,
+                    0x3021c0125290b192,
+                    0x1279222761d1c29b,
+                    0xf4b93e379b45a1be,
+                    0x12c7e46626d74c3f,
+                    0x00d8e732c4e38096,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe55173ce330c070f,
+                    0xd28aa10288ca9919,
+                    0x67a2fb7e030b82c2,
+                    0xd9d99ba528184a5f,
+                    0xb30404aa983f1e9f,
+                    0x3800e5745a47bc94,
+                    0xa26ba3ffeeb2c114,
+                    0x28c46491cf68f1f7,
+                    0x0a1cf69baeb58e30,
+                    0x839ea5ca66d64fdd,
+                    0xdcb4e62942707d6d,
+                    0x00ffb4347e57a608,
+                ])),
+                Felt::new(BigInteger768([
+                    0xef1a9de7db644fa9,
+                    0x125e640bb07404ca,
+                    0x85b93d8d48496d27,
+                    0x7c63761df49c913d,
+                    0x27ae28762aa4fe0e,
+                    0xe70be37ea2866e5e,
+                    0xb6d4ae77e10d9522,
+                    0xc0c1272cfa2ceb48,
+                    0xc68f710d56e38fa9,
+                    0xc0a6f355630411ce,
+                    0xaf530aee4b219bce,
+                    0x00531b859ac0a6b2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf46d8ff22bff54b9,
+                    0x6dfd8ee7be2faef1,
+                    0x165ec6309c031615,
+                    0xd98154b3628255a4,
+                    0x557d32513134e880,
+                    0x23a12ebcea49c2d3,
+                    0x629898c277902775,
+                    0x46db70b3162b3461,
+                    0x432505009851c5bd,
+                    0x06fe60f73485bd36,
+                    0x9cc2f0cc3fe968bd,
+                    0x003671a2c012ae89,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdac2b7c5baf21546,
+                    0x947c105cdb1b299e,
+                    0xc5745a1932ee1ab8,
+                    0x57cb3bebaad343ae,
+                    0x96349685cf828a66,
+                    0xf9c91ba9ef51f5a1,
+                    0x00523eb891249472,
+                    0xcf1dfb4a48657ce4,
+                    0xe919b62a767cf3ba,
+                    0x856e3c6c0b6c350e,
+                    0x836312d14dd31bd5,
+                    0x00e86522c948c40e,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger768([
+                    0xb327e0146f8de98f,
+                    0x0ace1ae2faea3839,
+                    0x78aa93e4e096b9fd,
+                    0xed1b70c63635442a,
+                    0xc85c72da559604fb,
+                    0xf855b2d84ce29619,
+                    0x6c527b16f623276e,
+                    0x09e90f66fe1fbd7c,
+                    0x4fc13d168f05a0ca,
+                    0xe9d10491f70f357b,
+                    0xf1b5d378a3f64f3f,
+                    0x00886789cfb28f32,
+                ])),
+                Felt::new(BigInteger768([
+                    0x84beedd9db48dc40,
+                    0x191f73ba4bd29b61,
+                    0x68a9e2dafe8eb19f,
+                    0x688fb51a3a968c4e,
+                    0xafc664cd868a09d1,
+                    0xfa90f5d11a8829e8,
+                    0x4c6f17c37929ac89,
+                    0x4a5aace419322754,
+                    0x847417c7029f503f,
+                    0x0ee409bf7658d296,
+                    0x2efc8756dd7bb229,
+                    0x00ae887c66d56977,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd644bf7a6ba5cdd3,
+                    0x790a02b38bd4dc1a,
+                    0xd00a8c0f88426472,
+                    0xc3e9b76596fdc0f5,
+                    0xbe8a82a591d66673,
+                    0xf629eb63800ac854,
+                    0xe5c699281c44a662,
+                    0x873f97e9705f4dc2,
+                    0x6004f7b86b75b433,
+                    0x7f1569fb04c86fda,
+                    0x104e2ed7818231a5,
+                    0x00b495c9adc63cde,
+                ])),
+                Felt::new(BigInteger768([
+                    0xff74955de6187ceb,
+                    0x901cb07c27ed44c4,
+                    0xf8c8c549b8372b5a,
+                    0xd5fe036b36df8f3d,
+                    0x87827e1fdaa66798,
+                    0x7899a570a8c0a029,
+                    0x396bd49007fdce2f,
+                    0x42428b9fef1e2d72,
+                    0x28e69194d5c7febd,
+                    0x435c57709697021e,
+                    0x8f822acb9d94422c,
+                    0x0073cbcfc8f01dd0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd7b92f726c88866b,
+                    0xdaa7418b57ec600b,
+                    0xfc356ad118cb699c,
+                    0x2809e3642b9be73f,
+                    0x37142434807e88c0,
+                    0x6ba736a85d0fafec,
+                    0xf103ef1c4e423ad2,
+                    0x5aeb92bc6634f42f,
+                    0x8687ad9080dcc863,
+                    0x852a354d2b5427b5,
+                    0x6ef9884061fb0402,
+                    0x001aad6109f24127,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8143e713c980ea65,
+                    0xf5b8bb1ee6a17434,
+                    0x8e2c256571975771,
+                    0x01f142ad45e08d43,
+                    0xa5e7401038ec5267,
+                    0x24690b17b78b039b,
+                    0xb670658cc857c69b,
+                    0xbc0649d9bf69c60e,
+                    0xf1b431843d6c97d4,
+                    0x85672160b05629a8,
+                    0x86e78c09e1fa25bf,
+                    0x00aaa7d386f1e7a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x21e6355a27177129,
+                    0x05672f36dd137fee,
+                    0xff7143dde0af0d6b,
+                    0x46e55abba2e52ec3,
+                    0xa797500f241c89a3,
+                    0xf5738295afe0c145,
+                    0xb787404a485cc0dd,
+                    0x3bcc355f0df27031,
+                    0x6a6240e4f7296676,
+                    0x02669497778ea88e,
+                    0xf649abc6b4371cad,
+                    0x00d63291425520b7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x22c5e4f588edde88,
+                    0xe50932d0074c2437,
+                    0x3260fbcfe77cc095,
+                    0xfed4f5320df700bd,
+                    0x1d7370e49bc57d2c,
+                    0x9cdcaf355a81eed6,
+                    0xb73aa82f1cef9ffa,
+                    0xa2530f3c77fba751,
+                    0x3020c8fe66cde045,
+                    0x961af25f3745a26b,
+                    0xa8717595c692c692,
+                    0x00316dfc0f1820a8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1c182bc0ad1cb976,
+                    0xad75895f2125cf56,
+                    0x8499979c1d45b52c,
+                    0x93747dfc480c868f,
+                    0x6047a13a852cfaa4,
+                    0xb0b877bfcecef4ee,
+                    0xe3fbff777bdde97e,
+                    0x50f5c8e69dae5ef1,
+                    0x1844e713b728ec0f,
+                    0xaefc08a83ae4a6be,
+                    0xd998185a44968273,
+                    0x0040e75a87ccdc25,
+                ])),
+                Felt::new(BigInteger768([
+                    0x188d9e5fb801587c,
+                    0x78d3cd5cfc274eba,
+                    0x921c8ca948e334f8,
+                    0xcd49fdc640b01a54,
+                    0xb77d56063fda822c,
+                    0x18b572d4348031c3,
+                    0x57fa9be924efddf2,
+                    0x9cd586627bf5c789,
+                    0x4beb361260582da3,
+                    0x70aee360c9891de8,
+                    0x2411164dcca98056,
+                    0x0113623dc05f0530,
+                ])),
+                Felt::new(BigInteger768([
+                    0x026093aa358bce0e,
+                    0xfc484f6c4baee5d5,
+                    0x446e6f4b81e89129,
+                    0x2793188a38bb8263,
+                    0x084773e68f8a19c8,
+                    0xadc656b683550b36,
+                    0x773a6e307e64f851,
+                    0xebc35d0c5f3030ed,
+                    0xd559c3a25e36d281,
+                    0x39e78a559010782c,
+                    0xef42a8ec8ecc029f,
+                    0x003eb21c12d80326,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36a7fd2cdeac37a1,
+                    0x5e964757b47205ff,
+                    0xbea81c41e00efed5,
+                    0x488a63cccdf70823,
+                    0xf2ea541159105df2,
+                    0x39fa0d244f81e7a4,
+                    0x83e599b1f23fe413,
+                    0xfac6516792bf7da1,
+                    0xdc619eb0df9e5525,
+                    0x980ebcd1a81da0fc,
+                    0x389608712ec3fa93,
+                    0x005c184f0e9db1cc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x12e50bdfa59eb881,
+                    0x214674343c701a2f,
+                    0x646c2e317a3f95f2,
+                    0x1b575fe4959172c2,
+                    0x23d7abc3062a949e,
+                    0x833b945429b9066b,
+                    0xeee0ebb03fbc5938,
+                    0x735ba4d5c2db1bf5,
+                    0x06a717ca488b0ef0,
+                    0xa2bea92fa59e4719,
+                    0x58925c0b158c8774,
+                    0x00c832b49a13f53a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8514675fc32d6258,
+                    0x539424573be72d7c,
+                    0xa306cfc0b5f08e51,
+                    0x998fa63c3334ceb8,
+                    0x8ffba06ea4724a20,
+                    0xfad7192b20a07ae9,
+                    0xcd90659ceae9f6e0,
+                    0x7775ee3bed90eb0b,
+                    0x7d4f54117fc66c80,
+                    0xefe7a0fe3b577c20,
+                    0x2738457f28fde8ab,
+                    0x0100bc417eec5e44,
+                ])),
+                Felt::new(BigInteger768([
+                    0x03526c68f74cb63b,
+                    0xe5a21af82b40007e,
+                    0x12dbab7bab8883aa,
+                    0xbd2483ea9536dccb,
+                    0x4d64524763d3e0d7,
+                    0xf87841f17bd0130f,
+                    0x13c5a1727b9be29a,
+                    0x04a592bfb2e5950c,
+                    0xcd3734a5f7928be5,
+                    0xab78f6b7c825b541,
+                    0x5690cd5d5041e964,
+                    0x00a63dce996fa8c7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2b207e97e999dfd7,
+                    0xc9fca00c2abe5c64,
+                    0xa457e5dc961e4d06,
+                    0x17f6261dc8bfa98e,
+                    0x93a062aba493b0bc,
+                    0x926bc1b2c84f83b4,
+                    0xebefa5967cb48170,
+                    0xcf2f948cb1c6826b,
+                    0x70ea306770632e5e,
+                    0xaf960c3e0e0be87d,
+                    0x794fa0948657594c,
+                    0x0049d58771e16d8d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xda0bdd2fa8071b1d,
+                    0xac03ce1f53cd9674,
+                    0xd55f85dcbe3076cd,
+                    0x32e7ea124e68b5d6,
+                    0x9919fb0bc7fde90f,
+                    0xd72703a61b32d5fe,
+                    0x0847b12aac11c691,
+                    0x6a7b034b2546b100,
+                    0x7ace048bcd5eaaa5,
+                    0xdd4e000bc72f4215,
+                    0xf215b057025d3299,
+                    0x0032b7903634452c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xab82ae0a536b4cdf,
+                    0x43e43ac0bdcf29ae,
+                    0xbc81d8405b9efaed,
+                    0x18fbdc43aa687876,
+                    0xcd1de280cd3dbe04,
+                    0xcc2e265bb1aa3f20,
+                    0x41f22724fa381dfa,
+                    0xe312f7203860c9ba,
+                    0xe5fd90238a2cb5d8,
+                    0x2061423bf2ddeec9,
+                    0x75692bb966c0007c,
+                    0x004c6d14d6f7182f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ce39c07b0c73b33,
+                    0xe45b3d1658471881,
+                    0xe9e9e62a4d2d8791,
+                    0x8b3a0535e8052b39,
+                    0xd3e4c9159666665a,
+                    0x270a4e97bc43bb2c,
+                    0x0890c5c93e8c549f,
+                    0x17539423883e8617,
+                    0x4d5e7af2ca5b2651,
+                    0xf1e346f54a12b1b0,
+                    0x7bee920c40a81418,
+                    0x00ebc551563533c5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa4f672da9d23e878,
+                    0x4660e1a42aa91898,
+                    0x8b53e83654bbfb1b,
+                    0x596fd6754515ebeb,
+                    0xe09748ee0ce1fcd8,
+                    0x5f37f52b39a75ac9,
+                    0x40ea3c7b772989de,
+                    0x47be17001442c89e,
+                    0xefd96bd566acbb04,
+                    0x33a82c60de53743a,
+                    0x977ed9abac8ffd75,
+                    0x003793ea78891fc9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc41f6bc80fd1fbab,
+                    0x4fee73c6ae076180,
+                    0x7ea8805d79c4e12e,
+                    0xccfcc451110e11a7,
+                    0xd36316e754b4ea38,
+                    0x6d47cd74f436effa,
+                    0x39fb2d85f4776e61,
+                    0x72e728300e608511,
+                    0x6ea68d8e06a5403a,
+                    0x92b00e918befdc11,
+                    0xe25ce1860ce2285c,
+                    0x00f552a4f7672b28,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01215d547315c74e,
+                    0xd024c9a087dad218,
+                    0x45c39f3ad77a8b8d,
+                    0x816be89607c9061c,
+                    0xc74233b8a4a2f603,
+                    0xb8ffb04ec50b0b5c,
+                    0x9d00613568ac6402,
+                    0x1574c0a8c2211e8e,
+                    0xececca9480ffa17c,
+                    0x940b82ec8229db87,
+                    0xbc45ddaf5c5ab28f,
+                    0x0000764cee437de7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2022fc545ac2c5a,
+                    0xfd37e0f35d318142,
+                    0xbc0876b3eddf3227,
+                    0x2b144b2e23f538b1,
+                    0xe708f5be6d9862fb,
+                    0x68063d7a5147fcc9,
+                    0xbdd7356d5ea6a56b,
+                    0x338cb465f5e9c2f9,
+                    0x5d0c0a6261ab039d,
+                    0xfd6f69ba38948d87,
+                    0x9d946e0c99cee7ba,
+                    0x00ce46d70cab22f3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa241807f880f7943,
+                    0xf4c53dc91318db48,
+                    0x89a5caec72b29472,
+                    0x07387158b3cb6e85,
+                    0xc2cc473fb7045007,
+                    0x3726a20c1bb3f64e,
+                    0xa0b7bc2ba93e12e3,
+                    0x14c9e389b40210d5,
+                    0x8f4dd4e94a1dec50,
+                    0x8bbbbd4c4fcecaf1,
+                    0xa4a869f8424474ac,
+                    0x000ada6296c642d7,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0608fffffffe917f,
+                    0x0f0872a0affea6b5,
+                    0xdbcc4aca877f009b,
+                    0x8f40fd962cad79dd,
+                    0xc6bd61ba58be2823,
+                    0x42b336e6f6d86c23,
+                    0xf5e8f13f9607ab11,
+                    0x69f8b7fad28d630a,
+                    0x720309801a155974,
+                    0xd08d9f2bb533068e,
+                    0x86f85c32de30fd9d,
+                    0x00f5e67cd377b979,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc808bbc8261fa184,
+                    0x567bc3ef228f6e79,
+                    0x33f160ebf2c37903,
+                    0x2599c43854fab3cf,
+                    0xb60c6898eebe1970,
+                    0x66edd58d6ef8ec5a,
+                    0x0161ef9ec2c25d7e,
+                    0x2e22c2f64aff230c,
+                    0x0626f6b513bc8307,
+                    0x4e72ffa661ef9420,
+                    0x7e2718e659f2b453,
+                    0x00c2905da1d99416,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2033656a27881ebd,
+                    0xdf85bd3020f177d2,
+                    0xba7fac03f924ff6d,
+                    0x1b2226c88e244af4,
+                    0xaa026222f01a3d83,
+                    0x41e2d7fee580050d,
+                    0x4b643c01d8fa4482,
+                    0x36248c97c9ab8453,
+                    0x3e3f8f41a990980c,
+                    0xf2a3f0a1b866b44e,
+                    0x59c2efe38c661b90,
+                    0x0110c112af04c399,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb7611452bd641806,
+                    0x9b9f777acf96fc77,
+                    0x82d0e4b9ddcb5007,
+                    0xc5305b740e736831,
+                    0x92baaf8e8a673551,
+                    0xde63b34a95dc3342,
+                    0x012a4be11ad2f7f0,
+                    0xe0461539ea57dfdf,
+                    0x966e0b162c2ba8e2,
+                    0x886112fc1e569b4a,
+                    0xde96bf3a9b9da638,
+                    0x00ba0f3e28f8c981,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5069e81fe66dfd54,
+                    0xc9b7b340bb02d487,
+                    0xbf9f5cd458e86049,
+                    0xb5a9578b5d7ce860,
+                    0x72230de632b8d1e8,
+                    0x2c67890b98c93149,
+                    0xe44c75eb2c367340,
+                    0xd7501c245f8a2467,
+                    0x3a5e97f5434547b7,
+                    0xd6b17788bfa45fe7,
+                    0xfbf3e40cb3c27ed7,
+                    0x0105cc21043401d6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe5a3fd99ff8a5aaa,
+                    0xdf877960b52f47f1,
+                    0x0280bced11097448,
+                    0x934b51fc60f558cf,
+                    0x1105d0787e6b2e5f,
+                    0x2def83eab92bb993,
+                    0x5a15ff36b93da504,
+                    0x627c8036ff839734,
+                    0x176d9aa97b2e39cb,
+                    0xd6d25fa40a2f347f,
+                    0xab40d71420f45042,
+                    0x004d14e31c52426b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x57eee247c8959f2e,
+                    0xce366f77e1000395,
+                    0x7d20a690a8b99bcb,
+                    0x8fe6cfe3b16395e9,
+                    0x56160d24c1d6aff7,
+                    0xf9be4a0016e87931,
+                    0x66cda8fb151eed9e,
+                    0x1083a40ed8ec0b5e,
+                    0xce0cf0e7b3414d2a,
+                    0x79e5c83e42e34c1e,
+                    0x0be170f21ee7c706,
+                    0x0021e974c4129e74,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71f54fa9d71cc04f,
+                    0x094652d551732390,
+                    0x1b368adcbdea3734,
+                    0xf3fe93e6fd59b54d,
+                    0xfac53d2670129144,
+                    0x0139eb73a74973d0,
+                    0x227a6dc487f4feb2,
+                    0xa96fd3c1ae5b9e9c,
+                    0xb262d4d8d498eef0,
+                    0xe1c20323a491acff,
+                    0xc37d03e32a0600ad,
+                    0x00baeaf9983e940d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c96d4add550f0b1,
+                    0x4ad158109ff9c609,
+                    0x09c4683986a23363,
+                    0xfda05504f0c9476e,
+                    0xb96822a31c69adcf,
+                    0xead3c7a2d87fadc6,
+                    0xf4dea5d50cde7838,
+                    0xadb490709f9fdb37,
+                    0x2a22feb79f4bc7d4,
+                    0x539e6a09bd0f757d,
+                    0xda34210cfe4d1f8b,
+                    0x0006383a7bcaacbd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x63f968801d1f6958,
+                    0xd1faa74839746bc4,
+                    0x7cb8bf90d92ad6ad,
+                    0xfcc64c041c9d6948,
+                    0xeb88a58e1d0a00a0,
+                    0xd50fcb8e11db5d54,
+                    0x8c53c14b7a555f59,
+                    0xa9a98939ca901bd2,
+                    0x0b16675976c9cad3,
+                    0x1e0d9e94ca54557e,
+                    0x7eaab275d3f384a3,
+                    0x0021db8c11834a70,
+                ])),
+                Felt::new(BigInteger768([
+                    0xebe36f5ff2402aa1,
+                    0x3637dd84ff1025bf,
+                    0x7581131c10483e1c,
+                    0x2e357f0c238343ed,
+                    0x1cb1cd4606028440,
+                    0x3c4b4103453ceedd,
+                    0x6cd36380f3e679a1,
+                    0x7facf29d2b911615,
+                    0x0f958ed5cd640509,
+                    0xf3a4081fdc60825c,
+                    0x4fdeb1f96f958a5d,
+                    0x00343130e7e1cc06,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b69248149c3d3bd,
+                    0x5726e6304bcfd1a9,
+                    0x4784fad8e3e02129,
+                    0x97b094e13f6e0cea,
+                    0x03793bde78249182,
+                    0x9586ba91562bfe11,
+                    0x725a7612ef09d4b6,
+                    0xd24d0b80511fdf7c,
+                    0x871525f59c0bfa29,
+                    0x0bddd17cc83e9156,
+                    0x171b5a4a4c5bffd2,
+                    0x00d97c87344db81a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8495dcacc91374fd,
+                    0xc404dd64734e88a4,
+                    0xbd6b6c4e5eb82f44,
+                    0xbe532b8a612ff33f,
+                    0x9d5d17c70be73d9e,
+                    0x524a02ec1cb309c7,
+                    0x58209f5736c47eba,
+                    0x1fe1cdd41beb06cd,
+                    0x9101999e15c70894,
+                    0x987d14e49ebc5451,
+                    0x4bf1944122890304,
+                    0x00cec3057fefa550,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x33d3da9f2bf97c1b,
+                    0xfb4c4011d43e7422,
+                    0x7e5fdc55103f6a24,
+                    0x1d347ed84f2bbe43,
+                    0x601544e767372a82,
+                    0x6bd634d04506011d,
+                    0x826440ebca2800f1,
+                    0x815034dbd2f31fdb,
+                    0x5c40d40e47086b6e,
+                    0x1041ba5718aab249,
+                    0x03f354895d925a4f,
+                    0x0083daeda0e515ad,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf81f1c9e1b205a03,
+                    0x639b66127464153d,
+                    0x89b98fbbeb7ebe63,
+                    0x3b578d2a7b24a247,
+                    0xde495660fef49ff6,
+                    0x4bf9b9de97b27f93,
+                    0xce8a2c755985d5bf,
+                    0x099ab1baadf241a9,
+                    0xe34e863e8d4f851c,
+                    0x5744fd41eadb9a2a,
+                    0x5d972551df279e0b,
+                    0x00e589f7c532bb93,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3d285769d8334bc2,
+                    0xaeaf9794113e5cdd,
+                    0xb4024425023eb024,
+                    0xf99e17309cc5ba2c,
+                    0xbc8c335df84f733c,
+                    0xfcd27ca8acf5ac42,
+                    0x0fac2d7c4e3823a1,
+                    0x67ff13d3320cc36b,
+                    0xb8d623c3df0087e7,
+                    0x4d61e1431c860c78,
+                    0x54e9720f21e02cd7,
+                    0x007368ffa93cf111,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89061b3ca7b34d34,
+                    0x5c700bb7ddbcb9b5,
+                    0x64d33b16c5153fbc,
+                    0x17bf9b89785aa82c,
+                    0x549fe526e086c823,
+                    0x6b937255c2c16e04,
+                    0x2f4f416bf25806da,
+                    0x6428142d317a32c9,
+                    0xec61063e350bdd72,
+                    0x8d16a0d37f6087aa,
+                    0x2edbe83b8f0743d4,
+                    0x006a27a8f065bcdf,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x311139444eddb4db,
+                    0x33cc43a0cf6be9d2,
+                    0x4e63365d756e6ca8,
+                    0x64dfa299a339a6c4,
+                    0x3355c00d62796517,
+                    0x6f83505d7e875440,
+                    0x8c2bff74a0820287,
+                    0x30a0f18b96084474,
+                    0x46c924d2e1b81657,
+                    0x1e108483aceb1fa9,
+                    0xdce807c9cfdd3392,
+                    0x00cb91b9e422758b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19082092f126b60a,
+                    0xc4eb8399eca6fcd0,
+                    0x433b4c4c5b8b3005,
+                    0x4a1a0ab49a741d07,
+                    0xc1ec8ebf1e448904,
+                    0x24aafe299bbeea10,
+                    0x567b6b0f15880005,
+                    0xd3d933fe7eec698a,
+                    0x210d63d9cd152445,
+                    0xa35c32d8cae2e794,
+                    0x5db1720d017f6861,
+                    0x00c0a863a3b8353c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a20aae9feb25e53,
+                    0x28861d686b374918,
+                    0x491abbdc04266607,
+                    0xd742ca1ebeb89967,
+                    0x1684073cc5c2ca5e,
+                    0x26c2c96d9f34d393,
+                    0x4e3d0d0ece8e1018,
+                    0x05e9990e3f3aebb3,
+                    0x3813ebcb7adb4965,
+                    0x5e48a1e0f0a79113,
+                    0xbdd4348428e52668,
+                    0x00ec3668296fb949,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa7eaf1dbae2bf6c3,
+                    0x6844fb164eb5a9ab,
+                    0x50056c847f990b30,
+                    0x087d6beeb8a70c18,
+                    0x1d332fd63a13fcbb,
+                    0x677c4f98d4b96732,
+                    0x9d6d69e890402ec6,
+                    0x422f79ce3baa8b4b,
+                    0x188166aabf023c98,
+                    0x3c2859e227bf46c6,
+                    0x54876894887c626c,
+                    0x007e61d7b20d0a43,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc6622670f5fd8a47,
+                    0xf0380707bdbd05b0,
+                    0x0a2fbed328b9f849,
+                    0xcfd4957d20a01de0,
+                    0x61e77e589dfad63f,
+                    0xdf472e127e4d06b4,
+                    0x73fbeff0c5d03666,
+                    0x9dd0a98192a2c22e,
+                    0x488022b708a48332,
+                    0xbac7146a90439321,
+                    0x5ae89ce4c5978d7b,
+                    0x00f63f3ed3ee26f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9948aa365f10db51,
+                    0xca0399479354dcf6,
+                    0x441624323dfdd7e9,
+                    0x8873fccdf2a198e4,
+                    0x19345c8d6caca354,
+                    0xf1044386aeab1b35,
+                    0x81298617ce98adcb,
+                    0xe09a6d7301e1b9d2,
+                    0x2b6a9a1311c0e99f,
+                    0x5073935720dfbb42,
+                    0xa08f4e38e28ece48,
+                    0x00ca0ca59a9bfdcd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x11a8e00a1367d16c,
+                    0x08a3c1475d7bdd4b,
+                    0xeba9bfa56380548b,
+                    0xc4bff0f2a54e36f0,
+                    0x1f015ee01e4916ce,
+                    0x80a95413ab49f252,
+                    0x18976c0740d73ab5,
+                    0x0b67a61cba50adb0,
+                    0xf6e373be8cec3549,
+                    0xcd7478563f269f77,
+                    0x0e497cd170e744e3,
+                    0x00847febb498bab4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe0b6efd96c7bcea7,
+                    0x27ee2519a8293b55,
+                    0x7d4efd4fc9ef3706,
+                    0x1bf316511829fd9b,
+                    0xb32edfa2363e916a,
+                    0xe2cf1cb494e1e3dd,
+                    0xeb37527cbaa0f7d1,
+                    0xd9e05a6685063540,
+                    0xf8507beffafbafed,
+                    0xdf3241fc351085ee,
+                    0xe89f9e6f76a27243,
+                    0x00b45e897a58ca50,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
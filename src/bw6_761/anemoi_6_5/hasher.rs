@@ -0,0 +1,3198 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U5>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger768([
+                0x5dbd177df7e2b130,
+                0x6a2f05280b6c35eb,
+                0xd342b445d4a94b0c,
+                0x3af91f0ea1299f7a,
+                0x31048524bea88d83,
+                0x2f5d3ddfd6a03958,
+                0x2b22b5238838f776,
+                0xeb3e3baad50cd875,
+                0xe269f894450b1402,
+                0x9c125bdd09e91f16,
+                0x53821418e3333e7c,
+                0x00f5e550fb5f2f4e,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x601731e5ed17405f,
+                    0x0c7c71fe24ae5863,
+                    0x7f96a0bd4b999adc,
+                    0x7089e5aec519214b,
+                    0x33d3e12ba56c99c6,
+                    0xf86cf4a9440b3054,
+                    0xb69ac5f7bd85664e,
+                    0x3b12c43658b7a035,
+                    0xb047bd9aa99d5221,
+                    0x8d71479e97ec30b0,
+                    0xe4651f33d63343c8,
+                    0x004f8fcb82b20e72,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4fbd5bab4257bd2,
+                    0xda1700151e03f0c2,
+                    0x8f5594a102c9b91d,
+                    0x4c722f81a5925dfd,
+                    0x8b3af7d74ce40207,
+                    0x41876f85397c990a,
+                    0x34d8ad5dcab1443c,
+                    0x3cb73a8d43a1b103,
+                    0x815226428724e64b,
+                    0xb864eaace0c120f4,
+                    0x042c2d48b9119bcf,
+                    0x004055efe0dbda0a,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x8e4c481170fe6561,
+                    0x09fddc30110c3605,
+                    0x625df6f1e708054b,
+                    0xb732e64891c0117f,
+                    0x0785e2e8f4224f3c,
+                    0xe2b2810be8ce44e1,
+                    0x48a25c078a280ef2,
+                    0x7fe691c08cb38b09,
+                    0x2c1f13ebd1cd4ac6,
+                    0x355cdeb8c37c5b73,
+                    0x6ad5ebfdcd9d5c1d,
+                    0x01037657ddcbf8dc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb721557ec819eca,
+                    0x585fc43436527200,
+                    0xc4939cd151967363,
+                    0x1ed81a18d19373ac,
+                    0x1be5d1f28a3df584,
+                    0x09ae01b6e0f3b408,
+                    0xa856310772e59e9d,
+                    0xfd205a976982cf73,
+                    0xb6b449cda4cfc793,
+                    0xb0ae657ca0772623,
+                    0xbfcf4da5e90dd228,
+                    0x00346033399e5d06,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9bce663bc7ab12f8,
+                    0xb2c44316cdbc4ba2,
+                    0x5ce30a1437469446,
+                    0x7d8355b6133343c7,
+                    0xe160538e0fa742eb,
+                    0x1ed591148aed87bd,
+                    0x81a7ae1f6de9912a,
+                    0xee1a245a5006f7aa,
+                    0x30c7451b6abdb934,
+                    0x6a2253cbb6b520aa,
+                    0xe0d47ac25cefb649,
+                    0x010739f60cbf07c4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x34a14aa24a70703e,
+                    0xc655791ecd098cda,
+                    0x0ef38bf82d5b25ee,
+                    0xf0f633e8d925a5f2,
+                    0x5867407a14acde5c,
+                    0x4618d53eede3c57f,
+                    0x6b46a4c3397636cf,
+                    0xab2fbb5208a925fe,
+                    0x441bb5275a42cad9,
+                    0x8b56d0ebd1157837,
+                    0x87db19977376c3f6,
+                    0x00cdb4642c2c1bbf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x11e662ae1025e200,
+                    0x91e577cd21e090b1,
+                    0x3a679e2b8e956317,
+                    0xa6fb5501d6004b82,
+                    0x6bcb80b73117fa5c,
+                    0xacf022030bbb47a0,
+                    0x810ed95b4bad9985,
+                    0xa8a4b8e15327baf8,
+                    0x5725a5c05c7208b2,
+                    0x7eb3c9f7d4439388,
+                    0x43f6ea22d4831cb3,
+                    0x00121e906be0c352,
+                ])),
+                Felt::new(BigInteger768([
+                    0x826f26ea84373b98,
+                    0x7d1b2835deae8743,
+                    0x49f1ea1e57945eb8,
+                    0xee85101262f909a6,
+                    0xcfced1e8971b7cb2,
+                    0x70fc6461f3ddb2a0,
+                    0xaafd0c39496686de,
+                    0x5b12e60eddff4b97,
+                    0x9f8d96910bd49cc4,
+                    0x80aa1ffe27493c77,
+                    0xe984c25e733ea020,
+                    0x00cbe9110e6733a5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd8545d730e690f70,
+                    0xc961b8b6c5fa5cf3,
+                    0x91fabe6697c5ada4,
+                    0x4614d896d0f4eb30,
+                    0xc7c2e157a007b5b7,
+                    0x81d9cae4dbd36c5b,
+                    0x9c9bbce60439edd5,
+                    0xcaa04e2b2f3a1ce0,
+                    0x054651124e95329f,
+                    0x7c6b801a2dec2b17,
+                    0x902ca0840e53ba8d,
+                    0x00cf6c50fe72424f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xac27ff13e5c00d35,
+                    0xb389c0d0e27a01b8,
+                    0xc7f9878a12e5632e,
+                    0xdd00dcc73287a0cd,
+                    0x30bf186fd1465ee8,
+                    0x3210175b8738d973,
+                    0x4eeac4b0d5ddabbf,
+                    0x6b0dde439e1d7b96,
+                    0x4acf1e304a3385d6,
+                    0xdbc0ee115770b8b2,
+                    0xd7d2c4f65df24009,
+                    0x006303ef2036d82f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x36d64cce3fbed2a5,
+                    0xab43dfa1e5c72322,
+                    0xd646c829e96bc0d2,
+                    0xc3f8d9af0e0ae9c0,
+                    0xca478e18030f0dd1,
+                    0x112323c60bc752c3,
+                    0xa034220d928bc1cb,
+                    0x2d21aa5c5300e95f,
+                    0x1eb6fb602d61d642,
+                    0x21c25a623cb90321,
+                    0xc6dee6d6342db985,
+                    0x00fc90a20bd81e66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x20244d86fe09ddca,
+                    0x507338fa6220c813,
+                    0x4d9b323436fbcbe8,
+                    0x239d75d0d4a3fa54,
+                    0x1eadeb2aeca2db5d,
+                    0x1c06438e58335f5c,
+                    0xb4232e17056310e7,
+                    0xc2adac0f942f8f6b,
+                    0x211a7a258ffbb26e,
+                    0xc886a09b388bf44b,
+                    0x77aeca20a3031f9d,
+                    0x00eb0318c1c57a9c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x151f65551db7f8d5,
+                    0xd96062054e643f53,
+                    0xe404251f1c1f3b47,
+                    0xfa07ffb1922644d5,
+                    0xb67467e3d2a97fd4,
+                    0x7639e8e458853e54,
+                    0xf9506d12b3636bd9,
+                    0xb1e86d6c7470e01c,
+                    0x1d5127bc3bd7f5a2,
+                    0xdafcec94e0e62a76,
+                    0x68f69fd2ce8e9edd,
+                    0x00be80b52e9a64fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x195c33499a2b7392,
+                    0x264d2dc6bb38ccbb,
+                    0xb9d55e98069b5804,
+                    0x20046a84ef407bea,
+                    0xf3808761990ffdab,
+                    0x38e2406a2a341fdf,
+                    0xa2bdfb886aba2a69,
+                    0x28b6c2f423d4cc37,
+                    0x337c4c7b87d2a9fb,
+                    0x9f9680a07156ac2f,
+                    0x99476a8af6d4e901,
+                    0x00f190e294429215,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x04acfd97cbc29c4c,
+                    0x7309e325a1638599,
+                    0xbae99ca07b666448,
+                    0x6079235794cc83ed,
+                    0x81c899639dd34a62,
+                    0x1435c9d94c7fdf25,
+                    0x08e50f3e4fe50a94,
+                    0x00803c7545ffd8ca,
+                    0x94e559aeef00b4ea,
+                    0x05dcdc0f4dedf5dd,
+                    0x57046325218cd588,
+                    0x00a65f0ffe1596c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x16ded684107676d6,
+                    0xe0f47928c2badec9,
+                    0x6fac3e99a46a4a08,
+                    0x5d93759ab99c60c5,
+                    0x0b35241c8d3cdba8,
+                    0xed1d35b21aecfcd5,
+                    0xfa93e367ccb6b4cf,
+                    0x9ea7e29d27dcd41c,
+                    0x1426bbcb2d123de8,
+                    0xa015d16376262cf4,
+                    0x7dc6edaa6dba4532,
+                    0x00ec34b9bd7e2440,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac40d6cf4076d874,
+                    0x0502be61d2307c00,
+                    0x62787263fa68fa48,
+                    0x50f52da75d350011,
+                    0xc53ff3e5975959da,
+                    0x691803548e78ea38,
+                    0xeba12fcb63677033,
+                    0x69a50bebe8a2c753,
+                    0xe285e5de5be23769,
+                    0x5a426ad8b0836e3a,
+                    0x3ddab8fdc13014c2,
+                    0x00c3e1c9d99a2c82,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5e04a583bf837863,
+                    0x3b1ca94512f33e18,
+                    0xbc1ad054e08ae584,
+                    0xc401fa9e2b7f7a65,
+                    0x3d6ea01379622e6f,
+                    0xc621a7b3defd16d9,
+                    0xaaad0424d443deec,
+                    0x9e91b380067d3b43,
+                    0x6a0405a396b0920c,
+                    0x85a710b8f10f958e,
+                    0xd4b1b367f5138587,
+                    0x01040d6799bd3c69,
+                ])),
+                Felt::new(BigInteger768([
+                    0xea73bd0a78480806,
+                    0x088d6d05d224226f,
+                    0x73c82fe6952115d6,
+                    0xab8f0fcc1674b1cf,
+                    0x398e5a5229ae22c5,
+                    0x0db0def264c59c1f,
+                    0x11547eb4fffad81b,
+                    0x33c27d1d6eb94dca,
+                    0xf3db4715ad661bfa,
+                    0x8c6bc5030598b35e,
+                    0x0851a60c6157d67d,
+                    0x0072aca7da8663df,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd96953eebe0e1b3b,
+                    0x0607f033ef72c2da,
+                    0x118eed920f7598f5,
+                    0x83b0371d76f0c978,
+                    0x4e2708885b0e2621,
+                    0x092331a6739d7ca1,
+                    0xd242cfa244f04399,
+                    0x663ece62cb0e4e4f,
+                    0x2ab486e82674e4af,
+                    0xd88ff11945b6a028,
+                    0x43fd84ce6acb7cde,
+                    0x0016eb3a611a31d8,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x127befe9f1e6672c,
+                0x430e26c64a1e6ad1,
+                0x8098d011587d4490,
+                0x8fb27c909ca2548c,
+                0x2b56919cd1598f16,
+                0x1a42c4c18263cd59,
+                0xc179b3e95ab2029d,
+                0x8acf6f457948e696,
+                0x77944fdb99cef371,
+                0x0be5b5d4d5a74e72,
+                0x7ec84a24804cc8d7,
+                0x001a7a2099e817a0,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb9c20176989f7122,
+                0xecb64f792d379837,
+                0x0d2cd8393f3cb6e9,
+                0xdf58c69829cec505,
+                0xa6a77db17561e02f,
+                0xf38c886fefba1470,
+                0x37d0fe3f80d4d2e7,
+                0xbd098e64bacca525,
+                0x2ed8fd0f6928e26e,
+                0xa81ac4c287b580a0,
+                0xb0b5f8f1f97494db,
+                0x006c722c9515da3d,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xc16ea59067b4671a,
+                0x9c66df5cd16a624a,
+                0x5700dce7d2cfc9c8,
+                0x8d82f075d04e45fe,
+                0xfcc0f21826b447f7,
+                0x19deeac2b3c734d4,
+                0x9fd332fd96393b64,
+                0x4e6b2783d73c6769,
+                0x8711e2a75992aa47,
+                0x79eee79df08c9161,
+                0xcb8f84f75a387591,
+                0x003fdb1ef1adf06b,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xea649d5f5fd20950,
+                0xd0faf0bf814db3bd,
+                0x47f0c51910a97fec,
+                0xb6142b234f7718be,
+                0xc81af1ed0bbd7e3b,
+                0x1744a38bd54317ff,
+                0x70c8ab4c4aa3079e,
+                0x63f777bcd2641a6e,
+                0x94deb8929c00bd10,
+                0x2bec67498959d3f8,
+                0xb586651ea715c199,
+                0x004a5775fc98a2dc,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe511878573123593,
+                0xbfbc8b96532cd1da,
+                0xe904e6f11c660fa4,
+                0x1e04b8db5d56d098,
+                0x37363b858f350453,
+                0x8f84f4c504a64588,
+                0xeea64ceedb1d8a1e,
+                0x347a1e54cd565248,
+                0x32e513b8cf64306f,
+                0x999447bc8dcee737,
+                0x75a585e210411d8d,
+                0x00aebce929bec8a6,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x53c49c62aed99cb7,
+                0x9ed95fd760953d34,
+                0xef3a5d0dfbd00f5c,
+                0xd3788622270719e7,
+                0xa3bdcef395a0ffd5,
+                0xacd8d193c281abd4,
+                0x012b1cfd43ce592c,
+                0x505320d9f2a5c418,
+                0x8d2352100b9effe8,
+                0xe06bdc19de5c2d80,
+                0x10eb74b520320ecf,
+                0x0054451954e83c2e,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x764d05dd1a03c720,
+                0x4a64e07710436c86,
+                0xd706afe7e384c827,
+                0x8b6d201a90b8ebf8,
+                0x9e23708689945b26,
+                0x71482a4cee92aa64,
+                0xdb280fb27e8aedb9,
+                0x57f126ec8408f3b9,
+                0x93f9876352e7c9bc,
+                0x504a49d0b410fd49,
+                0x2839c4077a41e442,
+                0x00d86e64f2f26106,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x8df58d2ca9a0ec31,
+                0x8f9f61ea7e0bd541,
+                0x9087c2fe6c62c4c9,
+                0x1e308cf2052f1dbf,
+                0x1e4597d8139b5340,
+                0xd36bcb5028bf425c,
+                0xa169e3bc9e280ab3,
+                0xacb0122ebb58bcc8,
+                0x33a02f6bed7adf38,
+                0x3ad2213c97501ee4,
+                0x21b0a0acf4c62a6c,
+                0x00e2488fca8909eb,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x6b12eb80a3050996,
+                0xd67c57736c57d9bf,
+                0x88aa0bb24f7af7a2,
+                0x0bf6d9b6648d19df,
+                0xfd6fdcff9958e724,
+                0x01af74253b26706c,
+                0xdd7020babf7ead1c,
+                0xe160956c3f9b001c,
+                0x8177450c37aa8932,
+                0xce2e04af3c6abb41,
+                0x261f47ab6bfafd26,
+                0x001bea3e54c4d55e,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x0e6320dced910488,
+                0xf368ead4cd882d68,
+                0x87e79d5abc2f23d1,
+                0x83e3c01b59177edd,
+                0x851817009b3a5045,
+                0xac098da24886358d,
+                0x9c013688bb97d7d7,
+                0x175114ed674e012c,
+                0xf4275f12288736c4,
+                0x81faffa096bda4c2,
+                0x8fcd4fd13e468cd3,
+                0x0122bcedb6b26b4f,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x127befe9f1e6672c,
+                0x430e26c64a1e6ad1,
+                0x8098d011587d4490,
+                0x8fb27c909ca2548c,
+                0x2b56919cd1598f16,
+                0x1a42c4c18263cd59,
+                0xc179b3e95ab2029d,
+                0x8acf6f457948e696,
+                0x77944fdb99cef371,
+                0x0be5b5d4d5a74e72,
+                0x7ec84a24804cc8d7,
+                0x001a7a2099e817a0,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb9c20176989f7122,
+                0xecb64f792d379837,
+                0x0d2cd8393f3cb6e9,
+                0xdf58c69829cec505,
+                0xa6a77db17561e02f,
+                0xf38c886fefba1470,
+                0x37d0fe3f80d4d2e7,
+                0xbd098e64bacca525,
+                0x2ed8fd0f6928e26e,
+                0xa81ac4c287b580a0,
+                0xb0b5f8f1f97494db,
+                0x006c722c9515da3d,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xc16ea59067b4671a,
+                0x9c66df5cd16a624a,
+                0x5700dce7d2cfc9c8,
+                0x8d82f075d04e45fe,
+                0xfcc0f21826b447f7,
+                0x19deeac2b3c734d4,
+                0x9fd332fd96393b64,
+                0x4e6b2783d73c6769,
+                0x8711e2a75992aa47,
+                0x79eee79df08c9161,
+                0xcb8f84f75a387591,
+                0x003fdb1ef1adf06b,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xea649d5f5fd20950,
+                0xd0faf0bf814db3bd,
+                0x47f0c51910a97fec,
+                0xb6142b234f7718be,
+                0xc81af1ed0bbd7e3b,
+                0x1744a38bd54317ff,
+                0x70c8ab4c4aa3079e,
+                0x63f777bcd2641a6e,
+                0x94deb8929c00bd10,
+                0x2bec67498959d3f8,
+                0xb586651ea715c199,
+                0x004a5775fc98a2dc,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 570];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+            bytes[380..475].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..95]);
+            bytes[475..570].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..95]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x56be7a3d7c8bd8ff,
+                    0x2bb60024c97c96f3,
+                    0x7d2428ed3d593d85,
+                    0x6b387f141f4ff170,
+                    0x9bdca50e5ab0c4d7,
+                    0xd8c9894df8709a6f,
+                    0x761ce543d24006ea,
+                    0x671caa74f2b9ab17,
+                    0x27dc849b66e3f957,
+                    0x41a0d4e773828e3d,
+                    0x200026bff8534130,
+                    0x00d9bf636b6cceab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f0821c984046cb6,
+                    0xc441deeb0789469c,
+                    0x6a5307a25d408335,
+                    0x23f9fa652b99c45e,
+                    0xf846482f756a2dc8,
+                    0xe11c8ae6ef48c603,
+                    0x87f8f5351947ef36,
+                    0x12f8af616051b44b,
+                    0xbcca119da8e34847,
+                    0x8e680a243bb761e1,
+                    0x3de0a7c1dd6d6e4c,
+                    0x0120284de9c7b909,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40d15aecae7a751f,
+                    0x4a6c4e99c658dd7d,
+                    0xe37256baf72574be,
+                    0xe0cca00fc1b5dc8b,
+                    0x4d29be409548756c,
+                    0x597563deab548d5b,
+                    0x3ca4a978914391b9,
+                    0x5b53d20460a78bd3,
+                    0xc6f46a5651d1a87e,
+                    0x7c7223a6b5d17567,
+                    0xd56e545797098cdf,
+                    0x006efb54d4146e8a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x897cf6c0a74fc595,
+                    0xa09a5bfb477761b2,
+                    0x38affaf65c00d70f,
+                    0xe55315529ba08c35,
+                    0xd092b092a3397504,
+                    0x19f813aaf9ba7f0f,
+                    0xd59dad818bc8c62f,
+                    0x5d3c1cf4745ef4e8,
+                    0x3d2358dc3fdb2ef4,
+                    0xa921fb483db6c3dc,
+                    0x1c6f5752e67a63fc,
+                    0x009c2f6b6cc088f3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2aab4f044f470a9,
+                    0xce2b24fbe436f700,
+                    0x81cf6aea84b2e49e,
+                    0xcaec1f4fdf39f854,
+                    0xbf01923edfe88cde,
+                    0x790c077bbdc13b8a,
+                    0x8cb83d565060934f,
+                    0x1a4667d3303a0332,
+                    0x75ec3025533a8f59,
+                    0xb9158d25cf97564a,
+                    0x74dad02507403205,
+                    0x00f87ce9e3fc9fc8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc60ca4d4a06d7698,
+                    0x5e502b842a241141,
+                    0x06cb98ae4b88384c,
+                    0xa482679399bc7777,
+                    0xa62778fb7addc2cd,
+                    0x1eef52e92a940b02,
+                    0x3b5dc6e7edbd6e1b,
+                    0xa83442e1edbe6b7c,
+                    0x75ad57c3e5872f22,
+                    0x74b8732eadab223f,
+                    0xdabc56266dae6982,
+                    0x00a09e59182fb49c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb59c4fc73eba4ed8,
+                    0x1398d42085bc4a26,
+                    0xedb249a8d9574f6a,
+                    0x66bc0e6e9661efac,
+                    0x78f6e6e0c0e1547e,
+                    0x1a08580ec88cfd59,
+                    0xa01f189cf1559b14,
+                    0x2258f8d4950a00be,
+                    0xc87c79bc6d1c7e18,
+                    0xf35b69d397f5b8f6,
+                    0x7df37dce0dc2a5ad,
+                    0x00a8b59c4da22f51,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7961c026e6ef3c0f,
+                    0x3acd1ada65630a95,
+                    0x94ab463c3d4f461b,
+                    0xfd1b24e5a7c92a1b,
+                    0xff65769546401355,
+                    0xaea85b604f15a8bd,
+                    0x149d3399434bfb1a,
+                    0x462bd1bd4602cbc4,
+                    0x5e4e76192814f43c,
+                    0x03c3021d4ffed297,
+                    0xa8786e40f949991f,
+                    0x00cc1c09a719651b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8a347b747cc912d1,
+                    0xb8e24213b4c23cae,
+                    0x9876ee61fd6b66fa,
+                    0xc17653075b4a1205,
+                    0xfddd77dfa8f83c3c,
+                    0x7b0dff0be632029f,
+                    0xaa4a828a66886f7c,
+                    0xb9fad4bafa81351d,
+                    0x3808f3ff4d455e82,
+                    0x4e151ac1e9d46bd6,
+                    0x7b348c8aec7e3c6c,
+                    0x0017a3176676bdb7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd6dd5ec8c0a89235,
+                    0xd65377fec9dc4910,
+                    0x80d2c2b79cea9557,
+                    0xe83cd6a6e77a545c,
+                    0x4892d60b361e562d,
+                    0xe769a2e4bb1f9f57,
+                    0x33aba0307c085c16,
+                    0xb6dbce7e8d675203,
+                    0x830c03bfc80b6027,
+                    0x9c19cbd67121e05c,
+                    0x4ee24e89c09d49a8,
+                    0x00ce495881101fea,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc24212bde12d471f,
+                    0xa9ad557e279d48b6,
+                    0xb19fde53d27f4e37,
+                    0xa7e39037dc05ab0f,
+                    0xb3032aa8e7965e85,
+                    0x7eac532278cd8dad,
+                    0xe0f3ac671c73ff0a,
+                    0xfabb71b5a4cdc9e3,
+                    0xb8a9598bf431b1d9,
+                    0x231ffbc5873fc0b5,
+                    0xc133d607a71f4da6,
+                    0x00c9f67b9eaa81e2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9932f5d2e896792f,
+                    0x96edb66870d190ce,
+                    0xd027bd841a5bd58c,
+                    0x7401ea1c88d05a20,
+                    0x32ec02f5dd0635e2,
+                    0xb84dd61660468759,
+                    0xe3f02855e74c894c,
+                    0xbda87cb06a09a92e,
+                    0xfcfd0b2cf072b84d,
+                    0x2dac7fe3d9a70d7f,
+                    0xbaff863a60e33cb9,
+                    0x011bba9cc8acaff2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xc9b9d332ac305cf7,
+                    0xc69f06f17c610986,
+                    0xb849f1a375cf2b73,
+                    0x3d8330196a293b3e,
+                    0x315b069da68e66b2,
+                    0x7e5caa18bc96624a,
+                    0xa29847a724febd6e,
+                    0x1fce402cc001697c,
+                    0x935855b573456051,
+                    0x4621bcc5351004e8,
+                    0x9cd2602d4ab12870,
+                    0x01152131ef04d20e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbffea1fac9a61b3c,
+                    0xc2790f4c23e0ae6e,
+                    0x47b1d92e9d5265e3,
+                    0x4ae8821e316f3a30,
+                    0xd703a60a1af9a720,
+                    0x2bf0a8bc310e1c3f,
+                    0xa532ab9a87c18ae6,
+                    0x91c36f7f2f283983,
+                    0xa9ccee560dafd80e,
+                    0x2906de817afe8ad0,
+                    0xbdc50ff9b987bfdf,
+                    0x00e25d3f60db5ee2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6a1023eac97bc78,
+                    0xd0759c4df4a3b79b,
+                    0x7f0c32fa23607edf,
+                    0x003ab70914e6d678,
+                    0x1fd6d800f05c0d0f,
+                    0xcfcc58e570029963,
+                    0x77c43f0fd5591abb,
+                    0x38f748178c68d3f9,
+                    0xc9cb2ad36fdb6901,
+                    0x6d932ea69a0bdcd2,
+                    0x1d6aef3dc98ad44e,
+                    0x00b3a4e0cf665281,
+                ])),
+                Felt::new(BigInteger768([
+                    0x35b2a433db14920c,
+                    0xb80997b2a7996784,
+                    0x38bbfedcc16154d7,
+                    0xb98a855112bb2288,
+                    0xa7104d847d8c4933,
+                    0x18dfd116cd8343da,
+                    0x2605b93a1768830a,
+                    0x54a533fea27eab11,
+                    0xd1ab1c69826e1c39,
+                    0x9588d86c454ef351,
+                    0x4ecb9a882623d281,
+                    0x0035399ca0bbd900,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d094f438ae2e95b,
+                    0x5e877d2e2c29b59e,
+                    0x4e02a38b7d577710,
+                    0x3a4ebd86c76bc3a6,
+                    0x92fdb68cb207f3dc,
+                    0x0b84f9fc9fa16515,
+                    0x3dfb11af37171730,
+                    0x60cdd2c294387118,
+                    0xa28359000bffb6fc,
+                    0x4495f8205af9641b,
+                    0xe5d4ddb0f4d13ed3,
+                    0x005042a7abcdd37f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3fb3878f3cf2b005,
+                    0x33fa74b3423dcccf,
+                    0x6b6fb03aab3fbb8d,
+                    0xda9bf50c971dc141,
+                    0xa9c4d780e1bcbafc,
+                    0x7b242bd858a09564,
+                    0x9df9e4576800fe2d,
+                    0x41d9c3021bc94aa2,
+                    0xff2f2b3d6ed945fb,
+                    0x1f7a339f196bf76d,
+                    0x24bf24f293f813f8,
+                    0x002a9379d0fbcedb,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x4463212a5711b2f5,
+                    0x229847395bd861e9,
+                    0xe70e6778d94c7080,
+                    0xf3739db60ff11a57,
+                    0x553eae6e10f19349,
+                    0x6cd45d879f46d089,
+                    0x8bab15ca14dceecb,
+                    0x60ae08036327a573,
+                    0xb4a7055a8b2bbad1,
+                    0x833bccc8e366f867,
+                    0x9fc682d2e2d70b50,
+                    0x001811f0403d3d82,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7048ebff7a64479e,
+                    0xb6ada84b19df455c,
+                    0xefffc681a6e73d40,
+                    0xab27f660e760c027,
+                    0xe525dfd0c2f1dd91,
+                    0x12defd5203f27277,
+                    0xb58f8cc66b59f3d5,
+                    0xb7b2d3dde105a1de,
+                    0x272c5b7d36de1dc1,
+                    0x150a9f50973adcb7,
+                    0xdfe1761e361e1092,
+                    0x00b64a44ca2d6520,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa10f4214f4760b8a,
+                    0x93ee3e5a48f8c8a6,
+                    0x4ecd9c436517a0c9,
+                    0x5e37ad3fe554b685,
+                    0x7e67cdfc4852d93f,
+                    0xa49af0646a8980ee,
+                    0x5ee644bd5c91ce26,
+                    0x1a868cc5fade4721,
+                    0xdb567e24cb69c476,
+                    0x7cef660854fd4dda,
+                    0x05d86cf41bd2e02f,
+                    0x00121c4f0e6acbdd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9a032877fe33a3d6,
+                    0x4061a695044d0349,
+                    0x7c437507458cd51a,
+                    0x22f5230947b40a55,
+                    0x01159696440ac2be,
+                    0x2a4dbe1a720c543e,
+                    0x75a8d1f18a3fbd70,
+                    0xc25a65d46653acf1,
+                    0x792023cc421d38b4,
+                    0xf084442a791dc989,
+                    0xecfefef3cf1544b7,
+                    0x00d0d4eace2d033b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe9daf16d58c57e91,
+                    0xb10b1875b0c8876d,
+                    0xedf7ebf3f4e0a857,
+                    0xd7a596d353e33c64,
+                    0xe3810462e57b95b4,
+                    0x15d72a17009b6e14,
+                    0x21c1bdc543777274,
+                    0x908fbfad252ee6f5,
+                    0x3c1ae4cb200d6883,
+                    0x33480c832034240e,
+                    0x44d3e883241d676e,
+                    0x0050f467f6bd9fc2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4c7d9ae74acd9177,
+                    0x63a0946f43190bf2,
+                    0x152cbfe8f387e270,
+                    0x26e4026937dfc82d,
+                    0x6485607b2888db39,
+                    0xf9095592eb72e416,
+                    0x2d29a9521640b235,
+                    0xb3b9792ef600e3ac,
+                    0x2fcfac7ac1b6a108,
+                    0xff5e4dc5da0b6a9e,
+                    0x3eb15e5c23630cea,
+                    0x008b59e98a5f6e21,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x78bf5871bdac3f73,
+                    0x8642f600865c3cad,
+                    0x002933edaff49044,
+                    0xe075f651682232bb,
+                    0xb0b73d2168d3801e,
+                    0xf0a50f26ce1dad00,
+                    0x613d9dc70c5e148d,
+                    0x68e3cbdc68efc857,
+                    0x3c1d40d6162858cc,
+                    0x22c063c622c564b7,
+                    0xc7fb7f11b09beffe,
+                    0x00047abb6f313b26,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2ab5e1ed1831bb22,
+                    0xfa6fbf921eac30b1,
+                    0x4d2e4a9566ca93e7,
+                    0x06577099cb7ee1c0,
+                    0x285de2e21a20c700,
+                    0x01aa1f2a533bce51,
+                    0x7595e139abc53773,
+                    0x99fd336c2578073c,
+                    0xae87e69398ac79a1,
+                    0x3b7724e8e2fa54c9,
+                    0xc648a2872dd599f1,
+                    0x00c2b071358132bb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfa811a3b49eef8cc,
+                    0x61e4cfdaa7ba6b6a,
+                    0xa54ef7d76caca493,
+                    0xcac01bb6b39c8f73,
+                    0xd6c6e23bd1ec0f5d,
+                    0x3d6bf063b1d67018,
+                    0xb7965d6429f3ff69,
+                    0xcbb1777bf95c49c9,
+                    0xd205e5bf93e63b38,
+                    0x7bab6ea4f63391d7,
+                    0x473a662c97fbf05c,
+                    0x0078ccaacbbf65e4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6e28a3abb8925a7a,
+                    0x68bc5d5ebc94a369,
+                    0xa3c1a4613a0a1ebb,
+                    0xad85ca4a3a3c2b91,
+                    0x2057709d1748608c,
+                    0x06d82420c3806b48,
+                    0x41657de8fbfb0bcb,
+                    0x5a223310cc18c621,
+                    0x9e4d4296860de0c5,
+                    0xb4bf3ba82c9e3427,
+                    0x9a76108889db0f5d,
+                    0x011c023e7e4b447a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8a46b4bc022e376b,
+                    0x391393cd98c67d98,
+                    0x2b9a61f1b9c2bd21,
+                    0x3055190ae58b2cff,
+                    0xf1c349740a498292,
+                    0x1640f703b4c0e1cf,
+                    0x815aa4c8bb2b3f4a,
+                    0xfb8cd75e1526e9f4,
+                    0x42ca27cdfde5ce6d,
+                    0x42d9bf599c202c14,
+                    0x85e22aa9a0d81bcc,
+                    0x00d3919f8af2a8be,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8040a32ed2c69921,
+                    0xebcfa1e0c58d5118,
+                    0x259c63213bbe077d,
+                    0xac4641ed33a8161d,
+                    0x21a52f7689e4da86,
+                    0xa2118c3a4487981a,
+                    0x72fc6874c87f561c,
+                    0xbf2263ea43beab8f,
+                    0xf130eabcafa1a981,
+                    0x0a46ad4701d63e61,
+                    0x03d9055a91b83175,
+                    0x0067a7be64e6ea70,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb95322041e5b2332,
+                    0x1ff5d1a82d2c65a9,
+                    0x3a222e5601671e2c,
+                    0x8995d211cae1a492,
+                    0x7cfbadfaf4f2617c,
+                    0xa21b3251ab8517d7,
+                    0x57bcee89538ff0c1,
+                    0x3581985e8e59afda,
+                    0xad194d80f82454a8,
+                    0x4f202b2bc558e9a6,
+                    0x13368225de041358,
+                    0x00926a0b205599e5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x610aa54797577fa3,
+                    0x10208805e8fb3b49,
+                    0xdf6d0decea9967d2,
+                    0x8410ec435f6feac7,
+                    0x27e6e251aa412d50,
+                    0x70bd26835165c0a9,
+                    0xdc845b759259a150,
+                    0x4b95e52c839c45b8,
+                    0xc41f41971625b608,
+                    0x49f5a1d9ac13e268,
+                    0x9542445630022ace,
+                    0x008dad115f94e9d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb02f9cd11547686a,
+                    0xfb9c09d78dc396ca,
+                    0xa5a9ee3c08b672bc,
+                    0x25d1b44505122f3e,
+                    0x76530e16f3fad51f,
+                    0x31a2e96fb59a33e2,
+                    0x6b043d50a8cbae00,
+                    0xecb81c19457c5d13,
+                    0x6681d142a86e9bec,
+                    0x027725ba8d8808fe,
+                    0x69da19a15d3acd60,
+                    0x00a83b0c7ebb336e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x870a3e47d4a16b6a,
+                    0x648b8ab085e80a3a,
+                    0xcb3f074c36b50c04,
+                    0xe3627f0dc626f58b,
+                    0x0e9173f8101ff16e,
+                    0x7d598ee4ab12d07b,
+                    0xcb2abfe79c5a7c96,
+                    0xf32e9340d74143ad,
+                    0xa0d2b62e94c18fc5,
+                    0x5f067095dcf4adfa,
+                    0xfe02bfb3b1dfca48,
+                    0x00dd894e13552920,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90cedfbae6d4a123,
+                    0x4a0f8a6838b77f4d,
+                    0xbba8235e38583f2e,
+                    0x14c0660263ee74d3,
+                    0xe83af666565e4061,
+                    0x33c6bb64f05a583c,
+                    0xc5aadf2d0a83b64d,
+                    0x3cbb1fba4c59a375,
+                    0x606bffb1076fb43a,
+                    0x8dba530f60dc8b70,
+                    0x88931d6dccf124de,
+                    0x00b54f9e7ade5a49,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5b75f041c65a2dfd,
+                    0xaccffd1c0d85ff86,
+                    0x18513ccd9476edd4,
+                    0x76534368ae0fa42f,
+                    0x68d536b8e7e10128,
+                    0xfa867563a55ad3f3,
+                    0xa12a714008ddeb48,
+                    0xa1014fc120f5be2e,
+                    0x39c82854c7248669,
+                    0x83754642e3d3325a,
+                    0x57d9ce21c9e4fb29,
+                    0x00151882d6f792b4,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0xc28cf53d2af4cc1b,
+                    0xa38fb456b74d11b8,
+                    0xf61cb256ef98a583,
+                    0xebf5f3a30dee3104,
+                    0xe1c03c0654c0a9ad,
+                    0xee972796f049bff1,
+                    0xafc71ff7282ee315,
+                    0xf2e7ca0f05df947f,
+                    0x0270aa3d5bc4a161,
+                    0x158439e4617f9bdd,
+                    0x465a2412e4800d0d,
+                    0x0094ce209732877a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf5e482abf5419551,
+                    0xcc170c74d8220a2b,
+                    0x59030d5287dc2da9,
+                    0x8e7a7839f82c7baa,
+                    0x887e0d9969651a60,
+                    0xb205b44c04992313,
+                    0x4771b19c44418be3,
+                    0xa8642255d19c528b,
+                    0x31fa178cf39de821,
+                    0x7c58b478961798c4,
+                    0xdf753ee80090f334,
+                    0x0050494ef05f514a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b603f64a687a0aa,
+                    0x4fa41c0b38d8c478,
+                    0x99aedbbd9ab23647,
+                    0xd0f5786e10888f42,
+                    0x7c458abf8f85a1a1,
+                    0x5877145b6cc393b6,
+                    0xe75cc488eb0b29b1,
+                    0x7480a54fe9df00cc,
+                    0x95383eb3b6de01ae,
+                    0xebcdf7ad87c712d9,
+                    0xbc518671f6652e5e,
+                    0x00f70e05f518f9f2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0ce00f00b5565cae,
+                    0x2415f270c7ee9958,
+                    0x519dddf2f4100490,
+                    0x36e06b6b8a51f01d,
+                    0xb585744989c312bc,
+                    0x18e02cdc4076eab4,
+                    0xf10274258a17027f,
+                    0x0fa1fb451944c035,
+                    0xcdd8cc73113618f2,
+                    0xac037d90bb076e4a,
+                    0x6d27099b33fbfe65,
+                    0x002984bd048c4c6e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc96d11224cd18f3f,
+                    0xe4867c57c753de00,
+                    0xdd98e7f897e081d7,
+                    0xa3efacaf9cba4f7c,
+                    0x11b48c32bf998191,
+                    0x71e56916f309470d,
+                    0x3fd4e1bf459d8751,
+                    0x05c7b299ccbd4de4,
+                    0x26b64bd3dacdcde2,
+                    0xcf313e5678675057,
+                    0x47bf67ca9c72b851,
+                    0x00a391efc8c292bb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc696f22ae612250,
+                    0x7cf52465714fbd0f,
+                    0x4d78f7ee468d87aa,
+                    0x136e1fc275eca159,
+                    0xeecc5647d9f267ab,
+                    0xd67566567bf16a5d,
+                    0x1811e75071aee6f5,
+                    0xe10611d9967112b5,
+                    0xdbc5efdf7153d436,
+                    0xb1eed96f1f87d6f2,
+                    0x124f048057d3dafd,
+                    0x00350cc31ca2d40a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf0772376a2d12c1b,
+                    0x080e0f5b430685b4,
+                    0x41f7689917c10485,
+                    0x1e7d65fc0cd654a2,
+                    0x9c23396def82dfda,
+                    0x1976b598db5ed8e3,
+                    0x06aa233baf110184,
+                    0x847701d2f1468e67,
+                    0x79889ea0b6f80691,
+                    0x7a87d5ce03b740e3,
+                    0x954b878762c16745,
+                    0x004fb149343c81f3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd8189df40f3c45d6,
+                    0xeb3701a10473213f,
+                    0x0021ef7b7447a351,
+                    0xb6c28ceb020ae493,
+                    0xe4707e699fd0de3d,
+                    0x470b59446a844fcb,
+                    0xa9cf07fe19ff48a0,
+                    0x6976d9b07c1e30a2,
+                    0x9b35ab0afa36541f,
+                    0xc1c94d2305606395,
+                    0xe711d8791f7f963f,
+                    0x00d78f1cbfb77166,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6665d8ac890d7f44,
+                    0x1be341bb9339771d,
+                    0x4739c6f54e92f80b,
+                    0x24881060a15c756c,
+                    0x2338a144d8df4fd0,
+                    0x2ca2eff0c7043fd1,
+                    0xfb7aaed67ccdde4d,
+                    0xdfb9e02ca0a6460a,
+                    0xed3b69e221f1e3bd,
+                    0x62a7d9b602f4d887,
+                    0x005a9fcd17331361,
+                    0x010fdd9dc6e3b48a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xf717d3395bf4798c,
+                    0x14db8eae43fc7a44,
+                    0x40b7d438f5889f5e,
+                    0x0fada448d3d9089c,
+                    0x4b81cd471c780036,
+                    0x01f7feda22398d66,
+                    0x93bfa6aff9bc5f35,
+                    0x48a404098583a1cd,
+                    0xc21216695143f0be,
+                    0x4d2a24ec663b0c1c,
+                    0xb8665448e00c23b6,
+                    0x00484d54fc5ce94b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x365b85ddad07655c,
+                    0xdafe8f551178c772,
+                    0x01345c66d1e8bae5,
+                    0x6bf1e07aa3f24725,
+                    0x603dcd609cd1ac2d,
+                    0x2e3d30a441c9d9db,
+                    0x569ed1e4636e2b6a,
+                    0xc716b07cfae57b73,
+                    0xad6f0dc6bc40c9d1,
+                    0xd6c521256c542d01,
+                    0xdc484bfbd1cb62a1,
+                    0x002d4c5f2a611a0e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x33dfe45b3f53d272,
+                    0xc6566a5afe5ca9c6,
+                    0x92289fb87c1b91c3,
+                    0x6b0fdb8dea373b7e,
+                    0xe66f1884ae83dcf2,
+                    0x7a1d77e0cfb7ff5d,
+                    0x8b5259282330cce6,
+                    0xb90e705094a9ca96,
+                    0x1e49d625fd3748fc,
+                    0xc51f568dbd29af43,
+                    0x3fdda9b794e383d0,
+                    0x004ddda86dc85f53,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5e3f801d229e63bd,
+                    0x89094459deb83c5d,
+                    0x074dd91b4e437e12,
+                    0x283cf6b91beef591,
+                    0xe539b19d166240c1,
+                    0x85259dc4e366e543,
+                    0x330ce0bea4d868b2,
+                    0x680549f1c34ba174,
+                    0x61e7dafb326de273,
+                    0xaa19ca259a3b854e,
+                    0xf8538bb9629f1b4f,
+                    0x00ee50cce9482ee6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05ca1a79fcb898f2,
+                    0x80411908d8b8b926,
+                    0x51cfadab83de669c,
+                    0xa7b76d30dfabc7ee,
+                    0x1cf12d08c58b2146,
+                    0xf9a4bf9bda438b69,
+                    0x39ace376ece4fe13,
+                    0x32c7c66c3979fb81,
+                    0xc0311b48554f36b6,
+                    0x10be8fee2c185651,
+                    0xd44c6160fca8f5ac,
+                    0x00071393b4f02a52,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd48f45ce863dea3a,
+                    0x7cb0f5e779c9d8f6,
+                    0x8d45d6d109ab6bb7,
+                    0x07bf452f20ba3402,
+                    0x54b8da7b43277ce7,
+                    0x4b8747e7cf2ee627,
+                    0xc415f107b2b658ea,
+                    0xc3918ba69679847a,
+                    0x1dc6da002d3fa986,
+                    0xb7b71543aa56a2af,
+                    0xa9e6aee6447a7932,
+                    0x00364233f2eae6a6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb184f5ce7a037952,
+                    0x488c4b78862289be,
+                    0xbdfdf66957f8600e,
+                    0xe8c98ed8a77cf2f7,
+                    0x6d13b6c7b9d73035,
+                    0x6c323867aa88930b,
+                    0xc43529c6448f8451,
+                    0x8f06cca37ba8d1ba,
+                    0x95489dfb32dddbb8,
+                    0x8eccbfba727c6e50,
+                    0x8dcd3e6455cbe45d,
+                    0x010ad1d5be97c435,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2029a182f9ebfa79,
+                    0xe39c0d44530136b4,
+                    0x4b456c5071450634,
+                    0x46ebd5c47b4afa2f,
+                    0xda34d492fa821270,
+                    0x7ca21779b3c15e1b,
+                    0x338e6e32337ee1d6,
+                    0xda75e8a55407c5df,
+                    0xb40ea1544756cc36,
+                    0xeb04816c5cda13d4,
+                    0x232f6e0f2fb96117,
+                    0x00e9e1c8f28a63ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9fbceeaffb78e54f,
+                    0x8c2fcf36007bea36,
+                    0xfe22902f84f180e9,
+                    0x5647b25a303fcfc7,
+                    0x99b09915c4ca3b17,
+                    0x5897923459fa38ea,
+                    0xd34b933ee8d5018d,
+                    0x573319676666c719,
+                    0x41d08320d31abc80,
+                    0x3153047eb17b9328,
+                    0x26b8ab344b01d721,
+                    0x00ff5b6f2b1a2b54,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xfab165b1fb295bc2,
+                    0x662ecc76002db84c,
+                    0x5322500098513813,
+                    0xb42f25ab44e71cdf,
+                    0xcf434538b4d50792,
+                    0x1f626e12b1b23339,
+                    0xe6420f493f076a1f,
+                    0x633d15d40ef51d35,
+                    0xabfca4902e6d1d34,
+                    0x20b07bd8b72b7c98,
+                    0xd434aa6132436db7,
+                    0x001bed27bf4396ee,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf6ecbd3af8684dd,
+                    0x5ba86673eeaf6290,
+                    0x9c266f9f721e9c64,
+                    0x5f096bc065cba4c0,
+                    0x47066bef7359e2f3,
+                    0x09069ca637430f68,
+                    0x28964bf00f47a18b,
+                    0x3e1fae0e6c97b834,
+                    0x473b97dec7716830,
+                    0x0c8fce0ba9f6f354,
+                    0xaa5e00547357092c,
+                    0x00bfad909b373a1b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa7c716e62386b117,
+                    0xa4aaac36daaddf32,
+                    0x6372b12679b63575,
+                    0xbd0480f3cd34dbaa,
+                    0xdf6992b7964eb2f6,
+                    0xad6ec0b360e59cf5,
+                    0xf5d4f2dec174cce3,
+                    0xa2f874ec974f7749,
+                    0x647b63c9d4da7c70,
+                    0x43faa8e9877131a3,
+                    0xfd20bd1066b17908,
+                    0x010b5d12d41fd0dd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe1fbd64a0fee66c1,
+                    0x9700282d31be473b,
+                    0x08b8afab30e72ebf,
+                    0xfb1145632ba50b72,
+                    0x4125289c0fddf2b3,
+                    0x58c481bf2463b2f1,
+                    0x1d26ff2533c36d39,
+                    0x6c6c7ff828a0ec36,
+                    0xf18c6bdd9b956165,
+                    0xd44f08896203df2f,
+                    0x9fcd4a668cf46de3,
+                    0x005b92f413d71bd3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9bc3bdbe65c1e643,
+                    0x70eb3a897a2072bd,
+                    0x99bcfb9140aa48be,
+                    0x4f255338f6f16b36,
+                    0x791ab87910982e2b,
+                    0x42fddf796bbf49e5,
+                    0xa64c77cd69edd4ca,
+                    0x010312f03bc4417e,
+                    0x6d370a173b6dae2c,
+                    0x97822bf1013a5ba5,
+                    0x4e705889d8e07142,
+                    0x00681073ce13774b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd3e856853eae05b0,
+                    0xee31f265783de7c2,
+                    0xbbd4a9134c66d956,
+                    0xe85dc1fe68ae4d5a,
+                    0x07b363b2cd8a6eb2,
+                    0x07d727bcd196bfe7,
+                    0x01aa1f900034d928,
+                    0xa796b8a90b2b7c8b,
+                    0xd0d4d7560dd0aa0f,
+                    0x94da3ed6e2fd1934,
+                    0xe25ac17e0ad1c355,
+                    0x00aa1a3ccf11c331,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x098523c4d4df716d,
+                    0xd9eec2fb7574a8f4,
+                    0x2c5a4e221d31ab06,
+                    0xacd28a1bb4add35e,
+                    0xf8d0a9ec8977dd09,
+                    0xfe19841992551221,
+                    0x5f50c773d6948865,
+                    0xca5366c61a399dc8,
+                    0x200cdc04f1f4d8f5,
+                    0x4455feee4c9710fc,
+                    0x2d1753d493d504a7,
+                    0x007c46981253cd82,
+                ])),
+                Felt::new(BigInteger768([
+                    0x79756dea24fe9dd8,
+                    0x48100e8af4297b40,
+                    0x9604f256efedd695,
+                    0x848ee8393b417c2d,
+                    0xde72c92c5aa14b38,
+                    0x5d78c1d67b25d8e6,
+                    0x1a64325bc53a89e6,
+                    0xe159210c92e0e7b1,
+                    0x7c3d54671d50a610,
+                    0x6b702d8915337e4d,
+                    0x83975b759dc6cd2f,
+                    0x003640a0139538d0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b3e145f193dec3c,
+                    0x233a605f0aab33dd,
+                    0x08131602e88459dd,
+                    0x62fe65102423fc63,
+                    0x710d55bf12b60167,
+                    0xce8c50da11328b86,
+                    0x756e85bba5bb676d,
+                    0x0de35895974885ca,
+                    0x9b32ede6ddcee2fd,
+                    0x9610e9b274b2c83d,
+                    0x56a55474895ca493,
+                    0x008eac275a116172,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x037083ca0893a1ae,
+                    0xd022011f6d55806d,
+                    0xc2f5ccd8778d77dc,
+                    0xb165351186caaf3d,
+                    0x79767118ccd42986,
+                    0x6d3eb0626db86915,
+                    0x3c17bb95e57d0ef7,
+                    0x8b45935ec14b2d8b,
+                    0xcf67dc2f6d808543,
+                    0xe3116fc3b4026147,
+                    0x1e08004b565c5ee5,
+                    0x0027da5925bb2300,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf91df91fcfed587f,
+                    0xdf0fca09713b26d8,
+                    0xefaa7fb6114d9354,
+                    0x53854dca568d0004,
+                    0x813687204256364f,
+                    0xb1b3fa0f2f7a20aa,
+                    0x7c0d2dd8d4c35f23,
+                    0xd21cbde529494174,
+                    0x3dfed5cbf3ff5c3e,
+                    0xa0905f18cb62fb8a,
+                    0x73ebf16aa59895f3,
+                    0x010e4c551e76dcdd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcb11050a401e76a2,
+                    0x58e3b0c6f42962d8,
+                    0xec56fcb8884523d9,
+                    0x244f1e5f89c07e0f,
+                    0xfc21e8bc8a0cd536,
+                    0xc3ca824166ebbe38,
+                    0x86b4c2e4cba700ad,
+                    0xd1b3203ee42ca85e,
+                    0x38b7fa2252c04eeb,
+                    0xdb9d7ab91d41aa84,
+                    0x9948906985059c52,
+                    0x00645a66a7096efb,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x7c77ea7e0ae68522,
+                    0x61c4e27164b43285,
+                    0xb92e1e63b1eb869f,
+                    0x0eea2c66e310fe6d,
+                    0x9ce86758531c1659,
+                    0xf4676902898b19a0,
+                    0x70d020f35d05e7a3,
+                    0x70355ba719b52a03,
+                    0xf6291ccc2e33c5aa,
+                    0x0299ea51e9014bd3,
+                    0x11c44b77bc053d42,
+                    0x00f62e43dfa6000a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c6d528c9c76fcae,
+                    0x5f6d52a4a353b18c,
+                    0x7a66751fd606bcd5,
+                    0x45a5697edf890130,
+                    0xb625b02925f41648,
+                    0x9d8844913075899a,
+                    0x8e6c27d10bbb10c0,
+                    0x87be472f97a08da1,
+                    0xd0f52efa459d9e2f,
+                    0x6a6eaf85a9e97d73,
+                    0x1b60ea3eacdf5927,
+                    0x004fb4bbca9be44e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4a62bc3c9d6a1af6,
+                    0x8c1abe532d4f98e4,
+                    0x9e3b0cca33038884,
+                    0xaa779a6464043082,
+                    0xeb06ceb710cce21b,
+                    0xe381970b2efb6597,
+                    0x56e4b0e8c123ceb5,
+                    0x72a2ce4a057f1a32,
+                    0x9a9d6e017c90bace,
+                    0xc168f747e39420bf,
+                    0xfa0a5fbc2ff74af1,
+                    0x00acff869f97d6ac,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f9404cbddd3ffe8,
+                    0x9360541a9c4d3d8a,
+                    0x264422c0a1061452,
+                    0x2d4a53fbbfb0fced,
+                    0x810f4f6c1ad6ffd4,
+                    0xa13ade01be48d7df,
+                    0xfa6e8649670ed921,
+                    0x78498fc8461bd9ca,
+                    0x7e685e188bf3c1a7,
+                    0xad72a7b3575b0484,
+                    0x1a3d26816fd1df61,
+                    0x00625febba7107d4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x16142cffe774b6c1,
+                    0x5d14f7677f0af0c2,
+                    0x0e2ca72f5c322fa1,
+                    0x2c1aad5527e7499e,
+                    0xf29c8d8dd2adb223,
+                    0x0deaa4105cae1e5c,
+                    0x7d951ce15f53d910,
+                    0x802a9f937c2ad0ab,
+                    0x372065110222034e,
+                    0xdd9782f76134e871,
+                    0xffd26149fd06763c,
+                    0x011c5087cec70b43,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf4b010c5d5ee1d00,
+                    0x0b843bde102be1b9,
+                    0xaa848e9353138f2a,
+                    0xcac141d885b0b93f,
+                    0xeb5ce276cbf8fbc6,
+                    0x9bb33ffea926c423,
+                    0x867470ecb1aabdaa,
+                    0x6a1451a34e94bc4c,
+                    0x57ded6a3d7d470ea,
+                    0xc6bedab9ddd1e6d1,
+                    0x94047b4deeac8c06,
+                    0x003f7bce0c3643f6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x296df4ebe4820633,
+                    0xc56874e2b3f18e42,
+                    0xc7bb033d996c0b2b,
+                    0x3bff2c13c3d6b48e,
+                    0x5d3b78c50f5cc14d,
+                    0x5a142981c26ed476,
+                    0x20f19d2591a26a6a,
+                    0x8ff3360a42af4f85,
+                    0x02b2e6e135d3b8b9,
+                    0x9796fe06745fe607,
+                    0xd38a5dfc8242add2,
+                    0x01070f1ba5916c4b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9865b9ddee75f871,
+                    0x8d4cb188d67cd729,
+                    0xb2d48b8fe2d16754,
+                    0x39e4fcfa4a117e52,
+                    0xa76d63dd9e4bf8b2,
+                    0x705fc6ab372309f4,
+                    0xadb234cc71c08b3a,
+                    0xba89244969d45d17,
+                    0x0257d02c301d6d59,
+                    0x2b616d5c11eb3b53,
+                    0x490188c79d1637e3,
+                    0x00634e668356e579,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6eaba05f51ff0ded,
+                    0xb7ad510037f95105,
+                    0xd12878d7226bbb64,
+                    0xecb1beb6bfac6e51,
+                    0x363a9d0342cba75b,
+                    0x1be3a32c93e89cf0,
+                    0xc4d7732935221d0d,
+                    0x9af9b07b7a4e90d6,
+                    0x534797e5ff6bb858,
+                    0xc03afe6c56a26fc3,
+                    0xfa62bd3923eb85cd,
+                    0x00505b957b258b5e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xda250bb34b932239,
+                    0x5639ffff674ebbc3,
+                    0xe882ad5f7869a426,
+                    0x741f03fa17d92742,
+                    0x14b170ace2109637,
+                    0x5a3cc08aac31aabd,
+                    0x78bb86f2e22a9bd8,
+                    0x8a10a6254ec261de,
+                    0x09368abe68e76354,
+                    0x099090075de873ae,
+                    0x6fcbbb56a1223ffe,
+                    0x0085ce0decf64b5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x98c07a7537bef452,
+                    0xe5419ee1236c4249,
+                    0x1dee3f0994ca124d,
+                    0xe3244b30b6041588,
+                    0x0dbf477c10007ddc,
+                    0x6ad85ecc8aedf350,
+                    0x777a3eec02fad2b6,
+                    0xaef56ef78f32b9f5,
+                    0x0f7f658dd532e664,
+                    0x533453366eeebc60,
+                    0xa2d3f85de92abbfc,
+                    0x001000f77ed12886,
+                ])),
+                Felt::new(BigInteger768([
+                    0x60e5e62dbaeea77a,
+                    0xfc84ed5298e87a95,
+                    0x4b09793f391b246b,
+                    0x78ce8089a183f088,
+                    0x5618e0f900342fab,
+                    0x1a3ad42fd694bed6,
+                    0xa15f410d3672310f,
+                    0x8a37dea8b5b61ba1,
+                    0x6a94feae2b4dd166,
+                    0x5fe8ab9522791fc4,
+                    0x1dedb7b691e92c21,
+                    0x011ab7b92e838ff4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xafc1dd95992d6c6f,
+                    0xcaad5c11fc832bcb,
+                    0xcffd7b083b8d8a1a,
+                    0x7392aab58e6c7d46,
+                    0x3dafd54725ede485,
+                    0x5b53f32b33bc7b64,
+                    0xb81fb6ebb702918b,
+                    0x126baa8703d934a0,
+                    0xd4abb38ccdb0c810,
+                    0xacda03dc592eea59,
+                    0x1ee62ba8a26d88e7,
+                    0x00a389cb9c2ba90f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x992927c42809c1af,
+                    0x4d6f685c6c0f3b0b,
+                    0x8c4075b3a6340a60,
+                    0x9ba7ced8fc1c2ea6,
+                    0x1b3a9a68f16e0c17,
+                    0x5a9f9d40c43fae17,
+                    0x1e5d94092c28678d,
+                    0x6baa01ef064cc7d9,
+                    0xf53ccb808a3f7357,
+                    0xbefd48cc0845e766,
+                    0x7484465e6d4afbab,
+                    0x007cd5dd3b4bee87,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdbf56053d2814247,
+                    0xd0629dce908a6204,
+                    0xc5c86210f08573f9,
+                    0xd79e5298a2120711,
+                    0x677043fa5d480d73,
+                    0xa067c69be780d53f,
+                    0x41515da7e2863cba,
+                    0x7567aaac70c684aa,
+                    0x2b43f66b0a25330e,
+                    0x4c86bdaa11a87814,
+                    0xa17dc6469dfa4d4b,
+                    0x002c5da849afb1b2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd653a2dd6ac29fd8,
+                    0x89bf5267ce793292,
+                    0x7daadcb9c1ba7b2d,
+                    0x1b55e34251c6f28d,
+                    0x410841e7dcebe6fb,
+                    0xfa17195f4b7c1f15,
+                    0x2f684424a55a9dec,
+                    0xa7ea365cc9178b4d,
+                    0x4dfa78a1ebccdc3d,
+                    0x9d65a5705f71b4da,
+                    0x9bd2ad854c248497,
+                    0x004e303e122cb7c7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x45b112202ccd4bae,
+                    0x980b934670cfd0ca,
+                    0xea59434c046b3793,
+                    0x3de1481371054de3,
+                    0x189984b4438e075c,
+                    0x6a2435c46eff7809,
+                    0x5d039eb93fee1326,
+                    0x7ab3dec89411e1c3,
+                    0x51d2843d62f86d0c,
+                    0x3f6794ecae9d45df,
+                    0x0b7d6541c1b2107b,
+                    0x00c517368f36f67f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfa99eb1f85dd4e5a,
+                    0x7fafb3c763e7fdf7,
+                    0x9648f57eaa9d287a,
+                    0xb2101d48e982cfb4,
+                    0x1cc95a9c35e54ff7,
+                    0xb37466466b35059d,
+                    0xb033ca41709b7d2a,
+                    0x53515884cd21a647,
+                    0x3397d811162687e7,
+                    0x1c92268a5591a70d,
+                    0x67730d7484eef5da,
+                    0x011b2347b5cbb710,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x2f6989aa4c69aa04,
+                    0x0dbf7f1b4bcb107b,
+                    0xff1b8886b6179c1f,
+                    0x0d5d50b70c4df970,
+                    0xfd3a7d5c726ae5e0,
+                    0x6b3bccb616bb5305,
+                    0x4b9797ccd0c93a19,
+                    0x6cf594526aae6749,
+                    0x8e33c709a1e350fe,
+                    0xc9ac5be1cc865b5e,
+                    0x838418829dbd10b3,
+                    0x00291561ea2dcbb6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x17609b72ea3acb51,
+                    0xbe9a3c44e5c95ef6,
+                    0xb2a4850cfec9c804,
+                    0xed1447bfc84fb501,
+                    0xa42119826da37e4e,
+                    0x7d105339a83d6762,
+                    0x871aae18796354bc,
+                    0xe8dbe1fce7cfc50f,
+                    0xd1ed0cb7ebc99b5a,
+                    0x93cbd5a4c197a9bb,
+                    0x59c682b60f60acb7,
+                    0x011e5a912e484b40,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc6c00ab468584a4e,
+                    0xc5a06045af403c8e,
+                    0x6078dcff4d1c7df2,
+                    0x68bf4280b2b5fd6a,
+                    0x234f47ad1b888ecf,
+                    0x8754a7fc4a88b961,
+                    0xedf99416d0712a8c,
+                    0x707af0e803837c9d,
+                    0x96192e6f85388d8d,
+                    0x93ff8b113c7eece0,
+                    0xf9557819d62cec2f,
+                    0x00a33b77ea2067e5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4a570f9bda79b4,
+                    0x7c10ff21191461a8,
+                    0x2ec2ea427cc319fa,
+                    0xbd8e679ff945bc61,
+                    0x54cd927675e1b5f3,
+                    0xaf43f41fd7fa8714,
+                    0x086df91acd120bec,
+                    0x0b0e5e343d4f0e1d,
+                    0x21b97cc033ee7df9,
+                    0x111272fbe3f2dc93,
+                    0xf724a6e37a006f5f,
+                    0x00432d1cda84bb05,
+                ])),
+                Felt::new(BigInteger768([
+                    0x395850d2c3ff9f04,
+                    0xa256732bb8181298,
+                    0x23200592429a618b,
+                    0xda4b8ceeca439444,
+                    0xbac568d88f16fc93,
+                    0xa05332dfd0220e10,
+                    0xd6452bd93adbe7ac,
+                    0xa64946f6f8d5b2de,
+                    0xa50cf25c5e625d18,
+                    0x409dfe2b712bc15d,
+                    0xcedd0aac0602bfa7,
+                    0x008053fb70058189,
+                ])),
+                Felt::new(BigInteger768([
+                    0x139f2bb5d20526f9,
+                    0x13e9a38aaa23e6c8,
+                    0x662ab5fec42d6f18,
+                    0x74bb2b96bea24b27,
+                    0x388020babf0fdbca,
+                    0xbd2eb1ea23d3721c,
+                    0x2901fc89024cdfd8,
+                    0x5afd4458267fb845,
+                    0x936efcc6a9d8ab1a,
+                    0x02f00cbaf9193f6f,
+                    0x8ec0e2f894ea8f42,
+                    0x00d8eaddefc33c19,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb9e81bf7910bad4a,
+                    0xaa2a52ad303c283e,
+                    0x58819b6b7c8112dc,
+                    0x4aa147a937f23a5e,
+                    0x3ec85971720730d5,
+                    0x020023c78ef90f01,
+                    0xa709b26e06be0ec0,
+                    0xaf80851d6ad54954,
+                    0xe50e72e31664348f,
+                    0x77f95c6aa47c7a20,
+                    0x86c2ee8682377ca5,
+                    0x00c5b2941d60de3e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa146a1485076322b,
+                    0xd7ab11666bb7b242,
+                    0xe228b80c2d51d25a,
+                    0x25357ffcfb3fd782,
+                    0xef928f97d4ac1bda,
+                    0xf5dd60182557a950,
+                    0xc41964a6de62527e,
+                    0xea7970245120605a,
+                    0x7888d815b0d4ab1d,
+                    0x18c8472bb4ac28e2,
+                    0xa72eb22b1b01bbe2,
+                    0x00424f2c1d035b34,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0e4a6094e1fcbbbb,
+                    0x2bb9519fbbaa8334,
+                    0xff5ef0df129267ef,
+                    0x4ebc7c5226d33f0e,
+                    0xf24bd648784e8ffd,
+                    0xf7721cd6fcfebaa5,
+                    0x06c47e26db8b507d,
+                    0x99ef6c432ee4f9d3,
+                    0x6b28a43ef1596ad5,
+                    0xb39bee22cde37bb9,
+                    0xd016d33587b46813,
+                    0x0075a6608edef6a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d6f5614b6e7e912,
+                    0x91ee1d12676d5796,
+                    0x93a44dff9a53f286,
+                    0xeb238b986459f5c4,
+                    0x3d80a8db2cc48f3e,
+                    0x45ccd57237eee949,
+                    0x640c201ec9edd593,
+                    0x60649d41d7c70f92,
+                    0x8cc27f6c19c993d0,
+                    0x6085dffc64d18d5b,
+                    0x36d9c6d8ff59b285,
+                    0x010b10f2876d81b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf668ba5c0dbc84b9,
+                    0xb49405f3f0500450,
+                    0xb864057a95cf95fa,
+                    0xe7059914d31196d6,
+                    0x2c3a3019cb55ebc9,
+                    0x4fe1d61256307fab,
+                    0xa4cf5bb2d2e168de,
+                    0x403bc33cca0151a8,
+                    0x0bb4cee988991faf,
+                    0x2dd2c262ec6ffd83,
+                    0x37baf306a3bc4733,
+                    0x0111e7247474da79,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf47c9da4edc12aa7,
+                    0x0126ccdd43f1a958,
+                    0x3190d21f04b4992d,
+                    0x02312a5ba88f3570,
+                    0x34b8c41af8a580aa,
+                    0xd38c7dfee7172919,
+                    0xc4b7281846c36899,
+                    0x448d13e67c1a1958,
+                    0xff1140b78422fc96,
+                    0x61dc459a7cce1a72,
+                    0x0cca6a00a284c373,
+                    0x0037714fdef2dd8c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xbe0c1e3c15ae86fc,
+                    0xa0baa39fe0aa770d,
+                    0xdf372f30c0c38d89,
+                    0x49ce68382c593549,
+                    0x7737c19c0c221801,
+                    0x2c512949a122d936,
+                    0x0904777d3a1896bb,
+                    0xc8bdfd58ad86ca7b,
+                    0xd4ae6b9c471c4772,
+                    0xc86eed5d8159a500,
+                    0x1c33f2a19ba2d713,
+                    0x009ee70d1970a0d7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf279547bd904688,
+                    0xf6b893cf2df111df,
+                    0xcb6ac61a7e951d10,
+                    0x3d460b11a5f05ecb,
+                    0x78ff65a86ef6768e,
+                    0xcaae3c55f4b00971,
+                    0xd5f462e370f511bb,
+                    0x31a26e320cc95f5a,
+                    0x5322e458afbf3cb9,
+                    0xc35e5f1a0dedd532,
+                    0xd47fc85bef2c9b3c,
+                    0x003e4b08f1fa9ea7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2128b7d57a8a508c,
+                    0xa1051537b2a5b89d,
+                    0xfedc412d16debae9,
+                    0x675272d4eabb2ce1,
+                    0xd3629d764af9aa46,
+                    0x05b2c2047e5ceffb,
+                    0xd3649701c4686f75,
+                    0x95be6bda160510f4,
+                    0x4e69cc7c365fb2db,
+                    0xcea609d1e44faab7,
+                    0x1898c0e4fb2b364f,
+                    0x010b89f240834193,
+                ])),
+                Felt::new(BigInteger768([
+                    0x11f596e253bec25e,
+                    0x78f7edbb148be05e,
+                    0x7f8b5be3f5723a03,
+                    0xa36c5117f6623797,
+                    0xd0d60dc5528b083a,
+                    0xf7b3bc515c0fe8ea,
+                    0x2f1ef6d442232c59,
+                    0x2f06862ce67bb27f,
+                    0x2b7f9aa3be4c579d,
+                    0xb38c4c4236ee8365,
+                    0xaada2a2cf0af5cf8,
+                    0x00d1d7c60f062be8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a6496870fc4b1b3,
+                    0xa1cf736169949f30,
+                    0xccaf1b4a5037713b,
+                    0xd27ffc0b6d58b196,
+                    0x04b5f47e8f77de24,
+                    0xc530a2d905f8d3a9,
+                    0x55482acf5a14f306,
+                    0xcf348217d7e20486,
+                    0xcfd3edea29d8a5a1,
+                    0x7c3c6e3ba7393a8d,
+                    0x2bb86ee008f32f14,
+                    0x00d2221cc7089ef6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdd0582c24afc763d,
+                    0x9956e51a4e84d8c9,
+                    0x06022a5a726b232e,
+                    0xb3c14f5d34a6d4db,
+                    0x097730a95d207122,
+                    0xd51778d6d2ffac49,
+                    0x872812fc3b03ffe1,
+                    0xd2a02c9580e89da1,
+                    0x1ab47c277ac3d1dc,
+                    0x3a966b5f9cab0fce,
+                    0x6612a8de7be76664,
+                    0x007d1c8245f4cd17,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0xdf34b74dc6be018b,
+                0xd8b99e6e5847dfd9,
+                0xd2c1a2b82736653c,
+                0xb2c4cd88c03b92f9,
+                0x74a70082d9bf6681,
+                0x728a27514eac792b,
+                0xdac6db1d31c775a6,
+                0x9f50eb7bdbd5febe,
+                0x77208a8e85b8ccf0,
+                0xc484cd9ffd8d00f2,
+                0x1099202cd67b2f61,
+                0x00b93d50812704ad,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xa2b68f45b0890e3d,
+                0x8591932e00923468,
+                0x7cafbdd9d27e0e12,
+                0xee3e37dd9cf8e0f3,
+                0xb60656c4234efbf8,
+                0x613afc49af719c1f,
+                0x48e93d35416370c6,
+                0xf66fbfb87c7320cf,
+                0xd05508265d57bb0a,
+                0x2d23955652f69594,
+                0xc73575e6284291b5,
+                0x0102236fe9f1b333,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x3a589a173b1af0aa,
+                0x2897144f6ab31d8f,
+                0x7346265aefaafbaa,
+                0x6126ec8559d605a9,
+                0x31ef853ff4470eb9,
+                0x069b35e0f9ed6af0,
+                0xa8251f11202a056d,
+                0x5d2c157728861bfb,
+                0xaf773d9e5298802d,
+                0xe5d2e43c8a3b3678,
+                0xab30368d947911a7,
+                0x011435debf53d9d9,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x61533d72484fb15a,
+                0xb630885e53d1eb7d,
+                0xd414d058438cec07,
+                0xe6af605162028b3f,
+                0x922eb32c67cd8955,
+                0xaa52a75f33bb669f,
+                0x75b0d1bc805b5785,
+                0xc8c924d71512e7d7,
+                0x8dcafa560abc038c,
+                0xe90e9c9f8fb8e861,
+                0xd48c49fc46bb0a28,
+                0x00c3775c948662ad,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x45ab9fc7b3edf2cd,
+                0xd6dffeff10d14918,
+                0xe82a9c344e38a960,
+                0x04f7f19b0ca82074,
+                0x60a140d5738f3551,
+                0xb4b15b9290d0845f,
+                0x1c587ab13af57c9d,
+                0xcff00bca433e3dea,
+                0x05258fff567cf356,
+                0x60840337b18854a4,
+                0x4e16d9d33a2309bb,
+                0x00a4161118e04bd1,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x51f3a88430195c6e,
+                0x8c64008f661643e4,
+                0xa3f7b9f76c3571b9,
+                0xd09f6c4b8a341abf,
+                0x6e0f214a527d65fd,
+                0xbe87c2793f30bde1,
+                0xb0aa5ccf1692018a,
+                0x603969781d90b1fa,
+                0xb6aece460237cda6,
+                0x37fca77d049183aa,
+                0xeb82018bb61f3db9,
+                0x00f51544835697b5,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x52b81999fff975da,
+                0x4169d04d227c7268,
+                0xceff9f89d5e982a9,
+                0x1539ddeff09f6ea7,
+                0xaf6d929ee0db41c8,
+                0x3361f8bc6f392a8e,
+                0xc62fecd468ac1231,
+                0xc8b5b4f28d5a67ff,
+                0x01047aa838b44d7e,
+                0xd992c3279eb2340b,
+                0xac62c968d95fd094,
+                0x00c63aa0262dc7c0,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x8078c8845af50f2c,
+                0x691c82634bcd02b7,
+                0xf9c05e4ec23a40ae,
+                0x534e5aea337d5e1b,
+                0x512660603b411488,
+                0x36d2899ce61a3d4a,
+                0x5faa84e69fd5f0f3,
+                0xd9192e2430edd058,
+                0x9e884a2dcd28d992,
+                0x602114fbb3a385bc,
+                0xbf73737cc494e1b7,
+                0x005bd7552c31862c,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x578143bfa8ab1a47,
+                0x244f576da0c8ff75,
+                0x5e2e22a659ee6b84,
+                0xe55d89dc3ee5ac3f,
+                0xb65eeb9c71cb3b89,
+                0x69611cd940a042b5,
+                0x6664b64313a3fdaf,
+                0xe4c9fdaa2e4b49d8,
+                0xe4b9b85b80ebff0b,
+                0x53461ed1342bb7ef,
+                0xeda7a4bf04fc4035,
+                0x006198eb862124f1,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x7c8073a0a6d72cbb,
+                0x165d7bcd840b4a7b,
+                0x12b2cdcee7dcfe96,
+                0xcc79ae7116d639bb,
+                0x0467324a3543c7de,
+                0xdd76012a655ebd4a,
+                0x2d445d195b5d475c,
+                0xf7fcf021eec505d2,
+                0xb17ffe9cc8a38edd,
+                0x173f04106b6c6465,
+                0x9f929d11b491d83b,
+                0x00e1c521dd5e7f47,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 475, 476, 1430] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 475];
+        let mut opad = vec![0x5cu8; 475];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
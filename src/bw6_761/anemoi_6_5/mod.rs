@@ -0,0 +1,2868 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 576 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 5 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 5;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// One element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9f6ed1080b6aae57,
+                    0x45a06bb64b34398a,
+                    0x84ae4a681fce12b7,
+                    0xc3080f3cf7cb3aa2,
+                    0x868f1919a6147856,
+                    0x90caaa6ab6f1eb29,
+                    0x5954572349297cf1,
+                    0xd88598fd283599bc,
+                    0xc2129d103ed7dc95,
+                    0x96c2fb5cf2bbb669,
+                    0xd433e0a481db7279,
+                    0x01072f6420052387,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1029caf609b2ff80,
+                    0x4c0b620abe46a0ab,
+                    0xdb27bf417d73e0f2,
+                    0x6c7f3efdd6bd3418,
+                    0xf1ff108822d5c2be,
+                    0x41a7304f72929725,
+                    0x80a7e830f277c027,
+                    0x5e303b611508697b,
+                    0x67214274a6867556,
+                    0x556b27fac0d2b32c,
+                    0x2102be0c1e2fbea9,
+                    0x001601cc7af40aaa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5dd1352cc395f855,
+                    0x17c232400f4997f0,
+                    0x118dfa9f7b4fcf2c,
+                    0xdff446f790da3842,
+                    0xbb539b214bea6e84,
+                    0x4d96b8a2b481584f,
+                    0x6b30b10ad4d9560f,
+                    0xc1c29ede459cabe1,
+                    0x0bb58721f0693070,
+                    0x6ddb3676c04fc683,
+                    0x3e70b1dddad30696,
+                    0x01205aec20472ae2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe7d4df15254a3976,
+                    0xcd7cf8bb4acdb37c,
+                    0xcfcedef58aba0295,
+                    0x753d0df3994160b8,
+                    0x21b50daef43bd60d,
+                    0xf969817d0d23e200,
+                    0x9a84d3c0796115a5,
+                    0x44eb32cb7b2cdfed,
+                    0x047fa7854bb6fa9f,
+                    0x348035224150b06c,
+                    0xd008a8ab6897b5b6,
+                    0x010597ae8f64ea5b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d8c7899be9d83d3,
+                    0x0aeebffcd87dc6a9,
+                    0x5b98d325f9eca8c6,
+                    0xd263e983cd0b203c,
+                    0xcf4deee11f5f3e8f,
+                    0x461c175c7cea1caa,
+                    0xb7ad50b0a577bc99,
+                    0x90a2b4d78d02f259,
+                    0x0addf1c3d80d0c3e,
+                    0x1c8d2cc966feff0a,
+                    0x40202f15e979c24f,
+                    0x002f22bbd6ec3ab6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde77d66fe7ad194b,
+                    0x6a3c11fb31959bf1,
+                    0x77b7fb3b8765f9e7,
+                    0x40cc94786a25279e,
+                    0x6893d08c19443dd1,
+                    0x12c301563a6799b8,
+                    0x62b8c97805d909f3,
+                    0x32601b06c68cfa23,
+                    0xbe32abb856a175e2,
+                    0xa1279e0726c90d9c,
+                    0xfafa07e97a1b8fc6,
+                    0x005182894a01668d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd80c0cc4a6ad6035,
+                    0x3767249d0e80dd0a,
+                    0xffad5050cab12958,
+                    0x7ee9d9fe83ba6fd6,
+                    0x1f57dcb30ef70329,
+                    0xae62a3dfff5da6f6,
+                    0x478867b270e83d06,
+                    0x543cde925b6a8bee,
+                    0xb6421f91fbe049b5,
+                    0x5cb8c65f9d690341,
+                    0x6e177c99a2d307d3,
+                    0x0109d76c35f5eb4c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae83ee2a53f1f321,
+                    0x72a4020c780f05d0,
+                    0xa2f906ef7d790efd,
+                    0xdf27d001c9c80d72,
+                    0xdffcbebf7b71ba23,
+                    0xa8b3ffbc48c0381f,
+                    0xb31c1279ba1a55f7,
+                    0x1cb06c36272dd8af,
+                    0x433272ab0b886df1,
+                    0x929b420af23cff00,
+                    0xd1ccf62a5ede6fc0,
+                    0x00b4ee918d14945e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc15ce50eb80d0301,
+                    0x0fd619b37e4a9f3c,
+                    0x33bf1c1d6e75b90b,
+                    0x252877a4b4ec409a,
+                    0xd204374b90887b2a,
+                    0x4289fd486353dc24,
+                    0x387069126fa279f8,
+                    0x84efcb822afd21dd,
+                    0x4c0cfffb65246029,
+                    0x621dcfd1d3c4ae2c,
+                    0x4dac46f681a0a999,
+                    0x0071a69cc3ec41b1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x74d55db848863109,
+                    0x40f2ac68617c4715,
+                    0x97544c7f0ab76a56,
+                    0x68bc2426dc7c159a,
+                    0x513f46964fbe6179,
+                    0xabab08a616ccfc90,
+                    0xef1fc34fc511394e,
+                    0x25fe7eab9764adc9,
+                    0xf5d6c45919e4f6df,
+                    0x7aeb3d1b451b6fe0,
+                    0x89ded0e6d6705d64,
+                    0x002e9c11935b6e50,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb010cebcfbd4405,
+                    0xd6d7d37ccfc3c657,
+                    0xb2391e3010ec7a6c,
+                    0xa375c6ef67edbb8e,
+                    0x6c0e28aa61d9a337,
+                    0xb08795e48fd29b39,
+                    0x3458565b2133a97f,
+                    0x73fa923873472245,
+                    0x99f64d8ee89d10a1,
+                    0x96c777b8c09a60a4,
+                    0x743e2c4a72cc9e84,
+                    0x005aa0bbaa853084,
+                ])),
+                Felt::new(BigInteger768([
+                    0x050b17bd40f83d9e,
+                    0xef95ce567f6ce3c7,
+                    0x3cbd6989751fe95e,
+                    0x8d3e614089bb973b,
+                    0x805d7980bb93d51f,
+                    0x2197edb3b132b834,
+                    0x5969d2e579a7e47e,
+                    0x76b6e89bfcba1079,
+                    0x7636888de19b0fc6,
+                    0x38905471357ef284,
+                    0x2473c308e8455768,
+                    0x0041f328d761fd3d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x07aeba19c63446d1,
+                    0xc7124d49945939f0,
+                    0x3ccf3616657704c0,
+                    0xacebf4f0404b011b,
+                    0xafbc130959a24290,
+                    0xef37948546bc4273,
+                    0x5cb54c84883a8677,
+                    0x2212607fe5dc6380,
+                    0x46cc446eddffc23b,
+                    0x0eff5f7ae106c16d,
+                    0xf21a6f2fdd6c7564,
+                    0x0096efcc0437a829,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0a1058e42401eceb,
+                    0xe4acfce287599f74,
+                    0x5444ad0177af7743,
+                    0x4a9f9941f08843b2,
+                    0x1eae204314aa9894,
+                    0x8d54b112b388d6c9,
+                    0x2d17780f1a888c66,
+                    0x3674f020d549314f,
+                    0xee63642ea66d167f,
+                    0xe3f557e4c9167482,
+                    0x21331f31ac562529,
+                    0x0103df4788f99618,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5576990c3698102e,
+                    0x7da21fdd69ca7576,
+                    0x0a3caf00f46ce854,
+                    0xd06a019b5963528b,
+                    0xc30643fc1b5aa0e4,
+                    0xc1820c4031d4099c,
+                    0x0067ef417862eaec,
+                    0xc979e8c6ef177952,
+                    0x6a525bc2cef6789a,
+                    0x33ff89db06adf27d,
+                    0xcce95f39dc21255e,
+                    0x00c66a9079533379,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce9361d09c2c7372,
+                    0xd2290d5fd3c20d36,
+                    0x1be85a4fed76c70a,
+                    0x514aa9cde7654c3e,
+                    0xf96b2c6d381ddcdc,
+                    0x9a50895173f04654,
+                    0x661be9722d8406bd,
+                    0xaa8f40fdcc8f199f,
+                    0x6e90bc53b578510c,
+                    0xd2eba6cb2eebcf07,
+                    0x7680d7c9f30d27fd,
+                    0x010f5f37639d6657,
+                ])),
+                Felt::new(BigInteger768([
+                    0x941517cd2b09565f,
+                    0x8ed7f7103adbb2a3,
+                    0xf2459152d3411cc3,
+                    0xa329e19a3b7c50fb,
+                    0x91cb4506cc0c99dc,
+                    0x053475edee8a5be1,
+                    0xd9a44cdecc833cbf,
+                    0x5fb20124f8fb5a54,
+                    0x5d3aab601eb507c7,
+                    0x8cfc7d3b9cfa4854,
+                    0x22e5955f90f7faaf,
+                    0x00426290bb4c071b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xec01de40371e2358,
+                    0x8fbe9cc7a1e5a9cc,
+                    0xc281da6d772eb875,
+                    0x52b6354b896f04d7,
+                    0x51de8d8c233920c1,
+                    0x35257f093b6ad79a,
+                    0x460ff3aa2be0087a,
+                    0x729faee9b9b348e4,
+                    0x2a63bc032b2fbafa,
+                    0xbb8599c63e17e2cc,
+                    0x19cdd29d0896fcbf,
+                    0x0024483cf8b3dccc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xcc7d3f777d5756ce,
+                    0xd321e25337ef5b33,
+                    0xb26ba94c235b97e6,
+                    0x6f852ad35f0a3059,
+                    0x2f4118c94bd736af,
+                    0xdb0ad0460cc83882,
+                    0xe7b5ea18347b03ea,
+                    0x5b0f1752ae042a7e,
+                    0xa16ee139762b985c,
+                    0x4b08bf87eb1bb1bb,
+                    0x7160601a72b07003,
+                    0x0024b695c759f082,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7c92576ca99fc257,
+                    0xa0bde3dde4b6cc34,
+                    0xad34e641dd5904a0,
+                    0x7c117c858fa1bcc1,
+                    0x86684171a42b0128,
+                    0x2d79253036a74322,
+                    0xfd7e8246b7ca32b2,
+                    0x603fe1a20540cc0a,
+                    0xd31f0cdaf0b2172a,
+                    0xb2b1050588669910,
+                    0x41a0deb04f17bdb5,
+                    0x0091b60707159052,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1390a06c2610c8d4,
+                    0xea3d26c11922df95,
+                    0x0e8e7f1028b762e9,
+                    0x0d88ae9c83d07070,
+                    0x007b4871f35fd13f,
+                    0x223fba72d012f88c,
+                    0xf9f3e5bddb2d9c22,
+                    0xaeee5a1837755746,
+                    0xdb4c67e9d772f4bd,
+                    0xb0b5ede8562cf8f3,
+                    0x2a03c57988e52d1c,
+                    0x000ea721c94039b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8de99584f273ff0e,
+                    0x11936f4fd1ee0304,
+                    0x6fd3ad5bbd507a45,
+                    0xbeb3a513975de7c5,
+                    0xc79b152e52d20a1f,
+                    0xcf924b6db0ae3818,
+                    0xefb5f848e75c9884,
+                    0x0bf39344037e0704,
+                    0xeefb6a76181423cd,
+                    0x42622dc71179e6da,
+                    0x47d4b7d448004c2b,
+                    0x0102bd9ec1463142,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4b72fa54b1a47803,
+                    0xd57bc8c37ccc63f2,
+                    0x1cd0f22c717fe46c,
+                    0x84d0112a8e0511c3,
+                    0x780bbe9343de0ae3,
+                    0x6f9293bf2a21ee31,
+                    0x215fd2c8caf5c302,
+                    0x8f39033dc7a14e39,
+                    0xb062e8a3d13d8001,
+                    0x3e3337586fcb75e7,
+                    0xa149af529b68c61a,
+                    0x01115eb47d2ded8a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0035ab2f17674625,
+                    0x7c4274953b745f23,
+                    0x65044b599582ab45,
+                    0xa594725ead8a4524,
+                    0x927c3fd882a14cfe,
+                    0x18e6b1c0a9d7cbcb,
+                    0x6184b5ee558e84cb,
+                    0x499913adabe9b857,
+                    0xf1d4293b73595048,
+                    0x7bddf0d1436a6610,
+                    0xe30b7a4b09b15102,
+                    0x0034eba21d522dc2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x6500c38a8e3f1645,
+                    0xaeb14f1be76359f2,
+                    0x614b5e4753b83d4b,
+                    0xff6caa839e7a3623,
+                    0xd8f2ad96d365821d,
+                    0xed3d26ac3350d604,
+                    0x1aff1a3b75d3f6ce,
+                    0xa64555697842a884,
+                    0x2b8585b5f3d9007c,
+                    0x32c24fa34af33d5a,
+                    0xf7964cd3f41d500e,
+                    0x006d199112732a22,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbf8f939a782ee7c5,
+                    0x521cda32e1a4b0bc,
+                    0xc5d32bf7df37d748,
+                    0x3fe62a98e6a79f6c,
+                    0x9fdb81b9a9a46bc4,
+                    0xfb1f85824640e577,
+                    0xf2b61a3cfdda2663,
+                    0x021766a0c7c20e05,
+                    0x23c1cffaef4d1e29,
+                    0x9092ed4e2522d8c3,
+                    0xec2eba2b5ff1eaba,
+                    0x00d1cb1321044734,
+                ])),
+                Felt::new(BigInteger768([
+                    0x003e12496ca40f17,
+                    0x7a18b43e91e069dd,
+                    0xcf4f6a903ca54f35,
+                    0x1440e8aee7801ccd,
+                    0x6f831cd66bb29c7d,
+                    0x7a6c0c9e376dc894,
+                    0xd49e861c8afb839e,
+                    0xda6d8c70be4edbd9,
+                    0x05f0f666d065abc5,
+                    0xf075cd56fc90a180,
+                    0x6f4013a1f0975f85,
+                    0x00d2d9b836b13e98,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4aaf4914c8009756,
+                    0x96741b0ccff2b875,
+                    0x354c02b08b061467,
+                    0xa2615ee44070bfdd,
+                    0x3cace8b6268b3520,
+                    0x77701647b2def724,
+                    0xf9866cd8d6ae408b,
+                    0x263ff6acc8658e45,
+                    0x2b7ad49448cf3e19,
+                    0xb1f24db5d0f3298f,
+                    0x750333087697d22a,
+                    0x0041b53eafbfe1b4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44b0d6e2b131b4d1,
+                    0x3b95feae4b529a07,
+                    0x09310db17f09eaf3,
+                    0x58963b4d0888ae7d,
+                    0xa904033d304a21cf,
+                    0xdca5586f571c6bd0,
+                    0x3f357451bf50f46d,
+                    0xad6f4f0d075d5057,
+                    0x9786b92aba16a284,
+                    0x658899d185d77f87,
+                    0xa4540cc5085d02b8,
+                    0x006571401724bc8b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa0b1b23fc636912c,
+                    0x16b6dbfc67bbabe9,
+                    0x84c5d024bc9cb6ed,
+                    0xdb8d9878ce31349f,
+                    0x44d0406dd93017a5,
+                    0x3a3cefce3f683e4a,
+                    0x392d5c4085547a2e,
+                    0xad2e8ff63f54a460,
+                    0x022a1f72c658da68,
+                    0xf9d954a636a78b3d,
+                    0x508d56832f798af7,
+                    0x0021ac7857c30cf8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb9da9fa92a40ff75,
+                    0x5a531ce388c48fb9,
+                    0x8035ead7637f56fb,
+                    0x87ab840f7c41935c,
+                    0xa5b06984b7ccec89,
+                    0x7344e962477a0e51,
+                    0x077d57b4a60a2359,
+                    0x1866daadfb8f9419,
+                    0xdf373aff1faf8c9d,
+                    0x2c7d91a2875c5033,
+                    0x055785c403ac0401,
+                    0x000a4ab3e8c22e53,
+                ])),
+                Felt::new(BigInteger768([
+                    0x377468ecc768f8f3,
+                    0x4e3d5b8fab2c7090,
+                    0x6e1afc3f0b40a4a3,
+                    0x07630a946b0feb5e,
+                    0xea3b9bc5d34a2707,
+                    0x77513f1f800bd1ba,
+                    0x030ecfaf15cbb53a,
+                    0x4bfe2e9db2182c4c,
+                    0x783c080c5e58f83b,
+                    0x155bad0a68bf5c07,
+                    0x87f1e61ee0799640,
+                    0x003c91e8d56d1229,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5861db3c492cdc52,
+                    0x36effe4de811dde7,
+                    0x5570688cce7a2fca,
+                    0xca97bf0fc49981d4,
+                    0x11fd9656a3b6d77a,
+                    0xe9684e155ac5e087,
+                    0x20b6c481bfa0f174,
+                    0xa393f9080d5edf7c,
+                    0x038ffbf9fa524b43,
+                    0x14ce84215d407d2e,
+                    0x0fc21c4c724761de,
+                    0x00e14c02c392e2d2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x331dffcb351b8b98,
+                    0x4044e9bdc962eb7a,
+                    0xd08bf02743d3813e,
+                    0xaee724d0655bb8a2,
+                    0x362641b4e961838d,
+                    0x51b5f6517cb14643,
+                    0x13438a38cd701935,
+                    0x9b0aa17749dd5841,
+                    0x61f1cb25c5e06c00,
+                    0xf49118222240a09a,
+                    0x0be5f3e69665695b,
+                    0x00637cf8df5ad2db,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7cf8dbef55ea62b4,
+                    0x6654bad5c6a2efb9,
+                    0x124e049f5e49e6fe,
+                    0xfd60ec485d697e72,
+                    0xa87cc0daf1daa494,
+                    0xfe13474073bc09df,
+                    0xc3f884d61a421646,
+                    0x89b51e6dd1e5891c,
+                    0xd33ac9e6291c46b2,
+                    0x12714ffa32d54453,
+                    0x4c03c534c86ad28e,
+                    0x0100afd759c9b330,
+                ])),
+                Felt::new(BigInteger768([
+                    0x68b80209ea4d565a,
+                    0x922fbc9da2388815,
+                    0xb4f33dfa908be67d,
+                    0x2113245a8aca2d62,
+                    0x034e705857c520c0,
+                    0x2b9461b63141f21d,
+                    0x4bc7961ddd8e4a5c,
+                    0xdbe27f9af51ac73d,
+                    0x5c5ff539e4e87398,
+                    0x0eafc4eb1aa91d67,
+                    0x036036e450b6da20,
+                    0x0097a24091cc85f0,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3fe9500de614f0da,
+                    0x1cacfea85f066874,
+                    0x0f23a886d3bc55a2,
+                    0x3c7ef9d657a28953,
+                    0x5daf02dafe7eeb0b,
+                    0x16e627d2632a73df,
+                    0x3a08abcdb531a55b,
+                    0x675913d0a61751ff,
+                    0x8a887d95c3d6fa53,
+                    0x9eed4f2f353abf27,
+                    0xdc7af996fab052af,
+                    0x010d3b5e56aa9a75,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb0e30c61214ed91b,
+                    0xd0e5f731807ed3d4,
+                    0x30beab02aae06591,
+                    0x5dd4755b293db35f,
+                    0x76a6995dd1b8fb23,
+                    0x6ed6b9d1f7fc4705,
+                    0xd5722dd641478e74,
+                    0xe07f7495667853c8,
+                    0x30406249420a75ab,
+                    0xd275b7b843a7a593,
+                    0xd3a70503ffbf3b3c,
+                    0x004b1d85a35cd704,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6e1e2faf11aa741,
+                    0xb0142697b25603cc,
+                    0x743b57c4799311d1,
+                    0x97587fe3b592f655,
+                    0xec2c8f180d4e59c4,
+                    0x0b24605a584cc41c,
+                    0xf0506fd33e785206,
+                    0x663ebc3c1f284d41,
+                    0x812cb20efb650d2c,
+                    0x1a8d99cbf5a570f2,
+                    0xcd39fa836f59d722,
+                    0x001802f4692da0ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x33ed7b2c0f7cb76b,
+                    0x63544c4e8c8f5868,
+                    0xebdf28d70fbf0cae,
+                    0x3773001716aa8cf9,
+                    0x4d7714317207bf78,
+                    0xa2601f4427687a0d,
+                    0xdaee778b3794e232,
+                    0xc935bbed8e5092f0,
+                    0xba3000804a4f9ced,
+                    0x41b8dccc5d70ff5b,
+                    0xca518c023f8d3520,
+                    0x00e93f56d08550c0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd80b8a46858e0b83,
+                    0x879719e331c9a527,
+                    0x2fc88a1aa3892bb0,
+                    0x79ce161c47e50678,
+                    0x7ff9d3c9adc9ccc3,
+                    0x7b80ae2a12aeb380,
+                    0xeb44fc133a2ee8c2,
+                    0x19189cfa45187ae7,
+                    0x621ac8e996ef4dbb,
+                    0x1cfb3718254c5277,
+                    0xf10e44f379c9ff4d,
+                    0x008524e93574e82f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72e1b25473a19140,
+                    0x5dff4556acf96d95,
+                    0x2ca997c890042d67,
+                    0x0a5b86ae0e72b34e,
+                    0xe8b992051fbdcd54,
+                    0x46c785c5d579e089,
+                    0xa154103f3c0c1e10,
+                    0x25b1fcdf89ff59d2,
+                    0xf72e400fa2300b90,
+                    0x04ee6f95f56f180e,
+                    0xf446027c38ad8ab5,
+                    0x00c15ecdf5abad28,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd568678f1e2e431f,
+                    0x0f4bfb7a1a78934e,
+                    0x5bad626daf3a5269,
+                    0xebc1100ff9e7e19b,
+                    0xac0305690e4ff339,
+                    0xcb1eb75f52fcc45e,
+                    0x83d3814c65e82464,
+                    0xf642638bf1f65e37,
+                    0xc09752c02bd7ebd7,
+                    0x94f513f6446cbe02,
+                    0xcd0f739c4a7b0e31,
+                    0x003f3130ba8bf859,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd66dcc80a1cecfee,
+                    0xb226439689207471,
+                    0x0c302a7cf542c52d,
+                    0x510f844a21f61ca9,
+                    0x555797b2f9effe7f,
+                    0x76f4b5298cae2f51,
+                    0x6d3accd6cfd735da,
+                    0xc1b2aa0d451821d6,
+                    0x9816e18a938af5ca,
+                    0x4eb11dd933d5a83e,
+                    0xdba3d50b44f239b1,
+                    0x009980f139a519aa,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4933d9d88b5512e1,
+                    0xf6e767aeac11650b,
+                    0x49582023d9b9e2fd,
+                    0x17453c390ce38963,
+                    0xe82736dd1ad0bfa7,
+                    0xaf9b611dcd234c7b,
+                    0x306ef9d43ca0dfa6,
+                    0xac2b8e50eaa9cedb,
+                    0x1be3439485923495,
+                    0x62f79cbb36f6abd0,
+                    0x1ab7f197c01396e9,
+                    0x011af624b68e904a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfea6163fd4f1c7c5,
+                    0x2937fcad6bc886c8,
+                    0x63bd844269fe03de,
+                    0xd655b28b35170db4,
+                    0xc11fe60dc10bd38c,
+                    0x353b64b834d8384d,
+                    0xea138beb8248db1a,
+                    0x4b4ded8416cec136,
+                    0x2e2ee31d241fb1f1,
+                    0xe438b6f564d0c895,
+                    0x7e54a1130c332bc6,
+                    0x00620259b953559d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1664387a4e47dc7a,
+                    0x7b95f19d33634182,
+                    0xa15b2472b6c6969d,
+                    0x5411cc26497b85d9,
+                    0x35e0d34c5472fde3,
+                    0x012029a1d220d0e9,
+                    0x96dafb45d6f653d4,
+                    0x36449a163eb2626e,
+                    0x76ad56734726ab6f,
+                    0xdd7bbf90d835dcd5,
+                    0xafe432c71c127025,
+                    0x00e7bd2c9a0f34be,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf653ce298f557994,
+                    0xe9a9ce4f4f6bc447,
+                    0x1127350e0e346704,
+                    0x6c218c07de4432ad,
+                    0x45955c8296324ddc,
+                    0x098399c097cf0e22,
+                    0x91a4ba1b65de25a1,
+                    0x50ce756a4c1a3b2b,
+                    0x32ccb32c8153e939,
+                    0x655b229c665d1065,
+                    0xb960582273da0c87,
+                    0x007bbcc45ea2f40f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x88c0fa7239bb03cd,
+                    0xda1d9200890b0ff0,
+                    0x4f4543af7cdadab3,
+                    0xbde55d52460f0245,
+                    0x022962613abe93c0,
+                    0x2697bd19e88cba80,
+                    0xc02749eef940c91b,
+                    0x243874fc2bf64898,
+                    0xdc09a9fa63de97d0,
+                    0x1fdabec158ad51ea,
+                    0x4ecf903e7b1f7944,
+                    0x011da0f7cd2a43f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x532c8818adf708cc,
+                    0x1bf7923ed7a53c7b,
+                    0x65c2e806a620c013,
+                    0x89a3ac87b1b984a9,
+                    0x050ae13234455c88,
+                    0x6b05f11eb06e9bff,
+                    0xa0f07291596e83d8,
+                    0x885895f75cd33c30,
+                    0xe653ec153e8c84ca,
+                    0x60af75439ac54bc2,
+                    0x7c95d17dc3544e0f,
+                    0x0015ab46ba836ab4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x604bf2e61be038e5,
+                    0x3f705ad54566ee40,
+                    0xa9795001a0d969fd,
+                    0xae668ec8d5d4f716,
+                    0x5171ea24898c6a1c,
+                    0xdd838cab1195d025,
+                    0xdf13334a44176e09,
+                    0x47a00eca564d4b6e,
+                    0xc414bc9cd64723a9,
+                    0x64f238ffbcbb4922,
+                    0xe16135b8ae79d0a3,
+                    0x00b8e09ccd0afe3d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c9d770ec41a55a4,
+                    0xcb3fa2a885cb88c0,
+                    0x251e4d0bc2125ad2,
+                    0x28396f707ef2b61e,
+                    0x65718f492168b928,
+                    0x88fbefbe7ba3eba0,
+                    0x88b5871adb42cc82,
+                    0x0ba062a47c6ab696,
+                    0xb0423a22123b1e5b,
+                    0xd5dfd24caac16fb4,
+                    0x54d4e30e2340d711,
+                    0x002c1e4a1822a535,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2998c7e0d0eb5491,
+                    0xb6b2800059f4c5ac,
+                    0x5a32b1e18a2191e6,
+                    0xdb7ae45bf9bae1c7,
+                    0x4439425867309c42,
+                    0x8ccceac5fcb96103,
+                    0x52f20a9718f3bae9,
+                    0x8a8a0cf97a0dcbba,
+                    0x5a01c0ade9056f05,
+                    0x06b454c2e4a83d70,
+                    0xac8923355add0d39,
+                    0x0070b28bb7f03a89,
+                ])),
+                Felt::new(BigInteger768([
+                    0xac0339640ef922cf,
+                    0x51aace43c955d62c,
+                    0x4d1f879584a2fe1c,
+                    0x025e6df946b176ad,
+                    0xa9b982479521a084,
+                    0xcc5949c3f4eea6eb,
+                    0x78a2a3b8a7ca2375,
+                    0x8dab8cf540d515f9,
+                    0x6fce37b9d84450bd,
+                    0x47cc2cfeeadb93b2,
+                    0x5daee199dda3ddb2,
+                    0x0066b946187ca126,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0d6dec0bd4566c47,
+                    0xf2d7039f7c0f8f71,
+                    0x920d8da97e677112,
+                    0x92f54c9f652f35fe,
+                    0x5b84d43cc9b3de2c,
+                    0x38ef89172bff2768,
+                    0x26297bf13d84c9da,
+                    0x47f1cf0f43ee5a8c,
+                    0x03bf2b82ccfdac32,
+                    0x9b2a0b17b415453f,
+                    0xf0fca3534ea55e75,
+                    0x00c7968173a8d57f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5c43d66dd0301140,
+                    0x83838c338388bcfc,
+                    0x211a73a94d6f85a1,
+                    0x2c3ab8b8d356141d,
+                    0x930a4601e1c7f0b1,
+                    0xc6d35b56e99ca8ed,
+                    0x0b5e7eb7faf01330,
+                    0x9db58bae4b9b5a60,
+                    0x41c471797c820c4f,
+                    0xce0076981f38efb3,
+                    0x86b0b5adc5be4d95,
+                    0x002f9dce3d9aae98,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcba6dd86ba552719,
+                    0x34f6fbc4ff46512f,
+                    0x33aba161930712fb,
+                    0xa1f786df6053f69e,
+                    0x3f3fe374c272ca46,
+                    0xab0b851899961e76,
+                    0xcaeb6d8306ce82df,
+                    0x37af44e6f54f6c3d,
+                    0xc16540b988f6575d,
+                    0xfad338d3da80bee7,
+                    0x4b6af0584e4be01b,
+                    0x00bbb1bce25a4441,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe6b47083c76cda5,
+                    0x040b2685edadb395,
+                    0x2a1f18bfe79c21f3,
+                    0x5d4b5d6524e3e793,
+                    0x84edd1e3286bd664,
+                    0xd103c9a68d5c3f5d,
+                    0x6bfb32544a13bf79,
+                    0xe7607762166155d2,
+                    0xc95f8e184c550a7f,
+                    0x34cc66ec30fbfcff,
+                    0xbf75cd13c40f8ded,
+                    0x00f38435f215ecef,
+                ])),
+                Felt::new(BigInteger768([
+                    0x999d0a2b960f0b57,
+                    0xa88b51101fdabb93,
+                    0x06c3f5040e396761,
+                    0xbe855a7a355ef2fe,
+                    0xc8c18a7c05168ce7,
+                    0xc38818835dfb506b,
+                    0x546f0e9418bab24a,
+                    0x95a9baee5dcf5ddd,
+                    0xe0d54cff96c58cbc,
+                    0xd7b709f76343c75c,
+                    0x13cacad30763cb2a,
+                    0x007007a2b8ef096c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7853d15f4ef4e6dd,
+                    0xa072852e61c526d2,
+                    0xb8b3c348726484e7,
+                    0x4cd63cae3713a050,
+                    0xa3921de5872b19af,
+                    0x6532dd6314c810fd,
+                    0x99a2b0532e557572,
+                    0xdd730807e0c287db,
+                    0x398aa06acd08bf5e,
+                    0xdfd7d1cbcbe35d34,
+                    0x566be60f1cafb8ba,
+                    0x0061906b38278195,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7458a0d207930255,
+                    0xa38755de69e7d0cb,
+                    0xb34bca57310699a2,
+                    0x66f2aa6ae1f426e7,
+                    0xa9959e1afa852dd0,
+                    0xccb4827710f27fec,
+                    0x5ed7d7f682ea583a,
+                    0x46cb1962b0bd4073,
+                    0x29d720aa6bfe32ed,
+                    0x284516fc466471a2,
+                    0xae9d7779d5adaed4,
+                    0x00cd02ae0a840029,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd98be756aef8c570,
+                    0x5cc4c8f7b9ae805f,
+                    0x16d79915027ac62f,
+                    0xdc15b5a129bb7f76,
+                    0x636794782cc2d199,
+                    0x187d5fda25a64154,
+                    0x0476d2d58e491ed4,
+                    0x29380cab070ddcd9,
+                    0x0216b662797194e9,
+                    0xf2a5fedfdb92ab3f,
+                    0xd750c6b638b510c1,
+                    0x00c9b34be92ded89,
+                ])),
+                Felt::new(BigInteger768([
+                    0x171388072ac9da4f,
+                    0x09fd23fa776d42d2,
+                    0x67092f9b59930916,
+                    0xa3499d657c5be251,
+                    0xc7aa442b69e55e84,
+                    0xf86f7e499ef36e5c,
+                    0x2a65bb4494cfddfc,
+                    0x3ca1e345594201a4,
+                    0x27b5ba7d83b0984b,
+                    0x6f484d7562df63de,
+                    0xf887aa10dc39ca84,
+                    0x00fdf3928f4c9984,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d3547697ae1e6fc,
+                    0x38f27c0efbb13048,
+                    0x63c6a7c7d3460381,
+                    0xf401da5fac656279,
+                    0xac1db79a29814315,
+                    0xf5f5eff9b558c97f,
+                    0x41f0b72bc7c7a53b,
+                    0xeffe4b93b218ff3b,
+                    0x56b196ff19eba1dc,
+                    0x679dbf8161cadb14,
+                    0xdca2dbb0552af99b,
+                    0x0026267ea4dbcd1f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01e6a549a9ae8a7d,
+                    0xe29eeaa069a170d1,
+                    0x93bf1d4427e04825,
+                    0xcdfeeb00f631c4b5,
+                    0xf8496d0cb18aca80,
+                    0x8a9aa5b337c41813,
+                    0xff75cb5cbc0391b8,
+                    0xeb328171569d5ab6,
+                    0x0e5c1091684b937d,
+                    0xa43dfc4fba085d64,
+                    0x2981026d75acc1c1,
+                    0x00100fb5cd4e2a83,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbf2ea4e7f6819086,
+                    0x5056709a2030cb98,
+                    0xadf4f4d6007794a7,
+                    0x88292d412d1a9762,
+                    0x49c27000d18b05fe,
+                    0xd2353d6b70a85c3f,
+                    0x1fc04b5114c6d7b8,
+                    0x9f672fc97cbbf876,
+                    0x732f11c45da638cc,
+                    0x7a356f9ff4e8bbbe,
+                    0x8cf5b97f8c9f9ff5,
+                    0x00bb5ccee4aecdd8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa2ce139d09168107,
+                    0x6efc3f03788bb5e2,
+                    0x8faad98006eed998,
+                    0x0c3eabba5db2f94a,
+                    0x1e480c7e57b22677,
+                    0x8f06370ef1585525,
+                    0xe8b10b813d7fe3d3,
+                    0xf1acfa01e12bbe94,
+                    0x6f2d0344c9c26e5d,
+                    0xab1512565d5af67b,
+                    0xaa5afb08c549c8a5,
+                    0x00b31b87dbb634bb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x79208acea366369a,
+                    0xb7b32dcc14c76e92,
+                    0xc6c0bdacb684bbde,
+                    0x1e449dc014690e2a,
+                    0x92017ef1f86bf6ea,
+                    0xa38698887c3325a2,
+                    0x67b1e18fba33e1d3,
+                    0xeb3cef3b8d0332db,
+                    0x52b7f090819e4be0,
+                    0xe258b8728716a2d8,
+                    0x83c3b050cacfb41a,
+                    0x0032ad94b5410234,
+                ])),
+                Felt::new(BigInteger768([
+                    0xefc6e8f601ac1cbe,
+                    0x4bf1a854507acb14,
+                    0x9538e4b4c55d3b52,
+                    0x67ca36a4e00547fa,
+                    0x7666d0321944f86a,
+                    0x121ffad37dccf109,
+                    0x8cf91de83207b6d2,
+                    0x019cd5b7c9678c00,
+                    0xa736ed0291615794,
+                    0x87c7471f018589f8,
+                    0x56f5855a989f8488,
+                    0x0071d190c7cd71f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x67aa060f6d5b722a,
+                    0x871b1fa782836d31,
+                    0x36a6c4fb935de748,
+                    0xf21a88bf9435db1a,
+                    0x872ec1a3df010949,
+                    0x2a9494dc4b93b5a7,
+                    0x22aac25791e4afbb,
+                    0x48992ca10b787d03,
+                    0xcabd690e4708635b,
+                    0xfa58a929e42dc17d,
+                    0x3166157cafdbbc67,
+                    0x00dab888806053db,
+                ])),
+                Felt::new(BigInteger768([
+                    0x667a96d904456bda,
+                    0x38607854e7487474,
+                    0x051d626a33196fd5,
+                    0x51ba6f69740bac56,
+                    0xe580dde9a12684bb,
+                    0xe569e010297b5b5e,
+                    0xad18757fd95c4e4e,
+                    0x79fa67b2194f1c84,
+                    0x8dd426363fc61bce,
+                    0x3644bf8eb653aa4a,
+                    0x9ef50f5efafc2998,
+                    0x00e7cfba2155251c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5187b7cf9a6fbc3d,
+                    0xd276b3a87aeef33b,
+                    0x1ea61bded88263f4,
+                    0x8ea221e026939792,
+                    0x53ef8b553c5775d8,
+                    0xf3acbdeece7c0b7a,
+                    0x54ab2766f722e643,
+                    0xe8745a977c56f8f1,
+                    0xd4b0f27cd6d8b2ac,
+                    0x3dd8023eeb9d940d,
+                    0xebc3df2a38f53817,
+                    0x003e4634c0c5cae2,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x39ff80c028ee8f1e,
+                    0x3b1b4c3cdb6f811a,
+                    0xc1c1fa84a4c3e4bb,
+                    0x06703e2518996687,
+                    0xf1818c4517dd1220,
+                    0xc715a60a692db76f,
+                    0x5a7f2bf0bd4c30c0,
+                    0xca9ce34ef79aaac6,
+                    0xeb311ef8ad65dbec,
+                    0xc721bf724f6a415b,
+                    0x6e9c0d5b0ac6126e,
+                    0x004efcaa21e20e69,
+                ])),
+                Felt::new(BigInteger768([
+                    0x308f578bfd7be5ec,
+                    0xe012aded55b4f9dd,
+                    0x43ff0c2125214986,
+                    0x44ef8b53d83268ea,
+                    0xd962ce277e955dfc,
+                    0xe8c595f859094e2f,
+                    0x9a7fd4400d995c3d,
+                    0x4d544f2180672a19,
+                    0x6a132d43348642bc,
+                    0x736e6593a6bda256,
+                    0xbc390a628b1e5c5d,
+                    0x010977d97bde7d20,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6299bf32a0808b8b,
+                    0xb3afe1382ce3a3d3,
+                    0xffe8ccf7db69cf85,
+                    0x0d22dce744dac7dd,
+                    0x1c833206551f1a24,
+                    0x7d590ca1d82a8ee6,
+                    0xd4c98fe1b61f2c03,
+                    0x8c9c514b31cbd585,
+                    0xee0ce0e2db9cb850,
+                    0x94154f61e9841dd2,
+                    0x0d6a27063bc82900,
+                    0x002799cefc648368,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbfb951f3c85bc316,
+                    0xb63a72a8be9c9a80,
+                    0xa46e540e44eb9859,
+                    0x8a4f6e34e48c66b9,
+                    0xc9079f1665378ec1,
+                    0xc5bc5a8ad2cb8a33,
+                    0xf83922ff191d9b93,
+                    0x0ded252f3a27b86b,
+                    0x8e57e46735e23b58,
+                    0x8f1509c94c255aed,
+                    0x0e77509859b3207d,
+                    0x00c817df3da0c792,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6ec40cc77ecbfa73,
+                    0x3f06ed611e0a1f78,
+                    0x6307d08cee581111,
+                    0x7ef9e7c7d29e2628,
+                    0xbfb68b4585900dc5,
+                    0xdbfd3d379b1ebd76,
+                    0xb438ddce69385d6c,
+                    0xbd1c1a7f36d43660,
+                    0x813fb7be36b06db3,
+                    0x41c902ba57640a36,
+                    0x32971f371603c2e3,
+                    0x00ed21dd123f5ca6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2485c2f9cde42476,
+                    0xcc3d3c55858b7b8f,
+                    0x5eaf7fe92c0bca0a,
+                    0xc1932e540b8601e8,
+                    0x6c3ac84961f926b2,
+                    0x79b3165f89057c25,
+                    0xc37046f0472c352f,
+                    0x080248ac4dfc1a92,
+                    0xfe6088a011adc296,
+                    0x64ec8aeab768067a,
+                    0xadff672b01deb3b3,
+                    0x00796a7fe124ec8c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xffbe6b19b3da8a0b,
+                    0xed568abaa601f190,
+                    0xe69732454f5ca74d,
+                    0xa36e92caefa35c65,
+                    0xfce8f27f3d3cd145,
+                    0xd83cab3029c5aad1,
+                    0x19974ab16f0e2632,
+                    0x386ce2b942d64fd6,
+                    0x036314dc524ce603,
+                    0x35718632c2022d39,
+                    0x585fc926ba5c14c1,
+                    0x009b36ee52bb76b0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2c1c602fc8a8d4e1,
+                    0x1d9a727cfd923dba,
+                    0xddca6a49d0684f81,
+                    0x83ef0aed9d34b1f4,
+                    0xe084f97875719681,
+                    0x39249c0e7fb42dde,
+                    0x76a92520693f0704,
+                    0xf1a8c8354f6a6d8c,
+                    0xbe81150059f88f2e,
+                    0x72b93a0497eb5bff,
+                    0x1a5764c096430a84,
+                    0x010321dbeced38b5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb55ca71250661bb8,
+                    0xe8f79e1d534483ba,
+                    0x7346df6dc90cd2eb,
+                    0xe3f776abe3a8fea1,
+                    0x70127d64c46499d8,
+                    0x15ea0d8db8b2a89d,
+                    0x8856bcb612cc223f,
+                    0x0c36c94d4ee3c741,
+                    0x2ec251e7b8d016d0,
+                    0x673dd75cab1edebc,
+                    0x2bf84dbfd8763fcd,
+                    0x0017b55da3b8883e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6f8c2db3f4cfad6a,
+                    0xad2d47aac9ea5c6c,
+                    0x7c0e798d2c3a7eb4,
+                    0x6f7c0c2cc945f92f,
+                    0x2e57d18c2c6f2416,
+                    0x2a8d8ccc1c3b57b2,
+                    0xf0a6a23a67f47426,
+                    0xee5631705adf24d6,
+                    0xff56fe048dc394b3,
+                    0x8b6e363e0cd6561e,
+                    0xfdfa11656b975264,
+                    0x011cb754f444baaf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x184a23c76f186103,
+                    0x6594a32010f2070b,
+                    0x1aa5fde01fa3c457,
+                    0x34d08735e60d5372,
+                    0x7884113061a384b8,
+                    0xb3bccee9d8e7c75d,
+                    0x26f8d90cd1023e76,
+                    0xc34009bffcf84e7e,
+                    0xe5b47c75f50621b1,
+                    0x810b87937df7eae6,
+                    0xb02df4e55f4a8891,
+                    0x000619a904b3bd00,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa82d52027aed6984,
+                    0x79f5732154660969,
+                    0x195d7b0721ca0265,
+                    0x2ef9933a34f9b311,
+                    0xdb2a1010fe86722f,
+                    0x952ebde8ab25df2f,
+                    0xfb3d8699c2533af0,
+                    0x20a79de00ff5beb9,
+                    0x3df8480a3c7caa1f,
+                    0xdd27da98b3f39c80,
+                    0x1491e6cd153a13f6,
+                    0x0060012e53cc3350,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xdcff47928367d7c1,
+                    0x17046b19b8d8b062,
+                    0xb6a7dda010f2bc77,
+                    0xfb3531658a79063e,
+                    0x4a1b41f3dfc8745b,
+                    0x8c18e8ad2552ec47,
+                    0xee22e6e745fc4e36,
+                    0x5795e28d1d792af7,
+                    0xb79de4bb201652e5,
+                    0x13c8115f2fc4b274,
+                    0xccee1de2f741f81a,
+                    0x008afab481e8c0dd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf804395d5253e446,
+                    0x811ffc288164a00c,
+                    0x0bc428ef1574d6ae,
+                    0x5220b5c7e2c11bc3,
+                    0x2d8ddb995335cae6,
+                    0x3e4a8a7bb2283bbb,
+                    0x390317eacbcb9b74,
+                    0x320640fc101d8e99,
+                    0xd90a133059bb2a15,
+                    0xe607a71ba83127b0,
+                    0xcc86fa0d9c119cab,
+                    0x005042d209f72d0c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7636918a3cf62f41,
+                    0xd3c9dec4f7d7ae19,
+                    0x9062de36eac27a9a,
+                    0xc1d32d531f8d76a1,
+                    0x386ff8d67bcd72ab,
+                    0x0dee744c6d64b15b,
+                    0xf7b0b4d5b21080de,
+                    0xe38035d7f2e1ce68,
+                    0xceb2835ecf720c99,
+                    0x81056d408966ba6e,
+                    0xaf68aac7420ff4fb,
+                    0x00eccc351d0caef4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4e47c103f4f73ab3,
+                    0x26d779579a5b6831,
+                    0x76c8aff012474bba,
+                    0x4df884454fe0c50b,
+                    0x374109fcc5c8df1e,
+                    0xa5ceb986e2750db2,
+                    0x3a5561b289bf1634,
+                    0x9320940553edf5cb,
+                    0xbfdff96345607ac6,
+                    0x9956800848aeab03,
+                    0xecc07c13d3a9144b,
+                    0x0099edc43e0c58af,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa7daa21b43d14238,
+                    0x4b16b05e0b2c687c,
+                    0x1152d55cb10d5033,
+                    0x409b6bbb88bfebcd,
+                    0xe5e9cc929a002c73,
+                    0x2b7e3f960c3cd7b8,
+                    0xf9d0ebf2173cb025,
+                    0x0108d7b843a2b6e1,
+                    0xdb1d26ce80d85264,
+                    0x798ced96f59c7ec4,
+                    0x4dcc659c6650c14e,
+                    0x00d6139daf99efda,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe9a9f4ebb5c8a47d,
+                    0x78a73c0d6dcdaf45,
+                    0x2b3c0c20893e5fa5,
+                    0x21135c763e093eca,
+                    0x9b6ba38218a66b14,
+                    0x4c7dc45249f5492a,
+                    0xface920326f3223b,
+                    0xb4e2c431c7a1df33,
+                    0x695b32e6bb712fea,
+                    0x716b005a2c2a5953,
+                    0x77ae558f00876cbf,
+                    0x00b094ebf5bb4d58,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x084684085577798a,
+                    0xf2a437cc30c7f584,
+                    0x48d2380ab4d10a35,
+                    0x8a125bb5ed3521a5,
+                    0xc5542ccd42b08c5a,
+                    0xbe059125f5fcba06,
+                    0x4fcba2eb624e6c07,
+                    0x0e3f563e17088f2d,
+                    0xb493970acfcb8ae1,
+                    0x131a41c6bcad93ec,
+                    0x946696fb42dea400,
+                    0x00ce9b9830971219,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe7f9773b51e226ad,
+                    0xdf170852bf48d85b,
+                    0xbecaa25bcbad3927,
+                    0x6df8c6040298f2ca,
+                    0x5e49e9fc766a6d4a,
+                    0xfb38bdc876a318db,
+                    0xf2ae4ef776baaba2,
+                    0x003699975fd52e7c,
+                    0x96d10d7c4ee551dc,
+                    0x2c33900dec4949b2,
+                    0xeaed9343ad686c56,
+                    0x009392aa7f21d684,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfa759311ce19cdc1,
+                    0x358a397b462bdf52,
+                    0x3ce2666632b1ebef,
+                    0xfce5307d8dcdeee6,
+                    0x5cc774a6eb2dab49,
+                    0xf0ea52c4e257f485,
+                    0x02d1468d8d371228,
+                    0xd7fd329d42a96b48,
+                    0x1b5a972f22898ca6,
+                    0x4c50e7d588d76e4d,
+                    0xe2fdb8f871b29cdd,
+                    0x01144f81e09fbadf,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3ded70ce78a4b886,
+                    0x32ce6cecd7fece15,
+                    0xd753f72d90d35f1e,
+                    0x81f4be9c4d3b1a30,
+                    0xe3219947f3c66142,
+                    0x24e934323ecf9bf0,
+                    0xfd16a525f9a718f6,
+                    0x211b4dadead46b94,
+                    0x49dfd764878e3c49,
+                    0xde2f44bfc5332b11,
+                    0x161a3f5f607ffa1e,
+                    0x00a47686aa0ba847,
+                ])),
+                Felt::new(BigInteger768([
+                    0x427bbae009918fae,
+                    0xc364b765fb034cb1,
+                    0x139ac7ea3bdbb587,
+                    0x2eb7c46f46ac0224,
+                    0xec4baa1fd127c5d4,
+                    0xd8d84f256ec05d5a,
+                    0x4359d1c7e78ba516,
+                    0xe140ab9874e9564c,
+                    0x7f680a4871e8a312,
+                    0x16f85d691fd88786,
+                    0x1de4f2f414231a94,
+                    0x000a2c2d97bf4fea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9aaf083d13d6b6e8,
+                    0x0778cbbf57fd9c46,
+                    0x019b32c12a2ceec2,
+                    0x79ef19a470a75a60,
+                    0xffefc8f244ac56a3,
+                    0x0fe64b78a458ca1b,
+                    0xdb9fbcaf8d571a8c,
+                    0xe3125d549f719058,
+                    0x5494c63125dd15e7,
+                    0x5f8c26cead60e3fd,
+                    0x15ccaaaea14be647,
+                    0x0086b2d9d50255e0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb8e25a25e813737a,
+                    0xcd2c9f25e59dbced,
+                    0x904e60fcb7b35ffd,
+                    0x82844ed91c4fb465,
+                    0xe490fa960d72ca4c,
+                    0x1132dcf0c45622a4,
+                    0xb70ee92c1657eeb5,
+                    0xdc1add6982fb3aca,
+                    0x58f8358acb123bd1,
+                    0xe6534e9297ce2d6a,
+                    0xebfea49d2ff14a5e,
+                    0x001bfd903dc158d3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x79ac4eb70f4f8305,
+                    0x92752ea91ae880e1,
+                    0x83ffb0dcb5ebbc56,
+                    0xd197a923ca6dd59a,
+                    0x1530da4a7211e3d7,
+                    0xdc11de543b34e906,
+                    0xcdb6893904c11c0b,
+                    0x2756c068c19d6c85,
+                    0xdb2741e14ecb7d9d,
+                    0x18be65b39051ec14,
+                    0x08ff9d1cda281cd9,
+                    0x01042964443529fc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb742085bdc976968,
+                    0x02588d1ba4b15800,
+                    0x276b907b3270d3d6,
+                    0x6955237f33125d6c,
+                    0xca7901f140218d06,
+                    0x4343ce6eaa5fb540,
+                    0xe0c7cd57fd475d48,
+                    0x8647d1bfb7968a64,
+                    0xe262bc8849592b1a,
+                    0x32e72d98a6a88dfa,
+                    0xa28dc4ffffb6aba9,
+                    0x00d6d37ddbc2d7ea,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7a56f81bb9b0de85,
+                    0xa304333bf5289051,
+                    0x1fc548c06203914b,
+                    0x261981b0f96c90f7,
+                    0xbb838fe95b1a2891,
+                    0x4ab22cbfe137b034,
+                    0x3105ca5a6f6bdfd3,
+                    0x0fb0fb0ace909c0d,
+                    0xb9c05a443e633e91,
+                    0xea21aade981685fc,
+                    0xe1872031ab747a02,
+                    0x000a13b56a0b6079,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7c2c3a7aec782852,
+                    0xb04d6d5b09f89091,
+                    0x35f09113cec789a7,
+                    0x11d6eeb8c8c925d1,
+                    0xad1c4570b9d736bf,
+                    0x1353c0b3f620c384,
+                    0x050f810bdce8ee6a,
+                    0x7f9d196fcd3fea94,
+                    0xa41b01f278caf03d,
+                    0xf377100bd2019a9a,
+                    0x14f2932ba8a13a04,
+                    0x007da2670e529e8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa2f7d8a7e41547be,
+                    0xb17c0b80fcff0be0,
+                    0xf4be9124569e2c4e,
+                    0xee1465870941c4fa,
+                    0x2159a8c7859f26e5,
+                    0xbaba70edc3b0513d,
+                    0x434cc40e981a388e,
+                    0xa0b256306efc837a,
+                    0x13c5b14904ed766a,
+                    0x2f5b08fa8822d64a,
+                    0xf9a7d5c81e38f3c7,
+                    0x00cb95b5982aaa94,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xcc48ea05f9c38fc2,
+                    0xfa75a670e2af9ca9,
+                    0xcf8bc3871edf23b5,
+                    0x6143b0bb14d156ed,
+                    0x5081a04260a9088f,
+                    0xe3860976b626a455,
+                    0x348c85d25d5e559f,
+                    0x09cc27a10ad27d82,
+                    0x0922b217225052de,
+                    0x1664a30558117ea8,
+                    0x9c37a73a9aabfffd,
+                    0x003917680a3293f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3af8ed90dcbedc3c,
+                    0xdebf47876b600043,
+                    0x70e57062367c12bf,
+                    0xf3a0e4ed460a3c60,
+                    0xf0c50565a6a01ef4,
+                    0x8fb1c21e204c2573,
+                    0x939456840275949f,
+                    0xb8f26172f2bd9213,
+                    0x00e6923264f21aa2,
+                    0x3a4663ec44f4ea88,
+                    0xf1294a7007357069,
+                    0x0068f03aec5acec8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaca41b63f9dfe527,
+                    0x39a6f5a21f12c5b7,
+                    0x236b1ad93f033dd9,
+                    0x8a804b778243d38f,
+                    0x7de1d53dd00037fa,
+                    0x4edc09897a4810ae,
+                    0x6faabe1a1294eed8,
+                    0x91df733873b18154,
+                    0xa04ec11f6463e4de,
+                    0xfaa3e507caad8879,
+                    0x1df54dd67f386271,
+                    0x00edf3109983ffca,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1eee5c24c385528c,
+                    0xcff2c69749e8bff6,
+                    0xcfa09e6fad98ddb2,
+                    0x2cf083a625270463,
+                    0x0e3ef7c487f3ba24,
+                    0xdbcd4d6a82db5b11,
+                    0x913484f729aebd2c,
+                    0x7b8a906aab51b3f0,
+                    0xa755a6e5211a9741,
+                    0xa9a0d81c6d911799,
+                    0xc3d5d37e6206e343,
+                    0x000e5efd8651c5f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ec8651b53ba11c1,
+                    0x0eb19e63b14043ec,
+                    0xd4bada7a296826ae,
+                    0xdb853d28645399f9,
+                    0xa3b6342846c0ce4f,
+                    0x7a9d6848767791ee,
+                    0xaa6233442d95c2cf,
+                    0x89c6acded4bcedd8,
+                    0x63b02c822dc50a82,
+                    0x454336cc1067d688,
+                    0x3d2005358586a17b,
+                    0x0111f2efd29b0d01,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7058e33962a9a6aa,
+                    0x7aca5021883a4160,
+                    0x762bc801553b742a,
+                    0x599fa671d8807743,
+                    0xb036cb1d8fedbcf7,
+                    0x9238d33e90f1cec7,
+                    0xacfe64aed52d4639,
+                    0x39351516b9bcae98,
+                    0xaca3f4f4d2499dd6,
+                    0x9ee44e8798ac036b,
+                    0xe57f5d143830906b,
+                    0x00545a70bab1f60b,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1977fffffffca848,
+                    0x91d7cd0e7ffcd9ce,
+                    0x40250b29a68d07dc,
+                    0x604be39c11d7c8b8,
+                    0x0887bad65b250924,
+                    0xbfc30c832cfefec3,
+                    0x8f50cd3fe3086c23,
+                    0x86c8b1bb05c4fe00,
+                    0x0cd9f590bc54bd23,
+                    0x2d79b050cfa5c8c4,
+                    0x14110de156cca5dc,
+                    0x011adc539c938d66,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::new(BigInteger768([
+                    0x136efffffffe16c9,
+                    0x82cf5a6dcffe3319,
+                    0x6458c05f1f0e0741,
+                    0xd10ae605e52a4eda,
+                    0x41ca591c0266e100,
+                    0x7d0fd59c3626929f,
+                    0x9967dc004d00c112,
+                    0x1ccff9c033379af5,
+                    0x9ad6ec10a23f63af,
+                    0x5cec11251a72c235,
+                    0x8d18b1ae789ba83e,
+                    0x0024f5d6c91bd3ec,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1d21176459c934d1,
+                    0xdf51b97b8eae67a0,
+                    0x5ce57138cfc91dd9,
+                    0x4e66aef443bfa22b,
+                    0x1fb76150ddb1e46b,
+                    0xa8fe1c22f71e2611,
+                    0x20bc91b91c732c81,
+                    0x7208a07e3190d8cc,
+                    0x50c840f6ce7e82f1,
+                    0x12c7613b4de632d2,
+                    0x8d3c150654d3102e,
+                    0x00276afadfaa967f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b2556b1676b8b95,
+                    0x9bfc7e321aebc21b,
+                    0xef85a7e695c82915,
+                    0xcd046c852419b635,
+                    0x920dea9cccc4a535,
+                    0x24038c595f9225db,
+                    0x9ac364f5116fc201,
+                    0xc0ae2ecdf61496d2,
+                    0xdedb9812189dd108,
+                    0xa994ab5f475ed8cf,
+                    0x74219c8a0879c18e,
+                    0x0084bffc9b05c44f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x128b183eefd98f28,
+                    0x2367e936c9779f62,
+                    0xb15ed5735f223e4b,
+                    0xc651cdc2f7d854df,
+                    0x670c44db8f829d31,
+                    0x6dc025c1f0954e65,
+                    0x20790104189cc6be,
+                    0xfeaec0d1bbe36c13,
+                    0xdbffd627ea66f4a4,
+                    0xdca11b6fad44fc58,
+                    0xd55382ded777aafc,
+                    0x00ac22d7c0834f50,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdd89df2ba35b5ae5,
+                    0x11bc62d15b7fa76e,
+                    0x1cde0aff1a14356c,
+                    0x7aead540d76704ae,
+                    0x3f01f7eb529fff8d,
+                    0x44b8b9f286dc5ce3,
+                    0xb98b5d5c375d941f,
+                    0xa72f6ed40adc4294,
+                    0xe31d85525546c4b3,
+                    0xf2ee4ecb9abf1bc5,
+                    0x2b0b91993d1760da,
+                    0x010d7720e2b6210a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x298273a8dd67a3ff,
+                    0xaa96923740cecf03,
+                    0xde7a0734ce59c35f,
+                    0x23e9c500fa6da393,
+                    0xda46ea02ca20539e,
+                    0xd41576d2a3c752e2,
+                    0x607df4be973783ba,
+                    0x000161182e15ef99,
+                    0xf74b1d4db3132a77,
+                    0x52937062a374397b,
+                    0x5a759512c152b914,
+                    0x00b0ebd1779e3c41,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd88d2e7c93d7db5c,
+                    0x33630cefa03c53fa,
+                    0x9d1383b377c63c17,
+                    0x18943e93e87f6302,
+                    0xd0f5d631ea78d2a2,
+                    0xea56597f6c1a8625,
+                    0x1c7daf8de7524593,
+                    0xaf3056682ac26d8e,
+                    0xea32f0a4b3e15cd2,
+                    0x05476953aeb2e8c4,
+                    0x7e9563b17da35b7a,
+                    0x00d5f5cf4c3cd155,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x623396b3d56ac514,
+                    0xd362703409659c97,
+                    0xbf4aae0543c375be,
+                    0xe8def9cd6a4a711a,
+                    0x43bda16b927dd97f,
+                    0xf685346e01292d0c,
+                    0x54d5c558a3659a50,
+                    0xde9c7fd7398ee0a1,
+                    0xafec5d6d7a400925,
+                    0xd67b21dddbabf2ce,
+                    0x045016f444c409b2,
+                    0x00d61675d941996b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa1f7196e1d4f95d1,
+                    0xf64efb09da1d363d,
+                    0x94e262bbc6edf86e,
+                    0x56ab744dfdc262a5,
+                    0x4bb612e4c78b9c4a,
+                    0xb6ab996d07e52c5b,
+                    0x9d1f2e3ed8314eb0,
+                    0xd20797504a8362cc,
+                    0xcce657bc9d5de490,
+                    0xbd8056862e5a0028,
+                    0xf9200026fc909fa1,
+                    0x00aadb608b95f1d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf7bbdd7580c40399,
+                    0x141ca93ebcdaa390,
+                    0xf225bce4624d2899,
+                    0x7d8179aab3551771,
+                    0x66afb422cc77d488,
+                    0xf2746e2265fe30ff,
+                    0x2a91013b0ebf2f9f,
+                    0x8dc20a8358f10248,
+                    0x4f04a4c1b652f582,
+                    0xd28decf1c36c081d,
+                    0x53ffb24dd97b7556,
+                    0x000b74cc3f15123e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x61d94674a6a5932a,
+                    0xfe87784894fb3247,
+                    0x9b53f7424492dee1,
+                    0x51fc091b3fbc9e0d,
+                    0x7a273cb14cfa6f0f,
+                    0x9c70764351a143ad,
+                    0x36afc771fc1ae9b9,
+                    0xe3f75cc666ed5d6e,
+                    0xf59355ab4342928b,
+                    0xd722cacdceea8bf8,
+                    0xaa729ded66eea71c,
+                    0x005fbbb94c2a3041,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb655d131d3a52445,
+                    0x6cc228c6192cc847,
+                    0x00067aa3c4017bb6,
+                    0x1180054500c3a6f2,
+                    0xcca41ca0d13040b7,
+                    0x90f3149096905ad0,
+                    0xfbe62e1d17d61baa,
+                    0xdf9cbe0ef7a284b3,
+                    0x7d55d4aa4bfa78b5,
+                    0x02c39dd347f5a694,
+                    0x1da46bfd41bd42d4,
+                    0x0059b9374a01c99b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x53b0c7454dedd869,
+                    0x0bbac2a3d0347369,
+                    0xb4aaf7a5a25b6591,
+                    0x6f75971073f2aa2c,
+                    0x88ad30217a50a087,
+                    0xa0ac1f9b6636c80c,
+                    0x36071feea69808ff,
+                    0x25023c977f409774,
+                    0xb635c90b33c4c3f5,
+                    0xb18707933ae881e4,
+                    0xa16018bd3a6b783f,
+                    0x006603b055f499f6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x1f31c4b3936df720,
+                    0xa745e022d375b976,
+                    0x88b079b85cd1eb04,
+                    0x26b576e88735ee83,
+                    0xedbc3c8696478343,
+                    0xf2397db41e671e60,
+                    0xdb42ff251d217c9f,
+                    0x2159f147ab1ad657,
+                    0x21f173c026354f8d,
+                    0xbfd7c150cc6e543a,
+                    0xfb6c2f0c341b66f1,
+                    0x002bf6faf8c84458,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd836a4044fa81973,
+                    0x7295a7fb49ecab9c,
+                    0xb717d19f260b3feb,
+                    0x9fba144eff85bd54,
+                    0x04cf678142c1263c,
+                    0xd92cc9e78c508f98,
+                    0x0ee9f27f2a80a55d,
+                    0x6fa542c748508431,
+                    0x890812ca17a619ab,
+                    0x898e6226e720c1f2,
+                    0x5536daff0d7d803f,
+                    0x006f05a6cef1afbe,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3eff5a0c9619c1f3,
+                    0xb5d034500aedade6,
+                    0xdd5cd1084c3b81c7,
+                    0xd91c18616a714cf1,
+                    0x167ab09ea6bc27ec,
+                    0x57573e48443eca84,
+                    0x055c2490be6c72b6,
+                    0xe3baef7c72e7e0c0,
+                    0x71f37426684a5ff6,
+                    0x1c5106458d7eb9f8,
+                    0x72bc4e1ac2af835e,
+                    0x000d342633cdc1a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf99d1e4960221912,
+                    0x342cbf8c9e651f43,
+                    0x6b5ab94b2779a28e,
+                    0x6a4ad16f6e4cad73,
+                    0x2b78d7be6851910e,
+                    0xa63d8bf4ca23032a,
+                    0x88068ef47315e936,
+                    0xaf706e86f84040d7,
+                    0xf048bbbe0bfc9e64,
+                    0xc57ce78ddfc6bc8b,
+                    0xe135d59f9a88f092,
+                    0x0074f87dd09eeecb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44da190ee3885b05,
+                    0x444a6249cdb0e71f,
+                    0xfe064fff88f8fedd,
+                    0xcc5da337b9bb62bd,
+                    0x0c1ddc5556605766,
+                    0xb685e51bf5284127,
+                    0x61accb5c0645b8c0,
+                    0xfb357782e816af7a,
+                    0x1f33609cc5fafb58,
+                    0xab58bd2caf81a10f,
+                    0xfbec20d304345827,
+                    0x0074b3c82a66527c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9e6cfa263262628a,
+                    0x688999503e81e76a,
+                    0x9890756c27bf0356,
+                    0xbf008aadf29a897f,
+                    0xbac69eeb2a97a4bb,
+                    0x3c356b2b1ef00b2d,
+                    0x2128559b93c352b1,
+                    0x691dbb35d7cb7091,
+                    0x3a708e080172d2f6,
+                    0x8b8f2abb5c6f74d0,
+                    0x5cf80a5b96dc052a,
+                    0x00b0d9a19bf3e9a7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x291cbc89bc95f9d7,
+                    0xd6cc241ef4245452,
+                    0xe1a7a243eb82e67f,
+                    0x07b9f8d2c39bd794,
+                    0x8b4cab0abc69184f,
+                    0x67577ce7a0bf30ad,
+                    0xd77b646688c6771e,
+                    0x8081f50cf152905f,
+                    0x6a0f4a7ca33de12c,
+                    0xacb692ce33c1efb6,
+                    0x397394deb23532b4,
+                    0x008dcb3fc933373b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x07542167438d3a18,
+                    0x891bf7dc2c688afd,
+                    0x3f3abd2625102e94,
+                    0x27f23e6e0832f754,
+                    0x8796848233b252ad,
+                    0x077599b0f861c33b,
+                    0x3cb04e00825ad2ef,
+                    0x6cfd62654ba1d8ef,
+                    0x8a927116e22cbb46,
+                    0xac7d58403531e6a0,
+                    0xd0b8406fc4bb4c54,
+                    0x002214d77e6cf43d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x09c2125dcaeae66c,
+                    0x2cc734958704a1b1,
+                    0x61378779921ff113,
+                    0x4dc08068be01d30b,
+                    0x62001084ff2132ec,
+                    0x5b1aa0ff1f00864d,
+                    0x8d79655d7d264fd2,
+                    0x13bb2c3f0585e5ed,
+                    0x764de6e20ff677c2,
+                    0x2c6cca9bead952c8,
+                    0x53a8e7b29ae252b6,
+                    0x00ff4912c9e81982,
+                ])),
+                Felt::new(BigInteger768([
+                    0x32b08b489a798e6e,
+                    0x1cefbbe6f103eb94,
+                    0x964d7eaaba58e444,
+                    0x5ab275427f8d5d6e,
+                    0x8a7deb70ab9ecd8a,
+                    0xfc1743a58714bade,
+                    0x9553ab7ae5870ea9,
+                    0x092efcb5f3a103ca,
+                    0x0b677f5911323779,
+                    0xccb6dc7458e16eb4,
+                    0x0ebaaf28f53f25e2,
+                    0x004ccaaca35ba25e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6468a4ba0eb1b717,
+                    0x49e91e9692fe849f,
+                    0xadd0f0579cbebe4e,
+                    0x13ef4489fb61e7ed,
+                    0x40a1d1c58974dfce,
+                    0xac073a157dbe61c8,
+                    0x15581dc4427cce8a,
+                    0x960dfe10047ed4b6,
+                    0x153a0953265a734b,
+                    0x79bcf54cd5ce7b1e,
+                    0x8e5e53217173f5da,
+                    0x00b6e3efc555284d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x68f6eecb9f9e8e3f,
+                    0xda7f690fb603033b,
+                    0xea17c11447c714b7,
+                    0xc0b24a5cf4d2cfe0,
+                    0x49cbe29d66d6445f,
+                    0x5ff65508adaf2332,
+                    0x5b9b4a6630615aab,
+                    0x56335bfa8e93bf6d,
+                    0x4ac23c3710b4da15,
+                    0xb2860df63073d793,
+                    0x962906b62517164f,
+                    0x001c9996b308cff0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xec42763c5d50180a,
+                    0x4d50f83469d5be23,
+                    0x69069b37b3866b2a,
+                    0xcb40bbe5b55d1027,
+                    0xccb8544aff0aec44,
+                    0x45d885ab4e687805,
+                    0x89ce7bc7ce8296f6,
+                    0xfd0bdb39c0c400c9,
+                    0x95b0b64c0a76779d,
+                    0x38fb90f5c6424aab,
+                    0x400d7e345a2f0135,
+                    0x0073e41f9e365217,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb7d8b994b091c839,
+                    0x97306b3569e8ecca,
+                    0x370b4171fc9f7b90,
+                    0xf5841176a012f2e8,
+                    0xaafa310a17efc9d3,
+                    0x66b8c6482e567b0b,
+                    0x3eb79716ca3f7f48,
+                    0x2f09f4dfe8bbe9e8,
+                    0x53e0049dab809321,
+                    0xf293dea271cea864,
+                    0x970a3939ff90c00c,
+                    0x0087fda64274968f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae160b5ebc0dd2d6,
+                    0x4895bba81ad5523a,
+                    0xb5fb0aa26cd2abf0,
+                    0xa7545392dfb7f2d9,
+                    0x36eefd8b592d0648,
+                    0xbb319db75b46e600,
+                    0x18cd6dea090433b9,
+                    0xf558a6c299a58367,
+                    0x1cf7f38fadc16219,
+                    0x5f261806e4c58e5c,
+                    0xb202e21734c65e01,
+                    0x00f00fdd9ff6e583,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9d47706bd6905b2d,
+                    0xde4e70751a626d83,
+                    0xc9c62d43131e34b8,
+                    0x64a3893fa3139763,
+                    0x777f811cdc9b4679,
+                    0xc7b8a76f23c5b148,
+                    0xddef304331b05d3d,
+                    0x6da5472e7764452b,
+                    0x8852da0e29688653,
+                    0x55d908e4c2c8aaff,
+                    0x3a055d6010841161,
+                    0x001ce7c10a3d0b91,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1f35035a43ef2c8f,
+                    0xc140a0eb8148bc92,
+                    0x542cbb09fe7c3455,
+                    0xb37d40df6e7c63c2,
+                    0xd3a03a2e81beaf98,
+                    0xdce8c2346946779a,
+                    0xa6991ed02e26c39a,
+                    0xcf35bf7cf3d8bd0f,
+                    0xd8def1d479f72588,
+                    0x3def565908803655,
+                    0x0020e01718c82293,
+                    0x003a77627f103c0a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x210a45b976b6765c,
+                    0xce89db0a9618bcd2,
+                    0x6a58035d6b402cb1,
+                    0x9f3aade73dd8f89c,
+                    0xc538efb5e07bbdc6,
+                    0xa58a56287e2f8aea,
+                    0x7aa2d5819ba3d231,
+                    0x3f21dde1f2880b96,
+                    0xc3399982b45ed735,
+                    0x4744bb86426b4af3,
+                    0x338c531115f4e295,
+                    0x00ae061423577a1c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc7e8fdceb7a93956,
+                    0xc761608720a725de,
+                    0x07a321768f514ac5,
+                    0xed09953e0812c0ef,
+                    0x0658ea50dcd7e306,
+                    0x933aa05778aa4bce,
+                    0x74cdf14be132f8fa,
+                    0x3a823f54bdbad34c,
+                    0x05b38a0777778714,
+                    0x41edb2d427be2c54,
+                    0xab18cde345f199fa,
+                    0x00755735e4f31fdb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x77500e5eca423536,
+                    0x7f605c6bac352756,
+                    0x5b2d783dfd8069b8,
+                    0x38a2ff12b293e87c,
+                    0x5950a86abf71991c,
+                    0x03dc4ccda50ef005,
+                    0x9fd8e28c399581e7,
+                    0x0586231319e9400c,
+                    0xa5a1daa94efababb,
+                    0xd3a6a02c2ebeed12,
+                    0x263bfad7965c36cc,
+                    0x00381d7a328dc639,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8b91dd00ca25e05c,
+                    0x26c04ba2dfd1fecb,
+                    0x1d5b199ac84cf3cd,
+                    0xa8077b189b8914d3,
+                    0x9dcd474bc40668df,
+                    0x1f1015a7f3e7813c,
+                    0x68896543aa130bb3,
+                    0xedee7db4968f2553,
+                    0x60f841908d6ee6fb,
+                    0xa88d76943df429c9,
+                    0x76061d7bb6cad396,
+                    0x00a629f69ec0286b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4ba626f9a867d1f0,
+                    0x6303c3d929b54b7e,
+                    0x211748b2196c94a5,
+                    0xa91da49671b95774,
+                    0x707fd34ea04758c7,
+                    0x01db999043fc9d15,
+                    0x54565c6369de5d21,
+                    0xf7b5da488959d8a9,
+                    0xd62e0d5c3d51485d,
+                    0x401e32018d22fa38,
+                    0x73c98b6fdfe820ec,
+                    0x004c97c9d2ed04d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd861009e3d6e5cf8,
+                    0x42d03d4b5d4c04b5,
+                    0xd41ae6abeee4b206,
+                    0xf664d42430ba710c,
+                    0xfe8e1af272aa0060,
+                    0x3de70d6efa261947,
+                    0x75fae6e2306c605e,
+                    0x49953c91ff981b39,
+                    0x0c7cf951c1bc189a,
+                    0x7e431d220264c29f,
+                    0xd8c340067cc9f92f,
+                    0x006023369e6cc0f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe39e0994cda31ba2,
+                    0x9afdd6af54a38828,
+                    0xc3282a077fc356c9,
+                    0x0c5876e4197f5daa,
+                    0x22288379bd8b155e,
+                    0x562d5f5fdac85295,
+                    0x8b59da300e9f42fc,
+                    0xe755b2cd437e6c08,
+                    0x765508ff4ddecd99,
+                    0x60bf6367236a3b05,
+                    0x8085a87d9b4eb828,
+                    0x0040cf03ef3239f0,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,3688 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 95],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 95>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 95],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(95 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 95 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 96];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 95 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 95];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(95) {
+            bytes.len() / 95
+        } else {
+            bytes.len() / 95 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 95-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 96];
+        for chunk in bytes.chunks(95) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..95].copy_from_slice(chunk);
+            } else {
+                // If we are dealing with the last chunk, it may be smaller than 95 bytes long, so
+                // we need to handle it slightly differently. We also append a byte set to 1 to the
+                // end of the string if needed. This pads the string in such a way that adding
+                // trailing zeros results in a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 96];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 95 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U96;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U95, consts::U7>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger768;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger768([
+                0xcd1a336b67267240,
+                0xa268352201e536f8,
+                0xa5ed5243c3c55d60,
+                0x7a4c894363db8048,
+                0xe9dd0a64e412f86c,
+                0x43da3fb47b4dcbaf,
+                0x7fbef4295855595f,
+                0xc5f582b12a84fb9a,
+                0x3e7033b6422c5a1c,
+                0x59e4fb52e860b0ab,
+                0x28aeddec2235ca45,
+                0x00044d3b60c77751,
+            ]))],
+            vec![
+                Felt::new(BigInteger768([
+                    0x4915cc4896ea5227,
+                    0x8c4bf0969b32b397,
+                    0xc42349118cc7aaac,
+                    0x2ee14c0fcb2bb610,
+                    0xd12cb117647b9377,
+                    0xdbfb6419f0f69473,
+                    0xfa14dce356fc91dd,
+                    0x840e0da20c4cdcbf,
+                    0xd5b268c2c182aa28,
+                    0x596a0457060681f3,
+                    0x599f4ac53813d4fb,
+                    0x0031eac8c5f3d0cc,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc64dcb0c53841bff,
+                    0x4688f13eaded7caa,
+                    0x38c302237f0b9678,
+                    0xca676c8f11435bed,
+                    0xa602920e36b24817,
+                    0x69f9aaed8a4c9222,
+                    0x0a265a9bb9baef85,
+                    0xd5accdc79bceb30c,
+                    0x6176684e448946c3,
+                    0x30679d00af8a1939,
+                    0x3cbf289f4db6ef67,
+                    0x00376db906cdbeb8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x77e534dd5e89bfd4,
+                    0x7f2dac7127907038,
+                    0x7fc329c2ed1b8d4b,
+                    0x5432ab9e6af660e1,
+                    0xf93a5f723a5c6091,
+                    0x9e79323f05075617,
+                    0xac45f46d0e3b41b2,
+                    0x07a0e6b787210175,
+                    0x1d43ea70fac71847,
+                    0xc77a99835038fd84,
+                    0xb1b7195750e59e86,
+                    0x005023d3f37440dd,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa156b0451de31bb6,
+                    0x8fee18285e4a231c,
+                    0xe5093b913189cb79,
+                    0x53da6d011b0dab1b,
+                    0xdedc0560075f0115,
+                    0x3fe6a5d24451c90e,
+                    0x56a32fd6379f547b,
+                    0x380e42ff1f491574,
+                    0xdbae76555f499559,
+                    0x74874a9bd3c88a04,
+                    0x72bdd5f83b3662fc,
+                    0x008d56f10ea49b6e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf6187904ddab04ad,
+                    0xe49d52842ce79c01,
+                    0xc3e2beace80b66b1,
+                    0xdd77b9472d426cd8,
+                    0x94cd8725ecad7c81,
+                    0x451250d559190995,
+                    0x4808ae225640a899,
+                    0x1c261385a9dd5e96,
+                    0x12c9939001064a2e,
+                    0xac127cc55abc21dd,
+                    0x1105a84d99513164,
+                    0x007a51efbc99c4e9,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xdb774dc0a100e2ed,
+                    0x2c1205025b2c5da7,
+                    0x071f2516e4d07795,
+                    0xc0b80185e6be9c19,
+                    0x9847688505c2a143,
+                    0xb88d17e0cc6bcb8e,
+                    0x15976a5423d26207,
+                    0x55734d49e3192d47,
+                    0x674454bbdf0838bb,
+                    0xa4ad41331fb5cd59,
+                    0x5de2c34c4bb1a5ea,
+                    0x0035a48272168e54,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe8b8ff1f8ceff48b,
+                    0xbec56effc603cdd8,
+                    0x5b6721be8e1bb44d,
+                    0x6e90dd050c95649f,
+                    0x44001b8d7cd968f0,
+                    0x247e9fbc33b41904,
+                    0x377122e40c291ec6,
+                    0x4c7185e8ed838e87,
+                    0x1ca2ed9677d4c2a7,
+                    0xbdcafb0dfe20622b,
+                    0x16465f5f8f4adc1b,
+                    0x0012105e59b5dc0f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7e7f6c3571bd5b61,
+                    0xe3ed04a44974a791,
+                    0xe5777e241f07435d,
+                    0x95657e628304c685,
+                    0x20be5c2fbcfe035a,
+                    0x5383d28dad459c19,
+                    0xafc7cbb2650122c9,
+                    0x41b6d8d893cd79ba,
+                    0x96f9ae11ab2ee92a,
+                    0x4b4a59a0749b3c34,
+                    0x1e2d04771d5edc24,
+                    0x0034403a0fa815a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4cfe7ca432c6390d,
+                    0x3eb77509128c4337,
+                    0x2ce133d38283273b,
+                    0xa0ad2a4019f24987,
+                    0x04339139dfa5ab1b,
+                    0x7fb1f439f721ee4e,
+                    0xa7ed8961f62635e3,
+                    0x04b5271443ce8e5d,
+                    0x2618217ba5874dfd,
+                    0x676e11ab71cf6b91,
+                    0x1c8fb86c8b6520a8,
+                    0x010b0483d989d338,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xfa48f8939be2a078,
+                    0xe833d1874f733b3d,
+                    0x6af3350fe8791d53,
+                    0x502231f0ac4e4384,
+                    0x84905e4251b2f885,
+                    0x349ac4c68902351a,
+                    0x4a508b42a6470c79,
+                    0xbc268e2dee910242,
+                    0x0bbc3c934d0db4bf,
+                    0x5a9a8935b8c83a13,
+                    0x69bc470276e1ae22,
+                    0x0005a07de3338e3b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8cd0769489bb5a80,
+                    0xb0e53654236c2a4b,
+                    0xb5056af5892952a3,
+                    0xbfe9b7623013f203,
+                    0xb47b19ffb12e125d,
+                    0x455a6b4d0486defd,
+                    0x6eb80097fd70e992,
+                    0x6a7b2f40a62875a2,
+                    0xa72808bbddf8c8c1,
+                    0xfa78c5ccaca3b826,
+                    0x49e59261da7bcaef,
+                    0x00489d848e24320e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb48807342f6d3681,
+                    0xebad2917475ce00c,
+                    0x05035c854fd1a0af,
+                    0x423f163a94d5ed4c,
+                    0xdc071ea845f1f3eb,
+                    0xdc4ecfc36a59939b,
+                    0xec0b05a8ad789150,
+                    0x7cecb67cf84dc831,
+                    0xfe785f998dd43a90,
+                    0x92b900ae0247ea58,
+                    0x821a4c92f6470a1c,
+                    0x0071e79eb5925f6b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa20f385d6226590c,
+                    0x374a4decfe7f2a46,
+                    0x44099e601bcdfa4e,
+                    0x028fe557c90734c2,
+                    0x7f5bea064a9dee76,
+                    0xb168b6061fa6d81b,
+                    0x2a2965b4734eb38e,
+                    0xba5cb9fcee112a01,
+                    0x0709b51fb5dbd06a,
+                    0x984dbf659395f411,
+                    0x0011aef9ce9149d1,
+                    0x00ed044a18425e8b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2216430a45b44823,
+                    0x6be578485bcaa482,
+                    0x3f2cfebd02587e38,
+                    0xc79c4fad3e1151db,
+                    0x0cd005b982f2838a,
+                    0xa23e3799058e4c77,
+                    0x709b4e3c558d5d7f,
+                    0xf4ce6005fe6488b7,
+                    0x86a35fafdbf0dde7,
+                    0x9ee5c1093d806c60,
+                    0xab26c83997cdefce,
+                    0x00d17f6d1852e1bf,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x664d4f272eaf1241,
+                    0x710adf946d02953e,
+                    0x28fadefa80adfffc,
+                    0xfd2cce41bca942f8,
+                    0x2324f6f294713d12,
+                    0x744c5ad1a387bd01,
+                    0xcb07f68a09930906,
+                    0xf02d5949a3ba309e,
+                    0x4d67c1a10a9e9849,
+                    0x22116f30a94f8a8d,
+                    0xa332b643acb2a0bd,
+                    0x00d015d0f0c762f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2fe3a169d55a3b40,
+                    0xd40301a541732148,
+                    0xd3aacf0e7e7ef442,
+                    0xaebc66292c0e2248,
+                    0x30abf512091199c2,
+                    0x931a1807fef02869,
+                    0x4df3d2ced8c49bea,
+                    0x0202c33c5e578ee5,
+                    0x1534032ee9430f2c,
+                    0x73de7b102047783d,
+                    0xeabac2e22070487b,
+                    0x00cdd416130dee3b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26e89d0f1217d924,
+                    0xfb6cb185aeed4768,
+                    0x537f0cb7339839ea,
+                    0xcee81e1fc126ac9d,
+                    0x43f537e7217006f3,
+                    0xb1c42fcf49f1f715,
+                    0x763933fd11b6dd66,
+                    0xc6a108ca3416880c,
+                    0x180a2efe37247cf2,
+                    0xea11d10ffb5498e3,
+                    0x4898691bd7801a15,
+                    0x001a2534428a307e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc2d1e7929c6dfa53,
+                    0x11986695e205b246,
+                    0x63dc997475af951f,
+                    0xbaec6241fcab8ac1,
+                    0xc3cb54551ccfde6d,
+                    0xb670378b2a0e17fb,
+                    0x86f15b644de28b84,
+                    0x9ac87ddc41464564,
+                    0xd7ce5707e9d9e0da,
+                    0x5a2a8e3dce3bad3f,
+                    0x5e4a202157d0f94b,
+                    0x0087311cd25c1e13,
+                ])),
+                Felt::new(BigInteger768([
+                    0xde352136734a3186,
+                    0xa61319741b401503,
+                    0x813c15ccbedc2b27,
+                    0x12c398a5639dd90e,
+                    0x2be7e5a8092b0c5d,
+                    0x1b131f820f0850ce,
+                    0x8b15e3b8b0a2c2fc,
+                    0xcb0b3033e1cdf693,
+                    0x3db91ec3a7f6a52b,
+                    0x15bbaed683d8b9fa,
+                    0xc6ddf00ab88dfab3,
+                    0x0024f5d9e8573c05,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3da9744513c28a51,
+                    0xf18518abad734a30,
+                    0x7327fefb87c52cba,
+                    0xe05a2f29c67a9a1d,
+                    0xca0e7021ef53770d,
+                    0x70d1653d53f87e26,
+                    0xb6594606c8419bc5,
+                    0x4130310287d0066a,
+                    0xb5938f9a3fdbca30,
+                    0x390f52bae7899a44,
+                    0x9867115704a8a1bc,
+                    0x000ac807b52fcc58,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x28c1716a9b783959,
+                0x7a6296a7f617ae32,
+                0xdf208c6d198bec91,
+                0x792e3880d6a41804,
+                0x0fc95244db18786c,
+                0x9229b1ce5663f4ab,
+                0xa89a5491b16e4c30,
+                0x4c2dbeaf09b8bd8a,
+                0x6bc050ff9177e98f,
+                0xe3e9201f2cd53bab,
+                0xbd77869957039573,
+                0x0117ed29bdf40413,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xace539be536b6a9b,
+                0x68c90bd75da9aff0,
+                0xe670b2df9c53e4ac,
+                0xa584e5343f4fd0f2,
+                0x38988bee9e42c04a,
+                0xc1744a82c115bdb0,
+                0x85b927e561690c88,
+                0x43719d5052e123cc,
+                0x82e9374dcba63cf9,
+                0xf5d993040d8e16db,
+                0xd5a6673a9a2a5cd6,
+                0x006c3eb5c654e665,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x0388334b1afe5fa5,
+                0xcf6cfa6d4a3975f4,
+                0x8537727166d39d98,
+                0xf7b0a20a232ca502,
+                0xb8909c86d5c66c65,
+                0x407aa4a5e3edb498,
+                0x83c1e041d64ef37c,
+                0xb15f03a142b1a46e,
+                0xbf5f59610eb2fbb6,
+                0xba3ecb5d55d62b42,
+                0xa90578b1b792ccbd,
+                0x0049c6577ac1392d,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x78056d4f0dba43fe,
+                0x9046d17266728ddb,
+                0x2cbcbe12a64f2851,
+                0xd0210c4ca966ca3c,
+                0xade72e5cf62c94e6,
+                0x22a509da83fc6a68,
+                0x1ab884e284990b79,
+                0xe06f91b0717f1e05,
+                0x3b01dccecc6b657c,
+                0xb527c3237a148b23,
+                0xdf9462633a99eb55,
+                0x00ce3454ede0af36,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xb6e5e3cf968a0069,
+                0x9c05068d40bcabe6,
+                0x508d97e1893c16f8,
+                0x7e902e41f869e422,
+                0x95798eb0d858a318,
+                0x8c90e619b7504bcf,
+                0x97bda2a0509037ac,
+                0xa20ca211da4261b0,
+                0x1d593bca8286915e,
+                0xb9b352eef98d69fc,
+                0x05ec8b31b0e2e57b,
+                0x00d9398b8656e151,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf6efdf0979061988,
+                0xdcce75746299d546,
+                0xd969eb17dd4e236e,
+                0x87487964bb34de52,
+                0x0edffab1bae489ae,
+                0x5a7a5e2882fc2291,
+                0x13d524a356e6067d,
+                0x77b530dffe30ae41,
+                0xb5ade5b4fc1c8bb5,
+                0x96d673a9867acc25,
+                0xe71aba09a44d70c0,
+                0x006ae6fcae963544,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xf0c25fa6cea4e948,
+                0x122acb49290dd971,
+                0x696d97f8f036c9bf,
+                0x5c4cacfc196b6c1a,
+                0xa84a9544e543b47c,
+                0x46c05f8e2096882b,
+                0x4bdaf80dae933000,
+                0xec592777569d6883,
+                0x2c30a078dcbe2261,
+                0x9d072c873cd44513,
+                0x4429d5f35e57121f,
+                0x00586ae5b0f2a7f4,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x473f3ce14fb119cd,
+                0x44cc854e6b5d19da,
+                0x941feeb13c1689d5,
+                0x6655f0c973546bc9,
+                0x720146b2cf9a01f5,
+                0xc1fa2c49b260cad5,
+                0x1d7ff85ef0da97f3,
+                0x715d60dd448b5643,
+                0x51b5a7cc9363089a,
+                0x1fff6261390432c3,
+                0x50e8663f72c0338c,
+                0x0109dd65f9c1d287,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x6f1bd3cad64e1239,
+                0x942f0af8067528c9,
+                0x1488ed5140222c2a,
+                0xe782615c0fef9648,
+                0xde775c3c5132d3f4,
+                0xb68ab896274efde9,
+                0xc6ca5191645937a2,
+                0xe111a94efe576f12,
+                0x8b0efe5292c4bcad,
+                0xf21a1233ab651287,
+                0x490dcf4b852ee689,
+                0x01101d532365174f,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x0bb980046d98eac7,
+                0xd20a167bcaa590fb,
+                0x88342c2fe1ac6981,
+                0x9e18c81c52415ace,
+                0x076fe54af561a022,
+                0xd4c9c2cad95f6345,
+                0x56c2a75e2cce7915,
+                0xb07e171af0b5ea3a,
+                0xca03bafc95347995,
+                0xba7e43ec88a23a44,
+                0xa90cc4d3a9fd5783,
+                0x01056309c2dee940,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0x28c1716a9b783959,
+                0x7a6296a7f617ae32,
+                0xdf208c6d198bec91,
+                0x792e3880d6a41804,
+                0x0fc95244db18786c,
+                0x9229b1ce5663f4ab,
+                0xa89a5491b16e4c30,
+                0x4c2dbeaf09b8bd8a,
+                0x6bc050ff9177e98f,
+                0xe3e9201f2cd53bab,
+                0xbd77869957039573,
+                0x0117ed29bdf40413,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xace539be536b6a9b,
+                0x68c90bd75da9aff0,
+                0xe670b2df9c53e4ac,
+                0xa584e5343f4fd0f2,
+                0x38988bee9e42c04a,
+                0xc1744a82c115bdb0,
+                0x85b927e561690c88,
+                0x43719d5052e123cc,
+                0x82e9374dcba63cf9,
+                0xf5d993040d8e16db,
+                0xd5a6673a9a2a5cd6,
+                0x006c3eb5c654e665,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x0388334b1afe5fa5,
+                0xcf6cfa6d4a3975f4,
+                0x8537727166d39d98,
+                0xf7b0a20a232ca502,
+                0xb8909c86d5c66c65,
+                0x407aa4a5e3edb498,
+                0x83c1e041d64ef37c,
+                0xb15f03a142b1a46e,
+                0xbf5f59610eb2fbb6,
+                0xba3ecb5d55d62b42,
+                0xa90578b1b792ccbd,
+                0x0049c6577ac1392d,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x78056d4f0dba43fe,
+                0x9046d17266728ddb,
+                0x2cbcbe12a64f2851,
+                0xd0210c4ca966ca3c,
+                0xade72e5cf62c94e6,
+                0x22a509da83fc6a68,
+                0x1ab884e284990b79,
+                0xe06f91b0717f1e05,
+                0x3b01dccecc6b657c,
+                0xb527c3237a148b23,
+                0xdf9462633a99eb55,
+                0x00ce3454ede0af36,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 760];
+            bytes[0..95].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..95]);
+            bytes[95..190].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..95]);
+            bytes[190..285].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..95]);
+            bytes[285..380].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..95]);
+            bytes[380..475].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..95]);
+            bytes[475..570].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..95]);
+            bytes[570..665].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..95]);
+            bytes[665..760].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..95]);
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x4b79c4e7804231e7,
+                    0xb9f4b61b7b1b31c9,
+                    0xdc175641c7878972,
+                    0x525348761a83ba16,
+                    0xd8ba37b50757a5d1,
+                    0x2355a13a60b0b537,
+                    0x042916bc47bc8572,
+                    0x10578ea62c54728f,
+                    0x016aec38de3cd58e,
+                    0x5481172e59d55bb5,
+                    0x30c90f55eaddd358,
+                    0x008438f6c04f0037,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfdd19e2d7dfaef75,
+                    0x1a197fc5b6cb92c0,
+                    0x8e3bc5ed6f65ba63,
+                    0x64f2343f282293e3,
+                    0x90c17c8cc9571303,
+                    0x161bf7d3a48cbf8a,
+                    0x3b642d65b69ee7fd,
+                    0x5ac0b50958ac4f94,
+                    0xa2df0c041ddf2931,
+                    0x4f7d8fe49472ef1a,
+                    0xa4148d90c3254b87,
+                    0x00ef471f909c3b9b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58723f8963a46340,
+                    0x934ce5e06fb2fd4a,
+                    0x73ec26b5349a6d89,
+                    0x97a75cb6d35e2fc8,
+                    0xad84174e1009fd16,
+                    0x7cd6513b21e90790,
+                    0x64d450829ffc2c94,
+                    0xebc85c4d447e777b,
+                    0xd281c0e83ed55938,
+                    0x595e6e3ff7605913,
+                    0x7e5723de9d69094d,
+                    0x00c0ccf339ea60f0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x531d4b2c015685c6,
+                    0x39fc5fbe9d3a40f0,
+                    0xe9ac5c534ca9e3ba,
+                    0xf844a74e023f398a,
+                    0xa899482201ec6346,
+                    0x81753fbec261594f,
+                    0xd8e890e07c5dfd2d,
+                    0xdc1670285116c5f8,
+                    0x67579e9d1d2d4f9a,
+                    0x1ddd0a8f081f9def,
+                    0x22dfaf0ae7bc2af1,
+                    0x0097e001549dc354,
+                ])),
+                Felt::new(BigInteger768([
+                    0x87f121fc4bb7c123,
+                    0xa1542bb3595f1c63,
+                    0xddaf8bdc0d820272,
+                    0x06e1008013e9efa7,
+                    0x31d387be82c67fa0,
+                    0x60e70c35c5129a6f,
+                    0xc3f262e828132932,
+                    0x484412a303ca5e2a,
+                    0x4a74921be71c87f0,
+                    0x6a38756433d7710c,
+                    0xdfca55ac30fb82b2,
+                    0x0094963ada346fa1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3babe6cfab1a912a,
+                    0x252ca5cf6a0915f2,
+                    0xcafa5689ad9ba692,
+                    0xff242cb3a99181b7,
+                    0xae2e787175012f98,
+                    0x9a6071395c4ebb2b,
+                    0xb8d31a5d27c45fe3,
+                    0xedfbea0d2a184a34,
+                    0xc9bdc9904448e8df,
+                    0x367cc0261bf30f16,
+                    0xf6ec9c0ec780c451,
+                    0x0077b1f18c3f833c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9a77cb46ca4fccd,
+                    0x7a359673d1f90494,
+                    0xa4c63deb5728e99b,
+                    0x4252cc4c1d9b1d42,
+                    0x7964f400ddcb4147,
+                    0x1c177501d8a3983d,
+                    0x8cd001028e83bb45,
+                    0x4c595269d4189694,
+                    0xfeeaf50134dbc2b2,
+                    0x92cd8d4d4d892923,
+                    0x9e69a72b05f72df2,
+                    0x00de488a180fd3c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7c23879adb7f7c88,
+                    0xd9d70ce80f2bc51b,
+                    0xafd05dab649d01a9,
+                    0xe4dc38d7509da0b1,
+                    0xbcfb5a8be87d5b3a,
+                    0x1482141e4eb1d5ef,
+                    0xea96552387f7fce8,
+                    0x82bfcba906c51ba1,
+                    0x97f3054e2d1d13fe,
+                    0x6c3dca6a9c6089a0,
+                    0x34c6755fd758fe03,
+                    0x00b55a4f46b879ef,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xbc2e15342ce4cc2b,
+                    0x3136cf8815e87448,
+                    0xcb387c31068e609e,
+                    0xf5586cf210374b38,
+                    0x3e6a4f43cc3d8c0b,
+                    0x0d9156822c08f8f1,
+                    0xf56c702cd5b04b0a,
+                    0x6d63800b087b53aa,
+                    0x163ab5d95082ff34,
+                    0x1d9f5bd94c130839,
+                    0x5de6e26dc5c1a846,
+                    0x00bf9c46e7d35423,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa68da083bbb69d69,
+                    0x0cfa56023d0c7866,
+                    0xade317a34d78bc66,
+                    0x25244c8c21ec028f,
+                    0x377c147a899ae21d,
+                    0x3365bdb4edbd1af5,
+                    0x5d764ed8f8768b80,
+                    0xe3091e2456b47825,
+                    0x780de4449887f29e,
+                    0xfa76e36c2982ac9e,
+                    0x5d115c7d3a407c73,
+                    0x00a8a373ff58053d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2bfe953c53f88753,
+                    0x2896c306101dc150,
+                    0xc1b5be538c89852b,
+                    0x0abe9c3257a05e08,
+                    0x6c07a8d6aed2c8e1,
+                    0x3246586b71a1d177,
+                    0x06931494c5596645,
+                    0xcd2deabb48d74e78,
+                    0xdba84530936909f8,
+                    0x55f13e183b2cfa35,
+                    0xa72494d3c10cc30b,
+                    0x00bc8f2a2e89e9f9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x71da336d9e5177d7,
+                    0x62cc7b3f2e735dbe,
+                    0xff96f4ef719354d7,
+                    0x36efd40f54723dcf,
+                    0x1cc46f5f860755a4,
+                    0xcb2448952624e099,
+                    0x9413dcc0cb86b391,
+                    0x97ee0b8077083ee1,
+                    0x7099e1402c1bb74b,
+                    0xca5bd01ec6cf42f5,
+                    0x6c5d5d93dc2ffd67,
+                    0x010013613576914e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x926d2258b6c2e420,
+                    0x7907391077e5ef53,
+                    0xbc67d21ffc9cdf0f,
+                    0x9652dec3e24efe86,
+                    0x1fb48bfbdd8c8869,
+                    0xf746818007931d88,
+                    0xaba364f364f79532,
+                    0xc61674e2dcc24a8b,
+                    0xd58ee901ff807849,
+                    0x16bd339032380073,
+                    0x21eda77e109d6848,
+                    0x0113aa666eb5ba9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc06128057b97e25a,
+                    0xea282352f24e3dd6,
+                    0x74d08b9235e656c2,
+                    0x1ac401d90e7211a1,
+                    0xae834228c4bbf5b0,
+                    0x7ee0d284b7760db2,
+                    0x9208fe1e04d8d583,
+                    0xb533824cb15d9905,
+                    0xbd74731f47453b47,
+                    0x224e7a8b12b0083f,
+                    0x9ba9cc1aaa03bd09,
+                    0x00d9269bc03d0086,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe6db489701ea652e,
+                    0xf4e62844d5f10a97,
+                    0x8513e690a3bb44d0,
+                    0x1b062a3a7ff6d697,
+                    0xac3ee7f9882cee79,
+                    0xba98b1a8923a33fd,
+                    0xedf206bba98915c8,
+                    0xda2f9d4cc992974d,
+                    0xbba3a985b131c1c4,
+                    0x0e648afe057733cf,
+                    0x624dfed48ab57f56,
+                    0x01116d32c377406c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd3325439108f87d,
+                    0xff3bc96f01debaa2,
+                    0xb43c093527b950f2,
+                    0xfbd4fc400d88b056,
+                    0x71a22eb5df35111c,
+                    0x3ba84aacc96c1da1,
+                    0xb61eea2c4e88745f,
+                    0xd3bcf356798cf547,
+                    0x932739be489a0227,
+                    0x3f784a5e2e333a69,
+                    0xc2afd9e0ef10b4cc,
+                    0x0119f609300e49e0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x4029e240908e45cd,
+                    0x706983fc746e008a,
+                    0x98007d6c665f3bc7,
+                    0xc70ce12b69e0ea2d,
+                    0x9a674f58a3c9b2c6,
+                    0xdbc5f517f695e8c7,
+                    0x98a3a704b6997489,
+                    0x8dbb625df606ef59,
+                    0x26d07ab01a55d1bd,
+                    0x6032ee1058c712fb,
+                    0xf200e0c7a6a26acf,
+                    0x011449738cec5acb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0bf2f9134af395e0,
+                    0x5cb760180dc329bb,
+                    0x1e7b5b678659d472,
+                    0x83138b97704866b5,
+                    0x0abd385a24d75906,
+                    0x112dbb105610313a,
+                    0xf1fe2a7fcea0f70e,
+                    0x8580226fc7acfd46,
+                    0x327bcb2fafd452ba,
+                    0xc6c704b433018442,
+                    0x8306a7bd899a3534,
+                    0x0020d3dc0ea45d4d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x29ac616a42e3e775,
+                    0xbe3b7b2fc89fd0c1,
+                    0xef74152715b8e96a,
+                    0xc510044463754c92,
+                    0xe0e3232664780634,
+                    0xe096f5066571df9b,
+                    0x0b708f877592fd7c,
+                    0xa5ad3af3a5f7d89b,
+                    0x976e1113fab2ca49,
+                    0x776ed5e6eb31c703,
+                    0xe5d3a28bd9ff6792,
+                    0x00a34608e65074f6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x24ef6494a1d8f889,
+                    0x1182a802fcc03c8e,
+                    0x8593f265a84fa272,
+                    0xa82baf4c1ac3038b,
+                    0x4d927715fdd95a38,
+                    0xd45accdf6b118101,
+                    0x77986fe0924dbc1f,
+                    0x1d584edc42834f3a,
+                    0xc4b948e3d256ad10,
+                    0xabb78f2252ab0d9e,
+                    0x5fc64f16de6c81d7,
+                    0x005fb9bebf98c18e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x28fb5399197701a5,
+                    0x98d37c9bed501505,
+                    0x59ad725f60a4f455,
+                    0xc87d52e9d29d54e1,
+                    0xe47796920ff17415,
+                    0x794ed0e6b4f4b64d,
+                    0x70e9f22d2ad11547,
+                    0x385bae5cc794973d,
+                    0x126b8441c26849c8,
+                    0xe8602ed51cf07e4c,
+                    0x63ac2802df43676a,
+                    0x00f6d97f5e67b22d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd008475c21f6b863,
+                    0x4262618d60948750,
+                    0x3a43f489ad0a9a4a,
+                    0x648babd5778a761f,
+                    0x3e09ddaf734edbe5,
+                    0x414f3eca3db219e6,
+                    0x9cc8e760cdb4f0cb,
+                    0xd5923cfe099fe406,
+                    0x6257f51eb5fa6209,
+                    0x9c500ac279f0ac53,
+                    0x11e784bf51203106,
+                    0x003b8cd202c31614,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9f148394c81db7f7,
+                    0x9824fc64786030dd,
+                    0x5d74fc4caf27c4e5,
+                    0xfd7a52f6b397fede,
+                    0xfb808fb99fadc929,
+                    0x9d126a6f5f3a4b6d,
+                    0x5381a394bc939a80,
+                    0x080d704538fdf6a2,
+                    0xd4f3c352ccc80f81,
+                    0x828bb5c1c12dddc8,
+                    0xd61a53d030c4ee30,
+                    0x007b54c43ef2e4d5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5171be91535fcd5,
+                    0x05e6385c4d012c9e,
+                    0x9ee49d6550d27cb6,
+                    0x66ad2cd12df19aca,
+                    0xe1f2b14975f53818,
+                    0x37363387ee0c6c20,
+                    0x66d7c7c52b1ca081,
+                    0x8d459a50d9f3fbe8,
+                    0x64e70131b600e5dd,
+                    0x4977a15f6721b2d9,
+                    0x5f8d8d4a87514e04,
+                    0x000b78b880b6f114,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x1a7e3e9fbaaf9464,
+                    0xfee04d283c97bce9,
+                    0xff37efe4e869c97b,
+                    0x53c7c3d779db0d76,
+                    0x6a8263f028356015,
+                    0x9dd458d8d3741a61,
+                    0xf693cb3b22951283,
+                    0x88c5801da85d3310,
+                    0x96f9e769fcb12706,
+                    0xeab97ec4a046fa87,
+                    0xaa58b42b6bb35f29,
+                    0x00f90cde93095860,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b45f9050a837444,
+                    0x3180ecadc0ba7190,
+                    0xb985dbeb778b809d,
+                    0xf403c6c38ccdb015,
+                    0x6986948eb12384c9,
+                    0xc84852afb014d94c,
+                    0x5de3f64768be581a,
+                    0x01f46e1925af5740,
+                    0x38de7deb5bbc2755,
+                    0xa41dd88c597f813a,
+                    0x2e0c2c3bdb05053b,
+                    0x002f6122899a5529,
+                ])),
+                Felt::new(BigInteger768([
+                    0x00bfac967e8fc23d,
+                    0x1aa94aeefecb72c6,
+                    0xc000f4fa3b87be9c,
+                    0x0a0f3e07076016ab,
+                    0x041dfc92fcd5e0a1,
+                    0x3efd30caaa7b529d,
+                    0xda2932ed88d75c06,
+                    0x904a02ac30e8d9c5,
+                    0x87471796b9c2e2fa,
+                    0x13aa9e593c318909,
+                    0xd4afce536efbe7b4,
+                    0x00205e239c23384d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1b9e5c72ceb6400a,
+                    0xda9a9e0329e223a5,
+                    0xae81649ea0e8c0c2,
+                    0x60f9dbff9b46c5c2,
+                    0xe61d82cfb4944386,
+                    0xdffd24e022aff418,
+                    0x01ce46ed0443867c,
+                    0x5acc8f804c84b30d,
+                    0x40503198e780a506,
+                    0xc3928c2322cf0186,
+                    0x85ab25cd49e7faa2,
+                    0x01023de900b46ee3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb877fde79efa8c93,
+                    0x66a2d479e3c56247,
+                    0xfc3d5050c6321a52,
+                    0xffb7a4df0db5e967,
+                    0x96129bcd6c8d8d82,
+                    0x56ebbde9795fccc9,
+                    0x5e199a3f328fdbbf,
+                    0xf26d8071d57731a4,
+                    0x3dbc92c10aa5be42,
+                    0xdc29e4f94ba7b56c,
+                    0xf1c6f7e2a827205e,
+                    0x009f5d7f3de7f29c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5eadf793424171f5,
+                    0x3127e8812d94445c,
+                    0xd1b7df5c79dee79b,
+                    0x0a97bba3210bb019,
+                    0x54168e99ee122199,
+                    0x726868c90283efbe,
+                    0x22952b12664b8dc7,
+                    0xb853aa2a14339a61,
+                    0xe2be17266d6089c3,
+                    0x1660df98f47d7797,
+                    0xf1b80a1888bcefcd,
+                    0x010795897e557a1e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfd307006fa9214aa,
+                    0x337ee94646d29f67,
+                    0x56fbe7fdeb38d867,
+                    0x20548eb9e81a984c,
+                    0xfa14b9eac2992cdf,
+                    0xd96536e54eac0a6a,
+                    0xe27f3bf91861844f,
+                    0xcfc55439ba6fc738,
+                    0x024619db3bbc6477,
+                    0xade7c3835a9dbc9a,
+                    0x011a6f913e87db87,
+                    0x010334c7e250693c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ed4a5e94a6782bd,
+                    0x959d0ed390db4c2f,
+                    0x287fa38987e42c63,
+                    0xba5cef24ef3a266f,
+                    0x1ac58e01fffd72c9,
+                    0x6170ec346078c500,
+                    0xda28e430ae31a308,
+                    0xce5eca7ee6edf209,
+                    0xc77a364bf8663b5c,
+                    0xb12398984fe01b5a,
+                    0x468eb79a7421626a,
+                    0x006a38932836b3bb,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xa2fc93d3bfd3ed6c,
+                    0x11ea73bb8c4ced35,
+                    0x885aca48213c40ea,
+                    0xf7ba45a881c9b6fd,
+                    0x2dc9d62d3cd8538a,
+                    0x314e0259f75f7f5c,
+                    0xb1054a1ddd691fb1,
+                    0x0e1d002a9c992101,
+                    0xf13bfc1568669961,
+                    0x77487d442e89c8c7,
+                    0x15d84d74a34017d0,
+                    0x005f3c95cdcec764,
+                ])),
+                Felt::new(BigInteger768([
+                    0x474b3be25088b317,
+                    0x4a00b5cfa541cd08,
+                    0x8d05645659b67822,
+                    0xdf636fc6b77dbec6,
+                    0xb9b4c1131015f7ee,
+                    0x7077d7b159926dd7,
+                    0xc364b9a1eb2c1827,
+                    0xd18303a6f3644218,
+                    0xca6a940c79c20a2f,
+                    0x9b251fda35bcdf8a,
+                    0x84fcb9222d3b5723,
+                    0x007a3fcab019413d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbdd4e5d56d60f75e,
+                    0x7ea8b99802c1cf2f,
+                    0x773dcb9584d52c0b,
+                    0xcfe68873a0a6ebdf,
+                    0x7d2d0fd9b43fe2d0,
+                    0x9f3072565b45c618,
+                    0x68f8b8f2e64241a6,
+                    0x4f8fb2c072491ced,
+                    0xce13f82df83faaef,
+                    0x99e7c1a037e0040c,
+                    0x8a86830fd14f0b01,
+                    0x00f69f77a979ad6c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38f78c8049bbf3f2,
+                    0xb0b4200b0f5d5abc,
+                    0x5cc8b63976252554,
+                    0xdcc6a3f489025252,
+                    0x47f341ca9fdfb811,
+                    0xe19b259ef2933e69,
+                    0xdd38322b21bb7b37,
+                    0x52d1888f59cfbb0d,
+                    0x7030e2a3e97836b9,
+                    0xfb6dc6694d9a8112,
+                    0x6d70a42691da2b6e,
+                    0x010762949b0d5934,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdfb10dde84f04dbe,
+                    0xb23ad259c8163276,
+                    0x803d86e0f51c4809,
+                    0xef19e3b43ba0c8e7,
+                    0xac9f68da09019764,
+                    0x379de151687c093b,
+                    0xfc4ecc7128302524,
+                    0xa2412f67ded68af7,
+                    0x5c20105f15dd3c8c,
+                    0x48f2cdf57b6ff7c3,
+                    0xd6918452fac44ed5,
+                    0x002d78a09e4d1784,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc178133a223fbae9,
+                    0x45eefc93a5df42b4,
+                    0x3c7f410dd1e73909,
+                    0x07557b12d2692bea,
+                    0x87269e9f8f175bdf,
+                    0x8ac51003162e373d,
+                    0xf822d29bb6519072,
+                    0x0f9d60117448c1b7,
+                    0x125b573c37719097,
+                    0x5c136754880a8d58,
+                    0x5e566cec9f2fa649,
+                    0x01163c3bc3f7f71b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x354b702c6f808e01,
+                    0x11e6dad038ab845b,
+                    0xfd88bf9c5b0d457a,
+                    0x701ca5cf98f7bf6d,
+                    0xefa6bca9a7aad539,
+                    0xb99556a897c27258,
+                    0xefb2747d69969495,
+                    0xdcb84d4f6bf0ed85,
+                    0xa9147da619231974,
+                    0xbf0e37d5e8b043ad,
+                    0x0d36efbd27afabd9,
+                    0x00bce16ed6713639,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9711dedb11d50bc7,
+                    0x29d3598dded9f45d,
+                    0x455ee6118d8f2327,
+                    0x13c93122bc60d029,
+                    0x748f76a1a2515b1e,
+                    0x8c9824e65fb6eb8d,
+                    0x071e5a25354871d9,
+                    0x1a3af8b8bded9de8,
+                    0x1321cb918ec6f0f9,
+                    0xe1362e08737a02c3,
+                    0x3ed460c06e86a086,
+                    0x004768428106115c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0xb9ea7efde18bbe0e,
+                    0x1a3b9e30d7fef741,
+                    0x8a2b4e873973dc01,
+                    0x8cbb3434eb6904d4,
+                    0x45e5107998e9ec45,
+                    0x02df6dada2a1fab4,
+                    0xeeb8fcf7a3643233,
+                    0x6d7e20e18ad4190c,
+                    0xb936873992cb8441,
+                    0x3a3f70c1ade203e2,
+                    0x81338bfd113677b3,
+                    0x01041c3ac9f0a8f9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3435597db5b73689,
+                    0xb84dcbb24dbbccdb,
+                    0x8b621c1078d4eadd,
+                    0x7a1181ffd0753a02,
+                    0xe97d8bd155dd2ef5,
+                    0x88ffd363caeeae36,
+                    0xef306135c326e23f,
+                    0x110a6f5404ba23e6,
+                    0x00285a161e8e5e35,
+                    0x167a12b1eeac1100,
+                    0xd6088da820d0b025,
+                    0x01214b2ffadb2b61,
+                ])),
+                Felt::new(BigInteger768([
+                    0x79b171fdd9959b4e,
+                    0xa63cf11999dd4985,
+                    0xb03a1bb61166e9a8,
+                    0x9a804f57a0d86223,
+                    0xb8c4435eb190e94e,
+                    0x9ead215db0ac32b0,
+                    0x044104a2c1aa2304,
+                    0x0e8411a3156240bf,
+                    0x444efd4075ce85be,
+                    0x5e1ca175a518df41,
+                    0x00b616edb6c3fc12,
+                    0x00c21b0ffd7866da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x783028ccf36795e0,
+                    0x6810ae0c7421433e,
+                    0xa45d62caffa33b2f,
+                    0xa33bf0cae63cd5c2,
+                    0xbd97d1c0da9db8fa,
+                    0x6e1cb131987e398a,
+                    0x793f9621a810c7b5,
+                    0x1b5c4e73f61730a7,
+                    0x4806985546e08fc7,
+                    0xf7430cc44e909ffa,
+                    0x95ac70f2608dff36,
+                    0x00e236a880779e0e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ab44e6816e32a80,
+                    0x86949a328094e4e2,
+                    0x310d0fdecf2f6ee9,
+                    0xe6eaba89a2b0b053,
+                    0x21d38d1a17b0f5d0,
+                    0x5520da21606db114,
+                    0xd9b56f0f6c4bc3f8,
+                    0x79baf9ee83a436f4,
+                    0xfa205d11bf914c92,
+                    0x24ef7207730be056,
+                    0x592d1e213fedbbd4,
+                    0x00ff7e6df1206984,
+                ])),
+                Felt::new(BigInteger768([
+                    0x81e35fdaf564c215,
+                    0xba2ebb8e5544e615,
+                    0x3d1267c8cfb09ffd,
+                    0x76dec8b07dd5f1bc,
+                    0xc5dcfcd53bed0ce8,
+                    0xd302915f61be5a76,
+                    0x315bbf5c4d19667b,
+                    0x4d129ba07e79eacd,
+                    0xc8ae66e8438476ab,
+                    0x7b691b148ba26f82,
+                    0x3c0ff35e94493fc1,
+                    0x00121f115c169d6f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x574d5e0c039c1a0c,
+                    0x6c46900bca76b9bc,
+                    0x9fe2d0e3a9881913,
+                    0x92ee46203fddb41e,
+                    0xf64985eec15557fc,
+                    0xb17037493323016d,
+                    0xe7353edbe3d34495,
+                    0x54de1bba0cf5db41,
+                    0x8e68a244b2ec33b4,
+                    0x5d479014e9eb077b,
+                    0x32976480d28cf196,
+                    0x00ed0f684cbd0c02,
+                ])),
+                Felt::new(BigInteger768([
+                    0x56197f8bc8db3d79,
+                    0x73797f61330283cd,
+                    0x1fba05f9b6aa91a6,
+                    0xbde6eeea810a2eae,
+                    0x474441afd58debbc,
+                    0xf5be00aae05e3d24,
+                    0x68120a65609185d5,
+                    0x8d67cc3c71cbc289,
+                    0x0748e40691a6f961,
+                    0xaf2e61643dfac432,
+                    0x4dcc41dee32e7253,
+                    0x0051658b0a674c4c,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger768([
+                    0x0e03ec2d58e1f7f5,
+                    0x3434837ef4e8f33b,
+                    0x833679c54965e49e,
+                    0x46626ef03fe997c8,
+                    0x4c6f6b80cc55c744,
+                    0xa4af6f4d4299d6e0,
+                    0xece639c78915f332,
+                    0xde4305f6403f71a3,
+                    0x0a9a2725af20531e,
+                    0xbe0a83c64837419b,
+                    0x5f9d2b776117fe58,
+                    0x00e387045902c092,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb13596ffa5902d55,
+                    0xc6c21e6eb449a525,
+                    0xda10c088773d19ac,
+                    0xb3dcac857a0219f7,
+                    0x99c24a69bbcc01f4,
+                    0xb5585f13ccde6105,
+                    0x00f984943bfa5041,
+                    0xb0b69f21c8defcf5,
+                    0x01e6582294ba20b6,
+                    0xd28c4f761b2d0b1b,
+                    0x42ae4363d2dc96b4,
+                    0x01054eaa7fa32fa9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf2dcb87578b5e7cb,
+                    0x5733fc2c20b1ef1a,
+                    0xb23ac1de9bc33aea,
+                    0x8f2eb4c143e946f3,
+                    0x36f73a4e7355c1eb,
+                    0x1a7e5989ce3739c0,
+                    0x5315250f481f5b3e,
+                    0x8aaf8a939288c910,
+                    0xe36e19693c9dd3de,
+                    0xe4ff514d1c398bda,
+                    0x897bd3fd28641c00,
+                    0x004b70816bae2601,
+                ])),
+                Felt::new(BigInteger768([
+                    0x17256cb4fd3a3397,
+                    0xf7e2a31a17eadf4c,
+                    0x042f27739761c25d,
+                    0xdab9103eeae3139e,
+                    0x7cc7c0cafceddd66,
+                    0x2f76b0a91da1f2d0,
+                    0xc0bc148b81c593eb,
+                    0x720720a6e293a899,
+                    0xcc1fdec6817a767b,
+                    0x560207727f032eb2,
+                    0xfa3d5bb308db7a96,
+                    0x007ac0bcae6f4ca7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd7692eeab68b8f38,
+                    0x629daa0f58967898,
+                    0x4dda6e2208495e7f,
+                    0x8705e8d009e75ebd,
+                    0x2eafbd3b81eb0baf,
+                    0x9039f84ab032fa2e,
+                    0x8b35553e83247566,
+                    0xbe913738af73a393,
+                    0x029f08b7dfd7debc,
+                    0xcb13d2ac55fa3da9,
+                    0x420bef83a95ced71,
+                    0x000d7e0ab71fce47,
+                ])),
+                Felt::new(BigInteger768([
+                    0x58274eb644b3e494,
+                    0x20970353327b65e5,
+                    0xe5a4301351914514,
+                    0xe05f2cadc7841458,
+                    0x3a391916b1f62f26,
+                    0xf4c4799b04abed16,
+                    0x2f24e668a8b63cc4,
+                    0xc59ac3824beefdc6,
+                    0x2f9c6311f287bbbb,
+                    0xfb2db19010c0d904,
+                    0xa928f93e949b8172,
+                    0x010b46652ee98df0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0332f891c76fb19a,
+                    0x9d78f40e46d427ef,
+                    0x96df5894e07be7c7,
+                    0x7aae5147670b1ab9,
+                    0x3ff66ad3c25d150f,
+                    0x5f0dfb6cd8511431,
+                    0x29461cd36e51878a,
+                    0xd3bbe8d68683f99b,
+                    0x359b7df2d12d9645,
+                    0x7f0b2a0b7bfd4f9c,
+                    0x7cc8f7e41bb97f6d,
+                    0x0068f3bcf6a5e56a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf1fab8852bf01f99,
+                    0x1342f82395489c9e,
+                    0xe84f334789e977e9,
+                    0x1bf2cd3d6dd8af57,
+                    0x525a64e67d7769db,
+                    0xed4f1bc4009d3e4b,
+                    0x68e08fa3efbe09d0,
+                    0x818fee78acd60e2e,
+                    0x2e1d9ddfc41ebeff,
+                    0x75b7b22732cfe818,
+                    0xfbc9d50f82318ff9,
+                    0x00fe89f29ba51cec,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x36e1f99b7261f673,
+                    0x2abebc10c74dc37a,
+                    0x0e69e7ee4e4382cf,
+                    0xb97b8b1195bed5f4,
+                    0xa81652480a62996c,
+                    0x99a7d68afa9ce859,
+                    0xa2eee846e70b7922,
+                    0xca7ea26f7fbf0258,
+                    0x5ec54a7c8316a6c8,
+                    0xd2e8808dc19fc891,
+                    0x9e85ae3be7e75312,
+                    0x00a617f9afd7f85f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfe16419d5a009e4d,
+                    0xe27af2e62398bee5,
+                    0x08a6f4615a8ef0ab,
+                    0x24b6d0196f8d858e,
+                    0x24871e022f398ab8,
+                    0xa7dfde83307bcbbf,
+                    0x65fcfd58a11f36b8,
+                    0x30ed061ceaa1d98b,
+                    0x226d6466e16ef75a,
+                    0xd809180d8435a33a,
+                    0xa8f08b8da5d13b68,
+                    0x001821ff5c64b541,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3b2fe192a79c4d73,
+                    0x3548b8d2d53e01d0,
+                    0x3ad07c536357e263,
+                    0xac8fd2f1e97c9e52,
+                    0xef54247775357d24,
+                    0x2298dffbde13244b,
+                    0xba50695e03bd0b50,
+                    0x30f164ed15a0f604,
+                    0x6d5ce6fb58a46c82,
+                    0xe2034b010548a141,
+                    0x1202476aced2b754,
+                    0x0060f70e664a9602,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1882b4a7916b43b1,
+                    0xdf01de79d0fa274e,
+                    0x16974828e2d84fd6,
+                    0x04cc79508b6ccb17,
+                    0x4805428857c4a696,
+                    0x69d505c62c1ef1b4,
+                    0x526b15bdd02c9e25,
+                    0xb3d706ef10d4e86c,
+                    0xc2f9b7fa3c383f77,
+                    0x64118df8165eb36f,
+                    0x7dbcfa53c471b464,
+                    0x01059d88f9d06605,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x87f0940d47999cf3,
+                    0x1902f1375a547d5a,
+                    0xdf74bc460b4213f4,
+                    0xa6ad34b51c5f7465,
+                    0xd016882b94ac1a20,
+                    0xe1e79903273b874b,
+                    0xd1f805d09dbafe0b,
+                    0x7a4791191f6b8bb2,
+                    0x3d7d624573ed0f93,
+                    0x543ecc747ef0c10f,
+                    0xd1f067cde97ff48e,
+                    0x00b01668bc1e7702,
+                ])),
+                Felt::new(BigInteger768([
+                    0x663d50b752fe7d8b,
+                    0xd7def09f701b2e0b,
+                    0x23eff6cbf54c154b,
+                    0x5dc74fdc4b8cd40f,
+                    0xa8a802a86a64b48e,
+                    0xe69ff044250fbc6d,
+                    0xfd731867c38b7c5d,
+                    0xb8ffead23e2a4a28,
+                    0x637e4e2e272610d9,
+                    0xe8941db8f744aedc,
+                    0x34fb32c901a12b78,
+                    0x00551b365147d4c4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa54cac8a33c8498d,
+                    0x20f63adfe15e6c2f,
+                    0x5aabec5e6b056f47,
+                    0x9d3f37be216b5353,
+                    0xe20582c3512c536f,
+                    0xf206362b7cee1969,
+                    0x777d95a503c1bcb7,
+                    0x4b07faed6ac9b983,
+                    0x70a8d3efcc35d84f,
+                    0x2dad127bb27b9b1b,
+                    0xb2e060c8854e9359,
+                    0x00e9f51bf87cbc68,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0175532672c561b8,
+                    0xcfb105c97172f573,
+                    0x1f58c684cea735ca,
+                    0x3c68a2f88687080e,
+                    0xb5043523e26f7981,
+                    0x2e61226fd6a759bf,
+                    0xe6848475e4794437,
+                    0xb4b213d97e29d54d,
+                    0x1d3f7bd6cd5a4107,
+                    0x14c6d473ada5eb1f,
+                    0x30c2743e6cdb8d09,
+                    0x00429457b9ecee95,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x5a6fb082971b53b8,
+                    0x2875039f535f784f,
+                    0xee60aad8b87a36b4,
+                    0x46ee87debfd1486e,
+                    0x71759f459831ff02,
+                    0x9779aa779ba26c49,
+                    0x9bd608c10350d1be,
+                    0x1456e7184d611dc7,
+                    0x7c57111869ad2b0d,
+                    0x19f456634b812614,
+                    0x7f4ccc88200d5c77,
+                    0x002b161b8fc52d0d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2b6a061c577d2bde,
+                    0xbfff9fb1c734aea5,
+                    0x14ed242bb8b53025,
+                    0xefe8c5f959c4c53a,
+                    0x9f20f61a6a060cd5,
+                    0xb23cae80a7c27c21,
+                    0xde2ebc87aeef2b9b,
+                    0x3894917f6bdc4c8b,
+                    0x9c93d5e48c4c41b9,
+                    0x673cba7d033d4876,
+                    0xc799082fddd1911a,
+                    0x00f5409f34da1f9e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf57612895573477a,
+                    0xb2af0dadc821b828,
+                    0xe695fb1857f121ae,
+                    0xf40799b11e0f0857,
+                    0xb129c4f2f2ae7320,
+                    0x549d1c2bc67221ec,
+                    0x27cec29ab34c5e8a,
+                    0xe9e202377ef820c7,
+                    0xb1dd28fea0bbb416,
+                    0x608b2710e3103eb8,
+                    0x2c18e17b099b0e46,
+                    0x010707749bf5e1c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbfddd3eb136a3de2,
+                    0xc55aae1f17b161b3,
+                    0xfcad0e89ecc84a6a,
+                    0x9005422c2c6da318,
+                    0xbd407884b8ff806e,
+                    0xdb1d9f220059e1d3,
+                    0x7dc2575bdabc68c6,
+                    0x209b6fa9bbe63a41,
+                    0xfd540448193f9482,
+                    0xb39dbdec0fd46dd7,
+                    0x22196bca26f403cb,
+                    0x01093ec1b978cfb8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xec456c62ca96f741,
+                    0x967f787275542073,
+                    0x75323a6c3f894d26,
+                    0x6ad97d8dfa5bc328,
+                    0xc6c58e805753e045,
+                    0xb8b00ba5fe9b19e5,
+                    0x3e170cf02fee5b2b,
+                    0xad656394effd3a87,
+                    0x8c9ddca52b34cb39,
+                    0x3f01d24595b50afe,
+                    0xf3ce59f4158b5b50,
+                    0x0059ba02efd902fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x773eb67fe7cd27e2,
+                    0xb6f3800d1177e9b7,
+                    0x806a14e5095414e6,
+                    0x0706fa339752f44f,
+                    0xd727299b2805427e,
+                    0xdb3cebef6cb4453e,
+                    0x0d9f2d6ead869f1b,
+                    0x3873a3ff0e7c3c23,
+                    0xdc7084dfc1d7d199,
+                    0x9ad37711069abc88,
+                    0xc93fdb10abd850cf,
+                    0x00b057d4394ea744,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2db09673f3bfdc6a,
+                    0x3519d5f37ee4d98c,
+                    0xad06e24484213e91,
+                    0xaa31fba90a781dab,
+                    0x5249870b80a3088d,
+                    0x57836a40083fa926,
+                    0x085cace716bf3492,
+                    0xd415fb5c351ec7b2,
+                    0x4451deafc61e39b4,
+                    0x63afaf1d993f7af8,
+                    0xb3781823ac475ea5,
+                    0x00299bb0224164db,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf0e311a8d2e42879,
+                    0x0b565d9b09164e6a,
+                    0x0091db6b270288c3,
+                    0xc17182efa73ce7ee,
+                    0xd137bad2a9838536,
+                    0x35d46caef4f94de7,
+                    0xa3bb701b483cb4bf,
+                    0x6d5c01df08347b33,
+                    0x8044b2e693250615,
+                    0x6566ccf31098ffbb,
+                    0xf1e0765faf9959eb,
+                    0x003cd8bcbb532c41,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xad2fa80abceee6d8,
+                    0x1b93c79019ff1596,
+                    0x36dc1e878e962023,
+                    0xc79fe4991908be3c,
+                    0xb88656b9ca446ed7,
+                    0xe84c8716723fe788,
+                    0x9276f75932e31e3b,
+                    0xe92d5921c5c97974,
+                    0x8fe86c29b69bf0c9,
+                    0x4ad0e77dea7a3bd6,
+                    0x956760a9b31fd6cb,
+                    0x0044fe3ac29ae45e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x39a7087099bc2d51,
+                    0x0c4a701d79baef61,
+                    0x69613bb585549d7c,
+                    0x593f7cf84c8343bd,
+                    0x06851557e61cfbc4,
+                    0x66178e66153585bf,
+                    0xe2530c4bc36f9ca1,
+                    0xd097f64d790c1406,
+                    0xe442ef5ca9d11c90,
+                    0x6d03acd4bff7c210,
+                    0x05f85adc0a326122,
+                    0x00a20f9c99ae8249,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd264578f82f18353,
+                    0xf69701b01a9470bf,
+                    0x3923b2ed282080df,
+                    0x8d20baaa063de7dd,
+                    0x5c0bfa7c973b0c58,
+                    0x8b8d81ac19988670,
+                    0x2a173f1737564be7,
+                    0xe7d3f157f6e42190,
+                    0xae80d0b317b2b5c3,
+                    0xbce10885d3fb5e29,
+                    0x2ed527cd7b5e3b80,
+                    0x005638c39204f384,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd23c7a1b8661f056,
+                    0x9442bc3cae1fc4b1,
+                    0x08e522c567611db2,
+                    0x1c3f7618630d59b4,
+                    0x2dcd6372ccea5cd0,
+                    0xd5c87eb4a7dea6a3,
+                    0xa905352afc86a651,
+                    0xe8069fdbdcf1592e,
+                    0xa8f66b6efdaf02a1,
+                    0x4c02a5be14698d9e,
+                    0x415ac12f802ae3fc,
+                    0x00cd8d294f852b34,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd6e0e6b2c4a2bc7d,
+                    0x103ed5e9cdc91fee,
+                    0xaede9595caf4b6ae,
+                    0x6f9f1f5c283bdbcf,
+                    0xc70d86450f5ef429,
+                    0x39b5c949afd4f035,
+                    0x8af2fb20ee2a745b,
+                    0x7408be6a8286f82b,
+                    0x164d8f55f5832327,
+                    0x24af392d13d54140,
+                    0xa6567e8e8010a2c9,
+                    0x004f61d1abc0dad5,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfe4c616188e83c47,
+                    0x8cb93e735ba09c2f,
+                    0x95963a8e54d16623,
+                    0x297bd2e9ff9fd94b,
+                    0x534bbb1913152017,
+                    0x585366d21a400962,
+                    0xbcabcfc579802954,
+                    0x2dd54ed677de3aa1,
+                    0x2ee54a2df9da9fa8,
+                    0x9b5d95ddf323a6dc,
+                    0x9c099d86347e49d0,
+                    0x00475e56aebc8bf3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee6f588baf0c8e28,
+                    0x61f8df7d0f5248a3,
+                    0xc5671fdcf0c3d437,
+                    0x2634315c04c9b0ac,
+                    0x88a8d9f2b2c69146,
+                    0x1891b312605de95c,
+                    0x460d98db5f622e4b,
+                    0x1967ab8cf26355cb,
+                    0x3eae2866403dc945,
+                    0x95afb293fdc5b6e0,
+                    0xb9d72e4229c0f5ba,
+                    0x01083618aaa77fed,
+                ])),
+                Felt::new(BigInteger768([
+                    0x39c7c229a149db7c,
+                    0xd45e4f2c214318cf,
+                    0x1745e2922df66970,
+                    0xf829ac28fe0f83bc,
+                    0x10df12fb4fac877e,
+                    0xf790dd2de2ca488a,
+                    0x671bc13e01e8d318,
+                    0x4e15d45fd6c64b73,
+                    0x1fadbeb8dc9a2715,
+                    0xe992515a68f60482,
+                    0x21dda5b31d5e9b99,
+                    0x00c4c000c129a86d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbf61edcfedf57393,
+                    0x5bd66180d1c6dd82,
+                    0x46272629698d0bd3,
+                    0x8273bba40ca653e8,
+                    0x3a0e3e1d5de2b093,
+                    0x3a599a03c378efc1,
+                    0x059d35ba25c9b5c8,
+                    0xacea815aaeb748ca,
+                    0xdcb616f1ce6419ab,
+                    0xda47d6319dec8944,
+                    0x3f2f7adac1349e05,
+                    0x00ac45a34b6978a1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xee35d4c42a4f0e9f,
+                    0xca818f2eb188a620,
+                    0x9a0edc85c1665326,
+                    0x639daff9fd0eb1a0,
+                    0x61c84161776b056e,
+                    0x4a374111fa0bee6c,
+                    0xb425482cce1d262d,
+                    0xcbc40d01f658576b,
+                    0x8b76e6b8f777f836,
+                    0x020b11879c3a6f3b,
+                    0x9e456ff2b8556b0c,
+                    0x010e6a88a96b13a0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x720bbf49cb4f8525,
+                    0xd7b6df7df6d45ac1,
+                    0x1c789b2b41088160,
+                    0xf778e73fa5bd0ade,
+                    0xe852d048459aa29e,
+                    0x60ecd7e332a43687,
+                    0xe0b875c13dc437c3,
+                    0xe581818d4ae5110a,
+                    0xff6a2b75fc28def0,
+                    0xba22aded1ff71b5a,
+                    0x12c3281be9715a70,
+                    0x0009643e240dc9c1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0bec1baf23c57707,
+                    0xc67606af6f35d6f0,
+                    0x3e1c767fb4319fcd,
+                    0x4007dcec9d9f2554,
+                    0xccb2bf5802a06933,
+                    0x50cba4de12ffc963,
+                    0x93041043c9cc2674,
+                    0x43b8d46d73ffc522,
+                    0xb5e7897cfce6c556,
+                    0xd21b5abb038cee01,
+                    0x06b1ff38763967c0,
+                    0x00c1f1b457ea850d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xcbc1bf2d3f5e5345,
+                    0x2e7556a11f44bea9,
+                    0x633fe9c545b1a6f7,
+                    0x5922f06a869496c6,
+                    0x70d3aa5194279bc7,
+                    0x1624e15770f3067b,
+                    0x5260622a35804fa9,
+                    0x639fcd250f4af7c8,
+                    0x01aed98f8139461a,
+                    0xec87d0b609f5f53a,
+                    0xc8b1be00eb0c9fc3,
+                    0x006fa0af9c96bb25,
+                ])),
+                Felt::new(BigInteger768([
+                    0x11ce0fee5262bde8,
+                    0xd493ad92ba1902ac,
+                    0x2bc7c8f04d8a42aa,
+                    0x4e6b3dd2f8e57634,
+                    0x04382380a9b5ef21,
+                    0xb41fc1c59c862f39,
+                    0xbeb44599451f29bd,
+                    0x0dc8188be7c974c8,
+                    0xa6cfd1645d615740,
+                    0xac2bc0234ff4a4e0,
+                    0x547062fb293f5295,
+                    0x011719116e196650,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3e475fe25cefaa1c,
+                    0xccff80e1fb589c22,
+                    0x63c221e9cd3cf402,
+                    0x87beea2ebe943779,
+                    0x9e8ef6943bfe8ab5,
+                    0x0370c0978e6e4ec3,
+                    0x5a15795bb56c1ec1,
+                    0x7aa09bbda2a199ac,
+                    0xdf4633d392d073c2,
+                    0x0bbfa29cd9bf45e4,
+                    0x24177d5bdad15724,
+                    0x004cae3bbea72f81,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9bb51f6cb65937bb,
+                    0x21666a9e3b54cec0,
+                    0x9e62559d187a40ab,
+                    0x9ae24883b7a3f694,
+                    0xe95619cdda7b7782,
+                    0x47e4fea6e4683ea1,
+                    0xabb2385400246be5,
+                    0xd37f9f9b1f638e95,
+                    0xeb7a224be00918f7,
+                    0x99fa8b9fd6ca0943,
+                    0x546686a1fbec6510,
+                    0x00a19bd030e5a1a1,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x2c31f52e39261a86,
+                    0x592c38b0189240f6,
+                    0x419bbc642144198d,
+                    0x601d973403eafc5d,
+                    0xe4637b384ae81014,
+                    0xb6ce6858bd6e4b07,
+                    0x4a34334704deec05,
+                    0x836a5c604329f78d,
+                    0xa578fdfb25058048,
+                    0x3968464cc29dbd66,
+                    0x386f21acffd69c89,
+                    0x010dcffc36d80237,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4ab6d10caac7a84a,
+                    0xf3d40958cbae6471,
+                    0x02f6b6ee1a474daf,
+                    0x348dbeead78e41e4,
+                    0x9821dee72d559421,
+                    0x2e47c0641caa2f0f,
+                    0xe880b2c1b5f9fc38,
+                    0xb5f515273c309905,
+                    0x6085165c123738ed,
+                    0x1ef5e36ef8a99698,
+                    0xa608897745ffcd4d,
+                    0x0074d2fb488b4f47,
+                ])),
+                Felt::new(BigInteger768([
+                    0x99f8c11cc3ec8f1f,
+                    0xaddcc041b7bb664a,
+                    0x3fadddef1f45ffe9,
+                    0x723f1396f6e5cfa8,
+                    0xf03746334d57ecc3,
+                    0xd12a9d2da86a38d3,
+                    0xdd664fbe8544cfa2,
+                    0x20b929a3beffede8,
+                    0x2894c34aa79042c8,
+                    0x1c000a57c88df1bd,
+                    0xe0e47b6d8403d23b,
+                    0x0029ec69b32ee627,
+                ])),
+                Felt::new(BigInteger768([
+                    0xecfc28003ff28514,
+                    0xcc6b5b2d9f0e2401,
+                    0xaec85b10f4526286,
+                    0x1618e90131c95f56,
+                    0xd78ab687587eb6b6,
+                    0x4c38a202701e2ed9,
+                    0x1db4232131c6472f,
+                    0x92f8fd25dcd2272f,
+                    0x1ff2b788e2aac0c9,
+                    0x10510bc1ab7606ca,
+                    0x76f81d95b0e53057,
+                    0x00411ffb19af3e6a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa59b9471623f4757,
+                    0x1eefabd994876c67,
+                    0x39cb75b98bb2027c,
+                    0xde62d70e1df35d41,
+                    0xe13ea503ecc43488,
+                    0xa83e219143498498,
+                    0x4864e2f53a17109e,
+                    0x1bb8f4bc6d75e06d,
+                    0x0b913b89c63fe7cf,
+                    0x197c61fb37811c1e,
+                    0xa5c2687686b7db7c,
+                    0x008f4f504a02f9da,
+                ])),
+                Felt::new(BigInteger768([
+                    0x371281a902c650e4,
+                    0x73cf5f635ac6df97,
+                    0x0d8e0e158e824c42,
+                    0xfce4c6e4081e4c5e,
+                    0xefb92917e4e4bd93,
+                    0x99263ba94c28ad11,
+                    0x37a9f73161be0af0,
+                    0x2f6818abe070dc8c,
+                    0xa9db598311bfaddf,
+                    0xc9bd744cdedcd969,
+                    0xbac56cd74fbe86c0,
+                    0x00a95a8ad25a5b8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8a6d1d92a5d7d7aa,
+                    0x55e0dd809b5a174b,
+                    0x5617be7dacecbdf3,
+                    0xb39e786f96cad39b,
+                    0xf2ef556894f723a7,
+                    0xe637733afd16ea38,
+                    0xdee919d80b411427,
+                    0xa7fb052f46cd65d5,
+                    0xeb96a958afa68b07,
+                    0x71dad990884904fc,
+                    0x7ab871992ce737fb,
+                    0x009658c343728279,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbe767cd250dc925c,
+                    0xd9c364e7140eec33,
+                    0x59627a77d75fc5e2,
+                    0xf54bc0d604ed3d85,
+                    0xc65369306fb02dc9,
+                    0x2aba5e25997e3107,
+                    0x9200150d52ad44c0,
+                    0x2feb28af568f1d4e,
+                    0x6329293d182121c5,
+                    0x9b811412792ec6c8,
+                    0x1854afd9f5753f6e,
+                    0x00847b432e820077,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x3e2311a7fd732412,
+                    0xc30da51c27114c96,
+                    0x6cbcd9475beac4e0,
+                    0xd5bb42359252a38b,
+                    0x35cff1148f061657,
+                    0x5c876392ff26610a,
+                    0x4d745a1fc62b63cf,
+                    0xd6c4c29aa43fbbc7,
+                    0x5ed4137f4c55232d,
+                    0x692b2621c711ba97,
+                    0x82e24a121a66e68a,
+                    0x011037d062892e8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf826692f1ec60208,
+                    0x512fd6bcd36a3627,
+                    0x81bb5a693627c41a,
+                    0xe95e3ce561143273,
+                    0x715e3d91c8919a1c,
+                    0x1684ffbf8a20d462,
+                    0x864c3bb4864bf7c5,
+                    0x7e05063ccd06c422,
+                    0xd5e6fa644bb6c514,
+                    0x08aa915e971b7b08,
+                    0x8a07ca1e3a97214f,
+                    0x00a6503eb494285e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4eaa12fb00a46266,
+                    0x6822adb4284cfaf8,
+                    0x44acf605b1ba089d,
+                    0xf5861d9108f267b9,
+                    0xe2f48ce4dc6a2e1e,
+                    0xc0cc35ccaf0c41c1,
+                    0x675840910af2cd94,
+                    0x7e353a4b41805c09,
+                    0xe5e9b18d2baacda9,
+                    0x2ab7c7ce276e326d,
+                    0x301ce02f5ef2ad0b,
+                    0x00a3be298a88124c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1ea1cca062b1665c,
+                    0xea083ca7cc58eade,
+                    0xa33a3b87e4465e45,
+                    0x6fe417b809062b1f,
+                    0xd341c76df4ea56bd,
+                    0x63c48dbb4d10e742,
+                    0x59cf649cf311eb79,
+                    0xd1eca13d2bd0b3aa,
+                    0xc4510888a6159b98,
+                    0xc2a4a64fc8f4f34a,
+                    0x18cd87827d0656c4,
+                    0x00c2d1648d57c79f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x863eaed7357040fa,
+                    0xd8a52d8185811cfa,
+                    0x1031ddf8d1f00533,
+                    0x9f2d2f1c04496f56,
+                    0x6ff10fa730d6cc05,
+                    0x5f447add8ee18b38,
+                    0x01c4593add265ef8,
+                    0xcdbf36b4ea829504,
+                    0xbcd8f072c62e88ee,
+                    0x66879398e52a24c2,
+                    0xf2fa0f16ebd5ffa5,
+                    0x00d6c94b55df7f2a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1d6d4df158fde6d9,
+                    0x9a8cf95c459cba63,
+                    0x55e48819cd9c48fa,
+                    0x01b7dcc90b5e88db,
+                    0xa2644b2903c08820,
+                    0xce115696f3ebfcb1,
+                    0x275b05a4ffbab1fe,
+                    0x12953609cd6aebf0,
+                    0x0e6f8b51ba447219,
+                    0x0b1f320735c47d72,
+                    0xd580f119db1da84e,
+                    0x007f0b78e825fe38,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc4820b027beb5b9b,
+                    0x789cd346c9f637bb,
+                    0x1cf4385440a53f04,
+                    0xfa4e437a698b51c3,
+                    0x415907cf27af5180,
+                    0xec67a5a15df1db0d,
+                    0xdb6c06f1bbdc1c48,
+                    0xb3891beb6c4fad2c,
+                    0x2c3333d4b30bc540,
+                    0x59cc97cb0c0c3b0b,
+                    0x826eb875874c81bd,
+                    0x00a651d5acf72449,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9b560a7976297159,
+                    0x6cd4fed008058c16,
+                    0x0a3b044eb2e96faf,
+                    0x82af06115d43037a,
+                    0x75005a92a2693bfe,
+                    0xfc542bec07fa3ed6,
+                    0x379abda5928c5192,
+                    0x5a52f0a96b22c8d2,
+                    0x2f75ffe37995aabb,
+                    0xb791fcc5d8dd5041,
+                    0x066906844c46b217,
+                    0x0026eb365f57f910,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x3ba4c1392a1cd705,
+                    0x4ffea52a5ef6b3e7,
+                    0x5e01f117b9ccdadd,
+                    0x8f0e716d6d205783,
+                    0xaddc37cf724c676b,
+                    0x2980611963580322,
+                    0xd8715a86597e4e80,
+                    0x63872cd6de11bca2,
+                    0x34fc4e845187efd3,
+                    0x9296f4300dfa7620,
+                    0x5eafd2d3d11b1e0b,
+                    0x00b3a464ff9ff12d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad8c07e6445a4419,
+                    0x54d75e9a3cb4ae57,
+                    0x6ebb8df9984aeb2c,
+                    0x030595ee768c154e,
+                    0x3876126006513a19,
+                    0xbda81c7ff542bf0b,
+                    0x31d148a8a95cb7a8,
+                    0xfbeef28f27882dcf,
+                    0x37d1a69aed8a70fe,
+                    0x1552994834939a38,
+                    0xb564a0da1022e14f,
+                    0x00861a2e236ffd7a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6883fe163ae0a483,
+                    0x601158ed02c63222,
+                    0x169ad6456e865cb2,
+                    0xbb3fb61cabcfe9c9,
+                    0xe26c6bb7377ef251,
+                    0x187d7bb8642423e5,
+                    0x95d44ac9a1cb236d,
+                    0x4d405d84f1b9db46,
+                    0x00ddd10afb2f9a3c,
+                    0x682f5c9777f52835,
+                    0x673c3509a3af2638,
+                    0x00664373ce7c732b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d7f996de4cec796,
+                    0xf46963a1c97c935c,
+                    0x6a88017e5516b705,
+                    0xec14d35925074f4d,
+                    0xf10aa9cd790ee1f7,
+                    0x03205d3f3ba7881c,
+                    0x5e2bc170de4418b4,
+                    0x3cd067bb301b8250,
+                    0x0acb3e0b3facd9dd,
+                    0x77b575cdd3c3a7dd,
+                    0x7c24f514177ab394,
+                    0x000ab22478bc7119,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8f729ca2f46bd425,
+                    0x6f3be681b452edf1,
+                    0x8a259b611e0e67bf,
+                    0x753bfee7d64e099e,
+                    0x2cc4d870bb513c4b,
+                    0xe2b8dca13e295150,
+                    0xf4060df7593baed2,
+                    0x92189a916bcb9b05,
+                    0x27ca389f4705afcf,
+                    0x19264a7b264d5d2c,
+                    0x068bd3de6b8a2299,
+                    0x0065cde010846ab0,
+                ])),
+                Felt::new(BigInteger768([
+                    0x13de5b1a7aa38c0a,
+                    0x6c0e2285cacebef3,
+                    0x37fffc63cd6cf641,
+                    0x96e4166c924a4f99,
+                    0x406ae7bea05b8cf7,
+                    0xae33be88a31cabd6,
+                    0xfed0e56e17e3837e,
+                    0x7a6eee95b879595d,
+                    0x13b817eedad2b518,
+                    0x354f96891298c73b,
+                    0x79e0323c45dc7ec3,
+                    0x0034b350f1cde6f7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8e325bfbf0da0366,
+                    0xa289b5972fe56ac6,
+                    0x0bb06eea65f67bbd,
+                    0xd121cc6f48977e1b,
+                    0xa6c1b96bf195115f,
+                    0x034652ce2af20fa9,
+                    0xf7d17e1e344f7523,
+                    0xe601309c718ca6bc,
+                    0xc6aeb604959a5715,
+                    0x071b01287212accf,
+                    0x552f7ce5ba569ebe,
+                    0x00f6bb6f9263baad,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfdbf03962ceb99ff,
+                    0x4df3f2f77a29b017,
+                    0x395527d130d90c8c,
+                    0xb661dcde23439b4b,
+                    0x8131b99658a9f8ea,
+                    0xfefae7103d721b18,
+                    0xf55e807b71817e3c,
+                    0x1b9524a9187d9c39,
+                    0x51032672f796ad04,
+                    0x51f8d8e315dc0b4d,
+                    0x588456ba1ce614e3,
+                    0x00abe34a5dedd67e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x6de59c12864ecc50,
+                    0x8ca52d079778fd46,
+                    0x92b4a7a384cd8322,
+                    0x7328142bc1efa74f,
+                    0xbc2ba295745a26f0,
+                    0x2b8f40dc1fd9bd94,
+                    0xff8e9b8f009bd851,
+                    0x0779e26cfdecc23a,
+                    0x630b93a10e4e1d83,
+                    0xd5d3df601667013c,
+                    0x4897e744ca9a4ba0,
+                    0x0090f8fd80084892,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9c404f15e5efd617,
+                    0xdcdd6abfbb2b73db,
+                    0x2ca1ab4d4578f591,
+                    0x3556485a86e78cb5,
+                    0xe9345386bf9cda8e,
+                    0xbad6c6a6651e3de7,
+                    0xcaec2b139e4d203b,
+                    0x93ad468baea24ccf,
+                    0xf4da283aa79082c5,
+                    0x772b5bf924900699,
+                    0x913d80881474a3f8,
+                    0x00b815d8f5310605,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3509906a6873fd25,
+                    0xa1b2adc8e0ac9538,
+                    0x9722eb6b9388a8c6,
+                    0x26ab1b9b5aa4e0ea,
+                    0x078b96e76b288cb3,
+                    0x5e974729b1801e91,
+                    0x2aaa64be54cccdeb,
+                    0xc6a7c2d04b29ee1d,
+                    0x63d59df84ba3bc0c,
+                    0x24d452193752a98a,
+                    0x7d6e64db1a317965,
+                    0x000ba43e031eb536,
+                ])),
+                Felt::new(BigInteger768([
+                    0x793f8eb767f29641,
+                    0x1e1deb2ebf79f451,
+                    0x1fa35221dfcad875,
+                    0xad098dc17a614e65,
+                    0x90ea62c7f96043b3,
+                    0x1e2b1e92f6ad9ddd,
+                    0xc1311fe9d7c89014,
+                    0x3a94489385e257fc,
+                    0x259c41f6a4aca0af,
+                    0xc1e53e6e829e515c,
+                    0x27b48ff0b31c99de,
+                    0x00faf020788e7cf8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8552f899b2e5302,
+                    0xab4d346c80b57de9,
+                    0x0bb21e49f458f5d4,
+                    0x7776757a2883467b,
+                    0x9531d1062048b3cd,
+                    0x1401706fe4d20d3d,
+                    0x8cd08085ae7d6731,
+                    0xa92caa140b952cb5,
+                    0xd5f1e1b9975f0255,
+                    0xdcef236ef033209e,
+                    0xbd0b9c99f13e4bd3,
+                    0x00ae94c15071c0b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x599b012822568563,
+                    0x98376f4854df5421,
+                    0x6679b6471a6b773a,
+                    0x338d224330028dcc,
+                    0x9035daac15ea5950,
+                    0x7010ca0f4d3e3553,
+                    0xdce8daf098bc88fe,
+                    0x20d3256086e79fed,
+                    0xb2c587b245003ab2,
+                    0x91b8e42fb6f9fe64,
+                    0xa090e0a8527f4f9b,
+                    0x001966af19476dad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x008b91f0ce3188cb,
+                    0xeb146e981a075c44,
+                    0x9f95ea742fae50eb,
+                    0x5b22926271846b83,
+                    0x480784ca241a51da,
+                    0xcedd9b0c4fe2f08d,
+                    0x0a84f2860a82a345,
+                    0xe4cee5a3095d6084,
+                    0x6b73fff82de99550,
+                    0x16232c576a5b1d2a,
+                    0x06ec56482b4d9ded,
+                    0x0032f6312dfff498,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc8fa8f1da9ca2dc3,
+                    0xae64125f1b17df6f,
+                    0xe3080ec619a2e3c9,
+                    0x2fdd387332f20a82,
+                    0xee9eaacc43433ea3,
+                    0xb44de18c40145316,
+                    0x4704028465231a5e,
+                    0xb852d9bd4975a958,
+                    0xe16c417654805e39,
+                    0xd72b419ebe5fe980,
+                    0x0b1291dc1d86d3b7,
+                    0x002d45c588f55327,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x5aa3c0e0d9e466c7,
+                    0x7d1155c541db1c54,
+                    0xf04f0a3c33877989,
+                    0x78385282204fd51f,
+                    0x86cb7ab5f4929a8a,
+                    0xf75393752f55216d,
+                    0xbf17938f8977ab67,
+                    0x5e0e9fea34230974,
+                    0x3e570750bd38374f,
+                    0xe0222bb67be7a241,
+                    0x91e0fe46d51b7bfb,
+                    0x005930cdb5fae12d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb7821edc96093a97,
+                    0xbeb0cf7932db3954,
+                    0x5242937449cc8533,
+                    0x70375726b9e7371d,
+                    0xf06b1230a3f7e7ff,
+                    0xd37b463b9d230765,
+                    0xa1662cc615b945c3,
+                    0x07566abf8f877a5d,
+                    0xad0525038a7aa274,
+                    0xfea692e3d5d84930,
+                    0xd0d792a0cc9e296f,
+                    0x007a181ba71d78f4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce0f6617730284a1,
+                    0xf2596131c789d16d,
+                    0x55c3338b8e201479,
+                    0x3ae10d6076252f2f,
+                    0xb8c42875e24a5c74,
+                    0xaf784316532e846d,
+                    0x1cf0f50197867c18,
+                    0xacd9e7daaf6f18ed,
+                    0x6c3e0f7efe73a89b,
+                    0x4f7b0991eafef740,
+                    0xd01edcd74afe2988,
+                    0x006d82cc563a055c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8708b7411b180fe3,
+                    0x069c7e12fdc4e83e,
+                    0xe74c058fa2c1c5af,
+                    0xfcddb00cca130b78,
+                    0xa8b5b0253efee013,
+                    0x19c1ecf8537612e8,
+                    0x81bd9b9af624efa5,
+                    0x34b3de4057eec645,
+                    0x74e4d18e900c8ffa,
+                    0x63fe54567dbe1e44,
+                    0x2aa88c3a854f3180,
+                    0x00064ca88aff625e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc7f26d33f11d5564,
+                    0x886b2f4c1c07ec30,
+                    0xc5e373376ac3a79e,
+                    0x2e22c75bd7aa4b0e,
+                    0xb45c0afadfea908f,
+                    0x81d1dd78b1c8867b,
+                    0x47e1ec045e5e2ecd,
+                    0x00392b63dea2f2c5,
+                    0xfe0f081167edc356,
+                    0xe235af0dd39c59cb,
+                    0x3a259c6f6932093e,
+                    0x00ee3614b602b75b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f8266d45bd29249,
+                    0x5327cb89ca9fa492,
+                    0xd9ca30c3ec4f4cc7,
+                    0x2271a6125b8a1ebb,
+                    0x44646f0efad159c9,
+                    0xd68c648438f74b5e,
+                    0x6bab81dfe92c89a7,
+                    0xa46d955ba6d22168,
+                    0xd857542eb3979021,
+                    0x125cfaad7e4ca5a9,
+                    0xb223ea5251158e8c,
+                    0x00270cf136752cc2,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa602e3cd480a730e,
+                    0xcdd7f9ad4b0d52c7,
+                    0x88506bc83bf85a69,
+                    0xb7fbaef4548023ce,
+                    0xed20867e089214d8,
+                    0x619d43197abf2ed1,
+                    0xbacfc22fe6948490,
+                    0x2fe3403a67f0398c,
+                    0x6ca67685e909484d,
+                    0x263f96e9d982fd50,
+                    0x9c3d661b1dd94ae0,
+                    0x010e9f2673f2c42f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbb9062743000bb3e,
+                    0x06573f8d3dab19e3,
+                    0xa4fe1465a96a4553,
+                    0x3f6ec08115f78e77,
+                    0x12bda274f24b3ed1,
+                    0xd6d73b0f7f546531,
+                    0x93bd1fa0273fd94c,
+                    0xaf5d2cfcb99df5c8,
+                    0xbf125ad44cb5dab0,
+                    0xd0fb61e5d8465c12,
+                    0x88426e2627f313c7,
+                    0x00e0dfb14c7e331e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger768([
+                    0x1fa0aa8d21f42e62,
+                    0xe0c59ecd8354a847,
+                    0x2075300d996e813a,
+                    0xeeb99796958190b7,
+                    0xf034c63a05b5ec23,
+                    0x05ae4888a542904c,
+                    0x96f8ecbf323f3a4c,
+                    0xb5a1b1a47bf2fb07,
+                    0x3d748385f6e2ec8b,
+                    0x38d0639bf1d24898,
+                    0x8b9ca7f3ab1c7323,
+                    0x00034c5fd3e2897d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x413e800eff829ad3,
+                    0xdfc31f51f3cb942c,
+                    0xe86daae3517df467,
+                    0x25e9f34e4b39d141,
+                    0xc3011989da2389ad,
+                    0x0a5267d5c2f5f3cd,
+                    0x8dabfe02e6bf0bcd,
+                    0x0eb95e4daa3028ae,
+                    0x9a846f4e83e63185,
+                    0xec6ff0c70d3bf423,
+                    0x736cbca664fac7a7,
+                    0x011f7c78a1d6ade9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x533ccb8304145a94,
+                    0x124c36133ebfb145,
+                    0xc2425f2843e4203f,
+                    0x6b2be14115079fb9,
+                    0x29a5248594f8475f,
+                    0x713e07ee5559320f,
+                    0x80bb26a3800f4683,
+                    0xfaa48c423ec39977,
+                    0x4368b9a794ee7a11,
+                    0x366a5a51be4575e5,
+                    0x1349d39f44f4e885,
+                    0x00ffa861554ada91,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb20eb4e7388bd3a4,
+                    0xeee23e86dccc4b90,
+                    0xb8c82e5d7c905b34,
+                    0x5f85090622ac5c33,
+                    0xba87df84032bc13f,
+                    0xee10c3a375fde2f7,
+                    0x8f7dbfb17b248f9f,
+                    0x4379f8fa76fe3d04,
+                    0xe634868df91034e2,
+                    0x843f9e03e5f1243e,
+                    0xcc2ea332b5ab52e3,
+                    0x003315159762ecae,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa01ce8499f2ad4ea,
+                    0x7973363e00494bd9,
+                    0xe5df54c2db986b28,
+                    0x118ac1a41c11bf6e,
+                    0x3c1063e2993864c7,
+                    0x35da0c2a3aa424ee,
+                    0x4a9c23e027f144fb,
+                    0xcf1210c639fc2631,
+                    0xdddea93a44e76591,
+                    0x27ad89d6e57d4920,
+                    0x62490ff798506cf7,
+                    0x00eaed7775a3c48a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x76b30ca9b12f09d9,
+                    0x90eeb75ab48bc7d4,
+                    0xd8c8601560ff8929,
+                    0x82642a932e3bb688,
+                    0xd7df340054f04919,
+                    0x0f23a7ca28688194,
+                    0x474be16602066ded,
+                    0x828ff5e949c9f645,
+                    0x591b6bb70682868b,
+                    0x755298a0f60b6602,
+                    0x458afccd052ec4b9,
+                    0x001bebfbaf7d1885,
+                ])),
+                Felt::new(BigInteger768([
+                    0x14d6071c917c8884,
+                    0xe51b8108118d3b05,
+                    0xfe1237a69fa1226b,
+                    0x7b793754470f206c,
+                    0x52ec8ef230db396a,
+                    0xc39b7860a47ca708,
+                    0x46d49079264a6fc1,
+                    0xe60c24faf1dae5cd,
+                    0x58f26f9c60a17db9,
+                    0x241da09154910488,
+                    0xfef57e56e25fbbd5,
+                    0x00fc17b214a2242c,
+                ])),
+                Felt::new(BigInteger768([
+                    0x76fbf32c69a1b252,
+                    0x23b7f3d2b483e50c,
+                    0x8b57acc8be492144,
+                    0xd8d354ecf7a33b00,
+                    0x0ede11cf1dda0e1c,
+                    0xd1cd49ab2a376cf5,
+                    0x42e151c8096a846e,
+                    0x0eb03269f51fb26f,
+                    0x3c01035beeec80b1,
+                    0xbed360daea0cfefb,
+                    0xd0c0c00702647447,
+                    0x00a159ba338a3736,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger768([
+                0xe001a85774623f96,
+                0x7ceac46301cf65c1,
+                0xe79732421de6b323,
+                0x32e4b2f13be8ba61,
+                0xb637094b108d6a2e,
+                0x96e946b9d55d6955,
+                0xfa1381f8438cec94,
+                0xaab903e0b3310e10,
+                0x17098b9900e341ac,
+                0x594bfb26fafe7a33,
+                0x82f50c0b5b3e2d27,
+                0x006936a2fbbbc6cf,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x3b842eb7ee9f43e9,
+                0x66ce1cc3872ea206,
+                0x869338b3ee5ebad4,
+                0xccc4067df97feb37,
+                0x177ffe538bddbb63,
+                0xc447f73c67d93ea0,
+                0x44e372203e81fd7d,
+                0xf880859863b2d6f1,
+                0xf0ef9bbd669c733a,
+                0x48b82f9a11e5c150,
+                0xc0b82335d1ed7fce,
+                0x003a71d5814cc279,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x940dd173056a2559,
+                0x3af307db211eaafb,
+                0x526ba81d0412017d,
+                0xf6ed90ab23ce1bf3,
+                0x921a036d92aa48b0,
+                0x476bd1e32250cc88,
+                0x11d8a9bc3660364c,
+                0x6fb86e2fab51d13c,
+                0x5f06d7e978da8bdb,
+                0x37e05929dfab79f4,
+                0x05adb2481c01faf6,
+                0x0101e66b70d3db9e,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xa052e4754125c538,
+                0xfaf7e417ad410c85,
+                0x675c6d464f4a2a19,
+                0x457b4885b976fade,
+                0x9deb6edebec09c71,
+                0x626518f58ce6b952,
+                0x299e7d5423cd5854,
+                0xc285e47961047b94,
+                0xdc618a4ab41b7b82,
+                0xc620b8b25485af9d,
+                0x1906a65dd850412a,
+                0x010ed2edc44c28ba,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x750a4ff865c97c3d,
+                0xe99ed94d043e465b,
+                0x422a4c95b8bbedd2,
+                0x5e73d64c8a9e55e6,
+                0xe4eea28d9143e4f4,
+                0x1e390c05fb30c45d,
+                0xcb86cc95b6ca11b5,
+                0xc013ed5f8bdf8b79,
+                0x1c31cb25e63ccd5e,
+                0xf7976f59ed426f9d,
+                0x3488e139725c6c34,
+                0x011e3cc29e7b0d90,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xe87f1579d4d7a659,
+                0x6c03e03042e3e2b8,
+                0x76f7471ef93f5fda,
+                0xa9cc6eff88ed127a,
+                0xeefe24c489d2a4ca,
+                0x0fb5ec61db888a53,
+                0xc7e701b50faa05d1,
+                0xf65d531248f0b53f,
+                0x6d2a3279664db9dc,
+                0x17ad83e2a1d10dd4,
+                0x3b55b87e3f633957,
+                0x00824020459463e0,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xa0056c4ad167abc5,
+                0x8313b8422f82f278,
+                0x84fb55647a5a0d22,
+                0x0a94b1ea117cd5ac,
+                0x412d205f609352ab,
+                0xfc57a166cb78722d,
+                0xdaaba7342cb7f0c3,
+                0xaa6d5b8a0efe0e11,
+                0xbfa1425ff823937e,
+                0x08f0e6655de043f2,
+                0x97d4cdb393355d04,
+                0x009325cd68e07d7a,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x751b3b3f6b1e3c06,
+                0xadc07507116c1a54,
+                0x7a6f82cd42af4264,
+                0x0887362bd3154077,
+                0x2bb227bddf7aa340,
+                0xcc845b6de090ccb2,
+                0x07d0d21ee365b261,
+                0xa266a97e98748a22,
+                0xa8c6071e18738171,
+                0x8e446dc41e54c20e,
+                0xab03f6a87e1021aa,
+                0x001338d710005b05,
+            ]))],
+            [Felt::new(BigInteger768([
+                0x4b1af4afbe2af9d4,
+                0x96377e5bb2d6c1db,
+                0x7242789a2215994b,
+                0x84a839c79b55c40d,
+                0x5a36bbdeb8da8224,
+                0x35992ee3bc287a27,
+                0x49aeeabc5eb2a5ec,
+                0x7323dfaec6aee676,
+                0x843c6f3425b75d9b,
+                0xb5226f6837f5eea4,
+                0xd4f8c570a03a07c0,
+                0x00d5c74007f5126d,
+            ]))],
+            [Felt::new(BigInteger768([
+                0xcd68d10bc6b80a4b,
+                0x2318b35d18dafb90,
+                0xb2ff06fa64301bb4,
+                0x0bcfad75ab748ecb,
+                0x7c1e75697ff82fa4,
+                0xd7a3d661efb9d3a3,
+                0x1ee6a1501f43bf63,
+                0x4d01304696735b50,
+                0x2987cf4a08dcf11f,
+                0xc2c145b28d4694e6,
+                0xccbe723a89b4f48e,
+                0x00ade5c1934d09ad,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 94, 95, 96, 190, 665, 666, 2000] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 665];
+        let mut opad = vec![0x5cu8; 665];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 96]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
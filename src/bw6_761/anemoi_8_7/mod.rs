@@ -0,0 +1,3707 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger768, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 768 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (96-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc19ea91d686f1bf5,
+                    0xba9fd5fae59642b3,
+                    0xe30b9506ef340483,
+                    0x78bf80742b4c0f6d,
+                    0xe991be8abdb5ba85,
+                    0x3a30196aba653964,
+                    0xfaa7c354c32c77dd,
+                    0x967889ea123661d3,
+                    0x68fef3f2c0916320,
+                    0x721e473b5786fb9b,
+                    0x997e06e5067005fd,
+                    0x008316346760363a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6362f87b7fb28749,
+                    0xdc0581e33f5de606,
+                    0x9d046dae028569bf,
+                    0x540346211ac831f0,
+                    0x5c3a8ab0c6e437c9,
+                    0x52c37afd2a00dc40,
+                    0x581eb0f5ce77dae0,
+                    0x015b36663593dec3,
+                    0x56e6f209fce3ec36,
+                    0xb5f81739aeb9da9a,
+                    0xea15aa7364182ea7,
+                    0x00a5539a971a72d1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbfcf93b8258c5213,
+                    0x0d98a613123a079d,
+                    0x1786669ab06418bb,
+                    0x8f8ca56b6178e00a,
+                    0xb9c5dd961cee64e8,
+                    0x59f3eb56364ccadd,
+                    0xf27b06cf1a5f142c,
+                    0x0e0dc36d1d94e9b4,
+                    0x35e2b90c7f72de0b,
+                    0x176ec801e536128a,
+                    0x6cb2cbd3069345c3,
+                    0x00d24bd03f332b76,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb51b319cda8169af,
+                    0xce7fa1153bf25535,
+                    0xeb4624f7e3805130,
+                    0x025714ddca8f053f,
+                    0xc43aab80ab95bd19,
+                    0xf32b6f3b38393d19,
+                    0x5785e6b229e7f4f9,
+                    0xf2b01bc9dd4fcd11,
+                    0xb3c53493a13f5c7b,
+                    0x6a3678a84280c1c5,
+                    0x8952421115452d1b,
+                    0x00295f51198e5043,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9a3e4a7579fa9d3b,
+                    0x23ed97569aae8b01,
+                    0xdc8a069462a662ad,
+                    0x032c01b20714995f,
+                    0x2e143679fa171e32,
+                    0x56168c8b6bc3dc1d,
+                    0x3a441cb2377f95cd,
+                    0xe0e722ed19340d04,
+                    0x6407c6992317915b,
+                    0x5443d4f71e9c0cb4,
+                    0x51b288951d653684,
+                    0x0002a0ed592c5002,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdd9d29cd337840f3,
+                    0xfb6b91154bd1fb23,
+                    0xc2e4ea8f15991e21,
+                    0x84b9740eeb7920c8,
+                    0xb7558d9a1f252604,
+                    0x5f01b9ab3b28a7a9,
+                    0x9f8661766e6b3691,
+                    0x9d722ddf9d36142d,
+                    0x3f5338a9c5af377e,
+                    0xd0cf2a54f04e7a36,
+                    0x142023c215f70f72,
+                    0x00b85fb4d1f4c985,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2f42958fdfbdfcee,
+                    0x30dfaebc2ffb222f,
+                    0x3e514b014a461322,
+                    0xf111bbb45b7c109d,
+                    0xece2f3ce370b5011,
+                    0x936a4b178499779f,
+                    0x2aeab34710396d10,
+                    0x5e7636ae1b869836,
+                    0xe6459220ea4806b0,
+                    0xaa566ed97c126ba4,
+                    0x248f3bf0ff913e06,
+                    0x01076b6bf3a984ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40f07585822212bc,
+                    0x13ba0f1245b2d4c3,
+                    0xd7ccb6455a473b19,
+                    0x6faa19595b289046,
+                    0x6979532dd356ce96,
+                    0x847e42d701deb878,
+                    0x3e01d13f7f289fb6,
+                    0xe0ac8daaadcd0811,
+                    0x25e8c8e3562cc096,
+                    0x4e46b79be758a6de,
+                    0x9fa89fe652de1a0c,
+                    0x00c759970b1fd755,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x67a13f898f12a8ed,
+                    0x32d3fa2bc8332c62,
+                    0x2e80a126365ad82c,
+                    0x3e7913d2da380896,
+                    0x251ab586bb749004,
+                    0xfe7cd4f55818d316,
+                    0x443202c8cb79ba4c,
+                    0xeec95081eae4dcca,
+                    0x02e5dd1900b81612,
+                    0xa272ddbd43fa9508,
+                    0x8353f9fe140c7c95,
+                    0x0117321fc611cdc2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9a00e05b7eda2bdd,
+                    0xf26973a0f548760b,
+                    0x387f673666e963bd,
+                    0xb4947d7d93c8f9c3,
+                    0xa5d5082e1e7ee0e1,
+                    0x50ea02cec7374b85,
+                    0xf35997a2e8278d29,
+                    0xef002fa23feb8425,
+                    0x34b4301b383ed6d3,
+                    0x08ac4d8b98436141,
+                    0x1efa74ef3066fb2c,
+                    0x00f1deb497916b3f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x44048cbec5732b62,
+                    0xb0855108dca25f78,
+                    0x8851e4806bbe8504,
+                    0x6c3d3421eed6c744,
+                    0x9f3563e3a258dba0,
+                    0xc1872cf2f6a1a711,
+                    0x9080d14c7f241000,
+                    0xdf8e9bebba7018ab,
+                    0x32b3b67786b6e7bf,
+                    0xf56cbc435889a834,
+                    0xf6d309c77adbef40,
+                    0x00a09a367ca74af8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x18cd3bfd6a56f2f8,
+                    0xa380c69de5f95638,
+                    0x87cf30d5ede30d71,
+                    0xefad080d5a557e4a,
+                    0x633ba56c3f1a68b0,
+                    0xe46aaea4dd53a525,
+                    0x9ff109c08c8175cc,
+                    0x7b6cd5b4bfde1445,
+                    0x02a663f9ab920e02,
+                    0x8d6415227525281e,
+                    0x3ce621408c6d83b4,
+                    0x005e0a8c13351f97,
+                ])),
+                Felt::new(BigInteger768([
+                    0x34918394202f9244,
+                    0xd0dd0bf371f6938f,
+                    0xe6859e4c437b0c64,
+                    0x551f362bb072c381,
+                    0x19da88605dc4261f,
+                    0x21b4168af85845b9,
+                    0xdfe9573ffb58b5e1,
+                    0x9501106062ae1b04,
+                    0xd54a89d5edac403a,
+                    0xc90fc9e3aca420cc,
+                    0xd62c1b3987585759,
+                    0x008b050f1a551eff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0310f5502b3f0eab,
+                    0x51f27254111c238d,
+                    0xd3e1ce7db1c9ef3c,
+                    0x18f4f430d53646c3,
+                    0xa18963a35c434e1f,
+                    0x35900c071ccd2738,
+                    0xa7897ce93692e27c,
+                    0xf61260c49ebd646a,
+                    0x20be858f85dcb41e,
+                    0x886fc89fdd797893,
+                    0xd80d777738e0310c,
+                    0x005d445c3da28bbe,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa55a43b2979745f5,
+                    0xb439dfded202db50,
+                    0x94015032e19324d2,
+                    0x52eeb38bdf15d9ad,
+                    0xd4cb0543662bba91,
+                    0x26f5dd78b042f323,
+                    0x26624073a91cabf6,
+                    0x1265b22a20df7b33,
+                    0x99a4a60761656b23,
+                    0x0b8d46f47d80eee7,
+                    0x5fe68d6cb5ae57d7,
+                    0x0049013d9d4fc47d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xedbaec5e3dc95ad9,
+                    0xb22848746c979748,
+                    0xe19dbcbc4bde9d22,
+                    0xe5a6000d8aa102cf,
+                    0x4bdb2dc480a02319,
+                    0x3d69880ad620341a,
+                    0xf508d8faf6d33c10,
+                    0x80921a0295ae712d,
+                    0xa583dd792d15d3f1,
+                    0x63e3afe3a1cd1306,
+                    0xa91f1035c5569997,
+                    0x00db5ada97d647aa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xdf98cb3407b3a128,
+                    0x06bd256eb8d4bcb1,
+                    0x7612b2524a7c62f8,
+                    0xf8a1950ebf95f6d3,
+                    0x777b4909de4c2418,
+                    0xb8e623dd0d3a70e0,
+                    0xfe2d1b7cee5a3e15,
+                    0x4c808103009a3c10,
+                    0x403b5c224212043c,
+                    0x6ea491291749c7d4,
+                    0xbd351f1b0c6d10d8,
+                    0x009c5af4e8fe38ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6cd6622cbe775a67,
+                    0xa130e56f300965f4,
+                    0x7f918b6c3a2f53f2,
+                    0x702b231e89a3a6df,
+                    0x0e642eb8f960b9a3,
+                    0x9fbde519db50a0d0,
+                    0xeaf574660dd3a546,
+                    0xda5c7f687997ef4d,
+                    0x57f6d10fc7b86f00,
+                    0x559ef9c6f141d77e,
+                    0x16c03fca0d2cdeb7,
+                    0x00821b5463ddbea9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdb3091a4a4ce4c4a,
+                    0xc608abf6800bfaa1,
+                    0x2c0017f04740a615,
+                    0xc7b0394033ba30c0,
+                    0x10c0a238c624da11,
+                    0x28d72d0c58ea416f,
+                    0x1dd7eac8cdf4c15d,
+                    0xe8ab980bb62f667e,
+                    0xf907b1a70673384f,
+                    0x90b49a60f56ed5bc,
+                    0xf6c3ed02579b4274,
+                    0x010d74042879a742,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcdc00b35d9c48315,
+                    0x73241e180510dcc0,
+                    0x699a43b482afdfe5,
+                    0x52dd7d6858960d36,
+                    0x8f760157d64fa505,
+                    0x1e3dc409c62b7cd1,
+                    0x912736a8e18260b3,
+                    0x4750a4610a0b7540,
+                    0x0a82801dded17c01,
+                    0xff230944fa74be93,
+                    0x69b2cbed31589414,
+                    0x00ca1892b70fc5b9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0a96e3f8238f3a47,
+                    0x567dc53a3815e1df,
+                    0xa1fc65be9bc8c5b7,
+                    0x9c544af5e26c6dec,
+                    0xf814622985aa2359,
+                    0x1312a2474f3eca30,
+                    0xb92d3f62f46bb2ed,
+                    0x795702c59ecc056a,
+                    0xdbcd84313cda7533,
+                    0x1847826b16fe61bf,
+                    0xd04c141778885cef,
+                    0x00d55830db59bcbb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe1bb073142d61d91,
+                    0x24e634ab39661133,
+                    0xeea811f9ceb7f0c0,
+                    0x57607701e9be779f,
+                    0x279666a5161b7e36,
+                    0xaaa16444619df183,
+                    0x04e9b01ddf27f257,
+                    0xb5e3d321782b7f74,
+                    0xf1085b58f0a2c15a,
+                    0xc0b189582d159819,
+                    0xc4a65bb8225904b4,
+                    0x00964862ab50dd01,
+                ])),
+                Felt::new(BigInteger768([
+                    0xab1cbb485ce9c3b9,
+                    0x22d787757e6f55ad,
+                    0xc991a65f35b12ebd,
+                    0xf92264078f3b035c,
+                    0x4ddac986fe12f939,
+                    0x14e50e3956f7bdaf,
+                    0xc2885eab8e7eabd8,
+                    0x9cc6dd5f9b54e257,
+                    0x8efab29d8cc5fc17,
+                    0x5ceceebee8ec2191,
+                    0x5e5a19e41e68266d,
+                    0x00a32408d2970471,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6217a621baddaebf,
+                    0x2ab65db68b3e0fe6,
+                    0xfd8bd0fffc7a321c,
+                    0xf2885820c8a94131,
+                    0x85231b09d3a1513f,
+                    0xce6883c22821075c,
+                    0x113950f2fb7b44c3,
+                    0x11e6fc4dacb75246,
+                    0x38a3800c1751d58a,
+                    0xe0627b868a5e779c,
+                    0x3e64702670d056b7,
+                    0x00bbc791e2a0ed86,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x9bc8dbddd5dd996a,
+                    0x9def8a6288182f95,
+                    0x5d91a119c5b2e1f3,
+                    0xc4527f082335362d,
+                    0x050665220034b5f8,
+                    0x02b4b14ecd0e6879,
+                    0xe73b19d553b60d29,
+                    0x66a685d168ed2e68,
+                    0x0f37f816406f4539,
+                    0x763c7fff20af1e9d,
+                    0x3ce5b743b8a3e3b3,
+                    0x00a0b7472bbc9d04,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcc64cb6fdc497505,
+                    0xadddb5f47fbe406d,
+                    0xdd3362e5f828e6fd,
+                    0x2b9625df247dd3a1,
+                    0xd85cbb1985b4d533,
+                    0x6c37dfbc5c1a37f5,
+                    0xd273bd73be185e32,
+                    0x5fe169c45bcf91bd,
+                    0x155e050a23197b33,
+                    0x3614f8d2521e4d4f,
+                    0xa052b94f606962ab,
+                    0x00bf998607790c26,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce11c057a6b20b9d,
+                    0x7373f732e46a9a85,
+                    0x5d05171656ebeb93,
+                    0x0d81686f55a6609c,
+                    0x61ea1940bf644f61,
+                    0x93b3052fb2fa7d4c,
+                    0xd952ddc92a18cc44,
+                    0xf5a021c30cb029c5,
+                    0xf30fd8355807d962,
+                    0x89496686b94b7158,
+                    0x0f9bc13edcf32e4f,
+                    0x00bf3b1edd74fd2f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf84755417919d20b,
+                    0x7fb8ada76d7ff658,
+                    0x913d05cbd92d9ee3,
+                    0xca8149fa1f324f5f,
+                    0x9f35ae4be4a31984,
+                    0x9de8952482d894a4,
+                    0x915880e4ff23d872,
+                    0xdf38180311e5163e,
+                    0x7941f0592e68c972,
+                    0xd32ac9b9d64fd15b,
+                    0x4b7102bcefc6459c,
+                    0x00caf0dcc7cf7afd,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1203e94a82d1ef2e,
+                    0xbcdb7aece685ae40,
+                    0x707a03b95d435172,
+                    0x765f11d6e138b88f,
+                    0xb85d530cfb167eea,
+                    0xdad63231be8e7ad4,
+                    0xc00f5b1cb6c5926f,
+                    0xfae12eb49d94ce5e,
+                    0x0996e054a87b7bcc,
+                    0x2266027674a87f2c,
+                    0x3228c46a55509cf1,
+                    0x00dd496215b023d6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe749e501f296d4e5,
+                    0x8c0f9328dc192a4e,
+                    0xe9dfc2cce31abbf4,
+                    0x735e61280b6c3e1d,
+                    0xe30390fa29ede476,
+                    0xbd9101cfebc6aeab,
+                    0x47db0d7e5c4c77d6,
+                    0x9983c655647a6074,
+                    0x5a0393140ced7299,
+                    0x3f1d9a0ef1c2c7eb,
+                    0xbc3f9742cbbbef84,
+                    0x0075624f94e2d891,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2e1dc3a16eba3ca9,
+                    0xe0b16a1ecd027069,
+                    0xad490245df747415,
+                    0x8860513987081c97,
+                    0x04b02af7a8e7aff8,
+                    0x9263d6df9fff5cd0,
+                    0x68f293a494b83269,
+                    0x632f93b239ac73b8,
+                    0x1d67916fb3b6d1fa,
+                    0x547ef0e9e14cc013,
+                    0xe54a9a897642e43e,
+                    0x00b4e9fa8cb0c3e6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x401ce647d62fac3d,
+                    0xe224928be63031eb,
+                    0x1a799321b3251753,
+                    0xeef12b1dfd0036a0,
+                    0x89ee847e7260591d,
+                    0x8849ed7e1abb298a,
+                    0x2056d413cf296762,
+                    0x25925ed9b095941e,
+                    0x18bf1a6646fede23,
+                    0xd63f93baf155e081,
+                    0x4bfe5f04c2c532fa,
+                    0x00944f149ba2395b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x2938427e6cf16500,
+                    0xd5df1454310d90ed,
+                    0x59bcb515b20d5d95,
+                    0x5ecc87101a82a225,
+                    0xa7ac6125bbdeff28,
+                    0xb181bf1157fe6f4c,
+                    0x64b9d6c51948c0b3,
+                    0x0c083261a4e65ec5,
+                    0x6c3f0f34c0929f64,
+                    0x09f2421dd2e9ac54,
+                    0x3acbd37ed8fdd463,
+                    0x000c36f2b74a2933,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb42b2dfc4d722d44,
+                    0xde735da971800fec,
+                    0xb0b8b3e06ea24156,
+                    0x12248f71ec5098b0,
+                    0xd8cac2a06f0085f7,
+                    0x215ce8038f13901b,
+                    0xa52c91c0370e71fc,
+                    0x1cc34bbf386a6433,
+                    0x5c65fc04db37c1f7,
+                    0xd958c2618489ad60,
+                    0xeeda326426ac751f,
+                    0x00f010f94cb1e49d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x80bc4036e85b0824,
+                    0xca1d69cf83456ed7,
+                    0xa360b2d47d3c2db2,
+                    0x536f63d3b8fa9b16,
+                    0x7a43f0e5e9880491,
+                    0xf07f58717a0d076a,
+                    0x0ac05754b3b6bc6b,
+                    0x238e32e235e557f9,
+                    0x3c47efb1c6772890,
+                    0x81f3ae6547c398c7,
+                    0x01b1df93a072d975,
+                    0x009da5f87c254b57,
+                ])),
+                Felt::new(BigInteger768([
+                    0x845f943613b87507,
+                    0xbbcbe7a7be42bcb4,
+                    0x24d426ceb3004dec,
+                    0xd1debd7b68241403,
+                    0x059c0d4122bfedba,
+                    0x634a603a6705501c,
+                    0x4fb582defee9d2a0,
+                    0xe1ebd53c755e8262,
+                    0x7aea413873171b2c,
+                    0x659d6d6742e76cfb,
+                    0x830a079a3b318367,
+                    0x0078145c1205fce8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1a24f3a70cf7f45d,
+                    0x6cbc85e34e976bf9,
+                    0xe0028464933696f3,
+                    0xf754c7157b057241,
+                    0x809db7ce16999612,
+                    0x8611e95ff871c8e0,
+                    0x34ffc50bac00000c,
+                    0xcf3efffb960608be,
+                    0x94ed5b200d79512b,
+                    0xbe52e02cc4609e11,
+                    0x926e0f5efb836995,
+                    0x00aaa3090e59e93f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5c791e98f9821151,
+                    0x785016a5c9c135a6,
+                    0xea66393ff3309399,
+                    0x3b1160c23150e67a,
+                    0xb4aaccfae069db90,
+                    0x57478d5054b64448,
+                    0x8ebf4c6011278ada,
+                    0xf733793e2d32f1df,
+                    0xda2bf2184f91907a,
+                    0xd8c3099905dadd36,
+                    0x5b31c292eed25037,
+                    0x001354f9dcf1a065,
+                ])),
+                Felt::new(BigInteger768([
+                    0x04dca1c32c3a1aac,
+                    0xe41d517e1a304530,
+                    0x573ffefbce040f73,
+                    0xdf1e9a4a5e8b4eba,
+                    0x0920d2ded0405999,
+                    0x80a05e45163002ff,
+                    0xe9d9ef7cb6af1b94,
+                    0xf3b2d94e918ff913,
+                    0xedec78f1018ff710,
+                    0x8634c7517c5478b3,
+                    0xef72d053991b6c1d,
+                    0x002b543338734cd9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8dd84a59f29f6b2f,
+                    0x794305abdb3f7771,
+                    0xffa6976559046404,
+                    0xfb12bb917423a0ef,
+                    0xe277ec6c4dd1295d,
+                    0x22f1f426d281af64,
+                    0x542838a143599860,
+                    0xf5d9edf2e6c560ce,
+                    0xe6fd5cc74d95ac2c,
+                    0x3102d76e86746cf3,
+                    0x5d6fc4447d1997d4,
+                    0x000edc460de427cb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x8c29e6e7d1502aa8,
+                    0x138dd80b57db539c,
+                    0x22ec389c8e83ae63,
+                    0x1e24955aa5806569,
+                    0x06cd5c18885572b0,
+                    0x6e97c3c39ad89ffd,
+                    0xb7cb7c9382519335,
+                    0x5d18c7bf4ab3a2a9,
+                    0x07b7086c1caa2bc5,
+                    0x7aa2c1678b808a85,
+                    0x4c92e23c7e6f291e,
+                    0x001717f55103fe55,
+                ])),
+                Felt::new(BigInteger768([
+                    0x19fccfcd54ae6b24,
+                    0x3dc5bd57ee2bd5cf,
+                    0x2b447f8f0a5c6de4,
+                    0x578ee85ffceb46c6,
+                    0x56fecdb6f5c188ab,
+                    0xacde30dcd17ef1ac,
+                    0x1570432ddbfad00b,
+                    0x43d54a8a9f33ae4c,
+                    0x30c6cf77671b1625,
+                    0x9bcb0d9ff25bbc8b,
+                    0x93d5972fee298306,
+                    0x003713d73e422f09,
+                ])),
+                Felt::new(BigInteger768([
+                    0x925cc82765a5f42b,
+                    0x4cf0d36cb5bc5828,
+                    0x6e9320da66349376,
+                    0xdb5b884054519de7,
+                    0x45178ad86eafe3f7,
+                    0x7eafb62173d205f5,
+                    0x47f36b83b9ca23a1,
+                    0x64fe49de3d514da0,
+                    0xbe3503ec07cb506f,
+                    0x6416a1f1615e5f86,
+                    0x24034ec9c4ca40e2,
+                    0x00287171f57e0c98,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40a21508c7a0af23,
+                    0xc23ed060873f6466,
+                    0xc29e507f4cc84b58,
+                    0xf8fb62ba51d7a7ac,
+                    0xaf6a7e3f08116121,
+                    0x44836c4cf15e49ff,
+                    0xcbb727fa71cfabd4,
+                    0x934731d4cc9b9b82,
+                    0x17ae900a06aa294e,
+                    0xc7d61a96dcdffada,
+                    0xa81cccdaff98dbc6,
+                    0x009dea2e5b5f9950,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe681c7647fc555e5,
+                    0x34f3fffd40a2a364,
+                    0x1ef3c9153227878a,
+                    0x0d188c0cedf48e78,
+                    0x83b763bf68206150,
+                    0x7ef79d2d3b4b18c6,
+                    0x7895809a24f43365,
+                    0x18010e8cd64f6cd9,
+                    0x749002fe1827e5d6,
+                    0x0894d4ee6edd9497,
+                    0xdbee5a462bab84f1,
+                    0x011b110afcef8d88,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3f71e1347483f395,
+                    0xe9b472b4fcdfd119,
+                    0x172358e35df3ba3c,
+                    0xe2e6ed67f088e9dd,
+                    0x5127b49f3db4d40a,
+                    0xcf55cc847caf3578,
+                    0x4e0768a36bbc6916,
+                    0x9958e9db3c94e66b,
+                    0x4238b300ec3a8f75,
+                    0x41c3ccd1b11efa84,
+                    0x970c56f9188ed633,
+                    0x0106045e2eb764a6,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd7d9046961ff31c1,
+                    0x73bc1897375a991d,
+                    0x22a95f3a25c6f3c8,
+                    0xcc24d6d681d6087c,
+                    0x768ae7adf7007ca4,
+                    0xe1615f65b58d3db4,
+                    0x3308d2052055ec70,
+                    0xa12a31c7ce849a16,
+                    0x3af523a50fe79598,
+                    0xddcb11670eb81297,
+                    0x2e9e838d1e70437c,
+                    0x00254d24ce586e68,
+                ])),
+                Felt::new(BigInteger768([
+                    0x34b27e1246a71271,
+                    0xbf6d533cf0f92b93,
+                    0xaafb5c1c5dcae4c6,
+                    0x2d503a41e21071f6,
+                    0x8389f3960cbff70b,
+                    0xf3f5c78045fdcb28,
+                    0xa9f698f06fa0509b,
+                    0xfcc8f16ea099d26d,
+                    0xabcff0e78e375937,
+                    0xa7bb57f03864e2a5,
+                    0xb4fc0e88236f8f02,
+                    0x0120a74846a35951,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfb4fffffffffc330,
+                    0x2074b24effffc6b4,
+                    0x5a53337936b8278b,
+                    0x39860afa32a61376,
+                    0x2f634f8d48c05b9d,
+                    0x23b81847b2a110ce,
+                    0x558e305f8130ae05,
+                    0xc9e7471b95da050e,
+                    0xe6ec6737c49cc35e,
+                    0xff5551673471245b,
+                    0x5497f3fdd230548e,
+                    0x00ba6fd1f655db44,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x202afffffffc6aed,
+                    0xcbbb40f50ffca000,
+                    0x846b45f3f2548b2f,
+                    0x0130d7d3ee163336,
+                    0xc60e36872ff96593,
+                    0x5cf15bddcca61200,
+                    0xe11042a03e84f724,
+                    0xe034529db61a19f5,
+                    0xa143e6d90069c240,
+                    0x73a8e94d8245a697,
+                    0x9721389f2401fb2c,
+                    0x00b2640097659a9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0405ffffffff0baa,
+                    0xb4b04c6b1fff19ce,
+                    0x3d32dc8704ff55bc,
+                    0xb4d5fe641dc8fbe9,
+                    0xd9d3967c3b297017,
+                    0x81cccf44a4904817,
+                    0x4e9b4b7fb95a720b,
+                    0x46a5cffc8c5e4207,
+                    0xf6acb100116390f8,
+                    0x8b0914c7ce22045e,
+                    0xaf503d773ecb53be,
+                    0x00a3eefde24fd0fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa57eb62c70240e3c,
+                    0x37e93eee1562c92e,
+                    0xb9d99960bdaa7554,
+                    0xfd56f9fcc4967bac,
+                    0xe903d5f65ff42c3b,
+                    0xfe63834ffec271ba,
+                    0x4193b0d229adc9a3,
+                    0xee76eb784ace8742,
+                    0xd6d5d386908e7549,
+                    0x472aa4499ae4a913,
+                    0x79b9e5831ef8ca24,
+                    0x010ecf7f88bc821e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaa89fc966447a690,
+                    0x269edcfa439598c3,
+                    0xb786ab825269e342,
+                    0xbe274d97965a26b7,
+                    0xf15e802655479117,
+                    0x97ed6440e503bd23,
+                    0xecd41b0a618c0c6d,
+                    0x5c963157fa1ef91f,
+                    0x82cb9a37581e86da,
+                    0x16f2fe6aff3693d1,
+                    0x4762c186644c12e1,
+                    0x0068f276a6ed1961,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0cbbfffffffe5424,
+                    0x48ebe6873ffe6ce7,
+                    0x20128594d34683ee,
+                    0x3025f1ce08ebe45c,
+                    0x8443dd6b2d928492,
+                    0xdfe18641967f7f61,
+                    0x47a8669ff1843611,
+                    0xc36458dd82e27f00,
+                    0x066cfac85e2a5e91,
+                    0x16bcd82867d2e462,
+                    0x0a0886f0ab6652ee,
+                    0x008d6e29ce49c6b3,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf29a000000007ab6,
+                    0x8c391832e000739b,
+                    0x77738a6b6870f959,
+                    0xbe36179047832b03,
+                    0x84f3089e56574722,
+                    0xc5a3614ac0b1d984,
+                    0x5c81153f4906e9fe,
+                    0x4d28be3a9f55c815,
+                    0xd72c1d6f77d5f5c5,
+                    0x73a18e069ac04458,
+                    0xf9dfaa846595555f,
+                    0x00d0f0a60a5be58c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x343842af033c617a,
+                    0x324f4e3869475086,
+                    0xf1926ae642c31cd2,
+                    0x54fb108b1b735600,
+                    0xddfc720a838b3c56,
+                    0x6f18419a95a76d61,
+                    0x0695357b53768a12,
+                    0x84651cf8d5c20ac8,
+                    0x9ccb4f81b181370a,
+                    0xea24b3c1afcc5a41,
+                    0x8676fb02480846ff,
+                    0x008fb2aabb34ab97,
+                ])),
+                Felt::new(BigInteger768([
+                    0xae72faec8208cbbb,
+                    0x33c3f08692a7cfd4,
+                    0x962152470d215df4,
+                    0x3c06327b99388b01,
+                    0xcdd77501783a6621,
+                    0xd4c9618ec6a52d98,
+                    0x51b7a10e6ee5709f,
+                    0x17703c788585a421,
+                    0x61a5e885cf182802,
+                    0xf982009a6ea62026,
+                    0x9a763a32203554f2,
+                    0x003012ff0107adf7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x59a35c411b23f117,
+                    0x678bbafdf523522e,
+                    0x39685247b582067c,
+                    0x68162b8bee33a5d0,
+                    0xd390ff58e75f55a2,
+                    0x8f638d4e6bf9eb60,
+                    0x020b754aec5118bc,
+                    0xbfdf398c87a4cfb4,
+                    0xa64912fdb65f792b,
+                    0xf420eadd7a8cec7a,
+                    0xa797006553c8ef74,
+                    0x0098cc8b40b2784b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe522eff8677d3613,
+                    0xd85532a7faa0835c,
+                    0x4ba498946789c9a3,
+                    0x75f081c7d9520388,
+                    0x3bfcaf545e7cc08e,
+                    0xd016d1e3c337b3e8,
+                    0x59243e820c64f1dc,
+                    0x5e95fb014ab5fdcc,
+                    0x763b3e5074a212fe,
+                    0xbd082da9981ec729,
+                    0x79d368f7e060dea9,
+                    0x00bf14196f983849,
+                ])),
+                Felt::new(BigInteger768([
+                    0x191a7cf7104e3ef9,
+                    0xa074e85ae7e9a5b1,
+                    0x61ee95889f91ef33,
+                    0xfc71c01e7dcf58bd,
+                    0x77371d4d47b049d2,
+                    0x7dccfef7232d7a01,
+                    0xfdd594e3b02ccccc,
+                    0xf57722a41a608b90,
+                    0x5a658a1815f48171,
+                    0x026f64b2a31290ee,
+                    0xb3133216e8663145,
+                    0x0081cc61f0026ff2,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5acec460f9e626ec,
+                    0xc8176d46bbfd33c4,
+                    0xd43f9bfbec4a7403,
+                    0x1aad08d7ed22bff1,
+                    0x79aa78e65a51ee4a,
+                    0xa1b5cbe1b62b100d,
+                    0x064852ef36666bd9,
+                    0x3b554f8d7452e5d1,
+                    0x5630dfcfc1efb11e,
+                    0x78cd7d2386cf41e9,
+                    0x6a325373e058344d,
+                    0x005144054c348e37,
+                ])),
+                Felt::new(BigInteger768([
+                    0xed3226e9de776443,
+                    0x0ee9ef5c6410faf5,
+                    0x23ad1006b33a2d9c,
+                    0xa77aeb35e6d9af46,
+                    0xaa7372fff9ce46f9,
+                    0x7c6f3e809f7f6b78,
+                    0xb15c5b963c03ca1f,
+                    0xfe2c029b41a71920,
+                    0xe45d9cdcfa286ede,
+                    0x0996120c8580f085,
+                    0xe72681bcc77729b6,
+                    0x00fb2b6183036ed3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8d3f7c7956464826,
+                    0x81cc1a05e5141f00,
+                    0x505103422fe0a885,
+                    0x51a30d036a1faea2,
+                    0xb50dcd9dadea0bed,
+                    0xdbd874616e3f30e4,
+                    0x6f295cc6f105586f,
+                    0xc0dd806d7c22c1f5,
+                    0x38d45fa7759cb0ae,
+                    0x315166edf3367e6a,
+                    0x65b1a816dde45c34,
+                    0x00b0292d01bb847a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xb354eed832aed087,
+                    0x0ab74ab743945cb6,
+                    0xbd9073cbe3b88312,
+                    0xbccd1f7613ac8b80,
+                    0x92fff197362e722e,
+                    0xa32d0d494929c5e4,
+                    0x4c87192dcf9fbc88,
+                    0x1e1f0a30acca8932,
+                    0xc52a70d9a771a5d8,
+                    0xcfedbb175b77c15e,
+                    0xd4095976c1dcbc84,
+                    0x004252c0ba163de3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1887eec84c88c670,
+                    0xaf6274da31c364e1,
+                    0x69786aaf5d5213f1,
+                    0xa56926591b14791c,
+                    0x71ecf76d153062ef,
+                    0x840f4126b643eba3,
+                    0x97d85e14915fe7a1,
+                    0x38963e116926c04a,
+                    0x02485435df7f7a80,
+                    0x525077813e8582e4,
+                    0x7713aef6f7f54c86,
+                    0x00581a6a1b4bb3f1,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5de7f67abfa597a2,
+                    0x5ab93152864228dd,
+                    0xe8784a3b5f669e67,
+                    0x9e59ae0909ca7c73,
+                    0x664dc10a926ea375,
+                    0x8e8fb71fde4b9439,
+                    0xf65df44071d84d7e,
+                    0x9c0f12580f0f42b2,
+                    0x263bcadb9f1a8f52,
+                    0x37448d449ac9c757,
+                    0x4c22628209a1636f,
+                    0x010e947765633054,
+                ])),
+                Felt::new(BigInteger768([
+                    0x876d4753dc39dceb,
+                    0x34bfec72c093f6d4,
+                    0xca87992d142955b1,
+                    0xe0748fa2cd2a5f07,
+                    0xf32b911cef96090f,
+                    0x912eb35c9d7d30d5,
+                    0xdb63c667642f8151,
+                    0x17b0c8a8105fc725,
+                    0x88d3c50b7019d628,
+                    0x68f5cf2afdea6562,
+                    0x1481c85f8d027965,
+                    0x001203620aabfdcb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x47c7ff0e1e797b34,
+                    0xcf92869e708d32eb,
+                    0x6fb0c4b8e5480991,
+                    0xe5c107917e005583,
+                    0x59c5b1f51ea81a07,
+                    0xa75d315b474a7fe1,
+                    0x99c6293a7e00106f,
+                    0xf156b04d2d6e6070,
+                    0xc06a597df155a7e2,
+                    0x06e799a6fe5d7dab,
+                    0x3c3a3d3e18b745b8,
+                    0x00e0eb4a61a5ffc1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb937a6d26b2a6e91,
+                    0xa676f6fc302bdafa,
+                    0x3e92952723f83c61,
+                    0xe28243726ac9cecd,
+                    0x95ddc29ffea31539,
+                    0x49d2e44ad2c833b7,
+                    0x7c21c14fab86c3c4,
+                    0x9fec8895ae986633,
+                    0x77a15907c41ca868,
+                    0xbbc6aa10fdae386d,
+                    0x034c4ec30cf20077,
+                    0x007bb3e780819c33,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc3c62047c5c5732e,
+                    0x64d9f30df95e6d6a,
+                    0x3d02c62f6d54e351,
+                    0xc182ef5025556875,
+                    0x6eb8d46be9e9fb79,
+                    0xa9c56de10f64cc66,
+                    0x210e497b05e4bddc,
+                    0x9ea1c7187e943df9,
+                    0x62500b0338fc0f3c,
+                    0x3bb7e6bd4686b134,
+                    0x4ce22999e357afec,
+                    0x00aad3b6d84b190a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x72d710d65c779b82,
+                    0xb0f63ed47e0b660d,
+                    0x624ba6473646ba5c,
+                    0x73d189108e65e2b5,
+                    0xaaee8d7e4f732b24,
+                    0x3a51beab5b1e50f8,
+                    0x2618d931a2220aeb,
+                    0xb4549e35f5bf795d,
+                    0x0d86d5304e6a8caa,
+                    0xba3a368ddb0e8f37,
+                    0xdff3794f6f70ad75,
+                    0x00083b4e0e71a3a4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xc3261552a227803c,
+                    0x2b0bd718a61d57f1,
+                    0x239c428c39040a95,
+                    0xfc64b74d50d76852,
+                    0xbead201b9b8c3883,
+                    0x7b8684d7860277f1,
+                    0x5f005e0aa86b0d1d,
+                    0x93e88c3649228ac9,
+                    0x1f237a1c8161bdd2,
+                    0x10e37920dc0d86aa,
+                    0x77a13416d3f0334b,
+                    0x005d9424cfb7969d,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe9078aa029dd5a30,
+                    0x1d7a84f39af4bca2,
+                    0x16dcfe803c9219a9,
+                    0x77ba789b738997aa,
+                    0x01e3611f40467aef,
+                    0x31a217e7c3e20631,
+                    0x91a8f71be0780833,
+                    0xb7450c4cca25fc98,
+                    0xaf3bf3887b3cb1aa,
+                    0xde3c800e0673cc1c,
+                    0x66a650009f4121d2,
+                    0x0038e9e1e500dbc6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3429d21c2e63474c,
+                    0x488dd2798f5056eb,
+                    0xdd5583d4ff00301e,
+                    0x0082dcbba984f26c,
+                    0xeda01c2980ce115e,
+                    0x73f4ad998b496dda,
+                    0x468455da7c57486a,
+                    0x5e473c53a40b9546,
+                    0x1fb1ca6fb218124c,
+                    0xf99ed29e9621dc3a,
+                    0x2d33c42088f9b9dd,
+                    0x0046cef1744c783c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xaeb848bf9efae726,
+                    0x8b580c45d6e22f9d,
+                    0x0049b12fc86dab44,
+                    0x4a70125a168f8e23,
+                    0xcbe03fcf6448889a,
+                    0x6f5f20e17b94a758,
+                    0xa13a0ccc7d3040f5,
+                    0x19d9a64bee5c4e9e,
+                    0xe2ed8a97299ac541,
+                    0xc41d0829f86b9a14,
+                    0xe112667be5154ca6,
+                    0x011af8568fe402f8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb0207d365d666033,
+                    0xa49d646cf5df787d,
+                    0x83594b458acc2587,
+                    0x84f6fe01f3d085b2,
+                    0x16fa1af43c474b8e,
+                    0x900a50f92c31ca23,
+                    0x3dcdbbef95e39d2a,
+                    0x6f565e24e41fc9d1,
+                    0x20cc33acbf1b81f7,
+                    0xe6dc9999c6a95d31,
+                    0x877b4b47813cc22a,
+                    0x00e1cb5ae1ebc19f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3466918312d9c315,
+                    0x8b70a3f203650a83,
+                    0xc5f26813dd3cf2b1,
+                    0x686aabef266b9e2b,
+                    0x0d452c1a0d71b8b5,
+                    0x24c0ed759d1c400e,
+                    0x29357fd7d237dec9,
+                    0x29ff19797b7b1ac5,
+                    0x3034d0503fe52b31,
+                    0x2488f6da32419460,
+                    0x4d3db4277841a623,
+                    0x00bf20bf597b8457,
+                ])),
+                Felt::new(BigInteger768([
+                    0xce1367c0bfba1f9f,
+                    0x5b92ca7dce652ce8,
+                    0x2d359b58e9d0632a,
+                    0x2e1b77b5feade7ae,
+                    0xe01b7629db8c2234,
+                    0xb71597b40b93dcf7,
+                    0xab8a0ab18e9c76cb,
+                    0x3a7259783b9b273b,
+                    0x15581b20d0512cc9,
+                    0x7493bd3e9bc61ced,
+                    0x7c4c4ccbad147e1c,
+                    0x0036b11c3d8bb9ad,
+                ])),
+                Felt::new(BigInteger768([
+                    0x38f14064738f15ac,
+                    0x2f57d26567b69abb,
+                    0xf7e6f3e1d3c03f6e,
+                    0x937e73e54ab54533,
+                    0x676984a78281adba,
+                    0xd2f82dde5963f1c9,
+                    0xbdb1875adb291db1,
+                    0x1d389c9770a1494d,
+                    0x76c64cab2a02f112,
+                    0x5f14bb66af52b62a,
+                    0xf7dc7bc199559a75,
+                    0x00c39e24502305b7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xccb7d791a93eba5a,
+                    0x62a7fa490d5ec3e6,
+                    0xc807da5a0fe76917,
+                    0x44ac59f8af481220,
+                    0x905acb19f0c8a9c4,
+                    0x9b0242f023bf1e9a,
+                    0xe157b4c292aa4ace,
+                    0x183c062d641339cc,
+                    0xb382a6b20c442f30,
+                    0x54046f065e20da64,
+                    0xebc201a37a956ff1,
+                    0x010dc1b1b992c635,
+                ])),
+                Felt::new(BigInteger768([
+                    0x05075e332a651932,
+                    0x7bcf70cd5ed6d946,
+                    0x33a0cbd73a50255c,
+                    0x865295c5ebdcf107,
+                    0x1d2b117c1c87969e,
+                    0x4edfebf140d62e32,
+                    0x626aa5ec692ad046,
+                    0xef72c47feb84768e,
+                    0x52904c3153be0c76,
+                    0x8cab7a0eddc185dd,
+                    0x5a644bc3ff48bc28,
+                    0x0006f21e59249dd1,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa9bd967c82f2d9e1,
+                    0xd90b88ea1656d229,
+                    0x64cba68bda090995,
+                    0x20abc175199b9656,
+                    0x702d0d3c136b9572,
+                    0x46c9b76577f730be,
+                    0x0ca7adf1490a016c,
+                    0xd95d895febaef85f,
+                    0xdcaa2a8bc83e844e,
+                    0x3b22d713f0d6d34c,
+                    0xeec09b1c3db4e83a,
+                    0x00a94388b84ea717,
+                ])),
+                Felt::new(BigInteger768([
+                    0x803b9ae8b4962fec,
+                    0x88c4baf37cd9f954,
+                    0x2bcb2a6062870d30,
+                    0x6bf2bd48f7e41fb3,
+                    0xe8fb47a16906568a,
+                    0x2b2cb5066337b737,
+                    0x003116dfc8d7ace7,
+                    0xab3f212490389558,
+                    0xcc753673da6b596a,
+                    0xb897dd47855f2671,
+                    0x3df88589347bfe75,
+                    0x010f9f89f9be00a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0240a3608635caed,
+                    0xb554a933462e09f6,
+                    0x0884b9fd17bb2cbc,
+                    0x2d6cbab29827c0e4,
+                    0x6f440982fee7fff6,
+                    0x55cd8e77396fdfbb,
+                    0xe08f239e26bd6a1a,
+                    0x035840ffdb5a5935,
+                    0xe6bfc88e4ab07717,
+                    0x744b27cb1cf19915,
+                    0xea1731ad7bb2c270,
+                    0x00598b307ad6e729,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9623e1566be36762,
+                    0xb752933e8cde98d4,
+                    0x4997585ffa0d9e71,
+                    0x2c2c6cf469040a51,
+                    0x7eaeedca368f8ec5,
+                    0x3af79c41dfb96df0,
+                    0x0d3cfb94533c6a30,
+                    0xc7cdd75912539adf,
+                    0x899bda70368e3f54,
+                    0xa76f85e06f85ca1a,
+                    0xafdd952d0363e426,
+                    0x000c32d7dbe1fc5e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7bc07cec0970307d,
+                    0xa3f80590150744b2,
+                    0xa8bf31c0633cfba2,
+                    0x2985489e4d61a019,
+                    0xfe26968347ec418b,
+                    0x3a7da352b867938a,
+                    0x17475bceb4975242,
+                    0x59db3f97c41bb4fa,
+                    0x19aadb435ad2207a,
+                    0x215415257d96ec52,
+                    0x055b01c7a86646c3,
+                    0x0065cb397153a89b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1e6ed6d49b7ccc15,
+                    0x5665473cd9619508,
+                    0xc8f968444cc4167f,
+                    0xfe6d11fec125c7fd,
+                    0x811f52b006e48890,
+                    0xb696491b58ab1fe1,
+                    0x88bdbba444f057d2,
+                    0x14db75e568acefe5,
+                    0x331388ba1d724742,
+                    0x108278bc23014059,
+                    0x6732a104696d7d64,
+                    0x00fc4d5334267eae,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x7e1e0c97a8eb7c00,
+                    0x185fc9acdd6983a5,
+                    0x2ec9d37b17f7cea5,
+                    0xb7b6cc434485032c,
+                    0xc73235412f675392,
+                    0xdb5288dbab9a2a3d,
+                    0x6d87e87df22a7967,
+                    0x2729c89054efb072,
+                    0x208d299011d63c1d,
+                    0x9bd254c4ade4deb0,
+                    0x74a2683a613132d3,
+                    0x009ab439893d8bd4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc9836ef4ad2f5dbe,
+                    0x261bf7b7ee687ccd,
+                    0x7789493a25f74a78,
+                    0xc163481d1d101425,
+                    0x23d4fea1a04155a9,
+                    0x66fac7bcc603618b,
+                    0x33553f6cfd4cffef,
+                    0xe3f94f21529415a3,
+                    0x0121e2390ffa6039,
+                    0x39f93482cf087e9e,
+                    0x9e2b894cbf157e32,
+                    0x011a1d462cfb1381,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf14876807fe8a9ba,
+                    0xb23f26e51a3c81bf,
+                    0x3fc8dc392f437336,
+                    0xe0c1c967298164eb,
+                    0xa545dee9992f8338,
+                    0xe09dd41cf896b6f1,
+                    0xf4ac999f75ba226f,
+                    0x50554181b5aa8f7a,
+                    0x7af2a00e0db6b0d9,
+                    0x38827fcce7475c0f,
+                    0x8d51d1e57b08100b,
+                    0x00365806c7b97066,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8ff17f325c16a8bf,
+                    0x46e8238238f8741d,
+                    0x2a5db0548a7c9cfa,
+                    0xf44feeb4adba3d65,
+                    0x190bf99c96ffde63,
+                    0x868fdbb6dbd5de91,
+                    0xf8985d2314881e8c,
+                    0x2ccd6320c303a446,
+                    0x645dca5c3710a887,
+                    0x54db7977c443f7ac,
+                    0x876f812e0c5c3c8e,
+                    0x00489e256de9af8d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x81516ef7ccf892b6,
+                    0x3dccd36944636eca,
+                    0x0bfc5e6c724c923d,
+                    0xc4a8d3479cfa9f5f,
+                    0x6298c3c87d68b2e1,
+                    0x6626f0921241a724,
+                    0xad489595cf134380,
+                    0xf2859f9b00b85fcb,
+                    0x85055201a252d977,
+                    0x4eb4973fbea41394,
+                    0x7120ca27666aeeb7,
+                    0x006f7eae62a26941,
+                ])),
+                Felt::new(BigInteger768([
+                    0x39df6b0fbf9dba8e,
+                    0x65c09a41f8968f7f,
+                    0xece6788f3d9c29c8,
+                    0x8e694abbdcc0b88e,
+                    0x765759351e67d5e3,
+                    0x9a62c5b7eda9da8a,
+                    0x0e6239a384eb7e6e,
+                    0xbd5c6b93c7eebd76,
+                    0x31067c50a1f0d2ae,
+                    0xc5e326056b542787,
+                    0x18493c3dec207a7a,
+                    0x00df22bafd4faea8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7901b580142ebb61,
+                    0x0c9bf95737bc041d,
+                    0x7bb0a8b812bf97f3,
+                    0x4d5b7a4eec6eb06d,
+                    0xdd428ebec7096a86,
+                    0x8bc8349469d0e326,
+                    0xb6db353301ebac86,
+                    0x5cb8456d370b576f,
+                    0x3fd092e81be318f0,
+                    0x18df65f6e7efa101,
+                    0x96e3049672da1d13,
+                    0x00aeb92590231d91,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad1e2e0188c75776,
+                    0x1988ce0ee3c46555,
+                    0xe9dafca21fc1e9f8,
+                    0x53ea8eb26bddb398,
+                    0x4c747b4ae5a4319d,
+                    0x6e4b1fc2e0694bf1,
+                    0x80fdad700578d3bf,
+                    0x7560889a5973caa3,
+                    0xa15e35d80fcfbbcb,
+                    0xcf4d7b86dc13fca5,
+                    0xdc7459c6bb185dd6,
+                    0x005237e8d93eb4d3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xe0bca2e3c6d111ed,
+                    0x4dfc681d14b4b638,
+                    0x6f38526a184a5f95,
+                    0x1df959b0514c5d55,
+                    0x44e6b7b90409d624,
+                    0x4cdda1e7ebe38878,
+                    0x4807d890382cebc6,
+                    0xcddb1f0a9e562eb5,
+                    0x03afb064dfbbf22e,
+                    0x114af0bbcbf9b1f2,
+                    0x480c64aab481909b,
+                    0x00e2d99c2b88a0c8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1be65e1fa68a0103,
+                    0x1641f8141f510482,
+                    0x6d735f99369bd9d8,
+                    0x18d7be585ec9fca8,
+                    0xcd89fae13dfc30b6,
+                    0xb14d51a068e1481e,
+                    0x3627abb0bc1ad112,
+                    0x86af8bcd5c024263,
+                    0x98d4bb872aabe64c,
+                    0xe955e4266002e9b3,
+                    0x5da4318b99e45127,
+                    0x00a50905fb68af83,
+                ])),
+                Felt::new(BigInteger768([
+                    0x582e29ca461982cc,
+                    0x836d0cfb437a44a4,
+                    0x1da89608d3e9397e,
+                    0xf5fffbf3ac2fd829,
+                    0xb372db9b3aeb4aae,
+                    0x2834cf4b3527315c,
+                    0xba27f8dd0a7fb96c,
+                    0xf9601d95b43895c5,
+                    0x727b9bdeed3068d0,
+                    0xe40b224e4166b429,
+                    0x59223939563c03fe,
+                    0x00af609030ed1175,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4183b84dc8965462,
+                    0x378cd5852a680598,
+                    0xb951cf78b0c44b1d,
+                    0xd47818e1341d4ad6,
+                    0x12690e0021752ba6,
+                    0x3b3d1f260eefbf0c,
+                    0x8ccb9461776e779a,
+                    0xdc151bd97b3722ce,
+                    0xbb2ddc16b32e03d9,
+                    0xac3396c9652e0aaf,
+                    0x771d00b437d4eb99,
+                    0x003aaf94235d25d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x779b3827c38db68d,
+                    0x32f78081b5e08e7b,
+                    0x2128eb13d61a7a80,
+                    0xf5882b44be34199f,
+                    0x0564651243b241ec,
+                    0x46bca6b6fd02a7da,
+                    0x40005aa08060e22f,
+                    0x6355e66c6dcf2fbd,
+                    0x25415ff59094f36a,
+                    0x8c350ce2336f74b4,
+                    0x06a159e150025bb8,
+                    0x0097568397a95ae9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f03b084803222ae,
+                    0xd13e13b212d30408,
+                    0x3c0ca7e97efdb343,
+                    0x7223584eba84fac4,
+                    0x9c4b3a0d774217e5,
+                    0xa62343554f7c00d9,
+                    0xb3c07712925fb519,
+                    0xb68829f6b1d66554,
+                    0x79dfb3492fa3b225,
+                    0x65815e45b4cc11b2,
+                    0x93d63929abcbe785,
+                    0x00912c16cf381bf9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x99485a8f4ffbbda2,
+                    0xe4f7550fdfa6c344,
+                    0x05a05359a4897e46,
+                    0x8bc49e277fed3031,
+                    0xf029b02139447a90,
+                    0x1ceaf0167178a0dc,
+                    0x000f053b004b48bc,
+                    0xe72630acd1dffa52,
+                    0x66be9e55b6314f82,
+                    0x4e60153fd5bd2205,
+                    0xca1e03f353494845,
+                    0x00dccea24c712783,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4283e5c49f77bb8c,
+                    0xb891f956efc4f4d4,
+                    0xc379b0bf394d5981,
+                    0xd18eb94206cf4bf5,
+                    0x835c9a1007be7f97,
+                    0xf637378a923623c2,
+                    0x22480102cc111d1c,
+                    0x552d2ea2842d70a1,
+                    0x5a6edecbc63bd712,
+                    0x44ed2a1afeeb3027,
+                    0xd7d8e10515dc5749,
+                    0x007ec959f704cae2,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x60c29578600ad9ea,
+                    0x7bff6fc3480e1c31,
+                    0x97a03d6db47c671d,
+                    0xff32f899e6017a41,
+                    0x699b35935fb379d3,
+                    0x285c7f309ff093ff,
+                    0xf47c6d198e3e47f4,
+                    0xaa7d645906e6ac4a,
+                    0x581d044f74ad1477,
+                    0xc925ae583f7fa50c,
+                    0x3405aec17a770229,
+                    0x009f92a32783fb5b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x565162cd385ab7b8,
+                    0x9ca5dcd61f191fe3,
+                    0xc8d174d0a19841b5,
+                    0x1836c563d02804ff,
+                    0x0aad94e049c5a4a5,
+                    0x09d82fc28913a6ab,
+                    0x68bab5f3dede3274,
+                    0x8445bfbb56ea1c2a,
+                    0x2dcd00c2a164b926,
+                    0x0449de2caa85309e,
+                    0xc4e4baf3f084548b,
+                    0x00584c1e44d6f472,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd0662c21d030ed7a,
+                    0x64f6ae3a3ec0a622,
+                    0xec25cc68cf6c5a68,
+                    0x87e8e21a4183d5f5,
+                    0xf60f264b34ea74f6,
+                    0xde02aab5410d57d3,
+                    0x9c7df648da740cc4,
+                    0x2d027869e56824ca,
+                    0x36c4fe98b9e79c2e,
+                    0x0040aad9a054e553,
+                    0x52c52f877898d70d,
+                    0x007b69d3b7e4d983,
+                ])),
+                Felt::new(BigInteger768([
+                    0x8552b350a688d46f,
+                    0xd20e684e06919f77,
+                    0x54f6d5e874ca13c4,
+                    0x61f2dd7b846787ee,
+                    0x556ac3fdb4255694,
+                    0xab172f86e63da1fc,
+                    0x6a3e2ae2dc028538,
+                    0x3dafa04a4e11b5ef,
+                    0x38fac4c751becfd9,
+                    0x79a2faf638c9218c,
+                    0x514a0e5b49aab82b,
+                    0x00005d1807c89aa4,
+                ])),
+                Felt::new(BigInteger768([
+                    0x6cca6b3aa8154a89,
+                    0x32162cefac188466,
+                    0xd6af398130de8b36,
+                    0x4dfd88a066c4af12,
+                    0x0064a0016f3641cf,
+                    0x97133eeb9faa9259,
+                    0xd6d67d7a937f8a9a,
+                    0x7d97148b47d5180e,
+                    0x98905305e68f44b3,
+                    0x230f24dda4328999,
+                    0xfc0f577ffe7e2f91,
+                    0x007894e537b1ba32,
+                ])),
+                Felt::new(BigInteger768([
+                    0x655497600c02a7ff,
+                    0x581dfbfe7ddc098c,
+                    0x36f9511f70898350,
+                    0x08c35f66cf372031,
+                    0x53b4feb232bba9dc,
+                    0x8d001629f2c4fe52,
+                    0x450f7439eb5bcb18,
+                    0x07ca8ad82279f44d,
+                    0xfb4af1ec3dd9ba9d,
+                    0xdda15c28557e379a,
+                    0x55a32a864b05fa43,
+                    0x00356bc43f2e8f55,
+                ])),
+                Felt::new(BigInteger768([
+                    0x449dd85086fbaa87,
+                    0x60c8bb2de631b07d,
+                    0xbbff4aa24bf9465b,
+                    0x550e832e40827dab,
+                    0xf076d59d904979cd,
+                    0xfeef133741ee4a15,
+                    0x4c6963a46a938f87,
+                    0x2018aa3e9c69afc2,
+                    0xc2d40b46aa87e042,
+                    0x38469e3af84e1e0e,
+                    0x5756525448a0167c,
+                    0x0118ed3631b92080,
+                ])),
+                Felt::new(BigInteger768([
+                    0x576361b6cceaa567,
+                    0xd5c074d6e045a7ca,
+                    0x523c85ea0c62ea52,
+                    0xbd3bff4d28fe0b69,
+                    0x26989e99e2a08856,
+                    0xfd9114f553df4dde,
+                    0x5dd57ce231eeb7c5,
+                    0xfae399b6649382a6,
+                    0xe8d52a7fbd354064,
+                    0x40890d213fdf123a,
+                    0xd97b4dc0da192163,
+                    0x00817f082f7677a6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x3796e6e324aec54b,
+                    0x93a611b22f7176e0,
+                    0x55a826184b13f04d,
+                    0x583482ab08e9b6c6,
+                    0xa044739ff1c2ed03,
+                    0x5d4881bc51cc5407,
+                    0xa9acd482170acfab,
+                    0x99a358dfb2ebae6c,
+                    0xde2bc0d7e084d770,
+                    0xff784a53ab17aca2,
+                    0x1d753636fb67acc5,
+                    0x0029c3e946ca6027,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcf6e30d2228be342,
+                    0x243698dfe40e0433,
+                    0x3ad53c58aae3eeca,
+                    0x954b8227f40c2a88,
+                    0x6741758411ba35bb,
+                    0xa06b624f7ffe214a,
+                    0x7ca698c7518baeed,
+                    0x40d0599949b65a4b,
+                    0x120354a387b1a250,
+                    0xefa52963e663182d,
+                    0x3f8b2a3f5437969b,
+                    0x00e65b35677f542e,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1757367666aef121,
+                    0x3557c70185b284c6,
+                    0x60418e5caeafe5ef,
+                    0xe528ae0ae26a2626,
+                    0xed9af061d51de210,
+                    0xe96f84d428551441,
+                    0x7cbe8eb718673090,
+                    0xe8cbee6bf2b207d8,
+                    0x7e885abc7fa47153,
+                    0x4590c98250142ccf,
+                    0xaa5d50ac01d51748,
+                    0x00763f20f4f96c6b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x01913953a2f292b9,
+                    0x21b9d17e186a5324,
+                    0x5e1b4f7b0de42157,
+                    0x2d2b45d25c29cd17,
+                    0x193b59f37c16cbe9,
+                    0x2511e0ce36acfc00,
+                    0x28f30a9b127d3508,
+                    0x66808de9dcee6bdd,
+                    0x35d3580561f0e3d7,
+                    0x375a9153bd35ac54,
+                    0x15cc4fb55868bffa,
+                    0x0111e6fcfb6072b8,
+                ])),
+                Felt::new(BigInteger768([
+                    0xad328cfac5316b48,
+                    0xfdd96ae69379184f,
+                    0xad06ce46d5001866,
+                    0x10a9a1ae141af5a4,
+                    0x703a3a25fdef9d27,
+                    0x61fb4d0ddda5877b,
+                    0x3315183688844ae2,
+                    0xe8b12d9f24bc34cc,
+                    0x2a627551aac9ca1d,
+                    0xb97ae241f0312a44,
+                    0x9c7f32aea6a0355b,
+                    0x00f0eee654c41bee,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0553a2fdd1e7bf41,
+                    0x9f6792ac9c5ffa69,
+                    0xacfddc8a9048f675,
+                    0x30905b9efb1035ac,
+                    0xf63347005ee7568e,
+                    0x6c63cc87d4dea963,
+                    0x591cc38222012c43,
+                    0xb766e6bd17ddff2e,
+                    0x0404e9406a3e79de,
+                    0xf9790f0392f3e495,
+                    0x2634598b40b6afcd,
+                    0x0021f72f44ac42f0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb61aed38dbde5c3a,
+                    0x4f16b8d9feb7f76d,
+                    0xa9cb8e1635123d21,
+                    0x497efb9d61d2ed09,
+                    0x1f6b35a4fb04e513,
+                    0xffe76c2e455af776,
+                    0xe72443383dfbb43f,
+                    0x8584646c97149c66,
+                    0x9e32b99a0059e8f7,
+                    0x25f29738d1894a7c,
+                    0x3283ec932f7ff9d5,
+                    0x00c7895927280b70,
+                ])),
+                Felt::new(BigInteger768([
+                    0xfbd1600f5a540c88,
+                    0x5286b8d9d8691eed,
+                    0x290dad1c82231581,
+                    0xc7b97335213869d7,
+                    0x896f6f3c7851d82f,
+                    0x308bf40b1d1128b5,
+                    0xeefa996cf9835142,
+                    0x11733d44de3aa5e3,
+                    0x87d520791829cdc4,
+                    0xdc7f4fdfdc81ab0d,
+                    0x905516728147385e,
+                    0x0004bffb9bd571c9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xd7b68536b6a330d6,
+                    0xbc397cea5f29244d,
+                    0x0867d15ddb1cae59,
+                    0xf2450aa3a761124d,
+                    0x1846d4197eccc443,
+                    0x0e2c581d56b9f273,
+                    0x5fbb3b2e2f02ab0e,
+                    0x7c2603febe9d2bd8,
+                    0xd94a4676a0d4d4ba,
+                    0x4f75f1fbf49bbeaf,
+                    0x3ab3fa29380dff70,
+                    0x00359bdc276b4b8a,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa48a7433d943f5d5,
+                    0x9ad3463a03d9cf51,
+                    0x9337b5ad1c1bc9c0,
+                    0x1de25ec0560c60c5,
+                    0xde37dc1ad45b79c2,
+                    0x5870254fe4774ee2,
+                    0xf0bf568ec8314a52,
+                    0xa147ca06efea52d5,
+                    0xd3d3235d2cc02386,
+                    0x8d0e4edae6f24053,
+                    0xdf6656e5ea4e3fb4,
+                    0x0019256b5135e490,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc0f91aae7b3f1763,
+                    0xceb8fc2c8f58c884,
+                    0x566febb1d3d63268,
+                    0xf0f9df8a9dc6b97e,
+                    0x4cf6f16b22ba3d04,
+                    0x7aa7bb9bd1317961,
+                    0x000a2785e2b526a6,
+                    0xc91b03d53a59f7ba,
+                    0xd98b5ecaa1d2ba76,
+                    0x6f26cc6420ef8171,
+                    0xf4758a7fcd5378e4,
+                    0x00a22c6f2abd73ab,
+                ])),
+                Felt::new(BigInteger768([
+                    0x770489cdcc5a7603,
+                    0x579a31a68294343e,
+                    0x4a261be5eeacdd13,
+                    0xda4b6c95449b7ac9,
+                    0xac99ed80790431ce,
+                    0xa696f195ef288089,
+                    0x54789aba2a1a2ead,
+                    0x3938c203201705e1,
+                    0xa7fede7e3e718134,
+                    0x81feebe8c2f4d09e,
+                    0x2c91c66ddb162aaa,
+                    0x008b1ac6a9a171dc,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5688b49fd2118639,
+                    0x0d63004ae4551f42,
+                    0x38112f6bd71c964d,
+                    0x2d154d62ff639062,
+                    0x53f238adf94f4cc4,
+                    0x05c6c617d51d7fa8,
+                    0xe320793537266d3b,
+                    0x4d8930110506ad1e,
+                    0xe13cef866a6cc65a,
+                    0x3521f591d28d41a2,
+                    0x7a405f6c5d9cd5ce,
+                    0x009661654524c16b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x52b84a343ef59e0c,
+                    0x730d83d822f1abfc,
+                    0xcc61278a93929335,
+                    0x63a76a46b03dac1c,
+                    0x4e451ec8b3683aea,
+                    0xaad0d6a550571c3e,
+                    0x727c42b7ec4a9e4c,
+                    0xee755a2e0b72c13e,
+                    0xcf7ffff22b500df6,
+                    0x13cd29973d33a11e,
+                    0x72acd27a33cf19d4,
+                    0x001c33ce7b7e696d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x09427fb3044bee95,
+                    0xcfd30705e24a1c19,
+                    0xba2b350920f45163,
+                    0xed77aa0d299229e9,
+                    0x75a56ae0a177a2c1,
+                    0x3a52a15ebdbf2544,
+                    0x1d74207f9d8b9055,
+                    0xce8ff0c0d5b3ca1a,
+                    0x8564c3d29afda2e1,
+                    0x941095454d30ef1a,
+                    0xc006a6c79fcfa6a3,
+                    0x003e5479d31e51e7,
+                ])),
+                Felt::new(BigInteger768([
+                    0x40028f2ef336e94a,
+                    0xb9aef24c82eeb9a2,
+                    0x1c2abbe4cd85b94c,
+                    0xa6003eff13a0c2c1,
+                    0xed2c454451c866a9,
+                    0xe92611fb802e0752,
+                    0xe6da86952019a4bb,
+                    0xf0973f2bcfcc760d,
+                    0x12ef436d0c91a75b,
+                    0x11705bdf277a7f02,
+                    0xf542f043f03881b7,
+                    0x010bb50afe68fd40,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xadea6d92bc67305e,
+                    0x1397b421fefc7207,
+                    0x0142bead71e81525,
+                    0x3a245247da806349,
+                    0x7479a1d1c2a40209,
+                    0x1a20f918a8d0bf41,
+                    0x4b7d66b755c6d83e,
+                    0x2e821e1315e8364b,
+                    0x07bf462efd471581,
+                    0xeeb4e74a4f0cdbcf,
+                    0x4913b47852d274e7,
+                    0x005e0b5f88089af8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x024e2a79c23727c7,
+                    0x216af9c668957c25,
+                    0xfaa37bda154074d7,
+                    0x00ac23beb4179562,
+                    0x5a24a7adad239744,
+                    0x8a073a9bb8160110,
+                    0xc13b30dc9c2a3522,
+                    0x052e7d9accc3cc6b,
+                    0xd1615374b1305855,
+                    0x3b5c490f667fd38a,
+                    0x8aeb90fc55f7d13b,
+                    0x000a1e585177b46f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbbcf9de4483a0d27,
+                    0x0e8c9a7e34ced576,
+                    0xda83edbbfef884c5,
+                    0x1aa57cb7babe34eb,
+                    0x26d1a4980e23f914,
+                    0x2f79819206ddd0fc,
+                    0x2326a884cee631e3,
+                    0xb9481665aacd2548,
+                    0x17e00ae67529285a,
+                    0x13eab7153f5453b3,
+                    0x90f89d6af6ae3337,
+                    0x00ad7e5d0472619f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf228103164734d63,
+                    0x141fc483e865a2ee,
+                    0x40fea00e76a40019,
+                    0x989efc6af43366a9,
+                    0x1e6f72f6356deb84,
+                    0x5694528cbe0ee1f7,
+                    0xe2899f742150aaa9,
+                    0x586f969a6e23d873,
+                    0x7a301dc536f6b08f,
+                    0x20fa4b6e247f88da,
+                    0xa32c456dcbaf3295,
+                    0x00aa8da407765ac5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x918f09be3843f0f7,
+                    0x1c468e6ac36486e4,
+                    0xaf9b9812d033363a,
+                    0xcbf6541b6b1c52d6,
+                    0x2443c6c7032bd60f,
+                    0x935ee79350a5525b,
+                    0xbd6856e42baaa421,
+                    0x27b58f6716ffc4a8,
+                    0x6a38cdfb83acc627,
+                    0xaf23c4c68ecbcfe4,
+                    0x91f7dd1fe878e1ea,
+                    0x00143f568f5b1565,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5f638ec0d37afef6,
+                    0x3303eae664b6b433,
+                    0x38d12f612ffe40f5,
+                    0xd40663a2b6e02d45,
+                    0x75e7f36b037c47e3,
+                    0xb6f49709835bc3ce,
+                    0x2f3566c681f0adc4,
+                    0x99230ce42a0dfda0,
+                    0xae98f62d69c8f51b,
+                    0x46965fb2248feab2,
+                    0x6122d0e141dbd9db,
+                    0x0078ee9a13576449,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb4302dfa76b5211d,
+                    0x24a71819ec564e92,
+                    0x0d779fcb55622c33,
+                    0x036ecfffa8822c8d,
+                    0x2357b6b4e7385c46,
+                    0x96be5ef507d973e8,
+                    0xfe723eed944cc25f,
+                    0xd799e21924198b75,
+                    0x59023e5fd93c7c52,
+                    0xb612ff88606d292c,
+                    0xad3ce2ef29556b37,
+                    0x00dac416ed456dfe,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd17f7db3130a857e,
+                    0xc8e72a403dfafc76,
+                    0x13c2ca39dcfcffec,
+                    0xe622bf5b7dc5221c,
+                    0x6ad38aa68744a9d7,
+                    0x524da64f2e178201,
+                    0x09eb2e1704d0dc0d,
+                    0xbe168cf551776818,
+                    0x93c02c7b69c6c4dd,
+                    0xf5e67a7c70403c42,
+                    0x7f9493e3e4c71f12,
+                    0x004534b1b2f82e6d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x76b532d3fd0ee459,
+                    0x9757ab982b88daf0,
+                    0x895b7db758f65990,
+                    0x41930558ca1e2c4e,
+                    0x94480db91ea9ae9e,
+                    0x0f7de36d1592d6d4,
+                    0xbb27e26590b5a16c,
+                    0xed7f61ad8e4b4bc0,
+                    0x1ce27bad04df65df,
+                    0xaee0ddf7553f6a71,
+                    0x0f327da05b326541,
+                    0x00aecce3d37b35a6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7ceef192531b4773,
+                    0x528efa7500485a60,
+                    0xc1c8024fa2a28b95,
+                    0x7d37cfbe087802ef,
+                    0x669e8c312adab9ea,
+                    0x5e93075a620a08bc,
+                    0x1a835627b2e448ba,
+                    0xb4f4dd61f1b4fb3c,
+                    0x14d994f77463b87f,
+                    0xb318ad1e35c0580d,
+                    0x3e8d4abd44548976,
+                    0x0101252aeb4671a7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xb2e6df574e79ebac,
+                    0x6d81f018a0b0069c,
+                    0x5b42804c5fa8af7d,
+                    0x467a9e58be975083,
+                    0x50b8ecf31fb15110,
+                    0x5230dd01f71ea687,
+                    0x877f9ca279782bbd,
+                    0x1500e95df89e8fb9,
+                    0x08116ada80c50dce,
+                    0x6dc5a79f338d5489,
+                    0x6685ae99fdba4eb1,
+                    0x00f770222326c4be,
+                ])),
+                Felt::new(BigInteger768([
+                    0x34e7aba7ee12dc2c,
+                    0x657e767ff4c90199,
+                    0x179eb9d9ed560c96,
+                    0xdd15556b335b61ce,
+                    0xf3bc764599583398,
+                    0xca20bd39f95a8786,
+                    0xe1baac1f6cd82eed,
+                    0x0baac2d73e82b6c1,
+                    0xb1fd322cace9b82b,
+                    0xf49f9454baf4a87a,
+                    0x54449be90352b06c,
+                    0x00bcb80a812c62be,
+                ])),
+                Felt::new(BigInteger768([
+                    0x11d27f26ef96f0e5,
+                    0x267eb8c6472f612d,
+                    0xc2ccee70f749df06,
+                    0xdeedf8d6c483f7f0,
+                    0xb5409691bde0992e,
+                    0x8cd5f7ee142d29e1,
+                    0x1733c0bef922cc8d,
+                    0xe961ce44fc6552ff,
+                    0xc41c85876e2f8f71,
+                    0xe76d8918aa978437,
+                    0xf59fa510ffd8e2b5,
+                    0x00c8686ace76742b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3bcb4448a7656b1f,
+                    0x439430752289b4ba,
+                    0xa2c18bb8990e949a,
+                    0x3506bb1b70233e62,
+                    0x061ed941057d3972,
+                    0x16d96d4787bc3a64,
+                    0xf63123610ebc010c,
+                    0x09884c37a6751a6f,
+                    0xf7375af33eb78eb5,
+                    0xfe6a8778466ec10b,
+                    0xe8367232cb8d57ae,
+                    0x009da22b113eff27,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdedde7bba848951e,
+                    0xdb105a67d96a9873,
+                    0x715ef1024f145dbd,
+                    0x8ce262c1f9ed9400,
+                    0xd574e94059579227,
+                    0xd0f1b4f10d3c3023,
+                    0x68102cc9e005a11c,
+                    0xaf7ca58e6a1e99b3,
+                    0xf3b7efc2262f5a10,
+                    0x91f3e38834c6c71f,
+                    0xeeb88ae5eb990657,
+                    0x007e7a639456ddfa,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe4edf3caf2a48a4b,
+                    0x4d6cf46dc2bca759,
+                    0x03dbc2f196d1b7ce,
+                    0x089c545247927b22,
+                    0xcf79cc21399ea084,
+                    0x948abafe05342810,
+                    0x7cacc61463a61741,
+                    0x834ea18a5a8a5254,
+                    0x5c0082c0c4523377,
+                    0x3e43e3f12b1d5c8b,
+                    0x2b44c8ed7e6b1088,
+                    0x00c864e8fb7f1e8f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0xbf0318a4e8f674f7,
+                    0x5b820c57d437afa9,
+                    0xf9faa3c484ab1597,
+                    0x89b610d0fbfd262b,
+                    0xdd9fd977f04cc137,
+                    0x21563ce63eeb97c8,
+                    0xdf007f6a050957ec,
+                    0x550ce914d63a0534,
+                    0x0c90a32325566cba,
+                    0xa98fdb3c842a1ae6,
+                    0x88abe0c8c54690bf,
+                    0x007ccbce6c51366e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc004ca271cc876e5,
+                    0xb4712d689f4392f7,
+                    0x519f8e21795b96d2,
+                    0x6fd4e1ae971d0c74,
+                    0xdbafd618ee6bf9ff,
+                    0xf1e0e900878ae85c,
+                    0x19efb152f2d4d49b,
+                    0xc3c46445b5e81495,
+                    0x03139c3b377ac2b0,
+                    0xf695e2f31c63f9a0,
+                    0x3d81e3156511859b,
+                    0x005a661b2b33ee9f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf96579e5c4774e28,
+                    0x3ca930eae819f194,
+                    0x21cae1456cfd78d4,
+                    0xc1d29d718855309c,
+                    0xa65f9ac40b5a1c09,
+                    0x2d9c92bf6f52256f,
+                    0xd41972f51811637e,
+                    0xa2bb4859dc5ca955,
+                    0x7ea94525f5dab1c7,
+                    0x7176a78321504b37,
+                    0x2f5e54d3bce034e6,
+                    0x0094a252c5b39b7b,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc88fcfd2d97cbb76,
+                    0x05a795f58cc31ce6,
+                    0x5f9488095c72b0e6,
+                    0x7d2ac8cc855332a3,
+                    0x01ed854bcc1c87af,
+                    0x2ca41f1bb4f5e831,
+                    0xfde9c0f15f5245f2,
+                    0x32db5be781175767,
+                    0xcb430a08dc029b21,
+                    0x3160e2707cae8b76,
+                    0x0dad25ea36896279,
+                    0x004cdaba3d57bdf0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa6ab6c805099d812,
+                    0xabe75f25b936e554,
+                    0x7642d691fd910985,
+                    0xc9a65cb4ccfef508,
+                    0x1f5f7de1bda76f2e,
+                    0xdb9acce3cb3ce8dc,
+                    0x0bb751a8e9cfd629,
+                    0x78b54c87c4c203ff,
+                    0x27df64d7b3b5633e,
+                    0xee5bcb606a794ea1,
+                    0x42ef0ba7a672b1f2,
+                    0x008443201cf074ff,
+                ])),
+                Felt::new(BigInteger768([
+                    0x1c99d7ea56550cb5,
+                    0x437be91477ea5b65,
+                    0xbe047b3567e3bee3,
+                    0x2f5b1b813bd8c82a,
+                    0x151712d32fec20b6,
+                    0xe60ca1dd837a1574,
+                    0x5728fdc26248519e,
+                    0x942aa7e5ae9e02f6,
+                    0xb5afdd05fb02e523,
+                    0xa5c79dd6a8fec945,
+                    0x41e472877f33abd2,
+                    0x0014c2220e2f795b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x703fff373115b3a3,
+                    0x791a04fe88964fe8,
+                    0xb74fc149c3de4597,
+                    0x94a6cb2c5405054a,
+                    0x73f970d1a99bbedb,
+                    0xddec7ef43d8daf76,
+                    0xb078e019e0600b5a,
+                    0xdfb750414cfe4a9d,
+                    0xbb98954bddae69f7,
+                    0x500475b8ede6ba6d,
+                    0xfa08c0c9034483b7,
+                    0x01033233404e029a,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7eddaa7c232c91f9,
+                    0x5a80ddf21b3a2572,
+                    0xf108182eb79c7c42,
+                    0xe376b857accc1d47,
+                    0x5fae78e8c1503335,
+                    0xb9d7785f8350bfed,
+                    0x83cf8e282d63ff52,
+                    0x8bf84dfff64306cc,
+                    0xe8d561f5ba4c6b02,
+                    0x81f963d08919a586,
+                    0x2e6838236bed57f9,
+                    0x0036648dc0fc1849,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x26ddfffffffc2d92,
+                    0x059eb4db9ffc6632,
+                    0xc8b180be3e1c0e83,
+                    0xa215cc0bca549db4,
+                    0x8394b23804cdc201,
+                    0xfa1fab386c4d253e,
+                    0x32cfb8009a018224,
+                    0x399ff380666f35eb,
+                    0x35add821447ec75e,
+                    0xb9d8224a34e5846b,
+                    0x1a31635cf137507c,
+                    0x0049ebad9237a7d9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5d24fffffff76643,
+                    0xd95c77b9eff7e5af,
+                    0xb888e5549646ce8a,
+                    0x60607fb95c0a8e5a,
+                    0x6f20270fd0d8f4ec,
+                    0xef825cc86a309504,
+                    0xb06c0081c7a95350,
+                    0x496a10c473b7c4c4,
+                    0x0f85eb5319d96173,
+                    0x459340f1b61bc6ad,
+                    0x122b3af11c3ef579,
+                    0x0014de340b3b52a3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3a4cfffffffa445b,
+                    0x886e0f496ffa994b,
+                    0x2d0a411d5d2a15c4,
+                    0x7320b211af7eec8f,
+                    0xc55f0b540734a302,
+                    0x772f80d4a273b7dd,
+                    0xcc379400e7024337,
+                    0x566fed4099a6d0e0,
+                    0xd084c431e6be2b0d,
+                    0x16c4336f4f5846a0,
+                    0xa74a150b69d2f8bb,
+                    0x006ee1845b537bc5,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3c4ffffffff9ca30,
+                    0xe2c6357efffa2632,
+                    0xcba3af60dfa9c0a2,
+                    0x4d8bb143be636a83,
+                    0xb248d69224c95b0e,
+                    0x3815e876f4bbdbe9,
+                    0x738539c0c3af7c3d,
+                    0x79c2d53edfd5f1e4,
+                    0x4bdb1cb1ef6ff389,
+                    0x5c48bdd3366948d0,
+                    0x7ef233c70938a29a,
+                    0x00c0d9034c7b6443,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x25ee7cc4f68e83ce,
+                    0xf2d8b6854ffe060a,
+                    0x4833ef244bd0c028,
+                    0xea4c9a6c3580f319,
+                    0x3cde210d274c007b,
+                    0x4b05311663cd8080,
+                    0x34875b4e4c5dbd42,
+                    0x2bbb8881a78ee40c,
+                    0x1bfea1786f189b77,
+                    0x577e63a8eda8b795,
+                    0x27c2c8e2c6951fbe,
+                    0x005a348b7e5c24ed,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcdb1aba9838aa1da,
+                    0x6436c83c87e93d48,
+                    0x126c69f4bb0763b1,
+                    0x14680aab2380e3b6,
+                    0x3a717717194dd70f,
+                    0x2d4e1c7299b27597,
+                    0x1bf90a6a91125fdc,
+                    0x4ca35b5ce00fc8ba,
+                    0x97ea9bcededad547,
+                    0x01845bb470f3aef3,
+                    0xe48e04dc4e2f25b0,
+                    0x0055997ca02033a6,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5ed7272a2561afae,
+                    0x8a035e509f127353,
+                    0x2aaa2d0ec54b4c5b,
+                    0x41852e3ca4296fde,
+                    0x71dc207d9b7af50c,
+                    0x147d9a4f5ee92fc1,
+                    0x44a8d229b1d11d02,
+                    0x4faaf6ef9651ad23,
+                    0x01d5ff6885d4076e,
+                    0x777584906d4fcd09,
+                    0xb5b5a5aef2955ca6,
+                    0x00f343b28e1efe87,
+                ])),
+                Felt::new(BigInteger768([
+                    0x302f20a0cde27a6b,
+                    0x290f3b3544de1ca5,
+                    0x31f4382b96e2dd24,
+                    0x49d0c65e796c279a,
+                    0x9720d239468b95a5,
+                    0x4d722ca9761f9cb0,
+                    0x5196564db730f142,
+                    0xe5fa2482e63858b4,
+                    0xbbc88484ebeb8a09,
+                    0xf9b446d3f6d8f765,
+                    0xc7d21828dc4086d5,
+                    0x00047edc67d30317,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe915ba348af58548,
+                    0xefe07773892d7159,
+                    0x649acd212335b094,
+                    0xbbdd9dc356a56a4c,
+                    0xaba0ad4fb795aed5,
+                    0x98eed3dd53bba6c0,
+                    0x8468a7e41f22725f,
+                    0x971b4d33ba74949a,
+                    0xfc88370d82db6c8e,
+                    0x690d392cff46af4e,
+                    0xc09c4704c228e2a6,
+                    0x00e8baadb49b1c5e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd9eacad091aff25,
+                    0x05c1609469e5d1e5,
+                    0xa58df76961096476,
+                    0x81626ce533180fac,
+                    0x669a858eed4c6bad,
+                    0x5ed63517c5d29110,
+                    0x08227dd550d92ac0,
+                    0x500384b3b799be1f,
+                    0x4cdf048a2a3c9464,
+                    0xb6bd29fc8c0bfeaa,
+                    0x7d8b239539c7c8c3,
+                    0x01107a9bf44acbe9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x89de62291123cbd8,
+                    0xe83661e4d4c61a12,
+                    0x1ddecd5b0f65d954,
+                    0x8ab59af495c49a45,
+                    0x8d48d8b284cfe152,
+                    0xcb4cc1ae93d03527,
+                    0x1163f2d061f1effc,
+                    0x704fc65dd683e47b,
+                    0xbbf354c00f3b0473,
+                    0xaa71d2c858c05e7c,
+                    0x9860902b0649f79e,
+                    0x005330c3b5139155,
+                ])),
+                Felt::new(BigInteger768([
+                    0xcbee04d05907733c,
+                    0xe0105782ad61684a,
+                    0xdf1454190c47e27a,
+                    0xfb9ba746d9a65a30,
+                    0xdec67a5b51123a6b,
+                    0xb4430ac423ded138,
+                    0x72c4a41ded64492d,
+                    0x1305d941cf82397d,
+                    0x19cf22aab896ac3c,
+                    0x8c7a0991b99e10a3,
+                    0xa2073c79d90235fb,
+                    0x00954918db90cc1c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x10a158ed9f95740e,
+                    0x2d96d546e7d530c1,
+                    0x14a0bb672e349621,
+                    0x41997f11b22fd002,
+                    0xaa65458885beee41,
+                    0xbadc874e30e8e498,
+                    0x57fa2a80a4f1f88f,
+                    0xc8431f3ab3b7173d,
+                    0xe060d698b81db170,
+                    0x2a800216efbe46c7,
+                    0x44c3077793a322e5,
+                    0x003e990c746914c3,
+                ])),
+                Felt::new(BigInteger768([
+                    0x860d827ec4cc13bf,
+                    0x884a9a0651451d1d,
+                    0xbc5e8b6e66d4a40d,
+                    0xe2fd847e998b0b89,
+                    0x4f1708062210a132,
+                    0xe562a52200c85b6e,
+                    0xabb3beea4c5b71e8,
+                    0xd105e979d1fd063f,
+                    0xe1e1b63a9309c604,
+                    0x8c94483c20fc1fd1,
+                    0x5e57b1266fae48c9,
+                    0x004979a2814a2216,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7c7141466c71483e,
+                    0x12906f517cef6770,
+                    0x383b32782e97ed04,
+                    0x8ee40a3fe4511cd6,
+                    0xac37aba19fced5b0,
+                    0x283a01dfa1a9ec27,
+                    0x7a0d01b30ffff7f2,
+                    0xe3367cb81ae7f7ba,
+                    0xf1aff51d43228e54,
+                    0x5131b9dcc2b89dd9,
+                    0x76950a252baa6946,
+                    0x011affb4bb2ac1a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x4265c1abd0fd5640,
+                    0x0b5640afd73e7884,
+                    0x518fe5b767368900,
+                    0x7fe18a43050e64ce,
+                    0x7d15f62ead0c021c,
+                    0x47b8d56cd23cd28b,
+                    0xc227deb1177fc1bc,
+                    0x92381f6bd95ad93f,
+                    0xff0af7c668e44e05,
+                    0x14a3319a4a75a922,
+                    0x9ff8bc597c596be7,
+                    0x000805d7d9d18605,
+                ])),
+                Felt::new(BigInteger768([
+                    0x9bd544bca48dc1ee,
+                    0x79b84982204c4da4,
+                    0x146293871cf41d02,
+                    0xf3f033f687f8c0d9,
+                    0xf231bf1ee0b4f2e9,
+                    0xfd8b387d616ef776,
+                    0x473cbf746ee7183e,
+                    0xeac9f720327ace78,
+                    0x4b84dfd56f9c29c4,
+                    0x6bd2efb21b819b15,
+                    0xa2d155c710147b06,
+                    0x00fb388fa8cc1690,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0a78fc6057c86008,
+                    0x106fed9954688877,
+                    0xdaa00fefd775e166,
+                    0x60af201f4c2526e8,
+                    0xd3db2f6f1cfe598a,
+                    0xf839d133073ba55a,
+                    0x7d300eb865693c9b,
+                    0x3aa85fa310295803,
+                    0x9c9bfb254545c71e,
+                    0x35475ef86cf7f17a,
+                    0xfb25e860bcf95428,
+                    0x00e0be1e9cd7c110,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2a1247da6100b995,
+                    0x8535ef9dcf342ca9,
+                    0x1d06e7097fdcf7fa,
+                    0xe6ce4365210f88c6,
+                    0xf747ecf59635a241,
+                    0x66f9d16bc0064ebd,
+                    0x763455fe567f04e0,
+                    0x8cc9b3ac8ca12520,
+                    0xf34ebe2a4042002f,
+                    0xe8e01582b5f38576,
+                    0xb10cd8b81d1b82c4,
+                    0x00cf06175fdff8e0,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe20df224c54b628b,
+                    0xa0da903c99f54818,
+                    0x2cd54d02bbd8afef,
+                    0x2ed53fd652a30102,
+                    0x381276fa36b909c8,
+                    0xa47f9c303a99c7f5,
+                    0xef67b4224648bb1a,
+                    0xfbdec868048d7f23,
+                    0xc2cf390d6dff7eca,
+                    0x38b2d52d4f394fe3,
+                    0x7c29b8f2883105ec,
+                    0x00567017e2ad651f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x4c172acad1a22c0b,
+                    0x0c372e6dae908ac3,
+                    0xd7211036de94e224,
+                    0xb134919fc17b4b3c,
+                    0xc2567e884b8410d4,
+                    0x4ce690965edfd7d0,
+                    0xd3614d5c55cceea4,
+                    0x83ada15278344d5e,
+                    0xb2d33f40e68e3a21,
+                    0x66a01e516d9896bb,
+                    0x77e60e363dd6072e,
+                    0x0095ca3fccc32837,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0909cf2261a617d7,
+                    0x33314dc19eaec959,
+                    0x9cbafaaea349276e,
+                    0x97a3aecf2225d366,
+                    0xa300cd7c76ff9fcc,
+                    0x5b8b3e7476309e1f,
+                    0xb6d8c94a2b501867,
+                    0xff4962976ddb177c,
+                    0x942c15332add2633,
+                    0xc5de27b4e49cb37b,
+                    0xcbc8836cf48fbfc4,
+                    0x005cd26a6bbd17a8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x39339dc21feb1277,
+                    0x7e06e27853213235,
+                    0x61397182bbecbd64,
+                    0xf69686935e17fb3c,
+                    0xd728ff287ea3ccfd,
+                    0x75412b4b528207a4,
+                    0x33f20daaccb71f23,
+                    0x99098f4aa42e48cc,
+                    0x8e763fc611e3e453,
+                    0x3bc24a3a52921bc3,
+                    0x6dfcc645d915f7bb,
+                    0x0015c972179b1f8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc700a76dbdcaeed9,
+                    0x2b59a7f4872aa5bd,
+                    0x42794183d0c20b34,
+                    0x132dba3aaa8a0333,
+                    0xea10f894f6ecbf81,
+                    0x3a2cb419b170dd75,
+                    0xc5005091489ae50e,
+                    0x874659280fd56783,
+                    0x628a7b773fa1ed96,
+                    0xe881737d65d81ed9,
+                    0x0724ad8258a9b0e3,
+                    0x009b7f706c232c3f,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbdbf50ead66a4730,
+                    0xbcf6c2df4857b087,
+                    0x6133b33a7ee239ec,
+                    0x08563ceb4fc5d538,
+                    0xdbdbdfb9d23a8c80,
+                    0xa4051020fb3da150,
+                    0x536272ce02510a2c,
+                    0x84f3183f85a5aa9b,
+                    0x507f4f8268ae9744,
+                    0x31b1430993621c1f,
+                    0x1d300a719ff7bf0d,
+                    0x00b2a5ad8969725d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x42d85baf099a66dc,
+                    0xcd2b5d04c7f91e25,
+                    0x61033062438fd30c,
+                    0xc66f460595622753,
+                    0x492b1c923ee075c0,
+                    0x33c2b8bbd91ef5e3,
+                    0x8434a2acce8ff66d,
+                    0xd3472f8771e625ab,
+                    0xbbc9d5130550ab33,
+                    0xf5885a89ca6cf694,
+                    0xd32f5fc00ff7069a,
+                    0x0005772d5507adb4,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd5b0d4ab7671acd8,
+                    0x39a6636bf4dd3635,
+                    0x9c68d26a642521f1,
+                    0xa7d9343075d3d119,
+                    0xa3169a5e3571c61b,
+                    0xfb6ae98bfef193a5,
+                    0xc4914e157bc93de6,
+                    0x7bd5086fb2ebef81,
+                    0x12b1bf44e3778309,
+                    0x24daeec74340b137,
+                    0xba3a97d048990744,
+                    0x00d041ed15bec4b7,
+                ])),
+                Felt::new(BigInteger768([
+                    0xd7a099592efee620,
+                    0x69899a7dc7245f71,
+                    0x9050a31ed41bf534,
+                    0x3f089c33ac6168af,
+                    0x45c657de015d956e,
+                    0xed88fd53d98da66e,
+                    0x5294f773276fb1a6,
+                    0xdc0b37f567748552,
+                    0x890350a2df2f010e,
+                    0xfd689de9f8e737ac,
+                    0x2b4f93219fd7168b,
+                    0x00db2f7e7043dc68,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x278a492b5c675bb1,
+                    0xf00ee2a89325d63c,
+                    0xfc185c72c70134f6,
+                    0x716f8244a7db08ab,
+                    0x29b0203e695a92a9,
+                    0xb73ebf4f34f82d38,
+                    0x92f21340bf4aeab5,
+                    0x8085a471e30bc4d4,
+                    0xfafea605680853d1,
+                    0x984796aa46918279,
+                    0xaf109a9ecf7eb5b4,
+                    0x00e6ae209d3994fb,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf807dfc94dd65744,
+                    0x3fbddac9f7db5cf9,
+                    0x3516f735139cc71c,
+                    0xa3defb47915cb7b7,
+                    0x5037aac5976f1d78,
+                    0xbcc6aff04cceeecd,
+                    0x74a474e13125b8a4,
+                    0x1c3fb8ccea34d739,
+                    0xc2c6ff688b5cde57,
+                    0xa8bc5f6d0cc564a8,
+                    0x5f6cb3b10fad5619,
+                    0x0040b1fd5680bcf8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x75532eaa700d5443,
+                    0x6b42e4723c47756d,
+                    0x4af1cdf8beb4c059,
+                    0x05e105cbce8150af,
+                    0x6702a7e311c8234b,
+                    0x3039ed428bb77f77,
+                    0xf239b433897ffe54,
+                    0x3fe65641cf59fe34,
+                    0xf36b68f65253b69d,
+                    0xf3ac1186fd91d810,
+                    0x55e327c6edf3e5c6,
+                    0x00e515b98e4b456f,
+                ])),
+                Felt::new(BigInteger768([
+                    0x90e98975be175848,
+                    0xa111862c6aaf547e,
+                    0x08c9803276150098,
+                    0x4e6d3d380c6f0ebd,
+                    0x01a27e7a967fb26b,
+                    0x490fe76a8ee094b2,
+                    0x874f6bde79156a17,
+                    0x5f5f25f64b7dd084,
+                    0x826b0b32fade638a,
+                    0x9b1811f6d287dcf7,
+                    0x572bd875e2cc7612,
+                    0x001bbeea60a8f27b,
+                ])),
+                Felt::new(BigInteger768([
+                    0x643a2792ce374d96,
+                    0xb7a0e950dc78a519,
+                    0xa5cde94fc9b34534,
+                    0x78a1b6487ef2a1e9,
+                    0xa8873ecf72425ef9,
+                    0xb2eb74e65f73cf8e,
+                    0xadc6fc882eb18c33,
+                    0x9a15f93db6ba3e38,
+                    0xbe0a446c4ece4559,
+                    0x87719391be7baaec,
+                    0x175afff6496b1fc1,
+                    0x00536a806d42cbcb,
+                ])),
+                Felt::new(BigInteger768([
+                    0x52adf8b2689e0406,
+                    0x4217ce3097c83c84,
+                    0x430a068bfba89e69,
+                    0xe682b1d402952620,
+                    0x8471ccb8f3787ac3,
+                    0x8e479036d532f983,
+                    0x69a7346b626a1900,
+                    0x5f95687ca193eb29,
+                    0x5d3ca0c40a8cb7f2,
+                    0x9c27186762d17858,
+                    0x31d721ab1544b6d2,
+                    0x0044d4b4e2137916,
+                ])),
+                Felt::new(BigInteger768([
+                    0x0d6a8ddae496d5f8,
+                    0xfb90a9779c5c99ae,
+                    0xcd77f3b9cc652224,
+                    0xacb5186b8c9d3510,
+                    0x034d642cf7fec0c5,
+                    0xa2854861edfa641b,
+                    0xe5b922982441d9ec,
+                    0xc5c0b803537c4ced,
+                    0x544b1af57e3c25c5,
+                    0x5838cba5a7819ac2,
+                    0x57540c45320fdce5,
+                    0x002234c079e9b188,
+                ])),
+                Felt::new(BigInteger768([
+                    0x33b6aac79adb7d9d,
+                    0xb61b00eb61f73084,
+                    0xb5e12e5dcec60d4f,
+                    0xc2404423fc045674,
+                    0x1b90b21d62ff7eff,
+                    0xd49cb69ca08f9636,
+                    0xbee128d63b6eca94,
+                    0xc24e1a2d9eff42db,
+                    0x92748fa3577671da,
+                    0xbd9be5f8539f157e,
+                    0x2302b236fe508d54,
+                    0x00c93eef06cfe9ab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x01711d896f7a4720,
+                    0xcd9df1b5b753f76c,
+                    0x302824d959379b28,
+                    0x6c93d38cc0fda3f0,
+                    0xa5e1819359c4ea98,
+                    0x3c9f7865aac75bc3,
+                    0xc64e146593ca5ed6,
+                    0xaacf6ce8708f53d5,
+                    0xe07db104ba39a5c8,
+                    0x3610cabac89cc56d,
+                    0x57403b5e0c68046a,
+                    0x00491bdff8de0554,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7db06d0b78ab46e7,
+                    0xdb56829aa9d29f32,
+                    0x195686000718a253,
+                    0x5a47521b349408d0,
+                    0xa579d40ef69815c7,
+                    0x89a0cef06ff6489f,
+                    0x5958421416abe0b0,
+                    0xb6cbd06c5e147d1c,
+                    0x5229aac629cf036c,
+                    0x979725af414e6d27,
+                    0xe91c960bcfd235d4,
+                    0x0092314afb3445e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7b4673e8595b2b08,
+                    0x110df889ae4fdc94,
+                    0x1e44e87ebe894819,
+                    0x13dd3ce2efc4eb26,
+                    0x88a21889726b9489,
+                    0x3488786529d1cedc,
+                    0x64e6ab3ca0c748fc,
+                    0x3df4fc5a8800872a,
+                    0x50e594766850bdc2,
+                    0x0a59cd9f81e023af,
+                    0x0e1459cb5df620e4,
+                    0x00d61f54c59419d8,
+                ])),
+                Felt::new(BigInteger768([
+                    0x3fca11fdfde17943,
+                    0x5335f916b3bf621e,
+                    0x9be6f4b47ced8563,
+                    0xfd5b8ede964e6952,
+                    0x39d4b0182a61f1b6,
+                    0x4f44b0675a6da9bb,
+                    0x47f300a216b9f903,
+                    0xa9efbb33fe83dd46,
+                    0xfe6ba2abd8125fee,
+                    0xc3312bfb141d1f4b,
+                    0xd26d0c423fd7948b,
+                    0x00fcff9905024c8c,
+                ])),
+                Felt::new(BigInteger768([
+                    0xf371e15f64b61ead,
+                    0x2257a07302a18d17,
+                    0x2bfbdec8f6c04a9d,
+                    0x483e22aa1c7a3ca9,
+                    0xc87f0af630d26694,
+                    0xb248ce3e964b7872,
+                    0x46e7cb68d18e1fee,
+                    0x2f70839dfc9c8dcd,
+                    0xc88f630c142a29b4,
+                    0x1b10aa7a88e7bdd0,
+                    0xb009c9e176f45437,
+                    0x00af0b69741f8663,
+                ])),
+                Felt::new(BigInteger768([
+                    0xa224d6ac1529791c,
+                    0xd7c6e957eec93c71,
+                    0x30520f24aa349d36,
+                    0x9f99328ae95d20f6,
+                    0x9f48eb2609093a5a,
+                    0x7d7c222225146c5d,
+                    0x0d1b62400aa8b138,
+                    0xf70d7ff41cf47ef3,
+                    0x42a492e64e915049,
+                    0x69d3d8381a849855,
+                    0x57cbc1cbfc7b2b7c,
+                    0x00538e60e6acc49e,
+                ])),
+                Felt::new(BigInteger768([
+                    0xc95c215100215aac,
+                    0xd413d3c1e81bfc87,
+                    0x1876ad169b9744f1,
+                    0x19442dbe36f3b6b0,
+                    0xb25da2b1370b9f60,
+                    0xb2fe761c23c15e72,
+                    0x2474e70227dc3372,
+                    0x80a1ee1c30eba549,
+                    0x65047a8f9f4e0f5b,
+                    0xdf2198be0cd262b0,
+                    0x7eb0f5033cad354b,
+                    0x00c09f8618231b5d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x5cbfb88ef3a90e6b,
+                    0xd26bb3b9f1b98718,
+                    0xd4e2dca2fd7e21ab,
+                    0xe11b12f4b1e8de77,
+                    0xdf50aaf55098996a,
+                    0x7b17afd69b7b7eb2,
+                    0x0f31fb803717c7c0,
+                    0x396942c4699a8dc8,
+                    0x6b031c2fca904fed,
+                    0xf7c8c054a4767ec7,
+                    0xdef00d63d628f53d,
+                    0x0046f4829b364ab4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger768([
+                    0x99c20a8b7b37ebcc,
+                    0x3054a581bbbc8482,
+                    0xc57e2f68adbce514,
+                    0x25ed54d42fd5c017,
+                    0xdd8ff42982a5f84b,
+                    0x9e66c9c3f82870ff,
+                    0xc938853180e6e297,
+                    0x6a9011f6e7d0723d,
+                    0x049f2a536e71d21b,
+                    0x60b4672811b12812,
+                    0x1838ed04d15851d5,
+                    0x010927effcfc190d,
+                ])),
+                Felt::new(BigInteger768([
+                    0x977a9c4ddd622dbe,
+                    0x13894bece49192a4,
+                    0x90a15bc07e63e4f9,
+                    0xe95cc920a7d47c9e,
+                    0xee33de11becfe04d,
+                    0x716e07d68dd7d931,
+                    0x72a4e13507b91172,
+                    0x2e62bd3355c5cac5,
+                    0xc9f473f10ca8ea82,
+                    0x62268ee18372391e,
+                    0x7d08da319cf0f43f,
+                    0x007e4f9a53372556,
+                ])),
+                Felt::new(BigInteger768([
+                    0x7cc0748e67e92dce,
+                    0xf327e02b9c5050c3,
+                    0x16bd5e3dceadac4e,
+                    0xbcfa66cc0b18ef27,
+                    0xc9f399791ae2a339,
+                    0xe63e63f95c25e857,
+                    0xa25c8cc07eb05b62,
+                    0xdca3f896fa177251,
+                    0xd4f988fbfb0847d1,
+                    0xa197e5e9124f2592,
+                    0x2afdb10af5f0b8c5,
+                    0x004c5993edc03157,
+                ])),
+                Felt::new(BigInteger768([
+                    0x77279ff660327de4,
+                    0x88c032274f7625a6,
+                    0x902d29e0305ba65e,
+                    0x64ebd51b2e8e060b,
+                    0x6e6330de245bf80b,
+                    0xe465a2d314175e5b,
+                    0xb165319e848d3b90,
+                    0xee16bc21b6222b07,
+                    0x4a25c73a03074f8f,
+                    0x60149522cbd94f84,
+                    0x0f32082b529be288,
+                    0x00be963372568503,
+                ])),
+                Felt::new(BigInteger768([
+                    0xe5f80388d122f988,
+                    0x2155789dfa8f5ea0,
+                    0x5a55b9d872170a5a,
+                    0xe5054c15d1df58cb,
+                    0xa7ac036b5afea557,
+                    0x908f858c8d567f28,
+                    0x786671b59aff9296,
+                    0x7abd34d5758f5402,
+                    0xbfd952c66df2c687,
+                    0x91e6fbb95293c6bd,
+                    0xcc8e177899d54dd6,
+                    0x000807839ce36391,
+                ])),
+                Felt::new(BigInteger768([
+                    0x2cf178ea7c5d5293,
+                    0x1b09449882f033ee,
+                    0xb5ef287ef9ea1fbe,
+                    0x0ef0738b3ba319ac,
+                    0x39373176122cc815,
+                    0x0efba358698d5228,
+                    0x2706a270b6fb9f42,
+                    0x0a8cb7fe16d9ab24,
+                    0x2a1c627acb544f92,
+                    0xdbbf9aafd884c921,
+                    0x316be7846c974d4b,
+                    0x008771ddb21a1690,
+                ])),
+                Felt::new(BigInteger768([
+                    0xdf364c8332a518d8,
+                    0xcfb87ba518e18017,
+                    0xbae0b276c8a475a6,
+                    0x554bf760a339e55e,
+                    0xa8beddd31cca0320,
+                    0xbbdb75b08a449a59,
+                    0xb273539838f046a0,
+                    0x9335d516b761bb9c,
+                    0x89090c16b58b19e4,
+                    0x867e9a1b9b053f8e,
+                    0xcf433a1c503f2fc0,
+                    0x0074768a683060e9,
+                ])),
+                Felt::new(BigInteger768([
+                    0xbd1d1e4c30925134,
+                    0x79463718d4c7998a,
+                    0x8f27180af14ad465,
+                    0x056d48a111ca96ce,
+                    0xf572e0dde3ddd54d,
+                    0xe7090239b4aef6b8,
+                    0x8bc40918beb7dcaa,
+                    0xfa3f2539668020f7,
+                    0x2544b062d1fb4b79,
+                    0xf98ebd63e7358fb5,
+                    0xe447f7413b42605f,
+                    0x007663045fc73467,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 112,
+    expanded: "ec77c9ffed6d1cbca4e3a235d4a460964f4750245416d81960abb1a1f097340a",
+    element: "3804809049153254237742207290308689515234817196958438932643245442270022171956216535569622818069150771882266027512270772529714661505971295496156132011286739638302837986463372174177161850432287260250255749659599113642121957941488917",
+}
@@ -0,0 +1,44 @@
+pub use ark_bw6_761::Fq as Felt;
+pub use ark_ff::BigInteger768;
+use ark_ff::Field;
+
+mod sbox;
+
+mod hash_to_field;
+pub use hash_to_field::{expand_message_anemoi, hash_to_field, HASH_TO_FIELD_BYTES};
+
+/// An instantiation of Anemoi with state width 2 and
+/// rate 1 aimed at providing 128 bits security.
+pub mod anemoi_2_1;
+
+/// An instantiation of Anemoi with state width 4 and
+/// rate 3 aimed at providing 128 bits security.
+pub mod anemoi_4_3;
+
+/// An instantiation of Anemoi with state width 6 and
+/// rate 5 aimed at providing 128 bits security.
+pub mod anemoi_6_5;
+
+/// An instantiation of Anemoi with state width 8 and
+/// rate 7 aimed at providing 128 bits security.
+pub mod anemoi_8_7;
+
+/// An instantiation of Anemoi with state width 10 and
+/// rate 9 aimed at providing 128 bits security.
+pub mod anemoi_10_9;
+
+/// An instantiation of Anemoi with state width 12 and
+/// rate 11 aimed at providing 128 bits security.
+pub mod anemoi_12_11;
+
+/// Instantiations of Anemoi aimed at providing 256 bits security.
+#[cfg(feature = "anemoi_256")]
+pub mod anemoi_256;
+
+// HELPER FUNCTION
+// ================================================================================================
+
+#[inline(always)]
+fn mul_by_generator(x: &Felt) -> Felt {
+    x.double()
+}
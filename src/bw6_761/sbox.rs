@@ -0,0 +1,958 @@
+use super::BigInteger768;
+use super::Felt;
+
+use crate::lanes::ChainElement;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 12] = [
+    0xf92b000000000053,
+    0x23f0bf0b766666b4,
+    0x406e2ecf59c395bb,
+    0x2860a741670afefb,
+    0x111e18b778c0cc4f,
+    0xea52abc171c931bd,
+    0x0248d699169f4835,
+    0xa9e3ca22234fbf0f,
+    0xfe4e46c2e6b7d88d,
+    0x08b0750cb44a5d84,
+    0x175178c002fcff8c,
+    0x00ae8b4963b57ba0,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 2;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger768([
+    0xfb4fffffffffc330,
+    0x2074b24effffc6b4,
+    0x5a53337936b8278b,
+    0x39860afa32a61376,
+    0x2f634f8d48c05b9d,
+    0x23b81847b2a110ce,
+    0x558e305f8130ae05,
+    0xc9e7471b95da050e,
+    0xe6ec6737c49cc35e,
+    0xff5551673471245b,
+    0x5497f3fdd230548e,
+    0x00ba6fd1f655db44,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+/// Computes `x^INV_ALPHA` with a fixed addition chain.
+///
+/// This function is **not** constant-time, as the underlying
+/// field arithmetic is not.
+#[inline(always)]
+pub(crate) fn exp_inv_alpha<T: ChainElement>(x: &T) -> T {
+    let t1 = x.square(); //        1: 2
+    let t2 = t1 * x; //            2: 3
+    let t3 = t2 * t1; //           3: 5
+    let t4 = t3 * x; //            4: 6
+    let t5 = t4 * x; //            5: 7
+    let t6 = t4 * t2; //           6: 9
+    let t7 = t4 * t3; //           7: 11
+    let t8 = t5 * t4; //           8: 13
+    let t9 = t6 * t4; //           9: 15
+    let t10 = t7 * t4; //         10: 17
+    let t11 = t8 * t4; //         11: 19
+    let t12 = t9 * t4; //         12: 21
+    let t13 = t10 * t4; //        13: 23
+    let t14 = t11 * t4; //        14: 25
+    let t15 = t12 * t4; //        15: 27
+    let t16 = t13 * t4; //        16: 29
+    let t17 = t14 * t4; //        17: 31
+    let t18 = t15 * t4; //        18: 33
+    let t19 = t16 * t4; //        19: 35
+    let t20 = t17 * t4; //        20: 37
+    let t21 = t18 * t4; //        21: 39
+    let t22 = t19 * t4; //        22: 41
+    let t23 = t20 * t4; //        23: 43
+    let t24 = t21 * t4; //        24: 45
+    let t25 = t22 * t4; //        25: 47
+    let t26 = t23 * t4; //        26: 49
+    let t27 = t24 * t4; //        27: 51
+    let t28 = t25 * t4; //        28: 53
+    let t29 = t26 * t4; //        29: 55
+    let t30 = t27 * t4; //        30: 57
+    let t31 = t30 * t4; //        31: 63
+    let mut t0 = t23.square(); // 32: 86
+    t0 = t0.square(); //          33: 172
+    t0 = t0.square(); //          34: 344
+    t0 *= t3; //                  35: 349
+    t0 = t0.square(); //          36: 698
+    t0 = t0.square(); //          37: 1396
+    t0 = t0.square(); //          38: 2792
+    t0 = t0.square(); //          39: 5584
+    t0 = t0.square(); //          40: 11168
+    t0 = t0.square(); //          41: 22336
+    t0 = t0.square(); //          42: 44672
+    t0 = t0.square(); //          43: 89344
+    t0 = t0.square(); //          44: 178688
+    t0 *= t24; //                 45: 178733
+    t0 = t0.square(); //          46: 357466
+    t0 = t0.square(); //          47: 714932
+    t0 = t0.square(); //          48: 1429864
+    t0 = t0.square(); //          49: 2859728
+    t0 = t0.square(); //          50: 5719456
+    t0 = t0.square(); //          51: 11438912
+    t0 = t0.square(); //          52: 22877824
+    t0 = t0.square(); //          53: 45755648
+    t0 *= t20; //                 54: 45755685
+    t0 = t0.square(); //          55: 91511370
+    t0 = t0.square(); //          56: 183022740
+    t0 = t0.square(); //          57: 366045480
+    t0 = t0.square(); //          58: 732090960
+    t0 = t0.square(); //          59: 1464181920
+    t0 = t0.square(); //          60: 2928363840
+    t0 *= t19; //                 61: 2928363875
+    t0 = t0.square(); //          62: 5856727750
+    t0 = t0.square(); //          63: 11713455500
+    t0 = t0.square(); //          64: 23426911000
+    t0 = t0.square(); //          65: 46853822000
+    t0 = t0.square(); //          66: 93707644000
+    t0 = t0.square(); //          67: 187415288000
+    t0 *= t24; //                 68: 187415288045
+    t0 = t0.square(); //          69: 374830576090
+    t0 = t0.square(); //          70: 749661152180
+    t0 = t0.square(); //          71: 1499322304360
+    t0 = t0.square(); //          72: 2998644608720
+    t0 = t0.square(); //          73: 5997289217440
+    t0 = t0.square(); //          74: 11994578434880
+    t0 = t0.square(); //          75: 23989156869760
+    t0 *= t25; //                 76: 23989156869807
+    t0 = t0.square(); //          77: 47978313739614
+    t0 = t0.square(); //          78: 95956627479228
+    t0 = t0.square(); //          79: 191913254958456
+    t0 = t0.square(); //          80: 383826509916912
+    t0 = t0.square(); //          81: 767653019833824
+    t0 = t0.square(); //          82: 1535306039667648
+    t0 *= t16; //                 83: 1535306039667677
+    t0 = t0.square(); //          84: 3070612079335354
+    t0 = t0.square(); //          85: 6141224158670708
+    t0 = t0.square(); //          86: 12282448317341416
+    t0 = t0.square(); //          87: 24564896634682832
+    t0 = t0.square(); //          88: 49129793269365664
+    t0 = t0.square(); //          89: 98259586538731328
+    t0 = t0.square(); //          90: 196519173077462656
+    t0 = t0.square(); //          91: 393038346154925312
+    t0 = t0.square(); //          92: 786076692309850624
+    t0 = t0.square(); //          93: 1572153384619701248
+    t0 = t0.square(); //          94: 3144306769239402496
+    t0 = t0.square(); //          95: 6288613538478804992
+    t0 = t0.square(); //          96: 12577227076957609984
+    t0 *= t13; //                 97: 12577227076957610007
+    t0 = t0.square(); //          98: 25154454153915220014
+    t0 = t0.square(); //          99: 50308908307830440028
+    t0 = t0.square(); //         100: 100617816615660880056
+    t0 = t0.square(); //         101: 201235633231321760112
+    t0 *= t3; //                 102: 201235633231321760117
+    t0 = t0.square(); //         103: 402471266462643520234
+    t0 = t0.square(); //         104: 804942532925287040468
+    t0 = t0.square(); //         105: 1609885065850574080936
+    t0 = t0.square(); //         106: 3219770131701148161872
+    t0 = t0.square(); //         107: 6439540263402296323744
+    t0 = t0.square(); //         108: 12879080526804592647488
+    t0 = t0.square(); //         109: 25758161053609185294976
+    t0 = t0.square(); //         110: 51516322107218370589952
+    t0 = t0.square(); //         111: 103032644214436741179904
+    t0 *= t25; //                112: 103032644214436741179951
+    t0 = t0.square(); //         113: 206065288428873482359902
+    t0 = t0.square(); //         114: 412130576857746964719804
+    t0 = t0.square(); //         115: 824261153715493929439608
+    t0 = t0.square(); //         116: 1648522307430987858879216
+    t0 = t0.square(); //         117: 3297044614861975717758432
+    t0 *= t2; //                 118: 3297044614861975717758435
+    t0 = t0.square(); //         119: 6594089229723951435516870
+    t0 = t0.square(); //         120: 13188178459447902871033740
+    t0 = t0.square(); //         121: 26376356918895805742067480
+    t0 = t0.square(); //         122: 52752713837791611484134960
+    t0 = t0.square(); //         123: 105505427675583222968269920
+    t0 = t0.square(); //         124: 211010855351166445936539840
+    t0 = t0.square(); //         125: 422021710702332891873079680
+    t0 = t0.square(); //         126: 844043421404665783746159360
+    t0 = t0.square(); //         127: 1688086842809331567492318720
+    t0 = t0.square(); //         128: 3376173685618663134984637440
+    t0 = t0.square(); //         129: 6752347371237326269969274880
+    t0 = t0.square(); //         130: 13504694742474652539938549760
+    t0 = t0.square(); //         131: 27009389484949305079877099520
+    t0 = t0.square(); //         132: 54018778969898610159754199040
+    t0 = t0.square(); //         133: 108037557939797220319508398080
+    t0 = t0.square(); //         134: 216075115879594440639016796160
+    t0 = t0.square(); //         135: 432150231759188881278033592320
+    t0 = t0.square(); //         136: 864300463518377762556067184640
+    t0 *= t25; //                137: 864300463518377762556067184687
+    t0 = t0.square(); //         138: 1728600927036755525112134369374
+    t0 = t0.square(); //         139: 3457201854073511050224268738748
+    t0 = t0.square(); //         140: 6914403708147022100448537477496
+    t0 = t0.square(); //         141: 13828807416294044200897074954992
+    t0 = t0.square(); //         142: 27657614832588088401794149909984
+    t0 = t0.square(); //         143: 55315229665176176803588299819968
+    t0 *= t27; //                144: 55315229665176176803588299820019
+    t0 = t0.square(); //         145: 110630459330352353607176599640038
+    t0 = t0.square(); //         146: 221260918660704707214353199280076
+    t0 = t0.square(); //         147: 442521837321409414428706398560152
+    t0 = t0.square(); //         148: 885043674642818828857412797120304
+    t0 = t0.square(); //         149: 1770087349285637657714825594240608
+    t0 = t0.square(); //         150: 3540174698571275315429651188481216
+    t0 *= t31; //                151: 3540174698571275315429651188481279
+    t0 = t0.square(); //         152: 7080349397142550630859302376962558
+    t0 = t0.square(); //         153: 14160698794285101261718604753925116
+    t0 = t0.square(); //         154: 28321397588570202523437209507850232
+    t0 = t0.square(); //         155: 56642795177140405046874419015700464
+    t0 = t0.square(); //         156: 113285590354280810093748838031400928
+    t0 = t0.square(); //         157: 226571180708561620187497676062801856
+    t0 *= t19; //                158: 226571180708561620187497676062801891
+    t0 = t0.square(); //         159: 453142361417123240374995352125603782
+    t0 = t0.square(); //         160: 906284722834246480749990704251207564
+    t0 = t0.square(); //         161: 1812569445668492961499981408502415128
+    t0 = t0.square(); //         162: 3625138891336985922999962817004830256
+    t0 = t0.square(); //         163: 7250277782673971845999925634009660512
+    t0 = t0.square(); //         164: 14500555565347943691999851268019321024
+    t0 = t0.square(); //         165: 29001111130695887383999702536038642048
+    t0 = t0.square(); //         166: 58002222261391774767999405072077284096
+    t0 = t0.square(); //         167: 116004444522783549535998810144154568192
+    t0 = t0.square(); //         168: 232008889045567099071997620288309136384
+    t0 = t0.square(); //         169: 464017778091134198143995240576618272768
+    t0 *= t10; //                170: 464017778091134198143995240576618272785
+    t0 = t0.square(); //         171: 928035556182268396287990481153236545570
+    t0 = t0.square(); //         172: 1856071112364536792575980962306473091140
+    t0 = t0.square(); //         173: 3712142224729073585151961924612946182280
+    t0 *= t2; //                 174: 3712142224729073585151961924612946182283
+    t0 = t0.square(); //         175: 7424284449458147170303923849225892364566
+    t0 = t0.square(); //         176: 14848568898916294340607847698451784729132
+    t0 = t0.square(); //         177: 29697137797832588681215695396903569458264
+    t0 = t0.square(); //         178: 59394275595665177362431390793807138916528
+    t0 = t0.square(); //         179: 118788551191330354724862781587614277833056
+    t0 = t0.square(); //         180: 237577102382660709449725563175228555666112
+    t0 = t0.square(); //         181: 475154204765321418899451126350457111332224
+    t0 = t0.square(); //         182: 950308409530642837798902252700914222664448
+    t0 = t0.square(); //         183: 1900616819061285675597804505401828445328896
+    t0 = t0.square(); //         184: 3801233638122571351195609010803656890657792
+    t0 *= t16; //                185: 3801233638122571351195609010803656890657821
+    t0 = t0.square(); //         186: 7602467276245142702391218021607313781315642
+    t0 = t0.square(); //         187: 15204934552490285404782436043214627562631284
+    t0 = t0.square(); //         188: 30409869104980570809564872086429255125262568
+    t0 = t0.square(); //         189: 60819738209961141619129744172858510250525136
+    t0 = t0.square(); //         190: 121639476419922283238259488345717020501050272
+    t0 = t0.square(); //         191: 243278952839844566476518976691434041002100544
+    t0 = t0.square(); //         192: 486557905679689132953037953382868082004201088
+    t0 *= t18; //                193: 486557905679689132953037953382868082004201121
+    t0 = t0.square(); //         194: 973115811359378265906075906765736164008402242
+    t0 = t0.square(); //         195: 1946231622718756531812151813531472328016804484
+    t0 = t0.square(); //         196: 3892463245437513063624303627062944656033608968
+    t0 = t0.square(); //         197: 7784926490875026127248607254125889312067217936
+    t0 = t0.square(); //         198: 15569852981750052254497214508251778624134435872
+    t0 = t0.square(); //         199: 31139705963500104508994429016503557248268871744
+    t0 *= t20; //                200: 31139705963500104508994429016503557248268871781
+    t0 = t0.square(); //         201: 62279411927000209017988858033007114496537743562
+    t0 = t0.square(); //         202: 124558823854000418035977716066014228993075487124
+    t0 = t0.square(); //         203: 249117647708000836071955432132028457986150974248
+    t0 *= t3; //                 204: 249117647708000836071955432132028457986150974253
+    t0 = t0.square(); //         205: 498235295416001672143910864264056915972301948506
+    t0 = t0.square(); //         206: 996470590832003344287821728528113831944603897012
+    t0 = t0.square(); //         207: 1992941181664006688575643457056227663889207794024
+    t0 = t0.square(); //         208: 3985882363328013377151286914112455327778415588048
+    t0 = t0.square(); //         209: 7971764726656026754302573828224910655556831176096
+    t0 = t0.square(); //         210: 15943529453312053508605147656449821311113662352192
+    t0 = t0.square(); //         211: 31887058906624107017210295312899642622227324704384
+    t0 = t0.square(); //         212: 63774117813248214034420590625799285244454649408768
+    t0 = t0.square(); //         213: 127548235626496428068841181251598570488909298817536
+    t0 *= t20; //                214: 127548235626496428068841181251598570488909298817573
+    t0 = t0.square(); //         215: 255096471252992856137682362503197140977818597635146
+    t0 = t0.square(); //         216: 510192942505985712275364725006394281955637195270292
+    t0 = t0.square(); //         217: 1020385885011971424550729450012788563911274390540584
+    t0 = t0.square(); //         218: 2040771770023942849101458900025577127822548781081168
+    t0 = t0.square(); //         219: 4081543540047885698202917800051154255645097562162336
+    t0 = t0.square(); //         220: 8163087080095771396405835600102308511290195124324672
+    t0 = t0.square(); //         221: 16326174160191542792811671200204617022580390248649344
+    t0 *= t13; //                222: 16326174160191542792811671200204617022580390248649367
+    t0 = t0.square(); //         223: 32652348320383085585623342400409234045160780497298734
+    t0 = t0.square(); //         224: 65304696640766171171246684800818468090321560994597468
+    t0 = t0.square(); //         225: 130609393281532342342493369601636936180643121989194936
+    t0 *= t2; //                 226: 130609393281532342342493369601636936180643121989194939
+    t0 = t0.square(); //         227: 261218786563064684684986739203273872361286243978389878
+    t0 = t0.square(); //         228: 522437573126129369369973478406547744722572487956779756
+    t0 = t0.square(); //         229: 1044875146252258738739946956813095489445144975913559512
+    t0 = t0.square(); //         230: 2089750292504517477479893913626190978890289951827119024
+    t0 = t0.square(); //         231: 4179500585009034954959787827252381957780579903654238048
+    t0 = t0.square(); //         232: 8359001170018069909919575654504763915561159807308476096
+    t0 = t0.square(); //         233: 16718002340036139819839151309009527831122319614616952192
+    t0 = t0.square(); //         234: 33436004680072279639678302618019055662244639229233904384
+    t0 = t0.square(); //         235: 66872009360144559279356605236038111324489278458467808768
+    t0 = t0.square(); //         236: 133744018720289118558713210472076222648978556916935617536
+    t0 *= t21; //                237: 133744018720289118558713210472076222648978556916935617575
+    t0 = t0.square(); //         238: 267488037440578237117426420944152445297957113833871235150
+    t0 = t0.square(); //         239: 534976074881156474234852841888304890595914227667742470300
+    t0 = t0.square(); //         240: 1069952149762312948469705683776609781191828455335484940600
+    t0 = t0.square(); //         241: 2139904299524625896939411367553219562383656910670969881200
+    t0 *= t9; //                 242: 2139904299524625896939411367553219562383656910670969881215
+    t0 = t0.square(); //         243: 4279808599049251793878822735106439124767313821341939762430
+    t0 = t0.square(); //         244: 8559617198098503587757645470212878249534627642683879524860
+    t0 = t0.square(); //         245: 17119234396197007175515290940425756499069255285367759049720
+    t0 = t0.square(); //         246: 34238468792394014351030581880851512998138510570735518099440
+    t0 = t0.square(); //         247: 68476937584788028702061163761703025996277021141471036198880
+    t0 = t0.square(); //         248: 136953875169576057404122327523406051992554042282942072397760
+    t0 = t0.square(); //         249: 273907750339152114808244655046812103985108084565884144795520
+    t0 = t0.square(); //         250: 547815500678304229616489310093624207970216169131768289591040
+    t0 *= t21; //                251: 547815500678304229616489310093624207970216169131768289591079
+    t0 = t0.square(); //         252: 1095631001356608459232978620187248415940432338263536579182158
+    t0 = t0.square(); //         253: 2191262002713216918465957240374496831880864676527073158364316
+    t0 = t0.square(); //         254: 4382524005426433836931914480748993663761729353054146316728632
+    t0 = t0.square(); //         255: 8765048010852867673863828961497987327523458706108292633457264
+    t0 = t0.square(); //         256: 17530096021705735347727657922995974655046917412216585266914528
+    t0 = t0.square(); //         257: 35060192043411470695455315845991949310093834824433170533829056
+    t0 = t0.square(); //         258: 70120384086822941390910631691983898620187669648866341067658112
+    t0 = t0.square(); //         259: 140240768173645882781821263383967797240375339297732682135316224
+    t0 *= t19; //                260: 140240768173645882781821263383967797240375339297732682135316259
+    t0 = t0.square(); //         261: 280481536347291765563642526767935594480750678595465364270632518
+    t0 = t0.square(); //         262: 560963072694583531127285053535871188961501357190930728541265036
+    t0 = t0.square(); //         263: 1121926145389167062254570107071742377923002714381861457082530072
+    t0 *= t2; //                 264: 1121926145389167062254570107071742377923002714381861457082530075
+    t0 = t0.square(); //         265: 2243852290778334124509140214143484755846005428763722914165060150
+    t0 = t0.square(); //         266: 4487704581556668249018280428286969511692010857527445828330120300
+    t0 = t0.square(); //         267: 8975409163113336498036560856573939023384021715054891656660240600
+    t0 = t0.square(); //         268: 17950818326226672996073121713147878046768043430109783313320481200
+    t0 = t0.square(); //         269: 35901636652453345992146243426295756093536086860219566626640962400
+    t0 = t0.square(); //         270: 71803273304906691984292486852591512187072173720439133253281924800
+    t0 = t0.square(); //         271: 143606546609813383968584973705183024374144347440878266506563849600
+    t0 = t0.square(); //         272: 287213093219626767937169947410366048748288694881756533013127699200
+    t0 = t0.square(); //         273: 574426186439253535874339894820732097496577389763513066026255398400
+    t0 *= t13; //                274: 574426186439253535874339894820732097496577389763513066026255398423
+    t0 = t0.square(); //         275: 1148852372878507071748679789641464194993154779527026132052510796846
+    t0 = t0.square(); //         276: 2297704745757014143497359579282928389986309559054052264105021593692
+    t0 = t0.square(); //         277: 4595409491514028286994719158565856779972619118108104528210043187384
+    t0 = t0.square(); //         278: 9190818983028056573989438317131713559945238236216209056420086374768
+    t0 = t0.square(); //         279: 18381637966056113147978876634263427119890476472432418112840172749536
+    t0 = t0.square(); //         280: 36763275932112226295957753268526854239780952944864836225680345499072
+    t0 = t0.square(); //         281: 73526551864224452591915506537053708479561905889729672451360690998144
+    t0 = t0.square(); //         282: 147053103728448905183831013074107416959123811779459344902721381996288
+    t0 *= t28; //                283: 147053103728448905183831013074107416959123811779459344902721381996341
+    t0 = t0.square(); //         284: 294106207456897810367662026148214833918247623558918689805442763992682
+    t0 = t0.square(); //         285: 588212414913795620735324052296429667836495247117837379610885527985364
+    t0 = t0.square(); //         286: 1176424829827591241470648104592859335672990494235674759221771055970728
+    t0 = t0.square(); //         287: 2352849659655182482941296209185718671345980988471349518443542111941456
+    t0 = t0.square(); //         288: 4705699319310364965882592418371437342691961976942699036887084223882912
+    t0 = t0.square(); //         289: 9411398638620729931765184836742874685383923953885398073774168447765824
+    t0 *= t25; //                290: 9411398638620729931765184836742874685383923953885398073774168447765871
+    t0 = t0.square(); //         291: 18822797277241459863530369673485749370767847907770796147548336895531742
+    t0 = t0.square(); //         292: 37645594554482919727060739346971498741535695815541592295096673791063484
+    t0 = t0.square(); //         293: 75291189108965839454121478693942997483071391631083184590193347582126968
+    t0 = t0.square(); //         294: 150582378217931678908242957387885994966142783262166369180386695164253936
+    t0 *= t7; //                 295: 150582378217931678908242957387885994966142783262166369180386695164253947
+    t0 = t0.square(); //         296: 301164756435863357816485914775771989932285566524332738360773390328507894
+    t0 = t0.square(); //         297: 602329512871726715632971829551543979864571133048665476721546780657015788
+    t0 = t0.square(); //         298: 1204659025743453431265943659103087959729142266097330953443093561314031576
+    t0 = t0.square(); //         299: 2409318051486906862531887318206175919458284532194661906886187122628063152
+    t0 = t0.square(); //         300: 4818636102973813725063774636412351838916569064389323813772374245256126304
+    t0 = t0.square(); //         301: 9637272205947627450127549272824703677833138128778647627544748490512252608
+    t0 = t0.square(); //         302: 19274544411895254900255098545649407355666276257557295255089496981024505216
+    t0 = t0.square(); //         303: 38549088823790509800510197091298814711332552515114590510178993962049010432
+    t0 = t0.square(); //         304: 77098177647581019601020394182597629422665105030229181020357987924098020864
+    t0 *= t19; //                305: 77098177647581019601020394182597629422665105030229181020357987924098020899
+    t0 = t0.square(); //         306: 154196355295162039202040788365195258845330210060458362040715975848196041798
+    t0 = t0.square(); //         307: 308392710590324078404081576730390517690660420120916724081431951696392083596
+    t0 = t0.square(); //         308: 616785421180648156808163153460781035381320840241833448162863903392784167192
+    t0 = t0.square(); //         309: 1233570842361296313616326306921562070762641680483666896325727806785568334384
+    t0 = t0.square(); //         310: 2467141684722592627232652613843124141525283360967333792651455613571136668768
+    t0 = t0.square(); //         311: 4934283369445185254465305227686248283050566721934667585302911227142273337536
+    t0 = t0.square(); //         312: 9868566738890370508930610455372496566101133443869335170605822454284546675072
+    t0 *= t28; //                313: 9868566738890370508930610455372496566101133443869335170605822454284546675125
+    t0 = t0.square(); //         314: 19737133477780741017861220910744993132202266887738670341211644908569093350250
+    t0 = t0.square(); //         315: 39474266955561482035722441821489986264404533775477340682423289817138186700500
+    t0 = t0.square(); //         316: 78948533911122964071444883642979972528809067550954681364846579634276373401000
+    t0 = t0.square(); //         317: 157897067822245928142889767285959945057618135101909362729693159268552746802000
+    t0 = t0.square(); //         318: 315794135644491856285779534571919890115236270203818725459386318537105493604000
+    t0 = t0.square(); //         319: 631588271288983712571559069143839780230472540407637450918772637074210987208000
+    t0 *= t9; //                 320: 631588271288983712571559069143839780230472540407637450918772637074210987208015
+    t0 = t0.square(); //         321: 1263176542577967425143118138287679560460945080815274901837545274148421974416030
+    t0 = t0.square(); //         322: 2526353085155934850286236276575359120921890161630549803675090548296843948832060
+    t0 = t0.square(); //         323: 5052706170311869700572472553150718241843780323261099607350181096593687897664120
+    t0 = t0.square(); //         324: 10105412340623739401144945106301436483687560646522199214700362193187375795328240
+    t0 = t0.square(); //         325: 20210824681247478802289890212602872967375121293044398429400724386374751590656480
+    t0 = t0.square(); //         326: 40421649362494957604579780425205745934750242586088796858801448772749503181312960
+    t0 = t0.square(); //         327: 80843298724989915209159560850411491869500485172177593717602897545499006362625920
+    t0 *= t9; //                 328: 80843298724989915209159560850411491869500485172177593717602897545499006362625935
+    t0 = t0.square(); //         329: 161686597449979830418319121700822983739000970344355187435205795090998012725251870
+    t0 = t0.square(); //         330: 323373194899959660836638243401645967478001940688710374870411590181996025450503740
+    t0 = t0.square(); //         331: 646746389799919321673276486803291934956003881377420749740823180363992050901007480
+    t0 = t0.square(); //         332: 1293492779599838643346552973606583869912007762754841499481646360727984101802014960
+    t0 = t0.square(); //         333: 2586985559199677286693105947213167739824015525509682998963292721455968203604029920
+    t0 *= t3; //                 334: 2586985559199677286693105947213167739824015525509682998963292721455968203604029925
+    t0 = t0.square(); //         335: 5173971118399354573386211894426335479648031051019365997926585442911936407208059850
+    t0 = t0.square(); //         336: 10347942236798709146772423788852670959296062102038731995853170885823872814416119700
+    t0 = t0.square(); //         337: 20695884473597418293544847577705341918592124204077463991706341771647745628832239400
+    t0 = t0.square(); //         338: 41391768947194836587089695155410683837184248408154927983412683543295491257664478800
+    t0 = t0.square(); //         339: 82783537894389673174179390310821367674368496816309855966825367086590982515328957600
+    t0 = t0.square(); //         340: 165567075788779346348358780621642735348736993632619711933650734173181965030657915200
+    t0 = t0.square(); //         341: 331134151577558692696717561243285470697473987265239423867301468346363930061315830400
+    t0 = t0.square(); //         342: 662268303155117385393435122486570941394947974530478847734602936692727860122631660800
+    t0 *= t10; //                343: 662268303155117385393435122486570941394947974530478847734602936692727860122631660817
+    t0 = t0.square(); //         344: 1324536606310234770786870244973141882789895949060957695469205873385455720245263321634
+    t0 = t0.square(); //         345: 2649073212620469541573740489946283765579791898121915390938411746770911440490526643268
+    t0 = t0.square(); //         346: 5298146425240939083147480979892567531159583796243830781876823493541822880981053286536
+    t0 = t0.square(); //         347: 10596292850481878166294961959785135062319167592487661563753646987083645761962106573072
+    t0 = t0.square(); //         348: 21192585700963756332589923919570270124638335184975323127507293974167291523924213146144
+    t0 = t0.square(); //         349: 42385171401927512665179847839140540249276670369950646255014587948334583047848426292288
+    t0 = t0.square(); //         350: 84770342803855025330359695678281080498553340739901292510029175896669166095696852584576
+    t0 = t0.square(); //         351: 169540685607710050660719391356562160997106681479802585020058351793338332191393705169152
+    t0 = t0.square(); //         352: 339081371215420101321438782713124321994213362959605170040116703586676664382787410338304
+    t0 *= t19; //                353: 339081371215420101321438782713124321994213362959605170040116703586676664382787410338339
+    t0 = t0.square(); //         354: 678162742430840202642877565426248643988426725919210340080233407173353328765574820676678
+    t0 = t0.square(); //         355: 1356325484861680405285755130852497287976853451838420680160466814346706657531149641353356
+    t0 = t0.square(); //         356: 2712650969723360810571510261704994575953706903676841360320933628693413315062299282706712
+    t0 = t0.square(); //         357: 5425301939446721621143020523409989151907413807353682720641867257386826630124598565413424
+    t0 = t0.square(); //         358: 10850603878893443242286041046819978303814827614707365441283734514773653260249197130826848
+    t0 = t0.square(); //         359: 21701207757786886484572082093639956607629655229414730882567469029547306520498394261653696
+    t0 = t0.square(); //         360: 43402415515573772969144164187279913215259310458829461765134938059094613040996788523307392
+    t0 *= t21; //                361: 43402415515573772969144164187279913215259310458829461765134938059094613040996788523307431
+    t0 = t0.square(); //         362: 86804831031147545938288328374559826430518620917658923530269876118189226081993577046614862
+    t0 = t0.square(); //         363: 173609662062295091876576656749119652861037241835317847060539752236378452163987154093229724
+    t0 = t0.square(); //         364: 347219324124590183753153313498239305722074483670635694121079504472756904327974308186459448
+    t0 = t0.square(); //         365: 694438648249180367506306626996478611444148967341271388242159008945513808655948616372918896
+    t0 = t0.square(); //         366: 1388877296498360735012613253992957222888297934682542776484318017891027617311897232745837792
+    t0 = t0.square(); //         367: 2777754592996721470025226507985914445776595869365085552968636035782055234623794465491675584
+    t0 *= t29; //                368: 2777754592996721470025226507985914445776595869365085552968636035782055234623794465491675639
+    t0 = t0.square(); //         369: 5555509185993442940050453015971828891553191738730171105937272071564110469247588930983351278
+    t0 = t0.square(); //         370: 11111018371986885880100906031943657783106383477460342211874544143128220938495177861966702556
+    t0 = t0.square(); //         371: 22222036743973771760201812063887315566212766954920684423749088286256441876990355723933405112
+    t0 *= t5; //                 372: 22222036743973771760201812063887315566212766954920684423749088286256441876990355723933405119
+    t0 = t0.square(); //         373: 44444073487947543520403624127774631132425533909841368847498176572512883753980711447866810238
+    t0 = t0.square(); //         374: 88888146975895087040807248255549262264851067819682737694996353145025767507961422895733620476
+    t0 = t0.square(); //         375: 177776293951790174081614496511098524529702135639365475389992706290051535015922845791467240952
+    t0 = t0.square(); //         376: 355552587903580348163228993022197049059404271278730950779985412580103070031845691582934481904
+    t0 = t0.square(); //         377: 711105175807160696326457986044394098118808542557461901559970825160206140063691383165868963808
+    t0 = t0.square(); //         378: 1422210351614321392652915972088788196237617085114923803119941650320412280127382766331737927616
+    t0 = t0.square(); //         379: 2844420703228642785305831944177576392475234170229847606239883300640824560254765532663475855232
+    t0 = t0.square(); //         380: 5688841406457285570611663888355152784950468340459695212479766601281649120509531065326951710464
+    t0 *= t9; //                 381: 5688841406457285570611663888355152784950468340459695212479766601281649120509531065326951710479
+    t0 = t0.square(); //         382: 11377682812914571141223327776710305569900936680919390424959533202563298241019062130653903420958
+    t0 = t0.square(); //         383: 22755365625829142282446655553420611139801873361838780849919066405126596482038124261307806841916
+    t0 = t0.square(); //         384: 45510731251658284564893311106841222279603746723677561699838132810253192964076248522615613683832
+    t0 = t0.square(); //         385: 91021462503316569129786622213682444559207493447355123399676265620506385928152497045231227367664
+    t0 = t0.square(); //         386: 182042925006633138259573244427364889118414986894710246799352531241012771856304994090462454735328
+    t0 = t0.square(); //         387: 364085850013266276519146488854729778236829973789420493598705062482025543712609988180924909470656
+    t0 = t0.square(); //         388: 728171700026532553038292977709459556473659947578840987197410124964051087425219976361849818941312
+    t0 = t0.square(); //         389: 1456343400053065106076585955418919112947319895157681974394820249928102174850439952723699637882624
+    t0 = t0.square(); //         390: 2912686800106130212153171910837838225894639790315363948789640499856204349700879905447399275765248
+    t0 = t0.square(); //         391: 5825373600212260424306343821675676451789279580630727897579280999712408699401759810894798551530496
+    t0 *= t6; //                 392: 5825373600212260424306343821675676451789279580630727897579280999712408699401759810894798551530505
+    t0 = t0.square(); //         393: 11650747200424520848612687643351352903578559161261455795158561999424817398803519621789597103061010
+    t0 = t0.square(); //         394: 23301494400849041697225375286702705807157118322522911590317123998849634797607039243579194206122020
+    t0 = t0.square(); //         395: 46602988801698083394450750573405411614314236645045823180634247997699269595214078487158388412244040
+    t0 = t0.square(); //         396: 93205977603396166788901501146810823228628473290091646361268495995398539190428156974316776824488080
+    t0 = t0.square(); //         397: 186411955206792333577803002293621646457256946580183292722536991990797078380856313948633553648976160
+    t0 = t0.square(); //         398: 372823910413584667155606004587243292914513893160366585445073983981594156761712627897267107297952320
+    t0 = t0.square(); //         399: 745647820827169334311212009174486585829027786320733170890147967963188313523425255794534214595904640
+    t0 = t0.square(); //         400: 1491295641654338668622424018348973171658055572641466341780295935926376627046850511589068429191809280
+    t0 *= t19; //                401: 1491295641654338668622424018348973171658055572641466341780295935926376627046850511589068429191809315
+    t0 = t0.square(); //         402: 2982591283308677337244848036697946343316111145282932683560591871852753254093701023178136858383618630
+    t0 = t0.square(); //         403: 5965182566617354674489696073395892686632222290565865367121183743705506508187402046356273716767237260
+    t0 = t0.square(); //         404: 11930365133234709348979392146791785373264444581131730734242367487411013016374804092712547433534474520
+    t0 = t0.square(); //         405: 23860730266469418697958784293583570746528889162263461468484734974822026032749608185425094867068949040
+    t0 = t0.square(); //         406: 47721460532938837395917568587167141493057778324526922936969469949644052065499216370850189734137898080
+    t0 = t0.square(); //         407: 95442921065877674791835137174334282986115556649053845873938939899288104130998432741700379468275796160
+    t0 = t0.square(); //         408: 190885842131755349583670274348668565972231113298107691747877879798576208261996865483400758936551592320
+    t0 *= t24; //                409: 190885842131755349583670274348668565972231113298107691747877879798576208261996865483400758936551592365
+    t0 = t0.square(); //         410: 381771684263510699167340548697337131944462226596215383495755759597152416523993730966801517873103184730
+    t0 = t0.square(); //         411: 763543368527021398334681097394674263888924453192430766991511519194304833047987461933603035746206369460
+    t0 = t0.square(); //         412: 1527086737054042796669362194789348527777848906384861533983023038388609666095974923867206071492412738920
+    t0 = t0.square(); //         413: 3054173474108085593338724389578697055555697812769723067966046076777219332191949847734412142984825477840
+    t0 = t0.square(); //         414: 6108346948216171186677448779157394111111395625539446135932092153554438664383899695468824285969650955680
+    t0 = t0.square(); //         415: 12216693896432342373354897558314788222222791251078892271864184307108877328767799390937648571939301911360
+    t0 = t0.square(); //         416: 24433387792864684746709795116629576444445582502157784543728368614217754657535598781875297143878603822720
+    t0 *= t14; //                417: 24433387792864684746709795116629576444445582502157784543728368614217754657535598781875297143878603822745
+    t0 = t0.square(); //         418: 48866775585729369493419590233259152888891165004315569087456737228435509315071197563750594287757207645490
+    t0 = t0.square(); //         419: 97733551171458738986839180466518305777782330008631138174913474456871018630142395127501188575514415290980
+    t0 = t0.square(); //         420: 195467102342917477973678360933036611555564660017262276349826948913742037260284790255002377151028830581960
+    t0 = t0.square(); //         421: 390934204685834955947356721866073223111129320034524552699653897827484074520569580510004754302057661163920
+    t0 = t0.square(); //         422: 781868409371669911894713443732146446222258640069049105399307795654968149041139161020009508604115322327840
+    t0 = t0.square(); //         423: 1563736818743339823789426887464292892444517280138098210798615591309936298082278322040019017208230644655680
+    t0 = t0.square(); //         424: 3127473637486679647578853774928585784889034560276196421597231182619872596164556644080038034416461289311360
+    t0 = t0.square(); //         425: 6254947274973359295157707549857171569778069120552392843194462365239745192329113288160076068832922578622720
+    t0 = t0.square(); //         426: 12509894549946718590315415099714343139556138241104785686388924730479490384658226576320152137665845157245440
+    t0 *= t24; //                427: 12509894549946718590315415099714343139556138241104785686388924730479490384658226576320152137665845157245485
+    t0 = t0.square(); //         428: 25019789099893437180630830199428686279112276482209571372777849460958980769316453152640304275331690314490970
+    t0 = t0.square(); //         429: 50039578199786874361261660398857372558224552964419142745555698921917961538632906305280608550663380628981940
+    t0 = t0.square(); //         430: 100079156399573748722523320797714745116449105928838285491111397843835923077265812610561217101326761257963880
+    t0 = t0.square(); //         431: 200158312799147497445046641595429490232898211857676570982222795687671846154531625221122434202653522515927760
+    t0 = t0.square(); //         432: 400316625598294994890093283190858980465796423715353141964445591375343692309063250442244868405307045031855520
+    t0 = t0.square(); //         433: 800633251196589989780186566381717960931592847430706283928891182750687384618126500884489736810614090063711040
+    t0 = t0.square(); //         434: 1601266502393179979560373132763435921863185694861412567857782365501374769236253001768979473621228180127422080
+    t0 *= t17; //                435: 1601266502393179979560373132763435921863185694861412567857782365501374769236253001768979473621228180127422111
+    t0 = t0.square(); //         436: 3202533004786359959120746265526871843726371389722825135715564731002749538472506003537958947242456360254844222
+    t0 = t0.square(); //         437: 6405066009572719918241492531053743687452742779445650271431129462005499076945012007075917894484912720509688444
+    t0 = t0.square(); //         438: 12810132019145439836482985062107487374905485558891300542862258924010998153890024014151835788969825441019376888
+    t0 = t0.square(); //         439: 25620264038290879672965970124214974749810971117782601085724517848021996307780048028303671577939650882038753776
+    t0 = t0.square(); //         440: 51240528076581759345931940248429949499621942235565202171449035696043992615560096056607343155879301764077507552
+    t0 *= t6; //                 441: 51240528076581759345931940248429949499621942235565202171449035696043992615560096056607343155879301764077507561
+    t0 = t0.square(); //         442: 102481056153163518691863880496859898999243884471130404342898071392087985231120192113214686311758603528155015122
+    t0 = t0.square(); //         443: 204962112306327037383727760993719797998487768942260808685796142784175970462240384226429372623517207056310030244
+    t0 = t0.square(); //         444: 409924224612654074767455521987439595996975537884521617371592285568351940924480768452858745247034414112620060488
+    t0 = t0.square(); //         445: 819848449225308149534911043974879191993951075769043234743184571136703881848961536905717490494068828225240120976
+    t0 = t0.square(); //         446: 1639696898450616299069822087949758383987902151538086469486369142273407763697923073811434980988137656450480241952
+    t0 = t0.square(); //         447: 3279393796901232598139644175899516767975804303076172938972738284546815527395846147622869961976275312900960483904
+    t0 = t0.square(); //         448: 6558787593802465196279288351799033535951608606152345877945476569093631054791692295245739923952550625801920967808
+    t0 = t0.square(); //         449: 13117575187604930392558576703598067071903217212304691755890953138187262109583384590491479847905101251603841935616
+    t0 = t0.square(); //         450: 26235150375209860785117153407196134143806434424609383511781906276374524219166769180982959695810202503207683871232
+    t0 = t0.square(); //         451: 52470300750419721570234306814392268287612868849218767023563812552749048438333538361965919391620405006415367742464
+    t0 = t0.square(); //         452: 104940601500839443140468613628784536575225737698437534047127625105498096876667076723931838783240810012830735484928
+    t0 *= t28; //                453: 104940601500839443140468613628784536575225737698437534047127625105498096876667076723931838783240810012830735484981
+    t0 = t0.square(); //         454: 209881203001678886280937227257569073150451475396875068094255250210996193753334153447863677566481620025661470969962
+    t0 = t0.square(); //         455: 419762406003357772561874454515138146300902950793750136188510500421992387506668306895727355132963240051322941939924
+    t0 = t0.square(); //         456: 839524812006715545123748909030276292601805901587500272377021000843984775013336613791454710265926480102645883879848
+    t0 = t0.square(); //         457: 1679049624013431090247497818060552585203611803175000544754042001687969550026673227582909420531852960205291767759696
+    t0 = t0.square(); //         458: 3358099248026862180494995636121105170407223606350001089508084003375939100053346455165818841063705920410583535519392
+    t0 *= t16; //                459: 3358099248026862180494995636121105170407223606350001089508084003375939100053346455165818841063705920410583535519421
+    t0 = t0.square(); //         460: 6716198496053724360989991272242210340814447212700002179016168006751878200106692910331637682127411840821167071038842
+    t0 = t0.square(); //         461: 13432396992107448721979982544484420681628894425400004358032336013503756400213385820663275364254823681642334142077684
+    t0 = t0.square(); //         462: 26864793984214897443959965088968841363257788850800008716064672027007512800426771641326550728509647363284668284155368
+    t0 = t0.square(); //         463: 53729587968429794887919930177937682726515577701600017432129344054015025600853543282653101457019294726569336568310736
+    t0 = t0.square(); //         464: 107459175936859589775839860355875365453031155403200034864258688108030051201707086565306202914038589453138673136621472
+    t0 = t0.square(); //         465: 214918351873719179551679720711750730906062310806400069728517376216060102403414173130612405828077178906277346273242944
+    t0 = t0.square(); //         466: 429836703747438359103359441423501461812124621612800139457034752432120204806828346261224811656154357812554692546485888
+    t0 *= t20; //                467: 429836703747438359103359441423501461812124621612800139457034752432120204806828346261224811656154357812554692546485925
+    t0 = t0.square(); //         468: 859673407494876718206718882847002923624249243225600278914069504864240409613656692522449623312308715625109385092971850
+    t0 = t0.square(); //         469: 1719346814989753436413437765694005847248498486451200557828139009728480819227313385044899246624617431250218770185943700
+    t0 = t0.square(); //         470: 3438693629979506872826875531388011694496996972902401115656278019456961638454626770089798493249234862500437540371887400
+    t0 = t0.square(); //         471: 6877387259959013745653751062776023388993993945804802231312556038913923276909253540179596986498469725000875080743774800
+    t0 = t0.square(); //         472: 13754774519918027491307502125552046777987987891609604462625112077827846553818507080359193972996939450001750161487549600
+    t0 = t0.square(); //         473: 27509549039836054982615004251104093555975975783219208925250224155655693107637014160718387945993878900003500322975099200
+    t0 = t0.square(); //         474: 55019098079672109965230008502208187111951951566438417850500448311311386215274028321436775891987757800007000645950198400
+    t0 *= t12; //                475: 55019098079672109965230008502208187111951951566438417850500448311311386215274028321436775891987757800007000645950198421
+    t0 = t0.square(); //         476: 110038196159344219930460017004416374223903903132876835701000896622622772430548056642873551783975515600014001291900396842
+    t0 = t0.square(); //         477: 220076392318688439860920034008832748447807806265753671402001793245245544861096113285747103567951031200028002583800793684
+    t0 = t0.square(); //         478: 440152784637376879721840068017665496895615612531507342804003586490491089722192226571494207135902062400056005167601587368
+    t0 = t0.square(); //         479: 880305569274753759443680136035330993791231225063014685608007172980982179444384453142988414271804124800112010335203174736
+    t0 = t0.square(); //         480: 1760611138549507518887360272070661987582462450126029371216014345961964358888768906285976828543608249600224020670406349472
+    t0 = t0.square(); //         481: 3521222277099015037774720544141323975164924900252058742432028691923928717777537812571953657087216499200448041340812698944
+    t0 = t0.square(); //         482: 7042444554198030075549441088282647950329849800504117484864057383847857435555075625143907314174432998400896082681625397888
+    t0 *= t25; //                483: 7042444554198030075549441088282647950329849800504117484864057383847857435555075625143907314174432998400896082681625397935
+    t0 = t0.square(); //         484: 14084889108396060151098882176565295900659699601008234969728114767695714871110151250287814628348865996801792165363250795870
+    t0 = t0.square(); //         485: 28169778216792120302197764353130591801319399202016469939456229535391429742220302500575629256697731993603584330726501591740
+    t0 = t0.square(); //         486: 56339556433584240604395528706261183602638798404032939878912459070782859484440605001151258513395463987207168661453003183480
+    t0 = t0.square(); //         487: 112679112867168481208791057412522367205277596808065879757824918141565718968881210002302517026790927974414337322906006366960
+    t0 = t0.square(); //         488: 225358225734336962417582114825044734410555193616131759515649836283131437937762420004605034053581855948828674645812012733920
+    t0 = t0.square(); //         489: 450716451468673924835164229650089468821110387232263519031299672566262875875524840009210068107163711897657349291624025467840
+    t0 = t0.square(); //         490: 901432902937347849670328459300178937642220774464527038062599345132525751751049680018420136214327423795314698583248050935680
+    t0 = t0.square(); //         491: 1802865805874695699340656918600357875284441548929054076125198690265051503502099360036840272428654847590629397166496101871360
+    t0 = t0.square(); //         492: 3605731611749391398681313837200715750568883097858108152250397380530103007004198720073680544857309695181258794332992203742720
+    t0 = t0.square(); //         493: 7211463223498782797362627674401431501137766195716216304500794761060206014008397440147361089714619390362517588665984407485440
+    t0 *= t13; //                494: 7211463223498782797362627674401431501137766195716216304500794761060206014008397440147361089714619390362517588665984407485463
+    t0 = t0.square(); //         495: 14422926446997565594725255348802863002275532391432432609001589522120412028016794880294722179429238780725035177331968814970926
+    t0 = t0.square(); //         496: 28845852893995131189450510697605726004551064782864865218003179044240824056033589760589444358858477561450070354663937629941852
+    t0 = t0.square(); //         497: 57691705787990262378901021395211452009102129565729730436006358088481648112067179521178888717716955122900140709327875259883704
+    t0 = t0.square(); //         498: 115383411575980524757802042790422904018204259131459460872012716176963296224134359042357777435433910245800281418655750519767408
+    t0 = t0.square(); //         499: 230766823151961049515604085580845808036408518262918921744025432353926592448268718084715554870867820491600562837311501039534816
+    t0 = t0.square(); //         500: 461533646303922099031208171161691616072817036525837843488050864707853184896537436169431109741735640983201125674623002079069632
+    t0 = t0.square(); //         501: 923067292607844198062416342323383232145634073051675686976101729415706369793074872338862219483471281966402251349246004158139264
+    t0 = t0.square(); //         502: 1846134585215688396124832684646766464291268146103351373952203458831412739586149744677724438966942563932804502698492008316278528
+    t0 = t0.square(); //         503: 3692269170431376792249665369293532928582536292206702747904406917662825479172299489355448877933885127865609005396984016632557056
+    t0 *= t30; //                504: 3692269170431376792249665369293532928582536292206702747904406917662825479172299489355448877933885127865609005396984016632557113
+    t0 = t0.square(); //         505: 7384538340862753584499330738587065857165072584413405495808813835325650958344598978710897755867770255731218010793968033265114226
+    t0 = t0.square(); //         506: 14769076681725507168998661477174131714330145168826810991617627670651301916689197957421795511735540511462436021587936066530228452
+    t0 = t0.square(); //         507: 29538153363451014337997322954348263428660290337653621983235255341302603833378395914843591023471081022924872043175872133060456904
+    t0 = t0.square(); //         508: 59076306726902028675994645908696526857320580675307243966470510682605207666756791829687182046942162045849744086351744266120913808
+    t0 = t0.square(); //         509: 118152613453804057351989291817393053714641161350614487932941021365210415333513583659374364093884324091699488172703488532241827616
+    t0 = t0.square(); //         510: 236305226907608114703978583634786107429282322701228975865882042730420830667027167318748728187768648183398976345406977064483655232
+    t0 = t0.square(); //         511: 472610453815216229407957167269572214858564645402457951731764085460841661334054334637497456375537296366797952690813954128967310464
+    t0 *= t11; //                512: 472610453815216229407957167269572214858564645402457951731764085460841661334054334637497456375537296366797952690813954128967310483
+    t0 = t0.square(); //         513: 945220907630432458815914334539144429717129290804915903463528170921683322668108669274994912751074592733595905381627908257934620966
+    t0 = t0.square(); //         514: 1890441815260864917631828669078288859434258581609831806927056341843366645336217338549989825502149185467191810763255816515869241932
+    t0 = t0.square(); //         515: 3780883630521729835263657338156577718868517163219663613854112683686733290672434677099979651004298370934383621526511633031738483864
+    t0 = t0.square(); //         516: 7561767261043459670527314676313155437737034326439327227708225367373466581344869354199959302008596741868767243053023266063476967728
+    t0 = t0.square(); //         517: 15123534522086919341054629352626310875474068652878654455416450734746933162689738708399918604017193483737534486106046532126953935456
+    t0 = t0.square(); //         518: 30247069044173838682109258705252621750948137305757308910832901469493866325379477416799837208034386967475068972212093064253907870912
+    t0 = t0.square(); //         519: 60494138088347677364218517410505243501896274611514617821665802938987732650758954833599674416068773934950137944424186128507815741824
+    t0 = t0.square(); //         520: 120988276176695354728437034821010487003792549223029235643331605877975465301517909667199348832137547869900275888848372257015631483648
+    t0 = t0.square(); //         521: 241976552353390709456874069642020974007585098446058471286663211755950930603035819334398697664275095739800551777696744514031262967296
+    t0 *= t29; //                522: 241976552353390709456874069642020974007585098446058471286663211755950930603035819334398697664275095739800551777696744514031262967351
+    t0 = t0.square(); //         523: 483953104706781418913748139284041948015170196892116942573326423511901861206071638668797395328550191479601103555393489028062525934702
+    t0 = t0.square(); //         524: 967906209413562837827496278568083896030340393784233885146652847023803722412143277337594790657100382959202207110786978056125051869404
+    t0 = t0.square(); //         525: 1935812418827125675654992557136167792060680787568467770293305694047607444824286554675189581314200765918404414221573956112250103738808
+    t0 *= t3; //                 526: 1935812418827125675654992557136167792060680787568467770293305694047607444824286554675189581314200765918404414221573956112250103738813
+    t0 = t0.square(); //         527: 3871624837654251351309985114272335584121361575136935540586611388095214889648573109350379162628401531836808828443147912224500207477626
+    t0 = t0.square(); //         528: 7743249675308502702619970228544671168242723150273871081173222776190429779297146218700758325256803063673617656886295824449000414955252
+    t0 = t0.square(); //         529: 15486499350617005405239940457089342336485446300547742162346445552380859558594292437401516650513606127347235313772591648898000829910504
+    t0 = t0.square(); //         530: 30972998701234010810479880914178684672970892601095484324692891104761719117188584874803033301027212254694470627545183297796001659821008
+    t0 = t0.square(); //         531: 61945997402468021620959761828357369345941785202190968649385782209523438234377169749606066602054424509388941255090366595592003319642016
+    t0 = t0.square(); //         532: 123891994804936043241919523656714738691883570404381937298771564419046876468754339499212133204108849018777882510180733191184006639284032
+    t0 = t0.square(); //         533: 247783989609872086483839047313429477383767140808763874597543128838093752937508678998424266408217698037555765020361466382368013278568064
+    t0 = t0.square(); //         534: 495567979219744172967678094626858954767534281617527749195086257676187505875017357996848532816435396075111530040722932764736026557136128
+    t0 *= t10; //                535: 495567979219744172967678094626858954767534281617527749195086257676187505875017357996848532816435396075111530040722932764736026557136145
+    t0 = t0.square(); //         536: 991135958439488345935356189253717909535068563235055498390172515352375011750034715993697065632870792150223060081445865529472053114272290
+    t0 = t0.square(); //         537: 1982271916878976691870712378507435819070137126470110996780345030704750023500069431987394131265741584300446120162891731058944106228544580
+    t0 = t0.square(); //         538: 3964543833757953383741424757014871638140274252940221993560690061409500047000138863974788262531483168600892240325783462117888212457089160
+    t0 = t0.square(); //         539: 7929087667515906767482849514029743276280548505880443987121380122819000094000277727949576525062966337201784480651566924235776424914178320
+    t0 = t0.square(); //         540: 15858175335031813534965699028059486552561097011760887974242760245638000188000555455899153050125932674403568961303133848471552849828356640
+    t0 = t0.square(); //         541: 31716350670063627069931398056118973105122194023521775948485520491276000376001110911798306100251865348807137922606267696943105699656713280
+    t0 = t0.square(); //         542: 63432701340127254139862796112237946210244388047043551896971040982552000752002221823596612200503730697614275845212535393886211399313426560
+    t0 *= t9; //                 543: 63432701340127254139862796112237946210244388047043551896971040982552000752002221823596612200503730697614275845212535393886211399313426575
+    t0 = t0.square(); //         544: 126865402680254508279725592224475892420488776094087103793942081965104001504004443647193224401007461395228551690425070787772422798626853150
+    t0 = t0.square(); //         545: 253730805360509016559451184448951784840977552188174207587884163930208003008008887294386448802014922790457103380850141575544845597253706300
+    t0 = t0.square(); //         546: 507461610721018033118902368897903569681955104376348415175768327860416006016017774588772897604029845580914206761700283151089691194507412600
+    t0 = t0.square(); //         547: 1014923221442036066237804737795807139363910208752696830351536655720832012032035549177545795208059691161828413523400566302179382389014825200
+    t0 = t0.square(); //         548: 2029846442884072132475609475591614278727820417505393660703073311441664024064071098355091590416119382323656827046801132604358764778029650400
+    t0 = t0.square(); //         549: 4059692885768144264951218951183228557455640835010787321406146622883328048128142196710183180832238764647313654093602265208717529556059300800
+    t0 = t0.square(); //         550: 8119385771536288529902437902366457114911281670021574642812293245766656096256284393420366361664477529294627308187204530417435059112118601600
+    t0 = t0.square(); //         551: 16238771543072577059804875804732914229822563340043149285624586491533312192512568786840732723328955058589254616374409060834870118224237203200
+    t0 = t0.square(); //         552: 32477543086145154119609751609465828459645126680086298571249172983066624385025137573681465446657910117178509232748818121669740236448474406400
+    t0 = t0.square(); //         553: 64955086172290308239219503218931656919290253360172597142498345966133248770050275147362930893315820234357018465497636243339480472896948812800
+    t0 *= t26; //                554: 64955086172290308239219503218931656919290253360172597142498345966133248770050275147362930893315820234357018465497636243339480472896948812849
+    t0 = t0.square(); //         555: 129910172344580616478439006437863313838580506720345194284996691932266497540100550294725861786631640468714036930995272486678960945793897625698
+    t0 = t0.square(); //         556: 259820344689161232956878012875726627677161013440690388569993383864532995080201100589451723573263280937428073861990544973357921891587795251396
+    t0 = t0.square(); //         557: 519640689378322465913756025751453255354322026881380777139986767729065990160402201178903447146526561874856147723981089946715843783175590502792
+    t0 = t0.square(); //         558: 1039281378756644931827512051502906510708644053762761554279973535458131980320804402357806894293053123749712295447962179893431687566351181005584
+    t0 = t0.square(); //         559: 2078562757513289863655024103005813021417288107525523108559947070916263960641608804715613788586106247499424590895924359786863375132702362011168
+    t0 = t0.square(); //         560: 4157125515026579727310048206011626042834576215051046217119894141832527921283217609431227577172212494998849181791848719573726750265404724022336
+    t0 = t0.square(); //         561: 8314251030053159454620096412023252085669152430102092434239788283665055842566435218862455154344424989997698363583697439147453500530809448044672
+    t0 *= t29; //                562: 8314251030053159454620096412023252085669152430102092434239788283665055842566435218862455154344424989997698363583697439147453500530809448044727
+    t0 = t0.square(); //         563: 16628502060106318909240192824046504171338304860204184868479576567330111685132870437724910308688849979995396727167394878294907001061618896089454
+    t0 = t0.square(); //         564: 33257004120212637818480385648093008342676609720408369736959153134660223370265740875449820617377699959990793454334789756589814002123237792178908
+    t0 = t0.square(); //         565: 66514008240425275636960771296186016685353219440816739473918306269320446740531481750899641234755399919981586908669579513179628004246475584357816
+    t0 = t0.square(); //         566: 133028016480850551273921542592372033370706438881633478947836612538640893481062963501799282469510799839963173817339159026359256008492951168715632
+    t0 = t0.square(); //         567: 266056032961701102547843085184744066741412877763266957895673225077281786962125927003598564939021599679926347634678318052718512016985902337431264
+    t0 *= t9; //                 568: 266056032961701102547843085184744066741412877763266957895673225077281786962125927003598564939021599679926347634678318052718512016985902337431279
+    t0 = t0.square(); //         569: 532112065923402205095686170369488133482825755526533915791346450154563573924251854007197129878043199359852695269356636105437024033971804674862558
+    t0 = t0.square(); //         570: 1064224131846804410191372340738976266965651511053067831582692900309127147848503708014394259756086398719705390538713272210874048067943609349725116
+    t0 = t0.square(); //         571: 2128448263693608820382744681477952533931303022106135663165385800618254295697007416028788519512172797439410781077426544421748096135887218699450232
+    t0 = t0.square(); //         572: 4256896527387217640765489362955905067862606044212271326330771601236508591394014832057577039024345594878821562154853088843496192271774437398900464
+    t0 = t0.square(); //         573: 8513793054774435281530978725911810135725212088424542652661543202473017182788029664115154078048691189757643124309706177686992384543548874797800928
+    t0 *= t2; //                 574: 8513793054774435281530978725911810135725212088424542652661543202473017182788029664115154078048691189757643124309706177686992384543548874797800931
+    t0 = t0.square(); //         575: 17027586109548870563061957451823620271450424176849085305323086404946034365576059328230308156097382379515286248619412355373984769087097749595601862
+    t0 = t0.square(); //         576: 34055172219097741126123914903647240542900848353698170610646172809892068731152118656460616312194764759030572497238824710747969538174195499191203724
+    t0 = t0.square(); //         577: 68110344438195482252247829807294481085801696707396341221292345619784137462304237312921232624389529518061144994477649421495939076348390998382407448
+    t0 = t0.square(); //         578: 136220688876390964504495659614588962171603393414792682442584691239568274924608474625842465248779059036122289988955298842991878152696781996764814896
+    t0 = t0.square(); //         579: 272441377752781929008991319229177924343206786829585364885169382479136549849216949251684930497558118072244579977910597685983756305393563993529629792
+    t0 = t0.square(); //         580: 544882755505563858017982638458355848686413573659170729770338764958273099698433898503369860995116236144489159955821195371967512610787127987059259584
+    t0 = t0.square(); //         581: 1089765511011127716035965276916711697372827147318341459540677529916546199396867797006739721990232472288978319911642390743935025221574255974118519168
+    t0 = t0.square(); //         582: 2179531022022255432071930553833423394745654294636682919081355059833092398793735594013479443980464944577956639823284781487870050443148511948237038336
+    t0 = t0.square(); //         583: 4359062044044510864143861107666846789491308589273365838162710119666184797587471188026958887960929889155913279646569562975740100886297023896474076672
+    t0 = t0.square(); //         584: 8718124088089021728287722215333693578982617178546731676325420239332369595174942376053917775921859778311826559293139125951480201772594047792948153344
+    t0 = t0.square(); //         585: 17436248176178043456575444430667387157965234357093463352650840478664739190349884752107835551843719556623653118586278251902960403545188095585896306688
+    t0 = t0.square(); //         586: 34872496352356086913150888861334774315930468714186926705301680957329478380699769504215671103687439113247306237172556503805920807090376191171792613376
+    t0 *= t27; //                587: 34872496352356086913150888861334774315930468714186926705301680957329478380699769504215671103687439113247306237172556503805920807090376191171792613427
+    t0 = t0.square(); //         588: 69744992704712173826301777722669548631860937428373853410603361914658956761399539008431342207374878226494612474345113007611841614180752382343585226854
+    t0 = t0.square(); //         589: 139489985409424347652603555445339097263721874856747706821206723829317913522799078016862684414749756452989224948690226015223683228361504764687170453708
+    t0 = t0.square(); //         590: 278979970818848695305207110890678194527443749713495413642413447658635827045598156033725368829499512905978449897380452030447366456723009529374340907416
+    t0 = t0.square(); //         591: 557959941637697390610414221781356389054887499426990827284826895317271654091196312067450737658999025811956899794760904060894732913446019058748681814832
+    t0 = t0.square(); //         592: 1115919883275394781220828443562712778109774998853981654569653790634543308182392624134901475317998051623913799589521808121789465826892038117497363629664
+    t0 = t0.square(); //         593: 2231839766550789562441656887125425556219549997707963309139307581269086616364785248269802950635996103247827599179043616243578931653784076234994727259328
+    t0 = t0.square(); //         594: 4463679533101579124883313774250851112439099995415926618278615162538173232729570496539605901271992206495655198358087232487157863307568152469989454518656
+    t0 = t0.square(); //         595: 8927359066203158249766627548501702224878199990831853236557230325076346465459140993079211802543984412991310396716174464974315726615136304939978909037312
+    t0 = t0.square(); //         596: 17854718132406316499533255097003404449756399981663706473114460650152692930918281986158423605087968825982620793432348929948631453230272609879957818074624
+    t0 *= t21; //                597: 17854718132406316499533255097003404449756399981663706473114460650152692930918281986158423605087968825982620793432348929948631453230272609879957818074663
+    t0 = t0.square(); //         598: 35709436264812632999066510194006808899512799963327412946228921300305385861836563972316847210175937651965241586864697859897262906460545219759915636149326
+    t0 = t0.square(); //         599: 71418872529625265998133020388013617799025599926654825892457842600610771723673127944633694420351875303930483173729395719794525812921090439519831272298652
+    t0 = t0.square(); //         600: 142837745059250531996266040776027235598051199853309651784915685201221543447346255889267388840703750607860966347458791439589051625842180879039662544597304
+    t0 = t0.square(); //         601: 285675490118501063992532081552054471196102399706619303569831370402443086894692511778534777681407501215721932694917582879178103251684361758079325089194608
+    t0 = t0.square(); //         602: 571350980237002127985064163104108942392204799413238607139662740804886173789385023557069555362815002431443865389835165758356206503368723516158650178389216
+    t0 = t0.square(); //         603: 1142701960474004255970128326208217884784409598826477214279325481609772347578770047114139110725630004862887730779670331516712413006737447032317300356778432
+    t0 *= t20; //                604: 1142701960474004255970128326208217884784409598826477214279325481609772347578770047114139110725630004862887730779670331516712413006737447032317300356778469
+    t0 = t0.square(); //         605: 2285403920948008511940256652416435769568819197652954428558650963219544695157540094228278221451260009725775461559340663033424826013474894064634600713556938
+    t0 = t0.square(); //         606: 4570807841896017023880513304832871539137638395305908857117301926439089390315080188456556442902520019451550923118681326066849652026949788129269201427113876
+    t0 = t0.square(); //         607: 9141615683792034047761026609665743078275276790611817714234603852878178780630160376913112885805040038903101846237362652133699304053899576258538402854227752
+    t0 = t0.square(); //         608: 18283231367584068095522053219331486156550553581223635428469207705756357561260320753826225771610080077806203692474725304267398608107799152517076805708455504
+    t0 = t0.square(); //         609: 36566462735168136191044106438662972313101107162447270856938415411512715122520641507652451543220160155612407384949450608534797216215598305034153611416911008
+    t0 = t0.square(); //         610: 73132925470336272382088212877325944626202214324894541713876830823025430245041283015304903086440320311224814769898901217069594432431196610068307222833822016
+    t0 *= t2; //                 611: 73132925470336272382088212877325944626202214324894541713876830823025430245041283015304903086440320311224814769898901217069594432431196610068307222833822019
+    t0 = t0.square(); //         612: 146265850940672544764176425754651889252404428649789083427753661646050860490082566030609806172880640622449629539797802434139188864862393220136614445667644038
+    t0 = t0.square(); //         613: 292531701881345089528352851509303778504808857299578166855507323292101720980165132061219612345761281244899259079595604868278377729724786440273228891335288076
+    t0 = t0.square(); //         614: 585063403762690179056705703018607557009617714599156333711014646584203441960330264122439224691522562489798518159191209736556755459449572880546457782670576152
+    t0 = t0.square(); //         615: 1170126807525380358113411406037215114019235429198312667422029293168406883920660528244878449383045124979597036318382419473113510918899145761092915565341152304
+    t0 = t0.square(); //         616: 2340253615050760716226822812074430228038470858396625334844058586336813767841321056489756898766090249959194072636764838946227021837798291522185831130682304608
+    t0 = t0.square(); //         617: 4680507230101521432453645624148860456076941716793250669688117172673627535682642112979513797532180499918388145273529677892454043675596583044371662261364609216
+    t0 = t0.square(); //         618: 9361014460203042864907291248297720912153883433586501339376234345347255071365284225959027595064360999836776290547059355784908087351193166088743324522729218432
+    t0 = t0.square(); //         619: 18722028920406085729814582496595441824307766867173002678752468690694510142730568451918055190128721999673552581094118711569816174702386332177486649045458436864
+    t0 = t0.square(); //         620: 37444057840812171459629164993190883648615533734346005357504937381389020285461136903836110380257443999347105162188237423139632349404772664354973298090916873728
+    t0 = t0.square(); //         621: 74888115681624342919258329986381767297231067468692010715009874762778040570922273807672220760514887998694210324376474846279264698809545328709946596181833747456
+    t0 = t0.square(); //         622: 149776231363248685838516659972763534594462134937384021430019749525556081141844547615344441521029775997388420648752949692558529397619090657419893192363667494912
+    t0 *= t22; //                623: 149776231363248685838516659972763534594462134937384021430019749525556081141844547615344441521029775997388420648752949692558529397619090657419893192363667494953
+    t0 = t0.square(); //         624: 299552462726497371677033319945527069188924269874768042860039499051112162283689095230688883042059551994776841297505899385117058795238181314839786384727334989906
+    t0 = t0.square(); //         625: 599104925452994743354066639891054138377848539749536085720078998102224324567378190461377766084119103989553682595011798770234117590476362629679572769454669979812
+    t0 = t0.square(); //         626: 1198209850905989486708133279782108276755697079499072171440157996204448649134756380922755532168238207979107365190023597540468235180952725259359145538909339959624
+    t0 = t0.square(); //         627: 2396419701811978973416266559564216553511394158998144342880315992408897298269512761845511064336476415958214730380047195080936470361905450518718291077818679919248
+    t0 *= t8; //                 628: 2396419701811978973416266559564216553511394158998144342880315992408897298269512761845511064336476415958214730380047195080936470361905450518718291077818679919261
+    t0 = t0.square(); //         629: 4792839403623957946832533119128433107022788317996288685760631984817794596539025523691022128672952831916429460760094390161872940723810901037436582155637359838522
+    t0 = t0.square(); //         630: 9585678807247915893665066238256866214045576635992577371521263969635589193078051047382044257345905663832858921520188780323745881447621802074873164311274719677044
+    t0 = t0.square(); //         631: 19171357614495831787330132476513732428091153271985154743042527939271178386156102094764088514691811327665717843040377560647491762895243604149746328622549439354088
+    t0 = t0.square(); //         632: 38342715228991663574660264953027464856182306543970309486085055878542356772312204189528177029383622655331435686080755121294983525790487208299492657245098878708176
+    t0 = t0.square(); //         633: 76685430457983327149320529906054929712364613087940618972170111757084713544624408379056354058767245310662871372161510242589967051580974416598985314490197757416352
+    t0 = t0.square(); //         634: 153370860915966654298641059812109859424729226175881237944340223514169427089248816758112708117534490621325742744323020485179934103161948833197970628980395514832704
+    t0 = t0.square(); //         635: 306741721831933308597282119624219718849458452351762475888680447028338854178497633516225416235068981242651485488646040970359868206323897666395941257960791029665408
+    t0 = t0.square(); //         636: 613483443663866617194564239248439437698916904703524951777360894056677708356995267032450832470137962485302970977292081940719736412647795332791882515921582059330816
+    t0 = t0.square(); //         637: 1226966887327733234389128478496878875397833809407049903554721788113355416713990534064901664940275924970605941954584163881439472825295590665583765031843164118661632
+    t0 *= t7; //                 638: 1226966887327733234389128478496878875397833809407049903554721788113355416713990534064901664940275924970605941954584163881439472825295590665583765031843164118661643
+    t0 = t0.square(); //         639: 2453933774655466468778256956993757750795667618814099807109443576226710833427981068129803329880551849941211883909168327762878945650591181331167530063686328237323286
+    t0 = t0.square(); //         640: 4907867549310932937556513913987515501591335237628199614218887152453421666855962136259606659761103699882423767818336655525757891301182362662335060127372656474646572
+    t0 = t0.square(); //         641: 9815735098621865875113027827975031003182670475256399228437774304906843333711924272519213319522207399764847535636673311051515782602364725324670120254745312949293144
+    t0 = t0.square(); //         642: 19631470197243731750226055655950062006365340950512798456875548609813686667423848545038426639044414799529695071273346622103031565204729450649340240509490625898586288
+    t0 = t0.square(); //         643: 39262940394487463500452111311900124012730681901025596913751097219627373334847697090076853278088829599059390142546693244206063130409458901298680481018981251797172576
+    t0 *= t5; //                 644: 39262940394487463500452111311900124012730681901025596913751097219627373334847697090076853278088829599059390142546693244206063130409458901298680481018981251797172583
+    t0 = t0.square(); //         645: 78525880788974927000904222623800248025461363802051193827502194439254746669695394180153706556177659198118780285093386488412126260818917802597360962037962503594345166
+    t0 = t0.square(); //         646: 157051761577949854001808445247600496050922727604102387655004388878509493339390788360307413112355318396237560570186772976824252521637835605194721924075925007188690332
+    t0 = t0.square(); //         647: 314103523155899708003616890495200992101845455208204775310008777757018986678781576720614826224710636792475121140373545953648505043275671210389443848151850014377380664
+    t0 = t0.square(); //         648: 628207046311799416007233780990401984203690910416409550620017555514037973357563153441229652449421273584950242280747091907297010086551342420778887696303700028754761328
+    t0 = t0.square(); //         649: 1256414092623598832014467561980803968407381820832819101240035111028075946715126306882459304898842547169900484561494183814594020173102684841557775392607400057509522656
+    t0 = t0.square(); //         650: 2512828185247197664028935123961607936814763641665638202480070222056151893430252613764918609797685094339800969122988367629188040346205369683115550785214800115019045312
+    t0 = t0.square(); //         651: 5025656370494395328057870247923215873629527283331276404960140444112303786860505227529837219595370188679601938245976735258376080692410739366231101570429600230038090624
+    t0 = t0.square(); //         652: 10051312740988790656115740495846431747259054566662552809920280888224607573721010455059674439190740377359203876491953470516752161384821478732462203140859200460076181248
+    t0 = t0.square(); //         653: 20102625481977581312231480991692863494518109133325105619840561776449215147442020910119348878381480754718407752983906941033504322769642957464924406281718400920152362496
+    t0 = t0.square(); //         654: 40205250963955162624462961983385726989036218266650211239681123552898430294884041820238697756762961509436815505967813882067008645539285914929848812563436801840304724992
+    t0 *= t23; //                655: 40205250963955162624462961983385726989036218266650211239681123552898430294884041820238697756762961509436815505967813882067008645539285914929848812563436801840304725035
+    t0 = t0.square(); //         656: 80410501927910325248925923966771453978072436533300422479362247105796860589768083640477395513525923018873631011935627764134017291078571829859697625126873603680609450070
+    t0 = t0.square(); //         657: 160821003855820650497851847933542907956144873066600844958724494211593721179536167280954791027051846037747262023871255528268034582157143659719395250253747207361218900140
+    t0 = t0.square(); //         658: 321642007711641300995703695867085815912289746133201689917448988423187442359072334561909582054103692075494524047742511056536069164314287319438790500507494414722437800280
+    t0 = t0.square(); //         659: 643284015423282601991407391734171631824579492266403379834897976846374884718144669123819164108207384150989048095485022113072138328628574638877581001014988829444875600560
+    t0 = t0.square(); //         660: 1286568030846565203982814783468343263649158984532806759669795953692749769436289338247638328216414768301978096190970044226144276657257149277755162002029977658889751201120
+    t0 *= t17; //                661: 1286568030846565203982814783468343263649158984532806759669795953692749769436289338247638328216414768301978096190970044226144276657257149277755162002029977658889751201151
+    t0 = t0.square(); //         662: 2573136061693130407965629566936686527298317969065613519339591907385499538872578676495276656432829536603956192381940088452288553314514298555510324004059955317779502402302
+    t0 = t0.square(); //         663: 5146272123386260815931259133873373054596635938131227038679183814770999077745157352990553312865659073207912384763880176904577106629028597111020648008119910635559004804604
+    t0 = t0.square(); //         664: 10292544246772521631862518267746746109193271876262454077358367629541998155490314705981106625731318146415824769527760353809154213258057194222041296016239821271118009609208
+    t0 = t0.square(); //         665: 20585088493545043263725036535493492218386543752524908154716735259083996310980629411962213251462636292831649539055520707618308426516114388444082592032479642542236019218416
+    t0 = t0.square(); //         666: 41170176987090086527450073070986984436773087505049816309433470518167992621961258823924426502925272585663299078111041415236616853032228776888165184064959285084472038436832
+    t0 = t0.square(); //         667: 82340353974180173054900146141973968873546175010099632618866941036335985243922517647848853005850545171326598156222082830473233706064457553776330368129918570168944076873664
+    t0 *= t17; //                668: 82340353974180173054900146141973968873546175010099632618866941036335985243922517647848853005850545171326598156222082830473233706064457553776330368129918570168944076873695
+    t0 = t0.square(); //         669: 164680707948360346109800292283947937747092350020199265237733882072671970487845035295697706011701090342653196312444165660946467412128915107552660736259837140337888153747390
+    t0 = t0.square(); //         670: 329361415896720692219600584567895875494184700040398530475467764145343940975690070591395412023402180685306392624888331321892934824257830215105321472519674280675776307494780
+    t0 = t0.square(); //         671: 658722831793441384439201169135791750988369400080797060950935528290687881951380141182790824046804361370612785249776662643785869648515660430210642945039348561351552614989560
+    t0 = t0.square(); //         672: 1317445663586882768878402338271583501976738800161594121901871056581375763902760282365581648093608722741225570499553325287571739297031320860421285890078697122703105229979120
+    t0 = t0.square(); //         673: 2634891327173765537756804676543167003953477600323188243803742113162751527805520564731163296187217445482451140999106650575143478594062641720842571780157394245406210459958240
+    t0 *= t8; //                 674: 2634891327173765537756804676543167003953477600323188243803742113162751527805520564731163296187217445482451140999106650575143478594062641720842571780157394245406210459958253
+    t0 = t0.square(); //         675: 5269782654347531075513609353086334007906955200646376487607484226325503055611041129462326592374434890964902281998213301150286957188125283441685143560314788490812420919916506
+    t0 = t0.square(); //         676: 10539565308695062151027218706172668015813910401292752975214968452651006111222082258924653184748869781929804563996426602300573914376250566883370287120629576981624841839833012
+    t0 = t0.square(); //         677: 21079130617390124302054437412345336031627820802585505950429936905302012222444164517849306369497739563859609127992853204601147828752501133766740574241259153963249683679666024
+    t0 = t0.square(); //         678: 42158261234780248604108874824690672063255641605171011900859873810604024444888329035698612738995479127719218255985706409202295657505002267533481148482518307926499367359332048
+    t0 = t0.square(); //         679: 84316522469560497208217749649381344126511283210342023801719747621208048889776658071397225477990958255438436511971412818404591315010004535066962296965036615852998734718664096
+    t0 = t0.square(); //         680: 168633044939120994416435499298762688253022566420684047603439495242416097779553316142794450955981916510876873023942825636809182630020009070133924593930073231705997469437328192
+    t0 = t0.square(); //         681: 337266089878241988832870998597525376506045132841368095206878990484832195559106632285588901911963833021753746047885651273618365260040018140267849187860146463411994938874656384
+    t0 = t0.square(); //         682: 674532179756483977665741997195050753012090265682736190413757980969664391118213264571177803823927666043507492095771302547236730520080036280535698375720292926823989877749312768
+    t0 = t0.square(); //         683: 1349064359512967955331483994390101506024180531365472380827515961939328782236426529142355607647855332087014984191542605094473461040160072561071396751440585853647979755498625536
+    t0 = t0.square(); //         684: 2698128719025935910662967988780203012048361062730944761655031923878657564472853058284711215295710664174029968383085210188946922080320145122142793502881171707295959510997251072
+    t0 = t0.square(); //         685: 5396257438051871821325935977560406024096722125461889523310063847757315128945706116569422430591421328348059936766170420377893844160640290244285587005762343414591919021994502144
+    t0 = t0.square(); //         686: 10792514876103743642651871955120812048193444250923779046620127695514630257891412233138844861182842656696119873532340840755787688321280580488571174011524686829183838043989004288
+    t0 = t0.square(); //         687: 21585029752207487285303743910241624096386888501847558093240255391029260515782824466277689722365685313392239747064681681511575376642561160977142348023049373658367676087978008576
+    t0 *= t29; //                688: 21585029752207487285303743910241624096386888501847558093240255391029260515782824466277689722365685313392239747064681681511575376642561160977142348023049373658367676087978008631
+    t0 = t0.square(); //         689: 43170059504414974570607487820483248192773777003695116186480510782058521031565648932555379444731370626784479494129363363023150753285122321954284696046098747316735352175956017262
+    t0 = t0.square(); //         690: 86340119008829949141214975640966496385547554007390232372961021564117042063131297865110758889462741253568958988258726726046301506570244643908569392092197494633470704351912034524
+    t0 = t0.square(); //         691: 172680238017659898282429951281932992771095108014780464745922043128234084126262595730221517778925482507137917976517453452092603013140489287817138784184394989266941408703824069048
+    t0 = t0.square(); //         692: 345360476035319796564859902563865985542190216029560929491844086256468168252525191460443035557850965014275835953034906904185206026280978575634277568368789978533882817407648138096
+    t0 = t0.square(); //         693: 690720952070639593129719805127731971084380432059121858983688172512936336505050382920886071115701930028551671906069813808370412052561957151268555136737579957067765634815296276192
+    t0 = t0.square(); //         694: 1381441904141279186259439610255463942168760864118243717967376345025872673010100765841772142231403860057103343812139627616740824105123914302537110273475159914135531269630592552384
+    t0 = t0.square(); //         695: 2762883808282558372518879220510927884337521728236487435934752690051745346020201531683544284462807720114206687624279255233481648210247828605074220546950319828271062539261185104768
+    t0 = t0.square(); //         696: 5525767616565116745037758441021855768675043456472974871869505380103490692040403063367088568925615440228413375248558510466963296420495657210148441093900639656542125078522370209536
+    t0 *= t13; //                697: 5525767616565116745037758441021855768675043456472974871869505380103490692040403063367088568925615440228413375248558510466963296420495657210148441093900639656542125078522370209559
+    t0 = t0.square(); //         698: 11051535233130233490075516882043711537350086912945949743739010760206981384080806126734177137851230880456826750497117020933926592840991314420296882187801279313084250157044740419118
+    t0 = t0.square(); //         699: 22103070466260466980151033764087423074700173825891899487478021520413962768161612253468354275702461760913653500994234041867853185681982628840593764375602558626168500314089480838236
+    t0 = t0.square(); //         700: 44206140932520933960302067528174846149400347651783798974956043040827925536323224506936708551404923521827307001988468083735706371363965257681187528751205117252337000628178961676472
+    t0 = t0.square(); //         701: 88412281865041867920604135056349692298800695303567597949912086081655851072646449013873417102809847043654614003976936167471412742727930515362375057502410234504674001256357923352944
+    t0 = t0.square(); //         702: 176824563730083735841208270112699384597601390607135195899824172163311702145292898027746834205619694087309228007953872334942825485455861030724750115004820469009348002512715846705888
+    t0 = t0.square(); //         703: 353649127460167471682416540225398769195202781214270391799648344326623404290585796055493668411239388174618456015907744669885650970911722061449500230009640938018696005025431693411776
+    t0 = t0.square(); //         704: 707298254920334943364833080450797538390405562428540783599296688653246808581171592110987336822478776349236912031815489339771301941823444122899000460019281876037392010050863386823552
+    t0 *= t27; //                705: 707298254920334943364833080450797538390405562428540783599296688653246808581171592110987336822478776349236912031815489339771301941823444122899000460019281876037392010050863386823603
+    t0 = t0.square(); //         706: 1414596509840669886729666160901595076780811124857081567198593377306493617162343184221974673644957552698473824063630978679542603883646888245798000920038563752074784020101726773647206
+    t0 = t0.square(); //         707: 2829193019681339773459332321803190153561622249714163134397186754612987234324686368443949347289915105396947648127261957359085207767293776491596001840077127504149568040203453547294412
+    t0 = t0.square(); //         708: 5658386039362679546918664643606380307123244499428326268794373509225974468649372736887898694579830210793895296254523914718170415534587552983192003680154255008299136080406907094588824
+    t0 = t0.square(); //         709: 11316772078725359093837329287212760614246488998856652537588747018451948937298745473775797389159660421587790592509047829436340831069175105966384007360308510016598272160813814189177648
+    t0 = t0.square(); //         710: 22633544157450718187674658574425521228492977997713305075177494036903897874597490947551594778319320843175581185018095658872681662138350211932768014720617020033196544321627628378355296
+    t0 = t0.square(); //         711: 45267088314901436375349317148851042456985955995426610150354988073807795749194981895103189556638641686351162370036191317745363324276700423865536029441234040066393088643255256756710592
+    t0 *= t28; //                712: 45267088314901436375349317148851042456985955995426610150354988073807795749194981895103189556638641686351162370036191317745363324276700423865536029441234040066393088643255256756710645
+    t0 = t0.square(); //         713: 90534176629802872750698634297702084913971911990853220300709976147615591498389963790206379113277283372702324740072382635490726648553400847731072058882468080132786177286510513513421290
+    t0 = t0.square(); //         714: 181068353259605745501397268595404169827943823981706440601419952295231182996779927580412758226554566745404649480144765270981453297106801695462144117764936160265572354573021027026842580
+    t0 = t0.square(); //         715: 362136706519211491002794537190808339655887647963412881202839904590462365993559855160825516453109133490809298960289530541962906594213603390924288235529872320531144709146042054053685160
+    t0 = t0.square(); //         716: 724273413038422982005589074381616679311775295926825762405679809180924731987119710321651032906218266981618597920579061083925813188427206781848576471059744641062289418292084108107370320
+    t0 = t0.square(); //         717: 1448546826076845964011178148763233358623550591853651524811359618361849463974239420643302065812436533963237195841158122167851626376854413563697152942119489282124578836584168216214740640
+    t0 = t0.square(); //         718: 2897093652153691928022356297526466717247101183707303049622719236723698927948478841286604131624873067926474391682316244335703252753708827127394305884238978564249157673168336432429481280
+    t0 *= t21; //                719: 2897093652153691928022356297526466717247101183707303049622719236723698927948478841286604131624873067926474391682316244335703252753708827127394305884238978564249157673168336432429481319
+    t0 = t0.square(); //         720: 5794187304307383856044712595052933434494202367414606099245438473447397855896957682573208263249746135852948783364632488671406505507417654254788611768477957128498315346336672864858962638
+    t0 = t0.square(); //         721: 11588374608614767712089425190105866868988404734829212198490876946894795711793915365146416526499492271705897566729264977342813011014835308509577223536955914256996630692673345729717925276
+    t0 = t0.square(); //         722: 23176749217229535424178850380211733737976809469658424396981753893789591423587830730292833052998984543411795133458529954685626022029670617019154447073911828513993261385346691459435850552
+    t0 = t0.square(); //         723: 46353498434459070848357700760423467475953618939316848793963507787579182847175661460585666105997969086823590266917059909371252044059341234038308894147823657027986522770693382918871701104
+    t0 = t0.square(); //         724: 92706996868918141696715401520846934951907237878633697587927015575158365694351322921171332211995938173647180533834119818742504088118682468076617788295647314055973045541386765837743402208
+    t0 = t0.square(); //         725: 185413993737836283393430803041693869903814475757267395175854031150316731388702645842342664423991876347294361067668239637485008176237364936153235576591294628111946091082773531675486804416
+    t0 = t0.square(); //         726: 370827987475672566786861606083387739807628951514534790351708062300633462777405291684685328847983752694588722135336479274970016352474729872306471153182589256223892182165547063350973608832
+    t0 = t0.square(); //         727: 741655974951345133573723212166775479615257903029069580703416124601266925554810583369370657695967505389177444270672958549940032704949459744612942306365178512447784364331094126701947217664
+    t0 = t0.square(); //         728: 1483311949902690267147446424333550959230515806058139161406832249202533851109621166738741315391935010778354888541345917099880065409898919489225884612730357024895568728662188253403894435328
+    t0 = t0.square(); //         729: 2966623899805380534294892848667101918461031612116278322813664498405067702219242333477482630783870021556709777082691834199760130819797838978451769225460714049791137457324376506807788870656
+    t0 *= t30; //                730: 2966623899805380534294892848667101918461031612116278322813664498405067702219242333477482630783870021556709777082691834199760130819797838978451769225460714049791137457324376506807788870713
+    t0 = t0.square(); //         731: 5933247799610761068589785697334203836922063224232556645627328996810135404438484666954965261567740043113419554165383668399520261639595677956903538450921428099582274914648753013615577741426
+    t0 = t0.square(); //         732: 11866495599221522137179571394668407673844126448465113291254657993620270808876969333909930523135480086226839108330767336799040523279191355913807076901842856199164549829297506027231155482852
+    t0 = t0.square(); //         733: 23732991198443044274359142789336815347688252896930226582509315987240541617753938667819861046270960172453678216661534673598081046558382711827614153803685712398329099658595012054462310965704
+    t0 = t0.square(); //         734: 47465982396886088548718285578673630695376505793860453165018631974481083235507877335639722092541920344907356433323069347196162093116765423655228307607371424796658199317190024108924621931408
+    t0 = t0.square(); //         735: 94931964793772177097436571157347261390753011587720906330037263948962166471015754671279444185083840689814712866646138694392324186233530847310456615214742849593316398634380048217849243862816
+    t0 = t0.square(); //         736: 189863929587544354194873142314694522781506023175441812660074527897924332942031509342558888370167681379629425733292277388784648372467061694620913230429485699186632797268760096435698487725632
+    t0 = t0.square(); //         737: 379727859175088708389746284629389045563012046350883625320149055795848665884063018685117776740335362759258851466584554777569296744934123389241826460858971398373265594537520192871396975451264
+    t0 *= t24; //                738: 379727859175088708389746284629389045563012046350883625320149055795848665884063018685117776740335362759258851466584554777569296744934123389241826460858971398373265594537520192871396975451309
+    t0 = t0.square(); //         739: 759455718350177416779492569258778091126024092701767250640298111591697331768126037370235553480670725518517702933169109555138593489868246778483652921717942796746531189075040385742793950902618
+    t0 = t0.square(); //         740: 1518911436700354833558985138517556182252048185403534501280596223183394663536252074740471106961341451037035405866338219110277186979736493556967305843435885593493062378150080771485587901805236
+    t0 = t0.square(); //         741: 3037822873400709667117970277035112364504096370807069002561192446366789327072504149480942213922682902074070811732676438220554373959472987113934611686871771186986124756300161542971175803610472
+    t0 = t0.square(); //         742: 6075645746801419334235940554070224729008192741614138005122384892733578654145008298961884427845365804148141623465352876441108747918945974227869223373743542373972249512600323085942351607220944
+    t0 = t0.square(); //         743: 12151291493602838668471881108140449458016385483228276010244769785467157308290016597923768855690731608296283246930705752882217495837891948455738446747487084747944499025200646171884703214441888
+    t0 *= t15; //                744: 12151291493602838668471881108140449458016385483228276010244769785467157308290016597923768855690731608296283246930705752882217495837891948455738446747487084747944499025200646171884703214441915
+    t0 = t0.square(); //         745: 24302582987205677336943762216280898916032770966456552020489539570934314616580033195847537711381463216592566493861411505764434991675783896911476893494974169495888998050401292343769406428883830
+    t0 = t0.square(); //         746: 48605165974411354673887524432561797832065541932913104040979079141868629233160066391695075422762926433185132987722823011528869983351567793822953786989948338991777996100802584687538812857767660
+    t0 = t0.square(); //         747: 97210331948822709347775048865123595664131083865826208081958158283737258466320132783390150845525852866370265975445646023057739966703135587645907573979896677983555992201605169375077625715535320
+    t0 = t0.square(); //         748: 194420663897645418695550097730247191328262167731652416163916316567474516932640265566780301691051705732740531950891292046115479933406271175291815147959793355967111984403210338750155251431070640
+    t0 = t0.square(); //         749: 388841327795290837391100195460494382656524335463304832327832633134949033865280531133560603382103411465481063901782584092230959866812542350583630295919586711934223968806420677500310502862141280
+    t0 = t0.square(); //         750: 777682655590581674782200390920988765313048670926609664655665266269898067730561062267121206764206822930962127803565168184461919733625084701167260591839173423868447937612841355000621005724282560
+    t0 = t0.square(); //         751: 1555365311181163349564400781841977530626097341853219329311330532539796135461122124534242413528413645861924255607130336368923839467250169402334521183678346847736895875225682710001242011448565120
+    t0 = t0.square(); //         752: 3110730622362326699128801563683955061252194683706438658622661065079592270922244249068484827056827291723848511214260672737847678934500338804669042367356693695473791750451365420002484022897130240
+    t0 *= t19; //                753: 3110730622362326699128801563683955061252194683706438658622661065079592270922244249068484827056827291723848511214260672737847678934500338804669042367356693695473791750451365420002484022897130275
+    t0 = t0.square(); //         754: 6221461244724653398257603127367910122504389367412877317245322130159184541844488498136969654113654583447697022428521345475695357869000677609338084734713387390947583500902730840004968045794260550
+    t0 = t0.square(); //         755: 12442922489449306796515206254735820245008778734825754634490644260318369083688976996273939308227309166895394044857042690951390715738001355218676169469426774781895167001805461680009936091588521100
+    t0 = t0.square(); //         756: 24885844978898613593030412509471640490017557469651509268981288520636738167377953992547878616454618333790788089714085381902781431476002710437352338938853549563790334003610923360019872183177042200
+    t0 = t0.square(); //         757: 49771689957797227186060825018943280980035114939303018537962577041273476334755907985095757232909236667581576179428170763805562862952005420874704677877707099127580668007221846720039744366354084400
+    t0 *= t9; //                 758: 49771689957797227186060825018943280980035114939303018537962577041273476334755907985095757232909236667581576179428170763805562862952005420874704677877707099127580668007221846720039744366354084415
+    t0 = t0.square(); //         759: 99543379915594454372121650037886561960070229878606037075925154082546952669511815970191514465818473335163152358856341527611125725904010841749409355755414198255161336014443693440079488732708168830
+    t0 = t0.square(); //         760: 199086759831188908744243300075773123920140459757212074151850308165093905339023631940383028931636946670326304717712683055222251451808021683498818711510828396510322672028887386880158977465416337660
+    t0 = t0.square(); //         761: 398173519662377817488486600151546247840280919514424148303700616330187810678047263880766057863273893340652609435425366110444502903616043366997637423021656793020645344057774773760317954930832675320
+    t0 = t0.square(); //         762: 796347039324755634976973200303092495680561839028848296607401232660375621356094527761532115726547786681305218870850732220889005807232086733995274846043313586041290688115549547520635909861665350640
+    t0 = t0.square(); //         763: 1592694078649511269953946400606184991361123678057696593214802465320751242712189055523064231453095573362610437741701464441778011614464173467990549692086627172082581376231099095041271819723330701280
+    t0 = t0.square(); //         764: 3185388157299022539907892801212369982722247356115393186429604930641502485424378111046128462906191146725220875483402928883556023228928346935981099384173254344165162752462198190082543639446661402560
+    t0 = t0.square(); //         765: 6370776314598045079815785602424739965444494712230786372859209861283004970848756222092256925812382293450441750966805857767112046457856693871962198768346508688330325504924396380165087278893322805120
+    t0 = t0.square(); //         766: 12741552629196090159631571204849479930888989424461572745718419722566009941697512444184513851624764586900883501933611715534224092915713387743924397536693017376660651009848792760330174557786645610240
+    t0 = t0.square(); //         767: 25483105258392180319263142409698959861777978848923145491436839445132019883395024888369027703249529173801767003867223431068448185831426775487848795073386034753321302019697585520660349115573291220480
+    t0 = t0.square(); //         768: 50966210516784360638526284819397919723555957697846290982873678890264039766790049776738055406499058347603534007734446862136896371662853550975697590146772069506642604039395171041320698231146582440960
+    t0 *= t25; //                769: 50966210516784360638526284819397919723555957697846290982873678890264039766790049776738055406499058347603534007734446862136896371662853550975697590146772069506642604039395171041320698231146582441007
+    t0 = t0.square(); //         770: 101932421033568721277052569638795839447111915395692581965747357780528079533580099553476110812998116695207068015468893724273792743325707101951395180293544139013285208078790342082641396462293164882014
+    t0 = t0.square(); //         771: 203864842067137442554105139277591678894223830791385163931494715561056159067160199106952221625996233390414136030937787448547585486651414203902790360587088278026570416157580684165282792924586329764028
+    t0 *= t2; //                 772: 203864842067137442554105139277591678894223830791385163931494715561056159067160199106952221625996233390414136030937787448547585486651414203902790360587088278026570416157580684165282792924586329764031
+    t0 = t0.square(); //         773: 407729684134274885108210278555183357788447661582770327862989431122112318134320398213904443251992466780828272061875574897095170973302828407805580721174176556053140832315161368330565585849172659528062
+    t0 = t0.square(); //         774: 815459368268549770216420557110366715576895323165540655725978862244224636268640796427808886503984933561656544123751149794190341946605656815611161442348353112106281664630322736661131171698345319056124
+    t0 = t0.square(); //         775: 1630918736537099540432841114220733431153790646331081311451957724488449272537281592855617773007969867123313088247502299588380683893211313631222322884696706224212563329260645473322262343396690638112248
+    t0 = t0.square(); //         776: 3261837473074199080865682228441466862307581292662162622903915448976898545074563185711235546015939734246626176495004599176761367786422627262444645769393412448425126658521290946644524686793381276224496
+    t0 = t0.square(); //         777: 6523674946148398161731364456882933724615162585324325245807830897953797090149126371422471092031879468493252352990009198353522735572845254524889291538786824896850253317042581893289049373586762552448992
+    t0 = t0.square(); //         778: 13047349892296796323462728913765867449230325170648650491615661795907594180298252742844942184063758936986504705980018396707045471145690509049778583077573649793700506634085163786578098747173525104897984
+    t0 = t0.square(); //         779: 26094699784593592646925457827531734898460650341297300983231323591815188360596505485689884368127517873973009411960036793414090942291381018099557166155147299587401013268170327573156197494347050209795968
+    t0 = t0.square(); //         780: 52189399569187185293850915655063469796921300682594601966462647183630376721193010971379768736255035747946018823920073586828181884582762036199114332310294599174802026536340655146312394988694100419591936
+    t0 = t0.square(); //         781: 104378799138374370587701831310126939593842601365189203932925294367260753442386021942759537472510071495892037647840147173656363769165524072398228664620589198349604053072681310292624789977388200839183872
+    t0 = t0.square(); //         782: 208757598276748741175403662620253879187685202730378407865850588734521506884772043885519074945020142991784075295680294347312727538331048144796457329241178396699208106145362620585249579954776401678367744
+    t0 *= t24; //                783: 208757598276748741175403662620253879187685202730378407865850588734521506884772043885519074945020142991784075295680294347312727538331048144796457329241178396699208106145362620585249579954776401678367789
+    t0 = t0.square(); //         784: 417515196553497482350807325240507758375370405460756815731701177469043013769544087771038149890040285983568150591360588694625455076662096289592914658482356793398416212290725241170499159909552803356735578
+    t0 = t0.square(); //         785: 835030393106994964701614650481015516750740810921513631463402354938086027539088175542076299780080571967136301182721177389250910153324192579185829316964713586796832424581450482340998319819105606713471156
+    t0 = t0.square(); //         786: 1670060786213989929403229300962031033501481621843027262926804709876172055078176351084152599560161143934272602365442354778501820306648385158371658633929427173593664849162900964681996639638211213426942312
+    t0 = t0.square(); //         787: 3340121572427979858806458601924062067002963243686054525853609419752344110156352702168305199120322287868545204730884709557003640613296770316743317267858854347187329698325801929363993279276422426853884624
+    t0 = t0.square(); //         788: 6680243144855959717612917203848124134005926487372109051707218839504688220312705404336610398240644575737090409461769419114007281226593540633486634535717708694374659396651603858727986558552844853707769248
+    t0 *= t15; //                789: 6680243144855959717612917203848124134005926487372109051707218839504688220312705404336610398240644575737090409461769419114007281226593540633486634535717708694374659396651603858727986558552844853707769275
+    t0 = t0.square(); //         790: 13360486289711919435225834407696248268011852974744218103414437679009376440625410808673220796481289151474180818923538838228014562453187081266973269071435417388749318793303207717455973117105689707415538550
+    t0 = t0.square(); //         791: 26720972579423838870451668815392496536023705949488436206828875358018752881250821617346441592962578302948361637847077676456029124906374162533946538142870834777498637586606415434911946234211379414831077100
+    t0 = t0.square(); //         792: 53441945158847677740903337630784993072047411898976872413657750716037505762501643234692883185925156605896723275694155352912058249812748325067893076285741669554997275173212830869823892468422758829662154200
+    t0 = t0.square(); //         793: 106883890317695355481806675261569986144094823797953744827315501432075011525003286469385766371850313211793446551388310705824116499625496650135786152571483339109994550346425661739647784936845517659324308400
+    t0 = t0.square(); //         794: 213767780635390710963613350523139972288189647595907489654631002864150023050006572938771532743700626423586893102776621411648232999250993300271572305142966678219989100692851323479295569873691035318648616800
+    t0 = t0.square(); //         795: 427535561270781421927226701046279944576379295191814979309262005728300046100013145877543065487401252847173786205553242823296465998501986600543144610285933356439978201385702646958591139747382070637297233600
+    t0 = t0.square(); //         796: 855071122541562843854453402092559889152758590383629958618524011456600092200026291755086130974802505694347572411106485646592931997003973201086289220571866712879956402771405293917182279494764141274594467200
+    t0 = t0.square(); //         797: 1710142245083125687708906804185119778305517180767259917237048022913200184400052583510172261949605011388695144822212971293185863994007946402172578441143733425759912805542810587834364558989528282549188934400
+    t0 *= t27; //                798: 1710142245083125687708906804185119778305517180767259917237048022913200184400052583510172261949605011388695144822212971293185863994007946402172578441143733425759912805542810587834364558989528282549188934451
+    t0 = t0.square(); //         799: 3420284490166251375417813608370239556611034361534519834474096045826400368800105167020344523899210022777390289644425942586371727988015892804345156882287466851519825611085621175668729117979056565098377868902
+    t0 = t0.square(); //         800: 6840568980332502750835627216740479113222068723069039668948192091652800737600210334040689047798420045554780579288851885172743455976031785608690313764574933703039651222171242351337458235958113130196755737804
+    t0 = t0.square(); //         801: 13681137960665005501671254433480958226444137446138079337896384183305601475200420668081378095596840091109561158577703770345486911952063571217380627529149867406079302444342484702674916471916226260393511475608
+    t0 = t0.square(); //         802: 27362275921330011003342508866961916452888274892276158675792768366611202950400841336162756191193680182219122317155407540690973823904127142434761255058299734812158604888684969405349832943832452520787022951216
+    t0 = t0.square(); //         803: 54724551842660022006685017733923832905776549784552317351585536733222405900801682672325512382387360364438244634310815081381947647808254284869522510116599469624317209777369938810699665887664905041574045902432
+    t0 = t0.square(); //         804: 109449103685320044013370035467847665811553099569104634703171073466444811801603365344651024764774720728876489268621630162763895295616508569739045020233198939248634419554739877621399331775329810083148091804864
+    t0 = t0.square(); //         805: 218898207370640088026740070935695331623106199138209269406342146932889623603206730689302049529549441457752978537243260325527790591233017139478090040466397878497268839109479755242798663550659620166296183609728
+    t0 = t0.square(); //         806: 437796414741280176053480141871390663246212398276418538812684293865779247206413461378604099059098882915505957074486520651055581182466034278956180080932795756994537678218959510485597327101319240332592367219456
+    t0 *= t27; //                807: 437796414741280176053480141871390663246212398276418538812684293865779247206413461378604099059098882915505957074486520651055581182466034278956180080932795756994537678218959510485597327101319240332592367219507
+    t0 = t0.square(); //         808: 875592829482560352106960283742781326492424796552837077625368587731558494412826922757208198118197765831011914148973041302111162364932068557912360161865591513989075356437919020971194654202638480665184734439014
+    t0 = t0.square(); //         809: 1751185658965120704213920567485562652984849593105674155250737175463116988825653845514416396236395531662023828297946082604222324729864137115824720323731183027978150712875838041942389308405276961330369468878028
+    t0 = t0.square(); //         810: 3502371317930241408427841134971125305969699186211348310501474350926233977651307691028832792472791063324047656595892165208444649459728274231649440647462366055956301425751676083884778616810553922660738937756056
+    t0 = t0.square(); //         811: 7004742635860482816855682269942250611939398372422696621002948701852467955302615382057665584945582126648095313191784330416889298919456548463298881294924732111912602851503352167769557233621107845321477875512112
+    t0 = t0.square(); //         812: 14009485271720965633711364539884501223878796744845393242005897403704935910605230764115331169891164253296190626383568660833778597838913096926597762589849464223825205703006704335539114467242215690642955751024224
+    t0 = t0.square(); //         813: 28018970543441931267422729079769002447757593489690786484011794807409871821210461528230662339782328506592381252767137321667557195677826193853195525179698928447650411406013408671078228934484431381285911502048448
+    t0 = t0.square(); //         814: 56037941086883862534845458159538004895515186979381572968023589614819743642420923056461324679564657013184762505534274643335114391355652387706391050359397856895300822812026817342156457868968862762571823004096896
+    t0 *= t24; //                815: 56037941086883862534845458159538004895515186979381572968023589614819743642420923056461324679564657013184762505534274643335114391355652387706391050359397856895300822812026817342156457868968862762571823004096941
+    t0 = t0.square(); //         816: 112075882173767725069690916319076009791030373958763145936047179229639487284841846112922649359129314026369525011068549286670228782711304775412782100718795713790601645624053634684312915737937725525143646008193882
+    t0 = t0.square(); //         817: 224151764347535450139381832638152019582060747917526291872094358459278974569683692225845298718258628052739050022137098573340457565422609550825564201437591427581203291248107269368625831475875451050287292016387764
+    t0 = t0.square(); //         818: 448303528695070900278763665276304039164121495835052583744188716918557949139367384451690597436517256105478100044274197146680915130845219101651128402875182855162406582496214538737251662951750902100574584032775528
+    t0 = t0.square(); //         819: 896607057390141800557527330552608078328242991670105167488377433837115898278734768903381194873034512210956200088548394293361830261690438203302256805750365710324813164992429077474503325903501804201149168065551056
+    t0 = t0.square(); //         820: 1793214114780283601115054661105216156656485983340210334976754867674231796557469537806762389746069024421912400177096788586723660523380876406604513611500731420649626329984858154949006651807003608402298336131102112
+    t0 = t0.square(); //         821: 3586428229560567202230109322210432313312971966680420669953509735348463593114939075613524779492138048843824800354193577173447321046761752813209027223001462841299252659969716309898013303614007216804596672262204224
+    t0 = t0.square(); //         822: 7172856459121134404460218644420864626625943933360841339907019470696927186229878151227049558984276097687649600708387154346894642093523505626418054446002925682598505319939432619796026607228014433609193344524408448
+    t0 *= t17; //                823: 7172856459121134404460218644420864626625943933360841339907019470696927186229878151227049558984276097687649600708387154346894642093523505626418054446002925682598505319939432619796026607228014433609193344524408479
+    t0 = t0.square(); //         824: 14345712918242268808920437288841729253251887866721682679814038941393854372459756302454099117968552195375299201416774308693789284187047011252836108892005851365197010639878865239592053214456028867218386689048816958
+    t0 = t0.square(); //         825: 28691425836484537617840874577683458506503775733443365359628077882787708744919512604908198235937104390750598402833548617387578568374094022505672217784011702730394021279757730479184106428912057734436773378097633916
+    t0 = t0.square(); //         826: 57382851672969075235681749155366917013007551466886730719256155765575417489839025209816396471874208781501196805667097234775157136748188045011344435568023405460788042559515460958368212857824115468873546756195267832
+    t0 = t0.square(); //         827: 114765703345938150471363498310733834026015102933773461438512311531150834979678050419632792943748417563002393611334194469550314273496376090022688871136046810921576085119030921916736425715648230937747093512390535664
+    t0 = t0.square(); //         828: 229531406691876300942726996621467668052030205867546922877024623062301669959356100839265585887496835126004787222668388939100628546992752180045377742272093621843152170238061843833472851431296461875494187024781071328
+    t0 = t0.square(); //         829: 459062813383752601885453993242935336104060411735093845754049246124603339918712201678531171774993670252009574445336777878201257093985504360090755484544187243686304340476123687666945702862592923750988374049562142656
+    t0 = t0.square(); //         830: 918125626767505203770907986485870672208120823470187691508098492249206679837424403357062343549987340504019148890673555756402514187971008720181510969088374487372608680952247375333891405725185847501976748099124285312
+    t0 = t0.square(); //         831: 1836251253535010407541815972971741344416241646940375383016196984498413359674848806714124687099974681008038297781347111512805028375942017440363021938176748974745217361904494750667782811450371695003953496198248570624
+    t0 *= t20; //                832: 1836251253535010407541815972971741344416241646940375383016196984498413359674848806714124687099974681008038297781347111512805028375942017440363021938176748974745217361904494750667782811450371695003953496198248570661
+    t0 = t0.square(); //         833: 3672502507070020815083631945943482688832483293880750766032393968996826719349697613428249374199949362016076595562694223025610056751884034880726043876353497949490434723808989501335565622900743390007906992396497141322
+    t0 = t0.square(); //         834: 7345005014140041630167263891886965377664966587761501532064787937993653438699395226856498748399898724032153191125388446051220113503768069761452087752706995898980869447617979002671131245801486780015813984792994282644
+    t0 = t0.square(); //         835: 14690010028280083260334527783773930755329933175523003064129575875987306877398790453712997496799797448064306382250776892102440227007536139522904175505413991797961738895235958005342262491602973560031627969585988565288
+    t0 *= t2; //                 836: 14690010028280083260334527783773930755329933175523003064129575875987306877398790453712997496799797448064306382250776892102440227007536139522904175505413991797961738895235958005342262491602973560031627969585988565291
+    t0 = t0.square(); //         837: 29380020056560166520669055567547861510659866351046006128259151751974613754797580907425994993599594896128612764501553784204880454015072279045808351010827983595923477790471916010684524983205947120063255939171977130582
+    t0 = t0.square(); //         838: 58760040113120333041338111135095723021319732702092012256518303503949227509595161814851989987199189792257225529003107568409760908030144558091616702021655967191846955580943832021369049966411894240126511878343954261164
+    t0 = t0.square(); //         839: 117520080226240666082676222270191446042639465404184024513036607007898455019190323629703979974398379584514451058006215136819521816060289116183233404043311934383693911161887664042738099932823788480253023756687908522328
+    t0 = t0.square(); //         840: 235040160452481332165352444540382892085278930808368049026073214015796910038380647259407959948796759169028902116012430273639043632120578232366466808086623868767387822323775328085476199865647576960506047513375817044656
+    t0 = t0.square(); //         841: 470080320904962664330704889080765784170557861616736098052146428031593820076761294518815919897593518338057804232024860547278087264241156464732933616173247737534775644647550656170952399731295153921012095026751634089312
+    t0 = t0.square(); //         842: 940160641809925328661409778161531568341115723233472196104292856063187640153522589037631839795187036676115608464049721094556174528482312929465867232346495475069551289295101312341904799462590307842024190053503268178624
+    t0 = t0.square(); //         843: 1880321283619850657322819556323063136682231446466944392208585712126375280307045178075263679590374073352231216928099442189112349056964625858931734464692990950139102578590202624683809598925180615684048380107006536357248
+    t0 = t0.square(); //         844: 3760642567239701314645639112646126273364462892933888784417171424252750560614090356150527359180748146704462433856198884378224698113929251717863468929385981900278205157180405249367619197850361231368096760214013072714496
+    t0 = t0.square(); //         845: 7521285134479402629291278225292252546728925785867777568834342848505501121228180712301054718361496293408924867712397768756449396227858503435726937858771963800556410314360810498735238395700722462736193520428026145428992
+    t0 = t0.square(); //         846: 15042570268958805258582556450584505093457851571735555137668685697011002242456361424602109436722992586817849735424795537512898792455717006871453875717543927601112820628721620997470476791401444925472387040856052290857984
+    t0 = t0.square(); //         847: 30085140537917610517165112901169010186915703143471110275337371394022004484912722849204218873445985173635699470849591075025797584911434013742907751435087855202225641257443241994940953582802889850944774081712104581715968
+    t0 = t0.square(); //         848: 60170281075835221034330225802338020373831406286942220550674742788044008969825445698408437746891970347271398941699182150051595169822868027485815502870175710404451282514886483989881907165605779701889548163424209163431936
+    t0 = t0.square(); //         849: 120340562151670442068660451604676040747662812573884441101349485576088017939650891396816875493783940694542797883398364300103190339645736054971631005740351420808902565029772967979763814331211559403779096326848418326863872
+    t0 = t0.square(); //         850: 240681124303340884137320903209352081495325625147768882202698971152176035879301782793633750987567881389085595766796728600206380679291472109943262011480702841617805130059545935959527628662423118807558192653696836653727744
+    t0 = t0.square(); //         851: 481362248606681768274641806418704162990651250295537764405397942304352071758603565587267501975135762778171191533593457200412761358582944219886524022961405683235610260119091871919055257324846237615116385307393673307455488
+    t0 = t0.square(); //         852: 962724497213363536549283612837408325981302500591075528810795884608704143517207131174535003950271525556342383067186914400825522717165888439773048045922811366471220520238183743838110514649692475230232770614787346614910976
+    t0 = t0.square(); //         853: 1925448994426727073098567225674816651962605001182151057621591769217408287034414262349070007900543051112684766134373828801651045434331776879546096091845622732942441040476367487676221029299384950460465541229574693229821952
+    t0 = t0.square(); //         854: 3850897988853454146197134451349633303925210002364302115243183538434816574068828524698140015801086102225369532268747657603302090868663553759092192183691245465884882080952734975352442058598769900920931082459149386459643904
+    t0 = t0.square(); //         855: 7701795977706908292394268902699266607850420004728604230486367076869633148137657049396280031602172204450739064537495315206604181737327107518184384367382490931769764161905469950704884117197539801841862164918298772919287808
+    t0 = t0.square(); //         856: 15403591955413816584788537805398533215700840009457208460972734153739266296275314098792560063204344408901478129074990630413208363474654215036368768734764981863539528323810939901409768234395079603683724329836597545838575616
+    t0 = t0.square(); //         857: 30807183910827633169577075610797066431401680018914416921945468307478532592550628197585120126408688817802956258149981260826416726949308430072737537469529963727079056647621879802819536468790159207367448659673195091677151232
+    t0 = t0.square(); //         858: 61614367821655266339154151221594132862803360037828833843890936614957065185101256395170240252817377635605912516299962521652833453898616860145475074939059927454158113295243759605639072937580318414734897319346390183354302464
+    t0 = t0.square(); //         859: 123228735643310532678308302443188265725606720075657667687781873229914130370202512790340480505634755271211825032599925043305666907797233720290950149878119854908316226590487519211278145875160636829469794638692780366708604928
+    t0 = t0.square(); //         860: 246457471286621065356616604886376531451213440151315335375563746459828260740405025580680961011269510542423650065199850086611333815594467440581900299756239709816632453180975038422556291750321273658939589277385560733417209856
+    t0 = t0.square(); //         861: 492914942573242130713233209772753062902426880302630670751127492919656521480810051161361922022539021084847300130399700173222667631188934881163800599512479419633264906361950076845112583500642547317879178554771121466834419712
+    t0 = t0.square(); //         862: 985829885146484261426466419545506125804853760605261341502254985839313042961620102322723844045078042169694600260799400346445335262377869762327601199024958839266529812723900153690225167001285094635758357109542242933668839424
+    t0 = t0.square(); //         863: 1971659770292968522852932839091012251609707521210522683004509971678626085923240204645447688090156084339389200521598800692890670524755739524655202398049917678533059625447800307380450334002570189271516714219084485867337678848
+    t0 = t0.square(); //         864: 3943319540585937045705865678182024503219415042421045366009019943357252171846480409290895376180312168678778401043197601385781341049511479049310404796099835357066119250895600614760900668005140378543033428438168971734675357696
+    t0 = t0.square(); //         865: 7886639081171874091411731356364049006438830084842090732018039886714504343692960818581790752360624337357556802086395202771562682099022958098620809592199670714132238501791201229521801336010280757086066856876337943469350715392
+    t0 = t0.square(); //         866: 15773278162343748182823462712728098012877660169684181464036079773429008687385921637163581504721248674715113604172790405543125364198045916197241619184399341428264477003582402459043602672020561514172133713752675886938701430784
+    t0 = t0.square(); //         867: 31546556324687496365646925425456196025755320339368362928072159546858017374771843274327163009442497349430227208345580811086250728396091832394483238368798682856528954007164804918087205344041123028344267427505351773877402861568
+    t0 = t0.square(); //         868: 63093112649374992731293850850912392051510640678736725856144319093716034749543686548654326018884994698860454416691161622172501456792183664788966476737597365713057908014329609836174410688082246056688534855010703547754805723136
+    t0 = t0.square(); //         869: 126186225298749985462587701701824784103021281357473451712288638187432069499087373097308652037769989397720908833382323244345002913584367329577932953475194731426115816028659219672348821376164492113377069710021407095509611446272
+    t0 = t0.square(); //         870: 252372450597499970925175403403649568206042562714946903424577276374864138998174746194617304075539978795441817666764646488690005827168734659155865906950389462852231632057318439344697642752328984226754139420042814191019222892544
+    t0 = t0.square(); //         871: 504744901194999941850350806807299136412085125429893806849154552749728277996349492389234608151079957590883635333529292977380011654337469318311731813900778925704463264114636878689395285504657968453508278840085628382038445785088
+    t0 = t0.square(); //         872: 1009489802389999883700701613614598272824170250859787613698309105499456555992698984778469216302159915181767270667058585954760023308674938636623463627801557851408926528229273757378790571009315936907016557680171256764076891570176
+    t0 = t0.square(); //         873: 2018979604779999767401403227229196545648340501719575227396618210998913111985397969556938432604319830363534541334117171909520046617349877273246927255603115702817853056458547514757581142018631873814033115360342513528153783140352
+    t0 = t0.square(); //         874: 4037959209559999534802806454458393091296681003439150454793236421997826223970795939113876865208639660727069082668234343819040093234699754546493854511206231405635706112917095029515162284037263747628066230720685027056307566280704
+    t0 = t0.square(); //         875: 8075918419119999069605612908916786182593362006878300909586472843995652447941591878227753730417279321454138165336468687638080186469399509092987709022412462811271412225834190059030324568074527495256132461441370054112615132561408
+    t0 = t0.square(); //         876: 16151836838239998139211225817833572365186724013756601819172945687991304895883183756455507460834558642908276330672937375276160372938799018185975418044824925622542824451668380118060649136149054990512264922882740108225230265122816
+    t0 = t0.square(); //         877: 32303673676479996278422451635667144730373448027513203638345891375982609791766367512911014921669117285816552661345874750552320745877598036371950836089649851245085648903336760236121298272298109981024529845765480216450460530245632
+    t0 = t0.square(); //         878: 64607347352959992556844903271334289460746896055026407276691782751965219583532735025822029843338234571633105322691749501104641491755196072743901672179299702490171297806673520472242596544596219962049059691530960432900921060491264
+    t0 = t0.square(); //         879: 129214694705919985113689806542668578921493792110052814553383565503930439167065470051644059686676469143266210645383499002209282983510392145487803344358599404980342595613347040944485193089192439924098119383061920865801842120982528
+    t0 = t0.square(); //         880: 258429389411839970227379613085337157842987584220105629106767131007860878334130940103288119373352938286532421290766998004418565967020784290975606688717198809960685191226694081888970386178384879848196238766123841731603684241965056
+    t0 = t0.square(); //         881: 516858778823679940454759226170674315685975168440211258213534262015721756668261880206576238746705876573064842581533996008837131934041568581951213377434397619921370382453388163777940772356769759696392477532247683463207368483930112
+    t0 = t0.square(); //         882: 1033717557647359880909518452341348631371950336880422516427068524031443513336523760413152477493411753146129685163067992017674263868083137163902426754868795239842740764906776327555881544713539519392784955064495366926414736967860224
+    t0 = t0.square(); //         883: 2067435115294719761819036904682697262743900673760845032854137048062887026673047520826304954986823506292259370326135984035348527736166274327804853509737590479685481529813552655111763089427079038785569910128990733852829473935720448
+    t0 *= t22; //                884: 2067435115294719761819036904682697262743900673760845032854137048062887026673047520826304954986823506292259370326135984035348527736166274327804853509737590479685481529813552655111763089427079038785569910128990733852829473935720489
+    t0 = t0.square(); //         885: 4134870230589439523638073809365394525487801347521690065708274096125774053346095041652609909973647012584518740652271968070697055472332548655609707019475180959370963059627105310223526178854158077571139820257981467705658947871440978
+    t0 * x //                    886: 4134870230589439523638073809365394525487801347521690065708274096125774053346095041652609909973647012584518740652271968070697055472332548655609707019475180959370963059627105310223526178854158077571139820257981467705658947871440979
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{Field, UniformRand};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_alpha() {
+        for _ in 0..100 {
+            let a = Felt::rand(&mut OsRng);
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+        }
+    }
+}
@@ -0,0 +1,1041 @@
+//! Generation of the constants of Anemoi instantiations.
+//!
+//! All constants are derived from a generator `g` of the multiplicative group
+//! of the underlying prime field, and from the digits of π, following the procedure
+//! of the reference implementation:
+//!
+//! - the S-Box multiplier `β` is `g`, and its first added constant `δ` is `g^-1`;
+//! - the round constants are, for round `r` and column `i`,
+//!   `C[r][i] = g * π_0^2r + (π_0^r + π_1^i)^α` and
+//!   `D[r][i] = g * π_1^2i + (π_0^r + π_1^i)^α + δ`;
+//! - the MDS matrix only depends on `g` and on the number of columns.
+//!
+//! This module emits the corresponding `round_constants.rs`, `mds.rs` and the
+//! constants of `sbox.rs`, with field elements written in Montgomery form, as
+//! well as addition chains implementing the inverse S-Box exponentiation.
+//!
+//! Files are emitted in full, documentation and tests included, so that regenerating
+//! the files of a field module reproduces them byte for byte. Only the implementation
+//! of `exp_inv_alpha` of an existing `sbox.rs` file may be preserved, see
+//! [`field_files`](crate::codegen::field_files).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ark_ff::{BigInteger, Field, FpParameters, PrimeField};
+
+use crate::params;
+
+/// First 100 digits of π, after the leading 3.
+pub const PI_0: &str = "1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+
+/// Following 100 digits of π.
+pub const PI_1: &str = "8214808651328230664709384460955058223172535940812848111745028410270193852110555964462294895493038196";
+
+/// An Anemoi instantiation, as laid out in a field module.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Instance {
+    /// Path of the instantiation module, relative to the field module.
+    pub path: &'static str,
+    /// Number of columns of the instantiation.
+    pub num_columns: usize,
+    /// Targetted security level, in bits.
+    pub security_level: u32,
+}
+
+/// All Anemoi instantiations defined for each field.
+pub const INSTANCES: [Instance; 11] = [
+    Instance {
+        path: "anemoi_2_1",
+        num_columns: 1,
+        security_level: 128,
+    },
+    Instance {
+        path: "anemoi_4_3",
+        num_columns: 2,
+        security_level: 128,
+    },
+    Instance {
+        path: "anemoi_6_5",
+        num_columns: 3,
+        security_level: 128,
+    },
+    Instance {
+        path: "anemoi_8_7",
+        num_columns: 4,
+        security_level: 128,
+    },
+    Instance {
+        path: "anemoi_10_9",
+        num_columns: 5,
+        security_level: 128,
+    },
+    Instance {
+        path: "anemoi_12_11",
+        num_columns: 6,
+        security_level: 128,
+    },
+    Instance {
+        path: "anemoi_256/anemoi_4_2",
+        num_columns: 2,
+        security_level: 256,
+    },
+    Instance {
+        path: "anemoi_256/anemoi_6_4",
+        num_columns: 3,
+        security_level: 256,
+    },
+    Instance {
+        path: "anemoi_256/anemoi_8_6",
+        num_columns: 4,
+        security_level: 256,
+    },
+    Instance {
+        path: "anemoi_256/anemoi_10_8",
+        num_columns: 5,
+        security_level: 256,
+    },
+    Instance {
+        path: "anemoi_256/anemoi_12_10",
+        num_columns: 6,
+        security_level: 256,
+    },
+];
+
+impl Instance {
+    /// Returns `true` if the instantiation is defined over `F`.
+    ///
+    /// The capacity and digests of the 256-bit instantiations are made of two field elements,
+    /// which bounds their generic security against collisions to `log2(p)` bits. They are
+    /// hence only defined over fields of at least 256 bits.
+    pub fn is_defined_over<F: PrimeField>(&self) -> bool {
+        self.security_level <= 128 || F::Params::MODULUS_BITS >= self.security_level
+    }
+
+    /// Returns the number of rounds of a new instantiation over `F` with S-Box exponent
+    /// `alpha`. The 128-bit instantiations follow [`params::reference_num_rounds`], and the
+    /// 256-bit ones follow the rule of the paper, [`params::num_rounds`].
+    pub fn num_rounds<F: PrimeField>(&self, alpha: u32) -> usize {
+        if self.security_level <= 128 {
+            params::reference_num_rounds(alpha, self.num_columns, self.security_level).unwrap()
+        } else {
+            params::num_rounds::<F>(alpha, self.num_columns, self.security_level).unwrap()
+        }
+    }
+}
+
+// CONSTANTS DERIVATION
+// ================================================================================================
+
+/// Returns the smallest exponent `α` such that `x -> x^α` is a permutation of `F`.
+pub fn alpha<F: PrimeField>() -> u32 {
+    (3..)
+        .step_by(2)
+        .find(|&alpha| params::is_valid_alpha::<F>(alpha))
+        .unwrap()
+}
+
+/// Returns the limbs of `α^-1 mod (p - 1)`, i.e. the exponent of the inverse S-Box.
+pub fn inv_alpha<F: PrimeField>(alpha: u32) -> Vec<u64> {
+    let mut modulus = F::Params::MODULUS;
+    modulus.sub_noborrow(&F::BigInt::from(1));
+    let modulus = modulus.as_ref();
+    let alpha = alpha as u128;
+
+    // Find k such that 1 + k * (p - 1) is divisible by alpha.
+    let remainder = modulus
+        .iter()
+        .rev()
+        .fold(0u128, |acc, &limb| ((acc << 64) | limb as u128) % alpha);
+    let k = (1..alpha)
+        .find(|k| (1 + k * remainder) % alpha == 0)
+        .expect("alpha must be coprime with p - 1");
+
+    let mut result = vec![0u64; modulus.len()];
+    let mut carry = 1u128;
+    for (r, &limb) in result.iter_mut().zip(modulus) {
+        let t = limb as u128 * k + carry;
+        *r = t as u64;
+        carry = t >> 64;
+    }
+
+    let mut remainder = carry;
+    for r in result.iter_mut().rev() {
+        let t = (remainder << 64) | *r as u128;
+        *r = (t / alpha) as u64;
+        remainder = t % alpha;
+    }
+
+    result
+}
+
+/// Returns the first added constant `δ` of the S-Box, i.e. the inverse of the field generator `g`.
+pub fn delta<F: PrimeField>(g: u64) -> F {
+    F::from(g).inverse().unwrap()
+}
+
+/// Returns the additive round constants `C` and `D` of an instantiation
+/// with `num_columns` columns and `num_rounds` rounds.
+pub fn round_constants<F: PrimeField>(
+    g: u64,
+    alpha: u32,
+    num_columns: usize,
+    num_rounds: usize,
+) -> (Vec<Vec<F>>, Vec<Vec<F>>) {
+    let delta = delta::<F>(g);
+    let g = F::from(g);
+    let pi_0 = F::from_str(PI_0).ok().unwrap();
+    let pi_1 = F::from_str(PI_1).ok().unwrap();
+
+    let mut c = Vec::with_capacity(num_rounds);
+    let mut d = Vec::with_capacity(num_rounds);
+    for r in 0..num_rounds {
+        let pi_0_r = pi_0.pow([r as u64]);
+        let mut c_r = Vec::with_capacity(num_columns);
+        let mut d_r = Vec::with_capacity(num_columns);
+        for i in 0..num_columns {
+            let pi_1_i = pi_1.pow([i as u64]);
+            let pow_alpha = (pi_0_r + pi_1_i).pow([alpha as u64]);
+            c_r.push(g * pi_0_r.square() + pow_alpha);
+            d_r.push(g * pi_1_i.square() + pow_alpha + delta);
+        }
+        c.push(c_r);
+        d.push(d_r);
+    }
+
+    (c, d)
+}
+
+/// Returns the MDS matrix of an instantiation with `num_columns` columns, as integer
+/// combinations of the field generator `g`. The matrix is of size 2 for a single column.
+pub fn mds_matrix(g: u64, num_columns: usize) -> Vec<Vec<u64>> {
+    match num_columns {
+        1 | 2 => vec![vec![1, g], vec![g, g * g + 1]],
+        3 => vec![vec![g + 1, 1, g + 1], vec![1, 1, g], vec![g, 1, 1]],
+        4 => vec![
+            vec![1, g + 1, g, g],
+            vec![g * g, g * g + g, g + 1, 2 * g + 1],
+            vec![g * g, g * g, 1, g + 1],
+            vec![g + 1, 2 * g + 1, g, g + 1],
+        ],
+        5 | 6 => {
+            let row = [1, 1, 3, 4, 5, 6];
+            (0..num_columns)
+                .map(|i| {
+                    (0..num_columns)
+                        .map(|j| row[(j + num_columns - i) % num_columns])
+                        .collect()
+                })
+                .collect()
+        }
+        _ => panic!("unsupported number of columns: {}", num_columns),
+    }
+}
+
+// ADDITION CHAINS
+// ================================================================================================
+
+/// An operation of an [`AdditionChain`], applied to its accumulator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChainOp {
+    /// Squares the accumulator.
+    Square,
+    /// Multiplies the accumulator by `x^k`, for some `k` of the precomputed table.
+    Multiply(u64),
+}
+
+/// Error raised when the steps of an [`AdditionChain`] do not compute its exponent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainError {
+    /// Decimal representation of the exponent of the chain.
+    pub expected: String,
+    /// Decimal representation of the exponent computed by the steps of the chain.
+    pub computed: String,
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the addition chain computes x^{} instead of x^{}",
+            self.computed, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+/// An addition chain computing `x^e` with a sliding window method.
+///
+/// The exponent is split into windows, and the powers of `x` they require are first
+/// precomputed in a table. The accumulator is then initialized with the first window,
+/// and updated by squarings and multiplications by precomputed powers.
+///
+/// The table either holds all odd powers of `x` up to the largest window value, after
+/// `x^2`, or only the window values, computed with the Bos–Coster heuristic.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdditionChain {
+    exponent: Vec<u64>,
+    /// Precomputed exponents `(k, i, j)` with `k = i + j`, in increasing order.
+    table: Vec<(u64, u64, u64)>,
+    first: u64,
+    ops: Vec<ChainOp>,
+}
+
+impl AdditionChain {
+    /// Returns the shortest addition chain computing `x^exponent`, among all sliding
+    /// window chains with windows from 2 to 7 bits, and all chains with windows from 2
+    /// to 12 bits whose table is computed with the Bos–Coster heuristic.
+    ///
+    /// # Panics
+    /// Panics if `exponent` is smaller than 2^7.
+    pub fn new(exponent: &[u64]) -> Self {
+        (2..8)
+            .map(|window| Self::with_window(exponent, window))
+            .chain((2..13).map(|window| Self::with_bos_coster(exponent, window)))
+            .min_by_key(|chain| chain.len())
+            .unwrap()
+    }
+
+    /// Returns the sliding window addition chain computing `x^exponent`
+    /// with windows of at most `window` bits.
+    ///
+    /// # Panics
+    /// Panics if `exponent` is smaller than 2^7.
+    pub fn with_window(exponent: &[u64], window: usize) -> Self {
+        let windows = split_windows(exponent, window);
+        let max_odd = windows.iter().map(|&(value, _)| value).max().unwrap();
+
+        // x^2, then all odd powers from x^3 to x^max_odd.
+        let mut table = vec![(2, 1, 1)];
+        for k in (3..=max_odd).step_by(2) {
+            table.push((k, k - 2, 2));
+        }
+
+        Self::from_windows(exponent, &windows, table)
+    }
+
+    /// Returns the addition chain computing `x^exponent` with windows of at most
+    /// `window` bits, whose table only holds the window values, as computed by
+    /// the Bos–Coster heuristic.
+    ///
+    /// # Panics
+    /// Panics if `exponent` is smaller than 2^7.
+    pub fn with_bos_coster(exponent: &[u64], window: usize) -> Self {
+        let windows = split_windows(exponent, window);
+        let targets: Vec<u64> = windows.iter().map(|&(value, _)| value).collect();
+
+        Self::from_windows(exponent, &windows, bos_coster(&targets))
+    }
+
+    fn from_windows(
+        exponent: &[u64],
+        windows: &[(u64, usize)],
+        table: Vec<(u64, u64, u64)>,
+    ) -> Self {
+        let first = windows[0].0;
+        let mut ops = Vec::new();
+        for &(value, length) in windows[1..].iter() {
+            ops.extend(core::iter::repeat_n(ChainOp::Square, length));
+            if value != 0 {
+                ops.push(ChainOp::Multiply(value));
+            }
+        }
+
+        Self {
+            exponent: exponent.to_vec(),
+            table,
+            first,
+            ops,
+        }
+    }
+
+    /// Returns the number of field operations (squarings and multiplications) of this chain.
+    pub fn len(&self) -> usize {
+        self.table.len() + self.ops.len()
+    }
+
+    /// Returns `true` if this chain does not require any field operation.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Evaluates this chain on `x`, returning `x^e`.
+    pub fn evaluate<F: Field>(&self, x: &F) -> F {
+        let mut table = BTreeMap::new();
+        table.insert(1, *x);
+        for &(k, i, j) in self.table.iter() {
+            let value = table[&i] * table[&j];
+            table.insert(k, value);
+        }
+
+        let mut acc = table[&self.first];
+        for op in self.ops.iter() {
+            match op {
+                ChainOp::Square => {
+                    acc.square_in_place();
+                }
+                ChainOp::Multiply(k) => acc *= table[k],
+            }
+        }
+
+        acc
+    }
+
+    /// Returns the Rust implementation of `exp_inv_alpha` computing this chain,
+    /// with each step commented with the exponent obtained so far.
+    ///
+    /// Returns an error if the steps of the chain do not compute its exponent.
+    pub fn to_rust(&self) -> Result<String, ChainError> {
+        let mut names = BTreeMap::new();
+        names.insert(1u64, "x".to_string());
+        let mut lines: Vec<(String, Vec<u64>)> = Vec::new();
+        for (index, &(k, i, j)) in self.table.iter().enumerate() {
+            let name = format!("t{}", index + 1);
+            let code = if i == j {
+                format!("let {} = {}.square();", name, names[&i])
+            } else {
+                format!("let {} = {} * {};", name, names[&i], names[&j])
+            };
+            lines.push((code, vec![k]));
+            names.insert(k, name);
+        }
+
+        let mut acc = vec![self.first];
+        for (i, op) in self.ops.iter().enumerate() {
+            let first = i == 0;
+            let last = i == self.ops.len() - 1;
+            let code = match op {
+                ChainOp::Square => {
+                    shl1(&mut acc);
+                    if first {
+                        format!("let mut t0 = {}.square();", names[&self.first])
+                    } else if last {
+                        "t0.square()".to_string()
+                    } else {
+                        "t0 = t0.square();".to_string()
+                    }
+                }
+                ChainOp::Multiply(k) => {
+                    add_small(&mut acc, *k);
+                    if first {
+                        format!("let mut t0 = {} * {};", names[&self.first], names[k])
+                    } else if last {
+                        format!("t0 * {}", names[k])
+                    } else {
+                        format!("t0 *= {};", names[k])
+                    }
+                }
+            };
+            lines.push((code, acc.clone()));
+        }
+        let trim = |a: &[u64]| a[..a.iter().rposition(|&l| l != 0).map_or(0, |i| i + 1)].to_vec();
+        if trim(&acc) != trim(&self.exponent) {
+            return Err(ChainError {
+                expected: to_decimal(&self.exponent),
+                computed: to_decimal(&acc),
+            });
+        }
+
+        let width = lines
+            .iter()
+            .enumerate()
+            .map(|(i, (code, _))| code.len() + 4 + (i + 1).to_string().len())
+            .max()
+            .unwrap();
+
+        let mut out = String::new();
+        out.push_str("#[inline(always)]\n");
+        out.push_str("pub(crate) fn exp_inv_alpha<T: ChainElement>(x: &T) -> T {\n");
+        for (i, (code, value)) in lines.iter().enumerate() {
+            let step = (i + 1).to_string();
+            let pad = width - code.len() - 3 - step.len();
+            writeln!(
+                out,
+                "    {} //{}{}: {}",
+                code,
+                " ".repeat(pad),
+                step,
+                to_decimal(value)
+            )
+            .unwrap();
+        }
+        out.push_str("}\n");
+
+        Ok(out)
+    }
+}
+
+/// Splits `exponent`, from its most significant bit, into odd windows of at most
+/// `window` bits and single zero bits, returned as pairs of values and bit lengths.
+///
+/// # Panics
+/// Panics if `exponent` is smaller than 2^7.
+fn split_windows(exponent: &[u64], window: usize) -> Vec<(u64, usize)> {
+    let bits: Vec<bool> = {
+        let num_bits = bit_length_u64(exponent);
+        (0..num_bits)
+            .rev()
+            .map(|i| (exponent[i / 64] >> (i % 64)) & 1 == 1)
+            .collect()
+    };
+    assert!(bits.len() > 7, "the exponent is too small");
+
+    let mut windows = Vec::new();
+    let mut i = 0;
+    while i < bits.len() {
+        if !bits[i] {
+            windows.push((0, 1));
+            i += 1;
+            continue;
+        }
+        let mut j = core::cmp::min(i + window, bits.len());
+        while !bits[j - 1] {
+            j -= 1;
+        }
+        let value = bits[i..j]
+            .iter()
+            .fold(0u64, |acc, &b| (acc << 1) | b as u64);
+        windows.push((value, j - i));
+        i = j;
+    }
+
+    windows
+}
+
+/// Returns an addition sequence computing all non-zero `targets` from 1, as steps
+/// `(k, i, j)` with `k = i + j` in increasing order, with the Bos–Coster heuristic.
+///
+/// The largest value `f` left to compute is written as the sum of two values of the
+/// sequence if possible, as `f / 2 + f / 2` otherwise if `f` is even, and else as
+/// `g + (f - g)`, where `g` is the largest value of the sequence below `f`. Any new
+/// term of the decomposition is added to the values left to compute.
+fn bos_coster(targets: &[u64]) -> Vec<(u64, u64, u64)> {
+    let mut values: BTreeSet<u64> = targets.iter().copied().filter(|&k| k != 0).collect();
+    values.insert(1);
+    let mut pending: BTreeSet<u64> = values.iter().copied().filter(|&k| k > 1).collect();
+
+    let mut steps = Vec::new();
+    while let Some(f) = pending.pop_last() {
+        let pair = values
+            .range(..f)
+            .rev()
+            .take_while(|&&g| 2 * g >= f)
+            .find(|&&g| values.contains(&(f - g)))
+            .copied();
+        let (i, j) = match pair {
+            Some(g) => (g, f - g),
+            None if f % 2 == 0 => (f / 2, f / 2),
+            None => {
+                let g = *values.range(..f).next_back().unwrap();
+                (g, f - g)
+            }
+        };
+        for k in [i, j] {
+            if values.insert(k) {
+                pending.insert(k);
+            }
+        }
+        steps.push((f, i.max(j), i.min(j)));
+    }
+    steps.reverse();
+
+    steps
+}
+
+/// Test of `exp_inv_alpha` appended to generated `sbox.rs` files.
+const EXP_INV_ALPHA_TEST: &str = "
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{Field, UniformRand};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_alpha() {
+        for _ in 0..100 {
+            let a = Felt::rand(&mut OsRng);
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+        }
+    }
+}
+";
+
+/// Returns the implementation of `exp_inv_alpha` computing `x^inv_alpha`.
+///
+/// Returns an error if the addition chain found does not compute `x^inv_alpha`.
+pub fn exp_inv_alpha_code(inv_alpha: &[u64]) -> Result<String, ChainError> {
+    AdditionChain::new(inv_alpha).to_rust()
+}
+
+fn bit_length_u64(a: &[u64]) -> usize {
+    match a.iter().rposition(|&limb| limb != 0) {
+        Some(i) => 64 * i + 64 - a[i].leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+fn shl1(a: &mut Vec<u64>) {
+    let mut carry = 0;
+    for limb in a.iter_mut() {
+        let next = *limb >> 63;
+        *limb = (*limb << 1) | carry;
+        carry = next;
+    }
+    if carry != 0 {
+        a.push(carry);
+    }
+}
+
+fn add_small(a: &mut Vec<u64>, b: u64) {
+    let mut carry = b;
+    for limb in a.iter_mut() {
+        let (sum, overflow) = limb.overflowing_add(carry);
+        *limb = sum;
+        carry = overflow as u64;
+        if carry == 0 {
+            return;
+        }
+    }
+    if carry != 0 {
+        a.push(carry);
+    }
+}
+
+fn to_decimal(a: &[u64]) -> String {
+    const CHUNK: u128 = 10_000_000_000_000_000_000;
+
+    let mut a = a.to_vec();
+    let mut chunks = Vec::new();
+    while a.iter().any(|&limb| limb != 0) {
+        let mut remainder = 0u128;
+        for limb in a.iter_mut().rev() {
+            let t = (remainder << 64) | *limb as u128;
+            *limb = (t / CHUNK) as u64;
+            remainder = t % CHUNK;
+        }
+        chunks.push(remainder as u64);
+    }
+
+    match chunks.split_last() {
+        Some((first, rest)) => rest.iter().rev().fold(first.to_string(), |acc, chunk| {
+            format!("{}{:019}", acc, chunk)
+        }),
+        None => "0".to_string(),
+    }
+}
+
+// FILES GENERATION
+// ================================================================================================
+
+/// Returns the name of the `BigInteger` type backing `F`.
+fn bigint_name<F: PrimeField>() -> String {
+    format!("BigInteger{}", 64 * <F::BigInt as BigInteger>::NUM_LIMBS)
+}
+
+/// Returns the limbs of the Montgomery representation of `x`.
+fn montgomery_limbs<F: PrimeField>(x: F) -> Vec<u64> {
+    let r = F::from_repr(F::Params::R).unwrap();
+    (x * r).into_repr().as_ref().to_vec()
+}
+
+fn write_felt<F: PrimeField>(out: &mut String, x: F, indent: usize, terminator: &str) {
+    let pad = " ".repeat(indent);
+    writeln!(out, "{}Felt::new({}([", pad, bigint_name::<F>()).unwrap();
+    for limb in montgomery_limbs(x) {
+        writeln!(out, "{}    0x{:016x},", pad, limb).unwrap();
+    }
+    writeln!(out, "{}])){}", pad, terminator).unwrap();
+}
+
+/// Returns the content of the `round_constants.rs` file of an instantiation.
+pub fn round_constants_file<F: PrimeField>(
+    g: u64,
+    alpha: u32,
+    num_columns: usize,
+    num_rounds: usize,
+) -> String {
+    let (c, d) = round_constants::<F>(g, alpha, num_columns, num_rounds);
+
+    let mut out = String::new();
+    out.push_str("//! Additive round constants implementation for Anemoi\n\n");
+    writeln!(out, "use super::{};", bigint_name::<F>()).unwrap();
+    out.push_str("use super::Felt;\n");
+    if num_columns == 1 {
+        out.push_str("use super::NUM_HASH_ROUNDS;\n");
+    } else {
+        out.push_str("use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};\n");
+    }
+
+    for (name, constants) in [("C", c), ("D", d)] {
+        writeln!(out, "\n/// Additive round constants {} for Anemoi.", name).unwrap();
+        if num_columns == 1 {
+            writeln!(
+                out,
+                "pub(crate) const {}: [Felt; NUM_HASH_ROUNDS] = [",
+                name
+            )
+            .unwrap();
+            for row in constants {
+                write_felt(&mut out, row[0], 4, ",");
+            }
+        } else {
+            writeln!(
+                out,
+                "pub(crate) const {}: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [",
+                name
+            )
+            .unwrap();
+            for row in constants {
+                out.push_str("    [\n");
+                for x in row {
+                    write_felt(&mut out, x, 8, ",");
+                }
+                out.push_str("    ],\n");
+            }
+        }
+        out.push_str("];\n");
+    }
+
+    out
+}
+
+/// Returns the content of the `mds.rs` file of an instantiation.
+pub fn mds_file<F: PrimeField>(g: u64, num_columns: usize) -> String {
+    let matrix = mds_matrix(g, num_columns);
+    let width = matrix
+        .iter()
+        .flatten()
+        .map(|x| x.to_string().len())
+        .max()
+        .unwrap();
+
+    let mut out = String::new();
+    out.push_str("//! MDS matrix implementation for Anemoi\n\n");
+    writeln!(out, "use super::{};", bigint_name::<F>()).unwrap();
+    out.push_str("use super::Felt;\nuse super::NUM_COLUMNS;\n\n");
+    out.push_str("/// Maximum Diffusion Layer matrix for Anemoi\n");
+    for row in matrix.iter() {
+        let row: Vec<String> = row
+            .iter()
+            .map(|x| format!("{:>width$}", x, width = width))
+            .collect();
+        writeln!(out, "/// [{}]", row.join(" ")).unwrap();
+    }
+    out.push_str("#[allow(unused)]\n");
+    if num_columns == 1 {
+        out.push_str("pub(crate) const MDS: [Felt; (NUM_COLUMNS + 1) * (NUM_COLUMNS + 1)] = [\n");
+    } else {
+        out.push_str("pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [\n");
+    }
+    for x in matrix.into_iter().flatten() {
+        write_felt(&mut out, F::from(x), 4, ",");
+    }
+    out.push_str("];\n");
+
+    out
+}
+
+/// Returns the constants section of the `sbox.rs` file of a field, i.e.
+/// everything preceding the implementation of `exp_inv_alpha`, its documentation included.
+pub fn sbox_constants<F: PrimeField>(g: u64, alpha: u32) -> String {
+    let mut out = String::new();
+    writeln!(out, "use super::{};", bigint_name::<F>()).unwrap();
+    out.push_str("use super::Felt;\n\nuse crate::lanes::ChainElement;\n\n");
+    out.push_str("#[allow(unused)]\n/// Exponent of the Anemoi S-Box\n");
+    writeln!(out, "pub(crate) const ALPHA: u32 = {};\n", alpha).unwrap();
+    out.push_str("#[allow(unused)]\n/// Inverse exponent\n");
+    let inv_alpha = inv_alpha::<F>(alpha);
+    writeln!(
+        out,
+        "pub(crate) const INV_ALPHA: [u64; {}] = [",
+        inv_alpha.len()
+    )
+    .unwrap();
+    for limb in inv_alpha {
+        writeln!(out, "    0x{:016x},", limb).unwrap();
+    }
+    out.push_str("];\n\n");
+    out.push_str("/// Multiplier of the Anemoi S-Box\n#[allow(unused)]\n");
+    writeln!(out, "pub(crate) const BETA: u32 = {};\n", g).unwrap();
+    out.push_str("/// First added constant of the Anemoi S-Box\n");
+    out.push_str("pub(crate) const DELTA: Felt = ");
+    let mut delta_felt = String::new();
+    write_felt(&mut delta_felt, delta::<F>(g), 0, ";");
+    out.push_str(&delta_felt);
+    out.push_str("\n#[allow(unused)]\n/// Second added constant of the Anemoi S-Box\n");
+    out.push_str("pub(crate) const QUAD: u32 = 2;\n\n");
+    out.push_str("/// Computes `x^INV_ALPHA` with a fixed addition chain.\n///\n");
+    out.push_str("/// This function is **not** constant-time, as the underlying\n");
+    out.push_str("/// field arithmetic is not.\n");
+
+    out
+}
+
+/// Returns the content of the `sbox.rs` file of a field, using the provided
+/// implementation of `exp_inv_alpha`, starting with its `#[inline(always)]` attribute,
+/// and followed by its test.
+pub fn sbox_file<F: PrimeField>(g: u64, alpha: u32, exp_inv_alpha: &str) -> String {
+    sbox_constants::<F>(g, alpha) + exp_inv_alpha + EXP_INV_ALPHA_TEST
+}
+
+/// Returns the implementation of `exp_inv_alpha` of the existing `sbox.rs` file at `path`,
+/// from its `#[inline(always)]` attribute to its tests.
+fn existing_exp_inv_alpha(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let start = content.find("#[inline(always)]")?;
+    let end = content.find("\n#[cfg(test)]")?;
+
+    content.get(start..end).map(str::to_string)
+}
+
+/// Returns the number of rounds of the existing instantiation in `dir`,
+/// as defined in its `mod.rs` file.
+fn existing_num_rounds(dir: &Path) -> Option<usize> {
+    let content = fs::read_to_string(dir.join("mod.rs")).ok()?;
+    let line = content
+        .lines()
+        .find(|line| line.contains("const NUM_HASH_ROUNDS: usize ="))?;
+
+    line.split('=')
+        .nth(1)?
+        .trim()
+        .trim_end_matches(';')
+        .parse()
+        .ok()
+}
+
+/// Returns the paths and contents of the `sbox.rs` file and of the `round_constants.rs`
+/// and `mds.rs` files of the [`INSTANCES`] defined over `F`, in the field module directory
+/// `dir`.
+///
+/// The implementation of `exp_inv_alpha` of an existing `sbox.rs` file is preserved, unless
+/// `new_chain` is set, in which case it is replaced by that of a new [`AdditionChain`]. The
+/// rest of the files is emitted in full. The number of rounds of an existing instantiation
+/// is the `NUM_HASH_ROUNDS` of its `mod.rs` file, and is given by [`Instance::num_rounds`]
+/// for new ones.
+///
+/// Returns an error if the addition chain found for `exp_inv_alpha` is invalid.
+pub fn field_files<F: PrimeField>(
+    g: u64,
+    dir: &Path,
+    new_chain: bool,
+) -> Result<Vec<(PathBuf, String)>, ChainError> {
+    let alpha = alpha::<F>();
+    let mut files = Vec::new();
+
+    let sbox_path = dir.join("sbox.rs");
+    let chain = match existing_exp_inv_alpha(&sbox_path).filter(|_| !new_chain) {
+        Some(chain) => chain,
+        None => exp_inv_alpha_code(&inv_alpha::<F>(alpha))?,
+    };
+    files.push((sbox_path, sbox_file::<F>(g, alpha, &chain)));
+
+    for instance in INSTANCES
+        .iter()
+        .filter(|instance| instance.is_defined_over::<F>())
+    {
+        let instance_dir = dir.join(instance.path);
+        let num_rounds =
+            existing_num_rounds(&instance_dir).unwrap_or_else(|| instance.num_rounds::<F>(alpha));
+
+        files.push((
+            instance_dir.join("round_constants.rs"),
+            round_constants_file::<F>(g, alpha, instance.num_columns, num_rounds),
+        ));
+        files.push((
+            instance_dir.join("mds.rs"),
+            mds_file::<F>(g, instance.num_columns),
+        ));
+    }
+
+    Ok(files)
+}
+
+/// Writes the files returned by [`field_files`] for the field module directory `dir`.
+pub fn write_field_files<F: PrimeField>(g: u64, dir: &Path, new_chain: bool) -> io::Result<()> {
+    let files = field_files::<F>(g, dir, new_chain)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    for (path, content) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_field<F: PrimeField>(name: &str, g: u64, generated_chain: bool) {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(name);
+
+        let files = field_files::<F>(g, &dir, false).unwrap();
+        let (defined, undefined): (Vec<&Instance>, Vec<_>) = INSTANCES
+            .iter()
+            .partition(|instance| instance.is_defined_over::<F>());
+        assert_eq!(files.len(), 1 + 2 * defined.len());
+        for instance in undefined {
+            assert!(
+                !dir.join(instance.path).exists(),
+                "{}/{}",
+                name,
+                instance.path
+            );
+        }
+        for (path, content) in files {
+            assert_eq!(
+                content,
+                fs::read_to_string(&path).unwrap(),
+                "{}",
+                path.display()
+            );
+        }
+
+        let inv_alpha = inv_alpha::<F>(alpha::<F>());
+        let existing = existing_exp_inv_alpha(&dir.join("sbox.rs")).unwrap();
+        if generated_chain {
+            assert_eq!(
+                exp_inv_alpha_code(&inv_alpha).unwrap(),
+                existing,
+                "{}/sbox.rs",
+                name
+            );
+        } else {
+            // Hand-written chains are only kept while they are shorter than the emitted ones
+            let steps = existing.lines().filter(|line| line.contains(" //")).count();
+            assert!(
+                steps < AdditionChain::new(&inv_alpha).len(),
+                "{}/sbox.rs",
+                name
+            );
+        }
+    }
+
+    #[cfg(feature = "bn_254")]
+    #[test]
+    fn test_inv_alpha() {
+        use crate::bn_254::Felt;
+        use ark_ff::{Field, UniformRand};
+        use rand_core::OsRng;
+
+        let alpha = alpha::<Felt>();
+        assert_eq!(alpha, 5);
+        let inv_alpha = inv_alpha::<Felt>(alpha);
+        for _ in 0..10 {
+            let x = Felt::rand(&mut OsRng);
+            assert_eq!(x.pow([alpha as u64]).pow(&inv_alpha), x);
+        }
+    }
+
+    fn check_chain<F: PrimeField>() {
+        use rand_core::OsRng;
+
+        let inv_alpha = inv_alpha::<F>(alpha::<F>());
+        let chain = AdditionChain::new(&inv_alpha);
+        for window in 2..13 {
+            assert!(chain.len() <= AdditionChain::with_window(&inv_alpha, window.min(7)).len());
+            assert!(chain.len() <= AdditionChain::with_bos_coster(&inv_alpha, window).len());
+        }
+        for _ in 0..10 {
+            let x = F::rand(&mut OsRng);
+            assert_eq!(chain.evaluate(&x), x.pow(&inv_alpha));
+            for window in 2..13 {
+                let bos_coster = AdditionChain::with_bos_coster(&inv_alpha, window);
+                assert_eq!(bos_coster.evaluate(&x), x.pow(&inv_alpha));
+            }
+        }
+    }
+
+    #[test]
+    fn test_addition_chain() {
+        assert_eq!(to_decimal(&[0, 1]), "18446744073709551616");
+        assert_eq!(
+            to_decimal(&[10_000_000_000_000_000_000, 0]),
+            "10000000000000000000"
+        );
+
+        #[cfg(feature = "bls12_377")]
+        check_chain::<crate::bls12_377::Felt>();
+        #[cfg(feature = "bls12_381")]
+        check_chain::<crate::bls12_381::Felt>();
+        #[cfg(feature = "bn_254")]
+        check_chain::<crate::bn_254::Felt>();
+        #[cfg(feature = "bw6_761")]
+        check_chain::<crate::bw6_761::Felt>();
+        #[cfg(feature = "ed_on_bls12_377")]
+        check_chain::<crate::ed_on_bls12_377::Felt>();
+        #[cfg(feature = "jubjub")]
+        check_chain::<crate::jubjub::Felt>();
+        #[cfg(feature = "pallas")]
+        check_chain::<crate::pallas::Felt>();
+        #[cfg(feature = "secp256k1")]
+        check_chain::<crate::secp256k1::Felt>();
+        #[cfg(feature = "secq256k1")]
+        check_chain::<crate::secq256k1::Felt>();
+        #[cfg(feature = "stark252")]
+        check_chain::<crate::stark252::Felt>();
+        #[cfg(feature = "vesta")]
+        check_chain::<crate::vesta::Felt>();
+    }
+
+    #[test]
+    fn test_bos_coster() {
+        let targets = [1, 3, 7, 13, 0, 29, 105];
+        let steps = bos_coster(&targets);
+        let mut values = BTreeSet::from([1]);
+        for &(k, i, j) in steps.iter() {
+            assert_eq!(k, i + j);
+            assert!(values.contains(&i) && values.contains(&j));
+            assert!(values.insert(k));
+        }
+        for k in targets.iter().filter(|&&k| k != 0) {
+            assert!(values.contains(k));
+        }
+        assert!(steps.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_chain_error() {
+        let exponent = [0xb2f5_6a3e_9d01_c47b, 0x5];
+        let mut chain = AdditionChain::with_bos_coster(&exponent, 5);
+        assert!(chain.to_rust().is_ok());
+
+        chain.ops.pop();
+        let error = chain.to_rust().unwrap_err();
+        assert_eq!(error.expected, to_decimal(&exponent));
+        assert_ne!(error.computed, error.expected);
+    }
+
+    #[test]
+    fn test_generated_files() {
+        #[cfg(feature = "bls12_377")]
+        check_field::<crate::bls12_377::Felt>("bls12_377", 15, true);
+        #[cfg(feature = "bls12_381")]
+        check_field::<crate::bls12_381::Felt>("bls12_381", 2, false);
+        #[cfg(feature = "bn_254")]
+        check_field::<crate::bn_254::Felt>("bn_254", 3, true);
+        #[cfg(feature = "bw6_761")]
+        check_field::<crate::bw6_761::Felt>("bw6_761", 2, true);
+        #[cfg(feature = "ed_on_bls12_377")]
+        check_field::<crate::ed_on_bls12_377::Felt>("ed_on_bls12_377", 22, true);
+        #[cfg(feature = "jubjub")]
+        check_field::<crate::jubjub::Felt>("jubjub", 7, true);
+        #[cfg(feature = "pallas")]
+        check_field::<crate::pallas::Felt>("pallas", 5, true);
+        #[cfg(feature = "secp256k1")]
+        check_field::<crate::secp256k1::Felt>("secp256k1", 3, true);
+        #[cfg(feature = "secq256k1")]
+        check_field::<crate::secq256k1::Felt>("secq256k1", 7, true);
+        #[cfg(feature = "stark252")]
+        check_field::<crate::stark252::Felt>("stark252", 3, true);
+        #[cfg(feature = "vesta")]
+        check_field::<crate::vesta::Felt>("vesta", 5, false);
+    }
+}
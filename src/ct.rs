@@ -0,0 +1,289 @@
+//! Statistical timing leakage tests of the Anemoi S-Box and permutation.
+//!
+//! These tests follow the methodology of dudect ("Dude, is my code constant time?",
+//! Reparaz, Balasch and Verbauwhede, 2017): the execution time of a function is measured
+//! over two classes of inputs, a fixed one and uniformly random ones, interleaved at random.
+//! A Welch's t-test is then applied to both distributions of timings, after cropping the
+//! largest measurements which are likely to be caused by interrupts or context switches.
+//! A t-statistic beyond [`T_THRESHOLD`] in absolute value indicates that the execution time
+//! depends on the input.
+//!
+//! The tests of the S-Box and of the permutation fail if leakage is detected. They are
+//! ignored by default, as they are meaningless in debug mode, take some time to collect
+//! enough measurements, and are expected to fail with the current field arithmetic (see
+//! below). They can be run locally with:
+//!
+//! ```shell
+//! cargo test --release ct:: -- --ignored --test-threads 1 --nocapture
+//! ```
+//!
+//! The number of measurements per test defaults to [`NUM_MEASUREMENTS`], and can be
+//! overridden with the `ANEMOI_CT_MEASUREMENTS` environment variable.
+//!
+//! As the arkworks field arithmetic ends its modular operations with a correction branching
+//! on the value of the result, none of the covered functions is constant-time, and these
+//! tests are expected to fail on most platforms. They serve as an audit tool to assess the
+//! timing behaviour on a given platform, e.g. after switching to a constant-time field
+//! arithmetic backend, which this crate does not provide. The harness itself is checked by
+//! a test which is not ignored, asserting that it detects the leakage of a function whose
+//! execution time obviously depends on its input.
+
+use ark_ff::Field;
+use rand_core::{OsRng, RngCore};
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Default number of timing measurements per test.
+const NUM_MEASUREMENTS: usize = 20_000;
+
+/// Bound on the absolute value of the t-statistic beyond which the
+/// timings of both classes are deemed to come from different distributions.
+const T_THRESHOLD: f64 = 10.0;
+
+/// Percentiles of the measurements above which timings are cropped.
+const PERCENTILES: [f64; 3] = [0.5, 0.9, 1.0];
+
+/// Online computation of Welch's t-statistic between two classes of measurements.
+#[derive(Debug, Default)]
+struct WelchTest {
+    count: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl WelchTest {
+    fn push(&mut self, class: usize, x: f64) {
+        self.count[class] += 1.0;
+        let delta = x - self.mean[class];
+        self.mean[class] += delta / self.count[class];
+        self.m2[class] += delta * (x - self.mean[class]);
+    }
+
+    fn t(&self) -> f64 {
+        let var = |i: usize| self.m2[i] / (self.count[i] - 1.0);
+        let num = self.mean[0] - self.mean[1];
+        let den = (var(0) / self.count[0] + var(1) / self.count[1]).sqrt();
+
+        num / den
+    }
+}
+
+fn num_measurements() -> usize {
+    std::env::var("ANEMOI_CT_MEASUREMENTS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(NUM_MEASUREMENTS)
+}
+
+/// Measures the execution time of `f` over a fixed input and random inputs drawn with
+/// `random`, and returns the largest t-statistic in absolute value over all croppings.
+fn max_t<T: Copy>(fixed: T, mut random: impl FnMut() -> T, f: impl Fn(&mut T)) -> f64 {
+    let mut rng = OsRng;
+    let n = num_measurements();
+
+    // Inputs are all generated beforehand, so that only `f` is measured.
+    let classes: Vec<usize> = (0..n).map(|_| (rng.next_u32() & 1) as usize).collect();
+    let mut inputs: Vec<T> = classes
+        .iter()
+        .map(|&class| if class == 0 { fixed } else { random() })
+        .collect();
+
+    let timings: Vec<f64> = inputs
+        .iter_mut()
+        .map(|input| {
+            let start = Instant::now();
+            f(black_box(input));
+            start.elapsed().as_nanos() as f64
+        })
+        .collect();
+
+    let mut sorted = timings.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    PERCENTILES
+        .iter()
+        .map(|p| {
+            let threshold = sorted[((n - 1) as f64 * p) as usize];
+            let mut test = WelchTest::default();
+            for (&class, &timing) in classes.iter().zip(timings.iter()) {
+                if timing <= threshold {
+                    test.push(class, timing);
+                }
+            }
+
+            test.t().abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Reports the t-statistic of the timings of `f` over a fixed input and random inputs,
+/// and asserts that it does not indicate a timing leakage.
+fn check<T: Copy>(name: &str, fixed: T, random: impl FnMut() -> T, f: impl Fn(&mut T)) {
+    let t = max_t(fixed, random, f);
+    println!("{}: |t| = {:.2}", name, t);
+    assert!(
+        t < T_THRESHOLD,
+        "{}: timing leakage detected, |t| = {:.2} >= {}",
+        name,
+        t,
+        T_THRESHOLD
+    );
+}
+
+#[test]
+fn test_leakage_detection() {
+    // The execution time of this function is proportional to its input, which is null
+    // for the fixed class.
+    let leaky = |x: &mut u64| {
+        for _ in 0..*x {
+            *x = black_box(*x);
+        }
+    };
+    let t = max_t(0u64, || 1_000 + OsRng.next_u64() % 1_000, leaky);
+    assert!(t >= T_THRESHOLD, "leakage not detected, |t| = {:.2}", t);
+}
+
+fn random_state<F: Field, const N: usize>() -> [F; N] {
+    let mut rng = OsRng;
+    [F::zero(); N].map(|_| F::rand(&mut rng))
+}
+
+macro_rules! ct_tests {
+    ($field:ident::$instance:ident, $sbox:ident, $permutation:ident) => {
+        #[test]
+        #[ignore = "timing test, failing with the non constant-time field arithmetic"]
+        fn $sbox() {
+            use crate::$field::$instance::{apply_sbox, STATE_WIDTH};
+            use crate::$field::Felt;
+            use ark_ff::Zero;
+
+            check(
+                concat!(stringify!($field), "::", stringify!($instance), " S-Box"),
+                [Felt::zero(); STATE_WIDTH],
+                random_state::<Felt, STATE_WIDTH>,
+                apply_sbox,
+            );
+        }
+
+        #[test]
+        #[ignore = "timing test, failing with the non constant-time field arithmetic"]
+        fn $permutation() {
+            use crate::$field::$instance::{apply_permutation, STATE_WIDTH};
+            use crate::$field::Felt;
+            use ark_ff::Zero;
+
+            check(
+                concat!(
+                    stringify!($field),
+                    "::",
+                    stringify!($instance),
+                    " permutation"
+                ),
+                [Felt::zero(); STATE_WIDTH],
+                random_state::<Felt, STATE_WIDTH>,
+                apply_permutation,
+            );
+        }
+    };
+}
+
+#[cfg(feature = "bls12_377")]
+ct_tests!(
+    bls12_377::anemoi_2_1,
+    bls12_377_2_1_sbox,
+    bls12_377_2_1_permutation
+);
+#[cfg(feature = "bls12_377")]
+ct_tests!(
+    bls12_377::anemoi_4_3,
+    bls12_377_4_3_sbox,
+    bls12_377_4_3_permutation
+);
+#[cfg(feature = "bls12_381")]
+ct_tests!(
+    bls12_381::anemoi_2_1,
+    bls12_381_2_1_sbox,
+    bls12_381_2_1_permutation
+);
+#[cfg(feature = "bls12_381")]
+ct_tests!(
+    bls12_381::anemoi_4_3,
+    bls12_381_4_3_sbox,
+    bls12_381_4_3_permutation
+);
+#[cfg(feature = "bn_254")]
+ct_tests!(bn_254::anemoi_2_1, bn_254_2_1_sbox, bn_254_2_1_permutation);
+#[cfg(feature = "bn_254")]
+ct_tests!(bn_254::anemoi_4_3, bn_254_4_3_sbox, bn_254_4_3_permutation);
+#[cfg(feature = "bw6_761")]
+ct_tests!(
+    bw6_761::anemoi_2_1,
+    bw6_761_2_1_sbox,
+    bw6_761_2_1_permutation
+);
+#[cfg(feature = "bw6_761")]
+ct_tests!(
+    bw6_761::anemoi_4_3,
+    bw6_761_4_3_sbox,
+    bw6_761_4_3_permutation
+);
+#[cfg(feature = "ed_on_bls12_377")]
+ct_tests!(
+    ed_on_bls12_377::anemoi_2_1,
+    ed_on_bls12_377_2_1_sbox,
+    ed_on_bls12_377_2_1_permutation
+);
+#[cfg(feature = "ed_on_bls12_377")]
+ct_tests!(
+    ed_on_bls12_377::anemoi_4_3,
+    ed_on_bls12_377_4_3_sbox,
+    ed_on_bls12_377_4_3_permutation
+);
+#[cfg(feature = "jubjub")]
+ct_tests!(jubjub::anemoi_2_1, jubjub_2_1_sbox, jubjub_2_1_permutation);
+#[cfg(feature = "jubjub")]
+ct_tests!(jubjub::anemoi_4_3, jubjub_4_3_sbox, jubjub_4_3_permutation);
+#[cfg(feature = "pallas")]
+ct_tests!(pallas::anemoi_2_1, pallas_2_1_sbox, pallas_2_1_permutation);
+#[cfg(feature = "pallas")]
+ct_tests!(pallas::anemoi_4_3, pallas_4_3_sbox, pallas_4_3_permutation);
+#[cfg(feature = "secp256k1")]
+ct_tests!(
+    secp256k1::anemoi_2_1,
+    secp256k1_2_1_sbox,
+    secp256k1_2_1_permutation
+);
+#[cfg(feature = "secp256k1")]
+ct_tests!(
+    secp256k1::anemoi_4_3,
+    secp256k1_4_3_sbox,
+    secp256k1_4_3_permutation
+);
+#[cfg(feature = "secq256k1")]
+ct_tests!(
+    secq256k1::anemoi_2_1,
+    secq256k1_2_1_sbox,
+    secq256k1_2_1_permutation
+);
+#[cfg(feature = "secq256k1")]
+ct_tests!(
+    secq256k1::anemoi_4_3,
+    secq256k1_4_3_sbox,
+    secq256k1_4_3_permutation
+);
+#[cfg(feature = "stark252")]
+ct_tests!(
+    stark252::anemoi_2_1,
+    stark252_2_1_sbox,
+    stark252_2_1_permutation
+);
+#[cfg(feature = "stark252")]
+ct_tests!(
+    stark252::anemoi_4_3,
+    stark252_4_3_sbox,
+    stark252_4_3_permutation
+);
+#[cfg(feature = "vesta")]
+ct_tests!(vesta::anemoi_2_1, vesta_2_1_sbox, vesta_2_1_permutation);
+#[cfg(feature = "vesta")]
+ct_tests!(vesta::anemoi_4_3, vesta_4_3_sbox, vesta_4_3_permutation);
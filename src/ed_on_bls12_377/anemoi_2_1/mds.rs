@@ -4,9 +4,9 @@ use super::BigInteger256;
 use super::Felt;
 use super::NUM_COLUMNS;
 
-/// Maximum Diffusion Layer matrix for Anemoi.
-/// [1, 22]
-/// [22, 485]
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [  1  22]
+/// [ 22 485]
 #[allow(unused)]
 pub(crate) const MDS: [Felt; (NUM_COLUMNS + 1) * (NUM_COLUMNS + 1)] = [
     Felt::new(BigInteger256([
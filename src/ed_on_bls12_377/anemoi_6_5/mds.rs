@@ -4,7 +4,7 @@ use super::BigInteger256;
 use super::Felt;
 use super::NUM_COLUMNS;
 
-/// Maximum Diffusion Layer matrix for Anemoi.
+/// Maximum Diffusion Layer matrix for Anemoi
 /// [23  1 23]
 /// [ 1  1 22]
 /// [22  1  1]
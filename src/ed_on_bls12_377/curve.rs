@@ -0,0 +1,154 @@
+//! The Ed-on-BLS12-377 twisted Edwards curve over the BLS12-377 scalar field, as defined in
+//! `ark-ed-on-bls12-377`, and hashing to it with Anemoi.
+
+use super::BigInteger256;
+use super::{hash_to_field, Felt};
+use crate::elligator2::{self, Elligator2Parameters};
+
+pub use ark_ed_on_bls12_377::{
+    EdwardsAffine, EdwardsParameters, EdwardsProjective, Fr, FrParameters,
+};
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 11
+    const Z: Felt = Felt::new(BigInteger256([
+        0x19beffffffffff6a,
+        0x761e46b21fffff64,
+        0x565ad035f75edf35,
+        0x0f929a91a9d71f63,
+    ]));
+}
+
+/// Hashes `msg` to a point of the prime order subgroup of Ed-on-BLS12-377, under the
+/// domain separation tag `dst`.
+///
+/// This follows the `hash_to_curve` construction of RFC 9380: two field elements are
+/// obtained with [`hash_to_field`], i.e. with the extendable output of the
+/// [`anemoi_4_3`](super::anemoi_4_3) instantiation, mapped to the curve with Elligator 2
+/// and added, before clearing the cofactor 4.
+pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> EdwardsAffine {
+    elligator2::hash_to_curve(&hash_to_field(msg, dst, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::models::{MontgomeryModelParameters, TEModelParameters};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{Field, PrimeField, SquareRootField, Zero};
+    use core::str::FromStr;
+
+    const DST: &[u8] = b"ANEMOI-HASH-TO-CURVE-TEST";
+
+    fn point(x: &str, y: &str) -> EdwardsAffine {
+        EdwardsAffine::new(Felt::from_str(x).unwrap(), Felt::from_str(y).unwrap())
+    }
+
+    #[test]
+    fn test_parameters() {
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        assert!(generator.is_on_curve());
+        assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!generator.is_zero());
+        assert_eq!(
+            generator
+                .mul(Fr::from(4u64) * EdwardsParameters::COFACTOR_INV)
+                .into_affine(),
+            generator
+        );
+
+        let a = <EdwardsParameters as TEModelParameters>::COEFF_A;
+        let d = <EdwardsParameters as TEModelParameters>::COEFF_D;
+        let a_minus_d_inv = (a - d).inverse().unwrap();
+        assert_eq!(
+            <EdwardsParameters as MontgomeryModelParameters>::COEFF_A,
+            (a + d).double() * a_minus_d_inv
+        );
+        assert_eq!(
+            <EdwardsParameters as MontgomeryModelParameters>::COEFF_B,
+            Felt::from(4u64) * a_minus_d_inv
+        );
+        assert!(EdwardsParameters::Z.legendre().is_qnr());
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        // Generated with scripts/map_to_curve.py.
+        let vectors = [
+            ("0", "0", "1"),
+            (
+                "1",
+                "6095709059986514708825094229723185123144384160204481604644974546001853050816",
+                "7770565596202430121859830210308435215899229607575581427143125188444423857256",
+            ),
+            (
+                "2",
+                "938046772447938757183596405885175621651251800040541972946995627148449542336",
+                "6973748647729545088630429715467184836788788752750255872638087756077854479973",
+            ),
+            (
+                "8444461749428370424248824938781546531375899335154063827935233455917409239040",
+                "6095709059986514708825094229723185123144384160204481604644974546001853050816",
+                "7770565596202430121859830210308435215899229607575581427143125188444423857256",
+            ),
+            (
+                "1512366075204170929049582354406559215",
+                "3470779515940967989175317186131591102814789586227860831623769300751692883717",
+                "8105628121386553216793767110474026781511104917064055080834940139671847090701",
+            ),
+            (
+                "2814820583142790141416274979593848843791966445051354609311744485305803079680",
+                "7074068611832761385517672592027221510532665180347559722261287460673390139675",
+                "4154557487872076320222766833992872370140337930744786803151411024305011096002",
+            ),
+        ];
+
+        for (u, x, y) in vectors {
+            let mapped = elligator2::map_to_curve::<EdwardsParameters>(Felt::from_str(u).unwrap());
+            assert!(mapped.is_on_curve());
+            assert_eq!(mapped, point(x, y));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        // Self-generated with this implementation, as there are no reference vectors for
+        // hashing to curves with Anemoi.
+        let vectors = [
+            (
+                &b""[..],
+                "630034442430436752338900414591067851429804392150294994505815609245244159065",
+                "4927610272736002373234850589760152837055074609302745050871429832245948805572",
+            ),
+            (
+                &b"abc"[..],
+                "960344777868342424121549940246770379858573459030619020596272581645483574502",
+                "6146394562336067495288335332213387979447222366062581295600754052932644692400",
+            ),
+            (
+                &[0u8; 200][..],
+                "4165702194164792900539376482356652133760893078456284019026283370380685146171",
+                "4581267657208118284161184239674487364837160385814035626581015675604301578322",
+            ),
+        ];
+
+        for (msg, x, y) in vectors {
+            let hashed = hash_to_curve(msg, DST);
+            assert!(hashed.is_on_curve());
+            assert!(hashed.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!hashed.is_zero());
+            assert_eq!(hashed, point(x, y));
+
+            assert_ne!(hashed, hash_to_curve(msg, b"ANEMOI-OTHER-DST"));
+        }
+
+        // The cofactor is cleared from the sum of both mapped elements.
+        let u = hash_to_field(b"abc", DST, 2);
+        let mut sum = elligator2::map_to_curve::<EdwardsParameters>(u[0]);
+        sum += &elligator2::map_to_curve::<EdwardsParameters>(u[1]);
+        assert_eq!(
+            hash_to_curve(b"abc", DST),
+            sum.mul(Fr::from(4u64).into_repr()).into_affine()
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 48,
+    expanded: "b5b8a0d9ecfb12d5674a86c1d225e8591d5d552c546aebf3ff59cec9ccc93437",
+    element: "3231982353036740267022034000469842833046438603709247367680251273489580711579",
+}
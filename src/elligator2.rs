@@ -0,0 +1,80 @@
+//! Hashing to twisted Edwards curves with the Elligator 2 map, following RFC 9380.
+
+use ark_ec::models::twisted_edwards_extended::GroupAffine;
+use ark_ec::models::{MontgomeryModelParameters, TEModelParameters};
+use ark_ec::AffineCurve;
+use ark_ff::{BigInteger, Field, One, PrimeField, SquareRootField, Zero};
+
+/// Parameters of the Elligator 2 map to a twisted Edwards curve.
+///
+/// The Montgomery form `K * t^2 = s^3 + J * s^2 + s` of the curve `a * v^2 + w^2 = 1 + d * v^2 * w^2`
+/// is expected to have coefficients `J = 2 * (a + d) / (a - d)` and `K = 4 / (a - d)`, so that
+/// both are related by the rational map of Appendix D.1 of RFC 9380.
+pub(crate) trait Elligator2Parameters: TEModelParameters {
+    /// Non-square element of the base field, chosen as in Appendix H.3 of RFC 9380.
+    const Z: Self::BaseField;
+}
+
+/// Returns the sign of `x`, i.e. the parity of its canonical representative.
+fn sgn0<F: PrimeField>(x: &F) -> bool {
+    x.into_repr().is_odd()
+}
+
+/// Maps a field element to a point of the curve, with the Elligator 2 map
+/// to its Montgomery form followed by the rational map to its twisted Edwards form.
+pub(crate) fn map_to_curve<P>(u: P::BaseField) -> GroupAffine<P>
+where
+    P: Elligator2Parameters,
+    P::BaseField: PrimeField,
+{
+    let j = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_A;
+    let k = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_B;
+    let k_inv = k.inverse().unwrap();
+    let j_over_k = j * k_inv;
+    let k_inv_squared = k_inv.square();
+    let curve_equation = |x: P::BaseField| ((x + j_over_k) * x + k_inv_squared) * x;
+
+    let mut x1 = -j_over_k
+        * (P::BaseField::one() + P::Z * u.square())
+            .inverse()
+            .unwrap_or_else(P::BaseField::zero);
+    if x1.is_zero() {
+        x1 = -j_over_k;
+    }
+    let x2 = -x1 - j_over_k;
+
+    let (x, mut y, sign) = match curve_equation(x1).sqrt() {
+        Some(y) => (x1, y, true),
+        None => (x2, curve_equation(x2).sqrt().unwrap(), false),
+    };
+    if sgn0(&y) != sign {
+        y = -y;
+    }
+    let s = x * k;
+    let t = y * k;
+
+    // Points with t = 0 or s = -1 are exceptional cases of the rational map,
+    // sent to the identity.
+    let denominator = t * (s + P::BaseField::one());
+    if denominator.is_zero() {
+        return GroupAffine::zero();
+    }
+    let denominator_inv = denominator.inverse().unwrap();
+    let v = s * (s + P::BaseField::one()) * denominator_inv;
+    let w = (s - P::BaseField::one()) * t * denominator_inv;
+
+    GroupAffine::new(v, w)
+}
+
+/// Maps two field elements to a point of the prime order subgroup of the curve,
+/// by adding their images through [`map_to_curve`] and clearing the cofactor.
+pub(crate) fn hash_to_curve<P>(u: &[P::BaseField]) -> GroupAffine<P>
+where
+    P: Elligator2Parameters,
+    P::BaseField: PrimeField,
+{
+    let mut point = map_to_curve::<P>(u[0]);
+    point += &map_to_curve::<P>(u[1]);
+
+    point.mul_by_cofactor()
+}
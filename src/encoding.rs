@@ -0,0 +1,424 @@
+//! Encodings of sequences of bytes as sequences of field elements.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use ark_ff::{FpParameters, PrimeField};
+
+/// Number of field elements encoding a full block of bytes with [`Encoding::BasePacked`].
+const PACKED_BLOCK_ELEMENTS: usize = 16;
+
+/// Strategy to encode a sequence of bytes as a sequence of field elements, before
+/// hashing them with [`Sponge::hash_with_encoding`](crate::Sponge::hash_with_encoding).
+///
+/// [`Encoding::BasePacked`] and [`Encoding::BigEndianChunks`] are injective, as they encode
+/// the length of the sequence first. [`Encoding::Padded`] and [`Encoding::Paper`] are not,
+/// see below. Below,
+/// `k = F::Params::CAPACITY / 8` is the largest number of bytes whose integer value is always
+/// less than the field modulus, i.e. 31 bytes for 256-bit fields and 47 bytes for 384-bit fields.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Encoding {
+    /// The encoding of [`Sponge::hash`](crate::Sponge::hash): the bytes are split into
+    /// chunks of `k` bytes, and a byte set to 1 is appended to the last chunk if it is
+    /// shorter than `k` bytes. Each chunk is encoded as the element whose little-endian
+    /// representation it is.
+    ///
+    /// This encoding is not injective: a `k`-byte input `y || 0x01` is encoded as the
+    /// `(k - 1)`-byte input `y`, and hence has the same digest. It is kept as is to match
+    /// [`Sponge::hash`](crate::Sponge::hash); any of the other encodings should be preferred
+    /// when inputs of different lengths must not collide.
+    #[default]
+    Padded,
+    /// The encoding of the Anemoi paper and of its reference implementation: the bytes are
+    /// split into chunks of `k` bytes, and the last chunk is only completed with zeros. Each
+    /// chunk is encoded as the element whose little-endian representation it is.
+    ///
+    /// This encoding is not injective: appending null bytes to a sequence does not change
+    /// its encoding, as long as they do not start a new chunk.
+    Paper,
+    /// A dense encoding, using about `log2(p)` bits of input per element. The length of
+    /// the sequence in bytes is encoded first, as an element. The bytes are then split into
+    /// blocks of the largest size `b` such that `256^b <= p^16`, the last one possibly
+    /// shorter, and each block is encoded as the digits of its little-endian integer value in
+    /// base `p`, least significant first: 16 digits for full blocks, and the fewest digits
+    /// representing any block of its size for the last one.
+    BasePacked,
+    /// The encoding used by many other libraries: the length of the sequence in bytes is
+    /// encoded first, as an element, followed by its chunks of `k` bytes, the last one
+    /// possibly shorter, each encoded as the element whose big-endian representation it is.
+    BigEndianChunks,
+}
+
+impl Encoding {
+    /// Returns the encoding of `bytes` as a sequence of elements of `F`.
+    pub fn encode<F: PrimeField>(&self, bytes: &[u8]) -> Vec<F> {
+        let chunk_size = F::Params::CAPACITY as usize / 8;
+
+        match self {
+            Encoding::Padded => bytes
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    if chunk.len() < chunk_size {
+                        let mut padded = chunk.to_vec();
+                        padded.push(1);
+                        F::from_le_bytes_mod_order(&padded)
+                    } else {
+                        F::from_le_bytes_mod_order(chunk)
+                    }
+                })
+                .collect(),
+            Encoding::Paper => bytes
+                .chunks(chunk_size)
+                .map(F::from_le_bytes_mod_order)
+                .collect(),
+            Encoding::BasePacked => {
+                let block_sizes = packed_block_sizes::<F>();
+                let block_size = block_sizes[PACKED_BLOCK_ELEMENTS - 1];
+
+                let mut elements = vec![F::from(bytes.len() as u64)];
+                for block in bytes.chunks(block_size) {
+                    let num_digits = block_sizes
+                        .iter()
+                        .position(|&size| size >= block.len())
+                        .unwrap()
+                        + 1;
+                    pack_block(block, num_digits, &mut elements);
+                }
+
+                elements
+            }
+            Encoding::BigEndianChunks => {
+                let mut elements = vec![F::from(bytes.len() as u64)];
+                elements.extend(bytes.chunks(chunk_size).map(F::from_be_bytes_mod_order));
+
+                elements
+            }
+        }
+    }
+}
+
+/// Returns, for each number of digits `n` from 1 to [`PACKED_BLOCK_ELEMENTS`], the largest
+/// number of bytes `b` such that `256^b <= p^n`.
+fn packed_block_sizes<F: PrimeField>() -> [usize; PACKED_BLOCK_ELEMENTS] {
+    let modulus = F::Params::MODULUS;
+    let modulus = modulus.as_ref();
+    let mut sizes = [0; PACKED_BLOCK_ELEMENTS];
+
+    // Schoolbook multiplication of the successive powers of p by p
+    let mut power = vec![1u64];
+    for size in sizes.iter_mut() {
+        let mut product = vec![0u64; power.len() + modulus.len()];
+        for (i, &a) in power.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in modulus.iter().enumerate() {
+                let t = a as u128 * b as u128 + product[i + j] as u128 + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + modulus.len()] = carry as u64;
+        }
+        while product.last() == Some(&0) {
+            product.pop();
+        }
+        power = product;
+
+        let num_bits = power.len() * 64 - power.last().unwrap().leading_zeros() as usize;
+        *size = (num_bits - 1) / 8;
+    }
+
+    sizes
+}
+
+/// Appends the `num_digits` least significant digits in base `p` of the little-endian
+/// integer value of `bytes`.
+fn pack_block<F: PrimeField>(bytes: &[u8], num_digits: usize, elements: &mut Vec<F>) {
+    let modulus = F::Params::MODULUS;
+    let modulus = modulus.as_ref();
+    // p^(-1) mod 2^64, as INV holds -p^(-1) mod 2^64
+    let modulus_inv = F::Params::INV.wrapping_neg();
+    let radix = F::from(1u128 << 64);
+
+    let mut limbs: Vec<u64> = bytes
+        .chunks(8)
+        .map(|chunk| {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(word)
+        })
+        .collect();
+
+    for _ in 0..num_digits {
+        // The next digit is the current value reduced modulo p
+        let digit = limbs
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, &limb| acc * radix + F::from(limb));
+        elements.push(digit);
+
+        // The value minus the digit is then divided by p, which is exact and hence
+        // computed limb by limb from the least significant one, with p^(-1) mod 2^64.
+        sub_shifted(&mut limbs, digit.into_repr().as_ref(), 1, 0);
+        for i in 0..limbs.len() {
+            let quotient = limbs[i].wrapping_mul(modulus_inv);
+            sub_shifted(&mut limbs, modulus, quotient, i);
+            // The limb is now null, and not involved in the next steps
+            limbs[i] = quotient;
+        }
+    }
+}
+
+/// Subtracts `factor * value * 2^(64 * shift)` from `limbs`, assuming that the result
+/// is non-negative.
+fn sub_shifted(limbs: &mut [u64], value: &[u64], factor: u64, shift: usize) {
+    let mut carry = 0u128;
+    for (j, limb) in limbs.iter_mut().enumerate().skip(shift) {
+        let word = value.get(j - shift).copied().unwrap_or(0);
+        if j - shift >= value.len() && carry == 0 {
+            break;
+        }
+
+        let t = factor as u128 * word as u128 + carry;
+        let (diff, borrow) = limb.overflowing_sub(t as u64);
+        *limb = diff;
+        carry = (t >> 64) + borrow as u128;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::BigInteger;
+    use num_bigint::BigUint;
+
+    fn to_biguint<F: PrimeField>(x: &F) -> BigUint {
+        BigUint::from_bytes_le(&x.into_repr().to_bytes_le())
+    }
+
+    // Checks the base p packing against an independent implementation.
+    fn check_base_packed<F: PrimeField>(expected_block_size: usize) {
+        let modulus = BigUint::from_bytes_le(&F::Params::MODULUS.to_bytes_le());
+        let block_sizes = packed_block_sizes::<F>();
+        assert_eq!(block_sizes[PACKED_BLOCK_ELEMENTS - 1], expected_block_size);
+        for (n, &size) in block_sizes.iter().enumerate() {
+            let power = modulus.pow(n as u32 + 1);
+            assert!(BigUint::from(1u8) << (8 * size) <= power);
+            assert!(BigUint::from(1u8) << (8 * size + 8) > power);
+        }
+
+        for len in [
+            0,
+            1,
+            31,
+            32,
+            100,
+            expected_block_size,
+            2 * expected_block_size + 5,
+        ] {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 255) as u8).collect();
+            let elements = Encoding::BasePacked.encode::<F>(&bytes);
+            assert_eq!(elements[0], F::from(len as u64));
+
+            let mut digits = elements[1..].iter();
+            for block in bytes.chunks(expected_block_size) {
+                let num_digits = block_sizes.iter().position(|&s| s >= block.len()).unwrap() + 1;
+                let mut value = BigUint::from_bytes_le(block);
+                for _ in 0..num_digits {
+                    let digit = &value % &modulus;
+                    assert_eq!(to_biguint(digits.next().unwrap()), digit);
+                    value /= &modulus;
+                }
+                assert_eq!(value, BigUint::from(0u8));
+            }
+            assert!(digits.next().is_none());
+        }
+
+        // The largest block value yields the largest digits
+        let elements = Encoding::BasePacked.encode::<F>(&[0xff; 8]);
+        assert_eq!(elements, vec![F::from(8u64), F::from(u64::MAX)]);
+    }
+
+    #[test]
+    fn test_base_packed() {
+        // 380.70 bits per element
+        #[cfg(feature = "bls12_381")]
+        check_base_packed::<crate::bls12_381::Felt>(761);
+        // 760.18 bits per element
+        #[cfg(feature = "bw6_761")]
+        check_base_packed::<crate::bw6_761::Felt>(1520);
+        // 256.00 bits per element
+        #[cfg(feature = "secp256k1")]
+        check_base_packed::<crate::secp256k1::Felt>(511);
+    }
+
+    #[cfg(feature = "bn_254")]
+    #[test]
+    fn test_bn_254_encodings() {
+        use crate::bn_254::anemoi_2_1::AnemoiHash;
+        use crate::bn_254::Felt;
+        use crate::Sponge;
+
+        // 253.59 bits per element
+        check_base_packed::<Felt>(507);
+
+        let bytes: Vec<u8> = (0..40).collect();
+        let mut chunk = [0u8; 31];
+        chunk.copy_from_slice(&bytes[..31]);
+        assert_eq!(
+            Encoding::BigEndianChunks.encode::<Felt>(&bytes),
+            vec![
+                Felt::from(40u64),
+                Felt::from_be_bytes_mod_order(&chunk),
+                Felt::from_be_bytes_mod_order(&bytes[31..]),
+            ]
+        );
+        assert_eq!(
+            Encoding::BigEndianChunks.encode::<Felt>(&[1, 2]),
+            vec![Felt::from(2u64), Felt::from(0x0102u64)]
+        );
+        assert_eq!(
+            Encoding::Padded.encode::<Felt>(&[1, 2]),
+            vec![Felt::from(0x010201u64)]
+        );
+        assert_eq!(
+            Encoding::Paper.encode::<Felt>(&[1, 2]),
+            vec![Felt::from(0x0201u64)]
+        );
+
+        // The padded encoding does not distinguish `y || 0x01` from `y`
+        let short: Vec<u8> = (1..31).collect();
+        let mut long = short.clone();
+        long.push(1);
+        assert_eq!(
+            Encoding::Padded.encode::<Felt>(&short),
+            Encoding::Padded.encode::<Felt>(&long)
+        );
+        assert_eq!(AnemoiHash::hash(&short), AnemoiHash::hash(&long));
+        for encoding in [Encoding::BasePacked, Encoding::BigEndianChunks] {
+            assert_ne!(
+                AnemoiHash::hash_with_encoding(&short, encoding),
+                AnemoiHash::hash_with_encoding(&long, encoding)
+            );
+        }
+
+        // These digests were generated by this implementation, and cross-checked with
+        // `scripts/anemoi.py`.
+        let vectors = [
+            (
+                &b""[..],
+                [
+                    "0000000000000000000000000000000000000000000000000000000000000000",
+                    "eb2069a4bfaae7743b3de0993be2499e8cf923a3bbac9c916c368c8c742b2e12",
+                    "eb2069a4bfaae7743b3de0993be2499e8cf923a3bbac9c916c368c8c742b2e12",
+                ],
+            ),
+            (
+                &b"anemoi"[..],
+                [
+                    "3bdba96e973a63a93ef44015ee6ca6628853664254979afdced827af41d16601",
+                    "c281eeb43ea6453d4a849674473796ff627872d0ffe786c84ea1f78483318b21",
+                    "00c7f813aba9e980db56e99d357f475314a9322c36a84017ca474b2b16d7112b",
+                ],
+            ),
+            (
+                &[0xab; 600][..],
+                [
+                    "1741e01587bb5ce25eeda6d0c63e20064d47ddbbd4291c292c4d01d84712112a",
+                    "9f9745e769dd056b439a081ea9d351b5525140e0119dedb956383224e724e910",
+                    "dfb212d1a99dec9d0ea6c08a4ac78a54c4240ca4a097cd662c176ad4076d9012",
+                ],
+            ),
+        ];
+
+        // The padded encoding is the one of `Sponge::hash`
+        for len in 0..100 {
+            let bytes = vec![0xcd; len];
+            assert_eq!(
+                AnemoiHash::hash(&bytes),
+                AnemoiHash::hash_field(&Encoding::Padded.encode(&bytes))
+            );
+        }
+
+        let encodings = [
+            Encoding::Padded,
+            Encoding::BasePacked,
+            Encoding::BigEndianChunks,
+        ];
+        for (bytes, digests) in vectors {
+            for (encoding, digest) in encodings.iter().zip(digests) {
+                let hash = AnemoiHash::hash_with_encoding(bytes, *encoding);
+                assert_eq!(hash, AnemoiHash::hash_field(&encoding.encode(bytes)));
+                assert_eq!(hash.to_string(), digest);
+            }
+        }
+    }
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    fn test_bls12_381_encodings() {
+        use crate::bls12_381::anemoi_2_1::AnemoiHash;
+        use crate::bls12_381::Felt;
+        use crate::Sponge;
+
+        let bytes: Vec<u8> = (0..50).collect();
+        let mut chunk = [0u8; 47];
+        chunk.copy_from_slice(&bytes[..47]);
+        assert_eq!(
+            Encoding::BigEndianChunks.encode::<Felt>(&bytes),
+            vec![
+                Felt::from(50u64),
+                Felt::from_be_bytes_mod_order(&chunk),
+                Felt::from_be_bytes_mod_order(&bytes[47..]),
+            ]
+        );
+        assert_eq!(
+            Encoding::Padded.encode::<Felt>(&bytes),
+            vec![
+                Felt::from_le_bytes_mod_order(&chunk),
+                Felt::from_le_bytes_mod_order(&[47, 48, 49, 1]),
+            ]
+        );
+
+        // These digests were generated by this implementation, and cross-checked with
+        // `scripts/anemoi.py`. The last input spans a full block of 761 bytes with
+        // `Encoding::BasePacked`, and 18 chunks of 47 bytes with the other encodings.
+        let vectors = [
+            (
+                &b""[..],
+                [
+                    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                    "e38335d7387a7b912185773370d6594acb7f638c797f809f92a1d470ddf62281b86204e69d34921d397640647f0c7401",
+                    "e38335d7387a7b912185773370d6594acb7f638c797f809f92a1d470ddf62281b86204e69d34921d397640647f0c7401",
+                ],
+            ),
+            (
+                &b"anemoi"[..],
+                [
+                    "ebe4fd672719b80f3ee8b341bbf70b8bc6fa6e76ba5d90a8c70c887e448beb32588344a46441594a75afece1b5e62712",
+                    "f9705be200d0b23385b10eca4ba2d7dfc6d2b98e88133f0a0fa7cf5a389e060cccd243e9369468af91f0ff6a3e971515",
+                    "8a2c18d32354bb752b7b647b2ae64f0dbd6cd0fe05a3ca54ea96aea368388ab0d59ea6127278a384118ce2db4d6ea408",
+                ],
+            ),
+            (
+                &[0xab; 800][..],
+                [
+                    "6326bd96a4c1bb5174521268e62b82a894616bf80855a3d28f137c2a021d84d793e7b72fe7dfb229141c6d51d0a08915",
+                    "0e7f1d6581e633c309878c8d3971e2d66e8e186fc5f932bf111c1fbf80f5151d2bf9a2e2691437fb8ddf27103d669603",
+                    "b1a3f91fe0a0911d48d99d85d66e0b6dd108aac8881ddcb9cfc6d39550252a8f51c3fd96371e409c133bee98cea24b0f",
+                ],
+            ),
+        ];
+
+        let encodings = [
+            Encoding::Padded,
+            Encoding::BasePacked,
+            Encoding::BigEndianChunks,
+        ];
+        for (bytes, digests) in vectors {
+            for (encoding, digest) in encodings.iter().zip(digests) {
+                let hash = AnemoiHash::hash_with_encoding(bytes, *encoding);
+                assert_eq!(hash, AnemoiHash::hash_field(&encoding.encode(bytes)));
+                assert_eq!(hash.to_string(), digest);
+            }
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//! Encoding of elements of a foreign prime field as limbs in a native one.
+//!
+//! An element of a foreign field `G` is encoded over a native field `F` as the little-endian
+//! limbs of its canonical representative, least significant limb first. Each limb spans
+//! `LIMB_BITS` bits, which must be strictly below the capacity of `F` so that limbs are
+//! canonical elements of `F`. Small limbs are cheap to range-check and to multiply without
+//! overflow in circuits over `F`, hence the default width
+//! [`DEFAULT_LIMB_BITS`](crate::foreign::DEFAULT_LIMB_BITS) of 64 bits.
+//! Every element of `G` is encoded over the same number
+//! [`num_limbs::<F, G, LIMB_BITS>()`](crate::foreign::num_limbs) of limbs, which makes the
+//! encoding of sequences of foreign elements injective for a given limb width.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use ark_ff::{FpParameters, PrimeField};
+
+/// The default bit length of the limbs encoding foreign elements.
+pub const DEFAULT_LIMB_BITS: usize = 64;
+
+/// Returns the number of limbs of `LIMB_BITS` bits in `F` encoding each element of `G`.
+///
+/// # Panics
+///
+/// Panics if `LIMB_BITS` is zero or not strictly below the capacity of `F`.
+pub fn num_limbs<F: PrimeField, G: PrimeField, const LIMB_BITS: usize>() -> usize {
+    assert!(
+        LIMB_BITS > 0 && LIMB_BITS < F::Params::CAPACITY as usize,
+        "limbs must be non-empty and strictly below the capacity of the native field"
+    );
+
+    (G::Params::MODULUS_BITS as usize).div_ceil(LIMB_BITS)
+}
+
+/// Returns the limbs of `LIMB_BITS` bits in `F` encoding the element `x` of `G`.
+///
+/// # Panics
+///
+/// Panics if `LIMB_BITS` is zero or not strictly below the capacity of `F`.
+pub fn to_limbs<F: PrimeField, G: PrimeField, const LIMB_BITS: usize>(x: &G) -> Vec<F> {
+    let num_limbs = num_limbs::<F, G, LIMB_BITS>();
+    let repr = x.into_repr();
+    let words = repr.as_ref();
+
+    // Returns the `len <= 64` bits of the representative starting at bit `offset`
+    let bits = |offset: usize, len: usize| {
+        let word = |i: usize| words.get(i).copied().unwrap_or(0);
+        let (index, shift) = (offset / 64, offset % 64);
+        let mut value = word(index) >> shift;
+        if shift > 0 {
+            value |= word(index + 1) << (64 - shift);
+        }
+        if len < 64 {
+            value &= (1 << len) - 1;
+        }
+        value
+    };
+
+    (0..num_limbs)
+        .map(|i| {
+            let mut limb = F::BigInt::default();
+            for (j, word) in limb.as_mut().iter_mut().enumerate() {
+                if 64 * j >= LIMB_BITS {
+                    break;
+                }
+                *word = bits(i * LIMB_BITS + 64 * j, (LIMB_BITS - 64 * j).min(64));
+            }
+
+            // Limbs are strictly less than 2^CAPACITY
+            F::from_repr(limb).unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{BigInteger, One, UniformRand, Zero};
+    use num_bigint::BigUint;
+    use rand_core::OsRng;
+
+    fn to_biguint<F: PrimeField>(x: &F) -> BigUint {
+        BigUint::from_bytes_le(&x.into_repr().to_bytes_le())
+    }
+
+    // Checks that the limbs of `x` are less than 2^LIMB_BITS and recompose it.
+    fn check_limbs<F: PrimeField, G: PrimeField, const LIMB_BITS: usize>(x: &G) {
+        let limbs = to_limbs::<F, G, LIMB_BITS>(x);
+        assert_eq!(limbs.len(), num_limbs::<F, G, LIMB_BITS>());
+
+        let mut recomposed = BigUint::zero();
+        for limb in limbs.iter().rev() {
+            let limb = to_biguint(limb);
+            assert!(limb.bits() as usize <= LIMB_BITS);
+            recomposed = (recomposed << LIMB_BITS) + limb;
+        }
+        assert_eq!(recomposed, to_biguint(x));
+    }
+
+    fn check_encoding<F: PrimeField, G: PrimeField, const LIMB_BITS: usize>() {
+        let mut rng = OsRng;
+        for x in [G::zero(), G::one(), -G::one(), G::rand(&mut rng)] {
+            check_limbs::<F, G, LIMB_BITS>(&x);
+        }
+        assert_ne!(
+            to_limbs::<F, G, LIMB_BITS>(&-G::one()),
+            to_limbs::<F, G, LIMB_BITS>(&(-G::one()).double())
+        );
+    }
+
+    // Checks the limbs of `x` against the provided hexadecimal values.
+    fn check_vector<F: PrimeField, G: PrimeField, const LIMB_BITS: usize>(
+        x: &G,
+        expected: &[&str],
+    ) {
+        let limbs: Vec<BigUint> = to_limbs::<F, G, LIMB_BITS>(x)
+            .iter()
+            .map(to_biguint)
+            .collect();
+        let expected: Vec<BigUint> = expected
+            .iter()
+            .map(|limb| BigUint::parse_bytes(limb.as_bytes(), 16).unwrap())
+            .collect();
+        assert_eq!(limbs, expected);
+    }
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    fn test_bls12_381_limbs() {
+        use crate::bls12_381::anemoi_4_3::AnemoiHash;
+        use crate::bls12_381::Felt;
+        use crate::Sponge;
+        use ark_bls12_381::Fr;
+
+        assert_eq!(num_limbs::<Felt, Fr, DEFAULT_LIMB_BITS>(), 4);
+        assert_eq!(num_limbs::<Felt, Fr, 88>(), 3);
+        assert_eq!(num_limbs::<Felt, Fr, 100>(), 3);
+        assert_eq!(num_limbs::<Felt, Felt, DEFAULT_LIMB_BITS>(), 6);
+        check_encoding::<Felt, Fr, DEFAULT_LIMB_BITS>();
+        check_encoding::<Felt, Fr, 88>();
+        check_encoding::<Felt, Fr, 100>();
+        check_encoding::<Felt, Felt, DEFAULT_LIMB_BITS>();
+        check_encoding::<Felt, Felt, 379>();
+
+        check_vector::<Felt, Fr, DEFAULT_LIMB_BITS>(
+            &-Fr::one(),
+            &[
+                "ffffffff00000000",
+                "53bda402fffe5bfe",
+                "3339d80809a1d805",
+                "73eda753299d7d48",
+            ],
+        );
+        check_vector::<Felt, Fr, 88>(
+            &-Fr::one(),
+            &[
+                "fe5bfeffffffff00000000",
+                "d80809a1d80553bda402ff",
+                "73eda753299d7d483339",
+            ],
+        );
+        check_vector::<Felt, Fr, 100>(
+            &-Fr::one(),
+            &[
+                "2fffe5bfeffffffff00000000",
+                "483339d80809a1d80553bda40",
+                "73eda753299d7d",
+            ],
+        );
+
+        let scalar = Fr::rand(&mut OsRng);
+        let mut hasher = AnemoiHash::new();
+        hasher.absorb_foreign(&[scalar, -scalar]);
+        let limbs = [
+            to_limbs::<_, _, DEFAULT_LIMB_BITS>(&scalar),
+            to_limbs::<_, _, DEFAULT_LIMB_BITS>(&-scalar),
+        ]
+        .concat();
+        assert_eq!(hasher.finalize(), AnemoiHash::hash_field(&limbs));
+
+        let mut hasher = AnemoiHash::new();
+        hasher.absorb_foreign_with_limb_bits::<_, 88>(&[scalar, -scalar]);
+        let limbs = [
+            to_limbs::<_, _, 88>(&scalar),
+            to_limbs::<_, _, 88>(&-scalar),
+        ]
+        .concat();
+        assert_eq!(hasher.finalize(), AnemoiHash::hash_field(&limbs));
+    }
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    #[should_panic(expected = "strictly below the capacity")]
+    fn test_limb_bits_capacity() {
+        use crate::bls12_381::Felt;
+
+        // The capacity of the BLS12-381 base field is 380 bits
+        let _ = to_limbs::<Felt, Felt, 380>(&Felt::one());
+    }
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    #[should_panic(expected = "non-empty")]
+    fn test_limb_bits_zero() {
+        use crate::bls12_381::Felt;
+
+        let _ = to_limbs::<Felt, Felt, 0>(&Felt::one());
+    }
+
+    #[cfg(all(feature = "pallas", feature = "vesta"))]
+    #[test]
+    fn test_pasta_limbs() {
+        use crate::pallas::Felt as PallasFelt;
+        use crate::vesta::Felt as VestaFelt;
+
+        assert_eq!(num_limbs::<PallasFelt, VestaFelt, DEFAULT_LIMB_BITS>(), 4);
+        assert_eq!(num_limbs::<VestaFelt, PallasFelt, 88>(), 3);
+        check_encoding::<PallasFelt, VestaFelt, DEFAULT_LIMB_BITS>();
+        check_encoding::<VestaFelt, PallasFelt, DEFAULT_LIMB_BITS>();
+        check_encoding::<PallasFelt, VestaFelt, 88>();
+        check_encoding::<VestaFelt, PallasFelt, 88>();
+    }
+
+    #[cfg(all(feature = "bw6_761", feature = "bn_254"))]
+    #[test]
+    fn test_bw6_761_limbs() {
+        use crate::bn_254::Felt as Bn254Felt;
+        use crate::bw6_761::Felt as Bw6761Felt;
+
+        assert_eq!(num_limbs::<Bw6761Felt, Bn254Felt, DEFAULT_LIMB_BITS>(), 4);
+        assert_eq!(num_limbs::<Bn254Felt, Bw6761Felt, DEFAULT_LIMB_BITS>(), 12);
+        assert_eq!(num_limbs::<Bn254Felt, Bw6761Felt, 88>(), 9);
+        check_encoding::<Bw6761Felt, Bn254Felt, DEFAULT_LIMB_BITS>();
+        check_encoding::<Bn254Felt, Bw6761Felt, DEFAULT_LIMB_BITS>();
+        check_encoding::<Bn254Felt, Bw6761Felt, 88>();
+    }
+}
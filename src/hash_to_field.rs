@@ -0,0 +1,182 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+/// Implements `expand_message_anemoi` and `hash_to_field` in a field module, with the
+/// `anemoi_4_3` instantiation of this field as XOF, reducing `$bytes` bytes of output into
+/// each element.
+///
+/// The tests pin `$expanded`, the hexadecimal encoding of 32 bytes of output of
+/// `expand_message_anemoi` over `"abc"`, and `$element`, the decimal value of the element
+/// returned by `hash_to_field` over the same message.
+macro_rules! impl_hash_to_field {
+    (bytes: $bytes:literal, expanded: $expanded:literal, element: $element:literal $(,)?) => {
+        #[cfg(not(feature = "std"))]
+        use alloc::vec::Vec;
+
+        use super::anemoi_4_3::AnemoiHash;
+        use super::Felt;
+        use crate::serialization::element_from_be_bytes_mod_order;
+        use crate::Encoding;
+
+        /// Number of uniformly distributed bytes reduced into each field element by
+        /// [`hash_to_field`], i.e. `ceil((ceil(log2(p)) + k) / 8)` for a security level `k`
+        /// of 128 bits.
+        pub const HASH_TO_FIELD_BYTES: usize = $bytes;
+
+        /// Prefix of the domain separation tags longer than 255 bytes,
+        /// which are hashed into shorter ones as in RFC 9380.
+        const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+        /// Expands `msg` into `len_in_bytes` uniformly distributed bytes, under the domain
+        /// separation tag `dst`.
+        ///
+        /// This follows `expand_message_xof` from RFC 9380, with the extendable output of the
+        /// [`anemoi_4_3`](super::anemoi_4_3) instantiation as XOF. The message, the output
+        /// length and the domain separation tag are absorbed as field elements rather than
+        /// through [`AnemoiHash::update`], whose padding is not injective: the message and the
+        /// tag are encoded with [`Encoding::BasePacked`], which prefixes them with their length,
+        /// and the output length is a single element. The output is squeezed from the final
+        /// state. Domain separation tags longer than 255 bytes are first replaced by 32 bytes of
+        /// output of the XOF over the encoding of `"H2C-OVERSIZE-DST-" || dst`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `len_in_bytes` is not less than 2^16.
+        pub fn expand_message_anemoi(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+            assert!(len_in_bytes < 1 << 16);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&Encoding::BasePacked.encode::<Felt>(msg));
+            hasher.absorb_field(&[Felt::from(len_in_bytes as u64)]);
+            if dst.len() > 255 {
+                let mut dst_hasher = AnemoiHash::new();
+                dst_hasher.absorb_field(
+                    &Encoding::BasePacked.encode::<Felt>(&[OVERSIZE_DST_PREFIX, dst].concat()),
+                );
+                let mut short_dst = [0u8; 32];
+                dst_hasher.finalize_xof().read(&mut short_dst);
+
+                hasher.absorb_field(&Encoding::BasePacked.encode::<Felt>(&short_dst));
+            } else {
+                hasher.absorb_field(&Encoding::BasePacked.encode::<Felt>(dst));
+            }
+
+            let mut output = vec![0u8; len_in_bytes];
+            hasher.finalize_xof().read(&mut output);
+
+            output
+        }
+
+        /// Hashes `msg` into `count` field elements, under the domain separation tag `dst`.
+        ///
+        /// This follows `hash_to_field` from RFC 9380: each element is obtained by reducing
+        /// modulo p [`HASH_TO_FIELD_BYTES`] bytes of output of [`expand_message_anemoi`], read
+        /// as a big-endian integer. The distribution of each element is hence within a
+        /// statistical distance of 2^-128 from the uniform one.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `count * HASH_TO_FIELD_BYTES` is not less than 2^16.
+        pub fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<Felt> {
+            expand_message_anemoi(msg, dst, count * HASH_TO_FIELD_BYTES)
+                .chunks(HASH_TO_FIELD_BYTES)
+                .map(element_from_be_bytes_mod_order)
+                .collect()
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use ark_ff::{BigInteger, FpParameters, PrimeField};
+            use core::str::FromStr;
+            use num_bigint::BigUint;
+
+            const DST: &[u8] = b"ANEMOI-HASH-TO-FIELD-TEST";
+
+            #[test]
+            fn test_expand_message() {
+                // Self-generated, as there are no reference vectors for `expand_message` with
+                // Anemoi.
+                let expected: Vec<u8> = (0..$expanded.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&$expanded[i..i + 2], 16).unwrap())
+                    .collect();
+                assert_eq!(expand_message_anemoi(b"abc", DST, 32), expected);
+
+                for len in [0, 1, 32, 100, 1000] {
+                    let output = expand_message_anemoi(b"abc", DST, len);
+                    assert_eq!(output.len(), len);
+                    assert_eq!(output, expand_message_anemoi(b"abc", DST, len));
+                }
+
+                // The output depends on the message, the domain separation tag and the output
+                // length
+                let output = expand_message_anemoi(b"abc", DST, 64);
+                assert_ne!(output, expand_message_anemoi(b"abd", DST, 64));
+                assert_ne!(
+                    output,
+                    expand_message_anemoi(b"abc", b"ANEMOI-OTHER-DST", 64)
+                );
+                assert_ne!(output[..32], expand_message_anemoi(b"abc", DST, 32)[..]);
+
+                // Messages whose padded byte encodings are equal, as a short last chunk is
+                // padded with a byte set to 1, are absorbed as distinct elements
+                let chunk_size = <Felt as PrimeField>::Params::CAPACITY as usize / 8;
+                let short = vec![0x42u8; chunk_size - 1];
+                let mut long = short.clone();
+                long.push(1);
+                assert_ne!(
+                    expand_message_anemoi(&short, DST, 32),
+                    expand_message_anemoi(&long, DST, 32)
+                );
+
+                // Moving bytes between the message and the domain separation tag changes the
+                // output
+                assert_ne!(
+                    expand_message_anemoi(b"abcA", b"B", 32),
+                    expand_message_anemoi(b"abc", b"AB", 32)
+                );
+
+                // Oversized domain separation tags are hashed into 32-byte ones
+                let long_dst = [0x42u8; 300];
+                let mut dst_hasher = AnemoiHash::new();
+                dst_hasher.absorb_field(
+                    &Encoding::BasePacked
+                        .encode::<Felt>(&[&b"H2C-OVERSIZE-DST-"[..], &long_dst].concat()),
+                );
+                let mut short_dst = [0u8; 32];
+                dst_hasher.finalize_xof().read(&mut short_dst);
+                assert_eq!(
+                    expand_message_anemoi(b"abc", &long_dst, 64),
+                    expand_message_anemoi(b"abc", &short_dst, 64)
+                );
+            }
+
+            #[test]
+            fn test_hash_to_field() {
+                let elements = hash_to_field(b"abc", DST, 3);
+                assert_eq!(elements.len(), 3);
+                assert_eq!(
+                    hash_to_field(b"abc", DST, 1),
+                    [Felt::from_str($element).unwrap()]
+                );
+                assert_eq!(elements, hash_to_field(b"abc", DST, 3));
+                assert_ne!(elements[0], elements[1]);
+                assert_ne!(elements, hash_to_field(b"abc", b"ANEMOI-OTHER-DST", 3));
+
+                let bytes = expand_message_anemoi(b"abc", DST, 3 * HASH_TO_FIELD_BYTES);
+                for (element, chunk) in elements.iter().zip(bytes.chunks(HASH_TO_FIELD_BYTES)) {
+                    let modulus = BigUint::from_bytes_le(
+                        &<Felt as PrimeField>::Params::MODULUS.to_bytes_le(),
+                    );
+                    let expected = BigUint::from_bytes_be(chunk) % modulus;
+                    assert_eq!(
+                        BigUint::from_bytes_le(&element.into_repr().to_bytes_le()),
+                        expected
+                    );
+                }
+
+                assert!(hash_to_field(b"abc", DST, 0).is_empty());
+            }
+        }
+    };
+}
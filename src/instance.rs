@@ -0,0 +1,574 @@
+//! Selection of Anemoi instantiations at runtime.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use crate::serialization::{self, element_size};
+use crate::{AnemoiDigestTrait, DigestError, Jive, Sponge};
+use ark_ff::PrimeField;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::de::{Error, Visitor};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Object-safe interface to an Anemoi instantiation, processing digests as their
+/// canonical bytes, as returned by [`AnemoiInstance::sponge`].
+pub trait DynSponge: fmt::Debug + Send + Sync {
+    /// Returns the instantiation behind this sponge.
+    fn instance(&self) -> AnemoiInstance;
+
+    /// Returns the length in bytes of the canonical encoding of a digest.
+    fn digest_len(&self) -> usize;
+
+    /// Returns the canonical encoding of the hash of the provided sequence of bytes,
+    /// as computed by [`Sponge::hash`].
+    fn hash(&self, bytes: &[u8]) -> Vec<u8>;
+
+    /// Compresses two digests, given by their canonical encodings, into one, as
+    /// [`Sponge::merge`] does.
+    ///
+    /// Returns an error if any of them is not the canonical encoding of a digest.
+    fn merge(&self, left: &[u8], right: &[u8]) -> Result<Vec<u8>, DigestError>;
+
+    /// Returns the number of field elements of the internal state.
+    fn state_width(&self) -> usize;
+
+    /// Returns the length in bytes of the canonical encoding of a field element.
+    fn element_len(&self) -> usize;
+
+    /// Compresses a state, given as the concatenated canonical encodings of its
+    /// [`Self::state_width`] elements, by a factor 2 with the Jive compression mode,
+    /// as [`Jive::compress`] does, and returns the encodings of the resulting elements.
+    ///
+    /// Returns an error if the input is not the canonical encoding of a state.
+    fn compress(&self, elems: &[u8]) -> Result<Vec<u8>, DigestError>;
+}
+
+/// Error raised when parsing the name of an unknown or disabled Anemoi instantiation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseInstanceError;
+
+impl fmt::Display for ParseInstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown or disabled Anemoi instantiation")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseInstanceError {}
+
+/// Implementation of [`DynSponge`] for the hasher `H` over `F`, with a state
+/// of `STATE_WIDTH` elements.
+struct InstanceSponge<F, H, const STATE_WIDTH: usize> {
+    instance: AnemoiInstance,
+    _marker: PhantomData<fn() -> (F, H)>,
+}
+
+impl<F, H, const STATE_WIDTH: usize> fmt::Debug for InstanceSponge<F, H, STATE_WIDTH> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InstanceSponge")
+            .field(&self.instance)
+            .finish()
+    }
+}
+
+impl<F, H, const STATE_WIDTH: usize> DynSponge for InstanceSponge<F, H, STATE_WIDTH>
+where
+    F: PrimeField,
+    H: Sponge<F> + Jive<F>,
+{
+    fn instance(&self) -> AnemoiInstance {
+        self.instance
+    }
+
+    fn digest_len(&self) -> usize {
+        H::Digest::BYTE_LEN
+    }
+
+    fn hash(&self, bytes: &[u8]) -> Vec<u8> {
+        H::hash(bytes).to_bytes()
+    }
+
+    fn merge(&self, left: &[u8], right: &[u8]) -> Result<Vec<u8>, DigestError> {
+        let digests = [H::Digest::from_bytes(left)?, H::Digest::from_bytes(right)?];
+
+        Ok(H::merge(&digests).to_bytes())
+    }
+
+    fn state_width(&self) -> usize {
+        STATE_WIDTH
+    }
+
+    fn element_len(&self) -> usize {
+        element_size::<F>()
+    }
+
+    fn compress(&self, elems: &[u8]) -> Result<Vec<u8>, DigestError> {
+        let elems: [F; STATE_WIDTH] = serialization::elements_from_bytes(elems)?;
+        let result = H::compress(&elems);
+
+        let size = element_size::<F>();
+        let mut bytes = vec![0u8; result.len() * size];
+        for (element, chunk) in result.iter().zip(bytes.chunks_mut(size)) {
+            serialization::element_to_bytes(element, chunk);
+        }
+
+        Ok(bytes)
+    }
+}
+
+macro_rules! instances {
+    ($($(#[$cfg:meta])* $variant:ident => $name:literal, $field:ident, $($path:ident)::+,)*) => {
+        /// An Anemoi instantiation, i.e. a field and a state width, among those enabled by
+        /// the features of this crate.
+        ///
+        /// Each instantiation is named after the path of its module, with `/` separators,
+        /// e.g. `"bls12_381/anemoi_4_3"` or `"secp256k1/anemoi_256/anemoi_4_2"`, which
+        /// [`Display`](fmt::Display) outputs and [`FromStr`] parses.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+        pub enum AnemoiInstance {
+            $(
+                $(#[$cfg])*
+                #[doc = concat!("The `", $name, "` instantiation.")]
+                $variant,
+            )*
+        }
+
+        impl AnemoiInstance {
+            /// All the instantiations enabled by the features of this crate.
+            pub const ALL: &'static [AnemoiInstance] = &[
+                $($(#[$cfg])* AnemoiInstance::$variant,)*
+            ];
+
+            /// Returns the name of this instantiation.
+            pub fn name(&self) -> &'static str {
+                match *self {
+                    $($(#[$cfg])* AnemoiInstance::$variant => $name,)*
+                }
+            }
+
+            /// Returns a sponge hashing with this instantiation.
+            pub fn sponge(&self) -> Box<dyn DynSponge> {
+                match *self {
+                    $(
+                        $(#[$cfg])*
+                        AnemoiInstance::$variant => Box::new(InstanceSponge::<
+                            crate::$field::Felt,
+                            crate::$($path)::+::AnemoiHash,
+                            { crate::$($path)::+::STATE_WIDTH },
+                        > {
+                            instance: *self,
+                            _marker: PhantomData,
+                        }),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+instances! {
+    #[cfg(feature = "bls12_377")]
+    Bls12_377Anemoi2_1 => "bls12_377/anemoi_2_1", bls12_377, bls12_377::anemoi_2_1,
+    #[cfg(feature = "bls12_377")]
+    Bls12_377Anemoi4_3 => "bls12_377/anemoi_4_3", bls12_377, bls12_377::anemoi_4_3,
+    #[cfg(feature = "bls12_377")]
+    Bls12_377Anemoi6_5 => "bls12_377/anemoi_6_5", bls12_377, bls12_377::anemoi_6_5,
+    #[cfg(feature = "bls12_377")]
+    Bls12_377Anemoi8_7 => "bls12_377/anemoi_8_7", bls12_377, bls12_377::anemoi_8_7,
+    #[cfg(feature = "bls12_377")]
+    Bls12_377Anemoi10_9 => "bls12_377/anemoi_10_9", bls12_377, bls12_377::anemoi_10_9,
+    #[cfg(feature = "bls12_377")]
+    Bls12_377Anemoi12_11 => "bls12_377/anemoi_12_11", bls12_377, bls12_377::anemoi_12_11,
+    #[cfg(all(feature = "bls12_377", feature = "anemoi_256"))]
+    Bls12_377Anemoi256_4_2 => "bls12_377/anemoi_256/anemoi_4_2", bls12_377, bls12_377::anemoi_256::anemoi_4_2,
+    #[cfg(all(feature = "bls12_377", feature = "anemoi_256"))]
+    Bls12_377Anemoi256_6_4 => "bls12_377/anemoi_256/anemoi_6_4", bls12_377, bls12_377::anemoi_256::anemoi_6_4,
+    #[cfg(all(feature = "bls12_377", feature = "anemoi_256"))]
+    Bls12_377Anemoi256_8_6 => "bls12_377/anemoi_256/anemoi_8_6", bls12_377, bls12_377::anemoi_256::anemoi_8_6,
+    #[cfg(all(feature = "bls12_377", feature = "anemoi_256"))]
+    Bls12_377Anemoi256_10_8 => "bls12_377/anemoi_256/anemoi_10_8", bls12_377, bls12_377::anemoi_256::anemoi_10_8,
+    #[cfg(all(feature = "bls12_377", feature = "anemoi_256"))]
+    Bls12_377Anemoi256_12_10 => "bls12_377/anemoi_256/anemoi_12_10", bls12_377, bls12_377::anemoi_256::anemoi_12_10,
+    #[cfg(feature = "bls12_381")]
+    Bls12_381Anemoi2_1 => "bls12_381/anemoi_2_1", bls12_381, bls12_381::anemoi_2_1,
+    #[cfg(feature = "bls12_381")]
+    Bls12_381Anemoi4_3 => "bls12_381/anemoi_4_3", bls12_381, bls12_381::anemoi_4_3,
+    #[cfg(feature = "bls12_381")]
+    Bls12_381Anemoi6_5 => "bls12_381/anemoi_6_5", bls12_381, bls12_381::anemoi_6_5,
+    #[cfg(feature = "bls12_381")]
+    Bls12_381Anemoi8_7 => "bls12_381/anemoi_8_7", bls12_381, bls12_381::anemoi_8_7,
+    #[cfg(feature = "bls12_381")]
+    Bls12_381Anemoi10_9 => "bls12_381/anemoi_10_9", bls12_381, bls12_381::anemoi_10_9,
+    #[cfg(feature = "bls12_381")]
+    Bls12_381Anemoi12_11 => "bls12_381/anemoi_12_11", bls12_381, bls12_381::anemoi_12_11,
+    #[cfg(all(feature = "bls12_381", feature = "anemoi_256"))]
+    Bls12_381Anemoi256_4_2 => "bls12_381/anemoi_256/anemoi_4_2", bls12_381, bls12_381::anemoi_256::anemoi_4_2,
+    #[cfg(all(feature = "bls12_381", feature = "anemoi_256"))]
+    Bls12_381Anemoi256_6_4 => "bls12_381/anemoi_256/anemoi_6_4", bls12_381, bls12_381::anemoi_256::anemoi_6_4,
+    #[cfg(all(feature = "bls12_381", feature = "anemoi_256"))]
+    Bls12_381Anemoi256_8_6 => "bls12_381/anemoi_256/anemoi_8_6", bls12_381, bls12_381::anemoi_256::anemoi_8_6,
+    #[cfg(all(feature = "bls12_381", feature = "anemoi_256"))]
+    Bls12_381Anemoi256_10_8 => "bls12_381/anemoi_256/anemoi_10_8", bls12_381, bls12_381::anemoi_256::anemoi_10_8,
+    #[cfg(all(feature = "bls12_381", feature = "anemoi_256"))]
+    Bls12_381Anemoi256_12_10 => "bls12_381/anemoi_256/anemoi_12_10", bls12_381, bls12_381::anemoi_256::anemoi_12_10,
+    #[cfg(feature = "bn_254")]
+    Bn254Anemoi2_1 => "bn_254/anemoi_2_1", bn_254, bn_254::anemoi_2_1,
+    #[cfg(feature = "bn_254")]
+    Bn254Anemoi4_3 => "bn_254/anemoi_4_3", bn_254, bn_254::anemoi_4_3,
+    #[cfg(feature = "bn_254")]
+    Bn254Anemoi6_5 => "bn_254/anemoi_6_5", bn_254, bn_254::anemoi_6_5,
+    #[cfg(feature = "bn_254")]
+    Bn254Anemoi8_7 => "bn_254/anemoi_8_7", bn_254, bn_254::anemoi_8_7,
+    #[cfg(feature = "bn_254")]
+    Bn254Anemoi10_9 => "bn_254/anemoi_10_9", bn_254, bn_254::anemoi_10_9,
+    #[cfg(feature = "bn_254")]
+    Bn254Anemoi12_11 => "bn_254/anemoi_12_11", bn_254, bn_254::anemoi_12_11,
+    #[cfg(feature = "bw6_761")]
+    Bw6_761Anemoi2_1 => "bw6_761/anemoi_2_1", bw6_761, bw6_761::anemoi_2_1,
+    #[cfg(feature = "bw6_761")]
+    Bw6_761Anemoi4_3 => "bw6_761/anemoi_4_3", bw6_761, bw6_761::anemoi_4_3,
+    #[cfg(feature = "bw6_761")]
+    Bw6_761Anemoi6_5 => "bw6_761/anemoi_6_5", bw6_761, bw6_761::anemoi_6_5,
+    #[cfg(feature = "bw6_761")]
+    Bw6_761Anemoi8_7 => "bw6_761/anemoi_8_7", bw6_761, bw6_761::anemoi_8_7,
+    #[cfg(feature = "bw6_761")]
+    Bw6_761Anemoi10_9 => "bw6_761/anemoi_10_9", bw6_761, bw6_761::anemoi_10_9,
+    #[cfg(feature = "bw6_761")]
+    Bw6_761Anemoi12_11 => "bw6_761/anemoi_12_11", bw6_761, bw6_761::anemoi_12_11,
+    #[cfg(all(feature = "bw6_761", feature = "anemoi_256"))]
+    Bw6_761Anemoi256_4_2 => "bw6_761/anemoi_256/anemoi_4_2", bw6_761, bw6_761::anemoi_256::anemoi_4_2,
+    #[cfg(all(feature = "bw6_761", feature = "anemoi_256"))]
+    Bw6_761Anemoi256_6_4 => "bw6_761/anemoi_256/anemoi_6_4", bw6_761, bw6_761::anemoi_256::anemoi_6_4,
+    #[cfg(all(feature = "bw6_761", feature = "anemoi_256"))]
+    Bw6_761Anemoi256_8_6 => "bw6_761/anemoi_256/anemoi_8_6", bw6_761, bw6_761::anemoi_256::anemoi_8_6,
+    #[cfg(all(feature = "bw6_761", feature = "anemoi_256"))]
+    Bw6_761Anemoi256_10_8 => "bw6_761/anemoi_256/anemoi_10_8", bw6_761, bw6_761::anemoi_256::anemoi_10_8,
+    #[cfg(all(feature = "bw6_761", feature = "anemoi_256"))]
+    Bw6_761Anemoi256_12_10 => "bw6_761/anemoi_256/anemoi_12_10", bw6_761, bw6_761::anemoi_256::anemoi_12_10,
+    #[cfg(feature = "ed_on_bls12_377")]
+    EdOnBls12_377Anemoi2_1 => "ed_on_bls12_377/anemoi_2_1", ed_on_bls12_377, ed_on_bls12_377::anemoi_2_1,
+    #[cfg(feature = "ed_on_bls12_377")]
+    EdOnBls12_377Anemoi4_3 => "ed_on_bls12_377/anemoi_4_3", ed_on_bls12_377, ed_on_bls12_377::anemoi_4_3,
+    #[cfg(feature = "ed_on_bls12_377")]
+    EdOnBls12_377Anemoi6_5 => "ed_on_bls12_377/anemoi_6_5", ed_on_bls12_377, ed_on_bls12_377::anemoi_6_5,
+    #[cfg(feature = "ed_on_bls12_377")]
+    EdOnBls12_377Anemoi8_7 => "ed_on_bls12_377/anemoi_8_7", ed_on_bls12_377, ed_on_bls12_377::anemoi_8_7,
+    #[cfg(feature = "ed_on_bls12_377")]
+    EdOnBls12_377Anemoi10_9 => "ed_on_bls12_377/anemoi_10_9", ed_on_bls12_377, ed_on_bls12_377::anemoi_10_9,
+    #[cfg(feature = "ed_on_bls12_377")]
+    EdOnBls12_377Anemoi12_11 => "ed_on_bls12_377/anemoi_12_11", ed_on_bls12_377, ed_on_bls12_377::anemoi_12_11,
+    #[cfg(feature = "jubjub")]
+    JubjubAnemoi2_1 => "jubjub/anemoi_2_1", jubjub, jubjub::anemoi_2_1,
+    #[cfg(feature = "jubjub")]
+    JubjubAnemoi4_3 => "jubjub/anemoi_4_3", jubjub, jubjub::anemoi_4_3,
+    #[cfg(feature = "jubjub")]
+    JubjubAnemoi6_5 => "jubjub/anemoi_6_5", jubjub, jubjub::anemoi_6_5,
+    #[cfg(feature = "jubjub")]
+    JubjubAnemoi8_7 => "jubjub/anemoi_8_7", jubjub, jubjub::anemoi_8_7,
+    #[cfg(feature = "jubjub")]
+    JubjubAnemoi10_9 => "jubjub/anemoi_10_9", jubjub, jubjub::anemoi_10_9,
+    #[cfg(feature = "jubjub")]
+    JubjubAnemoi12_11 => "jubjub/anemoi_12_11", jubjub, jubjub::anemoi_12_11,
+    #[cfg(feature = "pallas")]
+    PallasAnemoi2_1 => "pallas/anemoi_2_1", pallas, pallas::anemoi_2_1,
+    #[cfg(feature = "pallas")]
+    PallasAnemoi4_3 => "pallas/anemoi_4_3", pallas, pallas::anemoi_4_3,
+    #[cfg(feature = "pallas")]
+    PallasAnemoi6_5 => "pallas/anemoi_6_5", pallas, pallas::anemoi_6_5,
+    #[cfg(feature = "pallas")]
+    PallasAnemoi8_7 => "pallas/anemoi_8_7", pallas, pallas::anemoi_8_7,
+    #[cfg(feature = "pallas")]
+    PallasAnemoi10_9 => "pallas/anemoi_10_9", pallas, pallas::anemoi_10_9,
+    #[cfg(feature = "pallas")]
+    PallasAnemoi12_11 => "pallas/anemoi_12_11", pallas, pallas::anemoi_12_11,
+    #[cfg(feature = "secp256k1")]
+    Secp256k1Anemoi2_1 => "secp256k1/anemoi_2_1", secp256k1, secp256k1::anemoi_2_1,
+    #[cfg(feature = "secp256k1")]
+    Secp256k1Anemoi4_3 => "secp256k1/anemoi_4_3", secp256k1, secp256k1::anemoi_4_3,
+    #[cfg(feature = "secp256k1")]
+    Secp256k1Anemoi6_5 => "secp256k1/anemoi_6_5", secp256k1, secp256k1::anemoi_6_5,
+    #[cfg(feature = "secp256k1")]
+    Secp256k1Anemoi8_7 => "secp256k1/anemoi_8_7", secp256k1, secp256k1::anemoi_8_7,
+    #[cfg(feature = "secp256k1")]
+    Secp256k1Anemoi10_9 => "secp256k1/anemoi_10_9", secp256k1, secp256k1::anemoi_10_9,
+    #[cfg(feature = "secp256k1")]
+    Secp256k1Anemoi12_11 => "secp256k1/anemoi_12_11", secp256k1, secp256k1::anemoi_12_11,
+    #[cfg(all(feature = "secp256k1", feature = "anemoi_256"))]
+    Secp256k1Anemoi256_4_2 => "secp256k1/anemoi_256/anemoi_4_2", secp256k1, secp256k1::anemoi_256::anemoi_4_2,
+    #[cfg(all(feature = "secp256k1", feature = "anemoi_256"))]
+    Secp256k1Anemoi256_6_4 => "secp256k1/anemoi_256/anemoi_6_4", secp256k1, secp256k1::anemoi_256::anemoi_6_4,
+    #[cfg(all(feature = "secp256k1", feature = "anemoi_256"))]
+    Secp256k1Anemoi256_8_6 => "secp256k1/anemoi_256/anemoi_8_6", secp256k1, secp256k1::anemoi_256::anemoi_8_6,
+    #[cfg(all(feature = "secp256k1", feature = "anemoi_256"))]
+    Secp256k1Anemoi256_10_8 => "secp256k1/anemoi_256/anemoi_10_8", secp256k1, secp256k1::anemoi_256::anemoi_10_8,
+    #[cfg(all(feature = "secp256k1", feature = "anemoi_256"))]
+    Secp256k1Anemoi256_12_10 => "secp256k1/anemoi_256/anemoi_12_10", secp256k1, secp256k1::anemoi_256::anemoi_12_10,
+    #[cfg(feature = "secq256k1")]
+    Secq256k1Anemoi2_1 => "secq256k1/anemoi_2_1", secq256k1, secq256k1::anemoi_2_1,
+    #[cfg(feature = "secq256k1")]
+    Secq256k1Anemoi4_3 => "secq256k1/anemoi_4_3", secq256k1, secq256k1::anemoi_4_3,
+    #[cfg(feature = "secq256k1")]
+    Secq256k1Anemoi6_5 => "secq256k1/anemoi_6_5", secq256k1, secq256k1::anemoi_6_5,
+    #[cfg(feature = "secq256k1")]
+    Secq256k1Anemoi8_7 => "secq256k1/anemoi_8_7", secq256k1, secq256k1::anemoi_8_7,
+    #[cfg(feature = "secq256k1")]
+    Secq256k1Anemoi10_9 => "secq256k1/anemoi_10_9", secq256k1, secq256k1::anemoi_10_9,
+    #[cfg(feature = "secq256k1")]
+    Secq256k1Anemoi12_11 => "secq256k1/anemoi_12_11", secq256k1, secq256k1::anemoi_12_11,
+    #[cfg(all(feature = "secq256k1", feature = "anemoi_256"))]
+    Secq256k1Anemoi256_4_2 => "secq256k1/anemoi_256/anemoi_4_2", secq256k1, secq256k1::anemoi_256::anemoi_4_2,
+    #[cfg(all(feature = "secq256k1", feature = "anemoi_256"))]
+    Secq256k1Anemoi256_6_4 => "secq256k1/anemoi_256/anemoi_6_4", secq256k1, secq256k1::anemoi_256::anemoi_6_4,
+    #[cfg(all(feature = "secq256k1", feature = "anemoi_256"))]
+    Secq256k1Anemoi256_8_6 => "secq256k1/anemoi_256/anemoi_8_6", secq256k1, secq256k1::anemoi_256::anemoi_8_6,
+    #[cfg(all(feature = "secq256k1", feature = "anemoi_256"))]
+    Secq256k1Anemoi256_10_8 => "secq256k1/anemoi_256/anemoi_10_8", secq256k1, secq256k1::anemoi_256::anemoi_10_8,
+    #[cfg(all(feature = "secq256k1", feature = "anemoi_256"))]
+    Secq256k1Anemoi256_12_10 => "secq256k1/anemoi_256/anemoi_12_10", secq256k1, secq256k1::anemoi_256::anemoi_12_10,
+    #[cfg(feature = "stark252")]
+    Stark252Anemoi2_1 => "stark252/anemoi_2_1", stark252, stark252::anemoi_2_1,
+    #[cfg(feature = "stark252")]
+    Stark252Anemoi4_3 => "stark252/anemoi_4_3", stark252, stark252::anemoi_4_3,
+    #[cfg(feature = "stark252")]
+    Stark252Anemoi6_5 => "stark252/anemoi_6_5", stark252, stark252::anemoi_6_5,
+    #[cfg(feature = "stark252")]
+    Stark252Anemoi8_7 => "stark252/anemoi_8_7", stark252, stark252::anemoi_8_7,
+    #[cfg(feature = "stark252")]
+    Stark252Anemoi10_9 => "stark252/anemoi_10_9", stark252, stark252::anemoi_10_9,
+    #[cfg(feature = "stark252")]
+    Stark252Anemoi12_11 => "stark252/anemoi_12_11", stark252, stark252::anemoi_12_11,
+    #[cfg(feature = "vesta")]
+    VestaAnemoi2_1 => "vesta/anemoi_2_1", vesta, vesta::anemoi_2_1,
+    #[cfg(feature = "vesta")]
+    VestaAnemoi4_3 => "vesta/anemoi_4_3", vesta, vesta::anemoi_4_3,
+    #[cfg(feature = "vesta")]
+    VestaAnemoi6_5 => "vesta/anemoi_6_5", vesta, vesta::anemoi_6_5,
+    #[cfg(feature = "vesta")]
+    VestaAnemoi8_7 => "vesta/anemoi_8_7", vesta, vesta::anemoi_8_7,
+    #[cfg(feature = "vesta")]
+    VestaAnemoi10_9 => "vesta/anemoi_10_9", vesta, vesta::anemoi_10_9,
+    #[cfg(feature = "vesta")]
+    VestaAnemoi12_11 => "vesta/anemoi_12_11", vesta, vesta::anemoi_12_11,
+}
+
+impl fmt::Display for AnemoiInstance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for AnemoiInstance {
+    type Err = ParseInstanceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|instance| instance.name() == s)
+            .copied()
+            .ok_or(ParseInstanceError)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for AnemoiInstance {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for AnemoiInstance {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(InstanceVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct InstanceVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for InstanceVisitor {
+    type Value = AnemoiInstance;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the name of an Anemoi instantiation")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instance_names() {
+        for &instance in AnemoiInstance::ALL {
+            assert_eq!(instance.to_string().parse(), Ok(instance));
+            assert_eq!(instance.sponge().instance(), instance);
+        }
+        assert_eq!(AnemoiInstance::ALL.len(), {
+            // Only the fields of at least 256 bits have instantiations of the `anemoi_256` feature
+            let widths_256 = if cfg!(feature = "anemoi_256") { 5 } else { 0 };
+            let fields = [
+                (cfg!(feature = "bls12_377"), true),
+                (cfg!(feature = "bls12_381"), true),
+                (cfg!(feature = "bn_254"), false),
+                (cfg!(feature = "bw6_761"), true),
+                (cfg!(feature = "ed_on_bls12_377"), false),
+                (cfg!(feature = "jubjub"), false),
+                (cfg!(feature = "pallas"), false),
+                (cfg!(feature = "secp256k1"), true),
+                (cfg!(feature = "secq256k1"), true),
+                (cfg!(feature = "stark252"), false),
+                (cfg!(feature = "vesta"), false),
+            ];
+            fields
+                .iter()
+                .filter(|(enabled, _)| *enabled)
+                .map(|(_, large)| if *large { 6 + widths_256 } else { 6 })
+                .sum::<usize>()
+        });
+
+        for name in [
+            "",
+            "bls12_381",
+            "bls12_381/",
+            "BLS12_381/anemoi_4_3",
+            "bls12_381/anemoi_4_3/",
+            "bls12_381/anemoi_4_2",
+            "bls12_381::anemoi_4_3",
+            "bls12_383/anemoi_4_3",
+        ] {
+            assert_eq!(name.parse::<AnemoiInstance>(), Err(ParseInstanceError));
+        }
+    }
+
+    #[test]
+    fn test_instance_merge() {
+        for &instance in AnemoiInstance::ALL {
+            let sponge = instance.sponge();
+            let a = sponge.hash(b"a");
+            let b = sponge.hash(b"b");
+
+            let merged = sponge.merge(&a, &b).unwrap();
+            assert_eq!(merged.len(), sponge.digest_len(), "{}", instance);
+            assert_eq!(merged, sponge.merge(&a, &b).unwrap(), "{}", instance);
+            assert_ne!(merged, sponge.merge(&b, &a).unwrap(), "{}", instance);
+            assert!(sponge.merge(&a, &b[1..]).is_err(), "{}", instance);
+        }
+    }
+
+    #[test]
+    fn test_instance_compress() {
+        for &instance in AnemoiInstance::ALL {
+            let sponge = instance.sponge();
+            let len = sponge.element_len();
+            let state: Vec<u8> = (0..sponge.state_width())
+                .flat_map(|i| {
+                    let mut element = vec![0u8; len];
+                    element[0] = i as u8 + 1;
+                    element
+                })
+                .collect();
+
+            let compressed = sponge.compress(&state).unwrap();
+            assert_eq!(compressed.len(), state.len() / 2, "{}", instance);
+            let mut other = state.clone();
+            other[0] += 1;
+            assert_ne!(compressed, sponge.compress(&other).unwrap(), "{}", instance);
+
+            assert_eq!(
+                sponge.compress(&state[1..]),
+                Err(DigestError::InvalidLength {
+                    expected: state.len(),
+                    actual: state.len() - 1
+                })
+            );
+            let mut non_canonical = state.clone();
+            non_canonical[len..2 * len].fill(0xff);
+            assert_eq!(
+                sponge.compress(&non_canonical),
+                Err(DigestError::NonCanonical(1))
+            );
+        }
+    }
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    fn test_bls12_381_sponge() {
+        use crate::bls12_381::anemoi_4_3::{AnemoiDigest, AnemoiHash};
+
+        let instance: AnemoiInstance = "bls12_381/anemoi_4_3".parse().unwrap();
+        assert_eq!(instance, AnemoiInstance::Bls12_381Anemoi4_3);
+        let sponge = instance.sponge();
+        assert_eq!(sponge.digest_len(), 48);
+
+        let left = AnemoiHash::hash(b"left");
+        let right = AnemoiHash::hash(b"right");
+        assert_eq!(sponge.hash(b"left"), left.to_bytes());
+        assert_eq!(
+            sponge.merge(&left.to_bytes(), &right.to_bytes()),
+            Ok(AnemoiHash::merge(&[left, right]).to_bytes().to_vec())
+        );
+
+        let digests = [left, right, right, left];
+        let state: Vec<u8> = digests
+            .iter()
+            .flat_map(|digest| digest.to_bytes())
+            .collect();
+        let expected: Vec<u8> = AnemoiHash::compress(&AnemoiDigest::digests_to_elements(&digests))
+            .into_iter()
+            .flat_map(|element| AnemoiDigest::new([element]).to_bytes())
+            .collect();
+        assert_eq!(sponge.state_width(), 4);
+        assert_eq!(sponge.element_len(), 48);
+        assert_eq!(sponge.compress(&state), Ok(expected));
+
+        assert_eq!(
+            sponge.merge(&left.to_bytes()[1..], &right.to_bytes()),
+            Err(DigestError::InvalidLength {
+                expected: 48,
+                actual: 47
+            })
+        );
+        assert_eq!(
+            sponge.merge(&left.to_bytes(), &[0xff; 48]),
+            Err(AnemoiDigest::from_bytes(&[0xff; 48]).unwrap_err())
+        );
+    }
+
+    #[cfg(all(feature = "secp256k1", feature = "anemoi_256"))]
+    #[test]
+    fn test_secp256k1_anemoi_256_sponge() {
+        use crate::secp256k1::anemoi_256::anemoi_4_2::AnemoiHash;
+
+        let sponge = AnemoiInstance::Secp256k1Anemoi256_4_2.sponge();
+        assert_eq!(sponge.instance().name(), "secp256k1/anemoi_256/anemoi_4_2");
+        assert_eq!(sponge.digest_len(), 64);
+        assert_eq!(
+            sponge.hash(b"anemoi"),
+            AnemoiHash::hash(b"anemoi").to_bytes()
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "vesta"))]
+    #[test]
+    fn test_serde() {
+        let instance = AnemoiInstance::VestaAnemoi8_7;
+        let json = serde_json::to_string(&instance).unwrap();
+        assert_eq!(json, "\"vesta/anemoi_8_7\"");
+        assert_eq!(
+            serde_json::from_str::<AnemoiInstance>(&json).unwrap(),
+            instance
+        );
+        assert!(serde_json::from_str::<AnemoiInstance>("\"vesta/anemoi_9_8\"").is_err());
+    }
+}
@@ -4,7 +4,7 @@ use super::BigInteger256;
 use super::Felt;
 use super::NUM_COLUMNS;
 
-/// Maximum Diffusion Layer matrix for Anemoi.
+/// Maximum Diffusion Layer matrix for Anemoi
 /// [8 1 8]
 /// [1 1 7]
 /// [7 1 1]
@@ -0,0 +1,111 @@
+//! Scalar field of the Bandersnatch elliptic curve.
+
+use ark_ff::{biginteger::BigInteger256 as BigInteger, fields::*};
+
+/// A prime field element, in Montgomery representation.
+pub type Fr = Fp256<FrParameters>;
+
+/// Parameters of the prime field.
+#[derive(Debug)]
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+impl FftParameters for FrParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 5;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x4b263b9a8d79c573,
+        0xeadb3d0a007af1fd,
+        0xa54c8a4668832589,
+        0x0610860c4254fb9d,
+    ]);
+}
+impl FpParameters for FrParameters {
+    /// MODULUS = 13108968793781547619861935127046491459309155893440570251786403306729687672801
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x74fd06b52876e7e1,
+        0xff8f870074190471,
+        0x0cce760202687600,
+        0x1cfb69d4ca675f52,
+    ]);
+
+    const MODULUS_BITS: u32 = 253;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 3;
+
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x5817ca56bc48c0f8,
+        0x0383c7fc5f37dc74,
+        0x998c4fefecbc4ff8,
+        0x1824b159acc5056f,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xdbb4f5d658db47cb,
+        0x40fa7ca27fecb938,
+        0xaa9e6daec0055cea,
+        0x0ae793ddb14aec7d,
+    ]);
+
+    const INV: u64 = 0xf19f22295cc063df;
+
+    // GENERATOR = 7
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x1fb566d55baabf63,
+        0x1accd4e45609f0f7,
+        0xf2cde1856d1be1c3,
+        0x1817c84bc55e4972,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xba7e835a943b73f0,
+        0x7fc7c3803a0c8238,
+        0x06673b0101343b00,
+        0x0e7db4ea6533afa9,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x8ba7e835a943b73f,
+        0x07fc7c3803a0c823,
+        0x906673b0101343b0,
+        0x00e7db4ea6533afa,
+    ]);
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xc5d3f41ad4a1db9f,
+        0x03fe3e1c01d06411,
+        0x483339d80809a1d8,
+        0x0073eda753299d7d,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::*;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_fr() {
+        let mut rng = test_rng();
+        let a: Fr = UniformRand::rand(&mut rng);
+        let b: Fr = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        sqrt_field_test(a);
+        primefield_test::<Fr>();
+    }
+}
@@ -0,0 +1,247 @@
+//! The Bandersnatch twisted Edwards curve over the BLS12-381 scalar field,
+//! and hashing to it with Anemoi.
+
+use super::super::BigInteger256;
+use super::super::{hash_to_field, Felt};
+use crate::elligator2::{self, Elligator2Parameters};
+use ark_ec::models::twisted_edwards_extended::{GroupAffine, GroupProjective};
+use ark_ec::models::{ModelParameters, MontgomeryModelParameters, TEModelParameters};
+use ark_ff::Field;
+
+mod fr;
+pub use fr::{Fr, FrParameters};
+
+/// An affine point of the Bandersnatch curve.
+pub type EdwardsAffine = GroupAffine<BandersnatchParameters>;
+
+/// A point of the Bandersnatch curve in extended coordinates.
+pub type EdwardsProjective = GroupProjective<BandersnatchParameters>;
+
+/// Parameters of the Bandersnatch curve `a * x^2 + y^2 = 1 + d * x^2 * y^2`,
+/// with `a = -5` and `d = 45022363124591815672509500913686876175488063829319466900776701791074614335719`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BandersnatchParameters;
+
+impl ModelParameters for BandersnatchParameters {
+    type BaseField = Felt;
+    type ScalarField = Fr;
+}
+
+impl TEModelParameters for BandersnatchParameters {
+    /// COEFF_A = -5
+    const COEFF_A: Felt = Felt::new(BigInteger256([
+        0xfffffff40000000c,
+        0xece3b023ffec4ff3,
+        0x66b620607396203f,
+        0x6f23d7e5f361df62,
+    ]));
+
+    /// COEFF_D = 45022363124591815672509500913686876175488063829319466900776701791074614335719
+    const COEFF_D: Felt = Felt::new(BigInteger256([
+        0xa8dced1b47a2c730,
+        0x381c065aad3cccc7,
+        0x53ff52e1188351f8,
+        0x362e8d63990fe940,
+    ]));
+
+    /// COFACTOR = 4
+    const COFACTOR: &'static [u64] = &[4];
+
+    /// COFACTOR_INV = COFACTOR^(-1) mod r
+    const COFACTOR_INV: Fr = Fr::new(BigInteger256([
+        0x1605f295af12303e,
+        0x00e0f1ff17cdf71d,
+        0xe66313fbfb2f13fe,
+        0x06092c566b31415b,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Felt, Felt) = (GENERATOR_X, GENERATOR_Y);
+
+    type MontgomeryModelParameters = BandersnatchParameters;
+
+    #[inline(always)]
+    fn mul_by_a(elem: &Felt) -> Felt {
+        -(elem.double().double() + elem)
+    }
+}
+
+impl MontgomeryModelParameters for BandersnatchParameters {
+    /// COEFF_A = 2 * (a + d) / (a - d)
+    const COEFF_A: Felt = Felt::new(BigInteger256([
+        0xbb5f62a4db208e37,
+        0xf2534a00bc48e02d,
+        0x4e16c53199d5f845,
+        0x6a73b83193a4f19d,
+    ]));
+
+    /// COEFF_B = 4 / (a - d)
+    const COEFF_B: Felt = Felt::new(BigInteger256([
+        0x40ecec43d42cb05d,
+        0x88b85e04a721fff5,
+        0x758b323623d827fa,
+        0x3dcb807b250e31ba,
+    ]));
+
+    type TEModelParameters = BandersnatchParameters;
+}
+
+impl Elligator2Parameters for BandersnatchParameters {
+    /// Z = 5
+    const Z: Felt = Felt::new(BigInteger256([
+        0x0000000afffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x04c9cf6d363b9de5,
+    ]));
+}
+
+/// GENERATOR_X = 18886178867200960497001835917649091219057080094937609519140440539760939937304
+const GENERATOR_X: Felt = Felt::new(BigInteger256([
+    0xec2627e1e7ab47f5,
+    0x3e63de484f01aa9c,
+    0xfe0f5c3b53946dc4,
+    0x2d71920baeb2cfcd,
+]));
+
+/// GENERATOR_Y = 19188667384257783945677642223292697773471335439753913231509108946878080696678
+const GENERATOR_Y: Felt = Felt::new(BigInteger256([
+    0x4e30593e1895bd34,
+    0x156d738f32afbe4b,
+    0x45ef0b1ccdeb75f4,
+    0x6a7cca0037d2e71f,
+]));
+
+/// Hashes `msg` to a point of the prime order subgroup of Bandersnatch, under the
+/// domain separation tag `dst`.
+///
+/// This follows the `hash_to_curve` construction of RFC 9380: two field elements are
+/// obtained with [`hash_to_field`], i.e. with the extendable output of the
+/// [`anemoi_4_3`](super::super::anemoi_4_3) instantiation, mapped to the curve with Elligator 2
+/// and added, before clearing the cofactor 4.
+pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> EdwardsAffine {
+    elligator2::hash_to_curve(&hash_to_field(msg, dst, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{Field, PrimeField, SquareRootField, Zero};
+    use core::str::FromStr;
+
+    const DST: &[u8] = b"ANEMOI-HASH-TO-CURVE-TEST";
+
+    fn point(x: &str, y: &str) -> EdwardsAffine {
+        EdwardsAffine::new(Felt::from_str(x).unwrap(), Felt::from_str(y).unwrap())
+    }
+
+    #[test]
+    fn test_parameters() {
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        assert!(generator.is_on_curve());
+        assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!generator.is_zero());
+        assert_eq!(
+            generator
+                .mul(Fr::from(4u64) * BandersnatchParameters::COFACTOR_INV)
+                .into_affine(),
+            generator
+        );
+
+        let a = <BandersnatchParameters as TEModelParameters>::COEFF_A;
+        let d = <BandersnatchParameters as TEModelParameters>::COEFF_D;
+        let a_minus_d_inv = (a - d).inverse().unwrap();
+        assert_eq!(
+            <BandersnatchParameters as MontgomeryModelParameters>::COEFF_A,
+            (a + d).double() * a_minus_d_inv
+        );
+        assert_eq!(
+            <BandersnatchParameters as MontgomeryModelParameters>::COEFF_B,
+            Felt::from(4u64) * a_minus_d_inv
+        );
+        assert!(BandersnatchParameters::Z.legendre().is_qnr());
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        // Generated with scripts/map_to_curve.py.
+        let vectors = [
+            ("0", "0", "1"),
+            (
+                "1",
+                "9912268024397031058474559505647885744741350272360411759620219562492032642667",
+                "30151718732961596270388562278365022222389409609732653536024559465169123119074",
+            ),
+            (
+                "2",
+                "35007252406102166008923831300404286182760571370594127518679785885219032607052",
+                "44562938834782391261523585290984768181125624854576642468993922515408611778311",
+            ),
+            (
+                "52435875175126190479447740508185965837690552500527637822603658699938581184512",
+                "9912268024397031058474559505647885744741350272360411759620219562492032642667",
+                "30151718732961596270388562278365022222389409609732653536024559465169123119074",
+            ),
+            (
+                "1512366075204170929049582354406559215",
+                "31177157280659570564797854417589962112996443229579187292329071622615309987732",
+                "27724399942305618404229018387734654284877378276153013995753913041347245297772",
+            ),
+            (
+                "17478625058375396826482580169395321945896850833509212607534552899979527061504",
+                "17815709311623883261676951570204897497922531602748743178622563484163891493565",
+                "541290821385566830880063346692338202273131802070441369814960337890813243159",
+            ),
+        ];
+
+        for (u, x, y) in vectors {
+            let mapped =
+                elligator2::map_to_curve::<BandersnatchParameters>(Felt::from_str(u).unwrap());
+            assert!(mapped.is_on_curve());
+            assert_eq!(mapped, point(x, y));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        // Self-generated with this implementation, as there are no reference vectors for
+        // hashing to curves with Anemoi.
+        let vectors = [
+            (
+                &b""[..],
+                "11912497875036402995068353255520423584198432773779641950767532159933887267485",
+                "35932462750895150086491657466524084998880218582742008241560197366124589657244",
+            ),
+            (
+                &b"abc"[..],
+                "27871697558720873803388414242199389795498708002631767589693564434138735871357",
+                "6588599666759585222567191724714733470516747685520078725087641815118894508001",
+            ),
+            (
+                &[0u8; 200][..],
+                "33484456539952246025475601790018496823138649634446225823985314838530995723268",
+                "42182282161453554606018515406320710374167499783116830168358612010728459404452",
+            ),
+        ];
+
+        for (msg, x, y) in vectors {
+            let hashed = hash_to_curve(msg, DST);
+            assert!(hashed.is_on_curve());
+            assert!(hashed.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!hashed.is_zero());
+            assert_eq!(hashed, point(x, y));
+
+            assert_ne!(hashed, hash_to_curve(msg, b"ANEMOI-OTHER-DST"));
+        }
+
+        // The cofactor is cleared from the sum of both mapped elements.
+        let u = hash_to_field(b"abc", DST, 2);
+        let mut sum = elligator2::map_to_curve::<BandersnatchParameters>(u[0]);
+        sum += &elligator2::map_to_curve::<BandersnatchParameters>(u[1]);
+        assert_eq!(
+            hash_to_curve(b"abc", DST),
+            sum.mul(Fr::from(4u64).into_repr()).into_affine()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! The Jubjub twisted Edwards curve over the BLS12-381 scalar field, as defined in
+//! `ark-ed-on-bls12-381`, and hashing to it with Anemoi.
+
+use super::super::BigInteger256;
+use super::super::{hash_to_field, Felt};
+use crate::elligator2::{self, Elligator2Parameters};
+
+pub use ark_ed_on_bls12_381::{
+    EdwardsAffine, EdwardsParameters, EdwardsProjective, Fr, FrParameters,
+};
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 5
+    const Z: Felt = Felt::new(BigInteger256([
+        0x0000000afffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x04c9cf6d363b9de5,
+    ]));
+}
+
+/// Hashes `msg` to a point of the prime order subgroup of Jubjub, under the
+/// domain separation tag `dst`.
+///
+/// This follows the `hash_to_curve` construction of RFC 9380: two field elements are
+/// obtained with [`hash_to_field`], i.e. with the extendable output of the
+/// [`anemoi_4_3`](super::super::anemoi_4_3) instantiation, mapped to the curve with Elligator 2
+/// and added, before clearing the cofactor 8.
+pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> EdwardsAffine {
+    elligator2::hash_to_curve(&hash_to_field(msg, dst, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::models::{MontgomeryModelParameters, TEModelParameters};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{Field, PrimeField, SquareRootField, Zero};
+    use core::str::FromStr;
+
+    const DST: &[u8] = b"ANEMOI-HASH-TO-CURVE-TEST";
+
+    fn point(x: &str, y: &str) -> EdwardsAffine {
+        EdwardsAffine::new(Felt::from_str(x).unwrap(), Felt::from_str(y).unwrap())
+    }
+
+    #[test]
+    fn test_parameters() {
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        assert!(generator.is_on_curve());
+        assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!generator.is_zero());
+        assert_eq!(
+            generator
+                .mul(Fr::from(8u64) * EdwardsParameters::COFACTOR_INV)
+                .into_affine(),
+            generator
+        );
+
+        let a = <EdwardsParameters as TEModelParameters>::COEFF_A;
+        let d = <EdwardsParameters as TEModelParameters>::COEFF_D;
+        let a_minus_d_inv = (a - d).inverse().unwrap();
+        assert_eq!(
+            <EdwardsParameters as MontgomeryModelParameters>::COEFF_A,
+            (a + d).double() * a_minus_d_inv
+        );
+        assert_eq!(
+            <EdwardsParameters as MontgomeryModelParameters>::COEFF_B,
+            Felt::from(4u64) * a_minus_d_inv
+        );
+        assert!(EdwardsParameters::Z.legendre().is_qnr());
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        // Generated with scripts/map_to_curve.py.
+        let vectors = [
+            ("0", "0", "1"),
+            (
+                "1",
+                "47633601603280006610789313726622991585680456048568348353623238627991706927331",
+                "26717253715073086798640381114484440255418655376037961375185397737823965039517",
+            ),
+            (
+                "2",
+                "8939167122236275985389874463189730541952005857336263231270313870381924952488",
+                "37039776997744301034797114274119785350284819088817060790642578578984972713323",
+            ),
+            (
+                "52435875175126190479447740508185965837690552500527637822603658699938581184512",
+                "47633601603280006610789313726622991585680456048568348353623238627991706927331",
+                "26717253715073086798640381114484440255418655376037961375185397737823965039517",
+            ),
+            (
+                "1512366075204170929049582354406559215",
+                "34142689057676246170922356500424863747051177287232322241703638006871536007388",
+                "19512888014487253915325868319825233344004984746090871268875644331282002700317",
+            ),
+            (
+                "17478625058375396826482580169395321945896850833509212607534552899979527061504",
+                "47059978255853680297413489294571339737634425515447469369431744933413102762415",
+                "46976087863682652100847847841353813180788497081861006887774851408206856418510",
+            ),
+        ];
+
+        for (u, x, y) in vectors {
+            let mapped = elligator2::map_to_curve::<EdwardsParameters>(Felt::from_str(u).unwrap());
+            assert!(mapped.is_on_curve());
+            assert_eq!(mapped, point(x, y));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        // Self-generated with this implementation, as there are no reference vectors for
+        // hashing to curves with Anemoi.
+        let vectors = [
+            (
+                &b""[..],
+                "46381151171251551119950095050639073677939068360448017950733797601584752923364",
+                "15493255557891697687245906215030267998859841149140574737869274047056636173489",
+            ),
+            (
+                &b"abc"[..],
+                "10579043106294066582872250192248920998683939528801194936279812050721531463611",
+                "30551721627414786835679211168124910072480402467169540842847356011762305876134",
+            ),
+            (
+                &[0u8; 200][..],
+                "7321002785208747279370158853942377117941440621446563065123808414812606547626",
+                "45920895795177874184273147108614753655043423160356098517138482501698461194081",
+            ),
+        ];
+
+        for (msg, x, y) in vectors {
+            let hashed = hash_to_curve(msg, DST);
+            assert!(hashed.is_on_curve());
+            assert!(hashed.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!hashed.is_zero());
+            assert_eq!(hashed, point(x, y));
+
+            assert_ne!(hashed, hash_to_curve(msg, b"ANEMOI-OTHER-DST"));
+        }
+
+        // The cofactor is cleared from the sum of both mapped elements.
+        let u = hash_to_field(b"abc", DST, 2);
+        let mut sum = elligator2::map_to_curve::<EdwardsParameters>(u[0]);
+        sum += &elligator2::map_to_curve::<EdwardsParameters>(u[1]);
+        assert_eq!(
+            hash_to_curve(b"abc", DST),
+            sum.mul(Fr::from(8u64).into_repr()).into_affine()
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Twisted Edwards curves defined over the BLS12-381 scalar field.
+
+/// The Bandersnatch curve, with cofactor 4.
+pub mod bandersnatch;
+
+/// The Jubjub curve, with cofactor 8.
+pub mod jubjub;
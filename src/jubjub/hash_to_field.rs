@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 48,
+    expanded: "09e31d620e17baef197d84c9feab52a61fbe21986acef7b27d6f00121fedcde5",
+    element: "50661002265796581486875186601350305919452116418174027074023349713832499765122",
+}
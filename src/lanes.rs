@@ -0,0 +1,81 @@
+//! Lane-wise arithmetic used by the batch APIs.
+//!
+//! The exponentiation by the inverse of `α` in the Anemoi S-Box is a long chain of dependent
+//! field multiplications, whose latency cannot be hidden when processing a single hash state.
+//! Evaluating the same chain on several independent elements at once, one lane per element,
+//! exposes instruction-level parallelism to the CPU between the Montgomery multiplications of
+//! the different lanes.
+
+use ark_ff::Field;
+use core::ops::{Mul, MulAssign};
+
+/// Elements on which the `exp_inv_alpha` addition chains can be evaluated.
+pub(crate) trait ChainElement:
+    Copy
+    + Mul<Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + MulAssign
+    + for<'a> MulAssign<&'a Self>
+{
+    /// Returns `self * self`.
+    fn square(&self) -> Self;
+}
+
+impl<F: Field> ChainElement for F {
+    #[inline(always)]
+    fn square(&self) -> Self {
+        Field::square(self)
+    }
+}
+
+/// `N` field elements on which every operation is performed lane-wise.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Lanes<F: Field, const N: usize>(pub(crate) [F; N]);
+
+impl<F: Field, const N: usize> Mul for Lanes<F, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: Self) -> Self {
+        self *= &rhs;
+        self
+    }
+}
+
+impl<'a, F: Field, const N: usize> Mul<&'a Self> for Lanes<F, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: &'a Self) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<F: Field, const N: usize> MulAssign for Lanes<F, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs;
+    }
+}
+
+impl<'a, F: Field, const N: usize> MulAssign<&'a Self> for Lanes<F, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &'a Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a *= b;
+        }
+    }
+}
+
+impl<F: Field, const N: usize> ChainElement for Lanes<F, N> {
+    #[inline]
+    fn square(&self) -> Self {
+        let mut result = *self;
+        for a in result.0.iter_mut() {
+            a.square_in_place();
+        }
+
+        result
+    }
+}
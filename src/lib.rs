@@ -4,6 +4,25 @@
 //!
 //! All hash instantiations are defined using a `Sponge` trait and can both
 //! process sequences of bytes or native field elements.
+//!
+//! # Side-channel resistance
+//!
+//! The Anemoi permutation performs a fixed sequence of field operations, which does
+//! not depend on the processed values: the inverse exponentiation of the S-Box uses a
+//! fixed addition chain, and the multiplications by the field generator fixed sequences
+//! of doublings and additions. The sponge and compression modes only branch on the length
+//! of their input and on the compression factor, which are assumed to be public.
+//!
+//! However, the underlying arkworks field arithmetic is **not** constant-time: modular
+//! additions, subtractions and multiplications end with a conditional correction which
+//! branches on the value of the result, and comparisons of field elements, including
+//! digests, exit early. Hence, no function of this crate should be considered constant-time
+//! with respect to its input, nor be used to process secret data when timing side-channels
+//! are a concern. The ignored `ct::` tests measure the timing leakage of the S-Box and of
+//! the permutation over each field with a dudect-style statistical test, and can be run with
+//! `cargo test --release ct:: -- --ignored --test-threads 1 --nocapture`. They fail if
+//! leakage is detected, which is expected with the current field arithmetic. A constant-time
+//! field arithmetic backend is out of the scope of this crate.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(rustdoc::broken_intra_doc_links)]
@@ -16,9 +35,86 @@
 #[macro_use]
 extern crate alloc;
 
+// Allows the derive macro to refer to this crate from within its tests
+#[cfg(test)]
+extern crate self as anemoi;
+
 mod traits;
 pub use traits::*;
 
+/// Number of bits of security kept by the extendable output readers of the hashers, which
+/// squeeze `(CAPACITY - XOF_BIAS_BITS) / 8` bytes from each rate register. As the field
+/// modulus `p` exceeds `2^CAPACITY`, these bytes are within a statistical distance of
+/// `2^(8 * bytes) / p < 2^-XOF_BIAS_BITS` from the uniform distribution.
+#[allow(unused)]
+pub(crate) const XOF_BIAS_BITS: u32 = 127;
+
+/// Number of bits of security kept by the extendable output readers of the `anemoi_256`
+/// hashers, which squeeze `(2 * CAPACITY - XOF_256_BIAS_BITS) / 8` bytes from each pair
+/// `(x0, x1)` of rate registers, read as the integer `x0 + p * x1`. As this integer is
+/// uniformly distributed over `[0, p^2)`, these bytes are within a statistical distance of
+/// `2^(8 * bytes) / p^2 < 2^-XOF_256_BIAS_BITS` from the uniform distribution.
+#[cfg(feature = "anemoi_256")]
+pub(crate) const XOF_256_BIAS_BITS: u32 = 255;
+
+mod lanes;
+
+mod encoding;
+pub use encoding::Encoding;
+
+mod padding;
+pub use padding::PaddingRule;
+
+mod instance;
+pub use instance::{AnemoiInstance, DynSponge, ParseInstanceError};
+
+mod affine;
+pub use affine::AffineEncoding;
+
+/// Injective encoding of structured values as sequences of field elements, as absorbed by
+/// Anemoi hashers.
+pub mod absorb;
+pub use absorb::AnemoiAbsorb;
+#[cfg(feature = "derive")]
+pub use anemoi_derive::AnemoiAbsorb;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(not(feature = "std"))]
+    pub use alloc::vec::Vec;
+    pub use ark_ff::PrimeField;
+    #[cfg(feature = "std")]
+    pub use std::vec::Vec;
+}
+
+/// Decomposition of elements of foreign prime fields into limbs, as absorbed by Anemoi hashers.
+pub mod foreign;
+
+mod serialization;
+pub use serialization::DigestError;
+
+#[cfg(any(feature = "ed_on_bls12_377", feature = "jubjub"))]
+mod elligator2;
+
+#[cfg(any(feature = "bls12_377", feature = "bls12_381"))]
+mod sswu;
+
+#[cfg(feature = "bn_254")]
+mod svdw;
+
+#[macro_use]
+mod hash_to_field;
+
+/// Timing leakage tests of the Anemoi permutation.
+#[cfg(all(test, feature = "std"))]
+mod ct;
+
+pub mod params;
+
+/// Generation of the constants of Anemoi instantiations.
+#[cfg(feature = "std")]
+pub mod codegen;
+
 /// An implementation of instantiations of the Anemoi permutation
 /// in Sponge and Jive modes targetting the 128-bit security level
 /// over BLS12-377 base field.
@@ -37,6 +133,13 @@ pub mod bls12_381;
 #[cfg(feature = "bn_254")]
 pub mod bn_254;
 
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over BW6-761 base field. Its numbers of rounds follow the rule of the
+/// reference implementation, given by [`crate::params::reference_num_rounds`].
+#[cfg(feature = "bw6_761")]
+pub mod bw6_761;
+
 /// An implementation of instantiations of the Anemoi permutation
 /// in Sponge and Jive modes targetting the 128-bit security level
 /// over ED_ON_BLS12-377 base field.
@@ -55,6 +158,27 @@ pub mod jubjub;
 #[cfg(feature = "pallas")]
 pub mod pallas;
 
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over secp256k1 base field. Its numbers of rounds follow the rule of the
+/// reference implementation, given by [`crate::params::reference_num_rounds`].
+#[cfg(feature = "secp256k1")]
+pub mod secp256k1;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over secq256k1 base field. Its numbers of rounds follow the rule of the
+/// reference implementation, given by [`crate::params::reference_num_rounds`].
+#[cfg(feature = "secq256k1")]
+pub mod secq256k1;
+
+/// An implementation of instantiations of the Anemoi permutation
+/// in Sponge and Jive modes targetting the 128-bit security level
+/// over STARK-252 base field. Its numbers of rounds follow the rule of the
+/// reference implementation, given by [`crate::params::reference_num_rounds`].
+#[cfg(feature = "stark252")]
+pub mod stark252;
+
 /// An implementation of instantiations of the Anemoi permutation
 /// in Sponge and Jive modes targetting the 128-bit security level
 /// over Vesta base field.
@@ -0,0 +1,271 @@
+//! Padding rules of the Anemoi sponge.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::Encoding;
+use ark_ff::PrimeField;
+
+/// Rule to split the bytes hashed by an Anemoi sponge into field elements, selected with
+/// [`Sponge::hash_with_padding`](crate::Sponge::hash_with_padding).
+///
+/// Each rule is one of the [`Encoding`]s splitting bytes into chunks of
+/// `k = F::Params::CAPACITY / 8` bytes, i.e. 31 bytes for 256-bit fields and 47 bytes for
+/// 384-bit fields, which only differ in how the last chunk is completed. The resulting
+/// sequence of field elements is then padded as specified in the Anemoi paper by
+/// [`Sponge::hash_field`](crate::Sponge::hash_field), whatever the rule.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PaddingRule {
+    /// The rule of the Anemoi paper and of its reference implementation, i.e.
+    /// [`Encoding::Paper`].
+    Paper,
+    /// The rule of [`Sponge::hash`](crate::Sponge::hash), i.e. [`Encoding::Padded`].
+    #[default]
+    Current,
+}
+
+impl PaddingRule {
+    /// Returns the sequence of elements of `F` encoding `bytes` with this rule, before
+    /// the padding of the sponge.
+    pub fn encode<F: PrimeField>(&self, bytes: &[u8]) -> Vec<F> {
+        Encoding::from(*self).encode(bytes)
+    }
+}
+
+impl From<PaddingRule> for Encoding {
+    fn from(rule: PaddingRule) -> Self {
+        match rule {
+            PaddingRule::Paper => Encoding::Paper,
+            PaddingRule::Current => Encoding::Padded,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sponge;
+    use ark_ff::{One, Zero};
+
+    // Returns the bytes whose chunks of `chunk_size` bytes encode `bits` as field elements.
+    fn chunks(bits: &[u8], chunk_size: usize) -> Vec<u8> {
+        bits.iter()
+            .flat_map(|&bit| {
+                let mut chunk = vec![0u8; chunk_size];
+                chunk[0] = bit;
+                chunk
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "bn_254")]
+    #[test]
+    fn test_bn_254_paper_rule() {
+        use crate::bn_254::anemoi_4_3::AnemoiHash;
+        use crate::bn_254::Felt;
+
+        // Vectors of `test_anemoi_hash`, generated from https://github.com/anemoi-hash/anemoi-hash/.
+        // The reference implementation only hashes field elements, so that the byte inputs
+        // are these elements written as chunks of 31 bytes.
+        let vectors = [
+            (
+                [0, 0, 0, 0],
+                "42b9d6691deed006ace372b5852fb3e793c157c77b0db933d04033dcb09fab02",
+            ),
+            (
+                [1, 1, 1, 1],
+                "7722647235f1e5e33d058f2744d17455eeb112f9626f1e9b6a26583b58cbc12a",
+            ),
+            (
+                [0, 0, 1, 1],
+                "5a9052a06cb0a8b5c2621dbd2a9feed6c273cd7103317f30ca929faa2703810f",
+            ),
+            (
+                [1, 1, 0, 0],
+                "859b196b9089f57c3f69db4db961e57918922e8038a6bb8abdcd12dd9a67c32f",
+            ),
+        ];
+
+        for (bits, digest) in vectors {
+            let elements: Vec<Felt> = bits.iter().map(|&bit| Felt::from(bit)).collect();
+            let bytes = chunks(&bits, 31);
+            assert_eq!(PaddingRule::Paper.encode::<Felt>(&bytes), elements);
+
+            let hash = AnemoiHash::hash_field(&elements);
+            assert_eq!(hash.to_string(), digest);
+            let hash = AnemoiHash::hash_with_padding(&bytes, PaddingRule::Paper);
+            assert_eq!(hash.to_string(), digest);
+        }
+
+        // Trailing null bytes are only distinguished by the paper rule if they start a new chunk
+        for len in 1..31 {
+            let bytes = [b"anemoi".as_ref(), &vec![0; len - 1]].concat();
+            let mut extended = bytes.clone();
+            extended.push(0);
+            let paper = AnemoiHash::hash_with_padding(&bytes, PaddingRule::Paper);
+            let current = AnemoiHash::hash_with_padding(&bytes, PaddingRule::Current);
+            assert_eq!(
+                paper == AnemoiHash::hash_with_padding(&extended, PaddingRule::Paper),
+                extended.len().div_ceil(31) == bytes.len().div_ceil(31)
+            );
+            assert_ne!(
+                current,
+                AnemoiHash::hash_with_padding(&extended, PaddingRule::Current)
+            );
+        }
+        assert_eq!(
+            AnemoiHash::hash_with_padding(b"anemoi", PaddingRule::Current),
+            AnemoiHash::hash(b"anemoi")
+        );
+        assert_eq!(
+            AnemoiHash::hash_with_padding(b"anemoi", PaddingRule::Paper),
+            AnemoiHash::hash_field(&[Felt::from(0x696f6d656e61u64)])
+        );
+        assert_eq!(
+            AnemoiHash::hash_with_padding(&[], PaddingRule::Paper),
+            AnemoiHash::hash_field(&[])
+        );
+        assert_ne!(
+            AnemoiHash::hash_field(&[Felt::one(), Felt::zero()]),
+            AnemoiHash::hash_field(&[Felt::one()])
+        );
+
+        // Vectors of the paper rule, generated with `scripts/anemoi.py`, over the bytes
+        // `0, 1, ..., len - 1`. The rules agree on empty inputs and on inputs made of whole
+        // chunks, here of 1 and 2 times the rate, and disagree on a short last chunk.
+        let vectors = [
+            (
+                0,
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ),
+            (
+                93,
+                "260315f1757761b6c884814b2604e75d4d0664c4856c48b040f6e3bd0d335416",
+            ),
+            (
+                186,
+                "816581991595a2dbb1cdc42071f1ade04b3e9792dbcee8869f4bd5d4549e061d",
+            ),
+            (
+                92,
+                "40bbf670d6e7e7e7e9343f55be486813c65b7df9adec20e0f1eb340c8a842b25",
+            ),
+        ];
+
+        for (len, digest) in vectors {
+            let bytes: Vec<u8> = (0..len as u8).collect();
+            let paper = AnemoiHash::hash_with_padding(&bytes, PaddingRule::Paper);
+            assert_eq!(paper.to_string(), digest);
+            assert_eq!(
+                paper == AnemoiHash::hash_with_padding(&bytes, PaddingRule::Current),
+                len % 31 == 0
+            );
+        }
+
+        // Vectors of `Sponge::hash_field` over sequences whose lengths are multiples of the
+        // rate or not, generated with `scripts/anemoi.py`
+        let vectors: [(&[u64], &str); 3] = [
+            (
+                &[],
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ),
+            (
+                &[1, 2, 3],
+                "0988724de0e07560f8b5f96a05ec989be8e08d4d2412c8b158e9b9e6e6ed7628",
+            ),
+            (
+                &[1, 2],
+                "e296d5007887c579ab1b4276e30de80141f942d83b6f0643e08354033369501a",
+            ),
+        ];
+
+        for (elements, digest) in vectors {
+            let elements: Vec<Felt> = elements.iter().map(|&e| Felt::from(e)).collect();
+            let hash = AnemoiHash::hash_field(&elements);
+            assert_eq!(hash.to_string(), digest);
+        }
+    }
+
+    #[cfg(feature = "bls12_381")]
+    #[test]
+    fn test_bls12_381_paper_rule() {
+        use crate::bls12_381::anemoi_2_1::AnemoiHash;
+        use crate::bls12_381::Felt;
+
+        // Vectors of `test_anemoi_hash`, generated from https://github.com/anemoi-hash/anemoi-hash/,
+        // with each element written as a chunk of 47 bytes.
+        let vectors = [
+            (
+                [0, 0],
+                "8b9de016c34e9923cea2ec426bf4c850a45bd0d98ebb2ca18e8ecd9cec8768668d7d03de1ba766da6b8c27a70ce1b115",
+            ),
+            (
+                [1, 1],
+                "db79bd92cee519759adc96395699bc66a719c833b2220774363c8c23e65977e78808a9461b8c8d0fa62e6c6fb54f1409",
+            ),
+            (
+                [0, 1],
+                "0f7d9de8a8004cae18fe09e0142410430bd29a66fb663618d9fa523e4dbe3e84aa1d145556d7a962a1d4127c32e6830f",
+            ),
+            (
+                [1, 0],
+                "623bf6b5b5c43c247073fe125971aa5228b32d53021bdbdf344f565b6715e476c44495c48f3718adf0dcca9842dcbd11",
+            ),
+        ];
+
+        for (bits, digest) in vectors {
+            let elements: Vec<Felt> = bits.iter().map(|&bit| Felt::from(bit)).collect();
+            let bytes = chunks(&bits, 47);
+            assert_eq!(PaddingRule::Paper.encode::<Felt>(&bytes), elements);
+
+            let hash = AnemoiHash::hash_field(&elements);
+            assert_eq!(hash.to_string(), digest);
+            let hash = AnemoiHash::hash_with_padding(&bytes, PaddingRule::Paper);
+            assert_eq!(hash.to_string(), digest);
+        }
+
+        // Chunks spanning the whole capacity are never padded with the current rule
+        let bytes = chunks(&[1, 0], 47);
+        assert_eq!(
+            AnemoiHash::hash_with_padding(&bytes, PaddingRule::Current),
+            AnemoiHash::hash_with_padding(&bytes, PaddingRule::Paper)
+        );
+
+        // Vectors of the paper rule, generated with `scripts/anemoi.py`, over the bytes
+        // `0, 1, ..., len - 1`. With a rate of 1, every non-empty sequence of elements has a
+        // length multiple of the rate.
+        let vectors = [
+            (
+                0,
+                "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            ),
+            (
+                47,
+                "86e02a5bce3e949e1a7a7e644e448a3a2ab5188b152bcbb837895334b68a95a708abbafa3092edb4f9d6d7300f3ea30c",
+            ),
+            (
+                94,
+                "ae710e7d06f92140f2de2c27302c9d71b9c83fe70a7ab7327f95c00b41ff3801eb1f85cb27af4d81ca957dba7f27500c",
+            ),
+            (
+                46,
+                "362ef945416218f28c8dd953624ca999e245d59b9ff2a8e7ea3331641764e5cda64858d9c44aadf7b4ac5709cb3d4409",
+            ),
+        ];
+
+        for (len, digest) in vectors {
+            let bytes: Vec<u8> = (0..len as u8).collect();
+            let paper = AnemoiHash::hash_with_padding(&bytes, PaddingRule::Paper);
+            assert_eq!(paper.to_string(), digest);
+            assert_eq!(
+                paper == AnemoiHash::hash_with_padding(&bytes, PaddingRule::Current),
+                len % 47 == 0
+            );
+        }
+
+        assert_eq!(
+            AnemoiHash::hash_field(&[Felt::one()]).to_string(),
+            "311afebab8b37eb4da2caaa704cdf352b4c969a617ed7139dcecbbf97f282be944a5951069178c536d097ef90bdb5002"
+        );
+    }
+}
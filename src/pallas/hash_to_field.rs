@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 48,
+    expanded: "ca350528f9c2b68230f7402ad453a7514ad66b1705582423044592ab1072bc67",
+    element: "27536064845780025178102238439945079840630270363453032288045698804689162079106",
+}
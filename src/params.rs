@@ -0,0 +1,478 @@
+//! Security parameters computation for Anemoi instantiations.
+//!
+//! The number of rounds of an Anemoi instantiation follows the rule of the Anemoi
+//! paper (Bouvier et al., "New Design Techniques for Efficient Arithmetization-Oriented
+//! Hash Functions: Anemoi Permutations and Jive Compression Mode", CRYPTO 2023, ePrint
+//! 2022/840). Over a field of `p` elements, with `l` columns, an S-Box exponent `α`
+//! and targetting `s` bits of security, it is
+//!
+//! `r = max(8, min(5, l + 1) + 2 + r_alg)`,
+//!
+//! where `8` rounds thwart statistical (differential and linear) attacks, `min(5, l + 1)`
+//! rounds are a security margin, to which the paper adds `2` rounds, and `r_alg` is the
+//! maximum of the following algebraic bounds:
+//!
+//! - Gröbner basis: solving the CICO problem modelled by `2lr` variables with a Gröbner
+//!   basis at its degree of regularity costs `binom(4lr + κ_α, 2lr)^2` operations, where
+//!   `κ_α` is given by the table [`kappa`] of the paper. See [`groebner_rounds`].
+//! - Linearization: solving the same system by linearization at the Macaulay bound
+//!   `D = 1 + 2lr(α - 1)` costs `binom(2lr + D, 2lr)^2` operations.
+//!   See [`linearization_rounds`].
+//! - Interpolation: the polynomial of the `l` free inputs of the CICO problem must have
+//!   at least `2^s` monomials. See [`interpolation_rounds`].
+//! - Algebraic degree: a higher-order differential on the `2l` inputs must sum over more
+//!   than `2^s` points. See [`algebraic_rounds`].
+//!
+//! The two degree bounds rely on the exponentiation by `1/α` of the open Flystel, whose
+//! degree is at least `p / α` in both directions, so they are met after a single round
+//! whenever the field is large enough.
+//!
+//! The reference implementation of Anemoi follows a different rule, given by
+//! [`reference_num_rounds`]: the Gröbner basis bound plus `min(5, l + 1)` rounds, without
+//! the `2` additional rounds of the paper, with a floor of `10` rounds. Its Gröbner basis
+//! bound uses `κ_α = (α - 1) / 2`, which only matches the table of the paper for `α = 3`
+//! and `α = 5`, but reproduces its instantiations with `α = 11` as well. The 128-bit
+//! instantiations over the BLS12-377, BLS12-381, BN-254, Ed-on-BLS12-377, Jubjub, Pallas
+//! and Vesta fields were taken from the reference implementation and follow this rule, as
+//! do all other 128-bit instantiations. The 256-bit instantiations follow the rule of the
+//! paper. Both rules differ as follows at the 128-bit security level:
+//!
+//! | `α` | `l`              | paper                  | reference              |
+//! |-----|------------------|------------------------|------------------------|
+//! | 3   | 1, 2, 3, 4, 5, 6 | 21, 14, 12, 12, 11, 10 | 19, 12, 10, 10, 10, 10 |
+//! | 5   | 1, 2, 3, 4, 5, 6 | 21, 14, 12, 12, 11, 10 | 19, 12, 10, 10, 10, 10 |
+//! | 11  | 1, 2, 3, 4, 5, 6 | 19, 13, 11, 11, 10, 10 | 18, 11, 10, 10, 10, 10 |
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+
+/// Minimum number of rounds of any Anemoi instantiation, regardless of its
+/// width and security level, to thwart statistical attacks.
+pub const MIN_NUM_ROUNDS: usize = 8;
+
+/// Number of rounds added by the paper to the algebraic bounds, on top of the
+/// security margin.
+pub const ADDITIONAL_ROUNDS: usize = 2;
+
+/// Minimum number of rounds of the instantiations of the reference implementation.
+pub const REFERENCE_MIN_NUM_ROUNDS: usize = 10;
+
+/// Returns `true` if `x -> x^alpha` is a permutation of the field `F`,
+/// i.e. if `alpha` is coprime with `p - 1`.
+pub fn is_valid_alpha<F: PrimeField>(alpha: u32) -> bool {
+    if alpha < 3 {
+        return false;
+    }
+
+    // Compute (p - 1) mod alpha limb by limb, starting with the most significant one.
+    let mut modulus = F::Params::MODULUS;
+    modulus.sub_noborrow(&F::BigInt::from(1));
+    let remainder = modulus.as_ref().iter().rev().fold(0u128, |acc, &limb| {
+        ((acc << 64) | limb as u128) % alpha as u128
+    });
+
+    gcd(remainder as u32, alpha) == 1
+}
+
+/// Returns the constant `κ_α` of the paper, bounding the degree of regularity of the
+/// CICO system of an instantiation with S-Box exponent `alpha`, or `None` if the paper
+/// does not provide it for `alpha`.
+pub fn kappa(alpha: u32) -> Option<usize> {
+    match alpha {
+        3 => Some(1),
+        5 => Some(2),
+        7 => Some(4),
+        9 => Some(7),
+        11 => Some(9),
+        _ => None,
+    }
+}
+
+/// Returns the smallest number of rounds `r` for which `binom(4lr + κ_α, 2lr)^2`
+/// is at least `2^security_level`, thwarting Gröbner basis attacks on an instantiation
+/// with `num_columns` columns and S-Box exponent `alpha`.
+///
+/// Returns `None` if `κ_α` is not known for `alpha`.
+///
+/// # Panics
+/// Panics if `num_columns` is zero.
+pub fn groebner_rounds(alpha: u32, num_columns: usize, security_level: u32) -> Option<usize> {
+    Some(groebner_bound(kappa(alpha)?, num_columns, security_level))
+}
+
+/// Returns the smallest number of rounds `r` for which `binom(2lr + D, 2lr)^2`, with
+/// `D = 1 + 2lr(α - 1)`, is at least `2^security_level`, thwarting linearization attacks
+/// on an instantiation with `num_columns` columns and S-Box exponent `alpha`.
+///
+/// # Panics
+/// Panics if `alpha` is smaller than 2 or `num_columns` is zero.
+pub fn linearization_rounds(alpha: u32, num_columns: usize, security_level: u32) -> usize {
+    assert!(alpha >= 2, "the S-Box exponent must be at least 2");
+    assert!(num_columns > 0, "the number of columns must be positive");
+
+    smallest_rounds(security_level, |rounds| {
+        let num_variables = 2 * num_columns * rounds;
+        let degree = 1 + num_variables * (alpha as usize - 1);
+        binomial(num_variables + degree, num_variables)
+    })
+}
+
+/// Returns the number of rounds after which a polynomial in the `num_columns` free
+/// inputs of the CICO problem over `F`, of degree at least `p / alpha` in each of them,
+/// has at least `2^security_level` monomials, thwarting interpolation attacks.
+///
+/// Returns `None` if this never happens, i.e. if `F` is too small.
+pub fn interpolation_rounds<F: PrimeField>(
+    alpha: u32,
+    num_columns: usize,
+    security_level: u32,
+) -> Option<usize> {
+    degree_rounds::<F>(alpha, num_columns, security_level)
+}
+
+/// Returns the number of rounds after which a higher-order differential on the
+/// `2 * num_columns` inputs over `F`, of degree at least `p / alpha` in each of them,
+/// sums over at least `2^security_level` points, thwarting algebraic degree attacks.
+///
+/// Returns `None` if this never happens, i.e. if `F` is too small.
+pub fn algebraic_rounds<F: PrimeField>(
+    alpha: u32,
+    num_columns: usize,
+    security_level: u32,
+) -> Option<usize> {
+    degree_rounds::<F>(alpha, 2 * num_columns, security_level)
+}
+
+/// Returns the security margin, in number of rounds, added on top of the
+/// algebraic bounds.
+pub fn security_margin(num_columns: usize) -> usize {
+    core::cmp::min(5, num_columns + 1)
+}
+
+/// Returns the number of rounds of an Anemoi instantiation over `F` with `num_columns`
+/// columns and S-Box exponent `alpha`, targetting `security_level` bits of security,
+/// following the rule of the paper.
+///
+/// Returns `None` if `alpha` does not define a permutation over `F`, if `κ_α` is not
+/// known for `alpha`, or if `F` is too small to reach `security_level`.
+pub fn num_rounds<F: PrimeField>(
+    alpha: u32,
+    num_columns: usize,
+    security_level: u32,
+) -> Option<usize> {
+    if !is_valid_alpha::<F>(alpha) || num_columns == 0 {
+        return None;
+    }
+
+    let algebraic = [
+        groebner_rounds(alpha, num_columns, security_level)?,
+        linearization_rounds(alpha, num_columns, security_level),
+        interpolation_rounds::<F>(alpha, num_columns, security_level)?,
+        algebraic_rounds::<F>(alpha, num_columns, security_level)?,
+    ]
+    .into_iter()
+    .max()
+    .unwrap();
+    let rounds = security_margin(num_columns) + ADDITIONAL_ROUNDS + algebraic;
+
+    Some(core::cmp::max(MIN_NUM_ROUNDS, rounds))
+}
+
+/// Returns the number of rounds of an Anemoi instantiation with `num_columns` columns
+/// and S-Box exponent `alpha`, targetting `security_level` bits of security, following
+/// the rule of the reference implementation.
+///
+/// Returns `None` if `κ_α` is not known for `alpha`.
+///
+/// # Panics
+/// Panics if `num_columns` is zero.
+pub fn reference_num_rounds(alpha: u32, num_columns: usize, security_level: u32) -> Option<usize> {
+    // The reference implementation takes `κ_α = (α - 1) / 2`, for the exponents of the paper.
+    let kappa = kappa(alpha).map(|_| (alpha as usize - 1) / 2)?;
+    let rounds = security_margin(num_columns) + groebner_bound(kappa, num_columns, security_level);
+
+    Some(core::cmp::max(REFERENCE_MIN_NUM_ROUNDS, rounds))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Returns the smallest number of rounds `r` for which `binom(4lr + kappa, 2lr)^2`
+/// is at least `2^security_level`, with `l = num_columns`.
+fn groebner_bound(kappa: usize, num_columns: usize, security_level: u32) -> usize {
+    assert!(num_columns > 0, "the number of columns must be positive");
+
+    smallest_rounds(security_level, |rounds| {
+        let num_variables = 2 * num_columns * rounds;
+        binomial(2 * num_variables + kappa, num_variables)
+    })
+}
+
+/// Returns the smallest positive number of rounds `r` for which `cost(r)^2`
+/// is at least `2^security_level`, `cost` being increasing.
+fn smallest_rounds(security_level: u32, cost: impl Fn(usize) -> Vec<u32>) -> usize {
+    let mut rounds = 1;
+    loop {
+        let cost = cost(rounds);
+        // 2^security_level <= cost^2 if and only if 2^(security_level - 1) < cost^2.
+        if bit_length(&mul(&cost, &cost)) > security_level as usize {
+            return rounds;
+        }
+        rounds += 1;
+    }
+}
+
+/// Returns `Some(1)` if a dense polynomial in `num_variables` variables, of degree at
+/// least `p / alpha` in each of them, has at least `2^security_level` monomials.
+///
+/// As `binom(n + d, n) >= (d / n)^n`, there are at least `2^(n * (log2(d) - log2(n)))`
+/// such monomials. The degree does not grow further after the first round, as it
+/// is already close to `p`.
+fn degree_rounds<F: PrimeField>(
+    alpha: u32,
+    num_variables: usize,
+    security_level: u32,
+) -> Option<usize> {
+    let modulus_bits = F::Params::MODULUS_BITS as usize;
+    // floor(log2(p / alpha)) >= floor(log2(p)) - ceil(log2(alpha)).
+    let degree_bits = (modulus_bits - 1).checked_sub(ceil_log2(alpha as usize))?;
+    let monomial_bits = num_variables * degree_bits.checked_sub(ceil_log2(num_variables))?;
+
+    (monomial_bits >= security_level as usize).then_some(1)
+}
+
+fn ceil_log2(n: usize) -> usize {
+    (usize::BITS - (n - 1).leading_zeros()) as usize
+}
+
+/// Computes `binom(n, k)` as a little-endian sequence of 32-bit limbs.
+fn binomial(n: usize, k: usize) -> Vec<u32> {
+    let mut result = vec![1u32];
+    for i in 1..=k {
+        // The intermediate result binom(n - k + i, i) is always an integer.
+        let mut carry = 0u64;
+        for limb in result.iter_mut() {
+            let t = *limb as u64 * (n - k + i) as u64 + carry;
+            *limb = t as u32;
+            carry = t >> 32;
+        }
+        if carry != 0 {
+            result.push(carry as u32);
+        }
+
+        let mut remainder = 0u64;
+        for limb in result.iter_mut().rev() {
+            let t = (remainder << 32) | *limb as u64;
+            *limb = (t / i as u64) as u32;
+            remainder = t % i as u64;
+        }
+        while result.len() > 1 && result[result.len() - 1] == 0 {
+            result.pop();
+        }
+    }
+
+    result
+}
+
+fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let t = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+
+    result
+}
+
+fn bit_length(a: &[u32]) -> usize {
+    match a.iter().rposition(|&limb| limb != 0) {
+        Some(i) => 32 * i + 32 - a[i].leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(5, 2), vec![10]);
+        assert_eq!(binomial(10, 0), vec![1]);
+        // binom(68, 34) = 28453041475240576740
+        assert_eq!(binomial(68, 34), vec![0x972bc6e4, 0x8add8278, 0x1]);
+        assert_eq!(bit_length(&mul(&[1 << 31], &[1 << 31])), 63);
+        assert_eq!(ceil_log2(1), 0);
+        assert_eq!(ceil_log2(5), 3);
+        assert_eq!(ceil_log2(8), 3);
+    }
+
+    #[test]
+    fn test_bounds() {
+        // α = 5, l = 1, s = 128: binom(4r + 2, 2r)^2 >= 2^128 first holds for r = 17.
+        assert_eq!(groebner_rounds(5, 1, 128), Some(17));
+        assert_eq!(groebner_rounds(13, 1, 128), None);
+        assert_eq!(linearization_rounds(5, 1, 128), 10);
+        for num_columns in 1..7 {
+            for security_level in [128, 256] {
+                assert!(
+                    linearization_rounds(3, num_columns, security_level)
+                        <= groebner_rounds(3, num_columns, security_level).unwrap()
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "bn_254")]
+    #[test]
+    fn test_degree_bounds() {
+        use crate::bn_254::Felt;
+
+        assert_eq!(interpolation_rounds::<Felt>(5, 1, 128), Some(1));
+        assert_eq!(algebraic_rounds::<Felt>(5, 1, 256), Some(1));
+        // A single free input of degree below p cannot provide 2^256 monomials.
+        assert_eq!(interpolation_rounds::<Felt>(5, 1, 256), None);
+        assert_eq!(num_rounds::<Felt>(5, 1, 256), None);
+    }
+
+    #[test]
+    fn test_reference_rounds() {
+        let rounds: Vec<_> = (1..7)
+            .map(|num_columns| reference_num_rounds(5, num_columns, 128).unwrap())
+            .collect();
+        assert_eq!(rounds, [19, 12, 10, 10, 10, 10]);
+        let rounds: Vec<_> = (1..7)
+            .map(|num_columns| reference_num_rounds(11, num_columns, 128).unwrap())
+            .collect();
+        assert_eq!(rounds, [18, 11, 10, 10, 10, 10]);
+        assert_eq!(reference_num_rounds(13, 1, 128), None);
+    }
+
+    /// Checks that the `NUM_HASH_ROUNDS` of the given instantiations of a field
+    /// are the ones computed by `$rule`.
+    macro_rules! check_rounds {
+        ($field:ident, $rule:expr, $($($instance:ident)::+),+) => {
+            $(
+                assert_eq!(
+                    Some(crate::$field::$($instance)::+::NUM_HASH_ROUNDS),
+                    $rule(crate::$field::$($instance)::+::NUM_COLUMNS),
+                    concat!(stringify!($field), "::", stringify!($($instance)::+)),
+                );
+            )+
+        };
+    }
+
+    /// Checks the number of rounds of the 256-bit instantiations of a field with S-Box
+    /// exponent `$alpha`, which follow the rule of the paper.
+    macro_rules! check_256_field {
+        ($field:ident, $alpha:literal) => {
+            #[cfg(feature = "anemoi_256")]
+            check_rounds!(
+                $field,
+                |num_columns| num_rounds::<crate::$field::Felt>($alpha, num_columns, 256),
+                anemoi_256::anemoi_4_2,
+                anemoi_256::anemoi_6_4,
+                anemoi_256::anemoi_8_6,
+                anemoi_256::anemoi_10_8,
+                anemoi_256::anemoi_12_10
+            );
+            #[cfg(feature = "anemoi_256")]
+            assert_eq!(
+                [
+                    crate::$field::anemoi_256::anemoi_4_2::NUM_HASH_ROUNDS,
+                    crate::$field::anemoi_256::anemoi_6_4::NUM_HASH_ROUNDS,
+                    crate::$field::anemoi_256::anemoi_8_6::NUM_HASH_ROUNDS,
+                    crate::$field::anemoi_256::anemoi_10_8::NUM_HASH_ROUNDS,
+                    crate::$field::anemoi_256::anemoi_12_10::NUM_HASH_ROUNDS,
+                ],
+                [22, 17, 16, 14, 13]
+            );
+        };
+    }
+
+    /// Checks the number of rounds of the 128-bit instantiations of a field with S-Box
+    /// exponent `$alpha`, which follow the rule of the reference implementation.
+    macro_rules! check_reference_field {
+        ($field:ident, $alpha:literal) => {
+            check_rounds!(
+                $field,
+                |num_columns| reference_num_rounds($alpha, num_columns, 128),
+                anemoi_2_1,
+                anemoi_4_3,
+                anemoi_6_5,
+                anemoi_8_7,
+                anemoi_10_9,
+                anemoi_12_11
+            );
+        };
+    }
+
+    #[test]
+    fn test_num_rounds() {
+        #[cfg(feature = "bls12_377")]
+        {
+            check_reference_field!(bls12_377, 5);
+            check_256_field!(bls12_377, 5);
+        }
+        #[cfg(feature = "bls12_381")]
+        {
+            check_reference_field!(bls12_381, 5);
+            check_256_field!(bls12_381, 5);
+        }
+        #[cfg(feature = "bn_254")]
+        check_reference_field!(bn_254, 5);
+        #[cfg(feature = "bw6_761")]
+        {
+            check_reference_field!(bw6_761, 5);
+            check_256_field!(bw6_761, 5);
+        }
+        #[cfg(feature = "ed_on_bls12_377")]
+        check_reference_field!(ed_on_bls12_377, 11);
+        #[cfg(feature = "jubjub")]
+        check_reference_field!(jubjub, 5);
+        #[cfg(feature = "pallas")]
+        check_reference_field!(pallas, 5);
+        #[cfg(feature = "secp256k1")]
+        {
+            check_reference_field!(secp256k1, 5);
+            check_256_field!(secp256k1, 5);
+        }
+        #[cfg(feature = "secq256k1")]
+        {
+            check_reference_field!(secq256k1, 5);
+            check_256_field!(secq256k1, 5);
+        }
+        #[cfg(feature = "stark252")]
+        check_reference_field!(stark252, 3);
+        #[cfg(feature = "vesta")]
+        check_reference_field!(vesta, 5);
+    }
+
+    #[cfg(feature = "bn_254")]
+    #[test]
+    fn test_alpha() {
+        use crate::bn_254::Felt;
+
+        // p - 1 is divisible by 3 for the BN-254 base field.
+        assert!(!is_valid_alpha::<Felt>(3));
+        assert!(is_valid_alpha::<Felt>(5));
+        assert!(!is_valid_alpha::<Felt>(2));
+        assert_eq!(num_rounds::<Felt>(3, 1, 128), None);
+        assert_eq!(num_rounds::<Felt>(13, 1, 128), None);
+    }
+}
@@ -0,0 +1,2677 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U9>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x465e0e2a17da9e2b,
+                0x0927a6a6ccd560da,
+                0x43af10c817cfcc1b,
+                0xc5dea6a1e87b01af,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8c96c558bfc74aad,
+                    0xf1bf41ed1acfe0ad,
+                    0xe68d3c7a1948dd96,
+                    0x6f37085ba258d6b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50483788c14af49e,
+                    0x3a639cbb7d725354,
+                    0x0fee2b243a0af95a,
+                    0x1cbc2a93df8db04b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x48acf72f4d154277,
+                    0xa9e249f9fa9fd24a,
+                    0xac58b9e91090b8b1,
+                    0x809a181d320b0524,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6cb70c3d23a937d,
+                    0xdc1bbfb5535fa43e,
+                    0xcc9347cfd9f90be7,
+                    0x20705226d7e11da3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5d0fb1c7f21fafe,
+                    0x5e1039c642be676f,
+                    0xcae3f355a1c48e5b,
+                    0x00afb604fc86d87c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xbee750fd5260e551,
+                    0xb01de04db164ccd5,
+                    0xfc3c78805c91c960,
+                    0x1f6eebaf452f376e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43da70944fa60028,
+                    0x51c70b67a7ab1d00,
+                    0xe8a2d0ad739b96b0,
+                    0x15b74523151a07e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38de515368bba497,
+                    0xa711a1849816e11f,
+                    0x802d43856f7c9ed3,
+                    0xfaaf8a27b692c779,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa30bc8c0d90c2edc,
+                    0xaabc98fd39c47074,
+                    0xb19c0d0d0f5f69ca,
+                    0xd4d3c1e6648590c5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x04e5957f58505d66,
+                    0xb67e3b31ca2ec821,
+                    0x6531ab1fef8df426,
+                    0x535dba5f67005863,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ea2a32d4be3feea,
+                    0x71e01d3161d612fb,
+                    0x59793b24b00596df,
+                    0x85cf51bb8e760b15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9055041efc5438d4,
+                    0xe8927812805c6a5a,
+                    0x8ce397a733a98e24,
+                    0xf41f105583a953f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb852caed3599cde2,
+                    0x8ccb135f8a0086e4,
+                    0x5758f179c181169a,
+                    0x2528bdff5a3b412a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e1d282f89b8dd13,
+                    0xf1aad3b40aae1fa6,
+                    0xe57967473ae55699,
+                    0x19158b21648517e5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x97e00dfddef30394,
+                    0xfaa76ecbada9b239,
+                    0x44abc68bb9be0311,
+                    0x8731afcd45359090,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35980859e395a23d,
+                    0x39b1660390f8f06a,
+                    0x4c313ad57312e2bd,
+                    0x814f97877b045708,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ab4108423a3a92e,
+                    0x17f22b26d72786d6,
+                    0x28ba384452d89c08,
+                    0xae1d9191dee65747,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd201b30f22d135f,
+                    0xb7a75c9d2aaaddfd,
+                    0xa1155fbc4cae27d2,
+                    0xa3a986b3eaabed45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59046c417a85c631,
+                    0x27beca959726c2b2,
+                    0x3e23f92f96b0822d,
+                    0x062b7d26c9718c7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb19bd4ed315af387,
+                    0xc5bc8f9f6c09486d,
+                    0x462c782a3d4db090,
+                    0x5ddf8a30013cafd7,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x8b13a559e99e926a,
+                0x54d72f72b9e0389b,
+                0x84f5b1be41c9bd8a,
+                0x5d1090702ab53df6,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb3e0c6b086e4e2af,
+                0x7d34e4b7133d6e43,
+                0x4ebda07585847888,
+                0x0928003f1aeb1b58,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe7d68a423253a93a,
+                0x09b1a3274058a548,
+                0x71e6676e75cc7186,
+                0x8a6db766b2770b9d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x7e8dd355977bb10f,
+                0x1b47af442b1b35ee,
+                0x45c6a618e3d7bb17,
+                0xb69866b926f7297a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xbb550fc4d7d5e796,
+                0x35dc5851dbb59b8a,
+                0xbf4d06c5dea5017b,
+                0xddc47f0b19950eb7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe267cd7fb0190d40,
+                0x9da242bc8385297e,
+                0x3d2f355183990246,
+                0x4f28172fab7172d5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8ec66d0b2647d952,
+                0x6173f99bc0a45c67,
+                0x0295e7ec2aa69606,
+                0xead4c7d51d5c7d8a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x033cc456be627b36,
+                0x93679f45b627ee04,
+                0x99566af95c2e5fca,
+                0x9059bc0d9b2413e0,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x7bdcf70398ff7dcd,
+                0x1d7dade75e7046a2,
+                0xc42d8b55373cc59a,
+                0x758d237d22f9791b,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x99f76adf66f16033,
+                0x34a759f5743d7b06,
+                0xcce923e60855b2a4,
+                0xae84d51d60f04478,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x8b13a559e99e926a,
+                0x54d72f72b9e0389b,
+                0x84f5b1be41c9bd8a,
+                0x5d1090702ab53df6,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb3e0c6b086e4e2af,
+                0x7d34e4b7133d6e43,
+                0x4ebda07585847888,
+                0x0928003f1aeb1b58,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe7d68a423253a93a,
+                0x09b1a3274058a548,
+                0x71e6676e75cc7186,
+                0x8a6db766b2770b9d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x7e8dd355977bb10f,
+                0x1b47af442b1b35ee,
+                0x45c6a618e3d7bb17,
+                0xb69866b926f7297a,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 310];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x05f74a603eddbf35,
+                    0x12e147b9e5106890,
+                    0x8d720110d392f016,
+                    0xfb7e68db8fdb5d8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd885b1b77cec93a9,
+                    0xf0d4d451d5f94b9d,
+                    0xdf718d16df89e4b7,
+                    0x7613ebffe523427a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc726bb8dc71336e,
+                    0x2b01369e80044eeb,
+                    0x3ada4b7e048d9254,
+                    0x1643e6f867933038,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80f7ec0d6fd3a922,
+                    0xc4cf3e9663e9eff8,
+                    0xc4f038e641df1b37,
+                    0xf48bcba5c8cfabbb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b1099ca8153040c,
+                    0x00c4aa90ed3bce61,
+                    0x7a8247942a220d91,
+                    0x9d913eea2cfe7806,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95266bfec7a11712,
+                    0x250012ef82b20e5d,
+                    0xff01d6feab0042fd,
+                    0x943146d1f140f5cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57476f4ce26a04fa,
+                    0x215536d4e93922f1,
+                    0x65e90236130a564d,
+                    0x00f2c40b93519bec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10ec82255b035d8e,
+                    0xb0be9b8e8381627b,
+                    0x4eb8b05e20e58a07,
+                    0x5870cba0f714a68d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd5ea13c64624d75,
+                    0xbd7b99db2164acea,
+                    0x8ee615e2c6a06f37,
+                    0x3ee82586787f8956,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3464da236614593,
+                    0x62274f2571305d81,
+                    0xe22e345923b119b4,
+                    0x4edb5c004aea546a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3048f4bf919ef4e6,
+                    0xcd94cc707a9b0b89,
+                    0x58a371be2358312a,
+                    0x29f98ea74c6e0bb8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ed883a9bb2eed15,
+                    0x333e7f08d0988a13,
+                    0x26ec5651ef0004d9,
+                    0x02802192e78b595f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x059ff1ab7b7a88dc,
+                    0x5a17b10a0da5435c,
+                    0x40b6af5f17dd9b02,
+                    0x1b752fdd1b6b3028,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10fc97f65e82c1ae,
+                    0x2603b369ef89de0a,
+                    0x0605a43bb11f3c5e,
+                    0x5f73a6241a23302b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66c2a2680b0873ab,
+                    0x848d9377902f8974,
+                    0xbbf680b7b1441271,
+                    0x5885c66c405deb29,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79c954d43ccb0e74,
+                    0xf80529b815292735,
+                    0x3cc4205c7db655c4,
+                    0xf671b561b7f7ea0a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf9dd696af7ffb76,
+                    0x531f1cb0f2809ad7,
+                    0xd0e6526456b11419,
+                    0x517269045e3ad5ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfce09d1bee38b448,
+                    0x1ea687375d67095b,
+                    0xf89f0199554a204b,
+                    0x1b68e4faa3c85a32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c849da457924b82,
+                    0x0a87d5fdecaddfda,
+                    0x4b4a44c51046f8d9,
+                    0x1966eb9bde6eb97c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x719b1db8533c0e9b,
+                    0xf3fa6b74bbaac88f,
+                    0x4ef9cd1c2a02f72d,
+                    0xaec59d901588b439,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdec4881ad776fd66,
+                    0xaa8e42d56ba7ad9e,
+                    0x4d78db5e027dcdf7,
+                    0x988e6ba7401c1669,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x431b42279b5f0eb5,
+                    0xbed36c5e3bd93b84,
+                    0xecddc14724111943,
+                    0xa4901c8a3dfce3b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52fd27b378247cdd,
+                    0xdb1c916443b2bb98,
+                    0x925630dce97c8ba2,
+                    0xb1bc5c1c201b5d5a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x661c69b22dc95db3,
+                    0xb0981266aff4347e,
+                    0xe7ed2c31fa97b4e2,
+                    0x2a9087cd24cdf973,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2916a64de3ffadb3,
+                    0x80d1735417836eda,
+                    0x5c1b63cbdeafe709,
+                    0x97822bbab7d9f1f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc07856285fcd201,
+                    0xdc930c4e021c6a4c,
+                    0xc9be6d194b9e74bc,
+                    0xf3077220fd3bee14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x122e9f506c4e2496,
+                    0x824cb4e8f8a72d0d,
+                    0xc86c99363033d633,
+                    0x46c7677ae875887b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda75d33eb42dffd2,
+                    0x9e2449029e96d662,
+                    0xc70c43cde93b35e8,
+                    0xadd0f27fff74917d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x803e68e48c4ddc3f,
+                    0xb5bb9ececb0b982b,
+                    0xdd7a8ac630af0456,
+                    0x0b8c810d08a9f808,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63b6229acb65469e,
+                    0x78cca2aa88dac5c3,
+                    0xbe1a4f685ec49f4f,
+                    0x21b0e90775182344,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xffc9824c650dc037,
+                    0xfe1d845fd6cea29d,
+                    0x9a87daab8b7f2d37,
+                    0x36c542dd63c3dd4e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe235c09b9870fa11,
+                    0xeac147a665ae20ae,
+                    0x25dd058c7be607db,
+                    0x923dcd12ecacdf31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd06c4cd18564e9e9,
+                    0x408d42f204c21383,
+                    0xbd7cc537238a228f,
+                    0x386f5735b57751b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80f0b83173a0562d,
+                    0x4e7a539dd4733dc1,
+                    0xf5d400262b0cd1ea,
+                    0x7bd4c7f415fda683,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d7b13a45511d540,
+                    0xb995dfba5f6a0f55,
+                    0x5f3730ea028fbcfc,
+                    0x311c2d9f6098ba0f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0c5c06de7c6eb38,
+                    0xdc8c93f35c480726,
+                    0x128fc9edb62a6bec,
+                    0x8b00002bd35a2d02,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1165d7ab0ca2462d,
+                    0xd774d7a9c21d18fd,
+                    0xc729eb605ff20d83,
+                    0xf272d5f35947a8d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x368beed42a0c5584,
+                    0xe982643a4ed5528d,
+                    0xb69596b2a7772e86,
+                    0x2d8ff908be496475,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xafeb0e9e2c8bb030,
+                    0xfcbf7d1957964460,
+                    0x7ddeff0b54b7e2cc,
+                    0xbdcba6fc5b11151f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7513b299a6e8b0fe,
+                    0x532c747ef6be5ebc,
+                    0x7da8e864a9a584c8,
+                    0x44a21e0c251d94ad,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xfa7c86a597261d41,
+                    0x9ba40570069d3b55,
+                    0x492ffe30daccce1a,
+                    0x401c4ee26de57a94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54650e691594d319,
+                    0x22571911a99ee7d3,
+                    0x411e7ffd2eb10b30,
+                    0x28b053251c37698e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcaf999e881ace2cc,
+                    0x76b76ffb5ab7d90d,
+                    0xc513ffd4962e665e,
+                    0x9fbf9b31422fe2b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb92f38af0d9fe3d2,
+                    0xb78b8607eb7f935b,
+                    0x9e0280210a8fb637,
+                    0x93085a0535e0ef53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29415944e0eea66b,
+                    0xf41e00951d95d9f8,
+                    0xef9a0236010497b7,
+                    0x2ee9356776cdd15c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc819be7d1550711,
+                    0x35946fb786920f83,
+                    0x64eb17da270ba4ec,
+                    0x51e2699bc8cf6069,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8121202ea23da8d4,
+                    0xe4e150bb001706e3,
+                    0xd3335eb60ee6a9ad,
+                    0xcd5f95feb41bd801,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda0a51eae510bc29,
+                    0x35ab08966532c533,
+                    0x65e1c035e1c777c8,
+                    0x2a6537fad5dc3450,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd382f7db5e65f3f2,
+                    0x96822cebac8d708f,
+                    0xa98e0d7f37a5aba7,
+                    0x0b41f5009f0db64d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a7cac306d63ed7c,
+                    0xc16d018f004c7392,
+                    0x8254fa0118f98d0a,
+                    0x65ec962fc0d6f9bd,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x812c095fcdf20f9b,
+                    0x33f8fc83d7cf9c4c,
+                    0x850d7e73478a111c,
+                    0xb26eb463866d0116,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8059c7d2a2cc630b,
+                    0x1535d3429818dcab,
+                    0xa3ac263f5325407e,
+                    0xf31adea707e55e8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd18401053b66b58,
+                    0x73c0fc7b7f5164ab,
+                    0x8db7d2c360e86e6d,
+                    0x5dcd1e28acdcad8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24309f59cf0ee464,
+                    0xe360a59652540f14,
+                    0x204dfecea2682caa,
+                    0x1ee4ea2da8ab5001,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb633e506f9337abe,
+                    0x0f33c1a4634f43ba,
+                    0xf82db544bbcf0d3f,
+                    0x038b4d9f112ca6e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67136bfc72f30841,
+                    0x252c7ce3cff8773f,
+                    0xc7fd9b711afdab29,
+                    0x88be925801277b2b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4fbc42d92e25143d,
+                    0x890edd7974e8ebda,
+                    0x4eae983333db2bb4,
+                    0x7ba393f8ad409926,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x794ab7c242e04525,
+                    0x117f2201ac452ed2,
+                    0x446e5c91d5ad647a,
+                    0xdc9dc92f2da24bd8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30c9947b1b5ac0a2,
+                    0x3882bc727b698658,
+                    0x5dde49b940679702,
+                    0xbda6a513e098340f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb249a2554fed0db3,
+                    0xcc18a0c7c64926bb,
+                    0xdfea7c92781fb998,
+                    0xaa7fa68bf5b0096c,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xe520832dd63c12ce,
+                    0x42f41419a1cb7737,
+                    0x58ab32a3d6bc0305,
+                    0x810898cdc092f1b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x777197354f16c722,
+                    0xfe9c83e8f890863a,
+                    0x19851307976c0b15,
+                    0x5594ea6a0ce3f6e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6ca2c5d8dd0951d,
+                    0x092af9b67cdd0022,
+                    0x55e8291ce1d08add,
+                    0xd22acbe6f4128d3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5c9b893a7470313,
+                    0x05e48218227b32f2,
+                    0xf0486c01efb777ed,
+                    0x814c94ac9070f908,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c3098320d8c8548,
+                    0xef53681f713d1541,
+                    0x89fb6008c4602b51,
+                    0xfc2e08bca879b1bc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8ca69a83197958af,
+                    0x4a3a3aa9b0848c3f,
+                    0x1c7e92316fe5447a,
+                    0xc7b3fe7d4bff2bea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x440511a679b9c385,
+                    0x26622031ba8c7cf3,
+                    0x246cb150307f991e,
+                    0x5e92ddd2e4de5b54,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39e0f3c6ed63903f,
+                    0x84be755b917c2156,
+                    0xc5b409c639bba2bc,
+                    0x95789d9fa1f9ad8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd00615b38b5cf0c3,
+                    0x23af6cfa789a5b81,
+                    0x5865a1843b729067,
+                    0x28bf86f22a6c2f05,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1fad109cce98321,
+                    0x81ef98085b180386,
+                    0x417259ed9a3347cd,
+                    0x61b93ed3536acfbe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdbd7e7d70d5b2a25,
+                    0xb40b39c54bb43c42,
+                    0xf7e2b85f96f698ae,
+                    0x5cee3d408437e844,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c7068114ee4bf27,
+                    0xc4037e00360d1f2b,
+                    0x217b167bad23b3d2,
+                    0x1d66b78f02714d30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4e43234cd0f18ea,
+                    0xfa7733f915728801,
+                    0xb3247ef7c34edfdb,
+                    0x0ce2abd928f25cd0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72695d4e01fad576,
+                    0x8a332fdfa2581459,
+                    0xf15c7d2e135806d4,
+                    0x0f26c49588fcb475,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ba1bc9ece3182a9,
+                    0xc6d3e48f4c67363a,
+                    0xcd97434139978ec3,
+                    0x44a2b66ba62e4d0b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1c1225f604613390,
+                    0x9470303f2d726180,
+                    0x64af13f5a17f2e7b,
+                    0x6e42d463f3023645,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08c197e74c24fbb6,
+                    0xd014a21f94083cf0,
+                    0x177b3ab3a481d2c4,
+                    0x24363503853f2de3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef4012b342e4a67c,
+                    0x93c682087a688a0c,
+                    0x92f3373b782c6fb1,
+                    0x9b77bdfe3e142481,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3664c96a5457f5e6,
+                    0xa00dd45a5c724d86,
+                    0x1a9e225ba0591871,
+                    0x2acdacc1d9c254d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb1d9e76e2387c263,
+                    0xf1eff423868dbb9d,
+                    0x2b15ef217b7df3d3,
+                    0x0dec14ce31df0cd1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8e93e856c708224a,
+                    0x1cc1518e9b928b6c,
+                    0x3e0de208979e8178,
+                    0x13aa9b3795965e6c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39e362a772e3d900,
+                    0xad202368db8751dd,
+                    0xabcdb5d7a3637c7d,
+                    0xea918bf2b19d84aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0db32cc60a576e0,
+                    0x943bf0a6215ca640,
+                    0x6447b57d700ba3a4,
+                    0x8676fdf899a4b0ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d682930f9d26c26,
+                    0x29b3d9386567de2a,
+                    0x0767e73b516fb24c,
+                    0x2126d7e8d694a371,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2fdb507d1c56932,
+                    0x7a0f7bb1d6b8c349,
+                    0x129ebba6b1edbf05,
+                    0xb776a29227fa768f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8bec59224898942a,
+                    0xc25a5f1566541349,
+                    0xff6b11af7be462e2,
+                    0xea2b023db67457b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf97e37f6b3c0336,
+                    0x0f8f95f68f4ee376,
+                    0xd2cb4530663a3a86,
+                    0x5d607da037a5b609,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7ed23fd0675286e,
+                    0x9815cccf75426cf4,
+                    0x32decf1b9af38f67,
+                    0x817088408fe869c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f80f66ec243c0ef,
+                    0x9db1347ded46b78a,
+                    0xeba35c71d2ad3546,
+                    0xb066e37cdf1e68ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8264e498d84ec87d,
+                    0x558495ce7c1d089b,
+                    0xfd6d7ab2e65f39d3,
+                    0x97a01ead2f8e807a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa6312e55db9551d9,
+                    0x11b4fd4c4738e200,
+                    0xd425ad4c33ff1d75,
+                    0x0b440990009b5969,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x923cc5d961344f98,
+                    0x130874555d17469d,
+                    0x26f0f355c7fc8fbe,
+                    0xeddb2ab64bba74c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d4ad84d970ec07d,
+                    0xee8671a43a859ecf,
+                    0x71f2c258547edb68,
+                    0xb90402977ca90993,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe99c4086a4533842,
+                    0xdc4e901fd19c6505,
+                    0x23fa579907ccc81c,
+                    0xe2e412941730f2a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c518588596472db,
+                    0xf1f06b5239333b89,
+                    0xf893e623b38abf56,
+                    0x646bdba64cb8dc95,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0b8ee5e0bc347cf9,
+                    0x41305382ae1eafc5,
+                    0x9c6ff760148d6695,
+                    0x860ac99f842d46bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf77fa947bac4cb0,
+                    0x2f1e8c019c8af5d5,
+                    0x04ca8713b593ea99,
+                    0xd179bcc55a5fff32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36a760fd5eb2edf3,
+                    0x4c90196b223e6012,
+                    0xbb2d62849d52d96f,
+                    0x31da0e78b0bca389,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x209fb43150687848,
+                    0x00775dba8415f94f,
+                    0xa0a1d61ee880996f,
+                    0x859a990935c4af4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6259ebc65831eb7,
+                    0x11e136a2da1f5b48,
+                    0x1f67fa29326798f1,
+                    0x69aad94169e974c2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5bd949d66563790a,
+                    0xa2f84028123fb727,
+                    0x3a1a783973cada1c,
+                    0xd77acf5a775a7b35,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07c3e308cc8ad5a9,
+                    0xfb4d1b149282698e,
+                    0xc0f6557c2cb088e0,
+                    0x658750c2eb8c0acd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0983ad3363f3faf2,
+                    0x039c17d10e0996b4,
+                    0x5202647969dd73a8,
+                    0xf398808b9e426886,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf445ffdc00581f9a,
+                    0x1fdedfb08fb404c4,
+                    0x7609fb5bcdfdb1ff,
+                    0xc2db65929cc6bf5d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x409fa22f6369a68c,
+                    0xf2b950b0296fec9e,
+                    0x3d11dec8aeebd957,
+                    0x2587d532b9e8cba9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0a22dc01676eab74,
+                    0xb2c3531802b705f0,
+                    0xc5cc152b00d22b66,
+                    0xa83b6d993f627981,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x150b6ead637f5b46,
+                    0x50087e4a7bd425f4,
+                    0x5829648ee8366b89,
+                    0xbabf38069d6730d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9594319cbcedab4,
+                    0x5eb50ec5c5c458f7,
+                    0x4eda1ebccf50416b,
+                    0xdd79093a2f972c2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe11b81fea2089c6c,
+                    0x0bff27ff1cd6343f,
+                    0x57f6ff57942ed5f4,
+                    0x02a5ae2f4bab0456,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c4e8b4de65a101c,
+                    0x6146a2f30264704e,
+                    0x5d624e727f9ef61f,
+                    0xc3f02da5734512c4,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5fc1afa042458e25,
+                    0x35e3e64692001d3e,
+                    0x99ab501a2f8098e8,
+                    0xe409351a8ad25c52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9021d8d6f43b7c9,
+                    0xce09d7eb9c0e4b92,
+                    0x9785907617c9c07d,
+                    0xc2838695201f1ba4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd587c64bdf1bdf76,
+                    0xce05818599b11841,
+                    0x3460433f6556f632,
+                    0x0c625e38fd24d70d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80cf5ba171d874ce,
+                    0x7c20be3b09e17bf2,
+                    0x4d5338cd77c647e8,
+                    0x097bca022489833d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a64b88733637a84,
+                    0xa883a8a727e03eaf,
+                    0x4305f710f6bf408c,
+                    0xeb3213861669cc66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76d97028e84dd9c5,
+                    0xa0a16b34895bacf5,
+                    0xabb867c40b26afe2,
+                    0x3198b25b796dfaf5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xafe3a330b7558613,
+                    0xdfae7a949ac7396c,
+                    0x698f832cc8875c73,
+                    0x44c9eda8fe1ccf0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47583dfe8de7b8a3,
+                    0x9cd0f41fef0ce17b,
+                    0x75262a432f8882f5,
+                    0x61a88bf7602201fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d9d00ea082f2d64,
+                    0x4aa656e1d4d1b52f,
+                    0xf7a224067a707709,
+                    0xda1674832ae9aae0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a06f7918436f6f5,
+                    0xe7259735cc81f7ef,
+                    0xb1bb581a6815b2e5,
+                    0x53b7cedfdb203ab5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa33db91e614e7763,
+                    0x9d5eaa659cc93d91,
+                    0x2593a45ae91292f5,
+                    0x0e6c30b7fa32e9a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc875468455946ac7,
+                    0x77cd0a10deff7abe,
+                    0x7087bd524bae0924,
+                    0x23d716a6353a6f8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x13eb3f80d32a44c6,
+                    0x9b8540b3b650a003,
+                    0x5503e9d35b25f742,
+                    0xef312a23506e0c16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1711c4f1b29a1cbe,
+                    0x04b64f229e6b74a2,
+                    0x7e969957c308415a,
+                    0x53132236821cc156,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f9107205f29a51b,
+                    0x45446b1b79fee0b2,
+                    0x41c7f798192a65a3,
+                    0xf7bf9edd6de12fc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5ce7964801da630,
+                    0xa1a4d2e0bfc6c024,
+                    0xb5cb2cbe33768562,
+                    0x562cb7267aa7c904,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8aea330e42cc415d,
+                    0xd1dd49f30cd87c9c,
+                    0x1ce88fadaea38a36,
+                    0x98fe9cb477ea44ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0eaa867da40e52d,
+                    0x03b8b482c9823a9d,
+                    0x06339adb50f52b82,
+                    0x9d786add2d13a0fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5039c56f9c4ad28,
+                    0x5bbeff46bd6d9c96,
+                    0x4086fdc8419958fb,
+                    0x763b64100b60e526,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x411af564e2f4aa5c,
+                    0x54c0eb154ee75bbd,
+                    0xfc01776fbb1f785e,
+                    0xd9e87e2ec1a6b82c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xce7a8c482ded7a14,
+                    0x6b7d2f5f6d159334,
+                    0x3600a6f417a54efc,
+                    0x1616667b023686ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe4ff52794589aefb,
+                    0xe0ec1c232f7309a7,
+                    0x91b7f7db7b0b47c3,
+                    0xb3051c237e4fc4cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd705a3c169cff51a,
+                    0xafc333f75fa5793f,
+                    0xf47f29c68bfbee02,
+                    0x0b3e26ca6e70e086,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe51a99c3424977d6,
+                    0x89c52c8d72cdbddd,
+                    0x4922a91229ce5711,
+                    0xbf25c8a1e027cd66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x894573c7993fd25f,
+                    0x7cd68756d37dfa18,
+                    0x4e9b16ec2b9e51ad,
+                    0xadc22d9205df0fa9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d4ebc45418a618a,
+                    0x92959e2b5b55fdf0,
+                    0xddb6e55d058d7405,
+                    0x042a6b5a3a399fa6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49c9010f68e7dcb7,
+                    0x9b7b7609535c8111,
+                    0x3bfc0edb261d779d,
+                    0xddf0d02cddb2a0a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62b0a1806cb8f71c,
+                    0x7b70e7efb019d832,
+                    0xe7f1a53dbfe694d8,
+                    0x19d4f7e5d3c17612,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x551468a6cb5ca136,
+                    0xa60f0be2264a1309,
+                    0x2d637fd4f153872c,
+                    0x9783cc368258ac10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d207ec4897f3475,
+                    0x660b68dc74f87f27,
+                    0x2595b87034da52a7,
+                    0x2747c3ff0634085f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd8d104c4ef810639,
+                    0x87755763e739dbb5,
+                    0x83596d448fa5f351,
+                    0xface697736cc4234,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1bb2b4e2189037e6,
+                    0x067ea51269f5c800,
+                    0x6f5efbe67aadffb2,
+                    0xdfff2a0ead7181b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6108639f694b308b,
+                    0x236638962d6ba24b,
+                    0x0a37d95a5c97304c,
+                    0x23e5e8e4b33fb0b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x775059c3f0143d9d,
+                    0x451dd833c232b342,
+                    0x966f6cfa12c62c82,
+                    0x99c4bbe99ebb1b10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x429f4b29e54a17b4,
+                    0xe834585d94b1a55c,
+                    0xc820ca7b4049381b,
+                    0x88c10c3fea71c2a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a393983b1aa0d76,
+                    0x906162ba984cefff,
+                    0xc152137ed2da66fc,
+                    0x73916c32a7f99913,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc2317221f20ba06,
+                    0x6a35f8d3cfa4a23b,
+                    0x18c91cf34d9249b2,
+                    0x26779455afaa2b30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28733edff0a8d14b,
+                    0x887b94f3b3834757,
+                    0xbfd03298b1b4df74,
+                    0x099a177c8ddfdba8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8289ae0b7b27607c,
+                    0xb5fd6b760b4eac73,
+                    0x98d6772cc4dab839,
+                    0x343f4955a4811783,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1dec3f943ca6add8,
+                    0x5097c7043e05d8f2,
+                    0x6ca442a0023b3d65,
+                    0x4d33a955acebdc81,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x0d27dce3684f0c3c,
+                    0x26d73125ce409cf4,
+                    0xb0ea2f96a58ca0e8,
+                    0xf3ceeb76d5cafc92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f7e20e64102b83d,
+                    0xe3998bab177ae8e0,
+                    0x0cabaae38069104f,
+                    0x9bfcff9ae910b728,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47efc8bb618ab74e,
+                    0xa15b7d887a437866,
+                    0x884d87346f74bf1b,
+                    0x7b8ba1246bb58a2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2dbc48ee734bf3b,
+                    0xfe16ae7b2efb0596,
+                    0xccb58e6fa6dc5573,
+                    0x2511ece57bb0d684,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e7ec3e27cd9bfc1,
+                    0x9e9a93c06566df8d,
+                    0x6e1c3b20288db8ac,
+                    0x2f856bf488da687a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf97dd486933c9990,
+                    0x594fce9c947fff56,
+                    0xbf02bf3c72431032,
+                    0x8aab28a1e869d78f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5445ea0e1ede41a2,
+                    0xe8d96958d719904d,
+                    0xeb3a8d96d1214bfd,
+                    0x259c3970b3ca8b0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54437b2d371e96ab,
+                    0xb7b9d84babe068d0,
+                    0xda94a287671da8ca,
+                    0x3448391c8767b447,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd64ee43bf0669212,
+                    0x212fb4bb10c844a4,
+                    0x319c60a863910dca,
+                    0x211eb239f19718f0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9385e60ce8085c6f,
+                    0x04d0a0c175d2a1db,
+                    0x565d94dcc7232975,
+                    0xd20b26b095444649,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x830e047405a47868,
+                    0x5cda67a18aaf0c9d,
+                    0xf56eb9645484decf,
+                    0x953349afed9fbb5d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9f311d8eb166f5b,
+                    0x79690d691993dd71,
+                    0x72cc7a938be6af9e,
+                    0x2ea23a2b082d77bd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d6adbc6a66224e3,
+                    0x8392aa986374ef46,
+                    0x6d113db916b9ccc1,
+                    0x0ebf3951a4bca060,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbdd304aef8829450,
+                    0x2a9bcc88955ca575,
+                    0xf776626d7d061676,
+                    0x3a3b4f7842146163,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf0a4ac8b43fe576,
+                    0x77a1600bb485df7c,
+                    0x48764ea9b2f8b4bf,
+                    0xb69821f8f5fbb6cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x120d4386a7f57264,
+                    0x97a68f16ec68aee7,
+                    0xdc8f035b8b21bd1a,
+                    0x20e989e3bc3a94ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x847ff3a22dfbfa2d,
+                    0xa81ec0816ee37b8a,
+                    0xcd1f1a61baca2e85,
+                    0x5d2845294c704ab3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5b58fcb01489e1c,
+                    0x3146f635db78887e,
+                    0x371d32ae0f9ceb63,
+                    0xa6c341107743f326,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0ad51fc21fe9d8e,
+                    0x351f81352de4c296,
+                    0x56c0dfb3612161b0,
+                    0xfe3ec1afe79320a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f70deab9030d4cd,
+                    0x6a0f622a299b5298,
+                    0x1ee04f0533253ea9,
+                    0x7b05850fd2c68153,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x7556978967f702db,
+                0x3ff37bf0aaf145c9,
+                0x425c3ad304103c37,
+                0x2642ec87fa742099,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xac8d86afd8dd27f9,
+                0x9af9d539d03f8991,
+                0xa07748b9afc65889,
+                0x46383fb550ae3390,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9b379c09f97b5a55,
+                0xc38d002d85f32e03,
+                0x8b760e425458c1f5,
+                0xdb011ba9dec693c7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xfc52816a0b4a91dc,
+                0x8a491ce51ee331a0,
+                0x54d19761da047d37,
+                0x66aa88f5c1f6ea4d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb96c577d8911fd1a,
+                0xe74bfed7f02749b6,
+                0x919a2cae48995d5d,
+                0x18ed0437eba36370,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x69456c9abe782a43,
+                0xa0133f00f05aef36,
+                0x8238159b28f642ea,
+                0x625c373441108d5a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xcaab8294d4df0e9c,
+                0xc409bc69b140fc68,
+                0x1608fdaade3fb6de,
+                0x8aea489fe586122e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x46cbca59153e6bf1,
+                0xb238f456f0b75094,
+                0x038ba1d9db3b0a89,
+                0x0f62eacb68597b1e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3de87fe473450399,
+                0x92e4f17057ce4d1f,
+                0x848c9858815b5cdf,
+                0x9d3378c883245f67,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x37d26271b873a7de,
+                0xf4f6b74a61dbbd37,
+                0x06976e417c9b597b,
+                0xe60051213a3a288f,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 279, 280, 842] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 279];
+        let mut opad = vec![0x5cu8; 279];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
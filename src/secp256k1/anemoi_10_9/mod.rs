@@ -0,0 +1,2480 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 320 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 9 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 9;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8a5206662f2353f6,
+                    0xb77cebc7890aa282,
+                    0xcee331a4512a9038,
+                    0xb300a9aafd53af84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a8d513e55b9009e,
+                    0x1dbf31e141bf81b7,
+                    0x2a821e807d2d9b96,
+                    0x105f8cc0dde935bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58a734e68419d64a,
+                    0x56f03ab01f04c147,
+                    0x2af75a1df793e9e9,
+                    0x1d34533a3295d314,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c70d602bbd4ee25,
+                    0xef22cb85d3fb2995,
+                    0xad1b6a33e0bcf7b3,
+                    0x319e8d45ed5e49fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0734f412b9d433d8,
+                    0x13f30380c93128c4,
+                    0x41839ff0ae8d2626,
+                    0xb9054d7c847c50e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x882390daec542560,
+                    0x29e09e7e46e37c17,
+                    0xd4627ca3ff6944d5,
+                    0x675c2376e463e729,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4de1babebadcffd5,
+                    0x709066cc0713e688,
+                    0x64c19db459135c97,
+                    0xe6339cf204cdab3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c2e1370b681c2e0,
+                    0x3e3c642add932f2d,
+                    0x7b75261c0cc41d69,
+                    0x9b527cecbfd52b30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f7cdcf83b59c19b,
+                    0xd305d5c8706c2ec0,
+                    0xc4fadab1c816debb,
+                    0x42224ee98a49d49c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc300977b15cc5afc,
+                    0x0367905ded297412,
+                    0x796c95f4b7cc2da3,
+                    0x52dcb22180fecdf0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xac52d9bec216f190,
+                    0x5ac865d66a01bddc,
+                    0x0215ff1f237d6372,
+                    0x3dc3a87978e330b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4626c9d1e60205d8,
+                    0xe833d1f54db6be2f,
+                    0xeacbbca27dac293d,
+                    0xa15c8476df8c6778,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21d457be762a8061,
+                    0x66e2c1dee4c5b09d,
+                    0x01e3dac25583a827,
+                    0xf515fac6465136f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf33a3a50f65350ac,
+                    0x922b179c3e9d9454,
+                    0xf32bb28fea5ea3a0,
+                    0x9f0c18c773589fcb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7e6eba3192d5953,
+                    0xd8874f29f8967e6c,
+                    0x1c367bc74564e192,
+                    0xf2178c1b6500173e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7cd46bd5aa5e30b5,
+                    0x3777d036b28c6805,
+                    0x116a143fb42f3f73,
+                    0x226f9d090ebe911f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c02fb7d33c90839,
+                    0xe171a20172552ca8,
+                    0x89659cf51b1762f6,
+                    0x0ee6193015b980fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f4e081c3d5dab8e,
+                    0xeca307cc96357b4d,
+                    0x297e76c3e795a5b4,
+                    0x3e21b9e95e1d2237,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb6cde3c2d31fb9a,
+                    0x5088f220eff149c9,
+                    0x42185cafd55b5586,
+                    0x5de20e6f507735cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc9deb7a748657a3,
+                    0x97ce196200314ccc,
+                    0x35b8100736d67948,
+                    0x0151f2930613b539,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1860d023743f27dc,
+                    0xfdba7f26c2e2ccc5,
+                    0x2f0cecf97621bfa3,
+                    0xfa209b0cacdaa870,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7dfa059023f26710,
+                    0x6a68a9c318ae3fda,
+                    0x18385467e0331712,
+                    0x0216fc29bdf0f489,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2de23f30eabbc5d,
+                    0xb85595f1739432b3,
+                    0xf8d4df46f31f847d,
+                    0x94ec327d971de42d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c912f1c556d1b27,
+                    0x1eb8fa0f88a29285,
+                    0xb73b72bf9ecea1c3,
+                    0x02786a5335cd9deb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3a71d867d0a6126,
+                    0x354d80bcf531f836,
+                    0x7470682e9386327e,
+                    0x506a6baed3390d00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe3433e337e3417a,
+                    0x84e2af2d013e6aeb,
+                    0xa2897baefcafe3b4,
+                    0x4d2ab3ca98e076af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea2b8b6550177165,
+                    0x03311c132f26a635,
+                    0x16171027dbdf014d,
+                    0xa75f16dd74e609b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9fff23f62a2cc9e,
+                    0xf242a28c2eebf55a,
+                    0x1b4ef5301215274c,
+                    0xd059cc742bcfb948,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58aefc8c88dfa48b,
+                    0xc03bd35902ffe226,
+                    0x5b7f10878e8f183a,
+                    0x4f2c42bc7ee51515,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd878b43ac971da26,
+                    0xb27b7160d3a0b1d1,
+                    0x1c04ed2968ad5667,
+                    0x877106b5be8553e9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9a7a7f0c7fb8bf7e,
+                    0x833ab27a59009bf1,
+                    0xc97380521490a90f,
+                    0x8c384b0b8e9fc607,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb4ea97d090a7e84,
+                    0x119cf32e68208367,
+                    0x5043c59b24996357,
+                    0x970cb25ea032f86e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec48ff060cf876c9,
+                    0x59574b133763639f,
+                    0x5cf9fbc3ff15a66e,
+                    0x478b5b7da59623cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb175a35d3e488196,
+                    0x65ccb4ec70b50072,
+                    0x5a12852a95d04e00,
+                    0xf47ee0e407775901,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe137fd20fcd10cd7,
+                    0x522a8baeed26cdd1,
+                    0x57618ef61e74d88c,
+                    0x4ecdc8022fd6bfc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc34a80231710b3dc,
+                    0xcaf6cef8f7effc6c,
+                    0xa6417506db0d8698,
+                    0xb6edda115e3edd0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c2581a6cd5ef804,
+                    0x348f60e8db3a2355,
+                    0x3496eadb8204a76f,
+                    0x968cd3efac6051ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe108f80c4e08521b,
+                    0xa51cb6fa4dba2444,
+                    0x102d2b8635cc9c2e,
+                    0x70776a5c46dc0643,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x109e55cdd34a3411,
+                    0xa2cb58d1b83815c3,
+                    0x3d1cd75e130e426c,
+                    0x1716a11e08fc28f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b098123badc57b9,
+                    0x8490b524eb8873dd,
+                    0x9b7e2f3ad359219a,
+                    0x323d536ba0b661a0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7ffbdd79b48a297e,
+                    0x5f58f56ab7a25be7,
+                    0xc070777abd3e8b5a,
+                    0x6b680078f0519d0a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33f5e15e72c18658,
+                    0xdcd8103ebc18b5a6,
+                    0xc57adc9ab9d5474f,
+                    0x8f99aeb3547f49ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbec563c6117f0eec,
+                    0x6145b3aa5ce65034,
+                    0x68786772575fb6f3,
+                    0x1a4a8b7f9fc34c63,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd16cd8c36da65805,
+                    0x3bbf13eaf1a394fc,
+                    0xaf534dc2b0081515,
+                    0x0bcf4c0e29aa0c17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04bef31a0aa41d4e,
+                    0x0c0f7a9f005d0f29,
+                    0x14462fa7a7095995,
+                    0x5fe9d4ec3a9dc2fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x952aaf9d5e4604d5,
+                    0x2f2951653d2f3ff6,
+                    0xc49a251f65a7df61,
+                    0xddd05e377d6c9bc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5345082e3933f916,
+                    0x7f1a87d161973953,
+                    0x12b29ebdfc5da563,
+                    0x4fdae23a605bfa40,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2119b864cd8b28bd,
+                    0x8b5c8a8322097d12,
+                    0x00668453a8b209e4,
+                    0xdce199f58e7e7916,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe855c459201157b3,
+                    0x7ae8a2a1860b7faa,
+                    0xc0fa1d94229971a1,
+                    0xc0d6bb4c3f1be745,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9399961ef81999c8,
+                    0x212342183ce60755,
+                    0xe3bedafeb498d523,
+                    0x9e1f4001289f7cf0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x52897f13915170f7,
+                    0xa7a5f5963c236220,
+                    0x37bf9a1831dcf62b,
+                    0xe65e3027c696f142,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03f35669560b6042,
+                    0x75337a12f4fedc36,
+                    0xe28472fbb13925e5,
+                    0x3a06888f998abb21,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e536c821a88e7b5,
+                    0xf15dbbd6f78cbe71,
+                    0x89b1b534b087632b,
+                    0x659b89c23aeb7a45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a3f9a5d947a3fc8,
+                    0x6d2797980bcc3262,
+                    0x9a3dbb11103081de,
+                    0x3e268908fbf341de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1851807fe8760ca8,
+                    0xd71fffb46ded99dc,
+                    0xd3a29560e79921dc,
+                    0x10001f8e641be1d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc81ac37fe71cc192,
+                    0xdb6e0c7d70c8cc1a,
+                    0x601ce2dcad5c2381,
+                    0xc9d87ddee475e4cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc7d68286e019ccc,
+                    0x99508d986b001f3c,
+                    0x55717799633ad775,
+                    0x0e4ea8c4c8a12ebb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ff0678c8eede1e1,
+                    0xb154962ce162858c,
+                    0xfc45633cdd7a0cf6,
+                    0xa56670331fac4e43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6658166980cb9b5d,
+                    0x66be1e931d2551d6,
+                    0x898bdb967aee1db0,
+                    0x258ca51829c78b55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c25d9f07abd8f77,
+                    0x72cb966422b333cf,
+                    0x801b19e45557e586,
+                    0xdbf7f3605504a43e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc06c5c9036212e2f,
+                    0xf721f93d1ecb769d,
+                    0x186e843ce6d8707b,
+                    0x4f157dcade45e1d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd205907fef8f2de3,
+                    0xfdbaf5937b44ad85,
+                    0x7c62a0adc039029d,
+                    0x1484e218e6d21b7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98192f5fafdf0f2b,
+                    0x2ebdd9cdce18ab88,
+                    0x4ad29b4e24d88db1,
+                    0x5c3f429726152449,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26ec949a60402585,
+                    0xfffb85febca55691,
+                    0x614c2034306d8cd7,
+                    0xd8038e1f687c68d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ab7a4009f9496e6,
+                    0x6f59e942f4d69b0d,
+                    0x02d27de22cc0e974,
+                    0xa1f2358a0623255b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d21f427632f0332,
+                    0x0b99d3496ce26362,
+                    0x2dae9b7ec21c6de4,
+                    0xce44a6a111ab2c34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b6d8116e5fa73fd,
+                    0xcb68b2a073a16be0,
+                    0x254a13caf4b5b64f,
+                    0x422447c14eaccaae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3bbec3b131475965,
+                    0xa3c75194930f78f1,
+                    0x70d21d92116f5f80,
+                    0x96ac06b94a8635ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59f393a6d475aaf4,
+                    0x035fbbde74e04d67,
+                    0xc9aa85c034725c4c,
+                    0x207bbcac5e412777,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x873649b334533e0a,
+                    0xb08602553651425a,
+                    0xfac9ecabe50a1de5,
+                    0x9a83684a04c84406,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6661df47d55f7352,
+                    0xd1e4187d189c528d,
+                    0x672a47e3aac1d06f,
+                    0xfe300f168e29ac5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf08bce59f5a5f140,
+                    0x1f89010e9b9cbd4d,
+                    0x87947815b449f590,
+                    0x18b3805026002367,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd6794eb4b08a917,
+                    0x25131134a3f1492d,
+                    0x73d867a76b481c2f,
+                    0x382473e64be4b0f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x707a80ac4356f241,
+                    0x657936eb45eec933,
+                    0x56c8d9ee7b78ff5e,
+                    0xbf2f58fe2a84e301,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73814180b3c4f62f,
+                    0xad04ca7e13e64061,
+                    0x15f31b7741c880ae,
+                    0x3f6037893caf6376,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0447845f74f7c88c,
+                    0x2faa6cc1fbd93998,
+                    0x06314212b0c1b253,
+                    0x4c1441cbc8a71591,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9608872505654522,
+                    0x23cd949cd9495a0d,
+                    0x29c6ac005d897af5,
+                    0xa08d9f2066bf6df4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c266d90c5a153c4,
+                    0x79484c9cd4c40f69,
+                    0x76c9d745b250fe0d,
+                    0x2adc9e0614a2204c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1fad32a9b9db97a,
+                    0xa0a944675d452896,
+                    0x05f13bec7b8482ab,
+                    0xf49aa6662ada009a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48bbf0ac3397580d,
+                    0xba645c2c559413e0,
+                    0x315e412f66376582,
+                    0x8993550e7b3876de,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x148492d0f0c16642,
+                    0xc0e4f764db38f1f6,
+                    0x8d567c612b267de8,
+                    0x26552ea8e2cf0bf0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6c9b5b8db65080d,
+                    0x0f3af14fab02572f,
+                    0x01a851bccfb6ad21,
+                    0x3e188198e1a4bcd8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48577b6d5eb393ee,
+                    0x8ad8459432e48809,
+                    0x6b645158e12ddf4e,
+                    0x8ee117fb4554cbd6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5f630e30ce88cae,
+                    0x66e859184abd6364,
+                    0xe5ff622f2b2028f3,
+                    0x491fba07a95e41d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1173e0b9aa994498,
+                    0x1065e8ccb4b4837c,
+                    0x2e16c25c8b0a1d82,
+                    0x1de0f64744ba5441,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40bee09e020fc9d1,
+                    0x93cf7f6bd27fea06,
+                    0xadabd1533a7b3534,
+                    0x548b5b53e710200d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb27c125f79c28d35,
+                    0xe5887b0e768d3b31,
+                    0xaec007b6f11cad0d,
+                    0x84da45286dcf887a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a00ba3e8cb4de7b,
+                    0xcf892f050fb4ca5d,
+                    0xc18983c1fb41faa5,
+                    0x7342d4e6b551a868,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8590fcddf3532574,
+                    0x6b3cd530a38d82b8,
+                    0xb467b4e5459e0d9a,
+                    0x94d05dd5591ab412,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb7d671d43c7daf9,
+                    0xaad0c7ddc872acab,
+                    0x23dbe03d841f8f78,
+                    0xbf760eabdcf95b8f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcb19e7748f009a7b,
+                    0x9529775a98f2cf56,
+                    0x6da9b23dfdd954ea,
+                    0x02053f4ceb6713df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7dbfce3ba1ba0f1,
+                    0x1f554e47065be624,
+                    0xefdfeb71cffc6021,
+                    0x90da62317c389245,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf15164c78ea3a642,
+                    0x059a69151d36cc89,
+                    0xa04e5737e05ea695,
+                    0x570d809a0a4cdd3d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa12b27de0e1b2839,
+                    0xed8f439118473b08,
+                    0xa5502db8e126a0d8,
+                    0x1d0f4f0debaffe08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9735258452907384,
+                    0x854b881256562775,
+                    0x4b9ad1d4a862db17,
+                    0x3e0c4e3c34d69761,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56168936c95fcd5d,
+                    0x37f4fc8a71aa5c88,
+                    0xc6a0daebea607e9b,
+                    0xabb92220e52f1219,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4defb48326bc9b1,
+                    0x435b26dd56438832,
+                    0x07c50c2a2b1d781a,
+                    0x80a4383523fcb276,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb0116e1dccfd401,
+                    0x47da4e57ca1c521e,
+                    0x2d056360608c6aa8,
+                    0x84beb2bf9fa738a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8baf3238524d75c,
+                    0x978912c8fac7e952,
+                    0x69a83c2d972779d6,
+                    0xe95fb90db6106761,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39a71f0eee6ec245,
+                    0x4ee3dd277a70108e,
+                    0x8fda0b6ce509f66e,
+                    0xe94f75a004dc44be,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1f413c2271b52bea,
+                    0xc92d730bc6b0905f,
+                    0xe7c8022e5754a6b7,
+                    0xcb35861b41a64e8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b9b48feca38bf3d,
+                    0x0db72f615d454b88,
+                    0x114472fa762e6c55,
+                    0xf6d9b4487d2e76c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53df8da4afd2c23f,
+                    0x7a2fe004f7770fd3,
+                    0xddaa8b23e9c8ef0b,
+                    0x7ddd64aff2080be8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e4de5488d31b231,
+                    0x7d5f43d00978cfb7,
+                    0xbbf161921a9130f8,
+                    0xee50f6c74e5024c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36c16abb61aae263,
+                    0x55fd907dc432aa8b,
+                    0xaa7fed4c29bf5760,
+                    0x6e3bb1641850ffb4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd9ba8ff04429d76,
+                    0xd88f01f5ad04ec4a,
+                    0x945c11f7b1a0bda1,
+                    0x2732d45d736f49d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc50b2bce2fa58993,
+                    0xf733c77e3a7bf3a1,
+                    0xc77174a3143e567b,
+                    0x1266a1970d9f6153,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1d8b335743efb94,
+                    0x2f9a52b79b89fc98,
+                    0x38aeb6b4b086d8cf,
+                    0xa5a2766cdcdcfe8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3bf38f2dc8f0d76,
+                    0x349b841f2eef4fa9,
+                    0xb90ab2ae5f1c0863,
+                    0x7f7029d6d9d41b6f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbd82c32ed837587,
+                    0x1cf4d5c49cd89263,
+                    0x0f36dab3a123c31a,
+                    0x0a5c48fe353659a3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xed82523694f6ced9,
+                    0x6d3c76c68e42d247,
+                    0xd64478e0f882c07c,
+                    0xa041fe735930fa10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05a16f77596cc603,
+                    0xbb3f6d2b4059c507,
+                    0xc8f61fffd3d13616,
+                    0x81177830c82a571c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4b49f406a63b183,
+                    0x26c21bd08fcd5623,
+                    0xd92fe5827137c497,
+                    0x214ee2e4d3784288,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x878bbc8fa3052f8e,
+                    0x4a57d73866c6033b,
+                    0x2141d8d4b63d753f,
+                    0xd6b33057a23ca85a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f3dd9611a021737,
+                    0xbe6ab9f00f25c3c7,
+                    0x523d765b308ac0fd,
+                    0x7de6ced9e1761afe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd54c720b76a490a3,
+                    0x43d02248ae9e5ba2,
+                    0x1e3d600e72370bb5,
+                    0x599f372a3b383856,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1f72b312bd96c1c,
+                    0xb6aacbc919c05ccd,
+                    0x304efd7a109618e3,
+                    0xc3f806e912b1d7b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17923eae7fa528cb,
+                    0x611b83951886b3db,
+                    0x4607461883dcf48a,
+                    0xd47ecd6a3cc3109c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ce7a517f519f263,
+                    0x116b28588ab49d12,
+                    0xd97ae6ba924b00fd,
+                    0xfa6503698bd87a76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d0d7483341a5ef0,
+                    0x6020145e0aeb5779,
+                    0x82cdff98604f98e6,
+                    0xc66495823404db5f,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x18d2b11b22a5cc1f,
+                    0xbeaec93f7ad26d78,
+                    0x4bf9d56a82bff8a3,
+                    0x099dd132c05184d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34a2d443186e0a4f,
+                    0x07bb28f7db035f22,
+                    0x75b195c134201dc6,
+                    0x5661b1b8924090c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd8834f5e0700057,
+                    0xcab8440a0f257634,
+                    0x8c71bea50f8589bc,
+                    0xa80f52f07922650f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x339b88ccd36b51be,
+                    0xdf887e225b07c961,
+                    0x782fd73c6e6284a2,
+                    0x2d48ec800f707980,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cf3747863a6f571,
+                    0xfa4ad3cbd366183f,
+                    0x5eb3474faa97d48a,
+                    0x7a36bf8be6bac3ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fc10440b15417cc,
+                    0xfc4d5b1b1ea5a299,
+                    0xcc7f10d75bf129a3,
+                    0x4948fb723da172de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbac3f08b7279828,
+                    0xed98bd9b52e5012f,
+                    0x269713b3923d5082,
+                    0xf1feab2d1ed78ba3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd5d732b9d8717ed,
+                    0x608875a5ed34ebd7,
+                    0x233c930865876454,
+                    0x4c8aae98a130bf53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x383344b82a5ab883,
+                    0x9815b0b6774c0de4,
+                    0xdf1ff6cd02965e9d,
+                    0x4523d94bcdb265fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc348d7d10add4edc,
+                    0xe9aead033e7a6eae,
+                    0xfd6286b762b53270,
+                    0x844677f21ea979c0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x806267a1f8a6d8e6,
+                    0xba6d1fabcc8a2071,
+                    0x283d788ea057fb12,
+                    0xfeacfc875947d110,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2f7ec9ba906e337,
+                    0x57fedd832e906d75,
+                    0xa1cf61ee889f4898,
+                    0x7bc05578d1748984,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc6e2429b9719288,
+                    0xe430bc9c52d8a521,
+                    0x9c23628b4882c814,
+                    0x9010c2fa020c78e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca5eed6115646bbe,
+                    0x6f6cf242533db13f,
+                    0xcb61b4d53829284f,
+                    0x37b1918ea4fe8ffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a387a4907aeef61,
+                    0x7a7b0e62771cbe4c,
+                    0x85add716a1c3125d,
+                    0x992c90dbd92edf6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x292894d3b8a54359,
+                    0x3817b83e71dd905f,
+                    0x8ee487564025f956,
+                    0xdd47082f3717b74e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf99d06416629e76,
+                    0x73cf3f9dec1b8b69,
+                    0xc8cb2756a1f7af90,
+                    0xc4f81728b16b1d87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bc434f4cd53caba,
+                    0xb79079f5a7f45668,
+                    0xed39ce9a6a2b78e3,
+                    0x5bf3b8d4fcc724e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44e4dc25f616e21d,
+                    0xb385465a24fbdce9,
+                    0x6d65e82b4fdb654a,
+                    0x91af9f6a84da3fd1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf4dec99902fe857,
+                    0x6d34549d85151768,
+                    0x54b87e76d3db5d9a,
+                    0x88c115a0867a2556,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x61913b09b361afc1,
+                    0x1d7e62afcf16e053,
+                    0xa2214b5c92600460,
+                    0xa48b14be22c82574,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6167cab994f8a33,
+                    0xcf4622c5f765ede5,
+                    0xaef9f1801a136a00,
+                    0xf4ee97241f2e1979,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa831dc7fb72aa63e,
+                    0x702cae43091434a0,
+                    0x10bafd7b64866b11,
+                    0x5bb357f0b90aa725,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x540d8a81e6c95311,
+                    0x9856120c31641576,
+                    0x39783868a8ace939,
+                    0xcfc7d9acf661a1e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x881d261fe13dd221,
+                    0xd50db92f231facf8,
+                    0xf77819b3dbf5e6a0,
+                    0x2a95d47be811abf3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe176a54838ad4f1f,
+                    0x017d3e531a19eb64,
+                    0xb3a18dd16e64a186,
+                    0xae6822280b9b307c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9b72ae8507fe098,
+                    0x5ed90a3416c8a1fe,
+                    0xbd52160e8efafdc1,
+                    0x72e89770336d2d50,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x599362c55e7ce203,
+                    0x07e280fe50c931ef,
+                    0xe7f3ec34c0678ada,
+                    0xc26f3ef21f1692c2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3111712c818697fd,
+                    0x0352dff98b40ac80,
+                    0xc2fc48cc1ee1539d,
+                    0x53be2a96a228bfc0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68b0c1dd67f7f551,
+                    0x8d08eb767d2547bf,
+                    0x84cab6dd327267da,
+                    0x2130dee1d1e036ec,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x85c5e0459389803d,
+                    0x51dc1bad9a7333e9,
+                    0x7a532b1809940275,
+                    0x99de3d5dc7396192,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1aacff2d5c9320aa,
+                    0x6b076c30e1239128,
+                    0x3d00cd82c90782b1,
+                    0xb23371bad091c270,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d8c2cb10c12e0d8,
+                    0xd04b1e3b2c89b9e7,
+                    0xf3c504b2ccc1c8a0,
+                    0x73c8e82b3d9c92fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83fc8a0d2d0d3572,
+                    0x50022fc86b6e731c,
+                    0x8c9eac89f8bd6d28,
+                    0x7900cb514932fa26,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba1ded5016e4e2fb,
+                    0xe0a0b8a0c24b3e35,
+                    0x5b8c0906bd81c36f,
+                    0x7ea0c3374ecbaf98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbe8c5333b01ebf8,
+                    0xf023f1f3183d83a1,
+                    0x50d5c1beb703573d,
+                    0x18e50d9ffaffe5f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d7770c48200b870,
+                    0xcf4912b6102512db,
+                    0xe287b001b0353327,
+                    0xf386104dc9230557,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8b438b868fac633,
+                    0x9bb3fce3fc806f71,
+                    0x4f6b66eb0e19311f,
+                    0xfbf569cbd77439e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77e05340c4f596b6,
+                    0xc42830d2d3dc7bf5,
+                    0xb7aa26e7b4b69091,
+                    0x65232a9eb5445bc3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc5bef132f2f376a,
+                    0xad0ca29e8d9cac16,
+                    0x8a3c400a1cae7e3e,
+                    0x8400915deab305b9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdd2511d8f6293976,
+                    0xc9e88548c0a6dace,
+                    0xe156141c782038db,
+                    0x24edcb889e4fdc65,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9105f3c634814b9e,
+                    0xa79d642187eea73e,
+                    0xea159fd810767bd6,
+                    0x067f3f205fa6bdf6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9494f17e2a12d8f0,
+                    0x478a52a213bac4b1,
+                    0x36b4bbc959f8a00c,
+                    0xcbdfdaddcd14ed63,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77bee816bec36ca8,
+                    0xb24cb68c7bb2de64,
+                    0xbdb0b6fed26a6419,
+                    0x36214143697988e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02d5765f1d47d1d3,
+                    0x76ec99128b032843,
+                    0xaf29f6839fca5968,
+                    0xf43a0c403c2d2bce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3add68015f6a5e0,
+                    0x0ed15180d8850d99,
+                    0xa1e5d9671c2bc21c,
+                    0x5a193ccc7e9dc539,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ffc4db556604a2a,
+                    0xde0928909e9b771a,
+                    0xb446290517df6936,
+                    0x998f278c4033f74c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff4139331efc4d09,
+                    0x435989ff63d1e892,
+                    0x563f0a769075ef7c,
+                    0x56cd17217ce8f3b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fabb8c4959d1b2f,
+                    0x03fad83e6e6707e4,
+                    0xf1cd1c43ffcbfdf1,
+                    0xcce8b625976a8ece,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd4dad61ce05e385,
+                    0x8d0d3df30b060930,
+                    0x06a1299331cbb763,
+                    0xe266754d7f152bd5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9eb7cb9e0ef25b78,
+                    0xbdbc9954acf6af2d,
+                    0xf36e2b83a2a23075,
+                    0x28e035876d151b63,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57cb8aa75e3a5de9,
+                    0x6f5c8245b9627242,
+                    0x579737dbcede769e,
+                    0x6367a84529ea5656,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d587e74cd5036d4,
+                    0x535ab3f998fd964f,
+                    0xc6dea5ff1eb8d4fa,
+                    0x622ebfaa05ff627a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c9e5b998918a7d6,
+                    0xd0973190280b12e3,
+                    0xf6d6319f4c43c2a3,
+                    0x42f2042863f382bd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6236011a70ce2cb,
+                    0x217a847195f1edf0,
+                    0xa8f2089b07915cef,
+                    0xe018e34a125974ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf938b41390c895a8,
+                    0x749c06114cd3001a,
+                    0x82e25680667ce70f,
+                    0x230b3fcd27a8fb40,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ec38ca51546db49,
+                    0x487e2ccec6c15c2c,
+                    0xd26c940feda34aa2,
+                    0x2bcf2c47b9e2e671,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73915e5948ec79fd,
+                    0x95bb6e8251a1e195,
+                    0x8b0ca6246d30a34a,
+                    0xa78af7e57e7a37f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd5632baffaf982c1,
+                    0x75db03648fdacda2,
+                    0xdb74447269663b26,
+                    0xe6586e33826fd7bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5c53d067d7472a8,
+                    0x8ea1996ce7e59361,
+                    0x180be71bd81869c7,
+                    0x2713205d7df82c69,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x753d8dd21c53fcb5,
+                    0x882ad9da1063cdfa,
+                    0x2140689af3f1ece2,
+                    0x7062eb787d60fd26,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbce8eb0baef8ddb2,
+                    0x13a224d5aef4b5d0,
+                    0x6161226ec6ec62ca,
+                    0x9f3bde5696370934,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa27704ebe38da5b3,
+                    0xba842f6516ba19df,
+                    0x9d1aae408d50036d,
+                    0x1a5c3b7a194c10cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x713b6a7562ebe990,
+                    0x2a65a5be56adf599,
+                    0x4fcfaf09bf566ae1,
+                    0x662c8538ddce0816,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1bd92239726f8da4,
+                    0x58b69ec6e2ff6ad7,
+                    0x96780cc02fbae4a2,
+                    0x09a3902a8f89ee95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d8bf4e975d89f1f,
+                    0xaa9fc0f93923fe6d,
+                    0xdb38fa3556126aa7,
+                    0x8d7baeacd7eca24a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8cc8b8c41902fb3,
+                    0x4adb4ddb38ffd61c,
+                    0xed73c3dadb349363,
+                    0xfd9f35655b9b183d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c43654e39763dea,
+                    0x0fb9b1b5ff540455,
+                    0x8c8ade02bdb945ac,
+                    0xebf5594446922386,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2484bd792b8a4049,
+                    0x29548b185f1f09ac,
+                    0x2638f0cc186bb803,
+                    0x2f696dc942108ac6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34bee6c720ce7728,
+                    0xfc3f9d924ebdc84b,
+                    0xcc385a6d16a81dce,
+                    0xcad76f5310243533,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x253ad9b349feea49,
+                    0xe11943f9942af9c9,
+                    0x686308e50bf398fa,
+                    0x0744b57402c66254,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6950c83f9ca3d554,
+                    0x188448cb5647c1bf,
+                    0x80029a1d2261ba6f,
+                    0x10f8ff32e59f4563,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25d7be0015a0be9c,
+                    0x64bdac4526673659,
+                    0xc291688792717ec1,
+                    0xf8bda495aefd9afc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdfc505d74a5b6ddd,
+                    0xee1e84c28875c060,
+                    0x5db66a0a47ff36ae,
+                    0x8c3a3cc5da15651f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6915db504c26eda8,
+                    0xfac87454baef31e5,
+                    0xfccf87c816d1d119,
+                    0x61d5717ee7ff0091,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe50f345ca1f523f9,
+                    0x1337785050aba80c,
+                    0x1b8f4cfd8be0ad6d,
+                    0x4b622dab6a202bc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71363c33c67cade7,
+                    0xd61ddd37c02f7419,
+                    0x8053143460074a28,
+                    0xd5db2da96886459e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38e8d19b0c642281,
+                    0x2e1023f6f7dd8321,
+                    0x3419a3037ee0c99c,
+                    0x91e8c664662bb9bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaecd13318fbeb742,
+                    0x6096134f1401f435,
+                    0x15e33f1331df43a6,
+                    0xfd2d63b25d3307f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c2813b4e04e6edb,
+                    0xc2b32639832f07b3,
+                    0x7c8f337b83567cc0,
+                    0xa89e33a392a3fd86,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x190535e1738baa7d,
+                    0xc7e7765b56e822cf,
+                    0x3e859774c887d59f,
+                    0xc0a040d3cef517b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72bbb8680ab5fba4,
+                    0xf023d9592c97786c,
+                    0x74a477d3c4584122,
+                    0xa10835c2098d1165,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf94c34eb0dc2040e,
+                    0x0a042479b53077ca,
+                    0x240267f26cf62bb2,
+                    0x0611f5be8ea86219,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6231a2f3db3fbaff,
+                    0x33d6e5b1ccc52223,
+                    0x26e2ed9b9ab09c55,
+                    0x863a00a760b967d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80fcfdbdbf277b21,
+                    0x1abf9779f3ad94cf,
+                    0x08cc49899a0a6369,
+                    0x2ba14eca1c76e3e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0932317eed30257,
+                    0xac4a1098806a9164,
+                    0x838d7d339dc74a7a,
+                    0x1d5e7c75ba86cd77,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb25853a8d20104d,
+                    0x92824fac2dcc8d3b,
+                    0xba70ff26fb1990e5,
+                    0x71eb83ff666e9bcf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4e3bc66adf685a2,
+                    0x37a4343145c43816,
+                    0xdfb79e5f9a734d51,
+                    0x17f6086c00001b34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62f1a8d893b97d1b,
+                    0xdb39ac3ed7013f5f,
+                    0xdf1fa3a9e9ccd01d,
+                    0xd4c698141ee8f92a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7a1868ab296135e,
+                    0x4675e4b8c1fb3bef,
+                    0xccb61e00b657958e,
+                    0x5d8b7b323e5027b7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xab9f30523b02a2d5,
+                    0xd0f13cd57a65f645,
+                    0x8ed9e5fccf5f64d9,
+                    0x049364f3ff673390,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4983e0a92d10c9ac,
+                    0x472886ac5d65a0cb,
+                    0xa871d366a5ecacd5,
+                    0x8cd8fb8c0914ea1f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c5afbaf9ad48209,
+                    0x20c50390ae588d13,
+                    0xad587c3f117a1765,
+                    0x4b262b3fa2f0ee68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcddcf45fad399abb,
+                    0xe3cc835d60523332,
+                    0x0e008461f6e22de1,
+                    0xd0f696656400b72d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a0a302ad1b56e87,
+                    0x44ca80b7cb784cc2,
+                    0x1b0f0e2430e69246,
+                    0x5b40ef08ec2b8734,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ac839911da2cf5a,
+                    0x1c5be64c37e138b3,
+                    0x160f5485e23cc32d,
+                    0xab60838d522fe745,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08a0a1de31896bbf,
+                    0x33ff3aed2ae25c7e,
+                    0x55c7c4f465f2992c,
+                    0x124cd0892680596c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x995970a2ba137520,
+                    0x8948e496d9482b9a,
+                    0x4b9f6ecbfdc1d6c7,
+                    0xedb6370eeac84a47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeadd89c26cea7965,
+                    0x4ea4b461eb9991de,
+                    0x56a97000a447064b,
+                    0x60fd4c302942ae28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0c9d88bf7b58573,
+                    0x0468ebb93165311b,
+                    0xb3758252f3c6d745,
+                    0x28dadca1b51024b3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1910ca583657fa2b,
+                    0x63f6b8df29a11303,
+                    0xd21e93ddff1de448,
+                    0x2cb3dd9137fd6126,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9e6d046decbe435,
+                    0xd34ad91efe10ee33,
+                    0x7d32bf3ab978ca1c,
+                    0xfe500874716c4343,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e8646f91fd92a43,
+                    0xcc51de30dabbcb53,
+                    0x19a7c5fd3e935eff,
+                    0x92e2aaa64297211f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d041a9562ea691a,
+                    0x8b1c0d29cb0b0b86,
+                    0xcabb1426de4a77ad,
+                    0xae80f2f40099e4b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c1ab1fa13118440,
+                    0xd15626069cdf638a,
+                    0xde076c47cd447a79,
+                    0x6acb46f23d24a422,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5dc9745dc1cd2bc,
+                    0xab9eca9bb453e01e,
+                    0x23ee6d97fbc2faa0,
+                    0xa32e6652f8c620e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x157ad8b026673ba1,
+                    0x7fef2ced6e6895f5,
+                    0x68e5d62a0db12a1a,
+                    0x3319e61ecd0def6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x143a3e8199f5b10f,
+                    0x86605ef37083a12b,
+                    0x5e73aa71b7e64df1,
+                    0xd5d8f3b103b5aa18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c4fedad4f20427a,
+                    0x57466a5b76a845da,
+                    0xd7573537d66968ca,
+                    0x64ed1400a7076fdc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12a115d325ddd7d3,
+                    0x8814aec893508bf2,
+                    0x414362bfdd97867d,
+                    0x97b2ccd70ea0ae11,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3fb720693cc085e3,
+                    0x10ea93ffbf37d5a0,
+                    0xc7b446e12491013e,
+                    0x1f189dde948aecb7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d23964aadc3f3d8,
+                    0x6f15db5d831a64e3,
+                    0x67e76eb71dd2f98d,
+                    0xdb310d35c6939da9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce39de055e7cdd8d,
+                    0xac22638dd5a56b07,
+                    0x3c37b6c91691c15a,
+                    0xd9f28d2826e76138,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0d1c16fd87739e6,
+                    0x897f0ef6a75ec6f3,
+                    0xbac8da3010d1b451,
+                    0x1633e76f83328f93,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0b78f7db36a8d91,
+                    0x8dab6c4e9b3dab93,
+                    0x90cf6bcb0f05125c,
+                    0x0aa5251308ecaaf2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77aee68e18dca3b5,
+                    0xc15d2988e7a8f4b8,
+                    0xde71547cf7d8c9cb,
+                    0xd1e82f3f7dda3f90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28f8d0a769c7ff36,
+                    0xf462367cae4036a6,
+                    0xd64cde3b9d042d30,
+                    0x6a55a3ccbae5022e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23b78f48ec81988b,
+                    0x70f28acc116839f1,
+                    0x7d18be054d75b51a,
+                    0x6b80d692d751a78c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x047deae558a32a9b,
+                    0x80ecf0ea9eca3285,
+                    0x87abe899cb40e5a8,
+                    0xeb71eb995961f1a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35173baae0013e1a,
+                    0x1ee08b19cf61187c,
+                    0xdc81725279c51911,
+                    0xa23cae66dc92c6a8,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
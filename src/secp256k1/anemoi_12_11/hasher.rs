@@ -0,0 +1,3023 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U11>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x0e52c2dbc79742c0,
+                0x5ee398a7158d4380,
+                0xdf9ce78d9018358f,
+                0x6da33fdc5b9c4426,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9dba0be9b123b9cd,
+                    0xe4aa510b95512727,
+                    0x45e12a3e0b8ececf,
+                    0x4954f1c828e0b357,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2715b74df0eb70c,
+                    0x7c8749f59f9eec1e,
+                    0x12d3bf1fe0e3fad2,
+                    0xa00835782a4b3632,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xc8335c46da45c707,
+                    0xa2deddca802093ae,
+                    0x0799d5e516f186f7,
+                    0xa6d98ff1621c9896,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c413db2b23749a4,
+                    0xadd17a956b2099c8,
+                    0xbaabf230b1b13da6,
+                    0x4c981954eb65cc36,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55bc99cfc1217ee5,
+                    0x496178993ae43691,
+                    0x14a8304395d1b31e,
+                    0x7ea91b4c61d6ea14,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe85e2783baf197b9,
+                    0x444d8d283f23fbf4,
+                    0x08c9f1cfb68d0694,
+                    0xe9dd410ebb91f728,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32c28ec7343dfe6a,
+                    0x7e2c4793e916da1e,
+                    0xe68c58f4862eb102,
+                    0xb3550c4439ea02e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b7b54ad39ee36c1,
+                    0xd4a9fb81e26cb2d2,
+                    0x861529820b15a5e7,
+                    0xc82d237c4ef4ab96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54ee5405c3912590,
+                    0x57f313c4bd23258b,
+                    0x536d400073d3bd72,
+                    0xfc3ddb39cd137be4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8180e8e26d5d9e58,
+                    0xb382e37d506b4b94,
+                    0xe6190772a0cb9adf,
+                    0xc53c3edef649ece0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb084f45d15a90bcc,
+                    0xf78d80e5f18c4798,
+                    0x847745e5b39ead26,
+                    0x4aaadeb1ce72a69e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c2491af2179bf9f,
+                    0xc5856b3bdd2b9548,
+                    0xa48406c352521361,
+                    0x95a4acc72cc6f1c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9451f614db311a8,
+                    0x5dfbfb75ebf62371,
+                    0x596097a68bbc3e35,
+                    0x5b47f3e45b872103,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc3ec771a7a2d273,
+                    0x61e209693cf7a323,
+                    0x9120eda21eeb0440,
+                    0xe7b3165b85307e2e,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9e2f6018894ed85e,
+                    0x03c68395909892c4,
+                    0xeac7d33d14cf487d,
+                    0x5cfed9f6a4862e77,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6155c6da710d71a6,
+                    0x9785ecd79d9d070d,
+                    0x4ba0378991f0ce7e,
+                    0xbc71bc2f33b15e62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73cbdad2d8f599f7,
+                    0x5a5a40997ac884dc,
+                    0xf0f2e187cf962c72,
+                    0x65eeec52adc6e4d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8339f08b659ce50d,
+                    0xbf6027bc6feb6e1f,
+                    0x3f9fc68174f73231,
+                    0x864a4e7c0daa7f01,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b0a480abae20034,
+                    0x4fa45274d228f61f,
+                    0x4f98e02b22b137de,
+                    0x09d371393011a7d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf2af9d7f5f869a7,
+                    0xfa8ae1b6743c17b7,
+                    0xa650a7fc019bde0c,
+                    0x4564879f7c4039e9,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xcd4884bca9debcc1,
+                0xc312b619b6dea1f2,
+                0x9e4c81ae2e6410da,
+                0xb8d4de74ee95ce37,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1e804043225657fe,
+                0xb06495af83802d8f,
+                0xa0e4155a53093b01,
+                0xb647d30c449a66f5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x499b24c73fe34a77,
+                0x409b0deb16f2907c,
+                0x3178fd51ebb41bce,
+                0xf7a0d6bc3d0dcce7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x80045185482375a0,
+                0xbcbd5fb4ecb604a7,
+                0x1946181df2d68f64,
+                0x95eeb7296c65fc2e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x000f1810e66673c7,
+                0xfd3a6bf7276b1c5c,
+                0x07c62b2238b49d85,
+                0x44d0caad9d682a7a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x392b9a7286408c88,
+                0x47635c40f2443b05,
+                0x2fd879f31bd4d621,
+                0x2df823892dc0ee3d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1ecac455054cb05b,
+                0x85796df18ca0cd01,
+                0x5aaec2c77458fc56,
+                0xbd4a88e1103ed244,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8f81706366708e0c,
+                0x66e0bfb2bc1ea2df,
+                0xaa02aadcc4ec8189,
+                0x088c3e43ba77ef3e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x70cd346ff7ad154d,
+                0x43426d8a6d9d1daa,
+                0x8a4f23daa9044a36,
+                0x9b84a68555fd6907,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x26441acd302fab64,
+                0x53a65f84b241125e,
+                0xba66bbb73c9633ab,
+                0x9c849a9630dd4061,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xcd4884bca9debcc1,
+                0xc312b619b6dea1f2,
+                0x9e4c81ae2e6410da,
+                0xb8d4de74ee95ce37,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1e804043225657fe,
+                0xb06495af83802d8f,
+                0xa0e4155a53093b01,
+                0xb647d30c449a66f5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x499b24c73fe34a77,
+                0x409b0deb16f2907c,
+                0x3178fd51ebb41bce,
+                0xf7a0d6bc3d0dcce7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x80045185482375a0,
+                0xbcbd5fb4ecb604a7,
+                0x1946181df2d68f64,
+                0x95eeb7296c65fc2e,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 372];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+            bytes[310..341].copy_from_slice(&to_bytes!(input[10]).unwrap()[0..31]);
+            bytes[341..372].copy_from_slice(&to_bytes!(input[11]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x37580ef5cba717aa,
+                    0x6a77c061885560b4,
+                    0xedc5e70578350f42,
+                    0x0ba12d6266d82242,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58588d9f1a704e2f,
+                    0x7d885180a8701931,
+                    0x8defc8dbc4319cdb,
+                    0xb1a7a247ae7a8a10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe6420e513a97125,
+                    0x2fb7cbd8d0867c15,
+                    0x53b5471a26bbc641,
+                    0x9606eca1ca72c89b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf373242a96e7bc0,
+                    0x964c1d56b46f2e26,
+                    0x39616b70b0c4ed1b,
+                    0x066fbbf7a995c737,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16f964a5067d3ea2,
+                    0x8b3e391082d3fd5c,
+                    0xd3e0be3414d19a77,
+                    0x97648ea77e4d07e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb57b4776a1625e5d,
+                    0xb207f5307d04021f,
+                    0x127d80a0fb5edfa8,
+                    0x84d3045140739264,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8031ad87ada7ca73,
+                    0x3052b7a49216ec45,
+                    0x5fa8364e40245a45,
+                    0x7ea35cb119a5c522,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ec92e780c73748a,
+                    0x8d171b54aac1a536,
+                    0x5c90996567063cd6,
+                    0x81d53273890b998c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f923bcb50826032,
+                    0x7a31dcf46be7e83e,
+                    0xd4c61f524cc7881f,
+                    0x1004d48c2f9a4bab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e7ef77890086787,
+                    0x550b290d08b93eff,
+                    0x82c8e34fa11beba6,
+                    0x8e32a8199cca6ab4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49de33ec65929df7,
+                    0x335da6e3ef612df1,
+                    0xee1a071696eb5072,
+                    0xce359387d2dba649,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf55585d3fa478278,
+                    0x3e66a976ef2bced5,
+                    0xef35c42bc28c0bf3,
+                    0x9303049b0a554969,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3f1a6af09d7ae13f,
+                    0x7bcf8250c6d26f99,
+                    0xc7ae4107aded047b,
+                    0x131803b1fb2745a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2ff125fc7ddfc2e,
+                    0x3b23d84729562fd7,
+                    0x44bd57b9ecb49ca0,
+                    0xddb9862c0439d574,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4bdcec7eb61fe50f,
+                    0xa93c13cad712a625,
+                    0x2ee1880e9d283fb6,
+                    0x72f2f9629cea6081,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f68bf81c2fd583e,
+                    0x7938dc253d0ff722,
+                    0xfcccb3375bbe9713,
+                    0x372553c58d6d7bc9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee582fa8629f8c3c,
+                    0x9e29aceedb3be851,
+                    0xd87d85cda4deda4f,
+                    0x6f43d1d292145ab3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb71a366107bc324,
+                    0x128e08dd02832561,
+                    0x4eb86d853d458f21,
+                    0xca822a4cfb98d46e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad4ee4f085b59d46,
+                    0x0ec45cb5c6f23a99,
+                    0xcde141c3488632fa,
+                    0xb71f3cef5beec3c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ccbf984681462d7,
+                    0x10975df574167629,
+                    0x51b74218fe4c081d,
+                    0x8e771b905f92774c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3695078e62d4eb6,
+                    0x23ec1de342ce88a2,
+                    0x8233f3824edb5e3a,
+                    0xdc72a16827c63ea4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1715b08c520e5d44,
+                    0x45714249a3082c24,
+                    0xf97fd6d112b95123,
+                    0x91e36ca4c83782fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02544e19e3e480ff,
+                    0xeeb630959ca2a909,
+                    0xdd9bf467b76eb6f6,
+                    0x1a40da88cff06879,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ec183afdfbaa840,
+                    0xf5811d34115d54dc,
+                    0x34de1b618a977028,
+                    0xe3a575457ee965b4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x922e57d6637ebc2d,
+                    0xd17a0a1abc11a87b,
+                    0x92dcb55938c1ee63,
+                    0x34b366096dadd2a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fb0777df01b60f5,
+                    0x0b768efdd12ad555,
+                    0x81b9814dfd8c3925,
+                    0xa9d03053769498ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc22d113a978d332,
+                    0xf148e2dbfd9c01a1,
+                    0x5586abd07e734403,
+                    0xcb48de17bfcb528d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1684422e3b3eaa1,
+                    0x0cc870e6fe3007da,
+                    0x61efff0f486c8a28,
+                    0x435a037704fdda7d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2eea207df708267,
+                    0xd2d1de13508eddb1,
+                    0xbbabd1a2ea170984,
+                    0x1e932ae3964b02c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2547c04b0042c7df,
+                    0xe7bd2fe7737ad70e,
+                    0xbda60136841c6af7,
+                    0x2cc6801d8d530593,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf164373c21bba7ea,
+                    0x1ad7fc77c042f0fa,
+                    0xe74c195ef260f6a2,
+                    0xae7d6b0f2a1f8fbc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea8b1b8cdec114b4,
+                    0x27ef875a982ab625,
+                    0xb74fead9a41d2409,
+                    0xe0017c44b7bd17a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71290417eb6345c9,
+                    0x9e0e36ee914d008e,
+                    0x9184b1866be4b991,
+                    0xda4c27aa7822db8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf658dba92e6736b,
+                    0xce4762e920a13e10,
+                    0x6fcb9164a544e23c,
+                    0x95bb725f80bce4a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72331f11d40b42b8,
+                    0x37c0577073308610,
+                    0xb956c879939149aa,
+                    0x2c9ff6b880bcc971,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x743f67cadc5a0b94,
+                    0x1afefb40267fa418,
+                    0x4f742d37c1ba6993,
+                    0xc41c1de151e4c90b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe2d77fd75c02476e,
+                    0xdf5be929c22e719b,
+                    0xb5a59ecae3daee83,
+                    0xa84cacf7114e2007,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64f734dc79f2125e,
+                    0xe16df1a098112343,
+                    0xf433673c72cf0007,
+                    0x41d8ec7ab9f16af5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb544b397963e5556,
+                    0xf799a32f98c7e71e,
+                    0x546b3d0148882895,
+                    0x95ca5186a8a0a0ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa91703d2296997a2,
+                    0x48ab9c3d159c2603,
+                    0xe30fa84f109cf5ea,
+                    0xfac9011c5bb7e959,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7cd371f90e44e5bc,
+                    0xbfb086dccb48baae,
+                    0xfd26ede57b33ee1b,
+                    0xc6ce720e9c013876,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a0cc8fc5c289e31,
+                    0xbb39e246d729f4a9,
+                    0x471fe196f78d70b5,
+                    0x0d3b449ec7b36120,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19256a8a8fea6ecf,
+                    0x5110e36e4c96ffe2,
+                    0xf0e5b9d54ec326f2,
+                    0xb8649e4c20c66562,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70571cd6aacfde04,
+                    0xc073111d91e2895b,
+                    0x3f8b506dff8fa867,
+                    0xfb7400cfb5ebaf84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a2e9ef06330f594,
+                    0xdb80d5c3580e155f,
+                    0xfdac8ba06125e156,
+                    0xf0ca9a2315177a63,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0731d0b6be35466,
+                    0xb03af37c08906b16,
+                    0xe9e232ee7b71dad5,
+                    0x52c12812009420da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b296f07821195a9,
+                    0xc85ecc2a7e0abbfb,
+                    0x5f8f4a6bed26b2ba,
+                    0x8e448f25f727ca47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c75c07c0ebdd8a7,
+                    0x7223b9ca66a423c8,
+                    0xe300e8b502f1047a,
+                    0x2bd3b9a0688f74e8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xabc0412a5de7f9d6,
+                    0xb73161ac30731e85,
+                    0x0abea9bdcb6fcb2b,
+                    0xbc2dcfc9e86dd5a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x161d572fba1748f0,
+                    0x7726c3fd1ff94c88,
+                    0x8b8b201a850dcfcb,
+                    0xd68e12ab8f73c2d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a8bcb32091a7648,
+                    0x28ffc43be9f6ea3c,
+                    0x27d9d0bae025abd4,
+                    0x2bff206e46f21d8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36c91c67ffaef632,
+                    0x2661764ac59803fa,
+                    0xfd3da700918ccd6d,
+                    0x6f827ff0487fa5e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc8ef9680b5bf760,
+                    0xb8d34ea8c1e7158e,
+                    0xd393db9d164e5eb9,
+                    0x0cb787dd087100f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7235415a2cabb959,
+                    0xfdc097a1299f4e72,
+                    0xb7f555204e822b5b,
+                    0x9c6ea1712abd2cc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfcff3ecaef3c4713,
+                    0x58552d64f0ee6a9b,
+                    0x2efd9f79d5eec743,
+                    0x84c21683378b3587,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3fb21c1c47689959,
+                    0x840aefabb7e8d1dc,
+                    0xf6b750e6ea070ef5,
+                    0x4b2fa5976856bc11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c4e1c36cf919186,
+                    0x2245996a207cb902,
+                    0x52532198f52e63c0,
+                    0x1f9f6f0161739ca2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef3d3d02fa6ac77a,
+                    0xe17f7245f425b65d,
+                    0xccb5f6064c69e29f,
+                    0x684adef3ce27c7c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x617f08fdf1e6be29,
+                    0x13fd5f2275190a56,
+                    0x1999fac75f449dd4,
+                    0xf1cb5215214d2f33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d6ec5f18ad8d741,
+                    0x94c9adda53746e17,
+                    0x17f2cc5bdc05afa3,
+                    0xe449baa622ac7f33,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xaa0573b370785197,
+                    0x46e20867a4376074,
+                    0x633875ea34838b84,
+                    0x612da45fedb4c32d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe28737f66ed681ee,
+                    0x8c32cf4d9db9363a,
+                    0x508413ad2d2f9924,
+                    0x32e8945aace47adb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b8995f4b5f9c53e,
+                    0x315dcfaa8c69cc14,
+                    0x0df3068d989726d7,
+                    0x40413425b53c5279,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c5dbbf4345489fd,
+                    0x7ed3ec7e4a69b455,
+                    0x937eefb98725f7e0,
+                    0xe636130e719a2d21,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2622b950c48d81b,
+                    0x4704836430c2803b,
+                    0xa98a1316cbe573a2,
+                    0xe5d331eca7253f14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0caa2d64c72c86e4,
+                    0x5071f607be6a5de2,
+                    0x582536eb2fb257aa,
+                    0xcbf43e253065fe51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d1734a825b6e43b,
+                    0x4049a5a52a2dc13a,
+                    0x4c892fefd707fff3,
+                    0x10574bef519fd3b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d028ba58d8ecff7,
+                    0x03838c4f4c53b469,
+                    0x81991886a0655ae2,
+                    0x34603d61eef8e431,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb1bf6cd66baceb9,
+                    0x29f0e69611bd4c5a,
+                    0x113bc89016c5eed0,
+                    0x995d1d70992b6180,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d4a55e1a495a9c9,
+                    0x5b4fce3472aa2723,
+                    0xf7538f9d4db61cb1,
+                    0x9615de021deaedc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbdb5ffe941107b30,
+                    0xa3505f42f134a7e8,
+                    0xf464a5f9d258b4e6,
+                    0xa1553e4005226973,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5fcf13e7652edc1,
+                    0xb939e81b6102a47c,
+                    0xee5701739bc66ab4,
+                    0x945b4582311c83d0,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xfe3205fc48d00f98,
+                    0x5c69d2ad9a7228be,
+                    0x9abaf7a029436c6a,
+                    0x0b7d556ae762c9a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01e4280fd57c9601,
+                    0x59b4413ceae4dfd9,
+                    0x577be3422c18b0b9,
+                    0xc26e168b9f46a288,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x009ef27d921aeef2,
+                    0x7b8f3e45fe354ced,
+                    0x1640439e567ead27,
+                    0x7fc745a2cbfddc16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4498f96e5f4ee85b,
+                    0x1105e7d065326f95,
+                    0xa20bb663c42fa1d4,
+                    0xc9da4ef846d1e3c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c26fefe18e646a7,
+                    0x734d707bdc85014a,
+                    0x3d56b093388675b8,
+                    0x8adaac2d3bdf8a87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd2aea0f95e65282,
+                    0x4b300ab683b58819,
+                    0xffd137c17da02aa7,
+                    0x1ce1f816fbd9486e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x32f078097f43d2d1,
+                    0x49e1e6534082b52c,
+                    0x151f23076bbc5bc3,
+                    0x5aa64ea52e05d6fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5d05ef0770bd423,
+                    0x99a5fc1bb998b8c5,
+                    0x1d01eccf9defbeae,
+                    0x2c3a67c702b1926b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4be60598c965682,
+                    0x8edd08a3cb321132,
+                    0x7186f7ad3e7e4c68,
+                    0x91946d5cf07d3335,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24bd1d24cd380e9a,
+                    0x9a950fb8747a972b,
+                    0x4f9d30d815d2e2cf,
+                    0x8730466e5147755d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x223eb4b351f29e09,
+                    0x31eda6f978578220,
+                    0x1278f92f3e312b4b,
+                    0x663aefebdee2c87a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x148ed8d0fe55d386,
+                    0xf5be3cd48b853b6a,
+                    0x05775f5c42f95996,
+                    0x2813a4c6a1bd7cb4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x29c9d9826617af57,
+                    0xcbc5d97ef8c444b9,
+                    0xa95d0c2c960e83d1,
+                    0xca008a6140a1ff38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x859d7b936a59aa82,
+                    0xde756cd1612b003f,
+                    0xbe47b4e65c90c5eb,
+                    0xee910c416ac2264a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7daded102d1d7f2,
+                    0x3c207c86581ad22a,
+                    0xc25a46269b802bd7,
+                    0x0f6ade7f7bb7fcdc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf0b40b32d4ef7ab,
+                    0xac03670d20d425d9,
+                    0x06952b01deeb873b,
+                    0xb490b44ade0dc7c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc25aee96bbef40e2,
+                    0x97874cb587ed4dca,
+                    0x0cf2e035af0d5cc6,
+                    0xcbbb1e9640d6ac43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cfa9673156eddde,
+                    0x4fd4be6e8ecc062f,
+                    0x2731eb1251c3755b,
+                    0x0ca9174b8403e419,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x66766ccb9b18bad7,
+                    0x1946517c28c20d02,
+                    0x46b7937d283ffd05,
+                    0xa933b3fd2a22cde8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8540dbe17a721fd7,
+                    0xd34c2deef129292b,
+                    0xc3e204d8e06b1a0b,
+                    0x3fb1c860c73bb6b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x375d446f39c267ec,
+                    0x36e72d642809269b,
+                    0x5bb7a5fd9b630cd8,
+                    0xedd8a14484ddce39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb83f8dc3d09640f,
+                    0x1bf70a56f5896253,
+                    0xc1a524cf1ffdb158,
+                    0x84f40dd6ecbf18e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd37a51f8264330f6,
+                    0x4f1dedceeab7fec9,
+                    0xe5100044ff98da0c,
+                    0x7f9eeff5126fe35f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b4a2e2738dd95bd,
+                    0xa2ae95f89cc81e0c,
+                    0xcdb1f3f022f8582a,
+                    0x773579b114d1a330,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3bd8d4f9c667b587,
+                    0xf605a5fb4b82a148,
+                    0xa0cbb672cb15103e,
+                    0xb25a17885bf285fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x716fa857e7969372,
+                    0x84a5ca9b3eec0780,
+                    0x81a1bec5c3cf5c76,
+                    0xe5bf116f6585df88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1eed419774e4288,
+                    0x8fa6084346578f70,
+                    0x3d9a399b119cc5ad,
+                    0xa49e5cf879b5fbef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04d82ffe153064fc,
+                    0xf77186fa2e1517c9,
+                    0x44b7840595b137f0,
+                    0x5fc81ed905b1efa8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa95c3cccfcecb6c,
+                    0x118575e69d17cf6d,
+                    0x5beb10703e7a8eaf,
+                    0x1bcb4f186697480e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5c36e9814b6d06b,
+                    0x5de476b8d645259c,
+                    0x82eae9d2d35dc8f1,
+                    0x8157ade69cc141ca,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x778eecb4d4398c1c,
+                    0xb0686484e0bd4aa7,
+                    0x4e5ab4ea640716a6,
+                    0x52dc61e7c1eb72af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x220b5039721c0163,
+                    0x8cad9f223391186b,
+                    0xe7f761c7f921e051,
+                    0xec7a29f3e3fd2cc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2f1e41bc702ee2c,
+                    0x55d375046cdd8648,
+                    0xecfc62b99b254b4d,
+                    0x17d089b80656bcbe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa49799958e22c66,
+                    0x7041195118aecc35,
+                    0x14b2f0831d399062,
+                    0x1d3dc2fadcee6a8a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e967bc7999cf0ab,
+                    0x0048b7dcb6760358,
+                    0x9406d4865033badc,
+                    0xa42f11c29d93a625,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x751e0a907c7eebbf,
+                    0x4998acfcc3dae011,
+                    0xcf99e7520b2d60e7,
+                    0x32251535de164a8f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa2467eb33dc8fb69,
+                    0x2d7e5cf9f193e8a7,
+                    0x595150cd6cc7a312,
+                    0x5f332e56e2d9315c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x797b9bf3bdd821bc,
+                    0x245fb63474432f1a,
+                    0x2131bf3f22aed107,
+                    0xcffa0e775eb04e3e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71ef00bcedaab449,
+                    0x4197b49a84342287,
+                    0xd473045e34803f35,
+                    0xfcdc960c0f29dc89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48fe2dfab400cee0,
+                    0xea84fed4d506a420,
+                    0xbe2b0cfe6e15d80b,
+                    0xee354f6d47e805ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x411d081b38ae3a57,
+                    0x718dc86047a2033a,
+                    0x43e7a3460f64fe6b,
+                    0x0497f4eec356da0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1be09aa3dba1f6a,
+                    0xf6d070caadc7aca4,
+                    0x48e0c811676d5495,
+                    0x6ddb9f827f58df51,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0156bddc5e522eee,
+                    0xedc1182f9b93231a,
+                    0x8b09653653d93d91,
+                    0xc7f13d9eccee5d07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8356bc7970f8b8a5,
+                    0xa071dc06d769d0fe,
+                    0xff113149b2bd4d82,
+                    0xef32516d3113a419,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8304b0fb0accfcee,
+                    0x1e1c08330ac46691,
+                    0xaf0583c4ce561db4,
+                    0xaef0e4e816ba5168,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4e7df9d5bc42f60,
+                    0x4137d891b24efdd8,
+                    0xce95207f7f6d9e72,
+                    0xfa79603eaf0bbdf7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3aeede087af656a7,
+                    0x86cfd3e6f41cac3b,
+                    0xb6c731eade702b00,
+                    0x1f8c986330ed6108,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a8bb8a685f2e3b4,
+                    0x18cf08c79d51d1c7,
+                    0x3bdef5e6d104e916,
+                    0x536e13488705b69c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x05e1ef703b898fb8,
+                    0xee317a9ae234f53f,
+                    0x804aaf337113df47,
+                    0xd9008efdfba85d23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x597eeec32dc1101c,
+                    0x6b11e04b6666bc78,
+                    0x06482b48590c4a8c,
+                    0x6482eec18c8b4d9e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9fe64a68371d21e,
+                    0xf8fb671330ec9567,
+                    0x5e53aff5163665a0,
+                    0xcd2acc3660c8ddd6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1dfdc746bc0de574,
+                    0x6568da2f76202199,
+                    0xb778f6d69198392b,
+                    0xbba2eef8a2158c19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9b3fe60141de664,
+                    0xbfac20b595591bd1,
+                    0x39ce94c6edfdd807,
+                    0xa1d232adc3765556,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14c9b8b505b9e9a6,
+                    0xdc5ee6952cd0e315,
+                    0xb64f7ba29b7e71bc,
+                    0x699e49284f4a3279,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc6de9b3072786ac8,
+                    0xffa7e863ada8f594,
+                    0x3d9fe0f8262bc5d6,
+                    0xcb16741a605041dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x622dac0a1fb82276,
+                    0x1b24f040e7724a3d,
+                    0x4ce3815eb91d7311,
+                    0x3e0639cae136c032,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x751269173537edaa,
+                    0x779fd1b3085122f8,
+                    0x8bd83d2b45c676f8,
+                    0x1f5b2906dae431f0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab16843db38c4be6,
+                    0x853868b47507b56c,
+                    0x90ff494a4fc8513b,
+                    0x51e5c8fc4bce8dbf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf17781967fd2e74,
+                    0x9cc2904312a6f449,
+                    0xdcc9c6d47ec4fbca,
+                    0x64513aab4ff14ba3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f5375c68aab43dd,
+                    0x172019ff68b8f03d,
+                    0x0d771614076ef44d,
+                    0xeef588f4e8d4181e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd05e0e48dfdf246e,
+                    0x273228c579d21074,
+                    0x112d41678c67916b,
+                    0xa7cbb8b6b5262938,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e8aae91c78c1979,
+                    0x51ff585ace9344b2,
+                    0x67678d54444bb678,
+                    0x3dd4a78697e9282b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a7ce1eaf353c72f,
+                    0xf2f4f0ac443ee201,
+                    0x310d07fdef3e4ce1,
+                    0xcd4e259f637a5ef0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1511592d918ef95c,
+                    0xb937d9fd3a1fc530,
+                    0x87b698e9870f153f,
+                    0x5ede8a5851fdb45e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf2d7ab9f725e57d,
+                    0xb2eccb59df3bdc7b,
+                    0x86e9a80d05685b5d,
+                    0x9746be71c0df0a03,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf422be765add4587,
+                    0x71bd6e78b3389f3e,
+                    0x7a5bb68d8ec9aee8,
+                    0x0e04927a012580c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ded539cb56e0b25,
+                    0x69430f98572310af,
+                    0xd5743ed54c81e25e,
+                    0x712d1c1c6a49d4a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbf051a6c14952de,
+                    0x0ef695e121411ea4,
+                    0x4e5669054dec7a2b,
+                    0x2347b44195777c20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c4582b81f425ff9,
+                    0x2b134758336ff965,
+                    0xca9ac5c0bbfa07f8,
+                    0x51369cef3253ed50,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xffab2eb2711f65bc,
+                    0x1d0a321c8ce9e8dd,
+                    0x222d4f2fd221ffb2,
+                    0xe660e6e58a169790,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x276b57b2f7b4c38d,
+                    0x6a88e22358ade78b,
+                    0xcca88de79a966d7e,
+                    0xe37f352417f2e738,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3afe7d275a9df357,
+                    0xf21c70f4caf0e023,
+                    0x3cd90a75425e11d7,
+                    0xef35e29801022974,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe405ea03bbc0ad4d,
+                    0xeefae5b6b584ff10,
+                    0x9536bb30a3c2b6ee,
+                    0xd1ae468226df9b20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa74be59eaedbfaf7,
+                    0xc254ea30bd8f7482,
+                    0x16369c01aec26031,
+                    0x342553ecf797776d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6da830b0d0fc12bc,
+                    0x1868ad99df6045da,
+                    0xe961be25e4be7473,
+                    0x9aa845ae24654e02,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b0199157c8c0dcb,
+                    0xd2d8a86d228f7407,
+                    0x915a340f84689385,
+                    0x020af246eb143d34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4c80964b09e8c63,
+                    0x34e69008c825238b,
+                    0xe7bd8b0c5c9379f9,
+                    0xb15f5f752c94ca22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf372f1af138eead7,
+                    0x4a45c6cc8422f9cf,
+                    0xe7b1affb47850179,
+                    0x3b4ae1f19c5480e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6357d8973c94b9cb,
+                    0xf2f1e638d32f89fe,
+                    0xc4f47f8dcc0e865b,
+                    0x4c62538103a874d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x440a2384217dd696,
+                    0x6b9a8ed8dc8bec20,
+                    0x263359c281f41a54,
+                    0x28f73e2cd9ebbbd9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fd3333ac14267fe,
+                    0xfd7f94cec8687a72,
+                    0x152576d8c00b735c,
+                    0x7274771c391d5027,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x475653f670af2bb7,
+                    0x4aefa6584916289c,
+                    0xf4486a74ac67ebc4,
+                    0x7bfb7ca1be28ce91,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94fd8b7f2b56e363,
+                    0xe3dd69365f0209c7,
+                    0x47cbebd3b65ce403,
+                    0x174bcb702960ca37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5ca45151fc01316,
+                    0x3eff9d5f1bb8396a,
+                    0x22943aa32182b5d0,
+                    0xf7f2e636649b7a36,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1c6a1bca34bb4373,
+                    0x028560b89557832e,
+                    0x998808198a047a80,
+                    0x1a72ca3b2a1e1773,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0bfbb397738acd5a,
+                    0x3bd9d56fc877016d,
+                    0x39fe95484c763407,
+                    0x69e13f6d2884b0db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a0e54d2461f8e00,
+                    0xa532784b4994b430,
+                    0xb64e3fab4c7e9fb0,
+                    0x156b1be45873d413,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9deda7198b32c13d,
+                    0x05da13ddef99d46d,
+                    0x1e1b524dd5b55023,
+                    0xb9eb4c809955a7f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41fffdfdbbcf6e8b,
+                    0xb4df65cb8aa7a696,
+                    0x8382333c9750954c,
+                    0xafb45e4e40a226a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc4803ef0295036c,
+                    0xf7e5ee535dd767c0,
+                    0x36df557c9d34b6bb,
+                    0xf5b6f4ef23e32808,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03d7cc2bc658476c,
+                    0xc53365313fd20cfc,
+                    0x997d64c2d564631e,
+                    0xaf82afc2adae0a04,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fb64727b378d2ac,
+                    0xa9c35e0a09cfbadd,
+                    0xa18408e60d9ad65d,
+                    0xcfc5325de661887d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbd9d7939dd6dc96,
+                    0xf0f3dfdf11d80554,
+                    0xa355c40ed090b28d,
+                    0x923230f33718ec14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c0cb316ff9d74a0,
+                    0xb364bcef994f3989,
+                    0x9c19d3240d873cd4,
+                    0x5d412c9e79b2ff0f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x200a4a5dfe066a1e,
+                    0x701e58b50b94503b,
+                    0xcd49be9d76384576,
+                    0x42c8d1179158d88d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30748f61a9a097a2,
+                    0x97cb380f5c1340d3,
+                    0xbdfa106b3499df89,
+                    0xa626529fe4f836ab,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xbbf887cad2a818c5,
+                    0xdf6f24439935fa81,
+                    0xbb81d76083836900,
+                    0x6a8ca4a744a36cd4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a76682980986c89,
+                    0xfc219d51469af304,
+                    0x81a589c5e6f17d23,
+                    0x6b1eb81cb12207df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7d7469e041fd67a,
+                    0xd95f6a2aec5649e4,
+                    0xfd25fadd65c9ee9a,
+                    0x0e130c69890dc74d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x072de4c2ae8dd927,
+                    0x2a4ce6c3491feb4e,
+                    0x1517ab36a72a150e,
+                    0x915671bbdf70dd98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32eb952ccb7bf7c9,
+                    0x21713389956c3e08,
+                    0x91fa3b1e8e88827f,
+                    0xb66c695c61104fe8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16af50f84055ab19,
+                    0x2ccf51c74efb67f8,
+                    0x0cbafc4e150d44a0,
+                    0x155cc4a7019c8369,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45ef3eb7c4142a9a,
+                    0x854c24da357d7d93,
+                    0x1d0e79a7771ce06d,
+                    0x74ddaf45f908cbfc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b2055bd4455e885,
+                    0xccff541901388f2d,
+                    0xffd33aa946a59d13,
+                    0xdc5dcac634434178,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0046b864515292a2,
+                    0x828c5179493b9409,
+                    0x6523ff3730599745,
+                    0x6acf56a997c56716,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c017877df0f0dad,
+                    0x99ddb12b76b8db51,
+                    0xa0f327a373a82685,
+                    0xe6baa4c11bbb4ee4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06491b3231767557,
+                    0x43250d31c957fa75,
+                    0x1ea18f9aca2a58d8,
+                    0x270755d7f815cb43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa70ad25d25f48f4,
+                    0x4ca9b5f286e133d1,
+                    0xb8831462c5bdf697,
+                    0x5394d45898e70ad0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd7541f185021dfc6,
+                    0x91f867b1e14f4ee2,
+                    0xb722ca8607a5ade3,
+                    0xb9fcb03ac81ec45f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d7e11862dbd0e6a,
+                    0x062385cede995c2b,
+                    0x2f8bd12c5f657906,
+                    0xc1f93b079d60ee5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0009b61958c30ce,
+                    0xbc0af3d6f6ec314b,
+                    0xab968f67a07b352d,
+                    0xb0d386b26eddf9ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde5b17a005affe77,
+                    0x87e9740b671e581d,
+                    0x905f21f5133a8c18,
+                    0x55884c3ee71e13b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88637da768e4ebab,
+                    0x2aa890be5413006d,
+                    0x420c79cc378e25fe,
+                    0x8c86b575d210c0bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa275f516557b900b,
+                    0x272c62bcaf3e7ba7,
+                    0x16f9cf1bcf1adc68,
+                    0xb80ffb4ce5c20cd0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7a001e0b7abfe41,
+                    0xb7f887bbc1c397fe,
+                    0x461619d5ca0a6b0b,
+                    0x19325d98cbd8aeb9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ab5ccbbc28ef1cf,
+                    0x71d539aac8947fbb,
+                    0x1899c30ef95c8770,
+                    0x793e8414eff382e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7558701929cf810,
+                    0x72030259491ae978,
+                    0xfa7faeb75a0b8597,
+                    0xd8bfc1ad34407d15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69f6ae849f48f92e,
+                    0x8f3a955c05617e4a,
+                    0x9cc5687a1de5c667,
+                    0x21c3c2431b3d1dfc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29fe4ce17de697f8,
+                    0x3612efed44f7111e,
+                    0x02f03e203872d448,
+                    0x91694374db6571a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7be2cb662f7e603e,
+                    0x9d1519088177dde5,
+                    0xf61d85e65c85b633,
+                    0x81d68df137150a8f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6990f333863718fd,
+                    0x39a191447fd4a6cb,
+                    0x9433d913c8980a4e,
+                    0x4a7379187fa513c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa208ac5e102e5e12,
+                    0xd5636bcec4cbf421,
+                    0x03d9b7c9fc834e3e,
+                    0xc250988720d2c87b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef6ed989db494ad3,
+                    0x1cc536255e8638b4,
+                    0xaedb8e008f46e602,
+                    0x168c403528268339,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3e55ca18a914db6,
+                    0xe79beb615345351e,
+                    0x103313e7c49d6a0d,
+                    0x17e65b7603e6df67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75bedd114266f291,
+                    0xbefec1dd2e9e9197,
+                    0x4c110e547c057988,
+                    0x070e5da6a7088c39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc6c7a8075c7d51e,
+                    0xb7461d48e13fbcb0,
+                    0x450682d759e6cc69,
+                    0xde0f2acbdc0a7026,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0531f2e6de41543,
+                    0xa8e040fbf987bc4e,
+                    0xaccea4db6bc7e28e,
+                    0x81e1eb86727a51f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4761156f468dc1e,
+                    0xa78bde504b063dbc,
+                    0xfc61bcac0e67f4d9,
+                    0xfef6334171a25da6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe27a8c4747d6797f,
+                    0x868338100c076a4d,
+                    0xa89e8f93580c58f3,
+                    0x6c585a47327ab7f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51246fbd87013a32,
+                    0x491cc76f3efbc612,
+                    0x57a5fd82db58056a,
+                    0x4a0c4e3f92f0e67c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6953bde50d64139,
+                    0x6849d2f6b878a4df,
+                    0xb028586ad0ec6695,
+                    0xc4fd6d403ab638a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27f23d003e458015,
+                    0x89e414acb1f40e20,
+                    0xf5660194496e96b3,
+                    0x5d4e947b2b572b6f,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x4af7f778f3d14902,
+                    0x4b46816f752c76f6,
+                    0xee51ebd1b8488f4a,
+                    0x161f473aef403046,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03a80b8ecab1d4af,
+                    0xb5ea33c3d3ccd788,
+                    0xf958d1676de87d34,
+                    0xa92a5d9ae1f1cebe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x09ed8d175dcccb2d,
+                    0x0aac95f0840c487f,
+                    0x991f13e3e86bd377,
+                    0x5275abedfd65d2ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf1c54e64299b643,
+                    0x29f948a8b9988b5a,
+                    0x72167d03f6bbfb15,
+                    0xdb7e52fbf5b6847c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc3ffa6eb24d8cbfc,
+                    0x9f6da2bad8cc64ae,
+                    0x78aa3288e09c0c6f,
+                    0xa5268776fd30a858,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb1a352baad1783dc,
+                    0xda4d924d10cb2c48,
+                    0xec0ecafa8d3fc282,
+                    0xafcad7d7ccd3d229,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x714e0334fb1e5b5b,
+                    0x9f4b6caf3b833267,
+                    0x877f39bfc33be3e9,
+                    0x16ab4536c1707f81,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c0f02e5f0591d74,
+                    0x91f1ce3e837aa98c,
+                    0x53391d982361238e,
+                    0x3bdb4fe8c8cc72d2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5794c425a88f1310,
+                    0x878631496ee3f462,
+                    0xb54781e7a81aeed0,
+                    0x47f0372224c53ecb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f89f7cdc9c48d2c,
+                    0x41e3b2366f1fa47b,
+                    0x27ab0c5ca7858dbb,
+                    0x9d747c295480acbe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x670d59a74a5a1fab,
+                    0x0c4099ec056268e2,
+                    0x91099dc86dfbe08c,
+                    0xfd0db9e818d8483b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26a4caf52942a6e9,
+                    0x08a46b506baa5106,
+                    0x76b4a28685acc5d1,
+                    0xd2378b05f40c4637,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x47a51a087c94d06f,
+                    0x7b66cfbe50510757,
+                    0x765666dc76546bbe,
+                    0x3924989650571d9d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x815fda3af1b3f22c,
+                    0x36f45c7d63a75e95,
+                    0x708e388c9c61ac99,
+                    0xad3e684d7a5fb9d3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xeb49a2fba2c548db,
+                    0x3960a4812a0356b0,
+                    0xdd5cf9d29b4a4a8a,
+                    0x1e18a84f52b36497,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0e11b0b2da333a9,
+                    0x4b0400438d550e09,
+                    0x879d3ec5e5c8514a,
+                    0xa478495232b8b58e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbd16062f3b8d22db,
+                    0x7fdb6da74fc24bc9,
+                    0x1da59edcbb6ec238,
+                    0xb1332ad84a576394,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbd306807f0e0889,
+                    0xd5f737532fa78475,
+                    0x522d16ccdd467b9a,
+                    0x5843aa77e4fd37b1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7144acbbee4a7d1a,
+                    0x05c980f9645f3701,
+                    0xa7e90155391af09e,
+                    0x788fab75cee6e463,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9f15c521b39e44b,
+                    0xd83fbb36449bd8a7,
+                    0xeb527a1df3a432df,
+                    0x651322fc4e8eb62a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 341, 342, 1028] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 341];
+        let mut opad = vec![0x5cu8; 341];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
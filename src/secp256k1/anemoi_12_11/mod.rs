@@ -0,0 +1,2906 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7fa3d0a84f09ad63,
+                    0x471e50b5252d4417,
+                    0x935540e5a7301685,
+                    0xac613b7baf2f09b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda04df6e1398a23a,
+                    0xf033628123a24768,
+                    0x6ea6a2ccd2249968,
+                    0x01f329cecbae7f67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a9cf9510b2ba8d5,
+                    0x34db65f27685bdd7,
+                    0xa9d1d563106d0e1a,
+                    0xad17dceeb68c5dbf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfab82add850ed3c0,
+                    0xe41b1c951b669ae1,
+                    0xa7ebf1da2fc182b5,
+                    0xe777e4d58ec580c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ef4eef79e4634b6,
+                    0xc20ec8208ea067db,
+                    0x1b07d9927ab057c5,
+                    0x0715b2a06f87bca5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90f5866cf12dd346,
+                    0x3f53119949988fd2,
+                    0x9ac201ea31e9beef,
+                    0x8c69a588871707fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93a70f02ef27983c,
+                    0x268b033ebfb3be17,
+                    0x1e4e9e6bcb32c0a3,
+                    0x232d39cfbd5c4127,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62f17a064a454109,
+                    0x4033412317f79f3f,
+                    0xc98df18292dcc351,
+                    0x00865777cfd2cb1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d1d205cc9817c61,
+                    0xdba9ab42af1aeeb7,
+                    0xbe4795c39e33d090,
+                    0x521724f310fe44aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7a73e1435565a42,
+                    0x114f4906b70c0839,
+                    0x95b179d6c3703a8f,
+                    0x8c05b0aaaf97bcac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaaf46d3cce8402b4,
+                    0x366317ab53f6fc8d,
+                    0xd1250b182a9bf7f2,
+                    0x6a6e29792ff7ce59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabf3fe449d65112a,
+                    0xa5bb1ec7c89b51dc,
+                    0x797f41edd1fbe23d,
+                    0x037e77817051b956,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd8b06a738f8d09b2,
+                    0x4c68519c34e7ceb5,
+                    0x68a0a5cbb320204b,
+                    0x965c640bc1385fd2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x350b72bcd1b43926,
+                    0x6461a76c668f7e42,
+                    0x21ed3cc3efa4bbae,
+                    0xd6fea0092afd8b0a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d811b6921176bb8,
+                    0xc800c8f5a0df9211,
+                    0x2dfbeba0d71f14a9,
+                    0x4b54e1971c971f56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8b54cfb2d8987cf,
+                    0x574b0ecd36f718d8,
+                    0x8e41cb54248221fe,
+                    0x4a3221d7a959efc8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x733a50d0b1bef540,
+                    0x177a165a31a4b1fc,
+                    0x6863142b21906630,
+                    0x6ad6e2080b2379a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x549ea15b0eb41286,
+                    0x33a7003d257c8816,
+                    0x89f1c80187166cca,
+                    0x7a82eb36f2f7ca51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ede8dc3de45d73b,
+                    0x0031ab29ddce7c15,
+                    0x8f07ab279f9ef599,
+                    0xb7f276ede0192865,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfef44ccfee977aca,
+                    0xe7cabf9e7328e6ff,
+                    0x7ca1587d77f9dc74,
+                    0xc3d8d4432dc834e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0877bce171bf708,
+                    0x5096739afa20dd83,
+                    0x4bf2aff46bdb5bcf,
+                    0x76aed75992b7c547,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4da0bd33f94691c,
+                    0xaba0a835a65c1cb0,
+                    0xddae0e3ef8c06914,
+                    0x3747e9c863536e53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd33aa4564172be0,
+                    0xa1fdce251c6b218f,
+                    0xcffdddaaddf97be3,
+                    0x2d2b4c89f31eb85f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9342aa5d0b2b5eb,
+                    0xfd8700c191106ad9,
+                    0x71a660a2c5bd31da,
+                    0x833910874c92ca87,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x820bda52efc17325,
+                    0xc7180ee065c85cb3,
+                    0x5174e1ea9233bc2c,
+                    0x4c5d8ee056b96a82,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfeb78077dee49677,
+                    0x1e49c16c09fcb151,
+                    0x37642da4e21602d1,
+                    0x1ebde1003d4f6adf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ce0833cee78cadb,
+                    0x851dff2926746505,
+                    0x90d9111180c94e15,
+                    0x1953b23442b63589,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6a57013ae14e69c,
+                    0x0878a0b3bbf511eb,
+                    0x69c84fd80fb7f0e9,
+                    0xf42563b5211b1f7a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x86340f4938cb5477,
+                    0xe56f87d7c3f3d467,
+                    0x48c9b819ae937011,
+                    0x8c26301c4aadc0c2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7eb1455aa9ee99b4,
+                    0xe9451cbbb719e2f6,
+                    0xe36f917fecc23620,
+                    0xe69a7118a4e298c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaafc60fcf5249559,
+                    0x67ceb8241514d8d8,
+                    0xe6cd300234b749d8,
+                    0xa35d5f753ea9f8d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb52a4c22eb17cabe,
+                    0x395ff40103e4a74b,
+                    0x6d288571c625389d,
+                    0x40b8811f7d793f95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b8b401dffb84dfb,
+                    0x2bb47b7936091891,
+                    0x3b4e77c1cb13b42e,
+                    0x2800ba96e07c128b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde5e9b9f88817d75,
+                    0x0b6f2a26681cd9ef,
+                    0x468730d76bcaf8cc,
+                    0xcc79f66344d5161d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ad022bcd86412a7,
+                    0x3a61baee203e77f2,
+                    0x1911a79716dcb075,
+                    0xdd689c649f2f2236,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a8fda89695ffe65,
+                    0x412e793ae3e93447,
+                    0xbd33bd6ff39f80fc,
+                    0xecf968051aeda272,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe6e8e7b1146942e2,
+                    0xb49ce01feb9d415c,
+                    0xa8738cbb70278b29,
+                    0x65344f8a64397359,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e7c28e10adbbe09,
+                    0xb9be9080657cd9d3,
+                    0x35d838c5ac0dd3d7,
+                    0x08b78a651d839931,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15cf823f72fb988e,
+                    0x400fd43359d07875,
+                    0xcfa6db0e8a342f5b,
+                    0xc3d6a85b0193aacf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d8e0f21b037b34e,
+                    0x3b6e8055ee5a1347,
+                    0xca44e8e338b504ed,
+                    0x0824f7f15b5b4edf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42d962f19ce91a43,
+                    0x430b03783dec8682,
+                    0x1db31b9984db859f,
+                    0x7bef25fab160257b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ec6de206086f592,
+                    0xbd786a2b9b827ff3,
+                    0xf7d816d6fff45975,
+                    0x3fb7e4aca92f796f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54ef45d37f839a7d,
+                    0x11328d0dcbedc23f,
+                    0xf43326bbeb36053c,
+                    0x85c14263e0cc0ffd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x311809a3d990a1a4,
+                    0x2c7760257840b9bf,
+                    0x72e51df032da9a96,
+                    0x112e86b756f5c0f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9beca52598aa4b2c,
+                    0x28f7bbbf25e82a83,
+                    0x78c700a84dfdf05f,
+                    0x1ff3d2dca1becb04,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21e55275d60b7c23,
+                    0xa00fce9a95dc347c,
+                    0x073e51f0e563c4f4,
+                    0x4e370be581e1cbde,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0cd10b447dfcc7a,
+                    0x8b3b7390ae836d32,
+                    0x85e6b375bd194e97,
+                    0xbef465a65904873d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd95ac954b0c4c1bb,
+                    0x06166807be90a6e0,
+                    0x519c02442f89c40f,
+                    0xd25e30115cca4ee7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98ef8ee7d6859049,
+                    0x485b547b8d136f14,
+                    0x649808a80ee5de3f,
+                    0xc50fafdc78cf397c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63f02e558366fe66,
+                    0xf5da19ada87ad1ff,
+                    0x5f47f7465ed83962,
+                    0xc39ad220ab53708f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e76b51524b73422,
+                    0x79cc2cdb18e7329b,
+                    0x494f94dfc5579b0a,
+                    0x7dccedae9efb8709,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c76e88e36f3d00d,
+                    0xcdf2be9ff3352ea3,
+                    0x9d0e04aef6d33e04,
+                    0x751e8cea0d0796c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb74812e2ee41286,
+                    0xebf125a8d49c06e3,
+                    0x6728e9ff4c2c4741,
+                    0xbed2ac88e4dbcd96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5740cadafee59501,
+                    0x7cf6e212e3927a63,
+                    0x3df029571c58c4e4,
+                    0x98cc508b17060aa3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22ee673dc170fed3,
+                    0x2679da677e515dc7,
+                    0x68b777013c1fbbb7,
+                    0xaf6a60dcc0d74620,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66aaee2dba992c67,
+                    0x89d3b09e390cd7d5,
+                    0xfae30ce40510216e,
+                    0xaff9c941b6c602ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb28fe30abe227607,
+                    0x04bc8947235a0d93,
+                    0xa1996b339cd047e3,
+                    0x0838bb6748307a35,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f37a2e1e7170995,
+                    0xee40df01bcbffa0b,
+                    0x211cb4519bb5561a,
+                    0xf1d992134876e044,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbec8db77e7b1f4c1,
+                    0xbf2b94efed01a014,
+                    0x79cfc735f804ebe8,
+                    0xbebb825605c2f9c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x586fb43cbfc3eb8e,
+                    0xca70e947d81269e7,
+                    0x6e7fd865184f5a6f,
+                    0xa0a2919baa34fc35,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9f6d48b850b2507d,
+                    0xaffe69cf0708134d,
+                    0x81dbd04a5eb2bb99,
+                    0x6a1110c94ba5c91b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7180c0913947c551,
+                    0x2834213c736c491a,
+                    0x8c7f4193ae390803,
+                    0x14f47076467668dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x247b4322a0488e86,
+                    0xb8bc2b6256849d22,
+                    0xb92e773693ebbf3a,
+                    0xd30546e10c3ad037,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd51c848a43d54a0a,
+                    0xce21b69223bf7396,
+                    0xf0e1ee906f82de8d,
+                    0xaf368dd9b332a567,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2bb8f24ee0c269e,
+                    0xdd000f5c5fb8ef67,
+                    0x14f9c85a184a267d,
+                    0xa5d5024791c1750d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9a2989961e10fb6,
+                    0x4cacf79a9aff964b,
+                    0x25fb8be5c9c75f82,
+                    0x76262f146710ba31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56ddadf3b1846f17,
+                    0x80deb37f2d6f0ec2,
+                    0x06ef88c833939037,
+                    0x0a92c4e5bf31e0e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee209e7b18df0785,
+                    0x7b792ebbe4619498,
+                    0x71c3a0cec90ae9e4,
+                    0x792f923b015faebb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa7087fed08bfab1,
+                    0x09b31644c2b73255,
+                    0xe9df2fd863212b1d,
+                    0xe73d540e7cadd16b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02d689961781ed1f,
+                    0x18559f29e33aea1b,
+                    0xc849c230c3f11597,
+                    0x2f6014615ec037ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c62d6882997b483,
+                    0xa2a737b0320e5c5f,
+                    0x5e6c602167fa5a5f,
+                    0x0fff04f538b677dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92bd52ea2d6e4087,
+                    0xdd858372f8a54709,
+                    0xadade81d146ce535,
+                    0x197308930e5f1747,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcaf4453eb4021fc6,
+                    0x6d4a815ddd20a827,
+                    0xe55cbf493d6574e8,
+                    0xce9197f4f6394321,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ee756a8ed6ad989,
+                    0xef08aeba741befac,
+                    0xad997c34c0901b1b,
+                    0xb57f745a1fd4d42f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2555155deddaec5b,
+                    0x5fe8ca9baa436d9f,
+                    0x1f78232d4922c143,
+                    0x12c10531372d5490,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2cc8aeac38ec553,
+                    0x228e41fcabd818a6,
+                    0x8abcf5126be8cae2,
+                    0xd7444be8aafce77b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d4063baf7dadc38,
+                    0xd31e8c3cb11464e9,
+                    0x16b71f817ff2b2c6,
+                    0x1a968045e8c7d16b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07a86bb92c85efba,
+                    0xa91af60fff7788d7,
+                    0x67834c502c83230f,
+                    0x077bb770c8d71a4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23a031f7f396d966,
+                    0xd65f76fabc2ec3eb,
+                    0x67c63f50ecc4dde4,
+                    0x623e765072717b31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b8a7a545504194a,
+                    0x097d560f53b928e3,
+                    0xad35f17321fb1c4d,
+                    0xd35b2c2f913ee4b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31258c48290d04a9,
+                    0xe848bc8e91f3566e,
+                    0x51a58e1410138638,
+                    0x8dff71570261f600,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ebce77f16e58f43,
+                    0x05bbca2b6a9e7e02,
+                    0x2949251b77a12cfd,
+                    0xf9f6b4cd908a606c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3af72891da62e87,
+                    0x0386a8e29adcd33e,
+                    0xda6cdf75341120a3,
+                    0xf8191ea6f672115f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43d5fa020f504c7c,
+                    0xe043fd74fdf8e440,
+                    0xd51d8ba2e051280d,
+                    0x77fd12c00aebad75,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc763d9add5ce1647,
+                    0x1ba3b9cffc87ef75,
+                    0x38a392d7d8e1e8a4,
+                    0xf5a100c0d8d96f38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e51c82a178a8331,
+                    0x750babb74007fe4f,
+                    0xa7a97692de9caf70,
+                    0x96d85782b2eb7d4e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7ac804edcca1ec0,
+                    0x45d1b1a2b046679b,
+                    0xb74217afe5c18d54,
+                    0x9ba79455e74fbd8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2dc2c6623d348807,
+                    0x40e1e8532beff15f,
+                    0x3e2656b7949708bd,
+                    0xd7453cc6951ad9d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac96aba694477839,
+                    0x4956fa600b7054c6,
+                    0x14637866d939c59f,
+                    0xf40bd12efd048235,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34c7c0756aa5b82a,
+                    0x9c5499ffdd228d4c,
+                    0x1cceefcf60419da7,
+                    0xf7d785bbffb48eaa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca503051d840962d,
+                    0x18f4ab0e6b4df70d,
+                    0x86ee11dcc4acf10e,
+                    0x64eff6c54f248aa2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc610a9679c8a14e9,
+                    0xb9dcb17c5fae029c,
+                    0xee1b416f918c4db1,
+                    0x309905ca62a8861a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaacca4d65286a1ab,
+                    0x45f736b834de37c5,
+                    0x4a90d5e1c7b81b1d,
+                    0xd64bcb7bb26e9c95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75d609ecf3d51e72,
+                    0x8a35ef9f763badbd,
+                    0x8e418222134e88e1,
+                    0xc5be2f63281fb40f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x051ce432c3a61d36,
+                    0x23525dc4409fe8a2,
+                    0x333f902b07a0dc2d,
+                    0x071c490797dfa92b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1c8892b8039d78b,
+                    0xbd260ba39501beae,
+                    0xf31946ca006e727b,
+                    0x818f3bc7cf712f83,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x260ffc4b5ac1a322,
+                    0x1b94d88185ad2a68,
+                    0x4bdeb026657d1e2b,
+                    0xa453d09f30322203,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x433e039ce78eb274,
+                    0x097521a4b38e8399,
+                    0x7fc51fff0d7af195,
+                    0x1fcbe44e834c1df6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7b24422d1440663,
+                    0x161960f845a4eb58,
+                    0xc34793d4745d9286,
+                    0x3c53876019658954,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3ca1072032b1817,
+                    0x49f5d868481635bc,
+                    0x8bb6ef764caf5781,
+                    0x5e839a0fd7b8cd48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5c2037f3937ab41,
+                    0x50b310a241e3dce7,
+                    0x8e66af5e03a2e14d,
+                    0x5d616b26a2247ad9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3fcc7a3bbe1792b1,
+                    0xdbcdb4cd0ca92072,
+                    0x32e3020b4a58efcd,
+                    0x08984b74a18f256a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14b02f8f88262ceb,
+                    0xbe39f5fd47dadcd4,
+                    0x42981fdc08c74f62,
+                    0x726bf9a20f7ba387,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15d4ffbf806d8c7a,
+                    0xfe24134d3503b66a,
+                    0x22f27ea25531e646,
+                    0x2b3c6286b2f449f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79133c4fc5fd1f02,
+                    0x5ffdfe47ebff6e0f,
+                    0x1a81fcde56fa0a2d,
+                    0xd4dfcf1f3706a07a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe430c36f749ac755,
+                    0x57b1d1c5751faa9f,
+                    0x8b4777e78a2e07ad,
+                    0xd22d26e2f1d60592,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ce21d1b23b2f57f,
+                    0x6c6a83cc71995850,
+                    0x8120bc411597212d,
+                    0x780a79a396e55ba6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89bfedb1f04041b2,
+                    0xdc639c0d0e4965af,
+                    0x678aad73e8813e62,
+                    0x4f776de6026d689e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1687fe5c29ee8a78,
+                    0x79615339d48d3183,
+                    0xda1cf80a6dc70a81,
+                    0xacfc6e0cfe160257,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d36a1465fd51d3f,
+                    0xaa16c6e7c75bed33,
+                    0x13ea87c4aaa63c28,
+                    0x4bd207a75ce7e972,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7f64865d2640de6,
+                    0xe339b8c8c7725035,
+                    0x0148f0acf6f99bfe,
+                    0x6928839a5e366f8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05decca8b9a596d0,
+                    0x7c8c8a12774bdcc8,
+                    0xbdb647cee9afed85,
+                    0x008a59d64f55bbd7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x933ef03acb4c480f,
+                    0x8f6dfe0453096498,
+                    0x761cd929b4739d5c,
+                    0xdc99d6d18105fdfe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cb9471516f57711,
+                    0x718a0d326a74d1d5,
+                    0x9d43bdd90a871286,
+                    0x347526083452b1e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5900f8865f004283,
+                    0x0f45ff75916c1ee7,
+                    0xb4842c83eb05a585,
+                    0x1fc2a642db678a1a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ab33548f7d2ed95,
+                    0x509ebd79b8ba06bd,
+                    0xf725b7ddba5e804b,
+                    0x69339e563030dde0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8679a2b208ca434,
+                    0x7611e2a6be755820,
+                    0x5d57d338cfcba1c4,
+                    0x8e8ffc4e5c9c0bb3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1af73a23c0d02606,
+                    0xd56fc3ed4a9cb707,
+                    0x8c6b2e1ef2301fb1,
+                    0xf72e1326d8ffc323,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce4ce0a15ad7320a,
+                    0xcdeb0b7d7bd6e057,
+                    0x715386a6556909a2,
+                    0xeae926f2f8f67c19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01d3343998181cfb,
+                    0x5dca30a171710c7d,
+                    0xc63c369e99c98879,
+                    0xdc36a2577a80e8a5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9f7bcfa77fd7e5cf,
+                    0xf415e5994496d510,
+                    0x47af6ccd7f0d73fe,
+                    0xd32b011c6e2e6b19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd310af627d770d0,
+                    0x33444e07fbe6559b,
+                    0x86e86819b2bbe19c,
+                    0x38420a99c7347ae3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b59f76e728b5516,
+                    0x8f400eccca1a2441,
+                    0xdd10b6fc823991ea,
+                    0x91a4c1d032af30a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x287b13df96ebf9e8,
+                    0xc26e4faf39bf378b,
+                    0xe3c75cf7e6635ecb,
+                    0x08232a03b924d68b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0446a100a85d0bf2,
+                    0x5588a0bddabea937,
+                    0xa709e4c270d9d31e,
+                    0x5823b2f99d825356,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x831171eedffc9dc1,
+                    0xb3ee7b6232a7ef15,
+                    0x7037bb609aa48fba,
+                    0xe999021b04fe9acd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3764bfc9f70d6e89,
+                    0x48509ed56150343e,
+                    0xe39976961977953c,
+                    0x6b3ea8523debb58f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93233aa9a4706274,
+                    0x714fcb39aaf5ed03,
+                    0xaf0a3b1a1d718386,
+                    0x31acf3e0ac4c5dbe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53d266d404d4f9a4,
+                    0x0796b80d792039e4,
+                    0x41ebc99db38e850b,
+                    0xb0b0f26b78d57802,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb87cd6f43bf5526b,
+                    0x69147de633438f3b,
+                    0xdcf925ff374ddb58,
+                    0x304f93bcf76b1561,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d751bf4b70f8e83,
+                    0x801a00e0c1c5ecc8,
+                    0xcb565c3f01f87f63,
+                    0x95adac13f2e32b84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9676ac3d4160ea7d,
+                    0xbd89bf11e1874ae8,
+                    0x35bfbd5398e33f02,
+                    0x7ef4e449d8e403f7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2009b77e88970969,
+                    0x7c12ff540ec2bb90,
+                    0x0bb8456d6df12600,
+                    0x4053d4b10a65425e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5a039a902741c96,
+                    0x5e69f9327263ebe3,
+                    0x078540afc2cfc7e8,
+                    0xc43deaaab22e0fcb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0f9b33d39ee373c,
+                    0x2715276cb344590d,
+                    0x8ecb9959f974b311,
+                    0xb994fbab5b99e784,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c3ec5a803c8ad9c,
+                    0x655425a8da60bcdf,
+                    0x3d2e74ffbc347122,
+                    0x8f8248fad2948f11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0be0216b9d252ebf,
+                    0x442e687d008a92b8,
+                    0x3ac03a01f31ea68f,
+                    0x602dd08b90bce13b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f2a40a197153a23,
+                    0x53a78548e1031ba2,
+                    0xf467195e35e2d663,
+                    0x12c0231e260479bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x280fb7558e5a467c,
+                    0xf4f34395f767ca9a,
+                    0x43f491b794bfa65e,
+                    0x5042b8e8a03b01e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d6311a596d17d6e,
+                    0x178f095768b33b34,
+                    0xe26257fae6df8736,
+                    0xb4df27dbecc653d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4d0aea2193e4f82,
+                    0x6a58e3297de2edf3,
+                    0xecececd0177d54f6,
+                    0x404f18a89fb76f9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac70574ccfdd83c3,
+                    0x6d423690d1c08acb,
+                    0x20a84f931cbe348e,
+                    0x8f6ec5340e49d9e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x442df38a7ba54376,
+                    0x6b221d3a7cd790c5,
+                    0x95a5d2a4df01056e,
+                    0xe1a934426cbb4e1d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39710352591ea9b7,
+                    0x77f243897a1cc9c4,
+                    0x72dd1c2068a73250,
+                    0x5bcd1d50b9e294a6,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc3c29b7b554681ef,
+                    0xe6fafa786318e69c,
+                    0xa3a6c0fdf8bb9185,
+                    0x17957fb65e0113b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6a93560f976bbf9,
+                    0x03fa0c05d6ad54b0,
+                    0xea5abb2330bd4b7b,
+                    0xef11dc195861840a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x269c8bd68fb9a02d,
+                    0x6af7d2213db22809,
+                    0x9799f7c0a675cb2a,
+                    0xa85a1049cfb62a3e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa620a06ce261daa2,
+                    0x808692672bb0e887,
+                    0xa51c68230c752240,
+                    0x45b6e9f02d106dd3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0e0b25c9969b96e,
+                    0xb78af499505ed8cd,
+                    0x4700c881f4fe84f8,
+                    0x0255196a89e05c5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa81bbabfa5474c31,
+                    0x5ff02507dc829d2e,
+                    0xf2a0ee31077133f8,
+                    0x92c870673460c47e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e130b05218fc94d,
+                    0x8a3cf4a2620f8a85,
+                    0x63c20aa78518947b,
+                    0xd7ef82a038df156f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x621dc6acc540971d,
+                    0xbe80cc511af55bb2,
+                    0x3fc8fcd21b3ce8b0,
+                    0xd64a06187d390404,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x673c317625336b2f,
+                    0xc1e88a70ca1e3784,
+                    0x859897dec8a59842,
+                    0xcbe685f80fd44d6f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd1ce6ccb5c63309,
+                    0x671205a14164727d,
+                    0x044c023f076511da,
+                    0x2f10920010ab5e36,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe4572f2eeab01434,
+                    0xe4d3b77f1dc0bafd,
+                    0x139fab7511eca5f3,
+                    0xe0e19598c8517d10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58c0dd46074d69cd,
+                    0x3badcd66f8afc2ed,
+                    0x3929ecaa456f66e9,
+                    0xeee3b1aba489873b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbd05524b5379601a,
+                    0xf2dbe9aad903a534,
+                    0x67e238bf532ca57b,
+                    0x2f366da0ee043313,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e267f982d4ad09f,
+                    0x1d71b354e1e73d3f,
+                    0x581b27338400fdee,
+                    0xcb2c894f71687796,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94a368c4392e12d2,
+                    0xc3aab7033c63df70,
+                    0xbebd28a5ace569bb,
+                    0x061921e615f991b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72fc171f14f2f5ea,
+                    0x485d6c482c720fa0,
+                    0xd12858d0466fd44e,
+                    0x9a61c1241da93bc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7d52da771e392e7,
+                    0x9ff76f171241d36f,
+                    0xa5fc1d0cc92a8717,
+                    0xce057a8754a80093,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc13bdd70e7e9a46,
+                    0xd7b6bcc876ed79b8,
+                    0xdefefdaa1c7ac0ec,
+                    0x9f2e2f9f419d79b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf67cf3db4c5e4908,
+                    0x7b78aab1a2cd57d7,
+                    0x5660dd2687a453dc,
+                    0x3b78cd3cbd91f6d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36c453d0a1b7511d,
+                    0x9209e0a2174e7a2c,
+                    0xff54f53c2ba87a56,
+                    0x24a0aaa7e46b25e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1852c739cfa68ce,
+                    0xde0d7e7dcfefcf8a,
+                    0x5267a107e463b7d3,
+                    0x3167d5a2120e9548,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd3d463a7800d2f2,
+                    0xef5093cae4d9cf07,
+                    0xba18a2136a0edea6,
+                    0x85fde425b3de6673,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2ac01feed3aaf44,
+                    0x18be6feeafea49cf,
+                    0xe92863c4e12b0b41,
+                    0xb6ab070404cf7e99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1656fb65b1ba748,
+                    0x81ba798f138b882d,
+                    0x209934e5bc731d52,
+                    0x499e4ea98bcc799e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9f01782fd061b979,
+                    0x175c67d8e7e26c69,
+                    0x39ef2ac2301ce3f2,
+                    0x586dba3038a6429f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61c9651fbea8485f,
+                    0xbcf6a12d55b25b26,
+                    0x7733d247ae7d71b7,
+                    0x6aefe545d5122747,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5914990e6cab3b0,
+                    0x64108a6d507a296c,
+                    0x1906dc1c4ce7422e,
+                    0xaf84442da73865a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4fbb4d23ca5c85eb,
+                    0x3cde5706a4341df4,
+                    0x6cc9c6d7603de726,
+                    0xba0e31110c7f7cf1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebdcdcaa81603eda,
+                    0x317fdceaa829c120,
+                    0x790ceaa33fc830cf,
+                    0x737973107462b233,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x259f1dd5249ae920,
+                    0xfac3c51801675f38,
+                    0x2eb5801813749828,
+                    0x65dbea661c83dd10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69c9c29a4bc79ab1,
+                    0xec382cc3025d9746,
+                    0x93bd7de6aec00146,
+                    0x0a7aa4d397c985db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8d0757134872419,
+                    0x08e5fea351957d1d,
+                    0x00df9ce5f4f29796,
+                    0x808aa78b4d6352d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e512f1343dfe99d,
+                    0x013cf232f8833eab,
+                    0x9790ad5e72dd6750,
+                    0x593d256e04dd8ad8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x684a58e980f24155,
+                    0xc189b92444b89391,
+                    0x85950c8137779742,
+                    0x932fa337e12817dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0aaa8bdad7a50609,
+                    0xface9ff4ced1171c,
+                    0x87daf58b79983174,
+                    0x207a2811961c9a9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xacac924d6a3a55b4,
+                    0x5a2ce852ffa56c86,
+                    0xb0c9665a4c033521,
+                    0x2ce0d072e49b699f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x05efcfa9493e6bb1,
+                    0x3063fefc7f288cd0,
+                    0x101a450a0ae306a8,
+                    0x59307f05247bb092,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9bb5adfb65970ac3,
+                    0x92fb8f5898783b28,
+                    0xaf2bd4989856078b,
+                    0x41642afa362f8643,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e20404bd316459b,
+                    0xe4e0614fb9adcaa7,
+                    0x2a1627665075be2a,
+                    0xe050188e885043b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf49cbc2e95082776,
+                    0xcf7a9a21e3605f1c,
+                    0x68e28cfba02bc359,
+                    0xb86838db1aa72cda,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f0c4112831eab9e,
+                    0x178c7dcae2507402,
+                    0xf22c35b95072ecd6,
+                    0x93a9ff3639239f9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a31e3188d286f96,
+                    0x30a8a0bf32188d6c,
+                    0xf2e36dbfc8d260fb,
+                    0x89c0c87da72a58a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xced48bd20e532030,
+                    0xb95bad0ce598753d,
+                    0xfe0d34f0dbede61a,
+                    0x0b3985336f6e05d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8058a56f6c743a29,
+                    0x4573cbf8d87b92df,
+                    0x63c8a402b14fcb22,
+                    0x4590ab33c5275e4b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0d48d87f1d22286,
+                    0xef882f683313891b,
+                    0x84a6f720b9ba1d35,
+                    0x538c72a053490524,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f80892bee294e5b,
+                    0xbeb8bb1a04a03e24,
+                    0x1dda96468bd60b39,
+                    0x8917b7c4b945531d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa58ae49dff0b1f45,
+                    0xe2d7b8df06945b33,
+                    0xcbbb9a65a9ae27e2,
+                    0x72511984a6857481,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28953bf9fd6e1e00,
+                    0x7426437ac4bafa50,
+                    0x6b7a98674d3c1da1,
+                    0xe7c2cb65178b2943,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9ef2958bea616dc6,
+                    0x368565bf8e1b9fa3,
+                    0xdf9a00ab3db319da,
+                    0xf06a3e2ddb401ba0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x566e6db299bcc569,
+                    0xc741eb2aa16cf167,
+                    0x42397308f17710c3,
+                    0xf0d022963ac9ea46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30aa710655561329,
+                    0xdb447b40d3397a7e,
+                    0x85ae4ef9f277dce6,
+                    0x310beb18a83b927a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62475d13a0fd0219,
+                    0x085219f0b1bbd564,
+                    0xfd66be4f5e0f363d,
+                    0xa32a7ca563445d10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87aa3684d19ebe5a,
+                    0x33cc640fa265bfd5,
+                    0xcdd3fe99d1b6a212,
+                    0xe29935b4b3b17a90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x765da99213606aeb,
+                    0xded366ac4ed0b91a,
+                    0xfc031ee72b92f029,
+                    0x3e629d94ad819cf1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa13df9e8dbfc02ba,
+                    0x30aaf2e5be7c7e2c,
+                    0xd5fbdfb0e3aee0e4,
+                    0xaffe8237c682694d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94c6bcbbddebfc15,
+                    0xec10a9f32bc237d8,
+                    0xc8c88891d1da5b5c,
+                    0x4ec6e3bc7f2019fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3913976936095d05,
+                    0xae60b0fcdde02644,
+                    0xcf4878b0226d6653,
+                    0x617b64185756f324,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0344d38e22b8e99b,
+                    0x3a3bbed7542d034d,
+                    0xabb6fcb8647cb027,
+                    0xcf6909656409fe57,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5375c0ce660a6ab3,
+                    0x62fcbf3445456930,
+                    0x243034d42eeeb001,
+                    0xf6e5d5f7de477a3f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1be3f8476a863c7,
+                    0x6ac20a3a6c706025,
+                    0x526cfb4b5e6f13b9,
+                    0xed987cf42160e3ab,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x04ee7ce66d4724dd,
+                    0xbbaabd3d1a371912,
+                    0x63a08ad35eff1ebe,
+                    0xe82ff1548a2a4d4a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb56ba65a98ad456,
+                    0x3994ebc02020daaf,
+                    0x5f0925650952a45a,
+                    0x02de5bb765d55118,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd16ba8120579099f,
+                    0xe419deb1d058d247,
+                    0x3bdede2ad54a90fc,
+                    0xdb36ea97f3916663,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f1525a857b40a59,
+                    0x5bca59efe5bc325b,
+                    0x8656ff0e68bd0a53,
+                    0x3fa0608fccc53df4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd9f43851cd0e9d1,
+                    0x521ab2454ceecdeb,
+                    0x89093cd33072615e,
+                    0xc1f9789fbdab4ed0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9dd2e8f8e50ffabc,
+                    0x7ba682b5857def81,
+                    0x310493e0ba06d47a,
+                    0xb191e7fa596196f0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5576fa58d34c67c,
+                    0xe22a215917c1f222,
+                    0x3601634cef9aaccf,
+                    0x797742327e038918,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8384a62e5b9732c7,
+                    0xb82b25baecd77eec,
+                    0xfe16d1280716df5f,
+                    0x5c0bf3668e99ed3f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf50fddec46e21267,
+                    0x5ec753ffc785e1ab,
+                    0xa532afde1e7bfd24,
+                    0xe3e9a858aea87f8d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7586f8f635c04f02,
+                    0x99735d8b879f5bb1,
+                    0x1741ee23615403bf,
+                    0xec5f753c0d91ac08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b38af87dd02d42c,
+                    0x841eec5873dc472f,
+                    0xa7b28847e4de831a,
+                    0x05de52dbe6929a9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56184ce705b3b9c6,
+                    0x1a51b83ac6973efd,
+                    0x3cf3905a54dc086d,
+                    0xaaf501dd2d139477,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbbcdd267662e0bfa,
+                    0x034e6bac5e8a40a4,
+                    0xfc0a839f444e9830,
+                    0x92ed55edc56d368d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2244b697d0086d68,
+                    0x1843ffbb7a10131e,
+                    0x205a3a3dca27fe47,
+                    0xdd50402810b4b5da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a92780f569eebb7,
+                    0x2ca302f0c4168cea,
+                    0x8c9e99f6afc4e8b6,
+                    0x5088af3e543939dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe75842ebfe671374,
+                    0x897f08de418acaff,
+                    0x73fc7563d52ac59f,
+                    0xfc3101a36443f821,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04bb92d9414ade56,
+                    0xf652c232eb81b7f2,
+                    0xa2ad2dcf34a360c5,
+                    0x8431466c56bbd24a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde33760b2a3c3b34,
+                    0xce9b23b27719c17b,
+                    0x97527b56287c9daf,
+                    0x838cefc0f6f35c97,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8243fdd0a468ee45,
+                    0xb0c10473480fe46a,
+                    0x95222882bb84cbc5,
+                    0x6cf8208f6c3f851a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf773fa1dff923847,
+                    0xf0627d3dd391c179,
+                    0x1aeb71585876922b,
+                    0x96945125174bc309,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a00e416f6155d93,
+                    0x6bda341914f2b7ef,
+                    0xb2f21da43a145b7c,
+                    0x333489677b2cab67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72a2a85e6855ae49,
+                    0xed326a0d236650ce,
+                    0x2cf6b0326b33d17e,
+                    0xc69ff15fbf860d1c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5b99f501a6c4f2f,
+                    0x695fb9e0cd92dd04,
+                    0xfde35d3ccf5a537d,
+                    0xa9e7ba0a99a6a3e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54921ce3eec4835b,
+                    0x08f4dcb049d38f42,
+                    0xfe9ebd5912163499,
+                    0xcbed78a2ed13174d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb4a6c0071083e6f9,
+                    0xaae10b5046b8bacc,
+                    0xb8bcc06190525ad9,
+                    0x3765e39348f72be8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05958758aa317aef,
+                    0x21cb95785561d92a,
+                    0xec8a174baa51a67b,
+                    0x0fae58a10f2a51d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb56f994a8ee618c0,
+                    0xb9bf280603cef9c9,
+                    0x15e3d63678a50604,
+                    0x365f6d1e869882e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5b6ba1339c27a9a,
+                    0x6421831fdb6653ef,
+                    0xe4a109bc5322a429,
+                    0x1f40fafeaa1e35de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0cac7758cb91d015,
+                    0x4a8ed2f4d476ecbb,
+                    0xbdb241e0f6493ab1,
+                    0xe451489e45cb30f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd405b78ee2a5207c,
+                    0x5b32547232fb972f,
+                    0x43d9b8f8c66e5737,
+                    0x205865a76bff88a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x189a40a9ed5f0520,
+                    0x0e7b3a3087fe9603,
+                    0xcdeb42f5aefe84eb,
+                    0x979aa18751bd1210,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9566f1b6913d5168,
+                    0xf419e195cc9030fa,
+                    0xf640532a8fe8aedd,
+                    0x07000b9911ea1388,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf90214967e7ab5d7,
+                    0x89a3754aaec350de,
+                    0x49a6a97fd3c543f9,
+                    0xe6f9632d34ca6a66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69a3203095378ab0,
+                    0x47315b5175e1aa1c,
+                    0x02d1f6aad578dc90,
+                    0x6030ed0fec7dc56a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe47e7bf7e7eddfb9,
+                    0x6c9042acd3c252bc,
+                    0xd804f6deb1ec5c4b,
+                    0x9e90113adf0f2f1d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c828e3969d396e3,
+                    0xeb045efca22b1ee6,
+                    0x86b06e02d93d587e,
+                    0x5755846f067ac918,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9d76f0eecf5f0bc0,
+                    0xcc13f38e1110bc9d,
+                    0x24e0427cf9ec3dcf,
+                    0x5ea9edfa2664380a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c246f17a35a9207,
+                    0x0f9eece6a9c34986,
+                    0xfff22e7c9d337d0f,
+                    0x26627a0855ccad9e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x051f5bbe518e0ac8,
+                    0x03135ae07c63489b,
+                    0x98b026b6c1d13a6f,
+                    0x97327a9c9c9ba63e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2d2171a1d1e285d,
+                    0xc54f870f7f766eb1,
+                    0x43e020dbd0b635e5,
+                    0xf49b5f83cdb3f72e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fe0364451e245ce,
+                    0xc7bc1c8d239d550a,
+                    0x3c3c29ba5f80f17a,
+                    0x58489c691e38de35,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6179964c8308b24,
+                    0x329d1acf52489f53,
+                    0x2899f42ae28d8296,
+                    0x144a0cd66a0fca16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94c449d133a345c7,
+                    0x862c32475ff37d35,
+                    0x32fb392189279bd2,
+                    0x3483461b53edc2fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f210272aabb84fd,
+                    0xee6304249d99acbf,
+                    0x5fcd56d06e99fb5d,
+                    0x3903a80c020ca761,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x421b0bc46447f47e,
+                    0xafab6b8086ef36f5,
+                    0x00844d6aca06cf05,
+                    0xfe5af63d245ee612,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x314a3c9c505feefa,
+                    0xcca71af692c8ecaf,
+                    0x0eacf71130c658e3,
+                    0xa04513a1d6e6269a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd39f753ef5b084e9,
+                    0x0e93373e0951e253,
+                    0x7073703793f7b776,
+                    0xc0bb222162792a5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80175207034eb46f,
+                    0x0212784a58551d60,
+                    0x3da286c4023561e9,
+                    0x9322f491c299577b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x96e1c73e5ac50a83,
+                    0x499d543d6a5138f9,
+                    0x4d4462e15c763c2d,
+                    0x39fc861866e8e722,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa7574997d814f76,
+                    0x5a20d3e07af7032e,
+                    0xfba93cd23888cff5,
+                    0x617bcf8611bdad84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c9a6e4ef5dea7d2,
+                    0x217c4ed11afdde81,
+                    0xa0956152cdb71a82,
+                    0xc104edf9e6934a53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x294bcce9d312ce35,
+                    0x76bd0ab5ad08965c,
+                    0xdc82337d5312887d,
+                    0xd6ebf7d91c07ce16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c31f5b21ffefc19,
+                    0x7a90dc3be86ac474,
+                    0x19cbd7deee99cae9,
+                    0xe28ec0976ffb8291,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d1cf1474f660aca,
+                    0x4830c86f202589f4,
+                    0x724fd16ee21ad4b7,
+                    0x386342386b8f48a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9bfd3c96e083a7c,
+                    0x996a6fde6cfae63a,
+                    0x7ea1ff375f04a258,
+                    0xc7cfc362f0a22011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf855033a52e5f4d,
+                    0x66e24c1fed3c7d8d,
+                    0x00949ef9eb0f57f1,
+                    0xac2641bf2a5dadb8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20d3dd63e7fd5508,
+                    0xdbe60aea5ca67243,
+                    0x330fcc775ddbcb5a,
+                    0x30b5500b319f5109,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d2a5482275623f0,
+                    0xbedd8308f92a2320,
+                    0x0ae2baf53799f9c5,
+                    0x6a4fad11190534d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a89f7d1b977d8b0,
+                    0xc6a80ecf3eafa687,
+                    0x032823a5d945016c,
+                    0x6d3d4ca8a90eb927,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ee2dd85f4af75ab,
+                    0xef6724e2271741e5,
+                    0xe6beadd80993aefc,
+                    0x19f4ab50e163fe1d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7d02d4ae9470d47c,
+                    0xeccf86c775056be1,
+                    0x95b00c4b9cd1659c,
+                    0xe455d2a72f4f441a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd61ba135f7afb31d,
+                    0x2befa856f22a46c2,
+                    0x211795df91086499,
+                    0x702ae36c62a134b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24ba95e9e704f6be,
+                    0xb66fdbcf36813a6b,
+                    0xc7f83a4151409fdb,
+                    0xcc8085998596dacf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cfbb12c56f7304a,
+                    0xba92c45d937bd511,
+                    0xaae135540f6d9171,
+                    0xdcecb352a3d21f39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7599bb267bb3226a,
+                    0x4d7aac8d6bc38249,
+                    0xfe5aec646cf1b8ca,
+                    0x7db59b877adf1e48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4a764db2efa27c6,
+                    0x389555005526c0e3,
+                    0x7efc63bcc81e8b89,
+                    0x44d8a15f008c7209,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5aaeb238d7bfd35,
+                    0x0ee378cdb05be28e,
+                    0xcbfef15a0bbd3a24,
+                    0xbe0639f7c9a5db4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa090f974e4f73d92,
+                    0x3855908a729aabac,
+                    0x13e292f1eafafe07,
+                    0x1499c4b7b7079953,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef4476baf680cf0d,
+                    0x52c1dffee6c18275,
+                    0xe6bb212c25484dac,
+                    0x21d92a594827fd97,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad07cb6ac6db3bb2,
+                    0xd54c37e8eaa2f661,
+                    0x2e67393c86e2f468,
+                    0xf374955f5c9eb789,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ab973efd66910aa,
+                    0x8e38f45b6a35a690,
+                    0xf3988405516a30cf,
+                    0xe6496d8cec7e3cb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a150c4aad0e61b2,
+                    0x8048a490b99084db,
+                    0x5ef8b11dd0081638,
+                    0xc4ebd362fb7a0f56,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x57ea8d9e7481debd,
+                    0x9123386de3f0d6ab,
+                    0x62ebee56d98a8113,
+                    0x6e7f5a0fca6add12,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03345bd5325e98bb,
+                    0x49b99e510e29bfad,
+                    0x9cec185fb6bab8a7,
+                    0x8dd57a5cb2496f0a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d5b0b19638494da,
+                    0x28a192f9f6b1c9e5,
+                    0x4dbd79b4ca65852c,
+                    0xc13131e374b25590,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab1eac25495e0135,
+                    0x88075c35aeba6631,
+                    0x735712cb719cffcc,
+                    0xe8b0a09571d1828c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0931b71d19773506,
+                    0x825490cb15babcee,
+                    0xb27857eecba43662,
+                    0xfd697bcbdb5785ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb79986cac84597e4,
+                    0x2c0b6d498bc0b111,
+                    0x77266fc9b887a012,
+                    0xd9334d62552f7fba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54924b8b2384f0d4,
+                    0xc45da4fb8a2c782d,
+                    0xfca3922122f52b2d,
+                    0x4c21dd63d5b8f835,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94732c71120cf874,
+                    0xa9b3e09ec8edc319,
+                    0xa9ea4fb35a6a4835,
+                    0x15541d00f1ed8a3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x285cc045cbf200b8,
+                    0xc6b3ac9dfba1ed0a,
+                    0x2376e9aadd8b9f19,
+                    0xfcaf898e5461c7fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89e30f889d9e142e,
+                    0x983474ce4870e6cb,
+                    0x448e8b54054a17a9,
+                    0x8b35f20e82cd054f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x893420d0fc090c6f,
+                    0xb814f890203daa28,
+                    0x647cfef817866d4a,
+                    0x8949faf9bccedf4e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ad4ce8e07b815fb,
+                    0x4efda85c648162b5,
+                    0x34ea08224cf654ae,
+                    0x51d3ad0dde3024f2,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
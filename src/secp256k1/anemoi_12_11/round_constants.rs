@@ -0,0 +1,893 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x0000000000000000,
+            0x0000002300008593,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x063a893b5e031e1a,
+            0xc759ab3b5432011c,
+            0x1a2e82d7ce979427,
+            0x390bf16667c443b7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xddcc8687187dedb1,
+            0x8d6f3d3ef74084eb,
+            0x85a431cc17b3f493,
+            0x1149232813f2563c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcbd990ba9543871d,
+            0xfdbeba66739a950a,
+            0x3f38f256ad647c59,
+            0xc86db61fdb0001b3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe4b6362271debb4d,
+            0xf37d22e783163842,
+            0x281fe1f1003e92f3,
+            0x8e102f6d1176ea2a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf6ade6304943169f,
+            0x543a87e8578bfa0e,
+            0xc090de5ccf87e68b,
+            0xddf58607b1040ef1,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9fad2ab1de4ffd94,
+            0x36e9d41edfbb48bc,
+            0x3ce4e373e3338ab4,
+            0xe0d5b70dc27ef66b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x71abd6625c7f402d,
+            0xecf27194d7ed1a97,
+            0x3bd58c9cad01ebf4,
+            0x1e37005f634ec584,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb968ab3780c2aee4,
+            0x9d315a85c075c915,
+            0xdb0bace6c1e9cff5,
+            0x6eea207f535b175d,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x3e8ca82c441718b4,
+            0x6ccff560ec31755f,
+            0x27d49f3bb09a3953,
+            0x53b4f63a079ff2ec,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xec2aab2f89be78c9,
+            0x433faa5d37927980,
+            0x0dd9e6cde82b2043,
+            0x4ef77b55f8dd50b7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x358e9998371110a6,
+            0x953d034867ffd389,
+            0x5665a301131238be,
+            0x9175cd8bc0e815e0,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x1868cc7b218cfd08,
+            0x88dfef1cf454847f,
+            0x2d3f03efe6c22223,
+            0xcb6ffb67274f1513,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfb938d1911f1e1f0,
+            0x27880225fb5e931d,
+            0x0dd4734af814523c,
+            0x9235f5e1ce5177d3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x18de3dcb8a376608,
+            0x007d3c49db685872,
+            0xf95beb182b28206f,
+            0x0c61d9e712c4261e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xef9055b7a5711e18,
+            0xb4b9c6083ac29fe0,
+            0xc831d3afd4ad5f78,
+            0x82378bf24268591e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x14a15cdfe2c58dca,
+            0x19d3b0fe0bf1a83a,
+            0xa53398c3f7005548,
+            0x6696d1d3d329945c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8fbc74c4e874bd6a,
+            0xbb7657c33e64d3f2,
+            0x38150e5e7238ea91,
+            0x86ea146c206e8bb6,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x20ddf87b328cc902,
+            0x66561d228b030ff5,
+            0xb0d4ddba9d8459bf,
+            0x479bf57319de8030,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x833aeab772c0e5b9,
+            0x6368ff7dcdb566ec,
+            0x45752631fe5a1664,
+            0xae7dab3ba08e20bf,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4ed8f542a4ff338a,
+            0x0cfa6a922826a0c8,
+            0xade0dddb63173b49,
+            0x0c96d43357249e0a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcee58bfedb640bb2,
+            0xc7237fe73e14d818,
+            0x61ac187b7ef8690e,
+            0x2e891ddf52d2dbb0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0025f3eed62b6f3a,
+            0x8b690aa457bea18a,
+            0x0fb9938ffb372e84,
+            0x3ddef84af39052ea,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x9d1fc6655689d74e,
+            0xe43c94082a4c60dd,
+            0x28d5f207a89786ac,
+            0xfed9fb13bb75b7e9,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc309851b18057eb5,
+            0x58f22d696580a9b6,
+            0xf36ad2fb07d2d517,
+            0x151c5c6499c72abc,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd0190e3d8f31e364,
+            0xe41565118e2ae455,
+            0x02e8d98399eef919,
+            0x77969747b0cc4741,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe3146e852473bc4d,
+            0x9cb979dcda9d706d,
+            0x75469d4f7f289af1,
+            0x4ce677ad440f185a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4411a0c4bf7c9d44,
+            0x1393f6eb075b717b,
+            0xd3619eb2d85d27bf,
+            0xaa50423486c7d283,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8a16a5677179902d,
+            0xfe37e5921a6139cc,
+            0xa8997415caa889f1,
+            0xcf42b39df864b581,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc6513ae6c214ac99,
+            0x22fa416a62812762,
+            0xb61f0be2a2e9054a,
+            0x0c8e7015608b5d95,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa4ccf14624181d52,
+            0x8bfe0a6a8027c593,
+            0x96a2a2e68d5d69cd,
+            0xe5d6078b3d92f92b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb97273da12bb14dd,
+            0xc718f188bc54172e,
+            0xc34ec759d19d2720,
+            0x406e067fc70b6cee,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x03f3ff114f349720,
+            0xc4e81e50e45d1a72,
+            0x804a0e086e16cc4c,
+            0xea38fd44db6ebb56,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x3f9f67bf8eb7a8c9,
+            0x2afc83ea76a4fd78,
+            0xc6f539d0a730e649,
+            0xb9e45904f377e2a8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd00a7fc2e313785f,
+            0x918dcce975e01b5e,
+            0x06d531875cd2694c,
+            0x76c4a43899bd9aa6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5156368e2ce2f977,
+            0xae29197ae7e3109d,
+            0x0854d4c175a7f04e,
+            0xb02925aff96a8f08,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x715fae29fdaa3458,
+            0x9e3c5ebfcbdacbb5,
+            0xd32cee23e992b308,
+            0x7e750739aef968c1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x68eeaad955f6be00,
+            0x7e16df906ef0f5f4,
+            0x22fe83c05a677ff1,
+            0xb211448a437ce05a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc298c36eec2d4b42,
+            0xa5e4e245445ccaa1,
+            0x223988a706d0e512,
+            0x31ebed12c7341867,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf9afc3c205d60a64,
+            0x3473d65d66d0ea4a,
+            0x9259740e1d374ea7,
+            0xa314a3c3810a0dd6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb957e55aa711743e,
+            0x24a474af1e9fc987,
+            0xb3a3c9a36c016545,
+            0x6a0b3dc3dc33440a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x321534b57c0e0efe,
+            0x58dfc3abe8fe605c,
+            0x45c953e64076ab10,
+            0x2dbf7904542f47d2,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3f4b7168d163f9d5,
+            0x9dbcc6ea97e79257,
+            0xb1883138228b13dd,
+            0x2bb79af062c2269a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf9d16fe6debe0c52,
+            0x90afc95dfd26e641,
+            0x0980e79242832160,
+            0xce0067c45ecdaa91,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x46c4cd78ca44f86a,
+            0xc0a8375333248eaf,
+            0x1cc1aeae25d39e01,
+            0x0df5b256139e2bed,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x37df603f8fac665d,
+            0x649d70e232405e93,
+            0x3c4acdce75deafdd,
+            0x5cf43e3e8ec6de5e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1668b2d7a241b293,
+            0x0d0d214beab95c7d,
+            0xdb66bf76c85a079f,
+            0xe830163ce2626a59,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc7e0e8d853c22002,
+            0x3e9469905e5e3e80,
+            0x2f8f3ac977c55dc0,
+            0x03a5d27af3e596bc,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf4460a2f397b30ce,
+            0x82deaeb6a6f7eb90,
+            0x6376261e0c6aa77c,
+            0xe9c9169c5512100f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf9ae780865f9a562,
+            0x62437a1eedef75f9,
+            0x54cd922bfa409042,
+            0x167e79f70b3820ba,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaddf11cc3aacbc9a,
+            0x7eef68bbca6bd7db,
+            0x3ed3153ff9eac18f,
+            0x4c87369945842f6a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8029fab3783ba9d4,
+            0x5a299e98c7d33442,
+            0x8531b3935c576bfe,
+            0xd3b3ddaf56c8574b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x2bfdc3a15b6a4b11,
+            0x777ec1c866433e4b,
+            0x51f4b3ee90d50cd1,
+            0xf64202299bbca6fe,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xecd4036aec7d5c4b,
+            0xafe7508bc7633c83,
+            0xedc68333a96d6fa4,
+            0x0a05cb197ee890c8,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7e4de3cf1b5f8617,
+            0x4da262289ad1a8e5,
+            0xd2a2d2e1657e00d6,
+            0x7e8818adee48f8f3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x38e71936535645de,
+            0x8bbc83ef87ab8845,
+            0x1e5dc61a2f2842e3,
+            0x9043aa12508c07c8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4008ddfc8505fbe9,
+            0x257fcbd669efd56f,
+            0x0e9060084bffe7ef,
+            0x928928568205f80e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4b070ba56baac695,
+            0x53267662bd97ccdd,
+            0x16804677d9e7d36f,
+            0x06f67ba6b89baafe,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x099d5d6571a7995a,
+            0xd88ed2ad70cee684,
+            0xd28d8a733d1cd610,
+            0x5d60f725e1764cde,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xeb4ff96a4f9a98cd,
+            0xb0f3cdd6aa88ece3,
+            0x346d6e88c4ef9a00,
+            0xc4559582630f3d7a,
+            0x0000000000000000,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x0000000000000000,
+            0x000000235555dc2e,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x910239b700be78c0,
+            0x0f42d4c5f2481f5e,
+            0x6e4f6668e5f35b62,
+            0x39507628c0873840,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa7f31a06de87ff9d,
+            0xc7977d3d8480edbd,
+            0xdc2210690d4cf0d1,
+            0x1221dce1e0fc722c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x74d2217e5ba6a3cd,
+            0xb773408231eb4904,
+            0x0028a2241cf06fd4,
+            0x3f31dea9a0022ffc,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x66ef001069eef2e0,
+            0x989a92818b583dda,
+            0x35ac7028a7354cd8,
+            0x846469e5f7401dad,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcfd9ab18107f4963,
+            0xd4c784885f3da1a9,
+            0x7ed2aaad6b95e171,
+            0x94b45cb3ffa3632e,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc8e0a2e8e19092de,
+            0xfcbfba6dfbfaeedb,
+            0x62a10c2b44ffc651,
+            0x3dfaec31a98697d8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x25a6ff14027b2c4c,
+            0xfab1816e3ced885e,
+            0xb5b298e52629eecc,
+            0x7ba0ba45a3195b7a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xacc2b6ed4a0d5249,
+            0x9d2f80d314a0816b,
+            0x5745b43b194f07d1,
+            0xcce80f5d076cd4bb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x10b8b1260dbac6dd,
+            0xec5a61cb716c78dd,
+            0x0e8077c081f2686b,
+            0x279e53e7b3a9c2a2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x9796ed53850f41d5,
+            0xae33004606bece9c,
+            0x41229dbcf0ee15c5,
+            0xa270eaf2c5ae25a7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x37edd6b6018dd4e3,
+            0xdb9fe637369bcaa8,
+            0x3a63980910ec6f42,
+            0xa559d95bf68f0b8a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd7423a9fa3699117,
+            0xeb110ca79c634666,
+            0x35d4ef75c2151bef,
+            0x2858de8f68edeb33,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4534abb83689ccd4,
+            0xd1a2493aec2e1cad,
+            0x6a8b4261eac31342,
+            0xef635dcc68b3427c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa1de3f6ed21e0832,
+            0x9cd699d2bb622c90,
+            0x586fb53afc141679,
+            0x6a2376c9216d182f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5762549eedb0cb06,
+            0xd09f69ae4bccbf27,
+            0x91b76f031f8c4cbf,
+            0x55e497a449095d87,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x55b394f15cb2559b,
+            0x21223e2266ed191e,
+            0xbb561281794a08f9,
+            0xb9d3ef74fa919dff,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x27c1a7d0318d806c,
+            0x9e3471ed98cfe6da,
+            0xfeecc634e999df44,
+            0x9a91ce40b0acb612,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xadeb385a5da5a065,
+            0xbd98710f45bc701c,
+            0x2b19936744ee6cdf,
+            0x275a4cf9764c7e1c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x9b0fdb12409517c2,
+            0x02947cf4d12f8ebb,
+            0x13dabf6fbd1ff0bf,
+            0x8e80878455bf1334,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa60cc8a096221908,
+            0x9e64fe7d1acb1326,
+            0x7ea37225001a4aa7,
+            0xed2de573809cb7e6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x04eb5ca0ccdffbf4,
+            0xd81a59ef61c9959f,
+            0x9ce07df595ee6fa9,
+            0x850b9def744307e4,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0f6bfdbbf9547e30,
+            0x87c8ce2ac564b0ae,
+            0x978ad7744997fb89,
+            0x13f18a4a35c78458,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0358cb2c48dee175,
+            0xbc0be49497621205,
+            0x615c7404ec0f94b3,
+            0x9557294666830a11,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9b10c9f2d50320a9,
+            0x61c752e7e1936bba,
+            0x2f3f35d3f75e77d7,
+            0x709a81841e71f824,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x32e80390eeeadffe,
+            0x34d3b41a52fe6e01,
+            0x92de1feda0d66314,
+            0xd35941298e3a0931,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x854246dca77b702d,
+            0xdfb6df598e9b44a8,
+            0x0798dec5644d39ef,
+            0xa93d568695c401b2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc511766042dd5be8,
+            0xd61da284ec6990dd,
+            0xd025b1593774bdf9,
+            0x7c928fddd074ce33,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe456b42e26876d85,
+            0xac2a7aaa4960aacc,
+            0xf1fa6526612ae696,
+            0x2115133662d8b66b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x778444a6464e8151,
+            0xac5c638890f03a66,
+            0xb0353b0c2e82a2f0,
+            0x1ecb6be133d57f39,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x21ddc401b70e45bc,
+            0x82f13b3a2ae7a666,
+            0xf0c6ceba95043ce9,
+            0x73125db9bbccc4f8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc14af710486c941c,
+            0x05f54be2afd4bfa8,
+            0x7193d6bef09fc177,
+            0xcdeee1709e082d45,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4b2b654ca834d176,
+            0xf6038f1ec7080d7b,
+            0x30ec18796b569ba6,
+            0x784e0d2d26b2a314,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x65a8cb3de810ea12,
+            0xdba43ad58a603ba9,
+            0xe20915721e63acdf,
+            0xbde4d7bd36b3dcbe,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcf541c6b6e19d48b,
+            0x2d9e6d52d38cab2d,
+            0x6e85eb930b6ff64d,
+            0xfa5534dffdc099f6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa792ce30871550d4,
+            0x25a946ea44ff426f,
+            0x20bacce6195cbe51,
+            0xf424528ac643af12,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3a98481ef43ae542,
+            0xf5d6bce241b493f1,
+            0x714ac049354b1ebf,
+            0x1cd274554ea19b99,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xbceef549ef42c990,
+            0x1d9a673d2d8b85d7,
+            0x153d3976bd7bb2e3,
+            0x50b336683be807bb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x55f7f0e2a8c80a47,
+            0x37a78065f221a514,
+            0x16d5396948224d08,
+            0xd12213e833e6672f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6be0ee79c2c9d42d,
+            0x45c2ba9b45a60fe5,
+            0xf166f600d87badd9,
+            0xb8363968e5b46ef6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x04c9493c95b258ea,
+            0x215c426b476640c0,
+            0x5f4e2a005eb08ae1,
+            0xfebce55861a4aa65,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd479939139daeedb,
+            0x31071e6e11347997,
+            0xa228f25c283d11ae,
+            0x82dbbccc4276cee6,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2882a416d64f6d47,
+            0xb82e9d07656cfa21,
+            0x1a84c7de305ecfe8,
+            0x60817c13836e5f9f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6dd053118664de3b,
+            0xf30ac905136d15b3,
+            0xc69e61c967b2a4a5,
+            0x030ecda9d83cd81e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfa2293a6953a7dc8,
+            0x15424d6e389508af,
+            0xdc3c23f12940564b,
+            0x43984d33015480e1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xca0f23b15afaf67f,
+            0x38c3cd1a68c123bb,
+            0x66371441f33e5f63,
+            0x088247eb747545ab,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x81d8af749f3d6169,
+            0xcc9c67026b2b7343,
+            0x51efe4547d247d8e,
+            0x134e31d8e8d7d6e1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8a43e06d1fe9c267,
+            0xd9933c4cde3ff74a,
+            0x56cd9dc021a714b1,
+            0xef2e8a4a633123fd,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xdc4438bbb85ff13a,
+            0xd555c1c8ad4aa6e1,
+            0x5ee11595665b02f8,
+            0x449a2264d4fc17b6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6c74570f8799bca3,
+            0xfca3b6bb3d02f8f2,
+            0xa45965346b8cb2f8,
+            0x71940a81e3e51cea,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6003d3d77f9b8b21,
+            0x0b8ebbcc08a9a563,
+            0x90bbe3544974194a,
+            0xa830fc1b92785301,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1120ba02bd83831f,
+            0x665537c637214cf2,
+            0x418c52d825d3baf5,
+            0xa54912019bb48d3b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x9634bc1bd25f4310,
+            0x6f1344741f82a898,
+            0x5aec319d91bc2232,
+            0x4767486b016fe228,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xadfdf6de329e4baa,
+            0x82eb603d801248d4,
+            0xa7733efb9f6bc607,
+            0x1b95ad8e4d71ecac,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa9acf9735fbef10e,
+            0x62b8449e9d809fec,
+            0x0ebfb27e0eae52a2,
+            0xd4de5922fde01b7e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xef0ddf563a710b7b,
+            0xe8bb8fefd31b46f3,
+            0xae9b8947efb45be9,
+            0xe6de6f49b8e61edb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x358e87208f6f78cc,
+            0x74bdee4aa489dead,
+            0xa12b1e41eac935f9,
+            0xe9b822855ea73688,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1f5eb20c766d4a6b,
+            0x21f0def429422143,
+            0x138cd5e1f2a418b6,
+            0xd410e4a58d34fbd1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb7353cf7ae173be4,
+            0x92c224bd266a8c87,
+            0x1c36f8478d43e1c1,
+            0xaa0b7213d6d6a2ec,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xefdad3f65b363688,
+            0x4696acec5f9434ea,
+            0x2ecc1a760a2de6b3,
+            0xd16aaca3c145b441,
+            0x0000000000000000,
+        ])),
+    ],
+];
@@ -0,0 +1,2796 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U8>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x121e4b1fc7fd7ae7,
+                0x580ac3de73ba6450,
+                0x8451041cf21b48e3,
+                0x373bb1adcc5b584e,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa5421999158cba09,
+                    0x7a9608edf67a40d5,
+                    0x31deb2e2994588f2,
+                    0xd9927a3200e15a13,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8c10a883b0c3ca2,
+                    0xd0666efeaf9aa44e,
+                    0x436007561079d946,
+                    0x4c53f3ffbfdd08d1,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9189b4f74a4d06d0,
+                    0x48c1a1c749128bfa,
+                    0x98bae13ce2c4986a,
+                    0xa7cb3308f91060f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c658142ebfae1c7,
+                    0xa15f3d10b0c61642,
+                    0x91af1a4970f65a6e,
+                    0xe348ac9f59b63fef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b12db27d1c862ab,
+                    0x56a6248fe3d09840,
+                    0xfdd37ea979d8b3ac,
+                    0x115b73d613e8162d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x460f6d6dfd8fc2ba,
+                    0x97851258c512a411,
+                    0x090119fc7f945bc5,
+                    0x2fa61ccd6575541b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeaa1db4bd434fd73,
+                    0xdb995a060ec386ac,
+                    0xd60a0cf5dcc67747,
+                    0x1c5b6cb70d283d84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd143fdefb5d6eac3,
+                    0x3313299e0482239b,
+                    0x2f1999457ffcc342,
+                    0xde99ea44fc1970d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69c21ebea49b7022,
+                    0x82d4787d056f14a1,
+                    0x981fe0aabdddbbf4,
+                    0xcfd09ecfa1985905,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4d4d3882e22913ab,
+                    0x49dd53e275d8bb03,
+                    0x0ea47eefa5477ffd,
+                    0x770c00687501f7ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x794ab649b0ef9a1b,
+                    0x022dd773c3ef5ab7,
+                    0xde806caa8dcd4906,
+                    0x836b51eeca134ecd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x041c96b3b6d53a5b,
+                    0xa6c528c92ce2aac0,
+                    0x79c470f751d3abd0,
+                    0xba2e019e7f36988c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35b0dff86accb674,
+                    0x1c8e5090be84ea22,
+                    0x4ff9cf68d949e2b8,
+                    0xd14787b9cd1d3b6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef4041f1ecac9b37,
+                    0xb2d0ec1b5211d15d,
+                    0xee1991eaf394616b,
+                    0xb09a7866e42974ea,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5e7735d90e8b1abc,
+                    0x328f1b001f0166e9,
+                    0xf5af5c9197519b5e,
+                    0x17034046d42035d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17cc3381fbdbde6b,
+                    0x844cf923b763f288,
+                    0x7c2d334a20b80f4e,
+                    0x4c700b36c0b07a53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0aab28cc46113a84,
+                    0xf3de2e7dcff6da4e,
+                    0x7321b38320c51e42,
+                    0x6a9e83cf88e6f850,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabbcd8b43623d25e,
+                    0xce5d673cf2e970fc,
+                    0xb4f86a1d35f4ea36,
+                    0x449264bcda2474b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39fc0262a7a71fac,
+                    0x25b60e7bc5d4ac68,
+                    0x4fffe9741c20a8da,
+                    0x30442b3a89f46c20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x812ebe88600f606d,
+                    0xe59d7a261a8b0991,
+                    0x46120e0ade6b049c,
+                    0x612ed2fc65ae59ba,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x07297ea56df80ba4,
+                    0x3b39df4d53c16fe7,
+                    0x90b43f81db3f99f8,
+                    0xb4ef2d932dbb5db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ec1a89622c33514,
+                    0x5db7000f492d84d8,
+                    0x1c5dbc60e1819941,
+                    0x6acaf5620d13a90d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0a7a4af8bf443745,
+                    0xdea0ce8c9abab1ae,
+                    0xd75f8883a30cec94,
+                    0x47a3a034fcc23234,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48a6fb3369f0484f,
+                    0x012519127db9a930,
+                    0x06cc453d993fffde,
+                    0x74ea68a55212a649,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1922fe0572449efa,
+                    0x3e0efc8b572e8554,
+                    0xf9b63011595a96d8,
+                    0x59bfb62f5eeb0396,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9be970b012cddda5,
+                    0x8a771f1e1914921c,
+                    0x7bce8eb8d91f11d6,
+                    0x19a03ed106c9b3c0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x287869bf057d7183,
+                    0x020ef04cf1befd3d,
+                    0x027d0bfe4205a6f0,
+                    0x47a8590fd3882042,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2f29e5c0dcbe975,
+                    0xbfc950c3fcfcd2d6,
+                    0x7a877a74d95c92d9,
+                    0xa2e07af6ff126e02,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf42f8aeb821b59c1,
+                    0xa33b519d6967ed43,
+                    0x3198a89159f18284,
+                    0x78e7c735d840a3d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f8e24b16c054a77,
+                    0x4e8aed8384670de4,
+                    0x3c5f9afd52c780bf,
+                    0xcb0fb8c24f6352ec,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6b0b347a34aaebff,
+                    0xa388caf9d036db4f,
+                    0x8da0b6646eb806c7,
+                    0xa8b37efee5209fc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75a4688cf0503ff4,
+                    0x845967ac64405674,
+                    0x5785551b7a769b22,
+                    0x1d351be5993e49fb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x10de38156e915357,
+                    0x4c2bdd6b142aa391,
+                    0xeb30290ecb50b848,
+                    0xa34ba2a26f545c6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x381cc8cd29856605,
+                    0x1eac448adb251cc3,
+                    0xf59e4ce949b8a516,
+                    0xf770976dee06ab32,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6972a9364af38d28,
+                    0x4c5301673c036a96,
+                    0x1074f36f1fad416e,
+                    0xe66f73bb91912154,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0651e94cd9089e08,
+                    0x5c387cd96504b760,
+                    0x8be50091e3427c6f,
+                    0xacb5e7b36e3cc3ed,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6e896dcf916653a1,
+                    0xf0e0ec604fddd6cf,
+                    0xec8ca4a6b8bc0826,
+                    0x63420628051c5879,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed1111986af9920c,
+                    0x25c044f4a9a12fe4,
+                    0x6a645467fff10229,
+                    0x70c59208efd1d04f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaf854f1f9075ac7a,
+                    0x5ef8440cf4075202,
+                    0x1aa75da127f696f3,
+                    0x9eead889f59a41b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbec0dd9ea7458d0,
+                    0x8b3e2ee111a6ac4d,
+                    0x3dad53a4a472777f,
+                    0xf2fc94103e3289fb,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x07297ea56df80ba4,
+                    0x3b39df4d53c16fe7,
+                    0x90b43f81db3f99f8,
+                    0xb4ef2d932dbb5db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ec1a89622c33514,
+                    0x5db7000f492d84d8,
+                    0x1c5dbc60e1819941,
+                    0x6acaf5620d13a90d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0a7a4af8bf443745,
+                    0xdea0ce8c9abab1ae,
+                    0xd75f8883a30cec94,
+                    0x47a3a034fcc23234,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48a6fb3369f0484f,
+                    0x012519127db9a930,
+                    0x06cc453d993fffde,
+                    0x74ea68a55212a649,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1922fe0572449efa,
+                    0x3e0efc8b572e8554,
+                    0xf9b63011595a96d8,
+                    0x59bfb62f5eeb0396,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9be970b012cddda5,
+                    0x8a771f1e1914921c,
+                    0x7bce8eb8d91f11d6,
+                    0x19a03ed106c9b3c0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x287869bf057d7183,
+                    0x020ef04cf1befd3d,
+                    0x027d0bfe4205a6f0,
+                    0x47a8590fd3882042,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2f29e5c0dcbe975,
+                    0xbfc950c3fcfcd2d6,
+                    0x7a877a74d95c92d9,
+                    0xa2e07af6ff126e02,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 310];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x44dcdef728cb2277,
+                    0xd813590c9c9f0460,
+                    0x5d2e47145600fc81,
+                    0x6a9543c1969e4296,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b105f123f4f01a8,
+                    0xd90504b761911f32,
+                    0x91a9885e714bc4c6,
+                    0x34c46d538d738d22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a7796cbb431bd22,
+                    0xc6f7ce7d586ff1e4,
+                    0xe0f89f8d71561c9a,
+                    0x5ae8780b687cac98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf7a9856fe1c9aa3,
+                    0x722eb073dda1987b,
+                    0x5914ebb2839a399a,
+                    0x0455d1bac870ed19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb1eba44dcfc4409,
+                    0x5c88f61319b2a742,
+                    0x664f882221c23b3e,
+                    0x76e2d67cad881ab3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49dc0e0bc0771929,
+                    0xea20f17a520b9821,
+                    0x7cf21c0d7774cde5,
+                    0xc09bf2990bfa1857,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfae268c4fd7d298f,
+                    0x3a06ea6e67eb1351,
+                    0x75c737e43318151e,
+                    0x3373083a23c9cfcb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x170e80d525a11a07,
+                    0x71f813e9bc771a6c,
+                    0x5dcb4c62f95b1616,
+                    0xf006af9f2a8347e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc80951e9cf89d6a7,
+                    0x6242a3710b784100,
+                    0x8dea37651466610f,
+                    0x0c939e1a80097ef8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7498f6d4457d9220,
+                    0x26f4c4c4ef920d2a,
+                    0xba1b9e469d59d6ea,
+                    0x5a2c8e225da4a473,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8630a89a658dc44d,
+                    0x0bbdf73b77de1725,
+                    0xf2edf491b9aaf9a1,
+                    0x5be00df88fbc41fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77a87f6f8c7820cc,
+                    0x838a7f9a593cea81,
+                    0x997bc24fa63598fa,
+                    0xda95629cb2c3fc47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8803648de2b729d4,
+                    0xe381f56f9b0dedf1,
+                    0x532a678bc9af9128,
+                    0xbd6f2284cdbfcb83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf2c1c9162dbd1ba,
+                    0x7bf193156cdaf312,
+                    0x2e0a85c4aebfe802,
+                    0x1786420ef9fbdf07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb642a3d49ec0a8c9,
+                    0x6a948e369728a719,
+                    0xfcc64644d1c66327,
+                    0xc4eae795c4ffcb84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83f8abc767863d45,
+                    0x3dc1811f3af12a5e,
+                    0xf32a3d542dc19e19,
+                    0x05de8d5b7016aaf0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd25f5290ecdb3edb,
+                    0xa596946d39571a48,
+                    0x931fefaf83308cb4,
+                    0xb37c0b3d7fd9639b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0df3b891d172a5b,
+                    0x0363b4ffca14e192,
+                    0xb05295d1df28458c,
+                    0x4388866f91ce22a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbae6176d311751c7,
+                    0xdbb62d5381a6efb7,
+                    0x84e9a375a2759ed5,
+                    0xed476ca18441f3f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1a73b0e11d7f94c,
+                    0xfc08f671a32c739c,
+                    0x96e5f8d988088425,
+                    0x0f5d57598cecbb40,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa8da866d5928a6a6,
+                    0x98b75a4075ec6d48,
+                    0xa0d60f6809534fa1,
+                    0x8231a6fc17df4acf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4507afbb105e3c59,
+                    0x7474e460d3d00ab0,
+                    0x85bb927abb4ddfda,
+                    0x73f540abc773da68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3d9995938483df8,
+                    0xc7cf5c9a19159117,
+                    0x00f9fb49d9180f69,
+                    0xf71d86cb9892df10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefc027435c9d412b,
+                    0xf43ca678e5390c27,
+                    0xa5dd53e8b5635a2a,
+                    0xfd8e18c65a1697fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x676a0d275eeb7679,
+                    0xda57a0aba74f50ad,
+                    0xfefb8b30ccaad48b,
+                    0xfae77a38e544cbb1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1360eefdd2fbed4c,
+                    0x6b5773cb91e6b0ca,
+                    0x28e5b43129f88615,
+                    0x5b6efdcf29b2852e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0f711d4e53e41d7,
+                    0xdc6c0568fbaa7993,
+                    0x5ba6296b1e4501e9,
+                    0xb3321c468138a5e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9364cc5ff4994784,
+                    0x7dc2688c91dcc51f,
+                    0x64835b3e80454b7f,
+                    0xd3abc88f46118615,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19566513fc9fa27d,
+                    0x06a28f3fb15678f1,
+                    0x847ea115e38f311a,
+                    0x6b8bc5b33ab6ba84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1f95faea6d6365d,
+                    0xcdb0c468e8ceedba,
+                    0x8826d25cf597733c,
+                    0x3bc69f5024b1c69b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x984958c96d755e19,
+                    0xcfff936a8a426d76,
+                    0xae4a20abc8dbd19e,
+                    0x3f9dc60675935c19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x773486b487f29280,
+                    0x82e9c9bf06a6e79b,
+                    0x5f927158fb812d9a,
+                    0x69ab39a1fc1dde17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a0d934b6d9ee1ec,
+                    0x25692a20aa60aded,
+                    0xf35a806b02dbb9d8,
+                    0x6d7dfbd543a86177,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf354a4d88ef2fcb8,
+                    0x498740e599bac6ec,
+                    0x13337fd62f26c9a6,
+                    0xc377283d65159784,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c368522f15ea32a,
+                    0x0784246016d5087a,
+                    0xd05411701fec9f60,
+                    0x2ab4b9ff8d671d45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe5ba25b3723835c,
+                    0x44852749da557027,
+                    0x30b6e5b3992843d8,
+                    0x46df4e9253c4cbb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94fd97f637346603,
+                    0x7875c1a8efac6540,
+                    0xa54715d167de4572,
+                    0xcc736e6014e0f0f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae8b9d32566225e8,
+                    0x24967d8618217bb7,
+                    0x558968950d86a698,
+                    0x74d988105eee5c08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x258b800e996e23db,
+                    0xf0e2c8e05b8dd3cd,
+                    0x5beb3993b5fe1084,
+                    0xba0d3b0c9c2564ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf751555bca3dab32,
+                    0x499d5fae356dd04d,
+                    0x39424220e936d603,
+                    0xa35e387770d13493,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6799a719a5ba475d,
+                    0xb5c5d1277b8e26b2,
+                    0x2a2f896e9bea3380,
+                    0xdcad337a3c56b70b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x980bf4a204ebe808,
+                    0xc389ee6ae027e273,
+                    0x6c77a37d7a59f013,
+                    0x706cf30e8b1f75a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c2bb79ae1d5e360,
+                    0xb3f47bbfeb3a034d,
+                    0x761894a6da4bf62a,
+                    0x1f7fa2698c7d659c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7535e5b2fd8f12f4,
+                    0x28408ebf1e447457,
+                    0x2b65121ed73be0f3,
+                    0x28704fed99b43133,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec781fa4336bedf9,
+                    0x035b839e6bd43c37,
+                    0xa21cd752d61bea4e,
+                    0x5986e6d73a750917,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92c8dfa2ba7f209f,
+                    0xc05d2fc12be0174c,
+                    0x35480be909398a37,
+                    0xdd6e55ac8eeb2c4b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc51f1223a8a30150,
+                    0xc71d379d00bbcfd9,
+                    0x5985fcdd10b97f81,
+                    0x5a66f9b902e1d242,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e05b5d27a0bf5d3,
+                    0xbcab648f69628830,
+                    0x52a0031834d73fc4,
+                    0x857c9492f14df8d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8eec2ff1252e623,
+                    0xfc10c131ee3602d9,
+                    0x175b9c1f7c16f70f,
+                    0xe2abaa003d8edd79,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97b1ac0a516994b2,
+                    0x977ee5e625acb0d0,
+                    0x96f6240e1e886c48,
+                    0x07f0922616832046,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x73d30a84929d9aa0,
+                    0x9c1201c78ad909d0,
+                    0xbafa37ecd0990b58,
+                    0x0f3d56de243f6b56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83cb38a4eb0c0be3,
+                    0xe51cd1fe427b6304,
+                    0x44d85830e8eb443e,
+                    0x31717f0f75e37602,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2eb39b5b55b39134,
+                    0x7b7e6f17b710c780,
+                    0xc089695dd8113ef6,
+                    0x6346483f339c60f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3291be1f4142d9a,
+                    0x23bf9e338954f369,
+                    0xed09c62ee31be9fb,
+                    0x58ab995c232c959e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83d970f971ecd1e9,
+                    0x1daac01381fb5a47,
+                    0x0df756a16fde008f,
+                    0x30f27eb5544908a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x881e0bf75843c682,
+                    0xc9dbfcaefacb1db2,
+                    0x7c37ae32c23a2b63,
+                    0xbe25be886531069f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ac58c6d4b15e90c,
+                    0xf95d7be40778069a,
+                    0xd03d937ad28cc686,
+                    0xa28577b946bce477,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a52a2d9c49631d0,
+                    0x038e8ef979c152cf,
+                    0x1e5da6a2ffeecebd,
+                    0x03d613ceab0a090b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59c2f8f67528dc66,
+                    0x6c0dce00c742afd3,
+                    0xb63bfb4ebe18e434,
+                    0xebf6e2f8b4030246,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb79135ac8729f8cb,
+                    0xa2bb207253879db3,
+                    0x5c541acede5316f2,
+                    0xaf17ccc32a3f1f1a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xd3634f47f616f01e,
+                    0x2720c60e78df55c1,
+                    0xa4c471293943bfa0,
+                    0x7634285a9a3bb71e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a709f0c20fbe6cd,
+                    0xd2349d3aa3e074cf,
+                    0x033d2e7d34eacd35,
+                    0xa6e04e81ef5b774e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26bcc0e85e5d3756,
+                    0x89c4ce02cb3655b3,
+                    0x797f346eeeeadfd5,
+                    0x3530a103068f7f92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1676e41134b47905,
+                    0x48e2e7eba58ff5e6,
+                    0x4d810ee920942065,
+                    0x90f1e1bb8a97e17e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x166de9df7ca0517d,
+                    0x90abe91ba6ad1ebc,
+                    0xe075419f9aba7799,
+                    0x10f8a84542ffd4f0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc38a02c150f919c8,
+                    0x1fd7fd4f4906f9bf,
+                    0x7dcf376f071d7728,
+                    0xf08dd47ceef70b16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37fcc80222bed7f9,
+                    0xce88819c2ee6b31d,
+                    0x8a499de62fddf0a4,
+                    0x79b422edff8dbed3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69764044fa874bed,
+                    0x9f258c7dd6dcf79c,
+                    0x6d448877e14c65d0,
+                    0x16fb83bb59570ab3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x340e41b4c30be87f,
+                    0xe828e9dda94390c2,
+                    0x2bb1f7eaf1b2ef6d,
+                    0xb7a92d633a1fd5fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c7e00563f304f50,
+                    0xfd46e713c39677a7,
+                    0x8314c5c08a28b3c9,
+                    0x5f9f815ca7941084,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x83d947fc8e6be458,
+                    0xab8b0a01a2b14722,
+                    0xc234de76d6884b4a,
+                    0xae0f00aa3cff2c7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc55e90ae4a0ced99,
+                    0xe691368c4c9d3c6c,
+                    0xe3d06bc528735889,
+                    0x5a0255a43dcccc32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a1049ec58dd0d2a,
+                    0xe9c8f39dc56da40e,
+                    0xea10d1505721626f,
+                    0xe95948da65e97861,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c4e5a8c2a417e32,
+                    0x7c71f65bb6b54569,
+                    0x8b06188df5b566db,
+                    0x1d2f1b6b586dfe0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a68455ac9008279,
+                    0x72c69b0c2188f1ff,
+                    0xbb3981b2d097edf0,
+                    0xa81a2110eb3b55ba,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaff0c4a415d8f4ff,
+                    0x10b260bf06b1705e,
+                    0xe3f36cf3b16e7ad2,
+                    0x1b8da713eff4dd7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x756c48207f17b79b,
+                    0xe21597f938ac5791,
+                    0x80ff650d5f791ddc,
+                    0x0ff3428d19d6b372,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94bf0230fbd00b14,
+                    0x8d23b5d623bc53df,
+                    0xc4403a7a034b6138,
+                    0xcb9df1511ad83bcd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xadb5431993ab400f,
+                    0x880c5ba453067218,
+                    0x2a9203a78ff19783,
+                    0x8781f7b8698afe14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2fe794d944316169,
+                    0x51bcab2cf1c9f57f,
+                    0xab9f30c12a913cef,
+                    0x27198aae5153298d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x665922d460e44f12,
+                    0x2bbddea507ed62a7,
+                    0xdacb36a3d62333bd,
+                    0xa0c955ddbfcda011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f1558b3446046a8,
+                    0xde88d025e345830d,
+                    0x69c7768816a2e0a5,
+                    0xfcc69d197d877230,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe09716ea90197d42,
+                    0xcd18eba4481646d9,
+                    0x69819861bd7871ff,
+                    0x1bf7bfb3f822709a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7bbd2e72e0b7dbc6,
+                    0xf0bd8b4e2c96a328,
+                    0xb65772b45ec5a65d,
+                    0xa966029789e1de27,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cbeb4fd71b50928,
+                    0x69983db06372160b,
+                    0xfd565b6b603b68c0,
+                    0x74ebc1caa2340961,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x04b470ef98add120,
+                    0xe4001aa349dac278,
+                    0xb628ebe3882a6cac,
+                    0x3294c7dce103547e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb24f627e3f30cfe5,
+                    0x05a252a902eb8779,
+                    0x8d0727716ff632f0,
+                    0x6c24ad06c2e11ffa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b4da8552a37aaf8,
+                    0x5d2d687cbb02aef6,
+                    0x5578bbcfc2325ad5,
+                    0xa780e95a274ab5a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x096c4e4f5d3e2a30,
+                    0x515797e35110e3f0,
+                    0x2e850e4aa5c592bc,
+                    0xcd6b845f296ea2d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31c985a3ef3ba93d,
+                    0xd8292418a134c586,
+                    0x89bb23374bd64c3f,
+                    0x8caff840aa114305,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0161c5b39fb46d9d,
+                    0x7486b36b5f5a9923,
+                    0x1446d6a82f96063a,
+                    0x0797717adb2acafa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x923dc363783118d5,
+                    0x626c753da58d3498,
+                    0x68cef0638d612fe2,
+                    0x658c2ec83c483f9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68ae6376b141d230,
+                    0x06d9fab2f3067749,
+                    0xd32da482edb90a27,
+                    0x57861fab202bd8da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f1754100f267211,
+                    0xf982ff6ea7ab55a5,
+                    0x6183deaf1624f31a,
+                    0x37a8ce6f430761a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44ebff3ad389ccf1,
+                    0xe9934cf2e57d6d0f,
+                    0x0bd20a4db45472c3,
+                    0xcbe51a1af951e979,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x336e6046096df0e3,
+                    0x4452d2acfbda86ca,
+                    0x9a8e4829ad527cf7,
+                    0x7b38422be6a7be09,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b883b50c41e1a5a,
+                    0x3651b825fa66a09f,
+                    0x9a37047df46a8fb7,
+                    0x530f26d59814abeb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0fb1b9ca4707eea,
+                    0xd723924c2a08d586,
+                    0x1bbf3d57830a9ada,
+                    0x6f43df3db3b79353,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf38d7c2569ee834a,
+                    0xa7724ed41daf76d5,
+                    0xbe0dd95dce201441,
+                    0xef41d34a02aeb96c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce1ca47c010ccd91,
+                    0xd6f44afd02237cdb,
+                    0x8e7abe9c84a0971e,
+                    0xcd355bdefadd798a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x99f4dabb493713dc,
+                    0x9148a45830add776,
+                    0x0e37fae403e0f4f9,
+                    0x05a6d0b3f5ce2341,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79f7a5be97484520,
+                    0x2c8febe71cc3af24,
+                    0x73c51e8786207a0c,
+                    0xe12dde2b01ae3c3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0c68943e3c4a84e,
+                    0xdd11b4c32442244f,
+                    0xcc39f20ba91cc6a6,
+                    0xe7f57d5c2634a7a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb39d280f3936d66,
+                    0xab4df2753e35f2e9,
+                    0x198ee9afa3c29a47,
+                    0xfe7bd076ea80010b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f9ac614c23b5334,
+                    0x3595a003219e3912,
+                    0x39848df44d414953,
+                    0xdf79ebe6b5363388,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x84f2801a9480af3e,
+                    0xf762073344eb0db7,
+                    0x456fa115189b7096,
+                    0xeea42811a548fefa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9bda81b9c045c2e,
+                    0x88180ec44c769631,
+                    0x94366c38fa04a288,
+                    0xed95a99405144dfc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6dbb30c0e8c9b7d7,
+                    0x7a7ea8cd4317e0b8,
+                    0x7697b75c0f718df0,
+                    0x75e6d77172f02ae6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e6d5c8454d0ca8b,
+                    0x5d98815eb35136f2,
+                    0x3ca1520b052123d3,
+                    0xb0a56d5f3efc3d4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01df39b38eb869d9,
+                    0x6182e909de88b09a,
+                    0xf8afd1800ef6f039,
+                    0xd8ccaf3560bbedef,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xfe311fa1cc7b4b0d,
+                    0x6898b4691f697f1a,
+                    0xa37e6e6f539e0c18,
+                    0x2accff575f38b221,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ec28df4692de3fd,
+                    0x2bf89bb645168f91,
+                    0xf73f48483a3cc17e,
+                    0x2d4d1bc52ff754fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb4a74ad5a56834f,
+                    0x2c93c2cb616e7eb0,
+                    0x0f3160e641ff2b5b,
+                    0xcafd80b314ffd4f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb10b2a22c541a8cb,
+                    0xc4f8db9db3d0a376,
+                    0x3dafce3bbee8c7fe,
+                    0x40ba7007b5a4fd6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf33b7a784a5b86f0,
+                    0x3b9c412b47ab7d71,
+                    0x0fe1b46a3494f05f,
+                    0x4c0137f9955ed8b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x209c457e121e9e86,
+                    0xd5fe21adf7ef500a,
+                    0x1b284aea16ecc99b,
+                    0xd24f792721dcbe38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95b4c7e9691d6e2c,
+                    0xcc908c411eb7d9a9,
+                    0x95f7b72b90f3049c,
+                    0x54ad5f727afde4a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf115ca35ca313b62,
+                    0x5424a13b287cf55b,
+                    0x9022fb834c148226,
+                    0x8bd77bdf9605950a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdac3e8ad0b82f118,
+                    0x036f3d68498b5db1,
+                    0xd71f61ec516c04c6,
+                    0xe9d696de9a73896d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x729e255f3458a399,
+                    0xcc782ab4eb77a9e5,
+                    0x32cae23e19fae112,
+                    0x9efb1da174759334,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x961aa33819645eb2,
+                    0x647536b58ecf8795,
+                    0xd9e3ea85c78e9cbf,
+                    0x843a050380f1be96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50e7854ad53cb5fd,
+                    0xfcd995628629c181,
+                    0xee1f99cbb895eeb8,
+                    0xf5aea1d03a102e94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2900890057ad005,
+                    0x7a2ea3a1bda0ad3a,
+                    0x1120e4229c2b9b74,
+                    0xaa175d362f70a2cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf00157b0cf806d65,
+                    0x313d0996f5213c5a,
+                    0x31f13fa9d59bcff0,
+                    0x050470f06ed55aaf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36f85c768c421965,
+                    0x70d3f33ef6e41795,
+                    0x81839f692d3914cf,
+                    0x3b2815c7572e19da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b85e7b6041788f5,
+                    0xf3c88c399d993c71,
+                    0x63a05df134c9e246,
+                    0x475ab127bd59205d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x583946edfa5554db,
+                    0x30dc65a223e55f70,
+                    0x67c9dfb98a5d21ab,
+                    0x2fe568286b32c187,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2176187ec2b08c51,
+                    0x7e8cfe558bdff699,
+                    0xed2d10bd2b95e19a,
+                    0x7b017acb053e7d5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x588a6c9a52048676,
+                    0x0722aed47f6a6d6d,
+                    0x30198f886f6f68a0,
+                    0xdbd2ee3244f0859a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e936403592cb0c5,
+                    0x05d8d667c00954e2,
+                    0x1371f9ffc58cbd90,
+                    0x4753401181d29218,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4cb102dc48457b42,
+                    0x10b1cb0a05014107,
+                    0x70d88ddf305c3227,
+                    0x47d89c47d5e92eb1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1329099491911fe4,
+                    0xf300015e3afe0ebc,
+                    0xf6d3d8c547559568,
+                    0x4af16be8f9e5eb7f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b50f6f4e915484d,
+                    0x2d33ad9386fdf351,
+                    0x466de1fa91e3464e,
+                    0xdd618def2f706749,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefbfdd17aefa7ff8,
+                    0x3d06fc4cd36198b9,
+                    0xdd7c803f46a61b69,
+                    0xd824dcf73b091679,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6547f5f9f5838679,
+                    0xba070edb8b03a1a7,
+                    0x4ac3f60100aa6d45,
+                    0x335c141e46a81e2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58dcd69359322942,
+                    0x1932c2fedd1a8521,
+                    0x443fd9e928477915,
+                    0x74bdd049ecac86f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd274d14a6a961ad7,
+                    0x91fb3999ebb30183,
+                    0x8ade8ce15734af05,
+                    0x6168374470c8a245,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1137ae416bac8b1d,
+                    0x58409edf06fc6528,
+                    0x7cff7da174b741f3,
+                    0xed40a4133068af29,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2c9b247d67d4ff5,
+                    0x7c1c13a4b89a3df0,
+                    0x3e75b3c3979db153,
+                    0x81d39baf64934cff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbad7e17a9a541b15,
+                    0x51e35616648d2048,
+                    0xfe411ed4cafa0666,
+                    0x51102aa22d41c9c8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x03bd230e473f0dfc,
+                    0xd8af4f3e42c5ad9e,
+                    0xb10763058580f71b,
+                    0x577b1f737603c2cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7efe2b67000f5e2,
+                    0xf5b86320f7bba54d,
+                    0x04985f0b26da5fed,
+                    0xf7ebe470cfeadd95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ce54931d2cccc2a,
+                    0x627b62a00a3f4992,
+                    0x54d016837983b5e6,
+                    0x27fbf978801ebb5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d3081c95acf70f4,
+                    0x49f9e36cde7342e7,
+                    0x4302a0804936061a,
+                    0xb3f3d1eb096e9e78,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x185d70ef15ebac0b,
+                    0x629fe0c1422245a8,
+                    0x7d9a270c8258c75c,
+                    0x0fb05ed6608886d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfdaba691ea62cb5a,
+                    0x9ddd38463324f239,
+                    0x92bc70f7aedd8be5,
+                    0x78a8156095221ad4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0afc386e5d8a9684,
+                    0x01c0105823e37bd6,
+                    0xfcd4418d084ac447,
+                    0x265d3053507144c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c279294571f3086,
+                    0x8884a681f1b18fb2,
+                    0x3aeedb489b917f6b,
+                    0xbaa0c473367e6b59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x885f955d7379f7cf,
+                    0xcec1368648e9acd5,
+                    0x456b7c6f8754c684,
+                    0xed026b471b30e2ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x499d26747d7ec412,
+                    0x2341442190ca4bc7,
+                    0xea9fdd8b67d963c1,
+                    0xab53316db2013751,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xddccc0ed90242be2,
+                    0xc24afc065a4eff06,
+                    0x413b3a5b2ad8d9f9,
+                    0x34909f290fc6c9ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b74e2b48afce6f7,
+                    0x5929582e17396bb9,
+                    0x0c15f6a344d6bb99,
+                    0x07a3196b8f55cc8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x241c1e05518fccbc,
+                    0x9f6c62a44f053b89,
+                    0x943ac60b2ece6357,
+                    0xff9ad230b5163882,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2319fd8d7f32086b,
+                    0xe994386889d998d3,
+                    0x90083cd01e95745f,
+                    0xacabe41a624d7931,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25c94918cf904333,
+                    0xdcce50fcb21eeb27,
+                    0x89e0e70e73bb6f4f,
+                    0xf457d2c0f39b15ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x86c1c9f67f350ba7,
+                    0xc7ffe66adf08208f,
+                    0x32ccd7476cabafcc,
+                    0xa19f72fff32ad344,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b97ca7234a9f0fa,
+                    0x3d3af1b91c52d360,
+                    0x0cc500587f2e580c,
+                    0xa0c9f0309835e8cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66077b93fdebc485,
+                    0x696408006d08c60d,
+                    0xc89546db2c606964,
+                    0x2f46350c88457645,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90a98578f907def8,
+                    0xae00a2ac96cc3a5d,
+                    0xd0ef396127d8d955,
+                    0xe5aa9993492cf250,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61715102023409f1,
+                    0x673459d51368ed0d,
+                    0x84b1c0a0fe473c34,
+                    0xb88c50964d4be5aa,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdf2a61491887039f,
+                    0x95fca9a97f4d8c9b,
+                    0x036816a34d9f169c,
+                    0xd4b580502da6e6c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4053e737c31d4a75,
+                    0x62976d043c2e889f,
+                    0xc2f7a50b1faf51bf,
+                    0x2a42ce9d2c9c72ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe1cac3c972bafa9,
+                    0x395e8c6247221091,
+                    0xa1dfd4544079c31d,
+                    0xc1c88cf1a023da38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07a3de991c628fb6,
+                    0x558d3a02105e6a07,
+                    0x313db6edfb240c13,
+                    0x6dcc5a83fe7c1f6c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfca09451438c7577,
+                    0xb15a678d5788634e,
+                    0xe7286f1c0a240997,
+                    0x0f99a6bd5aefad58,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95bf1a42d247592c,
+                    0x3946f94aaa61a85a,
+                    0xea553ddc8d028f8f,
+                    0x807c4d909bd68403,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x13fde62f5bb787fd,
+                    0x3e7f5870988396af,
+                    0xf4fcc489d0e06009,
+                    0x396d6860c7fab607,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa150aca4e71154a1,
+                    0xfbf6cba4228f87ee,
+                    0xac2a32791433a16f,
+                    0x4aa4336f36a14833,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0805ac016837526b,
+                    0x79b4c4b2658eb08f,
+                    0xb7c8254925b95cc3,
+                    0x40a9e06732d58b75,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x067a0a3fd01fa21d,
+                    0x6276833252a50367,
+                    0x453b0907907bbc2d,
+                    0x53ab219920685af5,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x41757d2e26c4dc94,
+                0x5ca90253343334e6,
+                0x4f77249e186804aa,
+                0xf42fa1e05dbe646e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa5894d15707b7d1f,
+                0x72f5dc5abba4ace2,
+                0x24241b78942370d5,
+                0x988629e6298fbb20,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x49fec2802497e768,
+                0x6b1dc5938cfa5f06,
+                0xd655b5cd1c6a5b10,
+                0xb6b3dba5245ec4d7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x97b8e6e9689d5cf7,
+                0x59b4b55fa7ea8367,
+                0xff6440e3ceb5ce5a,
+                0xa5ba5d58df81f45e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb2bd32f838426cf9,
+                0xfb28a31ab2f0007d,
+                0x007802cc9fc706c3,
+                0x15620d48651585f6,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc14a159c4870df5c,
+                0x292bd4ec39f75d6f,
+                0x3883dadc57a3835e,
+                0x426dc6b369762da9,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe190842a231373b9,
+                0x168a9e17879f6ab3,
+                0x015b865be9b3ea6b,
+                0x0a685b67b09ee9cf,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9228b48d6bef7059,
+                0x13e9eda9d533794f,
+                0x67c658536aa2632f,
+                0x00cacbd461c5bf3c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x709be7d21eae0992,
+                0x2134ae80594fc8e0,
+                0xfac1dd7458af4c27,
+                0xe8b8b07351512dd1,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x6b708cb67bfc9dbc,
+                0xea3ed8f5566bf56a,
+                0x23960c97adb6e463,
+                0x6c7a6c4d70f29ece,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 248, 249, 749] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 248];
+        let mut opad = vec![0x5cu8; 248];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,2480 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 320 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 8 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 8;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 14 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 14;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb9eaa6600d8ec954,
+                    0x5883e91d7d926cbd,
+                    0x8b442e909fceadb1,
+                    0xe2766d3361e2e445,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf02fd4943de46205,
+                    0x4aa4f1f87942efaf,
+                    0x744099af79403e61,
+                    0x36b4fd6290716743,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd65f09b9d5cadf1,
+                    0xbf95cf4bba153bf0,
+                    0x1a86f3e02226386e,
+                    0x0f424056d3c6459d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc77431f3d2d31685,
+                    0x98e2d26378f31a04,
+                    0xc4c69cf945c334af,
+                    0xa3b734a8c9a367e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10f461959af94f6e,
+                    0xe3ada74e26097d19,
+                    0xb4fafa2ea7705c4e,
+                    0x839a1d5dc88cc26e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x527544426c2a4cd0,
+                    0xc4b7dd9a5b452fc0,
+                    0xda9a6353a66ee80f,
+                    0xc5e1b861ee763498,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d28bd21f82b2642,
+                    0x8fd25fba16f4519e,
+                    0xa5e4bcaebc048a04,
+                    0x11460f496cd11886,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5240310e1b66168,
+                    0x8bba9ebcfbfecbb8,
+                    0x71b754fbc46bb1b4,
+                    0x73135cdf12fa6820,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7881e159e0ba3cfa,
+                    0x4a1ce1a3da5dce9a,
+                    0x01142e903e2819ed,
+                    0x9895438291ec2ac4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27ad0fd974837fc0,
+                    0x9049653d099ed128,
+                    0x242a707ff0bf863d,
+                    0xc3645363e2445992,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf09aa4141e86148d,
+                    0x5258f7888a5f14f9,
+                    0xeef4aacd228f3a16,
+                    0x1663e0ab4e8ca318,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5487e83eff17bd7,
+                    0x6ff7baeab06f9f16,
+                    0x4fe3aa6f821f1cd1,
+                    0xa4aa0cbf45744cdc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4062d40746a892c8,
+                    0x38b2ce65954254c9,
+                    0x4e10a21145745df3,
+                    0x7f957f99bdc7ec22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe7e8586b7c3a5f8,
+                    0xa1ce9a58b93c3be2,
+                    0x428bfa960681e5dc,
+                    0x6d93b03c154af629,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x014680c888d8aeaf,
+                    0xbf63d3b0edbb1765,
+                    0xe277edd0e866af10,
+                    0xac7e5a9946a4e25a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc68e2ef22ac131b0,
+                    0x6ff7ddfa0b84b903,
+                    0xce00439edb469524,
+                    0x305d162cbc2bcd2b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55e1625ee7f6ce4a,
+                    0x6c88576e7e5b3cbe,
+                    0x1edfb30a5cd16a63,
+                    0xe915c5a16579e654,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55c5cd529ba49a9e,
+                    0xd86ad5b57fbfdb27,
+                    0xddf107ece3d660af,
+                    0xfc1a13f668d31a4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5b1b4431ed4ed10,
+                    0x32cc5232dd6d8874,
+                    0xa95772a96aea517b,
+                    0x85bd40819cdf23d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc174e7b7cf089ce8,
+                    0x1e5587ff35aadbf8,
+                    0x1ec7cb8251420a40,
+                    0x72458317fae3ef1d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x165b96768aa15d53,
+                    0x3fff24e13415ba70,
+                    0xfb19b8e599c6cf62,
+                    0xb07fcb749323ab31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72d6a81655d30ecf,
+                    0x2a60a9ff45e00955,
+                    0x8c694e0c02d4c539,
+                    0xe81b9ea770c36021,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x286843b058d22eb1,
+                    0x73ccba5e792e94ef,
+                    0x5f0210a254f29250,
+                    0x7c6d5b3a50f6c408,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d9848777c274e33,
+                    0x8f77d0766d5899bb,
+                    0xbcc51afcdac6d157,
+                    0xf85bffacecb5209b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x882754a3f49315ad,
+                    0x383e9b754cccbc56,
+                    0x019e1fdbc180d6fb,
+                    0xa5934a447c09e936,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15d991f34e9ac109,
+                    0x6907e5454d0cbbac,
+                    0x5bdd0f01edcfae16,
+                    0x8749e4c29d156ebd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e86161667fdb820,
+                    0x5514fdcbf08cdba7,
+                    0x25e428c2f6c52011,
+                    0x26b72c373081cbb8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea53c35c338f564b,
+                    0x11fbf168be3cea41,
+                    0xd43029b758d790ff,
+                    0x1c3fedc8c3b933b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14b34aa68f31d2d9,
+                    0x00c8246c1ecc9ffe,
+                    0x184e7ce2cf6552f7,
+                    0x434a55a7c7e0b0b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x981fa3dc1819e4a4,
+                    0xdd1a482d946dde23,
+                    0x0a64e71694557337,
+                    0x936d3a70e092704f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x41d48b07c4fee58c,
+                    0xa3465cd66515b4d5,
+                    0x34145eaeb981af21,
+                    0x1c6ff568f557283f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97db00599481a45a,
+                    0x6390b3b3ce468d95,
+                    0x918352999db1facc,
+                    0x66cf4e47f76e4c4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9755b9801721443,
+                    0xb310b07a04d32999,
+                    0xecd8aed7aab832b7,
+                    0x0b9d885bfa77b764,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65c7894056cced7c,
+                    0x0b4d4f938888a830,
+                    0xe9465dba28e5362c,
+                    0x1a403fef21483cb1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68a73b97c728f621,
+                    0xc54a0c1ff3047f35,
+                    0xdd9089a4ae264325,
+                    0x2d3dc5260d18ce22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f3421f69eb01d5c,
+                    0xf3536651fa90a871,
+                    0xed72fdc6457609b6,
+                    0xc121ccf2e5cc5dac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4e28e39889e1689,
+                    0x2a16d9a4fc063ec4,
+                    0x4d68fd23d601f2a8,
+                    0xcfb31db174c9f647,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x754a09c92d1a51bd,
+                    0xd85cbdbb7a4ac66c,
+                    0x788f1116ed3115da,
+                    0x83c9fe6e086e8b23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c3fbf0628fb46b1,
+                    0xf1fdbbce5fecbfe1,
+                    0x56acead989b491ee,
+                    0x6d8bbe4723bee7d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d2586feec95919f,
+                    0x4674d78d3869607f,
+                    0xbf304b3814bf0f98,
+                    0xe6a3c90c28579fed,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcfff2ea001cd27c0,
+                    0x8a66ff9282a08c11,
+                    0xc77584c400978b40,
+                    0xec519c688a274243,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4821561959610d9,
+                    0xf8efcaf9adeb9f22,
+                    0xb2bd54b4ffafdfe1,
+                    0xaa7272028ba8f8ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6141995da5e8e64b,
+                    0x61ef91af480d1fd3,
+                    0xa3654a74e8bbcfb0,
+                    0x478de47bcae615ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38ecfd8196e075d8,
+                    0x5484652396f1363e,
+                    0xaed44191af4c7502,
+                    0x886f2bb6742ccd2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdadb963fea10362d,
+                    0x251294b8012b377d,
+                    0xff17921b00bb8237,
+                    0x34a859a3a2d9525e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf25cc2fc39120316,
+                    0x4e00686931868124,
+                    0x2c514d6fcef54d67,
+                    0x11f154c13ed92828,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e01f7e3fe129e6d,
+                    0xbe1de1e4a0a44041,
+                    0x6ba6d9cb024de191,
+                    0xd4b7cf9479ece7a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2427e91ebda814cb,
+                    0xce49529bf706806e,
+                    0x5e9d9ba15288e829,
+                    0x46f24494f54f2005,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bfd2dd0c1e3241d,
+                    0x8a4cc1fa9b8f505f,
+                    0x446b48d052ca899f,
+                    0x123d162dcb54b891,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0cea8ef3629080a4,
+                    0x1661f5c4f88a759e,
+                    0x739adab3bc0107a6,
+                    0xcfc8085b12a119ca,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1a4075497da82b6a,
+                    0x210527c36c57b714,
+                    0xe4afbb8be71d1ecb,
+                    0x7218023b33b7edbe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcefd73d5ee93dda4,
+                    0xc89265723aaaa95f,
+                    0xe26dca6c8a42c209,
+                    0x3363e59294109902,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x632cd8d6b2e536aa,
+                    0x6222503d75214abd,
+                    0x4938a3d2eaf45d5c,
+                    0x4eb8e41c142963e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8e7accbea09edc0,
+                    0x05ead5b5d1dd541e,
+                    0xdc27ce6b0103c959,
+                    0x9ddcdf3f555c177e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0de6fe715700c145,
+                    0x19798b467cae30b3,
+                    0xc482ddda0856d40b,
+                    0x7d56c315b90898ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d1bb59187e8969b,
+                    0xa4157888791bc0fa,
+                    0xf480339dafacb8fd,
+                    0x8232b0d61195727c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00ec51d80c427256,
+                    0xba8d6781fa20dc9b,
+                    0x00ea540b447389e7,
+                    0xed827ac784afa54b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65970ef773a7f2c2,
+                    0x7aba349ee2f00be6,
+                    0xa239f8bb6f1ce177,
+                    0xce9814740c12f80b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3216ae8d9994253,
+                    0x0d74d79629964fa1,
+                    0x76f8e6026f2eb1ea,
+                    0xc2c45a8176d4012d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1342a24e09090dcb,
+                    0xca926b7ec8df9f02,
+                    0xfd63d10967c1ef8f,
+                    0xca555edcb77443ce,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4f04744b5cf2db3e,
+                    0xcd5600180afb0623,
+                    0xa2e6df0c765cec05,
+                    0xac0844f62557f035,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x370a96a2c3d1f3c6,
+                    0x0ce3c3de0ae4c07a,
+                    0x4cc10635c5661ccd,
+                    0x64327c21d256d7d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb819edf8282efad,
+                    0xdbbb392f2f4db382,
+                    0x515d5fe58ad56803,
+                    0xf60c829b0f720719,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5bb6d8218bdbb9b8,
+                    0x8c49df2c26b25621,
+                    0xe9e010f6b0d1546d,
+                    0x3755091750f58cad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb007b4b55f684378,
+                    0x84ae5f74107c3f0b,
+                    0x76b34646ac343d2e,
+                    0x8fb7f75f8d0c1aa1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1536c2144d7dc828,
+                    0xa84091bd35215f45,
+                    0x4374e0771c9c0a50,
+                    0x742774387b2e17cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e2ef86648f2a02b,
+                    0x4b6f84ff6433a4fa,
+                    0xc802d55969b4d8dc,
+                    0x14d5c39927bebe0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb58924521327c5be,
+                    0x519ee8dfca540a50,
+                    0x036ede07b42c26b2,
+                    0xbc13682d6d3ac059,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefb6599f99f5f92e,
+                    0x3ca4ea2b8b3d61db,
+                    0x4b5090229b9a7012,
+                    0x042fdacc9f3079ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6559eb6fc4fb4b2,
+                    0x83c8e0c7ea2e0ef7,
+                    0x4c5ed4d4bb6a6e1e,
+                    0xda02abb443423948,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf125a0ac3a2054fd,
+                    0xe17e5220367e5865,
+                    0x4a391856737d722e,
+                    0x2c516fd091796d90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd203fdbdf20c8588,
+                    0x3046d0f6a9676b38,
+                    0x6ca107897422ea36,
+                    0x86ac8a61a972b5a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e602ea7fbe523d9,
+                    0xd32c080c53a3c728,
+                    0xe147233765c59460,
+                    0xdd22f4fc1f9a767f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b6148936ee8c349,
+                    0xb539ddcbea563773,
+                    0x6c3065f2a24e8022,
+                    0xd9d8ab9eceef83da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fcccdc5ecc4c0bb,
+                    0x582a4e05a93e211d,
+                    0xce29ab8f0ca4bd48,
+                    0xa9d222766fd29230,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x718e0ff18f1a574e,
+                    0xbe38373cfd973ec4,
+                    0x435942d9390af27f,
+                    0x58cdb7db1648eb07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ffe6a866a2765f8,
+                    0x6127e50d4b104e77,
+                    0x0fe57fe845e18012,
+                    0x2ef95c21f0af9dd3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa100023a004e3cb1,
+                    0x7bef679629627328,
+                    0xc7e2b425ecf569fb,
+                    0x46d390b81a1096f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb6fa4185839565d,
+                    0xbc7ce685b8a360f5,
+                    0xf19722dd0f6a4b98,
+                    0xde9cfd2d04803469,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9537455eaba3b397,
+                    0xca4f6bf4882a3dca,
+                    0xe3c372b6d943d954,
+                    0x8c50b2ee1cb8de7c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcc84262b2271d8a8,
+                    0x39a626195b5df640,
+                    0x1401778bb01fb8e7,
+                    0xe38b2ff4ccbfa338,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82b9e83c1f0ae0e1,
+                    0x985032fd428394c2,
+                    0x5fbcb22738954afd,
+                    0x03147bc075c08739,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8b013c21ad15283,
+                    0x8d59e867ecb8c6fe,
+                    0xe26d5c648a3c7bc3,
+                    0xb3665e2ccdf6d611,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x225405f809b237cb,
+                    0x63c22fae6e9c9b95,
+                    0x49a30b0c80d35f2a,
+                    0x27a9717b472fe496,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4deac8c866d5a80,
+                    0x34efc24cf92ef846,
+                    0xaa1a5c5ef3dd49a1,
+                    0xea15fc449f5db9b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53844f466d00c003,
+                    0xb3a14e35cfa60fd8,
+                    0xc61720cecd1958c2,
+                    0xb2941e2eea817316,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa00b752e24153559,
+                    0x7ea4cc3a47963ab2,
+                    0x0433aa0f72cafaed,
+                    0xab1c33ce4cc91fb8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd77dadfbb6b5275,
+                    0x645d87fae977c310,
+                    0xd29c3f415efac38f,
+                    0x2574ac3018d02eb5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf652b80f501c95cd,
+                    0x6a75809ef86378ef,
+                    0x57e0a06d11f71dfa,
+                    0x6a31e0ddf85dd46b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2801c32296b32a79,
+                    0xaa59ef16509247e6,
+                    0xa032d6d2941d5e9a,
+                    0x87774b9265f785a5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xff0f419a4624a805,
+                    0x693a6a1cb23f9b69,
+                    0x495b368ad10bd1f9,
+                    0x9080788724fa20ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45c58d8dce9033bf,
+                    0x42e7ff1891832292,
+                    0xec6f6477da440159,
+                    0x0a61c08047a8286f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1be4db4871df38ec,
+                    0xed615d958f4860ff,
+                    0x6f70a760399b80aa,
+                    0xf87e7fb885f347b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe10cbb87b8c08850,
+                    0xa8d6a1041fbb32be,
+                    0xfa41452eca8c09bd,
+                    0x9a0952619bed18d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2cdd71054f03aac7,
+                    0x06c1240eb7a1481c,
+                    0x7d6f81d1b585fcf7,
+                    0xbdbb4a319efc82ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f75148caaee2e57,
+                    0xe02025e96817ee4a,
+                    0x1c5815d6dfdbc5a2,
+                    0xa297bf22061d8840,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e0730cb6e82fe9d,
+                    0xe9f11db50ff1884c,
+                    0x8ea9ef12bce9cc56,
+                    0xba2301f03d162ec2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x578d8d108a23745d,
+                    0x3206034d1e588789,
+                    0x38151c2a243a19b9,
+                    0x34b9e89ddf29ff2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93cc8d356a787886,
+                    0xeeb614f97b9d7f75,
+                    0x73e5382b31177206,
+                    0x9f9f055746abc6ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3d2e3dadfe2479d,
+                    0x197e46362a831fc9,
+                    0x2d50111bd6428dfb,
+                    0xf03aff134a8c6b3b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaebfce62410bc90e,
+                    0x3a1a147f4bdf7c32,
+                    0x03504f43bd8b9914,
+                    0x9f8c7b3911839270,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x635a0c53736c9cba,
+                    0x34b8f2cc3df1187a,
+                    0x5e0d5dc4d3583151,
+                    0xaee67496b4235132,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x779db8cb87e9aa5a,
+                    0xe7382e36e1b2cb57,
+                    0x300a870d68076ca6,
+                    0x0797116970d24c23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5b4966fc5e55474,
+                    0x098a949ee571f696,
+                    0x146b4ea4a4485eb1,
+                    0x9495937fbb1b4298,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7139ea6f0ac0af1b,
+                    0x25fd07ad16696412,
+                    0x8cb4fb6e191f394f,
+                    0xe4732d1fde6914f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54b65fbc967a4d86,
+                    0x2f5481a438587901,
+                    0xcfc8ea2ef4f35315,
+                    0x2d7b682d92e0948f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54c7913a9e2e0d23,
+                    0xe37d149094481d20,
+                    0xe5c084f89aefab9e,
+                    0x66d968b1c9cca485,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94ed80c840e31a89,
+                    0x792788b3da746e6d,
+                    0x5862e862b6792a18,
+                    0xca1af30ba5777a3c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9fbc564b75a0bd8,
+                    0xe7fac24b70bd99f8,
+                    0xba897efc29ea8445,
+                    0x96689b849ef90a14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x093ab61ad120ef36,
+                    0xb584decd2cb78b41,
+                    0x99ca6155fc0903e2,
+                    0xc2e0a631e8bb9474,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6590651e98ebe91b,
+                    0xbdce34280eeca52f,
+                    0xbe1d0d1ecb71f490,
+                    0x4560096c08c5ba6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7a9fae2aa61e361,
+                    0x6924a78e4f8e616e,
+                    0x262a43348179dc08,
+                    0x0e7ce90a6c368c81,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x230b604cacb40fe6,
+                    0x55f51ac083e3fb17,
+                    0x310c1ae93d60b498,
+                    0x39f5f76d7679206d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x693a761b8f6dd9bd,
+                    0xfe1ece39fa528d03,
+                    0x549322dc1f72ef38,
+                    0xf9a2c394c07648c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7413694fcad26366,
+                    0x8138012b8194fb25,
+                    0xafd33a2983d9b1bc,
+                    0x6121553fd137a895,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4a0dc56e762d89b,
+                    0x6256a740c1e81a76,
+                    0x145450afc98f68fd,
+                    0x63bebfee179d203a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x263570642e59eafd,
+                    0x81cd87f5da77fb09,
+                    0x7cd220436935edd1,
+                    0x1ebf88e6093bdf3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x884eb3ac5dbc792a,
+                    0xa998e4fc0c23e8ba,
+                    0x4e53daf1558a7ef8,
+                    0x57887e40a1f0e6f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x026d0375bbfffa18,
+                    0x4b66bc3856cc27d1,
+                    0x0d26f96941b6e1b6,
+                    0x94df7851c9a1713f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52e39a644d3cfa3c,
+                    0xa6156f0cb3c9d5ae,
+                    0x7af260a88454a354,
+                    0x758210ba32fbd747,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x17cd9eb93ed66f96,
+                    0x329619610af68dac,
+                    0xf400450b535acaf6,
+                    0x24c46a6f8179d13c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5aa896c9b45c99b9,
+                    0x070f9ade3b5faebb,
+                    0x8c4365bf80927024,
+                    0x5c3a5c9e33374c4b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab89264effdb05b3,
+                    0x59fb8f0dfe8fdd89,
+                    0xac1b201caf6be977,
+                    0xe0314215266519fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f14d4af8c32f867,
+                    0x67a2694bc1b22f6c,
+                    0x06bfbbbba63a74aa,
+                    0x8e7fc787098ae116,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94440bea2908423f,
+                    0x98f9901fda275756,
+                    0x29d64e631320c62b,
+                    0xa60fc43e6ab1d48e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7460a49b8ed563c4,
+                    0x33cecf48e403ffcb,
+                    0xf43389605d66c7dc,
+                    0x6842f8898babe59e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae8f76e2fa29c482,
+                    0x49472ae67650c5ac,
+                    0x1afb8cacd60df752,
+                    0xc10d0179ba369c8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70a2a8b3519d3086,
+                    0x0817081b511eae86,
+                    0x6f154d28e7265cd6,
+                    0x5bbca1018054a2b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36ac4e7c27cd76f7,
+                    0x46e89e21a6ef7caf,
+                    0x67b77b25cd4f35d7,
+                    0x8f4307f3b6d70eaf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc440a8747402f122,
+                    0x3b5343b075923da4,
+                    0x565447f856801672,
+                    0xf4b8985ea7a45f7d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x24a35919732fb97b,
+                    0xabbbb454bcd6ad93,
+                    0xdcacfa008fdc82ec,
+                    0x5272302e7ee70efb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14636bedfa8e7d00,
+                    0xfb567196ec58116e,
+                    0x3005f5b4a4eff60d,
+                    0xefa2c542b593786a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b5803a8d14ab106,
+                    0x0906084976297367,
+                    0x0215ce1a2a70b901,
+                    0x1f4b4d974ac97c92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ccb376edada7107,
+                    0x4e2eb3afaf6b84cd,
+                    0x31e31b4c20ce28de,
+                    0x286e1e5d03975a2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x999063489f23a0c2,
+                    0xa3b024117944936a,
+                    0x5a451b1b239fb7c3,
+                    0x1e1b51b7420dafbe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26cc5482f0b13975,
+                    0x56e34af313e6d328,
+                    0x0970f99cf92463eb,
+                    0x35bff520083a0f48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c9313038127e3e7,
+                    0x63e7c44c9728afbc,
+                    0xf670c5754aa89e2a,
+                    0x77424b918414084f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f5e0496b462b366,
+                    0x981892f567767074,
+                    0x093001cad1506b29,
+                    0xc1d2028fc205b45e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2123a59b0c914af2,
+                    0x7db71955ab9f2ca1,
+                    0x60d9d6bb2d4f8bf7,
+                    0x31cd432d49b65db2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62e6601e24157662,
+                    0x7448161fe2a0a590,
+                    0x20c3427473e7cbad,
+                    0x3bfc1879ca38127e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8ec10d38357db9e2,
+                    0x415a3974030c4219,
+                    0x254a6eb78c7b1249,
+                    0x644ec8182bc7b826,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bfa0747a435ad00,
+                    0x1866cbc6a7ed16dc,
+                    0x2b3cc7b7577770ca,
+                    0x8fd2070e7e8f60d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72174d414239cc5d,
+                    0x6aada17192ff35e4,
+                    0xdeb207bf28228e11,
+                    0x54e042feee3c456f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1526a7e269d3e77a,
+                    0x7b5b42126ad7ee4b,
+                    0x5fe3d575971fece6,
+                    0x6d2aeb0d703a6247,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a4281f03308ce91,
+                    0xa88f3c14f1dbe36e,
+                    0xeb47a65f86e94ebe,
+                    0xce1d395ca3b76d24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71bbc6b607421ddc,
+                    0x3207836667430b8a,
+                    0x80b47964d01dedc3,
+                    0xb12d51c9c77e93f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xade24ccbc15e3420,
+                    0xbaee7298ea66b396,
+                    0xf5f2dbd1f03dd39e,
+                    0xac5c23ad043e8002,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb16c9a0d3c78a81,
+                    0x14403cee7528cb28,
+                    0xfbf9ae710a655185,
+                    0x32790f61cdfcb2fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fd4cca78f639ec9,
+                    0xa9aee852f958a2cf,
+                    0x4084e931d7f69684,
+                    0xb940d9c51630c76d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73e0037e0ec71963,
+                    0x6599d39f3bf2aeb8,
+                    0x04b613785eb51dd4,
+                    0xecf3b937d0132049,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x30523b6b07d4b652,
+                    0x89732e4e26c4b380,
+                    0xbbafec8d28837ba4,
+                    0x2c1bd9c00d9b06c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba634e44f2bbd658,
+                    0xff16d4d8d5e2063b,
+                    0x5bfce876e3ad9888,
+                    0x43e901e312d553a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e3ddd8e2ccd42a8,
+                    0x6e2c1888b97d4a77,
+                    0x19ece47782d821a5,
+                    0x2cdae7cb6375dcba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf565f0a13eed6c52,
+                    0xf1d9083a0117e36e,
+                    0x169bf77aed832c6d,
+                    0xd419675090471053,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde48d56ff39a396e,
+                    0x1bfc0b35a974ec7e,
+                    0xa3f836618825af9f,
+                    0x78433310b3947ce9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f56f49881587caa,
+                    0xc127ab06164110b5,
+                    0xe181a8e66dda7a57,
+                    0xfa1bc3f7ac899f31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31f79ae783c34fb3,
+                    0x27b5ae839af2a143,
+                    0x44e8ffb1f09d799d,
+                    0x835a5ff50b844399,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d087461ee55016e,
+                    0xaa04aa80c044dd33,
+                    0x74b274cc5a7d97c5,
+                    0x56ee571dc7e582c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c5f33609677df7a,
+                    0x865afda166d466b5,
+                    0xb1527626895f5975,
+                    0x98912475b26407b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9016bbc54836125e,
+                    0xa4ac9586c18e668c,
+                    0x3bc6c2a395551323,
+                    0x42c993c486ed756d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x35b38c87e4f33158,
+                    0xcb330435a0b30dbb,
+                    0xe0e5d0884333a7c1,
+                    0x91ca47ccc49c77af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e7eed8ffdc194dd,
+                    0x8183c7c4dcce634c,
+                    0xd60e9b856e44c5fc,
+                    0x0b66c095af9117d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec6cddd4bfa222d5,
+                    0x326cf06832296923,
+                    0x7887f068130f755e,
+                    0xe78d822fed89f998,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x315ec35f97da9390,
+                    0x76f9c498bad4622c,
+                    0xdca188835f109760,
+                    0xc03f8c545916199e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2850c789e1748adc,
+                    0x25daa22e9dfc02f1,
+                    0xbfaf7e2ac9dff4db,
+                    0xc536950b8b9b8760,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc79f598f71fefe5,
+                    0x8501bf14687fdf87,
+                    0x5dc48eacf6e9e0a4,
+                    0xa904542d65554742,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0162533e33589f59,
+                    0x2fc14a3acc21b949,
+                    0x7ee70b9aecdedc39,
+                    0x7fdeaf58e1252785,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a96f3c4881beab7,
+                    0x2257ed5f2a76d010,
+                    0x9b4a27a53ebab1dd,
+                    0xc497b725b31c2a9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27aa2d03d3adeae0,
+                    0x6cceede5d1edb7a3,
+                    0x3a187f85ecc5f42a,
+                    0x1a319b87566f2d87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa33c249e06877aaf,
+                    0x73ba6732875a5136,
+                    0x9f8abbe91ca924b9,
+                    0xfa5b6d15523fbc78,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x186f4fc11ecdc33f,
+                    0xef9c3ae990a9fb04,
+                    0x13453a413d2702c7,
+                    0xc17a2963ddf247b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ddccf560e882ec0,
+                    0x4065c713cfd6a33a,
+                    0xe8f33dc8295dc9b5,
+                    0x1712201ae54b15f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70e88a95981b7cd8,
+                    0x6fb601c822ff7d9e,
+                    0x1dd0f4001e7691b7,
+                    0xaac84fc79ef7862b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cd830f21a0c3a21,
+                    0xd597e67cba13f714,
+                    0x051e12dda1694bcd,
+                    0xc8cfcfac71b5525f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb99c8fc56407699c,
+                    0x7642a9b198f16070,
+                    0xf2e30f0d73930313,
+                    0xefc12fab08e45e1a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x050e428bdd12b895,
+                    0xa33ea4611f91bcf3,
+                    0xbb8fa5f1e9403336,
+                    0x071bf4168fe3ef07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbee9440224c91b6,
+                    0x4ddd7c719ffa9a42,
+                    0xc9fd96e436c1c0e5,
+                    0xab52776f4539908b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e9dce898ba3735b,
+                    0xdf7e6c64106991fd,
+                    0xa294def95de11d6c,
+                    0x3f6034f55e28f0f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65aec1105506a472,
+                    0xf89a4dab8d2104e7,
+                    0x6f6bfac57f5b2544,
+                    0x6c419a84e6f979cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa21ddba4dad3ac03,
+                    0xa34248cd49821736,
+                    0x248826b4ae73f0bd,
+                    0xd1aabff4f58e1396,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000e0000356e,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x96b936c8f0b965c7,
+                    0xe301d7378b934765,
+                    0x70c381fedabea903,
+                    0x99e080a163851697,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb8484743721d671,
+                    0xf8c725341e743c3f,
+                    0xb7f84bd40ff6c717,
+                    0x38001a6fa86571f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9beb8b97884f96e1,
+                    0x7a311494a370b352,
+                    0xbdafd7ec5f500d31,
+                    0xc115cf2c76013b28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf11d77e42782782,
+                    0x76867c4da30b3e64,
+                    0x9a2c10ce22d0eb9d,
+                    0x74bc87f68580dc6e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5982fb2415f8e57,
+                    0x4ed8c4ce59f75477,
+                    0x94cbefc9e506cebf,
+                    0x28c732804f1c5806,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a1ce3ee5d662fd1,
+                    0x166f2895069adfcd,
+                    0xdf8a1a0a522436c4,
+                    0xa6c3f680b7fd655a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e60d572f39a02d6,
+                    0x3e9884f23868bfb1,
+                    0x318185195bd6ca10,
+                    0x2f765b97ffd9750c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x457a0bd6c909b2c2,
+                    0x6b3823cf376a3909,
+                    0x31ff13bf17fb5155,
+                    0x8ba7b4dd6c00bead,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1837dcf9fecdd20e,
+                    0xcddecac243b0b7fc,
+                    0x5e6513bfd53644d1,
+                    0xdb64411df579eef0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24cc99d8611b6db7,
+                    0xd99ddd7a3549f800,
+                    0xaaf251f8cea51d48,
+                    0x412cf6afe872826e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3e0d9e2a68ba3915,
+                    0x2d4fc1de27b00d82,
+                    0x9dda4ebbe975de49,
+                    0xd837f03f6d78d48c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x038f608818e5aefd,
+                    0xd853aadd0607c4c8,
+                    0x803a64228f989757,
+                    0x9ce0a5b68ddd0d6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d52e5033b177225,
+                    0xd9e431732275bfe7,
+                    0xa599ef412e3fe322,
+                    0x9a01fc219c077bfd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e8b680cd43cba6d,
+                    0x0f89e48a5f63a7ae,
+                    0xecb401be5bf6dea5,
+                    0x98e3999f0e978762,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8b623fd8d6dd9bc,
+                    0xec70d0bf40e2dae8,
+                    0xc8cab71ef015cd35,
+                    0x86c99fdc1ec9dd27,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cf32f759f0a7c67,
+                    0xf8b67281ef815c1e,
+                    0xd4703554513ccf88,
+                    0xcb2c4330753f6c25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93457bad75c0ca19,
+                    0xc1bbeff5fe5e4915,
+                    0xc24b61a1b43400aa,
+                    0xd2dedd821fb0b03a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x279166478d479533,
+                    0x7ec3004c2be73ce1,
+                    0xa5a324d1cdd913e0,
+                    0xb52c761c0709451e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2963c12a89fb2f3,
+                    0xcbd8787ab3f728e4,
+                    0x1687c54474d57293,
+                    0xd7cd4a6fd754e0aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd487ec5175b1c284,
+                    0xbf599c15fd12c297,
+                    0x42a96ba5b0836dd3,
+                    0xe599cf76ec5107f2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc8e8258546dc8d6d,
+                    0xd2031e41c8a224f8,
+                    0x0492e3605b686a9a,
+                    0xadff6328688a941d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70549610fb832630,
+                    0xde2643075220efdc,
+                    0x912d12eb1f065bf7,
+                    0x5a31d921273f1a69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cebd442502b82c9,
+                    0x5f217874273d4930,
+                    0x6dc671c63b3fe44b,
+                    0x8cb81dcb7188b3d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc608f0e6194dac68,
+                    0x5ef880d5e48900ee,
+                    0xc1780d94ea04fc00,
+                    0x4fdde7e738b3d652,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff1021766f325d2f,
+                    0x469ca8ff4ee3e926,
+                    0xec83b685adf48c2c,
+                    0x5739b9df354248e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0a26bf3a2b7bbfe,
+                    0x5cc847fd3fb35bc2,
+                    0x49d9f2b20df104d3,
+                    0x34493e2e3a92ee30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72e63b9e736013dc,
+                    0x5d82eab553672a3c,
+                    0x1b3102b8f0281aad,
+                    0xd5170e568799ab24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97e347a1dc4fb17c,
+                    0xf65a41315e7b3a4d,
+                    0x8b0440559bf763b6,
+                    0x678a63f753a92286,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbac5d66876343618,
+                    0x69d82c2915c82fb5,
+                    0x99c2681060f2bd1f,
+                    0x0b5df4694b33e554,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4395b481cb690deb,
+                    0xcc737032c2441572,
+                    0x843666ab18efa529,
+                    0x7abaa8c79ed5ec0a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa27310153eb13bf6,
+                    0x265ea5b57cc6c557,
+                    0xf3bc703df38298f1,
+                    0xa04b2f9b0dd4a274,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33917c92d1e1e65d,
+                    0x61fe1a6cfa522e7c,
+                    0xf40e2ba71a37682b,
+                    0xaaa82ca3397976da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23b7c38823877388,
+                    0xd53823d87f70e08e,
+                    0xd721bb9e1d3e462f,
+                    0x6dba58bda3112116,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3568052ff4f77ad1,
+                    0xcd666c6915d577b1,
+                    0xdf380bb50c225959,
+                    0xc09ab626a94f3fc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1ba250ec17ab1e8,
+                    0x14a143533d4c4db9,
+                    0x4c5e43980aa2599a,
+                    0x4804b63a58ea4324,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe12b5f82dd12c6e1,
+                    0xbd43ff220533a053,
+                    0x24362e0061d1ce08,
+                    0x91ad475c64fbcf32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40ddb401f5a1b7b1,
+                    0xe9907d60cb85e0b4,
+                    0xb7ecd4f10e597416,
+                    0x310f2688facdb98c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23837e7e196dc87f,
+                    0x5f6ca2cc9848d670,
+                    0xecbe7a42ef2db8f1,
+                    0x89bf378a547af70c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37525385fe83fbf2,
+                    0x24ceea41abe0d163,
+                    0x35698c91eddb18b5,
+                    0x1d838a5ef18e111d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a5665089308c14b,
+                    0x37b63b33551be526,
+                    0x74adacc97e1780ce,
+                    0x32759df37bf1d520,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa88806cfe84c596a,
+                    0x7d29da7f153b43d9,
+                    0x517fd6295848f3a2,
+                    0x2ee8a97d5b2d87ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cccf25b8385b6e3,
+                    0xa847a3bddae44361,
+                    0x47b630cc16081bb1,
+                    0x2180a2f0add9d23b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0606cdc7846f1b90,
+                    0xcf55933b99c5fb8d,
+                    0x46183f2803e66845,
+                    0x779bafc669b9ff3c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12e63befdd59cec8,
+                    0x009d4484a8f22933,
+                    0xf1e498650aebe64a,
+                    0xee33860c947d4758,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x620e64fab57a0677,
+                    0x922d964f43e5c9fd,
+                    0x54088d747f72b8ed,
+                    0xd8a8e4fed281ad08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe4a2c8b243c036d,
+                    0x0078597b944a52c3,
+                    0x1b7c6ad7372160b2,
+                    0xc98e924cdb3830e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14c905454c9b407d,
+                    0xed238054311b97e3,
+                    0xa5984fa0fd7de3e3,
+                    0xf146575efb597030,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ad04244358366bd,
+                    0xda4b5e1c97a09ce5,
+                    0xf6ffdc1bdb4da507,
+                    0xe60b5644dcd032e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x548bc41cda86a0c1,
+                    0x316a5ff117bf3309,
+                    0x09a7899974f82652,
+                    0xb852b588d7a1085a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac768f5232297ed6,
+                    0x11aa8d00a0807d9e,
+                    0xb4a1badce25e5abe,
+                    0xc339b87f54ca530f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0c75518551fe8741,
+                    0x24d0f1c0ae85d59f,
+                    0x2892eac3896cc03c,
+                    0xaaea65de936f1035,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75ecbaa71eeaf6d0,
+                    0xb7fb49a858296eed,
+                    0x4204c9dd2c1f590b,
+                    0xa2b16c8752af7249,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21a3b691a21e0540,
+                    0xa883b462f9c0b23a,
+                    0xd56d51f3de09e269,
+                    0xbc1ef48b1d9a7f96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcbc0a2460d273369,
+                    0x4ad46622f35b4993,
+                    0x6ab28bd00a3956a3,
+                    0xaf318ad4c4727e5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f7d6ca19118eda8,
+                    0x11dfc166c7810f04,
+                    0x51dc30fbcdbc24de,
+                    0x8da206dc4b209c43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd57614e69b05071,
+                    0xd46d2af31ba8488f,
+                    0xf6c53eba5ac51128,
+                    0x99ae4037fdfae860,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a8811fe90eeed9e,
+                    0xd42d9434183cd3a2,
+                    0x5fcbd8199f61a63f,
+                    0x95ae34dfbf7c345b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefc6be0e0a7614e8,
+                    0x1a869357075295f3,
+                    0x1b81c9bf99fc465c,
+                    0x3d6b1d4577e6516a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91d8da6df67b3075,
+                    0x8916986d4c4f036b,
+                    0xf57ff314304596b0,
+                    0xf586f2c750a1d908,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e2690e93c86acea,
+                    0xa24c08af94d794dc,
+                    0xe1a4865f9f55952a,
+                    0x39eb344257449b8a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
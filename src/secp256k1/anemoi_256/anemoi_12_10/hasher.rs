@@ -0,0 +1,3142 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U10>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0xe47ef8f3376494e0,
+                0x2b334690638403b1,
+                0xac8cf301c25691e6,
+                0xa176765022987279,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x84b63c6aef3e49d3,
+                    0x59eb555837504ca2,
+                    0xc121b1d4d8fba391,
+                    0x96c06261c6806a53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b1e174a976bfa01,
+                    0x18f06c82c7ce145e,
+                    0x2a2abf73a8865ee4,
+                    0x491487594a95d97b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xc1d6df24ef4d5c38,
+                    0x3c972bd4e5069541,
+                    0x16f93c3372e1467b,
+                    0xe67aa2cefa4e49d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4894d59f4126bba,
+                    0x84b3d903a10ec0ad,
+                    0x69de7d5029bc84e2,
+                    0xf8f6e89f42d6eec5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb949b95a7e039b49,
+                    0xf66ee200b321fd6f,
+                    0xf42e20812a7f2b5f,
+                    0x2dc3d3a410892d98,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x602d9075c2b91cc0,
+                    0xd17a833e2fa5477e,
+                    0xff6ea765af0c78fd,
+                    0x3b30a1d1becd84ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74eca469e24939a6,
+                    0x978f828a51366d8a,
+                    0x12f5f42ca871fd4d,
+                    0xfd0e0b1234261032,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbcaee92c6ac1131e,
+                    0x3a7e36a36938915d,
+                    0x9a5a0a1911dd977a,
+                    0x224962a62d66b859,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2439bbea796a76ab,
+                    0x3b0c70e7c6543c73,
+                    0xdd6bd776517167e7,
+                    0xda80322c6dd7b4fc,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7126b81ad72d4489,
+                    0x1c737d11920b639d,
+                    0x46d3d92ca01e49bf,
+                    0x8db12a883423ff19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92f5bc4e6a05b3bb,
+                    0xb14f35d3095aae6e,
+                    0x04478c809ec441ab,
+                    0xa6602ca40c0148e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48d0667505a715fb,
+                    0x4bec2db94e5ca5ee,
+                    0x73ea9aa3f09f7dd4,
+                    0xa9e3190cce7b9e65,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e60864fab6a4b7b,
+                    0x995c759694860a96,
+                    0x008195a55b8bd18e,
+                    0x68ad5c1849480141,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa73b3f27482eb506,
+                    0xa4b20266536e8f84,
+                    0xf785904e20b9a49d,
+                    0x36fadab75cb3a362,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf4f51a8c6bce6c72,
+                    0x05be716fbb2521a5,
+                    0x86fd0d70ac92c647,
+                    0x8371be199a5af0fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c25420e84af3c85,
+                    0xff3e7d740cdbf3b0,
+                    0x5839ec0433d2bc36,
+                    0x10009fbb929117b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4adbbbd6ffe1e7fa,
+                    0xffb3b64a7d68b042,
+                    0xe1177517e5d149a0,
+                    0xc60f4f5b4a019372,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb179dca09550669a,
+                    0x07560e6d76a54723,
+                    0xdb00941d777de44c,
+                    0x217f27dd557a893f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10e0850c6cc8c643,
+                    0x0b4d30976ad9e8a4,
+                    0xe75147e0f158aab2,
+                    0x7983fb6738a7c9e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e7d327651fab615,
+                    0xed694ac26200c885,
+                    0xd7c0eb687329c722,
+                    0x1a647411b4fa03d5,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xa4cec24d468be62d,
+                    0xd5af545907c43dc0,
+                    0xe5db93c4d7fc522f,
+                    0xf11e37c3a8a1bfc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda14f1b5b269fcc8,
+                    0xf1785531c30daa90,
+                    0x1112ab23299f34e6,
+                    0x9796a966fc2e3673,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe5cfb39abf7c79a8,
+                    0xa8328b875d2b79b3,
+                    0xab6e61b7323fbeb2,
+                    0xc91e275170c1a81f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d825bb96f7ea983,
+                    0x08825d4ee6bdbf83,
+                    0x31dfc6f16f28669b,
+                    0x97b2214899823bf8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2f019d49505bdece,
+                    0x4a41299284d2c882,
+                    0xbf189c3ab3fe35fa,
+                    0x33ea1b867b2382d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf7b3bc301f1db57,
+                    0x8a153dec738b3406,
+                    0xb3c0b4b37716f2ed,
+                    0xd87dea306d0fc05b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x252e41bfdac3c6c8,
+                    0x7cb0547a18fd9ea2,
+                    0xec0d453a3fdf79a7,
+                    0xf920d527a384c891,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f7f4cbc62bca15c,
+                    0xdc6af864a242889c,
+                    0xd1415d68d24940d1,
+                    0x58ac6f2084a19196,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x35db8693a4e6f405,
+                    0xb11be294c2bbdae9,
+                    0x8dfbb173f114d6a4,
+                    0x11aa92d47b7e8dc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2693f86a0b73ac4d,
+                    0xe6772e5210e2fc93,
+                    0xe51ae0e3c7e5fadc,
+                    0x57bb72914df55fe8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5dae958b7afc032d,
+                    0xbef827e876bf6993,
+                    0xa769aaafe0d1293d,
+                    0xa4639d0e032607f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8af7cd82958a3bbb,
+                    0x5bfcfd069d6600a6,
+                    0xe9989453fdbc7d08,
+                    0xb8bf10c57bc64af7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb6dab2811ab49c06,
+                    0xa4f8dcf2b80774b3,
+                    0x2dfc7b86453b4ea1,
+                    0x11360197771f5f10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca4c4dbe89036e08,
+                    0x65df0302668606fa,
+                    0xdb5d0d9f717e4ce7,
+                    0x73ae44c131ef2726,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3e1a6d557d89fd04,
+                    0x62909e4756119dc1,
+                    0x5ba4117fa1e8017c,
+                    0xc6a48248de964a7f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88963663647e616e,
+                    0x498d263d48b76373,
+                    0xfeb2da2fd1abc283,
+                    0x5a7336100e26dc62,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc9eb12a7f4fb63d7,
+                    0xb4e282c50dea8753,
+                    0xbd789e1332c026fd,
+                    0xeac4c142a48008cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6877d77d6b1300b4,
+                    0x7ba55433113abbc3,
+                    0x9ba91c7824a364be,
+                    0x6d1fa5675a05b22d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd80cc5507d5cc6ab,
+                    0x001c509a0dfce39f,
+                    0x071cb30fbd24ca74,
+                    0xcb88eee939d3e805,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50fbe96e681aad7e,
+                    0x63e54e3ebb186fa3,
+                    0xe5f467d604b20a99,
+                    0xe3ee4d5ca05de312,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xa4cec24d468be62d,
+                    0xd5af545907c43dc0,
+                    0xe5db93c4d7fc522f,
+                    0xf11e37c3a8a1bfc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda14f1b5b269fcc8,
+                    0xf1785531c30daa90,
+                    0x1112ab23299f34e6,
+                    0x9796a966fc2e3673,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe5cfb39abf7c79a8,
+                    0xa8328b875d2b79b3,
+                    0xab6e61b7323fbeb2,
+                    0xc91e275170c1a81f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d825bb96f7ea983,
+                    0x08825d4ee6bdbf83,
+                    0x31dfc6f16f28669b,
+                    0x97b2214899823bf8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2f019d49505bdece,
+                    0x4a41299284d2c882,
+                    0xbf189c3ab3fe35fa,
+                    0x33ea1b867b2382d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf7b3bc301f1db57,
+                    0x8a153dec738b3406,
+                    0xb3c0b4b37716f2ed,
+                    0xd87dea306d0fc05b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x252e41bfdac3c6c8,
+                    0x7cb0547a18fd9ea2,
+                    0xec0d453a3fdf79a7,
+                    0xf920d527a384c891,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f7f4cbc62bca15c,
+                    0xdc6af864a242889c,
+                    0xd1415d68d24940d1,
+                    0x58ac6f2084a19196,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 372];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+            bytes[310..341].copy_from_slice(&to_bytes!(input[10]).unwrap()[0..31]);
+            bytes[341..372].copy_from_slice(&to_bytes!(input[11]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3a3e55834a0b0ea5,
+                    0xaa45e01106d16979,
+                    0x5df47301e58bb5bf,
+                    0xdb04f9f81edc3bd7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97c2df2ee698f382,
+                    0x964058ee9e5c2ba1,
+                    0xcc74de7ba77d10bc,
+                    0x59f19f01bdabffa3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff52d42079a35ada,
+                    0xd5b0d52204e26082,
+                    0x8839877b4a65eb99,
+                    0xfdf39a6d12d18712,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2e5492b0d3f6831,
+                    0xd34b7f428d4e087d,
+                    0x5b3ca492cfddd831,
+                    0x6c4492baae9e7b00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6690ecc2e383aae3,
+                    0x11769b1ae5e8ee70,
+                    0xc0694a2a9a2b5a7b,
+                    0x25d1a944fd7b90da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e27ed3edd5e42a9,
+                    0xc2c4171b5873e524,
+                    0x05da0e3b8f54f27e,
+                    0x7cd185b76454a4c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c2e4f49e7d41b3e,
+                    0xfe9689f6adc7ff68,
+                    0x8ae2cf2e94b588b6,
+                    0xc8e6713caa8aa515,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfdaa258cecb0b8f7,
+                    0x55e483e90c4e75dc,
+                    0x462d95825955a605,
+                    0xf003b574ce9f2fb8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fc367a9a80b4d44,
+                    0x2eaca7014859a55c,
+                    0x53465e25a6ee6252,
+                    0x74481abd01dea0c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97aa61365d37b35b,
+                    0xdab5f1c65250a5ff,
+                    0x99368d13908b77c7,
+                    0x31198b65abb1f721,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e7a90fb08c01669,
+                    0x9f3aec7867e3fa48,
+                    0xdb3033ec7623f000,
+                    0x4c821098c93525be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x582a33e6b4f74aa6,
+                    0x149f2de335534b99,
+                    0x03e06eaa1c58f0e0,
+                    0x093e592ec2ce0c37,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8dbd4419dcb3ae76,
+                    0xc70431fdf87bb13d,
+                    0x2666367a47c2f9f1,
+                    0xaecbd44832d70213,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb7c8b65c7ec32ed,
+                    0xedf06f94f3e4f6f9,
+                    0xf66e59a80eac524e,
+                    0xc6d52e2b3c9f8fef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1825c36e32c8e255,
+                    0x2023eed23ea5c28b,
+                    0xcefb6731b98ab296,
+                    0x059344b6b3a97481,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65dea7920f7a2519,
+                    0xc2215a1b874761c3,
+                    0xfcee6a10371fb85e,
+                    0x82474fcaa10234a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50663d0d0f33919c,
+                    0x1bc597d14c551bc4,
+                    0xee3b929a91082bfc,
+                    0x87ac58422e2e6522,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92737baabab9faca,
+                    0x78808e9a57b362e1,
+                    0x32f2b2c3b6afd073,
+                    0x9b30b484df84969b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x818ec92affc07af6,
+                    0x8aeffa7bc7a42f21,
+                    0xdddecf61bd11f4f7,
+                    0xd692d72c92c1ab33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb592c59bc827b63d,
+                    0x8913459f2d2e623b,
+                    0x9be7ed486f40005b,
+                    0xf02cdccafc081365,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ec7638189d40559,
+                    0x60108ce02cecee7b,
+                    0xaf4658b510d1bd1b,
+                    0x596df030dc03bed4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x551ca98998b85059,
+                    0x8d8bf9746e868c9b,
+                    0x4dadeec58d3f07f5,
+                    0x702eb98a3df87e82,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d895af6c814d05f,
+                    0xfa34115752d624e3,
+                    0xa41eef7c2624702a,
+                    0x63e07e22f6f9f1e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x094b89808a9c9b5c,
+                    0x70721fd6c464e936,
+                    0x06a05a0a4aeb7a2c,
+                    0x1bc9ffcd292db317,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x915a93f1523cd3b6,
+                    0x18502dc9f788ed3a,
+                    0xa833b22894ca8f5a,
+                    0xb90c44e6554959b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe05658819a898dc2,
+                    0x97469150e5031ede,
+                    0x4733a29de6ed6573,
+                    0x3a2087a74da13f49,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b8dc21d38343e50,
+                    0x2ebd0747b3fd2b40,
+                    0xb71b44a38541a1da,
+                    0x1cef0541aa31f4f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46def71d93629e26,
+                    0x1cc587c484ad2308,
+                    0x1aa83942be83b9b0,
+                    0x3f7235b71b08e531,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce6af3d74ed67e21,
+                    0x9899759de48a5ebb,
+                    0xdbf462d1b8c69d43,
+                    0xa06779432931f405,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b450bd3f42792e0,
+                    0x21f1e3a674ea9b8b,
+                    0xca9cbad10b06b89e,
+                    0x4a197bdab714e28d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6498cc1737059a19,
+                    0x81bb3af53b7aece3,
+                    0xbc9d83258fcbdd14,
+                    0x05a9bd6b86e74c59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x894d06fa743a9ef8,
+                    0x63444ca2c416d41e,
+                    0x8d17248f60b6fe99,
+                    0x462924ad9c77b7c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bfa36afba726ba6,
+                    0xe2aa1a22f76af17f,
+                    0x8fc5a66cbae69429,
+                    0xd54febc55af78945,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0bc9b23ee5be7c8,
+                    0x8b3441f57db26aa3,
+                    0xe9b2e2c583af7529,
+                    0x647a455c2d54d150,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa189aed71d91e4ea,
+                    0x77edb551d9a647db,
+                    0xc93d4a1aa669fb87,
+                    0x21483201bc7da476,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8683926f8139dbf1,
+                    0x6983392618619581,
+                    0xe35571c6ef4f5d2b,
+                    0x9ede344b3dfbb7ba,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x675fb8c789416e97,
+                    0x302c29acfdb7634b,
+                    0x93c74a6906463a95,
+                    0x053de77068d39354,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb6a0bda34d6091f,
+                    0x84e83eba7626e503,
+                    0x1ff778fcf25f15ab,
+                    0x1bec698bae30eef3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0177b9ff56e8e00,
+                    0x69c5e2abdf42a8e3,
+                    0xc1fe483892e4cd38,
+                    0x1b186a135ed9206f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8313a3112268ec98,
+                    0xdbf5609a37e00e60,
+                    0x5e6b78a03c92cc8e,
+                    0x1ac3ff4f8a5f5dd0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe130f04ee28db708,
+                    0x1ed2048ebd44126a,
+                    0x2e6c4b9a549ba7af,
+                    0x2d98d382674fa37d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87cd65125602c11f,
+                    0x55f579a5f26f79e3,
+                    0xce303a503e35551f,
+                    0x12e74a16111c79a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68f982ead8d7cff3,
+                    0xad220c9f0423c287,
+                    0x104200ddfacc9deb,
+                    0xe8555e493058a48a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb11f8e4d64f0ba7e,
+                    0xd9e4572efe4f9ee8,
+                    0x97be3cf830de9eda,
+                    0x5d9936db0297e93e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x232bd6743ee0bd55,
+                    0xcb18b83aeda5dcdf,
+                    0xdeb3e883340081ea,
+                    0x0d721e672877d5a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x791faf8a318f3eb6,
+                    0xfba72c25a041f05d,
+                    0xb0fb4bdf3fea9b90,
+                    0xa34544092e4702e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8afca55f3215b7ee,
+                    0xb2a19989e168e8c8,
+                    0x05291c16cbf47af0,
+                    0x7451c6ab7298a928,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd706c0d87dcee589,
+                    0x2a93e97b238666be,
+                    0xf79314fc09f031fc,
+                    0x5e6a0ceacfd196df,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x795f77420c8bda43,
+                    0xf6260e93eb6dade2,
+                    0x34d947fbbe95b537,
+                    0xd46542c2f4ccd986,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2ac1adc185b77a9,
+                    0xf3eb62b0d54393a4,
+                    0xd328bdf9bd5fc249,
+                    0x979fd8216189c20e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66203465416cd532,
+                    0x190a0231902b2c1b,
+                    0x0a8c7932058851f8,
+                    0xfcc277fb5f4f54d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d1a777073c70037,
+                    0x4ad4324a6fac02e2,
+                    0xa26754ea3a034aad,
+                    0xbcaab77fac5b29cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaba3895f4be21159,
+                    0xb55cde2ead15a775,
+                    0xfed61bb0659802e2,
+                    0x0400c6529926097b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc569077724b1554d,
+                    0x3ff10574d4a3bd4a,
+                    0x5348f8a658917db1,
+                    0xcd59fad028634b05,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c2cdd9eab50f66d,
+                    0x4a1fb3204401a9b9,
+                    0x75251fcefa7e8b16,
+                    0xa06f514ad2486852,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0d2afca40cfda80,
+                    0x20ffed3ec2d89fea,
+                    0x3a0fc3ca9d48dbbf,
+                    0xefcaa2bdc63d25ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95b595f38b0f9287,
+                    0x3bea4103de57ab92,
+                    0xc63729a1f8972158,
+                    0x62921d26409e754a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x397eb12848a604dc,
+                    0xe1b419e932dfd2d7,
+                    0x4a7c059b5955ecf3,
+                    0xa327e71c52781071,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea93c83bb45d671a,
+                    0x10d7f8ee6ed9a021,
+                    0xfe5d5b638885870d,
+                    0x3284d7fa903e15bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36d0ca893c119cb8,
+                    0xc408abb9836cc2dd,
+                    0x78f152b1991c050b,
+                    0xcc5f4347c0a5a5c2,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4e51573c21d1e728,
+                    0xef1448ee551a80ad,
+                    0xd2df95fabe1213e0,
+                    0xe6567fdbe401c31c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3b32e44421b51dd,
+                    0x027c471a9a977ed2,
+                    0x1a877c26c1b42d5c,
+                    0x1124306c4815effd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba8fbfad98c83a2c,
+                    0x5229a2992899c6f5,
+                    0xe73a0e08496637e5,
+                    0x2b2754ebaef4b616,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdfcf2228822b7291,
+                    0xddf065a9b2e16d87,
+                    0x9d994c7293752fa6,
+                    0xdb9e4fd803468022,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69a96903d186336d,
+                    0x0258db9ea918d5aa,
+                    0x42c28a3e7b62f399,
+                    0xa284a1a6fd7dc161,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaed1609d710ba601,
+                    0x345225f3fffea97e,
+                    0x9b35ad5b59272799,
+                    0x1c0fd51e17dfb3f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x901fdad43489dadb,
+                    0xee260f5c1a6c2309,
+                    0xba361cdc912ff725,
+                    0xa16c194132a42b8d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9252db8904fc2af5,
+                    0xbeecc727306b0071,
+                    0xce8e196f691e96b9,
+                    0x01270c74019223c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11e4ad891369a5bd,
+                    0xd1ef2874764c71e1,
+                    0xca6d2d9a9963edb8,
+                    0x9b2b8a71bca578ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2ac4af14a2df32d,
+                    0x383bd92ac145a64f,
+                    0xe36a93a747c7a97c,
+                    0x73a66cc1f5db8d61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6de7c4aaf0637540,
+                    0xb6340db218ce3282,
+                    0x95ecb4308edeaf01,
+                    0x520602b39f1636a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x820be29314a344be,
+                    0x6789a4c3c995f470,
+                    0x04335bc20f58d3f3,
+                    0xb08e9fc6082ea4a7,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xb18a71a93b06cc60,
+                    0xef56380d8fbc9d81,
+                    0xed686d2bc6724d3b,
+                    0x116a6bfa0596341a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1d24238b3d667aa,
+                    0x9414a43940de8c13,
+                    0xdc683072c3222650,
+                    0xe550cdf3dc1a3a7d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x929b7050cb0c942e,
+                    0x7a99676d7c5da8e8,
+                    0x9affc42cc23601a6,
+                    0x117bd96463d94879,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf966178c486b2282,
+                    0xeafc7f09ef3a8467,
+                    0xb7d8a0be39321ed5,
+                    0x24a4b884dd8db14b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e43569f26f9da5e,
+                    0xa9d7dc40c4a93187,
+                    0x3a849e4969d53b9c,
+                    0xb34d179fc31c4b09,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea953fb6ba711c94,
+                    0x2d9551f84c230bd3,
+                    0x913e456dcdae0a03,
+                    0x195c79c96faf9586,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23162591d7de5cb7,
+                    0x0d06c10af4e5761f,
+                    0x47d101c17158a4a7,
+                    0x34cb0d76c695200d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53440e35261476ba,
+                    0xe1c94fe7d7ead461,
+                    0x83844db28c833a17,
+                    0xdeb0c63729d09c00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a162bfb06ee531e,
+                    0x1e0a464cbdc0680d,
+                    0xb0033588883d8941,
+                    0xd370e2c0945257be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabdd916073650453,
+                    0x20964901ce8b89bc,
+                    0xdd11c3638bdd2b90,
+                    0x02b274164381a80e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc776ac31d976c03,
+                    0x446ef3c4d063ad5b,
+                    0xe26f7c3052bc0224,
+                    0xd0c2ca7d11842924,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce4ea743b1cebd40,
+                    0x4b5bcc0818168d83,
+                    0xda27f9ffd6fc420e,
+                    0xd58882c055df9469,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xda7b3c0e5c78a0ad,
+                    0x57b178c4f2ca5a16,
+                    0x462aa19c5cd0c70b,
+                    0x36b89ebb72be5999,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6336c7e74e860a25,
+                    0x8c67d55600bccb44,
+                    0x3dbd8a8aab68a9b3,
+                    0x94b9fe29124e13e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x462c6604af4bb18b,
+                    0x8f9432675e354b55,
+                    0xd70733c657afdef2,
+                    0x367726b4cb566c44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x827aa314cf28b46a,
+                    0xc2913fb37f796995,
+                    0x60f2d7bca8affb1a,
+                    0xe7cdd737a9ffb55e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77ec3789bf4c6d13,
+                    0x3c44e85703b7e5a4,
+                    0x08e0a66344651796,
+                    0xd097b6e4a55e8977,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27770361bc4944d5,
+                    0x1a7dc3315d6a46b1,
+                    0xc15562db5137ede7,
+                    0x34ed245d72194d74,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfc42794a1ae96e3e,
+                    0x78f6cd25e6e0915d,
+                    0x0c58b3ac04a24ae6,
+                    0xcc6ddcf487ea4053,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b979249826033e2,
+                    0x83e9739c405a11ee,
+                    0xdb93a23654517d73,
+                    0xf66024b77a9a27c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ae4b55f079b2a04,
+                    0x98d3443652ba3485,
+                    0x42fc03a7a83812f6,
+                    0xb215699ce1ad6115,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46af0af12b224667,
+                    0x6da7df399b3f2a39,
+                    0x45eb4c373682b59c,
+                    0x80172cd8507ac6c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa86a3c16665162b3,
+                    0x836ad82448ef6ae7,
+                    0x00ebdba67b7452ae,
+                    0xb03cce7291b0bbad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6563aa925e61240a,
+                    0x9ac0258119945ad4,
+                    0xeb2dc96b35c333a5,
+                    0x8744ab0eddc81163,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdff99165c66349a6,
+                    0x7010b487a73cbab5,
+                    0xdcaa46b7053284e1,
+                    0x9595f334aff7591d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc018a930ef12b60,
+                    0xdf25718f39e5ba6e,
+                    0xcc9e18a5d57dc93a,
+                    0x59b25356a7f23ace,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xffa7442bc813028a,
+                    0x9237d09ca6d67567,
+                    0x965e4cc1dee15fee,
+                    0xf2e1b684a551c5de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a0276df8b3d0ee1,
+                    0x096dc8e87aeeccc8,
+                    0x05a7572e30b0b8cf,
+                    0xdbdc2d6aa9430b3d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0806f80c70825b76,
+                    0xfea593789b71d880,
+                    0x8d144f434c133347,
+                    0x37950469ca04c111,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e0245277ad9a87e,
+                    0x548bcbbd3d0b202b,
+                    0xe07e67b683558a02,
+                    0xb7fc901d35720dc2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x82faf2db0742b62e,
+                    0x401eb329c2d2959c,
+                    0x2eb0346b433f9f6d,
+                    0xd4631ec1c6588c09,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb82c60620553e955,
+                    0x6bfccfc3f3a4b265,
+                    0xc8f719cdb5023c75,
+                    0xf32b725a3848c70f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce45e83131a3f9b0,
+                    0x120c5d3149e97464,
+                    0x7e6a9d24342f26e1,
+                    0xb005abd4bda6ca1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x125ae862cb0a09e8,
+                    0x9997aac452a99eec,
+                    0x3ca51650bde8cb33,
+                    0x537f59faf051216b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb4c34a335d93724,
+                    0xe608a9a137289322,
+                    0x886174b7b0cc0513,
+                    0xdd6bfc7654e17123,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8570dbb6dac0dbae,
+                    0x3c2d8030b29f0fbd,
+                    0xe7389cfbe989a273,
+                    0xface70988eeeb8c1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc6e768be50f1ac39,
+                    0x85efb4e9aacb1390,
+                    0x6a364a5798dce69d,
+                    0xf609ea10340926db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b2e867521b393fc,
+                    0x41c0cbd34c8e8ee8,
+                    0xca957cb246b21da9,
+                    0xf206c232bd02d5a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55c0e0871b374108,
+                    0x6fda09246bf3951e,
+                    0x3060cf85e58ff429,
+                    0xa2965aa0381c8d9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9ed47390dc7c083,
+                    0x87aa26d0123058d3,
+                    0xf0f6309b13a74e9f,
+                    0xac9fd2ab64d32a96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabeaa0de812ee7d8,
+                    0x05b50ad729c2bbcb,
+                    0xb151b025f97b393d,
+                    0xb580a638ed0cfffd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7c2e4140bebfd33,
+                    0x3681998449148d15,
+                    0x8792ce6288b00172,
+                    0xcab82bfbd858d188,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdc2e19942832ecf5,
+                    0x0a1043ce558f7e23,
+                    0x4840390192bee906,
+                    0x27b847d32d8078f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfaea0582730b84bf,
+                    0x1b8f849eb481b04e,
+                    0x7071aa2eb339e0e8,
+                    0x6c9503654c2cc0f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb8c1a5b5d970164,
+                    0x3ff91efc436de63e,
+                    0xf5c77f296629d9ef,
+                    0xba6b5f95d72ba38a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92d238d1495ba756,
+                    0xa838d6c57b63ae53,
+                    0x8fe04c05ba98e592,
+                    0xc879101c5177642d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5f9bdd9aa6ca5d7,
+                    0x581e43bb4ff8c7e1,
+                    0x4478b6e9f232e9f0,
+                    0x1325f9cf32c70e1f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ed7a41e58b3a313,
+                    0x6164f55702c1d69f,
+                    0x51ee9fd6df5139bb,
+                    0xb48953e98ca8beac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x49bece08b3aac255,
+                    0x208bc4fd514eead3,
+                    0xe7bda42d2669d4b6,
+                    0xccc01741abca23b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29f3c69fddf97fa6,
+                    0x518ad8dca4523f79,
+                    0x8c7f85c8f6510772,
+                    0xfc7430e752edb08d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe193d2573aa2f3dc,
+                    0x310375a0f5ee7879,
+                    0x21d3b053683d4f0c,
+                    0x7e8d16da758abab8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb31dd56ccf259823,
+                    0xa089a6ec4ceb6b92,
+                    0x384ce38eee4ccc4c,
+                    0xd21309fc25a2f7c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f88fc974d7c4236,
+                    0x6a3d2a935ffb7772,
+                    0x6adbb5950558dbeb,
+                    0xb9cce487ed676848,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8cb4f98259ea873f,
+                    0x5568bafcd9250188,
+                    0x2f413196c6b864cc,
+                    0xf9f10cd846820333,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x034e9efc0d173670,
+                    0x716fc48bd9341d4f,
+                    0xca8539c9ee9f296c,
+                    0x5beedcc7482633da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b331386be577ffe,
+                    0x3e8d651bc52947d3,
+                    0xd8d20ea4843ff70d,
+                    0xfc798c5e917af3b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe007ef79f4dbdea5,
+                    0xfa0999806335fe38,
+                    0xea3dfe875347e63f,
+                    0x2f71727e3514a7e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x277ba9fae6e3b90d,
+                    0x694651d687ec84db,
+                    0x828b810b93344fee,
+                    0xe7b4e59e5d188764,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73432af83a474666,
+                    0x281544f28b2792e7,
+                    0x622fe87677bffbef,
+                    0x410adabf950e689e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50827b9ee3314d7f,
+                    0xc0e17a5119b207a1,
+                    0x8164b5f133c7cc2b,
+                    0x6d3a608aadbf3295,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5aecc120099eafec,
+                    0xadb412111488ef1e,
+                    0x31acdaead8aa9cdb,
+                    0x73459676f139c8f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f5bdb56d81dd159,
+                    0x0549c52839febb31,
+                    0x1293b990fdb9c8a1,
+                    0xc8bb0d485be85f1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x535a4b7ca7d9366c,
+                    0x9539b96bf1cd3b18,
+                    0x1c7b625c34848d63,
+                    0xaddf861a0af2db52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x147536fc570db586,
+                    0xd5733b2f2144286a,
+                    0x2995e3214fd3391a,
+                    0xac1f84503be03e70,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x947e3af6c913a744,
+                    0x51e7b84eaacb2d9d,
+                    0x27737229164c7b60,
+                    0x312f34ae8155b5e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12659026fa8017c7,
+                    0xa92a25d692516f68,
+                    0x9e9bbb273b581247,
+                    0x36aef708a055f21a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd450b6dfb04f68f5,
+                    0x55909e42d782c4a5,
+                    0xa8af3e1d29d8c7a0,
+                    0x6533c2d945723661,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1517be35b9d00843,
+                    0xb5180029d72f435f,
+                    0x3a8afcbe664e61c9,
+                    0x680ef2915494d0b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe4c98e6a0049909a,
+                    0x934b52baf944c4ac,
+                    0xbefb75a98a0e039b,
+                    0xa6254cd00c5cc7d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c46f5aca35bb9f6,
+                    0xb6a0dddc4bc32971,
+                    0xfadcbd8ff47d74f7,
+                    0xcdceca4244e683bd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c933211ebf34598,
+                    0x22b95580c3f2d371,
+                    0x5e20bbb73802aec6,
+                    0x4500a33141081c19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7bf6b449d2102aa1,
+                    0x4cdc2c6a9b9960bd,
+                    0xb12ff5f1333c9b1d,
+                    0xb0fd9d5763918e8b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4bd648ed41f9ac9f,
+                    0x48edefbc72c30d74,
+                    0xd0722b403d6febca,
+                    0x680592e35f4a2713,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e37509fbd8aaa39,
+                    0x62f38cbc5f0b60f4,
+                    0x3cd4b6d2b628c265,
+                    0x1f8d5e32222f35f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30f8445dad010920,
+                    0x50eb6b748ad3313e,
+                    0x94ad47a164afb85b,
+                    0x26692d35d8ba68c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa35b246b7e94af96,
+                    0x339776b0383bcf61,
+                    0x1cb45a0f56eed585,
+                    0x81efd71d8e284d4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfe0951124802428,
+                    0xc332c7ba02dbf706,
+                    0x1d198009d3705eb4,
+                    0x90c92c4ad624e84d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x890156024b95c175,
+                    0x7c819b74c376102c,
+                    0xa837ffd43cb5f609,
+                    0xd4cac5934acfa076,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf5e9f532cf1f7b1,
+                    0x67dcfa8679587ff8,
+                    0x521988d3878aa315,
+                    0x79a7895857ba37e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e9c5193b86306e3,
+                    0x809fcb1788b8818c,
+                    0x891dfaa7309413f4,
+                    0x0e0a552c0092e3cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee97bb470a514df6,
+                    0x2d5733b29e8de43d,
+                    0x4a2adf48823354db,
+                    0x768f64d64f93b485,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x043d3668326b5490,
+                    0xdc9fcacb74f22462,
+                    0xf8fe8cb998470aa8,
+                    0x33bee2b44ce7b9e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc16a3ba60da9c282,
+                    0xd150fb77044d7c6c,
+                    0xe57ed470d7fcaa5e,
+                    0x030351d591288a86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29c0dade8ab751cb,
+                    0x7c0b5390368cd679,
+                    0x24c131b679678781,
+                    0xe654c986fc5029ad,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xae09d79542888e76,
+                    0x6e7fa7c32c7604e6,
+                    0xd73b863f8c2a3846,
+                    0x6ce5ee40346433b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01d4b1881d331ffc,
+                    0x6e03b09105814c34,
+                    0xbeed62128a0d4666,
+                    0xd51f38417ccb715e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x514bf88925203398,
+                    0x914aa3be4267d0b9,
+                    0x8bb9b08256df3d86,
+                    0x3b9e009ad7b09860,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29b89fa889dbad98,
+                    0x2bd2e0f79a20e962,
+                    0x7ffdc6d486ad0cdb,
+                    0xcc900876b1484ddf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6c1a3c99cd9a2b0,
+                    0x340cdf3b4a54f75e,
+                    0x262092a46b76b5a0,
+                    0x0a0487b90956662f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a7d2ecde0c3c5ad,
+                    0xb4be1e433e5446a9,
+                    0xc7f6e461b1ffbc84,
+                    0x69f4d2bdb439bc56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f0640c3c7d6d1c6,
+                    0x9355b0cd3d6afef5,
+                    0xcae6d65e789b700b,
+                    0x0368466005aac0db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3fa7f6038f422b3a,
+                    0x0d0df06035f477f8,
+                    0xcb6e7caf8e32e362,
+                    0xd23a8576fbcab869,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78964af87a732026,
+                    0x3e22ca73fd23a684,
+                    0x997da2f3cf228d8d,
+                    0x08170398f7af0db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x053a27e0a968788e,
+                    0x5ad85691ac881508,
+                    0x1c01d868b737cc94,
+                    0xb23bad9d7b945d42,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd522ad043c31c6c,
+                    0x51858a0162719e29,
+                    0x96e4bd7b69901fba,
+                    0xc82b5b06a37ee5fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec278ab3078ed0c1,
+                    0x3cb3834e2bfed8ea,
+                    0x0558cfd058d2673f,
+                    0xcbf20acb827d4911,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6dfd5dc6cf6a05be,
+                    0xd109f82782a24e7b,
+                    0xcfba4ec2de13bf08,
+                    0xa55ca4f5d6bfb447,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ab11ab14ca796c0,
+                    0x3f56383c75fb6536,
+                    0x5ac90e3db1be4934,
+                    0x3c38c69840a36e46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f4e9d7682610b34,
+                    0xaa0bdc721b566966,
+                    0x89f620e69e4aa638,
+                    0x0d36c531a4d90c4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62107a7e5472ccc3,
+                    0xe4899ceeb8f7976e,
+                    0x1074af9bfd1981d6,
+                    0x188a94b782395c53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b8db6d047dea810,
+                    0xfbca2e2f9efa5eb9,
+                    0x9ad643170a407739,
+                    0xefc181ba455ce6d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdab8f407f959d28b,
+                    0x8ba8614410a8a5d4,
+                    0xe7b596ec484311e4,
+                    0x4114b412e6a2e5a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf52c0c2378acee1b,
+                    0x91dc8490f9dd3876,
+                    0x12c28b8107a4664a,
+                    0x2cb423d6d90975d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d5ef8019e7ff133,
+                    0x3c54aa61584189e6,
+                    0x2693bae02ba24b58,
+                    0x9417204efd172f2c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1497df9517f16d5,
+                    0x7b4709a121333a55,
+                    0x5c61a7872536a9c1,
+                    0xe23b279345964d61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e44a1df8254725a,
+                    0xbd1f53a0b5c42381,
+                    0xa655d51d407870e3,
+                    0x3ae27d1b36d82c98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc785046f8c636246,
+                    0x1cbade70ea7df65a,
+                    0xe13b48b57a8de09a,
+                    0xaaa9ed8a18fae942,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa38513196ab8c479,
+                    0x0c7c979568f14064,
+                    0x4e66aa245720375f,
+                    0xb0bbdba2f0786039,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x52b79e8424188a35,
+                    0x1a71a17b542730b3,
+                    0x72b744c65ca8c7c3,
+                    0xffa59714cae5b664,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda8472417dab19eb,
+                    0xc549a029d507dbe2,
+                    0x1c61f6339114c576,
+                    0x9faf9d824c94950e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e4dddd047bce23b,
+                    0x9b30b9ab6939e39a,
+                    0xa76a6b4d3975a97b,
+                    0x176aac444d891c6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x582b2d03e2f571e4,
+                    0x17de77ef202bd0fe,
+                    0x4e4019b9b1ef82f6,
+                    0xe4e1a58281737c3d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa054aa3e6af508e1,
+                    0xf93290089c006885,
+                    0x649a5151af2208fe,
+                    0xd2bf0d0329d2e18a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1395a5c7182779e9,
+                    0xf4f1c4bed339eb96,
+                    0xe022f7e1bf9f40b2,
+                    0x01d43ad16d943c5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x130134fb3d30251e,
+                    0x506f79d9990961f1,
+                    0x6fa7cd848b3b93ae,
+                    0xde2f24a8aeee532d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e3c459490c17d61,
+                    0x55fc0f896e540960,
+                    0x6e5b35cb5c18941a,
+                    0x1b7819f2c6dbe199,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21488e2f6f0c7df9,
+                    0x84c4c654cfa0f9e7,
+                    0x5beacc3d356d09ee,
+                    0x9a5dfc284a91fad0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c8215bab704b660,
+                    0x3965f12d954baf71,
+                    0xaa8fe32b0f690609,
+                    0xa0797b51f2504242,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f9c1d72ea1e373e,
+                    0xe47851c31a2f2f83,
+                    0xc0d3ac0011424784,
+                    0x0701dbfe7a23d2e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x867457486ad2b5fb,
+                    0xeaf48793667da862,
+                    0x1c3b5c92b3708e4f,
+                    0x2eb966e4b58e8626,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x14a86a7af0317ba5,
+                    0x31a7722562f0e73a,
+                    0xa48207b25f636b26,
+                    0x3ffb98d36a6d9a7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x405b0602389088bb,
+                    0x117fec99542025b9,
+                    0xe16a8339841c998a,
+                    0x8decf36735656f14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87ca495736b54ed4,
+                    0x0abae72771cc02c2,
+                    0xa111d1262a8919f2,
+                    0x5abb9de3659b1461,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde6d9810c4f84307,
+                    0xa82611fd2e755c99,
+                    0x7c84f17250fe6d20,
+                    0x7a6a5652f5298334,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64e9448157371ee3,
+                    0xdab9665784bc4ed6,
+                    0xa20b8d81bb5d1581,
+                    0xf56ede216a34ba2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ae83845c240f7ae,
+                    0x25ab0a6dee038ea7,
+                    0x9de80b49e880e01c,
+                    0x92f4584c4ac8fdf8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x361efad434e0b907,
+                    0x6084ea32512903da,
+                    0xb2f97817921fd902,
+                    0xf027f8252f5ab9be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c1f1a16b1f74b04,
+                    0x0d4e08d61632cdb2,
+                    0x9644b3453d27d4b2,
+                    0x3eef7bd78b063dbf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21e5fa239184b99c,
+                    0x75c14fe84073dd09,
+                    0xe8c92c0a40a21ff2,
+                    0xef6bec37a86ab2ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefc1f5a8e124dc03,
+                    0x7ef489083e5b90e7,
+                    0x2faa46b9cf1bb614,
+                    0x03ae31185cd3aa8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45d8894477dece2a,
+                    0xf59cf4c10bd0979b,
+                    0x34942e6c59da7467,
+                    0x1503ca204efcd502,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3f2520aace5a193,
+                    0xff23256d6e438c01,
+                    0x389ad7f0d26890ba,
+                    0xd09ddcbf9c1dfa7e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xe26938992d0d3aec,
+                    0x13c77bbbd0c377f1,
+                    0xc2eccfa1f27d8a7f,
+                    0xd6335cfe2c8bc79d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5cd997cb6b2aa91,
+                    0xaca6753b7c3c1c4f,
+                    0x257f169eca024f29,
+                    0x2352004229578150,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x49a3bc50fc641fa9,
+                    0x6f7ffb1c83098b88,
+                    0xda43b37a4c52300c,
+                    0xd8febab46c6ba0f0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd7046da4b483c1e,
+                    0x4dbb64f1be8ceba1,
+                    0x3abe0b15ef5ca7b6,
+                    0xb6ebbd0dc331d879,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9893d99dcb10c31c,
+                    0x238a938354b78b10,
+                    0x26127bc5f8e5bd94,
+                    0x3dc77c54e3734f55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d286e5ed9f80735,
+                    0x6976d83adda07b8b,
+                    0x6005c522a55092b5,
+                    0xb174f9be2e6716b7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0f916ac188d60297,
+                    0x9534e980828a30cb,
+                    0x504092fa284eb08b,
+                    0x2ec01503fb485d15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07aa47ce0be3a224,
+                    0x8c517856f52d96fc,
+                    0x0cacb7d8c09766b5,
+                    0xfdbbfc9ea8dcfff0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8840d554c76ab270,
+                    0xce8cc2fbe45d9b85,
+                    0xe1898a9990b24503,
+                    0xeb778ccb3325ee6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74f559dc7b2d285d,
+                    0x69d1b2cd6cfcedb5,
+                    0x7eb39309955e9373,
+                    0xdde8c8872a96dc1a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7285233f76ce9229,
+                    0xeafef6e043683393,
+                    0x3cf46f05ff247155,
+                    0x56a7abed3705db19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c6ac0723a1290f0,
+                    0xf096ff9166302690,
+                    0x1fd71f7bd5771c5b,
+                    0x78dad1543ea77e66,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6a0f7d84b589c6a2,
+                    0x57716281ab9d3ecf,
+                    0x5d663b9d3a4f15fc,
+                    0xfe17c84b4f791a36,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89aebfbd284d42a2,
+                    0x49b48e141f8dddd6,
+                    0x9e0ce6201477959b,
+                    0x12476a82a80c46dd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6bda023ae11713e0,
+                    0x301f976cff7185f6,
+                    0x577151c443c566fb,
+                    0xbd8f142f511345b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1740e2b33320593c,
+                    0x37c585ba46cafe79,
+                    0xc9a705ad1eea70db,
+                    0x3128383e2e4afa7b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x20080473573bb75a,
+                    0xc2b918b5727090e5,
+                    0x32cbf816a75b32ed,
+                    0xc1e99131db16c452,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5302401d9a4115e6,
+                    0x83eb91315d058741,
+                    0x763c812a31e38432,
+                    0x3d0951e8d12ee664,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4b49961b8c3acf03,
+                    0x3036bee7553b9e06,
+                    0x54d4971ab79bcae7,
+                    0xb7fcbd4065ddd4de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1feda5212e6f0f11,
+                    0xf4df3048aad5b02d,
+                    0x7ce0c78a58397726,
+                    0xde624998fedb29c7,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 310, 311, 935] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 310];
+        let mut opad = vec![0x5cu8; 310];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
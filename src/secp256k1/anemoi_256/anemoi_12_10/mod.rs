@@ -0,0 +1,2906 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 10 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 10;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 13 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 13;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1410928dff802acc,
+                    0x4baf003f9aeaed96,
+                    0x6b9fe86e60f02f03,
+                    0xf85da91534cb8ce2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b39b1f8a9c11bad,
+                    0x6993141946ab7b7f,
+                    0x3e92176434c6f0dd,
+                    0x712202200de11936,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbad6e5c5c5456607,
+                    0x6197af76457bc05d,
+                    0x81cbd980963cea29,
+                    0x93c025e053aae673,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2583c37bf3338e64,
+                    0x2931577d29f5c59d,
+                    0x092c07ec7703f3bf,
+                    0xec756f1ceb7db074,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9214daa09fbfdd65,
+                    0xac70fa3c8070e811,
+                    0xe6a771d74b40da01,
+                    0xbe12ecb09e1e2b0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4f9b3c8879607e2,
+                    0x9fd0e76e6f062070,
+                    0x90394ff0acc02a78,
+                    0x892f6444ff784f31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c9876f8396644c7,
+                    0x3fb055f67f4f617e,
+                    0x2c3f5736ccc5eacc,
+                    0x828192b930f85d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb56b043a12acd4a,
+                    0x1ed7d34230e2dc7f,
+                    0xd5987de04f3a8fb3,
+                    0x08e8971d6f296d63,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58421e2f99f7d126,
+                    0x9e27f5a02b23dca5,
+                    0x4892c8ce9bccaf31,
+                    0x7417ad74b3b58aa4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb343a2915f293b2d,
+                    0x51cd13b70a2204d7,
+                    0xadaaa7ae7879ba7f,
+                    0x87397644e4fb723d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82d106ca6af59f12,
+                    0xc8af058579d8eef6,
+                    0x1324f661380c41c6,
+                    0xaa7f680aee308387,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcfe9bf7770cf4d81,
+                    0x12700eb0794d3fbe,
+                    0xcd97f0c2ae2a941a,
+                    0xbe1014b98cda9a5d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x29300cff439e9b75,
+                    0xe02fa4b4f47dd492,
+                    0x9c18ec9095b08853,
+                    0x2851539c0b80cdb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe61ff299ac3e0fd6,
+                    0x8458a3e477db49e3,
+                    0x7ae0763fc2e08c45,
+                    0xb054e9232068421d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b09b498384032b3,
+                    0xf518c13a4f2a7ea9,
+                    0x42b483f8ea86e005,
+                    0x92103750a5d86c38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf104dd9a30b6f4f5,
+                    0xd425d0e9bca17d5f,
+                    0x28988ab037977dd7,
+                    0x89ca8e53e9165b0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a9ad3b0305744ff,
+                    0x7bc5a091ad4754df,
+                    0x0f1ccd6c05f3973b,
+                    0x52e2c2ec88994bb3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68a5ba71ba58c126,
+                    0xc3bd983e6fa8299d,
+                    0x148838421b85b183,
+                    0x81109d6748468efd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x479f8136a5cc471c,
+                    0x4d27da6ad30e75c0,
+                    0xdd53faee82b1e076,
+                    0x8c7a46914512175f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2f5b0d87cb0346e,
+                    0x25e260e7cbbc61f8,
+                    0x555ead0d2278db63,
+                    0x2226a4fbd2f6c94f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92e1b2ef9aabbba4,
+                    0x69181542fa343ec9,
+                    0x88839d6333ee2056,
+                    0xf21fdb4ca490446a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x700defa8651340cb,
+                    0x30de368798280c85,
+                    0x34ea9605a58129d1,
+                    0x20fd88321f83ada4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x008bef23600e2e64,
+                    0xc821e93630f69bde,
+                    0x0cb9aa0a5d38924f,
+                    0x571b7bcf24b9834f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72a27bc4d31805a5,
+                    0x25adca856b1e8d74,
+                    0x176e6a073d51cce8,
+                    0x6fc1e9700d12119e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0399377f264ffb23,
+                    0xae1fa43ccb22f275,
+                    0x1bee81e487409784,
+                    0xe2ed77763b47b6f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ef5025b27ba22b1,
+                    0x4cee1350cb473ed9,
+                    0x7420b038d25aeb7b,
+                    0x52b307978ef4074b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd87917b8c3fe67b1,
+                    0xa67375865c54d3ed,
+                    0xe96cc8ba48a98558,
+                    0xa379b048ef7496b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc448e3935962f48,
+                    0x7196742cf50d3cc6,
+                    0x61b205d051021d03,
+                    0xe6e410be930a03bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c62412b1bd11a9f,
+                    0x70e2bdba06d4a2e5,
+                    0xa63d8e037d10ac08,
+                    0xd7a4a191bede1675,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x597ae8b371245e7d,
+                    0x5588938fe4fdac96,
+                    0x60f4e4fee52c5335,
+                    0xac11259105662e38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd953ad16441fa4e,
+                    0xabe6f324760a9d05,
+                    0xfaade0d425e53bf1,
+                    0xb56c81e58fd2bc18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cc440572fb964cb,
+                    0xd08c066c96f04e6a,
+                    0x33d67dab7ebe36c4,
+                    0x04811ee07da37831,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63c333b1a20f97a9,
+                    0xf69e3c12f2a17fce,
+                    0xb94ebe15e0a58792,
+                    0x6f57fb7687da260c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb49818fd14e8f935,
+                    0x0d8ceb46b6dcc066,
+                    0x557454b9e962e38b,
+                    0x3adc5b3bfc1eaa37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6abc419d40482302,
+                    0x3291ee5959663b53,
+                    0x9bc5e046af7d0203,
+                    0x2feff33a1e1eee06,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15abb9cfecb59e0f,
+                    0x9324f19d1dd350cb,
+                    0x7dac780c0292f729,
+                    0x79cdbef958882858,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd210de662ce6d359,
+                    0xcff5b873c85400ef,
+                    0x0c386136132d7a01,
+                    0xeb15091ebdfc181f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x321115c05af6eeb4,
+                    0xdeddd116f57b5cfd,
+                    0xc1edc3843b11726f,
+                    0xe93b7f5096492edb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf520187dd32d5758,
+                    0xfe03f7aef098e97c,
+                    0xc29da8b8ea3f5941,
+                    0x6492c352bffb39be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ba16d1675d6043c,
+                    0xe203deddb3cee271,
+                    0xd2738c1fbde16918,
+                    0xcd5703c0da96f219,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x196a6a75f095f6c6,
+                    0x94ca272e862c1c39,
+                    0x1ba7187dce94109f,
+                    0xb38ef06704659d0f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7bfc5f6aff44d9d8,
+                    0xb994c033c2a0dba1,
+                    0x2725d6774df3bb3c,
+                    0xad2eebcfc87b1270,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x976129126f143943,
+                    0x08f3a7c6c14cc99c,
+                    0x511f63588d48d197,
+                    0xd0728a7b71d00d3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x122822bfe78023d4,
+                    0xda21a2c5bfe0fac9,
+                    0x2898e0739b4fc431,
+                    0xc2e8cf8b4a3c9612,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc595d77413e539f3,
+                    0x1040daa6e5313ed7,
+                    0x93f7fa97103bd6bb,
+                    0xdcf02e84d7529dcb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f9616911a32bf70,
+                    0x55b6b2b01394acfc,
+                    0x0b7c909b07f7fc4b,
+                    0xbba46d11d875164f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19c4f30c38fcb9c3,
+                    0x91b0a7a3b5cae31d,
+                    0x27dff52a33ea7e52,
+                    0x615b3ccb71f8bc07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7491ed87b238a56f,
+                    0x72d77440e07c2ad5,
+                    0x99c00a3e06da4552,
+                    0xbaec538212e3bc3d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xeae1d1e689390654,
+                    0x7062e773b1eac4af,
+                    0x720683f4dd8016ae,
+                    0x908fce3d51dc2fd6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d0109cd83e7f01d,
+                    0x12bf88d632ecffd5,
+                    0xec8956652d9a9842,
+                    0x2297266b776dc83c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98c8f23b6df39f9c,
+                    0xd47700a5ee5d3438,
+                    0x0b23e7d04098393c,
+                    0xd8d0e2ce23c0662a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x926dda5873045899,
+                    0x17f435c35f8e4391,
+                    0x35adb57f3004b7fd,
+                    0x23b2d12f0e8e6948,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xffab5a6dda6b5320,
+                    0xd1257ef36fc69f0f,
+                    0x40e58a26c707ffb6,
+                    0xa6df03c9baa20e99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d7b56ed643ab16d,
+                    0x331fbf5d608e4e37,
+                    0x5354724cedd34516,
+                    0xba8f08c1baeaef19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea32e357f06c1acb,
+                    0x064d0277a5ff4227,
+                    0x5748e44e061fc86d,
+                    0xddd84a5e3bbdbdaf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9fa4304e0d9eeba,
+                    0x14e4fc0669e09327,
+                    0x86bfa84e46b38be0,
+                    0xdf39423786a3aff2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1db1739bd100e3f3,
+                    0x9f8a4ca1afd1e9a1,
+                    0x7474224354c4518e,
+                    0x92c3c4c9340245d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa283be5996983b36,
+                    0x65cf62c16f0540ff,
+                    0x3cbf4ab5f2fdd409,
+                    0x02d5e4054083becd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdba97ae532e5bb7e,
+                    0x1d106bb025397a06,
+                    0xa1746eca96408bc5,
+                    0xc9e91f8be2ec724a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44532b1b3e80e20e,
+                    0x078d385399fb40d6,
+                    0x131b714568e60de9,
+                    0x7f82fb55ffe3ac3d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf950d478d8563829,
+                    0xeb5a20867c63a3f6,
+                    0x7c1cbff475e03b74,
+                    0x4877b6bd5683074e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f1a61862fcaa2a3,
+                    0x485eadb70c6b0331,
+                    0x9790d971ccb7a73d,
+                    0x663082ba26575c73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30e4473af7e9e612,
+                    0xd1f06b438fc1cf1f,
+                    0x39d4e1941eeb36e9,
+                    0x588dd34de6238900,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x023c52e155d34e61,
+                    0xd7a49e8b8637819f,
+                    0x905f352d00489f74,
+                    0x76166053d8312b23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85c7a3810499d69a,
+                    0x522a943020e34061,
+                    0x2dc15054a67c2158,
+                    0x0c55b2da274517fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb3f664b5b4ff639,
+                    0x7faf65d866f3017c,
+                    0x44deec386818d68e,
+                    0x93bbf1da97bbac7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82013834bfa54ee3,
+                    0x088f96ae6891f1da,
+                    0x4923ff3544a99b5a,
+                    0x7afa7023ab6a0478,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b922c27d676cf48,
+                    0xcbba662201eaa054,
+                    0xf0f90efaa7dc821f,
+                    0xe2b9344a4d761fc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc9b0cad8447e4db,
+                    0xc7dc120882ffb6b7,
+                    0x0ea6dbec66063c1f,
+                    0x520e0a9d262a8e6c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa982f4f6a715b6e9,
+                    0x240bdb07dbe97117,
+                    0xcceb5abd8a7a3158,
+                    0x2af9e334569af1f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b4e84ab3df87cf7,
+                    0x5fa801f07d0f123c,
+                    0xf17137342ba47d45,
+                    0xe86cff915b154a87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20d58b9f001513cd,
+                    0x2ece458f506ee383,
+                    0x6811de26d624f14b,
+                    0x1313dc8cfed841c9,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9722052a0d4fb569,
+                    0x483307c820d7dbfe,
+                    0x81e35cb7683a33c6,
+                    0xff2492f3a9c7c4a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2339da9b628b3f4b,
+                    0x8453cee967fe436a,
+                    0x054e903ae5952be8,
+                    0x4183bb14d5fcb0a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa25cfb625f1af922,
+                    0xb5b1a5b129396cf5,
+                    0x37b079d55673446b,
+                    0xef26c0514d48d5b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x498a44b0d04cf1d9,
+                    0x7a9109a3080b4ef9,
+                    0x836562886d1493c9,
+                    0x4e8360688aa64083,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3cf70fb1547ad149,
+                    0xd33a04e8b560a044,
+                    0x06b6643ef40d1ace,
+                    0x51b05934c8645a6f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4321fc9c27042bad,
+                    0xf1d55ea3578f3847,
+                    0xe53a43a33883bb9c,
+                    0x4a452459051a6f66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bb9b21d4f797b97,
+                    0x0bacd30e44b8d03c,
+                    0x215173e63926b9f3,
+                    0x27cd2a037d634cec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6eb782289f53f637,
+                    0x3af37e9b90177264,
+                    0xe299df97bce326ac,
+                    0xb4fafe301a007e39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4562dc18a57e2813,
+                    0x765ddb4a5e142b8f,
+                    0x1d0ea86d7fea6d52,
+                    0x64fdb7aa1b6c6cd3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0446036dad71c090,
+                    0x3ae08637d6596753,
+                    0x8850b51bf8c98c09,
+                    0xe054b8d6b146d31d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe22536d4affe172f,
+                    0x733738fead5e6018,
+                    0x21d3b6ed8792a48e,
+                    0x47e0c07b9fe7ba65,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba324416530bbac4,
+                    0xcb342f2af97f3626,
+                    0x50d8e9086bc7e66f,
+                    0x57e5f4884b5c924c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf23e7b5e5a96e87f,
+                    0x913ad44765643183,
+                    0xa40ec390c433864e,
+                    0x47342cc7e0acc667,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf033abea8a9ef1aa,
+                    0x7f2b9068f5184e9b,
+                    0xb0839299ed02b6d2,
+                    0x5b7afeb5987a703d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95c26b36a6782eff,
+                    0x863c60688dd995ab,
+                    0xa50fc7a9edef6f65,
+                    0x4f2a03535645f9be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9df5e2816e881a9a,
+                    0x159aaab5b874d93f,
+                    0xc9ccac95a67ecf64,
+                    0x00b96991e02bbbcf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2222299c0cb5964,
+                    0xd0a0e90810ab0da5,
+                    0xd21928b449144aae,
+                    0x6f5c49d5a92d4950,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e0f0587af6bf810,
+                    0x3cad4da31235ad86,
+                    0x7023cb402762df81,
+                    0x8fc3f6471577698b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fc5edbaa46788a9,
+                    0xb5566fac9020d8c0,
+                    0x0ca9aff04bf20659,
+                    0x76903e0ac8011a66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57d4a1766f02382d,
+                    0x1bc6526e4ed4bd23,
+                    0xbab085899691406b,
+                    0xc03fd017a1649a1a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79ca876fdc4f4ae9,
+                    0xe48b374cf664390c,
+                    0x4fed47ba6f0a4c47,
+                    0xa1496bf9c34258b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8bdf27b36abc8d5,
+                    0xf3a6f8fcc6e521c1,
+                    0x030bf69caef211b7,
+                    0xab45adf399bd3f5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd358459f8c5c28e5,
+                    0x9b73b9796d4cc387,
+                    0x55b09685a7331c32,
+                    0x88428f8dd9e3c989,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7e7e61a8952426f,
+                    0xb7f40881985f6c81,
+                    0x5f84632a0951a930,
+                    0x8bcd777ef54a9b37,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xab1d27abb0467fd9,
+                    0x585a4f0ae6e80cdd,
+                    0x03a7f08d2c5ada52,
+                    0xafd3c9b4aaa2b75e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbea32c45dc7b209c,
+                    0xf8edce6e66abab6d,
+                    0xc907d56c8969148b,
+                    0x91d423b0be2c237b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b28e014257c6822,
+                    0x68e8d10e807fdf9e,
+                    0x623eb57df22105a2,
+                    0x64cfd8b26de745e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf98537bef64da39a,
+                    0xb6a66adf8689e245,
+                    0xfa2f0d91a3b7b803,
+                    0x7ba52f7012fe11dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf5322c03366b825,
+                    0x20ed3bf95afab6ab,
+                    0xbf3fac5437634af0,
+                    0xc83fa8fed3ef0856,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01b5b59e4890ceaa,
+                    0xd1fe4c982b8e33cc,
+                    0xaa170cc38dcbcfac,
+                    0x5ee6f78a46566adc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41f773718ab926cc,
+                    0x69fbe85e11d30568,
+                    0x19f7086b1c8114c3,
+                    0xa6623f3e6978bad8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd661be8bd467ed0c,
+                    0xe6e8a1a9d4152f5c,
+                    0x7021ecd1d44cdf28,
+                    0xda025982b455d60c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed17e60668bf2f00,
+                    0xee723208ea864825,
+                    0xac5a589ba7ab9c0f,
+                    0xd5bb02cf9a4f78ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf05cf53c88e823ab,
+                    0x98975b0568c3f1cd,
+                    0xdf5c060f0de46c8d,
+                    0xc8a07c15e29eb639,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaebb5b670e98f65a,
+                    0x658a864ac267449f,
+                    0x731f681bee219242,
+                    0x57416f229bed2421,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88da72728c577efa,
+                    0x57f0a9fe66283fe1,
+                    0x5d5cc34355aba1d5,
+                    0x7307f885e4fe283e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xffe5557108da3535,
+                    0x844b4ecd67ed9a86,
+                    0xd37b57c099bdd73e,
+                    0x13a40a657f77d142,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc73a06eeabadc91,
+                    0x17bea1be83ed12f9,
+                    0xde7bdf5a6c47352b,
+                    0x4e22b42be22ab802,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1323c92675842ab,
+                    0x362d30044b9f3767,
+                    0xaa069425d25624f5,
+                    0xc18ad98fdac2b8be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc20288e9d517ded,
+                    0x758a8339cf14167d,
+                    0x4911a800000fde67,
+                    0x2e16b91c5da21ace,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf43e26a5502566eb,
+                    0xa0f9078b8a93bd93,
+                    0x985f51423dd82fe4,
+                    0x77d55a480e9563fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a1fe5c963974ff4,
+                    0x3b7d8c12f41b2873,
+                    0x5ea3e0d3396d089a,
+                    0x7df453bd51d9fae5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f6fb9bb5814c135,
+                    0x01c59cfeba9d5c39,
+                    0xf61a72cf70323fea,
+                    0xc9b6dc081427c6af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ab836b43ed03663,
+                    0x6e7be02b2194f5c9,
+                    0x4a5fa9319f6f422c,
+                    0x618c2d053d3a1f83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x676923bdebded269,
+                    0x365a2d87f17b7d2e,
+                    0x43300c234b6e4756,
+                    0x8ca40b7c0ce9aa5a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6ad412f7857bc66,
+                    0x63e3b55971fcc548,
+                    0x61323d915dc2effe,
+                    0x5006651072dc1608,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf85e7ce9e8b2f7ed,
+                    0x7d378530f25ed630,
+                    0xc963c2578701f84e,
+                    0x2784d2f3eae3bb76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75ca7e52e1d6dda6,
+                    0x81e8aba63e2ccfb4,
+                    0x5eadf68bfe247b7b,
+                    0x28f97e3f64c21cde,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdb6cc51b68ae8591,
+                    0x5072c8c5e5d2d5f8,
+                    0x9d8561c918a9ed40,
+                    0x35bb224edbedfe1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc39ce41a334ed07b,
+                    0xd25555d7c7ea7d7c,
+                    0x500b3db9c9ba199b,
+                    0xce33275fdcee5cc9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x492f511d1a06b504,
+                    0x47838f5538da2a7f,
+                    0x1d4eb161f0ef80a4,
+                    0xcb771dce9c2f47a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3e23772c80d1f24,
+                    0x89e509bdb3ae8328,
+                    0x6a80db03bb47cdd4,
+                    0x25a4ff107ba7da33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2e45a96f86d6e77,
+                    0x0b4126b760269def,
+                    0x0480902789497a23,
+                    0xb269aa75207fa050,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56a797aef9338062,
+                    0x8fe15ca40262e9f2,
+                    0x5f68c393582f113d,
+                    0x410dc0b28f146a19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89ae092dd5d92cb9,
+                    0x3499a7eada5c4c6d,
+                    0xd77e3b86f95c7930,
+                    0x9c5dfd352f8a89b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c2d79f1f10bce90,
+                    0x3330dc248c5c7f53,
+                    0x63f00eecbc96d222,
+                    0x1dc376757849f73b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c4eb55864a91c97,
+                    0xb17b7cbef42d2161,
+                    0x260862fa97855ab5,
+                    0x377b378a6e270e7a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87fcdd3328847b90,
+                    0x828ec517ee8c0fb7,
+                    0x87361983fee6038e,
+                    0x947eae3ad030e9ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3edd1a6fabb61c5,
+                    0xf6a6b3efd45722a6,
+                    0xb1e98a1dd7044bef,
+                    0x4cfc3eb90acbfb61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc5b205d0ffa682d,
+                    0xa6cd0ed421ae8e6a,
+                    0x99a7e4a572aa6c15,
+                    0x93bd8f5392b0b159,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa3a5efee18356eec,
+                    0x9e826bea3c8913f3,
+                    0xb2d95c315fd9c05c,
+                    0x6caa1d5a8581ed05,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdeee199be0a5f092,
+                    0x82126d19292ac3f7,
+                    0x4fe9cb203b7d87ef,
+                    0x394360c8b89da631,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fd2e1bfe8ba5cfc,
+                    0xd4eeebafbb3fc0ce,
+                    0x02665d9a1dedc4e0,
+                    0xd013915df8b18b5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa47561915777989c,
+                    0x3ec34d8938005095,
+                    0x58d81e1334b52893,
+                    0xd7f3d0abb2a31486,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f33ea7da397969e,
+                    0xefd0800415444f24,
+                    0x2f5325b575d922d6,
+                    0x66fc6d00b18d5da0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf568058da980a0a9,
+                    0xfefb54db75e463b2,
+                    0x5227035fa464c2fc,
+                    0xae397bec1bf6b659,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ec2db7448ae0f04,
+                    0x4e03b61b9cbf3aea,
+                    0x43ba8072c5492189,
+                    0x4a6637cebdccdf42,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0af71a6bda285c5d,
+                    0xee9f94b9bd34315e,
+                    0x8fb5c7d47bbcbffa,
+                    0xf065c6a2bcfe4f6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x336e332eab3ef714,
+                    0xc339fadf03aa8cd9,
+                    0x061dcc670a651217,
+                    0x2fd882aa85f6121e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3dceee12425f2e37,
+                    0xc2ae9857dcc97f07,
+                    0xb9d91b3274a3371b,
+                    0x037135cff6707d3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61e76f6a520240ae,
+                    0xbc182aabf60f6640,
+                    0x0db71eb07ead8751,
+                    0x4f42765849d3d201,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc387cdf48b001f6a,
+                    0x3c2be7fbf02c107c,
+                    0xc1cfcaf6a5655ba9,
+                    0x538347c9a78db793,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6da5b6a297aa42cb,
+                    0xf8a0259b8ad3ef1e,
+                    0xfd6b9c0c241e12a0,
+                    0xa15591e2e0ab620a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3124f2aac3e71adc,
+                    0xd2394eb55b31fc49,
+                    0x22dc93fe095ba7e4,
+                    0x7306e5a4eea030c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x624858fc472e11a0,
+                    0x4c708047490357fa,
+                    0x583c3bd7f3263110,
+                    0x33c560454842aa32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27ca875bc58cf7b8,
+                    0xb86390254441f296,
+                    0x84e7a688910ab7c7,
+                    0xb2a9692cbd4beec4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99abe17dd2db075c,
+                    0x5208d7891274d037,
+                    0x5c8fbf1a207e2564,
+                    0x82e140feb178458f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cf5434790b7df95,
+                    0x1fed19b3e9cb9037,
+                    0x7cc677a227c7e7ba,
+                    0xa704d2cfeff2f328,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a54690ce491bf1f,
+                    0x1c8c6a148ee6fb79,
+                    0x669676a44ad2c1dd,
+                    0x1bf0aa582f675696,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac8d6c14b28dc653,
+                    0xd1d79f686629c4a6,
+                    0x56093b01447360e0,
+                    0x20fac8deb39296aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93309f734efc4bfa,
+                    0x47a54b8326adacb7,
+                    0x100515a4572897db,
+                    0x58a26e46539a10a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6de2a7d7dc3d7e4,
+                    0x55716d3e100abab3,
+                    0xc429fe405b8d7dd4,
+                    0xb7048d6943f463a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50f99db3896480ca,
+                    0xf85f83b14b5b6299,
+                    0x593584d8979ded34,
+                    0x361b42218dca1e03,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0e6286422b16156,
+                    0xa25a73aaa89a382b,
+                    0x6757a5cd9a08ed99,
+                    0x06b4f5c00a96c2ff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xefc1cf4c0024b767,
+                    0x1f46fbaa332177c5,
+                    0xba4c5972c071583a,
+                    0x948ed5719895474e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77400eed08027757,
+                    0xbcf25001b9a54b66,
+                    0x5cf1f492f3eee46b,
+                    0xefc0f44b99d0faa7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab8669f72f28e3db,
+                    0xae8aedd363fac2f5,
+                    0x5dc416d1e4fd0350,
+                    0x09db888cabd91c9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1541c0214e8eda89,
+                    0x951ef74db80df12d,
+                    0x47f7329bb2a2f1f3,
+                    0x791e409cfef94a24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee9b2ec997d415f6,
+                    0xbcf2b0d7d2519b1b,
+                    0x1fcf5bed37dda5a4,
+                    0x47baed52d38343f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc8dd196028b55fa,
+                    0xd5a0f47899a0c54b,
+                    0x7be0fec49b9f4d94,
+                    0x56c0b69e8eab3dde,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf03d6559b76c79ec,
+                    0x9dfbc3d20f7281ac,
+                    0x01c5295776e6e909,
+                    0x7198fffe5ae1d5de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7954939bd996207,
+                    0x8e27b2a9a8243bc3,
+                    0x40017f818032d15c,
+                    0x5d7ee8b0883fa4e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdfd39a5217452c4a,
+                    0x48f543cecafd842e,
+                    0x108143efd5b487c2,
+                    0x78703c1c04530957,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd99f9c641396782e,
+                    0xe819d6fe33a433c5,
+                    0x26e21d48959fec92,
+                    0x6943b7750f72937d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62296059f042f0cf,
+                    0x7962709643896258,
+                    0x0794835832dc4ca4,
+                    0x30e3c09a15def40d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5810ad740e687c8,
+                    0x3d31dc912db4bdb8,
+                    0x59ab6a108002e432,
+                    0x7277fd32baa62fec,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1c7f017ced657659,
+                    0x7b8902991e3d29c4,
+                    0x1a9a75e2499d4eeb,
+                    0x6b635ebdcff1b4d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xffeba1a83e339be9,
+                    0xc75d4f86dc5c6e77,
+                    0x7429ece13e2c52a6,
+                    0x83749c5c496793c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5372b118c60f110,
+                    0xd476c9ed38db67d5,
+                    0x627af7461166cab0,
+                    0xe0c6a7d1972cf65c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3fb41d6ea229b26,
+                    0xe1595849113f61cb,
+                    0x3aa11698c68e91b2,
+                    0xdd60931e957bbd82,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54d316ca22cf3fe7,
+                    0x7ff31d4cc15ddf8d,
+                    0x513bc945944d8a26,
+                    0xf833ab5738e49f9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f64360e18cbb1ff,
+                    0x528771a8692f276d,
+                    0x923847af5abce8cc,
+                    0x66aabfffe18baae0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10ccd92ff2c912e9,
+                    0xedf4cb47859ffe85,
+                    0xf832b71ca2a95c83,
+                    0x0a919603d50b669b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ef8cb8dd97316a7,
+                    0x5b87a161e3a2d535,
+                    0x18bb2a9c1f0256cc,
+                    0x873152cad7e603a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d4c64e343cc2d4c,
+                    0x4475cc5247edd148,
+                    0x2fbd0b8dba616eaa,
+                    0xab82941b67877abc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8e9c51b397dc142,
+                    0x91ce0a00acb19f07,
+                    0x7d47c10ac9d8c25f,
+                    0x9bd358ac655ffca8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4e592053ccd57f6,
+                    0x70ce1f4ef22f8c1f,
+                    0xb683cde6b0c97d6b,
+                    0xe52b966192b3de4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa179abe7e158eff0,
+                    0x07fac63653fc78c7,
+                    0x8b38aa3c20934720,
+                    0x3bac907a58463361,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa9d0b891b9a6df58,
+                    0x35185a90df8a6ae9,
+                    0x94feee98b32b3b17,
+                    0x413fa9ec23ff478b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81b40ad48cb2f3ba,
+                    0x7794ce5c9a17cfda,
+                    0x3139e6982cc5d2d9,
+                    0x28a58896735ab501,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4970bb53e3361965,
+                    0xa56b23baf50923a7,
+                    0xb4e58d4062ac09c6,
+                    0x2dd96d2a2c0078d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1c89b84bea67a13,
+                    0x1055dd471786e1be,
+                    0x8b88aa5299af58d1,
+                    0x71ae29f607f64ab4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f5a0ad60215deed,
+                    0x8430e9191932cfb2,
+                    0x9c7fb8daf4bc9232,
+                    0x6640c785f74ed07a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ccbc4ebc70a1d92,
+                    0xaa3bd24c10794298,
+                    0x936e0fc29f7d4498,
+                    0x0b3e56e784102823,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7bcf6b8cba150bba,
+                    0x6ce62f934d192d60,
+                    0x9d108b57369ea76c,
+                    0xbf5f6290a43cc4ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2983d17572fe16aa,
+                    0x239553d21f440d9c,
+                    0xd7b93302ba38fd88,
+                    0xc2c6d76ae75b7900,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d836f3b2f8f6a1f,
+                    0x2e71bc5a57d029aa,
+                    0x8244e8fd26706eed,
+                    0xa6ff94227dda788b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14441269ac0199e8,
+                    0xe2dd0190323a7253,
+                    0xe134f66a107c269f,
+                    0x178860f7c57a4681,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4608de42846d419,
+                    0xcbbf9af383c67dac,
+                    0x1459571239e1dae5,
+                    0x4dc70945abb52bfb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc01fa08a2eb0f5c3,
+                    0xc3964a44977c68ce,
+                    0x4dd04cffe308ecee,
+                    0xcdb6175d9f0a09ea,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x20ecfa3d894f6447,
+                    0xecd290c8633d9af6,
+                    0x1c05585fe5f205de,
+                    0x58baaf63e4f8f3c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabd557b706187e83,
+                    0xfe876ba3fe08157d,
+                    0x25bf21a5da32e196,
+                    0xb045d6a124f5854e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a20f604d498327e,
+                    0xfd368afcb052994c,
+                    0xad3d06c6769d97b9,
+                    0xa525b034c7d48e78,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x993723680a3f1327,
+                    0x48d3c40b923968a6,
+                    0x55c71a7c732b9bfb,
+                    0x2c94f23499e04e9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69cd7aab9cfd901c,
+                    0xfe3c2fd65121aa67,
+                    0x7f5339c7ba87eaba,
+                    0x8f4eb815771117d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec37074e318910fa,
+                    0x1dadcfe7956bebea,
+                    0x041f9c65db65fb15,
+                    0x6d10f75c4fa09533,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3d84173d2caaee7,
+                    0x4e4830c4a966b880,
+                    0x009d3586926b194a,
+                    0x87f2934e7cddbc99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40302b58b4c5b988,
+                    0xc5eb50945d123cfd,
+                    0x8063b425d4cb935a,
+                    0x64d8df34bed70363,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb0b32d1255e660d,
+                    0xa05b1bceb9dd1175,
+                    0xb566de6a5048c988,
+                    0xb05398a26fd8ccf9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xccd40651cb031165,
+                    0xa6345ba8e094b03c,
+                    0x2ef4f88d47e9de4b,
+                    0x0ccd2caf44031141,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a82a6e44b68c85b,
+                    0x5f22919cc5915e4b,
+                    0x07ab912d412787e8,
+                    0xf1bce8e6a9d656fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc994fbb900bef130,
+                    0x334ea32d8745c350,
+                    0x79b80737ca546130,
+                    0xe9e7378627673713,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb821bb90b1e09107,
+                    0xda6b071d09f93e2c,
+                    0x3639045c261bd8df,
+                    0x435b99acc16ea8d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ec458671edd68ef,
+                    0xf970aa8e7494ab6f,
+                    0xb6cf300a59767889,
+                    0x2f42f2c4a73890ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61a56d965eccbc18,
+                    0x9cf386dbeca8a58d,
+                    0xf50e4a7583006c46,
+                    0x77ab67693bb81e4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3151f7ece9555402,
+                    0xa11a584f4a5b23e2,
+                    0x6a729e77f777553f,
+                    0x66c7e7761477007b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x087bdd616c9de0ea,
+                    0x2d0989a9dda21d57,
+                    0x3ae3db9bcfb361da,
+                    0xd65b388ee22934f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f8c9fec04b50445,
+                    0xd2df52553085a363,
+                    0xf0e3e602e3d54ca6,
+                    0xce398cb5ce0cd411,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaafe21ad8bf7ca95,
+                    0x00db00457c0d6a92,
+                    0xe79b60e7498983c7,
+                    0xdd35e64f42880d04,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bc4409c123acc08,
+                    0x25b698ebe2c3cb2e,
+                    0x895aa1e6c87c0862,
+                    0xf3a42bec10d10af1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ee768e8f0e97311,
+                    0x8fff443c7391c884,
+                    0xacf27c2d65b6e6f6,
+                    0x2500a53c7a2b6024,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa42ebac5b5bcf688,
+                    0xca5bc6c819a87dcd,
+                    0x5f52f1c013487d11,
+                    0xd18c41b81bf01227,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56ed624d3942dd23,
+                    0xe2d1bbc7a740fc0e,
+                    0xa07a1a8db09476c8,
+                    0xcaa65c82d3e93a8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc7ed9bd19e33c6a,
+                    0x378f49a37702dd61,
+                    0x3f5789f2b47e7bcc,
+                    0x4fde586e517c4ef2,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000001400004c54,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x12e8ccddc9e5f9db,
+                    0xeb7405a0a9253013,
+                    0xf8110703dc3d5627,
+                    0xf2d572e3b44e5b3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1428fff208bd49ba,
+                    0x035a3fb98cbfd8b1,
+                    0xdc74be4bd1368c46,
+                    0xd5670ecc286ac990,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1605d32755e9b309,
+                    0x431b922145219165,
+                    0xaa3282687187446f,
+                    0xe3fb12f9d4dd4528,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9257e08327d0bb64,
+                    0x3275d66ebd772d3a,
+                    0x0d3187bf9b084cb8,
+                    0x25b227ff1bfb870b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e4efc763f62675c,
+                    0x75437f2910208658,
+                    0x33a66d44000e2894,
+                    0x0cd5b871d7030d8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x882723a25db2de8d,
+                    0x47220a7d6e497257,
+                    0x079d7a4fccbc2d8b,
+                    0xff1b2a8e98d6db85,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcab84214a9490dcd,
+                    0xd25e003b8ed22990,
+                    0x839f63a6cfd9563b,
+                    0x4044b2b85b9020d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f8ada5b99de9894,
+                    0x896ae158eab0f52c,
+                    0x8255ece1f285a24b,
+                    0x1e60a2058dbc133f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5c2beb058322feb,
+                    0x6e27d65e77b39743,
+                    0xfc0cf7f34dc5aed2,
+                    0x29b5dc2b26997a18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc3acdc9a186deba,
+                    0x9ea0bc3f5af65f98,
+                    0x358adc4ffd088e2b,
+                    0x19d4d23c2e9de88a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5d5da2b080bf66c,
+                    0xd0772263190f2992,
+                    0xfc31385c664f35e9,
+                    0xbfda03a549a0c679,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76316450fa42a7fb,
+                    0xc315e97515aaef1b,
+                    0x277066a095411543,
+                    0x59ab00d6f214d5d5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x92f6f01dd645cd03,
+                    0xeed92167ae4b7814,
+                    0xd6ba2127eaf31c1a,
+                    0xf586502cab6a8761,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a48682c8fbd5b79,
+                    0x06739375c1fcf772,
+                    0xdb0825745d0fe96f,
+                    0xb0ff85bced7ae6b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x791a62e28027e336,
+                    0xdb1693e85307b5ea,
+                    0xc5d6d2f72786302e,
+                    0x3a7d5099f5d96e92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb1f4675231999e37,
+                    0x180d97351be2c606,
+                    0xc7ed406be0bc6b3e,
+                    0x0140a057e8fef5df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50993c9c7dfd2457,
+                    0xcba2494930256b2e,
+                    0xc2fcdb158d4f49df,
+                    0x232b23e1e8478e45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2154eecef1de5fa,
+                    0x3d3591090fcc83a4,
+                    0xeac3b5d19831fbf3,
+                    0xedf95e279409f20e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19e2d07561b9573e,
+                    0x5ad4238e5d6aa34f,
+                    0xae73330b2bb03c9e,
+                    0xbb373f87d8f02bbc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94a6354305f55e82,
+                    0xfcd6e1e8be26c4d7,
+                    0x3db15f4157a86066,
+                    0xa7359e4537e24374,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd221bebfe3dcfa6c,
+                    0x0d8700b948bc19e7,
+                    0xe59c9956a759cf51,
+                    0x5e0f63bb83fbdf5a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f976e2037b53b85,
+                    0xa68bc0436cf35fb4,
+                    0xa2686ad7c6a563a2,
+                    0x3fe9d9d47477dcd6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8e3053d91b5f168,
+                    0x0a499e17f8f7545b,
+                    0x4d5fc827eb9dcb14,
+                    0x11f7c0989009b39f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29a111ee3fe53efb,
+                    0x7581fc374b0b7723,
+                    0x4ebdf6e6cd15dc5c,
+                    0x80ba2d09fb24c88b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5075e20abc18f2f5,
+                    0xb49c4d7e2819a13f,
+                    0xa4363f70dc296ffa,
+                    0x47b117bc9a8c4108,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc5004a72094cbe4,
+                    0xc187df6311c839a1,
+                    0x462f76c273d5608c,
+                    0x62d5a358cbfed47a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb33d5aec5a513515,
+                    0x95a42ae4f44d6da3,
+                    0x17b0631fe4ee188a,
+                    0x6f19c0d425ab3cfa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07dc2b7d8e32aa27,
+                    0x26a21e252a11f60c,
+                    0x40a2fa8030ce6f53,
+                    0x8d10f623acddda15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb5a3a5f374e5237,
+                    0xd0f4d8347a7b9019,
+                    0x50188213c2ed09f5,
+                    0x42ffb2d124aaf8e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe15d9ac955d8848b,
+                    0xdc74f3c4de5f623a,
+                    0x9fc889eaff146fd4,
+                    0x039f76bd2b2e65a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96c63a86d461273f,
+                    0xd442abaec0120718,
+                    0x23aa4ebd6cec90fa,
+                    0x04a4a0ec7200561a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8a292f88fb5bb2f,
+                    0x138f0dca0d10efa6,
+                    0x6ad3727500409d2a,
+                    0x458ad0aa1eb8f6a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x801616b9bb82d810,
+                    0x36c899e7d317a14f,
+                    0x505684776055d136,
+                    0x07510f26f9bf021d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbca18ed2083d6b6f,
+                    0x71e70682d96625e4,
+                    0x6ba107ee945decd9,
+                    0x5dd32ca62f99a5aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e54c101fbf0ffd8,
+                    0x983894c9b0d19ff3,
+                    0x460ea8021fbe1f14,
+                    0xf402b0f3be38b4eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ca7c967f5b03414,
+                    0xc1fd3b73a6ab54ff,
+                    0x692b0b774f9626a6,
+                    0xe37d5a98683058dd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9882372d9f4022de,
+                    0x70a1848097aecac4,
+                    0x961f2112f3510519,
+                    0xfce410e4281f9523,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98c5f018f14fda0b,
+                    0xf0826932ca1da5fc,
+                    0x59d83c9d7fe7e963,
+                    0x04423fc900c5c5d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17c266e1b0bac8df,
+                    0xc239a07d9450748f,
+                    0x73fbb1bae501441e,
+                    0x3962ce739240794c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ff171860f399589,
+                    0x9e0685b8a0fd0a11,
+                    0x69d11d536975fe11,
+                    0xdcfcd7d0643b0fe2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a4f728e10d5d7ef,
+                    0x4923e6db87018f2e,
+                    0x8181eef9e84b193e,
+                    0x67285c22c08fbfbc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb682d5ec7965f07f,
+                    0x4691efd89f42f297,
+                    0xf45a8fe42082489b,
+                    0x1bbfce31a5c9ca7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd057888da0dc0b,
+                    0x88f11175e0e2a13f,
+                    0x82772091b089d920,
+                    0x684677c359184369,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x638bb00763874b99,
+                    0x78e59ca4b065ecca,
+                    0xf7710361df251048,
+                    0x2c3b67b663f118b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58e8e2daa5029ddb,
+                    0x758d46d75c101df7,
+                    0x977cd6d557b2f33b,
+                    0x83cee87e5728d3bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b27519647061be7,
+                    0x9dc47a5f640e2352,
+                    0x6ad66590ccc5c69b,
+                    0x34e6d07beb056a19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e1fecd54daf069d,
+                    0x89d2103db36d9359,
+                    0x5b40efd38fcc2b2a,
+                    0xb9bc6d31d2658455,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x658e5d164d4fd59a,
+                    0x378883125c826a80,
+                    0xb90cd45419b86051,
+                    0x2c6e34d02fd6d8de,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4a4f52db70571fa1,
+                    0xaf8c7c2dd152fa79,
+                    0x35f5c394b9b796b6,
+                    0x05b4c40afa065559,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab72a4a29cedf7fa,
+                    0xf07e7654e81acfb6,
+                    0x8e5c7f3d411d02d1,
+                    0x4e1a2eee82a7ff9e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d3d98304f144c80,
+                    0x4ebf77eb70053895,
+                    0x0011c3f421e4471f,
+                    0x11331cd55c085c18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa34e17ae7b2134fa,
+                    0x3b5002da48ad45e4,
+                    0x1db85bd9625d85c0,
+                    0xb640a050fb7de681,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd210721fe74a59b0,
+                    0x8386afdbe75c2f8f,
+                    0xd35b26afb4a91c4e,
+                    0x0ce136658bdb3daa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x320c71db00af9d86,
+                    0x5c7cc2ce75f53638,
+                    0xef2d53edc97090eb,
+                    0xa5f4f6fe8e946ae3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32bc80105d4b7256,
+                    0xaba81a506bb7fbf1,
+                    0x459f262b63989516,
+                    0xc3bb2af55448df08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92156817302f86c2,
+                    0x5c1e905585abf24c,
+                    0xe7a6ebe25dd7b6e2,
+                    0x3ec679e3385a82ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26aaafddcbe696dd,
+                    0x781ed39f0e1bd433,
+                    0x689930b73b6988e7,
+                    0xf63bf938b0cddea7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa491386414893187,
+                    0x9bf8ba68a221b7cd,
+                    0x298a34f356d2a7d3,
+                    0xc5505e730a8139d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa95e937942d897f9,
+                    0xce9a68e333a71f95,
+                    0xe9ea14ab0ac3e199,
+                    0x4c221fd0ef4291eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0685475fa13da2ae,
+                    0x9d9aee89bddc5631,
+                    0x5db368517b787118,
+                    0x6516c8cdd8e6694f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x38d54ee9cdcd2a42,
+                    0x018b45cce540d0d5,
+                    0x41c83bc34bf1a068,
+                    0x79e1c792acc697d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44d8a8542eee4662,
+                    0x6ab82757e54ad7fd,
+                    0xd08e5c8e597c0079,
+                    0xcff84c6537d29ddf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbe0399ed3633102,
+                    0x2d62e8657f5aea7c,
+                    0x2bb449d75039294b,
+                    0x06c6dc5ee15abfa0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cb0eee6feb4199a,
+                    0x3d56735cb44e5e92,
+                    0xa147ec81dc6ce4e7,
+                    0x6f91572810ecdf60,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ba605205469fb3b,
+                    0x4c4c4fb75bd43c0d,
+                    0xf1a6b9f7e7843827,
+                    0xeabbcf3036c6e198,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x241222fea8bed029,
+                    0x41276814f073dd88,
+                    0x9557e252d5df359a,
+                    0x861674fd274fe90f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x135c6ddad2a5b66a,
+                    0xbafe92717bb1cfaf,
+                    0x808854b46a0fc358,
+                    0x767e285f550f64a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8769dda4e333e531,
+                    0xc79bfbcc1be287f9,
+                    0xa93a954e7f4e7cc3,
+                    0x9ddfe54b0956e085,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30d2e98d6d278bc6,
+                    0x73d3f6963965674f,
+                    0x18b5405554340a0d,
+                    0xa0ddfbca1f87e535,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe71510bf36f5e18e,
+                    0x41ef67c9f9472349,
+                    0x58bc6634d3e510ae,
+                    0x2303dc0e3abb29fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4e1549b84736872,
+                    0x2e406e34d57328eb,
+                    0x16481956723ceea2,
+                    0x73a6cb1d324cb781,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51cf8e170184b016,
+                    0xbb72e5c22e7ea318,
+                    0x9388fecb3e296e09,
+                    0x5a82e9f53e137f7d,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,1983 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // We use internally the Jive compression method, as both digests do not fit
+        // into the rate registers at once.
+        let result = Self::compress(&Self::Digest::digests_to_elements(digests));
+        Self::Digest::new(result.try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U2>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger320([
+                0x9f96141e1d70bd3c,
+                0x6089816abb494626,
+                0x265dda8588eaa721,
+                0x51f3b26daaf1e597,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7cd383dc3ae5f5f4,
+                    0xaeb8e8dad1ceacf8,
+                    0xf1acb3faacd6a683,
+                    0xe6eb09e96242dee8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa186df114f9e6508,
+                    0xd81d2a85a72bc109,
+                    0x5bbd8bd2d1c7a783,
+                    0x18d6ce7942047add,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x15059c2f5a719a6a,
+                    0x09c467d4a9a4c5e8,
+                    0x51b90855faf49c43,
+                    0xd3d3b51f0815c981,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x299b51f6af6ddee9,
+                    0x991ea27d179c01ba,
+                    0x967faa22edd0b466,
+                    0xb7ef956e5dfac2fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a5d5a02a0f7cd2e,
+                    0x0fae3d162b23f029,
+                    0x37fd4466f5fe8c3d,
+                    0x86b1c3686fb5acaa,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1c5e5b4974494a3d,
+                    0xc988f9d4b7d09c03,
+                    0x6dfba6341c6aa8d1,
+                    0xb1af30707589ea41,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeeecf341eda0cf8a,
+                    0xbde1676bc56bce71,
+                    0x54db674688dde53b,
+                    0x532ba5931b0fb14d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bc81573f92efb02,
+                    0x0d290917a0b840cb,
+                    0x3f270848bd941372,
+                    0xe4b16fff1a99ecae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc03e0242f4956377,
+                    0xc57042419394209e,
+                    0xddb15f737a184de8,
+                    0x2d974cd8cb2225b5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xba6145b40ee5daa4,
+                    0x239eb1b78f084d3e,
+                    0x25417ddce902d361,
+                    0xc7bacf6582e25820,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefce1babd8c7f463,
+                    0x1324664671121ca4,
+                    0x44cdb00539145cf6,
+                    0x9d7bdfcdeafec77e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c9a1fd610e191dc,
+                    0x8b36d5d9e6e25af2,
+                    0x358169b8e6a462ef,
+                    0x4ef9200ac1da8a30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x524b9937db61dee8,
+                    0x46e4389a6c5b2ce4,
+                    0x4bc6847791d5ca51,
+                    0x5922c8b9d5d97472,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x583fabdd2723f036,
+                    0x701036b43e85a3a7,
+                    0xadee550b2ceb5761,
+                    0x80b5175731ea635e,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9436b7765b0ae728,
+                    0x72bf2c0077523d2e,
+                    0xf5515ebaf9dbaa1b,
+                    0xea1be0542e7311b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d4ab24636be17e7,
+                    0x912932a5493384e6,
+                    0x26f0cf70bfec7401,
+                    0x022916926d40fc5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7739e4acd45c4244,
+                    0x197b8d7d11eb5aba,
+                    0xdb6e24e1b859a4df,
+                    0xa6f5c667ae165923,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb82ae4bfa4f43f3e,
+                    0x4e69be1e1321cc60,
+                    0xdfd96d282cb912f8,
+                    0x7d26a41343be97c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x583189d68442284b,
+                    0x8446897a293ced08,
+                    0x625315fe098e80c1,
+                    0xfd65f4bdc6ef5929,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31e8641907d2998f,
+                    0x746cc65055573098,
+                    0x6a477ec241abaf7a,
+                    0xd96ebbf12def8a05,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xe00f3734341cba71,
+                    0x70f1ce1bdf361ee0,
+                    0x4d41dceb0a04e808,
+                    0xbef503b8a1352bd5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3947000a5ed2595,
+                    0x9a1a7697a7fb8148,
+                    0x0ab2a35774569ca8,
+                    0x6caa9814aa721654,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xea30025276f02600,
+                    0xe8dd2c9363c1e7c4,
+                    0xad7fea6714ef615f,
+                    0x2b9babc6d4e23c37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05a80254599a564d,
+                    0x8c4fec0f5072c88b,
+                    0x96126c092b2c107b,
+                    0x1b49ed04b3ea9013,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5da26c4e87b932b5,
+                    0x3186e8f12a0b7b6e,
+                    0x5cd3266b0c22f9b2,
+                    0xc2d38ab7803183d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56446bbe5f2a4375,
+                    0x1f07ff098f3869e4,
+                    0x69ded4998a37fb99,
+                    0xe1b03900398f9bd6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8f8496330366cf3e,
+                    0x9db000b8b040a725,
+                    0x26380b1ee5d1a83b,
+                    0xab4396036a9c845e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcba1f3be5f4265e7,
+                    0x429183e43715a7a5,
+                    0xb2f53099ff0f577e,
+                    0x80e03fea78e1d61d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc6ebffea2966ff63,
+                    0xfdce47c857c62ab4,
+                    0x3964b6e7853e453a,
+                    0x1fba05c507f1a00b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb9e687d368468d1,
+                    0x27c65f69796f410f,
+                    0xaba7feae4d652d17,
+                    0xc8f0c33e1877d1ff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf446f2e62a77a1e2,
+                    0xba00cc8731f155e6,
+                    0x309acc37b0910e89,
+                    0xa54e9d828692257d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00f4efe439039ad7,
+                    0x2b757c5cc4c2bdbc,
+                    0x48468de3acd4a755,
+                    0x1ae8f474af6c7d7c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x39c0b89acfca4c43,
+                    0x97ca52430e5d92de,
+                    0x9b2cd15bad0b0694,
+                    0x9575739801e1156a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6ef47dfa9344426,
+                    0xaba7a7a077ed643e,
+                    0x53871a7f994c6283,
+                    0x5aaadead172e8b40,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf4574576d95cf376,
+                    0xe7e87a1422d98966,
+                    0x48823b47e0e479a6,
+                    0xc8e23fd6d7a740aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a010a87d3abc6ee,
+                    0x34a577a15d858341,
+                    0x032015065e7f15ba,
+                    0xa2b8012638d91bc9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdf2431b017771f10,
+                    0x459ceb7caf676ef1,
+                    0xe23347c0b138600d,
+                    0x1c1beb005d656c8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2216a539a7a5c3f,
+                    0x893e935eab5effff,
+                    0x2b7c69e2070ebbff,
+                    0x803b96ce1dc90c98,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x10686432fca705e3,
+                    0x0a1efb67f4a5b6b6,
+                    0xef4b6cd6dac3c2c7,
+                    0xee4912cdec58cb18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x572f84f7011d87ec,
+                    0xdbcade202a7805b9,
+                    0x000e6fa94ac03039,
+                    0xed58e2be0ec2582a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xe00f3734341cba71,
+                    0x70f1ce1bdf361ee0,
+                    0x4d41dceb0a04e808,
+                    0xbef503b8a1352bd5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3947000a5ed2595,
+                    0x9a1a7697a7fb8148,
+                    0x0ab2a35774569ca8,
+                    0x6caa9814aa721654,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xea30025276f02600,
+                    0xe8dd2c9363c1e7c4,
+                    0xad7fea6714ef615f,
+                    0x2b9babc6d4e23c37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05a80254599a564d,
+                    0x8c4fec0f5072c88b,
+                    0x96126c092b2c107b,
+                    0x1b49ed04b3ea9013,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5da26c4e87b932b5,
+                    0x3186e8f12a0b7b6e,
+                    0x5cd3266b0c22f9b2,
+                    0xc2d38ab7803183d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56446bbe5f2a4375,
+                    0x1f07ff098f3869e4,
+                    0x69ded4998a37fb99,
+                    0xe1b03900398f9bd6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8f8496330366cf3e,
+                    0x9db000b8b040a725,
+                    0x26380b1ee5d1a83b,
+                    0xab4396036a9c845e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcba1f3be5f4265e7,
+                    0x429183e43715a7a5,
+                    0xb2f53099ff0f577e,
+                    0x80e03fea78e1d61d,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 124];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe83a98358649d239,
+                    0x40e802c69fd327df,
+                    0xec2bce5e0e5301fa,
+                    0x458c7c683ca0fba0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecc8f81f9d29f05f,
+                    0xf967a4097170f03d,
+                    0x517aecdb23da53dc,
+                    0xb1c13818ae7ebf35,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb752a6925d5568a,
+                    0xaae09fd34694107c,
+                    0xa4602dadc2657b4e,
+                    0xd218e591e3e595b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7030a1b7953f65e6,
+                    0xaebe659fe2fbcef9,
+                    0x7b979ef063984bec,
+                    0xd9d78f64e6200e68,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd9cdc04223b428e4,
+                    0x4ea842e53ac46315,
+                    0x3991dd86c9739e5c,
+                    0x88405b538395a5aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51a6646bfcc6c346,
+                    0x7fd1514e7c1f3880,
+                    0x567fe4fda257340b,
+                    0xf118cd5d8fddd79e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa14565857e2f4ef5,
+                    0x5dd85fd3ab2f5b5c,
+                    0x8e6c47e23f34ac42,
+                    0x24e43fb8ef1d4385,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe35ae0198737b79,
+                    0xc2242d0e165ba80a,
+                    0xadb5966e167f9276,
+                    0xb7d00c5a8626ce3b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1f324299ecb2c033,
+                    0x5c20983f78a191bd,
+                    0x794bb8154e7f7213,
+                    0x1eeb5fca11019039,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9ac10b2a38e7608,
+                    0x27c7ca83259412e0,
+                    0x86d430b4b18c117a,
+                    0x3dd4afc83a9af002,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fb263ef4064678a,
+                    0x36974a8cc9878bf1,
+                    0x58e52e2b2c7fee4a,
+                    0x2e083b3bf8c42989,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e12dea834bdb9fb,
+                    0x036ac300c5b8d484,
+                    0x1e1cbeaf890bf737,
+                    0x53ec56f305f2aadc,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x48295c11b33e9e2a,
+                    0x955c6dfd2dac5a04,
+                    0xdd733bf21a7e67c7,
+                    0x2c5fc624a9fa021c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3e8bb8e60d67945,
+                    0x7b1c48541b73394b,
+                    0x53bc0e370a3d932c,
+                    0x166a4643ef834f6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b6a509ea7782035,
+                    0xad1d914559fea44e,
+                    0xd19f237e136a0b3a,
+                    0x39cc0f08d0feee7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15c15a8c5279c644,
+                    0xb178624b0125251c,
+                    0xbbfdb8bc37ee21b4,
+                    0xdf56ecd3c9242645,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x16507b163d7857ad,
+                    0xafd96830c4e5dff7,
+                    0x0650d2f139466fea,
+                    0x3670734ce5d2df75,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9aeb3ef83a1c82d0,
+                    0x4dce9b377fbe61b8,
+                    0x21b5870983e9188c,
+                    0x458678967d359faa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec81e8e7059db0a9,
+                    0x54bb96849c8bf6ad,
+                    0x4348caba00cca96c,
+                    0x415a5c2c4e3c3ca8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e952589b75a7ffb,
+                    0x940b211989ca2bb8,
+                    0x88402c07f707e6dd,
+                    0xc46f555e92ddec37,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x84eb90f042365b3f,
+                    0xf768c116a5822521,
+                    0xd0798b332d934094,
+                    0x37060c34bb096b44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1931db5a5581f23,
+                    0x5cb00c5110248f3d,
+                    0xd73f6a3abc5dc965,
+                    0x62dd6af6e626bb45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0451d2e7d27d39e0,
+                    0xa359733d4b326804,
+                    0xeb880be96635aa2e,
+                    0x65002ac5b33e48a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48916fa80fc7478e,
+                    0x6cbb57e99189e1d1,
+                    0x96fc600f5c13130a,
+                    0x7ce8360a16dfaee8,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xc9de59f4737964be,
+                    0x20026dd2dafaceb9,
+                    0xbfc6c2701671976f,
+                    0x9f28b3bc47e2c558,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8bfa58e7ab1f964,
+                    0xf8b4b1320dee6195,
+                    0x73c23e26963d85dd,
+                    0x814eef779ceb7e94,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xee56e555601dad4e,
+                    0x464323ec2b65a504,
+                    0x3f2103847e0d61c4,
+                    0x3bcc345f6ab40798,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12875a7f862e9aff,
+                    0x3be5e188048550e9,
+                    0xad5f478196e0833c,
+                    0xdc6f194ac4f993cd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe4bd3d5aa6731f98,
+                    0xbf76d90f6001c842,
+                    0xb82a42f5db7b5d3d,
+                    0x46800ca464319de8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x745272a129bad9b4,
+                    0xa45e1dbda2f59b4d,
+                    0x4bba183afea324cf,
+                    0xb6c61a00442b2fef,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd46e97dbd73b5e5e,
+                    0xb9f68aa0761ef30f,
+                    0x38dd532b5cf0e883,
+                    0xe05308aa591898a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47ed9248f269ebdd,
+                    0x30cd6c910c216027,
+                    0xbdebe463c8c1e47f,
+                    0x58bc2c67b7d651ec,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf4e07a99d6943c50,
+                    0x44d75bb4c3b1a753,
+                    0xb061ce5e1fdad8e8,
+                    0x0034a64a61af9444,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x188a1cbbd383ae03,
+                    0x0ba34027e9496cd3,
+                    0xa6287d6928430351,
+                    0xfe7f172d41a90a60,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc14206eea436e9b5,
+                    0xc36e5c2c4d72a20f,
+                    0x9f63ef133c8277af,
+                    0xb78781a27ebe6487,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7fe5ce6827e3c3b5,
+                    0x20ea4b9fa0bc786a,
+                    0x87fb340cb4d71d09,
+                    0xec9092584ab03c63,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x167a0bc135357a3d,
+                    0x3e45ade4c69e5074,
+                    0xd414a0b65616bfcc,
+                    0x341fccb181786a08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a44775c4b38fe0a,
+                    0x11feac9a4ae6d01e,
+                    0xf8c839f179db3d30,
+                    0xbaa4ddd5360b5ce1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x140ba3ae0280152c,
+                    0x6e0eef0fda7341ca,
+                    0x69c9df747bb7768e,
+                    0x4be5b6d874c783bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x107dcf477b8ef504,
+                    0x429b0f46bfa1d084,
+                    0x72fd1c60f78d3446,
+                    0x3c5d3e1999123a56,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0337454ef207b20a,
+                    0xab6ea95c52b60c49,
+                    0xf0ab603b3c9129d5,
+                    0x75552a8c6aef0102,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e249c98e7e7e6af,
+                    0xa03e0fd6183b4c6b,
+                    0xe6b9e9f5c4b1ec31,
+                    0x7c858bc837c25644,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xef28ae542c137cd9,
+                    0x12f8f12e10526963,
+                    0xa4e2c8f90aa7e710,
+                    0x813b5135dc36614b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63a35520de3065c4,
+                    0x3651cc90e277db20,
+                    0x8acfdea19cfb1f10,
+                    0x1e506fff49262a3a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa21b2f4b48485362,
+                    0x399cad99842b0bd2,
+                    0xbcf16dbecbc37a59,
+                    0x75cea386b00da5e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23d9f81f78019d62,
+                    0xb0ed5754955e8956,
+                    0x1c0bd9e72a7d5e69,
+                    0xe44124995e88d9ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4aa8fb2e132a9fb5,
+                    0xd77463cbeb383fc6,
+                    0xeb7548e29f818fca,
+                    0xdaec516d11c4429e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e2537f850b9e501,
+                    0x19407daec18b5c85,
+                    0xeee4e8797441c69a,
+                    0xe6efe85df2b936d8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1231d1f30bebdfa5,
+                    0xc375f1a98c69cfb2,
+                    0x3615b895ed1911bd,
+                    0x80051607316affcf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8cd9d706c3751c86,
+                    0x492f5cc25fff0b03,
+                    0x1237d0bfc7a4fd59,
+                    0x8094c2304ac593cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x09e806ad485675e2,
+                    0xe337505de4b256ba,
+                    0xbe46162fb549425c,
+                    0x044ba5615258cafe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94e29af79f6f759f,
+                    0xaf21fe638f33119b,
+                    0xaa344ed05400e48c,
+                    0x3b2629e657387fff,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd8f8fc33554aaf33,
+                    0x3f73b08925c3c1f9,
+                    0x3359fac5d33c2289,
+                    0xc87a5574b1cd03e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b6ace09bf8d5c4c,
+                    0x50d3267500e1c180,
+                    0x6b469b99655a80ea,
+                    0xa9d43ba305263647,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57cde43537ad77b4,
+                    0x6b6b28bb881ed70b,
+                    0x61a6acbb8fe46ac5,
+                    0x12807415b4237552,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf133f33241b89783,
+                    0xa91b2220da05d47a,
+                    0x337f4cd7d9fd7b13,
+                    0x30767db5bad53a3f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7711cdb6dd99bdbc,
+                    0x1bc60bdb9791c481,
+                    0xe3438b144c1530eb,
+                    0xf3e445e3c1940273,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd701ac4ea1e4f11,
+                    0x6ded7d92eea2ff41,
+                    0xe6ff8964576c78cf,
+                    0x64795b418fdf146a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa97e66d36f9be00e,
+                    0x603c2267aa8a4eec,
+                    0x5db5710d0d0eb209,
+                    0xe7316d1eae4484c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9a2190ff16d8709,
+                    0x9940af6d6753dd5a,
+                    0x173cb2ad40abf717,
+                    0x4bee3fbbc2c79ad1,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9882ffeb3c385a9c,
+                    0xf5cf0aa89713b974,
+                    0x90a43889294c2fb8,
+                    0x1ec137a95b194ab7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3da419c1248f55f7,
+                    0xfa45d9c330c1fa06,
+                    0xe3af0c3c94c58d0d,
+                    0x64a69aa0e5f2739a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcebd5e8fd0673e5a,
+                    0x000001dbc63831be,
+                    0xe1bf988573699317,
+                    0x8425535a59c1889a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99da3845c0bb4df5,
+                    0x69126fbf8a42776d,
+                    0x05e520f24f0edb63,
+                    0xa1453cad0c582bb8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xadd5aa332a9635bc,
+                    0x2e20c7431cd976ee,
+                    0x6c4086991939eda6,
+                    0x3b3ef4d3068c0d2c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x059251072a1faba0,
+                    0x44c4deff7308f1d5,
+                    0x5497750b04b04104,
+                    0x9f984622de5f39a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4efbd9f9d0c5a247,
+                    0x6a9b336fc7f70376,
+                    0xde5886015e50f2a2,
+                    0x9be97f1ee1668b7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x247f75e4e2578a71,
+                    0x317599fc13b7c834,
+                    0x477148882e432671,
+                    0x82f6b18ef8b56b92,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xa29dff83ee2b61f3,
+                0x18b71f04e8e9304f,
+                0x33890096acaf1d4d,
+                0x2077a333e4ce43ed,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x00de3fd5e64c4c1e,
+                0x822905742feaf5ee,
+                0xec804b0614ede500,
+                0x183b4daa2fad9b65,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x590faffbd02df94c,
+                0x63d4f6cd02f76390,
+                0x03e45b30da1e820d,
+                0xfd4626a4a85ccdd8,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1c5c2a25c9a54e0c,
+                0xeac3f73182405337,
+                0xf6c9378f25b2cd02,
+                0x390f351210eeea8d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x56a8b918320af223,
+                0xbcb53abb5ca6ebf4,
+                0x9205eca30cc15be6,
+                0x35abddaaf32b3172,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xee92ff76a3da2b46,
+                0x6b960793fc6e356d,
+                0x2502fff6feb4594d,
+                0x91d6741a4fe281f2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9a7a67ea93cc7187,
+                0x21f9ebda4e7a03b9,
+                0xf94872aaf8ab496a,
+                0x265a7b10bcb008df,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xfe2e8ee8c3a3b79b,
+                0xf9c6f6ff6df11d8d,
+                0xb2623774e394219f,
+                0xb682094816fb37bd,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe72c8df52400ca96,
+                0x259d035ba6647482,
+                0x95c8896b9cf382c6,
+                0x21a7b95712bcf298,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1a3776cc24139d02,
+                0x5894c84ac6df1a27,
+                0x773360251622b7b3,
+                0xc071f68f2312a901,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 62, 63, 191] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 62];
+        let mut opad = vec![0x5cu8; 62];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
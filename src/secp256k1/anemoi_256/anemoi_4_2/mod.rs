@@ -0,0 +1,1180 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 2 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 2;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 22 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 22;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xd6d38706d9d3c350,
+                    0x8008d046b8f99211,
+                    0x5aebcaefaf5ca2e7,
+                    0xcdf189c40972b580,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0656c4e21f7089f0,
+                    0x3bd69b7ada9c511e,
+                    0x80763c2d8244433c,
+                    0x28c7be5d0b8e1cbf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf27227f61c0f0376,
+                    0xe083b2174f015ac7,
+                    0xe7a05773ceedaf47,
+                    0x7333e96e87f4a6fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3e7b400d00c0c15,
+                    0x275f760d77b16c5d,
+                    0x60696a24f3198cbd,
+                    0x517a35336d3648dd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xeee4357aa6409416,
+                    0xf6420d561f6398b1,
+                    0x2d9e4a09afdb59a3,
+                    0x3147ad06d6ef6857,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe281a4415ae1d4c1,
+                    0x8cf52b0284fe6287,
+                    0x0ac9bc9e6a808b20,
+                    0xa306d60d686e46a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0acf0180d3a9d7f4,
+                    0xf065b03a231bd269,
+                    0xfc35a2bd92faffe1,
+                    0xeef5b0a641f0abe5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c61737745b847d5,
+                    0xef290d0b2797202c,
+                    0xd580ce297d83e62f,
+                    0xaf1c716db5c84132,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x994afee232b82f3a,
+                    0xbf82f8673ed8e831,
+                    0x49fc72d1365ec4be,
+                    0xbc7f74b76fd5941c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6512278ff55aabd2,
+                    0x2f021ca3c62fce0a,
+                    0x08c00908fdcf80c2,
+                    0x36ba70238c0f20bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75a3be819ed5d768,
+                    0x70dbefddfdaaeaf7,
+                    0x79bb74fc3c786efd,
+                    0xee79d0c550c4f243,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe74f1a169e6a7a00,
+                    0xdd589105dc1dcdf8,
+                    0xca48a08f62fad79b,
+                    0x56e33ed7d2710b7a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xea928ba3e105bed0,
+                    0xc5d04ba3d36205c6,
+                    0x650305c033f804d9,
+                    0x8d950f3307b93a2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38eca26d03ee435e,
+                    0xea08279ef58ec1de,
+                    0x5736988c98cef0bd,
+                    0x9515c25bd34b1a7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa07f7af3178454e3,
+                    0x02ec2cc2949e48d7,
+                    0x22b32a066758cb01,
+                    0x653a1555e177c1ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x927e46cdd96dfce4,
+                    0xf67f5d52d3f6b953,
+                    0x08773e5444d7a50c,
+                    0xd60bd01ca1b5f332,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8e04aa1ae907191f,
+                    0x19cf69900c855a8e,
+                    0x96ccb0c7a4215c9c,
+                    0xece62b40ba2ba15a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fdc298f9e50e6d3,
+                    0x747d1866aca38916,
+                    0x79d6820710dd8e6a,
+                    0xde8e4af526615c7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac8516c1ef7c5be9,
+                    0x1758f995c1a5d3d0,
+                    0x04a2a23b76ae07ee,
+                    0xfd39334fb5df3e3f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf0c5de76d78980e,
+                    0xe93f9b44fe6f3ebb,
+                    0x40896459109c112e,
+                    0x96cce40d3bfcd2d7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x32ccc2954c202911,
+                    0x522c1c11619e6abc,
+                    0x3a57bf68c7911160,
+                    0x76dd34ee4e132715,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcde0095d88732cba,
+                    0xd26828d13bd33c25,
+                    0x54f68dee4f80910f,
+                    0x5e2fc797fac0449e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79b3044b44e6b214,
+                    0x8699378d5cfab714,
+                    0xb14d6743f5ed2f4c,
+                    0x5d5cec61d49ec8bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x390ace388f7e9b82,
+                    0xbc509b7f40d34143,
+                    0x4b3224f33448d718,
+                    0xcfafe989bffc7231,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd867361fe6343cae,
+                    0x46d2bbcfec65b2a1,
+                    0x7f6e63638073fd69,
+                    0x1564c03e18229780,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58e70f8fd15bcf1d,
+                    0x95e0bc76e4571211,
+                    0xb1615699132b4a2c,
+                    0x48b6f941c91770e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f86cf84f280a24c,
+                    0x6f55f243d7a40b85,
+                    0xe9ad769670ec7ef5,
+                    0x1ab4287d051b5770,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd02d2067f16cfde6,
+                    0x6f3ad6ccfc0e9b6f,
+                    0x1aec772ada0eacde,
+                    0xbd035db040e3ed3a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5249bf32010f9b87,
+                    0x10cf5a3f0267b645,
+                    0x4cfde8fd72bf5b01,
+                    0x20612efb2b75728e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11a4c2685bc5c4fc,
+                    0x228442b2d93ea4fe,
+                    0x7a59c062837250b9,
+                    0xfb3c37f1ecc793ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8fc0bce44d36045,
+                    0x2de1634641a2ea2c,
+                    0xbb2b81b80ee1ddef,
+                    0xee7043a212b73892,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c34bc3b18258199,
+                    0x3cf62fc88b6837ed,
+                    0x4ac8e9785f0906f7,
+                    0xc61d3b11ab2aa8de,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6cff8801e5908563,
+                    0x37efedee24437178,
+                    0x36f15d7c3f2059fe,
+                    0xa7cd083b9649e55d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a8b4535419953af,
+                    0x931067b267272f21,
+                    0x22c72e391a742c06,
+                    0x5203b6433f3ead63,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48f648c9fded4a1e,
+                    0x6bb566d0f2e3b6d9,
+                    0xf9ffb977b9e39794,
+                    0x9e7c91c2fcaf948a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb01f9e25a82358bf,
+                    0xc88413b2c07e8dfa,
+                    0xc22b840b8b9bee67,
+                    0xf116715981c98d48,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x153d6010ff758ba3,
+                    0x984dfc84e89abdc8,
+                    0x3fa7b3dc860dedbc,
+                    0x2017840a6bc31b58,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5423aa3ade3944ef,
+                    0xef8679409b25a5d0,
+                    0x2f4cd421a5b0ac66,
+                    0x6a92c61101e23bea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7faab1499c7bf9c2,
+                    0xb72f496759f6ea5f,
+                    0xe7ee47a56b422503,
+                    0x56ace8788fe4f512,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b9fd5fe60e5ff98,
+                    0x6d5eab88fe6b8646,
+                    0x87e75753aef02d8a,
+                    0x94c9be7fbcf94343,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x336ba101f1543f60,
+                    0xb74ecfad66d55f1b,
+                    0xb3d21d85faeee8e3,
+                    0x272bcb654344f3ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad7b8747ec0d0ab8,
+                    0x132861119eb08d87,
+                    0x5493d5bbbb0a6d06,
+                    0x1414bf90b2dce92d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18b97a396e63395d,
+                    0x65a48dd7a7508e11,
+                    0xadeea732d9671248,
+                    0x25ae4feb81e53a35,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd0bf439a2a260dd,
+                    0xdf423ecbcfd21d45,
+                    0xb45ee06b089c01c6,
+                    0x76c85b2d6697ae6a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x07cdc348481a750e,
+                    0xb9690f9bce82792d,
+                    0xb3be568bbb89f00b,
+                    0xf1120b1649913f3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61c7a36ac86ba154,
+                    0x83bf03fe44b3c265,
+                    0xb5ae2d65a36a2fc7,
+                    0x3c56fc902defbc8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d34be20894dbe75,
+                    0x06f959a64d4d8c87,
+                    0x08534b564cffc7c5,
+                    0x2612031c4b82b27c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9b0b4d61b78bc66,
+                    0x0290e13e0a6d9408,
+                    0x9868952f18b6e5a9,
+                    0x9a49bbbc74bd6894,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x029c297d39d8ece4,
+                    0xf7ca0f0cdd74c6b5,
+                    0xe7eb9bace45fff5b,
+                    0x7c4cfde3b8eb9902,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6b7e01a71495555,
+                    0x3349ef1f05e5e79f,
+                    0xe2df4017bfce2cab,
+                    0x522c10fc1bc035bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9713637cd9be540f,
+                    0xa688fad9bbfc990e,
+                    0x2b6574a3b8838299,
+                    0x6bd49302caa26d76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8ed51b2cd211960,
+                    0x9d6d4a3ac42461b0,
+                    0x2aa08b2e9e495cfa,
+                    0xa77292f90e94f43a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x485aa54d3d1316d2,
+                    0xed92b4ca7bd99b6f,
+                    0x62256757a14ba715,
+                    0x4cc3fd5cf0eb0f43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bdefe3024628240,
+                    0xf30e78d57c55d3ad,
+                    0x25c0297b0c3030c6,
+                    0xa7f6a14d017373e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1680928f38f82421,
+                    0x60f907dca735a204,
+                    0x6323dd5f37de1308,
+                    0x4de93cc2bfc7469d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4b7d180045d64c7,
+                    0x523cf567f762e6c8,
+                    0xc9e1fe1b5098d44d,
+                    0x28ee39007fd98b8a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9042fbaa2b4ef10d,
+                    0x39622053e06ea041,
+                    0x724d2e9dba294405,
+                    0x1fa94ad78a7a3e2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x874c4af586a580d0,
+                    0xbe4ca8c2bb9957a9,
+                    0x91a9382914ca24b4,
+                    0x7af50ae6b82fcfb1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc4624933c438ffd,
+                    0xf04853528d256d06,
+                    0x634952367247fcd9,
+                    0x0e02cf3429550b3f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xccd329c1451e2e2e,
+                    0xece28261c7f54aef,
+                    0x77ee3c104f08557a,
+                    0x42010d75326f0c9a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xce65f7338d70119f,
+                    0x99e4be1cd5d56124,
+                    0x146ff795199a197a,
+                    0xe30bffd5dc6f67cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb899eb4feabda8d,
+                    0xf2c19f8022c4078f,
+                    0xd957ef78609ba090,
+                    0x04c0402f4a69582f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f2687134ea4f29e,
+                    0x028e4852b24e6815,
+                    0xce944f6783268b2a,
+                    0x0582f212b435be18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb52867e254360bcf,
+                    0x96d7f3fc0b9ce182,
+                    0x21a74d6d009d509c,
+                    0x4d1e901e0d440a40,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7088e4e136ba9bd6,
+                    0xde25d75d2229e7eb,
+                    0x744c20a1d58c57ee,
+                    0x8b9ed790f60d1833,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x573db9f7eb2cd1ca,
+                    0x975c40db6ae077aa,
+                    0x4e0d199f9749da37,
+                    0x7b6846fb733854e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeeb4ca8918c3b1ae,
+                    0x17da53b16a769a5d,
+                    0xdf544c11262b547f,
+                    0xfe3b7ffcd387e568,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12a5e8aa898ba3e3,
+                    0x1a39d51033d3bf91,
+                    0x115f6e8fb20446e9,
+                    0xf479299b0ff992a8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x85f2a9d67e6f2ba7,
+                    0x2c058265c2f42164,
+                    0x51cc67e1224c1738,
+                    0x5707932da624f36d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e9e42381226b6cc,
+                    0x83e43197d6f60953,
+                    0x9754e5fb3dc6864a,
+                    0x76ec4fd2345928d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e63f86019e33be0,
+                    0xd603a36b7bc090cf,
+                    0xe5d71e9e86d798e1,
+                    0xc9569386f573e4d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb9681d9baa32a3d,
+                    0xec550105a12ba62f,
+                    0xdd8511a8feccb164,
+                    0xe654eb6b13b7b369,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb6c3c9cd8db4f0b4,
+                    0x91a7dc69ef267d94,
+                    0x90895bf423ca855d,
+                    0x72d130d80c2c3a42,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b033d841a682b42,
+                    0xe841845cd359605e,
+                    0x947b53f42b2dbcc3,
+                    0xaa9fa3844044e487,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e277c2a5a5c195e,
+                    0x91083ac7f81a2cdc,
+                    0xacd0e919c7d3e4c7,
+                    0xeaf04c016e7c3c9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4189d7fee8d2ab9c,
+                    0x59a1ab31a44b1fa4,
+                    0x31d82ff10fff30f0,
+                    0x2ca577071617234c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xebf79fdfaa3aa534,
+                    0xc6be1f4af0db1677,
+                    0xd365e3c8c5dc396a,
+                    0x44a7e143f5456af2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4fc5ddd0231275ad,
+                    0x4748d6b64ee71715,
+                    0x9ff1d4d55dc4dd07,
+                    0x75ee4518e143b4bd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3839892eaf45d4fb,
+                    0x75280cfded03ccd5,
+                    0xf34d9638f8330d66,
+                    0x12a9ef48bf2f5f62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf2d2e1b46c9a312,
+                    0xc0712ed66e410883,
+                    0x3d0ca00a20773b3b,
+                    0x85e70a9cfd5564c6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2627dc8bbf3f774e,
+                    0x74481a9c133aa73e,
+                    0x2748d718f887b223,
+                    0x90886b8bb309ad44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9c3e09ac463ee5c,
+                    0x1b24f896f5494d63,
+                    0x0783bd73fe613b1f,
+                    0x2c8e4d89d14cd77e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1a5977af9e8de25,
+                    0xbdbb7c596f659204,
+                    0xa1ca32b3a5e04c08,
+                    0x6c097b11ae6e2e58,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4136eb0529fe2e3d,
+                    0x297ac85edb562315,
+                    0x48a7ea5163e8e0f4,
+                    0x521f4069349f9649,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6102d3528973a146,
+                    0x72299c9d3e2177d4,
+                    0xa077c5fe3b6cfb2d,
+                    0xf14cc063bbab705e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe9218ae9b06c601,
+                    0x493e7557dd286f0c,
+                    0xbabf417312e29219,
+                    0xd8a6815a7d6ba94b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x429bfd1c4024e3a9,
+                    0x9e82ccf4228819c2,
+                    0x8d643ba38a10f21a,
+                    0x5da7665629e5448a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6fa7e690f13a16a,
+                    0xde16af2f19e6b55b,
+                    0x76c1025221596179,
+                    0x1e79251531e58bb8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x764212c9f8411505,
+                    0xa43a99ef62cb4eea,
+                    0x5e736d809b69e695,
+                    0xfdd7ac834fb616e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba03f258d3f01c4c,
+                    0x840c0ea993426469,
+                    0x6967622169878df8,
+                    0x74ef4c85625a99a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdec44848d3d6c460,
+                    0x61c8d02473378eaa,
+                    0xaf5c52c324864466,
+                    0xef2ba9918a9142e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b01a36694480a41,
+                    0x3d34c41ec41d465e,
+                    0xed69445a93be21b2,
+                    0xcbbe7cb1733bae10,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa1cd707fb4e353dc,
+                    0xb7b2172d47d63d5e,
+                    0x17cb19d2db9faa17,
+                    0xbbcc82a443306ddf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x440693b930d0ba02,
+                    0xaafa771fae78c16f,
+                    0xdeb63373d0a58491,
+                    0xaa51d7befdea726d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46c26afd084ce950,
+                    0x6e5feb48146d589d,
+                    0x8f0a6d8493537c0a,
+                    0x4258a5fff41361df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2ab395832c9fba1,
+                    0x21236543b9089aa6,
+                    0x92f6672c40d20d01,
+                    0x90608586d1ae0a70,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2287 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U4>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0xfe8c0e6f841648b9,
+                0x75e51f3acb699e2e,
+                0xe4544ea8e00bd644,
+                0x757c6f9c4e506198,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x052d68ea6f71de9c,
+                    0x88b95457206d12ff,
+                    0x49a06aff1d62fad2,
+                    0xad1dd3b5136840b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2549238bf65f6c93,
+                    0x80a0ba8d6bbc08d3,
+                    0x99b24219b4ebe166,
+                    0x7784c4424c271df0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x74f884ed80b6a334,
+                    0x0b4f8d2c6e04365e,
+                    0x79222053701701ba,
+                    0xf06d67a7e77ea9d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa1e88638798cde2,
+                    0xaad593f19158bb3b,
+                    0x98c53d9678f58f44,
+                    0x0efb9efcfec5d8b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf208585035a0a218,
+                    0xfc0663206d5133fc,
+                    0x1875c16189d7abff,
+                    0x15187444d2f969e2,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x423e3bbe60a92678,
+                    0x71e609d6628602d2,
+                    0x17db95f504ad1b63,
+                    0x9e33cc3c7fb0b2fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8884a3c603b7033,
+                    0x439f39ffb3810f17,
+                    0x9a6411798c4c56f7,
+                    0x442fec24063cd58d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a074f5eb6f6d2ab,
+                    0x29b35313b0e9047f,
+                    0x7a279b7b81b8b150,
+                    0x4d930a45a0828144,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0cc73697baf2f907,
+                    0x0699152ad707eb0d,
+                    0x67ceb84d813d361d,
+                    0x53606ef9cea2e47d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb6709b5b58676a78,
+                    0xceda37d91ecd10ab,
+                    0x65db46a150666a64,
+                    0x0a7b06e7a13d3f3e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa56d5242c6ff194,
+                    0x1d42e9c8631921f7,
+                    0xcd2ba38e14e549b0,
+                    0x8c47a0b5a505a3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e1b02bb545b31dd,
+                    0xc81b341310552843,
+                    0x176489549d12083a,
+                    0xba15d5c2d0ba9449,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ddfc3c3794234e9,
+                    0xef0659579dc3685f,
+                    0x8cc68bb11c10db98,
+                    0xf8450c2848c84319,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab5857e583fe0487,
+                    0x38df221163474940,
+                    0x20d73b919de4ca76,
+                    0x8e5e99c98b39d363,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb2a99fcdc41f9f66,
+                    0x24f4f98bf80acae6,
+                    0xe606f24bf62cb23c,
+                    0x4d3c384056967961,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f8be86fca8e086b,
+                    0xba1e33cf8784af77,
+                    0xfb481fad2ec23ed5,
+                    0x15f18d6d06f05f6e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf242dbbd50d668e3,
+                    0x6667b24baf0fb8eb,
+                    0xcc61a6a7f2230221,
+                    0x9bdb2892c801fc32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c8716fb195794b5,
+                    0xb03d2ccd979d31de,
+                    0x72a23313f9c2e84c,
+                    0xa9792cd9759c395f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a6286db35523abb,
+                    0x983df9a03ab65a52,
+                    0xe7b79d3782538edb,
+                    0xb32fd105b5b886e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65c439afcbbe3b4d,
+                    0xc5d5d67278824cde,
+                    0x8137864eaefdad5d,
+                    0x221c4eb12ff3b76a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x16d9c7cdf92dade1,
+                    0x4e56c15dbf76011e,
+                    0xcd5a737a0c608046,
+                    0xbe00b9ddad441e38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b2e39c8181a3f27,
+                    0x6cddc206f41cfd28,
+                    0x5b74bea7cd443733,
+                    0x80510a7b72bf37c9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5edadd4b7812daf2,
+                    0x6c27bb29c381ee57,
+                    0xd760e1faee0486cf,
+                    0xe6d349988436021b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab0dcf69bc83e4c9,
+                    0x6380803432c0a183,
+                    0x6c16a1a2c066afa5,
+                    0xf99233a80fdfffa2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xed62275923fce5a1,
+                    0x8b01ce92dd8e0e6c,
+                    0xb8c9a164ebf27523,
+                    0x9b0284c4c03cc464,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e3a1190dccb747b,
+                    0xc296e01fcbef3c8c,
+                    0x613528aa95d7c921,
+                    0x75a0b210658925ec,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23039a097b6a71db,
+                    0xdf2f7ba17891682f,
+                    0x8ab0b08d8db72953,
+                    0x5c10317b0d897fce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc476873925f09e0a,
+                    0x0499de7812f787df,
+                    0x9f6f3c0d33442530,
+                    0x0c98518b4ff63a9d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc57ba4bb89deede2,
+                    0xf97955f58cdf0b6f,
+                    0x538b3aa167ef14cb,
+                    0xb6c09692a6f5a001,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d9e68a01af489c9,
+                    0x1768b61ebed47360,
+                    0x3d4fd5616d2a001f,
+                    0x4764541025599c5a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x24a43f23e92d5917,
+                    0x99bc8c1357a03e0e,
+                    0x444315476267a898,
+                    0xdad359dd88ee3e1d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2af95f0bfacbdc7,
+                    0x182cc470082215e3,
+                    0xb622c6344a579e2b,
+                    0x3a905a20f1ae0b48,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x140cdf04643e958e,
+                    0x63fa469de1aebea2,
+                    0xcb0b588ff303942b,
+                    0xbf8e62dfe98970d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbda9f1a764d2d11b,
+                    0x532d3e6f21db677f,
+                    0x6f8f450b67699cea,
+                    0xb0c83f4ba46a5596,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4d0f18ced86e3c4f,
+                    0xea8cc5deb97a0af8,
+                    0x1cdccfaaca6d3bd4,
+                    0x703a1194b339b033,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a5105d5fd024122,
+                    0x57396f8ef7c7e0c7,
+                    0x1365df8c6aa3f23c,
+                    0x26889cb3c415031c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x26abdaf334fe254f,
+                    0x1e420997b2bdc798,
+                    0x38dadc6b79190a95,
+                    0x7b40609e280a2870,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d85633cd00ca278,
+                    0x77c03db7ac6d8fd5,
+                    0x34cd5e34eef6aac4,
+                    0x1c9a67b9445080a1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd363049a9dfe43d4,
+                    0x5f385280f1038932,
+                    0xa60f63aef5edd8b3,
+                    0x785ac20dbad12a6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb68764752b7d739c,
+                    0x4125c404790a793c,
+                    0xea84764db950c10a,
+                    0xd270db415e2e26e1,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x16d9c7cdf92dade1,
+                    0x4e56c15dbf76011e,
+                    0xcd5a737a0c608046,
+                    0xbe00b9ddad441e38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b2e39c8181a3f27,
+                    0x6cddc206f41cfd28,
+                    0x5b74bea7cd443733,
+                    0x80510a7b72bf37c9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5edadd4b7812daf2,
+                    0x6c27bb29c381ee57,
+                    0xd760e1faee0486cf,
+                    0xe6d349988436021b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab0dcf69bc83e4c9,
+                    0x6380803432c0a183,
+                    0x6c16a1a2c066afa5,
+                    0xf99233a80fdfffa2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xed62275923fce5a1,
+                    0x8b01ce92dd8e0e6c,
+                    0xb8c9a164ebf27523,
+                    0x9b0284c4c03cc464,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e3a1190dccb747b,
+                    0xc296e01fcbef3c8c,
+                    0x613528aa95d7c921,
+                    0x75a0b210658925ec,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23039a097b6a71db,
+                    0xdf2f7ba17891682f,
+                    0x8ab0b08d8db72953,
+                    0x5c10317b0d897fce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc476873925f09e0a,
+                    0x0499de7812f787df,
+                    0x9f6f3c0d33442530,
+                    0x0c98518b4ff63a9d,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 186];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x11e51dc87dd8417f,
+                    0x2b02bc4fcd1b5f71,
+                    0x548d23ecb1c6a861,
+                    0xc03bde0e017883ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15f4334ba2fb3180,
+                    0x0c925de1bd70cad9,
+                    0xeb082fe95e806632,
+                    0xc4769dce2ac4bd68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x045f7846c7acdc3a,
+                    0x4beb8650bc452b65,
+                    0x7150fb783e7ed7bf,
+                    0xc8c894e5a57b8f06,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ad61ae6c6406377,
+                    0x2051c66942419936,
+                    0xc95ed1916618150c,
+                    0x809cfaf57b7e5b22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c3c2c8a35ed7610,
+                    0x3c1d8d1da18b3840,
+                    0x5b5ba075c269d300,
+                    0x4947d12ddf525176,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7251ad11a824d4f,
+                    0x22ff6fcf370db3cf,
+                    0x131b0705f10e5f09,
+                    0x98f5713a21ec0749,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3434f0c1601d3103,
+                    0x8f591f1c4c8eacb3,
+                    0x0879fbbe83f5179c,
+                    0x65b09e88ed595eb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33408570bbc04074,
+                    0xfe14259761e5e647,
+                    0xd1fc7cf9b45bca27,
+                    0x31d9af01df97090d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73d67fe89f72992d,
+                    0xf9949824cd5e81c1,
+                    0x1841e46785477762,
+                    0xb70c334a486041c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x645da0694c467088,
+                    0x98ecdaf816c00619,
+                    0x9c745bb27d80e6b6,
+                    0xdd4d122b6f7a2348,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f903bd35c2d4443,
+                    0xad335fb2cc0708f8,
+                    0x04e3ff7237ae6750,
+                    0x2144d1db4e0bf423,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90f63476a0789895,
+                    0xae2c7c3de5452cbd,
+                    0x9b24446d8885e3af,
+                    0x33186f57b1130afb,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x730d596e186d3e33,
+                    0x9693089b6e56433b,
+                    0xe0dc5b7c9064571e,
+                    0x975030a439ed7a9e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad0d0f00cee6bc3a,
+                    0x48504a1d0a92c645,
+                    0x5ad6b3f4a440f796,
+                    0x641cdfa4ba226b0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25bfa8004622d220,
+                    0xb5a032a109eb2b18,
+                    0xa6a6f62537afc16c,
+                    0x16bac06bdce6ce9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf6bf844f483eeb2,
+                    0x7ce136ae8321ba2d,
+                    0x9a601e71bd6fcb05,
+                    0xe693b366b4ca538b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77960a10aa8d75bc,
+                    0xd1011656fa9bc8f1,
+                    0x155902ad15b63b90,
+                    0x066cae16cf1fe2f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98919e88742e5ad0,
+                    0x3123f76869a0523d,
+                    0x4bf3746ed5e3a1a2,
+                    0x151bfb687ff3511a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1021560c363a48a4,
+                    0xd764b3d0c37f6483,
+                    0x5a60f4e6604c8e29,
+                    0x524fa9a84904837a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c51df0677213f47,
+                    0x7d0c6a32477e861e,
+                    0x0b7ca8a50bb738db,
+                    0xf140b4bc06bb3c14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf946c6178f3bcd0b,
+                    0x212c8dedfd01c7cf,
+                    0x253c069b3bbe97d8,
+                    0x0167e99190450ef4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a878b00dd54213c,
+                    0xf7edb9ae9ea485bc,
+                    0x6b2f21c7c8bbfbef,
+                    0x24813355dd8cc1b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x275eedc425997545,
+                    0x2232ad3f40abe860,
+                    0x9c4a78a12fe8ea1a,
+                    0xc9c568050e19b027,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8eca249f3fa10aa3,
+                    0xad287d305c703360,
+                    0xbef7dc0db9036d5a,
+                    0xc2f629199e7db3b0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5c627c5ffc35bc2b,
+                    0x44985df87d2c9cb4,
+                    0x80e54308426ca428,
+                    0x7ddae68345aef55e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2e96ea58c92c35b,
+                    0xfc811685b3385be2,
+                    0xa8a37ed6e4c76ad2,
+                    0xa065b9d662f1d306,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2cf5c722aa4fe03,
+                    0x98d6dbe95265f45b,
+                    0x317c5042f32edc13,
+                    0x5e4ae6067642a953,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2f9d5c0e68b7ea7,
+                    0x0783ffc86d0854bd,
+                    0x2e773560dc6aa8ed,
+                    0xd242ea06e06ba410,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b26fe8c24d617ce,
+                    0xb2402b22f735beaf,
+                    0x9928be2c6b3490cb,
+                    0x3c81ef810a983ce0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4b164d3421fec90,
+                    0x1f7b6b95ee72788e,
+                    0x033886c98e7b4a65,
+                    0x2837d7f3468ba5db,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x93fc5b80a532b93f,
+                    0xd37673080f5aaa92,
+                    0x9eebd6f2828d87bd,
+                    0x8e554759205d0f77,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f506c346535213a,
+                    0xe77a3b20c2509863,
+                    0xba1aa509ec236925,
+                    0xc8a41d9df357a785,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d634ddbb62464eb,
+                    0x63852104c0972a43,
+                    0x1981c79834d9367f,
+                    0xc7e67da37a9838ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0d8881fbee31c5d,
+                    0x0c67c2c4ba3a0f37,
+                    0x0e70f1dc824868df,
+                    0x359c2a43dce8d5ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3adf617ee48f795c,
+                    0xdc92e3bcf0e53009,
+                    0xf2a5eafde996f23d,
+                    0x19161830a32ab1b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b3badaf4b5630e7,
+                    0x9466792fc7ae83d8,
+                    0x3ba56aa1400ca1ea,
+                    0xb90332373cec15ae,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x6fd21328885334d8,
+                    0x62c0c338ba4c2515,
+                    0x64dcc0141bf3ab69,
+                    0xbcb097bab266127f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecf6dcbb9cc0b16c,
+                    0xd0e1c4c5057fc811,
+                    0xab63e997eaaeadf2,
+                    0x2065e6d0fc576a3f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ddfe2c39fdfcb91,
+                    0xfba0659e937f5b21,
+                    0xb553a3c590d082c9,
+                    0x64f3ef417b043b3d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x28fbc3062e88c1c3,
+                    0x24f3560ff56dfeb0,
+                    0x95f3dfeb22a5b585,
+                    0x936a5f98feceefd2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cd5bcfe8fb4c230,
+                    0xfbc94b2bf06ee89d,
+                    0xd80b768f409a66ee,
+                    0x8771828ee1891993,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5139301ee6410ab,
+                    0x570968eb924818e6,
+                    0x969aa68c5ba4f61b,
+                    0xe6d64725b14f67d3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5bb9333fa444c2f5,
+                    0x4d12a1809030b752,
+                    0xa41a2183217791af,
+                    0xa9c7b6ac5231c124,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc2225c34253616d,
+                    0xc107dd4863785f7e,
+                    0x4e6db46a96c0e349,
+                    0x017adf1489d2c1b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x078cca62cad33d97,
+                    0xb9d926a8c609b3e5,
+                    0xac270dc9e7ff8719,
+                    0x7d3295971aa74479,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x89c51dc2102996ae,
+                    0x62f382783df10930,
+                    0x662c6da0784426c3,
+                    0x2090a74d4db49630,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a613eb176c8403b,
+                    0x1904ccbb0a1b88fe,
+                    0x63de4526521ecbf4,
+                    0x1d02dc4f7a388f21,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8762d74569b0d99b,
+                    0xdbe7d8af4283b171,
+                    0x00c27298854ee77f,
+                    0xba5e5260d393bf74,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7e9565ac880ed1fc,
+                    0x14f07a7280b4b588,
+                    0xf3584ce85adb23e5,
+                    0x5c6278da8c4aa892,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xced55232e6b43c6e,
+                    0x238afaf4c47aa318,
+                    0x407ffbf25c268193,
+                    0x51cf8832930c2ae7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6bdc3e4dd256d77b,
+                    0x6b14a5aeba3dde2d,
+                    0xaad35948ecec5ad6,
+                    0xa867c1985427f8bc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0b96477c32bc026a,
+                    0x514bdd04893afb12,
+                    0xe12c58602ba00527,
+                    0x8461fcf26943c8f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4affd4d0d72c9e2,
+                    0x59ce2057049961b9,
+                    0x35d9215c38586c40,
+                    0x58ee8c0dabf124b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xace1d4c7c4ab2dd3,
+                    0x75a81f0103d8ea27,
+                    0x3617ef1ec53d4d64,
+                    0x22580a623cb4bfe2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x457e9a41d8765dd9,
+                    0xfa9862bee1f01f1a,
+                    0x13d8073bb6ba5419,
+                    0xa5a3b8e5153afa8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f65fbcf29094243,
+                    0xd7c7db8a0514b29c,
+                    0xde7b96d62cc9b67d,
+                    0x3184a690a2f7ebd2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12020295a9e28186,
+                    0x84b37f08c7a9c19f,
+                    0xa05661f22995efc8,
+                    0x4a5dd8e40ac3f3f0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x696e68ce7110955c,
+                    0x0232b2a3cf30ebdf,
+                    0xa617e5f43afb6dbc,
+                    0x396fd72c32ddf063,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf538ce4fbbf61132,
+                    0x3a630e443132ccb8,
+                    0xb400298c9d78c31b,
+                    0xbead1160656c7521,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa281c61699b157af,
+                    0xae3ff88fba0d6248,
+                    0x48b041a9c2fbfce3,
+                    0xf75781a517f14e96,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xccd15f0dc5ccca87,
+                    0xee8f8a9e3816ca2a,
+                    0x9e7c5e1c10d20f4e,
+                    0x8188e7d7b73a02c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73bf0420485d9d7e,
+                    0x47f239d0a6932ca1,
+                    0xd9c66bb7f3706888,
+                    0x3c4964b0cb49e3b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d0f24a308d23bd6,
+                    0x74c81a2dbf4106b8,
+                    0x5659ea653796d54a,
+                    0xa3fd3548bdf06623,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7244024b1ee48963,
+                    0xef0de2dd8fb54e5d,
+                    0xb239c2c1dc9148ec,
+                    0x0384fc0d09e41e0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x863851d686b1de4a,
+                    0x0eef86d9f48e84ca,
+                    0x050abfd8cc322d88,
+                    0x53460c9668348b92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd082d03d791528cf,
+                    0x708474af503af925,
+                    0xc9754d4de0c7554f,
+                    0xcc46aeda7bc830c8,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd91d4686ce00a22a,
+                    0x5c33e70d47ec5ae2,
+                    0xa4ce15714bdfd930,
+                    0xf50e322d2f27cb8a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d99386bcf4cc227,
+                    0xc2d1f76a9131ceaf,
+                    0x9f12603496e5a0f3,
+                    0xc61bd009ccb1bfa9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x917ca88d7f1b9622,
+                    0xeab887dba9af89fa,
+                    0x66bb2e93493fc05e,
+                    0x77b4cf912598756b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1077ac9ed77965a,
+                    0xd994f14b65f621c3,
+                    0xbc91196695d29064,
+                    0x72c516fecb8e043e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf912117c6a608dff,
+                    0xac5b3a440fb2269e,
+                    0x6ec6561165c8c112,
+                    0xba444732c9f3a517,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57a3e1641237cff7,
+                    0xa4ef209c711fb7ac,
+                    0x3812305e126e8969,
+                    0x2ef965465f63b0c6,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8d8f934310dfb119,
+                    0x910e4995f054cf5d,
+                    0x1d092b81da836f5e,
+                    0x80f9f5c2cf8abb2c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe584bdaddb91ae4,
+                    0xbb7721a1ee8ee0b3,
+                    0x3a76c400869f5bb6,
+                    0x6d38d60298340688,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe61806c39ea07052,
+                    0x7b62fad0032cbdb5,
+                    0x81ffc44364abf0e3,
+                    0x20758cc713596d86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6afc82de05942cfe,
+                    0x948a10a83da8d8ec,
+                    0xfc7cce91cac2b49b,
+                    0x4f2c1ae75d3e099a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f15bd17afd6e921,
+                    0xf2452d6841fa29f5,
+                    0x7bd75f94277e9c3b,
+                    0x7142600cd16f8527,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6e411d9545a7d56,
+                    0xe362e1400a484e4a,
+                    0xf75255a5a44df46c,
+                    0x45ffd9adb3317a47,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf335dec70ebf5640,
+                    0x126af6820463c5d0,
+                    0x3bde1b86fc936dc3,
+                    0xbda2b138a3b0b7cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cd522f40ace76c8,
+                    0xb9de5e7225e37e2b,
+                    0x33141d1a5be27744,
+                    0x557b9bd9d3ad1cf7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebe22c84237dff8d,
+                    0x6e3af3f859150a09,
+                    0xccecaee37f57295e,
+                    0xa96fbafeb75c0da6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0bbdf1e5882b359b,
+                    0xb20d7eb68b0fc9e7,
+                    0xdfe1f8c561cebe47,
+                    0x8c6965a7550699ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x169951332c6e1022,
+                    0xc460af3ef9fb9a4c,
+                    0x4107cf7dc9b8891e,
+                    0x8e42f745afc1ec50,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7ea3707df644b78,
+                    0x1d11c0fb7fc2fcf4,
+                    0x9c2b7b38339f71f1,
+                    0xc0fc41bc2be6b0f2,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdb82505fe5fd601c,
+                    0x5b13f4741faa6db8,
+                    0xb35cb39b08dfae89,
+                    0xdbdd5b4c93c9f758,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f230ea5e7084bc7,
+                    0xf0726358c949494b,
+                    0x8f63225c9f21d986,
+                    0x88dcb3239ace2904,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed715ac828b168c1,
+                    0xed7d47610a25e23d,
+                    0x5d833f8e2af2fc3d,
+                    0x8de1a09010458266,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b3453569b68922c,
+                    0x681de64d966af217,
+                    0x63b718067237178b,
+                    0x7c3c96b0ab623dec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80a70860865ce23b,
+                    0xf4dc4843eee50db5,
+                    0x81bf71c093e2841b,
+                    0xe88dc73cc496273d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb1346c3e31434561,
+                    0xe5b81ea780b4fe95,
+                    0xb6b89991e789713e,
+                    0x9c5ea7f7e1362816,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2b5764dac94145ff,
+                    0x3d84d7c504dd8049,
+                    0x1076869d8ffc4974,
+                    0xc13a0cc757e541e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc28124721dc670b6,
+                    0x1a716940014798c8,
+                    0xb751937625c88ad6,
+                    0x8d2834bd59272426,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f3f3c5a2ca96681,
+                    0x75dfd58c316c1e7a,
+                    0xdbcb5b6cf5215af4,
+                    0x9d4ab51435217cdc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91a6cf405f774852,
+                    0x68274d8fa35ff240,
+                    0xc635183e832c1308,
+                    0xebe41258505e7602,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd5cb2d8b44d005f1,
+                    0x4891a91081314299,
+                    0x633aecf4e107e2e1,
+                    0x35d588b60d18da59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3677862a70d40a18,
+                    0x3e02459b9d58b73a,
+                    0x7ae073d0ddf9eb51,
+                    0xd3a04aabfce3d11a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3a09ff75aa273e67,
+                    0x55d6d40b93d1d286,
+                    0x4fb8d2d2054c18bc,
+                    0xc68e02a9bc08aa6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45bc140bcf0f0120,
+                    0x80690079bee15112,
+                    0xd55a1065d7f02cc2,
+                    0x59c114900f545d90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe14731cd1b4ccd6c,
+                    0x402306bb93e56313,
+                    0x653c34ac65c4f7ac,
+                    0x732bc48c262914f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe4ff6e6b84a53fa,
+                    0xb3c5c63465aba18c,
+                    0xd7420ec7608d283e,
+                    0x0505b2e16715c3d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80c6b57d6cc84c58,
+                    0xb7d3485ce5c800bf,
+                    0xadbbf1476ffec0ff,
+                    0xacbee648c12fabab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76be8ebc924311df,
+                    0xb6b0ae7b487dc5bc,
+                    0x166c6c0bbaee5a53,
+                    0x67a2dd686fa62486,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xaaa8d2a8c4f3b5a6,
+                0x2f42ed9c534b4848,
+                0xc5944d719772dc26,
+                0x420a6dcd29c1b7fc,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3ae51308aca19c40,
+                0x77c60a2778250034,
+                0x0499fd06bee5128f,
+                0x01b2294d91a7713a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1f682366b16b65ca,
+                0xc7f3a571b9b2cab6,
+                0x9eaee3b7a037fc12,
+                0x28752b57f6abc754,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8b8933b8f0a2b084,
+                0x57e027e28a9043a0,
+                0xcacd255f4fb1da37,
+                0xf7f1d5fd9b80e4c5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xadcbe247a00abe2d,
+                0x4ce3c13f05e2c68b,
+                0x9a795b57b5f9bdd9,
+                0xd4cda0c81cbfb0e0,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3b971d83050ba995,
+                0x1e01e115c2d73afa,
+                0x74b6e07dab012d19,
+                0x8844f649770f73e3,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x393b20271a161987,
+                0x5dc303410bb53140,
+                0x5d56ac47110f253e,
+                0xfbd8fd61c002755e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc8d31ed0420459d4,
+                0xa315285626036f7d,
+                0x722cea8739fa50e5,
+                0x7debce1fc5e011bf,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8c6bdaa699efd643,
+                0x59378c6d223ec6ba,
+                0xa8d197ddcd39bdd8,
+                0x9007cecfab194158,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf4a88df0211c9b51,
+                0xe3be45348a97bc38,
+                0x81cc6fb958e05985,
+                0x25b0423242788ec6,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 124, 125, 377] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 124];
+        let mut opad = vec![0x5cu8; 124];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,1631 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 192 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 4 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 4;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 17 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 17;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6c4ec16489b6e550,
+                    0x3e81528e99e91eaf,
+                    0x2b4dd15b1f5f3579,
+                    0x795008440a1f643f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xccaca490effc5d36,
+                    0xe2b706a38055f775,
+                    0xfed823b24051c0de,
+                    0x0ecd50b8765ed5aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b8268114087fe22,
+                    0x68171630957195e2,
+                    0x47e394b6f37830db,
+                    0x1b178a6bae1e3678,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48d4f61167ac4498,
+                    0xe6e96af110256624,
+                    0x28639b8a15942af2,
+                    0x62b7f33377116f6f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4c25ce87292b614,
+                    0x3fa999ed83ef5da4,
+                    0x563fba348a5f6d20,
+                    0x4f7e649fca097d91,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf70f50023ebaa487,
+                    0xe5d5097fb27cd71f,
+                    0xed786716ea77618e,
+                    0xb8d2c0563a41027b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc5f271c81b8b2d71,
+                    0x50eaf2d7fe9a005d,
+                    0x171de8eb8ab6bb60,
+                    0xb712ddc481ac1d4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3acd50e20b66c670,
+                    0xa082e8e718e4a4d5,
+                    0x0be3627cdd006f1c,
+                    0x982485033586ff59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x362c7c6ead34f353,
+                    0x7340056952024248,
+                    0xc39d693bf8ad2a33,
+                    0x2b9332f56e6146c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8969cdfed7f6b2b6,
+                    0xd2b6f1881e674607,
+                    0x588602797c7ddce4,
+                    0x26d6b1f6b30ff422,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3d4d2b2d29f5f55,
+                    0x8d2127cbd962c485,
+                    0xc4387317719ef1a9,
+                    0x51d3c7d4e99cbd44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x918ffe5b740c5e37,
+                    0x3ddf3c4855b6f017,
+                    0x261110a12e7eec36,
+                    0xe8fc3056c5e95cbe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x901a1b75fbcdddf0,
+                    0xe30a2028a1df6e2b,
+                    0x9ad4a35200488afe,
+                    0x6550d172c54295ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c980894405c4e6e,
+                    0x93a9b9b7af85ce58,
+                    0x981aa25db24963f0,
+                    0x16647cd32181f8ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e38c6a2c7178236,
+                    0x6fbd5a7cd9acf563,
+                    0x7f86185e600c10cc,
+                    0x0f25af8865df4339,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63f6819d2fbbbcc0,
+                    0xa6c25bec37ac0a7e,
+                    0x415e35a0acc18786,
+                    0x130b80d6152f9ebb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbde87d500e9c49cc,
+                    0xf0b0d60f5d1bd099,
+                    0x993bf1c5a050d4e5,
+                    0x21ad83cacbe3155f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe00a5b2f6fbdcb2,
+                    0x3e6bfdc181378284,
+                    0xb51a11cb78976dae,
+                    0x85b45d4edcc710bb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4ac282ca466cfbb6,
+                    0xdbdf4cc2922b6bfd,
+                    0x79bc2d98f686a979,
+                    0x4c232ee0c1298e34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbe4c4a6254d562c,
+                    0x7b9e56006616dd2d,
+                    0xae5a70c4556f0338,
+                    0xe5fd2404f035b33a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6da9bd22330cd4f8,
+                    0x69bab18da6ff307f,
+                    0xd05b3d3f33499619,
+                    0x70f4b72411863f89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f74fe7d34a10b40,
+                    0x9b65320c7c88acf2,
+                    0xf2ab9c5d82221ec9,
+                    0xb04db3c4b43ebc61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23a4e78b95ec9a0c,
+                    0x0aa4a70b39cf069b,
+                    0xd49834ec907206d4,
+                    0xb99e02c5ae9ec04d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d69d2322cf666a7,
+                    0xed09569afd2258ca,
+                    0xda9b9fd0ff26f4ec,
+                    0x0cc341de7d5664dd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x96da38ed5ccabb12,
+                    0x4a71bf422a4f1337,
+                    0xfb3685ca2b36b4a4,
+                    0xfc00ea0203a0d123,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa607fe066bb2ae3a,
+                    0xdaeb44c838c52c30,
+                    0xec12372b060781de,
+                    0x33313a5b59a79301,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x118cabb34972f047,
+                    0x6efab50a7cf68398,
+                    0x7da0ea25dffd64b8,
+                    0x99f14d08c20fabcf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa928a9df4ac12c59,
+                    0x71cf84159a1b9568,
+                    0x229da9ccfc062024,
+                    0x37dc6529d46464f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26002d27aeb77f55,
+                    0xf1af776a8f986dab,
+                    0x74b8946ff96bd847,
+                    0x03d53a3840157cc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc879e3805a405812,
+                    0xfba4d9726e55c85a,
+                    0xd9ec6cac2cee387b,
+                    0xc7613d9716a9f106,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x66b1fa89bf06a4c6,
+                    0x6b534b55ca25771e,
+                    0x33b7b58d7bd830f5,
+                    0x7c17aacc2c123ab3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3bba126462666f3e,
+                    0xb582616b4c2f5a09,
+                    0xf5a662e1e07f22eb,
+                    0xed5661af2d7a6f3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e6899f1e2015bd7,
+                    0x3f7f0822e33d58f6,
+                    0x25dc297e2f03bf86,
+                    0x209ceb5782dc216d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b701e7869fbb913,
+                    0xec9168a00b8b6610,
+                    0x3b25ad5bdb49a22d,
+                    0xd6cf540876de3c56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdcb389a468fdbca8,
+                    0x1b1a46c33bfbc62c,
+                    0xcfb5ce12896eedc5,
+                    0xadd757fa0c9a1eae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x506e6626bf491ccc,
+                    0xf8205eda669b0c66,
+                    0x6872848a0f3f8804,
+                    0x1596729c49cccadb,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc1d4f9fd24f57d5b,
+                    0xa685049772277edb,
+                    0x7a7de18aaab1a0fa,
+                    0x5803f5fd18ae75be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef5232aac3a6c2bc,
+                    0x4eb53ea7faec83db,
+                    0x8316e59d50aacef0,
+                    0x0b0c70bf9180ef4e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf045636bcae8604c,
+                    0x592b12ec59d73dcc,
+                    0xc3fbb11c88f755eb,
+                    0xe38b1e8dde2343b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xadc3821ce004a6d5,
+                    0xa4818f211516ed62,
+                    0x4ad9860be0291db0,
+                    0x16070818be097b55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x482e95c27611cafc,
+                    0x5af8ffa0649b0411,
+                    0xf8143f240b1fa61b,
+                    0x62d2ba3ce7f93704,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54894c67dfeee637,
+                    0xcd0dca30f5ac6702,
+                    0x63e5c2f611c7f80f,
+                    0x0884e7b1f06d0a36,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbc0515e9323f30f7,
+                    0xdb5bd5a6826290cf,
+                    0xe186ec53273877da,
+                    0xab466b5c2df9bca5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4cf5d2becdfc828,
+                    0x1f711d79efac981c,
+                    0xf779d1856b9d74db,
+                    0xc20f76c38ec6389b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b2007d75677d0cd,
+                    0xc1c64fca5f84db3a,
+                    0x3cf742f2ce543073,
+                    0xe1f52f21833b11f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf12425f9fdd5b0b,
+                    0x234e90e73660178d,
+                    0x2c941160f289c465,
+                    0x7f78210446d9b699,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1082fc2ce9aba292,
+                    0x47cf707804028c2d,
+                    0xaf748cc765202a70,
+                    0xc5641de46e992045,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9958b4029d4b00a1,
+                    0x24babd5d092d1a8e,
+                    0x262db085547abcff,
+                    0x309ee58e3f9becff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe71c583cb12b7693,
+                    0x8979b2e41744f2c4,
+                    0xd7604148d46d4002,
+                    0x914935a40dd5a50b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c3c271a3ac9b2d2,
+                    0x998f453b53d4723e,
+                    0xd8633519c3451b2c,
+                    0x315b07500ef23a88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f6200bd71fda0fd,
+                    0xebde688964b4b859,
+                    0xad629c71c17ee3b4,
+                    0x62d9284074a16600,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda18f0efb67c8527,
+                    0x00dfb038f71b2920,
+                    0xf5cf1f18a8b9a184,
+                    0xc5373f4c52fb551e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5242f0e2df356d2b,
+                    0xc9b1e0359c3f1767,
+                    0x61590d7da9dda439,
+                    0x2d4eca003acc0498,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23688519d7eabe92,
+                    0x463e554534184d73,
+                    0xe24faf2ff9151130,
+                    0x702a288b58b80eaf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa3d6212405909afa,
+                    0x24b58121d55048ed,
+                    0xc2dce7ac05ffaea7,
+                    0x4167c0e90f24113a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9c18c466fc373cf,
+                    0x320f12db323044c9,
+                    0xeb92ade76c084017,
+                    0xc1312343c2eefb55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed556464875fbb59,
+                    0x2de6006a642d9030,
+                    0x4c3fe908434d3bd7,
+                    0x56a1e0b3e93102bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49b597050da1faa5,
+                    0x0120e473de636d47,
+                    0x0a5ee68d66a906fa,
+                    0x7df9591f1dd3888b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4230c55cfa73a271,
+                    0x0a6eddae46ed4647,
+                    0x9f510d6c54f9c70b,
+                    0x61f79c5789edc6dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf753e752a044d691,
+                    0x8badd518438222af,
+                    0x3d66fce6d9c81883,
+                    0x2f4ae0277201e589,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1f83618f4070d907,
+                    0x7e6f8c1d3d11085f,
+                    0x934f67912d94f401,
+                    0xf277a85391d64981,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd32225e3c4cdcc6a,
+                    0xdb3cd1f8e6f92d8e,
+                    0x28e359e67b228bcc,
+                    0x5e6117dc9cb85495,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23af237cfc8b1dd8,
+                    0x4ac99b941f36a4d5,
+                    0xbac16e93351b8a6a,
+                    0x47f5d95f6d1f2461,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb476dfadf934bd90,
+                    0xf346ca2838720af2,
+                    0xd7348ce955eb2ca9,
+                    0x863b8244c360b34d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6bca7efecd7b5d7a,
+                    0x54178f80666703e4,
+                    0xb33faa55c85d3735,
+                    0xbb582d89d28a2b09,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2dd59c74e132488a,
+                    0x5741c9af63d25bb8,
+                    0xb90a4658680ae50b,
+                    0x9aa6791c92f8582d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd57196777d809f66,
+                    0x68e29abc15f9831b,
+                    0x4a5abbdee192dba4,
+                    0x4acd9c52e99527d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x005ff31357c3a0e6,
+                    0x80a26edcda81bbee,
+                    0x6b56a887fa14a23a,
+                    0x3ac351990c17c615,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ceb0f0a4218b0c7,
+                    0x0019beca00d673ca,
+                    0x3d600e8703af0faa,
+                    0x12f685b8bf60b1d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4aae9f3fee6a08fd,
+                    0x8239a4e7151f5807,
+                    0x337986df241980a5,
+                    0x9a93cd227510e723,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39c21df9218b343d,
+                    0x666111f87ddc4912,
+                    0x79b9fa42e7e1e638,
+                    0xb67e1e7f914a0f08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x629e5af99037b05f,
+                    0xf8d9a40913be8703,
+                    0x15e5a9795e7e29d6,
+                    0x6c3c4ce6a5b4bc34,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe4afd2abc23a48fd,
+                    0x1baef830966916a4,
+                    0xd23dae58d98b89aa,
+                    0x66b043b5c7bffabd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82940b9a0d962470,
+                    0xc2b536c39a362036,
+                    0x6bda1089b0631e72,
+                    0xebe105424e6966f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5e015a762d474c7,
+                    0xa37804ecd52f267f,
+                    0x12a79a04d18372e1,
+                    0xc2398381c8364b24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fee3f41a066117c,
+                    0x3887a88ca2c5590b,
+                    0xa80a58f9281d1026,
+                    0xc7ec1633cb0bedfa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc6bcd54f5507c39,
+                    0x855960fabd2a8775,
+                    0x481eaa820027b31e,
+                    0x4689a0b327cf1a8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c6e59c6cad72db7,
+                    0xa2d32ff1cc3a2b08,
+                    0xfdd48ae93e30c63e,
+                    0xddfd8085bfc29a0d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe2b4b82a2c86d153,
+                    0x47eb5e4f175d3a55,
+                    0x697cd3f3f3811378,
+                    0xaabcf1a0e159722c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96ca80115219d992,
+                    0x6d8f37fd3d09908f,
+                    0x731e1629730aab46,
+                    0x0fd1c83f9b7c0b40,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x303a6096480f8c07,
+                    0xeac22d52d3efeb0b,
+                    0x44e5903f55c96f13,
+                    0x47ea8a48ca0d0611,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e4ea1a969a82472,
+                    0xdf1ddaa2845ce422,
+                    0x72f288c746354ff0,
+                    0x96cd3ce91ab41c15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38ab4dd3b8e12460,
+                    0x15101e8e242d925d,
+                    0x9f2bb9c1f4185946,
+                    0x8f5a4571b1ded578,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c0895464c5f012a,
+                    0x75ffd774c768f9e0,
+                    0x4032e0601b667a56,
+                    0xdc8f5bb8596cb6bc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe9da4b694102f486,
+                    0xe4a7c856540e601a,
+                    0x2224243ab0582a0a,
+                    0x8cd58bf388547e4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xede00530ac22972e,
+                    0xd30590b1b163c0e6,
+                    0xc39a1f2e3d5e4d2e,
+                    0x4b23a5fbf998b5e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c25b1c940e763be,
+                    0x3971ba269090e072,
+                    0xd0a8f7a8ecd428e3,
+                    0xcff10d1a6a806e69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d737ebe6886263d,
+                    0x32a747416a442580,
+                    0x712961192d340d1a,
+                    0x20a597620c298c30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf965cb5972c0b4a8,
+                    0xca45dc5734ae3fcc,
+                    0x66558e5d48f2cf48,
+                    0xe192ebb43caeb518,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8626a1ba8cabe97c,
+                    0x0094eecbce007833,
+                    0x6b381c10f6cd71c5,
+                    0x96eeba56ca727034,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf8bc0202871e4b93,
+                    0x6ea765cfff3c5e0e,
+                    0x984868047d584d20,
+                    0x6d3f4f0b71889567,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x172f029e74955944,
+                    0x641a6f8cb239f03a,
+                    0xe89aa52ccdb03010,
+                    0x2e93af027b31407c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba4ed03ad16b477d,
+                    0x1a01bfcf21322987,
+                    0x87673e64dcdbbb21,
+                    0x4d0487cdf02a4f3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0857fa1ffbb73ca2,
+                    0xd04b78a8f2b798ea,
+                    0x667608621e320eb4,
+                    0x8dcbe9af400984d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e6cb10ae15d7095,
+                    0xfd17f6e14a0dd3c2,
+                    0x1b6b6de97afe8a64,
+                    0x03629f5a783957d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d22061ad67e3c69,
+                    0x07fe513a4a401a5d,
+                    0x37fd5f5d315888ef,
+                    0x6df1903dbb9034e2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x65d3bbf9b27ea4bb,
+                    0xdd988f7ebf0724ad,
+                    0x7cc534c8a9cf8ae1,
+                    0x46d0f29c8c1cab87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48fbb71859ae18ed,
+                    0x8ed3507a5ebe2bfe,
+                    0xbd09f84df38e75e2,
+                    0x9f8b702a10f8571e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67f16371e4346831,
+                    0xdb70a42c178854d7,
+                    0xc5771364b7f5761c,
+                    0x387770cf9f15cb2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc95b263f2e42b48b,
+                    0xbf02e8ee886e47ac,
+                    0xb09cc5c6b5263f1a,
+                    0x6525cdbfd0ff86e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e6743337bad35d6,
+                    0x5d87279c04638546,
+                    0xa19b952785a7ed12,
+                    0x65134f8a3d3c1a8a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c0955c77334c4a5,
+                    0xae812751b90368e6,
+                    0x2b4329ceb1f1257e,
+                    0xaa599defae2b5973,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe87c4bbcf4d84dd5,
+                    0x5402a97af0625131,
+                    0x1af5531aa5ff1950,
+                    0x0c769b9622457323,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6dbbdea83de1118,
+                    0x2789fd854c540dd2,
+                    0xcae686bedc5ff128,
+                    0xb6a6de62b9d3edac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfefa00759b7514e1,
+                    0x64d48b70ee95c353,
+                    0x6e4cdd5a1d6a4430,
+                    0x1936192b85b4d8e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe480995683f29007,
+                    0x42ef314e4fbb85c4,
+                    0xdcf05ef5d4aa3d57,
+                    0x7ca4767d9c527757,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c387d3c9727f78e,
+                    0x3b5a6e8b5c48fb04,
+                    0xe91dc6af5d4d4b47,
+                    0x50c5463b5dc0dbe2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9e27a93ca2ef88c,
+                    0xb51109fffd6bb06b,
+                    0xf2d30acdd30151f1,
+                    0x8861cdf3f8cc5389,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000900002259,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000900002259,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000900002259,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000900002259,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2cd3aceba1d12e95,
+                    0xbf512b39489714c9,
+                    0xff6f32005c9f10a1,
+                    0x8f8822208e427e78,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8e41f3ef84dd735,
+                    0xc8cc3dbab02caa5a,
+                    0x760e8cf0fe7900c1,
+                    0x993dd37d6ecc431b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6839946b7197bd0,
+                    0xb93a244232a08aa4,
+                    0xf53ab5990e892e52,
+                    0xe22b53e56ddfa24e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2dd68c2621b177a0,
+                    0x9a57560f4bf9ad0d,
+                    0xbe7898d5df43d351,
+                    0x17d45c218b2ebc31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68a4e4e3a159e9d7,
+                    0xd1c38a9271b4bda0,
+                    0x3e124056b6afa9cf,
+                    0x7c4b63d448b57e8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41a001094b2ebb80,
+                    0x6b66fb6ea67f1a75,
+                    0x7e3ae36866c4efc0,
+                    0x798678d3023bd7b3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe286e31624735a6d,
+                    0x38456684ea3e2613,
+                    0x2ca7a6f69834b577,
+                    0xda6fb7e64915ec37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a2e59ff56cf52cb,
+                    0x75c11e44d0368c07,
+                    0xab4b9adb67e80bfa,
+                    0x924e58badafc8fa0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f2309281fbde134,
+                    0x3013803d57112a9c,
+                    0xf47a2244a35754c3,
+                    0x57f3276b099567d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77f0533fd6843787,
+                    0x46b7bc376992d3de,
+                    0x88abea85049d1f32,
+                    0x752d65238bb87cf4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f9fc81942389e53,
+                    0x286985ea78ad38a4,
+                    0x38363477e21ec36f,
+                    0x305157e55b67e075,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2459206de0aa9e2f,
+                    0x944e0dc1b84e951a,
+                    0x90a8966d3de4d619,
+                    0x216b68f689bd533b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x85dff9ffb3cc0b53,
+                    0x4b6b9aa543e0c31b,
+                    0x8ece8ebcb20f98e7,
+                    0xbe3e0a33c4ec68c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c2b65f8afdbc261,
+                    0x6402877bb724c258,
+                    0x57b92a63b432f1e3,
+                    0x47cc593ec16e7f71,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7949937b012e020,
+                    0xba6ea3db3e1fc1a9,
+                    0xfaaf83873b3af432,
+                    0xc19556f0fd169f3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x218bca1df9c76454,
+                    0xf4497d2e49ca0d69,
+                    0xc933d9eacf68e350,
+                    0x9fd0c6afedd37557,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7e6e95038ff14ac,
+                    0x62d0a0e7417b2881,
+                    0x2509cdb9c75c685c,
+                    0xda726c312b9dc9de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdfcb82884d743924,
+                    0x920dcb12d64f5d1a,
+                    0x0f622841fed9ecb9,
+                    0x5c4d14d58ca81bae,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe35a4b96d6bbb0f7,
+                    0x86bf060933f40e94,
+                    0x67593ed236805116,
+                    0x17a30a6801fcd309,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ed775526ff57f1f,
+                    0x20c714ca150ccae0,
+                    0x1718c85fe19bae94,
+                    0x82e09577bd38c397,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbb1d8e1db5b874b,
+                    0xca1260cbd12133ee,
+                    0x38db1b9f2294d292,
+                    0xc35623f2bff54fef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb834b2c84ad0f8e7,
+                    0x3d8c0f633d55cd0d,
+                    0x3f83388b961aed56,
+                    0xb2abb4649c9ba790,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5496a587ab016a86,
+                    0x75f8b2166c74b8dd,
+                    0x86cae66d06ed3f72,
+                    0x1ab7eca5f3e61b38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80c0135c764dce9b,
+                    0xcf91ae871b212e8d,
+                    0xf0b5b17bc08636d2,
+                    0x05e557fc6445c139,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x801034c8b47a543f,
+                    0x72f81f25b8fc1211,
+                    0xc5fb19037aa279dd,
+                    0x9cacfddabdc231f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6a39d68b8c9fa0c,
+                    0xfebdcc7d645e4f31,
+                    0x8a346744c53e631a,
+                    0x8fc2b5357a566432,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2684e78555e7320,
+                    0x030da322b35beedd,
+                    0xf8d0aa0ca8f28ca5,
+                    0xac75b8cf546424e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb12fb951af4799c,
+                    0x20a9697bec4a9cb2,
+                    0x742495879d29d633,
+                    0xd33e1317e719df24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6820bba79aa1fbe,
+                    0x491109b956b1c532,
+                    0xdeb5104a570bcfe1,
+                    0x3ede56b95e6608a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x209a45a3147f2254,
+                    0x861987144e9c4066,
+                    0xc0b2af0bf80f2bd0,
+                    0x3eb95a4e36df2ff4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x64b4eeb5c5139fc1,
+                    0x0ae6d134c8345fea,
+                    0xefef4891ea05672b,
+                    0x4d59b16959bd1dba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac460b094b15a161,
+                    0xaa0a49530877a900,
+                    0x30c271e7da9dd709,
+                    0x0ebfc57b6d37eb71,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f4aa196fddc0f78,
+                    0x886685670c10c4bc,
+                    0x8a135d68ebc78149,
+                    0xf540ca50a6591ff6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2cc6d4e336992253,
+                    0xae378966ad7db391,
+                    0x0b0ba1629adf746b,
+                    0xbe08c0d7e119a075,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x039cc3d5ed2ea7d1,
+                    0xb9390c7648e73cbf,
+                    0x72c1ee5eae4d553e,
+                    0x4f1477a82b849574,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x130c8ba11399730e,
+                    0xaa0f86f06202273e,
+                    0x8b1cbdd1bf93711e,
+                    0xf7561723ae615e8a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
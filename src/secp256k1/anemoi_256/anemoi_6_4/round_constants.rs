@@ -0,0 +1,795 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x0000000000000000,
+            0x0000002300008593,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x063a893b5e031e1a,
+            0xc759ab3b5432011c,
+            0x1a2e82d7ce979427,
+            0x390bf16667c443b7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xddcc8687187dedb1,
+            0x8d6f3d3ef74084eb,
+            0x85a431cc17b3f493,
+            0x1149232813f2563c,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9fad2ab1de4ffd94,
+            0x36e9d41edfbb48bc,
+            0x3ce4e373e3338ab4,
+            0xe0d5b70dc27ef66b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x71abd6625c7f402d,
+            0xecf27194d7ed1a97,
+            0x3bd58c9cad01ebf4,
+            0x1e37005f634ec584,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb968ab3780c2aee4,
+            0x9d315a85c075c915,
+            0xdb0bace6c1e9cff5,
+            0x6eea207f535b175d,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x1868cc7b218cfd08,
+            0x88dfef1cf454847f,
+            0x2d3f03efe6c22223,
+            0xcb6ffb67274f1513,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfb938d1911f1e1f0,
+            0x27880225fb5e931d,
+            0x0dd4734af814523c,
+            0x9235f5e1ce5177d3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x18de3dcb8a376608,
+            0x007d3c49db685872,
+            0xf95beb182b28206f,
+            0x0c61d9e712c4261e,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x20ddf87b328cc902,
+            0x66561d228b030ff5,
+            0xb0d4ddba9d8459bf,
+            0x479bf57319de8030,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x833aeab772c0e5b9,
+            0x6368ff7dcdb566ec,
+            0x45752631fe5a1664,
+            0xae7dab3ba08e20bf,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4ed8f542a4ff338a,
+            0x0cfa6a922826a0c8,
+            0xade0dddb63173b49,
+            0x0c96d43357249e0a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc309851b18057eb5,
+            0x58f22d696580a9b6,
+            0xf36ad2fb07d2d517,
+            0x151c5c6499c72abc,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd0190e3d8f31e364,
+            0xe41565118e2ae455,
+            0x02e8d98399eef919,
+            0x77969747b0cc4741,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe3146e852473bc4d,
+            0x9cb979dcda9d706d,
+            0x75469d4f7f289af1,
+            0x4ce677ad440f185a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa4ccf14624181d52,
+            0x8bfe0a6a8027c593,
+            0x96a2a2e68d5d69cd,
+            0xe5d6078b3d92f92b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb97273da12bb14dd,
+            0xc718f188bc54172e,
+            0xc34ec759d19d2720,
+            0x406e067fc70b6cee,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x03f3ff114f349720,
+            0xc4e81e50e45d1a72,
+            0x804a0e086e16cc4c,
+            0xea38fd44db6ebb56,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x715fae29fdaa3458,
+            0x9e3c5ebfcbdacbb5,
+            0xd32cee23e992b308,
+            0x7e750739aef968c1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x68eeaad955f6be00,
+            0x7e16df906ef0f5f4,
+            0x22fe83c05a677ff1,
+            0xb211448a437ce05a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc298c36eec2d4b42,
+            0xa5e4e245445ccaa1,
+            0x223988a706d0e512,
+            0x31ebed12c7341867,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3f4b7168d163f9d5,
+            0x9dbcc6ea97e79257,
+            0xb1883138228b13dd,
+            0x2bb79af062c2269a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf9d16fe6debe0c52,
+            0x90afc95dfd26e641,
+            0x0980e79242832160,
+            0xce0067c45ecdaa91,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x46c4cd78ca44f86a,
+            0xc0a8375333248eaf,
+            0x1cc1aeae25d39e01,
+            0x0df5b256139e2bed,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf4460a2f397b30ce,
+            0x82deaeb6a6f7eb90,
+            0x6376261e0c6aa77c,
+            0xe9c9169c5512100f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf9ae780865f9a562,
+            0x62437a1eedef75f9,
+            0x54cd922bfa409042,
+            0x167e79f70b3820ba,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaddf11cc3aacbc9a,
+            0x7eef68bbca6bd7db,
+            0x3ed3153ff9eac18f,
+            0x4c87369945842f6a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7e4de3cf1b5f8617,
+            0x4da262289ad1a8e5,
+            0xd2a2d2e1657e00d6,
+            0x7e8818adee48f8f3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x38e71936535645de,
+            0x8bbc83ef87ab8845,
+            0x1e5dc61a2f2842e3,
+            0x9043aa12508c07c8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4008ddfc8505fbe9,
+            0x257fcbd669efd56f,
+            0x0e9060084bffe7ef,
+            0x928928568205f80e,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd52937c6408047b0,
+            0x2bdd02a81eda3b8b,
+            0xa73a608fa01b2634,
+            0x9294d7c987cabde6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf8c661842467febd,
+            0x779e18ecff8d95d4,
+            0x960aba7a63afca58,
+            0x1cf2eb398233f9e3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5677afbb899415e7,
+            0xfef0ed8190cadde8,
+            0xd68e433f782c7295,
+            0xdbaab132d64c81d9,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb85933a49fe2167f,
+            0x1a920822381e5848,
+            0x8c2ceaed29f9454e,
+            0xe2455b591f564c3b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x67994d7b2100c3dd,
+            0xa4e8f5ba28bcf511,
+            0xa5dbcfcc21c3c779,
+            0x83e77e3557dca9aa,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaae995a2b3b5a5a4,
+            0xe7122c92319a1f3c,
+            0x9bb1ae2ac63cafc7,
+            0xe751189651b05a7f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb597b6898e8cceef,
+            0x6939a114c338a520,
+            0x723d40980f164866,
+            0xdb83046fd2329473,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8cdcf9e57974a8a7,
+            0xe7f0fb248240214b,
+            0x8ed9742271b82cd0,
+            0x1197cc329c122095,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x7d167fb4d9c1624f,
+            0x7958ebe6b7994d7e,
+            0xaceffc6b7476e43c,
+            0xbed27d943f18f5d8,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x4cff12d37f0891fb,
+            0xbf584e818b101aef,
+            0xc5dbbd43c6d43d91,
+            0x6ff7f332c5c72485,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x16982273eef9f6ff,
+            0x243c1c22fc2bfe85,
+            0xdad550ea25ee733a,
+            0xb2ed03014fd2316a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x3dc414cc60573bf7,
+            0x767a2cc94795dcae,
+            0x114c2811c8800c20,
+            0x7e0bb6837605d0bf,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc72f92f317bd36fa,
+            0x87389d19cee2eb42,
+            0xa4823a109400fab9,
+            0xd4e971b900a5619f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6c3a63878f9c14b5,
+            0x952d0527271be839,
+            0xd0f995d87fc64f1f,
+            0x217faf865f54c7e5,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4e8a399c0c9f1700,
+            0x911a805672d548e1,
+            0x74ad597d5af7f021,
+            0x1919bf99316254cb,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x15b17664d10a6c10,
+            0xd9ea158c6c997c8d,
+            0x580bd8b7b52bd895,
+            0x964837d20c407d33,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1202dd591b9547cb,
+            0x233a9dc411f54fb5,
+            0x798b490f06e4a0a6,
+            0xf6de2aa36910cb99,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xace4982240ac4d24,
+            0x27591c9da78c8d03,
+            0xe92ae738007fa4f5,
+            0xc505063a90de410c,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0d1868147d4eae22,
+            0x8a40c44ff279a872,
+            0x529c70a064c3d19b,
+            0x81240ac411a9fac1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc21c4ba8a6ad9b7e,
+            0xc0b97ed7290f85a4,
+            0x84734e1883a97fa1,
+            0xf063cc454ae7d53d,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaa30a29cff72b939,
+            0xacf45cf288034868,
+            0x6be8be6d6938b77f,
+            0xa5acc9fc37e70348,
+            0x0000000000000000,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x0000000000000000,
+            0x000000235555dc2e,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x910239b700be78c0,
+            0x0f42d4c5f2481f5e,
+            0x6e4f6668e5f35b62,
+            0x39507628c0873840,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa7f31a06de87ff9d,
+            0xc7977d3d8480edbd,
+            0xdc2210690d4cf0d1,
+            0x1221dce1e0fc722c,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc8e0a2e8e19092de,
+            0xfcbfba6dfbfaeedb,
+            0x62a10c2b44ffc651,
+            0x3dfaec31a98697d8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x25a6ff14027b2c4c,
+            0xfab1816e3ced885e,
+            0xb5b298e52629eecc,
+            0x7ba0ba45a3195b7a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xacc2b6ed4a0d5249,
+            0x9d2f80d314a0816b,
+            0x5745b43b194f07d1,
+            0xcce80f5d076cd4bb,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd7423a9fa3699117,
+            0xeb110ca79c634666,
+            0x35d4ef75c2151bef,
+            0x2858de8f68edeb33,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4534abb83689ccd4,
+            0xd1a2493aec2e1cad,
+            0x6a8b4261eac31342,
+            0xef635dcc68b3427c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa1de3f6ed21e0832,
+            0x9cd699d2bb622c90,
+            0x586fb53afc141679,
+            0x6a2376c9216d182f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xadeb385a5da5a065,
+            0xbd98710f45bc701c,
+            0x2b19936744ee6cdf,
+            0x275a4cf9764c7e1c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x9b0fdb12409517c2,
+            0x02947cf4d12f8ebb,
+            0x13dabf6fbd1ff0bf,
+            0x8e80878455bf1334,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa60cc8a096221908,
+            0x9e64fe7d1acb1326,
+            0x7ea37225001a4aa7,
+            0xed2de573809cb7e6,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9b10c9f2d50320a9,
+            0x61c752e7e1936bba,
+            0x2f3f35d3f75e77d7,
+            0x709a81841e71f824,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x32e80390eeeadffe,
+            0x34d3b41a52fe6e01,
+            0x92de1feda0d66314,
+            0xd35941298e3a0931,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x854246dca77b702d,
+            0xdfb6df598e9b44a8,
+            0x0798dec5644d39ef,
+            0xa93d568695c401b2,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x21ddc401b70e45bc,
+            0x82f13b3a2ae7a666,
+            0xf0c6ceba95043ce9,
+            0x73125db9bbccc4f8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc14af710486c941c,
+            0x05f54be2afd4bfa8,
+            0x7193d6bef09fc177,
+            0xcdeee1709e082d45,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4b2b654ca834d176,
+            0xf6038f1ec7080d7b,
+            0x30ec18796b569ba6,
+            0x784e0d2d26b2a314,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3a98481ef43ae542,
+            0xf5d6bce241b493f1,
+            0x714ac049354b1ebf,
+            0x1cd274554ea19b99,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xbceef549ef42c990,
+            0x1d9a673d2d8b85d7,
+            0x153d3976bd7bb2e3,
+            0x50b336683be807bb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x55f7f0e2a8c80a47,
+            0x37a78065f221a514,
+            0x16d5396948224d08,
+            0xd12213e833e6672f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2882a416d64f6d47,
+            0xb82e9d07656cfa21,
+            0x1a84c7de305ecfe8,
+            0x60817c13836e5f9f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6dd053118664de3b,
+            0xf30ac905136d15b3,
+            0xc69e61c967b2a4a5,
+            0x030ecda9d83cd81e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfa2293a6953a7dc8,
+            0x15424d6e389508af,
+            0xdc3c23f12940564b,
+            0x43984d33015480e1,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xdc4438bbb85ff13a,
+            0xd555c1c8ad4aa6e1,
+            0x5ee11595665b02f8,
+            0x449a2264d4fc17b6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6c74570f8799bca3,
+            0xfca3b6bb3d02f8f2,
+            0xa45965346b8cb2f8,
+            0x71940a81e3e51cea,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6003d3d77f9b8b21,
+            0x0b8ebbcc08a9a563,
+            0x90bbe3544974194a,
+            0xa830fc1b92785301,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xa9acf9735fbef10e,
+            0x62b8449e9d809fec,
+            0x0ebfb27e0eae52a2,
+            0xd4de5922fde01b7e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xef0ddf563a710b7b,
+            0xe8bb8fefd31b46f3,
+            0xae9b8947efb45be9,
+            0xe6de6f49b8e61edb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x358e87208f6f78cc,
+            0x74bdee4aa489dead,
+            0xa12b1e41eac935f9,
+            0xe9b822855ea73688,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7542167eba2115b7,
+            0xbae2f30bf4523113,
+            0x820266335f068398,
+            0xe78ff239f98a7e22,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x23a6f0b840c4276a,
+            0x4e8d32db1dc65304,
+            0xc4f3a3af39f6eef7,
+            0x72328a6c4cb6aea8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc0b721f4c93ef9ab,
+            0xc81f1de39e2de5a6,
+            0x07d427802cb0cc38,
+            0x317e855d15165e06,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x91819be8092e4de7,
+            0xc2820094366705db,
+            0xa57882dcde0eeb6d,
+            0x8c5681dd7fe8678e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcb89663a2d0855eb,
+            0x94c217b66fc66a4b,
+            0x13484b4ced3534d3,
+            0x2e3d297c1131b987,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4e389165e30beef8,
+            0xc92a650267cddf06,
+            0x0b7b24b76feb5225,
+            0x923af8d47f4c91c3,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xdda533d833343fad,
+            0xc77cc236811f5f7f,
+            0x77e929db8320019a,
+            0x8a932e04ca82e946,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x3fb227aec0d7703a,
+            0x8e1d45d088e7a352,
+            0xe8a640f6fd1dad3f,
+            0xc0ec7a89ed2569f1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6f4a90834472e4f9,
+            0x11c44d06ad6b1a14,
+            0x0919c44bde1999af,
+            0x6ebb60e30473669c,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x9e54c06a4e61e376,
+            0xf0bdd76368414d87,
+            0xbe6a13e2f1faea7f,
+            0x01e6346c19ff1084,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf2b58086610ea320,
+            0x9d8ace8f221df8c4,
+            0x27848b1a6870e762,
+            0x451fc8fcfccd11f3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x594055e2f5ba9f5e,
+            0xe207f5a95cb2217d,
+            0x60585d4de93fb54c,
+            0x10d2b1769747d8ae,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x1a3fff8de36c91a9,
+            0xf5bbae69111c8e96,
+            0x9dff7d279566e7c1,
+            0xaa71e8045208015b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4a12809cfe06c639,
+            0x4b994000b2165334,
+            0x1e97bc8098880362,
+            0xf74caa94097a5c2b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6bc139b59e587fca,
+            0x39c5d1a3ecf9fe6b,
+            0xc4a87b3151f6d968,
+            0xef7aef9e4fcf1077,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x4e7610289837447d,
+            0x2de8101cec5ad4c9,
+            0xfe2d8b97faf0178c,
+            0xe6c38da2a02ab6db,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd58f2799857d7eaf,
+            0xbf21c1deda776f98,
+            0x73cddf806404a6d6,
+            0x479e053655bdf9cb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xafcfc566cde33b4e,
+            0xb57f572c5f38f776,
+            0xe5ca78b53bdce029,
+            0x165915c4f1d296a5,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7543f60ad9911e0b,
+            0x411126c97a44a8ec,
+            0x1c9ede25e7f20cb1,
+            0x579b8246bf9a185d,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb50f8a1aa5ab660d,
+            0xbf730adaf99b4dc6,
+            0xa2969f2f1e3381f1,
+            0xc71fc88a519ae762,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xdc82c41321bf3b0e,
+            0x9decff6a47b95b19,
+            0x8c690a8fe1ffeed3,
+            0x7cfcfb38b2e13cd4,
+            0x0000000000000000,
+        ])),
+    ],
+];
@@ -0,0 +1,2540 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U6>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0xce5038b857ce4245,
+                0x046b1f0a3b0c2cd7,
+                0xc464d326628f572a,
+                0xa1e26b3149ac1c21,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4f2364a43052b3dd,
+                    0xc1190f2cefa6b16d,
+                    0x17213fd15af6dfee,
+                    0x3e331079d742eb5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9e2a7821b434003,
+                    0x0bb7ccddb6376204,
+                    0x3cd19060ce119635,
+                    0x6be6548822670149,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf788c5504b797b36,
+                    0x72d2927663dc66fc,
+                    0xc3c520b05eaac960,
+                    0x486ac66aba07111a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc80d0534996a3ff,
+                    0x81ca190bb0f8c68b,
+                    0x208065c4306cde86,
+                    0x4d9b446fb3286684,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1969c5180fbcf27,
+                    0xa4211d4e19194a4c,
+                    0xf550e6a74466bc50,
+                    0xcea4512c503c3cdb,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x54045d4c65f71046,
+                    0x21b497954588e465,
+                    0x2302df887e6fd9ab,
+                    0x4da9b44e6028a3db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0853e0854c50efc6,
+                    0xc20411717c1e0382,
+                    0xe2d6902fe0791210,
+                    0x2ccdb25cf77d6134,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec941170a8e219a4,
+                    0xfcd87f4b4744e467,
+                    0xe30122baa485a8df,
+                    0x907567b05b87da6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb25908c9867c1c74,
+                    0x1e5901e554391d5c,
+                    0x919ab5ebb129030f,
+                    0xda563a45ce0436b4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa1d93a82279d80ed,
+                    0xc4fbb2fdefdf5319,
+                    0x5a51741d60b40da3,
+                    0xb5b7adc677fc412e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a463149bc97721b,
+                    0x9ab9d1ca33e299c1,
+                    0xdf67cd0b04377a8a,
+                    0x73458f266c33f4ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2a3a2d58f10c045,
+                    0x2c6852481d39bdf1,
+                    0x3d2dfd7139fad7ae,
+                    0x9c31c9f7c9c67df4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb48f9f81151f1d11,
+                    0x8c4da6f593d42131,
+                    0x3ecd3cd4eebc625e,
+                    0x9b53a2b4aa0cf510,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb1f81b0575c444e,
+                    0xb26815e8589ef452,
+                    0xdef595120b37fa33,
+                    0x4c0778c3eefa3a12,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5ef0fc4d7d0420e5,
+                    0x5b5b75ca0c851d4b,
+                    0x3ad7a3c6861bb621,
+                    0xc3f39f1e77ee0277,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33b227a7808e6d67,
+                    0xef4fed548c5b6da2,
+                    0x665be69d788eb8ac,
+                    0xb857230b759fb344,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e77eea152c52629,
+                    0x92dbfb0012663832,
+                    0x59632d3716ed3c65,
+                    0x94fe81b7059fb0dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19293c32ab015d41,
+                    0x11ef7cfcd3442964,
+                    0x06cffdeb43ce886b,
+                    0x2b92ff54286f9c21,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x644a286959db2f7a,
+                    0xf95a2927ff68bb25,
+                    0x23f51c527d015f01,
+                    0xf6e9b7c1d71037df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x062de68b511631e4,
+                    0x37b281116d94e396,
+                    0xd1fc77241f001f4d,
+                    0x03a309171bb609b2,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x90adf32bdd4fd522,
+                    0x8beb690fbad4e4d6,
+                    0xacbb0279c9751bf1,
+                    0x91811a2199689df5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd316d5d06c46061b,
+                    0x14d37b4b824d6295,
+                    0xe0180a71f1ebba0a,
+                    0xda3427200495c4d8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x183dbb7945c0bba2,
+                    0xd49c582fd38db6c4,
+                    0x69d0446468a9b625,
+                    0x77b801d7c1151a1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb0d3470d8c8893d,
+                    0xcbb5ba98de934a04,
+                    0xdd6a62b59e975c44,
+                    0xdc4d872e6e96cb89,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x45783e36dea11034,
+                    0xa2e8d15352fe9f0b,
+                    0xb13f22cf97308fb3,
+                    0x1bd1e1a07cdd1d98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81277c9615d115cd,
+                    0x7191a19f4727eb1e,
+                    0xe7aa172168315315,
+                    0xe5c403dfc98ab7cd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x60e3a95c5f4cca5e,
+                    0x03332470e8417a11,
+                    0x22938b050e6c9ca8,
+                    0x27e1a1e7ad73697f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88f664501f3a87d6,
+                    0x2fab6804e14da21e,
+                    0x8d983c133caf2c3f,
+                    0x47f4a3aa9fd56ae1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xae666a78c2863d26,
+                    0x83918c7afaf03a05,
+                    0x4555a7eacdef7b4b,
+                    0xe66ecb4d74be12ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b6cb141ab214f28,
+                    0x2a636d35a41870c2,
+                    0xdc7c1347b9d24022,
+                    0x2af0210136d5fbca,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb6412503ee344240,
+                    0x6c22cbafbc33e9d1,
+                    0x2a2247d487d85a07,
+                    0xf7d56ecf806154e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb8bce81ffabfcbc,
+                    0xf76a5f40969cfea5,
+                    0x13ab3473f6dd1422,
+                    0xecad1945abf1cc9f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x775020b3b7099553,
+                    0x15d161258f0aaad8,
+                    0x4199553cb15fee51,
+                    0x159bea04f9cbbc44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ea225f05b358f5d,
+                    0x95537f075c83f3ef,
+                    0x7492d03e2c0443f2,
+                    0x2c2a03d5d5ecd3f0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdb474c50b7c56d51,
+                    0x89d8e3b0e1945c11,
+                    0x5b864955e194d94d,
+                    0x8ed475046f2d1f0c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd58ec28d01aab945,
+                    0xfe896f6b87c92d1d,
+                    0x863d714c463bf209,
+                    0xb44bd418f5a95916,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x986dadb66bbfd60b,
+                    0x030b184ac15e6af8,
+                    0x24f8ce61622be893,
+                    0xc84f5c0d32e0bcd6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64f49ceb5d49d385,
+                    0x02d2e26a9510f25a,
+                    0x095af6e54dcbe2c4,
+                    0x0e55e696a83ce807,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x06791e279577b8b0,
+                    0xecaa65cfda9cf3cd,
+                    0x811460a73e55d3ae,
+                    0x4d429e5418ca203d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x908b95258ffee53c,
+                    0x50f065fa6bf8f4bd,
+                    0xebd4051941de5b57,
+                    0x370710c93240bbd2,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x90adf32bdd4fd522,
+                    0x8beb690fbad4e4d6,
+                    0xacbb0279c9751bf1,
+                    0x91811a2199689df5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd316d5d06c46061b,
+                    0x14d37b4b824d6295,
+                    0xe0180a71f1ebba0a,
+                    0xda3427200495c4d8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x183dbb7945c0bba2,
+                    0xd49c582fd38db6c4,
+                    0x69d0446468a9b625,
+                    0x77b801d7c1151a1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb0d3470d8c8893d,
+                    0xcbb5ba98de934a04,
+                    0xdd6a62b59e975c44,
+                    0xdc4d872e6e96cb89,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x45783e36dea11034,
+                    0xa2e8d15352fe9f0b,
+                    0xb13f22cf97308fb3,
+                    0x1bd1e1a07cdd1d98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81277c9615d115cd,
+                    0x7191a19f4727eb1e,
+                    0xe7aa172168315315,
+                    0xe5c403dfc98ab7cd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x60e3a95c5f4cca5e,
+                    0x03332470e8417a11,
+                    0x22938b050e6c9ca8,
+                    0x27e1a1e7ad73697f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88f664501f3a87d6,
+                    0x2fab6804e14da21e,
+                    0x8d983c133caf2c3f,
+                    0x47f4a3aa9fd56ae1,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 248];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2541e7eab788bc7c,
+                    0xcf0a5a47adfc9412,
+                    0xf76d66ad73b994cc,
+                    0x93e67bf56f0c4bc8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27968101362420d6,
+                    0x10f7678af0ee0a39,
+                    0xa3fcdf5f4a8ce5b2,
+                    0x777551c9f403595d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2e62028f29c17a1,
+                    0x73166a84af8dd79c,
+                    0xf6faee229730ca01,
+                    0x9f5af9963ce50354,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94e809d000f767a1,
+                    0x30496e3d658eab45,
+                    0x627c7da7da99c60d,
+                    0x3b883a3d856ed00f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb1a76a58309eb1ab,
+                    0x4cde5d860ae67375,
+                    0x60564fccf356e4b7,
+                    0xbecd0a0062125f47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb359f6bc62de8ac,
+                    0xa490dede5cf208b5,
+                    0x11fe7e027902c498,
+                    0xceb862be2d137ae8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87d5463c645f0d90,
+                    0x3b8a44b9de5cabd3,
+                    0x79722af612976610,
+                    0xf7ddb18becd72ece,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ff001e98a5e9147,
+                    0x5b9aaeaf0e3b871d,
+                    0xf3e87d0246a20aaf,
+                    0xffb3104c5abf15a8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb34ba3d10bc06dd6,
+                    0xac689bfcf8e174bd,
+                    0x7ab9bfaf291120a6,
+                    0x39390221a364449e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa791fa8bd47cb390,
+                    0x6117502c0cc07c05,
+                    0xbbd071799a12a142,
+                    0x1fa0f0ca4aebe533,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cab4379c3dd3872,
+                    0x308f5e1899ff8e78,
+                    0x92ba5023d067267c,
+                    0x1ec39a1a3b882823,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57cbf4a416caf7ee,
+                    0x6e225328dd92d0cb,
+                    0x12d7dc101f92630c,
+                    0x458cb81d63d0f09d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5fb0696570ebbb2,
+                    0xfd588db7a178b17a,
+                    0x4da0466d28da3e8a,
+                    0xd9bb1b17b98ba64e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe32f6867260364d,
+                    0x46e1cac319c0e32a,
+                    0x4cb41e73fc8d565c,
+                    0x5785382e5d335b9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x555494b3d4117f50,
+                    0x63aad970d981b245,
+                    0xad3d46202e6bfecc,
+                    0x4b6ba54f2dc79568,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x997769c036011294,
+                    0x2c8fa1130603e197,
+                    0xb4403af2c04ca163,
+                    0x892b991d2feddfbb,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1c46045b02121952,
+                    0xeecf4821c6972a98,
+                    0x15440b0b0bf24eb5,
+                    0x74c6a43799e5bf34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ce72a17bd4f1c3d,
+                    0x6b083d0a1981844a,
+                    0x2d2e2d90b2af3c60,
+                    0xb4a4fd92b0ab835f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x349f0ab098130066,
+                    0x0352d0fb0c974ef8,
+                    0xc3cb924e3fc90323,
+                    0xa946b18792611f2a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7b30892850d2902,
+                    0x88d7a5339b3d97e3,
+                    0xc1b2aca1e01c0793,
+                    0xbeb2359d52365efc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93cfb80d173c5db3,
+                    0xfee09886130b19ed,
+                    0x868c0d4a0d56ccb7,
+                    0x271d2740da73de95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81c607926d9d9cc1,
+                    0x0bbf7732d3de1cba,
+                    0x1692d612e3f463a0,
+                    0x1ab9b090a309166f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81bafe65e27366c5,
+                    0x8b1baeadb3f5fef9,
+                    0xd024d40510ed9a41,
+                    0xbfe938d10c3e419e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45bce05c8da52051,
+                    0xd5b4232fea767e62,
+                    0xab049104ef66386b,
+                    0x8e12167bfcc77eb9,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x83f1251cda01a8e6,
+                    0x769bb6ebd9669370,
+                    0x9dd9237cfd4ce320,
+                    0xbc7f070fef150f37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7dcdafe87b696757,
+                    0x7efd5cd6d2c37e9b,
+                    0x6c429382a20ba731,
+                    0x4c01d32ca11647e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fcc80c58376c1e7,
+                    0x788ce8525ba55679,
+                    0xa5866b05f3651a69,
+                    0x35f571428dde9f69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee887e1b34ec18d7,
+                    0xfdb7cd0727322aab,
+                    0x33c9fe8184a0a7e6,
+                    0xa39099143bda966c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3bfcc4f6f06c3ce4,
+                    0xe6c49e9bd7cc324b,
+                    0x56681c27426cfc2c,
+                    0x921c096b71390f7f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x826e0066600126c4,
+                    0x7457df80c8a59841,
+                    0x5be6087b5a7c9ef2,
+                    0xb2ec693973f2b299,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e09d037fdcc1e54,
+                    0x6d4da60b259a8da2,
+                    0x70e1f765866a3e89,
+                    0x731cf6a20297f877,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9f39efa852bfc7d,
+                    0xea861c9dd925da8d,
+                    0x0faeb3f03eb15f2e,
+                    0xb7281dfc1072f931,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa4ed84225e507ff5,
+                    0x3f2199921adb941e,
+                    0x52b7187904062d4b,
+                    0x9394d31193c764b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3f628406ae715dd,
+                    0xc2f8f8608fbe912b,
+                    0xc008f8ee92b361d7,
+                    0xac5ff362926d0163,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6df4a8235ec6f2e3,
+                    0x456cf1459fd8de74,
+                    0x2384ac84a979bfab,
+                    0x3d6c36741b79034e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x604edfde4f811a2d,
+                    0x7eef72a774c2c02d,
+                    0xe249bdc82178f981,
+                    0x4c5cd5ad2a5a05ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xece17dca2a0673fa,
+                    0x3f78fade64e0af79,
+                    0x9f9ab679d4ba0711,
+                    0x86e5b882c3b4b098,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x028aefb8fdbe21f8,
+                    0xb2b33193fe47bd5e,
+                    0x3b2cd4494630f88a,
+                    0x868e9909dce480af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x174aed65c361a166,
+                    0xf534cbe4042132e1,
+                    0xbaf94ac1ca6cb6e2,
+                    0xd6d88fe8f9849b1f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x713ffa6ecaa15a9d,
+                    0x1739ba9041cae268,
+                    0x5fc3af47b281d671,
+                    0x84b80379940151d6,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa14c5f87666087ea,
+                    0xa7063e7d28193b5d,
+                    0x18ee0f7fe64f595d,
+                    0xbece39efc7d865f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x568b1c8f2a88f859,
+                    0xdc27dc15a322f778,
+                    0x85613ce90720c368,
+                    0xda0ac961f69ed2f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f17589ca234846c,
+                    0xba3def5f02b055ef,
+                    0x198a71f272e08cc0,
+                    0x4969702579c802b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbde33cce73d672ad,
+                    0x8062c499d0052d71,
+                    0x51f912de97696ee0,
+                    0x2136830a9c32a78c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8b07b041af73aec,
+                    0x2b59d1ff5c7c64df,
+                    0x9477d6e6b5575879,
+                    0x8e911debf4864826,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa237f95be15ae4b2,
+                    0xa4e99f738b471b38,
+                    0xee25c47f9ab75bc8,
+                    0xd93ea41fb73ebac7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59d431e8346aaacf,
+                    0x84147b3eac25688c,
+                    0x98618e92e42f211b,
+                    0x555a366864007271,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x392e80b5bec5c9c8,
+                    0x5bce49762897ec35,
+                    0x30271d462f1a58ee,
+                    0x5a702abcf2ccd0f4,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xd0f3eb88dcacc5bf,
+                    0xcedc122580dd0433,
+                    0xece2cf13ab7b4f5d,
+                    0x682609dae5175882,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb436f41d452a326b,
+                    0x6689f0c62b4bb55b,
+                    0x2c40866e8b97817f,
+                    0xad72c971da9e19c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37ef87947f76ee37,
+                    0x4cf20536ff7f3f5f,
+                    0x9afd72db34e8b1d4,
+                    0x7276071be8e77b51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7639bccf4dd9e6d0,
+                    0xac87eb727f2b63f4,
+                    0x9bd0c6616b1d4fac,
+                    0x8d0d730eb7c9b453,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x44c710975487f034,
+                    0x123b4b4ba2bab787,
+                    0x8280d049577b4c91,
+                    0x2d466ae3af6bba79,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58cb59fe6dc930be,
+                    0xac4951c568d8f96f,
+                    0x1d09d4049f39cbeb,
+                    0xa159efa23896595e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb79f02fe81083d8,
+                    0xca3fb6dd639ec667,
+                    0x657ab9dfd85af5a4,
+                    0x00d6d4fe239d5333,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed381296c744046d,
+                    0x7f0278098fcd8574,
+                    0x287fda69c35ff222,
+                    0x97bf044c3ecc640d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x88cc8907670df251,
+                    0xed849247719bafcf,
+                    0x74ee3da115af0f22,
+                    0x27096e7ed9cdfb26,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e0b50c5a6c2d164,
+                    0x8ea13e2a4f9e7482,
+                    0x166c3dec96dea5cb,
+                    0xa819ca3215fcce72,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68956386fcf14c92,
+                    0xa9635cee1f9526d0,
+                    0xcb44163a7238a35c,
+                    0xc4346a8275f46a3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcba001741aeedadc,
+                    0x518e020fdc74c0ea,
+                    0x8acd1e0dd1cc62ae,
+                    0xf783c85dbab75339,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x125de45eb9def2bd,
+                    0xc58ecfbcab41f857,
+                    0x271f7167de070281,
+                    0xbdcc49f3c8c07879,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7302a62f5d15d14,
+                    0x70f131e486108d0d,
+                    0x54e10358eea0b18b,
+                    0xee3f2cce4fbfd1f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b2dc6ac5da0f57e,
+                    0x80c367c37e9ccb92,
+                    0x842784682a1ba9a6,
+                    0x665751198190338c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x670eecebe88ea1cf,
+                    0x929f0867ed06de8c,
+                    0xd8481890f428114f,
+                    0xae39c7102e9e467f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7c7e31fc72c8e5d6,
+                    0xef459c303acf0c88,
+                    0xcaa88ed29fec358e,
+                    0x0900b3fed6714e1e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedb01771d599f35c,
+                    0x5026fb3ac0c99559,
+                    0x4a0a22a631de7f5b,
+                    0xfea804fc49dab14f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x358d8c6f0122a624,
+                    0x080e9fe792209680,
+                    0x612192d76f6d96ae,
+                    0x8978bfaf51ed0794,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ba429ad7c059f0f,
+                    0x4335e96fd88ec145,
+                    0xd31ee4fb05a78d8a,
+                    0xd72fe0b0782a6e49,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa2567fa0850eafed,
+                    0x3654538e3e5fa483,
+                    0xbf4281078a3952a4,
+                    0xc2c9212c2f93fdab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefe709477060546e,
+                    0xc68773b25b26e996,
+                    0xbbb28881a67bb98e,
+                    0x00ce39e0d0458e18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x914953791ef9f77e,
+                    0x1f172c5cccded946,
+                    0xc05101a6eea891cc,
+                    0x2d23c7ba8a08e13b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4f534d3411030bc,
+                    0xd4302978479aefbc,
+                    0x9604d99e6e66af6f,
+                    0xc894b0e9beda64c7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb22b7ea87d9fd718,
+                    0x66edc97492457966,
+                    0x559509bea355f6fa,
+                    0xd5ef694aef2fa405,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4502ff81451a5f2,
+                    0x5346c5f8890a0b47,
+                    0xf71ae04bc96bc937,
+                    0xd7305a8003b2a582,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24d7f2b2452af084,
+                    0x6c577ccddf0cca63,
+                    0x7e19ed9b28b90104,
+                    0x99eccb7a38befd33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebd153519ddf0a2e,
+                    0x1b8677cde644d85f,
+                    0x578c6782eb09c8ca,
+                    0x17bea1980953b9cc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x301280a72feaf029,
+                    0x7cdfbd139eb0a37a,
+                    0xebe3567f83005f11,
+                    0x87a6bb2f00f18b45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9aa60dc1893b45cb,
+                    0x615cb7db00e7c324,
+                    0xa09c4e2d5217e5c3,
+                    0xa3544237ae2249f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa18803ff4c7396eb,
+                    0x3c118ec0e17ed0ed,
+                    0x7104e9278cc4e20c,
+                    0xb16bcb625df72e6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37c7d1f6cef41bf3,
+                    0xa1ff0e0155dbca19,
+                    0x85897b1ef935c179,
+                    0x2ae09fa2f65917ba,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc91ac4691b7fc58e,
+                    0x7ed0b21616afc8c6,
+                    0xdb1aeb2ed4dcf59f,
+                    0x6590ad9e4ab83723,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc94c518a804ed604,
+                    0xe082f27331d6a4b1,
+                    0x3efc193bf359f86f,
+                    0x271ddde1213104fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8cec1f0eb9aaa06,
+                    0xf2da902aa772a789,
+                    0x7ce51b405a9c325b,
+                    0xaf81afc1de416cb4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x316a6bf9b96bcc01,
+                    0x04c90673cb45a0e0,
+                    0xcb5898a7fe0d8e14,
+                    0x57bdf84caa3fa938,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x698a4f62acaa9050,
+                    0x07ea59ead21cd737,
+                    0x4ec8435f24ed20e7,
+                    0xfd9bab78396739a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6254849c22087dca,
+                    0x9761951487e7f1ce,
+                    0x23c56732e7fdd862,
+                    0x66a1f73d5fcd06ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3541141df46c7bc1,
+                    0x1adceb3a3c1455d3,
+                    0x35940de1bd0aa0d3,
+                    0x27bb87263c32b8bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b90351c5e7c7c09,
+                    0xaf83537f67bc1bcb,
+                    0xf42507e7dae1c4a9,
+                    0x737810b2ceb7e8bd,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd2d66956fbca5367,
+                    0x84983e820ba4aab1,
+                    0x509f7870797a22a5,
+                    0x742480d03e4bd7dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x231d4e12ef041f06,
+                    0x5efec8efd625262b,
+                    0xd65ff8011177b3c2,
+                    0x062a0dca54b54f09,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf10296a93c73809f,
+                    0x9c9ac5de32bec865,
+                    0xeec728528141e295,
+                    0xd825e8bf830d3211,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ac2c9931541668b,
+                    0x11ce5dd941ef5de6,
+                    0xd37980050b0587d0,
+                    0x96e2f857cdb9b474,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd3c4051c4942673,
+                    0x673ab19cf734f181,
+                    0x09d05f00c881a3e5,
+                    0xec069dde2120e82d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0229e99978cc03a1,
+                    0x6c40ac543429e1e7,
+                    0xd255861c7b47af22,
+                    0xba15d18880c907ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0272489ef9381952,
+                    0xb015bcd16aad736b,
+                    0xbaaa3aff579caa8e,
+                    0x5533fe9581e09226,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c4ebcf9d749fcbe,
+                    0xdba211fd5251e944,
+                    0x53f58ed1dc9e12fe,
+                    0x795e4d856914e6b5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdad04800aff12a29,
+                    0x88f608ce2343105e,
+                    0x58434ed0d776b623,
+                    0xb826a03d464d9b92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x455528de44d13bf0,
+                    0xce6e0926609fc8f9,
+                    0x69c7ce78d5e31ad7,
+                    0x97e73ece23fc61f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc860927f6ee6d6e,
+                    0xc0a1a038fef2682d,
+                    0xc8e47d6dbc14cbf7,
+                    0x50b3004f4ead1d89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b991de9b5533db1,
+                    0x5d3c52af6db99fca,
+                    0x9963bc969dc44e60,
+                    0x2fdb6c6b9ce87c83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x749b108e04f531e0,
+                    0xb310819184527f76,
+                    0xa196c7fd773565fc,
+                    0xefa3a525b33693f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8ddea9f7ac1f1c6,
+                    0x683bd5f02e569508,
+                    0x6c1eb052fc8799df,
+                    0xb873e19ad5a1bdf0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b1c290e5c391f0b,
+                    0x074c260dfcd5c66c,
+                    0x7ef02731a8b57312,
+                    0x37b013004bacd668,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36ec1affdcd183b2,
+                    0x6e462bebf5741c16,
+                    0xd7d1b1e0470bbee7,
+                    0x5106deb70036a97b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xbe3d3e4538a27800,
+                    0x2c2037793276c99b,
+                    0xafb1677000bf5f59,
+                    0xb373231088190f3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x303bfaf7ab863a1b,
+                    0xb1a4d5ddc0471005,
+                    0x532d27d70cc4f2f0,
+                    0x1fa09e4b27e2008f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x13d8661e28736d00,
+                    0x97bf5047af85ff34,
+                    0x435a392d8cbd4342,
+                    0xe0f782bf545e90eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc636560427649c94,
+                    0x2a856cfa97d78b27,
+                    0x45abae0660a33a28,
+                    0x33812e708e8dbfe0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c1bd09d71de1b9a,
+                    0x38340533415a95ea,
+                    0x349871e9e21d38ff,
+                    0x739f3235b3725eab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x512ee4a16523717a,
+                    0xa1b6656a5508d423,
+                    0x3d6cee7591c8aa8f,
+                    0xe1fb2269b7918006,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2e19d0ce200c03b,
+                    0x120e9641305d3da7,
+                    0x3f5ce6d3ea83f944,
+                    0x5dc58f54db1e1638,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7ecfffb6884d7fe,
+                    0xe0db5d1af04a5e99,
+                    0x6c7bde67b0734704,
+                    0x6e08fcdba7811301,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe5638e6b07bfbc3d,
+                    0xb67e189168a3c7f0,
+                    0x75190dafaad68b17,
+                    0xcf3748ba4b27188f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a29d585baa07b65,
+                    0x596d23970cb0a3de,
+                    0x7fce4f17e507630c,
+                    0x59c98512aea10972,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x369ca7d236e6cd71,
+                    0xd6871992436ce1da,
+                    0xd78b8dfbc860c2e8,
+                    0xc8a1030b3f7fc4a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b00808bb1488e73,
+                    0x265a9945124b187b,
+                    0xb221ac7dc67fbc31,
+                    0xf7d381b36d65a975,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ead1c7282ced406,
+                    0xa2a31f01bc7d549b,
+                    0x9d147fa5be32b4b8,
+                    0xbcb3abbe290fa644,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaedca28853df603d,
+                    0x3c2c5f74072061bf,
+                    0x783b8f85a0664d4f,
+                    0x53b9bad39d1a4057,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7133e2b0c434bc20,
+                    0x855905adc86438e0,
+                    0x7279a6bdc452b06c,
+                    0xf8d8fb794fb01f55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f79a92d15ac8920,
+                    0xcd58d2d157d01fa3,
+                    0xcb64d9932cbc0d1b,
+                    0x9244c9f5211bd624,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5b485fd4d4e2a3d7,
+                    0x9845f45ea9372029,
+                    0x4f2dcd0000d943b1,
+                    0x10d66bb827c888d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1847a8a9c686517b,
+                    0x89cf266f9fe2cdd7,
+                    0xc338ce08307ab442,
+                    0x9c9ac086c97948b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1c7de13f6e9ceb0,
+                    0x2d2a39f022ba16fe,
+                    0x08031c3341fcc33e,
+                    0xf6a18da4801d0a56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0acc1ca69bc2c270,
+                    0x8d77ac51937f0119,
+                    0x27b527e67440bf76,
+                    0xe536c0ee59d7bee2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9154e7edd944874,
+                    0xdf825f1eaba07796,
+                    0x28b02cf6971d033e,
+                    0x34e6ff8d36c50933,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca26e7c30dd2960b,
+                    0xea76a8a90773e089,
+                    0x58bc9b61111bb58d,
+                    0x2ebc5ffff54d7b6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3576fa7ed6c3371,
+                    0x181e344f853d0c33,
+                    0xf4c0fe341cd1a43b,
+                    0x578a82aee6a41b65,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9216a43606f38bbe,
+                    0xcd7b1dd6c0d26e64,
+                    0x7b8903fdaa390815,
+                    0x9d1f2c1b916805ef,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x85274c6e2908c699,
+                    0x8e0f0701d7917d4c,
+                    0xbfbd276b9a41e48c,
+                    0xca600a63161b1604,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1209ed9deb255e39,
+                    0x3470a86ef364c2fe,
+                    0xa548c1edb0d40734,
+                    0x046db2a2945a81d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdfcd1bd9e51eedeb,
+                    0x9f98a81e3183e36a,
+                    0x367d5bdace8c68c4,
+                    0x7804c2f5d4ab2f28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05f37ba50fbffcb8,
+                    0xb89cc2a250feb627,
+                    0x557f7770ed7e3efc,
+                    0x5757945bd5cbb923,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb135f7e412b250b,
+                    0x6b37aac2b708e3a1,
+                    0xabbdd52f7e754282,
+                    0x3b9c9e984658b201,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05b4a45b4b24699f,
+                    0x96249735998f06ab,
+                    0x183eaa0f77ce60e0,
+                    0x2823273da2267998,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c62b5ef0d407ab3,
+                    0x561dca7b3808c79f,
+                    0x5a73c78f4f8a0384,
+                    0xec92a80e30fb632e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3123a042823d8140,
+                    0xf94c931ee1b6cd4b,
+                    0x76557e2a5adab231,
+                    0xb776a504c14b86ec,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x3354240bef27d4d3,
+                0x2edff3952ad35ce3,
+                0x4ff18ebed718d25e,
+                0x151c4d776066a1ef,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x86446d5d71a5ad08,
+                0x07c6cbf7fefffcd3,
+                0x2d85389792700044,
+                0x673633d04a6bcb18,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xcb0d3eca25b0f2c5,
+                0x77172f6fbd440c0c,
+                0xe16bafd5f092baf9,
+                0x8adb6b912076870c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9bcac25bf5dfeec0,
+                0x49e271cc9cf62f83,
+                0xd87011b9eaeb6f03,
+                0xc09c8eebc8aec477,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa7b25178201fb4c3,
+                0x34b384882e4b9590,
+                0x2eee04c79833ee08,
+                0x4e5bee8a4ad91801,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3dfd742c8b59413b,
+                0xddc07ca9e84f8aec,
+                0x6f995ae34e64cf34,
+                0x69eede944e602426,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x6b99a041b6637965,
+                0x80e4dd829d7c0264,
+                0xcacd02a98bdf63c2,
+                0x8a36b1b44b6bb289,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x85a2ddbc9ed8767c,
+                0xf42f7eaf3a2f5bb4,
+                0x446cc1e49e19a763,
+                0x9de379f853c4403d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x7db8005f57335654,
+                0x29fff627cc848554,
+                0x0b32e88ca3612d96,
+                0x40b3bb331137572a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9b54548a0d5f47e8,
+                0x4ed306757b65ed94,
+                0xad2511d1b8e39a21,
+                0x07fa9ff253ddc941,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 186, 187, 563] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 186];
+        let mut opad = vec![0x5cu8; 186];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,2055 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 6 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 6;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 16 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 16;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x974557ed7049c719,
+                    0xca00b5ddb2e1c7e7,
+                    0xbabd2c7815d65632,
+                    0xf2380f2e7f1f658b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cb73eb0c812d6d2,
+                    0x766666d61fa4214c,
+                    0x4697cb0e17440e14,
+                    0x478e26bd23d569aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0edd2110c8b87e6,
+                    0x897c9327a3468ac0,
+                    0x33a6968c7983fe25,
+                    0xb36fed34c979c60f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ea8bed0ccc425fa,
+                    0x2c882e27cefc9605,
+                    0xd9f23f6b8d7d7eda,
+                    0xa08d2109a061961b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x86741f9a8d194c1f,
+                    0x186c37b79ceaa49f,
+                    0x4fe38f6808a1d0c3,
+                    0xcdd6006dd462c970,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2106ce94c556b405,
+                    0xf5f43d9604f057e1,
+                    0x3443a724e16bad9a,
+                    0x42db17eac6a2247b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77af9391bdf82f55,
+                    0x49d6983ab09b9515,
+                    0x50ea9bb246a1e45c,
+                    0x8a0974b7f4e1c4bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x184a8e599ec69dd5,
+                    0x17ddb27d605c507f,
+                    0x0d08b3da442009cd,
+                    0xde39296298a439c6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe50e3eab83756814,
+                    0x598a35acc793a5ea,
+                    0x9d77e54cd193745f,
+                    0x485f3a84a03aad19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31e0639014dcac07,
+                    0x46dae523fe220cf3,
+                    0xdf46f74b91a17749,
+                    0x7ea0ca43772e6980,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4bb1aa45277e4eb5,
+                    0xf1af3ce8e19eb226,
+                    0x8cb85d10f427799b,
+                    0xded12de37d9e997f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x358a1a83bf67a083,
+                    0x69a715a5759101b4,
+                    0x560c95bc401c46f0,
+                    0x54fa28c48d3f1c00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80bd9ece9391c414,
+                    0xdf8232a351cfa1c9,
+                    0x13068751f1c96094,
+                    0x180e8107c32a6d6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94a7b6ce38ef8d9e,
+                    0x83992d9425932999,
+                    0x3982f3f7319148f2,
+                    0x46ac5e92c22ef199,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45fab9c480a74a14,
+                    0x4d09ff26d9100d8b,
+                    0x69e2ee23a87ebac7,
+                    0x2e58bbac48e8e3d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeca33c3caa5709b1,
+                    0x17456a79d517e37d,
+                    0x0924c0d08dea508d,
+                    0x3c783e4f7d90a325,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3eb7c7c8085af79f,
+                    0xbbf9252c01629671,
+                    0x24d8552ca94b6cb6,
+                    0x6e313319b5b9e7f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25f3d0d68378b4d3,
+                    0x310cf45373f7ec06,
+                    0xd868358b8141a3a2,
+                    0x6b1de36845ebe72a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a1cfce8118d7d82,
+                    0xdbba3d47288e6b14,
+                    0x0ef8d9b6cc09b085,
+                    0x42de7165fa864bbf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3413411e8d802674,
+                    0xf2dbb4eee1740c78,
+                    0x543d972d2a11889b,
+                    0x93e3bb4c86527b90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaaebc33aff871549,
+                    0xf6d34ed7229743d4,
+                    0xacb0804fbcd6b10a,
+                    0xf114dea6588802bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa28bf7c3c60cca6e,
+                    0xa0681c81df832b45,
+                    0xf4ba43b9302c2560,
+                    0xe2e733a4105f3b07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6b366440a444d26,
+                    0x06848223f4cb9ea7,
+                    0xf5e637799119abe8,
+                    0x114dcd8a3583f2c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a287c5ef83c834,
+                    0x8d462025d09c6450,
+                    0x6947b4f4e4a8206e,
+                    0x36c96af4fe1d5b77,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8d1f9b6039e25924,
+                    0x35c0042b2a8c7b4e,
+                    0x50d4e0ed8a9b817a,
+                    0x255674fa089fd634,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05f9c4d41e349517,
+                    0x9eecd700da54bd64,
+                    0x01b7c4e5cccded68,
+                    0xcdfb9936d7a73331,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7bac716ec21e048,
+                    0xf98fc178327234cb,
+                    0x404c3bf96f7988fe,
+                    0xe1231db3c7351299,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe826c19f6f139e86,
+                    0x6a04a31039eb601e,
+                    0x4aa419af7edcdc6e,
+                    0x74cec8be01d13ca1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f70c2bd08402b2e,
+                    0x12b5266b8b028764,
+                    0xbb813e8b99eb375d,
+                    0x4725ebdc3b0ece27,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe670d16aeb132c64,
+                    0xbb7c10cf92235244,
+                    0xf69fa193eb1acec4,
+                    0x976ba5f1408987a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16c93ccb97c237d4,
+                    0x65e52495cf2fadfd,
+                    0x131ca860e67e736a,
+                    0x6f73399656f7ba16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe51b6cb83a6855aa,
+                    0x98d783f4b7a9b77d,
+                    0x816564e4430a69ab,
+                    0xf6cf9faee5e4a575,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xea08e411b2b46cd5,
+                    0xff82e01945a8cacb,
+                    0x9421f0cd5ea9b392,
+                    0xd72bf5c3c40284ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58decf184521d5af,
+                    0x291ac26301980f3a,
+                    0xeb1d43680583660f,
+                    0xd33a40c3c2c7668c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3881165d0e353c4,
+                    0x2acce8dec45d7dfb,
+                    0xcea32c032a4460a5,
+                    0x8458fc0ec2da73bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f462f9b65423594,
+                    0x0d03dcd24e4ea95d,
+                    0x20204124982cf3b8,
+                    0x74dc9eb32c6b1fe6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5c9262683d7644c,
+                    0xe0a55b61332b3569,
+                    0x32f683280ee66833,
+                    0xa16ce835d7ebe342,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x181e629f3683a116,
+                    0x3522a7ffd791cbc7,
+                    0x8e7b1fda0c9de7a4,
+                    0xdb4548e0c9c01e40,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9f19d7524d2776e,
+                    0x8e104a602af46f76,
+                    0x098fdb6250b2dd2b,
+                    0x85a826b8dbbfd28a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22c44595f01c17a4,
+                    0x8c775d42628922cc,
+                    0xbe06237b89c97f59,
+                    0x5dde9ef6f0c4d95c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x61e478397c7ed429,
+                    0x2d284a489f108e1e,
+                    0x7fc7753b52e7219f,
+                    0x1b11d3669c99cd60,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf2a859ce0b13bbe,
+                    0xfbf350735050e34e,
+                    0x1b55fe2ab1f83288,
+                    0x92c565dcb165a029,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14369eb1c89ecf09,
+                    0x4f5d4cac0626c3ff,
+                    0xe112fb6c1d7224bf,
+                    0xf10c6b67696cd6c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9a9e1dcdda31ef2,
+                    0x594b1e59e5711d76,
+                    0xff2464ea3ea38d25,
+                    0xe1bbcad8c869651d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18f89bef243d08e2,
+                    0x986c5224215b92fb,
+                    0xd748f6176596d40e,
+                    0x6969ca398cee9433,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x518003576b9d0fe1,
+                    0x2e8bae15cbcbe22e,
+                    0x51f18fdd79c07d78,
+                    0x9674e28bec9ae291,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd57d11240764863c,
+                    0xfceb11212b267337,
+                    0x10137cb67257c28a,
+                    0xea2cf3fb8afb79a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e967bb4b034e1fc,
+                    0xcc2791501d61aacf,
+                    0x62b08e703ed02794,
+                    0xc07f4fc7143ad014,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x18703df92c771faa,
+                    0xfc912dd19cfef645,
+                    0x17e640bd788da73c,
+                    0x3d5c8845592f92ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5036ca30eb3b57c4,
+                    0xa762ba294d40ede3,
+                    0x5eeec9f23c4ceade,
+                    0x2a4dcd9fc0fd4675,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb023ca726ef0be23,
+                    0xbd67723214d64a20,
+                    0x4a80a0fd64c849c1,
+                    0x473fceabac77e2a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdab603a766988ebf,
+                    0x6ba297aba7212a69,
+                    0x4a48fabca7aa507e,
+                    0x845dfd5558901cae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9e19fdbbe3bd15a,
+                    0x26805a2a5a601904,
+                    0xda05866072659840,
+                    0x490df130dbfc11dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x246006909e265313,
+                    0x5a56a46fac1d165e,
+                    0xa98e00414131d668,
+                    0x1ffe83199c05a51a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24d7267d27eeda07,
+                    0xb29afab447c90787,
+                    0x10af1736278b8a78,
+                    0x847811723319e804,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x420a64917855b9f2,
+                    0x55d05dc3463ec1dd,
+                    0x6c4b07016b0be177,
+                    0x36a26fb7c0f991f2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xab0c2350b7190924,
+                    0xd36f1cca0f825dd0,
+                    0xf7d6ab0af8f3edbf,
+                    0xbe4ad7b907c3bbeb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf469fe146a3b16d0,
+                    0x5b7c7310bb6fb709,
+                    0x1c72546f1e5bcbcc,
+                    0x3893adf48a09a087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2c3fda4508e3b6f,
+                    0x4e557ca488fb7650,
+                    0xd9dcb13c62317b16,
+                    0xf6cb00b2965008af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7fb1fbcee127f1ab,
+                    0x538800636aa6aeb8,
+                    0xef81a61311f6605a,
+                    0xb61e2179632e0b6f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb68acec0d48624b5,
+                    0x67b1aa2c131719b5,
+                    0x770ace7b07a4e85d,
+                    0x87bf650baac15793,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37e09e99f6099b5b,
+                    0xd35c289b82edf040,
+                    0x8770b6e92be21c8d,
+                    0xfe9f7ea23374a40c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87f49c92d14695be,
+                    0xc1af83c5fba56e71,
+                    0x03f773e21166dc74,
+                    0xcc6250da24c1be52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe58a397320f13bf0,
+                    0xfc11911bb2142da3,
+                    0xc2bebef3b81ef819,
+                    0xf8148d085baeaa6e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe56af0bf31c42d04,
+                    0xfbe70385b0a23fea,
+                    0xc7ea46f9e1a1a194,
+                    0x25a4021923040532,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe6f10c85e19c60e,
+                    0x400643a3ae40fe04,
+                    0xcdacaf310eebc65f,
+                    0x8c5cef2b14bcbf9d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3473648dce94de64,
+                    0x0d270c0ea638ca69,
+                    0x1a077bde43f3ff42,
+                    0x1652f04ca922fac5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79c25d55c7ac72f8,
+                    0x5df1fd3cbac3e7ad,
+                    0x380843aa50a193b1,
+                    0xd67588802958e3cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4698c5deac7f2300,
+                    0xb4780d89fa22685b,
+                    0x85bc21133858d475,
+                    0x192c07b322817f38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc498d0baea5e8ad7,
+                    0xdaf866245c6edfde,
+                    0xada50a4c308a0db5,
+                    0x2564be8a438ee2f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80bed1770cf5ff2f,
+                    0xe9db0681557999d1,
+                    0xf071e41aac0ed3ad,
+                    0xd1c67d5d62b9ed7d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6d50a80048a3907,
+                    0x04d3a494fcf8f8ee,
+                    0xc10b8ff1466bf865,
+                    0xb1adb5df04e2e8c2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0a2889db609ed8cc,
+                    0x802096dc7d475ab3,
+                    0x456fb82b6a1049a0,
+                    0x932dcd007cbe2ac1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd19b74503a349a5a,
+                    0x28c42db8d9426738,
+                    0x08bcb742e7dcba7c,
+                    0x81adf2afe9633fad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4162d3f586a38ace,
+                    0xfa051c1560b5c630,
+                    0xf38ee27df54567be,
+                    0x8e01129fb66b8988,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a0edcdd9a2f4410,
+                    0x461aabb047076e2f,
+                    0xffd58aca0ce800d1,
+                    0x2e955e0fede8da29,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41e21e309178a24d,
+                    0xf08437215e9443f9,
+                    0xb692397f3dd82c02,
+                    0xd904b2d44240b2c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60f3f7edb3b92b04,
+                    0x1e6165007f5d306d,
+                    0x0860dc37f334c7b6,
+                    0xa4d9afc43ff6fe5d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66a822f4674f8620,
+                    0x38b36893620b9bbb,
+                    0xf874952163f0ef93,
+                    0xdd1abcbe2ab42654,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x398a298950269bec,
+                    0x533ca91ba6b7ae13,
+                    0x2a75656d619bbcd1,
+                    0xaf1503cb18a85610,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3fa482471ad2242e,
+                    0xba9c94d9e9a703a9,
+                    0xb13b8ef0f0bdbb83,
+                    0x96300b5e92dbb2d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4bc818c8cc186c40,
+                    0x4f30e4c0402df504,
+                    0x3167586eb03b46de,
+                    0xbe9a6eb37366ac33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x815af1fb8d428be1,
+                    0x311edfa84d62f46e,
+                    0x5953df838254ac54,
+                    0x7b4a8117a040496b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x299b934e00bc4b03,
+                    0xe19f3296889fa796,
+                    0xb696b82a70c4b689,
+                    0xaec7db0f42282049,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25cb143df1353f5b,
+                    0xbf52c301d49f1e1f,
+                    0xd4f338b798d36c13,
+                    0xac9478332100d9b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23df8701de89dfe3,
+                    0xf0944d3d3d9030a5,
+                    0xc152ca8655159bdf,
+                    0xaaa353074a9d704f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50e4efef8d5171a9,
+                    0x9f7b1e557825bc92,
+                    0x58dcd771390cda3f,
+                    0x85c106f4f2ac35cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b41982b084d9e24,
+                    0x7b6cdff82299aca6,
+                    0xfa1eb4e8578dc10c,
+                    0x7a154d0920aac453,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcc39e3b6639b1fa0,
+                    0x5acb3fb4834cc1ce,
+                    0x91535e48c1d3d511,
+                    0xcbdee46ce14bf939,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd692b393dbba6729,
+                    0x4bb233309ad696a9,
+                    0xb33aa2ce8fd4c243,
+                    0x94896a6f2db7375c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2837cfdb7f0a1b32,
+                    0x4937281fb8e2c6b7,
+                    0xe406841c876f1226,
+                    0x5d298a3afcf46a18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x218233282e0f8f5f,
+                    0xcebbcb1cc4f85515,
+                    0xb2b82b09cbb3d795,
+                    0x526f4ed8575895a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30195e77ea8ce436,
+                    0x5c8470bd13405520,
+                    0xf6298fce4e26539d,
+                    0xa632b648b75bc437,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ed2149803509ecd,
+                    0xb7866a3ea4c6cd0e,
+                    0xdd9488664dc590f1,
+                    0xa00f674d2b952c67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc1709e855fe9370,
+                    0x837fc1741fe4a8dc,
+                    0x55dd0b1d1c4782bc,
+                    0x1f1710c6e8783c52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e6fc2fba74dae47,
+                    0xb0858e9eec9e6e16,
+                    0x449e824fb60caca1,
+                    0x257027f1498e53e3,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc497bc21025802d7,
+                    0x01b951a0444009a5,
+                    0x38a057c1c9c2b08a,
+                    0xd2fd60359c3b0bb5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc502a8dc9c9a1d57,
+                    0xad009f229fb6e595,
+                    0xc16b22fc64afbf0f,
+                    0xd4b9576f8e58c37f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ef07415f6839bb5,
+                    0x16c0dfeffb3e7384,
+                    0x090c777c4d5f2801,
+                    0xbc371e49bb521898,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x034108ef5342fc16,
+                    0xe06ce3f938b3ab6e,
+                    0xa783b3c123ec74a5,
+                    0x3edb5d4b11e3ab1e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa6a5f39f70d7763,
+                    0xb695c0c3ae6e92dc,
+                    0x21591adff5097253,
+                    0x612df9e313b6897a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0917a938b414aba,
+                    0x44eae799e64bde5d,
+                    0x59628c56b5f94ef5,
+                    0x6259f79a73a866e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4336565bd1ec9fd,
+                    0x3a776d92b600bc3f,
+                    0xd6e03c129bc9c0d8,
+                    0x537dd5aba584a10a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab103ef7470abd92,
+                    0x9eef2f01f241246e,
+                    0xd1d7cb1b6a699dfe,
+                    0x0247ec352114c552,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa20db2c0363d8de7,
+                    0xf6b80ce5f302af5d,
+                    0x8f8be92fb3bfdfc6,
+                    0x3ae9eea7b598588a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabdf7ba2e948d07b,
+                    0xaaa81b77d3955879,
+                    0x164d81ed57e57c34,
+                    0x7d67dcc7925c8d77,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cb9e5cb52aa2b5c,
+                    0x2570aaf595eccb99,
+                    0x0e63e6826c71ea9a,
+                    0x22af45a6caf68f72,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc483062e9a3a6b70,
+                    0x10cc41435d500d81,
+                    0x45d5ea838cb2331a,
+                    0x6e929c0822cd5682,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a90e07a8be05a6a,
+                    0x7ad9f32d210d70dc,
+                    0xd525ce7244c11694,
+                    0xd48017de558857a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9895001febfc1247,
+                    0x68e1160cd2909f98,
+                    0x012ed19addb593d9,
+                    0x392c1af45658411e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd203a50cd07f1882,
+                    0xb7fcc829235d2472,
+                    0x122a17d91237473c,
+                    0x34c8a57f2100ec48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82337502aaadaed1,
+                    0xf33992686e47e35d,
+                    0xbd2ab460c2a7ece9,
+                    0x63b7bf499ba86882,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7a397d67ab8c84fb,
+                    0x8730b249a19b719b,
+                    0x4de05a07e9585132,
+                    0x616470aa80618c0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x060c08c2d54abd13,
+                    0xd260bdd27e54e789,
+                    0x1c164a6d54cb9154,
+                    0xace054e9a154e8e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf58390a6e7631853,
+                    0x3de4aa1efa04aa6c,
+                    0xa79f119206b392c9,
+                    0x20edea9116f6cf0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8aa256d80936bdb,
+                    0x715baed611150a10,
+                    0x3973fa18a13f6cef,
+                    0xe9da3679447f6847,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe903c4fd242c809d,
+                    0xbcb1cb1e7296aa4d,
+                    0x71470016fe84f0c3,
+                    0xbf284a59f5cd4c6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae9cb8ecc47ad6de,
+                    0x0d4604e54953fb82,
+                    0x3b9e3066e016e05c,
+                    0x12f0fc7549d8279c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38806a8670d8e6e9,
+                    0x631a8846fa4a9943,
+                    0xf6de4a3ecefc835f,
+                    0x2061e754004645d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84a3e6134daa5c1e,
+                    0xa5bbc3158f405833,
+                    0xbb801d4908644d06,
+                    0xe120acc8bf0d47bb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x36dc3249911cdc6e,
+                    0x550d36c80ffe4f16,
+                    0x3d96cc76f57bfb69,
+                    0x6f6d455726a13410,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7ced74819438d6f,
+                    0x05c27900eb3fecf0,
+                    0x2c28625c6bcc1ba9,
+                    0x62a3d6162f39343f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d56c77c3b7b0e6a,
+                    0x934042b6f0230cac,
+                    0x0d353c40790ed64e,
+                    0x449ece7781f94303,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa592ccadfcd9076c,
+                    0x4e98c69b4c3e406a,
+                    0x5bb156faa23bebb8,
+                    0x6fd70fd89dbaeaa5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d78d1d413ebbc19,
+                    0x828a9b1d3372fabf,
+                    0x080b46a80163209b,
+                    0x72c85d9c7ff2e1f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0cf878c7b8c4ec3,
+                    0xa57df73d7e80b2c4,
+                    0x2fb4e8400e6ac462,
+                    0x43cdd6d531224ece,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c501eeb089e5daa,
+                    0x1b1aa26cdd3c1d42,
+                    0xa923ecc7aa2a70ae,
+                    0xa208090c59e3ccf8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe675bff59eccb6be,
+                    0x9fe76cca461e0b68,
+                    0x56e7b490b3d1e1ed,
+                    0x70204292a8df21a8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4c2fc594ffddd959,
+                    0xd745311d2dad50dc,
+                    0x8ab8481b43ac3e3f,
+                    0x027cf2930bfab50a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf42f23664c059bb1,
+                    0x0df446597733b1f0,
+                    0x7bb287f52b2663d5,
+                    0x256742fbef2d99ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d8a37213950151d,
+                    0xddf486051ed922df,
+                    0xb50c02f477f0c632,
+                    0xd1a0d868419e1746,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd50a9d2c52940d47,
+                    0x11650fdff4e82b2e,
+                    0x441e4b88967bb017,
+                    0x6a8b3611ac1b6e30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c7eb2be76e64ed7,
+                    0x0da7bc468dd3d12e,
+                    0x2d91f5471eb84e29,
+                    0xb27c0ee123ebd6aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9f86589740d3aef,
+                    0x2c24542804f37697,
+                    0x75f0d06bf004482b,
+                    0x2cc9b03517978bf1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e47ebe41173f145,
+                    0xa056f57be86cce8a,
+                    0x69506084ae96a710,
+                    0xba1f460512af3fb3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66846bc61ae5b246,
+                    0xd2736d08dad6e7a9,
+                    0x1afeb4801abcda6f,
+                    0x152c30b8ef7152de,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7f81e6b090220071,
+                    0x4bc79dcc23b7b214,
+                    0x53ad86e83db58170,
+                    0xa39014e07abd5782,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e6eab6582498c16,
+                    0x2d3b17037a7fced8,
+                    0x204927e39e1dfb84,
+                    0xf35e0c8ac665c877,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6eb1cd40b092aba5,
+                    0x9ee2b17a34834ef7,
+                    0xc90f9ee6f107bee4,
+                    0x196a846c539f7ef2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0c55cd9bc386281,
+                    0x7be1574e2601838e,
+                    0x51a78e9d5710ac11,
+                    0xda8ac752838e1ddc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf57a2797d19f12e,
+                    0x79b2302a921d3b9b,
+                    0xe2115f5e4e44dece,
+                    0x09d9a72b3d836a44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x301e35e6ed94b993,
+                    0xbd2f69e9081978f8,
+                    0xa481ba39094f32de,
+                    0xab66fef2b459622e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f33993e5d2c36d2,
+                    0x723c5a396f52bd57,
+                    0x1339093299658fcd,
+                    0x441cb664a8aae7e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c711a5f381de796,
+                    0xedf75efb0746358c,
+                    0x4926efcd66e5e5ef,
+                    0x6ef9cd3466db7ee9,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbf36d6aa52145a4b,
+                    0x9b4519e65ef1fcd4,
+                    0x4ffd656bb06482bd,
+                    0x171a30b23d3f64d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7fe96798d352107,
+                    0xa9890fd25cb697ed,
+                    0xc77481de0108fa23,
+                    0x0c74815194bea392,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x826224cdda12eabb,
+                    0xbcfde6aee1bb8a54,
+                    0x718297307f16e6ff,
+                    0x9d11084482130def,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f470e97822dca00,
+                    0x87dfd028438a75f5,
+                    0xe5a389675fa84630,
+                    0x4d19b4eccede7d95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71ceeac03a05192f,
+                    0x2f576d35a05df540,
+                    0x8e762e934379834d,
+                    0xdab30091a81cd777,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2cafa671a046788d,
+                    0xa3b048ab2ebe3a10,
+                    0x67c8a87910216906,
+                    0x10713e83e1740234,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fa59ba9aea17044,
+                    0xf3bb30a22aacdf6d,
+                    0x0995424f1e6af586,
+                    0xeb960b38528432ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a87e9e90a32da2a,
+                    0x64142d7f23b257f6,
+                    0x4097a2af2dcc2509,
+                    0x5d686247075a78c2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb542653ca20e9faa,
+                    0x440f3f701b0e9c95,
+                    0xe56f63f6fec229b6,
+                    0xe44f06d2c8564043,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9371d6e359d58a5e,
+                    0xb7b232615afbdcf0,
+                    0x39f64d6ffb27c095,
+                    0x83d718145e2959cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c1ca009d74d4916,
+                    0x15031b4f06854832,
+                    0xfa605496080af2db,
+                    0x57d9dcb0ddc7ff89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x638cf6979adc31b3,
+                    0x38fbf9cccc26c19d,
+                    0x1cd18fd1ae6470c3,
+                    0x7bd70528c30248cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x86f094ced1a29f44,
+                    0x930ec7720e052e10,
+                    0x00c8b9783ccdbb47,
+                    0xb4f63668adee32be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x882cb5288fab28bc,
+                    0x789f183eaff0f539,
+                    0xad51f34191e0db34,
+                    0xda5458c75a816510,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6bd4c5867a83b441,
+                    0x066e2f0297d98b34,
+                    0xbfe2233d44fffb03,
+                    0x9352e2d223ec5fb8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd14b64d192f481ab,
+                    0x7082554110dbeb0e,
+                    0x0ff944465155631e,
+                    0xd3548fa169821298,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfcf2c2b6389b1cb7,
+                    0xde74b472bc3c2d38,
+                    0x6172a6bd345f95af,
+                    0x353e27701817d58b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x574b1a73290622b2,
+                    0xae539acafa17c90d,
+                    0x3e96c45bb12ff071,
+                    0x6fbb18874ebfed2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda9ddde871424bda,
+                    0x297056745dcbf4f8,
+                    0x471ac213baf43d49,
+                    0x48c1b6ab585e8c93,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x566d7aa73bb45e00,
+                    0x5c84b39d2d2242b7,
+                    0xd8ca8e35900aaa35,
+                    0x49e6aea5c1ba9ca0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd995643cdd631bc8,
+                    0xc0f8d09d38036813,
+                    0x9d6cb18230c4a738,
+                    0x75537f2d6980fb65,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b3179e3665d7ba7,
+                    0xdc80298eae930fae,
+                    0xb1eca454abd7d529,
+                    0xedac73a850b6e058,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48b93919be4e1da6,
+                    0x8be7e61dba2e3c60,
+                    0x46fc6d782a2691ac,
+                    0xababd84431545264,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77f09394a18ad98b,
+                    0xcecc43407575d0b3,
+                    0xa629218a3c83c32e,
+                    0xce7474e33da99024,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3ed44bec9f2762f0,
+                    0x11a236c27221ea1f,
+                    0x28ec0f99f68cb022,
+                    0x175e38a042a28e06,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47ce92b62109fc7a,
+                    0xb5c3122e6d2fd8af,
+                    0xf2e01e6002cbede4,
+                    0xabe221e4ea5abe19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27a5505c2c430738,
+                    0xfef08b34f64c2a97,
+                    0x33b33d996d8754d4,
+                    0x669504b1fd929863,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x406835519b21af95,
+                    0x70aa0cb8b01ade9f,
+                    0xc1daf30ebca0e111,
+                    0xfd689ac0e1ecb199,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfddbb89ab62f3105,
+                    0x793e98a760243a44,
+                    0xf11d8d08d6b38eb6,
+                    0x74a7db9413278d8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0331f3a5c6005de8,
+                    0x28153f3951f4b0e2,
+                    0x2ef9047754bb9b98,
+                    0xde9893a51403154a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0275e18393fbdd21,
+                    0x6f6f403c4d8d46a4,
+                    0x16b44c76369d41f2,
+                    0x4fc697f28be1a382,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02b37dd8569afdd8,
+                    0x3d9300c3a6cda519,
+                    0x83b352c801d64e84,
+                    0x98f1d8d5342cc2da,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4aad01ad3a0be8d,
+                    0xdd230c3245c002c9,
+                    0x650153671b8b3071,
+                    0x4c9e29f091ddad25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2956c3cbb65cc05a,
+                    0x2a778d6c5f41e8ce,
+                    0x5ddd050163ffc7a8,
+                    0x078d87175d2bf4ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa50adcab2e9f7306,
+                    0x328df8b0be62e8d1,
+                    0xff4681aab747394b,
+                    0xe2d392b5c67696bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ad2283a09df2ec3,
+                    0x9e348276294b3660,
+                    0xbc600f20fe7ec6c7,
+                    0x2ed600af2f72083d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc2170ac6f41166f,
+                    0x4dd1a605e0a6db47,
+                    0xf4e44fd456be5e38,
+                    0x6c3f87179c6c0757,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a332e291c98bdb,
+                    0xbabe51485dcf30bb,
+                    0xc03460f5e133eb2a,
+                    0x82a3a5266dc31205,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42c213a3a809a1a0,
+                    0x3967f4e66a889a95,
+                    0xaa91421229107c2e,
+                    0xfd4d1448fb9dd652,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x916117b676aaf555,
+                    0xc0eb3f38369b7bdc,
+                    0xc039d7ed51477a14,
+                    0xd37678a73f2c40f0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x17a2129cdfa975f2,
+                    0x510014331d456508,
+                    0x24f7af038e76b063,
+                    0x4ce82a4819dd4fcc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8ede405147a4ce3,
+                    0xa77dc962db8ac9a0,
+                    0xd45afebeba5d0731,
+                    0x87eed75884ce45fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f3c637f473c6749,
+                    0xcf8069ff5c7ce585,
+                    0x2359fe8707b9d3c2,
+                    0xd1f4ce7bac141629,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x323925bdd324911d,
+                    0x37e989f01ded6b5d,
+                    0xd2834a047901d352,
+                    0xec3d55dc60d4cd94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9046d16d81ed2a9a,
+                    0xbd1d803f918ec1ce,
+                    0x730ecc868e65c04f,
+                    0x265435a44421bd4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04a4eee5047a7898,
+                    0x0d3bc0f89cab9848,
+                    0x0251552a440536db,
+                    0x38d24a40892d35e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33afec9dcd4e4234,
+                    0x7f8a04db82890cc9,
+                    0x46fd4c0f58543937,
+                    0x0201c9f3a20fc2ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d93e158df49f499,
+                    0xc512fcd189f0a059,
+                    0x7c507627c4c8e722,
+                    0xfeb8fcd598b205cd,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
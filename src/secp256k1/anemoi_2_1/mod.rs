@@ -0,0 +1,750 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 64 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    state[0] += round_constants::C[step % NUM_HASH_ROUNDS];
+    state[1] += round_constants::D[step % NUM_HASH_ROUNDS];
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x73eb8d4026e586e8,
+                    0x28094a06072fae57,
+                    0x03bf6c96a0bddbaa,
+                    0x10b9447fca0d61b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe29e8625eaa4e412,
+                    0x0b9769f81c3a4625,
+                    0x440358045de5c59d,
+                    0xb1fa6353fe37c064,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf527db5d30e1af40,
+                    0x5c9a2c46609fd949,
+                    0x815cddab7ac4be79,
+                    0x7433d93eedc2ff3d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45a98cb843b80b0e,
+                    0x557f8a31e9bd7b3c,
+                    0x27f7e456074ddf8c,
+                    0xda9f191e543d0c0b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe5054c17b88d970c,
+                    0x3d3cee5f6e4bd394,
+                    0x6c6dd35eec1db21a,
+                    0x61289c5da921e5c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xded09f267d9540f5,
+                    0x2b980bb323d4aba3,
+                    0x6aeb0b11380402dc,
+                    0x72ef5c1b1c75f314,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x426f55dd45a9dbf6,
+                    0xd7a1309d06aa7c32,
+                    0x9c21ff4105e8a620,
+                    0xa383159547793f70,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1d3c861afa18588,
+                    0x87832c3600d4345b,
+                    0xea9bd0efc1205d8e,
+                    0xa6a381255c476c89,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x648f65d97161af38,
+                    0x1e118e60998edb77,
+                    0x13d9820a4c58b80e,
+                    0x811a64d6e042385c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x860ad0867f40b9ad,
+                    0x618bc3688cfbdf6c,
+                    0x2251e2e466f8e671,
+                    0xaa655afee648582d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1051f8616c96ded8,
+                    0x502857bca8959803,
+                    0xd88a85facdb9a32d,
+                    0x280848a6be592b53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe24d7d90d211652b,
+                    0x2bf51ebde1f8c87b,
+                    0x3c3ad4b95a03881f,
+                    0x64f72c66fd04293e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfffabe47c6c2c3a5,
+                    0xdab940778dd8dc3c,
+                    0x2eb4a69747f60be2,
+                    0xaaf43b4b3a358178,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaaef79439479209b,
+                    0x56f39cb182c62dd8,
+                    0xea261201e13ec2d9,
+                    0xbaa6f0e6d683d605,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8409c8be0fc3dba6,
+                    0x2815c309b7c4fe33,
+                    0xf8670063e91f5da2,
+                    0x0ddd4511b76cf083,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xded40743517b01ac,
+                    0xc7896a4fbfa6e228,
+                    0x15aeb26d67a780a4,
+                    0xfbeab09b0deeb158,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3e508b1217d44975,
+                    0x043f8e0ed85c48b1,
+                    0x45385c9ea1021f33,
+                    0x66c9e8c009656940,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f3d84482ed60089,
+                    0xf953dc7d0a0dd9fc,
+                    0xd228b4bb253af23b,
+                    0x9a72ef9c8fc9d154,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x918aca25d9383730,
+                    0x75b87369327de9a3,
+                    0x82a0bb8c2d0ec1ec,
+                    0x310b5b759619f721,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd89177dee34f16da,
+                    0x382ab81edca94d07,
+                    0x9861c9d95117b46a,
+                    0x5da814e75d2e568f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4ec8b025280e117,
+                    0x44c264927d5593b8,
+                    0x2956735b9074571f,
+                    0x472e8f5a1d981681,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd44e45f48e0300c7,
+                    0xea266d7b4b17677a,
+                    0xc0ff235f5b0ca39b,
+                    0xc20960ddfce33dde,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x74b0813133aa9eba,
+                    0x76907dad4b1e1e9e,
+                    0x9cb93a78fee10d63,
+                    0xf1497fc7edddcddc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65d0c4e93cf9f665,
+                    0x82c330e8e5bed866,
+                    0xc65a4666ebde68c1,
+                    0x22641520ac66d52e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x290de28806c3e70d,
+                    0x6605de8f2175e648,
+                    0x9cc3136accec8b55,
+                    0x3c8e740cb0686e55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b4970cb68ff7fa1,
+                    0xdd2f28752421ceb8,
+                    0xb2faca5bc79a26db,
+                    0x977614d4a3f5c741,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd8b2a8bd439f06f2,
+                    0xd36965a5f6a982a7,
+                    0x9822988f501f8b36,
+                    0xc553f2accb206134,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7d11aacc29ef740,
+                    0x4a1aadaa8ec09fde,
+                    0xbb6a511d83e29caa,
+                    0x6975837a1e3b3565,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdd28eba3b8048309,
+                    0x771e225e3a923527,
+                    0xad2f30e17aa9f40f,
+                    0x85fedf2f6e3d2bd7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x840282b746d16c5e,
+                    0x8d13886c6395a04f,
+                    0xa1e63ae1736e51b8,
+                    0x00b89e29756d5a5b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x57add52c9d5d252d,
+                    0xa2aca9e9c1b43b34,
+                    0x02f9dc3db3f20d72,
+                    0xaae055d02cb5816f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8476f41f6d5af65,
+                    0x0234fcaa0f241b75,
+                    0x8c007b54dc36d281,
+                    0xf0bc4e3a8ed42c45,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd1c7f398c3495cb1,
+                    0x54b196c87bfbcf86,
+                    0x5bae07f999c30128,
+                    0x0f1c214c92011e27,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02e848a345a8eeb0,
+                    0x848bfe5d19c4e2b2,
+                    0xff7fe93ac4df4444,
+                    0x8bd9b088affb8aac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x34f5a1e0641d83d4,
+                    0x155e9e316adde05a,
+                    0xd47d2677e15e9e6a,
+                    0xbedfa64c67de0994,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4cbde382a165982,
+                    0x947f53820491c197,
+                    0x68855f9ea204a566,
+                    0xae74247eb847ef15,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000300000b73,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000a0000262a,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000300000b73,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4aea34ec41c26d92,
+                    0xfd9357ee8ddb5270,
+                    0xb5b3727e23baffe8,
+                    0x02f0b28a9c49c41a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec080f902e46c857,
+                    0xd5e93040cdb3c608,
+                    0xd41521d632cb2696,
+                    0xa0482c7478d31391,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3025f8c58b7bf454,
+                    0xb1b96ea5a2eb6244,
+                    0xca618be7dbc76135,
+                    0x01b47d1b25d20165,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x584304fd6512d43c,
+                    0x5f46f99b7782c6ab,
+                    0x1a8ef4d51738c04b,
+                    0x6e92facb8fb13997,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x693073c98c78c823,
+                    0x1e58bba365531616,
+                    0x92e1e185d4f4e939,
+                    0x8828b9abce853aea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf93de14ec3bc898,
+                    0xe81dbb56938ee292,
+                    0x5a8bdf72f24d0d63,
+                    0x9932cb2ce0fd0b1b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb08422f581de3ecf,
+                    0xa94b9fe7ef208d95,
+                    0xa6fb4e3c489684f5,
+                    0x7d15407fd932063f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9d3d8247c707374,
+                    0xfe17dc61dc85c436,
+                    0x80f26609b5fa6161,
+                    0x67fc0fba1a6a3f04,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xda80cd8394442c92,
+                    0xe25591dfc94a779c,
+                    0x5a2dc3a9e860cdf5,
+                    0xb2a932e6a1f3be2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x926ab13002757c08,
+                    0x2b8cb3fc75a44988,
+                    0x0e0934387e01ae26,
+                    0xa3d5493c95d6c538,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x23593c8ae26097fc,
+                    0xd2dc98b778932521,
+                    0x0e0d4553c76c8e9d,
+                    0xca3c13c890b5d6d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ed793dbd1382ce9,
+                    0x0d151da86e4b30fb,
+                    0x92ad2f99f84a5140,
+                    0x0d285fd86a697394,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,279 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger320;
+use super::Felt;
+use super::NUM_HASH_ROUNDS;
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [Felt; NUM_HASH_ROUNDS] = [
+    Felt::new(BigInteger320([
+        0x0000000000000000,
+        0x0000002300008593,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x9fad2ab1de4ffd94,
+        0x36e9d41edfbb48bc,
+        0x3ce4e373e3338ab4,
+        0xe0d5b70dc27ef66b,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x1868cc7b218cfd08,
+        0x88dfef1cf454847f,
+        0x2d3f03efe6c22223,
+        0xcb6ffb67274f1513,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x20ddf87b328cc902,
+        0x66561d228b030ff5,
+        0xb0d4ddba9d8459bf,
+        0x479bf57319de8030,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xc309851b18057eb5,
+        0x58f22d696580a9b6,
+        0xf36ad2fb07d2d517,
+        0x151c5c6499c72abc,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xa4ccf14624181d52,
+        0x8bfe0a6a8027c593,
+        0x96a2a2e68d5d69cd,
+        0xe5d6078b3d92f92b,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x715fae29fdaa3458,
+        0x9e3c5ebfcbdacbb5,
+        0xd32cee23e992b308,
+        0x7e750739aef968c1,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x3f4b7168d163f9d5,
+        0x9dbcc6ea97e79257,
+        0xb1883138228b13dd,
+        0x2bb79af062c2269a,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xf4460a2f397b30ce,
+        0x82deaeb6a6f7eb90,
+        0x6376261e0c6aa77c,
+        0xe9c9169c5512100f,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x7e4de3cf1b5f8617,
+        0x4da262289ad1a8e5,
+        0xd2a2d2e1657e00d6,
+        0x7e8818adee48f8f3,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xd52937c6408047b0,
+        0x2bdd02a81eda3b8b,
+        0xa73a608fa01b2634,
+        0x9294d7c987cabde6,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xb85933a49fe2167f,
+        0x1a920822381e5848,
+        0x8c2ceaed29f9454e,
+        0xe2455b591f564c3b,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xb597b6898e8cceef,
+        0x6939a114c338a520,
+        0x723d40980f164866,
+        0xdb83046fd2329473,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x4cff12d37f0891fb,
+        0xbf584e818b101aef,
+        0xc5dbbd43c6d43d91,
+        0x6ff7f332c5c72485,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xc72f92f317bd36fa,
+        0x87389d19cee2eb42,
+        0xa4823a109400fab9,
+        0xd4e971b900a5619f,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x15b17664d10a6c10,
+        0xd9ea158c6c997c8d,
+        0x580bd8b7b52bd895,
+        0x964837d20c407d33,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x0d1868147d4eae22,
+        0x8a40c44ff279a872,
+        0x529c70a064c3d19b,
+        0x81240ac411a9fac1,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x335c2ae916e5c4ad,
+        0xe48eaf8a7ed753ce,
+        0x2fd44441159a8096,
+        0xa8369b4e1dd69967,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xe7c4191740327237,
+        0x65108a09b9d7a054,
+        0xf1571148fb3be578,
+        0xe4a495d6d92a61ca,
+        0x0000000000000000,
+    ])),
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [Felt; NUM_HASH_ROUNDS] = [
+    Felt::new(BigInteger320([
+        0x0000000000000000,
+        0x000000235555dc2e,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xc8e0a2e8e19092de,
+        0xfcbfba6dfbfaeedb,
+        0x62a10c2b44ffc651,
+        0x3dfaec31a98697d8,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xd7423a9fa3699117,
+        0xeb110ca79c634666,
+        0x35d4ef75c2151bef,
+        0x2858de8f68edeb33,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xadeb385a5da5a065,
+        0xbd98710f45bc701c,
+        0x2b19936744ee6cdf,
+        0x275a4cf9764c7e1c,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x9b10c9f2d50320a9,
+        0x61c752e7e1936bba,
+        0x2f3f35d3f75e77d7,
+        0x709a81841e71f824,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x21ddc401b70e45bc,
+        0x82f13b3a2ae7a666,
+        0xf0c6ceba95043ce9,
+        0x73125db9bbccc4f8,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x3a98481ef43ae542,
+        0xf5d6bce241b493f1,
+        0x714ac049354b1ebf,
+        0x1cd274554ea19b99,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x2882a416d64f6d47,
+        0xb82e9d07656cfa21,
+        0x1a84c7de305ecfe8,
+        0x60817c13836e5f9f,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xdc4438bbb85ff13a,
+        0xd555c1c8ad4aa6e1,
+        0x5ee11595665b02f8,
+        0x449a2264d4fc17b6,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xa9acf9735fbef10e,
+        0x62b8449e9d809fec,
+        0x0ebfb27e0eae52a2,
+        0xd4de5922fde01b7e,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x7542167eba2115b7,
+        0xbae2f30bf4523113,
+        0x820266335f068398,
+        0xe78ff239f98a7e22,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x91819be8092e4de7,
+        0xc2820094366705db,
+        0xa57882dcde0eeb6d,
+        0x8c5681dd7fe8678e,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xdda533d833343fad,
+        0xc77cc236811f5f7f,
+        0x77e929db8320019a,
+        0x8a932e04ca82e946,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x9e54c06a4e61e376,
+        0xf0bdd76368414d87,
+        0xbe6a13e2f1faea7f,
+        0x01e6346c19ff1084,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x1a3fff8de36c91a9,
+        0xf5bbae69111c8e96,
+        0x9dff7d279566e7c1,
+        0xaa71e8045208015b,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x4e7610289837447d,
+        0x2de8101cec5ad4c9,
+        0xfe2d8b97faf0178c,
+        0xe6c38da2a02ab6db,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x7543f60ad9911e0b,
+        0x411126c97a44a8ec,
+        0x1c9ede25e7f20cb1,
+        0x579b8246bf9a185d,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xd85693d8212042b3,
+        0x76c9af74ff25fb72,
+        0x6607f4f14f38f83f,
+        0x8a252c472bb66b14,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x13f7cce9bd34a8fc,
+        0x0cf9bf08d65f4a99,
+        0x89151976b5f1e49f,
+        0xc7464f23652ae16d,
+        0x0000000000000000,
+    ])),
+];
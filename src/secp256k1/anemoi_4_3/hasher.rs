@@ -0,0 +1,1872 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U3>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger320([
+                0x4903a4dc1b74e6c4,
+                0x554404f941a659be,
+                0xa9d0a0880d7fe709,
+                0xf5ddd13facba983c,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd21e48408934ef81,
+                    0x1b1fc2be2831e845,
+                    0xd5ccb1c8b9fc4657,
+                    0xba308e30ca2e34bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6e9240c7108f13d,
+                    0xa36b1da229ccb60e,
+                    0x0abcbf9488244434,
+                    0x08bc0ad2f1ec3cd2,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x703b6490df808656,
+                    0xd80800ab43b79703,
+                    0xb051efdd915038c2,
+                    0xd4b4df6a5d2dc797,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b72440d4d3a514d,
+                    0xebd58d16de2f45e3,
+                    0xf5ae55a5014f7273,
+                    0xfd7aa07e77877834,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed08a01ddcb5a074,
+                    0x5d30d0f89accfdec,
+                    0x2d686d827ec80f4e,
+                    0x2e75fed66ecc7440,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4f14a29afb8dbd70,
+                    0xe70741823546bae1,
+                    0x07922ede1d4f3b07,
+                    0x092855211b5c9618,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee017d6967111672,
+                    0x703755a2c4a201e6,
+                    0xc4a84a1ed3ce3db2,
+                    0xc53f6fac6f320262,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb86ed8407df1a455,
+                    0xbf4cb4485c9962d4,
+                    0x207bc5923f1f98d7,
+                    0xf407268bcde92947,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaff0d253a923fb00,
+                    0x1536e4ff8dec3e20,
+                    0xb1ec0a788f3239e5,
+                    0x57da99d35dfefd57,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1443efdb8d625ab8,
+                    0x2f41ce69349b897d,
+                    0x82ec3ec226661f3b,
+                    0x6676e69aa478e649,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f0eba1f70bef808,
+                    0xd73c6b8562d8011d,
+                    0xd0075cb14b908747,
+                    0xd907832c17ab0d15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36ef7e81e3dd6343,
+                    0xd697a0e0d5a01dd7,
+                    0xc762fe5ce1c98a8b,
+                    0x8e1995e4689dba26,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe67fa0dddd94e65,
+                    0xa0cce374ff27daf7,
+                    0xbf64c53cbd410295,
+                    0xdc3d427bdee4270d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x294482d6e270ac43,
+                    0xeb4c9b74928b3f4c,
+                    0x54e1b9213c72d292,
+                    0x64a0c7e9b0e9f117,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x143151b4af3cfafe,
+                    0x1fcd3e33a0436504,
+                    0x6fa074ea9b48de75,
+                    0x7d33737bab453f8d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x199e4b05090fca76,
+                    0x95fc32311a6fccc2,
+                    0x90f3defaca2c7987,
+                    0xd53d24759ac0666a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb78be93ff5a9cf4f,
+                    0x40c317645cebc51f,
+                    0x089b6c4cc8151253,
+                    0xa0090237313e7c10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x198ac54803c8716e,
+                    0x5657f7cf7e80df08,
+                    0xaf1f27378aef0e90,
+                    0xe0ded78982f70b16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd632653e770c9ca8,
+                    0x716de2920b2bc673,
+                    0xf4ab26290eac4e61,
+                    0xd4b4224f0ca9d5d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc86df51c1ee247de,
+                    0x293ee1f70afa577c,
+                    0xb9cba1805199d5de,
+                    0xe2efbcf638e74e75,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x634561f7a7842efb,
+                0xd536ad0843b0fa56,
+                0x73f562cee8a840a4,
+                0x525caf1d1f1e4c4a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x6bef6175896d060a,
+                0x2e5d2c423cad6e04,
+                0x9cacfdd97693f887,
+                0xccf602a6f8ded3bf,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x57e94c83b545fba9,
+                0xb14640a9ee568778,
+                0xd29c51fd9cc4dd96,
+                0xeef1dda322132c83,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf415acc4e1609ae7,
+                0x62afbfc22c7d5448,
+                0xe9bdd282eeb80fc2,
+                0xa357e45869f9f9a0,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb9eb9c5ea499ac2b,
+                0x4f63cd807fe9b873,
+                0xa6598be386b99ea3,
+                0x09acee40e0ad5dd4,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x47a16c6ad46ff62f,
+                0x9248be0c6590898a,
+                0x9113590f30d6c3bd,
+                0x1c5376a88b58a70a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x78f2f4ea0815d64a,
+                0x6f0be5f8f2b0eac5,
+                0x6d54c5446554bedd,
+                0x0fe797b5693227a9,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf0ffebb2a2a263cd,
+                0x1630580f28d43730,
+                0x53c219521429f8a7,
+                0xda395f4d3aa8584a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x170d4f8d617fc511,
+                0xf51ebc8d5de8aeeb,
+                0x4a3fb4400e27e4e5,
+                0xf7287945fed2c97c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xba6b606631897238,
+                0xca97fea9af8a317d,
+                0xb43c2eed32cea3a8,
+                0x5287567ed2188475,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x634561f7a7842efb,
+                0xd536ad0843b0fa56,
+                0x73f562cee8a840a4,
+                0x525caf1d1f1e4c4a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x6bef6175896d060a,
+                0x2e5d2c423cad6e04,
+                0x9cacfdd97693f887,
+                0xccf602a6f8ded3bf,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x57e94c83b545fba9,
+                0xb14640a9ee568778,
+                0xd29c51fd9cc4dd96,
+                0xeef1dda322132c83,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf415acc4e1609ae7,
+                0x62afbfc22c7d5448,
+                0xe9bdd282eeb80fc2,
+                0xa357e45869f9f9a0,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 124];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger320([
+                    0xeee45472e2d5d1c0,
+                    0xbb6767ed831df159,
+                    0x33802dffd3158916,
+                    0xa13623a6fb9d33f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03597cbaeadd8422,
+                    0xdca2f8a0c4cafc09,
+                    0xd8ee4ef0cacac833,
+                    0xbc508fe5ff4fcd7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x403f5de8f64f0fde,
+                    0xca4a26dae063d616,
+                    0xe742fe8c58226c6c,
+                    0x6278dbf3481ec2e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd796205815b67e10,
+                    0x27f083d7225cdbe8,
+                    0xfc563f8a11042c91,
+                    0x3d2841048f3ff2f4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6da825aa650cec5c,
+                    0xafedbe4b9c204b13,
+                    0xbad519afa15e8515,
+                    0xf5720db534f4cb5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e0d4023514f29e1,
+                    0xd46a5ec4ab4427d3,
+                    0xe2cd9a06e1bf3dd0,
+                    0x0f73dfbddf2edd61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38c934bc0018bb8f,
+                    0x258565f05b2bb890,
+                    0x90fa6b4d472fe43d,
+                    0xc1c9ea9f66d7be5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ccf4b9f0ea5a760,
+                    0x7b1cf54c474a068d,
+                    0x8529995cdec9f2bd,
+                    0x2773d53f8d9948ca,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdae81e38be00b6f2,
+                    0xde6ce2c02491b4b6,
+                    0xb00f61c03aad2f0c,
+                    0x5f34c729b84c6e9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11b076879610f998,
+                    0x09c190915575bd2e,
+                    0xe09728bc5d1d91d0,
+                    0xf8c1858c8c1f57da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x480298463e105b24,
+                    0x6574e6142cb54987,
+                    0x54757c58755d9f08,
+                    0x96e5e1bd0a1ed455,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7cb3ae954b21d38a,
+                    0x11b4c0202b8ce3af,
+                    0xe271b7ca201a6e1b,
+                    0x937634cebac5b306,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xec1e1e108b0fdfb8,
+                    0xda55d21dc30dcfd3,
+                    0x26d727271bb5eb2c,
+                    0x2a2b55c6ea4e6572,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x299ad90b079f8e4e,
+                    0x8c65a3f6647c0a87,
+                    0x6eccaf2b669f2e98,
+                    0xb48c8e3cf4bbdb9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61a54a04d9341c57,
+                    0x684076d3503719c6,
+                    0x8775afb5e9ee9b63,
+                    0x0b9e041b2c3d3986,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56e20c48f6705683,
+                    0xe409dbfc2ce5d2fe,
+                    0x07644d91ebaca57b,
+                    0x0cbf6fc74e8659b6,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x50221483280cdefb,
+                    0xecf39d2be30e0972,
+                    0x29c2df6b0fc08186,
+                    0x1e965a396c876677,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc01d5256a8b62c2a,
+                    0x607f4727c18edba3,
+                    0xc623869f0099d5b0,
+                    0xdbcc3a9c9ac6313c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d6b8e2fed69a5f8,
+                    0x6908f6020e743808,
+                    0x592c9f803feddea8,
+                    0x7605b2b227991196,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26306919c6636cd3,
+                    0xca75c495a1d2296c,
+                    0x78074afcb1c5f732,
+                    0x5a907327bf3af812,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x60a39e8ccbd5d83b,
+                    0x00cc3a770d09123e,
+                    0x2a006b44db186750,
+                    0xd2c920c058cbe393,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b08536ba5485fef,
+                    0xb6e7256c3a8ec78f,
+                    0x9580d8bab7d60678,
+                    0x842adf68beb090c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcbbc88569d4d9833,
+                    0x3fd636aa80a4f3c6,
+                    0x610258d8d7573299,
+                    0xd19fb3f15626c1a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc46ce508e282687e,
+                    0xdc0e7833f8752fab,
+                    0x32ddd8363f09d632,
+                    0xb20e589000f51cb0,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x9de69f9b0413ed1a,
+                    0x614d28ab96afb582,
+                    0x337c623fec0a337e,
+                    0x792d9cdba21beecc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21f7da08b3e44c7d,
+                    0x3d94c7f458bd07e5,
+                    0x8e2462155ad383c8,
+                    0xf9434e72232cee4b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4d5752680bf4f842,
+                    0x661c8f8f6ec61c2a,
+                    0xf0c6e46cb061496d,
+                    0xf03292feaa4c6130,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa428b3259f0d4d44,
+                    0xed0146805dfaeeaa,
+                    0x22b9ccc0f20bc314,
+                    0xcd4bf2c536b24ba6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa2e5f8406a6ed65d,
+                    0x8918646755158e8a,
+                    0x5ddc50d63f7098d2,
+                    0x602ea8e72be5f3d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa782c6e7c6262c82,
+                    0x98724198018bdafe,
+                    0xf337a78e34809aa8,
+                    0x28a42e043ffb4388,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe1a9f4ad4dfdaaf0,
+                    0xb038c8a85a304ed8,
+                    0x62789eeb3e9c8eec,
+                    0x287ed3aade424d36,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe318b1e63d961309,
+                    0x5e0d84345078bbe5,
+                    0x20d56cd4eb925ad9,
+                    0x1a566e0642c04227,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2b4bbf1b5f1d21f5,
+                    0x8ee4bc991645d999,
+                    0x5ef88e77b75c529e,
+                    0x54007865b713a567,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbcdd2c436ad4a396,
+                    0xb8fba811dc7b6c2e,
+                    0x2df5456d1cc92ab8,
+                    0x21879d347bb02687,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x694f5bc4a2ec2199,
+                    0x10be1121b459b1a3,
+                    0xf4daa14ac7b2372a,
+                    0x7863d0eaf0dfb80c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58caf50ab1f33329,
+                    0x31e37b20206331bc,
+                    0xef5d23db4046a140,
+                    0x62e1475708e34bf6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd737a57f3d76fda2,
+                    0x56fe588c8f017a11,
+                    0x484774793de62a7e,
+                    0x4e50c486fb47a66e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c628c88a0e21d3d,
+                    0xcdb3c8ecad525f85,
+                    0x9e5de04e149f5d04,
+                    0x2bef6757bca3afd9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd3c0466825e079f5,
+                    0xacd7c0f6de8fcc1c,
+                    0xa9235e900b172797,
+                    0x9d024064205d3b6e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x479bb98928e0dc60,
+                    0x520f7138fc11e104,
+                    0x1230b722410d95f0,
+                    0x978b84f58d63ca68,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x21060669d0bd1988,
+                    0x8ea8efffb99ad2d3,
+                    0x46d261d7743c35f9,
+                    0x3d932f5cc6fcd4ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89fe363fd329966a,
+                    0x61d2165072ed1ea8,
+                    0x5fc70478e68ee798,
+                    0xd68c4f2ade07a4cf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecf24a91b08cad6c,
+                    0x9042214828e8afd3,
+                    0x172c5a6a1a192ad8,
+                    0x897615a3d5e92627,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff95c9cc397ba4d7,
+                    0x1242a707b109d1d1,
+                    0x0d13299885ee45d1,
+                    0xd0e7d3de94174965,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf4e7393d9ec1d02e,
+                    0x401ddf82b95f2dea,
+                    0x1e54d587ce45a80f,
+                    0x300845e317e36295,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf25c87e5eef15e9d,
+                    0x2879cf7a3f946317,
+                    0x2821f7907877875f,
+                    0x3a7169d7726c48ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb504858362e02f0a,
+                    0x2e8ff4897b967c3f,
+                    0x481989d3555ee682,
+                    0x4462c7d6a46e3235,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x104eec826130631a,
+                    0xa2cdbfc836edadf5,
+                    0x1878281c4e077dd8,
+                    0x25bdf92138366ce0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x15a22cfad25ea5e6,
+                    0xb37e7db5941e0d92,
+                    0x756c3594f7de525f,
+                    0xf77f7b8b7fa98e56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89998bc024733adc,
+                    0x3976ddb0acfdb9eb,
+                    0xe77f630def39012b,
+                    0x17a7fe153a435adc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21e6710741b8d228,
+                    0x22b9870be35cf563,
+                    0xa486a402f37c1397,
+                    0x211973f7503cc8e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe4f88e8cd4ab180c,
+                    0xfcb592e2c09f0081,
+                    0xe7876829000d28fd,
+                    0x9448b36414b2e915,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x05dc37792451fbf9,
+                    0xb5d386528a9bcab8,
+                    0x74e6a4cfe3820cb9,
+                    0x807e775befe3cb87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1acb9543933f1595,
+                    0x08eb999aa39e7fa8,
+                    0x382d6b5c820fc9cf,
+                    0x2161f2403cb65929,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc37c1b251783ea6b,
+                    0x99cafbd5abfc0347,
+                    0x7d1369cb5d70400c,
+                    0xbee6579eeeaeddc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8127e178b56e8f36,
+                    0xb29bd69fc7bbea72,
+                    0xdf9a305d43625bd4,
+                    0x71db9f42c1d1b399,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xc291d73cdc2e2792,
+                    0x76328b5ba8b2e333,
+                    0x6bfc36b6beb2b8b0,
+                    0xda83801efa32d4be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5461c3b7e551188f,
+                    0xf89c2f65a4169eee,
+                    0xcdad78985d0db201,
+                    0x0ebbc6a9377f6302,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac2840d13947f2a8,
+                    0x9ef6e3ec519b1676,
+                    0x241d74a5b5b7f997,
+                    0xfd9d4996e01cdfd7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb15d48e9ca737557,
+                    0x7ada1ea222046084,
+                    0xd53125d5673da838,
+                    0x11520e27d604f659,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x08302dd426dec147,
+                    0xa42f98e04a58a4d6,
+                    0x581290e964031215,
+                    0xab7139caa0b5bda6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b15589a5ac702fb,
+                    0x286522972642210d,
+                    0x62308a32a358a79c,
+                    0x9db7170a39b14cf9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xffd2f7ba0d2caa3a,
+                    0x7e6dd2693fc14ca3,
+                    0xe5d6a9ca6655f897,
+                    0xbd8e611f1846868f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2765d6aed487faa,
+                    0xd000789b8e1e13dd,
+                    0x393f7af3805adf3f,
+                    0x212333f117552bb3,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xcdfcd8b3e4bf1859,
+                    0x1a7793bf5440a207,
+                    0x7ad698e7276e3865,
+                    0x93fc13d7d824bf5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa62410caf88296aa,
+                    0xf98b3f96978e1b0f,
+                    0x6647cec1bdcb3878,
+                    0x2cb3f7560c9d2c51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfed937766f07d2c8,
+                    0x6b9da36c90306883,
+                    0x3ac0ca3d365da189,
+                    0xe953898dba3606b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1b5dd6025fffc5b,
+                    0xfe27190074574691,
+                    0x7afea72711436215,
+                    0xf1a2c81b8d4a3bbb,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xbfde79a4b7f83d68,
+                0x9ee1f09fef6cbd67,
+                0xc1a0c45546ddb746,
+                0x7270eb4dc548dd17,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf180058eab024957,
+                0x531dd60fccc10ad4,
+                0x1380b12da26d0c82,
+                0xbd7e85c3e0feacd7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4a68bf28309502df,
+                0x218aa5ff56a16989,
+                0x5113f86473f1337b,
+                0x88d2d6eb6be1375f,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc4c2a6938b93bdf9,
+                0x0e464cdcaaa90abe,
+                0x834e0bc02a2ee9c6,
+                0x42d541b121028f5d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xeaa0c6cb82365cab,
+                0xfe370c3115e9b7c4,
+                0x253781c711992dd0,
+                0x0b6ab15543cfe383,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xefb45591e48d1088,
+                0xd873ad2f40788bed,
+                0x615c362ae2a1d958,
+                0xbeca7ef8287fb2ee,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd4b9a79e8fe9c380,
+                0xf70a6ae5bc2dfb47,
+                0x1ab99952888e3e57,
+                0xf0d106a366ddd254,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x26eca0c06463e45c,
+                0xc0c49f96e09d4c5f,
+                0x478a3bc2abd22d0c,
+                0x9004052eb63bb89b,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xeb76b79df11c049d,
+                0xca27e21b094fb7fd,
+                0x70d17d9ddff8e550,
+                0x05fac198be5d42b6,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3ef184c3731ebac2,
+                0x6b419bf180e66f4b,
+                0x239cdb781a8a4ecc,
+                0x5d4de0973afc0386,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 93, 94, 284] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 93];
+        let mut opad = vec![0x5cu8; 93];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
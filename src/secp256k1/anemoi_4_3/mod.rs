@@ -0,0 +1,1180 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xf323db44d748c4c1,
+                    0x4fda59c1deb0ef61,
+                    0x395f49c6b2826a25,
+                    0xb172d6a9d5978032,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5681d4b14c29642c,
+                    0xe25554fe9e064931,
+                    0x5c1987c7cdfdcf07,
+                    0xf327d80cd5ef34bd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05efb458f1718817,
+                    0x0556d82a2d9b4b7e,
+                    0x1b2d05e4b00ca075,
+                    0x53fcb66e1bc1f4ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a6389f4c8b7eeb0,
+                    0x493a81b49d62ba37,
+                    0xa5184f071b2c021c,
+                    0x8d3e25a5569c4ae7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x40d97729408bcfbb,
+                    0x29e191d9fbb8b03b,
+                    0xc0d5714fba85f129,
+                    0xd3edf23cbcba88c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x746bfd32d6a46696,
+                    0xb85b1738a4f24efe,
+                    0xa5c8bf4e6edf8a0d,
+                    0xe57e57571c9fa88b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1e95c5a445ad19f,
+                    0x4e21cb6da2d46fbb,
+                    0x7b7fecdaf1430a7c,
+                    0x06d66c825203614c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83cfdd75f763f956,
+                    0x80d730f6016c02f9,
+                    0x9fbe6da99bcc5fac,
+                    0xe217c96ccc630b87,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0f448a7a4a8b74b7,
+                    0x6fa28019a0b737c5,
+                    0xe92528bf0c636c20,
+                    0x44f4f0537723f0b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4128ef8159a99aa0,
+                    0x94c19d04f40d6506,
+                    0xbf05aa931cbb7271,
+                    0x0cf209dcd5f13e2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac03461596b715b5,
+                    0x07e8f02c97f7549c,
+                    0x810753e8c7dff86f,
+                    0x614785905f04ce83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ca31332542eb711,
+                    0x27992f0996688df0,
+                    0x1d20414dda536526,
+                    0xaf35ebe3b3fa0551,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x420c3f9f1a93d416,
+                    0x1506e8604a74151f,
+                    0x31383f3240b2ffbd,
+                    0xeb81df2c24af11c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42c1be54323811c4,
+                    0x7a2223d9e309a567,
+                    0x9d0a4558fad453aa,
+                    0x68f0a8786acdd11b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3774ef10a8b192b5,
+                    0x543e6f77a5d7a9f6,
+                    0x81abf3797227fd4e,
+                    0x47db6600bdb2171d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7bebb2eb84f5fa63,
+                    0x6034393a0032f999,
+                    0x73e736a966150bea,
+                    0x3bb080234df0dd0d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5d18de71e422ea31,
+                    0xa80ca91beb795430,
+                    0xdf5af5185fa66726,
+                    0x8ee54991a710d975,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42dc2da1fc1585e4,
+                    0xb30968656135631d,
+                    0x29d9b1a1bff47a81,
+                    0x341d648d500d8024,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd81b40f790e3fbd,
+                    0x1ffbcdb9a501d92b,
+                    0x99c8986787ee0738,
+                    0x7981325380e74416,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb70c85dba6a80ca9,
+                    0xf498adf355c8b1de,
+                    0x6a37884070089bcd,
+                    0xdb3431d4eefcf38c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9b4bf6f744647846,
+                    0xeb25ebc07fa237bd,
+                    0xc1c22488fb7ecdd4,
+                    0x8cc9089ef553f943,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8cb6e7fb9a514654,
+                    0x65834df84e15e517,
+                    0x89e774b42e71ad95,
+                    0xd567caa729b7ee0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4af64b5bf42d1e37,
+                    0x4bd62d229a9ad54c,
+                    0x609058683a888289,
+                    0xc2d49a21427bf277,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b4b6fb00384b758,
+                    0x4fdc3b4fa92abebd,
+                    0xe5a65ae1565aa044,
+                    0x4d997ad522468333,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x145b7842918f78d6,
+                    0x34bc6898f50e4b6c,
+                    0xb9a22d83a796647c,
+                    0xc673f6d1569995eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe123520d3ef41ba2,
+                    0xd2b3cd561ec9607b,
+                    0xd7646c039d12c84a,
+                    0x108645f6d19aa6f0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c6a8819cca6ecbc,
+                    0x2d7592431095866b,
+                    0xb1ead1ea47594a23,
+                    0x66d43d49b428a727,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89311a527231c651,
+                    0xedac67fa93cdbddd,
+                    0x874e64904153373f,
+                    0x9b2bbf8e2368e142,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3dab1e64ab4c0044,
+                    0xdfadc781df8615ae,
+                    0xd458d91a7264b948,
+                    0x4cd834de8dafebdf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd4988a6fe174d31,
+                    0xc5e30963ef699d33,
+                    0x99bdb6f708e21826,
+                    0x53e59e65b9c9464c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa1b84ef4b6b64a9,
+                    0x0943cf018a6f3227,
+                    0xc3ad39716d3a92a1,
+                    0x8c5840a917b5b872,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd83c38b0fa741d8,
+                    0x8a5eaccb826505bb,
+                    0xca1f6f4ec870391d,
+                    0x63a9524ce11da219,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x86e94f29f84c9951,
+                    0x76ce5c7806f2ec0f,
+                    0x173fdcf68288f49e,
+                    0x637dda469faf406a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc57fd649f6dc0172,
+                    0x5ab36b98834f1471,
+                    0xbb1ef2130e4f328d,
+                    0xdcc62913e669d48c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb41f3c6d5345ccbd,
+                    0xc8bf8e254b65ff8e,
+                    0xb8f7420ab29a2c9f,
+                    0xf859289bd40f7ea1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3bb403d58fc8a2b,
+                    0x757b33f9e7779d43,
+                    0xc38f85d6c15441a8,
+                    0xb169c3f78accab70,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x89aee5d2984e0197,
+                    0xb2eefb9cfda69fdb,
+                    0x43428aa3cbcceb01,
+                    0x702e58c3e8239599,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d9e067f62fa2266,
+                    0x6a61948d7a182aa7,
+                    0xde055dcecc5005db,
+                    0xdbdbe1dae65237db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6399ff8634603015,
+                    0x2e301e3705cda1a3,
+                    0xf8c61ba09b79c425,
+                    0x77ae410c38da11da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84cc971c9aef29df,
+                    0x16ba263c39e7450d,
+                    0x67c8609543a0a805,
+                    0xc54b98d2edaf9c6d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd5187d73b1891a36,
+                    0xcbf16e409c152ff0,
+                    0x5c532044d6e0458e,
+                    0x4cf61b1d586b7f12,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x857254670a3283fd,
+                    0x1715ded203e578d3,
+                    0x81e915d4e5241c62,
+                    0x868bca0bcba23ac7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67768ba511c45576,
+                    0x735a8dca2c2b1f6f,
+                    0xe2cd1a93bc908424,
+                    0xbb9a5802767dab1d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d5b52aced315c3c,
+                    0xa757521a797c044d,
+                    0x5746f536b386cdad,
+                    0xbc7282e5452c2b26,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x84bf8c69756a4ab3,
+                    0x6129c069ecd27a6a,
+                    0xd2da1d753ebd12bb,
+                    0x0f310061e33d9fce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c854969c016378e,
+                    0x2d98474ce9f1e1cc,
+                    0x1992963125060bbf,
+                    0x20006fa2075d694b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d942519274ab50b,
+                    0x31a0bce4259a33df,
+                    0x19f77eb635b1b1b7,
+                    0x5dc898b2708e0b57,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb1f15602684bd919,
+                    0xfb2a2d0aa9834181,
+                    0x56d9a8ec90767c21,
+                    0x3766e1af065bfb62,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x0bdfe1d6074e5e2c,
+                    0xba812332974b1361,
+                    0x81d87cca03a70362,
+                    0xaff7f0996883cb2b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a497a193993ba8c,
+                    0x1394572e2e608c32,
+                    0x437f7d304a9e042e,
+                    0x0d36e8f802bc85f5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7bfab83c058e4c33,
+                    0x64369ad2393a625b,
+                    0x2b225c6bcb1a587c,
+                    0xcb30e55e197a3a58,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2cd71d91f0d0dadf,
+                    0xec67b65d35ed7393,
+                    0x08ad36560fc7563e,
+                    0x104fc605837c3492,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x54e541fde6981ed2,
+                    0x5a0a1f902784204b,
+                    0x589430b09200bebb,
+                    0x682152376ac3af90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3a2300b9b2f8eb9,
+                    0xa6f2a4eaeb1507a8,
+                    0x90e29b4e3000abb1,
+                    0xfc57d31ba5deb367,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x203ec92e0d71ff8e,
+                    0x3949d388dfe79b7d,
+                    0x61fc060adcecd1a4,
+                    0x6e47d3efd06be911,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e13cf3151d9e042,
+                    0xee75f28de4fddce3,
+                    0x6404ec61b18f6498,
+                    0x026ac26b8f5d4cee,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4f57010ffa188d2a,
+                    0x837c3a8a1f427f4f,
+                    0x057ebc2715c9ca8e,
+                    0xe548a6a9b63cd19b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2fe96c0514e94aa3,
+                    0x18f7faf31f3c5a3c,
+                    0x7474da732cff5255,
+                    0x8d8424a69374b0ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x260129719fc102a7,
+                    0xf008fa60d33626af,
+                    0xb748adba02b0b89c,
+                    0x6baad3e0ca19686f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82e149a36aa89af,
+                    0xc9be3b72a67be0b6,
+                    0x1d34262f0a567c82,
+                    0x2aaa9a488bc6ea2d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x611170f2fa637975,
+                    0x5820dd1493063420,
+                    0x060f9030479b9da8,
+                    0x17f0767165f037f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8159001398ada857,
+                    0x362994a433964630,
+                    0x1258849198494847,
+                    0xf999356780e2e875,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5195abdb99ac3296,
+                    0xa0a6f16ba23f6330,
+                    0x7a0e8cf968cc5a0c,
+                    0x1617d0822dddb547,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x254dea63b496a214,
+                    0x7a6bf7f95785c2d1,
+                    0x9c046f4232ea2834,
+                    0x9f5ecacfe9a55d0e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xabb284027224941e,
+                    0x6b3fd765eb31ffcb,
+                    0xbaa1a5eb32d4a038,
+                    0x9defb1abdc60451c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26db21368adea7ac,
+                    0x8fe6304408df065d,
+                    0xa68c5dcff7c885b7,
+                    0x3ec1bbdd3478c8ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x234f979d32aa6a2f,
+                    0x7eab4c0b0dcf981a,
+                    0x10d559d247b0387b,
+                    0x928ae839708418a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6bbc6ebd3ea4808,
+                    0x09285ea6d5298e76,
+                    0xc1e2d0628d9a5cd2,
+                    0x76b1016fe9b60d03,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x235844b5cd78b75d,
+                    0x00e8ca4fc61e7785,
+                    0x58bb32c7229026ab,
+                    0xd58356898f67ccfb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f58119c0c2ea37e,
+                    0xafdd733a810a5bcc,
+                    0x3d1fde257f01f48b,
+                    0xb053f1e69bea174a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc23c087210883553,
+                    0x6ec181683c46857b,
+                    0x225d5a8c877fcbe4,
+                    0x15ee0942163aee55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x368eceadb5b3d8f6,
+                    0xfe13307671307095,
+                    0x695daee546549af6,
+                    0x5be219854342d590,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000400000f44,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000d0000319d,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdabc5021b4098dd0,
+                    0xf53e28bd226cb7f8,
+                    0x4c56f45ae3810fec,
+                    0xd79cab8170b95d0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a7e6a8055b06b9e,
+                    0xf34ed16595a6b41d,
+                    0x28845a40f52133f4,
+                    0x940ceb7c54e89d17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0c74648017bc71a,
+                    0x190b86d3e19c9aa5,
+                    0x8a144b9971165fb4,
+                    0x71e2761fcfeae39a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e508b160a01a923,
+                    0xaf592f4dde10324c,
+                    0xc95f3f381e5d7798,
+                    0x20d847bd893ae527,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9fcbd223b826d670,
+                    0x4ee20e50e8c33745,
+                    0x0b3c029b2202c1d0,
+                    0x5d28cb8a5c5fc9c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa305a678c3a419ab,
+                    0x9398cfdda55ead79,
+                    0xb296a31f9608f122,
+                    0x13d235babafe10bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xded02abc7a2fe2bd,
+                    0x9a536d2884b4af5a,
+                    0x89f8fe824855d985,
+                    0x4d423e3b00a10822,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbcaf49647c01ab96,
+                    0x08441b026e05a98d,
+                    0xffe70191b5ee5e35,
+                    0x560e8ea0d24f0178,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdf13452138d474b5,
+                    0xce642b637cf78e03,
+                    0x62dd4b809cc7c18d,
+                    0x8dd5149d709ae3a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd233b6abf66b064,
+                    0x84247d1d96230447,
+                    0x9d0cbcf5035696fe,
+                    0x3703627ee5455ba1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a3190f015ed9575,
+                    0x99d92a95201e54c4,
+                    0x430e2f5d1268a659,
+                    0x6dab15eaea13237c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6495dc42e189c6d7,
+                    0xbd947a20339124fc,
+                    0x80733bd139eaaba9,
+                    0xb4ac15a18852d2e4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe51c7130c46c7ded,
+                    0xfa9d9b012dc906b1,
+                    0x3d191de51077767d,
+                    0x04bc16a7e898f155,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30ae53a6e5f325ef,
+                    0x260265a7bcf15a46,
+                    0xc9a3de40c9afabc1,
+                    0x07cd795f3aadbc74,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a0eedf6819b39d6,
+                    0x5c60cc3c3e43ec62,
+                    0x0a30162e6d4f365a,
+                    0xe1a63c56733e7ce5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fc275c11e7de7ba,
+                    0xb5c956205d0b2856,
+                    0x989ecf84b0b9fd1b,
+                    0xbb0a858587992bf6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2043e7a712c08ef3,
+                    0x1af2683205cf12e3,
+                    0xae46bf5b1a2e315f,
+                    0x5a34e54379ca9fe8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87a6d82cc3205856,
+                    0xe0bd68da1a4c3f06,
+                    0xb1609be1465319d4,
+                    0x4d606ba7a1d8a8a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30aa8dc56be98e37,
+                    0x852a42c7fe9856c5,
+                    0xf462ddd964ab0644,
+                    0x2e51ba1c3b4256e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb54f40ee766718a5,
+                    0x0e2a146309989c69,
+                    0xedfdf35e75b14b49,
+                    0x1d80168e224b1d4b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb160798bf204a979,
+                    0x108123ff493d8ae9,
+                    0x101acd379f96044e,
+                    0xe67f2c3d632612da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43797e42e23cab5c,
+                    0xe160df385cc2fc89,
+                    0x6d7045cc5dc40175,
+                    0x63d1769ec55c4fd8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d42e803e74c78ef,
+                    0x4a57b4af26040108,
+                    0xd075be8adcd3fea4,
+                    0x9dac354b85f3a08f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a04c07ec66da3f1,
+                    0x4dc89f75ae528895,
+                    0x93be962d1dfbc7d1,
+                    0xeef2a924a815d004,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2168 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U5>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0xba3b7690fbda3c6e,
+                0x021504ee3cb5a2e5,
+                0x8c09aad4a909e9f0,
+                0x8951e3dc41c7b71a,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8ea221dbe24880bc,
+                    0xa7d7144eeeec087d,
+                    0x1fc2215d408791b7,
+                    0x2344abba99231b7a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4dcb56e2b6ee505e,
+                    0x49824154399043f5,
+                    0x74d6965c28043755,
+                    0x887e11a39900685a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb039bc1a3eb88a80,
+                    0xfdf628e6a894f82d,
+                    0xb3a2639fba4136d7,
+                    0x9ec118d97a07c7a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x739608c6b0e24a16,
+                    0x3846938dc20ce75e,
+                    0xc418ac6ab505dcc8,
+                    0xbc04fd71ef3b201d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c547091b380530f,
+                    0x9d41b2dcd7c436dc,
+                    0x987c39904454121a,
+                    0x7d34ca2471063c00,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x76f82de0f5b9085e,
+                    0xcacf8c16afdabf05,
+                    0x9711e30691e96f16,
+                    0x1ea4366d38756d7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc910221d9932d2aa,
+                    0x04c2d599f2cfc6a3,
+                    0x9c779d6cd1e9d020,
+                    0x01ef7fd1b8831ccd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5e6e1150318a3e4,
+                    0x65413384da4ceb5e,
+                    0x27f453c27ca9d448,
+                    0x101ac2489969fac3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5a602c8dda7c914,
+                    0xfd77e9dcdb0e08c0,
+                    0x583eaade02d0379d,
+                    0x3eb3643015d52bc5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x731700c0cce9549b,
+                    0x026e5ceb2dc6d6ce,
+                    0xadf94558fea69923,
+                    0x9fb055ac7f8bb86e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf7eb04cad0b3f1b,
+                    0x062c760ce43b80da,
+                    0x55a984fa2cfcf618,
+                    0x47a7a00e055a3fcf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc12eb585d5625c24,
+                    0x53021de807480196,
+                    0x1ba4a2b908b2a4b6,
+                    0x8443731e4a3a54d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebd2e1783af18cc0,
+                    0x53271ca3cb8dc330,
+                    0xef33783dbd439e9c,
+                    0xd415c56075b74199,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf37b73193be2b035,
+                    0x2bc8b91f2f08a57b,
+                    0x3a6af56f342de3a6,
+                    0xb9e9f777526dd05c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa8b842433d0ed1fe,
+                    0x3a9ff53047843163,
+                    0x83a238a4cfcfb966,
+                    0x59f6789de955844f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1591e596691cb90,
+                    0xe7341a0873a058d2,
+                    0xa0bfa894b4532e33,
+                    0x3c7e2049de110653,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe39ad947dec32284,
+                    0x3f8b5f8539077f6b,
+                    0x700771b1612d8f42,
+                    0x6e78a628b93b4b19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f5976e269887c0d,
+                    0x35d7ea016b5afb20,
+                    0x11cbf5d7913e35eb,
+                    0x40c365ea12ad6bc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d8c7c54c6b60c86,
+                    0x4d8b91fc2791cf12,
+                    0x99b0bc8145ad75be,
+                    0x9d24442813b4c7f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68f0eb11d1e59f38,
+                    0x8fb8e4bff8c44779,
+                    0x3a5d185d62405183,
+                    0x3d06de73ea51b66d,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xe5d0263f5e42a3f5,
+                0x97ee7d943e5abcf7,
+                0xcfc603670035f01b,
+                0x495875ba6ed33e13,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x618523561e1c3854,
+                0x7bcda00ca5121c7d,
+                0x64d060acc555cfde,
+                0x15afceeaca57f2c5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4977c37c9e7f4499,
+                0x6921c54edb257640,
+                0xd1524c0654e16bb5,
+                0xbe7f69bdeb45d654,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd4533ded20d9f311,
+                0x00b2a8f0922ad145,
+                0xa020d6a1ede00262,
+                0xb1d8ffda8f8fe27f,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe5bb16d094010a4b,
+                0x6c2a3b7bea13846a,
+                0xdd578851db290087,
+                0xffe414cee7889cef,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf1c474f28c85a61f,
+                0x0192a2b9a266e4ff,
+                0x98c63f8f47c0d21d,
+                0xb345a0c11e978c9a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x7f27bd5418b34e58,
+                0x775b0a7e3f853be7,
+                0x8c222157d7582545,
+                0xb6db2fd264fc5348,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb83d20221033e9a1,
+                0x1888f9a86fa1e5e1,
+                0x3a2e3e6480db4a26,
+                0x78fa0424c98fc9c6,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa7bba58a92004a43,
+                0x42613f6c5afeba74,
+                0x7c9b09fd8e72cbf4,
+                0x5b732ec507194a82,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x650db4fbbc272ecb,
+                0x1666b721c0517961,
+                0x3f819084e0656578,
+                0x5140fa6b49f1675a,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xe5d0263f5e42a3f5,
+                0x97ee7d943e5abcf7,
+                0xcfc603670035f01b,
+                0x495875ba6ed33e13,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x618523561e1c3854,
+                0x7bcda00ca5121c7d,
+                0x64d060acc555cfde,
+                0x15afceeaca57f2c5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4977c37c9e7f4499,
+                0x6921c54edb257640,
+                0xd1524c0654e16bb5,
+                0xbe7f69bdeb45d654,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd4533ded20d9f311,
+                0x00b2a8f0922ad145,
+                0xa020d6a1ede00262,
+                0xb1d8ffda8f8fe27f,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 186];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xfc3c38876b36b873,
+                    0x35ce98479df2f004,
+                    0xdf46bcdd5d571989,
+                    0x7b886063019f473c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5967e20249b75f96,
+                    0xb32f021db3a52185,
+                    0xd4078371a1c33c64,
+                    0x3af6fd75619a82ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x664f40c476179555,
+                    0xad151107a15ba59e,
+                    0x8e8a61502d0de4b4,
+                    0xf24da1179155d99d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70b07bff0dd9739a,
+                    0xacc7cc01ad076f73,
+                    0x50a36258ff955236,
+                    0xdffd0aff8edabcc3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0330156c545d7bf2,
+                    0x41523be12939e667,
+                    0x56a7a87360b9bd74,
+                    0x852dbe280ecd90cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6001c77b6fd3632,
+                    0x33ac322169bd2ab1,
+                    0xb88f175b50e18ab1,
+                    0x50be23fde3e7321d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe58507049f79dc21,
+                    0xc04576b6e4667cf3,
+                    0xfa0843b0a19aed45,
+                    0x33f1439b53258ca3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67509bb8fa24d571,
+                    0x53ad0e3a20136db7,
+                    0x35a43e8a0152e5e9,
+                    0xa8c460acdd67aa87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9402fc8ab2e68404,
+                    0xfdd5c65ee07e6f6c,
+                    0x2d3057ee58b1c750,
+                    0x0dd86b35aa88ac8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x331c86b5a413a0c3,
+                    0x4fa06fc9af548f1b,
+                    0xc1da0563a4b965f2,
+                    0x6e3eb20fb5e761cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b22980486b07b8c,
+                    0x4cacd60e5ad47fac,
+                    0x1c5cff7b7621e779,
+                    0x859af8a0496829b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf46facfe9deb814,
+                    0xe743cc9c8e4a6770,
+                    0xc9c2b1bebd5c4b9e,
+                    0x3e25ef947763c6e7,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf5874d44ea481039,
+                    0xe340d06e8dcac99e,
+                    0xaa9ea1beb705d539,
+                    0x2a0aa65d5f1db79b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x254f452f202ec758,
+                    0x5211a578edc696dd,
+                    0x5c95cdd1146ebdbb,
+                    0xaa68620c08abfa69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2d71b7990e19226,
+                    0x7f9efd93f28ae17e,
+                    0x1871582665c09e31,
+                    0x526799aef5b763ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa739b42a6f84db70,
+                    0xec216465597978b1,
+                    0xe64b0ebc29e8e313,
+                    0x422541c2c007efc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb83e5acdab61dc8,
+                    0x077448624fdb7552,
+                    0x92b4887b49d187c5,
+                    0x349bf1f0bce29a2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe441064d871bea29,
+                    0xfd1c3459a8e5530a,
+                    0xfcc63e1bd03de364,
+                    0x699af14792ca6e3f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x338c0af89ad23a53,
+                    0x40c7fe12a71cd1e2,
+                    0x1f35d102fc0ea968,
+                    0x53588c1e63e3f164,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7024a96976b1d5eb,
+                    0xd08d201b78d39669,
+                    0x859f8e2bb75cf6bc,
+                    0xb3841c833e8328e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3a3b42908e1975f,
+                    0x8f6adcdfb121f83c,
+                    0x0cb66dfb62e4cc9c,
+                    0x61346e2bb866b593,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5dfbff64764f4743,
+                    0xdfd68186aba8191a,
+                    0x89c8715c15ae0449,
+                    0x7f1a92c33eabb69e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x247b10ec4a4a94f9,
+                    0x6d4718d681d6ad69,
+                    0xe81f0b20b80b5a74,
+                    0x6a3666e6359878d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b4fb8f9d4dae417,
+                    0xf4ddeccfcb4478a3,
+                    0xb37613d19f5dbb84,
+                    0x932aeb27d9f801e2,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9ac7d5d3bc452f0a,
+                    0xcbec79161f369095,
+                    0xf8b03f2fd5e5432b,
+                    0x15e3c877f96d6bc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7834ae3853a4d51c,
+                    0x2d23d39016df89d9,
+                    0x73e54bd2e43b2ce7,
+                    0x88abe5cb9bad2ff0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb36b21e749d33e5,
+                    0x2f6335bd265ca441,
+                    0x84e9ac66a4048489,
+                    0x7160264bdf492a73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f40a8de32f36a26,
+                    0xd6e0e51f4ac743f9,
+                    0x5fdc8a078455eb6a,
+                    0xaef2f3e5938c46a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d0a6ed09739483c,
+                    0x5457995ce9603f49,
+                    0x5b6405d423e0de66,
+                    0xfc4fc2b077dce073,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0d03a7ab4f7bbb1,
+                    0xcc8cc3e2ba6f8dee,
+                    0xb0265d51677c07fc,
+                    0x666926f97f8c3af0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd21b4ba5b85cccce,
+                    0xe95e984fb73fc5d9,
+                    0xd01c1de975f6e0f6,
+                    0x884e09cc1dfd2aab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8cc610c0163fa630,
+                    0x0afc003158621af2,
+                    0x890fb6dcb3d22714,
+                    0x83d0315b8ad0ab83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf49d5feb4065699,
+                    0x596f14b3bbe82a0b,
+                    0x7643557e11810632,
+                    0x1b811340343b9ce8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe127288e1bba2b8d,
+                    0xb309d153aa60f1ee,
+                    0xfde7b24760b109c3,
+                    0x2783b89ecf42687a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d57d981f2bd3c20,
+                    0x3db460e705b0f29f,
+                    0x538c1463a151a393,
+                    0x4b69f54cff62d805,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbead26f1af9b29f6,
+                    0xd7a1a85e8fb75a23,
+                    0xd28bef9577f95093,
+                    0xf69de6897d3416d9,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xe3c328a68dfd8a67,
+                    0x1e31ec171b5d5199,
+                    0x06865b12cd7b569b,
+                    0xf57326f38bcd7922,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x863f700a1a82653d,
+                    0xc51614f4ade9d23e,
+                    0xef44ac2cf7e6a108,
+                    0x7b85350e78c36a20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0be0cba14117b17,
+                    0x08cb2a974ed87b30,
+                    0xbc9f78936766e912,
+                    0x49f5a63fd86dfd62,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9d4bb5250ba358d7,
+                    0xcca968bee10f6e94,
+                    0xcd4f07eb20f9c3df,
+                    0x05b31ec10a753edd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8e925839f57b715,
+                    0x403f9bcba9b6393a,
+                    0xae9e21a74a73b5d2,
+                    0x9e44179259e52752,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b408c7542126727,
+                    0x7ef5d1d95f399ed8,
+                    0x4b94acc3c5023f63,
+                    0xada5e372aae348f2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe14683597682dbf9,
+                    0x5aa0e831941d7984,
+                    0x16f4ee047476f867,
+                    0xe9d9fc08049c5d40,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4aad7f5718f5c057,
+                    0xd12e6566c215d8c3,
+                    0x5e22997ea2a4661b,
+                    0xf83c2b5af7b81cc2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55b04eaf629f8b12,
+                    0x6973b064602b2133,
+                    0x97bd64ce31892ceb,
+                    0x881ffc13ffdfd118,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2065745da9ce14e3,
+                    0x65bbd96bcbfc98ef,
+                    0x91f08224924bdcc0,
+                    0xc2f161f70c0736c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf6b228a490a92e2,
+                    0x5b2a01812293f101,
+                    0x4248bbdf469a3707,
+                    0xe30ecc83ec1a7b0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c79111cf0735ce1,
+                    0x41e394a79c7d1497,
+                    0x592a0ada609ced11,
+                    0x354db5ba1cd1ab98,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe42bda5b2bab2ce9,
+                    0x283ad9c649b25b6f,
+                    0x9f0eed4b68245fb3,
+                    0xc19382746eb1f8cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60f8fa276b420b9e,
+                    0x14afd95e25eddd30,
+                    0xa4dbd0e911b1956c,
+                    0x6269565f31f98fe2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf63728444642f0af,
+                    0x2385b2510350dbf2,
+                    0xe3e42e880e9debbe,
+                    0x3c0aa9a1bafe3c73,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc8d9ad1b7598960f,
+                    0x830ac83d10ebc665,
+                    0x1e0b08cb5cacd8ad,
+                    0xc8d266c2c788cfa8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2704e7db823fc1c,
+                    0x08870f1316efc45e,
+                    0x85e1354f3cba734b,
+                    0xa66d2373d6c21d15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa88bd336d777a533,
+                    0xb32bb980264c01f7,
+                    0xb0887f714c09c706,
+                    0x850d286dcb212eaa,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x93e4cba273401005,
+                    0x7ea5596703f1ddcd,
+                    0x500632b94d36ea32,
+                    0xe9b3446de105fb82,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbed28c698eca5409,
+                    0x242236decb953959,
+                    0xbd8c39236b4699bf,
+                    0xef378c4baa3966a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3ff5c145f0ea90e,
+                    0x754c0e44e9b0e5dd,
+                    0x8d131f3fc91bdf82,
+                    0xfb9aa36091c60542,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc761b3669b8b7cea,
+                    0x6b70044befbb4459,
+                    0x8dc3144249b9346b,
+                    0xf59cae086c674e30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47d5170f56ced58a,
+                    0x9f6fcf09a2123c7e,
+                    0xbb141ffc98c651e3,
+                    0x91d31bb820713f92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef4e091b64287062,
+                    0xe432ea73a605a5f1,
+                    0x4a780f87d0f021dc,
+                    0x194cf739df4f5435,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe363ffcfafc29280,
+                    0x814cb6274f4be68a,
+                    0xd26016b5a5352506,
+                    0x11d1ab3b98fea50e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c768c2e9883521a,
+                    0xb2e2757ca83aee99,
+                    0xfa32eb18baeccf53,
+                    0x14c37318bb32f21e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x282ad1da325641f1,
+                    0xe54ff5fed5cbfe7f,
+                    0xcd94f92f00497e59,
+                    0x74de49bb3ab51300,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf1bffaa15828765c,
+                    0x92d441a1841033d9,
+                    0xe308cbe7b71c284c,
+                    0xcbf31b678d2e2094,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c2282143977030b,
+                    0x3aea3436e16a54f1,
+                    0x670aa4f0eec1a019,
+                    0x25380fa7aa10f6c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2b400a277698c8d,
+                    0x5b1c8c9c1a77240a,
+                    0x79e8410aa9b74cbf,
+                    0xfd608b0058f4f92e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6c861b160c12042a,
+                    0x93c6182cfed55ff0,
+                    0x777f047e7e39a805,
+                    0x12a4a95c7b86e627,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd091bfc2dacd4fc9,
+                    0xf16ceedc2eb06d3d,
+                    0xb5f50a27eb113309,
+                    0x87dcf53caf8b4aa6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf665d4f2d0cf5e4,
+                    0x4ceccf13097ec8ca,
+                    0x9f6217942846c936,
+                    0xd6afd2999fead452,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0829d4412291a4f2,
+                    0xa1ff741b037e1bac,
+                    0x0f1dd245a5ed447b,
+                    0xdecc66da4fa2ce59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x031bc59adf5bbb60,
+                    0x92dc45182a707214,
+                    0x8f4cdcadad44bfdb,
+                    0xb87b15ac38995f70,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ea98d67d006c9d8,
+                    0x34fefd934167af12,
+                    0xf763637c74137ce1,
+                    0xdaaaa1413f7ede84,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x763f90fd7410bbad,
+                    0x3b1a75eed5bb72bc,
+                    0x50e99416f3193ebc,
+                    0xb3a6eef5ef812829,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48ad4ca440d6ebbd,
+                    0x6b534f08700f264f,
+                    0x0451e22676578db9,
+                    0x46f5c2172fff059f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a6ab2d21424d4da,
+                    0x539c2ac5dada82f0,
+                    0xbc8be27eb81b776a,
+                    0x854dca1d1edd4818,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3d91f3318c06c00,
+                    0x54102ebeef8c4f5a,
+                    0x4fa38e195a658e0b,
+                    0x237ef56606965afb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c225efbf8b2b3be,
+                    0xf30c473aeea9150a,
+                    0xf49560ad01a832f5,
+                    0xcc524bdefe99f8fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4aeb6967e6dd3923,
+                    0xee45ceb959b4775e,
+                    0x430949a852201d0a,
+                    0xaa83eb6f712c6d07,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x920401d8bb95a03a,
+                    0x12117a9ab5499c24,
+                    0xf010d556f0b61359,
+                    0x3e213c42044ebdd2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d7a056b69cdbafc,
+                    0xc7e4703f6a3deddd,
+                    0xa263ddd2ac967274,
+                    0x5cd7e4d629de6e5a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d66b6eca7b43856,
+                    0xc3af3a2342ea82f4,
+                    0x9d60eb63298c8377,
+                    0x6cb30f84c05a4ae0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99c7fae0db1c3a98,
+                    0xab84f9e923cccddc,
+                    0xcc248b6ac73a4f01,
+                    0xbf5bb2c7f15ea7dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc62b1e871ebb95b,
+                    0x1aa08231707b49f3,
+                    0xcd4f2b065547b2cc,
+                    0x6a6fe4a081cb95de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb863fa38ad15ba58,
+                    0x10e331c6dcfbd08a,
+                    0x5627081a1ecb3620,
+                    0x8d7e187910b9d37e,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x0c13014ebc1fa1a7,
+                    0x42635790aea34aa1,
+                    0x0b4f7de36bd1f5f3,
+                    0x925cd38e1975b9af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0337fb3d1b4499e,
+                    0xc7fab55b294e56ab,
+                    0x0b8c47fb7fc9c817,
+                    0xfbad3021d312c476,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53d1326c6698530a,
+                    0x18f3637bed42346e,
+                    0xaa7fbf6f9dab0582,
+                    0x5820ca91b045af99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4af5e745aa358a91,
+                    0x92fe3ca320f5c828,
+                    0xd270bdf5b5191b6d,
+                    0x6a05ede9f93ce6f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd53df960a148668,
+                    0xfa7007be53642517,
+                    0x459a29733b77aa87,
+                    0x7e65e961133450b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae1931c77a45206d,
+                    0xf06a784aaa35d74e,
+                    0xe51385cf32ea763d,
+                    0x8c17f138cd4f5631,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x036519b07c22627f,
+                    0x98d3e6ec00698b06,
+                    0x72a0d2fe704ca7b1,
+                    0x7c7cf9dc635d0920,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x617b2360460f8a29,
+                    0x0716dca74b1d2a5d,
+                    0xea4d25f8540bdc08,
+                    0x854dc3446b7ae2ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb466b5caf6114130,
+                    0x284575fc5670f852,
+                    0x71371e81b2ab1c71,
+                    0xd770e3011364b1ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f868c6120e5a83a,
+                    0x2b2e03bd27abab38,
+                    0x30a8b087e513d44d,
+                    0x5b4a1873538b8c17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x042cbf95215c8d25,
+                    0x3f607dc3148317e7,
+                    0x476ea93f79826b9a,
+                    0x1053228e3e3360b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc2b0c2d66e3719c,
+                    0x4d3615d4d7aaad09,
+                    0xac1d95bdca34bd04,
+                    0x70b98f1a690a2f7a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2850ce981be2342d,
+                    0x1fa2b9a4ac6f6569,
+                    0x20d466693e6c1a18,
+                    0xd3d8c3fa67bb67ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddb12d569e79b7cf,
+                    0x1a757e31a1db39dd,
+                    0x0ccf442246d50e1d,
+                    0x0697774cd866217e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2166d6561e92fe4a,
+                    0x9fd5acd30f012179,
+                    0x05ea1adf5e50a57d,
+                    0x6ed5a9ef46f7aeea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x494805805196994d,
+                    0x0a73649465ffd6cf,
+                    0x1eebb510e9d73393,
+                    0x5e6f18a3c9d7b3c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d9e23950751a837,
+                    0xb3d8d178ea1c28c2,
+                    0x2b685dbacd97f442,
+                    0xc0620339ea91f007,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0407835611a54c48,
+                    0x1acf6105c7327aa0,
+                    0x4a583c0b29dde812,
+                    0xa8840a505a93245a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x3ac0a56bbc916e8c,
+                0xec132ba3181f9f09,
+                0xb26a7fd32cc8e0b5,
+                0xbaee0241dcfee0a5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe175671eed0d7ae4,
+                0x8bded663e9ff46a7,
+                0xc781d656306fb915,
+                0x519d19c60f3daf22,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x81a45161f2182f04,
+                0x9542fdfcb65e737b,
+                0x0cd4ec5148a48b6e,
+                0x6a362376fc344b1b,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x5c49a805e34c0877,
+                0x02c96f948b0d9e88,
+                0x2d6348de398300d9,
+                0xdb4de43514f35d6c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xbbb1ef384aeb5d76,
+                0xa09095be76de419e,
+                0x4105373e5b4a329b,
+                0x1338400166b42bd3,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe549b49b803dc414,
+                0x5904437da9e19e01,
+                0xe448444634309419,
+                0x0e54672c44c76152,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x6dad37497b90a4ae,
+                0x8437cbbd439c489a,
+                0x02d82651a4ee37c8,
+                0x4d62ef7321a592a1,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf4db96a0bbbc31f3,
+                0xbb926b051778e0b5,
+                0xaffdf2ee292e26ca,
+                0xb05024f78bfec176,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb863400ae7b0fe76,
+                0xd837113cac4e3e71,
+                0xb9aa57151e12f119,
+                0xeff3ba53f2f09597,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb575fe51da4d6427,
+                0xac74413f57e72988,
+                0xe8211d28e6dde0ac,
+                0xdce859a370254da3,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 155, 156, 470] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 155];
+        let mut opad = vec![0x5cu8; 155];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,1631 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 192 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 5 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 5;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x51944c77ee950a6c,
+                    0x55a39fe7d9f54746,
+                    0x4601ddda8e75e297,
+                    0x7b8cbc3e42cb92ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67dfb10a5f9e0c86,
+                    0x992b9a95cc61fc48,
+                    0x482bc707be1cfcea,
+                    0x2500b9ec677caae5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3835e878fab55dae,
+                    0x3d08637207a788e7,
+                    0xa2960348d0e6fce1,
+                    0xa1d198b334d82f7f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x184a55bf85de9ad2,
+                    0x7459f0333441b060,
+                    0x3c1b2f421b64be41,
+                    0x9175152255b8762e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ebb696b66c786a7,
+                    0xa53f92cedc9dbbfa,
+                    0xd06e914298cd66f8,
+                    0x33b407312e6bd12f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x582d2b95850b991b,
+                    0xfee69e37b87389a1,
+                    0x437d0950af5d31d3,
+                    0xe3a034c40c1053f8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x75097fa15e7b44fe,
+                    0xee6b0ea02df95bd9,
+                    0xeed40cdcfc02af76,
+                    0x8c367ed807845db2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a62b3813c443843,
+                    0x009a39d1d267f5ec,
+                    0x0e46b0e24b1b7fac,
+                    0xefd579379f92ecc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x204eaf458a328b72,
+                    0xed0cf8aa83344439,
+                    0x0b110a6fbf0a3634,
+                    0x8f2fad1ad584d479,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95f05b169ed486c6,
+                    0x0dade9f359731e60,
+                    0xc9127e2a0c7cb503,
+                    0x58ad8dd81000fa62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa66864da4a03bef8,
+                    0xec841e89b95ebaa3,
+                    0xe885d3361a7aa7fd,
+                    0x9a3fe3bf54c11ddb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5185ed4153fee33b,
+                    0xbc687535c9682504,
+                    0xd9d0f8728b7fa6f1,
+                    0x19e569b60ff39151,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa472bb371f446f91,
+                    0x5b626683cb6db250,
+                    0x029e43555a59c1b9,
+                    0x5cbf9a1258d87359,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7efef0a142d06a63,
+                    0x5538215236330516,
+                    0xe317294dd0dd5c68,
+                    0xa0ba8b6a6fb89921,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf66661ec950e4016,
+                    0xa1ee913453eb25a5,
+                    0xc8b6cc532d2bfaf3,
+                    0xca1fa4da1d1b0769,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77f48560bd7218fb,
+                    0xc70f4cf549a222c1,
+                    0x85583941c0b82fe1,
+                    0x36b07c9e33da9aca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16a3333dd3a2ed67,
+                    0xb7ec3db7b074640f,
+                    0xe3d2e04bdb9fe1d7,
+                    0xed28146cd41fd7bd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x710f8c982efcfc21,
+                    0xe8d106c86345b5f0,
+                    0xd77c030e63396137,
+                    0x5d1161ed13824ff4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9cc67eb2427469b9,
+                    0x622f8db8e292eeb7,
+                    0x54d8ec4da0ce9882,
+                    0xaaeb0fe7a80a2451,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2ab46358dad3862,
+                    0xce22a4b5f8ed7846,
+                    0xd17aa11fc2c1ca62,
+                    0xa11a84d48b8e8995,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44e2583f4ad1fd8a,
+                    0x7c538d0baae6c0aa,
+                    0x5a700825418b0078,
+                    0xbb439e2409708c6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec488acacb0d6456,
+                    0x701e8f6294fd5f55,
+                    0x16b719d4617179a8,
+                    0xe4a20bc4bcec7508,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8174af11536cbba7,
+                    0x40ff991a143b6011,
+                    0xff13a212525f902c,
+                    0x94959b770948dcfe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5726537713248a3f,
+                    0xa581bf42408582a7,
+                    0x952f73c43d9d115d,
+                    0x7187f3832f8a747c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc90e9f6964c474c4,
+                    0xe9b45a74b6188877,
+                    0xf4c1b98d914b795e,
+                    0x1545f8fcf5836a9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe99f0ed2f3969c24,
+                    0x24edd942e3b2d195,
+                    0x547fabf80094942c,
+                    0xcc5ae40be02ba0ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6144a7e94fd5477,
+                    0x800b9d04ee9d6a8b,
+                    0xf96d28946eb76487,
+                    0xfdc32b5220c42040,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6482dc33dca9b1ad,
+                    0x1a1a0790d04c4cd5,
+                    0x555dee2b2dd17a56,
+                    0x709d23c43655394b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d610c186825554e,
+                    0x7c366d55294471fa,
+                    0xc88bd11388aec9cf,
+                    0x82efbd422b2199d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6bddfab1ab5a35fc,
+                    0x5ef8f07abc4a9b59,
+                    0xe523ba22791da7fd,
+                    0x7623165643f72a85,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x739afc9e1d89083d,
+                    0x05620bf0a501dab7,
+                    0xa847c9b2778f7e58,
+                    0xbf834399e3d2251c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1038709f13732845,
+                    0x2bedc10565bf2292,
+                    0x6915f13e1f2f6450,
+                    0xd511be1a4a22c566,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda0f89a1a688dc8f,
+                    0xe6ffcf3af0a9dd1a,
+                    0xab7320e681bcaf67,
+                    0xba58db1d6a2a4985,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x935fe1062cb1df78,
+                    0xd6e5b37717ef06ec,
+                    0x651579448fa98153,
+                    0xf16758f2d5f422ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f164f8e18361eca,
+                    0xebc58cd9fe887e75,
+                    0x578f0a2b52f23d7c,
+                    0x4f76e8bf6e42f6d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14a5cccddb2f010c,
+                    0xcd242025d81a7e15,
+                    0x6796f4d06d58ddc1,
+                    0x4d8b6624435c809c,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbb43d6e4b6a344d6,
+                    0x368642d38e4c13fb,
+                    0xbb8f4baa16b460f6,
+                    0x59cc3953eb375806,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5fac6aa1835e40a,
+                    0x5cfbb7788b14f18a,
+                    0x257fda7c40ac683a,
+                    0xa37739c5078f866a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc38d09b351be814d,
+                    0x5372a5e4594295f3,
+                    0xa5b15eedf1fcec75,
+                    0x971967718103e608,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdeeabbf8c8f4c1ca,
+                    0xd658023e373a3f06,
+                    0x4986ecf2fc5ff564,
+                    0x79beacbaa24751ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96f01764fd2fc373,
+                    0x0bd2aba8a6628ba0,
+                    0x6c2c02ba46ffa633,
+                    0xb933d10ba093762d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe613c0d4c6315f44,
+                    0xa85ea0cd6614b71b,
+                    0x8e4f02a33851fe08,
+                    0xf97fa25769e6959c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf18d1da1f660277f,
+                    0x828b2a4f877e79f5,
+                    0x139b02171c62bf80,
+                    0xceee1c492662a09d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4e189cf2f1325fd,
+                    0x9b31bfea690265c3,
+                    0x60f781c1318351f5,
+                    0x09b2106562ba82b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d2b9e88bae22a91,
+                    0x5ffa0088a0f75ae6,
+                    0x3673f2253bb45de6,
+                    0x9664dbcfacdaf20f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8a072fa73153f87,
+                    0xa63faaf2439ef28a,
+                    0x5f68f8057ea9930c,
+                    0x668e13d9d0482795,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7848d0d900b607a3,
+                    0x20649646334d3d13,
+                    0xf9dc41990d38c745,
+                    0x2c32b76df266f4a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73a168c551f9950a,
+                    0xb65881d27c5a92bb,
+                    0x196850325cd4ab2e,
+                    0x0afc0712e45086fe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfe90b32c363f5ba4,
+                    0x2cce7596d0e41519,
+                    0xce2ff7ac177f9250,
+                    0xc767c2a79fdc70ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3802f12831c0f60,
+                    0x35a5124f0ab7967a,
+                    0xfacd81d20cb91ec3,
+                    0x1b64a3bc634c6bae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x387b44c73b583edb,
+                    0x8cbc105b47410dae,
+                    0x4e9388f519d5a277,
+                    0x5f45c06b9bea3388,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95d91bc5ade24c7e,
+                    0x8645b725ea8efcba,
+                    0x0272446bc6953024,
+                    0xbd1e24532159764f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ac68a3cb88df09d,
+                    0x4972f0862e917ced,
+                    0x8cc8a4f946420c58,
+                    0xd299dfd6a0d37c54,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e93dd75799cf119,
+                    0x7f2693a5a40b0eb4,
+                    0x0064be2485434c7a,
+                    0x968bc958a6da0ad4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf1562a49ff13bbef,
+                    0x6d9b9e49736c2d0a,
+                    0xedabba1fc31f17c0,
+                    0xc27fb2524527a741,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5134d3ca7f696ad9,
+                    0x92ae562ffaf65482,
+                    0x0c14fccb55260628,
+                    0xd744bc86a7036d54,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8858cba0762e19aa,
+                    0x789580bc108fc59c,
+                    0x07f06fc61619d988,
+                    0x76277678cdc66741,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x172aeda869444a34,
+                    0x12354623b28ede0e,
+                    0x13620c2405a83c6a,
+                    0xd3ee32161cad77ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48ddb21393b3e599,
+                    0x5747c7dbe097328b,
+                    0x4efaa725c66f7e5c,
+                    0x51656bfce553f61d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f00291f3c16e2ac,
+                    0x677007a22dc2f83c,
+                    0x39ccb46925fdbff4,
+                    0x9bd1e339465b20f7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb9391f16907e29b8,
+                    0xd2fa8087ef4e6b81,
+                    0xd3867c6f9c784cec,
+                    0xb829a01d589737c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc27d51b3200dcf3a,
+                    0x64ad7afbbdf97321,
+                    0xc65ce630e09b9623,
+                    0x9777c60d989b5ad7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d6125a5825d52e5,
+                    0x0e540deb8cd779fe,
+                    0xb5cf5510fd31dd95,
+                    0xd645029a93039469,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4a60a397d1cd91a,
+                    0xcaded4a924dd6fd3,
+                    0xbb0ae40566a60a1f,
+                    0xe4b392d4b2d40571,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7eaf0ea05d5100e,
+                    0x69107bf4801757d4,
+                    0x39defc56ea003fcf,
+                    0xe7629bb07a2ab0a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40ab21530be42be1,
+                    0x422b145a0ae08095,
+                    0x7cecfb31ec954509,
+                    0xdfc0119d74370922,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5127cc44258d6416,
+                    0xce083ef1793b5bbd,
+                    0x82cc448f055df099,
+                    0xdea7d35f80d45818,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c739f9984375fcd,
+                    0xb181566798efc757,
+                    0xd34784fef8b45a83,
+                    0xde3aaa4373c2574f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18cc80b49ca08053,
+                    0x416998ed653d51c5,
+                    0x8e0501b5aacfd6aa,
+                    0xa7ac77df6096051a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c98b298aa132fac,
+                    0x5a5c054a9b17ea7d,
+                    0xa6e72a292140fdd6,
+                    0x070a15e6b78dca14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x036d35b29e18422f,
+                    0xae49973226a0c4ea,
+                    0x6cc88eed1626f3b8,
+                    0xf165690088051db9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29dfdca868e64704,
+                    0x398302db69734157,
+                    0xa6363a7a8ab58b02,
+                    0x8ab42d58dada8ded,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x038037cd929148ce,
+                    0xef027a13c13bdcea,
+                    0x3b2f0a89a2b732b7,
+                    0x5169b0777c24598d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c3c65e563fa831e,
+                    0x4ea7b9bea62def47,
+                    0xea61365e7264e1e7,
+                    0x8dbf696dc6d81ced,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e29c3577176518d,
+                    0x07610c1a96a200b1,
+                    0xf6ddac2dc974323b,
+                    0x8365a6b9c0c11532,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfddfc1b38800970c,
+                    0x3205bac00a2627f6,
+                    0xf861fcf9ffe0c712,
+                    0x610a78f7d4b45934,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e04bae2ba7bcfc1,
+                    0xbf7e3bd529183173,
+                    0x4af6d600efbb4dfe,
+                    0xa7291658ed8ecd4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5d5b490f163a0d4,
+                    0x59acd5d8e1ac5be4,
+                    0xfcc6880ab2c6cde0,
+                    0x801460ca7851f3c1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x756f9a2130b398db,
+                    0x709935fc54a48b4d,
+                    0xf21e013019110e33,
+                    0x83dfcf2cc61b2ba1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f0cdd3286ceac03,
+                    0x2875b78cffa19c1e,
+                    0x7ec0ad6ac7f7f4a7,
+                    0x94cf2c3328f095a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88d0adb9c0dcbe08,
+                    0x013906ed94f08f54,
+                    0xafc003dfb8a4f8ac,
+                    0x8817818c5ea97830,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x606268429a05e661,
+                    0xc5715066bb1e860d,
+                    0x4a0228efad841f08,
+                    0x07fa0c04868dafe5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48bb5938ce5726e0,
+                    0x6b22e83f6471eda4,
+                    0x2769967f7ffc5b0f,
+                    0x4f7a688cf90eae42,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3ff15b0cf95c3d6,
+                    0x6b380f34c5b9b0b3,
+                    0x5053755082e4a08a,
+                    0x45a6053dd061c909,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0a377cf2f0faa7cd,
+                    0x6c7f2290d153e671,
+                    0xe6c9f5f0d0c21c6b,
+                    0x4edb5bed4e94c2fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x851655f1938a139e,
+                    0x98ccda889a885956,
+                    0xe799dbbed0901699,
+                    0xd661842726bf2ecb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3e61b3a0d27d30d,
+                    0x7666ac060058537d,
+                    0xda8731be26a9e094,
+                    0x3c00423dabf93fc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e1df05a7634f424,
+                    0x2a1928e3cc6950f8,
+                    0x0802a9ae222f8ccd,
+                    0x814a3ed4fa034490,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2ba09f14d39da52,
+                    0x2a6a1cbc12dac98a,
+                    0x3fb86f3be10aefe6,
+                    0xbedc48bd29a1c501,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff3932d8e9b0a0c6,
+                    0x620e3eeff2d7fe32,
+                    0x9294b2c16cd6c0c7,
+                    0x612e622c85875a92,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0c64c258b3ffb693,
+                    0x40b2b138fb96417e,
+                    0xa2c8ea3c65a47f30,
+                    0x4ee34052429d72b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c27f0b1863c9bf2,
+                    0x77b77e6807e1793c,
+                    0x22ce06dba3f2d5ed,
+                    0x0076caf219f32edc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8582d9347a9bfa48,
+                    0xf2965c47dcc65f65,
+                    0x0b85ebaa80995fd7,
+                    0x661eb8874438fc59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x109e5a80d450d67e,
+                    0x09f4c82b55872224,
+                    0x200ce9059bda43b0,
+                    0xc44feaf2cd3c70f5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x210b3029ede7fedc,
+                    0xe2cb0526174d05a0,
+                    0xe4955c4bd06d572c,
+                    0xd476dbd7c78d0409,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99ff30baf885507a,
+                    0xcf080fe174b6ce3c,
+                    0xd1ad4d2cd0d19913,
+                    0xbc6369b26f631172,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x17c8322fd085e0f6,
+                    0xe320b058b7e68357,
+                    0xbe0a19b09deba9e8,
+                    0x07d0b490c15e06fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2400dddbb7beccb4,
+                    0xf398f52e47f1d5fc,
+                    0xd6c2b97fc0244724,
+                    0xf03effc9b39cd594,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda4d76c5a90b02f2,
+                    0x4dbccf19ca8c0580,
+                    0x2412f5befeaf49b8,
+                    0x248c45e0fb010c7f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc38541a52d48723,
+                    0xb3087afa6bd906db,
+                    0x4da22b0f9a3cb226,
+                    0x7c7d89cd7d4bcd0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9571443625116201,
+                    0x1afe900c28d9d67a,
+                    0x91e8b70f5ce465b2,
+                    0xdf88aa3994b6f630,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38a28bc1961b430d,
+                    0xf8d714fc961de621,
+                    0x353143c9ba749114,
+                    0x7d9203da44374d30,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe2298162e6bba05b,
+                    0x672f43dfdd815340,
+                    0xfa5e274443c8dc89,
+                    0x6224036558365944,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47e22f1672f4ea31,
+                    0xf731d27ff9e96286,
+                    0x8e24532b890d62c3,
+                    0xf05bfd91a864f0ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf737d1c4ab59d2a9,
+                    0xa3657cf42de8af79,
+                    0xb29a64a0dee00b40,
+                    0xf748a49843ac969c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5575f4f02a3c2a64,
+                    0x0edb9853f9c41642,
+                    0xab2eabed452476e1,
+                    0xdec8daeba1741b28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19a55484f5fe4239,
+                    0x6cec099cdd7331d7,
+                    0x360d58b13db050e5,
+                    0x3842cc26b392c209,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4812689e2fcf1ff5,
+                    0xe40a45afaca3af68,
+                    0x5d165c9605755d64,
+                    0x982aa7563242327e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000900002259,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000900002259,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000900002259,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000900002259,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000500001315,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x92e4527c7418f7fd,
+                    0x2835d27805a565b4,
+                    0xb294113c231275b3,
+                    0xe0fcc632ba6dd7ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a39e7bb4aeec835,
+                    0x53cd58222b4fce45,
+                    0x0a2945717178ab50,
+                    0x695a0e12853fb614,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4e6d0a78d24b6b7,
+                    0x23103414808386b6,
+                    0x92cc022923feac4a,
+                    0x0562218dfc063ec8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1567a6edfb554b4c,
+                    0x1fbcb02daea5c18e,
+                    0x0a29d3f671372224,
+                    0xa0e29e0d815e8dc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d79b49043e13d5b,
+                    0xaf3c41ee2937053d,
+                    0x30e354f9a2247115,
+                    0x4a5ce20ae3fdccad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdc3a6eea8d7aec5,
+                    0xca2d4418671b1835,
+                    0xd60d070781d97eee,
+                    0xd69a1ccd15b2b4db,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x180dfca24d1016d3,
+                    0xefbeab34a5f606a6,
+                    0x0638c1aa0ed01024,
+                    0xc4ac6f17bc0324ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2eee8082fa188698,
+                    0x9cba02521317d569,
+                    0x801eba3a0af7ecde,
+                    0xb0f580050b0829d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x082c5951d9c63c3e,
+                    0x7b7a606f927fcd5b,
+                    0x04dab4dacbd017ed,
+                    0xa8861b45d9eb90bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88761b9f7109fcab,
+                    0x2d88f1cd43fb7f7a,
+                    0x1602730d38e688eb,
+                    0xa377d783ced341a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4de1a7b16bfe9dd9,
+                    0x26aee8a85b873080,
+                    0x55c3869f0b6d58b4,
+                    0xad0e91d85d1986fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e93899ed4a122a8,
+                    0x72121859ae2dffae,
+                    0x109261beb05dd0c1,
+                    0x3c0f4ae9421b83b5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7d8cb6a88c140a79,
+                    0x246414e3e1394112,
+                    0xecde7a7aae400a99,
+                    0x01cffcd310f739da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b002493abfc3863,
+                    0x6880012b6ce53a41,
+                    0x5df966ea154fd4c2,
+                    0xd93da6cd793fb11f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97a2e80573a1e1e3,
+                    0x54b0ee410edc6027,
+                    0x767eef4f69804c70,
+                    0xfef3da2cbe76b787,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2991c0cf76bedb3,
+                    0xb41b556f6fe8683e,
+                    0xb181166979c0b394,
+                    0x61c88075141b80d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c4d0ddb99895f26,
+                    0x0ac3d6576aeebaa6,
+                    0xea551f07b4705715,
+                    0xa3e967689d32ed43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb585410a47932f53,
+                    0x0b65be07f7d1abcb,
+                    0x59c0aa2332271d47,
+                    0x1f0d7b38fc6fee26,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x93c65ee840ab6700,
+                    0x44dbb46b6953fcca,
+                    0xdc095e773cea520e,
+                    0xd47eae58354ceb02,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9153ea8aa10452e,
+                    0x902d447899cad8ea,
+                    0xe828b4178b6374a5,
+                    0x81b634da293b9697,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6d910f11cd7bdc4,
+                    0x2c65ee5ad76a9d1b,
+                    0x16aeb13b5579b356,
+                    0x533f447026048347,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60a55b6d0168c099,
+                    0x8207452728076d4d,
+                    0xe436627281f00487,
+                    0x1f7e84dcc288e56e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xece5706a635fde43,
+                    0xcfb16d898c993a48,
+                    0x1669648974cdbb50,
+                    0xddca06629ea5685c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0dbf1bbc968e2eff,
+                    0x815de77f10250141,
+                    0x9f7a4b15ddf3e24a,
+                    0xfe17e82c93468e85,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xec5781b29e026025,
+                    0xb70ef2f851bbf95b,
+                    0x5f36f73e329015a9,
+                    0xa1b2e990a519237c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcab1745d8365ba51,
+                    0xbff012d45f7c69d5,
+                    0x0105b46d5a1dce36,
+                    0x65b485fd65fe020d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45a6eb31d25b7659,
+                    0xeab7d5523a316582,
+                    0x34f3fc5098968e97,
+                    0x2c3d635cf2b7f704,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f48ed0875b2fab2,
+                    0x30f34116e8e95b7b,
+                    0xb35ccc4596f8f078,
+                    0xedaad3f68c425a27,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2bccc48b3aa4219,
+                    0x2cef15f80282d12f,
+                    0xb0007c07e60f0d3b,
+                    0xd2934b7c50d1aa88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf52eac815823fba6,
+                    0xfcdb401b7c84706c,
+                    0x388d94076b5e7451,
+                    0x98a98c547fa7fccf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xad677bbabb4acd27,
+                    0x2184d5d027916d71,
+                    0x420682c013b101ec,
+                    0x560e9d8817f0b073,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fb325cb5bbe11cb,
+                    0x48918d3c6124c435,
+                    0xa051a8526976610f,
+                    0x3859eebfcba10e07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5968506d281a95e,
+                    0xd0251b13c0560bc2,
+                    0x2fd92d99334803a0,
+                    0x0e10ac59f4b49358,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x047f8e76b0b8e1ad,
+                    0xd328cd730980cfce,
+                    0xe2066f1010c87c7e,
+                    0xf459439f865da745,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62199bf6a481eccd,
+                    0x7d89184877632406,
+                    0x94afb90f129312ed,
+                    0x6cc8043fca314601,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea785b1f3c0618a6,
+                    0x39a9fada3ec15b2f,
+                    0xaa6d129703aac6f6,
+                    0x1fbbe6b5ee6e93c2,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
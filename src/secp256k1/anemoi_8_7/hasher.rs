@@ -0,0 +1,2421 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U7>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0xb16a00ce060c5c8e,
+                0xa08d21e69f3fa06c,
+                0x851be98390f4d115,
+                0xf25fdb0fbf38ac96,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x53cd3700c6c9d8cc,
+                    0x56b7ae5e582b594f,
+                    0xcb29b87bbb07f08f,
+                    0x61a603378304f725,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd31c382d88c8791d,
+                    0xd2aedb1139cd44cb,
+                    0x91bb06fb88ba5241,
+                    0x0e206720bf09975a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6d6f517c8fd99f32,
+                    0x6c92d33105513397,
+                    0x66500d5881acf97b,
+                    0x511a9b0621a1bb60,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94dfcfc445af9090,
+                    0xe243ed313ff42d47,
+                    0xfa522af51b7fc749,
+                    0xb117c4bbb0c83587,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc45819da94707515,
+                    0xca889a7392189be4,
+                    0x59821a6b524f5862,
+                    0xe66bc614f2f0fffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2d16f7a59cd44008,
+                    0x64e07acdd4f4f673,
+                    0x6b8fe55e383396fc,
+                    0x6a0987e069cac027,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd44b729b480597d4,
+                    0x66fc3f1305fc3809,
+                    0x9208c7bb3c8921da,
+                    0x6a6383e3226bed20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34fb04b369a0064d,
+                    0x08de8121f0b8b436,
+                    0xcae189d8040921ce,
+                    0xc170bd3b50588a0f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5e157dece9c06df,
+                    0x9c6c85f3e69a348f,
+                    0x419c6bcb3f9c29dd,
+                    0x7f9ae20076f7c6a0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe9a7ae07cdb58e71,
+                    0xd53cde41ed24aea3,
+                    0x382d4d8c75323295,
+                    0xb95f7bf462c2fd36,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15937986d872ffa7,
+                    0x025ba29a1688277a,
+                    0xdac8a8bbce31cdb0,
+                    0x79f5ed0c038eac22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f1b98ebeadc7590,
+                    0xc6e7169d6224fea4,
+                    0xa8b1df0e0d5f4b90,
+                    0xd1ecccf34ef85f47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18d8097e6a083ece,
+                    0x9586ce611ff9cc5c,
+                    0xd381cb28ea380caa,
+                    0x6ab6380afedd9d64,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76eb8fb1b7f374c6,
+                    0x297a9a808c3de3e7,
+                    0xd91c80b79c15f270,
+                    0xe2a4fca8fd2a08e8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x52836fe415ee3b58,
+                    0xbe668a5f532845d4,
+                    0xcb7b734c3dcf48f2,
+                    0x6221fdac53f1ee92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77073288c72e31d3,
+                    0xd68e94d4e8ecd5ea,
+                    0x5832273b774223b0,
+                    0x5e03c1887ec44899,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2937c52a95f485a8,
+                    0xec9f9fa7ce67c9b2,
+                    0x71342a0269f66a8c,
+                    0x66d83fa71203c007,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc386fa27e99e2ee,
+                    0x63d558862e941f92,
+                    0x0ebd47c00d8c1c0c,
+                    0xfcd270bc7fef632e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98d89138cd2795da,
+                    0x311c2a80482c1465,
+                    0xb12422075d61d796,
+                    0x9b60d1b7ee2d8e9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd6c11ccc9900044,
+                    0x10fbcc1d4d3819cb,
+                    0x7cdeacdb1723ee31,
+                    0x291a0090dd3cd495,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x943a52ce20fa3a55,
+                0x27d3f650c6218cec,
+                0x05d11056507ef95a,
+                0xada81e79e053c975,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x757c89ef668dd678,
+                0x2fd5c684d6e9edd8,
+                0x932fa8bd902d44d9,
+                0x936527c815e49436,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xbae5d96fc34573b9,
+                0x9a1d3a2ff80b0a74,
+                0x0b12d05cd74349e9,
+                0xe4f1a3017b905c6a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3d27bd5808489644,
+                0xbb884c71a71418dd,
+                0x213210d7ae858ce0,
+                0xaf5f309a47b4b0fe,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3028340e14289f3c,
+                0x14bb5cf34823395a,
+                0xf16216b0d1f02151,
+                0xbfa537a461651e6f,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1cf6de520419f621,
+                0x2be97c688149f279,
+                0x5d249130fced49fd,
+                0xba9d9be61599693a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x0aaf9bfb85041585,
+                0xa7188511199e0b62,
+                0xf21eae6daf445724,
+                0xa4f4600851f13a08,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x148a14b35779682d,
+                0x2c2a8b7df53c53b6,
+                0x18f1a42ff7494a08,
+                0x4214e1cea193708c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x79b473fa5142673a,
+                0xed2156d50bb40640,
+                0x641ad327503e1c8a,
+                0x8531497d1e1dd53e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x795acf718278d4a1,
+                0x146f86483be8b195,
+                0x214e9e431c10bf98,
+                0x8c73e706171a8783,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x943a52ce20fa3a55,
+                0x27d3f650c6218cec,
+                0x05d11056507ef95a,
+                0xada81e79e053c975,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x757c89ef668dd678,
+                0x2fd5c684d6e9edd8,
+                0x932fa8bd902d44d9,
+                0x936527c815e49436,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xbae5d96fc34573b9,
+                0x9a1d3a2ff80b0a74,
+                0x0b12d05cd74349e9,
+                0xe4f1a3017b905c6a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3d27bd5808489644,
+                0xbb884c71a71418dd,
+                0x213210d7ae858ce0,
+                0xaf5f309a47b4b0fe,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 248];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x753eefc3760fe45e,
+                    0x5cb23ed0665a4240,
+                    0xb61434744e0b20b6,
+                    0x95758407009b17c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4062b83bf19eefb2,
+                    0x4e53191936fb5fea,
+                    0x0f9d20d305ee64a3,
+                    0x088b1aaf7e80d51f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2e01ec575709bd9,
+                    0x09f68a2f047d3179,
+                    0xc05299c0bab1fff4,
+                    0xa5c680421ba1cb9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35715f1244ae8307,
+                    0x89b840b71f2c5e7c,
+                    0x773b5c69dca1abf5,
+                    0x055693aa1ce65f9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26230e710821923e,
+                    0x5ae102ecae47dc5a,
+                    0x15c094fea5d81ef5,
+                    0x2be97f0ed66d04aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd98e0095216b2c7f,
+                    0x86891fc49559f707,
+                    0x8264573597316bcd,
+                    0x308477363bcf18d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53331fdffc042d9f,
+                    0x31b13b9cd88c0c66,
+                    0x7bd1e0bd0ceb3d4b,
+                    0x567e1c2c60406c57,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e26fba81e50ea1f,
+                    0x97e2ba542eb65957,
+                    0x32697e221e2c9516,
+                    0x6b2cec0d69c179ee,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6e6f7c2a88bc7801,
+                    0xbf258813b1bd9030,
+                    0xd30d7a42903b0717,
+                    0x847ad7b0c472dac2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfebcade669b43152,
+                    0xaf88659b5009ae14,
+                    0x22e185695996719e,
+                    0x3b99fc1fa42bd7b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e06f3e4777c27a0,
+                    0x857cd05c1ced17db,
+                    0x5f7fa01036bf25bd,
+                    0x57fa8a35b2a04ae5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x626705a8c6bcddc1,
+                    0x97f1cfd835c2ffed,
+                    0xb063372107586ab0,
+                    0xbe4d9fcc78766024,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2735a3862dfc6ce7,
+                    0x6f14145d6f26cfb9,
+                    0x3f1251f43601d28e,
+                    0x084b2c0222ed729c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe30ab73cfc3f30be,
+                    0x5c27962c56835464,
+                    0xc25f7a0491ee34a6,
+                    0xbb9cad2757ec515c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9897e917aaf6e46,
+                    0x321a7b4c84bf8c8a,
+                    0x8c6ba7e96c70ff28,
+                    0x30029db6c399bb5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6807d23b648cd7f,
+                    0x3773c3af69ccc6b2,
+                    0xbe1367acc45d60df,
+                    0xf5c82a63b1655a1d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6a2dfd31f6285a8b,
+                    0x0c893939a08c82c5,
+                    0x1f43fce1c5aa4d2f,
+                    0x451b45840a6d8e2d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x615af044bbeabb8b,
+                    0xbae0d153b20af87f,
+                    0x672306db113e4238,
+                    0xe4b494be3f6edb06,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdeddd397e889b159,
+                    0xef1778033353fa40,
+                    0x8cfcd2d9f50f0082,
+                    0x92550667e987c17f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fa6bb7442967cd5,
+                    0xf63d9ae8b0788faf,
+                    0xca34c54ede36cff1,
+                    0xb4561bdd602e104c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c0323153203e80f,
+                    0x62aff038ff68fb06,
+                    0x0bdfb58bd21ea253,
+                    0x67630297554d379d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab9f1181fc90524a,
+                    0x1b495a18d44c766d,
+                    0x0951cb60b644f2fc,
+                    0x45ef10b3175a3c78,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e94b79f464fcd07,
+                    0x454f14588b9a2ca3,
+                    0x0701cbf96c466233,
+                    0xc40ceb09bd43ff43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40556bfc1a5fecfb,
+                    0x423d37ff85b72582,
+                    0xb63c07cdcdb725af,
+                    0x2c4b534ea7d90249,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7237247b8a937d94,
+                    0xa730ff194f5f80ab,
+                    0x5bc991589f231feb,
+                    0x7334569d632357d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4de2c326f4c6808,
+                    0x67454f1800aa45c8,
+                    0x4e2bd42c38ed99d5,
+                    0xfbf76d64e36c3a7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2e249e9d1cfa1db,
+                    0xc0288666e55b5e98,
+                    0x7bba85caf7ba7962,
+                    0x32094a8036f2c6e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96cdd7f90d3145ee,
+                    0xd93c3380316d558c,
+                    0xc1abf8e96a046e58,
+                    0x9bc97798e826efb1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x250b8195f16cf2df,
+                    0xb74abb2b61fdf9d1,
+                    0x13fad01675886301,
+                    0x73a5c8311025ce50,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x882a87d23a7dedcf,
+                    0xe37bc7b3b54213ce,
+                    0xb474eb52dbcc5418,
+                    0x0aa476cbd447a6ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedbc0c64479ca04a,
+                    0xfe213f0c8d785c32,
+                    0x6c77fb9107817f26,
+                    0x0a18707ab7b5d117,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3406a011257c5fd,
+                    0x95b7327042c3f247,
+                    0x3b469d1f40122c33,
+                    0x22cb6059ed044da0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x255026d5677310cc,
+                    0x86f171fcbf147a6d,
+                    0x181393152e832abf,
+                    0x5691fc55d37a4fee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c7a1ca61f945332,
+                    0xc7b25583495faa3c,
+                    0x0a6a3f8c654a7302,
+                    0xb0fb96f88f41c441,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04279c7b45b803f6,
+                    0x7cda507eb5714feb,
+                    0x3099b9559e1391a1,
+                    0x400b8430c1807743,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa45f58849202a846,
+                    0xacee7b96c4af5c79,
+                    0x477775577346ca41,
+                    0x597abb58ffbfa74f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4837187e94326021,
+                    0x43d79b371312dfa2,
+                    0x6f9ef831d1dabe4c,
+                    0x79e110f7e14db8ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbffb83328896532,
+                    0x5a737d99208ff5f1,
+                    0x77b3f87dad226c29,
+                    0xba2185c82f1996bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2ff43630d01af2e,
+                    0x90a16c8fd43fb555,
+                    0xbc5d34d7c26d019b,
+                    0x3eb0672d6a6687b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0919004a9c50c227,
+                    0xdcb9b28ac31ba862,
+                    0x7bb2bae22d2f3002,
+                    0xff8e59449892eaaa,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xffe105eb4657c980,
+                    0x74d80cfecf6ee51f,
+                    0x3da8b1530b0d35f2,
+                    0xb73178d6c361f37c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4cbf12f658515d6,
+                    0x4bcbc17e143519e5,
+                    0xd44019f13e9fd737,
+                    0xfb6e4ad2ef8785c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa257ae3600ab1e16,
+                    0x31a13c7ee2e2a702,
+                    0x2a0067e467f9520f,
+                    0x19702357347df57f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x951d6ba4c82f6951,
+                    0xa86d8976c0d3ea48,
+                    0x558cebbe3efcbc05,
+                    0xf319e6f187f93cc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77c996b795b5e476,
+                    0xcc76144a6a6ef1ff,
+                    0x47a4c78d1d717ee7,
+                    0x1539514ef3a93b8a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7f5bfd6d47d4579,
+                    0x282a97d2b932fb55,
+                    0x9bce50409678beb3,
+                    0x1d9ed0a9607270d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3d8504282e4013d,
+                    0x2e423fca48c25ec9,
+                    0x36683bfe2753c643,
+                    0xb75b7f7dfa73b986,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x944a37d20c3d415d,
+                    0x36db62ba40174a24,
+                    0xf217b4dc36097c2c,
+                    0x4bdf42c5b5fdd9d9,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x7fd0865fa8136692,
+                    0xbb79e3b211b08309,
+                    0xf8268450edb6b97c,
+                    0xc40f753b60fff875,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f52d55b3ec8df7a,
+                    0xa1467eeb4d80fe98,
+                    0xbdc48cd85de79d80,
+                    0x30bdafc13f04f3e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf19266fc1c5593ef,
+                    0x9f1d150c6ceb1236,
+                    0xa1808d7354cdb5aa,
+                    0xfb0266c3e8d0f62e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb789d915781e3d40,
+                    0xe78f5a8862360c16,
+                    0xe50fe3ec30609f35,
+                    0x28709bfde1de824d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3f822488ff6af0a3,
+                    0xe32cc33f9ec56555,
+                    0x75506b85bd441dc8,
+                    0xda191583398ce7a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe8cb9ade81b5ed1,
+                    0x6203d068c42ff1cd,
+                    0x54924de82da3b869,
+                    0x4b437fa0089b3b5a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd9ebed04ff4da21,
+                    0x9e4439e594387e84,
+                    0xe8a82b8348626884,
+                    0x4052d29b1e1452be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb54b22338d186161,
+                    0x93c2944c8104f874,
+                    0xee888ce5935deb04,
+                    0xe6ad09c9d6440d34,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x39bc58567acaeacb,
+                    0x973b76420cb6a0a6,
+                    0xab13d354b538dd59,
+                    0xad004d236d2206d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d34bdc63186ec0d,
+                    0xe1fcb2af36e58449,
+                    0x189c14c9fa0221fc,
+                    0xfc9af77ad731a8f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a7f70557ba55ad7,
+                    0x3a785561f38fe171,
+                    0x407fb6f7d936a1a0,
+                    0x76a7dd0d1675a5be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaeef863eb5177dbb,
+                    0x0b010c8f1f427c90,
+                    0x430f289eafc8420d,
+                    0x1ad44b57962313e4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8120439406961117,
+                    0xe711de876f2ab573,
+                    0x5b80bfd456c9e4f8,
+                    0x3288c0e3264f5533,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x938dfe5914ca14c0,
+                    0xb451ea41db52a8d3,
+                    0x1e5775d30df3697d,
+                    0x38e26bed3ccef4cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19ed91e78218ea06,
+                    0xe13d04087d3e34ac,
+                    0x7d3a1020d6a4bd0a,
+                    0x3efb20edc8c87b28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36d43ce41ea3dc78,
+                    0x06d6153e4ddc580e,
+                    0xff120c533a1ef548,
+                    0xa4dfd889af8dee22,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb28a3ac787432c59,
+                    0x9ede839fd72c1aad,
+                    0xe8fa8bcc43425b1c,
+                    0x9294b87a1c919383,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2b1dc932077c9ac,
+                    0x09cc6c6bb7911362,
+                    0x68541daada27fced,
+                    0xfc7d0e2d567bae34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45b3c741f0253bf0,
+                    0x1f63a53bfe529de2,
+                    0x9d414715a22e7709,
+                    0x0503849e76b800a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cd2c9d490a53ba4,
+                    0x381eec7479956212,
+                    0x76093b26a70ad795,
+                    0xdd5aa1f0ccb315ad,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1e43a78b4767780c,
+                    0xd7363ae60997dbf3,
+                    0xc089d4d4c882ffb5,
+                    0xfa63d91dd767ebdb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa95f163865b4bb8e,
+                    0xe6ad6c4903122750,
+                    0xd442ad13f035fb8a,
+                    0xa396de12cde6e43e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ecdf248ed477ea6,
+                    0x5d2a56b32720147a,
+                    0xeaf20814e3201d86,
+                    0xe09b004999db4cd9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c40f600fae06ed0,
+                    0xab8e641c6527b68c,
+                    0x02e5ec4b5e0a6b8a,
+                    0x209bc98e9bc12515,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x143dec8bd784f884,
+                    0xc22eef8b441701ce,
+                    0x9e52670d1c262a95,
+                    0x7742ec5576eeb36e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc05c25bcb2b15ee6,
+                    0xd66b4db14dcf0542,
+                    0xb047397e69eab93d,
+                    0xfd0a007cc4b57197,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22f92eb3c84a5757,
+                    0x9c449076b1ff6104,
+                    0xf4080023542561e2,
+                    0x147539a9d8cec845,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3d88a0b3f089c13,
+                    0xd79544ab8fffd7a4,
+                    0x4c13efe6306f2350,
+                    0xa9683f0b6f58f551,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xab381c95037afe75,
+                    0xa4ae0b93810983b7,
+                    0x0775ac10aa91517f,
+                    0xd7872e886351b1b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c52915105c49c46,
+                    0xbf0549af6ae630d0,
+                    0xbb66d8f938cce3a9,
+                    0xbf5c6516586f1d44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaef6d49fb3e2f4ab,
+                    0xeddd2809c72ba8cb,
+                    0xc666de602552c5ae,
+                    0xda7f6018c205a241,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5aac90cb49975d6b,
+                    0xdd8b88e78ba18de5,
+                    0x0f17bdc6a8379e0a,
+                    0xaa31fc7bc0de1a95,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0e2205485be67477,
+                    0x00a989a44ab9fdd3,
+                    0x5743a080edc146d2,
+                    0x02004d82b67768f5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf42363953e606b4,
+                    0xa268d0d327751f09,
+                    0x49ad10d32cd844af,
+                    0x8eca606566077c14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc8f864c4f194808,
+                    0x42f9c739ff7cd1b7,
+                    0xe49ffec0261d505f,
+                    0x750a7f8b860a3862,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc43536359638272c,
+                    0xc5cd92b582c545d3,
+                    0x0bfecb445cde68be,
+                    0xae5650be294c1e7b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd368f9e6f79340d9,
+                    0x9183d3288eff56a7,
+                    0x6c3eb3670c89b1fa,
+                    0x9890715e21521cdb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79eb2e6ba837c975,
+                    0x8d7706c6b3cbbc3b,
+                    0x48d76db5b00f84ee,
+                    0x46a1b24201d3667e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6d0e85521ed1514,
+                    0xeb312923d6bd480d,
+                    0xacc7aa96d9f5fb23,
+                    0x816659bbd41d4421,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8d3fcbf7e12087a,
+                    0x5373ca969fce9bae,
+                    0x5ea79f7620562bbe,
+                    0x53e80e88765843d8,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa759d5bf4b8d29ae,
+                    0xbec81515ba97737f,
+                    0xeca510e0408cf67c,
+                    0x4a75eef7ab8f1c0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9f2d8db26d829d2,
+                    0xc28d2462308a8281,
+                    0xdbe38968c2f5c8cb,
+                    0x018b59665131be4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e8e27cec0e33825,
+                    0x0ff5df68eab509aa,
+                    0xaff2bdd290e545cd,
+                    0x434cfb0b22604ccd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x455dfe8dbb8a5e18,
+                    0x8aca93f604d6effa,
+                    0xaab897983f344fc1,
+                    0xd228b0798614f40e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71375fd5ca371173,
+                    0x7853481821fd8f55,
+                    0xad5e8fdc9ce61e14,
+                    0x83894cbaf7e81f64,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9272b4b3d37896fc,
+                    0x4dc17fff13bbd25a,
+                    0x1f6ec48c16112ff7,
+                    0xc7a3ad77806449b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x815af3e97eaeae6a,
+                    0x34b8fec747637523,
+                    0xa8bb4f50ec80557e,
+                    0xcb57474b0734154d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb74b82b582cd8880,
+                    0x29f41b26100a40da,
+                    0xd4ade83a3aefa0eb,
+                    0xf119a6dd648932a5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x64d126461b2788b7,
+                    0x546e35023af12d9e,
+                    0xaf61bb0e2a158f65,
+                    0xad7dd845abc20182,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f798a9311a20b90,
+                    0x699b590288902c28,
+                    0xb7e7cf3f4491c857,
+                    0x59e76dfcf3c434f0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b10f739dcfd59d1,
+                    0x9db70e939461052e,
+                    0x66f25806b6d445ed,
+                    0xb9d33ca8f0e366c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99a8a19b5b8e247b,
+                    0xe4be58a755aaeef6,
+                    0xc0de99c95e5c4bb0,
+                    0xe2f0f778e6343b94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69a2fdc8e2c82f0c,
+                    0xe896ca88583f89ce,
+                    0xcbc1b0738eed4f14,
+                    0x85663cd35e1334c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe400d585d1904757,
+                    0x58f0f6c2dbcab383,
+                    0x4646b1d788eab093,
+                    0xf57188cfcae352a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53a508773c195a8d,
+                    0xf09e05209512a62c,
+                    0x935932dd627b40ad,
+                    0xe0ecebd2b5680409,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9454e9facf730a34,
+                    0x15b2173f707c8ccf,
+                    0x74ea3e3cf949a370,
+                    0x5f3a6ee07fe669a7,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x75a15cb9ed9541e7,
+                    0x42773583e6a1f91f,
+                    0xafbea7e8edc6b102,
+                    0x3cdfe699a08430d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1178b94c553c6734,
+                    0x503954e1e6897c2b,
+                    0xb38f2d0db392f158,
+                    0xce18364669e29985,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x797894cbce0af09c,
+                    0xf134228a42cb76cb,
+                    0x1ae093436b2665e3,
+                    0x6573f3977a9ced7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68ec56287bc8cce8,
+                    0x193fd6cebb257fcb,
+                    0xe10c9bd3d4ca3b4e,
+                    0xa8fa538f1b104f8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7201cb6000404d0b,
+                    0x227054291a58088d,
+                    0x13b06af603bc8cf4,
+                    0x6c669a72c9733f53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa428710d436db026,
+                    0x012c6b59053448d8,
+                    0x7964fc13dc138b0e,
+                    0xd76d10f721542442,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3bf4d9f6508627e8,
+                    0x06832e60c0703810,
+                    0xb53bb3321fba2e7c,
+                    0x850fe9a902e8fcb5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa488fe6ae79ddfa2,
+                    0x72e271cdc8793d54,
+                    0x7e171e658781c1e0,
+                    0x803a9bdd91e8172d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x346fb2a7e7691481,
+                    0x928c7fdbe6f3d57f,
+                    0x0d6b508fe6c38e72,
+                    0x7a7b9c9f9d59d0e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x905326ee00e0dbae,
+                    0x77881a9e669520ab,
+                    0x1851aab2f0c4981f,
+                    0x97e4b6ea7644aabd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x907f45e25c035abd,
+                    0x076562343c09a22c,
+                    0x8bd168877e20950d,
+                    0xd854fed82d75bf76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0bfb7077a26cf093,
+                    0xda2bb57396d705a6,
+                    0xb618d20dfecf07b5,
+                    0xdd64a0dc319e8c22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5baa135490f88ab6,
+                    0xb40137c7e7670b9d,
+                    0x1aff74d416077a12,
+                    0xe2683382186bd582,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x578d651d6d7f483b,
+                    0xa8226146c9cce2de,
+                    0x17054ae59663a16e,
+                    0x7fd15789a10f4146,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0b9e44daf65d14d,
+                    0x623e7cc54815357f,
+                    0x2b3df1a56ee29b34,
+                    0x042b1db0794fd0e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24dc381384a31717,
+                    0xa340c0fae68e58cb,
+                    0xc17ea5d6d946e363,
+                    0x950884fe7c4b36bb,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3a40aa4a3e9c7bf6,
+                    0x52357aba7368e77e,
+                    0x8b3474391eda9e08,
+                    0x732c5e0b77bda4d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10c15f741d275be1,
+                    0x15357589abeb37e0,
+                    0x843ba6412c05c8a9,
+                    0x35f64d4fe029bf31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd991371f1b87bdb8,
+                    0xa854c6a99b1ad65f,
+                    0x62a937c2d1867a99,
+                    0xb2efc6f15b968811,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47c9b419e6d91275,
+                    0x24f1c0bd42ea34e9,
+                    0x2dd12521d96b4c4f,
+                    0x5970040d5bceae72,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef881bc4ca37b25d,
+                    0xf78cb62a95c4837c,
+                    0x870611cec903930a,
+                    0xb3608dcfaa5a658d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b00e97466f0d79a,
+                    0x06d3ea07a6a15834,
+                    0x85d62aec4877ba86,
+                    0xadfe001b34e228c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f641357bd40f371,
+                    0x48778b946f10167a,
+                    0x59c11ee3be961f52,
+                    0xb3620c6f8b6dfca3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcad8fd52a48f18dd,
+                    0x09b5fc3af35e371c,
+                    0xb566e3b449669270,
+                    0xbfe8f4dadd0da496,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x573a4ef77d354af9,
+                    0xbb97beab1a013283,
+                    0x860af9be38223208,
+                    0x42b24cea09126c71,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58078df84c1e7c9a,
+                    0x9bca6797abc2dc12,
+                    0xb0e414d751f32cac,
+                    0x269d350fd486df56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9199272aed7ef12a,
+                    0x0a3b1324b67fc929,
+                    0xc6471335874a97b9,
+                    0xa1a61c0fe39de2fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa90324f14d888a1a,
+                    0xabfdabc0b70a43ac,
+                    0x7ca0e5a691acb721,
+                    0x8146637389665b49,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1f503748132f34e,
+                    0x104f493d5e89609c,
+                    0x7f54a5382244d526,
+                    0xe886c8344bdc7954,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab9c13a76f6b3a6a,
+                    0x3ddbd9db568ca1d6,
+                    0x69fea0a2edb25e59,
+                    0x08379295c89b749a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4bc4a4fe59de1d05,
+                    0x2ab59f13fb32b859,
+                    0x84adc70e590333bf,
+                    0x69ce5db59ba1a636,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cecb65132e09ecb,
+                    0x1043654578a76898,
+                    0xd263d09745bcd074,
+                    0x54fcd81dea29a425,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xc83f9bce7b501edd,
+                0xe36cd2322e529fef,
+                0x3c7b8288d0ccabdd,
+                0x184027be6ab464d4,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x80f8bf3cc4939298,
+                0x773761da7832ce1c,
+                0xa11371d6c6a829bb,
+                0x4c5c7188368082f7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x80600cb2dd0eb70c,
+                0xbeb18ae2566e82f1,
+                0x473ec7b53839e303,
+                0x3b176d02f0ec696b,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x657010b9bc1cf026,
+                0x8376e2101597eb01,
+                0xf624521b758100c9,
+                0x4f462647db74b34d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4799283ea00dcd25,
+                0x9a531e0909159ac4,
+                0xc9d3bb9b86213a72,
+                0xcc19fd36926c6a14,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf81a3449e705c099,
+                0xa8c08a33fc1ba05e,
+                0xca52a64a8118ae81,
+                0x9b165398e0e516fe,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf335430ee3aac0af,
+                0x30ccf453a3e5c37b,
+                0x92522849d3b9dbe2,
+                0x709c349c15e654e1,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x11371cf79fc5aeed,
+                0x28551e9226312d82,
+                0xb121af2cac0f034a,
+                0xf4bd18ee0d2e92e7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc061ec84ef9b7847,
+                0x51620eec189b4526,
+                0xd27da3d512ef4833,
+                0x257bfce9ea26c432,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x49f1a3da5f0f7422,
+                0xfbeb7b0b10145c51,
+                0x2732154b9554a443,
+                0xcd71e4670a86cecf,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 217, 218, 656] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 217];
+        let mut opad = vec![0x5cu8; 217];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,2055 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4f09c4fae5460a13,
+                    0x02fec7d3bb000a54,
+                    0x6c21fcd049b460f6,
+                    0x61fc5949801ff012,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c6580240c38b335,
+                    0xa7b07e070ebfc70c,
+                    0xd35eb833b9ce7a3a,
+                    0x37d71b058b8f822b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c933f5a116cf12f,
+                    0x9afca6bde8e1de98,
+                    0x0b5965b7843310d5,
+                    0xd77505e1dba8c15c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbffb597e865dd050,
+                    0x4e2c8cd3bc67d1ad,
+                    0xece46b04b320effd,
+                    0xb36c0b3940f661b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bfcf6fc661c0daf,
+                    0xb7d9f0cc2b34be39,
+                    0xd46d2e0144999e19,
+                    0x7e4241b4b5a7a35b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb58e063804f7897,
+                    0x12ab8f14a97e7eaa,
+                    0x36d44e04a8fcf5e6,
+                    0x41438dbdaf180854,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a31c96596b99dd2,
+                    0x508bf89817e78a3c,
+                    0xdee0e25b26603fe0,
+                    0x71697aac96f63a47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a70094e0ef9462d,
+                    0x0e09911a86bc2907,
+                    0xf8448dbc2a503311,
+                    0x2e4a0a1c41278d58,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2bd73079f2e0722a,
+                    0x735f5838332ded39,
+                    0x6e996f712fe48842,
+                    0x8721910413ef8187,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74e3206595b2d9a2,
+                    0x6e8a8cea37ce2e43,
+                    0x022d15fbee00f1a8,
+                    0x003e4d7267ac1a6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9c9483b79850664,
+                    0x60971063c76b404b,
+                    0xf4e2eb3e60cc4366,
+                    0xe6098f984f283d90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5768520095d19b3b,
+                    0x4d475c788a6ab938,
+                    0xec6d935156b9ac20,
+                    0x0dbf6203befa6e33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa667a4b39d62d850,
+                    0xdd0b3f4605c88cfe,
+                    0xccdf7f24f87a608d,
+                    0xd5ee101c555da7a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0bb95827fb0900b,
+                    0xcadd36e5c8d02b1c,
+                    0x2529234c009edbe8,
+                    0x0457e914e28b28ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf09ed14089309081,
+                    0xffea90c783604d56,
+                    0x77e1b2acc672a0e7,
+                    0x24a25c700bce8ed7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd084cb69713b609,
+                    0xf7ed1552f408ac3d,
+                    0x55fd9bdbc3a1398c,
+                    0xb3882fba7e0f472b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x30a4944360f1cc12,
+                    0x89b49348c9e8ac1c,
+                    0xdf06ba749d941d9e,
+                    0x3020f08993886964,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ea8859a8dc292fd,
+                    0x504530ddf8b17b20,
+                    0xc4889faa7fb93c5f,
+                    0xe8141b8b91a79913,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde73cd1fd808c8c7,
+                    0xc77905e2a968b034,
+                    0x311624610f5e7977,
+                    0x406b86c49d06135b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x637406f0248f69f4,
+                    0x4f7a926fa5ce2e99,
+                    0x994574e8c5b59fac,
+                    0xa8eba6a38ce3b408,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b2c5425b8cf39fd,
+                    0x90a1855c44446ba4,
+                    0xa8b98299d123e918,
+                    0x6e18e29b647ba3d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2126c1ac82cbb84,
+                    0xb76a93e1874c80b7,
+                    0xfe3550003204eaed,
+                    0x63cb7171be297b7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebd190f11fa66ea3,
+                    0x30db95acb97d6c28,
+                    0x6f5ace2029a43075,
+                    0x395d04ecf91f8d82,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32c37d9a55028be9,
+                    0x4f3cbb3d4e1bbe54,
+                    0xf8841a5a9e894e73,
+                    0xbff4de11b36957e9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x30fc4b41c4195801,
+                    0x7c6e4b9ad651cd20,
+                    0x627216ee41a9309d,
+                    0x0f9af0054546a98e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88d977e5748b82a8,
+                    0xc5aa5573e3b1b8b7,
+                    0x1d28251450ecba45,
+                    0x13bdd0347a73c8e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08435510963e31ed,
+                    0x28537f0080658290,
+                    0xb3aa298e871a351a,
+                    0x08f01c3e43e99b05,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5490b1f49a491c0,
+                    0xc40a7a90a286d0fa,
+                    0xb033f78764aabc55,
+                    0x9df7611ab987ad98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x09b742f52a99679c,
+                    0x6112929dd2ef7cad,
+                    0xd5bec558e6decf1a,
+                    0x46a7495b29521d1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x329cbb2873a5a9fd,
+                    0xd51879496a888a13,
+                    0x836216df538450f3,
+                    0xbe26b10064e1c5e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2d9eb6a65a2ae0b,
+                    0xbbf72ae327fd782c,
+                    0xcb213426bd327914,
+                    0x7a001c3cca81fc07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x639a1be4113de2dd,
+                    0x4f3e864eacceb225,
+                    0x237b19e23b89e979,
+                    0xd29be7fdb832d457,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2316e47cf24047f9,
+                    0xc60991ea8b125e57,
+                    0xfaadcc5bb9521ddb,
+                    0xc19d81315c4f7293,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c36231a2bbe63ab,
+                    0xc0101d9b6435a664,
+                    0x8f378318645a9880,
+                    0xf9b2055a9e35b690,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a386462a8674a9d,
+                    0xac442eb7b553a8a1,
+                    0xa8dc8f716f2bff92,
+                    0x05deb5442495c39a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf08d0c9b7a184a4c,
+                    0x99aca999f24ed663,
+                    0xecd2b8d3b6d6cff7,
+                    0x4975a728354b7609,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x431154cd6489fe1e,
+                    0xceba04e75879378d,
+                    0x7297589c56ff77e2,
+                    0x507becb42c2d90fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb803cdcd07564c4,
+                    0x5066466292c52a45,
+                    0x1f9dd8ef86f6bf3d,
+                    0xd487b6d465081ebd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f5c7e7ca2842cf0,
+                    0xed64cdd89fa3b73b,
+                    0xf8f0bfd1e46ac79b,
+                    0x37963d8363f1b089,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a7ae2b5882fd105,
+                    0x71a6b8af4785836f,
+                    0xc7f86f8d2ce7c4cf,
+                    0x43baaceeb958f5e2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3c5cf99695a726c2,
+                    0x659b8ed9911b42b5,
+                    0x423695e1bd7a4fb6,
+                    0xa0e913e1b32eee69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf58e394b2d781586,
+                    0xd6e7a406d56e0359,
+                    0x8fd3e8d57fbcd25c,
+                    0x0ec388a24b0db545,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52d34ce744916cee,
+                    0xea22bf0b5e5d0b63,
+                    0x074a1db9dc00f3b8,
+                    0x734154e983dbc5e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x801a6e41bc067809,
+                    0x282078fedafe636c,
+                    0xa21c169345b3f325,
+                    0xe2963dc168708e6f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d8b65df588f6d87,
+                    0x3e02d511e4380bbc,
+                    0xb0bc3d1614ae4730,
+                    0xc96f3e0019654e92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39a5466f93e55ddf,
+                    0x741bf6ae6257b391,
+                    0x62167d193ceee891,
+                    0x93627891cc1ad21d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5474885794bc336,
+                    0xf5812776bdfb2a5d,
+                    0x0d1d2b383e9206d6,
+                    0xbb43ab1ae7c9ccb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16b002c0642d15ef,
+                    0x411a3c9e4b74853e,
+                    0xcf55e6d956120cb6,
+                    0x2be46e46760759f7,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x000000005555569b,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecd3e1293f2bd49b,
+                    0x3c29ded5a6bc3f56,
+                    0x04594d35504db69d,
+                    0x4b731b8afd4d42cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b109917af6212bc,
+                    0x707b8ae5be04436e,
+                    0x9eac70c62a3dcacb,
+                    0xc45aa5c26bc870c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3082ab6c55849bb7,
+                    0x74e089bb67ee48d2,
+                    0xf0a0ea69392c89a7,
+                    0xd7df51573ec1a2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02094d175803f224,
+                    0x3e96c3bc5fe6d6f5,
+                    0xf9518c4de1a1fda7,
+                    0x4562a32f897f9754,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000004555565df,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfffffffefffffc2f,
+                    0xfffffffefffffc2e,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x30c89d0212ea3f83,
+                    0xb7cc329be316b6a5,
+                    0x41a2ab3d7bc7aee8,
+                    0xe88735f74aa3bf86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a74f9b805fe1c99,
+                    0x7001637a928da1b7,
+                    0xfc6776e849e8bfc3,
+                    0x4aa392388a740ba5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd39766fdfb38235,
+                    0x0c771ab552bfacbc,
+                    0x4f855789224e16d7,
+                    0x167e6173afcae6d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b0fe2ea40307eb0,
+                    0xfd86557edfd5e3df,
+                    0x7a67e6f899a58fdf,
+                    0x2b86b2e5a3957f43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9a8d8fa2fa339d2,
+                    0x4803fcbab9115441,
+                    0x0889ce11bd89004f,
+                    0x170604824772b255,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9a1b37a44e962afc,
+                    0x8f3bdcba7ab66077,
+                    0x20ca91527ec1a32d,
+                    0xaa73b66ee575f591,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb87384f2a1086cb7,
+                    0x82679e4823054c41,
+                    0x7e345882fee954cc,
+                    0x5b9a68da9bbaf1e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45e4e8eb1cd708c3,
+                    0xf6e79aafacfdb6a6,
+                    0x631b9abcdf95e3d0,
+                    0xad8f5c27ab2ccf77,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3714c5beaf7644d5,
+                    0x17113ea28fd5c0ae,
+                    0xee97577998a29810,
+                    0x358bf434f31501ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf54e506a809d9a4a,
+                    0xe8840984174fbcba,
+                    0x1cf750ea2101bbab,
+                    0x1568a1ab09ffe490,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1861e565314725f8,
+                    0xf30021c9975df9ab,
+                    0xd99a1deb74127bf5,
+                    0x41890fe0439baddd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a3717701a266215,
+                    0xdf24d898d7cd12b5,
+                    0x4f565ba22aed3dc4,
+                    0x736ef5f9de4dd3a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7b4d3d87f563da2,
+                    0x5eec4ab58905cd84,
+                    0x0fa3e223dd6f7da5,
+                    0x29cf1f523c4a8e0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78d604f4710b7ea7,
+                    0xf93dd064ce9a96aa,
+                    0x06279302b274b32c,
+                    0x2f457e3ecbb66324,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c164fa2b61a38ad,
+                    0xfcce49024fdd50d8,
+                    0x22a5ffc74bcfd18d,
+                    0xe71e04ea06b19ae2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54f63473e4053fb3,
+                    0xc750485c215520e4,
+                    0xbcc0bb2d498b28cb,
+                    0xdf512bdca91f008c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6a76a88d2f7402ca,
+                    0x648295d0ba837f68,
+                    0xd87a158b26d1f0c1,
+                    0xa0a0ada2a08ba13a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e2d99a2cf67844e,
+                    0xa75df3a6c1595aa4,
+                    0xb8cc9f52772435b3,
+                    0xe5de5d98354c3470,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7dcd1c9337849764,
+                    0x4a99f353ed54f75c,
+                    0xce83f80c5d08d513,
+                    0xdfc2c01ba954dba4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f49f35183a06334,
+                    0x942ad36a3baaf764,
+                    0x422a7659f252889c,
+                    0x1bc868bd9e7f09cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x86c37c065214904d,
+                    0xbefe93f114edeed2,
+                    0x0419b0ad2d42b93e,
+                    0x94e9f8e4a30b9e17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7a0af5068d5c3d0,
+                    0x129b8121b1d2118d,
+                    0x9a06c57d0a8ddbd1,
+                    0xc4e35d8119d3fd5d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e62ff8c47afa4c0,
+                    0x4162c064712907a1,
+                    0x803b2261429830df,
+                    0xe4317f5b8cd5b950,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6240ebfe1f7b9cfe,
+                    0xbbf715bca379d84f,
+                    0x05121df752fef3e7,
+                    0xb757c0cfc8729a4e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7844861f3232e808,
+                    0x589798e3084ade2c,
+                    0x844e936476bf548a,
+                    0x02186a6e14e339b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0632922ff1985f28,
+                    0xdfa5d63e379ee799,
+                    0xdcafdad953f74bc8,
+                    0xa9993837e0fc78ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44676b5bc07d50d7,
+                    0xd825eaf4d59ade96,
+                    0xc321adf4203bbc26,
+                    0x6ed38b3e8861c2fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fbae7f230f126f1,
+                    0x47a4e98e6a7926a9,
+                    0x18a84b4040b0280d,
+                    0xcb9c13f062d5c475,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x509ec9610200a186,
+                    0xbeb83de5aec8a540,
+                    0x5e62f7fa818e1917,
+                    0xdce85fbf38a8c06e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x607d371306f28cc9,
+                    0x388a7caa58779346,
+                    0x88833f19fcfd8289,
+                    0xc25219880450f893,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83af544927876f75,
+                    0x91bb216c02bd3c5d,
+                    0x4947fe13371d4f97,
+                    0xd41f94ef76a684f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8165c27508e14c66,
+                    0xda09e482c042f599,
+                    0x9ded88188f54aac0,
+                    0x6066c091768d8900,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x73b1db8e122412f1,
+                    0xba77b6fd4b607a56,
+                    0x4230e2d7d6b9d928,
+                    0x73a3218e8c97e785,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2e302673c00fb61,
+                    0x2cb9e9b4f6ca53ae,
+                    0x4a9a58cd6a13ba7f,
+                    0xfa426368fdc1a524,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbcfc4755f69888e,
+                    0x7e8613243e774435,
+                    0x84d1cba202330191,
+                    0x3050dbd9c5a63188,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe26fe46526a8700,
+                    0xd42efa67acae3642,
+                    0x2cc26a9e13f35f11,
+                    0xbf8ff690cbdf725a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfc11c9408b45b8b,
+                    0xacef951d63279a01,
+                    0x7b067b09fa34833d,
+                    0x7287d850c1d4f431,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeaadb65af7958ced,
+                    0xd25e2d8e2d768d8f,
+                    0xd76d6ed5878332e6,
+                    0x324495378de36fa5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba901a1e83e0a342,
+                    0xe5019f08472777d4,
+                    0x2513138de537a1cb,
+                    0x31a8a8a5a010294b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf22e1abe768c32cf,
+                    0xe9ce1982018ca433,
+                    0x0b4b4c5fe96bcc92,
+                    0xe69e89fcf20ac818,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd8cf5da68bfbb12a,
+                    0xe94f4dd3f8621697,
+                    0x1348a253b1ad097a,
+                    0xd2c9b28df11c44e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62223a2f816b7bb4,
+                    0x5b46b13152e1e8fa,
+                    0x0cdd68d76099aac0,
+                    0x13f577cf970d82be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf28b5dbe356c2cb5,
+                    0x9ef8a4aba0f63ee4,
+                    0xd951707be7b2a6d2,
+                    0x3c5df8f679693dd6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71fd3c98c294e4f6,
+                    0x9b383d9b5e9f70e2,
+                    0x5c905e8389668b12,
+                    0x48d142c3d8bed35b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0d883582b32e412,
+                    0x47824694a3fa0f57,
+                    0xb2385a040c5d1497,
+                    0x5d33af42fe3ed376,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9627de707c902cf0,
+                    0xbc5e66fde66c69b6,
+                    0x5c96bfa16dc181c5,
+                    0x3d34bdf58cfc42ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ed38f651109f0a0,
+                    0xb8029e2eb6acbd7e,
+                    0x56a6c4c7db74d5f6,
+                    0x8f31e2cad569dc84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5c8181d0085cf88,
+                    0xda5b29bf8568750f,
+                    0xc5580a755112638a,
+                    0x8e3382993df22422,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbfe2cdbeb2a3d7ed,
+                    0x1a1a257e9c0a72ec,
+                    0xa0477934d97482bb,
+                    0xc65e5c561075e928,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c8621c8e5f3023a,
+                    0x02f20564c415831c,
+                    0x022ee3e7d52e3ea8,
+                    0xbda1cd540925a1a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d64ab8ba1553074,
+                    0x9b601e81a4d1c420,
+                    0xba994b05f8f66118,
+                    0xa194242c5bd038f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe5b77d758b7de03,
+                    0x176e3e97a7692b48,
+                    0xe57697502e4ee2d0,
+                    0x8141ed7d77f12fe2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b8d2aaabce20207,
+                    0x35bec261ae2f7347,
+                    0xbb9c7ec8abb09629,
+                    0x1592ca1386fad07c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff6545d969b783b0,
+                    0xff207876c6a575c6,
+                    0x80d05e395a6d8c8d,
+                    0xd018334a88670b23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x913190e45d46a21b,
+                    0x2dfe37f9249da6cc,
+                    0x56cd20e09d1aec9b,
+                    0xe76f865f23776ae5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x761a45b0d709f2e4,
+                    0xfa72afe77ac25e85,
+                    0x2712316819f5bb4e,
+                    0x945d723fb8e8b86b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0ece44bafb903745,
+                    0xba4aa7eabd69a0aa,
+                    0x06e6f348f715e057,
+                    0x9b7d4c85ae05e541,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d90c24a7242e88c,
+                    0x10613dd5455b7bf1,
+                    0xdf04d784c65e1df7,
+                    0x5435d4d5542e23a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58df03c7fb903895,
+                    0xc923c5fd962c5c31,
+                    0xc7e4e2e7082a4a96,
+                    0xda018fec87cac8cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b66a39611fe28c9,
+                    0xd9f6a892fe7591f1,
+                    0xc662d12b3e66d972,
+                    0xd110086c30ec090c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88eaaa0eb9d45111,
+                    0x96efeb55de6c8c74,
+                    0x2c1c66a7d4874b67,
+                    0x1251cded94a1950d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd7378f542300f11,
+                    0x6b0df4eac31aae76,
+                    0x819a6f2396a9bfcf,
+                    0x798d67e74e1c9750,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b95289b2aaeb825,
+                    0x72726120c83a0c6b,
+                    0x186427454a0159f8,
+                    0x2f53895af422181d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e7181aef2e5750c,
+                    0x6b6ebc314ba62051,
+                    0xcf333c905307d3b3,
+                    0xd56ef1f7401b6eb5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5787fcb862888092,
+                    0x9b6b588493670a2c,
+                    0x1e1d044455eeb276,
+                    0xecd5df05c879e91d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x602242cc55ac6867,
+                    0x8c29eac52140a94b,
+                    0x405ad901d2618746,
+                    0x08aeb60f1f218c5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9abc7df05050b89,
+                    0x932307fc0e5b2613,
+                    0x0866c3de6608ec6a,
+                    0x22f62d0d0e062a69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14ea3ddd4ac9e980,
+                    0x3920966ed255e88f,
+                    0xece7c5c20fa5d471,
+                    0x7b503a92de2abb57,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf96e166a4aef0a34,
+                    0x41efd36f774bca61,
+                    0x50a1551087f11e4a,
+                    0x719741edc925465d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44a8d314e137b42a,
+                    0x5a06157afbbddd3c,
+                    0x1485d7f39d01303b,
+                    0x527c311dfe01c3f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf083b89abab2ded7,
+                    0xeb6897d5237d1418,
+                    0x284fb02095a80544,
+                    0x8359c1aac260f4d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa14bfcf5f4c33dd3,
+                    0xd117a35fb799aaa6,
+                    0xd2f1be0d6f3b72b0,
+                    0x5d58eb448a2dace6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf5f29d6dfa43dba1,
+                    0xf336a9c640df9f51,
+                    0xcf38c233a73af4a2,
+                    0xda49f922095a33f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc813df3292522a7,
+                    0xc7c651e688f60d94,
+                    0xc67a4c5a017a383d,
+                    0xd9a70d554cb499c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50ddbd48aede3909,
+                    0x9bb2cca07420b786,
+                    0x86ba71a396c6b08b,
+                    0x584e674523b54098,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6c2b550e0ee93c5,
+                    0x487e89d6cdd7413a,
+                    0xd1d4222a5971c219,
+                    0x201298c5663d51ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b48ca8c51a3e22f,
+                    0xc5ebcd2d6bbb1cc4,
+                    0x9600c2055180e3c5,
+                    0xb68c793e6df98526,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9ae114d733bb366,
+                    0x8ee41c3a96dcc01c,
+                    0x7c603fbe8c979342,
+                    0x9c6dcf13ce52da41,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa872b1b751dbe10a,
+                    0xf1469dc0c40e474e,
+                    0xef697a15b0de692f,
+                    0x1c17795f7a7afafe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3611a9f5a959cd71,
+                    0x6a090a1e000f5c80,
+                    0x410261a48d0f8c4f,
+                    0xdb7f498c1ec3d66b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8f94295e28416118,
+                    0xa5198a90da091108,
+                    0x35f013771f5e5a2f,
+                    0xc5a010d6d2bf401a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24c39932521b6ecb,
+                    0x5c688129efb41cfd,
+                    0x7770bed124745406,
+                    0x6a3920759eafe1ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f8c5886b192a655,
+                    0xe2f19038e990d3fa,
+                    0x0063803679675074,
+                    0x118d0e2141a39d7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8f8d6d581d55210,
+                    0x67d769dea3cc5be2,
+                    0x07c46573d5aaae54,
+                    0x8b5517ea4b7e6acc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82d0e7a51a61e84f,
+                    0x8a36f1fcf3981b6e,
+                    0x6024f1b8b6961e48,
+                    0xdb38ec60f805e6f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xece8693eca20dce2,
+                    0xdcb9705bcc49aa93,
+                    0x8f3e9056ed0f56f5,
+                    0xb2c137fe3c53c469,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x575dc84e8d772c2e,
+                    0x0657ec2f2240194b,
+                    0x4b3af22675511635,
+                    0x31231ca06cdfcf3c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9a1e66c874ecdfc,
+                    0x15893092ed8ab4f9,
+                    0xef81a985eb7d0075,
+                    0x9b5a1633637db639,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbf32c1272d94b880,
+                    0x4037a3ec5f74799b,
+                    0x11de2174e4c185f4,
+                    0x7d896c366dffec5d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b37fa6b062ad9d4,
+                    0x8d4564d8536e0bdb,
+                    0x3b8b5f1d77e44b26,
+                    0xf24f30eb07163dbe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8dcb756cac10d82,
+                    0x19a35906ebf153ab,
+                    0xe3f1e40f0296337c,
+                    0x6a86c0d1bb06a794,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeaccb455696437e0,
+                    0x049de203c602b43c,
+                    0x0881046884beed4d,
+                    0xe69aa8c41805ec38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a4e57a2d9cd1489,
+                    0x09406f3969729b40,
+                    0x0e2c8845d7b50fd4,
+                    0xd3906d4fbb0d81ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4da248b6bee2ee8,
+                    0x2a94223348dc41a9,
+                    0x4608254cac03e282,
+                    0x158f015f078d707d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebf47df45148ccbd,
+                    0x6237a4f1afeb4906,
+                    0x528a6e9a570da80b,
+                    0xc95de0bc74683436,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7aba82a6d018d4f4,
+                    0x55c54a78496f72be,
+                    0xd0d1bbc1851a8791,
+                    0x92cb167105b31c87,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000000b000029fb,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x0000002000007a20,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000017000057c7,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x00000012000044b2,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x853bbf12389726f1,
+                    0x3e4d525d91114d9a,
+                    0x8932afd6a3fd4915,
+                    0x2567c6a3b050aa3f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf55c6460182d617,
+                    0x1fc7c258d4876f28,
+                    0xed594102cb454087,
+                    0xec04ee16b8c1f924,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9682f6bd618290ef,
+                    0xfe869adea3961797,
+                    0x069cee48d5eab8d4,
+                    0x4a9d511d220cd9b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58d205855b13a67e,
+                    0xace0119f58da5aff,
+                    0x560c5e7cde34700e,
+                    0x32aa311f75147a88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe921da839a9601c3,
+                    0x47adaf36d3f1865f,
+                    0x8410f24e1fcc3363,
+                    0x6ba701c0d5ef5170,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x922780dc9d19645a,
+                    0x8210ec24618a969f,
+                    0x568117a8a4c810a0,
+                    0x128d699c09b6fc15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x399c7d17c9818c33,
+                    0x6781ed5d78dc2cd3,
+                    0xaa0da4747c845666,
+                    0x5e6e2184dfa620ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0673896cac728840,
+                    0x04c882e843ea4f62,
+                    0xc685ee64b2163508,
+                    0xa7d0f8d160858407,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x11e24404ed471475,
+                    0xe51eeaf190bd5ad8,
+                    0x2dd16d92e441c450,
+                    0xed8968e528e2e960,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b540e5c9f691c82,
+                    0x6b7c813b36e8e3ab,
+                    0x3e9feb94d1a76729,
+                    0x8964255a85fe1d88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15d0d15d1ff527de,
+                    0x51097c09a8f0a56f,
+                    0xf6ba48cfaad9a127,
+                    0x8b8dddd05f4f3d0c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4265fcaf48beabf5,
+                    0x1f1944c49782429c,
+                    0xa5f79f275b0498b0,
+                    0x8db2d562606b0d1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21dc9e9df3184d6f,
+                    0x3bf37003623ae674,
+                    0xd519f5e1355c8502,
+                    0xee1dcd019cdc030d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c3dd51234455b26,
+                    0xef2489e882390fa6,
+                    0x24b38057dfda5492,
+                    0x53db80c141734165,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe369d804ae0bd4ed,
+                    0x90b16ff0ab52e413,
+                    0xe99820df8b28e958,
+                    0x0e9ea501e6d5edc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35e12d5ff388fbc4,
+                    0x6b645d7be2a5a38e,
+                    0xcf321fc3abe51dc1,
+                    0xfb126eb5f839a663,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x43d31920a8a708eb,
+                    0x30ddded9ba7cdb42,
+                    0xff74052d00811224,
+                    0xea63ee220992cbdc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e79d4a49a7b2866,
+                    0xe52d5adeb76d3f50,
+                    0xaf38ca44e7a31e3c,
+                    0xa6bfae21cd1f8f88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8250fb08aa090874,
+                    0xdbe4bf4eb1981785,
+                    0x0e3ca25e0f7245d5,
+                    0xb1e05514ab283928,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7fbc15901c0fbc9a,
+                    0xe0be3f25aac9de38,
+                    0x07c362c1891793cc,
+                    0x4641e7f39e8fe7aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6e5efa48b1a16df,
+                    0x40bed93d16628cb9,
+                    0x207dd1cfd926f840,
+                    0xccb3bf60017e7113,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x074ea3c98aee37e3,
+                    0x450b34dba502eb92,
+                    0x1ca124c0fd70901c,
+                    0x9f1948a1b356fdc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65953fd49bbeb405,
+                    0x49bb08eeaddb502d,
+                    0x38f8db0556f2cd5c,
+                    0xa83b7c0a723b4578,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fd9a920a9c8e589,
+                    0x52fab49ceb5f2b1b,
+                    0x279fbf1cf913b70b,
+                    0xbfccd9a80bcbe1cf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaed8ed0c4e3edfbc,
+                    0xbee3f4c62a9fbfe8,
+                    0xf2cdaf057dcd2d88,
+                    0xaa092e96da045292,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae605ab09c1f9358,
+                    0x5478c62824cae390,
+                    0x6e711dbfc0044d7f,
+                    0x222a2cac4725488f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1fb49067149b5b5,
+                    0x5091cd0ec400d08e,
+                    0x11567d47eaec4cd7,
+                    0x2c12048cc32fc3b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecf734859968816e,
+                    0x385971a355f807d7,
+                    0x85bafcd8d15e7644,
+                    0xe5eedac2426e0da0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f8835b5aba45983,
+                    0xe3dd191fea754925,
+                    0xbf0f9312ebc28841,
+                    0xc2eefcf15e76d8f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3c3220cc44a795a,
+                    0x66eb27ce70c95412,
+                    0xb662cb6eaa85873c,
+                    0x3cca6f117b8f6dfb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe65baf5bddbdb447,
+                    0x0338c8a9e13f1258,
+                    0x631cf22ffc38fb6d,
+                    0x3260ba9365e66a49,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x113349534c8f0475,
+                    0x2a49143f66f17c2c,
+                    0x986d8294f5a5615f,
+                    0xa30b4f89a6d9ddda,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4c321c400ae714b7,
+                    0xf7167d7f40f11493,
+                    0x2c2abfba9e65a6a4,
+                    0x452b04cff4e4dfd9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa25fe4ac96b5b030,
+                    0x85761309079cc845,
+                    0xb6a668028d0d221b,
+                    0x02d5fcfdeffb3729,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a858afd062b6b5c,
+                    0x8fe1a1449068e1b8,
+                    0x37dc7a90327a29ac,
+                    0xed84297a6c857925,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12323acafbd2e5b4,
+                    0x63740a8e41f4fa87,
+                    0x3c119c073f885f9b,
+                    0x600bb09f94b0b023,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fb7f4b2e50fc390,
+                    0xd55988c7f8b280fa,
+                    0xab1e2aaca88d0c02,
+                    0xdb06b23d025dd8e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66cf062a92a6a914,
+                    0x2c4965a628ebc2a8,
+                    0xeeffaf008165eef1,
+                    0x02671ee98f8fc818,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b5d4305052eee94,
+                    0x3a0139691ec3058d,
+                    0x1e5b06d13b395019,
+                    0x0b44c14b366582dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf5b71040639d653,
+                    0x08c49065b7e7e806,
+                    0x9aafa3dd864471cc,
+                    0x61ec9c79f5fe7ac7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x370eeddfe2b00e7e,
+                    0xd010e86dc308c0c2,
+                    0xc56457515a5214e9,
+                    0x3a2a6ca4037e9ebc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e74532cf0fec75f,
+                    0x68140fa95e191118,
+                    0x36a6519555128670,
+                    0xbde0b991c74e480c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1d020e0420e4f06,
+                    0x658030004df2d5cc,
+                    0xbeab7ad6576642a1,
+                    0xf38eea0f38e5d36a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x811bd4f0e753106f,
+                    0x3d25e4bfa1b30563,
+                    0xb621dd70f5027587,
+                    0x704eeccc7ac70946,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23c6ab40aec3390a,
+                    0xd083e30f212f8fc2,
+                    0x2d2cabcc1ea948df,
+                    0x6e190f931b701cac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e2fa07bcfc86745,
+                    0xb1a92394d6a5c509,
+                    0xfb4527dd1b24642d,
+                    0x468cec1eed066678,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb3797bade3c29e0,
+                    0x6df107aaae3dbff7,
+                    0x66ab0ff7ff8ca5db,
+                    0xb760bea74d8bee80,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2080ea65c0354bf5,
+                    0x8027a7b774f8cb15,
+                    0x0510efc6ff92f85c,
+                    0xde7023354a5e8cb1,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,122 @@
+//! Base field of the secp256k1 elliptic curve.
+//!
+//! The modulus spans the full 256 bits, which the 4-limb arithmetic of `ark-ff`
+//! does not support (it requires a spare top bit), hence the extra limb.
+
+use ark_ff::{biginteger::BigInteger320 as BigInteger, fields::*};
+
+/// A prime field element, in Montgomery representation.
+pub type Fq = Fp320<FqParameters>;
+
+/// Parameters of the prime field.
+#[derive(Debug)]
+pub struct FqParameters;
+
+impl Fp320Parameters for FqParameters {}
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 1;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xfffffffefffffc2f,
+        0xfffffffefffffc2e,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x0000000000000000,
+    ]);
+}
+impl FpParameters for FqParameters {
+    /// MODULUS = 115792089237316195423570985008687907853269984665640564039457584007908834671663
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_BITS: u32 = 256;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 64;
+
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x0000000000000000,
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000007a2000e90a1,
+        0x0000000000000001,
+        0x0000000000000000,
+    ]);
+
+    const INV: u64 = 0xd838091dd2253531;
+
+    // GENERATOR = 3
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x0000000000000000,
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffffbfffff0b,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+        0x0000000000000000,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::*;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_fq() {
+        let mut rng = test_rng();
+        let a: Fq = UniformRand::rand(&mut rng);
+        let b: Fq = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        sqrt_field_test(a);
+        primefield_test::<Fq>();
+    }
+}
@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 48,
+    expanded: "a3b26eab5dc6afd79cf55edc5d2a3ae2cd39519acf001248e3017d91c24382ea",
+    element: "96544630684793120323155689221045683037926334859291589275457698037830751991637",
+}
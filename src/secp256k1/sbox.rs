@@ -0,0 +1,344 @@
+use super::BigInteger320;
+use super::Felt;
+
+use crate::lanes::ChainElement;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 5] = [
+    0x66666665fffffe79,
+    0x6666666666666666,
+    0x6666666666666666,
+    0x6666666666666666,
+    0x0000000000000000,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 3;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger320([
+    0x0000000000000000,
+    0x000000005555569b,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+/// Computes `x^INV_ALPHA` with a fixed addition chain.
+///
+/// This function is **not** constant-time, as the underlying
+/// field arithmetic is not.
+#[inline(always)]
+pub(crate) fn exp_inv_alpha<T: ChainElement>(x: &T) -> T {
+    let t1 = x.square(); //        1: 2
+    let t2 = t1 * x; //            2: 3
+    let t3 = t2.square(); //       3: 6
+    let t4 = t3 * x; //            4: 7
+    let t5 = t4 * t1; //           5: 9
+    let t6 = t5 * t4; //           6: 16
+    let t7 = t6 * t5; //           7: 25
+    let t8 = t7 * t6; //           8: 41
+    let t9 = t8 * t6; //           9: 57
+    let t10 = t9 * t6; //         10: 73
+    let t11 = t10.square(); //    11: 146
+    let t12 = t11 * t9; //        12: 203
+    let t13 = t12 * t1; //        13: 205
+    let t14 = t12.square(); //    14: 406
+    let t15 = t13.square(); //    15: 410
+    let t16 = t14.square(); //    16: 812
+    let t17 = t16 * t4; //        17: 819
+    let t18 = t17 * t16; //       18: 1631
+    let t19 = t18 * t15; //       19: 2041
+    let t20 = t19 * t3; //        20: 2047
+    let mut t0 = t17.square(); // 21: 1638
+    t0 = t0.square(); //          22: 3276
+    t0 = t0.square(); //          23: 6552
+    t0 = t0.square(); //          24: 13104
+    t0 = t0.square(); //          25: 26208
+    t0 = t0.square(); //          26: 52416
+    t0 = t0.square(); //          27: 104832
+    t0 = t0.square(); //          28: 209664
+    t0 = t0.square(); //          29: 419328
+    t0 = t0.square(); //          30: 838656
+    t0 = t0.square(); //          31: 1677312
+    t0 = t0.square(); //          32: 3354624
+    t0 *= t17; //                 33: 3355443
+    t0 = t0.square(); //          34: 6710886
+    t0 = t0.square(); //          35: 13421772
+    t0 = t0.square(); //          36: 26843544
+    t0 = t0.square(); //          37: 53687088
+    t0 = t0.square(); //          38: 107374176
+    t0 = t0.square(); //          39: 214748352
+    t0 = t0.square(); //          40: 429496704
+    t0 = t0.square(); //          41: 858993408
+    t0 = t0.square(); //          42: 1717986816
+    t0 = t0.square(); //          43: 3435973632
+    t0 = t0.square(); //          44: 6871947264
+    t0 = t0.square(); //          45: 13743894528
+    t0 *= t17; //                 46: 13743895347
+    t0 = t0.square(); //          47: 27487790694
+    t0 = t0.square(); //          48: 54975581388
+    t0 = t0.square(); //          49: 109951162776
+    t0 = t0.square(); //          50: 219902325552
+    t0 = t0.square(); //          51: 439804651104
+    t0 = t0.square(); //          52: 879609302208
+    t0 = t0.square(); //          53: 1759218604416
+    t0 = t0.square(); //          54: 3518437208832
+    t0 = t0.square(); //          55: 7036874417664
+    t0 = t0.square(); //          56: 14073748835328
+    t0 = t0.square(); //          57: 28147497670656
+    t0 = t0.square(); //          58: 56294995341312
+    t0 *= t17; //                 59: 56294995342131
+    t0 = t0.square(); //          60: 112589990684262
+    t0 = t0.square(); //          61: 225179981368524
+    t0 = t0.square(); //          62: 450359962737048
+    t0 = t0.square(); //          63: 900719925474096
+    t0 = t0.square(); //          64: 1801439850948192
+    t0 = t0.square(); //          65: 3602879701896384
+    t0 = t0.square(); //          66: 7205759403792768
+    t0 = t0.square(); //          67: 14411518807585536
+    t0 = t0.square(); //          68: 28823037615171072
+    t0 = t0.square(); //          69: 57646075230342144
+    t0 = t0.square(); //          70: 115292150460684288
+    t0 = t0.square(); //          71: 230584300921368576
+    t0 *= t17; //                 72: 230584300921369395
+    t0 = t0.square(); //          73: 461168601842738790
+    t0 = t0.square(); //          74: 922337203685477580
+    t0 = t0.square(); //          75: 1844674407370955160
+    t0 = t0.square(); //          76: 3689348814741910320
+    t0 = t0.square(); //          77: 7378697629483820640
+    t0 = t0.square(); //          78: 14757395258967641280
+    t0 = t0.square(); //          79: 29514790517935282560
+    t0 = t0.square(); //          80: 59029581035870565120
+    t0 = t0.square(); //          81: 118059162071741130240
+    t0 = t0.square(); //          82: 236118324143482260480
+    t0 = t0.square(); //          83: 472236648286964520960
+    t0 = t0.square(); //          84: 944473296573929041920
+    t0 *= t17; //                 85: 944473296573929042739
+    t0 = t0.square(); //          86: 1888946593147858085478
+    t0 = t0.square(); //          87: 3777893186295716170956
+    t0 = t0.square(); //          88: 7555786372591432341912
+    t0 = t0.square(); //          89: 15111572745182864683824
+    t0 = t0.square(); //          90: 30223145490365729367648
+    t0 = t0.square(); //          91: 60446290980731458735296
+    t0 = t0.square(); //          92: 120892581961462917470592
+    t0 = t0.square(); //          93: 241785163922925834941184
+    t0 = t0.square(); //          94: 483570327845851669882368
+    t0 = t0.square(); //          95: 967140655691703339764736
+    t0 = t0.square(); //          96: 1934281311383406679529472
+    t0 = t0.square(); //          97: 3868562622766813359058944
+    t0 *= t17; //                 98: 3868562622766813359059763
+    t0 = t0.square(); //          99: 7737125245533626718119526
+    t0 = t0.square(); //         100: 15474250491067253436239052
+    t0 = t0.square(); //         101: 30948500982134506872478104
+    t0 = t0.square(); //         102: 61897001964269013744956208
+    t0 = t0.square(); //         103: 123794003928538027489912416
+    t0 = t0.square(); //         104: 247588007857076054979824832
+    t0 = t0.square(); //         105: 495176015714152109959649664
+    t0 = t0.square(); //         106: 990352031428304219919299328
+    t0 = t0.square(); //         107: 1980704062856608439838598656
+    t0 = t0.square(); //         108: 3961408125713216879677197312
+    t0 = t0.square(); //         109: 7922816251426433759354394624
+    t0 = t0.square(); //         110: 15845632502852867518708789248
+    t0 *= t17; //                111: 15845632502852867518708790067
+    t0 = t0.square(); //         112: 31691265005705735037417580134
+    t0 = t0.square(); //         113: 63382530011411470074835160268
+    t0 = t0.square(); //         114: 126765060022822940149670320536
+    t0 = t0.square(); //         115: 253530120045645880299340641072
+    t0 = t0.square(); //         116: 507060240091291760598681282144
+    t0 = t0.square(); //         117: 1014120480182583521197362564288
+    t0 = t0.square(); //         118: 2028240960365167042394725128576
+    t0 = t0.square(); //         119: 4056481920730334084789450257152
+    t0 = t0.square(); //         120: 8112963841460668169578900514304
+    t0 = t0.square(); //         121: 16225927682921336339157801028608
+    t0 = t0.square(); //         122: 32451855365842672678315602057216
+    t0 = t0.square(); //         123: 64903710731685345356631204114432
+    t0 *= t17; //                124: 64903710731685345356631204115251
+    t0 = t0.square(); //         125: 129807421463370690713262408230502
+    t0 = t0.square(); //         126: 259614842926741381426524816461004
+    t0 = t0.square(); //         127: 519229685853482762853049632922008
+    t0 = t0.square(); //         128: 1038459371706965525706099265844016
+    t0 = t0.square(); //         129: 2076918743413931051412198531688032
+    t0 = t0.square(); //         130: 4153837486827862102824397063376064
+    t0 = t0.square(); //         131: 8307674973655724205648794126752128
+    t0 = t0.square(); //         132: 16615349947311448411297588253504256
+    t0 = t0.square(); //         133: 33230699894622896822595176507008512
+    t0 = t0.square(); //         134: 66461399789245793645190353014017024
+    t0 = t0.square(); //         135: 132922799578491587290380706028034048
+    t0 = t0.square(); //         136: 265845599156983174580761412056068096
+    t0 *= t17; //                137: 265845599156983174580761412056068915
+    t0 = t0.square(); //         138: 531691198313966349161522824112137830
+    t0 = t0.square(); //         139: 1063382396627932698323045648224275660
+    t0 = t0.square(); //         140: 2126764793255865396646091296448551320
+    t0 = t0.square(); //         141: 4253529586511730793292182592897102640
+    t0 = t0.square(); //         142: 8507059173023461586584365185794205280
+    t0 = t0.square(); //         143: 17014118346046923173168730371588410560
+    t0 = t0.square(); //         144: 34028236692093846346337460743176821120
+    t0 = t0.square(); //         145: 68056473384187692692674921486353642240
+    t0 = t0.square(); //         146: 136112946768375385385349842972707284480
+    t0 = t0.square(); //         147: 272225893536750770770699685945414568960
+    t0 = t0.square(); //         148: 544451787073501541541399371890829137920
+    t0 = t0.square(); //         149: 1088903574147003083082798743781658275840
+    t0 *= t17; //                150: 1088903574147003083082798743781658276659
+    t0 = t0.square(); //         151: 2177807148294006166165597487563316553318
+    t0 = t0.square(); //         152: 4355614296588012332331194975126633106636
+    t0 = t0.square(); //         153: 8711228593176024664662389950253266213272
+    t0 = t0.square(); //         154: 17422457186352049329324779900506532426544
+    t0 = t0.square(); //         155: 34844914372704098658649559801013064853088
+    t0 = t0.square(); //         156: 69689828745408197317299119602026129706176
+    t0 = t0.square(); //         157: 139379657490816394634598239204052259412352
+    t0 = t0.square(); //         158: 278759314981632789269196478408104518824704
+    t0 = t0.square(); //         159: 557518629963265578538392956816209037649408
+    t0 = t0.square(); //         160: 1115037259926531157076785913632418075298816
+    t0 = t0.square(); //         161: 2230074519853062314153571827264836150597632
+    t0 = t0.square(); //         162: 4460149039706124628307143654529672301195264
+    t0 *= t17; //                163: 4460149039706124628307143654529672301196083
+    t0 = t0.square(); //         164: 8920298079412249256614287309059344602392166
+    t0 = t0.square(); //         165: 17840596158824498513228574618118689204784332
+    t0 = t0.square(); //         166: 35681192317648997026457149236237378409568664
+    t0 = t0.square(); //         167: 71362384635297994052914298472474756819137328
+    t0 = t0.square(); //         168: 142724769270595988105828596944949513638274656
+    t0 = t0.square(); //         169: 285449538541191976211657193889899027276549312
+    t0 = t0.square(); //         170: 570899077082383952423314387779798054553098624
+    t0 = t0.square(); //         171: 1141798154164767904846628775559596109106197248
+    t0 = t0.square(); //         172: 2283596308329535809693257551119192218212394496
+    t0 = t0.square(); //         173: 4567192616659071619386515102238384436424788992
+    t0 = t0.square(); //         174: 9134385233318143238773030204476768872849577984
+    t0 = t0.square(); //         175: 18268770466636286477546060408953537745699155968
+    t0 *= t17; //                176: 18268770466636286477546060408953537745699156787
+    t0 = t0.square(); //         177: 36537540933272572955092120817907075491398313574
+    t0 = t0.square(); //         178: 73075081866545145910184241635814150982796627148
+    t0 = t0.square(); //         179: 146150163733090291820368483271628301965593254296
+    t0 = t0.square(); //         180: 292300327466180583640736966543256603931186508592
+    t0 = t0.square(); //         181: 584600654932361167281473933086513207862373017184
+    t0 = t0.square(); //         182: 1169201309864722334562947866173026415724746034368
+    t0 = t0.square(); //         183: 2338402619729444669125895732346052831449492068736
+    t0 = t0.square(); //         184: 4676805239458889338251791464692105662898984137472
+    t0 = t0.square(); //         185: 9353610478917778676503582929384211325797968274944
+    t0 = t0.square(); //         186: 18707220957835557353007165858768422651595936549888
+    t0 = t0.square(); //         187: 37414441915671114706014331717536845303191873099776
+    t0 = t0.square(); //         188: 74828883831342229412028663435073690606383746199552
+    t0 *= t17; //                189: 74828883831342229412028663435073690606383746200371
+    t0 = t0.square(); //         190: 149657767662684458824057326870147381212767492400742
+    t0 = t0.square(); //         191: 299315535325368917648114653740294762425534984801484
+    t0 = t0.square(); //         192: 598631070650737835296229307480589524851069969602968
+    t0 = t0.square(); //         193: 1197262141301475670592458614961179049702139939205936
+    t0 = t0.square(); //         194: 2394524282602951341184917229922358099404279878411872
+    t0 = t0.square(); //         195: 4789048565205902682369834459844716198808559756823744
+    t0 = t0.square(); //         196: 9578097130411805364739668919689432397617119513647488
+    t0 = t0.square(); //         197: 19156194260823610729479337839378864795234239027294976
+    t0 = t0.square(); //         198: 38312388521647221458958675678757729590468478054589952
+    t0 = t0.square(); //         199: 76624777043294442917917351357515459180936956109179904
+    t0 = t0.square(); //         200: 153249554086588885835834702715030918361873912218359808
+    t0 = t0.square(); //         201: 306499108173177771671669405430061836723747824436719616
+    t0 *= t17; //                202: 306499108173177771671669405430061836723747824436720435
+    t0 = t0.square(); //         203: 612998216346355543343338810860123673447495648873440870
+    t0 = t0.square(); //         204: 1225996432692711086686677621720247346894991297746881740
+    t0 = t0.square(); //         205: 2451992865385422173373355243440494693789982595493763480
+    t0 = t0.square(); //         206: 4903985730770844346746710486880989387579965190987526960
+    t0 = t0.square(); //         207: 9807971461541688693493420973761978775159930381975053920
+    t0 = t0.square(); //         208: 19615942923083377386986841947523957550319860763950107840
+    t0 = t0.square(); //         209: 39231885846166754773973683895047915100639721527900215680
+    t0 = t0.square(); //         210: 78463771692333509547947367790095830201279443055800431360
+    t0 = t0.square(); //         211: 156927543384667019095894735580191660402558886111600862720
+    t0 = t0.square(); //         212: 313855086769334038191789471160383320805117772223201725440
+    t0 = t0.square(); //         213: 627710173538668076383578942320766641610235544446403450880
+    t0 = t0.square(); //         214: 1255420347077336152767157884641533283220471088892806901760
+    t0 *= t17; //                215: 1255420347077336152767157884641533283220471088892806902579
+    t0 = t0.square(); //         216: 2510840694154672305534315769283066566440942177785613805158
+    t0 = t0.square(); //         217: 5021681388309344611068631538566133132881884355571227610316
+    t0 = t0.square(); //         218: 10043362776618689222137263077132266265763768711142455220632
+    t0 = t0.square(); //         219: 20086725553237378444274526154264532531527537422284910441264
+    t0 = t0.square(); //         220: 40173451106474756888549052308529065063055074844569820882528
+    t0 = t0.square(); //         221: 80346902212949513777098104617058130126110149689139641765056
+    t0 = t0.square(); //         222: 160693804425899027554196209234116260252220299378279283530112
+    t0 = t0.square(); //         223: 321387608851798055108392418468232520504440598756558567060224
+    t0 = t0.square(); //         224: 642775217703596110216784836936465041008881197513117134120448
+    t0 = t0.square(); //         225: 1285550435407192220433569673872930082017762395026234268240896
+    t0 = t0.square(); //         226: 2571100870814384440867139347745860164035524790052468536481792
+    t0 = t0.square(); //         227: 5142201741628768881734278695491720328071049580104937072963584
+    t0 *= t17; //                228: 5142201741628768881734278695491720328071049580104937072964403
+    t0 = t0.square(); //         229: 10284403483257537763468557390983440656142099160209874145928806
+    t0 = t0.square(); //         230: 20568806966515075526937114781966881312284198320419748291857612
+    t0 = t0.square(); //         231: 41137613933030151053874229563933762624568396640839496583715224
+    t0 = t0.square(); //         232: 82275227866060302107748459127867525249136793281678993167430448
+    t0 = t0.square(); //         233: 164550455732120604215496918255735050498273586563357986334860896
+    t0 = t0.square(); //         234: 329100911464241208430993836511470100996547173126715972669721792
+    t0 = t0.square(); //         235: 658201822928482416861987673022940201993094346253431945339443584
+    t0 = t0.square(); //         236: 1316403645856964833723975346045880403986188692506863890678887168
+    t0 = t0.square(); //         237: 2632807291713929667447950692091760807972377385013727781357774336
+    t0 = t0.square(); //         238: 5265614583427859334895901384183521615944754770027455562715548672
+    t0 = t0.square(); //         239: 10531229166855718669791802768367043231889509540054911125431097344
+    t0 = t0.square(); //         240: 21062458333711437339583605536734086463779019080109822250862194688
+    t0 *= t17; //                241: 21062458333711437339583605536734086463779019080109822250862195507
+    t0 = t0.square(); //         242: 42124916667422874679167211073468172927558038160219644501724391014
+    t0 = t0.square(); //         243: 84249833334845749358334422146936345855116076320439289003448782028
+    t0 = t0.square(); //         244: 168499666669691498716668844293872691710232152640878578006897564056
+    t0 = t0.square(); //         245: 336999333339382997433337688587745383420464305281757156013795128112
+    t0 = t0.square(); //         246: 673998666678765994866675377175490766840928610563514312027590256224
+    t0 = t0.square(); //         247: 1347997333357531989733350754350981533681857221127028624055180512448
+    t0 = t0.square(); //         248: 2695994666715063979466701508701963067363714442254057248110361024896
+    t0 = t0.square(); //         249: 5391989333430127958933403017403926134727428884508114496220722049792
+    t0 = t0.square(); //         250: 10783978666860255917866806034807852269454857769016228992441444099584
+    t0 = t0.square(); //         251: 21567957333720511835733612069615704538909715538032457984882888199168
+    t0 = t0.square(); //         252: 43135914667441023671467224139231409077819431076064915969765776398336
+    t0 = t0.square(); //         253: 86271829334882047342934448278462818155638862152129831939531552796672
+    t0 = t0.square(); //         254: 172543658669764094685868896556925636311277724304259663879063105593344
+    t0 *= t18; //                255: 172543658669764094685868896556925636311277724304259663879063105594975
+    t0 = t0.square(); //         256: 345087317339528189371737793113851272622555448608519327758126211189950
+    t0 = t0.square(); //         257: 690174634679056378743475586227702545245110897217038655516252422379900
+    t0 = t0.square(); //         258: 1380349269358112757486951172455405090490221794434077311032504844759800
+    t0 = t0.square(); //         259: 2760698538716225514973902344910810180980443588868154622065009689519600
+    t0 = t0.square(); //         260: 5521397077432451029947804689821620361960887177736309244130019379039200
+    t0 = t0.square(); //         261: 11042794154864902059895609379643240723921774355472618488260038758078400
+    t0 = t0.square(); //         262: 22085588309729804119791218759286481447843548710945236976520077516156800
+    t0 = t0.square(); //         263: 44171176619459608239582437518572962895687097421890473953040155032313600
+    t0 = t0.square(); //         264: 88342353238919216479164875037145925791374194843780947906080310064627200
+    t0 = t0.square(); //         265: 176684706477838432958329750074291851582748389687561895812160620129254400
+    t0 = t0.square(); //         266: 353369412955676865916659500148583703165496779375123791624321240258508800
+    t0 *= t20; //                267: 353369412955676865916659500148583703165496779375123791624321240258510847
+    t0 = t0.square(); //         268: 706738825911353731833319000297167406330993558750247583248642480517021694
+    t0 = t0.square(); //         269: 1413477651822707463666638000594334812661987117500495166497284961034043388
+    t0 = t0.square(); //         270: 2826955303645414927333276001188669625323974235000990332994569922068086776
+    t0 = t0.square(); //         271: 5653910607290829854666552002377339250647948470001980665989139844136173552
+    t0 = t0.square(); //         272: 11307821214581659709333104004754678501295896940003961331978279688272347104
+    t0 = t0.square(); //         273: 22615642429163319418666208009509357002591793880007922663956559376544694208
+    t0 = t0.square(); //         274: 45231284858326638837332416019018714005183587760015845327913118753089388416
+    t0 = t0.square(); //         275: 90462569716653277674664832038037428010367175520031690655826237506178776832
+    t0 = t0.square(); //         276: 180925139433306555349329664076074856020734351040063381311652475012357553664
+    t0 = t0.square(); //         277: 361850278866613110698659328152149712041468702080126762623304950024715107328
+    t0 = t0.square(); //         278: 723700557733226221397318656304299424082937404160253525246609900049430214656
+    t0 *= t19; //                279: 723700557733226221397318656304299424082937404160253525246609900049430216697
+    t0 = t0.square(); //         280: 1447401115466452442794637312608598848165874808320507050493219800098860433394
+    t0 = t0.square(); //         281: 2894802230932904885589274625217197696331749616641014100986439600197720866788
+    t0 = t0.square(); //         282: 5789604461865809771178549250434395392663499233282028201972879200395441733576
+    t0 = t0.square(); //         283: 11579208923731619542357098500868790785326998466564056403945758400790883467152
+    t0 = t0.square(); //         284: 23158417847463239084714197001737581570653996933128112807891516801581766934304
+    t0 = t0.square(); //         285: 46316835694926478169428394003475163141307993866256225615783033603163533868608
+    t0 * t9 //                   286: 46316835694926478169428394003475163141307993866256225615783033603163533868665
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{Field, UniformRand};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_alpha() {
+        for _ in 0..100 {
+            let a = Felt::rand(&mut OsRng);
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+        }
+    }
+}
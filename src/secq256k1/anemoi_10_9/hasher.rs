@@ -0,0 +1,2677 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U9>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x9bac8ffbbe0c9d17,
+                0xcff66cf3759608c3,
+                0xbd0d548cd9dcb39c,
+                0x6feefda3fbe73174,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x0ecf972c78f57f18,
+                    0x1d2ecce9d338136c,
+                    0x43d8ca76c02dc605,
+                    0xdb5d1ea0e766162e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54df6c1dcadb2c0d,
+                    0x473d3a9eb037a2f2,
+                    0x8e23942d100e3811,
+                    0xb1e978068ce0fcf9,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6d82409582f85772,
+                    0x84a32a340d31f580,
+                    0xeca4bb8375da47d9,
+                    0xfff76dba90967abe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x09014f5f46510c47,
+                    0x0159e32838592f6b,
+                    0xd5198c4ba634ac0f,
+                    0xae914b9a2b78563d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e634e25479c9447,
+                    0x5cb3e31670340463,
+                    0x6ae18aa57636e2fc,
+                    0x38867cdbf6402518,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5d2f78b95c59c236,
+                    0xac546d610f272246,
+                    0x998a1d63c11f1469,
+                    0x638e1ed4dd7136a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x242ade0495a39beb,
+                    0x3ea0645ab0ea34af,
+                    0xfba2d06385047867,
+                    0x0888d99d9f380c1a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9f2bfa3d0f0ce5e,
+                    0xe8acceaafa5b7dfb,
+                    0xf453ee67691eb2e2,
+                    0x05e48a4f39ee4bef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ad610cc03c7ac99,
+                    0xdfebfb3d25b57f99,
+                    0x3a8270423d5b644e,
+                    0xdb9dd67a99dfdd31,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x60cbed9cc6d9e3fc,
+                    0x8ebf494997bc1908,
+                    0x8269cca5cfe21476,
+                    0x819ca4cce584460e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7a6a5af66f49055,
+                    0x795b5967f2b318c4,
+                    0xe8b0fd84eda0a4c1,
+                    0xcdbdc9b25cdb248e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb647d249274b0460,
+                    0x9c6acd29034e102f,
+                    0xcecff87c04120a45,
+                    0xb898f5a38ac95181,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa57ea4e5e3fc7524,
+                    0xa4c3b032b1b75a49,
+                    0x29143299147639eb,
+                    0x42e95cfbb7c5615c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeff3ada288c7012b,
+                    0x565ed6c79df25d97,
+                    0x8e5a2f2ea9be39c8,
+                    0xa467a76d5211667d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x20a695a1dd1d6a43,
+                    0x8b3bf2c4aaf0c511,
+                    0x55ccdb2cc51a7b75,
+                    0xbd97c34867ae0a3f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fa405cf53c93064,
+                    0xd873802818936bab,
+                    0x5cf9a219fa4152d7,
+                    0x2574e161e59a4fcc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4e70250753d4edd,
+                    0x9ca1ab5010c5d551,
+                    0xf71f5c7b685237ae,
+                    0x878bbeeee729aef1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaea2b0dc91e7664c,
+                    0x084db06c95032619,
+                    0xff34ded992baed89,
+                    0x03327b3fbd52394e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19ca399868d05039,
+                    0xe61da79c00c0f9d8,
+                    0x4f1e08e685b696e6,
+                    0x5a988b300b0bc9e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf3b6fa6366b844c,
+                    0x1a4daa6504aa8bad,
+                    0x71283763f8879ad1,
+                    0x05cbc8860d7f8d0a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x9e3ee9479f5afa02,
+                0xf3592b7f1937a10a,
+                0x0e5f72d1d772b7ac,
+                0xe9003748be1b8da6,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd6777b77bd2e9a87,
+                0xea3ab65fa0f997d8,
+                0x72fc572f03a4cd2d,
+                0x46d50f07e8ba0ea8,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc81a79a8366d67b2,
+                0x32522bbcba85fea7,
+                0xe6e4d4874f87ad0a,
+                0x52faf0abd35dd4df,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x30a5adcc7797aa01,
+                0xb134235c518cf629,
+                0x38508552f747261b,
+                0xe2a8b9478a7b71ef,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xfbf14fbf26ff4e15,
+                0x80d3cf839fd1ec9b,
+                0xd7bbc62324983711,
+                0x2552df7bc120fb0a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xde7191e1e862989d,
+                0x66fa5a60ced1a958,
+                0x939bbf542010fe8d,
+                0xdc25d6ec01a78686,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x2c81baa296918122,
+                0x32c0fe7d9fc17dfd,
+                0xefc5cf1dc1352a25,
+                0x783827771a86a034,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4b312fdbb9e4b311,
+                0xaa823eb207d8da51,
+                0xd844eb6ba873dc10,
+                0xbe3828ab4151b7b3,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa8c74ac310c9c910,
+                0xeeb709b4d0c5bad5,
+                0xd8971c6bda097ecc,
+                0x0d080a19b2168f8c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x2eb808f6d438e49b,
+                0xde94199141a40bc4,
+                0x74352a32dce51ae1,
+                0x2d40cf30054c8344,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x9e3ee9479f5afa02,
+                0xf3592b7f1937a10a,
+                0x0e5f72d1d772b7ac,
+                0xe9003748be1b8da6,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd6777b77bd2e9a87,
+                0xea3ab65fa0f997d8,
+                0x72fc572f03a4cd2d,
+                0x46d50f07e8ba0ea8,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc81a79a8366d67b2,
+                0x32522bbcba85fea7,
+                0xe6e4d4874f87ad0a,
+                0x52faf0abd35dd4df,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x30a5adcc7797aa01,
+                0xb134235c518cf629,
+                0x38508552f747261b,
+                0xe2a8b9478a7b71ef,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 310];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x198d36be514e46ba,
+                    0x6c5c719902b2f728,
+                    0xa9a16e0b92086d98,
+                    0x60488e4d5dd8a421,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdca7fe04b4f0accd,
+                    0x79692fee9f697114,
+                    0xf8f454920c04539a,
+                    0x4e242daa5721268f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62e6b4b58fff8897,
+                    0xf6e6a22dcdf071d3,
+                    0x8269ec6f49f9f92b,
+                    0x22558ccabf2a9ec3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd8adaa0a6b592f6,
+                    0x6aec67ed844f00e3,
+                    0xa84db53c73c717d8,
+                    0x5f603cca392f87e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8cad5149f08c139f,
+                    0xe3405971e2f468e3,
+                    0x45c5184af0f02f3c,
+                    0xc8543f07065a21c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6498499e742e886,
+                    0x0dbb3b23eadcbd9d,
+                    0x04ca7e30fc06e8cf,
+                    0xbbd23d03f520baa9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16436415f3e0e9ec,
+                    0xc23acdcec22d92e9,
+                    0x124d99b426ef33a8,
+                    0x480ee7b1f93ebe83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbaaf0f2b44a4cc43,
+                    0x8ed8f54a6a7eba26,
+                    0x2ef78a6c374410d6,
+                    0x34ab285a9e9d4c8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f2dd12ee7198339,
+                    0xbd9a981e86ca5559,
+                    0xa6c5c31682a0e36e,
+                    0xdea885a7be31260e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x326ef17fed51958f,
+                    0x4533ee846f76c804,
+                    0xb7245eb6cadaec8b,
+                    0xb0d17030a764f253,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4761ba540e52aa46,
+                    0x1a5dfa5633bcda04,
+                    0x01a377122a6c600d,
+                    0x0101e552b08824b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb57ddca54bb0f40e,
+                    0x34c6c850728bfe4a,
+                    0xac5d86255df6037c,
+                    0x03d203c400fa427a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc44caaeff41df480,
+                    0x45567c53a77abe40,
+                    0x930f5d61f796cd70,
+                    0x2992767513828f20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f140dd4c16b81b2,
+                    0x58e9573d57e53416,
+                    0x9ab5b9497f8b0ad5,
+                    0xe50c7bbb2fd0f2b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2139364ce24499e,
+                    0x08df5bf6a0c4e21f,
+                    0xd7cf6de0ce0ab646,
+                    0x638e2fa7c23f965e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x691dd8749c2b4417,
+                    0x5e5fc4db897976b4,
+                    0xd427cd160eb84fe7,
+                    0xded12c59e8b61d2d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91432c57f924d2f6,
+                    0xd404edafff17cb3c,
+                    0xbdf03d43f654890a,
+                    0x500b203100d490bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85dc762df7636eab,
+                    0x8841b512439d8774,
+                    0xc20ad57d3df9a071,
+                    0x7db5402553729f93,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x312baafae57cffe8,
+                    0xf0470256f8f66e81,
+                    0x06c06efd4e8daffe,
+                    0x0a57ca19e026f2dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe650a900f898b7ab,
+                    0xf5679668ced557f4,
+                    0x09fc6132fe184d5f,
+                    0xc332089d719f2eff,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x58e7a2ef55ca0403,
+                    0x17c2391414a24dfe,
+                    0x396a913b35bd8e67,
+                    0xd6134e101a7d21c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef8fc6732d49b05d,
+                    0xc82eea90d7922a81,
+                    0x0828ed932abd744a,
+                    0xfdef761061c23d83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15a77754f1825009,
+                    0xd7bb42895b559d11,
+                    0x75299e3393b0511a,
+                    0x1c12a6bf1f08d00c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6dbdc85ab4d62d80,
+                    0x7ae254a1ed44751a,
+                    0x2b2b136e0f9129d5,
+                    0xf630dd26b2c3a36a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd5d0fa876b1e3d95,
+                    0xd65a63289817233b,
+                    0x679e4238e7259e33,
+                    0x6862de6b2341ebf7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x935e2441eddf09f1,
+                    0xafb2a3463dd55008,
+                    0xa0e4407454dbf883,
+                    0x45f8860438774276,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce2267160d970f3e,
+                    0xc51b40f2bab32ee1,
+                    0xe756bf55cfe6a229,
+                    0x2370ee12914a64b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc44f868dacd2e3f3,
+                    0x45b6b7b3b05afdfc,
+                    0x2dde8b202990e1a6,
+                    0x973c3789c5929d67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e3ef950275def97,
+                    0x26b3d1c129661094,
+                    0xf1bbd3c46c8f3891,
+                    0xd39f07650b0db736,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe740f2895411f44,
+                    0x31b03f01fc424c47,
+                    0xb5c2f5b329e9dfd2,
+                    0x90a732f5195e1706,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2c19b8db24d6bea5,
+                    0x92af33669f7418a3,
+                    0xbe9138dfb78af4e6,
+                    0x996c367732651847,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5188e9ae12a51a5,
+                    0x085542dc44e5fd69,
+                    0x2193b0583e5b7fd1,
+                    0xca26db7086aa16e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81e28c265e092b77,
+                    0x5c275f9097d55749,
+                    0x66db4fa67cdca66a,
+                    0x98e21bdc578338b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cedd00069735441,
+                    0x534a58beca47cd2f,
+                    0x7d0ec50d536ab9a5,
+                    0x7b8c3d7e20fd58a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04466e34cd879d53,
+                    0x13c60dc024f56b4c,
+                    0xda983d3592ff6ae1,
+                    0xec535f884f6d871a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc2fa9ce14086833d,
+                    0x0519bcf4c3c18692,
+                    0xbd8271d02719652a,
+                    0xb102efab383c518c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5ea99a8e4392888,
+                    0x1a0a1ea56846d087,
+                    0xa05d1133b7f8ed4d,
+                    0x41f5145f58caeb15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82e55259e9733129,
+                    0x47ad5335f6bd0bc0,
+                    0x1504901e2403c0c5,
+                    0x9de71635204ef18c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd841fa76f25b607,
+                    0xdbd7732291f9b118,
+                    0x48cc215433793f41,
+                    0x07827623f5b56b91,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf329b9b04c33e6e5,
+                    0x9444fa9d9870ccac,
+                    0x8c9a5f5c2bdaba92,
+                    0x1d868ff85d5117f8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4a4bb4308739230d,
+                    0xdd36776281097004,
+                    0xcc5678e625cd6709,
+                    0x834880e957ddbd53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x603d8cb91345c051,
+                    0x6b8daa02d370396c,
+                    0xd88a4a930fe68d49,
+                    0x8cf7d15a2b3fdd33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e96d1e43c63cdcf,
+                    0xf1e1afa703f444b7,
+                    0xe80ae49ae41e8217,
+                    0x32220817d2baa8d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28f2721ded740124,
+                    0x28ebb774dc21b723,
+                    0xfa08cc4a6e4dee2d,
+                    0x01d7cb67f1cab70b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16ee9c5e118aae9c,
+                    0xabbdce574f17ca44,
+                    0x90109704e3590fd8,
+                    0xee015ae49ee7c3f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77ec0b49af13dbff,
+                    0xc78b19fbe0ceae64,
+                    0x0bf6c06a8321ff0f,
+                    0x16f03af9cbdf4bd2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18a71dca2f5a45cf,
+                    0x5b96fe552094df4e,
+                    0x930ff5ceb325eb00,
+                    0x89059d2d21ba3cc3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64c7a4e1dc8dd16a,
+                    0xd63013a961edfeb4,
+                    0x975c894e4cdb06c3,
+                    0x9ac2e5bbffa112b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x403a0a24c13032c7,
+                    0xfc5a061757e62f19,
+                    0x51b0fd317df79ff1,
+                    0x3b03ec1540766ecc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fc6318191ad13da,
+                    0x864f6215e38ec1e4,
+                    0x8590b8ea711861ce,
+                    0x52141fc5c0edd1af,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa3864ff78ee74c55,
+                    0x8cb082bd8731f9a0,
+                    0xb614a3adde1f3e46,
+                    0x3cdc9b0179533cd0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7af4bd68b44930e4,
+                    0xeba01a557730fb22,
+                    0x3e9dd9baa4955fe4,
+                    0xb977dd71d789cb89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfae506abf817e9ca,
+                    0xdd974912f39664ad,
+                    0xf3517f6a26072df8,
+                    0x22f55bb7fe2e7def,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb028ff3ca1644711,
+                    0x34163ca6beb6b9cb,
+                    0x3624f5db1974d38a,
+                    0x86d5f41a75266506,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1034a76dae990fc6,
+                    0x8eac7d13c3a4d362,
+                    0x86901ef5110fb9e5,
+                    0xfb2b95b1039994df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8a6276e46bd486f,
+                    0x7ad9b8ec2dbb3bdb,
+                    0x445ccebf383bfa00,
+                    0x1187e589a8346bd3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe625e3603b07e880,
+                    0xb3fc0e1687c7ca4b,
+                    0xd40e89c906b8b52b,
+                    0x581e0d81ebb8862d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f5552cea0544aaa,
+                    0xeb7d710eb6234519,
+                    0x113aedd76539e119,
+                    0x0fbf12ba3bd6078f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b192cf0ce08cbc9,
+                    0xedab8771110d63de,
+                    0xa91bb05d6fb5365c,
+                    0x8515c34e10287917,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d3a36242683fce8,
+                    0x9f7a973e308583b3,
+                    0x022ba80c20aa12a7,
+                    0x8d277b898604f751,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xd88c52bc8bf531ea,
+                    0x340effb236732ff8,
+                    0x8db358850cf174d3,
+                    0x9000b72136b59db5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x634daeb179ab2dad,
+                    0xd465c9f44fa10041,
+                    0x1b1a597cd309cdaa,
+                    0xaaf207a994ee1b46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc524d98375c71ea,
+                    0x97af848cf377a864,
+                    0x5d75efaa3b8bfa33,
+                    0x84452c8d07e2036c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4bbed8c86bc1a443,
+                    0x38d16f2faad318a6,
+                    0x5088ec9ade612ec0,
+                    0x7c38d233c96deacb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc168cbe7cfcb9f99,
+                    0x669e0c50df48ddf6,
+                    0x7edad8a47308da7a,
+                    0xaab3885f2b85379f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x27a7d710f3807e56,
+                    0x4b098cf50c9b55e2,
+                    0x5e4dc722c6d97e77,
+                    0xa45669c61b7523af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd4bac5820683008,
+                    0x56f5730fa5378b7a,
+                    0x3ba77f4dad00f66a,
+                    0x87069f8a90a76129,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e93d4e3e61938f8,
+                    0xa6bdce6379e93c02,
+                    0xf1cab38783f66e70,
+                    0x3ef5bc2091acf5a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x724ddb60f35fa91d,
+                    0x458622b9b8f1e735,
+                    0xff935c22ed1e7fd7,
+                    0xde53d855289c355b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9eef58828fd9fa8,
+                    0x4f4b1bdd424f1638,
+                    0x275ff188e1825ed9,
+                    0x459e883e2992ec33,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe8f438cde29e7183,
+                    0x7317ca8a22640f76,
+                    0x59973a2ec6e52a58,
+                    0x23a3943af97870e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7e26212121c2c3a,
+                    0xeae9895ba8565788,
+                    0x1daa22da5c7e6049,
+                    0x3b571e9a98f4908c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x675162d0c4bf5a56,
+                    0x06fb26515b0018e5,
+                    0xdda7cf7ace0ed66a,
+                    0x1503681d22aaf197,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78b1149d002f5c2f,
+                    0xe3eefe3685117297,
+                    0xcee6b2db1f17991b,
+                    0x87e1b58c7a69911b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb7390a4b4c5edad,
+                    0x1859844abf110710,
+                    0xd23aa3854a7b10f4,
+                    0x16c9a4f54bfcabbf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x39aa1a7e15d20217,
+                    0xa89b37421a5590d1,
+                    0xee5a6713b0649aa9,
+                    0x2f6e62da8b8ab08f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc976a8d66a607aaf,
+                    0x6e293d421877c11e,
+                    0xc3b05c255a7739d8,
+                    0xc1dcad25a3e60952,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fb2aa557da6cfc5,
+                    0x2ea0b88ffed1987f,
+                    0xf2a55632df073416,
+                    0xcab5000b5196e599,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b09aa5d21385c1e,
+                    0x114f969f5e8f846e,
+                    0xee17b5f9da9fb846,
+                    0x689b5f2fcfcb6b9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x961d718af13e1a66,
+                    0x6e8b8c9de68e635d,
+                    0x9f0d6ad2e838f84a,
+                    0x45ef1e851657627f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf50231663280ebda,
+                    0xa5e5b4c35bbb720f,
+                    0xb93786cb2371f8bd,
+                    0x1f666a2e8fbe8c12,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72e9920e44aae38b,
+                    0x13a9ffbdee3d6baa,
+                    0xece3b19b2f549010,
+                    0x12e31fec0255b463,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f34500dc1fb7390,
+                    0xb93e9b4931d9e87b,
+                    0x6c655ec2418c7e34,
+                    0xc463cda00d5b3a0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a321fd9eba38c91,
+                    0x739e503b0a4e4047,
+                    0xb3635016b09fb583,
+                    0x9812354752a2a66a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38a796574beb9917,
+                    0x31f278a46f030b3d,
+                    0x835eb97b1ae4b462,
+                    0x313f767cce281534,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdbe2678377ee44ea,
+                    0x2c32ced04fbd1c75,
+                    0x783fa4510b6d191c,
+                    0xb02b2e9708d7d553,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x160005c6e200be42,
+                    0x9afc14a288a3d528,
+                    0xb184bef64a31a4b6,
+                    0xb68c0a2f77248e9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14313c0eab3c158f,
+                    0x47325a6669b836ab,
+                    0x7cec941c628fc963,
+                    0x0059cb006672fe58,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7e7bc641afc9fcd,
+                    0xd5ac5db5dfe7d1dc,
+                    0x32716aa09f449d37,
+                    0x6a82b8fb89accabf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58ffef05a7c97af1,
+                    0x6315ac036923c790,
+                    0xc8b65435f1e88ff9,
+                    0x85f9038b8caa8758,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa64e8bf3149615fd,
+                    0x30f85647586adaad,
+                    0xfb9542330d531c3b,
+                    0x33ab685c77cad199,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e848c9f9e70ed90,
+                    0x41bb13dcfd1dd1ef,
+                    0xa4f56985b47d84b6,
+                    0xfaee3b5736e317ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5501164bb0be94bc,
+                    0x9db2b565f55365c7,
+                    0x27fcbd9b96379d25,
+                    0xc740e60ae36e3e6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd58d2b5b598292f,
+                    0x3faf1bb4b0c9f1b4,
+                    0x4128790b0e7f5f43,
+                    0x079204e4b26bd890,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61f2ff6e7057ae40,
+                    0x3c43464ef1b85fb4,
+                    0x1436af1819360a05,
+                    0xb46ba4d295c4558e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa673dc07c0e37a95,
+                    0x69969c91d46c2f68,
+                    0xadcfbfe7aad923f3,
+                    0xd0086576dd11df2c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5375e60c896dc066,
+                    0xeaf1c715d72dcb95,
+                    0x79a66c1496319043,
+                    0xc1971ec1a6b8768e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf677fb77c5885e25,
+                    0xe03fc18cffd61bae,
+                    0xcfb72837fc85bf56,
+                    0x5b18e8f23cba94ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x702dafdc7780a394,
+                    0x68b15a2a15817ad6,
+                    0xeb910903ab807ce9,
+                    0xd3c80c96d0b50d76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed5c6476ab627e93,
+                    0x34a17f841896cbcd,
+                    0xf762d9c515cf1a29,
+                    0x89cf384e63647a0b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xce6393c93e9f5372,
+                    0x637f1addc0e67595,
+                    0xb5c0b7fdbcc9045c,
+                    0xa5c845eb1b0dc87e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa00cbc60cc2b5726,
+                    0xd07cdf098f8f68db,
+                    0x8526e72c2e836f81,
+                    0xeb9b4cdb0937b767,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00bf44170d99cbef,
+                    0xaa7f2027ea332efb,
+                    0x44fd7b6f2fc01b59,
+                    0x2fde1eb9960f639e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1d02a10f362ff66,
+                    0x40fc2bddae93ce3b,
+                    0x4ce6485900be4906,
+                    0x8a039f6dc0190c3c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2d7c45417f66f6e,
+                    0xc48cb3f73fee5d54,
+                    0xe82a3037eabe3ade,
+                    0xb0ab65be2d69fa82,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa54bc9086e8857a0,
+                    0x5f5b2a9bf1e75abc,
+                    0x87621b452816c10b,
+                    0x1e01c1d6e8cf68c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdaa0b5e58f0aeea8,
+                    0xe9727d27846868ad,
+                    0x3b45fa71b350ce6d,
+                    0xa7cd077321f39cac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25e9653b0a8dea45,
+                    0x6e55499c8ad9a074,
+                    0xa8bb85ffbeda8c23,
+                    0x59e3d7299161628f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed53e9c07dbfb198,
+                    0x62a8e2734604aa4d,
+                    0xa3e540941667de0d,
+                    0xfa69df689a1a06df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x650e6af984076c9d,
+                    0xf693db7667fdb501,
+                    0xbf918f3a0d2802de,
+                    0xf79c6e9b9e227bd8,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4c811f7f211f0511,
+                    0xa054d9998790beaf,
+                    0xaf8a8d3e4a5de8da,
+                    0x5ab0767bd1e7acc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5461eae563b78518,
+                    0x98e12e28d7bb92d0,
+                    0xed31bf4fc325a465,
+                    0x4274dc8f86fdf57e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f78dacb62dad597,
+                    0x72116c7741915bb7,
+                    0x28fc88c2b2e3d12d,
+                    0x5f3068622af47342,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x879cb50e7d1e3066,
+                    0xeb627ad2578d09a8,
+                    0x736ad674a4249b8d,
+                    0x5f21f481cf87a972,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa07cbb6f0a2fa1dc,
+                    0xfc8c5bc0cbf9fbe6,
+                    0xb806b88c21762469,
+                    0x13c2f09f6357b49b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf143ec4c8c1c4454,
+                    0xff0455ee78ec5cf3,
+                    0xb4fcc7ec1b892177,
+                    0x87767f858c75d8a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7ba21fe8318b83d,
+                    0xd990a2449e7573b1,
+                    0xb34d84af618fbf8f,
+                    0x623dfe54ce0f9b1f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c9a23127841f8c8,
+                    0x16a8d84ee8b9918a,
+                    0xc6c6aa91249d1a3e,
+                    0x5907e86f4b8a0495,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3de7e700128cc083,
+                    0x5170bdb038187fef,
+                    0xef6e866786bb677a,
+                    0x5453af0ff742ae9d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa450dcb69891c89c,
+                    0x33e5417831051345,
+                    0xd488d3f995cf6ad1,
+                    0x5e77fca31b4827a2,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x83cbc26e2020320a,
+                    0x083143af718b8caf,
+                    0xbcdf615d95f007f3,
+                    0x34ca08b17a2886bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3616f88e1c57252,
+                    0x4cb12c16fc2bcb7e,
+                    0x667133c9fdf0fe92,
+                    0xdf8cb636c19c6d4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ee1a74d5a561349,
+                    0x908545cb8b9665ef,
+                    0x41b6393d6b2649c8,
+                    0xd5412d3958c05b9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7c0d3434b10487c,
+                    0x68299be396329311,
+                    0xbd8821c4672b0824,
+                    0x481e16f2f4792660,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9010948dd761db9f,
+                    0xf328a5f4d2313752,
+                    0x3c36cfb3eaee6f36,
+                    0x9f1938ef660713a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20bf2fa2068f24c2,
+                    0xe2d6ae5a49418477,
+                    0x0b652475f6d115f8,
+                    0xce4f5855294ccf01,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9154dddd80dcd2fc,
+                    0x9502822695b95a96,
+                    0xa550f014d1b30ca6,
+                    0x03d28f457d902193,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb744aa87462a5b86,
+                    0x951cb894e486bdf2,
+                    0x78bb371780651809,
+                    0x0e02c468e1cca948,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf40540ca550389a,
+                    0x0f4d057b423c85af,
+                    0x4ca3c092afa4f07f,
+                    0x8fb13302fbb4f000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc3b498e468c47b4,
+                    0xd9564ca925749c04,
+                    0xef593c3e8f4d72c0,
+                    0x2c57a78509ae89a9,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3b8a32c562a9d9fd,
+                    0x545d2cddae6eff4d,
+                    0xda91f20e470b7166,
+                    0x5319599ec0d338da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38e221415b5e649d,
+                    0x71a392d3a868ef5e,
+                    0x3ac353123066b520,
+                    0xb912554dcb97df5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7dfb84ed8819f889,
+                    0xd59565f1206c692f,
+                    0xd84ccedd2d2010d4,
+                    0xd4429675756e8367,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4858a9195a7fec74,
+                    0x64e218fdc4447804,
+                    0xbf596ba19798539b,
+                    0x5717a53e5b651ff3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5282054d92c2d681,
+                    0x95670f26e62f6e4c,
+                    0x8ce6c61b50a742d5,
+                    0xe7b9a718d81f6449,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x853dbdf417052f0f,
+                    0xda1b941687b65d11,
+                    0x8311f59aa2057788,
+                    0xa37d67473999bcfe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x701fc78cb0e8084f,
+                    0x1fa6325f3ffce623,
+                    0xf5a6ed3de4b3e826,
+                    0x0e69958e4048743a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc3a94182b70bd2a,
+                    0xe159917b3c492ea4,
+                    0x9e0f87639f3f6a9d,
+                    0x5ee254b0b6e97cf8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96db2a1cca2dbefa,
+                    0x9105e208e62fc322,
+                    0xa976ad6675c019e9,
+                    0xfb4bdcf0dc4536c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b93193a0f80d8ae,
+                    0x44e1a9c3263632f9,
+                    0x1bab12206b7b7bf9,
+                    0x491b56f98d29d4e5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xedfeecdd408f6fd0,
+                    0xfeb9f95dceac118f,
+                    0x8f421c0397d06dfc,
+                    0xcd0e529c6df357a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x267d06284d43abf8,
+                    0x8dfe8e23a8aa2f04,
+                    0xb22a186b032a0026,
+                    0xb98eb7c205616bd1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2280961e39838d3,
+                    0x3771f8bfc6f60e52,
+                    0x10f14196a1c6c870,
+                    0x6d33eadebd057273,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7e54d3179223d73,
+                    0x35af043e403ca5a9,
+                    0x899eeb9d8414ee0c,
+                    0x9402fb10e25fe039,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2dd6dc876687836,
+                    0xe0ce6376d77b6868,
+                    0xe962b6b5c97d6429,
+                    0xe0c3b3ecd0faf2de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc71928a89d89092f,
+                    0xc1937ed5c0397b6f,
+                    0x7051d7c6943c0fa5,
+                    0xe910f7262df775b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e0fa173218dc9d6,
+                    0x16c1f6dff8690f37,
+                    0x1292840a6c681571,
+                    0xd55d8f33f0375dfd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5080c31b1883d2ec,
+                    0xe0983ac8f1362a76,
+                    0xb5ebe507e07c6ad2,
+                    0x31803ce0bf7eeba4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1a0904be02d3bf8,
+                    0xf42393dfe3e4d737,
+                    0x09f19aa1eb4e5a24,
+                    0x4ec3d7540d983122,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0dd7563b4d74cc4,
+                    0x8cf687bb8328204f,
+                    0xc7046883606fbb9f,
+                    0x77b7c7b4e352ae6d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xacb1e0156d110204,
+                    0x9165093e0370269a,
+                    0xf745ab7fb646b948,
+                    0xb686c88e2ab06245,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9bd7ecb1fe4acb68,
+                    0x785868d2b5140701,
+                    0x8f315cb8e3e1700e,
+                    0x014523ac32897432,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x809afb9a052b0d99,
+                    0xc43a6757d6e50975,
+                    0xfa0b3cdb43eae886,
+                    0x9d01112b7619761a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89058a0a953432a2,
+                    0x19b10915a794d157,
+                    0x6de203eac405704c,
+                    0xfee3d29a64a35082,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82a001c64ca1c9b,
+                    0x8c4db991a991917f,
+                    0x0a9e0a9e0f6538e1,
+                    0x3ed9abdbcb4eb069,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2dfc553d0ccc93d3,
+                    0x8b711d2d71673897,
+                    0xbc50458cb8983ed4,
+                    0x23172ad5e752b82f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76e09dfcc5cc7672,
+                    0xc8399fc96d7b4fe9,
+                    0x7eea935bf4256469,
+                    0xaefa8a58902fbab5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa85af3a28b64b2f,
+                    0xc059ccb35b30ca1a,
+                    0x7ab4b17870292334,
+                    0xc8c2a44dfc371ac1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb27f5de8e285ed2c,
+                    0x7dae40be1facb6d8,
+                    0x75ce9031b1cf7890,
+                    0x06834436308c7d0a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf15a5d488343e8f6,
+                    0x1fbf5b077383198a,
+                    0x42f5853084e76b20,
+                    0x56a034cc4de19398,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7a98c2f4bdf7210f,
+                    0x79be31422c4cd800,
+                    0xa722660a5d709d97,
+                    0x125d3d14b2be89d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6708dfe981a1636c,
+                    0x16528b6c7c871630,
+                    0x788c7f5e93cf217c,
+                    0xf3d080772039733e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15bb648b7f05d232,
+                    0x062793e5152c0c41,
+                    0xdf6875ffb4536154,
+                    0x09ff6cf4984f2147,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42540af1cb24bb33,
+                    0x78a1003776bbc4bf,
+                    0x9e66b101f79fbba7,
+                    0xd81cc6b8dfed2553,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x732027572d43b81d,
+                    0x311bbb68ed2d47db,
+                    0x60a87254c98a0f34,
+                    0x7d1e4bc2412729b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60e9cd704fdfc9fd,
+                    0xab913800f24a7a00,
+                    0x86e03d9184ed6626,
+                    0xfdd94aa6fc0a7ce6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabff972d7f9ae0c1,
+                    0xad3ab7d108f245cc,
+                    0x7aefc98db7127d40,
+                    0x347099c0a896eb99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b4a8008eae4d1a2,
+                    0xa529ceebfd9299da,
+                    0xeb9b9c79e70226ab,
+                    0x2057f0e36a151873,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c74a39ef1ace46c,
+                    0x9c6a8ea51e2d6a92,
+                    0x054d10a0755d0f90,
+                    0xdec4250ba98c1b5d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d9d594042ea6764,
+                    0xc675fb7ab0c60ef4,
+                    0x231a62a8a51b67a2,
+                    0x9d2f87f4daed1d78,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xa5af369cd81d92db,
+                0xca360fe6a5168ec4,
+                0xd5a766eb6cf145ee,
+                0xe62445eac878ded2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x601f6c1c75f2ad99,
+                0x68305331c86bda56,
+                0xb2b347a3c671c205,
+                0x8e4526048ff89c0c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8c7a44659e3900ae,
+                0xa6961fd1ba945951,
+                0xf60a82e45b050b1d,
+                0x12a975747b7e2fe7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1455cc786fe3408d,
+                0x4fe29684182b91c4,
+                0x31d53a38acbbb92b,
+                0x6a8a8dc0672a6d97,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x0d9116b30d29d3e4,
+                0xbf805cb9c144e025,
+                0x822eda5773aaa830,
+                0x2e2c6228ef633e49,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x35fac96635ee0184,
+                0x063d760d024cd0cb,
+                0xa543add6557c329b,
+                0x0cb50e6f4063b071,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc3d2f9c4be98e949,
+                0xd1fe30b8cbc85dd5,
+                0xabdae8f77d436c01,
+                0x16c2512af03cc882,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd586e5eeab2f6b50,
+                0x150908a4d709a5dd,
+                0xd25ab3713c73975a,
+                0x768fc05fbe4ae640,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb654da6c7b454ffb,
+                0x437852a9830035d5,
+                0x9fb3c62fda4a1abd,
+                0xc887a220f51c38d2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3720e3bca4f834ad,
+                0x26f91b7aafb6f187,
+                0x7a65fb1dfd45f9d5,
+                0x501556ccedf80fad,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 279, 280, 842] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 279];
+        let mut opad = vec![0x5cu8; 279];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
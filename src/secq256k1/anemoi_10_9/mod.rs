@@ -0,0 +1,2480 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 320 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 9 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 9;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6f78760225c0a8d3,
+                    0xf299a4dd1e22f0ed,
+                    0xdf05b80f5d3074f4,
+                    0x059c0e5dc637985a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdedf388b8b693cab,
+                    0x4e58de53d4413fe7,
+                    0x96613ae19a1e0798,
+                    0x8961af1d59b5f85f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40b8f7941d709529,
+                    0x762b63526d8a2a91,
+                    0x96d408b15b9be598,
+                    0xb8c845e2e728f6e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc97599f981334984,
+                    0x240d1f74eef12812,
+                    0x76cdb9b38d00eb70,
+                    0x71641cbfa1f78a79,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf780e9d0c1d925ae,
+                    0x5bf99dca649dea2f,
+                    0xb33ce2658fb95e43,
+                    0x83acec77a96e45dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e0b44c891fd3a66,
+                    0xbc73f9afdf5f363c,
+                    0x1ede3962ed159f16,
+                    0x203918f87b33cb52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff21bcc5bb5ddee4,
+                    0x6757dc15a36ac689,
+                    0x77b6ad0ecbb391b6,
+                    0x37d79cf215189cc3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbaf454af603e224,
+                    0xd142a3e438460c0b,
+                    0xf9b1a8cdd7804ec8,
+                    0xb23c8ef21ebfa097,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x410dde6df0d893c1,
+                    0x877ce2b3e36be998,
+                    0x1f990267c3fc414f,
+                    0x12b98e94233d6cc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x273961aa5418d1a0,
+                    0x5d77b12847e04ce0,
+                    0x27ee952b256ccbf4,
+                    0x57f222f9500e90a5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7c1c46cd7d53203f,
+                    0x5ed452cb6f41fce3,
+                    0x2d8a14539bfbdb95,
+                    0xb04b1791bd517e2a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x833111d4c53681d6,
+                    0x15044cccc5a3cb6c,
+                    0xe5caec510a77f77a,
+                    0xacd496a9ad30b2b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce760c136cac29a8,
+                    0x7c8ec5a6ff38b9fc,
+                    0x303f0cd9a7318c0d,
+                    0x37b110f78cd2c71b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf2f7386c74fa889,
+                    0xa2ddbc3f61820e83,
+                    0x41638a7821b89d36,
+                    0xe00b5c3141112555,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa199e10d25af7229,
+                    0x916fddf3bb9a1207,
+                    0x5596c1994775e732,
+                    0xb91c88027247a6c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27679b4aff13130d,
+                    0x08c173da1401f2fc,
+                    0x41def81fe2087dd5,
+                    0x3b1ae1ca62ab3cfd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c00437ee6b046db,
+                    0xbdb937f0ea9072f9,
+                    0xe7a0cf862cc6d171,
+                    0xd2ee0a275c69a478,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae04a733914345ca,
+                    0xe32530d62a09c6c8,
+                    0x48b9353c45fe4a31,
+                    0x6435b28e847ca837,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3dcff60bacf51a4,
+                    0x2676c8b91bc88adc,
+                    0x56403c75b24b77d9,
+                    0x837e69f3850763c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8b1a6a5dea0ee18,
+                    0xca51de07e34bf479,
+                    0x8c69bac166895c51,
+                    0x4ca4843dadc7039c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x97fdf17a5567c681,
+                    0x31d5b1cd99efb35b,
+                    0x2fca11a7b0062a79,
+                    0x72c81fa868d10ba0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8988f7b9621b179a,
+                    0xae94e6c7144533ec,
+                    0x1279c180fc7e20f8,
+                    0x8321d557fa653c85,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb55a50b344abfef,
+                    0xcd2848b252deab8f,
+                    0x01dcbe11dfe88ae1,
+                    0x2edee7e3abff69a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a2fb7976d96d365,
+                    0x85f34461a859e4ca,
+                    0x31f0b7c81217b0cc,
+                    0xe057b035700d744b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf952445caed0abe,
+                    0x4ef1b701c2bc1b89,
+                    0x04c5c24b41f6b6e1,
+                    0x3b057be77b6c31fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefd9b2fd0fadc381,
+                    0xcb8da4a5934a09b6,
+                    0xe2003e4baf0e70e5,
+                    0x1b9b9367691b897e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b31141cc22e5905,
+                    0x8dd45d27004404e1,
+                    0x6098a9a8d86de207,
+                    0x80bcc937f293c140,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ad9ec58be28d523,
+                    0x0c697ddd31285ff1,
+                    0x5adf966fbb0844a3,
+                    0xffdaa1f0195378ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6779ecca9071f8bb,
+                    0xc762414294471839,
+                    0x63756d4e5c74d759,
+                    0xd7a2226b32e9cefe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22f01f75234f0577,
+                    0xee93315a1203ad8c,
+                    0x230547a0ae58e11f,
+                    0x114361e027a932b7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdb89cfbff1379eae,
+                    0x2df5159252ca7c75,
+                    0x07ebda799d414f02,
+                    0x2298cf66eb930233,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c8dcd048b383fd8,
+                    0xcbabd52fbbbcd670,
+                    0x8305214b28d50ca4,
+                    0xf8076a1c8c71e939,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8aec4820a795cc5,
+                    0xbefb286393cb64c6,
+                    0x4d8d418bfa96264b,
+                    0xc42a0b938e3c4d19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8237e536a95817b,
+                    0x096e022ddbd0acbd,
+                    0x7fe36e227ca51da7,
+                    0x8f34113111af3208,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fd4a6e6efe18c08,
+                    0xe087aa131c72806c,
+                    0x53da5a76a2068617,
+                    0xb52ba559176c071e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64cd1e16081b4e6a,
+                    0xd0bd07b4169a0b82,
+                    0x8225193443c46706,
+                    0xf534b61469f14e94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34d687ff0b644b0d,
+                    0x40f81d94c3313faa,
+                    0xb643a99b6f2d38cf,
+                    0x0cc514aa57934774,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52840f3adf4be3db,
+                    0xf20e4023eb74f4a9,
+                    0x82832b3ed5ab839e,
+                    0x4edcf8368be0b97a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85e7130dfb257ae4,
+                    0xb72914489d71e94a,
+                    0x5c4d398a423fc761,
+                    0xecf9efda5837e068,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51adb3d737ce88bd,
+                    0x1f04e3dda52bcedd,
+                    0x4be23ea471375a76,
+                    0x41948c0f3d3756ff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf7e0450a1394fcb5,
+                    0x03dd7514b3828be5,
+                    0x505229fc4c6c15cd,
+                    0x64657b726571ac37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fbd0a34af7ec888,
+                    0xca802801bfb08bb9,
+                    0xac4cc9dfa4cca1e6,
+                    0xdfc5aba869887c51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x009e0273b541ed28,
+                    0xe025bad68ab2bdf8,
+                    0x27a5fa29b074dff9,
+                    0x980d44653b5679b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a4b695c00066f2d,
+                    0x4cd31fcae2a38af0,
+                    0x606a76bec74f6225,
+                    0x0a65468100c058df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcae973f2d4739017,
+                    0x946c2b0f6d5bd575,
+                    0x513d63d091beb019,
+                    0x490e111f54100057,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29e9025a8d32496d,
+                    0x77f4988de4726d11,
+                    0x28d98515d2694c03,
+                    0xe158b49a7f9bb5a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c213870bd5071d3,
+                    0x8584c4f08eed0b24,
+                    0x33804c5b68b787b0,
+                    0x9fdff2e8de82ff4e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc392f4c8ee36b48d,
+                    0xeddc6ea16f1ad674,
+                    0xbc17b14e13d7bfdf,
+                    0x757781027174740b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b692cce483d0c0a,
+                    0x24029051f6f5dbb8,
+                    0xac6b2bc0c502d2fd,
+                    0xcb739cc115ca081a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77642cdf14cfff8e,
+                    0xb4eeb4d8e1c334f5,
+                    0x0123414754045ce1,
+                    0xd07bfd5034c6cb1b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0d5ab275e54414ad,
+                    0xd085269a38071592,
+                    0xeb5f2aef39efaa5a,
+                    0x8cba5e9d9ef55de0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe4d9baade5722083,
+                    0x0342423af8a55c3c,
+                    0xdb9df4eef3ce385d,
+                    0x2c18d8cee87c6838,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7e4882abcd8074e,
+                    0xf3629b754659aef5,
+                    0x6a3422b456dfbca0,
+                    0x31f849913fde231e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb17113f7a89a520b,
+                    0xa8d19acd3e9070ba,
+                    0x168c4a56472a5c9c,
+                    0x88b1e8228507dc58,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f1de809692471c5,
+                    0xb1ab2ca5c80d2fd2,
+                    0x84271bf2fb826908,
+                    0x252ac01864dba0c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88646306ba9520db,
+                    0x379d3215856512ee,
+                    0x569089f84ea6f891,
+                    0x7edd46ad46816a4a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd70e988027fe66b8,
+                    0x66647ffdee1d277f,
+                    0x1739e6d898c0bedf,
+                    0x3d30bbca6a8c4e5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x965507f27fec00c3,
+                    0x8f480f39090d7ff5,
+                    0x5eca988e670d7fd0,
+                    0xa9cf7d051b4fc53f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b2d0c33c2a710cc,
+                    0x8919ce9dc8aa4854,
+                    0x41d39ff7ba8ddcb6,
+                    0x5b6d2c8e5e70c314,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc775713c1ccfc227,
+                    0x0a9ed0e049e49fad,
+                    0x28fdce14f624954f,
+                    0x8f20836a8875c7b0,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7995f01011d00372,
+                    0xb06ea1b13fefa90e,
+                    0xdfa9e4591e89def9,
+                    0xb17cb4fba9318649,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce9fe1471277b745,
+                    0x797ed3acca450a26,
+                    0xccb869d143223dfd,
+                    0xdc13d7c9caa33b5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad6d6762047d8ddc,
+                    0xf65dc14c85b6ff18,
+                    0x067f1462024b0146,
+                    0x26b9a1e694f37c30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e3d898cd6d3ac88,
+                    0x1409e9fafeb6303e,
+                    0x3df4db4993d9345f,
+                    0x26bce927df6a75f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27fcc30cf638c3f7,
+                    0x4da33ca1f80ca975,
+                    0xd42f3eb90ede64a6,
+                    0x2abe04db70621d0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c035722f69dbea1,
+                    0x510b651bc39dd91c,
+                    0xe050001144f5718e,
+                    0xad22c127a8761b43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52e1d0aeb1ad0f4f,
+                    0x128cc7c1ceb24fcf,
+                    0xf2a4ea0f729145b1,
+                    0x117497f76a5a5fa2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63fa2203517f91f4,
+                    0xe086f39c0d651444,
+                    0x912f2feae4fa2e0c,
+                    0x8e3dd7e0f0c38fc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x13029523f9ad4fbe,
+                    0x3fbe5a2d885abf6e,
+                    0xbc1680decaabb7b9,
+                    0xce9562e51b8bd6a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62a39a08d8f7f36f,
+                    0xd31b0266cb6f808d,
+                    0x9f5224e15f733473,
+                    0x09eb86338c74c8d1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1ab124bd286dd134,
+                    0x2d28cf6c85648c5c,
+                    0x1974647543449263,
+                    0x7628f651ec8da111,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ce395d65ffcf8bc,
+                    0x567810585bb33319,
+                    0x6594de7976e3cd3a,
+                    0x16108521a933ea00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3313f8aba5ae70a9,
+                    0xd1ef6936198b8d92,
+                    0x69c2e6bfceec6e30,
+                    0x85a9057a48eec193,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2ad32131c0685f7,
+                    0xd38c3a25251501da,
+                    0x12cc689894a01d52,
+                    0x86e241934fb23262,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9d751c5353b9eb5,
+                    0x7ff97c35182b3146,
+                    0x16d4e363665078c3,
+                    0x1ada47ed93d39f86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6471b535ae88896e,
+                    0x2fd80c7d781ab512,
+                    0x49a6fe423c14d233,
+                    0xff1fc77627822a4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb55d6092b29742,
+                    0x4e61afc19cc62f1b,
+                    0x708bc905de248dbf,
+                    0x22000332ee151721,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x309488d0b5847f81,
+                    0x4ea8023dc0f6477f,
+                    0x9c02b5da687477b5,
+                    0x239625d956d3619d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6381cf93d4ce3a2,
+                    0xac3e5f83c96e4987,
+                    0xa47420f862f520bb,
+                    0x44509a46852df48f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88ad719a6c739151,
+                    0x5e1504132f2749eb,
+                    0x46acc538d4d33462,
+                    0xd235e049392e2d31,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x345d9d6269b29411,
+                    0x800a2699b4808e5c,
+                    0x8625b33f99083749,
+                    0xbacb4fc7727c4bbe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xadd05d5bee8058d8,
+                    0x41baf19b92a215d5,
+                    0xca283e5b7fd7879e,
+                    0x17ca6797f8da84b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98256a560d4d0e27,
+                    0x8f13a14719dd9c53,
+                    0x7b3ed458feb4303f,
+                    0x1c90d4e7e9ee4e0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a9793708e90fdeb,
+                    0xf4d622582404de2d,
+                    0x46aee240492e694c,
+                    0x206e0a09f913e480,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0dd8ca655a01d10,
+                    0x2d6547e4f245af98,
+                    0xcbda165531240534,
+                    0x4e6bed93339c3647,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a499ec54b20d18e,
+                    0xdd978281cfd91074,
+                    0x79e99c18c520305e,
+                    0x6befa5bce600748e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1226e052f7384bcc,
+                    0x0ce825f3d9480c71,
+                    0xac056868265c795f,
+                    0x41a66f1428692007,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa95f9a8ec0aa3e38,
+                    0xc05cc2b8fc5f5b29,
+                    0xbe43452a2cc10a37,
+                    0xd2aa368e757c80f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x987584e71c419abb,
+                    0x8c10f010cbef1acb,
+                    0x7ba8892727ce27ba,
+                    0x72f40966dfc31767,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6449098d5f99b477,
+                    0xae6b62c50d9617a0,
+                    0xb0d97bb329982202,
+                    0x8b71bc0fd627254f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaf72219d9492f74e,
+                    0x4785c7756be5bc3f,
+                    0x9817079233ef1ac9,
+                    0x7b855f29728cb08c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4b448be568efc20,
+                    0xc37d236fcc470f30,
+                    0xab2282aee5ab2335,
+                    0x38a6ff44f7b77982,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04a02dc097424fa8,
+                    0xf63c1268eab65b19,
+                    0x5bd2dd86923a9151,
+                    0x0cd13d438fe3358a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5cf43f3cbc9031b,
+                    0xda99949974ecb477,
+                    0x5530b0d22d549bde,
+                    0xc8bce64fcca08917,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1156583544b91ac7,
+                    0xa9e62c50b1d06c59,
+                    0x28e79a6265ded814,
+                    0x685c8e66191dd243,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5f4f71c129771ce,
+                    0xa804f463f9379ece,
+                    0x24a5cdc5fe274ee7,
+                    0x0e5234be2bf4a96c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5615e1a12796b20,
+                    0x10e5760d5df3d984,
+                    0xcd290a3c4650da4a,
+                    0xa13e815f8c3284a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaeeb2f8fb47b4ffa,
+                    0x33378997a9bc3051,
+                    0x58e9bdff82f5d8c8,
+                    0xae7c4e7ad4ad4ca1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe23eb299a8d1dfc4,
+                    0x738c9ee83edf829a,
+                    0x04d9cc76010761e3,
+                    0x523cbab1f5135229,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf55cd50862aa2b01,
+                    0x27c31835dfc7be2a,
+                    0xe3ccf99a4aa97207,
+                    0x945870a9ae3b2c22,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0727d1db1c158b68,
+                    0xbe2d49d9a3da4d29,
+                    0x60c4555c9f9fe1da,
+                    0xefcec4353bf6f86c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c0c96bfff132dec,
+                    0x0585ca447499a704,
+                    0x7bea5d03f24a7842,
+                    0xa6c22c3702dd9cfc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51aa7baeb1fdc59a,
+                    0x879d4f3323a45bc4,
+                    0x6c8f2f9ca1c0b55b,
+                    0x72b2ec187a0727fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4364f5a4a429f431,
+                    0x28f50d0fa7efea79,
+                    0x7a0687cbd0d0f426,
+                    0x8265cde0fdd8bb51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf66af887c546af2,
+                    0xd004f53b42ac8990,
+                    0xd62b7c3abccd9b86,
+                    0x3bd58b46ba03a50c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51fd472562238a8c,
+                    0x6de626d559265e35,
+                    0x848d41958b001bbc,
+                    0xe7f7efdc10466891,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65b3668bf892eea5,
+                    0x91e7c147a7edd947,
+                    0x420afac33ce633da,
+                    0x0d3d1aa08cc9ebf0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d33293d68d16825,
+                    0x4ba14c1d07f79e36,
+                    0xcd84c083923fc339,
+                    0x10f711115c4a3bcd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7afef93b72c6043a,
+                    0xc867488a415ffd1d,
+                    0x7497e97b9fad0ae9,
+                    0x981f294dfb810b22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa21063d4ebf2f194,
+                    0xcf982440bff657db,
+                    0x3c98317123ee9fc2,
+                    0x942d312a9f38945f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9978dd6887ec25d8,
+                    0x2b295984ce9038e4,
+                    0x64d1ec9a94e77602,
+                    0x52c583b4e2206a89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaac634274da49e4c,
+                    0xa2b914f59bed6314,
+                    0x52e57dedb5725fa6,
+                    0xa0162fe5096b9ce6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a2a9158332e4c0b,
+                    0x7c599251aafbe939,
+                    0x330ccf1401c5e169,
+                    0xacc19edc72a10d25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdadaad40674b8034,
+                    0x79ac65704908e149,
+                    0x3cbf0bc6210f5d27,
+                    0x262786700e539cc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff80468766c99eb6,
+                    0x5e5f12df54dd99a2,
+                    0x733ee936fec3a8f2,
+                    0xc917709bc78b72a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3857dd16f1f3f70,
+                    0x9968f8d1bd1e9584,
+                    0xd1590b41623499a1,
+                    0x3fee27d901c58454,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c9692b254de422f,
+                    0xf9819267491ee40a,
+                    0x6b936db3095fea6a,
+                    0xbcde077767a025e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac1af05a61299de0,
+                    0xd8f427098ead26e4,
+                    0x5f95585296dcd41c,
+                    0x6c287140e8a3f31c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad8f139045216fb7,
+                    0x904c7b5029ff67fe,
+                    0x2f4c79f7e317f69f,
+                    0x502be1ea0649af94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x277196c7faad6d8b,
+                    0x191c49eada1b3dfb,
+                    0xd24f75f9b268bf26,
+                    0x2a63b66ae910dab4,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbe3428f50f9be32e,
+                    0x0bb2ac694df0b06e,
+                    0x9bd965970a283f20,
+                    0xce7007297f7434df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa43ee7b11101dab2,
+                    0xc3d90724f74fd16d,
+                    0x235da0228da2f057,
+                    0x67970b80159be82e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23ce55f98d32a35e,
+                    0xcd1a556e05c5985f,
+                    0xfd97b6ea111521cb,
+                    0x5d9b705a4cf4b170,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc502c54f0f00149,
+                    0x4e71c525b0023cae,
+                    0x58de05e9a82be4a9,
+                    0x63c8856365d4efe6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60d76d4aac580ca0,
+                    0x889244ab45733417,
+                    0x2ced6b0f91570e89,
+                    0x1a5329ba009d6b14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebf83100d32ef3c6,
+                    0xb399b577b47a329b,
+                    0x781e52fcf5140d5f,
+                    0x347af7b91cd461fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b6f2d5aa692395c,
+                    0xc2f35075964accf5,
+                    0x3ee7ebdad4c6308c,
+                    0xd1953e9f438cbcbe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbb7f2082493e017,
+                    0xf20d4494e591ac4c,
+                    0xd9c6da7c2cbe2db9,
+                    0xdccaa8f3575ce024,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc67a192f9aa1af30,
+                    0xe8f5e00922914017,
+                    0xe94525f45d1cd055,
+                    0xb76143ce9fa0e2d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b3531037c4531b1,
+                    0x9ef0547c23cf505b,
+                    0xd0bd5ac435078806,
+                    0xef44e20057bfeeaf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8a2e20a51d548048,
+                    0xe3f5a2ee9b258e4d,
+                    0xdf163fb4d7ae33da,
+                    0xef6e6259303a793f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x984d9e5b42ebfdf2,
+                    0xff5f0455c38f28f6,
+                    0x7e2620970f8aafc2,
+                    0x415c6a999541a2c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9eb5a1874bc4f98c,
+                    0xc6d26cc129fdcb7b,
+                    0xc10ca2bf3617aa0d,
+                    0x3f6c786360d0d12d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x442d5af4a90ae24d,
+                    0xd6853cb0fa92ec69,
+                    0x64eafada14024af6,
+                    0xfb443ba0daa92537,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x365b5195cec01bc6,
+                    0x5207c64251560c30,
+                    0x56e279577984c03b,
+                    0x9511458f7edf4cf1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8e3a1e91f3f1452,
+                    0x4bd4348065612c57,
+                    0xe88820ddd05416b0,
+                    0x42366bafc50f6624,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x482c4e6f42919266,
+                    0xe60d75bb0f87ac22,
+                    0x29ab9e84e0e2c522,
+                    0xd53e108fa9b49b50,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7acde96ea639805,
+                    0x77e64a4d54e987e2,
+                    0xd3cf39ea6a2f15c2,
+                    0x23f54f49e2bb791c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5979fe3cbe00194b,
+                    0x1e12ebed6181af09,
+                    0x35d4116e4601b967,
+                    0xc3af6aa39da0ade2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x701a4b4d0b075f06,
+                    0x5d0631c43472585d,
+                    0xfbd1a77ec63d8280,
+                    0x38423357c881811c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7b2fa977b89bbc9e,
+                    0xc13840bc19c5222a,
+                    0xb9ca851c8da3dd22,
+                    0x5693f640c56f8ab7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbde00a0236ad9ef,
+                    0x7cfe544c3bdefe21,
+                    0x890d8f34cb524434,
+                    0xfc3faaffbdf8ce81,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56ec3f2aaa4667b6,
+                    0x4e97974c31e6bda5,
+                    0x4bc07a1149391b52,
+                    0x357c54bd982a123b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ff44aa4bcaf2a57,
+                    0x1e67d8b7ae2c44c5,
+                    0x0d416ed54e04838a,
+                    0x8b9f7355202b1901,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5efd920768e61fa,
+                    0x21a7e5d198a87b83,
+                    0x9fe209ab05fd8fbf,
+                    0x68c19de89038b22e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01f4ff17a0f0d48e,
+                    0x0078c41e565382f1,
+                    0x50fc8cb1b0f5b1a6,
+                    0x874dcaeb10186d12,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1b6ba9e9ce5ab87,
+                    0xbb9850e49d7ad148,
+                    0x0695b183fa9df3c7,
+                    0x34881625803232ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81fb45046a71dc45,
+                    0xcc6dfe75276ec9d7,
+                    0x534e8ddc2cb7ea4b,
+                    0xce58c192338f0991,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c03c96930ecfe3d,
+                    0x276246fb04a6d012,
+                    0x7f3316fb6920edef,
+                    0xc845eb4a3c57ab48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7383b5a55c6fe2ab,
+                    0x5c1ea0c5d1926105,
+                    0xc701b93ae8c909da,
+                    0x1cda64e0d4ee6767,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x18e1a4b62f1ffa05,
+                    0xb949c6d0a0e2f24e,
+                    0x2110e2fbbfb3d7eb,
+                    0xc4dbbde2a302462b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc72b6136f7e2e153,
+                    0x9ddabfdd41a63d1f,
+                    0x090f6818664ccdf5,
+                    0xc58d6ad941b48555,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c3a0573cec1adfc,
+                    0xffe2e05ec943534a,
+                    0x38faf3b51f681c79,
+                    0x93ffe7d147c553a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e0b6ca26892f4f2,
+                    0x832903707ff85ef8,
+                    0xd4d4f782e9a1f608,
+                    0x3133fbc5a7b53948,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e283573fd4d14dc,
+                    0x582e613641d33654,
+                    0x4932b836231cf202,
+                    0x873b6f4949c1893e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20a6528869aa789c,
+                    0x861458407b0bf9a8,
+                    0x8e5301243d0928fa,
+                    0xdb21bce3f55acc69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0844a101d76ab8ea,
+                    0x22ad980b2b6346a4,
+                    0x38e4a8162eed0196,
+                    0xeecaad5acc8328f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a29c52ae502e7db,
+                    0xb671af47bf2ee203,
+                    0x9d081068d4eb770a,
+                    0x848b98bbe14c58ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeeb3e70b667efa0c,
+                    0xbfd9fc4f626d193e,
+                    0x240f1046eec96b18,
+                    0xc35dd46b371a566b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d228189d153069e,
+                    0x9380315bef1b7d13,
+                    0x4d7bd0471375d8e4,
+                    0xa2aa44cad315b20e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8b26db7cecaa35fd,
+                    0x64c0f8adc74bb239,
+                    0x9e116041957a66a1,
+                    0xd23dd716b7a5e870,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d2850451edfcaab,
+                    0x05fc49f68d7261af,
+                    0x55f27541e358800c,
+                    0xc2ce132f112584ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa143e879b3caab8c,
+                    0x1fe35884d21e07fd,
+                    0xf5ccd3b5506f0fca,
+                    0x186b2ec8ac181646,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a9ed09d58fac572,
+                    0x54028dcf97513033,
+                    0x3bcf9971fa099207,
+                    0xb3393c73280ac4d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd27608d941729367,
+                    0x99b9933c3bde21da,
+                    0x9d52a95e1fc0b474,
+                    0x4e3980b107993e42,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x934c4e6743363e18,
+                    0x16401eae250de16f,
+                    0xec65d1675a27f86a,
+                    0x9e24433dfcba09ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7086421393571763,
+                    0xffa20944bcb8c13a,
+                    0x3562b8de1f98df89,
+                    0x4b21f5ab0c1f63c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2907f36402bf4ec9,
+                    0x82b342f03b7860f3,
+                    0x01def88d08505195,
+                    0x188f82790bb846c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x238c359d7a72d625,
+                    0xbe21b73b6543d3d3,
+                    0x4a1fd4c7817be4aa,
+                    0xf9acee67adb337ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ea9a2d62bd7ab49,
+                    0xf592b3db36361a9e,
+                    0x838ac3d73df8f109,
+                    0x7e21d23b740a01c2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa88885579b7d0756,
+                    0xe8a183073bd94f00,
+                    0xede9126ddcb01064,
+                    0x9a5f1ef4cc7879a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8336f899ab10fcf7,
+                    0x685f909808d8d1da,
+                    0x87fa5e36cba6d663,
+                    0xa8cd4c591dfc9c7d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7bf659f91e8f8679,
+                    0xd0002996d48c38b6,
+                    0x950e5f7aa5f068ff,
+                    0x1d9f510f99bc5283,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf281a383cf42466b,
+                    0x77eac6323baad559,
+                    0x33a1af6b6a226202,
+                    0x89252f41e93085fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1316b8db9d0d279c,
+                    0x9f8828777813d19a,
+                    0xbf7e1c8f4101921d,
+                    0xd71b0bc4f74ac1ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0512b7a56f25b95b,
+                    0x2343174c90269ef3,
+                    0x2344ee2a78ac04cf,
+                    0x2088eefed5537bc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd02a00db6e6f888b,
+                    0x7035e12b28f67b31,
+                    0xfc0a674de779a78d,
+                    0x8afaa277eb9c1c29,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x356fb60c2cfd7460,
+                    0x9e27e6e061bd7b38,
+                    0x17bbbed7258b0a26,
+                    0x5af20076c3972743,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23ac429652ec1dfe,
+                    0x958d1fd505f11e56,
+                    0xfff265ac6f09162e,
+                    0xa5d9109faa48bc23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x86e772397e3a7907,
+                    0x6e0ee0a40a7105a2,
+                    0x8a75f308dc85572a,
+                    0xe028e115844b7b27,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa40a6c28a8d4e73a,
+                    0x30c1ac2cb4b7c13b,
+                    0xfc19596c426d7035,
+                    0x619b49e81655085e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78720d508f2a9ccb,
+                    0x78f5ef578ce8a0fa,
+                    0xea8410fb0f6135ed,
+                    0x6208d6bc13ca1e00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1c1bd2b355e1533,
+                    0x5731908b76bf88cc,
+                    0xfd77b50b5a15e050,
+                    0x5010c9121e7e3ef8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cd929d5f0dacb8b,
+                    0xaaeaa95ebdaf003e,
+                    0xdac4b4dae3f11af4,
+                    0x5bd1245dbc061186,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc976aa21c764cd84,
+                    0xf707e5769409c88b,
+                    0xe594aa46d1981d34,
+                    0x88e0afbdf1e0cdc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24b02f9d2a58a69d,
+                    0x982d2b377eeb7624,
+                    0x700b4235b65d324b,
+                    0x97fe119d68f1ea13,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e78f2176fb3c6f5,
+                    0xc4d5c6210b620697,
+                    0x5044f7f72520be6d,
+                    0x4bd0aac3c34ec6b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff86f9d58fb7b6cd,
+                    0xd89d298f0f451782,
+                    0x5edf6d87a430275b,
+                    0xda61543810e14b2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a857ea2b884314d,
+                    0xa591ccd30570992c,
+                    0xfb0899082cf6efae,
+                    0xa0908c6dbad2759a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee777e36cd08b4d1,
+                    0x05ba7c53b0acb40d,
+                    0xfd222bf2cdadb0a1,
+                    0x264daa6e9bbaf3e5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2087b2ef6fc974e8,
+                    0x5690d861e363066f,
+                    0xe67a92a73920ced5,
+                    0xc577806dccefa51e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11a788d1355a025b,
+                    0xbad7cdd951836f9d,
+                    0x24ecd7ca9e233ef5,
+                    0x740297db72af759a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6970f5c073825d3e,
+                    0x055893518bf880da,
+                    0x1db6ab66631673fa,
+                    0xfe6c23176349f000,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14e7783e750bcee9,
+                    0x53ecef73e1d77f60,
+                    0x16e7e3686132b81b,
+                    0xa130ac9323527ed3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a98a5caaf70dd8d,
+                    0xaa87717649b9df80,
+                    0xad348c10bc3d2272,
+                    0x2e9bf367376fad41,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a06a5be1e3f11b9,
+                    0x020000e1e93a5f23,
+                    0x18e64f0a47b18294,
+                    0x705a879260a52140,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcef3973eb2087422,
+                    0xfa86bcc81b398ca8,
+                    0x6f92dde4a8a78b21,
+                    0xc37b55823ea54b7a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec34e934b3edb0b3,
+                    0xa8e0b520405ac33a,
+                    0xb4f8b72e13b20156,
+                    0xcb57afbac9cc2c3e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00d7b435a00e404e,
+                    0xb63281ff389cb234,
+                    0xbdbde2bc3ffedeaf,
+                    0x5c75231029c00310,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f6624d13f1168cc,
+                    0x3d94e12c937cc4f0,
+                    0x5009f854e6fe8f3f,
+                    0xab5d15627800a9e5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaa6498803d18d967,
+                    0xebcb0d7b83a0147f,
+                    0x7a896e318aa75039,
+                    0x2d8e82589dae6ed8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73669c4497bd7022,
+                    0x136d234a40126195,
+                    0x1d0f1600dac27ad7,
+                    0x2884a0a2d2b4aa11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf231c9c9dc73e216,
+                    0x7258e2b0c236f26b,
+                    0xcd15e661d86117b5,
+                    0x42eed1ce3499752e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a9a27355cd6a8ef,
+                    0xa08e9903616e1224,
+                    0x797ba6ff98544426,
+                    0xf48947b2f1680085,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa16f608140745629,
+                    0x1178b1eac98684e2,
+                    0x661e4ed5a0933884,
+                    0x442328c891126e5d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0a6f69d4f4cac6f,
+                    0x10ea4e91ad155cbb,
+                    0x887328b67aa94790,
+                    0x73a123b10cfbfd51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99d3f81533dee819,
+                    0x0e9e7c30a115bdff,
+                    0x8c657ae2f9207f4f,
+                    0x110d75e6b00e9df6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67692a0c46839140,
+                    0x56aa65a9494c8ea1,
+                    0xf40a01a82eda6f5e,
+                    0x54e5d15d4423556d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc009f351f0c8180b,
+                    0xb08ccd183b0e8dac,
+                    0xf4f904d24a1ce5bc,
+                    0x648110f8618febed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c5103562dc9f3a9,
+                    0x2b6422c378de15be,
+                    0x3152e1de622c4d59,
+                    0xd83bcb5444fe6edb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x14c39cc946cf99da,
+                    0xbc3fee336ba0f36c,
+                    0x96629d4dda518d84,
+                    0xaf61fbb4cba359ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3d024172fb5dae7,
+                    0x0b9b072aeb91e087,
+                    0x8aa892198d91cc36,
+                    0xe261b88dd6ab08a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdb8386a7141e4ec,
+                    0xf5de1deab0f2adfc,
+                    0x9af8a84370b04e0f,
+                    0x4a183f3ba14edbfe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4242dd648aab97cc,
+                    0xede8564b6fd05f0f,
+                    0xc25ebb9782163611,
+                    0x0137d732482a4c96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4befb7d78b5abfe,
+                    0x1a47cce430dc469c,
+                    0x918677de238173e9,
+                    0xe2c2f7d91780cc69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x981c0ca0236c77e3,
+                    0xba3f19f05cad06a3,
+                    0x3fa830254f39ea6e,
+                    0x8fc186f76a3b4b6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee2b8ff1a8e5a6f8,
+                    0xf0fe1afb2befd7be,
+                    0xff4dde84dcdc18b7,
+                    0x466491dd65a2c3ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x13750c8c296c48e3,
+                    0xb5b161fd534dc596,
+                    0x113ed65f9da81887,
+                    0x495200f882cace26,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a899d7c33a59f16,
+                    0xcfd15b66fc580e9a,
+                    0xaee85cb3dae2fa54,
+                    0x2d2f92c6c6af0d1e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa57f13f64ed0a714,
+                    0x1cda220fefac1a97,
+                    0xb1f72cf4072b7896,
+                    0x9259de156198d417,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb3f61c95f0cc7291,
+                    0xbaf8363637ffd48e,
+                    0xd7460541f109e477,
+                    0x3251ebe1933cfaa9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0150230601061812,
+                    0xab1b8d0a7f31faf4,
+                    0x8acf9c0d8c4b18ef,
+                    0x49002d86ea006cc9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0316de899c72ee76,
+                    0x1c8d0d5b832f4d37,
+                    0x2f963491705ed9fb,
+                    0xcd4ea9951f41cfab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a9000157d9af7b2,
+                    0x7347c01f5dbe5606,
+                    0xd8205b26f9c6c98a,
+                    0xfd7c790ff7a43f02,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x907ef4456550ea08,
+                    0x194186a01d92a877,
+                    0x3f7cb7748330b490,
+                    0x4aac7ab76b45e655,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20c3f546832ac22f,
+                    0x2ccef3853fd56d6f,
+                    0xc1c9562367087d64,
+                    0x5ff4dc7ee0bb8a61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79735e360d000c92,
+                    0x189e91656b7e0f1c,
+                    0x9323face4a5348c0,
+                    0x7cdcc0e2450e9e0a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c1212b88a2d1e2b,
+                    0x97c1c74a69bdfb7a,
+                    0x71c1cb0e75e1d50b,
+                    0xf990ed66830247f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0aa4fb41e6d2d0a0,
+                    0xb16683f2aea68507,
+                    0x8a33c9f29f9845c6,
+                    0x404aa86528836983,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17aad971f2512a10,
+                    0x479846aa6d513844,
+                    0xe19a9a3ece7e149a,
+                    0x9051951c27012a55,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8a5a205c3f0baea9,
+                    0xa38d69333e68d6c6,
+                    0xc10bdb8e87b9846e,
+                    0xf426565d30e1ef2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f24c6dc335b0fc7,
+                    0xd3f1569221ae5a2c,
+                    0x5b739a560374ffe3,
+                    0xc223f50a4ecfe8e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb5e2408089047db,
+                    0xfd70610f19ced647,
+                    0x66b2855d4019f2e9,
+                    0xe1949d31309e1355,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8ef595da29290bc,
+                    0x22bd5ca995b73589,
+                    0xc60c5984ad818be3,
+                    0x66af5e97be84c367,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72b78a2adf01184c,
+                    0xf8c5b5352f4ae25f,
+                    0x9bb834a52b13b3bf,
+                    0x9019404d129cf8fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26aba67ed7925242,
+                    0xd776f1317b58c14f,
+                    0xedcdca22279168fc,
+                    0xfac8041de9dccf50,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9400fae0fe180610,
+                    0xdbe23e4378b8ccd4,
+                    0x3057bacd92347edb,
+                    0xda4e9ca9ea1cd0c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x468694aaa839e4dd,
+                    0x9f99fcebe0f83487,
+                    0x5b0b7aa00f304cc3,
+                    0xda414ae32ef2df92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45d3bbb7d9c56ab2,
+                    0x2b8ccbcdef2a3918,
+                    0x00850ed7ccf7ea2e,
+                    0x4aa70c757e3b952b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa82f2bb7a5b06a19,
+                    0x14f8a86bb00a1f74,
+                    0x1a99e7dbdb85d769,
+                    0xb48a3ac54a51660e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,753 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x0000000000000000,
+            0xc6f3988c47bc0f19,
+            0x8f5c58db4bef96e5,
+            0x0000000000000031,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xdcc439163211d00e,
+            0x117f9fcbb4a62003,
+            0xa10a9a57632f4078,
+            0x6accec4f8a3e1954,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x89f42bf7f9a29e91,
+            0x67f456edb9a8a2c5,
+            0xa57c8f3443907042,
+            0x66f5c3cf97415800,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xac1ddf47a6729999,
+            0x8d5d8229402a34f6,
+            0x396d8daaae21254a,
+            0x057de8c365bcd1d0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaa82fe450c75907f,
+            0xeb45723b4b5f523a,
+            0x906164129d37ff90,
+            0xc3dbb395a4c97f74,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x182ec001ec41553b,
+            0x3288391dcc807c99,
+            0xd349fe0ce4da0882,
+            0x45b4c9d1fc8c977f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd657de674909af11,
+            0x3e1677842cbef72c,
+            0x261614195baaa308,
+            0x5fb21bf549cc34a7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0d8e1e6258b7de01,
+            0x36c464b8501ae3ed,
+            0xa3841cfebb7f45d1,
+            0xcb91b00749e5c268,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcd64ecb47d8fbfdb,
+            0x6479aa07599bae03,
+            0x7b617c5964d1234d,
+            0xabf0ebf4f27ae04e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x7ede69fe8a879531,
+            0xb30d829b6498fe8f,
+            0x7d6b24091aeb1cf6,
+            0x1374ff89dbb9bef4,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x5659f783061f8461,
+            0x7d2692d2d89f53eb,
+            0xdd7bf8236f44e68d,
+            0x8d9db9f45c855028,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x36dfcff69ef452c2,
+            0x70a013d4e4ccdfe0,
+            0x21de27b7b6792096,
+            0x821c16f0449a5a52,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x429a245fb1587b72,
+            0xae2bef917e8b06ac,
+            0x9d6c3fbf49eef9f9,
+            0x0cb70708d20792b1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x43d65e2d53c2c9c6,
+            0x6ae36cb69a5d180d,
+            0xf5aae43a5849e001,
+            0x5f1102006ad954f3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6bae69179ec8c1fb,
+            0x7f24ed099002ec1c,
+            0xb8f08e9a8a811d7b,
+            0xd3c5ed6f4484e123,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2e0452c5be429f7b,
+            0x4021eb4e3c1670ce,
+            0x0c9c6388712f198e,
+            0x7e77c55b55378c6b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x73e3e86cf3f12001,
+            0xb4ca2fca18ce3606,
+            0x94e6ab4112f9471c,
+            0xe37a87c530490774,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc50b200c5d6f3bf0,
+            0x4d111d827432313f,
+            0xde499b1c70e4a02b,
+            0xe789a4c459efc7a4,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfc9f4b48e9eb0c33,
+            0xc897a67a00ef6fe3,
+            0xdd3cd19038be7cb7,
+            0x3d44faf8b9fd4409,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x342f36c43fd36f49,
+            0x66f2cca0494d3c17,
+            0xa0dc769beb3cc6a2,
+            0xfe17b234cf16234c,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x45b5d759325b2062,
+            0x88743d21233040ca,
+            0x88981e3f3b70948b,
+            0x311cd1089bf47002,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1ad64e391c0c014c,
+            0x6fad0f7df183a43b,
+            0xad6d85cb5759e9b5,
+            0xf3e58d7703583060,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x813540269ebe9fec,
+            0xc9a346836f92ecac,
+            0xa55ca9903f42e44d,
+            0x8e10c20514dc7800,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xafbc10433fd9cf93,
+            0x1abffdcd7fe61362,
+            0xf28c3795e9746bb6,
+            0x8920c04d816d0104,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaaec77689fdb2ec3,
+            0x96cec8794e60930b,
+            0xf5857e064ce25ac1,
+            0x1a68840a506fa27d,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2c68da049b5ddea8,
+            0x7590e3340cc7d095,
+            0x89c87a202e42ef9d,
+            0x281afe467fec2c91,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa1ea9614d20ebb4c,
+            0x59fe28bee2aaedec,
+            0x879fbb45165be644,
+            0x6256fc5cb70df84b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x7d6f756fed0a78c3,
+            0x6c59f9316357d328,
+            0xaecb86e3b3a0c60f,
+            0xf721dfdc086b0150,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe8309ce8e923ca4b,
+            0xebd9d7dcc29a7b2e,
+            0x4abad6c0c8f1bf55,
+            0xb3ee1dc62e94c2ab,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x180d841ee51f08aa,
+            0x546a24e50c928006,
+            0x5ca9bf406302507f,
+            0x3555f1c1b71b97eb,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc0a2f570879803dc,
+            0xf1ad6a7739a72b7e,
+            0xf2884bc848ff5f23,
+            0x42e000d3764c05c2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xac461d22ab78911e,
+            0x9ee7865bfc71f5f3,
+            0xf6f33ff79c993c51,
+            0x94c640e726f19d1a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x9d761fc56118e96b,
+            0xc212d4f6d595309c,
+            0x717fb4465124f7c6,
+            0x4c8ecb071b30d097,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfaea590b2d8484d2,
+            0x0c9927e846c215d2,
+            0xba07307e9e66db0f,
+            0x87898813d13fd0cd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc8952d4f00e2a54e,
+            0xf5af0574980d461f,
+            0xe237e387b85d9d8f,
+            0xeda0ea8c4d7fef85,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x99def4deb16404d0,
+            0xb6871cd83e4561f6,
+            0x7d9fde9a11f46a7d,
+            0x44a385ad6b108747,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5531586fa5d54040,
+            0x4eb684de27ea53cd,
+            0x7d9930f95f48a5d3,
+            0x6ff5fdf2a72f0240,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x526d81c93af14b61,
+            0x41d149686dc534e4,
+            0x94661bc59ea20b7f,
+            0xd1659e34b74385e8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1198f1bd8303d78b,
+            0x476eb9e45b510b8e,
+            0x394420eb8880f463,
+            0x64151f0e75542f53,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1974aff8996135b4,
+            0xfc21d21aefeafe75,
+            0x3070cb29f1596ea9,
+            0xe06a3bd5fa2cba88,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xba9219e3cf450d14,
+            0x26b847293facdb16,
+            0x55419f2396733196,
+            0x822bc7e3a5d526ae,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x63fb389566645e91,
+            0x73d453187b9c3be1,
+            0x3ce878f59456095e,
+            0xe86104d0e8da92df,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x620d0d8c4566235b,
+            0xe5745db25db62a15,
+            0x6ae900d92446ece9,
+            0x0a210bf912f839e7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcfb7f0b740c9e8b0,
+            0xe56eca2f15de6791,
+            0x7e594b43d210e653,
+            0xed9d9f9db7be41fe,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x438adb3712616a47,
+            0xb1656c824d2279a3,
+            0x0cb15f01b769e610,
+            0x34dac8ba1127ca0b,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8ed0b343602605ee,
+            0xa6ecabe154da9f95,
+            0xcd6ce6c11cb2e4f7,
+            0x7036e4bccf7f5965,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x331e575a822d2d32,
+            0x860ded80a34c4636,
+            0x44bf0a49f061deaa,
+            0x38b8e3f119e75675,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xdbb9a51ef264b8b7,
+            0x4a4f575b37a57364,
+            0x11c870d36d612108,
+            0xf6bc10857e6e86f9,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x755d26bf7b96fce5,
+            0x3b1e1ad8cf5e1034,
+            0x898d99a429aad809,
+            0x970260de546398cb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe52612d1a1698fba,
+            0xe25eafc8f23dfaee,
+            0xda21b125b76b6ea4,
+            0x243d9d2a767d4679,
+            0x0000000000000000,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x76c7df60eb84f765,
+            0x8dd7576d508a4a72,
+            0x99435ddee9c0a493,
+            0x6db6db6db6db6de8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0e1d789bd598b66c,
+            0x28a726a7e34ab2bd,
+            0xcfe786d0c3441b46,
+            0xe117521cf4768ea8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd57f78ca15bc0d6f,
+            0xdd1fc406fe56661d,
+            0x52ba6337a826a666,
+            0xe07a5dfbe1bda6da,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc165f116e2a5b4d6,
+            0x3a9a0af6a7eb648e,
+            0xe09956bddeb0b2ae,
+            0xf54e35ee80e9e8c8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb0da09cb511e3fcf,
+            0x2a972a0adf2ddb62,
+            0x2da6a6f4a81ee01e,
+            0x6473f84a2f513a36,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcfd55f162e4757de,
+            0xdc76a7882c3355a1,
+            0x0319b9e9ca229004,
+            0x4e0fbad59cb52fad,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x488fdda04311a0ad,
+            0x3848ade9b2482795,
+            0x7adbb76c0336f7ab,
+            0x70a097589d51d471,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x99f82ae7cb52581d,
+            0x8efa815aebad44f4,
+            0x76aaa7db678cf5ca,
+            0xdfba5fc97daf3bb8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x63b95faa400da515,
+            0x3a12057768f8db0f,
+            0x4875fc45dcd82a87,
+            0x36654eb5f6f521bd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x85e693c4f5e79100,
+            0x9018c6dafe94c5a2,
+            0x40991dc46d497757,
+            0x4eb159d44f8ea42c,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8eb77bb386e74a74,
+            0xde5fdc0d75d8f202,
+            0x8c0ca17036d7d4c0,
+            0x0c2f6f031ad399da,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x29ceb44bd7be07ce,
+            0x221d250aa7dcd557,
+            0xf564b87a404fdbea,
+            0x0941565eb645aba0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0f8d748e32eafa3f,
+            0x785bc3eb06ecccfe,
+            0xef53b80bd84710a2,
+            0x97167ad623f6bd85,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1ab414cc2538b3b1,
+            0xb2757fdd9689aa63,
+            0x41805196b29b4dea,
+            0x5fbc28cc8d7947e7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf36d77fa48ea813a,
+            0x137b0c196785783d,
+            0xfadf75c5bf29de8d,
+            0x85390bc4d67f77df,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcc5a29ab319da491,
+            0xcb877114ff0296a5,
+            0x678c5f790eb708da,
+            0x9e44a2d62df7af03,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0cf8c0ea4f17d2cf,
+            0xd5c4a0a552481302,
+            0x14cc8ea772c5038a,
+            0x0bdaef9fbc6631aa,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x785205d63128773e,
+            0xcc0f749ac3b53eda,
+            0xdc90660cd531b7ef,
+            0x132440fdc650cb5f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x3975549cadf43521,
+            0x3a55f62d22ce89fa,
+            0xd57191906904ebba,
+            0xdf2b4a30f70f0fe3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x621439cf0c522c4a,
+            0x6ac64b559739afbd,
+            0x8f2ab06af5da88ce,
+            0x50c5f8f67b8292ef,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6198026f13396085,
+            0x74a97b2606dc8440,
+            0xe6637b4c976d30c7,
+            0xe59837eab99d5439,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x31777ae6e4b5ef27,
+            0xf17738974bbd9ed5,
+            0x302eca4e759a5312,
+            0xb0f47eb8d45e1c35,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb2087a20dffb1647,
+            0xa97155d9dfd617e5,
+            0xa67ed59d6204a901,
+            0x4e59e7a5c6263d5a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaa4c0f3aa12ff24d,
+            0x329f28d8133caadb,
+            0xed9c58b2d82f87a9,
+            0xbfb598ed03678e7d,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x565dcea3d9dd26d1,
+            0xfb71ff6cbd0d2450,
+            0xe6af18f215f4c9dc,
+            0x01c5543341c4d3bf,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x653c3603a5923cf2,
+            0x7f951d6e11bb31ef,
+            0xf6334b9df58aecc7,
+            0x3c12169d4d0d9942,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x954f52389445088f,
+            0xb4462af40d74a6a6,
+            0x190074389fe7b08e,
+            0x7ee19f13378c6c9a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcb33e053579d0d45,
+            0x69f704bcf4e21c45,
+            0xbe8d276141adebb1,
+            0x16e6b6f1692d4f24,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xffb1ccc973d00b2c,
+            0x2187ff1c7738308b,
+            0x546a6c4e22f83c37,
+            0x49fea7da6007d89e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa0426ad018ad6020,
+            0x918b34f44bcecf6a,
+            0x5c72ce9c97602087,
+            0x7c2e735f57e951a7,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb7ad68a74ec942c8,
+            0x8c7c882a0ef19217,
+            0xb10f8684550bfc6a,
+            0xa3906ed5a40de2e2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5de1f07e2aabbf03,
+            0x89fa6c09f792b3ec,
+            0xda7062296ae9a6b8,
+            0xfe0a3949081081d7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6944006d58de9fd0,
+            0x0b29a0e1e6bf1f34,
+            0xd35dbe0223f6bd84,
+            0xb90cf7c7dc938ed9,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd0a2a023752da655,
+            0xd31232a0cbb6d06e,
+            0x15d32f4a3d31f80c,
+            0x6a5367d36353572f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4f2eccab21379c25,
+            0x08ec1c15f857fa87,
+            0x341d5c2231800db6,
+            0x8132c1d54eee19b0,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7dfc8b9cf24fc86e,
+            0xe38eaa4a62de364a,
+            0x748184efae77c7b2,
+            0x950aa34f52777da2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf3e04f529ec2f2d7,
+            0xcc01da4b72597f80,
+            0x9970bec4be0fd028,
+            0xc8f0a5f441f30038,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4b7c276bdc3b4537,
+            0x6271a82c1ef4f0fb,
+            0x2e9e911b01ea912c,
+            0x2d9a7a95324b5d66,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd4645c5d44677dc0,
+            0xa020345c2f9433e4,
+            0xcd6a8b50b7c2d14f,
+            0x3695ae6dc10cceef,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8d2172dc3370b13d,
+            0xa197587b9f842097,
+            0xbab0af5dfaf29ebe,
+            0x63b2c2beb53ffded,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd31cd01219072054,
+            0xf60416ba8cd48efe,
+            0x093c4f11152cbfb0,
+            0x2187052ade1bebe5,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x37174ee8682860ca,
+            0x9363eaa4ee9a4729,
+            0x15d91058d5536499,
+            0x904fcc77d47e5fb3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0f2d8fb88ff2ef55,
+            0x1db6b86296060638,
+            0xc23a7fc669c5a37a,
+            0xb54a07fededfe040,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc6f07ac70b03de87,
+            0xe06486c612b06f7c,
+            0xcf98bf40e388f425,
+            0x0f124ea25456b076,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xab771c17857d76b3,
+            0xb3ce11e8d4931b95,
+            0x540a4ccda3394709,
+            0x07176f481d1adc4c,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x83cef0655d88aab3,
+            0xd3150d4049e5d690,
+            0x4341f1d6ee8d87d0,
+            0x695c4e9a2604f051,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe2adf4a13791c0f0,
+            0x027a16dabe2dd490,
+            0xdf89fcd584804ea4,
+            0x3a71d82e23c9f4fd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa57b4fb2205bd4f5,
+            0x24bf66f26890325e,
+            0x2af44ae90600ec58,
+            0xfbaf39216894ff07,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x490937c2f9718441,
+            0x92f0693d74139b32,
+            0x9ca768c98e43fa99,
+            0x12413c790f3ad8f8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x2985daa5c8262dab,
+            0x41a3e6fd21921ff4,
+            0xe354fa19f65be45c,
+            0x5044704ea0af2a6f,
+            0x0000000000000000,
+        ])),
+    ],
+];
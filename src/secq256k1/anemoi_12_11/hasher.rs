@@ -0,0 +1,3023 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U11>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0xb32375c2386260c3,
+                0xcb02a90babb57a20,
+                0x4f6384bc59ee29a2,
+                0xd14cbcd6efa59de2,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2ac0a802730da417,
+                    0xb2b44d75e1e5be47,
+                    0x9bcf70e519f90343,
+                    0x7801ccba12041d44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70ad956e5205bfe1,
+                    0x259d67cd1a7b35b9,
+                    0x3b27c6776f45f9db,
+                    0xf9d1639a3c705953,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdcb38f2726ad1352,
+                    0x99479f7776bf92aa,
+                    0x71ef4033c53e1f8a,
+                    0x0cd89dc040e39913,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42a9d0ca515f4483,
+                    0xf84a171e914d901e,
+                    0xbf0a76f2adc930bd,
+                    0xfb4f42ddd42ed826,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0974506518e00b4,
+                    0xe760a039285cda6c,
+                    0xa1d5efee7c932d74,
+                    0x666eddd1e176a8fa,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xce7179f44132a05d,
+                    0x4a969790e23a380b,
+                    0x7693be9c74374c87,
+                    0x5cc39a741a19dc26,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x809ef93c4d7bcdda,
+                    0x97aecd024fc4d9a6,
+                    0x9daba2befab876a7,
+                    0xc5738a076d934c97,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a61a1db47b35365,
+                    0xbdcd8a12b61766a1,
+                    0x61bdb02dc09ce472,
+                    0x81b2c970fae1fdc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc62a0437088a930,
+                    0xecfe78e09373106b,
+                    0xccb2a14ad24bd0cc,
+                    0x85932a9f4e212934,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa0fea5b918c684de,
+                    0x341081ffc98cb4c0,
+                    0x9355141558bbf129,
+                    0xc69c264ea3e878b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92472ad15220f1aa,
+                    0x20ce81bf1475727e,
+                    0x1977ee70fdfdd92e,
+                    0x0b1bec7161c5796f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0728b7565c66659,
+                    0xdc36ba5f06d723ad,
+                    0x9853a44e7c613412,
+                    0x85b280a2fb61d3f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x485fa5a9e4a78808,
+                    0xcd4e764b82e86667,
+                    0x86a7921fc88177c5,
+                    0x4f5ceb7fee847c29,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf33563a01c0e7f43,
+                    0x8cd189d2728f6c16,
+                    0xf59881ab25ac09a1,
+                    0x0e80655bd658c811,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xbd8a968a6fd49f50,
+                    0x2beb8166d73a3e11,
+                    0x6bbfdd11278456d7,
+                    0x5985515a6de33ab9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d16f666eacf4952,
+                    0x9d0192889aebb05b,
+                    0x3bc9d00de1ca92f3,
+                    0x9c485fd34a506bc9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e3e77d93a5ea3b5,
+                    0xa58e0ebb63aa3be8,
+                    0x5f2ab70a2b9ae4cd,
+                    0x61dffbb56eb835b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc5fd2b9eecc553e,
+                    0x48ba3cc23e634ca5,
+                    0x61752538eb461bd2,
+                    0x311aeb34b45b1b91,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52d8ba68e5b141e2,
+                    0xb3bd15575ca88df3,
+                    0x8da3828b2f387ad3,
+                    0x4e9fcfe38f6c1e73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6254253106fc311a,
+                    0x19a0fe73d8aead01,
+                    0x0e86bb70177125c6,
+                    0x289dc89ac37d8e75,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xee2865c043fe5db4,
+                0xec0069b788f93406,
+                0x6f18f848f438ce8a,
+                0x8d4a7b22b9313c8d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x94df4f4fe288558a,
+                0x75b3464aa4582b4d,
+                0x8479326d7375577a,
+                0x63943fcaf00f46af,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x696612ad5b7d7bd2,
+                0x0ce207ebe1caebe5,
+                0xeac30d0cc9a42e2d,
+                0xf51558f2859e6228,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x88bf394fb8ddf22e,
+                0x6e29224f2887f1f5,
+                0x621a32d26d19701b,
+                0xe9baf2b420d5aeaa,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x969afd9bac118151,
+                0xcbc83d370fc1d838,
+                0xd28b7b8f21bcddd7,
+                0x82c2d8c38f42f584,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe7995cbf8fe13422,
+                0x9ee525174630de77,
+                0x62d67b1b37e60bbd,
+                0xcdee62b41aa596d8,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x01f68bc8ba5ee454,
+                0x537973a357361cbb,
+                0x27ae3c27ef165c15,
+                0x9568e39e9d2b7886,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x90c22d9089352012,
+                0xe11ba3af5dec3f6a,
+                0x507f60e309e24641,
+                0x957f840dc7e07b5b,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xfb7c99873ee53863,
+                0xeaf202130a1af728,
+                0xa38d1db53694cbe7,
+                0x69abf07afb5af7c2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4a63e8eb374e0c00,
+                0x064fdc669a41d464,
+                0xc2bc19a2222e4531,
+                0x1ce4b2c17d8d949e,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xee2865c043fe5db4,
+                0xec0069b788f93406,
+                0x6f18f848f438ce8a,
+                0x8d4a7b22b9313c8d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x94df4f4fe288558a,
+                0x75b3464aa4582b4d,
+                0x8479326d7375577a,
+                0x63943fcaf00f46af,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x696612ad5b7d7bd2,
+                0x0ce207ebe1caebe5,
+                0xeac30d0cc9a42e2d,
+                0xf51558f2859e6228,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x88bf394fb8ddf22e,
+                0x6e29224f2887f1f5,
+                0x621a32d26d19701b,
+                0xe9baf2b420d5aeaa,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 372];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+            bytes[310..341].copy_from_slice(&to_bytes!(input[10]).unwrap()[0..31]);
+            bytes[341..372].copy_from_slice(&to_bytes!(input[11]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x55a259336314d12c,
+                    0x905e9a1ec2de4c36,
+                    0x068c55232e069a08,
+                    0x1f4c5811fc2bfff9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6beff2bd7412b739,
+                    0x2d8b3e8c2d01c8ec,
+                    0x9750a932b0c1d801,
+                    0x293185e5c8854d9d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x739c5b3e2665446c,
+                    0x4efcbef64429c790,
+                    0x9cb296d5af8eaa49,
+                    0x5f7b47a632548ced,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32d255413076a776,
+                    0x81cbb9c539d69c26,
+                    0xc12e9c18b0d218f9,
+                    0x45900b405e0a5cc8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4d6c178a99d0807,
+                    0x90d48b711376a054,
+                    0x3d2903e367c66452,
+                    0x1c78b4ea9dc9d29f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0c28cc662cfaa1c,
+                    0x2b00cbe48378bc33,
+                    0x9b2147a850fc8270,
+                    0xe92a758779c8e8bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44bf65fc1489d491,
+                    0xb896821acf672c7c,
+                    0x93a1e8281924c3b2,
+                    0x2b5717db2d2d40ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a677365cfbe37aa,
+                    0xe8b77155a2347326,
+                    0xcfa802842d168f2b,
+                    0xb41b296d25309b7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7af05fc1878fe90a,
+                    0x40634474461b3b79,
+                    0xa726e19234a79f6f,
+                    0xb902a3375c441224,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x657c5ba22b5766ec,
+                    0x6db3cfe33a4c9f58,
+                    0x8742390ed8e38c09,
+                    0x1d41b43bfa64eb2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20c336e6bee0697b,
+                    0xf5cbce5c9ebb174e,
+                    0xc3562a8ad2b8f801,
+                    0xa7f26169d63e791c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7cd6b738d7a877dd,
+                    0x4beb0cf6bab759e2,
+                    0xb31c9331cf83d83c,
+                    0x22843401435bf8ff,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1f09c8c6d286fee3,
+                    0xe6131a970e6881e8,
+                    0xa69912c9db9d0312,
+                    0x25041c64a5f6db6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45c0ef0e1b45f1da,
+                    0x5d8da52686a2ccbd,
+                    0x97cb0adf003c19c9,
+                    0xcc06adc584de44aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa71c51e91f998966,
+                    0x0def57a3c53a7d1d,
+                    0x1d7bce28cf3c8fce,
+                    0xdaa9e68059035818,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3b61dcc4af88790,
+                    0x2c9b18dba52991d5,
+                    0x9f2f1e06834c37de,
+                    0xa99cbf98c03b1620,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd927cd46db80f59e,
+                    0x3e355647c3a5717d,
+                    0xa2ff3218d1ea4f98,
+                    0xff7a625ae505143e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8b7103008375fde,
+                    0xedaf50b76d565ef6,
+                    0xc7b9648940a12a37,
+                    0x8954cd8c94c16097,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x458386b79fb5ced5,
+                    0x46e6562110f21f80,
+                    0xe924505f5a8ad306,
+                    0x249a9c859d04f34b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62053c4bab287a11,
+                    0x2bbc9adc28cf9024,
+                    0x829056f3367e5bda,
+                    0x0ae3eb489383418f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43c41b87731a515d,
+                    0xb4d4b537eed6fecb,
+                    0x533e6bf597f7d288,
+                    0x847db23c45a1b365,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcfbf08efd8b8a994,
+                    0x2371124afa0b4dd2,
+                    0x8c32e8bb7162435e,
+                    0x38f36f9640677ba7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28670b712eb75135,
+                    0xf96d548b289f3731,
+                    0xe0945b14706cfee1,
+                    0xfb7ea1cae79ba746,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c4903ecf2d3871b,
+                    0x315eb23d84732840,
+                    0xd2361032f863a9f9,
+                    0x6245fe538e1de6c0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb5e63e015648d663,
+                    0x6f38094e898b601d,
+                    0xddcaa57ae28e13af,
+                    0x9cb87b3c5f75f718,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a473552b5677b1a,
+                    0x41a32084ed869a54,
+                    0xbf48bd0e0ac43c1c,
+                    0xb5a6af72a0a8cc7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa55d41371a8a6bd9,
+                    0x2d18f30744c6c54a,
+                    0x27b19d5b636dde65,
+                    0xa47fb64538a84a00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04f0194cd03473fc,
+                    0x48a68b0af7fa3a8b,
+                    0x92ec67c101dec7ef,
+                    0xa26c6c22ae9fadc0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03a195c2ee9e7549,
+                    0x53c9fd5004091818,
+                    0x38ad4d6ef91d026b,
+                    0x79d933c363ed6741,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11494bf8f9bba544,
+                    0xaf2317ce706df521,
+                    0x1637e694fcd7a586,
+                    0xd3f8b171115fc04f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed4af42f8c027496,
+                    0x66b23c9d24304f20,
+                    0x6038b32df6631bb4,
+                    0x68d4f743bc209bc2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x428bed66eae2baab,
+                    0x8fc9346bd7685290,
+                    0x1721b6606af2b05b,
+                    0x98827b0dee4922d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f4bb888ec9add07,
+                    0x0afb541b291017f1,
+                    0x9496673f9b099eb9,
+                    0x65470063041a3796,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b1cb688a28672b0,
+                    0x45581ed4e459bb5f,
+                    0x526f8abd86dc7cf3,
+                    0xb3165fc1cce919e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e7bae2a87ede258,
+                    0xc8ab11fc9317a4e9,
+                    0xfb52bbe15c5f1842,
+                    0xf24ff83f18efcb46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb24b70ac308dd6c1,
+                    0xf8e01296bc04d0d5,
+                    0x367156ccee348f02,
+                    0x7172b732e98cf9ba,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7424b2868df97c73,
+                    0x84a0464c726f2a65,
+                    0x355e1d27e3b129aa,
+                    0xa8512b3c1f435124,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd0b4fc2246a389c,
+                    0x3ab8c3fe8f8cc110,
+                    0x3efb70b6d0851e79,
+                    0xc9c2f3651932fcb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cc5ee4f4207e64f,
+                    0xa6c7037ceb688868,
+                    0xfce89653c8c84376,
+                    0x7a2b9389eff8ab28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0af33006eef289f,
+                    0x122d0d57383b74fd,
+                    0x389feae709089d33,
+                    0x77b3c91aaeb70eb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b14b879c8caa91d,
+                    0xd2863f9751a87865,
+                    0xb502135614c879bd,
+                    0xab3cc39185661d54,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c5890a702b530c5,
+                    0xa1453f37fdb8dc99,
+                    0x4981d94de392a1e4,
+                    0x423a29a5a86d4fc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35273c6bec3b4dde,
+                    0xac4afe876693a03a,
+                    0x9b78ed7863bd22cb,
+                    0xc00ff36ebd8c2758,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f59cdeb20277d6b,
+                    0x34c56a35db303c05,
+                    0x5554ed0005cc546b,
+                    0x468acf3fa0747fb5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87a0e53ac005cd86,
+                    0x0f9207483faccee9,
+                    0x4af2cc9f25dad497,
+                    0x6f313cdaf6ce0239,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a769444d8177388,
+                    0xf195f7accd1e1438,
+                    0x7a089b14e7085635,
+                    0x28b1a3794380b993,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x86e945bd7496a59b,
+                    0x561503d70b22c047,
+                    0x6e6a5c63656ec497,
+                    0xbeb6a116ba041fa2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31d3d8f72683fe95,
+                    0xe568ee3a05bef9eb,
+                    0x9bc52388feae40b9,
+                    0x0636db2d9e97f547,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x480101988531938c,
+                    0x45d2bae90d7d85e2,
+                    0xfad23a38e8734f84,
+                    0x6e9b4101e3712e11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c8530ba73d64e02,
+                    0x5b1cc15dcb085c7f,
+                    0xc2099f8ce75229c5,
+                    0x0b78f333b342aa48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2e3514f1ca447cd,
+                    0x4e109684e701734f,
+                    0x7f6092ecef717edf,
+                    0x82537b9bbf2d262c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a9a8439cdae00cd,
+                    0x6d3fc4593b26d35c,
+                    0x160a35e619fe2bc0,
+                    0x717cda0ab0c88494,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x890ee5aec8972257,
+                    0x669f603c7733fb0a,
+                    0x9318bc5251fd9d1a,
+                    0x02a3ba396a611ae1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe71da5d313b6d852,
+                    0x24e404a2db4c7ec4,
+                    0xcec30b8b4ef78ce5,
+                    0xc6a4efea292a9d3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53f2b8fe4b396059,
+                    0xa2a7b8a7c634613d,
+                    0xa681d499e2c7462b,
+                    0xb87f2b5765e4c57f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbba5cb38e02045e,
+                    0xe90c03faa14884cd,
+                    0x8c80fa8364dcdda7,
+                    0x1ee734c2b215f944,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ae9e205bb51d65d,
+                    0xc896b8dbd6ba189b,
+                    0xdad6779885ba502d,
+                    0xca07585122522006,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3f6201b9b0c38bf,
+                    0x72a8662b0a878ee1,
+                    0xe926a84ba47caf38,
+                    0xc084176b4f052820,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ebdfd0924673af2,
+                    0xbc77b46d789a846b,
+                    0xedc0c4358470c2c3,
+                    0x6eab8aaa5aba3513,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x307f24857a7436c5,
+                    0x49c6cfd91ae292a3,
+                    0x7eb386c00d7a2671,
+                    0x9371a0de492e7706,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x72f141ad377261a8,
+                    0xb2397bf5b9c15871,
+                    0x124f37fd91a153d8,
+                    0xb90ec6917dd286f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30aabce3f2253b59,
+                    0x3acc73747a7650e9,
+                    0xb09d3ca83f81b330,
+                    0x63bba21b629ded4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0cd68e6180870b53,
+                    0x41543b27642f62eb,
+                    0xed2de68ff516a23e,
+                    0xa9d6b945921f3072,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19f7ec4b779cf0d5,
+                    0xc91e7cc66fc2d5b2,
+                    0x5859e43b71c6302c,
+                    0x8da25adbff900457,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf89fefa2d2f71c12,
+                    0x861cd2a06d9fd861,
+                    0x6e1a7e8f9a702ff7,
+                    0xd6ccf386f5e66e8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0fb89919deeef0e,
+                    0x3932872df27bcc21,
+                    0x9953c783e68a855e,
+                    0x6a9e6ebaef3b0c32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf75f144bba6fde29,
+                    0x1476fc61881544be,
+                    0x9b9f13906d7fd42a,
+                    0x8dd8c13b20475cde,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7890e8b28ffbd45,
+                    0x7bc0347875d5b000,
+                    0xe798f85311eb9513,
+                    0x4aa1eed3fc7f7bc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbd3b3df6d6b3c53,
+                    0x90f10e7b87e4defc,
+                    0xd9d4cc23ac84329b,
+                    0xcecd9dc4145f8722,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0033d7b3e6406f67,
+                    0x4e64a47d0f8c15bd,
+                    0xb893cd8e4e08f8c4,
+                    0x08d1809fc7965474,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddd9a256f98fe9b5,
+                    0xc4293f9f08a5f14b,
+                    0x8e619c36e2471e67,
+                    0x5e1ba245bf72fe70,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89b01da369a61c19,
+                    0xeb71676c73d6f351,
+                    0xc54435eec31c7ae4,
+                    0x58af4bd953fb71c2,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x8673f24dcd41fe8f,
+                    0xabb0a185ebf7f9d3,
+                    0x48afc9701bdb6a0e,
+                    0xed9622a7e12c60ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9e6b6cd427a24d6,
+                    0x75b635783c2e8286,
+                    0xe97c5265fa907175,
+                    0xdcd8a3d42c4e1e85,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14834abf54c89312,
+                    0xccb72f3b4bf83278,
+                    0x9c43ff343a37c453,
+                    0x3a5bf747f4dd243e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa289c59516ea00cb,
+                    0x865c9a5d6a7d9462,
+                    0x694af250291f7571,
+                    0x7c97cf7cd6e7d928,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0dc1cfadd80a0cc1,
+                    0xbadb9d2e3354abc4,
+                    0x669d33c72a38c8c3,
+                    0x3e76e937828e262b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x914321349cc11c69,
+                    0xce670faa4f4b8d24,
+                    0xd886b5f98ed20c60,
+                    0x86729a8f4ea165de,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe707c6fe628d083f,
+                    0x63c8b4c74b034271,
+                    0x35b86c774728607a,
+                    0xe89f1714f798851c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb36d714c0ac4f64,
+                    0x5980e4e89add444f,
+                    0x32630da9e49f2b0f,
+                    0xe31d9c15d00abe08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bca81f35b82f5a7,
+                    0xc582d7df18cd4a93,
+                    0xee92c0784833aada,
+                    0xed3fca30d63441bd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf1af8f3b658b4a0,
+                    0x1991d48baa215f65,
+                    0x25c0103bcbc1c3f8,
+                    0x331b6714bdc9add5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7fbb15a8ae1ff155,
+                    0x00a6dc65df79f6cb,
+                    0x60c774ee06f66831,
+                    0xa231853746a21907,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x639540603276471d,
+                    0x239c0f7e3299abd1,
+                    0x0ef55192d4180ef5,
+                    0x39c7070980eb133b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x007be21c39e82e3e,
+                    0xe7afe014f7a7ba84,
+                    0x8e8bed402da5d1dd,
+                    0xa0d42a5b8e2ede83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee9cb4d30f36979d,
+                    0xcda8e2841243a2f5,
+                    0x784d3b0c5c2419a3,
+                    0xe0fc70846c592389,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06e4ee7ec98d6531,
+                    0x12f7f4bcf9bebd24,
+                    0x104c646d95e1a9ed,
+                    0x07eaf4f602be7466,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ad66421e0cb3561,
+                    0x9b25415513600a5b,
+                    0x0475609acc538373,
+                    0x905f22d7f8378c46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3253b32f02489f2f,
+                    0xacbde584ff02c739,
+                    0xb6240029d18fb610,
+                    0x6868e879cd78add9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb96e5802ab52a84,
+                    0x7aebef3a43329993,
+                    0x010b0a543f5edd11,
+                    0x8bb7353d0e7fbfca,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9b50df308430c700,
+                    0x5523ab252708b0c2,
+                    0x698fdb10646dafe0,
+                    0x9d95987e0379ba86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e7869d2e57c2330,
+                    0x04905bed5bfb8a0c,
+                    0xdc6dc016ce65715a,
+                    0xe4e007567649ab79,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe9f8d732d1e677f,
+                    0xa311de5be997d149,
+                    0x989c3e0fc7f26cfc,
+                    0xb035cacd853679fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46622d3a4dc5886f,
+                    0xe468b12185f98d75,
+                    0xc413f9f386397649,
+                    0x8085743920a78c50,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0cfe588f42c62da,
+                    0xdc5c81852fda2db7,
+                    0x3a9cd11058d8f3df,
+                    0xb2e10d49fd30b1dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x903b5eb1ee464f84,
+                    0x0b761ffae4ef9ae5,
+                    0x76076402dd9dbded,
+                    0xc809e0d7412ea32a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe75e6678bb263e3e,
+                    0xb410f9453f59eefa,
+                    0x698d2190005e9bfa,
+                    0x1cbe6f3064181e7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf48601eeb814013b,
+                    0x473f51f4c4f2e81b,
+                    0xdf7f157895ee02d0,
+                    0xc3b5198a7d1e9b68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4efed56de94170a7,
+                    0xb291495e4456f85a,
+                    0xe03c5168e2a0ae93,
+                    0x9d934d377a716084,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee212e0320d55759,
+                    0xaf4e5bcb015ab328,
+                    0xfbe0fe0774fa17b9,
+                    0x37f8d452258246cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5392af828b36c25b,
+                    0xaa09d27255aabd00,
+                    0x59bf8a2cb4bb0118,
+                    0x59ad21ce067daac4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8ff5dff25e973fe,
+                    0x8a7fd4fcff353858,
+                    0x038dee538a917027,
+                    0xd40e873535d775dc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x640aadd321b5b6a6,
+                    0xd1d6430a8bbdfe58,
+                    0x09bf2a3493e533ba,
+                    0x9ca581a3d02aaa0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ede65c87d0aedd4,
+                    0x568e7738e96fc966,
+                    0x239e398e2942daed,
+                    0xdf757f5021f0549e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ed9038b3b2d84d7,
+                    0xcb40a61d3a976fd3,
+                    0xc04460534696abe6,
+                    0xb9f6322847f94075,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd84bc46f8c06a49,
+                    0xe2a7f6eb62b5e74d,
+                    0xa898c48a15726623,
+                    0xeea3ab8abae01027,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b512831aed66864,
+                    0x7735b098c9683edf,
+                    0xbc5528faf9a96e3a,
+                    0x6efdc99c4f221eac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebd3503fc0480411,
+                    0x9f75ffd06c832822,
+                    0x2be6016cfd050681,
+                    0x0167e6050a7d0b71,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x230902b48856aa9c,
+                    0xd62d695fbf7285a4,
+                    0x1a127642ca379bea,
+                    0x73feb42172a54343,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8dfc6be235ace3d3,
+                    0xbab01edf3a5f85eb,
+                    0xfdf3d95c370862de,
+                    0x4a5ccc1d2ada6a46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51e99ec798b6918b,
+                    0xbd883b45a9221893,
+                    0x81bd5ebc177da2d0,
+                    0xebfe6ec0f39cf28a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b17be65ccfd14c2,
+                    0x044ba362d1d23bd2,
+                    0x03d712057d4839ef,
+                    0x1a0991a1cc0c5b79,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf9e5b4b8d07f896,
+                    0xabb041ba66807ccc,
+                    0xf809cca8e91a411c,
+                    0x2ff82f2aace2c018,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d137d2c7c881f1c,
+                    0x60e3c126b36a7c84,
+                    0x882d8e71d631adc9,
+                    0xd77c69a03ecf3064,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x13a9cf4da31ff610,
+                    0x28f4f39da60510c9,
+                    0x8d993ad56a3a4373,
+                    0xe83e232367258a3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28900d54b81774bd,
+                    0x4f90666e861ee5ea,
+                    0x81fd9d5bd8244cc3,
+                    0x16cb909ce922628a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1b55397a410dc5c,
+                    0x4fee3dd55582962f,
+                    0x6d437dbd4b8c8427,
+                    0x6b6f1d2d9282703e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x272155a932d30d84,
+                    0x0976ed985a9f5449,
+                    0x48499a76b28501c0,
+                    0x392fee39deba4973,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x267a723d79b7a89f,
+                    0xd449c995b08e40a0,
+                    0xe5aaf526c072d6a1,
+                    0x0ce4072f452f485a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3860533d2ff63005,
+                    0xed2738a8beacbc39,
+                    0xaeebff2df4687c03,
+                    0x0775fd714ecb6e44,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0c10d28150c68a94,
+                    0xd05174a75b9ffc55,
+                    0xb70a477ec33eb0e9,
+                    0x0f92821131ff85e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb83bb5abb967c9ba,
+                    0x45db3f90d4da7713,
+                    0xd608ef49a1ea83a1,
+                    0xf336e93a23648a51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3e72e05f8e028d5,
+                    0x96e0994b556821e5,
+                    0x42ba116fd870775f,
+                    0x4b67487ed954d321,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca95c039b6707560,
+                    0x102185c06c9c0944,
+                    0xb7dbdb41cd58db58,
+                    0x62003db26cb0bdb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f6040a8ec9f95f4,
+                    0x82398560a45f0d86,
+                    0xe4cb003ec3dfa578,
+                    0x28e24423fdbc2ea2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c1925cadee02112,
+                    0xb7993b2517041b80,
+                    0x0555e6297dccda6c,
+                    0x62649b9cd1e1142a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x64f5b84751f87145,
+                    0xa4f9ffdbfb1125e1,
+                    0xf63ba08fb7ca6a8e,
+                    0x627924f97484b065,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2955ce8d1c7e9e12,
+                    0xb493f2376e0518c9,
+                    0x32cc4049cf2f89e3,
+                    0x285cb06a3da529eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ad28dba0d656a38,
+                    0x7f7f6f3e452b337d,
+                    0x6c74bd40fea2213e,
+                    0x9597328df1de056f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x632dd2f7881c4950,
+                    0xe882ebce42b946fa,
+                    0x436f9c0234bba7a5,
+                    0x0249c1bdfb8bafa1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd89475a91175b563,
+                    0x69e284336b9028b3,
+                    0x28d624a8597a9f4a,
+                    0x0d1a2bdd8a35f34f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdef3ceca85f38dd7,
+                    0x9d93d9b2fdd0c143,
+                    0xe3088d702f2c7f1a,
+                    0x872955b309c436f9,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x437eeaa88e8f98ce,
+                    0xb00145f6e0062bfb,
+                    0x4dd104c9300a6523,
+                    0x4fc8f4d6b5ae7de2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x375b047e898adc46,
+                    0x4aefb85030236e57,
+                    0x490df526f862b0df,
+                    0x1c25890045f18bfd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5975fd686ddf3ae9,
+                    0xab885edc34dd7365,
+                    0xcd5316491065adb1,
+                    0x6f63e29f6a628204,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x348e40e847e4aa01,
+                    0x490b34d36e433d60,
+                    0x58fa5dc64b45888c,
+                    0x8cfa8ead6a1bfe4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef059996f18ec195,
+                    0xb60a83a1b02a17c6,
+                    0x5e6fc81d9e312aad,
+                    0x2a190e8e7b7118ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53fa858f1a60aff8,
+                    0xd04506f8ab85c441,
+                    0x567c7a0f2777ebc6,
+                    0x1ec5ea297ca90752,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x240217f8240505a3,
+                    0x08a01e5954b1fa50,
+                    0x8a2504c72e94ccfe,
+                    0xa49e56dc798ab271,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa311670c0cb637a,
+                    0x9c8591f501bf9d31,
+                    0x7615c0b73f4811f4,
+                    0x22c5d8a4d8899137,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe267eac6e6910431,
+                    0xb3fdca9d49ba36d3,
+                    0xb34ed15d6be29354,
+                    0x28f6f8a0663b2fec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4fe497eded78207,
+                    0x0999e92af258bf5f,
+                    0x3da075bf98a2391c,
+                    0x4bcf8279e29cc0bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d65bcba25768af1,
+                    0x59a940e553efc6dd,
+                    0x017e3432813df469,
+                    0x8be0abff33758f7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61ab6eb106ae67ef,
+                    0xcf5998cf3f4e6327,
+                    0xb65e974f192b0991,
+                    0xfa2d33d1083dff07,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x20c9c7ae788eefe0,
+                    0x2ddfd06e003afe1d,
+                    0xe58e0279173fee72,
+                    0xb52503e95d26750f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x162f26c7d1829822,
+                    0x11c74b4238578d6c,
+                    0xbb5ea55dcb349cb7,
+                    0xf3cfffe88b48af89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6dbc31030bd54f37,
+                    0xc61b35cfc217a964,
+                    0x6a40d2b22890486f,
+                    0xf3eb7420a1ffef76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03253578b9844078,
+                    0xaf80f6d46c66bf27,
+                    0xc621af5e9576bc68,
+                    0x9ab375988179d1e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbce4b856e9ff8d65,
+                    0x51e88b63ff76ea94,
+                    0xf9ef300c394f68da,
+                    0x9fb2ab5eb897b446,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c1bd5361aeab067,
+                    0xa191fc76f3d7b3d1,
+                    0x98359a28ebead739,
+                    0xcf1e71b1d2bd2d6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa41796c6d27e638e,
+                    0x95d52a81d156e18b,
+                    0x3e3c50b7c225b55f,
+                    0x93eb6d9d9810d017,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x156e9ff180f25fb3,
+                    0xcc6294b40027801c,
+                    0x4cc3734d278b64e0,
+                    0x78567e3f07a6829d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07e09e8ebb13a476,
+                    0x075de7447c3be183,
+                    0x9c95fb4ba8458097,
+                    0x602a5365c2c0dc5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c1ed6ac65eb4ee2,
+                    0xc2c415d92e80e427,
+                    0xe865c1ad3cab4b26,
+                    0xd61873dc78cb67f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14e93b2d6b5e0cc4,
+                    0x2c412f1f05eaeff4,
+                    0xc48e37568612c739,
+                    0x7cd65d0f3ed87a1d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5e1ad18a2b60e51,
+                    0xa032b4027e38f731,
+                    0x0ae197e47290661e,
+                    0x21f642e163638f08,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1a3b8ebd2fd9f393,
+                    0xdd376203708bf813,
+                    0x060b0aba2e6de8cd,
+                    0x48ecb355c9243afd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeecdd61479dc3220,
+                    0x0cdc63e648444509,
+                    0x766d66845988e51c,
+                    0x00776a7acf666c27,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe57d161209c14d8a,
+                    0xf02c1deb4a10fe5b,
+                    0xdce4e6b2a8360024,
+                    0x14cd6825ede4d28e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb86936a0ef1cdfe0,
+                    0x865a9238e1f8db4a,
+                    0x1f44ee45444cb30c,
+                    0x3585aa2b4a9e96ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x746f10ae5fb42734,
+                    0xe8b3a49f24e59ccd,
+                    0x065de41e6af03fb5,
+                    0xc2c89ac456fa0d91,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7fb505b26adab62e,
+                    0xd2451ac61d64a18b,
+                    0xf1c067e0edf9b64d,
+                    0x38371cf5d20f3bc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cac1816106fb98e,
+                    0xb3249ed2722f824e,
+                    0x72595d5390de3261,
+                    0xa3d9156b5e017c52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d1ce3dd1880e70c,
+                    0x62458930520f7348,
+                    0x587eafc502e1b267,
+                    0x669d9ff5d9ca3574,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78c13ed05e3199ce,
+                    0xa5706a25d33d794e,
+                    0x5c1ae58d1ebe3c80,
+                    0xfc900bc2d8fb14ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0d7e6e7d99c80f4,
+                    0x6e250334ba2b2b4b,
+                    0xf797b41bedef53d2,
+                    0xfc20cbb477c2a0f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe986840d567c0cb3,
+                    0xdb52e549f16c92c8,
+                    0xfc277854abbdc140,
+                    0xc05d27202ea25de5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63eb3b89bbd9262d,
+                    0xa0810e9192344273,
+                    0x584d38b9e2d37371,
+                    0x1dc838a265ebf459,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2f2725eb8730343c,
+                    0x5493f3c13df9a9e1,
+                    0x4c48dba8705e7ce9,
+                    0x191317bebf7a4c1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3bb10167af525df,
+                    0x0de1c9e3a9078b7c,
+                    0xa77ced911a5c87a7,
+                    0x7ddd8c276f067086,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcef9d03e6985f507,
+                    0xf72fbfd242a7484d,
+                    0xc76ea36f460b7bf2,
+                    0x58eb191781df22b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25da6aca3074b1d3,
+                    0xb25d2cd130baa2ac,
+                    0xdc1648094459851c,
+                    0x5b5b64a80b7997e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x406f8534a89154dc,
+                    0x57182d4391d98c87,
+                    0xf3534278219b8ba6,
+                    0xcf7508026a1165f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cd40188fdf38ba7,
+                    0xb96358ca813df840,
+                    0x6b896158fd805982,
+                    0xf1da33d0669581f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe50c5473863632a0,
+                    0xbc3448754349fdfa,
+                    0x656b8e5c61472765,
+                    0x5de82196c1f4d1c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf50c1bd90830a50,
+                    0x42454cfe6fc95a87,
+                    0x9e9a9c84b39f10d4,
+                    0xfe67cd604456d9a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d55831fade384b1,
+                    0x4786f4c8a77ed62a,
+                    0x3b40801b4b397056,
+                    0xdc2d86e918f16070,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcbd2b2cfd61310f2,
+                    0xb6c7cbc975095b65,
+                    0xc348b722210a6a87,
+                    0x357a6dbd934db3d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff4a200bbf28d4c1,
+                    0x1ad95e96dd2a9d19,
+                    0x0013510a7e29369a,
+                    0x6d401d724b1c95fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a16f7dc42986f64,
+                    0x7f5de730355c5a71,
+                    0x12e09df2b7594c77,
+                    0x3e88bd6ea8a1c52c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xc0946945e24286d8,
+                    0xa066821d86a2d86e,
+                    0xf918733bd9845339,
+                    0xf06a33dac5f033d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f3cfdc9e2b53cd8,
+                    0x4db91e50a48190dc,
+                    0x7d64b8c3c407cc6b,
+                    0x7288b98add80958c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60064510a6776afd,
+                    0x46574c3dd264ee89,
+                    0xfeaebbfc97080c97,
+                    0x1bba6b985be6cdcb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa6f47e1caa4149f,
+                    0xcb3e5e3de5351315,
+                    0x64d2561a36d262f6,
+                    0x98479c5062445df4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32a2e3f676a97578,
+                    0x13821476ec0ad6ba,
+                    0xd7ec762f1af21f31,
+                    0x695dc6037dc114c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ca4650ca08c487d,
+                    0x4b9ce4c8cc2944eb,
+                    0x87c23546551832e0,
+                    0x804944b0881aefb2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d254bf2abe84ccf,
+                    0xc21c97e07199a7b1,
+                    0x21a1337a20cf0485,
+                    0x7d40e5ba71179249,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42080ec8929154a3,
+                    0xd899913b7b8b9539,
+                    0x85b684d4a15b2e99,
+                    0xef3cdff0fbe142ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4b6860c17bf6222,
+                    0x54b165b2e0a8c692,
+                    0x4d1def1c281fd0bd,
+                    0xc2fed748474016a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa77d1bd0d5ad4a86,
+                    0x2a6906fc3638bb22,
+                    0xbee7856e5aa17aeb,
+                    0x859efca2cbbeaed9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c8336370b1eb475,
+                    0xe55104b1150d6c65,
+                    0xc7b27995675a6bec,
+                    0xa78eed7dc749fabe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x662d4e5f5be017b9,
+                    0x81656b5472f5a3e1,
+                    0x6d506a60f4be5b6b,
+                    0xc1c27a86f48f8b79,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x70be08f1a44ff888,
+                    0xecd004143fd7d269,
+                    0xc18524976ba7e60a,
+                    0xdf97116b81dbbdc8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a8456f28844e88b,
+                    0x9677244c7ee291e0,
+                    0xd4995c8de218f56f,
+                    0x44269e12cabad800,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x491b879f9f4a746e,
+                    0xc6349cf953beb0cf,
+                    0x7077ea894e21219f,
+                    0xa2296219d6b42c9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf0f98b155777c83,
+                    0x0fbd176323b6dd41,
+                    0x705248404a6363e1,
+                    0x9ee8678421dbedb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74d6a6af881c6cae,
+                    0x89b0f8266cc361ac,
+                    0x65772a9ee53cad47,
+                    0x8b15b1fe77fa70d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c5d935c1a36c210,
+                    0x43a769766d35475a,
+                    0x966ac07c2748d29a,
+                    0xd1131b6280e77177,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e48822236fa4b5d,
+                    0xc78572470b4f88ee,
+                    0x900fab8eb1330f49,
+                    0x132a002fdc4ba694,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa22cecfc72940c98,
+                    0xbec002a8dd42e6b5,
+                    0xbdf35d4996474f7a,
+                    0x035566904146052d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50c3fed75a94ccba,
+                    0x3a5bfe785cf79636,
+                    0x75137fc5177eff72,
+                    0xd6d5f4270a7dad95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb80c1e0b38bf8cd0,
+                    0x4f357dfd388f7b3f,
+                    0x19579957132fe7fd,
+                    0x4ac170cd575afa49,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6534c1f1c31393a,
+                    0x55b003b5319b421f,
+                    0x29a0529bcf479951,
+                    0x531c27d8ec28760a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ba8c498dd7ec7ae,
+                    0x972d7b1e9fa03e4f,
+                    0x478dcf40a0e6ebc5,
+                    0xcbd96cff29c4bad9,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xe8e6ae2e29de5d21,
+                    0x78949108bbfc37d3,
+                    0x4b90fc6b804bf727,
+                    0x666903275897ab17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1de13f0a25ef00c9,
+                    0x0fcb029946af03d2,
+                    0x2b4dfaafb281f349,
+                    0xdfe30de051d75d8d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x72e8a180cbc36cb9,
+                    0xb494af3ee4b94359,
+                    0x8512a1dd96527388,
+                    0x7810667d146edfe1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e14b1dbd94509e0,
+                    0xdbffec0bc84faf4b,
+                    0x67186f788478fdfd,
+                    0x50000a340ebf7f18,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x79e2253d3587f15d,
+                    0xecb6dd7041209ea5,
+                    0x54fc51d7951731dc,
+                    0x112807cb5e6600c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5379fe84a80b641,
+                    0x290b362cb98da6a9,
+                    0x7dcda5fb67d67a2a,
+                    0xfd12c89973106f99,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcb1b9513050f0ed7,
+                    0x5f345138e1e96f4c,
+                    0x3cc8ea30853910bf,
+                    0x00ac709585e0e660,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x757138a5811b78a1,
+                    0x7f11733c685371ef,
+                    0x16891e0d323ca593,
+                    0x2d6f5c66d81fdaf5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x664cbcb332cf3ddf,
+                    0x1417cea07d9a9ca4,
+                    0x6b7412673a951514,
+                    0x14484d44961cd2ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa83542a5b09dbca5,
+                    0x818dd26979ecc77b,
+                    0x19261d36b3863557,
+                    0xe21af95f0e53a27c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x767eb9c03a1a7f73,
+                    0x979aa0291c6c2e69,
+                    0xe67727a0bbf4eea4,
+                    0xd978994078844ae8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7bf7a1ddd9aaf74e,
+                    0x2305407a6dc4a64b,
+                    0xd7c9d859cefdf6c7,
+                    0x046f43d346423b68,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x470f5486dc12c8ad,
+                    0x7b6a1fee73f7e100,
+                    0x731f6f3e1ac2ef49,
+                    0xb521ff928cdfdd34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83115dd76de74507,
+                    0x4b3d9610a9f242da,
+                    0xc64bf280204edd86,
+                    0xbad0e92ec01459d3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd1678bd737c63048,
+                    0x743415128012f9c5,
+                    0xd85a002f5b50ffcf,
+                    0x6dd873440414176a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16ebfe0cf28f58fd,
+                    0x54d7342be4e5d0ec,
+                    0x9f2a25bcf63c95ff,
+                    0x1e3f0302faf93919,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3c3e101ef483037b,
+                    0xa332f0c545292ff3,
+                    0x48b15de9356a8e37,
+                    0x7be968805f8d773b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1615c7e09dd32fc,
+                    0x7b842bb16105461f,
+                    0x67b9f59b425c8e60,
+                    0x1cf08ae811b73c6b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfcff3329aedd224a,
+                    0xe37d043fe342a6fe,
+                    0xbedde619a66f49e7,
+                    0xed427559db055db6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x316e460f3366e944,
+                    0xe742dacf52332d25,
+                    0x83133f6ca0c4f5e3,
+                    0x931b53a5859378bc,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 341, 342, 1028] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 341];
+        let mut opad = vec![0x5cu8; 341];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
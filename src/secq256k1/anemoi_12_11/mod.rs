@@ -0,0 +1,2906 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5552011018aed531,
+                    0x56341ac1d3cf3ac7,
+                    0x15e7e37cfce0126f,
+                    0xa52c89e4478769fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18bafc744f35d538,
+                    0xa7f6f16494c53808,
+                    0x42672cf99461f100,
+                    0x009dab43e2ae0ddb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42207a8a39d09be8,
+                    0x4438f24fc15ed4d4,
+                    0xc45992c86cd180f3,
+                    0x0e16e5fbd3f387b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77f1cea52735c3ac,
+                    0x71efc0759cc78304,
+                    0x69396488fd269209,
+                    0x7f69a51622248401,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95602bc99c926fcb,
+                    0x85f228ff86c19756,
+                    0x8948da686ff8348f,
+                    0x7b058fbf93915b30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc710b12b3d421257,
+                    0xb72ffc214428fe08,
+                    0x0b3ae10006492ffc,
+                    0xe99e3d18d4525904,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24400789d82e9acf,
+                    0x3a3a8db4de0968cf,
+                    0xaa640ffcdb3bf934,
+                    0xcf996163b1afdb38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7153b24799f07924,
+                    0x53e29eef2551b57c,
+                    0xf5da0b4e621bfc5e,
+                    0x37389413686dda22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15b2c23af420eb09,
+                    0x5f32681829db9191,
+                    0xfd5a68bb693098cb,
+                    0x633351a400538905,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1bf56c3eb976c6a,
+                    0xf7b0a936f3f80e06,
+                    0x7ca27b06cc70856e,
+                    0xc3d0c44b4ab08cb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd46f349156016b0b,
+                    0x9ca4eaffb2eca45a,
+                    0x879efe8abcab14d9,
+                    0x81783a3ed0c21e03,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3e0df592e548cb5,
+                    0x4022c5650a6c4af9,
+                    0x7e3699fb3822a67e,
+                    0xb970aac81b0f68c9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd6462f4a910d8e32,
+                    0xa812414021fe5c4d,
+                    0x61ce450d4fd5b6c0,
+                    0x7571a02909e65b0a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1fdfbe09b7b0e46,
+                    0xadb7e339f1b39f48,
+                    0xf14df0adc9c46d31,
+                    0x55f20664781b5305,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x894703e4d7a169b5,
+                    0x37d84483139d7152,
+                    0x7b631d599e102894,
+                    0x7e63d1130346102b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97a1ee1ae596b67b,
+                    0xf9a400d962170e57,
+                    0x3afb51ea8d5bf376,
+                    0x9b0af808432ce45f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9918bf01deb9c6b,
+                    0x83fa9035599862a2,
+                    0x7743d111fa38fd8b,
+                    0xab42e9e5c5e2396c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9653e24413c5c95e,
+                    0xb033b1b6c5f30898,
+                    0x9b1e659c4aa84500,
+                    0xe1f306b7e9666a6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc01ede129073dd0,
+                    0x04d8d0f51e3225d8,
+                    0x53d63db75de6868a,
+                    0xa9d9f1f1093a0d55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeec684302ec22eb3,
+                    0xaec898fe8657977e,
+                    0x089b321f3c07f007,
+                    0x42b50834c27479d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d9f11b6c1abc602,
+                    0x61f881b96c5bdbba,
+                    0xf2ab4c5e668586a8,
+                    0xcb7833355cd2d30e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf43c55675cdf3bb9,
+                    0x8cb263840e945657,
+                    0x542297e8ba94c77f,
+                    0x46494992297caad2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c3ad61c41ac7e8a,
+                    0x3ae4c720dc1c0026,
+                    0x4aff79b82ed99482,
+                    0xb4f9ba768a109652,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74a984740255ccca,
+                    0xfb37473536a8a5e1,
+                    0x74871c4224f4c755,
+                    0xc87ff08c22c094b8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x82a91eaac989efc5,
+                    0x06492520f2bff751,
+                    0x8ae7272ec2dc2444,
+                    0x14fbf339723b91a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24bcbaa2ec6d05d0,
+                    0x7d42474f0b0b2ae4,
+                    0xb4b4b2d75bf63aaa,
+                    0x9061dd9dee9accfe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f7bbac5714c55b8,
+                    0xd9bbf66b1f7f01e6,
+                    0x9907f9fc68ce2952,
+                    0x4b2123ba9f226bbd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab682006b6c6827d,
+                    0x6549795eae34675e,
+                    0x74226133a446fd2c,
+                    0x98b3ab4ee1e0e49c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x920d7a17e12a18f9,
+                    0x3738c81605ddf0d6,
+                    0x34e3c7f6a003599b,
+                    0xa9064560aab93a52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20b0348b8c80d0de,
+                    0xb7d700127ec41d50,
+                    0x7c2f64a56760dcfb,
+                    0x6824d50904b4111c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc376f676077d16ba,
+                    0x6ec79dd63d389854,
+                    0xc88c94e21b48d113,
+                    0x61fb22ae791c36bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe58b1fd691368dd0,
+                    0x4abacd69dfc98612,
+                    0x911f00de140abd0a,
+                    0x097aba0bee0c05a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00930956f8ce7011,
+                    0x42c8f583b94850c4,
+                    0x8ef07888c466a2d3,
+                    0xcd988bff9f7c1ed5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9c3228395ebaa18,
+                    0x20e5750947e204e4,
+                    0x35e784e81db820c9,
+                    0x2cceabfad8009780,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b1d98214577848c,
+                    0x256699feb4e236b1,
+                    0x559cf7c07e352cb0,
+                    0x71755d005900006a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9f8c1f8f6766281,
+                    0x44758821d942c3f6,
+                    0xefbd33dbea9fabae,
+                    0xba356903db2c4f8a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x38c85e6e9841d509,
+                    0x52e2b27140be01a8,
+                    0xed986fd860ec6715,
+                    0x8c4f2ab14cf7a3b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f0e3389e69a5732,
+                    0xfaeac86a1666124a,
+                    0x7fa8dad7f701666c,
+                    0x7ff27d9cf9cf8f45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28e5057dc21190ab,
+                    0x30a2de1ae173ddd8,
+                    0x96ae9581753b8575,
+                    0xb527f2b346916d2c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e547178a1f8b545,
+                    0x079e9ee984b8df48,
+                    0xc888c9979eaf69ee,
+                    0x5fd4c2af38a9fb19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee1e002d9de654bf,
+                    0x888f994de717bb98,
+                    0x5ec3abc2f2b4893d,
+                    0x7f137f6875a2d261,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f016622e00d793c,
+                    0xd7b7e2f0b22b38fa,
+                    0xfcfe8c14a1836ec7,
+                    0xaaab5f8e98490cd4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf24a8dd3a56bb1ab,
+                    0x0b2ffe2fcbc50fcf,
+                    0xc287473e8d710865,
+                    0x8ca76379922eb4e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69957fed783326a0,
+                    0xc442b661a24a96ec,
+                    0xb7fc6cd3e3ee5678,
+                    0xc35b50cdb398a143,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc066fe20b435344,
+                    0x6bd05a677d27def3,
+                    0x4f18360aa1ab9319,
+                    0xd6af234492de5a0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0dc5b2fc96e52470,
+                    0x945b16c3a0f826a0,
+                    0xf947ed7aa6c17a5a,
+                    0x8b424ea56bbfdb11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa74151704f8ef890,
+                    0x902b2ddc6b950ad3,
+                    0xc95474d7fb83e086,
+                    0x35edcbb5394a539d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d18280c74887d0d,
+                    0xfe38c326be53963b,
+                    0x12c2458415a0151f,
+                    0x56a97906cbe3310f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe29506905569e9f4,
+                    0xe164239279bd27a6,
+                    0x20616eaabbfa0bf2,
+                    0x844e17c52d7b2a9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfac302579809111b,
+                    0x264e885f92a7172c,
+                    0x8e5b9c51a3c348f0,
+                    0x9b69bc4b871bba26,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0127b80db532736e,
+                    0x7921f2c1fa02d191,
+                    0x01832d1153766065,
+                    0x49636813ac8a3ed0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fbb771b0e3a9337,
+                    0x289a24c1ca4f968d,
+                    0xfa3f132e7073723a,
+                    0x77cff23bfcc486a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc44d7932b3501fe,
+                    0xd091e541df3f4bbf,
+                    0x01f6896f537b7df2,
+                    0x448057991fc99aac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f716954fb8874f5,
+                    0xa7d3a7b81bfd943a,
+                    0x94263b0545cc687d,
+                    0xb0231236194842a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3839d8f812727ff0,
+                    0xfb267f25c4220ea1,
+                    0x7959df756e00672c,
+                    0xf97f9c27385608bd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x09ba21f85aef3512,
+                    0xbb73ce08d83588f3,
+                    0x955142e617bea889,
+                    0x45d78fb5c9ab89e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x473917d97c0333d3,
+                    0xf17aff54b5bfafb2,
+                    0xc9925eb11f0caaef,
+                    0xafe0a93d3fd33d32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x359faf9e17f6d51a,
+                    0xf46318ca94263b20,
+                    0x90af1af88ae3a6dc,
+                    0xf153c9fda9c97fd2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0a7da999c265a9c,
+                    0x3b3de28e6258fc54,
+                    0x1c05fc2984f5f849,
+                    0xe992edad701a7fd4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20903a2921f5d078,
+                    0xfbc2e8d0eedc398c,
+                    0x90fe75b01047a0ad,
+                    0xbe5e8478a1c45bee,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3910af6ba662e26b,
+                    0xc869fe6530b39bb2,
+                    0x80464eb3be2618a9,
+                    0xefe7aea6ceddb537,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x916f6cf5eec705f6,
+                    0xaee5270df3334d51,
+                    0x6afab9c2707cf4d3,
+                    0x40749430d0c6fdcd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a90cb0266ca2749,
+                    0x04df678da2f13e1d,
+                    0x0b1ca30648bb6897,
+                    0xd60aee9ea42e8467,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a835ee0691babee,
+                    0xd7f8c8f3fbfe5038,
+                    0x7aee1208f3a54587,
+                    0xd4103b0c900fea22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85329ab02d00d5eb,
+                    0x6350b67468a64528,
+                    0x6fa3fce1055c638f,
+                    0x4564194f71cdc5b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecba7c7f2db2a138,
+                    0x87f200e63abf0d99,
+                    0x3cbe78abdf63673d,
+                    0x86add471ba7cb385,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43c68b84bfd82afd,
+                    0x87f25e8fa8254f8a,
+                    0xc4e11202db7b5704,
+                    0xffdc12860705cb9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cf8723bd2b5d173,
+                    0x55e56c5f59701e64,
+                    0x181f27b79544a8f4,
+                    0x77a46d9c6c200042,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ae99920318397ba,
+                    0x8611e4e0d3bed749,
+                    0x04608e543141dc5d,
+                    0x9246b1d695769e73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfef7640d3196a132,
+                    0x3ae87f16a65e39a9,
+                    0x28bfe3e2e05c5347,
+                    0x30c3805af0defa02,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d9e795e236398de,
+                    0xc0823ea3245e9c62,
+                    0x08d91c49f461629d,
+                    0x1f40eb163f2f2127,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03191a6f536b05d5,
+                    0xa6ff8de53dac6303,
+                    0x1a8f36791c85a6f0,
+                    0xf4568a3a93a143b7,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6eab218fea9cea98,
+                    0x9faa2a6239de502f,
+                    0x83df47142e5a5d99,
+                    0xbd1d28ffd4bff16b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4636a3e6f70f7579,
+                    0xf105818f67f066ad,
+                    0x6caf7eeb1f70c0d9,
+                    0xfbec046e65dc7e93,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ffa3177de886809,
+                    0xc72e64ca7f2ef187,
+                    0x59e241dca3abbf13,
+                    0xf6fad68a251f2c5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac563b65ffd86e07,
+                    0x594461087e3600af,
+                    0x060ae632c4d2f063,
+                    0x577c5a9a66bc2ab4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x688cc1b97bcc3d70,
+                    0x3ede40a84154b1f8,
+                    0x2573642380fc8d5b,
+                    0x8a53829438782196,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x580bad9a9e18c58c,
+                    0xb2439aa1bd2d4502,
+                    0x951790fb14b3f337,
+                    0x6861434dd967bc19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2edfbb4a1ba6e50b,
+                    0x12c21008d5e9ae5c,
+                    0x6b84b94f0791e591,
+                    0x737194fd9c8ab622,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b80cf2724c1c298,
+                    0x27a39e2761d9048e,
+                    0x8552e451ef3d9998,
+                    0x0c2f4ce0ec677abb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d3906daf3d7ddcd,
+                    0x50e6157befb23f92,
+                    0x782c5d13420efd4a,
+                    0xab89f5e306fb4300,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x094ea029ac94275b,
+                    0xefe09b251c7937e8,
+                    0xd45c83e35afd9984,
+                    0x2a902d599fa747fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0bd4c5110f0069f1,
+                    0x24940320886f3f62,
+                    0x5e182b211c3764ab,
+                    0xb7d3ed8af70cf51d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5a7fe64d47cf28c,
+                    0x03d7fac58a67b660,
+                    0xab25d909cec1c40e,
+                    0xaed91028f3347bf8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4c169e8528feba3e,
+                    0xc010c0cfd90fc9ff,
+                    0xb71e71abebdfb679,
+                    0xa41f5c229a38f1f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35c5d671f48abe3f,
+                    0x7ffbab122611bf64,
+                    0x12902257ae7d218f,
+                    0xb3b972ff89fb9058,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82c9676b1dfff315,
+                    0x395ad7e27d98233f,
+                    0xd48866a2a03b7a1d,
+                    0x41d398bac04817a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xced8e57cf3cc0f98,
+                    0x64f7d3c585de2456,
+                    0x1524b8e7100714c3,
+                    0x916a591610af53df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1cd9c836bb35985,
+                    0xf34821b6883514bd,
+                    0x787671aa76131b4f,
+                    0xff2f58214fbae65d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25a5bcc847d9e745,
+                    0x86c642286058d7cf,
+                    0x1eb4b03b39a051a9,
+                    0x74db74af2eef69d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44cc5086bb4bf969,
+                    0x0b51e9182d5599bd,
+                    0xadce1e5d1b52440a,
+                    0x33e6fb2c67e7cb9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x661fb5e2c492eaf2,
+                    0xda6f087e700ecff8,
+                    0x2bca5546f087833d,
+                    0x3b0f3842c2308214,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7a4b9f2f125c0f8,
+                    0xdf7cb38ed730c83b,
+                    0x28825528b6e5dfff,
+                    0x3fe5f4f38a77004d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3cd6f1766b9c56d,
+                    0xfa6e3d11ec47c962,
+                    0xa2c9e106d37d0c50,
+                    0x93a6772b5e60207e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f151ea436c3c8c1,
+                    0xf600d2ffe51fea12,
+                    0x00d3bded79a831ba,
+                    0x579f7b27f0ce59b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56b223451a835a99,
+                    0x9095184480a5b40d,
+                    0xab639e4aa1119eea,
+                    0x09bb9a046bd173af,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8bbc7702c931dd49,
+                    0x0cf25946eb66ffd5,
+                    0xe40904ceac542919,
+                    0xa95c231480bb7224,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b78e458b760ad66,
+                    0x54411520419a92d1,
+                    0x8819e612d3269a6b,
+                    0x991445f20afaabb4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed221bf39d4c3195,
+                    0xf1f022399d97843e,
+                    0xf5777369768862f4,
+                    0x27fe1b079c814da4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x855fd1aac637ee3f,
+                    0x40355acb0ae91e54,
+                    0xdc16865e7d4c30df,
+                    0x236b15d3c4246485,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e44920c5bfa0ce0,
+                    0xf80194f73f83a824,
+                    0x9d839e808e4e8446,
+                    0xd364431079a97e7a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc02c7bef25973284,
+                    0xe865b01c0cef897d,
+                    0x1a94da6a7e80c9b4,
+                    0x4fefaa0ca85df88c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2724e1206c820a65,
+                    0x18b30d17382ec459,
+                    0x7620df9216f06ad9,
+                    0x3a4e54967b02747d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x279383bb85bbf4ce,
+                    0xb452843bee3514cd,
+                    0xc17c6387d47197d2,
+                    0xbb1ecbae257245f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc743293f9f757e1c,
+                    0xc4e2aa9cedc9fed1,
+                    0x7a02efcdf7a2749c,
+                    0xc75b518c3bec30f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf64cec5eaf49c6c7,
+                    0xbc5784e220664874,
+                    0x4ecae9e218c8b4e9,
+                    0xfd592589a5052bd6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68d56aec81b0ff38,
+                    0xedb56d5afacc3f71,
+                    0xe6348f48ca1e5fb4,
+                    0x3d0356b9d86a308a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34dd9d3d766a2113,
+                    0xb21c5406cd8e1352,
+                    0x12ba5b7cf64a583a,
+                    0xfc17baa8c21f21c2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbcbdb36fb1b383be,
+                    0xd1c4695476ee53dc,
+                    0x2cc2c2cb6e45ff62,
+                    0x1861482203009d33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedb11c84e0734e2b,
+                    0x8d098f831ca16e3c,
+                    0x501f73e2201dcffa,
+                    0x13712457f10ad405,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ff5f0a7e520f13d,
+                    0x93788ad1aadd2e16,
+                    0x3fa38b21fd6486d6,
+                    0xd43183731f74d184,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c2c91356e7cd9aa,
+                    0x222adcec8703b85a,
+                    0x49c4e2ee52e59c6c,
+                    0xb8cac878ce9499f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d554b1674c03c81,
+                    0x25ee8eda3d45f012,
+                    0x67cdc0f208ebc87f,
+                    0xbfe347a8212fa147,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc91f229dee05d564,
+                    0x04f04a6e34eaf31d,
+                    0x8021995c165280f7,
+                    0xa32d97f2da452002,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ac769196b4866b7,
+                    0xa7632ae07d554094,
+                    0xc5de225e8772b858,
+                    0xcea63d52e98a387b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2857920b40b8a3fb,
+                    0x21a57a5fdb02fb33,
+                    0x9c0109e1f29b69a2,
+                    0xb9c7484f898be687,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x461c0edc03b9cf73,
+                    0x6e2f03aea682f468,
+                    0x20709a749ad5e0f5,
+                    0x46bc21bfe3f8b277,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58ae21d66c4315ae,
+                    0x2ed97386f09f93ca,
+                    0x982637a492ebc3b5,
+                    0x1cfa07b44cd2c27f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b1e88aa7f0aef4d,
+                    0x5f9825d108646ae0,
+                    0xfd347e5efcf7c155,
+                    0xc6751928634a4d55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2183072cbe6632be,
+                    0x846f9ad8a17bc95b,
+                    0x23251ecea831577b,
+                    0x56518c6191771ae8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x030141e5b1d32bc1,
+                    0x77941aef6de35bd0,
+                    0x966471fa225790b1,
+                    0xd5abebfbc8f96f36,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d2e3d41941640a9,
+                    0x2c413999c5981b5e,
+                    0xc431443e02757bf1,
+                    0x6da33ac3bd0e0a32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d5eec6dd6583bb4,
+                    0x427caa56e322df11,
+                    0x66917c7063d1c0a4,
+                    0x1d480b4ffb0211b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd030cb4c09590fc6,
+                    0xe042efceb179bcc1,
+                    0xd15fa1c0f1dd5358,
+                    0x5ca5c4f1f8cc472f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba939ccac5f40238,
+                    0xa66a2ed34317cb53,
+                    0x171a76295acc9758,
+                    0x0214ddc8f1671de6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbcd3b241876fc72,
+                    0xf58645c0e468f807,
+                    0xb9a9bace3b3875a4,
+                    0x8bad868376718a6c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81e0e43b099e9a6f,
+                    0x31905ab9106dc439,
+                    0x9c056b3af4d3d16f,
+                    0x6317349a7561dc49,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaaf4b51d340480d3,
+                    0x831ee2dae891a49b,
+                    0xfc91567aa0af5212,
+                    0xf4414a14f28fd1d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x009b845cb5e82bf6,
+                    0x696446eb6cd56074,
+                    0xd34a2bd24520425f,
+                    0xf31d87bc3dd00682,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb38286abff628c6f,
+                    0xa49dcd4b08d4e94f,
+                    0x1d34dca9b47bf0ca,
+                    0x37a838a07009fc4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82c3e31e30d15af2,
+                    0x0c0524c244891294,
+                    0x75b6dd55ee14b400,
+                    0xa7bc009824fcafd1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b1d006cab74fe17,
+                    0xb2d53a2426f73836,
+                    0x33199c2c703ea6ab,
+                    0x866569e02f698bba,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xab9055e4ea05cd94,
+                    0x776df14be6b3937d,
+                    0x8ce582de1e0015aa,
+                    0x1ae91acbb534b2ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3157868d078694f,
+                    0x53a6ff7b826c888a,
+                    0x66207e36b42c1479,
+                    0xee72ec3398dbd4a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x818068de64a854e5,
+                    0x4d2a1ec12de4e8cb,
+                    0xbce9996f093063b5,
+                    0xbc85494ca0305b27,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8130efb8fb596d0d,
+                    0xb0c5d86dde2026c4,
+                    0x8ee7464ea251d32a,
+                    0xe792a4a990c172cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea361e85b40023c9,
+                    0x27e630b5bd268f99,
+                    0x6d3c48fe3a0fb668,
+                    0x4f8a960fceb912a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d52fa4846c8b700,
+                    0x0f8a7a336de0d7ec,
+                    0x0ede27c0416bd875,
+                    0x393f191b4dfb217f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66a951a32a6565d7,
+                    0xea7cec3c3f54b848,
+                    0x3288e6931bf84152,
+                    0xdb0104c2a0da44d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c45810689763034,
+                    0x84a8eab04a71ab38,
+                    0x45ef6f779c02819f,
+                    0xc3389c66666a49c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c100e19bb851640,
+                    0x8c64f1d058b611d3,
+                    0xe8cae2157d7dc54e,
+                    0x941a4637e6765f8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8ef29cb26b369b0,
+                    0x3ed8310b5c06e632,
+                    0x2923832ff55f40ef,
+                    0x867eaeaf85ab5d73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a4ecd7ac6adac0b,
+                    0xd613339e89a15290,
+                    0x7c1dbfbc317745a3,
+                    0xf4064b462a16ec21,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0903ac0f696261f6,
+                    0x2d2bca6937e9dc14,
+                    0x9b1049c0be59bf1b,
+                    0xed76c6d6f363b183,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3ab8a0e66788fe8b,
+                    0xfddd25bc5bac3df8,
+                    0xb694133c51535433,
+                    0x09afee0975dbdf6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd2b629af2b6d9f4,
+                    0x854b97f04f360e95,
+                    0xd2dd00085ea3e616,
+                    0x8ac723827958327d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d84f1014102e8d9,
+                    0xb66f1613e073eb0b,
+                    0xf540aa0c731a0c48,
+                    0xf6aa1c1d0cfa069b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe17946daf9cf7b70,
+                    0x8e8dd0ec718e4b91,
+                    0xb7fc2b1750281336,
+                    0x5855ac5c8a30419f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9e8b6b0cce380b6,
+                    0x48c337318b34c618,
+                    0x79571a5092e28fc3,
+                    0xf88451469b223d33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d10f81b838a0635,
+                    0xdf31b9f9d58e5622,
+                    0x003dffb417f6c473,
+                    0x49ed0d7c5bc09d16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5838133f6b8209c1,
+                    0x6567aae20888acac,
+                    0x36004846ec4d5f8a,
+                    0x479ecf20eadebed4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e5a9604d504411b,
+                    0x7ebcb55dcba58400,
+                    0x97cb5185e449fa76,
+                    0x7e75d2245112f3cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9610887c06aaca32,
+                    0xf33cc6fedda97499,
+                    0x1fb50a5443544dfc,
+                    0x76575769f63a926b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53e28e517ca69a0a,
+                    0x2a305b9201de5fea,
+                    0x72b19073fb473a33,
+                    0xd29b416f98665654,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71b197c2b5d03a6d,
+                    0x6f83e494f27a9406,
+                    0x45770e7fedcfbbdb,
+                    0x75fddfa363dd593b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2cc1e8e287d779bd,
+                    0x9c94c93cf13c2fc8,
+                    0x4c08c90e4d3adf09,
+                    0x4eff902bbe2e3d66,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcb3d7ed00bf0e98c,
+                    0xb0d238228918a9d4,
+                    0x38495c1fe9e65255,
+                    0x440682f54f3858e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd77f42c6401e56fd,
+                    0xcc105e274b235646,
+                    0x5a40de520692628d,
+                    0x273cb9f115c2c4f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd3eb3c72de6f90e,
+                    0x4b8abca8e7f7bb11,
+                    0xacf9927b325ddccd,
+                    0xce4ca14daf48486b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35c9474c953c2493,
+                    0xc56b3b7b6cd04552,
+                    0x4049475fb2ff4780,
+                    0x0bdc098b0f74c849,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62bd8bdc3fa722c8,
+                    0xadd9c096ce048153,
+                    0x31ced7788d3c8565,
+                    0xaa2389be23a21145,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6a1e1fccbe0afdf,
+                    0x8605bf2e41832be5,
+                    0xa1f90e48c936605c,
+                    0x9def2636d117f359,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa22ab8ef663b651,
+                    0x452c0b71f16e2c5c,
+                    0x0cf119cf9e3e4a51,
+                    0x1e70c9071f1b7857,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ef2ea6df48a42ba,
+                    0x99ce48e5081786aa,
+                    0xe35d8963cc6d6d9a,
+                    0xf2dce040c47b2912,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8741fc0a2dee307c,
+                    0xa21c122a65705ab4,
+                    0xa3603eb30cc41eee,
+                    0x90f8c82b1e29ffe2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b6a4bc6f27b0650,
+                    0x86ccd7d6c8421d34,
+                    0xbcaff8f36a9b022e,
+                    0x4dbdd28d3eb2074f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c2d4504c8fb83b2,
+                    0xffe07f92bfc6ca66,
+                    0x8c09123e48b4eed5,
+                    0xbfaa7cd47b069666,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60e5dd0f00bf8517,
+                    0x0ba6e0976bd15d1f,
+                    0x3fbec248530000d1,
+                    0x668392d0aa9c7bdb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdd53d2cd5ca9f4ba,
+                    0x2794de688aab0543,
+                    0x8693c2abdef63208,
+                    0xac658a95a547e1d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6f998a6c62a966f,
+                    0x6ae7cc3c87e3c65d,
+                    0x759a67967216f223,
+                    0x9f33a92b650a5a20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e871bd1832db692,
+                    0x6512cd0138f01cc2,
+                    0xc811ac2f98686c69,
+                    0x73afffe27fa87fcc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16410557a4525a73,
+                    0x397ba0cebcdf724b,
+                    0xac54bd6238fdfc95,
+                    0x6bedf94b43c0cc8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x604d018c638666f8,
+                    0xb27c2687052089a3,
+                    0x0a41914428760bd6,
+                    0x37ed6825e20be03a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69381e06cfc6d3b9,
+                    0x3ebee1a12e9fe11f,
+                    0x7c6137df9e63f675,
+                    0x8edcc0956a7427ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8230f1092f46037b,
+                    0x9498f07aa14869e4,
+                    0x2c346470b35b16bb,
+                    0x445ed9c9a2c2d734,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29e90cba82486665,
+                    0xc51ff7ce55013036,
+                    0x2ab0b3614df6c147,
+                    0x8fd50648938832c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb5a2135a6106dd2,
+                    0xbf2e15034d4704e0,
+                    0x2bd166f4f6e3656a,
+                    0x091b5b7e6d9b5074,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e5326382449abbb,
+                    0x3e340fb4e735463a,
+                    0xb9c9d32d61881d6b,
+                    0x1a74c6f3998e3dc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22eadfb2ac26bc02,
+                    0x6ef84ae27b990d52,
+                    0x63efa42e3eadd9a3,
+                    0xf3aa0ee08b70312c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2cb4647e75d5153f,
+                    0x9297ba2b90fa0a53,
+                    0x3e79957cf8781ca6,
+                    0x9a37e189111a9e9e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf7a2be7002abe570,
+                    0xab03f37e7d696533,
+                    0xedc56e208c487b6d,
+                    0xd23ec7d3cb7e86cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23549113008bff71,
+                    0x460d224f1946b87b,
+                    0x681053bdac182f99,
+                    0x4f87f6e01c19ac10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68c45b2d32a1e602,
+                    0x2452247939eb22d9,
+                    0x00a301c53c0718bb,
+                    0x807d2b011bda2756,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67ddec719d29b49a,
+                    0x01863faa4f51b94e,
+                    0x96bb17cbbfe9d4c4,
+                    0x4852e363df1f6bff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0743d03c587beb88,
+                    0xa87fe778946d0b8a,
+                    0x89182aaec5a4d98c,
+                    0xd119dfb0cba8be58,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80b768a16d833581,
+                    0xa585521cecded871,
+                    0xf91b60132eccfad1,
+                    0xb7ce8247d384ad19,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1aa4b1347b8f094c,
+                    0x43197408c13f0086,
+                    0x5dbff6f0a5ab0c7f,
+                    0x199efcd3d794f1ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92001018dfb67d66,
+                    0xd10aa7740057adc4,
+                    0xde90d0438628042a,
+                    0xe1dc09682a939b74,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8032bb5248f00406,
+                    0x7759a921061d28ad,
+                    0xf680d5c431cb7aa6,
+                    0x8970c8b422f1e1cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05bcd0694d2cc88d,
+                    0x60dc6acf147790f5,
+                    0x47963941cd35cc47,
+                    0xb9b43acbc669d6e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cc7715ddccede5e,
+                    0xc9f52dfbf0881485,
+                    0x64f0d8246c910cf4,
+                    0xc0c78da37d8338aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb18e748f5c5673f1,
+                    0x932855af29db76e5,
+                    0x8d261fcdea74b76d,
+                    0xe1df46fc46f1f1cf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7fd70eeb6c75ab1b,
+                    0x629f30eeb059c986,
+                    0x39800f3fa0a57e8f,
+                    0xcf5c191e31d883e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c0d978af76b7d31,
+                    0x342f33cebb0814f3,
+                    0x4ef6c368381a7d68,
+                    0x7b8a5c7272c84cba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd51374065829b2fc,
+                    0x9821b14067ac6192,
+                    0x4d282784d62a4eb8,
+                    0xd02a40ba45f34ded,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c17bb691f45a36f,
+                    0xaa7ed9673558287c,
+                    0xa76cb26ada9b6700,
+                    0x3d3541c4dc6d61db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23aef69d1762fd2a,
+                    0xa79fd884a3884601,
+                    0x28123dfcb5a36314,
+                    0x52dd97df53380de6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73c37e2eda7c18c1,
+                    0x6906d0290647e8c4,
+                    0x28516d4966d3700a,
+                    0x88fbed17242f953b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b0dff99a8350741,
+                    0xb104ba0e33299160,
+                    0xe4362dfd05d861c3,
+                    0x846776dab274a896,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1cfd63c4b0675788,
+                    0xef7fab65fb47774d,
+                    0x589ac0fcae556f72,
+                    0x81b901ee1cb9dbfa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7394dada7b3d104,
+                    0xe57ecd441dfe377d,
+                    0x41e31c1b66d7fb34,
+                    0xa8e08e04203a6151,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc884b73f2f08c2aa,
+                    0xef7cd5de7b34389f,
+                    0x6b35390aead67a10,
+                    0x5ead1aa0d124f7c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4acc867bc8038b8a,
+                    0x083827f26efaead1,
+                    0xb6941ab8dad09301,
+                    0xad91e0c347d63a25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85805c6c8f37bb74,
+                    0x0f97c23f6809a363,
+                    0xfd58cb5be0d3aaeb,
+                    0x1b98e2a034a0869c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7f7d51a7e8d89f7f,
+                    0xe06432c82d34ce4a,
+                    0x2e942c984f028f1a,
+                    0xbf2862d5bd04e5c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f8475b3660f1a56,
+                    0xb6e189f3bf9dda53,
+                    0x96e5784c77f4bb99,
+                    0x19812593f01c2c8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0899637f3b9af0e,
+                    0x2d0bd24380085a94,
+                    0x9fba7b380cefc5cc,
+                    0x027edca74ed99d9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63c57bedbe5c2777,
+                    0x0962bfaa47ab0780,
+                    0x83d58c4d691f99c0,
+                    0x1a2a8c26d133dad1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab44b39af85aeacc,
+                    0x811cd871da5a2710,
+                    0x1a30919f747b8b1d,
+                    0xf3d12bceda2d80a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7f1bbde8d2e33b7,
+                    0x9a8ea2f2f01e2b24,
+                    0x539a9d71fed6fa68,
+                    0xfc2e38598d77e1ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec5775a12c73f368,
+                    0xba619c1ffca7c047,
+                    0xbf9d2bb768271b84,
+                    0xdfa98ef3cf86c811,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8002735e386c48ba,
+                    0x1f9cc3325ee77d95,
+                    0xe517d91e431219e0,
+                    0xb6734b005d50d4c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f2ba9b8f18f2d34,
+                    0xb8726204fd3e508d,
+                    0x7c00bb102354675f,
+                    0x42118530005af174,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6285eb8f25db8e54,
+                    0xfa60816ec53f45ff,
+                    0xe394a5157ff981a3,
+                    0x40525dccdbba5d43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d3ad16eb529a17b,
+                    0xcba5e4e430f64e49,
+                    0x1c9700b02ebcb4ca,
+                    0x96e0f7857cf48206,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0c4cb0d37c2b9c7,
+                    0xba834d57c9e28a45,
+                    0x9a788d4451e0271e,
+                    0x8ee783016ab63b60,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x383f43fd27df5bcb,
+                    0x8224358343b63cb2,
+                    0xa94b3f92b81b1594,
+                    0x6feffea06d120cc3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49d4ac79d1ec4438,
+                    0x84219aa588b4155a,
+                    0x1e1d068b6d863a95,
+                    0x184461761f5640c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf708bc17e3e909a6,
+                    0xdbadd99675366cef,
+                    0x9c4c8345aa3cea2d,
+                    0xd15b6d7cfcf00045,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12364b0610fa3341,
+                    0x029aa822e20e94d0,
+                    0xea328de9fc0c0957,
+                    0xd3413878bc98f7e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca09c8934fb0ceaf,
+                    0xd364750ce3632aca,
+                    0x02f1a8a539d599ec,
+                    0x6df24da5bbdaed54,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca83621ce2e45327,
+                    0x492527e73500676f,
+                    0x7463309353b5bac3,
+                    0x5b611bf7c74be5c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69603402d373544e,
+                    0x487929ade7f06187,
+                    0xe59ee626dca97ef3,
+                    0x7d4d180ad8159a1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62b471935a11a7d6,
+                    0xfc47a4aaf22fc538,
+                    0x2fb43d073434f46d,
+                    0x43c770f12fbd5b01,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca4f5a534769615d,
+                    0x5cb694fe9c1f83b6,
+                    0x297af0d18b68c99d,
+                    0x926c354b3d07949a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87f0614bbf5dd885,
+                    0xa9bfeb52b2961dfc,
+                    0xe1608cd7c56b868e,
+                    0xd5096372887e8466,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07aa84c81d1543cd,
+                    0x705a3488b2bc5040,
+                    0xa681587b6ef9f01c,
+                    0xcc58b863c65fe859,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0eec9527b156c4e0,
+                    0xaf868dbeaee46eda,
+                    0x1b38f96efc211252,
+                    0x9b7a6c78e8a2f1c3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x48e54fd80e1dfe97,
+                    0xb3be5736f5b79249,
+                    0x5f7c9a71cd044682,
+                    0x0be5dcfb496122ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47176a4dedad277f,
+                    0x0cd6f51eb57cbb39,
+                    0x0ae714f9f055fb4f,
+                    0x6f7e6faa72bb817f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc369b77bc8a1658,
+                    0x544f79eb6a352924,
+                    0x41879bf36ada20f9,
+                    0x8fdccb5f7cad6c3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdff1a5f66a623b24,
+                    0xfa0b98c4e1b2615e,
+                    0x0a4680af928750f2,
+                    0x39e27b4a6bbfa293,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf17ecc4b37a1c71,
+                    0x30b78792d06389b3,
+                    0xc83efc0f5c406409,
+                    0xffc0806002943f03,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c24af7e362e0021,
+                    0xe1efca26c5cc2588,
+                    0x0932c0fd5eb2d380,
+                    0xc8ffc66241ce0de3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1be3401e66396a3f,
+                    0xd3a6a14a0ed03c82,
+                    0x79526231904c1073,
+                    0x22efa7a39a88d5d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2920536d17352864,
+                    0x46545bc57be16ed6,
+                    0x980d30e77f45f627,
+                    0x01258b20aece09f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ae6a1174f6dd68b,
+                    0x412d5f6c41772fa5,
+                    0x80cee06e7004fa66,
+                    0xdedb3e36c15fbf52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21a92e61c1b003a7,
+                    0x05784bd4a37199ed,
+                    0x1d1256b8679c2cd1,
+                    0xf442c3e4a3e04ade,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf90241030c722d77,
+                    0x426dc61aeb1bf627,
+                    0x7befbd4652dbb3a7,
+                    0xa4222703ab7319eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0928f290decc839,
+                    0xdaf90a98b7d3185c,
+                    0x07d52c0398eccc92,
+                    0x20992d089fa89575,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf3259bc78c3e07d1,
+                    0xc712b81867f9e024,
+                    0x234549ec7ee45db7,
+                    0xc730a4d381433cbc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe94fa022dd284aa9,
+                    0x22c1135c5c3fa36d,
+                    0x010d3862a90a802f,
+                    0x0b1437f42c18d1d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98d49e54e3615683,
+                    0xad80e85ccb882ea6,
+                    0xe73137becbb10d61,
+                    0xdc0adfe5ac03119b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23589cd8b99dba8b,
+                    0x70ab100e9cc8fe6b,
+                    0x42771002855df922,
+                    0xd42a1aaff16f6c88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d58f43ba9a9959d,
+                    0x1b07f92fa4678f3f,
+                    0xe31c7cbc7c166a8d,
+                    0x12cd4459e5506039,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa81559a894b78a0f,
+                    0xb4327cc289921ff4,
+                    0x1f3bfc9144fae689,
+                    0x52d39592dc885739,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a2e691f5cb5fb44,
+                    0xc331e917b95349ef,
+                    0x55386aac72a4fd33,
+                    0x5447748b21a5258a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d72c88c7e148dca,
+                    0xcf91ce4636ca8cc4,
+                    0xe87aa71c4ae57bbf,
+                    0x1c463bdb90e400be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6dac7247d8ec0c5,
+                    0xabf272b7f7da6704,
+                    0x6653b439a630cedf,
+                    0xf40f25f756a68818,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd6ffcd3bfec5a7d,
+                    0x5f8f2c83c5a8b2c1,
+                    0x079b2a5ba4b3eb3b,
+                    0x66980a1690522555,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2497097000dcbbdc,
+                    0x6ea80ec055bdc58f,
+                    0x9294a8ddd100b9db,
+                    0xecc7782b694babcf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3e060c602be427d,
+                    0x9a55757c213fa019,
+                    0x2c084f3b78526a14,
+                    0xb8faa1f505269fff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1f7f292d98b834f1,
+                    0xcf8d991abdab3503,
+                    0x36c83c39e1236900,
+                    0x28e23969a70c1f37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7478a7270d70cd39,
+                    0xd3c2bb7a3cfdabce,
+                    0xc16ebcc47223f78d,
+                    0x71e6852dea839b28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95980ae1dd649c02,
+                    0xf09d9783cd4f2aa5,
+                    0x2c5136fece0eec36,
+                    0x13c166fa4e192d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb709d8b3891169db,
+                    0x7950f3ecf1c66beb,
+                    0xce5ec88a07bb0eeb,
+                    0x1a6a3da286ed953b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8d9b8122a6e3d7a,
+                    0x95e00d8d3297597f,
+                    0x7756bbbaf54a7d57,
+                    0x7227c051380efccf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a1e15213ef430f4,
+                    0x63c34891cc1993eb,
+                    0x49d6479510e8a924,
+                    0xcad389d8844a6784,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30216019441e023d,
+                    0xffad2fa8d6a04e76,
+                    0x33d61b084822f7a7,
+                    0x9e9e08f207074805,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25db1a14836a4745,
+                    0xf843cab4247c6536,
+                    0xada6caf34fcea30e,
+                    0xd83bf0efb30b8750,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf16aa177ee0a404,
+                    0x11cd4b35768be25e,
+                    0xead2607d595e264e,
+                    0x288673f51da40002,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3bd25667a3de1c2b,
+                    0x444cf144c176f264,
+                    0x03a3a23228e32987,
+                    0x80613d074ab6dfdc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaeac3072b6c0d62f,
+                    0x4bf456d4276c504f,
+                    0x24300dbd77390639,
+                    0xec07faa8fb2dc394,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6feafb6781e232e6,
+                    0x2cce95853da0d8df,
+                    0x85c52511939e3f26,
+                    0x8dbfb9a2a1e1545b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x361863652b640a0b,
+                    0x3551358ca592d5ab,
+                    0xc7e5d889bad213d5,
+                    0x8475c4ba616606ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17c8372fb80200ff,
+                    0x7c5568b9b98a0aa8,
+                    0x5545780d3aa6b512,
+                    0xd7defa217ae4f96c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94c0d3d0200529cf,
+                    0xfe4f0271ec8f89e6,
+                    0x075a29bae0d8086c,
+                    0x1bf2a6bf56b38e4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa749a7839b7c2c19,
+                    0xed33b5dd8aea7e48,
+                    0x8836336864d15fdd,
+                    0xb32857fc45339247,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdae5f6d06741d2fc,
+                    0xb60df85f14967799,
+                    0xa713dabf2d493819,
+                    0xb82a105b6063328b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc03c419d64a0dc3d,
+                    0x7acdef893cc843cb,
+                    0xb8e354d78f4ef73f,
+                    0x2edbf68a07a173b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2839c87140e66974,
+                    0x62f1da6c07b29b46,
+                    0x026effd5c827c616,
+                    0xa1519923059aa1c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0cf60bbb85330a48,
+                    0xadf306864038beb7,
+                    0x4ee73e9d98a95f84,
+                    0x1f023349c40694c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8defc35d53daee0b,
+                    0x68e0914261bf5277,
+                    0xf104db5f503ea569,
+                    0xee87dc6ed1aefbe4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e41edb4204714a8,
+                    0x711e0763ad41e6a4,
+                    0x8fa956c909c1e57c,
+                    0xe1b695858ebddf8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3196c7ad60949361,
+                    0xb9032f942e12d253,
+                    0x526e6a4844201c42,
+                    0xb37a610d82ea7c11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5837fff3cd88f0df,
+                    0xc02492bd9617fa33,
+                    0x3048713b1f188baa,
+                    0x84e540ea1763d1f5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc9ac52eb5a0c6356,
+                    0x6358c030e7f0b9fd,
+                    0x2c91d08bd1b8992e,
+                    0xf4fb7c1e723023ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f6b16421a825a39,
+                    0xd0ca24b65933721b,
+                    0xe460fdb66673b9d8,
+                    0xa19dc0ad4cbc2818,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x826766dfce205379,
+                    0xc9ba62c4eed6c6b3,
+                    0x32d1e2f029a71bb5,
+                    0x3da69d453637091c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96e3e5bb04ce009f,
+                    0x61a2e11e9a7deaa6,
+                    0x0400e572bb909d55,
+                    0x8da6d428d18327dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49f5392900097823,
+                    0x0c7f845a124ef723,
+                    0x8ac347f4f79998c1,
+                    0x3cf6bb26d09bfab2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x900774607f571a83,
+                    0xb3eaa66d667d00c5,
+                    0xf618470fb1fa16fc,
+                    0x4b914223894c0d71,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbb36feecf2eed1d,
+                    0x38700dbb15b08b86,
+                    0x003c4ed1ab91421c,
+                    0x1d7eb06ad764bf68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c8a68fdb3feac2c,
+                    0xe6852f96beee3a60,
+                    0x3bdd88921234c8c7,
+                    0x27a862583b52a731,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0751fca0540d7d3,
+                    0xca515d0a8858a8e2,
+                    0x2f859c686848078a,
+                    0x1f39b047aba28237,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fa84ddc9d024515,
+                    0x1c1a41fad493a461,
+                    0x4bc5fb6575d9a4ba,
+                    0x6ad82020f3a74f81,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x076b711d7e13816a,
+                    0xcf24db89aeae971f,
+                    0x16d16e23a8daf2c2,
+                    0x6512a9d78bfa2602,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c521aec577433d6,
+                    0xb6030f83eed86a7b,
+                    0xd4d01d66ea0ce4cd,
+                    0xa96cc85b8dddd4a2,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2796 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U8>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x3d3c98e9dd4540eb,
+                0x64d55dbc3df26229,
+                0x6e06eaa955de418c,
+                0x4559f438e92a421d,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0xfd85e9a233b6ee5d,
+                    0xec2fc6325ab364aa,
+                    0x2e27a6bdb5b4256f,
+                    0xbce15e4cbbabf11c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb4243edeb543350,
+                    0xdb3ea01d49b52eef,
+                    0x06655008b8e1abaa,
+                    0x0bbf44bb5f1629f1,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xeb29ac861842a3f2,
+                    0x5f93b14e77a6402a,
+                    0x88305cb5af2d8307,
+                    0x2dc1a56e9a061d9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7aeb3d330acb9be,
+                    0x01c37119edca86ed,
+                    0x9d0a869500f1860c,
+                    0x10e8baa3a8f2c5ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x967f522257bf7492,
+                    0x2bd8bfc631fb546e,
+                    0x33d3d801392add29,
+                    0x7bc21c9a2c9cc5e8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8109ed95734bb9ea,
+                    0x676a213d458b4629,
+                    0x5c91ed9ece4d398d,
+                    0x2fea0f4c4f777305,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c064ca444003235,
+                    0xf052bb5e0e29a541,
+                    0x3d7db53ef576cdf6,
+                    0xcb77ebae7a3e63e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0652c41407e3c4da,
+                    0x03e4a1f2ab94de15,
+                    0x07263ecb42f5cbd4,
+                    0x920b9a447a66cbf4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x182fa3407af0ef1c,
+                    0xa48c96cad8df3da9,
+                    0x2c869bb1e03ea88f,
+                    0x57e92309904984e4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdbf64b0705373415,
+                    0x0748cf670d8ab819,
+                    0xb1e40c6124b347a6,
+                    0x22d54f50b5ecde31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a324997ebd5da4c,
+                    0x642d5457758b9979,
+                    0xd58afc8a8025ef2b,
+                    0xc2e2e40caf902932,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01263b761d42c6f7,
+                    0x64407f7fddd3b469,
+                    0xf173f2579c7308a2,
+                    0x20ee49b7cec2ae37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e869ba2a7644eed,
+                    0xa55863316eb19189,
+                    0xfffed74b062cee23,
+                    0x224b1b75868c512a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8da76380b97208f9,
+                    0x1a6874f4410f9b58,
+                    0xededa2f04aff0aa6,
+                    0x199a21cde5f862e6,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x49b8173f5c8f3b69,
+                    0x00c343e179d73654,
+                    0xdb04190aa62b8af4,
+                    0x503d14efb6a8ec7d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8a998a8d61b4856,
+                    0xaf235c0b0a11f10b,
+                    0xf8d7e46918521544,
+                    0xeff6c17a6638fe25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60658b04875518a3,
+                    0x2d11cb3309baf31d,
+                    0x0bce8f6db42e68cf,
+                    0x74f3d701e6b61a2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x322b76f5f6c9768a,
+                    0x7d729bd447cc7022,
+                    0xa4420ab6ded67c28,
+                    0x575a757d95b07626,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x373315c1c522f455,
+                    0xfb8ec01d68ea59a3,
+                    0x3871523953826494,
+                    0x3c53fbd36ef6fdf6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd69d8170f4a38674,
+                    0x3258bc7ec3c264ae,
+                    0x82b850c2c7065dbe,
+                    0xc18c95b9fffd0d92,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x3a40242be182d34a,
+                    0xbcd596b6c8f3f1eb,
+                    0x631e7569fb832622,
+                    0xe5b67cb9a6014b62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2229bc47f3521590,
+                    0x19a22e1abd7d574a,
+                    0xeaf6d911043f9df7,
+                    0x625f48828e021a77,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xafb871aa39d0caef,
+                    0x6ef21d2e502f45f9,
+                    0xbe3244276f7c9198,
+                    0x399a955ffd886192,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2eb163e9ee6782e,
+                    0x871ee0ad757407ec,
+                    0x8910839d9762bd7f,
+                    0x4c682cd02919fe62,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x82c11fcb2e7fe88f,
+                    0xc9c0d9a51b2c5779,
+                    0x6d1806317655ce44,
+                    0x786ff75ec9316b9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0b16b5160509cbd,
+                    0xd1df1ec80947417c,
+                    0x73c4c7c283be5b4d,
+                    0xfd86ce550bfb8819,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x917a240e0acb730b,
+                    0xcf8705d56b0d89ea,
+                    0xf6b7cc75cb6f25d4,
+                    0x0848a92075663868,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4709f4ee00c15fc3,
+                    0x78fc2a94668a5d0f,
+                    0xc277d1d788ca54a7,
+                    0xc537b24f7b7e0fd6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdbf588e5b1bf19f7,
+                    0xc633a4e76d460def,
+                    0x73b47728bacf1145,
+                    0xf61e9b36cfe4c2c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2818f09ffe243dc3,
+                    0x4531c9bad7a9a923,
+                    0xceaf62221f98b051,
+                    0x98044763a6800933,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x99d0009f508de01f,
+                    0x3533cf1730bd005f,
+                    0xfd5c413c4ddb78a6,
+                    0x320e8d157e143a3e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d4ce56bdb144f5c,
+                    0x33bdf8e0d02b1cd9,
+                    0x9409de01a7908183,
+                    0x661ab913688df8cf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6a2f640b836f338c,
+                    0x9fe4eb58502ede30,
+                    0xc13a1caa7a011fbb,
+                    0xa25cb90ba3e9246e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c3f7e692cf693e5,
+                    0x77f9b066afa8c4a0,
+                    0x523cb51effe5e8da,
+                    0x1ff57f4c472116f4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8cfa50903a69cb03,
+                    0x70b2cbda7b6ef5c5,
+                    0x40980a5ae22221fe,
+                    0x2efe1f363ef01c27,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd975e0819b72f3e,
+                    0x7d1326c09bbe9618,
+                    0xb6b1fbec573c86ec,
+                    0xd21ac09578ec7c2c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x764947d3b1b7adef,
+                    0x2252d5b3021d11bf,
+                    0xc729e37019d0d797,
+                    0xdd39ad99909f9faf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdea86415be1ef58c,
+                    0x8a772566b399bff6,
+                    0xa53146e247ce7125,
+                    0xf4a7b0d5e7d605c9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x21dbb6d20a08443e,
+                    0x7cb15cfb826472d7,
+                    0xa3744baa841ef3ab,
+                    0x5dc0086aba22b610,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x591e3f21bfc2d461,
+                    0x8f15a77570b29411,
+                    0x80f0ff901f7f6a75,
+                    0x79640816d6cc2053,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x3a40242be182d34a,
+                    0xbcd596b6c8f3f1eb,
+                    0x631e7569fb832622,
+                    0xe5b67cb9a6014b62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2229bc47f3521590,
+                    0x19a22e1abd7d574a,
+                    0xeaf6d911043f9df7,
+                    0x625f48828e021a77,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xafb871aa39d0caef,
+                    0x6ef21d2e502f45f9,
+                    0xbe3244276f7c9198,
+                    0x399a955ffd886192,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2eb163e9ee6782e,
+                    0x871ee0ad757407ec,
+                    0x8910839d9762bd7f,
+                    0x4c682cd02919fe62,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x82c11fcb2e7fe88f,
+                    0xc9c0d9a51b2c5779,
+                    0x6d1806317655ce44,
+                    0x786ff75ec9316b9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0b16b5160509cbd,
+                    0xd1df1ec80947417c,
+                    0x73c4c7c283be5b4d,
+                    0xfd86ce550bfb8819,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x917a240e0acb730b,
+                    0xcf8705d56b0d89ea,
+                    0xf6b7cc75cb6f25d4,
+                    0x0848a92075663868,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4709f4ee00c15fc3,
+                    0x78fc2a94668a5d0f,
+                    0xc277d1d788ca54a7,
+                    0xc537b24f7b7e0fd6,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 310];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x421b34368f71c365,
+                    0xd127677965fb3cd4,
+                    0xd2c0188ce96343ff,
+                    0xb97e96a11c0bc085,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf947044d1d9f9530,
+                    0xaa2d6038bc0322ce,
+                    0xd693ed22f30026c5,
+                    0xea001416409aee1d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x692abffc4b21786d,
+                    0x9f54f7a28d7145a6,
+                    0x0273dfbba6db2f1e,
+                    0x757b0fec2a495189,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04fd87018aed498d,
+                    0x9226ec527ea70745,
+                    0x8ed4ce85b3ea9e14,
+                    0x582224649b403935,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99c3518185fce184,
+                    0x0b1275eadda84fe7,
+                    0xe9aa04d31753300b,
+                    0xc3ef5d325a7aa047,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x672921b956c10c27,
+                    0x42321893a33433e9,
+                    0xef65e8f6e444b95b,
+                    0x8894d8f0aa7354e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88649d60dabac6e9,
+                    0x1627a2a35fdedbc3,
+                    0xd610a58b265df2df,
+                    0x2bcd0750b8dca59f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59589a4279c2b5c3,
+                    0xaafffe41cd01d1bb,
+                    0x53e39c1b82386f93,
+                    0x6d6e8b0bdb23dce6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4faf7012a3e1791f,
+                    0x9b909da133a6061b,
+                    0xa0d1891288fac7aa,
+                    0x880d8e39ab35a4db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7887bf7f817c28a3,
+                    0x07a3646102a81c09,
+                    0x38ef7b14338e6560,
+                    0x03d67933d6ad45cd,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4cb4dd629e6365f6,
+                    0xa2f2c63da1bf4c0e,
+                    0x78a29ba3f4e81a77,
+                    0x5e942f191062a943,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb30baea475ae3c8,
+                    0xf478e47a7c6fe45b,
+                    0x837a5266cd02485e,
+                    0xb958ebd24aed0a44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08693217a6f7213c,
+                    0x24e6177887223e12,
+                    0x39c7a705fefff8db,
+                    0xb579fe965e618e02,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae36eb1920e06143,
+                    0xe14fa638d5a97929,
+                    0xf425f864b058a372,
+                    0x6dba80ef5e2526b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6bd7e69cd3adbc9,
+                    0x820c53af48aec13a,
+                    0x28d0de62e02d3374,
+                    0xabebcd28450b57ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a196bea2419b84b,
+                    0x6a463a181ad220d7,
+                    0x05833e5e8bdec6d1,
+                    0x1fe8d0233241790c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9141da06a3bc3132,
+                    0xdc42e9713f64df97,
+                    0x3deb77a655db42fb,
+                    0xe4cae11857fa3f62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc65b3ecba0e10a09,
+                    0x8356ce12d4a6f5b6,
+                    0xc1639aa708a2f9a7,
+                    0x2704d10d873b7f25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd047f44cd4a63505,
+                    0x4abdfaf93626f5a1,
+                    0x6b9a8f99af744cc4,
+                    0x719f1762c81fbf85,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00fb0d729612b9a2,
+                    0xca4bbcef1fc94407,
+                    0xd8123df9ad247da8,
+                    0xc1290cf66f12aefc,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5947e6995f9d112f,
+                    0xf004d712be41a97f,
+                    0x6691386bd18b0142,
+                    0x91fce776b0db64cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c4b95db738f9e26,
+                    0xa128fe8068e7e335,
+                    0xf62b37bbb4b2c65c,
+                    0x2841dcfab8345ee7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58e17173682cfaf7,
+                    0x698cb46aefe1f35d,
+                    0x18da0b2cbefc3f91,
+                    0x3493d2603b35f195,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f31d0edaee868f4,
+                    0x7513a7b5e59d69a0,
+                    0xfc01d9b3f058b73d,
+                    0x02bba4a6474d1c38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x009bcd260aece771,
+                    0xc503cc79e2368bca,
+                    0xe33051aa1bd0d6fb,
+                    0xbae5cb2ac4039859,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x321f69d6814a2516,
+                    0x6e882576da170677,
+                    0x09d269ed8401be8d,
+                    0x6e5ce77dd9585928,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2654f75c69071004,
+                    0xd920aea9500c4a56,
+                    0xa5e944ad5fc0e379,
+                    0x7a05d59a5bc7fc9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa21c9d9a74c6a497,
+                    0x72b7021a29b1a5b0,
+                    0x5504a8345eb5dbd6,
+                    0x4b47ba2e6fd5a6a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40ca98eb2502f80e,
+                    0xbb75fe5585add592,
+                    0xbc77e6bed2fccf19,
+                    0x46cfd6b6824a64bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x025ea7e3e4f52062,
+                    0xaf23b1ee0e4682fe,
+                    0x7dd8bdce4ee43101,
+                    0x66e6a213733a9d81,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x71501561c42f0f4d,
+                    0x9700fc6682e53a3e,
+                    0xce454d7a10af4df8,
+                    0xfc95c1bbc4aad274,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0f5c7c7b5f9ca67,
+                    0xf17483fedc89e8bf,
+                    0xb1ee2dbc0ef8b152,
+                    0x68276a6e1bc4f671,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa790f78fb644c1a5,
+                    0xd7fad4914e0d3ab2,
+                    0xc56c15038494f99b,
+                    0x5e66e54f1a971c9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1d4eb023f9822e6,
+                    0x99c162d3f854309c,
+                    0xf3431688e2040ff9,
+                    0x0b55ed18721115d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ece1e3be52f7ad5,
+                    0x0840c432f87be685,
+                    0x3d11d2cadbaab025,
+                    0x3757ec73d3f1cfe5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38902226586d664d,
+                    0x011e6acafab7b036,
+                    0xa088ddd20544b9f5,
+                    0xeb7ea49557240f1c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4042a13aa2584ed4,
+                    0x339b17b2d2d59315,
+                    0x25cf3c4885489260,
+                    0xe7579469ab4b72a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84a23e4f8daec11a,
+                    0x6200d90112f7f306,
+                    0x2e8b3945f6a24f42,
+                    0xec02d2d0aa48e364,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd845bcf1b3c616d0,
+                    0x6fea8a11e4868df5,
+                    0x25ffa3de7f4d59b2,
+                    0xf17d5097cfdb1b92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30deec68c4ed433b,
+                    0xa742463cd2c1d31e,
+                    0x17e1a73d1e7f7556,
+                    0xd6da03d9cc75cfd2,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x618e182bd12d0856,
+                    0x67218ffb6b782a94,
+                    0x2e378aa42b116413,
+                    0x0f3141b9613e3886,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd5b666b921b6526,
+                    0xc5c5004851a83885,
+                    0xc3a20a964a55f5d7,
+                    0x6a7744972cfd0c86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39a854db9fd2d360,
+                    0x2de5db98a9a64b53,
+                    0x10956371e1caa5a4,
+                    0x04f6d3962782cbb7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae95f6f95f7ddfe2,
+                    0x2742bcea06ab7d29,
+                    0xc911e6a9f8e26ee0,
+                    0x11c351bd19ab0779,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x371c481afd3ce16c,
+                    0xd4c20c668ab6bcd1,
+                    0x87b98a42915a2f5e,
+                    0x5ea6cdf5b9906b6e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x882d2df021ddaae2,
+                    0x575330ecc1e0937d,
+                    0xb933289b937c93f2,
+                    0x65caaccf86cedda3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbc624e280840ee9,
+                    0xe3ca7bc2f66e48df,
+                    0x6e08a48090db8dd6,
+                    0xe220b862f1eca844,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab9edb720449fb6d,
+                    0x0ecfbd8b0b859578,
+                    0xb73703bb8e0564c5,
+                    0xe67cdfb4053dcadb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe359e512320f3650,
+                    0xe53e7545c0ffc4c2,
+                    0x4494c8083399ea81,
+                    0x72d9bcab8e02111e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55438666f2740a9e,
+                    0x85da5dafd4a3ecb7,
+                    0xa40be58a28cfb908,
+                    0xed8b9e44374ac8dc,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb755dca084c5a774,
+                    0x5008386570786ab6,
+                    0x7723bd09d14609af,
+                    0x6492f5d6e12df2d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ef318bd8c337843,
+                    0x5d8c64b18f90202f,
+                    0xea52eb3378e5dbfe,
+                    0xe038d1a1a63c15a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21941d05d026bf95,
+                    0x94bc21ec212b7cf0,
+                    0x9d993283c4b72e40,
+                    0x45be774cb11a7ffc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fc468bb327d4fc4,
+                    0x8b302d5e19eb307c,
+                    0x554a1716a2495a94,
+                    0x1c4b31f98d161d52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x348ce4cd00a886a4,
+                    0x5fbb0fbda3e282cb,
+                    0x35a3727c1b07c0d2,
+                    0x7976bac19f4724ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38d8043b1ee8caae,
+                    0x591b203c643bb96a,
+                    0xfc5bf1b3ac8cb378,
+                    0x087c2e196ffb4e70,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17877d51beae4497,
+                    0x1345ffb4c20a52c1,
+                    0xe4ecc8d3eccbf83b,
+                    0x8605c6b25d668604,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27a08b45a8854a6d,
+                    0x63c62f47ac076479,
+                    0x624d1576ad856a35,
+                    0x2cc17432de8cbbdf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe57e1a5d976d7342,
+                    0x376c70eec03cabbb,
+                    0x3148070836db8684,
+                    0x9d303326f8b72541,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x907ca068bb925107,
+                    0x94c1524633db1ab2,
+                    0x2065aa9f27762e88,
+                    0x67b1c3adf32e79db,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x4452118d1583b825,
+                    0x54911766948a217e,
+                    0x4ef7cf8664faf60f,
+                    0xe93acf713b72af4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e4542db001480f3,
+                    0xaef8e4df0e9dc13b,
+                    0x73d8b1a43ff4c82e,
+                    0xdbad6e0c63fcc5dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46242bf5ea059d05,
+                    0x1405a330b79f86c1,
+                    0x242fa49bf183a150,
+                    0xfde6e8a008e67b8a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c22ba4806d258f9,
+                    0x9414e83286e21bab,
+                    0x218f0ef8971f78ed,
+                    0xc5fd6dff33ea4e39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a1165616ee08ccf,
+                    0x05926708e5c2db84,
+                    0x842d061cb25f2b23,
+                    0x042175a63e069131,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x905eb73a735fbd96,
+                    0xd61411507072334b,
+                    0xd9226be11f91b836,
+                    0x217264183eaddeb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff5db3f97fbc64dc,
+                    0xb4ff153598ecf804,
+                    0x155e8ed83e5decb2,
+                    0x16de201dc7f35cee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf38237e30e2c67c8,
+                    0xaa75120f271dd930,
+                    0x119258065f2d5008,
+                    0x9b5c6755efb021e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x872f55152e68ae43,
+                    0xa2e552a9f8299b1f,
+                    0x0eef31462f19ffba,
+                    0x9bbfef991772aed2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f0aca204cb60e26,
+                    0x3aedc3b34656532f,
+                    0x710c19d9347057db,
+                    0x2291c888069c6faa,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe9dce5cfa5277da0,
+                    0xcf9e3ad6141bb185,
+                    0xd666c805fc1ea936,
+                    0x8b30dc392e0575b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0119bc391e6f7b9c,
+                    0x14d4c34e539e9d8f,
+                    0x3850c7f1f9713245,
+                    0xa54bae8f52cc34dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0c707255b62fc8d,
+                    0x8513e3c16a720932,
+                    0x63cc0825207854a2,
+                    0x55973f498d396dd2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb38d71ce28415248,
+                    0x52a8a44413eaf322,
+                    0x68933c4700445eff,
+                    0x30eeb8028882aba6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea04f316baf11403,
+                    0x37d7868093258e61,
+                    0x67c1a3022948a6a0,
+                    0xef6fc84f1a076e21,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0c3aa8e4a0ed2985,
+                    0xc18a95361bfda777,
+                    0xecf8e4591e2cdd63,
+                    0xdc20f457c2b2cb2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e2440f71e75bf52,
+                    0x1bcf814ee3fe9f12,
+                    0xf985aa1277a1bcab,
+                    0x307a24bb79a354a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfcd016980f22365b,
+                    0x6947d1bc8c4f8a03,
+                    0xe5a133a3e18cb642,
+                    0xa162e3a569ef7179,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb7e77bd696f9b1c,
+                    0x25ccf091421b1373,
+                    0xe129004371564b87,
+                    0x785cfff0d4a108a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x120f71feb68882a5,
+                    0xba079cd26d3dd108,
+                    0x8de0df026129ed5c,
+                    0xcfca2d48d63795d1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6f2bbb48f0f10233,
+                    0x5c620305cfcf897e,
+                    0x02fe5573824708c6,
+                    0xc20c10a1ae507f3e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9735a3660daa7507,
+                    0x022a2d0ebed502fa,
+                    0x61bfa3228aebc802,
+                    0xdf3b726537bbcf4b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb2f2d69fc752cca,
+                    0xef400a03805c9699,
+                    0x3bca52875473891d,
+                    0x9c786e5addc46453,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x274482fb2774ae0f,
+                    0x47030f3dd07566c4,
+                    0x2e094bd7e870496d,
+                    0x8e0b3e717124a540,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3394fe927c544527,
+                    0xe48d3d0b8e9fb69c,
+                    0xc7da0626b845d4de,
+                    0x1cf6bb82bbd3056e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x27535f6f288775ef,
+                    0x02a4975fbb0897a1,
+                    0xb154a51c4dc2e63a,
+                    0x58810fea966a2af1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcac12fd2f1450c82,
+                    0xf6874a3345a1a4ae,
+                    0xa009094dacd23213,
+                    0x3495ee76d2ac29ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9897afed82eaf93,
+                    0x5e1c2a165973479f,
+                    0x775cd8ca5f2d40be,
+                    0x1d53efc24a41a554,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab25bdcd0e0e996c,
+                    0xe7183c391c726b7b,
+                    0x9475d154c535b4da,
+                    0x22e41e7ce1318af6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x401fd41c04bf27ae,
+                    0x3f260b84fe6766f4,
+                    0x183e81279d401cfc,
+                    0x8455094f27ace7bc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc5175a933b54985e,
+                    0x7b30fce8904675c8,
+                    0xca52794b864dbabc,
+                    0xe98910ce96b98a44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2aee86092d50a4ad,
+                    0x4faaa0849ee59e8d,
+                    0xce93568997d0f1b8,
+                    0x56f57aeb03178ec6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde7d592453228a77,
+                    0x6def027a798a1419,
+                    0x6f5322ef4f3ca53d,
+                    0x9120ee27835f24e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7b194f43437b540,
+                    0x78d57b0f40540356,
+                    0x2f75d18710b93531,
+                    0xf7202819a92326ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c60c071e3dff8f3,
+                    0x455073614d1aa48d,
+                    0xc9632c985726500f,
+                    0xdacdd4822f451522,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x958eec2ceadf7575,
+                    0x5a2313e3c415508e,
+                    0xc0603260e57f5b76,
+                    0x9c4e627709e6503c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba28b948af12be57,
+                    0xfa74854f999475fb,
+                    0xb8783614c4eb2643,
+                    0xec4b254e09d71e6c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a319865857b1dc2,
+                    0x6bc9f4d8763a36a6,
+                    0x9bd85d0d3870fdb4,
+                    0x5f7f84b368fc2eff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b3f8b656611bda6,
+                    0x603bdb0a9f2a6a1a,
+                    0xe772f0a47b402523,
+                    0x464626ee9980d7e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b48ceb20f101bec,
+                    0x82bbf74da22917e0,
+                    0xe97075fa624f7122,
+                    0xe8d4528dd251363f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb14231cff13ee958,
+                    0x033118cd53f0fb13,
+                    0x1ae3c00b00fddd32,
+                    0x6debbde763dcd636,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabb56b98211c69e0,
+                    0x35a5016306dc7ca0,
+                    0x8e55354f584dd1b7,
+                    0xc9c6fc3a9de1ac4c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a4fbe6b47f97e51,
+                    0x1c47f66b0cff1490,
+                    0x71ed86c5654522e3,
+                    0x243608da571bcaca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12200d3929342665,
+                    0xc33ebbba639ae172,
+                    0x2aafa907ddcbc4b6,
+                    0x35e47f78deee7d29,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77dc8574465b71dc,
+                    0xeb26fe04421cce2f,
+                    0xc97203fdc4e46fb4,
+                    0xeda0918cee658677,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7c27440623c691da,
+                    0xcffb87dbb08f7b41,
+                    0x4ceeed0f1d984ede,
+                    0x5f9b2d34d28537c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e578c3c03bd523d,
+                    0xf73240aa20663677,
+                    0x15caa5c1b16a8b09,
+                    0x283e61c4d78a40ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab8f161f1e491e93,
+                    0xa77336a97c678c11,
+                    0x49bcea363ad397c8,
+                    0xcdbdf3c9a67d3ff1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4b6315d5c00470e,
+                    0x35b906c2e22285e1,
+                    0x8ca72039746eec3b,
+                    0x331026efe8598fca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f57b96d56465b52,
+                    0x459c8d39f6e26722,
+                    0xe46258c784cc4cdc,
+                    0xb282c0e4249e4f08,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x73c288cfae3e3bcc,
+                    0x9fff0b5dc2d92817,
+                    0xa5dc21b5207b9500,
+                    0xf7144c9563bca056,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa256386f9bfb602,
+                    0x9d3b209eda6d0783,
+                    0x5d0412afacc2a43a,
+                    0x1d88dfe3f9a27eef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e2877cd1f14a4ce,
+                    0xe0fc50d0ef3ddef4,
+                    0x92335dbceb0ce175,
+                    0x8e201d3cc0f3a433,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e38214a5025332c,
+                    0x0b61e124405b2e55,
+                    0xf1212d99de3aa1d7,
+                    0xd624050ccde49a96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9587a5786de41f4,
+                    0x6aaaab6abb7a8f73,
+                    0x4fdc624444e8a614,
+                    0x6339139fdfa9a45d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ff756f1b267a244,
+                    0x913b61cccd581a31,
+                    0x084ad3adc855491a,
+                    0xd0ba9d9ba03cdcad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7339676164329cf7,
+                    0x77291791b63fbd05,
+                    0xc394c2d9d4b227a5,
+                    0xb6dc9c1381f09039,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f768ed77304f09f,
+                    0x6bb5402b33472f5e,
+                    0xb490f4b89e98bcfb,
+                    0x9ad5dc7b3820df28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb59aba7e63afaae9,
+                    0x107e017fa42bcac9,
+                    0xd0870572800e5701,
+                    0xf19901879706c3e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf66d621ca28573e4,
+                    0xb849c6aaa15395d1,
+                    0x454f3dca25d8f7ae,
+                    0xf8c64d2882934ffb,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe0954e0e4116b753,
+                    0x9d2bae51a933f9ad,
+                    0x11f6e5631b885a90,
+                    0x0f380c0a10ba9794,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3dbbf37ec8ebcc1,
+                    0x42ea7e403020b103,
+                    0x97e06159841205bb,
+                    0xb2bad2c84c7a724c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x327eb373a847aab7,
+                    0xadc6a7edd626d70b,
+                    0xe09c7c130d248f10,
+                    0x7ec0f9b66f416dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a9c64b805f15afd,
+                    0xac0be2f3b662e2ff,
+                    0xd87037c0e5f097c2,
+                    0xd4df6f2f3c9f4022,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fdc381f9a88125a,
+                    0x7c5bbb5ccffef273,
+                    0xc96e62f821f7b988,
+                    0xb18768b54246305d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80db95ef6087d414,
+                    0x63bcacf5eb43e439,
+                    0xf7aaad99969e7cae,
+                    0xbed17fbeb45359de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2abda38a8d50168b,
+                    0x98af558cb2b944ee,
+                    0xc8e725519d452e1f,
+                    0x4dcad172487210e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18a3f615fff2a511,
+                    0x03f8a7ce7d39ed22,
+                    0x0272b7b6e6d50737,
+                    0x8907b68856fd7e3d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbde50a233520877f,
+                    0x06c3c226a74f80ac,
+                    0x4dd63da759606111,
+                    0x92cff67f6e6fd5af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ab5d1ec147f7033,
+                    0x639d7543b6313d70,
+                    0x29e7006f456d59bd,
+                    0x937cb3889d324189,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb4f5048ed855d0c4,
+                    0x73428dd659994ff2,
+                    0x760e7c6d3b5dc3a4,
+                    0xc83f3b7cbb7a0a38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe35d2f0eea798d04,
+                    0xed631fa1a9dad1fa,
+                    0x0a15b16954f40be3,
+                    0xd377ea9f26691d31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x943fa9c60bb62fc3,
+                    0xddb6378bf1d709b3,
+                    0x66a0f1a1f02e295f,
+                    0xd36a7b7dc3bf04aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x216ff9c3839d7631,
+                    0xca654f8fcd1ece19,
+                    0x57e2c75ef9816174,
+                    0x869c52d62ee80b73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0133c807660e8f68,
+                    0x94847660f733a8fa,
+                    0xefbfce88a2a79dc1,
+                    0x3711a9e737f2ee43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74bb567e3e58c3df,
+                    0x6f23d86ce19884d0,
+                    0xadf2cc8cefd08ba6,
+                    0xefb0d8001bbf112c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c800f51a50ef526,
+                    0x420d0b96346a982b,
+                    0xfb13a47d43b5de73,
+                    0xe62ebfdc05ced471,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x083ac0fda92d3c49,
+                    0x92a21de02ce85ad6,
+                    0x177f3d9fe4551589,
+                    0xf2c41b567a860351,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ef79b9144fb0980,
+                    0x17bbdc2004757f3c,
+                    0x45d7bd349ea7f533,
+                    0x8ba86cdbed410069,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5bc8c90342aeeaae,
+                    0xa71a757f45e88926,
+                    0xdef830cf9f3c0aef,
+                    0x6a1d06379f59f803,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x25eadb86cd4a44fc,
+                    0x81acd64032232708,
+                    0xc39901f04e21039c,
+                    0xd2d773ce1b39fe33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0af52a3770d80901,
+                    0x20f170afc397fdfd,
+                    0xee5dbd0c37576f99,
+                    0xa213ee8256cedbe6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6e2547578caf32d,
+                    0x913cbd6aa6ad1c61,
+                    0xf834498cd17a85dd,
+                    0x2feda8da880130fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb08603df6d95c4fb,
+                    0x5a411bc49b59afed,
+                    0x8e3fe2ca47bc1a0a,
+                    0xc94856b05cf2dcd4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f9eaa8ede5871a5,
+                    0xbb515f5384fddd52,
+                    0x19b6acd62a2edd25,
+                    0xa3d8b3bd0f59a053,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeae16824b2005d9b,
+                    0x96d3a50af3f415ce,
+                    0x9e85ad43b652116b,
+                    0x5d8399a1f4a643c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd9c62e992754ba7,
+                    0x6ff06bbd7a36498b,
+                    0x6025fd0ac8ea9c39,
+                    0x299da45a805223aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x036078e38206c37e,
+                    0x47c86fb70f5ae47a,
+                    0xfc7888b44bc47336,
+                    0x2397ea9f8db45729,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fc576e0385d2e59,
+                    0xf81b96740f488b5f,
+                    0xb8b7f6bbe7f115f7,
+                    0x16e1e27d7e3a1718,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x444c868f92594124,
+                    0x51b99f808ab46eb4,
+                    0x93f2012f00158608,
+                    0xba205b51858f89ca,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9a906b299beeaa4a,
+                    0x431be962f411d310,
+                    0xf36d6b089509e148,
+                    0x0a1dd817c4e0e948,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34724cc91dfc1ff0,
+                    0x0c89573ee61edeb6,
+                    0xa62381be4e343ac6,
+                    0xdcf6b0cd07318971,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d17274debc5f4a3,
+                    0xe9e9037d59a18d35,
+                    0x0b0321317d44700e,
+                    0x04589c21ee81c746,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48321e25e058ed09,
+                    0x74e15914abe7c64a,
+                    0xb90bcb4300d83935,
+                    0xae083701dd4843e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e3ce01026846cca,
+                    0x760baa3b1139b244,
+                    0x147ba8bffa818631,
+                    0x93db42b2a3e31618,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38304a385972a50e,
+                    0xa4fd0872b64f8246,
+                    0xff0237b63710b97a,
+                    0x3f0a02f4e127dd33,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda2035b95d3e1707,
+                    0x57266e26296ab34f,
+                    0x9cfa9a9b15c8b8f0,
+                    0xec93fc63188c210f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe02cdfef4bdbd583,
+                    0x9e0f6ad96b9c1f3d,
+                    0x58a85c80c12f8b33,
+                    0x041e6a106387f46c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d88bd379c843812,
+                    0x5501373fa54bc5d3,
+                    0x1974bb7747db2c88,
+                    0x8ffb760ec52e9589,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0aa171f8c3baeacf,
+                    0x6803b988466c19b4,
+                    0x8408e26b0b4b5be4,
+                    0x5af6fab39fc6d7a7,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xc180f0d3fb27f822,
+                    0xcd5691c8122a873f,
+                    0x069cfb76155c5bb8,
+                    0x0508b650a30ea3d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7534513d8ef7082e,
+                    0x95b83ab11bab454f,
+                    0xfb3dc3930376627a,
+                    0x92b3f13b52b83868,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6c3eab43ec40674,
+                    0xe0a33aaaa7788d6f,
+                    0x49bbc80d4bb854b4,
+                    0x605e06b3df64c2a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f6d4d1e99e35454,
+                    0xcbdfbc7b3ef774fb,
+                    0x671048664ac9154b,
+                    0x85f2ac506075b716,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa44c248ea13ba6dc,
+                    0x7f7a251fa3099383,
+                    0xa3528de975ca9f32,
+                    0xc159fdaf8bf0cbea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6990186d6eab466d,
+                    0x231fe2ac61fb7379,
+                    0x405b0ee62103fcea,
+                    0x7e9e53c64205f7f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1275dbd7b9912cf,
+                    0x19a46d1938daab70,
+                    0x076e997575177bc6,
+                    0x21f4f4d3d9359e04,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb059ded1a62a016b,
+                    0xe3649502186e1af8,
+                    0xb8a247a5443388fc,
+                    0x2a1ec525a62f02dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf36941a8ae5ba042,
+                    0x06024dd808359e35,
+                    0x55e82292a1f8fab1,
+                    0xb9e69fc06cbded68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64e2b5f335106d2a,
+                    0xfd0bc3e0729127d1,
+                    0xc7036838df95b730,
+                    0x2e72162726b9eb2b,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x1f78846104adf822,
+                0x812a57fdb9927ff7,
+                0x8cbc3adbdff203a2,
+                0x8cee09c31a46d01e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x89a663bfac310562,
+                0x58ac720bbfb45294,
+                0x800e9ddf20a74c89,
+                0x91fea3ad14607c03,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc9ab50f961bfd992,
+                0x7ea952dd1aab9954,
+                0x42d877663f9535c0,
+                0xa6724a63b095322e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa5182d164e10ba71,
+                0xb118bbd7dd137491,
+                0x3b29a15549db8937,
+                0xf62529f2511e2fcb,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9aa521cf76f34130,
+                0x2d00a753d38e3883,
+                0x09fa27695dab0c03,
+                0x9deecd91999be0bf,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x2670b0f1811e6132,
+                0x79113aa5ab09c831,
+                0xaaccdc02a0430089,
+                0x1d1a5aa381344b60,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x2eac35f5a996b6f0,
+                0x43ec961809043eec,
+                0x3c146b597311428a,
+                0xc3303b3f8718167a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa429fbbb9eaa67bf,
+                0x63e146c045a2ff62,
+                0x9a803743a576fc2f,
+                0xf0e9be23946aaddf,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x79922041cc3198f6,
+                0xab47da71f7dcbed1,
+                0xa1fc84034b25f4b9,
+                0x84e4d93780758091,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x92d4f819e28b7815,
+                0x71bb69f653ce114a,
+                0xd64f8b8144903ebe,
+                0x7f814ea0881fbd64,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 248, 249, 749] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 248];
+        let mut opad = vec![0x5cu8; 248];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,2480 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 320 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 8 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 8;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 14 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 14;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3051ae846c372e5f,
+                    0x3e59f54186f7c5b6,
+                    0xf3ded063fbee0206,
+                    0xe48dc865a412fc25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd481e00f0e4194af,
+                    0xc0d862c56bc79d62,
+                    0x01dff13d4793a494,
+                    0xc693a8363eeb7e14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x653d58a983a7c171,
+                    0x43d57cdc99c67665,
+                    0x017456ac4825a4b9,
+                    0xa0c740c38dff1b8c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8cdb9e41f2999a37,
+                    0x70571f8b41fdbcff,
+                    0x944cdf27048267d2,
+                    0xf114804e58369558,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66edbb478b4eb300,
+                    0x7638c37e8a72e035,
+                    0xdfbba367edf93289,
+                    0x8785422f9a20325e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb8aa08e3d7e504b,
+                    0xa0becf007e35c209,
+                    0xa69ba5a8e8612fdd,
+                    0xc7c180f70f20ec03,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x815a6ab773852389,
+                    0x8c6d0a38be0a0a68,
+                    0x344367a1946fc3f5,
+                    0x6b3369ab64b1482c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf7b14d3ca192fa6,
+                    0xfc11a92f81c0c39b,
+                    0xe8a4ce9bc7ffc6fc,
+                    0x7f2182d331ed336a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5545b481c2bf04a,
+                    0xa73b2e6491f0cb12,
+                    0x619c558206d7e040,
+                    0xcfcf43589a77f9b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9666180b96910e7e,
+                    0x8d097e1999bb5daa,
+                    0x53f4923867c40084,
+                    0x7573e774f71f0ed7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa96fcf7ca8afa4c3,
+                    0xd9e71bc6b5e8754d,
+                    0xf79e6be0c69df996,
+                    0xa548676f0008a757,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x006bc1244319f757,
+                    0x3715bced22164a80,
+                    0x31bc2f43922cc500,
+                    0x59a0bfa608a0d74d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92ffec685fc7516e,
+                    0x55fc5cf5fcc7ca8e,
+                    0xaf8fb904bf2bedab,
+                    0x5d9ce277d9d0a1da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf5aa8698ec2e80d,
+                    0x4a8a0839bb43b204,
+                    0x8ae3225e7b407854,
+                    0xe02765fe5e608038,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdececd4d3d000649,
+                    0x1ac79c8c23af0f08,
+                    0x6d683d46ad65cfa8,
+                    0x84f1bbde011d7ab9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb0c9f38c520e6ef,
+                    0xe0912f62ac15409b,
+                    0xffa2e149636efab1,
+                    0x5d6a9df67b70a735,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb614b6acc1cf887,
+                    0x07ce010a73db976b,
+                    0x01c4f50013ef090a,
+                    0x908c2e3557a857b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98283bbb8921cebd,
+                    0x0246a0849c8f5f03,
+                    0xbd52a90b853bb3ae,
+                    0xcde8c5a12a1cf9fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd55901386bb1a3fa,
+                    0xa2c1898d0c27d747,
+                    0x9fea75a02b55bc65,
+                    0xaaa936e8591edb5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ebbac3f4df1fbdd,
+                    0xf9ca5641918fd377,
+                    0xde31510c66657181,
+                    0x615764417d335c4c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xabcb70b3c52cba3e,
+                    0x2078f7e2ae1ecc38,
+                    0x9ec3560af1b6c110,
+                    0xabae87d766e00708,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae89e17caa60f3a8,
+                    0x98c0683a9dbfb414,
+                    0x18efdaa2b5ac3cf9,
+                    0xb9b0d87c065bf6a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b8c0e6dca65c78f,
+                    0xe1719ddc56eb7d77,
+                    0x5ee9050a6b174f2f,
+                    0x368b1e21d5ef1e52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb287d64d266e7d3,
+                    0x61fb66e1e0854e37,
+                    0xbbbf7661f5c8081b,
+                    0xe8737fea9c93b7de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x860f8fb54c3e73e0,
+                    0x82d614bbd37d2719,
+                    0x10acd0c57d66c810,
+                    0x04091f5c0c87b386,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16bc43294fe2cac7,
+                    0xef7a740a3647db1f,
+                    0x5deb89a1255fe36c,
+                    0x4a1b9600074a3b2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92791569bc10ba28,
+                    0x4d9ad8b623f356fe,
+                    0x65428e1ffdd3d88a,
+                    0x6e6c0fce957c3027,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1636e609a0123a7,
+                    0x679af399de5378a8,
+                    0x53df8c5c31b8ddb2,
+                    0xa3327a7cf912ad13,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87dbbe9dbc2445b3,
+                    0xcf4695a5127e1a2e,
+                    0xed8fea17f6201b8e,
+                    0xe7977146672511a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55ab1af4cbd839aa,
+                    0xba24fdeaea26feb6,
+                    0x3a77c2ed02944a32,
+                    0x2f86c29e0824a709,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb13a58aff161ab9a,
+                    0x36f3239e7f531265,
+                    0x9e4cc5c1a7aa3992,
+                    0x9ce0ff158d37939a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x13951db951a87a15,
+                    0x8c1955f880d69c32,
+                    0xaf98365a4da5913a,
+                    0x37c855abfe543fae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b210043fba321f6,
+                    0x70fed551cfd8468c,
+                    0x66963a367e39431e,
+                    0x6ecd111ddacfd9ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfda20cea292855a6,
+                    0x9e7b3529d28b776d,
+                    0xe26faa363cd44237,
+                    0x5212c5e7aea99de7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe566442bf7f09caf,
+                    0x42ccb360e9d05bae,
+                    0xd8a6d60182b64c9d,
+                    0xd03fbc8dadae979d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1de0e74b19e6bc3,
+                    0x8c64783a23286378,
+                    0xb9cab9645ca496b4,
+                    0xe35d6ab556d20e0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc96394590b365206,
+                    0xf7f302c1ada94196,
+                    0x12fbda5c279cb707,
+                    0xe5892bcd03e74161,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9889b296ab2c3e6a,
+                    0xa0a24d868d983e73,
+                    0xd225239b81487f8e,
+                    0x7278c03d7ca519ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x010048dd05c76ce4,
+                    0x8c4dfdacbcb1400c,
+                    0x3cb095f68adbc0a4,
+                    0x2b9354402aa279aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7c8c59c6dd04764,
+                    0x795d1db719eaa5b9,
+                    0xdf6fc24e917f01a8,
+                    0x71afe41e77d613e3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc9b70bfd9774605e,
+                    0x4e18ac4a16a7f41c,
+                    0xd72ba78f983bec91,
+                    0x33d32618e100e4c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60f548c267d96078,
+                    0x87b1f60759b5cef9,
+                    0x78e4e5e052589676,
+                    0x8aae559df1aad05f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x990629904cc4fbeb,
+                    0x65d54c20e27611b9,
+                    0x73273e71ef1ec2fd,
+                    0x68a45eb8775d9282,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a96880554ee4d4b,
+                    0xb69a6372311412ce,
+                    0x8944879fd5d6fb28,
+                    0x49204096fc1995ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06735b2198299688,
+                    0xe2a7957936ae0f23,
+                    0xdfdb36e3be72a171,
+                    0xcf8dad905276dea7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd81772ce5058f7d9,
+                    0xfa57625a858c63f4,
+                    0x318a679ca0beb8e0,
+                    0xb88067f99b4b12a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb94ae11c2fea502,
+                    0xb991a097bb12aaf8,
+                    0x27fe7447140c49a0,
+                    0x5bb8c0f4f387f581,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73c1ac6cdd84846d,
+                    0x814ed53884023a6b,
+                    0xeecb349a1466c74d,
+                    0xaf5b35483ad244ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b1a94b05fa85b7b,
+                    0x551adce34d245718,
+                    0xdb0d629cf4c3f780,
+                    0xe4ddb357dd69884c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x872b363c9d4a4410,
+                    0x96d476a14c4ab096,
+                    0xe623261a99a57ade,
+                    0xf4839a74c7bf6c51,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd8cb7ff441645a21,
+                    0xc71b99682ead9a7d,
+                    0xba4c6d7e5ad48944,
+                    0x2245b282b086547a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37db028070357b14,
+                    0x04dde42cae9223a8,
+                    0x85eceb0a0b9def1b,
+                    0xfa6a1858968f848b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x837c7a060fded4bf,
+                    0x28744766161d459b,
+                    0x88bf0588a1394445,
+                    0x6c888ad4692a220c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8832f5d7f77e92fa,
+                    0xf569cae9bf5a0962,
+                    0x5d5bae25ec78d22c,
+                    0x564bb68d4df19d4b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7203097a7b6d46d,
+                    0x9dbdc113563d694a,
+                    0x280732b64a8d0b08,
+                    0x66705a0889afee89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12bccb23c28573be,
+                    0xcb7936fdf6193fa0,
+                    0x074183d37f065bbc,
+                    0xba03b577990db7dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc99ea812dc34d740,
+                    0xb87dc850d1a3ed40,
+                    0xdaebf0da028c5cce,
+                    0xae8525467159e8c2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42f636685ab72c9a,
+                    0xa65a0d15e409d6b0,
+                    0x9f164b670e00148e,
+                    0x5934400e17d6c430,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3411ea48f1e6fae7,
+                    0x1ab97281f6d0ccf6,
+                    0x4845dc1058a27959,
+                    0x3cbd87e4cee5eda5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e80db602b00fe0b,
+                    0xdd438e7c5d08d7ec,
+                    0x6f693ffca4bef3fd,
+                    0x2d399b5c5437f69b,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb345792f68867de0,
+                    0x118a9624f2fa7e16,
+                    0xe1a81d35475d6305,
+                    0xe81c9e1ba7ff5ce0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8dc9088e51340168,
+                    0x760bf9b1fd038714,
+                    0x77ea7dd3374bcb9b,
+                    0x1fad7c7b12385347,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1498fe0316e958cb,
+                    0xd72eb7c56baef65a,
+                    0x2a124798d2cf9fac,
+                    0x65c4b07a3b501c8d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3899c904c1c5c3b7,
+                    0x5e0e815f93a749c4,
+                    0x0cee125d14a57e8e,
+                    0x6570552534258a3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ea2aaedf310ceca,
+                    0x87f0550fa22d7f84,
+                    0x8ecc052b4e49f67b,
+                    0x8ec633dac47391cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67e9f6abf510b2a6,
+                    0xe415168ae1da05af,
+                    0x49d6a3410dcff129,
+                    0x090ee98ff19d933f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3acb067996e06543,
+                    0x43cda7ca6b6d8d1e,
+                    0x30d74a02dfe8b563,
+                    0x73668e18a4b086c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb64f7cad17a9b7b1,
+                    0xb0d1266264d2a95c,
+                    0x92476f71c2e59f8c,
+                    0xb5c352b28bb6d072,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44bf98939ad78841,
+                    0x8fdc84a481d27ae5,
+                    0x8c41eb9c8b3a766f,
+                    0xecfa8e8a4cde79ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee2980cfd789254d,
+                    0xae26279a15913994,
+                    0x571c9ed05ec8309e,
+                    0x6a1d083ee1d41dfd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa6cfaf2de3d33b48,
+                    0x14cdadcf0f6ae007,
+                    0xbb1f4f9566426b4a,
+                    0xe9785336f73dffc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5c31fec6571c213,
+                    0x66a24b2815216bdb,
+                    0x064348de872d798e,
+                    0x2bb159aa08bdf0ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbc569c9370830c9,
+                    0xd4160e4edca2ccb4,
+                    0x69e1bf9e1d84b8cf,
+                    0x18d1d7917df0320a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x291d275bc66c1d6c,
+                    0x6a214e2579be6ef6,
+                    0x1f9e8824acae6ee4,
+                    0xa9e4f2f84415e4a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa07686f6109d7864,
+                    0x4a07c051b9f50bbc,
+                    0x8f7e7ffc8334c6b1,
+                    0xb66f3ae667e7fd3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbef385433958f9bb,
+                    0x7b4faecb49ac3bbd,
+                    0x01a9591833c2efcd,
+                    0xca270f5f57f578ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x694b6435f0c2504d,
+                    0x048e7207e65c737e,
+                    0x7421f4b3caecdbac,
+                    0x0e7e31598ccc3032,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cc450e55c3fb987,
+                    0x56baad3d090edd75,
+                    0x281c682ef231d13b,
+                    0xb553a98a20495373,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa2f729681130b0c,
+                    0xc0ae072050f28d0f,
+                    0x8703832b5f722c1d,
+                    0xadecf301962c938d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb18fd976a5cf2962,
+                    0x5e1ec73160cdcf82,
+                    0xb8611ac67827dca5,
+                    0x4fe8d70b57844c5b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6f35cc573446371d,
+                    0x3ba3b9b56ed4b145,
+                    0x72071035644b9d96,
+                    0xa879d3077c3d950e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3a46987889d9c4e,
+                    0x22a78fbaead804cf,
+                    0xcf5cc23b04eeff1c,
+                    0xdd4dcfc3c8b62e6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31a4ff51763d8888,
+                    0x9fe6c896cbbedce8,
+                    0x94936236074b2bb4,
+                    0xece1899265be8368,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6998d8346fd79ef,
+                    0x2ca04e2938c6434c,
+                    0xa0cc5993c037c8e3,
+                    0xcd4536588c6da869,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa25efb4dd44a8ae8,
+                    0xa8b342f380670f80,
+                    0x55eae023ed6c8542,
+                    0x5c3d671bd2f69a65,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58c923f499b3f0c6,
+                    0x6543329e0bcaacbf,
+                    0x53e8475eea0b924a,
+                    0x73033df2e8559282,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x527faf710651a5aa,
+                    0x192db2fa4a2ff3dd,
+                    0x3ef08dfc5e910907,
+                    0x13a3134228995749,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78b34beaab5287cb,
+                    0x54c8a3f8f31240bb,
+                    0xdc5a2e506444f57d,
+                    0x85ea2530d9b5204d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9003b97b54a15b22,
+                    0x74367e7b06ae368c,
+                    0xa0f7ecadcf4743c5,
+                    0x0e138bfdedc1951f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeae838e02bad1dad,
+                    0xfdf6a07cfb5a29db,
+                    0xd520e0031db6568d,
+                    0xc46be3cb6bf55128,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf96938325c149b5c,
+                    0x5eb25194b8afd4e7,
+                    0x73d3157a7c1559c0,
+                    0x782bf54d74ada6b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9ed222dd1774b98,
+                    0x709bb285d98fe129,
+                    0x2a3f7edebd54410d,
+                    0x58520264d1417b67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6632dd4d00165006,
+                    0x9841fdd752bbc277,
+                    0xecf7ce076315d177,
+                    0xee00cc2eb229bda8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90e61031354b3246,
+                    0xf754b4a90387af50,
+                    0x40359fd72fe773db,
+                    0xcc038fbde735b42e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3277e861ceea3a18,
+                    0x5f0a703c92db80bf,
+                    0xa8cffe902baf45b7,
+                    0xf9d41e6858ee07af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21d6751fd32efaef,
+                    0x0e24e6df84c5c332,
+                    0x8bc28f88d22183e0,
+                    0xd5a53056ab30d9f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e6900b0b8102a67,
+                    0xd4fba4441ecbae01,
+                    0xfe1c26379986afe3,
+                    0x9f6ba94711be7e88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63bed4f68bdd088a,
+                    0xc3f4c200a5c570b6,
+                    0x249bf869dff9633d,
+                    0xbccee0695bfd7f29,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a8f60f7f0081e2e,
+                    0x50e8728cdaabc13c,
+                    0xbefc10c608fced61,
+                    0x4a42bb4a063ac156,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x619c92d5d081bdb7,
+                    0xc82f31618d8c9f3c,
+                    0x6914767a2c45c88f,
+                    0xc961abda91c189f3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb38016a74648d672,
+                    0xdf6179b55272128b,
+                    0xa00f51de8f81b84f,
+                    0xeebb4cb46ce9e767,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddc10ac3695fa364,
+                    0x9bd35ddf4d932cb5,
+                    0x44fa42a0de0af8e8,
+                    0x69368e5390fae0a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2114242456607e4d,
+                    0xbd7ab5dda1f84382,
+                    0x437fa6d0acd0d1ac,
+                    0x35ce3743d4992ff5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50f54f5cbc5a6b32,
+                    0x99423a96e020cc89,
+                    0x8655d0ae74330b52,
+                    0x298df13ce53b0cf4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x291cdf7e43c217dd,
+                    0x87438a935868fe00,
+                    0xc159294cd6762b55,
+                    0x1a6c66108bae21b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa731904245ca6c8c,
+                    0xa3914b4a5edaf4e7,
+                    0x37871b66169ff209,
+                    0x2ab0e4564c02eb69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c15c948f2756f06,
+                    0x0388eaa7ea60d542,
+                    0x8b529fbfa0208995,
+                    0xc2c136634d70215c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x718a905512e6c6e6,
+                    0x1b5b7b541b0fe71c,
+                    0xacc6abedea12ae7c,
+                    0x8481f0515bbbedca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5f562891729493b,
+                    0xd67dbe9a31afcd4d,
+                    0x2bce53242901d534,
+                    0x01d27497af5c7723,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x852bbb21e5a6a67b,
+                    0x970632eabfecf5af,
+                    0x2c361696bb9abb72,
+                    0x638db81a0a427f6d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x33c27eb518ee1937,
+                    0xc5711dc3dcf7fc9b,
+                    0x7e706674389af039,
+                    0x83af3c337aa5c078,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcaa63268e4ae46af,
+                    0x0e5c3114acbf2ff6,
+                    0x69b2965d1efbf57d,
+                    0x920fb8d980fd5c16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc97467cd85d68e96,
+                    0x5b8e67d4289ad68a,
+                    0x71e5843b86441692,
+                    0xbd37f4ce5e67bbf9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6f37e108867994c,
+                    0x62634180beec2f3f,
+                    0x050002f4d26a8e9d,
+                    0x514cfc7a4c265cc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35ac1ed08c87c0a1,
+                    0x90e4f77a8fc79dc6,
+                    0x809f1681821e71d4,
+                    0xcee973e05684911f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7da164abf90303c1,
+                    0x02a5b2aaedb20cfb,
+                    0x2531989c51a6e985,
+                    0xa93e0a3069bf0fe9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x980219ff182e100d,
+                    0xa5a3611ff2856a70,
+                    0x4e2e7677270cbcb3,
+                    0xecbf3caaba0a93e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe840edbba58beaa7,
+                    0x755ac2a990695f1a,
+                    0xb1a2b6fb6ec0eaa8,
+                    0x80d7176e2594a0a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21ca44236f5282be,
+                    0x5848fd4293dfa8b1,
+                    0xd9443029c62db7a4,
+                    0xb0cce32ee38ef3af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcea0a3e5060874db,
+                    0x2a88fbb0036098fd,
+                    0xb1eafcdda40ce055,
+                    0x13ed79eb756f8651,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2f5ed2951248b8fa,
+                    0xab9e03f3d0342282,
+                    0xf6f92367501d9a92,
+                    0x311fd640497ad316,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1a3ca29d5e8d29e,
+                    0x0d193d2e19d2b830,
+                    0x95eac81a6cc2fb50,
+                    0xd69214ed7daa1236,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04e3867059f14830,
+                    0xd1d0ef817266636c,
+                    0x2bba67f083814120,
+                    0xb0334a3910cbfb5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4847f8e9f7e705ea,
+                    0xee85a161c34fc4ea,
+                    0xd8cd2f6dddc2825a,
+                    0x7f3495c18983cdb2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea7f60f4ccbd2c8c,
+                    0xa20f1b80f5f9ef4e,
+                    0x698ebbb4d7d2bde9,
+                    0x06a9e45e977f8b21,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03b9e9a625a97855,
+                    0x2936d337a066f3b2,
+                    0xd0d8ebcab8d8ed6c,
+                    0x24d4096e14ab8d75,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x893c6b0df3b6a386,
+                    0x9c137617f3bdcba1,
+                    0xe1ab61ab82bdacc2,
+                    0xfd81a0f1320fbbf1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78816da118fbeeae,
+                    0xea619dee87f893af,
+                    0x002e22b49af38404,
+                    0x7a1cef1b114e1ba5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x265c4c69d7093dec,
+                    0xa0306514b5ea718c,
+                    0x47e8c4b9df878845,
+                    0x00a11e06e462d0cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf535695ad74dc23,
+                    0xd6a6e96a953bb94c,
+                    0x0142a27121665fe5,
+                    0x78da7fde88e31ea8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6b8d4764be2e8186,
+                    0xac8e9f381b679a24,
+                    0x15bd3191501d8c3f,
+                    0x9b20a64d489601a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe982498cbc92dcc,
+                    0x833be0707ccacbe3,
+                    0x803e4ad3e8baeaf4,
+                    0xb6554f726ec81cf2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6abe2dac71981d6a,
+                    0x0fc94b499dc79b22,
+                    0x56fc520864357f5f,
+                    0x9d656dd6cbaa3135,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe24ef1b221063347,
+                    0x632a8727a0067968,
+                    0x98a003815ed330b2,
+                    0xb56b82ec49999ab1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fa1591d0f4a4eff,
+                    0x1b44dee2105d398a,
+                    0xb42cf535fa0cbe18,
+                    0x8d48cce8dd1b8a05,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97cdfec76cd1e597,
+                    0xc3b4eaa4a4b44bd1,
+                    0x1bb84c2ba719191b,
+                    0x1e6e85cca0256904,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24c9323c8c3273fc,
+                    0xb67b1c5f374d9d2c,
+                    0x875c4488082b1c37,
+                    0x86eaab0658a630a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b7ebc0d1ec9a842,
+                    0x8268589f396c264d,
+                    0x15054ea2164ab531,
+                    0x3c8fd24ef0a238fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xffb7f0770b2d3791,
+                    0xb849271ddddf2566,
+                    0x2e1c8283cdbdb790,
+                    0x31f0a23816dcb4fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb63cbea84a1223b1,
+                    0x260345b6b561b50b,
+                    0x731973f55bb2be2f,
+                    0xce3b2734f1e4e86e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xad2bc99e691807e5,
+                    0xc8629fd63f3dc4a9,
+                    0xf1204deb175f963b,
+                    0x3ba1796ef6458c60,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x935480aa17d897dd,
+                    0x31fbbc161f636e6b,
+                    0xf3b6cac6731aab27,
+                    0x6ac83db4023b895a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfcc94672ad44ff10,
+                    0x443c57664684b0d7,
+                    0xc8dc4533e6f4d8b0,
+                    0xf44828840a49533e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9cd6d2c408fecb5,
+                    0xaf70c143177cd5c7,
+                    0x98f8b9668c886a1b,
+                    0x138124dadf7ff338,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd637edfe856c5b8b,
+                    0x2e0d6589960012b2,
+                    0xfd0926bbe495b63b,
+                    0xe4215a86f53b387a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e7a1ae9d7861783,
+                    0x806fe9b55b161bbc,
+                    0x1b2dc72bd8b1ddb3,
+                    0xf5b81b3098818a13,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab498ec6c18f8607,
+                    0x732a7fdb40f97ea6,
+                    0x410aa7cc34e6fff4,
+                    0x7f7400bd58bd5697,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc92524cd92848aca,
+                    0x54642bdbad051821,
+                    0xac4f615ba4856e75,
+                    0xa47e43f121470971,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99c741af11894fd3,
+                    0x6392d5fe9c28d90b,
+                    0x2de85d3dcca7e88d,
+                    0x64d812987e26834c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18d4751636d20af4,
+                    0x0d370aa41918bc4c,
+                    0x767874f01d4d7f37,
+                    0x4a6a4616878b01e0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x77ab90c85449bf32,
+                    0xd6be0c440e8530c3,
+                    0x4ba2d8fac883a852,
+                    0xa581b394ad9274a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a0fa1ad632dcc93,
+                    0x20d27d454e06517c,
+                    0xecfeaf49897320ce,
+                    0x4ee86407edb13192,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3179e663e993f7c1,
+                    0x1d5b394f60cbc22f,
+                    0xbeb646762d3c0a8c,
+                    0x537efba337e25a4a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa256ba9bdcccb41c,
+                    0x0543c9ac9b5dc152,
+                    0xff48fdba43a6651c,
+                    0xef76caf6acf6cf2b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x881506afcd383187,
+                    0x6867f0e7c0773f9e,
+                    0x636a8a21a960d987,
+                    0x60b5eaa3f0f6477f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb39c5095b3d4d6ce,
+                    0x5b30c7f2f76ae5fc,
+                    0xa33948c44c027372,
+                    0x0a1e487730a068c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67247dc6dc853ea1,
+                    0x7b5fa08867a77e3e,
+                    0x71872aa4dbd2eef3,
+                    0xdab12ccedd88a093,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c4d385d8392543f,
+                    0x24563edb22200c52,
+                    0x49869210ca414463,
+                    0x4e3dd3cf420d43b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7cb5e3c23531ff7d,
+                    0x6f5eae9d2460211c,
+                    0x7c8945c8d2b845c5,
+                    0x38d15d1b1dc1e26b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x047463f628d79a62,
+                    0xa8859421568a2d86,
+                    0x878c43ad606bd0db,
+                    0xf2fad74d8286ea69,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x69b3c94d921dad61,
+                    0x6e2ed772fe82ec8f,
+                    0xb7906faeb9a92a4f,
+                    0x64f947ec2e7418cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1c7d9ebe39385f4,
+                    0xa2fd379d6b27b2e1,
+                    0x5225cccd2d09d9eb,
+                    0xe3a5aae8949006dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc646b4be48be6a1,
+                    0xe1b5b872e2fadfb4,
+                    0x05edf8290d3ad663,
+                    0x7e3d95d8e1434126,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7e031f2fc391baf,
+                    0x3adf33721b449260,
+                    0x4b868af827c9fb59,
+                    0x52009e0879da3bfc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3496b1bb1c60065,
+                    0x753b93e5887f356c,
+                    0x500e5fa0c38e9d24,
+                    0x8c0fd18b22418946,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa19b61ba06fabc95,
+                    0x866653773c0864b5,
+                    0xb4b9e26606242b56,
+                    0xfcf494b459873ee8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95ffb2e1bfaff097,
+                    0x2ab4f49eb9a018f5,
+                    0x1346f0043718b0d2,
+                    0xf735ab0bd3958565,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c29af02a6b80071,
+                    0xd0760f1f8f90413c,
+                    0xaedba6fac470cbfd,
+                    0x89c590ce7020e048,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fcd58e44714ed61,
+                    0x11586d8e236822fc,
+                    0xa9710f98fb73e6db,
+                    0xd370aa55d98ba596,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdc71e695a3956a4,
+                    0x7a25da85bc41d129,
+                    0xc13d35ab63d6b996,
+                    0xc143f2dbd87558a5,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x40abe9a4e88aa264,
+                    0x69f7ad8fc9506eb0,
+                    0x4bd8bcd9537fd79a,
+                    0x97add0135891da6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a4c37ab3a74e662,
+                    0x793a76d8a4ac8938,
+                    0x9920ed301c7a3085,
+                    0x39a865ea0a2d204f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9d65e534dbe6dba,
+                    0x4336d616a751612a,
+                    0x3bd0386abea42e44,
+                    0x14576af2be678c3e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0a297719d850b491,
+                    0x5a4ae303cdf7e651,
+                    0xf35fbcfcec2272c3,
+                    0xe2c9dfd8843e5663,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8087d2780b5a6136,
+                    0x5d0bdf19f4534fe2,
+                    0x86279e383f788093,
+                    0x74eeb4659b28f626,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16091c33622e7b6a,
+                    0x4c9b027095ed807d,
+                    0x9c4ec629a830d49e,
+                    0xd66cd8e26844bbb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52c27164df23eaa2,
+                    0xb3ec5e539cc75d5e,
+                    0xb5fe5d253ee838bc,
+                    0x26387ba80b19dbb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03edb13980bf5198,
+                    0x292e07f4bd16e914,
+                    0x8175a9dd9946302e,
+                    0xbbed2d6e59380454,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4239823d761ae0fe,
+                    0x7fd7088bf1c24cff,
+                    0xa7df9c045d885bc0,
+                    0x629def58a5270491,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31766af5771b001d,
+                    0xa6b913213c8385af,
+                    0xa42506987946c7db,
+                    0xd7738381b94ba867,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x827ed44c9d086e72,
+                    0xca6feb626a073cbb,
+                    0x0000000000000011,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd62e1fbfc4441447,
+                    0x6d21ce30afefa3ff,
+                    0x83118b92f58c1847,
+                    0x366f96b81515c5da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x16ed61e16516769a,
+                    0xa2121f0101f25333,
+                    0xc5255e2c796ac00a,
+                    0x14a9e126f865c2a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7369a8114d794d36,
+                    0x3660f7b3bdde57d8,
+                    0x0aae409e4901501b,
+                    0x38bf4ebafb0c11e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2124049d635c4805,
+                    0xc62060af02348bfc,
+                    0x59967d74ea8042e9,
+                    0xe486c3b4481803ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3cfef280ee8cd2eb,
+                    0x4a10b19ee2d23f04,
+                    0x0d33c452d10adabf,
+                    0xf8540f134cbb86d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5782e4ec3181129,
+                    0xa4f8956a5744dab3,
+                    0xd2daf747de1dec51,
+                    0x151018c17b6c87e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b2ce5d0a01f0b2e,
+                    0xa4fb504a76ea986f,
+                    0xf79b66464fc64182,
+                    0x6c25d72bdd5729f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41959a758fc4f1c5,
+                    0xdbe9abdfd830b540,
+                    0x434a5ec03d30f79b,
+                    0x408ee97bca0e11d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5179dd3b3450e919,
+                    0xf9263f8a51e77533,
+                    0xdf6215b22bec0d0f,
+                    0x81ccbeaeeee46284,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d4305f7e2e82740,
+                    0x4bb7d2e830c05e23,
+                    0xd8fef4b1318f26d6,
+                    0xef756f24b8a078cf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x932f84f62d9a559f,
+                    0x9eb1f158c9303ae7,
+                    0x82514a91c3056e9d,
+                    0xc1c04b81924ccf1c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69f7fc6112d39e9d,
+                    0xb2ea719c4adbba41,
+                    0xde80740ee230b213,
+                    0xb0c3b933f69b4e96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x148c0ade32731eba,
+                    0x31b166f4d2352350,
+                    0xe44f71947d4bb229,
+                    0xf6d87deef8d70150,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x964a44cb801105d1,
+                    0x4fd379c7e11e1681,
+                    0x41eb52e4e12fbc42,
+                    0x0066a5b8b5ea9381,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f152a854a6b84ab,
+                    0xf05b2c3c0fd660d6,
+                    0xa1b660eb6f50fc38,
+                    0x4c18a78610b2acd1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc04cdb7503d68763,
+                    0x26ea45e8aaf861ca,
+                    0x7db6676539f76e69,
+                    0x2a619dd0762d3764,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05f706096761dee9,
+                    0xa68c38ce725a8ad0,
+                    0xb01cb45cbc5c98db,
+                    0xf581587819023e81,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcab3a6762596203d,
+                    0xa4a5e5c0c08e712c,
+                    0x7aea7646aeddbbee,
+                    0xa5f12276fef5b7ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9af7c79cdab1715,
+                    0xc57d37f07de8abab,
+                    0x5f8a54d4792c9690,
+                    0x6d5c2268d0d5503c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e34a904132b5b8c,
+                    0x4084dc826ef7b956,
+                    0xda15ca73f398ea1e,
+                    0x29f2f4a8df9da2b7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xce9683e3a46b0f75,
+                    0x95a27ba603ddf85c,
+                    0x947c8f92e8670314,
+                    0x45ed88bd5f84f6c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59527f66c8b16b2b,
+                    0xc693df3324e00150,
+                    0x31435cb506dd8a49,
+                    0x54409e0f4f4d5614,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fa5f019b86f8dc5,
+                    0x393147a59e363812,
+                    0xe003a05abe421638,
+                    0xb89c7733adbad032,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6362685414db0ab,
+                    0x7e4bc471cf16627f,
+                    0x248b4f01066fcb22,
+                    0x1b10ad12f3e8967c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf29b3df64cac3f4a,
+                    0xaa6f3022a737517f,
+                    0xe99a88caafc2c33f,
+                    0x92c1e76878d86e02,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeee5df2517e497a1,
+                    0xd3dc019874059be9,
+                    0xd8d9d87cf0137979,
+                    0x48bc1cc50d2ba3ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x412f06e1ecb7eda0,
+                    0x4bb960aecb6b0c65,
+                    0xef8d811634604b46,
+                    0x3cb9994253c76b9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a57063b30106556,
+                    0x8f419144b13e59f1,
+                    0x57a1adac7e422905,
+                    0xc4b200ebd88eacfb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fe3821c434ba60c,
+                    0x0fcd47042cff901b,
+                    0xeb8d8b242611a169,
+                    0x48afd03e262145fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x798830f7c79d2c72,
+                    0x610c4cea758a330c,
+                    0x692250b2c04c4758,
+                    0x6a1a9093f3651392,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x192c5ba638037254,
+                    0x53f10661e3bdaa8e,
+                    0x62fd05383728bce3,
+                    0x904fcb94ab955749,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaaa8b2273bc758b6,
+                    0x4bd82d58511d4810,
+                    0xb168545b11b80a74,
+                    0x2f2bed06542d5ead,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e1de9468dcfb493,
+                    0xfad24bb5e20fdb71,
+                    0xb7c3b2f03e534197,
+                    0x85a84900127bca78,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72204300e237a4c4,
+                    0xa7dec59a87c4e2b1,
+                    0xb32630414e8aef98,
+                    0x1dce4aa874d4fe68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00c825418fdef13b,
+                    0x169540497a16d28e,
+                    0x6d4f7f75156e080a,
+                    0xee3eafaea7f7c5e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe56ab701524e6a0,
+                    0xb271d2fdb9bc00c2,
+                    0x7ef9089b1bf88957,
+                    0xd1e5df797619411e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69c769619fbcee78,
+                    0x481ee20363136389,
+                    0x213d9b2b3965754f,
+                    0xcdeeb8b9fd90ab62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3725131b9c333682,
+                    0x6c8b29988eb575c7,
+                    0x237ee8aa79bd82bd,
+                    0x3c20e415f28edbfb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf24a9617970353b,
+                    0x8e9d51aa56e143ea,
+                    0x1423b18f86857f96,
+                    0xe23ac6f5e8bfafd0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca0564730a934b43,
+                    0x30e1b9d93ca98f9f,
+                    0x89369321b3955af8,
+                    0x71b499a3d7faf231,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x61aa8d26abd1d067,
+                    0x8bdabc450e75d1cd,
+                    0xd9e22efb7f548689,
+                    0xc7a94438f97e816d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4873eb67d319c95,
+                    0xd10fe1d39ec7fcd3,
+                    0x10b312cb602aea81,
+                    0x8102c8a454ac9d16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf4450fd2ebd4cbf,
+                    0x8e7e343f2f4a4125,
+                    0xba9e60c04786940c,
+                    0x7a8f1ebe62829e7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcec784bbe7afb324,
+                    0x19b1df2f408cb5ad,
+                    0x288345a70ea1af18,
+                    0x12c6e036e00870df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c86d341bc6f9bca,
+                    0x5ecceae811bf33b7,
+                    0x8f68cd340032978d,
+                    0x2ef387bef4b5e731,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52acbecbd51cf4d9,
+                    0xb247d9ea487b1cc9,
+                    0x4b0c08759bf4f079,
+                    0xf123edd0f1d2f394,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9bdbf9da144887f9,
+                    0x7f2fc6654098648b,
+                    0xcf4e914317750120,
+                    0x68e0bdc45b15266c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb92bc3030441708a,
+                    0xd64fe2a8bff5e9b9,
+                    0x4041ef6d244de1c0,
+                    0x1944db863d9131f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12d35fc87c6dfd1d,
+                    0xce68cb1b3b9df24a,
+                    0xba6fd2060a4ba9ae,
+                    0xec231f9aacfae4d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61f96bc3782688ad,
+                    0x7fc7cf47066ca0cd,
+                    0x408a12176b907b8b,
+                    0xa59159ce1df8b6bf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x34d9fdbcc4fc02c0,
+                    0x872460d545bd82b3,
+                    0x04af5e5699cae0e2,
+                    0xe22d7c33b6bbc7c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39593f8b17c859d4,
+                    0x368fcbe2aac8a4af,
+                    0x42ea85b13ee6ef6e,
+                    0xc07d525d7ccccc76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcca83100fd820922,
+                    0x153e0e6142787ed2,
+                    0xeeae6566459273cd,
+                    0xd4eea5dba949d058,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77cc429acafdb619,
+                    0x3f7e610638630c0c,
+                    0xeca6609751382396,
+                    0x6b18b2de09d71a47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b52f8a0328b6cf7,
+                    0xa2eb8b3933129d15,
+                    0x4d82b53d7fa3e0a7,
+                    0x78e4c13ca118126f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92fe8f3a379ffd71,
+                    0xe6651487e5492484,
+                    0xcd30d4421ed6c2bd,
+                    0x97edc193424d39e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91ba86438f1cecf8,
+                    0x387220fa01023a4a,
+                    0x14f7440c3def51b3,
+                    0xbdb3751e02d63b6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15e68919925b8bd1,
+                    0xd481dcdf9a595525,
+                    0x8036bb02c961755a,
+                    0x01dbcf4981c06486,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1baef423e6f35f3d,
+                    0xd2eb4db33a3a822d,
+                    0xafa397be16f2d232,
+                    0xfd5431d0e1811f5a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7637cc9f6dd0d555,
+                    0xe7941342e54f67a2,
+                    0xaae5b1f3876ef43b,
+                    0xf0262bc0b21d00b1,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,3142 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U10>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x078ebe84ff054953,
+                0x061150bba4423460,
+                0xd71f11d5df6eac30,
+                0x2875a67ed0f30ec1,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf5509200a6ffbdc8,
+                    0x2a546a0b1dd53039,
+                    0x98e482cf41561023,
+                    0x05ad587fae7c3ab9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x270b9673eebed0d1,
+                    0x14e7f8747f4ad9ea,
+                    0xce087bb502162819,
+                    0x20413be492f4d3c4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1b227a2cbcc6e02f,
+                    0xb3674ebaf40897b4,
+                    0x78c14820edf4e378,
+                    0xfe46d56a30a662d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4daa591bda79ed27,
+                    0xbb12105f5a1ae0b0,
+                    0xc0278df93ed0340a,
+                    0x370d56c04c83e8e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe35f6ebe6cbfb37b,
+                    0xa1dba7c58a867990,
+                    0xa07715f96c9bd8fe,
+                    0x2f2f593db9dadd29,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x814a66c7e35871bc,
+                    0xf67a5a980dda6963,
+                    0xb561358bdebbde3a,
+                    0xd68581e9953d2e8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x419fcd1c92143b96,
+                    0x5913111c7ecd7eb3,
+                    0x0519380117a00bf5,
+                    0xeffe425804c71662,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeeb5b38a7a692470,
+                    0x92085cb5f8c194f8,
+                    0x9ad089ff8dd23015,
+                    0x54eacc6ea12c58aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c4338d2566b5754,
+                    0xc5824707c600d7e3,
+                    0xdae9d29ebced8b3a,
+                    0x38f150a3f5f8fea5,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x10b4494a7188a6c7,
+                    0x062b748ab544535b,
+                    0x5c796214e7ebd98d,
+                    0x57d72af0d96ac228,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c2449a0e171dccb,
+                    0x6a44586551069db9,
+                    0x01718623f4db8750,
+                    0xccd652dc70add580,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d4fdb9bbd109e86,
+                    0x2d8a6b8ae3efefc5,
+                    0x1b30117662ca1d69,
+                    0x79b35130d9cbedbd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e1eeda79fbb9542,
+                    0x1b07c6abc9b1ff51,
+                    0xc6d5db637e066af4,
+                    0xfc86c4cb6e54b5d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf3b679836c08cb9,
+                    0x6b57a30bca43aaa3,
+                    0xd88c8e367544aab3,
+                    0x382c2c450eed9649,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5bd2889b8bc2346a,
+                    0x0e5e29ea961514dc,
+                    0x9b5d985929981c3a,
+                    0x33603babee99ae20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37a4b6af29d10dfb,
+                    0x1bc4bb60c5c7aeb3,
+                    0xe8e04de913e60fae,
+                    0xbef830845b38bbc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe237c19e05aa77b4,
+                    0x15e4a200271a9b60,
+                    0xb8d08446a24943d6,
+                    0xcf0f16736b3cc804,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b8644e0212d28cd,
+                    0x3932098becc1e598,
+                    0xcedf1a23884f7683,
+                    0x1b107f84acf85829,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0b8806a07a5fc1c,
+                    0xa59d64dadf19f916,
+                    0xa5ca8e47652099cb,
+                    0x00a7686397ece51d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5313c4b9a35786a3,
+                    0x3a613bcd6037657f,
+                    0x2540f2ca691de454,
+                    0x71d0dfe80716eefd,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xdf8b31286a698086,
+                    0x35e90b6bd130d621,
+                    0x9d0547198f82ce75,
+                    0xe91b904fb4eddc96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46ca07f5e937b43f,
+                    0x724c3a4b53fe1864,
+                    0x712a4568a9bb56cd,
+                    0x742f4c3f775791b6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x80edfd4ce89f66d1,
+                    0x9733da5bb1c3cec4,
+                    0x336f3a9514807831,
+                    0x264e02c04b0f4014,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59b215da7b413db9,
+                    0x3c61167f18a75d99,
+                    0xfba4e0b3f6ace9d0,
+                    0xe4d1ac4a6354d9c6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd5c64b93cf8dc022,
+                    0x4d0e0b97dfdc23fa,
+                    0xe14e22bfa9eacd5d,
+                    0x95004cde4a754e16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a41e502dd830e99,
+                    0x5f12eb7678d7598e,
+                    0x23459a9ad404fe1f,
+                    0xc9f0677fdd658aa0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x84d1beb07eb4e352,
+                    0x47b8e0ff6dea09df,
+                    0xb276cdf7e590b815,
+                    0x98f306691aa7ba31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5df1c246dcaf237,
+                    0x037dff8cbd64c303,
+                    0x7847cf782fcb0d50,
+                    0x1c390c850f2be598,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc9cd245c7156400c,
+                    0x31c9a31440ebc708,
+                    0x73329787f4655f43,
+                    0x313f6090610487b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a08f45736178caf,
+                    0xac4112bd43fe14e4,
+                    0x7d75cc423962072f,
+                    0x7ba6f04a35f2049f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8ed2c1521253e3d6,
+                    0xf9539e3c3d2553ba,
+                    0x35a0e0c8ebc9968c,
+                    0x92e6dfd676c870e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3a7acdb32141620,
+                    0x26b5bd4a02f11932,
+                    0xfb00b7b3928f8207,
+                    0x4eb15f1b38a73589,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x44e357ba421607fe,
+                    0xff774762a96cb9f5,
+                    0x597747850eab791c,
+                    0xc104b91930b07928,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd7730c30bc4a6c1,
+                    0x9633c100ceec7645,
+                    0x247b26693b93a40e,
+                    0xdda96cdab7999dd7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xceacb6a2ae01b1a1,
+                    0x75726b2d6a441360,
+                    0x8df240de933a11a5,
+                    0x4bbfc9adc9e4b5c2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97a250d4f3b3dc4d,
+                    0xe29c0fa921a0714c,
+                    0xfc8e06cf7aaf78a9,
+                    0xac643aa05da621fc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x348e920684889299,
+                    0x1d8befbbf6f2f455,
+                    0x23f0efe3a2b5f472,
+                    0x719da97420517896,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4000289d784e4fcc,
+                    0x549c9c055ab8c2b3,
+                    0x83c5b24902b136f8,
+                    0x171b564dee73a9a7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe3a6203b00f74019,
+                    0xdb3a69bd5ab34c92,
+                    0x2a3d6d06dfb30bc5,
+                    0xed617dac9e2ec637,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f4b237e76ac6b36,
+                    0x530d27863e69fef3,
+                    0x2251822b80ab2039,
+                    0x4cf951fe2985d079,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xdf8b31286a698086,
+                    0x35e90b6bd130d621,
+                    0x9d0547198f82ce75,
+                    0xe91b904fb4eddc96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46ca07f5e937b43f,
+                    0x724c3a4b53fe1864,
+                    0x712a4568a9bb56cd,
+                    0x742f4c3f775791b6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x80edfd4ce89f66d1,
+                    0x9733da5bb1c3cec4,
+                    0x336f3a9514807831,
+                    0x264e02c04b0f4014,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59b215da7b413db9,
+                    0x3c61167f18a75d99,
+                    0xfba4e0b3f6ace9d0,
+                    0xe4d1ac4a6354d9c6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd5c64b93cf8dc022,
+                    0x4d0e0b97dfdc23fa,
+                    0xe14e22bfa9eacd5d,
+                    0x95004cde4a754e16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a41e502dd830e99,
+                    0x5f12eb7678d7598e,
+                    0x23459a9ad404fe1f,
+                    0xc9f0677fdd658aa0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x84d1beb07eb4e352,
+                    0x47b8e0ff6dea09df,
+                    0xb276cdf7e590b815,
+                    0x98f306691aa7ba31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5df1c246dcaf237,
+                    0x037dff8cbd64c303,
+                    0x7847cf782fcb0d50,
+                    0x1c390c850f2be598,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 372];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+            bytes[310..341].copy_from_slice(&to_bytes!(input[10]).unwrap()[0..31]);
+            bytes[341..372].copy_from_slice(&to_bytes!(input[11]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xbc87a57c7ce924a2,
+                    0xf2571d46ab6a507b,
+                    0x84c6dcab795b3b7d,
+                    0x11fe13fe5b989b2d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f6e4e539c40d7a2,
+                    0xf49e4598bfc6398c,
+                    0xf7dea6949250ec14,
+                    0x099d8f9130b09d6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58a561a73f68e6a3,
+                    0x7fde3de702345f8d,
+                    0x87576f8039f50024,
+                    0xb88116c862889deb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f3f8cf3e41cd40d,
+                    0x7c86d6d008853db1,
+                    0x45088dd86cbac7c4,
+                    0x316c19a4c5a14e2a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06e7a0a0cb3d5586,
+                    0x8acf701d125875bd,
+                    0x69eb262054d48592,
+                    0x869b835d53ba41ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb2a130758c09f47,
+                    0x30a24be58492d3c4,
+                    0xe0daf3ad8f067223,
+                    0xdbab957424f527c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08c3444ad4c07450,
+                    0x02c092c2b7f295b6,
+                    0x7d06d3b3625cc492,
+                    0x93eee652072d54ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbdbdd935e96b7459,
+                    0xc09f77b4d3c59642,
+                    0xf3778bed90bca29f,
+                    0x446fd1c3b47eea89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d84634cd6481697,
+                    0xbe43c5cb1de4f649,
+                    0x8fe6c5f771f74a00,
+                    0x1d589cefb96969e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5edeb6898d8b173,
+                    0x0eddd56b2bae0417,
+                    0x8f6626e46eaaa98b,
+                    0x243ea2ce3508f319,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b3a9ad378e1c731,
+                    0x2efab7599901c9c5,
+                    0x899930e7c37406b4,
+                    0x14f0d4634da001c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3c16c54e1b09828,
+                    0x5ff9fb5ba32c7725,
+                    0x082a88c67e84d4d0,
+                    0x05a3927c743f836c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2e671e61f1a4ec1f,
+                    0x0e08db400bae4b30,
+                    0x3cc3d41f4cb2e95c,
+                    0x510216b4c459ea03,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x68fbda5fd141e442,
+                    0xf7c4364068d19191,
+                    0x905b66d3db9b6af4,
+                    0xf98f485ced55eedf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25cdd0b3d9c93f46,
+                    0x9587949d5c73a6ae,
+                    0x96c530b82491dda5,
+                    0xc4ed69f011811d79,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4d698059c92f115,
+                    0x620c489adcfe0616,
+                    0xda0eb3c734196e17,
+                    0xc1a51a862700c389,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ff368937a8f2914,
+                    0x35a1a5931786a950,
+                    0xe6869260cb8b06c1,
+                    0x9bd3b00c3efa4a85,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56476af308a0b468,
+                    0x14ef22d2b59943c6,
+                    0xd02a92d4cb877048,
+                    0x140e3d2b158b9c39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd46f63ac1a57e440,
+                    0xc2302c494b71c2a6,
+                    0xb7eb8beaa53cda91,
+                    0x5cec93b127f66ffa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3fd67d188c57e162,
+                    0x1e6a625de25ac17a,
+                    0xa089ce080a20424d,
+                    0x6f00fd557f0be1d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2fab62702dc322fc,
+                    0x99513a53100ff684,
+                    0x516a3623925773de,
+                    0xc7516c5da58b6a7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f835256e63cd685,
+                    0x86b544d398df6a12,
+                    0xc205832300c91b41,
+                    0xb4df733708ea9fc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d9da063752bca39,
+                    0xf7e26bf6a9d59835,
+                    0x9a80f32513acfcb9,
+                    0x8144940ddec82d18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38f786dd32aa9d19,
+                    0x92346b14284382c4,
+                    0x0707bb56ee266b3f,
+                    0x56fcaab4eb362605,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf52af562aa16a432,
+                    0x74aa5987a8ee5449,
+                    0xafa26ad99ffcc1c4,
+                    0xb00d6a891abf53c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe074bf23aeb8b673,
+                    0x418425e4e407a5e4,
+                    0x06c726ce01e53220,
+                    0xee8332036657c87c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfe9b4ec3c3f411a,
+                    0x4929e52fbc547777,
+                    0x6a09d822e9f75aa6,
+                    0xf7f8105bdc82cc90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18e39a9f3cd96aa9,
+                    0x0ac394866f6ef0ac,
+                    0xb4cacdae2fd5c4be,
+                    0xbc694fc5322382eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f21dba3f3687c45,
+                    0x2e6eeb0add7c9d1e,
+                    0xf8d058450cb40e8c,
+                    0x6692004fecb096c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10382d25981e9ad2,
+                    0xf88de05521dfc2c3,
+                    0x949f5133b1cdfa0d,
+                    0xc371504e94c6237d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x484ce751ce61d645,
+                    0x595c5066bc76d549,
+                    0x33d214f5abe3a15b,
+                    0xbe394562a92e1d47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb6b055462ae2c28,
+                    0x52947a66897490d6,
+                    0xf253b37139a82a5c,
+                    0x49b8f3cfc96e7bb5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6678a764fa507b02,
+                    0x13776ffa2d2c874d,
+                    0x16fa3f0ce40becdd,
+                    0xdb5cc89276715462,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff27a5e1911b8944,
+                    0xb977d1fd3cd50eeb,
+                    0x71d1c0a0742c4c87,
+                    0xc661d51874b3ed47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34971c2b9f3f0833,
+                    0xefa3749224adadc4,
+                    0x4ea745a0baef7c8c,
+                    0x43218ef259aff404,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f6d44ab6b1d871b,
+                    0xd0dccf569d192e8b,
+                    0x27af824c9db47edd,
+                    0x00ec628b7a4ec6b1,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf2b4348edd587db6,
+                    0xea6fee28e9799c08,
+                    0x9a29881f9542e0e6,
+                    0xfb8f3216a28dd9ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8962962576049031,
+                    0x486cf412f6083a05,
+                    0x8fa75ec7f7b2afee,
+                    0x892f63dd64340ec8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaaf6027cc0988735,
+                    0x2aef70cd7dba7779,
+                    0x4e82f50ef1466b53,
+                    0x33833d2d363554b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0f8a938dce65a0e,
+                    0x075ad0039e1032a1,
+                    0xee8fe3d45c0557bb,
+                    0x757fd812d6d5e7ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1443d587829b1c66,
+                    0xb175cf44d5f9425c,
+                    0x0936e4e8e6c6b941,
+                    0xf06fbcba47303f59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b55bae9b8f73e19,
+                    0xf86a35f4a1932580,
+                    0x25b3ea8bd4a021bd,
+                    0x79af506f025c4371,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3dd48ae1e3909f0c,
+                    0x49bd4e541850f6a5,
+                    0xe5087c934022b0a0,
+                    0xee0458d920c985fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf655880e1e0034f2,
+                    0x869818857bf4c3ac,
+                    0x3404b0d58f2a25c8,
+                    0x2bcc920e59ade7af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49e8dda788bdfcdc,
+                    0x292c4fa9a27a1ccb,
+                    0xa22898bf558b0032,
+                    0xb6f57fa94cb0c399,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1045ad742580776,
+                    0xca0d12c0af1c61f4,
+                    0xcfc3eed39cdfbd8c,
+                    0x9e9c625f67bde904,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32c2837363ac3a76,
+                    0x1a67f62cdc764262,
+                    0x3ceb49e28323e205,
+                    0xb46a4c07b7cc78a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d3328bb926a412e,
+                    0xab1dd27962557d3f,
+                    0x3432567b7d1e8e9f,
+                    0x1e07958439c4d31d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x65271b1e1e39a54f,
+                    0xba44c8a655d4b6fe,
+                    0xdd72632a419f6796,
+                    0x28738c8f8ff30619,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e3d1702a21c26db,
+                    0x2840ece270203c4b,
+                    0xef7c7ca2d2b0f34a,
+                    0x67f0480bb892fbb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7cfb2fc9dbef7604,
+                    0x545e969a72c62758,
+                    0x1bac6418a436f90e,
+                    0xddfc1397804629b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a69be8961adf0ce,
+                    0x068cb0e516b0aa76,
+                    0x189d6748dd4ea903,
+                    0x7a060ea761542bfe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8bf0591ba70f269,
+                    0xce47875d22db46aa,
+                    0xc3fe197c58d53166,
+                    0x4d2f38e276cca79a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4596cc9835dd0e5,
+                    0x2ec522973d2d98e9,
+                    0x45888711faff2789,
+                    0xc467c0ccde70de99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf72358911b61537,
+                    0x0ef3555af8470199,
+                    0xee47a69901e2cf6f,
+                    0xa21ef2b46466466f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x770fcd673648cb34,
+                    0xc78e4ba10af0ce75,
+                    0x1ada4a0846f14647,
+                    0xccaef5f10e95a6fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x750c8fc586172e13,
+                    0x6f56f5dd9a9a6924,
+                    0xe23f4ed96e276d3e,
+                    0x016884d8f01540bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c81d9b64793b219,
+                    0x386c53df9eb4d452,
+                    0x07a3e25fee2f4f73,
+                    0x46564c18dcbb899a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27698fbe34c1b9ce,
+                    0x404e99aa9cd7a937,
+                    0xe206d48603c8e092,
+                    0x31490e5d39851c05,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d59f51961e33a28,
+                    0x367177f2da4a748f,
+                    0xc84bf42850212d2b,
+                    0xa3bf2e24e697b6bb,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb455ee704724231f,
+                    0x52de0dca1b6da749,
+                    0x8e2e183289c1fb1d,
+                    0x145bd0cf6380bae0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa620253328137d4e,
+                    0x8df2c37c75c7486a,
+                    0x20dc458db7fba3cb,
+                    0x61f6b86d3a3c12f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e65505a67f6fbca,
+                    0xddc7b2a751a30cc0,
+                    0x46ed958268383957,
+                    0x219551dd8362498e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9550bc400ca70ff,
+                    0x9de521a5b2cccba9,
+                    0x92990b355ee705a8,
+                    0x90faebedea7e9cdf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8320fcfd77c52f50,
+                    0x5440ead205423be7,
+                    0xe515b6e4f45df0a5,
+                    0xce6d860629f9b54b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2ab8715b15cf5ad,
+                    0x112fc6fb38307c39,
+                    0x3f6a545d0f6e98bf,
+                    0x3beef9a1bd250ac2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x46ba2b8bc9941426,
+                    0x42924477c5735366,
+                    0x5ad9d4c64cb49d96,
+                    0x3a506500f189c480,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a77df624bfe5b37,
+                    0x92800cfac22f5413,
+                    0x07408b3dc137fc3e,
+                    0x66f10549c1fcb613,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83366ce073650a0f,
+                    0x22a0cb78fe1db91c,
+                    0xf5c3868c3c900c72,
+                    0x7769729680598ac9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf06857fd8cb04a8,
+                    0xcf65ff0c842c94a7,
+                    0x58a2853687cb0753,
+                    0xf6636ea04e9a5426,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x984585e5482bc0bc,
+                    0xbda7e17c358478f5,
+                    0x2d122d813f8a640c,
+                    0xe4ad0169f0ad0cf1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc28e097487174a0b,
+                    0x5b3db3794d347b2c,
+                    0x8cc95d4d33688200,
+                    0xaa37916e64fff7ce,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xcd84607e5feec041,
+                    0xecb695fd065bf2c4,
+                    0xba52ed82ea46172e,
+                    0x66d346432e687978,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42ecbdf13638b087,
+                    0xc06637653b71b287,
+                    0x04565947b9712b0c,
+                    0xd26d0e260ac7e1cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fec2f5dec4c8075,
+                    0xbe1d7d5539953edd,
+                    0xdf447f736d591ac6,
+                    0x49ac582f644aea47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7485eb4d681072bb,
+                    0x6b370061d6edd061,
+                    0x0f45742ab5dd2c34,
+                    0xfd22d530a7b50a63,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59cbd04446b43f3c,
+                    0x7bc5d3cde3c08d4b,
+                    0x5d68ee7ddd224ea7,
+                    0x2b678e69441846cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd180c26b9fe1672a,
+                    0x74823f0f957a55aa,
+                    0x8693cf9684b9c9cc,
+                    0xb985702077f00e42,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2ac0a6e952b61446,
+                    0x19fa56608eccde32,
+                    0x1e16ee8131733ca6,
+                    0x6d1ed93a0970a347,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6a4af2edfe7090b,
+                    0xc44bcf0b970da57d,
+                    0x78ed0a337b5f4cd6,
+                    0x55de3acd835949cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb418c41e0e6783c7,
+                    0x8c72d0d1d6513459,
+                    0xee315eaed1233a4b,
+                    0x97ba5f6c1fcd1421,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96230b875e83b419,
+                    0x2d659a325f5c426b,
+                    0xee77650a3ede5491,
+                    0x80526ffe6eccf065,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d4be57b495cb019,
+                    0x0fc352fe76a1430a,
+                    0xf681b3f40533f079,
+                    0xc0e81b8abd8b524b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd70cdd30627608b1,
+                    0x8d2ec9a52b5fb032,
+                    0xcc7fe8c2f2551dd8,
+                    0xdc178c43b5a4118a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1abab43d7de7a963,
+                    0xb3be88b7f7223d32,
+                    0x264d50266ff4ba3d,
+                    0xb89828876be18234,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x749ccf1d0a40a07e,
+                    0x3fa12188866d4ad8,
+                    0x071ffecc71ca9a2d,
+                    0xa5278fa73d117275,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x023bfefacd499689,
+                    0x358fe9b0abf5d68c,
+                    0x7a67d6522c1a91b7,
+                    0xb99bbf8bf03e241a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad96ed62c2dd37fa,
+                    0xe58d383d3956e577,
+                    0xe7f45a1f07c971ac,
+                    0x43d09dfa8fab1806,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12b5e9c4f847f57d,
+                    0x0ba0e40d650bee7e,
+                    0x40c3d0dcd7f75c62,
+                    0x5994d85a992fb464,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ee31c7961380ca6,
+                    0x3b8eab2b403a2687,
+                    0x8281f2f4a26b66d6,
+                    0x3d40208ee0403970,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x41f9a6eae7620ab2,
+                    0x4e969ade4da9aa6d,
+                    0x44a90920817eae38,
+                    0xba5f2399bacf4829,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75cf6e44cf006c4d,
+                    0x6266e36c01ce0ad7,
+                    0x1ee00c26f3cec487,
+                    0xa65d3f056755ab17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e2293e0fcf03679,
+                    0x947a5429552c55a6,
+                    0x75a1128751b6bb5d,
+                    0x50823385ab23faac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71e9f0e698da3ebc,
+                    0x4f05ff0d042906b0,
+                    0x76c9e158a1061062,
+                    0xf16b002b43bc73bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1efb13d03f4fc7b,
+                    0x675f37bb634ac03f,
+                    0xe134614469c99f2f,
+                    0xabd6fcac95adb39b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21bce64f768700df,
+                    0x0844c8238d2ea1d0,
+                    0x1bb26a85a1592334,
+                    0x01e4edc66038376e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6419404ea2da2681,
+                    0x4701034bae37f2f0,
+                    0x0d0e5acb23cc4513,
+                    0xd930a35799b55ffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xead73ff8984122f0,
+                    0x987907a2caa32859,
+                    0x9a2e2098b4ac43c9,
+                    0xc11b69bdcf6f2bf5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37477d673fe04de4,
+                    0x88b0013356f05617,
+                    0xd2b8d930330ffd56,
+                    0x9d3f7664839767b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11fc54e84f1a4e5d,
+                    0xb6dd3ca60e19cd25,
+                    0x1f8153d1a19202fb,
+                    0x86149ed96e91d12d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x748db5a513efba6c,
+                    0x493f2610034e6db5,
+                    0xc302067226aea9fb,
+                    0x736637d1a385abaa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10618819ec771423,
+                    0xdad689b911ae021b,
+                    0xe8c8fa7a6312c0a0,
+                    0xc0cb80290702ee9f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x44c787d698fee0b3,
+                    0x18a542a0e97c2a67,
+                    0x10bbfb9c26c2cadd,
+                    0x547054c2bcd5efe2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79111be499611e0a,
+                    0xca62598de35bddb0,
+                    0xe081d144dc6b0bb2,
+                    0x7ef09ee91d7c435b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab4b9c1f625d1f7b,
+                    0x231d2fdc78516ad4,
+                    0x6b11b8da79230304,
+                    0x581bff38fa5d04b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e47edf938dfa1b9,
+                    0x62504f3b011ffe22,
+                    0x28939c6a4b9dc2a0,
+                    0x9b8012dac9a56f25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f865fb630891461,
+                    0xaccaeacf8b6761cf,
+                    0xb37b612b82da1ae5,
+                    0xa3453bc25d2b87ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c9683ddf0f111e6,
+                    0xba2f8ab3b23d618e,
+                    0x9a796a66f0f8dafd,
+                    0x62cde5adfdb15460,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf3555f5cc8b2a832,
+                    0x9b4ad479b1a5db9a,
+                    0x96feed02e2082e21,
+                    0x06f8435e9a769a38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe58169797b082f47,
+                    0xe29e678f13e4c94d,
+                    0x1a1da2f32105fbf6,
+                    0x506162fab435e74b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a7d55e0071b085d,
+                    0x707d96ab0a19f248,
+                    0x74db805344c8a39d,
+                    0x81c17570355d0c38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x248d417eddca4956,
+                    0x979620dadf76695a,
+                    0x1a792460c7b5d91d,
+                    0x6c3fcaa3120c71a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ca130e6613f0743,
+                    0x387c671c44b2b023,
+                    0x7dcaf33ffbd58082,
+                    0xc557847752d1cc56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdeb41254618be558,
+                    0x7158fda69575de4c,
+                    0x547b0651dd682f96,
+                    0x8ed54d73fca36e21,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x335ac0cef81c2303,
+                    0x6aaab3fd20feca33,
+                    0x3faea7a3bb2d5b43,
+                    0xb4f2955c6afd8254,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x704b0861f009ae36,
+                    0x2c0a0e5a4286cb03,
+                    0x4c0a4b54d8bb2deb,
+                    0x53819e79bc9e81e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6f5a5dda14c0960,
+                    0x43ad06f0bae285f0,
+                    0x3f8f4a38922f2fa2,
+                    0x56865448a3330656,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef59864d481df6de,
+                    0x117dc981459ec813,
+                    0xbbceaa85bc376ab8,
+                    0x3e109779712a1549,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb790ee448f2218d1,
+                    0x76de03aae7d2e844,
+                    0x0200ca3b345b0c14,
+                    0xde71672469b07a6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x436afc24a60c0ea4,
+                    0x6df93e9f865b928a,
+                    0x49d3e24c2e343f42,
+                    0x387cfc0a206a7afd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0c1975da0e918e17,
+                    0xa142bdcb849315df,
+                    0x0f1ea0349c680197,
+                    0x52f222d96331c04f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5764561e63d8a5c2,
+                    0x099bc5e0c12cd94b,
+                    0xf48e7ad046da782c,
+                    0xf4556fbe369ae348,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe74ed8c1e1f7bc5e,
+                    0xaeecc5dc9422b214,
+                    0x88c4da842529030c,
+                    0xba5cf089440b1cc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ff87cd8866bc800,
+                    0xc4f7315c8c011162,
+                    0x5d32897a5cef3221,
+                    0x6d6ccd7ae6528e86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6de9e6a46fc18aa8,
+                    0x18448c40a445d84b,
+                    0x43574f1323c9f0bd,
+                    0x7de5477ae8a211de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xccfc8db279753e9a,
+                    0x4645a2daa8ff1be0,
+                    0xfbdd4ced8de58160,
+                    0xdf668a11fbbd4e30,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x36a10344b4dfbc75,
+                    0x5233d77960132589,
+                    0xe4b80f8620dc735c,
+                    0x6609ed047557ae99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab2146f8e81bd4f0,
+                    0x9e4936b2856c44fe,
+                    0xf8306893b1fd253d,
+                    0x9fab1329a3d0427a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0f3e6a49ed33e8d,
+                    0x580cae434a256dd1,
+                    0x48e57416893f46be,
+                    0x8748c03c863755a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81c223bc73d33f90,
+                    0x9ce4fb3a61630dc1,
+                    0xd535e88fdb9bca21,
+                    0xd7ec6b9919866c73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c6b08d0f7d9da5d,
+                    0x95bb8033d8f15bb2,
+                    0xcb5e468a626ce5db,
+                    0x5e874eb4659a5ab8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53a60dae302527b1,
+                    0x73e1310a538db36d,
+                    0x07662da8b1d26782,
+                    0xeea045ad60e59369,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf74de53667998724,
+                    0x3cc08cc087a5cfcc,
+                    0x257396a4248e7585,
+                    0xdad69704eef0c4a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5de97354a9d02f1,
+                    0xdcacbcc8ce641d72,
+                    0xea880f0f3ae8837f,
+                    0xeb45a26131aa5a4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2d909a91f9e00b3,
+                    0x113f08c0311c4151,
+                    0x35896e7dd96e3f0d,
+                    0xe73f345d5c4ece12,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x038ac9a5cc66d510,
+                    0xd80213def5f6b83c,
+                    0xf5d6e633703fb71e,
+                    0xd7143853feeac1f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x567d695a3fb8e567,
+                    0xe8c6ba3fe2dbbc21,
+                    0xd4b4b934446df5b3,
+                    0x5f245356b78573e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9158e2c57654b18f,
+                    0xbfe683550bd9cb4d,
+                    0x5d9ab429bf0e844c,
+                    0x88c68b05aafdac34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33ccd67a6da503fa,
+                    0xaf31d4e79a764c41,
+                    0xe0c65b7bdbcd47b4,
+                    0x23ee3a7b9d539db6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedd8ffca2fd883fb,
+                    0x12355c0e5d2165d9,
+                    0x701f979e75f1a0ae,
+                    0x396d5b593f8588b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c70300b6a014e6a,
+                    0x96ae84aaf267cb39,
+                    0xac59b75661872ebf,
+                    0x6c6c9d1d460a368d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8eb783c59faded31,
+                    0x48a4848192219bcd,
+                    0x8daed9ddc806c5a4,
+                    0xf4dde97ab13e7b6e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98b514b58a612dcc,
+                    0x3ecfe721d0f5aacd,
+                    0x79da0b9064333b6e,
+                    0x240dc531a3782663,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfaae22389785f4cf,
+                    0x6c8ba342aa39d7fa,
+                    0x530b7312b602adad,
+                    0xa5a979f0ba05a84c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x91e40e841f7700c1,
+                    0x758b3973b29ebcfe,
+                    0x97d3868962dc62f5,
+                    0x30541a7af7f36fe6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60c89a92d6cb8d54,
+                    0x7e9854bb5abd8679,
+                    0x8c5f009aac6cdd0a,
+                    0x28e66d1b383f4e12,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23252c540a487b90,
+                    0x20338e67fcc2b540,
+                    0xe731a729a606e44b,
+                    0xcc8bb7d3cf4af373,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc687f6f8040844ac,
+                    0x8854f660d44ae19c,
+                    0x489ccfd31653f0ee,
+                    0x3f2fd4e3e0acf5ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c59d5c88a6c5aad,
+                    0xd768de04185e1ec2,
+                    0xcb2fcb6843a01a5d,
+                    0x931939ebd9fa5fa3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2af50556b93266e6,
+                    0xa47db452b0a514c5,
+                    0x5ff039ccebf6c0e3,
+                    0xe165e345d9ff9caa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93dd13c49484a87b,
+                    0x3e9590a0c596bd79,
+                    0xbb41f22d700258f6,
+                    0xf2ad90a3246d0bd8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76fe41f720dd758e,
+                    0xebd733070defb3f2,
+                    0x80742d2b51d2f52c,
+                    0x46aa5f5b60773176,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60f0e6c75e0b7edc,
+                    0x048296077e292b86,
+                    0x1dd745e925fae7fd,
+                    0xea5478fe010937ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5608449466c03191,
+                    0x95d8953a49918841,
+                    0x7a6ebde6ecf13c22,
+                    0x4532c7f1959093a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x927ed36edbcf5151,
+                    0x3d02884972307daa,
+                    0x89c00a4544755764,
+                    0xa4cf9b262c14aab8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfb34a5c1b897d0e,
+                    0xf27fb6ac5aae953f,
+                    0x876bf9f81ead8626,
+                    0xdc7c5e2d5b5a6be8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa7344e4ecec540d7,
+                    0xb05da3b4961231a3,
+                    0x3d2cdbe97e751485,
+                    0x8f00060d575dbf47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59f886d218c4ee49,
+                    0x83fc30dc8638299a,
+                    0x9ad4a01dc8cabd0f,
+                    0x9b9eb71ea786cfed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04224c18b6422aea,
+                    0x57e84c0b4dbfe6f4,
+                    0xfac37931f1ad9cf0,
+                    0x71cb0f7502fc796a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x700e106a37eca164,
+                    0xbf02449274267e18,
+                    0x3c01c54badd66cab,
+                    0x5185ae17c9f5bcf5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5523dae32dd40cb7,
+                    0xec7f5064036d9387,
+                    0xec9622e49e596ef2,
+                    0x46ee427d0a79bba7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf763d167ea65c2d8,
+                    0xab85abcb3eb50bfa,
+                    0x05fd516838a353f9,
+                    0x78b92fcb7c4b0ef5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42d4b118baac00e9,
+                    0x6b21ad75c7d95fd5,
+                    0x605c13ba19a27bff,
+                    0x9e62883fca50a870,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62168a22c7c5fe69,
+                    0xcd0e3dcfd72c5469,
+                    0x11872cbd48e6673b,
+                    0xb77e0f7cbb897ce8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0fc214df1dd1522f,
+                    0x2879cab456ba31d0,
+                    0x2514b70126aa2359,
+                    0xc0b8dd12ad76c358,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7eff7719e357e651,
+                    0x44c06bb95816085f,
+                    0x73f8ef4f6dab9a8f,
+                    0x4829df5694ab2a8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc72127f1e348cf29,
+                    0x22a8e8a81fa6bac1,
+                    0xaf75f5150831f6e8,
+                    0x720850c16f2266a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecf2c69474e2d060,
+                    0x31d36f02f1f68331,
+                    0x4795aa5852a15fec,
+                    0x6e14acd020b9152d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x0845e26fa2c82a79,
+                    0x920aea1844607ab3,
+                    0x0954667d372c7c7c,
+                    0x8d2c318d195cf1bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0df4c7b0e2cb1d25,
+                    0x30583d0420de3b8f,
+                    0xfe56405d95432467,
+                    0xab5c19fc159b5eff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6dc8700e44a427c,
+                    0xc423d5a2fa0c1324,
+                    0xc2028d9946b79e11,
+                    0xed7e6c30565991fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e084d1b6f6d1267,
+                    0x3dcfc8b8cf605559,
+                    0x2f712bdd2c3428b9,
+                    0x5352bc04b00c0947,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29780b14e5760e24,
+                    0x9a576d2ccd6a0b09,
+                    0x54641db7a8730ddd,
+                    0xf6806a43c485da56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a68bea2e3477148,
+                    0xdb639c5cd4de8bbc,
+                    0x6ccd8aa0067cb0b4,
+                    0xe2b1d2ca314fae02,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b0e4558cf98b010,
+                    0xfd163dfecb74360f,
+                    0x377b8b04c5d170be,
+                    0x6593484d1f589fa6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb7fad0451c491c19,
+                    0x261a89c0fe3a8eef,
+                    0xf00a061aafb9c0d2,
+                    0x8acdaba9924c17ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24b3f61a08c81861,
+                    0x3002c4964d2f357c,
+                    0x714b72f03b39310c,
+                    0xbdab6de39b934aea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97ed5b3f7001a5ed,
+                    0x3eb2371aca279d1d,
+                    0xf3b8ddd58cc0538b,
+                    0xe754c5c7a3ec1a0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5349aed96250b8f3,
+                    0xac8272b293bcb844,
+                    0xfda709fa55877db8,
+                    0x113301239b40e445,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ee18380f0c4ed3c,
+                    0xe41787032906d808,
+                    0x37f43850e2b62bec,
+                    0x3b6715723c31a1eb,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xfd1fe35ac7ddb80a,
+                    0x8750d14f61773d65,
+                    0x7d7f3a76ea722a93,
+                    0x667c2cc433bddfb8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3cc3e769f03b640,
+                    0xfa46ee5ce6feb30c,
+                    0x885703ec97a22ce7,
+                    0x5533318bc8c052eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeedb8ee50d534b9f,
+                    0xb6ca8555e7004536,
+                    0x0ca7a08e8b93b326,
+                    0xe81cbb26c813a055,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1608eb899226f12a,
+                    0x2465bdc3fc7ac78e,
+                    0x29b7513c760ed360,
+                    0x5f89e090081c6b88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ac16622a3d02034,
+                    0x205bcefa8aee5d15,
+                    0x46b250528aaa5945,
+                    0x8059ebce76a35e75,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e58c8cc5437ac9a,
+                    0x8d1f83372fcef297,
+                    0x97c127444908230a,
+                    0x32ab2a7e36776db0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x594666df59a2cce3,
+                    0x84251d2b0f0cf950,
+                    0xf437fb3aa64f435d,
+                    0x9fb4b20344880a2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4afad1e85b484377,
+                    0x53f63ee39133c262,
+                    0x279cc523508c1a2e,
+                    0x937e92c21527258f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab20c397d1f282dd,
+                    0x77facd5c351ca7d2,
+                    0x39d06a974b81e2a2,
+                    0xb472aaed917ac901,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd616231b0746704,
+                    0xb511b1b1a331549c,
+                    0xb29d3b3d697c284d,
+                    0x5ede92627a14bdf6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xace2b72c375a6399,
+                    0x195e9a50d07658b5,
+                    0x4c4deea53df64a7d,
+                    0xa925acee9fcdbfd9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26030780e9d5698c,
+                    0x17090babc7623838,
+                    0xc3194620b20e0655,
+                    0x8da59f917efc8f5f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x03bbf1014eafc685,
+                    0xc68f6d33025cb52b,
+                    0xa811a433613f2df9,
+                    0x6490c6ff2da0c598,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd02595b03795c6cf,
+                    0xc9eaa96330f3308e,
+                    0x5eec2e1a92e30531,
+                    0xdd77a340274e9dd9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05ca6964aaea711a,
+                    0xf9e1ec1fb3c91c5a,
+                    0xbc11b4ffc1fdca9d,
+                    0x5e58832d992b1348,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c308c84fcdd7efc,
+                    0x4e8c99da0585f77d,
+                    0x03bfc6c2d1ee6aa9,
+                    0xf308840875fa8890,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81d09d3070c4eee0,
+                    0x8951f8bbcca763b4,
+                    0x337e76171db0adbb,
+                    0xd67f2ac23a2ed665,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30b6ef1928b74d93,
+                    0xe6595717de0b5770,
+                    0x18542c77fdef7e02,
+                    0x7c6386ea0092eb7f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40545f1e5878675f,
+                    0x0abeda7b35964b94,
+                    0x5ad40a6b4ff43c2a,
+                    0xda5f028e972fab8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb31af70f9000efd9,
+                    0xc1c2eae8b47bdbef,
+                    0x877390c17e0f5171,
+                    0xabad16c25ab50526,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97ad264b261335b1,
+                    0xebbea8825acd377b,
+                    0x0fd50bd087de62b9,
+                    0xab7e641aa9b858ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc74757ca724b8183,
+                    0x4c54ce2ac15dbeca,
+                    0x75f62d6cacf8a5d4,
+                    0x85b378964c415cba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x701ae9f44ca1a86f,
+                    0x889b876949939b96,
+                    0xfae7828dab7dbe20,
+                    0xec14bae09726f0ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84adbd708aa18294,
+                    0x6e35b8ffede25881,
+                    0x1f69711b7e35c699,
+                    0xadc7cf0090c51028,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x873c602092ef7ff2,
+                    0x2699e72023b1beed,
+                    0xf7005b7434c1809d,
+                    0xdbe72cdbd6cbaa8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x094eae909dbe07ea,
+                    0x2ac1bd094948981c,
+                    0x9a2f9d08f4082110,
+                    0x891553772a6cfa72,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9c52f1f5da4406e5,
+                    0xfb819d4a2c76b55a,
+                    0x02ca012407ca676b,
+                    0xc5c15430e6c909b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84023959d0aa8494,
+                    0xc43155fc7280f7e0,
+                    0x33e45800ac92bf41,
+                    0xb248370fa7ca4bbc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6fda3e707342f428,
+                    0x3a40798f58db6200,
+                    0xe178f7557406a858,
+                    0xcbc8c06df54f5ab2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81447a6c5e1fa3dd,
+                    0xa60e280a50b5b69b,
+                    0x71964be01bff72b1,
+                    0x26384e30acfcc3ec,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa2398d7c1810fc65,
+                    0x8fc149dc56d82017,
+                    0x9b7e7cec3cff08c6,
+                    0xb6b853cbfba0f671,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49a3e6ee0e2b6aa7,
+                    0xff02cdb5e3dd131c,
+                    0xb15c5805362df81e,
+                    0x99ad2cf70b4a5640,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x42467cf0773cd9ac,
+                    0x2dd2a35ba3833429,
+                    0x3e8c3ec4f68e6e7c,
+                    0xb1311bb669d4dd2d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81b60530a71b72c1,
+                    0xc8cb535abbc1da97,
+                    0x5088e9815bd51785,
+                    0x35e3204e4dd0a641,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb9fe1bfee959461a,
+                    0x607d92b5d838b282,
+                    0x51ee3de5104a960e,
+                    0x4664da2d2b855b2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0f4a94c468f9803,
+                    0x40ef8220399ba21a,
+                    0x2b1b22996bc39c38,
+                    0xa75b80fc19cdf8da,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0d024c8f69f93c02,
+                    0x4e822845078889be,
+                    0x721fa9c8e202bf6c,
+                    0xd64efd7e0c9cbb97,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96e2ee0f77ba46b2,
+                    0x84a14af672da2fa8,
+                    0xa1413b435e4fe37b,
+                    0xc09ac811c0ec8a7e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb760e20afe420055,
+                    0xe1a8ba9b07a86733,
+                    0xe3fe91ddac8f969a,
+                    0xa08dfc9e1b3b0aa9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xadf89757d0725c56,
+                    0x50f316db323966a5,
+                    0x052ec631633c6097,
+                    0x6bf9883237ab5f1a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb34020d5ac5c8956,
+                    0xb1bb680b7ff68c95,
+                    0x0e7b452be4b88e69,
+                    0x7ac3039ff6f8860f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8c019d6af644477,
+                    0x3c2d9acc344d1035,
+                    0x905e59c1487041b7,
+                    0xf6f379acb30a9532,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0c9f9d2bec80d46d,
+                    0x2bd819cfd71717ce,
+                    0x86f94cb1b905af24,
+                    0x6ea1ffd432e4efc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe885a148a38531d6,
+                    0xd413366136229cb8,
+                    0x2ce68619c7293792,
+                    0xa648538097bdff69,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 310, 311, 935] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 310];
+        let mut opad = vec![0x5cu8; 310];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
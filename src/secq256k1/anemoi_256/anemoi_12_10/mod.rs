@@ -0,0 +1,2906 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 10 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 10;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 13 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 13;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x92ade98dd5dd06dc,
+                    0xd114659e6c2886a5,
+                    0x17d2987dc0ac7128,
+                    0x9fbae4b12887cca2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea400c0354855b8b,
+                    0x0d4a0ed21ea10f5c,
+                    0x59bb7dd755962e3a,
+                    0xbeca19af9a133279,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a78aae6765dbe74,
+                    0x02d4a89c7bef3dde,
+                    0x84654db3d2f6a376,
+                    0xd60107eaff1569e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x197d161b1fb74f9a,
+                    0x57155a7bbbef0b4d,
+                    0x3a5b65642116af2f,
+                    0x84a21adc7834a1a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9164e54ec8c65c8,
+                    0xb4175e1aef236598,
+                    0xe2ae629ba47efb1f,
+                    0x8ee90e55b15b3f31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3cf325af45d27f0e,
+                    0xb4802500addb270e,
+                    0xafc5ef1729613dde,
+                    0x0d07b41fbb37bf3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29f487ff6485eba1,
+                    0xdee54f7e39bef684,
+                    0xd416f01269222732,
+                    0xcba3844b3888425d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x634f4021a2f6dd84,
+                    0x90e763e9aa86d526,
+                    0xf9c8db767d5dd9cc,
+                    0x910be25a072fbe1c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f131276a762ab09,
+                    0xacabf160169ace2d,
+                    0xf788e268039c5aaa,
+                    0xbddd80a2ee6646ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82088b0a0c79df54,
+                    0x4f1f71a15fbca3f7,
+                    0xed8482c907caccf3,
+                    0x8a61f00e81087ae0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d3d420ed4f1aacb,
+                    0xebdd56777972cb56,
+                    0xaee9f1a522d5f214,
+                    0x850537d862a15ff7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3338e0616e62c6d5,
+                    0xb8bd96524be328de,
+                    0x9667938bb4186cb6,
+                    0x050aa7faca319d6d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x78ba6dc7b0dee15f,
+                    0x0037fc505ebc79e5,
+                    0x7e5299e0c5e6dbef,
+                    0x760f6ddc6dc5d522,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a8469c2c81b20a0,
+                    0x4364d8b72fcdc216,
+                    0x7210bc48fb8e4a0b,
+                    0x5fe6a8e238635026,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeff483f8beccdcc6,
+                    0x49b8f8e3a9c02e2d,
+                    0x684e067338ca2227,
+                    0xe51578457988d540,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3cf1d7badbfe2077,
+                    0x3fdd1debfe2097af,
+                    0x41bc35bc6e3d5284,
+                    0xd604eee0dc7fe02e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a27214842528a9f,
+                    0xd1b5071d05a0dbdf,
+                    0x965dabcb3b92f15c,
+                    0x8ef424612648d239,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x832ada23e7a812b6,
+                    0x23d3e2340807aa39,
+                    0x83313b6f0d7f51c2,
+                    0x076bfad6de3bac09,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb828c86ea343f13,
+                    0x1070a801ffebdfda,
+                    0x76f40cc790c3034e,
+                    0xa1d1c233512b6c6c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4a8b75352e336d6,
+                    0x614f5c593d1f894b,
+                    0xb6ce52da39aba43a,
+                    0xb5ed8ab942700304,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c34d0a1da0684c0,
+                    0x9e60b2b126184472,
+                    0xad7dccec2b64f105,
+                    0x7401ff542ed72f11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe962bc2eb8881f56,
+                    0x88cef29ac53a465e,
+                    0x5158ff083677f243,
+                    0x9eb8818350abdb4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ac492a9f19dd65e,
+                    0x19f91f57b9c194e2,
+                    0x2713cf99d4807f9a,
+                    0x36561048eda8f94d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cdb231c05d88c7a,
+                    0x80d52846b673687c,
+                    0x55205758af8b8381,
+                    0xde5f6cf57e4d6a0b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfab2ea8f7f168f94,
+                    0x03152eb242c24b6a,
+                    0x33a845ca6f899c6b,
+                    0x0b8253570b5eb763,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfacc6a8b252826eb,
+                    0x4a83f61ad4535d4e,
+                    0xd1c9173349652b7b,
+                    0xb9ff85af252a26ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fda4afcfcef6168,
+                    0xfb145af8b267d32c,
+                    0x9a7e89b4a403c7ab,
+                    0x23c0d430fda9ff5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32d3e525c20c857f,
+                    0x46145b79c4dbc245,
+                    0x6529cc20fdc73750,
+                    0x1eb4174768f6d52d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d27c078455caa5c,
+                    0xb77fab43753af25e,
+                    0xbc290f3962c0559b,
+                    0x63f79a7016b6c244,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4cac113b57d749d,
+                    0x5209dbd69549a781,
+                    0xe5b142c0c3caab8e,
+                    0x5531ca5fb647dd9e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf4445d19a93dc98,
+                    0x5af819cdad79b86f,
+                    0x9a113f39b072d1cd,
+                    0x15deedfb1d5f8c4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7e77cd6d1b771b8,
+                    0x5d2df4fc849fdc38,
+                    0x9ad0ab632004284e,
+                    0x0859b2679f1cb5ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd32d4225d8ad5fcb,
+                    0xed0fb4cc6e81e05b,
+                    0xe52859e39fa1e5db,
+                    0x4819baaafff6095f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e1bcd8f29d8fb77,
+                    0x34efe13cffc314c1,
+                    0xaa142ef1598b81a9,
+                    0x2d5aa9f4623acdef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd8a93e405663344,
+                    0xa574c3a750a72ebd,
+                    0x2cd14df9afce405e,
+                    0x9af1841d8b84ea7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26b57ecba7ed5ff1,
+                    0xbacd6a6af082b6c7,
+                    0xef1bebcbd6f2117e,
+                    0xcf48673a3c7b50cb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2ece61e7be5b7c42,
+                    0x8152b66f7dd5da20,
+                    0x7a2cb48b9a65d633,
+                    0x468312c12d5a3df7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07ae4589fc388fc5,
+                    0x93bfba7feb284cb7,
+                    0x1cf90dba65d8dfbf,
+                    0x830a58e614bf7af3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9f19f1ece6178fb,
+                    0xf4db69f6224d2e02,
+                    0x8f00398cee702adf,
+                    0xf1fef62559eaf7ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e656cb0e69346ea,
+                    0x28b0790ea84e9271,
+                    0x964b2e408577aab8,
+                    0x153531eb7e1c8487,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0b5a251783b8ab4,
+                    0x63ee0253477cc6cb,
+                    0x35db531a6cef9906,
+                    0xc97ea38982751908,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad90ee87ff2f4a1e,
+                    0x241d7864b84a0053,
+                    0x5de11092e276ffcc,
+                    0x9a4a00d5ae4c383c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94cbe80af1c11c8c,
+                    0xf5a58b5d59e3b4e9,
+                    0x32078226894ff198,
+                    0xbe42fbafcb426918,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e7452985cacbfd4,
+                    0x2104b61106895c96,
+                    0x0c52b8f4479cb134,
+                    0x460703af1ffe41e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf52f867d0c376d54,
+                    0x1b2fd30e878dcf5c,
+                    0x48e5c48da8f6dfa2,
+                    0xc27c91f2e61da947,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4997d9eefa5d8d7c,
+                    0x2446f9a0ec80b375,
+                    0x73988248d37a5ee6,
+                    0x4012f9fc244d846b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1cad05474d6f4541,
+                    0xec9e79f6bcffb888,
+                    0x9dc93b8000675600,
+                    0x68c527239596b997,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ad2926e5e067e1d,
+                    0xabe4c6f3eb038cd8,
+                    0xad09cff9bae69862,
+                    0x873b548801362cd2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3142854fd256fdde,
+                    0xaa3cc535f6c51d06,
+                    0xa3a81f200d150dc2,
+                    0x71e779a1e786145f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebee1e5c66d7e286,
+                    0xadac0681c02cc586,
+                    0xdde4344b09e87ac9,
+                    0x346449a612dcc358,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1907c5a1c5b6230b,
+                    0x815914c9ca1825b4,
+                    0x99450d71beda39dd,
+                    0xde300609483714d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e100a9626c67bda,
+                    0x41bc387ff703993e,
+                    0xa791caf1c630ea18,
+                    0xfe1356b0cfb5e8ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabd2941a2e982dcc,
+                    0xb4eaef98ab132f13,
+                    0x5f1704c340780f09,
+                    0x0614cd7794c9d9cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18c8e1aff8cd58c2,
+                    0xe84968193642cc6b,
+                    0xc2903c1032dd9e88,
+                    0x7fd2b507cc7d45ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3b6bd3b46d75592,
+                    0x64203e6198ab95f6,
+                    0x0f5f950fdf6c4bbe,
+                    0x976e378b86cba7fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b4bc0c45b933ba1,
+                    0xf0cb2e8acae4cf20,
+                    0xf91370e27e03fe28,
+                    0xe8848cb2f1455717,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9107374f368c07ef,
+                    0x7dbf7eb6c92e7499,
+                    0x1ddfd16270372a54,
+                    0x5efed07b21af0163,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbde29d68b53ca29b,
+                    0x14e67de1aeb2001b,
+                    0xe1fdfdd3b2cc2520,
+                    0xffd13044e8ffa3cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb7e82d8265fa586,
+                    0x1250c4f1aa375c5c,
+                    0x398dce2b3f8c6df4,
+                    0xc90732f1ed6921f5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x310068c23ca6c071,
+                    0xc58fed223ccffe25,
+                    0x4f0615ec59d45db9,
+                    0x3494fb2648c1c67a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x780deb9a185b5533,
+                    0xa4b9388b144f8bee,
+                    0x9786c7c9ddd674d5,
+                    0x280c6c54d327f43c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc7391d9f5919f4d,
+                    0xcd60935d135398ae,
+                    0xf8967e0bb28a772e,
+                    0x4e646584fe25db94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d60f55a792e4ced,
+                    0x28efc44c56e31246,
+                    0x4edb7cc8d3209ce4,
+                    0xa103763c52177f61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a61cc44184f9c01,
+                    0x60365f01d7b6efcc,
+                    0xcc0a281855cf5a3c,
+                    0x98bdb754f4104e0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x842169c544154b4b,
+                    0xd5e5992030a40348,
+                    0x1b7f9d8456c54e60,
+                    0x1aa5a506fb44ab9a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb999200b490f212,
+                    0x86963897d8340dda,
+                    0xbade987a135b8c44,
+                    0x4c393f12da65ae11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9aad4be23cf654b,
+                    0x97dd38d1b89c80b0,
+                    0xd69640a03bcac916,
+                    0xf9f4c43147b08fc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x07a9b964bf2004ac,
+                    0x5963ffbadc48482a,
+                    0xe8854ab440f55a33,
+                    0x8d13178365c0b802,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c93acea6e54acc6,
+                    0xed3059e8a37bf48f,
+                    0x547e122c5b1d5c75,
+                    0xe9192d1ad4cbdfe0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad3b72be2d0298bf,
+                    0xcf1637a1e906ab95,
+                    0xddb92ef234944d76,
+                    0x4559b3da8bed75a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8123e5e87a290c6f,
+                    0xfe1cddd6b422d930,
+                    0xcc675b42de974225,
+                    0xda3eab036bbacf85,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x627cab016a7894b0,
+                    0x2f86cacfa53b1c90,
+                    0x0b0d2ffde71eb14f,
+                    0x5a70aa38d87ccb5b,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6e4f35e8e6586f11,
+                    0x44176df62568b577,
+                    0x8bb4eb2198376c3d,
+                    0xe9a0a44f40d5e43f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x828806c22a7783bd,
+                    0xa279ceb23721e4d8,
+                    0x5dd679878f2e92a2,
+                    0x49a3bcdbae8d30d9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1f0c9ceed91a764,
+                    0xc9bdf20781c4bcf6,
+                    0x75e71b82769379a5,
+                    0x9732ea3ec5fd5a2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac63a8e9bb4b1b09,
+                    0x123c15bb20d69b77,
+                    0x032bebc00eee84ec,
+                    0x6c77bfef2521c88f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a5c75341e49fd90,
+                    0xf54c45fba3f9e2ab,
+                    0x5c5b4c3f3a947dc1,
+                    0x13423e6bae83ec6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0edad45aac9a2881,
+                    0x1830985a970a2f74,
+                    0xc4268d29e64ce3da,
+                    0x61322dc18f87ccb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x617bdccc2df504c0,
+                    0x2e654fe9d30675ba,
+                    0x9f8234d5b300bee8,
+                    0xae23b68be2e2b166,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6df56469889678c8,
+                    0xdcd8f49ef098858d,
+                    0xd77127d0bedd1161,
+                    0xaf7d8112d7286b97,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x276c40380482120d,
+                    0x82e914096f0e7904,
+                    0xe27626b6ea2080d6,
+                    0xa5daba7731be4d96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69d08dd8f684d0df,
+                    0xbcecdbc9dd0fc4cc,
+                    0xe858e401329bd968,
+                    0x57b65aea4e77a1cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe57bdfabf66716ba,
+                    0x62e0bd7f24c3ed12,
+                    0x2a11fe621901b137,
+                    0xffdd04923d9cd8dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e459a112e671607,
+                    0x177615bb5bbe13a2,
+                    0xb7a58d398bcdd6da,
+                    0x47a7001a7a62dec6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa34ee5eb66170d37,
+                    0x97d21f4246eebf83,
+                    0x95fa19816f7c7b46,
+                    0xe6cc8736aaf55db4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0da3002e98541618,
+                    0x3b295e5775d203f3,
+                    0x3e7834753acc8b07,
+                    0x6b3cbd895d7d01a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b036bebba5eaa68,
+                    0xf213e884cbca7b48,
+                    0x57ddc1375d43232a,
+                    0x3f839f17a440b0da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b50598cad0245c8,
+                    0x2cf8e07cca6afef2,
+                    0xf2a552e44789b930,
+                    0xfee67374c0f5a15a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xebaad87850342bd4,
+                    0x058d889ac59177a8,
+                    0xcc24f350d5832d48,
+                    0x6697ea32df662910,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7df3f0741f062177,
+                    0x7b060426cb7ee933,
+                    0x6dbcba6e9d2b07c2,
+                    0x8aabe4d74154aac8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22730e3906be35b1,
+                    0x7898c8bdc8f216d3,
+                    0x450b24261ade543e,
+                    0xf0f72e8c9b1070c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7eebc8ef56541e5b,
+                    0xa61854c9b8fd400c,
+                    0xf39b7ce81ae09aaf,
+                    0xc0b04615f0d6d4c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47c4246de2a11c68,
+                    0x988f3614d3504e8e,
+                    0xccffbf345bf999b8,
+                    0x90878ce326ce533c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd610b23f262cbd3e,
+                    0x896750d1cebb6643,
+                    0x6127503e4f64d4a5,
+                    0x507ba528d95a5c59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaee5e5d1db6abd55,
+                    0xc3c4013dda204c79,
+                    0x76b66e7aff00f33d,
+                    0xa2e9294d20b5096f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x724d0f7c78ccf435,
+                    0x595e91eb88a03c11,
+                    0x229fa31be7968166,
+                    0xe2890458cb56cabf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb1aef29c6b2a113e,
+                    0xfaa19d58655e53db,
+                    0x9f97c88abf15de9d,
+                    0xd841c323c27a6f76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef15f8fd8ad5a174,
+                    0xf579b3f903aeea19,
+                    0x5d4839990d05c1ec,
+                    0x3fa51cee1d09b3dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bfb4b9daa991a01,
+                    0x72ce22e1d8457acf,
+                    0xc857f863572ac33f,
+                    0x9716588950a27a0a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d5aa6d4d8f6f776,
+                    0x9ea5bca90c33898d,
+                    0x9ae3a980da53e9a3,
+                    0xe9fc59dcb85cbe8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2ee81b8df31103c,
+                    0x81a84586f759abcf,
+                    0x96bf0c14ad912437,
+                    0x581d3b21cc742f8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdf70c0ab0c97b5f2,
+                    0x8363135e16692280,
+                    0x39fb96e74af4666d,
+                    0x8351a3eb6ecce3ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa7a7968b1786cbb,
+                    0xba68497373fd2a73,
+                    0x15be6eb63283c4a1,
+                    0x19007270e08b41f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8df0b470e98c25a,
+                    0xaa1c72f5d7763cd1,
+                    0xc592e05758f09bca,
+                    0xc58329d7139a794f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb3964285a97acce,
+                    0x310f225a6f224334,
+                    0x35d468d71eeab97e,
+                    0x920f9bbfefd74f5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24ac1ae5728f6cb5,
+                    0x53cc0a8374d8a8ff,
+                    0x7a6e93a0ff16c020,
+                    0x5f33dfa3d1fafb0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc452855eacbc1026,
+                    0xc0019f4aa17764ba,
+                    0xe12a675c9ad24f67,
+                    0x5262a2acf1e95784,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf32a4b21c0a09d38,
+                    0x7dbececc13d30d44,
+                    0xbd3b6b67252a0cad,
+                    0x5ad74ccc62ab8750,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2d3a5a3e846335b5,
+                    0x69bd9dedc4469d96,
+                    0x9b6bd9861a2b1cb3,
+                    0x65e6a8a29900cb43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9375fabb3d4c5548,
+                    0x0fb184020b94ada3,
+                    0x6c1330a80a210b73,
+                    0xf6a5b1261a509527,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x601a7e6fc3b84cad,
+                    0xa1debbfc65c147e7,
+                    0x840b4d8e0196fdea,
+                    0xa7254608480ba0f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdf7629e4771f142,
+                    0xc20877b848e6c625,
+                    0x8eaa94c77582a944,
+                    0x1904c6e433cfa93f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x133f0701a8214b12,
+                    0xb0c0acd068e1d69b,
+                    0x666a4038b52cec4f,
+                    0x6dd3d1593f66d76e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdaa85c380e44fbdf,
+                    0xd65c2878a16e03e7,
+                    0x01919a81ebb3d246,
+                    0x43ff5f58aecdb7d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x202ba5ec2e117d90,
+                    0x32b45eb221fcb21e,
+                    0xeef0bfa5bc3a5846,
+                    0x13ab071f5ff68f95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60949d607e46895b,
+                    0x06def0cf890d19ee,
+                    0x807360d49a13f9bd,
+                    0x2e0127e46db0c700,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55d185ef9949a480,
+                    0xd0455bd7f94e92fa,
+                    0x3794a52d459e45e3,
+                    0x0c71e606235ffd77,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d43630c80716c69,
+                    0x48137ebbf3dccfa0,
+                    0x4fb658c97dd3175f,
+                    0xb0373852c39ef5dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa58bbc3d82e6a124,
+                    0xdf468339c7c25313,
+                    0x0a4a6e56939e4da9,
+                    0x19efbe5f03032355,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ab5148b714404e8,
+                    0xda2795257f290431,
+                    0xaa003609fdcd5594,
+                    0x4e6fd4429bc722d1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x300a50aee1536570,
+                    0xa67fa7203af4a7e0,
+                    0x599e65ae2982c76e,
+                    0x48542ab9219902ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65c3d122a2d3285d,
+                    0x69ba5811f1ace282,
+                    0x906b2b3f1f71c7c0,
+                    0x21b09d1584078399,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5322424a8303501,
+                    0xfbff89c7ebbd7451,
+                    0x7c4dc2a91131383d,
+                    0x6c7cfeba4d642b7f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe95ae7d178ba549c,
+                    0x2182964adb64b653,
+                    0x9cbc86fb8a610ec2,
+                    0x0a0729a395cd6c21,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3bf398ea7a6d8c41,
+                    0x40826232469f170f,
+                    0xd2f2ce2c7455eeb1,
+                    0x68c0685928af32a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1a5383a08807792,
+                    0x6882d0cfd135b72e,
+                    0xad0ed86a413e0693,
+                    0x7efcb9ac49281bee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x727e64e7bf5c4ae7,
+                    0xf6045c2e33501185,
+                    0x226a2e216ed78112,
+                    0x9c351a41ea1c7513,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c3ca5c46fb3ccc2,
+                    0xc1a84cabb66a1680,
+                    0xc8219a2704826f1f,
+                    0x2c7c2ed43f8d53da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce1e9c552e67ac2a,
+                    0xe60af502a43987cc,
+                    0xd6182a5e097f096c,
+                    0xf4663490388fbf66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x058b054567565a96,
+                    0x6cdf79847e05d446,
+                    0x875b82f41f4c9c25,
+                    0xd0d66e93ce3d4f41,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe95d751d49cf9fca,
+                    0xe69f34ebaf0858f0,
+                    0x64878800c4509734,
+                    0x683b0fb646747773,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9146e947cb95de2a,
+                    0x27038583a3017db3,
+                    0xaa430d40ba34435a,
+                    0x68f7993dc3cd646f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1b4782e40367c1de,
+                    0xe5273d4b3a182704,
+                    0xcc21fa3c08548047,
+                    0x8a9fc0e4df05e5b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x703b440400c256ad,
+                    0x81f564034719546b,
+                    0x8ea10a9808549726,
+                    0xa9a908960730e5a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7086329d4a223db2,
+                    0x125f1a2f7332775d,
+                    0xaff2ec1dbdad018e,
+                    0xd4b40f49274fc2d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9267e8ac33087028,
+                    0xefc6e97a57fe8207,
+                    0x38047c086360e93b,
+                    0xe2017ca815007941,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x309e3df46860b2a7,
+                    0x47e63620410322f3,
+                    0x1960dd90ed8d8b0b,
+                    0xd8bd040deea23125,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1d3628654f7953f,
+                    0xd63facb8b6362843,
+                    0x8f9d30bc135c962f,
+                    0x56b0dfa749d8610a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc533902125e23d01,
+                    0x813ebd7c071913e3,
+                    0x28cb8a460cb3d1f3,
+                    0x1465b7a1b7f97b36,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ed6652c1e734e64,
+                    0x4b4a0def8da700e6,
+                    0x3da19ad5c791e6ef,
+                    0x5b60dacdac475780,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x095812429cb5ec69,
+                    0x6ea605d63eeb0978,
+                    0x2df0bea13896e03e,
+                    0xbd4cb50c94be1fb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf725c5d1fd4d9782,
+                    0xc91de4f87d8eb647,
+                    0xd5b104437e8e4ac3,
+                    0x46becf50bc1d09a3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0baf7f25be88100c,
+                    0xc805aebfb1782776,
+                    0xc8bb410fd8b71ed4,
+                    0x297071c38ec82e24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22f6ea74dae25560,
+                    0xe6afc6b666b83ed1,
+                    0x3dcfbd363587751c,
+                    0x22fe108213a9c8d0,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb4822ea562fa348a,
+                    0x584f669874b19ea9,
+                    0xf4b024a3d7780d18,
+                    0xfbdac61bbf17d8ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea07679c2cfd3e82,
+                    0x3f369b43689d23d6,
+                    0x1dce339cd87eea20,
+                    0xe173b323e991c88e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30c60394c51b9047,
+                    0xe2352999c21d6e30,
+                    0xcb8d738a0adc9c57,
+                    0x1a24bc79269dfaf0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0c083dd0ef727f4,
+                    0x8d587f7045bb3b0e,
+                    0x8b7f8a1c27164bd1,
+                    0x6e3c9e0a33e813f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc198be69f8c9b5e3,
+                    0x0d9e0fb439576ffc,
+                    0xf25578b935e7d378,
+                    0xd226aaeb54fd26c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e63012efa87e21a,
+                    0xb05f6d0dfee599a3,
+                    0x8a8af8e89a1a48f0,
+                    0xba11b070ac85739b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06c512c880693547,
+                    0x3fea0ec71557e95c,
+                    0x1e55ab63e0ba9796,
+                    0x13b1d3c3aec1b9a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdffa3a972e91fde5,
+                    0x8739f106d43c71c1,
+                    0x5bf2d6ef27ed3c51,
+                    0xfe07d952df3681f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f7904661e4ee93a,
+                    0x6a79fbc37dce9532,
+                    0xf5297b9ad22e302d,
+                    0x0208506eac6f4143,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8841f9288771d3db,
+                    0xe5f3a735f0f91b01,
+                    0xa7ce3db2db53e4d5,
+                    0xf784d5f24448e614,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xccf93954b77c861d,
+                    0x465eafbf0fc947a8,
+                    0x4ee1c58fce58c195,
+                    0x966020e08eeefc60,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x056837f7e768427b,
+                    0xffe11c419a76d3fd,
+                    0x7dd9a495f708767b,
+                    0xcf9c5c33f37d6414,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd944160366aedbf8,
+                    0x802f2ac6f81096f5,
+                    0x48836fed30145ca8,
+                    0x68cd7e2093970f6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4ed251a60e1548b,
+                    0x88f838a19bc0931e,
+                    0x96a97346437ff3dd,
+                    0xddd4543eb5e4aade,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15154a3c4d5d5c6d,
+                    0xbf450f6036389096,
+                    0x00179d9e91e2433e,
+                    0xf5b53130b0fe3849,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92ec74ecd7014b80,
+                    0xab0371b194068e41,
+                    0x154b74ddf33a1c28,
+                    0x6ec8f8a0d9227bf8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8e16cdd95bf010c,
+                    0xb8670c08ee2179a1,
+                    0x1c3ff87fe404c424,
+                    0x55d54d39f3fad652,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x325a6e07574020f4,
+                    0x4f76a11600f2e375,
+                    0x2b84c68c68ffb570,
+                    0x6c5b63ed43e781f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9b99ee65d1e1773,
+                    0x5b3f1362d89c264b,
+                    0x8af45369ba6ea1e0,
+                    0xc07acaf06865215c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27a4f276150b533d,
+                    0xb9c5e3e32c234d65,
+                    0x0b3262aba19e1d73,
+                    0x48b8f566e40df423,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe78e93b10782da9,
+                    0x1d9763ccfb8836a5,
+                    0xde8157cfbeea3b0c,
+                    0x4ee32cdcbc05fd32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7522c45b2c7149fd,
+                    0x97e0045f81a3fb20,
+                    0x00a56105bbf0d6b3,
+                    0x9657d9819b4d208b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf46103c96d45f6b4,
+                    0x6b48d7bb590a1ce0,
+                    0xc740bce40d29f32a,
+                    0x3403172018c6287f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf22912d87300945,
+                    0x2ada1f03d5e5cdf3,
+                    0xff70b5884bd25f22,
+                    0xe52fb31befa6e489,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2fb9c6e5296e33a8,
+                    0xf06cec86212b0dcb,
+                    0xdbbb75f22efd2ddd,
+                    0x5b23792096de07a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd129eec613728be1,
+                    0xe09fd49f1b6541f2,
+                    0xfbc12670291dbb34,
+                    0x294daebfa7333b2d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd89dce99546c8c77,
+                    0x77fa90279fa5e490,
+                    0xa1bee4dc61fbedac,
+                    0x485b51a9936e86e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd773cd679cf6d76e,
+                    0xd958894d126158c5,
+                    0xcb9bcdbe5b501bfe,
+                    0xba059c753680e0ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa981fcce9350257f,
+                    0x9cfe21ad971c85ef,
+                    0x194de4eebaa008c4,
+                    0x59d005f1b55d8b00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc001832a683c3ff6,
+                    0xd0465ea1c47b97fa,
+                    0x7ce3e477abbe255c,
+                    0xa90bdc84316b6938,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb566aa00b2c949f,
+                    0x5d2c28bf2371c163,
+                    0xc9c23783f3258f57,
+                    0xe177db8499b562d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f1f138ca2f6d587,
+                    0x0b1b35c2a41f47ef,
+                    0x3a049fef3b804904,
+                    0xdebcaede334ac7e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9f26754236f8c1d,
+                    0xb42342f3c932c9d4,
+                    0x5a51ebec809a3328,
+                    0x21b0b435b27fc7f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d27bcafadcc2c4a,
+                    0xdf919550494718c4,
+                    0x8fdbb5b13781a1a6,
+                    0x14fd0afc2cc33043,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5fa258d13915d60f,
+                    0xe72b92f54871e0cf,
+                    0x92303adb5e935f83,
+                    0x2913ceb3a315da3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0b2d6f026074311,
+                    0xc58f21fee0ae4a98,
+                    0x87d69fdaad2522c3,
+                    0x7dd22f3d8879e981,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x51612252aeebeed6,
+                    0xc3004148431b5e11,
+                    0x772c08dc29b4237d,
+                    0xc569055fee4a92b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25f5270fdd8446d9,
+                    0xdd15dc6711cde7e5,
+                    0xe700644af3fd417b,
+                    0x2e55877b4b2b8571,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0bbc80423d4f7d5,
+                    0xb53509e9095ad072,
+                    0x1939e23d8c453229,
+                    0x9918fe8ca36efce0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x660dd1c8f833ed87,
+                    0x0528163ecf61e9d6,
+                    0x1a70abbdd2ba8715,
+                    0x572bf29fb7765b9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd65711c03590c91b,
+                    0xfbd18719be9aa20a,
+                    0xaa099ed5fa8cf48e,
+                    0xd3c8bdcdb95f063d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70d05b192a7e8a43,
+                    0x26789a4dcbda72ca,
+                    0xd3d37086e4eb18b4,
+                    0xea308753241eff1a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x197baaee5efb3e97,
+                    0x4d1f76004fac8b20,
+                    0x4bd53835929f3bdf,
+                    0xf58e9ed5fb48ba89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ae694f9d5d52742,
+                    0x85d4cac3612eb1e6,
+                    0x1ed2608d030e99d0,
+                    0x37999eefdf917a1e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48bdd06a51d977a0,
+                    0xeff0b5ffac8191d0,
+                    0xb6296a17c953ca7b,
+                    0x6f0de88b5d7cd1ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35dffe0ea80a093d,
+                    0x97d7df94c8d596da,
+                    0x85222eb9b662d031,
+                    0x44038671e367bbc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb8f56613ce01561,
+                    0x6d665753f3dd44ce,
+                    0x42dce305d1d623e3,
+                    0x87536e46722b1184,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c683df658ceaf2e,
+                    0xc110ee653e6dc563,
+                    0x0661106e80757125,
+                    0xe8c763c2c3e499dd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8dc54ae9d679333c,
+                    0xa7c6a9908a17e84e,
+                    0x7953b069942d4d95,
+                    0x70d51999c171a08e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06d719bdfa7abee0,
+                    0x310955827f5f4fe6,
+                    0xf3eb2c0b3cd85036,
+                    0xcf6c71a43e85dd5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a6884204a1e7d54,
+                    0x54fa111f2f27b166,
+                    0x54ae5a6085c55bac,
+                    0xa1705dbd8dca7662,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xafd5c257e7688d24,
+                    0x772b9ec84b3d10ed,
+                    0xa03268930f3fed3a,
+                    0xd3de97f4ac212b34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc8a2a3766001f40,
+                    0xd8963f8e8881c9ab,
+                    0xa90560e7671bae4d,
+                    0xba44c100ac2138c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x001dbdafc6bd7631,
+                    0xfef30db177512475,
+                    0x61df0a702e9f5c78,
+                    0xc2e47e315ecc92fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0480d3f377b43a8e,
+                    0xb7a4fb890794920b,
+                    0x274dffc624422428,
+                    0xbe0eee0c27c17f4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x896a199354482d86,
+                    0xec0d403097fe80f7,
+                    0x041acc3dbe35769e,
+                    0x5b31bb71d74eb4d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78faab0ac035be67,
+                    0x9159fd193f498478,
+                    0x01eeea5bea614354,
+                    0xc2ab3ff4dd763f75,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf69329ad260c97f5,
+                    0xb180ed44c00a3d87,
+                    0x16544147e967b0d2,
+                    0x5935877428763f13,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6e1d680623d2d7a,
+                    0xfd2502366a49dfc1,
+                    0xb38a74059582a9ee,
+                    0x009740f7816b78c2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x93231e4cbadc94ba,
+                    0x2f0660903a8f7652,
+                    0xdeea846ea9361da7,
+                    0x09d5de852c08979a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3b5cb551c9eebb56,
+                    0x74908ece14c6e052,
+                    0x10c4f803744968d4,
+                    0x3897148e0aebbf7a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11ea3a4d701e09f8,
+                    0xba4223966a9554d5,
+                    0x6419557cdec99645,
+                    0x39d7e3b00ec3b2fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x445e9910fed656d9,
+                    0x64f18bf0ffa7ccd9,
+                    0xb73f38d51a0bcfdc,
+                    0x270a697f3b68c49d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x95636380c968f0b9,
+                    0xa731a91209c7b94a,
+                    0x052d2bdc8257ba41,
+                    0xc32a9783f32c8595,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76326168e9800dc4,
+                    0xafcce62aa0fb5aee,
+                    0x90dc509f769afc5e,
+                    0x992d82d47362752a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x424c657d40b04bcf,
+                    0x49029a1d2f782552,
+                    0x8d90e0de0829ebe3,
+                    0xfdd61989c95585d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a6f31cb412b59b7,
+                    0x9d3a9b7df7246b23,
+                    0x2171dff184cbae4f,
+                    0x338487f39f75a280,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf40d4a8bdc7c06df,
+                    0x2495faeefd9f2131,
+                    0x31a0e90da02a1f2f,
+                    0x340e0f63b0a712c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4734fcc57a3b4f2f,
+                    0x95e5b65d419a767f,
+                    0x4a8e5c6d79e63fd5,
+                    0xf4494387dcf080dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9451da96840d4ae,
+                    0x34449e5f68f2d513,
+                    0x1141aa65cd554272,
+                    0x6b7bbc823fa9a0c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50f423177a12f194,
+                    0x550510085194b89f,
+                    0x5b175df218e00623,
+                    0x526b7be24a35257a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x819d8c11eaf08179,
+                    0xbdf85defa1d5aee6,
+                    0x7cc7c53fc0a0ac98,
+                    0x0910874472228f30,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x03909cffbbc2e6ec,
+                    0x6a56bdfa4e537b55,
+                    0x0000000000000019,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb44f010613bb8eb0,
+                    0x19a7fe731b434042,
+                    0x9e120c61170ad292,
+                    0x5bdaa010a03659ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc91a83fb057bf120,
+                    0x9368195352ae57d0,
+                    0xc607e0c23f917325,
+                    0x18620c42d906f223,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x456e3582a1fdcbfa,
+                    0xe3cc628276124c60,
+                    0xd27f3854846544a3,
+                    0x1b182e6a407541b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd877ad59404de175,
+                    0xd67017cbaee4b822,
+                    0x4a8e376b04d36b3c,
+                    0x62225d410261c5b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ff70303113522f3,
+                    0x795f6c521d9e1bb1,
+                    0x10ea2c5bd93ac0e1,
+                    0x1dbadcd9dd97a5d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3606ebc26215091,
+                    0x5e12a958e27a4137,
+                    0x6e5a0d6f2d5ff5bf,
+                    0xd6f19993c441de6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe18de6d4268a7aab,
+                    0xf116e6580fa6d05f,
+                    0xaf505d26dd03ff53,
+                    0xc457f2b46e39b53e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd821d84840c6480d,
+                    0xcdc9b87934b98dac,
+                    0x4060fee347e2939a,
+                    0x51c73496108a58cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbedc11ec43971811,
+                    0xbe75be7113fe99ef,
+                    0x744ab6e0c667b6eb,
+                    0x4dda7bb3cf6306ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x877870e7ac49177a,
+                    0x957a149ccdfa4835,
+                    0x502ca44546243e0e,
+                    0x7077f382036d400d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe344d34b9c5c4f42,
+                    0xe328f4e2ffec1997,
+                    0xa4ff990fb6fb8578,
+                    0x415ff0a9a0a61155,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x79a53f1167045cad,
+                    0x3d4e4d64dd346228,
+                    0x7680a341be7086a9,
+                    0xc370c3c62404de16,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa570f085e1cab234,
+                    0x1efab9fd5a17876f,
+                    0xc6fe1150e039a04b,
+                    0x1a3422261c538e66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb44d8d954b018f3,
+                    0x292a2f35f2270df3,
+                    0xd44fd1cb6c9c5359,
+                    0x09d38c9f8b4596b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9e8fa8196f9a830,
+                    0x8291d5478f269c85,
+                    0x0abff9755a8f0820,
+                    0x5669114f9afe8d6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51602db52f11a040,
+                    0x00214bcacea92e2f,
+                    0xc80472ce8f5cca1d,
+                    0xc44b36829a9cbdf0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7d573796ee08c29,
+                    0xaa87ac349623141d,
+                    0xfc2fad3978095841,
+                    0xd92a463c56003347,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa497e20fd9341e3e,
+                    0x0b9ab60695f234a0,
+                    0x4c5c221bc35c2062,
+                    0x72674e0cb2b2e4e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff3458987008e5ac,
+                    0x85ab68501a7e51ac,
+                    0x47945140a2b65040,
+                    0x277f4c76f5b533d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd8f156ccf375cf9,
+                    0x7ac482ac4aec0dbc,
+                    0x2c9fb6b93f8041ec,
+                    0x8cbecf49c28e8906,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c7ad8041de1212a,
+                    0xb2f78cd3572443ce,
+                    0x610d886237e0645c,
+                    0xc0c50de601062ffb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb80c10d96b15e838,
+                    0x220709963344e532,
+                    0xf0bef48930f8dd3a,
+                    0x8e05fa0221c0f6eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcee2030921af4275,
+                    0x8a158736f480ea4b,
+                    0xd4c0e2a8e1742a17,
+                    0xe32bdbfc91746cd0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83d126fa80b69837,
+                    0x9bff83e7e5a725e9,
+                    0x15283341914ec5f4,
+                    0xb26a533c0781b4ff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf9ba0729ae13b29c,
+                    0x00d5b2e2c9edebfc,
+                    0x5705556896e42e23,
+                    0xfcf0d78385b88888,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf627ff103bde5f13,
+                    0x7c642795c27ff122,
+                    0xfc634245fc2fedff,
+                    0x770a12e833e7cc23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4379845ade9f5501,
+                    0x447e890f08859e17,
+                    0xde0427a17dc90208,
+                    0x7383e8258d3f36cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a77b97389370a18,
+                    0x7bc2d399a6c6c18f,
+                    0x7fc25537ee9b866c,
+                    0xfc2f702d9430172c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x869174c697d6f0d0,
+                    0x5b8bb9bd3eeed8b0,
+                    0x06ca0fc1a598f0f0,
+                    0x7f674be5705dde68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf56c4c3e20b55535,
+                    0x59b59b0122badb7e,
+                    0x64bcc37c0654fc2b,
+                    0x5e81da7e5c52e35a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf83173b26369be84,
+                    0x75505ed579733a87,
+                    0xee6904b9f187e91b,
+                    0xa19dd0263d0d6a03,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e5aa60a5bb72011,
+                    0xb3c1cc9ac115a32e,
+                    0x0cffa6c9ad280146,
+                    0x48f54b0f1eb7ca4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x854e610966fb05cf,
+                    0xb1a22ebfd481bf43,
+                    0x209b5563973946e8,
+                    0x613a7913ff90e975,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20be98bd1ef92a9f,
+                    0x97fdca91fe9eb372,
+                    0x821fdec4a3371e1b,
+                    0xeca212ed4eed151a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1a9051c52472440,
+                    0x380d03a4c9ecaaa5,
+                    0xa55a5478a230bf2a,
+                    0xe1aaf7563861d27f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe05a3551eff5f8d0,
+                    0xc812f9f9dae12c1d,
+                    0x5c245766763aa6a2,
+                    0x398af7e7f9d4ffd2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x08a796c450c8ae16,
+                    0x2b5f9e0247f51694,
+                    0x5cc1802e4fae72df,
+                    0xbbc833f779319709,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf10e152a4b40e674,
+                    0xadd5bc0c7ba8ad1d,
+                    0xe5dc2ce555762ba9,
+                    0x4f7e19fd44d419e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9d862d53fdfadf1,
+                    0x850634afc6eb2e7b,
+                    0x3ef3b2a76ac73427,
+                    0x056f8fa581f865d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1880e17aa12e7433,
+                    0x0b8c7cf30700e41e,
+                    0x37020281306ac82b,
+                    0x7d6bf89ae2d03279,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cb7efe8155c584e,
+                    0xf87649c67c82b9c7,
+                    0x9c282cc3da42a305,
+                    0xd00f1bab52fce943,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d45860d295eb972,
+                    0x50bcb45a66354535,
+                    0xe55542f3189e281d,
+                    0x498e4d486f32051f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1cc223547678a340,
+                    0x0c16b11667261628,
+                    0x56ba865328ea0178,
+                    0xdd3c7fbc671d25f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75e22f5f2f1a932b,
+                    0xb8a708eae8b56cde,
+                    0x517edff6e682913d,
+                    0x058c1ca8ccccab15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3980755e9130eac3,
+                    0xb43795ff1f825a24,
+                    0xe98b83311b55f70b,
+                    0x0c3b294c1d27d327,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5066fb0f11bea0e2,
+                    0x9674b70d3f4fd37d,
+                    0x55a3491a4b578588,
+                    0xf287dbaece0e43b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfdb7f1a5b46fe67a,
+                    0x11981f588cc33ab5,
+                    0xb22b4e6dbc828cbd,
+                    0xc160b775f1de1e63,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc4fbd011d68a9db,
+                    0xa607ae2f8b7c63ab,
+                    0x164363a4e723d04b,
+                    0xa3ad8f2053228c43,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x951113e8906b6e80,
+                    0xb37e28e5a45997f3,
+                    0x8468b108ba9bf827,
+                    0xa8bfbe74ef4d1bb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x986f3686e89c92b0,
+                    0xbc6547585cba5fdc,
+                    0x8e979a6ceef8ccb8,
+                    0x4900abd3e3e15abc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf089ffbbfafa6a1b,
+                    0x43ecbe83b931f70f,
+                    0x8d9289bdd6ca2af6,
+                    0xc0635b53f7dceacd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06c61d9709e135f2,
+                    0x63df883e132c7e0f,
+                    0x13d1a4eca5c89e73,
+                    0x6fe5a49705cab846,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6e0269a16285a93,
+                    0xd15a99abb4d3fff2,
+                    0xaf2264163dfe8e2e,
+                    0x2bc3b70017157605,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8f303a53b34c703,
+                    0xbf8d7ee951690d76,
+                    0x20c9f8c94750c16b,
+                    0xa8b5e1797866d1d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd6bf38ea2db2924,
+                    0x5f62a890f7233787,
+                    0x5d98df55e273e167,
+                    0xd1618283fe89866d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50fff8c797269ed5,
+                    0xfa23d3c41350b8f2,
+                    0x8bb361c063b4e51e,
+                    0x1e730f4c0cf100b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f6eda0f6c737c94,
+                    0x8e856abe4278c5fc,
+                    0x23f5bd0e40e8297b,
+                    0xc7864522325080c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0322d54628c7246e,
+                    0x72f11322aeb07584,
+                    0xa86e4438b0a9842e,
+                    0x95fa43e9c8081952,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73098b3c5aa8d295,
+                    0xf684934629804df2,
+                    0xbf2890e6c1b422bd,
+                    0xa5be29cc22634326,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb947a2e9e01e1c96,
+                    0x8438e60863fb9a19,
+                    0x45b9eeeb2a0c2bac,
+                    0x04100321c898d83c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4eda2237ad4a895f,
+                    0x5f4bbb33afc4a4a6,
+                    0xd5037fc32c97ccf2,
+                    0xe520ba2c91894375,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60dcaed47de47b50,
+                    0xf6e920e4ed64d33f,
+                    0x96c388d03cd296da,
+                    0x57d11a12216df708,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b724c37c7f6394f,
+                    0x53ce2b686816e8b8,
+                    0x51b5b3069f04c09b,
+                    0x2318ac8e3d43b05d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74f7eeb814d54169,
+                    0xea748813d4241917,
+                    0xc1c9ff92d8ccec2b,
+                    0x43aca4999c85a4cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89d82e0479680000,
+                    0x4df485f777cdcaab,
+                    0x946c92cfe710e8b8,
+                    0x7e5c055c74a0fdf9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb69c15016c773b5d,
+                    0x52bfb1b04fdb557a,
+                    0x26aa7bb7d457e486,
+                    0xe70485b3625ccba9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42753b08846cf5ca,
+                    0x6b2cf6ac4800c595,
+                    0x54e3d65cc579b3c8,
+                    0x16e64c076cd7a6c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96fef2092c5ee346,
+                    0xc40874092ef08ef9,
+                    0x31340bb1ed5569c2,
+                    0xb53494db04fa6c06,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59bc191951f0a4f6,
+                    0x9cf67bd3800fb20c,
+                    0x1ff28fb2377e2295,
+                    0x6528d021ff0360e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f56bd9b1d3ad864,
+                    0x13f7ae20637bda92,
+                    0xdf0a33c31a0b084c,
+                    0x109695452c013023,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a88030c0e80f35f,
+                    0x4e8a54891436d2de,
+                    0xd62a59b9a56e28e5,
+                    0xb1d3e15b92de7220,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18b7a9e13018da0f,
+                    0x11668961a4943740,
+                    0x2be7fd17c821c115,
+                    0xc4d9eafe056dd169,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,1157 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x0000000000000000,
+            0xc6f3988c47bc0f19,
+            0x8f5c58db4bef96e5,
+            0x0000000000000031,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xdcc439163211d00e,
+            0x117f9fcbb4a62003,
+            0xa10a9a57632f4078,
+            0x6accec4f8a3e1954,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x89f42bf7f9a29e91,
+            0x67f456edb9a8a2c5,
+            0xa57c8f3443907042,
+            0x66f5c3cf97415800,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xac1ddf47a6729999,
+            0x8d5d8229402a34f6,
+            0x396d8daaae21254a,
+            0x057de8c365bcd1d0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaa82fe450c75907f,
+            0xeb45723b4b5f523a,
+            0x906164129d37ff90,
+            0xc3dbb395a4c97f74,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x95bccd8018c3fa98,
+            0xf4960e6df69c9189,
+            0xff89a71cce08d91e,
+            0x07f4c43d44d02119,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x182ec001ec41553b,
+            0x3288391dcc807c99,
+            0xd349fe0ce4da0882,
+            0x45b4c9d1fc8c977f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd657de674909af11,
+            0x3e1677842cbef72c,
+            0x261614195baaa308,
+            0x5fb21bf549cc34a7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0d8e1e6258b7de01,
+            0x36c464b8501ae3ed,
+            0xa3841cfebb7f45d1,
+            0xcb91b00749e5c268,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcd64ecb47d8fbfdb,
+            0x6479aa07599bae03,
+            0x7b617c5964d1234d,
+            0xabf0ebf4f27ae04e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x7ede69fe8a879531,
+            0xb30d829b6498fe8f,
+            0x7d6b24091aeb1cf6,
+            0x1374ff89dbb9bef4,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xbf37eef341fcd260,
+            0x2d249b8322e9121e,
+            0xb7295929f350f844,
+            0x9e06d17cfeed125a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x5659f783061f8461,
+            0x7d2692d2d89f53eb,
+            0xdd7bf8236f44e68d,
+            0x8d9db9f45c855028,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x36dfcff69ef452c2,
+            0x70a013d4e4ccdfe0,
+            0x21de27b7b6792096,
+            0x821c16f0449a5a52,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x429a245fb1587b72,
+            0xae2bef917e8b06ac,
+            0x9d6c3fbf49eef9f9,
+            0x0cb70708d20792b1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x43d65e2d53c2c9c6,
+            0x6ae36cb69a5d180d,
+            0xf5aae43a5849e001,
+            0x5f1102006ad954f3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6bae69179ec8c1fb,
+            0x7f24ed099002ec1c,
+            0xb8f08e9a8a811d7b,
+            0xd3c5ed6f4484e123,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x81d5e782266a6df3,
+            0x670f02d3a58a5076,
+            0xe5fb6d023b607b64,
+            0x164a91b2e6b6fcc5,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2e0452c5be429f7b,
+            0x4021eb4e3c1670ce,
+            0x0c9c6388712f198e,
+            0x7e77c55b55378c6b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x73e3e86cf3f12001,
+            0xb4ca2fca18ce3606,
+            0x94e6ab4112f9471c,
+            0xe37a87c530490774,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc50b200c5d6f3bf0,
+            0x4d111d827432313f,
+            0xde499b1c70e4a02b,
+            0xe789a4c459efc7a4,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfc9f4b48e9eb0c33,
+            0xc897a67a00ef6fe3,
+            0xdd3cd19038be7cb7,
+            0x3d44faf8b9fd4409,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x342f36c43fd36f49,
+            0x66f2cca0494d3c17,
+            0xa0dc769beb3cc6a2,
+            0xfe17b234cf16234c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x693f36c014dc21fc,
+            0xcdaf374d5b581f2f,
+            0xd5711c6d8e7ecbb3,
+            0xd92d0fc234726fdc,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x45b5d759325b2062,
+            0x88743d21233040ca,
+            0x88981e3f3b70948b,
+            0x311cd1089bf47002,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1ad64e391c0c014c,
+            0x6fad0f7df183a43b,
+            0xad6d85cb5759e9b5,
+            0xf3e58d7703583060,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x813540269ebe9fec,
+            0xc9a346836f92ecac,
+            0xa55ca9903f42e44d,
+            0x8e10c20514dc7800,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xafbc10433fd9cf93,
+            0x1abffdcd7fe61362,
+            0xf28c3795e9746bb6,
+            0x8920c04d816d0104,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaaec77689fdb2ec3,
+            0x96cec8794e60930b,
+            0xf5857e064ce25ac1,
+            0x1a68840a506fa27d,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc459ca771cd9a548,
+            0x9ca1a68dc749d165,
+            0xb5de809d5590ba6d,
+            0x9da17f9ba723a99d,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2c68da049b5ddea8,
+            0x7590e3340cc7d095,
+            0x89c87a202e42ef9d,
+            0x281afe467fec2c91,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa1ea9614d20ebb4c,
+            0x59fe28bee2aaedec,
+            0x879fbb45165be644,
+            0x6256fc5cb70df84b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x7d6f756fed0a78c3,
+            0x6c59f9316357d328,
+            0xaecb86e3b3a0c60f,
+            0xf721dfdc086b0150,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe8309ce8e923ca4b,
+            0xebd9d7dcc29a7b2e,
+            0x4abad6c0c8f1bf55,
+            0xb3ee1dc62e94c2ab,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x180d841ee51f08aa,
+            0x546a24e50c928006,
+            0x5ca9bf406302507f,
+            0x3555f1c1b71b97eb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd3ec921e72c7eba1,
+            0xc87a22bd76227620,
+            0x5f7bc5d88cdebeee,
+            0x3cfdd6bcc55b8f8f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc0a2f570879803dc,
+            0xf1ad6a7739a72b7e,
+            0xf2884bc848ff5f23,
+            0x42e000d3764c05c2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xac461d22ab78911e,
+            0x9ee7865bfc71f5f3,
+            0xf6f33ff79c993c51,
+            0x94c640e726f19d1a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x9d761fc56118e96b,
+            0xc212d4f6d595309c,
+            0x717fb4465124f7c6,
+            0x4c8ecb071b30d097,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfaea590b2d8484d2,
+            0x0c9927e846c215d2,
+            0xba07307e9e66db0f,
+            0x87898813d13fd0cd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc8952d4f00e2a54e,
+            0xf5af0574980d461f,
+            0xe237e387b85d9d8f,
+            0xeda0ea8c4d7fef85,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x081134b2f3822194,
+            0xb442692716e74b1b,
+            0x1abec6a1c2ac0d30,
+            0x17c9babac6fe2c7f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x99def4deb16404d0,
+            0xb6871cd83e4561f6,
+            0x7d9fde9a11f46a7d,
+            0x44a385ad6b108747,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5531586fa5d54040,
+            0x4eb684de27ea53cd,
+            0x7d9930f95f48a5d3,
+            0x6ff5fdf2a72f0240,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x526d81c93af14b61,
+            0x41d149686dc534e4,
+            0x94661bc59ea20b7f,
+            0xd1659e34b74385e8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1198f1bd8303d78b,
+            0x476eb9e45b510b8e,
+            0x394420eb8880f463,
+            0x64151f0e75542f53,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1974aff8996135b4,
+            0xfc21d21aefeafe75,
+            0x3070cb29f1596ea9,
+            0xe06a3bd5fa2cba88,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1214262623f5ddcd,
+            0x2abefac9b5e985a2,
+            0xd9b8feb8cb8b13e9,
+            0x76b85e42c50afb56,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xba9219e3cf450d14,
+            0x26b847293facdb16,
+            0x55419f2396733196,
+            0x822bc7e3a5d526ae,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x63fb389566645e91,
+            0x73d453187b9c3be1,
+            0x3ce878f59456095e,
+            0xe86104d0e8da92df,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x620d0d8c4566235b,
+            0xe5745db25db62a15,
+            0x6ae900d92446ece9,
+            0x0a210bf912f839e7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcfb7f0b740c9e8b0,
+            0xe56eca2f15de6791,
+            0x7e594b43d210e653,
+            0xed9d9f9db7be41fe,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x438adb3712616a47,
+            0xb1656c824d2279a3,
+            0x0cb15f01b769e610,
+            0x34dac8ba1127ca0b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcaf7b689f0415ad4,
+            0x490652078894c547,
+            0x633f1c55f8926f1f,
+            0x60d3eb83949ea3c0,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8ed0b343602605ee,
+            0xa6ecabe154da9f95,
+            0xcd6ce6c11cb2e4f7,
+            0x7036e4bccf7f5965,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x331e575a822d2d32,
+            0x860ded80a34c4636,
+            0x44bf0a49f061deaa,
+            0x38b8e3f119e75675,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xdbb9a51ef264b8b7,
+            0x4a4f575b37a57364,
+            0x11c870d36d612108,
+            0xf6bc10857e6e86f9,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x755d26bf7b96fce5,
+            0x3b1e1ad8cf5e1034,
+            0x898d99a429aad809,
+            0x970260de546398cb,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe52612d1a1698fba,
+            0xe25eafc8f23dfaee,
+            0xda21b125b76b6ea4,
+            0x243d9d2a767d4679,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x41b1bbc58d4abe25,
+            0xa8c59a78d4fb97b1,
+            0x4793995c9683911f,
+            0x009b0d667c824c8f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6aca2cc429911440,
+            0xb625ceeed27db93b,
+            0x026f90b6f0cb8418,
+            0x3e18ef6fc7c86c88,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x62d82d1ca2593aab,
+            0xe0f67e91ebd1b8cb,
+            0x53807f2ffa7c51dc,
+            0xf71e7e09b37937be,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5d7a762fd74efd4b,
+            0x89b7498e509ac66c,
+            0xdf595015a18569ab,
+            0x97cdb5166bbc0267,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd4e4422383fd7569,
+            0xabd6b4a63d0b64d0,
+            0x7b3c7a53dafe72b8,
+            0xc550db87b534c0b8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa59812dfe631d114,
+            0x3e5b532acb20dccc,
+            0x688d1900ac5a1d3e,
+            0x337997be502a60b2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd31907cf560647a2,
+            0xbfca557deafdaf7d,
+            0xd97f593bd59085fe,
+            0xd1e45b847cfcefdc,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x26b1c8a9eaccaf8e,
+            0x099cdb47f4b28c51,
+            0xe026ef4053cc4c66,
+            0xcebed7f6785208ef,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xbbbc2670c3414abb,
+            0x4e1f217341215970,
+            0x670bbd63e35ed397,
+            0x2284f1cb4ba26910,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfedb30b223bf7e24,
+            0x0d4db8c4b1cb1184,
+            0xf39948b5afde030d,
+            0x398e0a4b02ec3d04,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfc21c6d59d5bd956,
+            0x0a9880ca1b1e253f,
+            0x13faf308f908267b,
+            0x1a8a30a4f8236e39,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5fdc1df30927ca40,
+            0xf25ef3dbc8be71de,
+            0x707629c58d2e2d41,
+            0x7450072ed67281f6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd44a93ac12d783bd,
+            0xc84f576fc35469fc,
+            0x37fa00d5f4d5bd32,
+            0x540bcec00209955f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb737db99d3e43355,
+            0xbdb886bba2c43c7e,
+            0xab4fd264ccbe693d,
+            0x1267632fb34fa676,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x2d6e120ca59cd28a,
+            0x37cba00c693dc108,
+            0x68368d016283f401,
+            0x44d3cedf88c5e921,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8ab8c93c13f78505,
+            0x0c82baaad5cd04c0,
+            0x178c204c56d6f5f5,
+            0xcb41df9a9fb27fc6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xab4c93227f4d2d2f,
+            0xad45e071e29f87f3,
+            0x41868a3d4cdc051f,
+            0x20f092f313a7e9fe,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf2cb79eb78facbb2,
+            0x7900275d78c9b5d8,
+            0x9c9044f76db71775,
+            0x58049691fae46b1f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x131d6c1945ac3d54,
+            0x640ba60dc6a7dd6d,
+            0x249667254aafef5d,
+            0x22f06608d252b73e,
+            0x0000000000000000,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x76c7df60eb84f765,
+            0x8dd7576d508a4a72,
+            0x99435ddee9c0a493,
+            0x6db6db6db6db6de8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0e1d789bd598b66c,
+            0x28a726a7e34ab2bd,
+            0xcfe786d0c3441b46,
+            0xe117521cf4768ea8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd57f78ca15bc0d6f,
+            0xdd1fc406fe56661d,
+            0x52ba6337a826a666,
+            0xe07a5dfbe1bda6da,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc165f116e2a5b4d6,
+            0x3a9a0af6a7eb648e,
+            0xe09956bddeb0b2ae,
+            0xf54e35ee80e9e8c8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb0da09cb511e3fcf,
+            0x2a972a0adf2ddb62,
+            0x2da6a6f4a81ee01e,
+            0x6473f84a2f513a36,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1f1885eea52eb315,
+            0xae12af13ed302211,
+            0x8499af50c55b2699,
+            0x9213533fcbcce649,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcfd55f162e4757de,
+            0xdc76a7882c3355a1,
+            0x0319b9e9ca229004,
+            0x4e0fbad59cb52fad,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x488fdda04311a0ad,
+            0x3848ade9b2482795,
+            0x7adbb76c0336f7ab,
+            0x70a097589d51d471,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x99f82ae7cb52581d,
+            0x8efa815aebad44f4,
+            0x76aaa7db678cf5ca,
+            0xdfba5fc97daf3bb8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x63b95faa400da515,
+            0x3a12057768f8db0f,
+            0x4875fc45dcd82a87,
+            0x36654eb5f6f521bd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x85e693c4f5e79100,
+            0x9018c6dafe94c5a2,
+            0x40991dc46d497757,
+            0x4eb159d44f8ea42c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8972671524e8961b,
+            0xc9abebb270614055,
+            0x62221837321abf93,
+            0xc2c976156f370200,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8eb77bb386e74a74,
+            0xde5fdc0d75d8f202,
+            0x8c0ca17036d7d4c0,
+            0x0c2f6f031ad399da,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x29ceb44bd7be07ce,
+            0x221d250aa7dcd557,
+            0xf564b87a404fdbea,
+            0x0941565eb645aba0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0f8d748e32eafa3f,
+            0x785bc3eb06ecccfe,
+            0xef53b80bd84710a2,
+            0x97167ad623f6bd85,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1ab414cc2538b3b1,
+            0xb2757fdd9689aa63,
+            0x41805196b29b4dea,
+            0x5fbc28cc8d7947e7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf36d77fa48ea813a,
+            0x137b0c196785783d,
+            0xfadf75c5bf29de8d,
+            0x85390bc4d67f77df,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8c99a34d184e365f,
+            0x75900ab9dfd1e3f7,
+            0x0fb5197f5c74a963,
+            0xb143fa5675269df0,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcc5a29ab319da491,
+            0xcb877114ff0296a5,
+            0x678c5f790eb708da,
+            0x9e44a2d62df7af03,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0cf8c0ea4f17d2cf,
+            0xd5c4a0a552481302,
+            0x14cc8ea772c5038a,
+            0x0bdaef9fbc6631aa,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x785205d63128773e,
+            0xcc0f749ac3b53eda,
+            0xdc90660cd531b7ef,
+            0x132440fdc650cb5f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x3975549cadf43521,
+            0x3a55f62d22ce89fa,
+            0xd57191906904ebba,
+            0xdf2b4a30f70f0fe3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x621439cf0c522c4a,
+            0x6ac64b559739afbd,
+            0x8f2ab06af5da88ce,
+            0x50c5f8f67b8292ef,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5a56882658e6a6e9,
+            0x90fec1f25cc0f9f9,
+            0xab8a1b8e8587face,
+            0x1561a0d1dd53e9ed,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6198026f13396085,
+            0x74a97b2606dc8440,
+            0xe6637b4c976d30c7,
+            0xe59837eab99d5439,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x31777ae6e4b5ef27,
+            0xf17738974bbd9ed5,
+            0x302eca4e759a5312,
+            0xb0f47eb8d45e1c35,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb2087a20dffb1647,
+            0xa97155d9dfd617e5,
+            0xa67ed59d6204a901,
+            0x4e59e7a5c6263d5a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaa4c0f3aa12ff24d,
+            0x329f28d8133caadb,
+            0xed9c58b2d82f87a9,
+            0xbfb598ed03678e7d,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x565dcea3d9dd26d1,
+            0xfb71ff6cbd0d2450,
+            0xe6af18f215f4c9dc,
+            0x01c5543341c4d3bf,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x32fd700dce676542,
+            0xc0c0e970e972c9ce,
+            0x8ed2e0db0b0e9677,
+            0x6e849a1294ede54d,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x653c3603a5923cf2,
+            0x7f951d6e11bb31ef,
+            0xf6334b9df58aecc7,
+            0x3c12169d4d0d9942,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x954f52389445088f,
+            0xb4462af40d74a6a6,
+            0x190074389fe7b08e,
+            0x7ee19f13378c6c9a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcb33e053579d0d45,
+            0x69f704bcf4e21c45,
+            0xbe8d276141adebb1,
+            0x16e6b6f1692d4f24,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xffb1ccc973d00b2c,
+            0x2187ff1c7738308b,
+            0x546a6c4e22f83c37,
+            0x49fea7da6007d89e,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa0426ad018ad6020,
+            0x918b34f44bcecf6a,
+            0x5c72ce9c97602087,
+            0x7c2e735f57e951a7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1f53c72b1de20b03,
+            0xc5173ebc68db2ca9,
+            0x470f9a86ada7fbe5,
+            0x6d5ca2a8629e53b9,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb7ad68a74ec942c8,
+            0x8c7c882a0ef19217,
+            0xb10f8684550bfc6a,
+            0xa3906ed5a40de2e2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5de1f07e2aabbf03,
+            0x89fa6c09f792b3ec,
+            0xda7062296ae9a6b8,
+            0xfe0a3949081081d7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6944006d58de9fd0,
+            0x0b29a0e1e6bf1f34,
+            0xd35dbe0223f6bd84,
+            0xb90cf7c7dc938ed9,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd0a2a023752da655,
+            0xd31232a0cbb6d06e,
+            0x15d32f4a3d31f80c,
+            0x6a5367d36353572f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4f2eccab21379c25,
+            0x08ec1c15f857fa87,
+            0x341d5c2231800db6,
+            0x8132c1d54eee19b0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x11af80f75b992198,
+            0x41aa689ed9f706e2,
+            0x546f048e2839ea44,
+            0x94e1dc51c4e16117,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7dfc8b9cf24fc86e,
+            0xe38eaa4a62de364a,
+            0x748184efae77c7b2,
+            0x950aa34f52777da2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf3e04f529ec2f2d7,
+            0xcc01da4b72597f80,
+            0x9970bec4be0fd028,
+            0xc8f0a5f441f30038,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4b7c276bdc3b4537,
+            0x6271a82c1ef4f0fb,
+            0x2e9e911b01ea912c,
+            0x2d9a7a95324b5d66,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd4645c5d44677dc0,
+            0xa020345c2f9433e4,
+            0xcd6a8b50b7c2d14f,
+            0x3695ae6dc10cceef,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8d2172dc3370b13d,
+            0xa197587b9f842097,
+            0xbab0af5dfaf29ebe,
+            0x63b2c2beb53ffded,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x08c595f205c76283,
+            0x4a5f6a00c847af24,
+            0x4bc3a83ec18fb0eb,
+            0xe3872f797c93492a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd31cd01219072054,
+            0xf60416ba8cd48efe,
+            0x093c4f11152cbfb0,
+            0x2187052ade1bebe5,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x37174ee8682860ca,
+            0x9363eaa4ee9a4729,
+            0x15d91058d5536499,
+            0x904fcc77d47e5fb3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0f2d8fb88ff2ef55,
+            0x1db6b86296060638,
+            0xc23a7fc669c5a37a,
+            0xb54a07fededfe040,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc6f07ac70b03de87,
+            0xe06486c612b06f7c,
+            0xcf98bf40e388f425,
+            0x0f124ea25456b076,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xab771c17857d76b3,
+            0xb3ce11e8d4931b95,
+            0x540a4ccda3394709,
+            0x07176f481d1adc4c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf61645c5dae92f2c,
+            0x0aeb035dc381ce5d,
+            0x9262cf73d0cd3d07,
+            0x1c96dc5f9d06c06f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x83cef0655d88aab3,
+            0xd3150d4049e5d690,
+            0x4341f1d6ee8d87d0,
+            0x695c4e9a2604f051,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe2adf4a13791c0f0,
+            0x027a16dabe2dd490,
+            0xdf89fcd584804ea4,
+            0x3a71d82e23c9f4fd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa57b4fb2205bd4f5,
+            0x24bf66f26890325e,
+            0x2af44ae90600ec58,
+            0xfbaf39216894ff07,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x490937c2f9718441,
+            0x92f0693d74139b32,
+            0x9ca768c98e43fa99,
+            0x12413c790f3ad8f8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x2985daa5c8262dab,
+            0x41a3e6fd21921ff4,
+            0xe354fa19f65be45c,
+            0x5044704ea0af2a6f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4943d1f52b932402,
+            0xc786dd9cb7cc23da,
+            0x3891a7a2c1df73c5,
+            0x16282ad8a3293af3,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd00ed59ea2cd30a3,
+            0x13294a450bd43528,
+            0x1195b225dd89aa1c,
+            0xd6bcedb515f89188,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc2dbd78f036104c6,
+            0xd38ee4fc9bb5ebdc,
+            0x879c8814a97e4501,
+            0x985606aeb506645b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd7b02deeb0e94fe6,
+            0xda53963616882a1c,
+            0x91d640845508b826,
+            0x3c3f721a4d8d088a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x18d6bedf7db17463,
+            0x34841d02260c34c1,
+            0x27a75fd25a7b1873,
+            0xe00e4b8a67b68efa,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5a3e466c88c7e6a3,
+            0xce7ba4563ec046c4,
+            0x0b11784e062e161f,
+            0xfefeff4a7206d2bd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8b1f2b2a9ff1e3dc,
+            0x54b7d5b262d0e05d,
+            0x63ce7ddb1bcfebd0,
+            0x86f00d5e9b4e6c55,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x22511abd6b365f3e,
+            0xb656fa2b0ddeb48f,
+            0x87ca5096b2009e56,
+            0x3ca8fa1d5cc2767b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x31bf3735cbe32aa5,
+            0x05cbe5382f6c794a,
+            0x33a5063003d6f2a7,
+            0x99029e51e36fde39,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8f104ec3a4f3e68e,
+            0x22fe62c6b61f61fd,
+            0x3e93790bd4d77d73,
+            0xb345eb307afd8bb3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x96414b576e73acde,
+            0x9dab6999933d41bc,
+            0x58e3186ee9faf821,
+            0x0a8dc48940e58506,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xaadcfab8b2eb731c,
+            0xd235e8941c338826,
+            0xab77c8fa58785210,
+            0x151b929c8e8f3c8c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa2501d5a045d35c6,
+            0x225134fe798e87a4,
+            0x5ac6655cac8b4eef,
+            0xde5da47bb69b5a63,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x5bee7606db597020,
+            0xfef4be5514819ac8,
+            0x5234c5b5bedf289f,
+            0xd6b9e27e961d44a9,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcce3ae1194ddbd0d,
+            0x0e9cc2ba5188d675,
+            0x341167c816e88085,
+            0x11b9d88e1ef08ef1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4460728d7bcaf808,
+            0x4157c395d4214acd,
+            0x61c7e29d0fbcddcf,
+            0x9b621da81620ff1b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x2eb10171073a4c91,
+            0x1a2c051104073a40,
+            0x85b0419dd1bb4439,
+            0x675c83ff5ac73172,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x2711407dd993c068,
+            0x32aa57e5758761f1,
+            0xd6d37626cceda9b7,
+            0x4f387f27b15e565c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0a9581071dd0f9f6,
+            0xdd31e28576e1f0a9,
+            0x46a45da69651ee8d,
+            0x03aa98ec8541ace9,
+            0x0000000000000000,
+        ])),
+    ],
+];
@@ -0,0 +1,1983 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // We use internally the Jive compression method, as both digests do not fit
+        // into the rate registers at once.
+        let result = Self::compress(&Self::Digest::digests_to_elements(digests));
+        Self::Digest::new(result.try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U2>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger320([
+                0x8a056565954cff06,
+                0xc87c9b15a2da9ece,
+                0x98551ad31b1ab74b,
+                0x33ab857ee9ce97f2,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5ae12e60da591706,
+                    0x04fb3d4c37a843ef,
+                    0x071ebf9a8973b426,
+                    0x62be1f7d41603fb9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d34bbaaea0774a3,
+                    0xc93e00bd245c5b87,
+                    0xee15bdded1723c61,
+                    0xd79b1ccd71d7ed37,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x963ecacb3cbba880,
+                    0x1a6006ad256e7cb3,
+                    0x011fd90455896277,
+                    0x47a4452b94afa3c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xabc903041b9746c3,
+                    0xd69482bbb761b627,
+                    0x11db22ad3535f580,
+                    0x51409cd7eec1bdc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbf02ffbab3ddcea,
+                    0x06cefe2ab4f178db,
+                    0xcc3b5703d02eddd9,
+                    0xdaa1606fd6109ad8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2e6050477c25d5e9,
+                    0xf27d41432e65e911,
+                    0x1eb62cd27330bd3b,
+                    0x0479514668983119,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1de32533d61fef45,
+                    0x075062d87299fa10,
+                    0x104da2fad9c135f0,
+                    0xb70cd7e4208eed9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff9d5c92bd82b0c6,
+                    0x9e18753667ddf901,
+                    0x442c42f8744bad8b,
+                    0x489dbcedabebf632,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e18f90a234744df,
+                    0x9c87cea9654b2504,
+                    0x60214b584d190e68,
+                    0x767ba24317e9c35a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9f25c147ea390442,
+                    0x66e3e8d09ce34272,
+                    0x72b40b9a14287793,
+                    0x2ffe56610e94adad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfacbcbfeafe56d14,
+                    0x605fbd87d971e3c7,
+                    0x9c0303eb734c0cad,
+                    0x985b039667f10d5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfeb3d3a90b699a99,
+                    0x6da5ff46a6faaae7,
+                    0xb1fc18ac252a3917,
+                    0x470ccfc88c6196b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83511e28cdb2e082,
+                    0xe5bb24076ce7d4a5,
+                    0x085208b28fec34e8,
+                    0x5734ab6791c4e2ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82930f798c1dbf73,
+                    0xa8cef7e682133daf,
+                    0xe68126ef8eaf84f8,
+                    0x7d6217b209f256cb,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3a7bbf55161365f0,
+                    0xae562e7ed921baf7,
+                    0x102af0556e7ae8f8,
+                    0xc27531a318787149,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc27c7d52c3a1fe86,
+                    0xb438bb90034c95e2,
+                    0x845913b3c6742378,
+                    0xa4641cd8b59e9ded,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3f2eee8fe2fac34,
+                    0xe9bfd308d1e2bbc2,
+                    0xdc599f03c0aadc3a,
+                    0xe4afd21f97de4ebb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a95495d5847931b,
+                    0x01eb16806888bb9d,
+                    0x0baecc990f65209c,
+                    0x693c12887dafb1ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4249279f637ef034,
+                    0x4fca24f7670cbbd9,
+                    0x3acd95b8cb688f99,
+                    0xaae9af0ce7df61df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde1b3da0802e51f5,
+                    0x0a53d29e223a4b7b,
+                    0xcc847d7e811d4ddc,
+                    0x877715e36210bb6a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x3a83583ed460483c,
+                    0x479fc863714bc7d5,
+                    0x3f7f9385aab27f55,
+                    0x6a7221d7f782a735,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb2d5594ad9f2702,
+                    0x783a887508d7c1de,
+                    0x5b42971ab0354363,
+                    0x1697cb486fd86f53,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9909ec7a40b9bc63,
+                    0xd0ecc9e649a9f7cf,
+                    0x81dbd3abd761bb28,
+                    0x3a819477acc735da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83fcdf21cb8ad71a,
+                    0x764bcd68de6e7c4e,
+                    0x8dd506d9f3ee1038,
+                    0x7ec456dabd2b8e0b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x44255a5143321148,
+                    0xcbe7d6240dbe5108,
+                    0xe9fcb1894e28090a,
+                    0x1f391ca0a21cb5f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20e16473e3bc8f22,
+                    0xf29fb9d2c21d45c6,
+                    0xe48d1703f8ff0b04,
+                    0xa64990510e316c03,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf0091790ce8a0b4d,
+                    0x5b10206634369fff,
+                    0xb67aa0a6ef20be20,
+                    0xdfbac3159139ff90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf202f1917e0eee71,
+                    0xb8527f455ba17f5f,
+                    0x71104875b46f5a4f,
+                    0x60c8a70861556466,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x59891d166e18470c,
+                    0xb09713bb2db4d761,
+                    0x0c7aed36c5bfbdcd,
+                    0x9c31d3012c4c3379,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ce2f4b18507aaf4,
+                    0x3cd4a9fc4b871bc6,
+                    0x6a447d8d8261538a,
+                    0xa51fb4449e2363ff,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x77f7f3db18a9451f,
+                    0x07d94ee8e06865b6,
+                    0x5996022dfe80c997,
+                    0x79ec335b2a1aefac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd5c4f7ceb2613b8e,
+                    0xa4edd4e5a4ff6ae0,
+                    0xbeb7b97485a6e190,
+                    0x713bad4eabbf759f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x837f3dc140d16d77,
+                    0x632be18772c3fa59,
+                    0x481c85b823322455,
+                    0x6e0de24578d5c35c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7af88379969400b9,
+                    0x69fbc3a31f01dc3c,
+                    0x70deb1e3bec5e82b,
+                    0x08aa17fbf6a88655,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe63539e1f31d07ed,
+                    0x0f6e234e00a9405a,
+                    0xb658d31bfefb175e,
+                    0x0362bb7bfd21beab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb4fb472470c2c33,
+                    0xfc2a1e8e31906251,
+                    0x4dd79e0d4ebe5b86,
+                    0x20348ce091e90c4a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x985f29d6cc059054,
+                    0x63d116594aef30b5,
+                    0x11a0a9266f53ab57,
+                    0x899942cf58652018,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32c9dfdb8c7ee85f,
+                    0xe414fcc1344b2dc9,
+                    0xb8588edd0fc5974d,
+                    0xa7940eabb204a4a1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x51cc84aa1f84658d,
+                    0x22164c2bc5b4a5bf,
+                    0xd7200db6f8f4d12c,
+                    0xbfbc68f3ed9bdd07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd74b5dfb373250ec,
+                    0x74cd142f2d7082ab,
+                    0xbf51bcf36df066db,
+                    0x8fd62994c4aadb25,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x3a83583ed460483c,
+                    0x479fc863714bc7d5,
+                    0x3f7f9385aab27f55,
+                    0x6a7221d7f782a735,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb2d5594ad9f2702,
+                    0x783a887508d7c1de,
+                    0x5b42971ab0354363,
+                    0x1697cb486fd86f53,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9909ec7a40b9bc63,
+                    0xd0ecc9e649a9f7cf,
+                    0x81dbd3abd761bb28,
+                    0x3a819477acc735da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83fcdf21cb8ad71a,
+                    0x764bcd68de6e7c4e,
+                    0x8dd506d9f3ee1038,
+                    0x7ec456dabd2b8e0b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x44255a5143321148,
+                    0xcbe7d6240dbe5108,
+                    0xe9fcb1894e28090a,
+                    0x1f391ca0a21cb5f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20e16473e3bc8f22,
+                    0xf29fb9d2c21d45c6,
+                    0xe48d1703f8ff0b04,
+                    0xa64990510e316c03,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf0091790ce8a0b4d,
+                    0x5b10206634369fff,
+                    0xb67aa0a6ef20be20,
+                    0xdfbac3159139ff90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf202f1917e0eee71,
+                    0xb8527f455ba17f5f,
+                    0x71104875b46f5a4f,
+                    0x60c8a70861556466,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 124];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger320([
+                    0x898174ab7a5f7389,
+                    0x9d0d856d9bd67591,
+                    0x6c97788d68575c80,
+                    0x63c3019080b75ac4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe43fff413a87438c,
+                    0xd8865a3df7bda9eb,
+                    0x087176515e7aff92,
+                    0xa50270c7f7d938b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f517fe9cb6c0797,
+                    0xb90aff3ccfe8b3b4,
+                    0x88a8683896e1870a,
+                    0xb895744c5e5501ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ad03f70ae282bb5,
+                    0xf6d3e06cdb9df5d3,
+                    0xbc420046c16a8830,
+                    0x954b59fa232383cf,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdfd5094991bd3725,
+                    0x3bc6adb4e6698681,
+                    0x17dec645c66c1c81,
+                    0x592a42f60a5fd0a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9c3e29ea9d31a1e,
+                    0xd8a622a22c28a834,
+                    0x8b6c4f781d1fae67,
+                    0x2f5063e9cfff6a34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb98b245a7c9448a4,
+                    0x6b49ddcf0674c5c6,
+                    0x05ff72c6c744681b,
+                    0x3c5f574ab05dd920,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96944ba65d5965a8,
+                    0xb2890245fc9673a7,
+                    0x1eef32f4d3bb2714,
+                    0xecdcf3988146c9af,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa0ab891726e48b0c,
+                    0x08629bccbf215df4,
+                    0x061ac17683a8e960,
+                    0xf06df8ead777aab0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdfea9ba909184488,
+                    0x78d1fe061c5da151,
+                    0x59a95a783f1bcc25,
+                    0xcb656a37d8016d55,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f397eceb9900de2,
+                    0xbd89bc648a543065,
+                    0xcdf38a28c916cfdc,
+                    0xf0572aef5e3d8809,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x794f9a312a188bd3,
+                    0x5495bd59aa4ea91a,
+                    0x661cd6906ab11d0c,
+                    0x4172a1bc252c11c4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xedcffde1785330c3,
+                    0xc5442927596423dd,
+                    0x3154e674f2b5d95d,
+                    0x1489ef5499662ced,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9f67dfe27f75887,
+                    0xb577067df8470f1b,
+                    0xdfdb99d99c74d6f5,
+                    0x2304d332ea5d9b24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8e48312abbed1bc,
+                    0xc81c1316e83794cf,
+                    0x2a95085aa01254fd,
+                    0x5627e728d17bf2f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7a10e82e6eaef71,
+                    0x831e8ced81c54dfa,
+                    0xd8cb599bc5325fe5,
+                    0x5ae8cfa5ee395930,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x95a423c4d407cec1,
+                    0x15884ed8ae3dbf19,
+                    0xc2a5de7e52a67882,
+                    0xdbb404e32c16cf14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc7aef1d91208516,
+                    0x6813fb289be5041a,
+                    0xa471dc0171e68d43,
+                    0xf6017f77d3131ff2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x236958cde6c6a39e,
+                    0xd7c8e062ec016de9,
+                    0x527199eaee09572b,
+                    0x83f8a0625978db47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x546498409f8c74ce,
+                    0x6a49e00001fd470c,
+                    0x83f343983fa2a4ff,
+                    0xb5a5b39537193f6d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7578961a12986a52,
+                    0x3d9efd69342c5da3,
+                    0xfa2ec7e4d5de6dcc,
+                    0xacf1bef401aea863,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd48537d2b2188712,
+                    0xcae88f733b24903b,
+                    0x5bc66ebc28463ad3,
+                    0x181caeacbe5dddcd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0589069beb2a6731,
+                    0x54820f0604706faf,
+                    0xc9a607974bf9bea8,
+                    0x8ff00375266d17ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36ac27e969d6fac5,
+                    0xb1c7a23c1b7107f3,
+                    0x098e084aa9eb9981,
+                    0xf7cf059f74126e99,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x36dcb4c38f717842,
+                    0x43ba18d05def7931,
+                    0x3b706d57390f5ff7,
+                    0x677fcfc5fefa8679,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf195276aeb2faeb9,
+                    0x721f8cc5c19f7729,
+                    0x41a68a8f4174cd09,
+                    0x888a552b7ff46163,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xda76933d93c0d64a,
+                    0x5e9ef45d161286d4,
+                    0xd53da951dc47b45a,
+                    0xe0e573b9ad5433c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b677d45084b02b7,
+                    0x6476a689c7885669,
+                    0x53544c6fd615d0f0,
+                    0xfb77eae0a98af76e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3dd30db3c61dba8b,
+                    0x52ca4d00e8845901,
+                    0xcd1f5271407f689a,
+                    0xfd228218d3784b32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7dec19c2aa337b28,
+                    0xfa73f4d29f1aa60c,
+                    0x1b1975e1ae5b5963,
+                    0x09753a573901358c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x34c13af4b57edb25,
+                    0x4dd80e860a56926f,
+                    0xb9e22d0f4e3f7f16,
+                    0x1b073c957f6972d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e86d9ead0ff457c,
+                    0x2b1e829aa13f6191,
+                    0xafa77f33ae489a45,
+                    0xf9daf2d382c3a26f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9725eb3df7488b36,
+                    0x2b30f39ee87ca3bf,
+                    0x03bb4ac6e1a8ef07,
+                    0x0d1e2f81de5b9351,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06dbaa27e38a76f8,
+                    0x6c599c45ffe64ce2,
+                    0xf00843fd36c390b8,
+                    0xb349447429d1a8bb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd3f51299d04f9b82,
+                    0x1cf1080b8761a75d,
+                    0x29be4de649df2153,
+                    0x45a322d9ede7d903,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0e72956bbe60905,
+                    0x25eabedd6afa4376,
+                    0x4bd4d1a58cb59148,
+                    0xb1d31386696f1856,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0f5c2e80b2ed1503,
+                    0x68bc28a7a4fef662,
+                    0xf2baaf18003f033a,
+                    0x6780c9c01525b390,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99ad82bb6b30f481,
+                    0x49b6700d78fa744e,
+                    0x35dca37d33bb164d,
+                    0x06782274a6d95d4f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x07f73b357dd092ef,
+                    0xf462615292cc6a90,
+                    0x3d990a046fd27ed1,
+                    0xfe8cd5e965b5536a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xccb3dab7f5a7e1e3,
+                    0x453c7fce9cba0a01,
+                    0xfc07e96ecc56b850,
+                    0x24b5ebde7cf1e597,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7252897503c2e810,
+                    0x347f6f15dcb3e93f,
+                    0x4f8fd9c08ac1fd12,
+                    0x8665a36af0ea4bf1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc828831388fc4019,
+                    0x925121b6302426e2,
+                    0x3fcd1bc82d251752,
+                    0x0265842d46d5d331,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x78db874410a6d35c,
+                    0xbf26c45079c39f11,
+                    0xb7427eba63a3d4fb,
+                    0x00522771c2513986,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbf998cb4d8ab8e5,
+                    0xe2ad210ce79debff,
+                    0x1268c52b4fa992ea,
+                    0x2e6e602c1db8c4c9,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2b90a49afc23bf80,
+                    0x42941200c42c9bf5,
+                    0xed88ca37b47e6778,
+                    0x34cbb65f885ced74,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3dadfe54189cf89e,
+                    0x8d90930e75682ebd,
+                    0xe75ea194a01220ca,
+                    0x328735af3a861655,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb124e989a0c1e9b,
+                    0x20c153e25f02eba9,
+                    0xf65c0cb5e600af24,
+                    0xa81f8520a53edc72,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x830cbc45bf2ced61,
+                    0x58f9c43f7840a200,
+                    0x1a739ca7f2f82fe4,
+                    0xc0f968993e7dd034,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xbd231c31831fca68,
+                    0xa5be098ce5eff168,
+                    0xe7df315a30ff9d1f,
+                    0x7bbb8d1cdd8892a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8caa0233599c242,
+                    0xc62b78a0ccc20cb0,
+                    0x53cbeb262e4f81e3,
+                    0x5290a903b3fdd0b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43ea351c0b379077,
+                    0x87a084187bd8d2d6,
+                    0xadc7622cb39012a1,
+                    0x20c0f6b3ecdf797b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd41df96c661e902,
+                    0x3a8fa124ec59dcb2,
+                    0x4caa6db9cffdcb54,
+                    0xa821b29b6ee08a9e,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xeb82b20353f2c214,
+                    0x7fe70b080179d850,
+                    0x7684962dfb1bf909,
+                    0xf7b721c1d758bd39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c95b5da20d83789,
+                    0x0685c09d4015e9a1,
+                    0x454af604050b9b00,
+                    0xd014dd572c4ff955,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc058acb803997779,
+                    0x0ecc1178c0afad37,
+                    0x25a28d538513887b,
+                    0x3e1c6fbaf9eae257,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb4ce1c0f0daf42a,
+                    0x0283aba5bf3793c4,
+                    0xa538e7686335f408,
+                    0x2361d5e1a0dfc491,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x94a3853f416a5a9f,
+                    0x87f2ee1476cbf843,
+                    0xed547dc3a3b55e81,
+                    0x5e6cccf1f55e4a66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67e06f66430d82f0,
+                    0xea7f0d81acfc55f9,
+                    0x389e0b960e345bf8,
+                    0x841c35988ba1cf84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x433b4aafb41135cf,
+                    0xd64a3589699a4119,
+                    0x91457f3886d2a742,
+                    0xb69f74b546413317,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2cb0a5ca8c7c4d28,
+                    0x7ffdb34dbe96fe04,
+                    0xdc9ffd30b7b17517,
+                    0x8e83291e2434ee9e,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4b2cb250e4b3dd3b,
+                    0x285cd1d0f894efdf,
+                    0x7dac737f1ea5d872,
+                    0x6f812ef0606bde30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92e7db3d4f7c4264,
+                    0x7dc8bef31d844952,
+                    0xd0f690459693bb2b,
+                    0x18984c00c9f30c6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97a6e3b01ea4e6ea,
+                    0x15df9302afe0569d,
+                    0x0ec7e09105872d8e,
+                    0xd65a78068b339124,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a95a1ae3b9b0a1a,
+                    0xe808ec85132be9b1,
+                    0xcf280466160aa9fb,
+                    0x879544d484b2ae05,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xddce25e3f72d8120,
+                    0x7ebfa9c9d30bfb47,
+                    0x5145857ac9692256,
+                    0x3c7233ec0a7ea44d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01630e0e70f94326,
+                    0xfa0370bd6a2321cb,
+                    0xb65aa6bb2987d515,
+                    0x7cf6813f162a9d77,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf3f47c70d6262cf,
+                    0x5cde76f934df1188,
+                    0x926bd2be6dea127d,
+                    0x5ea4bfff8bdf1dad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d813b9ea2ac6249,
+                    0x70b44efcdd54ace1,
+                    0x6c890f12f21c0826,
+                    0x273688aa7b9fde0d,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x2871dc2e7aa126fb,
+                0xb5d9a5961f8ef05b,
+                0x7d16f7e67a842d00,
+                0xf00a24f17eeee7dc,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x460bb1f5cbd597c0,
+                0x0866be002e523d02,
+                0x2891f5c1b25d854c,
+                0xdc5d5e9a56df2b37,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xfbecc8e9a01af472,
+                0x928f64ecd8565ed1,
+                0xe838c852eedac1ff,
+                0x0697bc700c7980be,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb375b652b647df60,
+                0xbe47b439fc4d53c4,
+                0x6989ac42fc88195c,
+                0x14e22f69022d1547,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xbdd62a2609343e43,
+                0x4fa280f2752a304b,
+                0x5497d52695aaa824,
+                0x6b6f2b43c1e10cf9,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4a97b496f403b4d5,
+                0xdf41283c470fae7f,
+                0xee09b264d2f9ae64,
+                0xa916ef407da320e0,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x7c76b54f33895dff,
+                0x25909ff500008bf1,
+                0xe536167c3d0532a7,
+                0xa6cb63002c518659,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x2dd25a4c033cbef1,
+                0x99e8e4d8a9da9140,
+                0x03e6df5d05189bf5,
+                0xbe3021c52e28f530,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x091ab9a1ddb39cfe,
+                0xc36bbb1c20f93212,
+                0x9a3e05a4096fae3c,
+                0xc9661d1364087af9,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x542518c80512a959,
+                0x1ff5e41fe810b1f4,
+                0x0b41f315105e4db5,
+                0x3747356ca34ee6cc,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 62, 63, 191] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 62];
+        let mut opad = vec![0x5cu8; 62];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
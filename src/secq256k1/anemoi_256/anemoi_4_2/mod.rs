@@ -0,0 +1,1180 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 2 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 2;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 22 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 22;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x6b025166d940782c,
+                    0xd1e5a905a281a9d3,
+                    0x4702a2652a58227e,
+                    0x87be736bbc5bb38d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55d7206d5db9e1fb,
+                    0xe0342dd5a2d5463e,
+                    0xd62d352ef2884082,
+                    0xe84acb18be2bd881,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc38640504a4fb31,
+                    0x1c275f8fdfeea6ae,
+                    0x22896e04a48b93e5,
+                    0x5e3e7e30d2ff1253,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27f75d14170b2c3b,
+                    0xbf34f7ad9f128e00,
+                    0x555f2f16ac4991fb,
+                    0xb7de7ebdc0290b47,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf35750df5ade7b67,
+                    0x8c6e2dde1af15700,
+                    0x927523c3e281cdd0,
+                    0xa0e81f5b9c04cc48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9085b9f6c813c37e,
+                    0x30add1e24c66e722,
+                    0xa3074e930b279a36,
+                    0x279e46399f48795f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67ab06c79883062a,
+                    0x3d1982a203de7028,
+                    0x937108d88a774df0,
+                    0xa0a3ad778e124b10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4add765a43288329,
+                    0x1be1557755338bc4,
+                    0x7761057e22de72a3,
+                    0x99b1d00284792d28,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x34a1783c9ea2f295,
+                    0x681319d39d3371a3,
+                    0xc00ca509644c67ff,
+                    0xda6b8a851233d256,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xda905238d9e1e431,
+                    0xdb0c3a3908ce32ad,
+                    0x6de0f645402a3c1a,
+                    0xcbdff5f28df944c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe29aa7efc112e00e,
+                    0x9b47f846598a56c6,
+                    0xd208059f2259b9bc,
+                    0x96af580110c8e09c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb171e152c33482bd,
+                    0xb96b728d0cd643d0,
+                    0x7698bb3fb2a2063a,
+                    0xd62f0c9a54249a45,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x920e66b751bed9ff,
+                    0x0f68ce02431c8f90,
+                    0xacc4d8f43d9891ba,
+                    0x46c9fffd53bf9e41,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x407f755e2f542b0d,
+                    0x8d959f279a68e587,
+                    0x71e9bebf71f1dc66,
+                    0x9d4cc0d67ad57dbe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1618c087280c9579,
+                    0xc71d62de033342a9,
+                    0xb597b433c5006189,
+                    0x36421cfa362625e4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2ac940f743b7fc9,
+                    0x4a3ce1fa36dee573,
+                    0x610a18866096409e,
+                    0xd3e651a93f3c575c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x57d183245bf96813,
+                    0x5572bdf013d4c753,
+                    0x1d7c1611ec6ab74a,
+                    0x960d761b5f69c7c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x090e7a3de5c526c2,
+                    0x54440fd49ae6cf0d,
+                    0x7f4b338cd1634217,
+                    0x26d43b659f0fb865,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66f7879243700eaf,
+                    0x718ad89b92ae77f4,
+                    0x9ecbca9eab8c7907,
+                    0xa3c3985ac6fb1251,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8acd16f63d3f8c84,
+                    0x0d0a50306e795e4d,
+                    0x104b1675ab7d44e9,
+                    0x9940614c84d42042,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf7335f190dd92e20,
+                    0xf2ce49fc45e6c19c,
+                    0x8de7a7ff1dbf1591,
+                    0x00e7accfbdb767a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48f0e0509e0ea52c,
+                    0x970dcedb71deea4f,
+                    0x9e17ba5cea988e27,
+                    0x7d2832ab67e982df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e32ae3c3759f03d,
+                    0x1fa94ca4e4aac2f6,
+                    0x121674b0c224aa39,
+                    0x2110080faf39d498,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb02452adbe51435,
+                    0xb9da00fdf0b262cd,
+                    0x41a5c69f823c5801,
+                    0xafe3b34626464d2f,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2591b34e6067e54c,
+                    0xf2f8c9f064020274,
+                    0x9b8112604387eb50,
+                    0x136e0fe0cf7c36d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26166bbd4ce542cf,
+                    0x3b2cc05c85bed8b5,
+                    0x3c94fb58d16ed274,
+                    0x266aa9d627e3cc32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe57bf3bcb993e20,
+                    0xfadbb6fe264867eb,
+                    0x23a2ef6ad1097155,
+                    0xba08547f11407d5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x504f465ad1412dc9,
+                    0x79804515dc293a10,
+                    0x286eb4b5e5ab9d36,
+                    0x62c8e40fd22405c1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8e88865cf03564d5,
+                    0xea8b37e10fa644cc,
+                    0xd2977ae2ae0897d6,
+                    0x049b62bdef7e8c72,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x983a26836f4d71c1,
+                    0x8251251964e31b13,
+                    0x8961b994d93880f7,
+                    0xe64d0cf38cdbd381,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b3caba546de53e6,
+                    0xa64fe677062ae785,
+                    0x63dc2fb79ddedce2,
+                    0x4d6741e34ba462b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xccf4e6d6939a4e35,
+                    0x5064e1c94455a912,
+                    0xb5e0d6ddb8951a91,
+                    0xbe4e97d912850b3c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc48cc97c0cf9fab1,
+                    0xd0e1591a37bb78fc,
+                    0x469d2803c7920d8b,
+                    0x977e84d27aadf49d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd5e81c2fe3b23b4,
+                    0x58896a072d69ee87,
+                    0x9d7180bfe7a9e1ea,
+                    0x0c01faf069c72ca7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x134d632f6bf92ea5,
+                    0x9cbf0f4f89eddc27,
+                    0xb1a9932ccf889a5a,
+                    0xf7dc904efe6325d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x08e6f32617ad3c4d,
+                    0x6993212f2cf7bb2c,
+                    0x5cdbcc29e39b2b2f,
+                    0x0726ff6488c7fe59,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x10f1204d982207a9,
+                    0xfea0347ddec34fa5,
+                    0x6893a549883a3ca8,
+                    0x7698b388a4a37587,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3357a59c0fcbea4,
+                    0x0bd073bc1d418caf,
+                    0x1e15dc6bb315ec01,
+                    0xac3c345e42cae854,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc44fb726cff096db,
+                    0xc291ec2d9b3ea11b,
+                    0x48324c213df19bd9,
+                    0x87d1f4968ca59858,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd47536197a03bdc,
+                    0x453ed7cd87e16940,
+                    0xa184908fe369aa0a,
+                    0x29decb53956b6f2c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9b78094b7aadfc34,
+                    0x164f04ba8baea68b,
+                    0x66b948f827953297,
+                    0xc107da836bc02ceb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x304d3b4aa13cf652,
+                    0xa6d98368d1831b0d,
+                    0x71c23d894fc61bbe,
+                    0x8ff7f0f73331eb66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c9a30d1d0fe3a79,
+                    0x877240c2ca354e71,
+                    0x5a06cc6aacae707f,
+                    0x618bb6301d439e6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0cd4007f1b126eed,
+                    0xbb34793ef93a0291,
+                    0x23dd02f40bec809e,
+                    0x9bbfc332dc7f98fe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x139bb988a9e1147e,
+                    0x15c73feec19fda24,
+                    0xc62323f2baad3e22,
+                    0x3051d87f0b0568bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc86c700c1f86f1c9,
+                    0x337bdba108b45f0e,
+                    0xc8cd8e85bd0bf297,
+                    0x6b5cd4cfeda9c660,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2ba19c9a338c5a0,
+                    0x7f3aeaba5eee4cdb,
+                    0x72de188dab2e12e9,
+                    0x43981a5ab4d22dd4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06085b76aad4e82d,
+                    0xfd7cc17e48f9f0de,
+                    0x682c981b7d07cb0a,
+                    0x089c50276fed092a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xd5fcd144696746bc,
+                    0x93018dc80487cd3d,
+                    0x11117dac37130344,
+                    0x7e61713fbe585a15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc9779f60baad623e,
+                    0x7838482d0bc4ee30,
+                    0xf35c64ffcabd8646,
+                    0x4dbd3f85093bd251,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x180c0723cf96c242,
+                    0xdd08b56d1bec568e,
+                    0xdf991b73d0c50f57,
+                    0x06a03b66443b1103,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7296e4300a3ca889,
+                    0x172497c3cdb7ef5c,
+                    0x7ad6253a5493f72c,
+                    0x376cff379ffc304d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa74a2626bbddadfa,
+                    0xdcc50074e3dc8a85,
+                    0xb244be1688245b3f,
+                    0x510db69ffd212428,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bbbe442a923214c,
+                    0xb1d48aa47aecbf77,
+                    0x2ef81fd13c9c761f,
+                    0x5e3f6c03e958a7c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1e884b44b2110cc,
+                    0x2b9de11c30639557,
+                    0x4fd539dba961c3bb,
+                    0x2eb5a9758b975001,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x930eccf8f9f12963,
+                    0x4aa721733c1da635,
+                    0xdc007bec71702046,
+                    0x9c0e3e1f0b8355c6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd38d8f0f2fbc201b,
+                    0xf37d4b4e6ea817ea,
+                    0x1ebc54c63f935d28,
+                    0xeef6d7d8f450f6b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4261c7f10241ef5,
+                    0xa51d5fff14ba6ea6,
+                    0x4f1b3c8bf249f0b7,
+                    0xd0a78a867f1a1033,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc826c11cab920b91,
+                    0x23347567eae445b2,
+                    0x33308fc23df8c2c8,
+                    0x1d4cb047b5262eb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38b2b1e8d48c9666,
+                    0x83160df3925eaaea,
+                    0xccd7545ec2300c15,
+                    0xb943100ef7a83c7a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4a38285051d70586,
+                    0x3decc65b9bc09e03,
+                    0x949698b3597c2069,
+                    0x8c27136d6e67944b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x96f8ac3650fdd253,
+                    0xf683e5e8925c10fb,
+                    0x3eaf5a473edc1e59,
+                    0xdf49f56764963f7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3aca416cef4e45a,
+                    0xb5bcabeb2c71a587,
+                    0x76ca00b3d81a0664,
+                    0x751e23eded832186,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12aef8605b7feb7f,
+                    0xcd94f665a84b218b,
+                    0x128b190686185aa6,
+                    0xc30c818e661f33e8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x13b0bee57650f2f4,
+                    0xdf144223470eba52,
+                    0x64665d9b4428622d,
+                    0x417623aae66c034b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcae4442f6901f20c,
+                    0x661044593c557a3a,
+                    0x059055b3e2a60696,
+                    0x23c6ee2aa59ffd4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5636e79dcde2b95,
+                    0xb13b9c323672113b,
+                    0xded546fe29bd8dd8,
+                    0xca1982c265e2be67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe403078e871cc066,
+                    0xe4d2882ab46c0cac,
+                    0xaff62723b075263c,
+                    0xf2c8ed9b527c36eb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x09267472ea77b0fb,
+                    0xebf3d21ad5d7d4e3,
+                    0x2383ed23d0e8f7b8,
+                    0x6a30660fc107c738,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e6373ad4808d7bb,
+                    0x5be0d8ec370ca03f,
+                    0x58f8a76768f14ed2,
+                    0xda7bf33994b6d285,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd83f9405a67aab47,
+                    0xff5247f2197d785c,
+                    0x0cb00ed65b8ba19f,
+                    0xdfdd6dc07579be14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3839acbcaaa7b5e3,
+                    0x1f496be502d10960,
+                    0xe257988408681c85,
+                    0x88c6054ed87c2d9f,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd89d6fcfe3b873ec,
+                    0x672dcd35f859101b,
+                    0xb89840aac241af34,
+                    0x9e8e2de2fefb1a52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb67c33dcb3df88ae,
+                    0x5fbd710c1911de04,
+                    0xff8629ab1a8950ba,
+                    0xa3a080ba02198a94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1aeb162ab75bf857,
+                    0x22618dbf912e4d3f,
+                    0x9805e56509f76293,
+                    0x65ce9f037d99a768,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54d4e53532ae0a21,
+                    0x5855dbdcb59f32d0,
+                    0x07c261371688c160,
+                    0xcf46947eb36ea4e0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x59c8a6dfbb67148c,
+                    0x44371126e1c48650,
+                    0xfb0d9ccf306b9620,
+                    0xe4c9aabb5e8dba70,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x214a3d13e7af299a,
+                    0x2f3cd54c8b98aa43,
+                    0x0c57697b8f8d9109,
+                    0x9fc317237f38c0d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90980f5a37a25db6,
+                    0xc1496c51df8e1b60,
+                    0x0ad510ee131c7a65,
+                    0xe305e055dca685d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4722b8deef4c1940,
+                    0x1486a9f131929395,
+                    0x9ba8b05e2f291c8b,
+                    0x63decbce9424f8b1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x21aa1f3bbf737148,
+                    0x1731bddfe40d5d14,
+                    0x487afc99df98f236,
+                    0xa38ba1866e076817,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00b11e613b3cf1a8,
+                    0xa20f409ce4acd909,
+                    0x4a7824c10f789038,
+                    0x4978f533814de8d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1ef9b24b554a61c,
+                    0xbed666e44f53f291,
+                    0x332b42ae73fd5f8f,
+                    0x865be204ebb38376,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x263ce377303fd292,
+                    0x2b04aef2085606fd,
+                    0x995f628769e65fba,
+                    0xc9cfde6a270ec6f1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xec16a67fa7823e45,
+                    0x9b6ee24f8091537d,
+                    0x4b6210a61180f4e6,
+                    0xa72cc9412e8350bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4c7b60f3d47e3ff1,
+                    0x9121c593a9ea3842,
+                    0x4e5dced1b962d0ae,
+                    0x7183762faa2d74b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0cf05959938f6632,
+                    0x95b1131fd18cc78f,
+                    0x52111df16ece876b,
+                    0xf6df7d0fe4b51e95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff7e7fb02664e6f1,
+                    0xb2cc277b1c4eb8ce,
+                    0xb541d24ddfbfba5e,
+                    0x353a8f5d2e76f79b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe01c3da485285007,
+                    0xeed743ad3e1c71b1,
+                    0x8b58b58676b2904b,
+                    0x3be6a6d56dcbf066,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bd795223ce5e0fc,
+                    0x3344412f3fd3f5dc,
+                    0xd4fd4c612187f8ab,
+                    0xc7157e00a633901a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5accd596afea69f3,
+                    0x5d5aa02215d6c3e8,
+                    0xc7cb1816d4a40731,
+                    0x797b80ec1baf6bc2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31b3cb656b6e0c36,
+                    0x53fa8986122eeba7,
+                    0x5562ef9dfa39c037,
+                    0x1c7a093727aeb0bb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5ef066e301700f92,
+                    0x0f0093283b7c7536,
+                    0x925080f7af821f81,
+                    0x63940ca2d20788dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x777f283be17680f6,
+                    0x94d84851c999f408,
+                    0x592c2e2d35802b5d,
+                    0x93884bad52eb9094,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa308819756bcdd4e,
+                    0xba70361b998b9283,
+                    0x3b280060893987ec,
+                    0xa7d405920ed0602c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e5f4668f4957224,
+                    0x72f97231dfe358cb,
+                    0xaac8117a1c1e591f,
+                    0x76a994bedd2c5f49,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
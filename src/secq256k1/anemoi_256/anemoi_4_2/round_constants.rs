@@ -0,0 +1,717 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x0000000000000000,
+            0xc6f3988c47bc0f19,
+            0x8f5c58db4bef96e5,
+            0x0000000000000031,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xdcc439163211d00e,
+            0x117f9fcbb4a62003,
+            0xa10a9a57632f4078,
+            0x6accec4f8a3e1954,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x182ec001ec41553b,
+            0x3288391dcc807c99,
+            0xd349fe0ce4da0882,
+            0x45b4c9d1fc8c977f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd657de674909af11,
+            0x3e1677842cbef72c,
+            0x261614195baaa308,
+            0x5fb21bf549cc34a7,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x5659f783061f8461,
+            0x7d2692d2d89f53eb,
+            0xdd7bf8236f44e68d,
+            0x8d9db9f45c855028,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x36dfcff69ef452c2,
+            0x70a013d4e4ccdfe0,
+            0x21de27b7b6792096,
+            0x821c16f0449a5a52,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2e0452c5be429f7b,
+            0x4021eb4e3c1670ce,
+            0x0c9c6388712f198e,
+            0x7e77c55b55378c6b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x73e3e86cf3f12001,
+            0xb4ca2fca18ce3606,
+            0x94e6ab4112f9471c,
+            0xe37a87c530490774,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x45b5d759325b2062,
+            0x88743d21233040ca,
+            0x88981e3f3b70948b,
+            0x311cd1089bf47002,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1ad64e391c0c014c,
+            0x6fad0f7df183a43b,
+            0xad6d85cb5759e9b5,
+            0xf3e58d7703583060,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2c68da049b5ddea8,
+            0x7590e3340cc7d095,
+            0x89c87a202e42ef9d,
+            0x281afe467fec2c91,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa1ea9614d20ebb4c,
+            0x59fe28bee2aaedec,
+            0x879fbb45165be644,
+            0x6256fc5cb70df84b,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc0a2f570879803dc,
+            0xf1ad6a7739a72b7e,
+            0xf2884bc848ff5f23,
+            0x42e000d3764c05c2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xac461d22ab78911e,
+            0x9ee7865bfc71f5f3,
+            0xf6f33ff79c993c51,
+            0x94c640e726f19d1a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x99def4deb16404d0,
+            0xb6871cd83e4561f6,
+            0x7d9fde9a11f46a7d,
+            0x44a385ad6b108747,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5531586fa5d54040,
+            0x4eb684de27ea53cd,
+            0x7d9930f95f48a5d3,
+            0x6ff5fdf2a72f0240,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xba9219e3cf450d14,
+            0x26b847293facdb16,
+            0x55419f2396733196,
+            0x822bc7e3a5d526ae,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x63fb389566645e91,
+            0x73d453187b9c3be1,
+            0x3ce878f59456095e,
+            0xe86104d0e8da92df,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8ed0b343602605ee,
+            0xa6ecabe154da9f95,
+            0xcd6ce6c11cb2e4f7,
+            0x7036e4bccf7f5965,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x331e575a822d2d32,
+            0x860ded80a34c4636,
+            0x44bf0a49f061deaa,
+            0x38b8e3f119e75675,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6aca2cc429911440,
+            0xb625ceeed27db93b,
+            0x026f90b6f0cb8418,
+            0x3e18ef6fc7c86c88,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x62d82d1ca2593aab,
+            0xe0f67e91ebd1b8cb,
+            0x53807f2ffa7c51dc,
+            0xf71e7e09b37937be,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x26b1c8a9eaccaf8e,
+            0x099cdb47f4b28c51,
+            0xe026ef4053cc4c66,
+            0xcebed7f6785208ef,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xbbbc2670c3414abb,
+            0x4e1f217341215970,
+            0x670bbd63e35ed397,
+            0x2284f1cb4ba26910,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb737db99d3e43355,
+            0xbdb886bba2c43c7e,
+            0xab4fd264ccbe693d,
+            0x1267632fb34fa676,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x2d6e120ca59cd28a,
+            0x37cba00c693dc108,
+            0x68368d016283f401,
+            0x44d3cedf88c5e921,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2ad6799b56987bde,
+            0xfceee5d01da0fee0,
+            0x4265d9ccf86361b2,
+            0x447eaa38c4f1aa7b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x730a11b11841d141,
+            0xa998bbc98c687f14,
+            0xf276c7f2c525f4af,
+            0xdab74640be8eb25c,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf9adfdcd99234281,
+            0x3168d618c8846d16,
+            0x8a4b2fb3b8b5a33a,
+            0x4f32523289bd84d0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x34d474931f6ed12f,
+            0x82bf2d99ab6ff269,
+            0x5c5b046e2af91b13,
+            0x13dab2bd62b7d396,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x59f9c112ca889b19,
+            0xf5044b76cd207510,
+            0x6fa5873268b0d577,
+            0xbe8c1b27e6d4cd49,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc890df915553c0ef,
+            0x0f1b9111a36398a0,
+            0x321df9cc5836d507,
+            0x01629415f770b0ab,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x44804c513dae2344,
+            0xe88f272d58469cd7,
+            0x1615ad0cd1c360fc,
+            0xee6e891903d2491a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x675dbc737ede3f34,
+            0x91fa46ec688416fe,
+            0xe0a6c5519c20581c,
+            0xde4faad5edf04861,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xfb3680bba8612acd,
+            0x8574cc5f6afaf1c6,
+            0x16ed858316ffb156,
+            0x74255d09d260436f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1c475eedd1b519fe,
+            0x488bfbf3c4c510ba,
+            0xaa1817ac99be0d0a,
+            0x762b8dd87beb8686,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xbedf6b23639804f5,
+            0xcd7550cfd405bf10,
+            0x2deb10dd04383dec,
+            0x5db93a81f2c0c645,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x13296b0feef63e7f,
+            0x9584c55457e359c5,
+            0x35eb287df6fab410,
+            0xba3e7dbc318e4d0e,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x995fb818d7bed3dc,
+            0x36b6aa544b9192da,
+            0x5c17008073102be4,
+            0x6b139822c99251f6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4622304e6bfbb39e,
+            0x732ebb95333bc498,
+            0xe3479be67f4c3b86,
+            0x52e8001c30f30482,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3229d5beedc927ae,
+            0x50ce4f8df275a34d,
+            0xdb1eded959934be9,
+            0x0174966d5b23c06f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x60423c324fc0bc27,
+            0x6c6dbbe0e2e8214d,
+            0x84c8fd636282a927,
+            0x33b03ac4ac255033,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x02ede10e2715d55f,
+            0xa4b1c0b48cbc9875,
+            0xc9c04691619e3018,
+            0x810a88e2a0014979,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa6ef25aa5dd249bd,
+            0x298a1de2ab1bfeb1,
+            0x1751de2d12880bb9,
+            0x493229c0c6d0e63f,
+            0x0000000000000000,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x76c7df60eb84f765,
+            0x8dd7576d508a4a72,
+            0x99435ddee9c0a493,
+            0x6db6db6db6db6de8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0e1d789bd598b66c,
+            0x28a726a7e34ab2bd,
+            0xcfe786d0c3441b46,
+            0xe117521cf4768ea8,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcfd55f162e4757de,
+            0xdc76a7882c3355a1,
+            0x0319b9e9ca229004,
+            0x4e0fbad59cb52fad,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x488fdda04311a0ad,
+            0x3848ade9b2482795,
+            0x7adbb76c0336f7ab,
+            0x70a097589d51d471,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8eb77bb386e74a74,
+            0xde5fdc0d75d8f202,
+            0x8c0ca17036d7d4c0,
+            0x0c2f6f031ad399da,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x29ceb44bd7be07ce,
+            0x221d250aa7dcd557,
+            0xf564b87a404fdbea,
+            0x0941565eb645aba0,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcc5a29ab319da491,
+            0xcb877114ff0296a5,
+            0x678c5f790eb708da,
+            0x9e44a2d62df7af03,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0cf8c0ea4f17d2cf,
+            0xd5c4a0a552481302,
+            0x14cc8ea772c5038a,
+            0x0bdaef9fbc6631aa,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6198026f13396085,
+            0x74a97b2606dc8440,
+            0xe6637b4c976d30c7,
+            0xe59837eab99d5439,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x31777ae6e4b5ef27,
+            0xf17738974bbd9ed5,
+            0x302eca4e759a5312,
+            0xb0f47eb8d45e1c35,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x653c3603a5923cf2,
+            0x7f951d6e11bb31ef,
+            0xf6334b9df58aecc7,
+            0x3c12169d4d0d9942,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x954f52389445088f,
+            0xb4462af40d74a6a6,
+            0x190074389fe7b08e,
+            0x7ee19f13378c6c9a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb7ad68a74ec942c8,
+            0x8c7c882a0ef19217,
+            0xb10f8684550bfc6a,
+            0xa3906ed5a40de2e2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5de1f07e2aabbf03,
+            0x89fa6c09f792b3ec,
+            0xda7062296ae9a6b8,
+            0xfe0a3949081081d7,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7dfc8b9cf24fc86e,
+            0xe38eaa4a62de364a,
+            0x748184efae77c7b2,
+            0x950aa34f52777da2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf3e04f529ec2f2d7,
+            0xcc01da4b72597f80,
+            0x9970bec4be0fd028,
+            0xc8f0a5f441f30038,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd31cd01219072054,
+            0xf60416ba8cd48efe,
+            0x093c4f11152cbfb0,
+            0x2187052ade1bebe5,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x37174ee8682860ca,
+            0x9363eaa4ee9a4729,
+            0x15d91058d5536499,
+            0x904fcc77d47e5fb3,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x83cef0655d88aab3,
+            0xd3150d4049e5d690,
+            0x4341f1d6ee8d87d0,
+            0x695c4e9a2604f051,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe2adf4a13791c0f0,
+            0x027a16dabe2dd490,
+            0xdf89fcd584804ea4,
+            0x3a71d82e23c9f4fd,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd00ed59ea2cd30a3,
+            0x13294a450bd43528,
+            0x1195b225dd89aa1c,
+            0xd6bcedb515f89188,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc2dbd78f036104c6,
+            0xd38ee4fc9bb5ebdc,
+            0x879c8814a97e4501,
+            0x985606aeb506645b,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x22511abd6b365f3e,
+            0xb656fa2b0ddeb48f,
+            0x87ca5096b2009e56,
+            0x3ca8fa1d5cc2767b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x31bf3735cbe32aa5,
+            0x05cbe5382f6c794a,
+            0x33a5063003d6f2a7,
+            0x99029e51e36fde39,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x5bee7606db597020,
+            0xfef4be5514819ac8,
+            0x5234c5b5bedf289f,
+            0xd6b9e27e961d44a9,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcce3ae1194ddbd0d,
+            0x0e9cc2ba5188d675,
+            0x341167c816e88085,
+            0x11b9d88e1ef08ef1,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3c8f8595e9633198,
+            0x9e6ca6ad61b0f26a,
+            0x0312c32eeb7a3863,
+            0xab943a2ba59dacd5,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x7f821f4392d834b3,
+            0xe0ab67bb470629c2,
+            0xd81998ca7a809881,
+            0x4a6060935297bc53,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xeed0fcc697e55354,
+            0x7c3e6916d95fccea,
+            0xfead4cff9f87de75,
+            0xd2a9fbd196fe305a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe488d3b0d632d0fb,
+            0x1dd88892e221a99c,
+            0xf5b3092fd40f236f,
+            0x9fe5e6bc235586bd,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6f08789b44134d4c,
+            0x6484f65ffd7c7204,
+            0xe7469fa21c9e2bff,
+            0x97c666edba769735,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x580355cb5716a35c,
+            0x898ee0dca8de8d30,
+            0xceb4f9b1ce67f8ad,
+            0xe3306a3b7e6f8234,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6f0095ddd8eae76f,
+            0x173fb4f7da16fcc3,
+            0x5c3c556ee7cd7ab9,
+            0x2658c32e571eb53f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4c6f6624d21cf258,
+            0x10ee9cb2102ace4a,
+            0x4bc35529746e3ef9,
+            0x1ecd6f4af499bc24,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xab47de3874d12133,
+            0x63bf2136a0febaca,
+            0x40ed0bb73fa6fe6f,
+            0x881e2d9f3708a392,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x86ea1c8f5626ff5d,
+            0x771a18c6209f311d,
+            0xf90d855684a92743,
+            0x92b7e8cd93f0ee46,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xca806c3f1fa89b9d,
+            0x51d2a5e3b889a71e,
+            0x2c28d3e951b8f356,
+            0x210efd7d2b7e38db,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd95bcc506308c420,
+            0x6a25e263623d9932,
+            0x591ed30006bf369a,
+            0x8627cb171da8c741,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xbe46f7712da8494c,
+            0x4c608af35e866560,
+            0xacac1e187e7637d8,
+            0xd70b559675132614,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x259acfcb79e71807,
+            0xd91c642f6c06ee7e,
+            0x58d2a0f44cf6149a,
+            0xc77347ef8fd0e03e,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x0b87d058a94efaa1,
+            0x5bd8710f64e629de,
+            0x3dd3664ca346a179,
+            0xdd2281d8295c23c6,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x345f3863f3123cd2,
+            0x0d0cc876cbe65f02,
+            0x0c736c4c6e79cbd9,
+            0x17f1b08f2dbabb27,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3140e90a50ea8868,
+            0x207be3b9d8d56004,
+            0x142c63deee9cd077,
+            0xd1c441038020fbf2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8fd38dcb3fa8ebbf,
+            0xf59808e31d0b1da0,
+            0x86b3e2f061ca7937,
+            0xa27f6c415a4da054,
+            0x0000000000000000,
+        ])),
+    ],
+];
@@ -0,0 +1,2287 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U4>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x1f9a267c34d89f40,
+                0x81a5236eab80d5ce,
+                0xd9f0f77129602503,
+                0x0898115b16e7b97e,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x26164bb45c22e3b7,
+                    0x05379cae6417e147,
+                    0xc86b5640e80c95ce,
+                    0x88b1119e387bb6de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15d8ac9458852ce3,
+                    0xd3755942bd4a717b,
+                    0x1f49aba8883cc799,
+                    0x9ed4ee35b0e56e0e,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2d184f7630d34342,
+                    0x9bb18a57588e4c01,
+                    0x91e8027a59d2777f,
+                    0xa2d59a167e04d7ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0035d014c78a0694,
+                    0xe478ce11dbe40761,
+                    0xbf1d07b0b0b767ac,
+                    0x98c7d42a340d7ca9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55cdcd1c09b653c1,
+                    0x66a1992cfda992a3,
+                    0x4d028b854cd7d18b,
+                    0xafff1f4e2f223fcc,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7aa5d589c3365d59,
+                    0x505f7d86d0491a31,
+                    0x3e7c49c657440024,
+                    0x2e3375f2f839f653,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe847bc0632cf535,
+                    0xc20e359d172dbc60,
+                    0x463dd6a1ad870469,
+                    0xf6ba019092234639,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe89f8005d8525e93,
+                    0x0b572e897bcac3fd,
+                    0x4b80793782fcb801,
+                    0x3787236033ebc7d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2f120b61dd3f209,
+                    0xb6c2557d35b6d471,
+                    0xf3261db0a60da355,
+                    0xdd4288d1b734439a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdf95039db849a88e,
+                    0xa22a93184b8e6e9f,
+                    0xdf11c29b03931bbd,
+                    0x37cb99a48256d31b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xafe6470546df75a1,
+                    0xf05fcb6e77a30788,
+                    0x1bfe80f83942e3b7,
+                    0xe2795c3ee321c557,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1c9f6a17852a274,
+                    0xa144f3239a81b007,
+                    0xbe11712929af3296,
+                    0xea41cafbff67f114,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4f44be4049c0586,
+                    0xb33e0ecbe92ab075,
+                    0x524a12d203eb6e4e,
+                    0xc0ea982272869aae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2baf27e44e54ed38,
+                    0x0aa20855f9561494,
+                    0x5704ce6f7954d3c7,
+                    0x8a50769fe6b0fbd8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xc31d2f914c474028,
+                    0x2888e140552fe6e6,
+                    0xfc3328547dc22730,
+                    0xc7c12210f808cdda,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c67b66879f9ddaf,
+                    0xf0b3c92cfced48a2,
+                    0x5df372a44e873963,
+                    0xed683ca7c1d37242,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc283b5fc15d05d35,
+                    0xab1a8442d8215e41,
+                    0x2b2e777496ff6a65,
+                    0x126bd4803580ccb1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa57f3a161be784c3,
+                    0xfd5a7b1d763e4515,
+                    0x81f8c4e9bd627136,
+                    0x5dc2328a114a0613,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x283311bcf7b8b23a,
+                    0xb5b5d27f02898501,
+                    0xb1d7316e4129385f,
+                    0xedc04df8a79b71bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9ea3ab91aa64edff,
+                    0xdf28d50551879def,
+                    0xdd0b4baffb0763bf,
+                    0x23242074c5fe2717,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x2ebd3ed357e4f49d,
+                    0x5725549133bb66c9,
+                    0xc2dacca805939b95,
+                    0xf259052a2c57bf9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c6655e821e0d425,
+                    0xb3911919210409ee,
+                    0x93fba6adc12f38ca,
+                    0xf1f526476f697107,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5f830de785857dff,
+                    0x0cf81a4822b04891,
+                    0xda71a700a29574f4,
+                    0x44774c965e9d426b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa1dcdbe7dbb022a,
+                    0x5fa5a0f384c15636,
+                    0x876fd26b82eb04bd,
+                    0x3a800757b04fc0b6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x45176f9d83ee3464,
+                    0xbbfc7c822e3e7874,
+                    0x7c37f801778bc99b,
+                    0xe476565fb034a852,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e7b0936351e8b2f,
+                    0x6605787838cad49c,
+                    0x82bc6fff429ab57e,
+                    0xaa21bb7d35288c9b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3643f307eae353a8,
+                    0xd1d3d8801344c2a6,
+                    0x6f2d1fca189f2fa2,
+                    0xd399c58741695112,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ac22d6958bb435f,
+                    0xd6e9e5505213f139,
+                    0xea70539073bdcd57,
+                    0x49ff2eedd27b4896,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x770434042454b4af,
+                    0x357e97cc634ed339,
+                    0x7ceb59aac7a34fd2,
+                    0xe38fe1416e8c7717,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaaacc335b7ace4e9,
+                    0x4b496fba0516fe0c,
+                    0x2d0430e74589ba0d,
+                    0xac03e12b66e145e3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x34369b8fecca76d4,
+                    0x0fcdc51d55375cf5,
+                    0x282fd55b2a7b673e,
+                    0x439da34dd394b04b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb1195eb1c1258dc,
+                    0xbc78ca9358ae4cb4,
+                    0x1702d2e0282366b0,
+                    0x4f85c153a1984287,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa37ff4f0343c7137,
+                    0x99aa9864466d1ed1,
+                    0x3eea682c4d3df0c7,
+                    0x71c6d19cc0b4aaa1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd75f90e43d653c51,
+                    0x72be3b29b9d97019,
+                    0xe841ee400c9be003,
+                    0x9cc55bd9c53cce09,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa60bfd8449ed9664,
+                    0x4d1bd40d7fc1d52b,
+                    0x17e4598f83de7615,
+                    0xe59a0d28c18a09b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x600e30b46a93d43e,
+                    0x9028dcfb43cbec60,
+                    0x5be87b7ead7da800,
+                    0x74a8620230258117,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7865e79c5b684037,
+                    0xe990f36ca1cde662,
+                    0x60e3a53fefdbdb35,
+                    0x16980549e31f5f70,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35920075c3c222a7,
+                    0x9e0078b99a622845,
+                    0x2f47e52121daa45c,
+                    0x8685bf2965df7cb7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf3a599166c521f74,
+                    0x9bcf0cfedec2e9af,
+                    0xa96d4030abef52ce,
+                    0x6fc519422d96c579,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ba960d03d18e62e,
+                    0x15d7885781eb6b38,
+                    0x94ec2d8f0e157629,
+                    0xd140f7c5d305cd85,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x2ebd3ed357e4f49d,
+                    0x5725549133bb66c9,
+                    0xc2dacca805939b95,
+                    0xf259052a2c57bf9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c6655e821e0d425,
+                    0xb3911919210409ee,
+                    0x93fba6adc12f38ca,
+                    0xf1f526476f697107,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5f830de785857dff,
+                    0x0cf81a4822b04891,
+                    0xda71a700a29574f4,
+                    0x44774c965e9d426b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa1dcdbe7dbb022a,
+                    0x5fa5a0f384c15636,
+                    0x876fd26b82eb04bd,
+                    0x3a800757b04fc0b6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x45176f9d83ee3464,
+                    0xbbfc7c822e3e7874,
+                    0x7c37f801778bc99b,
+                    0xe476565fb034a852,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e7b0936351e8b2f,
+                    0x6605787838cad49c,
+                    0x82bc6fff429ab57e,
+                    0xaa21bb7d35288c9b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3643f307eae353a8,
+                    0xd1d3d8801344c2a6,
+                    0x6f2d1fca189f2fa2,
+                    0xd399c58741695112,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ac22d6958bb435f,
+                    0xd6e9e5505213f139,
+                    0xea70539073bdcd57,
+                    0x49ff2eedd27b4896,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 186];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x4a5298b044f41cd2,
+                    0xa8b6961fa16f5f7b,
+                    0xef5a5a04ccfae349,
+                    0x41e4bc8128c50db9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc74865485f4efe02,
+                    0x8463817c254e2e15,
+                    0xa8eccbab9a1a241c,
+                    0xe76c91f2e606d476,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x923a3b45a4ca638e,
+                    0xa2f3e7d7d81d6f0f,
+                    0xcc923a034dc0f323,
+                    0x6ca4619888332804,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0bcc4405af603bc1,
+                    0x1f5833fa8a384928,
+                    0x735d0815aec70363,
+                    0xa5bac3a380ad230f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3dd257add259dd95,
+                    0x12c5b9948377fd87,
+                    0x5605f7648a1928bd,
+                    0x0df58718a6bf8d41,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6d4ee2bc6ec187d,
+                    0x5d761db52bef1883,
+                    0xbc45c68cc1ad8878,
+                    0x3d7ada3954adb920,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xac956497dc8e865a,
+                    0xa97f0c6ec2946226,
+                    0x38cf941a5a91a548,
+                    0xb8eea0adb36d5f17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5b9584f62560787,
+                    0x8b3afafa7ca61e3a,
+                    0xa1fcb5b7d180a85d,
+                    0x2f2aaa5ee9a0375b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c82a67c5964e4a5,
+                    0x129b6130f2951683,
+                    0x2ff95659b8ee0640,
+                    0x6144ecebdf1aca8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0765b37bdc955cfc,
+                    0xc67e555b1d85e32b,
+                    0x7f6293e2aee9e568,
+                    0xc84ee4217f911cb9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fc678903aa5618f,
+                    0xde745cd7d28c0bda,
+                    0xfc0a9eb1b1209f0b,
+                    0x696a18c4fe61abea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x041484b2017563de,
+                    0x8537706a92979bcf,
+                    0x475f38acefb3af79,
+                    0x87422199b0a4a34f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5987a59d4bc909a3,
+                    0x0f8559ae71a40362,
+                    0x6317fe332e53876f,
+                    0xbd35ea8e4a0671a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9969b040b048741f,
+                    0x1b92e09133cf7ea8,
+                    0x83c25bf274757296,
+                    0xe9bfa5ac14463790,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e38595470e8925d,
+                    0x389595b41dc66f04,
+                    0x5d201bb7e1dea4d0,
+                    0x7e0972cccb75b114,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf221c3db6246fd51,
+                    0x2214e4d1f6fc464b,
+                    0x35e578dd6decffcd,
+                    0xe945541d51c15716,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5b22c6f6e4771fb,
+                    0xbed52bef5c4f57e3,
+                    0x1379a6f42afb1100,
+                    0x2807726806f55743,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70b686acece724b1,
+                    0x417d3ad8efc1fb36,
+                    0x2c643385c63e5698,
+                    0x2d82849c39a2c631,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x85ca52bab4308b06,
+                    0x0d143ea3341fb31b,
+                    0x93638bc4798cb110,
+                    0x335711c6b0c2424c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe80a6e5458c3460e,
+                    0x5d9139cc09da0c90,
+                    0x06c9fb6c99bdc274,
+                    0x5ca5f74f2d2367af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50d045ffd309cbd4,
+                    0x936c0f92b0dffad5,
+                    0x6ad0d7d5f5bba64e,
+                    0xb6e0c004190e13da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x709d64267ffd5bf3,
+                    0x0232ffcda1aeea4e,
+                    0xd97b37ea19681cff,
+                    0x811bbac6ab015b60,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x486bc8fe92050e5a,
+                    0xbe3ac0e4e73a2630,
+                    0x2ac66d5fb0f84737,
+                    0x198c0be2c0e440c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa95ea5096caf0e1b,
+                    0xa6527757829580ef,
+                    0xe00742929a62fab6,
+                    0x3578356a3d5cb469,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8df9cf0c82ea3777,
+                    0x57858eb439568842,
+                    0x76aeb6e804e36edc,
+                    0xba826b352dd85053,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0dd4cb251295d53,
+                    0xf0cdd7b5b360b4b4,
+                    0xec106dbd41c5944e,
+                    0xf1e3dd1c31a2e154,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb078cff3d5d2be8c,
+                    0xa7646f6a9c9b5301,
+                    0xb660f6066e3e76fd,
+                    0x3e8827049dcd87b5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55907d31f9c38f7f,
+                    0x007762c0879e70cf,
+                    0x465ef1c616391499,
+                    0x7d1eca3f7857eb9d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70e48b24c9854bf5,
+                    0xd6c9d88aeee5fabf,
+                    0x8d645ff1f7ec7f2b,
+                    0x502d7b91254410eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4e042d064ddb6b3,
+                    0x802a4395f2fa9b96,
+                    0x443e2975f10256cd,
+                    0xc8eb566372ab6043,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd957b3e1e2471329,
+                    0x4af6cfcc3724028a,
+                    0x6d361e0d91355d63,
+                    0xdaad3a57b4fe5b72,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x312ac85c5a26a097,
+                    0xa17f8c80ce418a9d,
+                    0x3637b4c6ed63f512,
+                    0xf7bfc86c5ed085e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1688e2447d1b80f0,
+                    0xd516af56cc18f1b8,
+                    0xc0442f0ee5f9bb9d,
+                    0x71d5b89fb09b13c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee6da3151a45fc88,
+                    0xe3a9fe7909b98ca9,
+                    0xb552ec174c9e0ff8,
+                    0x392a8727435b7409,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee3866dc8d4bc1ca,
+                    0x1ec8bc73f89d6420,
+                    0xe695732b5052fca5,
+                    0xdac169a3c3031b37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3f03917f648d892,
+                    0x65b118119b9d4fe0,
+                    0xd354e9d7154a7477,
+                    0x65866ca91380e96b,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x8c6d643d1f26d315,
+                    0xf009020c9583375c,
+                    0x8c582bbafe51b296,
+                    0x45c32717de27e193,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd73a837209f4aa2f,
+                    0xc6e35317f2933f30,
+                    0xf77fbc685294d684,
+                    0xbcfa2f214717a9ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14e566aa1ae89bf0,
+                    0x5c075fee60d478cb,
+                    0x6aa17082e61048ec,
+                    0x348caa7593b31364,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe87d5d1681dfc461,
+                    0x84cc003150bcad6f,
+                    0x96648f9ef5b40fdc,
+                    0x2039f6d98b946d69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b2ad5ec06d29155,
+                    0x4f370bb5201afd23,
+                    0xfb150d20d04f17fe,
+                    0xc1f3fdfb752c480e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2ed4ed7319dda579,
+                    0x7d1baa6479272b12,
+                    0x0e269288fc052d17,
+                    0x85bf3779320649a3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcdc90c1cd38a5e64,
+                    0x2789d2f05b851f1e,
+                    0xa38e4dedd942f4fd,
+                    0x6c2e89145acf3f39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0556694459d176f,
+                    0x6a71d67f500a3a72,
+                    0x084486111bde3822,
+                    0x1a2d598ea5904f64,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87dae8a3c698b972,
+                    0xf35ae021faa9786b,
+                    0x3ef2ce5df974a3c2,
+                    0x0cc89ff5bb8de1d6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x85975896b220dc6b,
+                    0xbebdb4249468c443,
+                    0x069636cff7089ffd,
+                    0x987c50754c7026fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b536b4714f98088,
+                    0xd5e36fb093e84db1,
+                    0x98d3b851b01c7793,
+                    0x286b6deee53fae45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73a19e16868a7cef,
+                    0x1e1c26ab5132173c,
+                    0x655053f445176217,
+                    0x2ff252ad854acbb8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0df8786b4920e894,
+                    0x8645305d7427f300,
+                    0x5714c83c564aa51a,
+                    0x79de7edfd333bc1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8627d2519d6be95b,
+                    0x99091f13fa772f41,
+                    0x57b15df1ec20aa73,
+                    0x6dc20987fc7115df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa86f7058f826fe6,
+                    0x5e711a95ebed7018,
+                    0x8a288c1511ebbaa6,
+                    0x1eb3e99fa5f8f365,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc749fda0571ec416,
+                    0xb6fd8834fbadb24f,
+                    0x43b0a9e0c46f2f46,
+                    0x90c014b2a14a7445,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f33fd95fba061d0,
+                    0x551f1364faa8f2d8,
+                    0x44ec8db25b565b56,
+                    0x252fb5eeaf1e351b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae870df9c5f409e7,
+                    0x006f89f1d3b68ab2,
+                    0x3fc75e5bb016d1eb,
+                    0xa0b44ee767f68039,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x451bf2d54b98a682,
+                    0x9d100d618a390a37,
+                    0xfaa85e9e75215e26,
+                    0x58aa00b5a603e0cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee32fe990f91566e,
+                    0xbe77cb7ceb9a6e8c,
+                    0x4e5ebf85dd532fb6,
+                    0x2238ac49d36d222d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa23ea46d5c22ba0b,
+                    0xfc01f6d8ad6e08b5,
+                    0x6a070e7f77b7a3ba,
+                    0xd2fb0693b25ce291,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3dc863b9c6da1e0f,
+                    0x8784192ca106a653,
+                    0xe98e7f90688e6c6f,
+                    0x2bcfd503b8772c35,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefed85c8ad3528d7,
+                    0xfcebcca98566a3a9,
+                    0x537f06d2a2f2c553,
+                    0xb71786481581c6f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e3df71f20eb6438,
+                    0x7637732d50d3197c,
+                    0x3291433b35a77770,
+                    0x2b0a1487aa057b31,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x49033615abe2b7ed,
+                    0xb3826e6c3f513191,
+                    0x5ff6610b06cdc5a4,
+                    0x4e3b2b23b7ca7e5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7765d72aac5ce705,
+                    0xd3dd9392b90b8aef,
+                    0x844c07559def3fcb,
+                    0x70487215c818f4ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x347a02c45110d267,
+                    0x9ecaecbe6caae791,
+                    0x79551fc6d267022c,
+                    0xad51aa6d0b0a7543,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x133a606e6b17eb00,
+                    0x591d4d9d0658d560,
+                    0xeb90cec42dc37f1d,
+                    0x722fc71e526fa85d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85b61a5ce3137d9a,
+                    0x658aa604eddbecad,
+                    0xf663e71ac8d82c46,
+                    0xeb99580e0d171590,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7410a94bb4b2172,
+                    0x70215a9325b880a6,
+                    0x409cb1038caceba4,
+                    0xfa456f1f7b3d8a2f,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xced680a5609ccfe1,
+                    0xedc43fa57a65e893,
+                    0x49abc97a644cb7a2,
+                    0x454d4d4f99deb75e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9eae7256358c858,
+                    0x85e33ba0810412ae,
+                    0x2eda496e74ed7431,
+                    0x3cebfb0b22eeaa3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ed011fcdf3df82b,
+                    0xa5db6ae696458165,
+                    0x16aaa757463a761b,
+                    0x71891aaa783d1d28,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x231f8f9df1fa5590,
+                    0xa2ea361bd3d5947c,
+                    0x377b0cfcd7bd3f83,
+                    0x29455d4df66721e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf58975e3b21bd4ba,
+                    0x1b7c74574862d5ae,
+                    0xf1074d187b1cc24f,
+                    0x901bdf9cb1168db3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6901bfa3df1ffa79,
+                    0xcf050609269aee64,
+                    0x28c3fd7b8722975e,
+                    0x8b19afa8be802f67,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x237456c3195afbb6,
+                    0x7af5696435d5f809,
+                    0xa407254bd24c36b6,
+                    0x7ed851b94b125e51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4fba957957f603b,
+                    0x7a455bc097b15357,
+                    0x21dcabbd43ab5b90,
+                    0xb5664210ccb7d6a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfabf20f882a76422,
+                    0x9f900c774bc04c75,
+                    0x513a804e39a0c044,
+                    0x6b684f15ef81533a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab4608bd7962cede,
+                    0x8422c972bd2fb2a7,
+                    0x7868d71313fed12e,
+                    0xdeed07ef88d6a86e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd82ea257f3706062,
+                    0x6043511552e282ba,
+                    0x2671b176119e65e4,
+                    0xb413e87ad1a05f80,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x83c744ef9e6b0ddf,
+                    0x946941160c2fdc2b,
+                    0x070a058d3ae30302,
+                    0x9017b8a8e2a195e4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x140f6236ca570108,
+                    0x3b9c439490d0d7d5,
+                    0xa53b7a2bbf1728c2,
+                    0x8cfb165831c88fab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c1edfdb35a252ff,
+                    0x344f5b397fdc5ab3,
+                    0x2d5ea9e0c40683b0,
+                    0x0f502279a4be6013,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14eed87431a9d4a6,
+                    0x2d4dede75a630635,
+                    0xefbb5b65bd7efb46,
+                    0xfcb48be9dce1085a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74e7d1c5c4e5f8f1,
+                    0x7c3b3b6a66832a43,
+                    0x5b4589f7f5662142,
+                    0x5a1f0dc102beb604,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x313a1b5fa9a589ac,
+                    0xdcd1970163b9ff96,
+                    0xa71add415b45fed1,
+                    0xcc7f6d1c8195f9b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb24497365ba2d662,
+                    0x53a23868acfa0a32,
+                    0x91591bf00b4ed3f2,
+                    0x97780d83938b04d9,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x786ab0bc4d3a294f,
+                    0xbb61a59dbde02db6,
+                    0x09f59cc6988cdc65,
+                    0x64ea1a5136e623bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20eba2bd07cd5591,
+                    0x6bfed4be36d66449,
+                    0x0767160a2f4ccf4b,
+                    0x3c1fe3beececc3a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x306d370e442c728a,
+                    0xaf9f1df44c9fae92,
+                    0x404ed38a36b8b914,
+                    0x29c5b6a11f45e947,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4971019026695d34,
+                    0xe47cd8d04f2d90c2,
+                    0x0b0dd68c49ddcca0,
+                    0xe05edcd55ab9868b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x120fcda6a80c6f83,
+                    0x51e491c368b9f27e,
+                    0xdcbfed775881ca51,
+                    0x035f1df8b0fae48e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa019493d94770291,
+                    0x13d418c92f571367,
+                    0x0a0fdebe0ed9d669,
+                    0x3637412317d40671,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x0c32b4d15b314913,
+                    0x36540404644dae5d,
+                    0x16f3705ee1dd97e0,
+                    0x450babca3dabaa79,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x468d57fee46b8e9d,
+                    0xfc4391588a070304,
+                    0x4a498df3aa4cd4a5,
+                    0x1e23fc590501321f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeca4a2879c136b4b,
+                    0x3a57b52f034a7a81,
+                    0x513bc8b3d7d1fd9c,
+                    0x6ab29df2306dbcb0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca654922d1930b6a,
+                    0xee827f938ea7cdd4,
+                    0x8e2f5e41d1aab5c2,
+                    0xfdb0ee3118c816f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8919742b18eaa330,
+                    0xaf30ef89e33db488,
+                    0x0318c219b7e75103,
+                    0x312c231133baf588,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17b1822fa84621af,
+                    0x2fea4b1d7aabe4e7,
+                    0x3c27ea097f470bd6,
+                    0xba82770cf9338006,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe3ee42f3352d11c8,
+                    0xec5cde4ace047199,
+                    0xd5a2e03a559a7c94,
+                    0x0c57c7010f698016,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60a5b81f4af08ec2,
+                    0x385a1d1a1c165e7f,
+                    0x14d7dba3673d401e,
+                    0x248fd1d5fc31fc5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa23e7bec3a32664,
+                    0xddd73c84a48704d3,
+                    0xbe731e146f4bd300,
+                    0x1042707bc73243e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x80d271ab945043f5,
+                    0x8991f80b03706285,
+                    0x7fde8dc800173fea,
+                    0x97505c1352a29f39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe08fee17333074b7,
+                    0xc7afec013b8247c7,
+                    0xe39b9d273e0ad9d0,
+                    0xc863253fd2835d8a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdaf4d4ca7b32501d,
+                    0xdd373c8a6a1fc730,
+                    0x985a5486be969339,
+                    0xdeb1a4bbe786efab,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xb8baefcc73cdd7f3,
+                0x5844d82c39a24f1c,
+                0xee7958a636f6d209,
+                0x374a00aeb8f29ec2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x72aac1e8d259b9ee,
+                0x966fd9643ab63569,
+                0x9fa02f48c20854f3,
+                0x67ed2c4e32c6ff1b,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x05f95b54dfc02f45,
+                0x85568991a638d1fd,
+                0xeac5a25cee95d0e2,
+                0x93248298bbed7073,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x548c61f44da4d9e2,
+                0xb2bd4a8079832931,
+                0x04ba4315ec3c79a8,
+                0xf0da1111b6faa0fb,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x813d383272f658af,
+                0x9f0a4a0224b4e4c6,
+                0xb72efc777bd6cb5f,
+                0xdac149fe1b68a3b2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xdab918c68f6fbd25,
+                0x8dbb6341bf2e20b0,
+                0x030234a2b2130876,
+                0xcfeced800d2b7105,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd584078a61f928b3,
+                0x8cff2b7f63372b04,
+                0xfabbd23324baf7ae,
+                0xf2d49c5b2a145a06,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x03608d9950eda4a3,
+                0x3ea52ac65529560f,
+                0x18617dd738a3d2cc,
+                0x28a507d893de9df0,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x66d8d7e95a746c96,
+                0xed0d7a22626569bf,
+                0xa2b12d62858fb29a,
+                0xa659e94274fd91ba,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4d5e20f8e8e15f74,
+                0x2dca175e2fe2111a,
+                0x8c55f4a7d2406cd6,
+                0xdb592c4d91db4be1,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 124, 125, 377] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 124];
+        let mut opad = vec![0x5cu8; 124];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
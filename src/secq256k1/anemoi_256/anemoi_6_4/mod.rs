@@ -0,0 +1,1631 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 192 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 4 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 4;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 17 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 17;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9e289569576d5ebb,
+                    0x8dbb00b83e762318,
+                    0x784320d268dd10bd,
+                    0x775740136fc7f3a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd2a34853e954d41,
+                    0x024b46b8583cf68f,
+                    0xf9e5b7ed662b4b8d,
+                    0x684ef9b9d56b9690,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88e05a79f7f8d425,
+                    0x8a7dcdf3b00995ea,
+                    0x6f321f04f12b8c97,
+                    0x5801425a5b0216ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x726d123edf4b40b2,
+                    0x0ab0fb25b06a1bca,
+                    0x4ef1b92d0ebf8ecc,
+                    0x21c5b28331aa2279,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65795e39e11fd332,
+                    0x18ede8567de01e3d,
+                    0x7350b086c3e4865e,
+                    0xbfca2f23f08cb434,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbaf7efca52806b83,
+                    0x4b6dd5062d477658,
+                    0xbc5893983b08397d,
+                    0xb329243831c1ee33,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xccbd88d4093fb516,
+                    0xb4f050bdc61d1900,
+                    0x401c83dc2c812dbf,
+                    0xf26ac18b17a5f578,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee64e514c9b8aea8,
+                    0x0ff34909f17f6e5f,
+                    0xe1010e9856685367,
+                    0xa75d4c4ac9faf5da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8216f491af732036,
+                    0x5bedf09af6ae55b4,
+                    0xa17eec1fc6ba55b4,
+                    0xc9d8263cb18e301b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57c27d3d1ca4d66a,
+                    0x6ef558a986ec9666,
+                    0xb9147ad5875e4abb,
+                    0xfd357647b2d867e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b4204d992ece90b,
+                    0xc2aaf1f7de5faf78,
+                    0x9907a1f8b5e6dd96,
+                    0xd397087564c1bb8b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17ab2e1ce990032b,
+                    0xf88e77abb81e9875,
+                    0xdb67f02ec20f2b34,
+                    0xdf56b543fce0b2bb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd0223b3642c91b,
+                    0x30ed602f7757bf3a,
+                    0x27e1a9a3169395de,
+                    0x01a3ac9900466ed6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff5010a6654f77c1,
+                    0xb8128fb0edd89966,
+                    0xe3f373b0a7c2c6d5,
+                    0x69a4b46c0bc374ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2abb19bbbebc405a,
+                    0x06209d97e44aede0,
+                    0x78f950568b509d6b,
+                    0x491f42bf1483f219,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64a300cda378d275,
+                    0x44abae24d139d511,
+                    0x1353080d58b8be56,
+                    0x4bb8388f8f6f75e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa755db56ba45ca1a,
+                    0x749e77f00543b981,
+                    0xc5e12e27a308f372,
+                    0x57aee00e0630140d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ee490e627d9c380,
+                    0x446acf1fdf4d1422,
+                    0xfa3a7615e7229392,
+                    0xd4d1123e1c9f5a95,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x890ee23a0d02d972,
+                    0xba14b2b1ce3d20a1,
+                    0x8f1cdcedc9dbeea2,
+                    0x55f7ef4df60e7733,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbafb624e939f767d,
+                    0xea24ae8e4f5c0376,
+                    0x9f2ab59df9899d06,
+                    0x0b99a20b37140d94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8479f87aa2953ecc,
+                    0x2ac7b0a28402a385,
+                    0xb032658fd62384f5,
+                    0x9cf543758ba78414,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8a1c7b74e4f0a55,
+                    0xdb0320c4d9a12319,
+                    0x29b4437c25a668a2,
+                    0x331f49a3466bdc68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ed8332a945f8bbb,
+                    0x479ceba4199ff707,
+                    0x249340a9bbbfc296,
+                    0x8f59d1b1097d398a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4258c87ba4f3c41,
+                    0x67adb94054e25756,
+                    0x4628b5a98aa3f006,
+                    0x1dccc8f127e63f54,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdcb7c35f49326f81,
+                    0x6e162792c8f0c00e,
+                    0xeeb7d247ec3657f7,
+                    0x79b06063ff57f381,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8cd46bde30ca0257,
+                    0x041d7aa4f5aa2d0c,
+                    0xaea2613dc3fa3fb8,
+                    0x776d2f74f0acaf88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa3fe070797884f3,
+                    0xc6c11aa18ea2846b,
+                    0xeec6560e86a97395,
+                    0xd38a06db77200049,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x212e776ebf58da74,
+                    0x42c532ced0ba352d,
+                    0x81e28ecc3c7e71ff,
+                    0x835a12abd86ad05d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4caadeb32484b33,
+                    0xc6f3cf06ebf04c15,
+                    0x5bdd4b9242b53596,
+                    0xede38c82bbb1d604,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x813ed43a618da421,
+                    0x51ec6bfa66cee720,
+                    0xf91c83fbf8c49c9e,
+                    0x6cfc66c73871bd08,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf15bb46d641fc5fb,
+                    0x364065005a2274a3,
+                    0x7fb04081b589ec53,
+                    0xdd3562e4d8e030cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x358ba5d055c19e88,
+                    0x3cc88f488806b71b,
+                    0xcba2d33847e486aa,
+                    0x5a51f5777671814e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18bb45ec493e34f7,
+                    0x87fd8dfaabad8473,
+                    0x4d09505c54790a0c,
+                    0x3c23b49f5525a9ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5982949f506f9c99,
+                    0x0e38776923b6bae6,
+                    0xd36bd3e91f537fe9,
+                    0xfe7de4ac0c691d7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb5e86d421767ef,
+                    0xe816275e348e6f8e,
+                    0x55d0fe142bbfabe7,
+                    0x0aab08577f076f5a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a10317e81e4df52,
+                    0xaf10bbbd096b4ed4,
+                    0xa4927dd73d66af5a,
+                    0xc21114b19a497373,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x848e17a36911b752,
+                    0xee02169d0fcd6099,
+                    0xc754e938b270c956,
+                    0x995d0f134d84b97d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69946bdc9760e89c,
+                    0x2e04201ea12ea49c,
+                    0x00268dd04317714b,
+                    0x18495c52d4198bab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa9a23b6f2600b0a,
+                    0x12be6c8bfa4f89ea,
+                    0x5a2a3de66947f019,
+                    0xeb328688013a2017,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f665dc0374a403f,
+                    0x9cd97dd4edb035ce,
+                    0xb6ef438340bb5e9a,
+                    0x2f02ec88f01e519d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf5041f50724579c,
+                    0x783d98cce757adc4,
+                    0x50748e516d21c26a,
+                    0xa40af66d980e206c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1334524fdde4c827,
+                    0x4e21e30fe07eb8ca,
+                    0x99fa4adbc3791d88,
+                    0xbd639639b86c6f48,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1bf1e67f5ef7fcc9,
+                    0x7dee29e0ab0015dc,
+                    0x5cd5981fa4a34426,
+                    0x5251f5ec6e812b45,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2fe50f8461943a58,
+                    0x2b54ae5bba0d09c4,
+                    0x5889dfc4c03ac97a,
+                    0xff0ace171138501f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40c6999dedb0a0e9,
+                    0x181aa0dc25409b18,
+                    0xfe92e589ba774f48,
+                    0x886e9be731273a73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4b0958707d57119,
+                    0x01f767fcb05f50e4,
+                    0xd49bb4eef2c082a5,
+                    0xee373a204f91a149,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f63bcc99b3472c3,
+                    0xdfb330f9da624d96,
+                    0x136ad73eed31595a,
+                    0x4a8d86a8280e0bf4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4c52f52cdef1b1a,
+                    0xaca6bc9d46d6222e,
+                    0x100acd129475845b,
+                    0x3b88923fe76d3c6b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe37b567db867e98e,
+                    0x1ea59cc348b6bba4,
+                    0x2ca4cc67849d8f43,
+                    0x10995a1f9ef0062c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x623cce0b51181d51,
+                    0x6b7e997fadc05081,
+                    0x59022d112dbd9ebd,
+                    0x3b7f7c2046de9cf6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0797f251b4a5d03,
+                    0x763031fd990977e4,
+                    0x023f90ec9891c874,
+                    0x25a9f9f0761eccaf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77a2cf20e84ee30c,
+                    0x76ab5873bb486a98,
+                    0x0bc0c253309bbadd,
+                    0x7ff07b9b8374c900,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeebfc43a6979a10f,
+                    0xbfc43ec6179fe6d7,
+                    0x069d960479d82380,
+                    0x76d14a63bbc344ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc281fc9162921808,
+                    0x06f959c5416cafe9,
+                    0x0cb74e7453803c0e,
+                    0xbba26fab4a91027f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0c25697d32864e33,
+                    0x320a2f025315b3d4,
+                    0xb7671621468de515,
+                    0xe5efe2fc36fbb560,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x405b3302ff0ca5d4,
+                    0xbdc36dadf33e5f4f,
+                    0xc9a4a810b55de1a7,
+                    0x3eb105f576777580,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa54c5a1b38759357,
+                    0x6e7da62e845c5353,
+                    0xf39c45d215dc6849,
+                    0xbc05ae6aa440c412,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0f99a842f6e8232,
+                    0xca4006208162d980,
+                    0xfff4f32e7c8cfa15,
+                    0x13f1795f8b782ae6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfaac4a1dd955ddef,
+                    0x673d944268318ce1,
+                    0x278b6c14458837b8,
+                    0x406eaa1a0acc9cb3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa519407d2f8516b8,
+                    0x0ba1ac1cfb8fdcc0,
+                    0xe8318d2b7af4cabc,
+                    0xfd94d72968bd57ec,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb4e354dfe09a5836,
+                    0xb11e13a6f4bcd464,
+                    0x3231baa56b66b2c9,
+                    0x8e06ddacbf5b0b84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfcb78b9f9b41376a,
+                    0xa1bbcf07ad4fdf33,
+                    0xba909ae011fd42f2,
+                    0x2fb87bb443dde642,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcff8b8f6f462510e,
+                    0x2a1c6fa40f9cf63c,
+                    0x854e5d9769dfc491,
+                    0xdb9b8898632bb3a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd7b6098d7afea83,
+                    0x7ab43666cd934c59,
+                    0x3826e1f77baaf6c0,
+                    0xe806aa7f27d374ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xddba65aa28e2363b,
+                    0x6127d21b774c6c94,
+                    0xd418c8fab4ccb034,
+                    0xcbc56ec0e39e259c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb2d9c6238365495,
+                    0x54dd5b404011cd03,
+                    0xa3fad8c487e78c94,
+                    0x425585db743e04b8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2cd5f63d7f8b41ef,
+                    0xf8a345d5e2ae54d6,
+                    0xecb91982c48e83bc,
+                    0xc8f4a258d3d2e18d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71d74d7506c7ac30,
+                    0x80b7f70642ae473a,
+                    0xdb296daea8243ca1,
+                    0x2668e83da6c98ea7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35d9cee179e70bb8,
+                    0x0489133def43c1d0,
+                    0x49fd256e272b3a9e,
+                    0x00e71dbd6b9e8e2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5e548128d93ba31,
+                    0xb7b8dd1c90000a23,
+                    0x00607e812b70cb82,
+                    0xa1db20e965862d87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa46acb299666e317,
+                    0xb8cd11a46c06800c,
+                    0xb6ea7d7ccba1d406,
+                    0x1e5c4b089ffa2bb9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf64f2c4a3b6b4fb6,
+                    0xed3dd6697c6c5b0a,
+                    0x33fbab92e1c05b05,
+                    0x2564356f3ba54043,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1905098ce777067d,
+                    0xa06a5154bc2f6fed,
+                    0xd0babe6c13c06443,
+                    0x81459510616e7b5d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc888cf0b261cf619,
+                    0xc477c4b1fccb0249,
+                    0xf6f7c79c38c9d921,
+                    0x088a2211ff1ed09b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8479f111271b4434,
+                    0x6136248399b9e86d,
+                    0xb6827a9e84af472c,
+                    0x9f6941a7b2a8ec42,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9eac00e6912e5981,
+                    0xff83838674744da2,
+                    0xf3dc7947dce2dd4d,
+                    0x250cd9cfb9bd5bd0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81defff4481e755d,
+                    0x4dd8ea3167bf968f,
+                    0x2d9a2372c9470530,
+                    0x750e3e779b52c819,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7bb99523425d1a4,
+                    0x822ff9fcb3689cfe,
+                    0x7d70ffa9f1278b70,
+                    0xc3ec8c4f074aac99,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8de0f3236d4a9f17,
+                    0xf246a1f825f81399,
+                    0x37c3b18e14d45516,
+                    0xb778c1114c11f843,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x878ea53b2b83d673,
+                    0x65ed28415864dc13,
+                    0xb71c2db913d5cd25,
+                    0x64caa7dec47dcbab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca1c909906f8dfcb,
+                    0xa3453cb1f7f462d4,
+                    0xf694494986d4305b,
+                    0x5b7af3d0b22a7410,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3f2aab7457f96fe,
+                    0x967beb9ef40f8442,
+                    0xde2d83aebd5914e5,
+                    0x2da26cd2892682e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ab7e2b3c48fc499,
+                    0x3aead480c5174fb5,
+                    0x499a06f5ae919b5c,
+                    0x71831faffef90151,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae82067b72b858c2,
+                    0x1109addf603c61f1,
+                    0xcc4e2d91bb3a55a6,
+                    0x1853d86b13ab9c4d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x441be1702131adc3,
+                    0x8b34c828985f6586,
+                    0xd9578437c820f606,
+                    0x0b143bd5e9850343,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd87fbe2bf8858c6,
+                    0x634810c6da3cfc46,
+                    0xeed6dc266381a668,
+                    0xada9dbb03a760810,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x35dcd2ea6c0a9e00,
+                    0x4ca1ead9f2ed82bc,
+                    0xcc370ea9d6630cb7,
+                    0xafc73077118372b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaac7f7c4447ecd7c,
+                    0x8c51f42f06935dd1,
+                    0x97edeb10ce44a239,
+                    0xabd5a1139d5555c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6d59d935b72ea62b,
+                    0x3a6559cb7ff04a0a,
+                    0x184ddd1c0190f34e,
+                    0x78dda49fb0a8ba29,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb60c7f0bbe7c882,
+                    0xdbf31d5de198db03,
+                    0x671b279808868bb2,
+                    0xa0c5a04e022c954b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3051dc44174c40b1,
+                    0x237d56cae1044973,
+                    0x1c09b3437c043ac6,
+                    0x714312abeefee027,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e2c18e3ae318ef8,
+                    0x1d91e5d44dbcbea4,
+                    0x3c378cae805b875b,
+                    0xb386fa1d8a9eee47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa562fe892d3889a1,
+                    0xbbc004315f91589f,
+                    0x17d72f723016d328,
+                    0x4ee45f493935c93a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92f600f44b5997dd,
+                    0x0848d9766de7ec89,
+                    0x8fc70d6812a6232e,
+                    0x874b49c112ba4ae2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab0d84cf94e32bad,
+                    0xbb854eaecfcf4cfb,
+                    0xba36819f862a4f1e,
+                    0xd89b425e36634234,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde6cf741aa003375,
+                    0x2ecdabdee2e6da53,
+                    0x07c88fb336482647,
+                    0xff1e4d107bc81c96,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2a9b1d9b0c8cd0a7,
+                    0xfb048aa6565983b3,
+                    0x32a30bef42732cca,
+                    0x047baeafe172130a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa1dc3df5bbc55bdc,
+                    0xf03b2c252638bd36,
+                    0xf29606aa01412d42,
+                    0xded911a0c33fbb98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0b4c233df57fc34,
+                    0xee4f75a3a95276b3,
+                    0x7096c780dfbba1c6,
+                    0x1709c69ebaba1731,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6e84d72360986eb5,
+                    0xd43d83d055082f0a,
+                    0xa74ab40fb087bb38,
+                    0xe890cd1c3d722ef1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaf24a934398c169e,
+                    0x6dc85f62355e8211,
+                    0x057bf5103a9ec058,
+                    0x1fb3eeb2f167f611,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe345ccb316f58e00,
+                    0x138a1147eb7099de,
+                    0x05b5dd9f9acad26f,
+                    0x6128089f860420d4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9ee72324428c255e,
+                    0x64ee724150b90f45,
+                    0x6043283591787a05,
+                    0xcd4b6dffb31e937b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3990952d813d726e,
+                    0x76513efb170cdbe9,
+                    0xe07fd68ace924b3c,
+                    0xfb01f8e47098e3c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76d6d49ce95e37ed,
+                    0xcc375501cfdd1320,
+                    0x1fdcc34bd341283f,
+                    0x66bee2cc7573b81c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56417cb3b997756b,
+                    0xacac566399c57786,
+                    0x7f36bce9a27c668c,
+                    0xfb2bf4784344863e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45036aca011696a3,
+                    0x9359be55f129eb97,
+                    0xe2721633222f25bd,
+                    0x8632baf4ce87830f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f3aff6b34a7a74c,
+                    0xe7f1ea8876061e66,
+                    0x4b85d0c134277265,
+                    0xfa798c2e3ad28a9b,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4307b8a62c65aaaf,
+                    0x9a6354ae5c2d5c08,
+                    0x0000000000000015,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4307b8a62c65aaaf,
+                    0x9a6354ae5c2d5c08,
+                    0x0000000000000015,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4307b8a62c65aaaf,
+                    0x9a6354ae5c2d5c08,
+                    0x0000000000000015,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4307b8a62c65aaaf,
+                    0x9a6354ae5c2d5c08,
+                    0x0000000000000015,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf61b510848c70058,
+                    0x4155af5882882304,
+                    0x30e18ff0fc4734ad,
+                    0x0e00d7d29fda0da0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8199f5dcde79d4fe,
+                    0x22a1a2052fed4c46,
+                    0xc543e05ded552fa1,
+                    0xe5b082b8432bc1cc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfcdc88c360a062b4,
+                    0x9ddaaeebfeae7943,
+                    0x6295772f47bbde2c,
+                    0x30da772c5bcd1c6f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecf7c768ed7d024f,
+                    0x47a9173a289c9d64,
+                    0x8925e57f22769f69,
+                    0x94c54e89afcbcbea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00a9e27ad41c3706,
+                    0x5743c30debc51289,
+                    0x56127413c4a29dc5,
+                    0x3c54bf74b6caf769,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd4371fb37d505bac,
+                    0xb7e6714240f7879e,
+                    0xb08471154efb8d15,
+                    0x1c5d1b64004b811a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x48e7ceb84bedc37b,
+                    0x3cd5365ccd838da8,
+                    0x29dc0475f119f8c4,
+                    0xfc684eeeb6612e4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5cc070e758f9d054,
+                    0x9f0bdc633831c2cb,
+                    0xacede049d87774c1,
+                    0x9ca013a4efb8f065,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73b6030c1e77c99a,
+                    0x03b6823aedb6a6f0,
+                    0x340a51e52c785128,
+                    0xc492e3288b260993,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe4ba9913b223ef35,
+                    0xf6d55e5bbd07e08a,
+                    0x0a8a82b51a8fd7ba,
+                    0x11803c7f54a7bdf7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x250a35a54d8efd0c,
+                    0xaea916d22277af40,
+                    0x2926ce4e973b834a,
+                    0xc947f1dcd2b231da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e04c876a7838c2c,
+                    0x13e4d24faa153378,
+                    0xadb1e1f83e8eaa15,
+                    0x608c230d95a1286a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1e5f676a48252f58,
+                    0xc1e47b7318f07cf4,
+                    0x1b4b733357a1bc5e,
+                    0x84853e1812b9b7fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcb91c8fbc3f51244,
+                    0x617ff463aaafd3c7,
+                    0x5dafc7030857f578,
+                    0x87306ac79e932e69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20559a5143b875da,
+                    0xc3ac97d6487da573,
+                    0xac728856b2cb6d4e,
+                    0xaafeaf00ae9c91a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe09fccd476b1a71,
+                    0x8187c915ec2877ca,
+                    0xe8f968fe873337fc,
+                    0xc65fcde8721d14c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55168ec4417ec6cc,
+                    0x472bd3f12325949f,
+                    0xa6ea7229adf7ee9a,
+                    0xcc7aac77002aa7db,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa45435f9c1d41c27,
+                    0x164f1282aa9bbe2f,
+                    0xa92a1e6ce1c1d515,
+                    0x9aaac1bf741f0230,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3ce4b800f1125a02,
+                    0xb763904e36b60dd4,
+                    0xdb3ea25be133f6d9,
+                    0xb4c289c6cc443950,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84bbcf419166cf4d,
+                    0x3342c344bde093c1,
+                    0xff238c114cff8842,
+                    0x4d08a7ca0a164f04,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0682a3163da6d86c,
+                    0xe703d5f71749987b,
+                    0x1852a2f9148ff5f3,
+                    0x1b40dc1a4cccd893,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x907f1553b99181fa,
+                    0x47bb6f1f4881c3ec,
+                    0x57b507effccbb8bb,
+                    0xfe52ae0ac4b48550,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcf18a9ff3d473fe8,
+                    0x7ee69c4947a27de7,
+                    0xb0706f2b3efd6bae,
+                    0x8ac893b635436afc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe001040956143446,
+                    0x3cf3a1ce347d801c,
+                    0xaf0d2877f4167355,
+                    0x72a867650b3936e9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3c88dde04ab58173,
+                    0x802c518e744ff033,
+                    0x0c64a22b12b7a1d3,
+                    0xbb04bc15a4a10d76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31964c6f1383d0ae,
+                    0xb4bd115e6e8adf99,
+                    0x3958871f61d5c67f,
+                    0x84992ea7bfc870fd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecfc70da22c0cb60,
+                    0x00fb8f6e7cb52d94,
+                    0xc5a221b5b2230898,
+                    0x15449f0ea8185811,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd1fedb096667ae90,
+                    0x4e4243a6c46120e1,
+                    0x6beb269ef3feaf03,
+                    0xa34de718fcd548e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x191e20921367240b,
+                    0xeee7ddf45854a3d3,
+                    0x9e3cbf1da91fb15c,
+                    0xdcd19318258b5f80,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c26872a69f61885,
+                    0x72e476fa577d16ed,
+                    0xd3644520e5a9d015,
+                    0x27a45ca05d4e0a3c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6948a1b6be71d03c,
+                    0xb4aad81142e7acc4,
+                    0xe17f3295f45f5d72,
+                    0x9b547f45b52b4085,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x190e0e68e4841a43,
+                    0x860790ae59aef023,
+                    0x1fcc55d326d2df05,
+                    0x97859a7b59e1800a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89cb287b5b2b2867,
+                    0xa4f4865e0045988a,
+                    0xa232b34d9c1ec9a9,
+                    0xfed0ddaecbe2a445,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3bb36e327756b76f,
+                    0x6d41589de6d1144a,
+                    0x58cc69a75983d4c8,
+                    0x056f0796c932d50e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc175deb8c73069d4,
+                    0x5e8b1e6221514ccb,
+                    0xa8771157c7bd6606,
+                    0x5edff66ce039b960,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99788ee4e4cdf4e7,
+                    0xf6a824c43b85e5e4,
+                    0xfbdb2910c5ede127,
+                    0xa1089d5823cba648,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
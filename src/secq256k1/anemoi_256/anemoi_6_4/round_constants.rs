@@ -0,0 +1,795 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger320;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x0000000000000000,
+            0xc6f3988c47bc0f19,
+            0x8f5c58db4bef96e5,
+            0x0000000000000031,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xdcc439163211d00e,
+            0x117f9fcbb4a62003,
+            0xa10a9a57632f4078,
+            0x6accec4f8a3e1954,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x89f42bf7f9a29e91,
+            0x67f456edb9a8a2c5,
+            0xa57c8f3443907042,
+            0x66f5c3cf97415800,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x182ec001ec41553b,
+            0x3288391dcc807c99,
+            0xd349fe0ce4da0882,
+            0x45b4c9d1fc8c977f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd657de674909af11,
+            0x3e1677842cbef72c,
+            0x261614195baaa308,
+            0x5fb21bf549cc34a7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0d8e1e6258b7de01,
+            0x36c464b8501ae3ed,
+            0xa3841cfebb7f45d1,
+            0xcb91b00749e5c268,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x5659f783061f8461,
+            0x7d2692d2d89f53eb,
+            0xdd7bf8236f44e68d,
+            0x8d9db9f45c855028,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x36dfcff69ef452c2,
+            0x70a013d4e4ccdfe0,
+            0x21de27b7b6792096,
+            0x821c16f0449a5a52,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x429a245fb1587b72,
+            0xae2bef917e8b06ac,
+            0x9d6c3fbf49eef9f9,
+            0x0cb70708d20792b1,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2e0452c5be429f7b,
+            0x4021eb4e3c1670ce,
+            0x0c9c6388712f198e,
+            0x7e77c55b55378c6b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x73e3e86cf3f12001,
+            0xb4ca2fca18ce3606,
+            0x94e6ab4112f9471c,
+            0xe37a87c530490774,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc50b200c5d6f3bf0,
+            0x4d111d827432313f,
+            0xde499b1c70e4a02b,
+            0xe789a4c459efc7a4,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x45b5d759325b2062,
+            0x88743d21233040ca,
+            0x88981e3f3b70948b,
+            0x311cd1089bf47002,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x1ad64e391c0c014c,
+            0x6fad0f7df183a43b,
+            0xad6d85cb5759e9b5,
+            0xf3e58d7703583060,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x813540269ebe9fec,
+            0xc9a346836f92ecac,
+            0xa55ca9903f42e44d,
+            0x8e10c20514dc7800,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2c68da049b5ddea8,
+            0x7590e3340cc7d095,
+            0x89c87a202e42ef9d,
+            0x281afe467fec2c91,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa1ea9614d20ebb4c,
+            0x59fe28bee2aaedec,
+            0x879fbb45165be644,
+            0x6256fc5cb70df84b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x7d6f756fed0a78c3,
+            0x6c59f9316357d328,
+            0xaecb86e3b3a0c60f,
+            0xf721dfdc086b0150,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xc0a2f570879803dc,
+            0xf1ad6a7739a72b7e,
+            0xf2884bc848ff5f23,
+            0x42e000d3764c05c2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xac461d22ab78911e,
+            0x9ee7865bfc71f5f3,
+            0xf6f33ff79c993c51,
+            0x94c640e726f19d1a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x9d761fc56118e96b,
+            0xc212d4f6d595309c,
+            0x717fb4465124f7c6,
+            0x4c8ecb071b30d097,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x99def4deb16404d0,
+            0xb6871cd83e4561f6,
+            0x7d9fde9a11f46a7d,
+            0x44a385ad6b108747,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5531586fa5d54040,
+            0x4eb684de27ea53cd,
+            0x7d9930f95f48a5d3,
+            0x6ff5fdf2a72f0240,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x526d81c93af14b61,
+            0x41d149686dc534e4,
+            0x94661bc59ea20b7f,
+            0xd1659e34b74385e8,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xba9219e3cf450d14,
+            0x26b847293facdb16,
+            0x55419f2396733196,
+            0x822bc7e3a5d526ae,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x63fb389566645e91,
+            0x73d453187b9c3be1,
+            0x3ce878f59456095e,
+            0xe86104d0e8da92df,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x620d0d8c4566235b,
+            0xe5745db25db62a15,
+            0x6ae900d92446ece9,
+            0x0a210bf912f839e7,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8ed0b343602605ee,
+            0xa6ecabe154da9f95,
+            0xcd6ce6c11cb2e4f7,
+            0x7036e4bccf7f5965,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x331e575a822d2d32,
+            0x860ded80a34c4636,
+            0x44bf0a49f061deaa,
+            0x38b8e3f119e75675,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xdbb9a51ef264b8b7,
+            0x4a4f575b37a57364,
+            0x11c870d36d612108,
+            0xf6bc10857e6e86f9,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6aca2cc429911440,
+            0xb625ceeed27db93b,
+            0x026f90b6f0cb8418,
+            0x3e18ef6fc7c86c88,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x62d82d1ca2593aab,
+            0xe0f67e91ebd1b8cb,
+            0x53807f2ffa7c51dc,
+            0xf71e7e09b37937be,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5d7a762fd74efd4b,
+            0x89b7498e509ac66c,
+            0xdf595015a18569ab,
+            0x97cdb5166bbc0267,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x26b1c8a9eaccaf8e,
+            0x099cdb47f4b28c51,
+            0xe026ef4053cc4c66,
+            0xcebed7f6785208ef,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xbbbc2670c3414abb,
+            0x4e1f217341215970,
+            0x670bbd63e35ed397,
+            0x2284f1cb4ba26910,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xfedb30b223bf7e24,
+            0x0d4db8c4b1cb1184,
+            0xf39948b5afde030d,
+            0x398e0a4b02ec3d04,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb737db99d3e43355,
+            0xbdb886bba2c43c7e,
+            0xab4fd264ccbe693d,
+            0x1267632fb34fa676,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x2d6e120ca59cd28a,
+            0x37cba00c693dc108,
+            0x68368d016283f401,
+            0x44d3cedf88c5e921,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8ab8c93c13f78505,
+            0x0c82baaad5cd04c0,
+            0x178c204c56d6f5f5,
+            0xcb41df9a9fb27fc6,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x2ad6799b56987bde,
+            0xfceee5d01da0fee0,
+            0x4265d9ccf86361b2,
+            0x447eaa38c4f1aa7b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x730a11b11841d141,
+            0xa998bbc98c687f14,
+            0xf276c7f2c525f4af,
+            0xdab74640be8eb25c,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x26f88feee17895c1,
+            0x5d30c6e39511dcab,
+            0xfe8b59f1e0345f67,
+            0x600d7dbaa6de4f65,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xf9adfdcd99234281,
+            0x3168d618c8846d16,
+            0x8a4b2fb3b8b5a33a,
+            0x4f32523289bd84d0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x34d474931f6ed12f,
+            0x82bf2d99ab6ff269,
+            0x5c5b046e2af91b13,
+            0x13dab2bd62b7d396,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x440292ab5b5177c6,
+            0x93af28ef5dddabf1,
+            0x40aa93f7575e36cd,
+            0xebd7480d6eedb30a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x59f9c112ca889b19,
+            0xf5044b76cd207510,
+            0x6fa5873268b0d577,
+            0xbe8c1b27e6d4cd49,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc890df915553c0ef,
+            0x0f1b9111a36398a0,
+            0x321df9cc5836d507,
+            0x01629415f770b0ab,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x90fc7b2fc8f0d85b,
+            0x17b1073600a07cc5,
+            0xa2fda85db18c5530,
+            0x0048049ac27b7950,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x44804c513dae2344,
+            0xe88f272d58469cd7,
+            0x1615ad0cd1c360fc,
+            0xee6e891903d2491a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x675dbc737ede3f34,
+            0x91fa46ec688416fe,
+            0xe0a6c5519c20581c,
+            0xde4faad5edf04861,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc5fa0592de00a68d,
+            0x9154f10bd01a7d0d,
+            0x23a89524bd88cf57,
+            0xcd42f4bbaf0d9878,
+            0x0000000000000000,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger320([
+            0x76c7df60eb84f765,
+            0x8dd7576d508a4a72,
+            0x99435ddee9c0a493,
+            0x6db6db6db6db6de8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0e1d789bd598b66c,
+            0x28a726a7e34ab2bd,
+            0xcfe786d0c3441b46,
+            0xe117521cf4768ea8,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd57f78ca15bc0d6f,
+            0xdd1fc406fe56661d,
+            0x52ba6337a826a666,
+            0xe07a5dfbe1bda6da,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcfd55f162e4757de,
+            0xdc76a7882c3355a1,
+            0x0319b9e9ca229004,
+            0x4e0fbad59cb52fad,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x488fdda04311a0ad,
+            0x3848ade9b2482795,
+            0x7adbb76c0336f7ab,
+            0x70a097589d51d471,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x99f82ae7cb52581d,
+            0x8efa815aebad44f4,
+            0x76aaa7db678cf5ca,
+            0xdfba5fc97daf3bb8,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x8eb77bb386e74a74,
+            0xde5fdc0d75d8f202,
+            0x8c0ca17036d7d4c0,
+            0x0c2f6f031ad399da,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x29ceb44bd7be07ce,
+            0x221d250aa7dcd557,
+            0xf564b87a404fdbea,
+            0x0941565eb645aba0,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0f8d748e32eafa3f,
+            0x785bc3eb06ecccfe,
+            0xef53b80bd84710a2,
+            0x97167ad623f6bd85,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xcc5a29ab319da491,
+            0xcb877114ff0296a5,
+            0x678c5f790eb708da,
+            0x9e44a2d62df7af03,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0cf8c0ea4f17d2cf,
+            0xd5c4a0a552481302,
+            0x14cc8ea772c5038a,
+            0x0bdaef9fbc6631aa,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x785205d63128773e,
+            0xcc0f749ac3b53eda,
+            0xdc90660cd531b7ef,
+            0x132440fdc650cb5f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6198026f13396085,
+            0x74a97b2606dc8440,
+            0xe6637b4c976d30c7,
+            0xe59837eab99d5439,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x31777ae6e4b5ef27,
+            0xf17738974bbd9ed5,
+            0x302eca4e759a5312,
+            0xb0f47eb8d45e1c35,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xb2087a20dffb1647,
+            0xa97155d9dfd617e5,
+            0xa67ed59d6204a901,
+            0x4e59e7a5c6263d5a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x653c3603a5923cf2,
+            0x7f951d6e11bb31ef,
+            0xf6334b9df58aecc7,
+            0x3c12169d4d0d9942,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x954f52389445088f,
+            0xb4462af40d74a6a6,
+            0x190074389fe7b08e,
+            0x7ee19f13378c6c9a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcb33e053579d0d45,
+            0x69f704bcf4e21c45,
+            0xbe8d276141adebb1,
+            0x16e6b6f1692d4f24,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xb7ad68a74ec942c8,
+            0x8c7c882a0ef19217,
+            0xb10f8684550bfc6a,
+            0xa3906ed5a40de2e2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x5de1f07e2aabbf03,
+            0x89fa6c09f792b3ec,
+            0xda7062296ae9a6b8,
+            0xfe0a3949081081d7,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x6944006d58de9fd0,
+            0x0b29a0e1e6bf1f34,
+            0xd35dbe0223f6bd84,
+            0xb90cf7c7dc938ed9,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x7dfc8b9cf24fc86e,
+            0xe38eaa4a62de364a,
+            0x748184efae77c7b2,
+            0x950aa34f52777da2,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xf3e04f529ec2f2d7,
+            0xcc01da4b72597f80,
+            0x9970bec4be0fd028,
+            0xc8f0a5f441f30038,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4b7c276bdc3b4537,
+            0x6271a82c1ef4f0fb,
+            0x2e9e911b01ea912c,
+            0x2d9a7a95324b5d66,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd31cd01219072054,
+            0xf60416ba8cd48efe,
+            0x093c4f11152cbfb0,
+            0x2187052ade1bebe5,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x37174ee8682860ca,
+            0x9363eaa4ee9a4729,
+            0x15d91058d5536499,
+            0x904fcc77d47e5fb3,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0f2d8fb88ff2ef55,
+            0x1db6b86296060638,
+            0xc23a7fc669c5a37a,
+            0xb54a07fededfe040,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x83cef0655d88aab3,
+            0xd3150d4049e5d690,
+            0x4341f1d6ee8d87d0,
+            0x695c4e9a2604f051,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe2adf4a13791c0f0,
+            0x027a16dabe2dd490,
+            0xdf89fcd584804ea4,
+            0x3a71d82e23c9f4fd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xa57b4fb2205bd4f5,
+            0x24bf66f26890325e,
+            0x2af44ae90600ec58,
+            0xfbaf39216894ff07,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xd00ed59ea2cd30a3,
+            0x13294a450bd43528,
+            0x1195b225dd89aa1c,
+            0xd6bcedb515f89188,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc2dbd78f036104c6,
+            0xd38ee4fc9bb5ebdc,
+            0x879c8814a97e4501,
+            0x985606aeb506645b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xd7b02deeb0e94fe6,
+            0xda53963616882a1c,
+            0x91d640845508b826,
+            0x3c3f721a4d8d088a,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x22511abd6b365f3e,
+            0xb656fa2b0ddeb48f,
+            0x87ca5096b2009e56,
+            0x3ca8fa1d5cc2767b,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x31bf3735cbe32aa5,
+            0x05cbe5382f6c794a,
+            0x33a5063003d6f2a7,
+            0x99029e51e36fde39,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x8f104ec3a4f3e68e,
+            0x22fe62c6b61f61fd,
+            0x3e93790bd4d77d73,
+            0xb345eb307afd8bb3,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x5bee7606db597020,
+            0xfef4be5514819ac8,
+            0x5234c5b5bedf289f,
+            0xd6b9e27e961d44a9,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xcce3ae1194ddbd0d,
+            0x0e9cc2ba5188d675,
+            0x341167c816e88085,
+            0x11b9d88e1ef08ef1,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4460728d7bcaf808,
+            0x4157c395d4214acd,
+            0x61c7e29d0fbcddcf,
+            0x9b621da81620ff1b,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x3c8f8595e9633198,
+            0x9e6ca6ad61b0f26a,
+            0x0312c32eeb7a3863,
+            0xab943a2ba59dacd5,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x7f821f4392d834b3,
+            0xe0ab67bb470629c2,
+            0xd81998ca7a809881,
+            0x4a6060935297bc53,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x0d75095aa4d7c2f4,
+            0xacf635f915015834,
+            0x628f12539a105e8e,
+            0xd2f0cc6c1b2b32e2,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0xeed0fcc697e55354,
+            0x7c3e6916d95fccea,
+            0xfead4cff9f87de75,
+            0xd2a9fbd196fe305a,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xe488d3b0d632d0fb,
+            0x1dd88892e221a99c,
+            0xf5b3092fd40f236f,
+            0x9fe5e6bc235586bd,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4e16a088ba71bed1,
+            0xd21d8d3efb4ff388,
+            0x5863804304f59a80,
+            0x7b1cb06b0fcf3fb7,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6f08789b44134d4c,
+            0x6484f65ffd7c7204,
+            0xe7469fa21c9e2bff,
+            0x97c666edba769735,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x580355cb5716a35c,
+            0x898ee0dca8de8d30,
+            0xceb4f9b1ce67f8ad,
+            0xe3306a3b7e6f8234,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x3aa0feb643464348,
+            0xf0283d3e1c24a1f4,
+            0xbdf58fcd2c3ed42c,
+            0xe5500f1f29be245f,
+            0x0000000000000000,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger320([
+            0x6f0095ddd8eae76f,
+            0x173fb4f7da16fcc3,
+            0x5c3c556ee7cd7ab9,
+            0x2658c32e571eb53f,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0x4c6f6624d21cf258,
+            0x10ee9cb2102ace4a,
+            0x4bc35529746e3ef9,
+            0x1ecd6f4af499bc24,
+            0x0000000000000000,
+        ])),
+        Felt::new(BigInteger320([
+            0xc53dbc90a9d1e231,
+            0x6e4d2d0e8dca64f8,
+            0x0d260c869a58118a,
+            0x10faed8f95fae5c0,
+            0x0000000000000000,
+        ])),
+    ],
+];
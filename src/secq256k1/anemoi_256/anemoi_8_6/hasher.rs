@@ -0,0 +1,2540 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::serialization::wide_element_to_bytes;
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE <= RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each pair of rate registers by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((2 * <<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_256_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, taken by pairs
+/// `(x0, x1)` each providing the `(2 * CAPACITY - 255) / 8` least significant bytes of the
+/// integer `x0 + p * x1`, where `p` is the field modulus and `CAPACITY` its number of bits
+/// minus one. Their distribution is within a statistical distance of 2^-255 from the uniform
+/// one, matching the 256-bit security level of this instantiation. The state is permuted once
+/// all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH / 2],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH / 2],
+            pos: XOF_BYTES * RATE_WIDTH / 2,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        let rate = self.state[..RATE_WIDTH].chunks(2);
+        for (chunk, pair) in self.buf.chunks_mut(XOF_BYTES).zip(rate) {
+            wide_element_to_bytes(&pair[0], &pair[1], chunk);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U64;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U6>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x0f1ece8afc3cecc0,
+                0x801ca61af82448ee,
+                0x265dc32092e9b8ac,
+                0xef2f8eca3cd241fb,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9d9ba40d3d324f9e,
+                    0x1179207e962a71c1,
+                    0x33ea67b6a9761fcd,
+                    0x9b31ae2c5fb52009,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x195808a3eb7f67f7,
+                    0xa406283cbf193761,
+                    0x1fac87309e1defe5,
+                    0x172675f087ed942c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x939b02625fcc590d,
+                    0x2f2ea5df0b673244,
+                    0x720d14dcbafeef7b,
+                    0x4e3dded64dbd7e00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e13b287027ceedc,
+                    0x7525ae865e567551,
+                    0x6cc3e163d750d75b,
+                    0xc801bbc6281e9031,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17cb3b8efc46ac3f,
+                    0x4c02a095dd271fb2,
+                    0x2e60a59c1e1ec7a7,
+                    0x13ff1b848fe46dfe,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1b90e644eee07c79,
+                    0x0036862f22ea1c81,
+                    0xb756ed2b7a3e3604,
+                    0x9a637788659c7ed0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa63f0dc0d138152,
+                    0x01e3225117cc167b,
+                    0x12565a528f438dad,
+                    0x96c53fd1a62d4889,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x046bb9dc180f80e6,
+                    0xb2f11407284934d3,
+                    0x5ab81b809974c882,
+                    0xb507d2a9140c693a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7cfb5ca077df46c0,
+                    0x699444217c73a813,
+                    0x280ad22e9f1670b0,
+                    0xbc72ed372c7c8844,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xdc915bdb4594616e,
+                    0x8e2b6ceffb8c624c,
+                    0xeda930de35d38741,
+                    0x6e36d8a71843b63c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf3ad70c1065320e,
+                    0x0d1fa02d71d2e032,
+                    0x84b3f118d8afdbfe,
+                    0x9cacc780c2c1777f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfdd9a8ed933f4994,
+                    0xe076ad10dd3a5b8d,
+                    0xc355d73c334bf062,
+                    0x2be357b41c065cdd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6dea0fd3f3ce58ea,
+                    0x348f1f3060322bab,
+                    0x776586654c21e568,
+                    0x57226c44536aaa80,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33c946a82679f5d3,
+                    0xb5887edcd2ecbb1e,
+                    0x4f857ed8a24d37dd,
+                    0x9b8a7f577e97bfb3,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1692465173614986,
+                    0x1b146851de9342dd,
+                    0xbc90b6db1e013153,
+                    0xb3fdf3740d199737,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab0729199fc0e38e,
+                    0x7377cde92b75d33a,
+                    0xb542193825b40354,
+                    0x3f808e4b99b0808b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x786f770589298aba,
+                    0x78bed551cd65fc56,
+                    0x4d2c2354138d3948,
+                    0xa56ce21f9f09fbb3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38dcafae5ed349ea,
+                    0xc3b892fc7ea24750,
+                    0xa23a90dcdef6b983,
+                    0xd3e691fa416b5f0c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5070b2df4025acc9,
+                    0x8e0189a80e5544a2,
+                    0xe53a681eaf1a6f1d,
+                    0x7c812b52ca2b747c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06deb1adf1aa526f,
+                    0x3e37bd6a4908b590,
+                    0x7fd2609d19d9794d,
+                    0x1f25fa90abac7471,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x671c932ac35fb2e1,
+                    0x57088958cfdd19a4,
+                    0xf4791b2ea525ab25,
+                    0x82714d380032a4bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb881dd7b59c4717,
+                    0xdf2e84e5a376afec,
+                    0xfc09497abba801b6,
+                    0xcd52dba3c96724e1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfe8085d601490429,
+                    0x52beb34df68451b2,
+                    0xa701681082cf09a5,
+                    0x1dbe3b5054c5fae5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x308690e9f42409b1,
+                    0xe9f19731de496637,
+                    0x8b790ce564756692,
+                    0x078d833071856730,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaad2335d1eebf7f2,
+                    0x766f2c06bb648986,
+                    0xf32cdb5ac6830129,
+                    0x6697a69ca32e4e8d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b063f33aa5d185c,
+                    0xc9234bd24d5263c7,
+                    0xb5dbb4648ffbf987,
+                    0xc4d9560e185ce16f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe55a0e5d1a96e277,
+                    0xe1ad11703a19c06b,
+                    0xc869e184361b5da9,
+                    0xf51ad0801728f65a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3958ec034f3addb7,
+                    0xc61aee1094f30dd9,
+                    0xe2f1d23d912e2cd1,
+                    0x6895c378bd3509ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xab8d8673e7cb5804,
+                    0x9cab5103cd1bfdc3,
+                    0x6e908515d24fa73c,
+                    0xa8c13cdd257ae9d6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcaf90fc7a3ec17f3,
+                    0x83488d7a6e2f26a1,
+                    0xfd34412974686ba8,
+                    0xe3580976c3840748,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x06032488dec5931c,
+                    0x08040c1e94cc5137,
+                    0x460675e19b422d33,
+                    0xe4cd666887faf602,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf99ae07ec94fbe9c,
+                    0x0d562793e7e57db7,
+                    0x6534dca256df6e99,
+                    0x8a66cf386acf1aa8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc399bd66a2f07668,
+                    0x52dc3019ff0a7507,
+                    0x47d2de4133523e10,
+                    0x1eae99d9ac451724,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2373076513c8864a,
+                    0xf818b969f827f162,
+                    0x5c5bcff3949813d1,
+                    0xb533951480ee0898,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0a298f500f726661,
+                    0xc8e0b28a74c76e97,
+                    0x277836c5d6755af6,
+                    0x465ae386ecc7115f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b42711128bc01f1,
+                    0x7cd2542f88553f15,
+                    0x2ad40cd0e0067122,
+                    0x8bbf9e8740a5474c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0d17df8140bae0c2,
+                    0x8a85ff0d92a2d395,
+                    0x2d3afafe8c779a41,
+                    0x7aa198f539c1bf06,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x095a0c610cdcc976,
+                    0xca86c3e1be24ba71,
+                    0x3b05fc39182af77f,
+                    0x1d19de9a80143083,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1f9b40a96f7d9ff8,
+                    0x4f795e966683efb5,
+                    0xe2fc32449f87190e,
+                    0xce52827a30747ad7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf6d99a869025cd5a,
+                    0x215d4958e6287f28,
+                    0x549db9b0e0f9e78e,
+                    0x21c1c024accff483,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x671c932ac35fb2e1,
+                    0x57088958cfdd19a4,
+                    0xf4791b2ea525ab25,
+                    0x82714d380032a4bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeb881dd7b59c4717,
+                    0xdf2e84e5a376afec,
+                    0xfc09497abba801b6,
+                    0xcd52dba3c96724e1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfe8085d601490429,
+                    0x52beb34df68451b2,
+                    0xa701681082cf09a5,
+                    0x1dbe3b5054c5fae5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x308690e9f42409b1,
+                    0xe9f19731de496637,
+                    0x8b790ce564756692,
+                    0x078d833071856730,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaad2335d1eebf7f2,
+                    0x766f2c06bb648986,
+                    0xf32cdb5ac6830129,
+                    0x6697a69ca32e4e8d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b063f33aa5d185c,
+                    0xc9234bd24d5263c7,
+                    0xb5dbb4648ffbf987,
+                    0xc4d9560e185ce16f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe55a0e5d1a96e277,
+                    0xe1ad11703a19c06b,
+                    0xc869e184361b5da9,
+                    0xf51ad0801728f65a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3958ec034f3addb7,
+                    0xc61aee1094f30dd9,
+                    0xe2f1d23d912e2cd1,
+                    0x6895c378bd3509ac,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 248];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5f3fd9619f631de9,
+                    0xb36c043820d1b974,
+                    0xeddf7248985adb89,
+                    0x4885361714107ebf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x916f2a1546ac4812,
+                    0x4180c5b45984b520,
+                    0xc10ed534bbe8fdf6,
+                    0x68f3218c67b330b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xce9b8a73f6346c35,
+                    0x2ad0aa440f401dce,
+                    0xbc335b76d2ea9ff8,
+                    0xfd080cf228f95f52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2219153fad9f7e7e,
+                    0xb6a2bb04b7550ea6,
+                    0xf36a9f897c564a77,
+                    0x295d3dba7317091d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb027e173850b4d0a,
+                    0x100d86d606c9e6d9,
+                    0x272ddba0cf499c0d,
+                    0x4fe681bd84c8f984,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d7bde88c2b5f04c,
+                    0x4536201258e4d157,
+                    0x36b95574ba4c4018,
+                    0x22b72f9f7ac1b69e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb064369cffc0c882,
+                    0x25be50a7cb6029a0,
+                    0xd76191c959561aac,
+                    0xd5c9951e2ab21740,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9512afd2ed857c03,
+                    0x75fb2e63c1cf0780,
+                    0x45329141bc40471c,
+                    0x9d24873d15c0c500,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x8c75d1922d146e2b,
+                    0xf8407fa927627594,
+                    0x0e4309e563c17e8a,
+                    0xbaa5334609cbd0de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x87de2a862589baab,
+                    0x923b3dbc00ea0ae2,
+                    0xe67f673d82affd7b,
+                    0xe1eb626eb3e9254c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x013e8a97800f02ce,
+                    0x0dcdb9d1d47afa59,
+                    0xfe2cefec69c0f67d,
+                    0x8b9b5c8c02cde52f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x118a6205fd07e2be,
+                    0x97792e6fc20297ee,
+                    0x560a1de379ba133f,
+                    0x06ceb47e48041740,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcabe041dc909ab79,
+                    0x5460a470b4a64600,
+                    0xeb8b09b99772ed73,
+                    0x3fe096ed025c464a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe2d995c9026b5c5,
+                    0xd9a74b80c8569812,
+                    0x40b92104dbc47d7e,
+                    0x14d70235d50b8c6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x478c0cd3b680cdde,
+                    0x27385944a63ea511,
+                    0xb510a4a78dbce5c4,
+                    0x89c2376489e10141,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea082134f1e67678,
+                    0x8ea7e403c4baf17a,
+                    0x6e0805e08e286f0a,
+                    0x0d05ce25975c609f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe0616f81ea5db8bf,
+                    0x23924f7c9bdc959f,
+                    0xe15a212e4c3e9634,
+                    0xc9a6e4c5dec31353,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e31fe22937b37ff,
+                    0x6824839852b70159,
+                    0xb1a8d4da9432c253,
+                    0x8c76669633602184,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ed9064385b691cf,
+                    0xfa95bd358a7fc944,
+                    0x645ee08c3a2cfe12,
+                    0x5f21dd891f3394a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12e0b2568f3b113a,
+                    0xf3294484b53e6101,
+                    0xbe7ff38315b9c5f0,
+                    0x3dd0d95dcd3a14b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdfdfbb81c2dc8ea0,
+                    0xbefb2e6d0bb6d120,
+                    0xe8d4b711cf917fc7,
+                    0xea34b81ad35c6c0c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ea5c143f215a354,
+                    0xe84b470ac50fb5ba,
+                    0x75025cf633b0fe3d,
+                    0x528159cc9adfc975,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc02183c75d452ea8,
+                    0x5c6ec03f79c323ca,
+                    0x126ae5c64cb30da8,
+                    0x55050377c5b585cf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d82a3c004e708d9,
+                    0xef3ef6ad98e560ae,
+                    0x3e27af57e2ccba2c,
+                    0x3bfc950680e28bbf,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6e57083d0363e172,
+                    0xcfecf9e98dc2721b,
+                    0xfd17f45219f85046,
+                    0x0177fa8548bf6abf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdaac1e2b8e7e0318,
+                    0xcc8891323976014c,
+                    0xe88859af1d653745,
+                    0x5424f4414cd84a72,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4ccf7ca2908706b0,
+                    0xbb557aeb757c37cb,
+                    0xea7f700bb2a8745c,
+                    0x3a0d066f25910eea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7f6ae54f5f8f099,
+                    0xef5d63bf4ce30a23,
+                    0x80194b30b8c92f15,
+                    0xaf5e2b5b21a17444,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25bb84577821d4f8,
+                    0x2f2a4ff5b2210c42,
+                    0x1749acacbda1eba9,
+                    0x929111a0b4ac1dc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa61dfa1d47f33593,
+                    0xf54408b5abcf6089,
+                    0x12eba031d9264363,
+                    0x8fd1f1413ca37132,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f0c09c1acd2153e,
+                    0x6cefe36f014fd6ca,
+                    0xd1eb786014492789,
+                    0x9453f25457c363c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9aaa65e00ff7513d,
+                    0x2e36a959be55ea61,
+                    0x5948e6d241d69c48,
+                    0xb230b5750f087412,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb998850defd187cb,
+                    0x2182a912ed439a23,
+                    0xe50cd80e00dd0d2f,
+                    0x8115c5ce3a54c662,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb3de785332bcffc,
+                    0xa8f684bc8fdb0a6c,
+                    0x364a40711b1974e4,
+                    0xec97139f10937328,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7665c9ad3d979de,
+                    0xae2f354883771c14,
+                    0x693072c1ba623701,
+                    0x0b5243e544b36c3c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa813667c8f30a19c,
+                    0xe771790a15a2203c,
+                    0x1aefa400f6784221,
+                    0x54592e6ec4c189a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdb9dfd69237af1ea,
+                    0xf697637385360150,
+                    0x724d22254200cb41,
+                    0xced9e1a2556bd37e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f12d81a09ff09ab,
+                    0xdd06304e442184ee,
+                    0x207f2bd408c8e3bb,
+                    0x640c6fa42c8adc76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0fa0eafb7142246,
+                    0x6091b9eb86b09974,
+                    0x83a0b4d7798f859d,
+                    0xa4a8a69fb47a9827,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb2671ff6debd3952,
+                    0xe0024f7735a9d9b6,
+                    0xa93e4e3bd1ae2661,
+                    0x4f705371318c7db0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x2b800705dbcff9e3,
+                    0x14f6f7a5079788fd,
+                    0xcd1796bcf54fe791,
+                    0xbbdd02bbfa0bc0ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa148005e38b80371,
+                    0xe1082ce6533ad7df,
+                    0x6580c528fd3fefde,
+                    0x75041991c10f552d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff8e21f315f12bd5,
+                    0x1ff349debca8229b,
+                    0x6c6b1e6869ec2f83,
+                    0x0b6de851bc6dda66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb24d59e1bc75e4d2,
+                    0x2f98848a41648356,
+                    0xc8f24baca3f52532,
+                    0x8aaa5592a7836744,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc83b53605f838af,
+                    0x3cbdda81b4743c75,
+                    0xdb5d12b24d331369,
+                    0xf86d550f01171d8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ad85c53380403b3,
+                    0xe95f770cea7db9e7,
+                    0xa6af3189d151c01c,
+                    0x749b3bd42bed58f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e8f3e3c37453d14,
+                    0x7a04cdf2f4a184d0,
+                    0xbb177b6297f89e08,
+                    0x3b030d61a185fe99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5244755ef18adb6c,
+                    0x929bf3888fe18443,
+                    0x04164b36014593cb,
+                    0x42320f6255adc85c,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xe42a0d545e42dc2a,
+                    0x17b72b6ed40d0c32,
+                    0xbae06613a1706abd,
+                    0xa552afc66c576dde,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1fda2fe59bf31f8,
+                    0x592a456a35f44bcb,
+                    0x074bc8d848c6c288,
+                    0x30f090797ab286cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe20873f1862f938a,
+                    0x8739a65d9aa42dc6,
+                    0xc98ae59135835e33,
+                    0x79c398ead8893eac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3af2c2f6a0bfa603,
+                    0xd53a6da0247b5d0c,
+                    0xd7be1a7acfd1c618,
+                    0xa9b34a833a220841,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x30f77afe3e227273,
+                    0xc530a4190dfb820b,
+                    0xb5a9f3243b392198,
+                    0xa22f664696ba77c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9483717b8583af65,
+                    0x31278d1b40737964,
+                    0x8cb510d898d317b0,
+                    0xa984bcf448dd5c4a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1dbfedab5209409,
+                    0x718a9d4d2cb70cd1,
+                    0x87d979b085c226ac,
+                    0x58365d478272bf7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4fb6e2713358369,
+                    0x0a8ca43ebe03ae60,
+                    0xf399c6c9b46f6c9e,
+                    0x776f03bbf9ec70a2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc691157af1fde273,
+                    0xfe663e070539690e,
+                    0x86b45675ee9b8f16,
+                    0x3ffafdd0488293ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5715968c2e68889d,
+                    0xbf5cae28fba2c198,
+                    0x20c4b559b8f5b1a5,
+                    0x109735824a01b9dc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x630b9336065bb9d7,
+                    0xb7d0a051e3bbf9a5,
+                    0x5893418afb1a6192,
+                    0xd00fc4628ad7f24a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64d176c09430385d,
+                    0x0b5944b83fc04487,
+                    0xa19be0f8456709a6,
+                    0xa02d4b665c16d470,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa4621c6af9a1f3cf,
+                    0xc224c252c5ec151f,
+                    0xbd852d989db9da55,
+                    0x2caeac0f614fa3de,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3db4fd61b882de86,
+                    0x55cbede2e7636f58,
+                    0x6bd6f0f4d03fa250,
+                    0x3866870517e64436,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5149ece477883bd9,
+                    0xcbd4bd61256a4091,
+                    0x85fd87a254b34a6d,
+                    0x3c76da83b78693ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb6fae233c4301f0,
+                    0xa6b4d9f579fec138,
+                    0x88d723b8a715f55e,
+                    0x701cb9aba9069d05,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf83787c8b8f972ee,
+                    0x6df42cad338b5c21,
+                    0xcb4b31c66f99e21c,
+                    0x5e573f3fa8e5b98d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0f4c958b29b9b503,
+                    0xf8099d0da0887559,
+                    0xecfaf3c12e0a5de4,
+                    0x5a4e0fa5ca805cc5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x044e7cbbef508e83,
+                    0x7b20e7abe794b59d,
+                    0x45c6a98cedb65437,
+                    0xd8400f6f0b47bfd3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x78484e0878a5f29c,
+                    0x8a0dcad5582f7aec,
+                    0x2930078c66d537f9,
+                    0x8f4e0be5f069f7c7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x52957ca125f3fab3,
+                    0x7ed98fdf3fd2efcb,
+                    0x988f4e53d892743b,
+                    0x8958d7b3d2b4f4ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x245580ca2ca4bb9b,
+                    0x41e9d42ba5409aa2,
+                    0xe3fdf0430bb36fc5,
+                    0x7427fd77d2d87bae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e26752e38dce322,
+                    0x30a724b0db1ee3be,
+                    0xb246b57739f36fe8,
+                    0xcd9790202ac7ac08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc86198df1c21db40,
+                    0xada4192439ee3fca,
+                    0x265f3fdbae583827,
+                    0x0a37bc47b4759bbd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2e8a2415fbf38de6,
+                    0xd32c767313ddbc1f,
+                    0x7756e1e0b9c086a7,
+                    0x5bc8fe53bf7a4803,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12cdbacf81b95ca0,
+                    0x4027dee57a7f6692,
+                    0xe0f78626665bdeb1,
+                    0xf4a9df2a1fb16f12,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42d513951321a729,
+                    0xe644f808ff056c96,
+                    0xa7f2955e93468316,
+                    0xcf90d1b15a66d820,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0a008fc7003a2fd,
+                    0x581c6fd5de135162,
+                    0x4383d780cb965ad2,
+                    0x3ea4397bbc574f89,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x02b619be35cd5ca8,
+                    0xa4c388dc12fad4dc,
+                    0x4064aeb115e92f9e,
+                    0xc3147a30e51491f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2af16a5052b20e01,
+                    0xdf3e7acdb0c985d3,
+                    0x09872a3f7a01c364,
+                    0x9dcf456446cadf6b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40acdc78ac818ecb,
+                    0x38d5902f4b5feb4e,
+                    0x4d0d86668789df56,
+                    0x73c6734e03010a07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x062a70834e870739,
+                    0x7c92a32268a3fb19,
+                    0x7c334e25faeae03f,
+                    0x726275a81f17c239,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf659f059df68c808,
+                    0x1ba89381ecef65c5,
+                    0xd8311cb4deb38467,
+                    0x02aa0e02dbbb8c7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x035700fe31d687c5,
+                    0x36e25e9b6a3ddc1c,
+                    0x67da51df3f5fda9e,
+                    0x1a4068c83f83891d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74c4b31aa5e05fea,
+                    0xced1ba919450a4db,
+                    0xc6cde31264b12b1e,
+                    0xe374632b171a3e23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae91ecdf1649a4f9,
+                    0xcb81b44103b73970,
+                    0xe816302bd5cb6c05,
+                    0x60946f7a8a43974c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4b7d1dc79768d58e,
+                    0xe0d66e7a2a7769b7,
+                    0x4903fb84d4fd6f09,
+                    0x3111619b44b257f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d7a388e5432ea61,
+                    0x9718ddb1d2c2b483,
+                    0x2880b597ea7655a9,
+                    0xd3a0d72043935e07,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51420846a5722f8f,
+                    0x5ffa5c65ceb09e2e,
+                    0x6fce6b3848801eb5,
+                    0x23f93a0c56b4d970,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fd0566a453c2211,
+                    0xcd987ce70bbee792,
+                    0xf8c3330fbaf28d42,
+                    0x9e6f54a024cb067f,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x03edf2f223312976,
+                    0xc48cd3efd83d6c0c,
+                    0x56ef7a56e491bb1e,
+                    0xf9a2fc6438c4ca3d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x550622bfcc72bd76,
+                    0xfbd12522a5c9affb,
+                    0x774bb4afc4566e73,
+                    0x53213d25f2db1869,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd0a7e4e789f88e9,
+                    0x55be54a3a0c9b1f7,
+                    0xbbdc6ea35f4052b6,
+                    0x127c937e1f27c300,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29acd384370720b4,
+                    0x03d3253d0ac777a2,
+                    0xe28e1f6e0b3253a1,
+                    0xa1d89ddfcc162599,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2b9eaad7e88e7d27,
+                    0x6748cc32bccb0f6b,
+                    0xe3fb08effc56cbd2,
+                    0x0523fd78ea8eeb7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x447d4867b4cc036c,
+                    0x694d99e9fe54eb4d,
+                    0x9ca96f52d0d896d8,
+                    0xd454a6c800aef456,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04605ac897e134c9,
+                    0x8e1fe78f1041ab83,
+                    0x4d518d859759416e,
+                    0x1fc17e921c956591,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89b0b1678f8bf665,
+                    0xc637937da347503f,
+                    0x676be28bc745f66a,
+                    0xc83ac0370892773d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x32a56b631648b975,
+                    0x8704c3060db6d80e,
+                    0xe258aac28a20c234,
+                    0x5deb96fa98880a95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x399d2fa4821c18b3,
+                    0x87976a89a8c75095,
+                    0x3a61f6a8b53722ce,
+                    0xa5c7b0d1de04ef56,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x099b457226da3444,
+                    0x8fad0ed62f645ae3,
+                    0xbefe1cea1457750d,
+                    0x2216015a39a242ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5b232492304d0bcf,
+                    0xffe5a22943bf26ec,
+                    0xe2647ca053a08eec,
+                    0xa9ee58521faec0f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe977d362ad6bc6a,
+                    0xe433133812f4220e,
+                    0x72ce4a0b3fb31eae,
+                    0x76616130870b8127,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd57ff43fdf98c110,
+                    0xc357d088ce2931ed,
+                    0x2219f308fdebbb97,
+                    0xaf06df801c217ce1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41aa98e1d684318f,
+                    0xd84cd77af75210f0,
+                    0xd136d494ff65af04,
+                    0xcaf1a963d9124cec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1258204891d5556b,
+                    0x1b13ba123dc7613d,
+                    0xd37b9880a16f9cf5,
+                    0x7202d259639e2862,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5369a7a68994e677,
+                    0x7317d3f74df43150,
+                    0x05f2be21109ab419,
+                    0x25bfa57d66beb3e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a0c3bc52cb3d08a,
+                    0xa4d52811fee81bf4,
+                    0x5167bf3230ea38ad,
+                    0x01567ff466dff505,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54ac606fb59f283d,
+                    0x0164b40378114e48,
+                    0x2ddbf36923f40864,
+                    0xfab94cff6be4e947,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb7b7f781efbbcc6,
+                    0x8c4d3577db09af1c,
+                    0xf552c17c8d28f4e2,
+                    0xcba608dc33c40bb3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5c196b99a402b00,
+                    0x23fee821d54a8b43,
+                    0x1f9aad8853f39413,
+                    0x109cc800d06b0c64,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf82c6b6456f6a656,
+                    0xf8857797b14579bc,
+                    0xbdb9223de06accbb,
+                    0x6a8f7c8dfffd613a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6c386f12f5626367,
+                    0xe8f271466ba5b412,
+                    0xba5df7410770a073,
+                    0x6b8891651286d0ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2de221ebb97fa18b,
+                    0x7d8f46630de5a2d0,
+                    0x458716e6a09da5c9,
+                    0x74af306d76fd96f9,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x7d88ca0b364015eb,
+                    0xf5ad1002a4d1a486,
+                    0xead3f9cc70adea9c,
+                    0x68c681c516917f34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad60268c549501e2,
+                    0xf3f8113404aec40a,
+                    0xf3333742b45f0074,
+                    0x328060baad2d34da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x22861d11b9ac55f8,
+                    0x8a8d597291bc563a,
+                    0x5c039706f2c8790b,
+                    0xa13608fb466ffc6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c08cdf485411ec6,
+                    0x5d4e69e7829917f7,
+                    0xcd62a8332202413b,
+                    0xd02890b7e77b87c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3be9c3951e82721,
+                    0x8bf52cfadf236ca4,
+                    0x1b1b749fc5b01234,
+                    0x41b2dd0754f03867,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4be44283e165424a,
+                    0xa608050197c10b4e,
+                    0xb478fba3bfd29d4c,
+                    0xed1dba8fefef60a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x418eb2356952371e,
+                    0xe13e56342b71ef52,
+                    0xfd668644f96636c9,
+                    0x5104adbd883030ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x529e414342307527,
+                    0x6e773e25f7cb5a1a,
+                    0xec922a99db9ef807,
+                    0x4aac7c5f12d38ef0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x08566663d65a40dc,
+                    0x53b503799a14d409,
+                    0x7f3680d35903f378,
+                    0xa3a0bd95e9573be9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x229f8caf7f8cc04e,
+                    0x6032e20fcaddb6c0,
+                    0xabf59ca60d0798be,
+                    0x4433e6f166d98adb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85253dc31445730a,
+                    0xf973a00afe07633b,
+                    0x7b75ca1605d37b53,
+                    0xc2aed78f9e732ddd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18df466c301d4f9a,
+                    0x9e92b0ed29e1141c,
+                    0x851fd5aa4218f7a7,
+                    0xd35e4934c8f72905,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9b91161bc2aa6a17,
+                    0x19ebd66cc6ae97e7,
+                    0x55f5afc465080b52,
+                    0xa8a19c877c4592be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xec6888731ebc6193,
+                    0xfaa5ad7a127667aa,
+                    0x133acc9397ed5e05,
+                    0xe5fc616f7a662d67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3194dd726d9e038,
+                    0x5fc785b5aa1a1977,
+                    0x43c21591e8dac3d4,
+                    0x7a387ecfaec8f258,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x777581d2c64d70c2,
+                    0x27ed17ccee510fad,
+                    0x7f44412fc5091da6,
+                    0x02f8dbb9dd5f2e9b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1d765714c153aace,
+                    0x3f03c545d72f1dfa,
+                    0x5c4ead9fddb46252,
+                    0xbcc207aec4c80fab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba246c958d733502,
+                    0xf4fbda4abe19a7f3,
+                    0xc3287b10537a83f2,
+                    0x2f93a99b78c7d67c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x19171d84c97bb5e3,
+                    0xbc31daeba96b3b6e,
+                    0x49b684e61ac5c613,
+                    0xeeec1e853e4ebab1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb2bb9c4f2bbfec0,
+                    0xcc6a838c228ca914,
+                    0x7b50aa33eada6a34,
+                    0xf83befe1f0fe3f25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ed1b4dae5cd4526,
+                    0xede35a66f31827f1,
+                    0x06e0cf279895c35a,
+                    0x9dd727f1a7c683e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56ae9c862de76ed4,
+                    0x96f1675eae9ab3c3,
+                    0x0d8cf7b6d0666509,
+                    0x5e229566a9b7c006,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6be27c31a425ccc1,
+                    0xb25cc06cf78bf7ad,
+                    0x05af0bf1fa31af17,
+                    0xd637cdd779106d11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8231ca524181ee98,
+                    0x6295691eee1a367d,
+                    0xb297b2efb65333f2,
+                    0x9723e19b3585e6a8,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x235088ae0ebb066e,
+                0x12a6a7f019d84296,
+                0x63752ef7ef8c5193,
+                0xf9ba23adf9b53b98,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1cad9c61eb8fb6c8,
+                0xfd11b8f2da98762b,
+                0xbdd244770e3dcc95,
+                0x1b59843e5bf7042d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x25b15770eabc1c03,
+                0xc63df453750fc898,
+                0xa1a82e52e812abf6,
+                0xc0cf431b79731481,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x6efe564795b9cedd,
+                0xcfcb6aa59d6fe606,
+                0x3830c9e869c2bc73,
+                0x11a8c743d9c318c8,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa585ce21997c0b0e,
+                0xb9a9855033792dae,
+                0x67bcd21676bb06a8,
+                0xd80f14311631d04c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x51a61e615e9860bf,
+                0xb6e59f3ace42edbf,
+                0x693e70c8a86f1b10,
+                0x464146daba4dee9c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8adf11a7588fee9f,
+                0x5d5c3579622d75b4,
+                0x8e9110399c267217,
+                0xeb8a07b7d8fb9350,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x87f53c9525442eb7,
+                0x981bc27719b7e364,
+                0x538fded3e8bcb50f,
+                0xe301b6aa354e2fba,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4ad7a80df583b839,
+                0xe7358e2e93d47ec7,
+                0x4a5dbad9a6675788,
+                0x806276de95cd8e46,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xfbbe8c1887481c8f,
+                0x2f83f34423526726,
+                0x34685464f9a92f8c,
+                0x2d7dc05bb98311fa,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 186, 187, 563] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 186];
+        let mut opad = vec![0x5cu8; 186];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
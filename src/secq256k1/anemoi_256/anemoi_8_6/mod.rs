@@ -0,0 +1,2055 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 2 elements of the state are reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 6 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 6;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 2 elements (64-bytes) are returned as digest.
+pub const DIGEST_SIZE: usize = 2;
+
+/// The number of rounds is set to 16 to provide 256-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 16;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8007cccb10691e97,
+                    0xd158e63de343fe7a,
+                    0x0412874ae500abe3,
+                    0xab68d4cb5aa87600,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b2915dca59eae2f,
+                    0xea7a55a0c4d63512,
+                    0x1aa30d3be5a4194c,
+                    0x8194271c82eddcdd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47656bfe5599581e,
+                    0x2f3bf4c59f1d0fcf,
+                    0xb2470f9e14e9bed5,
+                    0x721fcfd94887315e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf4f20f09888bce58,
+                    0x8ebc0b843704f5e5,
+                    0x5ca52d51b243c5a6,
+                    0xcfe9f66c730216d5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c1dc60144614941,
+                    0x2b43d7d67a45256c,
+                    0x133a81eef6ec5356,
+                    0x9677270365dea615,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee00abe7c6f4b85f,
+                    0x1c4c8ba7f22f82a4,
+                    0x8b228d4760ca35e9,
+                    0xc1aa6505002a93e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c088cd314563bb0,
+                    0xb27eeb607cb68495,
+                    0xe9b75a12754fb2b4,
+                    0xdeabdc1583d44aba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x744eba672d8c0f70,
+                    0x063a10b09514a743,
+                    0xbc54a6e9e21c06ef,
+                    0xc7cff5c2a259a4e2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0a946ffa2fd908c3,
+                    0xb7e255d2725edc98,
+                    0xffc0c3f434f879dd,
+                    0xaa6b7faddfed9865,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73965984f2cb0cc5,
+                    0x28b6aaab7e2e81f7,
+                    0x64c0a6cbb9149493,
+                    0x1efbaf37114e8ade,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf3899afa1829c28b,
+                    0xbaa8d032094deb1f,
+                    0x36a1277dd2753061,
+                    0xcc62db98eeb8c207,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x030c437864ebc03c,
+                    0x07bc9f4f718632a4,
+                    0x6cd7f418143ac6b1,
+                    0x8e44ca80c21782ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x365513ef8f37e103,
+                    0xd398cd0bb48b070d,
+                    0x649df5b529f606d7,
+                    0xbdaa32eedca72b6c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8bb0a794cddf9b9d,
+                    0x98814c8489f63220,
+                    0x6c2c423f4b474867,
+                    0xedd30305d84c0cdb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x915e053c85f406ab,
+                    0xb9e2b7d895eda621,
+                    0x71df248b7a177147,
+                    0xf0c69c2569a355d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cb6fdd5911c194b,
+                    0x27940908abe9aacb,
+                    0x19e37505a560d95e,
+                    0xb56ebafa65c90834,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x41db16a6b73cfbc5,
+                    0x318f6ea4457056ec,
+                    0xca3e81401cb9e9fc,
+                    0x56a41ae08d643e8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe27d0bab37f7bfd5,
+                    0x57169e42699a7987,
+                    0x313b2b0827b5d8f1,
+                    0x78fcb47fa7763e8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x260024a9f3c0dfb7,
+                    0x9e4b13903c2e4f4e,
+                    0x47e7ced4edcfc8ec,
+                    0xb420ae248f8bf413,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x442dc6f12a1e61fd,
+                    0x046c3a2991cad6d3,
+                    0x1d2cf4db9433509e,
+                    0xb7f5df07544eb236,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81e1b801073202eb,
+                    0xb12f4ec63538a4e8,
+                    0xb83631ca28eff785,
+                    0x69348dcef74037b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ae355bd0a919950,
+                    0x805f6b5504da37a6,
+                    0xdd5592514851bf1c,
+                    0xbbac21b7a5d36a68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x148797dd724f335b,
+                    0xa4e6a04595a4de85,
+                    0xff225d225ba67b04,
+                    0x4bd60e08a4e3a6c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e52caff84c3d3c1,
+                    0x3a8e3f46d246e0ed,
+                    0x28823d5ad5d1eacb,
+                    0x2aa15ca1ec1e0569,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5dc8568683c5782e,
+                    0x7bd5c36591e6ad7d,
+                    0x3d557710e6f04d2a,
+                    0x5698bc9694facdf9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0fff206a3444eaf,
+                    0x016095bade2ec157,
+                    0x5375a4b6cd78d1de,
+                    0x470d0a647803f090,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c6280573b6b2866,
+                    0x1358ce5ff4a5d0a8,
+                    0xc194c64793bd1094,
+                    0x980acb9fe8d42973,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xba203711c345eeb5,
+                    0xcd8889dfcac8af63,
+                    0x73397a46079d3f27,
+                    0x511b0703cd4ee6d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae572b781da2c158,
+                    0x00efc4d8cf0da0f7,
+                    0xd678d6ec35c36baa,
+                    0x2ab5c539605022ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c40d20761ca40a9,
+                    0xa6e23b8e32249f1c,
+                    0xbb117a39688b77de,
+                    0x5c6b4f2e97f192ce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c836d5d2ddf059d,
+                    0x32b0b503cde68360,
+                    0xcb12ee96a9af77d5,
+                    0xc22617f4e8ad297c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb034c5bfbd27b1a4,
+                    0x05af36d31390ad6d,
+                    0x897327863eea00bc,
+                    0x907dd74d3e92d87d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5642303db840ef2d,
+                    0x79afbde56f4d8b1d,
+                    0xff27a505d875a418,
+                    0x2337cbb9b76ffe2c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9dcd111af3f98e5b,
+                    0x27a0e6344970e5d3,
+                    0x41e2aaf891149a8b,
+                    0x9a893c33e835c9cd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6569bd0edb05fd0,
+                    0x10453f3f16cb5f7c,
+                    0x6ef7927e95f4ed85,
+                    0xc404513f561d6d46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x137b535f627e3804,
+                    0xb082408a45fb4074,
+                    0xee79d091fba97800,
+                    0x88c3d2f6bde8102a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bc683d47397edcd,
+                    0x77c84bc86df9f406,
+                    0xee90a4df4e295d08,
+                    0x77ce0dd9d483bf3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe211250b4cf8a01,
+                    0xec5f612d6a876deb,
+                    0x571aedd17ad471ad,
+                    0x7a082dbea63b3011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaee5412936173bab,
+                    0xf9db72669c958d93,
+                    0x4f638c45d7d33282,
+                    0x8dfe40d129d9489c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa4cd88a3ee4a1bb,
+                    0xb5250628cc028233,
+                    0x92091e00c4ddcfb1,
+                    0xdefc1a62a246dc0f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5078503524149d07,
+                    0xa5e1e16fb63c7922,
+                    0x08c282060c510b06,
+                    0x25c285fb08e69139,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2685529610659c26,
+                    0x166108723922536a,
+                    0x1224548377948ff7,
+                    0x94c9b38b1d4d2497,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff842b457a306fcc,
+                    0x8253bb7b0282d0d2,
+                    0x9e4749a296412587,
+                    0x0c111c2cc3825d84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6cc81dddc6d4c0a5,
+                    0x978ad8529fcbc28d,
+                    0xe2f142d9f1e91b38,
+                    0x8fec35ef94982dc3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xceb7e205786a9653,
+                    0x9e8f8d14292ab887,
+                    0x8093264ef20964d2,
+                    0x32393e24b7303bd8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23e3722e4b2e0b4b,
+                    0x6c1b748d8b4a44a3,
+                    0x89da415b596f5c9a,
+                    0x07da6126f59c524e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ecb79a37b008551,
+                    0xdfc9817082f819f7,
+                    0x6e2b09cf8fb2ffe2,
+                    0xd35b129212d9997e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9145b3c68452a2d,
+                    0x42e7a84559272beb,
+                    0x80574c5f0b6e2b90,
+                    0xe8a8b8a38d909973,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xdedcf21badc80b53,
+                    0xe97c7d3f82eac09f,
+                    0xfdec3f408b82299c,
+                    0x3a18e802dd9aa2c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61b7fdbb208f5678,
+                    0x352b72c2a13d5677,
+                    0x14735215ac0ef754,
+                    0x28fc330549d36fc9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f261d10492e7869,
+                    0x2a42c5a19327384b,
+                    0x2b8ca3e196d79bf2,
+                    0x72d3a2a3b21d162f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x593e53b54b27cc15,
+                    0xc0b28e6a81698d61,
+                    0xeedd9cc765a24ba8,
+                    0xd3253ddd9a2effcd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a2149104ae1e748,
+                    0xc0ff8279b707bcb0,
+                    0x610029c628233acd,
+                    0xc300013bc25949b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x700af7c7a8846ee0,
+                    0x88992ca0a333f224,
+                    0xe5c79cefd9c5a565,
+                    0xf4a16874377eeab1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4e89c73b49b3a29e,
+                    0xe592586e41133b88,
+                    0xf12218d52402ff97,
+                    0x382dc552a3d25c61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x497a15db666377b7,
+                    0xec656f2e456da026,
+                    0x79a0e7480822449c,
+                    0x77f00491a0397b2c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x60b649b194c49cb9,
+                    0x1fec7c6676acaba9,
+                    0xb4dbeb44d78e8d49,
+                    0x3126bb5b8ecd71d2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00f4fd3fbb389bfa,
+                    0x67145b8da4ac29aa,
+                    0x169308fc9918c2d7,
+                    0xf041b45e964d6976,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x758fbc7e4132f9b7,
+                    0x83752b6c0a232a92,
+                    0x93946f488e06b71e,
+                    0x8d85d187ee7447f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc5956b5566f1611,
+                    0x97e2f54eee755193,
+                    0xa01311d9d4f8d825,
+                    0x497c782da01da28b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bc8f4612f58c9ce,
+                    0xd56138db805a35d7,
+                    0xeebb6e844617af97,
+                    0x0030b836fa7b8d5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x14b88018fe9a984e,
+                    0xece6e342f64aaa2e,
+                    0xe2a1fabc71624e3b,
+                    0xd1b22111784de057,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f09ad0664b8011d,
+                    0x2f3ca16f21484509,
+                    0xb4f5ae97b4404d8d,
+                    0xa688ec54ba7ee591,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa00999b3a81b1bcf,
+                    0x66c7ec5e52b2068f,
+                    0x3cd257cf682b98e5,
+                    0x2c2e65e9ac6f9671,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x472ee8a3543dc626,
+                    0xc92a4137c1b9b7d4,
+                    0x84b72b69c88771b8,
+                    0x05788a06c1cb0022,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7be4a2d5110f82c4,
+                    0xd286945a26bb4dc2,
+                    0x0689d88383decee4,
+                    0xa00d856502e442ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac2c7ca9c49049ba,
+                    0x0e48d1696fd2c3cd,
+                    0xff572f9d0b252b33,
+                    0xe847c976ef70d493,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f8b505a5581c458,
+                    0xab540a157553bb18,
+                    0x692c14fab2087e03,
+                    0x483494024f849f76,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0f6fafa79f392dd,
+                    0xc4dbf5a2d581262f,
+                    0xfda1eecff5cc27e0,
+                    0xc68dc68813ee7c2d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a434f17ceb06aad,
+                    0x36faaccffb235bcf,
+                    0x9384dbd7629b1986,
+                    0xb10ede79d0254856,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ce6f1b74803aba0,
+                    0xd9dd55b418aee0a1,
+                    0xca5b586a78d761c5,
+                    0x36b73c71634c84a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc42a0d80b15f3d3,
+                    0x4831e354aca08291,
+                    0x61aa938858002c3f,
+                    0x085fcd7693271444,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4fdfd52e419a09f5,
+                    0x2d642db3025e8411,
+                    0x63b561ee59af1d64,
+                    0xf6cf805407b2679b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37f8edd745f79734,
+                    0xc7481f7d828f6e50,
+                    0x2d035af032c55e7f,
+                    0xb45703abe5c4c33d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x591dabdb7650f5e1,
+                    0x2db3bb76ff185f33,
+                    0xc114b9788ea4c75a,
+                    0x9c806718149a6ef6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbefa57cdabd5b359,
+                    0x7e8e6cf5f3766a4c,
+                    0x88dbb09f77ae77d0,
+                    0x8d6659ddbbea3aff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2682d3b748a0197e,
+                    0xce739a78fcd4ee91,
+                    0xcda4fd353558d8a6,
+                    0x5d5e8309a0d5aebe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca1fcad6127674e5,
+                    0x344c3f74c2fa1ad1,
+                    0x9798542b5737c1cc,
+                    0x4026d059c0dd641a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x966ce3538886cc59,
+                    0x754dd4673bc29a9f,
+                    0xf7721129c45f4e60,
+                    0x65bcbc50246d56e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c878d802c404f7d,
+                    0xbc83b36540f9464d,
+                    0xa3ed2bc42ff82d2b,
+                    0x5556a03afa3fd618,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf1a6840a90a368f5,
+                    0x142a537d8fc010aa,
+                    0x2efd53bf8b2dba49,
+                    0x63a74bebca0e7c34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f696a45c8ac264f,
+                    0x3ded5e11c14ca055,
+                    0x73d4787a50e1d8d0,
+                    0x36015a298a39c921,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8100b50d2cd17a9,
+                    0x1bdfa7ad113b7824,
+                    0x1aec8189482078a6,
+                    0x8dbe11764a35db4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3d7e398f3a818713,
+                    0xa06b7e5ab4c39987,
+                    0x43f349d0b1deb426,
+                    0xae66d30a57ccef35,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb028f6ad664ff964,
+                    0xa41cf72a12de8920,
+                    0x2a9fca164d2af765,
+                    0xefee5aa890222ae0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb19c6e3f3dd5df61,
+                    0x751615847202dbc1,
+                    0xd6a942d7ad01764c,
+                    0xb589a0f18054bbe6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6034c3f46d759c49,
+                    0xa24c798971da8c14,
+                    0xcf64d16ca357815b,
+                    0xd18a35c9f7d68e95,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x898be3b5e6e83732,
+                    0x8858d90e71a0dcc8,
+                    0x75e12f71855d1a94,
+                    0xd60ee33c145f62e1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xedcf9d0f9f49e15a,
+                    0xd887de88c9724af0,
+                    0x21fee79a8541db2b,
+                    0xd7d2e718260fefc8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7448c26dc675f9d,
+                    0x6bdd22d116186078,
+                    0x2b615c33f24266df,
+                    0x1385d50a39972d5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x40c9928c9cf0758d,
+                    0x5bd1ebc265f5dd46,
+                    0x80af5d2a9edcb99d,
+                    0xe1df662c9fa9ebf5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69091d6b9bdbd2d9,
+                    0xb6eee9622315197a,
+                    0xc58d392b5702ead4,
+                    0x2b8c87c2afca9f3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfac7a7750e4a521,
+                    0x17d707183fcbac1f,
+                    0x5d8b9b012531d93c,
+                    0x591ecb8bde49f3c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd604d1734db207d5,
+                    0x0febe38e49fec60b,
+                    0x187c55eda2993b5a,
+                    0xde2541fedc15eca0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd676ff73d404b035,
+                    0x648172a388c14f16,
+                    0x06dffce023d7ed6b,
+                    0x8c933770e2ee6505,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3437a60d3aba9a1,
+                    0x3c071017c91e06a2,
+                    0xabaec495927d6193,
+                    0xc0d34e9f67d675ea,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x885a3c41477b23c3,
+                    0xacbd421c9008ef65,
+                    0x6191d393d947917e,
+                    0x224a8de9fc40170f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89ec5c8338bebb3d,
+                    0xf86e5d528021125b,
+                    0x884a45fc6cc8795d,
+                    0x11e5f86bed1d4b2a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1afbc44f45615694,
+                    0x652363fb2658a7af,
+                    0x0a382a56f7b05fc8,
+                    0x6b81b6898f7563c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc02e6450fdc41293,
+                    0x839142f6a6941092,
+                    0xb75eaf80f10b9c76,
+                    0x8824843539756ed6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e62046146e90d95,
+                    0x58947c9ed5315da8,
+                    0x146042de44c4e010,
+                    0xcce089fd11394da9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57f768c130a74fa6,
+                    0x4d9c59f57a3f7aa8,
+                    0xd73a411b096d550d,
+                    0x51dc4b94b12383f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x543d4f85695432b4,
+                    0xe92c13d43b888340,
+                    0x958aedd813c5d297,
+                    0x1b38a5fee96d42ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd64777354e810c6e,
+                    0xad45c6593896e937,
+                    0x739557aac646f555,
+                    0xa75ce8198ac6707c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa385a5bdaf151039,
+                    0x51e672203f220554,
+                    0xed9aa781718b12e8,
+                    0xfc0e35b1172e79aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x58a47b5508dd4ad6,
+                    0xb072bff221262646,
+                    0x693c5a997ce7f293,
+                    0x0ea26f27cd37312a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39d8b0a1668da1b9,
+                    0xbd353117b0b91572,
+                    0x45c2a612d920d653,
+                    0x62d232ca15691d9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x403edd93b83439d2,
+                    0x294b401a531abc2b,
+                    0xf305da96218c49c9,
+                    0x1a4a3254ebe6af38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67ecd64e8ebef34c,
+                    0x93083c2a4d78fd52,
+                    0x44275db7cf43fa4d,
+                    0x15b06edcc0f69e4e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ad722b2c42278b5,
+                    0x3148d8587d060924,
+                    0x76776f7825b62a64,
+                    0xbf48a9ee9bd41aec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x716f154df3543f7c,
+                    0xe09cd66f5613ffa3,
+                    0xc59bf0ec335f99ac,
+                    0xee026463686c754c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e3cbdaa049911e6,
+                    0x59feb78892373055,
+                    0x8aa461812011b26d,
+                    0x05b42dc65dd95a92,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76249a83c5dbfa7e,
+                    0x24a7d9ecd6ee5c7a,
+                    0xd1f9d35d4faa8218,
+                    0xc7e9a763308d7cb4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4fd0e7f354b95d3a,
+                    0x0a81ea5daee34a87,
+                    0x0dc4b3e16246d0c5,
+                    0x4a7ae6c62578d80d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x449731b4ac5e08d6,
+                    0x347ca5cdc6fe0659,
+                    0x9156da37572c7ff1,
+                    0xe102f27e68ccb633,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8631f69477c5138c,
+                    0xa739c6b5f08dfaf0,
+                    0xa378d2cb3cb8a02b,
+                    0xcb24bac7766a1e61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb97f7a45ae2e7e80,
+                    0x3bbcc44e01b99f77,
+                    0x2ee2294d61939dd1,
+                    0x8bf180890f9ffabc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xadbd28e2f0f7b054,
+                    0xa3caf4e8950053ce,
+                    0xa8ac348b0b598b35,
+                    0xc87c7756dd5496d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x622899624cca7861,
+                    0xece7ab7b72aa45e8,
+                    0x25fde03f6b019e00,
+                    0xf5fa79f0c31fa6e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e272ec8c044ccb3,
+                    0x72bdd0fb060e76aa,
+                    0xb50f44e91c6f8e32,
+                    0xb75e59897e3f0159,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd6bc68e90d75f3b0,
+                    0x7e5dacd8080a61e1,
+                    0xe74fe66c49fc61c6,
+                    0x8ef31d76075bc92f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2076269e115834b1,
+                    0xdb4d7e2cfe41f50a,
+                    0xab347a9ed1d8d5a4,
+                    0x92321967bcaa24d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6aaaf801d7dd0ac5,
+                    0x6bebc52d57c9e3ba,
+                    0x8134cedd72bcf856,
+                    0xd1bff37a5f49713e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4bb46b52a05a8982,
+                    0xfab2fc7defcd491f,
+                    0x9924b4db0516bf14,
+                    0x5f95ab56dd2c10f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1658fc8bab2a8d1e,
+                    0x99be701899a81271,
+                    0xa19b4625aed470c5,
+                    0xe0d24bd1a5e43508,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac6c1b32adb2ff7a,
+                    0x2c34584c32d5bc10,
+                    0x134fc5dafb0d91f4,
+                    0x203e8cfcb255c330,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41ed6a2db3f15247,
+                    0xd46c27ac11727c61,
+                    0x9f8bab01237fc25a,
+                    0x90d85e110254c192,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66225522efeb497f,
+                    0x4f59a10732bd62d7,
+                    0xfe6aae0593c19beb,
+                    0x79bf1edac0ca6d07,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0b488bb559b05bf9,
+                    0x5cc49c2f5133984c,
+                    0xbe054900d58ec660,
+                    0x0055bb07874096b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8c35eb025c2d52c,
+                    0x2c5fb454154fb5ce,
+                    0xefff817064a97683,
+                    0xc702306ab155e8e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e36d90a8f1098b2,
+                    0x6b7d1c1fcd00fad5,
+                    0xf56811061972ab98,
+                    0x152eeec93fa91204,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18bf6505d04b25de,
+                    0x4d9ad77d93abc553,
+                    0x96cc05ed60923b38,
+                    0x174a7dd69bfc8128,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3dd3798236039cbf,
+                    0xe0203c8e933b4d35,
+                    0x6b2ed5cd9975ccf5,
+                    0x023c26e5ce0d6439,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9621f063b59557e1,
+                    0xd0607ff57f104d5d,
+                    0x85e0088bdf137a9a,
+                    0x2191f0a6a140a514,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17249b5a2da57ee5,
+                    0xeeca8a7d27379c42,
+                    0xcdb3f5352e2c9c1e,
+                    0xd449da790e82d22a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9370fc315bff782,
+                    0x0d4dd7f2856bcf7c,
+                    0xfdbe6154a12743f9,
+                    0x9db1f012456f852c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3f7b1016784446f2,
+                    0x87cdb2c2706e0d09,
+                    0xbfe15d36bb3a354e,
+                    0xba9bdbe7b5b44e59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x683aef0d6e405fb3,
+                    0x1dcca9a185cf7cb9,
+                    0x644c0c24a185f078,
+                    0xfd6974572bb0b24f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d66e0b704fcd310,
+                    0xac355b2077e7b0a7,
+                    0x17068b5ce9405404,
+                    0x24fe328aad5ffc86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0f21351f24aecce,
+                    0x252289606e34c318,
+                    0x5da8475ed7d2b9a5,
+                    0x7219e08d8d9bdd84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b12376737fd71ab,
+                    0x385e7a7c63dbbad3,
+                    0x9559046a54c1b92a,
+                    0xe98b2ac17fa7c2a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2af2737b2aa65c02,
+                    0x7ec596d2f7bd97f0,
+                    0xb6dd4823aed2f122,
+                    0xd94ca46cc56d56d4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdad3cb85b73e763e,
+                    0xd0dfa12e06449700,
+                    0x2a55c13fc1e27fb2,
+                    0x3e60660b85649d2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe650c0d109df26fa,
+                    0xaedde6f515856e5c,
+                    0xba53162826637cce,
+                    0x1d47da26eba34670,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9723a6e331fb14f5,
+                    0xb258b2fe608e2a6c,
+                    0xef03f85e9caf422f,
+                    0x5b05ec80e396329e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa34e06df0d5f3e93,
+                    0xf6a3fee42d6ee7a0,
+                    0x8d7669ca3fbe8f1c,
+                    0xd0c4f4d0003977f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e70f8c05b9e089e,
+                    0xe976eb54dd0abef5,
+                    0x884e8afbe91f57c9,
+                    0xa9ef8ea50600a78f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x579b7b7a10e7be06,
+                    0x44bd983119b706cf,
+                    0x0b675ad1782b2ab1,
+                    0x507e1d0f7e99510b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c7a35fd9edacd90,
+                    0xba714f762edea23c,
+                    0x3b48e99af4eebfa4,
+                    0x594f9a2a408bcc62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe48464a00e13d5ab,
+                    0xc17e4443a9d72e0a,
+                    0xb6d925cd5af42499,
+                    0xdb9e1dd95041e8cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x843ee6e909348a75,
+                    0x3dd5629730c089b7,
+                    0xe8556b2583388c9a,
+                    0xef637542aa46e30c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4103ca19dacb6697,
+                    0xa7c558fe3f657052,
+                    0x490d751f0619b53e,
+                    0x21c2bf308bba8883,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc004e9a68c7362b2,
+                    0x38441674a5fb71e6,
+                    0xc4f900ec33868855,
+                    0xdce872c88a169ccb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cff85cde70ea497,
+                    0xc507ccf56252099f,
+                    0xeb3ae0bcce9ab474,
+                    0x132b20e4d1c53c2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8924bd3a128d9cf4,
+                    0x8f1ce8931c9ec353,
+                    0x7d18e1eb8987319b,
+                    0x40f352f52c144a38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6d7ba56ca960fe5,
+                    0x9c88cdda20ca5d70,
+                    0x17dfea3eda37f88c,
+                    0x4207270bb4c4f7da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72dfe487e47a8eeb,
+                    0xdbe94e81d13243f6,
+                    0x4ae931684c0ab0f1,
+                    0xef1c157fb6e79378,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac4b8bae13ecdd8f,
+                    0x5d37eaf03ecfb12b,
+                    0x3a6a93789a50f967,
+                    0x2066698ce890eb38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe5b1df26e6e906d5,
+                    0x93344b3640b775fc,
+                    0x2b94c274a75c0c86,
+                    0xde95625f35e9e7f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x92dbd441b4ed7c82,
+                    0x2c06f752acf9fbf1,
+                    0x339831de8ae707c6,
+                    0xc1d9e89a95a22357,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x02aac86b05a5196e,
+                    0x45240fd6bce3f675,
+                    0xb1cd2d7a6d24e91c,
+                    0xd0d69a7d76d40d31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x615e5aa46fab2729,
+                    0x84f6d022b9e436e2,
+                    0x52a3f9cd0755f42e,
+                    0x7dda0af7b4219acc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24a914b0849b6573,
+                    0x6efc849682d1e76f,
+                    0x809553914c225def,
+                    0xbf67fea39353e25c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x342dfd4de59747b9,
+                    0xa65c707a2c9fe058,
+                    0x737bb2fc8776cd5f,
+                    0x1cbb3866476a7ce0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x77984c0088d82c70,
+                    0x6ac5ba71bcf87a06,
+                    0x14353a03d57caf6c,
+                    0x4f7f3d5ed76ab28a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cacdafdec1bdf7a,
+                    0xf61cf46b509109fb,
+                    0x0039af4e2fa0e6d4,
+                    0x1cb6d89ee8751ccc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7527f2b5d0cf9a7e,
+                    0x615afd76ebfa8970,
+                    0xbaae8a14d07f60a0,
+                    0x8bae8c87b17ea7fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2b1490454947796,
+                    0x2083a9c50dac2a76,
+                    0xe9bdf4da738e6dcc,
+                    0x10b156dd4a385d61,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x195a88024efb56dc,
+                    0xac3fb238088c211e,
+                    0xf966556e1f8d12ed,
+                    0x79db406c93e37f7d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc57a5807c4097727,
+                    0x723d6002569c4c87,
+                    0xbc92fd38f2fa6856,
+                    0x7f42d8329daa1a24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe38000ffc3a6076b,
+                    0x64f9fbe8a513e219,
+                    0x55b108d5ef428cd5,
+                    0x268ccea3cdcc88af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28ddeba04546f0fd,
+                    0x4529f4a3aa2ac8d3,
+                    0x9429b196e77855fc,
+                    0xc1756bd3cd3912b3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5b57cb327dede6f,
+                    0xf9adf8edbf3710d1,
+                    0x6fd6cb12e925fdb2,
+                    0x20fb683b93c23e39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7689f2453773b2e6,
+                    0x09c0953b29669338,
+                    0x0440d38bae0aeece,
+                    0xd598f83563a73808,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff5b9c65a102e4f7,
+                    0x56043f5eff2f1a9c,
+                    0x47457954e372456b,
+                    0x65b47b07848f7e91,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x816546216d78618f,
+                    0xf265984eca6d8d35,
+                    0xf572273d6dd7bca7,
+                    0xd96e216d2a501494,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x135e9dcf71cd7282,
+                    0xb4a1ddced56e263a,
+                    0x136df52c50fcca37,
+                    0x6fb936bc1376e427,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4092892980c2c16e,
+                    0x8dd0044073a427d4,
+                    0x601c2ad8598cba6c,
+                    0x9fb839a4cf3bebe1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x113fd5bf8e688011,
+                    0x625276f504b94fb5,
+                    0xfab3001d42c82d18,
+                    0xf756ee5af45b8751,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa95592dbad2faa1e,
+                    0x1cd09862ca45ed1e,
+                    0x6c5b84324918afaf,
+                    0xfa6b24b23b90e275,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62867c0d42c58f05,
+                    0xbf33e57cdd4015ba,
+                    0xd1fc3424eac3d228,
+                    0x236365379dc19837,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xffc68e2f39eaf7f1,
+                    0x1a29e5efaaf55214,
+                    0xd4f0d818d39dc0df,
+                    0xec2ca26948429b0f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ab43f530a29fe71,
+                    0x5f722dfec4b485be,
+                    0x5688a1b4f61a0571,
+                    0xbea508515a447a17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde599073ce1f83a8,
+                    0x79683dc5e0bf04e6,
+                    0x8636fa39e0fa3e3a,
+                    0xdea219fb3a273f2c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x07e4c626eab27490,
+                    0x6f67e484b2499a47,
+                    0x1309817e0eef18c3,
+                    0xc790044ab01cd71e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e5054dac8ac012a,
+                    0x9ae4a7e7e4fb6422,
+                    0xdfd608f31acc4f9d,
+                    0xfe5c470c0d4bdf7d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe4f2f4af447f1fe,
+                    0x166842e079a6b361,
+                    0xf0f9f0d0689d5ca2,
+                    0xeed193003e940908,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc122064d97dc9f94,
+                    0xa0e1bce9f57cfea9,
+                    0x6defaa5c7002dae7,
+                    0x41cf1690677bb942,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdce0463f5fb9ef2c,
+                    0xc843864151fcce2c,
+                    0x37400c231beb6892,
+                    0xfc14f723df9f7fc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ba06973b7b4c442,
+                    0x6813fb3811c35252,
+                    0xd5ed08430a61d6fe,
+                    0xd6281de4cfde9bcd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6486623539dfa756,
+                    0xef75acf7bdc8b316,
+                    0x7fe005835f2bdf40,
+                    0xf1c031393b11100a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x51f8e3b97310d54e,
+                    0x9e00c4a87e5a2f7d,
+                    0xf4fe524585a3379d,
+                    0x8b5b6b2f6b04ee7b,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,750 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 64 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    state[0] += round_constants::C[step % NUM_HASH_ROUNDS];
+    state[1] += round_constants::D[step % NUM_HASH_ROUNDS];
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x7914310a37209a82,
+                    0x1e7a81f128b2d524,
+                    0xcc6b65abae93b90a,
+                    0x446a4cd1e081280a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23ff1b8efa9704c1,
+                    0xd0352ffdaabc4653,
+                    0x9a118d8b8f8f513e,
+                    0x4951c4a672359e1c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd99aff4e03a6b306,
+                    0xed94942f1d24dee0,
+                    0xabbdbbea5e0b141d,
+                    0x7119b04c76d84dad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7c7bee047d33385,
+                    0x6a6ffc28f10f9e02,
+                    0x0d80004b4523ef12,
+                    0x755da9b9fe79d1a7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb95560732b54d516,
+                    0xd2d84afea783b177,
+                    0x0b30e99f68368cda,
+                    0x0b1076f16953fe49,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd942d74dd4351f9d,
+                    0x3f47dc93137809cb,
+                    0xe5de42c67454206e,
+                    0xad0fb3c8b2c15354,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5a11f636301e7766,
+                    0x054a4254a74d7ffc,
+                    0x298a1e02ad27daae,
+                    0x92aebd55376e0915,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf9359d84ad7345c4,
+                    0xe3d64f57c36643c9,
+                    0x1308cee431378d1b,
+                    0xc13ee9eb21e2bd97,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5bd4584107859466,
+                    0xdd2a5b9356117a28,
+                    0x0438ff49918b7e3d,
+                    0xf5f0b6d72bc21949,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1293a427c313ced,
+                    0xf14decbec899597d,
+                    0x3ae98cbc7f48ba11,
+                    0xed10aa608dd7828d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x00514337f8807580,
+                    0x48628c98dab5462b,
+                    0x7626549462582d9e,
+                    0x10557e40a2177244,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11e86fb068ce5e49,
+                    0x3a9acc8d338e79da,
+                    0xac200447690df94c,
+                    0x6e18a8ce10ac722d,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x55159b1e96a86501,
+                    0x5c611ce5c749a79a,
+                    0xa2a6a8c79b044696,
+                    0xe5c83216bb147efc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7058dea276f5e54,
+                    0x1e62c66aea8ef46d,
+                    0x344b71b6a27540cb,
+                    0x108af738ddf8e2db,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xfca46df41405974d,
+                    0x2c454133cec500d0,
+                    0x3e21bffbb6d0ae38,
+                    0xc732ea588bcc0b7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x446367877cbdcd1b,
+                    0xb2bbf9b5005691cd,
+                    0x8eacfad6ffdd95e7,
+                    0x6ef42a861e862d94,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf74e33eecc629ad1,
+                    0xced83c04b4cf1a23,
+                    0xdcfbe84eb4f6a8a5,
+                    0x684777f39f48d5d1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x97d16b9959704530,
+                    0x30ef1e300e34d01f,
+                    0x20a5d015beca598f,
+                    0x40b44b9545ff36ad,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x58e23883399f8dc8,
+                    0x5d6b49b91c7bd05e,
+                    0xc9fbae1e92a694fd,
+                    0xb71bee4ebd8a4ad4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x159182d7ee4e942f,
+                    0x5489ce3764da3147,
+                    0x2b1b3aa00a75b76c,
+                    0x195d6a7b13e234a8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x40d8811c3e342869,
+                    0xbfe17e317d3cea3b,
+                    0xcb5f66c1e1144dd9,
+                    0xf43114d4dbe6806a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xecfeb4e2626b957f,
+                    0xc86f540cb6c3eee1,
+                    0x364351e481b7bb0c,
+                    0x6fc45c55684a79a2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1afe4ab591de75eb,
+                    0x79f6fd50b5a41796,
+                    0x6859637fd74c8827,
+                    0xa1fd0c136814dc8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaac6bbb4a181e33c,
+                    0x17e9fde03dc25625,
+                    0x62f670b55e07ea48,
+                    0xbff28f688ba38ccd,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x2f9ff628f1e46b24,
+                    0xf8d1fbc908759e88,
+                    0xc6ab316893452d7e,
+                    0x45879560065de853,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc5204b3e555a396,
+                    0x39a266267851e5cd,
+                    0xc6259c61fca61345,
+                    0xa60d6a4b40cd45f1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x35dc7d681ea2d8ce,
+                    0x3709b858af354aad,
+                    0xbcef82a246461fa7,
+                    0x27c6bb0e3afab8a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3731c376dc8bc0fd,
+                    0x8a86cd7cc58dc7ab,
+                    0x26e75d5a7ff6d9f7,
+                    0x0c436996aed8d20a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa73006bc648f1b93,
+                    0x6d61352f0ffa82c9,
+                    0x61dfe90c9ac8d4d5,
+                    0x2daab90b04180aab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa71b18738eff790d,
+                    0xa29cd50b6ddd51c5,
+                    0xfbe476695db7db8b,
+                    0xa28384df80816d1c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x662266a749dc87a2,
+                    0xee08e0d9c0e69a2e,
+                    0xe566ace128ad50bf,
+                    0x2006fa92d0c9804e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x934dfeda5170c542,
+                    0xcb10400617acc2de,
+                    0x6b52a654aa5dcae4,
+                    0x93c5633b92fdb462,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xca290f4dfb2dfb11,
+                    0x8eb5a204532af6ae,
+                    0xa9367380a1dafbc2,
+                    0x7920e9fb3077d90d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9507ce449f5cb311,
+                    0x7b8f5946da68ff19,
+                    0x993d789b633671d6,
+                    0x175cbf244ef788d7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5f630ea36071cc62,
+                    0xf155c601a8acc995,
+                    0x598ff62014cbd9d3,
+                    0x101502b93125c5e8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1747c28666996e26,
+                    0x5c1c81cb57999b63,
+                    0xa5066e4e834fe88b,
+                    0x00b0f104ee5833da,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc13f6a264e843739,
+                    0xe537f5b135039e5d,
+                    0x0000000000000008,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x88e9887f5567414e,
+                    0x89d8daf1c3d0b454,
+                    0x000000000000003f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc13f6a264e843739,
+                    0xe537f5b135039e5d,
+                    0x0000000000000008,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc6949ce0f662df3a,
+                    0xa187533b95906639,
+                    0x31b278167bcfb468,
+                    0xcfe57d6ecbfad1f0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb74178dc0c436a6,
+                    0x443c7f5f7390effb,
+                    0x2206e4ff5f540229,
+                    0x5553d852d4a90383,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb838d5a826751fb9,
+                    0x00b956c21615c05b,
+                    0xcd43101bc606156c,
+                    0x7d9e9e2d02e876e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01487f69791cdb49,
+                    0x5f8b9617524c297a,
+                    0xc3bcce1cea216fef,
+                    0x7b99bcd1c3341260,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x39a437b20cb265ea,
+                    0xf4ef94e453e53e42,
+                    0x451f25ee2acfd5ab,
+                    0x9f435b2787a20676,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b4f241ea7073d6f,
+                    0x6a6e73aefbff84a6,
+                    0xdfbe7fec8966b344,
+                    0xfd5b02f435ef9a58,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6dfae46c4318e76c,
+                    0x90bf2d69a97d6d55,
+                    0xd4a93931d13ddd06,
+                    0x2a6cb133d5b96eff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd557df4356e8d7f5,
+                    0x059ba1030ad21ff8,
+                    0x3bf336b1630ed614,
+                    0xbcbe3ba66b0fbd61,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1d8d54a186809f47,
+                    0x34f2360d9cc15026,
+                    0xd9e4bfc0585818a1,
+                    0x1caa23f9593c96f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x63e51eaf4ce10e02,
+                    0xee2ed3a623b23024,
+                    0x8e7eb6ddcd9f1e3e,
+                    0xe003baf5bf9fa97b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x025960502ea3cf6c,
+                    0x761d52910de0094b,
+                    0xdcbcfa45abfb35a3,
+                    0x14eb99dbb58f30e2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27b964b7ad141a1a,
+                    0x96e9c3c2b8b9dc70,
+                    0xae314636372e6003,
+                    0x93222606e5428a0e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
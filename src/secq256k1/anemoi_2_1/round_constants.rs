@@ -0,0 +1,279 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger320;
+use super::Felt;
+use super::NUM_HASH_ROUNDS;
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [Felt; NUM_HASH_ROUNDS] = [
+    Felt::new(BigInteger320([
+        0x0000000000000000,
+        0xc6f3988c47bc0f19,
+        0x8f5c58db4bef96e5,
+        0x0000000000000031,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x182ec001ec41553b,
+        0x3288391dcc807c99,
+        0xd349fe0ce4da0882,
+        0x45b4c9d1fc8c977f,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x5659f783061f8461,
+        0x7d2692d2d89f53eb,
+        0xdd7bf8236f44e68d,
+        0x8d9db9f45c855028,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x2e0452c5be429f7b,
+        0x4021eb4e3c1670ce,
+        0x0c9c6388712f198e,
+        0x7e77c55b55378c6b,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x45b5d759325b2062,
+        0x88743d21233040ca,
+        0x88981e3f3b70948b,
+        0x311cd1089bf47002,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x2c68da049b5ddea8,
+        0x7590e3340cc7d095,
+        0x89c87a202e42ef9d,
+        0x281afe467fec2c91,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xc0a2f570879803dc,
+        0xf1ad6a7739a72b7e,
+        0xf2884bc848ff5f23,
+        0x42e000d3764c05c2,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x99def4deb16404d0,
+        0xb6871cd83e4561f6,
+        0x7d9fde9a11f46a7d,
+        0x44a385ad6b108747,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xba9219e3cf450d14,
+        0x26b847293facdb16,
+        0x55419f2396733196,
+        0x822bc7e3a5d526ae,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x8ed0b343602605ee,
+        0xa6ecabe154da9f95,
+        0xcd6ce6c11cb2e4f7,
+        0x7036e4bccf7f5965,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x6aca2cc429911440,
+        0xb625ceeed27db93b,
+        0x026f90b6f0cb8418,
+        0x3e18ef6fc7c86c88,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x26b1c8a9eaccaf8e,
+        0x099cdb47f4b28c51,
+        0xe026ef4053cc4c66,
+        0xcebed7f6785208ef,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xb737db99d3e43355,
+        0xbdb886bba2c43c7e,
+        0xab4fd264ccbe693d,
+        0x1267632fb34fa676,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x2ad6799b56987bde,
+        0xfceee5d01da0fee0,
+        0x4265d9ccf86361b2,
+        0x447eaa38c4f1aa7b,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xf9adfdcd99234281,
+        0x3168d618c8846d16,
+        0x8a4b2fb3b8b5a33a,
+        0x4f32523289bd84d0,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x59f9c112ca889b19,
+        0xf5044b76cd207510,
+        0x6fa5873268b0d577,
+        0xbe8c1b27e6d4cd49,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x44804c513dae2344,
+        0xe88f272d58469cd7,
+        0x1615ad0cd1c360fc,
+        0xee6e891903d2491a,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xfb3680bba8612acd,
+        0x8574cc5f6afaf1c6,
+        0x16ed858316ffb156,
+        0x74255d09d260436f,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xbedf6b23639804f5,
+        0xcd7550cfd405bf10,
+        0x2deb10dd04383dec,
+        0x5db93a81f2c0c645,
+        0x0000000000000000,
+    ])),
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [Felt; NUM_HASH_ROUNDS] = [
+    Felt::new(BigInteger320([
+        0x76c7df60eb84f765,
+        0x8dd7576d508a4a72,
+        0x99435ddee9c0a493,
+        0x6db6db6db6db6de8,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xcfd55f162e4757de,
+        0xdc76a7882c3355a1,
+        0x0319b9e9ca229004,
+        0x4e0fbad59cb52fad,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x8eb77bb386e74a74,
+        0xde5fdc0d75d8f202,
+        0x8c0ca17036d7d4c0,
+        0x0c2f6f031ad399da,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xcc5a29ab319da491,
+        0xcb877114ff0296a5,
+        0x678c5f790eb708da,
+        0x9e44a2d62df7af03,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x6198026f13396085,
+        0x74a97b2606dc8440,
+        0xe6637b4c976d30c7,
+        0xe59837eab99d5439,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x653c3603a5923cf2,
+        0x7f951d6e11bb31ef,
+        0xf6334b9df58aecc7,
+        0x3c12169d4d0d9942,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xb7ad68a74ec942c8,
+        0x8c7c882a0ef19217,
+        0xb10f8684550bfc6a,
+        0xa3906ed5a40de2e2,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x7dfc8b9cf24fc86e,
+        0xe38eaa4a62de364a,
+        0x748184efae77c7b2,
+        0x950aa34f52777da2,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xd31cd01219072054,
+        0xf60416ba8cd48efe,
+        0x093c4f11152cbfb0,
+        0x2187052ade1bebe5,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x83cef0655d88aab3,
+        0xd3150d4049e5d690,
+        0x4341f1d6ee8d87d0,
+        0x695c4e9a2604f051,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xd00ed59ea2cd30a3,
+        0x13294a450bd43528,
+        0x1195b225dd89aa1c,
+        0xd6bcedb515f89188,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x22511abd6b365f3e,
+        0xb656fa2b0ddeb48f,
+        0x87ca5096b2009e56,
+        0x3ca8fa1d5cc2767b,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x5bee7606db597020,
+        0xfef4be5514819ac8,
+        0x5234c5b5bedf289f,
+        0xd6b9e27e961d44a9,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x3c8f8595e9633198,
+        0x9e6ca6ad61b0f26a,
+        0x0312c32eeb7a3863,
+        0xab943a2ba59dacd5,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xeed0fcc697e55354,
+        0x7c3e6916d95fccea,
+        0xfead4cff9f87de75,
+        0xd2a9fbd196fe305a,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x6f08789b44134d4c,
+        0x6484f65ffd7c7204,
+        0xe7469fa21c9e2bff,
+        0x97c666edba769735,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0x6f0095ddd8eae76f,
+        0x173fb4f7da16fcc3,
+        0x5c3c556ee7cd7ab9,
+        0x2658c32e571eb53f,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xab47de3874d12133,
+        0x63bf2136a0febaca,
+        0x40ed0bb73fa6fe6f,
+        0x881e2d9f3708a392,
+        0x0000000000000000,
+    ])),
+    Felt::new(BigInteger320([
+        0xca806c3f1fa89b9d,
+        0x51d2a5e3b889a71e,
+        0x2c28d3e951b8f356,
+        0x210efd7d2b7e38db,
+        0x0000000000000000,
+    ])),
+];
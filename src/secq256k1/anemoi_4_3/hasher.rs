@@ -0,0 +1,1872 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U3>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger320([
+                0x888f4c7f1d58a2a0,
+                0x4091e941828270bb,
+                0x84a94d8852ecf027,
+                0xe0b2cbb49d250fc2,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb7c3ad10bab82210,
+                    0x07440e3ecf7029bc,
+                    0xd3c61bbf450e745b,
+                    0x361cbcf33f58daf3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2297b09326ac63c,
+                    0x3bd6a33df6aa8163,
+                    0x21a06aa3a617d3e7,
+                    0x9fd1c65bb43ac0d2,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x0fe9669085eae542,
+                    0xce385c159266701c,
+                    0x4ab1e2c641685a4a,
+                    0x43a4e0b70bf2a1fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b1e580e3efc0807,
+                    0xb95f378fb137e856,
+                    0x88bce00b43a94301,
+                    0x70654edc24807bd5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62c714e03b2c4d9e,
+                    0x182bf5e3d6ca8e36,
+                    0xbb9354ed9bbb6206,
+                    0xb041db8b3d7b5b1a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6c0b614f432453da,
+                    0xffab52c96d5a4954,
+                    0x5187f22c7fa03d89,
+                    0xf2d087387a62372a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9a442b09089f092,
+                    0xd82cb5dfc0afe80d,
+                    0x2f5e0e8c358dbb32,
+                    0x9a4aa0dcc54e1b11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d864315ec67ba5e,
+                    0x0a569decf9b69391,
+                    0xb67eb467f48fc82e,
+                    0x519dd326c1f40f22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8ad83b3cbf69c085,
+                    0xaaf306e7337175cb,
+                    0xd919ef9578252e3c,
+                    0xf23b18d70a5530bd,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x93bea5549016fc3a,
+                    0x06ca2f747f2c7d52,
+                    0xfd56a2d1a10d5240,
+                    0x63f60faecf4a0f31,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x167e2c72e30ede5e,
+                    0x6e8cc0a8b21bb7e7,
+                    0x7aff55b60cfe8994,
+                    0xf7a56f14763e1d53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90da2b7cbee5b4b3,
+                    0x5e24297c6232e7e6,
+                    0x405cf41d42a294a2,
+                    0x484ef4fc23e92531,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72c66ebd45cf31b6,
+                    0xa4133f50ce5277d7,
+                    0x1aab1997f381b56a,
+                    0x91f78de924f5585a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5bfe98be3965fa0b,
+                    0xe8343827e35a1c5a,
+                    0xc8bc23b897972652,
+                    0x4481f936163ce166,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x1733cc5ddd1b5ab2,
+                    0xd1fc8882170b9564,
+                    0x3c9ba13f7d3ebac0,
+                    0xc81efc2603f0e80b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f8e714d4acc3dd8,
+                    0x4778b68dac07d5f1,
+                    0x3c7006ea8da3384e,
+                    0xc09c453885ee3fac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1fcee222cb4e7420,
+                    0x612e7e8a83cb1b59,
+                    0x91d873e798c4d534,
+                    0x12909f2e18f8ddb4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7b79a23c29f34bf,
+                    0x49af599d8134512d,
+                    0xbfeccfca64e23cf0,
+                    0x2c1eeac61d310472,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc05c12a4bc4a9ead,
+                    0x091c99ccde4ab1c9,
+                    0x4a049eb749e5309c,
+                    0x8b41b06a48db0dfd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0d4de42f2136f5e,
+                    0x17fa575215af39ed,
+                    0x598fe3f0482f89bc,
+                    0x6c3c7101e5dc8f53,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xfece704aeae04bd4,
+                0xc86df25debd04572,
+                0x398faf95d07ca532,
+                0xd9d78508ae2e2e81,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8ea9feb8602abbd4,
+                0x9281d9bec21b84b6,
+                0xd08781af4c6a9035,
+                0xe0545874f562ed67,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9b0d971d805012e3,
+                0x5d790bdab413a08a,
+                0x1383a2009706bd46,
+                0x1a3d181f3f034dfe,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb8473930fb531d7f,
+                0x0fe93730487438dd,
+                0x1bb94aa4ffb8daac,
+                0x87546e7c6f699bfa,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xcc8b69e16c997eab,
+                0xf695a7853880963a,
+                0xfb0648497055a546,
+                0xe7a0aa43a9b64782,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8e6b7e7b46d553b9,
+                0xeed3c16f8a176275,
+                0x3ec3daa87b586965,
+                0x45bf421360d079fe,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc9a81c8c84839e29,
+                0x58d932923426749b,
+                0x2e2ddab269aa43dc,
+                0x2807d1b5df639f82,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x94a58f92f5c32be1,
+                0xa74bb0cb7f1bcfd0,
+                0xbdba1fd8a296404d,
+                0xef2e2acceb892f65,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xbcb49a4b110aec84,
+                0x6e0ee7c9267e6e8b,
+                0x72792aae0887b0fc,
+                0xad825682ab6b0ffd,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd1b5f94d430f0d8b,
+                0x20f3d64d3d49b883,
+                0xb94cd353a3bc73ec,
+                0xd48638ea7b3061e1,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xfece704aeae04bd4,
+                0xc86df25debd04572,
+                0x398faf95d07ca532,
+                0xd9d78508ae2e2e81,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8ea9feb8602abbd4,
+                0x9281d9bec21b84b6,
+                0xd08781af4c6a9035,
+                0xe0545874f562ed67,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9b0d971d805012e3,
+                0x5d790bdab413a08a,
+                0x1383a2009706bd46,
+                0x1a3d181f3f034dfe,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xb8473930fb531d7f,
+                0x0fe93730487438dd,
+                0x1bb94aa4ffb8daac,
+                0x87546e7c6f699bfa,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 124];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5aeaa58f9611dd99,
+                    0x92d675bab456c0f0,
+                    0x28a71aabbe13c021,
+                    0x72d7d4b038d7f242,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcd9d39c622dce0f4,
+                    0x8beb7468b6cb43db,
+                    0x3579d30a9f709090,
+                    0xd822b665eab5e314,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x880a061ab3182f5f,
+                    0x2747753399c42a7f,
+                    0x1d38abce73e571eb,
+                    0x255002dba6e2e2ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36394af750ffdcd6,
+                    0xe6305d0a8e4930a0,
+                    0x14592b1381cff665,
+                    0x261931bdb07ab3f4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x64b215fbc5432219,
+                    0x1be915f6458fa977,
+                    0xf2616367dbcf5f6d,
+                    0x39a4e02339b4b122,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4aeb16cfe4e7e675,
+                    0xd117f7a726cdacee,
+                    0xa60e3c2cf75ab008,
+                    0xb180f3ff5bb650a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x382a0d29852f074f,
+                    0x7b24a930f0ff91a5,
+                    0x36a8cac1d994170b,
+                    0xccfe9af1d7cedb51,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa483698a5e3dfd8f,
+                    0xbe6ffe5cd58bafd4,
+                    0xcc5817909239c037,
+                    0x745f91e4ee91af05,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x93ebc605671cccae,
+                    0x7902b48b1bde2ce2,
+                    0x8a701f7a208af161,
+                    0xe1c31b605e2cc314,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x586abe085d39fb01,
+                    0xd37f050ba5fbcf17,
+                    0xadff50881d7cc11e,
+                    0xadc51c8588d2b22b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa576ddb4dadd9a85,
+                    0xf82d1cc33a6bacc1,
+                    0xcc0582c8c9318493,
+                    0x723a9d88efc84f5a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84a049c6ef066870,
+                    0xe3f3accf482953cd,
+                    0x79ba17c857653407,
+                    0xd7d0d5f1265958e4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa247b9519e2593ec,
+                    0x67756429b1fada87,
+                    0x872cbe22e8658284,
+                    0x9fdfa5e247c9a0ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7c66dadffc2dad21,
+                    0x914f26e382130f37,
+                    0x70dbbcc44247316b,
+                    0xb5a86c7920576fb8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60b74e32ce55a146,
+                    0xa94b806741c8b437,
+                    0xac8078c5627d9fd6,
+                    0xc24aaaf3ebd3a0da,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x86b2f29c9e23822c,
+                    0x84142ca8e9ee5a48,
+                    0x4c5b77d024334f03,
+                    0xd07786f81b849cae,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa67a02adaf58827b,
+                    0x1f05facd32dcc882,
+                    0x0ba9ca3a7c039a07,
+                    0x8a4fc95c002b215f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x636fdf2a3ef862ba,
+                    0x46edc98015817558,
+                    0xfe42e87f45c9ee85,
+                    0xf97ac16cf3d722c1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x44cfcdaf1c34cfde,
+                    0x1333cd4ba105a0bf,
+                    0xf131f8e208431448,
+                    0x41c9bb59fc7dbb5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3eac1dd1d34c68f1,
+                    0x4961cb0c84762095,
+                    0x682d82dd8a66f89f,
+                    0x852cbe4394caae82,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3cfb08e675ba286c,
+                    0x50ca521a1f06c151,
+                    0x282f3225d03a3c2f,
+                    0x9d952a0f6080da4a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85f975a296592a9f,
+                    0xadd49b9c33c48475,
+                    0x3ce1a5d3b2c8af9d,
+                    0x6121c288a5d51479,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x211e60e782cb41e5,
+                    0x82fd1ab3ed47425f,
+                    0x3ab0f43404288e0b,
+                    0x80f9813ff1a41040,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65cf73df156895b7,
+                    0x2fe52200b69008ad,
+                    0xbe84b82250415f7b,
+                    0xe768da6948a99231,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xac80102552e8fdbd,
+                    0x7b98edec8071db26,
+                    0x3ff3dc8f58111064,
+                    0xe4aeb84de9c53f61,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x05258eaac41c9388,
+                    0xce00154bfad540b1,
+                    0x779659d4087efc4c,
+                    0xd6b99af13ccd2bf1,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xbc6b972737fa3806,
+                    0xaf89d8faf5dd48ab,
+                    0x6907c021026fd9a8,
+                    0xa027a0ef1c04eaa9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc3ed543ccacc0f08,
+                    0xddc51d7deb5e4955,
+                    0xccbef4b20160d4d9,
+                    0x1bacc233bba883bf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3945c31cfa53eb88,
+                    0x76030c270392e26e,
+                    0x9564008c2d3ba79f,
+                    0x796fc95c1b1c92bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fba14017f6af151,
+                    0x2d0436a2f1544b34,
+                    0x8f44f10cb41a55e4,
+                    0xc397799e31644d08,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0fcd6793b1c7b415,
+                    0x132edb4c1739545d,
+                    0x70773607ad8f7120,
+                    0x506b727ae9bfc553,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a366d7a9fa581de,
+                    0xfd47abe5f3769e05,
+                    0xdc38f21fd41e5eea,
+                    0x079266851d5f830c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1ccf1233a0703a0d,
+                    0x3b2fa608f6434472,
+                    0xed7a08b050232c37,
+                    0xace804c4f8b05805,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2665f92c560093fd,
+                    0x49fdaa5f72198938,
+                    0x909658da85449610,
+                    0x02cb8250bb529d91,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa81d4823f9043b9d,
+                    0x679e3af63eb10094,
+                    0x243579aeb1255779,
+                    0x46b2535eeb94792d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd468f7b8ddb30e6d,
+                    0xf7838a2c71199a73,
+                    0xa16f8f923f2de6f2,
+                    0x58a44716032e40a7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x893dddab22201396,
+                    0xe8f05148c76dc774,
+                    0x9dccd370ae562766,
+                    0xf1ac03bab0b0d9e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x403799aca0ccc364,
+                    0xbb6b627c2d71194d,
+                    0x10d71a882e6b21c5,
+                    0xaf165132481ea009,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x53a52d4c8d531617,
+                    0x300097c3f9f1d82a,
+                    0x716491e6ed6829f4,
+                    0x7f678e4747f22fcb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43d045052cb482cc,
+                    0xa8937ca286b7d03c,
+                    0x4e7141ac887a4cea,
+                    0x4eac3bd48326d091,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6a63a459a537fc15,
+                    0x743e24de4b20248e,
+                    0x8b710e9da1590142,
+                    0x2dab7586bd7aba20,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc1c8193da0175c57,
+                    0x58e9b362c23ae621,
+                    0xa7ef9baf743b650b,
+                    0x6e4faf7c4fbfba97,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0dce08d33d92786d,
+                    0x1287ba318455b869,
+                    0xb8a7b8723be3fcf9,
+                    0xb979cb2ad71f8abf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x074d0a371940a604,
+                    0x46f1cdfc342db039,
+                    0xaa83ea9162d29b6f,
+                    0x04ddcf84955aadcc,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger320([
+                    0x76231a8c692752ea,
+                    0xa6ab2e66d1b864a9,
+                    0x0773f58e90258cdb,
+                    0x1079e9f22df39aee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2c7ef1ed68ceac28,
+                    0x16ecc0c2cc95a9b2,
+                    0x1aaaba90afbde802,
+                    0x6a422893cbe6626b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x330d9bcdf0856f2c,
+                    0x6e418910a29e9869,
+                    0xd9b4c1c8cd461967,
+                    0x1b49c2c91fe4b4b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbc66ea25f77cd5a,
+                    0xe42dbb1c279bf547,
+                    0x782eefa5d1414e18,
+                    0xfd3bee74e872380f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xcacba800ed4f2025,
+                    0x20d61379ab3b304c,
+                    0x71f2cfd4be51c48a,
+                    0xeaa33b3483804cf8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa91c50090d63ee56,
+                    0x7aa8abb459747f06,
+                    0x662d8fa0bb050111,
+                    0xaed5cbd34007bf8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x82651dbda4de61e5,
+                    0x8e0e15f7587dfaba,
+                    0xbf3eca22ce5b47dd,
+                    0x6a8227bed7f5d6ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde2021ae3d26a534,
+                    0xc1905194c72a0bd2,
+                    0xa64c6c7385beb56a,
+                    0xc2cdf1928b9f261f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x47c17802d94e3206,
+                    0xcc940d6245983731,
+                    0x041c7c6bf45f0ea4,
+                    0xb599213b007617cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x36e999ae78d4444b,
+                    0x6def9fcf2021dd01,
+                    0xc00d2b8f16c1e34f,
+                    0xb909cb7638b6d505,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56e32c65afa9259c,
+                    0x01d4fce7e36ed82c,
+                    0xde8c3f3ce87a0092,
+                    0xd58d968427208570,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7df1b990c2ddf71,
+                    0x28212ea1510a3732,
+                    0xc44a38eadb452ac4,
+                    0xc77c3df557459736,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x3d252a2ca618445a,
+                    0xde7d905dc61c8762,
+                    0x2685b6a2e10ca8ad,
+                    0xb51d4c99fa774670,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ef5224720e1992c,
+                    0x555980c129079b57,
+                    0xd605e7bea5d49032,
+                    0x2042e4a1533e6eac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd7e125a63fbdab37,
+                    0xaae20bf5a240ec86,
+                    0xbfa962fc7074c19f,
+                    0x51b70e1a33fdb4b6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd96e4e284c2d8d16,
+                    0x6e6e02eb4f2ae36f,
+                    0xcc9ea23d24465c80,
+                    0x5a4f5b3f2202b41d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xe6b7756c1f351b8b,
+                    0xa1c8353c1ad91294,
+                    0xa5c9fcc2256df096,
+                    0x85d8c3008b1cbea5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x69946ef7ed56a66c,
+                    0x3564e7a3f6377e96,
+                    0xdbdedf0f4107063e,
+                    0xb79373e07cbe3bde,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x644fb292f5d7e5d8,
+                    0x8d54b3abfd52d64f,
+                    0x344a675691fa279c,
+                    0x982a44a14c7efdc2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2851d0ff99590d6,
+                    0x48ed15e0f0938a4c,
+                    0x83fe0e38cca7af4b,
+                    0xf6d312dc54f04809,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x005b3f78ce6b6a4d,
+                    0x5d52393e184f5c30,
+                    0xe7226c6296a63c95,
+                    0x13af080db688eb67,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6265e467c16bf13e,
+                    0xda8f45b356856bee,
+                    0xfafd13312e5f5eb3,
+                    0x49d46a480334f14d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x23febc1df2f9fe52,
+                    0xc8b0f337126dca8f,
+                    0x2b5bac913d626b90,
+                    0x5b494829cea2a9bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed1111aa80b74f4c,
+                    0x4187191df28d1491,
+                    0xa6162dff8f6cd2dc,
+                    0x2d9e657913dd26fe,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xf1d3404346cf5004,
+                0x8eea2651cbfe7b9b,
+                0xb78a366360900cb2,
+                0xbb68533f26926b52,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8058eb6402c6470e,
+                0x8d4ef678e13b9201,
+                0x35c6b4d303d0ae82,
+                0xbbd46322d7ad6e69,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x192d7891a9889b98,
+                0xe85865e3459e8d67,
+                0x24a8f198e155fd84,
+                0x3d0742fa4c80dfc5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4a03d50e516d35f3,
+                0x107687320aaff262,
+                0x4cb0282781add00b,
+                0x57fdd900071f4860,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1eb7947c207e3e5c,
+                0x6a1c0bfdb18120b1,
+                0x06972ec60d8da296,
+                0xbb03108c46b7c8f7,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe95ecfd627bb6eda,
+                0x9dfa58605b6c8d24,
+                0xa36f99364bd7eb99,
+                0x77c9606da721a0a6,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9124f3c5f260f7ad,
+                0x3c811c36324b6533,
+                0x77a6c1aeb13848da,
+                0x9baeffe20fcbda7b,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x7a79e8f834275f03,
+                0x3ace02b25a6540a2,
+                0x4ae634a47c617013,
+                0x56f73aef7b45feeb,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xd505c774e5f0133b,
+                0x43cedb0f9104f905,
+                0x46ef3e359ac40e22,
+                0x4c3c52d6dd4e3c1a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3ff449bd2e7b1991,
+                0xeb46a28576713de0,
+                0x0d5d275920964834,
+                0x6f89a9762fcd2945,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 93, 94, 284] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 93];
+        let mut opad = vec![0x5cu8; 93];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
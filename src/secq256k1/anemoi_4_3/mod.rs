@@ -0,0 +1,1180 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0x5a8fd2db40b5b2f5,
+                    0xd640cd95db98e24b,
+                    0x82d1ddcf67d657d2,
+                    0x51451164ae04c06c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72d6c28fc33b0616,
+                    0x3071cf574cdf65ec,
+                    0x81d58249cc31dd88,
+                    0x427d3e5c01ca2e84,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x566681ac692d594c,
+                    0xfd5b1724c403a6e0,
+                    0xd3ccbceb0aedeaf3,
+                    0xf680cc844884c089,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedfae1ecfce608e2,
+                    0x4878e7e598b1beea,
+                    0xf30a33584198ccdb,
+                    0xe2dbb09b4346c01e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x756cc004dc714ec6,
+                    0x6113a5a288e5744c,
+                    0x3a9ea30488d1e725,
+                    0x1ce0e1e0140f0bfa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d1efee880be103d,
+                    0x3215e2c6a19fb380,
+                    0x7f07b3d220f22195,
+                    0x93e52c1d1b617592,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5bf0bc48a434a4a0,
+                    0x7380a8d097101c4d,
+                    0x9e88f577a76ae91c,
+                    0x10b1c0000345ed16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a7d4f0b35cb64f5,
+                    0x0c32b404bf44ec96,
+                    0xfdbb2e95f27f793b,
+                    0x0bbc9c28493b2f37,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa6a9bcc614c57719,
+                    0x97c35d1d8641fc91,
+                    0x0bbd93c495abe3d6,
+                    0xe266e90ac03f2c0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x026ff46c0e9b5a60,
+                    0x433b5373777cfe51,
+                    0x017e77c14e0a1ea7,
+                    0xaba51636bf642f4b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d697b5f15cb414b,
+                    0xbe99902fd08031b8,
+                    0xf687cb4661c04684,
+                    0xe8b770c889fcb2c3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1363bef59dce4c17,
+                    0xb44cac189bd29ec5,
+                    0x6062968d64ddb63e,
+                    0xe59cc1a49db863ce,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc5b4b103215bda39,
+                    0xb6720e71fa671449,
+                    0x757a114c4aec001b,
+                    0x8d73498b62d12a6a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2d58f9b65c3d8082,
+                    0xc76d07ed522df459,
+                    0xead50e79d8ef9c20,
+                    0x85023a053140fb00,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xacf77ecebce0806a,
+                    0x8fdeabf1a0552e3b,
+                    0x918f18f52aabe691,
+                    0x0c96c09f23c1a86e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc48e4124d90426a6,
+                    0x111c405b9587797b,
+                    0x217fc8b46736b3ff,
+                    0x3190cd1484692446,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd35ceb8fd10a62bb,
+                    0x0d5eda973ca64888,
+                    0x3afd31b24d5905a0,
+                    0x7999f27c0275dbb4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa6fae1ac64184b1d,
+                    0x63821665b009d862,
+                    0xb23b18aadd37e677,
+                    0xa7a6e5843d5e41af,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32f97711312392ab,
+                    0x3a81e11b157b8f86,
+                    0xe4df0a2aeae94a00,
+                    0x53da0af1af1192c2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x540ba100106df840,
+                    0x989540cacd0e73ba,
+                    0xf73e8a5103fec704,
+                    0x0a56a9bc86d008c6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x52878c9e840b25d3,
+                    0x2d25be90399c70cb,
+                    0xac1c4d5bc267640b,
+                    0x9268e465922b2713,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29bb0b9e6d079082,
+                    0x4d6306e1c6150cb5,
+                    0x880580ff4592bd42,
+                    0x98059526b9b26801,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1964ca304fdb59d1,
+                    0xe931951a8f62703a,
+                    0x400d8144c911dc95,
+                    0x2378f1542284198a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e1aaa1dcd08576e,
+                    0xe655554ed7677d29,
+                    0x757ee51966be71a4,
+                    0xec033143af067ff0,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x26f36711b54baed0,
+                    0x0ae78d7f0ce5a8bb,
+                    0xfa9e8496ffa635fd,
+                    0xa148ed5fd0e94835,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2a1c10649b75e2f6,
+                    0xcfa6d824f829c296,
+                    0xe60b3e92849470c5,
+                    0x4d553b64ad350b52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa7d1ab76a47fecb,
+                    0x795530604b34c61e,
+                    0x8c9fff0768d750ff,
+                    0x9d94e65227fd4967,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5408b3878b9190f1,
+                    0x859eb6ac80a754c3,
+                    0xb8e1f72825086c73,
+                    0x7367f91d916d541c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2d71c00b994ffa05,
+                    0xb929d6696d69ca2d,
+                    0x8ee391ca6c0ae160,
+                    0xd8319f2c8c53664e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1d7beefb29e425a3,
+                    0xa19ad943eee9deb6,
+                    0x992daf5d7ef3d080,
+                    0x1d5d3b654bb26b59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcfa58eaa230ef4e0,
+                    0xc2ad8efcfe9951eb,
+                    0x4dcb2ce1c3e0cbc8,
+                    0xc65a7e2d0ce2a39b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a8e6fced5ca78b0,
+                    0x7a5911cab9bd60e4,
+                    0x9cfe651ab3058363,
+                    0xe23b145fdebe2b09,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa5094a56d25b0235,
+                    0xde9ea0d437a70ab7,
+                    0x7d75cd0e0d14b574,
+                    0xdcd7d73712459859,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef36587a724e2676,
+                    0xac382c2cce30f2d8,
+                    0x68c5bf3c1a014fe4,
+                    0x6301fc8db8de972a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9802b29f6c50bcd6,
+                    0xdaac436518e99cca,
+                    0xb3bd43713b84c069,
+                    0xf42f674d1b423280,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7fbc4077644b7382,
+                    0x2b8e62873d2f2ee2,
+                    0xd22961ef9e5e2a9e,
+                    0x5c8d26a6d8954660,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x5f5693d48fa7d5c3,
+                    0xc31d5de5adbbc6f3,
+                    0xaac3f414bf56e959,
+                    0x55627d0d33f577f6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x20cb1e649f63e3d8,
+                    0x03cd5e5bc02de140,
+                    0x0b19f0082c74d348,
+                    0xac25cec88cb7cdf6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbcbb55062b8d1b85,
+                    0x7586cad731c5c1a9,
+                    0xd03fbbcd33edd162,
+                    0x6b3449a50cb83585,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f3ee2537cb0dc83,
+                    0x5ad179c59296e0dd,
+                    0xf8322b0c3ee83521,
+                    0x9c9749c363c09b46,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xde89275f892434ef,
+                    0x640b54fdc7b4730d,
+                    0xbc828a9c172f8a02,
+                    0xbdf0e31adaee94aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x748ae34583033a8b,
+                    0xe15d2dd1801d08fb,
+                    0x30612084fc1ead48,
+                    0x1435a722d81ec6e7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75792057b024adce,
+                    0x70aaba4f6768e894,
+                    0x5a854f9b08159c10,
+                    0xf203bc7e1361064e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61af0d7e93bf6a36,
+                    0xded341fcfc0a5159,
+                    0xbf02ced18c8fe181,
+                    0x2ef1076d4482102b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x59b33351970f2559,
+                    0x81fed777b358b76a,
+                    0x4b214ddfc92f76f3,
+                    0x3da9a3fcfb0b3892,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb570d73839aa6b0d,
+                    0xb0d45e8d1d295a04,
+                    0x5c3418307fc90886,
+                    0x65eba442bf514b5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb0a344fab75f7b63,
+                    0xcccd6823f41b422a,
+                    0xf7fbcabe870b878b,
+                    0x6f96bde1cd5a816d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfcae44ee5a4c132c,
+                    0xb6781f9206747393,
+                    0x6175211b79df4757,
+                    0x2739fa7b039606df,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger320([
+                    0xd34a5d7802140c4a,
+                    0xf4fbbbaf57707269,
+                    0xaea37db05cb5fbd6,
+                    0x19ea656554962bd4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa7322f486390cc93,
+                    0x47c66a3560c6b3c6,
+                    0xc12c1733b95922bc,
+                    0xd3146c6789a749f2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17057affb0b7ac6e,
+                    0xce2cddbdaa34b4c5,
+                    0x1d756cfd2c5983fe,
+                    0x8f977ff30fad8cc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe153339cdc409bdb,
+                    0x20f7da3a3393a804,
+                    0x20245e0e446a90e6,
+                    0x0085c2e9d1f716cf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x287fa25a08f314ab,
+                    0xfc2da310c51df3a2,
+                    0xf8b11f348bed7682,
+                    0x0e343258da65d016,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7d4316ad23acbc14,
+                    0xdb30a5ab4d75f649,
+                    0x249d4258d57ea93e,
+                    0x0c8dd63ac87f622d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae0d3b91446eed0f,
+                    0xe9f10cda13efadf5,
+                    0x49ec3651368d7888,
+                    0xcc8adcd7879af9ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8ba61413be5ca18,
+                    0x3a9ceb25f7a03325,
+                    0x3e55908648e1e143,
+                    0x4cd91b07d52c697a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb22b203d61b20711,
+                    0x9c0eeea85cdd3701,
+                    0x156e95af123efcb8,
+                    0xfd7284897396991a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe96b5217d0f8cbc1,
+                    0x2d9faa4782c0476b,
+                    0xbeed16f0b7938bbe,
+                    0xcc538a4170c388bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6291c051a9b97a6,
+                    0xbfe76716caa2e47c,
+                    0x4d60edbc12cb83ac,
+                    0x0a1cbd8ea6e26976,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x67a85ffa84ad7f75,
+                    0x4e8033c54384eb51,
+                    0xa19b53fbf6012a25,
+                    0x75b8ff76bdbe5fb2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xca031844d6f4dfa7,
+                    0xd14b87892875c74e,
+                    0xb626e1f247df5f68,
+                    0xe2fc8d9756e82c17,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8c5ac77fdc17ea2,
+                    0xddef9738fd9b3952,
+                    0x681ecaed30acb7a8,
+                    0xfcca7ead36d5c006,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30ec4b7e544a8541,
+                    0x90ad9385bff3f5e4,
+                    0xbaa305df080eaab2,
+                    0x0735698841fd8b8a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5755624e5c84a465,
+                    0x4de0d7676536bd1f,
+                    0xbc40bcf55ad16a1a,
+                    0xa8e0af44365c67b7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe7818860e629a515,
+                    0x8af81df7e915d857,
+                    0xe3f98004e36eced2,
+                    0xea7d4ef6bf600891,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdddd894ad0f492d7,
+                    0x78fa0aa9340d62af,
+                    0x35beb93264304c61,
+                    0x33d4dc832c7f131d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48a1ea48f986427d,
+                    0x5d18bccd17a21769,
+                    0x91709a586ba3b620,
+                    0xfebe60d96ae0eeba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5edefba9482b3333,
+                    0xe1e07346c5218e2e,
+                    0xc6f8434006121262,
+                    0x3d6284d6e6ddb6c6,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x13b310510f797e26,
+                    0x25c629578f26bdb1,
+                    0x2720cf15cfd9a745,
+                    0x82a3223e5fb12c1b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ffa1022d07bc2f4,
+                    0xc11325ceb1ee24b3,
+                    0xf94b9e93898f2d44,
+                    0x817c77b5715a7869,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2068e9282fa146c5,
+                    0xbb4f78ff6635bd6b,
+                    0x0f92dd5077ee46c2,
+                    0xf2af12f0911072ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b99ef7d234c03ba,
+                    0x548faf435ed128a0,
+                    0x8659e178240a063d,
+                    0x05b59d8fc4341202,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x016d0b997e4df5f8,
+                    0x2a8918ca85bafe22,
+                    0x000000000000000a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4a28f2a5a3eb7887,
+                    0x6f10d0a2f8d452b2,
+                    0x0000000000000048,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa68dcfb2a9fa5e0a,
+                    0x45fe52a390743bad,
+                    0xf6d8201a6e25ef03,
+                    0xdf795c3a18293177,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb624a5de2823d753,
+                    0xd1a17f463840941f,
+                    0x8114f7ecbc62abda,
+                    0xef65f1fe32c7a43a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x430274f440a38f1a,
+                    0x942553b5cd2ab8b5,
+                    0xee5a58fa7add2ce1,
+                    0xedaa428f3fb5f02b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad5617d3c3b4cd5d,
+                    0xc06a1d677b636017,
+                    0xa1eddbd68865be32,
+                    0x0f3f51ddcda71df7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9555411602ac3937,
+                    0xfa822abfe357afa4,
+                    0xf8fdefa26264173a,
+                    0x66150df455e17f52,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x12f3212d95f5ca13,
+                    0x3f62171d264a8352,
+                    0xf38ecfc9863b4bde,
+                    0xd72137e921a9dd71,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbbfb297a09deff3c,
+                    0xfac9f49b16c2d3b2,
+                    0x43cb0cbec6c02d07,
+                    0xe4a524ec8a693d30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x932ac80dd80c1eec,
+                    0xaaaeb2c8e8471636,
+                    0x24798f88a5ceb3c9,
+                    0x0d0edf4f507ba5fe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x952c2797373a11d2,
+                    0x7b536934d46b698e,
+                    0x4dea36441747cef3,
+                    0x93bb4c5388ef5658,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe56ed0712b6437b,
+                    0xa22c171e948da962,
+                    0xe05492cd5a8a346b,
+                    0xd672a08a2f4ee529,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62c8241e3eeea4ff,
+                    0x8dd40564cdf92aba,
+                    0xbf41d4207991c3de,
+                    0xbc822e5d4def41ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaa864018c212d45a,
+                    0xfb493c570007ee6a,
+                    0x882dba9f65c7dec8,
+                    0x31ac021bc86d36fd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x08aa39b315c38d4e,
+                    0xc8109fc94cb7f6f0,
+                    0x8efe6e6e9c98650f,
+                    0xcc860453d6c06c44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x867f0910b4d4d43e,
+                    0xf64ac851faef387c,
+                    0x5113cff378d77b1e,
+                    0x94749cf81618b5e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xadcb72c2aa8e492c,
+                    0x429fe00fa4e2765c,
+                    0xd6b5e60e933814fc,
+                    0xdb5691fe044b3882,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x728e37841ce8fdef,
+                    0x02f3868b26717106,
+                    0x999c50450e973da0,
+                    0x0693677a600c171e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x78eb8c52fc6d2674,
+                    0x6470aecaf6e24aaf,
+                    0x5c309065a0c0e57f,
+                    0x554f568cf6d98e5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaca9a27617841d81,
+                    0xc2b1186895ac2d04,
+                    0xbb12abf9c97692df,
+                    0x89003a5dec71f7b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1c8bcdce695b3bd7,
+                    0x52c592939f93d06d,
+                    0xc10c7baaf78c0d4e,
+                    0x34972ac8d3043de0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x90cfcdd43a9862dc,
+                    0x2b220509161b89ed,
+                    0xd8c7fc0530781347,
+                    0x6ee08c572ffe9fdf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf44007118202cdce,
+                    0x8290be63af873da7,
+                    0xf832251e92c3e42b,
+                    0x0d0a6834792a7700,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdba419d5e8f6396,
+                    0x53085a887ea0d44a,
+                    0xc2aaa2698cea6a75,
+                    0xdcc55124c183b970,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f8a18498f6f6b97,
+                    0x13a2d0d70e959527,
+                    0xf35deeab6b8df598,
+                    0xa87f2223bba73518,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xde13ba6b0a9df1a1,
+                    0x9f58de5f5fe1b054,
+                    0xb724640068cffdf1,
+                    0x8e2901eab2a2e668,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
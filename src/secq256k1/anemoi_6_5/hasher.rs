@@ -0,0 +1,2168 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U5>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x954e5b1a5c733991,
+                0x9c1d68f1b577525f,
+                0x7c5e3b8e59ee051b,
+                0x83dba51768cf1592,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd86c3ac0e373537a,
+                    0x91a36d1ab6634f52,
+                    0x9a4d03156dc7b64e,
+                    0x9cf135e688079346,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65c4268e085b064b,
+                    0x9a6bef18f3d88dfd,
+                    0xbf10bbf27ff8f332,
+                    0x6ba38e6395a4daee,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x719fbb864d8dffff,
+                    0x2b00d762668b4aa3,
+                    0x8ecc814ecd0dde22,
+                    0xf57119596ad8a447,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x637b46ae1964da13,
+                    0xb3b10169f7b1b6e6,
+                    0x2fc89bc4accca810,
+                    0x3454326b50cbf66f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeaac183ef6dfb8f7,
+                    0x0f2566432013aa43,
+                    0x0a13d8a5abc898a7,
+                    0x91ffce881c1a83d8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x045ec3942f8b75b9,
+                    0x15b833b4a2919572,
+                    0x2fb21dbc549fbece,
+                    0x4e70b852c916ce48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x967fe5813c038b74,
+                    0x207b8aa45b3b3b82,
+                    0x5a0d4af43d083690,
+                    0xf75297fe4ccdd76d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ea20a890781b2e8,
+                    0xd4c2babe7c212977,
+                    0xcfb1e827150a36bf,
+                    0xc898bab49d05b704,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0cffb4c0662c9c98,
+                    0x462ab305b829e635,
+                    0x55425ce24da9e809,
+                    0x7ab879d9af8c00fc,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xcb27efe913824e49,
+                    0x3374c1aec77f9c9a,
+                    0xe87dc9cfffdecadb,
+                    0xd399cba457954e72,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x57eef5c2b1faac84,
+                    0x461d776db2f2728a,
+                    0x1e3d7e1900c09134,
+                    0xb97a3f0d733cb08b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3465cbdbb65cd9b1,
+                    0x7d5379b3d8ebd180,
+                    0x0c1f4e0d39cd5e33,
+                    0xb856fd18b2f862a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x268ba068f3798851,
+                    0x07d9bdc7acdc739a,
+                    0x4226b863433af7ef,
+                    0x2f6a37b9b47799dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x645abaaa4e8e4824,
+                    0xaa8086f290ab0dca,
+                    0xade36a88797d5620,
+                    0xcf2ea9470dc8180a,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x0d83c1216d4147ff,
+                    0x017eee87c3ac6cd0,
+                    0x73c1f68b26edc034,
+                    0x2558ce3dc1206f8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xad326d9d03934850,
+                    0xeefd1f8eecbe7f9d,
+                    0x2b3c6b3e6e388135,
+                    0x73d1a9d7eb70c4ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b8bd5e5fbbed425,
+                    0x35616a4efdbb6cf8,
+                    0x034a931da893f271,
+                    0x6ef18ea7c3638193,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x84f4571e4fc48766,
+                    0x6920c4cf6d567861,
+                    0x9d2c15ae7dc5297f,
+                    0x48d38d3da8a1fbd3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc6af3be5593e4f0b,
+                    0x36c98e7ff9e7b403,
+                    0xa6000681dc16ba54,
+                    0x9e38c3c1fb2340e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf57c629dfaeecace,
+                    0xa4ca1d76ac9037dd,
+                    0x3394cde6c5e404d6,
+                    0x813f30c23559f1b2,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x47093ada7efaf9f6,
+                0x81c733b169aedea8,
+                0x0c6c19721e6aeb77,
+                0xff985b0f27727fb2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x5b93d365277c3324,
+                0x5ce82666e59d24d8,
+                0xb3afb754452ad83a,
+                0x2df4c777e32df94d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xaf77125f3246debc,
+                0xb5f649381e572db5,
+                0x341e22e0d56931de,
+                0xd0298b45078b1ad0,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3ca04936a559ac45,
+                0x137bd8801a96b483,
+                0x322c40ccf5903f2d,
+                0xbb6bb8eeeda0601f,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x6ab51c955dc8e610,
+                0xedd7d14d7dc8be15,
+                0x80668c0c25712ef5,
+                0xe90ebc5d53eeabbd,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xef86a2f44b6f21ef,
+                0x33bf4d4fceb748ff,
+                0x26d81356eb968163,
+                0x952969a2d44afd80,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xfcadbe631ef29272,
+                0xc6cd9431af4f2422,
+                0x7c169b138de2256a,
+                0x50ec42a5999ec2b5,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9f04ca3afdb5053a,
+                0x2219228dbb20eac6,
+                0xca5f12ce6b1f5858,
+                0x29d0b163ad4e7301,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xdc1cb40c85c84cf1,
+                0xeca0d44cffeed953,
+                0x62adfdb0d73afb1a,
+                0x3860c02735fcdd73,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xba92cd84e3da08fa,
+                0x192c01341b2769e2,
+                0x6baae878d600465d,
+                0x38315fdbdb2bfca0,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x47093ada7efaf9f6,
+                0x81c733b169aedea8,
+                0x0c6c19721e6aeb77,
+                0xff985b0f27727fb2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x5b93d365277c3324,
+                0x5ce82666e59d24d8,
+                0xb3afb754452ad83a,
+                0x2df4c777e32df94d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xaf77125f3246debc,
+                0xb5f649381e572db5,
+                0x341e22e0d56931de,
+                0xd0298b45078b1ad0,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3ca04936a559ac45,
+                0x137bd8801a96b483,
+                0x322c40ccf5903f2d,
+                0xbb6bb8eeeda0601f,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 186];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6833501985688a5e,
+                    0xc38d1a02c53ec208,
+                    0x8858b9e676814d27,
+                    0xda5a6cb9df4a24b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf150937df50c84f7,
+                    0x21dba44f52b8a30a,
+                    0xd6818969670c02e3,
+                    0x13ebd7c9346a6685,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5abb42ddc6d18d34,
+                    0x70e7da598f690b0d,
+                    0xf0961d8337f5b11a,
+                    0x53d1fefab17bd549,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94a6fbe2d78b058e,
+                    0x0a74827e9d58b79d,
+                    0xd0c59d167bd4af67,
+                    0xd38ef0ac9df1b1e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4de41902c85dd40a,
+                    0x869d259e6d94f855,
+                    0xf63472f73ec7653b,
+                    0x0361454645bf0739,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd46533503b2a8e4,
+                    0x06be35783ea68cf6,
+                    0x73818e5b1e3b1fcc,
+                    0xa77f082eed77835d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x653baef1c96871d9,
+                    0x476fbb36b1ffd503,
+                    0x95e84f772fa75079,
+                    0x52965f3f09d1ef7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x45535c35859a8b8a,
+                    0x2644da5f0582c98b,
+                    0x6f4a8cc752bc8780,
+                    0x949b3054b4814495,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfc5916dbdbf5d876,
+                    0x1c1f0657d61944f3,
+                    0x3c321b2d68ce0f8c,
+                    0x65f3426f6e88b836,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf45b316ee9a577f6,
+                    0xa426cb61e48c45c7,
+                    0x0336c76330562185,
+                    0x7c9a780d88ce442e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01fe5ce790fc3eac,
+                    0xbc6a62f85ad59ba3,
+                    0xf554e646a3157f7b,
+                    0x18cad5e8b2cc881b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x428eebcc467f04e0,
+                    0x37f1d8e72e7fe28c,
+                    0x09951c7171c3fc93,
+                    0xf9599d5919a10b49,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xefc80b03b1775e60,
+                    0x75198b8f72da2e9f,
+                    0x32908cf26615a08a,
+                    0x8ea308589c4e38a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd64499d51256804,
+                    0x87d7057da9b9603b,
+                    0x1730e9a193b3fd01,
+                    0xf4206252ce1371f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0ed84e9ac08e7aa,
+                    0xb1f33510f9387077,
+                    0x6ddd6d7da8f503ad,
+                    0x960c1f1450d28707,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02cc41c2daf96696,
+                    0x1551573ec60b146c,
+                    0xd52eeca7609790ae,
+                    0x4871fdf6ce14f3a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bac1c9ea7e9a009,
+                    0xb0d368d4adfdc77f,
+                    0xe5ceed068995d0ba,
+                    0xb0ae6724594c2b47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbad893f15241e1bb,
+                    0x85312ec793b5fa7a,
+                    0x04619f9a9ccb0c82,
+                    0x4db9f0010a8ef216,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6f79ce1cf13b3f1d,
+                    0x56f7cdc68c0d10b3,
+                    0x0dfacb72e1d1a79c,
+                    0x8c994629e2b92083,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x118be67554e8b0de,
+                    0xa1b4a4d5caa7d702,
+                    0x564558940cd05ce4,
+                    0x899a65c72f9213ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a4f6a5865c9f6e7,
+                    0xdcd2be383c0532d5,
+                    0xc4d1ab074e2a1b1c,
+                    0xca4f015eb35507ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf18b46f13709d442,
+                    0x8dab40163fed56bb,
+                    0x3b2cc1e36c245bfb,
+                    0xee4d8a756f092372,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b7edad4be9bfee3,
+                    0x12991b44511dc7c1,
+                    0xbc92d6bea327d269,
+                    0x46768ff693f1dbb6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcfd542e11ef8bd47,
+                    0xec24c6b6245200fb,
+                    0xe7c94c532e541c48,
+                    0x1be8bcaa95246315,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x58ccd19343451319,
+                    0x1ae45255135f3e6b,
+                    0xcca9635bc3d5f188,
+                    0xc6591730630e4d75,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbec25793a7773588,
+                    0x39d1724e35030a1e,
+                    0x48140f486f2e460c,
+                    0x4958dc650dec77c6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x871af519191ebee2,
+                    0xb5eaf78bcb1807a9,
+                    0x0040260803379d7a,
+                    0xe8fd2410b593486a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6fd0fe5baf5bd7d2,
+                    0x53d90ad3b1fcc855,
+                    0x26bb4ca1d65add59,
+                    0xb66fbeaeafa00f73,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31141a0289fbfeaf,
+                    0xc669fc8c4cf02fff,
+                    0x05f86d6269c39d2f,
+                    0xfedd50e935350ae2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x709ced22a32d2e6e,
+                    0xef0c01041f4d4e80,
+                    0xd41d974f37759ca7,
+                    0xefec74a0f14049b8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x054be8dadd4939fb,
+                    0x9c0e500a52c3c59d,
+                    0xe8708fa5569f4a0a,
+                    0x4ce8a2820e1b70fe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49af582d4439e217,
+                    0x03317eb3471b8bb8,
+                    0x226cc39e5142ed3e,
+                    0x830c84ad6cd0228a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb30cec38fde81ef5,
+                    0x54f8f511b459d924,
+                    0xf57a592e332de94c,
+                    0xe3853a38d9d88e2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x600161b53164de73,
+                    0x446e1954cb4e3bb5,
+                    0x43b7c7f1c13afc44,
+                    0xcfb6df27e107a19e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x28ea60d33b3adcd2,
+                    0xa3b50fd23e0d2183,
+                    0x5db7b270603c35ae,
+                    0x12f0790aff1a7da3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef2eb9d0d9fd5bf1,
+                    0x9ce411cb762587a5,
+                    0xa77e9c60ee3f5a78,
+                    0x3c6750bf2452c9a8,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0x09d1b0db5d01fd68,
+                    0xd43e8db2c472d5e0,
+                    0x6b0393b50b324706,
+                    0xad33717c7cb45a5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x302e877b99f7f984,
+                    0x53ea9e1c9e21545d,
+                    0xe99e1d7d2b0b343f,
+                    0x9adc8e8030a954b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0d90377b8d1eabe3,
+                    0x9681dbe5df88bf54,
+                    0xbbc5efe816621760,
+                    0xc43afc0800043984,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2df25fcfb99c19d5,
+                    0x27f50e2c84e2d96d,
+                    0xaa65f4d31ca3208b,
+                    0xfde63174844c421b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcdec7bc35deb20e5,
+                    0x9a9bc4c3976a286d,
+                    0x514784d7d251aa61,
+                    0x761869c8bb49477c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00cdd6ae0a67114d,
+                    0x8c3c01b793112380,
+                    0x1e47eba60a6bdf1f,
+                    0x385c637115b54dde,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x976ba3f35c99de38,
+                    0xbe36cd060ea4f2ab,
+                    0x017da71ba91c8538,
+                    0x023a28d5781de424,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fba7685d6d9dbef,
+                    0xe0edc861399fef86,
+                    0xc48183ad46cef79e,
+                    0x25cf3c63e0819e9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb3f5d18cd83bdb3c,
+                    0xa1f5013f2f120656,
+                    0x5d367e61dc795357,
+                    0xefe7dc096b4484ba,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2dc677b9244ed84f,
+                    0xbcd3171264e38bdb,
+                    0x06d111612069fb0c,
+                    0xcd0f511c01ac8262,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8a1ba86acdd2eaba,
+                    0x1edb4088f16f9d12,
+                    0x3ed509c5e21d02b4,
+                    0x3b3bbfddd2593515,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5f81310af7a2c70,
+                    0x6899dff829f68c78,
+                    0x18ecc4ee33f83277,
+                    0xf5d938e8a1b1f0ae,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xecd452e53dd9f887,
+                    0xec25f3d1526bc782,
+                    0xca8966cd77ea4b23,
+                    0x29d79e29d321998d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98ed8f5531129ba8,
+                    0x77638defa3088d5b,
+                    0x11ef72de15e0c48b,
+                    0xf195cef3606e7626,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x00e2410d551534ab,
+                    0xb9a975d270ef0309,
+                    0x047d42c9f3806c50,
+                    0xa6e1e69fbbf33ddf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3b7b2c7e933ec805,
+                    0x9d6a3f281bc7abbf,
+                    0x0a71771347a83a3d,
+                    0x04010477d63f2ad3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1e015c0acead9e1e,
+                    0x69562ee80a59a39e,
+                    0xa00431e3be57b747,
+                    0xab6e935251a198ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa95ec05430228c18,
+                    0x07c9bd1710a70a69,
+                    0x2d938304a1362af5,
+                    0x5d1ccb8644a37c43,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x44b92c0cbf98139c,
+                    0x35abde7e736e80ff,
+                    0x861d6c22c49e343e,
+                    0x436216634721496c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d906d3d1cfbfd0c,
+                    0xfeb449561b1fecd5,
+                    0x5edfb6667b6e386d,
+                    0x88f52f12ae0a6890,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4182abf8d057a7c5,
+                    0x5dc19aaae9e8faae,
+                    0xe10b9a1c2e23f118,
+                    0xf810150e9da9731f,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xaf5fbc2a2159f646,
+                    0xef78be1b2c288d74,
+                    0x55f37d1a0cad9758,
+                    0x28529d4a2363ba22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcbec347ae01754c2,
+                    0xac58ee80c0fe8785,
+                    0x70a469020dfa2c3b,
+                    0xbc80cd719f379e5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e6874b2d674098b,
+                    0x8ee1a57da987a8a5,
+                    0xc415831d6f117400,
+                    0x8a4551eaa4329b89,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x790c0104f823b6fc,
+                    0x2262758e140172d4,
+                    0x3c6f9ff6c920c21c,
+                    0x647f3a0baa5f2bf0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe1096c1047c33f21,
+                    0xa84329502f7a5963,
+                    0x78e0f6a50e0aba9e,
+                    0x08013d3f58ee0b0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb0ca19b48a734d3,
+                    0xbc967e359f7d7f36,
+                    0xa685f659c130b9e9,
+                    0x79f18bee9745740a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa2001a7e2d847aca,
+                    0xe5d1aed9c0b9bf69,
+                    0x21684f477451ab7e,
+                    0xb4863a5875fee087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x09c7ad1b8908b084,
+                    0x520eb0e971ff6ab9,
+                    0xb3d7c1e2998acc54,
+                    0xa31a395500b33c89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75da43b53eb092bf,
+                    0x2ba488716ab3a894,
+                    0xc253844325e12c53,
+                    0x69b241695bc1b736,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x5bd6311c59dc47dd,
+                    0xe55acb63cf03cf7f,
+                    0x55fc6b2769866579,
+                    0x69688efcaead87f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x292e4b8643cc6559,
+                    0x1c32ec4d01e7a471,
+                    0x09bec214c51bda9d,
+                    0xe999de65c83a7011,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bd35217185f7230,
+                    0x7e2fc2fe23219c3e,
+                    0x349fbdf8c8b64776,
+                    0x6127fccf4b2d458f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73c6e5a0941156ff,
+                    0xa2a86e1293d584fc,
+                    0x9b99a6bedcecf371,
+                    0xd801ea96d11e9609,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x217f7e706804bbf9,
+                    0x5e00adcd72fa75b7,
+                    0x8e2f04abbebab4c6,
+                    0x4e52b4394296583f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd8ab544ad90963d7,
+                    0xc0cf70e857edfcb1,
+                    0x0627af0245097cfa,
+                    0x6e53d63c8889c764,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xeb9e4d1704ce574d,
+                    0xa1d74cd0728db2d9,
+                    0xa00c21c8343e5d0b,
+                    0x5ed58725bbe907c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x689671ade6dea175,
+                    0xb9f25a198b32d02a,
+                    0xa6e4339ae1221dfb,
+                    0xbf991e8c1f8e534b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc8e6a0f2b3d381ee,
+                    0xea9caa8bebb226f0,
+                    0xdaaf423f9c70c500,
+                    0xd07600cc13ab543a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb8d1a84033b9abe7,
+                    0xe081d8eb5021d9aa,
+                    0x836171abeea56cfa,
+                    0x38edf751887d0926,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed9ab8f85a2ea4ce,
+                    0x362b3b5d38878094,
+                    0x75aaad4be2d8c843,
+                    0xce01be0e827b1f2e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7daec85b28cb4689,
+                    0x4bb5b6e4baa43cf6,
+                    0xd1e2373653b184a4,
+                    0xb64e2f45f1d4339c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x9404ee8fc4b4868b,
+                    0x85b81c51a42d34c4,
+                    0x2e9e1eab04f1122b,
+                    0x8b03cd780e6f2a0f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0db9fa5777d4554d,
+                    0xbee075d9272dab2c,
+                    0x8f91821752ec1c18,
+                    0x497a7c8475d2a3d3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9048e2369bcaa35a,
+                    0x78fa0a1cac46949b,
+                    0x926c320bbeba2ef5,
+                    0x60a7e4f9a527b24b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73f64b364612bc80,
+                    0x255d3eedbeef7e8f,
+                    0xb8ec91e6a8675de5,
+                    0x499813d12b221829,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x11e7a1966eff208f,
+                    0x807db63ebccffd6f,
+                    0xbf4728325346e376,
+                    0x3b78a193e78b456e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x199de75e714f8720,
+                    0x2646ea69e72cee5b,
+                    0x054194dfb81e16da,
+                    0xc565da8517308d08,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xa835ba589659b478,
+                    0x4a1f20ce841216e8,
+                    0x62156d02bb093569,
+                    0x34833909a55b5c9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a19cee7794904ad,
+                    0x76ad89874df394e4,
+                    0x93a18a0358ee5663,
+                    0x184f9dd00ef9ee23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd4e9e1b753483aa,
+                    0xf7e03baa9eac1e60,
+                    0xa948b4496aec71a1,
+                    0x53685684cc4b263d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf1a4b7e06f8a71f4,
+                    0x36bca39185deed6c,
+                    0x9b466ebb71471444,
+                    0x840a62604786b300,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf20a3364a5afd565,
+                    0x65d8a0197be74cd7,
+                    0x56109e2e31b69d5a,
+                    0x911a898d1482c1b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb1e4a38a6a7992dd,
+                    0x57792bfa668b539e,
+                    0x181b01ec908dd13f,
+                    0x2cdb179e4744f78c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xc3bebc9a3fb43ef8,
+                    0x19341b330c18d8e9,
+                    0x643c7ee2e41ecb7c,
+                    0x82a3a1ce9962e75a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0160126749b65d3d,
+                    0x3d785b1ca72722c2,
+                    0x10972166432cfe86,
+                    0x3ac8f6454185ea7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x230eb0e09e4e1258,
+                    0xe9fbf70f9b3ebc6b,
+                    0xf374b6972831fa42,
+                    0xd78463706ffbe676,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x088538d537797742,
+                    0x5c1963f14128ef3c,
+                    0xbc019f598f815efa,
+                    0x619d448bce251c64,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x930154d505f180b8,
+                    0x408c0b8fc0ea568e,
+                    0x51407ad5423dba51,
+                    0x4cbb3d14331cb5a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cac14ffb8dd05ed,
+                    0xf37666750d0c493b,
+                    0xbd0241aa726db7c5,
+                    0x1ba6657ede6672c0,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf0920d96fb4c6f5a,
+                    0x0acb03c7f7bfcbcb,
+                    0x5857afdd335fd55a,
+                    0x9176d923a752ac8d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf0f21a4ba8c926a4,
+                    0xf9688fbe85a891e8,
+                    0xece6da3ad71a80a5,
+                    0x337fd0ab6739654d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15f58eb4fd3e7473,
+                    0xbd818890e18480e4,
+                    0xfeba399e25c1ea96,
+                    0x8980b2532b7062a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94bca283d25bac81,
+                    0x8cbf98bdbb9ec408,
+                    0xb9e8632190a4e45b,
+                    0xc103ed07fd93765d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb437a4b2d25b7c28,
+                    0x5f65498c4dc0cd13,
+                    0x8eed9366b6faeca6,
+                    0x70ab40096855ee78,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x701918040682e041,
+                    0x1ce20d001e9660d2,
+                    0xeb3959c5fcdf4be4,
+                    0x386a2d262f202877,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xc7ebb2b8e3ac204d,
+                0x494d4de7e38ba919,
+                0x1067a11a4c9f92a9,
+                0x0c4afc04ad61e89e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x3cda53b451b80ac6,
+                0x941df7c10015851f,
+                0x19f56550f960aa0d,
+                0xac5afeae554ad776,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x2b498d793b795422,
+                0x866ab9bfc80e484d,
+                0x2335a92acc64d030,
+                0x17f14142c3e4077e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xee07d4a7d165ae38,
+                0x89995aacd101152a,
+                0x5e92e015367f3039,
+                0xfe2449e275b7a825,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4ede5b4a05697a36,
+                0x42f40e9d97429958,
+                0x34d73573d6680f78,
+                0x145f7456d929838e,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa3ba6c8156e8a705,
+                0x78e2a134d119c371,
+                0xcdb2949912c13f7b,
+                0x4939520be9cb320f,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xf2ebfdef54b3440b,
+                0x2659089bd8cf3c48,
+                0x57cd47c2f5dd0ce4,
+                0x20eabd194ab742ae,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x6a9c01f80da0be59,
+                0xc41fd9e083d3585b,
+                0xe2e34d0ccda237d0,
+                0x607c1eb5e7bb1558,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1dbe252200ee545b,
+                0x5f8c5702ae74a8d5,
+                0xaa4ae4f2e43d882a,
+                0xf648318fa692883a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc28b55fc378873fa,
+                0x562116c724598ffa,
+                0x1f92826cfffd92be,
+                0x092161610ecc8db9,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 155, 156, 470] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 155];
+        let mut opad = vec![0x5cu8; 155];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
@@ -0,0 +1,1631 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 192 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 5 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 5;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x723b7dc92effb718,
+                    0xd6c1ab7434d8d7d7,
+                    0x8525c059de3ed32d,
+                    0xbb630da6beb93b57,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc181d58ecdd5559a,
+                    0xba08516d59b3660b,
+                    0x40954ce90f1753a5,
+                    0x7214d6d2129c14e0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x06cd0978bcb9175c,
+                    0x2e86941635f4af83,
+                    0x3351ab0860014a58,
+                    0x7dc64f1ab6e129a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6b39bf649b45a06f,
+                    0x296cad1a7730e250,
+                    0x11286a0e852f959b,
+                    0x73dfd772bf4bdba6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb937e56c25f553ee,
+                    0xd7ebc6a6c2aff43b,
+                    0xf5ef59de8f90cf14,
+                    0x7d94591faf72aa92,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x661368d372848b6d,
+                    0xc68ce779efb0f0f2,
+                    0xa9d180c8b1bfcb91,
+                    0x63a5176ab488805c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf548bd548d0b8f0e,
+                    0x65fd600ef77edbc1,
+                    0x014518a6f71bd8b0,
+                    0xf6ade2e73c89433c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7725603fcaad9ae,
+                    0x2a5dad86ad67f6a3,
+                    0x38563eb672902e3f,
+                    0x81cc12209b395a83,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x232d6849771d04e0,
+                    0x31f7a7fcd5b83b34,
+                    0xc2f77af5c4a96d7a,
+                    0xc5ca19c02ec5b496,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x819ba34b41a1b427,
+                    0x238fd4657b1f392f,
+                    0x3cd61777717bbfef,
+                    0x1cdbdc9aa463cbad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x556e82288b8ed77e,
+                    0x21fd3ca969c7ae32,
+                    0xf397230e415cb5d7,
+                    0x9e463adade52c580,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa3f8f5aac6f13cdc,
+                    0xf09f207e8a6d6e2b,
+                    0xf4c200678a08cfc3,
+                    0xc9fd997ad70269bd,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x9767e632368a55ff,
+                    0xf2db9a861ec4dfa6,
+                    0xfa2a1aa87c695841,
+                    0x535a64dcecaa4dd6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x49aa423b882e39fc,
+                    0xc9c3e7dcb4455ab0,
+                    0xd283af07b1b53fa4,
+                    0x83cddf21bcf06a48,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x658702a2fa1239c9,
+                    0xc1e7f3d9019103e8,
+                    0xc71c560e81f7d4f9,
+                    0x9832a9a8a6c77abf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf72a5b26d8cdd0fe,
+                    0xc535a0eda369ece8,
+                    0x1d99cde646ec2547,
+                    0x3925cb702c387502,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x543dce4ffcb94881,
+                    0xa2e92fa65310f4d4,
+                    0x20e7b00da8898de8,
+                    0x1eb26372f64c1b85,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8747b7a971305c27,
+                    0x37011305b3796c5c,
+                    0xbd6a746ed23bb3be,
+                    0x91c6d971e6d80fe9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xea9582cf7c97fc9b,
+                    0xcf9257f7669c177a,
+                    0xcf087edbe4a6483b,
+                    0x3ceefb8f75edf810,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x374e796343dfab87,
+                    0x27e249063baa8c7c,
+                    0x0570e690b894433d,
+                    0x621bc82bd2611e0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1cf62b6e1f53155a,
+                    0x583458aa8681eba1,
+                    0x341aa7997c230349,
+                    0xb4eec6a0b15cb59c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd2827dcb4e4c2273,
+                    0xbf47f786f4765b9c,
+                    0x503550b43ae0700f,
+                    0x7baea0431ed66e98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9681324fac308e22,
+                    0x8e0cdc40be5e100d,
+                    0xbe0c9182eefbd177,
+                    0x1b9cc31564ec125b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f0cf9d97d72902d,
+                    0xc552326cc4d91029,
+                    0x12d610025063abd9,
+                    0xb8a4dfb3f1f7ce65,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x333396e4a4459e1a,
+                    0x3b533fb4cfbcd1fa,
+                    0xd45224c310e1ed71,
+                    0xc36cfd827df1a226,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4146b9e9d3429a00,
+                    0x7e77939b7fbbf3f7,
+                    0xafa7b9df55acfd27,
+                    0xc73e2d5ee76a5121,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x041600dc7e236539,
+                    0xecc068d843fa613f,
+                    0xe2215c693abe54fd,
+                    0x1a93c8a1b120d46c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff9e32420adbb62b,
+                    0x7bc47aea971e1dd6,
+                    0x380e5ebdd1ecbcbd,
+                    0x20c85254456cff77,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x34217f030fde8673,
+                    0xac59a384f5ba218b,
+                    0x60c1ba0f1547698a,
+                    0x88cd1aee756fb8ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x273288d7386d5808,
+                    0x4991f00a565b1de0,
+                    0xf460ec16fa6347a7,
+                    0x2609aa8aa3e60ab9,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe5ef73190a9198d8,
+                    0xdec48a945e993eb9,
+                    0xa24bf505fc5a5fc4,
+                    0xa0444ca18891213f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbe1fb50d016562d7,
+                    0xea925a39f62344d6,
+                    0x3d08a34cc3287630,
+                    0x3b2cf99f766b2757,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa04f120a5e5d9711,
+                    0xb359920a590f5f10,
+                    0x29beafa8fa44cd31,
+                    0x8d05ee64fb1fe047,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd0c3b7e8aecc9db2,
+                    0xcd57042ff46e0c97,
+                    0x0ba0dbdccd4e35d3,
+                    0x5e0bb1f040b75032,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x935d71c5f4e8a7c8,
+                    0xe1d5ca329858f950,
+                    0xb71325cc761f6497,
+                    0x98f578707c6672a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x266c890d4e857739,
+                    0x647d9681d9aab9fa,
+                    0x0401660cda4d33a9,
+                    0xb2310eef3ff8e93d,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6675cc291452c26d,
+                    0xf072d92cae8bcd26,
+                    0x94841a73b1cbbbe1,
+                    0xcc0ee20ecb370be1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9bb6bc534051dcf,
+                    0x3310ec59a9821ad8,
+                    0x8b28c238f9a5a7c4,
+                    0xfb88c1251ea38cc0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb23f067f0ff5108,
+                    0x22770a9a4098f8cb,
+                    0xe6095042aafe9a8a,
+                    0x9ed269cf4d8a98b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38985e82e3521d84,
+                    0x9a59751395205dd6,
+                    0xf3a1b323f59f676c,
+                    0x940e39c77d9205aa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54e04fbaa0db8b85,
+                    0x95ee9a661788a096,
+                    0xfad21d5b2b9c7f03,
+                    0x68515b19fa9c8aae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9349b20e6b47f710,
+                    0xcdfbb395286c98f8,
+                    0x40590a88da5aaf00,
+                    0x3707488605a9abfb,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf1d1ea1c5793ca0c,
+                    0x0b81bad72b2ae622,
+                    0x2c02a178d2d50537,
+                    0x5f17b9dfef456d89,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91cee060e4a0dd87,
+                    0xa36fb0772e5edd8a,
+                    0x5d3f9f035b48e5fe,
+                    0x9be71461b2510ac5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4a093cbd3df6767d,
+                    0x173348131487f3bc,
+                    0xd4414b09eb4351eb,
+                    0x69627b4685c4a672,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc3c60347620b06a,
+                    0x63aa80b7d7430228,
+                    0x2a01fb063063d009,
+                    0x146e1ab1d2c59aa9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0ab3e53fcda10015,
+                    0x6371bd7308724112,
+                    0x605e033baa7ca4d7,
+                    0x4e1749253605410b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xff53dc14d5171898,
+                    0xa0d1f5d5825006aa,
+                    0x14613df1bfd1e390,
+                    0xbfbef18e59a97a98,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa2987a93520c0951,
+                    0xcaa03b78e4606e89,
+                    0x0a54776b35c6dda7,
+                    0x8aa430558382406d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1bc8ee8f2f756ab3,
+                    0x9a3a69d451ec07ff,
+                    0x840f18eef9aeda1a,
+                    0x29e84b83714ef4e6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6114b38f284986e4,
+                    0xd5e426926fc3486d,
+                    0x626100df86e1045c,
+                    0x43501ad65041b0ca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa827725f678d8ce4,
+                    0x1640e733312461bc,
+                    0x16c51861df0c34c7,
+                    0xba419c3ba0044afa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8f68c9b45917661,
+                    0xf88a3d75af9ee310,
+                    0x9a7c7defe2f01920,
+                    0x010fa07abcb744fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x518da85c80e3368d,
+                    0xd7e9527152db78e1,
+                    0x90f8b53049756e1f,
+                    0x8b981ffa22f43492,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf32f79fede4020f0,
+                    0x2aa59a9003b340c6,
+                    0x821c9562c5254a6b,
+                    0x6eacc648b2013b5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeca8bd2098efaf81,
+                    0x2d2ca8fb6f5700e1,
+                    0x2265b5cdae54c476,
+                    0x23a3db2e91fe7c44,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe177d81d19e2a87b,
+                    0xdd35d24c5b43995d,
+                    0xbfff52fe51a8465c,
+                    0xfca2e472088b594e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60ca3ab5e9058e09,
+                    0x32c841c3fa35154c,
+                    0x860d8f07a0b551c7,
+                    0x290854fa3d8a47dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb4817cd54386e7b,
+                    0x55d21f78df4ab060,
+                    0x862613a5e943aa2a,
+                    0xb37d2b0222e33abb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f5c0f9b94c0704e,
+                    0x2dc2f58b3e87be46,
+                    0x36826ee723f9ab09,
+                    0x01979489c9702301,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x356942e70f7876f2,
+                    0xc8b78d4bf3ae7dc6,
+                    0xa8e446129a9189a8,
+                    0xc9586c38f54e865e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43ad97e544e45695,
+                    0xe81c45b489b4d24a,
+                    0x3ad2489b04b1bd3f,
+                    0x4796b760030a4299,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x15bb29e33fc21591,
+                    0x771059b255c12618,
+                    0x5d8d3896070299fd,
+                    0x4a02a50d4ce42764,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3008e948827eca25,
+                    0x22af6be47f3847cc,
+                    0xa3854a35ead5a605,
+                    0xaba996de1fc39420,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0bf5f603356a8da6,
+                    0xac76af08ae5e1467,
+                    0xff4dfef4466a626d,
+                    0x39f1e949d8d4a9e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5107c24ff0026cc,
+                    0x9645b98ba0475a78,
+                    0x8a78e21f15107061,
+                    0x1eb78bab3701ec1e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2924897723749a5b,
+                    0xaafe3aa289097595,
+                    0xe3ce9d243defb8e3,
+                    0x498aa6c5e576ffc8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8210a3e91fcdf2b8,
+                    0x386af0fd601ed450,
+                    0xd96d4132c54101e0,
+                    0x6ed41cefeddac16a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc6052f4e227cc8c,
+                    0x76d5c55e15f8892e,
+                    0x6b2ab44ca05ab3a6,
+                    0x99018c3836907a0e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa170b4d1027f06b2,
+                    0xd963d67520cc769d,
+                    0x8303b15c7230bd04,
+                    0xab6fe12227c27ad7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x522f96c66f238e57,
+                    0xbe3d488937a3a8ad,
+                    0x5da7e21b03468185,
+                    0x2023a2e0c307a080,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c5504d72356732a,
+                    0x28bd68c1588496a7,
+                    0xe01d1ee7ed8d2510,
+                    0x08809975f62ce5a4,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd8f883b71433b874,
+                    0x640faa0b765e11ff,
+                    0xad7c6a68f1afc109,
+                    0x7edec183d3fb8264,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x106f29da5c67da74,
+                    0x0f55ac496c1c223a,
+                    0x1a7f40e0f0e5cabe,
+                    0xcc5851c2ee448c90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3ea7ebfa22cc967b,
+                    0xe573378ea1c1c2ec,
+                    0x8567a04999c8611b,
+                    0x75f324c6ec3fd6c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76b8d9397a98977a,
+                    0x7397ede6ba9ca853,
+                    0x8c6c050a4926ef98,
+                    0x431579481a52a2ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x138f93b4768499b7,
+                    0xd0966fc40143a4e7,
+                    0x0a3e9d47d0941340,
+                    0x1b5d4610fa4c2e3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x623cd9fbaca53c02,
+                    0xe8ee061cbba3720c,
+                    0x71d619cb1e9b1d93,
+                    0x9ede99d2b6502224,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x12f32e4a792312c6,
+                    0xd37a2fb27d0c9df6,
+                    0x54c0c1624d96b65b,
+                    0xfe004365ec901c2d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xab8c7e68dbce13be,
+                    0x1359e5726f876df2,
+                    0x6f9c395e87da5051,
+                    0x3dc3b706fb41dce5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4fd31a609fea4749,
+                    0xd5167a9e58d1d49d,
+                    0x872b29a6fe5a5923,
+                    0xe10b10ea23a6a088,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x88d997ab92028610,
+                    0x6b66d66dcaa1b991,
+                    0x783d67426f37be67,
+                    0x683071aecfd9ce86,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x035e134ab4781260,
+                    0xb9fb025414a0c4f5,
+                    0xe5c0227aba65e202,
+                    0x85496bb05d84bea1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x983883b8203e8b6e,
+                    0xf8d6bc31664348e5,
+                    0xe169ade782440ff2,
+                    0xd93846b7a644e985,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x188846a3804f1f19,
+                    0xc96d8f61ced5b1fb,
+                    0x038b329172c017d7,
+                    0xb690576b33278daf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2babda718266d7ba,
+                    0xcf8eee8c4bb79b8f,
+                    0x1d93314d8c335d2b,
+                    0xd7674a55e9e4dd4a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf193874c06aa270b,
+                    0xd30d85bf4cfb0997,
+                    0x25bebf6a9c441c49,
+                    0x2231402fd1ddb7a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73cee4b8e30b8e77,
+                    0x23cd866f33c6d3e4,
+                    0x524269165c5803af,
+                    0x8b45c9818199b979,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x168a74ff1e4f948e,
+                    0xf7c4eb2565281192,
+                    0x35ce4105cff0f965,
+                    0x12ca4e1f95e9f9b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac806d7dd502f5c0,
+                    0x0483fc5ff8002bf7,
+                    0x9fdcb40b8d55b5a1,
+                    0x686c5abf86a535bc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x014ebc452eac5032,
+                    0xe118d4f22980e5b4,
+                    0x42d1f32f92153526,
+                    0xe0c096e7d9882e24,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c3375792e4f54c1,
+                    0x8339216cc6dc3097,
+                    0x9a75aeb4e46a5fed,
+                    0x95b680bc4b032c88,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3338f9914c2d064b,
+                    0xe9e822ed905a5596,
+                    0x374d5fffdeddcb76,
+                    0xdafb26ea2198a299,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8c3e337c30b163c,
+                    0x82bbbc8c4b354624,
+                    0xbadba360bea1c9e2,
+                    0xae9c142f52d6dbc4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f74c0b1e575367d,
+                    0xfaf4fecd74a2d7f6,
+                    0xade8d6c791895acc,
+                    0x378fed795f9870e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c150f39bcfc6e3d,
+                    0xb9eb6514f6cc1507,
+                    0x4705775b38adc99e,
+                    0xbb5bc35ba5321c51,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa6920cfb359bbd37,
+                    0x1a22fdbdc8d38b14,
+                    0x8ff1d1482a96043a,
+                    0xe6a1bd9f780abb0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x32a3e842cf7c8e50,
+                    0xa9f123576077ab35,
+                    0x38cae79e4a72f1fb,
+                    0x907758e4857d8798,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa61311d3bfa4d286,
+                    0x26dcd27e4e54c41c,
+                    0xe694d489904f7255,
+                    0xdf084c0578cf1d68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb286524d6258dae,
+                    0x3abd10cadfc5567f,
+                    0x308cf4e93c315abb,
+                    0xf19dc7f46eaf98a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc66a54cee10e85cc,
+                    0xcd55efb8a02a5e39,
+                    0xfb6fd2bd4504ece2,
+                    0x8204e555a2f4fdb1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeefe49cda41735aa,
+                    0x6dbe2ac7452cffbd,
+                    0xc66da262d27f21a8,
+                    0x1e6032fc9544e09a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x378b3d2a538a9da0,
+                    0x1f4a30e59a74fba1,
+                    0x62a286c8e09e900f,
+                    0xcdc5ffbd721b7584,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf7bf01bcfd12c6b8,
+                    0x60f74db5806593d1,
+                    0xa48356c53c42446b,
+                    0x3dabb127d36e2e62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa84a1d698af877e8,
+                    0xae6055903f192f88,
+                    0x2ae90ee2bc3ba267,
+                    0x5b8b4e84a69d5dde,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b7df6acda945884,
+                    0xaa7a36b98d9033a9,
+                    0x3c0c3b22e4220000,
+                    0xac5e263fcdb648c7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7a3ba52bab66564,
+                    0x5bd2d4265d155170,
+                    0x2f3e96b23f8302cf,
+                    0x0db5a3b5debe8284,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e2ef54522699d52,
+                    0xece9e258752a1e36,
+                    0xd03f4e72c5ffd403,
+                    0x4b8ed4be3cf65d2e,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4307b8a62c65aaaf,
+                    0x9a6354ae5c2d5c08,
+                    0x0000000000000015,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4307b8a62c65aaaf,
+                    0x9a6354ae5c2d5c08,
+                    0x0000000000000015,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4307b8a62c65aaaf,
+                    0x9a6354ae5c2d5c08,
+                    0x0000000000000015,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x4307b8a62c65aaaf,
+                    0x9a6354ae5c2d5c08,
+                    0x0000000000000015,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x419aad0cae17b4b7,
+                    0x6fda3be3d6725de6,
+                    0x000000000000000b,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcedfb2fd92b847e4,
+                    0x85f5d1e4b2d5c7bc,
+                    0xb19f96754ca6dbf2,
+                    0x72e78418f01f5601,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0b5a735235aab41,
+                    0xced067a091a407c0,
+                    0x6dd10d4d1710338f,
+                    0x84dd14b737feee77,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e9935a2cbc57717,
+                    0xc67b168d8d4de236,
+                    0x5e4dca09267c7320,
+                    0xba62c124a664f41a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x750925c4c4ec01c7,
+                    0xda545cbe8ccbfb2d,
+                    0x272b2c5bec733461,
+                    0x9274949b5b46ab71,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3535a128dce9778d,
+                    0x6d4e3d62789e70c4,
+                    0x5308da5aef3fbe02,
+                    0x8fd230dc68dec4e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa2105e9794a7c63c,
+                    0x55f42578cfd0fc75,
+                    0x45f86bcc1bce93f3,
+                    0xa180fd91a8b808b0,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x846b3981710b1167,
+                    0x67a04674d9399f0d,
+                    0x4efb91a8e762cc61,
+                    0x361e59877cf7c294,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae84cf7e02dd50bc,
+                    0x9fa9652a8f547a93,
+                    0x768b1e51c9e976b0,
+                    0x631170d3e1605cce,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8172e86c49fbd469,
+                    0xdb50c6bcb96c926c,
+                    0x480cacb5a553a601,
+                    0x10d09fba96d942ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb62e703d26144e6,
+                    0x4e6e9b0ae6123b3b,
+                    0xd155fbd0cf31134e,
+                    0x450731b1113952c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a40428091ef433a,
+                    0xb7e627eb482d9fe6,
+                    0x10d7a333473026ce,
+                    0x37d4ce2fb2be4dd5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x395b283b60b08d1a,
+                    0x8f5f2f510427e43b,
+                    0xa1e806850a44fc72,
+                    0xe66aaa3904bfee79,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3dc9b41408b54013,
+                    0x989f8d465f411685,
+                    0x67e2c12e0454fe41,
+                    0x9d74072e120f07e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5f98170f90f0859e,
+                    0xe8fd6c5bd6d9503a,
+                    0x29559fc944d03b0a,
+                    0x7d50630fda1c7076,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x09dd7775fa2b922f,
+                    0x7f310f76d41f61da,
+                    0x5c2052b24bb82062,
+                    0xf78aee7421d774b7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x402f627dceccc7a3,
+                    0x3bad3883530c3681,
+                    0xe06204ecef9d9e50,
+                    0x58ed17c842c2cf10,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xee69a957e7ca6a8b,
+                    0x0c2c20f50a7587d9,
+                    0x157bd4ade3aec8d6,
+                    0x4a1f2b69a7c341bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe4626a3bc0552d8,
+                    0x340513ee4096da9e,
+                    0x6ac2e44a99448b1b,
+                    0x773a471e21a4c30e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc2eff879a222771b,
+                    0xa5b0cbccffbd47a4,
+                    0x6b7048306c0264ed,
+                    0x73946f4c2409b274,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc5506eddc0bb0839,
+                    0xaee2e08f17d90bc4,
+                    0x606541e38e90255d,
+                    0x7355280b0fb7ccdd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x99d2ff150fb6c3a3,
+                    0x7a070caaaec06c77,
+                    0xa580b501c1dc9f7d,
+                    0x95f5c7fd5f55121f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3f1998ad4f830b3e,
+                    0x8ca935942a90a43b,
+                    0x8d29489dba06ef23,
+                    0xecbbd0a138ac81c4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39c92db1e6affa19,
+                    0xa2983b370d78b6d3,
+                    0x10efc5c800a3a9a3,
+                    0xb9303e2048aa8f9c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x41657a3525cf7f62,
+                    0xa3fc5f7213e4026f,
+                    0xc33efa30f2110f1e,
+                    0xeee755dc95340e79,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1a4bb30716897aaa,
+                    0x7c5f909a83c1617b,
+                    0xed00162c219ea687,
+                    0xbdc7a60c0c4c4b4b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x24fadc2e911e446a,
+                    0xb955d83a82a03170,
+                    0x16ce88a967351692,
+                    0x90532aaa4b321084,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27f4bf1b54e7c490,
+                    0x6cfadbb76198db40,
+                    0x0efc752104dc81f0,
+                    0xbdebd4464697c260,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd8a095e6b6303af,
+                    0xa8d2b0f9bc8bc2f8,
+                    0x2653df96dc315ea6,
+                    0xbb759d4d2269936c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94c2ccc4aeb0d271,
+                    0xbb7785bd38c059d3,
+                    0x15b82780bcdd89b2,
+                    0x3bb590013f070ae6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x17c5866760c966e8,
+                    0x5b195603c8f868e3,
+                    0xd7095a78f1d2f69c,
+                    0x9e20404878a76920,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x380483689f4327af,
+                    0x3e25bd4823494b07,
+                    0x10e004222313d82d,
+                    0x883623389934c977,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89d9f123acc5e7ed,
+                    0x14d73ed8c6b0fb7c,
+                    0x338545c142824554,
+                    0x8c40d685d3d734fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5e99401ef90067a,
+                    0x8a45cc24dcfde35c,
+                    0x81de15261cd3d744,
+                    0x39a0fdda98cbc2df,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd84e45f4eb7a050c,
+                    0xbf390befaea285a2,
+                    0x2a95dd1be327ea8b,
+                    0x1c2d246ba09cb78a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc7289591c61f280d,
+                    0x4ca9e51044c5b81e,
+                    0xa3d383194270d6de,
+                    0x0fd78432bbb0dd25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f54a5a847c47a51,
+                    0x5f790937df06ebba,
+                    0x5701a87566b6e7b1,
+                    0x57e474f721e23793,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2421 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 40];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 40];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 40];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U7>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger320;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger320([
+                0x4462c2f7468f18b3,
+                0x7a351f1ec6e8d393,
+                0x254290724089e704,
+                0xf7c371bd9970225a,
+                0x0000000000000000,
+            ]))],
+            vec![
+                Felt::new(BigInteger320([
+                    0x659f154962f7d7ba,
+                    0xeb53c933c88e0a62,
+                    0x274c922f051f7a7a,
+                    0xd833c58cc47f1eea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6f088e2dd56a904,
+                    0x7ac8a66e405a5192,
+                    0xcbb9d80f61654e14,
+                    0xcb3f00edcb25ec09,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xaecfe8c762cb1df3,
+                    0x4614148a8a20549b,
+                    0x3392e88f2eba4a5d,
+                    0x861d71f7a2f77951,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21412664ac27e1a3,
+                    0xa18233575a68f508,
+                    0xc5a35d67bc883469,
+                    0x8450fcba055c65f8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac3355bf256a04e7,
+                    0x999af3bc633fa6a9,
+                    0xb6077e108165245e,
+                    0xe10ef409a7850d8b,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xcd680d613676d0c7,
+                    0xeef4de69fe326a7a,
+                    0xf2b26ec93ec80553,
+                    0x2032821e44b4174e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe748f9dc5a46b7ab,
+                    0xcc3c2e562856dba1,
+                    0xb6eac062992c1176,
+                    0x06c552d5d2451441,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x347374204a590a8d,
+                    0xb9b6821cb0ee5fa9,
+                    0x590d9722ef39f531,
+                    0x94ff58bbd54536fa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x493570d074ce33d0,
+                    0x468ec5bdf1e525a2,
+                    0x6cc92bf4061884de,
+                    0x1cf909da9ef41958,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xb555ebfeabadbd0b,
+                    0x7f8647052b9a437a,
+                    0x59cce20a56622ea8,
+                    0x3a7141e298c257ab,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd3e56c673f0b526f,
+                    0x022c5ab76e3603fd,
+                    0x44c269f702eaa861,
+                    0x1792456a3423d798,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x31a43745ea8d265c,
+                    0xa52e0a50e48a5d7e,
+                    0x4d2e2d36d45b8da0,
+                    0x6eb0552a06bb6e2b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8d638c34091fffe0,
+                    0x734249a50a3a7fe4,
+                    0x1f58d83db59ac19a,
+                    0xeedec5bdccdab1d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb9eda2fb05141bb7,
+                    0x57581ecae9685c6a,
+                    0x75399a05830052a0,
+                    0x06909b5e6745b62d,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x508f4fb3c752f2eb,
+                    0xe9ab83b1f0f5b4de,
+                    0xfb0debcbdc8a87f4,
+                    0x393727a55e1e5571,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e4f3e68f93a65d4,
+                    0x51f0083b69397b8d,
+                    0x6ac0d0df1b3e2c97,
+                    0x16c4490f92bc3f25,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7b8b2128d8b951e4,
+                    0xbe4eb363d4a805cf,
+                    0x0eeaa468d1524ec3,
+                    0x137516887ebc4f6f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2edb270f0be89ab0,
+                    0x8adadb9e2a851d5f,
+                    0xc0b3c07eb1cb02f9,
+                    0xfe640b703a990e60,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x47f5e0c58718a70e,
+                    0x656b007ce32dd936,
+                    0x79a042336d520eb3,
+                    0xbdd18eb511e53f5b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1a0cd2c004518e5e,
+                    0x97068f4989902c8d,
+                    0x9e4f6ead13af077e,
+                    0xe649c01fbb19ec48,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xcfe78ea14401efb3,
+                0x1c58091f8baeba8d,
+                0x311168113e82d3bd,
+                0x1a379ecba9b3a7d9,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc9e549ae6780c1de,
+                0x5c7aea45a30c5eeb,
+                0x5f147427f3a2cd70,
+                0x3a8e5c2d8013c2f4,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9c5a8391561d25d1,
+                0x14759c502a1a6598,
+                0xb29d79c895f52cb0,
+                0x2fcec452a5bb4a21,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1552dc12b01ab645,
+                0x2186537a23129a71,
+                0x0ae6decf2832a165,
+                0xf458f0f0c87ca93c,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8118aaba400c7414,
+                0xacbc4418159cf1b5,
+                0x77e175c1b3fe06be,
+                0x2ff17c313b686c22,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xeb7cd9f2b900b51d,
+                0x5e70a6537dfbddc9,
+                0xe1dd6e2e7b89c2a5,
+                0xa8573ae5108a3af0,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xa83dfd8320b7a7ef,
+                0x956bc2bc800139da,
+                0x36218262e167a086,
+                0xfd1c17582223e509,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe04d87d2bf85e7ca,
+                0xa99e77b7f7db5272,
+                0x6bee1ed7186fff72,
+                0x5988ba0435a7edce,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x07210b0c69289a9b,
+                0x33884ad3bdf05bcc,
+                0x3c5b4e39363c1312,
+                0xe25c84567c1d21ec,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc56af465c35392bf,
+                0x0e8712c23b71eb05,
+                0xf9f52180dd28520b,
+                0x11a205ea6b11b23e,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0xcfe78ea14401efb3,
+                0x1c58091f8baeba8d,
+                0x311168113e82d3bd,
+                0x1a379ecba9b3a7d9,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xc9e549ae6780c1de,
+                0x5c7aea45a30c5eeb,
+                0x5f147427f3a2cd70,
+                0x3a8e5c2d8013c2f4,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9c5a8391561d25d1,
+                0x14759c502a1a6598,
+                0xb29d79c895f52cb0,
+                0x2fcec452a5bb4a21,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x1552dc12b01ab645,
+                0x2186537a23129a71,
+                0x0ae6decf2832a165,
+                0xf458f0f0c87ca93c,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 248];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf510ad0e4ef19579,
+                    0x5d2afbbbb5973761,
+                    0x06685fe06dd57b30,
+                    0xbcfeb8e0b6de5a2f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7cdef0e9fe27636a,
+                    0xea95c9eb74bc2a35,
+                    0x62d696cd00942fa9,
+                    0x28b98a2c9cf12a14,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf01b5489fa7d2f3,
+                    0x65f57cabbf53d055,
+                    0x353cb51a1e42eb6a,
+                    0x18e6250d49b23cca,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbc8ffb56bf843389,
+                    0x4a049f843222b54a,
+                    0x65ff9a3e5e40586a,
+                    0x6e26fb7aa3db1e16,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9664d648a21c101c,
+                    0x58d16c066e499179,
+                    0x48b4653fda45875c,
+                    0x9eb6dca8a99d245c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa0d69445c7f53a96,
+                    0x35b1cbb8933deb09,
+                    0x899d08c869ce31f4,
+                    0x92a81d4dc1b547f1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xef3554587a40789b,
+                    0x20cbe9ef5ef1e704,
+                    0x5ccc827e43d51625,
+                    0x3f80b2e80e850301,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2060d2b2fd774e7e,
+                    0x94d465bfd37dc5ce,
+                    0xe47ea65b6973b1a9,
+                    0x55ce90646b1d587e,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x22e08f9e2cc96a56,
+                    0xcac1a16ce2ff007f,
+                    0x595a6e15e19e056a,
+                    0x14fc24ac9a284c80,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c0decb0bf7fa937,
+                    0xa9893b5da644c610,
+                    0xec6aabb6c230307b,
+                    0xe45a3cd4f632ae66,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbf108d3a47364db9,
+                    0x9fe6942736962ffa,
+                    0x57231f057ee76d5d,
+                    0x4b7d60a9da79dc43,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb527638c5e37d1c9,
+                    0x99eeebce85e462f8,
+                    0x1361def862a0df10,
+                    0xf637c464feca997f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x55471e8fd2774939,
+                    0xc5d316850084125f,
+                    0xcd02b4b266112f53,
+                    0x31a5c25267eecf5e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfa885aefad82e2c1,
+                    0xb91adafbf824be27,
+                    0xacf51c61bacedb77,
+                    0x4a74e7e8ac345b11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x498937f449955c8a,
+                    0xddd6204fde587ae6,
+                    0xab65199e052e117e,
+                    0x7149f4d577ed6486,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43a75d19dad15c93,
+                    0x47b9d49b7e114761,
+                    0x066c9ec362e60e6e,
+                    0x2120c4c2c9dc3b6c,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x936d7e3f472b2634,
+                    0x2bb52f209c6ef32f,
+                    0x5570c71ca5cde0a6,
+                    0xa3efe2a888124d9b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea663ae96cbfa0d7,
+                    0x25bd7cbf49ae2ca9,
+                    0x294b506a1c901327,
+                    0xf9c8a34ea446034e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe6bafd8faec0b515,
+                    0x28e7aec52097b893,
+                    0x7047a3de182f1fce,
+                    0x57d569351cb7be0c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe9724d8d3161d491,
+                    0x52c757c7db29d04e,
+                    0xac372daac6ab4416,
+                    0xa2ce2201e89ce1cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb70a1d88cb2d1706,
+                    0x8f6d120b8f1c82a8,
+                    0xacc137f392d1f199,
+                    0x1e802ebdb7f596cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c685d842e1be9c5,
+                    0xb6938374e2515709,
+                    0xb4df7521f09a0da4,
+                    0x334fc10db64909e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x50854b956c7e8930,
+                    0x149d541754666b15,
+                    0x18c0daf2c697d062,
+                    0x0d1a21a6551d2073,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x75519f82b2c211fd,
+                    0xc6ce48b5280e8660,
+                    0x2a019185030c69ec,
+                    0x8d5edc165e4026ec,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xfc026d3a00030437,
+                    0xede5413301868052,
+                    0x1d055fed30195ca9,
+                    0xbb0d90fd0d7ec22f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xca0808f010a8ae61,
+                    0x10ce1a1bece8332f,
+                    0xae68cf814826d48a,
+                    0x09151cfb021e2dac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30bcc701d569472f,
+                    0x96d12fdd34918f82,
+                    0x7ee426362df3d301,
+                    0xb10c5c2193a10e22,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x028619c82fb8c8f6,
+                    0xe37910a9eee90011,
+                    0x13763fa3b7e94bf8,
+                    0x7a7ea061a10c5e41,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x64f893d7ddab52d8,
+                    0xdf8e86e545d4166d,
+                    0x4d0798b85bee9c12,
+                    0x7b66414d87091c8a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x624d29218b527ea3,
+                    0xc711782591f85bcb,
+                    0x3340a39c4f6c0552,
+                    0x317ec755b1efc40b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x061f20bcf6fc8b8e,
+                    0x3597e49b764efccd,
+                    0x2d13f6446909f60f,
+                    0x078795db2d4722f7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xefd9aa48aa991e08,
+                    0xa63eb57e69ce0b0a,
+                    0x8fd978192a8191e7,
+                    0xf17c0f92f5c103ca,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x63336a62e58ba5bd,
+                    0xf694b4de4a6f550e,
+                    0x4ae8a993f0b146fc,
+                    0x26bb940087512848,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xae1cc0ba3f59a62b,
+                    0x78cb1a502e62c223,
+                    0xb67888001206f43f,
+                    0xc9dc43b2ee11eb8e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e903c41883d5915,
+                    0x0389e7830d425931,
+                    0x7940e3862b41a18d,
+                    0x3583dc39ab1e396c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03365b120252ca7a,
+                    0x506abd687c5fe89e,
+                    0x0a7e0fead796e9e7,
+                    0x5d8cf83f3b9b0fd7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbb55ac2f283701c6,
+                    0xf6df292af1fadda0,
+                    0x6e99944422090b63,
+                    0x502b74ded287b908,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70a867d7143b1f90,
+                    0xb0e2e2bcfe0d2dac,
+                    0xdee76b5ba9105699,
+                    0x559855afeb59621a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdcc95e0a4b30f79c,
+                    0xc0b93bd185159394,
+                    0x1e6d60844ec3a59c,
+                    0x573fe62b22626d5c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc07b7481878c802,
+                    0x8852c9324ace7397,
+                    0x5a21a2e10556e3ae,
+                    0x8a195991cd648dd4,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x6928e296fb4dbb9e,
+                    0x2b9bb8c2d88f0a18,
+                    0xfee189354628cb00,
+                    0xe8f38ec9ed0063a1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb80ec28595d6030d,
+                    0xf3554e7bb2ff0b8f,
+                    0xf80f85e66b4b5e4e,
+                    0x05ca8161a3b84b0d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xeabf10b9d4de4131,
+                    0x2632196b71bd556d,
+                    0x1145a1a93e854b46,
+                    0xa128b39bebf41463,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b2f5011a9a3561b,
+                    0x0af3dceb14f704ab,
+                    0xcb2e7d0ceeb62427,
+                    0x4334a1665db38a40,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9a0157e6b48c504,
+                    0xac1001a91d582a8d,
+                    0xf2f53f4c9b97fe2a,
+                    0xcb9b4f2c3b536d6d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed7687163cb922b6,
+                    0xbc13a6f33e61481c,
+                    0x63c22d7a64c1045b,
+                    0x3997b67920fa37b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfbf955df0d88e181,
+                    0xcbdaef1b27e67ec3,
+                    0x7dabc38a59da5045,
+                    0xd7ce1827f3d46530,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb4241c88ba00b77c,
+                    0x0d5c4ec5dd314ad3,
+                    0x3cf020576dc5b1c6,
+                    0x6b36f404be1b4a32,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger320([
+                    0xcfc0efd2b945bbd7,
+                    0xe86f49447d5a071f,
+                    0x36b5db0e5bdf86dd,
+                    0x63dae9b433976c30,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x355aeaac5efc154b,
+                    0x5d78e0bece81841e,
+                    0x9c2154bd3eac37d5,
+                    0xa7ac4a82f9a9d489,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6325c8e51a591531,
+                    0xbdffd72dbdbca5b6,
+                    0x185570e5daeedb62,
+                    0xee49f7d5573bcb18,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x659378ec0ea4129d,
+                    0x2a04b63a281251cc,
+                    0x947acb5d8cd0ffaa,
+                    0x953df61898a08938,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xca32229d7335b587,
+                    0x677f93cd04c00150,
+                    0x219969e776daf33a,
+                    0x6705c7d19c042f4a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x53254a3c6fb2c692,
+                    0xb8651dca92fcc8a0,
+                    0xb260bb2fd7bd30a0,
+                    0x802642bfd8f44304,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd468d49373436a74,
+                    0x8d5b0dc00c28f3d0,
+                    0x36c78110d572aca2,
+                    0xd558ad89bfd4a4c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x222244f6737fdd32,
+                    0x814b0d3671d773c3,
+                    0xa378161d94a33700,
+                    0x79c41fdeb61b0ffa,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x99c7bca74db2a1c5,
+                    0x440261baa5ba5b9c,
+                    0xd6494078d3b62311,
+                    0x73f2c4b9940813ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x89742b77e2cbe181,
+                    0xdd68e9d960a36009,
+                    0x223f15e955700c55,
+                    0x9612d41fa96c4c08,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd9b46ce2e9032688,
+                    0x3752b50d9fdcb7ec,
+                    0xae646364a515cd7a,
+                    0x6866130f46fbedee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30c7fff129060fc2,
+                    0x97a54c0bb1c2091c,
+                    0xc58e485f7ccdeeee,
+                    0x9abffa2c3256f4da,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xe9d4140c7e95d696,
+                    0x385f6291826e80dc,
+                    0xe57326d44244e48d,
+                    0x8f94b5bfd90a3716,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb6e4b9fa668332cf,
+                    0xec764dfda640c4db,
+                    0x8fb6f0fa70c14472,
+                    0x7b170bf02e5109ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4df8ff627685a009,
+                    0xe80c7a7c5543ae43,
+                    0x5d44aba54bea95dd,
+                    0x8c817af7b4327a35,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdc6873166014d5c4,
+                    0x4b0cc83ee2ffd941,
+                    0x4e36f04008c36468,
+                    0xad11cadea3412527,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6d9cced8d42bb1e5,
+                    0xe5ba8f18d0a6b5d5,
+                    0x018ebc76a8658bb7,
+                    0x6bb25db10a39b71c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf43adbe3ce3d19ec,
+                    0xf8eae04cce9a5765,
+                    0x707df32583bc568d,
+                    0x2abe8e54c5d98262,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x937d9c6f37c65df2,
+                    0xa09a69d7541c9fac,
+                    0xbcf2fee8a175911f,
+                    0xaba058a7eaa8cf5f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x37016c173e143540,
+                    0x68244b84ae77089c,
+                    0x478f9ef9ddf4c9e9,
+                    0xe79dbaf94914e00e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xca727522ea5be988,
+                    0xc9eb77dee46e0ef3,
+                    0xacde6bae3219e630,
+                    0xb9fbfe529ed249a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x745d010c0b8df31d,
+                    0x85b068d81678f22d,
+                    0x9c856ddc090de81a,
+                    0x2a7b940e544f6f3a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdd30761f7ffb5373,
+                    0x95923ad18193abb0,
+                    0x873d04d86fde9beb,
+                    0x8407af7f4486dfc7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26d5558550662eef,
+                    0x3b9a57270ed65a26,
+                    0x95570898ff9fd1b1,
+                    0x55c0c927274a08ce,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xf1ad18bef1cc87fd,
+                    0x6666d9bb081d4cdd,
+                    0x2c8a9481f5c578b5,
+                    0x5092f07aadaccb01,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x25e992a299cae491,
+                    0xe02a786e36ec541e,
+                    0x507cdb0e0b74315a,
+                    0xc6b3573dfb2d4579,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb5422245f8200863,
+                    0xd8a226934895b5e1,
+                    0xc8ad1a3936b7ae03,
+                    0x0871ab421be2a705,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04b4f732e1a65b69,
+                    0x1efd4d5ae9cd2684,
+                    0xdbed4472e6e3abe8,
+                    0x10b49bd92735dcf2,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xedf343a23c5a1270,
+                    0x515696af48d7ccc4,
+                    0xa26194b4c1558e51,
+                    0x5603c16ff3a67a34,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76da7ea1dc8d0135,
+                    0x70b78a6c587a2b78,
+                    0x523f7e2ccbdafdf5,
+                    0x107dac71b041c274,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf916017681876f0d,
+                    0x744e2778a8f182c2,
+                    0x9f46a3bb979a6ce5,
+                    0x01fabbbf9ecf7b96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04d89922f482fd03,
+                    0x05578822badae667,
+                    0xe11df486be4f29c4,
+                    0x8b14c32a8ac1d204,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1d28107659977925,
+                    0xd80e87f93bb53243,
+                    0x12214db6864f37de,
+                    0x35a00d07bd90508c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x85a014a2d2e32dcd,
+                    0xcba5c02f65f053eb,
+                    0xe253f6ca4d55118d,
+                    0xb2cc943bf3b54945,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0c407309d49be2b,
+                    0xccd648212d24856f,
+                    0xdb25ccf25a6c395c,
+                    0x263aec742e21f586,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2daf86c99016f977,
+                    0xb44e557fba60a653,
+                    0x997ef4e248f0ed75,
+                    0x0f4cb86473510864,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x06fb2c0a020b3c18,
+                    0x5dfb6aac9eea51dc,
+                    0xb7aa6ecdde6e7648,
+                    0xd9adcdaf64b6dd1d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x56a70fb548b3b9bb,
+                    0x21ba56a97d48dadc,
+                    0x60623d2f72c240f1,
+                    0x84a11f538bce3589,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x10f935f4c0f7a732,
+                    0xee0e06131a3b07b3,
+                    0x1eb81b7a9bef735a,
+                    0x5f8626b4bf2a8357,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe942a8e6189af3a6,
+                    0xcb9134f1f180dc9c,
+                    0x08b5a39265618a23,
+                    0x91f45f6a61e7f1e7,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x861f7b09f3f8d4de,
+                    0x318eedb0dee2c5f9,
+                    0x307f4f149b90de2b,
+                    0xd2c46a38d8ab609c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f4af3a7df159ef8,
+                    0x736c43e07c488ee9,
+                    0x512c713f0183d5b1,
+                    0x6d415e67be1c03b1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52be4f5349517c93,
+                    0x5b3c9bb97848dcb1,
+                    0x2b9c2bcd5a7044a9,
+                    0x72a726763f2cd653,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf5b16e3014c676cb,
+                    0xbc97563f3ae5835c,
+                    0x58b068fb0dd8b651,
+                    0x958fa84aaa775316,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a06641f4bdde334,
+                    0x976fa8cb642b3dfc,
+                    0xe720e2a7bb113e6d,
+                    0x25b4759cd3e39a42,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2e9d3b918490d286,
+                    0x93cd905c94ca459f,
+                    0xafd62f2c942d18d2,
+                    0x743d4f8ae39e3544,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6f266724c96170a5,
+                    0x2511dafeaf98e1f2,
+                    0x14b8f95319da0ba4,
+                    0xbb67530dc1a5706e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc29dd65a38ae576d,
+                    0x9ae39828c0036f18,
+                    0xbbde52407df8b9aa,
+                    0x78176c4fa7e49a22,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xecbbafc15e130949,
+                    0x97492e91fe3b477e,
+                    0x62f010eca70b793d,
+                    0xb545f4405c6523a7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cc3461472e06fec,
+                    0x9b3477095bf6f5c7,
+                    0xef024fede788456e,
+                    0xfef65e2dea061d23,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd947c7b75cf45cf5,
+                    0x60773f4472a2f5c8,
+                    0x22d7b8493c798fdb,
+                    0x1ee58e8ba215205e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa51922aacaa17951,
+                    0xae9470765b102211,
+                    0x038a310236fa1d81,
+                    0x9b43e78d567a8225,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa17614eded02ec05,
+                    0x8b698790e27d2a2e,
+                    0x7b2956c9e136cea9,
+                    0x6cc0d4e66b1de847,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x417457ef76378c7c,
+                    0x071137f0a3abb2a0,
+                    0xa880628decd67f4d,
+                    0xff02b6f6204ebf37,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9fbb76ceae688d5a,
+                    0xb097101859a26719,
+                    0x10c88464433b59e6,
+                    0xd25e68086eea1109,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8e99bdcc94926c55,
+                    0x46436f633e8f9417,
+                    0x9f989c176a12a467,
+                    0xc56e8b52559cd53f,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x59b0044855c1902a,
+                    0xdd07c5c893245c15,
+                    0xa3d28a2f0c36d78f,
+                    0x5c1233adbf3a4505,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xadffa1ce55304fce,
+                    0xcb758b05b5250d92,
+                    0xecc2114207c9c65b,
+                    0xe56d78c700531672,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb07a87cdbdc0619d,
+                    0xe756deac5e65e518,
+                    0x644b36f5afdb5543,
+                    0x1bfba44fe64793c8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf180f270d4697439,
+                    0xb68318a5878e2e79,
+                    0xe0fd119762068858,
+                    0x644892c02b5a7f70,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9f412b716f3b1714,
+                    0xc848684bdd44184d,
+                    0x89e683fcf63b791e,
+                    0xc5833aac76ae1b68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9db9e719b848415d,
+                    0xa4175b7c619fe080,
+                    0x7cd20969402ddefa,
+                    0x005db95145d297fc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x081389e81af7da66,
+                    0xcb2805146faeab04,
+                    0x66babe59598b923d,
+                    0x6fd252925e11da47,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x38fe6d1a297952ee,
+                    0x8137cbda852f9f93,
+                    0x5d34fd060a560d67,
+                    0xf60605e4cb36c996,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xf60582ce5c90a48a,
+                    0xd37a8ab80668c28e,
+                    0xb99898c4d76ab1a0,
+                    0xf63e42dbc342ce7c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf27c61740fbba687,
+                    0x61fc4846efb0d77d,
+                    0x2a1efc4a96232447,
+                    0x62957358b3b84cd9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x132668891432bfb9,
+                    0xe485d320764032e6,
+                    0x070876f211b66c52,
+                    0x79250e7a281b9988,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x525e4006bcb9089b,
+                    0x78c4405081f7d39b,
+                    0x0aa9619531f86a4a,
+                    0xbb9b5464c44c7a6e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66bb7dce34092d8d,
+                    0x2ab7dd2bcec59e5a,
+                    0x05c438f0162aafac,
+                    0x56412893ddd10e06,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf8cf91ad6659f48e,
+                    0x2d29b7851e697625,
+                    0x36034ae02ac5c814,
+                    0x11b7006cb0367d79,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x74279e4530ff27c4,
+                    0x152ec9d41479fae6,
+                    0x8192f96fd1eddd31,
+                    0x986f04fe8350003e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9d758698a620f266,
+                    0xc9edbb24c035c962,
+                    0xed8732241d5fc232,
+                    0x395644a81a3a79a7,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0x645c7ef2de6b38cf,
+                    0xb1f3a95208b9f5ab,
+                    0xbdf36619e9ee7812,
+                    0xd9a5d61ace53ed12,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd484e43074ee4231,
+                    0x8b996e2ea314af8b,
+                    0xfd1482d7e974dee6,
+                    0x55b9434f280d8bfb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd60241321fa9f6b3,
+                    0x8d87d11fd28f4f28,
+                    0x99a86a82c4a2fede,
+                    0xb1ef6a9c056318a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfdb61d002587704a,
+                    0x5258d3c138e2764b,
+                    0x115dc5ddbc4174a1,
+                    0xf5c6103a376de2bf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x824299211e64db01,
+                    0xf4126f4f44059054,
+                    0x5da7d4abb95052cb,
+                    0xbd3ad1be8ceaaa3e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3c730611ec80e508,
+                    0x5959c5a504178dd3,
+                    0xbadaa07495bc4d50,
+                    0x155c3b513c991416,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8b19d89b1df56477,
+                    0x87c3a0ba9a700797,
+                    0xe93c76a14349758c,
+                    0x7841eea5a33532ec,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa04367176c7aa885,
+                    0x1ff81b25347fbc13,
+                    0xed2fb0fec1f89ba6,
+                    0x7fc31cb69dceb8b8,
+                    0x0000000000000000,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger320([
+                    0xd7496b1d8bad009b,
+                    0xb91e523fb70cce89,
+                    0xe38ee3f4a449413b,
+                    0xbaa84b34b151775f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x778cb55c449d33b0,
+                    0x8f8df762a0145bc1,
+                    0x103a712137f18a64,
+                    0x5646198af3aed39b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9e3458444d39281d,
+                    0x234a5b553fd27a21,
+                    0x5ceee6e4f662f7d4,
+                    0x42fe0eeb0660753a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8abfeed869aefcda,
+                    0x9f22a8825d48ea21,
+                    0x97a4ace53a42f63e,
+                    0x595dde1f285579b9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66013e810dae5a9f,
+                    0x2738ff91b6596101,
+                    0x2037b7faa0b01812,
+                    0xc2c39bfcefef6471,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7c3319ac7656b6e,
+                    0x4ff9be61c9364dc7,
+                    0xfbe700ac6d25fab0,
+                    0x8d861a5c39f0180d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1f3411f4263e7e0b,
+                    0x2d17b52e32879e9c,
+                    0x220b53056d109f80,
+                    0x7d23837e6ca79c9f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x965bb3a158892f9e,
+                    0xe6039282bd2eb9e1,
+                    0x3bd0a57893808126,
+                    0x9a79d732a70f199a,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger320([
+                0x4e305f36a0d2766e,
+                0xb88efd9dd3194249,
+                0x7fa76c0f024b99c2,
+                0x8f0f22251d1d950a,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x943dc94a293f413d,
+                0xb92d12c0b72bf10d,
+                0xae39bc45b8ae0780,
+                0x3648d7f9eae82709,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xae1397d9a21b370e,
+                0x7b0592dff96b3c37,
+                0x6c7b02264b09ebd2,
+                0x0d2ba614b6c742c1,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x4b7583661b46fcb0,
+                0xe291397d02618cc6,
+                0x20a5b3b407b42348,
+                0x443f07865ecee021,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x23e328f70ba451ad,
+                0x3c56fba7b94874a0,
+                0xe4865fe765f91599,
+                0x567ac1f673b89d60,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xcb73c6d23a94d543,
+                0x077e3be5a70e2652,
+                0xe47d153ede58b3fd,
+                0xc3ea53fc79af0fc2,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x40ae6e098247b60d,
+                0x59b23482a07c017e,
+                0x9cea5f1e15da86f6,
+                0x74363343ba42281f,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x9aa9300725eb239d,
+                0xf79e842ec022be5b,
+                0xfbe254308b3db460,
+                0x68fc86bafbc95b9d,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0xe11693d3bed9da86,
+                0x4a38e9dd3578913b,
+                0xae0ec1ee94c40668,
+                0x9d6a90ec1c776a08,
+                0x0000000000000000,
+            ]))],
+            [Felt::new(BigInteger320([
+                0x8dba85c445eb90df,
+                0x633305c519a3188e,
+                0x11ed71bb2b09dcba,
+                0xb3def5172afb38e9,
+                0x0000000000000000,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 217, 218, 656] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 217];
+        let mut opad = vec![0x5cu8; 217];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
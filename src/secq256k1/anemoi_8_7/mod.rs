@@ -0,0 +1,2055 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger320, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x2c732b40a13fbfc4,
+                    0xa4d745ff0b5e7494,
+                    0x3b3336c6a3fbdd4b,
+                    0xb2301e9d76885835,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0744783de195f76a,
+                    0xe1aafdc0fd9f26df,
+                    0x99c64a0d4a8976c9,
+                    0x63b1a0f54fd8ba1c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x04f263f0e52d87bc,
+                    0x87ebf49594f1a6ee,
+                    0xe4d675f7aff57818,
+                    0x2d1ee72b74936a62,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdefd92fd6a080f6f,
+                    0x80e83613bae24c75,
+                    0x86d069f6bf7dea69,
+                    0x966c8fa3a9634e4f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3b5a6db6752305f4,
+                    0x6840dad295e0cf1e,
+                    0xe9493c6ea31377d7,
+                    0x01d79fba2e4dcdae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb02ad18ab7f0a642,
+                    0xa157da9cb8337b45,
+                    0x82987be64f755de0,
+                    0xe1b4e049f58feb11,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc98ba26875d3be78,
+                    0x2d1e1a23b56890b5,
+                    0x3060af1eb2e5bc0a,
+                    0xb7f335ff165f7440,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f089360b6b8579c,
+                    0x66c9f5c422f86d8e,
+                    0x4fbbba882d83227e,
+                    0xa11c45e019974ca3,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x7dbe983451aed8f2,
+                    0x11feb2597674f614,
+                    0x361391b4e413fc12,
+                    0x012fcd0f8124f7d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x72d159004f7647b2,
+                    0x11182479354b536f,
+                    0x3d7cd5b6ef47a68e,
+                    0xe9659a9c3b662d46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe04ff90626fd4de0,
+                    0xe42726e47665bbb8,
+                    0x21edc147cb0ded85,
+                    0x80c7e94c88f806ea,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa9cd950ecafaa611,
+                    0x853503a5779fe0df,
+                    0x24ea0dffebafb9bc,
+                    0x1d615f7c12f3a0be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2f45fffac988994a,
+                    0x17b67a8d3a0744a2,
+                    0x4a2781d2dc23e55e,
+                    0x8bb9a464b1a516eb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4144762773b97abf,
+                    0xc2c8557787a87b1a,
+                    0x392a927f63cf4ec7,
+                    0xf5988ee782ec39c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd364dcd9ac7c33a2,
+                    0x235cafa3910571a4,
+                    0x8cedc5da9888de80,
+                    0xd5e71f85e959a804,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9cfa7f19e1cedda1,
+                    0xe197270500dc5b0e,
+                    0x4cb7b5283bf6769d,
+                    0x0ccbe7bc138f6915,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6690739963748d4d,
+                    0x86fb16bafcf3b3af,
+                    0xdd8523f5ad562c09,
+                    0xde5ebd0007b435f9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0974fd488d4ee7c8,
+                    0x8b562d08c9014ec7,
+                    0x56c97b961b933c3f,
+                    0xa410e077074844ad,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x43ddecdb80ff4448,
+                    0x4fa75d8070ce1a6e,
+                    0x55ff9a988c2ed575,
+                    0xa7803b431fd021a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd868a84f8430c57a,
+                    0x584cfa7302b009ec,
+                    0x2c6507ea2986900c,
+                    0xf53b6b680bb578f4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa5896899c3a1c5bd,
+                    0x13235e7d6784ed4b,
+                    0xfcec02dc7a1602c5,
+                    0x35a470e122f39ab6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7837001d625a1990,
+                    0xc8f717439adac172,
+                    0x8c23727596814c54,
+                    0xd103e7a0db6fa076,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8c54b94982078d54,
+                    0x95c0d0e924a1532c,
+                    0xbdfa3376a36744d4,
+                    0xdeb4f1f3d486e88c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbb4d07b06094bad,
+                    0xad4134961c3f80fd,
+                    0xcafb3d77413533a0,
+                    0x8860019c9fb99a1e,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x6b8bfa5eb37d240d,
+                    0xde43e80e6a94577b,
+                    0x2267e3e8909bf6f2,
+                    0xda68ff97266d7f8f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbd8693e9a750583,
+                    0x1e64ca667d46917e,
+                    0x95274e0e9f37d5f4,
+                    0xaf49075cc5eda9e9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfe4c4b60761d9e89,
+                    0xe52c72862674aa15,
+                    0xf26791e1dabf6f14,
+                    0xd394a3e35e34a303,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60057dbeabe1c8fa,
+                    0xf3f0ee283604f659,
+                    0x4a2e3f7a17b94e14,
+                    0xfd667a9470069573,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x454d026f55d36c96,
+                    0xc584c7dc581cc913,
+                    0x6d619cdbc435473f,
+                    0x8fe1ffe671d7fb53,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6b741179c93a424,
+                    0xc7559388a7f74ebf,
+                    0x0580cdf3ba9764d0,
+                    0x5dbb38bee729f142,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3dbcd36dc24a4270,
+                    0xad1fb03294c4aaea,
+                    0x53714d1338b60a7d,
+                    0xfe8fd8c815a5edfe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf2ebc0badc153f86,
+                    0x6aade6f0b5ec877b,
+                    0x14d554a33cec0d6e,
+                    0xf3fb94059e34e114,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x721268944203d9ac,
+                    0x2b2cf95026eabfaa,
+                    0x4bc53bdbcd3761de,
+                    0x184f7d8896e0f00c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0e0d65ac108f12ca,
+                    0xe07243c1b6c80aa7,
+                    0x6a786265e1daaee2,
+                    0x4ffcfcad9e880186,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe736f95c8036a20f,
+                    0x5955db844b1876d4,
+                    0x04f98abaf0f1fb39,
+                    0xeda371fb39e79d81,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc45b851aa4576209,
+                    0x4e600efe63b5f761,
+                    0x8884c6c2dc09721d,
+                    0x1328f39e398d16a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa94471d39c2c2a12,
+                    0xf16a87b207c627ff,
+                    0x7724f0ccb9ec22ea,
+                    0xc435d028d34977f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x236d9b4f860a8d57,
+                    0x60941ba1eaf61799,
+                    0x0d8bce636c275071,
+                    0xab7cb7546eba8450,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa874c5e68bf05050,
+                    0xf83b9cb8a1969dae,
+                    0x9dc27ad27a564107,
+                    0x00fd87bda67fc9a0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xb28323f356f94157,
+                    0xfb8b5f7026e0f9cf,
+                    0x1ba246c1bdc32e4c,
+                    0x05016a00c653499d,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x63c1d8cce63bd49e,
+                    0xa6668b79ddad7392,
+                    0x43c537e1ec554f0a,
+                    0xb5bd8aacbc14576d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x60ebfe788981dd2b,
+                    0x134541d9e78c38c3,
+                    0xc260ceeaa6f8b86e,
+                    0xb6a1315e86b153a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6311706ced53822,
+                    0x7c7cd29fcbd3209c,
+                    0xf739b8aa5581d71c,
+                    0xc3e16b80f53300b4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc10748026a82cd8f,
+                    0x093cafe226f80852,
+                    0x88dd50d5abcace9c,
+                    0x87dea4d6f0d769ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x61e7b711c441a35f,
+                    0x87dc1d3650eb2ebc,
+                    0x32c7bcc98323ab00,
+                    0x9cfa5470b05fe58a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4d1657497fe4e279,
+                    0x0eebf6a4013ed194,
+                    0x196a6e50473e8e57,
+                    0xc30607e2fe3c4697,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6fd397825f0efc1,
+                    0xe401a35bf534e0b0,
+                    0xd01d6104f656783e,
+                    0xe5586046ccc2fd38,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x391d378e87534ca2,
+                    0x0128250575594412,
+                    0xbb610f1a3c25559f,
+                    0xfbb68602221b4693,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc6e3bee108ce3b59,
+                    0x09e705039dd10dad,
+                    0x6db6db6db6db6db7,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x98cd2ced0e33d784,
+                    0x44e4c34316b88c83,
+                    0xaac104af4bbb62c5,
+                    0x6561d3b9b440493c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x59a3db8920f8cde6,
+                    0xbb60bc2d82abd818,
+                    0x414a981483db6888,
+                    0x0c0c3ab1c55ba3ac,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc4f931ae917b8dd9,
+                    0x0b07b98e5365063d,
+                    0x7f545b56241e4593,
+                    0x67ee6d39274ccf90,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe0bde9d33098f2a7,
+                    0xc285cff7ac23ffbd,
+                    0x345b8fbe660e02ad,
+                    0x5136a59334f04d39,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x76c7df60eb84f765,
+                    0xc850ca7a871c3151,
+                    0x34701dce238c0bcf,
+                    0x6db6db6db6db6dc1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfd25e8cd0364141,
+                    0x7a813b737f7ee17c,
+                    0xbaaedce6af48a03a,
+                    0xfffffffffffffffe,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa90e348badffd2d0,
+                    0xd3f40033c75fab41,
+                    0x2d5655cf92d6ea25,
+                    0x61f374cd58a9fd94,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd80bdf7d5d279e0,
+                    0x7e9689378f05f3b7,
+                    0xfebc461c82a197cd,
+                    0x089159ce34a5e06f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc669a05bba046fa,
+                    0xf8b57020fb541ba8,
+                    0x1d46e570ae236985,
+                    0x00e003b15de6a336,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfb03ffb4ef14e50e,
+                    0xaf0b8393b646e17c,
+                    0xdfb12ddf917a2372,
+                    0x016e73a6516c8b15,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x600f919c9d3550ea,
+                    0xc4b45457d9fbc8ea,
+                    0x568d5c1bde90ebe3,
+                    0xcaa71a8a52da5f0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x163648bb0fefabc4,
+                    0x21b35bd3d12011e0,
+                    0x0f029a677bddede1,
+                    0x01d7cb1646ef99c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xdbb20260a6849fe8,
+                    0xee7bcd9396d3c462,
+                    0x4e521c39ffd84533,
+                    0x30d1da572f82809d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa4a93e9b27509023,
+                    0xe6f1b82dd040d514,
+                    0x9d3e9e601fb81ca9,
+                    0x65e0a5bb98083a90,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3d4189b6e0dcf6c9,
+                    0xf2b39ba30039f5fa,
+                    0x8277b721095b91be,
+                    0xdc7c2cd516a595e1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x150e619a987bad1f,
+                    0x64f5cba7c8492e9d,
+                    0xb326bcab224bdeaf,
+                    0xd860b4a8515627be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4f0d31ea1bfbf009,
+                    0x76a4a6de6cfe58fd,
+                    0x40adf17f87546493,
+                    0xc2d73682e0eb5678,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x647108024b076290,
+                    0xfef2d013f2ac277c,
+                    0xec0f42bd549f0035,
+                    0x009162c5f15f985d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc31f6f20d683e554,
+                    0xcf5cf449798f1fb2,
+                    0xbe31a9e25ebf95db,
+                    0x2abeb75a8c52e99b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1ee7cf0b9063326d,
+                    0x86b665b9b030c83f,
+                    0xb4aa334d39b9dc62,
+                    0x7bd17898196936cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x21b104e6a89cb366,
+                    0xc0011accdaa29267,
+                    0x0e6f6087f2f94ca7,
+                    0xb0a282df51d102c9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x345a796c487d7b64,
+                    0x20dbadf66e6b275e,
+                    0xed687ce05442befc,
+                    0xedca0b8dc050efaf,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x1eafeca3fb3ed1f2,
+                    0xee871238897ef1c0,
+                    0x8a8863dcd114f8f1,
+                    0x877654b31e682d32,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0da4b4b72c23d58a,
+                    0x6e8767ee7a12b084,
+                    0x1b0a1c3cc5e6db1a,
+                    0xcc0ed0b394603953,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xfd0cb13d11aec4fa,
+                    0x2766c5e6cccbd751,
+                    0x939f5f3e6762777c,
+                    0x0fdf0cf294c2b6a4,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x609d488863d6e5f9,
+                    0xf5b269eaa24a2284,
+                    0x3885c634f84de295,
+                    0x2bf774fabf7fd654,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5a3c5bcc8ac318de,
+                    0xcfddc001c97adbb3,
+                    0xffcdbb9cabe94845,
+                    0x99f059d4d42b575b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe3b8418b6aff0697,
+                    0xb798cdfe1328e68a,
+                    0x6a69a0f729bd695c,
+                    0xa98d7649cbe2287d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x2bc6432b55ac4768,
+                    0x184ccdc1920e3fa2,
+                    0x310645825517833d,
+                    0x5dd57bc32464fa82,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7e99f909bd5f3d35,
+                    0x06862ba126e18212,
+                    0x34f1610d326c0f57,
+                    0x0aad519fed68c451,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x25d6f729bdd841b2,
+                    0xbd671fb2b29d3ebc,
+                    0x25e1a56b6f442826,
+                    0x071771d1d0ea9f4d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1938c38631dc4305,
+                    0xbf5a47fb3b833730,
+                    0x4fbd0b5e5d43123f,
+                    0xb864b9cc169615ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7ae24e648917a4f5,
+                    0x0ff86e6a8555a8fe,
+                    0x05a0e61967deb2d2,
+                    0x42c8428286776aa8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe25b997513ef5ad9,
+                    0x7d58bb12970211a5,
+                    0x84f4cd695f67985b,
+                    0x8489e0e67b0be4bb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa79b0fe4c84dca44,
+                    0x254d0d0a67c5905a,
+                    0x603e4ef5fac747a9,
+                    0xdf2ba1ff26e81b99,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd954baec1e6daf09,
+                    0x3f3945e5bbbdf0b9,
+                    0x61e59bbaf62cba68,
+                    0xa3972e963125ecc6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6a5f1a0b402e2b56,
+                    0x7da9370b281dffed,
+                    0x61304fe24f389abf,
+                    0x5f472ac02a2a6798,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x73f01680b93bce29,
+                    0x94d0f6d2d54a98db,
+                    0x6dbefa65325f60ad,
+                    0x19ef935a1cf67104,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x8702a6d32c5fed1b,
+                    0xd687165f0cd2fc5f,
+                    0xeba80c728dce2d08,
+                    0xdcb3cf8088634472,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x02cf49f3d7ae7fc2,
+                    0x038aad5d0a5530b4,
+                    0x953d70d0957ce359,
+                    0xe030e6a092e1b812,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b854e727444d0ad,
+                    0x264067b2fe93dd50,
+                    0x18b7cf6090923b5a,
+                    0xffddda893bc9b4ff,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf76b1dc2b2201398,
+                    0xb1f92c07e906864a,
+                    0xb07345bb648552cc,
+                    0x32840ba67af29841,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x94a81442b6cd4ea2,
+                    0x38dedc728c35706e,
+                    0x3fa57f6233bb6f2c,
+                    0x99160d36f4e53191,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9448acd579cf76f0,
+                    0xac4c9ae478eef895,
+                    0x70d72fa70fc4f5d4,
+                    0xac7c206e8b91e7ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7f401c5c835660bf,
+                    0x10b4449028eaf9e9,
+                    0x97c5bea0da516363,
+                    0xd29729ce03dcee7e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf21f15a14ca36d67,
+                    0x36e4c5b23227b24c,
+                    0x449ae885c0b701ae,
+                    0xb439892e2d39c87a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xa016cc83ad1f9205,
+                    0x370d16f3108670ad,
+                    0x8d0c3023a5f8e7a1,
+                    0x9f014f14f09eca3b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x03dc65e3f01aec80,
+                    0xfb0b1aaaa62b9dea,
+                    0x08311dd1fc19c1a3,
+                    0xdffbe0bafb06e982,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x01ee1c15e567510a,
+                    0x23f8f29d53195233,
+                    0x2e610dd6ee88216d,
+                    0x6d509a340acf5022,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc99509d1d01f3d46,
+                    0x809a8727b8cb4a53,
+                    0x43ac0d4018a1a31c,
+                    0x57c04d97f0c2ce81,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x62d213485b511af3,
+                    0xbf8ca6ab0d420df2,
+                    0x310565f573c4a138,
+                    0xc9ca49677c324676,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xaceb871ac21e5633,
+                    0x2549d6448054130b,
+                    0xd3e2bf2637979240,
+                    0xe03b27b5cf4c77cb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x65ad2cccb35e3fd6,
+                    0x85473e3cc247a27d,
+                    0xeeac5f783e57f6aa,
+                    0x0fa66ad28e9a20ef,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0c7d615cb212f573,
+                    0xaaf9cbbf9032a094,
+                    0xabd9fee92b7c7348,
+                    0xd9cd8f48bda6ef58,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x3fbd8119fd9b5338,
+                    0xad92a9171dbe5ee8,
+                    0x668e1f9ad26354e4,
+                    0xc5b4e367cf3f848e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x466a83e2e908b9b6,
+                    0x809216d7bcbaaf89,
+                    0x3ab4575557c9a24c,
+                    0x9de0f0c806615a01,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xedc89c748de762ab,
+                    0xa713eab6c32794be,
+                    0x4d5a1f07f529d3b8,
+                    0x31aeba43faacc7d8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6de2b7b2db7a48b6,
+                    0xae96c6200961ab5e,
+                    0xfb636aaf136c009c,
+                    0xb2299e12f24de10c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x52a8b99547d9549b,
+                    0xc9dbdc448f5825b9,
+                    0x1440e3d0f1140942,
+                    0x6620e566e08bea69,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3312592a9fce9b0b,
+                    0xdc0ab9513c67461a,
+                    0xce3024ebb3855acf,
+                    0x4902cff2dfe5740d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe7a29b7f602b46eb,
+                    0xefd076ed62c7de3d,
+                    0x155447f5b6a3eefb,
+                    0xd2ec7e820b1ee0c0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa51d238479920934,
+                    0x1f9a62b03e2118cd,
+                    0x96da1351fc5fa178,
+                    0xe23849a87da2b1f8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x78f044d785d76f27,
+                    0x4bc5db1cf36f4676,
+                    0x4278638928593aac,
+                    0xe0bc8e60de6b2770,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0b58a200f5bd70ee,
+                    0x1a47ecd08f9d4418,
+                    0x383773868a3dcc83,
+                    0xf7cc9b529cb9e421,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcabb960f563a10e9,
+                    0xefa8050d0d3c7098,
+                    0x0b5d9d857507dc2c,
+                    0xedf32eb49c8d5eac,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x998802f2278d9866,
+                    0x7a38a61229bb3c01,
+                    0xa317c28bea5700a7,
+                    0xe1cb11cefaefe4b8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x27755748d0082f4e,
+                    0x056c699f4851e382,
+                    0xd92c4ab5a13fbd0a,
+                    0x899219a1b32ce509,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac6211e075dce094,
+                    0x14e670d3fa39cc22,
+                    0xfd5a687181ff0938,
+                    0xa98c9faa1d63f4f3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5d21ca8d44245123,
+                    0x038e6962cf2f0857,
+                    0x77092415faacf697,
+                    0x4ab22bad8569858e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa38007dc1173da23,
+                    0xd9c47a8b3973d5e9,
+                    0x082111efc234c997,
+                    0xf608c38fecd109a7,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x94b398b7ab2baef0,
+                    0x57c625716c7952e0,
+                    0x12163275c6f43710,
+                    0x153f148f935a99ae,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5870b2ecd43b770c,
+                    0xde4e654fa6658754,
+                    0x3a1e05ba911484d0,
+                    0xcdfc661ccb02088f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd6c15d3b67401ed3,
+                    0xe6ad5ff4d094452d,
+                    0x779c2437620bc8c9,
+                    0x936369f1934b6f2a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf85fd0774a215a50,
+                    0x1d5942fb687c1982,
+                    0x5405647775d54f60,
+                    0xccc357f30b2e3098,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc33929febe69af50,
+                    0xe657ef42dd8e504f,
+                    0xb8e6d31843990676,
+                    0x79131d807d205279,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa443d65c230a400d,
+                    0xe26b0adf4f7e305f,
+                    0xba0c9664480bb228,
+                    0xb2f102b84102872d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3e5361040627bacf,
+                    0xb9e64f6dd25cb606,
+                    0xe98f68d407fc1374,
+                    0x223b1e977d3f4df9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0bb372669eee8c14,
+                    0x297e85edc6c8ef79,
+                    0x6270b08033dd044e,
+                    0xb935e1c3e1861781,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x42609973fdf2270a,
+                    0x5e300fea9fef8cd0,
+                    0x2e7c67cd49cfd7bb,
+                    0xa5c5eab7d49c0c68,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a32f1ba8f45cff1,
+                    0x8c3691e5a8ff2c0e,
+                    0xe91a7f2acd9406cb,
+                    0x776ccc078b17130d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xa8347bc356c36655,
+                    0xae27b575688b622e,
+                    0x0ddef3b49e9bed6c,
+                    0xdd14900c3e424f59,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x30a2da770482cc6c,
+                    0xcf89492106acf683,
+                    0x5b03bd78605fc0e1,
+                    0xae100e2d478d7d87,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3968196f47736581,
+                    0x4638d2913aae61c9,
+                    0xa671fababc6dd6a6,
+                    0x6955e63f66771988,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5c19319e9e4c6bde,
+                    0xcb63208e92a4f906,
+                    0xd1e4e124f645afdf,
+                    0xd48bc0bdf8941ae9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xffd381b760f4887e,
+                    0xeeab22e8272de7ac,
+                    0xebfdb16a4618c5ba,
+                    0x8d348a41c6b85fd7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe54f8f282b571caf,
+                    0xb4cd2076b3897322,
+                    0x44e81843fca6abff,
+                    0x1a4860ffc6153532,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x65d47ea782367294,
+                    0xae9361134ce3ef5c,
+                    0x95ae7a8f2f5768b4,
+                    0x8071e1d73e2f3e46,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f577cc198eae92f,
+                    0xa94ac86aaedacd44,
+                    0xf825543e6dc5f625,
+                    0x32c9b28ab77c8816,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe2158ba2c5c04033,
+                    0x754088b365771319,
+                    0x239fe01f1b6aa344,
+                    0x43faf7397dea34a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xed98a23a7e7d45a5,
+                    0x9c0ded2b8ecdb20b,
+                    0x01d7d140b58149b6,
+                    0x4ace8eec37915adf,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x33baefee0dcfa043,
+                    0xf5189aec20e3a86e,
+                    0xcd29a0662dfbe205,
+                    0x5380b7d3254b8de5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xea01ab9e576f1eed,
+                    0x73d5ce2a780c3d12,
+                    0x319dead120a5e31c,
+                    0x67d85ee371ba7bb2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc0aa3818adc94f17,
+                    0xf4e616fc83e7c0c5,
+                    0x6425bf590215fe3c,
+                    0xe42892d0860bf6b0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x9c303df42fe65347,
+                    0x3dd20e69a237a025,
+                    0x5325716a8e36e044,
+                    0x7986fd59b8f4053c,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x4b791176fa095c0e,
+                    0xa8e409ce0241de9f,
+                    0x916dc15f6723fefd,
+                    0x62f583d0ad1ff852,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe159288229b3f18c,
+                    0x91f6d5f86067e2f0,
+                    0x8e2f012ae57ed1ab,
+                    0x2a4d92d177e20744,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x39d43e10d3fd95cc,
+                    0xd0105e5360248fae,
+                    0x6f6a5cafc8db5fdb,
+                    0xfb4eb0f4a2ed8738,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x48205d7694ad263e,
+                    0xd84dece10b779659,
+                    0x29871e72b17ebf62,
+                    0x31da6c1ea257f3d0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x81824f02bc9950f3,
+                    0xf039faf23f0b4846,
+                    0x62daaa86d20e7f93,
+                    0x25dcd68b40faf9fb,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf34bfdd7cfec5ba9,
+                    0x3f4ab1f8cb97c92a,
+                    0x7030e22083f5a152,
+                    0xa071a51d47c7a49b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x26c6793b12ef54f3,
+                    0x45471eda13cfb1e0,
+                    0xb4dcd83bf946c29f,
+                    0x743407ea289e3b78,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x29f9d224b920e640,
+                    0x36bf8ededf61ac33,
+                    0x331d18224beecef7,
+                    0xab1cad0b8897fb2b,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xc41981594b202329,
+                    0x3a4a274640799aa1,
+                    0x000000000000001d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x16d0b997e4df5f80,
+                    0xa8918ca85bafe220,
+                    0x00000000000000a2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0xd3127b24f952b9d5,
+                    0xf8e9ab94bca50706,
+                    0x0000000000000087,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x0000000000000000,
+                    0x86c5f71917f2505a,
+                    0x4a0b35c1fb383721,
+                    0x0000000000000030,
+                    0x0000000000000000,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xb6b6dd38353802c4,
+                    0x044ab7cb1335b96e,
+                    0x395f9e46ccc935ac,
+                    0xefa6d4087d24f2dd,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x881930877e3b7c36,
+                    0x8b1bd2831c67ad60,
+                    0x9e4b5fcf45c167df,
+                    0x5eba47dc186a7e0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x578a584c2fa78660,
+                    0xb4f5daa6e3ab0263,
+                    0x062e3878a3252964,
+                    0xd2a9480370e669be,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x91a7a7c86cd9d831,
+                    0xf05f3f898dc7e756,
+                    0x9d944987076ff8ae,
+                    0x5ae93f6a46d8e9d7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf93cb699b7ea903d,
+                    0xcc8b37e9abb58a39,
+                    0x268f268de6ce7dde,
+                    0xdad70d6ecc22c0ba,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbfe41ac142d6d3af,
+                    0x287f7c3886fa53cd,
+                    0x0844c2ab538c3fed,
+                    0x2af5383a5ef4ac96,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe15c58d18ae62568,
+                    0x27cf748d7a73edeb,
+                    0xc53a08fed6e70bda,
+                    0x6c0d79407dd640a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4872cde1adb0c749,
+                    0x993fa1c86bca0c42,
+                    0x736f0488bf038bbf,
+                    0x0483180819ccfcc4,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xd54cc25c8fc53d6b,
+                    0xb2d1d08f9029fa88,
+                    0xc969a03715dfa8ae,
+                    0x4d552c8ee8a7203a,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4b1043c639fd9d14,
+                    0x9541ac34c25e2f7f,
+                    0xee7da50d59f0e374,
+                    0xe358bb6273a5cff2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x7a67ab288560bd3c,
+                    0xd31fe9c556af7f0d,
+                    0x9fc3dbe5f6a841f7,
+                    0x6e8cb8870623bb49,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8f51e986b56d64d6,
+                    0xc1dad982b9443d2b,
+                    0xc7504430e257dbb3,
+                    0x1ae06246419a55ed,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x647733262ea25b76,
+                    0xd0cec15d1969fdc7,
+                    0xdec111a70f966c6d,
+                    0x7c5a0971a8a1a83d,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xd57273ce25c20266,
+                    0x3b0971336d9cdabc,
+                    0x82eed2ab3c09156b,
+                    0x8ab9ba7db560d2a9,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbaacbe88e0ea9766,
+                    0xc800ad266e531133,
+                    0x18914d8ba91ea8cd,
+                    0x029e7c63af8ca4ee,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x8fc6fb9563371dfe,
+                    0x66a5182d189d4efd,
+                    0xe6a63411198a282a,
+                    0xb3a3b278cf6ce6d8,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x04fb8734234da335,
+                    0xbb79f6153a8e209d,
+                    0x74711d1236c006d1,
+                    0x263192b640be3c7b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5e457e0de48d3657,
+                    0xf55329f14be5705f,
+                    0xd602ee15c9198514,
+                    0xbad6bc91ded99be6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x869be3f1707077dd,
+                    0xb53ee1b674ed89bb,
+                    0xadc60933ea623a25,
+                    0x77e0a487fc7801a8,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3a98d35137c53ea2,
+                    0x389afa095c25cff0,
+                    0xdde40adc14d27963,
+                    0x2995455fe074dcc0,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xc11f73c1bf9329b7,
+                    0xd5a6199f87f05cb9,
+                    0xc5ec762fcc26993e,
+                    0x24c8f252c189dcda,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x6ace57f241db025f,
+                    0x6b17107473bb7786,
+                    0x9cd39a011e40df09,
+                    0x9eddff0e386a7f98,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xcc73bd03db439243,
+                    0x7713743b5e5d60f6,
+                    0x7a832309a2220b69,
+                    0x4f412e0b352277e5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x378cea14bd152d85,
+                    0xa21e539636c7386c,
+                    0xf81743d23ff6070c,
+                    0x7210f90172770369,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xc487f8a0c3de370a,
+                    0x3f7ca3b0ad31f69e,
+                    0x5583395eaf51d250,
+                    0x2f2c9e86d6033efa,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x18019662bcb545d4,
+                    0x56e01011922ac8d9,
+                    0xa7cb675ab3a58062,
+                    0xf643e3df424760b2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x103aca63b5d8c484,
+                    0x471b314a7e9eeadd,
+                    0x69df14f41bb78c9a,
+                    0xe449fc17cbf7412e,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ea0acf72336ba4b,
+                    0xa25d724e3820f959,
+                    0x55a7479fb36ca8ec,
+                    0xa99fabefbb7698bc,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x3327e71747c83516,
+                    0x486ef5d1d5560705,
+                    0xa148f16e369b704b,
+                    0xd7840586662d40c5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xac9407589c127747,
+                    0x05deea014800d9bc,
+                    0xb43c5a636866ded9,
+                    0x9089a8753e524690,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1586750a8354f54c,
+                    0xbccf5abf7d35132f,
+                    0xd0d5fb846e29e429,
+                    0x1ac5edee9771808c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x71d1366067e3bc91,
+                    0x5a36ff9f4bf24b60,
+                    0x78ecee13999e7e39,
+                    0xed1bf8c407bbb59a,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0xcf37cd01162d1eaf,
+                    0xcca48d0005309c5e,
+                    0x5d1ef62153fa94c5,
+                    0xfe422134f0746bb7,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x5ce9e3d2ce415235,
+                    0xa0b65957bf597563,
+                    0x273426d390f14ede,
+                    0x33dd622e3b1ea8e3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x42b070c7a36bd08a,
+                    0xe69ed2bc653035e1,
+                    0x57e8ff7fda1418f1,
+                    0xe8d6d55b40560389,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xf21617ce714e5f23,
+                    0x6fac6f355a81b56a,
+                    0x3fc16f015549767c,
+                    0x2fb1becedfb83325,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe112e06ce2f1f01e,
+                    0x048fefb006591c42,
+                    0x34f5624e56b9251c,
+                    0x2452e9a1b5d73724,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x66eb4e619e2354bc,
+                    0x27fbb6376bfce94e,
+                    0x0617bed0d80956a6,
+                    0x7a0cf7530e5d1e0b,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x70b1f31ef0339161,
+                    0xd178cec7b24f2aa8,
+                    0x66e606aca40c12e4,
+                    0xa1baff65504c5949,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x011bb768c3644734,
+                    0x46a60a90e15b1384,
+                    0x1a78a9db77d8309f,
+                    0x8bda3d60a18fe5bc,
+                    0x0000000000000000,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger320([
+                    0x258d4348d26bc16b,
+                    0x430903e3cd385e41,
+                    0x318028a7eb91671c,
+                    0xf181e5e351168fb3,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x4cfa95d1692bb1aa,
+                    0xb8c3b8502db3c4fd,
+                    0xda87f2db9ee9e1ee,
+                    0xf8383633f723a1c2,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x543f8c81ba7d3dd9,
+                    0xb0959432c325284c,
+                    0xc8a48cbdfffb4b7b,
+                    0x93f966f2cb0f11a6,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xe8515dcf5036c09a,
+                    0x11e8beb01ee91e60,
+                    0x385098e2b583db26,
+                    0x0031f070f67c80a5,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x1b7f5cd14d963a5d,
+                    0xea30a96515826566,
+                    0x30ddf6a02018dc25,
+                    0xf8e47d8e0fbe346f,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbea208e2894101bf,
+                    0x1d349eb6d7539947,
+                    0x6d63545077ce654a,
+                    0xb1f85058b348033c,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0xbd3523241e8677fe,
+                    0xb795c624a138aeec,
+                    0x6193180cd4f1ed15,
+                    0xcdb97fd213efaee1,
+                    0x0000000000000000,
+                ])),
+                Felt::new(BigInteger320([
+                    0x54eff8f1bc7f558c,
+                    0xa4f072e5f61d091c,
+                    0x9718b9ae5ece175e,
+                    0xaf490f4d63824ef7,
+                    0x0000000000000000,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
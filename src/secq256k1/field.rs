@@ -0,0 +1,122 @@
+//! Base field of the secq256k1 elliptic curve, i.e. the scalar field of secp256k1.
+//!
+//! The modulus spans the full 256 bits, which the 4-limb arithmetic of `ark-ff`
+//! does not support (it requires a spare top bit), hence the extra limb.
+
+use ark_ff::{biginteger::BigInteger320 as BigInteger, fields::*};
+
+/// A prime field element, in Montgomery representation.
+pub type Fq = Fp320<FqParameters>;
+
+/// Parameters of the prime field.
+#[derive(Debug)]
+pub struct FqParameters;
+
+impl Fp320Parameters for FqParameters {}
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 6;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x0112cb0f605a214a,
+        0x92225daffb794500,
+        0x7e42003a6ccb6212,
+        0x55980b07bc222114,
+        0x0000000000000000,
+    ]);
+}
+impl FpParameters for FqParameters {
+    /// MODULUS = 115792089237316195423570985008687907852837564279074904382605163141518161494337
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xbfd25e8cd0364141,
+        0xbaaedce6af48a03b,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_BITS: u32 = 256;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 64;
+
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x0000000000000000,
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0x1e004f504dfd7f79,
+        0x08fcf59774a052ea,
+        0x27c4120fc94e1653,
+        0x3c1a6191e5702644,
+        0x0000000000000000,
+    ]);
+
+    const INV: u64 = 0x4b0dff665588b13f;
+
+    // GENERATOR = 7
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x0000000000000000,
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x0000000000000008,
+        0x0000000000000000,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xdfe92f46681b20a0,
+        0x5d576e7357a4501d,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xeeff497a3340d905,
+        0xfaeabb739abd2280,
+        0xffffffffffffffff,
+        0x03ffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x777fa4bd19a06c82,
+        0xfd755db9cd5e9140,
+        0xffffffffffffffff,
+        0x01ffffffffffffff,
+        0x0000000000000000,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::*;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_fq() {
+        let mut rng = test_rng();
+        let a: Fq = UniformRand::rand(&mut rng);
+        let b: Fq = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        sqrt_field_test(a);
+        primefield_test::<Fq>();
+    }
+}
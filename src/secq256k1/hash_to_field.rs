@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 48,
+    expanded: "ab47ac2f6fa134c0a7034814daef7c13409602a7f4c897d7a4969b49a0f957f5",
+    element: "29308084337975194057667213717846707430781627353537795709829661626805783801412",
+}
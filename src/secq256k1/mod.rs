@@ -0,0 +1,53 @@
+pub use ark_ff::BigInteger320;
+use ark_ff::Field;
+pub use field::Fq as Felt;
+
+mod field;
+mod sbox;
+
+mod hash_to_field;
+pub use hash_to_field::{expand_message_anemoi, hash_to_field, HASH_TO_FIELD_BYTES};
+
+/// An instantiation of Anemoi with state width 2 and
+/// rate 1 aimed at providing 128 bits security.
+pub mod anemoi_2_1;
+
+/// An instantiation of Anemoi with state width 4 and
+/// rate 3 aimed at providing 128 bits security.
+pub mod anemoi_4_3;
+
+/// An instantiation of Anemoi with state width 6 and
+/// rate 5 aimed at providing 128 bits security.
+pub mod anemoi_6_5;
+
+/// An instantiation of Anemoi with state width 8 and
+/// rate 7 aimed at providing 128 bits security.
+pub mod anemoi_8_7;
+
+/// An instantiation of Anemoi with state width 10 and
+/// rate 9 aimed at providing 128 bits security.
+pub mod anemoi_10_9;
+
+/// An instantiation of Anemoi with state width 12 and
+/// rate 11 aimed at providing 128 bits security.
+pub mod anemoi_12_11;
+
+/// Instantiations of Anemoi targetting the 256-bit security level.
+///
+/// Their capacity and their digests are made of two elements of this 256-bit field,
+/// which bounds their generic security against collisions to `log2(p)` bits, less
+/// than 256 bits by a negligible amount.
+#[cfg(feature = "anemoi_256")]
+pub mod anemoi_256;
+
+// HELPER FUNCTION
+// ================================================================================================
+
+#[inline(always)]
+fn mul_by_generator(x: &Felt) -> Felt {
+    let x2 = x.double();
+    let x4 = x2.double();
+    let x8 = x4.double();
+
+    x8 - x
+}
@@ -0,0 +1,357 @@
+use super::BigInteger320;
+use super::Felt;
+
+use crate::lanes::ChainElement;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 5;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 5] = [
+    0x99751870a691cdcd,
+    0xc88be3ebbf6d4cfc,
+    0xcccccccccccccccb,
+    0xcccccccccccccccc,
+    0x0000000000000000,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 7;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger320([
+    0x76c7df60eb84f765,
+    0xc6e3bee108ce3b59,
+    0x09e705039dd10dad,
+    0x6db6db6db6db6db7,
+    0x0000000000000000,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+/// Computes `x^INV_ALPHA` with a fixed addition chain.
+///
+/// This function is **not** constant-time, as the underlying
+/// field arithmetic is not.
+#[inline(always)]
+pub(crate) fn exp_inv_alpha<T: ChainElement>(x: &T) -> T {
+    let t1 = x.square(); //        1: 2
+    let t2 = t1.square(); //       2: 4
+    let t3 = t2 * x; //            3: 5
+    let t4 = t3 * t2; //           4: 9
+    let t5 = t4 * t1; //           5: 11
+    let t6 = t5 * t3; //           6: 16
+    let t7 = t5.square(); //       7: 22
+    let t8 = t6 * t4; //           8: 25
+    let t9 = t6.square(); //       9: 32
+    let t10 = t9 * t1; //         10: 34
+    let t11 = t8.square(); //     11: 50
+    let t12 = t11 * t3; //        12: 55
+    let t13 = t11 * t7; //        13: 72
+    let t14 = t12 * t7; //        14: 77
+    let t15 = t14 * t7; //        15: 99
+    let t16 = t12 * t11; //       16: 105
+    let t17 = t12.square(); //    17: 110
+    let t18 = t14.square(); //    18: 154
+    let t19 = t18 * t4; //        19: 163
+    let t20 = t19 * t17; //       20: 273
+    let t21 = t18.square(); //    21: 308
+    let t22 = t21 * t15; //       22: 407
+    let t23 = t21 * t19; //       23: 471
+    let t24 = t23 * t10; //       24: 505
+    let t25 = t24 * t1; //        25: 507
+    let t26 = t25 * t21; //       26: 815
+    let t27 = t26 * t2; //        27: 819
+    let t28 = t27 * t9; //        28: 851
+    let t29 = t28 * t11; //       29: 901
+    let t30 = t28 * t13; //       30: 923
+    let t31 = t30 * t13; //       31: 995
+    let mut t0 = t27.square(); // 32: 1638
+    t0 = t0.square(); //          33: 3276
+    t0 = t0.square(); //          34: 6552
+    t0 = t0.square(); //          35: 13104
+    t0 = t0.square(); //          36: 26208
+    t0 = t0.square(); //          37: 52416
+    t0 = t0.square(); //          38: 104832
+    t0 = t0.square(); //          39: 209664
+    t0 = t0.square(); //          40: 419328
+    t0 = t0.square(); //          41: 838656
+    t0 = t0.square(); //          42: 1677312
+    t0 = t0.square(); //          43: 3354624
+    t0 *= t27; //                 44: 3355443
+    t0 = t0.square(); //          45: 6710886
+    t0 = t0.square(); //          46: 13421772
+    t0 = t0.square(); //          47: 26843544
+    t0 = t0.square(); //          48: 53687088
+    t0 = t0.square(); //          49: 107374176
+    t0 = t0.square(); //          50: 214748352
+    t0 = t0.square(); //          51: 429496704
+    t0 = t0.square(); //          52: 858993408
+    t0 = t0.square(); //          53: 1717986816
+    t0 = t0.square(); //          54: 3435973632
+    t0 = t0.square(); //          55: 6871947264
+    t0 = t0.square(); //          56: 13743894528
+    t0 *= t27; //                 57: 13743895347
+    t0 = t0.square(); //          58: 27487790694
+    t0 = t0.square(); //          59: 54975581388
+    t0 = t0.square(); //          60: 109951162776
+    t0 = t0.square(); //          61: 219902325552
+    t0 = t0.square(); //          62: 439804651104
+    t0 = t0.square(); //          63: 879609302208
+    t0 = t0.square(); //          64: 1759218604416
+    t0 = t0.square(); //          65: 3518437208832
+    t0 = t0.square(); //          66: 7036874417664
+    t0 = t0.square(); //          67: 14073748835328
+    t0 = t0.square(); //          68: 28147497670656
+    t0 = t0.square(); //          69: 56294995341312
+    t0 *= t27; //                 70: 56294995342131
+    t0 = t0.square(); //          71: 112589990684262
+    t0 = t0.square(); //          72: 225179981368524
+    t0 = t0.square(); //          73: 450359962737048
+    t0 = t0.square(); //          74: 900719925474096
+    t0 = t0.square(); //          75: 1801439850948192
+    t0 = t0.square(); //          76: 3602879701896384
+    t0 = t0.square(); //          77: 7205759403792768
+    t0 = t0.square(); //          78: 14411518807585536
+    t0 = t0.square(); //          79: 28823037615171072
+    t0 = t0.square(); //          80: 57646075230342144
+    t0 = t0.square(); //          81: 115292150460684288
+    t0 = t0.square(); //          82: 230584300921368576
+    t0 *= t27; //                 83: 230584300921369395
+    t0 = t0.square(); //          84: 461168601842738790
+    t0 = t0.square(); //          85: 922337203685477580
+    t0 = t0.square(); //          86: 1844674407370955160
+    t0 = t0.square(); //          87: 3689348814741910320
+    t0 = t0.square(); //          88: 7378697629483820640
+    t0 = t0.square(); //          89: 14757395258967641280
+    t0 = t0.square(); //          90: 29514790517935282560
+    t0 = t0.square(); //          91: 59029581035870565120
+    t0 = t0.square(); //          92: 118059162071741130240
+    t0 = t0.square(); //          93: 236118324143482260480
+    t0 = t0.square(); //          94: 472236648286964520960
+    t0 = t0.square(); //          95: 944473296573929041920
+    t0 *= t27; //                 96: 944473296573929042739
+    t0 = t0.square(); //          97: 1888946593147858085478
+    t0 = t0.square(); //          98: 3777893186295716170956
+    t0 = t0.square(); //          99: 7555786372591432341912
+    t0 = t0.square(); //         100: 15111572745182864683824
+    t0 = t0.square(); //         101: 30223145490365729367648
+    t0 = t0.square(); //         102: 60446290980731458735296
+    t0 = t0.square(); //         103: 120892581961462917470592
+    t0 = t0.square(); //         104: 241785163922925834941184
+    t0 = t0.square(); //         105: 483570327845851669882368
+    t0 = t0.square(); //         106: 967140655691703339764736
+    t0 = t0.square(); //         107: 1934281311383406679529472
+    t0 = t0.square(); //         108: 3868562622766813359058944
+    t0 *= t27; //                109: 3868562622766813359059763
+    t0 = t0.square(); //         110: 7737125245533626718119526
+    t0 = t0.square(); //         111: 15474250491067253436239052
+    t0 = t0.square(); //         112: 30948500982134506872478104
+    t0 = t0.square(); //         113: 61897001964269013744956208
+    t0 = t0.square(); //         114: 123794003928538027489912416
+    t0 = t0.square(); //         115: 247588007857076054979824832
+    t0 = t0.square(); //         116: 495176015714152109959649664
+    t0 = t0.square(); //         117: 990352031428304219919299328
+    t0 = t0.square(); //         118: 1980704062856608439838598656
+    t0 = t0.square(); //         119: 3961408125713216879677197312
+    t0 = t0.square(); //         120: 7922816251426433759354394624
+    t0 = t0.square(); //         121: 15845632502852867518708789248
+    t0 *= t27; //                122: 15845632502852867518708790067
+    t0 = t0.square(); //         123: 31691265005705735037417580134
+    t0 = t0.square(); //         124: 63382530011411470074835160268
+    t0 = t0.square(); //         125: 126765060022822940149670320536
+    t0 = t0.square(); //         126: 253530120045645880299340641072
+    t0 = t0.square(); //         127: 507060240091291760598681282144
+    t0 = t0.square(); //         128: 1014120480182583521197362564288
+    t0 = t0.square(); //         129: 2028240960365167042394725128576
+    t0 = t0.square(); //         130: 4056481920730334084789450257152
+    t0 = t0.square(); //         131: 8112963841460668169578900514304
+    t0 = t0.square(); //         132: 16225927682921336339157801028608
+    t0 = t0.square(); //         133: 32451855365842672678315602057216
+    t0 = t0.square(); //         134: 64903710731685345356631204114432
+    t0 *= t27; //                135: 64903710731685345356631204115251
+    t0 = t0.square(); //         136: 129807421463370690713262408230502
+    t0 = t0.square(); //         137: 259614842926741381426524816461004
+    t0 = t0.square(); //         138: 519229685853482762853049632922008
+    t0 = t0.square(); //         139: 1038459371706965525706099265844016
+    t0 = t0.square(); //         140: 2076918743413931051412198531688032
+    t0 = t0.square(); //         141: 4153837486827862102824397063376064
+    t0 = t0.square(); //         142: 8307674973655724205648794126752128
+    t0 = t0.square(); //         143: 16615349947311448411297588253504256
+    t0 = t0.square(); //         144: 33230699894622896822595176507008512
+    t0 = t0.square(); //         145: 66461399789245793645190353014017024
+    t0 = t0.square(); //         146: 132922799578491587290380706028034048
+    t0 = t0.square(); //         147: 265845599156983174580761412056068096
+    t0 *= t27; //                148: 265845599156983174580761412056068915
+    t0 = t0.square(); //         149: 531691198313966349161522824112137830
+    t0 = t0.square(); //         150: 1063382396627932698323045648224275660
+    t0 = t0.square(); //         151: 2126764793255865396646091296448551320
+    t0 = t0.square(); //         152: 4253529586511730793292182592897102640
+    t0 = t0.square(); //         153: 8507059173023461586584365185794205280
+    t0 = t0.square(); //         154: 17014118346046923173168730371588410560
+    t0 = t0.square(); //         155: 34028236692093846346337460743176821120
+    t0 = t0.square(); //         156: 68056473384187692692674921486353642240
+    t0 = t0.square(); //         157: 136112946768375385385349842972707284480
+    t0 = t0.square(); //         158: 272225893536750770770699685945414568960
+    t0 = t0.square(); //         159: 544451787073501541541399371890829137920
+    t0 = t0.square(); //         160: 1088903574147003083082798743781658275840
+    t0 *= t26; //                161: 1088903574147003083082798743781658276655
+    t0 = t0.square(); //         162: 2177807148294006166165597487563316553310
+    t0 = t0.square(); //         163: 4355614296588012332331194975126633106620
+    t0 = t0.square(); //         164: 8711228593176024664662389950253266213240
+    t0 = t0.square(); //         165: 17422457186352049329324779900506532426480
+    t0 = t0.square(); //         166: 34844914372704098658649559801013064852960
+    t0 = t0.square(); //         167: 69689828745408197317299119602026129705920
+    t0 = t0.square(); //         168: 139379657490816394634598239204052259411840
+    t0 = t0.square(); //         169: 278759314981632789269196478408104518823680
+    t0 = t0.square(); //         170: 557518629963265578538392956816209037647360
+    t0 = t0.square(); //         171: 1115037259926531157076785913632418075294720
+    t0 = t0.square(); //         172: 2230074519853062314153571827264836150589440
+    t0 *= t20; //                173: 2230074519853062314153571827264836150589713
+    t0 = t0.square(); //         174: 4460149039706124628307143654529672301179426
+    t0 = t0.square(); //         175: 8920298079412249256614287309059344602358852
+    t0 = t0.square(); //         176: 17840596158824498513228574618118689204717704
+    t0 = t0.square(); //         177: 35681192317648997026457149236237378409435408
+    t0 = t0.square(); //         178: 71362384635297994052914298472474756818870816
+    t0 = t0.square(); //         179: 142724769270595988105828596944949513637741632
+    t0 = t0.square(); //         180: 285449538541191976211657193889899027275483264
+    t0 = t0.square(); //         181: 570899077082383952423314387779798054550966528
+    t0 = t0.square(); //         182: 1141798154164767904846628775559596109101933056
+    t0 = t0.square(); //         183: 2283596308329535809693257551119192218203866112
+    t0 = t0.square(); //         184: 4567192616659071619386515102238384436407732224
+    t0 *= t31; //                185: 4567192616659071619386515102238384436407733219
+    t0 = t0.square(); //         186: 9134385233318143238773030204476768872815466438
+    t0 = t0.square(); //         187: 18268770466636286477546060408953537745630932876
+    t0 = t0.square(); //         188: 36537540933272572955092120817907075491261865752
+    t0 = t0.square(); //         189: 73075081866545145910184241635814150982523731504
+    t0 = t0.square(); //         190: 146150163733090291820368483271628301965047463008
+    t0 = t0.square(); //         191: 292300327466180583640736966543256603930094926016
+    t0 = t0.square(); //         192: 584600654932361167281473933086513207860189852032
+    t0 = t0.square(); //         193: 1169201309864722334562947866173026415720379704064
+    t0 = t0.square(); //         194: 2338402619729444669125895732346052831440759408128
+    t0 *= t23; //                195: 2338402619729444669125895732346052831440759408599
+    t0 = t0.square(); //         196: 4676805239458889338251791464692105662881518817198
+    t0 = t0.square(); //         197: 9353610478917778676503582929384211325763037634396
+    t0 = t0.square(); //         198: 18707220957835557353007165858768422651526075268792
+    t0 = t0.square(); //         199: 37414441915671114706014331717536845303052150537584
+    t0 = t0.square(); //         200: 74828883831342229412028663435073690606104301075168
+    t0 = t0.square(); //         201: 149657767662684458824057326870147381212208602150336
+    t0 = t0.square(); //         202: 299315535325368917648114653740294762424417204300672
+    t0 = t0.square(); //         203: 598631070650737835296229307480589524848834408601344
+    t0 = t0.square(); //         204: 1197262141301475670592458614961179049697668817202688
+    t0 = t0.square(); //         205: 2394524282602951341184917229922358099395337634405376
+    t0 *= t25; //                206: 2394524282602951341184917229922358099395337634405883
+    t0 = t0.square(); //         207: 4789048565205902682369834459844716198790675268811766
+    t0 = t0.square(); //         208: 9578097130411805364739668919689432397581350537623532
+    t0 = t0.square(); //         209: 19156194260823610729479337839378864795162701075247064
+    t0 = t0.square(); //         210: 38312388521647221458958675678757729590325402150494128
+    t0 = t0.square(); //         211: 76624777043294442917917351357515459180650804300988256
+    t0 = t0.square(); //         212: 153249554086588885835834702715030918361301608601976512
+    t0 = t0.square(); //         213: 306499108173177771671669405430061836722603217203953024
+    t0 = t0.square(); //         214: 612998216346355543343338810860123673445206434407906048
+    t0 = t0.square(); //         215: 1225996432692711086686677621720247346890412868815812096
+    t0 = t0.square(); //         216: 2451992865385422173373355243440494693780825737631624192
+    t0 = t0.square(); //         217: 4903985730770844346746710486880989387561651475263248384
+    t0 *= t28; //                218: 4903985730770844346746710486880989387561651475263249235
+    t0 = t0.square(); //         219: 9807971461541688693493420973761978775123302950526498470
+    t0 = t0.square(); //         220: 19615942923083377386986841947523957550246605901052996940
+    t0 = t0.square(); //         221: 39231885846166754773973683895047915100493211802105993880
+    t0 = t0.square(); //         222: 78463771692333509547947367790095830200986423604211987760
+    t0 = t0.square(); //         223: 156927543384667019095894735580191660401972847208423975520
+    t0 = t0.square(); //         224: 313855086769334038191789471160383320803945694416847951040
+    t0 = t0.square(); //         225: 627710173538668076383578942320766641607891388833695902080
+    t0 = t0.square(); //         226: 1255420347077336152767157884641533283215782777667391804160
+    t0 = t0.square(); //         227: 2510840694154672305534315769283066566431565555334783608320
+    t0 = t0.square(); //         228: 5021681388309344611068631538566133132863131110669567216640
+    t0 = t0.square(); //         229: 10043362776618689222137263077132266265726262221339134433280
+    t0 *= t24; //                230: 10043362776618689222137263077132266265726262221339134433785
+    t0 = t0.square(); //         231: 20086725553237378444274526154264532531452524442678268867570
+    t0 = t0.square(); //         232: 40173451106474756888549052308529065062905048885356537735140
+    t0 = t0.square(); //         233: 80346902212949513777098104617058130125810097770713075470280
+    t0 = t0.square(); //         234: 160693804425899027554196209234116260251620195541426150940560
+    t0 = t0.square(); //         235: 321387608851798055108392418468232520503240391082852301881120
+    t0 = t0.square(); //         236: 642775217703596110216784836936465041006480782165704603762240
+    t0 = t0.square(); //         237: 1285550435407192220433569673872930082012961564331409207524480
+    t0 = t0.square(); //         238: 2571100870814384440867139347745860164025923128662818415048960
+    t0 = t0.square(); //         239: 5142201741628768881734278695491720328051846257325636830097920
+    t0 = t0.square(); //         240: 10284403483257537763468557390983440656103692514651273660195840
+    t0 = t0.square(); //         241: 20568806966515075526937114781966881312207385029302547320391680
+    t0 *= t22; //                242: 20568806966515075526937114781966881312207385029302547320392087
+    t0 = t0.square(); //         243: 41137613933030151053874229563933762624414770058605094640784174
+    t0 = t0.square(); //         244: 82275227866060302107748459127867525248829540117210189281568348
+    t0 = t0.square(); //         245: 164550455732120604215496918255735050497659080234420378563136696
+    t0 = t0.square(); //         246: 329100911464241208430993836511470100995318160468840757126273392
+    t0 = t0.square(); //         247: 658201822928482416861987673022940201990636320937681514252546784
+    t0 = t0.square(); //         248: 1316403645856964833723975346045880403981272641875363028505093568
+    t0 = t0.square(); //         249: 2632807291713929667447950692091760807962545283750726057010187136
+    t0 = t0.square(); //         250: 5265614583427859334895901384183521615925090567501452114020374272
+    t0 = t0.square(); //         251: 10531229166855718669791802768367043231850181135002904228040748544
+    t0 *= t19; //                252: 10531229166855718669791802768367043231850181135002904228040748707
+    t0 = t0.square(); //         253: 21062458333711437339583605536734086463700362270005808456081497414
+    t0 = t0.square(); //         254: 42124916667422874679167211073468172927400724540011616912162994828
+    t0 = t0.square(); //         255: 84249833334845749358334422146936345854801449080023233824325989656
+    t0 = t0.square(); //         256: 168499666669691498716668844293872691709602898160046467648651979312
+    t0 = t0.square(); //         257: 336999333339382997433337688587745383419205796320092935297303958624
+    t0 = t0.square(); //         258: 673998666678765994866675377175490766838411592640185870594607917248
+    t0 = t0.square(); //         259: 1347997333357531989733350754350981533676823185280371741189215834496
+    t0 = t0.square(); //         260: 2695994666715063979466701508701963067353646370560743482378431668992
+    t0 = t0.square(); //         261: 5391989333430127958933403017403926134707292741121486964756863337984
+    t0 = t0.square(); //         262: 10783978666860255917866806034807852269414585482242973929513726675968
+    t0 = t0.square(); //         263: 21567957333720511835733612069615704538829170964485947859027453351936
+    t0 = t0.square(); //         264: 43135914667441023671467224139231409077658341928971895718054906703872
+    t0 = t0.square(); //         265: 86271829334882047342934448278462818155316683857943791436109813407744
+    t0 = t0.square(); //         266: 172543658669764094685868896556925636310633367715887582872219626815488
+    t0 *= t29; //                267: 172543658669764094685868896556925636310633367715887582872219626816389
+    t0 = t0.square(); //         268: 345087317339528189371737793113851272621266735431775165744439253632778
+    t0 = t0.square(); //         269: 690174634679056378743475586227702545242533470863550331488878507265556
+    t0 = t0.square(); //         270: 1380349269358112757486951172455405090485066941727100662977757014531112
+    t0 = t0.square(); //         271: 2760698538716225514973902344910810180970133883454201325955514029062224
+    t0 = t0.square(); //         272: 5521397077432451029947804689821620361940267766908402651911028058124448
+    t0 = t0.square(); //         273: 11042794154864902059895609379643240723880535533816805303822056116248896
+    t0 = t0.square(); //         274: 22085588309729804119791218759286481447761071067633610607644112232497792
+    t0 = t0.square(); //         275: 44171176619459608239582437518572962895522142135267221215288224464995584
+    t0 = t0.square(); //         276: 88342353238919216479164875037145925791044284270534442430576448929991168
+    t0 *= t16; //                277: 88342353238919216479164875037145925791044284270534442430576448929991273
+    t0 = t0.square(); //         278: 176684706477838432958329750074291851582088568541068884861152897859982546
+    t0 = t0.square(); //         279: 353369412955676865916659500148583703164177137082137769722305795719965092
+    t0 = t0.square(); //         280: 706738825911353731833319000297167406328354274164275539444611591439930184
+    t0 = t0.square(); //         281: 1413477651822707463666638000594334812656708548328551078889223182879860368
+    t0 = t0.square(); //         282: 2826955303645414927333276001188669625313417096657102157778446365759720736
+    t0 = t0.square(); //         283: 5653910607290829854666552002377339250626834193314204315556892731519441472
+    t0 = t0.square(); //         284: 11307821214581659709333104004754678501253668386628408631113785463038882944
+    t0 = t0.square(); //         285: 22615642429163319418666208009509357002507336773256817262227570926077765888
+    t0 = t0.square(); //         286: 45231284858326638837332416019018714005014673546513634524455141852155531776
+    t0 = t0.square(); //         287: 90462569716653277674664832038037428010029347093027269048910283704311063552
+    t0 = t0.square(); //         288: 180925139433306555349329664076074856020058694186054538097820567408622127104
+    t0 = t0.square(); //         289: 361850278866613110698659328152149712040117388372109076195641134817244254208
+    t0 = t0.square(); //         290: 723700557733226221397318656304299424080234776744218152391282269634488508416
+    t0 *= t30; //                291: 723700557733226221397318656304299424080234776744218152391282269634488509339
+    t0 = t0.square(); //         292: 1447401115466452442794637312608598848160469553488436304782564539268977018678
+    t0 = t0.square(); //         293: 2894802230932904885589274625217197696320939106976872609565129078537954037356
+    t0 = t0.square(); //         294: 5789604461865809771178549250434395392641878213953745219130258157075908074712
+    t0 = t0.square(); //         295: 11579208923731619542357098500868790785283756427907490438260516314151816149424
+    t0 = t0.square(); //         296: 23158417847463239084714197001737581570567512855814980876521032628303632298848
+    t0 = t0.square(); //         297: 46316835694926478169428394003475163141135025711629961753042065256607264597696
+    t0 = t0.square(); //         298: 92633671389852956338856788006950326282270051423259923506084130513214529195392
+    t0 * t14 //                  299: 92633671389852956338856788006950326282270051423259923506084130513214529195469
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{Field, UniformRand};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_alpha() {
+        for _ in 0..100 {
+            let a = Felt::rand(&mut OsRng);
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+        }
+    }
+}
@@ -0,0 +1,414 @@
+//! Serialization of field elements shared by all instantiations.
+//!
+//! Field elements are encoded as the little-endian bytes of their canonical
+//! representative, over the minimal number of bytes fitting the field modulus.
+//! Decoding rejects any value which is not less than the modulus. Human-readable formats
+//! represent these bytes as hexadecimal strings.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use ark_ff::{FpParameters, PrimeField};
+use core::fmt;
+
+/// Errors raised when decoding an Anemoi digest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DigestError {
+    /// The encoding does not have the byte length of a digest.
+    InvalidLength {
+        /// Byte length of the digest.
+        expected: usize,
+        /// Byte length of the provided encoding.
+        actual: usize,
+    },
+    /// The digest element at the given index is not less than the field modulus.
+    NonCanonical(usize),
+    /// The string is not a hexadecimal encoding.
+    InvalidHex,
+}
+
+impl fmt::Display for DigestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DigestError::InvalidLength { expected, actual } => write!(
+                f,
+                "invalid digest length: expected {} bytes, got {}",
+                expected, actual
+            ),
+            DigestError::NonCanonical(i) => {
+                write!(f, "non-canonical encoding of digest element {}", i)
+            }
+            DigestError::InvalidHex => write!(f, "invalid hexadecimal string"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DigestError {}
+
+/// Returns the number of bytes of the canonical encoding of an element of `F`.
+pub(crate) fn element_size<F: PrimeField>() -> usize {
+    (F::Params::MODULUS_BITS as usize).div_ceil(8)
+}
+
+/// Writes the canonical encoding of `element` into `bytes`, which
+/// must be of length [`element_size`].
+pub(crate) fn element_to_bytes<F: PrimeField>(element: &F, bytes: &mut [u8]) {
+    let repr = element.into_repr();
+    for (chunk, limb) in bytes.chunks_mut(8).zip(repr.as_ref()) {
+        chunk.copy_from_slice(&limb.to_le_bytes()[..chunk.len()]);
+    }
+}
+
+/// Writes the least significant bytes of the little-endian encoding of the integer
+/// `low + p * high` into `bytes`, where `p` is the field modulus. This integer is
+/// uniformly distributed over `[0, p^2)` when `low` and `high` are over the field.
+#[cfg(feature = "anemoi_256")]
+pub(crate) fn wide_element_to_bytes<F: PrimeField>(low: &F, high: &F, bytes: &mut [u8]) {
+    let modulus = F::Params::MODULUS;
+    let modulus = modulus.as_ref();
+    let n = modulus.len();
+
+    // Schoolbook multiplication of `high` by the modulus, accumulated onto `low`.
+    // The result is less than p^2, hence fits in twice as many limbs as the modulus.
+    let mut limbs = vec![0u64; 2 * n];
+    limbs[..n].copy_from_slice(low.into_repr().as_ref());
+    for (i, &h) in high.into_repr().as_ref().iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &m) in modulus.iter().enumerate() {
+            let t = limbs[i + j] as u128 + h as u128 * m as u128 + carry;
+            limbs[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        limbs[i + n] = carry as u64;
+    }
+
+    for (chunk, limb) in bytes.chunks_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_le_bytes()[..chunk.len()]);
+    }
+}
+
+/// Reads an element from its canonical encoding, returning `None` if `bytes`
+/// is not of length [`element_size`] or encodes a value not less than the modulus.
+pub(crate) fn element_from_bytes<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    if bytes.len() != element_size::<F>() {
+        return None;
+    }
+
+    let mut repr = F::BigInt::default();
+    for (limb, chunk) in repr.as_mut().iter_mut().zip(bytes.chunks(8)) {
+        let mut buf = [0u8; 8];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(buf);
+    }
+
+    // `from_repr` rejects values which are not less than the modulus.
+    F::from_repr(repr)
+}
+
+/// Reduces the big-endian integer encoded by `bytes` modulo the field modulus.
+pub(crate) fn element_from_be_bytes_mod_order<F: PrimeField>(bytes: &[u8]) -> F {
+    // Chunks one byte shorter than the modulus encode values less than it, and
+    // are accumulated from the most significant one.
+    let chunk_size = element_size::<F>() - 1;
+    let shift = F::from(2u64).pow([8 * chunk_size as u64]);
+
+    let mut result = F::zero();
+    for chunk in bytes.rchunks(chunk_size).rev() {
+        let mut repr = F::BigInt::default();
+        for (i, byte) in chunk.iter().rev().enumerate() {
+            repr.as_mut()[i / 8] |= (*byte as u64) << (8 * (i % 8));
+        }
+
+        result = result * shift + F::from_repr(repr).unwrap();
+    }
+
+    result
+}
+
+/// Reads `N` consecutive canonically encoded elements from `bytes`.
+pub(crate) fn elements_from_bytes<F: PrimeField, const N: usize>(
+    bytes: &[u8],
+) -> Result<[F; N], DigestError> {
+    let size = element_size::<F>();
+    if bytes.len() != N * size {
+        return Err(DigestError::InvalidLength {
+            expected: N * size,
+            actual: bytes.len(),
+        });
+    }
+
+    let mut elements = [F::zero(); N];
+    for (i, (element, chunk)) in elements.iter_mut().zip(bytes.chunks(size)).enumerate() {
+        *element = element_from_bytes(chunk).ok_or(DigestError::NonCanonical(i))?;
+    }
+
+    Ok(elements)
+}
+
+/// Displays a sequence of bytes as a lowercase hexadecimal string.
+pub(crate) struct Hex<'a>(pub(crate) &'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes a hexadecimal string, ignoring case, returning `None` if it contains
+/// any non-hexadecimal character or has an odd number of digits.
+pub(crate) fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+#[cfg(feature = "serde")]
+pub(crate) use self::serde_impl::*;
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use core::marker::PhantomData;
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a sequence of bytes, as a hexadecimal string in human-readable
+    /// formats and as raw bytes otherwise.
+    pub(crate) fn serialize_bytes<S: Serializer>(
+        bytes: &[u8],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&Hex(bytes))
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    /// Deserializes a sequence of exactly `N` bytes serialized with [`serialize_bytes`].
+    pub(crate) fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        let bytes = deserialize_byte_vec(deserializer, N)?;
+
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn deserialize_byte_vec<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        len: usize,
+    ) -> Result<Vec<u8>, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BytesVisitor(len))
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor(len))
+        }
+    }
+
+    struct BytesVisitor(usize);
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "{} bytes or a hexadecimal string of {} digits",
+                self.0,
+                2 * self.0
+            )
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            match decode_hex(v) {
+                Some(bytes) if bytes.len() == self.0 => Ok(bytes),
+                _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+            }
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            if v.len() != self.0 {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+
+            Ok(v.to_vec())
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(self.0);
+            while let Some(byte) = seq.next_element()? {
+                // Untrusted sequences are not read past the expected length
+                if bytes.len() == self.0 {
+                    return Err(A::Error::invalid_length(self.0 + 1, &self));
+                }
+                bytes.push(byte);
+            }
+
+            self.visit_bytes(&bytes)
+        }
+    }
+
+    /// A field element serialized through its canonical encoding.
+    struct Element<F>(F);
+
+    impl<F: PrimeField> Serialize for Element<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut bytes = vec![0u8; element_size::<F>()];
+            element_to_bytes(&self.0, &mut bytes);
+
+            serialize_bytes(&bytes, serializer)
+        }
+    }
+
+    impl<'de, F: PrimeField> Deserialize<'de> for Element<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let bytes = deserialize_byte_vec(deserializer, element_size::<F>())?;
+
+            element_from_bytes(&bytes)
+                .map(Element)
+                .ok_or_else(|| D::Error::custom("non-canonical field element encoding"))
+        }
+    }
+
+    /// (De)serialization of arrays of field elements, to be used with `#[serde(with)]`.
+    pub(crate) mod elements {
+        use super::*;
+
+        pub(crate) fn serialize<F: PrimeField, S: Serializer, const N: usize>(
+            elements: &[F; N],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let mut tuple = serializer.serialize_tuple(N)?;
+            for element in elements {
+                tuple.serialize_element(&Element(*element))?;
+            }
+
+            tuple.end()
+        }
+
+        pub(crate) fn deserialize<'de, F: PrimeField, D: Deserializer<'de>, const N: usize>(
+            deserializer: D,
+        ) -> Result<[F; N], D::Error> {
+            deserializer.deserialize_tuple(N, ElementsVisitor(PhantomData))
+        }
+
+        struct ElementsVisitor<F, const N: usize>(PhantomData<F>);
+
+        impl<'de, F: PrimeField, const N: usize> Visitor<'de> for ElementsVisitor<F, N> {
+            type Value = [F; N];
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} field elements", N)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut elements = [F::zero(); N];
+                for (i, element) in elements.iter_mut().enumerate() {
+                    *element = seq
+                        .next_element::<Element<F>>()?
+                        .ok_or_else(|| A::Error::invalid_length(i, &self))?
+                        .0;
+                }
+
+                Ok(elements)
+            }
+        }
+    }
+
+    /// (De)serialization of byte arrays, to be used with `#[serde(with)]`.
+    pub(crate) mod bytes {
+        use super::*;
+
+        pub(crate) fn serialize<S: Serializer, const N: usize>(
+            bytes: &[u8; N],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_bytes(bytes, serializer)
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+            deserializer: D,
+        ) -> Result<[u8; N], D::Error> {
+            deserialize_bytes(deserializer)
+        }
+    }
+
+    /// Deserializes an index into a buffer, rejecting values not less than its length `LEN`.
+    pub(crate) fn deserialize_index<'de, D: Deserializer<'de>, const LEN: usize>(
+        deserializer: D,
+    ) -> Result<usize, D::Error> {
+        let idx = usize::deserialize(deserializer)?;
+        if idx >= LEN {
+            return Err(D::Error::custom("index out of bounds"));
+        }
+
+        Ok(idx)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use serde::de::value::{Error as ValueError, SeqDeserializer};
+
+        #[test]
+        fn test_bytes_visitor_seq() {
+            let seq = SeqDeserializer::<_, ValueError>::new([1u8, 2, 3].into_iter());
+            assert_eq!(BytesVisitor(3).visit_seq(seq).unwrap(), vec![1, 2, 3]);
+
+            let seq = SeqDeserializer::<_, ValueError>::new([1u8, 2].into_iter());
+            assert!(BytesVisitor(3).visit_seq(seq).is_err());
+
+            // An endless sequence is rejected once it exceeds the expected length
+            let seq = SeqDeserializer::<_, ValueError>::new(core::iter::repeat(0u8));
+            assert!(BytesVisitor(3).visit_seq(seq).is_err());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "anemoi_256", feature = "bn_254"))]
+mod tests {
+    use super::*;
+    use crate::bn_254::Felt;
+    use ark_ff::{BigInteger, One, UniformRand, Zero};
+    use num_bigint::BigUint;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_wide_element_to_bytes() {
+        let to_biguint = |x: &Felt| BigUint::from_bytes_le(&x.into_repr().to_bytes_le());
+        let modulus = BigUint::from_bytes_le(&<Felt as PrimeField>::Params::MODULUS.to_bytes_le());
+
+        let mut rng = OsRng;
+        let mut inputs = vec![(Felt::zero(), Felt::zero()), (-Felt::one(), -Felt::one())];
+        inputs.extend((0..10).map(|_| (Felt::rand(&mut rng), Felt::rand(&mut rng))));
+
+        for (low, high) in inputs {
+            let expected = to_biguint(&low) + &modulus * to_biguint(&high);
+            let mut expected = expected.to_bytes_le();
+            expected.resize(64, 0);
+
+            let mut bytes = [0u8; 64];
+            wide_element_to_bytes(&low, &high, &mut bytes);
+            assert_eq!(bytes[..], expected[..]);
+
+            let mut bytes = [0u8; 31];
+            wide_element_to_bytes(&low, &high, &mut bytes);
+            assert_eq!(bytes[..], expected[..31]);
+        }
+    }
+}
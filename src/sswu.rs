@@ -0,0 +1,98 @@
+//! Hashing to short Weierstrass curves with the simplified SWU map, following RFC 9380.
+
+use ark_ec::models::short_weierstrass_jacobian::GroupAffine;
+use ark_ec::models::SWModelParameters;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, Field, One, PrimeField, SquareRootField, Zero};
+
+/// Parameters of the simplified SWU map to a short Weierstrass curve, through
+/// an isogenous curve `y^2 = x^3 + A' * x + B'` with `A' * B' != 0`.
+pub(crate) trait SswuParameters: SWModelParameters {
+    /// Coefficient `A'` of the isogenous curve.
+    const ISO_COEFF_A: Self::BaseField;
+    /// Coefficient `B'` of the isogenous curve.
+    const ISO_COEFF_B: Self::BaseField;
+    /// Non-square element of the base field, chosen as in Appendix H.2 of RFC 9380.
+    const Z: Self::BaseField;
+    /// Coefficients of the numerator of the x-coordinate of the isogeny, by increasing degree.
+    const X_MAP_NUMERATOR: &'static [Self::BaseField];
+    /// Coefficients of the denominator of the x-coordinate of the isogeny, by increasing degree.
+    const X_MAP_DENOMINATOR: &'static [Self::BaseField];
+    /// Coefficients of the numerator of the y-coordinate of the isogeny, by increasing degree.
+    const Y_MAP_NUMERATOR: &'static [Self::BaseField];
+    /// Coefficients of the denominator of the y-coordinate of the isogeny, by increasing degree.
+    const Y_MAP_DENOMINATOR: &'static [Self::BaseField];
+    /// Scalar clearing the cofactor of the curve, as little-endian limbs.
+    const H_EFF: &'static [u64];
+}
+
+/// Returns the sign of `x`, i.e. the parity of its canonical representative.
+fn sgn0<F: PrimeField>(x: &F) -> bool {
+    x.into_repr().is_odd()
+}
+
+/// Evaluates the polynomial with coefficients `coeffs`, by increasing degree, at `x`.
+fn evaluate<F: Field>(coeffs: &[F], x: F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * x + coeff)
+}
+
+/// Maps a field element to a point of the curve, with the simplified SWU map
+/// to the isogenous curve followed by the isogeny.
+pub(crate) fn map_to_curve<P>(u: P::BaseField) -> GroupAffine<P>
+where
+    P: SswuParameters,
+    P::BaseField: PrimeField,
+{
+    let a = P::ISO_COEFF_A;
+    let b = P::ISO_COEFF_B;
+    let curve_equation = |x: P::BaseField| (x.square() + a) * x + b;
+
+    let z_u2 = P::Z * u.square();
+    let tv1 = (z_u2.square() + z_u2)
+        .inverse()
+        .unwrap_or_else(P::BaseField::zero);
+    let x1 = if tv1.is_zero() {
+        b * (P::Z * a).inverse().unwrap()
+    } else {
+        -b * a.inverse().unwrap() * (P::BaseField::one() + tv1)
+    };
+    let x2 = z_u2 * x1;
+
+    let (x, mut y) = match curve_equation(x1).sqrt() {
+        Some(y) => (x1, y),
+        None => (x2, curve_equation(x2).sqrt().unwrap()),
+    };
+    if sgn0(&u) != sgn0(&y) {
+        y = -y;
+    }
+
+    // The denominators only vanish on the kernel of the isogeny,
+    // sent to the identity.
+    let x_denominator = evaluate(P::X_MAP_DENOMINATOR, x);
+    let y_denominator = evaluate(P::Y_MAP_DENOMINATOR, x);
+    if x_denominator.is_zero() || y_denominator.is_zero() {
+        return GroupAffine::zero();
+    }
+
+    GroupAffine::new(
+        evaluate(P::X_MAP_NUMERATOR, x) * x_denominator.inverse().unwrap(),
+        y * evaluate(P::Y_MAP_NUMERATOR, x) * y_denominator.inverse().unwrap(),
+        false,
+    )
+}
+
+/// Maps two field elements to a point of the prime order subgroup of the curve,
+/// by adding their images through [`map_to_curve`] and clearing the cofactor.
+pub(crate) fn hash_to_curve<P>(u: &[P::BaseField]) -> GroupAffine<P>
+where
+    P: SswuParameters,
+    P::BaseField: PrimeField,
+{
+    let mut point = map_to_curve::<P>(u[0]).into_projective();
+    point += &map_to_curve::<P>(u[1]).into_projective();
+
+    point.mul(P::H_EFF).into_affine()
+}
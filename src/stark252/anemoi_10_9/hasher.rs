@@ -0,0 +1,2462 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 32];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 32];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 32];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U9>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger256;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger256([
+                0x254cf5de033ab727,
+                0x46ead3fe636758de,
+                0x93f3435ef8de7ba9,
+                0x0082691d7dbe0027,
+            ]))],
+            vec![
+                Felt::new(BigInteger256([
+                    0x7cee1ef67e2f1b00,
+                    0x3acdda69352ac0ce,
+                    0x8ffc7a1fdeb8ec51,
+                    0x02404a98b34ca2a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xebee20ffd5f903a5,
+                    0xec0d5602beb4144b,
+                    0xd1dc2babfcf2270e,
+                    0x06d8e3fe22fe46af,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x293c5a3bf1c89abd,
+                    0xd9e83206e4efb31e,
+                    0x6073429dfcf545b4,
+                    0x0363e1243d8a54a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x472a71cfcbee4157,
+                    0x6df40161a2a36a96,
+                    0xa4f912bcb47679c5,
+                    0x06a3304f65ce548a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe676a0f3451ef9cd,
+                    0x9a61e3ba2801706d,
+                    0xaee54a01804f2b36,
+                    0x0487edb492631a07,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x4746132135262c97,
+                    0x14a225635cd56b0c,
+                    0xc0023006e72ec809,
+                    0x056ccdfb5ccc7c52,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9d09f55ed8e0bdd,
+                    0x306a4d23ac4ff0d7,
+                    0x141baa4e006ae406,
+                    0x072564d092328db3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1fa2e511a5efe1e5,
+                    0x994685ca9122d5ef,
+                    0xd437e4a757092c96,
+                    0x01b4544bdb82d14e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6aa265bb1c8952f,
+                    0x67333a2e2ab0024f,
+                    0x15eb859b7492afb3,
+                    0x01361915dcce439f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xd30dca9fe59ec41c,
+                    0xe45f798281afe6b1,
+                    0xb630865ae930e3ab,
+                    0x00584d485ccebbd5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a05a5d7bbce31c3,
+                    0x1c73cf01e9fb62ee,
+                    0xf3b904c62cfc3c6c,
+                    0x05420c3f2d639e69,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee733e3ba7af79e3,
+                    0x07f7679d017d0fd0,
+                    0xca4fd5bfa3b7cde3,
+                    0x06a08a8817b973a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d6b69202552bf18,
+                    0xb0f42b8a7e325c14,
+                    0x488151fde4e3b715,
+                    0x07423c4dfc01f7ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbed67138e79c52e3,
+                    0xd687c2ab3a2a1b45,
+                    0x9acb85b814198411,
+                    0x026dd1ed1564395d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x3798395a65be0542,
+                    0x0838652051de4f20,
+                    0x7f49ed63bf68d298,
+                    0x073f8892dc3545b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe2433457220bab36,
+                    0x559b000ecceb5469,
+                    0xdc8f3f136956d69f,
+                    0x07f534f0c390e6e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1c38f074c468657,
+                    0x147dc3d50a288de0,
+                    0x674c9612ccc00910,
+                    0x03dbe9d820a3b5d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x487333169e6e2bd0,
+                    0xb850d2b6b662dcee,
+                    0x14f3f1cc8156da09,
+                    0x04c172a4849b4e07,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92b1c02af561a931,
+                    0xde59170fe3f4fb5f,
+                    0xb29ef7b7014366c0,
+                    0x016479429131dfd3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd075f986690b8beb,
+                    0x4a7f960e1757bc1b,
+                    0x40eb3c3460468ad7,
+                    0x06b93e68b99fb640,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0xe8a42b48d9da9add,
+                0x9a676d0b612df24f,
+                0xca958af4cc10ec77,
+                0x0742197b2e236031,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x47f51a29005bea31,
+                0x3c0994756b53fb81,
+                0x2e06fffaf37f625d,
+                0x058156b5b5c73789,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x1e9a5d0a4b84a244,
+                0xa605868bcd8d65a4,
+                0x039b4dccf1785149,
+                0x033f90fe1155443e,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x034f30370e6e3177,
+                0x76865ba514de166d,
+                0x249bb18c9f0c4d5d,
+                0x011506e7d01d6a4e,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xd1b82b7f9958b433,
+                0x4d57b1bf47e15c71,
+                0xe75dbcb76e32d28f,
+                0x028da538790391dc,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x105041c8ac798659,
+                0xc5f7f0dea0162baa,
+                0x1631d32bbc576c88,
+                0x0383be5a4479c6ef,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xd208488735f9c6c8,
+                0xe13a17c9274736f9,
+                0x4fe2227a3c45f95a,
+                0x040f7cf1312ba77b,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x542b222a69f13ab9,
+                0x881257f26c278490,
+                0x8bed1dd74d2f54f4,
+                0x0234fa31a64cf1a6,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x23a56bedf1d4f272,
+                0xea52eca15a0d171a,
+                0xed8683fcc0489c2b,
+                0x03d27734ac54f72a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x360ecad03c6734c1,
+                0x6320ebc0263c46c5,
+                0x99cc1e979508490f,
+                0x04084b791828714e,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0xe8a42b48d9da9add,
+                0x9a676d0b612df24f,
+                0xca958af4cc10ec77,
+                0x0742197b2e236031,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x47f51a29005bea31,
+                0x3c0994756b53fb81,
+                0x2e06fffaf37f625d,
+                0x058156b5b5c73789,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x1e9a5d0a4b84a244,
+                0xa605868bcd8d65a4,
+                0x039b4dccf1785149,
+                0x033f90fe1155443e,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x034f30370e6e3177,
+                0x76865ba514de166d,
+                0x249bb18c9f0c4d5d,
+                0x011506e7d01d6a4e,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 310];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xe2e6d4f5e6b3e158,
+                    0xef9600589ff723df,
+                    0xcc2e1f9d7dc89413,
+                    0x00bbc5a9e56650f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0d407d3d19b168cf,
+                    0xfc6940d8c885f5f7,
+                    0x7b70a02e8f9c5e77,
+                    0x056a4bd8aaa4dc88,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92ac5d44981ab01c,
+                    0x92205d06de0b43f1,
+                    0x80523c5b33eba35c,
+                    0x0186c36e5acedf5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61959bae1af238de,
+                    0xcf004f30b4ea2b22,
+                    0x772720a033a6ca5e,
+                    0x03dec6f728fbeef2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x510236ae6db255cd,
+                    0xf8f25b88275c46f1,
+                    0xb0f280f69879ae67,
+                    0x01a7c21bd9b4df2a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8cfa7d2d0b310f1,
+                    0x66ee152138e63abc,
+                    0x09a23da1ceb283f3,
+                    0x07c4684b6133d197,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25f46daa36b90ecc,
+                    0xf6841cb1713adadc,
+                    0xd0009d718d0989c6,
+                    0x067491d2f1e05d71,
+                ])),
+                Felt::new(BigInteger256([
+                    0x82527ce0138d7f13,
+                    0x48de04481c998dcd,
+                    0x44b5d48f14ed14f5,
+                    0x0414ae7060ce8afa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf1025d8c6ea8b883,
+                    0x3ce8fdb26433387c,
+                    0x3a5972739a0d042e,
+                    0x042ed9a6407c92dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ed0ea6dc6a9ff9f,
+                    0x013f972f832abdad,
+                    0xe4a7dcc7a6f750e9,
+                    0x05e9a7729e2cbb24,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xad4faf82ddd4c12d,
+                    0x237c3c3b2a4e89da,
+                    0xf7f50c2e98f8201f,
+                    0x077c1f98cf13a975,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa79eb01b3afc331d,
+                    0x9eca36c9151f309c,
+                    0x0443314d844b988a,
+                    0x047fd7e953211cda,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7e9e32bd87570664,
+                    0x57b0c7040b9a0446,
+                    0xa4f7b65016d556a7,
+                    0x037a4cc3ee84381e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x02fa70d18c85ebac,
+                    0x20c2cb385073076e,
+                    0xe8f9bd893e7b1e56,
+                    0x07b1db0b2c4895ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c518bee54d7d94a,
+                    0x4265e163a5a98982,
+                    0xb42b08fef627c2ee,
+                    0x07c970d37a29f5eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c5cd3c2c1a3dd2b,
+                    0x4c92b6cdafa054ef,
+                    0x81d24c782407ea72,
+                    0x0129745a4abdb199,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4477e4f042ad9e18,
+                    0xdc337f5ae20e7803,
+                    0x21b296a9ffe2299a,
+                    0x040caaedbdd2e368,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ba798d9f4dcfea5,
+                    0x5e5ceb790e876d45,
+                    0x7b5f7089d61a6d67,
+                    0x032207b202546aea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5285031922dd4959,
+                    0xedbf4bdc5b90b938,
+                    0x291eb92925a6e58f,
+                    0x0739d69a44ec9533,
+                ])),
+                Felt::new(BigInteger256([
+                    0x731e8c9bf3d68e8a,
+                    0x43197209f7a45852,
+                    0x5fe419b264fa7f1c,
+                    0x073b91b8810f0b91,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x60dc478809cfdf19,
+                    0x33fc63ad71503204,
+                    0xee1db1ce2dbabed8,
+                    0x022f03aed72bd35c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6f3eabc1be185075,
+                    0x7a74ed0f9b2961e3,
+                    0x2b4e64d224c3c7c1,
+                    0x0136d245154426a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb700c5d8e737ee8,
+                    0x5c71ecdcfdf40077,
+                    0x65ea696abd0e32b9,
+                    0x074e0f520aa0c8eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x53d935e3463f9644,
+                    0x33394f5ea8f8ce0e,
+                    0x57935eb0bcb464e7,
+                    0x0548dc76b2d2fa75,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7e7a1816ac87188c,
+                    0x40bfc377d1825b7b,
+                    0x2893de0f993705f7,
+                    0x01afe59bb8fb20b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a8c868c50380347,
+                    0x92eb75ce385d9d25,
+                    0x39621bb1169d7a6a,
+                    0x0504ac5cc15e9d30,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14ef81febcb16512,
+                    0x0b9e6fc3f93d8ca0,
+                    0xd415122531411b19,
+                    0x07886f8fcb4f7bcc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x52f7ea28dda2a2c8,
+                    0x6504ec0722602218,
+                    0xd875c00595802267,
+                    0x03dd87052bc2c2a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x79966be2c0c942fd,
+                    0x36d66fc6ced2360b,
+                    0x1c342483b9851a11,
+                    0x025db081d7951db8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45549e81697d38b1,
+                    0xd2c0bed0c5c60f52,
+                    0x5877a01a69215dd2,
+                    0x0021bea0046add72,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xa21f80f590b34a89,
+                    0xef8f28f464eae987,
+                    0x74bf6cf18a463f5b,
+                    0x0210694f238680f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x599dd53f719b8e45,
+                    0xce8985b4ba67802e,
+                    0x1c207fa4cf41b794,
+                    0x06bb17c8585d0868,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63dcf956ba481295,
+                    0x7733ccfb52553a29,
+                    0x8cbaf6e6ec0cb67e,
+                    0x02e7cca7b5cc7255,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b7ceea76d52bacf,
+                    0x01a08b9402b5b2b6,
+                    0x9ac806f451df09dc,
+                    0x037b720652b7614f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9429db419e2d0465,
+                    0x3c154be7ad071c13,
+                    0x6a345b14961592cc,
+                    0x069fbd6098c17374,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec9fab9e6cab6f93,
+                    0x5f948697fee684b7,
+                    0x6e34c1947c4dbf62,
+                    0x07136c7cd4870ca1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa67b4f81020b970a,
+                    0xba2d865b3d47429d,
+                    0x8446b73b89ceb760,
+                    0x027d96d99503b1ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x410efcddddb2d2a4,
+                    0xcdfc77688dd85483,
+                    0xb25ffcc9821594ed,
+                    0x02d76de602c90b26,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc54315c0180965b,
+                    0x74686a3afde583f4,
+                    0x407ae32e87072762,
+                    0x04dd072bb07cc2c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef38659064a95ff3,
+                    0x60fcade67a9f27cf,
+                    0xc6a8da126100a193,
+                    0x0151b3b2fc3db59c,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xb0febb364b67efcf,
+                    0x7664c03869d8d48c,
+                    0x9bd2091bcfb8f74d,
+                    0x00a6539a89b31d1c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf889c43048c4b796,
+                    0x81a7627133880bb0,
+                    0x5a94d88b32af06a0,
+                    0x07284295fc9845be,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8bc8a349213c51f,
+                    0xaf5714ab61a0239e,
+                    0x4699453e8f4c7442,
+                    0x04b07327956efc0f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e859340da28156f,
+                    0xffc89010cb468823,
+                    0x41f19f1131ddbd4e,
+                    0x04500007de995910,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d33bf033b913d43,
+                    0xe07918cbe14c1315,
+                    0xa87e88c8ff6bd1b7,
+                    0x05d8cb9e4a83d016,
+                ])),
+                Felt::new(BigInteger256([
+                    0x952d2c169699b06f,
+                    0x705a45bbf1d3dfd7,
+                    0x265d909cef1c97f9,
+                    0x0346dad45491bd11,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19b0473fada47f9d,
+                    0x4b15d6653396a5ff,
+                    0x6b5b6cb96ccb8cd6,
+                    0x06cba0b302050cf6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcaadc1f245079360,
+                    0xdde4dbb299143611,
+                    0x248fa670ea23b5ba,
+                    0x0021d4a8e77352b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x192cfbd7de9fef71,
+                    0x4c4356fa02b8cec4,
+                    0xa277ac37a218d27c,
+                    0x059a3ef28101934e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a55b70b9ddabf3b,
+                    0xa34a47fa3036bab7,
+                    0x6a132c9a01a4cb78,
+                    0x0225db10c84325b9,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xf167b3c0dd6b9a51,
+                    0x925f1fee5843c870,
+                    0x77abfd37c3516849,
+                    0x068e12d4423ccc48,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0d7a495fa028812,
+                    0x8885826d74d460ef,
+                    0x4a33d4d9d67557e5,
+                    0x07848f8ed15c8fa4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c5921b3cdd61dd5,
+                    0xe92f0a6105c4ad7b,
+                    0xed6ea2d8977341d3,
+                    0x01981580b59008a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x73abdc397fdeab83,
+                    0x425c0dd6c06b74c2,
+                    0x4199390331fa6714,
+                    0x06117554bc179494,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a9860f1556fe9ac,
+                    0xf63897ca97a09939,
+                    0xed6cf5f1f014b9c5,
+                    0x01cd8373735c0f1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabb652d21db019e5,
+                    0x9bff7f4adf70986f,
+                    0x7e53543d280346d8,
+                    0x04199af19181a585,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc47e3ab800e0f4f7,
+                    0x09e107e761ee198b,
+                    0x1bd43e2f47165b72,
+                    0x01728d3e7ac05da6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8013285daf76f2b7,
+                    0xa0ac489610c022d8,
+                    0x50431cb7596523ba,
+                    0x074bfe3eeba97b5d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x761e129b891e3e69,
+                    0xf369d5023a1090c8,
+                    0x4e25166100c839bf,
+                    0x0363ad015fd8d70f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1664d678d618e827,
+                    0x978f901746f933f7,
+                    0x2b2a8d2491dfd421,
+                    0x0298bddb9d589dd2,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger256([
+                    0x66bad43cf68c19db,
+                    0x7fbbf2cb88c22c91,
+                    0x76feb4b27ea0f27a,
+                    0x053815e1f7054308,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3f8a43fe56db737c,
+                    0xd0b91f97761c717c,
+                    0x3fb35fabe305bbca,
+                    0x06101a57a10e2153,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdda0ac5d43de5960,
+                    0xefdf160351b327d3,
+                    0x0efb8493579b28a7,
+                    0x002fe6f74f000b12,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe552382ec38fbbdf,
+                    0x27d956c36489c869,
+                    0x0b2a145f809b9dce,
+                    0x009256be799645a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63604ce3da2416c1,
+                    0xe48c84595c479548,
+                    0x06c007ae026923ae,
+                    0x04208ba01724392f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa02f58870a1a9385,
+                    0x4b91fc189ae160bd,
+                    0x722261692cd867f4,
+                    0x0401f43cd1cd1417,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f34258333302e8e,
+                    0xceae51d197dc798e,
+                    0x82d197f569161535,
+                    0x063af58aa79ac34a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6541c042d5d4e495,
+                    0x211fcaa3d57d09f0,
+                    0x87f42e78a748eb6b,
+                    0x06fc47dd15da291e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef4ef6ca3170510f,
+                    0xee7ec730a56c13d1,
+                    0xd7900fe8962e9650,
+                    0x071df700a99add15,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdfaeb5315e398c1e,
+                    0xe48df104613f2e5e,
+                    0x8deb062c522c977d,
+                    0x0773a8ecc89ec3e2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x230e732810722f6b,
+                    0x3003ad58ac2afc45,
+                    0x2519acc1ec4548fe,
+                    0x0180f88548695e67,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1cdbb8d0788a8a8,
+                    0xa847232c05a7406d,
+                    0x8db52f5d3b1cdad3,
+                    0x05ec04f993d36b14,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8bcb66b986229284,
+                    0x22d447ef95845f6b,
+                    0x52b1596bfd2cb7bb,
+                    0x02555a0b4da237ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c9d79fadb4d61f8,
+                    0xe901e5334b552bfc,
+                    0xf8b25d15102c0f97,
+                    0x07935bbc7b776875,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9709009759c4f42a,
+                    0x6bb57955532b90b2,
+                    0x982a30f08a23182d,
+                    0x053399057377b93c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x326408e91d815832,
+                    0xd843cf23ad8162f6,
+                    0xbeb3e39878ea66ce,
+                    0x044b5d68c2ae0ec7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1dd30fd0b5bb895b,
+                    0x6be198615d4c8339,
+                    0x4a0ff70c92910594,
+                    0x04c894b3e57ebefd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x338e31687cf79d2d,
+                    0xa13663006770fe36,
+                    0x8f5e4026c88ae60b,
+                    0x012589aef84783d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x071770bf7097fc5e,
+                    0x56f7ef3d0141ff9d,
+                    0x05265e1d9b26e950,
+                    0x018b310bb34208c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ac5f9f16e6ff5fd,
+                    0x8126d2992160aaa4,
+                    0x3e4e91b54f0dd7b0,
+                    0x06b38f1b934ab085,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4033c982eb3265b7,
+                    0xaa938e828afa4316,
+                    0x93715515f4d0b908,
+                    0x055c5a79999e5111,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c0893dcd04a129e,
+                    0xc1c69213a38835bc,
+                    0x362442e1a78e52c6,
+                    0x07fa815d51923a96,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd6d462b730991ec5,
+                    0xf05e7f767a980b97,
+                    0xb4ea17140c51c8d0,
+                    0x031150c7544fbe91,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36d6d7b6f0ced941,
+                    0xdae663b170e387d3,
+                    0x1bfaca8c5788ae83,
+                    0x052f697871496eaf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc4753aecf97c016a,
+                    0x0e6997df73b2114d,
+                    0xc2cbe65625627eff,
+                    0x01bf38a740c5bfe3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb59c0f1c22cbded3,
+                    0x811b4d00b8fb923d,
+                    0x1fb1f4c5760b3773,
+                    0x03adf9d98ec08610,
+                ])),
+                Felt::new(BigInteger256([
+                    0x378f3a740639c9d5,
+                    0xdd517cd367b43f12,
+                    0xcfe8f801689790ef,
+                    0x0408d3f1ee7fd8dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61ce4a704b0a3531,
+                    0x9c5167487a80845a,
+                    0x731f4e644b721b55,
+                    0x01aa26b36b1967d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd0edd4915c43c7db,
+                    0x127631d606ea1d58,
+                    0x0268ad4ca78b4386,
+                    0x005682d1f022dcf7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7da369ba7792b23f,
+                    0x85827ddb456a7fa5,
+                    0x3a66347a533e3d2a,
+                    0x007a21452b6e27c7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa9e724e8b4a276fa,
+                    0x91ca7e61d9c18fd5,
+                    0x136ec4f8fbfa67f4,
+                    0x03de87da8867360b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8265f7b041678207,
+                    0xbc8d50582bde9b52,
+                    0x2ab4eb77481db223,
+                    0x04b3bb8e31aef63d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24f48e86fd87c675,
+                    0x40b4a3f86c81b8de,
+                    0x0f2d2b9f79a1bc98,
+                    0x035fe47ab44ecbce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae06931d1674622b,
+                    0x3c682fc97f2424e0,
+                    0xa40ce6caa231f4bd,
+                    0x052e796604288f97,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a4c7f772d523d4f,
+                    0xad45fe835b0ca8e6,
+                    0x4ddc9053e8e851df,
+                    0x03d8f3484be16a28,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x039ada144188d7e5,
+                    0x8c33b4df6e12e315,
+                    0xfa1c88b2f643ca7a,
+                    0x06d87d183a0773d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81d927d66f7cd046,
+                    0x052c1b236e1fd9b1,
+                    0x2d2c7659ca8054e4,
+                    0x061d5573987dc524,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8fd6b0f50f82b0d,
+                    0xd964685870348218,
+                    0x14ca7564460a9d5b,
+                    0x045a5d7d22d7375f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8e66b2752bc17edb,
+                    0xaa74d3bfb606b313,
+                    0x65ecaa0735a2e386,
+                    0x0228df78159f67f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99b202dfea5e75f4,
+                    0x465589a180f2d227,
+                    0x589c188ae4b82e73,
+                    0x02e01152ab20e4c6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa1d410d442cb90bc,
+                    0xe8957c3e5cd60bee,
+                    0xd2c3bed244b98b79,
+                    0x06979563b984a28f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x82594aaf7e736c0c,
+                    0x83471daeccf55179,
+                    0x29e9bc89acf9b43d,
+                    0x07ea2dd642f2be9a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77592aec9f59e182,
+                    0xd884a856913a01b8,
+                    0xee74b7d709ebf27f,
+                    0x024ad599fe998f57,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6333e91d0a10f699,
+                    0x902ab3fee35cebd0,
+                    0xca5d75729514e1f6,
+                    0x075e263cc0f0037a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b9ff66e95a57f51,
+                    0xa5761008483b61c2,
+                    0x1c299266b81b3c56,
+                    0x050d8e75b8846d03,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9500cd766727de65,
+                    0x72cfbcbaf4bef795,
+                    0xec5b51eccb734173,
+                    0x057bd62ff333ec47,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd6fd18d2d3d7be4e,
+                    0x108e064a5d6b2c9c,
+                    0xbe733455b5ce5b1d,
+                    0x0036a16937f6ce61,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a1f7ef5d7a803e1,
+                    0x67d3387181e2c5e9,
+                    0xe97739f1feb66455,
+                    0x06111d5492f9183a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa466e164f23d3c31,
+                    0x049a51edfabd0635,
+                    0xe4d7923f966e3376,
+                    0x062881c99ec7db7a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d3377e29b1707a0,
+                    0x6f9a860b643d66e0,
+                    0xd957dec87a2ee9be,
+                    0x06ef6902de1e761a,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 10],
+            vec![Felt::one(); 10],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xfc5a291e3c774beb,
+                    0xe0a43d0e421159fa,
+                    0xdc2fd10b349dd929,
+                    0x02a8a0e5db366178,
+                ])),
+                Felt::new(BigInteger256([
+                    0x206e2ed205aa45fc,
+                    0xfa29a33807188089,
+                    0x65f07e14a70048af,
+                    0x03e533a69bb3ecc7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2cd382d9331cf0f3,
+                    0xc26e97225393e0d3,
+                    0xd0ace88322fdfc5b,
+                    0x052c379484baf8d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd627c854059ff26e,
+                    0x51b296a5bb55d616,
+                    0x8ef0e3740aafe287,
+                    0x001607c8403ce278,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2fa9ca8385dd0c4,
+                    0x3bb2a7a98d4895b4,
+                    0xb07b4323fca9a4b5,
+                    0x03466dc90db7039d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x225276bd29e79e40,
+                    0x89aa4a038aa4998a,
+                    0xbad3773012c14bde,
+                    0x050daa48d357009a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e9dbfe930eeec7a,
+                    0x62f83b2bee3747a9,
+                    0xd7af3e963a5421ed,
+                    0x071e54230139a2a4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1142a2ae1c37fb98,
+                    0x49ec43d4efd8ef20,
+                    0xcc72f7feeb352862,
+                    0x02896f3f32095634,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcbfa8e981fd80942,
+                    0xc20c667caa86a951,
+                    0x5741d1e2f3dd52de,
+                    0x078961950d83a8e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe14e345596cee0c0,
+                    0x3abdb27680601467,
+                    0xced2fdb2782db3f2,
+                    0x06cbb62304665acf,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x7a87acb6441b5f33,
+                    0x0709dfcad4740cd7,
+                    0x3fa860295a4bb79a,
+                    0x00f94026d0f58004,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7f73a30a0a098a5,
+                    0xaeeb1f588ac35cad,
+                    0xf910ff0c9dd159d3,
+                    0x032d8d3c82dc6c42,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7d69001c11aac995,
+                    0x10b74ca2dd7ced47,
+                    0x1f1a8be4da6be974,
+                    0x03ae3dec85d50746,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69f0adcf24168563,
+                    0x11eed2324be4e944,
+                    0x1d852b14e6c7ee55,
+                    0x0121371a8703d8d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x593aa2d6340d9ae0,
+                    0x91089c41fcdefaef,
+                    0xa9abcc2c160548c2,
+                    0x05050b4560197cc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9ae89798ff3c57f,
+                    0x2b89fe4d9f251f8d,
+                    0xfa21ce46e6d12c3d,
+                    0x059476de84e4498e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37b2cf53a2e161f1,
+                    0x49e7fb110982ca20,
+                    0x89a70f5134b27f95,
+                    0x0504ad1145b6fd48,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa156382d3ab08e20,
+                    0x28c783177e881254,
+                    0x0e08c057f273a395,
+                    0x04b7b7e391fc0d9f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36a224b9c57f873c,
+                    0x39efbb79e1283b0d,
+                    0xc89ad345171c32fb,
+                    0x046164ac34d720b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3435b794ce2e4b3,
+                    0x500d9f1e66ac4f4c,
+                    0x063c5183b119e176,
+                    0x0229c7f7fba0fd9d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x15800bb419d4dd29,
+                    0x8eef0819210f3a1d,
+                    0xfaf848099c50c330,
+                    0x06662d8003f4f680,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe2b4f4d84697183a,
+                    0xed92c199fc6d864a,
+                    0x6ccd1c36d205474f,
+                    0x0299d9d5dc33c632,
+                ])),
+                Felt::new(BigInteger256([
+                    0xedbb0f1bb8666880,
+                    0xedc0ada48883844f,
+                    0x3b28b2cc3b3659f6,
+                    0x01f52ffd498409f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30fd853b719d58d5,
+                    0xefdef3ec4c28e865,
+                    0x1cb3f51528fc304d,
+                    0x02fa465e05479fb6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57858aeaf3f4a864,
+                    0x16ceb709e85cf8f9,
+                    0xac6acfa2f1cd162c,
+                    0x043ec5f4a4343fed,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee3a725efccf83fe,
+                    0x5b548bc4fa3a23b1,
+                    0x62b0da268a2cf686,
+                    0x022e90b17c93e586,
+                ])),
+                Felt::new(BigInteger256([
+                    0x888b90f387476898,
+                    0xcd23988f2e4eefda,
+                    0x487df31c003259f5,
+                    0x04aa66daab156a17,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa2ebf4f0f57a3c99,
+                    0x88e1bba67ba3c01d,
+                    0x6efa7fd33c49702a,
+                    0x030958839d76c53d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b61ca3e8e81ead0,
+                    0x7a7b00a44c0a5fe9,
+                    0x5e4d6ad26365441b,
+                    0x03a8fbf13f0c4491,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81477c7e4ab3bb05,
+                    0x7b8bd7020dcf8e61,
+                    0xb8c77685a32e19d1,
+                    0x003e11b2abcd81a6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xbf762ceedb41448c,
+                    0x76608762bddbf5df,
+                    0x155343256a45c29a,
+                    0x02dd2377fd659947,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8b2c82e24935a31,
+                    0x038ade3a61b4dbc0,
+                    0xd37af645be278fc6,
+                    0x07dcc06a9ccbf247,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e62bce0b39ce4ca,
+                    0xc9b876bcc9d44420,
+                    0xbeb741d49b68a5a4,
+                    0x05f1b7e9d6b8aca3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4322e1057eb584f0,
+                    0x1f0bba6c885918bd,
+                    0xb30dd01d5d91c1b0,
+                    0x003e3a759db17ee7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x64b4b0279039b6c6,
+                    0x0618b5b4c5e5ecc2,
+                    0x91ba27db95f6f120,
+                    0x05ec703affa2229d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c0b36c8825df7aa,
+                    0x2d0d84045a1741e2,
+                    0x6add1a68db195415,
+                    0x06195d36f263b7f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b24caff3176944d,
+                    0x0278684b9cdeeb9c,
+                    0xad33991b94b350a4,
+                    0x00b3ee504480f57f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8bfb8a2de7962a73,
+                    0x79d90a83b1fff182,
+                    0xbf9a5a3ee7cb8d77,
+                    0x05577c24b491c477,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbf5e3f70d4e29d79,
+                    0x93643529538b155f,
+                    0x8cf94f87603a06ac,
+                    0x025a28041c467aee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbf377834b12609c8,
+                    0x61c2a787959802c4,
+                    0x1ce1fc8574bebb4f,
+                    0x0217d75685b75d31,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xb31c6665ca0ebfd6,
+                    0x50efa9e10f8c6368,
+                    0xf8e2bbe0638fecbc,
+                    0x0418fc6b1bf38fe9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x482d8eb7860323bc,
+                    0x298e1e596956aa30,
+                    0x1373321a06f6b02f,
+                    0x0724f2d5912619ea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x809193bf44cb7888,
+                    0x5bfc47f899f30413,
+                    0xa57519d5104f4f5a,
+                    0x06cfb73d81adbf6d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1868c8bc357e0179,
+                    0x07385483523641c6,
+                    0xd2d8807ed666d4e3,
+                    0x058ada1e040309b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed2e7d9775e674ce,
+                    0xfc0929b528a75b9f,
+                    0xb68d0e861451a6ce,
+                    0x07bfb922d9f4f97d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3dafc6ff8a3c94b,
+                    0x2d0cc572cbb08f4e,
+                    0x53b40ce308525aa1,
+                    0x01a37ef0c032b39b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7636fbd86cd35beb,
+                    0xbb3a3c0c846aa61e,
+                    0x197c7658241a97f8,
+                    0x0586c2b2454dfdab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ea64703bcfbd63e,
+                    0xccc78f3cb5b963bc,
+                    0x93e2f9c2bde8a6f7,
+                    0x02e46c9d7b00676c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x98bb56fa215c7e7f,
+                    0xc43f655588157e14,
+                    0x1cc00ceb1b477ea3,
+                    0x02675c8165b5ac02,
+                ])),
+                Felt::new(BigInteger256([
+                    0x912c27fe8423a1bb,
+                    0x4f96e8d9d753f947,
+                    0xb0cdf15ccb655d41,
+                    0x077c3aa74e37d329,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xcf8ee36452eb8afd,
+                    0x9a2a0dbf987c99fd,
+                    0x881f8895d043b3a3,
+                    0x048814a11bfb06da,
+                ])),
+                Felt::new(BigInteger256([
+                    0x350f12a47583d7d3,
+                    0x596d37add11b3111,
+                    0xd37ffe363687169f,
+                    0x07c0bdc58f7c5aa5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee211b9d546d1343,
+                    0xf036fae4e6a29e41,
+                    0xeb8a6fe7c9e279bc,
+                    0x02b044963735ab87,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4640661fefec606,
+                    0x626ce283a3c06ca7,
+                    0x00fce0a805ab0b62,
+                    0x0318c1de7d5d4447,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a47bbb05e6c2a38,
+                    0xb886c2054fa3a5b3,
+                    0x4c8963db02266813,
+                    0x06d9b72c1c03af5d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x31fe8d6152809c1d,
+                    0xe3f2db59c087ed0f,
+                    0xaa358b9418555482,
+                    0x03519e386f628eea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ca6fb0b8b791330,
+                    0x3a1d8497d1871bfe,
+                    0x75c09f7f477d54c0,
+                    0x04c9ba64cc1a8e19,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8d409361a2d84de4,
+                    0x4f3a4cff02a459b7,
+                    0x236879695f05394a,
+                    0x0773ab61a2f6439d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfbff0467ea5bb977,
+                    0xeefcadce98ab9d90,
+                    0x12ae5b0a1a91f0be,
+                    0x04c4fc30e9899c66,
+                ])),
+                Felt::new(BigInteger256([
+                    0x62b2a15f7499d9b8,
+                    0x544d978b021b89b0,
+                    0xcdbc9a9fec6a202a,
+                    0x018d98b0c7144eab,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0xcc9849ab2ef9b955,
+                0x4cba038311632393,
+                0xd797b4ff3c46986a,
+                0x002af98f77cdee1a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x23a2ea48a2c983d2,
+                0x0e6cd0c30ee6266d,
+                0xe2633dec25929664,
+                0x07cad192017ba145,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x744e1000d32fc0b7,
+                0x4fd676fce5d758cd,
+                0x965cc390bede0352,
+                0x06894c4c18ce22f9,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xe5a2b4d32f3c7113,
+                0xbd7a8c5b94e18ea6,
+                0xdb970a9ebe3b136f,
+                0x02783bf2e7010ac7,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x2f288cd301e7293b,
+                0x52c8dcbd41d8d457,
+                0x0909e57cef7fc723,
+                0x0713663ce48729dc,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x46757cff0b032ecf,
+                0x518d742751ac1008,
+                0x1ddc1c26d23d6818,
+                0x01567e991a1f0f33,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x4af8b7f13825a26d,
+                0x1e822fe5685a6e6f,
+                0xa0452f65143c5391,
+                0x078331d586170d12,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x2e57c47ccbaa788b,
+                0x4c8bd4f519261319,
+                0x350a74929e0e739d,
+                0x07e0a31e71a2208f,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x900d0008e235896f,
+                0x2d520f7cd63fd580,
+                0x38e55fa1d899290a,
+                0x01915340877382fb,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x34b77a3c4837052e,
+                0x7fcd24898f73eb32,
+                0x6e11f683108a6733,
+                0x04ada29bca9624e2,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 10));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 279, 280, 842] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 279];
+        let mut opad = vec![0x5cu8; 279];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
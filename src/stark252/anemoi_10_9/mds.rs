@@ -0,0 +1,165 @@
+//! MDS matrix implementation for Anemoi
+
+use super::BigInteger256;
+use super::Felt;
+use super::NUM_COLUMNS;
+
+/// Maximum Diffusion Layer matrix for Anemoi
+/// [1 1 3 4 5]
+/// [5 1 1 3 4]
+/// [4 5 1 1 3]
+/// [3 4 5 1 1]
+/// [1 3 4 5 1]
+#[allow(unused)]
+pub(crate) const MDS: [Felt; NUM_COLUMNS * NUM_COLUMNS] = [
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff61,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff570,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff61,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff570,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff61,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff570,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff61,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff570,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff81,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff790,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffff61,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff570,
+    ])),
+    Felt::new(BigInteger256([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ])),
+];
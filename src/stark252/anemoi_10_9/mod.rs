@@ -0,0 +1,2185 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger256, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 10 field elements or 320 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 10;
+/// 9 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 9;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 5;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4]];
+    let y: [Felt; NUM_COLUMNS] = [state[6], state[7], state[8], state[9], state[5]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4];
+    state[0] = sum_coeffs + x[3] + (x[2] + x[3] + x[4].double()).double();
+    state[1] = sum_coeffs + x[4] + (x[3] + x[4] + x[0].double()).double();
+    state[2] = sum_coeffs + x[0] + (x[4] + x[0] + x[1].double()).double();
+    state[3] = sum_coeffs + x[1] + (x[0] + x[1] + x[2].double()).double();
+    state[4] = sum_coeffs + x[2] + (x[1] + x[2] + x[3].double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4];
+    state[5] = sum_coeffs + y[3] + (y[2] + y[3] + y[4].double()).double();
+    state[6] = sum_coeffs + y[4] + (y[3] + y[4] + y[0].double()).double();
+    state[7] = sum_coeffs + y[0] + (y[4] + y[0] + y[1].double()).double();
+    state[8] = sum_coeffs + y[1] + (y[0] + y[1] + y[2].double()).double();
+    state[9] = sum_coeffs + y[2] + (y[1] + y[2] + y[3].double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3f6ddfe726bb2de6,
+                    0x3bf4c6568c6ef951,
+                    0x971f4fd3b2030888,
+                    0x0071acdf5b5d3c14,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19cad922efe222c2,
+                    0xbab3d51e2d16d895,
+                    0x367a4a6e80a1c96d,
+                    0x03887d9729f58399,
+                ])),
+                Felt::new(BigInteger256([
+                    0x48385efb85fb0154,
+                    0x906f771cec29bd85,
+                    0x38785b1a4645c30a,
+                    0x046ee184c5da5924,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97e30a3360fa0929,
+                    0x905cb96e4eff7bdc,
+                    0x38d6b697cd67f093,
+                    0x069a8b625e4c093f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ad910986f95d7bf,
+                    0x15d45c18955fc067,
+                    0x5d4adf1861c23cb5,
+                    0x017a5acd1b8b57d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x923d4cdaefee7ba6,
+                    0xb8163014c2798651,
+                    0x1e04474208f806fc,
+                    0x06192156659e19d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7d4b9ba43a83e680,
+                    0x28aff6602640f702,
+                    0x240f017b97aa709d,
+                    0x03bb3270924e106d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x628c964bacae103d,
+                    0x05d043ca597864f8,
+                    0x3b1210ac2f5ecbdb,
+                    0x06fdef87c95da7d9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae291a5a4b801e8d,
+                    0x5d54d7aecc6c2f01,
+                    0xa4699a94f4e3df70,
+                    0x02bfb6baa9f14c41,
+                ])),
+                Felt::new(BigInteger256([
+                    0x220f158bfb044728,
+                    0x0b8c215c4aa00a65,
+                    0x68c439d1a7658e10,
+                    0x024c39758d2411e0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x019afddadff7af2b,
+                    0x58cd453ecbdfebaf,
+                    0xac28337f97f62d78,
+                    0x057785b662804ca0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0380f16196e153ea,
+                    0xa237da879c0b0583,
+                    0xea222051ceecee29,
+                    0x05fc34b905288435,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a865a95addabd46,
+                    0x75bd6c67f096ac00,
+                    0xbbdfb8611f5c9e4c,
+                    0x044e435bccfb97dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01cc4372abad786b,
+                    0x3cbb8705d071a7f9,
+                    0x92f3b03b8fa541d5,
+                    0x058adfaae902cb73,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19ecc4d71335ec27,
+                    0x3eb4d47ca3277595,
+                    0xb08f2eccf51448b1,
+                    0x01ddb2bac2b1b78b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x502e840e883793e8,
+                    0x62f0fd99e881caa4,
+                    0x947fdf7e79842858,
+                    0x0291f1a79f95d83d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x31643f06b31c36c0,
+                    0xa423a44dc9bc8fa1,
+                    0x63f4ee7798ae5790,
+                    0x03e8b56489f5c088,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7499bd535f628927,
+                    0x52255e2485c4f07e,
+                    0x6e23a2216f4107ff,
+                    0x0388e9f2863635e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf15ef3fd2e06770c,
+                    0x2f71c9cc38a3f90c,
+                    0x361ba0d7fb6c89d7,
+                    0x04d34053ece03cb4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2315e7b85e29a83b,
+                    0xc43f9b7416b9a692,
+                    0xb8b2ebb459dc0958,
+                    0x02819074fd57c73b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x46610621134e2f7b,
+                    0x9f552ff6bea9c5b4,
+                    0x3423b3dd44280353,
+                    0x02d4b546070e4789,
+                ])),
+                Felt::new(BigInteger256([
+                    0x764af8aa917fcf80,
+                    0x309dd96fc5e38cf3,
+                    0x63aa027d5043a273,
+                    0x04e00b8539b5dbb5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9408a431cac7469f,
+                    0xe235fdf0e7f27f2e,
+                    0xa55fc0c23e5e3dc3,
+                    0x03eababcda6a7803,
+                ])),
+                Felt::new(BigInteger256([
+                    0x42613c413547ded9,
+                    0x6b8924998c5115e5,
+                    0x5bbd9e0ad182483c,
+                    0x01cd7ebcf597e5a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb32b0278dec71981,
+                    0x6ebad42c881edd22,
+                    0x4c8de29eb00b5133,
+                    0x00f2e7898fc9ad95,
+                ])),
+                Felt::new(BigInteger256([
+                    0x972ac092d505f2c4,
+                    0xe9c60ff36032c683,
+                    0xd51d702b7ebb2357,
+                    0x0113738f4a149d9e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2e9073432b0103b7,
+                    0x86d4716517518e77,
+                    0x9585d86d8a2fdb6b,
+                    0x03c2fe6d7c69a149,
+                ])),
+                Felt::new(BigInteger256([
+                    0x064646e6350efdb9,
+                    0xb3621d3f3a965249,
+                    0x9e3c5393ceda527f,
+                    0x04624824a9602fa9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4877244b74bb2317,
+                    0xc1e746cf6ef73a2c,
+                    0xdbc633d7c9822287,
+                    0x05983db34e3ac0d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76937c8fb9853669,
+                    0x9f0ce9b6a132de90,
+                    0xa7121e5209de1285,
+                    0x00cd00d48ead6c10,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x46fe9234b39a21a7,
+                    0xa18e3309928c53f0,
+                    0xf372e5a05d0a3f59,
+                    0x01315ddc5c370473,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfb9d6e4030be79ce,
+                    0x12ad62df8e8fd695,
+                    0x1d0c9adaae06616c,
+                    0x000ba3364216aff1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0c15d167adb94465,
+                    0xa3321742f659581e,
+                    0xd42e0ead84ab9017,
+                    0x07b3c62cc0ca0c74,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0db465a267bff5b9,
+                    0x04c29f95d64b31be,
+                    0x3833a11a7d7bf3fe,
+                    0x07c798eec1d3d22b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x14617cf3baf04caf,
+                    0x7c9108b660727637,
+                    0xf715180152634f25,
+                    0x02a4c9dcd74f854e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xafd813202b299dee,
+                    0x762bbde7fa90ba5c,
+                    0x1317b7be1f3c61a8,
+                    0x02f1fdb12f27e11b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe340e411b19fe8bb,
+                    0x359c4eebe84872b7,
+                    0x32cddf967aca6b08,
+                    0x009999286b993280,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa89169c0b5e0afb8,
+                    0x69c7acb55d31e880,
+                    0x766e70a43ee10059,
+                    0x06f6aa2aec4a52fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7bf7dd8bc1c43857,
+                    0x3a8dcd97af4985a1,
+                    0xb4796c8dca70d1d1,
+                    0x02cbd759f332e166,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7eb03135ba5f140,
+                    0x7bf1468a3565daed,
+                    0xb13d1100dd3a1803,
+                    0x03da4b7298a00f12,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd71dec10502684c2,
+                    0xd0bf62bc42134ceb,
+                    0x84be2e6a9acd9916,
+                    0x053464cd9e894137,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e4c2945ababe87b,
+                    0xc797af51e206341e,
+                    0xb97324f06509cd13,
+                    0x07343f9b5516d2b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3909d694263dcd9,
+                    0x3fa7dd82de67eff6,
+                    0xe272d149c40caea9,
+                    0x01ed3c42941ab83e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7439480ad35b1f35,
+                    0x6267b93a42cc29fd,
+                    0xebdc61385ec868b2,
+                    0x07e8d72345ce05b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xedc19745e63ee1cb,
+                    0xd4594deec73c6415,
+                    0x81c65a200aad41ae,
+                    0x03b422141254ec5f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7a7f22748de879d,
+                    0x28592b1ef2629046,
+                    0xe701850f2ad9a86e,
+                    0x040e267f1ba456bf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff30f0feee670024,
+                    0x17f86ff33539cb25,
+                    0x89e8bc1f0313a095,
+                    0x004c3e1522e6173b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd77bd3466f7c09fe,
+                    0x2cd311f542624a38,
+                    0x472d6e052a30f187,
+                    0x003c4aa6aede892e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbff2747f24d4f8d5,
+                    0x940fcf2c3824e9dd,
+                    0x6355df6def19aa57,
+                    0x03e7c389ae45a66e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa3cff1e182a71cea,
+                    0x1e24160b763e54c7,
+                    0x241bf9ae6bb558e6,
+                    0x04d4dc0db203b8f7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa432412b009fb161,
+                    0xe98db7bfc894a531,
+                    0x55f3b02fea31a6f2,
+                    0x0060cb4765a48d64,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6493604ba1702a0,
+                    0x5664b528534e838d,
+                    0x7e72ab9c9e1667f8,
+                    0x0721bab1136dea93,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63f1a774e1ae1b70,
+                    0x32df4380f7c0627c,
+                    0x645c17c1027ac505,
+                    0x0377cf103ec88c96,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf27f43a8f738a554,
+                    0xfb3cfb7c19d0692a,
+                    0xda72125b20071839,
+                    0x008437536e8caa5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf5558dd68000cb6f,
+                    0x442d41737bca0ea1,
+                    0xd7bedce70639f955,
+                    0x05c56c61cdc33e2b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xafef3fcc7378f40a,
+                    0x972d4de9622cd4fd,
+                    0x42542fe94ce3493f,
+                    0x05baa3f13934b5a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5db00a94e6cbc3e3,
+                    0x14372f6dff9e6c26,
+                    0x5f1dd2480732e5a7,
+                    0x02e29723bd1d7b43,
+                ])),
+                Felt::new(BigInteger256([
+                    0x815b0841df4ad837,
+                    0x2a01bb05572587c4,
+                    0xffac0cfba9467ece,
+                    0x0019aded9a5f4124,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0662c90bc3e72baa,
+                    0x29a152db039a0aa1,
+                    0xd349d04258ec9c6a,
+                    0x035301749e9020a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x738c4d28592e20bf,
+                    0x58ae131af9a4a1d6,
+                    0xfa3a4cdd5000b1d0,
+                    0x05b747a7e8255985,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x05dcde832a13df8f,
+                    0x0b18ccefee5161c3,
+                    0x57b132c48a02fa92,
+                    0x03b5d40ef8c8bde4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf3abad2ecbaf6f1d,
+                    0x587d2ca47d1afaea,
+                    0xfb3909f66a16f7fd,
+                    0x03eab7e7860f7461,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbdbfda0df51c0c04,
+                    0x633069b8580c90d4,
+                    0x4ec43a1f1264d25e,
+                    0x069ef436283e7654,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01602d7c1eb24293,
+                    0xbf4edec6dc0be027,
+                    0xe095631ecccbccb2,
+                    0x00af31f8e304deb7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37154dbf8556f81c,
+                    0x6e02a8d2c8f2e50c,
+                    0xdbe472f969481a3b,
+                    0x047e19bf0075610b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd8518e797621163b,
+                    0x7f980ca95ab4df37,
+                    0x7522cf8505d09e4c,
+                    0x008b7cf2c8c62ce1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x38d61469f0db1627,
+                    0x539995e8757f89d1,
+                    0x5958ec0cabebc3b5,
+                    0x06dbd876d8c72ea4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa96e6a8f96f31104,
+                    0x55439ede8ee1d189,
+                    0x26843c27139d813a,
+                    0x07dc26cc6f165ff5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0435c8e9f2c1663c,
+                    0xe5afc4e8b8974050,
+                    0xa66f14d1e4a77657,
+                    0x020c0a6e89be06de,
+                ])),
+                Felt::new(BigInteger256([
+                    0x325f351f483df6fd,
+                    0xced1340d9c460e50,
+                    0xffeb13fb865fe523,
+                    0x04967867b8fe977e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc0e104239139cfe8,
+                    0xfce1a28c45e00529,
+                    0xdc1db75640bf68e9,
+                    0x015193c862b398a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd44c78c763fa7267,
+                    0xc81b6149fca0ec14,
+                    0xe2ef5db99c1dfe89,
+                    0x0091f3a165e27279,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b063785f970a7d5,
+                    0xa78c38c4b069a00f,
+                    0x3a70baf5269e0738,
+                    0x0288eae98265f2e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba0e33e1c8d68bee,
+                    0xe34378dc1495d9f9,
+                    0xbd9a3103a17ccf7e,
+                    0x02ea0ed80e11d048,
+                ])),
+                Felt::new(BigInteger256([
+                    0x34c842286c1e355b,
+                    0xb600197720219a02,
+                    0x193084554f8fc390,
+                    0x03ccac33d8c6876e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x40e88865b73ed120,
+                    0x2acf3caefa57160b,
+                    0xd63df219b853655b,
+                    0x071f1fbb93e27346,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c2dd9e03cf1f9e2,
+                    0xf32da5a1ae1d6313,
+                    0xcfb2e1cbb22aaa4b,
+                    0x053346d4d251767b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdeb5f846e150d95e,
+                    0xead13980e9ba3188,
+                    0x66a083701422a6c1,
+                    0x01b32e06741fa70c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c17806217af33a7,
+                    0x556bf1981684aca6,
+                    0x6d7f5fa453a5bcd3,
+                    0x07e30a2d4e41ed6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b8a3e63da30307b,
+                    0x159847ee7611d563,
+                    0xcd91c62fce381882,
+                    0x0556f85ca64a4203,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x966ae789275e248c,
+                    0xc5d28f487ae928b8,
+                    0x8607fd0613a07e31,
+                    0x014ecbae335d2433,
+                ])),
+                Felt::new(BigInteger256([
+                    0x17d00b1a1d666369,
+                    0xde5643f5a360c083,
+                    0x6a6a567d5df45304,
+                    0x027e3e128a9c2306,
+                ])),
+                Felt::new(BigInteger256([
+                    0x53028526894f6aa7,
+                    0xd484f14da8508c2e,
+                    0xb33c7fd37c4f9438,
+                    0x02e8a63fc573fe8c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeee6bf31d86eb977,
+                    0x8dd52ebbe29a1fe0,
+                    0xa0682ddd7c292dfb,
+                    0x032e38681f26093a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf86cb3fd828d34ff,
+                    0x9b4405860a96c752,
+                    0x8947b97ffd9c909a,
+                    0x05e4e80c09cc3f96,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd70cf73db494b731,
+                    0xa4bd9aaa183889c1,
+                    0x68a3e27e333b306c,
+                    0x07b4fc0cfb2471ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd9c7e27b2ecf460,
+                    0x44c7c28142a82b01,
+                    0xf96c7d5e72733a5e,
+                    0x061ba31efb779465,
+                ])),
+                Felt::new(BigInteger256([
+                    0x711a79412e52307c,
+                    0x4caf326a855eefff,
+                    0x60211386c2bd8f13,
+                    0x06001afd54b3c640,
+                ])),
+                Felt::new(BigInteger256([
+                    0x451b4ec6dc1cdd4a,
+                    0xe50b79bb6f9a039a,
+                    0x7c5d7f80d7360e52,
+                    0x02c0d89fbc836128,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b79af946681dc76,
+                    0x535729261c705e1c,
+                    0xfcbbf698ab04cb11,
+                    0x02326d4f5dbe6044,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfecc266b79c0fcf1,
+                    0x395abe3d20240583,
+                    0xc6b2ab335334db54,
+                    0x001a81e3fa2b4450,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d4a9f0757da85a4,
+                    0xf3e6882af7689de4,
+                    0x61ddc9a2905e5549,
+                    0x02864f345ddf9590,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b236cdaf77c6299,
+                    0x5064c75f5f275dd1,
+                    0xfb332242cff7b36d,
+                    0x0498c71570e2bb8a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72b730b4fc869212,
+                    0x3780bd3dca34e772,
+                    0x5371509a850e8162,
+                    0x02485b65520e02ed,
+                ])),
+                Felt::new(BigInteger256([
+                    0x44a6dd5a9307dd49,
+                    0xbbfa01b55fa9f9fc,
+                    0x317f704f6ec030c9,
+                    0x009223674a63e04c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf944c8d98cab364e,
+                    0xaa71e1b535cc0fce,
+                    0xa3c187e779a263d8,
+                    0x03334ea3f1f07ca0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe407d0142cf80a69,
+                    0x0054a44dbb1d4bc3,
+                    0x4da7c7810e4663fc,
+                    0x01f84e095ab7f238,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45c6fb9ec3d01ef4,
+                    0x5a41715ca8dfdea8,
+                    0x1831c3edf3b17b5c,
+                    0x03e5c13d51da6c5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcbd2c3f0a9d3537d,
+                    0x515c1d979c0451ee,
+                    0x5e05859859db809f,
+                    0x05c30ddc0b24efd3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37d156fb585557a2,
+                    0xd0d12ef74f2aff58,
+                    0xbdf0a69be3328027,
+                    0x02a6150655c8b62a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x46518991229892a4,
+                    0x7b73f817dfdd4113,
+                    0x4a3aa44db33ca93f,
+                    0x07f33e933c4c0ca3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05c83d48bdf19f01,
+                    0x090074d517e2ce53,
+                    0x72bcfb7a661697ad,
+                    0x033d772787d28d92,
+                ])),
+                Felt::new(BigInteger256([
+                    0x93be17aa82b26eb6,
+                    0x9d326a371a4ddfc7,
+                    0x64364123feae974b,
+                    0x01a958bd18180a82,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe32782a35b7528b4,
+                    0xcbb885be06181141,
+                    0xc7fcddf740a398bd,
+                    0x02e814c763e953a4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1a0334d83e2e9ee4,
+                    0xd22a033c4a93b35f,
+                    0x723a28b1d4a88665,
+                    0x06a90b8106fd076b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd222e387b5b34a97,
+                    0xfb33494549d68009,
+                    0x69e537fa2e97247d,
+                    0x07ca486c1c7bbc32,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3be0da456d731bc5,
+                    0xe076f8de144b640d,
+                    0xc962db39b49d8ad1,
+                    0x027a991dd16c80a1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeccc617b9c28f684,
+                    0x8fda3d248d6d49aa,
+                    0x8a5a630b2f6f0148,
+                    0x0494672d5b5c968d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec2505f6a8786831,
+                    0x0404295ffe9ed383,
+                    0x7076b4718ccb7037,
+                    0x06cd6ebbc720e0f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x667ef247da788419,
+                    0x669151ea30c54df0,
+                    0x78e40ca223f18073,
+                    0x063dc3f2602173b9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3e9c1fbba3ecda07,
+                    0x06adaa376370f28f,
+                    0x85366510b4e655e8,
+                    0x0101fffb4ab992d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd68cd7be0c1e35c6,
+                    0x4acf385518b681f7,
+                    0xde1af733f91b2562,
+                    0x05cebde94956d64b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ffeff477ea8cf0d,
+                    0x93cc0a37ec32a353,
+                    0xe07e922fd080bbe4,
+                    0x06aacde5c4246575,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbbad9f6e9a406dc8,
+                    0x34256b828b2a2c50,
+                    0xae2147c1975f55e2,
+                    0x075d72c5625b7636,
+                ])),
+                Felt::new(BigInteger256([
+                    0x862069c1fe7f33ad,
+                    0xa3659efff4115bf3,
+                    0x445acac6bb864dcc,
+                    0x049e9a054e0d3f42,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed0c308e555c2295,
+                    0x32691c29a56b61e6,
+                    0xbeaafcce43683463,
+                    0x07eb9942496e90ba,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09e4c9c71e4bcd81,
+                    0xdc16aabe011a0b74,
+                    0x58b51e76df3e9a9a,
+                    0x05438dc3ba6a478d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb342f2be7b5bac9f,
+                    0x2a38d6a728869813,
+                    0xe2beae3f66d22177,
+                    0x07875825f9b283ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b571e51e095db9b,
+                    0xb15941f479c622cb,
+                    0xbbaea9d03546495f,
+                    0x003b6d24fa12a305,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0c1c94dcc33860f,
+                    0x15e0756f32d4c00f,
+                    0xf01b3421fbf6f466,
+                    0x0362b400568de132,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 10],
+            [Felt::one(); 10],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x523662d0c9ecebdd,
+                    0x0ba114a36af71dcd,
+                    0xd60521d2c9fd79fe,
+                    0x00b5299c5bfa8283,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a97347e8e2d0fc9,
+                    0x1d4829ed2d16d757,
+                    0x885d47e530425fb0,
+                    0x00e7db89f738bfc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb325f5275fecfe09,
+                    0x92a6571ec0f33124,
+                    0x4d454f593f8b32c8,
+                    0x065ed6f46a3b2844,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3bd57aef5e233d4a,
+                    0xbbeecddbcb3d2659,
+                    0xc3c7577ecd0f1ec5,
+                    0x04eaf6a1285381dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87d6adc54d4a010a,
+                    0xe0e6b77a5422a6cd,
+                    0xc9f8c7e27a361445,
+                    0x00eeb38c3758d561,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf6cf7ada97c54241,
+                    0x33a9b807588b0e36,
+                    0x6e8a48fae69325ae,
+                    0x05b17b0921e59efd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1c868e123b4a710,
+                    0x07bf5ab3d2e5b71f,
+                    0x11e7c76465fe3538,
+                    0x073aba1859820070,
+                ])),
+                Felt::new(BigInteger256([
+                    0x095f4445dc4eb80b,
+                    0xe08e4d4856c5d211,
+                    0xec4dee04bc15ae77,
+                    0x014f32c7c5e8f4af,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ef876e4bcbb2bf,
+                    0xad8e9585e4b47644,
+                    0xcd6a43607c8f5b20,
+                    0x05993a0b3ad4b68c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1459ed5c8bdf1439,
+                    0x88577ea4ebfb60df,
+                    0xab5e86f5cf5f07f7,
+                    0x0256fb4fbc3b0720,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdd351fdd1a4f4678,
+                    0x15e1e6a0b8c12657,
+                    0x5d5364cfe523399e,
+                    0x0020cb285c184678,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb118c30d8439de12,
+                    0xd26c653238b81f9e,
+                    0x058874fdcbbbf82a,
+                    0x0422b3d218a451d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ad6bc3e931bf010,
+                    0xbd909c9113d71c77,
+                    0x029c7029a955a906,
+                    0x0219c501962fab51,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b156faafe224708,
+                    0x179dad49a3eea571,
+                    0xfc5c40ea6a45a846,
+                    0x0538ad40b86d0cea,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3fe4f278e2b641d,
+                    0xebeb53254a39204a,
+                    0xb9ee686d556440dd,
+                    0x0122d16bfe6dd6f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x54e6c5cc264e1c76,
+                    0x9b9c63eb76f10cb3,
+                    0x032c7a97c8310d46,
+                    0x07e2aa9d0b7e20d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa8dea38c0b0285e1,
+                    0x8e814172dbc604da,
+                    0x2035e14bd2e8321d,
+                    0x0286c06a6d5e3714,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3720c5ecc9b71249,
+                    0xc3fff2219c571b19,
+                    0x4d63fbc8e6b50698,
+                    0x049736f4b27f41ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x238b9931b73174e9,
+                    0xe8dc939542169d09,
+                    0x64b6668b5a522819,
+                    0x02600c9ec7696f5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x33ee13cca62d5f36,
+                    0xa9500d246d6e0919,
+                    0x349690603f112da2,
+                    0x04463150fdd6ff5e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x096ab4b1465640b3,
+                    0x113d9914fcb6ab10,
+                    0xb27a99a9dcd6a24b,
+                    0x0169ef96a14de588,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9610beff45a0f753,
+                    0x02c3680a00ee80b1,
+                    0xf9620a57ef5f3a46,
+                    0x04694a0af518c8aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95eca5373f30551d,
+                    0x169f32e97363e68e,
+                    0xd3f0104823d857b2,
+                    0x028ab12cbb9c0013,
+                ])),
+                Felt::new(BigInteger256([
+                    0x860dd86461f75026,
+                    0x569fc8553a8274b3,
+                    0x7e2813ecf7e01078,
+                    0x00c8894534c1aa4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25152db726315c50,
+                    0x186073c45ce2e50b,
+                    0x80c80acb4c6306f0,
+                    0x05e0bf568e9ae8ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf900aee8a482b7b3,
+                    0x49704ffbe893171f,
+                    0x16febab63f33dee2,
+                    0x01608831a1dbceab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x301869123c7a4b5f,
+                    0x5e09e9d5ece82a67,
+                    0x80c170be3d747d59,
+                    0x026b923702a2b9da,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7e5cf6d59e6f3e06,
+                    0xc9e82270b63cbb58,
+                    0x7b738c0556f4826f,
+                    0x03fd684dc814389e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x34bbfbe96722ea22,
+                    0x6425f73ee85e5321,
+                    0x90e83db1f45d5462,
+                    0x0226c1d430b511cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x617877395a5a8cd9,
+                    0x1e8dccae04726cd7,
+                    0x9b81513c59e3266a,
+                    0x02a8b5e947f738f7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1f40e078ed92db9e,
+                    0x7aab50a65dcea7b0,
+                    0xd2c64777be0b7040,
+                    0x010bd25f0b398ec7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6bdad25808d39a99,
+                    0xa84349d32cd27f5b,
+                    0xac6089a3619b885e,
+                    0x05da9188370c0a14,
+                ])),
+                Felt::new(BigInteger256([
+                    0x873bc5c684296db7,
+                    0xaef1e6183efc963f,
+                    0x2c18e33c856e6c92,
+                    0x05b1c73c5a9ebd27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x682c936c6c7cb166,
+                    0x3e296d17616f0057,
+                    0x4ca781aac7ba247c,
+                    0x058426d88685d533,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf68ce60af7bb0608,
+                    0xd79f2ea5e9224e2f,
+                    0xea7022d478243d38,
+                    0x040596d8b8050877,
+                ])),
+                Felt::new(BigInteger256([
+                    0x820387f6fece878f,
+                    0x67eb2b4b2219e5ad,
+                    0x44c63550d5926d6e,
+                    0x00cca9aedcaae6a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x93230b2d65a7e3f8,
+                    0x9857b721e7a3e7a1,
+                    0x25b82c63cdaf003b,
+                    0x00e6ec4878f40d42,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fe7441c2c1ca1d,
+                    0xeb211432537de584,
+                    0xd053b18452740570,
+                    0x02fb8cf3d5db1d5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71c6b1b823debf4b,
+                    0xb1e268de49618067,
+                    0xe6e6c763c0343950,
+                    0x05ef218d87582668,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ecdc1b3bb484ebb,
+                    0x700cf4ce9212824e,
+                    0xcbed964d571af062,
+                    0x07e769d508b8742f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x91158a05ba07e698,
+                    0x737fd3d5543403b7,
+                    0x8bedeefe501fa232,
+                    0x03a8d0a5550ff04d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3d95ab65d4dbd89b,
+                    0x851f4f7b2ea62ebd,
+                    0x9962541ebfa6ad5d,
+                    0x0539605a77ed6f42,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2a6c3c2f0ef21609,
+                    0x263fe99842bd7ace,
+                    0x34e9b5318883178b,
+                    0x0418281122a427d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x98fcfc510c068015,
+                    0xf8bfd27e1accdc3e,
+                    0x7dad60559f0fa1f6,
+                    0x064123d99be93bc2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6949f767b5dec59,
+                    0xd1a2756c13ecc825,
+                    0x5d8695b123c34dad,
+                    0x05a61ff0816e0bfc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8141ebf7643cc7a6,
+                    0x618ee8a7b7ccef90,
+                    0x597a66cb867027d6,
+                    0x01b7dc0e65b33020,
+                ])),
+                Felt::new(BigInteger256([
+                    0x699b9e8d737a6ce5,
+                    0xc71ee265ba1ecf24,
+                    0x4561606ff1b4b05d,
+                    0x037e8f203a15750a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc139d062ab7e669e,
+                    0x85109a42c64df23e,
+                    0x702f5cc2c0b1806b,
+                    0x01fe272a56651358,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb78d476fde2a885e,
+                    0xc9fdba736fc33d5d,
+                    0x456171310a5182d1,
+                    0x071353c2f86709fd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc38584f558fa571f,
+                    0xba550ae3a122fe3a,
+                    0xf4b95faba9637a6e,
+                    0x03720d10ba1231fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x53344de11de6a7b6,
+                    0x8c7650609b358711,
+                    0xb8bf1cf1439cbd58,
+                    0x06d5dc0a32912037,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6f17a17477756e66,
+                    0x5b57a6eadf779598,
+                    0x7b81edaa5a43e053,
+                    0x01594ef1b37df58a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb683915d7cd50ee3,
+                    0x22cd42175eba04dc,
+                    0x636647d837ab79fb,
+                    0x0027487c92c3a309,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcb46bd1d100ed9b0,
+                    0x2c8836009f9aac1e,
+                    0x3f855bc22803dec7,
+                    0x03f20640847f8d05,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9c57d5a51ef7610,
+                    0xadf8421c1f1766e2,
+                    0x96e838ffe04a4f5a,
+                    0x033c0b8276c3ce3a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e39ebce0ad6b39f,
+                    0xf276c72be6a928a0,
+                    0x8627ffd2b6868fab,
+                    0x00a457f09ddc43f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7019f89596f072fb,
+                    0x3e340ee6a20bba8d,
+                    0x165ba7103b550bce,
+                    0x0191fd76e58d9a3b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0992901c509d6906,
+                    0xeb0e5300763a37a9,
+                    0x7d7185aaae958b73,
+                    0x041a83e6218f3e6d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6478e1783ac116cf,
+                    0x7bd56f1eb5e09bbb,
+                    0xe83e2890350d55e5,
+                    0x0658e0df1cad813c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbba0bf1e9dd9e743,
+                    0x2f46683b9acfb010,
+                    0xbf87473d58143b48,
+                    0x02ae6c944846d1c3,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 10],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe41,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe250,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8cc6c75d72dff016,
+                    0x351910bfac8963fb,
+                    0x472b9d2b502c527c,
+                    0x050ee64547eeed19,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaba83f9d2f4c6526,
+                    0xa17b2fb9b71fabc0,
+                    0x76f56662b1c6a1d7,
+                    0x054c34a083b64f83,
+                ])),
+                Felt::new(BigInteger256([
+                    0x844d0b2294833cd0,
+                    0xb2366f9915e6f7d1,
+                    0x70dded44947e54c8,
+                    0x0571d85d7c9ef2fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6469ece621893737,
+                    0x12191e8ed96079a4,
+                    0xb39f312ca3ff1db1,
+                    0x0372c7f063a6a6d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7195e95c182246d2,
+                    0x3aa3abab425ccef2,
+                    0x410fb443d4731253,
+                    0x066d9cbdeb8bc125,
+                ])),
+                Felt::new(BigInteger256([
+                    0x236b5f2909bd137a,
+                    0x14a7fb46426db68e,
+                    0x3ca20848561dd157,
+                    0x0728ef6e6a514fc5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x11848b99dd53f3c0,
+                    0x1c87045d8015376d,
+                    0xcf83cf283f05c250,
+                    0x00d8ed607c8f3e2b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x75b5de3c7b56c22b,
+                    0x52a82979d7c4f89c,
+                    0xa98c68f0440d115a,
+                    0x047b8cbea055635e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf8ad59197af7a98a,
+                    0xb94167868fd52c10,
+                    0x03eb2f1347104ed0,
+                    0x06f391f928720a77,
+                ])),
+                Felt::new(BigInteger256([
+                    0x363571153af04185,
+                    0x3d03c9e45e7cf8f8,
+                    0xd3dd8abc7feafaa6,
+                    0x02f19e34659c7aed,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6f1f6218173f0567,
+                    0x1b0f76672fd6d297,
+                    0xfdca381700ec124b,
+                    0x05219a1e1125006f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f32aa3ccdf69403,
+                    0xf3ecd758fd056d4c,
+                    0xb79441ab8323bd91,
+                    0x0315be0f9e4bdfdf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0c3768183a1ed3a6,
+                    0x0c95d7c8950e5a42,
+                    0xbdbbc690a300d335,
+                    0x07eb9642357cefdb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6147d7d8cbaea721,
+                    0x42b357ef5e7f4593,
+                    0xf174dc8eecafc3cb,
+                    0x01c988761ca356da,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb743d77e7843682d,
+                    0xe5693e1130285291,
+                    0x201a3171566e5075,
+                    0x002e2e5497530053,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc2dc613d528a0203,
+                    0xbc65167f46ce5af4,
+                    0x7df5b72eadcda9ef,
+                    0x06c43790a6ec684b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x966ae37143b5b40a,
+                    0xdfc484104d5a1e80,
+                    0xfd9d644f3d87e748,
+                    0x07f6440ec43d45c1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0d4c6a32ece69c6b,
+                    0x73068cef91236725,
+                    0xa69dd6cebf206702,
+                    0x075d523819aff716,
+                ])),
+                Felt::new(BigInteger256([
+                    0x11add9e8a8569efa,
+                    0x8cbedfd9337ea1ef,
+                    0xc5849eb7de69b426,
+                    0x01fa381a007bf9cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x34fc82e4aa1e88de,
+                    0x68200bce533d047c,
+                    0xa558bb4cf3d6d7c3,
+                    0x070e38f5a332d7c4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x32e2a97b905c4580,
+                    0x2c3ffe0612492b73,
+                    0xa4355a86952e4839,
+                    0x05f92eed6547e354,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81912bb6a32bf19d,
+                    0xd4f7c06d86f6b0d7,
+                    0xc74f81e57c950a98,
+                    0x07e24252afeae645,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f38948289399198,
+                    0x0927c311bc123a1b,
+                    0x7d44def621182985,
+                    0x00aba906eae1e5df,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0d455a40ada0f567,
+                    0x1ee2a60fd236de7e,
+                    0x1f98667e4b7d9ddc,
+                    0x0141b56b25b5666f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe6a9ee392400682a,
+                    0x3d83fa474e80f3eb,
+                    0xe5f1683e5e19092f,
+                    0x029c001b056b7be1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf8e9b14b04a11ab,
+                    0x3dccb4a6f8e90620,
+                    0x8feca05a1510f104,
+                    0x0662c09ea5fe14ea,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabff2168d5102657,
+                    0x85aa50d6eec78583,
+                    0xbea1dbfc89113858,
+                    0x07ba47b7654bd112,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb968f651b91b01aa,
+                    0xc8b5076be95a57af,
+                    0xddad3e24b4749907,
+                    0x069262b3512fb906,
+                ])),
+                Felt::new(BigInteger256([
+                    0xebe2283e31b7b167,
+                    0x9e7a382916889034,
+                    0xf71bc2bc73192d03,
+                    0x06035f1c05957075,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e7a40401c9b25e5,
+                    0x2e8f7d85afe6e04a,
+                    0x57425c7a146af527,
+                    0x05aae83127639631,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9241d00d0b7ca314,
+                    0x5e85ea5d5afe7217,
+                    0xca402fa227900264,
+                    0x0228473a04524eee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa2144cec553d7eb2,
+                    0xd586c50909ae95d1,
+                    0x860fe288d502888b,
+                    0x016a448c3d703236,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b58a8efba44a4a3,
+                    0xa7f692dab3299cb0,
+                    0xe10c9b7495ccb394,
+                    0x00bad3c4495618d1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x10124122e50dd937,
+                    0x9191337652363243,
+                    0x3d6911a29b96f2ce,
+                    0x00905f1d01816391,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f2c35c42edddf42,
+                    0x3dab169bb085cea5,
+                    0xf2011c7e575eaf4d,
+                    0x00fcfcfc737ad381,
+                ])),
+                Felt::new(BigInteger256([
+                    0x85be43397b2fcca6,
+                    0x60ebb1a10a11d3f9,
+                    0x325597dce9079650,
+                    0x014cd5a3570fef69,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0debc2d40ffb07a8,
+                    0x868d9c5261b209b5,
+                    0xeab4ef0672adc6cc,
+                    0x0056302646cc7ce9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b237d4f3ffb2720,
+                    0x52b5211181c6cddb,
+                    0x29aa26c86b068e21,
+                    0x02c1fa543729161c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3315b4d2a96f6717,
+                    0xc6efa2a027f736f8,
+                    0x45acfbcda08f92da,
+                    0x0502b3f04c64b7b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7e417f4ce4a050a8,
+                    0xb9708a86041a0b02,
+                    0x72b883534ef5152b,
+                    0x01e7d432202b29f4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf2caf88d54a99f56,
+                    0x668a752e19e5fc95,
+                    0x8e63d07dd85ea272,
+                    0x026dd84c2bb50168,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa2b70c7e9780a137,
+                    0x1da7a968b6817173,
+                    0x79142c0d45040c81,
+                    0x04f986f51d552b4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f6997f79d7d3088,
+                    0xfb82f917d35fa80e,
+                    0xf9ce372d919c8e28,
+                    0x040dd015eeba7485,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd46145b53a5f0c7,
+                    0xf89e915033a1d086,
+                    0xed179d745c5c0169,
+                    0x003fff79a9719c49,
+                ])),
+                Felt::new(BigInteger256([
+                    0x970d09ff2be23546,
+                    0x493efa8a85099086,
+                    0xfda5377df0317ff8,
+                    0x00a16529d4651391,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe5dcf4ea1291af51,
+                    0x7b4762d7eb432039,
+                    0x17fe916e17196a29,
+                    0x0116321e5e785e86,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd8696f5366ef3365,
+                    0xe7e383fc9b47f444,
+                    0x548d6a54997583b7,
+                    0x03bfddfa363abca9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b681c6e8b3ceb15,
+                    0x5ece0c33004e0215,
+                    0x8cfc56ccd14fb854,
+                    0x079df5537be200f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c43f34f1c74a9bb,
+                    0x7777a808cf545708,
+                    0x39fc90c73b4f4325,
+                    0x02fed5f8019d4018,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde5bb2370fc03d71,
+                    0x837fba15a991820d,
+                    0xcc8e80a23270c8e7,
+                    0x00b8710d26ef009d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf3cd0aa5e5c3f7d0,
+                    0x682fe020b0bade29,
+                    0x4f126e2c4666137f,
+                    0x00995700022b39c3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e8ae4f801b62920,
+                    0x79ee2557a16addb4,
+                    0x94d4bd7edd33e6c9,
+                    0x0474246caba5192d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe76225544f981194,
+                    0x53cdc28525c6ede3,
+                    0xb42a66b2d2549dd5,
+                    0x07e36c25c748e6fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b9980c009b14ae5,
+                    0x9b444f5ee7d316e7,
+                    0xdfb20978528d4960,
+                    0x01d94c173ec0c0fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6cb0a6a019d4e4f0,
+                    0xb6539d9df1a11eca,
+                    0x8d61091bd973ef7d,
+                    0x02751596a55b1c0d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbcbbc49b456a3f91,
+                    0x162e340d267450cd,
+                    0xe96cc27e7a04546f,
+                    0x06a68dfe938df6fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a57391482f942fe,
+                    0xf5966202c1692adb,
+                    0x2cafc68eeea30ce8,
+                    0x04e9fd970a4647c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b0c16e4e7f3f1f5,
+                    0x1298077adbfdc630,
+                    0x06e3a7dc06f528ae,
+                    0x05c0de9f7c8bbfb0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x96cf3c77d5bd09ef,
+                    0xf3bdd3b6cde7f1a6,
+                    0x07bf1dbc8432c9a9,
+                    0x002f30ddac6c63db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3712d63293e54131,
+                    0xcd8b94b8c8f4696a,
+                    0x73753e568a36bdd4,
+                    0x0551837bc42fb6da,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
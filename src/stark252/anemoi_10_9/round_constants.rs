@@ -0,0 +1,653 @@
+//! Additive round constants implementation for Anemoi
+
+use super::BigInteger256;
+use super::Felt;
+use super::{NUM_COLUMNS, NUM_HASH_ROUNDS};
+
+/// Additive round constants C for Anemoi.
+pub(crate) const C: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xfffffffffffffea1,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x07ffffffffffe8b0,
+        ])),
+        Felt::new(BigInteger256([
+            0xd9a51415d93746c2,
+            0x65a965c6c42d6733,
+            0xea613593a1f0cfd2,
+            0x049e4416a959b21c,
+        ])),
+        Felt::new(BigInteger256([
+            0x0f5d906c84c39c00,
+            0x000c1074d46ed79a,
+            0x68e2af207c4bd1be,
+            0x021a8342841914dc,
+        ])),
+        Felt::new(BigInteger256([
+            0x680d9c7e69f8b197,
+            0x6d65ff721c0628b0,
+            0xba076138fdc77c8d,
+            0x04eab459262fee47,
+        ])),
+        Felt::new(BigInteger256([
+            0xae0cce4315587fa8,
+            0x802f3f149dcea812,
+            0xea4f143ad9199ddb,
+            0x012e82788fce3bad,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xfbfeb6480dfe122e,
+            0xd42973e6fd7a78ac,
+            0xcf8569fe1a40aca9,
+            0x079817ad4f627bc8,
+        ])),
+        Felt::new(BigInteger256([
+            0xc3609de584c4e331,
+            0xfb3d2640675a8c21,
+            0x7376547cd59c599f,
+            0x060a8fda78999521,
+        ])),
+        Felt::new(BigInteger256([
+            0xb043ba88c1d023f7,
+            0x433a5949865e91a2,
+            0xdbfffea10a5fc425,
+            0x03443f40b1502b79,
+        ])),
+        Felt::new(BigInteger256([
+            0x25ac215d6da34673,
+            0xf45bf50b8a1b0c48,
+            0x27c390bf6c8d583f,
+            0x060ebbf13d9b7933,
+        ])),
+        Felt::new(BigInteger256([
+            0x0cc06c740beeda87,
+            0xf53b3db2d6a02f52,
+            0x0a7947bf0dc5f4a3,
+            0x01f92ead069aef1a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x364bfb4b89130222,
+            0xdf2f91795b8970c8,
+            0x6574ec5139c3b88f,
+            0x00fb393cbcadd3af,
+        ])),
+        Felt::new(BigInteger256([
+            0x73acc413f534714e,
+            0xfddd5a77f657abfb,
+            0xf05b9318c0eae61b,
+            0x05637e8f6bcf83ca,
+        ])),
+        Felt::new(BigInteger256([
+            0x3c94513c858777b2,
+            0x76242a732ab6de07,
+            0xd30b025e362344b5,
+            0x015b57de849be3f2,
+        ])),
+        Felt::new(BigInteger256([
+            0xe8278983921e76c5,
+            0x00f79235f7d1c151,
+            0xd4fd08fd3d63e190,
+            0x01b57c1101e95149,
+        ])),
+        Felt::new(BigInteger256([
+            0x446e0fb9f4029fd4,
+            0x5de3402e7fcf941e,
+            0x26deab244bffcddb,
+            0x03909f7a29f5799d,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xf3aac841154555a8,
+            0x55edd7c71a9dcf10,
+            0x086db79108b1cd4c,
+            0x0111c3fd45ee3085,
+        ])),
+        Felt::new(BigInteger256([
+            0x7719a15fbed79504,
+            0xd8e33794a80c0f5e,
+            0x20f3b5c9ec86b73e,
+            0x01def8997bb0a5ef,
+        ])),
+        Felt::new(BigInteger256([
+            0xa8a41906e39b85ee,
+            0xf534067bcd976681,
+            0x3e1f741c3aa9487d,
+            0x044edb0609d31920,
+        ])),
+        Felt::new(BigInteger256([
+            0xb1851db6be770fdb,
+            0x0edfb5c1d9e45e32,
+            0xc84d6d380fde6f76,
+            0x002dd13e726f2374,
+        ])),
+        Felt::new(BigInteger256([
+            0x3654631706feec52,
+            0x41317afb4c50c97c,
+            0x9f5e0ccb471a4dfb,
+            0x0492a2e5860e4cc7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x33d622593364471e,
+            0xe60fc56bab5345a0,
+            0x928814b70fccea55,
+            0x0727b9c7ceb871b2,
+        ])),
+        Felt::new(BigInteger256([
+            0xcb72973907af855d,
+            0x94cd147bf36b7b84,
+            0x39a6113657564859,
+            0x06bcbe350497e60e,
+        ])),
+        Felt::new(BigInteger256([
+            0x1afca6d8acc5bb1b,
+            0x80ea33c88ad9ab49,
+            0x2ea99a828d82622e,
+            0x06b823e91a38a849,
+        ])),
+        Felt::new(BigInteger256([
+            0xfb801602126c78db,
+            0x03b549e599b6ffb8,
+            0xbec493623c6e401b,
+            0x01885e39384d086e,
+        ])),
+        Felt::new(BigInteger256([
+            0xf19692d5fab7978d,
+            0xc4653611bfd89d6d,
+            0x01d369de9c066827,
+            0x01e674e73119833c,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xab0c42ca099b5b2a,
+            0x77be7eeb75f4f283,
+            0xd5b0e93d98ea3c2b,
+            0x00d6b8b36119d367,
+        ])),
+        Felt::new(BigInteger256([
+            0xfccc5efb96271243,
+            0xf02fd3be55918d32,
+            0xdda5b8de28838dac,
+            0x010f1bf6c5f2d7c4,
+        ])),
+        Felt::new(BigInteger256([
+            0x627ce3efc0ddab17,
+            0x28053f973c695157,
+            0x8bf89f900a437dda,
+            0x07e13b9b23024a06,
+        ])),
+        Felt::new(BigInteger256([
+            0x6bf7350a5146bef7,
+            0xc7d6ecae2d03bb37,
+            0xb0319ff68f691d50,
+            0x0668f9e06ad854ff,
+        ])),
+        Felt::new(BigInteger256([
+            0x683c6e5da2003652,
+            0x1ce3f40634f2ee45,
+            0x55e2320fb79b52d3,
+            0x04e2b31ec686c949,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xbcfd89316e851dcb,
+            0x60b97e066f67d02d,
+            0x75e9eb11f7561fc5,
+            0x07726e4115659650,
+        ])),
+        Felt::new(BigInteger256([
+            0x4f3158beba0e6f7d,
+            0x556a6fa3cebeac83,
+            0x665954eb26971e3d,
+            0x016720746984f19b,
+        ])),
+        Felt::new(BigInteger256([
+            0x7dfb862651c18fa3,
+            0x9a90f202f768c6b7,
+            0x9fff3e8747111ce3,
+            0x02684df038667cdd,
+        ])),
+        Felt::new(BigInteger256([
+            0x3fc9daec115e9c06,
+            0xd74b24bef7041fdc,
+            0xe8ca28ba7cd9e1af,
+            0x02769c6169a72748,
+        ])),
+        Felt::new(BigInteger256([
+            0xfd584a6ee5f850e3,
+            0xf433f901c2eb8a34,
+            0xdd3a81608bc55e3f,
+            0x0377a3c3f0debcd3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc3f9d606e9cabda2,
+            0x3d56ef7d63e44c63,
+            0x97408f3e741f5de4,
+            0x05eb054c1509c394,
+        ])),
+        Felt::new(BigInteger256([
+            0x1cec2a825d7ac055,
+            0x95814174d5f86a98,
+            0xdc2ce5f0d11f68da,
+            0x02d39c95cb79c241,
+        ])),
+        Felt::new(BigInteger256([
+            0x8e7f2e446b5300b1,
+            0xc55d546dc3c2da88,
+            0xc38238c8a0664c2d,
+            0x031e84d74aff8aaf,
+        ])),
+        Felt::new(BigInteger256([
+            0x70021e716f0be0eb,
+            0x4a256295afae8350,
+            0xe022c8bfd8c593d7,
+            0x03cbdf91b769b8cd,
+        ])),
+        Felt::new(BigInteger256([
+            0x7b2549a3ae855373,
+            0xacec8f00a56d5165,
+            0x288cbbe1703de378,
+            0x01b6b8bb2dec458b,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xb9cc59ec944fed67,
+            0x7cafee18764bfb5c,
+            0x0f25696c57a528f6,
+            0x0048de517bbab7d5,
+        ])),
+        Felt::new(BigInteger256([
+            0x948e7a7332f4a89e,
+            0x370b6c8e1b09e8c0,
+            0xe93874fba35859a1,
+            0x02f120c19fe6ed71,
+        ])),
+        Felt::new(BigInteger256([
+            0x0207d3d4a9064d0c,
+            0xeef9b6dd915d9c6c,
+            0x23c2874cd67a3502,
+            0x07e7d55497d8054e,
+        ])),
+        Felt::new(BigInteger256([
+            0xb1433cf99bb618f9,
+            0x2a34a6b1cc9487ed,
+            0xc009e7d16f1929ce,
+            0x071618066ffc97bf,
+        ])),
+        Felt::new(BigInteger256([
+            0xf86ab5be5ff96cf2,
+            0x68eb4427d865b33a,
+            0xb1f82f3dc5b46a9f,
+            0x076aaff68cee34a7,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x87cb687b0eb143c5,
+            0x68c765b73a564086,
+            0x0f76c3516fe90cfb,
+            0x06e1849f7a0dd5ef,
+        ])),
+        Felt::new(BigInteger256([
+            0x991eb23ce8fbfd70,
+            0x019853cae668c06f,
+            0x316a2256c95279ed,
+            0x00eb3241411c0ef4,
+        ])),
+        Felt::new(BigInteger256([
+            0x9d033a7adb83ee8d,
+            0xde0c53cc89a3c981,
+            0x36f3c3216ef537c7,
+            0x069466c44462678d,
+        ])),
+        Felt::new(BigInteger256([
+            0xac4fd46fd81a6cf8,
+            0x7617322192469fb3,
+            0x959e603cac0e38df,
+            0x04f4b30267bbf566,
+        ])),
+        Felt::new(BigInteger256([
+            0x5b8f37400e04eeb6,
+            0xac3f25ddd73e6cd8,
+            0x80a1eab8f804ac6a,
+            0x059ed41cbad11a32,
+        ])),
+    ],
+];
+
+/// Additive round constants D for Anemoi.
+pub(crate) const D: [[Felt; NUM_COLUMNS]; NUM_HASH_ROUNDS] = [
+    [
+        Felt::new(BigInteger256([
+            0xaaaaaaaaaaaaa941,
+            0xaaaaaaaaaaaaaaaa,
+            0xaaaaaaaaaaaaaaaa,
+            0x07ffffffffffe7fb,
+        ])),
+        Felt::new(BigInteger256([
+            0xf0de40729d3c193e,
+            0x4f7de3e7600c13ac,
+            0x2ca79dbc31f24d5c,
+            0x067c7dad60627393,
+        ])),
+        Felt::new(BigInteger256([
+            0x852832a5c642730b,
+            0x363cc55b51ed828b,
+            0x2e0c5ed9d541e7f7,
+            0x0121a1baa0d4dfc9,
+        ])),
+        Felt::new(BigInteger256([
+            0x99c58cac92633781,
+            0xb8254265f7e553e9,
+            0x8909d19f626ee128,
+            0x008a35f4372dc8cd,
+        ])),
+        Felt::new(BigInteger256([
+            0x87cf2ed61dd12cc5,
+            0xba61070099d64345,
+            0x2908f0d5fd971448,
+            0x02f961e68d990e65,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x3c4d27430f19d19c,
+            0x6bcd8aec51fe1fb7,
+            0x16bb7d2483897660,
+            0x0380b6acdbdc9869,
+        ])),
+        Felt::new(BigInteger256([
+            0x703d90929f3aca7b,
+            0xd20b10bbad1234fa,
+            0x52482521243bf635,
+            0x03d16870bc1c73ed,
+        ])),
+        Felt::new(BigInteger256([
+            0xbbb2231259c00fd1,
+            0x66647a8aadb638f3,
+            0x3db516d621f3f96a,
+            0x0633fcb85a8613cd,
+        ])),
+        Felt::new(BigInteger256([
+            0xed07d7dbec7ee12c,
+            0x2c14a45a0fd333e0,
+            0x935169a18fd2dbe7,
+            0x0596dc8bdb13711f,
+        ])),
+        Felt::new(BigInteger256([
+            0x7c2693576ad89c73,
+            0x1c6671f97c80c6e4,
+            0xe5be8cd5f0e18a1d,
+            0x07acad1a90dfdf37,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc9c08d3753292eb6,
+            0x159233435e07ec45,
+            0xf513437ffe6051ee,
+            0x0005c4ac3d670eff,
+        ])),
+        Felt::new(BigInteger256([
+            0x73afd7b1d8a4c5be,
+            0x7369cfb7ea0a2947,
+            0x1795a7c56ade5259,
+            0x064c4395a3918147,
+        ])),
+        Felt::new(BigInteger256([
+            0x9b28dab6e671d0b2,
+            0x380cd679000959cb,
+            0x7d285e9ba90b49a2,
+            0x076d01c62210eaf6,
+        ])),
+        Felt::new(BigInteger256([
+            0x02a960f2d9f47ea4,
+            0xd76ecc492b84bd5e,
+            0x88f325e7bbfd34de,
+            0x045f891b93a067e6,
+        ])),
+        Felt::new(BigInteger256([
+            0x06fa578e1be6cee5,
+            0x23ccff39d3ab0024,
+            0x4a8c34438a6f32fc,
+            0x04660a57a879885a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc5cac29164deab9e,
+            0x55cdbe39a62a4d52,
+            0x4f6158bc2e8b58ee,
+            0x0026f6ab661eae5b,
+        ])),
+        Felt::new(BigInteger256([
+            0xb5c81d6227cb12d6,
+            0x17ecf17d24cc8f6e,
+            0xff831472f7b715c0,
+            0x02d264de52e9e5f0,
+        ])),
+        Felt::new(BigInteger256([
+            0x45e40ae5ca09084f,
+            0x8099f72a2bf7e50a,
+            0x9f921a560ece3fae,
+            0x026b2c2c46bf6298,
+        ])),
+        Felt::new(BigInteger256([
+            0x0ab25d8a8bd0411c,
+            0xaed4347d96a55d03,
+            0x3398d41eefb4b508,
+            0x02e28587a39d7c97,
+        ])),
+        Felt::new(BigInteger256([
+            0x378c134fb46644c5,
+            0xd0987eaf293a3846,
+            0x7a60dfe6e6c6a55f,
+            0x0572b501a4099e0a,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x590610b3fd60631a,
+            0xa580ad0caa920e8b,
+            0xa3a79a6e05c0c0c4,
+            0x058d479ede112a38,
+        ])),
+        Felt::new(BigInteger256([
+            0x5d310745eb05c935,
+            0x9367cf92e3de463e,
+            0xe261546b32a0f1a7,
+            0x070085a2caf960bf,
+        ])),
+        Felt::new(BigInteger256([
+            0x0b4c8cc20d960382,
+            0xcbe125a55cec747b,
+            0x5a48254831c1a42b,
+            0x0424d038464d2c71,
+        ])),
+        Felt::new(BigInteger256([
+            0xa7bd49e05a287022,
+            0x633ac9cfca2a4932,
+            0xf43bded4ec5ed07a,
+            0x038d6dab58a39c40,
+        ])),
+        Felt::new(BigInteger256([
+            0x45de37192281b606,
+            0x135d3af4107456e1,
+            0xa70221860bcd0a59,
+            0x0216e22c3e3d0f2e,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xd459a07d31af8a42,
+            0x0d7de03a694977a2,
+            0xc9ae8927c829c032,
+            0x062654d51c46c186,
+        ])),
+        Felt::new(BigInteger256([
+            0x92a83e60d7956936,
+            0xc51908833a1a1420,
+            0x693f16463d19e492,
+            0x003cf1af382887fe,
+        ])),
+        Felt::new(BigInteger256([
+            0x56ea39317fc60699,
+            0x494aab220291d6bd,
+            0x9a754488e7ce6d6f,
+            0x0437f634faeb03b6,
+        ])),
+        Felt::new(BigInteger256([
+            0x1c51d840f71ac959,
+            0xfdaae646518cc0e5,
+            0xc887059c78a55b47,
+            0x0758179d37031e59,
+        ])),
+        Felt::new(BigInteger256([
+            0xc0a181f927e267e6,
+            0x422a729679a463ec,
+            0xddef03ea60ada29c,
+            0x03fd2eae7f7e8ac3,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0x32a880ec64d7c0ee,
+            0x3967854a152481fc,
+            0x53642fc1610d38bb,
+            0x067a4b90cafbeefe,
+        ])),
+        Felt::new(BigInteger256([
+            0x316ad22bc9bb3a7c,
+            0x6d424a5d65af6020,
+            0xdb6f571875a50a12,
+            0x024d375ad6240c74,
+        ])),
+        Felt::new(BigInteger256([
+            0xbec6756fdee85f31,
+            0xfec503826ff978cc,
+            0x97f888455f13a167,
+            0x007749b80ab8a12d,
+        ])),
+        Felt::new(BigInteger256([
+            0x3c82182a85711a74,
+            0x500dc44bcdf55239,
+            0xea9c3325a08db496,
+            0x051dfb4c303b5b42,
+        ])),
+        Felt::new(BigInteger256([
+            0xa21af8123a18f683,
+            0x5c691d86ba052c8b,
+            0x4ec3f8006f4f42f8,
+            0x044a6081a43fe8ee,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xef1a2525916fa3db,
+            0x9b730070a8b98f25,
+            0x4fb054773050f063,
+            0x0675eecf85b56701,
+        ])),
+        Felt::new(BigInteger256([
+            0xb49afb531e79ce6a,
+            0x32c725de0c01af28,
+            0x2c3868a772a7ce39,
+            0x053cbfaff32e27da,
+        ])),
+        Felt::new(BigInteger256([
+            0x84bf74f1a9cc1355,
+            0xaeff6f9cdb6c1d91,
+            0x967103100ae34a3b,
+            0x02b08cd2d866f9be,
+        ])),
+        Felt::new(BigInteger256([
+            0x222fb3139470a26f,
+            0x48560bd225b846a1,
+            0xbcea53b44ef3e047,
+            0x07f64ab039133786,
+        ])),
+        Felt::new(BigInteger256([
+            0xd55d4eaab3f83c29,
+            0x9a8fbd353b9f84af,
+            0x750bb30aa64241ba,
+            0x040c81ac9c62bc64,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xa4b60ee803012d3c,
+            0x83374fd87388c597,
+            0x024b7823a859ca4b,
+            0x0420a72911f509a2,
+        ])),
+        Felt::new(BigInteger256([
+            0xec06b120bb00104e,
+            0x7cbca1c4097ab4c9,
+            0x73fa4130d963cdd5,
+            0x00a7232fed2a0159,
+        ])),
+        Felt::new(BigInteger256([
+            0xb811805eae8bb94b,
+            0x810722d9616e66ed,
+            0x31679b12d57a41e6,
+            0x02c6bca44ace22ac,
+        ])),
+        Felt::new(BigInteger256([
+            0x233a377888273418,
+            0xd0d0a0bafb05d2b7,
+            0xd787bc4479ca8513,
+            0x068d62791734c4c7,
+        ])),
+        Felt::new(BigInteger256([
+            0x126c20a22c78af43,
+            0xfef9c32926ff6dfe,
+            0x392d6fe5903bd7b6,
+            0x050d583c20f359d0,
+        ])),
+    ],
+    [
+        Felt::new(BigInteger256([
+            0xc31bba3eb910bbd1,
+            0xdd91b76111b7d50b,
+            0x52ab2d2848ba1fac,
+            0x011b71f397c53843,
+        ])),
+        Felt::new(BigInteger256([
+            0x40fd85b2acb59d59,
+            0xb58c78eaaefe56c3,
+            0x0c3a49ab877a5f7d,
+            0x0503592c15dc3385,
+        ])),
+        Felt::new(BigInteger256([
+            0xa37383cd1cb79305,
+            0xde5cafb233d95e4d,
+            0x94a73206f611b607,
+            0x07d572907ed59594,
+        ])),
+        Felt::new(BigInteger256([
+            0x6ead6bb70039c04f,
+            0x8af61c149adcb4c7,
+            0xfd2a8fcf3edc0581,
+            0x02ce21f196713306,
+        ])),
+        Felt::new(BigInteger256([
+            0xc5f73eec1632693f,
+            0xb09094c8fffcf1e5,
+            0x57e586804aa88ade,
+            0x01a3a0ded6534ff3,
+        ])),
+    ],
+];
@@ -0,0 +1,2764 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 32];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 32];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 32];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U11>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger256;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger256([
+                0x4ff7af2776f6aebd,
+                0xc7157cdb2d5d8c12,
+                0xc43cd8efe1a8ed84,
+                0x03c8ee19d96bda41,
+            ]))],
+            vec![
+                Felt::new(BigInteger256([
+                    0x5d7ea45e46f44a2f,
+                    0x97e04f3941f7ad8d,
+                    0x198eda5a3147ff2c,
+                    0x040b0f89a2a43361,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1cbafb102b772ea9,
+                    0x8b912f0aea75a7b7,
+                    0x2c45a25258fd5ef4,
+                    0x04ac20082b89156f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xcb103b66df455127,
+                    0xc2616ce685dac421,
+                    0x72f60a68480108b4,
+                    0x019b95afaaa96336,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf5f326ce7fac3e10,
+                    0x5836b355feae47bb,
+                    0x5330cd4dd1e14940,
+                    0x04818c73066d54d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd599db096875a91a,
+                    0x6bd0e9f1cc2498cc,
+                    0xbcbe2d7fc47c3dbc,
+                    0x02a3477056997bc3,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x75cdf98f85745d99,
+                    0x8186613c6eef98b5,
+                    0xcb5cb3a2fff57a47,
+                    0x015c9e606b7c18d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99cca40ff58bed82,
+                    0x9e1f07912eedb606,
+                    0x5dd15b8b7e311705,
+                    0x058caecffa0c2c81,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94e722744ba4068e,
+                    0xbe9e659aeb7c2d9f,
+                    0x1a0a6222fbf619a8,
+                    0x073385d09e6e5547,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8badde7cdb528e2d,
+                    0x58de1fafd9304f9f,
+                    0x34d0a1c4823e23ad,
+                    0x0208193dd38deba0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x5154be507844a98d,
+                    0x0c007a61f5607ce0,
+                    0x471c09efdbf0743f,
+                    0x0618ae0300f04381,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c74419ac59fecf6,
+                    0xff61ab6db73c94aa,
+                    0x8649623c873ac6a2,
+                    0x008d6e9639dff4fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x98f0501f63555cdc,
+                    0x5058db11269cac8a,
+                    0x89d8ffc78c0f8dfa,
+                    0x052c647d6c5dfa1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6d1649e217afbd0,
+                    0xf742fcaaf8b6cba9,
+                    0xad536f1dad2a8cd0,
+                    0x0044e4ee294a9f45,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbef54629cf4accdf,
+                    0xf7c47e4c5dcda9a1,
+                    0x69a52197e5c94361,
+                    0x021131a6983f9674,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x45be25c367a14cc8,
+                    0xc95d1d224e81bbb5,
+                    0x88794064df1455fe,
+                    0x0314192c9143f244,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7265bf8c029c4f8,
+                    0x13fa6e1e9431605b,
+                    0xf25d11153db3eb6f,
+                    0x0026afd091ef16d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf102e778cc6b30c1,
+                    0xd7d9841974902579,
+                    0x02153a42269adf73,
+                    0x0296d84bef265adb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x162da17d405ac3fa,
+                    0xc1802e224375a401,
+                    0x0239682ed0b198b4,
+                    0x07283b9dd04f58d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5bafb4ae0f4af71,
+                    0x0c6e45c176f837ba,
+                    0x5c3c08bd2455f58a,
+                    0x00371ceda4afba13,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3899a6a53eac7312,
+                    0x28427e930e402fff,
+                    0x3a1cf3fa3e2ba27e,
+                    0x0604c8bc746ea600,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0xaa9032e3fdf9693e,
+                0x8466f7f976af5690,
+                0xb4714e167c3f5d77,
+                0x03d3a947e3bc767f,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xee16df86920e27a9,
+                0xbc1b10655de19bd1,
+                0x7ad57d9242e5585d,
+                0x019e3634b0fe8c92,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x44513bb4b8bdc113,
+                0xf37a694d79bc75a6,
+                0x59cbe5982297dcef,
+                0x0541f02eae22e410,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xecc9c710a6c05a2b,
+                0xefdd7c4b834d75c7,
+                0x251387eea13ba7bd,
+                0x0062f200a89a4c4a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x72b143b78d5c32f1,
+                0xc2ab314b1c1e7a14,
+                0xab8cb7b0075227da,
+                0x022d76a00a35fca8,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x2047cba7ca006369,
+                0x8985bb43f5736326,
+                0xbbe2ea78c82e8f70,
+                0x040f8ff93d655a90,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xb30822f5caf86c86,
+                0x6888b9861dc5c214,
+                0x117f7f6f1d55395a,
+                0x028b88a3abb4f3ed,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x98779c4bf1296e45,
+                0xa9e92ddb3af64e98,
+                0x028cb75d94f7cd0a,
+                0x00cdd0de7f11be7e,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x933137aa5b31e38f,
+                0x2ae4e1a2a44095ca,
+                0x7a55890f4a9d50aa,
+                0x021378c4ba38e29c,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xb34698f1601d02e1,
+                0x6a6a30d082db29fa,
+                0x12d6df69da2eb17b,
+                0x07abf493dc178267,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0xaa9032e3fdf9693e,
+                0x8466f7f976af5690,
+                0xb4714e167c3f5d77,
+                0x03d3a947e3bc767f,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xee16df86920e27a9,
+                0xbc1b10655de19bd1,
+                0x7ad57d9242e5585d,
+                0x019e3634b0fe8c92,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x44513bb4b8bdc113,
+                0xf37a694d79bc75a6,
+                0x59cbe5982297dcef,
+                0x0541f02eae22e410,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xecc9c710a6c05a2b,
+                0xefdd7c4b834d75c7,
+                0x251387eea13ba7bd,
+                0x0062f200a89a4c4a,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 372];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+            bytes[248..279].copy_from_slice(&to_bytes!(input[8]).unwrap()[0..31]);
+            bytes[279..310].copy_from_slice(&to_bytes!(input[9]).unwrap()[0..31]);
+            bytes[310..341].copy_from_slice(&to_bytes!(input[10]).unwrap()[0..31]);
+            bytes[341..372].copy_from_slice(&to_bytes!(input[11]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x673cd258f0f45cac,
+                    0x100f0bf42d302896,
+                    0xd43033ee793bbdfa,
+                    0x0699892ab8843218,
+                ])),
+                Felt::new(BigInteger256([
+                    0x616c160aecaa4bb5,
+                    0xaa6b3fd0390f2863,
+                    0x110e47226931bba0,
+                    0x02008f9d13b36700,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb22a9ee9d085b0d2,
+                    0x3700e9c745512b2b,
+                    0x0f7589b5bdd6ae7b,
+                    0x057819235e60f8ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a7326abea97d3cb,
+                    0x4d67de467f8aa737,
+                    0xccee60ac4f4bc711,
+                    0x05ccb3efe5bb6fd5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x050a6facfedf521c,
+                    0x63c4cc788cfb2cd4,
+                    0xe97e461d7bf2597b,
+                    0x04cdafc9e21eaa70,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89245cade5a4cfb8,
+                    0x9b442ea99c2f1c4c,
+                    0x3bfa6b996d7ffd7a,
+                    0x023b202cb241333b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x62892d7248ab7e01,
+                    0xc640abba337bc344,
+                    0xc5b428f067b66051,
+                    0x021af74839a3d836,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d7ec1ce0ffd26d2,
+                    0xb6af32791feb10ac,
+                    0x4b36f7c6378af640,
+                    0x058fce56d4062c0e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd56a2948dff6e169,
+                    0x20bf7dc3792df981,
+                    0xf3ea69cffbe8b9ee,
+                    0x071b60307d32aa4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x682aaa8435d0623f,
+                    0x0c36abf30111d007,
+                    0xefb3506777041492,
+                    0x03af46d7243de28f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf2a4f2a9312b0b7,
+                    0xd19d04616083d45d,
+                    0xcfa8579cfacb4a90,
+                    0x04f086dd3ab26dc3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeccea10d1a448971,
+                    0x9a21004d7aec3b42,
+                    0xe650e09e4fde4f12,
+                    0x02e0838712880c44,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x943b2cc5dbb730a5,
+                    0x83bf5b213fb24217,
+                    0x34ad4e60735e500f,
+                    0x02a8738634a381f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f75f240b1c26b92,
+                    0xcfc16e0f7e9d1e96,
+                    0x0d2aa982fa0a4c6b,
+                    0x0705ac21b632ef2a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba08206c555986ad,
+                    0x1c893425a38f60da,
+                    0x782124845a7b29dd,
+                    0x051093b39b3b554a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbf479e944b4fa113,
+                    0x3961569c9afa4a36,
+                    0xf357006eaacc8575,
+                    0x04b57139119854a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa9048a6566c9f99c,
+                    0xc716b872a1c2bee9,
+                    0x51618dfc0965e946,
+                    0x004d3dcd2b460295,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa71dcac32abca1d0,
+                    0xd8d393ac9e09e50a,
+                    0xf09bad1bc39d4272,
+                    0x021cb911a0374c09,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b33a77d8df55269,
+                    0xf28fb3e8ee2e5221,
+                    0x0c05efd6d73fd033,
+                    0x03a134255f17e236,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf200fe75c42ad94b,
+                    0xf51192d1aa129c5f,
+                    0x1249499915c4d74c,
+                    0x05b166d52c0d6858,
+                ])),
+                Felt::new(BigInteger256([
+                    0x15c4ee843fb1141a,
+                    0x8760a03b252005bf,
+                    0x6b785cc0b6d138ac,
+                    0x03c9aeda647a9b46,
+                ])),
+                Felt::new(BigInteger256([
+                    0x64bffb73d05fa8da,
+                    0xc9382bc6526f13f7,
+                    0x8dc0d69f684522c8,
+                    0x054526a09759850c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fc9f17918941a91,
+                    0x03599ae2cd635ba6,
+                    0x4c1f2c17ce9abe90,
+                    0x07488f8c68caa170,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba95367e4bb47e4a,
+                    0xcc99af43fc4507ce,
+                    0x1d930fe2fe91c515,
+                    0x00fd365a476022ee,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x5d4cb565d141ddef,
+                    0xeb56001227207b2e,
+                    0xaa106d9c2b51d7b2,
+                    0x017ffc563d3c3cbc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa561dc7e08878571,
+                    0xa1dd2e66bd7572ac,
+                    0xc58afb12bddd1aaf,
+                    0x05a6eb27dea2b163,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb44240cdabefdea1,
+                    0xa9487dfd62258229,
+                    0x8751ca7261bbaf4e,
+                    0x06636fcd38ff6a33,
+                ])),
+                Felt::new(BigInteger256([
+                    0x494e10b5a7d8067b,
+                    0xfdf793f9e1d2e56c,
+                    0xfeb88df153322678,
+                    0x07055b0b63c49e11,
+                ])),
+                Felt::new(BigInteger256([
+                    0xce5f488b331fa6d3,
+                    0x8a74062e0caaeda7,
+                    0x4c479d4e3fb96432,
+                    0x0221e27873aa5e6f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5ed192c1b2057c31,
+                    0x6c07e4eaf2ab26e6,
+                    0xf0af3357bc4e4714,
+                    0x0640ff5b9fea6fbc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9080335d91a97bd7,
+                    0x8d1026a88037aa81,
+                    0x2277807ddaf1cc09,
+                    0x02672444f068e007,
+                ])),
+                Felt::new(BigInteger256([
+                    0x960d58d0ff64528e,
+                    0xcc1a97a0d05947d6,
+                    0x6ca0ea3d765540b5,
+                    0x070e5bdab50e9048,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7d261a4db7047caf,
+                    0xada0cf37c9e33f59,
+                    0xa2bfd03afaedf99c,
+                    0x076ef82f353189a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa4b9e9f0e318ed2,
+                    0xd426564bea142901,
+                    0xdc2340f8a22cf82b,
+                    0x06165eef7716b152,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8819cbf9c5f9b4d,
+                    0x36c5fccfaeda67a5,
+                    0xd83adbc34346e67d,
+                    0x029d482c3d435119,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3b3d309c179ba4a,
+                    0x40ca60ccb48f4672,
+                    0xbb0f45a5d7b45845,
+                    0x002843414b5b554d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xf5c950521292c467,
+                    0xb5afbdb9a26ef835,
+                    0x804e923cc7f042b1,
+                    0x06db2afd946b6198,
+                ])),
+                Felt::new(BigInteger256([
+                    0x371376a24b74b7f8,
+                    0x22ce778b514c50ec,
+                    0x6816c57545cf39f7,
+                    0x00dbd0c87f103dd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x426fc26878abba9e,
+                    0x126aa715700097e2,
+                    0x42aedd38aa3c0eb6,
+                    0x018048b60c91db36,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f9e84bb5d4c4a32,
+                    0x557bb04a741f4e30,
+                    0xcb6d41adcaaac0ba,
+                    0x00e9a06ac6b0281f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e708e0a6694c422,
+                    0x83e2910a1742a1b3,
+                    0x803e0c841b422f8b,
+                    0x03a6a7296d2ec4a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8fa8f767a0768ff6,
+                    0x8a76378963583db7,
+                    0x4add73383eb7a7a6,
+                    0x04ed8f266aa3aaf2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b83975623fcde33,
+                    0xc8745f4aa807afcc,
+                    0x6da4c0ea2544b4b6,
+                    0x052178481a513360,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20b516e2cb28d286,
+                    0x8f9cba3ee6b25139,
+                    0xb56067ed62e34add,
+                    0x03789c38cdf4d93a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x752d887302116e40,
+                    0x61bf513c5b43674a,
+                    0x702d96c8133d5f85,
+                    0x033f9986b8e438fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09b71c2a9e3b5042,
+                    0x51c07feb3ce11ab6,
+                    0xd808934e3664ebea,
+                    0x06591e9c2ae688a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x712c36e35243ec6c,
+                    0xe9aac0af1ba6432b,
+                    0x23f15d704953d810,
+                    0x020e0a3c6b759e57,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf4feddef28422a8,
+                    0x8f460a9c56cef9a2,
+                    0xe0abe28392f08dae,
+                    0x053ca976a70733b6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x4da18ae19525509d,
+                    0x99a65f92fd18b9cb,
+                    0xbe20f51d63129abb,
+                    0x0628e7846688a407,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41cefe79e39aae78,
+                    0x5a7e7dbc9879a4b9,
+                    0xe59e76203c6c51bc,
+                    0x07a35135d2da5604,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4018886bd3aa4693,
+                    0x3cf8541c288fef84,
+                    0xeeb1597f8329a0c9,
+                    0x0062c27fff550564,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24cf281b67c93a9a,
+                    0x08ae3cbb1f22e247,
+                    0x37bfe816c658766a,
+                    0x058d0e306f85e7a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb6da2797edc52607,
+                    0x4fea1271646c1089,
+                    0x2734177e3289ce4d,
+                    0x06c2c695b89b60ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66c7b55673c70a18,
+                    0x08aed3e5c3424086,
+                    0x3fd04892ddba1587,
+                    0x051413e81d8587b8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7794e94b8e8bbe8d,
+                    0x957be873e87eb8ac,
+                    0x8a5dace6326d766e,
+                    0x03b58573b1305995,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a2ec97536c1a133,
+                    0xc11c7677b2cb169a,
+                    0xb5f87035eb62fee1,
+                    0x05a8c149ecb8357d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbda77dbe1aed2ff5,
+                    0x8ba266ff804c76ea,
+                    0x38baf07ea9fc10b6,
+                    0x0543b96539a52cee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd63c8976c0df10b6,
+                    0x2653e76b121238c0,
+                    0xd3dd1561cda96368,
+                    0x05f2127912038dfc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ecb3db23bb147ee,
+                    0x8588352c1f7934c0,
+                    0x57a5f317f225047c,
+                    0x040672566422ee49,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72ef2426464c3b3f,
+                    0x1070bd4b422a7fe5,
+                    0xba17a15ab67c98c1,
+                    0x040d5bbe3cdd8465,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x2487102bf3bf1a0b,
+                    0x380564edfc7a9edc,
+                    0xeae72be38c3fb34d,
+                    0x02579e85b5abbc4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90fff999b5074017,
+                    0x6e5e3f7b23b43140,
+                    0x13024d1505a8e2dd,
+                    0x07fefe6dde333ed5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb0e497b83e4a882,
+                    0xe128d63decaaa8fc,
+                    0x1feace891c866f6f,
+                    0x0002bbd758f54252,
+                ])),
+                Felt::new(BigInteger256([
+                    0xead35daa675d31ce,
+                    0xf498b833f862c00d,
+                    0xe23159b1dc6f3b49,
+                    0x003d38f9a244a90c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8efc7f4feae51789,
+                    0x2d5b60104fc29eb5,
+                    0x79322300ea1ad204,
+                    0x00f10cccd3b39fd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4118d76f8b72fc5e,
+                    0xfa7de5b749a2386a,
+                    0x79085bc425b121b4,
+                    0x033c098b284854dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x599bc6312b456a8a,
+                    0x98eba230444d3156,
+                    0x0842fa7c423d01e5,
+                    0x01d89d266e03e979,
+                ])),
+                Felt::new(BigInteger256([
+                    0x170bc9fe7b1a4286,
+                    0x3a07d1dc7ae3cd00,
+                    0xe1a5950e574cc26b,
+                    0x03f134e9a51a8597,
+                ])),
+                Felt::new(BigInteger256([
+                    0x591320ebe8f897dd,
+                    0x6b6f5a427ea545b4,
+                    0xb3166fdb63904a7a,
+                    0x06f34afe6e52d1ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd56eb2c2a97d11ae,
+                    0x7d826d6657fda00f,
+                    0x8a6d661fccf04c64,
+                    0x015668e229437cef,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c13d4cb340a99f4,
+                    0x3c4cce47dcb8392f,
+                    0x2301cf86dd6fb30e,
+                    0x0789043b9b4e5203,
+                ])),
+                Felt::new(BigInteger256([
+                    0x10f2547244cdaedc,
+                    0x68faa7cd7713b305,
+                    0x708159c1a686d6c9,
+                    0x05a9dc3eb17ddd86,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger256([
+                    0x850ab85bef52a313,
+                    0x0fa1b352cf25c866,
+                    0xb09936a97784f392,
+                    0x0371ab9aeaf8feff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d843927e029e04a,
+                    0x1701e9e83602777f,
+                    0x3f58fddd9ce45abc,
+                    0x03b4ebdd50a00e64,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1caa2d670ecf8b7c,
+                    0xed685f904b38dcfa,
+                    0x8a5ae26d8ae101c1,
+                    0x01bec38107d13ef7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x42f8fcec4537f4cb,
+                    0xfecb163486054d19,
+                    0xe31bb82abfa11f13,
+                    0x03663a797a06e8e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f16750f41b6b615,
+                    0xa8bfb742387b7808,
+                    0x0b30fb0e30dd3867,
+                    0x042ec3ef8e88c3bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb483054ff4b7f9b8,
+                    0x3b5c378aa2ccd959,
+                    0xac809eae3f0b6b1d,
+                    0x04860b9540ad4535,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9fa14b3059614fdc,
+                    0xa7cefcd92abc7ea0,
+                    0x7828aa1a8bb93d82,
+                    0x000108c7a0fc2ad2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f93694733cdb675,
+                    0xb6bf0f09ae575ae9,
+                    0xec7ccfda76c78e21,
+                    0x03034526d4195632,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d7039b9a8ae24c8,
+                    0x7b691aa61c9accbb,
+                    0x20041deb7c71b97d,
+                    0x05eeb23820fc89ef,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ea916cf377b4423,
+                    0xc2fef8cfa9bbd7d3,
+                    0xa86c4794b40a889f,
+                    0x015f20283c8ac839,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd8cf15c02da4d730,
+                    0x8ef46c1a12124230,
+                    0x36c7e6abd0ea6857,
+                    0x01413407633686a1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d666ffeb835a325,
+                    0x42da83e55f8f6d41,
+                    0x5ce307b68f27abb3,
+                    0x0222f9f3e0756e66,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdfc620c80c5026ae,
+                    0x2bcc2a7f22c4435a,
+                    0x98a95363d1bfa2e2,
+                    0x04c180c948060fe7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcc2b03acc6dfa41c,
+                    0x3a210129c3514276,
+                    0x8dcad0ffb70e4e3b,
+                    0x07dbee49e36ae6e1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a1801aed87e9f2a,
+                    0x862a225efebbe328,
+                    0xd7db7532d49c6e53,
+                    0x01b1dc5f4f87cd01,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b95956eb8d6273c,
+                    0x9aa529b78a7f3150,
+                    0xb30f232f4cca42cc,
+                    0x039a3e3e926507a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7f2f6cfb3d18ddc,
+                    0x88ee878fddb4416c,
+                    0x8c19f20f6f98e790,
+                    0x07f6de3f04df3a48,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff696235179d466c,
+                    0x616acb5881d73970,
+                    0x6db17f899cd25760,
+                    0x074e3496db24dad7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2bde71abeb65ba7d,
+                    0x04e6caad150adb0f,
+                    0x5732d6fd7823de42,
+                    0x02f172cfd9bd86b7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7ce79c478579fa44,
+                    0xc03fb3bb0df6dc0e,
+                    0x83b2f6e6f1f3f4c7,
+                    0x07f68d68ff7c24d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0e73c949004742b,
+                    0x57fbc62edc0b0f9b,
+                    0x5981cbac98b4fc06,
+                    0x04e6a8e8e82be744,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7e4f2700fcab1dce,
+                    0xb727c97004e7114d,
+                    0xa896122ca8c60112,
+                    0x05ba92840724aefc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc22aaa4c135aff35,
+                    0x6946fc7ee9eeb592,
+                    0xcb0402f2fe6561b5,
+                    0x043af24fdddd4a48,
+                ])),
+                Felt::new(BigInteger256([
+                    0x055a06130eeaf0e4,
+                    0xb5505b0f4735c2f4,
+                    0x2db66deca9344e86,
+                    0x029dd5710f6240c4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9013570e77742df9,
+                    0x9dd4755c1f7c2173,
+                    0x1d00a96b7189ae1c,
+                    0x078528d66b558ec5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbccff765f1e029a9,
+                    0x7615049ee5a00a0f,
+                    0x0ac5a1b02d79b189,
+                    0x07c37ded233e2e91,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeaa042c4caaac899,
+                    0xe353e767b74dbafe,
+                    0xe3f6a89538bbe8e7,
+                    0x055176bcd4f5b7a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x357ecebeeb83ec99,
+                    0xa5281798d3ff3a36,
+                    0x72be17e9de98cabd,
+                    0x02591fcd20af974f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xddd8a6003ef43b02,
+                    0x2176893912e9c371,
+                    0x0aabc1b83a79ddff,
+                    0x01d8ca301784431f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90671801741bec5d,
+                    0x11026a61506b4b67,
+                    0x25cc5aab05641dde,
+                    0x03552f334ca93931,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc3884c6688efc266,
+                    0x21b46585e9a749a5,
+                    0x3b12e13a920f1a27,
+                    0x06dfdbbd2888760c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7038d8c94e2bb95a,
+                    0xcfb1ddec1a43cc88,
+                    0x00e8d1de1f0f503b,
+                    0x078cd9e6fb25ce73,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc67c6a8dd81e1185,
+                    0x51d98b9eed5b02c3,
+                    0x9a9bf5ac2c8edf0c,
+                    0x07da8fe24b421b4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60f9743b902d5c4a,
+                    0x0166ae07ef4addba,
+                    0x3e5b2addcb64b7d9,
+                    0x0674d7aa8a96c722,
+                ])),
+                Felt::new(BigInteger256([
+                    0x629211d895e3d6cc,
+                    0x06f9411f36142c54,
+                    0x002b8b821c5c9b2a,
+                    0x032f21c8261518d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c14d86c0d0dd8bf,
+                    0xb26be3353544e3e8,
+                    0x73974b2a1b84d4f3,
+                    0x0494c5b272fc2434,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0795c1276248006d,
+                    0x29031e24050429d8,
+                    0xdfaf581bec1066f4,
+                    0x03badc65d5a27715,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9008b9cb49ae18f,
+                    0xa821f5c0afccfcf8,
+                    0xd0db6c7bcff9008b,
+                    0x07655b0672b641e7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe26727b6cc794768,
+                    0x57b3c1d344640045,
+                    0x1d1e66f90d2da586,
+                    0x04edd96137a73bf9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9be7203b705ac11e,
+                    0x0cce0c7caa1a599b,
+                    0x9c178f012d7681a8,
+                    0x033bd3dc59694122,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63d6384eb1d9a2d5,
+                    0x853737aef79823ab,
+                    0x758dbfa4095feec2,
+                    0x071f5a94e063e15a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a3e62294111c2aa,
+                    0xf61d893583f86936,
+                    0x2a8fb9c90fd2171e,
+                    0x01be2c5627f5443d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa2d76637d1751de9,
+                    0x26c8258c759cabc2,
+                    0x60d6d1c6712e45a2,
+                    0x05988db45ce6caeb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8fa05f84f4272245,
+                    0x8a24e2e40830fc59,
+                    0x2bff5a204ca6ec6e,
+                    0x0386861ce4db2f49,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c3c59620345d2a7,
+                    0xf71dc01566425460,
+                    0x57de554f60f68f6f,
+                    0x040f225c59a97f4c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26fa5ce4087301af,
+                    0xd23ee6bb129fa122,
+                    0x53b636b025b3e9cb,
+                    0x0486de2bd761972d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6fb7d86fe130699,
+                    0xcf3d8dab691664a1,
+                    0xc447259d1d1c2e55,
+                    0x015a4943f597f3f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcd4759319d6333cc,
+                    0x3cc842220612694f,
+                    0x667bd8b2e9143f52,
+                    0x014d936c740a09c3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfa79c05f2c31b9f0,
+                    0x39ae4ba651583734,
+                    0x4a8fd25bb88437d7,
+                    0x02bb5f87762e0b8d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa877bfd9d2a78f8d,
+                    0xa1b3cfc57086d79b,
+                    0x743f4f2339317dd8,
+                    0x01dc97d5db9b961e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0583df93058695ef,
+                    0xff6a4e79cf186517,
+                    0x6bbba50d62c950e8,
+                    0x0100fb2b2ca634b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a41f401ee388d6e,
+                    0x50153710b40d0ee1,
+                    0xe72f0f2ec5bd073c,
+                    0x00ee353422b6a8a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd579f43c7bdf2e06,
+                    0x8652ac24a684354d,
+                    0xcbc8a4bc5007da9f,
+                    0x0112792e9e2b536e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7aaec0850ab4d1e3,
+                    0x0159efb76bc9b431,
+                    0x3d2a966e62640840,
+                    0x018eef61a932dd07,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9589ee35fe087aa7,
+                    0x367d979f4262a38c,
+                    0x356cb9b995d2d71d,
+                    0x073b5663fd55833d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x84ec99f5c23be4f6,
+                    0x013271a5e09d803a,
+                    0xfa412dbea4d57088,
+                    0x06752660ea2aa8f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x48b9d4ef689dc1e0,
+                    0xfae9ee3c8dee24f7,
+                    0xee4c9b9521b752c4,
+                    0x06d4bfde0e3ab1d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5dd1b92690631891,
+                    0x3f6cfbbff6e41c26,
+                    0x844a6f2f7828ea0c,
+                    0x05215b04f282be01,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb9a8f1ddd358fbf,
+                    0xc6831d39812c0ae5,
+                    0x4dcaea12ee3f1ecd,
+                    0x0771ee7c34ac44ca,
+                ])),
+                Felt::new(BigInteger256([
+                    0xce6fc376f044af52,
+                    0x5cf3c0c1a7423e22,
+                    0xf80d0ff730dbf08f,
+                    0x06931ace2e98cc44,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 12],
+            vec![Felt::one(); 12],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x8d01d9d9d5c62341,
+                    0xf1d57ddf5276faca,
+                    0x0e38cd3b3c74cb52,
+                    0x02d6b900ccd376c9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6f0943728666d477,
+                    0xd3c9d992e81abc45,
+                    0xe9d23b01be6c0d8b,
+                    0x0078382d3506f1cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x23e0b1af04d572c2,
+                    0x8d1af105abad2dad,
+                    0x415e1f4476572850,
+                    0x045631137a06ba99,
+                ])),
+                Felt::new(BigInteger256([
+                    0x91e22cef034f5849,
+                    0x5e8e73e9104c5aee,
+                    0xdb4717c96188c88e,
+                    0x0023edf956187d12,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0499ffd027c6e835,
+                    0xb06e0d83548821a8,
+                    0x9d4b3e5d2cb692fe,
+                    0x05c49238ebc62ef4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf18a32548f22dc29,
+                    0x87fbaf0e07c4c2bd,
+                    0x6ce2899746586067,
+                    0x004717e769ab9d0d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7e210b8cfcd6e390,
+                    0x56108cc3a17b5461,
+                    0xd4ba75c41f6283ce,
+                    0x07d7463f73f72506,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e0bd7d0a18565c6,
+                    0x47d3ec349a6dd6dc,
+                    0x602b40e2839ff197,
+                    0x058a5f5dc5cba9c7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0c1711bf0562ef88,
+                    0xab86c0a99050948d,
+                    0xf8195629f1d4bada,
+                    0x07a30762e817adf8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92d1998e2061a8a0,
+                    0x7627855d997b1140,
+                    0x5b3f760c518d20ee,
+                    0x0760ffc8b1c60e81,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c7059d22a0e42c4,
+                    0x52c65989e6ad2529,
+                    0x44a9c244934467f8,
+                    0x00ae50aae7fbb1f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71679150cc1915db,
+                    0xceab47e1314a92df,
+                    0x661237840bc8f08d,
+                    0x01b80221b488c1bf,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x0b09cec6f6854b0f,
+                    0xd58cfff48762b696,
+                    0xf482c807c569c06e,
+                    0x06a6df6f6d629a08,
+                ])),
+                Felt::new(BigInteger256([
+                    0x768b5df81415d785,
+                    0xac108efffa49ff55,
+                    0x082cf4e8c45f80c5,
+                    0x026dffb868b1612c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3cb419254b64372,
+                    0x4a5cadc1c04dfd1a,
+                    0x6a4ac732ba9199d2,
+                    0x04a1bb68f8eec27d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe0ead30b1081fb58,
+                    0x4919943ef4457552,
+                    0xa4d77cce7c10b0fd,
+                    0x0036cc089b57266d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4344daf53900d74a,
+                    0x364a24cc4d28ee16,
+                    0xec73be78e539e593,
+                    0x0617e5d5db99c52b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7ef347a0705f5ab,
+                    0x689c250c99f380cc,
+                    0x321aa68d62ae9b16,
+                    0x04d0ef295be0a337,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0c1a16e0d9a346c6,
+                    0xd3be40ea350df147,
+                    0x71c909eea6958d90,
+                    0x076536f2f5d5e751,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6fe0837d8b0a24c5,
+                    0xf1f51a1c8f5adac7,
+                    0x413e91a1ca3b9743,
+                    0x05fd0630312577ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacebda0e753eb7eb,
+                    0xd4a5bd414ef36bbc,
+                    0x5fc0bfbdfd8aec84,
+                    0x0031a1ec1d2808ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0x529af2f64722c9fb,
+                    0xb0a47577c6c1c93c,
+                    0x14adb0b8d25a4e20,
+                    0x0737f8df41b5932d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9941455620afcb2,
+                    0xcc9bc05f02484846,
+                    0x159a2c111ba25342,
+                    0x03830f34d0c537f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1395e43cd47495a2,
+                    0xc671cb0a532cee2c,
+                    0x7e97857f76971555,
+                    0x013680a6ef7ab508,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x698ec39224f05312,
+                    0x4489607c71a5b196,
+                    0x3f715290c9f5a599,
+                    0x025a7823c54332de,
+                ])),
+                Felt::new(BigInteger256([
+                    0x59a55b2e3c71002f,
+                    0x749e09b255062819,
+                    0x1b5c7cb6a00d487f,
+                    0x01d16ec19777e3ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63490b58e539b4fe,
+                    0x522a00ddbea16dfd,
+                    0xec92570faaff26db,
+                    0x05a466349c76ce6f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95f7e7ba0bfb50e4,
+                    0x727db7c2d43af52a,
+                    0xb9ea9912d7821416,
+                    0x014105c70c830d81,
+                ])),
+                Felt::new(BigInteger256([
+                    0x54b10aba4b494448,
+                    0xb8da23abb740f8e2,
+                    0x38104a17565be97b,
+                    0x018dcfc03368b764,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a2fb632131999dd,
+                    0x3efb9e92481b86df,
+                    0x764850b3e2f4db88,
+                    0x04c1d98b07936a54,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2e6d060ddf93147e,
+                    0xd2f98e9dc0f33041,
+                    0x2e897a8be9e4d4b7,
+                    0x07e582c8c09d806b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef54353890b9f1e6,
+                    0x402787f733199f3c,
+                    0x206804c98704b99f,
+                    0x01d9ea3761ca40db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9db55d3f847c0549,
+                    0x56449ca3efbe8738,
+                    0xfdc06773084f7aa8,
+                    0x0767735e9cd133db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8d3b6ed8204f1f36,
+                    0x8008fb3ece644058,
+                    0xb8553983c1139795,
+                    0x0230785f860ec0fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a8d2a267f275988,
+                    0xacc175d7398a5870,
+                    0x9137c30f3b9f7a4d,
+                    0x00198814af40623b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x08640df967bdff46,
+                    0x8b5b864914e796c8,
+                    0x0716a504a71521aa,
+                    0x07fbd865b11ac446,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x8974d08bc7190b4d,
+                    0xee74036f754ba167,
+                    0xcebe37fe35438e9c,
+                    0x06bad00cbcb23b89,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b668481879efbc7,
+                    0x38b987cf3bb275e8,
+                    0x50d9908c70db1a11,
+                    0x04135107969f95c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x31ab1df8a1d1180c,
+                    0xd9f70f281b761009,
+                    0x79c160f13724914a,
+                    0x0158bd7d91dceff9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4deb4d4ab6b25b3f,
+                    0x7550b9639c83188f,
+                    0x7d7d07863c1bcf4b,
+                    0x008668eea18a38a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x433f7fc6c127776b,
+                    0xc93539b6a471cd2f,
+                    0xbfd738f6386efa62,
+                    0x06791660ae442bc7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b9151897cd2c2a2,
+                    0xf0d1af186eaf3fe3,
+                    0x94deab26f0116523,
+                    0x00a0bdd6ba212254,
+                ])),
+                Felt::new(BigInteger256([
+                    0x44c0eb5cddf33768,
+                    0xe51adc30f99b2fdf,
+                    0x70522eb9008f3cd2,
+                    0x0448eb65544cf089,
+                ])),
+                Felt::new(BigInteger256([
+                    0x178c6a1136533ce3,
+                    0x106788817c3f5ffc,
+                    0xc4b0de56527b995b,
+                    0x00d2e5e5fb1fe9cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fcba84ca8ec38d1,
+                    0xc7ce81f7dabbeb6a,
+                    0xe9aeeb48b806e5aa,
+                    0x038891b5c294fba0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97a0caba0e03b2c2,
+                    0x3575e83942e04d6f,
+                    0xb444790b1680cb58,
+                    0x04c66c8ee0414bd7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ca32e7dbf7e509a,
+                    0x6a35a33ef54dfc13,
+                    0xf201242843e27b2c,
+                    0x06998a75e4dc2a87,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4829b85a0d65657b,
+                    0xfc109d717a4979d5,
+                    0x0e760a1eacc5842d,
+                    0x02ef7a5c2a8e3965,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xcc84a1d1afac43fd,
+                    0xc885756c093551ed,
+                    0x01e431552f6c3e46,
+                    0x024be6ae8db34f90,
+                ])),
+                Felt::new(BigInteger256([
+                    0x462bf851e46ffc2b,
+                    0x4d2c403e525d3f6d,
+                    0xd00c1c8c511be99b,
+                    0x06f11719b31305af,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66423523e722cf01,
+                    0xaa8665e6053885cf,
+                    0xc547d593eb27fc6f,
+                    0x07da877c60eb6b7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd75c87492650a9ea,
+                    0xcdec7dd3e35a0f3e,
+                    0x136d51c2842c9e8d,
+                    0x06108332b205e543,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8acda0275c3a41be,
+                    0x9c305393ec4917ed,
+                    0x5fdfaef7ad80f016,
+                    0x0267d4964ac7e5bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea4bcb12e48ef953,
+                    0x5ad0d225070d1af6,
+                    0x8784b04a979b84f1,
+                    0x052faeea74df6bf5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x258e36c880a74be5,
+                    0x3b60e91e5dbf6880,
+                    0x9ade8d7a973d538a,
+                    0x046d1e172ad6d82f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7d9385ca1fc65cdf,
+                    0x3aea43cbe1fb7566,
+                    0x49953317200d02f9,
+                    0x022e16467e292860,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c79efc0516e8350,
+                    0xb6e21f83c419fa2c,
+                    0xbf41c1c4098644a9,
+                    0x003c798d4387bc3d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9325ad43baa47e3a,
+                    0xdb31bb9c79faaceb,
+                    0x870bd0bf0b538a2d,
+                    0x00a6b295c3235151,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea72b05540af56ed,
+                    0x2a1e639161b71342,
+                    0xe31e216bcdb2af19,
+                    0x04feb612cda489f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x597b6c3bef8a4840,
+                    0x4fec0714bdecb834,
+                    0x997f2a336f48353e,
+                    0x07a0250f3ee41d18,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x6a0ebb0c1e2c294b,
+                    0xa3a9e252af69e1fb,
+                    0x874ac9a57c811101,
+                    0x066dc305f69626fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b3efbd1a286a125,
+                    0xb6c87de70a9a4dca,
+                    0x5829d474f05867ef,
+                    0x040465ff004101aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fc838d47b8ea9a3,
+                    0x10033278d327b9e0,
+                    0x60c3068f6c5e4c5c,
+                    0x03479ffef4c3f8a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a23a2249e9a3e7f,
+                    0x44a4c69e047a34f4,
+                    0xb8315cd05cd1d364,
+                    0x04d5fe66a8e15da6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa0ce933f1e1108ea,
+                    0xe9c90b8bd2114982,
+                    0xf4547c0afc2b491e,
+                    0x04c4edfb353a96e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x24286fddbb3144ab,
+                    0x471eef91b6e59319,
+                    0x1297c94478fbf951,
+                    0x053578cf976f1e96,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaac7971d70b1f5e9,
+                    0x5d47e3ef20d7fba1,
+                    0x0ee108f75a72c0f2,
+                    0x05924c986a81db6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09b4a1cce0dbf1e3,
+                    0xf8003d449dd062cb,
+                    0x8dd6935e6b26456f,
+                    0x0777b9e2a3558a5d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1dc18530134163e,
+                    0x5621b43ab5d32f01,
+                    0xf1c2431250526252,
+                    0x05ea1f973781f48e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfa82e84aa34374a7,
+                    0x5ab88292d290084d,
+                    0x3cfd1828d950fe4a,
+                    0x04d98fe8218b44cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1fdeb73b4d97e69,
+                    0xfed1cf47eac19a00,
+                    0xf059846593c5fad2,
+                    0x057c7d8db8b5fd58,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3aeb2aa0ef8a32ef,
+                    0x0ba86fa33b357ff9,
+                    0x9efeb19a4dbe9d77,
+                    0x073692e57db8993e,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger256([
+                    0xf0cb5ad23fd8e4a3,
+                    0xa5c9ca2552da1d68,
+                    0x4625142533432dbb,
+                    0x015f330b815301a1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55003b641a19cecc,
+                    0x512937a75ed49df2,
+                    0xcef554b69b90e4ed,
+                    0x03a131ec0b543c6d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x15e09aaa2fb44bd4,
+                    0xb22c839959698d8d,
+                    0xcef4aeb1d9155f57,
+                    0x0730ef07252f3b62,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ba2f015237e9dbd,
+                    0xbc988bbeb7a29ffe,
+                    0xf1cc1f25b9f9c274,
+                    0x06855f42f1198cd2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf1d1194698a053b3,
+                    0x3ae4d46dff3467ef,
+                    0xfc9ebaa615f4f8c6,
+                    0x066a3b679c6d1720,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6729fb50975311c2,
+                    0x3630f639cfa7ad38,
+                    0xae8b73b8a0aae868,
+                    0x02c4611f50f4c939,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9ef0588c8ec52ddc,
+                    0xc6298d5adb04a03d,
+                    0x7bb8a59d0f3e3bfd,
+                    0x04130e089fc6b833,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0090c95b911008f4,
+                    0x2cb7d83a5a13b050,
+                    0x59ff770043ee4461,
+                    0x004ef55e1603146f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa65323f6e4928c5c,
+                    0x151d5bc483c72533,
+                    0xbcb55046203f52dd,
+                    0x063e674dabe46314,
+                ])),
+                Felt::new(BigInteger256([
+                    0x06edf5babe3ff0ff,
+                    0xf3105309706b49db,
+                    0x95c199193cb9b06e,
+                    0x07683ced25d2637a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x80586f4cdc10ab83,
+                    0x9cdfa93876ea6c61,
+                    0x151df5baeb766619,
+                    0x00beaa900de93923,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05ec372c38f4ee42,
+                    0x40644e5c17a2d1b1,
+                    0x3999ae332ec587a0,
+                    0x02c1586ceb9fb2c9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7f3a99f551f0cc2a,
+                    0x7ae8bd83f98ee02c,
+                    0x2a15665aa1c61197,
+                    0x036d7f9f66f6c52a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x07166013eac6ce55,
+                    0x691000f989e1faf4,
+                    0xce68edde4b7e3054,
+                    0x05a3da91d69d9a63,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9eff4459af5cb5bb,
+                    0xd293c3a571df149b,
+                    0x80132011659deefd,
+                    0x02f2ce4d5ef22a94,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe387603c0a1915ae,
+                    0x595ebc52d9c9dcaf,
+                    0x6fd27b90d8b1efd7,
+                    0x07564cd2617549f2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41296d80e15ae305,
+                    0xe1d3d9f8e5e9d72d,
+                    0x207e0be59b2356fc,
+                    0x0480f4d82e627dc0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf4beb323d0e572b6,
+                    0x99b53ea466e414f8,
+                    0x5a67d09db16ecf53,
+                    0x015e7e0a25e47110,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbff78c8293ee8191,
+                    0x60df2fe15ab24001,
+                    0xe2e3fa8783ec25ff,
+                    0x05d14ddffcdcc056,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8d7f92833752035a,
+                    0xd3df9a3292133ca2,
+                    0x1780a0699b2c312a,
+                    0x062e5b6f0db8d571,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 341, 342, 1028] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 341];
+        let mut opad = vec![0x5cu8; 341];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
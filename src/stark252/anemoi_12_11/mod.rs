@@ -0,0 +1,2552 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger256, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 12 field elements or 384 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 12;
+/// 11 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 11;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 6;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    let x: [Felt; NUM_COLUMNS] = [state[0], state[1], state[2], state[3], state[4], state[5]];
+    let y: [Felt; NUM_COLUMNS] = [state[7], state[8], state[9], state[10], state[11], state[6]];
+
+    let sum_coeffs = x[0] + x[1] + x[2] + x[3] + x[4] + x[5];
+    state[0] = sum_coeffs + x[3] + x[5] + (x[2] + x[3] + (x[4] + x[5]).double()).double();
+    state[1] = sum_coeffs + x[4] + x[0] + (x[3] + x[4] + (x[5] + x[0]).double()).double();
+    state[2] = sum_coeffs + x[5] + x[1] + (x[4] + x[5] + (x[0] + x[1]).double()).double();
+    state[3] = sum_coeffs + x[0] + x[2] + (x[5] + x[0] + (x[1] + x[2]).double()).double();
+    state[4] = sum_coeffs + x[1] + x[3] + (x[0] + x[1] + (x[2] + x[3]).double()).double();
+    state[5] = sum_coeffs + x[2] + x[4] + (x[1] + x[2] + (x[3] + x[4]).double()).double();
+
+    let sum_coeffs = y[0] + y[1] + y[2] + y[3] + y[4] + y[5];
+    state[6] = sum_coeffs + y[3] + y[5] + (y[2] + y[3] + (y[4] + y[5]).double()).double();
+    state[7] = sum_coeffs + y[4] + y[0] + (y[3] + y[4] + (y[5] + y[0]).double()).double();
+    state[8] = sum_coeffs + y[5] + y[1] + (y[4] + y[5] + (y[0] + y[1]).double()).double();
+    state[9] = sum_coeffs + y[0] + y[2] + (y[5] + y[0] + (y[1] + y[2]).double()).double();
+    state[10] = sum_coeffs + y[1] + y[3] + (y[0] + y[1] + (y[2] + y[3]).double()).double();
+    state[11] = sum_coeffs + y[2] + y[4] + (y[1] + y[2] + (y[3] + y[4]).double()).double();
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd272cb1cab9b88f5,
+                    0x1a9d930d0760d4ee,
+                    0xfbe8df285888b9ab,
+                    0x06a036b72c9a24a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3f97e454c0e9b40c,
+                    0x2dd85a9155fbb459,
+                    0x495b45c2fef58551,
+                    0x031585714f36cfc7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x194c91672b2a71ad,
+                    0x5d233b0745ff90c4,
+                    0x8a681b62b3befd6a,
+                    0x015eb4c28702e19b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x942d2c2faeb9fe63,
+                    0xb8ac4f5aa3d3171c,
+                    0x3bf4b72937148f56,
+                    0x02055ed96cf83c89,
+                ])),
+                Felt::new(BigInteger256([
+                    0x08329de0dc644968,
+                    0x3ae86c24f6ba478f,
+                    0xd6960573807c6167,
+                    0x0124b9948cb6bc17,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9057e040d3d02f8f,
+                    0x3e29f78e53cd41a8,
+                    0xe127b83d34bf301f,
+                    0x0325f4c298861d91,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30391c79c8d02860,
+                    0x42e2b0b229adcfc4,
+                    0x61795f3dba37763e,
+                    0x06803899d8049b78,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20df0d2099096994,
+                    0x9ec6d42a21436055,
+                    0x956d9c0c94ce9825,
+                    0x0126f75721aa4244,
+                ])),
+                Felt::new(BigInteger256([
+                    0x193b384aa09e3fd4,
+                    0x6720017d68ccd44b,
+                    0x9d6405d6353d1b86,
+                    0x05e004c64171fdea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0c4b5d6db274989c,
+                    0xe6251e40cdde986d,
+                    0xdba4af8dbb4dadad,
+                    0x049280675571cb3c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8392a14d632e1637,
+                    0x9e9e8beb7fb0ba8d,
+                    0x4e32c558289471fc,
+                    0x0389f864bcd36bfc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa265c7d7179490e6,
+                    0x0c405e3f9dc5b870,
+                    0xa4e9a6fb686a5d3e,
+                    0x077a7cf73026504c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x738b71537465c4dd,
+                    0x82d1e3fee0cc72d5,
+                    0x72ae7e84e3d7a455,
+                    0x02973b11460bdf53,
+                ])),
+                Felt::new(BigInteger256([
+                    0x84b1a1b532f6c54a,
+                    0x3259844a569fdb5d,
+                    0xfddaf3262ac0fad9,
+                    0x03862c2b6aa49b96,
+                ])),
+                Felt::new(BigInteger256([
+                    0x22b6fcd3faf572b3,
+                    0x23f84f046146d5e8,
+                    0x25bbbc43aa773292,
+                    0x057ca623ef8b5ffb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3e6de1c504164e22,
+                    0x50a8cf0fd8ddeaa8,
+                    0x010353c9dab3223e,
+                    0x07c7512f7e7be77c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa49a4286fb047a94,
+                    0x0dc95d7ee1b28202,
+                    0x23eba07e76f9af8e,
+                    0x02c93e35bb1a9e42,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2119f1a91fe81156,
+                    0x6a389676b84dbbf6,
+                    0xeca3e0d2b9e98c79,
+                    0x058f7d679ff9c575,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe38414e8fe7f0afe,
+                    0x2b4b7283a69c2733,
+                    0xa24242e40399c6b0,
+                    0x04abd315da180471,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc64975ba48d8705,
+                    0x7b64114d3c9db29c,
+                    0x90f80f9b52d1d173,
+                    0x01e3e08d9092f49d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcc03128f3fa59ff5,
+                    0x1c4ec1deda15dc70,
+                    0x6ca6d4e7a4a46b7d,
+                    0x0523bd716a64bb77,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c78776289aad35c,
+                    0xbf7619aedb8a4241,
+                    0x11d1dfe2a521edd0,
+                    0x03ff61d428534761,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe761da54fe2301d2,
+                    0xa76f20bfa5a9f870,
+                    0x8eca0bbd58763e80,
+                    0x005ecc1e0a1c2d35,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80a2a348030b7f63,
+                    0xaf542e86b73bc6ff,
+                    0xa0891757a5a98f01,
+                    0x0796d130fa167a25,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1f0070f82c287caa,
+                    0xe27235af7f95e8d1,
+                    0xebcec111d1a3f814,
+                    0x042a279b02502fd5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f9e6d907e899a6c,
+                    0x893c2318e598ab0c,
+                    0x513006aff10c07f9,
+                    0x07a237601b3b00e4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf367d484a1d3bae0,
+                    0x8e9aa1e004fc4b46,
+                    0x5d1fc1673bb99b1f,
+                    0x02c0bb803bc6aa45,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc91ce0df296baca,
+                    0x59b9fa9eca6cf59e,
+                    0xe0979b3f1505546b,
+                    0x02af84a4c2ca1923,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa283893c7dcee2a5,
+                    0xf2f278c8358c35bc,
+                    0x9d3e33e0f7e2a028,
+                    0x06af17c864a089a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0bc5523379ddaf7a,
+                    0x6335407fdebb19b6,
+                    0xbdec8061bec12bbf,
+                    0x015800c633d196f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x85a9cf4642f1a1a9,
+                    0x9f735cdef805152f,
+                    0x313c0c03ba7a6e90,
+                    0x03010e7e284fd3fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67405e74c8d0857f,
+                    0x8a21dde9e8bab1f1,
+                    0xbaec56a771b4e93b,
+                    0x00a0f5c77a41ad76,
+                ])),
+                Felt::new(BigInteger256([
+                    0x15bc59a3c058a4c7,
+                    0x83107d5b6f8b61ab,
+                    0xc1158b6b0b252f5d,
+                    0x002c16aa29a7d49f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c4efa4c812bb5ca,
+                    0x1197303c6230a0ad,
+                    0x39cb780e98ceb780,
+                    0x030c78bc872dd63a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fdc97e907829fc9,
+                    0xb7f447729b63a63f,
+                    0xe2e3175fa91f723d,
+                    0x02717b0333b7481c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x251bf9d3da60c754,
+                    0xe483297a6ce059bd,
+                    0xa73d28ed1835cdf1,
+                    0x05d1fb4db7104a84,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8d43c217bfd67725,
+                    0x5b493cd989a310f5,
+                    0x83435b578df91f02,
+                    0x027cbe20816178ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3a3294beb5b7971,
+                    0x57e782cf50335952,
+                    0x9bc540dd841c3a19,
+                    0x00da86e363ccb1a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x593d77045c65c631,
+                    0x16b24f1bb596f8d8,
+                    0x54a5dba46ce6c029,
+                    0x039cb6673ffa637c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x35579fc8d329470c,
+                    0x28109e02ffaf195c,
+                    0x1a5cc5bec1df30b5,
+                    0x01b6a8ac72a17e61,
+                ])),
+                Felt::new(BigInteger256([
+                    0x54ea5eca68a947eb,
+                    0x0b830d3a7ba9be54,
+                    0x87c5973ee91b22d7,
+                    0x04c46ebdf0391fc4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd8602bb3fc7a7498,
+                    0x19c4ca5259cab4e6,
+                    0xd7ce5dd48798ff5e,
+                    0x075060735515dec3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2146d2d969efeca7,
+                    0xf6676b4fb0a92d2b,
+                    0xa15f1a6a2c55189e,
+                    0x039b03ca8de23094,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a3cac6000df33b0,
+                    0x33e289390ace5ab1,
+                    0x39c7cad3d55c7063,
+                    0x01a32bb55a67afaf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0572e27b9439ce3a,
+                    0x07560fa0c3f85559,
+                    0x7c9018630c3d892c,
+                    0x0412e2988e5cc102,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc3a7749d42c4a22,
+                    0xb8a89b2d4daf2d4b,
+                    0xb10669af7ce0322a,
+                    0x03610771e63cf368,
+                ])),
+                Felt::new(BigInteger256([
+                    0x212d720b7b8d40d4,
+                    0x8e1af5b926ddf952,
+                    0x581c60cbb3e8eb4e,
+                    0x04ae3413267b1ed1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7938c86c2c067535,
+                    0xba60d8bf658ddcac,
+                    0x52205c05ed33e06d,
+                    0x0579761279672989,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x064611d076ea2bfa,
+                    0x67df8813092c5d21,
+                    0x89d31d04a282c41a,
+                    0x0550155c0eb5eea6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x251522714d9104d1,
+                    0x30b666cc87cc6bae,
+                    0x626d9d682c8641f4,
+                    0x00fd457d7835584c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa97e11db9ac5fd32,
+                    0xa7f20dca21fc58de,
+                    0x6abe14bc23751e0d,
+                    0x053b6a9388624aae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0edfacf21a10bd9,
+                    0x9951cdaabfaf298f,
+                    0xb34c1495a7682fc1,
+                    0x0541406a8015c9a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x82b0addebee3d90d,
+                    0x61bdd52c2c9d5a99,
+                    0xb08a588565fc50d4,
+                    0x075ef4db7612392c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x119c6e02f08fa9c8,
+                    0x48f17f5e7eecebe8,
+                    0xef5925dc4867a0ef,
+                    0x03f25d42d2a58d44,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd32be021c98c2547,
+                    0x6af6f0c000b0673e,
+                    0x6f99a13d65ec51b8,
+                    0x036d09a61292ab76,
+                ])),
+                Felt::new(BigInteger256([
+                    0x04d93a55323c0f17,
+                    0xa1fca22a354ad58f,
+                    0xff4d6f59da9627c3,
+                    0x0749572ce0055599,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97d4f6d3ec092076,
+                    0xfb75c77654b64125,
+                    0x510e2db28c321e7f,
+                    0x03dfd7825fe267a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc7d1a52c30b3905a,
+                    0xdc0cfcdde989abc3,
+                    0x2b6956a07aa57c35,
+                    0x0765ff3917d60a02,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc866eb9e6023b0fa,
+                    0x786656570932998b,
+                    0x8997d6e4dfd561f3,
+                    0x03a7ebfe1d6d484d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x888c693bb382dba7,
+                    0x62816dc6cda00602,
+                    0x2a508810fb20a8b1,
+                    0x03bcfba8ffe1af15,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x135e5e359d8ecd7d,
+                    0xee3e265bad362c7f,
+                    0x2af6bae72b45d32a,
+                    0x049687b58d6807bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2a4f86fb779b5d7b,
+                    0x95b0f31bca71ff4c,
+                    0x87279454ff4d9b84,
+                    0x05ab79be7b1f7d1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e3384b348dc0a36,
+                    0x673968315bd33173,
+                    0xd530196c21dc4d23,
+                    0x03b14b20d54d9c36,
+                ])),
+                Felt::new(BigInteger256([
+                    0x88975c81e150fb39,
+                    0xe40c6a59ccf8dc10,
+                    0x261099afc3cbfd47,
+                    0x07f63cbb6d0b605d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb47393671d95a7dc,
+                    0x5fe0cc5e7dcb7aa2,
+                    0x9515a5762097b4fd,
+                    0x0505d4886d084938,
+                ])),
+                Felt::new(BigInteger256([
+                    0x90fc52a67a7d6b9a,
+                    0xf9ecddf5532ca0ba,
+                    0x095e21123f6077ca,
+                    0x005ec1303ab12566,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63101f5b8bc3e408,
+                    0xf65b204b649debb6,
+                    0x7b37872e973fecc1,
+                    0x02e18acf60b99962,
+                ])),
+                Felt::new(BigInteger256([
+                    0x301a1fb754536098,
+                    0x87681b27f713e659,
+                    0xb1b9beabc2285f04,
+                    0x018d3bb89c4fac24,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4086d382065f638,
+                    0xbcdfca7c36e4b0a6,
+                    0xbdf31b658692145f,
+                    0x00c7b8b54dadf106,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b21f642bc6c89ad,
+                    0xcbbcb2e076188808,
+                    0x61ebba46849a369e,
+                    0x005cc8aa4b71df8e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c2baf41a2ea306d,
+                    0x6f2e45a5aa05f0d9,
+                    0x8c41d667d0d9e2d3,
+                    0x04a383ce604e7b96,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd1d0de56958d856,
+                    0x41474e68018cb35f,
+                    0xcfb9a18cb688018f,
+                    0x04fd0136d7ed5f30,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb4da33c9c8ab2f48,
+                    0x43f6e16e76e0b73b,
+                    0x6ff5afbb08335ab4,
+                    0x024539a9adf393ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9933c73c063f023a,
+                    0x5fd2e8404a93a3d1,
+                    0xc90a78b6ae4f3501,
+                    0x02954ecd589d6182,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e6fae39eb3974de,
+                    0x69642e410132b7d5,
+                    0xfea3bd9f9bcd6eba,
+                    0x06864e2e349270ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc7e60fc1f0610bc3,
+                    0x50b141011b13a5c5,
+                    0x63690856728871dd,
+                    0x064133d572965e9f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa8be4057d803c849,
+                    0xab27d615556b5761,
+                    0xfd1e87ec8e1cb672,
+                    0x042c917eaca0d2f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x392981fc8043cdba,
+                    0xa553431504881594,
+                    0x56ff872163956206,
+                    0x01f4e5c152ba28cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89d73cd9e3ebb54a,
+                    0xa1c557f25980efbf,
+                    0xbb55989763a156de,
+                    0x078a9361457ab5ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8caca945ac36db52,
+                    0xbe7252b7be6a574b,
+                    0x6bbf77fd6150b559,
+                    0x0135a91e02e3b942,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9506673ab8bfa95f,
+                    0xf3f30a1de5871ffc,
+                    0x641cd59a2b2bafe1,
+                    0x01fbb8f9308a3c64,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c198ff9463a7309,
+                    0x743849b001ba370e,
+                    0x8bc6c1694db85548,
+                    0x00f4e772426ae644,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1686ef37d81582f8,
+                    0xc46ac354c66a4041,
+                    0xf9c01e940b98e396,
+                    0x06873bcae42eef96,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ef8c46205c82ae,
+                    0xe71647601779db21,
+                    0xfd41dbcd88767961,
+                    0x042ae7f020704ee2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3a8938773dc0ff97,
+                    0xff25ff578ced8793,
+                    0xbc4a0ecf3c19659c,
+                    0x01062b44101672fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67eb4891672953a0,
+                    0xaa5e356474ce0164,
+                    0x2cbf6cfa94c08b3f,
+                    0x05b3af823280d558,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95987019099d2d4c,
+                    0x5505a146ae4f7e36,
+                    0x070f7abae8e384dc,
+                    0x05dbf5ae7c08aec4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37c61513038ca4ff,
+                    0x957c06a9ab442645,
+                    0x640b180a86e52519,
+                    0x0176e20085f556ea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1296875bafbd2319,
+                    0xebf875a068dca3c9,
+                    0x1a7e4b5f47222e66,
+                    0x069501db47ed13c5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x213e99037cea432c,
+                    0xafa1d53bd810c293,
+                    0x91d5b0bc3214c955,
+                    0x042c1fbde00cd19b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3c9a66507d170b2,
+                    0x344d4e8968a59356,
+                    0x9c4766679033b3f2,
+                    0x03c0e77826037fdb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x138fbb3ba7cfb8dd,
+                    0x83605775f1b96b5b,
+                    0x0ea81d29ca31f29c,
+                    0x040a045a0bc69fa7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e442d21d2bc2e21,
+                    0x1827a986bf58df87,
+                    0x32f4575493841937,
+                    0x04c23c4e5c1f9b69,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05a9fcdee15c4a7a,
+                    0x2b61b0a77df76b71,
+                    0x159b29b7aeebfcbd,
+                    0x06d84adc506c58eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff3526ad957d17c5,
+                    0xdfa77c7197d813f9,
+                    0x453a0c6049ad686e,
+                    0x00d64b0ba2aa3e76,
+                ])),
+                Felt::new(BigInteger256([
+                    0x079f1a71b7281537,
+                    0xd029bb7ad6806401,
+                    0x462bb137f9f9f67e,
+                    0x02abc601a916e063,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1bd1ffc0a8e90548,
+                    0x28301fffa6434791,
+                    0x13d79b7a9f249d62,
+                    0x023dfffc6d19dcab,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe7622f38bc89c1fb,
+                    0x483d3a109332ac2e,
+                    0x7c046b2c4db336df,
+                    0x0377acb903cb1d0e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4b4b2a9179330710,
+                    0x997ae4527cc38f7c,
+                    0xd4f4dd4aaaa50379,
+                    0x06853d8e14586178,
+                ])),
+                Felt::new(BigInteger256([
+                    0x650b2c07f28a246a,
+                    0x779a1d29bf921be4,
+                    0xba9136d77576db03,
+                    0x03a936829b6bf7de,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab8216a2abacfd22,
+                    0x6eb79456ae05119b,
+                    0xd117b7f463b6c8b7,
+                    0x05caf6b3b84779dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03c4a3d51490e578,
+                    0xdd3d6c73e64c96fc,
+                    0x9f6b93fa08edfc2a,
+                    0x04de56dc95ccc173,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd14d4660e8f7de67,
+                    0xf98542fe4e20be3c,
+                    0x747a5a2537595638,
+                    0x00513d037c3cbb5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b0c4639edb2dd8e,
+                    0xfbdd2787985bf6ff,
+                    0x05c4c8985ec07e85,
+                    0x034f7e93f71780e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6bd31c99b63f106,
+                    0x31850783cafdb607,
+                    0xcab13cdfddc50f6b,
+                    0x0130f84c936bcde8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x643867efaf1bc405,
+                    0x2324fedc5ac73ce5,
+                    0x6863b94d6a10e9e8,
+                    0x042fdfc5d63be814,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67098ea04acf6bbe,
+                    0xc77a75cdfb48f092,
+                    0x0dab1d7fcaa43ac2,
+                    0x04975708325304e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x223c6a16d6fca1c7,
+                    0xb839622af077e59d,
+                    0x0d73ad7120dbe612,
+                    0x006e07a2aecdbba4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcc6d72e318e3d4ec,
+                    0x6e2239608257383e,
+                    0xd04eebfc0506c45a,
+                    0x036fedd7035ea092,
+                ])),
+                Felt::new(BigInteger256([
+                    0x325f1381d8560d5d,
+                    0xbffd649c8acfa0c3,
+                    0x1c1b78820f427293,
+                    0x04d923ed729bc7e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72706b6e6a2ca5ce,
+                    0x7a823cf6ca1d2f7c,
+                    0x21d649fa0b7b6705,
+                    0x0338f09cc8797c71,
+                ])),
+                Felt::new(BigInteger256([
+                    0x071ec3878ccb4112,
+                    0x8ca031d79fb4b386,
+                    0x8f19f70596bb0ced,
+                    0x07b119290f79653a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc780abfb2adbac20,
+                    0xe4f1af685ed0c623,
+                    0xc3a4c2987464c8c7,
+                    0x05da13d93e8013f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92e1f085bc099fb2,
+                    0x2ad08e5e1e578c88,
+                    0x4a65739e1b5edd1c,
+                    0x0467782ef50b0388,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4a5daec1a5df205d,
+                    0xfbc1db1423dd14a8,
+                    0x3df5aec418475a24,
+                    0x0413b5b0505c903e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5cd3512b0d0e2338,
+                    0xcd486a3c121fdef1,
+                    0xa981ed9e10f01977,
+                    0x034c0396eddd9ebc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94875ab0cb9ad4d0,
+                    0x322327952b176c03,
+                    0x1903274f9be9da57,
+                    0x0074e3571c7256db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb5f0fa37f5de1718,
+                    0xc72006e7da8d1256,
+                    0xf98203a814468cb5,
+                    0x0783d2b73a77635a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5637ca5f835ccc2e,
+                    0xab4597c9ba23c684,
+                    0xd4381d93eba0456e,
+                    0x06efb96ec01bb648,
+                ])),
+                Felt::new(BigInteger256([
+                    0x751f190f1aee2c6b,
+                    0x820c10691fefb775,
+                    0x0760ffeb38b7640c,
+                    0x05146524c55be269,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x843304b660e0b6ef,
+                    0x4f5056041006bd59,
+                    0x28079dfabac55df8,
+                    0x039a36659bea8529,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94ac0227d738fcf5,
+                    0xa8f1d2f2cd61ef14,
+                    0xf6d0b1306dcbb33d,
+                    0x0312345013a0e178,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9ef3e1ca071651d,
+                    0xee16058a3ba9eb62,
+                    0xe5a47ad5cc0cd28a,
+                    0x07285ef57b6ff8b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f90a265c86fd23f,
+                    0x9243026429cb3d57,
+                    0x33eeb8bdcfa03763,
+                    0x0431cac5fcb576c0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6da1a8f91bb0aa5,
+                    0x20628d579bcf3492,
+                    0x5feb2fce334b5244,
+                    0x0375763449e961fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2eee163d9d27a162,
+                    0xf8cb11e0878298d9,
+                    0x0aca9be2169392a8,
+                    0x06a50b91afa8ba5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b05f519b0a1520a,
+                    0xc6efdbbefa365c4f,
+                    0x99ae813c0fb9e62d,
+                    0x026f34145fb20a82,
+                ])),
+                Felt::new(BigInteger256([
+                    0x32f89de76b059b03,
+                    0x84d1a2b0219515f3,
+                    0xadf2610bcffde4ac,
+                    0x049f6b56f3ea4f3b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe893596469a24359,
+                    0xc1642b432f3c377d,
+                    0x78b1d0f4af2c3a01,
+                    0x06d5dd1bf16567c2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f17653c9c79ded7,
+                    0x8e80b6715773a3e7,
+                    0x0cccfd63a35774c6,
+                    0x0155794c366adf48,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b4e99142b9b5ff5,
+                    0x6417239342fd9d74,
+                    0xaa9e94c5931351fb,
+                    0x012f1c786845a133,
+                ])),
+                Felt::new(BigInteger256([
+                    0x125dbb4666b2cb16,
+                    0x16b3e0a1bdfa4cd8,
+                    0xe9e529b31f9288a7,
+                    0x0570cf96ebf31328,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5be488a1fc8d3e72,
+                    0x523c401e076177de,
+                    0x6a10faa25a8d5ce3,
+                    0x07071d3268799c08,
+                ])),
+                Felt::new(BigInteger256([
+                    0x931b6b38008842fb,
+                    0x349fa1a7ef4a0a1e,
+                    0xeb17b7b00fb84379,
+                    0x07ffddd701ae1ce4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7dc26dd2b218547d,
+                    0x369a238f67d4408a,
+                    0xcc2af048f3a30eb2,
+                    0x0389a98dd9883412,
+                ])),
+                Felt::new(BigInteger256([
+                    0x65c8b0d3928c93c1,
+                    0x0c89151838898ab2,
+                    0xca54536eb0f41957,
+                    0x010657933bf4fe80,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6482168ee1d0c832,
+                    0xbc3aafac4255bcac,
+                    0x9d6508ecdf7c0d3d,
+                    0x0052e6630e443eb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbce2544a98c6d4a8,
+                    0x292411c2b72698c3,
+                    0x24e08c5c37a6a922,
+                    0x02b0236b8f5be5f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7577570e058c6f13,
+                    0xcf773aa9c3329f6e,
+                    0xba4ef2e6e18ff890,
+                    0x0125df2b42ce7865,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8831be374db8e88,
+                    0x552f3ea8ff98b58d,
+                    0x3ace74714a807e95,
+                    0x03586f79ff3efde1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x700e48976038f222,
+                    0x6f30c9deb379612b,
+                    0xc45894e0dbe4e28c,
+                    0x04357e4114812de2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66c175bb89a3ff95,
+                    0xb9b112c0ac13c35e,
+                    0x36e951d024225634,
+                    0x02ee0e82b90b88db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x70d0271487a17c09,
+                    0x0304e288b5f72245,
+                    0x2a0881c8d2315dce,
+                    0x011eccb6ac9f74b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09665a9a6fae28ce,
+                    0x625be00b871f672c,
+                    0x24d03ac73081f2b4,
+                    0x00653bd0919d0414,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 12],
+            [Felt::one(); 12],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb7992e9e23c4b8ce,
+                    0x0eb64a77a2395b14,
+                    0x27e7c212c2b52c9c,
+                    0x07afc7c2e6732606,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6e27b1c6462cd930,
+                    0xde7ed30798e21079,
+                    0x66c0197ca1afb832,
+                    0x00a36ac6f01f5f34,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1df85417f40b5fb1,
+                    0x95322547947a7475,
+                    0xc032afac6ad38a32,
+                    0x04cca88444bdc04c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x394f2846e65dc206,
+                    0xfcd1bbb0149af7f8,
+                    0xb1e97c6624809303,
+                    0x0110821451586f3d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f166271664c4b4b,
+                    0xa5f8c317aed2e2b1,
+                    0xbe92192f25499595,
+                    0x04fcf8a2b4901218,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe4a34a94ef7befa6,
+                    0xcbfb8149772aaed0,
+                    0x7a89bc6a103c18c0,
+                    0x0445dc812ee51d3d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18b352d644013e07,
+                    0xa15f2196ff706858,
+                    0x72645009e82becfa,
+                    0x049199e2bb4280f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa3a17ad968fb8f3c,
+                    0xd7736da74cda2b45,
+                    0xf66d63d3394fab3f,
+                    0x0276150f3b9967e5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83d905e82d5be48c,
+                    0x3f7164491e90c7d1,
+                    0xea5ad8a59c5aeb84,
+                    0x0350ab137eb56dd9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6500881461e0c04c,
+                    0xd8342fed1a635eca,
+                    0x8792d323fb454aac,
+                    0x07a2fb5295b67765,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa51e7870192c48f9,
+                    0xbc41aec0c559f174,
+                    0x7b01ebc13cda8e75,
+                    0x018da835adbfe4cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3ba47ec9a4b73b3e,
+                    0xc48310445b462a1b,
+                    0xac9d1cac91033088,
+                    0x0516b124aa002d0b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6474619c239ada05,
+                    0x6119703c56723bac,
+                    0xb1becc6906c5753c,
+                    0x015fe5e3f72de10c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd3c2d8d290bb59f,
+                    0xf8aa8ef136e5d930,
+                    0xbba888d68018bfa9,
+                    0x00a29e3614f6f2f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x86f943ebbc47556e,
+                    0x76906adf900d08eb,
+                    0x0694e228df1fcea1,
+                    0x05a3c2c2990f931d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c241f501fc7c323,
+                    0x92ece5ba49ce389c,
+                    0xe8e80ee1a7d37551,
+                    0x0416743a8a0c30f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x025213eb58c8f517,
+                    0x64ca36cc43133cd7,
+                    0xf07c6322458f8993,
+                    0x020cb051253c46f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe4d66a93d7a61a7f,
+                    0x8ddd126583768811,
+                    0x35336904c1bd657e,
+                    0x01e5a1e074abdebb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa593cb8e6df68218,
+                    0x704b5e66cc721d6c,
+                    0x7bafb1bd431fa62d,
+                    0x04bf79c3e4193b7f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f6e9ec3d566959,
+                    0xcedf401072eddfe9,
+                    0xa7517684d97978c5,
+                    0x0284b287eebe895c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd0be8de849e4e47b,
+                    0x0cc04b97da060baa,
+                    0x12b986e6dc666a93,
+                    0x073b23e03ec2b517,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc996b45b2480b0f9,
+                    0xee5121a85f6e418a,
+                    0x5c1a7c63c7012cb4,
+                    0x06aa1fef3aba434c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc4544e7ad26ed406,
+                    0xae20d2a7c5f9ae3e,
+                    0x19821ac6de6834e9,
+                    0x0226b0542e7edca5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2d3f775cd46eff9,
+                    0x97b428e98f58a568,
+                    0x3c61d910a6505683,
+                    0x02931db5ed14e210,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcce904be75d28486,
+                    0x547b94f8cb8aafcc,
+                    0x2d1d1758315191f1,
+                    0x076cf039226da327,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7b33d44dc244f85,
+                    0x86e4e13a84b4e8f4,
+                    0x54f231f6600c2f01,
+                    0x0350891a3bdaf62b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf06472e54947cc8b,
+                    0x5ec5ec9791df5d14,
+                    0x8a8343e129d77792,
+                    0x05ee501044f9fed3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x98b512e76b79ad4b,
+                    0xc481b4f942e984d4,
+                    0xe7326ecd66203b75,
+                    0x0137e3cf9e3917fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x596068722d8f4483,
+                    0x6636cf2840e3fe1b,
+                    0x5b04a122eaa18a3e,
+                    0x03396813950551d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2db5cffabafd3da3,
+                    0x47ab6a1b345f5fff,
+                    0x66feff4d6e2faa45,
+                    0x020e500cabbf3535,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ad506c81afab729,
+                    0xc5c12a593c419b77,
+                    0x53faf60462e9cea6,
+                    0x07585589316b0bd3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x45493bfb28f55cd3,
+                    0xb0bc2ce9e6464a5b,
+                    0x0ceb94b5729b1693,
+                    0x06679ecb718fe7ac,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3d6f9e38577a805b,
+                    0xc252cd82d89f14bb,
+                    0x81344e33e7997d4c,
+                    0x01a8f95faae36b2e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff0ddb42f7ce0cad,
+                    0x0faf764325a4f997,
+                    0x27ccf6af75b6bd32,
+                    0x044ca8990b8ce8f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3ae90da812865124,
+                    0xf018728b4de2f1fb,
+                    0x4084997cf5ba8a60,
+                    0x008a65b98d169e77,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd9c85032737e74d0,
+                    0x073986e5545bb458,
+                    0xad4f8c6033885687,
+                    0x001e6949c5db7f49,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x43a81b3f83294096,
+                    0x42f1fa2274d93942,
+                    0x9fef8efe73038893,
+                    0x0587f6997e65e0f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd1c6f673b6f00e45,
+                    0x8d1c5c9557538426,
+                    0x5426b24ba5d00857,
+                    0x0309df69a8259d79,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1183899441bd74d1,
+                    0xb3f8b149eacfeac7,
+                    0xc0ec91146c96b365,
+                    0x0311ac5b925b8af4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdbb1e09df5189e7f,
+                    0xa33c4e62c2f111fd,
+                    0x31a4e452d1e5d028,
+                    0x03026361fcb500d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe350179fd901d03b,
+                    0x3c0b85e1b111c4ae,
+                    0x02c1fc8588dc173b,
+                    0x04b7378e5226f7b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc21a3c8ecac8be7c,
+                    0x4729d2138cbd36b7,
+                    0xe543d0ca35d630df,
+                    0x01ad43b4591613a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdf9f2853d65873dc,
+                    0x491700fc1bed2558,
+                    0x72555e6ed1c444ea,
+                    0x02838fbe3e9ab2d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5276d5aa22769170,
+                    0xb60f824c2e577749,
+                    0x0ab06faf550256e4,
+                    0x01d6902f770dc402,
+                ])),
+                Felt::new(BigInteger256([
+                    0x42038004079d069b,
+                    0x96375c795b1323f2,
+                    0x24a7cb26624973fd,
+                    0x0104e70895a667c7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0155a79c03c597e0,
+                    0xbdc9c45663033216,
+                    0x59d5b4fb4d6d420e,
+                    0x0313ea732dc07a47,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2a2d8c58a5ec022,
+                    0x79fb5958b9e0f68d,
+                    0xdfdb18aa5e983e9c,
+                    0x0422def2a592fdce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7253ed6d1349e5ca,
+                    0x8cb173093cc4fccc,
+                    0xd3ac1acd040392f2,
+                    0x00d55cbb3dad880e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6561bb4eb6636dc0,
+                    0xec23ce7f2cbc9b4d,
+                    0x4aebffc27fd9012c,
+                    0x0626c01ddf1d6b9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe46c3c1fe4b9fc87,
+                    0x1a85e05b075aa8ee,
+                    0xd8c55412c48eab78,
+                    0x06eef5b2edffe8a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xadab31f171f23cd0,
+                    0x630b4cae0cc30a5f,
+                    0xcda72b7303b1b51f,
+                    0x04238b9d77aac8f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30b697261db933e3,
+                    0x02c62706d3bf9509,
+                    0xe2cd85ab7cb7e2d0,
+                    0x009a373fec1c6254,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca09df6d60318d0c,
+                    0x447bf60ef4bd26b7,
+                    0x78853a74b87b8381,
+                    0x007535794240350b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x04d25519e8ac5e71,
+                    0xd4bd8d6f5b7b5b49,
+                    0xace4706478090bad,
+                    0x02e6ba2e6dc2c061,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc00aeaab9cf37c8a,
+                    0xfe40fc30504e9335,
+                    0xf77bf949c13aff89,
+                    0x04e2deed65c3a0b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x996767e61eacce2b,
+                    0x7f4e1ae96c5972e9,
+                    0x6f5c1a3dee98993b,
+                    0x00862e87b020dddf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbdedc7106349d88b,
+                    0x7941047aed458a3f,
+                    0x02c6528a5de76122,
+                    0x01604a6c993811fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9976913371922c31,
+                    0x8f85ff52cbb899dd,
+                    0xbdc6628df283204e,
+                    0x0512151ae230bf1a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb9bcddaeac5d22de,
+                    0xb9739b8c52f4f1b2,
+                    0x673b1c6d526d9c1f,
+                    0x06a28f6c5b84f56f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ce55a08ff161a16,
+                    0x584ba1a0190868a8,
+                    0x84570c455fdca732,
+                    0x00af11affe63d313,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x50bc5957bccc2c4e,
+                    0x7488e4dd64fc0ba6,
+                    0x0ff28a3b66653316,
+                    0x06b514d0630b5a2c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8368076dd9a653d5,
+                    0xae578eb10eef39c4,
+                    0xfbb844ce64519058,
+                    0x0041036bcf112965,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2bcf378206793c33,
+                    0x38ed15a4467930ca,
+                    0x7f6e4d75256b6fc2,
+                    0x07e75df20ab931e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf5a6156d02cfac9,
+                    0x4d53cc7f2bb7be94,
+                    0x5cd4a05bc8062302,
+                    0x01d52b1b9fb2a8de,
+                ])),
+                Felt::new(BigInteger256([
+                    0x433a5d7a0cc0388e,
+                    0xdf13a23fea059b5c,
+                    0x584bb96f0f4e4f49,
+                    0x0464b34198965ee2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf5adbe6ca7d47215,
+                    0x1ef393c56ed95743,
+                    0x27554fcef8b3a52a,
+                    0x06f8feb93988cfe7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x470f59170d3ea67f,
+                    0x27b24f33ba63fa5e,
+                    0x9a3a3b915204273b,
+                    0x01dc49c6cf7875eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb77c25c624ea0706,
+                    0x9129675b38d2e13a,
+                    0xfb30c21fa4b51dc0,
+                    0x0289e976e0a0625d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa635c89d2d02d143,
+                    0x98c58e1aab07616a,
+                    0xaaf783755c072132,
+                    0x06346deeb18cc382,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1471f841ae78bf3c,
+                    0xdca5b27b16bec241,
+                    0xc5a1ed40aa458ba8,
+                    0x048c606ec5c650cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3c17628233a21450,
+                    0x5cd621a3b3fe5161,
+                    0xc78e82aa663f3784,
+                    0x077aa8b6c78518d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2754d223e395e9b4,
+                    0x370b3a9fd48ec00a,
+                    0x7545f5ebfed72d08,
+                    0x05234c45d8b8cab3,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 12],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd590,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4c2a297c7befcf7b,
+                    0xa5d3534580152f2a,
+                    0x2efac49552ba4fe7,
+                    0x058f3a9c8a5c8c6b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x392e95160a8aaf25,
+                    0x56387cfb47a6842d,
+                    0x8b16dd9af096d934,
+                    0x0011792a4c532b29,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0203e1c32322754c,
+                    0x346495e852a3fa05,
+                    0xc800cc91d7c97138,
+                    0x002eedfd9056d379,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef4c965ec1553b87,
+                    0x193d2b56291c608c,
+                    0x02a870982eb6fb7a,
+                    0x019b38427973ce50,
+                ])),
+                Felt::new(BigInteger256([
+                    0x00d8961b043534ac,
+                    0xeef7906cd237fac1,
+                    0x3849da86f5893a0a,
+                    0x01422e9797137aa4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57a6f6f09b9340a0,
+                    0x9ee416f4b5ec3527,
+                    0xc8788ebdf98af755,
+                    0x0452e4dfcac7fa54,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa96065b4f04674e6,
+                    0x2f52379a94fe19e1,
+                    0x70f3d48400fc312d,
+                    0x07c163f8e801b563,
+                ])),
+                Felt::new(BigInteger256([
+                    0x181071bb3789fe3d,
+                    0x92071b68c069b0c1,
+                    0x97edc8e472f64819,
+                    0x0193e362d3913b5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x691751ea9648da86,
+                    0x196513d226f8f736,
+                    0x20415c145488fc00,
+                    0x02ed8a4250c08122,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaa329255be573b5,
+                    0x111fef6389822184,
+                    0x19b8b5ec5d068c90,
+                    0x0496b69073bf2531,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2659d4d359883072,
+                    0x8c91141bdf94b63a,
+                    0x339ea101ec039976,
+                    0x05ce106211f44e40,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b5552a416bc4c5e,
+                    0xe052472c0ff11a26,
+                    0xb8e5d12f79f970fb,
+                    0x05d20d5f2a969592,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4fcdbb3cca6d04e0,
+                    0x544a5713c8df72b0,
+                    0x16689d08a9c986e9,
+                    0x00e8da2475b8ebde,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4d47da2b3adc966,
+                    0xc714839648a88db1,
+                    0x7360fbbb5e44cf70,
+                    0x04780ba6f38ad462,
+                ])),
+                Felt::new(BigInteger256([
+                    0x831c42a8ed4ee5a1,
+                    0x28cec969aa8d59d8,
+                    0x746cf19881073ed1,
+                    0x0226022ab72f2aad,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdacf74afc4d4fc74,
+                    0xc86b609be3d96fd4,
+                    0x11c1d7dc08eb9a6c,
+                    0x0657495ae933c970,
+                ])),
+                Felt::new(BigInteger256([
+                    0x952d6903ab81b212,
+                    0xbafd4e67308e5cf6,
+                    0xbfe718d26694167f,
+                    0x075603e20ce4997f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd785789b1b1df94b,
+                    0xee95a060c248fb17,
+                    0x63b1f5caadcad84a,
+                    0x0478d47ca0a3832c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80656974d4a26d3b,
+                    0xf75f3e391a8d54c7,
+                    0x486a457556d3d2c0,
+                    0x07b566ab91ab49a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfa4e80dcb0a4d3f0,
+                    0x6ef8e1427891c02b,
+                    0x0338f7b14058a399,
+                    0x057f5ba6c64277b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcafffc8004abc6de,
+                    0x7b99ee896003fb45,
+                    0xce7163530e9ed92e,
+                    0x0382645de325a0be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d004ff92b6f8bdb,
+                    0xa7dc5af10513ef29,
+                    0xec776ffbac048229,
+                    0x012f78721d04b306,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b321512bff0acfe,
+                    0x63f90e199dadee6b,
+                    0x5e7d189400ed2e51,
+                    0x02080037d51109a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x46be85c906d220b0,
+                    0x138d1aa0851f6e30,
+                    0xb56d4acc0bbc2126,
+                    0x01d23b91f1008c43,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x89c2d06c2170b732,
+                    0xb6cf984b9033f9d6,
+                    0xef73f1a6d1aac658,
+                    0x05dce17095b0cdcb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89bbb70587f78546,
+                    0x2d49b9bbadfdcbbd,
+                    0x12c8cf5fb5a240f1,
+                    0x00a1567dd8e360a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ad286639f5ab19d,
+                    0x4460e7b17ee540f4,
+                    0xfdfe33cc3f7bee98,
+                    0x020f94086485d54e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe7b5e1fba5af159f,
+                    0x2ec5080851db1d65,
+                    0x1376fc503be0c4bf,
+                    0x04788ad289555d27,
+                ])),
+                Felt::new(BigInteger256([
+                    0x12d2eba2add65c3c,
+                    0x3bd059e5558cffb8,
+                    0xbce29a88a2ed60fc,
+                    0x05c79463a5b1d2b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf716294f1517e065,
+                    0xe7beb8f1a96dcbc8,
+                    0x6317ace1e56e0e47,
+                    0x0495ff592ae318b1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4d6e5c49954bdac3,
+                    0x2925c7f576b43e55,
+                    0x6208aee6e5677d86,
+                    0x03ca388479404a70,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfa3a6d08d17b1469,
+                    0xc0609836cc497d21,
+                    0x11398d31aad4c475,
+                    0x0335dcbf1598d52e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfdafd5dcaa646b1d,
+                    0x2e16d885f4bb66a7,
+                    0x07ffca213c51e672,
+                    0x048f928be9063d70,
+                ])),
+                Felt::new(BigInteger256([
+                    0x27d69ccaff032c86,
+                    0x3540f5450d15e360,
+                    0x9246a9e064b488d7,
+                    0x02691d4149316ce2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x96f31e0cad6f6a10,
+                    0xd77a6c9b35770daa,
+                    0x76fae3af9b9a4e5b,
+                    0x020f2e0a7fcc31f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd5e3afef3b2e1a51,
+                    0xd806fef0c28dfe61,
+                    0xd62b99c565030cf7,
+                    0x0757f732366eeca5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb5ef125fd171af52,
+                    0x501e2dfe5a28e56e,
+                    0x6ad0592b95808522,
+                    0x0774149f81b96e8b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x641452a844e1db66,
+                    0x107507becc0720c7,
+                    0xeefa50586accb0f4,
+                    0x0391ae432d0db12c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc80f240c66d1674,
+                    0xc562e7cc481e5ce4,
+                    0xa87ca6ded25b69aa,
+                    0x00d1d2b352bcd68c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x95e924a4583ab0bd,
+                    0xb9ef29af23207549,
+                    0x8e3d712590eafa66,
+                    0x047ca7a90a970beb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x353b7355104d8894,
+                    0xd7c20574215eb99f,
+                    0x06eb72d0b9516522,
+                    0x048a8ecb8f0cdf76,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5f7f524f593df6fa,
+                    0x99c65255a7f09d99,
+                    0x8e1e1bbc9b4a3c3e,
+                    0x07f0c838f5cdcab8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdc6544b0c5639448,
+                    0xe392d642715fdbc7,
+                    0x8ca2790b18a007ba,
+                    0x07e2e079d8456adc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71763344f62821f0,
+                    0xa6890bb4807bb357,
+                    0xe88cab9af2711f9b,
+                    0x03701114ae2e11a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1d81662fb62d79e,
+                    0x15cf31104d0fe088,
+                    0x8f6b891af5e5b795,
+                    0x031359b15bf21e11,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5fc30392e73ef782,
+                    0x1bff40ec1d3e20c9,
+                    0x07924324e7f0cc2d,
+                    0x036d2d847a0a92a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87e2f17cc08ea088,
+                    0xe1a95a939a5ad98a,
+                    0xf90111ca18a86e75,
+                    0x05250719fe3f7be0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x289ce8e446519bfe,
+                    0x67051b00f58d8e60,
+                    0xa7a41fa07466aac0,
+                    0x056705f4db8dbc5a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x24c94599bcdae820,
+                    0x8dc14fe695f49ccd,
+                    0x7fd1b37eacfc2edc,
+                    0x079b9e1dc260c3a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc4c8be93025073dd,
+                    0x485e148138257986,
+                    0x52e82ae07799587f,
+                    0x02202090927ef3d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa53acd103f3ea17a,
+                    0xc211d63d38767ed0,
+                    0x58d2194ba4955bdb,
+                    0x0213cec9d1255912,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26debb6434cceeba,
+                    0x4ceb2d3bff38c5ba,
+                    0x9b75b96789a62623,
+                    0x05deadeb681ac732,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1951db8eeb028e32,
+                    0x4799d9f72a25a9ba,
+                    0xbe57f5afc1121b13,
+                    0x01db0c5fe45b1503,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7df1bcdceace06b7,
+                    0x4566b733b080ec5e,
+                    0xf9e0154116d166d8,
+                    0x042adef21f9a3347,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4b689b7e31452462,
+                    0x3bef9cd0679e101a,
+                    0xaafd1b3cd4710d00,
+                    0x046387f6b887e5d6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x10d35e329f1a195b,
+                    0x8969eb5d31a02d5e,
+                    0x792eb75a24e087b0,
+                    0x00a9c65da51e53ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ca6d98c7c1aa98d,
+                    0x9eed11f158618e6f,
+                    0xcd68fb6645d9f525,
+                    0x042dfc7ed7e47172,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3acce1d996df2f14,
+                    0xc523afba76fe2e13,
+                    0x0ffc698f34f54e61,
+                    0x0560eac396f3f624,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6a9ba0c1c741116d,
+                    0xdfcea2604fd2291e,
+                    0xf54335c80fa0413f,
+                    0x05cf6bf7697bd0d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe7265d30041ccfec,
+                    0xd871f553e85638c5,
+                    0x84766d216ee63533,
+                    0x031978642a3f6e06,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2731d695194de718,
+                    0x180e88582169e681,
+                    0xa2c2b6dd7bcf0a2d,
+                    0x05ce5720253775cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7afea90a3796a6cb,
+                    0xa185d2de08cd0df7,
+                    0x172d258260ea12a2,
+                    0x025626378d2bfd22,
+                ])),
+                Felt::new(BigInteger256([
+                    0x23ad9643661b7d74,
+                    0xfc03e8723e2eb35e,
+                    0xb889a959580e76ce,
+                    0x05de1a5023f1f1a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae72d1a72eb2471d,
+                    0x18aa08d3e7bcd3e9,
+                    0x52199aec31209e6d,
+                    0x06aa7291bcbdd9cb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fe7a33852fe8c8c,
+                    0x019856922a3c6814,
+                    0x487d009a37b5cff3,
+                    0x0304d67b2b53d0fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x800123a468dac3e5,
+                    0x3f50474271403071,
+                    0x0a1758af65afd31a,
+                    0x0194a0a8e6aff2e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfa69926eed44deb0,
+                    0x349e93cb7d316d55,
+                    0xfd034b047ed68c8b,
+                    0x012853931a0333dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc76d10d246eded5,
+                    0x958e882628e1432f,
+                    0x82a486f9aaba7e34,
+                    0x0646e34481e224ca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5d74a619887a354a,
+                    0xe7d71a355043ebdf,
+                    0x3fac1f52b4cf4324,
+                    0x04ce37fce1c621a4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd043939fca7e9f46,
+                    0x775bdcb37aa1fa0c,
+                    0xf0e75497b7bc3d32,
+                    0x06aae06eab8e8470,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc75e757b1c62050,
+                    0xf1913e8d8f38974f,
+                    0x367137f367ee39b5,
+                    0x030ce09e9a439dd7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5b66931dcac1fdf9,
+                    0x103532bcce982000,
+                    0x52458defac2bfafe,
+                    0x056e13f9d5c6a17a,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,690 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger256, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 2 field elements or 64 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 2;
+/// 1 element of the state is reserved for rate.
+pub const RATE_WIDTH: usize = 1;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 1;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = RATE_WIDTH;
+
+/// The number of rounds is set to 19 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 19;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    state[0] += round_constants::C[step % NUM_HASH_ROUNDS];
+    state[1] += round_constants::D[step % NUM_HASH_ROUNDS];
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(STATE_WIDTH) {
+            for (j, s) in state.iter().enumerate().take(STATE_WIDTH) {
+                *r += *s * mds::MDS[i * STATE_WIDTH + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x9dff9d5d17b3f3ba,
+                    0x3a1bfc60d78d0930,
+                    0x426eae6ce13dea34,
+                    0x035687dd465012ef,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcdceb03504bf4bcc,
+                    0x95a30697626089db,
+                    0x9ddbeebe958b3947,
+                    0x019d19bce118ba6f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd3cdb1f150379b8f,
+                    0x53e6ec23dc8609f4,
+                    0x53738d73c5f9cd6b,
+                    0x019b15133be737e5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xadee2b14e2b341a1,
+                    0xf7cc10eb55ca2001,
+                    0xbba54e5f0d679af9,
+                    0x04a87a8bb71b2f00,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3c475658b98dca67,
+                    0xeb2dfb6689b9a136,
+                    0x560bcdb44d749ef8,
+                    0x07206b5a817b8bab,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa4b3a2ccac54eece,
+                    0xaadad8af76d41d23,
+                    0xdf4fb410a5722f5f,
+                    0x00a648063b13ffe2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xce1456343bf82bd5,
+                    0x7fd7d379c688437f,
+                    0xa6a0a621abfb07a2,
+                    0x06bc69a88ce7982b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d7875e97868fa01,
+                    0xe055da2bf8915943,
+                    0x9b4fb0de6ea51d63,
+                    0x0637b2a3db59eab5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x081858a203bd53e3,
+                    0x257be9eac2020b8c,
+                    0xcd973e6dddcefcbd,
+                    0x048f375966f26b9a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b0d85d2e4471ece,
+                    0x02217d05d693bcae,
+                    0x8073d008737a37bf,
+                    0x00853126543fcb16,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe302289fb5f73a79,
+                    0xe7ac715655df0f01,
+                    0x45281f4909c009b2,
+                    0x04f3352d1014f6ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0x84e51974289d1140,
+                    0xb8779dd685115ed3,
+                    0x9fb686e519886d3e,
+                    0x0450e6e2f09b9ad8,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3b9e860585f9c74e,
+                    0xb663b756bd599048,
+                    0x9aea554e5818cd28,
+                    0x0616b3c2744d798f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf450ea215cc364c0,
+                    0x641d0cae4c353978,
+                    0xde831b6ba0a4db89,
+                    0x07ab56c609991ec4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc8589e832d8229ce,
+                    0xa81ee10c248d9c67,
+                    0xcf783523a9272256,
+                    0x055d351552d721c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x65ff6b34f8cd5b41,
+                    0xc9a5d3e5446a70f4,
+                    0xa39a43d8341ae3ee,
+                    0x07b5d2adb2c41fab,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x22098cf76361e133,
+                    0xed1d11ac3c711af1,
+                    0x6eb3198297e04bda,
+                    0x0296ee60e4e09795,
+                ])),
+                Felt::new(BigInteger256([
+                    0xddb04fc44e2bd3b3,
+                    0x72ffb3226f7a3fa0,
+                    0x5f18f4e1dcf595db,
+                    0x042427b633885cba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc6420afcf97313db,
+                    0x62837f5e2e99a00d,
+                    0x3245f7fccc68d130,
+                    0x043d3ba3588850f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb3781ccea5238aa,
+                    0xa67b17558904f04d,
+                    0xb0eec822dd47b6fb,
+                    0x019401edbfec1d37,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1b3efeb9f294ee7a,
+                    0x8009ffe5c1013983,
+                    0x282fddd572dc198a,
+                    0x060396b09505bd64,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcb973c8d84f9d679,
+                    0x628d3f976caf1509,
+                    0xabb394af835f44e5,
+                    0x0476c00ed84c56d0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xceca3a1bd1387e03,
+                    0xbfa00db69d7d3160,
+                    0xcb34c215be0cb8a0,
+                    0x03bf2795ac341e7c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xebd725ed8a20fec2,
+                    0x2e9050cefa7e03d7,
+                    0x4b9943cb5f5ea03b,
+                    0x05f3c323c741b8cd,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(), Felt::zero()],
+            [Felt::one(), Felt::one()],
+            [Felt::zero(), Felt::one()],
+            [Felt::one(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0x6f37d84b48a9af2a,
+                    0x6a1f43c227c02bc5,
+                    0xdf0508a5c76b87c7,
+                    0x0088ef0bbe4f8e37,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b3210ae4b098aa0,
+                    0xca4b21770665fe95,
+                    0x31d556d2f6b0fccc,
+                    0x04def89d25258d61,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x57242aa9c6c12e16,
+                    0x8ae467d05bbc329b,
+                    0x714bb8aaebbb4d0b,
+                    0x01453b2ecc2a68fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x07e86b3479c58c5c,
+                    0xc454ed0d31ecbc76,
+                    0x7f6676a159d743b5,
+                    0x041a0eb24817386a,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd9a150da31f0da2c,
+                    0x0f1313698f27f4a0,
+                    0x16ade3d87bcff589,
+                    0x05325c2f22321e18,
+                ])),
+                Felt::new(BigInteger256([
+                    0xadb3363ff6247e91,
+                    0x2e0797c5802e8c3e,
+                    0x57f3a5a59225560e,
+                    0x07afd6762eb3b7d2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfd42c9deb7d5f35f,
+                    0x3fc5dc926588c5be,
+                    0xaba5174e8d28a13c,
+                    0x0124a858844dc848,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf63a202cf41ffedc,
+                    0xf267428a18a9c610,
+                    0xd902635e9d6aef22,
+                    0x05ac5d090104f045,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x23c7dedc4236251b,
+                    0x6b3624dda9fca8b6,
+                    0x90664a6ab69b15eb,
+                    0x0122f3e5a727e391,
+                ])),
+                Felt::new(BigInteger256([
+                    0x46efd37a2baef979,
+                    0x5ff81117d63690bd,
+                    0xde899f372c4a20f9,
+                    0x014b566cdc4bbf48,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x28bb7051bbbf9c2c,
+                    0x5885dc8607178fa4,
+                    0xcea7ffd670bf3587,
+                    0x06b9687597de715a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76c42ef32b78413f,
+                    0x6a509b44bf432c72,
+                    0x02d3a54d64b04544,
+                    0x02f2a6a996c03643,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xffffffffffffffa1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff9b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffec1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffead0,
+                ])),
+            ],
+            [
+                Felt::one(),
+                Felt::new(BigInteger256([
+                    0xffffffffffffffa1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff9b0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x20ce0a5629c64f09,
+                    0xc900a8273af22785,
+                    0x74850d1eab7e7e2d,
+                    0x0725d8e32dc0364a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d9c2fb0c85c77b8,
+                    0x254d19ecb73c7524,
+                    0x8f647e2ef92c7756,
+                    0x02508346ae66300d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6edd6c473411d329,
+                    0xd7e32ef7f18267fd,
+                    0xef7f1c8ef941182c,
+                    0x05936745a470122a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5480b00a15fb05d5,
+                    0x4bfe79f50673f46e,
+                    0x4de3cc4e459a8c3c,
+                    0x04d4448335676ec9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe2baf39a145e55dc,
+                    0x9929daba0fb3995c,
+                    0x1e88d4c9323ff7b3,
+                    0x0441df91ae4d455c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55e4110e333f8023,
+                    0xf98527f3af495855,
+                    0xb38e240128e53d28,
+                    0x0475752b399b87c4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdff12a659435eff1,
+                    0x16fba430af8617f1,
+                    0x36ac416a65696ea5,
+                    0x0229bf73875c98f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x960d9f5db0c1ceae,
+                    0x375a2f1c273c0de6,
+                    0x7d07279dcda73b12,
+                    0x04299b63971abb1d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf897594ac5431186,
+                    0x8b1e58252ca05aed,
+                    0x2c0328103b7978d7,
+                    0x0504f72c3c0b216c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30b5df5a7b782e09,
+                    0x015319875c17a187,
+                    0x62931767deb68b80,
+                    0x005a3bf1906d236b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8d07fd2b3e285fe8,
+                    0x9777ae5444e114fb,
+                    0xd722efbe9ed00554,
+                    0x07915c725c1f1412,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ddc2674e5f160f4,
+                    0x30b7a6418de66b65,
+                    0x883c748941205542,
+                    0x01a6bc00ab1d7248,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
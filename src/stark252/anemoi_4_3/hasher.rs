@@ -0,0 +1,1759 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 32];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 32];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 32];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U3>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger256;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![Felt::new(BigInteger256([
+                0x8b0749eca2300bd4,
+                0xd30bed9d500528ba,
+                0x723bc819adf5b807,
+                0x06b1b6836e8b3fe5,
+            ]))],
+            vec![
+                Felt::new(BigInteger256([
+                    0x1083ff3ee2bcd4a5,
+                    0x4edd8179a4ea45fa,
+                    0x5204b94722e432f4,
+                    0x00db45f8fe5fb4ca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0cbdbd7c0074ce2b,
+                    0xae2cbf189476fa46,
+                    0xf2c463a44884a7c8,
+                    0x020cebf938406331,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x10243d9e015334db,
+                    0xefb29ee8822dc45c,
+                    0xf3a378105f98fc93,
+                    0x05669f7188ca357c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x325754c952146cf9,
+                    0xdce9d42aa53fd69b,
+                    0xac8a4a02c5e4fd41,
+                    0x0669219ee4457a42,
+                ])),
+                Felt::new(BigInteger256([
+                    0x420ba3a6851a6b16,
+                    0x732f17c1e9db508a,
+                    0xf3f8f043351687a1,
+                    0x0187decb7979a579,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x6873f903500a108b,
+                    0x1f589981436d2c51,
+                    0x5bcb11578b4ff7b5,
+                    0x03df40e8a0d188e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6ffc53b4ed704de,
+                    0xf49620e07bb2ab7d,
+                    0xa936bcc6422fc269,
+                    0x031237b74c557ec9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x16dbb48a9ff0113e,
+                    0x24908af01f98be0c,
+                    0xfcf3797d3fa97e1a,
+                    0x0593abbbe37f7346,
+                ])),
+                Felt::new(BigInteger256([
+                    0x182ecba30f9429a2,
+                    0x05fccbb2951cb9f3,
+                    0xc3974ef167973bf0,
+                    0x071a8993bf23418b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x78552264d0910e6c,
+                    0x2854d62a7390cf77,
+                    0xa8173bcea27b3eee,
+                    0x03adfce6773e6737,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd3b28535ef953826,
+                    0x0f786e3898cdb455,
+                    0x180d002e3368d74a,
+                    0x06ae4b4ccdf6b830,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ecd327a4887b03f,
+                    0xa2cbf8e4adad7b26,
+                    0xaae0592dc073f176,
+                    0x075fafc191ab64d6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x312eb40c86a0be02,
+                    0xbf2c338e6caaef37,
+                    0x39deeaa153ebd8a3,
+                    0x0710350b93675c18,
+                ])),
+                Felt::new(BigInteger256([
+                    0x657fcd9290537551,
+                    0x29e63e0c765c35e3,
+                    0x6f8ae63a2413451e,
+                    0x005da2194b6a0351,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x5d3759ee6e8acc20,
+                    0x978ec2ba982a41d1,
+                    0xf51eb810fed5860c,
+                    0x00b7d509517d5f65,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5f8fb46a068e328c,
+                    0x4e5d57cb1fa8acca,
+                    0x4d3c58e52549b06c,
+                    0x01bf73c523533d2e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf5d7005bb01adb7e,
+                    0xeccb3db4eed01b36,
+                    0xa748bc259016021e,
+                    0x01a7483ce03e7c5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x65a2175b7ee2a946,
+                    0x14c1cbde87e4d075,
+                    0x69ff91e79b1a488a,
+                    0x05e052a77e57ce6a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e3fa18cac461551,
+                    0x9a33844250eb3102,
+                    0x43edb3c53fdfc9ea,
+                    0x059d0d9d6397fdeb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd5c388fcb9723fb,
+                    0xee8c1ac122db8bd4,
+                    0x9ff6310883c97671,
+                    0x0423c0ac10b9590e,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0x0c1f189fea39f848,
+                0x89f34e6c98c3af94,
+                0xc50d6257a0599865,
+                0x079b46da158ed9c1,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xb4358fe9de07951b,
+                0xaa11e69f2ef6a9cb,
+                0x33bc719db5f12351,
+                0x06a746ca315a4abb,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xc8ec3e7b40406235,
+                0x9820ef7d587f64df,
+                0x7d3a938024fb135c,
+                0x00b564c805c63939,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xc51617372b7b6503,
+                0x1ccd8668c40aead9,
+                0xf85babb4d440298c,
+                0x0607cfc8423c91c1,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x2e18d13ecbc2bcce,
+                0x2e0c61a95063cb75,
+                0x36f694bef77a2ed2,
+                0x01dcdec7e0143535,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x01d4cb87818cd3e3,
+                0x918f5aaabfad129c,
+                0x6be0031a410dd945,
+                0x010654ea46e83acb,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x76cdaa9e699d1ae0,
+                0x01d9c9788738136a,
+                0x65bf55075e68b807,
+                0x05fb17ef0572d3f2,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x9cd6e9e79918543a,
+                0xc4017195ebd1b22e,
+                0x387052b0d1465b61,
+                0x0047359a006e3e6a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x38bcf51e720bab62,
+                0xf0edc6ce0612df75,
+                0xcc2280f402dbdd7a,
+                0x079b9d606a40e3e0,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x56d3e5df11a12bc3,
+                0xffdacc17d9d5dbb0,
+                0xaa110283220886b1,
+                0x05476224026ba8ed,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0x0c1f189fea39f848,
+                0x89f34e6c98c3af94,
+                0xc50d6257a0599865,
+                0x079b46da158ed9c1,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xb4358fe9de07951b,
+                0xaa11e69f2ef6a9cb,
+                0x33bc719db5f12351,
+                0x06a746ca315a4abb,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xc8ec3e7b40406235,
+                0x9820ef7d587f64df,
+                0x7d3a938024fb135c,
+                0x00b564c805c63939,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xc51617372b7b6503,
+                0x1ccd8668c40aead9,
+                0xf85babb4d440298c,
+                0x0607cfc8423c91c1,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 124];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger256([
+                    0xeb8696073ea751cb,
+                    0x02a87cc9d2b43089,
+                    0xbb5c460089d2b389,
+                    0x0205c330740865a6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1c15c99d8cb5753f,
+                    0xb60f40feda7c8409,
+                    0x0f18445da05ecc54,
+                    0x009220442a37b2fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x49c718485a04fa98,
+                    0x5e5cb573e0e136bf,
+                    0x9168b9f105fffcfb,
+                    0x045889e0fb20cc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0x58a1217cbc0806ea,
+                    0xab78df8ebe3e3e4f,
+                    0xc04df3677dc7d3c1,
+                    0x000312e12808bf19,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x838cc6ba6dc14b26,
+                    0x049ae44498b82327,
+                    0x50f0d6a2eff572dd,
+                    0x07bf4b14bb26c75d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbbb7e8096035ebf6,
+                    0x016066d0b1e6d748,
+                    0x203d422cd7173c89,
+                    0x03c2ba6cf203ef47,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0df6e9d219f04083,
+                    0x42f4aae8392213cb,
+                    0x68dca6db6e4069d3,
+                    0x07ac647d9e576697,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdd816c5dc68e98bc,
+                    0xedf7552551ec5ac5,
+                    0x29e3a7b17e2be252,
+                    0x003522abe1b0ff84,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xf1b0b68174ccf7bd,
+                    0xc59f9e50c4d33a63,
+                    0x5407f75d687dbbe0,
+                    0x02708a2ffc7f328d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2eb7cb9c0f4ae29,
+                    0xb6aaa18e361165f8,
+                    0xb39d1e1b0f4d637c,
+                    0x07a9466960b171dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb464352fddeeecff,
+                    0x88bd6087d4c07f18,
+                    0x2ec2672e74001d3f,
+                    0x05dce9e452e2e85a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf097bedc1d65228,
+                    0x8f4bd139f19b637d,
+                    0xecb53e4c38da4916,
+                    0x01d46517e608b472,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xd24592a99414d64d,
+                    0x099c822348067a6e,
+                    0xfc577743ff279730,
+                    0x0778f4a32bab6623,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd95b9ff7c925c19b,
+                    0xaf3d9fc372f426aa,
+                    0x23c367f2f5448eef,
+                    0x06cde84afcf730aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x15c2ad936425ceab,
+                    0xafb85aa740fff501,
+                    0x00e2304f34e30bc6,
+                    0x032dfd9bec22f422,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6d9a0c69f882a2f5,
+                    0xfa25c33c7d4bce1e,
+                    0xada596b90fc20af5,
+                    0x0235ad6e17aa6ea1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x95227af5b1b3d4da,
+                    0x743f713182b0c3c2,
+                    0x0eb920f2c700e0cb,
+                    0x048a96b23461d52b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe56e413af7e0a675,
+                    0x11c155deba4e8b0a,
+                    0x29226775e01cd238,
+                    0x007db2e5d0207044,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9c43b56729d2a9b2,
+                    0x4338131e475abb89,
+                    0x6ba853e0479d5913,
+                    0x06423191af221d05,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1398560052b5c4d2,
+                    0x501e2aae04efeff4,
+                    0x2c6fd6bfd256e18d,
+                    0x00b35671309054f6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x2fa4f3f19b3aca78,
+                    0xefb12e2138bc86ab,
+                    0x2b89e60d7c4b5066,
+                    0x0206d79fb76207d6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd9efec77b880239,
+                    0x86a80b07ed659f0f,
+                    0xaf9a06a68ec0cc10,
+                    0x03ef0c5e2cfb1dc7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x01111784e27ee404,
+                    0x74ad0e37585d4d60,
+                    0x7541517e13b8e64b,
+                    0x049e9c277b67cf8f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x85cd75ccb0c7d4ff,
+                    0x80393c31a967da11,
+                    0x3e3a0a1622fa7b53,
+                    0x036ea0be0ad26803,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaf660129aaf0d5a,
+                    0x8d909a9182191ac3,
+                    0x302b75b0041def3c,
+                    0x0631bb21ed4a89fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5a7c493aa824529d,
+                    0x476c48e85654243c,
+                    0x997153a90ad665c9,
+                    0x027e0c82ad3c79a6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1df28db157118fae,
+                    0x3e2de1337ab1032d,
+                    0xccad7fe5cc6a8c6d,
+                    0x038e959a870695e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9ea9bf9f7a07fcce,
+                    0x85939f144c31dcf7,
+                    0x35b035fd0cfff4da,
+                    0x01eb4b485f7b530f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe89c64c1dbaba496,
+                    0x05c6ee3a89c130c3,
+                    0xb579c21d86671fed,
+                    0x070a4f373e68b526,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fe9332d02e700d,
+                    0xa67463d82a27f0eb,
+                    0xf798920389a92f81,
+                    0x02607a6b228344f5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x803bae1563e77d71,
+                    0x10ffdb653b5227e7,
+                    0x9041e861b5bc16a2,
+                    0x05574a47ceb0300f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb9c78c742889dcb,
+                    0x637440028d80646b,
+                    0xd02bccf125f0a71f,
+                    0x0634ba52c96564c0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3d85cde1b09ff8b0,
+                    0xae98153d99130b65,
+                    0xad99bec933061a6b,
+                    0x043c40695f9fbb6d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3bcd07f88643cb84,
+                    0x2c5d4b59c93fba66,
+                    0x52489a7d29562a37,
+                    0x053416f27d10d779,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x722db2720c753744,
+                    0x93f7365e93b4c4f3,
+                    0xc12abecd743d5ccc,
+                    0x042a7be6a343f0f0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x272be386ba0e857e,
+                    0x7dd7f3831ba367d1,
+                    0x62f61152f1d961fd,
+                    0x026ecaf3eeaa47e4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfeeeccbc5a04f927,
+                    0xf900404c2eaaa2a3,
+                    0x2e0da15c75ea6cad,
+                    0x025fbb6415c91ba1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x914c35c1be831fc0,
+                    0x20e730756b88f350,
+                    0x18fa4a8862c5cf1e,
+                    0x017bb9528c94e4b2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0de9bf8ccc74c725,
+                    0xc03221dd21086178,
+                    0x054fe4ee3c8e3e39,
+                    0x049f04c051e398f9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde2a173df3f03ab4,
+                    0xc997a2501511d1de,
+                    0x5ff65c91cd6c5f62,
+                    0x06d8f86b354c5b17,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5b37272e9b73ebea,
+                    0x69b49336a9914b3a,
+                    0x14ea1af3b7446627,
+                    0x00433270e1a62c8e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe738ace731976755,
+                    0x1d102e38767d8c8b,
+                    0x070593e3e4116b2d,
+                    0x03fe350098b569a9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xddfc6bc8afc45a9e,
+                    0x0a74521fa9914557,
+                    0x0dfac467e9b1b242,
+                    0x0399c2d5ac91001b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x996e760e30fae151,
+                    0x3954a34db41824e5,
+                    0x103d3ff837f4a508,
+                    0x02999c8c84d78a62,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 4],
+            vec![Felt::one(); 4],
+            vec![Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            vec![Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            vec![
+                Felt::new(BigInteger256([
+                    0x2acf66a2a239136a,
+                    0xaef2a81b843c4779,
+                    0x991cf7cc7d1eb2eb,
+                    0x01988b9aac43474f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60ae8eb7a0d4274f,
+                    0x33d8694f53dfdc73,
+                    0x34dce470bedb43d6,
+                    0x0007e57ddcef1a90,
+                ])),
+                Felt::new(BigInteger256([
+                    0x181aefd900412175,
+                    0x57b784a5999131e9,
+                    0xc60c0d4ecd02845f,
+                    0x0573820fb5d23b60,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa211a837ee9985bb,
+                    0xed91f81be28678c5,
+                    0xecf841cdc3e51406,
+                    0x0747b0e3f3f480d7,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x6be70f3cdbea1e24,
+                    0xc89d0931e70b8bfe,
+                    0x33b97f8d2a3e28d7,
+                    0x010e8b23fa49dbe2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xafe53439785bd12b,
+                    0x4976245a9ed4d680,
+                    0x85b73b8943202fb2,
+                    0x06401eed4be60889,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7460e79805e21f4e,
+                    0xb337688ad217f1bc,
+                    0x479bd352f5a00df0,
+                    0x03e92f2e7278d045,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8be78ca5bb4c2e1d,
+                    0xcdfcb00c8df4821e,
+                    0xd5ebd1170209e266,
+                    0x06f1d8926e292107,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x0846baebbe2511a0,
+                    0x3606456b1e954a54,
+                    0xd23c29c7aeef977b,
+                    0x070a95b41ab28b38,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1fcac098c36d3579,
+                    0x9f81c9c363a49eef,
+                    0x6656cb15ff8b60e6,
+                    0x077ece3702033c4d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a88ed2908190b25,
+                    0x8fab9687285463ef,
+                    0x902f3ef39e330d21,
+                    0x0272325db928c300,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd648f2149bc0c53e,
+                    0xb6a806663238326a,
+                    0xad6343dbb1d22390,
+                    0x06d773c03aa4c7b2,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xb31311b6ea76311b,
+                    0x35ee68c345c6bcc5,
+                    0x9fd1efa60467e7c6,
+                    0x03d0497f1bc17ea1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7a7d8bd2443d6107,
+                    0x85d7246132545136,
+                    0x93794f9923a999c7,
+                    0x04ecf8dce871c0e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f8e42d69c200ff3,
+                    0x112d512343c70210,
+                    0x6cb47bdf4307d3c1,
+                    0x0750dbf35ccf4578,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd5d759ae3b44d25,
+                    0x5bd7a3e41ab87645,
+                    0xc657d494c56f7726,
+                    0x00ff9e7361699088,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x0906c750dd70c2ea,
+                    0x83d2c95d85d3025f,
+                    0x002a066e5e9cf9c7,
+                    0x039267f34196de1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf77b8c2a5951204e,
+                    0x96b3a6ee28753558,
+                    0xb0f10da13c35a809,
+                    0x069722716816037d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x58eeb35bd2e59985,
+                    0x4d56a64fef59cb4b,
+                    0xe68bd04e86016bd4,
+                    0x0376badd971d4cc8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4404260622b2156b,
+                    0x76c8db024563e56e,
+                    0x2804ab2341b81094,
+                    0x02fb247d358159f8,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xff647d9f875e6ea7,
+                    0x08471fa6ed76e359,
+                    0xd6194f2d796d60be,
+                    0x0271a2d63cd10fd1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7534bc1f753f7cc6,
+                    0x1b091c1e5a7f15d5,
+                    0x74b6a5dabb600859,
+                    0x0229d916e98d10ef,
+                ])),
+                Felt::new(BigInteger256([
+                    0x626ff724abd6d105,
+                    0xdff8ed26674e0747,
+                    0x12b777bb9b763d73,
+                    0x06e8c0b550f6c7d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25695cdc59400b19,
+                    0x93a0c9e688e8540a,
+                    0x94f319c7cdcadd29,
+                    0x011937dec260ac63,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0x0572a94d42d35ff6,
+                0xd4fce379d86d3f00,
+                0xc99cc9590ef45505,
+                0x00afc7a49a870390,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xbc9c4d50d1198c7c,
+                0xc3c18047c6e2e024,
+                0x025db5e2d96a8147,
+                0x0579e0e2e681e8f0,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x809af7f4abda14a2,
+                0xac3b5212b3e921af,
+                0xad12542110104f6e,
+                0x016ac9a260ebfa0b,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x5bd826dca6701b3b,
+                0x74741b67c8d28c53,
+                0x606db552dbacbdc1,
+                0x038c049a981594bf,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x1d728046e95d395e,
+                0x971f87f05dfe7d74,
+                0x7fe9056b8984b6e9,
+                0x00ddf1bb806933af,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xbe2e237bfb210067,
+                0xde6875c62b125975,
+                0x3938de060548207d,
+                0x07f83ec2068476de,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x1af41f71ab0f816d,
+                0xfea2a1d842b1ae38,
+                0x9d93eeb67c111607,
+                0x03b5d880f3023644,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xa4a0d2d303828a30,
+                0xfef4f0f91c16c281,
+                0x341ea2446b72dfa0,
+                0x02392c172ad1e48d,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x9da7ce74fe232338,
+                0x484cbe28344ad194,
+                0x738b06d89449f6c8,
+                0x040605c7cf382456,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xabbff056d316910b,
+                0xdd0129bcd239ec8a,
+                0xf32937e89eac3b2b,
+                0x046e7e34ae426b40,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 4));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 93, 94, 284] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 93];
+        let mut opad = vec![0x5cu8; 93];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
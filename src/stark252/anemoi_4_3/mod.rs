@@ -0,0 +1,1062 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger256, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 4 field elements or 128 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 4;
+/// 3 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 3;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 2;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 12 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 12;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    state[0] += mul_by_generator(&state[1]);
+    state[1] += mul_by_generator(&state[0]);
+
+    state[3] += mul_by_generator(&state[2]);
+    state[2] += mul_by_generator(&state[3]);
+    state.swap(2, 3);
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xfcaea5944a63307e,
+                    0x7445adabcb136623,
+                    0x0501fb8d77677fb4,
+                    0x0258810f2c51ed5a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9354ad3a1d948310,
+                    0xd7bef6fd5e7e1910,
+                    0xe625b7986f59592c,
+                    0x039edc23f2169d97,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe780d29166177be8,
+                    0xd939acafcb4f377b,
+                    0x32363d99599b1e35,
+                    0x0438f16acbe3b894,
+                ])),
+                Felt::new(BigInteger256([
+                    0x166b22b4171c78af,
+                    0x2b7519ad99d4a0dd,
+                    0xebf6d61b7699309c,
+                    0x06cd190a9da5af49,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb6cf899792289102,
+                    0x2b53e8409905e3a4,
+                    0x649a410aedd09a4d,
+                    0x010b4772f1eb5494,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cb2e3c17569f8c3,
+                    0x0f01ccffe9bcb0cf,
+                    0x801ca05b7916ba22,
+                    0x0679022d1604ff3f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19d834a796928bdc,
+                    0x6cfbec5ba991c89f,
+                    0xf90af0f2c83e42ee,
+                    0x019e2e9385925ef9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae677e1628b7b411,
+                    0x62f531bc033e647c,
+                    0x864d3950c8616364,
+                    0x056ed76848293157,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa39e09239d6c9a05,
+                    0x6a2dd9aff2a8e5fe,
+                    0x6b623a5ef7067bc9,
+                    0x04a017781db31132,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdbcecf12b5b9782f,
+                    0x7dbede3e88223bb2,
+                    0x8c61b05e0f4f6415,
+                    0x00239886479a0be9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9826b5a5c130e1a8,
+                    0xd8e2642435359c2e,
+                    0x007380bff0b16587,
+                    0x04737c65f962cb56,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbcd4f6fda700b74c,
+                    0xa8c2504410672d76,
+                    0xfe5ee6be09beb997,
+                    0x022bda98a1b10f21,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x413dbd7bc73873f5,
+                    0x33276bac67eff27a,
+                    0xa9e2b2daab86d6be,
+                    0x01b317e267123711,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc904169fdbdaaf8a,
+                    0x2f88ebee5343956a,
+                    0xc3af70fd00740cbe,
+                    0x07a4cc40343df9e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x593539cfea6ad8b1,
+                    0xeac65d59ee604af7,
+                    0x7ce59c93418ff4f5,
+                    0x06833745279dfd08,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9065defccaa6fccc,
+                    0x49325f9f479aa455,
+                    0x54ec91c2d08b4f69,
+                    0x019badb4631038ba,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x13d28f8fe2a1c722,
+                    0x13e32d1c4c804e88,
+                    0x8b956af24b056fda,
+                    0x0612258864ae104c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xac19cba8435092aa,
+                    0x05e118c3b3390753,
+                    0xc7c2f6a3ab2e018c,
+                    0x07f0006c242c4440,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f48c7ccae0200df,
+                    0x91fa4e7de0186d13,
+                    0x66cd90a31e32f2a1,
+                    0x06cf3949055ba88a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd0d6cc95eef7f090,
+                    0x328084c74d8a1609,
+                    0x116ed8d0b5dcf5d2,
+                    0x04625e971a7c1b70,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xdab2c7ce7e809719,
+                    0x62301cc7e8c325c6,
+                    0x0fdb319ed9180377,
+                    0x0612c268ed755323,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf41e26b2bf2cd353,
+                    0x288cb0748b6089f8,
+                    0x999209bb2cdafaab,
+                    0x04d144d3616381bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x32c9d6e3868d125a,
+                    0xbf186979f9d168da,
+                    0xc0d243caa0a3ac9d,
+                    0x03ca1e5fc6900e42,
+                ])),
+                Felt::new(BigInteger256([
+                    0x70119071fee24884,
+                    0x5ddaf0a58ba903a6,
+                    0x70d631ae528c4d96,
+                    0x076fc72667f46317,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2f17ba7c5ff59034,
+                    0xbbfb75f54f91f1fb,
+                    0x90963ae17e14acd2,
+                    0x07c80a1532905c03,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9cf18a3da0cd7bc9,
+                    0xcdfcd94d2c6842b0,
+                    0x972201b1d89648e6,
+                    0x00bbde4b83272f99,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3257dd64326a640,
+                    0x85ce8cdc87ed4e69,
+                    0xcd49edb18c6a668f,
+                    0x00a1e143df6184a6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xae47a03628681316,
+                    0x80f6830bb893eca4,
+                    0xa6c71ad9acad255d,
+                    0x053aff92bc2a4115,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x610e7b32253004ae,
+                    0x518c3ab5dd6d27fd,
+                    0xdccb31697f9f35a0,
+                    0x0668a69877df66eb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b8380b9c2b1778a,
+                    0x18408022980bbac1,
+                    0xa46094eaa68c6c73,
+                    0x01d94b54129dd88d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1400cef87b466d46,
+                    0xec0ebc063d22ca5f,
+                    0x1d50f3c79f4e9bd3,
+                    0x037bc09fb871650d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf53bc8d89d47da1c,
+                    0x130b8a1c6c25c264,
+                    0xa885b58d01d5cd83,
+                    0x04666d6520e1d983,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8b6e40762839b4f5,
+                    0x4331726b5a4f0b0d,
+                    0x0bd54ba786730187,
+                    0x05ccdf4b3260312e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9201960ae77a203e,
+                    0x9971cd269dcfa94a,
+                    0x4e2a690bf317ee65,
+                    0x0283510c59b9b824,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9066d2a564c606aa,
+                    0x28aa4209f4368fcc,
+                    0x1c1f2f2180dcf270,
+                    0x00e56e824639e5a5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf635c5ce6d4167bd,
+                    0x31e096265edc287f,
+                    0xdb29816b9b5af620,
+                    0x05f286fc5b81ab4f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcaa303435774d5fa,
+                    0x81e74600f09ebdc8,
+                    0x5849e64ff20b2947,
+                    0x07213d294f1a9d48,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71917143503f64ab,
+                    0xae338f65cfd11d26,
+                    0xc1932e8048afcdc4,
+                    0x071b1cd06e48adfb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec3e3d56f3392d30,
+                    0x3774f01a46b689fa,
+                    0x1a64f4182e192128,
+                    0x01eea9daf43d3551,
+                ])),
+                Felt::new(BigInteger256([
+                    0x365f246191500d8b,
+                    0x8be8fd819f384086,
+                    0xa7163838f53c78de,
+                    0x001df6ce1eac4df2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x906ab6c2fd4725fb,
+                    0x4327109c4287a233,
+                    0xc2fae3304c711f88,
+                    0x017e368c415d94d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f3cf88d950fbbce,
+                    0x0e6329e6738f6a4e,
+                    0x2820e13227f171bd,
+                    0x06868feb1ca478ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x38b57d059c27fda0,
+                    0x36bf75c67216148a,
+                    0x4fb639f6a19ca646,
+                    0x03f91604358b30c4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x18d3a574dd293ba4,
+                    0xce66d9a4d90ddc64,
+                    0xadb4a0924efa46e4,
+                    0x047f9c52256162d4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x33e85a41a0f86ee8,
+                    0x06cf270acb4213a5,
+                    0xd8a3fd2f9e76bb4e,
+                    0x073fbbeb65b334a7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xad7d51c808291d6f,
+                    0xbb772e4053946759,
+                    0xc45c2af0655ef02c,
+                    0x04ac5f47ac894266,
+                ])),
+                Felt::new(BigInteger256([
+                    0x56ef328f85658ce9,
+                    0x2260a73a91ab3a27,
+                    0x44e30d53f4bda0cd,
+                    0x0461e42c04cebcd5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x852e1688390c2dd3,
+                    0x7a934a1b77e84491,
+                    0x63bbf62cb11b1540,
+                    0x07331a5ef3a7ceb9,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 4],
+            [Felt::one(); 4],
+            [Felt::zero(), Felt::zero(), Felt::one(), Felt::one()],
+            [Felt::one(), Felt::one(), Felt::zero(), Felt::zero()],
+            [
+                Felt::new(BigInteger256([
+                    0xc1e1e98c4fcaa2d4,
+                    0x040561e243522b92,
+                    0x8f2c25771da3a743,
+                    0x027056f07e18f72d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3516c4cbc8f0a1b,
+                    0x8413d05604a39097,
+                    0x2c49b977078238c0,
+                    0x07be20de208dc1ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa643e7f199e7c0dc,
+                    0xb58e4570d6901929,
+                    0x7ca02fb65dd131df,
+                    0x049db4553c788726,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd41aaa83f48a4e3f,
+                    0x16545b412601f425,
+                    0xedff7c630838ea0b,
+                    0x0497b22b24a91ef6,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf4ee75ca5b72ea6c,
+                    0xf2d14ace605ef377,
+                    0xbc590dfb452d1aa1,
+                    0x06fb98ddc28dc9a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb890d37f0f37896d,
+                    0x8a02fd0fbd8ab706,
+                    0x1154afd653ed8102,
+                    0x03df12acb928b651,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa01b8742db7a6751,
+                    0x6377ef241096fe6e,
+                    0xcfc70214e7d0af48,
+                    0x0731c9c29cf58b05,
+                ])),
+                Felt::new(BigInteger256([
+                    0x161ba39da41b3d46,
+                    0x39e2a384b1871a73,
+                    0x244b6afb0243f038,
+                    0x0479a169e9e1a067,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x35d18514b6bd318f,
+                    0xbc2088752b5ea58b,
+                    0x0d5b482076e0b840,
+                    0x022cd663a301e8b8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0e7336d6454079f,
+                    0x5661d505f6ba657e,
+                    0x047188846e32fa1a,
+                    0x0099c2dda59d9d44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e15cc282684924f,
+                    0x80fbdac408878590,
+                    0xa0578799fc584832,
+                    0x048973784867a913,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9fb3d3adeb8faba,
+                    0x841bfdebb799a463,
+                    0xbbac2566e7c3ea9a,
+                    0x03aa540f4dc75f72,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa3fc0a6a46c4e0ca,
+                    0x89a59c4297fbed57,
+                    0xaa5ba8b06ff1370a,
+                    0x009d14c53436aa92,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8bab5762151bc496,
+                    0xdd7d99d1c9f1fd5f,
+                    0xe9aeaedbfa9c95e3,
+                    0x0250c915d33619e8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc2c7d73b55312bc3,
+                    0x5495557dc918b578,
+                    0xc39e88af02652a25,
+                    0x075cf6d6b741e739,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb3e5d4839fb25273,
+                    0xe3317fda21c46f82,
+                    0x319efd38e387c060,
+                    0x047e892636609825,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcf1511f29f17c719,
+                    0xf4e6078461ad2621,
+                    0x81d4564abecdda04,
+                    0x03ad6c233ba3c536,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7856a3b8fbe77420,
+                    0x15b9bb7e4b93d394,
+                    0xaf4661e2159b4278,
+                    0x026fbb37a3876d9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9b83a067674664c7,
+                    0x96a77b4c235d800c,
+                    0x34d1791b2d693589,
+                    0x04a30c13f4a3e516,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7055139769305cef,
+                    0xdef59da43e564ee8,
+                    0x25bd382fdde3db19,
+                    0x0446a3f7c074f383,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2ca8ba6d5571ddfe,
+                    0xaaff59e590118eb4,
+                    0x57dc819e13521925,
+                    0x00217d4541361e5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x39c37b6ec0506754,
+                    0x2c30be46cc56b387,
+                    0x7a52df7b07516454,
+                    0x0242a118b5530421,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc3b5ff1237906418,
+                    0xc2b932f1d2c7cc63,
+                    0x1af3df5c17ec8c17,
+                    0x07931698017ce79b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9fd0cd9fbf28cdc,
+                    0xf03d21531e0d7c7e,
+                    0xffe5d42927971e8a,
+                    0x05ba70794a1028b9,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 4],
+            [
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xffffffffffffff81,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff790,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffe61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe470,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6bd62e728577c122,
+                    0x9040d2e4513cdd5a,
+                    0x140951dc342a5184,
+                    0x01aab98adfc23cbf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26d3f7a44cf64d80,
+                    0x34d64902f85a28a7,
+                    0x6865af0ba4012d4e,
+                    0x04be4d7ebfd47818,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6e66258c24190d1,
+                    0x36ff2b93a9b23fa2,
+                    0x63e00b8621ac7faa,
+                    0x0270cf2ada12b448,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfaf70efbe0ac734e,
+                    0x5a8bc82bd3a6d811,
+                    0xa8405248c2d6b0de,
+                    0x03f021d5cab0a3ee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1ea0f047891986b1,
+                    0x90da41fd98ff188c,
+                    0xf0571d7e40f59da9,
+                    0x0298d0e3ee07ec74,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1473a455aa841d7f,
+                    0x3c91c308888800ab,
+                    0xe25a085116ce59ff,
+                    0x03a9855883407b9e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf66e3966368a7336,
+                    0x644a70f0e34c15be,
+                    0x93a07139b9b5fe11,
+                    0x020efeb1c0c24145,
+                ])),
+                Felt::new(BigInteger256([
+                    0x836633757f19c0f2,
+                    0x905741f6ba7b3fab,
+                    0x8aa855c214f2a97c,
+                    0x055ec5d7df3c4ec5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x08871f5ce3b9486c,
+                    0xbf4607870f8dd608,
+                    0x1aafe1adc179a68f,
+                    0x03fa1efc93dac084,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a7c91840f7fe0e2,
+                    0x9433eb9b2563e797,
+                    0x54812d8db29fedc9,
+                    0x04881fd3612ddebf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe43ca1b35246b1a5,
+                    0x070f8e37d1303514,
+                    0x9cb2bc34dcccc332,
+                    0x0146ae7826fe5a8b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfacbb1421d58a73d,
+                    0x962a856b7c1824ce,
+                    0x766fbc3892be91c8,
+                    0x005d7ee0bd62b8a5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x46fe109086182e8c,
+                    0x221e69b7f5d1e576,
+                    0x6767b5445fc6f8b6,
+                    0x078f7006add8f84d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60a58913a7645037,
+                    0x43d8d6f9ab67adc2,
+                    0x1fe5cea919f18006,
+                    0x00ff1929dcc1029e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc3d5a359f45d5b9,
+                    0xe0f180537d0e8fec,
+                    0x7c7a9745eab73ed0,
+                    0x02956daa5c264d9f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb77fe5dc3302aced,
+                    0xf769d6784044653f,
+                    0x390e4e80c28ae697,
+                    0x071d3fd5cbb4d007,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3818fd1d92ce2378,
+                    0x361339ff4468a0df,
+                    0x8fa77bf0ff9fa16d,
+                    0x02fc9dca263a0dfe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x20a19b11b451de87,
+                    0xb7f3697c18cdb632,
+                    0x5e3cd5b5147a26bf,
+                    0x0365949616359788,
+                ])),
+                Felt::new(BigInteger256([
+                    0x42dff4cd9f038b42,
+                    0xa2ec0f88a86ecf0e,
+                    0xc431a381661f7bb6,
+                    0x022fc8339e60a2a3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x64237ed04451068c,
+                    0x7f6ba9e61ca9ed37,
+                    0x8166639f5fc7a8ad,
+                    0x033264aecfc5ccf0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd9f32cb9966313fa,
+                    0x2f9194b9f515a949,
+                    0xc6d5200f29464622,
+                    0x06e9608f612f2ac0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc79d019b8379a340,
+                    0xbae57c74ab97af64,
+                    0xced23fa8832436ba,
+                    0x06fec2c6d8e08441,
+                ])),
+                Felt::new(BigInteger256([
+                    0x351f0a10a2a3b921,
+                    0x3868ba289664e1aa,
+                    0x50c1723d6f5cc2d2,
+                    0x0473b4414e86df58,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63131d441f7b8f79,
+                    0x6bf3616b95f67162,
+                    0x0d3836146602d48e,
+                    0x04ee335bed118582,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2021 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 32];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 32];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 32];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U5>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    use super::super::BigInteger256;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger256([
+                0x58626d4c865230dd,
+                0x4ccf0909ef561781,
+                0x652b0004e10f9239,
+                0x0339d84f83e05346,
+            ]))],
+            vec![
+                Felt::new(BigInteger256([
+                    0xf78a6d70c0539842,
+                    0xf642ef609e44899c,
+                    0x49b5f5da7d0360c6,
+                    0x02426c177300f7e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd89a6e43e2d33e9f,
+                    0xf049729f25c6ac0f,
+                    0x0711c84b5a11ef7d,
+                    0x06591ed3f28a3595,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x850f1c0e574656fd,
+                    0xef88acc9f8afb9b4,
+                    0x8f8def0d7264ec01,
+                    0x035f261c4acee400,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6422e6006a5b4955,
+                    0xebdd836defa6932c,
+                    0x79ad4472f97d0032,
+                    0x0053077080b514b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0153644f3893b441,
+                    0x6758cac2c42ba865,
+                    0x8482127cf65ea12c,
+                    0x07415d2695085eaf,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xbeb6befe8c69d63d,
+                    0xa0becf73e7b9ba66,
+                    0x961b169111c750e4,
+                    0x06dc5777ba63e143,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8cc195c8fbb4b53b,
+                    0x3a556e697cfea014,
+                    0xbe55e17ea524a07d,
+                    0x02ef5e2430df4b03,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ae0634fef90d93f,
+                    0xd5c44e6dce53299c,
+                    0x825287a768342e95,
+                    0x0350e20fba749fde,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37636801811b868b,
+                    0x3fc21ae5bc738a5c,
+                    0xd2a920f27532d015,
+                    0x06adbcf796673fd1,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x1d8265a769434917,
+                    0x9a31472375dd0bb6,
+                    0x1f15a74df6c1f205,
+                    0x051949834d73aa57,
+                ])),
+                Felt::new(BigInteger256([
+                    0x25c758976c86d270,
+                    0x35440afdf50d8f7f,
+                    0x07f6c434190e2c68,
+                    0x0699439af99481fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9d5346e3ceddb4f,
+                    0x8aee3c072d355a04,
+                    0x4a907ae6822f52e3,
+                    0x04ae465cf23984bd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2611b7fae70bbd06,
+                    0x1f16cc82a2985640,
+                    0x82d8ceddacfedfb7,
+                    0x015d51ca6ce71b4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9577f267dbb8ff42,
+                    0xdf9486e4f34808ba,
+                    0x1b047982b9d43167,
+                    0x01afe78ab15dd28b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xdc0cc6c6a5c62167,
+                    0x398da913041a3062,
+                    0x6d2910b8038f07cb,
+                    0x0512798108272eca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x03d2230289ee82d1,
+                    0xba245905e4277abf,
+                    0x08f05d00f084532a,
+                    0x00169ea49611f4d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb25b036d738d3f33,
+                    0x980cd3988c4d61bb,
+                    0xac2b2990e4c5f611,
+                    0x07ac657680788c98,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb9dde370ba5fcf90,
+                    0xa85889788e905572,
+                    0x14bcf62c5246889e,
+                    0x042f31d4cb36a2f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x648c4d3ea617fcb0,
+                    0xf67352ce19238504,
+                    0x851a79c431998656,
+                    0x0107e81c380b8572,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1029e12ac5eea615,
+                    0x0ad65010abf132ef,
+                    0x80dc78788c47897e,
+                    0x0157711bc74463e8,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0xb2b054f2b67dc8f3,
+                0x5fc782386b72a988,
+                0x80852eb51df129d4,
+                0x047327e1920a2cc3,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xfa5fa39177811c01,
+                0xfdb3b2d4cc3c837d,
+                0x8505bda9104a3e9c,
+                0x0556283870e57b9e,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xc17d2a95e9133ad2,
+                0xbb066fac963de500,
+                0x348414c50c66fccd,
+                0x0310048bdf30ac38,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x049436595ff40944,
+                0x3425810b380b88b0,
+                0x975a5c286eef0be9,
+                0x046177e390fcb0c1,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x22ae765febccaa82,
+                0x9e42e0ab3732699f,
+                0x00890fa9c836abc9,
+                0x076eb046b74dc3e4,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x0f60a7974a9375fb,
+                0xe307f39d3f568804,
+                0x70382b820494f222,
+                0x02f9c496325e4e1b,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xf4287281800bd23d,
+                0x4563a45bf6f7bfcf,
+                0x5e0962f10a3dc680,
+                0x0759d9df1efc7e73,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x49f71d5d3b61c496,
+                0xcc582a6d543827a5,
+                0x7ba3126998438635,
+                0x070282ef7478450a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xa33fa5d31d12ca79,
+                0x738e40180fbf849a,
+                0x7062ed4145ff0133,
+                0x010b3636c8294535,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x08ee9d48ce391ac2,
+                0xa166eb1defd35e1a,
+                0x90b5b50628b1c54d,
+                0x05d6e66bbbadab10,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0xb2b054f2b67dc8f3,
+                0x5fc782386b72a988,
+                0x80852eb51df129d4,
+                0x047327e1920a2cc3,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xfa5fa39177811c01,
+                0xfdb3b2d4cc3c837d,
+                0x8505bda9104a3e9c,
+                0x0556283870e57b9e,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xc17d2a95e9133ad2,
+                0xbb066fac963de500,
+                0x348414c50c66fccd,
+                0x0310048bdf30ac38,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x049436595ff40944,
+                0x3425810b380b88b0,
+                0x975a5c286eef0be9,
+                0x046177e390fcb0c1,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 186];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xf5b8811e8d167c45,
+                    0x3575c33d47a24bca,
+                    0xf32e110ef7f97e6b,
+                    0x037cccc8af44609e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ecd3067c2fab564,
+                    0xf36a0764034d3772,
+                    0x3d37243b03dba4ab,
+                    0x01faa9bd9765ed63,
+                ])),
+                Felt::new(BigInteger256([
+                    0x78e964c0a7d1f736,
+                    0x7e19887ff66cce45,
+                    0x9a4e63496fd5884b,
+                    0x00743c2f0063c572,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3b8b2721b2648b82,
+                    0x1b70539654a9a4be,
+                    0x468ab1429ebfe58f,
+                    0x02e4185552adc6d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb23b2dda52059b59,
+                    0x0691485ddd88e47e,
+                    0xd97fe4780715c524,
+                    0x03a68cef85484fc3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3b12d33818781ec,
+                    0xaa25622cf936130f,
+                    0x7b161fb531a01f46,
+                    0x0678f14a90056666,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x1ee753d7900bbe18,
+                    0x402f8d4a086f4137,
+                    0xdbbdb3e0fe043db4,
+                    0x023eb71c1a9b6be3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e333e13e55b3765,
+                    0x86b43c4c6705aaf5,
+                    0x31023791dd8dd790,
+                    0x04306c48e3fa58f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8ed51df2d99b3ee4,
+                    0xe2da5c15e30eab38,
+                    0x14a6341f87afbae6,
+                    0x071a24b4c0785960,
+                ])),
+                Felt::new(BigInteger256([
+                    0x93b5786fc9d69a37,
+                    0xc6cea98a08497076,
+                    0xf560cd850532067a,
+                    0x03c0a511a5af1dae,
+                ])),
+                Felt::new(BigInteger256([
+                    0x22483e8c12a024dc,
+                    0x00f9e487aa72899a,
+                    0xe20b17286f7eff4f,
+                    0x04ebdf8d5f2303a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc3c2b481d79c7bf,
+                    0xad1180b349603bd9,
+                    0xafc060ec524ca0e6,
+                    0x00897e52d89c7ed9,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x886211b61836ec09,
+                    0x6fb04e0a58cdf39b,
+                    0x50919ed0b7a6c022,
+                    0x00f1b75dc42d7d06,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab411964043ebb89,
+                    0x4ff137684efb9b9a,
+                    0x51395a822ea6e047,
+                    0x030dae8478b3dbfb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc7af4caba3ade982,
+                    0x5317846f3c188eaa,
+                    0x39dec689d889a525,
+                    0x05d5369bf4c55373,
+                ])),
+                Felt::new(BigInteger256([
+                    0x91a51aa4fab31617,
+                    0xc27f065069c0285f,
+                    0x84108c9183f5354d,
+                    0x0628d6637c294a0f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x47d3cd9a246035fd,
+                    0x6c6771a457f5ac32,
+                    0xf07ae8b1db99fa9e,
+                    0x05d870e291e5dc5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13747455411ee6c9,
+                    0x32e815d846b5015e,
+                    0xb1313e863a618b00,
+                    0x030c82029844d597,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x141fd5f75534813b,
+                    0x2ec6781f517e6a7f,
+                    0xf1b9efea11f803a4,
+                    0x030b38fabed5cbaf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xacf29692f813d674,
+                    0xfc388c2a02a78ce3,
+                    0x0decf78882a506ad,
+                    0x04fcafb51cc9f22b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe1ec77c8e3fad84e,
+                    0x54b53b4db5deb1a9,
+                    0x9f323ee91413f842,
+                    0x07dc45e1ee3bc53e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7aec46a960c7280e,
+                    0x7a23728f04f633b1,
+                    0x727cfe4064758110,
+                    0x0046d43d75e504e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3bfb01c879b6b604,
+                    0x64f7853a364a0455,
+                    0x182230a9592e445e,
+                    0x04fd55e1f6eea218,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdaec5d0e36e20458,
+                    0x899bf3c35ce2de30,
+                    0x1f0b1dc6e00b6b98,
+                    0x007fe1309f00cbe4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x708602e48df709a1,
+                    0xdc89fee285287ae5,
+                    0x4a13eb0a5adca95e,
+                    0x044036003ca96b38,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72673682f475493c,
+                    0xf6ee8ee25cae49c1,
+                    0xcf9b67e825bbc9f5,
+                    0x0349a2fee5c5876a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcfb8e0feb43ac115,
+                    0xf477e38763d4c3f4,
+                    0x60adaa5b33cfb8fe,
+                    0x0527e1d81fac69ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0x973a86a803cb3856,
+                    0x3ddf3a1230f9fc46,
+                    0xafd612528b21e413,
+                    0x041a825b614fb4ef,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe09719416a117395,
+                    0x705a74169949f120,
+                    0x5224145ab0aa7f27,
+                    0x023f4bd596bc6d55,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb298970c38c08319,
+                    0x6ca356c19bb96e69,
+                    0x398a7ead3e459b45,
+                    0x03dada622aec3497,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x9196a0a8a9606788,
+                    0x35db85f635ab5f57,
+                    0x48c179db707e8950,
+                    0x03ded873bb740e75,
+                ])),
+                Felt::new(BigInteger256([
+                    0x40cbd0cc460af993,
+                    0x6724fcf248f8c3f5,
+                    0xf801eef06be27295,
+                    0x06e6fac422603620,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5419ebf3933c8baf,
+                    0xff0bf40faf835297,
+                    0x7aa014c73e382c50,
+                    0x044627f986944e8e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa8fec735b0a13abc,
+                    0xefc482c5a725d6de,
+                    0x333e08b7210914be,
+                    0x03c49f040c159da2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50523a4053a37fe4,
+                    0x2ec2fdb0a72e1621,
+                    0x44fdffe690c10c15,
+                    0x04df6e251325fd4b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x694c5d264357cd8b,
+                    0xd98d5bc6fe19945d,
+                    0x132766917043d5d7,
+                    0x06a8de7c2dcdf4b8,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger256([
+                    0xff835d3911c41735,
+                    0xced3ceb9d49f3fd1,
+                    0x2747fdf96119a2c4,
+                    0x01474f59a054c8be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50fec7213de7a520,
+                    0xf852201f231200f5,
+                    0xe986c32ab0bea762,
+                    0x05a5c4e47f62897d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a0a5c7c385c9604,
+                    0x519fca6970144f97,
+                    0xc0a9acbf682ac5e3,
+                    0x07b8f44e48d9a25b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xacede31d4d25015d,
+                    0x3ca34b52eaafa1a9,
+                    0x99b5b79a78a9b5cd,
+                    0x036151cc219c25c5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1333c07f5e4ad326,
+                    0xe8dfac559bf07a36,
+                    0x0691e8e30f0d0770,
+                    0x06314040fde5d9d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19a2382ebc81fe01,
+                    0x5c8f50f7070753ab,
+                    0x96188863d4a73af9,
+                    0x0629898db39ec110,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x55b91e14a421e748,
+                    0x2dc7c150db56c1db,
+                    0x3c1a9d18a90a94c2,
+                    0x0031ec8c99d5c770,
+                ])),
+                Felt::new(BigInteger256([
+                    0x88900d4a148652e7,
+                    0x2ddcc1a56b8930b1,
+                    0xdc938ec563c579d7,
+                    0x029a44f058576538,
+                ])),
+                Felt::new(BigInteger256([
+                    0x40eaea98e97a89c5,
+                    0xe12aaa8503128a9c,
+                    0xe5fdf9530c7a0fc7,
+                    0x0605e62b75373ed8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0abf6319ea70bebc,
+                    0x67b18a9b7c0dac9d,
+                    0x5045531c2fd0758f,
+                    0x05c6b95b0f05c72e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd96fdc869986ec02,
+                    0xa9cb844c2d00bfe7,
+                    0x050be50560bfe4e8,
+                    0x06de88e5b6aa7246,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0d9db648dd40d7ab,
+                    0xa2521e74c88d44ab,
+                    0x5ab836e097b404a9,
+                    0x0726c2e5653d0564,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa7e8b78b09a86a3e,
+                    0xe0528c60c383499c,
+                    0x6296bb0906638008,
+                    0x01456d0b02e1b35a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x103d6d43c23b34a8,
+                    0x54bc14e87a8f119b,
+                    0xa9f9a7e386694723,
+                    0x078b2c847d38f77c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8500473c3f9289eb,
+                    0x21fb415eb51e272f,
+                    0x3ee6c2239afc983d,
+                    0x07e6e9a954665c75,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x63d479371276ddd7,
+                    0x6ecd0f257e1bf6c3,
+                    0x846b516aeba7ba5b,
+                    0x0696f39b56a1a189,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8e1e58c4a599328a,
+                    0xc110bd2543784b3e,
+                    0xc735d077db537a02,
+                    0x0605c20a8d20351d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4feff44fcd9aa3f5,
+                    0xc39c337471f4e48d,
+                    0x6fa4b9a795d8b440,
+                    0x05482e6ecb362898,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb7655f54fb35ed97,
+                    0xfe640d897c6a6af6,
+                    0xc3ffc5f0fc6691f6,
+                    0x02df55d7060d7477,
+                ])),
+                Felt::new(BigInteger256([
+                    0x39723382bfdec643,
+                    0xb745f4317aeca70e,
+                    0x701cf30fb8dc7d1a,
+                    0x05c024820fb40c04,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd831abd0cebf873f,
+                    0xda22ef9751cadda6,
+                    0x2b5fbe660f4a2827,
+                    0x05245d4ad8db95e5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x9442c351a3d4dea4,
+                    0xc82ed3fd858891ee,
+                    0x13017bd02c2535c5,
+                    0x019a63b8c548acca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7806beeceea4fc8b,
+                    0xcf4f510a57d7b09e,
+                    0xbcc2fb3f1280bf44,
+                    0x06473ac574a5f4af,
+                ])),
+                Felt::new(BigInteger256([
+                    0x831951a3e9999563,
+                    0x23537e0aeeb5c793,
+                    0x0d6c9ec371646f52,
+                    0x0295a33319b8ab35,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd6abbe0c202d7e1d,
+                    0x9ded4a69a38c761d,
+                    0x777536d06f2a3883,
+                    0x0539e7cd8222e341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b38da1ca18bbe6e,
+                    0x0d874ffed177614b,
+                    0x2242ca6eaf58e4ee,
+                    0x06f622398736fb15,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7cd90e4fc2b98ee,
+                    0x5784ca0af0f3f0f1,
+                    0x0643b09eea59f79a,
+                    0x0157eb56d4b03e03,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5f26c1ad5dd913b7,
+                    0x2dc5398338696a61,
+                    0x9402383d659c54a7,
+                    0x01dcb8493762b650,
+                ])),
+                Felt::new(BigInteger256([
+                    0x30ad6e2d8b6afd35,
+                    0xca310ce5785a1d79,
+                    0xb6a4c64881496251,
+                    0x06d068ac6c345b81,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb32453cc18761cc8,
+                    0x0c6c6fd2d6e897a5,
+                    0x87150f2d585ee6c0,
+                    0x070cd859cbbdcfc3,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 6],
+            vec![Felt::one(); 6],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xa476929ec6216e4c,
+                    0x4e0b9c5fe867adcd,
+                    0x6109ff8e1d41cb07,
+                    0x077bc8a5cc0d0601,
+                ])),
+                Felt::new(BigInteger256([
+                    0x239e3c29305ac078,
+                    0xe47db3a464441668,
+                    0xe2d441bca332faca,
+                    0x01f4e410e2c46aa8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x415e5d0eb162d6f0,
+                    0xed45c43c9a994513,
+                    0x6b8e48f3db5534f3,
+                    0x03351f1263e4bd97,
+                ])),
+                Felt::new(BigInteger256([
+                    0x250a1f3099aa9daf,
+                    0x08ca384e93793b59,
+                    0x5bb6172d7cccbf68,
+                    0x059adce5c598707b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd712717b73b70dfd,
+                    0x238504db2308d504,
+                    0xafe883e141c4de5b,
+                    0x025f1b44d2bc604a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe96dde99d96d72a6,
+                    0x8e4eb2a04b94e72a,
+                    0xd3a18b599126d6f6,
+                    0x071665e9f46e4bd0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xdbfffa21d6b1fcf6,
+                    0x7005846292eed527,
+                    0x79011eb88d3f5f02,
+                    0x046f09982b40de99,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe6761a6b7fd2bbf4,
+                    0x849d23c768767059,
+                    0x7997689b00c6ed45,
+                    0x0721b20c194340fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbbc1bcb9eea57e78,
+                    0x5fac74edc43b49e2,
+                    0x66eb2be04c2f6d5f,
+                    0x0302d65e27161846,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd531c94e17078a88,
+                    0xfddee96e4eadd469,
+                    0xd9718e6bd63cb8c4,
+                    0x07c03d7fdef3ad4d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f7a85a4afae8e46,
+                    0x2923d5e467a90a4a,
+                    0x37db00f10afe9c41,
+                    0x05a41b7c779fb8b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x428b07f4ec4c247e,
+                    0x1c447f7ff94b48e4,
+                    0x545fadbc7fc39600,
+                    0x0007baa1c708865b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xc7a933cfd776069f,
+                    0xf5f83ca79ac0b28b,
+                    0xc838e77b57a9388e,
+                    0x00e5b60f6e48d1e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf37cb7312ebec7ff,
+                    0x64d64acfd422c58d,
+                    0xf1fd6abe962ac295,
+                    0x0425d18df2915130,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeefc114d55066382,
+                    0x5a310790b3252421,
+                    0xa503b43492b0f9ca,
+                    0x00b71491d1468377,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6054ddd14ea130df,
+                    0xe59453d734044dd6,
+                    0x3bc561aa32c9f857,
+                    0x0045cd75a92669db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8bf496b6deab473,
+                    0x778a723db06d650f,
+                    0xea4150f0c0321f76,
+                    0x0420b09f9fdae0ce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a8a2829146406b7,
+                    0xfbedb0fde712740d,
+                    0xa4454b7552987846,
+                    0x03f4a76bb2d8867f,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x8e48d05caf4b81a6,
+                    0x03969b7537bbb411,
+                    0x4333ea9154d93779,
+                    0x06f0437889e97d5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcf1ef5364aac2fd2,
+                    0x2416c039ab57922f,
+                    0xa0ff7707a6d1dda5,
+                    0x03fe0c5002cff2cc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7af8272caadf21e6,
+                    0x9b342f64fb3c7a70,
+                    0xcc901f047abc08f3,
+                    0x02497f72bc816adf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa9fcd9b761a77e08,
+                    0xb45d58e1eac37af9,
+                    0x398cbb8feae12607,
+                    0x02dea4dc61cda35f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55ff3de06e23dc05,
+                    0x1a335866d4a53bb6,
+                    0x36ba5ac2a8d13706,
+                    0x00f2bfb2e7f7367f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2d86640eccf2c4b,
+                    0x38c2ff054a748d01,
+                    0xc3fa5498f473a03a,
+                    0x054e15690b3c999b,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x73447b7de40f99fa,
+                    0xf26a1021fb1539fc,
+                    0x1a304ec83cbdd3ad,
+                    0x001e0f8e1cc7771b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea6426d7caaff635,
+                    0x243c59cf7d836e8c,
+                    0x4f2290ff22dc1231,
+                    0x030a4abf96cc066f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97c879358cd07b8d,
+                    0x84dc7bb2e2dfd8fa,
+                    0x0797fdd4eea0b29f,
+                    0x0502ecc2e09bd466,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb79d730f05f4b171,
+                    0x23b67562adff589a,
+                    0xbcfce5afbd7b9d20,
+                    0x04d477014536296a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8e28530142008ac6,
+                    0xcd53f61ed6e0fe64,
+                    0xc1b59619c49908bd,
+                    0x00e09dbe018b19f7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe68b4679a038377d,
+                    0x44bd71ad6bb1b1e0,
+                    0x8da389287da2c197,
+                    0x061568d1e440580d,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xe98a6c22abe25eb1,
+                    0x09f9bf41ee21cfb0,
+                    0x2a795f619c08ddc5,
+                    0x05616bfa8503dcd9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf411383cb050ec93,
+                    0xc08caa7984592437,
+                    0x7ceb03a5ec3aa099,
+                    0x0104ff700e67bd1d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc8e6079390c7e5b4,
+                    0x48ba9c35e85e8661,
+                    0xe74d777ee736fda9,
+                    0x069cbc092e05b8c0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26753d0d94d1390b,
+                    0xdca1528cfe8b97cc,
+                    0xe52f0ae9698bff2e,
+                    0x006d4b016cfeeb56,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8282823c092a3f7d,
+                    0x80842ac279b9cc27,
+                    0x6284a33d406aeb74,
+                    0x051021bdb4e7a305,
+                ])),
+                Felt::new(BigInteger256([
+                    0x751ea7af6850ca4f,
+                    0xefe6a6db1289702a,
+                    0xb1b89fa4bbb632d0,
+                    0x0117396b10a5eabc,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0xea8c80d688085258,
+                0x18c5b94267c5905e,
+                0xd1786de37a03100b,
+                0x06a6088c6890f486,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xd9c3dbcb67f1d283,
+                0x8212489f8da76f8a,
+                0x366028e15c5df837,
+                0x07bc1b9ad320c09a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x1f3415f7a222c3f3,
+                0x3ccf2d7b49f27d29,
+                0xfeac2531194a1e61,
+                0x00d217a867646b70,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xf1ccf5e961388267,
+                0xb3cf2d5c719bb12f,
+                0xb0096f0228445f21,
+                0x03cc05262aed3eb6,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xfaeda978cb5c31e3,
+                0x784aa2e886d9c6f9,
+                0xe6ee5764e15dd768,
+                0x06240a8c679701cb,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x2ee6931078292557,
+                0xa337e9bce3de667e,
+                0xb1f5a7f169ca38bb,
+                0x04de9c0f744db9f3,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x2c908ff06b805e54,
+                0x7acdb890e7bf0cba,
+                0xdd7a0b3628661fa2,
+                0x0187d689ffbd1d67,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x46a02ddbf1673c98,
+                0x8192159a1c7d2c7b,
+                0xb688b34e2892707e,
+                0x065ecbefd3084c6c,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x6375f40e2303c12a,
+                0x97097a9ca8b5ce53,
+                0x63978c2cd1bd8f7f,
+                0x05968393f6a4573e,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xb289eb894804401f,
+                0x7b1145a2b19efb67,
+                0x01bb4fa7dfd3505f,
+                0x0194162c3eb6d96a,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 6));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 155, 156, 470] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 155];
+        let mut opad = vec![0x5cu8; 155];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
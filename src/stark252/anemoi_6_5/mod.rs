@@ -0,0 +1,1454 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger256, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 6 field elements or 192 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 6;
+/// 5 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 5;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 3;
+
+/// One element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t -= beta_y2;
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        let beta_y2 = mul_by_generator(&y2);
+        *t += beta_y2 + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    let tmp = state[0] + mul_by_generator(&state[2]);
+    state[2] += state[1];
+    state[2] += mul_by_generator(&state[0]);
+
+    state[0] = tmp + state[2];
+    state[1] += tmp;
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc6e2adb3f807e176,
+                    0x41a560fc308f131b,
+                    0x62ea68e3a31d1d1a,
+                    0x00daf754bb94183a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf8e532b20f6e8ead,
+                    0xe966f514e224916f,
+                    0xd69d73e6c3fb8ee8,
+                    0x0401d710b05d45b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b045d3b59e0b19a,
+                    0xd072fee7b4f115ec,
+                    0x5c194534cb6a27ad,
+                    0x00d9ab4a813ac317,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa3cfa2a4de2c819f,
+                    0xd7972854f8ec8670,
+                    0x3ba7639b2e1a0d25,
+                    0x0564a3e006c29984,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbd50a7b2989bf483,
+                    0x1d9d4a98f4dbb358,
+                    0x71642e312ef1f0fa,
+                    0x07ebcc41a15aa1e6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x50b47f5a6e5a03b9,
+                    0x23c7fda232a1fb4e,
+                    0x56a4c24e24a19e81,
+                    0x0149c43089a673f9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7e56782ad98a535f,
+                    0xe8bf05cf2a1b9af6,
+                    0xc7d8e62a70a63900,
+                    0x004c557923a53efd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2466b242e37135cf,
+                    0x4522ee916351ffda,
+                    0xd0280fea20f4d2d0,
+                    0x061b6ae2bfe72df8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a0c04e0484274a1,
+                    0x79d4d8868dd1bfe5,
+                    0xabaf4595829ea124,
+                    0x00710bced710ea09,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc218c70f8cf56584,
+                    0xfc1c98231d0d0b4c,
+                    0xec764e8958dc8ca7,
+                    0x047f33c0777dc9e2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa8eae1444ec3630,
+                    0x73d5eb0c7aa6aab9,
+                    0x9bab437afe9f7f48,
+                    0x043c82ec367d4eef,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f9321130134fd73,
+                    0x81e48fd2031af943,
+                    0x1036ff0379989c27,
+                    0x0677607f0f0f60fc,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaf64bdcc2f1598e1,
+                    0xff0505f34e8a1b6e,
+                    0x7a0bd8217b61740a,
+                    0x05d98c842e14216a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xda0e2da730c414f7,
+                    0x8f670dff816969f7,
+                    0xff2b64954ff8d295,
+                    0x055969d8f7466f12,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc167f39b33af9b45,
+                    0xa7711b6d00ac60d4,
+                    0xba87eae6a4cd32e0,
+                    0x00ed24274cf8423c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ed487f082ba511e,
+                    0xca024b0005713b38,
+                    0x167ba0868a35c2c2,
+                    0x07dfab7f3b5e35ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x979c4c67b269ca59,
+                    0xcce43f32e14a0339,
+                    0x615f4601d836db04,
+                    0x01db31d77b78cf11,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76c6ef5f1b68ff1f,
+                    0x6f3abc08739ceaf6,
+                    0x1537c306c48d1e09,
+                    0x0230a809e102669c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8919b85a0381953f,
+                    0xa82bf7c721fb8acf,
+                    0x4884d7eaf7954a2f,
+                    0x0383d91982db2b73,
+                ])),
+                Felt::new(BigInteger256([
+                    0x78e793109aa123b3,
+                    0x4dba879371c6b375,
+                    0x8426109a7077b369,
+                    0x0342adf83c57dcc1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc465e09e67aad27d,
+                    0xc5a18dbb8cc55151,
+                    0x4112833f8ac140ae,
+                    0x06e286edccf5a33c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x178821f2d198e619,
+                    0x29cad77418a18592,
+                    0x1708f615d4d9c3dd,
+                    0x01ec4db66a0abe8b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2b1f55bc23d66f85,
+                    0x6a6574e91328eac6,
+                    0xe3fe0f347e988fe7,
+                    0x047b375b51eb2090,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcd6da2c91a13419c,
+                    0x1dffebc0fb7287d6,
+                    0x41c3a6bba92ac08a,
+                    0x04b8d4c8c8b86172,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xd944b0946c5ef5ec,
+                    0xfa846ffd34d1c599,
+                    0xe480c693cacac71a,
+                    0x0025f1d3ade63742,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72d5de3b98f72978,
+                    0x635bede81901bd02,
+                    0x8281eb6b5f3c27ef,
+                    0x078efbcaecc772a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x622640d1f8f16a7c,
+                    0xc43226428c493387,
+                    0x9d0216785d246fed,
+                    0x01012cf4d3eac4d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd4ed5524125c639f,
+                    0x4f5a666d122a1f28,
+                    0xcb2d65e03443f4f1,
+                    0x00ce4d576bb61ef3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3bc56046eb43ec8a,
+                    0xf397305072b77087,
+                    0xc1b8dcf3f3548fef,
+                    0x030544855f39d294,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72a06cffe0a474ca,
+                    0x16ea9e01ec46357e,
+                    0xf1361192dc6c7931,
+                    0x02839552a047a656,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x22bc4765c7a45a1c,
+                    0x9e262283831729b5,
+                    0x70753b11d0250849,
+                    0x0428dd576d11c88e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed6e740e1fd86a19,
+                    0xab6b728745594514,
+                    0xcd20ded31fab20c6,
+                    0x068560dd4da5687d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ad74466bff1ee3a,
+                    0xfb67e1c83b67f13e,
+                    0xf259af214171e515,
+                    0x0786034b55fbcdf4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x43198ff1cd2e9fbf,
+                    0x64d090baa6fdbb49,
+                    0xc441e4de6a1ccb0d,
+                    0x051306f14c272d16,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7dba8381b69eb947,
+                    0x4e88a308bce3e48b,
+                    0xe22af8377137ebbb,
+                    0x0775994adf89a6ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1be1989062ee775,
+                    0x50ca653e81720ce0,
+                    0x8e41496320e2bd1b,
+                    0x04b0c11586332367,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4669600fb1174066,
+                    0xb97a286a9f0da351,
+                    0xe41e78a9d5582382,
+                    0x0611fcb65343508d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb3de16e8c40cac11,
+                    0xffe0f6cdb4106514,
+                    0x7d9c57a7c0a88443,
+                    0x03b6c0b44ab61f84,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde7b782c699d4a2e,
+                    0xa36144d5d07501eb,
+                    0x1718c159ce386f27,
+                    0x047dc6dff0ba9a6e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7fd4318aa3588c64,
+                    0x732755ca7e6ee770,
+                    0x5035740d0dd193c9,
+                    0x0691988ce0b71269,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf6f6b743b0f79069,
+                    0xc8257444e6ef978b,
+                    0x00ce8aa7a174deaa,
+                    0x02f6d3bc4e8bd509,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb751020b6cb4e56a,
+                    0x8e51067471efa03e,
+                    0xd7cc5c25c31f041f,
+                    0x02d379bd372d251c,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x355bea244025b865,
+                    0x567b8443532af0a3,
+                    0x260a6089de5134e6,
+                    0x001920c66ff8bca9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x802f46e719adddda,
+                    0x6c4000d96408412d,
+                    0xaf0f589825f15437,
+                    0x01bb7632f26a37c2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7c271af675fb7cd9,
+                    0x502aa4b3687da46b,
+                    0xedc88b6c24b56e06,
+                    0x0201cf6763d30c69,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c7e71d5f8107677,
+                    0x6c467d5dd45ff426,
+                    0x23c5587e455e92f3,
+                    0x02b8bded9a91e6d8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3a3ee9de7a34efed,
+                    0x33710a9ea82a3e8b,
+                    0x82cfc10595977cda,
+                    0x06557324b0b94bd7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1637880849880ad0,
+                    0xb0f0a46c7591742f,
+                    0xe4b7332edccf23cc,
+                    0x052df65bc6f21507,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xbde9e6dfef84255f,
+                    0x01358677097b1666,
+                    0x8ee59650def31987,
+                    0x03880cfb818bb6b3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x115abc5cf4fea904,
+                    0xae356d18b5ae9ea1,
+                    0x3ece301997c91281,
+                    0x0284bff8f6a28be1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7dd9a88d42e05fb5,
+                    0x2aea1d241ecdb474,
+                    0x935d0d814a511ea1,
+                    0x0074bead70f03b9f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x21bcc6fc6b569e34,
+                    0x32de4917ebaa568a,
+                    0xc2864921974ce8c8,
+                    0x07ef51fb0d821f5c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc46cff8ae6118286,
+                    0xb84126bae70829bc,
+                    0xbb4d1730b6666013,
+                    0x01381f98646d7fff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0134a4b873e48cbe,
+                    0xb53890df5be80cbc,
+                    0xfc7d9d2c5b780b65,
+                    0x05815014772368fd,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x053872529355263a,
+                    0x287cf9157b84ea61,
+                    0xffc31493bc2f89de,
+                    0x0361104d0fb3371f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1157d9f841bd61ca,
+                    0xdd0ff4feed11053f,
+                    0x00cadbe158396dac,
+                    0x024792e6fb9298f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf29bc4e140fb529c,
+                    0xbabfc8e5175c6365,
+                    0x78f5775610796d35,
+                    0x0282a0bd7ff332db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x33a81d855c22d26b,
+                    0xf4692d5385d3996e,
+                    0x18003b650f78e952,
+                    0x04ce410eab974586,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f3c3811f7540bf7,
+                    0xb91b6049a71a29cb,
+                    0x860504d75d5f11ad,
+                    0x011be06239e1212b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc1cdca530dfda4fd,
+                    0xeda09c0f872c9402,
+                    0x23e9f738ac3bee1c,
+                    0x04100b8b095d0225,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1d4d3a4c9350e49e,
+                    0x7ed547dc2a255594,
+                    0xafab4caafce17b40,
+                    0x0738d9c759152b62,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfeb17bdfba5f9bc0,
+                    0xceb0e8c8a220e38e,
+                    0x3f125f5a9b5d72ad,
+                    0x06a94eff90432b34,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd40979326da67a9,
+                    0x9533589c518ce5b6,
+                    0xd81b576be30ca7cf,
+                    0x07bd23bab761375b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5ca6a4342a76988,
+                    0xd3b768a965256d65,
+                    0x1993a83d474be481,
+                    0x0297b08f74f04155,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc686c4369f03da4d,
+                    0x02af9b004bae5397,
+                    0x24fbfc083c2a7c66,
+                    0x021ec22fd4346cbc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0a5bc217ab86f936,
+                    0x4908903a493b4321,
+                    0x39cb86cf900aedc6,
+                    0x029521c6006ba66d,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xcfe859eb3e2e5910,
+                    0x6491546ae4d3bca5,
+                    0xfb773722f5c24a1e,
+                    0x00b6a8448b83653b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0fedfeb528c04a90,
+                    0x1110fc8c92774445,
+                    0xd31c61fbf6a95438,
+                    0x058e39bab5bf9913,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4237b70957a673e6,
+                    0x8aaeb592eaa6fbcb,
+                    0x24a1af5f5e01e51a,
+                    0x038253322c2f5e3c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5140c2d82a4ee04a,
+                    0x6f2365e0b8c92297,
+                    0x1dd1cb7462b63589,
+                    0x06caa4b12424f3d6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x83b45994a2908f2f,
+                    0x304072ebc15c61b2,
+                    0x37dde4a17524b9aa,
+                    0x04145e57d0ae3fe2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36dfb5e4978668dd,
+                    0xa69cb584d19d409c,
+                    0xb62870a79c8ee55f,
+                    0x00aaceab0ba7beea,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 6],
+            [Felt::one(); 6],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xf420bc0d019daa57,
+                    0x7ec1411ba80be81c,
+                    0xd6c5419f28c2447e,
+                    0x0154cd5727d970e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x71011bf729ea4fb8,
+                    0x7ecb21a17f71d088,
+                    0xd5b69ece8abf5ded,
+                    0x0471ab4be5a78443,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb7f1ef276cff63af,
+                    0xbc1baab29f0d195b,
+                    0xb4a02ead835c3209,
+                    0x036aa964dfc40fb9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69d77daf6ed7192b,
+                    0x04b78767ef0359c5,
+                    0x32955fb79d610b76,
+                    0x01d69f96d6ea43f1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f947b96c6415f6d,
+                    0x583c8f2d01d01877,
+                    0xfc744a1f02cc6c31,
+                    0x02545fe2dfaf3acc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeca8552fbb2ffb30,
+                    0xe744fb63944e7cf7,
+                    0x5d581b03b12ce4aa,
+                    0x045188d2a8ad09fe,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8bc55ec333cc457b,
+                    0x5303b7c9d8e02c2c,
+                    0x594446493fda414a,
+                    0x056327c830a6ce96,
+                ])),
+                Felt::new(BigInteger256([
+                    0x807c14c08a056685,
+                    0xffbdc9b0b54fb0ae,
+                    0x4fa0b5f0bcdebdf3,
+                    0x0401a00e8c8a1831,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2c8461cdf8e28939,
+                    0x2135ae769df86e82,
+                    0x6ae81e6975fbace8,
+                    0x04c8e392f45fae58,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0eff6437c39db19f,
+                    0x4dbbcaeb4c58dfca,
+                    0x72b97ba29d5d7784,
+                    0x071e97e1e3fdd033,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f755f30999b8585,
+                    0xe09016963274a459,
+                    0xe926d392c5832201,
+                    0x06189c0854c95c0c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x576a27fef0e2f861,
+                    0x03a548b0721c2e90,
+                    0x9971c7406e6afe94,
+                    0x052724f2e276e6b4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2c368451d95ca478,
+                    0x8714ea44d6b0987d,
+                    0xe3545be26d71cfe3,
+                    0x04ae664895204560,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e5e2a30c351616d,
+                    0xdf66e56d5bbf9e81,
+                    0xbe725edb137bac0f,
+                    0x06677531dcaf6545,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8f2860c10cc01998,
+                    0x9c07880df069f9d7,
+                    0x5b43b3d308fa6135,
+                    0x075c16e0fadbf5e2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf00c925aac897a59,
+                    0xd3c3aaab13a7440a,
+                    0xc444cf40c165c5c0,
+                    0x01cd30ed32570a7b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd26ade3a22fc1918,
+                    0x983784b1a921baef,
+                    0xb5501c17037c38cc,
+                    0x03a88519a7b09431,
+                ])),
+                Felt::new(BigInteger256([
+                    0x91f576e83b34a2ac,
+                    0x2e2a79653b5e60ab,
+                    0xd915608286deea5e,
+                    0x06b02ccbb1017090,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x64f1b20e84eadfab,
+                    0xe25acb1b4af88648,
+                    0x73b59ec4a05ec40e,
+                    0x056ca9c67dfebc78,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6d133efe6d0a5218,
+                    0x9cfb56d93225cd0e,
+                    0xd89696896d07e760,
+                    0x069badfecce1e6bf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x63bda2e493d68212,
+                    0x9b4cb985325d4b6c,
+                    0xfe572900f772d9c6,
+                    0x01596f8b9a1c2481,
+                ])),
+                Felt::new(BigInteger256([
+                    0x367fffd8908c30a6,
+                    0xfb44aaeb36a60f64,
+                    0x5f60b601d81e13b7,
+                    0x00bc077d58fc29b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60d1ce0ba114f211,
+                    0x91a0f0040dad4be4,
+                    0x64f2d013687bb830,
+                    0x009db4bac9d345e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x456ff2f6e10ce6dc,
+                    0x8dd493eed0a99d9d,
+                    0x00b490b017be6895,
+                    0x07d2957e2b930594,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x079ab6f95603e3e2,
+                    0xe52373fd7908c07f,
+                    0xc5a814f7187729f4,
+                    0x03321c129701a122,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2f3c561dfd6049c,
+                    0xa996d1a5d7536eaa,
+                    0xb13d018b0c0afaad,
+                    0x06506e0276e339a4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99bca98dcc32e30b,
+                    0xf1f73e2d35153c4f,
+                    0xf420d7f0707f103a,
+                    0x0422fc8af1913471,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e8d331639d96141,
+                    0x1c74dc48d80d5c04,
+                    0x8dd9af90029aecea,
+                    0x0229619d73114fe1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaa17b9d96f7b3cb1,
+                    0x5f5a57b0f99e46d5,
+                    0x99ab768e7b425eb6,
+                    0x016afa686c24caa3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ce9834b04da535c,
+                    0x8021889748421b35,
+                    0x5a227dc97c80be19,
+                    0x00f835de88644516,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc3a889d526381b64,
+                    0xecb3399eb31b3d77,
+                    0x1e31bec9a1c1f462,
+                    0x021e4aabbabb383d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9d499473d0c07770,
+                    0x71487fa8f598c86f,
+                    0x8ce923aad3c14b48,
+                    0x0743eda53f951745,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc0d68c0562d5a3c9,
+                    0xef0e6f6606f07286,
+                    0xdab9f3bb12777b82,
+                    0x069c332dc6c7427d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b63371bc389d771,
+                    0x5090d9eb9052bbdb,
+                    0x51639104fb169baa,
+                    0x030c45f3cdeb78f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc4bdb6332290f419,
+                    0xbe64eb9c3821482d,
+                    0xef00d2dede5bdbe1,
+                    0x0485baf4fb370a2f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9f50769629e9be09,
+                    0x4fe3285116ac35c7,
+                    0xd5a62cebfbee2b65,
+                    0x035b7008232bad73,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 6],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffee1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffecf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff570,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff570,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffee1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffecf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff570,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff570,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffee1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffecf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff570,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff570,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffee1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffecf0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff570,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffffffffffffff61,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07fffffffffff570,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x214bc8c8e45e87ce,
+                    0x6a3ed0da9bd5d66b,
+                    0x034c60013b39380e,
+                    0x076f863c041d86b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8cf7a57a7286251a,
+                    0x31df62d504a504b8,
+                    0xca5c6c763d963889,
+                    0x000674d1accd2438,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05553f459bc2b26b,
+                    0xb72a8fa716a2a23b,
+                    0x0ea6925988625d72,
+                    0x03dabcb63cf7e6aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd2583a488f91dd8e,
+                    0x5b1555b7579c45ea,
+                    0x197ec25e31e2c348,
+                    0x04fd86b9831304d5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x79c349d4cdf6a61d,
+                    0x4da820c86328a2bf,
+                    0xf18c84498c1c733e,
+                    0x0429c77a0d1b108d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x453d45a37ccb32a1,
+                    0xf4b2305288c22023,
+                    0x854a591856f334b4,
+                    0x052548121ea4fe45,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x61a317053cc0a150,
+                    0xd0a362b290b21b69,
+                    0x605248bb943676bd,
+                    0x04b1cd7b20a40b97,
+                ])),
+                Felt::new(BigInteger256([
+                    0x91ce98eda87947a9,
+                    0xb6628cde68192861,
+                    0xe99d57765eac05f6,
+                    0x07bf728f9a4ff1ae,
+                ])),
+                Felt::new(BigInteger256([
+                    0x505092d81e4cc02c,
+                    0x19fe9f84dde8a3b6,
+                    0xc655a735f2692ebb,
+                    0x00f3fafa12de3219,
+                ])),
+                Felt::new(BigInteger256([
+                    0x113d35a065c7d4ea,
+                    0xbcd4ceb66d523f1f,
+                    0x08f30415f9ed64ac,
+                    0x0203f49bc593973f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x23ddb3d6d55792bf,
+                    0xcd68c008899b7248,
+                    0xdac50dbb0c068722,
+                    0x009b88a0e339b317,
+                ])),
+                Felt::new(BigInteger256([
+                    0x44c9a9c881533a8c,
+                    0xf311575e55d2fb67,
+                    0xc79fbd9b5c51dc1d,
+                    0x068f90edc4d0cadb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4bd9be7c5bc459a7,
+                    0x6bd8aeb87829e7d4,
+                    0xb8d29db0ed2c7074,
+                    0x069169d81ca051ec,
+                ])),
+                Felt::new(BigInteger256([
+                    0x380dd0c5c2ee52a9,
+                    0x3a9267dc03ae2485,
+                    0xb391d6369bdc9f94,
+                    0x012a201d62638c09,
+                ])),
+                Felt::new(BigInteger256([
+                    0x722a17e75c27686a,
+                    0x10ad2c49d03b61d0,
+                    0xc3b3265564cb7cf0,
+                    0x03cebeec96ec2b17,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9bd3393b794af06d,
+                    0xde1736d82e825c96,
+                    0xbf690de19a66e493,
+                    0x048704e7191feb13,
+                ])),
+                Felt::new(BigInteger256([
+                    0x34860c3263cd2ace,
+                    0x41acfe181f75e7bc,
+                    0xdb33ea5bce8c746d,
+                    0x07c044acefb72424,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf942a3f150b2684b,
+                    0xca94b2254a6ad585,
+                    0xbd4a840852b95a84,
+                    0x0376ed05da6a377f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8fd092cad00fd908,
+                    0x9399695b277d13e1,
+                    0xa0c9b59fcc4e5eb6,
+                    0x01b413472d4d6a65,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfd3dd9baad78b7f7,
+                    0x513c4e841436359b,
+                    0x4751b050f3bf38c3,
+                    0x0014a6681935109c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xffa5f80e8fa17328,
+                    0xdf5871b0456cab53,
+                    0x320e9bd845970d53,
+                    0x003b1adde0fa4078,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa2b72a87a79171b7,
+                    0xc16affabe1f70abf,
+                    0x1202a9051a259837,
+                    0x0539865eb6d0c3f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x49c1c08c33c66ade,
+                    0x114384b4824917ae,
+                    0x83c982c908945bee,
+                    0x02a460b1005ac883,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9e655cf254d7edb4,
+                    0x3dfc0ee6305790ae,
+                    0x8eedb6ec294fa4df,
+                    0x0267bb2be20900f1,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3851477e68b1204c,
+                    0x06019a508fcb61e5,
+                    0x9860b5292fe3e36d,
+                    0x03a4d078992e8fb3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x87c479049a72919d,
+                    0x64a0002aef9be418,
+                    0x53479e5375ff5553,
+                    0x05eb7fb5e29877fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x638093dbae14934b,
+                    0x4af86bcb7782ec77,
+                    0xf6561860c5ef88c7,
+                    0x0409bec52d79515c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3f7d3709aa2ccb23,
+                    0x6f5e587e8ef0a69c,
+                    0xf837164373f5ec9b,
+                    0x0749a5f6053caf19,
+                ])),
+                Felt::new(BigInteger256([
+                    0x22a8d66721e1b3cf,
+                    0x34da7522ca087617,
+                    0x9d5b0307ff93e38e,
+                    0x00df551f4dbcff4d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x39bde3ed8d256ab0,
+                    0xbaa56bf30d2a4bba,
+                    0xb4fe9104f0e2c726,
+                    0x076286b53fe3f4e2,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaf45ebddf4f7741f,
+                    0xe04f23bbddc78869,
+                    0x7097edbda4a70adf,
+                    0x022de50b459f01dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa375c2591f797e2c,
+                    0x2b270779bd855d7b,
+                    0x3b48bda5ace9b234,
+                    0x0536d1da4ea616c9,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa919bdf8a63e6d63,
+                    0x26709beb15daf35d,
+                    0xc23853c2cb7ea3f4,
+                    0x043b00d6368e0258,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdfd42bd1c254ec2d,
+                    0x8bba3e70387c45ea,
+                    0xd737bc7b61b80995,
+                    0x01a373ab47b5b9d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8637d21c97183873,
+                    0xfffaa1afffc5b186,
+                    0xb8d1b2d9cb8dda45,
+                    0x0105fcd88825226a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf8ecd04b552671c3,
+                    0xdba2c5114f62ca2b,
+                    0xf40c368d92185ab4,
+                    0x03f8e6dae2bc44d9,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,2240 @@
+//! Sponge trait implementation for Anemoi
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::digest::AnemoiDigest;
+use super::{apply_permutation, apply_permutation_batch};
+use super::{Jive, Sponge};
+use super::{DIGEST_SIZE, NUM_COLUMNS, RATE_WIDTH, STATE_WIDTH};
+
+use super::Felt;
+use super::{One, Zero};
+use crate::{foreign, AffineEncoding, AnemoiAbsorb};
+
+use ark_ff::{FromBytes, PrimeField};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use rand_core::{OsRng, RngCore};
+
+#[cfg(feature = "digest")]
+use ::digest::{
+    consts, core_api::BlockSizeUser, typenum::Prod, ExtendableOutput, ExtendableOutputReset,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update, XofReader,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An Anemoi hash instantiation
+pub struct AnemoiHash {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::elements"))]
+    state: [Felt; STATE_WIDTH],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, RATE_WIDTH>")
+    )]
+    idx: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::bytes"))]
+    buf: [u8; 31],
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_index::<_, 31>")
+    )]
+    buf_len: usize,
+}
+
+impl Default for AnemoiHash {
+    fn default() -> Self {
+        Self {
+            state: [Felt::zero(); STATE_WIDTH],
+            idx: 0,
+            buf: [0u8; 31],
+            buf_len: 0,
+        }
+    }
+}
+
+impl AnemoiHash {
+    /// Returns a new hasher, absorbing sequences of bytes incrementally through
+    /// [`Self::update`]. Its digest is the one returned by [`Sponge::hash`] over
+    /// the concatenation of all absorbed sequences.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the provided sequence of bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let len = core::cmp::min(31 - self.buf_len, bytes.len());
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+
+            // Full chunks can be absorbed right away, as they are not padded
+            // even when ending the sequence.
+            if self.buf_len == 31 {
+                self.absorb_buffer();
+            }
+        }
+    }
+
+    /// Absorbs the provided sequence of field elements into the hasher, each one into the
+    /// next rate register. Bytes absorbed through [`Self::update`] which do not form a full
+    /// chunk yet are first absorbed as a padded partial chunk, as when finalizing.
+    ///
+    /// Absorbing a sequence of elements into a new hasher hence yields the digest returned
+    /// by [`Sponge::hash_field`].
+    pub fn absorb_field(&mut self, elems: &[Felt]) {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        for &element in elems {
+            self.absorb_element(element);
+        }
+    }
+
+    /// Absorbs the injective encoding of a structured value as a sequence of field elements,
+    /// as described in [`AnemoiAbsorb`].
+    pub fn absorb<T: AnemoiAbsorb<Felt> + ?Sized>(&mut self, value: &T) {
+        self.absorb_field(&value.encode());
+    }
+
+    /// Absorbs the canonical encoding of an elliptic curve point, over a curve defined over
+    /// this field or one of its extensions, as described in [`AffineEncoding`].
+    pub fn absorb_affine<A: AffineEncoding<Felt>>(&mut self, point: &A) {
+        self.absorb_field(&point.to_elements());
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of [`foreign::DEFAULT_LIMB_BITS`] bits as described in the [`foreign`] module.
+    pub fn absorb_foreign<G: PrimeField>(&mut self, elems: &[G]) {
+        self.absorb_foreign_with_limb_bits::<G, { foreign::DEFAULT_LIMB_BITS }>(elems);
+    }
+
+    /// Absorbs the provided sequence of elements of a foreign prime field, each one decomposed
+    /// into limbs of `LIMB_BITS` bits as described in the [`foreign`] module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMB_BITS` is zero or not strictly below the capacity of this field.
+    pub fn absorb_foreign_with_limb_bits<G: PrimeField, const LIMB_BITS: usize>(
+        &mut self,
+        elems: &[G],
+    ) {
+        for elem in elems {
+            self.absorb_field(&foreign::to_limbs::<Felt, G, LIMB_BITS>(elem));
+        }
+    }
+
+    /// Returns the digest of all the bytes and field elements absorbed so far.
+    pub fn finalize(self) -> AnemoiDigest {
+        let state = self.finalize_state();
+
+        AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    /// Returns a reader of the extendable output of all the bytes and field elements
+    /// absorbed so far.
+    pub fn finalize_xof(self) -> AnemoiXofReader {
+        AnemoiXofReader::new(self.finalize_state())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the internal state after absorbing the last, partial chunk
+    /// and applying the final padding, as done in [`Sponge::hash`].
+    fn finalize_state(mut self) -> [Felt; STATE_WIDTH] {
+        if self.buf_len > 0 {
+            self.absorb_buffer();
+        }
+
+        // If the number of absorbed elements is a multiple of RATE_WIDTH, we add 1 to the
+        // last register of the capacity. Otherwise, we add 1 to the rate register next to the
+        // last absorbed element and apply a final Anemoi permutation.
+        if self.idx == 0 {
+            self.state[STATE_WIDTH - 1] += Felt::one();
+        } else {
+            self.state[self.idx] += Felt::one();
+            apply_permutation(&mut self.state);
+        }
+
+        self.state
+    }
+
+    /// Adds a field element to the next rate register, applying the permutation once
+    /// all of them have been filled.
+    fn absorb_element(&mut self, element: Felt) {
+        self.state[self.idx] += element;
+        self.idx += 1;
+        if self.idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.idx = 0;
+        }
+    }
+
+    /// Absorbs the buffered bytes as a field element into the next rate register,
+    /// padding them with a byte set to 1 if they do not form a full chunk.
+    fn absorb_buffer(&mut self) {
+        let mut buf = [0u8; 32];
+        buf[..self.buf_len].copy_from_slice(&self.buf[..self.buf_len]);
+        if self.buf_len < 31 {
+            buf[self.buf_len] = 1;
+        }
+
+        self.absorb_element(Felt::read(&buf[..]).unwrap());
+
+        self.buf = [0u8; 31];
+        self.buf_len = 0;
+    }
+}
+
+// Allows using Anemoi in hash maps, as `finish` returns the 8 least significant bytes of the
+// digest of all written bytes. It is however much slower than common non-cryptographic hashers.
+impl Hasher for AnemoiHash {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let bytes = self.finalize().to_bytes();
+
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+}
+
+/// A [`BuildHasher`] of keyed [`AnemoiHash`] instances, for use in hash maps.
+///
+/// Each built hasher first absorbs a key, drawn at random by default to prevent
+/// HashDoS attacks by adversaries unable to predict the hashes of the map keys.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct AnemoiBuildHasher {
+    key: [u8; 32],
+}
+
+impl fmt::Debug for AnemoiBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The key is redacted, as it must remain secret
+        f.debug_struct("AnemoiBuildHasher").finish_non_exhaustive()
+    }
+}
+
+impl AnemoiBuildHasher {
+    /// Returns a new builder of hashers keyed with random bytes.
+    pub fn new() -> Self {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+
+        Self { key }
+    }
+
+    /// Returns a new builder of hashers keyed with the provided bytes.
+    pub fn with_key(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+impl Default for AnemoiBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for AnemoiBuildHasher {
+    type Hasher = AnemoiHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&self.key);
+
+        hasher
+    }
+}
+
+impl Sponge<Felt> for AnemoiHash {
+    type Digest = AnemoiDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // Compute the number of field elements required to represent this
+        // sequence of bytes.
+        let num_elements = if bytes.len().is_multiple_of(31) {
+            bytes.len() / 31
+        } else {
+            bytes.len() / 31 + 1
+        };
+
+        let sigma = if num_elements % RATE_WIDTH == 0 {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        // Initialize the internal hash state to all zeroes.
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // Absorption phase
+
+        // Break the string into 31-byte chunks, then convert each chunk into a field element,
+        // and absorb the element into the rate portion of the state. The conversion is
+        // guaranteed to succeed as we spare one last byte to ensure this can represent a valid
+        // element encoding.
+        let mut i = 0;
+        let mut num_hashed = 0;
+        let mut buf = [0u8; 32];
+        for chunk in bytes.chunks(31) {
+            if num_hashed + i < num_elements - 1 {
+                buf[..31].copy_from_slice(chunk);
+            } else {
+                // The last chunk may be smaller than the others, which requires a special handling.
+                // In this case, we also append a byte set to 1 to the end of the string, padding the
+                // sequence in a way that adding additional trailing zeros will yield a different hash.
+                let chunk_len = chunk.len();
+                buf = [0u8; 32];
+                buf[..chunk_len].copy_from_slice(chunk);
+                // [Different to paper]: We pad the last chunk with 1 to prevent length extension attack.
+                if chunk_len < 31 {
+                    buf[chunk_len] = 1;
+                }
+            }
+
+            // Convert the bytes into a field element and absorb it into the rate portion of the
+            // state. An Anemoi permutation is applied to the internal state if all the the rate
+            // registers have been filled with additional values. We then reset the insertion index.
+            state[i] += Felt::read(&buf[..]).unwrap();
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+                num_hashed += RATE_WIDTH;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        // Finally, return the first DIGEST_SIZE elements of the state.
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn hash_field(elems: &[Felt]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        let sigma = if elems.len().is_multiple_of(RATE_WIDTH) {
+            Felt::one()
+        } else {
+            Felt::zero()
+        };
+
+        let mut i = 0;
+        for &element in elems.iter() {
+            state[i] += element;
+            i += 1;
+            if i % RATE_WIDTH == 0 {
+                apply_permutation(&mut state);
+                i = 0;
+            }
+        }
+
+        // We then add sigma to the last register of the capacity.
+        state[STATE_WIDTH - 1] += sigma;
+
+        // If the message length is not a multiple of RATE_WIDTH, we append 1 to the rate cell
+        // next to the one where we previously appended the last message element. This is
+        // guaranted to be in the rate registers (i.e. to not require an extra permutation before
+        // adding this constant) if sigma is equal to zero. We then apply a final Anemoi permutation
+        // to the whole state.
+        if sigma.is_zero() {
+            state[i] += Felt::one();
+            apply_permutation(&mut state);
+        }
+
+        // Squeezing phase
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+
+    fn merge(digests: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize state to all zeros
+        let mut state = [Felt::zero(); STATE_WIDTH];
+
+        // 2*DIGEST_SIZE < RATE_SIZE so we can safely store
+        // the digests into the rate registers at once
+        state[0..DIGEST_SIZE].copy_from_slice(digests[0].as_elements());
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(digests[1].as_elements());
+
+        // Apply internal Anemoi permutation
+        apply_permutation(&mut state);
+
+        Self::Digest::new(state[..DIGEST_SIZE].try_into().unwrap())
+    }
+}
+
+impl Jive<Felt> for AnemoiHash {
+    fn compress(elems: &[Felt]) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = [Felt::zero(); NUM_COLUMNS];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+        }
+
+        result.to_vec()
+    }
+
+    fn compress_k(elems: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(elems.len() == STATE_WIDTH);
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut state = elems.try_into().unwrap();
+        apply_permutation(&mut state);
+
+        let mut result = vec![Felt::zero(); STATE_WIDTH / k];
+        let c = result.len();
+        for (i, r) in result.iter_mut().enumerate() {
+            for j in 0..k {
+                *r += elems[i + c * j] + state[i + c * j];
+            }
+        }
+
+        result
+    }
+}
+
+impl AnemoiHash {
+    /// Computes the Jive compression of each of the provided states, as
+    /// [`Jive::compress`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_batch(inputs: &[[Felt; STATE_WIDTH]]) -> Vec<[Felt; NUM_COLUMNS]> {
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = [Felt::zero(); NUM_COLUMNS];
+                for (i, r) in result.iter_mut().enumerate() {
+                    *r = elems[i] + elems[i + NUM_COLUMNS] + state[i] + state[i + NUM_COLUMNS];
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the digest of each of the provided sequences of field elements, as
+    /// [`Sponge::hash_field`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn hash_field_batch<T: AsRef<[Felt]>>(inputs: &[T]) -> Vec<AnemoiDigest> {
+        // initialize states to all zeros
+        let mut states = vec![[Felt::zero(); STATE_WIDTH]; inputs.len()];
+
+        let num_blocks = inputs
+            .iter()
+            .map(|elems| elems.as_ref().len().div_ceil(RATE_WIDTH))
+            .max()
+            .unwrap_or(0);
+
+        // Each sequence is absorbed by blocks of RATE_WIDTH elements, followed by an
+        // Anemoi permutation. As in `hash_field`, an incomplete last block is padded
+        // by appending 1 to the rate cell next to its last element.
+        let mut active = Vec::with_capacity(inputs.len());
+        for block in 0..num_blocks {
+            active.clear();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if let Some(chunk) = elems.as_ref().chunks(RATE_WIDTH).nth(block) {
+                    for (s, &element) in state.iter_mut().zip(chunk) {
+                        *s += element;
+                    }
+                    if chunk.len() < RATE_WIDTH {
+                        state[chunk.len()] += Felt::one();
+                    }
+                    active.push(*state);
+                }
+            }
+
+            apply_permutation_batch(&mut active);
+
+            let mut permuted = active.iter();
+            for (elems, state) in inputs.iter().zip(states.iter_mut()) {
+                if elems.as_ref().len() > block * RATE_WIDTH {
+                    *state = *permuted.next().unwrap();
+                }
+            }
+        }
+
+        // The sigma constant is added to the capacity after the last permutation,
+        // hence does not affect the digests.
+        states
+            .iter()
+            .map(|state| AnemoiDigest::new(state[..DIGEST_SIZE].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Computes the Jive compression by a factor `k` of each of the provided states, as
+    /// [`Jive::compress_k`] would, processing them by groups of [`BATCH_SIZE`](super::BATCH_SIZE).
+    pub fn compress_k_batch(inputs: &[[Felt; STATE_WIDTH]], k: usize) -> Vec<Vec<Felt>> {
+        assert!(STATE_WIDTH.is_multiple_of(k));
+        assert!(k.is_multiple_of(2));
+
+        let mut states = inputs.to_vec();
+        apply_permutation_batch(&mut states);
+
+        let c = STATE_WIDTH / k;
+        inputs
+            .iter()
+            .zip(states.iter())
+            .map(|(elems, state)| {
+                let mut result = vec![Felt::zero(); c];
+                for (i, r) in result.iter_mut().enumerate() {
+                    for j in 0..k {
+                        *r += elems[i + c * j] + state[i + c * j];
+                    }
+                }
+
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the parent level of the provided level of a `k`-ary Merkle tree, whose
+    /// nodes consist of `STATE_WIDTH / k` consecutive elements. Each group of `k` sibling
+    /// nodes is compressed into its parent with [`Jive::compress_k`].
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the level is not a multiple of `STATE_WIDTH`.
+    pub fn merkle_level(nodes: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(nodes.len().is_multiple_of(STATE_WIDTH));
+
+        let inputs: Vec<[Felt; STATE_WIDTH]> = nodes
+            .chunks_exact(STATE_WIDTH)
+            .map(|siblings| siblings.try_into().unwrap())
+            .collect();
+
+        Self::compress_k_batch(&inputs, k).concat()
+    }
+
+    /// Computes the root of a `k`-ary Merkle tree from its leaves, each consisting
+    /// of `STATE_WIDTH / k` consecutive elements.
+    ///
+    /// # Panics
+    /// Panics if the number of leaves is not a power of `k`.
+    pub fn merkle_root(leaves: &[Felt], k: usize) -> Vec<Felt> {
+        assert!(!leaves.is_empty());
+
+        let mut level = leaves.to_vec();
+        while level.len() > STATE_WIDTH / k {
+            level = Self::merkle_level(&level, k);
+        }
+        assert!(level.len() == STATE_WIDTH / k);
+
+        level
+    }
+}
+
+/// Number of bytes squeezed from each rate register by an [`AnemoiXofReader`].
+const XOF_BYTES: usize = ((<<Felt as PrimeField>::Params as ark_ff::FpParameters>::CAPACITY
+    - crate::XOF_BIAS_BITS)
+    / 8) as usize;
+
+/// A reader of the extendable output of an [`AnemoiHash`].
+///
+/// The output is squeezed from the rate registers of the final hash state, each providing
+/// its `(CAPACITY - 127) / 8` least significant bytes, where `CAPACITY` is the number of
+/// bits of the field modulus minus one. Their distribution is within a statistical distance
+/// of 2^-127 from the uniform one. The state is permuted once all rate registers have been read.
+#[derive(Clone, Debug)]
+pub struct AnemoiXofReader {
+    state: [Felt; STATE_WIDTH],
+    buf: [u8; XOF_BYTES * RATE_WIDTH],
+    pos: usize,
+}
+
+impl AnemoiXofReader {
+    fn new(state: [Felt; STATE_WIDTH]) -> Self {
+        Self {
+            state,
+            buf: [0u8; XOF_BYTES * RATE_WIDTH],
+            pos: XOF_BYTES * RATE_WIDTH,
+        }
+    }
+
+    /// Fills the output buffer from the rate registers, and permutes the state.
+    fn squeeze(&mut self) {
+        for (chunk, element) in self.buf.chunks_mut(XOF_BYTES).zip(self.state.iter()) {
+            chunk.copy_from_slice(&ark_ff::to_bytes![element].unwrap()[..XOF_BYTES]);
+        }
+
+        apply_permutation(&mut self.state);
+        self.pos = 0;
+    }
+
+    /// Fills the provided buffer with the next bytes of the extendable output.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            if self.pos == self.buf.len() {
+                self.squeeze();
+            }
+
+            *byte = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for AnemoiHash {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for AnemoiHash {
+    type OutputSize = consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl BlockSizeUser for AnemoiHash {
+    // A block of bytes fills all the rate registers.
+    type BlockSize = Prod<consts::U31, consts::U7>;
+}
+
+#[cfg(feature = "digest")]
+impl Update for AnemoiHash {
+    fn update(&mut self, data: &[u8]) {
+        AnemoiHash::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for AnemoiHash {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(self).to_bytes());
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for AnemoiHash {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&AnemoiHash::finalize(*self).to_bytes());
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for AnemoiHash {
+    fn reset(&mut self) {
+        AnemoiHash::reset(self);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutput for AnemoiHash {
+    type Reader = AnemoiXofReader;
+
+    fn finalize_xof(self) -> Self::Reader {
+        AnemoiHash::finalize_xof(self)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl ExtendableOutputReset for AnemoiHash {
+    fn finalize_xof_reset(&mut self) -> Self::Reader {
+        let reader = AnemoiHash::finalize_xof(*self);
+        AnemoiHash::reset(self);
+
+        reader
+    }
+}
+
+#[cfg(feature = "digest")]
+impl XofReader for AnemoiXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        AnemoiXofReader::read(self, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BigInteger256;
+    use super::super::BATCH_SIZE;
+    use super::*;
+    use ark_ff::to_bytes;
+
+    #[test]
+    fn test_anemoi_hash() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![Felt::new(BigInteger256([
+                0x5f13dff42f358706,
+                0xd575905a11e3463c,
+                0xcde1b169fdde18b5,
+                0x04fdd4dfee716fb4,
+            ]))],
+            vec![
+                Felt::new(BigInteger256([
+                    0xc812487059a1e673,
+                    0xcf38a10c321d4b10,
+                    0xb5a98f77e1e18a3f,
+                    0x0575cf9c7838a18b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c93ad0a9a9e617e,
+                    0x3767d33102ddf9d8,
+                    0xb801e72d6633f747,
+                    0x01ca2e439968f2b5,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x9cab6fc3300216a5,
+                    0xeac623cc6534f3de,
+                    0x671b4c7a66ba9173,
+                    0x01fce3f70c6de685,
+                ])),
+                Felt::new(BigInteger256([
+                    0x993f47d2dfb0f224,
+                    0x30d51dc9eff84d9d,
+                    0x12d219c24616456b,
+                    0x0797370fbfd6c945,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb01b4d6b8347ec9,
+                    0x6c00910c831b0cf3,
+                    0xb82e8c89dd8ca4a0,
+                    0x03469d903a0bf6c0,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xba79c64a82ac26b9,
+                    0x9f11007ca124aca0,
+                    0xc5103abedc898d31,
+                    0x0718c0acab21dfbc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19593fe21debdabe,
+                    0x4322ac2d0a783b38,
+                    0xee2a7f8b58e59ac1,
+                    0x01b203dc80a49401,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf6103a7e5843472c,
+                    0x211a5d8190e93dda,
+                    0x56baed73eeec7889,
+                    0x077d841fbcf4aa22,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5101f3e64ad70498,
+                    0xb57a436d06f34b59,
+                    0x52048589469fbe53,
+                    0x00d8d1581600b696,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xc6f9aabda74a5010,
+                    0xa3c73b3921c19cf6,
+                    0xfb98005649ae410f,
+                    0x01328baf07adc37c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x39795057bbd83c79,
+                    0xfe75936d5a5341bd,
+                    0x8644640882008a13,
+                    0x02ae2a8e6153b45e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x43b700d2ba5a09b3,
+                    0x965f104fc0278ac1,
+                    0x35d2c14ac95f980e,
+                    0x0378389aaeb8fb0d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa83f66cd9f9ffe80,
+                    0x9d60314d21720836,
+                    0x3494844294f4f2e7,
+                    0x04eff329c7c03bfa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5711fb3c58a6a79b,
+                    0xb4a63495c4f7a56c,
+                    0xfa9f169a34b9edd4,
+                    0x032fbf8f2d0cb690,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xb12dee06b408d6d4,
+                    0x24682f387effa7bf,
+                    0x3041bbc96278e7a8,
+                    0x00ee61d70a6328d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0dcdac539447ad4b,
+                    0xba92bde49121cfe8,
+                    0xd2e2ca446fd05d8f,
+                    0x033a2486d7f879dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2fd840c93d1acb06,
+                    0xfba224b2d9e857dd,
+                    0x08c50714a40ff1ea,
+                    0x01ee42a5304214c8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe31a8892b666a447,
+                    0xfc96359c9af55b7e,
+                    0x71f465ca32dded2e,
+                    0x00e220e736ae163f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ff330e01cb3ccdc,
+                    0x075e12e8cb378236,
+                    0xb02f1ba02d1741c5,
+                    0x02ac840145ccbd2f,
+                ])),
+                Felt::new(BigInteger256([
+                    0xef573f34cb4c7d6e,
+                    0x1d5ec1323b879532,
+                    0xef4f07648da33bbf,
+                    0x03e55a7974b32392,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0x86ed786391a69b45,
+                0x914520df6b6a8eff,
+                0x8a241498c6cd8e42,
+                0x0120fd33c04faf3d,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x35a277c9ee97cedf,
+                0x6d9958720e0782a3,
+                0xae237d187647ad90,
+                0x00e85b6cc706b87a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x599a5128173a6f12,
+                0x44a7825bd7ac0923,
+                0x27eaac186ff56889,
+                0x047db880def53654,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x13f70b7edfa47650,
+                0xecf8bad316475a1b,
+                0x541351b2a04ff34a,
+                0x00c7a0906c9d6051,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xb1f5bcf7d3ded586,
+                0x198425da026fed31,
+                0x1f31b4d102492bbe,
+                0x0272f05ac310e341,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xbd0afd2fb3a0169a,
+                0xb3204a1f2adb6d2e,
+                0x98add2c833fba08c,
+                0x01bc37b171e23eb3,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x1d445330edadee30,
+                0xdd40aa1793fe1665,
+                0xbaa4663dd1690e7a,
+                0x04fd477d45e85a06,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x74547cbdfd652d89,
+                0x128f6665bfb6d707,
+                0x7d3d9162bfea733f,
+                0x0143a2d3df7b6523,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x1b5639c1b861dec3,
+                0xb1036b4db0448b8c,
+                0xd817282051309e31,
+                0x052d05b71dfbba0a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xf22dcb53f0cc0a3f,
+                0xf0b71954d599890e,
+                0x9b9f41c921ca0171,
+                0x06a29e0f6a39d767,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected, AnemoiHash::hash_field(input).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_bytes() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0x86ed786391a69b45,
+                0x914520df6b6a8eff,
+                0x8a241498c6cd8e42,
+                0x0120fd33c04faf3d,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x35a277c9ee97cedf,
+                0x6d9958720e0782a3,
+                0xae237d187647ad90,
+                0x00e85b6cc706b87a,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x599a5128173a6f12,
+                0x44a7825bd7ac0923,
+                0x27eaac186ff56889,
+                0x047db880def53654,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x13f70b7edfa47650,
+                0xecf8bad316475a1b,
+                0x541351b2a04ff34a,
+                0x00c7a0906c9d6051,
+            ]))],
+        ];
+
+        // The inputs can all be represented with at least 1 byte less than the field size,
+        // hence computing the Anemoi hash digest from the byte sequence yields the same
+        // result as treating the inputs as field elements.
+        for (input, expected) in input_data.iter().zip(output_data) {
+            let mut bytes = [0u8; 248];
+            bytes[0..31].copy_from_slice(&to_bytes!(input[0]).unwrap()[0..31]);
+            bytes[31..62].copy_from_slice(&to_bytes!(input[1]).unwrap()[0..31]);
+            bytes[62..93].copy_from_slice(&to_bytes!(input[2]).unwrap()[0..31]);
+            bytes[93..124].copy_from_slice(&to_bytes!(input[3]).unwrap()[0..31]);
+            bytes[124..155].copy_from_slice(&to_bytes!(input[4]).unwrap()[0..31]);
+            bytes[155..186].copy_from_slice(&to_bytes!(input[5]).unwrap()[0..31]);
+            bytes[186..217].copy_from_slice(&to_bytes!(input[6]).unwrap()[0..31]);
+            bytes[217..248].copy_from_slice(&to_bytes!(input[7]).unwrap()[0..31]);
+
+            assert_eq!(expected, AnemoiHash::hash(&bytes).to_elements());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_jive() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x7d82c7964e9dba4c,
+                    0x1a7f59811ad210d2,
+                    0x5c6016520dacfb38,
+                    0x0632b9829d95505b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xca9e7d4234cb79a6,
+                    0x57e0085ca9868c4f,
+                    0x0f605c0ef55209f0,
+                    0x00d6c497a565ddbf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfa139e1d1f70fbff,
+                    0x86b11348b7b59fb1,
+                    0xb4f097b863840289,
+                    0x002ea08de9526e9e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf27bb2453c5ec131,
+                    0x14b9a1a02f88f3a8,
+                    0xa279405e729824c0,
+                    0x022f3ad81d73abf4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc1104c9e7ddf78b6,
+                    0x9d828436e9e46e3b,
+                    0xbf7fa396129d069c,
+                    0x027cb5781219a992,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb82a0a6b664d875b,
+                    0x4ead1b80a5247109,
+                    0x903483cc850ab9ef,
+                    0x0487892c3c250373,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf1bf09f7c8aa2e89,
+                    0xd913f3c37c25741b,
+                    0x5532fc48952ff45c,
+                    0x02227be319812d4a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1ce008ecf986fa4e,
+                    0x0f470a1c1e0b3db6,
+                    0x38e7a875144cf83f,
+                    0x06a80fd19dd044f4,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x5bcce3b475391652,
+                    0xb64ee296df33d249,
+                    0xa96694dd7d403c38,
+                    0x0124d0a2106e1ec0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4f51296424c5c211,
+                    0xa180a3b68ca06e01,
+                    0x2b358e0206f53722,
+                    0x07e3fbe3bdd852f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa957d68ad3ce050f,
+                    0x92d4160aad248d43,
+                    0x75b68b487fdd591d,
+                    0x0678e09791b2bd51,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f61c0e917cf5bb5,
+                    0xf74a9ce46ab697e5,
+                    0x8fb295833075c8f4,
+                    0x0291a19e13070dfc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd0917fe462b0c489,
+                    0xba8948b06faaf452,
+                    0xd73907c99388e929,
+                    0x039655d5d7b0ee31,
+                ])),
+                Felt::new(BigInteger256([
+                    0x950e65cb117f3376,
+                    0x49ca31f304727e46,
+                    0xaaed6d772f4efeb3,
+                    0x079c21c86daa11f2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x163b018a2ea391cf,
+                    0x2a143a271ac752f5,
+                    0x603980f30b04dc68,
+                    0x06728e4987509610,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb1b9762f4c7d906f,
+                    0x228279a16757ab41,
+                    0x72700a719937c7a5,
+                    0x019595905bb4f551,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x1db25768b1ef8ce9,
+                    0x13affcc2ebea1ab1,
+                    0x07df72f85c39f3ff,
+                    0x07662bd479803367,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3f5950807abe330a,
+                    0x910b5de1af06e090,
+                    0x7c4e18e04f485f46,
+                    0x031b6ab13d1eff00,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbcff4fa8eb5a10c4,
+                    0xa2d9812920d457e2,
+                    0xdb529f3a76a94cf0,
+                    0x026a3b682c188591,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe7a05433be334068,
+                    0xd16474211aa4288c,
+                    0x05386b1c26b98a87,
+                    0x06d0d7610d13cd44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7f2eed8ff2d688d6,
+                    0x035405a43e2a710c,
+                    0x4f0ceb9fdeb86fc0,
+                    0x05563899de5765f6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc77804f78e9cf21d,
+                    0x40511c64200dec40,
+                    0x6eea22d2f4942418,
+                    0x0054a1c0fd5855cf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x838ad5cf0f0fa861,
+                    0xb05ececdbd324927,
+                    0x7b92e31e77f148f3,
+                    0x072a3332623a7945,
+                ])),
+                Felt::new(BigInteger256([
+                    0x27695b7df8dc46a1,
+                    0x8c6f45c659d80b24,
+                    0x77d654e4d21dd6e2,
+                    0x00c794451feeaac6,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0xd36bb30507e6d9cc,
+                    0x194f13571130cdec,
+                    0x33bd637babacd909,
+                    0x06c86b1a631a3385,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1d093b41276f76b1,
+                    0xbbbdd275bd7e6396,
+                    0x4e42db26d39c483a,
+                    0x014086a23a50035a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6ea4f74804cd72f5,
+                    0x60b76705ce36011d,
+                    0xa151c1b1beccecf2,
+                    0x07c986d06257d833,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36b80caea6857b25,
+                    0x1b425e4da980dc1f,
+                    0x20af3d01c6aaeced,
+                    0x02b32b65198e8221,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeb2c45e4b38c615f,
+                    0xe2aee2516f8addeb,
+                    0xc9f79760ea0fe3f5,
+                    0x021a65fa7eaf7cd8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa48f437310db3f49,
+                    0x6e1b200bf8924dbf,
+                    0x219d5b6a243fbaaf,
+                    0x070efdfbbb9cd36c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf80698960f8cbea4,
+                    0xc93c53132eff90b6,
+                    0xf56c25276f4f6a87,
+                    0x063b94db131d968b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4e56c47d5842d324,
+                    0x1f9bd43e498d247d,
+                    0x1ecdc73846c97697,
+                    0x03e47d07a724b740,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x85965e73790259c5,
+                    0xa0f324fb0593bd24,
+                    0x2b9a2a07067fc8f1,
+                    0x07e6d2f1d82c72d4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe3c2929c3a94d91,
+                    0x8f85d87f84e9eaea,
+                    0x897216f5b2e96dc7,
+                    0x017bf7c91a0530fc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8ffa15e91d1d33e,
+                    0x87314efe6213a2c0,
+                    0x05854e29a90a3253,
+                    0x0330c43623b993f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf94c235ead3c62cd,
+                    0xad6db432e62ec4ed,
+                    0xd778bcd98af602d4,
+                    0x002a7cdbda60178b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69af4fd5d915e7c4,
+                    0x52f3f2672128a484,
+                    0xfcbee6db8fc616e0,
+                    0x0320bec8851091ea,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab9a8e64082fcd98,
+                    0xec61c198a7747d0b,
+                    0x3133da9e27a1b581,
+                    0x05595656710755ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4eb4fd265912b168,
+                    0xe6e50cc3346996c3,
+                    0x29566a5118dbe332,
+                    0x058e2e45929c2692,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdb0f579ed0f4530b,
+                    0x8dc7ffad5df0dbee,
+                    0x88bdbdca6b00289f,
+                    0x0207dab328189a08,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x2b455756105f1373,
+                    0x7bbf1b9b83427b32,
+                    0x734eabcbdabe3984,
+                    0x00a033d91b3b043a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0765760551d0e275,
+                    0xdf9e507608764e1d,
+                    0xfe52291eac224507,
+                    0x074020c3b1039a9e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1654a47e050697b4,
+                    0x25b672b28c930cb6,
+                    0x207ce097d2074b90,
+                    0x06f1ca5a85c78bce,
+                ])),
+                Felt::new(BigInteger256([
+                    0x781da626b6e2d655,
+                    0x2265fc3ed53ed818,
+                    0x10d280ac80463033,
+                    0x046df662af15515e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8c78675a934680c8,
+                    0xad51105bde4e7263,
+                    0xf67235ed500b44e3,
+                    0x00cf7e135dddb4ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9e2429a4cbaff18,
+                    0x399d8d90324de9d9,
+                    0x6e02273f626b3c0c,
+                    0x07ed65d6f7211981,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfb137987600febfa,
+                    0x861bd8829f189d70,
+                    0xc6e36480696c8507,
+                    0x020be30916804db7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5fabd006c5ada3af,
+                    0xbb1b1ee2c7e3412d,
+                    0x4f619d2b8764a0fc,
+                    0x027ddb1ca43ccebf,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [
+                Felt::new(BigInteger256([
+                    0x9fd5fe38b34b466d,
+                    0x86c487757c69de7a,
+                    0x30f17f672195604e,
+                    0x00e097f86ad5f20a,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc9fcca32f6808389,
+                    0x5cc5f46bfa7f6571,
+                    0x0f36812b93bbbcc9,
+                    0x012bd5876d7d4401,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66f2536ed05cc981,
+                    0x08e4eb2945103ecd,
+                    0xd91ba65445b086ae,
+                    0x07d03360a01aa1a9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x66c0702120e74f30,
+                    0x454d167655f5f549,
+                    0xe01253825804b764,
+                    0x02323f32cf94808b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x55ee57654f1e5364,
+                    0x91309b285ca53df1,
+                    0x2caccb0d08ba4ad0,
+                    0x05508b8814c65156,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67d4b55c056a7964,
+                    0x5f226e57e5d47c54,
+                    0xa1ed44aa6faa6ece,
+                    0x00fd73bd5f32efc2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc64bda2dca1ce250,
+                    0x4f7df44872fca1b8,
+                    0x9ac46a19c42e451b,
+                    0x066f105c5acb58aa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe23a0e880bd5c305,
+                    0x368f4b8c0571dc03,
+                    0xb730b0c833cb9d1f,
+                    0x05e3ff2c8cac1659,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xda0a0aa6e0d40a9a,
+                    0xd6a037077ed32c16,
+                    0x5049d7ecf3ad3414,
+                    0x078507252d995c86,
+                ])),
+                Felt::new(BigInteger256([
+                    0x12674efb497f45ed,
+                    0x65b6869585686ff7,
+                    0x5e5ba8278e1d48da,
+                    0x06edba815ef67f3c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7374885e9c98fec0,
+                    0xfa80abbea4f31bcb,
+                    0x15f7d87766f8556d,
+                    0x07ef762c2ce4ea8b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x05c3a3fa2081b589,
+                    0xea4806a48d8f4c1f,
+                    0x3c8951579792e7f1,
+                    0x07f39a01c537f22b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x94d1312add0aa667,
+                    0xf6a8147c81eba7a1,
+                    0x6bbfd553c9e379a1,
+                    0x0493e0ed0c08b3d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf938cbb5932c8597,
+                    0x4d9027c9db0fe8a1,
+                    0x4fca97fe1508dad8,
+                    0x02cdef65bf1da800,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba43701b0d324771,
+                    0x834c7050a22d042e,
+                    0xee1b6b532e2b2bc1,
+                    0x06d82fa82a993b0c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xed0264462ee5186e,
+                    0x9419a7b451baedf8,
+                    0x40bed63f3f328f13,
+                    0x03c8a9026e3baaee,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x405b067668ed7b5e,
+                    0xf3fd53c2bf60e788,
+                    0x15a7726504502976,
+                    0x0635e2c6006fe1be,
+                ])),
+                Felt::new(BigInteger256([
+                    0x145f63c929bb9780,
+                    0xd88335c6988145ea,
+                    0x96745ac17ba55ae6,
+                    0x06623fc6431cc929,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0166d2eb422be4dd,
+                    0x940b6be481d71657,
+                    0xc6e6183367345de2,
+                    0x053d0d6869c72001,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5e57e2c705749bbc,
+                    0x8538ddb9e9571eb4,
+                    0x76357aca9b3d3f09,
+                    0x07924d4fcccc12e7,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xb3da7beee2bbc649,
+                    0x055cee496fc35367,
+                    0xecaff94570f8992b,
+                    0x0391f8a896273c1b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe94c39d6d0d01b11,
+                    0x1b1e1ef1bd6d2531,
+                    0xbca2e7d144c9ab53,
+                    0x022c92379941a176,
+                ])),
+                Felt::new(BigInteger256([
+                    0x077c95a03212d1bf,
+                    0x46ff8f4750c0d188,
+                    0xa3abc87232f50d02,
+                    0x003df24e2c3f8c1d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7b0d4b443298b242,
+                    0x89a01ee3a1ff7181,
+                    0xe692efde93a94561,
+                    0x05d9e1a946f2c901,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x3d5ea61df9783afb,
+                    0x999fcd196b543b81,
+                    0x166ef63361783c9e,
+                    0x01a8e62e54898e49,
+                ])),
+                Felt::new(BigInteger256([
+                    0x94fbd1289cb89f83,
+                    0xf4e02ebac6f41c21,
+                    0x30e9e91ae40519b9,
+                    0x07c6ebf8656a774d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x048c85739ebe686f,
+                    0x82f07a3c7582a503,
+                    0x2cec392bf8a04d69,
+                    0x03cab0b40c5f2931,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc80ea5641cff29a1,
+                    0x7f96f35237e49dcb,
+                    0x0c5d04e9753f2df5,
+                    0x06fcbc5ba41e4459,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x598e013bce56e790,
+                    0xb4ee29b3695c98fb,
+                    0x5883fa94cc60f7d5,
+                    0x003bef6696174075,
+                ])),
+                Felt::new(BigInteger256([
+                    0x106b935223a84542,
+                    0xe205dbcc34e221a4,
+                    0x6d67002b4fa9d532,
+                    0x02ec31e4da6d30b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6cd97e2f22c1ba22,
+                    0xba30c6c3cf2e9af4,
+                    0x2d5606220e75f140,
+                    0x013920225bd9d718,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2d978761b7bcbaca,
+                    0x26ced3045f71873e,
+                    0x9ba2b115e00900c6,
+                    0x07b71b26762b6948,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x5c1d38398d3daffe,
+                    0x0ba3c89ca2066cfc,
+                    0xc008d61dc3952c8a,
+                    0x02449f65961ed2a6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x22daeaf67dc1aa4a,
+                    0x3e9bf0ead711e0a2,
+                    0x2f3b34e4ae3a34fb,
+                    0x001107a06485c4cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x16c29ca243c88705,
+                    0x3411bba98b4eb82d,
+                    0x722544c56452dca7,
+                    0x0028a078df9c5d8f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9a85c985b7f01cdd,
+                    0x488496e2ee5be3e5,
+                    0x016f59199277fba8,
+                    0x0047bd8d5ebce614,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x899df57a709afc0a,
+                    0x3d5c35b15096007a,
+                    0xfd2543cab5568bd0,
+                    0x02ccc4c642a84745,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6f3cddc3a63922eb,
+                    0x1cc9e2f76961eb34,
+                    0xb51d36db9726bdaa,
+                    0x0275965918d487ad,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1cd2c50133c02de9,
+                    0x56ab75e1763b736e,
+                    0x2b47c3363357d591,
+                    0x06fc02334623f6cd,
+                ])),
+                Felt::new(BigInteger256([
+                    0x78f74f94c54b2f32,
+                    0x6f0a2f3504580b90,
+                    0x0aa840e9a792974b,
+                    0x0057a51c83d7c31b,
+                ])),
+            ],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress(input));
+        }
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 2));
+        }
+
+        let input_data = [
+            vec![Felt::zero(); 8],
+            vec![Felt::one(); 8],
+            vec![
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            vec![
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x8bc99c159f86b0d9,
+                    0xfb73d4a1c6922fde,
+                    0xb55b0c271c45f22b,
+                    0x02453b24ffcecbd9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e6abc52f860b7bc,
+                    0x64bf84b12f4ab652,
+                    0xfaf7bc08f46a5fed,
+                    0x01bcd79ee1e26db4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x830cf80821215eee,
+                    0xf628aa608ef15d66,
+                    0x9c4a5891d40977ab,
+                    0x06e29b79ae24ad22,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8db1630e48f7a9d4,
+                    0x3e39e7d3c58c06f8,
+                    0x75bae0e1b3651cba,
+                    0x04372ab369abd008,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4dd8cdcb7c6b6f5d,
+                    0x5d68ed05d64c67a0,
+                    0x22a56ae980c36781,
+                    0x03808d5b5bd9c5b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5244ee6421440b7b,
+                    0x69d5f89563674024,
+                    0x365de8203f69f9c6,
+                    0x04acff0206d63b4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc90e0a6eccd558ea,
+                    0x50304434e180e8a0,
+                    0x392a5a3514abcc3b,
+                    0x0552773797b80218,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe3a7b25ba4261def,
+                    0x764c8be9d5e0e475,
+                    0x4899b77c42a455b6,
+                    0x00630babb432c9da,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x1d757e0d0bb14f2e,
+                    0x7e46689d21b0c51a,
+                    0xbd3631cc094b7cee,
+                    0x07595e45325f6d04,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe32ac24a2ce268e2,
+                    0xdb2806373ab4031e,
+                    0x70f6cc0b48568b65,
+                    0x037c1c669d12907b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbc50d5c453968a05,
+                    0xe8f002a56ef40dfc,
+                    0x97f2e2689aadd0d4,
+                    0x01b158f1e1124b9a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x976377054ac5826f,
+                    0x0b81416f9c1b5746,
+                    0xd6ceffe4a02f7f55,
+                    0x0357cb47d830b1b6,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa149bbacf6dde9d9,
+                    0xee8edff0c213e137,
+                    0xbc002f6c5da59834,
+                    0x05422d934feb07fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5bf751f4fb95b94c,
+                    0x83e7b6481b08403c,
+                    0x78bcd70d76ddd9da,
+                    0x06ee946da68a6260,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37faac61d8327c7f,
+                    0x9f03f9d5e095484d,
+                    0x9ff8b9c9fcd03b17,
+                    0x01b5bb6d095fa445,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbe3b4c71c643d9b0,
+                    0x5ba3b9217b5036ba,
+                    0x71b9a329a8123ee9,
+                    0x068026306d6a5a0e,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x6d25a4c92819aea8,
+                    0x46437f484734778a,
+                    0x660d8f92a4b68b08,
+                    0x02249280c90b316a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f850b3a6613713e,
+                    0x819346fd25e8f2a1,
+                    0x003e8a5a09673df5,
+                    0x042c3c49a9c862ee,
+                ])),
+                Felt::new(BigInteger256([
+                    0x21decc6ce088db76,
+                    0x80976813d567e0ea,
+                    0xd813d4f08b1b9e56,
+                    0x022bc53cc400691c,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4dce43070bb15ba2,
+                    0xd7b54c60d22ba100,
+                    0x2a63a18a46e87960,
+                    0x06859a7a8d0073a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf9babbd2687817a8,
+                    0xb7a0dbc73c32bec7,
+                    0xfdc94de3f36ebeab,
+                    0x075b257e87de677b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc1cbcf21879155d4,
+                    0x0f63b71f36b4b6e8,
+                    0x02edc439ea215a20,
+                    0x07a19020b80eece7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf342107d55546565,
+                    0x4c9e3ef1ae324ac7,
+                    0x996fb4fe7a31e302,
+                    0x0647d7e252f80ecb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x156aae3104db6c22,
+                    0x2f0a1eb488f99344,
+                    0xb87d770286fe5f90,
+                    0x076e815d32fe0f35,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x98104cbf86a329c1,
+                    0xa3f3140595b1952f,
+                    0x18dcec545127afa7,
+                    0x075da9134abc29c6,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41489f8fa5bac3c7,
+                    0x4eb0d2c8b7ee165b,
+                    0x67cff809711721b6,
+                    0x07e7fb1080427a86,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd1dfa7137b0ab3fe,
+                    0x7a4e99966eb2b0f4,
+                    0xe87f509fe2d66303,
+                    0x039cd5d522ae7dfd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7b873b30688c1d0,
+                    0x24561d51b848cf05,
+                    0xadcdb6cc2a52475c,
+                    0x067bf16357be169b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x432d1e3f372e2ff3,
+                    0xff2e8a91e136d311,
+                    0x8ffb56c17f7630d7,
+                    0x07c899e4e553b579,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe67d224b8c2bf128,
+                    0x6e2ad3e3c36b52c3,
+                    0x0532a4b42e0d74ae,
+                    0x044556f39fe67031,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff7fc5dfc79ed3b0,
+                    0x80290e9310980fef,
+                    0x2c3940bd5f857372,
+                    0x01304a5409c3d772,
+                ])),
+                Felt::new(BigInteger256([
+                    0x627373ba5e823ec1,
+                    0xf013f93df1b432f3,
+                    0xb5c48532c5e64a5f,
+                    0x033e36dd6ea14a04,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x9f6842dd8ee032de,
+                    0x91eee2f09e3483c7,
+                    0x1bd8763f21557f00,
+                    0x02a439c269e31c89,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa80f88cbe1706704,
+                    0x6da5b878a09b8d05,
+                    0x3ec8133e540cedfb,
+                    0x00351cae4b9e77c2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff960f499e3b2b31,
+                    0x340547f21743d087,
+                    0xede485081214a00f,
+                    0x01db90fef1288be0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabe0f147f6150098,
+                    0x4c7881e45e3a35a7,
+                    0x98438382844d94c7,
+                    0x007fe4f6e02d7e02,
+                ])),
+                Felt::new(BigInteger256([
+                    0x242e825dc89ed242,
+                    0xd3a44e8bb0461f4d,
+                    0x42250b0547f679fe,
+                    0x069953b65f603028,
+                ])),
+                Felt::new(BigInteger256([
+                    0x29a198872ee28697,
+                    0xd99f1558e19b2055,
+                    0x9f92934fc712f9e0,
+                    0x03a5a0147f63fcb1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab39bf087c2dd417,
+                    0x516061d7a93c6218,
+                    0x6dd7f849d71447b4,
+                    0x060ae3d1ad478551,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2f0ad2dbf0af578d,
+                    0x8a806320e968d9e3,
+                    0xd274f7f2f7160359,
+                    0x063fad979bf9b536,
+                ])),
+            ],
+            vec![
+                Felt::new(BigInteger256([
+                    0x1c4922b3b2b031b8,
+                    0x0bc15c111f2e92be,
+                    0x437efa8e5e1f30de,
+                    0x03fca1890645243b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcb973f9c4635eb73,
+                    0xa3973847fb8c90e2,
+                    0xee95daac2b7474cf,
+                    0x01fa9717b652f59a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x69801c928426d73a,
+                    0x80e663b98bef9be0,
+                    0xaaee8dbf0d5b51a9,
+                    0x0176c94ad35c886d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfb9e043dc9dd626c,
+                    0xadf776df7a8242a8,
+                    0x00b5cb3f4ed0f552,
+                    0x015788914dd34f48,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb88f8fa92141845d,
+                    0xbe6b3e602aec89a0,
+                    0xdbcf6125574a98d6,
+                    0x021e40a81c21a5de,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd495aca2edbb18e9,
+                    0x4b3008b3a1c0a858,
+                    0x8e8af46faa8143d8,
+                    0x004800259e1abe61,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdf02ac07d1a991a6,
+                    0x5cef34c974404e3c,
+                    0x506f8080353a9a4d,
+                    0x065f0f7a510a2105,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8483b437520c3b60,
+                    0xce61ca54dbba35f4,
+                    0x3c9cc6bf91262cf5,
+                    0x02698f0238289493,
+                ])),
+            ],
+        ];
+
+        let output_data = [
+            [Felt::new(BigInteger256([
+                0x37858bfb9b0fe2a6,
+                0x31bc7d8111ef7803,
+                0xf955fa6953065b2a,
+                0x040ee0134802582f,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x6648f5772a7b721b,
+                0x76604954bae83802,
+                0x208f2a99705e9bd9,
+                0x02a10ece5b70affb,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x65a985fae76e04cd,
+                0x211f700036be03f8,
+                0x0126a9e38055ba4f,
+                0x0655d1d47eacb846,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x354fd141ac4e8bdb,
+                0x5b9e544b50e3826b,
+                0xea64aee44c4a0f4f,
+                0x0202a8fd63fb41ac,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x2d77ea643aa65db1,
+                0xee717ed397143b08,
+                0xc5060a159a6ad308,
+                0x02abe9611848dab3,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x91abdd22ff82f817,
+                0xd5bdd6d91a8c6c60,
+                0xcd5ca8f4b649def6,
+                0x031769be4cdcf046,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xf93da3926d1482ae,
+                0x7d4f373ada032b60,
+                0x7f44c4a858975a92,
+                0x051a70794420be06,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x676e7187de234b41,
+                0xd53cd6a182ec2d4b,
+                0xdd8d4c4d36e07a1f,
+                0x05d89344af6ef7e8,
+            ]))],
+            [Felt::new(BigInteger256([
+                0xc8138079842e2541,
+                0x1b0609007fe8ce5c,
+                0xd9746c0434799378,
+                0x00b0cc51b12bb3d0,
+            ]))],
+            [Felt::new(BigInteger256([
+                0x74fed9a8dd8d901c,
+                0x34242becff32c7a2,
+                0x05a37e3ce5374d80,
+                0x05f60edae47f467c,
+            ]))],
+        ];
+
+        for (input, expected) in input_data.iter().zip(output_data) {
+            assert_eq!(expected.to_vec(), AnemoiHash::compress_k(input, 8));
+        }
+    }
+
+    #[test]
+    fn test_anemoi_merge() {
+        let a = AnemoiHash::hash(b"a");
+        let b = AnemoiHash::hash(b"b");
+        let c = AnemoiHash::hash(b"c");
+
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, c]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[b, a]));
+        assert_ne!(AnemoiHash::merge(&[a, b]), AnemoiHash::merge(&[a, a]));
+    }
+
+    #[test]
+    fn test_anemoi_batch() {
+        let inputs: Vec<Vec<Felt>> = (0..3 * STATE_WIDTH)
+            .map(|len| (0..len).map(|i| Felt::from((len * i + 1) as u64)).collect())
+            .collect();
+
+        let expected: Vec<AnemoiDigest> = inputs
+            .iter()
+            .map(|input| AnemoiHash::hash_field(input))
+            .collect();
+        assert_eq!(AnemoiHash::hash_field_batch(&inputs), expected);
+
+        let states: Vec<[Felt; STATE_WIDTH]> = (0..2 * BATCH_SIZE + 1)
+            .map(|i| core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64)))
+            .collect();
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress(state))
+            .collect();
+        let output: Vec<Vec<Felt>> = AnemoiHash::compress_batch(&states)
+            .iter()
+            .map(|result| result.to_vec())
+            .collect();
+        assert_eq!(output, expected);
+
+        let expected: Vec<Vec<Felt>> = states
+            .iter()
+            .map(|state| AnemoiHash::compress_k(state, 2))
+            .collect();
+        assert_eq!(AnemoiHash::compress_k_batch(&states, 2), expected);
+
+        let mut level: Vec<Felt> = states[..4].concat();
+        let root = AnemoiHash::merkle_root(&level, 2);
+        while level.len() > STATE_WIDTH / 2 {
+            level = level
+                .chunks(STATE_WIDTH)
+                .flat_map(|siblings| AnemoiHash::compress_k(siblings, 2))
+                .collect();
+        }
+        assert_eq!(root, level);
+    }
+
+    #[test]
+    fn test_anemoi_hash_incremental() {
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [0, 1, 30, 31, 32, 62, 217, 218, 656] {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+            let expected = AnemoiHash::hash(&bytes);
+
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes);
+            assert_eq!(hasher.finalize(), expected);
+
+            let mut hasher = AnemoiHash::new();
+            for byte in bytes.iter() {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), expected);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.update(&bytes[..split]);
+            hasher.update(&bytes[split..]);
+            assert_eq!(hasher.finalize(), expected);
+
+            hasher.reset();
+            assert_eq!(hasher, AnemoiHash::new());
+        }
+    }
+
+    #[test]
+    fn test_anemoi_hash_absorb_field() {
+        use ark_ff::UniformRand;
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+
+        for len in [
+            0,
+            1,
+            RATE_WIDTH - 1,
+            RATE_WIDTH,
+            RATE_WIDTH + 1,
+            3 * RATE_WIDTH,
+        ] {
+            let mut elems = vec![Felt::zero(); len];
+            for elem in elems.iter_mut() {
+                *elem = Felt::rand(&mut rng);
+            }
+            let expected = AnemoiHash::hash_field(&elems);
+
+            let split = (rng.next_u32() as usize) % (len + 1);
+            let mut hasher = AnemoiHash::new();
+            hasher.absorb_field(&elems[..split]);
+            hasher.absorb_field(&elems[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // Buffered bytes are absorbed as a padded chunk before the elements
+        let mut hasher = AnemoiHash::new();
+        hasher.update(b"Anemoi");
+        hasher.absorb_field(&[Felt::zero()]);
+        let mut other = AnemoiHash::new();
+        other.update(b"Anemoi");
+        other.absorb_field(&[]);
+        other.absorb_field(&[Felt::zero()]);
+        assert_eq!(hasher.finalize(), other.finalize());
+        assert_ne!(hasher.finalize(), AnemoiHash::hash(b"Anemoi"));
+    }
+
+    #[test]
+    fn test_anemoi_build_hasher() {
+        let digest = AnemoiHash::hash(b"Anemoi");
+
+        let build_hasher = AnemoiBuildHasher::new();
+        assert_eq!(build_hasher.hash_one(digest), build_hasher.hash_one(digest));
+        assert_ne!(
+            build_hasher.hash_one(digest),
+            AnemoiBuildHasher::new().hash_one(digest)
+        );
+
+        // The key is not displayed
+        let key = [7u8; 32];
+        assert_eq!(
+            format!("{:?}", AnemoiBuildHasher::with_key(key)),
+            "AnemoiBuildHasher { .. }"
+        );
+
+        // Built hashers absorb the key before any written bytes
+        let mut hasher = AnemoiBuildHasher::with_key(key).build_hasher();
+        hasher.write(b"Anemoi");
+        let mut expected = AnemoiHash::new();
+        expected.update(&key);
+        expected.update(b"Anemoi");
+        let bytes = expected.finalize().to_bytes();
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        );
+
+        let mut map = hashbrown::HashMap::with_hasher(build_hasher);
+        for i in 0..100u64 {
+            map.insert(AnemoiHash::hash(&i.to_le_bytes()), i);
+        }
+        for i in 0..100u64 {
+            assert_eq!(map.get(&AnemoiHash::hash(&i.to_le_bytes())), Some(&i));
+        }
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_anemoi_hash_digest() {
+        use ::digest::{Digest, ExtendableOutput, XofReader};
+        use hmac::{Mac, SimpleHmac};
+        use rand_core::{OsRng, RngCore};
+
+        let mut rng = OsRng;
+        let mut bytes = vec![0u8; 100];
+        rng.fill_bytes(&mut bytes);
+        let expected = AnemoiHash::hash(&bytes).to_bytes();
+
+        assert_eq!(<AnemoiHash as Digest>::digest(&bytes)[..], expected[..]);
+
+        let mut hasher = <AnemoiHash as Digest>::new();
+        Digest::update(&mut hasher, &bytes[..10]);
+        Digest::update(&mut hasher, &bytes[10..]);
+        assert_eq!(hasher.finalize_reset()[..], expected[..]);
+        assert_eq!(hasher, AnemoiHash::new());
+
+        // The extendable output matches the inherent one, and does not depend on how it is read
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&bytes);
+        let mut output = [0u8; 500];
+        hasher.finalize_xof().read(&mut output);
+        let mut reader = ExtendableOutput::finalize_xof(hasher);
+        let mut chunked = [0u8; 500];
+        for chunk in chunked.chunks_mut(7) {
+            XofReader::read(&mut reader, chunk);
+        }
+        assert_eq!(output, chunked);
+
+        // HMAC, as specified in RFC 2104, with blocks filling the rate registers
+        let key = b"key";
+        let mac = SimpleHmac::<AnemoiHash>::new_from_slice(key)
+            .unwrap()
+            .chain_update(&bytes)
+            .finalize()
+            .into_bytes();
+        let mut ipad = vec![0x36u8; 217];
+        let mut opad = vec![0x5cu8; 217];
+        for (i, k) in key.iter().enumerate() {
+            ipad[i] ^= k;
+            opad[i] ^= k;
+        }
+        let mut inner = AnemoiHash::new();
+        inner.update(&ipad);
+        inner.update(&bytes);
+        let mut outer = AnemoiHash::new();
+        outer.update(&opad);
+        outer.update(&inner.finalize().to_bytes());
+        assert_eq!(mac[..], outer.finalize().to_bytes()[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_anemoi_hash_serde() {
+        use ark_ff::UniformRand;
+        use rand_core::OsRng;
+
+        let mut rng = OsRng;
+        let mut state = [Felt::zero(); STATE_WIDTH];
+        for item in state.iter_mut() {
+            *item = Felt::rand(&mut rng);
+        }
+
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH - 1,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert_eq!(serde_json::from_str::<AnemoiHash>(&json).unwrap(), hasher);
+
+        let encoded = bincode::serialize(&hasher).unwrap();
+        assert_eq!(
+            bincode::deserialize::<AnemoiHash>(&encoded).unwrap(),
+            hasher
+        );
+
+        // Buffered bytes are preserved
+        let mut hasher = AnemoiHash::new();
+        hasher.update(&[1u8; 32]);
+        let json = serde_json::to_string(&hasher).unwrap();
+        let deserialized = serde_json::from_str::<AnemoiHash>(&json).unwrap();
+        assert_eq!(deserialized, hasher);
+        assert_eq!(deserialized.finalize(), hasher.finalize());
+
+        // Indices out of the rate registers are rejected
+        let hasher = AnemoiHash {
+            state,
+            idx: RATE_WIDTH,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(serde_json::from_str::<AnemoiHash>(&json).is_err());
+    }
+}
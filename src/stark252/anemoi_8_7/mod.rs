@@ -0,0 +1,1819 @@
+//! Implementation of the Anemoi permutation
+
+use super::{mul_by_generator, sbox, BigInteger256, Felt};
+use crate::lanes::Lanes;
+use crate::{Jive, Sponge};
+use ark_ff::{Field, One, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unroll::unroll_for_loops;
+
+/// Digest for Anemoi
+mod digest;
+/// Sponge for Anemoi
+mod hasher;
+/// MDS matrix for Anemoi
+mod mds;
+/// Round constants for Anemoi
+mod round_constants;
+
+pub use digest::AnemoiDigest;
+pub use hasher::{AnemoiBuildHasher, AnemoiHash, AnemoiXofReader};
+
+// ANEMOI CONSTANTS
+// ================================================================================================
+
+/// Function state is set to 8 field elements or 256 bytes.
+/// 1 element of the state is reserved for capacity.
+pub const STATE_WIDTH: usize = 8;
+/// 7 elements of the state are reserved for rate.
+pub const RATE_WIDTH: usize = 7;
+
+/// The state is divided into two even-length rows.
+pub const NUM_COLUMNS: usize = 4;
+
+/// 1 element (32-bytes) is returned as digest.
+pub const DIGEST_SIZE: usize = 1;
+
+/// The number of rounds is set to 10 to provide 128-bit security level.
+pub const NUM_HASH_ROUNDS: usize = 10;
+
+/// Number of states processed together by [`apply_permutation_batch`].
+pub const BATCH_SIZE: usize = 4;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies the Anemoi S-Box on the current
+/// hash state elements.
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+pub(crate) fn apply_sbox(state: &mut [Felt; STATE_WIDTH]) {
+    let mut x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+    let mut y: [Felt; NUM_COLUMNS] = state[NUM_COLUMNS..].try_into().unwrap();
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t -= mul_by_generator(&y2);
+    });
+
+    let mut x_alpha_inv = x;
+    x_alpha_inv
+        .iter_mut()
+        .for_each(|t| *t = sbox::exp_inv_alpha(t));
+
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(i, t)| *t -= x_alpha_inv[i]);
+
+    x.iter_mut().enumerate().for_each(|(i, t)| {
+        let y2 = y[i].square();
+        *t += mul_by_generator(&y2) + sbox::DELTA;
+    });
+
+    state[..NUM_COLUMNS].copy_from_slice(&x);
+    state[NUM_COLUMNS..].copy_from_slice(&y);
+}
+
+/// Applies the Anemoi S-Box on a group of hash states, interleaving
+/// the computations of the different states column by column.
+#[inline(always)]
+pub(crate) fn apply_sbox_batch(states: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE]) {
+    for i in 0..NUM_COLUMNS {
+        let mut x = Lanes([Felt::zero(); BATCH_SIZE]);
+        for (state, t) in states.iter_mut().zip(x.0.iter_mut()) {
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] -= beta_y2;
+            *t = state[i];
+        }
+
+        let x_alpha_inv = sbox::exp_inv_alpha(&x);
+
+        for (state, t) in states.iter_mut().zip(x_alpha_inv.0.iter()) {
+            state[NUM_COLUMNS + i] -= t;
+            let y2 = state[NUM_COLUMNS + i].square();
+            let beta_y2 = mul_by_generator(&y2);
+            state[i] += beta_y2 + sbox::DELTA;
+        }
+    }
+}
+
+/// Applies matrix-vector multiplication of the current
+/// hash state with the Anemoi MDS matrix.
+#[inline(always)]
+pub(crate) fn apply_mds(state: &mut [Felt; STATE_WIDTH]) {
+    apply_mds_internal(&mut state[..NUM_COLUMNS]);
+    state[NUM_COLUMNS..].rotate_left(1);
+    apply_mds_internal(&mut state[NUM_COLUMNS..]);
+}
+
+#[inline(always)]
+fn apply_mds_internal(state: &mut [Felt]) {
+    state[0] += state[1];
+    state[2] += state[3];
+    state[3] += mul_by_generator(&state[0]);
+    state[1] = mul_by_generator(&(state[1] + state[2]));
+    state[0] += state[1];
+    state[2] += mul_by_generator(&state[3]);
+    state[1] += state[2];
+    state[3] += state[0];
+}
+
+// ANEMOI PERMUTATION
+// ================================================================================================
+
+/// Applies an Anemoi permutation to the provided state
+///
+/// This function is **not** constant-time with respect to the
+/// state, as the underlying field arithmetic is not.
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_permutation(state: &mut [Felt; STATE_WIDTH]) {
+    for i in 0..NUM_HASH_ROUNDS {
+        apply_round(state, i);
+    }
+
+    apply_mds(state)
+}
+
+/// Applies an Anemoi round to the provided state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    apply_round_constants(state, step);
+    apply_mds(state);
+    apply_sbox(state);
+}
+
+/// Adds the round constants of the provided step to the state
+#[inline(always)]
+#[unroll_for_loops]
+pub(crate) fn apply_round_constants(state: &mut [Felt; STATE_WIDTH], step: usize) {
+    // determine which round constants to use
+    let c = &round_constants::C[step % NUM_HASH_ROUNDS];
+    let d = &round_constants::D[step % NUM_HASH_ROUNDS];
+
+    for i in 0..NUM_COLUMNS {
+        state[i] += c[i];
+        state[NUM_COLUMNS + i] += d[i];
+    }
+}
+
+/// Applies an Anemoi permutation to each of the provided states.
+///
+/// The states are processed by groups of [`BATCH_SIZE`], whose S-Box
+/// computations are interleaved to benefit from instruction-level
+/// parallelism. This yields a higher throughput than applying the
+/// permutation to each state in turn. With the `parallel` feature,
+/// the groups are additionally processed in parallel.
+pub fn apply_permutation_batch(states: &mut [[Felt; STATE_WIDTH]]) {
+    #[cfg(not(feature = "parallel"))]
+    states
+        .chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+
+    #[cfg(feature = "parallel")]
+    states
+        .par_chunks_mut(BATCH_SIZE)
+        .for_each(apply_permutation_group);
+}
+
+/// Applies an Anemoi permutation to each state of a group of at most
+/// [`BATCH_SIZE`] states, interleaving their S-Box computations if the
+/// group is full.
+fn apply_permutation_group(states: &mut [[Felt; STATE_WIDTH]]) {
+    if states.len() < BATCH_SIZE {
+        states.iter_mut().for_each(apply_permutation);
+        return;
+    }
+
+    let group: &mut [[Felt; STATE_WIDTH]; BATCH_SIZE] = states.try_into().unwrap();
+    for i in 0..NUM_HASH_ROUNDS {
+        for state in group.iter_mut() {
+            apply_round_constants(state, i);
+            apply_mds(state);
+        }
+        apply_sbox_batch(group);
+    }
+
+    group.iter_mut().for_each(apply_mds);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_naive_mds(state: &mut [Felt; STATE_WIDTH]) {
+        let x: [Felt; NUM_COLUMNS] = state[..NUM_COLUMNS].try_into().unwrap();
+        let mut y: [Felt; NUM_COLUMNS] = [Felt::zero(); NUM_COLUMNS];
+        y[0..NUM_COLUMNS - 1].copy_from_slice(&state[NUM_COLUMNS + 1..]);
+        y[NUM_COLUMNS - 1] = state[NUM_COLUMNS];
+
+        let mut result = [Felt::zero(); STATE_WIDTH];
+        for (i, r) in result.iter_mut().enumerate().take(NUM_COLUMNS) {
+            for (j, s) in x.into_iter().enumerate().take(NUM_COLUMNS) {
+                *r += s * mds::MDS[i * NUM_COLUMNS + j];
+            }
+        }
+        for (i, r) in result.iter_mut().enumerate().skip(NUM_COLUMNS) {
+            for (j, s) in y.into_iter().enumerate() {
+                *r += s * mds::MDS[(i - NUM_COLUMNS) * NUM_COLUMNS + j];
+            }
+        }
+
+        state.copy_from_slice(&result);
+    }
+
+    #[test]
+    fn test_sbox() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xa035cc053af11e8f,
+                    0x22f718f94621db4b,
+                    0x0ffe758b437ac137,
+                    0x0166c116416622ff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0311f21e5a8577e0,
+                    0x93c3de1070be44a9,
+                    0x59ddd863bd1dc317,
+                    0x02b7052ee88bf8a0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb276346610d223cb,
+                    0xa7c88cc707cda701,
+                    0x96ed9eee486ae6ae,
+                    0x02c48669d45215bb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4571499b242eca0a,
+                    0x5d620cfa4cf39689,
+                    0x76e4074cd8087792,
+                    0x0074261af7658702,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf875ef3a3162744a,
+                    0x8e02601a5ae65df2,
+                    0xf57486ee0980ab6c,
+                    0x05701fa76e6b6f00,
+                ])),
+                Felt::new(BigInteger256([
+                    0x596e26c62f041712,
+                    0xbde62f6e8a18ca50,
+                    0x4b8a90d5d3623b63,
+                    0x00dc575e9b5fdb28,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb619ff59a31ca0a5,
+                    0x6006f247590016f9,
+                    0xd56c0f2e1e3dbfab,
+                    0x05864e64683c6521,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb605f2c4beb5d858,
+                    0xc0821494b6793f62,
+                    0x548479b738ef8e30,
+                    0x02c41ad50d910aa9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x705315fbe3cab2c4,
+                    0x71556fb3a6caabc3,
+                    0xe291bfacc8abcbfc,
+                    0x011aac1b32969723,
+                ])),
+                Felt::new(BigInteger256([
+                    0x61ca10f99b0f6a90,
+                    0xb6bb146579785357,
+                    0xe6aa310d53d2cd00,
+                    0x00f9fd5593f4d001,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe856507291b0a824,
+                    0xfeec77d70a322c51,
+                    0xd9b2043e77258e98,
+                    0x05afe50725463d98,
+                ])),
+                Felt::new(BigInteger256([
+                    0x576dc96f96b427c1,
+                    0x8dffbdc7754255d1,
+                    0xb082de3d1a55636e,
+                    0x02e7988029478c44,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e81a005a5791276,
+                    0x8ece5af5b1a4f251,
+                    0x3981b9ead772c1e2,
+                    0x068e2ea00e2b769d,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb2415f44fb862b53,
+                    0xc9fbacc0e9a530ac,
+                    0xcd269895a52c5246,
+                    0x0426ddaebe36a972,
+                ])),
+                Felt::new(BigInteger256([
+                    0x64c283d7f8568828,
+                    0x04db7fcd417dbbe5,
+                    0xd4ea2931048f203c,
+                    0x007b7025ea993816,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1071a761872d6426,
+                    0xff89913de4a591ac,
+                    0x8edf6b77f9cf207b,
+                    0x01bd2d166cd49dc8,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x7d5eced146af73e3,
+                    0x5a6b97f214239617,
+                    0x0cb1828637cc4bae,
+                    0x077479b2348d8f1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x77abd8436b9923ea,
+                    0x1f8740468e5db778,
+                    0x24899883c85304c3,
+                    0x00b133552ecbaf40,
+                ])),
+                Felt::new(BigInteger256([
+                    0xab028c78a7051a0d,
+                    0x047ae12ab42ad261,
+                    0xe678962dc316cdeb,
+                    0x004d91bef01770fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x764e7289250c9f11,
+                    0x197537ca9460a1f3,
+                    0xbf83033e454053cd,
+                    0x0307fa9b57fe4bcf,
+                ])),
+                Felt::new(BigInteger256([
+                    0xff75d72ec65417b6,
+                    0x585ee8bfa74550a4,
+                    0xe1282c0b82db268e,
+                    0x02414ea3aac4c8d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x68426905594e7c48,
+                    0x4d18115d332e340f,
+                    0x66533dc8b4ad2690,
+                    0x05f921384745fb60,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6b655b86ace8f62f,
+                    0xcb26ea0d1af72db7,
+                    0xd1bd59ec9afa02ce,
+                    0x03683b1199175c83,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0212a74a0601c20,
+                    0x28fa46e8d6cc04a0,
+                    0xb9e9cc0d076802d7,
+                    0x041749c9f7dcac62,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x6e24c010c34b657c,
+                    0xb29bbccb2636e08e,
+                    0xbbae73af5902141b,
+                    0x02090553306686d7,
+                ])),
+                Felt::new(BigInteger256([
+                    0x933b3be90f11b59a,
+                    0x74f06f54501dc0ed,
+                    0xe7661b3447ccad51,
+                    0x0158674c85154960,
+                ])),
+                Felt::new(BigInteger256([
+                    0x871edb2dd3ab08a1,
+                    0x59acf9858fd51c71,
+                    0x36611b23b3f3dd2a,
+                    0x00c7554a3a284dff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4fd73e904260a372,
+                    0xec3543c48454354f,
+                    0x5bc16eb4b6fc6de2,
+                    0x03ee9a4410c106f8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13fdb61377c56840,
+                    0x76a1f6df3b458f42,
+                    0x37bdac02c642205e,
+                    0x05cde56f7a595f71,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5c328ac35d646a27,
+                    0x451af36a8da8ca22,
+                    0x259624c9fdf1338d,
+                    0x04c4740d42a20a17,
+                ])),
+                Felt::new(BigInteger256([
+                    0x89332a607825b022,
+                    0x112483424690bb97,
+                    0x3e4567de77b81854,
+                    0x0626d1438e599628,
+                ])),
+                Felt::new(BigInteger256([
+                    0x02a0b29b523ff044,
+                    0xebc6fbf9dac9662d,
+                    0x35bccb951c17f780,
+                    0x03bca1573edc26a9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xca1f6647c413deb3,
+                    0x961e229391b3e797,
+                    0x1e94e8d474abc402,
+                    0x017f551ec68d5591,
+                ])),
+                Felt::new(BigInteger256([
+                    0x40e3d1284ac5d1a3,
+                    0x2e3749daa9734987,
+                    0x797630524dde7572,
+                    0x07e9945057a28db0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8e827dea44b61a08,
+                    0xf28b954ffa197b3b,
+                    0xc978843c51c1ea0c,
+                    0x02af8eab1d18f3d1,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1b070de933e42161,
+                    0x53b3dad8d89a2772,
+                    0x42c439916c3e38ff,
+                    0x06f934da07fd15d3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8dc8e797a11c3e3c,
+                    0xbd5667636877e8ad,
+                    0x821e9442bf6fe79b,
+                    0x076e780f126d8e11,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe9893d3a9c4d0923,
+                    0x45af3a33264c876e,
+                    0x4d73132e4e0dcce7,
+                    0x048dea0fa2b11249,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbef599542f9a1fb0,
+                    0x0c8c7bd8882c3af9,
+                    0x30d1031b96771777,
+                    0x0021e5f64fd454a2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e93f5dc73ce881a,
+                    0x3a69543dd40ff9ad,
+                    0x618410d33f349b78,
+                    0x054da4c1f7b2c507,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2366b4f20c78a190,
+                    0xcdd36f55f1288ff3,
+                    0xa3959cbe21d6e44e,
+                    0x000ffe1883547180,
+                ])),
+                Felt::new(BigInteger256([
+                    0x19976d56399131cc,
+                    0xdf8b25c3b64ad24c,
+                    0x69f5a9369cba9668,
+                    0x0033ae02007f4546,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4013c0af4d6b34c0,
+                    0x4687a5e5704228e2,
+                    0xa37e813a8b5bf67c,
+                    0x030e6e0dfc8364b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9bfaf73571eb57dc,
+                    0xe395ffc7a1d1ad1a,
+                    0x5418e425af8e0248,
+                    0x0072af56b6818ae9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8a690dfe15f2cf03,
+                    0xef10f7a68cad1e44,
+                    0xd52c6317118263c7,
+                    0x0370e7090547a005,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe60660841f3afc9e,
+                    0xc1142577db0db21c,
+                    0x5df437fb13eae906,
+                    0x059411af1c7cdf01,
+                ])),
+                Felt::new(BigInteger256([
+                    0x98df8e45ec4ff559,
+                    0xe014653082c7a8a6,
+                    0xbe881377dfccfd1c,
+                    0x06fbcd6a269bbf8e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5912c4a3f92326c4,
+                    0xe38276cf7c77ff56,
+                    0xcdf60b14b149d24c,
+                    0x03fb37f721d09320,
+                ])),
+            ],
+        ];
+
+        let output = [
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaaa1,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07ffffffffffff5b,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4cce4c9cbf804ce1,
+                    0x00511e0db4f62dc5,
+                    0xc27d6b30f32a95ff,
+                    0x057b737ffc5da003,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc6ca029b1ae23a25,
+                    0xbe4874c584a88cfd,
+                    0x2e41e795aaa46639,
+                    0x07e1e974ddb3729b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x41f540dbc46cc9c3,
+                    0x8c6fbfc4cda67d98,
+                    0xc1fd69ae6484f60b,
+                    0x028e49f482a0f341,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+                Felt::new(BigInteger256([
+                    0x97fc6462c8f8390c,
+                    0x6166fa76cfd1ab9e,
+                    0xb663b01101737a37,
+                    0x07b2f3013869b618,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaaaaaaaaaaaaaa21,
+                    0xaaaaaaaaaaaaaaaa,
+                    0xaaaaaaaaaaaaaaaa,
+                    0x07fffffffffff6db,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0000000000000020,
+                    0x0000000000000000,
+                    0x0000000000000000,
+                    0x0000000000000220,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1c88ba843b270826,
+                    0xf6751cfa6624b194,
+                    0xba43e35407382e47,
+                    0x0033d339b1f39ea3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3dc52a9887747e4b,
+                    0x39d5e668ae0eb0ae,
+                    0x3de631a58581fb0b,
+                    0x049e158d03148a4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x7bc920c47978c238,
+                    0xd450fde5c7d26896,
+                    0x4ecf919e9579fb62,
+                    0x0336c201b87dfa18,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4dc2b06da76c312b,
+                    0xdd3753b7c0077973,
+                    0x8206215c02575c64,
+                    0x046536d548eeccf8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x72e0f0f355a7757b,
+                    0x7ea6bbd05f787c3d,
+                    0x219cb4fc13590434,
+                    0x02076ad3dd68f32a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x996c7903e73ffff6,
+                    0x315218b82cc0b79d,
+                    0x65849fc0ab71ae38,
+                    0x03f921e29a15f023,
+                ])),
+                Felt::new(BigInteger256([
+                    0x60215b3de8bdd112,
+                    0xdf407daa69ee3039,
+                    0x1ba2febd54aac9fa,
+                    0x02dd9b88e5923816,
+                ])),
+                Felt::new(BigInteger256([
+                    0x211fe0dc2cb74110,
+                    0x700534a624aeadae,
+                    0x82557071b90650ef,
+                    0x04647a5106e62fc4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xe5184ecf20de7ce1,
+                    0xe729529fecdfcd0f,
+                    0x239d01b660ba94dd,
+                    0x04fc28445fb0011a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x93326e6f02d5df38,
+                    0x282a58a04700a453,
+                    0xc39796e4ae067876,
+                    0x01359b17fe254894,
+                ])),
+                Felt::new(BigInteger256([
+                    0x55e262be6ade5692,
+                    0x1ddbdcf54d0a847f,
+                    0xcb7a9208a36d9546,
+                    0x0059c219a8497de8,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99f404d2722f0d41,
+                    0x13fc03b2e963b543,
+                    0xb6de41478b152cb2,
+                    0x02980bfb05c61560,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4633ee3ece1d1309,
+                    0xdc49c90109abf3e4,
+                    0x209716d539de45b4,
+                    0x0598254184a8503c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfadf236e173ad236,
+                    0xdea88f8cf424be34,
+                    0xb1e4ecead126c963,
+                    0x03a0724a48c43b18,
+                ])),
+                Felt::new(BigInteger256([
+                    0x453b08edf9e5765e,
+                    0x7db1e99cd0debd5e,
+                    0x8aa7cc9457bd4436,
+                    0x062394def711136e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc85a73dbddc1092,
+                    0x75bce85b10e36772,
+                    0xe0609464a2c97edb,
+                    0x05faa33385744625,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x233bbfca93826488,
+                    0x844fd2d08d8b4c90,
+                    0xd366036777493e9f,
+                    0x06ec19e713e21bf4,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5574ae5802ab88cd,
+                    0x4a803bccd432afa4,
+                    0x6e2543686e63078f,
+                    0x04dc4be2fb264250,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbb1333f0e336139f,
+                    0x6901df8b4bac3372,
+                    0x882e8c734d423544,
+                    0x052a1c12166581c2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa50aad756c4f9274,
+                    0x868e23317a0b92f0,
+                    0xaeac958721a3d869,
+                    0x05c43b0d99d08db7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb264ae67df49133a,
+                    0xe24adcaf39744c24,
+                    0x6a633f3e194d629f,
+                    0x05d9a3703e494eca,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe20c2f17728123f3,
+                    0xc0a69d092ee72c81,
+                    0x08caeee00137aab0,
+                    0x013742e54a3df7f4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa7c7f09d1db07a87,
+                    0x34e917f27d3eb79d,
+                    0x1fb2e75ba801e363,
+                    0x025a2ba0a95d7f8e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x36f502dbc7977a4d,
+                    0x1fb02311b35ebef3,
+                    0xb07f9a0317112470,
+                    0x07a00d5043c3e43f,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1406a4839f854be7,
+                    0x7bc3b31588a48a82,
+                    0x64dfcc4b935d53e6,
+                    0x07da566543a49840,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf0b0c7ed83e926c0,
+                    0x197db01a377415a9,
+                    0x345fc7a9b991f84b,
+                    0x018515bb6c5df628,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa58bcb41957b6f15,
+                    0x4df8d9c6f02b16ff,
+                    0x62cf621b89145edb,
+                    0x0158d1bf30b86522,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4199a270700fa14a,
+                    0x60b240bc0aa08b4e,
+                    0xafe8ebc7ab13d30e,
+                    0x046b35e107794dea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x28ef44eb207f815e,
+                    0x9a89fa2a2be527af,
+                    0xdcf5220a3a3f52b6,
+                    0x039aa087632d7925,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfa3d12835708556e,
+                    0xa800262d84e4d16c,
+                    0xd1b75ebaa8d8b42b,
+                    0x06b738366278f685,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13aae5b6a35c1dc1,
+                    0x8b5f779462b91074,
+                    0x69cbd0017b512e76,
+                    0x032329e85de5d14a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x712f7abddf53b569,
+                    0x60a726bcc6ca4a89,
+                    0xdd5f21ad6ed703f7,
+                    0x024d2f9edf261d1b,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x11db3d39aa01eed9,
+                    0xf4363f48f7852ed1,
+                    0x2a4212a2c0045fd7,
+                    0x023f6f1f915b2346,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbba4cff90fc4a0d4,
+                    0x37c0d8ee91efbf83,
+                    0x52013d067f03c3ac,
+                    0x040896d3f726d8fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2cb799ccfbb16923,
+                    0xd54a9474036d1b89,
+                    0x3db77678fe9f2b73,
+                    0x05cbd77755a417f3,
+                ])),
+                Felt::new(BigInteger256([
+                    0x49a2dbc45c342eb5,
+                    0xf03f8749786912d5,
+                    0x4f3f13211be5548a,
+                    0x05d763600e745ed1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xde1428375ef5d6f0,
+                    0x88539f39c1f6576c,
+                    0x2b78660be59d6468,
+                    0x046afa1a36507563,
+                ])),
+                Felt::new(BigInteger256([
+                    0xaf43ed4aa2ef18ad,
+                    0x3f067f4e5805dc65,
+                    0xb1c12e5a33c767d3,
+                    0x024915d5406861e9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x09e4633f73d70e4f,
+                    0x09d98bce0ec7e5cf,
+                    0x5fcf5d3741ccc93a,
+                    0x03435dbcbe762881,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf1f0215211639348,
+                    0x45b5382086c1aa5e,
+                    0x166a7022df01ebda,
+                    0x0098e8b77f575a22,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x789c1f0387c1e3cb,
+                    0x9815bbb5c84a168c,
+                    0xef37f8334aa7c8cc,
+                    0x00ab6ca54f380eeb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6486ee79f7f820ee,
+                    0xc5363fe0c6f4a179,
+                    0x9576336b24e104ae,
+                    0x0459977d14c5c0b4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xec0b46fcfc37ec15,
+                    0x4ec9a6685e8f80bc,
+                    0xaed3bd8519d0a8c4,
+                    0x0104860629d38ade,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee3961b137f2c385,
+                    0xa78bb45050353059,
+                    0x1954019b4c3060cb,
+                    0x06bf7974941fe192,
+                ])),
+                Felt::new(BigInteger256([
+                    0x3bb6622766e9b367,
+                    0xad2ca7f8d6c38064,
+                    0x89a332949833c1c1,
+                    0x022c19582ff9e8d2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe950912b6b3c8b5c,
+                    0xc417250b34558a1c,
+                    0x4ebe9171536c05ee,
+                    0x01038fbdd81c259e,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb99a7c8305a12668,
+                    0xa504769e169ac867,
+                    0xd6a60dd8beb8b0f2,
+                    0x077dac80a448ffc2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0e82b88bc5676617,
+                    0x6c0aae814f14300d,
+                    0xb077e95f373c3a3b,
+                    0x04376d30b2371066,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_sbox(i);
+        }
+
+        for (&i, o) in input.iter().zip(output) {
+            assert_eq!(i, o);
+        }
+    }
+
+    #[test]
+    fn test_mds() {
+        // Generated with scripts/anemoi.py, a port of https://github.com/anemoi-hash/anemoi-hash/
+        let mut input = [
+            [Felt::zero(); 8],
+            [Felt::one(); 8],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+            ],
+            [
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::one(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x71b9c24c37928aca,
+                    0xa57e4c51cdc83114,
+                    0x3fd30356d459672e,
+                    0x0358b398ec881653,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd034b5524213fe79,
+                    0x335436ccde1ad6ed,
+                    0x666194f0d0c0c4cb,
+                    0x03638f4cb6718228,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc91356e773b925db,
+                    0x9209317a807fca76,
+                    0x0b411420d6f50744,
+                    0x06404b99b6cb85b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92de012b8035ef74,
+                    0xd2f976e06ac45e5d,
+                    0xafd7010b225183cc,
+                    0x01830856324a6f35,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8b26b9a7e095e685,
+                    0xfa3c4c0d8a4480af,
+                    0x27310e5381705f54,
+                    0x043fcda23ae0a7d1,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcde096a706d5aa51,
+                    0xe856f749ac71d14a,
+                    0x5229b131a387e303,
+                    0x057d5bc3df8314af,
+                ])),
+                Felt::new(BigInteger256([
+                    0x402e72ade9ef00bc,
+                    0xc6f2ad04780ae794,
+                    0x16c5a0910afdca9b,
+                    0x06f0c680900b0319,
+                ])),
+                Felt::new(BigInteger256([
+                    0x17b17193192a3fbd,
+                    0xf9f136be56609555,
+                    0xdf239cc219299eaf,
+                    0x04938d9b14d85a8e,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x932fc1eef5bd25e3,
+                    0x54a16b7a1285881f,
+                    0x4467e37b9c232566,
+                    0x034a3277ebc68178,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc7375171529c391b,
+                    0x5a32b80dfc323142,
+                    0xd442538cdb2961fa,
+                    0x06c85122a06effb8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa968f510cc4d839c,
+                    0xfd191933a97925c6,
+                    0x0fa991317dc3f216,
+                    0x04d52b86dcdcc744,
+                ])),
+                Felt::new(BigInteger256([
+                    0xf7962520fea9fb26,
+                    0x971d53800564a6e1,
+                    0x8b2742bf57b582f6,
+                    0x01d3c3d593d11083,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1e7990462d52c98d,
+                    0xa22c2c4eac4df7c9,
+                    0x1594a11fd699d086,
+                    0x0189b07513c7ce57,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa6e66195d5bbd514,
+                    0x38694df5c66bca27,
+                    0x1140a75d051d3b6a,
+                    0x017b5a70a74f8135,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92018568011cd020,
+                    0x1f9c55ceff5fb656,
+                    0x8fad9e632a4678dd,
+                    0x0786feeca92658fa,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb0d4a4c9dc4420ac,
+                    0x2e9378456f278d5d,
+                    0xc3921cdf89423908,
+                    0x073453fe57965733,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc48dbdfcec3e7add,
+                    0x1447451ee6fbdf1c,
+                    0x76f830eb11db97fe,
+                    0x07883d3eb6c810b2,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffafb1ded41ae14,
+                    0xf5828c2e3584060e,
+                    0x699cd4769abfcf3b,
+                    0x05e8a6c0b27dddea,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26d808fd47104d7c,
+                    0xa6721b1f4f703161,
+                    0x02d4f0200658b579,
+                    0x026d3b76d9a05788,
+                ])),
+                Felt::new(BigInteger256([
+                    0x469ccda612f115fb,
+                    0xa6db6a0e3f89e8a2,
+                    0x39d017b05885203a,
+                    0x05262b7c6fc36940,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfc94dd56dd287eff,
+                    0xe7959e74d302efb9,
+                    0xa8d5fd600eb8c01a,
+                    0x03dab3e3177c51b9,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2bc0c2bc1d21ae1c,
+                    0xe7d0a60cc320d1ba,
+                    0xd9a0540c90876576,
+                    0x019bccd96a52acc7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xabe181f361e3f379,
+                    0x1eff77fe76ceaa7e,
+                    0x203df8df46d87e91,
+                    0x064969a49be7a343,
+                ])),
+                Felt::new(BigInteger256([
+                    0x67c05a8e255beaa3,
+                    0x0b79582e426a9a91,
+                    0x74148447f492bbea,
+                    0x07d431fa0f524fb5,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x2361f49664f79cd2,
+                    0x40390ad82008c37f,
+                    0xee6f7cc32fcf63ba,
+                    0x0774c1fb32f0362e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x54a7dc64156f96e8,
+                    0x0aa326f940c1509d,
+                    0xc2d130686c56d372,
+                    0x04e26cd6b95335a8,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbcc217fd976bfb8a,
+                    0x86b851986213b992,
+                    0x26ceba6874fbb7ef,
+                    0x02ae27b07447473e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x92f5e2587620dad4,
+                    0xd602f93d3d71e64e,
+                    0x5613da8b98d0ae4e,
+                    0x003221649994e846,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcfc003bb141616c7,
+                    0x74eaf86c14114b28,
+                    0x495f6730ca237616,
+                    0x05dac8acd5ba470b,
+                ])),
+                Felt::new(BigInteger256([
+                    0xdfce0b4957bf72ad,
+                    0x7c6f410b8b069b23,
+                    0xef00fc52f4396c9d,
+                    0x0502de6788910eca,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4c6262e591476671,
+                    0xd1e4e618aace01e5,
+                    0x7c67611947ff3671,
+                    0x03b5c64096499982,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5571fa977d476d11,
+                    0x28b64e0f59f817e9,
+                    0xd17ada682dfbc4eb,
+                    0x06c5d3e3940c3fd3,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x694be0b1619941a5,
+                    0xf455f3202f1d60fc,
+                    0x00707b80c3920ba3,
+                    0x063721ec009b4285,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd1d26426c1a196a7,
+                    0x75a5efc6a8ed174e,
+                    0x2467dd8944fa7bbb,
+                    0x00cb62f9db3e8146,
+                ])),
+                Felt::new(BigInteger256([
+                    0xbcf7305696750b32,
+                    0x9e88effe816b59ab,
+                    0x553b4452c0bb8ed5,
+                    0x02ce16e80ac9d4fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba3e46105376f5cc,
+                    0x2e0a04e7dd1ea093,
+                    0xf170feaff291df6c,
+                    0x0192d1e644fb08da,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0ee3ce467bdd4356,
+                    0x52894283a353d043,
+                    0x22849d3144d416d9,
+                    0x0197841bfd7def32,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe2f7567037c23039,
+                    0xeaa5c6a11f445203,
+                    0xe76c141cd7ab54f0,
+                    0x020ee6555a8170dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1181f6cf117a4d6f,
+                    0x8c1545c237d36d0f,
+                    0x7430ecedc09c5f51,
+                    0x07356fbd6fb23993,
+                ])),
+                Felt::new(BigInteger256([
+                    0x37a880942e0c05d5,
+                    0xd9ab05f4b0ef9c1b,
+                    0xa389b482c0cda9d3,
+                    0x02c480eb04aa6cb4,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xad5363f6445f2bd0,
+                    0x6c7813941d2ed890,
+                    0x442e28d1af908180,
+                    0x02379d6698597b78,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2404751c3c40ef26,
+                    0xdebbed2fdea3fa1b,
+                    0x3f9f6094fea9be78,
+                    0x00d9dabce59a2257,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76f7a627c473b06a,
+                    0x581e7605276fa135,
+                    0xcd3ef32a507ca324,
+                    0x06d81adf52d7c035,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa1974d73805a5d17,
+                    0x9f3eef8681350df3,
+                    0x62beb7629707a470,
+                    0x03aa8ca782d8f645,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc5161b8ddad8dc29,
+                    0x1098210d02769ec0,
+                    0x0197d543ef93a558,
+                    0x00aec2d4843c98c0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x863c2890c42e1f68,
+                    0x156db74ab9e130e2,
+                    0x0112e52ccbb6e6e7,
+                    0x01044f5c2642decb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xecd755892b841f45,
+                    0x6f309374d1cf9418,
+                    0x068cca77ee4746b9,
+                    0x04b5f599ef2a1aea,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe5858c3611c5057f,
+                    0xcad3305f6e674e41,
+                    0x3fe81b86e1504f46,
+                    0x01ae4f9c919ba96d,
+                ])),
+            ],
+        ];
+
+        let mut input2 = input;
+
+        let output = [
+            [Felt::zero(); 8],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffea1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe8b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffc01,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffbc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd21,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffdc1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd9d0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffea1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe8b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffc01,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffbc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd21,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffdc1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd9d0,
+                ])),
+            ],
+            [
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffea1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe8b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffc01,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffbc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd21,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffdc1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd9d0,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xfffffffffffffea1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffe8b0,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffc01,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffbc10,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffd21,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffcf30,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfffffffffffffdc1,
+                    0xffffffffffffffff,
+                    0xffffffffffffffff,
+                    0x07ffffffffffd9d0,
+                ])),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+                Felt::zero(),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0xc6609fce1bafc496,
+                    0xa1d7209608000748,
+                    0x0aa1969e03301b8f,
+                    0x0030ec9b818ffd57,
+                ])),
+                Felt::new(BigInteger256([
+                    0xea5fb8575d74f251,
+                    0x465a468792a98b47,
+                    0x06e371288c3c91d6,
+                    0x025f70bb19520335,
+                ])),
+                Felt::new(BigInteger256([
+                    0x65ed9027bb6bb6fd,
+                    0x7d55a910368c8c01,
+                    0xa27672d22e26a240,
+                    0x00eac7053abb9e48,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f0a07d508d94fd1,
+                    0xff4820d2766d7dad,
+                    0xad16608014d02349,
+                    0x05e8bda29cc735dd,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb722e30f9bd22000,
+                    0xe0aa33bf2e8cb1a9,
+                    0xc03e34b69f4d0781,
+                    0x03ba877e0eda27bc,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6be785e9bd79522a,
+                    0x3dd9bd24d1e510b5,
+                    0x83a098dc32659083,
+                    0x04be5dc58ba5ded4,
+                ])),
+                Felt::new(BigInteger256([
+                    0xc2d3ac2f126bdd36,
+                    0x0c792db3c7d517ea,
+                    0x2c51b5e8419e36a1,
+                    0x0571f88bec59cecf,
+                ])),
+                Felt::new(BigInteger256([
+                    0x6c76b8b64eb607a7,
+                    0xe8c36cb726475cf6,
+                    0x223d38522c4e6fb5,
+                    0x0544bbed98651692,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x930a5649a114868f,
+                    0x7a0f91cd0fe7b324,
+                    0x65e3ad8189350c78,
+                    0x02664517bf8c074c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xee047ae0aad4eea8,
+                    0x4a3f154144b13f85,
+                    0x677c01f42033456f,
+                    0x071e9cc94d59196c,
+                ])),
+                Felt::new(BigInteger256([
+                    0xb56137f75219c716,
+                    0x7f03a6fc438145c3,
+                    0x1a428b7b0e4ac057,
+                    0x06cadb4c1a029340,
+                ])),
+                Felt::new(BigInteger256([
+                    0x99d5b58b78ca9eab,
+                    0x1da94fe54173862d,
+                    0x3b09955a46d02591,
+                    0x027193bcf7fd9b1f,
+                ])),
+                Felt::new(BigInteger256([
+                    0x5cd71665f6f3d438,
+                    0x291992ee164b32f6,
+                    0xdb6b5ae7cdcb3b8b,
+                    0x01d1637d8e035548,
+                ])),
+                Felt::new(BigInteger256([
+                    0x4ed134373f4844ae,
+                    0xa08ada946506a36a,
+                    0xfcc22a482c9258e8,
+                    0x073f443ab867b6ab,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2ae204cf1f2d15a9,
+                    0xcf76eb6b1486f0f2,
+                    0xc24515208e2ad1a4,
+                    0x06703a1a7ada3b81,
+                ])),
+                Felt::new(BigInteger256([
+                    0x26085ba5a8d08d5e,
+                    0xd356aa8b13fbac3a,
+                    0xd3cacd48329028e7,
+                    0x0662200a932cb1fb,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x0cd82e5eaf495d8c,
+                    0xd23a056069fa4563,
+                    0xd35a9a369974560a,
+                    0x05e50d1b5ceaca4e,
+                ])),
+                Felt::new(BigInteger256([
+                    0x726936cb0e1f4a6b,
+                    0x626853219b9142ab,
+                    0xc218142566bcc63f,
+                    0x0072167f3fc83a9d,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2a19c187385615d0,
+                    0x99f81f0e4e16e274,
+                    0xcf527f5079e3d76e,
+                    0x07fded634c235eeb,
+                ])),
+                Felt::new(BigInteger256([
+                    0xa10f27554ebaee54,
+                    0x9672e355ff03dd88,
+                    0xaee9c20bf7cbabf3,
+                    0x035de496087ffeff,
+                ])),
+                Felt::new(BigInteger256([
+                    0x08467238ac3eb8df,
+                    0x3cfb69efdea41a97,
+                    0xb157bc81b5cbd3ca,
+                    0x05ce25034e5d1daa,
+                ])),
+                Felt::new(BigInteger256([
+                    0x1f6c679e3e69ac1b,
+                    0x1d4c2c4b3c9655d7,
+                    0x97b69da8a040b060,
+                    0x0436da79ed7333e3,
+                ])),
+                Felt::new(BigInteger256([
+                    0xeec83a15113094d1,
+                    0xe720e06697e1b778,
+                    0xe03d2e12c1d4c09d,
+                    0x064debf4a5506643,
+                ])),
+                Felt::new(BigInteger256([
+                    0x8bc21d9e06781c99,
+                    0x390162865f757efb,
+                    0x47c8a0a54aa43ffd,
+                    0x01587c6078885f41,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x65295528e35c7b88,
+                    0x80f7873e019ee598,
+                    0x705bfd410a8fe43d,
+                    0x039f509541d19b1b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2e117e5c26858025,
+                    0xb69d4c565fcad949,
+                    0x797f8b34c4000eca,
+                    0x01cf74677e87e153,
+                ])),
+                Felt::new(BigInteger256([
+                    0x40f1fa2dbd903856,
+                    0x8081f6e9bef607cd,
+                    0xba643b1f559661b9,
+                    0x028752a428f9b1fe,
+                ])),
+                Felt::new(BigInteger256([
+                    0x603caa70c8b2f185,
+                    0x378f15ef616f083c,
+                    0xda31df4f77d33811,
+                    0x00d6fe6fa030c693,
+                ])),
+                Felt::new(BigInteger256([
+                    0x80ed91d750f597f2,
+                    0x9ce6ace0805acbef,
+                    0x312d4582fc93f769,
+                    0x01bbcd1b1f0b08fb,
+                ])),
+                Felt::new(BigInteger256([
+                    0x76e30cd266cc2903,
+                    0x0bea15c1d93cf8c5,
+                    0x8368a81034e2ad29,
+                    0x05b1e8f300d5a69b,
+                ])),
+                Felt::new(BigInteger256([
+                    0x2225e929fedd6a2e,
+                    0xbd5790058eb6c9df,
+                    0xbda3bff9748758ce,
+                    0x04aec08000a545dc,
+                ])),
+                Felt::new(BigInteger256([
+                    0xd53ee01f20203a0f,
+                    0xfcce1ab935e9ee33,
+                    0xbcc5c4f87b6156ad,
+                    0x01c083c0515548aa,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x1635d48125e39f39,
+                    0x30a690edee6facf6,
+                    0x6614baadf1644557,
+                    0x0687683e5ce3e107,
+                ])),
+                Felt::new(BigInteger256([
+                    0x951843da240b43f3,
+                    0xd736abc2a4a94b4a,
+                    0xa7d6c210c0cb8cd8,
+                    0x07b4edee5a67f742,
+                ])),
+                Felt::new(BigInteger256([
+                    0xba00b43121627d05,
+                    0x108bfdbb8e44169f,
+                    0x669a606cd7f3cee1,
+                    0x00300a95d95dd9f5,
+                ])),
+                Felt::new(BigInteger256([
+                    0x81cee919e30b1de6,
+                    0x9ca43e8a53adb66b,
+                    0xc60ec47bfd9bbae1,
+                    0x0521c8d6356c3510,
+                ])),
+                Felt::new(BigInteger256([
+                    0xfca41e3c7b674171,
+                    0x9f97b712fb5c4b5a,
+                    0x0a5abcefeb02143e,
+                    0x03f8b4601fc36a8a,
+                ])),
+                Felt::new(BigInteger256([
+                    0x13a641e4e2cc41d4,
+                    0x79402a30f259211a,
+                    0x14deee8c815dbb8b,
+                    0x073df6516c02e6e0,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0b7b70e7b0a17e0a,
+                    0x50637f814e1494d2,
+                    0x662132a72ea35b8f,
+                    0x04899804167326b5,
+                ])),
+                Felt::new(BigInteger256([
+                    0xe8f3d440d2f9fdbb,
+                    0x56521ec0a3f758d6,
+                    0x3fb65d40f8ad47df,
+                    0x015d3ab47bdc58c9,
+                ])),
+            ],
+            [
+                Felt::new(BigInteger256([
+                    0x8712133903cd10e7,
+                    0xcd7ff8f691acce78,
+                    0xd2a4aacc60c45222,
+                    0x0526feeeafd42802,
+                ])),
+                Felt::new(BigInteger256([
+                    0x0f25b7c2ceab0d57,
+                    0xfc3a33359c873cdb,
+                    0x494ac6b67f3186d3,
+                    0x03350d87fdacaf64,
+                ])),
+                Felt::new(BigInteger256([
+                    0x596b7d9c4b7e1766,
+                    0x79ee3b0306ad410e,
+                    0xfa73a550cca774aa,
+                    0x011f86bccbcc2530,
+                ])),
+                Felt::new(BigInteger256([
+                    0x9cb0ebe40607bede,
+                    0x4e5aeac9065a546f,
+                    0xc0cbfe63027ab67f,
+                    0x0205f400ac87f794,
+                ])),
+                Felt::new(BigInteger256([
+                    0x396c760138184171,
+                    0x6471f96353b9484e,
+                    0xdfc5e16cf77fdfa9,
+                    0x02f35d17247410c7,
+                ])),
+                Felt::new(BigInteger256([
+                    0xcbe6613e32d2acf6,
+                    0x969803f32981396e,
+                    0x630acf2967103154,
+                    0x052edfb67269ea77,
+                ])),
+                Felt::new(BigInteger256([
+                    0x058d6956ea6caa32,
+                    0xb6c4554f6178b61c,
+                    0x8ae49d61298e7f4b,
+                    0x07f5c7956362d364,
+                ])),
+                Felt::new(BigInteger256([
+                    0x57bd0bdce207d99f,
+                    0x02e4faaef9423601,
+                    0xf83cc59f150e0de3,
+                    0x04d0eecde8f79684,
+                ])),
+            ],
+        ];
+
+        for i in input.iter_mut() {
+            apply_mds(i);
+        }
+        for i in input2.iter_mut() {
+            apply_naive_mds(i);
+        }
+
+        for (index, (&i_1, i_2)) in input.iter().zip(input2).enumerate() {
+            assert_eq!(output[index], i_1);
+            assert_eq!(output[index], i_2);
+        }
+    }
+
+    #[test]
+    fn test_permutation_batch() {
+        let mut states: [[Felt; STATE_WIDTH]; 2 * BATCH_SIZE + 1] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| Felt::from((i * STATE_WIDTH + j) as u64))
+        });
+
+        let mut expected = states;
+        expected.iter_mut().for_each(apply_permutation);
+
+        apply_permutation_batch(&mut states);
+        assert_eq!(states, expected);
+    }
+}
@@ -0,0 +1,123 @@
+//! The STARK-252 prime field, used by Cairo and StarkNet.
+
+use ark_ff::{biginteger::BigInteger256 as BigInteger, fields::*};
+
+/// A prime field element, in Montgomery representation.
+pub type Fq = Fp256<FqParameters>;
+
+/// Parameters of the prime field.
+#[derive(Debug)]
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 192;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x4106bccd64a2bdd8,
+        0xaaada25731fe3be9,
+        0x0a35c5be60505574,
+        0x07222e32c47afc26,
+    ]);
+}
+impl FpParameters for FqParameters {
+    /// MODULUS = 3618502788666131213697322783095070105623107215331596699973092056135872020481
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0800000000000011,
+    ]);
+
+    const MODULUS_BITS: u32 = 252;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 4;
+
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0xffffffffffffffe1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffffdf0,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xfffffd737e000401,
+        0x00000001330fffff,
+        0xffffffffff6f8000,
+        0x07ffd4ab5e008810,
+    ]);
+
+    const INV: u64 = 0xffffffffffffffff;
+
+    // GENERATOR = 3
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0xffffffffffffffa1,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x07fffffffffff9b0,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8000000000000000,
+        0x0400000000000008,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x0800000000000011,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x0400000000000008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::fields::*;
+    use ark_ff::One;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_fq() {
+        let mut rng = test_rng();
+        let a: Fq = UniformRand::rand(&mut rng);
+        let b: Fq = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        sqrt_field_test(a);
+        from_str_test::<Fq>();
+        // `primefield_test` computes `1 << TWO_ADICITY` on a `u64`, which overflows here.
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let mut root = Fq::two_adic_root_of_unity();
+        for _ in 0..FqParameters::TWO_ADICITY - 1 {
+            root.square_in_place();
+        }
+        assert_eq!(root, -Fq::one());
+        assert_eq!(root.square(), Fq::one());
+    }
+}
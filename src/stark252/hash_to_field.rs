@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 48,
+    expanded: "3148f00213d73cf38a249b30e073fd170370d7534bc0b8a79f2dee67aff76824",
+    element: "2901596625137526755409781854298792804093790807608295396359444046712421905262",
+}
@@ -0,0 +1,335 @@
+use super::BigInteger256;
+use super::Felt;
+
+use crate::lanes::ChainElement;
+
+#[allow(unused)]
+/// Exponent of the Anemoi S-Box
+pub(crate) const ALPHA: u32 = 3;
+
+#[allow(unused)]
+/// Inverse exponent
+pub(crate) const INV_ALPHA: [u64; 4] = [
+    0xaaaaaaaaaaaaaaab,
+    0xaaaaaaaaaaaaaaaa,
+    0xaaaaaaaaaaaaaaaa,
+    0x0555555555555560,
+];
+
+/// Multiplier of the Anemoi S-Box
+#[allow(unused)]
+pub(crate) const BETA: u32 = 3;
+
+/// First added constant of the Anemoi S-Box
+pub(crate) const DELTA: Felt = Felt::new(BigInteger256([
+    0xaaaaaaaaaaaaaaa1,
+    0xaaaaaaaaaaaaaaaa,
+    0xaaaaaaaaaaaaaaaa,
+    0x07ffffffffffff5b,
+]));
+
+#[allow(unused)]
+/// Second added constant of the Anemoi S-Box
+pub(crate) const QUAD: u32 = 2;
+
+/// Computes `x^INV_ALPHA` with a fixed addition chain.
+///
+/// This function is **not** constant-time, as the underlying
+/// field arithmetic is not.
+#[inline(always)]
+pub(crate) fn exp_inv_alpha<T: ChainElement>(x: &T) -> T {
+    let t1 = x.square(); //        1: 2
+    let t2 = t1.square(); //       2: 4
+    let t3 = t2 * x; //            3: 5
+    let t4 = t3 * x; //            4: 6
+    let t5 = t4 * t3; //           5: 11
+    let t6 = t5 * x; //            6: 12
+    let t7 = t6 * t5; //           7: 23
+    let t8 = t7 * t4; //           8: 29
+    let t9 = t8.square(); //       9: 58
+    let t10 = t9 * t7; //         10: 81
+    let t11 = t10.square(); //    11: 162
+    let t12 = t11.square(); //    12: 324
+    let t13 = t12 * t7; //        13: 347
+    let t14 = t13 * t12; //       14: 671
+    let t15 = t14 * t6; //        15: 683
+    let t16 = t13.square(); //    16: 694
+    let t17 = t16 * t14; //       17: 1365
+    let t18 = t16.square(); //    18: 1388
+    let t19 = t18 * t17; //       19: 2753
+    let mut t0 = t17.square(); // 20: 2730
+    t0 = t0.square(); //          21: 5460
+    t0 = t0.square(); //          22: 10920
+    t0 = t0.square(); //          23: 21840
+    t0 = t0.square(); //          24: 43680
+    t0 = t0.square(); //          25: 87360
+    t0 = t0.square(); //          26: 174720
+    t0 = t0.square(); //          27: 349440
+    t0 = t0.square(); //          28: 698880
+    t0 = t0.square(); //          29: 1397760
+    t0 = t0.square(); //          30: 2795520
+    t0 = t0.square(); //          31: 5591040
+    t0 *= t17; //                 32: 5592405
+    t0 = t0.square(); //          33: 11184810
+    t0 = t0.square(); //          34: 22369620
+    t0 = t0.square(); //          35: 44739240
+    t0 = t0.square(); //          36: 89478480
+    t0 = t0.square(); //          37: 178956960
+    t0 = t0.square(); //          38: 357913920
+    t0 = t0.square(); //          39: 715827840
+    t0 = t0.square(); //          40: 1431655680
+    t0 = t0.square(); //          41: 2863311360
+    t0 = t0.square(); //          42: 5726622720
+    t0 = t0.square(); //          43: 11453245440
+    t0 = t0.square(); //          44: 22906490880
+    t0 *= t17; //                 45: 22906492245
+    t0 = t0.square(); //          46: 45812984490
+    t0 = t0.square(); //          47: 91625968980
+    t0 = t0.square(); //          48: 183251937960
+    t0 = t0.square(); //          49: 366503875920
+    t0 = t0.square(); //          50: 733007751840
+    t0 = t0.square(); //          51: 1466015503680
+    t0 = t0.square(); //          52: 2932031007360
+    t0 = t0.square(); //          53: 5864062014720
+    t0 = t0.square(); //          54: 11728124029440
+    t0 = t0.square(); //          55: 23456248058880
+    t0 = t0.square(); //          56: 46912496117760
+    t0 = t0.square(); //          57: 93824992235520
+    t0 *= t17; //                 58: 93824992236885
+    t0 = t0.square(); //          59: 187649984473770
+    t0 = t0.square(); //          60: 375299968947540
+    t0 = t0.square(); //          61: 750599937895080
+    t0 = t0.square(); //          62: 1501199875790160
+    t0 = t0.square(); //          63: 3002399751580320
+    t0 = t0.square(); //          64: 6004799503160640
+    t0 = t0.square(); //          65: 12009599006321280
+    t0 = t0.square(); //          66: 24019198012642560
+    t0 = t0.square(); //          67: 48038396025285120
+    t0 = t0.square(); //          68: 96076792050570240
+    t0 = t0.square(); //          69: 192153584101140480
+    t0 = t0.square(); //          70: 384307168202280960
+    t0 = t0.square(); //          71: 768614336404561920
+    t0 *= t19; //                 72: 768614336404564673
+    t0 = t0.square(); //          73: 1537228672809129346
+    t0 = t0.square(); //          74: 3074457345618258692
+    t0 = t0.square(); //          75: 6148914691236517384
+    t0 = t0.square(); //          76: 12297829382473034768
+    t0 = t0.square(); //          77: 24595658764946069536
+    t0 = t0.square(); //          78: 49191317529892139072
+    t0 = t0.square(); //          79: 98382635059784278144
+    t0 = t0.square(); //          80: 196765270119568556288
+    t0 = t0.square(); //          81: 393530540239137112576
+    t0 = t0.square(); //          82: 787061080478274225152
+    t0 = t0.square(); //          83: 1574122160956548450304
+    t0 = t0.square(); //          84: 3148244321913096900608
+    t0 *= t17; //                 85: 3148244321913096901973
+    t0 = t0.square(); //          86: 6296488643826193803946
+    t0 = t0.square(); //          87: 12592977287652387607892
+    t0 = t0.square(); //          88: 25185954575304775215784
+    t0 = t0.square(); //          89: 50371909150609550431568
+    t0 = t0.square(); //          90: 100743818301219100863136
+    t0 = t0.square(); //          91: 201487636602438201726272
+    t0 = t0.square(); //          92: 402975273204876403452544
+    t0 = t0.square(); //          93: 805950546409752806905088
+    t0 = t0.square(); //          94: 1611901092819505613810176
+    t0 = t0.square(); //          95: 3223802185639011227620352
+    t0 = t0.square(); //          96: 6447604371278022455240704
+    t0 = t0.square(); //          97: 12895208742556044910481408
+    t0 *= t17; //                 98: 12895208742556044910482773
+    t0 = t0.square(); //          99: 25790417485112089820965546
+    t0 = t0.square(); //         100: 51580834970224179641931092
+    t0 = t0.square(); //         101: 103161669940448359283862184
+    t0 = t0.square(); //         102: 206323339880896718567724368
+    t0 = t0.square(); //         103: 412646679761793437135448736
+    t0 = t0.square(); //         104: 825293359523586874270897472
+    t0 = t0.square(); //         105: 1650586719047173748541794944
+    t0 = t0.square(); //         106: 3301173438094347497083589888
+    t0 = t0.square(); //         107: 6602346876188694994167179776
+    t0 = t0.square(); //         108: 13204693752377389988334359552
+    t0 = t0.square(); //         109: 26409387504754779976668719104
+    t0 = t0.square(); //         110: 52818775009509559953337438208
+    t0 *= t17; //                111: 52818775009509559953337439573
+    t0 = t0.square(); //         112: 105637550019019119906674879146
+    t0 = t0.square(); //         113: 211275100038038239813349758292
+    t0 = t0.square(); //         114: 422550200076076479626699516584
+    t0 = t0.square(); //         115: 845100400152152959253399033168
+    t0 = t0.square(); //         116: 1690200800304305918506798066336
+    t0 = t0.square(); //         117: 3380401600608611837013596132672
+    t0 = t0.square(); //         118: 6760803201217223674027192265344
+    t0 = t0.square(); //         119: 13521606402434447348054384530688
+    t0 = t0.square(); //         120: 27043212804868894696108769061376
+    t0 = t0.square(); //         121: 54086425609737789392217538122752
+    t0 = t0.square(); //         122: 108172851219475578784435076245504
+    t0 = t0.square(); //         123: 216345702438951157568870152491008
+    t0 *= t17; //                124: 216345702438951157568870152492373
+    t0 = t0.square(); //         125: 432691404877902315137740304984746
+    t0 = t0.square(); //         126: 865382809755804630275480609969492
+    t0 = t0.square(); //         127: 1730765619511609260550961219938984
+    t0 = t0.square(); //         128: 3461531239023218521101922439877968
+    t0 = t0.square(); //         129: 6923062478046437042203844879755936
+    t0 = t0.square(); //         130: 13846124956092874084407689759511872
+    t0 = t0.square(); //         131: 27692249912185748168815379519023744
+    t0 = t0.square(); //         132: 55384499824371496337630759038047488
+    t0 = t0.square(); //         133: 110768999648742992675261518076094976
+    t0 = t0.square(); //         134: 221537999297485985350523036152189952
+    t0 = t0.square(); //         135: 443075998594971970701046072304379904
+    t0 = t0.square(); //         136: 886151997189943941402092144608759808
+    t0 *= t17; //                137: 886151997189943941402092144608761173
+    t0 = t0.square(); //         138: 1772303994379887882804184289217522346
+    t0 = t0.square(); //         139: 3544607988759775765608368578435044692
+    t0 = t0.square(); //         140: 7089215977519551531216737156870089384
+    t0 = t0.square(); //         141: 14178431955039103062433474313740178768
+    t0 = t0.square(); //         142: 28356863910078206124866948627480357536
+    t0 = t0.square(); //         143: 56713727820156412249733897254960715072
+    t0 = t0.square(); //         144: 113427455640312824499467794509921430144
+    t0 = t0.square(); //         145: 226854911280625648998935589019842860288
+    t0 = t0.square(); //         146: 453709822561251297997871178039685720576
+    t0 = t0.square(); //         147: 907419645122502595995742356079371441152
+    t0 = t0.square(); //         148: 1814839290245005191991484712158742882304
+    t0 = t0.square(); //         149: 3629678580490010383982969424317485764608
+    t0 *= t17; //                150: 3629678580490010383982969424317485765973
+    t0 = t0.square(); //         151: 7259357160980020767965938848634971531946
+    t0 = t0.square(); //         152: 14518714321960041535931877697269943063892
+    t0 = t0.square(); //         153: 29037428643920083071863755394539886127784
+    t0 = t0.square(); //         154: 58074857287840166143727510789079772255568
+    t0 = t0.square(); //         155: 116149714575680332287455021578159544511136
+    t0 = t0.square(); //         156: 232299429151360664574910043156319089022272
+    t0 = t0.square(); //         157: 464598858302721329149820086312638178044544
+    t0 = t0.square(); //         158: 929197716605442658299640172625276356089088
+    t0 = t0.square(); //         159: 1858395433210885316599280345250552712178176
+    t0 = t0.square(); //         160: 3716790866421770633198560690501105424356352
+    t0 = t0.square(); //         161: 7433581732843541266397121381002210848712704
+    t0 = t0.square(); //         162: 14867163465687082532794242762004421697425408
+    t0 *= t17; //                163: 14867163465687082532794242762004421697426773
+    t0 = t0.square(); //         164: 29734326931374165065588485524008843394853546
+    t0 = t0.square(); //         165: 59468653862748330131176971048017686789707092
+    t0 = t0.square(); //         166: 118937307725496660262353942096035373579414184
+    t0 = t0.square(); //         167: 237874615450993320524707884192070747158828368
+    t0 = t0.square(); //         168: 475749230901986641049415768384141494317656736
+    t0 = t0.square(); //         169: 951498461803973282098831536768282988635313472
+    t0 = t0.square(); //         170: 1902996923607946564197663073536565977270626944
+    t0 = t0.square(); //         171: 3805993847215893128395326147073131954541253888
+    t0 = t0.square(); //         172: 7611987694431786256790652294146263909082507776
+    t0 = t0.square(); //         173: 15223975388863572513581304588292527818165015552
+    t0 = t0.square(); //         174: 30447950777727145027162609176585055636330031104
+    t0 = t0.square(); //         175: 60895901555454290054325218353170111272660062208
+    t0 *= t17; //                176: 60895901555454290054325218353170111272660063573
+    t0 = t0.square(); //         177: 121791803110908580108650436706340222545320127146
+    t0 = t0.square(); //         178: 243583606221817160217300873412680445090640254292
+    t0 = t0.square(); //         179: 487167212443634320434601746825360890181280508584
+    t0 = t0.square(); //         180: 974334424887268640869203493650721780362561017168
+    t0 = t0.square(); //         181: 1948668849774537281738406987301443560725122034336
+    t0 = t0.square(); //         182: 3897337699549074563476813974602887121450244068672
+    t0 = t0.square(); //         183: 7794675399098149126953627949205774242900488137344
+    t0 = t0.square(); //         184: 15589350798196298253907255898411548485800976274688
+    t0 = t0.square(); //         185: 31178701596392596507814511796823096971601952549376
+    t0 = t0.square(); //         186: 62357403192785193015629023593646193943203905098752
+    t0 = t0.square(); //         187: 124714806385570386031258047187292387886407810197504
+    t0 = t0.square(); //         188: 249429612771140772062516094374584775772815620395008
+    t0 *= t17; //                189: 249429612771140772062516094374584775772815620396373
+    t0 = t0.square(); //         190: 498859225542281544125032188749169551545631240792746
+    t0 = t0.square(); //         191: 997718451084563088250064377498339103091262481585492
+    t0 = t0.square(); //         192: 1995436902169126176500128754996678206182524963170984
+    t0 = t0.square(); //         193: 3990873804338252353000257509993356412365049926341968
+    t0 = t0.square(); //         194: 7981747608676504706000515019986712824730099852683936
+    t0 = t0.square(); //         195: 15963495217353009412001030039973425649460199705367872
+    t0 = t0.square(); //         196: 31926990434706018824002060079946851298920399410735744
+    t0 = t0.square(); //         197: 63853980869412037648004120159893702597840798821471488
+    t0 = t0.square(); //         198: 127707961738824075296008240319787405195681597642942976
+    t0 = t0.square(); //         199: 255415923477648150592016480639574810391363195285885952
+    t0 = t0.square(); //         200: 510831846955296301184032961279149620782726390571771904
+    t0 = t0.square(); //         201: 1021663693910592602368065922558299241565452781143543808
+    t0 *= t17; //                202: 1021663693910592602368065922558299241565452781143545173
+    t0 = t0.square(); //         203: 2043327387821185204736131845116598483130905562287090346
+    t0 = t0.square(); //         204: 4086654775642370409472263690233196966261811124574180692
+    t0 = t0.square(); //         205: 8173309551284740818944527380466393932523622249148361384
+    t0 = t0.square(); //         206: 16346619102569481637889054760932787865047244498296722768
+    t0 = t0.square(); //         207: 32693238205138963275778109521865575730094488996593445536
+    t0 = t0.square(); //         208: 65386476410277926551556219043731151460188977993186891072
+    t0 = t0.square(); //         209: 130772952820555853103112438087462302920377955986373782144
+    t0 = t0.square(); //         210: 261545905641111706206224876174924605840755911972747564288
+    t0 = t0.square(); //         211: 523091811282223412412449752349849211681511823945495128576
+    t0 = t0.square(); //         212: 1046183622564446824824899504699698423363023647890990257152
+    t0 = t0.square(); //         213: 2092367245128893649649799009399396846726047295781980514304
+    t0 = t0.square(); //         214: 4184734490257787299299598018798793693452094591563961028608
+    t0 *= t17; //                215: 4184734490257787299299598018798793693452094591563961029973
+    t0 = t0.square(); //         216: 8369468980515574598599196037597587386904189183127922059946
+    t0 = t0.square(); //         217: 16738937961031149197198392075195174773808378366255844119892
+    t0 = t0.square(); //         218: 33477875922062298394396784150390349547616756732511688239784
+    t0 = t0.square(); //         219: 66955751844124596788793568300780699095233513465023376479568
+    t0 = t0.square(); //         220: 133911503688249193577587136601561398190467026930046752959136
+    t0 = t0.square(); //         221: 267823007376498387155174273203122796380934053860093505918272
+    t0 = t0.square(); //         222: 535646014752996774310348546406245592761868107720187011836544
+    t0 = t0.square(); //         223: 1071292029505993548620697092812491185523736215440374023673088
+    t0 = t0.square(); //         224: 2142584059011987097241394185624982371047472430880748047346176
+    t0 = t0.square(); //         225: 4285168118023974194482788371249964742094944861761496094692352
+    t0 = t0.square(); //         226: 8570336236047948388965576742499929484189889723522992189384704
+    t0 = t0.square(); //         227: 17140672472095896777931153484999858968379779447045984378769408
+    t0 *= t17; //                228: 17140672472095896777931153484999858968379779447045984378770773
+    t0 = t0.square(); //         229: 34281344944191793555862306969999717936759558894091968757541546
+    t0 = t0.square(); //         230: 68562689888383587111724613939999435873519117788183937515083092
+    t0 = t0.square(); //         231: 137125379776767174223449227879998871747038235576367875030166184
+    t0 = t0.square(); //         232: 274250759553534348446898455759997743494076471152735750060332368
+    t0 = t0.square(); //         233: 548501519107068696893796911519995486988152942305471500120664736
+    t0 = t0.square(); //         234: 1097003038214137393787593823039990973976305884610943000241329472
+    t0 = t0.square(); //         235: 2194006076428274787575187646079981947952611769221886000482658944
+    t0 = t0.square(); //         236: 4388012152856549575150375292159963895905223538443772000965317888
+    t0 = t0.square(); //         237: 8776024305713099150300750584319927791810447076887544001930635776
+    t0 = t0.square(); //         238: 17552048611426198300601501168639855583620894153775088003861271552
+    t0 = t0.square(); //         239: 35104097222852396601203002337279711167241788307550176007722543104
+    t0 = t0.square(); //         240: 70208194445704793202406004674559422334483576615100352015445086208
+    t0 *= t17; //                241: 70208194445704793202406004674559422334483576615100352015445087573
+    t0 = t0.square(); //         242: 140416388891409586404812009349118844668967153230200704030890175146
+    t0 = t0.square(); //         243: 280832777782819172809624018698237689337934306460401408061780350292
+    t0 = t0.square(); //         244: 561665555565638345619248037396475378675868612920802816123560700584
+    t0 = t0.square(); //         245: 1123331111131276691238496074792950757351737225841605632247121401168
+    t0 = t0.square(); //         246: 2246662222262553382476992149585901514703474451683211264494242802336
+    t0 = t0.square(); //         247: 4493324444525106764953984299171803029406948903366422528988485604672
+    t0 = t0.square(); //         248: 8986648889050213529907968598343606058813897806732845057976971209344
+    t0 = t0.square(); //         249: 17973297778100427059815937196687212117627795613465690115953942418688
+    t0 = t0.square(); //         250: 35946595556200854119631874393374424235255591226931380231907884837376
+    t0 = t0.square(); //         251: 71893191112401708239263748786748848470511182453862760463815769674752
+    t0 = t0.square(); //         252: 143786382224803416478527497573497696941022364907725520927631539349504
+    t0 = t0.square(); //         253: 287572764449606832957054995146995393882044729815451041855263078699008
+    t0 *= t17; //                254: 287572764449606832957054995146995393882044729815451041855263078700373
+    t0 = t0.square(); //         255: 575145528899213665914109990293990787764089459630902083710526157400746
+    t0 = t0.square(); //         256: 1150291057798427331828219980587981575528178919261804167421052314801492
+    t0 = t0.square(); //         257: 2300582115596854663656439961175963151056357838523608334842104629602984
+    t0 = t0.square(); //         258: 4601164231193709327312879922351926302112715677047216669684209259205968
+    t0 = t0.square(); //         259: 9202328462387418654625759844703852604225431354094433339368418518411936
+    t0 = t0.square(); //         260: 18404656924774837309251519689407705208450862708188866678736837036823872
+    t0 = t0.square(); //         261: 36809313849549674618503039378815410416901725416377733357473674073647744
+    t0 = t0.square(); //         262: 73618627699099349237006078757630820833803450832755466714947348147295488
+    t0 = t0.square(); //         263: 147237255398198698474012157515261641667606901665510933429894696294590976
+    t0 = t0.square(); //         264: 294474510796397396948024315030523283335213803331021866859789392589181952
+    t0 = t0.square(); //         265: 588949021592794793896048630061046566670427606662043733719578785178363904
+    t0 = t0.square(); //         266: 1177898043185589587792097260122093133340855213324087467439157570356727808
+    t0 *= t17; //                267: 1177898043185589587792097260122093133340855213324087467439157570356729173
+    t0 = t0.square(); //         268: 2355796086371179175584194520244186266681710426648174934878315140713458346
+    t0 = t0.square(); //         269: 4711592172742358351168389040488372533363420853296349869756630281426916692
+    t0 = t0.square(); //         270: 9423184345484716702336778080976745066726841706592699739513260562853833384
+    t0 = t0.square(); //         271: 18846368690969433404673556161953490133453683413185399479026521125707666768
+    t0 = t0.square(); //         272: 37692737381938866809347112323906980266907366826370798958053042251415333536
+    t0 = t0.square(); //         273: 75385474763877733618694224647813960533814733652741597916106084502830667072
+    t0 = t0.square(); //         274: 150770949527755467237388449295627921067629467305483195832212169005661334144
+    t0 = t0.square(); //         275: 301541899055510934474776898591255842135258934610966391664424338011322668288
+    t0 = t0.square(); //         276: 603083798111021868949553797182511684270517869221932783328848676022645336576
+    t0 = t0.square(); //         277: 1206167596222043737899107594365023368541035738443865566657697352045290673152
+    t0 = t0.square(); //         278: 2412335192444087475798215188730046737082071476887731133315394704090581346304
+    t0 * t15 //                  279: 2412335192444087475798215188730046737082071476887731133315394704090581346987
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{Field, UniformRand};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_alpha() {
+        for _ in 0..100 {
+            let a = Felt::rand(&mut OsRng);
+            assert_eq!(exp_inv_alpha(&a), a.pow(INV_ALPHA));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Hashing to short Weierstrass curves with the Shallue-van de Woestijne map, following RFC 9380.
+
+use ark_ec::models::short_weierstrass_jacobian::GroupAffine;
+use ark_ec::models::SWModelParameters;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, Field, One, PrimeField, SquareRootField, Zero};
+
+/// Parameters of the Shallue-van de Woestijne map to a short Weierstrass curve
+/// `y^2 = g(x) = x^3 + A * x + B`.
+pub(crate) trait SvdwParameters: SWModelParameters {
+    /// Element of the base field chosen as in Appendix H.1 of RFC 9380.
+    const Z: Self::BaseField;
+    /// `g(Z)`.
+    const C1: Self::BaseField;
+    /// `-Z / 2`.
+    const C2: Self::BaseField;
+    /// `sqrt(-g(Z) * (3 * Z^2 + 4 * A))`, with `sgn0(C3) = 0`.
+    const C3: Self::BaseField;
+    /// `-4 * g(Z) / (3 * Z^2 + 4 * A)`.
+    const C4: Self::BaseField;
+}
+
+/// Returns the sign of `x`, i.e. the parity of its canonical representative.
+fn sgn0<F: PrimeField>(x: &F) -> bool {
+    x.into_repr().is_odd()
+}
+
+/// Maps a field element to a point of the curve with the Shallue-van de Woestijne map.
+pub(crate) fn map_to_curve<P>(u: P::BaseField) -> GroupAffine<P>
+where
+    P: SvdwParameters,
+    P::BaseField: PrimeField,
+{
+    let curve_equation = |x: P::BaseField| (x.square() + P::COEFF_A) * x + P::COEFF_B;
+
+    let tv1 = u.square() * P::C1;
+    let tv2 = P::BaseField::one() + tv1;
+    let tv1 = P::BaseField::one() - tv1;
+    let tv3 = (tv1 * tv2).inverse().unwrap_or_else(P::BaseField::zero);
+    let tv4 = u * tv1 * tv3 * P::C3;
+    let x1 = P::C2 - tv4;
+    let x2 = P::C2 + tv4;
+
+    let (x, mut y) = if let Some(y) = curve_equation(x1).sqrt() {
+        (x1, y)
+    } else if let Some(y) = curve_equation(x2).sqrt() {
+        (x2, y)
+    } else {
+        let x3 = (tv2.square() * tv3).square() * P::C4 + P::Z;
+        (x3, curve_equation(x3).sqrt().unwrap())
+    };
+    if sgn0(&u) != sgn0(&y) {
+        y = -y;
+    }
+
+    GroupAffine::new(x, y, false)
+}
+
+/// Maps two field elements to a point of the prime order subgroup of the curve,
+/// by adding their images through [`map_to_curve`] and clearing the cofactor.
+pub(crate) fn hash_to_curve<P>(u: &[P::BaseField]) -> GroupAffine<P>
+where
+    P: SvdwParameters,
+    P::BaseField: PrimeField,
+{
+    let mut point = map_to_curve::<P>(u[0]).into_projective();
+    point += &map_to_curve::<P>(u[1]).into_projective();
+
+    point.into_affine().mul_by_cofactor()
+}
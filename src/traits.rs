@@ -1,4 +1,7 @@
-use ark_ff::Field;
+use crate::{DigestError, Encoding, PaddingRule};
+use ark_ff::{Field, PrimeField};
+use core::fmt::Debug;
+use core::hash::Hash;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
@@ -6,18 +9,80 @@ use alloc::vec::Vec;
 /// Trait for implementing a Sponge construction.
 pub trait Sponge<F: Field> {
     /// Specifies a digest type returned by this hasher.
-    type Digest;
+    type Digest: AnemoiDigestTrait<F>;
 
     /// Returns a hash of the provided sequence of bytes.
     fn hash(bytes: &[u8]) -> Self::Digest;
 
     /// Returns a hash of the provided sequence of field elements.
+    ///
+    /// The sequence is padded as specified in the Anemoi paper and in its reference
+    /// implementation, which is the same for every [`PaddingRule`]: rules only differ in
+    /// how bytes are split into field elements.
     fn hash_field(elems: &[F]) -> Self::Digest;
 
+    /// Returns a hash of the provided sequence of bytes, encoded as a sequence of
+    /// field elements with the provided [`Encoding`].
+    ///
+    /// With [`Encoding::Padded`], this is the same as [`Self::hash`]. Other encodings
+    /// yield the hash of the encoded sequence of field elements with [`Self::hash_field`].
+    fn hash_with_encoding(bytes: &[u8], encoding: Encoding) -> Self::Digest
+    where
+        F: PrimeField,
+    {
+        match encoding {
+            Encoding::Padded => Self::hash(bytes),
+            _ => Self::hash_field(&encoding.encode(bytes)),
+        }
+    }
+
+    /// Returns a hash of the provided sequence of bytes, split into field elements
+    /// with the provided [`PaddingRule`].
+    ///
+    /// This is the same as [`Self::hash_with_encoding`] with the [`Encoding`] of the rule,
+    /// and hence as [`Self::hash`] with [`PaddingRule::Current`].
+    fn hash_with_padding(bytes: &[u8], rule: PaddingRule) -> Self::Digest
+    where
+        F: PrimeField,
+    {
+        Self::hash_with_encoding(bytes, rule.into())
+    }
+
     /// Compresses two given digests into one.
     fn merge(digests: &[Self::Digest; 2]) -> Self::Digest;
 }
 
+/// Trait for the digests returned by Anemoi hashers over the field `F`.
+pub trait AnemoiDigestTrait<F: Field>: Copy + Debug + Eq + Hash + Default {
+    /// Length in bytes of the encoding of a digest returned by [`Self::to_bytes`].
+    const BYTE_LEN: usize;
+
+    /// Returns a reference to the elements of the digest.
+    fn as_elements(&self) -> &[F];
+
+    /// Returns the elements of the digest.
+    fn to_elements(&self) -> Vec<F> {
+        self.as_elements().to_vec()
+    }
+
+    /// Returns a `Vec<F>` from the provided digest slice.
+    fn digests_to_elements(digests: &[Self]) -> Vec<F> {
+        digests
+            .iter()
+            .flat_map(|digest| digest.as_elements().iter().copied())
+            .collect()
+    }
+
+    /// Returns the canonical encoding of the digest, of [`Self::BYTE_LEN`] bytes.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Returns the digest encoded by the provided bytes, as output by [`Self::to_bytes`].
+    ///
+    /// Returns an error if the length of the sequence is not [`Self::BYTE_LEN`], or if
+    /// any element of the digest is not canonically encoded.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, DigestError>;
+}
+
 /// Trait for implementing a Jive compression function instantiation.
 pub trait Jive<F: Field> {
     /// Compresses the provided field element slice as input by 2.
@@ -30,3 +95,47 @@ pub trait Jive<F: Field> {
     /// The slice must be of the same length than the underlying hash state.
     fn compress_k(elems: &[F], k: usize) -> Vec<F>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Only relies on the bounds of `Sponge::Digest`.
+    fn check_digest<F: PrimeField, H: Sponge<F>>(byte_len: usize) {
+        let elems = [F::one(), F::from(2u64), F::from(3u64)];
+        let digest = H::hash_field(&elems);
+
+        assert_eq!(H::Digest::BYTE_LEN, byte_len);
+        let bytes = digest.to_bytes();
+        assert_eq!(bytes.len(), H::Digest::BYTE_LEN);
+        assert_eq!(H::Digest::from_bytes(&bytes), Ok(digest));
+        assert!(H::Digest::from_bytes(&bytes[1..]).is_err());
+
+        assert_eq!(digest.to_elements(), digest.as_elements());
+        assert_eq!(
+            H::Digest::digests_to_elements(&[digest, H::Digest::default()]),
+            [
+                digest.to_elements(),
+                vec![F::zero(); digest.as_elements().len()]
+            ]
+            .concat()
+        );
+        assert_ne!(digest, H::hash_field(&elems[..2]));
+        assert_eq!(
+            H::Digest::from_bytes(&vec![0; byte_len]),
+            Ok(H::Digest::default())
+        );
+    }
+
+    #[test]
+    fn test_digest_trait() {
+        #[cfg(feature = "bls12_381")]
+        check_digest::<_, crate::bls12_381::anemoi_2_1::AnemoiHash>(48);
+        #[cfg(feature = "bn_254")]
+        check_digest::<_, crate::bn_254::anemoi_4_3::AnemoiHash>(32);
+        #[cfg(all(feature = "bw6_761", feature = "anemoi_256"))]
+        check_digest::<_, crate::bw6_761::anemoi_256::anemoi_4_2::AnemoiHash>(192);
+        #[cfg(feature = "pallas")]
+        check_digest::<_, crate::pallas::anemoi_12_11::AnemoiHash>(32);
+    }
+}
@@ -4,7 +4,7 @@ use super::BigInteger256;
 use super::Felt;
 use super::NUM_COLUMNS;
 
-/// Maximum Diffusion Layer matrix for Anemoi.
+/// Maximum Diffusion Layer matrix for Anemoi
 /// [6 1 6]
 /// [1 1 5]
 /// [5 1 1]
@@ -0,0 +1,7 @@
+//! Hashing to the field with Anemoi, following RFC 9380.
+
+impl_hash_to_field! {
+    bytes: 48,
+    expanded: "1c71d20398d2f234e69be75b18b7c161293f350118644a70a4b2da1d47194ee6",
+    element: "25709405941058498476230631489294860788704983159132673272327964281950665304218",
+}